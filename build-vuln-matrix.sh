@@ -0,0 +1,61 @@
+#!/bin/bash
+# Soteria Vulnerability Matrix Builder
+#
+# Secure programs can compile individual vulnerabilities back in through
+# vuln-* cargo features (see the [features] table of each program's Cargo.toml).
+# This script builds, for every program that declares such features:
+#   1. The baseline .so            -> <program>/target/deploy/<lib>.so
+#   2. One .so per vuln-* feature  -> <program>/target/deploy/vuln-matrix/<feature>/<lib>.so
+#
+# The differential suites load these with FeatureVariant (soteria-test-kit)
+# and check that each feature build, and only that build, accepts its exploit.
+#
+# Usage:
+#   ./build-vuln-matrix.sh                  # every program with vuln-* features
+#   ./build-vuln-matrix.sh stream-secure    # only programs whose path matches
+#   ./build-vuln-matrix.sh --list           # print the matrix without building
+set -e
+
+ROOT="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+MATRIX_DIR="target/deploy/vuln-matrix"
+
+LIST_ONLY=0
+FILTER=""
+if [ "$1" = "--list" ]; then
+    LIST_ONLY=1
+else
+    FILTER="$1"
+fi
+
+# vuln-* feature names declared in a manifest's [features] table
+vuln_features() {
+    sed -n '/^\[features\]/,/^\[/p' "$1" | grep -o '^vuln-[a-z0-9-]*' || true
+}
+
+built=0
+for manifest in "$ROOT"/programs/*/*/Cargo.toml; do
+    program_dir="$(dirname "$manifest")"
+    features="$(vuln_features "$manifest")"
+
+    [ -z "$features" ] && continue
+    [ -n "$FILTER" ] && [[ "$program_dir" != *"$FILTER"* ]] && continue
+
+    echo "==> ${program_dir#$ROOT/}"
+    if [ "$LIST_ONLY" = 1 ]; then
+        for feature in $features; do
+            echo "    $feature"
+        done
+        continue
+    fi
+
+    (cd "$program_dir" && cargo build-sbf)
+    for feature in $features; do
+        echo "    building $feature"
+        (cd "$program_dir" && cargo build-sbf --features "$feature" --sbf-out-dir "$MATRIX_DIR/$feature")
+        built=$((built + 1))
+    done
+done
+
+if [ "$LIST_ONLY" = 0 ]; then
+    echo "Built $built feature variant(s)"
+fi
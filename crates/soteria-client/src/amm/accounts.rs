@@ -0,0 +1,196 @@
+// AMM Accounts
+//
+// Mirrors state/amm_config.rs, state/pool_config.rs, state/position.rs and
+// state/limit_order.rs.
+
+use solana_sdk::pubkey::Pubkey;
+
+use super::SWAP_VOLUME_RING_SIZE;
+use crate::codec::{DataReader, DecodeError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmmConfig {
+    pub admin: Pubkey,
+    pub pool_creation_authority: Pubkey,
+    pub fee_tiers: Vec<u16>,
+    pub bump: u8,
+}
+
+impl AmmConfig {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "AmmConfig")?;
+        Ok(Self {
+            admin: reader.pubkey()?,
+            pool_creation_authority: reader.pubkey()?,
+            fee_tiers: reader.vec(|r| r.u16())?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolConfig {
+    pub authority: Pubkey,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub lp_token_mint: Pubkey,
+    pub fee_basis_points: u16,
+    pub locked: bool,
+    pub config_bump: u8,
+    pub authority_bump: u8,
+    pub lp_mint_bump: u8,
+    pub flash_loan_active: bool,
+    pub flash_loan_mint: Pubkey,
+    pub flash_loan_vault_balance_before: u64,
+    pub flash_loan_fee: u64,
+    pub pending_fee_basis_points: u16,
+    pub fee_change_unlock_time: i64,
+    pub fee_change_delay_seconds: i64,
+    pub emergency_withdraw_enabled: bool,
+    pub referral_fee_basis_points: u16,
+    pub oracle_program: Pubkey,
+    pub price_feed: Pubkey,
+    pub max_oracle_deviation_bps: u16,
+    pub max_price_impact_bps: u16,
+    pub circuit_breaker_auto_lock: bool,
+    pub fee_growth_global: u128,
+    pub max_swap_per_slot_bps: u16,
+    pub swap_volumes: [SlotSwapVolume; SWAP_VOLUME_RING_SIZE],
+    pub fee_tier: u16,
+}
+
+// One slot's entry in PoolConfig::swap_volumes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SlotSwapVolume {
+    pub slot: u64,
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+    pub volume_a: u64,
+    pub volume_b: u64,
+}
+
+impl SlotSwapVolume {
+    fn read(reader: &mut DataReader) -> Result<Self, DecodeError> {
+        Ok(Self {
+            slot: reader.u64()?,
+            reserve_a: reader.u64()?,
+            reserve_b: reader.u64()?,
+            volume_a: reader.u64()?,
+            volume_b: reader.u64()?,
+        })
+    }
+}
+
+impl PoolConfig {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "PoolConfig")?;
+        Ok(Self {
+            authority: reader.pubkey()?,
+            token_a_mint: reader.pubkey()?,
+            token_b_mint: reader.pubkey()?,
+            lp_token_mint: reader.pubkey()?,
+            fee_basis_points: reader.u16()?,
+            locked: reader.bool()?,
+            config_bump: reader.u8()?,
+            authority_bump: reader.u8()?,
+            lp_mint_bump: reader.u8()?,
+            flash_loan_active: reader.bool()?,
+            flash_loan_mint: reader.pubkey()?,
+            flash_loan_vault_balance_before: reader.u64()?,
+            flash_loan_fee: reader.u64()?,
+            pending_fee_basis_points: reader.u16()?,
+            fee_change_unlock_time: reader.i64()?,
+            fee_change_delay_seconds: reader.i64()?,
+            emergency_withdraw_enabled: reader.bool()?,
+            referral_fee_basis_points: reader.u16()?,
+            oracle_program: reader.pubkey()?,
+            price_feed: reader.pubkey()?,
+            max_oracle_deviation_bps: reader.u16()?,
+            max_price_impact_bps: reader.u16()?,
+            circuit_breaker_auto_lock: reader.bool()?,
+            fee_growth_global: reader.u128()?,
+            max_swap_per_slot_bps: reader.u16()?,
+            swap_volumes: [
+                SlotSwapVolume::read(&mut reader)?,
+                SlotSwapVolume::read(&mut reader)?,
+                SlotSwapVolume::read(&mut reader)?,
+                SlotSwapVolume::read(&mut reader)?,
+            ],
+            fee_tier: reader.u16()?,
+        })
+    }
+
+    // Volume recorded for `slot`, if the ring still holds it
+    pub fn swap_volume_at(&self, slot: u64) -> Option<&SlotSwapVolume> {
+        self.swap_volumes
+            .iter()
+            .find(|entry| entry.slot == slot && entry.reserve_a > 0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Position {
+    pub pool_config: Pubkey,
+    pub asset: Pubkey,
+    pub lp_tokens: u64,
+    pub amount_a: u64,
+    pub amount_b: u64,
+    pub entry_price: u64,
+    pub created_at: i64,
+    pub fee_growth_checkpoint: u128,
+    pub bump: u8,
+}
+
+impl Position {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "Position")?;
+        Ok(Self {
+            pool_config: reader.pubkey()?,
+            asset: reader.pubkey()?,
+            lp_tokens: reader.u64()?,
+            amount_a: reader.u64()?,
+            amount_b: reader.u64()?,
+            entry_price: reader.u64()?,
+            created_at: reader.i64()?,
+            fee_growth_checkpoint: reader.u128()?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LimitOrder {
+    pub owner: Pubkey,
+    pub pool_config: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub order_id: u64,
+    pub input_amount: u64,
+    pub min_output_amount: u64,
+    pub keeper_tip: u64,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl LimitOrder {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "LimitOrder")?;
+        Ok(Self {
+            owner: reader.pubkey()?,
+            pool_config: reader.pubkey()?,
+            input_mint: reader.pubkey()?,
+            output_mint: reader.pubkey()?,
+            order_id: reader.u64()?,
+            input_amount: reader.u64()?,
+            min_output_amount: reader.u64()?,
+            keeper_tip: reader.u64()?,
+            expires_at: reader.i64()?,
+            bump: reader.u8()?,
+        })
+    }
+
+    // Whether a fill at `now` is still allowed
+    pub fn is_expired(&self, now: i64) -> bool {
+        now > self.expires_at
+    }
+}
@@ -0,0 +1,1134 @@
+// AMM Instructions
+//
+// Pools are addressed by their two mints and fee tier; every PDA, vault and
+// user token account is derived from them. Account order follows each
+// #[derive(Accounts)] struct in the program.
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey,
+    pubkey::Pubkey,
+};
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+
+use super::{
+    pda::{
+        amm_config_address, limit_order_address, lp_mint_address, pool_authority_address,
+        pool_config_address, position_address, program_data_address,
+    },
+    PROGRAM_ID,
+};
+use crate::{
+    codec::DataWriter,
+    nft_staking::MPL_CORE_PROGRAM_ID,
+    token::{associated_token_address, ASSOCIATED_TOKEN_PROGRAM_ID},
+};
+
+pub const INSTRUCTIONS_SYSVAR_ID: Pubkey = pubkey!("Sysvar1nstructions1111111111111111111111111");
+
+// Addresses shared by the liquidity and swap instructions
+struct PoolAccounts {
+    pool_config: Pubkey,
+    pool_authority: Pubkey,
+    lp_token_mint: Pubkey,
+    token_a_vault: Pubkey,
+    token_b_vault: Pubkey,
+}
+
+impl PoolAccounts {
+    fn derive(
+        token_a_mint: &Pubkey,
+        token_b_mint: &Pubkey,
+        fee_tier: u16,
+        token_program: &Pubkey,
+    ) -> Self {
+        let (pool_config, _) = pool_config_address(token_a_mint, token_b_mint, fee_tier);
+        let (pool_authority, _) = pool_authority_address(&pool_config);
+        let (lp_token_mint, _) = lp_mint_address(&pool_config);
+        Self {
+            pool_config,
+            pool_authority,
+            lp_token_mint,
+            token_a_vault: associated_token_address(&pool_authority, token_a_mint, token_program),
+            token_b_vault: associated_token_address(&pool_authority, token_b_mint, token_program),
+        }
+    }
+}
+
+pub fn initialize_amm_config(
+    admin: &Pubkey,
+    pool_creation_authority: &Pubkey,
+    fee_tiers: &[u16],
+) -> Instruction {
+    let (amm_config, _) = amm_config_address();
+
+    let mut writer = DataWriter::anchor("initialize_amm_config")
+        .pubkey(pool_creation_authority)
+        .u32(fee_tiers.len() as u32);
+    for fee in fee_tiers {
+        writer = writer.u16(*fee);
+    }
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new(amm_config, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+            AccountMeta::new_readonly(program_data_address().0, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: writer.into_vec(),
+    }
+}
+
+pub fn update_amm_config(
+    admin: &Pubkey,
+    new_admin: &Pubkey,
+    pool_creation_authority: &Pubkey,
+    fee_tiers: &[u16],
+) -> Instruction {
+    let (amm_config, _) = amm_config_address();
+
+    let mut writer = DataWriter::anchor("update_amm_config")
+        .pubkey(new_admin)
+        .pubkey(pool_creation_authority)
+        .u32(fee_tiers.len() as u32);
+    for fee in fee_tiers {
+        writer = writer.u16(*fee);
+    }
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new(amm_config, false),
+        ],
+        data: writer.into_vec(),
+    }
+}
+
+pub fn initialize_pool(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_basis_points: u16,
+    referral_fee_basis_points: u16,
+    token_program: &Pubkey,
+) -> Instruction {
+    // The creation fee is the pool's fee tier
+    let pool = PoolAccounts::derive(token_a_mint, token_b_mint, fee_basis_points, token_program);
+    let (amm_config, _) = amm_config_address();
+
+    let data = DataWriter::anchor("initialize_pool")
+        .u16(fee_basis_points)
+        .u16(referral_fee_basis_points)
+        .into_vec();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(amm_config, false),
+            AccountMeta::new_readonly(*token_a_mint, false),
+            AccountMeta::new_readonly(*token_b_mint, false),
+            AccountMeta::new(pool.pool_config, false),
+            AccountMeta::new_readonly(pool.pool_authority, false),
+            AccountMeta::new(pool.lp_token_mint, false),
+            AccountMeta::new(pool.token_a_vault, false),
+            AccountMeta::new(pool.token_b_vault, false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Accounts for deposit_liquidity(_sol), withdraw_liquidity and emergency_withdraw
+fn liquidity_accounts(
+    user: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    token_program: &Pubkey,
+) -> Vec<AccountMeta> {
+    let pool = PoolAccounts::derive(token_a_mint, token_b_mint, fee_tier, token_program);
+
+    vec![
+        AccountMeta::new(*user, true),
+        AccountMeta::new_readonly(pool.pool_config, false),
+        AccountMeta::new_readonly(pool.pool_authority, false),
+        AccountMeta::new(pool.lp_token_mint, false),
+        AccountMeta::new_readonly(*token_a_mint, false),
+        AccountMeta::new_readonly(*token_b_mint, false),
+        AccountMeta::new(
+            associated_token_address(user, token_a_mint, token_program),
+            false,
+        ),
+        AccountMeta::new(
+            associated_token_address(user, token_b_mint, token_program),
+            false,
+        ),
+        AccountMeta::new(
+            associated_token_address(user, &pool.lp_token_mint, token_program),
+            false,
+        ),
+        AccountMeta::new(pool.token_a_vault, false),
+        AccountMeta::new(pool.token_b_vault, false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+    ]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_liquidity(
+    depositor: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    desired_amount_a: u64,
+    desired_amount_b: u64,
+    max_amount_a: u64,
+    max_amount_b: u64,
+    expiration: i64,
+    token_program: &Pubkey,
+) -> Instruction {
+    let data = DataWriter::anchor("deposit_liquidity")
+        .u64(desired_amount_a)
+        .u64(desired_amount_b)
+        .u64(max_amount_a)
+        .u64(max_amount_b)
+        .i64(expiration)
+        .into_vec();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: liquidity_accounts(
+            depositor,
+            token_a_mint,
+            token_b_mint,
+            fee_tier,
+            token_program,
+        ),
+        data,
+    }
+}
+
+// deposit_liquidity accounts; amounts on the wrapped SOL side are lamports
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_liquidity_sol(
+    depositor: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    desired_amount_a: u64,
+    desired_amount_b: u64,
+    max_amount_a: u64,
+    max_amount_b: u64,
+    expiration: i64,
+    token_program: &Pubkey,
+) -> Instruction {
+    let data = DataWriter::anchor("deposit_liquidity_sol")
+        .u64(desired_amount_a)
+        .u64(desired_amount_b)
+        .u64(max_amount_a)
+        .u64(max_amount_b)
+        .i64(expiration)
+        .into_vec();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: liquidity_accounts(
+            depositor,
+            token_a_mint,
+            token_b_mint,
+            fee_tier,
+            token_program,
+        ),
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn withdraw_liquidity(
+    withdrawer: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    lp_tokens_to_burn: u64,
+    min_amount_a: u64,
+    min_amount_b: u64,
+    expiration: i64,
+    token_program: &Pubkey,
+) -> Instruction {
+    let data = DataWriter::anchor("withdraw_liquidity")
+        .u64(lp_tokens_to_burn)
+        .u64(min_amount_a)
+        .u64(min_amount_b)
+        .i64(expiration)
+        .into_vec();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: liquidity_accounts(
+            withdrawer,
+            token_a_mint,
+            token_b_mint,
+            fee_tier,
+            token_program,
+        ),
+        data,
+    }
+}
+
+// Moves LP from the (token_a_mint, token_b_mint, fee_tier) pool to the
+// (dest_token_a_mint, dest_token_b_mint, dest_fee_tier) pool, usually the same
+// pair at another tier. Destination vaults are named by the source mints, so
+// for a reversed-order pool of the same pair they are its B and A vaults
+#[allow(clippy::too_many_arguments)]
+pub fn migrate_liquidity(
+    user: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    dest_token_a_mint: &Pubkey,
+    dest_token_b_mint: &Pubkey,
+    dest_fee_tier: u16,
+    lp_amount: u64,
+    min_lp_out: u64,
+    expiration: i64,
+    token_program: &Pubkey,
+) -> Instruction {
+    let source = PoolAccounts::derive(token_a_mint, token_b_mint, fee_tier, token_program);
+    let dest = PoolAccounts::derive(
+        dest_token_a_mint,
+        dest_token_b_mint,
+        dest_fee_tier,
+        token_program,
+    );
+
+    let data = DataWriter::anchor("migrate_liquidity")
+        .u64(lp_amount)
+        .u64(min_lp_out)
+        .i64(expiration)
+        .into_vec();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(source.pool_config, false),
+            AccountMeta::new_readonly(source.pool_authority, false),
+            AccountMeta::new(source.lp_token_mint, false),
+            AccountMeta::new_readonly(dest.pool_config, false),
+            AccountMeta::new_readonly(dest.pool_authority, false),
+            AccountMeta::new(dest.lp_token_mint, false),
+            AccountMeta::new_readonly(*token_a_mint, false),
+            AccountMeta::new_readonly(*token_b_mint, false),
+            AccountMeta::new(
+                associated_token_address(user, &source.lp_token_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(user, &dest.lp_token_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(user, token_a_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(user, token_b_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(source.token_a_vault, false),
+            AccountMeta::new(source.token_b_vault, false),
+            AccountMeta::new(
+                associated_token_address(&dest.pool_authority, token_a_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(&dest.pool_authority, token_b_mint, token_program),
+                false,
+            ),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Same accounts as withdraw_liquidity; only the LP amount is passed
+pub fn emergency_withdraw(
+    withdrawer: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    lp_tokens_to_burn: u64,
+    token_program: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: liquidity_accounts(
+            withdrawer,
+            token_a_mint,
+            token_b_mint,
+            fee_tier,
+            token_program,
+        ),
+        data: DataWriter::anchor("emergency_withdraw")
+            .u64(lp_tokens_to_burn)
+            .into_vec(),
+    }
+}
+
+// `asset` is a new keypair that must also sign; the NFT is minted to the depositor
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_position(
+    depositor: &Pubkey,
+    asset: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    desired_amount_a: u64,
+    desired_amount_b: u64,
+    max_amount_a: u64,
+    max_amount_b: u64,
+    expiration: i64,
+    uri: &str,
+    token_program: &Pubkey,
+) -> Instruction {
+    let pool = PoolAccounts::derive(token_a_mint, token_b_mint, fee_tier, token_program);
+    let (position, _) = position_address(asset);
+
+    let data = DataWriter::anchor("deposit_position")
+        .u64(desired_amount_a)
+        .u64(desired_amount_b)
+        .u64(max_amount_a)
+        .u64(max_amount_b)
+        .i64(expiration)
+        .string(uri)
+        .into_vec();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*depositor, true),
+            AccountMeta::new(*asset, true),
+            AccountMeta::new(pool.pool_config, false),
+            AccountMeta::new_readonly(pool.pool_authority, false),
+            AccountMeta::new(pool.lp_token_mint, false),
+            AccountMeta::new_readonly(*token_a_mint, false),
+            AccountMeta::new_readonly(*token_b_mint, false),
+            AccountMeta::new(
+                associated_token_address(depositor, token_a_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(depositor, token_b_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(&pool.pool_authority, &pool.lp_token_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(pool.token_a_vault, false),
+            AccountMeta::new(pool.token_b_vault, false),
+            AccountMeta::new(position, false),
+            AccountMeta::new_readonly(MPL_CORE_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Burns the position NFT and its LP tokens; the withdrawer must own `asset`
+#[allow(clippy::too_many_arguments)]
+pub fn withdraw_position(
+    withdrawer: &Pubkey,
+    asset: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    min_amount_a: u64,
+    min_amount_b: u64,
+    expiration: i64,
+    token_program: &Pubkey,
+) -> Instruction {
+    let pool = PoolAccounts::derive(token_a_mint, token_b_mint, fee_tier, token_program);
+    let (position, _) = position_address(asset);
+
+    let data = DataWriter::anchor("withdraw_position")
+        .u64(min_amount_a)
+        .u64(min_amount_b)
+        .i64(expiration)
+        .into_vec();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*withdrawer, true),
+            AccountMeta::new(*asset, false),
+            AccountMeta::new(position, false),
+            AccountMeta::new_readonly(pool.pool_config, false),
+            AccountMeta::new_readonly(pool.pool_authority, false),
+            AccountMeta::new(pool.lp_token_mint, false),
+            AccountMeta::new_readonly(*token_a_mint, false),
+            AccountMeta::new_readonly(*token_b_mint, false),
+            AccountMeta::new(
+                associated_token_address(withdrawer, token_a_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(withdrawer, token_b_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(&pool.pool_authority, &pool.lp_token_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(pool.token_a_vault, false),
+            AccountMeta::new(pool.token_b_vault, false),
+            AccountMeta::new_readonly(MPL_CORE_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Pays the position's accrued fees to `owner`, who must own `asset`
+pub fn claim_lp_fees(
+    owner: &Pubkey,
+    asset: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    token_program: &Pubkey,
+) -> Instruction {
+    let pool = PoolAccounts::derive(token_a_mint, token_b_mint, fee_tier, token_program);
+    let (position, _) = position_address(asset);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new_readonly(*asset, false),
+            AccountMeta::new(position, false),
+            AccountMeta::new(pool.pool_config, false),
+            AccountMeta::new_readonly(pool.pool_authority, false),
+            AccountMeta::new(pool.lp_token_mint, false),
+            AccountMeta::new_readonly(*token_a_mint, false),
+            AccountMeta::new_readonly(*token_b_mint, false),
+            AccountMeta::new(
+                associated_token_address(owner, token_a_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(owner, token_b_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(&pool.pool_authority, &pool.lp_token_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(pool.token_a_vault, false),
+            AccountMeta::new(pool.token_b_vault, false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("claim_lp_fees").into_vec(),
+    }
+}
+
+// Accounts for swap_tokens, swap_tokens_exact_out and swap_sol_for_token
+// referrer_token_account and price_feed are Anchor optional accounts: the program ID means None
+fn swap_accounts(
+    swapper: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    token_program: &Pubkey,
+    referrer_token_account: Option<&Pubkey>,
+    price_feed: Option<&Pubkey>,
+) -> Vec<AccountMeta> {
+    let pool = PoolAccounts::derive(token_a_mint, token_b_mint, fee_tier, token_program);
+    let referrer = match referrer_token_account {
+        Some(referrer) => AccountMeta::new(*referrer, false),
+        None => AccountMeta::new_readonly(PROGRAM_ID, false),
+    };
+
+    vec![
+        AccountMeta::new(*swapper, true),
+        AccountMeta::new(pool.pool_config, false),
+        AccountMeta::new_readonly(pool.pool_authority, false),
+        AccountMeta::new_readonly(*token_a_mint, false),
+        AccountMeta::new_readonly(*token_b_mint, false),
+        AccountMeta::new(
+            associated_token_address(swapper, token_a_mint, token_program),
+            false,
+        ),
+        AccountMeta::new(
+            associated_token_address(swapper, token_b_mint, token_program),
+            false,
+        ),
+        AccountMeta::new(pool.token_a_vault, false),
+        AccountMeta::new(pool.token_b_vault, false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        referrer,
+        AccountMeta::new_readonly(*price_feed.unwrap_or(&PROGRAM_ID), false),
+    ]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn swap_tokens(
+    swapper: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    swap_token_a_for_b: bool,
+    input_amount: u64,
+    min_output_amount: u64,
+    expiration: i64,
+    token_program: &Pubkey,
+    referrer_token_account: Option<&Pubkey>,
+    price_feed: Option<&Pubkey>,
+) -> Instruction {
+    let data = DataWriter::anchor("swap_tokens")
+        .bool(swap_token_a_for_b)
+        .u64(input_amount)
+        .u64(min_output_amount)
+        .i64(expiration)
+        .into_vec();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: swap_accounts(
+            swapper,
+            token_a_mint,
+            token_b_mint,
+            fee_tier,
+            token_program,
+            referrer_token_account,
+            price_feed,
+        ),
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn swap_tokens_exact_out(
+    swapper: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    swap_token_a_for_b: bool,
+    max_input_amount: u64,
+    output_amount: u64,
+    expiration: i64,
+    token_program: &Pubkey,
+    referrer_token_account: Option<&Pubkey>,
+    price_feed: Option<&Pubkey>,
+) -> Instruction {
+    let data = DataWriter::anchor("swap_tokens_exact_out")
+        .bool(swap_token_a_for_b)
+        .u64(max_input_amount)
+        .u64(output_amount)
+        .i64(expiration)
+        .into_vec();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: swap_accounts(
+            swapper,
+            token_a_mint,
+            token_b_mint,
+            fee_tier,
+            token_program,
+            referrer_token_account,
+            price_feed,
+        ),
+        data,
+    }
+}
+
+// swap_tokens accounts; input_amount is lamports, swapped out of the wrapped SOL side
+#[allow(clippy::too_many_arguments)]
+pub fn swap_sol_for_token(
+    swapper: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    input_amount: u64,
+    min_output_amount: u64,
+    expiration: i64,
+    token_program: &Pubkey,
+    referrer_token_account: Option<&Pubkey>,
+    price_feed: Option<&Pubkey>,
+) -> Instruction {
+    let data = DataWriter::anchor("swap_sol_for_token")
+        .u64(input_amount)
+        .u64(min_output_amount)
+        .i64(expiration)
+        .into_vec();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: swap_accounts(
+            swapper,
+            token_a_mint,
+            token_b_mint,
+            fee_tier,
+            token_program,
+            referrer_token_account,
+            price_feed,
+        ),
+        data,
+    }
+}
+
+// swap_tokens accounts followed by the LP mint and the swapper's LP token ATA,
+// whose balance decides the rebate
+#[allow(clippy::too_many_arguments)]
+pub fn swap_with_rebate(
+    swapper: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    swap_token_a_for_b: bool,
+    input_amount: u64,
+    min_output_amount: u64,
+    expiration: i64,
+    token_program: &Pubkey,
+    referrer_token_account: Option<&Pubkey>,
+    price_feed: Option<&Pubkey>,
+) -> Instruction {
+    let data = DataWriter::anchor("swap_with_rebate")
+        .bool(swap_token_a_for_b)
+        .u64(input_amount)
+        .u64(min_output_amount)
+        .i64(expiration)
+        .into_vec();
+
+    let pool = PoolAccounts::derive(token_a_mint, token_b_mint, fee_tier, token_program);
+    let mut accounts = swap_accounts(
+        swapper,
+        token_a_mint,
+        token_b_mint,
+        fee_tier,
+        token_program,
+        referrer_token_account,
+        price_feed,
+    );
+    accounts.push(AccountMeta::new_readonly(pool.lp_token_mint, false));
+    accounts.push(AccountMeta::new_readonly(
+        associated_token_address(swapper, &pool.lp_token_mint, token_program),
+        false,
+    ));
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data,
+    }
+}
+
+// Pool authority instructions: [authority (signer), pool_config (mut)]
+fn pool_admin_instruction(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    data: Vec<u8>,
+) -> Instruction {
+    let (pool_config, _) = pool_config_address(token_a_mint, token_b_mint, fee_tier);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(pool_config, false),
+        ],
+        data,
+    }
+}
+
+pub fn lock_pool(authority: &Pubkey, token_a_mint: &Pubkey, token_b_mint: &Pubkey) -> Instruction {
+    pool_admin_instruction(
+        authority,
+        token_a_mint,
+        token_b_mint,
+        fee_tier,
+        DataWriter::anchor("lock_pool").into_vec(),
+    )
+}
+
+pub fn unlock_pool(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+) -> Instruction {
+    pool_admin_instruction(
+        authority,
+        token_a_mint,
+        token_b_mint,
+        fee_tier,
+        DataWriter::anchor("unlock_pool").into_vec(),
+    )
+}
+
+pub fn request_fee_change(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    new_fee_basis_points: u16,
+) -> Instruction {
+    fee_change_instruction(
+        authority,
+        token_a_mint,
+        token_b_mint,
+        fee_tier,
+        DataWriter::anchor("request_fee_change")
+            .u16(new_fee_basis_points)
+            .into_vec(),
+    )
+}
+
+pub fn change_pool_fee(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+) -> Instruction {
+    fee_change_instruction(
+        authority,
+        token_a_mint,
+        token_b_mint,
+        fee_tier,
+        DataWriter::anchor("change_pool_fee").into_vec(),
+    )
+}
+
+// Fee changes also read the global config to enforce its fee tiers
+fn fee_change_instruction(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    data: Vec<u8>,
+) -> Instruction {
+    let mut instruction =
+        pool_admin_instruction(authority, token_a_mint, token_b_mint, fee_tier, data);
+    let (amm_config, _) = amm_config_address();
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(amm_config, false));
+    instruction
+}
+
+pub fn set_emergency_withdraw(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    enabled: bool,
+) -> Instruction {
+    pool_admin_instruction(
+        authority,
+        token_a_mint,
+        token_b_mint,
+        fee_tier,
+        DataWriter::anchor("set_emergency_withdraw")
+            .bool(enabled)
+            .into_vec(),
+    )
+}
+
+// A max_oracle_deviation_bps of 0 disables the guard
+pub fn set_oracle_guard(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    oracle_program: &Pubkey,
+    price_feed: &Pubkey,
+    max_oracle_deviation_bps: u16,
+) -> Instruction {
+    pool_admin_instruction(
+        authority,
+        token_a_mint,
+        token_b_mint,
+        fee_tier,
+        DataWriter::anchor("set_oracle_guard")
+            .pubkey(oracle_program)
+            .pubkey(price_feed)
+            .u16(max_oracle_deviation_bps)
+            .into_vec(),
+    )
+}
+
+// Caps the pool price move of a single swap; auto_lock locks the pool on a breach
+// A max_price_impact_bps of 0 disables the circuit breaker
+pub fn set_circuit_breaker(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    max_price_impact_bps: u16,
+    auto_lock: bool,
+) -> Instruction {
+    pool_admin_instruction(
+        authority,
+        token_a_mint,
+        token_b_mint,
+        fee_tier,
+        DataWriter::anchor("set_circuit_breaker")
+            .u16(max_price_impact_bps)
+            .bool(auto_lock)
+            .into_vec(),
+    )
+}
+
+// 0 disables the per-slot swap limit
+pub fn set_swap_limit(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    max_swap_per_slot_bps: u16,
+) -> Instruction {
+    pool_admin_instruction(
+        authority,
+        token_a_mint,
+        token_b_mint,
+        fee_tier,
+        DataWriter::anchor("set_swap_limit")
+            .u16(max_swap_per_slot_bps)
+            .into_vec(),
+    )
+}
+
+// Borrows `amount` of borrow_mint from the pool vault into borrower_token_account
+// A flash_loan_end for the same pool must follow in the same transaction
+pub fn flash_loan_begin(
+    borrower: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    borrow_mint: &Pubkey,
+    borrower_token_account: &Pubkey,
+    amount: u64,
+    token_program: &Pubkey,
+) -> Instruction {
+    let (pool_config, _) = pool_config_address(token_a_mint, token_b_mint, fee_tier);
+    let (pool_authority, _) = pool_authority_address(&pool_config);
+    let token_vault = associated_token_address(&pool_authority, borrow_mint, token_program);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*borrower, true),
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new_readonly(pool_authority, false),
+            AccountMeta::new_readonly(*borrow_mint, false),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new(*borrower_token_account, false),
+            AccountMeta::new_readonly(INSTRUCTIONS_SYSVAR_ID, false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        data: DataWriter::anchor("flash_loan_begin")
+            .u64(amount)
+            .into_vec(),
+    }
+}
+
+// pool_config must stay the first account - flash_loan_begin looks for it there
+pub fn flash_loan_end(
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    borrow_mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let (pool_config, _) = pool_config_address(token_a_mint, token_b_mint, fee_tier);
+    let (pool_authority, _) = pool_authority_address(&pool_config);
+    let token_vault = associated_token_address(&pool_authority, borrow_mint, token_program);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new_readonly(pool_authority, false),
+            AccountMeta::new_readonly(*borrow_mint, false),
+            AccountMeta::new_readonly(token_vault, false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        data: DataWriter::anchor("flash_loan_end").into_vec(),
+    }
+}
+
+// (input_mint, output_mint) of a limit order in the given direction
+fn order_mints<'a>(
+    token_a_mint: &'a Pubkey,
+    token_b_mint: &'a Pubkey,
+    swap_token_a_for_b: bool,
+) -> (&'a Pubkey, &'a Pubkey) {
+    if swap_token_a_for_b {
+        (token_a_mint, token_b_mint)
+    } else {
+        (token_b_mint, token_a_mint)
+    }
+}
+
+// Escrows input_amount of the input side; keeper_tip is paid in lamports to the filler
+#[allow(clippy::too_many_arguments)]
+pub fn place_limit_order(
+    owner: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    swap_token_a_for_b: bool,
+    order_id: u64,
+    input_amount: u64,
+    min_output_amount: u64,
+    keeper_tip: u64,
+    expires_at: i64,
+    token_program: &Pubkey,
+) -> Instruction {
+    let (pool_config, _) = pool_config_address(token_a_mint, token_b_mint, fee_tier);
+    let (limit_order, _) = limit_order_address(&pool_config, owner, order_id);
+    let (input_mint, output_mint) = order_mints(token_a_mint, token_b_mint, swap_token_a_for_b);
+
+    let data = DataWriter::anchor("place_limit_order")
+        .u64(order_id)
+        .u64(input_amount)
+        .u64(min_output_amount)
+        .u64(keeper_tip)
+        .i64(expires_at)
+        .into_vec();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new_readonly(pool_config, false),
+            AccountMeta::new_readonly(*input_mint, false),
+            AccountMeta::new_readonly(*output_mint, false),
+            AccountMeta::new(limit_order, false),
+            AccountMeta::new(
+                associated_token_address(&limit_order, input_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(owner, input_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(owner, output_mint, token_program),
+                false,
+            ),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Refunds the escrow and the keeper tip; only the owner can sign
+pub fn cancel_limit_order(
+    owner: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    swap_token_a_for_b: bool,
+    order_id: u64,
+    token_program: &Pubkey,
+) -> Instruction {
+    let (pool_config, _) = pool_config_address(token_a_mint, token_b_mint, fee_tier);
+    let (limit_order, _) = limit_order_address(&pool_config, owner, order_id);
+    let (input_mint, _) = order_mints(token_a_mint, token_b_mint, swap_token_a_for_b);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new_readonly(*input_mint, false),
+            AccountMeta::new(limit_order, false),
+            AccountMeta::new(
+                associated_token_address(&limit_order, input_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(owner, input_mint, token_program),
+                false,
+            ),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("cancel_limit_order").into_vec(),
+    }
+}
+
+// Permissionless; the keeper signs and receives the tip, the owner receives the output
+// price_feed is an Anchor optional account: the program ID means None
+#[allow(clippy::too_many_arguments)]
+pub fn fill_limit_order(
+    keeper: &Pubkey,
+    owner: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    swap_token_a_for_b: bool,
+    order_id: u64,
+    token_program: &Pubkey,
+    price_feed: Option<&Pubkey>,
+) -> Instruction {
+    let pool = PoolAccounts::derive(token_a_mint, token_b_mint, fee_tier, token_program);
+    let (limit_order, _) = limit_order_address(&pool.pool_config, owner, order_id);
+    let (input_mint, output_mint) = order_mints(token_a_mint, token_b_mint, swap_token_a_for_b);
+    let (input_vault, output_vault) = if swap_token_a_for_b {
+        (pool.token_a_vault, pool.token_b_vault)
+    } else {
+        (pool.token_b_vault, pool.token_a_vault)
+    };
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*keeper, true),
+            AccountMeta::new(*owner, false),
+            AccountMeta::new(pool.pool_config, false),
+            AccountMeta::new_readonly(pool.pool_authority, false),
+            AccountMeta::new_readonly(*input_mint, false),
+            AccountMeta::new_readonly(*output_mint, false),
+            AccountMeta::new(limit_order, false),
+            AccountMeta::new(
+                associated_token_address(&limit_order, input_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(owner, output_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(input_vault, false),
+            AccountMeta::new(output_vault, false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*price_feed.unwrap_or(&PROGRAM_ID), false),
+        ],
+        data: DataWriter::anchor("fill_limit_order").into_vec(),
+    }
+}
@@ -0,0 +1,28 @@
+// AMM (amm-secure)
+//
+// Constant-product pools with LP tokens, slippage/deadline-protected swaps,
+// flash loans, timelocked fee changes and an emergency withdraw switch.
+// deposit_position/withdraw_position hold liquidity as Metaplex Core NFTs.
+// Every token instruction accepts SPL Token or Token-2022 via token_program.
+
+use solana_sdk::{pubkey, pubkey::Pubkey};
+
+pub mod accounts;
+pub mod instructions;
+pub mod pda;
+
+pub use accounts::*;
+pub use pda::*;
+
+pub const PROGRAM_ID: Pubkey = pubkey!("FeLNaVGuQZWizZMd2hfy4MaXoko3kLC5q675q5EE5KaC");
+
+// Flash loan fee charged by flash_loan_end (FLASH_LOAN_FEE_BASIS_POINTS)
+pub const FLASH_LOAN_FEE_BASIS_POINTS: u64 = 9;
+
+// Slots of swap volume kept on PoolConfig (SWAP_VOLUME_RING_SIZE)
+pub const SWAP_VOLUME_RING_SIZE: usize = 4;
+
+// Fee owed on a flash loan of `amount`, rounded up like the program
+pub fn flash_loan_fee(amount: u64) -> u64 {
+    ((amount as u128 * FLASH_LOAN_FEE_BASIS_POINTS as u128).div_ceil(10_000)) as u64
+}
@@ -0,0 +1,110 @@
+// AMM PDAs
+//
+// Seeds match constants.rs:
+// - amm config: ["amm_global_config"]
+// - program data: [PROGRAM_ID] under the upgradeable loader
+// - pool config: ["amm_config", token_a_mint, token_b_mint, fee_tier (u16 LE)]
+// - pool authority: ["amm_authority", pool_config]
+// - LP mint: ["lp_mint", pool_config]
+// - position: ["position", position_asset]
+// - limit order: ["limit_order", pool_config, owner, order_id (u64 LE)]
+// Pool vaults are the pool authority's associated token accounts.
+
+use solana_sdk::pubkey::Pubkey;
+
+use super::PROGRAM_ID;
+use crate::token::associated_token_address;
+
+pub const AMM_GLOBAL_CONFIG_SEED: &[u8] = b"amm_global_config";
+pub const AMM_CONFIG_SEED: &[u8] = b"amm_config";
+pub const AMM_AUTHORITY_SEED: &[u8] = b"amm_authority";
+pub const LP_MINT_SEED: &[u8] = b"lp_mint";
+pub const POSITION_SEED: &[u8] = b"position";
+pub const LIMIT_ORDER_SEED: &[u8] = b"limit_order";
+
+pub const BPF_LOADER_UPGRADEABLE_ID: Pubkey =
+    solana_sdk::pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+pub fn amm_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AMM_GLOBAL_CONFIG_SEED], &PROGRAM_ID)
+}
+
+// ProgramData account holding the upgrade authority initialize_amm_config checks
+pub fn program_data_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROGRAM_ID.as_ref()], &BPF_LOADER_UPGRADEABLE_ID)
+}
+
+pub fn pool_config_address(
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            AMM_CONFIG_SEED,
+            token_a_mint.as_ref(),
+            token_b_mint.as_ref(),
+            &fee_tier.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    )
+}
+
+pub fn pool_authority_address(pool_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AMM_AUTHORITY_SEED, pool_config.as_ref()], &PROGRAM_ID)
+}
+
+pub fn lp_mint_address(pool_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LP_MINT_SEED, pool_config.as_ref()], &PROGRAM_ID)
+}
+
+pub fn position_address(asset: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POSITION_SEED, asset.as_ref()], &PROGRAM_ID)
+}
+
+pub fn limit_order_address(pool_config: &Pubkey, owner: &Pubkey, order_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            LIMIT_ORDER_SEED,
+            pool_config.as_ref(),
+            owner.as_ref(),
+            &order_id.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    )
+}
+
+// Pool vault holding `mint` for the (token_a_mint, token_b_mint, fee_tier) pool
+pub fn vault_address(
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Pubkey {
+    let (pool_config, _) = pool_config_address(token_a_mint, token_b_mint, fee_tier);
+    let (pool_authority, _) = pool_authority_address(&pool_config);
+    associated_token_address(&pool_authority, mint, token_program)
+}
+
+// Pool authority's LP token account holding the LP tokens of every NFT position
+pub fn position_lp_vault_address(
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+    token_program: &Pubkey,
+) -> Pubkey {
+    let (pool_config, _) = pool_config_address(token_a_mint, token_b_mint, fee_tier);
+    let (pool_authority, _) = pool_authority_address(&pool_config);
+    let (lp_mint, _) = lp_mint_address(&pool_config);
+    associated_token_address(&pool_authority, &lp_mint, token_program)
+}
+
+// Limit order's associated token account escrowing its input tokens
+pub fn order_escrow_address(
+    limit_order: &Pubkey,
+    input_mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Pubkey {
+    associated_token_address(limit_order, input_mint, token_program)
+}
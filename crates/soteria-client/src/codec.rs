@@ -0,0 +1,231 @@
+// Encoding and Decoding
+//
+// Anchor programs serialize arguments and accounts with Borsh behind an
+// 8-byte discriminator. The Pinocchio escrow uses a 1-byte discriminator and
+// #[repr(C)] layouts. Both are handled with the same little-endian reader
+// and writer so builders and decoders never index raw offsets.
+
+use sha2::{Digest, Sha256};
+use solana_sdk::pubkey::Pubkey;
+use std::fmt;
+
+// First 8 bytes of sha256("<namespace>:<name>")
+fn sighash(namespace: &str, name: &str) -> [u8; 8] {
+    let preimage = format!("{}:{}", namespace, name);
+    let hash = Sha256::digest(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash[..8]);
+    discriminator
+}
+
+// Anchor instruction discriminator: sha256("global:<method_name>")[..8]
+pub fn instruction_discriminator(method: &str) -> [u8; 8] {
+    sighash("global", method)
+}
+
+// Anchor account discriminator: sha256("account:<AccountName>")[..8]
+pub fn account_discriminator(account_name: &str) -> [u8; 8] {
+    sighash("account", account_name)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    // Data does not start with the expected account discriminator
+    DiscriminatorMismatch { expected: &'static str },
+    // Data ended before every field was read
+    UnexpectedEnd,
+    // Enum tag outside the known variants
+    InvalidEnumTag { name: &'static str, tag: u8 },
+    // String field is not valid UTF-8
+    InvalidUtf8,
+    // Fixed-size account has the wrong length
+    InvalidLength { expected: usize, actual: usize },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::DiscriminatorMismatch { expected } => {
+                write!(f, "account data is not a {}", expected)
+            }
+            DecodeError::UnexpectedEnd => write!(f, "account data ended early"),
+            DecodeError::InvalidEnumTag { name, tag } => {
+                write!(f, "invalid {} tag {}", name, tag)
+            }
+            DecodeError::InvalidUtf8 => write!(f, "string field is not valid UTF-8"),
+            DecodeError::InvalidLength { expected, actual } => {
+                write!(
+                    f,
+                    "expected {} bytes of account data, got {}",
+                    expected, actual
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+// Little-endian writer for instruction data
+pub(crate) struct DataWriter {
+    data: Vec<u8>,
+}
+
+impl DataWriter {
+    // Start Anchor instruction data with its 8-byte discriminator
+    pub(crate) fn anchor(method: &str) -> Self {
+        Self {
+            data: instruction_discriminator(method).to_vec(),
+        }
+    }
+
+    // Start instruction data with a single discriminator byte
+    pub(crate) fn tagged(tag: u8) -> Self {
+        Self { data: vec![tag] }
+    }
+
+    // Start with no prefix, for bytes that are hashed rather than sent
+    pub(crate) fn empty() -> Self {
+        Self { data: Vec::new() }
+    }
+
+    pub(crate) fn u8(mut self, value: u8) -> Self {
+        self.data.push(value);
+        self
+    }
+
+    pub(crate) fn bool(self, value: bool) -> Self {
+        self.u8(value as u8)
+    }
+
+    pub(crate) fn u16(mut self, value: u16) -> Self {
+        self.data.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    pub(crate) fn u32(mut self, value: u32) -> Self {
+        self.data.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    pub(crate) fn u64(mut self, value: u64) -> Self {
+        self.data.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    pub(crate) fn i64(mut self, value: i64) -> Self {
+        self.data.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    pub(crate) fn bytes(mut self, value: &[u8]) -> Self {
+        self.data.extend_from_slice(value);
+        self
+    }
+
+    pub(crate) fn pubkey(self, value: &Pubkey) -> Self {
+        self.bytes(value.as_ref())
+    }
+
+    // Borsh String: u32 length prefix followed by UTF-8 bytes
+    pub(crate) fn string(self, value: &str) -> Self {
+        self.u32(value.len() as u32).bytes(value.as_bytes())
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+// Little-endian reader for account data
+pub(crate) struct DataReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> DataReader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    // Check the Anchor account discriminator and position after it
+    pub(crate) fn anchor(data: &'a [u8], account_name: &'static str) -> Result<Self, DecodeError> {
+        let mut reader = Self::new(data);
+        let discriminator = reader.take(8)?;
+        if discriminator != account_discriminator(account_name) {
+            return Err(DecodeError::DiscriminatorMismatch {
+                expected: account_name,
+            });
+        }
+        Ok(reader)
+    }
+
+    pub(crate) fn take(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        if self.data.len() < len {
+            return Err(DecodeError::UnexpectedEnd);
+        }
+        let (head, tail) = self.data.split_at(len);
+        self.data = tail;
+        Ok(head)
+    }
+
+    pub(crate) fn array<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        let mut out = [0u8; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    pub(crate) fn u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub(crate) fn bool(&mut self) -> Result<bool, DecodeError> {
+        Ok(self.u8()? != 0)
+    }
+
+    pub(crate) fn u16(&mut self) -> Result<u16, DecodeError> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<u32, DecodeError> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    pub(crate) fn u64(&mut self) -> Result<u64, DecodeError> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    pub(crate) fn u128(&mut self) -> Result<u128, DecodeError> {
+        Ok(u128::from_le_bytes(self.array()?))
+    }
+
+    pub(crate) fn i32(&mut self) -> Result<i32, DecodeError> {
+        Ok(i32::from_le_bytes(self.array()?))
+    }
+
+    pub(crate) fn i64(&mut self) -> Result<i64, DecodeError> {
+        Ok(i64::from_le_bytes(self.array()?))
+    }
+
+    pub(crate) fn pubkey(&mut self) -> Result<Pubkey, DecodeError> {
+        Ok(Pubkey::new_from_array(self.array()?))
+    }
+
+    pub(crate) fn string(&mut self) -> Result<String, DecodeError> {
+        let len = self.u32()? as usize;
+        let bytes = self.take(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| DecodeError::InvalidUtf8)
+    }
+
+    // Borsh Vec<T>: u32 length prefix followed by each element
+    pub(crate) fn vec<T>(
+        &mut self,
+        mut read: impl FnMut(&mut Self) -> Result<T, DecodeError>,
+    ) -> Result<Vec<T>, DecodeError> {
+        let len = self.u32()? as usize;
+        let mut items = Vec::with_capacity(len.min(self.data.len()));
+        for _ in 0..len {
+            items.push(read(self)?);
+        }
+        Ok(items)
+    }
+}
@@ -0,0 +1,106 @@
+// Escrow Accounts
+//
+// MakeState and FeeConfig are #[repr(C)] with no discriminator, so they are
+// decoded by fixed offsets and the length must match size_of exactly.
+
+use solana_sdk::pubkey::Pubkey;
+
+use crate::codec::{DataReader, DecodeError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MakeState {
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub token_b_wanted_amount: u64,
+    pub token_a_offered_amount: u64,
+    pub expires_at: i64,
+    pub allowed_taker: Pubkey,
+    pub arbiter: Pubkey,
+    pub dispute_window: i64,
+    pub dispute_deadline: i64,
+    pub bump: u8,
+    pub is_initialized: bool,
+}
+
+impl MakeState {
+    // 210 bytes of fields padded to 8-byte alignment
+    pub const LEN: usize = 216;
+
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        if data.len() != Self::LEN {
+            return Err(DecodeError::InvalidLength {
+                expected: Self::LEN,
+                actual: data.len(),
+            });
+        }
+
+        let mut reader = DataReader::new(data);
+        Ok(Self {
+            id: reader.u64()?,
+            proposer: reader.pubkey()?,
+            token_mint_a: reader.pubkey()?,
+            token_mint_b: reader.pubkey()?,
+            token_b_wanted_amount: reader.u64()?,
+            token_a_offered_amount: reader.u64()?,
+            expires_at: reader.i64()?,
+            allowed_taker: reader.pubkey()?,
+            arbiter: reader.pubkey()?,
+            dispute_window: reader.i64()?,
+            dispute_deadline: reader.i64()?,
+            bump: reader.u8()?,
+            is_initialized: reader.bool()?,
+        })
+    }
+
+    // All-zero allowed_taker means anyone can take the offer
+    pub fn is_public(&self) -> bool {
+        self.allowed_taker == Pubkey::default()
+    }
+
+    // All-zero arbiter means the offer cannot be disputed
+    pub fn has_arbiter(&self) -> bool {
+        self.arbiter != Pubkey::default()
+    }
+
+    // A dispute was opened and its window has not lapsed
+    pub fn is_disputed(&self, now: i64) -> bool {
+        self.dispute_deadline != 0 && now < self.dispute_deadline
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeConfig {
+    pub authority: Pubkey,
+    pub fee_bps: u16,
+    pub bump: u8,
+    pub is_initialized: bool,
+}
+
+impl FeeConfig {
+    // 32 + 2 + 1 + 1, no padding needed
+    pub const LEN: usize = 36;
+
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        if data.len() != Self::LEN {
+            return Err(DecodeError::InvalidLength {
+                expected: Self::LEN,
+                actual: data.len(),
+            });
+        }
+
+        let mut reader = DataReader::new(data);
+        Ok(Self {
+            authority: reader.pubkey()?,
+            fee_bps: reader.u16()?,
+            bump: reader.u8()?,
+            is_initialized: reader.bool()?,
+        })
+    }
+
+    // Fee on a Token B payment, rounded down like the program
+    pub fn fee_for(&self, amount: u64) -> u64 {
+        (amount as u128 * self.fee_bps as u128 / 10_000) as u64
+    }
+}
@@ -0,0 +1,498 @@
+// Escrow Instructions
+//
+// Discriminators match the Instruction enum in instructions/mod.rs.
+// The *_data functions are exposed separately so callers can pair correct
+// data with hand-picked accounts (e.g. to probe the program's checks).
+// Pass Pubkey::default() as allowed_taker for a public offer.
+// fee_bps is in basis points of the Token B payment (10_000 = 100%).
+// An arbiter can only be named on a private offer (non-default allowed_taker).
+// ProposeOffer data carries a version byte after the discriminator; the
+// builders emit v2, and propose_offer_v1_data the original layout.
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+
+use super::{
+    pda::{fee_config_address, fee_vault_address, offer_address, vault_address},
+    PROGRAM_ID,
+};
+use crate::{
+    codec::DataWriter,
+    token::{associated_token_address, ASSOCIATED_TOKEN_PROGRAM_ID},
+};
+
+pub const PROPOSE_OFFER: u8 = 0;
+pub const TAKE_OFFER: u8 = 1;
+pub const REFUND_OFFER: u8 = 2;
+pub const REAP_EXPIRED_OFFER: u8 = 3;
+pub const PROPOSE_OFFER_BATCH: u8 = 4;
+pub const SET_FEE_CONFIG: u8 = 5;
+pub const TAKE_OFFER_WITH_FEE: u8 = 6;
+pub const OPEN_DISPUTE: u8 = 7;
+pub const RESOLVE_DISPUTE: u8 = 8;
+pub const SWEEP_DUST: u8 = 9;
+
+// ProposeOffer data versions (PROPOSE_OFFER_V1 / PROPOSE_OFFER_V2 in parser.rs)
+pub const PROPOSE_OFFER_V1: u8 = 1;
+pub const PROPOSE_OFFER_V2: u8 = 2;
+// size_of::<ProposalOfferData>() - repr(C) pads bump out to 8-byte alignment
+pub const PROPOSE_OFFER_DATA_LEN: usize = 72;
+// size_of::<ProposalOfferDataV1>()
+pub const PROPOSE_OFFER_V1_DATA_LEN: usize = 32;
+// size_of::<ProposeOfferBatchHeader>() and size_of::<BatchOfferEntry>()
+pub const BATCH_HEADER_LEN: usize = 56;
+pub const BATCH_ENTRY_LEN: usize = 16;
+// size_of::<ArbiterConfig>(), optionally appended to ProposeOffer data
+pub const ARBITER_CONFIG_LEN: usize = 40;
+
+// Side of the deal ResolveDispute awards the vault to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisputeAward {
+    Maker = 0,
+    Taker = 1,
+}
+
+// [discriminator][version: 2][id: 8][token_b_wanted: u64][token_a_offered: u64]
+// [expires_at: i64][allowed_taker: 32][bump: u8][padding: 7]
+pub fn propose_offer_data(
+    offer_id: u64,
+    token_b_wanted_amount: u64,
+    token_a_offered_amount: u64,
+    expires_at: i64,
+    allowed_taker: &Pubkey,
+    bump: u8,
+) -> Vec<u8> {
+    DataWriter::tagged(PROPOSE_OFFER)
+        .u8(PROPOSE_OFFER_V2)
+        .u64(offer_id)
+        .u64(token_b_wanted_amount)
+        .u64(token_a_offered_amount)
+        .i64(expires_at)
+        .pubkey(allowed_taker)
+        .u8(bump)
+        .bytes(&[0u8; 7])
+        .into_vec()
+}
+
+// [discriminator][version: 1][id: 8][token_b_wanted: u64][token_a_offered: u64]
+// [bump: u8][padding: 7]
+// Public offer that never expires
+pub fn propose_offer_v1_data(
+    offer_id: u64,
+    token_b_wanted_amount: u64,
+    token_a_offered_amount: u64,
+    bump: u8,
+) -> Vec<u8> {
+    DataWriter::tagged(PROPOSE_OFFER)
+        .u8(PROPOSE_OFFER_V1)
+        .u64(offer_id)
+        .u64(token_b_wanted_amount)
+        .u64(token_a_offered_amount)
+        .u8(bump)
+        .bytes(&[0u8; 7])
+        .into_vec()
+}
+
+// v2 ProposeOffer data followed by [arbiter: 32][dispute_window: i64]
+#[allow(clippy::too_many_arguments)]
+pub fn propose_offer_with_arbiter_data(
+    offer_id: u64,
+    token_b_wanted_amount: u64,
+    token_a_offered_amount: u64,
+    expires_at: i64,
+    allowed_taker: &Pubkey,
+    bump: u8,
+    arbiter: &Pubkey,
+    dispute_window: i64,
+) -> Vec<u8> {
+    let mut data = propose_offer_data(
+        offer_id,
+        token_b_wanted_amount,
+        token_a_offered_amount,
+        expires_at,
+        allowed_taker,
+        bump,
+    );
+    data.extend(
+        DataWriter::empty()
+            .pubkey(arbiter)
+            .i64(dispute_window)
+            .into_vec(),
+    );
+    data
+}
+
+// [discriminator][first_id: 8][expires_at: i64][allowed_taker: 32][count: u8][padding: 7]
+// followed by (token_b_wanted, token_a_offered) per offer
+pub fn propose_offer_batch_data(
+    first_id: u64,
+    expires_at: i64,
+    allowed_taker: &Pubkey,
+    entries: &[(u64, u64)],
+) -> Vec<u8> {
+    let mut writer = DataWriter::tagged(PROPOSE_OFFER_BATCH)
+        .u64(first_id)
+        .i64(expires_at)
+        .pubkey(allowed_taker)
+        .u8(entries.len() as u8)
+        .bytes(&[0u8; 7]);
+    for (token_b_wanted_amount, token_a_offered_amount) in entries {
+        writer = writer
+            .u64(*token_b_wanted_amount)
+            .u64(*token_a_offered_amount);
+    }
+    writer.into_vec()
+}
+
+// [discriminator][fee_bps: u16]
+pub fn set_fee_config_data(fee_bps: u16) -> Vec<u8> {
+    DataWriter::tagged(SET_FEE_CONFIG).u16(fee_bps).into_vec()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn propose_offer(
+    maker: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+    offer_id: u64,
+    token_b_wanted_amount: u64,
+    token_a_offered_amount: u64,
+    expires_at: i64,
+    allowed_taker: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let (offer, bump) = offer_address(maker, offer_id);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*maker, true),
+            AccountMeta::new_readonly(*token_mint_a, false),
+            AccountMeta::new_readonly(*token_mint_b, false),
+            AccountMeta::new(
+                associated_token_address(maker, token_mint_a, token_program),
+                false,
+            ),
+            AccountMeta::new(offer, false),
+            AccountMeta::new(vault_address(&offer, token_mint_a, token_program), false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+        ],
+        data: propose_offer_data(
+            offer_id,
+            token_b_wanted_amount,
+            token_a_offered_amount,
+            expires_at,
+            allowed_taker,
+            bump,
+        ),
+    }
+}
+
+// Same accounts as propose_offer; the arbiter settles disputes within dispute_window seconds
+#[allow(clippy::too_many_arguments)]
+pub fn propose_offer_with_arbiter(
+    maker: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+    offer_id: u64,
+    token_b_wanted_amount: u64,
+    token_a_offered_amount: u64,
+    expires_at: i64,
+    allowed_taker: &Pubkey,
+    arbiter: &Pubkey,
+    dispute_window: i64,
+    token_program: &Pubkey,
+) -> Instruction {
+    let mut ix = propose_offer(
+        maker,
+        token_mint_a,
+        token_mint_b,
+        offer_id,
+        token_b_wanted_amount,
+        token_a_offered_amount,
+        expires_at,
+        allowed_taker,
+        token_program,
+    );
+    let (_, bump) = offer_address(maker, offer_id);
+
+    ix.data = propose_offer_with_arbiter_data(
+        offer_id,
+        token_b_wanted_amount,
+        token_a_offered_amount,
+        expires_at,
+        allowed_taker,
+        bump,
+        arbiter,
+        dispute_window,
+    );
+    ix
+}
+
+// Creates offers first_id, first_id + 1, ... - one per (token_b_wanted, token_a_offered) entry
+#[allow(clippy::too_many_arguments)]
+pub fn propose_offer_batch(
+    maker: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+    first_id: u64,
+    expires_at: i64,
+    allowed_taker: &Pubkey,
+    entries: &[(u64, u64)],
+    token_program: &Pubkey,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*maker, true),
+        AccountMeta::new_readonly(*token_mint_a, false),
+        AccountMeta::new_readonly(*token_mint_b, false),
+        AccountMeta::new(
+            associated_token_address(maker, token_mint_a, token_program),
+            false,
+        ),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+    ];
+    for index in 0..entries.len() as u64 {
+        let (offer, _) = offer_address(maker, first_id + index);
+        accounts.push(AccountMeta::new(offer, false));
+        accounts.push(AccountMeta::new(
+            vault_address(&offer, token_mint_a, token_program),
+            false,
+        ));
+    }
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data: propose_offer_batch_data(first_id, expires_at, allowed_taker, entries),
+    }
+}
+
+// The proposer's Token B account is created by the program if missing
+// Vault Token A above the offered amount goes back to the proposer's ATA A
+pub fn take_offer(
+    taker: &Pubkey,
+    proposer: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+    offer_id: u64,
+    token_program: &Pubkey,
+) -> Instruction {
+    let (offer, _) = offer_address(proposer, offer_id);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*taker, true),
+            AccountMeta::new(*proposer, false),
+            AccountMeta::new(
+                associated_token_address(proposer, token_mint_b, token_program),
+                false,
+            ),
+            AccountMeta::new_readonly(*token_mint_b, false),
+            AccountMeta::new_readonly(*token_mint_a, false),
+            AccountMeta::new(
+                associated_token_address(taker, token_mint_a, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(taker, token_mint_b, token_program),
+                false,
+            ),
+            AccountMeta::new(offer, false),
+            AccountMeta::new(vault_address(&offer, token_mint_a, token_program), false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new(
+                associated_token_address(proposer, token_mint_a, token_program),
+                false,
+            ),
+        ],
+        data: vec![TAKE_OFFER],
+    }
+}
+
+// Same accounts as take_offer, followed by the fee accounts
+// The fee vault is created by the program (taker pays) if missing
+#[allow(clippy::too_many_arguments)]
+pub fn take_offer_with_fee(
+    taker: &Pubkey,
+    proposer: &Pubkey,
+    token_mint_a: &Pubkey,
+    token_mint_b: &Pubkey,
+    offer_id: u64,
+    fee_authority: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let mut ix = take_offer(
+        taker,
+        proposer,
+        token_mint_a,
+        token_mint_b,
+        offer_id,
+        token_program,
+    );
+    let (fee_config, _) = fee_config_address();
+
+    ix.accounts.extend([
+        AccountMeta::new_readonly(fee_config, false),
+        AccountMeta::new_readonly(*fee_authority, false),
+        AccountMeta::new(
+            fee_vault_address(fee_authority, token_mint_b, token_program),
+            false,
+        ),
+    ]);
+    ix.data = vec![TAKE_OFFER_WITH_FEE];
+    ix
+}
+
+pub fn refund_offer(
+    maker: &Pubkey,
+    token_mint_a: &Pubkey,
+    offer_id: u64,
+    token_program: &Pubkey,
+) -> Instruction {
+    let (offer, _) = offer_address(maker, offer_id);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*maker, true),
+            AccountMeta::new_readonly(*token_mint_a, false),
+            AccountMeta::new(
+                associated_token_address(maker, token_mint_a, token_program),
+                false,
+            ),
+            AccountMeta::new(offer, false),
+            AccountMeta::new(vault_address(&offer, token_mint_a, token_program), false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: vec![REFUND_OFFER],
+    }
+}
+
+// Returns vault Token A above the offered amount to the maker; the offer stays open
+pub fn sweep_dust(
+    maker: &Pubkey,
+    token_mint_a: &Pubkey,
+    offer_id: u64,
+    token_program: &Pubkey,
+) -> Instruction {
+    let (offer, _) = offer_address(maker, offer_id);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*maker, true),
+            AccountMeta::new_readonly(*token_mint_a, false),
+            AccountMeta::new(
+                associated_token_address(maker, token_mint_a, token_program),
+                false,
+            ),
+            AccountMeta::new_readonly(offer, false),
+            AccountMeta::new(vault_address(&offer, token_mint_a, token_program), false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        data: vec![SWEEP_DUST],
+    }
+}
+
+// Permissionless once the offer has expired; Token A and rent go back to the maker
+pub fn reap_expired_offer(
+    caller: &Pubkey,
+    maker: &Pubkey,
+    token_mint_a: &Pubkey,
+    offer_id: u64,
+    token_program: &Pubkey,
+) -> Instruction {
+    let (offer, _) = offer_address(maker, offer_id);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*caller, true),
+            AccountMeta::new(*maker, false),
+            AccountMeta::new_readonly(*token_mint_a, false),
+            AccountMeta::new(
+                associated_token_address(maker, token_mint_a, token_program),
+                false,
+            ),
+            AccountMeta::new(offer, false),
+            AccountMeta::new(vault_address(&offer, token_mint_a, token_program), false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+        ],
+        data: vec![REAP_EXPIRED_OFFER],
+    }
+}
+
+// Creates the fee config on first call; the first signer becomes the fee authority
+pub fn set_fee_config(authority: &Pubkey, fee_bps: u16) -> Instruction {
+    let (fee_config, _) = fee_config_address();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(fee_config, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: set_fee_config_data(fee_bps),
+    }
+}
+
+// Maker or allowed taker freezes the offer for the arbiter's dispute window
+pub fn open_dispute(disputant: &Pubkey, maker: &Pubkey, offer_id: u64) -> Instruction {
+    let (offer, _) = offer_address(maker, offer_id);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*disputant, true),
+            AccountMeta::new(offer, false),
+        ],
+        data: vec![OPEN_DISPUTE],
+    }
+}
+
+// recipient must be the maker (DisputeAward::Maker) or the allowed taker (DisputeAward::Taker)
+// and already hold a Token A account; vault and offer rent go back to the maker
+pub fn resolve_dispute(
+    arbiter: &Pubkey,
+    maker: &Pubkey,
+    recipient: &Pubkey,
+    token_mint_a: &Pubkey,
+    offer_id: u64,
+    award: DisputeAward,
+    token_program: &Pubkey,
+) -> Instruction {
+    let (offer, _) = offer_address(maker, offer_id);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*arbiter, true),
+            AccountMeta::new(*maker, false),
+            AccountMeta::new_readonly(*recipient, false),
+            AccountMeta::new_readonly(*token_mint_a, false),
+            AccountMeta::new(
+                associated_token_address(recipient, token_mint_a, token_program),
+                false,
+            ),
+            AccountMeta::new(offer, false),
+            AccountMeta::new(vault_address(&offer, token_mint_a, token_program), false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: vec![RESOLVE_DISPUTE, award as u8],
+    }
+}
@@ -0,0 +1,23 @@
+// Escrow (pino-escrow p-secure)
+//
+// Pinocchio token escrow: a maker deposits Token A into a vault owned by an
+// offer PDA, and a taker completes the swap by paying Token B. Offers can be
+// private, expire, and be created in batches, and marketplace takes can
+// route a protocol fee to the fee authority.
+//
+// Unlike the Anchor programs, instructions use a 1-byte discriminator and
+// #[repr(C)] data, and the offer account has no discriminator.
+
+use solana_sdk::{pubkey, pubkey::Pubkey};
+
+pub mod accounts;
+pub mod instructions;
+pub mod pda;
+
+pub use accounts::*;
+pub use pda::*;
+
+pub const PROGRAM_ID: Pubkey = pubkey!("J8Ru6Zti7EwTwVt35BGN2irvD1ELEjv2MkCYGAbCqaok");
+
+// Maximum offers per ProposeOfferBatch (MAX_BATCH_OFFERS)
+pub const MAX_BATCH_OFFERS: usize = 4;
@@ -0,0 +1,37 @@
+// Escrow PDAs
+//
+// Offer: ["offer", maker, id] where id is the 8-byte offer id.
+// Vault: the offer PDA's associated token account for Token A.
+// Fee config: ["fee_config"], one per program.
+// Fee vault: the fee authority's associated token account for Token B.
+
+use solana_sdk::pubkey::Pubkey;
+
+use super::PROGRAM_ID;
+use crate::token::associated_token_address;
+
+pub const OFFER_SEED: &[u8] = b"offer";
+pub const FEE_CONFIG_SEED: &[u8] = b"fee_config";
+
+pub fn offer_address(maker: &Pubkey, offer_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[OFFER_SEED, maker.as_ref(), &offer_id.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+pub fn vault_address(offer: &Pubkey, token_mint_a: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    associated_token_address(offer, token_mint_a, token_program)
+}
+
+pub fn fee_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_CONFIG_SEED], &PROGRAM_ID)
+}
+
+pub fn fee_vault_address(
+    fee_authority: &Pubkey,
+    token_mint_b: &Pubkey,
+    token_program: &Pubkey,
+) -> Pubkey {
+    associated_token_address(fee_authority, token_mint_b, token_program)
+}
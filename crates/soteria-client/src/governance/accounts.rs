@@ -0,0 +1,662 @@
+// Governance Accounts
+//
+// Mirrors state/*.rs. Enums use Borsh variant order, so the tag values below
+// must stay in the same order as the program's enum declarations.
+
+use solana_sdk::pubkey::Pubkey;
+
+use super::MAX_LOCK_SECONDS;
+use crate::{
+    codec::{DataReader, DecodeError},
+    multisig::ProposalType as MultisigProposalType,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemberRanks {
+    Member,
+    Bronze,
+    Contributor,
+    Guardian,
+    Leader,
+}
+
+impl MemberRanks {
+    fn read(reader: &mut DataReader) -> Result<Self, DecodeError> {
+        match reader.u8()? {
+            0 => Ok(MemberRanks::Member),
+            1 => Ok(MemberRanks::Bronze),
+            2 => Ok(MemberRanks::Contributor),
+            3 => Ok(MemberRanks::Guardian),
+            4 => Ok(MemberRanks::Leader),
+            tag => Err(DecodeError::InvalidEnumTag {
+                name: "MemberRanks",
+                tag,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalStatus {
+    Active,
+    Passed,
+    Rejected,
+    Vetoed,
+}
+
+impl ProposalStatus {
+    fn read(reader: &mut DataReader) -> Result<Self, DecodeError> {
+        match reader.u8()? {
+            0 => Ok(ProposalStatus::Active),
+            1 => Ok(ProposalStatus::Passed),
+            2 => Ok(ProposalStatus::Rejected),
+            3 => Ok(ProposalStatus::Vetoed),
+            tag => Err(DecodeError::InvalidEnumTag {
+                name: "ProposalStatus",
+                tag,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoteType {
+    Upvote,
+    Downvote,
+}
+
+impl VoteType {
+    fn read(reader: &mut DataReader) -> Result<Self, DecodeError> {
+        match reader.u8()? {
+            0 => Ok(VoteType::Upvote),
+            1 => Ok(VoteType::Downvote),
+            tag => Err(DecodeError::InvalidEnumTag {
+                name: "VoteType",
+                tag,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub admin: Pubkey,
+    pub minimum_stake: u64,
+    pub token_mint: Pubkey,
+    pub vote_power: u8,
+    pub quorum: u64,
+    pub approval_threshold_bps: u16,
+    pub proposal_count: u64,
+    pub unbonding_period: i64,
+    pub decay_rate_bps: u16,
+    pub decay_epoch_seconds: i64,
+    pub reward_rate_per_sec: u64,
+    pub pending_admin: Pubkey,
+    pub current_season: u64,
+    pub stake_ramp_seconds: i64,
+    pub proposal_deposit: u64,
+    pub is_paused: bool,
+    pub config_bump: u8,
+}
+
+impl Config {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "Config")?;
+        Ok(Self {
+            admin: reader.pubkey()?,
+            minimum_stake: reader.u64()?,
+            token_mint: reader.pubkey()?,
+            vote_power: reader.u8()?,
+            quorum: reader.u64()?,
+            approval_threshold_bps: reader.u16()?,
+            proposal_count: reader.u64()?,
+            unbonding_period: reader.i64()?,
+            decay_rate_bps: reader.u16()?,
+            decay_epoch_seconds: reader.i64()?,
+            reward_rate_per_sec: reader.u64()?,
+            pending_admin: reader.pubkey()?,
+            current_season: reader.u64()?,
+            stake_ramp_seconds: reader.i64()?,
+            proposal_deposit: reader.u64()?,
+            is_paused: reader.bool()?,
+            config_bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Treasury {
+    pub admin: Pubkey,
+    pub total_staked: u64,
+    pub stakers_count: u64,
+    pub total_unbonding: u64,
+    pub treasury_token_account: Pubkey,
+    pub holdings_count: u16,
+    pub state_bump: u8,
+    pub vault_bump: u8,
+}
+
+impl Treasury {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "Treasury")?;
+        Ok(Self {
+            admin: reader.pubkey()?,
+            total_staked: reader.u64()?,
+            stakers_count: reader.u64()?,
+            total_unbonding: reader.u64()?,
+            treasury_token_account: reader.pubkey()?,
+            holdings_count: reader.u16()?,
+            state_bump: reader.u8()?,
+            vault_bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserProfile {
+    pub username: String,
+    pub owner: Pubkey,
+    pub reputation_points: i64,
+    pub stake_amount: u64,
+    pub role_level: MemberRanks,
+    pub upvotes_received: u64,
+    pub downvotes_received: u64,
+    pub total_votes_cast: u64,
+    pub last_vote_timestamp: i64,
+    pub created_at: i64,
+    pub last_stake_timestamp: i64,
+    pub delegated_to: Pubkey,
+    pub delegated_stake: u64,
+    pub last_decay_ts: i64,
+    pub reward_debt: u64,
+    pub last_reward_ts: i64,
+    pub weighted_stake: u64,
+    pub stake_start_ts: i64,
+    pub metadata_uri: Option<String>,
+}
+
+impl UserProfile {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "UserProfile")?;
+        Ok(Self {
+            username: reader.string()?,
+            owner: reader.pubkey()?,
+            reputation_points: reader.i64()?,
+            stake_amount: reader.u64()?,
+            role_level: MemberRanks::read(&mut reader)?,
+            upvotes_received: reader.u64()?,
+            downvotes_received: reader.u64()?,
+            total_votes_cast: reader.u64()?,
+            last_vote_timestamp: reader.i64()?,
+            created_at: reader.i64()?,
+            last_stake_timestamp: reader.i64()?,
+            delegated_to: reader.pubkey()?,
+            delegated_stake: reader.u64()?,
+            last_decay_ts: reader.i64()?,
+            reward_debt: reader.u64()?,
+            last_reward_ts: reader.i64()?,
+            weighted_stake: reader.u64()?,
+            stake_start_ts: reader.i64()?,
+            metadata_uri: match reader.bool()? {
+                true => Some(reader.string()?),
+                false => None,
+            },
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UsernameRegistry {
+    pub claimed: bool,
+    pub owner: Pubkey,
+    pub bump: u8,
+}
+
+impl UsernameRegistry {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "UsernameRegistry")?;
+        Ok(Self {
+            claimed: reader.bool()?,
+            owner: reader.pubkey()?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Proposal {
+    pub config: Pubkey,
+    pub proposer: Pubkey,
+    pub proposal_id: u64,
+    pub title: String,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub created_at: i64,
+    pub voting_ends_at: i64,
+    pub status: ProposalStatus,
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "Proposal")?;
+        Ok(Self {
+            config: reader.pubkey()?,
+            proposer: reader.pubkey()?,
+            proposal_id: reader.u64()?,
+            title: reader.string()?,
+            votes_for: reader.u64()?,
+            votes_against: reader.u64()?,
+            created_at: reader.i64()?,
+            voting_ends_at: reader.i64()?,
+            status: ProposalStatus::read(&mut reader)?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ballot {
+    pub voter: Pubkey,
+    pub proposal: Pubkey,
+    pub support: bool,
+    pub weight: u64,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+impl Ballot {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "Ballot")?;
+        Ok(Self {
+            voter: reader.pubkey()?,
+            proposal: reader.pubkey()?,
+            support: reader.bool()?,
+            weight: reader.u64()?,
+            timestamp: reader.i64()?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Delegation {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub amount: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Delegation {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "Delegation")?;
+        Ok(Self {
+            delegator: reader.pubkey()?,
+            delegate: reader.pubkey()?,
+            amount: reader.u64()?,
+            created_at: reader.i64()?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnbondingTicket {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub unlock_at: i64,
+    pub bump: u8,
+}
+
+impl UnbondingTicket {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "UnbondingTicket")?;
+        Ok(Self {
+            owner: reader.pubkey()?,
+            amount: reader.u64()?,
+            unlock_at: reader.i64()?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VoteCooldown {
+    pub voter: Pubkey,
+    pub last_vote_timestamp: i64,
+    pub season: u64,
+    pub bump: u8,
+}
+
+impl VoteCooldown {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "VoteCooldown")?;
+        Ok(Self {
+            voter: reader.pubkey()?,
+            last_vote_timestamp: reader.i64()?,
+            season: reader.u64()?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoteRecord {
+    pub voter: Pubkey,
+    pub target_username: String,
+    pub target_owner: Pubkey,
+    pub vote_type: VoteType,
+    pub vote_weight: i64,
+    pub timestamp: i64,
+    pub season: u64,
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "VoteRecord")?;
+        Ok(Self {
+            voter: reader.pubkey()?,
+            target_username: reader.string()?,
+            target_owner: reader.pubkey()?,
+            vote_type: VoteType::read(&mut reader)?,
+            vote_weight: reader.i64()?,
+            timestamp: reader.i64()?,
+            season: reader.u64()?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VoteHistoryEntry {
+    pub target: Pubkey,
+    pub vote_type: VoteType,
+    pub timestamp: i64,
+    pub weight: i64,
+}
+
+// Ring buffer of recent votes; `head` is the next slot to overwrite once full
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoteHistory {
+    pub owner: Pubkey,
+    pub bump: u8,
+    pub capacity: u16,
+    pub head: u16,
+    pub total_recorded: u64,
+    pub entries: Vec<VoteHistoryEntry>,
+}
+
+impl VoteHistory {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "VoteHistory")?;
+        Ok(Self {
+            owner: reader.pubkey()?,
+            bump: reader.u8()?,
+            capacity: reader.u16()?,
+            head: reader.u16()?,
+            total_recorded: reader.u64()?,
+            entries: reader.vec(|r| {
+                Ok(VoteHistoryEntry {
+                    target: r.pubkey()?,
+                    vote_type: VoteType::read(r)?,
+                    timestamp: r.i64()?,
+                    weight: r.i64()?,
+                })
+            })?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeasonEntry {
+    pub user: Pubkey,
+    pub reputation: i64,
+    pub claimed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeasonSnapshot {
+    pub config: Pubkey,
+    pub season_id: u64,
+    pub total_staked: u64,
+    pub reward_pool: u64,
+    pub finalized: bool,
+    pub bump: u8,
+    pub entries: Vec<SeasonEntry>,
+}
+
+impl SeasonSnapshot {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "SeasonSnapshot")?;
+        Ok(Self {
+            config: reader.pubkey()?,
+            season_id: reader.u64()?,
+            total_staked: reader.u64()?,
+            reward_pool: reader.u64()?,
+            finalized: reader.bool()?,
+            bump: reader.u8()?,
+            entries: reader.vec(|r| {
+                Ok(SeasonEntry {
+                    user: r.pubkey()?,
+                    reputation: r.i64()?,
+                    claimed: r.bool()?,
+                })
+            })?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SupportedMint {
+    pub config: Pubkey,
+    pub mint: Pubkey,
+    pub weight_bps: u16,
+    pub treasury_token_account: Pubkey,
+    pub total_staked: u64,
+    pub bump: u8,
+}
+
+impl SupportedMint {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "SupportedMint")?;
+        Ok(Self {
+            config: reader.pubkey()?,
+            mint: reader.pubkey()?,
+            weight_bps: reader.u16()?,
+            treasury_token_account: reader.pubkey()?,
+            total_staked: reader.u64()?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MintStake {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub voting_power: u64,
+    pub bump: u8,
+}
+
+impl MintStake {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "MintStake")?;
+        Ok(Self {
+            owner: reader.pubkey()?,
+            mint: reader.pubkey()?,
+            amount: reader.u64()?,
+            voting_power: reader.u64()?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+// The action field is the multisig's own ProposalType; the program mirrors it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MultisigAction {
+    pub proposal: Pubkey,
+    pub multisig: Pubkey,
+    pub action: MultisigProposalType,
+    pub executed: bool,
+    pub bump: u8,
+}
+
+impl MultisigAction {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "MultisigAction")?;
+        Ok(Self {
+            proposal: reader.pubkey()?,
+            multisig: reader.pubkey()?,
+            action: MultisigProposalType::read(&mut reader)?,
+            executed: reader.bool()?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Council {
+    pub config: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub veto_window: i64,
+    pub bump: u8,
+}
+
+impl Council {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "Council")?;
+        Ok(Self {
+            config: reader.pubkey()?,
+            members: reader.vec(|r| r.pubkey())?,
+            threshold: reader.u8()?,
+            veto_window: reader.i64()?,
+            bump: reader.u8()?,
+        })
+    }
+
+    // Last moment (exclusive) a proposal can be vetoed, and the first it can be executed
+    pub fn veto_deadline(&self, proposal: &Proposal) -> i64 {
+        proposal.voting_ends_at.saturating_add(self.veto_window)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VoteEscrow {
+    pub owner: Pubkey,
+    pub config: Pubkey,
+    pub amount: u64,
+    pub locked_at: i64,
+    pub unlock_at: i64,
+    pub bump: u8,
+}
+
+impl VoteEscrow {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "VoteEscrow")?;
+        Ok(Self {
+            owner: reader.pubkey()?,
+            config: reader.pubkey()?,
+            amount: reader.u64()?,
+            locked_at: reader.i64()?,
+            unlock_at: reader.i64()?,
+            bump: reader.u8()?,
+        })
+    }
+
+    // Power the program counts for this lock at `now` (VoteEscrow::voting_power)
+    pub fn voting_power(&self, now: i64) -> u64 {
+        let remaining = self.unlock_at.saturating_sub(now).clamp(0, MAX_LOCK_SECONDS);
+        (self.amount as u128 * remaining as u128 / MAX_LOCK_SECONDS as u128) as u64
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProposalDeposit {
+    pub proposal: Pubkey,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl ProposalDeposit {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "ProposalDeposit")?;
+        Ok(Self {
+            proposal: reader.pubkey()?,
+            depositor: reader.pubkey()?,
+            amount: reader.u64()?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TreasuryHolding {
+    pub treasury: Pubkey,
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub oracle_program: Pubkey,
+    pub price_feed: Pubkey,
+    pub decimals: u8,
+    pub index: u16,
+    pub bump: u8,
+}
+
+impl TreasuryHolding {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "TreasuryHolding")?;
+        Ok(Self {
+            treasury: reader.pubkey()?,
+            mint: reader.pubkey()?,
+            token_account: reader.pubkey()?,
+            oracle_program: reader.pubkey()?,
+            price_feed: reader.pubkey()?,
+            decimals: reader.u8()?,
+            index: reader.u16()?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HoldingValue {
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub price: u64,
+    pub exponent: i32,
+    pub value: u128,
+}
+
+impl HoldingValue {
+    fn read(reader: &mut DataReader) -> Result<Self, DecodeError> {
+        Ok(Self {
+            mint: reader.pubkey()?,
+            amount: reader.u64()?,
+            price: reader.u64()?,
+            exponent: reader.i32()?,
+            value: reader.u128()?,
+        })
+    }
+}
+
+// Return value of treasury_value_report; values in TREASURY_VALUE_DECIMALS
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreasuryValueReport {
+    pub total_value: u128,
+    pub holdings: Vec<HoldingValue>,
+}
+
+impl TreasuryValueReport {
+    // Return data is plain Borsh, without an account discriminator
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::new(data);
+        Ok(Self {
+            total_value: reader.u128()?,
+            holdings: reader.vec(HoldingValue::read)?,
+        })
+    }
+}
@@ -0,0 +1,1169 @@
+// Governance Instructions
+//
+// DAO accounts are derived from the admin the config was created for, user
+// accounts from the user's wallet. Account order follows each
+// #[derive(Accounts)] struct in the program. Staking uses SPL Token only.
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey,
+    pubkey::Pubkey,
+    signature::Signature,
+};
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+
+use super::{pda::*, PROGRAM_ID};
+use crate::{
+    amm::instructions::INSTRUCTIONS_SYSVAR_ID,
+    codec::DataWriter,
+    multisig::{ProposalType as MultisigProposalType, PROGRAM_ID as MULTISIG_PROGRAM_ID},
+    token::{associated_token_address, ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID},
+};
+
+// Arguments to init_dao, in the order the program reads them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DaoParams {
+    pub admin: Pubkey,
+    pub minimum_stake: u64,
+    pub token_mint: Pubkey,
+    pub vote_power: u8,
+    pub quorum: u64,
+    pub approval_threshold_bps: u16,
+    pub unbonding_period: i64,
+    pub decay_rate_bps: u16,
+    pub decay_epoch_seconds: i64,
+    pub reward_rate_per_sec: u64,
+    pub stake_ramp_seconds: i64,
+    pub proposal_deposit: u64,
+}
+
+pub fn init_dao(signer: &Pubkey, params: &DaoParams) -> Instruction {
+    let (config, _) = config_address(&params.admin);
+
+    let data = DataWriter::anchor("init_dao")
+        .pubkey(&params.admin)
+        .u64(params.minimum_stake)
+        .pubkey(&params.token_mint)
+        .u8(params.vote_power)
+        .u64(params.quorum)
+        .u16(params.approval_threshold_bps)
+        .i64(params.unbonding_period)
+        .u16(params.decay_rate_bps)
+        .i64(params.decay_epoch_seconds)
+        .u64(params.reward_rate_per_sec)
+        .i64(params.stake_ramp_seconds)
+        .u64(params.proposal_deposit)
+        .into_vec();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*signer, true),
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+pub fn initialize_treasury(signer: &Pubkey, admin: &Pubkey, token_mint: &Pubkey) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (treasury, _) = treasury_address(admin);
+    let (treasury_authority, _) = treasury_authority_address(&config, admin);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*signer, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new_readonly(treasury_authority, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new(treasury_token_address(admin, token_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("initialize_treasury").into_vec(),
+    }
+}
+
+pub fn create_profile(user: &Pubkey, username: &str) -> Instruction {
+    let (user_registry, _) = username_registry_address(username);
+    let (user_profile, _) = user_profile_address(user);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new(user_registry, false),
+            AccountMeta::new(user_profile, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("create_profile")
+            .string(username)
+            .into_vec(),
+    }
+}
+
+// Registers `token_mint` as a supported stake mint; `authority` must be the admin
+pub fn add_supported_mint(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+    weight_bps: u16,
+) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (treasury, _) = treasury_address(admin);
+    let (treasury_authority, _) = treasury_authority_address(&config, admin);
+    let (supported_mint, _) = supported_mint_address(&config, token_mint);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(treasury, false),
+            AccountMeta::new_readonly(treasury_authority, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new(supported_mint, false),
+            AccountMeta::new(treasury_token_address(admin, token_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("add_supported_mint")
+            .u16(weight_bps)
+            .into_vec(),
+    }
+}
+
+// Stakes the DAO's base mint
+pub fn stake_tokens(
+    user: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    stake(user, admin, token_mint, amount, false)
+}
+
+// Stakes a mint registered with add_supported_mint
+pub fn stake_supported_mint(
+    user: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    stake(user, admin, token_mint, amount, true)
+}
+
+// Anchor treats the program ID as "None" for optional accounts
+fn stake(
+    user: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+    amount: u64,
+    supported: bool,
+) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (treasury, _) = treasury_address(admin);
+    let (user_profile, _) = user_profile_address(user);
+
+    let (supported_mint, mint_stake) = if supported {
+        (
+            supported_mint_address(&config, token_mint).0,
+            mint_stake_address(&config, token_mint, user).0,
+        )
+    } else {
+        (PROGRAM_ID, PROGRAM_ID)
+    };
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(user_profile, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new(
+                associated_token_address(user, token_mint, &TOKEN_PROGRAM_ID),
+                false,
+            ),
+            AccountMeta::new(treasury_token_address(admin, token_mint), false),
+            AccountMeta::new(supported_mint, false),
+            AccountMeta::new(mint_stake, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("stake_tokens").u64(amount).into_vec(),
+    }
+}
+
+// Moves stake into an unbonding ticket; tokens are released by claim_unbonded
+pub fn unstake_tokens(user: &Pubkey, admin: &Pubkey, amount: u64) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (treasury, _) = treasury_address(admin);
+    let (user_profile, _) = user_profile_address(user);
+    let (unbonding_ticket, _) = unbonding_ticket_address(user);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(user_profile, false),
+            AccountMeta::new(unbonding_ticket, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("unstake_tokens").u64(amount).into_vec(),
+    }
+}
+
+pub fn claim_unbonded(user: &Pubkey, admin: &Pubkey, token_mint: &Pubkey) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (treasury, _) = treasury_address(admin);
+    let (treasury_authority, _) = treasury_authority_address(&config, admin);
+    let (unbonding_ticket, _) = unbonding_ticket_address(user);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new_readonly(treasury_authority, false),
+            AccountMeta::new(unbonding_ticket, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new(
+                associated_token_address(user, token_mint, &TOKEN_PROGRAM_ID),
+                false,
+            ),
+            AccountMeta::new(treasury_token_address(admin, token_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("claim_unbonded").into_vec(),
+    }
+}
+
+pub fn claim_rewards(user: &Pubkey, admin: &Pubkey, token_mint: &Pubkey) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (treasury, _) = treasury_address(admin);
+    let (treasury_authority, _) = treasury_authority_address(&config, admin);
+    let (user_profile, _) = user_profile_address(user);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(treasury, false),
+            AccountMeta::new_readonly(treasury_authority, false),
+            AccountMeta::new(user_profile, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new(
+                associated_token_address(user, token_mint, &TOKEN_PROGRAM_ID),
+                false,
+            ),
+            AccountMeta::new(treasury_token_address(admin, token_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("claim_rewards").into_vec(),
+    }
+}
+
+// upvote and downvote share the same accounts and argument
+fn vote(
+    method: &str,
+    voter: &Pubkey,
+    admin: &Pubkey,
+    target_user: &Pubkey,
+    target_username: &str,
+) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (voter_profile, _) = user_profile_address(voter);
+    let (target_user_registry, _) = username_registry_address(target_username);
+    let (target_user_profile, _) = user_profile_address(target_user);
+    let (vote_cooldown, _) = vote_cooldown_address(voter);
+    let (vote_record, _) = vote_record_address(voter, target_username);
+    let (vote_history, _) = vote_history_address(voter);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*voter, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(voter_profile, false),
+            AccountMeta::new_readonly(target_user_registry, false),
+            AccountMeta::new(target_user_profile, false),
+            AccountMeta::new(vote_cooldown, false),
+            AccountMeta::new(vote_record, false),
+            AccountMeta::new(vote_history, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor(method)
+            .string(target_username)
+            .into_vec(),
+    }
+}
+
+pub fn upvote(
+    voter: &Pubkey,
+    admin: &Pubkey,
+    target_user: &Pubkey,
+    target_username: &str,
+) -> Instruction {
+    vote("upvote", voter, admin, target_user, target_username)
+}
+
+pub fn downvote(
+    voter: &Pubkey,
+    admin: &Pubkey,
+    target_user: &Pubkey,
+    target_username: &str,
+) -> Instruction {
+    vote("downvote", voter, admin, target_user, target_username)
+}
+
+// `season` must be the config's current season - the user's standing is
+// recorded in that season's snapshot before the reset
+pub fn reset_user_reputation(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    user: &Pubkey,
+    season: u64,
+) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (user_profile, _) = user_profile_address(user);
+    let (season_snapshot, _) = season_snapshot_address(&config, season);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(user_profile, false),
+            AccountMeta::new(season_snapshot, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("reset_user_reputation")
+            .pubkey(user)
+            .into_vec(),
+    }
+}
+
+pub fn propose_admin(authority: &Pubkey, admin: &Pubkey, new_admin: &Pubkey) -> Instruction {
+    let (config, _) = config_address(admin);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new(config, false),
+        ],
+        data: DataWriter::anchor("propose_admin")
+            .pubkey(new_admin)
+            .into_vec(),
+    }
+}
+
+// `admin` is the original admin the config PDA was derived from
+pub fn accept_admin(new_admin: &Pubkey, admin: &Pubkey) -> Instruction {
+    let (config, _) = config_address(admin);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*new_admin, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new(config, false),
+        ],
+        data: DataWriter::anchor("accept_admin").into_vec(),
+    }
+}
+
+// `proposal_id` must be the config's current proposal_count
+// The proposer's deposit (config.proposal_deposit scaled by rank) moves from
+// their base-mint ATA into the ATA of the proposal's deposit PDA
+pub fn create_governance_proposal(
+    proposer: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+    proposal_id: u64,
+    title: &str,
+) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (proposer_profile, _) = user_profile_address(proposer);
+    let (proposal, _) = proposal_address(&config, proposal_id);
+    let (proposal_deposit, _) = proposal_deposit_address(&proposal);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*proposer, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(proposer_profile, false),
+            AccountMeta::new(proposal, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new(
+                associated_token_address(proposer, token_mint, &TOKEN_PROGRAM_ID),
+                false,
+            ),
+            AccountMeta::new(proposal_deposit, false),
+            AccountMeta::new(
+                associated_token_address(&proposal_deposit, token_mint, &TOKEN_PROGRAM_ID),
+                false,
+            ),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("create_governance_proposal")
+            .string(title)
+            .into_vec(),
+    }
+}
+
+pub fn cast_ballot(voter: &Pubkey, admin: &Pubkey, proposal_id: u64, support: bool) -> Instruction {
+    ballot(voter, admin, proposal_id, support, false)
+}
+
+// Casts a ballot that also counts the voter's vote escrow
+pub fn cast_ballot_with_escrow(
+    voter: &Pubkey,
+    admin: &Pubkey,
+    proposal_id: u64,
+    support: bool,
+) -> Instruction {
+    ballot(voter, admin, proposal_id, support, true)
+}
+
+// Anchor treats the program ID as "None" for optional accounts
+fn ballot(
+    voter: &Pubkey,
+    admin: &Pubkey,
+    proposal_id: u64,
+    support: bool,
+    with_escrow: bool,
+) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (voter_profile, _) = user_profile_address(voter);
+    let (proposal, _) = proposal_address(&config, proposal_id);
+    let (ballot, _) = ballot_address(&proposal, voter);
+    let vote_escrow = if with_escrow {
+        vote_escrow_address(&config, voter).0
+    } else {
+        PROGRAM_ID
+    };
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*voter, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(voter_profile, false),
+            AccountMeta::new(proposal, false),
+            AccountMeta::new(ballot, false),
+            AccountMeta::new_readonly(vote_escrow, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("cast_ballot").bool(support).into_vec(),
+    }
+}
+
+// Settles the deposit too: refunded to the proposer's ATA or burned, and both
+// deposit accounts closed to the proposer
+pub fn finalize_proposal(
+    caller: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+    proposal_id: u64,
+    proposer: &Pubkey,
+) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (proposal, _) = proposal_address(&config, proposal_id);
+    let (proposal_deposit, _) = proposal_deposit_address(&proposal);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*caller, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(proposal, false),
+            AccountMeta::new(*proposer, false),
+            AccountMeta::new(*token_mint, false),
+            AccountMeta::new(
+                associated_token_address(proposer, token_mint, &TOKEN_PROGRAM_ID),
+                false,
+            ),
+            AccountMeta::new(proposal_deposit, false),
+            AccountMeta::new(
+                associated_token_address(&proposal_deposit, token_mint, &TOKEN_PROGRAM_ID),
+                false,
+            ),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("finalize_proposal").into_vec(),
+    }
+}
+
+pub const ED25519_PROGRAM_ID: Pubkey = pubkey!("Ed25519SigVerify111111111111111111111111111");
+
+// One off-chain vote settled by submit_vote_batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VoteBatchEntry {
+    pub voter: Pubkey,
+    pub support: bool,
+    pub weight: u64,
+}
+
+impl VoteBatchEntry {
+    // Bytes the voter signs: proposal | support | weight (little-endian)
+    pub fn vote_message(&self, proposal: &Pubkey) -> [u8; 41] {
+        let mut message = [0u8; 41];
+        message[..32].copy_from_slice(proposal.as_ref());
+        message[32] = self.support as u8;
+        message[33..].copy_from_slice(&self.weight.to_le_bytes());
+        message
+    }
+}
+
+// Ed25519 precompile instruction checking `signature` by `signer` over `message`
+// Key, signature and message are all read from this instruction (index
+// u16::MAX), which is the only layout submit_vote_batch accepts
+pub fn ed25519_verify(signer: &Pubkey, signature: &Signature, message: &[u8]) -> Instruction {
+    const PUBLIC_KEY_OFFSET: u16 = 16;
+    const SIGNATURE_OFFSET: u16 = PUBLIC_KEY_OFFSET + 32;
+    const MESSAGE_OFFSET: u16 = SIGNATURE_OFFSET + 64;
+
+    Instruction {
+        program_id: ED25519_PROGRAM_ID,
+        accounts: vec![],
+        data: DataWriter::tagged(1) // num_signatures
+            .u8(0) // padding
+            .u16(SIGNATURE_OFFSET)
+            .u16(u16::MAX)
+            .u16(PUBLIC_KEY_OFFSET)
+            .u16(u16::MAX)
+            .u16(MESSAGE_OFFSET)
+            .u16(message.len() as u16)
+            .u16(u16::MAX)
+            .pubkey(signer)
+            .bytes(signature.as_ref())
+            .bytes(message)
+            .into_vec(),
+    }
+}
+
+// Settles `entries` on a proposal; `relayer` pays for the ballots
+// Entry i must be verified by the ed25519_verify instruction placed
+// entries.len() - i positions before this one
+pub fn submit_vote_batch(
+    relayer: &Pubkey,
+    admin: &Pubkey,
+    proposal_id: u64,
+    entries: &[VoteBatchEntry],
+) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (proposal, _) = proposal_address(&config, proposal_id);
+
+    let mut accounts = vec![
+        AccountMeta::new(*relayer, true),
+        AccountMeta::new_readonly(*admin, false),
+        AccountMeta::new_readonly(config, false),
+        AccountMeta::new(proposal, false),
+        AccountMeta::new_readonly(INSTRUCTIONS_SYSVAR_ID, false),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+    ];
+    let mut writer = DataWriter::anchor("submit_vote_batch").u32(entries.len() as u32);
+    for entry in entries {
+        accounts.push(AccountMeta::new_readonly(user_profile_address(&entry.voter).0, false));
+        accounts.push(AccountMeta::new(ballot_address(&proposal, &entry.voter).0, false));
+        writer = writer.pubkey(&entry.voter).bool(entry.support).u64(entry.weight);
+    }
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data: writer.into_vec(),
+    }
+}
+
+// Must be sent by the proposal's proposer before any ballot is cast
+pub fn attach_multisig_action(
+    proposer: &Pubkey,
+    admin: &Pubkey,
+    proposal_id: u64,
+    multisig: &Pubkey,
+    action: MultisigProposalType,
+) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (proposal, _) = proposal_address(&config, proposal_id);
+    let (multisig_action, _) = multisig_action_address(&proposal);
+
+    let data = action
+        .write(DataWriter::anchor("attach_multisig_action").pubkey(multisig))
+        .into_vec();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*proposer, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(proposal, false),
+            AccountMeta::new(multisig_action, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// `multisig_proposal` must be the multisig's proposal PDA for its current
+// proposal_count; the multisig program checks the seeds. With a council the
+// proposal can only be relayed once its veto window has passed
+pub fn execute_multisig_action(
+    payer: &Pubkey,
+    admin: &Pubkey,
+    proposal_id: u64,
+    multisig: &Pubkey,
+    multisig_proposal: &Pubkey,
+) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (proposal, _) = proposal_address(&config, proposal_id);
+    let (multisig_action, _) = multisig_action_address(&proposal);
+    let (executor, _) = multisig_executor_address(&config);
+    let (council, _) = council_address(&config);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(proposal, false),
+            AccountMeta::new(multisig_action, false),
+            AccountMeta::new(executor, false),
+            AccountMeta::new(*multisig, false),
+            AccountMeta::new(*multisig_proposal, false),
+            AccountMeta::new_readonly(MULTISIG_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(council, false),
+        ],
+        data: DataWriter::anchor("execute_multisig_action").into_vec(),
+    }
+}
+
+// `authority` must be the config admin; the council can only be set up once
+pub fn init_council(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    members: &[Pubkey],
+    threshold: u8,
+    veto_window: i64,
+) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (council, _) = council_address(&config);
+
+    let mut writer = DataWriter::anchor("init_council").u32(members.len() as u32);
+    for member in members {
+        writer = writer.pubkey(member);
+    }
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(council, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: writer.u8(threshold).i64(veto_window).into_vec(),
+    }
+}
+
+// Co-signing council members follow the fixed accounts as read-only signers
+fn council_co_signers(accounts: &mut Vec<AccountMeta>, co_signers: &[Pubkey]) {
+    accounts.extend(
+        co_signers
+            .iter()
+            .map(|co_signer| AccountMeta::new_readonly(*co_signer, true)),
+    );
+}
+
+// `member` plus `co_signers` must reach the council threshold
+pub fn council_set_pause(
+    member: &Pubkey,
+    admin: &Pubkey,
+    co_signers: &[Pubkey],
+    paused: bool,
+) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (council, _) = council_address(&config);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*member, true),
+        AccountMeta::new_readonly(*admin, false),
+        AccountMeta::new(config, false),
+        AccountMeta::new_readonly(council, false),
+    ];
+    council_co_signers(&mut accounts, co_signers);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data: DataWriter::anchor("council_set_pause").bool(paused).into_vec(),
+    }
+}
+
+// `member` plus `co_signers` must reach the council threshold
+pub fn council_veto(
+    member: &Pubkey,
+    admin: &Pubkey,
+    proposal_id: u64,
+    co_signers: &[Pubkey],
+) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (council, _) = council_address(&config);
+    let (proposal, _) = proposal_address(&config, proposal_id);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*member, true),
+        AccountMeta::new_readonly(*admin, false),
+        AccountMeta::new_readonly(config, false),
+        AccountMeta::new_readonly(council, false),
+        AccountMeta::new(proposal, false),
+    ];
+    council_co_signers(&mut accounts, co_signers);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data: DataWriter::anchor("council_veto").into_vec(),
+    }
+}
+
+pub fn delegate_votes(delegator: &Pubkey, admin: &Pubkey, delegate: &Pubkey) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (delegator_profile, _) = user_profile_address(delegator);
+    let (delegate_profile, _) = user_profile_address(delegate);
+    let (delegation, _) = delegation_address(delegator);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*delegator, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(delegator_profile, false),
+            AccountMeta::new(delegate_profile, false),
+            AccountMeta::new(delegation, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("delegate_votes").into_vec(),
+    }
+}
+
+pub fn revoke_delegation(delegator: &Pubkey, delegate: &Pubkey) -> Instruction {
+    let (delegator_profile, _) = user_profile_address(delegator);
+    let (delegate_profile, _) = user_profile_address(delegate);
+    let (delegation, _) = delegation_address(delegator);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*delegator, true),
+            AccountMeta::new(delegator_profile, false),
+            AccountMeta::new(delegate_profile, false),
+            AccountMeta::new(delegation, false),
+        ],
+        data: DataWriter::anchor("revoke_delegation").into_vec(),
+    }
+}
+
+pub fn decay_reputation(caller: &Pubkey, admin: &Pubkey, user: &Pubkey) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (user_profile, _) = user_profile_address(user);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*caller, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(user_profile, false),
+        ],
+        data: DataWriter::anchor("decay_reputation").into_vec(),
+    }
+}
+
+pub fn change_username(user: &Pubkey, old_username: &str, new_username: &str) -> Instruction {
+    let (user_profile, _) = user_profile_address(user);
+    let (old_registry, _) = username_registry_address(old_username);
+    let (new_registry, _) = username_registry_address(new_username);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new(user_profile, false),
+            AccountMeta::new(old_registry, false),
+            AccountMeta::new(new_registry, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("change_username")
+            .string(new_username)
+            .into_vec(),
+    }
+}
+
+// None clears the profile's metadata URI
+pub fn update_profile_metadata(user: &Pubkey, metadata_uri: Option<&str>) -> Instruction {
+    let (user_profile, _) = user_profile_address(user);
+
+    let data = DataWriter::anchor("update_profile_metadata");
+    let data = match metadata_uri {
+        Some(metadata_uri) => data.u8(1).string(metadata_uri),
+        None => data.u8(0),
+    };
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new(user_profile, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: data.into_vec(),
+    }
+}
+
+pub fn close_profile(user: &Pubkey, username: &str) -> Instruction {
+    let (user_profile, _) = user_profile_address(user);
+    let (user_registry, _) = username_registry_address(username);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new(user_profile, false),
+            AccountMeta::new(user_registry, false),
+        ],
+        data: DataWriter::anchor("close_profile").into_vec(),
+    }
+}
+
+// Pass the delegate when the slashed user has an active delegation
+// Anchor treats the program ID as "None" for optional accounts
+#[allow(clippy::too_many_arguments)]
+pub fn slash_stake(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+    user: &Pubkey,
+    slash_bps: u16,
+    burn: bool,
+    delegate: Option<&Pubkey>,
+) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (treasury, _) = treasury_address(admin);
+    let (treasury_authority, _) = treasury_authority_address(&config, admin);
+    let (user_profile, _) = user_profile_address(user);
+
+    let (delegation, delegate_profile) = match delegate {
+        Some(delegate) => (delegation_address(user).0, user_profile_address(delegate).0),
+        None => (PROGRAM_ID, PROGRAM_ID),
+    };
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new_readonly(treasury_authority, false),
+            AccountMeta::new(user_profile, false),
+            AccountMeta::new(delegation, false),
+            AccountMeta::new(delegate_profile, false),
+            AccountMeta::new(*token_mint, false),
+            AccountMeta::new(treasury_token_address(admin, token_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("slash_stake")
+            .u16(slash_bps)
+            .bool(burn)
+            .into_vec(),
+    }
+}
+
+// `season` must be the config's current season
+pub fn close_season(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    season: u64,
+    reward_pool: u64,
+) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (treasury, _) = treasury_address(admin);
+    let (season_snapshot, _) = season_snapshot_address(&config, season);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(treasury, false),
+            AccountMeta::new(season_snapshot, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("close_season")
+            .u64(reward_pool)
+            .into_vec(),
+    }
+}
+
+pub fn claim_season_reward(
+    user: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+    season_id: u64,
+) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (treasury, _) = treasury_address(admin);
+    let (treasury_authority, _) = treasury_authority_address(&config, admin);
+    let (season_snapshot, _) = season_snapshot_address(&config, season_id);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(treasury, false),
+            AccountMeta::new_readonly(treasury_authority, false),
+            AccountMeta::new(season_snapshot, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new(
+                associated_token_address(user, token_mint, &TOKEN_PROGRAM_ID),
+                false,
+            ),
+            AccountMeta::new(treasury_token_address(admin, token_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("claim_season_reward")
+            .u64(season_id)
+            .into_vec(),
+    }
+}
+
+// Only records from a season before the config's current season can be closed
+pub fn close_vote_record(voter: &Pubkey, admin: &Pubkey, target_username: &str) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (vote_record, _) = vote_record_address(voter, target_username);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*voter, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(vote_record, false),
+        ],
+        data: DataWriter::anchor("close_vote_record")
+            .string(target_username)
+            .into_vec(),
+    }
+}
+
+pub fn close_cooldown(voter: &Pubkey, admin: &Pubkey) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (vote_cooldown, _) = vote_cooldown_address(voter);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*voter, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(vote_cooldown, false),
+        ],
+        data: DataWriter::anchor("close_cooldown").into_vec(),
+    }
+}
+
+// Locks base-mint tokens in the user's vote escrow for `duration` seconds
+pub fn lock_tokens(
+    user: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+    amount: u64,
+    duration: i64,
+) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (vote_escrow, _) = vote_escrow_address(&config, user);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(vote_escrow, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new(
+                associated_token_address(user, token_mint, &TOKEN_PROGRAM_ID),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(&vote_escrow, token_mint, &TOKEN_PROGRAM_ID),
+                false,
+            ),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("lock_tokens")
+            .u64(amount)
+            .i64(duration)
+            .into_vec(),
+    }
+}
+
+// Moves a live lock's unlock time to now + `duration`
+pub fn extend_lock(user: &Pubkey, admin: &Pubkey, duration: i64) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (vote_escrow, _) = vote_escrow_address(&config, user);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*user, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(vote_escrow, false),
+        ],
+        data: DataWriter::anchor("extend_lock").i64(duration).into_vec(),
+    }
+}
+
+pub fn withdraw_expired_lock(user: &Pubkey, admin: &Pubkey, token_mint: &Pubkey) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (vote_escrow, _) = vote_escrow_address(&config, user);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(vote_escrow, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new(
+                associated_token_address(user, token_mint, &TOKEN_PROGRAM_ID),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(&vote_escrow, token_mint, &TOKEN_PROGRAM_ID),
+                false,
+            ),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("withdraw_expired_lock").into_vec(),
+    }
+}
+
+// Registers `token_mint` as a treasury holding priced by `price_feed`, a feed
+// of `oracle_program`; `authority` must be the admin
+pub fn register_treasury_holding(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+    oracle_program: &Pubkey,
+    price_feed: &Pubkey,
+) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (treasury, _) = treasury_address(admin);
+    let (treasury_authority, _) = treasury_authority_address(&config, admin);
+    let (treasury_holding, _) = treasury_holding_address(&treasury, token_mint);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new_readonly(treasury_authority, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new(treasury_holding, false),
+            AccountMeta::new(treasury_token_address(admin, token_mint), false),
+            AccountMeta::new_readonly(*price_feed, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("register_treasury_holding")
+            .pubkey(oracle_program)
+            .into_vec(),
+    }
+}
+
+// Read-only portfolio valuation; `holdings` lists every registered
+// (mint, price_feed) in holding index order
+pub fn treasury_value_report(admin: &Pubkey, holdings: &[(Pubkey, Pubkey)]) -> Instruction {
+    let (config, _) = config_address(admin);
+    let (treasury, _) = treasury_address(admin);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*admin, false),
+        AccountMeta::new_readonly(config, false),
+        AccountMeta::new_readonly(treasury, false),
+    ];
+    for (mint, price_feed) in holdings {
+        accounts.push(AccountMeta::new_readonly(
+            treasury_holding_address(&treasury, mint).0,
+            false,
+        ));
+        accounts.push(AccountMeta::new_readonly(
+            treasury_token_address(admin, mint),
+            false,
+        ));
+        accounts.push(AccountMeta::new_readonly(*price_feed, false));
+        accounts.push(AccountMeta::new_readonly(
+            supported_mint_address(&config, mint).0,
+            false,
+        ));
+    }
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data: DataWriter::anchor("treasury_value_report").into_vec(),
+    }
+}
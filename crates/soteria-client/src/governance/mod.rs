@@ -0,0 +1,28 @@
+// Governance (g-secure)
+//
+// Reputation DAO: staked profiles, upvotes/downvotes with cooldowns,
+// multi-mint weighted staking, stake-weighted proposals with relayed
+// signed vote batches, vote-escrowed locks, delegation, slashing,
+// seasonal rewards and an oracle-priced treasury portfolio.
+// Every DAO account is keyed by the admin the config was created for.
+
+use solana_sdk::{pubkey, pubkey::Pubkey};
+
+pub mod accounts;
+pub mod instructions;
+pub mod pda;
+
+pub use accounts::*;
+pub use pda::*;
+
+pub const PROGRAM_ID: Pubkey = pubkey!("5TwUNSWhJ4AwYNnaP5APZyxBXW4usurdbfkCQCCRfAMP");
+
+// Voting window for governance proposals (PROPOSAL_VOTING_PERIOD_SECONDS)
+pub const PROPOSAL_VOTING_PERIOD_SECONDS: i64 = 3 * 24 * 3600;
+
+// Vote escrow lock bounds (MIN_LOCK_SECONDS / MAX_LOCK_SECONDS)
+pub const MIN_LOCK_SECONDS: i64 = 7 * 24 * 3600;
+pub const MAX_LOCK_SECONDS: i64 = 4 * 365 * 24 * 3600;
+
+// Fixed-point decimals of treasury_value_report values (TREASURY_VALUE_DECIMALS)
+pub const TREASURY_VALUE_DECIMALS: u32 = 6;
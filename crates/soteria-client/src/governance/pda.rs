@@ -0,0 +1,160 @@
+// Governance PDAs
+//
+// Seeds match constants.rs. The treasury token account is the treasury
+// authority's associated token account for the DAO mint (or for a supported
+// mint, which gets its own).
+
+use solana_sdk::pubkey::Pubkey;
+
+use super::PROGRAM_ID;
+use crate::token::{associated_token_address, TOKEN_PROGRAM_ID};
+
+pub const CONFIG_SEED: &[u8] = b"config";
+pub const TREASURY_SEED: &[u8] = b"treasury";
+pub const TREASURY_AUTH_SEED: &[u8] = b"treasury_auth";
+pub const USER_PROFILE_SEED: &[u8] = b"user_profile";
+pub const USER_REGISTRY_SEED: &[u8] = b"user_registry";
+pub const VOTE_COOLDOWN_SEED: &[u8] = b"cooldown";
+pub const VOTE_RECORD_SEED: &[u8] = b"vote_record";
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+pub const BALLOT_SEED: &[u8] = b"ballot";
+pub const UNBONDING_TICKET_SEED: &[u8] = b"unbonding";
+pub const DELEGATION_SEED: &[u8] = b"delegation";
+pub const VOTE_HISTORY_SEED: &[u8] = b"vote_history";
+pub const SEASON_SNAPSHOT_SEED: &[u8] = b"season_snapshot";
+pub const SUPPORTED_MINT_SEED: &[u8] = b"supported_mint";
+pub const MINT_STAKE_SEED: &[u8] = b"mint_stake";
+pub const MULTISIG_ACTION_SEED: &[u8] = b"multisig_action";
+pub const MULTISIG_EXECUTOR_SEED: &[u8] = b"multisig_executor";
+pub const COUNCIL_SEED: &[u8] = b"council";
+pub const VOTE_ESCROW_SEED: &[u8] = b"vote_escrow";
+pub const TREASURY_HOLDING_SEED: &[u8] = b"treasury_holding";
+pub const PROPOSAL_DEPOSIT_SEED: &[u8] = b"proposal_deposit";
+
+pub fn config_address(admin: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_SEED, admin.as_ref()], &PROGRAM_ID)
+}
+
+pub fn treasury_address(admin: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TREASURY_SEED, admin.as_ref()], &PROGRAM_ID)
+}
+
+pub fn treasury_authority_address(config: &Pubkey, admin: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[TREASURY_AUTH_SEED, config.as_ref(), admin.as_ref()],
+        &PROGRAM_ID,
+    )
+}
+
+// Token account holding staked tokens for the DAO created by `admin`
+pub fn treasury_token_address(admin: &Pubkey, token_mint: &Pubkey) -> Pubkey {
+    let (config, _) = config_address(admin);
+    let (treasury_authority, _) = treasury_authority_address(&config, admin);
+    associated_token_address(&treasury_authority, token_mint, &TOKEN_PROGRAM_ID)
+}
+
+pub fn user_profile_address(user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[USER_PROFILE_SEED, user.as_ref()], &PROGRAM_ID)
+}
+
+pub fn username_registry_address(username: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[USER_REGISTRY_SEED, username.as_bytes()], &PROGRAM_ID)
+}
+
+pub fn vote_cooldown_address(voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VOTE_COOLDOWN_SEED, voter.as_ref()], &PROGRAM_ID)
+}
+
+pub fn vote_record_address(voter: &Pubkey, target_username: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VOTE_RECORD_SEED, voter.as_ref(), target_username.as_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+pub fn proposal_address(config: &Pubkey, proposal_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PROPOSAL_SEED, config.as_ref(), &proposal_id.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+pub fn ballot_address(proposal: &Pubkey, voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[BALLOT_SEED, proposal.as_ref(), voter.as_ref()],
+        &PROGRAM_ID,
+    )
+}
+
+pub fn unbonding_ticket_address(user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[UNBONDING_TICKET_SEED, user.as_ref()], &PROGRAM_ID)
+}
+
+pub fn delegation_address(delegator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DELEGATION_SEED, delegator.as_ref()], &PROGRAM_ID)
+}
+
+pub fn vote_history_address(voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VOTE_HISTORY_SEED, voter.as_ref()], &PROGRAM_ID)
+}
+
+pub fn season_snapshot_address(config: &Pubkey, season_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEASON_SNAPSHOT_SEED,
+            config.as_ref(),
+            &season_id.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    )
+}
+
+pub fn supported_mint_address(config: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SUPPORTED_MINT_SEED, config.as_ref(), mint.as_ref()],
+        &PROGRAM_ID,
+    )
+}
+
+pub fn mint_stake_address(config: &Pubkey, mint: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MINT_STAKE_SEED, config.as_ref(), mint.as_ref(), user.as_ref()],
+        &PROGRAM_ID,
+    )
+}
+
+pub fn multisig_action_address(proposal: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MULTISIG_ACTION_SEED, proposal.as_ref()], &PROGRAM_ID)
+}
+
+// The DAO's member key on a multisig; proposer of relayed multisig proposals
+pub fn multisig_executor_address(config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MULTISIG_EXECUTOR_SEED, config.as_ref()], &PROGRAM_ID)
+}
+
+// Emergency council of a DAO; at most one per config
+pub fn council_address(config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COUNCIL_SEED, config.as_ref()], &PROGRAM_ID)
+}
+
+// A user's vote escrow in one DAO; its token account is the escrow's ATA
+pub fn vote_escrow_address(config: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VOTE_ESCROW_SEED, config.as_ref(), user.as_ref()],
+        &PROGRAM_ID,
+    )
+}
+
+// A treasury's registry entry for one held mint; its tokens sit in
+// treasury_token_address(admin, mint)
+pub fn treasury_holding_address(treasury: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[TREASURY_HOLDING_SEED, treasury.as_ref(), mint.as_ref()],
+        &PROGRAM_ID,
+    )
+}
+
+// The deposit escrowed for a proposal; its tokens sit in the deposit's ATA
+pub fn proposal_deposit_address(proposal: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROPOSAL_DEPOSIT_SEED, proposal.as_ref()], &PROGRAM_ID)
+}
@@ -0,0 +1,20 @@
+// Soteria Client
+//
+// Off-chain SDK for the secure programs. Each program module provides:
+// 1. pda - Seeds and PDA derivation matching the program's constants.rs
+// 2. instructions - Builders that return a ready-to-sign Instruction
+// 3. accounts - Decoders that turn raw account data into typed structs
+//
+// The crate depends only on solana-sdk types, so the same builders work from
+// LiteSVM tests and from bots talking to an RPC node.
+
+pub mod codec;
+pub mod token;
+
+pub mod amm;
+pub mod escrow;
+pub mod governance;
+pub mod multisig;
+pub mod nft_staking;
+
+pub use codec::{account_discriminator, instruction_discriminator, DecodeError};
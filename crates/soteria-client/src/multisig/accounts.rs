@@ -0,0 +1,542 @@
+// Multisig Accounts
+//
+// Mirrors state/*.rs. Enums use Borsh variant order, so the tag values below
+// must stay in the same order as the program's enum declarations.
+
+use sha2::{Digest, Sha256};
+use solana_sdk::pubkey::Pubkey;
+
+use super::{LOOKUP_TABLE_ADDRESSES_DOMAIN, MAX_OWNERS, PROPOSAL_HASH_DOMAIN};
+use crate::codec::{DataReader, DataWriter, DecodeError};
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemberRole {
+    Admin = 0,
+    Proposer = 1,
+    Executor = 2,
+}
+
+impl MemberRole {
+    fn read(reader: &mut DataReader) -> Result<Self, DecodeError> {
+        match reader.u8()? {
+            0 => Ok(MemberRole::Admin),
+            1 => Ok(MemberRole::Proposer),
+            2 => Ok(MemberRole::Executor),
+            tag => Err(DecodeError::InvalidEnumTag {
+                name: "MemberRole",
+                tag,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Member {
+    pub pubkey: Pubkey,
+    pub role: MemberRole,
+}
+
+// Per-action thresholds (state/threshold_policy.rs); 0 = the default threshold
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ThresholdPolicy {
+    pub member_changes: u8,
+    pub settings_changes: u8,
+    pub vault_operations: u8,
+    pub small_transfers: u8,
+    pub transfers: u8,
+    pub small_transfer_limit: u64,
+}
+
+impl ThresholdPolicy {
+    fn write(&self, writer: DataWriter) -> DataWriter {
+        writer
+            .u8(self.member_changes)
+            .u8(self.settings_changes)
+            .u8(self.vault_operations)
+            .u8(self.small_transfers)
+            .u8(self.transfers)
+            .u64(self.small_transfer_limit)
+    }
+
+    fn read(reader: &mut DataReader) -> Result<Self, DecodeError> {
+        Ok(Self {
+            member_changes: reader.u8()?,
+            settings_changes: reader.u8()?,
+            vault_operations: reader.u8()?,
+            small_transfers: reader.u8()?,
+            transfers: reader.u8()?,
+            small_transfer_limit: reader.u64()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalType {
+    AddMember {
+        new_member: Pubkey,
+        role: MemberRole,
+    },
+    RemoveMember {
+        member_to_remove: Pubkey,
+    },
+    ChangeThreshold {
+        new_threshold: u8,
+    },
+    ChangeTimelock {
+        new_timelock: u64,
+    },
+    ApproveParentProposal {
+        parent_multisig: Pubkey,
+        parent_proposal_id: u64,
+    },
+    SetVaultPaysRent {
+        enabled: bool,
+    },
+    DelegateVaultStake {
+        vote_account: Pubkey,
+        amount: u64,
+    },
+    DeactivateVaultStake {
+        stake_account: Pubkey,
+    },
+    WithdrawVaultStake {
+        stake_account: Pubkey,
+    },
+    SweepToken {
+        mint: Pubkey,
+        recipient: Pubkey,
+    },
+    CreateLookupTable,
+    ExtendLookupTable {
+        lookup_table: Pubkey,
+        addresses_hash: [u8; 32],
+        address_count: u8,
+    },
+    SetThresholdPolicy {
+        policy: ThresholdPolicy,
+    },
+}
+
+impl ProposalType {
+    pub(crate) fn write(&self, writer: DataWriter) -> DataWriter {
+        match self {
+            ProposalType::AddMember { new_member, role } => {
+                writer.u8(0).pubkey(new_member).u8(*role as u8)
+            }
+            ProposalType::RemoveMember { member_to_remove } => {
+                writer.u8(1).pubkey(member_to_remove)
+            }
+            ProposalType::ChangeThreshold { new_threshold } => writer.u8(2).u8(*new_threshold),
+            ProposalType::ChangeTimelock { new_timelock } => writer.u8(3).u64(*new_timelock),
+            ProposalType::ApproveParentProposal {
+                parent_multisig,
+                parent_proposal_id,
+            } => writer.u8(4).pubkey(parent_multisig).u64(*parent_proposal_id),
+            ProposalType::SetVaultPaysRent { enabled } => writer.u8(5).bool(*enabled),
+            ProposalType::DelegateVaultStake {
+                vote_account,
+                amount,
+            } => writer.u8(6).pubkey(vote_account).u64(*amount),
+            ProposalType::DeactivateVaultStake { stake_account } => {
+                writer.u8(7).pubkey(stake_account)
+            }
+            ProposalType::WithdrawVaultStake { stake_account } => {
+                writer.u8(8).pubkey(stake_account)
+            }
+            ProposalType::SweepToken { mint, recipient } => {
+                writer.u8(9).pubkey(mint).pubkey(recipient)
+            }
+            ProposalType::CreateLookupTable => writer.u8(10),
+            ProposalType::ExtendLookupTable {
+                lookup_table,
+                addresses_hash,
+                address_count,
+            } => writer
+                .u8(11)
+                .pubkey(lookup_table)
+                .bytes(addresses_hash)
+                .u8(*address_count),
+            ProposalType::SetThresholdPolicy { policy } => policy.write(writer.u8(12)),
+        }
+    }
+
+    // Canonical proposal hash (Proposal::proposal_hash in state/proposal.rs):
+    // SHA-256 over the domain, this type's Borsh encoding, the multisig and
+    // the proposal id. A signer computes it from what they mean to approve and
+    // compares it with the proposal account before signing.
+    pub fn proposal_hash(&self, multisig: &Pubkey, proposal_id: u64) -> [u8; 32] {
+        let action = self.write(DataWriter::empty()).into_vec();
+        let mut hasher = Sha256::new();
+        hasher.update(PROPOSAL_HASH_DOMAIN);
+        hasher.update(&action);
+        hasher.update(multisig.as_ref());
+        hasher.update(proposal_id.to_le_bytes());
+        hasher.finalize().into()
+    }
+
+    // ExtendLookupTable for `addresses`, committing to the exact list
+    pub fn extend_lookup_table(lookup_table: Pubkey, addresses: &[Pubkey]) -> Self {
+        ProposalType::ExtendLookupTable {
+            lookup_table,
+            addresses_hash: lookup_table_addresses_hash(addresses),
+            address_count: addresses.len() as u8,
+        }
+    }
+
+    pub(crate) fn read(reader: &mut DataReader) -> Result<Self, DecodeError> {
+        match reader.u8()? {
+            0 => Ok(ProposalType::AddMember {
+                new_member: reader.pubkey()?,
+                role: MemberRole::read(reader)?,
+            }),
+            1 => Ok(ProposalType::RemoveMember {
+                member_to_remove: reader.pubkey()?,
+            }),
+            2 => Ok(ProposalType::ChangeThreshold {
+                new_threshold: reader.u8()?,
+            }),
+            3 => Ok(ProposalType::ChangeTimelock {
+                new_timelock: reader.u64()?,
+            }),
+            4 => Ok(ProposalType::ApproveParentProposal {
+                parent_multisig: reader.pubkey()?,
+                parent_proposal_id: reader.u64()?,
+            }),
+            5 => Ok(ProposalType::SetVaultPaysRent {
+                enabled: reader.bool()?,
+            }),
+            6 => Ok(ProposalType::DelegateVaultStake {
+                vote_account: reader.pubkey()?,
+                amount: reader.u64()?,
+            }),
+            7 => Ok(ProposalType::DeactivateVaultStake {
+                stake_account: reader.pubkey()?,
+            }),
+            8 => Ok(ProposalType::WithdrawVaultStake {
+                stake_account: reader.pubkey()?,
+            }),
+            9 => Ok(ProposalType::SweepToken {
+                mint: reader.pubkey()?,
+                recipient: reader.pubkey()?,
+            }),
+            10 => Ok(ProposalType::CreateLookupTable),
+            11 => Ok(ProposalType::ExtendLookupTable {
+                lookup_table: reader.pubkey()?,
+                addresses_hash: reader.array()?,
+                address_count: reader.u8()?,
+            }),
+            12 => Ok(ProposalType::SetThresholdPolicy {
+                policy: ThresholdPolicy::read(reader)?,
+            }),
+            tag => Err(DecodeError::InvalidEnumTag {
+                name: "ProposalType",
+                tag,
+            }),
+        }
+    }
+}
+
+// Address list hash stored by ExtendLookupTable (lookup_table_addresses_hash in
+// state/proposal.rs): SHA-256 over the domain and the addresses in order
+pub fn lookup_table_addresses_hash(addresses: &[Pubkey]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(LOOKUP_TABLE_ADDRESSES_DOMAIN);
+    for address in addresses {
+        hasher.update(address.as_ref());
+    }
+    hasher.finalize().into()
+}
+
+// create_transfer_proposal argument, stored on the TransferProposal:
+// Wallet recipients must be system-owned, ProgramVault recipients must be
+// owned by the named program when the transfer executes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecipientKind {
+    Wallet,
+    ProgramVault { owner: Pubkey },
+}
+
+impl RecipientKind {
+    pub(crate) fn write(&self, writer: DataWriter) -> DataWriter {
+        match self {
+            RecipientKind::Wallet => writer.u8(0),
+            RecipientKind::ProgramVault { owner } => writer.u8(1).pubkey(owner),
+        }
+    }
+
+    fn read(reader: &mut DataReader) -> Result<Self, DecodeError> {
+        match reader.u8()? {
+            0 => Ok(RecipientKind::Wallet),
+            1 => Ok(RecipientKind::ProgramVault {
+                owner: reader.pubkey()?,
+            }),
+            tag => Err(DecodeError::InvalidEnumTag {
+                name: "RecipientKind",
+                tag,
+            }),
+        }
+    }
+}
+
+// execute_transfer_proposal argument: lamports the vault must lose and the
+// recipient must gain, checked by the program after the transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpectedOutcome {
+    pub vault_delta: u64,
+    pub recipient_delta: u64,
+}
+
+impl ExpectedOutcome {
+    // Borsh Option<ExpectedOutcome>: 0 for None, 1 followed by both deltas
+    pub(crate) fn write_option(outcome: Option<&Self>, writer: DataWriter) -> DataWriter {
+        match outcome {
+            Some(outcome) => writer
+                .u8(1)
+                .u64(outcome.vault_delta)
+                .u64(outcome.recipient_delta),
+            None => writer.u8(0),
+        }
+    }
+}
+
+// validate_proposal return value: the MultisigError codes execute_transfer_proposal
+// would fail with right now, in check order. Read from the transaction's return data
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProposalValidation {
+    pub errors: Vec<u32>,
+}
+
+impl ProposalValidation {
+    // Return data is plain Borsh, without an account discriminator
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::new(data);
+        Ok(Self {
+            errors: reader.vec(|r| r.u32())?,
+        })
+    }
+
+    pub fn is_executable(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalStatus {
+    Active,
+    Executed,
+    Cancelled,
+}
+
+impl ProposalStatus {
+    fn read(reader: &mut DataReader) -> Result<Self, DecodeError> {
+        match reader.u8()? {
+            0 => Ok(ProposalStatus::Active),
+            1 => Ok(ProposalStatus::Executed),
+            2 => Ok(ProposalStatus::Cancelled),
+            tag => Err(DecodeError::InvalidEnumTag {
+                name: "ProposalStatus",
+                tag,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Multisig {
+    pub multisig_id: u64,
+    pub creator: Pubkey,
+    pub threshold: u8,
+    pub owner_count: u8,
+    pub members: [Member; MAX_OWNERS],
+    pub proposal_count: u64,
+    pub last_executed_proposal: u64,
+    pub paused: bool,
+    pub timelock_seconds: u64,
+    pub vault: Pubkey,
+    pub bump: u8,
+    pub vault_bump: u8,
+    pub vault_pays_rent: bool,
+    pub threshold_policy: ThresholdPolicy,
+}
+
+impl Multisig {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "Multisig")?;
+        let multisig_id = reader.u64()?;
+        let creator = reader.pubkey()?;
+        let threshold = reader.u8()?;
+        let owner_count = reader.u8()?;
+        let mut members = [Member {
+            pubkey: Pubkey::default(),
+            role: MemberRole::Executor,
+        }; MAX_OWNERS];
+        for member in members.iter_mut() {
+            member.pubkey = reader.pubkey()?;
+            member.role = MemberRole::read(&mut reader)?;
+        }
+        Ok(Self {
+            multisig_id,
+            creator,
+            threshold,
+            owner_count,
+            members,
+            proposal_count: reader.u64()?,
+            last_executed_proposal: reader.u64()?,
+            paused: reader.bool()?,
+            timelock_seconds: reader.u64()?,
+            vault: reader.pubkey()?,
+            bump: reader.u8()?,
+            vault_bump: reader.u8()?,
+            vault_pays_rent: reader.bool()?,
+            threshold_policy: ThresholdPolicy::read(&mut reader)?,
+        })
+    }
+
+    // Members in use (the array is padded to MAX_OWNERS)
+    pub fn active_members(&self) -> &[Member] {
+        &self.members[..(self.owner_count as usize).min(MAX_OWNERS)]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Proposal {
+    pub multisig: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub proposal_type: ProposalType,
+    pub status: ProposalStatus,
+    pub approval_bitmap: u64,
+    pub approval_count: u8,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub executed_at: i64,
+    pub rent_payer: Pubkey,
+    pub bump: u8,
+    pub proposal_hash: [u8; 32],
+    pub title: String,
+    pub memo: String,
+}
+
+impl Proposal {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "Proposal")?;
+        Ok(Self {
+            multisig: reader.pubkey()?,
+            proposal_id: reader.u64()?,
+            proposer: reader.pubkey()?,
+            proposal_type: ProposalType::read(&mut reader)?,
+            status: ProposalStatus::read(&mut reader)?,
+            approval_bitmap: reader.u64()?,
+            approval_count: reader.u8()?,
+            created_at: reader.i64()?,
+            expires_at: reader.i64()?,
+            executed_at: reader.i64()?,
+            rent_payer: reader.pubkey()?,
+            bump: reader.u8()?,
+            proposal_hash: reader.array()?,
+            title: reader.string()?,
+            memo: reader.string()?,
+        })
+    }
+
+    // The stored hash matches this proposal's parameters
+    pub fn has_valid_hash(&self) -> bool {
+        self.proposal_type.proposal_hash(&self.multisig, self.proposal_id) == self.proposal_hash
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferProposal {
+    pub multisig: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub status: ProposalStatus,
+    pub approval_bitmap: u64,
+    pub approval_count: u8,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub executed_at: i64,
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub recipient_kind: RecipientKind,
+    pub rent_payer: Pubkey,
+    pub bump: u8,
+}
+
+impl TransferProposal {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "TransferProposal")?;
+        Ok(Self {
+            multisig: reader.pubkey()?,
+            proposal_id: reader.u64()?,
+            proposer: reader.pubkey()?,
+            status: ProposalStatus::read(&mut reader)?,
+            approval_bitmap: reader.u64()?,
+            approval_count: reader.u8()?,
+            created_at: reader.i64()?,
+            expires_at: reader.i64()?,
+            executed_at: reader.i64()?,
+            amount: reader.u64()?,
+            recipient: reader.pubkey()?,
+            recipient_kind: RecipientKind::read(&mut reader)?,
+            rent_payer: reader.pubkey()?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+// Zero-copy MultisigLarge (state/multisig_large.rs): a repr(C) header followed
+// by member_capacity 33-byte slots, read positionally rather than via Borsh
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultisigLarge {
+    pub creator: Pubkey,
+    pub multisig_id: u64,
+    pub timelock_seconds: u64,
+    pub proposal_count: u64,
+    pub member_count: u16,
+    pub member_capacity: u16,
+    pub threshold: u8,
+    pub paused: bool,
+    pub bump: u8,
+    // Active members only; unused slots are not decoded
+    pub members: Vec<Member>,
+}
+
+impl MultisigLarge {
+    // Discriminator + header bytes before the member slab
+    pub const HEADER_SPACE: usize = 8 + 64;
+
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "MultisigLarge")?;
+        let creator = reader.pubkey()?;
+        let multisig_id = reader.u64()?;
+        let timelock_seconds = reader.u64()?;
+        let proposal_count = reader.u64()?;
+        let member_count = reader.u16()?;
+        let member_capacity = reader.u16()?;
+        let threshold = reader.u8()?;
+        let paused = reader.u8()? == 1;
+        let bump = reader.u8()?;
+        reader.u8()?; // _padding
+        let mut members = Vec::with_capacity(member_count as usize);
+        for _ in 0..member_count {
+            members.push(Member {
+                pubkey: reader.pubkey()?,
+                role: MemberRole::read(&mut reader)?,
+            });
+        }
+        Ok(Self {
+            creator,
+            multisig_id,
+            timelock_seconds,
+            proposal_count,
+            member_count,
+            member_capacity,
+            threshold,
+            paused,
+            bump,
+            members,
+        })
+    }
+}
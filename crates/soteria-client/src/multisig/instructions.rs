@@ -0,0 +1,594 @@
+// Multisig Instructions
+//
+// Account order follows each #[derive(Accounts)] struct in the program.
+// Builders take addresses rather than deriving them so callers can target
+// any multisig, including ones they only know by address.
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+
+use super::{
+    accounts::{ExpectedOutcome, MemberRole, ProposalType, RecipientKind},
+    ADDRESS_LOOKUP_TABLE_PROGRAM_ID, PROGRAM_ID, STAKE_CONFIG_ID, STAKE_PROGRAM_ID,
+    SYSVAR_CLOCK_ID, SYSVAR_RENT_ID, SYSVAR_STAKE_HISTORY_ID,
+};
+use crate::{codec::DataWriter, token::associated_token_address};
+
+pub fn create_multisig(
+    creator: &Pubkey,
+    multisig: &Pubkey,
+    vault: &Pubkey,
+    multisig_id: u64,
+    threshold: u8,
+    timelock_seconds: u64,
+) -> Instruction {
+    let data = DataWriter::anchor("create_multisig")
+        .u64(multisig_id)
+        .u8(threshold)
+        .u64(timelock_seconds)
+        .into_vec();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*creator, true),
+            AccountMeta::new(*multisig, false),
+            AccountMeta::new(*vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Optional rent_payer account: the program ID stands in for None, otherwise the
+// payer is writable and signs unless it is the vault (which signs via seeds)
+fn rent_payer_meta(rent_payer: Option<(&Pubkey, bool)>) -> AccountMeta {
+    match rent_payer {
+        Some((rent_payer, is_signer)) => AccountMeta::new(*rent_payer, is_signer),
+        None => AccountMeta::new_readonly(PROGRAM_ID, false),
+    }
+}
+
+// The proposer pays the proposal's rent
+pub fn create_proposal(
+    proposer: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+    proposal_type: ProposalType,
+) -> Instruction {
+    build_create_proposal(proposer, multisig, proposal, proposal_type, None)
+}
+
+// rent_payer reimburses the proposer: pass the vault (requires vault_pays_rent)
+// with is_signer = false, or a sponsor that signs the transaction
+pub fn create_proposal_with_rent_payer(
+    proposer: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+    proposal_type: ProposalType,
+    rent_payer: &Pubkey,
+    is_signer: bool,
+) -> Instruction {
+    build_create_proposal(
+        proposer,
+        multisig,
+        proposal,
+        proposal_type,
+        Some((rent_payer, is_signer)),
+    )
+}
+
+fn build_create_proposal(
+    proposer: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+    proposal_type: ProposalType,
+    rent_payer: Option<(&Pubkey, bool)>,
+) -> Instruction {
+    let data = proposal_type
+        .write(DataWriter::anchor("create_proposal"))
+        .into_vec();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*proposer, true),
+            AccountMeta::new(*multisig, false),
+            AccountMeta::new(*proposal, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            rent_payer_meta(rent_payer),
+        ],
+        data,
+    }
+}
+
+pub fn approve_proposal(owner: &Pubkey, multisig: &Pubkey, proposal: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(*proposal, false),
+        ],
+        data: DataWriter::anchor("approve_proposal").into_vec(),
+    }
+}
+
+// child_multisig approves proposal on multisig by executing child_proposal,
+// an ApproveParentProposal; child_rent_payer receives child_proposal's rent
+pub fn approve_as_multisig(
+    executor: &Pubkey,
+    child_multisig: &Pubkey,
+    child_proposal: &Pubkey,
+    child_rent_payer: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*executor, true),
+            AccountMeta::new(*child_multisig, false),
+            AccountMeta::new(*child_proposal, false),
+            AccountMeta::new(*child_rent_payer, false),
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(*proposal, false),
+        ],
+        data: DataWriter::anchor("approve_as_multisig").into_vec(),
+    }
+}
+
+// rent_payer (Proposal::rent_payer) receives the proposal account's rent when it closes
+pub fn execute_proposal(
+    executor: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+    rent_payer: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*executor, true),
+            AccountMeta::new(*multisig, false),
+            AccountMeta::new(*proposal, false),
+            AccountMeta::new(*rent_payer, false),
+        ],
+        data: DataWriter::anchor("execute_proposal").into_vec(),
+    }
+}
+
+pub fn cancel_proposal(
+    canceller: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+    rent_payer: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*canceller, true),
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(*proposal, false),
+            AccountMeta::new(*rent_payer, false),
+        ],
+        data: DataWriter::anchor("cancel_proposal").into_vec(),
+    }
+}
+
+// A None title keeps the current one; memo is appended to the proposal's memo
+// The proposer pays the rent for the extra bytes
+pub fn attach_memo(
+    proposer: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+    title: Option<&str>,
+    memo: &str,
+) -> Instruction {
+    let data = DataWriter::anchor("attach_memo");
+    let data = match title {
+        Some(title) => data.u8(1).string(title),
+        None => data.u8(0),
+    };
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*proposer, true),
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(*proposal, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: data.string(memo).into_vec(),
+    }
+}
+
+pub fn create_transfer_proposal(
+    proposer: &Pubkey,
+    multisig: &Pubkey,
+    transfer_proposal: &Pubkey,
+    amount: u64,
+    recipient: &Pubkey,
+) -> Instruction {
+    build_create_transfer_proposal(
+        proposer,
+        multisig,
+        transfer_proposal,
+        amount,
+        recipient,
+        RecipientKind::Wallet,
+        None,
+    )
+}
+
+// Opts in to a program-owned recipient: execution requires the recipient to be
+// owned by `vault_owner` instead of the System Program
+pub fn create_program_vault_transfer_proposal(
+    proposer: &Pubkey,
+    multisig: &Pubkey,
+    transfer_proposal: &Pubkey,
+    amount: u64,
+    recipient: &Pubkey,
+    vault_owner: &Pubkey,
+) -> Instruction {
+    build_create_transfer_proposal(
+        proposer,
+        multisig,
+        transfer_proposal,
+        amount,
+        recipient,
+        RecipientKind::ProgramVault {
+            owner: *vault_owner,
+        },
+        None,
+    )
+}
+
+// See create_proposal_with_rent_payer
+pub fn create_transfer_proposal_with_rent_payer(
+    proposer: &Pubkey,
+    multisig: &Pubkey,
+    transfer_proposal: &Pubkey,
+    amount: u64,
+    recipient: &Pubkey,
+    rent_payer: &Pubkey,
+    is_signer: bool,
+) -> Instruction {
+    build_create_transfer_proposal(
+        proposer,
+        multisig,
+        transfer_proposal,
+        amount,
+        recipient,
+        RecipientKind::Wallet,
+        Some((rent_payer, is_signer)),
+    )
+}
+
+fn build_create_transfer_proposal(
+    proposer: &Pubkey,
+    multisig: &Pubkey,
+    transfer_proposal: &Pubkey,
+    amount: u64,
+    recipient: &Pubkey,
+    recipient_kind: RecipientKind,
+    rent_payer: Option<(&Pubkey, bool)>,
+) -> Instruction {
+    let data = recipient_kind
+        .write(
+            DataWriter::anchor("create_transfer_proposal")
+                .u64(amount)
+                .pubkey(recipient),
+        )
+        .into_vec();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*proposer, true),
+            AccountMeta::new(*multisig, false),
+            AccountMeta::new(*transfer_proposal, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            rent_payer_meta(rent_payer),
+        ],
+        data,
+    }
+}
+
+pub fn approve_transfer_proposal(
+    owner: &Pubkey,
+    multisig: &Pubkey,
+    transfer_proposal: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(*transfer_proposal, false),
+        ],
+        data: DataWriter::anchor("approve_transfer_proposal").into_vec(),
+    }
+}
+
+pub fn execute_transfer_proposal(
+    executor: &Pubkey,
+    multisig: &Pubkey,
+    transfer_proposal: &Pubkey,
+    rent_payer: &Pubkey,
+    vault: &Pubkey,
+    recipient: &Pubkey,
+) -> Instruction {
+    build_execute_transfer_proposal(
+        executor,
+        multisig,
+        transfer_proposal,
+        rent_payer,
+        vault,
+        recipient,
+        None,
+    )
+}
+
+// The program fails the transfer unless the vault and recipient balances
+// move by exactly the expected amounts
+pub fn execute_transfer_proposal_with_expected_outcome(
+    executor: &Pubkey,
+    multisig: &Pubkey,
+    transfer_proposal: &Pubkey,
+    rent_payer: &Pubkey,
+    vault: &Pubkey,
+    recipient: &Pubkey,
+    expected_outcome: &ExpectedOutcome,
+) -> Instruction {
+    build_execute_transfer_proposal(
+        executor,
+        multisig,
+        transfer_proposal,
+        rent_payer,
+        vault,
+        recipient,
+        Some(expected_outcome),
+    )
+}
+
+fn build_execute_transfer_proposal(
+    executor: &Pubkey,
+    multisig: &Pubkey,
+    transfer_proposal: &Pubkey,
+    rent_payer: &Pubkey,
+    vault: &Pubkey,
+    recipient: &Pubkey,
+    expected_outcome: Option<&ExpectedOutcome>,
+) -> Instruction {
+    let data = ExpectedOutcome::write_option(
+        expected_outcome,
+        DataWriter::anchor("execute_transfer_proposal"),
+    )
+    .into_vec();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*executor, true),
+            AccountMeta::new(*multisig, false),
+            AccountMeta::new(*transfer_proposal, false),
+            AccountMeta::new(*rent_payer, false),
+            AccountMeta::new(*vault, false),
+            AccountMeta::new(*recipient, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Read-only dry run of execute_transfer_proposal; nothing signs or is written,
+// so it can be simulated with any fee payer. Decode the return data with
+// ProposalValidation::try_from_bytes
+pub fn validate_proposal(
+    executor: &Pubkey,
+    multisig: &Pubkey,
+    transfer_proposal: &Pubkey,
+    vault: &Pubkey,
+    recipient: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*executor, false),
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new_readonly(*transfer_proposal, false),
+            AccountMeta::new_readonly(*vault, false),
+            AccountMeta::new_readonly(*recipient, false),
+        ],
+        data: DataWriter::anchor("validate_proposal").into_vec(),
+    }
+}
+
+// vote_account is only read for DelegateVaultStake; pass None for the others
+// (the program ID stands in for the missing account)
+pub fn execute_stake_proposal(
+    executor: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+    rent_payer: &Pubkey,
+    vault: &Pubkey,
+    stake_account: &Pubkey,
+    vote_account: Option<&Pubkey>,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*executor, true),
+            AccountMeta::new(*multisig, false),
+            AccountMeta::new(*proposal, false),
+            AccountMeta::new(*rent_payer, false),
+            AccountMeta::new(*vault, false),
+            AccountMeta::new(*stake_account, false),
+            AccountMeta::new_readonly(*vote_account.unwrap_or(&PROGRAM_ID), false),
+            AccountMeta::new_readonly(SYSVAR_CLOCK_ID, false),
+            AccountMeta::new_readonly(SYSVAR_RENT_ID, false),
+            AccountMeta::new_readonly(SYSVAR_STAKE_HISTORY_ID, false),
+            AccountMeta::new_readonly(STAKE_CONFIG_ID, false),
+            AccountMeta::new_readonly(STAKE_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("execute_stake_proposal").into_vec(),
+    }
+}
+
+// Token accounts are the vault's and the recipient's ATAs for the mint
+#[allow(clippy::too_many_arguments)]
+pub fn execute_sweep_proposal(
+    executor: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+    rent_payer: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    recipient: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*executor, true),
+            AccountMeta::new(*multisig, false),
+            AccountMeta::new(*proposal, false),
+            AccountMeta::new(*rent_payer, false),
+            AccountMeta::new_readonly(*vault, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(associated_token_address(vault, mint, token_program), false),
+            AccountMeta::new_readonly(*recipient, false),
+            AccountMeta::new(associated_token_address(recipient, mint, token_program), false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        data: DataWriter::anchor("execute_sweep_proposal").into_vec(),
+    }
+}
+
+// recent_slot is read by CreateLookupTable, addresses by ExtendLookupTable
+pub fn execute_lookup_table_proposal(
+    executor: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+    rent_payer: &Pubkey,
+    vault: &Pubkey,
+    lookup_table: &Pubkey,
+    recent_slot: u64,
+    addresses: &[Pubkey],
+) -> Instruction {
+    let mut writer = DataWriter::anchor("execute_lookup_table_proposal")
+        .u64(recent_slot)
+        .u32(addresses.len() as u32);
+    for address in addresses {
+        writer = writer.pubkey(address);
+    }
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*executor, true),
+            AccountMeta::new(*multisig, false),
+            AccountMeta::new(*proposal, false),
+            AccountMeta::new(*rent_payer, false),
+            AccountMeta::new(*vault, false),
+            AccountMeta::new(*lookup_table, false),
+            AccountMeta::new_readonly(ADDRESS_LOOKUP_TABLE_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: writer.into_vec(),
+    }
+}
+
+pub fn toggle_pause(admin: &Pubkey, multisig: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new(*multisig, false),
+        ],
+        data: DataWriter::anchor("toggle_pause").into_vec(),
+    }
+}
+
+pub fn create_multisig_large(
+    creator: &Pubkey,
+    multisig_large: &Pubkey,
+    multisig_id: u64,
+    timelock_seconds: u64,
+) -> Instruction {
+    let data = DataWriter::anchor("create_multisig_large")
+        .u64(multisig_id)
+        .u64(timelock_seconds)
+        .into_vec();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*creator, true),
+            AccountMeta::new(*multisig_large, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// The admin pays rent for the added slots
+pub fn grow_multisig_large(admin: &Pubkey, multisig_large: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new(*multisig_large, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("grow_multisig_large").into_vec(),
+    }
+}
+
+// Co-signers are appended as signing remaining accounts; together with the
+// admin they must reach the multisig's current threshold
+pub fn add_large_member(
+    admin: &Pubkey,
+    multisig_large: &Pubkey,
+    co_signers: &[Pubkey],
+    new_member: &Pubkey,
+    role: MemberRole,
+    new_threshold: u8,
+) -> Instruction {
+    let data = DataWriter::anchor("add_large_member")
+        .pubkey(new_member)
+        .u8(role as u8)
+        .u8(new_threshold)
+        .into_vec();
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(*multisig_large, false),
+    ];
+    accounts.extend(
+        co_signers
+            .iter()
+            .map(|co_signer| AccountMeta::new_readonly(*co_signer, true)),
+    );
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data,
+    }
+}
+
+pub fn toggle_pause_large(admin: &Pubkey, multisig_large: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new(*multisig_large, false),
+        ],
+        data: DataWriter::anchor("toggle_pause_large").into_vec(),
+    }
+}
@@ -0,0 +1,45 @@
+// Multisig (m-secure)
+//
+// M-of-N multisig with role-based members, timelocked governance proposals
+// and SOL transfer proposals paid from a vault PDA.
+
+use solana_sdk::{pubkey, pubkey::Pubkey};
+
+pub mod accounts;
+pub mod instructions;
+pub mod pda;
+
+pub use accounts::*;
+pub use pda::*;
+
+pub const PROGRAM_ID: Pubkey = pubkey!("HH8rYFiTjMX8FiiRgiFQx1jnXdT9D4TTiC5mSBhe9r7P");
+
+// Members array capacity (MAX_OWNERS in constants.rs)
+pub const MAX_OWNERS: usize = 10;
+
+// Proposal metadata bounds in bytes (constants.rs)
+pub const MAX_PROPOSAL_TITLE_LEN: usize = 64;
+pub const MAX_PROPOSAL_MEMO_LEN: usize = 128;
+
+// Proposal account size as created, before attach_memo grows it
+// (Proposal::space(0, 0); m-secure's tests assert the two agree)
+pub const PROPOSAL_SPACE: usize = 253;
+
+// Domain prefix of the canonical proposal hash (constants.rs)
+pub const PROPOSAL_HASH_DOMAIN: &[u8] = b"soteria:multisig:proposal:v1";
+
+// ExtendLookupTable address list bound and hash domain (constants.rs)
+pub const MAX_LOOKUP_TABLE_EXTEND: u8 = 20;
+pub const LOOKUP_TABLE_ADDRESSES_DOMAIN: &[u8] = b"soteria:multisig:lookup_table:v1";
+
+// Native stake program and the accounts execute_stake_proposal passes to it
+pub const STAKE_PROGRAM_ID: Pubkey = pubkey!("Stake11111111111111111111111111111111111111");
+pub const STAKE_CONFIG_ID: Pubkey = pubkey!("StakeConfig11111111111111111111111111111111");
+pub const SYSVAR_CLOCK_ID: Pubkey = pubkey!("SysvarC1ock11111111111111111111111111111111");
+pub const SYSVAR_RENT_ID: Pubkey = pubkey!("SysvarRent111111111111111111111111111111111");
+pub const SYSVAR_STAKE_HISTORY_ID: Pubkey =
+    pubkey!("SysvarStakeHistory1111111111111111111111111");
+
+// Address lookup table program, driven by execute_lookup_table_proposal
+pub const ADDRESS_LOOKUP_TABLE_PROGRAM_ID: Pubkey =
+    pubkey!("AddressLookupTab1e1111111111111111111111111");
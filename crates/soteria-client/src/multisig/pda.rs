@@ -0,0 +1,73 @@
+// Multisig PDAs
+//
+// Seeds match constants.rs:
+// - multisig: ["multisig", creator, multisig_id]
+// - vault: ["vault", multisig]
+// - proposal: ["proposal", multisig, proposal_id]
+// - transfer proposal: ["transfer", multisig, proposal_id]
+// - vault stake: ["vault_stake", multisig, proposal_id]
+// - large multisig: ["multisig_large", creator, multisig_id]
+// - vault lookup table: [vault, recent_slot] under the lookup table program
+
+use solana_sdk::pubkey::Pubkey;
+
+use super::{ADDRESS_LOOKUP_TABLE_PROGRAM_ID, PROGRAM_ID};
+
+pub const MULTISIG_SEED: &[u8] = b"multisig";
+pub const VAULT_SEED: &[u8] = b"vault";
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+pub const TRANSFER_PROPOSAL_SEED: &[u8] = b"transfer";
+pub const VAULT_STAKE_SEED: &[u8] = b"vault_stake";
+pub const MULTISIG_LARGE_SEED: &[u8] = b"multisig_large";
+
+pub fn multisig_address(creator: &Pubkey, multisig_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MULTISIG_SEED, creator.as_ref(), &multisig_id.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+pub fn vault_address(multisig: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED, multisig.as_ref()], &PROGRAM_ID)
+}
+
+pub fn proposal_address(multisig: &Pubkey, proposal_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PROPOSAL_SEED, multisig.as_ref(), &proposal_id.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+pub fn transfer_proposal_address(multisig: &Pubkey, proposal_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            TRANSFER_PROPOSAL_SEED,
+            multisig.as_ref(),
+            &proposal_id.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    )
+}
+
+// Stake account created by the DelegateVaultStake proposal `proposal_id`
+pub fn vault_stake_address(multisig: &Pubkey, proposal_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VAULT_STAKE_SEED, multisig.as_ref(), &proposal_id.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+// Lookup table a CreateLookupTable proposal executed at `recent_slot` creates
+pub fn vault_lookup_table_address(vault: &Pubkey, recent_slot: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[vault.as_ref(), &recent_slot.to_le_bytes()],
+        &ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+    )
+}
+
+pub fn multisig_large_address(creator: &Pubkey, multisig_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MULTISIG_LARGE_SEED, creator.as_ref(), &multisig_id.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
@@ -0,0 +1,185 @@
+// NFT Staking Accounts
+//
+// Mirrors state/*.rs. PenaltyCurve uses Borsh variant order.
+
+use solana_sdk::pubkey::Pubkey;
+
+use super::BADGE_MILESTONE_COUNT;
+use crate::codec::{DataReader, DecodeError};
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PenaltyCurve {
+    Linear = 0,
+    Quadratic = 1,
+}
+
+impl PenaltyCurve {
+    fn read(reader: &mut DataReader) -> Result<Self, DecodeError> {
+        match reader.u8()? {
+            0 => Ok(PenaltyCurve::Linear),
+            1 => Ok(PenaltyCurve::Quadratic),
+            tag => Err(DecodeError::InvalidEnumTag {
+                name: "PenaltyCurve",
+                tag,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollectionState {
+    pub authority: Pubkey,
+    pub collection: Pubkey,
+    pub total_minted: u64,
+    pub total_staked: u64,
+    pub max_staked: u64,
+    pub paused: bool,
+    pub early_unstake_penalty: u64,
+    pub penalty_curve: PenaltyCurve,
+    pub badge_milestone_days: [u16; BADGE_MILESTONE_COUNT],
+    pub handoff_keeps_stake_time: bool,
+    pub bump: u8,
+}
+
+impl CollectionState {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "CollectionState")?;
+        Ok(Self {
+            authority: reader.pubkey()?,
+            collection: reader.pubkey()?,
+            total_minted: reader.u64()?,
+            total_staked: reader.u64()?,
+            max_staked: reader.u64()?,
+            paused: reader.bool()?,
+            early_unstake_penalty: reader.u64()?,
+            penalty_curve: PenaltyCurve::read(&mut reader)?,
+            badge_milestone_days: [reader.u16()?, reader.u16()?, reader.u16()?],
+            handoff_keeps_stake_time: reader.bool()?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollectionTreasury {
+    pub collection: Pubkey,
+    pub total_penalties: u64,
+    pub bump: u8,
+}
+
+impl CollectionTreasury {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "CollectionTreasury")?;
+        Ok(Self {
+            collection: reader.pubkey()?,
+            total_penalties: reader.u64()?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RarityTier {
+    pub value: String,
+    pub multiplier_bps: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RarityConfig {
+    pub collection: Pubkey,
+    pub tiers: Vec<RarityTier>,
+    pub bump: u8,
+}
+
+impl RarityConfig {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "RarityConfig")?;
+        Ok(Self {
+            collection: reader.pubkey()?,
+            tiers: reader.vec(|r| {
+                Ok(RarityTier {
+                    value: r.string()?,
+                    multiplier_bps: r.u16()?,
+                })
+            })?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RewardConfig {
+    pub collection: Pubkey,
+    pub reward_mint: Pubkey,
+    pub reward_rate_per_sec: u64,
+    pub total_rewards_paid: u64,
+    pub total_funded: u64,
+    pub emission_end_ts: i64,
+    pub bump: u8,
+}
+
+impl RewardConfig {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "RewardConfig")?;
+        Ok(Self {
+            collection: reader.pubkey()?,
+            reward_mint: reader.pubkey()?,
+            reward_rate_per_sec: reader.u64()?,
+            total_rewards_paid: reader.u64()?,
+            total_funded: reader.u64()?,
+            emission_end_ts: reader.i64()?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StakeRecord {
+    pub asset: Pubkey,
+    pub owner: Pubkey,
+    pub staked_at: i64,
+    pub last_claim: i64,
+    pub unstake_requested_at: i64,
+    pub cooldown_ends_at: i64,
+    pub bump: u8,
+}
+
+impl StakeRecord {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "StakeRecord")?;
+        Ok(Self {
+            asset: reader.pubkey()?,
+            owner: reader.pubkey()?,
+            staked_at: reader.i64()?,
+            last_claim: reader.i64()?,
+            unstake_requested_at: reader.i64()?,
+            cooldown_ends_at: reader.i64()?,
+            bump: reader.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BadgeRecord {
+    pub asset: Pubkey,
+    pub owner: Pubkey,
+    pub badge: Pubkey,
+    pub milestone: u8,
+    pub awarded_at: i64,
+    pub bump: u8,
+}
+
+impl BadgeRecord {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = DataReader::anchor(data, "BadgeRecord")?;
+        Ok(Self {
+            asset: reader.pubkey()?,
+            owner: reader.pubkey()?,
+            badge: reader.pubkey()?,
+            milestone: reader.u8()?,
+            awarded_at: reader.i64()?,
+            bump: reader.u8()?,
+        })
+    }
+}
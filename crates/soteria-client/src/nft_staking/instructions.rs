@@ -0,0 +1,502 @@
+// NFT Staking Instructions
+//
+// Program PDAs are derived from the collection and asset. The collection's
+// update authority must co-sign stake, finalize_unstake, early_unstake and
+// owner_handoff because the program adds and removes Metaplex Core plugins on
+// the asset.
+// Account order follows each #[derive(Accounts)] struct in the program.
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+
+use super::{
+    accounts::PenaltyCurve, pda::*, BADGE_MILESTONE_COUNT, MPL_CORE_PROGRAM_ID, PROGRAM_ID,
+};
+use crate::{
+    codec::DataWriter,
+    token::{associated_token_address, ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID},
+};
+
+// collection must sign - it is created by Metaplex Core at this address
+pub fn create_collection(
+    authority: &Pubkey,
+    collection: &Pubkey,
+    payer: &Pubkey,
+    name: &str,
+    uri: &str,
+) -> Instruction {
+    let (collection_state, _) = collection_state_address(collection);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(*collection, true),
+            AccountMeta::new(collection_state, false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(MPL_CORE_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("create_collection")
+            .string(name)
+            .string(uri)
+            .into_vec(),
+    }
+}
+
+// asset must sign - it is created by Metaplex Core at this address
+#[allow(clippy::too_many_arguments)]
+pub fn mint_nft(
+    authority: &Pubkey,
+    asset: &Pubkey,
+    collection: &Pubkey,
+    update_authority: &Pubkey,
+    owner: &Pubkey,
+    payer: &Pubkey,
+    name: &str,
+    uri: &str,
+) -> Instruction {
+    let (collection_state, _) = collection_state_address(collection);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(*asset, true),
+            AccountMeta::new(*collection, false),
+            AccountMeta::new(collection_state, false),
+            AccountMeta::new_readonly(*update_authority, false),
+            AccountMeta::new_readonly(*owner, false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(MPL_CORE_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("mint_nft")
+            .string(name)
+            .string(uri)
+            .into_vec(),
+    }
+}
+
+pub fn stake(
+    owner: &Pubkey,
+    update_authority: &Pubkey,
+    payer: &Pubkey,
+    asset: &Pubkey,
+    collection: &Pubkey,
+) -> Instruction {
+    let (collection_state, _) = collection_state_address(collection);
+    let (stake_record, _) = stake_record_address(asset);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new_readonly(*update_authority, true),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(*asset, false),
+            AccountMeta::new(*collection, false),
+            AccountMeta::new(collection_state, false),
+            AccountMeta::new(stake_record, false),
+            AccountMeta::new_readonly(MPL_CORE_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("stake").into_vec(),
+    }
+}
+
+// Starts the UNSTAKE_COOLDOWN; only the owner signs since no plugin changes
+pub fn request_unstake(owner: &Pubkey, asset: &Pubkey, collection: &Pubkey) -> Instruction {
+    let (stake_record, _) = stake_record_address(asset);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new_readonly(*asset, false),
+            AccountMeta::new_readonly(*collection, false),
+            AccountMeta::new(stake_record, false),
+        ],
+        data: DataWriter::anchor("request_unstake").into_vec(),
+    }
+}
+
+// Thaws the asset once the cooldown has ended
+pub fn finalize_unstake(
+    owner: &Pubkey,
+    update_authority: &Pubkey,
+    payer: &Pubkey,
+    asset: &Pubkey,
+    collection: &Pubkey,
+) -> Instruction {
+    let (collection_state, _) = collection_state_address(collection);
+    let (stake_record, _) = stake_record_address(asset);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new_readonly(*update_authority, true),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(*asset, false),
+            AccountMeta::new(*collection, false),
+            AccountMeta::new(collection_state, false),
+            AccountMeta::new(stake_record, false),
+            AccountMeta::new_readonly(MPL_CORE_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("finalize_unstake").into_vec(),
+    }
+}
+
+// Transfers a staked asset to new_owner and re-stakes it for them; both sign
+// transfer_accounts: extra accounts the Metaplex Core transfer needs, forwarded as-is
+#[allow(clippy::too_many_arguments)]
+pub fn owner_handoff(
+    owner: &Pubkey,
+    new_owner: &Pubkey,
+    update_authority: &Pubkey,
+    payer: &Pubkey,
+    asset: &Pubkey,
+    collection: &Pubkey,
+    transfer_accounts: &[AccountMeta],
+) -> Instruction {
+    let (collection_state, _) = collection_state_address(collection);
+    let (stake_record, _) = stake_record_address(asset);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*owner, true),
+        AccountMeta::new_readonly(*new_owner, true),
+        AccountMeta::new_readonly(*update_authority, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new(*asset, false),
+        AccountMeta::new(*collection, false),
+        AccountMeta::new_readonly(collection_state, false),
+        AccountMeta::new(stake_record, false),
+        AccountMeta::new_readonly(MPL_CORE_PROGRAM_ID, false),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+    ];
+    accounts.extend_from_slice(transfer_accounts);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data: DataWriter::anchor("owner_handoff").into_vec(),
+    }
+}
+
+// Unstakes before MIN_STAKE_DURATION; the penalty goes to the collection treasury
+pub fn early_unstake(
+    owner: &Pubkey,
+    update_authority: &Pubkey,
+    payer: &Pubkey,
+    asset: &Pubkey,
+    collection: &Pubkey,
+) -> Instruction {
+    let (collection_state, _) = collection_state_address(collection);
+    let (stake_record, _) = stake_record_address(asset);
+    let (collection_treasury, _) = collection_treasury_address(collection);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new_readonly(*update_authority, true),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(*asset, false),
+            AccountMeta::new(*collection, false),
+            AccountMeta::new(collection_state, false),
+            AccountMeta::new(stake_record, false),
+            AccountMeta::new(collection_treasury, false),
+            AccountMeta::new_readonly(MPL_CORE_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("early_unstake").into_vec(),
+    }
+}
+
+// reward_mint is any SPL Token mint; its reward vault is created here
+pub fn init_reward_config(
+    authority: &Pubkey,
+    collection: &Pubkey,
+    reward_mint: &Pubkey,
+    reward_rate_per_sec: u64,
+) -> Instruction {
+    let (collection_state, _) = collection_state_address(collection);
+    let (reward_config, _) = reward_config_address(collection);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(*collection, false),
+            AccountMeta::new_readonly(collection_state, false),
+            AccountMeta::new(reward_config, false),
+            AccountMeta::new_readonly(*reward_mint, false),
+            AccountMeta::new(reward_vault_address(collection, reward_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("init_reward_config")
+            .u64(reward_rate_per_sec)
+            .into_vec(),
+    }
+}
+
+// Deposit is taken from the authority's associated token account for the reward mint
+pub fn fund_rewards(
+    authority: &Pubkey,
+    collection: &Pubkey,
+    reward_mint: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let (collection_state, _) = collection_state_address(collection);
+    let (reward_config, _) = reward_config_address(collection);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(*collection, false),
+            AccountMeta::new_readonly(collection_state, false),
+            AccountMeta::new(reward_config, false),
+            AccountMeta::new_readonly(*reward_mint, false),
+            AccountMeta::new(
+                associated_token_address(authority, reward_mint, &TOKEN_PROGRAM_ID),
+                false,
+            ),
+            AccountMeta::new(reward_vault_address(collection, reward_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("fund_rewards").u64(amount).into_vec(),
+    }
+}
+
+// Rewards are paid from the reward vault to the owner's associated token account
+// voter_profile is an Anchor optional account: the program ID means None.
+// Pass the owner's governance UserProfile to claim with the reputation boost.
+pub fn claim_rewards(
+    owner: &Pubkey,
+    asset: &Pubkey,
+    collection: &Pubkey,
+    reward_mint: &Pubkey,
+    voter_profile: Option<&Pubkey>,
+) -> Instruction {
+    let (stake_record, _) = stake_record_address(asset);
+    let (reward_config, _) = reward_config_address(collection);
+    let (rarity_config, _) = rarity_config_address(collection);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new_readonly(*asset, false),
+            AccountMeta::new_readonly(*collection, false),
+            AccountMeta::new(stake_record, false),
+            AccountMeta::new(reward_config, false),
+            AccountMeta::new_readonly(rarity_config, false),
+            AccountMeta::new_readonly(*reward_mint, false),
+            AccountMeta::new(reward_vault_address(collection, reward_mint), false),
+            AccountMeta::new(
+                associated_token_address(owner, reward_mint, &TOKEN_PROGRAM_ID),
+                false,
+            ),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*voter_profile.unwrap_or(&PROGRAM_ID), false),
+        ],
+        data: DataWriter::anchor("claim_rewards").into_vec(),
+    }
+}
+
+// Collection authority instructions: [authority (signer), collection, collection_state (mut)]
+fn collection_admin_instruction(
+    authority: &Pubkey,
+    collection: &Pubkey,
+    data: Vec<u8>,
+) -> Instruction {
+    let (collection_state, _) = collection_state_address(collection);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(*collection, false),
+            AccountMeta::new(collection_state, false),
+        ],
+        data,
+    }
+}
+
+pub fn set_collection_config(
+    authority: &Pubkey,
+    collection: &Pubkey,
+    max_staked: u64,
+    paused: bool,
+) -> Instruction {
+    collection_admin_instruction(
+        authority,
+        collection,
+        DataWriter::anchor("set_collection_config")
+            .u64(max_staked)
+            .bool(paused)
+            .into_vec(),
+    )
+}
+
+pub fn set_early_unstake_penalty(
+    authority: &Pubkey,
+    collection: &Pubkey,
+    early_unstake_penalty: u64,
+    penalty_curve: PenaltyCurve,
+) -> Instruction {
+    collection_admin_instruction(
+        authority,
+        collection,
+        DataWriter::anchor("set_early_unstake_penalty")
+            .u64(early_unstake_penalty)
+            .u8(penalty_curve as u8)
+            .into_vec(),
+    )
+}
+
+// keep_stake_time: owner_handoff keeps the stake's start time instead of restarting it
+pub fn set_handoff_policy(authority: &Pubkey, collection: &Pubkey, keep_stake_time: bool) -> Instruction {
+    collection_admin_instruction(
+        authority,
+        collection,
+        DataWriter::anchor("set_handoff_policy")
+            .bool(keep_stake_time)
+            .into_vec(),
+    )
+}
+
+// milestone_days: stake days required for each badge, strictly increasing
+pub fn set_badge_milestones(
+    authority: &Pubkey,
+    collection: &Pubkey,
+    milestone_days: [u16; BADGE_MILESTONE_COUNT],
+) -> Instruction {
+    let mut writer = DataWriter::anchor("set_badge_milestones");
+    for days in milestone_days {
+        writer = writer.u16(days);
+    }
+
+    collection_admin_instruction(authority, collection, writer.into_vec())
+}
+
+// badge must sign - it is created by Metaplex Core at this address
+pub fn mint_milestone_badge(
+    owner: &Pubkey,
+    badge: &Pubkey,
+    asset: &Pubkey,
+    collection: &Pubkey,
+    milestone: u8,
+    name: &str,
+    uri: &str,
+) -> Instruction {
+    let (collection_state, _) = collection_state_address(collection);
+    let (stake_record, _) = stake_record_address(asset);
+    let (badge_record, _) = badge_record_address(asset, milestone);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new(*badge, true),
+            AccountMeta::new_readonly(*asset, false),
+            AccountMeta::new_readonly(*collection, false),
+            AccountMeta::new_readonly(collection_state, false),
+            AccountMeta::new_readonly(stake_record, false),
+            AccountMeta::new(badge_record, false),
+            AccountMeta::new_readonly(MPL_CORE_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("mint_milestone_badge")
+            .u8(milestone)
+            .string(name)
+            .string(uri)
+            .into_vec(),
+    }
+}
+
+// Both collections' update authorities sign; the new one pays for the new state
+pub fn migrate_collection_state(
+    old_authority: &Pubkey,
+    new_authority: &Pubkey,
+    old_collection: &Pubkey,
+    new_collection: &Pubkey,
+) -> Instruction {
+    let (old_collection_state, _) = collection_state_address(old_collection);
+    let (new_collection_state, _) = collection_state_address(new_collection);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*old_authority, true),
+            AccountMeta::new(*new_authority, true),
+            AccountMeta::new_readonly(*old_collection, false),
+            AccountMeta::new_readonly(*new_collection, false),
+            AccountMeta::new(old_collection_state, false),
+            AccountMeta::new(new_collection_state, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("migrate_collection_state").into_vec(),
+    }
+}
+
+// tiers: (rarity attribute value, reward multiplier in basis points)
+pub fn set_rarity_config(
+    authority: &Pubkey,
+    collection: &Pubkey,
+    tiers: &[(&str, u16)],
+) -> Instruction {
+    let (collection_state, _) = collection_state_address(collection);
+    let (rarity_config, _) = rarity_config_address(collection);
+
+    let mut writer = DataWriter::anchor("set_rarity_config").u32(tiers.len() as u32);
+    for (value, multiplier_bps) in tiers {
+        writer = writer.string(value).u16(*multiplier_bps);
+    }
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(*collection, false),
+            AccountMeta::new_readonly(collection_state, false),
+            AccountMeta::new(rarity_config, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: writer.into_vec(),
+    }
+}
+
+pub fn set_asset_rarity(
+    authority: &Pubkey,
+    asset: &Pubkey,
+    collection: &Pubkey,
+    rarity: &str,
+) -> Instruction {
+    let (collection_state, _) = collection_state_address(collection);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(*asset, false),
+            AccountMeta::new(*collection, false),
+            AccountMeta::new_readonly(collection_state, false),
+            AccountMeta::new_readonly(MPL_CORE_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: DataWriter::anchor("set_asset_rarity")
+            .string(rarity)
+            .into_vec(),
+    }
+}
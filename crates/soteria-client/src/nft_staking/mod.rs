@@ -0,0 +1,27 @@
+// NFT Staking (n-secure)
+//
+// Metaplex Core collection staking: assets are frozen in place while staked,
+// earn reward tokens scaled by rarity, and pay a penalty on early unstake.
+// Long stakes earn soulbound milestone badges.
+
+use solana_sdk::{pubkey, pubkey::Pubkey};
+
+pub mod accounts;
+pub mod instructions;
+pub mod pda;
+
+pub use accounts::*;
+pub use pda::*;
+
+pub const PROGRAM_ID: Pubkey = pubkey!("xbwEtBJ9eoyGCAkvr4P2JmMH8wSnrb6amh2po57oGGJ");
+
+pub const MPL_CORE_PROGRAM_ID: Pubkey = pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+
+// Minimum stake before a normal unstake is allowed (MIN_STAKE_DURATION)
+pub const MIN_STAKE_DURATION: i64 = 30 * 24 * 60 * 60;
+
+// Wait between request_unstake and finalize_unstake (UNSTAKE_COOLDOWN)
+pub const UNSTAKE_COOLDOWN: i64 = 7 * 24 * 60 * 60;
+
+// Number of milestone badges per collection (BADGE_MILESTONE_COUNT)
+pub const BADGE_MILESTONE_COUNT: usize = 3;
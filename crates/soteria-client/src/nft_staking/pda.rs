@@ -0,0 +1,50 @@
+// NFT Staking PDAs
+//
+// Seeds match constants.rs. Everything except the stake and badge records is
+// keyed by the Metaplex Core collection; those two are keyed by the asset.
+// The reward vault is the reward config's associated token account.
+
+use solana_sdk::pubkey::Pubkey;
+
+use super::PROGRAM_ID;
+use crate::token::{associated_token_address, TOKEN_PROGRAM_ID};
+
+pub const COLLECTION_STATE_SEED: &[u8] = b"collection_state";
+pub const REWARD_CONFIG_SEED: &[u8] = b"reward_config";
+pub const STAKE_RECORD_SEED: &[u8] = b"stake_record";
+pub const COLLECTION_TREASURY_SEED: &[u8] = b"collection_treasury";
+pub const RARITY_CONFIG_SEED: &[u8] = b"rarity_config";
+pub const BADGE_RECORD_SEED: &[u8] = b"badge_record";
+
+pub fn collection_state_address(collection: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COLLECTION_STATE_SEED, collection.as_ref()], &PROGRAM_ID)
+}
+
+pub fn reward_config_address(collection: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REWARD_CONFIG_SEED, collection.as_ref()], &PROGRAM_ID)
+}
+
+// Token account claims are paid from, filled by fund_rewards
+pub fn reward_vault_address(collection: &Pubkey, reward_mint: &Pubkey) -> Pubkey {
+    let (reward_config, _) = reward_config_address(collection);
+    associated_token_address(&reward_config, reward_mint, &TOKEN_PROGRAM_ID)
+}
+
+pub fn stake_record_address(asset: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STAKE_RECORD_SEED, asset.as_ref()], &PROGRAM_ID)
+}
+
+pub fn collection_treasury_address(collection: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[COLLECTION_TREASURY_SEED, collection.as_ref()],
+        &PROGRAM_ID,
+    )
+}
+
+pub fn rarity_config_address(collection: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RARITY_CONFIG_SEED, collection.as_ref()], &PROGRAM_ID)
+}
+
+pub fn badge_record_address(asset: &Pubkey, milestone: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BADGE_RECORD_SEED, asset.as_ref(), &[milestone]], &PROGRAM_ID)
+}
@@ -0,0 +1,662 @@
+// Unit tests for the AMM client
+
+use solana_sdk::pubkey::Pubkey;
+use soteria_client::amm::{instructions, *};
+use soteria_client::token::{
+    associated_token_address, NATIVE_MINT, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID,
+};
+use soteria_client::{account_discriminator, instruction_discriminator};
+
+#[test]
+fn test_swap_referrer_slot() {
+    // Test: No referrer passes the program ID (read-only) in the optional slot
+    let swapper = Pubkey::new_unique();
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    let ix = instructions::swap_tokens(
+        &swapper,
+        &mint_a,
+        &mint_b,
+        30,
+        true,
+        1_000,
+        900,
+        0,
+        &TOKEN_PROGRAM_ID,
+        None,
+        None,
+    );
+    assert_eq!(ix.accounts.len(), 14);
+    assert_eq!(ix.accounts[12].pubkey, PROGRAM_ID);
+    assert!(!ix.accounts[12].is_writable);
+    assert_eq!(ix.accounts[13].pubkey, PROGRAM_ID);
+
+    let referrer = Pubkey::new_unique();
+    let ix = instructions::swap_tokens(
+        &swapper,
+        &mint_a,
+        &mint_b,
+        30,
+        true,
+        1_000,
+        900,
+        0,
+        &TOKEN_PROGRAM_ID,
+        Some(&referrer),
+        None,
+    );
+    assert_eq!(ix.accounts[12].pubkey, referrer);
+    assert!(ix.accounts[12].is_writable);
+
+    // bool + input + min output + expiration
+    assert_eq!(ix.data[..8], instruction_discriminator("swap_tokens"));
+    assert_eq!(ix.data.len(), 8 + 1 + 8 + 8 + 8);
+}
+
+#[test]
+fn test_swap_price_feed_slot() {
+    // Test: The price feed follows the referrer and is never writable
+    let swapper = Pubkey::new_unique();
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let price_feed = Pubkey::new_unique();
+
+    let ix = instructions::swap_tokens_exact_out(
+        &swapper,
+        &mint_a,
+        &mint_b,
+        30,
+        false,
+        1_000,
+        900,
+        0,
+        &TOKEN_PROGRAM_ID,
+        None,
+        Some(&price_feed),
+    );
+    assert_eq!(ix.accounts[12].pubkey, PROGRAM_ID);
+    assert_eq!(ix.accounts[13].pubkey, price_feed);
+    assert!(!ix.accounts[13].is_writable);
+    assert!(!ix.accounts[13].is_signer);
+
+    // oracle program + feed + max deviation
+    let ix = instructions::set_oracle_guard(
+        &swapper,
+        &mint_a,
+        &mint_b,
+        30,
+        &Pubkey::new_unique(),
+        &price_feed,
+        500,
+    );
+    assert_eq!(ix.data[..8], instruction_discriminator("set_oracle_guard"));
+    assert_eq!(ix.data.len(), 8 + 32 + 32 + 2);
+    assert_eq!(ix.data[72..], 500u16.to_le_bytes());
+}
+
+#[test]
+fn test_circuit_breaker_accounts() {
+    // Test: Swaps take the pool config writable so the circuit breaker can lock it
+    let swapper = Pubkey::new_unique();
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    let ix = instructions::swap_tokens(
+        &swapper,
+        &mint_a,
+        &mint_b,
+        30,
+        true,
+        1_000,
+        900,
+        0,
+        &TOKEN_PROGRAM_ID,
+        None,
+        None,
+    );
+    assert_eq!(ix.accounts[1].pubkey, pool_config_address(&mint_a, &mint_b, 30).0);
+    assert!(ix.accounts[1].is_writable);
+
+    // max price impact + auto-lock flag
+    let ix = instructions::set_circuit_breaker(&swapper, &mint_a, &mint_b, 30, 1_000, true);
+    assert_eq!(ix.data[..8], instruction_discriminator("set_circuit_breaker"));
+    assert_eq!(ix.data.len(), 8 + 2 + 1);
+    assert_eq!(ix.data[8..10], 1_000u16.to_le_bytes());
+    assert_eq!(ix.data[10], 1);
+    assert!(ix.accounts[0].is_signer);
+    assert!(ix.accounts[1].is_writable);
+}
+
+#[test]
+fn test_swap_limit_accounts() {
+    // Test: set_swap_limit carries the cap; fills take the pool config writable to record volume
+    let authority = Pubkey::new_unique();
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    let ix = instructions::set_swap_limit(&authority, &mint_a, &mint_b, 30, 2_500);
+    assert_eq!(ix.data[..8], instruction_discriminator("set_swap_limit"));
+    assert_eq!(ix.data[8..], 2_500u16.to_le_bytes());
+    assert!(ix.accounts[0].is_signer);
+    assert!(ix.accounts[1].is_writable);
+
+    let ix = instructions::fill_limit_order(
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &mint_a,
+        &mint_b,
+        30,
+        true,
+        0,
+        &TOKEN_PROGRAM_ID,
+        None,
+    );
+    assert_eq!(ix.accounts[2].pubkey, pool_config_address(&mint_a, &mint_b, 30).0);
+    assert!(ix.accounts[2].is_writable);
+}
+
+#[test]
+fn test_swap_with_rebate_accounts() {
+    // Test: Rebate swaps append the LP mint and the swapper's LP ATA to the swap accounts
+    let swapper = Pubkey::new_unique();
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (pool_config, _) = pool_config_address(&mint_a, &mint_b, 30);
+    let (lp_mint, _) = lp_mint_address(&pool_config);
+
+    let ix = instructions::swap_with_rebate(
+        &swapper,
+        &mint_a,
+        &mint_b,
+        30,
+        true,
+        1_000,
+        900,
+        0,
+        &TOKEN_PROGRAM_ID,
+        None,
+        None,
+    );
+    assert_eq!(ix.accounts.len(), 16);
+    assert_eq!(ix.accounts[13].pubkey, PROGRAM_ID);
+    assert_eq!(ix.accounts[14].pubkey, lp_mint);
+    assert_eq!(
+        ix.accounts[15].pubkey,
+        associated_token_address(&swapper, &lp_mint, &TOKEN_PROGRAM_ID)
+    );
+    assert!(!ix.accounts[15].is_writable);
+
+    // Same arguments as swap_tokens
+    assert_eq!(ix.data[..8], instruction_discriminator("swap_with_rebate"));
+    assert_eq!(ix.data.len(), 8 + 1 + 8 + 8 + 8);
+}
+
+#[test]
+fn test_native_sol_accounts() {
+    // Test: SOL variants reuse the deposit and swap accounts, with the user's wSOL ATA in place
+    let user = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let wsol_ata = associated_token_address(&user, &NATIVE_MINT, &TOKEN_PROGRAM_ID);
+
+    let ix = instructions::deposit_liquidity_sol(
+        &user,
+        &NATIVE_MINT,
+        &mint,
+        30,
+        1_000,
+        2_000,
+        1_000,
+        2_000,
+        0,
+        &TOKEN_PROGRAM_ID,
+    );
+    let deposit = instructions::deposit_liquidity(
+        &user,
+        &NATIVE_MINT,
+        &mint,
+        30,
+        1_000,
+        2_000,
+        1_000,
+        2_000,
+        0,
+        &TOKEN_PROGRAM_ID,
+    );
+    assert_eq!(ix.accounts, deposit.accounts);
+    assert_eq!(ix.accounts[6].pubkey, wsol_ata);
+    assert!(ix.accounts[6].is_writable);
+    assert_eq!(ix.data[..8], instruction_discriminator("deposit_liquidity_sol"));
+    assert_eq!(ix.data[8..], deposit.data[8..]);
+
+    // No direction flag: SOL is always the input
+    let ix = instructions::swap_sol_for_token(
+        &user,
+        &mint,
+        &NATIVE_MINT,
+        30,
+        1_000,
+        900,
+        0,
+        &TOKEN_PROGRAM_ID,
+        None,
+        None,
+    );
+    assert_eq!(ix.accounts.len(), 14);
+    assert_eq!(ix.accounts[6].pubkey, wsol_ata);
+    assert_eq!(ix.data[..8], instruction_discriminator("swap_sol_for_token"));
+    assert_eq!(ix.data.len(), 8 + 8 + 8 + 8);
+    assert_eq!(ix.data[8..16], 1_000u64.to_le_bytes());
+}
+
+#[test]
+fn test_position_accounts() {
+    // Test: Position instructions sign with the new asset and share the position LP vault
+    let user = Pubkey::new_unique();
+    let asset = Pubkey::new_unique();
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (position, _) = position_address(&asset);
+    let lp_vault = position_lp_vault_address(&mint_a, &mint_b, 30, &TOKEN_PROGRAM_ID);
+
+    let ix = instructions::deposit_position(
+        &user,
+        &asset,
+        &mint_a,
+        &mint_b,
+        30,
+        1_000,
+        2_000,
+        1_000,
+        2_000,
+        0,
+        "uri",
+        &TOKEN_PROGRAM_ID,
+    );
+    assert!(ix.accounts[1].is_signer);
+    assert_eq!(ix.accounts[1].pubkey, asset);
+    assert_eq!(ix.accounts[9].pubkey, lp_vault);
+    assert_eq!(ix.accounts[12].pubkey, position);
+    assert_eq!(ix.accounts[13].pubkey, soteria_client::nft_staking::MPL_CORE_PROGRAM_ID);
+    // 4 amounts + expiration + borsh string
+    assert_eq!(ix.data.len(), 8 + 8 * 5 + 4 + 3);
+    // Fee growth is snapshotted on the pool
+    assert!(ix.accounts[2].is_writable);
+
+    let ix = instructions::claim_lp_fees(&user, &asset, &mint_a, &mint_b, 30, &TOKEN_PROGRAM_ID);
+    assert_eq!(ix.accounts[2].pubkey, position);
+    assert!(ix.accounts[3].is_writable);
+    assert_eq!(ix.accounts[10].pubkey, lp_vault);
+    assert_eq!(ix.data, instruction_discriminator("claim_lp_fees"));
+
+    let ix = instructions::withdraw_position(
+        &user,
+        &asset,
+        &mint_a,
+        &mint_b,
+        30,
+        0,
+        0,
+        0,
+        &TOKEN_PROGRAM_ID,
+    );
+    assert_eq!(ix.data[..8], instruction_discriminator("withdraw_position"));
+    assert!(!ix.accounts[1].is_signer);
+    assert!(ix.accounts[1].is_writable);
+    assert_eq!(ix.accounts[2].pubkey, position);
+    assert_eq!(ix.accounts[10].pubkey, lp_vault);
+}
+
+#[test]
+fn test_vaults_follow_token_program() {
+    // Test: Vaults are the pool authority's ATAs under the chosen token program
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (pool_config, _) = pool_config_address(&mint_a, &mint_b, 30);
+    let (pool_authority, _) = pool_authority_address(&pool_config);
+
+    let ix = instructions::initialize_pool(
+        &Pubkey::new_unique(),
+        &mint_a,
+        &mint_b,
+        30,
+        0,
+        &TOKEN_2022_PROGRAM_ID,
+    );
+    assert_eq!(ix.accounts[4].pubkey, pool_config);
+    assert_eq!(
+        ix.accounts[7].pubkey,
+        associated_token_address(&pool_authority, &mint_a, &TOKEN_2022_PROGRAM_ID)
+    );
+    assert_eq!(
+        ix.accounts[8].pubkey,
+        vault_address(&mint_a, &mint_b, 30, &mint_b, &TOKEN_2022_PROGRAM_ID)
+    );
+    assert_eq!(ix.accounts[9].pubkey, TOKEN_2022_PROGRAM_ID);
+}
+
+#[test]
+fn test_migrate_liquidity_accounts() {
+    // Test: Source and destination are the same pair at different fee tiers
+    let user = Pubkey::new_unique();
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (source_pool, _) = pool_config_address(&mint_a, &mint_b, 30);
+    let (dest_pool, _) = pool_config_address(&mint_a, &mint_b, 100);
+
+    let ix = instructions::migrate_liquidity(
+        &user,
+        &mint_a,
+        &mint_b,
+        30,
+        &mint_a,
+        &mint_b,
+        100,
+        1_000,
+        900,
+        0,
+        &TOKEN_PROGRAM_ID,
+    );
+    assert_eq!(ix.data[..8], instruction_discriminator("migrate_liquidity"));
+    assert_eq!(ix.data.len(), 8 + 8 + 8 + 8);
+    assert_eq!(ix.data[16..24], 900u64.to_le_bytes());
+    assert_eq!(ix.accounts.len(), 20);
+    assert!(ix.accounts[0].is_signer);
+    assert_eq!(ix.accounts[1].pubkey, source_pool);
+    assert_eq!(ix.accounts[4].pubkey, dest_pool);
+    assert_eq!(ix.accounts[6].pubkey, lp_mint_address(&dest_pool).0);
+    assert_eq!(ix.accounts[7].pubkey, mint_a);
+    assert_eq!(
+        ix.accounts[13].pubkey,
+        vault_address(&mint_a, &mint_b, 30, &mint_a, &TOKEN_PROGRAM_ID)
+    );
+    assert_eq!(
+        ix.accounts[15].pubkey,
+        vault_address(&mint_a, &mint_b, 100, &mint_a, &TOKEN_PROGRAM_ID)
+    );
+    assert_eq!(
+        ix.accounts[16].pubkey,
+        vault_address(&mint_a, &mint_b, 100, &mint_b, &TOKEN_PROGRAM_ID)
+    );
+
+    // Destination vaults are named by the source mints, not a reversed pool's order
+    let ix = instructions::migrate_liquidity(
+        &user,
+        &mint_a,
+        &mint_b,
+        30,
+        &mint_b,
+        &mint_a,
+        30,
+        1_000,
+        900,
+        0,
+        &TOKEN_PROGRAM_ID,
+    );
+    assert_eq!(ix.accounts[4].pubkey, pool_config_address(&mint_b, &mint_a, 30).0);
+    assert_eq!(
+        ix.accounts[15].pubkey,
+        vault_address(&mint_b, &mint_a, 30, &mint_a, &TOKEN_PROGRAM_ID)
+    );
+}
+
+#[test]
+fn test_pool_address_per_fee_tier() {
+    // Test: The fee tier is part of the pool seeds, so each tier of a pair is its own pool
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (pool_30, _) = pool_config_address(&mint_a, &mint_b, 30);
+    let (pool_100, _) = pool_config_address(&mint_a, &mint_b, 100);
+    assert_ne!(pool_30, pool_100);
+    assert_eq!(
+        pool_30,
+        Pubkey::find_program_address(
+            &[AMM_CONFIG_SEED, mint_a.as_ref(), mint_b.as_ref(), &30u16.to_le_bytes()],
+            &PROGRAM_ID,
+        )
+        .0
+    );
+
+    // initialize_pool derives the pool from the fee it is created with
+    let ix = instructions::initialize_pool(
+        &Pubkey::new_unique(),
+        &mint_a,
+        &mint_b,
+        100,
+        0,
+        &TOKEN_PROGRAM_ID,
+    );
+    assert_eq!(ix.accounts[4].pubkey, pool_100);
+}
+
+#[test]
+fn test_amm_config_admin_accounts() {
+    // Test: Initialization names the program and its ProgramData; updates only the config
+    let admin = Pubkey::new_unique();
+    let ix = instructions::initialize_amm_config(&admin, &admin, &[5, 30]);
+    assert_eq!(ix.accounts.len(), 5);
+    assert_eq!(ix.accounts[2].pubkey, PROGRAM_ID);
+    assert_eq!(
+        ix.accounts[3].pubkey,
+        Pubkey::find_program_address(&[PROGRAM_ID.as_ref()], &BPF_LOADER_UPGRADEABLE_ID).0
+    );
+
+    let new_admin = Pubkey::new_unique();
+    let ix = instructions::update_amm_config(&admin, &new_admin, &admin, &[5, 30, 100]);
+    assert_eq!(ix.data[..8], instruction_discriminator("update_amm_config"));
+    assert_eq!(ix.data[8..40], new_admin.to_bytes());
+    assert_eq!(ix.data.len(), 8 + 32 + 32 + 4 + 3 * 2);
+    assert_eq!(ix.accounts.len(), 2);
+    assert!(ix.accounts[0].is_signer && !ix.accounts[0].is_writable);
+    assert_eq!(ix.accounts[1].pubkey, amm_config_address().0);
+}
+
+#[test]
+fn test_fee_change_reads_amm_config() {
+    // Test: Both fee change steps pass the global config read-only for the tier check
+    let authority = Pubkey::new_unique();
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    for ix in [
+        instructions::request_fee_change(&authority, &mint_a, &mint_b, 30, 100),
+        instructions::change_pool_fee(&authority, &mint_a, &mint_b, 30),
+    ] {
+        assert_eq!(ix.accounts.len(), 3);
+        assert_eq!(ix.accounts[1].pubkey, pool_config_address(&mint_a, &mint_b, 30).0);
+        assert_eq!(ix.accounts[2].pubkey, amm_config_address().0);
+        assert!(!ix.accounts[2].is_writable);
+    }
+}
+
+#[test]
+fn test_flash_loan_end_leads_with_pool_config() {
+    // Test: flash_loan_begin finds the matching end by its first account
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let ix = instructions::flash_loan_end(&mint_a, &mint_b, 30, &mint_a, &TOKEN_PROGRAM_ID);
+
+    assert_eq!(
+        ix.accounts[0].pubkey,
+        pool_config_address(&mint_a, &mint_b, 30).0
+    );
+    assert_eq!(flash_loan_fee(10_000), 9);
+    assert_eq!(flash_loan_fee(1), 1);
+}
+
+#[test]
+fn test_decode_pool_config() {
+    // Test: Every PoolConfig field is read in declaration order
+    let authority = Pubkey::new_unique();
+    let lp_mint = Pubkey::new_unique();
+    let price_feed = Pubkey::new_unique();
+
+    let mut data = account_discriminator("PoolConfig").to_vec();
+    data.extend_from_slice(authority.as_ref());
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // token_a_mint
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // token_b_mint
+    data.extend_from_slice(lp_mint.as_ref());
+    data.extend_from_slice(&30u16.to_le_bytes()); // fee_basis_points
+    data.push(1); // locked
+    data.extend_from_slice(&[255, 254, 253]); // bumps
+    data.push(0); // flash_loan_active
+    data.extend_from_slice(Pubkey::default().as_ref()); // flash_loan_mint
+    data.extend_from_slice(&0u64.to_le_bytes()); // flash_loan_vault_balance_before
+    data.extend_from_slice(&0u64.to_le_bytes()); // flash_loan_fee
+    data.extend_from_slice(&50u16.to_le_bytes()); // pending_fee_basis_points
+    data.extend_from_slice(&1_000i64.to_le_bytes()); // fee_change_unlock_time
+    data.extend_from_slice(&172_800i64.to_le_bytes()); // fee_change_delay_seconds
+    data.push(1); // emergency_withdraw_enabled
+    data.extend_from_slice(&2_000u16.to_le_bytes()); // referral_fee_basis_points
+    data.extend_from_slice(Pubkey::default().as_ref()); // oracle_program
+    data.extend_from_slice(price_feed.as_ref());
+    data.extend_from_slice(&250u16.to_le_bytes()); // max_oracle_deviation_bps
+    data.extend_from_slice(&1_500u16.to_le_bytes()); // max_price_impact_bps
+    data.push(1); // circuit_breaker_auto_lock
+    data.extend_from_slice(&(3u128 << 63).to_le_bytes()); // fee_growth_global
+    data.extend_from_slice(&500u16.to_le_bytes()); // max_swap_per_slot_bps
+    for slot in [8u64, 5, 0, 0] {
+        // swap_volumes: slot, reserve_a, reserve_b, volume_a, volume_b
+        let reserve = if slot == 0 { 0 } else { 1_000 };
+        for value in [slot, reserve, reserve, slot * 10, 0] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+    data.extend_from_slice(&30u16.to_le_bytes()); // fee_tier
+
+    let pool = PoolConfig::try_from_bytes(&data).expect("PoolConfig should decode");
+    assert_eq!(pool.authority, authority);
+    assert_eq!(pool.lp_token_mint, lp_mint);
+    assert_eq!(pool.fee_basis_points, 30);
+    assert!(pool.locked);
+    assert_eq!(pool.lp_mint_bump, 253);
+    assert_eq!(pool.pending_fee_basis_points, 50);
+    assert_eq!(pool.fee_change_delay_seconds, 172_800);
+    assert!(pool.emergency_withdraw_enabled);
+    assert_eq!(pool.referral_fee_basis_points, 2_000);
+    assert_eq!(pool.price_feed, price_feed);
+    assert_eq!(pool.max_oracle_deviation_bps, 250);
+    assert_eq!(pool.max_price_impact_bps, 1_500);
+    assert!(pool.circuit_breaker_auto_lock);
+    assert_eq!(pool.fee_growth_global, 3u128 << 63);
+    assert_eq!(pool.max_swap_per_slot_bps, 500);
+    assert_eq!(pool.swap_volume_at(5).map(|entry| entry.volume_a), Some(50));
+    assert_eq!(pool.swap_volume_at(8).map(|entry| entry.reserve_b), Some(1_000));
+    // Never-written entries do not count as slot 0
+    assert_eq!(pool.swap_volume_at(0), None);
+    assert_eq!(pool.fee_tier, 30);
+}
+
+#[test]
+fn test_decode_position() {
+    // Test: Position decodes the record written by deposit_position
+    let pool_config = Pubkey::new_unique();
+    let asset = Pubkey::new_unique();
+
+    let mut data = account_discriminator("Position").to_vec();
+    data.extend_from_slice(pool_config.as_ref());
+    data.extend_from_slice(asset.as_ref());
+    data.extend_from_slice(&999u64.to_le_bytes()); // lp_tokens
+    data.extend_from_slice(&1_000u64.to_le_bytes()); // amount_a
+    data.extend_from_slice(&2_000u64.to_le_bytes()); // amount_b
+    data.extend_from_slice(&2_000_000_000u64.to_le_bytes()); // entry_price
+    data.extend_from_slice(&1_700_000_000i64.to_le_bytes()); // created_at
+    data.extend_from_slice(&(1u128 << 64).to_le_bytes()); // fee_growth_checkpoint
+    data.push(254); // bump
+
+    let position = Position::try_from_bytes(&data).expect("Position should decode");
+    assert_eq!(position.pool_config, pool_config);
+    assert_eq!(position.asset, asset);
+    assert_eq!(position.lp_tokens, 999);
+    assert_eq!(position.amount_b, 2_000);
+    assert_eq!(position.entry_price, 2_000_000_000);
+    assert_eq!(position.created_at, 1_700_000_000);
+    assert_eq!(position.fee_growth_checkpoint, 1u128 << 64);
+    assert_eq!(position.bump, 254);
+}
+
+#[test]
+fn test_limit_order_accounts() {
+    // Test: Order mints, escrow and vaults follow the order's direction
+    let owner = Pubkey::new_unique();
+    let keeper = Pubkey::new_unique();
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (pool_config, _) = pool_config_address(&mint_a, &mint_b, 30);
+    let (limit_order, _) = limit_order_address(&pool_config, &owner, 7);
+    let escrow = order_escrow_address(&limit_order, &mint_b, &TOKEN_PROGRAM_ID);
+
+    let ix = instructions::place_limit_order(
+        &owner,
+        &mint_a,
+        &mint_b,
+        30,
+        false,
+        7,
+        1_000,
+        950,
+        5_000,
+        0,
+        &TOKEN_PROGRAM_ID,
+    );
+    assert_eq!(ix.data[..8], instruction_discriminator("place_limit_order"));
+    assert_eq!(ix.data.len(), 8 + 8 * 5);
+    assert_eq!(ix.data[24..32], 950u64.to_le_bytes());
+    assert_eq!(ix.accounts[2].pubkey, mint_b);
+    assert_eq!(ix.accounts[3].pubkey, mint_a);
+    assert_eq!(ix.accounts[4].pubkey, limit_order);
+    assert_eq!(ix.accounts[5].pubkey, escrow);
+
+    let ix =
+        instructions::cancel_limit_order(&owner, &mint_a, &mint_b, 30, false, 7, &TOKEN_PROGRAM_ID);
+    assert_eq!(ix.data, instruction_discriminator("cancel_limit_order"));
+    assert!(ix.accounts[0].is_signer);
+    assert_eq!(ix.accounts[2].pubkey, limit_order);
+    assert_eq!(ix.accounts[3].pubkey, escrow);
+
+    let ix = instructions::fill_limit_order(
+        &keeper,
+        &owner,
+        &mint_a,
+        &mint_b,
+        30,
+        false,
+        7,
+        &TOKEN_PROGRAM_ID,
+        None,
+    );
+    assert_eq!(ix.accounts.len(), 14);
+    assert!(ix.accounts[0].is_signer);
+    assert!(!ix.accounts[1].is_signer);
+    assert_eq!(ix.accounts[7].pubkey, escrow);
+    assert_eq!(
+        ix.accounts[8].pubkey,
+        associated_token_address(&owner, &mint_a, &TOKEN_PROGRAM_ID)
+    );
+    assert_eq!(
+        ix.accounts[9].pubkey,
+        vault_address(&mint_a, &mint_b, 30, &mint_b, &TOKEN_PROGRAM_ID)
+    );
+    assert_eq!(
+        ix.accounts[10].pubkey,
+        vault_address(&mint_a, &mint_b, 30, &mint_a, &TOKEN_PROGRAM_ID)
+    );
+    assert_eq!(ix.accounts[13].pubkey, PROGRAM_ID);
+}
+
+#[test]
+fn test_decode_limit_order() {
+    // Test: LimitOrder decodes the record written by place_limit_order
+    let owner = Pubkey::new_unique();
+    let input_mint = Pubkey::new_unique();
+
+    let mut data = account_discriminator("LimitOrder").to_vec();
+    data.extend_from_slice(owner.as_ref());
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // pool_config
+    data.extend_from_slice(input_mint.as_ref());
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // output_mint
+    data.extend_from_slice(&7u64.to_le_bytes()); // order_id
+    data.extend_from_slice(&1_000u64.to_le_bytes()); // input_amount
+    data.extend_from_slice(&950u64.to_le_bytes()); // min_output_amount
+    data.extend_from_slice(&5_000u64.to_le_bytes()); // keeper_tip
+    data.extend_from_slice(&1_700_000_000i64.to_le_bytes()); // expires_at
+    data.push(253); // bump
+
+    let order = LimitOrder::try_from_bytes(&data).expect("LimitOrder should decode");
+    assert_eq!(order.owner, owner);
+    assert_eq!(order.input_mint, input_mint);
+    assert_eq!(order.order_id, 7);
+    assert_eq!(order.min_output_amount, 950);
+    assert_eq!(order.keeper_tip, 5_000);
+    assert_eq!(order.bump, 253);
+    assert!(!order.is_expired(1_700_000_000));
+    assert!(order.is_expired(1_700_000_001));
+}
@@ -0,0 +1,67 @@
+// Unit tests for discriminators, token addresses and decode errors
+
+use solana_sdk::pubkey::Pubkey;
+use soteria_client::token::*;
+use soteria_client::*;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+#[test]
+fn test_instruction_discriminator() {
+    // Test: Matches Anchor's sha256("global:initialize")[..8]
+    assert_eq!(
+        instruction_discriminator("initialize"),
+        [175, 175, 109, 31, 13, 152, 155, 237]
+    );
+    assert_ne!(
+        instruction_discriminator("swap_tokens"),
+        instruction_discriminator("swap_tokens_exact_out")
+    );
+}
+
+#[test]
+fn test_account_discriminator_uses_account_namespace() {
+    // Test: Account and instruction discriminators for the same name differ
+    assert_ne!(
+        account_discriminator("Proposal"),
+        instruction_discriminator("Proposal")
+    );
+}
+
+#[test]
+fn test_associated_token_address_matches_spl() {
+    // Test: The client's ATA derivation agrees with the SPL library
+    assert_eq!(
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        spl_associated_token_account::ID
+    );
+
+    let wallet = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    for token_program in [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID] {
+        assert_eq!(
+            associated_token_address(&wallet, &mint, &token_program),
+            get_associated_token_address_with_program_id(&wallet, &mint, &token_program)
+        );
+    }
+}
+
+#[test]
+fn test_decode_error_display() {
+    // Test: Errors read well when surfaced by off-chain tooling
+    assert_eq!(
+        DecodeError::DiscriminatorMismatch {
+            expected: "PoolConfig"
+        }
+        .to_string(),
+        "account data is not a PoolConfig"
+    );
+    assert_eq!(
+        DecodeError::InvalidLength {
+            expected: 168,
+            actual: 0
+        }
+        .to_string(),
+        "expected 168 bytes of account data, got 0"
+    );
+}
@@ -0,0 +1,286 @@
+// Unit tests for the escrow client
+
+use solana_sdk::pubkey::Pubkey;
+use soteria_client::escrow::{instructions, *};
+use soteria_client::token::{associated_token_address, TOKEN_PROGRAM_ID};
+use soteria_client::DecodeError;
+
+#[test]
+fn test_propose_offer_data_layout() {
+    // Test: 1 discriminator byte + version 2 + the 72-byte repr(C) ProposalOfferData
+    let allowed_taker = Pubkey::new_unique();
+    let data = instructions::propose_offer_data(1, 50, 100, 3_600, &allowed_taker, 253);
+
+    assert_eq!(data.len(), 2 + instructions::PROPOSE_OFFER_DATA_LEN);
+    assert_eq!(data[0], instructions::PROPOSE_OFFER);
+    assert_eq!(data[1], instructions::PROPOSE_OFFER_V2);
+    assert_eq!(data[2..10], 1u64.to_le_bytes());
+    assert_eq!(data[34..66], allowed_taker.to_bytes());
+    assert_eq!(data[66], 253);
+    assert_eq!(data[67..], [0u8; 7]);
+}
+
+#[test]
+fn test_propose_offer_v1_data_layout() {
+    // Test: 1 discriminator byte + version 1 + the 32-byte repr(C) ProposalOfferDataV1
+    let data = instructions::propose_offer_v1_data(1, 50, 100, 253);
+
+    assert_eq!(data.len(), 2 + instructions::PROPOSE_OFFER_V1_DATA_LEN);
+    assert_eq!(data[0], instructions::PROPOSE_OFFER);
+    assert_eq!(data[1], instructions::PROPOSE_OFFER_V1);
+    assert_eq!(data[2..10], 1u64.to_le_bytes());
+    assert_eq!(data[10..18], 50u64.to_le_bytes());
+    assert_eq!(data[18..26], 100u64.to_le_bytes());
+    assert_eq!(data[26], 253);
+    assert_eq!(data[27..], [0u8; 7]);
+}
+
+#[test]
+fn test_propose_offer_uses_offer_bump() {
+    // Test: The builder embeds the canonical bump of the derived offer PDA
+    let maker = Pubkey::new_unique();
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (offer, bump) = offer_address(&maker, 9);
+
+    let ix = instructions::propose_offer(
+        &maker,
+        &mint_a,
+        &mint_b,
+        9,
+        50,
+        100,
+        3_600,
+        &Pubkey::default(),
+        &TOKEN_PROGRAM_ID,
+    );
+    assert_eq!(ix.accounts[4].pubkey, offer);
+    assert_eq!(
+        ix.accounts[5].pubkey,
+        vault_address(&offer, &mint_a, &TOKEN_PROGRAM_ID)
+    );
+    assert_eq!(ix.data[66], bump);
+}
+
+#[test]
+fn test_propose_offer_batch_layout() {
+    // Test: Header + one entry per offer, and one (offer, vault) pair per offer
+    let maker = Pubkey::new_unique();
+    let entries = [(10, 20), (30, 40), (50, 60)];
+
+    let ix = instructions::propose_offer_batch(
+        &maker,
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        5,
+        3_600,
+        &Pubkey::default(),
+        &entries,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    assert_eq!(
+        ix.data.len(),
+        1 + instructions::BATCH_HEADER_LEN + entries.len() * instructions::BATCH_ENTRY_LEN
+    );
+    assert_eq!(ix.data[49], entries.len() as u8);
+    assert_eq!(ix.accounts.len(), 7 + 2 * entries.len());
+    assert_eq!(ix.accounts[7].pubkey, offer_address(&maker, 5).0);
+    assert_eq!(ix.accounts[11].pubkey, offer_address(&maker, 7).0);
+}
+
+#[test]
+fn test_decode_make_state() {
+    // Test: Fixed repr(C) layout decodes, wrong lengths are rejected
+    let proposer = Pubkey::new_unique();
+
+    let mut data = Vec::with_capacity(MakeState::LEN);
+    data.extend_from_slice(&1u64.to_le_bytes());
+    data.extend_from_slice(proposer.as_ref());
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // token_mint_a
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // token_mint_b
+    data.extend_from_slice(&50u64.to_le_bytes());
+    data.extend_from_slice(&100u64.to_le_bytes());
+    data.extend_from_slice(&3_600i64.to_le_bytes());
+    data.extend_from_slice(Pubkey::default().as_ref()); // allowed_taker
+    data.extend_from_slice(Pubkey::default().as_ref()); // arbiter
+    data.extend_from_slice(&0i64.to_le_bytes()); // dispute_window
+    data.extend_from_slice(&0i64.to_le_bytes()); // dispute_deadline
+    data.push(254); // bump
+    data.push(1); // is_initialized
+    data.resize(MakeState::LEN, 0);
+
+    let offer = MakeState::try_from_bytes(&data).expect("MakeState should decode");
+    assert_eq!(offer.id, 1);
+    assert_eq!(offer.proposer, proposer);
+    assert_eq!(offer.token_a_offered_amount, 100);
+    assert!(offer.is_initialized);
+    assert!(offer.is_public());
+    assert!(!offer.has_arbiter());
+
+    assert_eq!(
+        MakeState::try_from_bytes(&data[..160]),
+        Err(DecodeError::InvalidLength {
+            expected: MakeState::LEN,
+            actual: 160
+        })
+    );
+}
+
+#[test]
+fn test_take_offer_with_fee_layout() {
+    // Test: TakeOffer accounts unchanged, fee accounts appended after the proposer's ATA A
+    let (taker, proposer, fee_authority) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    let take = instructions::take_offer(&taker, &proposer, &mint_a, &mint_b, 3, &TOKEN_PROGRAM_ID);
+    let ix = instructions::take_offer_with_fee(
+        &taker,
+        &proposer,
+        &mint_a,
+        &mint_b,
+        3,
+        &fee_authority,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    assert_eq!(ix.data, vec![instructions::TAKE_OFFER_WITH_FEE]);
+    assert_eq!(ix.accounts.len(), take.accounts.len() + 3);
+    assert_eq!(ix.accounts[..take.accounts.len()], take.accounts[..]);
+    assert_eq!(
+        take.accounts[12].pubkey,
+        associated_token_address(&proposer, &mint_a, &TOKEN_PROGRAM_ID)
+    );
+    assert!(take.accounts[12].is_writable);
+    assert_eq!(ix.accounts[13].pubkey, fee_config_address().0);
+    assert_eq!(ix.accounts[14].pubkey, fee_authority);
+    assert_eq!(
+        ix.accounts[15].pubkey,
+        fee_vault_address(&fee_authority, &mint_b, &TOKEN_PROGRAM_ID)
+    );
+    assert!(ix.accounts[15].is_writable);
+}
+
+#[test]
+fn test_set_fee_config_data_layout() {
+    // Test: 1 discriminator byte + fee_bps as u16 LE
+    let data = instructions::set_fee_config_data(250);
+    assert_eq!(data, vec![instructions::SET_FEE_CONFIG, 250, 0]);
+}
+
+#[test]
+fn test_decode_fee_config() {
+    // Test: Fixed repr(C) layout decodes, fee rounds down without overflowing
+    let authority = Pubkey::new_unique();
+
+    let mut data = Vec::with_capacity(FeeConfig::LEN);
+    data.extend_from_slice(authority.as_ref());
+    data.extend_from_slice(&100u16.to_le_bytes());
+    data.push(255); // bump
+    data.push(1); // is_initialized
+
+    let config = FeeConfig::try_from_bytes(&data).expect("FeeConfig should decode");
+    assert_eq!(config.authority, authority);
+    assert_eq!(config.fee_bps, 100);
+    assert!(config.is_initialized);
+    assert_eq!(config.fee_for(199), 1);
+    assert_eq!(config.fee_for(u64::MAX), u64::MAX / 100);
+
+    assert!(FeeConfig::try_from_bytes(&data[..35]).is_err());
+}
+
+#[test]
+fn test_arbiter_dispute_layout() {
+    // Test: ArbiterConfig is appended to ProposeOffer data; dispute ixs keep the program's account order
+    let (maker, taker, arbiter) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    let propose = instructions::propose_offer(
+        &maker,
+        &mint_a,
+        &mint_b,
+        9,
+        50,
+        100,
+        3_600,
+        &taker,
+        &TOKEN_PROGRAM_ID,
+    );
+    let ix = instructions::propose_offer_with_arbiter(
+        &maker,
+        &mint_a,
+        &mint_b,
+        9,
+        50,
+        100,
+        3_600,
+        &taker,
+        &arbiter,
+        600,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    assert_eq!(ix.accounts, propose.accounts);
+    assert_eq!(
+        ix.data.len(),
+        2 + instructions::PROPOSE_OFFER_DATA_LEN + instructions::ARBITER_CONFIG_LEN
+    );
+    assert_eq!(&ix.data[..propose.data.len()], &propose.data[..]);
+    assert_eq!(&ix.data[74..106], arbiter.as_ref());
+    assert_eq!(&ix.data[106..], &600i64.to_le_bytes());
+
+    let (offer, _) = offer_address(&maker, 9);
+
+    let open = instructions::open_dispute(&taker, &maker, 9);
+    assert_eq!(open.data, vec![instructions::OPEN_DISPUTE]);
+    assert!(open.accounts[0].is_signer);
+    assert_eq!(open.accounts[1].pubkey, offer);
+
+    let resolve = instructions::resolve_dispute(
+        &arbiter,
+        &maker,
+        &taker,
+        &mint_a,
+        9,
+        instructions::DisputeAward::Taker,
+        &TOKEN_PROGRAM_ID,
+    );
+    assert_eq!(resolve.data, vec![instructions::RESOLVE_DISPUTE, 1]);
+    assert_eq!(resolve.accounts.len(), 9);
+    assert!(resolve.accounts[0].is_signer);
+    assert_eq!(resolve.accounts[5].pubkey, offer);
+    assert_eq!(
+        resolve.accounts[6].pubkey,
+        vault_address(&offer, &mint_a, &TOKEN_PROGRAM_ID)
+    );
+}
+
+#[test]
+fn test_sweep_dust_layout() {
+    // Test: SweepDust takes the maker's ATA A and the offer's vault, with the maker signing
+    let (maker, mint_a) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (offer, _) = offer_address(&maker, 4);
+
+    let ix = instructions::sweep_dust(&maker, &mint_a, 4, &TOKEN_PROGRAM_ID);
+
+    assert_eq!(ix.data, vec![instructions::SWEEP_DUST]);
+    assert_eq!(ix.accounts.len(), 6);
+    assert!(ix.accounts[0].is_signer);
+    assert_eq!(
+        ix.accounts[2].pubkey,
+        associated_token_address(&maker, &mint_a, &TOKEN_PROGRAM_ID)
+    );
+    assert_eq!(ix.accounts[3].pubkey, offer);
+    assert!(!ix.accounts[3].is_writable);
+    assert_eq!(
+        ix.accounts[4].pubkey,
+        vault_address(&offer, &mint_a, &TOKEN_PROGRAM_ID)
+    );
+}
@@ -0,0 +1,520 @@
+// Unit tests for the governance client
+
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use soteria_client::account_discriminator;
+use soteria_client::governance::{instructions, *};
+
+#[test]
+fn test_init_dao_data() {
+    // Test: Every DaoParams field is written in argument order
+    let params = instructions::DaoParams {
+        admin: Pubkey::new_unique(),
+        minimum_stake: 1_000,
+        token_mint: Pubkey::new_unique(),
+        vote_power: 2,
+        quorum: 10_000_000,
+        approval_threshold_bps: 5_000,
+        unbonding_period: 604_800,
+        decay_rate_bps: 1_000,
+        decay_epoch_seconds: 604_800,
+        reward_rate_per_sec: 1_000,
+        stake_ramp_seconds: 86_400,
+        proposal_deposit: 5_000_000,
+    };
+    let ix = instructions::init_dao(&params.admin, &params);
+
+    assert_eq!(ix.accounts[1].pubkey, config_address(&params.admin).0);
+    // 8 discriminator + 32 + 8 + 32 + 1 + 8 + 2 + 8 + 2 + 8 + 8 + 8 + 8
+    assert_eq!(ix.data.len(), 133);
+    assert_eq!(ix.data[8..40], params.admin.to_bytes());
+    assert_eq!(ix.data[80], 2); // vote_power
+    assert_eq!(ix.data[117..125], 86_400i64.to_le_bytes()); // stake_ramp_seconds
+    assert_eq!(ix.data[125..], 5_000_000u64.to_le_bytes()); // proposal_deposit
+}
+
+#[test]
+fn test_slash_stake_without_delegate() {
+    // Test: Optional delegation accounts fall back to the program ID
+    let user = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let ix = instructions::slash_stake(&admin, &admin, &mint, &user, 500, true, None);
+    assert_eq!(ix.accounts[6].pubkey, PROGRAM_ID);
+    assert_eq!(ix.accounts[7].pubkey, PROGRAM_ID);
+
+    let delegate = Pubkey::new_unique();
+    let ix = instructions::slash_stake(&admin, &admin, &mint, &user, 500, true, Some(&delegate));
+    assert_eq!(ix.accounts[6].pubkey, delegation_address(&user).0);
+    assert_eq!(ix.accounts[7].pubkey, user_profile_address(&delegate).0);
+    assert_eq!(ix.accounts[9].pubkey, treasury_token_address(&admin, &mint));
+}
+
+#[test]
+fn test_decode_user_profile() {
+    // Test: Fields after the variable-length username decode at the right offsets
+    let owner = Pubkey::new_unique();
+    let username = "alice";
+
+    let mut data = account_discriminator("UserProfile").to_vec();
+    data.extend_from_slice(&(username.len() as u32).to_le_bytes());
+    data.extend_from_slice(username.as_bytes());
+    data.extend_from_slice(owner.as_ref());
+    data.extend_from_slice(&(-25i64).to_le_bytes()); // reputation_points
+    data.extend_from_slice(&5_000u64.to_le_bytes()); // stake_amount
+    data.push(MemberRanks::Guardian as u8);
+    for value in [3u64, 1, 9] {
+        data.extend_from_slice(&value.to_le_bytes()); // vote counters
+    }
+    for value in [10i64, 20, 30] {
+        data.extend_from_slice(&value.to_le_bytes()); // timestamps
+    }
+    data.extend_from_slice(Pubkey::default().as_ref()); // delegated_to
+    data.extend_from_slice(&0u64.to_le_bytes()); // delegated_stake
+    data.extend_from_slice(&40i64.to_le_bytes()); // last_decay_ts
+    data.extend_from_slice(&0u64.to_le_bytes()); // reward_debt
+    data.extend_from_slice(&50i64.to_le_bytes()); // last_reward_ts
+    data.extend_from_slice(&7_500u64.to_le_bytes()); // weighted_stake
+    data.extend_from_slice(&60i64.to_le_bytes()); // stake_start_ts
+    data.push(0); // metadata_uri: None
+
+    let profile = UserProfile::try_from_bytes(&data).expect("UserProfile should decode");
+    assert_eq!(profile.username, username);
+    assert_eq!(profile.owner, owner);
+    assert_eq!(profile.reputation_points, -25);
+    assert_eq!(profile.stake_amount, 5_000);
+    assert_eq!(profile.role_level, MemberRanks::Guardian);
+    assert_eq!(profile.total_votes_cast, 9);
+    assert_eq!(profile.last_reward_ts, 50);
+    assert_eq!(profile.weighted_stake, 7_500);
+    assert_eq!(profile.stake_start_ts, 60);
+    assert_eq!(profile.metadata_uri, None);
+
+    // A set metadata URI follows as Some(len, bytes)
+    let uri = "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+    data.pop();
+    data.push(1);
+    data.extend_from_slice(&(uri.len() as u32).to_le_bytes());
+    data.extend_from_slice(uri.as_bytes());
+
+    let profile = UserProfile::try_from_bytes(&data).expect("UserProfile should decode");
+    assert_eq!(profile.metadata_uri.as_deref(), Some(uri));
+}
+
+#[test]
+fn test_update_profile_metadata_data() {
+    // Test: The URI is written as a Borsh Option<String>
+    let user = Pubkey::new_unique();
+    let uri = "https://example.com/alice.json";
+
+    let ix = instructions::update_profile_metadata(&user, Some(uri));
+    assert_eq!(ix.accounts[1].pubkey, user_profile_address(&user).0);
+    assert_eq!(ix.data[8], 1);
+    assert_eq!(ix.data[9..13], (uri.len() as u32).to_le_bytes());
+    assert_eq!(&ix.data[13..], uri.as_bytes());
+
+    let ix = instructions::update_profile_metadata(&user, None);
+    assert_eq!(ix.data.len(), 9);
+    assert_eq!(ix.data[8], 0);
+}
+
+#[test]
+fn test_stake_supported_mint_accounts() {
+    // Test: Base-mint stakes leave the registry accounts as "None"
+    let user = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let (config, _) = config_address(&admin);
+
+    let ix = instructions::stake_tokens(&user, &admin, &mint, 1_000);
+    assert_eq!(ix.accounts[8].pubkey, PROGRAM_ID);
+    assert_eq!(ix.accounts[9].pubkey, PROGRAM_ID);
+
+    let ix = instructions::stake_supported_mint(&user, &admin, &mint, 1_000);
+    assert_eq!(ix.accounts[7].pubkey, treasury_token_address(&admin, &mint));
+    assert_eq!(ix.accounts[8].pubkey, supported_mint_address(&config, &mint).0);
+    assert_eq!(ix.accounts[9].pubkey, mint_stake_address(&config, &mint, &user).0);
+}
+
+#[test]
+fn test_decode_supported_mint() {
+    // Test: Registry fields decode in declaration order
+    let config = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let treasury_token_account = Pubkey::new_unique();
+
+    let mut data = account_discriminator("SupportedMint").to_vec();
+    data.extend_from_slice(config.as_ref());
+    data.extend_from_slice(mint.as_ref());
+    data.extend_from_slice(&25_000u16.to_le_bytes()); // weight_bps
+    data.extend_from_slice(treasury_token_account.as_ref());
+    data.extend_from_slice(&9_000u64.to_le_bytes()); // total_staked
+    data.push(254); // bump
+
+    let entry = SupportedMint::try_from_bytes(&data).expect("SupportedMint should decode");
+    assert_eq!(entry.config, config);
+    assert_eq!(entry.mint, mint);
+    assert_eq!(entry.weight_bps, 25_000);
+    assert_eq!(entry.treasury_token_account, treasury_token_account);
+    assert_eq!(entry.total_staked, 9_000);
+    assert_eq!(entry.bump, 254);
+}
+
+#[test]
+fn test_decode_vote_history_entries() {
+    // Test: Vec entries decode and unknown enum tags are rejected
+    let target = Pubkey::new_unique();
+
+    let mut data = account_discriminator("VoteHistory").to_vec();
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // owner
+    data.push(255); // bump
+    data.extend_from_slice(&4u16.to_le_bytes()); // capacity
+    data.extend_from_slice(&1u16.to_le_bytes()); // head
+    data.extend_from_slice(&1u64.to_le_bytes()); // total_recorded
+    data.extend_from_slice(&1u32.to_le_bytes()); // entries
+    data.extend_from_slice(target.as_ref());
+    data.push(1); // Downvote
+    data.extend_from_slice(&100i64.to_le_bytes());
+    data.extend_from_slice(&(-2i64).to_le_bytes());
+
+    let history = VoteHistory::try_from_bytes(&data).expect("VoteHistory should decode");
+    assert_eq!(history.capacity, 4);
+    assert_eq!(history.entries.len(), 1);
+    assert_eq!(history.entries[0].target, target);
+    assert_eq!(history.entries[0].vote_type, VoteType::Downvote);
+    assert_eq!(history.entries[0].weight, -2);
+
+    // Corrupt the vote type tag
+    let tag_offset = 8 + 32 + 1 + 2 + 2 + 8 + 4 + 32;
+    data[tag_offset] = 7;
+    assert_eq!(
+        VoteHistory::try_from_bytes(&data),
+        Err(soteria_client::DecodeError::InvalidEnumTag {
+            name: "VoteType",
+            tag: 7
+        })
+    );
+}
+
+#[test]
+fn test_multisig_action_instructions() {
+    // Test: The action is written in the multisig's ProposalType encoding and
+    // execution signs nothing but the payer
+    let proposer = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let multisig = Pubkey::new_unique();
+    let multisig_proposal = Pubkey::new_unique();
+    let (config, _) = config_address(&admin);
+    let (proposal, _) = proposal_address(&config, 3);
+
+    let action = soteria_client::multisig::ProposalType::ChangeTimelock { new_timelock: 3_600 };
+    let ix = instructions::attach_multisig_action(&proposer, &admin, 3, &multisig, action);
+    assert_eq!(ix.accounts[4].pubkey, multisig_action_address(&proposal).0);
+    // 8 discriminator + 32 multisig + 1 tag + 8 new_timelock
+    assert_eq!(ix.data.len(), 49);
+    assert_eq!(ix.data[8..40], multisig.to_bytes());
+    assert_eq!(ix.data[40], 3);
+
+    let ix = instructions::execute_multisig_action(
+        &proposer,
+        &admin,
+        3,
+        &multisig,
+        &multisig_proposal,
+    );
+    assert_eq!(ix.accounts[5].pubkey, multisig_executor_address(&config).0);
+    assert_eq!(ix.accounts[8].pubkey, soteria_client::multisig::PROGRAM_ID);
+    assert_eq!(ix.accounts[10].pubkey, council_address(&config).0);
+    assert_eq!(ix.accounts.iter().filter(|meta| meta.is_signer).count(), 1);
+}
+
+#[test]
+fn test_council_instructions() {
+    // Test: Members are a Borsh Vec, and co-signers trail the fixed accounts
+    // as read-only signers
+    let admin = Pubkey::new_unique();
+    let members = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+    let (config, _) = config_address(&admin);
+    let (council, _) = council_address(&config);
+
+    let ix = instructions::init_council(&admin, &admin, &members, 2, 86_400);
+    assert_eq!(ix.accounts[3].pubkey, council);
+    // 8 discriminator + 4 + 3 * 32 members + 1 threshold + 8 veto_window
+    assert_eq!(ix.data.len(), 117);
+    assert_eq!(ix.data[8..12], 3u32.to_le_bytes());
+    assert_eq!(ix.data[12..44], members[0].to_bytes());
+    assert_eq!(ix.data[108], 2);
+    assert_eq!(ix.data[109..], 86_400i64.to_le_bytes());
+
+    let ix = instructions::council_set_pause(&members[0], &admin, &members[1..2], true);
+    assert_eq!(ix.data[8..], [1]);
+    assert!(ix.accounts[2].is_writable);
+    assert_eq!(ix.accounts.len(), 5);
+    assert!(ix.accounts[4].is_signer && !ix.accounts[4].is_writable);
+
+    let ix = instructions::council_veto(&members[2], &admin, 4, &members[..2]);
+    assert_eq!(ix.accounts[4].pubkey, proposal_address(&config, 4).0);
+    assert!(ix.accounts[4].is_writable);
+    assert_eq!(ix.accounts.iter().filter(|meta| meta.is_signer).count(), 3);
+}
+
+#[test]
+fn test_decode_council() {
+    // Test: Member list and veto window decode; Vetoed is status tag 3
+    let config = Pubkey::new_unique();
+    let members = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let mut data = account_discriminator("Council").to_vec();
+    data.extend_from_slice(config.as_ref());
+    data.extend_from_slice(&2u32.to_le_bytes());
+    for member in &members {
+        data.extend_from_slice(member.as_ref());
+    }
+    data.push(2); // threshold
+    data.extend_from_slice(&3_600i64.to_le_bytes()); // veto_window
+    data.push(255); // bump
+
+    let council = Council::try_from_bytes(&data).expect("Council should decode");
+    assert_eq!(council.config, config);
+    assert_eq!(council.members, members);
+    assert_eq!(council.threshold, 2);
+    assert_eq!(council.veto_window, 3_600);
+
+    let mut data = account_discriminator("Proposal").to_vec();
+    data.extend_from_slice(config.as_ref()); // config
+    data.extend_from_slice(members[0].as_ref()); // proposer
+    data.extend_from_slice(&1u64.to_le_bytes()); // proposal_id
+    data.extend_from_slice(&0u32.to_le_bytes()); // title
+    data.extend_from_slice(&[0; 16]); // votes_for, votes_against
+    data.extend_from_slice(&100i64.to_le_bytes()); // created_at
+    data.extend_from_slice(&200i64.to_le_bytes()); // voting_ends_at
+    data.extend_from_slice(&[3, 254]); // status, bump
+
+    let proposal = Proposal::try_from_bytes(&data).expect("Proposal should decode");
+    assert_eq!(proposal.status, ProposalStatus::Vetoed);
+    assert_eq!(council.veto_deadline(&proposal), 3_800);
+}
+
+#[test]
+fn test_vote_batch_instructions() {
+    // Test: Signature data stays inside the Ed25519 instruction and every entry
+    // brings its profile and ballot, in entry order
+    let relayer = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let voter = Pubkey::new_unique();
+    let (config, _) = config_address(&admin);
+    let (proposal, _) = proposal_address(&config, 2);
+
+    let entry = instructions::VoteBatchEntry { voter, support: true, weight: 5_000 };
+    let message = entry.vote_message(&proposal);
+    assert_eq!(message[..32], proposal.to_bytes());
+    assert_eq!(message[32], 1);
+    assert_eq!(message[33..], 5_000u64.to_le_bytes());
+
+    let ix = instructions::ed25519_verify(&voter, &Signature::from([7u8; 64]), &message);
+    assert_eq!(ix.program_id, instructions::ED25519_PROGRAM_ID);
+    // 2 header + 14 offsets + 32 key + 64 signature + 41 message
+    assert_eq!(ix.data.len(), 153);
+    for index in [4, 8, 14] {
+        assert_eq!(ix.data[index..index + 2], u16::MAX.to_le_bytes());
+    }
+    assert_eq!(ix.data[16..48], voter.to_bytes());
+    assert_eq!(ix.data[112..], message);
+
+    let ix = instructions::submit_vote_batch(&relayer, &admin, 2, &[entry]);
+    assert_eq!(ix.accounts.len(), 8);
+    assert_eq!(ix.accounts[3].pubkey, proposal);
+    assert_eq!(ix.accounts[6].pubkey, user_profile_address(&voter).0);
+    assert_eq!(ix.accounts[7].pubkey, ballot_address(&proposal, &voter).0);
+    assert!(ix.accounts[7].is_writable);
+    // 8 discriminator + 4 length + 41 per entry
+    assert_eq!(ix.data.len(), 53);
+}
+
+#[test]
+fn test_decode_vote_record_season() {
+    // Test: Season sits after the timestamp and the close builder targets the same record
+    let voter = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let target_owner = Pubkey::new_unique();
+    let username = "alice";
+
+    let mut data = account_discriminator("VoteRecord").to_vec();
+    data.extend_from_slice(voter.as_ref());
+    data.extend_from_slice(&(username.len() as u32).to_le_bytes());
+    data.extend_from_slice(username.as_bytes());
+    data.extend_from_slice(target_owner.as_ref());
+    data.push(1); // Downvote
+    data.extend_from_slice(&15i64.to_le_bytes()); // vote_weight
+    data.extend_from_slice(&100i64.to_le_bytes()); // timestamp
+    data.extend_from_slice(&3u64.to_le_bytes()); // season
+    data.push(253); // bump
+
+    let record = VoteRecord::try_from_bytes(&data).expect("VoteRecord should decode");
+    assert_eq!(record.target_username, username);
+    assert_eq!(record.vote_type, VoteType::Downvote);
+    assert_eq!(record.season, 3);
+    assert_eq!(record.bump, 253);
+
+    let ix = instructions::close_vote_record(&voter, &admin, username);
+    assert_eq!(ix.accounts[2].pubkey, config_address(&admin).0);
+    assert_eq!(ix.accounts[3].pubkey, vote_record_address(&voter, username).0);
+    // 8 discriminator + 4 length + username
+    assert_eq!(ix.data.len(), 17);
+}
+
+#[test]
+fn test_vote_escrow_instructions() {
+    // Test: Ballots leave the escrow as "None" unless asked, and the lock
+    // tokens sit in the escrow's own ATA
+    let user = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let (config, _) = config_address(&admin);
+    let (vote_escrow, _) = vote_escrow_address(&config, &user);
+
+    let ix = instructions::cast_ballot(&user, &admin, 0, true);
+    assert_eq!(ix.accounts[6].pubkey, PROGRAM_ID);
+
+    let ix = instructions::cast_ballot_with_escrow(&user, &admin, 0, true);
+    assert_eq!(ix.accounts[6].pubkey, vote_escrow);
+    assert!(!ix.accounts[6].is_writable);
+
+    let ix = instructions::lock_tokens(&user, &admin, &mint, 1_000, MAX_LOCK_SECONDS);
+    assert_eq!(ix.accounts[3].pubkey, vote_escrow);
+    assert_eq!(
+        ix.accounts[6].pubkey,
+        soteria_client::token::associated_token_address(
+            &vote_escrow,
+            &mint,
+            &soteria_client::token::TOKEN_PROGRAM_ID
+        )
+    );
+    assert_eq!(&ix.data[8..16], &1_000u64.to_le_bytes());
+    assert_eq!(&ix.data[16..], &MAX_LOCK_SECONDS.to_le_bytes());
+
+    let ix = instructions::withdraw_expired_lock(&user, &admin, &mint);
+    assert_eq!(ix.accounts[3].pubkey, vote_escrow);
+    assert!(ix.accounts[3].is_writable);
+}
+
+#[test]
+fn test_decode_vote_escrow() {
+    // Test: Lock fields decode in declaration order and power decays to zero
+    let owner = Pubkey::new_unique();
+    let config = Pubkey::new_unique();
+    let mut data = account_discriminator("VoteEscrow").to_vec();
+    data.extend_from_slice(owner.as_ref());
+    data.extend_from_slice(config.as_ref());
+    data.extend_from_slice(&4_000u64.to_le_bytes()); // amount
+    data.extend_from_slice(&100i64.to_le_bytes()); // locked_at
+    data.extend_from_slice(&(100 + MAX_LOCK_SECONDS).to_le_bytes()); // unlock_at
+    data.push(253); // bump
+
+    let escrow = VoteEscrow::try_from_bytes(&data).expect("VoteEscrow should decode");
+    assert_eq!(escrow.owner, owner);
+    assert_eq!(escrow.config, config);
+    assert_eq!(escrow.amount, 4_000);
+    assert_eq!(escrow.locked_at, 100);
+    assert_eq!(escrow.bump, 253);
+
+    assert_eq!(escrow.voting_power(100), 4_000);
+    assert_eq!(escrow.voting_power(100 + MAX_LOCK_SECONDS / 4), 3_000);
+    assert_eq!(escrow.voting_power(escrow.unlock_at), 0);
+    assert_eq!(escrow.voting_power(escrow.unlock_at + 1), 0);
+}
+
+#[test]
+fn test_proposal_deposit_accounts() {
+    // Test: Create takes the deposit into the deposit PDA's ATA; finalize pays
+    // the proposer and closes the deposit accounts to them
+    let proposer = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let (config, _) = config_address(&admin);
+    let (proposal, _) = proposal_address(&config, 3);
+    let (proposal_deposit, _) = proposal_deposit_address(&proposal);
+    let deposit_ata = soteria_client::token::associated_token_address(
+        &proposal_deposit,
+        &mint,
+        &soteria_client::token::TOKEN_PROGRAM_ID,
+    );
+
+    let ix = instructions::create_governance_proposal(&proposer, &admin, &mint, 3, "Grants");
+    assert_eq!(ix.accounts[4].pubkey, proposal);
+    assert_eq!(ix.accounts[5].pubkey, mint);
+    assert_eq!(ix.accounts[7].pubkey, proposal_deposit);
+    assert_eq!(ix.accounts[8].pubkey, deposit_ata);
+
+    let caller = Pubkey::new_unique();
+    let ix = instructions::finalize_proposal(&caller, &admin, &mint, 3, &proposer);
+    assert_eq!(ix.accounts[4].pubkey, proposer);
+    assert!(ix.accounts[4].is_writable);
+    // Burning changes the mint's supply
+    assert!(ix.accounts[5].is_writable);
+    assert_eq!(ix.accounts[7].pubkey, proposal_deposit);
+    assert_eq!(ix.accounts[8].pubkey, deposit_ata);
+}
+
+#[test]
+fn test_decode_proposal_deposit() {
+    // Test: Deposit fields decode in declaration order
+    let proposal = Pubkey::new_unique();
+    let depositor = Pubkey::new_unique();
+    let mut data = account_discriminator("ProposalDeposit").to_vec();
+    data.extend_from_slice(proposal.as_ref());
+    data.extend_from_slice(depositor.as_ref());
+    data.extend_from_slice(&2_000_000u64.to_le_bytes()); // amount
+    data.push(254); // bump
+
+    let deposit = ProposalDeposit::try_from_bytes(&data).expect("ProposalDeposit should decode");
+    assert_eq!(deposit.proposal, proposal);
+    assert_eq!(deposit.depositor, depositor);
+    assert_eq!(deposit.amount, 2_000_000);
+    assert_eq!(deposit.bump, 254);
+}
+
+#[test]
+fn test_treasury_value_report_accounts() {
+    // Test: Each holding adds [holding, token account, feed, supported mint PDA]
+    // in the order given, all read-only
+    let admin = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let feed = Pubkey::new_unique();
+    let (config, _) = config_address(&admin);
+    let (treasury, _) = treasury_address(&admin);
+
+    let ix = instructions::treasury_value_report(&admin, &[(mint, feed)]);
+    assert_eq!(ix.accounts.len(), 3 + 4);
+    assert_eq!(ix.accounts[2].pubkey, treasury);
+    assert_eq!(ix.accounts[3].pubkey, treasury_holding_address(&treasury, &mint).0);
+    assert_eq!(ix.accounts[4].pubkey, treasury_token_address(&admin, &mint));
+    assert_eq!(ix.accounts[5].pubkey, feed);
+    assert_eq!(ix.accounts[6].pubkey, supported_mint_address(&config, &mint).0);
+    assert!(ix.accounts.iter().all(|meta| !meta.is_writable && !meta.is_signer));
+}
+
+#[test]
+fn test_decode_treasury_value_report() {
+    // Test: Return data is plain Borsh (no discriminator), u128 values
+    let mint = Pubkey::new_unique();
+    let mut data = 2_500_000u128.to_le_bytes().to_vec(); // total_value
+    data.extend_from_slice(&1u32.to_le_bytes()); // holdings.len()
+    data.extend_from_slice(mint.as_ref());
+    data.extend_from_slice(&1_000_000u64.to_le_bytes()); // amount
+    data.extend_from_slice(&2_500_000u64.to_le_bytes()); // price
+    data.extend_from_slice(&(-6i32).to_le_bytes()); // exponent
+    data.extend_from_slice(&2_500_000u128.to_le_bytes()); // value
+
+    let report = TreasuryValueReport::try_from_bytes(&data).expect("report should decode");
+    assert_eq!(report.total_value, 2_500_000);
+    assert_eq!(
+        report.holdings,
+        vec![HoldingValue {
+            mint,
+            amount: 1_000_000,
+            price: 2_500_000,
+            exponent: -6,
+            value: 2_500_000,
+        }]
+    );
+}
@@ -0,0 +1,480 @@
+// Unit tests for the multisig client
+
+use sha2::Digest;
+use solana_sdk::pubkey::Pubkey;
+use soteria_client::multisig::{instructions, *};
+use soteria_client::token::{associated_token_address, TOKEN_PROGRAM_ID};
+use soteria_client::{account_discriminator, instruction_discriminator, DecodeError};
+
+// Build raw Multisig account data with `owners` as admins
+fn multisig_account_data(creator: &Pubkey, owners: &[Pubkey], threshold: u8) -> Vec<u8> {
+    let mut data = account_discriminator("Multisig").to_vec();
+    data.extend_from_slice(&7u64.to_le_bytes()); // multisig_id
+    data.extend_from_slice(creator.as_ref());
+    data.push(threshold);
+    data.push(owners.len() as u8);
+    for index in 0..MAX_OWNERS {
+        let owner = owners.get(index).copied().unwrap_or_default();
+        data.extend_from_slice(owner.as_ref());
+        data.push(MemberRole::Admin as u8);
+    }
+    data.extend_from_slice(&3u64.to_le_bytes()); // proposal_count
+    data.extend_from_slice(&2u64.to_le_bytes()); // last_executed_proposal
+    data.push(0); // paused
+    data.extend_from_slice(&3600u64.to_le_bytes()); // timelock_seconds
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // vault
+    data.push(254); // bump
+    data.push(253); // vault_bump
+    data.push(1); // vault_pays_rent
+    data.extend_from_slice(&[0, 4, 0, 2, 0]); // threshold_policy entries
+    data.extend_from_slice(&1_000_000u64.to_le_bytes()); // small_transfer_limit
+    data
+}
+
+#[test]
+fn test_create_proposal_data() {
+    // Test: ProposalType is Borsh-encoded after the discriminator
+    let new_member = Pubkey::new_unique();
+    let ix = instructions::create_proposal(
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        ProposalType::AddMember {
+            new_member,
+            role: MemberRole::Executor,
+        },
+    );
+
+    assert_eq!(ix.program_id, PROGRAM_ID);
+    assert_eq!(ix.data[..8], instruction_discriminator("create_proposal"));
+    assert_eq!(ix.data[8], 0); // AddMember variant
+    assert_eq!(ix.data[9..41], new_member.to_bytes());
+    assert_eq!(ix.data[41], MemberRole::Executor as u8);
+    assert_eq!(ix.data.len(), 42);
+}
+
+#[test]
+fn test_approve_parent_proposal_data() {
+    // Test: ApproveParentProposal encodes parent multisig then parent proposal id
+    let parent_multisig = Pubkey::new_unique();
+    let ix = instructions::create_proposal(
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        ProposalType::ApproveParentProposal {
+            parent_multisig,
+            parent_proposal_id: 5,
+        },
+    );
+
+    assert_eq!(ix.data[8], 4); // ApproveParentProposal variant
+    assert_eq!(ix.data[9..41], parent_multisig.to_bytes());
+    assert_eq!(ix.data[41..49], 5u64.to_le_bytes());
+    assert_eq!(ix.data.len(), 49);
+}
+
+#[test]
+fn test_vault_stake_proposal_data() {
+    // Test: Stake variants follow SetVaultPaysRent; vote account is optional on execute
+    let vote_account = Pubkey::new_unique();
+    let ix = instructions::create_proposal(
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        ProposalType::DelegateVaultStake {
+            vote_account,
+            amount: 3,
+        },
+    );
+    assert_eq!(ix.data[8], 6); // DelegateVaultStake variant
+    assert_eq!(ix.data[9..41], vote_account.to_bytes());
+    assert_eq!(ix.data[41..49], 3u64.to_le_bytes());
+
+    let multisig = Pubkey::new_unique();
+    let (stake_account, _) = vault_stake_address(&multisig, 0);
+    assert_ne!(stake_account, vault_stake_address(&multisig, 1).0);
+    let ix = instructions::execute_stake_proposal(
+        &Pubkey::new_unique(),
+        &multisig,
+        &proposal_address(&multisig, 0).0,
+        &Pubkey::new_unique(),
+        &vault_address(&multisig).0,
+        &stake_account,
+        None,
+    );
+    assert_eq!(ix.accounts.len(), 13);
+    assert_eq!(ix.accounts[5].pubkey, stake_account);
+    assert_eq!(ix.accounts[6].pubkey, PROGRAM_ID);
+    assert_eq!(ix.accounts[11].pubkey, STAKE_PROGRAM_ID);
+}
+
+#[test]
+fn test_sweep_token_proposal() {
+    // Test: SweepToken follows the stake variants; execute derives both ATAs
+    let (mint, recipient) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let ix = instructions::create_proposal(
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        ProposalType::SweepToken { mint, recipient },
+    );
+    assert_eq!(ix.data[8], 9); // SweepToken variant
+    assert_eq!(ix.data[9..41], mint.to_bytes());
+    assert_eq!(ix.data[41..73], recipient.to_bytes());
+
+    let multisig = Pubkey::new_unique();
+    let (vault, _) = vault_address(&multisig);
+    let ix = instructions::execute_sweep_proposal(
+        &Pubkey::new_unique(),
+        &multisig,
+        &proposal_address(&multisig, 0).0,
+        &Pubkey::new_unique(),
+        &vault,
+        &mint,
+        &recipient,
+        &TOKEN_PROGRAM_ID,
+    );
+    assert_eq!(ix.data, instruction_discriminator("execute_sweep_proposal"));
+    assert_eq!(ix.accounts.len(), 10);
+    assert_eq!(
+        ix.accounts[6].pubkey,
+        associated_token_address(&vault, &mint, &TOKEN_PROGRAM_ID)
+    );
+    assert_eq!(
+        ix.accounts[8].pubkey,
+        associated_token_address(&recipient, &mint, &TOKEN_PROGRAM_ID)
+    );
+    assert!(ix.accounts[6].is_writable && ix.accounts[8].is_writable);
+}
+
+#[test]
+fn test_lookup_table_proposals() {
+    // Test: Lookup table variants follow SweepToken; Extend commits to the address list
+    let ix = instructions::create_proposal(
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        ProposalType::CreateLookupTable,
+    );
+    assert_eq!(ix.data.len(), 9);
+    assert_eq!(ix.data[8], 10); // CreateLookupTable variant
+
+    let lookup_table = Pubkey::new_unique();
+    let addresses = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let proposal_type = ProposalType::extend_lookup_table(lookup_table, &addresses);
+    let ix = instructions::create_proposal(
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        proposal_type,
+    );
+    let mut preimage = LOOKUP_TABLE_ADDRESSES_DOMAIN.to_vec();
+    preimage.extend_from_slice(addresses[0].as_ref());
+    preimage.extend_from_slice(addresses[1].as_ref());
+    assert_eq!(ix.data[8], 11); // ExtendLookupTable variant
+    assert_eq!(ix.data[9..41], lookup_table.to_bytes());
+    assert_eq!(ix.data[41..73], sha2::Sha256::digest(&preimage)[..]);
+    assert_eq!(ix.data[73], 2);
+    assert_ne!(
+        lookup_table_addresses_hash(&[addresses[1], addresses[0]]),
+        lookup_table_addresses_hash(&addresses)
+    );
+
+    let multisig = Pubkey::new_unique();
+    let (vault, _) = vault_address(&multisig);
+    let (table, _) = vault_lookup_table_address(&vault, 42);
+    assert_ne!(table, vault_lookup_table_address(&vault, 43).0);
+    let ix = instructions::execute_lookup_table_proposal(
+        &Pubkey::new_unique(),
+        &multisig,
+        &proposal_address(&multisig, 0).0,
+        &Pubkey::new_unique(),
+        &vault,
+        &table,
+        42,
+        &addresses,
+    );
+    assert_eq!(
+        ix.data[..8],
+        instruction_discriminator("execute_lookup_table_proposal")
+    );
+    assert_eq!(ix.data[8..16], 42u64.to_le_bytes());
+    assert_eq!(ix.data[16..20], 2u32.to_le_bytes());
+    assert_eq!(ix.data.len(), 20 + 64);
+    assert_eq!(ix.accounts.len(), 8);
+    assert!(ix.accounts[4].is_writable && ix.accounts[5].is_writable);
+    assert_eq!(ix.accounts[6].pubkey, ADDRESS_LOOKUP_TABLE_PROGRAM_ID);
+}
+
+#[test]
+fn test_set_threshold_policy_data() {
+    // Test: SetThresholdPolicy follows the lookup table variants, entries then the limit
+    let policy = ThresholdPolicy {
+        member_changes: 4,
+        settings_changes: 4,
+        vault_operations: 3,
+        small_transfers: 2,
+        transfers: 3,
+        small_transfer_limit: 5_000_000_000,
+    };
+    let ix = instructions::create_proposal(
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        ProposalType::SetThresholdPolicy { policy },
+    );
+    assert_eq!(ix.data.len(), 9 + 5 + 8);
+    assert_eq!(ix.data[8], 12); // SetThresholdPolicy variant
+    assert_eq!(ix.data[9..14], [4, 4, 3, 2, 3]);
+    assert_eq!(ix.data[14..], 5_000_000_000u64.to_le_bytes());
+}
+
+#[test]
+fn test_create_proposal_rent_payer_meta() {
+    // Test: rent_payer is the trailing account, the program ID standing in for None
+    let (proposer, multisig, proposal) =
+        (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+    let proposal_type = ProposalType::SetVaultPaysRent { enabled: true };
+
+    let ix = instructions::create_proposal(&proposer, &multisig, &proposal, proposal_type);
+    let rent_payer = ix.accounts.last().expect("rent_payer meta");
+    assert_eq!(rent_payer.pubkey, PROGRAM_ID);
+    assert!(!rent_payer.is_writable);
+    assert_eq!(ix.data[8..], [5, 1]); // SetVaultPaysRent { enabled: true }
+
+    let (vault, _) = vault_address(&multisig);
+    let ix = instructions::create_proposal_with_rent_payer(
+        &proposer,
+        &multisig,
+        &proposal,
+        proposal_type,
+        &vault,
+        false,
+    );
+    let rent_payer = ix.accounts.last().expect("rent_payer meta");
+    assert_eq!(rent_payer.pubkey, vault);
+    assert!(rent_payer.is_writable && !rent_payer.is_signer);
+}
+
+#[test]
+fn test_create_transfer_proposal_recipient_kind() {
+    // Test: recipient_kind is a Borsh enum after amount and recipient
+    let keys: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+
+    let ix = instructions::create_transfer_proposal(&keys[0], &keys[1], &keys[2], 500, &keys[3]);
+    assert_eq!(ix.data[..8], instruction_discriminator("create_transfer_proposal"));
+    assert_eq!(ix.data[8..16], 500u64.to_le_bytes());
+    assert_eq!(ix.data[16..48], keys[3].to_bytes());
+    assert_eq!(ix.data[48..], [0]); // Wallet
+
+    let ix = instructions::create_program_vault_transfer_proposal(
+        &keys[0], &keys[1], &keys[2], 500, &keys[3], &keys[4],
+    );
+    assert_eq!(ix.data[48], 1); // ProgramVault
+    assert_eq!(ix.data[49..], keys[4].to_bytes());
+    assert_eq!(ix.accounts.len(), 5);
+}
+
+#[test]
+fn test_execute_transfer_expected_outcome_data() {
+    // Test: expected_outcome is a Borsh Option after the discriminator
+    let keys: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+
+    let ix = instructions::execute_transfer_proposal(
+        &keys[0], &keys[1], &keys[2], &keys[3], &keys[4], &keys[5],
+    );
+    assert_eq!(ix.data[..8], instruction_discriminator("execute_transfer_proposal"));
+    assert_eq!(ix.data[8..], [0]); // None
+
+    let expected = ExpectedOutcome {
+        vault_delta: 1_000,
+        recipient_delta: 999,
+    };
+    let ix = instructions::execute_transfer_proposal_with_expected_outcome(
+        &keys[0], &keys[1], &keys[2], &keys[3], &keys[4], &keys[5], &expected,
+    );
+    assert_eq!(ix.data[8], 1); // Some
+    assert_eq!(ix.data[9..17], 1_000u64.to_le_bytes());
+    assert_eq!(ix.data[17..25], 999u64.to_le_bytes());
+    assert_eq!(ix.data.len(), 25);
+    assert_eq!(ix.accounts.len(), 7);
+}
+
+#[test]
+fn test_validate_proposal() {
+    // Test: no signers, nothing writable, and the Vec<u32> return data decodes
+    let keys: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+
+    let ix = instructions::validate_proposal(&keys[0], &keys[1], &keys[2], &keys[3], &keys[4]);
+    assert_eq!(ix.data, instruction_discriminator("validate_proposal"));
+    assert_eq!(ix.accounts.len(), 5);
+    assert!(ix.accounts.iter().all(|meta| !meta.is_signer && !meta.is_writable));
+
+    let mut return_data = 2u32.to_le_bytes().to_vec();
+    return_data.extend_from_slice(&6006u32.to_le_bytes());
+    return_data.extend_from_slice(&6011u32.to_le_bytes());
+    let validation = ProposalValidation::try_from_bytes(&return_data).unwrap();
+    assert_eq!(validation.errors, vec![6006, 6011]);
+    assert!(!validation.is_executable());
+
+    let validation = ProposalValidation::try_from_bytes(&0u32.to_le_bytes()).unwrap();
+    assert!(validation.is_executable());
+
+    assert_eq!(
+        ProposalValidation::try_from_bytes(&return_data[..6]),
+        Err(DecodeError::UnexpectedEnd)
+    );
+}
+
+#[test]
+fn test_attach_memo_data() {
+    // Test: Option<String> title then String memo, both Borsh-encoded
+    let keys: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+
+    let ix = instructions::attach_memo(&keys[0], &keys[1], &keys[2], Some("Title"), "memo");
+    assert_eq!(ix.data[..8], instruction_discriminator("attach_memo"));
+    assert_eq!(ix.data[8], 1); // Some
+    assert_eq!(ix.data[9..13], 5u32.to_le_bytes());
+    assert_eq!(&ix.data[13..18], b"Title");
+    assert_eq!(ix.data[18..22], 4u32.to_le_bytes());
+    assert_eq!(&ix.data[22..], b"memo");
+    assert!(ix.accounts[0].is_signer && ix.accounts[0].is_writable);
+    assert!(ix.accounts[2].is_writable);
+
+    let ix = instructions::attach_memo(&keys[0], &keys[1], &keys[2], None, "");
+    assert_eq!(ix.data[8..], [0, 0, 0, 0, 0]); // None, empty memo
+}
+
+#[test]
+fn test_multisig_pdas() {
+    // Test: Proposal and transfer proposal PDAs never collide for the same id
+    let (multisig, _) = multisig_address(&Pubkey::new_unique(), 1);
+    assert_ne!(
+        proposal_address(&multisig, 0).0,
+        transfer_proposal_address(&multisig, 0).0
+    );
+    assert_ne!(
+        proposal_address(&multisig, 0).0,
+        proposal_address(&multisig, 1).0
+    );
+}
+
+#[test]
+fn test_decode_multisig() {
+    // Test: Fixed members array decodes and active_members trims the padding
+    let creator = Pubkey::new_unique();
+    let owners = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let multisig = Multisig::try_from_bytes(&multisig_account_data(&creator, &owners, 2))
+        .expect("Multisig should decode");
+
+    assert_eq!(multisig.multisig_id, 7);
+    assert_eq!(multisig.creator, creator);
+    assert_eq!(multisig.threshold, 2);
+    assert_eq!(multisig.proposal_count, 3);
+    assert_eq!(multisig.timelock_seconds, 3600);
+    assert_eq!(multisig.vault_bump, 253);
+    assert!(multisig.vault_pays_rent);
+    assert_eq!(
+        multisig.threshold_policy,
+        ThresholdPolicy {
+            settings_changes: 4,
+            small_transfers: 2,
+            small_transfer_limit: 1_000_000,
+            ..ThresholdPolicy::default()
+        }
+    );
+
+    let active: Vec<Pubkey> = multisig.active_members().iter().map(|m| m.pubkey).collect();
+    assert_eq!(active, owners);
+}
+
+#[test]
+fn test_decode_rejects_wrong_account() {
+    // Test: A Multisig account is not accepted as a Proposal, and truncation is reported
+    let data = multisig_account_data(&Pubkey::new_unique(), &[Pubkey::new_unique()], 1);
+
+    assert_eq!(
+        Proposal::try_from_bytes(&data),
+        Err(DecodeError::DiscriminatorMismatch {
+            expected: "Proposal"
+        })
+    );
+    assert_eq!(
+        Multisig::try_from_bytes(&data[..100]),
+        Err(DecodeError::UnexpectedEnd)
+    );
+}
+
+#[test]
+fn test_proposal_hash() {
+    // Test: Hash covers the domain, variant tag, params, multisig and proposal id
+    let multisig = Pubkey::new_unique();
+    let proposal_type = ProposalType::ChangeThreshold { new_threshold: 2 };
+    let hash = proposal_type.proposal_hash(&multisig, 4);
+
+    let mut preimage = PROPOSAL_HASH_DOMAIN.to_vec();
+    preimage.extend_from_slice(&[2, 2]); // ChangeThreshold tag, new_threshold
+    preimage.extend_from_slice(multisig.as_ref());
+    preimage.extend_from_slice(&4u64.to_le_bytes());
+    assert_eq!(hash[..], sha2::Sha256::digest(&preimage)[..]);
+
+    let other_params = ProposalType::ChangeThreshold { new_threshold: 1 };
+    assert_ne!(other_params.proposal_hash(&multisig, 4), hash);
+    assert_ne!(proposal_type.proposal_hash(&multisig, 5), hash);
+    assert_ne!(proposal_type.proposal_hash(&Pubkey::new_unique(), 4), hash);
+}
+
+#[test]
+fn test_add_large_member_data() {
+    // Test: Co-signers follow the fixed accounts as read-only signers
+    let admin = Pubkey::new_unique();
+    let (multisig_large, _) = multisig_large_address(&admin, 1);
+    let co_signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let new_member = Pubkey::new_unique();
+    let ix = instructions::add_large_member(
+        &admin,
+        &multisig_large,
+        &co_signers,
+        &new_member,
+        MemberRole::Executor,
+        3,
+    );
+
+    assert_eq!(ix.data[..8], instruction_discriminator("add_large_member"));
+    assert_eq!(ix.data[8..40], new_member.to_bytes());
+    assert_eq!(ix.data[40..], [MemberRole::Executor as u8, 3]);
+    assert_eq!(ix.accounts.len(), 4);
+    assert!(ix.accounts[2..].iter().all(|m| m.is_signer && !m.is_writable));
+    assert_ne!(multisig_large, multisig_address(&admin, 1).0);
+}
+
+#[test]
+fn test_decode_multisig_large() {
+    // Test: Zero-copy header decodes and only member_count slots are read
+    let creator = Pubkey::new_unique();
+    let second = Pubkey::new_unique();
+    let mut data = account_discriminator("MultisigLarge").to_vec();
+    data.extend_from_slice(creator.as_ref());
+    data.extend_from_slice(&9u64.to_le_bytes()); // multisig_id
+    data.extend_from_slice(&60u64.to_le_bytes()); // timelock_seconds
+    data.extend_from_slice(&0u64.to_le_bytes()); // proposal_count
+    data.extend_from_slice(&2u16.to_le_bytes()); // member_count
+    data.extend_from_slice(&16u16.to_le_bytes()); // member_capacity
+    data.extend_from_slice(&[2, 1, 255, 0]); // threshold, paused, bump, _padding
+    assert_eq!(data.len(), MultisigLarge::HEADER_SPACE);
+    for (pubkey, role) in [(creator, MemberRole::Admin), (second, MemberRole::Proposer)] {
+        data.extend_from_slice(pubkey.as_ref());
+        data.push(role as u8);
+    }
+    data.resize(MultisigLarge::HEADER_SPACE + 16 * 33, 0);
+
+    let multisig = MultisigLarge::try_from_bytes(&data).expect("MultisigLarge should decode");
+    assert_eq!(multisig.creator, creator);
+    assert_eq!(multisig.multisig_id, 9);
+    assert_eq!(multisig.member_capacity, 16);
+    assert_eq!(multisig.threshold, 2);
+    assert!(multisig.paused);
+    assert_eq!(multisig.members.len(), 2);
+    assert_eq!(multisig.members[1].pubkey, second);
+    assert_eq!(multisig.members[1].role, MemberRole::Proposer);
+}
@@ -0,0 +1,260 @@
+// Unit tests for the NFT staking client
+
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
+use soteria_client::nft_staking::{instructions, *};
+use soteria_client::token::{associated_token_address, TOKEN_PROGRAM_ID};
+use soteria_client::{account_discriminator, instruction_discriminator};
+
+#[test]
+fn test_set_rarity_config_data() {
+    // Test: Tiers serialize as a Borsh Vec of (String, u16)
+    let collection = Pubkey::new_unique();
+    let ix = instructions::set_rarity_config(
+        &Pubkey::new_unique(),
+        &collection,
+        &[("rare", 15_000), ("legendary", 30_000)],
+    );
+
+    let mut expected = instruction_discriminator("set_rarity_config").to_vec();
+    expected.extend_from_slice(&2u32.to_le_bytes());
+    expected.extend_from_slice(&4u32.to_le_bytes());
+    expected.extend_from_slice(b"rare");
+    expected.extend_from_slice(&15_000u16.to_le_bytes());
+    expected.extend_from_slice(&9u32.to_le_bytes());
+    expected.extend_from_slice(b"legendary");
+    expected.extend_from_slice(&30_000u16.to_le_bytes());
+
+    assert_eq!(ix.data, expected);
+    assert_eq!(ix.accounts[3].pubkey, rarity_config_address(&collection).0);
+}
+
+#[test]
+fn test_claim_rewards_accounts() {
+    // Test: Rewards are paid from the reward config's vault to the owner's ATA
+    let owner = Pubkey::new_unique();
+    let asset = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let (reward_config, _) = reward_config_address(&collection);
+
+    let ix = instructions::claim_rewards(&owner, &asset, &collection, &reward_mint, None);
+    assert_eq!(ix.accounts[3].pubkey, stake_record_address(&asset).0);
+    assert_eq!(ix.accounts[6].pubkey, reward_mint);
+    assert!(!ix.accounts[6].is_writable, "Nothing is minted");
+    assert_eq!(
+        ix.accounts[7].pubkey,
+        associated_token_address(&reward_config, &reward_mint, &TOKEN_PROGRAM_ID)
+    );
+    assert_eq!(
+        ix.accounts[8].pubkey,
+        associated_token_address(&owner, &reward_mint, &TOKEN_PROGRAM_ID)
+    );
+    assert_eq!(ix.accounts[12].pubkey, PROGRAM_ID, "No voter profile");
+
+    // Test: The voter profile is passed through as the trailing account
+    let (profile, _) = soteria_client::governance::user_profile_address(&owner);
+    let ix =
+        instructions::claim_rewards(&owner, &asset, &collection, &reward_mint, Some(&profile));
+    assert_eq!(ix.accounts.len(), 13);
+    assert_eq!(ix.accounts[12].pubkey, profile);
+    assert!(!ix.accounts[12].is_writable);
+}
+
+#[test]
+fn test_fund_rewards_accounts() {
+    // Test: Deposit moves from the authority's ATA into the collection's reward vault
+    let authority = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+
+    let ix = instructions::fund_rewards(&authority, &collection, &reward_mint, 5_000);
+    assert!(ix.accounts[0].is_signer);
+    assert_eq!(ix.accounts[3].pubkey, reward_config_address(&collection).0);
+    assert_eq!(
+        ix.accounts[5].pubkey,
+        associated_token_address(&authority, &reward_mint, &TOKEN_PROGRAM_ID)
+    );
+    assert_eq!(ix.accounts[6].pubkey, reward_vault_address(&collection, &reward_mint));
+
+    let mut expected = instruction_discriminator("fund_rewards").to_vec();
+    expected.extend_from_slice(&5_000u64.to_le_bytes());
+    assert_eq!(ix.data, expected);
+
+    // Test: Each collection gets its own vault for the same mint
+    assert_ne!(
+        reward_vault_address(&collection, &reward_mint),
+        reward_vault_address(&Pubkey::new_unique(), &reward_mint)
+    );
+}
+
+#[test]
+fn test_mint_milestone_badge_accounts() {
+    // Test: Badge record is keyed by asset and milestone; milestone leads the args
+    let owner = Pubkey::new_unique();
+    let badge = Pubkey::new_unique();
+    let asset = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+
+    let ix = instructions::mint_milestone_badge(&owner, &badge, &asset, &collection, 2, "B", "u");
+    assert!(ix.accounts[1].is_signer);
+    assert_eq!(ix.accounts[5].pubkey, stake_record_address(&asset).0);
+    assert_eq!(ix.accounts[6].pubkey, badge_record_address(&asset, 2).0);
+    assert_ne!(badge_record_address(&asset, 2).0, badge_record_address(&asset, 1).0);
+
+    let mut expected = instruction_discriminator("mint_milestone_badge").to_vec();
+    expected.push(2);
+    expected.extend_from_slice(&1u32.to_le_bytes());
+    expected.extend_from_slice(b"B");
+    expected.extend_from_slice(&1u32.to_le_bytes());
+    expected.extend_from_slice(b"u");
+    assert_eq!(ix.data, expected);
+}
+
+#[test]
+fn test_migrate_collection_state_accounts() {
+    // Test: Both authorities sign and each collection maps to its own state PDA
+    let old_authority = Pubkey::new_unique();
+    let new_authority = Pubkey::new_unique();
+    let old_collection = Pubkey::new_unique();
+    let new_collection = Pubkey::new_unique();
+
+    let ix = instructions::migrate_collection_state(
+        &old_authority,
+        &new_authority,
+        &old_collection,
+        &new_collection,
+    );
+    assert!(ix.accounts[0].is_signer && ix.accounts[1].is_signer);
+    assert_eq!(ix.accounts[4].pubkey, collection_state_address(&old_collection).0);
+    assert_eq!(ix.accounts[5].pubkey, collection_state_address(&new_collection).0);
+    assert!(ix.accounts[4].is_writable && ix.accounts[5].is_writable);
+    assert_eq!(ix.data, instruction_discriminator("migrate_collection_state"));
+}
+
+#[test]
+fn test_unstake_steps_accounts() {
+    // Test: request_unstake is owner-only; finalize_unstake needs the update authority
+    let owner = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let asset = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+
+    let ix = instructions::request_unstake(&owner, &asset, &collection);
+    assert_eq!(ix.accounts.len(), 4);
+    assert!(ix.accounts[0].is_signer);
+    assert_eq!(ix.accounts[3].pubkey, stake_record_address(&asset).0);
+    assert!(ix.accounts[3].is_writable);
+    assert_eq!(ix.data, instruction_discriminator("request_unstake"));
+
+    let ix = instructions::finalize_unstake(&owner, &authority, &owner, &asset, &collection);
+    assert!(ix.accounts[1].is_signer);
+    assert_eq!(ix.accounts[6].pubkey, stake_record_address(&asset).0);
+    assert_eq!(ix.data, instruction_discriminator("finalize_unstake"));
+}
+
+#[test]
+fn test_owner_handoff_accounts() {
+    // Test: Seller and buyer both sign; transfer accounts are appended last
+    let owner = Pubkey::new_unique();
+    let buyer = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let asset = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+    let extra = AccountMeta::new_readonly(Pubkey::new_unique(), false);
+
+    let ix = instructions::owner_handoff(
+        &owner,
+        &buyer,
+        &authority,
+        &buyer,
+        &asset,
+        &collection,
+        &[extra.clone()],
+    );
+    assert_eq!(ix.accounts.len(), 11);
+    assert!(ix.accounts[..4].iter().all(|account| account.is_signer));
+    assert!(!ix.accounts[6].is_writable); // collection_state
+    assert_eq!(ix.accounts[7].pubkey, stake_record_address(&asset).0);
+    assert_eq!(ix.accounts[10], extra);
+    assert_eq!(ix.data, instruction_discriminator("owner_handoff"));
+}
+
+#[test]
+fn test_decode_stake_record_cooldown() {
+    // Test: Cooldown timestamps follow last_claim
+    let asset = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    let mut data = account_discriminator("StakeRecord").to_vec();
+    data.extend_from_slice(asset.as_ref());
+    data.extend_from_slice(owner.as_ref());
+    data.extend_from_slice(&1_000i64.to_le_bytes()); // staked_at
+    data.extend_from_slice(&2_000i64.to_le_bytes()); // last_claim
+    data.extend_from_slice(&3_000i64.to_le_bytes()); // unstake_requested_at
+    data.extend_from_slice(&(3_000 + UNSTAKE_COOLDOWN).to_le_bytes()); // cooldown_ends_at
+    data.push(253); // bump
+
+    let record = StakeRecord::try_from_bytes(&data).expect("StakeRecord should decode");
+    assert_eq!(record.asset, asset);
+    assert_eq!(record.last_claim, 2_000);
+    assert_eq!(record.unstake_requested_at, 3_000);
+    assert_eq!(record.cooldown_ends_at, 3_000 + UNSTAKE_COOLDOWN);
+    assert_eq!(record.bump, 253);
+}
+
+#[test]
+fn test_decode_collection_state() {
+    // Test: PenaltyCurve decodes and the stake counters are read in order
+    let authority = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+
+    let mut data = account_discriminator("CollectionState").to_vec();
+    data.extend_from_slice(authority.as_ref());
+    data.extend_from_slice(collection.as_ref());
+    data.extend_from_slice(&12u64.to_le_bytes()); // total_minted
+    data.extend_from_slice(&4u64.to_le_bytes()); // total_staked
+    data.extend_from_slice(&100u64.to_le_bytes()); // max_staked
+    data.push(0); // paused
+    data.extend_from_slice(&1_000u64.to_le_bytes()); // early_unstake_penalty
+    data.push(PenaltyCurve::Quadratic as u8);
+    for days in [30u16, 90, 365] {
+        data.extend_from_slice(&days.to_le_bytes()); // badge_milestone_days
+    }
+    data.push(1); // handoff_keeps_stake_time
+    data.push(254); // bump
+
+    let state = CollectionState::try_from_bytes(&data).expect("CollectionState should decode");
+    assert_eq!(state.authority, authority);
+    assert_eq!(state.collection, collection);
+    assert_eq!(state.total_staked, 4);
+    assert_eq!(state.max_staked, 100);
+    assert!(!state.paused);
+    assert_eq!(state.penalty_curve, PenaltyCurve::Quadratic);
+    assert_eq!(state.badge_milestone_days, [30, 90, 365]);
+    assert!(state.handoff_keeps_stake_time);
+    assert_eq!(state.bump, 254);
+}
+
+#[test]
+fn test_decode_reward_config() {
+    // Test: Funding totals and the emission end follow the paid counter
+    let collection = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+
+    let mut data = account_discriminator("RewardConfig").to_vec();
+    data.extend_from_slice(collection.as_ref());
+    data.extend_from_slice(reward_mint.as_ref());
+    data.extend_from_slice(&10u64.to_le_bytes()); // reward_rate_per_sec
+    data.extend_from_slice(&400u64.to_le_bytes()); // total_rewards_paid
+    data.extend_from_slice(&1_000u64.to_le_bytes()); // total_funded
+    data.extend_from_slice(&1_100i64.to_le_bytes()); // emission_end_ts
+    data.push(252); // bump
+
+    let config = RewardConfig::try_from_bytes(&data).expect("RewardConfig should decode");
+    assert_eq!(config.reward_mint, reward_mint);
+    assert_eq!(config.reward_rate_per_sec, 10);
+    assert_eq!(config.total_rewards_paid, 400);
+    assert_eq!(config.total_funded, 1_000);
+    assert_eq!(config.emission_end_ts, 1_100);
+    assert_eq!(config.bump, 252);
+}
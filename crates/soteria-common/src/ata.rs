@@ -0,0 +1,35 @@
+// Associated Token Account Helpers
+//
+// An ATA's address is fully determined by (wallet, mint, token program), so a
+// token account passed in without Anchor's associated_token constraints can
+// be checked by re-deriving the address.
+//
+// Why the token program matters:
+// SPL Token and Token-2022 derive different ATAs for the same wallet and mint.
+// Deriving with the wrong program rejects valid accounts or accepts the wrong one.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::pubkey;
+
+// Associated Token Account program
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+// Derive the ATA for a wallet and mint under the given token program
+// Seeds: [wallet, token_program, mint] under the ATA program
+pub fn associated_token_address(wallet: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+// True when `address` is the ATA of `wallet` for `mint`
+pub fn is_associated_token_address(
+    address: &Pubkey,
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> bool {
+    *address == associated_token_address(wallet, mint, token_program)
+}
@@ -0,0 +1,18 @@
+// Soteria Common
+//
+// Validation helpers shared by the secure Anchor programs.
+// Each helper captures a check that several programs used to write by hand:
+// 1. ata       - Associated token account derivation
+// 2. ownership - System-program ownership of wallet accounts
+// 3. math      - u128-intermediate multiply/divide and basis points
+// 4. time      - Expiration and deadline guards
+//
+// DESIGN:
+// Helpers return bool or Option rather than an error type. Each program keeps
+// its own error enum, so callers wrap the result in require! or ok_or and the
+// error a user sees still names the program-specific failure.
+
+pub mod ata;
+pub mod math;
+pub mod ownership;
+pub mod time;
@@ -0,0 +1,44 @@
+// Checked Math Helpers
+//
+// Token amounts are u64, but amount * rate overflows u64 long before the
+// result does. These helpers multiply in u128 and only narrow the final value.
+//
+// All helpers return None on overflow, on a zero denominator, or when the
+// result does not fit in u64. Callers map None to their own error.
+
+// Denominator for basis-point values (10000 bps = 100%)
+pub const BASIS_POINTS_DIVISOR: u64 = 10_000;
+
+// a * b / denominator, rounded down
+pub fn mul_div_floor(a: u64, b: u64, denominator: u64) -> Option<u64> {
+    let result = (a as u128)
+        .checked_mul(b as u128)?
+        .checked_div(denominator as u128)?;
+
+    u64::try_from(result).ok()
+}
+
+// a * b / denominator, rounded up
+//
+// Use when rounding down would favour the caller, e.g. fees or amounts owed
+pub fn mul_div_ceil(a: u64, b: u64, denominator: u64) -> Option<u64> {
+    if denominator == 0 {
+        return None;
+    }
+
+    let result = (a as u128)
+        .checked_mul(b as u128)?
+        .div_ceil(denominator as u128);
+
+    u64::try_from(result).ok()
+}
+
+// amount * bps / 10000, rounded down
+pub fn apply_bps_floor(amount: u64, bps: u64) -> Option<u64> {
+    mul_div_floor(amount, bps, BASIS_POINTS_DIVISOR)
+}
+
+// amount * bps / 10000, rounded up
+pub fn apply_bps_ceil(amount: u64, bps: u64) -> Option<u64> {
+    mul_div_ceil(amount, bps, BASIS_POINTS_DIVISOR)
+}
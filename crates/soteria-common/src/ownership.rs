@@ -0,0 +1,18 @@
+// Account Ownership Helpers
+//
+// Wallets are owned by the System Program. Checking this before sending
+// lamports to an UncheckedAccount keeps funds from landing in a program-owned
+// account (a PDA or another program's state) that no one can withdraw from.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+// True when the account is owned by the System Program
+pub fn is_system_owned(account: &AccountInfo) -> bool {
+    *account.owner == system_program::ID
+}
+
+// True when the account is owned by `program_id`
+pub fn is_owned_by(account: &AccountInfo, program_id: &Pubkey) -> bool {
+    account.owner == program_id
+}
@@ -0,0 +1,31 @@
+// Time Helpers
+//
+// Expiration and deadline guards over unix timestamps.
+// Helpers take `now` as an argument instead of reading the Clock sysvar, so
+// the same timestamp is used for every check in an instruction.
+
+// True once `now` is past `expires_at`
+// The final second (now == expires_at) is still valid
+pub fn is_expired(now: i64, expires_at: i64) -> bool {
+    now > expires_at
+}
+
+// Timestamp `duration` seconds after `now`, or None on overflow
+pub fn expires_after(now: i64, duration: i64) -> Option<i64> {
+    now.checked_add(duration)
+}
+
+// Seconds left before `deadline`, or None if the deadline has been reached
+// (or the difference does not fit in i64)
+//
+// Lets a caller reject an expired deadline and a deadline too far in the
+// future with two distinct errors:
+//   let remaining = seconds_until(now, deadline).ok_or(Error::Expired)?;
+//   require!(remaining <= MAX, Error::TooFar);
+pub fn seconds_until(now: i64, deadline: i64) -> Option<i64> {
+    if deadline > now {
+        deadline.checked_sub(now)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,41 @@
+// Unit tests for associated token account helpers
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::{self, get_associated_token_address_with_program_id};
+use anchor_spl::{token, token_2022};
+use soteria_common::ata::*;
+
+#[test]
+fn test_associated_token_address_matches_spl() {
+    // Test: The helper agrees with the SPL associated-token library
+    assert_eq!(ASSOCIATED_TOKEN_PROGRAM_ID, associated_token::ID);
+
+    let wallet = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    for token_program in [token::ID, token_2022::ID] {
+        assert_eq!(
+            associated_token_address(&wallet, &mint, &token_program),
+            get_associated_token_address_with_program_id(&wallet, &mint, &token_program)
+        );
+    }
+}
+
+#[test]
+fn test_is_associated_token_address() {
+    // Test: Only the exact (wallet, mint, token program) combination matches
+    let wallet = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let ata = associated_token_address(&wallet, &mint, &token::ID);
+
+    assert!(is_associated_token_address(&ata, &wallet, &mint, &token::ID));
+
+    // Different wallet, different mint, swapped arguments
+    assert!(!is_associated_token_address(&ata, &Pubkey::new_unique(), &mint, &token::ID));
+    assert!(!is_associated_token_address(&ata, &wallet, &Pubkey::new_unique(), &token::ID));
+    assert!(!is_associated_token_address(&ata, &mint, &wallet, &token::ID));
+
+    // Same wallet and mint under Token-2022 derive a different account
+    assert!(!is_associated_token_address(&ata, &wallet, &mint, &token_2022::ID));
+    assert_ne!(ata, associated_token_address(&wallet, &mint, &token_2022::ID));
+}
@@ -0,0 +1,46 @@
+// Unit tests for checked math helpers
+
+use soteria_common::math::*;
+
+#[test]
+fn test_mul_div_floor() {
+    // Test: Rounds down and survives intermediate products above u64::MAX
+    assert_eq!(mul_div_floor(10, 3, 4), Some(7));
+    assert_eq!(mul_div_floor(0, 3, 4), Some(0));
+    assert_eq!(mul_div_floor(u64::MAX, u64::MAX, u64::MAX), Some(u64::MAX));
+
+    // Zero denominator and results above u64::MAX
+    assert_eq!(mul_div_floor(10, 3, 0), None);
+    assert_eq!(mul_div_floor(u64::MAX, 2, 1), None);
+}
+
+#[test]
+fn test_mul_div_ceil() {
+    // Test: Rounds up only when there is a remainder
+    assert_eq!(mul_div_ceil(10, 3, 4), Some(8));
+    assert_eq!(mul_div_ceil(10, 4, 4), Some(10));
+    assert_eq!(mul_div_ceil(1, 1, 10_000), Some(1));
+    assert_eq!(mul_div_ceil(0, 3, 4), Some(0));
+    assert_eq!(mul_div_ceil(u64::MAX, u64::MAX, u64::MAX), Some(u64::MAX));
+
+    assert_eq!(mul_div_ceil(10, 3, 0), None);
+    assert_eq!(mul_div_ceil(u64::MAX, 2, 1), None);
+}
+
+#[test]
+fn test_apply_bps() {
+    // Test: Basis points of an amount in both rounding directions
+    assert_eq!(apply_bps_floor(1_000, 30), Some(3));
+    assert_eq!(apply_bps_ceil(1_000, 30), Some(3));
+
+    // 0.3% of 1001 is 3.003
+    assert_eq!(apply_bps_floor(1_001, 30), Some(3));
+    assert_eq!(apply_bps_ceil(1_001, 30), Some(4));
+
+    // 100% returns the amount unchanged, even at u64::MAX
+    assert_eq!(apply_bps_floor(u64::MAX, BASIS_POINTS_DIVISOR), Some(u64::MAX));
+    assert_eq!(apply_bps_ceil(u64::MAX, BASIS_POINTS_DIVISOR), Some(u64::MAX));
+
+    // Above 100% can overflow
+    assert_eq!(apply_bps_floor(u64::MAX, BASIS_POINTS_DIVISOR + 1), None);
+}
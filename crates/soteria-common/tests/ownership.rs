@@ -0,0 +1,36 @@
+// Unit tests for account ownership helpers
+
+use anchor_lang::prelude::{AccountInfo, Pubkey};
+use anchor_lang::system_program;
+use soteria_common::ownership::*;
+
+#[test]
+fn test_is_system_owned() {
+    // Test: Wallets pass, program-owned accounts do not
+    let key = Pubkey::new_unique();
+    let mut lamports = 1_000_000;
+    let mut data = [];
+
+    let wallet = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &system_program::ID, false, 0);
+    assert!(is_system_owned(&wallet));
+
+    let program = Pubkey::new_unique();
+    let mut lamports = 1_000_000;
+    let mut data = [0u8; 8];
+    let pda = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &program, false, 0);
+    assert!(!is_system_owned(&pda));
+}
+
+#[test]
+fn test_is_owned_by() {
+    // Test: Ownership compares against the given program only
+    let key = Pubkey::new_unique();
+    let program = Pubkey::new_unique();
+    let mut lamports = 1_000_000;
+    let mut data = [0u8; 8];
+    let account = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &program, false, 0);
+
+    assert!(is_owned_by(&account, &program));
+    assert!(!is_owned_by(&account, &Pubkey::new_unique()));
+    assert!(!is_owned_by(&account, &system_program::ID));
+}
@@ -0,0 +1,34 @@
+// Unit tests for time helpers
+
+use soteria_common::time::*;
+
+const NOW: i64 = 1_700_000_000;
+
+#[test]
+fn test_is_expired() {
+    // Test: Valid through the expiry second, expired after it
+    assert!(!is_expired(NOW - 1, NOW));
+    assert!(!is_expired(NOW, NOW));
+    assert!(is_expired(NOW + 1, NOW));
+}
+
+#[test]
+fn test_expires_after() {
+    // Test: Adds the duration with overflow protection
+    assert_eq!(expires_after(NOW, 3_600), Some(NOW + 3_600));
+    assert_eq!(expires_after(NOW, 0), Some(NOW));
+    assert_eq!(expires_after(i64::MAX, 1), None);
+}
+
+#[test]
+fn test_seconds_until() {
+    // Test: Only deadlines strictly in the future have time remaining
+    assert_eq!(seconds_until(NOW, NOW + 60), Some(60));
+    assert_eq!(seconds_until(NOW, NOW + 1), Some(1));
+    assert_eq!(seconds_until(NOW, NOW), None);
+    assert_eq!(seconds_until(NOW, NOW - 60), None);
+
+    // Extreme values do not overflow
+    assert_eq!(seconds_until(i64::MIN, i64::MAX), None);
+    assert_eq!(seconds_until(0, i64::MAX), Some(i64::MAX));
+}
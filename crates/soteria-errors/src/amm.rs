@@ -0,0 +1,211 @@
+// AMM Error Codes
+//
+// amm-cl-secure:       AmmClError (#[error_code], 6000 + index)
+// amm-cl-vulnerable:   AmmClError (#[error_code], 6000 + index)
+// amm-secure:          AmmError (#[error_code], 6000 + index)
+// amm-vulnerable:      AmmError (#[error_code], 6000 + index)
+// gauge-secure:        GaugeError (#[error_code], 6000 + index)
+// gauge-vulnerable:    GaugeError (#[error_code], 6000 + index)
+
+pub mod amm_cl_secure {
+    crate::error_codes! {
+        pub enum AmmClError {
+            ZeroAmount = 6000,
+            ZeroLiquidity = 6001,
+            IdenticalMints = 6002,
+            InvalidTickSpacing = 6003,
+            TickOutOfBounds = 6004,
+            TickNotAligned = 6005,
+            InvalidTickRange = 6006,
+            TooManyTicks = 6007,
+            MissingTickAccount = 6008,
+            InvalidTickAccount = 6009,
+            InsufficientLiquidity = 6010,
+            SlippageExceeded = 6011,
+            Unauthorized = 6012,
+            Overflow = 6013,
+            Underflow = 6014,
+        }
+    }
+}
+
+pub mod amm_cl_vulnerable {
+    crate::error_codes! {
+        pub enum AmmClError {
+            ZeroAmount = 6000,
+            ZeroLiquidity = 6001,
+            IdenticalMints = 6002,
+            InvalidTickSpacing = 6003,
+            TickOutOfBounds = 6004,
+            TickNotAligned = 6005,
+            InvalidTickRange = 6006,
+            TooManyTicks = 6007,
+            InvalidTickAccount = 6008,
+            InsufficientLiquidity = 6009,
+            SlippageExceeded = 6010,
+            Overflow = 6011,
+            Underflow = 6012,
+        }
+    }
+}
+
+pub mod amm_secure {
+    crate::error_codes! {
+        pub enum AmmError {
+            FeeTooHigh = 6000,
+            IdenticalTokenMints = 6001,
+            InvalidMint = 6002,
+            Unauthorized = 6003,
+            InsufficientBalance = 6004,
+            ZeroDepositAmount = 6005,
+            ZeroWithdrawAmount = 6006,
+            InsufficientLiquidity = 6007,
+            ExcessiveDepositAmount = 6008,
+            InsufficientWithdrawAmount = 6009,
+            ZeroSwapAmount = 6010,
+            SlippageExceeded = 6011,
+            InsufficientPoolLiquidity = 6012,
+            Overflow = 6013,
+            Underflow = 6014,
+            DivisionByZero = 6015,
+            PoolLocked = 6016,
+            PoolAlreadyLocked = 6017,
+            PoolAlreadyUnlocked = 6018,
+            UnauthorizedAccess = 6019,
+            TransactionExpired = 6020,
+            ExpirationTooFar = 6021,
+            ExpirationInPast = 6022,
+            CurveCalculationFailed = 6023,
+            InvalidCurveParams = 6024,
+            ZeroFlashLoanAmount = 6025,
+            FlashLoanAlreadyActive = 6026,
+            NoActiveFlashLoan = 6027,
+            FlashLoanInProgress = 6028,
+            MissingFlashLoanEnd = 6029,
+            FlashLoanCpiNotAllowed = 6030,
+            FlashLoanNotRepaid = 6031,
+            UnauthorizedPoolCreator = 6032,
+            FeeTierNotWhitelisted = 6033,
+            InvalidFeeTierCount = 6034,
+            DuplicateFeeTier = 6035,
+            NoPendingFeeChange = 6036,
+            FeeChangeTimelockActive = 6037,
+            PoolNotLocked = 6038,
+            EmergencyWithdrawDisabled = 6039,
+            ReferralFeeTooHigh = 6040,
+            InvalidReferrer = 6041,
+            InvalidOracleConfig = 6042,
+            PriceFeedRequired = 6043,
+            InvalidPriceFeed = 6044,
+            OraclePriceUnavailable = 6045,
+            StaleOraclePrice = 6046,
+            OracleConfidenceTooWide = 6047,
+            OraclePriceDeviation = 6048,
+            PositionUriTooLong = 6049,
+            InvalidMplCoreProgram = 6050,
+            PositionOwnerMismatch = 6051,
+            PositionPoolMismatch = 6052,
+            InvalidCircuitBreakerConfig = 6053,
+            PriceImpactTooHigh = 6054,
+            NotNativeSolPool = 6055,
+            WrappedSolAccountNotEmpty = 6056,
+            RentRefundMismatch = 6057,
+            LimitOrderExpired = 6058,
+            LimitPriceNotReached = 6059,
+            NoFeesToClaim = 6060,
+            InvalidMigrationPool = 6061,
+            InvalidSwapLimitConfig = 6062,
+            SlotSwapLimitExceeded = 6063,
+            InvariantViolated = 6064,
+            InvalidProgramData = 6065,
+            UnauthorizedConfigAdmin = 6066,
+        }
+    }
+}
+
+pub mod amm_vulnerable {
+    crate::error_codes! {
+        pub enum AmmError {
+            FeeTooHigh = 6000,
+            IdenticalTokenMints = 6001,
+            InvalidMint = 6002,
+            Unauthorized = 6003,
+            InsufficientBalance = 6004,
+            ZeroDepositAmount = 6005,
+            ZeroWithdrawAmount = 6006,
+            InsufficientLiquidity = 6007,
+            ExcessiveDepositAmount = 6008,
+            InsufficientWithdrawAmount = 6009,
+            ZeroSwapAmount = 6010,
+            SlippageExceeded = 6011,
+            InsufficientPoolLiquidity = 6012,
+            Overflow = 6013,
+            Underflow = 6014,
+            DivisionByZero = 6015,
+            PoolLocked = 6016,
+            PoolAlreadyLocked = 6017,
+            PoolAlreadyUnlocked = 6018,
+            UnauthorizedAccess = 6019,
+            TransactionExpired = 6020,
+            ExpirationTooFar = 6021,
+            ExpirationInPast = 6022,
+            CurveCalculationFailed = 6023,
+            InvalidCurveParams = 6024,
+            FlashLoanAlreadyActive = 6025,
+            NoActiveFlashLoan = 6026,
+            MissingFlashLoanEnd = 6027,
+            FlashLoanCpiNotAllowed = 6028,
+            UnauthorizedPoolCreator = 6029,
+            FeeTierNotWhitelisted = 6030,
+            InvalidFeeTierCount = 6031,
+            NoPendingFeeChange = 6032,
+            PoolNotLocked = 6033,
+            ReferralFeeTooHigh = 6034,
+            InvalidReferrer = 6035,
+            InvalidOracleConfig = 6036,
+            PriceFeedRequired = 6037,
+            InvalidPriceFeed = 6038,
+            OraclePriceUnavailable = 6039,
+            OracleConfidenceTooWide = 6040,
+            OraclePriceDeviation = 6041,
+            PositionUriTooLong = 6042,
+            InvalidMplCoreProgram = 6043,
+            PositionOwnerMismatch = 6044,
+            InvalidCircuitBreakerConfig = 6045,
+            NotNativeSolPool = 6046,
+            LimitOrderExpired = 6047,
+            InvalidMigrationPool = 6048,
+            InvalidSwapLimitConfig = 6049,
+        }
+    }
+}
+
+pub mod gauge_secure {
+    crate::error_codes! {
+        pub enum GaugeError {
+            ZeroAmount = 6000,
+            ZeroEmissionRate = 6001,
+            InsufficientRewards = 6002,
+            IdenticalMints = 6003,
+            InsufficientStake = 6004,
+            Unauthorized = 6005,
+            Overflow = 6006,
+            Underflow = 6007,
+        }
+    }
+}
+
+pub mod gauge_vulnerable {
+    crate::error_codes! {
+        pub enum GaugeError {
+            ZeroAmount = 6000,
+            ZeroEmissionRate = 6001,
+            InsufficientRewards = 6002,
+            IdenticalMints = 6003,
+            InsufficientStake = 6004,
+            Unauthorized = 6005,
+            Overflow = 6006,
+            Underflow = 6007,
+        }
+    }
+}
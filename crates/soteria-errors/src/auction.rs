@@ -0,0 +1,44 @@
+// Auction Error Codes
+//
+// auction-secure:      AuctionError (#[error_code], 6000 + index)
+// auction-vulnerable:  AuctionError (#[error_code], 6000 + index)
+
+pub mod auction_secure {
+    crate::error_codes! {
+        pub enum AuctionError {
+            ZeroAmount = 6000,
+            InvalidDuration = 6001,
+            IdenticalMints = 6002,
+            AuctionClosed = 6003,
+            AuctionEnded = 6004,
+            AuctionNotEnded = 6005,
+            BidTooLow = 6006,
+            SellerCannotBid = 6007,
+            MissingPreviousBidderAccount = 6008,
+            InvalidPreviousBidderAccount = 6009,
+            AuctionHasBids = 6010,
+            NoBids = 6011,
+            Unauthorized = 6012,
+            Overflow = 6013,
+        }
+    }
+}
+
+pub mod auction_vulnerable {
+    crate::error_codes! {
+        pub enum AuctionError {
+            ZeroAmount = 6000,
+            InvalidDuration = 6001,
+            IdenticalMints = 6002,
+            AuctionClosed = 6003,
+            AuctionEnded = 6004,
+            AuctionNotEnded = 6005,
+            BidTooLow = 6006,
+            SellerCannotBid = 6007,
+            AuctionHasBids = 6008,
+            NoBids = 6009,
+            Unauthorized = 6010,
+            Overflow = 6011,
+        }
+    }
+}
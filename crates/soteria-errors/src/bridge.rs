@@ -0,0 +1,36 @@
+// Bridge Error Codes
+//
+// bridge-secure:       BridgeError (#[error_code], 6000 + index)
+// bridge-vulnerable:   BridgeError (#[error_code], 6000 + index)
+
+pub mod bridge_secure {
+    crate::error_codes! {
+        pub enum BridgeError {
+            ZeroAmount = 6000,
+            InvalidGuardianCount = 6001,
+            InvalidGuardian = 6002,
+            InvalidThreshold = 6003,
+            NotGuardian = 6004,
+            DuplicateAttestation = 6005,
+            InsufficientAttestations = 6006,
+            AlreadyExecuted = 6007,
+            InsufficientLiquidity = 6008,
+            ThresholdReached = 6009,
+        }
+    }
+}
+
+pub mod bridge_vulnerable {
+    crate::error_codes! {
+        pub enum BridgeError {
+            ZeroAmount = 6000,
+            InvalidGuardianCount = 6001,
+            InvalidGuardian = 6002,
+            InvalidThreshold = 6003,
+            NotGuardian = 6004,
+            InsufficientAttestations = 6005,
+            AlreadyExecuted = 6006,
+            InsufficientLiquidity = 6007,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+// CLOB Error Codes
+//
+// clob-secure:         ClobError (#[error_code], 6000 + index)
+// clob-vulnerable:     ClobError (#[error_code], 6000 + index)
+
+pub mod clob_secure {
+    crate::error_codes! {
+        pub enum ClobError {
+            ZeroAmount = 6000,
+            ZeroPrice = 6001,
+            IdenticalMints = 6002,
+            OrderBookFull = 6003,
+            OrderNotFound = 6004,
+            InvalidSide = 6005,
+            PricesDoNotCross = 6006,
+            SelfTrade = 6007,
+            InvalidOwnerAccount = 6008,
+            Unauthorized = 6009,
+            Overflow = 6010,
+            Underflow = 6011,
+        }
+    }
+}
+
+pub mod clob_vulnerable {
+    crate::error_codes! {
+        pub enum ClobError {
+            ZeroAmount = 6000,
+            ZeroPrice = 6001,
+            IdenticalMints = 6002,
+            OrderBookFull = 6003,
+            OrderNotFound = 6004,
+            InvalidSide = 6005,
+            PricesDoNotCross = 6006,
+            InvalidOwnerAccount = 6007,
+            Unauthorized = 6008,
+            Overflow = 6009,
+            Underflow = 6010,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+// Drop Error Codes
+//
+// drop-secure:         DropError (#[error_code], 6000 + index)
+// drop-vulnerable:     DropError (#[error_code], 6000 + index)
+
+pub mod drop_secure {
+    crate::error_codes! {
+        pub enum DropError {
+            ZeroAmount = 6000,
+            InvalidNodeCount = 6001,
+            InvalidMerkleRoot = 6002,
+            InvalidClaimWindow = 6003,
+            ClaimWindowClosed = 6004,
+            ClaimWindowOpen = 6005,
+            InvalidIndex = 6006,
+            AlreadyClaimed = 6007,
+            ProofTooLong = 6008,
+            InvalidProof = 6009,
+            ExceedsMaxClaim = 6010,
+            Unauthorized = 6011,
+            Overflow = 6012,
+        }
+    }
+}
+
+pub mod drop_vulnerable {
+    crate::error_codes! {
+        pub enum DropError {
+            ZeroAmount = 6000,
+            InvalidNodeCount = 6001,
+            InvalidMerkleRoot = 6002,
+            InvalidClaimWindow = 6003,
+            ClaimWindowClosed = 6004,
+            ClaimWindowOpen = 6005,
+            InvalidIndex = 6006,
+            ProofTooLong = 6007,
+            InvalidProof = 6008,
+            ExceedsMaxClaim = 6009,
+            Unauthorized = 6010,
+            Overflow = 6011,
+        }
+    }
+}
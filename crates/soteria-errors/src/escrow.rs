@@ -0,0 +1,17 @@
+// Escrow Error Codes
+//
+// a-secure:            EscrowError (#[error_code], 6000 + index)
+
+pub mod a_secure {
+    crate::error_codes! {
+        pub enum EscrowError {
+            InvalidExpiry = 6000,
+            OfferExpired = 6001,
+            TakerNotAllowed = 6002,
+            InvalidTokenMintA = 6003,
+            InvalidTokenMintB = 6004,
+            InvalidProposer = 6005,
+            InsufficientFunds = 6006,
+        }
+    }
+}
@@ -0,0 +1,162 @@
+// Governance Error Codes
+//
+// g-secure:            GovernanceError (#[error_code], 6000 + index)
+// g-vulnerable:        GovernanceError (#[error_code], 6000 + index)
+
+pub mod g_secure {
+    crate::error_codes! {
+        pub enum GovernanceError {
+            InsufficientStake = 6000,
+            MinimumStakeRequired = 6001,
+            InvalidStakeAmount = 6002,
+            VoteCooldownActive = 6003,
+            CannotVoteForSelf = 6004,
+            CannotDownvote = 6005,
+            UnauthorizedRole = 6006,
+            InvalidUsername = 6007,
+            UsernameAlreadyExists = 6008,
+            UsernameNotFound = 6009,
+            UnauthorizedAdmin = 6010,
+            UnauthorizedUser = 6011,
+            ProfileMismatch = 6012,
+            InvalidTokenMint = 6013,
+            InvalidTreasuryAccount = 6014,
+            InsufficientTreasuryBalance = 6015,
+            SystemPaused = 6016,
+            MathOverflow = 6017,
+            TokenTransferFailed = 6018,
+            TokenAccountCreationFailed = 6019,
+            InvalidInstructionData = 6020,
+            AccountAlreadyInitialized = 6021,
+            InvalidGovernanceParams = 6022,
+            InvalidProposalTitle = 6023,
+            ProposalNotActive = 6024,
+            VotingPeriodEnded = 6025,
+            VotingPeriodActive = 6026,
+            StakeAfterProposal = 6027,
+            InvalidUnbondingPeriod = 6028,
+            UnbondingPeriodActive = 6029,
+            NothingToClaim = 6030,
+            CannotDelegateToSelf = 6031,
+            DelegationChainNotAllowed = 6032,
+            StakeDelegated = 6033,
+            InvalidDecayParams = 6034,
+            DecayNotDue = 6035,
+            NoRewardsToClaim = 6036,
+            InsufficientRewardReserve = 6037,
+            UsernameUnchanged = 6038,
+            ProfileHasStake = 6039,
+            ProfileHasDelegations = 6040,
+            NotPendingAdmin = 6041,
+            InvalidSlashAmount = 6042,
+            MissingDelegationAccounts = 6043,
+            SeasonNotFinalized = 6044,
+            SeasonAlreadyFinalized = 6045,
+            NotInSeasonStandings = 6046,
+            SeasonRewardClaimed = 6047,
+            InvalidMintWeight = 6048,
+            MissingMintStakeAccount = 6049,
+            BallotsAlreadyCast = 6050,
+            ProposalNotPassed = 6051,
+            MultisigActionAlreadyExecuted = 6052,
+            InvalidMultisigAccount = 6053,
+            InvalidMultisigProgram = 6054,
+            InvalidVoteBatchSize = 6055,
+            MissingBatchAccounts = 6056,
+            InvalidSignatureInstruction = 6057,
+            SignatureMismatch = 6058,
+            InvalidVoteWeight = 6059,
+            InvalidBallotAccount = 6060,
+            InvalidStakeRamp = 6061,
+            VoteSeasonNotOver = 6062,
+            InvalidCouncilConfig = 6063,
+            NotCouncilMember = 6064,
+            InsufficientCouncilSignatures = 6065,
+            VetoWindowClosed = 6066,
+            VetoWindowActive = 6067,
+            RankTooLow = 6068,
+            InvalidLockDuration = 6069,
+            LockExpired = 6070,
+            LockNotExtended = 6071,
+            LockNotExpired = 6072,
+            TooManyTreasuryHoldings = 6073,
+            InvalidPriceFeed = 6074,
+            OraclePriceUnavailable = 6075,
+            StaleOraclePrice = 6076,
+            OracleConfidenceTooWide = 6077,
+            IncompleteTreasuryReport = 6078,
+            MetadataUriTooLong = 6079,
+            InvalidMetadataUri = 6080,
+        }
+    }
+}
+
+pub mod g_vulnerable {
+    crate::error_codes! {
+        pub enum GovernanceError {
+            InsufficientStake = 6000,
+            MinimumStakeRequired = 6001,
+            InvalidStakeAmount = 6002,
+            VoteCooldownActive = 6003,
+            CannotVoteForSelf = 6004,
+            CannotDownvote = 6005,
+            UnauthorizedRole = 6006,
+            InvalidUsername = 6007,
+            UsernameAlreadyExists = 6008,
+            UsernameNotFound = 6009,
+            UnauthorizedAdmin = 6010,
+            UnauthorizedUser = 6011,
+            ProfileMismatch = 6012,
+            InvalidTokenMint = 6013,
+            InvalidTreasuryAccount = 6014,
+            InsufficientTreasuryBalance = 6015,
+            SystemPaused = 6016,
+            MathOverflow = 6017,
+            TokenTransferFailed = 6018,
+            TokenAccountCreationFailed = 6019,
+            InvalidInstructionData = 6020,
+            AccountAlreadyInitialized = 6021,
+            InvalidGovernanceParams = 6022,
+            InvalidProposalTitle = 6023,
+            ProposalNotActive = 6024,
+            VotingPeriodEnded = 6025,
+            VotingPeriodActive = 6026,
+            StakeAfterProposal = 6027,
+            InvalidUnbondingPeriod = 6028,
+            UnbondingPeriodActive = 6029,
+            NothingToClaim = 6030,
+            CannotDelegateToSelf = 6031,
+            DelegationChainNotAllowed = 6032,
+            StakeDelegated = 6033,
+            InvalidDecayParams = 6034,
+            DecayNotDue = 6035,
+            NoRewardsToClaim = 6036,
+            InsufficientRewardReserve = 6037,
+            UsernameUnchanged = 6038,
+            ProfileHasStake = 6039,
+            ProfileHasDelegations = 6040,
+            NotPendingAdmin = 6041,
+            InvalidSlashAmount = 6042,
+            MissingDelegationAccounts = 6043,
+            SeasonNotFinalized = 6044,
+            SeasonAlreadyFinalized = 6045,
+            NotInSeasonStandings = 6046,
+            SeasonRewardClaimed = 6047,
+            InvalidMintWeight = 6048,
+            MissingMintStakeAccount = 6049,
+            InvalidVoteBatchSize = 6050,
+            MissingBatchAccounts = 6051,
+            InvalidSignatureInstruction = 6052,
+            SignatureMismatch = 6053,
+            InvalidVoteWeight = 6054,
+            InvalidBallotAccount = 6055,
+            InvalidCouncilConfig = 6056,
+            NotCouncilMember = 6057,
+            VetoWindowClosed = 6058,
+            InvalidLockDuration = 6059,
+            LockExpired = 6060,
+            LockNotExtended = 6061,
+            LockNotExpired = 6062,
+        }
+    }
+}
@@ -0,0 +1,60 @@
+// Lending Error Codes
+//
+// lend-secure:         LendingError (#[error_code], 6000 + index)
+// lend-vulnerable:     LendingError (#[error_code], 6000 + index)
+
+pub mod lend_secure {
+    crate::error_codes! {
+        pub enum LendingError {
+            CollateralFactorTooHigh = 6000,
+            InvalidLiquidationThreshold = 6001,
+            LiquidationBonusTooHigh = 6002,
+            BorrowRateTooHigh = 6003,
+            IdenticalMints = 6004,
+            Unauthorized = 6005,
+            ZeroAmount = 6006,
+            InvalidPrice = 6007,
+            PriceNotSet = 6008,
+            StalePrice = 6009,
+            InsufficientCollateral = 6010,
+            InsufficientLiquidity = 6011,
+            BorrowLimitExceeded = 6012,
+            WithdrawalUndercollateralized = 6013,
+            NoDebt = 6014,
+            PositionHealthy = 6015,
+            LiquidationTooLarge = 6016,
+            MarketMismatch = 6017,
+            Overflow = 6018,
+            Underflow = 6019,
+            DivisionByZero = 6020,
+        }
+    }
+}
+
+pub mod lend_vulnerable {
+    crate::error_codes! {
+        pub enum LendingError {
+            CollateralFactorTooHigh = 6000,
+            InvalidLiquidationThreshold = 6001,
+            LiquidationBonusTooHigh = 6002,
+            BorrowRateTooHigh = 6003,
+            IdenticalMints = 6004,
+            Unauthorized = 6005,
+            ZeroAmount = 6006,
+            InvalidPrice = 6007,
+            PriceNotSet = 6008,
+            StalePrice = 6009,
+            InsufficientCollateral = 6010,
+            InsufficientLiquidity = 6011,
+            BorrowLimitExceeded = 6012,
+            WithdrawalUndercollateralized = 6013,
+            NoDebt = 6014,
+            PositionHealthy = 6015,
+            LiquidationTooLarge = 6016,
+            MarketMismatch = 6017,
+            Overflow = 6018,
+            Underflow = 6019,
+            DivisionByZero = 6020,
+        }
+    }
+}
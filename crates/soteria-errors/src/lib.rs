@@ -0,0 +1,118 @@
+// Soteria Errors
+//
+// Error code registry for every program in the workspace. Anchor numbers
+// #[error_code] variants by position (6000, 6001, ...), so tests used to assert
+// magic strings like "6300" that silently went stale when a variant moved.
+// This crate provides:
+// 1. ErrorCode     - Trait over a program's error enum: code, name, lookup by code
+// 2. error_codes!  - Declares an enum with explicit, stable codes
+// 3. One module per program (m_secure, amm_vulnerable, ...) named after its
+//    directory, holding an enum with the same name as the program's own
+//
+// DESIGN:
+// Programs keep their #[error_code] enums so the IDL and on-chain logs are
+// unchanged, and re-export the matching enum here as <Name>Code. The codes are
+// checked against each program's errors.rs by tests/registry.rs, so adding or
+// reordering a variant fails there until the registry is updated.
+// No dependencies: the test-kit and clients can use it without Anchor.
+
+use core::fmt;
+
+mod amm;
+mod auction;
+mod bridge;
+mod clob;
+mod drop;
+mod escrow;
+mod governance;
+mod lending;
+mod lst;
+mod multisig;
+mod nfts;
+mod oracle;
+mod raffle;
+mod streaming;
+mod subs;
+mod vesting;
+
+pub use amm::*;
+pub use auction::*;
+pub use bridge::*;
+pub use clob::*;
+pub use drop::*;
+pub use escrow::*;
+pub use governance::*;
+pub use lending::*;
+pub use lst::*;
+pub use multisig::*;
+pub use nfts::*;
+pub use oracle::*;
+pub use raffle::*;
+pub use streaming::*;
+pub use subs::*;
+pub use vesting::*;
+
+// Offset Anchor adds to the variant index of #[error_code] enums
+pub const ANCHOR_ERROR_OFFSET: u32 = 6000;
+
+// A program's error enum with stable codes
+//
+// code() is the number carried by InstructionError::Custom, name() the
+// variant name Anchor logs as "Error Code: <Name>"
+pub trait ErrorCode: Copy + Eq + fmt::Debug + fmt::Display + 'static {
+    // Every variant, in code order
+    const VARIANTS: &'static [Self];
+
+    fn code(self) -> u32;
+
+    fn name(self) -> &'static str;
+
+    fn from_code(code: u32) -> Option<Self> {
+        Self::VARIANTS.iter().copied().find(|variant| variant.code() == code)
+    }
+}
+
+// Declare a registry enum
+//
+// error_codes! {
+//     pub enum MultisigError {
+//         NotAMember = 6000,
+//         AlreadyMember = 6001,
+//     }
+// }
+#[macro_export]
+macro_rules! error_codes {
+    (pub enum $name:ident { $($variant:ident = $code:literal,)* }) => {
+        #[repr(u32)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant = $code,)*
+        }
+
+        impl $crate::ErrorCode for $name {
+            const VARIANTS: &'static [Self] = &[$(Self::$variant,)*];
+
+            fn code(self) -> u32 {
+                self as u32
+            }
+
+            fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => stringify!($variant),)*
+                }
+            }
+        }
+
+        impl From<$name> for u32 {
+            fn from(error: $name) -> u32 {
+                error as u32
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{} ({})", $crate::ErrorCode::name(*self), *self as u32)
+            }
+        }
+    };
+}
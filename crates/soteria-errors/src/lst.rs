@@ -0,0 +1,34 @@
+// LST Error Codes
+//
+// lst-secure:          LstError (#[error_code], 6000 + index)
+// lst-vulnerable:      LstError (#[error_code], 6000 + index)
+
+pub mod lst_secure {
+    crate::error_codes! {
+        pub enum LstError {
+            ZeroAmount = 6000,
+            DepositTooSmall = 6001,
+            InvalidExchangeRate = 6002,
+            UpdateTooSoon = 6003,
+            RateChangeTooLarge = 6004,
+            RateNotBacked = 6005,
+            InsufficientVaultBalance = 6006,
+            Unauthorized = 6007,
+            Overflow = 6008,
+            DivisionByZero = 6009,
+        }
+    }
+}
+
+pub mod lst_vulnerable {
+    crate::error_codes! {
+        pub enum LstError {
+            ZeroAmount = 6000,
+            DepositTooSmall = 6001,
+            InvalidExchangeRate = 6002,
+            InsufficientVaultBalance = 6003,
+            Overflow = 6004,
+            DivisionByZero = 6005,
+        }
+    }
+}
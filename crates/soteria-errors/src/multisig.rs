@@ -0,0 +1,98 @@
+// Multisig Error Codes
+//
+// m-secure:            MultisigError (#[error_code], 6000 + index)
+// m-vulnerable:        MultisigError (#[error_code], 6000 + index)
+// pm-secure:           MultisigError (Pinocchio, Custom(index))
+
+pub mod m_secure {
+    crate::error_codes! {
+        pub enum MultisigError {
+            NotAMember = 6000,
+            AlreadyMember = 6001,
+            CannotRemoveCreator = 6002,
+            MaxMembersReached = 6003,
+            MinimumOneMember = 6004,
+            InsufficientPermissions = 6005,
+            OnlyAdmin = 6006,
+            CannotPropose = 6007,
+            CannotExecute = 6008,
+            CannotAddSelf = 6009,
+            InvalidThreshold = 6010,
+            ThresholdExceedsOwners = 6011,
+            ProposalNotActive = 6012,
+            AlreadyApproved = 6013,
+            InsufficientApprovals = 6014,
+            NotProposer = 6015,
+            ProposalExpired = 6016,
+            TimelockNotPassed = 6017,
+            InvalidProposalType = 6018,
+            InvalidProposal = 6019,
+            InsufficientFunds = 6020,
+            InvalidRecipient = 6021,
+            Overflow = 6022,
+            MultisigPaused = 6023,
+            InvalidParameter = 6024,
+            InvalidParentMultisig = 6025,
+            ParentProposalMismatch = 6026,
+            VaultRentDisabled = 6027,
+            InvalidRentPayer = 6028,
+            OutcomeMismatch = 6029,
+            MemberSlabFull = 6030,
+            InvalidStakeAccount = 6031,
+            InvalidVoteAccount = 6032,
+            TitleTooLong = 6033,
+            MemoTooLong = 6034,
+            InvalidMint = 6035,
+            ProposalHashMismatch = 6036,
+            ReentrantExecution = 6037,
+            InvalidLookupTable = 6038,
+            LookupTableAddressesMismatch = 6039,
+            InvalidThresholdPolicy = 6040,
+        }
+    }
+}
+
+pub mod m_vulnerable {
+    crate::error_codes! {
+        pub enum MultisigError {
+            InvalidOperation = 6000,
+            Unauthorized = 6001,
+            InvalidState = 6002,
+            NotAMember = 6003,
+            InvalidThreshold = 6004,
+            ProposalNotActive = 6005,
+            InsufficientApprovals = 6006,
+            Overflow = 6007,
+        }
+    }
+}
+
+pub mod pm_secure {
+    crate::error_codes! {
+        pub enum MultisigError {
+            NotAMember = 0,
+            AlreadyMember = 1,
+            CannotRemoveCreator = 2,
+            MaxMembersReached = 3,
+            MinimumOneMember = 4,
+            OnlyAdmin = 5,
+            CannotPropose = 6,
+            CannotExecute = 7,
+            CannotAddSelf = 8,
+            InvalidRole = 9,
+            InvalidThreshold = 10,
+            ThresholdExceedsOwners = 11,
+            ProposalNotActive = 12,
+            AlreadyApproved = 13,
+            InsufficientApprovals = 14,
+            ProposalExpired = 15,
+            TimelockNotPassed = 16,
+            InvalidProposalType = 17,
+            InvalidProposal = 18,
+            InsufficientFunds = 19,
+            InvalidRecipient = 20,
+            Overflow = 21,
+            InvalidParameter = 22,
+        }
+    }
+}
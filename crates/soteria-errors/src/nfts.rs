@@ -0,0 +1,107 @@
+// NFT Staking Error Codes
+//
+// n-secure:            NftError (#[error_code], 6000 + index)
+// n-vulnerable:        NftError (#[error_code], 6000 + index)
+
+pub mod n_secure {
+    crate::error_codes! {
+        pub enum NftError {
+            CollectionAlreadyInitialized = 6000,
+            CollectionAuthorityMismatch = 6001,
+            InvalidCollection = 6002,
+            AssetOwnerMismatch = 6003,
+            AssetNotInCollection = 6004,
+            AlreadyStaked = 6005,
+            NotStaked = 6006,
+            StakingNotInitialized = 6007,
+            AttributesNotInitialized = 6008,
+            InvalidTimestamp = 6009,
+            Overflow = 6010,
+            Underflow = 6011,
+            NameTooLong = 6012,
+            UriTooLong = 6013,
+            EmptyName = 6014,
+            EmptyUri = 6015,
+            InvalidMplCoreProgram = 6016,
+            UnauthorizedAuthority = 6017,
+            UnauthorizedOwner = 6018,
+            FreezeDelegateNotFound = 6019,
+            MinimumStakeDurationNotMet = 6020,
+            InvalidPayer = 6021,
+            InvalidRewardRate = 6022,
+            InvalidRewardMint = 6023,
+            NoRewardsToClaim = 6024,
+            CollectionPaused = 6025,
+            StakingCapReached = 6026,
+            InvalidStakingCap = 6027,
+            TooManyRarityTiers = 6028,
+            InvalidRarityValue = 6029,
+            InvalidRarityMultiplier = 6030,
+            DuplicateRarityTier = 6031,
+            InvalidMilestone = 6032,
+            InvalidMilestoneDays = 6033,
+            MilestoneNotReached = 6034,
+            InvalidVoterProfile = 6035,
+            VoterProfileNotGovernanceOwned = 6036,
+            DuplicateStakingAttribute = 6037,
+            InvalidStakingAttribute = 6038,
+            UnstakeAlreadyRequested = 6039,
+            UnstakeNotRequested = 6040,
+            UnstakeCooldownActive = 6041,
+            LockupAlreadyComplete = 6042,
+            InvalidFundingAmount = 6043,
+            InsufficientRewardFunds = 6044,
+            InvalidNewOwner = 6045,
+        }
+    }
+}
+
+pub mod n_vulnerable {
+    crate::error_codes! {
+        pub enum NftError {
+            CollectionAlreadyInitialized = 6000,
+            CollectionAuthorityMismatch = 6001,
+            InvalidCollection = 6002,
+            AssetOwnerMismatch = 6003,
+            AssetNotInCollection = 6004,
+            AlreadyStaked = 6005,
+            NotStaked = 6006,
+            StakingNotInitialized = 6007,
+            AttributesNotInitialized = 6008,
+            InvalidTimestamp = 6009,
+            Overflow = 6010,
+            Underflow = 6011,
+            NameTooLong = 6012,
+            UriTooLong = 6013,
+            EmptyName = 6014,
+            EmptyUri = 6015,
+            InvalidMplCoreProgram = 6016,
+            UnauthorizedAuthority = 6017,
+            UnauthorizedOwner = 6018,
+            FreezeDelegateNotFound = 6019,
+            MinimumStakeDurationNotMet = 6020,
+            InvalidPayer = 6021,
+            InvalidRewardRate = 6022,
+            InvalidRewardMint = 6023,
+            NoRewardsToClaim = 6024,
+            CollectionPaused = 6025,
+            StakingCapReached = 6026,
+            InvalidStakingCap = 6027,
+            TooManyRarityTiers = 6028,
+            InvalidRarityValue = 6029,
+            InvalidRarityMultiplier = 6030,
+            DuplicateRarityTier = 6031,
+            InvalidMilestone = 6032,
+            InvalidMilestoneDays = 6033,
+            MilestoneNotReached = 6034,
+            InvalidVoterProfile = 6035,
+            VoterProfileNotGovernanceOwned = 6036,
+            DuplicateStakingAttribute = 6037,
+            InvalidStakingAttribute = 6038,
+            UnstakeAlreadyRequested = 6039,
+            UnstakeNotRequested = 6040,
+            UnstakeCooldownActive = 6041,
+            LockupAlreadyComplete = 6042,
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// Oracle Error Codes
+//
+// oracle-consumer:     ConsumerError (#[error_code], 6000 + index)
+// oracle-secure:       OracleError (#[error_code], 6000 + index)
+// oracle-vulnerable:   OracleError (#[error_code], 6000 + index)
+
+pub mod oracle_consumer {
+    crate::error_codes! {
+        pub enum ConsumerError {
+            ZeroAmount = 6000,
+            InvalidFeed = 6001,
+            DecimalsMismatch = 6002,
+            MissingReturnData = 6003,
+            InvalidReturnData = 6004,
+            InvalidExponent = 6005,
+            SlippageExceeded = 6006,
+            InsufficientLiquidity = 6007,
+            Overflow = 6008,
+        }
+    }
+}
+
+pub mod oracle_secure {
+    crate::error_codes! {
+        pub enum OracleError {
+            InvalidPublisherCount = 6000,
+            InvalidPublisher = 6001,
+            InvalidStaleness = 6002,
+            InvalidConfidenceLimit = 6003,
+            InvalidExponent = 6004,
+            UnauthorizedPublisher = 6005,
+            InvalidPrice = 6006,
+            PriceUnavailable = 6007,
+            StalePrice = 6008,
+            ConfidenceTooWide = 6009,
+            Overflow = 6010,
+        }
+    }
+}
+
+pub mod oracle_vulnerable {
+    crate::error_codes! {
+        pub enum OracleError {
+            InvalidPublisherCount = 6000,
+            InvalidPublisher = 6001,
+            InvalidStaleness = 6002,
+            InvalidConfidenceLimit = 6003,
+            InvalidExponent = 6004,
+            UnauthorizedPublisher = 6005,
+            InvalidPrice = 6006,
+            PriceUnavailable = 6007,
+            ConfidenceTooWide = 6008,
+            Overflow = 6009,
+        }
+    }
+}
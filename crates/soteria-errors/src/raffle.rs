@@ -0,0 +1,50 @@
+// Raffle Error Codes
+//
+// raffle-secure:       RaffleError (#[error_code], 6000 + index)
+// raffle-vulnerable:   RaffleError (#[error_code], 6000 + index)
+
+pub mod raffle_secure {
+    crate::error_codes! {
+        pub enum RaffleError {
+            ZeroAmount = 6000,
+            InvalidMaxTickets = 6001,
+            InvalidDuration = 6002,
+            RaffleEnded = 6003,
+            RaffleNotEnded = 6004,
+            SoldOut = 6005,
+            CreatorCannotEnter = 6006,
+            AlreadyDrawn = 6007,
+            NoTickets = 6008,
+            InvalidReveal = 6009,
+            InvalidWinnerAccount = 6010,
+            Unauthorized = 6011,
+            Overflow = 6012,
+            RevealDeadlinePassed = 6013,
+            RevealWindowOpen = 6014,
+            NotSealed = 6015,
+            AlreadySealed = 6016,
+            RandomnessUnavailable = 6017,
+            InvalidTicket = 6018,
+            AlreadyRefunded = 6019,
+            InvalidRefundAccount = 6020,
+        }
+    }
+}
+
+pub mod raffle_vulnerable {
+    crate::error_codes! {
+        pub enum RaffleError {
+            ZeroAmount = 6000,
+            InvalidMaxTickets = 6001,
+            InvalidDuration = 6002,
+            RaffleEnded = 6003,
+            RaffleNotEnded = 6004,
+            SoldOut = 6005,
+            CreatorCannotEnter = 6006,
+            AlreadyDrawn = 6007,
+            NoTickets = 6008,
+            InvalidWinnerAccount = 6009,
+            Overflow = 6010,
+        }
+    }
+}
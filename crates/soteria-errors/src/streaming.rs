@@ -0,0 +1,36 @@
+// Streaming Error Codes
+//
+// stream-secure:       StreamError (#[error_code], 6000 + index)
+// stream-vulnerable:   StreamError (#[error_code], 6000 + index)
+
+pub mod stream_secure {
+    crate::error_codes! {
+        pub enum StreamError {
+            ZeroAmount = 6000,
+            InvalidTimeRange = 6001,
+            StartInPast = 6002,
+            DurationTooLong = 6003,
+            AmountExceedsAvailable = 6004,
+            StreamCancelled = 6005,
+            Unauthorized = 6006,
+            Overflow = 6007,
+            Underflow = 6008,
+        }
+    }
+}
+
+pub mod stream_vulnerable {
+    crate::error_codes! {
+        pub enum StreamError {
+            ZeroAmount = 6000,
+            InvalidTimeRange = 6001,
+            StartInPast = 6002,
+            DurationTooLong = 6003,
+            AmountExceedsAvailable = 6004,
+            StreamCancelled = 6005,
+            Unauthorized = 6006,
+            Overflow = 6007,
+            Underflow = 6008,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+// Subscriptions Error Codes
+//
+// subs-secure:         SubsError (#[error_code], 6000 + index)
+// subs-vulnerable:     SubsError (#[error_code], 6000 + index)
+
+pub mod subs_secure {
+    crate::error_codes! {
+        pub enum SubsError {
+            ZeroAmount = 6000,
+            InvalidPeriod = 6001,
+            InvalidApprovedPeriods = 6002,
+            ChargeTooEarly = 6003,
+            InsufficientAllowance = 6004,
+            Unauthorized = 6005,
+            Overflow = 6006,
+        }
+    }
+}
+
+pub mod subs_vulnerable {
+    crate::error_codes! {
+        pub enum SubsError {
+            ZeroAmount = 6000,
+            InvalidPeriod = 6001,
+            InvalidApprovedPeriods = 6002,
+            InsufficientAllowance = 6003,
+            Unauthorized = 6004,
+            Overflow = 6005,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+// Vesting Error Codes
+//
+// vesting-secure:      VestingError (#[error_code], 6000 + index)
+// vesting-vulnerable:  VestingError (#[error_code], 6000 + index)
+
+pub mod vesting_secure {
+    crate::error_codes! {
+        pub enum VestingError {
+            ZeroAmount = 6000,
+            InvalidSchedule = 6001,
+            DurationTooLong = 6002,
+            NothingToClaim = 6003,
+            NotRevocable = 6004,
+            AlreadyRevoked = 6005,
+            Unauthorized = 6006,
+            Overflow = 6007,
+            Underflow = 6008,
+            DivisionByZero = 6009,
+        }
+    }
+}
+
+pub mod vesting_vulnerable {
+    crate::error_codes! {
+        pub enum VestingError {
+            ZeroAmount = 6000,
+            InvalidSchedule = 6001,
+            DurationTooLong = 6002,
+            NothingToClaim = 6003,
+            NotRevocable = 6004,
+            AlreadyRevoked = 6005,
+            Unauthorized = 6006,
+            Overflow = 6007,
+            Underflow = 6008,
+            DivisionByZero = 6009,
+        }
+    }
+}
@@ -0,0 +1,261 @@
+// Registry tests
+//
+// Each registry enum must list the program's own error variants in source
+// order, numbered from the program's base code. A program that adds, removes
+// or reorders a variant fails here until its registry module is updated.
+
+use soteria_errors::*;
+
+// Variant names of the first enum in an errors.rs, in declaration order
+fn source_variants(source: &str) -> Vec<&str> {
+    let body = source
+        .split_once("pub enum ")
+        .and_then(|(_, rest)| rest.split_once('{'))
+        .and_then(|(_, rest)| rest.split_once("\n}"))
+        .map(|(body, _)| body)
+        .expect("errors.rs should declare an enum");
+
+    body.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("//") && !line.starts_with("#["))
+        .map(|line| line.trim_end_matches(','))
+        .collect()
+}
+
+fn assert_matches_source<E: ErrorCode>(source: &str, base: u32) {
+    let names: Vec<&str> = E::VARIANTS.iter().map(|variant| variant.name()).collect();
+    assert_eq!(names, source_variants(source));
+
+    for (index, variant) in E::VARIANTS.iter().enumerate() {
+        assert_eq!(variant.code(), base + index as u32, "{}", variant);
+        assert_eq!(E::from_code(variant.code()), Some(*variant));
+    }
+}
+
+#[test]
+fn test_amm_codes_match_source() {
+    assert_matches_source::<amm_cl_secure::AmmClError>(
+        include_str!("../../../programs/amm/amm-cl-secure/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<amm_cl_vulnerable::AmmClError>(
+        include_str!("../../../programs/amm/amm-cl-vulnerable/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<amm_secure::AmmError>(
+        include_str!("../../../programs/amm/amm-secure/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<amm_vulnerable::AmmError>(
+        include_str!("../../../programs/amm/amm-vulnerable/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<gauge_secure::GaugeError>(
+        include_str!("../../../programs/amm/gauge-secure/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<gauge_vulnerable::GaugeError>(
+        include_str!("../../../programs/amm/gauge-vulnerable/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+}
+
+#[test]
+fn test_auction_codes_match_source() {
+    assert_matches_source::<auction_secure::AuctionError>(
+        include_str!("../../../programs/auction/auction-secure/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<auction_vulnerable::AuctionError>(
+        include_str!("../../../programs/auction/auction-vulnerable/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+}
+
+#[test]
+fn test_bridge_codes_match_source() {
+    assert_matches_source::<bridge_secure::BridgeError>(
+        include_str!("../../../programs/bridge/bridge-secure/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<bridge_vulnerable::BridgeError>(
+        include_str!("../../../programs/bridge/bridge-vulnerable/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+}
+
+#[test]
+fn test_clob_codes_match_source() {
+    assert_matches_source::<clob_secure::ClobError>(
+        include_str!("../../../programs/clob/clob-secure/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<clob_vulnerable::ClobError>(
+        include_str!("../../../programs/clob/clob-vulnerable/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+}
+
+#[test]
+fn test_drop_codes_match_source() {
+    assert_matches_source::<drop_secure::DropError>(
+        include_str!("../../../programs/drop/drop-secure/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<drop_vulnerable::DropError>(
+        include_str!("../../../programs/drop/drop-vulnerable/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+}
+
+#[test]
+fn test_escrow_codes_match_source() {
+    assert_matches_source::<a_secure::EscrowError>(
+        include_str!("../../../programs/escrow/a-secure/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+}
+
+#[test]
+fn test_governance_codes_match_source() {
+    assert_matches_source::<g_secure::GovernanceError>(
+        include_str!("../../../programs/governance/g-secure/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<g_vulnerable::GovernanceError>(
+        include_str!("../../../programs/governance/g-vulnerable/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+}
+
+#[test]
+fn test_lending_codes_match_source() {
+    assert_matches_source::<lend_secure::LendingError>(
+        include_str!("../../../programs/lending/lend-secure/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<lend_vulnerable::LendingError>(
+        include_str!("../../../programs/lending/lend-vulnerable/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+}
+
+#[test]
+fn test_lst_codes_match_source() {
+    assert_matches_source::<lst_secure::LstError>(
+        include_str!("../../../programs/lst/lst-secure/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<lst_vulnerable::LstError>(
+        include_str!("../../../programs/lst/lst-vulnerable/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+}
+
+#[test]
+fn test_multisig_codes_match_source() {
+    assert_matches_source::<m_secure::MultisigError>(
+        include_str!("../../../programs/multisig/m-secure/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<m_vulnerable::MultisigError>(
+        include_str!("../../../programs/multisig/m-vulnerable/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<pm_secure::MultisigError>(
+        include_str!("../../../programs/multisig/pm-secure/src/errors.rs"),
+        0,
+    );
+}
+
+#[test]
+fn test_nfts_codes_match_source() {
+    assert_matches_source::<n_secure::NftError>(
+        include_str!("../../../programs/nfts/n-secure/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<n_vulnerable::NftError>(
+        include_str!("../../../programs/nfts/n-vulnerable/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+}
+
+#[test]
+fn test_oracle_codes_match_source() {
+    assert_matches_source::<oracle_consumer::ConsumerError>(
+        include_str!("../../../programs/oracle/oracle-consumer/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<oracle_secure::OracleError>(
+        include_str!("../../../programs/oracle/oracle-secure/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<oracle_vulnerable::OracleError>(
+        include_str!("../../../programs/oracle/oracle-vulnerable/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+}
+
+#[test]
+fn test_raffle_codes_match_source() {
+    assert_matches_source::<raffle_secure::RaffleError>(
+        include_str!("../../../programs/raffle/raffle-secure/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<raffle_vulnerable::RaffleError>(
+        include_str!("../../../programs/raffle/raffle-vulnerable/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+}
+
+#[test]
+fn test_streaming_codes_match_source() {
+    assert_matches_source::<stream_secure::StreamError>(
+        include_str!("../../../programs/streaming/stream-secure/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<stream_vulnerable::StreamError>(
+        include_str!("../../../programs/streaming/stream-vulnerable/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+}
+
+#[test]
+fn test_subs_codes_match_source() {
+    assert_matches_source::<subs_secure::SubsError>(
+        include_str!("../../../programs/subs/subs-secure/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<subs_vulnerable::SubsError>(
+        include_str!("../../../programs/subs/subs-vulnerable/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+}
+
+#[test]
+fn test_vesting_codes_match_source() {
+    assert_matches_source::<vesting_secure::VestingError>(
+        include_str!("../../../programs/vesting/vesting-secure/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+    assert_matches_source::<vesting_vulnerable::VestingError>(
+        include_str!("../../../programs/vesting/vesting-vulnerable/src/errors.rs"),
+        ANCHOR_ERROR_OFFSET,
+    );
+}
+
+#[test]
+fn test_from_code_and_display() {
+    // Test: Codes outside the enum are rejected, Display names the variant
+    assert_eq!(
+        m_secure::MultisigError::from_code(6023),
+        Some(m_secure::MultisigError::MultisigPaused)
+    );
+    assert_eq!(m_secure::MultisigError::from_code(5999), None);
+    assert_eq!(pm_secure::MultisigError::from_code(0), Some(pm_secure::MultisigError::NotAMember));
+    assert_eq!(
+        m_secure::MultisigError::NotAMember.to_string(),
+        "NotAMember (6000)"
+    );
+    assert_eq!(u32::from(n_secure::NftError::VoterProfileNotGovernanceOwned), 6036);
+}
@@ -0,0 +1,207 @@
+// Compute Budget Regression
+//
+// Backs each secure program's tests/compute.rs:
+// 1. ComputeBudget::load reads the checked-in baselines for the package
+// 2. record takes the result of one instruction and stores the compute units
+//    it consumed (from LiteSVM's TransactionMetadata) under a name
+// 3. check compares every measurement with its baseline and panics when one
+//    grew by more than the tolerance
+//
+// Baselines file: one `name = units` line per instruction, `#` starts a comment.
+// SOTERIA_CU_TOLERANCE_PCT overrides the default tolerance of 5%.
+// SOTERIA_UPDATE_CU_BASELINES=1 rewrites the file with the new measurements
+// instead of comparing, for when a fix is expected to cost more.
+
+use litesvm::types::{TransactionMetadata, TransactionResult};
+use std::{collections::BTreeMap, fmt::Write as _, fs, path::PathBuf};
+
+pub const CU_TOLERANCE_ENV: &str = "SOTERIA_CU_TOLERANCE_PCT";
+pub const UPDATE_CU_BASELINES_ENV: &str = "SOTERIA_UPDATE_CU_BASELINES";
+pub const DEFAULT_CU_TOLERANCE_PCT: u64 = 5;
+pub const DEFAULT_CU_BASELINES_FILE: &str = "tests/compute-baselines.txt";
+
+// One instruction that consumed more than its baseline allows
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComputeRegression {
+    pub name: String,
+    pub baseline: u64,
+    pub measured: u64,
+}
+
+impl ComputeRegression {
+    // Growth over the baseline in whole percent, rounded up
+    pub fn increase_pct(&self) -> u64 {
+        let growth = (self.measured - self.baseline) as u128 * 100;
+        growth.div_ceil((self.baseline as u128).max(1)) as u64
+    }
+}
+
+pub struct ComputeBudget {
+    path: PathBuf,
+    tolerance_pct: u64,
+    update: bool,
+    baselines: BTreeMap<String, u64>,
+    measured: BTreeMap<String, u64>,
+}
+
+impl ComputeBudget {
+    // Read baselines from a path relative to the package root
+    // A missing file means no baselines yet: every measurement is new
+    pub fn load(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let baselines = match fs::read_to_string(&path) {
+            Ok(contents) => parse_baselines(&contents)
+                .unwrap_or_else(|err| panic!("Invalid baselines in {}: {}", path.display(), err)),
+            Err(_) => BTreeMap::new(),
+        };
+
+        let tolerance_pct = std::env::var(CU_TOLERANCE_ENV)
+            .ok()
+            .map(|value| {
+                value.trim().parse().unwrap_or_else(|_| {
+                    panic!("{} must be a whole percentage, got {:?}", CU_TOLERANCE_ENV, value)
+                })
+            })
+            .unwrap_or(DEFAULT_CU_TOLERANCE_PCT);
+        let update = std::env::var_os(UPDATE_CU_BASELINES_ENV).is_some_and(|value| value != "0");
+
+        ComputeBudget {
+            path,
+            tolerance_pct,
+            update,
+            baselines,
+            measured: BTreeMap::new(),
+        }
+    }
+
+    pub fn tolerance_pct(mut self, tolerance_pct: u64) -> Self {
+        self.tolerance_pct = tolerance_pct;
+        self
+    }
+
+    pub fn update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+
+    // Store the units consumed by a successful instruction
+    // Panics with the program logs if it failed, or if the name was already recorded
+    pub fn record(&mut self, name: &str, result: TransactionResult) -> TransactionMetadata {
+        let meta = crate::assert_tx_ok!(result, format!("{} should succeed", name));
+        let previous = self.measured.insert(name.to_string(), meta.compute_units_consumed);
+        assert!(previous.is_none(), "Compute units for {} recorded twice", name);
+        println!("[CU] {}: {}", name, meta.compute_units_consumed);
+        meta
+    }
+
+    pub fn baseline(&self, name: &str) -> Option<u64> {
+        self.baselines.get(name).copied()
+    }
+
+    pub fn measured(&self, name: &str) -> Option<u64> {
+        self.measured.get(name).copied()
+    }
+
+    // Measurements above baseline * (100 + tolerance) / 100
+    pub fn regressions(&self) -> Vec<ComputeRegression> {
+        self.measured
+            .iter()
+            .filter_map(|(name, &measured)| {
+                let baseline = self.baseline(name)?;
+                let allowed = baseline as u128 * (100 + self.tolerance_pct) as u128 / 100;
+                (measured as u128 > allowed).then(|| ComputeRegression {
+                    name: name.clone(),
+                    baseline,
+                    measured,
+                })
+            })
+            .collect()
+    }
+
+    // Compare with the baselines (or rewrite them in update mode) and panic on
+    // any regression beyond the tolerance
+    pub fn check(&self) {
+        assert!(!self.measured.is_empty(), "No compute units were recorded");
+
+        if self.update {
+            self.write_baselines()
+                .unwrap_or_else(|err| panic!("Could not write {}: {}", self.path.display(), err));
+            println!("[CU] Wrote {} baselines to {}", self.measured.len(), self.path.display());
+            return;
+        }
+
+        for (name, measured) in &self.measured {
+            match self.baseline(name) {
+                Some(baseline) => println!("[CU] {}: {} (baseline {})", name, measured, baseline),
+                None => println!("[CU] {}: {} (no baseline)", name, measured),
+            }
+        }
+        for name in self.baselines.keys().filter(|name| !self.measured.contains_key(*name)) {
+            println!("[CU] {}: baseline no longer measured", name);
+        }
+        if self.measured.keys().any(|name| !self.baselines.contains_key(name)) {
+            println!(
+                "[CU] Run with {}=1 and commit {} to record missing baselines",
+                UPDATE_CU_BASELINES_ENV,
+                self.path.display()
+            );
+        }
+
+        let regressions = self.regressions();
+        if !regressions.is_empty() {
+            let mut report = String::new();
+            for regression in &regressions {
+                let _ = write!(
+                    report,
+                    "\n  {}: {} -> {} (+{}%)",
+                    regression.name,
+                    regression.baseline,
+                    regression.measured,
+                    regression.increase_pct()
+                );
+            }
+            panic!(
+                "Compute units regressed more than {}% over {}:{}",
+                self.tolerance_pct,
+                self.path.display(),
+                report
+            );
+        }
+    }
+
+    fn write_baselines(&self) -> std::io::Result<()> {
+        let mut contents = format!(
+            "# Compute units per instruction, checked by tests/compute.rs\n\
+             # Regenerate with {}=1 cargo test-sbf --test compute\n",
+            UPDATE_CU_BASELINES_ENV
+        );
+        for (name, units) in &self.measured {
+            let _ = writeln!(contents, "{} = {}", name, units);
+        }
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, contents)
+    }
+}
+
+pub fn parse_baselines(contents: &str) -> Result<BTreeMap<String, u64>, String> {
+    let mut baselines = BTreeMap::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (name, units) = line
+            .split_once('=')
+            .ok_or_else(|| format!("line {}: expected `name = units`", index + 1))?;
+        let units = units
+            .trim()
+            .parse()
+            .map_err(|_| format!("line {}: {:?} is not a unit count", index + 1, units.trim()))?;
+        if baselines.insert(name.trim().to_string(), units).is_some() {
+            return Err(format!("line {}: duplicate entry {}", index + 1, name.trim()));
+        }
+    }
+    Ok(baselines)
+}
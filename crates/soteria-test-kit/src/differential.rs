@@ -0,0 +1,299 @@
+// Differential Harness
+//
+// Replays the same transaction script against both builds of a program pair:
+// 1. Both .so files are loaded into one LiteSVM under their own program IDs
+// 2. setup and exploit run once per build, with that build's program ID
+// 3. The exploit transaction must succeed on the vulnerable build and fail on
+//    the secure one
+//
+// Every PDA is derived from the program ID, so the two runs never share
+// accounts even though they share the SVM, the clock and the token programs.
+//
+// Feature variants run the same scenarios against the vulnerability matrix:
+// the secure crate rebuilt with one vuln-* cargo feature (build-vuln-matrix.sh).
+// Both builds share a declare_id!, so each gets its own LiteSVM instead.
+
+use litesvm::{types::TransactionResult, LiteSVM};
+use solana_sdk::pubkey::Pubkey;
+use std::{
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+};
+
+use crate::{exploit::panic_message, harness::ProgramHarness, ExploitInfo};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Build {
+    Secure,
+    Vulnerable,
+}
+
+impl Build {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Build::Secure => "secure",
+            Build::Vulnerable => "vulnerable",
+        }
+    }
+}
+
+// Program IDs of the two builds
+#[derive(Debug, Clone, Copy)]
+pub struct ProgramPair {
+    pub secure: Pubkey,
+    pub vulnerable: Pubkey,
+}
+
+impl ProgramPair {
+    pub fn program_id(&self, build: Build) -> Pubkey {
+        match build {
+            Build::Secure => self.secure,
+            Build::Vulnerable => self.vulnerable,
+        }
+    }
+
+    // Load both builds from paths relative to the package root
+    pub fn load(
+        &self,
+        secure_path: impl Into<PathBuf>,
+        vulnerable_path: impl Into<PathBuf>,
+    ) -> LiteSVM {
+        ProgramHarness::builder()
+            .program_file(self.secure, secure_path)
+            .program_file(self.vulnerable, vulnerable_path)
+            .build()
+            .into_svm()
+    }
+}
+
+// Where build-vuln-matrix.sh writes each feature build, under the package root
+pub const VULN_MATRIX_DIR: &str = "target/deploy/vuln-matrix";
+
+// The secure build recompiled with a single vuln-* feature
+#[derive(Debug, Clone, Copy)]
+pub struct FeatureVariant {
+    pub program_id: Pubkey,
+    pub feature: &'static str,
+}
+
+impl FeatureVariant {
+    // <secure_dir>/target/deploy/vuln-matrix/<feature>/<lib_name>.so
+    pub fn so_path(&self, secure_dir: impl AsRef<Path>, lib_name: &str) -> PathBuf {
+        secure_dir
+            .as_ref()
+            .join(VULN_MATRIX_DIR)
+            .join(self.feature)
+            .join(format!("{}.so", lib_name))
+    }
+
+    // Load the baseline and the feature build into separate SVMs
+    pub fn load(
+        &self,
+        baseline_path: impl Into<PathBuf>,
+        variant_path: impl Into<PathBuf>,
+    ) -> (LiteSVM, LiteSVM) {
+        let load = |path: PathBuf| {
+            ProgramHarness::builder()
+                .program_file(self.program_id, path)
+                .build()
+                .into_svm()
+        };
+        (load(baseline_path.into()), load(variant_path.into()))
+    }
+}
+
+pub trait DifferentialScenario {
+    // Whatever exploit needs from setup: keypairs, addresses
+    type State;
+
+    fn info(&self) -> ExploitInfo;
+
+    // Build the honest state the attacker starts from on `program_id`
+    fn setup(&self, svm: &mut LiteSVM, program_id: &Pubkey) -> Self::State;
+
+    // The attack; must be the same instructions for both builds
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        program_id: &Pubkey,
+        state: &mut Self::State,
+    ) -> TransactionResult;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DifferentialResult {
+    pub vuln_id: String,
+    pub title: String,
+    // Some(error) when the exploit transaction was rejected
+    pub secure_error: Option<String>,
+    pub vulnerable_error: Option<String>,
+    // Set when setup or exploit panicked on either build
+    pub error: Option<String>,
+}
+
+impl DifferentialResult {
+    pub fn passed(&self) -> bool {
+        self.error.is_none() && self.secure_error.is_some() && self.vulnerable_error.is_none()
+    }
+}
+
+// Run a scenario on both builds and panic unless only the vulnerable build accepts it
+pub fn run_differential<S: DifferentialScenario>(
+    svm: &mut LiteSVM,
+    pair: &ProgramPair,
+    scenario: &S,
+) -> DifferentialResult {
+    let result = execute_differential(svm, pair, scenario);
+
+    if let Some(error) = &result.error {
+        panic!("Differential {} could not run: {}", result.vuln_id, error);
+    }
+    assert!(
+        result.vulnerable_error.is_none(),
+        "Differential {}: exploit rejected by the vulnerable build: {}",
+        result.vuln_id,
+        result.vulnerable_error.as_deref().unwrap_or_default()
+    );
+    assert!(
+        result.secure_error.is_some(),
+        "Differential {}: exploit accepted by the secure build",
+        result.vuln_id
+    );
+    result
+}
+
+// Run a scenario on both builds and return the outcome without panicking
+pub fn execute_differential<S: DifferentialScenario>(
+    svm: &mut LiteSVM,
+    pair: &ProgramPair,
+    scenario: &S,
+) -> DifferentialResult {
+    let info = scenario.info();
+    println!("\n================================================================================");
+    println!("DIFFERENTIAL TEST: {} ({} - {})", info.title, info.id, info.severity.as_str());
+    println!("================================================================================");
+
+    let vulnerable = run_build(svm, pair, scenario, Build::Vulnerable);
+    let secure = run_build(svm, pair, scenario, Build::Secure);
+
+    println!("[LESSON] {}", info.lesson);
+    println!("================================================================================\n");
+
+    into_result(&info, vulnerable, secure)
+}
+
+// Run a scenario on the baseline and on one feature build, and panic unless
+// only the feature build accepts it
+pub fn run_feature_differential<S: DifferentialScenario>(
+    baseline: &mut LiteSVM,
+    variant_svm: &mut LiteSVM,
+    variant: &FeatureVariant,
+    scenario: &S,
+) -> DifferentialResult {
+    let result = execute_feature_differential(baseline, variant_svm, variant, scenario);
+
+    if let Some(error) = &result.error {
+        panic!("Feature {} ({}) could not run: {}", variant.feature, result.vuln_id, error);
+    }
+    assert!(
+        result.vulnerable_error.is_none(),
+        "Feature {} ({}): exploit rejected with the feature enabled: {}",
+        variant.feature,
+        result.vuln_id,
+        result.vulnerable_error.as_deref().unwrap_or_default()
+    );
+    assert!(
+        result.secure_error.is_some(),
+        "Feature {} ({}): exploit accepted by the baseline build",
+        variant.feature,
+        result.vuln_id
+    );
+    result
+}
+
+// Run a scenario on the baseline and on one feature build without panicking
+// secure_error holds the baseline outcome, vulnerable_error the feature build's
+pub fn execute_feature_differential<S: DifferentialScenario>(
+    baseline: &mut LiteSVM,
+    variant_svm: &mut LiteSVM,
+    variant: &FeatureVariant,
+    scenario: &S,
+) -> DifferentialResult {
+    let info = scenario.info();
+    println!("\n================================================================================");
+    println!(
+        "FEATURE DIFFERENTIAL: {} ({} - {}) with {}",
+        info.title,
+        info.id,
+        info.severity.as_str(),
+        variant.feature
+    );
+    println!("================================================================================");
+
+    let vulnerable = run_on(variant_svm, &variant.program_id, variant.feature, scenario);
+    let secure = run_on(baseline, &variant.program_id, "baseline", scenario);
+
+    println!("[LESSON] {}", info.lesson);
+    println!("================================================================================\n");
+
+    into_result(&info, vulnerable, secure)
+}
+
+fn into_result(
+    info: &ExploitInfo,
+    vulnerable: Result<Option<String>, String>,
+    secure: Result<Option<String>, String>,
+) -> DifferentialResult {
+    let mut result = DifferentialResult {
+        vuln_id: info.id.to_string(),
+        title: info.title.to_string(),
+        secure_error: None,
+        vulnerable_error: None,
+        error: None,
+    };
+    match (vulnerable, secure) {
+        (Ok(vulnerable_error), Ok(secure_error)) => {
+            result.vulnerable_error = vulnerable_error;
+            result.secure_error = secure_error;
+        }
+        (Err(error), _) | (_, Err(error)) => result.error = Some(error),
+    }
+    result
+}
+
+fn run_build<S: DifferentialScenario>(
+    svm: &mut LiteSVM,
+    pair: &ProgramPair,
+    scenario: &S,
+    build: Build,
+) -> Result<Option<String>, String> {
+    run_on(svm, &pair.program_id(build), build.as_str(), scenario)
+}
+
+// Ok(None) if the exploit was accepted, Ok(Some(error)) if rejected, Err on a panic
+fn run_on<S: DifferentialScenario>(
+    svm: &mut LiteSVM,
+    program_id: &Pubkey,
+    build: &str,
+    scenario: &S,
+) -> Result<Option<String>, String> {
+    let label = build.to_uppercase();
+
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut state = scenario.setup(svm, program_id);
+        scenario.exploit(svm, program_id, &mut state)
+    }))
+    .map_err(|payload| format!("{} build: {}", build, panic_message(payload)))?;
+
+    match outcome {
+        Ok(_) => {
+            println!("[{}] Exploit accepted", label);
+            Ok(None)
+        }
+        Err(failed) => {
+            let error = format!("{:?}", failed.err);
+            println!("[{}] Exploit rejected: {}", label, error);
+            Ok(Some(error))
+        }
+    }
+}
@@ -0,0 +1,166 @@
+// Exploit Harness
+//
+// Structure for the vulnerable suites' exploit tests:
+// 1. setup - Build the SVM and the honest state the attacker starts from
+// 2. exploit - Send the attack transaction (must succeed on the vulnerable program)
+// 3. assert_impact - Check the damage and report the funds it put at risk
+//
+// run_exploit drives the three phases, turns panics and a failed attack
+// transaction into a failed result, and writes one JSON file per scenario so
+// results from every suite can be collected without parsing test output.
+//
+// Output: target/exploit-results/<package>-<vuln id>.json relative to the
+// package being tested, or SOTERIA_EXPLOIT_RESULTS_DIR when set.
+
+use litesvm::types::TransactionResult;
+use serde::Serialize;
+use std::{
+    any::Any,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+};
+
+pub const EXPLOIT_RESULTS_DIR_ENV: &str = "SOTERIA_EXPLOIT_RESULTS_DIR";
+pub const DEFAULT_EXPLOIT_RESULTS_DIR: &str = "target/exploit-results";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Low => "low",
+            Severity::Medium => "medium",
+            Severity::High => "high",
+            Severity::Critical => "critical",
+        }
+    }
+}
+
+// Static description of one vulnerability
+// id matches the "VULNERABILITY V00N" marker in the vulnerable program; suites
+// whose program has no numbered markers number their scenarios in test order
+#[derive(Debug, Clone, Copy)]
+pub struct ExploitInfo {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub severity: Severity,
+    pub lesson: &'static str,
+}
+
+pub trait ExploitScenario {
+    // Whatever the later phases need: the SVM, keypairs, addresses, balances
+    type State;
+
+    fn info(&self) -> ExploitInfo;
+
+    fn setup(&self) -> Self::State;
+
+    // The attack itself; an Err here means the exploit did not reproduce
+    fn exploit(&self, state: &mut Self::State) -> TransactionResult;
+
+    // Assert the impact and return the funds at risk in base units of the
+    // affected token (lamports for SOL); 0 when no funds are directly exposed
+    fn assert_impact(&self, state: &mut Self::State) -> u64;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ExploitResult {
+    pub program: String,
+    pub vuln_id: String,
+    pub title: String,
+    pub severity: Severity,
+    pub funds_at_risk: u64,
+    pub passed: bool,
+    pub error: Option<String>,
+}
+
+impl ExploitResult {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("ExploitResult always serializes")
+    }
+}
+
+// Run a scenario, record its result and panic if the exploit did not reproduce
+pub fn run_exploit<S: ExploitScenario>(scenario: &S) -> ExploitResult {
+    let program = std::env::var("CARGO_PKG_NAME").unwrap_or_else(|_| "unknown".to_string());
+    let result = execute_exploit(&program, scenario);
+
+    let json = result.to_json();
+    println!("[EXPLOIT RESULT] {}", json);
+    if let Err(err) = write_result(&result, &json) {
+        println!("[EXPLOIT RESULT] Could not write result file: {}", err);
+    }
+
+    if let Some(error) = &result.error {
+        panic!("Exploit {} did not reproduce: {}", result.vuln_id, error);
+    }
+    result
+}
+
+// Run a scenario and return its result without writing a file or panicking
+pub fn execute_exploit<S: ExploitScenario>(program: &str, scenario: &S) -> ExploitResult {
+    let info = scenario.info();
+    println!("\n================================================================================");
+    println!("EXPLOIT TEST: {} ({} - {})", info.title, info.id, info.severity.as_str());
+    println!("================================================================================");
+
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut state = scenario.setup();
+        if let Err(failed) = scenario.exploit(&mut state) {
+            for log in &failed.meta.logs {
+                println!("  {}", log);
+            }
+            return Err(format!("attack transaction failed: {:?}", failed.err));
+        }
+        Ok(scenario.assert_impact(&mut state))
+    }))
+    .unwrap_or_else(|payload| Err(panic_message(payload)));
+
+    println!("[LESSON] {}", info.lesson);
+    println!("================================================================================\n");
+
+    let (funds_at_risk, error) = match outcome {
+        Ok(funds_at_risk) => (funds_at_risk, None),
+        Err(error) => (0, Some(error)),
+    };
+    ExploitResult {
+        program: program.to_string(),
+        vuln_id: info.id.to_string(),
+        title: info.title.to_string(),
+        severity: info.severity,
+        funds_at_risk,
+        passed: error.is_none(),
+        error,
+    }
+}
+
+pub fn results_dir() -> PathBuf {
+    std::env::var_os(EXPLOIT_RESULTS_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_EXPLOIT_RESULTS_DIR))
+}
+
+fn write_result(result: &ExploitResult, json: &str) -> std::io::Result<()> {
+    let dir = results_dir();
+    fs::create_dir_all(&dir)?;
+    let file = format!("{}-{}.json", result.program, result.vuln_id);
+    fs::write(dir.join(file), json)
+}
+
+pub(crate) fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "scenario panicked".to_string()
+    }
+}
@@ -0,0 +1,146 @@
+// Program Harness
+//
+// Builder over LiteSVM that replaces each suite's setup_svm:
+// 1. Loads one or more programs by .so path or bytes
+// 2. Optionally pins the clock to a start timestamp
+// 3. Hands out accounts funded with a default airdrop
+//
+// Paths are resolved against the current directory, which cargo sets to the
+// package root when running tests, so "target/deploy/x.so" works from any
+// program crate the same way include_bytes!("../target/deploy/x.so") did.
+
+use litesvm::{types::TransactionResult, LiteSVM};
+use solana_sdk::{
+    clock::Clock,
+    instruction::Instruction,
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::Keypair,
+};
+use std::path::PathBuf;
+
+use crate::svm;
+
+pub const DEFAULT_AIRDROP_LAMPORTS: u64 = 10 * LAMPORTS_PER_SOL;
+
+enum ProgramSource {
+    File(PathBuf),
+    Bytes(Vec<u8>),
+}
+
+pub struct ProgramHarness {
+    pub svm: LiteSVM,
+    pub airdrop_lamports: u64,
+}
+
+pub struct ProgramHarnessBuilder {
+    programs: Vec<(Pubkey, ProgramSource)>,
+    airdrop_lamports: u64,
+    unix_timestamp: Option<i64>,
+}
+
+impl ProgramHarness {
+    pub fn builder() -> ProgramHarnessBuilder {
+        ProgramHarnessBuilder {
+            programs: Vec::new(),
+            airdrop_lamports: DEFAULT_AIRDROP_LAMPORTS,
+            unix_timestamp: None,
+        }
+    }
+
+    // New keypair funded with the default airdrop
+    pub fn funded_account(&mut self) -> Keypair {
+        svm::create_funded_account(&mut self.svm, self.airdrop_lamports)
+    }
+
+    // New keypair funded with a specific amount
+    pub fn funded_account_with(&mut self, lamports: u64) -> Keypair {
+        svm::create_funded_account(&mut self.svm, lamports)
+    }
+
+    pub fn send_ix(&mut self, ix: Instruction, signer: &Keypair) -> TransactionResult {
+        svm::send_ix(&mut self.svm, ix, signer)
+    }
+
+    pub fn send_tx(
+        &mut self,
+        ixs: &[Instruction],
+        payer: &Keypair,
+        signers: &[&Keypair],
+    ) -> TransactionResult {
+        svm::send_tx(&mut self.svm, ixs, payer, signers)
+    }
+
+    pub fn advance_time(&mut self, seconds: u64) {
+        svm::advance_time(&mut self.svm, seconds);
+    }
+
+    // Current Clock unix_timestamp
+    pub fn now(&self) -> i64 {
+        self.svm.get_sysvar::<Clock>().unix_timestamp
+    }
+
+    // Drop the harness and keep the SVM, for suites that pass &mut LiteSVM around
+    pub fn into_svm(self) -> LiteSVM {
+        self.svm
+    }
+}
+
+impl ProgramHarnessBuilder {
+    // Load a compiled program from a path relative to the package root
+    pub fn program_file(mut self, program_id: Pubkey, path: impl Into<PathBuf>) -> Self {
+        self.programs
+            .push((program_id, ProgramSource::File(path.into())));
+        self
+    }
+
+    // Load a program from bytes, e.g. include_bytes!
+    pub fn program_bytes(mut self, program_id: Pubkey, bytes: &[u8]) -> Self {
+        self.programs
+            .push((program_id, ProgramSource::Bytes(bytes.to_vec())));
+        self
+    }
+
+    // Lamports given to each account from ProgramHarness::funded_account
+    pub fn airdrop_lamports(mut self, lamports: u64) -> Self {
+        self.airdrop_lamports = lamports;
+        self
+    }
+
+    // Start the clock at a fixed timestamp instead of LiteSVM's default
+    pub fn unix_timestamp(mut self, unix_timestamp: i64) -> Self {
+        self.unix_timestamp = Some(unix_timestamp);
+        self
+    }
+
+    pub fn build(self) -> ProgramHarness {
+        let mut svm = LiteSVM::new();
+
+        for (program_id, source) in self.programs {
+            let bytes = match source {
+                ProgramSource::File(path) => std::fs::read(&path).unwrap_or_else(|err| {
+                    panic!(
+                        "Failed to read program {} from {}: {} (build it with `cargo build-sbf`)",
+                        program_id,
+                        path.display(),
+                        err
+                    )
+                }),
+                ProgramSource::Bytes(bytes) => bytes,
+            };
+            svm.add_program(program_id, &bytes)
+                .unwrap_or_else(|err| panic!("Failed to load program {}: {:?}", program_id, err));
+        }
+
+        if let Some(unix_timestamp) = self.unix_timestamp {
+            let mut clock = svm.get_sysvar::<Clock>();
+            clock.unix_timestamp = unix_timestamp;
+            svm.set_sysvar::<Clock>(&clock);
+        }
+
+        ProgramHarness {
+            svm,
+            airdrop_lamports: self.airdrop_lamports,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+// Soteria Test Kit
+//
+// LiteSVM boilerplate that every program's tests used to copy into utils.rs.
+// This crate provides:
+// 1. anchor_discriminator - Instruction sighash for hand-built Anchor instructions
+// 2. create_funded_account / advance_time / set_upgrade_authority - Account,
+//    clock and program setup
+// 3. send_ix / send_tx / send_tx_expect_* - Sign, send, and expire the blockhash
+// 4. assert_tx_ok! / assert_tx_err! - Assertions that print program logs on failure
+// 5. ProgramHarness - Builder that loads programs by path and funds accounts
+// 6. ExploitScenario / run_exploit - Structured exploit tests with JSON results
+// 7. DifferentialScenario / run_differential - One script against both builds,
+//    or against feature builds of the secure crate (run_feature_differential)
+// 8. ComputeBudget - Compute-unit regression checks against checked-in baselines
+// 9. ParityScenario / run_parity - One script against two implementations of a program
+// 10. errors / assert_tx_err_code! - Typed program error codes (soteria-errors)
+// 11. Scenario - Exploit scenarios loaded from JSON and replayed against any build
+//
+// Program-specific code (PDA derivation, instruction builders, account
+// readers, scenarios) stays in each program's tests/utils.rs.
+
+pub mod compute;
+pub mod differential;
+pub mod exploit;
+pub mod harness;
+pub mod macros;
+pub mod parity;
+pub mod scenario;
+pub mod svm;
+
+pub use compute::*;
+pub use differential::*;
+pub use exploit::*;
+pub use harness::*;
+pub use parity::*;
+pub use scenario::*;
+pub use svm::*;
+
+pub use soteria_errors as errors;
+pub use soteria_errors::ErrorCode;
@@ -0,0 +1,79 @@
+// Transaction Assertion Macros
+//
+// Both macros print the program logs before panicking, so a failing test
+// shows why the transaction behaved unexpectedly instead of a bare Err(..).
+
+// Assert a TransactionResult succeeded and return its TransactionMetadata
+//
+// assert_tx_ok!(result);
+// assert_tx_ok!(result, "Deposit should succeed");
+#[macro_export]
+macro_rules! assert_tx_ok {
+    ($result:expr) => {
+        $crate::assert_tx_ok!($result, "Transaction should succeed")
+    };
+    ($result:expr, $msg:expr) => {
+        match $result {
+            Ok(meta) => meta,
+            Err(failed) => {
+                for log in &failed.meta.logs {
+                    println!("  {}", log);
+                }
+                panic!("{}: {:?}", $msg, failed.err);
+            }
+        }
+    };
+}
+
+// Assert a TransactionResult failed and return its FailedTransactionMetadata
+// With an error name, also assert the name appears in the logs (Anchor logs
+// "Error Code: <Name>" for custom errors)
+//
+// assert_tx_err!(result);
+// assert_tx_err!(result, "Unauthorized");
+#[macro_export]
+macro_rules! assert_tx_err {
+    ($result:expr) => {
+        match $result {
+            Ok(meta) => {
+                for log in &meta.logs {
+                    println!("  {}", log);
+                }
+                panic!("Transaction should fail");
+            }
+            Err(failed) => failed,
+        }
+    };
+    ($result:expr, $error:expr) => {{
+        let failed = $crate::assert_tx_err!($result);
+        let found = failed.meta.logs.iter().any(|log| log.contains($error));
+        if !found {
+            for log in &failed.meta.logs {
+                println!("  {}", log);
+            }
+            panic!("Expected error {} but got {:?}", $error, failed.err);
+        }
+        failed
+    }};
+}
+
+// Assert a TransactionResult failed with a specific program error code and
+// return its FailedTransactionMetadata
+// Takes any soteria-errors enum, so a renumbered variant is caught here
+// instead of a stale string
+//
+// assert_tx_err_code!(result, MultisigErrorCode::NotAMember);
+#[macro_export]
+macro_rules! assert_tx_err_code {
+    ($result:expr, $code:expr) => {{
+        let failed = $crate::assert_tx_err!($result);
+        let expected = $code;
+        if $crate::custom_error_code(&failed) != Some($crate::ErrorCode::code(expected)) {
+            for log in &failed.meta.logs {
+                println!("  {}", log);
+            }
+            panic!("Expected error {} but got {:?}", expected, failed.err);
+        }
+        failed
+    }};
+}
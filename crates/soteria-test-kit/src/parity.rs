@@ -0,0 +1,144 @@
+// Parity Harness
+//
+// Replays the same scenario against several implementations of one program,
+// e.g. pino-escrow and its Anchor port:
+// 1. Every implementation is loaded into one LiteSVM under its own program ID
+// 2. setup, run and observe execute once per implementation
+// 3. All implementations must agree on whether run was accepted, and observe
+//    must return the same values for each of them
+//
+// Unlike DifferentialScenario, instruction layouts differ between
+// implementations, so the scenario is handed the implementation itself and
+// picks the matching instruction builders.
+
+use litesvm::{types::TransactionResult, LiteSVM};
+
+pub trait ParityScenario {
+    // Describes one implementation: program ID and instruction builders
+    type Implementation;
+
+    // Whatever run and observe need from setup: keypairs, addresses
+    type State;
+
+    fn name(&self) -> &'static str;
+
+    fn setup(&self, svm: &mut LiteSVM, implementation: &Self::Implementation) -> Self::State;
+
+    // The transaction(s) under test; the last result decides accepted/rejected
+    fn run(
+        &self,
+        svm: &mut LiteSVM,
+        implementation: &Self::Implementation,
+        state: &mut Self::State,
+    ) -> TransactionResult;
+
+    // Values that must match across implementations, e.g. token balances
+    fn observe(
+        &self,
+        svm: &LiteSVM,
+        implementation: &Self::Implementation,
+        state: &Self::State,
+    ) -> Vec<u64>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParityOutcome {
+    pub label: String,
+    // Some(error) when run was rejected
+    pub error: Option<String>,
+    pub observed: Vec<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParityResult {
+    pub scenario: String,
+    pub outcomes: Vec<ParityOutcome>,
+}
+
+impl ParityResult {
+    // Every implementation accepted, or every implementation rejected
+    pub fn same_verdict(&self) -> bool {
+        self.outcomes
+            .windows(2)
+            .all(|pair| pair[0].error.is_some() == pair[1].error.is_some())
+    }
+
+    pub fn same_observations(&self) -> bool {
+        self.outcomes
+            .windows(2)
+            .all(|pair| pair[0].observed == pair[1].observed)
+    }
+
+    pub fn passed(&self) -> bool {
+        self.same_verdict() && self.same_observations()
+    }
+
+    // Whether the (agreed) verdict was acceptance
+    pub fn accepted(&self) -> bool {
+        self.outcomes.iter().all(|outcome| outcome.error.is_none())
+    }
+}
+
+// Run a scenario on every implementation and panic unless they all agree
+pub fn run_parity<S: ParityScenario>(
+    svm: &mut LiteSVM,
+    implementations: &[(&str, S::Implementation)],
+    scenario: &S,
+) -> ParityResult {
+    let result = execute_parity(svm, implementations, scenario);
+
+    assert!(
+        result.same_verdict(),
+        "Parity {}: implementations disagree on the verdict: {:?}",
+        result.scenario,
+        result.outcomes
+    );
+    assert!(
+        result.same_observations(),
+        "Parity {}: implementations disagree on the observed state: {:?}",
+        result.scenario,
+        result.outcomes
+    );
+    result
+}
+
+// Run a scenario on every implementation and return the outcomes without panicking
+pub fn execute_parity<S: ParityScenario>(
+    svm: &mut LiteSVM,
+    implementations: &[(&str, S::Implementation)],
+    scenario: &S,
+) -> ParityResult {
+    println!("\n================================================================================");
+    println!("PARITY TEST: {}", scenario.name());
+    println!("================================================================================");
+
+    let outcomes = implementations
+        .iter()
+        .map(|(label, implementation)| {
+            let mut state = scenario.setup(svm, implementation);
+            let error = scenario
+                .run(svm, implementation, &mut state)
+                .err()
+                .map(|failed| format!("{:?}", failed.err));
+            let observed = scenario.observe(svm, implementation, &state);
+
+            match &error {
+                None => println!("[{}] Accepted, observed {:?}", label, observed),
+                Some(error) => println!("[{}] Rejected: {}, observed {:?}", label, error, observed),
+            }
+
+            ParityOutcome {
+                label: label.to_string(),
+                error,
+                observed,
+            }
+        })
+        .collect();
+
+    println!("================================================================================\n");
+
+    ParityResult {
+        scenario: scenario.name().to_string(),
+        outcomes,
+    }
+}
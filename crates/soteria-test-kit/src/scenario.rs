@@ -0,0 +1,493 @@
+// Scenario Replay
+//
+// Exploit scenarios written as JSON instead of Rust:
+// 1. keypairs - Named accounts created and funded with an airdrop
+// 2. pdas - Named addresses derived from seeds and the program under test
+// 3. setup - Ordered airdrops, clock moves and transactions building the honest state
+// 4. exploit - The attack transaction (must succeed on a vulnerable build)
+// 5. impact - Checks on balances and account data after the attack
+//
+// The file never names a program ID. Instructions default to "program", which
+// is bound when the scenario is replayed, so one file runs against any build
+// whose instruction layout matches: run_exploit via Scenario::replay, or both
+// builds of a pair via run_differential.
+//
+// Account names resolve to keypairs, then PDAs, then "program" and
+// "system_program", then a literal base58 address.
+//
+// Values (instruction data and seeds) are single-key objects:
+//   {"anchor": "toggle_pause"}      8-byte Anchor discriminator
+//   {"u8": 1} {"u16": 1} {"u32": 1} {"u64": 1} {"i64": -1} {"bool": true}
+//   {"pubkey": "creator"}           32 bytes of a named account
+//   {"string": "alice"}             Borsh string (u32 length + bytes)
+//   {"utf8": "vault"}               raw bytes, e.g. a PDA seed
+//   {"bytes": [1, 2]}               raw bytes
+//   {"vec": [...]}                  Borsh Vec (u32 length + items)
+//   {"tuple": [...]}                items back to back, e.g. one Vec<(Pubkey, u8)> item
+//   {"option": null} / {"option": {"u64": 1}}
+
+use litesvm::{types::TransactionResult, LiteSVM};
+use serde::Deserialize;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use std::{collections::BTreeMap, fs, path::PathBuf, str::FromStr};
+
+use crate::{
+    svm, DifferentialScenario, ExploitInfo, ExploitScenario, ProgramHarness, Severity,
+};
+
+// Name instructions use for the program being replayed against
+pub const SCENARIO_PROGRAM: &str = "program";
+pub const SCENARIO_SYSTEM_PROGRAM: &str = "system_program";
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    pub id: String,
+    pub title: String,
+    // "low", "medium", "high" or "critical"
+    pub severity: String,
+    pub lesson: String,
+    #[serde(default)]
+    pub keypairs: Vec<ScenarioKeypair>,
+    #[serde(default)]
+    pub pdas: Vec<ScenarioPda>,
+    #[serde(default)]
+    pub setup: Vec<ScenarioStep>,
+    pub exploit: ScenarioTransaction,
+    #[serde(default)]
+    pub impact: Vec<ScenarioCheck>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioKeypair {
+    pub name: String,
+    #[serde(default)]
+    pub lamports: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioPda {
+    pub name: String,
+    pub seeds: Vec<ScenarioValue>,
+    // Program the PDA belongs to, "program" by default
+    #[serde(default = "default_program")]
+    pub program: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum ScenarioStep {
+    Airdrop { account: String, lamports: u64 },
+    AdvanceTime(u64),
+    Send(ScenarioTransaction),
+}
+
+// Signed by the payer and every keypair an instruction marks as signer
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioTransaction {
+    pub payer: String,
+    pub instructions: Vec<ScenarioInstruction>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioInstruction {
+    #[serde(default = "default_program")]
+    pub program: String,
+    pub accounts: Vec<ScenarioAccountMeta>,
+    #[serde(default)]
+    pub data: Vec<ScenarioValue>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioAccountMeta {
+    pub account: String,
+    #[serde(default)]
+    pub signer: bool,
+    #[serde(default)]
+    pub writable: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum ScenarioValue {
+    Anchor(String),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    I64(i64),
+    Bool(bool),
+    Pubkey(String),
+    String(String),
+    Utf8(String),
+    Bytes(Vec<u8>),
+    Vec(Vec<ScenarioValue>),
+    Tuple(Vec<ScenarioValue>),
+    Option(Option<Box<ScenarioValue>>),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "check", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ScenarioCheck {
+    // Lamports gained (positive) or lost (negative) across the exploit
+    // With funds_at_risk the size of the change counts toward the reported funds at risk
+    BalanceChange {
+        account: String,
+        delta: i64,
+        #[serde(default)]
+        funds_at_risk: bool,
+    },
+    // Account data at offset starts with the encoded values
+    AccountData {
+        account: String,
+        offset: usize,
+        equals: Vec<ScenarioValue>,
+    },
+    // Account no longer exists (closed or never funded)
+    Closed { account: String },
+}
+
+fn default_program() -> String {
+    SCENARIO_PROGRAM.to_string()
+}
+
+// Keypairs and addresses of one replay, plus lamports snapshotted before the exploit
+pub struct ScenarioAccounts {
+    pub program_id: Pubkey,
+    pub keypairs: BTreeMap<String, Keypair>,
+    pub pdas: BTreeMap<String, Pubkey>,
+    balances_before: BTreeMap<Pubkey, u64>,
+}
+
+impl ScenarioAccounts {
+    pub fn keypair(&self, name: &str) -> &Keypair {
+        self.keypairs
+            .get(name)
+            .unwrap_or_else(|| panic!("Scenario has no keypair named {:?}", name))
+    }
+
+    pub fn address(&self, name: &str) -> Pubkey {
+        if let Some(keypair) = self.keypairs.get(name) {
+            return keypair.pubkey();
+        }
+        if let Some(pda) = self.pdas.get(name) {
+            return *pda;
+        }
+        match name {
+            SCENARIO_PROGRAM => self.program_id,
+            // The system program's ID is all zeros
+            SCENARIO_SYSTEM_PROGRAM => Pubkey::default(),
+            _ => Pubkey::from_str(name)
+                .unwrap_or_else(|_| panic!("Scenario has no account named {:?}", name)),
+        }
+    }
+
+    pub fn encode(&self, values: &[ScenarioValue]) -> Vec<u8> {
+        let mut out = Vec::new();
+        for value in values {
+            self.encode_value(value, &mut out);
+        }
+        out
+    }
+
+    fn encode_value(&self, value: &ScenarioValue, out: &mut Vec<u8>) {
+        match value {
+            ScenarioValue::Anchor(method) => out.extend_from_slice(&svm::anchor_discriminator(method)),
+            ScenarioValue::U8(v) => out.push(*v),
+            ScenarioValue::U16(v) => out.extend_from_slice(&v.to_le_bytes()),
+            ScenarioValue::U32(v) => out.extend_from_slice(&v.to_le_bytes()),
+            ScenarioValue::U64(v) => out.extend_from_slice(&v.to_le_bytes()),
+            ScenarioValue::I64(v) => out.extend_from_slice(&v.to_le_bytes()),
+            ScenarioValue::Bool(v) => out.push(*v as u8),
+            ScenarioValue::Pubkey(name) => out.extend_from_slice(self.address(name).as_ref()),
+            ScenarioValue::String(s) => {
+                out.extend_from_slice(&(s.len() as u32).to_le_bytes());
+                out.extend_from_slice(s.as_bytes());
+            }
+            ScenarioValue::Utf8(s) => out.extend_from_slice(s.as_bytes()),
+            ScenarioValue::Bytes(bytes) => out.extend_from_slice(bytes),
+            ScenarioValue::Vec(items) => {
+                out.extend_from_slice(&(items.len() as u32).to_le_bytes());
+                for item in items {
+                    self.encode_value(item, out);
+                }
+            }
+            ScenarioValue::Tuple(items) => {
+                for item in items {
+                    self.encode_value(item, out);
+                }
+            }
+            ScenarioValue::Option(None) => out.push(0),
+            ScenarioValue::Option(Some(inner)) => {
+                out.push(1);
+                self.encode_value(inner, out);
+            }
+        }
+    }
+
+    pub fn instruction(&self, spec: &ScenarioInstruction) -> Instruction {
+        let accounts = spec
+            .accounts
+            .iter()
+            .map(|meta| {
+                let pubkey = self.address(&meta.account);
+                if meta.writable {
+                    AccountMeta::new(pubkey, meta.signer)
+                } else {
+                    AccountMeta::new_readonly(pubkey, meta.signer)
+                }
+            })
+            .collect();
+        Instruction {
+            program_id: self.address(&spec.program),
+            accounts,
+            data: self.encode(&spec.data),
+        }
+    }
+
+    pub fn send(&self, svm: &mut LiteSVM, tx: &ScenarioTransaction) -> TransactionResult {
+        let ixs: Vec<Instruction> = tx.instructions.iter().map(|ix| self.instruction(ix)).collect();
+
+        let payer = self.keypair(&tx.payer);
+        let mut signers = vec![payer];
+        for meta in tx.instructions.iter().flat_map(|ix| &ix.accounts) {
+            if !meta.signer || meta.account == tx.payer {
+                continue;
+            }
+            let keypair = self.keypairs.get(&meta.account).unwrap_or_else(|| {
+                panic!("{:?} is marked signer but is not a scenario keypair", meta.account)
+            });
+            if !signers.iter().any(|signer| signer.pubkey() == keypair.pubkey()) {
+                signers.push(keypair);
+            }
+        }
+
+        svm::send_tx(svm, &ixs, payer, &signers)
+    }
+}
+
+impl Scenario {
+    // Read a scenario from a path relative to the package root
+    pub fn load(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let contents = fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("Failed to read scenario {}: {}", path.display(), err));
+        Self::from_json(&contents)
+            .unwrap_or_else(|err| panic!("Invalid scenario {}: {}", path.display(), err))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let scenario: Scenario = serde_json::from_str(json).map_err(|err| err.to_string())?;
+        scenario.parse_severity()?;
+        Ok(scenario)
+    }
+
+    fn parse_severity(&self) -> Result<Severity, String> {
+        match self.severity.as_str() {
+            "low" => Ok(Severity::Low),
+            "medium" => Ok(Severity::Medium),
+            "high" => Ok(Severity::High),
+            "critical" => Ok(Severity::Critical),
+            other => Err(format!("unknown severity {:?}", other)),
+        }
+    }
+
+    // Replay against a build loaded from a path relative to the package root
+    pub fn replay(&self, program_id: Pubkey, program_path: impl Into<PathBuf>) -> ScenarioReplay<'_> {
+        ScenarioReplay {
+            scenario: self,
+            program_id,
+            program_path: program_path.into(),
+        }
+    }
+
+    // Fund the keypairs, derive the PDAs and run the setup steps on `program_id`
+    // A setup transaction that fails panics with its logs
+    pub fn setup_on(&self, svm: &mut LiteSVM, program_id: &Pubkey) -> ScenarioAccounts {
+        let mut accounts = ScenarioAccounts {
+            program_id: *program_id,
+            keypairs: BTreeMap::new(),
+            pdas: BTreeMap::new(),
+            balances_before: BTreeMap::new(),
+        };
+
+        for spec in &self.keypairs {
+            let keypair = if spec.lamports > 0 {
+                svm::create_funded_account(svm, spec.lamports)
+            } else {
+                Keypair::new()
+            };
+            accounts.keypairs.insert(spec.name.clone(), keypair);
+        }
+
+        // In order, so a PDA's seeds can name an earlier PDA
+        for spec in &self.pdas {
+            let seeds: Vec<Vec<u8>> = spec
+                .seeds
+                .iter()
+                .map(|seed| accounts.encode(std::slice::from_ref(seed)))
+                .collect();
+            let seed_refs: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+            let (pda, _) = Pubkey::find_program_address(&seed_refs, &accounts.address(&spec.program));
+            accounts.pdas.insert(spec.name.clone(), pda);
+        }
+
+        for (index, step) in self.setup.iter().enumerate() {
+            match step {
+                ScenarioStep::Airdrop { account, lamports } => {
+                    svm.airdrop(&accounts.address(account), *lamports)
+                        .expect("Airdrop should succeed");
+                }
+                ScenarioStep::AdvanceTime(seconds) => svm::advance_time(svm, *seconds),
+                ScenarioStep::Send(tx) => {
+                    crate::assert_tx_ok!(
+                        accounts.send(svm, tx),
+                        format!("Setup step {} should succeed", index)
+                    );
+                }
+            }
+        }
+
+        accounts
+    }
+
+    // Snapshot the balances the impact checks compare against, then send the exploit
+    pub fn exploit_on(&self, svm: &mut LiteSVM, accounts: &mut ScenarioAccounts) -> TransactionResult {
+        for check in &self.impact {
+            if let ScenarioCheck::BalanceChange { account, .. } = check {
+                let address = accounts.address(account);
+                let balance = svm.get_balance(&address).unwrap_or(0);
+                accounts.balances_before.insert(address, balance);
+            }
+        }
+        accounts.send(svm, &self.exploit)
+    }
+
+    // Assert every impact check and return the funds at risk
+    pub fn check_impact(&self, svm: &LiteSVM, accounts: &ScenarioAccounts) -> u64 {
+        let mut funds_at_risk = 0u64;
+        for check in &self.impact {
+            match check {
+                ScenarioCheck::BalanceChange {
+                    account,
+                    delta,
+                    funds_at_risk: at_risk,
+                } => {
+                    let address = accounts.address(account);
+                    let before = accounts.balances_before[&address] as i128;
+                    let after = svm.get_balance(&address).unwrap_or(0) as i128;
+                    assert_eq!(
+                        after - before,
+                        *delta as i128,
+                        "Balance change of {}",
+                        account
+                    );
+                    if *at_risk {
+                        funds_at_risk += delta.unsigned_abs();
+                    }
+                }
+                ScenarioCheck::AccountData {
+                    account,
+                    offset,
+                    equals,
+                } => {
+                    let data = svm
+                        .get_account(&accounts.address(account))
+                        .unwrap_or_else(|| panic!("Account {} should exist", account))
+                        .data;
+                    let expected = accounts.encode(equals);
+                    assert_eq!(
+                        data.get(*offset..*offset + expected.len()),
+                        Some(expected.as_slice()),
+                        "Data of {} at offset {}",
+                        account,
+                        offset
+                    );
+                }
+                ScenarioCheck::Closed { account } => {
+                    let lamports = svm.get_balance(&accounts.address(account)).unwrap_or(0);
+                    assert_eq!(lamports, 0, "Account {} should be closed", account);
+                }
+            }
+        }
+        funds_at_risk
+    }
+
+    // ExploitInfo holds &'static str; a test process loads a handful of
+    // scenarios, so their strings are leaked rather than borrowed
+    pub fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: Box::leak(self.id.clone().into_boxed_str()),
+            title: Box::leak(self.title.clone().into_boxed_str()),
+            severity: self.parse_severity().expect("Severity is checked on load"),
+            lesson: Box::leak(self.lesson.clone().into_boxed_str()),
+        }
+    }
+}
+
+// A scenario bound to one program build, for run_exploit
+pub struct ScenarioReplay<'a> {
+    scenario: &'a Scenario,
+    program_id: Pubkey,
+    program_path: PathBuf,
+}
+
+pub struct ScenarioState {
+    pub svm: LiteSVM,
+    pub accounts: ScenarioAccounts,
+}
+
+impl ExploitScenario for ScenarioReplay<'_> {
+    type State = ScenarioState;
+
+    fn info(&self) -> ExploitInfo {
+        self.scenario.info()
+    }
+
+    fn setup(&self) -> ScenarioState {
+        let mut svm = ProgramHarness::builder()
+            .program_file(self.program_id, self.program_path.clone())
+            .build()
+            .into_svm();
+        let accounts = self.scenario.setup_on(&mut svm, &self.program_id);
+        ScenarioState { svm, accounts }
+    }
+
+    fn exploit(&self, state: &mut ScenarioState) -> TransactionResult {
+        self.scenario.exploit_on(&mut state.svm, &mut state.accounts)
+    }
+
+    fn assert_impact(&self, state: &mut ScenarioState) -> u64 {
+        self.scenario.check_impact(&state.svm, &state.accounts)
+    }
+}
+
+impl DifferentialScenario for Scenario {
+    type State = ScenarioAccounts;
+
+    fn info(&self) -> ExploitInfo {
+        Scenario::info(self)
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, program_id: &Pubkey) -> ScenarioAccounts {
+        self.setup_on(svm, program_id)
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        accounts: &mut ScenarioAccounts,
+    ) -> TransactionResult {
+        self.exploit_on(svm, accounts)
+    }
+}
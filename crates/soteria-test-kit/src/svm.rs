@@ -0,0 +1,156 @@
+// LiteSVM Helpers
+//
+// Free functions over a LiteSVM instance. ProgramHarness wraps the same
+// functions for tests that prefer to keep the SVM and defaults together.
+
+use litesvm::{
+    types::{FailedTransactionMetadata, TransactionMetadata, TransactionResult},
+    LiteSVM,
+};
+use sha2::{Digest, Sha256};
+use solana_sdk::{
+    account::Account,
+    clock::Clock,
+    instruction::{Instruction, InstructionError},
+    pubkey,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+// Upgradeable loader (loader v3) and its account layouts:
+// Program     = u32 tag 2, programdata address
+// ProgramData = u32 tag 3, u64 slot, Option<Pubkey> authority, then the ELF at 45
+const BPF_LOADER_UPGRADEABLE_ID: Pubkey =
+    pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+const PROGRAM_STATE_TAG: u32 = 2;
+const PROGRAMDATA_STATE_TAG: u32 = 3;
+const PROGRAMDATA_METADATA_LEN: usize = 45;
+
+// Build Anchor instruction discriminator
+// Formula: first 8 bytes of sha256("global:method_name")
+pub fn anchor_discriminator(method: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", method);
+    let hash = Sha256::digest(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash[..8]);
+    discriminator
+}
+
+// Create and fund account
+pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
+    let keypair = Keypair::new();
+    svm.airdrop(&keypair.pubkey(), lamports)
+        .expect("Airdrop should succeed");
+    keypair
+}
+
+// Redeploy a loaded program under the upgradeable loader with `authority` as
+// its upgrade authority, and return its ProgramData address
+// LiteSVM::add_program uses the non-upgradeable loader, which has no
+// ProgramData, so instructions gated on the upgrade authority need this first
+pub fn set_upgrade_authority(svm: &mut LiteSVM, program_id: &Pubkey, authority: &Pubkey) -> Pubkey {
+    let elf = svm
+        .get_account(program_id)
+        .expect("Program should be loaded before setting its upgrade authority")
+        .data;
+    let (program_data, _) =
+        Pubkey::find_program_address(&[program_id.as_ref()], &BPF_LOADER_UPGRADEABLE_ID);
+
+    let mut data = Vec::with_capacity(PROGRAMDATA_METADATA_LEN + elf.len());
+    data.extend_from_slice(&PROGRAMDATA_STATE_TAG.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.push(1);
+    data.extend_from_slice(authority.as_ref());
+    data.extend_from_slice(&elf);
+    let programdata_account = Account {
+        lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+        data,
+        owner: BPF_LOADER_UPGRADEABLE_ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    svm.set_account(program_data, programdata_account)
+        .expect("Failed to write ProgramData");
+
+    let mut data = PROGRAM_STATE_TAG.to_le_bytes().to_vec();
+    data.extend_from_slice(program_data.as_ref());
+    let program_account = Account {
+        lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+        data,
+        owner: BPF_LOADER_UPGRADEABLE_ID,
+        executable: true,
+        rent_epoch: 0,
+    };
+    svm.set_account(*program_id, program_account)
+        .expect("Failed to redeploy program as upgradeable");
+
+    program_data
+}
+
+// Move the clock forward by `seconds`
+// LiteSVM never advances time on its own, so both the timestamp and the slot
+// are moved (at ~400ms per slot): programs reading either see time pass
+pub fn advance_time(svm: &mut LiteSVM, seconds: u64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += seconds as i64;
+    svm.set_sysvar::<Clock>(&clock);
+    svm.warp_to_slot(clock.slot + seconds * 5 / 2 + 1);
+}
+
+// Sign and send a single instruction with one signer as payer
+pub fn send_ix(svm: &mut LiteSVM, ix: Instruction, signer: &Keypair) -> TransactionResult {
+    send_tx(svm, &[ix], signer, &[signer])
+}
+
+// Sign and send a transaction
+// The blockhash is expired afterwards so an identical transaction sent next
+// gets a new signature instead of failing as AlreadyProcessed
+pub fn send_tx(
+    svm: &mut LiteSVM,
+    ixs: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
+) -> TransactionResult {
+    let tx = Transaction::new_signed_with_payer(
+        ixs,
+        Some(&payer.pubkey()),
+        signers,
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    svm.expire_blockhash();
+    result
+}
+
+// Send a transaction and expect success
+pub fn send_tx_expect_success(
+    svm: &mut LiteSVM,
+    ix: Instruction,
+    payer: &Keypair,
+    signers: &[&Keypair],
+) -> TransactionMetadata {
+    crate::assert_tx_ok!(send_tx(svm, &[ix], payer, signers))
+}
+
+// Send a transaction and expect failure
+// Returns the debug-formatted failure (error and logs) for matching error names
+pub fn send_tx_expect_failure(
+    svm: &mut LiteSVM,
+    ix: Instruction,
+    payer: &Keypair,
+    signers: &[&Keypair],
+) -> String {
+    let failed: FailedTransactionMetadata =
+        crate::assert_tx_err!(send_tx(svm, &[ix], payer, signers));
+    format!("{:?}", failed)
+}
+
+// Custom error code of a failed transaction, if an instruction returned one
+// Anchor #[error_code] variants and Pinocchio ProgramError::Custom both land here
+pub fn custom_error_code(failed: &FailedTransactionMetadata) -> Option<u32> {
+    match failed.err {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => Some(code),
+        _ => None,
+    }
+}
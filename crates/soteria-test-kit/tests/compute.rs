@@ -0,0 +1,117 @@
+use litesvm::LiteSVM;
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::Signer};
+use solana_system_interface::instruction as system_instruction;
+use soteria_test_kit::*;
+use std::{fs, path::PathBuf};
+
+// Fresh baselines path per test, so parallel tests never share a file
+fn baselines_path(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("soteria-compute-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(format!("{}.txt", test));
+    let _ = fs::remove_file(&path);
+    path
+}
+
+// Record one system transfer and return its compute units
+fn record_transfer(budget: &mut ComputeBudget) -> u64 {
+    let mut svm = LiteSVM::new();
+    let payer = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+    let ix = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1_000);
+    budget.record("transfer", send_ix(&mut svm, ix, &payer)).compute_units_consumed
+}
+
+#[test]
+fn test_parse_baselines() {
+    let baselines = parse_baselines("# header\ndeposit = 1200\n\n  swap=3400  # inline\n").unwrap();
+
+    assert_eq!(baselines.len(), 2);
+    assert_eq!(baselines["deposit"], 1200);
+    assert_eq!(baselines["swap"], 3400);
+}
+
+#[test]
+fn test_parse_baselines_rejects_bad_lines() {
+    assert!(parse_baselines("deposit 1200").is_err());
+    assert!(parse_baselines("deposit = lots").is_err());
+    assert!(parse_baselines("deposit = 1\ndeposit = 2").is_err());
+}
+
+#[test]
+fn test_within_tolerance_passes() {
+    let path = baselines_path("within");
+    let measured = {
+        let mut budget = ComputeBudget::load(&path).update(false);
+        record_transfer(&mut budget)
+    };
+    // Baseline 5% below the measurement, tolerance 10%
+    fs::write(&path, format!("transfer = {}\n", measured * 100 / 105)).unwrap();
+
+    let mut budget = ComputeBudget::load(&path).tolerance_pct(10).update(false);
+    record_transfer(&mut budget);
+
+    assert!(budget.regressions().is_empty());
+    budget.check();
+}
+
+#[test]
+fn test_regression_is_reported() {
+    let path = baselines_path("regression");
+    fs::write(&path, "transfer = 1\n").unwrap();
+
+    let mut budget = ComputeBudget::load(&path).tolerance_pct(5).update(false);
+    let measured = record_transfer(&mut budget);
+
+    let regressions = budget.regressions();
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0].name, "transfer");
+    assert_eq!(regressions[0].baseline, 1);
+    assert_eq!(regressions[0].measured, measured);
+    assert_eq!(regressions[0].increase_pct(), (measured - 1) * 100);
+}
+
+#[test]
+#[should_panic(expected = "Compute units regressed more than 5%")]
+fn test_check_panics_on_regression() {
+    let path = baselines_path("panics");
+    fs::write(&path, "transfer = 1\n").unwrap();
+
+    let mut budget = ComputeBudget::load(&path).tolerance_pct(5).update(false);
+    record_transfer(&mut budget);
+    budget.check();
+}
+
+#[test]
+fn test_missing_baseline_is_not_a_regression() {
+    let path = baselines_path("missing");
+
+    let mut budget = ComputeBudget::load(&path).update(false);
+    record_transfer(&mut budget);
+
+    assert_eq!(budget.baseline("transfer"), None);
+    assert!(budget.regressions().is_empty());
+    budget.check();
+    assert!(!path.exists(), "Check alone should not write baselines");
+}
+
+#[test]
+fn test_update_writes_baselines() {
+    let path = baselines_path("update");
+    fs::write(&path, "transfer = 1\nremoved = 10\n").unwrap();
+
+    let mut budget = ComputeBudget::load(&path).update(true);
+    let measured = record_transfer(&mut budget);
+    budget.check();
+
+    let written = parse_baselines(&fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(written.len(), 1);
+    assert_eq!(written["transfer"], measured);
+}
+
+#[test]
+#[should_panic(expected = "recorded twice")]
+fn test_record_rejects_duplicate_names() {
+    let mut budget = ComputeBudget::load(baselines_path("duplicate")).update(false);
+    record_transfer(&mut budget);
+    record_transfer(&mut budget);
+}
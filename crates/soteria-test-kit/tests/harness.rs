@@ -0,0 +1,117 @@
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::Signer};
+use solana_system_interface::instruction as system_instruction;
+use soteria_test_kit::*;
+
+#[test]
+fn test_funded_account_uses_default_airdrop() {
+    let mut harness = ProgramHarness::builder().build();
+
+    let account = harness.funded_account();
+
+    assert_eq!(
+        harness.svm.get_balance(&account.pubkey()),
+        Some(DEFAULT_AIRDROP_LAMPORTS)
+    );
+}
+
+#[test]
+fn test_funded_account_respects_configured_airdrop() {
+    let mut harness = ProgramHarness::builder()
+        .airdrop_lamports(LAMPORTS_PER_SOL)
+        .build();
+
+    let account = harness.funded_account();
+    let other = harness.funded_account_with(3 * LAMPORTS_PER_SOL);
+
+    assert_eq!(harness.svm.get_balance(&account.pubkey()), Some(LAMPORTS_PER_SOL));
+    assert_eq!(harness.svm.get_balance(&other.pubkey()), Some(3 * LAMPORTS_PER_SOL));
+}
+
+#[test]
+fn test_unix_timestamp_and_advance_time() {
+    let mut harness = ProgramHarness::builder().unix_timestamp(1_000).build();
+    assert_eq!(harness.now(), 1_000);
+
+    harness.advance_time(3_600);
+
+    assert_eq!(harness.now(), 4_600);
+}
+
+#[test]
+#[should_panic(expected = "cargo build-sbf")]
+fn test_missing_program_file_panics_with_build_hint() {
+    ProgramHarness::builder()
+        .program_file(Pubkey::new_unique(), "target/deploy/does_not_exist.so")
+        .build();
+}
+
+#[test]
+fn test_identical_transactions_both_land() {
+    let mut harness = ProgramHarness::builder().build();
+    let payer = harness.funded_account();
+    let recipient = Pubkey::new_unique();
+
+    // Same instruction twice: send_ix expires the blockhash in between, so the
+    // second transaction has a new signature and isn't AlreadyProcessed
+    for _ in 0..2 {
+        let ix = system_instruction::transfer(&payer.pubkey(), &recipient, LAMPORTS_PER_SOL);
+        assert_tx_ok!(harness.send_ix(ix, &payer));
+    }
+
+    assert_eq!(harness.svm.get_balance(&recipient), Some(2 * LAMPORTS_PER_SOL));
+}
+
+#[test]
+fn test_assert_tx_err_matches_logs() {
+    let mut harness = ProgramHarness::builder().build();
+    let payer = harness.funded_account_with(LAMPORTS_PER_SOL);
+    let recipient = Pubkey::new_unique();
+
+    let ix = system_instruction::transfer(&payer.pubkey(), &recipient, 5 * LAMPORTS_PER_SOL);
+    assert_tx_err!(harness.send_ix(ix, &payer), "insufficient lamports");
+}
+
+// System program errors are numbered from 0, like a Pinocchio program's
+soteria_test_kit::errors::error_codes! {
+    pub enum SystemError {
+        AccountAlreadyInUse = 0,
+        ResultWithNegativeLamports = 1,
+    }
+}
+
+#[test]
+fn test_assert_tx_err_code_matches_custom_error() {
+    let mut harness = ProgramHarness::builder().build();
+    let payer = harness.funded_account_with(LAMPORTS_PER_SOL);
+    let recipient = Pubkey::new_unique();
+
+    let ix = system_instruction::transfer(&payer.pubkey(), &recipient, 5 * LAMPORTS_PER_SOL);
+    let failed = assert_tx_err_code!(
+        harness.send_ix(ix, &payer),
+        SystemError::ResultWithNegativeLamports
+    );
+    assert_eq!(custom_error_code(&failed), Some(1));
+}
+
+#[test]
+fn test_send_tx_expect_helpers() {
+    let mut harness = ProgramHarness::builder().build();
+    let payer = harness.funded_account();
+    let recipient = Pubkey::new_unique();
+
+    let ix = system_instruction::transfer(&payer.pubkey(), &recipient, LAMPORTS_PER_SOL);
+    send_tx_expect_success(&mut harness.svm, ix, &payer, &[&payer]);
+
+    let ix = system_instruction::transfer(&payer.pubkey(), &recipient, 100 * LAMPORTS_PER_SOL);
+    let error = send_tx_expect_failure(&mut harness.svm, ix, &payer, &[&payer]);
+    assert!(error.contains("insufficient lamports"));
+}
+
+#[test]
+fn test_anchor_discriminator_matches_sighash() {
+    // sha256("global:initialize")[..8], the discriminator Anchor generates
+    assert_eq!(
+        anchor_discriminator("initialize"),
+        [175, 175, 109, 31, 13, 152, 155, 237]
+    );
+}
@@ -0,0 +1,84 @@
+use litesvm::{types::TransactionResult, LiteSVM};
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::Keypair, signature::Signer};
+use solana_system_interface::instruction as system_instruction;
+use soteria_test_kit::*;
+
+// Stand-in implementations: no programs are loaded, each "implementation" is
+// just the amount it transfers out of a 1 SOL account
+struct TransferScenario;
+
+struct TransferState {
+    sender: Keypair,
+    recipient: Pubkey,
+}
+
+impl ParityScenario for TransferScenario {
+    type Implementation = u64;
+    type State = TransferState;
+
+    fn name(&self) -> &'static str {
+        "Test transfer"
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, _amount: &u64) -> TransferState {
+        TransferState {
+            sender: create_funded_account(svm, LAMPORTS_PER_SOL),
+            recipient: Pubkey::new_unique(),
+        }
+    }
+
+    fn run(&self, svm: &mut LiteSVM, amount: &u64, state: &mut TransferState) -> TransactionResult {
+        let ix = system_instruction::transfer(&state.sender.pubkey(), &state.recipient, *amount);
+        send_ix(svm, ix, &state.sender)
+    }
+
+    fn observe(&self, svm: &LiteSVM, _amount: &u64, state: &TransferState) -> Vec<u64> {
+        vec![svm.get_balance(&state.recipient).unwrap_or_default()]
+    }
+}
+
+#[test]
+fn test_run_parity_passes_when_implementations_agree() {
+    let mut svm = LiteSVM::new();
+    let implementations = [("first", LAMPORTS_PER_SOL / 2), ("second", LAMPORTS_PER_SOL / 2)];
+
+    let result = run_parity(&mut svm, &implementations, &TransferScenario);
+
+    assert!(result.passed());
+    assert!(result.accepted());
+    assert_eq!(result.outcomes.len(), 2);
+    assert_eq!(result.outcomes[0].observed, vec![LAMPORTS_PER_SOL / 2]);
+}
+
+#[test]
+fn test_run_parity_passes_when_all_reject() {
+    let mut svm = LiteSVM::new();
+    let implementations = [("first", 2 * LAMPORTS_PER_SOL), ("second", 2 * LAMPORTS_PER_SOL)];
+
+    let result = run_parity(&mut svm, &implementations, &TransferScenario);
+
+    assert!(result.passed());
+    assert!(!result.accepted());
+    assert!(result.outcomes.iter().all(|outcome| outcome.error.is_some()));
+}
+
+#[test]
+fn test_execute_parity_reports_verdict_mismatch() {
+    let mut svm = LiteSVM::new();
+    let implementations = [("first", LAMPORTS_PER_SOL / 2), ("second", 2 * LAMPORTS_PER_SOL)];
+
+    let result = execute_parity(&mut svm, &implementations, &TransferScenario);
+
+    assert!(!result.same_verdict());
+    assert!(!result.passed());
+    assert_eq!(result.outcomes[1].label, "second");
+}
+
+#[test]
+#[should_panic(expected = "disagree on the observed state")]
+fn test_run_parity_panics_on_observation_mismatch() {
+    let mut svm = LiteSVM::new();
+    let implementations = [("first", LAMPORTS_PER_SOL / 2), ("second", LAMPORTS_PER_SOL / 4)];
+
+    run_parity(&mut svm, &implementations, &TransferScenario);
+}
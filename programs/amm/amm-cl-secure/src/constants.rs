@@ -0,0 +1,33 @@
+// Seed for pool PDA
+// Derived with: [POOL_SEED, token_a_mint_pubkey, token_b_mint_pubkey]
+pub const POOL_SEED: &[u8] = b"pool";
+
+// Seed for tick PDA
+// Derived with: [TICK_SEED, pool_pubkey, tick_index (i32 LE)]
+pub const TICK_SEED: &[u8] = b"tick";
+
+// Seed for position PDA
+// Derived with: [POSITION_SEED, pool_pubkey, owner_pubkey, lower_tick (i32 LE), upper_tick (i32 LE)]
+pub const POSITION_SEED: &[u8] = b"position";
+
+// Seed for tick slab PDA
+// Derived with: [TICK_SLAB_SEED, pool_pubkey]
+pub const TICK_SLAB_SEED: &[u8] = b"tick_slab";
+
+// TICK LIMITS
+
+// Tick i has price 1.0001^i, so the range covers prices ~1/22000 .. ~22000
+pub const MIN_TICK: i32 = -100_000;
+pub const MAX_TICK: i32 = 100_000;
+
+// Widest allowed tick spacing
+pub const MAX_TICK_SPACING: u16 = 1_000;
+
+// Initialized ticks tracked per pool (two per distinct position range edge)
+// The tick slab is created with the initial capacity and grown by realloc
+// (grow_tick_slab) one step at a time, never past the maximum
+pub const TICK_SLAB_INITIAL_CAPACITY: usize = 32;
+pub const TICK_SLAB_GROWTH_STEP: usize = 32;
+pub const MAX_INITIALIZED_TICKS: usize = 1_024;
+
+pub const ANCHOR_DISCRIMINATOR: usize = 8;
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+pub use soteria_errors::amm_cl_secure::AmmClError as AmmClErrorCode;
+
+#[error_code]
+pub enum AmmClError {
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Liquidity must be greater than zero")]
+    ZeroLiquidity,
+
+    #[msg("Token A and token B mints must be different")]
+    IdenticalMints,
+
+    #[msg("Tick spacing must be between 1 and MAX_TICK_SPACING")]
+    InvalidTickSpacing,
+
+    #[msg("Tick is outside MIN_TICK..=MAX_TICK")]
+    TickOutOfBounds,
+
+    #[msg("Tick is not a multiple of the pool's tick spacing")]
+    TickNotAligned,
+
+    #[msg("Lower tick must be below upper tick")]
+    InvalidTickRange,
+
+    #[msg("Pool has no room for another initialized tick")]
+    TooManyTicks,
+
+    #[msg("Swap crosses a tick whose account was not supplied")]
+    MissingTickAccount,
+
+    #[msg("Tick account is not the next initialized tick of this pool")]
+    InvalidTickAccount,
+
+    #[msg("Swap produced no output: no liquidity in the swap direction")]
+    InsufficientLiquidity,
+
+    #[msg("Output amount below minimum")]
+    SlippageExceeded,
+
+    #[msg("Unauthorized: Cannot perform this action")]
+    Unauthorized,
+
+    #[msg("Arithmetic overflow")]
+    Overflow,
+
+    #[msg("Arithmetic underflow")]
+    Underflow,
+}
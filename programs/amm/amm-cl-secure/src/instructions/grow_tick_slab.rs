@@ -0,0 +1,71 @@
+// Grow Tick Slab Instruction
+//
+// Adds TICK_SLAB_GROWTH_STEP zeroed slots to a pool's tick slab, so more
+// position edges can be initialized. The payer tops up rent for the new size,
+// then the account is resized.
+//
+// SECURITY:
+// - Anyone may grow a slab: it only adds empty slots, paid for by the caller
+// - Capacity never exceeds MAX_INITIALIZED_TICKS
+// - New slots are zeroed by the runtime and sit past tick_count, so they are
+//   never read as initialized ticks
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct GrowTickSlab<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [TICK_SLAB_SEED, tick_slab.load()?.pool.as_ref()],
+        bump = tick_slab.load()?.bump,
+    )]
+    pub tick_slab: AccountLoader<'info, TickSlab>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> GrowTickSlab<'info> {
+    pub fn grow_tick_slab(&mut self) -> Result<()> {
+        let capacity = self.tick_slab.load()?.tick_capacity as usize;
+        let new_capacity = capacity
+            .checked_add(TICK_SLAB_GROWTH_STEP)
+            .ok_or(AmmClError::Overflow)?;
+        require!(
+            new_capacity <= MAX_INITIALIZED_TICKS,
+            AmmClError::TooManyTicks
+        );
+
+        // Top up rent for the new size
+        let info = self.tick_slab.to_account_info();
+        let new_space = TickSlab::space(new_capacity);
+        let rent_due = Rent::get()?
+            .minimum_balance(new_space)
+            .saturating_sub(info.lamports());
+
+        if rent_due > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.payer.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+
+        info.resize(new_space)?;
+        self.tick_slab.load_mut()?.tick_capacity = new_capacity as u16;
+
+        msg!("Tick slab grown to {} slots", new_capacity);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+// Initialize Pool Instruction
+//
+// Opens a concentrated liquidity pool for a token pair at a starting price.
+//
+// HOW IT WORKS:
+// 1. Creates the pool PDA for (token_a_mint, token_b_mint)
+// 2. Creates the pool's empty tick slab (TICK_SLAB_INITIAL_CAPACITY slots)
+// 3. Creates the token A and token B vault ATAs owned by the pool
+// 4. Sets the price to the sqrt price of `initial_tick`, with no liquidity
+//
+// SECURITY:
+// - Token mints must differ
+// - Tick spacing and starting tick are bounds-checked
+// - Vaults are recorded on the pool and checked by every later instruction (has_one)
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, errors::*, math::*, state::*};
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mint::token_program = token_program)]
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR + Pool::INIT_SPACE,
+        seeds = [POOL_SEED, token_a_mint.key().as_ref(), token_b_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = TickSlab::space(TICK_SLAB_INITIAL_CAPACITY),
+        seeds = [TICK_SLAB_SEED, pool.key().as_ref()],
+        bump
+    )]
+    pub tick_slab: AccountLoader<'info, TickSlab>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = token_a_mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program,
+    )]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = token_b_mint,
+        associated_token::authority = pool,
+        associated_token::token_program = token_program,
+    )]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializePool<'info> {
+    pub fn initialize_pool(
+        &mut self,
+        tick_spacing: u16,
+        initial_tick: i32,
+        bumps: &InitializePoolBumps,
+    ) -> Result<()> {
+        require_keys_neq!(
+            self.token_a_mint.key(),
+            self.token_b_mint.key(),
+            AmmClError::IdenticalMints
+        );
+        require!(
+            (1..=MAX_TICK_SPACING).contains(&tick_spacing),
+            AmmClError::InvalidTickSpacing
+        );
+
+        // Rejects ticks outside MIN_TICK..=MAX_TICK
+        let sqrt_price = sqrt_price_at_tick(initial_tick)?;
+
+        self.pool.set_inner(Pool {
+            token_a_mint: self.token_a_mint.key(),
+            token_b_mint: self.token_b_mint.key(),
+            token_a_vault: self.token_a_vault.key(),
+            token_b_vault: self.token_b_vault.key(),
+            tick_spacing,
+            sqrt_price,
+            current_tick: initial_tick,
+            liquidity: 0,
+            bump: bumps.pool,
+        });
+
+        // load_init checks the account is fresh and writes the discriminator
+        let mut tick_slab = self.tick_slab.load_init()?;
+        tick_slab.pool = self.pool.key();
+        tick_slab.tick_capacity = TICK_SLAB_INITIAL_CAPACITY as u16;
+        tick_slab.bump = bumps.tick_slab;
+
+        msg!(
+            "Pool initialized: tick spacing {}, starting tick {}",
+            tick_spacing,
+            initial_tick
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+// Instructions Module
+//
+// Exports all instruction handlers for the concentrated liquidity AMM program
+
+pub mod initialize_pool;
+pub mod open_position;
+pub mod close_position;
+pub mod swap;
+pub mod grow_tick_slab;
+
+pub use initialize_pool::*;
+pub use open_position::*;
+pub use close_position::*;
+pub use swap::*;
+pub use grow_tick_slab::*;
@@ -0,0 +1,199 @@
+// Open Position Instruction
+//
+// Provides `liquidity` over the price range [lower_tick, upper_tick).
+//
+// HOW IT WORKS:
+// 1. Adds the position's liquidity to its two edge ticks
+//    (creating the Tick accounts and listing them on the tick slab if new)
+// 2. If the range contains the current tick, adds it to the pool's active liquidity
+// 3. Deposits the tokens backing the position at the current price:
+//    - Range above the price: token A only
+//    - Range containing the price: both tokens
+//    - Range below the price: token B only
+//
+// SECURITY:
+// - Range must be ordered, in bounds and aligned to the tick spacing
+// - Deposits round up so the vaults always cover every position
+// - Checked arithmetic on all liquidity updates
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, helpers::*, math::*, state::*};
+
+#[derive(Accounts)]
+#[instruction(lower_tick: i32, upper_tick: i32)]
+pub struct OpenPosition<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, token_a_mint.key().as_ref(), token_b_mint.key().as_ref()],
+        bump = pool.bump,
+        has_one = token_a_mint,
+        has_one = token_b_mint,
+        has_one = token_a_vault,
+        has_one = token_b_vault,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [TICK_SLAB_SEED, pool.key().as_ref()],
+        bump = tick_slab.load()?.bump,
+    )]
+    pub tick_slab: AccountLoader<'info, TickSlab>,
+
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR + Tick::INIT_SPACE,
+        seeds = [TICK_SEED, pool.key().as_ref(), &lower_tick.to_le_bytes()],
+        bump
+    )]
+    pub tick_lower: Box<Account<'info, Tick>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR + Tick::INIT_SPACE,
+        seeds = [TICK_SEED, pool.key().as_ref(), &upper_tick.to_le_bytes()],
+        bump
+    )]
+    pub tick_upper: Box<Account<'info, Tick>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR + Position::INIT_SPACE,
+        seeds = [
+            POSITION_SEED,
+            pool.key().as_ref(),
+            owner.key().as_ref(),
+            &lower_tick.to_le_bytes(),
+            &upper_tick.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(mut)]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_a_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_b_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> OpenPosition<'info> {
+    pub fn open_position(
+        &mut self,
+        lower_tick: i32,
+        upper_tick: i32,
+        liquidity: u64,
+        bumps: &OpenPositionBumps,
+    ) -> Result<()> {
+        require!(liquidity > 0, AmmClError::ZeroLiquidity);
+        self.pool.validate_range(lower_tick, upper_tick)?;
+
+        // Update the edge ticks, listing any that were uninitialized
+        // A full slab fails with TooManyTicks until grow_tick_slab adds room
+        let pool_key = self.pool.key();
+        {
+            let tick_slab_info = self.tick_slab.to_account_info();
+            let mut data = tick_slab_info.try_borrow_mut_data()?;
+            let (tick_slab, slab) = TickSlab::split_mut(&mut data);
+            if self.tick_lower.add_liquidity(pool_key, lower_tick, bumps.tick_lower, liquidity, false)? {
+                tick_slab.insert_tick(slab, lower_tick)?;
+            }
+            if self.tick_upper.add_liquidity(pool_key, upper_tick, bumps.tick_upper, liquidity, true)? {
+                tick_slab.insert_tick(slab, upper_tick)?;
+            }
+        }
+
+        // Liquidity is active immediately if the range contains the price
+        if self.pool.in_range(lower_tick, upper_tick) {
+            self.pool.liquidity = self
+                .pool
+                .liquidity
+                .checked_add(liquidity)
+                .ok_or(AmmClError::Overflow)?;
+        }
+
+        // Deposit, rounded up in the pool's favour
+        let (amount_a, amount_b) = position_amounts(
+            self.pool.sqrt_price,
+            self.pool.current_tick,
+            lower_tick,
+            upper_tick,
+            liquidity,
+            true,
+        )?;
+
+        if amount_a > 0 {
+            transfer_tokens(
+                amount_a,
+                self.token_a_mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.owner_token_a.to_account_info(),
+                &self.token_a_mint.to_account_info(),
+                &self.token_a_vault.to_account_info(),
+                &self.owner.to_account_info(),
+            )?;
+        }
+        if amount_b > 0 {
+            transfer_tokens(
+                amount_b,
+                self.token_b_mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.owner_token_b.to_account_info(),
+                &self.token_b_mint.to_account_info(),
+                &self.token_b_vault.to_account_info(),
+                &self.owner.to_account_info(),
+            )?;
+        }
+
+        self.position.set_inner(Position {
+            pool: pool_key,
+            owner: self.owner.key(),
+            lower_tick,
+            upper_tick,
+            liquidity,
+            bump: bumps.position,
+        });
+
+        msg!(
+            "Position opened: [{}, {}) liquidity {}, deposited {} A / {} B",
+            lower_tick,
+            upper_tick,
+            liquidity,
+            amount_a,
+            amount_b
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+// Swap Instruction
+//
+// Swaps token A for token B (a_to_b) or B for A along the concentrated
+// liquidity curve, crossing ticks as the price leaves each range.
+//
+// HOW IT WORKS:
+// 1. Finds the next initialized tick in the swap direction on the tick slab
+//    (or the MIN_TICK/MAX_TICK price bound if there is none)
+// 2. Swaps against the active liquidity up to that tick's price
+// 3. On reaching the tick, crosses it: applies its liquidity_net to the
+//    active liquidity and moves current_tick past it
+// 4. Repeats until the input is used up or the price bound is reached
+//    (a partial fill only takes the input it used)
+//
+// Tick accounts are passed in remaining_accounts, one per crossed tick, in
+// crossing order.
+//
+// SECURITY:
+// - Every crossed tick comes from the pool's tick slab, never from the caller;
+//   its account must be this pool's Tick for exactly that index
+// - A missing tick account fails the swap instead of skipping the crossing
+// - Slippage protection on the total output
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, helpers::*, math::*, state::*};
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    pub trader: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, token_a_mint.key().as_ref(), token_b_mint.key().as_ref()],
+        bump = pool.bump,
+        has_one = token_a_mint,
+        has_one = token_b_mint,
+        has_one = token_a_vault,
+        has_one = token_b_vault,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        seeds = [TICK_SLAB_SEED, pool.key().as_ref()],
+        bump = tick_slab.load()?.bump,
+    )]
+    pub tick_slab: AccountLoader<'info, TickSlab>,
+
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_a_mint,
+        token::authority = trader,
+        token::token_program = token_program,
+    )]
+    pub trader_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_b_mint,
+        token::authority = trader,
+        token::token_program = token_program,
+    )]
+    pub trader_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> Swap<'info> {
+    pub fn swap(
+        &mut self,
+        amount_in: u64,
+        min_amount_out: u64,
+        a_to_b: bool,
+        tick_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(amount_in > 0, AmmClError::ZeroAmount);
+
+        let pool_key = self.pool.key();
+        let tick_slab_info = self.tick_slab.to_account_info();
+        let tick_slab_data = tick_slab_info.try_borrow_data()?;
+        let (tick_slab, slab) = TickSlab::split(&tick_slab_data);
+        let pool = &mut self.pool;
+        let mut tick_accounts = tick_accounts.iter();
+        let mut remaining = amount_in;
+        let mut total_in: u64 = 0;
+        let mut total_out: u64 = 0;
+
+        while remaining > 0 {
+            // SECURITY: the next boundary comes from the pool's own tick slab
+            let next_tick = if a_to_b {
+                tick_slab.next_tick_down(slab, pool.current_tick)
+            } else {
+                tick_slab.next_tick_up(slab, pool.current_tick)
+            };
+            let target = match next_tick {
+                Some(tick) => sqrt_price_at_tick(tick)?,
+                None if a_to_b => min_sqrt_price()?,
+                None => max_sqrt_price()?,
+            };
+
+            let (sqrt_price, step_in, step_out) =
+                compute_swap_step(pool.sqrt_price, target, pool.liquidity, remaining, a_to_b)?;
+            remaining -= step_in;
+            total_in = total_in.checked_add(step_in).ok_or(AmmClError::Overflow)?;
+            total_out = total_out.checked_add(step_out).ok_or(AmmClError::Overflow)?;
+            pool.sqrt_price = sqrt_price;
+
+            // Input used up inside the range
+            if sqrt_price != target {
+                pool.current_tick = tick_at_sqrt_price(sqrt_price)?;
+                break;
+            }
+
+            // Reached the price bound: partial fill
+            let Some(tick_index) = next_tick else {
+                pool.current_tick = if a_to_b { MIN_TICK } else { MAX_TICK };
+                break;
+            };
+
+            // SECURITY: the crossing cannot be skipped by leaving the tick out,
+            // and only this pool's Tick for this index is accepted
+            let tick_info = tick_accounts.next().ok_or(AmmClError::MissingTickAccount)?;
+            let tick = Tick::load(tick_info)?;
+            require_keys_eq!(tick.pool, pool_key, AmmClError::InvalidTickAccount);
+            require!(tick.index == tick_index, AmmClError::InvalidTickAccount);
+
+            pool.liquidity = cross_tick(pool.liquidity, tick.liquidity_net, a_to_b)?;
+            pool.current_tick = if a_to_b { tick_index - 1 } else { tick_index };
+        }
+
+        require!(total_out > 0, AmmClError::InsufficientLiquidity);
+        require!(total_out >= min_amount_out, AmmClError::SlippageExceeded);
+
+        let (mint_in, mint_out, vault_in, vault_out, trader_in, trader_out) = if a_to_b {
+            (
+                &self.token_a_mint,
+                &self.token_b_mint,
+                &self.token_a_vault,
+                &self.token_b_vault,
+                &self.trader_token_a,
+                &self.trader_token_b,
+            )
+        } else {
+            (
+                &self.token_b_mint,
+                &self.token_a_mint,
+                &self.token_b_vault,
+                &self.token_a_vault,
+                &self.trader_token_b,
+                &self.trader_token_a,
+            )
+        };
+
+        transfer_tokens(
+            total_in,
+            mint_in.decimals,
+            &self.token_program.to_account_info(),
+            &trader_in.to_account_info(),
+            &mint_in.to_account_info(),
+            &vault_in.to_account_info(),
+            &self.trader.to_account_info(),
+        )?;
+
+        let token_a_mint_key = self.token_a_mint.key();
+        let token_b_mint_key = self.token_b_mint.key();
+        let pool_seeds = &[
+            POOL_SEED,
+            token_a_mint_key.as_ref(),
+            token_b_mint_key.as_ref(),
+            &[self.pool.bump],
+        ];
+
+        transfer_from_vault(
+            total_out,
+            mint_out.decimals,
+            &self.token_program.to_account_info(),
+            &vault_out.to_account_info(),
+            &mint_out.to_account_info(),
+            &trader_out.to_account_info(),
+            &self.pool.to_account_info(),
+            pool_seeds,
+        )?;
+
+        msg!(
+            "Swapped {} in for {} out, price now at tick {}",
+            total_in,
+            total_out,
+            self.pool.current_tick
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+// Concentrated Liquidity AMM Program - SECURE VERSION
+//
+// Implementation of a v3-style concentrated liquidity AMM. This program
+// allows users to:
+// 1. Create a pool for a token pair at a starting price
+// 2. Open positions providing liquidity over a chosen tick range
+// 3. Swap along the curve, crossing ticks as the price leaves each range
+// 4. Close their positions and withdraw the backing tokens
+//
+// SECURITY FEATURES:
+// - Only a position's owner can close it
+// - Swaps cross every initialized tick on the way, taken from the pool's own
+//   zero-copy tick slab; the caller only supplies the matching Tick accounts
+// - Tick accounts are checked against the pool and the expected index
+// - Deposits round up and payouts round down, so the vaults stay solvent
+// - Checked arithmetic to prevent overflow/underflow
+//
+// PRICE MODEL:
+// Tick i has price 1.0001^i (token B per token A). Pools store the sqrt price
+// in Q32.32 fixed point; see math.rs. There is no swap fee.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+pub mod math;
+
+use instructions::*;
+
+declare_id!("F2CXdm8rJ2jz2KxTSR6ASmXFZ2e7isYTHfxks2qYMSXJ");
+
+#[program]
+pub mod amm_cl_secure {
+    use super::*;
+
+    // Create the pool and its vaults at the price of `initial_tick`
+    pub fn initialize_pool(ctx: Context<InitializePool>, tick_spacing: u16, initial_tick: i32) -> Result<()> {
+        ctx.accounts.initialize_pool(tick_spacing, initial_tick, &ctx.bumps)
+    }
+
+    // Provide liquidity over [lower_tick, upper_tick)
+    pub fn open_position(
+        ctx: Context<OpenPosition>,
+        lower_tick: i32,
+        upper_tick: i32,
+        liquidity: u64,
+    ) -> Result<()> {
+        ctx.accounts.open_position(lower_tick, upper_tick, liquidity, &ctx.bumps)
+    }
+
+    // Withdraw a position's liquidity and close it
+    pub fn close_position(ctx: Context<ClosePosition>) -> Result<()> {
+        ctx.accounts.close_position()
+    }
+
+    // Swap along the curve; crossed Tick accounts go in remaining accounts
+    pub fn swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
+        amount_in: u64,
+        min_amount_out: u64,
+        a_to_b: bool,
+    ) -> Result<()> {
+        ctx.accounts.swap(amount_in, min_amount_out, a_to_b, ctx.remaining_accounts)
+    }
+
+    // Add TICK_SLAB_GROWTH_STEP slots to a pool's tick slab
+    pub fn grow_tick_slab(ctx: Context<GrowTickSlab>) -> Result<()> {
+        ctx.accounts.grow_tick_slab()
+    }
+}
@@ -0,0 +1,211 @@
+// Concentrated Liquidity Math
+//
+// Prices are token B per token A. Pools store sqrt(price) as a Q32.32 fixed
+// point u64 ("sqrt price"); tick i sits at price 1.0001^i. Liquidity L is a
+// u64 and every intermediate product is computed in u128.
+//
+// Within one tick range the curve is x * y = L^2, so moving the sqrt price
+// from sa to sb (sa < sb) takes:
+// - token A: L * (1/sa - 1/sb)
+// - token B: L * (sb - sa)
+//
+// ROUNDING:
+// Every amount the pool receives rounds up and every amount it pays rounds
+// down, so rounding never leaves the vaults short.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*};
+
+// Fractional bits of a sqrt price
+const Q32: u32 = 32;
+
+// floor(2^64 * 1.0001^(-2^k / 2)) for k = 0..17: the sqrt price of tick -2^k in Q64.64
+// 2^16 < MAX_TICK < 2^17, so any tick in range is a product of these steps
+const SQRT_RATIO_STEPS: [u128; 17] = [
+    18445821805675392311,
+    18444899583751176498,
+    18443055278223354162,
+    18439367220385604838,
+    18431993317065449817,
+    18417254355718160513,
+    18387811781193591352,
+    18329067761203520168,
+    18212142134806087854,
+    17980523815641551639,
+    17526086738831147013,
+    16651378430235024244,
+    15030750278693429944,
+    12247334978882834399,
+    8131365268884726200,
+    3584323654723342297,
+    696457651847595233,
+];
+
+// Sqrt price of a tick, Q32.32, rounded up
+pub fn sqrt_price_at_tick(tick: i32) -> Result<u64> {
+    require!(
+        (MIN_TICK..=MAX_TICK).contains(&tick),
+        AmmClError::TickOutOfBounds
+    );
+
+    // Q64.64 sqrt price of -|tick|: each step is < 2^64, so no product overflows
+    let abs_tick = tick.unsigned_abs();
+    let mut ratio: u128 = 1 << 64;
+    for (bit, step) in SQRT_RATIO_STEPS.iter().enumerate() {
+        if abs_tick & (1 << bit) != 0 {
+            ratio = (ratio * step) >> 64;
+        }
+    }
+
+    // Positive ticks are the reciprocal
+    if tick > 0 {
+        ratio = u128::MAX / ratio;
+    }
+
+    // Q64.64 -> Q32.32
+    let sqrt_price = (ratio >> Q32) + u128::from(ratio % (1 << Q32) != 0);
+    u64::try_from(sqrt_price).map_err(|_| error!(AmmClError::Overflow))
+}
+
+pub fn min_sqrt_price() -> Result<u64> {
+    sqrt_price_at_tick(MIN_TICK)
+}
+
+pub fn max_sqrt_price() -> Result<u64> {
+    sqrt_price_at_tick(MAX_TICK)
+}
+
+// Largest tick whose sqrt price is <= `sqrt_price`
+pub fn tick_at_sqrt_price(sqrt_price: u64) -> Result<i32> {
+    require!(
+        sqrt_price >= min_sqrt_price()?,
+        AmmClError::TickOutOfBounds
+    );
+
+    let (mut low, mut high) = (MIN_TICK, MAX_TICK);
+    while low < high {
+        let mid = low + (high - low + 1) / 2;
+        if sqrt_price_at_tick(mid)? <= sqrt_price {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    Ok(low)
+}
+
+// Token A between two sqrt prices: L * (1/lower - 1/upper)
+pub fn amount_a_delta(sqrt_lower: u64, sqrt_upper: u64, liquidity: u64, round_up: bool) -> Result<u64> {
+    let numerator = (liquidity as u128) << Q32;
+    let (lower, upper) = (sqrt_lower as u128, sqrt_upper as u128);
+
+    let amount = if round_up {
+        numerator.div_ceil(lower).saturating_sub(numerator / upper)
+    } else {
+        (numerator / lower).saturating_sub(numerator.div_ceil(upper))
+    };
+
+    u64::try_from(amount).map_err(|_| error!(AmmClError::Overflow))
+}
+
+// Token B between two sqrt prices: L * (upper - lower)
+pub fn amount_b_delta(sqrt_lower: u64, sqrt_upper: u64, liquidity: u64, round_up: bool) -> Result<u64> {
+    let product = (liquidity as u128)
+        .checked_mul(sqrt_upper.saturating_sub(sqrt_lower) as u128)
+        .ok_or(AmmClError::Overflow)?;
+
+    let mut amount = product >> Q32;
+    if round_up && product % (1 << Q32) != 0 {
+        amount += 1;
+    }
+
+    u64::try_from(amount).map_err(|_| error!(AmmClError::Overflow))
+}
+
+// Token amounts backing `liquidity` over [lower_tick, upper_tick) at the pool's price
+// - Price below the range: all token A
+// - Price inside the range: both tokens, split at the current price
+// - Price above the range: all token B
+pub fn position_amounts(
+    sqrt_price: u64,
+    current_tick: i32,
+    lower_tick: i32,
+    upper_tick: i32,
+    liquidity: u64,
+    round_up: bool,
+) -> Result<(u64, u64)> {
+    let sqrt_lower = sqrt_price_at_tick(lower_tick)?;
+    let sqrt_upper = sqrt_price_at_tick(upper_tick)?;
+
+    if current_tick < lower_tick {
+        Ok((amount_a_delta(sqrt_lower, sqrt_upper, liquidity, round_up)?, 0))
+    } else if current_tick < upper_tick {
+        Ok((
+            amount_a_delta(sqrt_price, sqrt_upper, liquidity, round_up)?,
+            amount_b_delta(sqrt_lower, sqrt_price, liquidity, round_up)?,
+        ))
+    } else {
+        Ok((0, amount_b_delta(sqrt_lower, sqrt_upper, liquidity, round_up)?))
+    }
+}
+
+// One swap step inside a single tick range, moving the price towards `target`
+// Returns (new sqrt price, amount in, amount out)
+// Stops at `target` if `amount_remaining` is enough to reach it
+pub fn compute_swap_step(
+    sqrt_price: u64,
+    target: u64,
+    liquidity: u64,
+    amount_remaining: u64,
+    a_to_b: bool,
+) -> Result<(u64, u64, u64)> {
+    if a_to_b {
+        // Selling A pushes the price down
+        let max_in = amount_a_delta(target, sqrt_price, liquidity, true)?;
+        let (new_price, amount_in) = if amount_remaining >= max_in {
+            (target, max_in)
+        } else {
+            // 1/new = 1/price + amount / L, rounded so the price moves less
+            let numerator = (liquidity as u128) << Q32;
+            let denominator = (numerator / sqrt_price as u128)
+                .checked_add(amount_remaining as u128)
+                .ok_or(AmmClError::Overflow)?;
+            let new_price = u64::try_from(numerator.div_ceil(denominator))
+                .map_err(|_| error!(AmmClError::Overflow))?;
+            (new_price.clamp(target, sqrt_price), amount_remaining)
+        };
+        let amount_out = amount_b_delta(new_price, sqrt_price, liquidity, false)?;
+        Ok((new_price, amount_in, amount_out))
+    } else {
+        // Selling B pushes the price up
+        let max_in = amount_b_delta(sqrt_price, target, liquidity, true)?;
+        let (new_price, amount_in) = if amount_remaining >= max_in {
+            (target, max_in)
+        } else {
+            // new = price + amount / L, rounded down
+            let step = ((amount_remaining as u128) << Q32) / liquidity as u128;
+            let new_price = (sqrt_price as u128)
+                .checked_add(step)
+                .ok_or(AmmClError::Overflow)?
+                .min(target as u128) as u64;
+            (new_price, amount_remaining)
+        };
+        let amount_out = amount_a_delta(sqrt_price, new_price, liquidity, false)?;
+        Ok((new_price, amount_in, amount_out))
+    }
+}
+
+// Apply a crossed tick's liquidity_net to the active liquidity
+// Moving up enters the ranges starting at the tick; moving down undoes that
+pub fn cross_tick(liquidity: u64, liquidity_net: i128, a_to_b: bool) -> Result<u64> {
+    let liquidity = liquidity as i128;
+    let next = if a_to_b {
+        liquidity.checked_sub(liquidity_net)
+    } else {
+        liquidity.checked_add(liquidity_net)
+    }
+    .ok_or(AmmClError::Overflow)?;
+
+    u64::try_from(next).map_err(|_| error!(AmmClError::Underflow))
+}
@@ -0,0 +1,13 @@
+// State Module
+//
+// Exports all state structures used by the concentrated liquidity AMM program
+
+pub mod pool;
+pub mod tick;
+pub mod tick_slab;
+pub mod position;
+
+pub use pool::*;
+pub use tick::*;
+pub use tick_slab::*;
+pub use position::*;
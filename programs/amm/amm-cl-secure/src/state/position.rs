@@ -0,0 +1,26 @@
+// Position State
+//
+// Liquidity an owner provides over the price range [lower_tick, upper_tick).
+// The liquidity only trades against swaps (and changes its token mix)
+// while the pool's current tick is inside the range.
+
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Position {
+    // Pool the position belongs to
+    pub pool: Pubkey,
+
+    // Only this address can close the position
+    pub owner: Pubkey,
+
+    pub lower_tick: i32,
+
+    pub upper_tick: i32,
+
+    pub liquidity: u64,
+
+    // PDA bump seed
+    pub bump: u8,
+}
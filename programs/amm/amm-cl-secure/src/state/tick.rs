@@ -0,0 +1,83 @@
+// Tick State
+//
+// One Tick per initialized position edge in a pool.
+// - liquidity_net: change in active liquidity when the price crosses this tick
+//   moving up (+L for each position starting here, -L for each ending here)
+// - liquidity_gross: total liquidity referencing the tick; 0 means uninitialized
+//
+// The account stays allocated once its liquidity_gross drops back to 0 and is
+// reused if a later position starts or ends at the same tick.
+
+use anchor_lang::prelude::*;
+use crate::errors::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Tick {
+    pub pool: Pubkey,
+
+    pub index: i32,
+
+    pub liquidity_net: i128,
+
+    pub liquidity_gross: u64,
+
+    // PDA bump seed
+    pub bump: u8,
+}
+
+impl Tick {
+    // Add a position edge; returns true if the tick was uninitialized before
+    pub fn add_liquidity(
+        &mut self,
+        pool: Pubkey,
+        index: i32,
+        bump: u8,
+        liquidity: u64,
+        is_upper: bool,
+    ) -> Result<bool> {
+        let flipped = self.liquidity_gross == 0;
+        if flipped {
+            self.pool = pool;
+            self.index = index;
+            self.bump = bump;
+        }
+
+        self.liquidity_gross = self
+            .liquidity_gross
+            .checked_add(liquidity)
+            .ok_or(AmmClError::Overflow)?;
+        self.liquidity_net = if is_upper {
+            self.liquidity_net.checked_sub(liquidity as i128)
+        } else {
+            self.liquidity_net.checked_add(liquidity as i128)
+        }
+        .ok_or(AmmClError::Overflow)?;
+
+        Ok(flipped)
+    }
+
+    // Remove a position edge; returns true if the tick is now uninitialized
+    pub fn remove_liquidity(&mut self, liquidity: u64, is_upper: bool) -> Result<bool> {
+        self.liquidity_gross = self
+            .liquidity_gross
+            .checked_sub(liquidity)
+            .ok_or(AmmClError::Underflow)?;
+        self.liquidity_net = if is_upper {
+            self.liquidity_net.checked_add(liquidity as i128)
+        } else {
+            self.liquidity_net.checked_sub(liquidity as i128)
+        }
+        .ok_or(AmmClError::Overflow)?;
+
+        Ok(self.liquidity_gross == 0)
+    }
+
+    // Deserialize a Tick passed in remaining accounts
+    // Checks the owner and discriminator; the caller checks pool and index
+    pub fn load(info: &AccountInfo) -> Result<Tick> {
+        require_keys_eq!(*info.owner, crate::ID, AmmClError::InvalidTickAccount);
+        let data = info.try_borrow_data()?;
+        Tick::try_deserialize(&mut &data[..])
+    }
+}
@@ -0,0 +1,107 @@
+// Tick Slab State
+//
+// One TickSlab per pool: the sorted list of initialized ticks (edges of at
+// least one open position). Swaps use it to find the next tick they must
+// cross, so a caller can never skip a range edge by leaving its Tick account out.
+//
+// The account is a fixed header followed by a slab of tick indexes:
+//
+// [discriminator (8)][TickSlab header][i32; tick_capacity]
+//
+// A Borsh Vec<i32> on the pool would be decoded and re-encoded in full by
+// every instruction. Here the header is read in place and the ticks are cast
+// straight out of the slab, and lookups binary-search the tick_count ticks
+// in use, so a swap costs the same at 32 slots as at MAX_INITIALIZED_TICKS.
+//
+// The slab starts at TICK_SLAB_INITIAL_CAPACITY slots and grows by realloc
+// (grow_tick_slab) in TICK_SLAB_GROWTH_STEP steps up to MAX_INITIALIZED_TICKS.
+// Slots past tick_count are zeroed and unused.
+
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+use crate::{constants::*, errors::*};
+
+#[account(zero_copy)]
+pub struct TickSlab {
+    // Pool the ticks belong to
+    pub pool: Pubkey,
+
+    // Slots in use / slots allocated in the tick slab
+    pub tick_count: u16,
+    pub tick_capacity: u16,
+
+    // PDA bump seed
+    pub bump: u8,
+
+    pub _padding: [u8; 3],
+}
+
+impl TickSlab {
+    // Bytes before the tick slab
+    pub const HEADER_SPACE: usize = ANCHOR_DISCRIMINATOR + size_of::<TickSlab>();
+
+    // Account size for a slab of `capacity` slots
+    pub fn space(capacity: usize) -> usize {
+        Self::HEADER_SPACE + capacity * size_of::<i32>()
+    }
+
+    // Split raw account data into the header and the tick slab
+    // The AccountLoader has already checked the owner and discriminator
+    pub fn split(data: &[u8]) -> (&TickSlab, &[i32]) {
+        let (header, slab) = data[ANCHOR_DISCRIMINATOR..].split_at(size_of::<TickSlab>());
+        let header: &TickSlab = bytemuck::from_bytes(header);
+        let slab_len = header.tick_capacity as usize * size_of::<i32>();
+        (header, bytemuck::cast_slice(&slab[..slab_len]))
+    }
+
+    pub fn split_mut(data: &mut [u8]) -> (&mut TickSlab, &mut [i32]) {
+        let (header, slab) = data[ANCHOR_DISCRIMINATOR..].split_at_mut(size_of::<TickSlab>());
+        let header: &mut TickSlab = bytemuck::from_bytes_mut(header);
+        let slab_len = header.tick_capacity as usize * size_of::<i32>();
+        (header, bytemuck::cast_slice_mut(&mut slab[..slab_len]))
+    }
+
+    // Initialized ticks in ascending order (the slab is padded to tick_capacity)
+    pub fn active_ticks<'a>(&self, slab: &'a [i32]) -> &'a [i32] {
+        &slab[..self.tick_count as usize]
+    }
+
+    // Next tick a price decrease crosses: the highest initialized tick <= current_tick
+    pub fn next_tick_down(&self, slab: &[i32], current_tick: i32) -> Option<i32> {
+        let ticks = self.active_ticks(slab);
+        let above = ticks.partition_point(|tick| *tick <= current_tick);
+        above.checked_sub(1).map(|index| ticks[index])
+    }
+
+    // Next tick a price increase crosses: the lowest initialized tick > current_tick
+    pub fn next_tick_up(&self, slab: &[i32], current_tick: i32) -> Option<i32> {
+        let ticks = self.active_ticks(slab);
+        let above = ticks.partition_point(|tick| *tick <= current_tick);
+        ticks.get(above).copied()
+    }
+
+    // Record a newly initialized tick
+    pub fn insert_tick(&mut self, slab: &mut [i32], tick: i32) -> Result<()> {
+        let count = self.tick_count as usize;
+        if let Err(index) = self.active_ticks(slab).binary_search(&tick) {
+            require!(
+                count < self.tick_capacity as usize,
+                AmmClError::TooManyTicks
+            );
+            slab.copy_within(index..count, index + 1);
+            slab[index] = tick;
+            self.tick_count += 1;
+        }
+        Ok(())
+    }
+
+    // Forget a tick no position references any more
+    pub fn remove_tick(&mut self, slab: &mut [i32], tick: i32) {
+        let count = self.tick_count as usize;
+        if let Ok(index) = self.active_ticks(slab).binary_search(&tick) {
+            slab.copy_within(index + 1..count, index);
+            slab[count - 1] = 0;
+            self.tick_count -= 1;
+        }
+    }
+}
@@ -0,0 +1,119 @@
+// Compute-unit regression tests for amm-cl-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+mod utils;
+
+use litesvm_token::CreateMint;
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+};
+use soteria_test_kit::{ComputeBudget, DEFAULT_CU_BASELINES_FILE};
+use utils::*;
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let mut svm = setup_svm();
+    let mint_authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let token_a_mint = CreateMint::new(&mut svm, &mint_authority)
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create token A mint");
+    let token_b_mint = CreateMint::new(&mut svm, &mint_authority)
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create token B mint");
+
+    let ix = build_initialize_pool_ix(
+        &mint_authority.pubkey(),
+        &token_a_mint,
+        &token_b_mint,
+        TICK_SPACING,
+        0,
+    );
+    budget.record("initialize_pool", send_ix(&mut svm, ix, &mint_authority));
+
+    let (pool, _) = derive_pool_pda(&token_a_mint, &token_b_mint);
+    let mut scenario = ClScenario {
+        svm,
+        mint_authority,
+        token_a_mint,
+        token_b_mint,
+        pool,
+    };
+    let alice = create_trader(&mut scenario);
+    let bob = create_trader(&mut scenario);
+
+    // In range: deposits both tokens and creates both Tick accounts
+    let ix = build_open_position_ix(&alice.pubkey(), &token_a_mint, &token_b_mint, -600, 600, LIQUIDITY);
+    budget.record("open_position", send_ix(&mut scenario.svm, ix, &alice));
+    open_position(&mut scenario, &bob, 1200, 2400);
+
+    // Swap within one range (binary search for the new tick dominates)
+    let ix = swap_ix(&scenario, &bob, 100_000_000, 0, false, &[]);
+    budget.record("swap", send_ix(&mut scenario.svm, ix, &bob));
+
+    // Swap crossing two ticks
+    let ix = swap_ix(&scenario, &bob, 4_000_000_000, 0, false, &[600, 1200]);
+    budget.record("swap_cross_two_ticks", send_ix(&mut scenario.svm, ix, &bob));
+
+    let ix = build_close_position_ix(&alice.pubkey(), &alice.pubkey(), &token_a_mint, &token_b_mint, -600, 600);
+    budget.record("close_position", send_ix(&mut scenario.svm, ix, &alice));
+
+    budget.check();
+}
+
+// Pool with Alice's [-600, 600) and Bob's [1200, 2400) open, plus a trader
+fn two_range_scenario() -> (ClScenario, Keypair) {
+    let mut scenario = setup_cl_scenario();
+    let alice = create_trader(&mut scenario);
+    let bob = create_trader(&mut scenario);
+    open_position(&mut scenario, &alice, -600, 600);
+    open_position(&mut scenario, &bob, 1200, 2400);
+    (scenario, bob)
+}
+
+// Zero-copy tick slab: a swap reads the slab header in place and
+// binary-searches only the ticks in use, so the same swap over the same
+// positions must cost the same at 32 slots as at MAX_INITIALIZED_TICKS
+#[test]
+fn test_tick_slab_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+
+    let (mut scenario, trader) = two_range_scenario();
+    let ix = swap_ix(&scenario, &trader, 4_000_000_000, 0, false, &[600, 1200]);
+    let small_units = budget
+        .record("swap_cross_two_ticks_32_slots", send_ix(&mut scenario.svm, ix, &trader))
+        .compute_units_consumed;
+
+    // Grow 32 -> 1024 slots before the same swap
+    let (mut scenario, trader) = two_range_scenario();
+    let ix = build_grow_tick_slab_ix(&trader.pubkey(), &scenario.pool);
+    budget.record("grow_tick_slab", send_ix(&mut scenario.svm, ix, &trader));
+    while get_pool(&scenario.svm, &scenario.pool).tick_capacity < MAX_INITIALIZED_TICKS {
+        let ix = build_grow_tick_slab_ix(&trader.pubkey(), &scenario.pool);
+        send_ix(&mut scenario.svm, ix, &trader).expect("Grow should succeed");
+    }
+    let ix = swap_ix(&scenario, &trader, 4_000_000_000, 0, false, &[600, 1200]);
+    let large_units = budget
+        .record("swap_cross_two_ticks_1024_slots", send_ix(&mut scenario.svm, ix, &trader))
+        .compute_units_consumed;
+
+    println!(
+        "swap crossing two ticks: {} CU at 32 slots, {} CU at 1024 slots",
+        small_units, large_units
+    );
+    assert!(
+        large_units.abs_diff(small_units) <= small_units / 100,
+        "swap should not scale with tick slab capacity"
+    );
+
+    budget.check();
+}
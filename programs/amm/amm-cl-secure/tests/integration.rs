@@ -0,0 +1,262 @@
+// Integration tests for concentrated liquidity AMM program using LiteSVM
+// These tests verify core functionality: range positions, swaps crossing
+// ticks, the tick account checks that stop a swap skipping a crossing, and
+// growth of the zero-copy tick slab
+//
+// The swap scenarios use the same two positions of LIQUIDITY around price 1.0:
+// - Alice: [-600, 600), in range at tick 0
+// - Bob:   [1200, 2400), above the price, so token A only
+// Expected amounts are exact outputs of the Q32.32 math in src/math.rs
+
+mod utils;
+
+use utils::*;
+use solana_sdk::signature::Signer;
+
+// Deposits for the two positions at tick 0 (rounded up)
+const ALICE_DEPOSIT_A: u64 = 2_955_301_107;
+const ALICE_DEPOSIT_B: u64 = 2_955_301_083;
+const BOB_DEPOSIT_A: u64 = 5_484_160_076;
+
+#[test]
+fn test_open_and_close_position() {
+    // Test: Positions deposit the tokens for their range and withdraw them on close
+    println!("\n[TEST START] test_open_and_close_position - Range positions");
+
+    let mut scenario = setup_cl_scenario();
+    let alice = create_trader(&mut scenario);
+    let bob = create_trader(&mut scenario);
+    let (mint_a, mint_b) = (scenario.token_a_mint, scenario.token_b_mint);
+    println!("[Setup] Pool open at tick 0, Alice and Bob funded");
+
+    // Invalid ranges are rejected
+    for (lower, upper, liquidity, reason) in [
+        (600, 600, LIQUIDITY, "Empty range"),
+        (600, -600, LIQUIDITY, "Inverted range"),
+        (-590, 600, LIQUIDITY, "Unaligned tick"),
+        (-100_020, 600, LIQUIDITY, "Tick below MIN_TICK"),
+        (-600, 600, 0, "Zero liquidity"),
+    ] {
+        let ix = build_open_position_ix(&alice.pubkey(), &mint_a, &mint_b, lower, upper, liquidity);
+        assert!(send_ix(&mut scenario.svm, ix, &alice).is_err(), "{} should fail", reason);
+    }
+    println!("[Success] Empty, inverted, unaligned, out-of-bounds and zero-liquidity positions rejected");
+
+    println!("[Action] Alice opens [-600, 600), Bob opens [1200, 2400)");
+    open_position(&mut scenario, &alice, -600, 600);
+    open_position(&mut scenario, &bob, 1200, 2400);
+
+    assert_eq!(balance_a(&scenario, &alice.pubkey()), TRADER_BALANCE - ALICE_DEPOSIT_A);
+    assert_eq!(balance_b(&scenario, &alice.pubkey()), TRADER_BALANCE - ALICE_DEPOSIT_B);
+    assert_eq!(balance_a(&scenario, &bob.pubkey()), TRADER_BALANCE - BOB_DEPOSIT_A);
+    assert_eq!(balance_b(&scenario, &bob.pubkey()), TRADER_BALANCE);
+    println!("[Success] Alice deposited both tokens, Bob (above the price) only token A");
+
+    let pool = get_pool(&scenario.svm, &scenario.pool);
+    assert_eq!(pool.liquidity, LIQUIDITY, "Only Alice's range is active");
+    assert_eq!(pool.ticks, vec![-600, 600, 1200, 2400]);
+    println!("[Success] Active liquidity {}, ticks {:?}", pool.liquidity, pool.ticks);
+
+    // Bob cannot close Alice's position
+    let ix = build_close_position_ix(&bob.pubkey(), &alice.pubkey(), &mint_a, &mint_b, -600, 600);
+    assert!(send_ix(&mut scenario.svm, ix, &bob).is_err(), "Non-owner close should fail");
+    println!("[Success] Close by non-owner rejected");
+
+    println!("[Action] Alice closes her position");
+    let ix = build_close_position_ix(&alice.pubkey(), &alice.pubkey(), &mint_a, &mint_b, -600, 600);
+    let result = send_ix(&mut scenario.svm, ix, &alice);
+    assert!(result.is_ok(), "Close failed: {:?}", result.err());
+
+    // Payouts round down: one atom of each token stays in the pool
+    assert_eq!(balance_a(&scenario, &alice.pubkey()), TRADER_BALANCE - 1);
+    assert_eq!(balance_b(&scenario, &alice.pubkey()), TRADER_BALANCE - 1);
+    let (position, _) = derive_position_pda(&scenario.pool, &alice.pubkey(), -600, 600);
+    assert!(
+        scenario.svm.get_account(&position).is_none_or(|a| a.lamports == 0),
+        "Position should be closed"
+    );
+    println!("[Success] Alice withdrew her deposit less rounding, position closed");
+
+    let pool = get_pool(&scenario.svm, &scenario.pool);
+    assert_eq!(pool.liquidity, 0);
+    assert_eq!(pool.ticks, vec![1200, 2400]);
+    println!("[Success] Active liquidity back to 0, Alice's ticks delisted");
+
+    println!("[TEST END] test_open_and_close_position");
+}
+
+#[test]
+fn test_swap_crosses_ticks() {
+    // Test: Swaps cross range edges and switch the active liquidity
+    println!("\n[TEST START] test_swap_crosses_ticks - Tick crossing");
+
+    let mut scenario = setup_cl_scenario();
+    let alice = create_trader(&mut scenario);
+    let bob = create_trader(&mut scenario);
+    let trader = create_trader(&mut scenario);
+    let (mint_a, mint_b) = (scenario.token_a_mint, scenario.token_b_mint);
+    open_position(&mut scenario, &alice, -600, 600);
+    open_position(&mut scenario, &bob, 1200, 2400);
+    let start_price = get_pool(&scenario.svm, &scenario.pool).sqrt_price;
+    println!("[Setup] Alice [-600, 600) and Bob [1200, 2400) open");
+
+    println!("[Action] Trader buys A with 4000 B, crossing ticks 600 and 1200");
+    let ix = swap_ix(&scenario, &trader, 4_000_000_000, 3_794_504_788, false, &[600, 1200]);
+    let result = send_ix(&mut scenario.svm, ix, &trader);
+    assert!(result.is_ok(), "Swap B->A failed: {:?}", result.err());
+
+    assert_eq!(balance_a(&scenario, &trader.pubkey()), TRADER_BALANCE + 3_794_504_788);
+    assert_eq!(balance_b(&scenario, &trader.pubkey()), TRADER_BALANCE - 4_000_000_000);
+
+    let pool = get_pool(&scenario.svm, &scenario.pool);
+    assert!(pool.sqrt_price > start_price);
+    assert_eq!(pool.current_tick, 1379);
+    assert_eq!(pool.liquidity, LIQUIDITY, "Only Bob's range is active");
+    println!("[Success] Left Alice's range at 600, entered Bob's at 1200, now at tick 1379");
+
+    println!("[Action] Trader sells 3000 A back, crossing ticks 1200 and 600");
+    let ix = swap_ix(&scenario, &trader, 3_000_000_000, 3_199_132_251, true, &[1200, 600]);
+    let result = send_ix(&mut scenario.svm, ix, &trader);
+    assert!(result.is_ok(), "Swap A->B failed: {:?}", result.err());
+
+    let pool = get_pool(&scenario.svm, &scenario.pool);
+    assert_eq!(pool.current_tick, 159);
+    assert_eq!(pool.liquidity, LIQUIDITY, "Only Alice's range is active");
+    println!("[Success] Back in Alice's range at tick 159");
+
+    println!("[Action] Both LPs close");
+    let ix = build_close_position_ix(&alice.pubkey(), &alice.pubkey(), &mint_a, &mint_b, -600, 600);
+    let result = send_ix(&mut scenario.svm, ix, &alice);
+    assert!(result.is_ok(), "Alice close failed: {:?}", result.err());
+    let ix = build_close_position_ix(&bob.pubkey(), &bob.pubkey(), &mint_a, &mint_b, 1200, 2400);
+    let result = send_ix(&mut scenario.svm, ix, &bob);
+    assert!(result.is_ok(), "Bob close failed: {:?}", result.err());
+
+    // Alice's range holds both tokens at tick 159; Bob's is out of range again
+    assert_eq!(balance_a(&scenario, &alice.pubkey()), TRADER_BALANCE - ALICE_DEPOSIT_A + 2_160_796_310);
+    assert_eq!(balance_b(&scenario, &alice.pubkey()), TRADER_BALANCE - ALICE_DEPOSIT_B + 3_756_168_810);
+    assert_eq!(balance_a(&scenario, &bob.pubkey()), TRADER_BALANCE - 2);
+    assert_eq!(get_pool(&scenario.svm, &scenario.pool).liquidity, 0);
+    println!("[Success] Vaults covered both withdrawals");
+
+    println!("[TEST END] test_swap_crosses_ticks");
+}
+
+#[test]
+fn test_swap_tick_validation() {
+    // Test: Crossings cannot be skipped and partial fills only take what they use
+    println!("\n[TEST START] test_swap_tick_validation - Tick account checks");
+
+    let mut scenario = setup_cl_scenario();
+    let alice = create_trader(&mut scenario);
+    let bob = create_trader(&mut scenario);
+    let trader = create_trader(&mut scenario);
+
+    // Empty pool: nothing to swap against
+    let ix = swap_ix(&scenario, &trader, 1_000_000, 0, true, &[]);
+    assert!(send_ix(&mut scenario.svm, ix, &trader).is_err(), "Swap without liquidity should fail");
+    println!("[Success] Swap against an empty pool rejected");
+
+    open_position(&mut scenario, &alice, -600, 600);
+    open_position(&mut scenario, &bob, 1200, 2400);
+    println!("[Setup] Alice [-600, 600) and Bob [1200, 2400) open");
+
+    // The swap reaches ticks 600 and 1200
+    for (ticks, reason) in [
+        (vec![], "No tick accounts"),
+        (vec![600], "Missing tick 1200"),
+        (vec![1200, 600], "Ticks out of order"),
+        (vec![-600, 1200], "Wrong tick"),
+    ] {
+        let ix = swap_ix(&scenario, &trader, 4_000_000_000, 0, false, &ticks);
+        assert!(send_ix(&mut scenario.svm, ix, &trader).is_err(), "{} should fail", reason);
+    }
+    println!("[Success] Missing, reordered and wrong tick accounts rejected");
+
+    // Slippage: one atom more than the swap yields
+    let ix = swap_ix(&scenario, &trader, 4_000_000_000, 3_794_504_789, false, &[600, 1200]);
+    assert!(send_ix(&mut scenario.svm, ix, &trader).is_err(), "Slippage should fail");
+    println!("[Success] Output below minimum rejected");
+
+    let pool = get_pool(&scenario.svm, &scenario.pool);
+    assert_eq!((pool.current_tick, pool.liquidity), (0, LIQUIDITY));
+    println!("[Success] Pool state untouched by the failed swaps");
+
+    println!("[Action] Trader sells 10,000 A: more than the liquidity below the price");
+    let ix = swap_ix(&scenario, &trader, 10_000_000_000, 0, true, &[-600]);
+    let result = send_ix(&mut scenario.svm, ix, &trader);
+    assert!(result.is_ok(), "Partial fill failed: {:?}", result.err());
+
+    // Only the input that moved the price to the bound is taken
+    assert_eq!(balance_a(&scenario, &trader.pubkey()), TRADER_BALANCE - 3_045_298_833);
+    assert_eq!(balance_b(&scenario, &trader.pubkey()), TRADER_BALANCE + ALICE_DEPOSIT_B - 1);
+
+    let pool = get_pool(&scenario.svm, &scenario.pool);
+    assert_eq!(pool.current_tick, MIN_TICK);
+    assert_eq!(pool.liquidity, 0);
+    println!("[Success] Partial fill: 3045 A taken, all of Alice's B paid out, price at MIN_TICK");
+
+    println!("[TEST END] test_swap_tick_validation");
+}
+
+#[test]
+fn test_tick_slab_growth() {
+    // Test: A full tick slab rejects new ticks until grow_tick_slab adds room
+    println!("\n[TEST START] test_tick_slab_growth - Zero-copy tick slab");
+
+    let mut scenario = setup_cl_scenario();
+    let lp = create_trader(&mut scenario);
+    let (mint_a, mint_b) = (scenario.token_a_mint, scenario.token_b_mint);
+
+    let pool = get_pool(&scenario.svm, &scenario.pool);
+    assert_eq!(pool.tick_capacity, TICK_SLAB_INITIAL_CAPACITY);
+    assert!(pool.ticks.is_empty());
+
+    // Disjoint one-spacing ranges above the price, two new ticks each
+    let range = |i: i32| (1200 + 120 * i, 1260 + 120 * i);
+    let full = TICK_SLAB_INITIAL_CAPACITY as i32 / 2;
+    println!("[Action] LP opens {} disjoint ranges, filling every slot", full);
+    for i in 0..full {
+        let (lower, upper) = range(i);
+        open_position(&mut scenario, &lp, lower, upper);
+    }
+    let pool = get_pool(&scenario.svm, &scenario.pool);
+    assert_eq!(pool.ticks.len(), TICK_SLAB_INITIAL_CAPACITY as usize);
+    assert!(pool.ticks.is_sorted());
+
+    let (lower, upper) = range(full);
+    let ix = build_open_position_ix(&lp.pubkey(), &mint_a, &mint_b, lower, upper, LIQUIDITY);
+    assert!(send_ix(&mut scenario.svm, ix, &lp).is_err(), "Full tick slab should reject new ticks");
+
+    // A range whose edges are both initialized needs no slot
+    let ix = build_open_position_ix(&lp.pubkey(), &mint_a, &mint_b, range(0).0, range(1).1, LIQUIDITY);
+    let result = send_ix(&mut scenario.svm, ix, &lp);
+    assert!(result.is_ok(), "Range over existing ticks failed: {:?}", result.err());
+    println!("[Success] New ticks rejected on a full slab, existing ticks still usable");
+
+    println!("[Action] LP grows the slab and retries");
+    let ix = build_grow_tick_slab_ix(&lp.pubkey(), &scenario.pool);
+    let result = send_ix(&mut scenario.svm, ix, &lp);
+    assert!(result.is_ok(), "Grow failed: {:?}", result.err());
+    assert_eq!(
+        get_pool(&scenario.svm, &scenario.pool).tick_capacity,
+        TICK_SLAB_INITIAL_CAPACITY + TICK_SLAB_GROWTH_STEP
+    );
+
+    open_position(&mut scenario, &lp, lower, upper);
+    let pool = get_pool(&scenario.svm, &scenario.pool);
+    assert_eq!(pool.ticks.len(), TICK_SLAB_INITIAL_CAPACITY as usize + 2);
+    assert!(pool.ticks.is_sorted());
+    println!("[Success] Slab grown to {} slots, new range listed", pool.tick_capacity);
+
+    // Growth stops at MAX_INITIALIZED_TICKS
+    while get_pool(&scenario.svm, &scenario.pool).tick_capacity < MAX_INITIALIZED_TICKS {
+        let ix = build_grow_tick_slab_ix(&lp.pubkey(), &scenario.pool);
+        send_ix(&mut scenario.svm, ix, &lp).expect("Grow below the maximum should succeed");
+    }
+    let ix = build_grow_tick_slab_ix(&lp.pubkey(), &scenario.pool);
+    assert!(send_ix(&mut scenario.svm, ix, &lp).is_err(), "Grow past the maximum should fail");
+    println!("[Success] Growth capped at {} slots", MAX_INITIALIZED_TICKS);
+
+    println!("[TEST END] test_tick_slab_growth");
+}
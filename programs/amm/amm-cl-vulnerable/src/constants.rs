@@ -0,0 +1,25 @@
+// Seed for pool PDA
+// Derived with: [POOL_SEED, token_a_mint_pubkey, token_b_mint_pubkey]
+pub const POOL_SEED: &[u8] = b"pool";
+
+// Seed for tick PDA
+// Derived with: [TICK_SEED, pool_pubkey, tick_index (i32 LE)]
+pub const TICK_SEED: &[u8] = b"tick";
+
+// Seed for position PDA
+// Derived with: [POSITION_SEED, pool_pubkey, owner_pubkey, lower_tick (i32 LE), upper_tick (i32 LE)]
+pub const POSITION_SEED: &[u8] = b"position";
+
+// TICK LIMITS
+
+// Tick i has price 1.0001^i, so the range covers prices ~1/22000 .. ~22000
+pub const MIN_TICK: i32 = -100_000;
+pub const MAX_TICK: i32 = 100_000;
+
+// Widest allowed tick spacing
+pub const MAX_TICK_SPACING: u16 = 1_000;
+
+// Initialized ticks tracked per pool (two per distinct position range edge)
+pub const MAX_INITIALIZED_TICKS: usize = 32;
+
+pub const ANCHOR_DISCRIMINATOR: usize = 8;
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+pub use soteria_errors::amm_cl_vulnerable::AmmClError as AmmClErrorCode;
+
+#[error_code]
+pub enum AmmClError {
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Liquidity must be greater than zero")]
+    ZeroLiquidity,
+
+    #[msg("Token A and token B mints must be different")]
+    IdenticalMints,
+
+    #[msg("Tick spacing must be between 1 and MAX_TICK_SPACING")]
+    InvalidTickSpacing,
+
+    #[msg("Tick is outside MIN_TICK..=MAX_TICK")]
+    TickOutOfBounds,
+
+    #[msg("Tick is not a multiple of the pool's tick spacing")]
+    TickNotAligned,
+
+    #[msg("Lower tick must be below upper tick")]
+    InvalidTickRange,
+
+    #[msg("Pool has no room for another initialized tick")]
+    TooManyTicks,
+
+    #[msg("Tick account is not a tick in the swap direction")]
+    InvalidTickAccount,
+
+    #[msg("Swap produced no output: no liquidity in the swap direction")]
+    InsufficientLiquidity,
+
+    #[msg("Output amount below minimum")]
+    SlippageExceeded,
+
+    #[msg("Arithmetic overflow")]
+    Overflow,
+
+    #[msg("Arithmetic underflow")]
+    Underflow,
+}
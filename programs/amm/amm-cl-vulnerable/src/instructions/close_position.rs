@@ -0,0 +1,173 @@
+// Close Position Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// VULNERABILITY:
+// V001: Missing owner check - anyone can close anyone's position and take its tokens
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, helpers::*, math::*, state::*};
+
+#[derive(Accounts)]
+pub struct ClosePosition<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, token_a_mint.key().as_ref(), token_b_mint.key().as_ref()],
+        bump = pool.bump,
+        has_one = token_a_mint,
+        has_one = token_b_mint,
+        has_one = token_a_vault,
+        has_one = token_b_vault,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [TICK_SEED, pool.key().as_ref(), &position.lower_tick.to_le_bytes()],
+        bump = tick_lower.bump,
+    )]
+    pub tick_lower: Box<Account<'info, Tick>>,
+
+    #[account(
+        mut,
+        seeds = [TICK_SEED, pool.key().as_ref(), &position.upper_tick.to_le_bytes()],
+        bump = tick_upper.bump,
+    )]
+    pub tick_upper: Box<Account<'info, Tick>>,
+
+    #[account(
+        mut,
+        seeds = [
+            POSITION_SEED,
+            pool.key().as_ref(),
+            position.owner.as_ref(),
+            &position.lower_tick.to_le_bytes(),
+            &position.upper_tick.to_le_bytes(),
+        ],
+        bump = position.bump,
+        has_one = pool,
+        // VULNERABILITY V001: position.owner never compared to the signer
+        // Secure version:
+        //   has_one = owner @ AmmClError::Unauthorized,
+        // The seeds use position.owner, so any existing position passes, and
+        // the payout goes to the signer's token accounts below
+        // Attack scenario (position theft):
+        // 1. Victim opens a position worth thousands of tokens
+        // 2. Attacker calls close_position on it with their own token accounts
+        // 3. Attacker receives the victim's tokens and the position's rent
+        close = owner,
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(mut)]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_a_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_b_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClosePosition<'info> {
+    pub fn close_position(&mut self) -> Result<()> {
+        let lower_tick = self.position.lower_tick;
+        let upper_tick = self.position.upper_tick;
+        let liquidity = self.position.liquidity;
+
+        // Withdrawal, rounded down in the pool's favour
+        let (amount_a, amount_b) = position_amounts(
+            self.pool.sqrt_price,
+            self.pool.current_tick,
+            lower_tick,
+            upper_tick,
+            liquidity,
+            false,
+        )?;
+
+        // Update the edge ticks, delisting any that are now unused
+        if self.tick_lower.remove_liquidity(liquidity, false)? {
+            self.pool.remove_tick(lower_tick);
+        }
+        if self.tick_upper.remove_liquidity(liquidity, true)? {
+            self.pool.remove_tick(upper_tick);
+        }
+
+        if self.pool.in_range(lower_tick, upper_tick) {
+            self.pool.liquidity = self
+                .pool
+                .liquidity
+                .checked_sub(liquidity)
+                .ok_or(AmmClError::Underflow)?;
+        }
+
+        let token_a_mint_key = self.token_a_mint.key();
+        let token_b_mint_key = self.token_b_mint.key();
+        let pool_seeds = &[
+            POOL_SEED,
+            token_a_mint_key.as_ref(),
+            token_b_mint_key.as_ref(),
+            &[self.pool.bump],
+        ];
+
+        if amount_a > 0 {
+            transfer_from_vault(
+                amount_a,
+                self.token_a_mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.token_a_vault.to_account_info(),
+                &self.token_a_mint.to_account_info(),
+                &self.owner_token_a.to_account_info(),
+                &self.pool.to_account_info(),
+                pool_seeds,
+            )?;
+        }
+        if amount_b > 0 {
+            transfer_from_vault(
+                amount_b,
+                self.token_b_mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.token_b_vault.to_account_info(),
+                &self.token_b_mint.to_account_info(),
+                &self.owner_token_b.to_account_info(),
+                &self.pool.to_account_info(),
+                pool_seeds,
+            )?;
+        }
+
+        msg!(
+            "Position closed: [{}, {}) liquidity {}, withdrew {} A / {} B",
+            lower_tick,
+            upper_tick,
+            liquidity,
+            amount_a,
+            amount_b
+        );
+
+        Ok(())
+    }
+}
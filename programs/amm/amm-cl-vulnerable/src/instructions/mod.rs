@@ -0,0 +1,13 @@
+// Instructions Module
+//
+// Exports all instruction handlers for the concentrated liquidity AMM program
+
+pub mod initialize_pool;
+pub mod open_position;
+pub mod close_position;
+pub mod swap;
+
+pub use initialize_pool::*;
+pub use open_position::*;
+pub use close_position::*;
+pub use swap::*;
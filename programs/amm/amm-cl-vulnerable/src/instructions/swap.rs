@@ -0,0 +1,184 @@
+// Swap Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// VULNERABILITY:
+// V002: Caller-chosen tick crossings - the swap crosses only the Tick accounts
+//       the caller passes, so leaving one out keeps a range's liquidity active
+//       past its edge
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, helpers::*, math::*, state::*};
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    pub trader: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, token_a_mint.key().as_ref(), token_b_mint.key().as_ref()],
+        bump = pool.bump,
+        has_one = token_a_mint,
+        has_one = token_b_mint,
+        has_one = token_a_vault,
+        has_one = token_b_vault,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_a_mint,
+        token::authority = trader,
+        token::token_program = token_program,
+    )]
+    pub trader_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_b_mint,
+        token::authority = trader,
+        token::token_program = token_program,
+    )]
+    pub trader_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> Swap<'info> {
+    pub fn swap(
+        &mut self,
+        amount_in: u64,
+        min_amount_out: u64,
+        a_to_b: bool,
+        tick_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(amount_in > 0, AmmClError::ZeroAmount);
+
+        let pool = &mut self.pool;
+        let mut tick_accounts = tick_accounts.iter();
+        let mut remaining = amount_in;
+        let mut total_in: u64 = 0;
+        let mut total_out: u64 = 0;
+
+        while remaining > 0 {
+            // VULNERABILITY V002: The next boundary is whatever Tick account the
+            // caller passes next; pool.ticks is never consulted
+            // Secure version:
+            //   let next_tick = if a_to_b { pool.next_tick_down() } else { pool.next_tick_up() };
+            //   ...and the supplied account must be this pool's Tick for that index
+            // Attack scenario (tick skipping):
+            // 1. Alice provides [-600, 600) at tick 0; Bob provides [1200, 2400),
+            //    which holds only token A
+            // 2. Attacker buys A with no tick accounts: the swap never crosses 600,
+            //    so Alice's liquidity keeps selling A above her range
+            // 3. That A comes out of Bob's deposit: the attacker receives more A than
+            //    Alice ever put in, and Bob can no longer close his position
+            let next_tick = tick_accounts.next().map(Tick::load).transpose()?;
+            let target = match &next_tick {
+                Some(tick) => sqrt_price_at_tick(tick.index)?,
+                None if a_to_b => min_sqrt_price()?,
+                None => max_sqrt_price()?,
+            };
+            require!(
+                if a_to_b { target <= pool.sqrt_price } else { target >= pool.sqrt_price },
+                AmmClError::InvalidTickAccount
+            );
+
+            let (sqrt_price, step_in, step_out) =
+                compute_swap_step(pool.sqrt_price, target, pool.liquidity, remaining, a_to_b)?;
+            remaining -= step_in;
+            total_in = total_in.checked_add(step_in).ok_or(AmmClError::Overflow)?;
+            total_out = total_out.checked_add(step_out).ok_or(AmmClError::Overflow)?;
+            pool.sqrt_price = sqrt_price;
+
+            // Input used up inside the range
+            if sqrt_price != target {
+                pool.current_tick = tick_at_sqrt_price(sqrt_price)?;
+                break;
+            }
+
+            // Reached the price bound: partial fill
+            let Some(tick) = next_tick else {
+                pool.current_tick = if a_to_b { MIN_TICK } else { MAX_TICK };
+                break;
+            };
+
+            pool.liquidity = cross_tick(pool.liquidity, tick.liquidity_net, a_to_b)?;
+            pool.current_tick = if a_to_b { tick.index - 1 } else { tick.index };
+        }
+
+        require!(total_out > 0, AmmClError::InsufficientLiquidity);
+        require!(total_out >= min_amount_out, AmmClError::SlippageExceeded);
+
+        let (mint_in, mint_out, vault_in, vault_out, trader_in, trader_out) = if a_to_b {
+            (
+                &self.token_a_mint,
+                &self.token_b_mint,
+                &self.token_a_vault,
+                &self.token_b_vault,
+                &self.trader_token_a,
+                &self.trader_token_b,
+            )
+        } else {
+            (
+                &self.token_b_mint,
+                &self.token_a_mint,
+                &self.token_b_vault,
+                &self.token_a_vault,
+                &self.trader_token_b,
+                &self.trader_token_a,
+            )
+        };
+
+        transfer_tokens(
+            total_in,
+            mint_in.decimals,
+            &self.token_program.to_account_info(),
+            &trader_in.to_account_info(),
+            &mint_in.to_account_info(),
+            &vault_in.to_account_info(),
+            &self.trader.to_account_info(),
+        )?;
+
+        let token_a_mint_key = self.token_a_mint.key();
+        let token_b_mint_key = self.token_b_mint.key();
+        let pool_seeds = &[
+            POOL_SEED,
+            token_a_mint_key.as_ref(),
+            token_b_mint_key.as_ref(),
+            &[self.pool.bump],
+        ];
+
+        transfer_from_vault(
+            total_out,
+            mint_out.decimals,
+            &self.token_program.to_account_info(),
+            &vault_out.to_account_info(),
+            &mint_out.to_account_info(),
+            &trader_out.to_account_info(),
+            &self.pool.to_account_info(),
+            pool_seeds,
+        )?;
+
+        msg!(
+            "Swapped {} in for {} out, price now at tick {}",
+            total_in,
+            total_out,
+            self.pool.current_tick
+        );
+
+        Ok(())
+    }
+}
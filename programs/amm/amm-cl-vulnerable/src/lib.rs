@@ -0,0 +1,64 @@
+// Concentrated Liquidity AMM Program - VULNERABLE VERSION
+//
+// WARNING: This program contains intentional security vulnerabilities for educational purposes.
+// NEVER deploy this to production. Use amm-cl-secure instead.
+//
+// This vulnerable concentrated liquidity AMM demonstrates two range-position flaws:
+// - Closing positions without checking who owns them
+// - Letting the swap caller decide which ticks get crossed
+//
+// VULNERABILITIES DOCUMENTED:
+// See individual instruction files for detailed vulnerability explanations.
+// Each vulnerability is marked with VULNERABILITY comments.
+//
+// PRICE MODEL:
+// Tick i has price 1.0001^i (token B per token A). Pools store the sqrt price
+// in Q32.32 fixed point; see math.rs. There is no swap fee.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+pub mod math;
+
+use instructions::*;
+
+declare_id!("47W3KnvZdBm5YtiXppPjufmPZJnPLcsAeiFvq4SCuqwC");
+
+#[program]
+pub mod amm_cl_vulnerable {
+    use super::*;
+
+    // Create the pool and its vaults at the price of `initial_tick`
+    pub fn initialize_pool(ctx: Context<InitializePool>, tick_spacing: u16, initial_tick: i32) -> Result<()> {
+        ctx.accounts.initialize_pool(tick_spacing, initial_tick, &ctx.bumps)
+    }
+
+    // Provide liquidity over [lower_tick, upper_tick)
+    pub fn open_position(
+        ctx: Context<OpenPosition>,
+        lower_tick: i32,
+        upper_tick: i32,
+        liquidity: u64,
+    ) -> Result<()> {
+        ctx.accounts.open_position(lower_tick, upper_tick, liquidity, &ctx.bumps)
+    }
+
+    // Withdraw a position's liquidity and close it
+    pub fn close_position(ctx: Context<ClosePosition>) -> Result<()> {
+        ctx.accounts.close_position()
+    }
+
+    // Swap along the curve; crossed Tick accounts go in remaining accounts
+    pub fn swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
+        amount_in: u64,
+        min_amount_out: u64,
+        a_to_b: bool,
+    ) -> Result<()> {
+        ctx.accounts.swap(amount_in, min_amount_out, a_to_b, ctx.remaining_accounts)
+    }
+}
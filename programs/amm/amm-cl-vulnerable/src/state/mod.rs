@@ -0,0 +1,11 @@
+// State Module
+//
+// Exports all state structures used by the concentrated liquidity AMM program
+
+pub mod pool;
+pub mod tick;
+pub mod position;
+
+pub use pool::*;
+pub use tick::*;
+pub use position::*;
@@ -0,0 +1,86 @@
+// Pool State
+//
+// One Pool per token pair. Holds the swap price and the liquidity active at
+// that price: the summed liquidity of every position whose range contains the
+// current tick.
+//
+// `ticks` is the sorted list of initialized ticks (edges of at least one open
+// position).
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*};
+
+#[account]
+#[derive(InitSpace)]
+pub struct Pool {
+    pub token_a_mint: Pubkey,
+
+    pub token_b_mint: Pubkey,
+
+    // Vaults: ATAs of the pool PDA
+    pub token_a_vault: Pubkey,
+
+    pub token_b_vault: Pubkey,
+
+    // Position edges must be multiples of this
+    pub tick_spacing: u16,
+
+    // Current sqrt price, Q32.32 (see math.rs)
+    pub sqrt_price: u64,
+
+    // Tick containing the current price
+    pub current_tick: i32,
+
+    // Liquidity of all positions with lower_tick <= current_tick < upper_tick
+    pub liquidity: u64,
+
+    // Initialized ticks in ascending order
+    #[max_len(MAX_INITIALIZED_TICKS)]
+    pub ticks: Vec<i32>,
+
+    // PDA bump seed
+    pub bump: u8,
+}
+
+impl Pool {
+    // Check a position range against the pool's bounds and spacing
+    pub fn validate_range(&self, lower_tick: i32, upper_tick: i32) -> Result<()> {
+        require!(lower_tick < upper_tick, AmmClError::InvalidTickRange);
+        require!(
+            lower_tick >= MIN_TICK && upper_tick <= MAX_TICK,
+            AmmClError::TickOutOfBounds
+        );
+
+        let spacing = self.tick_spacing as i32;
+        require!(
+            lower_tick % spacing == 0 && upper_tick % spacing == 0,
+            AmmClError::TickNotAligned
+        );
+
+        Ok(())
+    }
+
+    // Whether a position over [lower_tick, upper_tick) is active at the current price
+    pub fn in_range(&self, lower_tick: i32, upper_tick: i32) -> bool {
+        lower_tick <= self.current_tick && self.current_tick < upper_tick
+    }
+
+    // Record a newly initialized tick
+    pub fn insert_tick(&mut self, tick: i32) -> Result<()> {
+        if let Err(index) = self.ticks.binary_search(&tick) {
+            require!(
+                self.ticks.len() < MAX_INITIALIZED_TICKS,
+                AmmClError::TooManyTicks
+            );
+            self.ticks.insert(index, tick);
+        }
+        Ok(())
+    }
+
+    // Forget a tick no position references any more
+    pub fn remove_tick(&mut self, tick: i32) {
+        if let Ok(index) = self.ticks.binary_search(&tick) {
+            self.ticks.remove(index);
+        }
+    }
+}
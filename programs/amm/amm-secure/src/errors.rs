@@ -1,6 +1,8 @@
 
 use anchor_lang::prelude::*;
 
+pub use soteria_errors::amm_secure::AmmError as AmmErrorCode;
+
 #[error_code]
 pub enum AmmError {
     #[msg("Fee basis points cannot exceed maximum allowed (1000 = 10%)")]
@@ -80,4 +82,130 @@ pub enum AmmError {
 
     #[msg("Invalid curve parameters provided")]
     InvalidCurveParams,
+
+    #[msg("Flash loan amount cannot be zero")]
+    ZeroFlashLoanAmount,
+
+    #[msg("A flash loan is already active for this pool")]
+    FlashLoanAlreadyActive,
+
+    #[msg("No flash loan is active for this pool")]
+    NoActiveFlashLoan,
+
+    #[msg("Pool operations are disabled while a flash loan is in progress")]
+    FlashLoanInProgress,
+
+    #[msg("Flash loan begin must be followed by flash loan end in the same transaction")]
+    MissingFlashLoanEnd,
+
+    #[msg("Flash loan begin cannot be invoked via CPI")]
+    FlashLoanCpiNotAllowed,
+
+    #[msg("Flash loan was not repaid with the required fee")]
+    FlashLoanNotRepaid,
+
+    #[msg("Only the pool creation authority can create pools")]
+    UnauthorizedPoolCreator,
+
+    #[msg("Fee is not one of the whitelisted fee tiers")]
+    FeeTierNotWhitelisted,
+
+    #[msg("Fee tiers must contain between 1 and MAX_FEE_TIERS entries")]
+    InvalidFeeTierCount,
+
+    #[msg("Fee tiers cannot contain duplicates")]
+    DuplicateFeeTier,
+
+    #[msg("No fee change has been requested")]
+    NoPendingFeeChange,
+
+    #[msg("Fee change timelock has not elapsed yet")]
+    FeeChangeTimelockActive,
+
+    #[msg("Pool is not locked")]
+    PoolNotLocked,
+
+    #[msg("Emergency withdraw is not enabled for this pool")]
+    EmergencyWithdrawDisabled,
+
+    #[msg("Referral fee share exceeds maximum")]
+    ReferralFeeTooHigh,
+
+    #[msg("Invalid referrer token account")]
+    InvalidReferrer,
+
+    #[msg("Invalid oracle guard configuration")]
+    InvalidOracleConfig,
+
+    #[msg("Pool has an oracle guard but no price feed was passed")]
+    PriceFeedRequired,
+
+    #[msg("Price feed does not match the pool's oracle configuration")]
+    InvalidPriceFeed,
+
+    #[msg("Oracle price has not been published")]
+    OraclePriceUnavailable,
+
+    #[msg("Oracle price is stale")]
+    StaleOraclePrice,
+
+    #[msg("Oracle confidence interval is too wide")]
+    OracleConfidenceTooWide,
+
+    #[msg("Pool price deviates too far from the oracle price")]
+    OraclePriceDeviation,
+
+    #[msg("Position URI too long")]
+    PositionUriTooLong,
+
+    #[msg("Invalid Metaplex Core program")]
+    InvalidMplCoreProgram,
+
+    #[msg("Signer does not own the position NFT")]
+    PositionOwnerMismatch,
+
+    #[msg("Position belongs to a different pool")]
+    PositionPoolMismatch,
+
+    #[msg("Invalid circuit breaker configuration")]
+    InvalidCircuitBreakerConfig,
+
+    #[msg("Swap moves the pool price more than the circuit breaker allows")]
+    PriceImpactTooHigh,
+
+    #[msg("Neither pool token is wrapped SOL")]
+    NotNativeSolPool,
+
+    #[msg("Temporary wSOL account must start empty")]
+    WrappedSolAccountNotEmpty,
+
+    #[msg("Temporary wSOL account was not refunded to its owner")]
+    RentRefundMismatch,
+
+    #[msg("Limit order has expired")]
+    LimitOrderExpired,
+
+    #[msg("Pool price has not reached the order's limit")]
+    LimitPriceNotReached,
+
+    #[msg("Position has no fees to claim")]
+    NoFeesToClaim,
+
+    #[msg("Destination pool is not another pool of the same token pair")]
+    InvalidMigrationPool,
+
+    #[msg("Invalid per-slot swap limit")]
+    InvalidSwapLimitConfig,
+
+    #[msg("Swap input in this slot exceeds the pool's per-slot limit")]
+    SlotSwapLimitExceeded,
+
+    #[msg("Pool invariant violated: k per LP token decreased")]
+    InvariantViolated,
+
+    #[msg("Program data account does not belong to this program")]
+    InvalidProgramData,
+
+    #[msg("Signer is not the AMM config admin")]
+    UnauthorizedConfigAdmin,
 }
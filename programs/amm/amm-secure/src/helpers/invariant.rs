@@ -0,0 +1,110 @@
+// Pool Invariant Helpers
+//
+// Defense in depth for the constant product pool. Swaps, deposits and
+// withdrawals snapshot the vault balances and LP supply before moving any
+// tokens, and check the balances they leave behind against that snapshot.
+// The pricing math already rounds in the pool's favor; this catches any
+// path where it does not, before the transaction can land.
+//
+// The invariant is k per LP token squared: reserve_a * reserve_b / lp_supply^2
+// - A swap keeps lp_supply, so k itself must not drop
+// - A deposit or withdrawal moves reserves and LP supply in proportion, so the
+//   ratio holds as long as every rounding step favors the pool
+//
+// Both sides are cross-multiplied into 256-bit products of four u64 values and
+// compared exactly, so reserves and supplies up to u64::MAX never overflow or
+// round the check in anyone's favor.
+
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+
+// Vault balances and LP supply at one point of an instruction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoolSnapshot {
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+    pub lp_supply: u64,
+}
+
+impl PoolSnapshot {
+    pub fn new(reserve_a: u64, reserve_b: u64, lp_supply: u64) -> Self {
+        Self {
+            reserve_a,
+            reserve_b,
+            lp_supply,
+        }
+    }
+
+    // Snapshot for swaps, which never mint or burn LP tokens
+    // The same LP supply on both sides of the check cancels out, so 1 stands in for it
+    pub fn reserves(reserve_a: u64, reserve_b: u64) -> Self {
+        Self::new(reserve_a, reserve_b, 1)
+    }
+}
+
+// Assert the instruction did not lower k per LP token
+// `fees` are the amounts (token A, token B) the instruction paid out of the
+// reserves as a share of its fee, i.e. a swap's referral payout. They were part
+// of what the swapper paid in, so they are added back to `post` and the trade
+// is held to k on its own. Deposits and withdrawals pass (0, 0).
+//
+// Skipped when `pre` has no LP supply: a first deposit has nothing to compare to
+pub fn assert_k_not_decreased(
+    pre: PoolSnapshot,
+    post: PoolSnapshot,
+    fees: (u64, u64),
+) -> Result<()> {
+    if pre.lp_supply == 0 {
+        return Ok(());
+    }
+
+    // Fees were paid out of the vaults, so adding them back always fits in u64
+    let post_a = post
+        .reserve_a
+        .checked_add(fees.0)
+        .ok_or(AmmError::Overflow)?;
+    let post_b = post
+        .reserve_b
+        .checked_add(fees.1)
+        .ok_or(AmmError::Overflow)?;
+
+    // post_a * post_b / post_lp^2 >= pre_a * pre_b / pre_lp^2, cross-multiplied
+    // Each factor is a product of two u64 values, so it always fits in u128
+    let post_side = widening_mul(
+        post_a as u128 * post_b as u128,
+        pre.lp_supply as u128 * pre.lp_supply as u128,
+    );
+    let pre_side = widening_mul(
+        pre.reserve_a as u128 * pre.reserve_b as u128,
+        post.lp_supply as u128 * post.lp_supply as u128,
+    );
+
+    require!(post_side >= pre_side, AmmError::InvariantViolated);
+
+    Ok(())
+}
+
+// Full 256-bit product of two u128 values, as (high, low) halves
+// Tuples compare the high half first, so results order like the products
+fn widening_mul(x: u128, y: u128) -> (u128, u128) {
+    const LOW_BITS: u128 = u64::MAX as u128;
+
+    let (x_high, x_low) = (x >> 64, x & LOW_BITS);
+    let (y_high, y_low) = (y >> 64, y & LOW_BITS);
+
+    // Four 64x64-bit partial products, none of which can overflow u128
+    let low_low = x_low * y_low;
+    let high_low = x_high * y_low;
+    let low_high = x_low * y_high;
+    let high_high = x_high * y_high;
+
+    // Middle 64-bit column: carry out of the low column plus the low halves
+    // of both cross terms, at most 3 * (2^64 - 1)
+    let middle = (low_low >> 64) + (high_low & LOW_BITS) + (low_high & LOW_BITS);
+
+    let low = (middle << 64) | (low_low & LOW_BITS);
+    let high = high_high + (high_low >> 64) + (low_high >> 64) + (middle >> 64);
+
+    (high, low)
+}
@@ -0,0 +1,725 @@
+// AMM Helper Functions
+//
+// Reusable calculation and CPI helpers for the AMM program.
+// These functions reduce code duplication across instructions.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions},
+    state::Mint as MintState,
+};
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token_interface::{
+    Burn, CloseAccount, Mint, MintTo, SyncNative, TransferChecked, burn, close_account, mint_to,
+    sync_native, transfer_checked,
+};
+
+use soteria_common::{math::*, time::seconds_until};
+
+use crate::{constants::*, errors::*};
+
+pub mod invariant;
+
+pub use invariant::*;
+
+// VALIDATION HELPERS
+
+// Validate transaction expiration timestamp
+// Ensures transaction is not expired and not too far in the future
+// Used in deposit, withdraw, and swap instructions
+pub fn validate_expiration(expiration: i64) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Transaction must not be expired
+    let time_until_expiration = seconds_until(current_time, expiration)
+        .ok_or(AmmError::TransactionExpired)?;
+
+    // Expiration cannot be more than MAX_EXPIRATION_SECONDS in the future
+    require!(
+        time_until_expiration <= MAX_EXPIRATION_SECONDS,
+        AmmError::ExpirationTooFar
+    );
+
+    Ok(())
+}
+
+// LIQUIDITY CALCULATION HELPERS
+
+// Calculate LP tokens for first deposit (pool initialization)
+// Uses geometric mean formula: LP = sqrt(a * b) - MINIMUM_LIQUIDITY
+// The MINIMUM_LIQUIDITY is permanently locked to prevent inflation attacks
+//
+// Why lock minimum liquidity?
+// Without locking, an attacker could:
+// 1. Create pool with 1 wei of each token
+// 2. Receive sqrt(1*1) = 1 LP token
+// 3. Donate large amounts to inflate LP token value
+// 4. Small depositors get rounded to 0 LP tokens
+pub fn calculate_first_deposit(amount_a: u64, amount_b: u64) -> Result<(u64, u64, u64)> {
+    // Calculate product with overflow protection
+    let product = (amount_a as u128)
+        .checked_mul(amount_b as u128)
+        .ok_or(AmmError::Overflow)?;
+
+    // Geometric mean provides initial liquidity valuation
+    // sqrt(a * b) ensures equal weighting of both tokens
+    // Using f64 for simplicity (production may use integer sqrt for precision)
+    let liquidity = (product as f64).sqrt() as u64;
+
+    // Ensure sufficient liquidity for minimum lock
+    require!(liquidity > MINIMUM_LIQUIDITY, AmmError::InsufficientLiquidity);
+
+    // Lock MINIMUM_LIQUIDITY permanently by not minting those LP tokens
+    // This protects against inflation attacks
+    let lp_tokens = liquidity
+        .checked_sub(MINIMUM_LIQUIDITY)
+        .ok_or(AmmError::Underflow)?;
+
+    Ok((amount_a, amount_b, lp_tokens))
+}
+
+// Calculate proportional deposit for existing pool
+// Maintains current pool ratio to prevent price manipulation
+// Formula: LP_minted = min(desired_a/vault_a, desired_b/vault_b) * lp_supply
+//
+// Why use min()?
+// Using minimum ensures depositor cannot manipulate pool price.
+// Excess tokens are not deposited, maintaining the pool ratio.
+pub fn calculate_subsequent_deposit(
+    desired_a: u64,
+    desired_b: u64,
+    vault_a: u64,
+    vault_b: u64,
+    lp_supply: u64,
+) -> Result<(u64, u64, u64)> {
+    // Calculate LP tokens if only depositing token A
+    // Formula: LP = (desired_a / vault_a) * lp_supply
+    require!(vault_a > 0 && vault_b > 0 && lp_supply > 0, AmmError::DivisionByZero);
+
+    let lp_from_a = mul_div_floor(desired_a, lp_supply, vault_a).ok_or(AmmError::Overflow)?;
+
+    // Calculate LP tokens if only depositing token B
+    let lp_from_b = mul_div_floor(desired_b, lp_supply, vault_b).ok_or(AmmError::Overflow)?;
+
+    // Use minimum to maintain pool ratio
+    // This prevents price manipulation
+    let lp_to_mint = std::cmp::min(lp_from_a, lp_from_b);
+
+    // Calculate actual token amounts needed based on LP to mint
+    // These amounts maintain the pool's current ratio
+    // Rounded up so the new LP tokens never buy in below the pool's value per LP;
+    // lp_to_mint was rounded down from the desired amounts, so they still cap the result.
+    // Rounding down here would let every deposit take up to one unit of each token
+    // from the existing LPs, and the deposit would then fail assert_k_not_decreased
+    let amount_a = mul_div_ceil(lp_to_mint, vault_a, lp_supply).ok_or(AmmError::Overflow)?;
+    let amount_b = mul_div_ceil(lp_to_mint, vault_b, lp_supply).ok_or(AmmError::Overflow)?;
+
+    Ok((amount_a, amount_b, lp_to_mint))
+}
+
+// Calculate withdrawal amounts when burning LP tokens
+// Returns proportional share of both tokens
+// Formula: amount = (lp_burned / lp_supply) * vault_balance
+pub fn calculate_withdrawal(
+    lp_to_burn: u64,
+    vault_a: u64,
+    vault_b: u64,
+    lp_supply: u64,
+) -> Result<(u64, u64)> {
+    require!(lp_supply > 0, AmmError::DivisionByZero);
+
+    // Calculate token A to withdraw
+    let amount_a = mul_div_floor(lp_to_burn, vault_a, lp_supply).ok_or(AmmError::Overflow)?;
+
+    // Calculate token B to withdraw
+    let amount_b = mul_div_floor(lp_to_burn, vault_b, lp_supply).ok_or(AmmError::Overflow)?;
+
+    Ok((amount_a, amount_b))
+}
+
+// Calculate the entry price recorded on an LP position
+// Token B per token A in base units, scaled by POSITION_PRICE_SCALE
+// Formula: entry_price = amount_b * POSITION_PRICE_SCALE / amount_a, rounded down
+pub fn calculate_entry_price(amount_a: u64, amount_b: u64) -> Result<u64> {
+    require!(amount_a > 0, AmmError::DivisionByZero);
+
+    let entry_price =
+        mul_div_floor(amount_b, POSITION_PRICE_SCALE, amount_a).ok_or(AmmError::Overflow)?;
+
+    Ok(entry_price)
+}
+
+// LP FEE HELPERS
+
+// Calculate the pool's fee growth: liquidity per LP token, as Q64.64
+// Formula: fee_growth = sqrt(reserve_a * reserve_b) * 2^64 / lp_supply
+//
+// Why does this measure fees?
+// Deposits and withdrawals add or remove liquidity and LP tokens in the same
+// proportion, and a swap keeps reserve_a * reserve_b constant before its fee.
+// What is left to move liquidity per LP token is the fee paid into the pool.
+//
+// round_up is used for checkpoints and rounding down for the current value,
+// so claims measured between the two never exceed what was earned
+pub fn calculate_fee_growth(
+    reserve_a: u64,
+    reserve_b: u64,
+    lp_supply: u64,
+    round_up: bool,
+) -> Result<u128> {
+    require!(lp_supply > 0, AmmError::DivisionByZero);
+
+    let product = (reserve_a as u128)
+        .checked_mul(reserve_b as u128)
+        .ok_or(AmmError::Overflow)?;
+
+    let mut liquidity = integer_sqrt(product);
+    if round_up && liquidity * liquidity < product {
+        liquidity += 1;
+    }
+
+    let scaled = liquidity
+        .checked_mul(1u128 << FEE_GROWTH_FRACTIONAL_BITS)
+        .ok_or(AmmError::Overflow)?;
+
+    let mut fee_growth = scaled / lp_supply as u128;
+    if round_up && scaled % lp_supply as u128 != 0 {
+        fee_growth += 1;
+    }
+
+    Ok(fee_growth)
+}
+
+// Calculate a position's accrued fees, as LP tokens to redeem
+// The position keeps enough LP tokens to be worth what it was at the checkpoint;
+// the rest is fees. Formula: fee_lp = lp_tokens - ceil(lp_tokens * checkpoint / fee_growth)
+//
+// Both Q64.64 values are shifted down to fit u64 first. The checkpoint rounds up
+// and the current growth down, so the kept share rounds up in the pool's favor
+pub fn calculate_claimable_lp_fees(
+    lp_tokens: u64,
+    fee_growth_checkpoint: u128,
+    fee_growth: u128,
+) -> Result<u64> {
+    if fee_growth <= fee_growth_checkpoint {
+        return Ok(0);
+    }
+
+    let shift = u64::BITS.saturating_sub(fee_growth.leading_zeros());
+    let growth = fee_growth >> shift;
+    let mut checkpoint = fee_growth_checkpoint >> shift;
+    if checkpoint << shift != fee_growth_checkpoint {
+        checkpoint += 1;
+    }
+
+    // A checkpoint rounded up to the growth leaves nothing to claim
+    let checkpoint = checkpoint.min(growth);
+    let retained = mul_div_ceil(lp_tokens, checkpoint as u64, growth as u64)
+        .ok_or(AmmError::Overflow)?;
+
+    let fee_lp = lp_tokens.checked_sub(retained).ok_or(AmmError::Underflow)?;
+
+    Ok(fee_lp)
+}
+
+// Integer square root, rounded down (Newton's method)
+fn integer_sqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    let mut x = value;
+    let mut y = value / 2 + (value & 1);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+
+    x
+}
+
+// SWAP CALCULATION HELPERS
+
+// Calculate the input required to receive exactly `output_amount` (exact-output swap)
+// Inverse of the constant product formula with the swap fee taken from the input:
+//   net_input   = ceil(input_reserve * output_amount / (output_reserve - output_amount))
+//   gross_input = ceil(net_input * 10_000 / (10_000 - fee_basis_points))
+//
+// Why round up?
+// Every rounding step must favor the pool. Rounding down lets a swapper request
+// small outputs whose true cost is a fraction of a token and pay nothing for them,
+// draining the output vault one free swap at a time.
+pub fn calculate_exact_out_input(
+    input_reserve: u64,
+    output_reserve: u64,
+    output_amount: u64,
+    fee_basis_points: u16,
+) -> Result<u64> {
+    // Pool can never give out its entire reserve
+    require!(
+        output_amount < output_reserve,
+        AmmError::InsufficientPoolLiquidity
+    );
+
+    let numerator = (input_reserve as u128)
+        .checked_mul(output_amount as u128)
+        .ok_or(AmmError::Overflow)?;
+
+    let denominator = (output_reserve as u128)
+        .checked_sub(output_amount as u128)
+        .ok_or(AmmError::Underflow)?;
+
+    // Ceiling division: (n + d - 1) / d
+    let net_input = numerator
+        .checked_add(denominator - 1)
+        .ok_or(AmmError::Overflow)?
+        .checked_div(denominator)
+        .ok_or(AmmError::DivisionByZero)?;
+
+    // Gross up for the swap fee, rounding up again
+    let fee_denominator = 10_000u128
+        .checked_sub(fee_basis_points as u128)
+        .ok_or(AmmError::Underflow)?;
+    require!(fee_denominator > 0, AmmError::InvalidCurveParams);
+
+    let gross_input = net_input
+        .checked_mul(10_000)
+        .ok_or(AmmError::Overflow)?
+        .checked_add(fee_denominator - 1)
+        .ok_or(AmmError::Overflow)?
+        .checked_div(fee_denominator)
+        .ok_or(AmmError::DivisionByZero)?;
+
+    u64::try_from(gross_input).map_err(|_| error!(AmmError::Overflow))
+}
+
+// FLASH LOAN HELPERS
+
+// Calculate the fee owed on a flash loan
+// Rounds up so even the smallest loan pays at least 1 token to LPs
+// Formula: fee = ceil(amount * FLASH_LOAN_FEE_BASIS_POINTS / 10_000)
+pub fn calculate_flash_loan_fee(amount: u64) -> Result<u64> {
+    let fee = apply_bps_ceil(amount, FLASH_LOAN_FEE_BASIS_POINTS).ok_or(AmmError::Overflow)?;
+
+    Ok(fee)
+}
+
+// REFERRAL HELPERS
+
+// Calculate the referrer's slice of the swap fee
+// Rounds down so the referral can never exceed its share of the fee
+// Formula: referral = amount * fee_basis_points * referral_fee_basis_points / 10_000^2
+pub fn calculate_referral_fee(
+    amount: u64,
+    fee_basis_points: u16,
+    referral_fee_basis_points: u16,
+) -> Result<u64> {
+    // Both rates are u16, so their product always fits in u64
+    let combined_bps = fee_basis_points as u64 * referral_fee_basis_points as u64;
+    let referral = mul_div_floor(amount, combined_bps, BASIS_POINTS_DIVISOR * BASIS_POINTS_DIVISOR)
+        .ok_or(AmmError::Overflow)?;
+
+    Ok(referral)
+}
+
+// FEE REBATE HELPERS
+
+// Swap fee charged to a swapper holding lp_balance LP tokens of the pool
+// At or above FEE_REBATE_MIN_LP_TOKENS, FEE_REBATE_BASIS_POINTS of the fee is waived
+// The waived part rounds down so the rebate never exceeds its share of the fee
+pub fn calculate_rebated_fee(fee_basis_points: u16, lp_balance: u64) -> Result<u16> {
+    if lp_balance < FEE_REBATE_MIN_LP_TOKENS {
+        return Ok(fee_basis_points);
+    }
+
+    // Both rates are u16, so their product always fits in u64
+    let rebate = fee_basis_points as u64 * FEE_REBATE_BASIS_POINTS as u64 / BASIS_POINTS_DIVISOR;
+    let rebated_fee = (fee_basis_points as u64)
+        .checked_sub(rebate)
+        .ok_or(AmmError::Underflow)?;
+
+    Ok(rebated_fee as u16)
+}
+
+// ORACLE HELPERS
+
+// Distance between the pool price and the oracle price, in basis points of the oracle price
+// Both prices are token B per token A in UI units:
+// pool   = (reserve_b / 10^decimals_b) / (reserve_a / 10^decimals_a)
+// oracle = price * 10^exponent
+// Formula: deviation = |pool - oracle| * 10_000 / oracle, rounded down
+pub fn calculate_oracle_deviation_bps(
+    reserve_a: u64,
+    reserve_b: u64,
+    decimals_a: u8,
+    decimals_b: u8,
+    price: u64,
+    exponent: i32,
+) -> Result<u128> {
+    require!(exponent <= 0, AmmError::InvalidPriceFeed);
+    require!(price > 0, AmmError::OraclePriceUnavailable);
+    require!(reserve_a > 0, AmmError::InsufficientPoolLiquidity);
+
+    // Cross-multiply to stay in integers, cancelling the shared power of ten:
+    // pool_value   = reserve_b * 10^(decimals_a - exponent)
+    // oracle_value = price * reserve_a * 10^decimals_b
+    let pool_exponent = decimals_a as u32 + exponent.unsigned_abs();
+    let oracle_exponent = decimals_b as u32;
+    let shared = pool_exponent.min(oracle_exponent);
+
+    let pool_value = 10u128
+        .checked_pow(pool_exponent - shared)
+        .and_then(|scale| scale.checked_mul(reserve_b as u128))
+        .ok_or(AmmError::Overflow)?;
+    let oracle_value = 10u128
+        .checked_pow(oracle_exponent - shared)
+        .and_then(|scale| scale.checked_mul(reserve_a as u128))
+        .and_then(|value| value.checked_mul(price as u128))
+        .ok_or(AmmError::Overflow)?;
+
+    let deviation = pool_value
+        .abs_diff(oracle_value)
+        .checked_mul(BASIS_POINTS_DIVISOR as u128)
+        .ok_or(AmmError::Overflow)?
+        .checked_div(oracle_value)
+        .ok_or(AmmError::DivisionByZero)?;
+
+    Ok(deviation)
+}
+
+// CIRCUIT BREAKER HELPERS
+
+// How far a swap moves the price of the input token, in basis points
+// The input token is priced in output tokens before and after the swap:
+// before = output_reserve / input_reserve
+// after  = (output_reserve - output_removed) / (input_reserve + input_added)
+// Formula: impact = (1 - after / before) * 10_000, with after / before computed
+// in PRICE_IMPACT_SCALE fixed point
+//
+// The price ratio rounds down and the impact rounds up, so a swap just over
+// the limit is never let through by truncation
+pub fn calculate_price_impact_bps(
+    input_reserve: u64,
+    output_reserve: u64,
+    input_added: u64,
+    output_removed: u64,
+) -> Result<u64> {
+    require!(input_reserve > 0, AmmError::InsufficientPoolLiquidity);
+    require!(output_reserve > 0, AmmError::InsufficientPoolLiquidity);
+
+    let output_kept = output_reserve
+        .checked_sub(output_removed)
+        .ok_or(AmmError::InsufficientPoolLiquidity)?;
+    let input_after = input_reserve
+        .checked_add(input_added)
+        .ok_or(AmmError::Overflow)?;
+
+    // after / before = (output_kept / output_reserve) * (input_reserve / input_after)
+    let output_ratio = mul_div_floor(output_kept, PRICE_IMPACT_SCALE, output_reserve)
+        .ok_or(AmmError::Overflow)?;
+    let price_ratio = mul_div_floor(output_ratio, input_reserve, input_after)
+        .ok_or(AmmError::Overflow)?;
+
+    let impact = mul_div_ceil(
+        PRICE_IMPACT_SCALE - price_ratio,
+        BASIS_POINTS_DIVISOR,
+        PRICE_IMPACT_SCALE,
+    )
+    .ok_or(AmmError::Overflow)?;
+
+    Ok(impact)
+}
+
+// TOKEN-2022 HELPERS
+
+// Calculate the transfer fee withheld when sending `amount` of a mint
+// Returns 0 for legacy SPL Token mints and Token-2022 mints without the
+// TransferFeeConfig extension
+//
+// Why this matters:
+// With a transfer fee mint, the destination receives `amount - fee`.
+// If the pool priced swaps off the gross amount, every swap would pay out
+// more than the vault actually received and LPs would slowly be drained.
+pub fn calculate_transfer_fee(mint: &InterfaceAccount<Mint>, amount: u64) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+
+    // Legacy SPL Token mints have no extensions
+    if *mint_info.owner == anchor_spl::token::ID {
+        return Ok(0);
+    }
+
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+
+    let fee = match mint_state.get_extension::<TransferFeeConfig>() {
+        Ok(transfer_fee_config) => transfer_fee_config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(AmmError::Overflow)?,
+        Err(_) => 0,
+    };
+
+    Ok(fee)
+}
+
+// Calculate the fee that must be added on top of `post_fee_amount` so the
+// destination receives exactly `post_fee_amount`
+// Used by deposits so the vault is credited the amount the LP math expects
+pub fn calculate_inverse_transfer_fee(
+    mint: &InterfaceAccount<Mint>,
+    post_fee_amount: u64,
+) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+
+    if *mint_info.owner == anchor_spl::token::ID {
+        return Ok(0);
+    }
+
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+
+    let fee = match mint_state.get_extension::<TransferFeeConfig>() {
+        Ok(transfer_fee_config) => transfer_fee_config
+            .calculate_inverse_epoch_fee(Clock::get()?.epoch, post_fee_amount)
+            .ok_or(AmmError::Overflow)?,
+        Err(_) => 0,
+    };
+
+    Ok(fee)
+}
+
+// NATIVE SOL HELPERS
+
+// Which side of the pool is wrapped SOL
+// Returns true for token A, false for token B
+// Used by deposit_liquidity_sol and swap_sol_for_token
+pub fn native_sol_is_token_a(token_a_mint: &Pubkey, token_b_mint: &Pubkey) -> Result<bool> {
+    if *token_a_mint == native_mint::ID {
+        Ok(true)
+    } else if *token_b_mint == native_mint::ID {
+        Ok(false)
+    } else {
+        err!(AmmError::NotNativeSolPool)
+    }
+}
+
+// CPI HELPERS
+
+// Generic token transfer helper
+// Used for transferring tokens to/from vaults
+// Uses transfer_checked so the same CPI works for SPL Token and Token-2022 mints
+// (Token-2022 rejects plain `transfer` for mints with transfer fee extensions)
+pub fn transfer_tokens<'info>(
+    amount: u64,
+    decimals: u8,
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+) -> Result<()> {
+    transfer_checked(
+        CpiContext::new(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+        ),
+        amount,
+        decimals,
+    )
+}
+
+// Transfer tokens from vault (requires PDA signer)
+// Used in withdraw and swap instructions
+pub fn transfer_from_vault<'info>(
+    amount: u64,
+    decimals: u8,
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let signer_seeds = &[authority_seeds];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )
+}
+
+// Close a token account owned by a PDA (requires PDA signer)
+// Used when a limit order's escrow is emptied by a fill or a cancel
+// Rent goes to `destination`
+pub fn close_from_pda<'info>(
+    token_program: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let signer_seeds = &[authority_seeds];
+
+    close_account(CpiContext::new_with_signer(
+        token_program.clone(),
+        CloseAccount {
+            account: account.clone(),
+            destination: destination.clone(),
+            authority: authority.clone(),
+        },
+        signer_seeds,
+    ))
+}
+
+// Mint LP tokens (requires PDA authority)
+// Used when depositing liquidity
+pub fn mint_lp_tokens<'info>(
+    amount: u64,
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let signer_seeds = &[authority_seeds];
+
+    mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            MintTo {
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+// Burn LP tokens
+// Used when withdrawing liquidity
+// User burns LP tokens to receive their proportional share
+pub fn burn_lp_tokens<'info>(
+    amount: u64,
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+) -> Result<()> {
+    burn(
+        CpiContext::new(
+            token_program.clone(),
+            Burn {
+                mint: mint.clone(),
+                from: from.clone(),
+                authority: authority.clone(),
+            },
+        ),
+        amount,
+    )
+}
+
+// Burn LP tokens held by the pool authority (requires PDA signer)
+// Used when withdrawing an LP position, whose tokens sit in the position LP vault
+pub fn burn_lp_tokens_from_vault<'info>(
+    amount: u64,
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let signer_seeds = &[authority_seeds];
+
+    burn(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            Burn {
+                mint: mint.clone(),
+                from: from.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+// Wrap SOL into the owner's temporary wSOL account
+// Moves `amount` lamports in, then syncs the token balance to match
+pub fn wrap_sol<'info>(
+    amount: u64,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    wrapped_sol_account: &AccountInfo<'info>,
+) -> Result<()> {
+    transfer(
+        CpiContext::new(
+            system_program.clone(),
+            Transfer {
+                from: owner.clone(),
+                to: wrapped_sol_account.clone(),
+            },
+        ),
+        amount,
+    )?;
+
+    sync_native(CpiContext::new(
+        token_program.clone(),
+        SyncNative {
+            account: wrapped_sol_account.clone(),
+        },
+    ))
+}
+
+// Close the owner's temporary wSOL account back to the owner
+// Returns the rent and any wrapped SOL the operation did not spend
+//
+// Why check the refund?
+// The account was opened and funded by the owner within this instruction.
+// Every lamport it held must come back to them, so the owner's balance has to
+// rise by exactly what the account held and the account must be left empty.
+pub fn close_wrapped_sol<'info>(
+    token_program: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    wrapped_sol_account: &AccountInfo<'info>,
+) -> Result<()> {
+    let refund = wrapped_sol_account.lamports();
+    let owner_balance_before = owner.lamports();
+
+    close_account(CpiContext::new(
+        token_program.clone(),
+        CloseAccount {
+            account: wrapped_sol_account.clone(),
+            destination: owner.clone(),
+            authority: owner.clone(),
+        },
+    ))?;
+
+    let expected_balance = owner_balance_before
+        .checked_add(refund)
+        .ok_or(AmmError::Overflow)?;
+    require!(
+        wrapped_sol_account.lamports() == 0 && owner.lamports() == expected_balance,
+        AmmError::RentRefundMismatch
+    );
+
+    Ok(())
+}
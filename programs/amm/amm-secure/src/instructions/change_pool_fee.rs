@@ -0,0 +1,78 @@
+// Change Pool Fee Instruction
+//
+// Second step of the two-step fee change. Applies the fee queued by
+// request_fee_change once the timelock has elapsed.
+//
+// SECURITY:
+// - Only pool authority can execute
+// - Fails until fee_change_unlock_time has passed
+// - Pending state cleared so the same request cannot be replayed
+// - Pending fee re-checked against the AmmConfig tiers, which the admin may
+//   have changed during the delay
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct ChangePoolFee<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+
+    /// CHECK: Canonical PDA enforced by seeds; deserialized as AmmConfig
+    /// (owner + discriminator checked) in the handler when initialized
+    #[account(
+        seeds = [AMM_GLOBAL_CONFIG_SEED],
+        bump
+    )]
+    pub amm_config: UncheckedAccount<'info>,
+}
+
+impl<'info> ChangePoolFee<'info> {
+    pub fn change_pool_fee(&mut self) -> Result<()> {
+        // Validate authority
+        self.pool_config.assert_is_authority(&self.authority.key())?;
+
+        require!(
+            self.pool_config.fee_change_unlock_time != 0,
+            AmmError::NoPendingFeeChange
+        );
+
+        // LPs must have had the full notice period
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time >= self.pool_config.fee_change_unlock_time,
+            AmmError::FeeChangeTimelockActive
+        );
+
+        AmmConfig::assert_fee_allowed(
+            &self.amm_config,
+            self.pool_config.pending_fee_basis_points,
+        )?;
+
+        let old_fee = self.pool_config.fee_basis_points;
+        self.pool_config.fee_basis_points = self.pool_config.pending_fee_basis_points;
+
+        // Clear pending change
+        self.pool_config.pending_fee_basis_points = 0;
+        self.pool_config.fee_change_unlock_time = 0;
+
+        msg!(
+            "Pool fee changed: {} -> {} bp",
+            old_fee,
+            self.pool_config.fee_basis_points
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,235 @@
+// Claim LP Fees Instruction
+//
+// Pays an LP position the fees it earned since its last checkpoint, without
+// closing it. Swap and flash loan fees stay in the pool and raise the pool's fee
+// growth (liquidity per LP token); the position's share of that growth is
+// redeemed as LP tokens from the position LP vault.
+//
+// HOW IT WORKS:
+// 1. Current fee growth is read from the vaults and the LP supply (rounded down)
+// 2. fee_lp = LP tokens the position can give up and still be worth what it was
+//    at its checkpoint
+// 3. fee_lp is burned from the position LP vault and its share of both vaults is
+//    paid to the position owner
+// 4. The position keeps lp_tokens - fee_lp and is checkpointed at the current
+//    fee growth (rounded up)
+//
+// SECURITY:
+// - Ownership read from the Core asset account, not from instruction data
+// - Position must belong to this pool (has_one = pool_config)
+// - Every rounding step favors the pool: the position keeps at least its
+//   checkpointed value, and dust left by rounding stays with all LPs
+// - Lock and flash loan checks as in withdraw_position; a borrowed vault would
+//   understate the fee growth
+//
+// TOKEN-2022:
+// Fees are paid like a withdrawal; the owner receives amount - transfer fee.
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use mpl_core::accounts::BaseAssetV1;
+
+use crate::{constants::*, errors::*, helpers::*, state::*};
+
+#[derive(Accounts)]
+pub struct ClaimLpFees<'info> {
+    // Position NFT owner (receives the fees)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // Position NFT
+    #[account(
+        constraint = asset.owner == owner.key() @ AmmError::PositionOwnerMismatch,
+    )]
+    pub asset: Box<Account<'info, BaseAssetV1>>,
+
+    // Position record PDA
+    // Seeds: ["position", asset]
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, asset.key().as_ref()],
+        bump = position.bump,
+        has_one = asset,
+        has_one = pool_config @ AmmError::PositionPoolMismatch,
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    // Mutable to snapshot the fee growth
+    #[account(
+        mut,
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+
+    /// CHECK: PDA signer
+    #[account(
+        seeds = [AMM_AUTHORITY_SEED, pool_config.key().as_ref()],
+        bump = pool_config.authority_bump,
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LP_MINT_SEED, pool_config.key().as_ref()],
+        bump = pool_config.lp_mint_bump,
+    )]
+    pub lp_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = pool_config.token_a_mint)]
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = pool_config.token_b_mint)]
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = token_a_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = token_b_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // LP tokens backing every position in this pool
+    #[account(
+        mut,
+        associated_token::mint = lp_token_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub position_lp_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_a_mint,
+        token::authority = pool_authority,
+        token::token_program = token_program,
+    )]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_b_mint,
+        token::authority = pool_authority,
+        token::token_program = token_program,
+    )]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimLpFees<'info> {
+    pub fn claim_lp_fees(&mut self) -> Result<()> {
+        // Check pool not locked
+        self.pool_config.assert_not_locked()?;
+
+        // Borrowed flash loan tokens cannot be routed back through the pool
+        self.pool_config.assert_no_flash_loan()?;
+
+        let vault_a_balance = self.token_a_vault.amount;
+        let vault_b_balance = self.token_b_vault.amount;
+        let lp_supply = self.lp_token_mint.supply;
+
+        // Check pool has liquidity
+        require!(lp_supply > 0, AmmError::InsufficientLiquidity);
+
+        // Fees earned since the checkpoint, as LP tokens
+        let fee_growth = calculate_fee_growth(vault_a_balance, vault_b_balance, lp_supply, false)?;
+        let fee_lp = calculate_claimable_lp_fees(
+            self.position.lp_tokens,
+            self.position.fee_growth_checkpoint,
+            fee_growth,
+        )?;
+        require!(fee_lp > 0, AmmError::NoFeesToClaim);
+        require!(self.position_lp_vault.amount >= fee_lp, AmmError::InsufficientBalance);
+
+        // Fee LP tokens redeem like any other LP tokens
+        let (amount_a, amount_b) =
+            calculate_withdrawal(fee_lp, vault_a_balance, vault_b_balance, lp_supply)?;
+
+        // Fees too small to pay in both tokens keep accruing
+        require!(amount_a > 0 && amount_b > 0, AmmError::NoFeesToClaim);
+
+        // Checkpoint at the growth the claim was measured against, rounded up
+        let fee_growth_checkpoint =
+            calculate_fee_growth(vault_a_balance, vault_b_balance, lp_supply, true)?;
+
+        let pool_config_key = self.pool_config.key();
+        let authority_seeds = &[
+            AMM_AUTHORITY_SEED,
+            pool_config_key.as_ref(),
+            &[self.pool_config.authority_bump],
+        ];
+
+        // Burn the fee LP tokens from the position LP vault
+        burn_lp_tokens_from_vault(
+            fee_lp,
+            &self.token_program.to_account_info(),
+            &self.lp_token_mint.to_account_info(),
+            &self.position_lp_vault.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        // Transfer fees from vaults using helper
+        transfer_from_vault(
+            amount_a,
+            self.token_a_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.token_a_vault.to_account_info(),
+            &self.token_a_mint.to_account_info(),
+            &self.owner_token_a.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        transfer_from_vault(
+            amount_b,
+            self.token_b_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.token_b_vault.to_account_info(),
+            &self.token_b_mint.to_account_info(),
+            &self.owner_token_b.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        self.position.lp_tokens = self
+            .position
+            .lp_tokens
+            .checked_sub(fee_lp)
+            .ok_or(AmmError::Underflow)?;
+        self.position.fee_growth_checkpoint = fee_growth_checkpoint;
+        self.pool_config.fee_growth_global = fee_growth;
+
+        msg!(
+            "LP fees claimed: {} LP -> {} A, {} B (asset {})",
+            fee_lp,
+            amount_a,
+            amount_b,
+            self.asset.key()
+        );
+
+        Ok(())
+    }
+}
@@ -14,11 +14,17 @@
 // - Expiration timestamp: Prevents stale transactions from executing
 // - Pool lock check: Deposit disabled when pool is paused
 // - Box<Account> usage: Reduces stack usage to prevent stack overflow
+// - Invariant check: k per LP token is re-checked against the reloaded vaults
+//
+// TOKEN-2022:
+// For mints with a transfer fee, the depositor sends amount + fee so the vault
+// is credited exactly the amount the LP math was based on. Slippage limits are
+// checked against the gross amount the depositor actually pays.
 
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::{constants::*, errors::*, state::*, helpers::*};
@@ -35,6 +41,7 @@ pub struct DepositLiquidity<'info> {
             AMM_CONFIG_SEED,
             pool_config.token_a_mint.as_ref(),
             pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
         ],
         bump = pool_config.config_bump,
     )]
@@ -54,16 +61,17 @@ pub struct DepositLiquidity<'info> {
         seeds = [LP_MINT_SEED, pool_config.key().as_ref()],
         bump = pool_config.lp_mint_bump,
         mint::authority = pool_authority,
+        mint::token_program = token_program,
     )]
-    pub lp_token_mint: Box<Account<'info, Mint>>,
+    pub lp_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     // Token A mint (verified against pool_config)
     #[account(address = pool_config.token_a_mint)]
-    pub token_a_mint: Box<Account<'info, Mint>>,
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
 
     // Token B mint (verified against pool_config)
     #[account(address = pool_config.token_b_mint)]
-    pub token_b_mint: Box<Account<'info, Mint>>,
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
 
     // Depositor's token A account (source of token A)
     // Anchor validates mint and authority via constraints
@@ -71,16 +79,18 @@ pub struct DepositLiquidity<'info> {
         mut,
         token::mint = token_a_mint,
         token::authority = depositor,
+        token::token_program = token_program,
     )]
-    pub depositor_token_a: Account<'info, TokenAccount>,
+    pub depositor_token_a: InterfaceAccount<'info, TokenAccount>,
 
     // Depositor's token B account (source of token B)
     #[account(
         mut,
         token::mint = token_b_mint,
         token::authority = depositor,
+        token::token_program = token_program,
     )]
-    pub depositor_token_b: Account<'info, TokenAccount>,
+    pub depositor_token_b: InterfaceAccount<'info, TokenAccount>,
 
     // Depositor's LP token account (created if doesn't exist)
     #[account(
@@ -88,26 +98,29 @@ pub struct DepositLiquidity<'info> {
         payer = depositor,
         associated_token::mint = lp_token_mint,
         associated_token::authority = depositor,
+        associated_token::token_program = token_program,
     )]
-    pub depositor_lp_token: Box<Account<'info, TokenAccount>>,
+    pub depositor_lp_token: Box<InterfaceAccount<'info, TokenAccount>>,
 
     // Pool's token A vault (holds all token A in the pool)
     #[account(
         mut,
         token::mint = token_a_mint,
         token::authority = pool_authority,
+        token::token_program = token_program,
     )]
-    pub token_a_vault: Box<Account<'info, TokenAccount>>,
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     // Pool's token B vault (holds all token B in the pool)
     #[account(
         mut,
         token::mint = token_b_mint,
         token::authority = pool_authority,
+        token::token_program = token_program,
     )]
-    pub token_b_vault: Box<Account<'info, TokenAccount>>,
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
@@ -124,6 +137,9 @@ impl<'info> DepositLiquidity<'info> {
         // Check pool not locked
         self.pool_config.assert_not_locked()?;
 
+        // Borrowed flash loan tokens cannot be routed back through the pool
+        self.pool_config.assert_no_flash_loan()?;
+
         // Validate expiration using helper
         validate_expiration(expiration)?;
 
@@ -148,24 +164,36 @@ impl<'info> DepositLiquidity<'info> {
             )?
         };
 
-        // Slippage protection
-        require!(amount_a <= max_amount_a, AmmError::ExcessiveDepositAmount);
-        require!(amount_b <= max_amount_b, AmmError::ExcessiveDepositAmount);
+        // Gross up for Token-2022 transfer fees so the vaults receive exactly amount_a/amount_b
+        let gross_amount_a = amount_a
+            .checked_add(calculate_inverse_transfer_fee(&self.token_a_mint, amount_a)?)
+            .ok_or(AmmError::Overflow)?;
+        let gross_amount_b = amount_b
+            .checked_add(calculate_inverse_transfer_fee(&self.token_b_mint, amount_b)?)
+            .ok_or(AmmError::Overflow)?;
+
+        // Slippage protection (against what the depositor actually pays)
+        require!(gross_amount_a <= max_amount_a, AmmError::ExcessiveDepositAmount);
+        require!(gross_amount_b <= max_amount_b, AmmError::ExcessiveDepositAmount);
         require!(lp_tokens > 0, AmmError::InsufficientLiquidity);
 
         // Transfer tokens to vaults using helper
         transfer_tokens(
-            amount_a,
+            gross_amount_a,
+            self.token_a_mint.decimals,
             &self.token_program.to_account_info(),
             &self.depositor_token_a.to_account_info(),
+            &self.token_a_mint.to_account_info(),
             &self.token_a_vault.to_account_info(),
             &self.depositor.to_account_info(),
         )?;
 
         transfer_tokens(
-            amount_b,
+            gross_amount_b,
+            self.token_b_mint.decimals,
             &self.token_program.to_account_info(),
             &self.depositor_token_b.to_account_info(),
+            &self.token_b_mint.to_account_info(),
             &self.token_b_vault.to_account_info(),
             &self.depositor.to_account_info(),
         )?;
@@ -187,6 +215,20 @@ impl<'info> DepositLiquidity<'info> {
             authority_seeds,
         )?;
 
+        // Defense in depth: the deposit must not have lowered k per LP token
+        self.token_a_vault.reload()?;
+        self.token_b_vault.reload()?;
+        self.lp_token_mint.reload()?;
+        assert_k_not_decreased(
+            PoolSnapshot::new(vault_a_balance, vault_b_balance, lp_supply),
+            PoolSnapshot::new(
+                self.token_a_vault.amount,
+                self.token_b_vault.amount,
+                self.lp_token_mint.supply,
+            ),
+            (0, 0),
+        )?;
+
         msg!("Deposited: {} A, {} B -> {} LP", amount_a, amount_b, lp_tokens);
 
         Ok(())
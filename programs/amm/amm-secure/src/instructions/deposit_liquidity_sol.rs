@@ -0,0 +1,266 @@
+// Deposit Liquidity SOL Instruction
+//
+// deposit_liquidity for pools with a wrapped SOL side, paid in native SOL.
+// The native side's amounts are in lamports.
+//
+// HOW IT WORKS:
+// 1. Deposit math is identical to deposit_liquidity (first deposit / proportional)
+// 2. The depositor's wSOL ATA is created if needed and must start empty
+// 3. Exactly the lamports the deposit needs are wrapped into it (transfer + sync_native)
+// 4. Both tokens move to the vaults and LP tokens are minted as usual
+// 5. The wSOL ATA is closed back to the depositor, refunding its rent
+//
+// SECURITY:
+// - Same slippage, expiration, lock and flash loan checks as deposit_liquidity
+// - The wSOL account is the depositor's ATA, so only their own account is wrapped into and closed
+// - An account already holding wSOL is rejected rather than unwrapped as a side effect
+// - Only the amount the LP math needs is wrapped, so nothing is left behind
+// - The close is checked to return every lamport the account held to the depositor
+// - k per LP token is re-checked against the reloaded vaults, as in deposit_liquidity
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, errors::*, helpers::*, state::*};
+
+#[derive(Accounts)]
+pub struct DepositLiquiditySol<'info> {
+    // User adding liquidity (pays the SOL side and any ATA rent)
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    // Pool configuration PDA
+    #[account(
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+
+    // Pool authority PDA (mint authority of the LP token)
+    /// CHECK: PDA signer, validated by seeds
+    #[account(
+        seeds = [AMM_AUTHORITY_SEED, pool_config.key().as_ref()],
+        bump = pool_config.authority_bump,
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LP_MINT_SEED, pool_config.key().as_ref()],
+        bump = pool_config.lp_mint_bump,
+        mint::authority = pool_authority,
+        mint::token_program = token_program,
+    )]
+    pub lp_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = pool_config.token_a_mint)]
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = pool_config.token_b_mint)]
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Depositor's token A ATA
+    // Created for the wSOL side, which only lives for this instruction
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = token_a_mint,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program,
+    )]
+    pub depositor_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Depositor's token B ATA
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = token_b_mint,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program,
+    )]
+    pub depositor_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = lp_token_mint,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program,
+    )]
+    pub depositor_lp_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_a_mint,
+        token::authority = pool_authority,
+        token::token_program = token_program,
+    )]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_b_mint,
+        token::authority = pool_authority,
+        token::token_program = token_program,
+    )]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositLiquiditySol<'info> {
+    pub fn deposit_liquidity_sol(
+        &mut self,
+        desired_amount_a: u64,
+        desired_amount_b: u64,
+        max_amount_a: u64,
+        max_amount_b: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        // Check pool not locked
+        self.pool_config.assert_not_locked()?;
+
+        // Borrowed flash loan tokens cannot be routed back through the pool
+        self.pool_config.assert_no_flash_loan()?;
+
+        // Validate expiration using helper
+        validate_expiration(expiration)?;
+
+        // Check non-zero amounts
+        require!(desired_amount_a > 0, AmmError::ZeroDepositAmount);
+        require!(desired_amount_b > 0, AmmError::ZeroDepositAmount);
+
+        // One side must be wrapped SOL, and its ATA must hold nothing yet
+        let sol_is_token_a =
+            native_sol_is_token_a(&self.token_a_mint.key(), &self.token_b_mint.key())?;
+        let wrapped_sol_account = if sol_is_token_a {
+            &self.depositor_token_a
+        } else {
+            &self.depositor_token_b
+        };
+        require!(
+            wrapped_sol_account.amount == 0,
+            AmmError::WrappedSolAccountNotEmpty
+        );
+
+        let vault_a_balance = self.token_a_vault.amount;
+        let vault_b_balance = self.token_b_vault.amount;
+        let lp_supply = self.lp_token_mint.supply;
+
+        let (amount_a, amount_b, lp_tokens) = if lp_supply == 0 {
+            calculate_first_deposit(desired_amount_a, desired_amount_b)?
+        } else {
+            calculate_subsequent_deposit(
+                desired_amount_a,
+                desired_amount_b,
+                vault_a_balance,
+                vault_b_balance,
+                lp_supply,
+            )?
+        };
+
+        // Gross up for Token-2022 transfer fees (always 0 on the wSOL side)
+        let gross_amount_a = amount_a
+            .checked_add(calculate_inverse_transfer_fee(&self.token_a_mint, amount_a)?)
+            .ok_or(AmmError::Overflow)?;
+        let gross_amount_b = amount_b
+            .checked_add(calculate_inverse_transfer_fee(&self.token_b_mint, amount_b)?)
+            .ok_or(AmmError::Overflow)?;
+
+        // Slippage protection (against what the depositor actually pays)
+        require!(gross_amount_a <= max_amount_a, AmmError::ExcessiveDepositAmount);
+        require!(gross_amount_b <= max_amount_b, AmmError::ExcessiveDepositAmount);
+        require!(lp_tokens > 0, AmmError::InsufficientLiquidity);
+
+        // Wrap exactly what the SOL side needs
+        let wrap_amount = if sol_is_token_a {
+            gross_amount_a
+        } else {
+            gross_amount_b
+        };
+        wrap_sol(
+            wrap_amount,
+            &self.token_program.to_account_info(),
+            &self.system_program.to_account_info(),
+            &self.depositor.to_account_info(),
+            &wrapped_sol_account.to_account_info(),
+        )?;
+
+        transfer_tokens(
+            gross_amount_a,
+            self.token_a_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.depositor_token_a.to_account_info(),
+            &self.token_a_mint.to_account_info(),
+            &self.token_a_vault.to_account_info(),
+            &self.depositor.to_account_info(),
+        )?;
+
+        transfer_tokens(
+            gross_amount_b,
+            self.token_b_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.depositor_token_b.to_account_info(),
+            &self.token_b_mint.to_account_info(),
+            &self.token_b_vault.to_account_info(),
+            &self.depositor.to_account_info(),
+        )?;
+
+        let pool_config_key = self.pool_config.key();
+        let authority_seeds = &[
+            AMM_AUTHORITY_SEED,
+            pool_config_key.as_ref(),
+            &[self.pool_config.authority_bump],
+        ];
+
+        mint_lp_tokens(
+            lp_tokens,
+            &self.token_program.to_account_info(),
+            &self.lp_token_mint.to_account_info(),
+            &self.depositor_lp_token.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        // Unwrap: the emptied wSOL ATA goes back to the depositor
+        close_wrapped_sol(
+            &self.token_program.to_account_info(),
+            &self.depositor.to_account_info(),
+            &wrapped_sol_account.to_account_info(),
+        )?;
+
+        // Defense in depth: the deposit must not have lowered k per LP token
+        self.token_a_vault.reload()?;
+        self.token_b_vault.reload()?;
+        self.lp_token_mint.reload()?;
+        assert_k_not_decreased(
+            PoolSnapshot::new(vault_a_balance, vault_b_balance, lp_supply),
+            PoolSnapshot::new(
+                self.token_a_vault.amount,
+                self.token_b_vault.amount,
+                self.lp_token_mint.supply,
+            ),
+            (0, 0),
+        )?;
+
+        msg!(
+            "Deposited: {} A, {} B -> {} LP ({} lamports wrapped)",
+            amount_a,
+            amount_b,
+            lp_tokens,
+            wrap_amount
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,343 @@
+// Deposit Position Instruction
+//
+// Alternative liquidity mode: instead of receiving fungible LP tokens, the depositor
+// receives a Metaplex Core NFT representing the position.
+//
+// HOW IT WORKS:
+// 1. Deposit math is identical to deposit_liquidity (first deposit / proportional)
+// 2. LP tokens are minted into the position LP vault, held by the pool authority
+// 3. A Core asset is minted to the depositor with an Attributes plugin recording
+//    the deposited amounts, LP tokens and entry price
+// 4. A Position PDA keyed by the asset records the same values for withdraw_position,
+//    and is checkpointed at the pool's fee growth for claim_lp_fees
+//
+// SECURITY:
+// - Same slippage, expiration, lock and flash loan checks as deposit_liquidity
+// - Same k per LP token check as deposit_liquidity after the LP tokens are minted
+// - Asset must be a fresh keypair signer, so each position gets its own record
+// - Pool authority is the asset's update authority, so only this program can
+//   edit the attributes
+// - Metaplex Core program validated by address
+//
+// TOKEN-2022:
+// Transfer fees are grossed up exactly as in deposit_liquidity; the recorded
+// amounts are what the vaults were credited.
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use mpl_core::{
+    ID as MPL_CORE_ID,
+    instructions::CreateV2CpiBuilder,
+    types::{Attribute, Attributes, Plugin, PluginAuthorityPair},
+};
+
+use crate::{constants::*, errors::*, helpers::*, state::*};
+
+#[derive(Accounts)]
+pub struct DepositPosition<'info> {
+    // User adding liquidity (pays for the asset, position and vault rent)
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    // Position NFT being minted
+    // Must be a new keypair, must sign
+    #[account(mut)]
+    pub asset: Signer<'info>,
+
+    // Pool configuration PDA
+    // Mutable to snapshot the fee growth
+    #[account(
+        mut,
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+
+    // Pool authority PDA (mints LP tokens, holds the position LP vault,
+    // update authority of the position NFT)
+    /// CHECK: PDA signer, validated by seeds
+    #[account(
+        seeds = [AMM_AUTHORITY_SEED, pool_config.key().as_ref()],
+        bump = pool_config.authority_bump,
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    // LP token mint (pool authority is mint authority)
+    #[account(
+        mut,
+        seeds = [LP_MINT_SEED, pool_config.key().as_ref()],
+        bump = pool_config.lp_mint_bump,
+        mint::authority = pool_authority,
+        mint::token_program = token_program,
+    )]
+    pub lp_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Token A mint (verified against pool_config)
+    #[account(address = pool_config.token_a_mint)]
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Token B mint (verified against pool_config)
+    #[account(address = pool_config.token_b_mint)]
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Depositor's token A account (source of token A)
+    #[account(
+        mut,
+        token::mint = token_a_mint,
+        token::authority = depositor,
+        token::token_program = token_program,
+    )]
+    pub depositor_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Depositor's token B account (source of token B)
+    #[account(
+        mut,
+        token::mint = token_b_mint,
+        token::authority = depositor,
+        token::token_program = token_program,
+    )]
+    pub depositor_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // LP tokens backing every position in this pool (created on first position)
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = lp_token_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub position_lp_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Pool's token A vault
+    #[account(
+        mut,
+        token::mint = token_a_mint,
+        token::authority = pool_authority,
+        token::token_program = token_program,
+    )]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Pool's token B vault
+    #[account(
+        mut,
+        token::mint = token_b_mint,
+        token::authority = pool_authority,
+        token::token_program = token_program,
+    )]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Position record PDA
+    // Seeds: ["position", asset]
+    #[account(
+        init,
+        payer = depositor,
+        space = ANCHOR_DISCRIMINATOR + Position::INIT_SPACE,
+        seeds = [POSITION_SEED, asset.key().as_ref()],
+        bump,
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    // Metaplex Core program
+    #[account(address = MPL_CORE_ID @ AmmError::InvalidMplCoreProgram)]
+    /// CHECK: Validated by address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositPosition<'info> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn deposit_position(
+        &mut self,
+        desired_amount_a: u64,
+        desired_amount_b: u64,
+        max_amount_a: u64,
+        max_amount_b: u64,
+        expiration: i64,
+        uri: String,
+        bumps: &DepositPositionBumps,
+    ) -> Result<()> {
+        // Check pool not locked
+        self.pool_config.assert_not_locked()?;
+
+        // Borrowed flash loan tokens cannot be routed back through the pool
+        self.pool_config.assert_no_flash_loan()?;
+
+        // Validate expiration using helper
+        validate_expiration(expiration)?;
+
+        // Check non-zero amounts
+        require!(desired_amount_a > 0, AmmError::ZeroDepositAmount);
+        require!(desired_amount_b > 0, AmmError::ZeroDepositAmount);
+
+        require!(uri.len() <= MAX_POSITION_URI_LENGTH, AmmError::PositionUriTooLong);
+
+        let vault_a_balance = self.token_a_vault.amount;
+        let vault_b_balance = self.token_b_vault.amount;
+        let lp_supply = self.lp_token_mint.supply;
+
+        // Same deposit math as deposit_liquidity
+        let (amount_a, amount_b, lp_tokens) = if lp_supply == 0 {
+            calculate_first_deposit(desired_amount_a, desired_amount_b)?
+        } else {
+            calculate_subsequent_deposit(
+                desired_amount_a,
+                desired_amount_b,
+                vault_a_balance,
+                vault_b_balance,
+                lp_supply,
+            )?
+        };
+
+        // Gross up for Token-2022 transfer fees so the vaults receive exactly amount_a/amount_b
+        let gross_amount_a = amount_a
+            .checked_add(calculate_inverse_transfer_fee(&self.token_a_mint, amount_a)?)
+            .ok_or(AmmError::Overflow)?;
+        let gross_amount_b = amount_b
+            .checked_add(calculate_inverse_transfer_fee(&self.token_b_mint, amount_b)?)
+            .ok_or(AmmError::Overflow)?;
+
+        // Slippage protection (against what the depositor actually pays)
+        require!(gross_amount_a <= max_amount_a, AmmError::ExcessiveDepositAmount);
+        require!(gross_amount_b <= max_amount_b, AmmError::ExcessiveDepositAmount);
+        require!(lp_tokens > 0, AmmError::InsufficientLiquidity);
+
+        let entry_price = calculate_entry_price(amount_a, amount_b)?;
+
+        // Transfer tokens to vaults using helper
+        transfer_tokens(
+            gross_amount_a,
+            self.token_a_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.depositor_token_a.to_account_info(),
+            &self.token_a_mint.to_account_info(),
+            &self.token_a_vault.to_account_info(),
+            &self.depositor.to_account_info(),
+        )?;
+
+        transfer_tokens(
+            gross_amount_b,
+            self.token_b_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.depositor_token_b.to_account_info(),
+            &self.token_b_mint.to_account_info(),
+            &self.token_b_vault.to_account_info(),
+            &self.depositor.to_account_info(),
+        )?;
+
+        // Mint LP tokens into the position LP vault instead of to the depositor
+        let pool_config_key = self.pool_config.key();
+        let authority_seeds = &[
+            AMM_AUTHORITY_SEED,
+            pool_config_key.as_ref(),
+            &[self.pool_config.authority_bump],
+        ];
+
+        mint_lp_tokens(
+            lp_tokens,
+            &self.token_program.to_account_info(),
+            &self.lp_token_mint.to_account_info(),
+            &self.position_lp_vault.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        // Defense in depth: the deposit must not have lowered k per LP token
+        self.token_a_vault.reload()?;
+        self.token_b_vault.reload()?;
+        self.lp_token_mint.reload()?;
+        assert_k_not_decreased(
+            PoolSnapshot::new(vault_a_balance, vault_b_balance, lp_supply),
+            PoolSnapshot::new(
+                self.token_a_vault.amount,
+                self.token_b_vault.amount,
+                self.lp_token_mint.supply,
+            ),
+            (0, 0),
+        )?;
+
+        // Mint the position NFT to the depositor
+        // Attributes are informational; withdraw_position reads the Position PDA
+        let attribute_list = vec![
+            Attribute {
+                key: POSITION_POOL_KEY.to_string(),
+                value: pool_config_key.to_string(),
+            },
+            Attribute {
+                key: POSITION_AMOUNT_A_KEY.to_string(),
+                value: amount_a.to_string(),
+            },
+            Attribute {
+                key: POSITION_AMOUNT_B_KEY.to_string(),
+                value: amount_b.to_string(),
+            },
+            Attribute {
+                key: POSITION_LP_TOKENS_KEY.to_string(),
+                value: lp_tokens.to_string(),
+            },
+            Attribute {
+                key: POSITION_ENTRY_PRICE_KEY.to_string(),
+                value: entry_price.to_string(),
+            },
+        ];
+
+        let plugins = vec![PluginAuthorityPair {
+            plugin: Plugin::Attributes(Attributes { attribute_list }),
+            authority: None,
+        }];
+
+        CreateV2CpiBuilder::new(&self.mpl_core_program.to_account_info())
+            .asset(&self.asset.to_account_info())
+            .payer(&self.depositor.to_account_info())
+            .owner(Some(&self.depositor.to_account_info()))
+            .update_authority(Some(&self.pool_authority.to_account_info()))
+            .system_program(&self.system_program.to_account_info())
+            .name(POSITION_NAME.to_string())
+            .uri(uri)
+            .plugins(plugins)
+            .invoke()?;
+
+        // Checkpoint at the fee growth including this deposit, rounded up so
+        // the position cannot claim fees earned before it joined
+        let reserve_a = vault_a_balance.checked_add(amount_a).ok_or(AmmError::Overflow)?;
+        let reserve_b = vault_b_balance.checked_add(amount_b).ok_or(AmmError::Overflow)?;
+        let new_lp_supply = lp_supply.checked_add(lp_tokens).ok_or(AmmError::Overflow)?;
+        let fee_growth_checkpoint =
+            calculate_fee_growth(reserve_a, reserve_b, new_lp_supply, true)?;
+        self.pool_config.fee_growth_global =
+            calculate_fee_growth(reserve_a, reserve_b, new_lp_supply, false)?;
+
+        // Record the position
+        self.position.set_inner(Position {
+            pool_config: pool_config_key,
+            asset: self.asset.key(),
+            lp_tokens,
+            amount_a,
+            amount_b,
+            entry_price,
+            created_at: Clock::get()?.unix_timestamp,
+            fee_growth_checkpoint,
+            bump: bumps.position,
+        });
+
+        msg!(
+            "Position opened: {} A, {} B -> {} LP (asset {})",
+            amount_a,
+            amount_b,
+            lp_tokens,
+            self.asset.key()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,190 @@
+// Emergency Withdraw Instruction
+//
+// Lets LPs exit a locked pool. Locking freezes deposits, withdrawals, and swaps;
+// if the pool authority also enables emergency_withdraw_enabled, LPs can still
+// burn LP tokens for their proportional share of both vaults.
+//
+// HOW IT WORKS:
+// 1. Pool must be locked and emergency withdraw enabled
+// 2. Calculate proportional withdrawal: amount = (lp_burned / lp_supply) * vault_balance
+// 3. Burn LP tokens, transfer both tokens from vaults to user
+//
+// SECURITY:
+// - Only available while locked - no swaps can move the price, so no slippage
+//   or expiration parameters are needed
+// - Authority opt-in: a lock stays a full freeze unless the authority enables exits
+// - Proportional only: the same math as withdraw_liquidity, rounded down
+// - No flash loan in progress
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, errors::*, helpers::*, state::*};
+
+#[derive(Accounts)]
+pub struct EmergencyWithdraw<'info> {
+    #[account(mut)]
+    pub withdrawer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+
+    /// CHECK: PDA signer
+    #[account(
+        seeds = [AMM_AUTHORITY_SEED, pool_config.key().as_ref()],
+        bump = pool_config.authority_bump,
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LP_MINT_SEED, pool_config.key().as_ref()],
+        bump = pool_config.lp_mint_bump,
+    )]
+    pub lp_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = pool_config.token_a_mint)]
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = pool_config.token_b_mint)]
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = withdrawer,
+        associated_token::mint = token_a_mint,
+        associated_token::authority = withdrawer,
+        associated_token::token_program = token_program,
+    )]
+    pub withdrawer_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = withdrawer,
+        associated_token::mint = token_b_mint,
+        associated_token::authority = withdrawer,
+        associated_token::token_program = token_program,
+    )]
+    pub withdrawer_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = lp_token_mint,
+        token::authority = withdrawer,
+        token::token_program = token_program,
+    )]
+    pub withdrawer_lp_token: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = token_a_mint,
+        token::authority = pool_authority,
+        token::token_program = token_program,
+    )]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_b_mint,
+        token::authority = pool_authority,
+        token::token_program = token_program,
+    )]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> EmergencyWithdraw<'info> {
+    pub fn emergency_withdraw(&mut self, lp_tokens_to_burn: u64) -> Result<()> {
+        // Validate withdrawer LP token account (Anchor constraints already check mint and authority)
+        require!(self.withdrawer_lp_token.amount >= lp_tokens_to_burn, AmmError::InsufficientBalance);
+
+        // Only for paused pools - use withdraw_liquidity otherwise
+        self.pool_config.assert_locked()?;
+
+        // Authority must have opted in to emergency exits
+        require!(
+            self.pool_config.emergency_withdraw_enabled,
+            AmmError::EmergencyWithdrawDisabled
+        );
+
+        self.pool_config.assert_no_flash_loan()?;
+
+        // Check non-zero LP amount
+        require!(lp_tokens_to_burn > 0, AmmError::ZeroWithdrawAmount);
+
+        let vault_a_balance = self.token_a_vault.amount;
+        let vault_b_balance = self.token_b_vault.amount;
+        let lp_supply = self.lp_token_mint.supply;
+
+        // Check pool has liquidity
+        require!(lp_supply > 0, AmmError::InsufficientLiquidity);
+
+        // Calculate withdrawal amounts using helper
+        let (amount_a, amount_b) = calculate_withdrawal(
+            lp_tokens_to_burn,
+            vault_a_balance,
+            vault_b_balance,
+            lp_supply,
+        )?;
+
+        // Check non-zero withdrawals
+        require!(amount_a > 0 || amount_b > 0, AmmError::InsufficientLiquidity);
+
+        // Burn LP tokens using helper
+        burn_lp_tokens(
+            lp_tokens_to_burn,
+            &self.token_program.to_account_info(),
+            &self.lp_token_mint.to_account_info(),
+            &self.withdrawer_lp_token.to_account_info(),
+            &self.withdrawer.to_account_info(),
+        )?;
+
+        // Transfer tokens from vaults using helper
+        let pool_config_key = self.pool_config.key();
+        let authority_seeds = &[
+            AMM_AUTHORITY_SEED,
+            pool_config_key.as_ref(),
+            &[self.pool_config.authority_bump],
+        ];
+
+        transfer_from_vault(
+            amount_a,
+            self.token_a_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.token_a_vault.to_account_info(),
+            &self.token_a_mint.to_account_info(),
+            &self.withdrawer_token_a.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        transfer_from_vault(
+            amount_b,
+            self.token_b_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.token_b_vault.to_account_info(),
+            &self.token_b_mint.to_account_info(),
+            &self.withdrawer_token_b.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        msg!("Emergency withdrawn: {} LP -> {} A, {} B", lp_tokens_to_burn, amount_a, amount_b);
+
+        Ok(())
+    }
+}
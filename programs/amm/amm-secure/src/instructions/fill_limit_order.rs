@@ -0,0 +1,355 @@
+// Fill Limit Order Instruction
+//
+// Permissionless crank: any keeper swaps a limit order's escrow against the pool
+// once the pool price is good enough, and collects the order's keeper tip.
+//
+// HOW IT WORKS:
+// 1. The escrowed input is priced on the curve like an exact-input swap
+// 2. The owner's net output must meet the order's min_output_amount (the limit)
+// 3. Escrow -> input vault (order PDA signs), output vault -> owner (pool authority signs)
+// 4. The escrow is closed to the owner, the keeper is paid the tip, and the
+//    LimitOrder PDA is closed to the owner
+//
+// SECURITY:
+// - The limit is read from the order, never from the keeper
+// - The keeper chooses when to fill, so it can move the price first; whatever it
+//   does, the fill fails with LimitPriceNotReached unless the owner gets the limit
+// - Output always goes to the owner's ATA; the keeper only receives the tip
+// - Lock, flash loan, circuit breaker, per-slot swap limit and oracle guard apply
+//   as in swap_tokens.
+//   A keeper cannot lock the pool, so an order over the circuit breaker limit is
+//   rejected and stays open
+// - Expired orders cannot be filled, only cancelled
+// - After the transfers the reloaded vaults must hold at least the k they
+//   started with (assert_k_not_decreased)
+//
+// TOKEN-2022:
+// The curve is priced on what the input vault receives, and the limit is checked
+// against what the owner receives, as in swap_tokens.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use constant_product_curve::{ConstantProduct, LiquidityPair};
+
+use crate::{constants::*, errors::*, helpers::*, state::*};
+
+#[derive(Accounts)]
+pub struct FillLimitOrder<'info> {
+    // Anyone can crank a fill; receives the keeper tip
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    // Order owner (receives the escrow and order rent)
+    #[account(mut)]
+    pub owner: SystemAccount<'info>,
+
+    // Mutable to record the fill against the per-slot swap limit
+    #[account(
+        mut,
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+
+    /// CHECK: PDA signer
+    #[account(
+        seeds = [AMM_AUTHORITY_SEED, pool_config.key().as_ref()],
+        bump = pool_config.authority_bump,
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    pub input_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub output_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Limit order PDA
+    // Seeds: ["limit_order", pool_config, owner, order_id]
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            LIMIT_ORDER_SEED,
+            pool_config.key().as_ref(),
+            owner.key().as_ref(),
+            &limit_order.order_id.to_le_bytes(),
+        ],
+        bump = limit_order.bump,
+        has_one = owner,
+        has_one = pool_config,
+        has_one = input_mint,
+        has_one = output_mint,
+    )]
+    pub limit_order: Box<Account<'info, LimitOrder>>,
+
+    #[account(
+        mut,
+        associated_token::mint = input_mint,
+        associated_token::authority = limit_order,
+        associated_token::token_program = token_program,
+    )]
+    pub order_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = output_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_output_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = input_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub input_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = output_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub output_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+
+    // Optional oracle feed, required when the pool has an oracle guard
+    /// CHECK: Matched against pool_config.price_feed, owner and layout checked on read
+    pub price_feed: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> FillLimitOrder<'info> {
+    pub fn fill_limit_order(&mut self) -> Result<()> {
+        // Check pool not locked
+        self.pool_config.assert_not_locked()?;
+
+        // Borrowed flash loan tokens cannot be routed back through the pool
+        self.pool_config.assert_no_flash_loan()?;
+
+        // Expired orders can only be cancelled by their owner
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time <= self.limit_order.expires_at,
+            AmmError::LimitOrderExpired
+        );
+
+        let input_amount = self.order_escrow.amount;
+        require!(input_amount > 0, AmmError::ZeroSwapAmount);
+
+        let input_reserve = self.input_vault.amount;
+        let output_reserve = self.output_vault.amount;
+
+        // Check pool has liquidity
+        require!(input_reserve > 0, AmmError::InsufficientPoolLiquidity);
+        require!(output_reserve > 0, AmmError::InsufficientPoolLiquidity);
+
+        // Price the fill on what the input vault actually receives after transfer fees
+        let input_transfer_fee = calculate_transfer_fee(&self.input_mint, input_amount)?;
+        let net_input_amount = input_amount
+            .checked_sub(input_transfer_fee)
+            .ok_or(AmmError::Underflow)?;
+        require!(net_input_amount > 0, AmmError::ZeroSwapAmount);
+
+        // The curve is oriented input -> output, so the order always swaps X for Y
+        let mut curve = ConstantProduct::init(
+            input_reserve,
+            output_reserve,
+            input_reserve,
+            self.pool_config.fee_basis_points,
+            None,
+        )
+        .map_err(|_| AmmError::CurveCalculationFailed)?;
+
+        // The curve only gets a non-zero floor; the limit is checked on the net
+        // output below so an unreachable price reports LimitPriceNotReached
+        let swap_result = curve
+            .swap(LiquidityPair::X, net_input_amount, 1)
+            .map_err(|_| AmmError::CurveCalculationFailed)?;
+
+        require!(swap_result.deposit > 0, AmmError::InvalidCurveParams);
+        require!(swap_result.withdraw > 0, AmmError::InvalidCurveParams);
+        require!(
+            swap_result.withdraw <= output_reserve,
+            AmmError::InsufficientPoolLiquidity
+        );
+
+        // Limit check against what the owner actually receives
+        let output_transfer_fee = calculate_transfer_fee(&self.output_mint, swap_result.withdraw)?;
+        let net_output_amount = swap_result
+            .withdraw
+            .checked_sub(output_transfer_fee)
+            .ok_or(AmmError::Underflow)?;
+        require!(
+            net_output_amount >= self.limit_order.min_output_amount,
+            AmmError::LimitPriceNotReached
+        );
+
+        // Circuit breaker: rejected outright, the keeper has no say over the pool lock
+        if self.pool_config.has_circuit_breaker() {
+            let impact_bps = calculate_price_impact_bps(
+                input_reserve,
+                output_reserve,
+                net_input_amount,
+                swap_result.withdraw,
+            )?;
+            require!(
+                impact_bps <= self.pool_config.max_price_impact_bps as u64,
+                AmmError::PriceImpactTooHigh
+            );
+        }
+
+        // Per-slot limit on what the input vault receives
+        let input_is_token_a = self.input_mint.key() == self.pool_config.token_a_mint;
+        let (reserve_a, reserve_b) = if input_is_token_a {
+            (input_reserve, output_reserve)
+        } else {
+            (output_reserve, input_reserve)
+        };
+        self.pool_config.record_swap_volume(
+            Clock::get()?.slot,
+            input_is_token_a,
+            net_input_amount,
+            reserve_a,
+            reserve_b,
+        )?;
+
+        let pool_config_key = self.pool_config.key();
+        let owner_key = self.owner.key();
+        let order_id_bytes = self.limit_order.order_id.to_le_bytes();
+        let order_seeds = &[
+            LIMIT_ORDER_SEED,
+            pool_config_key.as_ref(),
+            owner_key.as_ref(),
+            order_id_bytes.as_ref(),
+            &[self.limit_order.bump],
+        ];
+        let authority_seeds = &[
+            AMM_AUTHORITY_SEED,
+            pool_config_key.as_ref(),
+            &[self.pool_config.authority_bump],
+        ];
+
+        // Escrow -> input vault
+        transfer_from_vault(
+            input_amount,
+            self.input_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.order_escrow.to_account_info(),
+            &self.input_mint.to_account_info(),
+            &self.input_vault.to_account_info(),
+            &self.limit_order.to_account_info(),
+            order_seeds,
+        )?;
+
+        // Output vault -> owner
+        transfer_from_vault(
+            swap_result.withdraw,
+            self.output_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.output_vault.to_account_info(),
+            &self.output_mint.to_account_info(),
+            &self.owner_output_token.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        // Post-fill pool price must stay close to the oracle
+        self.check_oracle_guard()?;
+
+        // Defense in depth: the fill must not have lowered k
+        // k is symmetric in the reserves, so the input/output orientation is fine
+        self.input_vault.reload()?;
+        self.output_vault.reload()?;
+        assert_k_not_decreased(
+            PoolSnapshot::reserves(input_reserve, output_reserve),
+            PoolSnapshot::reserves(self.input_vault.amount, self.output_vault.amount),
+            (0, 0),
+        )?;
+
+        // Empty escrow's rent goes back to the owner who paid it
+        close_from_pda(
+            &self.token_program.to_account_info(),
+            &self.order_escrow.to_account_info(),
+            &self.owner.to_account_info(),
+            &self.limit_order.to_account_info(),
+            order_seeds,
+        )?;
+
+        // Pay the keeper; the rest of the order's lamports go to the owner on close
+        let keeper_tip = self.limit_order.keeper_tip;
+        if keeper_tip > 0 {
+            self.limit_order.to_account_info().sub_lamports(keeper_tip)?;
+            self.keeper.to_account_info().add_lamports(keeper_tip)?;
+        }
+
+        msg!(
+            "Limit order {} filled: {} in -> {} out (limit {}), keeper tip {}",
+            self.limit_order.order_id,
+            input_amount,
+            net_output_amount,
+            self.limit_order.min_output_amount,
+            keeper_tip
+        );
+
+        Ok(())
+    }
+
+    // Reject the fill if it left the pool price too far from the oracle price
+    // No-op when the pool has no oracle guard
+    fn check_oracle_guard(&mut self) -> Result<()> {
+        if !self.pool_config.has_oracle_guard() {
+            return Ok(());
+        }
+
+        let feed = self.price_feed.as_ref().ok_or(AmmError::PriceFeedRequired)?;
+        require_keys_eq!(feed.key(), self.pool_config.price_feed, AmmError::InvalidPriceFeed);
+
+        let price_feed =
+            PriceFeed::load(&feed.to_account_info(), &self.pool_config.oracle_program)?;
+        price_feed.assert_usable(Clock::get()?.unix_timestamp)?;
+
+        // Compare against the reserves the fill left behind
+        self.input_vault.reload()?;
+        self.output_vault.reload()?;
+
+        let (reserve_a, reserve_b, decimals_a, decimals_b) =
+            if self.input_mint.key() == self.pool_config.token_a_mint {
+                (
+                    self.input_vault.amount,
+                    self.output_vault.amount,
+                    self.input_mint.decimals,
+                    self.output_mint.decimals,
+                )
+            } else {
+                (
+                    self.output_vault.amount,
+                    self.input_vault.amount,
+                    self.output_mint.decimals,
+                    self.input_mint.decimals,
+                )
+            };
+
+        let deviation_bps = calculate_oracle_deviation_bps(
+            reserve_a,
+            reserve_b,
+            decimals_a,
+            decimals_b,
+            price_feed.price,
+            price_feed.exponent,
+        )?;
+        require!(
+            deviation_bps <= self.pool_config.max_oracle_deviation_bps as u128,
+            AmmError::OraclePriceDeviation
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+// Flash Loan Begin Instruction
+//
+// Lends tokens from one of the pool vaults for the duration of a single transaction.
+// The borrower must place a flash_loan_end instruction for the same pool later in
+// the transaction; flash_loan_end verifies the vault was repaid plus the fee.
+//
+// HOW IT WORKS:
+// 1. flash_loan_begin records the vault balance and fee, then transfers tokens out
+// 2. The borrower uses the tokens in any instructions that follow
+// 3. The borrower transfers amount + fee back into the vault
+// 4. flash_loan_end compares the vault balance against the recorded balance + fee
+// If repayment is short, flash_loan_end fails and the whole transaction reverts.
+//
+// SECURITY:
+// - Instruction introspection guarantees a matching flash_loan_end exists
+// - Cannot be invoked via CPI (introspection only sees top-level instructions)
+// - Only one active loan per pool; deposits, withdrawals, and swaps are blocked
+//   until flash_loan_end so borrowed tokens cannot be deposited back as "repayment"
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    self as instructions_sysvar, load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, helpers::*, state::*};
+
+#[derive(Accounts)]
+pub struct FlashLoanBegin<'info> {
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+
+    /// CHECK: PDA signer
+    #[account(
+        seeds = [AMM_AUTHORITY_SEED, pool_config.key().as_ref()],
+        bump = pool_config.authority_bump,
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    // Mint being borrowed - must be token A or token B of this pool
+    #[account(
+        constraint = pool_config.is_pool_mint(&token_mint.key()) @ AmmError::InvalidMint,
+        mint::token_program = token_program,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Receives the borrowed tokens
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program,
+    )]
+    pub borrower_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Instructions sysvar, validated by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> FlashLoanBegin<'info> {
+    pub fn flash_loan_begin(&mut self, amount: u64) -> Result<()> {
+        // Check pool not locked
+        self.pool_config.assert_not_locked()?;
+
+        // One loan at a time per pool
+        require!(
+            !self.pool_config.flash_loan_active,
+            AmmError::FlashLoanAlreadyActive
+        );
+
+        require!(amount > 0, AmmError::ZeroFlashLoanAmount);
+
+        let vault_balance = self.token_vault.amount;
+        require!(amount <= vault_balance, AmmError::InsufficientPoolLiquidity);
+
+        // Repayment is only enforced if flash_loan_end actually runs
+        self.validate_flash_loan_end()?;
+
+        let fee = calculate_flash_loan_fee(amount)?;
+
+        // Record the loan before tokens leave the vault
+        self.pool_config.flash_loan_active = true;
+        self.pool_config.flash_loan_mint = self.token_mint.key();
+        self.pool_config.flash_loan_vault_balance_before = vault_balance;
+        self.pool_config.flash_loan_fee = fee;
+
+        let pool_config_key = self.pool_config.key();
+        let authority_seeds = &[
+            AMM_AUTHORITY_SEED,
+            pool_config_key.as_ref(),
+            &[self.pool_config.authority_bump],
+        ];
+
+        transfer_from_vault(
+            amount,
+            self.token_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.token_vault.to_account_info(),
+            &self.token_mint.to_account_info(),
+            &self.borrower_token_account.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        msg!("Flash loan: {} borrowed, {} fee due", amount, fee);
+
+        Ok(())
+    }
+
+    // Verify a flash_loan_end for this pool appears later in the transaction
+    fn validate_flash_loan_end(&self) -> Result<()> {
+        let instructions = self.instructions.to_account_info();
+        let current_index = load_current_index_checked(&instructions)? as usize;
+
+        // Introspection only sees top-level instructions, so under CPI the
+        // instruction at the current index belongs to the calling program
+        let current_ix = load_instruction_at_checked(current_index, &instructions)?;
+        require_keys_eq!(
+            current_ix.program_id,
+            crate::ID,
+            AmmError::FlashLoanCpiNotAllowed
+        );
+
+        // pool_config is the first account of flash_loan_end
+        let mut index = current_index + 1;
+        while let Ok(ix) = load_instruction_at_checked(index, &instructions) {
+            if ix.program_id == crate::ID
+                && ix.data.starts_with(crate::instruction::FlashLoanEnd::DISCRIMINATOR)
+                && ix.accounts.first().map(|meta| meta.pubkey) == Some(self.pool_config.key())
+            {
+                return Ok(());
+            }
+            index += 1;
+        }
+
+        err!(AmmError::MissingFlashLoanEnd)
+    }
+}
@@ -0,0 +1,87 @@
+// Flash Loan End Instruction
+//
+// Closes out the active flash loan by checking the vault was repaid.
+// Must run in the same transaction as flash_loan_begin (enforced by begin).
+//
+// SECURITY:
+// - Repayment is verified against the real vault balance, not borrower input
+// - Vault must hold at least balance_before + fee (fee goes to LPs)
+// - Vault is pinned to the pool authority ATA for the borrowed mint
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct FlashLoanEnd<'info> {
+    // Must stay the first account - flash_loan_begin looks for it at index 0
+    #[account(
+        mut,
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+
+    /// CHECK: PDA used to derive the vault address
+    #[account(
+        seeds = [AMM_AUTHORITY_SEED, pool_config.key().as_ref()],
+        bump = pool_config.authority_bump,
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(
+        address = pool_config.flash_loan_mint @ AmmError::InvalidMint,
+        mint::token_program = token_program,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        associated_token::mint = token_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> FlashLoanEnd<'info> {
+    pub fn flash_loan_end(&mut self) -> Result<()> {
+        require!(
+            self.pool_config.flash_loan_active,
+            AmmError::NoActiveFlashLoan
+        );
+
+        // Vault must be back to where it started plus the fee
+        let required_balance = self
+            .pool_config
+            .flash_loan_vault_balance_before
+            .checked_add(self.pool_config.flash_loan_fee)
+            .ok_or(AmmError::Overflow)?;
+
+        require!(
+            self.token_vault.amount >= required_balance,
+            AmmError::FlashLoanNotRepaid
+        );
+
+        msg!(
+            "Flash loan repaid: vault {} (required {})",
+            self.token_vault.amount,
+            required_balance
+        );
+
+        // Clear loan state so pool operations resume
+        self.pool_config.flash_loan_active = false;
+        self.pool_config.flash_loan_mint = Pubkey::default();
+        self.pool_config.flash_loan_vault_balance_before = 0;
+        self.pool_config.flash_loan_fee = 0;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+// Initialize AMM Config Instruction
+//
+// Creates the optional global config that makes pool creation permissioned.
+// The config is a singleton PDA, so it can only be initialized once - deploy
+// scripts should call this immediately after the program is deployed.
+//
+// SECURITY:
+// - Only the program's upgrade authority can initialize the config, so nobody
+//   can front-run the deploy script and take over pool creation
+// - Fee tiers must be non-empty, bounded by MAX_FEE_TIERS, and unique
+// - Every fee tier must respect MAX_FEE_BASIS_POINTS
+
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, program::AmmSecure, state::*};
+
+#[derive(Accounts)]
+pub struct InitializeAmmConfig<'info> {
+    // Program upgrade authority, becomes the config admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR + AmmConfig::INIT_SPACE,
+        seeds = [AMM_GLOBAL_CONFIG_SEED],
+        bump
+    )]
+    pub amm_config: Box<Account<'info, AmmConfig>>,
+
+    // This program, to locate its ProgramData account
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+            @ AmmError::InvalidProgramData
+    )]
+    pub program: Program<'info, AmmSecure>,
+
+    // SECURITY: The upgrade authority stored here must sign
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ AmmError::UnauthorizedConfigAdmin
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeAmmConfig<'info> {
+    pub fn initialize_amm_config(
+        &mut self,
+        pool_creation_authority: Pubkey,
+        fee_tiers: Vec<u16>,
+        bumps: &InitializeAmmConfigBumps,
+    ) -> Result<()> {
+        AmmConfig::validate_fee_tiers(&fee_tiers)?;
+
+        self.amm_config.set_inner(AmmConfig {
+            admin: self.admin.key(),
+            pool_creation_authority,
+            fee_tiers,
+            bump: bumps.amm_config,
+        });
+
+        msg!("AMM config initialized: pool creator {}", pool_creation_authority);
+        msg!("Fee tiers: {:?}", self.amm_config.fee_tiers);
+
+        Ok(())
+    }
+}
@@ -1,22 +1,42 @@
 // Initialize Pool Instruction
 //
 // Creates a new AMM liquidity pool for a token pair.
+//
+// PERMISSIONED CREATION:
+// The global AmmConfig PDA is always passed in. If it has not been initialized,
+// pool creation is permissionless. Once initialized, only its
+// pool_creation_authority can create pools, and only with a whitelisted fee tier.
+// The address is pinned by seeds so callers cannot omit or substitute it.
 
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::{constants::*, errors::*, state::*};
 
 #[derive(Accounts)]
+#[instruction(fee_basis_points: u16)]
 pub struct InitializePool<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
-    pub token_a_mint: Box<Account<'info, Mint>>,
-    pub token_b_mint: Box<Account<'info, Mint>>,
+    /// CHECK: Canonical PDA enforced by seeds; deserialized as AmmConfig
+    /// (owner + discriminator checked) in the handler when initialized
+    #[account(
+        seeds = [AMM_GLOBAL_CONFIG_SEED],
+        bump
+    )]
+    pub amm_config: UncheckedAccount<'info>,
+
+    // Both mints must be owned by the token program passed in
+    // (SPL Token or Token-2022), so a single program handles every CPI
+    #[account(mint::token_program = token_program)]
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mint::token_program = token_program)]
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         init,
@@ -26,6 +46,7 @@ pub struct InitializePool<'info> {
             AMM_CONFIG_SEED,
             token_a_mint.key().as_ref(),
             token_b_mint.key().as_ref(),
+            fee_basis_points.to_le_bytes().as_ref(),
         ],
         bump
     )]
@@ -45,26 +66,29 @@ pub struct InitializePool<'info> {
         bump,
         mint::decimals = 9,
         mint::authority = pool_authority,
+        mint::token_program = token_program,
     )]
-    pub lp_token_mint: Box<Account<'info, Mint>>,
+    pub lp_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         init,
         payer = authority,
         associated_token::mint = token_a_mint,
         associated_token::authority = pool_authority,
+        associated_token::token_program = token_program,
     )]
-    pub token_a_vault: Box<Account<'info, TokenAccount>>,
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         init,
         payer = authority,
         associated_token::mint = token_b_mint,
         associated_token::authority = pool_authority,
+        associated_token::token_program = token_program,
     )]
-    pub token_b_vault: Box<Account<'info, TokenAccount>>,
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
@@ -73,6 +97,7 @@ impl<'info> InitializePool<'info> {
     pub fn initialize_pool(
         &mut self,
         fee_basis_points: u16,
+        referral_fee_basis_points: u16,
         bumps: &InitializePoolBumps,
     ) -> Result<()> {
         // Validate fee is within limits (max 10%)
@@ -81,12 +106,21 @@ impl<'info> InitializePool<'info> {
             AmmError::FeeTooHigh
         );
 
+        // Referrers can take at most half of the swap fee
+        require!(
+            referral_fee_basis_points <= MAX_REFERRAL_FEE_BASIS_POINTS,
+            AmmError::ReferralFeeTooHigh
+        );
+
         // Ensure token mints are different
         require!(
             self.token_a_mint.key() != self.token_b_mint.key(),
             AmmError::IdenticalTokenMints
         );
 
+        // Enforce the global config if it exists
+        self.validate_pool_creation(fee_basis_points)?;
+
         // Initialize pool configuration
         self.pool_config.set_inner(PoolConfig {
             authority: self.authority.key(),
@@ -98,6 +132,24 @@ impl<'info> InitializePool<'info> {
             config_bump: bumps.pool_config,
             authority_bump: bumps.pool_authority,
             lp_mint_bump: bumps.lp_token_mint,
+            flash_loan_active: false,
+            flash_loan_mint: Pubkey::default(),
+            flash_loan_vault_balance_before: 0,
+            flash_loan_fee: 0,
+            pending_fee_basis_points: 0,
+            fee_change_unlock_time: 0,
+            fee_change_delay_seconds: FEE_CHANGE_DELAY_SECONDS,
+            emergency_withdraw_enabled: false,
+            referral_fee_basis_points,
+            oracle_program: Pubkey::default(),
+            price_feed: Pubkey::default(),
+            max_oracle_deviation_bps: 0,
+            max_price_impact_bps: 0,
+            circuit_breaker_auto_lock: false,
+            fee_growth_global: 0,
+            max_swap_per_slot_bps: 0,
+            swap_volumes: [SlotSwapVolume::default(); SWAP_VOLUME_RING_SIZE],
+            fee_tier: fee_basis_points,
         });
 
         msg!(
@@ -105,8 +157,27 @@ impl<'info> InitializePool<'info> {
             self.token_a_mint.key(),
             self.token_b_mint.key()
         );
-        msg!("Fee: {} basis points", fee_basis_points);
+        msg!(
+            "Fee: {} basis points ({} to referrers)",
+            fee_basis_points,
+            referral_fee_basis_points
+        );
 
         Ok(())
     }
+
+    // Check the creator and fee against the global AmmConfig
+    fn validate_pool_creation(&self, fee_basis_points: u16) -> Result<()> {
+        // Not initialized yet - pool creation is permissionless
+        if self.amm_config.data_is_empty() {
+            return Ok(());
+        }
+
+        // Account::try_from verifies the owner is this program and the
+        // AmmConfig discriminator matches before trusting any field
+        let amm_config_info = self.amm_config.to_account_info();
+        let amm_config = Account::<AmmConfig>::try_from(&amm_config_info)?;
+
+        amm_config.assert_can_create_pool(&self.authority.key(), fee_basis_points)
+    }
 }
@@ -0,0 +1,408 @@
+// Migrate Liquidity Instruction
+//
+// Moves an LP position from one pool to another pool of the same token pair
+// (a different fee tier) in a single instruction, without the tokens ever
+// passing through the user's wallet.
+//
+// HOW IT WORKS:
+// 1. Source LP tokens are priced like withdraw_liquidity: amount = (lp / supply) * vault
+// 2. The withdrawn amounts are priced like deposit_liquidity against the
+//    destination reserves (first deposit or proportional)
+// 3. Source LP is burned, and the deposit moves straight from the source
+//    vaults to the destination vaults (source pool authority signs)
+// 4. Whatever the destination ratio does not take is refunded to the user
+// 5. Destination LP is minted to the user (destination pool authority signs)
+//
+// Pools are keyed by their ordered mint pair and fee tier, so the destination
+// is usually the same pair at another tier. A pool created with the mints
+// reversed also qualifies, which is why vaults are named by mint rather than
+// by the destination's A/B order.
+//
+// SECURITY:
+// - Both pools must be unlocked with no flash loan in progress
+// - The destination must be a different pool holding both source mints;
+//   otherwise one side would land in an account the destination never reads
+// - Slippage protection: min_lp_out on the destination LP minted
+// - Expiration check: Prevents stale transactions
+// - Neither pool may end with a lower k per LP token (assert_k_not_decreased);
+//   a first deposit into an empty destination has nothing to compare to
+//
+// TOKEN-2022:
+// The deposit is priced on what the destination vaults receive after one
+// transfer fee, then grossed up so they receive exactly that. The remainder of
+// the withdrawal, net of its own fee, is refunded to the user.
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, errors::*, helpers::*, state::*};
+
+#[derive(Accounts)]
+pub struct MigrateLiquidity<'info> {
+    // LP moving between pools (pays for ATA creation if needed)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Pool the position leaves
+    #[account(
+        seeds = [
+            AMM_CONFIG_SEED,
+            source_pool_config.token_a_mint.as_ref(),
+            source_pool_config.token_b_mint.as_ref(),
+            source_pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = source_pool_config.config_bump,
+    )]
+    pub source_pool_config: Box<Account<'info, PoolConfig>>,
+
+    /// CHECK: PDA signer for the source vaults
+    #[account(
+        seeds = [AMM_AUTHORITY_SEED, source_pool_config.key().as_ref()],
+        bump = source_pool_config.authority_bump,
+    )]
+    pub source_pool_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LP_MINT_SEED, source_pool_config.key().as_ref()],
+        bump = source_pool_config.lp_mint_bump,
+    )]
+    pub source_lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Pool the position joins - checked against the source pair in the handler
+    #[account(
+        seeds = [
+            AMM_CONFIG_SEED,
+            dest_pool_config.token_a_mint.as_ref(),
+            dest_pool_config.token_b_mint.as_ref(),
+            dest_pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = dest_pool_config.config_bump,
+    )]
+    pub dest_pool_config: Box<Account<'info, PoolConfig>>,
+
+    /// CHECK: PDA signer for the destination LP mint
+    #[account(
+        seeds = [AMM_AUTHORITY_SEED, dest_pool_config.key().as_ref()],
+        bump = dest_pool_config.authority_bump,
+    )]
+    pub dest_pool_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LP_MINT_SEED, dest_pool_config.key().as_ref()],
+        bump = dest_pool_config.lp_mint_bump,
+        mint::authority = dest_pool_authority,
+        mint::token_program = token_program,
+    )]
+    pub dest_lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Token A and B of the source pool
+    #[account(address = source_pool_config.token_a_mint)]
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = source_pool_config.token_b_mint)]
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = source_lp_mint,
+        token::authority = user,
+        token::token_program = token_program,
+    )]
+    pub user_source_lp: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = dest_lp_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_dest_lp: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Receive whatever the destination ratio does not take
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_a_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_b_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_a_mint,
+        associated_token::authority = source_pool_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub source_vault_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_b_mint,
+        associated_token::authority = source_pool_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub source_vault_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Destination vaults, named by mint: these are the destination's B and A
+    // vaults when its mints are in reverse order
+    #[account(
+        mut,
+        associated_token::mint = token_a_mint,
+        associated_token::authority = dest_pool_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub dest_vault_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_b_mint,
+        associated_token::authority = dest_pool_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub dest_vault_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateLiquidity<'info> {
+    pub fn migrate_liquidity(
+        &mut self,
+        lp_amount: u64,
+        min_lp_out: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        // Both pools must be open for withdrawals and deposits
+        self.source_pool_config.assert_not_locked()?;
+        self.source_pool_config.assert_no_flash_loan()?;
+        self.dest_pool_config.assert_not_locked()?;
+        self.dest_pool_config.assert_no_flash_loan()?;
+
+        // Validate expiration using helper
+        validate_expiration(expiration)?;
+
+        // Destination must be another pool of this pair, so both sides land
+        // in vaults the destination prices against
+        require_keys_neq!(
+            self.dest_pool_config.key(),
+            self.source_pool_config.key(),
+            AmmError::InvalidMigrationPool
+        );
+        require!(
+            self.dest_pool_config.is_pool_mint(&self.token_a_mint.key())
+                && self.dest_pool_config.is_pool_mint(&self.token_b_mint.key()),
+            AmmError::InvalidMigrationPool
+        );
+
+        // Check non-zero LP amount
+        require!(lp_amount > 0, AmmError::ZeroWithdrawAmount);
+        require!(self.user_source_lp.amount >= lp_amount, AmmError::InsufficientBalance);
+
+        // Both pools before any tokens move, for the k per LP token checks below
+        let source_pre = PoolSnapshot::new(
+            self.source_vault_a.amount,
+            self.source_vault_b.amount,
+            self.source_lp_mint.supply,
+        );
+        let dest_pre = PoolSnapshot::new(
+            self.dest_vault_a.amount,
+            self.dest_vault_b.amount,
+            self.dest_lp_mint.supply,
+        );
+
+        // Withdraw side, priced on the source reserves
+        let source_lp_supply = self.source_lp_mint.supply;
+        require!(source_lp_supply > 0, AmmError::InsufficientLiquidity);
+
+        let (amount_a, amount_b) = calculate_withdrawal(
+            lp_amount,
+            self.source_vault_a.amount,
+            self.source_vault_b.amount,
+            source_lp_supply,
+        )?;
+        require!(amount_a > 0, AmmError::InsufficientLiquidity);
+        require!(amount_b > 0, AmmError::InsufficientLiquidity);
+
+        // What the destination vaults would receive after Token-2022 transfer fees
+        let net_amount_a = amount_a
+            .checked_sub(calculate_transfer_fee(&self.token_a_mint, amount_a)?)
+            .ok_or(AmmError::Underflow)?;
+        let net_amount_b = amount_b
+            .checked_sub(calculate_transfer_fee(&self.token_b_mint, amount_b)?)
+            .ok_or(AmmError::Underflow)?;
+
+        // Deposit side, priced on the destination reserves
+        let dest_lp_supply = self.dest_lp_mint.supply;
+        let (deposit_a, deposit_b, lp_out) = if dest_lp_supply == 0 {
+            calculate_first_deposit(net_amount_a, net_amount_b)?
+        } else {
+            calculate_subsequent_deposit(
+                net_amount_a,
+                net_amount_b,
+                self.dest_vault_a.amount,
+                self.dest_vault_b.amount,
+                dest_lp_supply,
+            )?
+        };
+
+        // Slippage protection on the destination LP
+        require!(lp_out > 0, AmmError::InsufficientLiquidity);
+        require!(lp_out >= min_lp_out, AmmError::SlippageExceeded);
+
+        // Gross up so the destination vaults receive exactly deposit_a/deposit_b
+        let gross_deposit_a = deposit_a
+            .checked_add(calculate_inverse_transfer_fee(&self.token_a_mint, deposit_a)?)
+            .ok_or(AmmError::Overflow)?;
+        let gross_deposit_b = deposit_b
+            .checked_add(calculate_inverse_transfer_fee(&self.token_b_mint, deposit_b)?)
+            .ok_or(AmmError::Overflow)?;
+
+        // The rest of the withdrawal goes back to the user
+        let refund_a = amount_a
+            .checked_sub(gross_deposit_a)
+            .ok_or(AmmError::Underflow)?;
+        let refund_b = amount_b
+            .checked_sub(gross_deposit_b)
+            .ok_or(AmmError::Underflow)?;
+
+        // Burn source LP using helper
+        burn_lp_tokens(
+            lp_amount,
+            &self.token_program.to_account_info(),
+            &self.source_lp_mint.to_account_info(),
+            &self.user_source_lp.to_account_info(),
+            &self.user.to_account_info(),
+        )?;
+
+        let source_pool_key = self.source_pool_config.key();
+        let source_seeds = &[
+            AMM_AUTHORITY_SEED,
+            source_pool_key.as_ref(),
+            &[self.source_pool_config.authority_bump],
+        ];
+
+        // Source vaults -> destination vaults
+        transfer_from_vault(
+            gross_deposit_a,
+            self.token_a_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.source_vault_a.to_account_info(),
+            &self.token_a_mint.to_account_info(),
+            &self.dest_vault_a.to_account_info(),
+            &self.source_pool_authority.to_account_info(),
+            source_seeds,
+        )?;
+
+        transfer_from_vault(
+            gross_deposit_b,
+            self.token_b_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.source_vault_b.to_account_info(),
+            &self.token_b_mint.to_account_info(),
+            &self.dest_vault_b.to_account_info(),
+            &self.source_pool_authority.to_account_info(),
+            source_seeds,
+        )?;
+
+        // Source vaults -> user, for the side the destination ratio left over
+        if refund_a > 0 {
+            transfer_from_vault(
+                refund_a,
+                self.token_a_mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.source_vault_a.to_account_info(),
+                &self.token_a_mint.to_account_info(),
+                &self.user_token_a.to_account_info(),
+                &self.source_pool_authority.to_account_info(),
+                source_seeds,
+            )?;
+        }
+
+        if refund_b > 0 {
+            transfer_from_vault(
+                refund_b,
+                self.token_b_mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.source_vault_b.to_account_info(),
+                &self.token_b_mint.to_account_info(),
+                &self.user_token_b.to_account_info(),
+                &self.source_pool_authority.to_account_info(),
+                source_seeds,
+            )?;
+        }
+
+        // Mint destination LP using helper
+        let dest_pool_key = self.dest_pool_config.key();
+        let dest_seeds = &[
+            AMM_AUTHORITY_SEED,
+            dest_pool_key.as_ref(),
+            &[self.dest_pool_config.authority_bump],
+        ];
+
+        mint_lp_tokens(
+            lp_out,
+            &self.token_program.to_account_info(),
+            &self.dest_lp_mint.to_account_info(),
+            &self.user_dest_lp.to_account_info(),
+            &self.dest_pool_authority.to_account_info(),
+            dest_seeds,
+        )?;
+
+        // Defense in depth: neither the withdrawal from the source nor the
+        // deposit into the destination may lower that pool's k per LP token
+        // k is symmetric in the reserves, so vaults named by mint compare fine
+        self.source_vault_a.reload()?;
+        self.source_vault_b.reload()?;
+        self.source_lp_mint.reload()?;
+        self.dest_vault_a.reload()?;
+        self.dest_vault_b.reload()?;
+        self.dest_lp_mint.reload()?;
+        assert_k_not_decreased(
+            source_pre,
+            PoolSnapshot::new(
+                self.source_vault_a.amount,
+                self.source_vault_b.amount,
+                self.source_lp_mint.supply,
+            ),
+            (0, 0),
+        )?;
+        assert_k_not_decreased(
+            dest_pre,
+            PoolSnapshot::new(
+                self.dest_vault_a.amount,
+                self.dest_vault_b.amount,
+                self.dest_lp_mint.supply,
+            ),
+            (0, 0),
+        )?;
+
+        msg!(
+            "Migrated: {} LP -> {} A, {} B -> {} LP ({} A, {} B refunded)",
+            lp_amount,
+            deposit_a,
+            deposit_b,
+            lp_out,
+            refund_a,
+            refund_b
+        );
+
+        Ok(())
+    }
+}
@@ -2,16 +2,58 @@
 //
 // Exports all instruction handlers for the AMM program
 
+pub mod initialize_amm_config;
+pub mod update_amm_config;
 pub mod initialize_pool;
 pub mod deposit_liquidity;
+pub mod deposit_liquidity_sol;
 pub mod withdraw_liquidity;
+pub mod migrate_liquidity;
 pub mod swap_tokens;
+pub mod swap_with_rebate;
+pub mod swap_sol_for_token;
 pub mod lock_pool;
 pub mod unlock_pool;
+pub mod flash_loan_begin;
+pub mod flash_loan_end;
+pub mod request_fee_change;
+pub mod change_pool_fee;
+pub mod set_emergency_withdraw;
+pub mod emergency_withdraw;
+pub mod set_oracle_guard;
+pub mod set_circuit_breaker;
+pub mod set_swap_limit;
+pub mod deposit_position;
+pub mod withdraw_position;
+pub mod claim_lp_fees;
+pub mod place_limit_order;
+pub mod cancel_limit_order;
+pub mod fill_limit_order;
 
+pub use initialize_amm_config::*;
+pub use update_amm_config::*;
 pub use initialize_pool::*;
 pub use deposit_liquidity::*;
+pub use deposit_liquidity_sol::*;
 pub use withdraw_liquidity::*;
+pub use migrate_liquidity::*;
 pub use swap_tokens::*;
+pub use swap_with_rebate::*;
+pub use swap_sol_for_token::*;
 pub use lock_pool::*;
 pub use unlock_pool::*;
+pub use flash_loan_begin::*;
+pub use flash_loan_end::*;
+pub use request_fee_change::*;
+pub use change_pool_fee::*;
+pub use set_emergency_withdraw::*;
+pub use emergency_withdraw::*;
+pub use set_oracle_guard::*;
+pub use set_circuit_breaker::*;
+pub use set_swap_limit::*;
+pub use deposit_position::*;
+pub use withdraw_position::*;
+pub use claim_lp_fees::*;
+pub use place_limit_order::*;
+pub use cancel_limit_order::*;
+pub use fill_limit_order::*;
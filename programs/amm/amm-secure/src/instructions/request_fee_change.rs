@@ -0,0 +1,73 @@
+// Request Fee Change Instruction
+//
+// First step of the two-step fee change. Queues a new swap fee that can only be
+// applied by change_pool_fee after the pool's fee_change_delay_seconds.
+// Requesting again replaces the pending fee and restarts the delay.
+//
+// SECURITY:
+// - Only pool authority can request
+// - New fee validated against MAX_FEE_BASIS_POINTS up front
+// - Once the global AmmConfig exists, the new fee must be a whitelisted tier,
+//   the same rule initialize_pool applies
+// - LPs see the pending fee and unlock time on-chain before it takes effect
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct RequestFeeChange<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+
+    /// CHECK: Canonical PDA enforced by seeds; deserialized as AmmConfig
+    /// (owner + discriminator checked) in the handler when initialized
+    #[account(
+        seeds = [AMM_GLOBAL_CONFIG_SEED],
+        bump
+    )]
+    pub amm_config: UncheckedAccount<'info>,
+}
+
+impl<'info> RequestFeeChange<'info> {
+    pub fn request_fee_change(&mut self, new_fee_basis_points: u16) -> Result<()> {
+        // Validate authority
+        self.pool_config.assert_is_authority(&self.authority.key())?;
+
+        // Validate fee is within limits (max 10%)
+        require!(
+            new_fee_basis_points <= MAX_FEE_BASIS_POINTS,
+            AmmError::FeeTooHigh
+        );
+
+        // Pools cannot leave the whitelisted tiers after creation
+        AmmConfig::assert_fee_allowed(&self.amm_config, new_fee_basis_points)?;
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let unlock_time = current_time
+            .checked_add(self.pool_config.fee_change_delay_seconds)
+            .ok_or(AmmError::Overflow)?;
+
+        self.pool_config.pending_fee_basis_points = new_fee_basis_points;
+        self.pool_config.fee_change_unlock_time = unlock_time;
+
+        msg!(
+            "Fee change requested: {} -> {} bp, executable after {}",
+            self.pool_config.fee_basis_points,
+            new_fee_basis_points,
+            unlock_time
+        );
+
+        Ok(())
+    }
+}
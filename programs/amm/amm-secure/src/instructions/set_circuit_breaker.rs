@@ -0,0 +1,55 @@
+// Set Circuit Breaker Instruction
+//
+// Caps how far a single swap may move the pool price, and chooses what happens
+// to a swap over the cap: rejected, or the pool is locked until the authority
+// unlocks it. Only pool authority can set.
+//
+// Setting max_price_impact_bps to 0 disables the circuit breaker.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetCircuitBreaker<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+}
+
+impl<'info> SetCircuitBreaker<'info> {
+    pub fn set_circuit_breaker(&mut self, max_price_impact_bps: u16, auto_lock: bool) -> Result<()> {
+        // Validate authority
+        self.pool_config.assert_is_authority(&self.authority.key())?;
+
+        require!(
+            max_price_impact_bps <= MAX_PRICE_IMPACT_BASIS_POINTS,
+            AmmError::InvalidCircuitBreakerConfig
+        );
+
+        // Auto-lock only means something with a limit to trip
+        if auto_lock {
+            require!(max_price_impact_bps > 0, AmmError::InvalidCircuitBreakerConfig);
+        }
+
+        self.pool_config.max_price_impact_bps = max_price_impact_bps;
+        self.pool_config.circuit_breaker_auto_lock = auto_lock;
+
+        msg!(
+            "Circuit breaker: max price impact {} basis points, auto-lock {}",
+            max_price_impact_bps,
+            auto_lock
+        );
+
+        Ok(())
+    }
+}
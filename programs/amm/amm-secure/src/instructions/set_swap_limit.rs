@@ -0,0 +1,45 @@
+// Set Swap Limit Instruction
+//
+// Caps the input each pool vault may receive from swaps within one slot, as a
+// share of its balance at the start of the slot. Bounds single-block sandwiches
+// and drains split into many swaps. Only pool authority can set.
+//
+// Setting max_swap_per_slot_bps to 0 disables the limit.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetSwapLimit<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+}
+
+impl<'info> SetSwapLimit<'info> {
+    pub fn set_swap_limit(&mut self, max_swap_per_slot_bps: u16) -> Result<()> {
+        // Validate authority
+        self.pool_config.assert_is_authority(&self.authority.key())?;
+
+        require!(
+            max_swap_per_slot_bps <= MAX_SWAP_PER_SLOT_BASIS_POINTS,
+            AmmError::InvalidSwapLimitConfig
+        );
+
+        self.pool_config.max_swap_per_slot_bps = max_swap_per_slot_bps;
+
+        msg!("Swap limit: {} basis points of reserves per slot", max_swap_per_slot_bps);
+
+        Ok(())
+    }
+}
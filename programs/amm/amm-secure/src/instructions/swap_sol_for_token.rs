@@ -0,0 +1,86 @@
+// Swap SOL For Token Instruction
+//
+// Exact-input swap out of the wrapped SOL side of a pool, paid in native SOL.
+// input_amount is in lamports. Everything else (slippage, expiration,
+// referrals, oracle guard, circuit breaker) is the swap_tokens path.
+//
+// HOW IT WORKS:
+// 1. The swapper's wSOL ATA is created if needed and must start empty
+// 2. input_amount lamports are wrapped into it (transfer + sync_native)
+// 3. The swap runs from the wSOL ATA with the pool fee
+// 4. The wSOL ATA is closed back to the swapper, refunding its rent
+//
+// If the circuit breaker locks the pool instead of swapping, the close returns
+// the wrapped SOL along with the rent.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{errors::*, helpers::*, instructions::SwapTokens};
+
+#[derive(Accounts)]
+pub struct SwapSolForToken<'info> {
+    pub swap: SwapTokens<'info>,
+}
+
+impl<'info> SwapSolForToken<'info> {
+    pub fn swap_sol_for_token(
+        &mut self,
+        input_amount: u64,
+        min_output_amount: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        // SOL goes in, so the wSOL side is the input side
+        let swap_token_a_for_b = native_sol_is_token_a(
+            &self.swap.token_a_mint.key(),
+            &self.swap.token_b_mint.key(),
+        )?;
+
+        // Only an empty wSOL ATA is treated as temporary
+        let wrapped_sol_account = self.wrapped_sol_account(swap_token_a_for_b);
+        require!(
+            wrapped_sol_account.amount == 0,
+            AmmError::WrappedSolAccountNotEmpty
+        );
+        let wrapped_sol_info = wrapped_sol_account.to_account_info();
+
+        wrap_sol(
+            input_amount,
+            &self.swap.token_program.to_account_info(),
+            &self.swap.system_program.to_account_info(),
+            &self.swap.swapper.to_account_info(),
+            &wrapped_sol_info,
+        )?;
+
+        // The circuit breaker reads the swapper's input balance
+        if swap_token_a_for_b {
+            self.swap.swapper_token_a.reload()?;
+        } else {
+            self.swap.swapper_token_b.reload()?;
+        }
+
+        let fee_basis_points = self.swap.pool_config.fee_basis_points;
+        self.swap.swap_exact_in(
+            swap_token_a_for_b,
+            input_amount,
+            min_output_amount,
+            expiration,
+            fee_basis_points,
+        )?;
+
+        close_wrapped_sol(
+            &self.swap.token_program.to_account_info(),
+            &self.swap.swapper.to_account_info(),
+            &wrapped_sol_info,
+        )
+    }
+
+    // The swapper's ATA for the wSOL side
+    fn wrapped_sol_account(&self, swap_token_a_for_b: bool) -> &InterfaceAccount<'info, TokenAccount> {
+        if swap_token_a_for_b {
+            &self.swap.swapper_token_a
+        } else {
+            &self.swap.swapper_token_b
+        }
+    }
+}
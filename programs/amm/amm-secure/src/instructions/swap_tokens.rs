@@ -2,26 +2,68 @@
 //
 // Swaps tokens using constant product formula (x * y = k).
 // Fee is deducted from input before calculating output.
+//
+// Two modes share the same accounts:
+// - swap_tokens: exact input, output must be >= min_output_amount
+// - swap_tokens_exact_out: exact output, input must be <= max_input_amount
+//   (inverse curve math rounds up so the pool is never underpaid)
+//
+// REFERRALS:
+// If a referrer token account is passed, referral_fee_basis_points of the swap
+// fee is paid to it from the input vault after the deposit. The referral is
+// always smaller than the fee, so k still grows. Pool vaults cannot be referrers.
+//
+// ORACLE GUARD:
+// If the pool authority set max_oracle_deviation_bps, the pinned price feed must be
+// passed and the post-swap pool price must stay within that distance of the oracle
+// price. The feed must be owned by the pinned oracle program, published, fresh, and
+// confident enough. A swap that pushes the pool away from the market is rejected.
+//
+// CIRCUIT BREAKER:
+// If the pool authority set max_price_impact_bps, a swap that would move the pool
+// price further than that in one go is stopped before any tokens move. It is
+// rejected, or with circuit_breaker_auto_lock the pool is locked until the
+// authority unlocks it, so one giant swap cannot drain the output vault.
+//
+// PER-SLOT SWAP LIMIT:
+// If the pool authority set max_swap_per_slot_bps, the input each vault receives
+// from swaps within one slot is capped at that share of its balance at the start
+// of the slot. A sandwich puts its front-run, the victim's swap and its back-run
+// in the same slot, so the cap bounds how far it can move the price; splitting a
+// drain into many small swaps in one block runs into the same cap.
+//
+// INVARIANT:
+// After the transfers the reloaded vaults, with any referral payout added back,
+// must hold at least the k they started with (assert_k_not_decreased).
+//
+// TOKEN-2022:
+// With transfer fee mints the vault only receives input_amount - input_fee, and the
+// swapper only receives output - output_fee. The curve is priced on the amount the
+// vault actually receives, and min_output_amount is checked against what the
+// swapper actually receives.
 
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount, Transfer, transfer},
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked},
 };
 use constant_product_curve::{ConstantProduct, LiquidityPair};
 
-use crate::{constants::*, errors::*, state::*};
+use crate::{constants::*, errors::*, helpers::*, state::*};
 
 #[derive(Accounts)]
 pub struct SwapTokens<'info> {
     #[account(mut)]
     pub swapper: Signer<'info>,
 
+    // Mutable so the circuit breaker can lock the pool
     #[account(
+        mut,
         seeds = [
             AMM_CONFIG_SEED,
             pool_config.token_a_mint.as_ref(),
             pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
         ],
         bump = pool_config.config_bump,
     )]
@@ -35,44 +77,56 @@ pub struct SwapTokens<'info> {
     pub pool_authority: UncheckedAccount<'info>,
 
     #[account(address = pool_config.token_a_mint)]
-    pub token_a_mint: Box<Account<'info, Mint>>,
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(address = pool_config.token_b_mint)]
-    pub token_b_mint: Box<Account<'info, Mint>>,
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         init_if_needed,
         payer = swapper,
         associated_token::mint = token_a_mint,
         associated_token::authority = swapper,
+        associated_token::token_program = token_program,
     )]
-    pub swapper_token_a: Box<Account<'info, TokenAccount>>,
+    pub swapper_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         init_if_needed,
         payer = swapper,
         associated_token::mint = token_b_mint,
         associated_token::authority = swapper,
+        associated_token::token_program = token_program,
     )]
-    pub swapper_token_b: Box<Account<'info, TokenAccount>>,
+    pub swapper_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
         token::mint = token_a_mint,
         token::authority = pool_authority,
+        token::token_program = token_program,
     )]
-    pub token_a_vault: Box<Account<'info, TokenAccount>>,
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
         token::mint = token_b_mint,
         token::authority = pool_authority,
+        token::token_program = token_program,
     )]
-    pub token_b_vault: Box<Account<'info, TokenAccount>>,
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+
+    // Optional referrer token account (input mint) paid a slice of the swap fee
+    #[account(mut)]
+    pub referrer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    // Optional oracle feed, required when the pool has an oracle guard
+    /// CHECK: Matched against pool_config.price_feed, owner and layout checked on read
+    pub price_feed: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> SwapTokens<'info> {
@@ -82,12 +136,36 @@ impl<'info> SwapTokens<'info> {
         input_amount: u64,
         min_output_amount: u64,
         expiration: i64,
+    ) -> Result<()> {
+        let fee_basis_points = self.pool_config.fee_basis_points;
+        self.swap_exact_in(
+            swap_token_a_for_b,
+            input_amount,
+            min_output_amount,
+            expiration,
+            fee_basis_points,
+        )
+    }
+
+    // Exact-input swap priced with fee_basis_points
+    // swap_tokens passes the pool fee, swap_with_rebate the rebated one
+    pub fn swap_exact_in(
+        &mut self,
+        swap_token_a_for_b: bool,
+        input_amount: u64,
+        min_output_amount: u64,
+        expiration: i64,
+        fee_basis_points: u16,
     ) -> Result<()> {
         // Check pool not locked
+        #[cfg(not(feature = "vuln-missing-lock-check"))]
         self.pool_config.assert_not_locked()?;
 
+        // Borrowed flash loan tokens cannot be routed back through the pool
+        self.pool_config.assert_no_flash_loan()?;
+
         // Validate expiration
-        self.validate_expiration(expiration)?;
+        validate_expiration(expiration)?;
 
         // Check non-zero amounts
         require!(input_amount > 0, AmmError::ZeroSwapAmount);
@@ -100,12 +178,26 @@ impl<'info> SwapTokens<'info> {
         require!(vault_a_balance > 0, AmmError::InsufficientPoolLiquidity);
         require!(vault_b_balance > 0, AmmError::InsufficientPoolLiquidity);
 
+        // Determine input/output mints for transfer fee calculation
+        let (input_mint, output_mint) = if swap_token_a_for_b {
+            (&self.token_a_mint, &self.token_b_mint)
+        } else {
+            (&self.token_b_mint, &self.token_a_mint)
+        };
+
+        // Price the swap on what the vault actually receives after transfer fees
+        let input_transfer_fee = calculate_transfer_fee(input_mint, input_amount)?;
+        let net_input_amount = input_amount
+            .checked_sub(input_transfer_fee)
+            .ok_or(AmmError::Underflow)?;
+        require!(net_input_amount > 0, AmmError::ZeroSwapAmount);
+
         // Initialize constant product curve
         let mut curve = ConstantProduct::init(
             vault_a_balance,
             vault_b_balance,
             vault_a_balance,
-            self.pool_config.fee_basis_points,
+            fee_basis_points,
             None,
         )
         .map_err(|_| AmmError::CurveCalculationFailed)?;
@@ -119,13 +211,20 @@ impl<'info> SwapTokens<'info> {
 
         // Calculate swap
         let swap_result = curve
-            .swap(swap_direction, input_amount, min_output_amount)
+            .swap(swap_direction, net_input_amount, min_output_amount)
             .map_err(|_| AmmError::CurveCalculationFailed)?;
 
         // Validate swap result
         require!(swap_result.deposit > 0, AmmError::InvalidCurveParams);
         require!(swap_result.withdraw > 0, AmmError::InvalidCurveParams);
-        require!(swap_result.withdraw >= min_output_amount, AmmError::SlippageExceeded);
+
+        // Slippage protection against what the swapper actually receives
+        let output_transfer_fee = calculate_transfer_fee(output_mint, swap_result.withdraw)?;
+        let net_output_amount = swap_result
+            .withdraw
+            .checked_sub(output_transfer_fee)
+            .ok_or(AmmError::Underflow)?;
+        require!(net_output_amount >= min_output_amount, AmmError::SlippageExceeded);
 
         // Check pool has enough output tokens
         let output_vault_balance = if swap_token_a_for_b {
@@ -138,61 +237,347 @@ impl<'info> SwapTokens<'info> {
             AmmError::InsufficientPoolLiquidity
         );
 
+        // Circuit breaker: a swap that moves the price too far never reaches the vaults
+        if self.trip_circuit_breaker(
+            swap_token_a_for_b,
+            input_amount,
+            net_input_amount,
+            swap_result.withdraw,
+        )? {
+            return Ok(());
+        }
+
+        // Per-slot limit on what the input vault receives
+        self.record_swap_volume(swap_token_a_for_b, net_input_amount)?;
+
         // Perform swap transfers
-        if swap_token_a_for_b {
-            self.deposit_token_a(swap_result.deposit)?;
+        // The swapper sends the gross input_amount; the vault is credited net_input_amount
+        let referral_amount = if swap_token_a_for_b {
+            self.deposit_token_a(input_amount)?;
+            let referral_amount =
+                self.pay_referrer(swap_token_a_for_b, net_input_amount, fee_basis_points)?;
             self.withdraw_token_b(swap_result.withdraw)?;
-            msg!("Swapped {} A -> {} B", swap_result.deposit, swap_result.withdraw);
+            msg!("Swapped {} A -> {} B", swap_result.deposit, net_output_amount);
+            referral_amount
         } else {
-            self.deposit_token_b(swap_result.deposit)?;
+            self.deposit_token_b(input_amount)?;
+            let referral_amount =
+                self.pay_referrer(swap_token_a_for_b, net_input_amount, fee_basis_points)?;
             self.withdraw_token_a(swap_result.withdraw)?;
-            msg!("Swapped {} B -> {} A", swap_result.deposit, swap_result.withdraw);
+            msg!("Swapped {} B -> {} A", swap_result.deposit, net_output_amount);
+            referral_amount
+        };
+
+        // Post-swap pool price must stay close to the oracle
+        self.check_oracle_guard()?;
+
+        // Defense in depth: the swap must not have lowered k
+        self.check_invariant(vault_a_balance, vault_b_balance, swap_token_a_for_b, referral_amount)
+    }
+
+    pub fn swap_tokens_exact_out(
+        &mut self,
+        swap_token_a_for_b: bool,
+        max_input_amount: u64,
+        output_amount: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        // Check pool not locked
+        #[cfg(not(feature = "vuln-missing-lock-check"))]
+        self.pool_config.assert_not_locked()?;
+
+        // Borrowed flash loan tokens cannot be routed back through the pool
+        self.pool_config.assert_no_flash_loan()?;
+
+        // Validate expiration
+        validate_expiration(expiration)?;
+
+        // Check non-zero amounts
+        require!(output_amount > 0, AmmError::ZeroSwapAmount);
+        require!(max_input_amount > 0, AmmError::SlippageExceeded);
+
+        let vault_a_balance = self.token_a_vault.amount;
+        let vault_b_balance = self.token_b_vault.amount;
+
+        // Check pool has liquidity
+        require!(vault_a_balance > 0, AmmError::InsufficientPoolLiquidity);
+        require!(vault_b_balance > 0, AmmError::InsufficientPoolLiquidity);
+
+        let (input_mint, output_mint, input_reserve, output_reserve) = if swap_token_a_for_b {
+            (&self.token_a_mint, &self.token_b_mint, vault_a_balance, vault_b_balance)
+        } else {
+            (&self.token_b_mint, &self.token_a_mint, vault_b_balance, vault_a_balance)
+        };
+
+        // Vault sends enough that the swapper receives exactly output_amount after transfer fees
+        let output_transfer_fee = calculate_inverse_transfer_fee(output_mint, output_amount)?;
+        let gross_output_amount = output_amount
+            .checked_add(output_transfer_fee)
+            .ok_or(AmmError::Overflow)?;
+
+        // Input the vault must receive, rounded up in the pool's favor
+        let fee_basis_points = self.pool_config.fee_basis_points;
+        let net_input_amount = calculate_exact_out_input(
+            input_reserve,
+            output_reserve,
+            gross_output_amount,
+            fee_basis_points,
+        )?;
+        require!(net_input_amount > 0, AmmError::InvalidCurveParams);
+
+        // Swapper also covers any transfer fee on the way in
+        let input_transfer_fee = calculate_inverse_transfer_fee(input_mint, net_input_amount)?;
+        let input_amount = net_input_amount
+            .checked_add(input_transfer_fee)
+            .ok_or(AmmError::Overflow)?;
+
+        // Slippage protection
+        require!(input_amount <= max_input_amount, AmmError::SlippageExceeded);
+
+        // Circuit breaker: a swap that moves the price too far never reaches the vaults
+        if self.trip_circuit_breaker(
+            swap_token_a_for_b,
+            input_amount,
+            net_input_amount,
+            gross_output_amount,
+        )? {
+            return Ok(());
         }
 
-        Ok(())
+        // Per-slot limit on what the input vault receives
+        self.record_swap_volume(swap_token_a_for_b, net_input_amount)?;
+
+        // Perform swap transfers
+        let referral_amount = if swap_token_a_for_b {
+            self.deposit_token_a(input_amount)?;
+            let referral_amount =
+                self.pay_referrer(swap_token_a_for_b, net_input_amount, fee_basis_points)?;
+            self.withdraw_token_b(gross_output_amount)?;
+            msg!("Swapped {} A -> exactly {} B", input_amount, output_amount);
+            referral_amount
+        } else {
+            self.deposit_token_b(input_amount)?;
+            let referral_amount =
+                self.pay_referrer(swap_token_a_for_b, net_input_amount, fee_basis_points)?;
+            self.withdraw_token_a(gross_output_amount)?;
+            msg!("Swapped {} B -> exactly {} A", input_amount, output_amount);
+            referral_amount
+        };
+
+        // Post-swap pool price must stay close to the oracle
+        self.check_oracle_guard()?;
+
+        // Defense in depth: the swap must not have lowered k
+        self.check_invariant(vault_a_balance, vault_b_balance, swap_token_a_for_b, referral_amount)
     }
 
-    fn validate_expiration(&self, expiration: i64) -> Result<()> {
-        let current_time = Clock::get()?.unix_timestamp;
-        require!(expiration > current_time, AmmError::TransactionExpired);
+    // Pay the referrer's slice of the swap fee out of the input vault
+    // Computed on the fee the swap was priced with, so a rebated swap pays a rebated referral
+    // Returns the amount paid; no-op returning 0 when no referrer account is passed
+    fn pay_referrer(
+        &self,
+        swap_token_a_for_b: bool,
+        net_input_amount: u64,
+        fee_basis_points: u16,
+    ) -> Result<u64> {
+        let referrer = match &self.referrer_token_account {
+            Some(referrer) => referrer,
+            None => return Ok(0),
+        };
 
-        let time_until_expiration = expiration
-            .checked_sub(current_time)
-            .ok_or(AmmError::Underflow)?;
+        let (input_mint, input_vault) = if swap_token_a_for_b {
+            (&self.token_a_mint, &self.token_a_vault)
+        } else {
+            (&self.token_b_mint, &self.token_b_vault)
+        };
+
+        // Referral is paid in the input token
+        require_keys_eq!(referrer.mint, input_mint.key(), AmmError::InvalidReferrer);
+
+        // A pool vault as referrer would turn the payout into a self-transfer:
+        // reported as paid, but the tokens never leave the pool
+        require_keys_neq!(referrer.owner, self.pool_authority.key(), AmmError::InvalidReferrer);
+
+        let referral_amount = calculate_referral_fee(
+            net_input_amount,
+            fee_basis_points,
+            self.pool_config.referral_fee_basis_points,
+        )?;
+        if referral_amount == 0 {
+            return Ok(0);
+        }
+
+        let pool_config_key = self.pool_config.key();
+        let authority_seeds = &[
+            AMM_AUTHORITY_SEED,
+            pool_config_key.as_ref(),
+            &[self.pool_config.authority_bump],
+        ];
+
+        transfer_from_vault(
+            referral_amount,
+            input_mint.decimals,
+            &self.token_program.to_account_info(),
+            &input_vault.to_account_info(),
+            &input_mint.to_account_info(),
+            &referrer.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        msg!("Referral fee paid: {} to {}", referral_amount, referrer.key());
+
+        Ok(referral_amount)
+    }
+
+    // Check the swap's price impact against the pool's circuit breaker
+    // Over the limit the swap is rejected, unless auto-lock is on: then the pool is
+    // locked and Ok(true) tells the caller to skip the transfers. An error would roll
+    // the lock back, so the tripping swap succeeds without moving any tokens.
+    // Auto-lock needs a swapper who can pay input_amount, so nobody can freeze the
+    // pool by quoting a swap they could never make
+    // No-op when the pool has no circuit breaker
+    fn trip_circuit_breaker(
+        &mut self,
+        swap_token_a_for_b: bool,
+        input_amount: u64,
+        net_input_amount: u64,
+        output_amount: u64,
+    ) -> Result<bool> {
+        if !self.pool_config.has_circuit_breaker() {
+            return Ok(false);
+        }
+
+        let (input_reserve, output_reserve, input_balance) = if swap_token_a_for_b {
+            (self.token_a_vault.amount, self.token_b_vault.amount, self.swapper_token_a.amount)
+        } else {
+            (self.token_b_vault.amount, self.token_a_vault.amount, self.swapper_token_b.amount)
+        };
+
+        let impact_bps = calculate_price_impact_bps(
+            input_reserve,
+            output_reserve,
+            net_input_amount,
+            output_amount,
+        )?;
+        if impact_bps <= self.pool_config.max_price_impact_bps as u64 {
+            return Ok(false);
+        }
 
         require!(
-            time_until_expiration <= MAX_EXPIRATION_SECONDS,
-            AmmError::ExpirationTooFar
+            self.pool_config.circuit_breaker_auto_lock && input_balance >= input_amount,
+            AmmError::PriceImpactTooHigh
+        );
+
+        self.pool_config.lock()?;
+        msg!("Circuit breaker tripped: {} basis points price impact, pool locked", impact_bps);
+
+        Ok(true)
+    }
+
+    // Count the swap's net input against the pool's per-slot limit
+    // Called before any transfer, so the vaults still hold the pre-swap reserves
+    fn record_swap_volume(&mut self, swap_token_a_for_b: bool, net_input_amount: u64) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        let (reserve_a, reserve_b) = (self.token_a_vault.amount, self.token_b_vault.amount);
+
+        self.pool_config.record_swap_volume(
+            slot,
+            swap_token_a_for_b,
+            net_input_amount,
+            reserve_a,
+            reserve_b,
+        )
+    }
+
+    // Reject the swap if it left the pool price too far from the oracle price
+    // No-op when the pool has no oracle guard
+    fn check_oracle_guard(&mut self) -> Result<()> {
+        if !self.pool_config.has_oracle_guard() {
+            return Ok(());
+        }
+
+        let feed = self.price_feed.as_ref().ok_or(AmmError::PriceFeedRequired)?;
+        require_keys_eq!(feed.key(), self.pool_config.price_feed, AmmError::InvalidPriceFeed);
+
+        let price_feed =
+            PriceFeed::load(&feed.to_account_info(), &self.pool_config.oracle_program)?;
+        price_feed.assert_usable(Clock::get()?.unix_timestamp)?;
+
+        // Compare against the reserves the swap left behind
+        self.token_a_vault.reload()?;
+        self.token_b_vault.reload()?;
+
+        let deviation_bps = calculate_oracle_deviation_bps(
+            self.token_a_vault.amount,
+            self.token_b_vault.amount,
+            self.token_a_mint.decimals,
+            self.token_b_mint.decimals,
+            price_feed.price,
+            price_feed.exponent,
+        )?;
+        require!(
+            deviation_bps <= self.pool_config.max_oracle_deviation_bps as u128,
+            AmmError::OraclePriceDeviation
         );
 
         Ok(())
     }
 
+    // Check the reserves the swap left behind against the ones it started from
+    // The referral left the input vault as part of the fee, so it is counted back in
+    fn check_invariant(
+        &mut self,
+        reserve_a_before: u64,
+        reserve_b_before: u64,
+        swap_token_a_for_b: bool,
+        referral_amount: u64,
+    ) -> Result<()> {
+        self.token_a_vault.reload()?;
+        self.token_b_vault.reload()?;
+
+        let fees = if swap_token_a_for_b {
+            (referral_amount, 0)
+        } else {
+            (0, referral_amount)
+        };
+
+        assert_k_not_decreased(
+            PoolSnapshot::reserves(reserve_a_before, reserve_b_before),
+            PoolSnapshot::reserves(self.token_a_vault.amount, self.token_b_vault.amount),
+            fees,
+        )
+    }
+
     fn deposit_token_a(&self, amount: u64) -> Result<()> {
-        transfer(
+        transfer_checked(
             CpiContext::new(
                 self.token_program.to_account_info(),
-                Transfer {
+                TransferChecked {
                     from: self.swapper_token_a.to_account_info(),
+                    mint: self.token_a_mint.to_account_info(),
                     to: self.token_a_vault.to_account_info(),
                     authority: self.swapper.to_account_info(),
                 },
             ),
             amount,
+            self.token_a_mint.decimals,
         )
     }
 
     fn deposit_token_b(&self, amount: u64) -> Result<()> {
-        transfer(
+        transfer_checked(
             CpiContext::new(
                 self.token_program.to_account_info(),
-                Transfer {
+                TransferChecked {
                     from: self.swapper_token_b.to_account_info(),
+                    mint: self.token_b_mint.to_account_info(),
                     to: self.token_b_vault.to_account_info(),
                     authority: self.swapper.to_account_info(),
                 },
             ),
             amount,
+            self.token_b_mint.decimals,
         )
     }
 
@@ -205,17 +590,19 @@ impl<'info> SwapTokens<'info> {
         ];
         let signer_seeds = &[&authority_seeds[..]];
 
-        transfer(
+        transfer_checked(
             CpiContext::new_with_signer(
                 self.token_program.to_account_info(),
-                Transfer {
+                TransferChecked {
                     from: self.token_a_vault.to_account_info(),
+                    mint: self.token_a_mint.to_account_info(),
                     to: self.swapper_token_a.to_account_info(),
                     authority: self.pool_authority.to_account_info(),
                 },
                 signer_seeds,
             ),
             amount,
+            self.token_a_mint.decimals,
         )
     }
 
@@ -228,17 +615,19 @@ impl<'info> SwapTokens<'info> {
         ];
         let signer_seeds = &[&authority_seeds[..]];
 
-        transfer(
+        transfer_checked(
             CpiContext::new_with_signer(
                 self.token_program.to_account_info(),
-                Transfer {
+                TransferChecked {
                     from: self.token_b_vault.to_account_info(),
+                    mint: self.token_b_mint.to_account_info(),
                     to: self.swapper_token_b.to_account_info(),
                     authority: self.pool_authority.to_account_info(),
                 },
                 signer_seeds,
             ),
             amount,
+            self.token_b_mint.decimals,
         )
     }
 }
\ No newline at end of file
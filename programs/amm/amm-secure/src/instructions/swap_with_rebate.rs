@@ -0,0 +1,58 @@
+// Swap With Rebate Instruction
+//
+// Exact-input swap with a reduced fee for swappers who also provide liquidity.
+// If the swapper holds at least FEE_REBATE_MIN_LP_TOKENS of the pool's LP token,
+// FEE_REBATE_BASIS_POINTS of the swap fee is waived. Everything else (slippage,
+// expiration, referrals, oracle guard, transfer fees) is the swap_tokens path,
+// run with the rebated fee.
+//
+// LP BALANCE VALIDATION:
+// The balance decides the fee, so it is only read from the swapper's associated
+// token account for this pool's LP mint. Accepting any token account would let a
+// swapper claim the rebate with a mint they printed themselves, or with someone
+// else's LP position.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{helpers::*, instructions::SwapTokens};
+
+#[derive(Accounts)]
+pub struct SwapWithRebate<'info> {
+    pub swap: SwapTokens<'info>,
+
+    #[account(address = swap.pool_config.lp_token_mint)]
+    pub lp_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Owned by the token program, right mint, right owner, canonical address
+    #[account(
+        associated_token::mint = lp_token_mint,
+        associated_token::authority = swap.swapper,
+        associated_token::token_program = swap.token_program,
+    )]
+    pub swapper_lp_token: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+impl<'info> SwapWithRebate<'info> {
+    pub fn swap_with_rebate(
+        &mut self,
+        swap_token_a_for_b: bool,
+        input_amount: u64,
+        min_output_amount: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        let fee_basis_points = calculate_rebated_fee(
+            self.swap.pool_config.fee_basis_points,
+            self.swapper_lp_token.amount,
+        )?;
+        msg!("Swap fee: {} basis points", fee_basis_points);
+
+        self.swap.swap_exact_in(
+            swap_token_a_for_b,
+            input_amount,
+            min_output_amount,
+            expiration,
+            fee_basis_points,
+        )
+    }
+}
@@ -15,6 +15,7 @@ pub struct UnlockPool<'info> {
             AMM_CONFIG_SEED,
             pool_config.token_a_mint.as_ref(),
             pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
         ],
         bump = pool_config.config_bump,
     )]
@@ -0,0 +1,52 @@
+// Update AMM Config Instruction
+//
+// Replaces the global config's admin, pool creator and fee tiers.
+// Rotating the admin hands over every future update; the old key loses
+// access in the same instruction.
+//
+// Existing pools keep their fee when a tier is removed, but no new pool can be
+// created at it and no pool can move to it.
+//
+// SECURITY:
+// - Only the current admin can update the config
+// - The new admin cannot be the default (unsignable) address
+// - Fee tiers are validated exactly like at initialization
+
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct UpdateAmmConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AMM_GLOBAL_CONFIG_SEED],
+        bump = amm_config.bump,
+        has_one = admin @ AmmError::UnauthorizedConfigAdmin
+    )]
+    pub amm_config: Box<Account<'info, AmmConfig>>,
+}
+
+impl<'info> UpdateAmmConfig<'info> {
+    pub fn update_amm_config(
+        &mut self,
+        new_admin: Pubkey,
+        pool_creation_authority: Pubkey,
+        fee_tiers: Vec<u16>,
+    ) -> Result<()> {
+        require!(new_admin != Pubkey::default(), AmmError::UnauthorizedConfigAdmin);
+        AmmConfig::validate_fee_tiers(&fee_tiers)?;
+
+        let amm_config = &mut self.amm_config;
+        amm_config.admin = new_admin;
+        amm_config.pool_creation_authority = pool_creation_authority;
+        amm_config.fee_tiers = fee_tiers;
+
+        msg!("AMM config updated: admin {}, pool creator {}", new_admin, pool_creation_authority);
+        msg!("Fee tiers: {:?}", amm_config.fee_tiers);
+
+        Ok(())
+    }
+}
@@ -13,11 +13,16 @@
 // - Balance verification: Ensures vaults have sufficient tokens before transfer
 // - Expiration check: Prevents stale transactions
 // - Pool lock check: Withdrawal disabled when pool is paused
+// - Invariant check: k per LP token is re-checked against the reloaded vaults
+//
+// TOKEN-2022:
+// For mints with a transfer fee the withdrawer receives amount - fee, so the
+// minimum amounts are compared against the post-fee amounts.
 
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::{constants::*, errors::*, helpers::*, state::*};
@@ -32,6 +37,7 @@ pub struct WithdrawLiquidity<'info> {
             AMM_CONFIG_SEED,
             pool_config.token_a_mint.as_ref(),
             pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
         ],
         bump = pool_config.config_bump,
     )]
@@ -49,52 +55,57 @@ pub struct WithdrawLiquidity<'info> {
         seeds = [LP_MINT_SEED, pool_config.key().as_ref()],
         bump = pool_config.lp_mint_bump,
     )]
-    pub lp_token_mint: Box<Account<'info, Mint>>,
+    pub lp_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(address = pool_config.token_a_mint)]
-    pub token_a_mint: Box<Account<'info, Mint>>,
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(address = pool_config.token_b_mint)]
-    pub token_b_mint: Box<Account<'info, Mint>>,
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         init_if_needed,
         payer = withdrawer,
         associated_token::mint = token_a_mint,
         associated_token::authority = withdrawer,
+        associated_token::token_program = token_program,
     )]
-    pub withdrawer_token_a: Box<Account<'info, TokenAccount>>,
+    pub withdrawer_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         init_if_needed,
         payer = withdrawer,
         associated_token::mint = token_b_mint,
         associated_token::authority = withdrawer,
+        associated_token::token_program = token_program,
     )]
-    pub withdrawer_token_b: Box<Account<'info, TokenAccount>>,
+    pub withdrawer_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
         token::mint = lp_token_mint,           
         token::authority = withdrawer,         
+        token::token_program = token_program,
     )]
-    pub withdrawer_lp_token: Account<'info, TokenAccount>,
+    pub withdrawer_lp_token: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
         token::mint = token_a_mint,
         token::authority = pool_authority,
+        token::token_program = token_program,
     )]
-    pub token_a_vault: Box<Account<'info, TokenAccount>>,
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
         token::mint = token_b_mint,
         token::authority = pool_authority,
+        token::token_program = token_program,
     )]
-    pub token_b_vault: Box<Account<'info, TokenAccount>>,
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
@@ -113,6 +124,9 @@ impl<'info> WithdrawLiquidity<'info> {
         // Check pool not locked
         self.pool_config.assert_not_locked()?;
 
+        // Borrowed flash loan tokens cannot be routed back through the pool
+        self.pool_config.assert_no_flash_loan()?;
+
         // Validate expiration using helper
         validate_expiration(expiration)?;
 
@@ -136,9 +150,17 @@ impl<'info> WithdrawLiquidity<'info> {
             lp_supply,
         )?;
 
-        // Slippage protection
-        require!(amount_a >= min_amount_a, AmmError::InsufficientWithdrawAmount);
-        require!(amount_b >= min_amount_b, AmmError::InsufficientWithdrawAmount);
+        // Amounts the withdrawer actually receives after Token-2022 transfer fees
+        let net_amount_a = amount_a
+            .checked_sub(calculate_transfer_fee(&self.token_a_mint, amount_a)?)
+            .ok_or(AmmError::Underflow)?;
+        let net_amount_b = amount_b
+            .checked_sub(calculate_transfer_fee(&self.token_b_mint, amount_b)?)
+            .ok_or(AmmError::Underflow)?;
+
+        // Slippage protection (against what the withdrawer actually receives)
+        require!(net_amount_a >= min_amount_a, AmmError::InsufficientWithdrawAmount);
+        require!(net_amount_b >= min_amount_b, AmmError::InsufficientWithdrawAmount);
 
         // Check non-zero withdrawals
         require!(amount_a > 0, AmmError::InsufficientLiquidity);
@@ -167,8 +189,10 @@ impl<'info> WithdrawLiquidity<'info> {
 
         transfer_from_vault(
             amount_a,
+            self.token_a_mint.decimals,
             &self.token_program.to_account_info(),
             &self.token_a_vault.to_account_info(),
+            &self.token_a_mint.to_account_info(),
             &self.withdrawer_token_a.to_account_info(),
             &self.pool_authority.to_account_info(),
             authority_seeds,
@@ -176,13 +200,29 @@ impl<'info> WithdrawLiquidity<'info> {
 
         transfer_from_vault(
             amount_b,
+            self.token_b_mint.decimals,
             &self.token_program.to_account_info(),
             &self.token_b_vault.to_account_info(),
+            &self.token_b_mint.to_account_info(),
             &self.withdrawer_token_b.to_account_info(),
             &self.pool_authority.to_account_info(),
             authority_seeds,
         )?;
 
+        // Defense in depth: the withdrawal must not have lowered k per LP token
+        self.token_a_vault.reload()?;
+        self.token_b_vault.reload()?;
+        self.lp_token_mint.reload()?;
+        assert_k_not_decreased(
+            PoolSnapshot::new(vault_a_balance, vault_b_balance, lp_supply),
+            PoolSnapshot::new(
+                self.token_a_vault.amount,
+                self.token_b_vault.amount,
+                self.lp_token_mint.supply,
+            ),
+            (0, 0),
+        )?;
+
         msg!("Withdrawn: {} LP -> {} A, {} B", lp_tokens_to_burn, amount_a, amount_b);
 
         Ok(())
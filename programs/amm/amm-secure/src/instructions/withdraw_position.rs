@@ -0,0 +1,264 @@
+// Withdraw Position Instruction
+//
+// Closes an LP position opened by deposit_position: burns the position NFT and the
+// LP tokens it represents, and pays out the proportional share of both vaults.
+//
+// HOW IT WORKS:
+// 1. The signer must own the position NFT
+// 2. Withdrawal amounts are calculated from the Position PDA's LP tokens
+// 3. Those LP tokens are burned from the position LP vault (pool authority signs)
+// 4. Tokens are transferred from the vaults to the withdrawer
+// 5. The NFT is burned and the Position PDA is closed to the withdrawer
+//
+// SECURITY:
+// - Ownership read from the Core asset account, not from instruction data
+// - Position must belong to the pool being withdrawn from (has_one = pool_config);
+//   otherwise a position minted in a worthless pool could redeem LP tokens
+//   escrowed by another pool's positions
+// - Slippage protection, expiration, lock, flash loan and k per LP token checks
+//   as in withdraw_liquidity
+// - Metaplex Core program validated by address
+//
+// TOKEN-2022:
+// For mints with a transfer fee the withdrawer receives amount - fee, so the
+// minimum amounts are compared against the post-fee amounts.
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use mpl_core::{ID as MPL_CORE_ID, accounts::BaseAssetV1, instructions::BurnV1CpiBuilder};
+
+use crate::{constants::*, errors::*, helpers::*, state::*};
+
+#[derive(Accounts)]
+pub struct WithdrawPosition<'info> {
+    // Position NFT owner (receives tokens and the position rent)
+    #[account(mut)]
+    pub withdrawer: Signer<'info>,
+
+    // Position NFT being redeemed
+    #[account(
+        mut,
+        constraint = asset.owner == withdrawer.key() @ AmmError::PositionOwnerMismatch,
+    )]
+    pub asset: Box<Account<'info, BaseAssetV1>>,
+
+    // Position record PDA
+    // Seeds: ["position", asset]
+    #[account(
+        mut,
+        close = withdrawer,
+        seeds = [POSITION_SEED, asset.key().as_ref()],
+        bump = position.bump,
+        has_one = asset,
+        has_one = pool_config @ AmmError::PositionPoolMismatch,
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+
+    /// CHECK: PDA signer
+    #[account(
+        seeds = [AMM_AUTHORITY_SEED, pool_config.key().as_ref()],
+        bump = pool_config.authority_bump,
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LP_MINT_SEED, pool_config.key().as_ref()],
+        bump = pool_config.lp_mint_bump,
+    )]
+    pub lp_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = pool_config.token_a_mint)]
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = pool_config.token_b_mint)]
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = withdrawer,
+        associated_token::mint = token_a_mint,
+        associated_token::authority = withdrawer,
+        associated_token::token_program = token_program,
+    )]
+    pub withdrawer_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = withdrawer,
+        associated_token::mint = token_b_mint,
+        associated_token::authority = withdrawer,
+        associated_token::token_program = token_program,
+    )]
+    pub withdrawer_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // LP tokens backing every position in this pool
+    #[account(
+        mut,
+        associated_token::mint = lp_token_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub position_lp_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_a_mint,
+        token::authority = pool_authority,
+        token::token_program = token_program,
+    )]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_b_mint,
+        token::authority = pool_authority,
+        token::token_program = token_program,
+    )]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Metaplex Core program
+    #[account(address = MPL_CORE_ID @ AmmError::InvalidMplCoreProgram)]
+    /// CHECK: Validated by address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawPosition<'info> {
+    pub fn withdraw_position(
+        &mut self,
+        min_amount_a: u64,
+        min_amount_b: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        // Check pool not locked
+        self.pool_config.assert_not_locked()?;
+
+        // Borrowed flash loan tokens cannot be routed back through the pool
+        self.pool_config.assert_no_flash_loan()?;
+
+        // Validate expiration using helper
+        validate_expiration(expiration)?;
+
+        let lp_tokens = self.position.lp_tokens;
+        require!(self.position_lp_vault.amount >= lp_tokens, AmmError::InsufficientBalance);
+
+        let vault_a_balance = self.token_a_vault.amount;
+        let vault_b_balance = self.token_b_vault.amount;
+        let lp_supply = self.lp_token_mint.supply;
+
+        // Check pool has liquidity
+        require!(lp_supply > 0, AmmError::InsufficientLiquidity);
+        require!(vault_a_balance > 0, AmmError::InsufficientLiquidity);
+        require!(vault_b_balance > 0, AmmError::InsufficientLiquidity);
+
+        // Calculate withdrawal amounts using helper
+        let (amount_a, amount_b) =
+            calculate_withdrawal(lp_tokens, vault_a_balance, vault_b_balance, lp_supply)?;
+
+        // Amounts the withdrawer actually receives after Token-2022 transfer fees
+        let net_amount_a = amount_a
+            .checked_sub(calculate_transfer_fee(&self.token_a_mint, amount_a)?)
+            .ok_or(AmmError::Underflow)?;
+        let net_amount_b = amount_b
+            .checked_sub(calculate_transfer_fee(&self.token_b_mint, amount_b)?)
+            .ok_or(AmmError::Underflow)?;
+
+        // Slippage protection (against what the withdrawer actually receives)
+        require!(net_amount_a >= min_amount_a, AmmError::InsufficientWithdrawAmount);
+        require!(net_amount_b >= min_amount_b, AmmError::InsufficientWithdrawAmount);
+
+        // Check non-zero withdrawals
+        require!(amount_a > 0, AmmError::InsufficientLiquidity);
+        require!(amount_b > 0, AmmError::InsufficientLiquidity);
+
+        let pool_config_key = self.pool_config.key();
+        let authority_seeds = &[
+            AMM_AUTHORITY_SEED,
+            pool_config_key.as_ref(),
+            &[self.pool_config.authority_bump],
+        ];
+
+        // Burn the position's LP tokens from the position LP vault
+        burn_lp_tokens_from_vault(
+            lp_tokens,
+            &self.token_program.to_account_info(),
+            &self.lp_token_mint.to_account_info(),
+            &self.position_lp_vault.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        // Transfer tokens from vaults using helper
+        transfer_from_vault(
+            amount_a,
+            self.token_a_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.token_a_vault.to_account_info(),
+            &self.token_a_mint.to_account_info(),
+            &self.withdrawer_token_a.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        transfer_from_vault(
+            amount_b,
+            self.token_b_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.token_b_vault.to_account_info(),
+            &self.token_b_mint.to_account_info(),
+            &self.withdrawer_token_b.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        // Defense in depth: the withdrawal must not have lowered k per LP token
+        self.token_a_vault.reload()?;
+        self.token_b_vault.reload()?;
+        self.lp_token_mint.reload()?;
+        assert_k_not_decreased(
+            PoolSnapshot::new(vault_a_balance, vault_b_balance, lp_supply),
+            PoolSnapshot::new(
+                self.token_a_vault.amount,
+                self.token_b_vault.amount,
+                self.lp_token_mint.supply,
+            ),
+            (0, 0),
+        )?;
+
+        // Burn the position NFT (owner signs; Position PDA is closed by Anchor)
+        BurnV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
+            .asset(&self.asset.to_account_info())
+            .payer(&self.withdrawer.to_account_info())
+            .authority(Some(&self.withdrawer.to_account_info()))
+            .system_program(Some(&self.system_program.to_account_info()))
+            .invoke()?;
+
+        msg!(
+            "Position closed: {} LP -> {} A, {} B (asset {})",
+            lp_tokens,
+            amount_a,
+            amount_b,
+            self.asset.key()
+        );
+
+        Ok(())
+    }
+}
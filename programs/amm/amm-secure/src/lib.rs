@@ -6,6 +6,8 @@
 // 2. Provide liquidity and earn fees by receiving LP tokens
 // 3. Swap tokens at prices determined by the constant product formula
 // 4. Remove liquidity by burning LP tokens
+// 5. Hold liquidity as an NFT position instead of fungible LP tokens
+// 6. Place limit orders that keepers fill against the pool for a tip
 //
 // SECURITY FEATURES:
 // - Pool lock/unlock for emergency pause
@@ -14,6 +16,12 @@
 // - Fee validation (max 10%)
 // - Checked arithmetic to prevent overflow/underflow
 // - Box<Account> to reduce stack usage and prevent stack overflow
+// - Optional oracle guard rejecting swaps that push the price away from the market
+// - LP position NFTs bound to the pool that minted them
+// - Fee rebates only for LP balances read from the swapper's own LP token ATA
+// - Native SOL wrapped into the user's own empty wSOL ATA, closed back with a refund check
+// - Limit orders filled only at or above the owner's stored limit
+// - Optional per-slot cap on swap input against sandwich and split-swap drains
 //
 // CONSTANT PRODUCT FORMULA:
 // The pool maintains: token_a_reserve * token_b_reserve = k (constant)
@@ -36,10 +44,38 @@ declare_id!("FeLNaVGuQZWizZMd2hfy4MaXoko3kLC5q675q5EE5KaC");
 pub mod amm_secure {
     use super::*;
 
+    // Create the optional global config that makes pool creation permissioned
+    // Singleton PDA - the upgrade authority calls it once right after deployment
+    pub fn initialize_amm_config(
+        ctx: Context<InitializeAmmConfig>,
+        pool_creation_authority: Pubkey,
+        fee_tiers: Vec<u16>,
+    ) -> Result<()> {
+        ctx.accounts
+            .initialize_amm_config(pool_creation_authority, fee_tiers, &ctx.bumps)
+    }
+
+    // Rotate the config admin, change the pool creator or the fee tiers
+    // Config admin only
+    pub fn update_amm_config(
+        ctx: Context<UpdateAmmConfig>,
+        new_admin: Pubkey,
+        pool_creation_authority: Pubkey,
+        fee_tiers: Vec<u16>,
+    ) -> Result<()> {
+        ctx.accounts
+            .update_amm_config(new_admin, pool_creation_authority, fee_tiers)
+    }
+
     // Create a new liquidity pool for a token pair
     // Only needs to be called once per token pair
-    pub fn initialize_pool(ctx: Context<InitializePool>, fee_basis_points: u16) -> Result<()> {
-        ctx.accounts.initialize_pool(fee_basis_points, &ctx.bumps)
+    pub fn initialize_pool(
+        ctx: Context<InitializePool>,
+        fee_basis_points: u16,
+        referral_fee_basis_points: u16,
+    ) -> Result<()> {
+        ctx.accounts
+            .initialize_pool(fee_basis_points, referral_fee_basis_points, &ctx.bumps)
     }
 
     // Add liquidity to the pool and receive LP tokens
@@ -61,6 +97,25 @@ pub mod amm_secure {
         )
     }
 
+    // deposit_liquidity for a pool with a wrapped SOL side, paid in native SOL
+    // Wraps into a temporary wSOL ATA and closes it back to the depositor
+    pub fn deposit_liquidity_sol(
+        ctx: Context<DepositLiquiditySol>,
+        desired_amount_a: u64,
+        desired_amount_b: u64,
+        max_amount_a: u64,
+        max_amount_b: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        ctx.accounts.deposit_liquidity_sol(
+            desired_amount_a,
+            desired_amount_b,
+            max_amount_a,
+            max_amount_b,
+            expiration,
+        )
+    }
+
     // Remove liquidity by burning LP tokens
     // Returns proportional share of both tokens from the pool
     pub fn withdraw_liquidity(
@@ -78,6 +133,17 @@ pub mod amm_secure {
         )
     }
 
+    // Move LP from one pool to another pool of the same pair (another fee tier)
+    // Source vaults pay the destination vaults directly; the unused side is refunded
+    pub fn migrate_liquidity(
+        ctx: Context<MigrateLiquidity>,
+        lp_amount: u64,
+        min_lp_out: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        ctx.accounts.migrate_liquidity(lp_amount, min_lp_out, expiration)
+    }
+
     // Swap one token for another using constant product formula
     // Fee is deducted from input before calculating output
     pub fn swap_tokens(
@@ -95,6 +161,52 @@ pub mod amm_secure {
         )
     }
 
+    // Swap for an exact output amount using the inverse constant product formula
+    // Required input is rounded up in the pool's favor and capped by max_input_amount
+    pub fn swap_tokens_exact_out(
+        ctx: Context<SwapTokens>,
+        swap_token_a_for_b: bool,
+        max_input_amount: u64,
+        output_amount: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        ctx.accounts.swap_tokens_exact_out(
+            swap_token_a_for_b,
+            max_input_amount,
+            output_amount,
+            expiration,
+        )
+    }
+
+    // Exact-input swap with part of the fee waived for swappers holding the pool's LP token
+    // The LP balance is read from the swapper's own LP token ATA
+    pub fn swap_with_rebate(
+        ctx: Context<SwapWithRebate>,
+        swap_token_a_for_b: bool,
+        input_amount: u64,
+        min_output_amount: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        ctx.accounts.swap_with_rebate(
+            swap_token_a_for_b,
+            input_amount,
+            min_output_amount,
+            expiration,
+        )
+    }
+
+    // Exact-input swap from native SOL into the pool's other token
+    // Wraps into a temporary wSOL ATA and closes it back to the swapper
+    pub fn swap_sol_for_token(
+        ctx: Context<SwapSolForToken>,
+        input_amount: u64,
+        min_output_amount: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        ctx.accounts
+            .swap_sol_for_token(input_amount, min_output_amount, expiration)
+    }
+
     // Emergency pause - only pool authority can lock
     pub fn lock_pool(ctx: Context<LockPool>) -> Result<()> {
         ctx.accounts.lock_pool()
@@ -104,4 +216,139 @@ pub mod amm_secure {
     pub fn unlock_pool(ctx: Context<UnlockPool>) -> Result<()> {
         ctx.accounts.unlock_pool()
     }
+
+    // Borrow tokens from a pool vault for the duration of one transaction
+    // Must be paired with flash_loan_end later in the same transaction
+    pub fn flash_loan_begin(ctx: Context<FlashLoanBegin>, amount: u64) -> Result<()> {
+        ctx.accounts.flash_loan_begin(amount)
+    }
+
+    // Verify the flash loan was repaid with the fee
+    // Fails (reverting the whole transaction) if the vault is short
+    pub fn flash_loan_end(ctx: Context<FlashLoanEnd>) -> Result<()> {
+        ctx.accounts.flash_loan_end()
+    }
+
+    // Queue a new swap fee - only pool authority
+    // Applied by change_pool_fee after fee_change_delay_seconds
+    pub fn request_fee_change(
+        ctx: Context<RequestFeeChange>,
+        new_fee_basis_points: u16,
+    ) -> Result<()> {
+        ctx.accounts.request_fee_change(new_fee_basis_points)
+    }
+
+    // Apply the queued fee once the timelock has elapsed - only pool authority
+    pub fn change_pool_fee(ctx: Context<ChangePoolFee>) -> Result<()> {
+        ctx.accounts.change_pool_fee()
+    }
+
+    // Enable/disable emergency withdrawals while locked - only pool authority
+    pub fn set_emergency_withdraw(ctx: Context<SetEmergencyWithdraw>, enabled: bool) -> Result<()> {
+        ctx.accounts.set_emergency_withdraw(enabled)
+    }
+
+    // Proportional LP exit from a locked pool (requires emergency withdraw enabled)
+    pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>, lp_tokens_to_burn: u64) -> Result<()> {
+        ctx.accounts.emergency_withdraw(lp_tokens_to_burn)
+    }
+
+    // Pin an oracle feed and the maximum pool/oracle price deviation - only pool authority
+    // A deviation of 0 turns the guard off
+    pub fn set_oracle_guard(
+        ctx: Context<SetOracleGuard>,
+        oracle_program: Pubkey,
+        price_feed: Pubkey,
+        max_oracle_deviation_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_oracle_guard(oracle_program, price_feed, max_oracle_deviation_bps)
+    }
+
+    // Cap the pool price move a single swap may cause - only pool authority
+    // With auto_lock, a swap over the cap locks the pool instead of failing
+    pub fn set_circuit_breaker(
+        ctx: Context<SetCircuitBreaker>,
+        max_price_impact_bps: u16,
+        auto_lock: bool,
+    ) -> Result<()> {
+        ctx.accounts.set_circuit_breaker(max_price_impact_bps, auto_lock)
+    }
+
+    // Cap the input each vault may receive from swaps in one slot - only pool authority
+    // Bounds single-block sandwiches; 0 disables the limit
+    pub fn set_swap_limit(ctx: Context<SetSwapLimit>, max_swap_per_slot_bps: u16) -> Result<()> {
+        ctx.accounts.set_swap_limit(max_swap_per_slot_bps)
+    }
+
+    // Add liquidity as an NFT position
+    // LP tokens stay in the pool's position LP vault; the depositor receives a
+    // Metaplex Core asset whose attributes record the amounts and entry price
+    pub fn deposit_position(
+        ctx: Context<DepositPosition>,
+        desired_amount_a: u64,
+        desired_amount_b: u64,
+        max_amount_a: u64,
+        max_amount_b: u64,
+        expiration: i64,
+        uri: String,
+    ) -> Result<()> {
+        ctx.accounts.deposit_position(
+            desired_amount_a,
+            desired_amount_b,
+            max_amount_a,
+            max_amount_b,
+            expiration,
+            uri,
+            &ctx.bumps,
+        )
+    }
+
+    // Redeem an NFT position - only the asset owner
+    // Burns the asset and its LP tokens and returns the proportional share of the pool
+    pub fn withdraw_position(
+        ctx: Context<WithdrawPosition>,
+        min_amount_a: u64,
+        min_amount_b: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        ctx.accounts.withdraw_position(min_amount_a, min_amount_b, expiration)
+    }
+
+    // Pay an NFT position the swap fees it earned since its last claim - only the asset owner
+    // Redeems the position's share of the pool's fee growth as LP tokens; the rest stays open
+    pub fn claim_lp_fees(ctx: Context<ClaimLpFees>) -> Result<()> {
+        ctx.accounts.claim_lp_fees()
+    }
+
+    // Escrow input tokens to be swapped for at least min_output_amount
+    // keeper_tip lamports are held on the order and paid to the keeper that fills it
+    pub fn place_limit_order(
+        ctx: Context<PlaceLimitOrder>,
+        order_id: u64,
+        input_amount: u64,
+        min_output_amount: u64,
+        keeper_tip: u64,
+        expires_at: i64,
+    ) -> Result<()> {
+        ctx.accounts.place_limit_order(
+            order_id,
+            input_amount,
+            min_output_amount,
+            keeper_tip,
+            expires_at,
+            &ctx.bumps,
+        )
+    }
+
+    // Refund an unfilled limit order - only the order owner
+    pub fn cancel_limit_order(ctx: Context<CancelLimitOrder>) -> Result<()> {
+        ctx.accounts.cancel_limit_order()
+    }
+
+    // Permissionless crank: swap a limit order against the pool once its limit is reachable
+    // The owner receives the output, the keeper receives the tip
+    pub fn fill_limit_order(ctx: Context<FillLimitOrder>) -> Result<()> {
+        ctx.accounts.fill_limit_order()
+    }
 }
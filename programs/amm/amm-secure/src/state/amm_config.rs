@@ -0,0 +1,83 @@
+// Global AMM Configuration State
+//
+// Optional singleton that turns pool creation into a permissioned operation.
+// Until it is initialized, anyone can create pools with any valid fee.
+// Once initialized, only `pool_creation_authority` can create pools, and only
+// with a fee from the whitelisted `fee_tiers`.
+//
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*};
+
+#[account]
+#[derive(InitSpace)]
+pub struct AmmConfig {
+    // Program upgrade authority at initialization
+    // Only this address can update the config (see update_amm_config)
+    pub admin: Pubkey,
+
+    // Only this address can create new pools
+    pub pool_creation_authority: Pubkey,
+
+    // Whitelisted swap fees in basis points (e.g. [5, 30, 100])
+    #[max_len(MAX_FEE_TIERS)]
+    pub fee_tiers: Vec<u16>,
+
+    // PDA bump seed
+    pub bump: u8,
+}
+
+impl AmmConfig {
+    // Validate a fee tier list before storing it
+    // Bounded so the account size is fixed, unique, and within the global fee cap
+    pub fn validate_fee_tiers(fee_tiers: &[u16]) -> Result<()> {
+        require!(
+            !fee_tiers.is_empty() && fee_tiers.len() <= MAX_FEE_TIERS,
+            AmmError::InvalidFeeTierCount
+        );
+
+        for (i, fee) in fee_tiers.iter().enumerate() {
+            // Whitelisted tiers cannot bypass the global fee cap
+            require!(*fee <= MAX_FEE_BASIS_POINTS, AmmError::FeeTooHigh);
+            require!(
+                !fee_tiers[..i].contains(fee),
+                AmmError::DuplicateFeeTier
+            );
+        }
+
+        Ok(())
+    }
+
+    // Assert `creator` may create a pool charging `fee_basis_points`
+    // Called by initialize_pool when the global config exists
+    pub fn assert_can_create_pool(&self, creator: &Pubkey, fee_basis_points: u16) -> Result<()> {
+        require!(
+            self.pool_creation_authority == *creator,
+            AmmError::UnauthorizedPoolCreator
+        );
+        self.assert_fee_whitelisted(fee_basis_points)
+    }
+
+    // Assert `fee_basis_points` is one of the whitelisted tiers
+    pub fn assert_fee_whitelisted(&self, fee_basis_points: u16) -> Result<()> {
+        require!(
+            self.fee_tiers.contains(&fee_basis_points),
+            AmmError::FeeTierNotWhitelisted
+        );
+        Ok(())
+    }
+
+    // Fee check for an existing pool's fee changes
+    // `amm_config` must already be pinned to the AMM_GLOBAL_CONFIG_SEED PDA.
+    // Until the config is initialized any fee up to the cap is allowed, as at
+    // pool creation
+    pub fn assert_fee_allowed(amm_config: &AccountInfo, fee_basis_points: u16) -> Result<()> {
+        if amm_config.data_is_empty() {
+            return Ok(());
+        }
+
+        // Owner + discriminator checked before trusting fee_tiers
+        let amm_config = Account::<AmmConfig>::try_from(amm_config)?;
+        amm_config.assert_fee_whitelisted(fee_basis_points)
+    }
+}
@@ -6,7 +6,8 @@
 
 
 use anchor_lang::prelude::*;
-use crate::errors::*;
+use soteria_common::math::apply_bps_floor;
+use crate::{constants::*, errors::*};
 
 #[account]
 #[derive(InitSpace)]
@@ -37,6 +38,87 @@ pub struct PoolConfig {
     pub config_bump: u8,       // Bump for this config PDA
     pub authority_bump: u8,    // Bump for pool authority PDA
     pub lp_mint_bump: u8,      // Bump for LP mint PDA
+
+    // Flash loan in progress flag
+    // Set by flash_loan_begin, cleared by flash_loan_end
+    pub flash_loan_active: bool,
+
+    // Mint of the vault the active flash loan borrowed from
+    pub flash_loan_mint: Pubkey,
+
+    // Vault balance recorded by flash_loan_begin before tokens left the vault
+    pub flash_loan_vault_balance_before: u64,
+
+    // Fee owed to LPs on top of the borrowed amount
+    pub flash_loan_fee: u64,
+
+    // Fee queued by request_fee_change, applied by change_pool_fee
+    pub pending_fee_basis_points: u16,
+
+    // Unix timestamp after which the pending fee can be applied (0 = nothing pending)
+    pub fee_change_unlock_time: i64,
+
+    // Notice period LPs get before a requested fee change can take effect
+    pub fee_change_delay_seconds: i64,
+
+    // Allows LPs to exit via emergency_withdraw while the pool is locked
+    // Toggled by the pool authority with set_emergency_withdraw
+    pub emergency_withdraw_enabled: bool,
+
+    // Share of the swap fee paid to an optional referrer, in basis points of the fee
+    // Example: 2000 with a 30bp swap fee = 6bp of the input goes to the referrer
+    pub referral_fee_basis_points: u16,
+
+    // Oracle program that must own price_feed
+    pub oracle_program: Pubkey,
+
+    // Oracle feed pricing token A in token B, checked after every swap
+    pub price_feed: Pubkey,
+
+    // Maximum distance between the post-swap pool price and the oracle price
+    // 0 = oracle guard disabled, swaps don't need a price feed
+    pub max_oracle_deviation_bps: u16,
+
+    // Largest move of the pool price a single swap may cause, in basis points
+    // 0 = circuit breaker disabled
+    pub max_price_impact_bps: u16,
+
+    // When true, a swap over max_price_impact_bps locks the pool instead of failing
+    pub circuit_breaker_auto_lock: bool,
+
+    // Pool liquidity per LP token, sqrt(reserve_a * reserve_b) / lp_supply as Q64.64
+    // Only swap and flash loan fees raise it; snapshotted whenever a position
+    // is opened or claims fees
+    pub fee_growth_global: u128,
+
+    // Cap on the input each vault may receive from swaps in one slot, in basis
+    // points of that vault's balance before the slot's first swap
+    // 0 = no per-slot limit
+    pub max_swap_per_slot_bps: u16,
+
+    // Swap input per slot for the last SWAP_VOLUME_RING_SIZE slots that saw a swap
+    // Only the entry for the current slot counts towards the limit
+    pub swap_volumes: [SlotSwapVolume; SWAP_VOLUME_RING_SIZE],
+
+    // Fee the pool was created with; part of its PDA seeds so one pair can
+    // have a pool per fee tier. Fixed for the pool's lifetime, unlike
+    // fee_basis_points which request_fee_change can move
+    pub fee_tier: u16,
+}
+
+// Swap input one slot has put into the pool
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct SlotSwapVolume {
+    pub slot: u64,
+
+    // Vault balances before the slot's first swap; the cap is a share of these
+    // Never zero once written, since swaps need liquidity on both sides
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+
+    // Net input received by each vault from swaps in this slot
+    pub volume_a: u64,
+    pub volume_b: u64,
 }
 
 impl PoolConfig {
@@ -63,6 +145,89 @@ impl PoolConfig {
         Ok(())
     }
 
+    // Assert pool is locked
+    // Called by emergency_withdraw, which only exists for paused pools
+    pub fn assert_locked(&self) -> Result<()> {
+        require!(self.locked, AmmError::PoolNotLocked);
+        Ok(())
+    }
+
+    // Assert no flash loan is in progress
+    // Called by deposit, withdraw, and swap so borrowed tokens cannot be
+    // routed back through the pool to fake a repayment
+    pub fn assert_no_flash_loan(&self) -> Result<()> {
+        require!(!self.flash_loan_active, AmmError::FlashLoanInProgress);
+        Ok(())
+    }
+
+    // Check whether a mint belongs to this pool
+    pub fn is_pool_mint(&self, mint: &Pubkey) -> bool {
+        self.token_a_mint == *mint || self.token_b_mint == *mint
+    }
+
+    // Whether swaps must be checked against the oracle
+    pub fn has_oracle_guard(&self) -> bool {
+        self.max_oracle_deviation_bps > 0
+    }
+
+    // Whether swaps are checked against the price impact limit
+    pub fn has_circuit_breaker(&self) -> bool {
+        self.max_price_impact_bps > 0
+    }
+
+    // Whether swaps count against a per-slot volume limit
+    pub fn has_swap_limit(&self) -> bool {
+        self.max_swap_per_slot_bps > 0
+    }
+
+    // Add a swap's net input to the current slot's volume and check it stays
+    // within max_swap_per_slot_bps of the input vault's balance at the start of
+    // the slot. A sandwich needs both legs and the victim's swap in one slot,
+    // so the cap bounds how far it can push the price around the victim.
+    // reserve_a / reserve_b are the vault balances before this swap
+    // No-op when the pool has no swap limit
+    pub fn record_swap_volume(
+        &mut self,
+        slot: u64,
+        input_is_token_a: bool,
+        net_input_amount: u64,
+        reserve_a: u64,
+        reserve_b: u64,
+    ) -> Result<()> {
+        if !self.has_swap_limit() {
+            return Ok(());
+        }
+
+        let max_swap_per_slot_bps = self.max_swap_per_slot_bps as u64;
+        let entry = &mut self.swap_volumes[(slot % SWAP_VOLUME_RING_SIZE as u64) as usize];
+
+        // First swap of the slot: the entry still holds an older slot (or was never written)
+        if entry.slot != slot || entry.reserve_a == 0 {
+            *entry = SlotSwapVolume {
+                slot,
+                reserve_a,
+                reserve_b,
+                volume_a: 0,
+                volume_b: 0,
+            };
+        }
+
+        let (volume, slot_reserve) = if input_is_token_a {
+            (&mut entry.volume_a, entry.reserve_a)
+        } else {
+            (&mut entry.volume_b, entry.reserve_b)
+        };
+
+        *volume = volume
+            .checked_add(net_input_amount)
+            .ok_or(AmmError::Overflow)?;
+        let max_volume =
+            apply_bps_floor(slot_reserve, max_swap_per_slot_bps).ok_or(AmmError::Overflow)?;
+        require!(*volume <= max_volume, AmmError::SlotSwapLimitExceeded);
+
+        Ok(())
+    }
+
     // Assert caller is the pool authority
     // Used to restrict lock/unlock to pool creator
     pub fn assert_is_authority(&self, caller: &Pubkey) -> Result<()> {
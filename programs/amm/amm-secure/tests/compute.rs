@@ -0,0 +1,116 @@
+// Compute-unit regression tests for amm-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+mod utils;
+
+use litesvm_token::{get_spl_account, CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{clock::Clock, native_token::LAMPORTS_PER_SOL, signature::Signer};
+use spl_associated_token_account::get_associated_token_address;
+use utils::*;
+
+// 48 hour fee change delay
+const FEE_CHANGE_DELAY_SECONDS: u64 = 172_800;
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let owner = authority.pubkey();
+    let liquidity = 100_000_000_000;
+
+    set_upgrade_authority(&mut svm, &AMM_PROGRAM_ID, &owner);
+    let ix = build_initialize_amm_config_ix(&owner, &owner, &[30, 100]);
+    budget.record("initialize_amm_config", send_ix(&mut svm, ix, &authority));
+
+    let ix = amm_ix::update_amm_config(&owner, &owner, &owner, &[30, 100]);
+    budget.record("update_amm_config", send_ix(&mut svm, ix, &authority));
+
+    let mint_a = CreateMint::new(&mut svm, &authority).decimals(DECIMALS).send().unwrap();
+    let mint_b = CreateMint::new(&mut svm, &authority).decimals(DECIMALS).send().unwrap();
+    let ix = build_initialize_pool_ix(&owner, &mint_a, &mint_b, 30);
+    budget.record("initialize_pool", send_ix(&mut svm, ix, &authority));
+
+    for mint in [&mint_a, &mint_b] {
+        let ata = CreateAssociatedTokenAccount::new(&mut svm, &authority, mint).send().unwrap();
+        MintTo::new(&mut svm, &authority, mint, &ata, 2 * liquidity).send().unwrap();
+    }
+
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+    let ix = build_deposit_liquidity_ix(
+        &owner,
+        &mint_a,
+        &mint_b,
+        liquidity,
+        liquidity,
+        liquidity,
+        liquidity,
+        expiration,
+    );
+    budget.record("deposit_liquidity", send_ix(&mut svm, ix, &authority));
+
+    let ix = build_swap_tokens_ix(&owner, &mint_a, &mint_b, true, 1_000_000_000, 1, expiration);
+    budget.record("swap_tokens", send_ix(&mut svm, ix, &authority));
+
+    let ix = build_swap_tokens_exact_out_ix(
+        &owner,
+        &mint_a,
+        &mint_b,
+        false,
+        2_000_000_000,
+        1_000_000_000,
+        expiration,
+    );
+    budget.record("swap_tokens_exact_out", send_ix(&mut svm, ix, &authority));
+
+    // begin -> repay -> end, measured as one transaction
+    let loan_amount = liquidity / 2;
+    let owner_ata_a = get_associated_token_address(&owner, &mint_a);
+    let vault_a = derive_vault(&mint_a, &mint_b, &mint_a);
+    let ixs = [
+        build_flash_loan_begin_ix(&owner, &mint_a, &mint_b, &mint_a, &owner_ata_a, loan_amount),
+        build_repay_ix(
+            &owner_ata_a,
+            &mint_a,
+            &vault_a,
+            &owner,
+            loan_amount + amm::flash_loan_fee(loan_amount),
+        ),
+        build_flash_loan_end_ix(&mint_a, &mint_b, &mint_a),
+    ];
+    budget.record("flash_loan", send_tx(&mut svm, &ixs, &authority, &[&authority]));
+
+    let ix = build_request_fee_change_ix(&owner, &mint_a, &mint_b, 100);
+    budget.record("request_fee_change", send_ix(&mut svm, ix, &authority));
+    advance_time(&mut svm, FEE_CHANGE_DELAY_SECONDS);
+    let ix = build_change_pool_fee_ix(&owner, &mint_a, &mint_b);
+    budget.record("change_pool_fee", send_ix(&mut svm, ix, &authority));
+
+    let (pool_config, _) = amm::pool_config_address(&mint_a, &mint_b, POOL_FEE_TIER);
+    let (lp_mint, _) = amm::lp_mint_address(&pool_config);
+    let owner_lp_ata = get_associated_token_address(&owner, &lp_mint);
+    let lp_account: spl_token::state::Account = get_spl_account(&svm, &owner_lp_ata).unwrap();
+    let lp_quarter = lp_account.amount / 4;
+
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+    let ix = build_withdraw_liquidity_ix(&owner, &mint_a, &mint_b, lp_quarter, 1, 1, expiration);
+    budget.record("withdraw_liquidity", send_ix(&mut svm, ix, &authority));
+
+    // Emergency withdraw needs a locked pool with the escape hatch enabled
+    let ix = build_lock_pool_ix(&owner, &mint_a, &mint_b);
+    budget.record("lock_pool", send_ix(&mut svm, ix, &authority));
+    let ix = build_set_emergency_withdraw_ix(&owner, &mint_a, &mint_b, true);
+    budget.record("set_emergency_withdraw", send_ix(&mut svm, ix, &authority));
+    let ix = build_emergency_withdraw_ix(&owner, &mint_a, &mint_b, lp_quarter);
+    budget.record("emergency_withdraw", send_ix(&mut svm, ix, &authority));
+    let ix = build_unlock_pool_ix(&owner, &mint_a, &mint_b);
+    budget.record("unlock_pool", send_ix(&mut svm, ix, &authority));
+
+    budget.check();
+}
@@ -0,0 +1,258 @@
+// Property-based fuzz tests for AMM pool invariants
+// Random sequences of deposits, swaps and withdrawals are replayed against a fresh pool.
+// After every step:
+//   - a swap never lowers k = reserve_a * reserve_b (the fee only ever adds to it)
+//   - a withdrawal never pays out more than the burned LP share of the reserves
+//   - a rejected transaction leaves reserves and LP supply untouched
+// At the end the LP redeems the whole LP supply, which the reserves must still cover.
+
+mod utils;
+
+use litesvm::{types::TransactionResult, LiteSVM};
+use litesvm_token::{get_spl_account, CreateAssociatedTokenAccount, MintTo};
+use proptest::prelude::*;
+use solana_sdk::{
+    clock::Clock,
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use spl_associated_token_account::get_associated_token_address;
+use utils::*;
+
+// Starting reserves; sqrt(a * b) always clears MINIMUM_LIQUIDITY
+const MIN_INITIAL_RESERVE: u64 = 1_000_000;
+const MAX_INITIAL_RESERVE: u64 = 1_000_000_000_000;
+
+// Largest single deposit or swap input
+const MAX_OP_AMOUNT: u64 = 1_000_000_000_000;
+
+// Longest generated sequence of pool operations
+const MAX_OPS: usize = 24;
+
+// Balance minted to the LP and the trader; covers MAX_OPS operations of MAX_OP_AMOUNT
+const WALLET_BALANCE: u64 = 1_000_000_000_000_000;
+
+#[derive(Clone, Debug)]
+enum PoolOp {
+    Deposit { amount_a: u64, amount_b: u64 },
+    Swap { a_for_b: bool, amount: u64 },
+    Withdraw { share_bps: u16 },
+}
+
+fn pool_op() -> impl Strategy<Value = PoolOp> {
+    prop_oneof![
+        (1..MAX_OP_AMOUNT, 1..MAX_OP_AMOUNT)
+            .prop_map(|(amount_a, amount_b)| PoolOp::Deposit { amount_a, amount_b }),
+        (any::<bool>(), 1..MAX_OP_AMOUNT).prop_map(|(a_for_b, amount)| PoolOp::Swap { a_for_b, amount }),
+        (1..=10_000u16).prop_map(|share_bps| PoolOp::Withdraw { share_bps }),
+    ]
+}
+
+// Reserves and LP supply at one point in the sequence
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PoolSnapshot {
+    reserve_a: u64,
+    reserve_b: u64,
+    lp_supply: u64,
+}
+
+impl PoolSnapshot {
+    fn k(&self) -> u128 {
+        self.reserve_a as u128 * self.reserve_b as u128
+    }
+}
+
+// Pool seeded by `lp` (the only liquidity provider) plus a separate trader
+struct FuzzPool {
+    svm: LiteSVM,
+    lp: Keypair,
+    trader: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    vault_a: Pubkey,
+    vault_b: Pubkey,
+    lp_mint: Pubkey,
+    lp_token_account: Pubkey,
+}
+
+impl FuzzPool {
+    fn new(initial_a: u64, initial_b: u64) -> Self {
+        let mut svm = setup_svm();
+        let lp = create_funded_account(&mut svm, 100 * LAMPORTS_PER_SOL);
+        let trader = create_funded_account(&mut svm, 100 * LAMPORTS_PER_SOL);
+
+        let (mint_a, mint_b) = setup_pool_with_reserves(&mut svm, &lp, initial_a, initial_b);
+
+        // Top up the LP for later deposits and fund the trader on both sides
+        for mint in [mint_a, mint_b] {
+            let lp_ata = get_associated_token_address(&lp.pubkey(), &mint);
+            MintTo::new(&mut svm, &lp, &mint, &lp_ata, WALLET_BALANCE)
+                .owner(&lp)
+                .send()
+                .unwrap();
+
+            let trader_ata = CreateAssociatedTokenAccount::new(&mut svm, &trader, &mint)
+                .owner(&trader.pubkey())
+                .send()
+                .unwrap();
+            MintTo::new(&mut svm, &lp, &mint, &trader_ata, WALLET_BALANCE)
+                .owner(&lp)
+                .send()
+                .unwrap();
+        }
+
+        let (pool_config, _) = amm::pool_config_address(&mint_a, &mint_b, POOL_FEE_TIER);
+        let (lp_mint, _) = amm::lp_mint_address(&pool_config);
+
+        FuzzPool {
+            vault_a: derive_vault(&mint_a, &mint_b, &mint_a),
+            vault_b: derive_vault(&mint_a, &mint_b, &mint_b),
+            lp_token_account: get_associated_token_address(&lp.pubkey(), &lp_mint),
+            svm,
+            lp,
+            trader,
+            mint_a,
+            mint_b,
+            lp_mint,
+        }
+    }
+
+    fn token_balance(&self, token_account: &Pubkey) -> u64 {
+        get_spl_account::<spl_token::state::Account>(&self.svm, token_account)
+            .map(|account| account.amount)
+            .unwrap_or(0)
+    }
+
+    fn snapshot(&self) -> PoolSnapshot {
+        PoolSnapshot {
+            reserve_a: self.token_balance(&self.vault_a),
+            reserve_b: self.token_balance(&self.vault_b),
+            lp_supply: get_spl_account::<spl_token::state::Mint>(&self.svm, &self.lp_mint)
+                .unwrap()
+                .supply,
+        }
+    }
+
+    fn expiration(&self) -> i64 {
+        self.svm.get_sysvar::<Clock>().unix_timestamp + 60
+    }
+
+    // Send one operation; a Withdraw too small to burn any LP token is skipped (None)
+    fn apply(&mut self, op: &PoolOp) -> Option<TransactionResult> {
+        let expiration = self.expiration();
+
+        let ix = match *op {
+            PoolOp::Deposit { amount_a, amount_b } => build_deposit_liquidity_ix(
+                &self.lp.pubkey(),
+                &self.mint_a,
+                &self.mint_b,
+                amount_a,
+                amount_b,
+                u64::MAX,
+                u64::MAX,
+                expiration,
+            ),
+            PoolOp::Swap { a_for_b, amount } => {
+                let swap_ix = build_swap_tokens_ix(
+                    &self.trader.pubkey(),
+                    &self.mint_a,
+                    &self.mint_b,
+                    a_for_b,
+                    amount,
+                    1,
+                    expiration,
+                );
+                return Some(send_ix(&mut self.svm, swap_ix, &self.trader));
+            }
+            PoolOp::Withdraw { share_bps } => {
+                let held = self.token_balance(&self.lp_token_account);
+                let lp_tokens_to_burn = (held as u128 * share_bps as u128 / 10_000) as u64;
+                if lp_tokens_to_burn == 0 {
+                    return None;
+                }
+                build_withdraw_liquidity_ix(
+                    &self.lp.pubkey(),
+                    &self.mint_a,
+                    &self.mint_b,
+                    lp_tokens_to_burn,
+                    0,
+                    0,
+                    expiration,
+                )
+            }
+        };
+
+        // Deposits and withdrawals are both made by the seeding LP
+        Some(send_ix(&mut self.svm, ix, &self.lp))
+    }
+}
+
+// Largest amount of one reserve that burning `burned` of `supply` LP tokens may pay out
+fn redeemable(reserve: u64, burned: u64, supply: u64) -> u64 {
+    (reserve as u128 * burned as u128 / supply as u128) as u64
+}
+
+proptest! {
+    // Every case boots a fresh LiteSVM with the program loaded, so keep the count modest
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn fuzz_pool_invariants(
+        initial_a in MIN_INITIAL_RESERVE..MAX_INITIAL_RESERVE,
+        initial_b in MIN_INITIAL_RESERVE..MAX_INITIAL_RESERVE,
+        ops in prop::collection::vec(pool_op(), 1..MAX_OPS),
+    ) {
+        let mut pool = FuzzPool::new(initial_a, initial_b);
+
+        for op in &ops {
+            let before = pool.snapshot();
+            let Some(result) = pool.apply(op) else { continue };
+            let after = pool.snapshot();
+
+            if result.is_err() {
+                prop_assert_eq!(after, before, "Rejected {:?} changed pool state", op);
+                continue;
+            }
+
+            match *op {
+                PoolOp::Swap { .. } => {
+                    prop_assert_eq!(after.lp_supply, before.lp_supply);
+                    prop_assert!(
+                        after.k() >= before.k(),
+                        "k decreased on {:?}: {} -> {}", op, before.k(), after.k()
+                    );
+                }
+                PoolOp::Deposit { .. } => {
+                    prop_assert!(after.lp_supply > before.lp_supply);
+                    prop_assert!(after.reserve_a >= before.reserve_a);
+                    prop_assert!(after.reserve_b >= before.reserve_b);
+                }
+                PoolOp::Withdraw { .. } => {
+                    prop_assert!(after.reserve_a <= before.reserve_a);
+                    prop_assert!(after.reserve_b <= before.reserve_b);
+                    let burned = before.lp_supply - after.lp_supply;
+                    let paid_a = before.reserve_a - after.reserve_a;
+                    let paid_b = before.reserve_b - after.reserve_b;
+                    prop_assert!(paid_a <= redeemable(before.reserve_a, burned, before.lp_supply));
+                    prop_assert!(paid_b <= redeemable(before.reserve_b, burned, before.lp_supply));
+                }
+            }
+        }
+
+        // Redeem every LP token in circulation: the reserves must cover the whole supply
+        let before = pool.snapshot();
+        let held = pool.token_balance(&pool.lp_token_account);
+        prop_assert_eq!(held, before.lp_supply, "The seeding LP holds the whole supply");
+
+        if held > 0 {
+            let result = pool.apply(&PoolOp::Withdraw { share_bps: 10_000 }).unwrap();
+            prop_assert!(result.is_ok(), "Full redemption rejected: {:?}", result.err());
+
+            let after = pool.snapshot();
+            prop_assert_eq!(after.lp_supply, 0);
+            prop_assert!(after.reserve_a <= before.reserve_a);
+            prop_assert!(after.reserve_b <= before.reserve_b);
+        }
+    }
+}
@@ -4,11 +4,13 @@
 mod utils;
 
 use utils::*;
+use litesvm::{types::TransactionResult, LiteSVM};
 use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo, get_spl_account};
 use solana_sdk::{
     clock::Clock,
     native_token::LAMPORTS_PER_SOL,
-    signature::Signer,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
     transaction::Transaction,
 };
 
@@ -242,8 +244,8 @@ fn test_deposit_and_withdraw() {
     println!("[Step 1] Liquidity successfully deposited");
 
     // Check LP balance after deposit
-    let (pool_config, _) = derive_pool_config_pda(&mint_a, &mint_b);
-    let (lp_mint, _) = derive_lp_mint_pda(&pool_config);
+    let (pool_config, _) = amm::pool_config_address(&mint_a, &mint_b, POOL_FEE_TIER);
+    let (lp_mint, _) = amm::lp_mint_address(&pool_config);
     let depositor_lp_ata = spl_associated_token_account::get_associated_token_address(
         &depositor.pubkey(),
         &lp_mint,
@@ -284,6 +286,66 @@ fn test_deposit_and_withdraw() {
     println!("[TEST END] test_deposit_and_withdraw");
 }
 
+#[test]
+fn test_deposit_rounds_in_pool_favor() {
+    // Test: A deposit pays the rounded-up price of the LP tokens it receives
+    println!("\n[TEST START] test_deposit_rounds_in_pool_favor - Rounding direction");
+
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let depositor = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    // sqrt(10,000 * 10,000) - MINIMUM_LIQUIDITY = 9,000 LP for 10,000 of each token
+    let (mint_a, mint_b) = setup_pool_with_reserves(&mut svm, &authority, 10_000, 10_000);
+    println!("[Setup] Pool reserves: 10,000 A / 10,000 B, 9,000 LP");
+
+    let depositor_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &depositor, &mint_a)
+        .owner(&depositor.pubkey())
+        .send()
+        .unwrap();
+    let depositor_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &depositor, &mint_b)
+        .owner(&depositor.pubkey())
+        .send()
+        .unwrap();
+    for (mint, ata) in [(&mint_a, &depositor_ata_a), (&mint_b, &depositor_ata_b)] {
+        MintTo::new(&mut svm, &authority, mint, ata, 5).owner(&authority).send().unwrap();
+    }
+
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+
+    // 5 of each buys floor(5 * 9,000 / 10,000) = 4 LP, worth 4.44 of each token
+    println!("[Action] Depositing 5 A / 5 B with max 4 of each");
+    let deposit_ix =
+        build_deposit_liquidity_ix(&depositor.pubkey(), &mint_a, &mint_b, 5, 5, 4, 4, expiration);
+    assert_tx_err_code!(
+        send_ix(&mut svm, deposit_ix, &depositor),
+        errors::amm_secure::AmmError::ExcessiveDepositAmount
+    );
+    println!("[Success] ExcessiveDepositAmount - 4 LP cannot be bought for 4 of each");
+
+    println!("[Action] Depositing 5 A / 5 B with max 5 of each");
+    let deposit_ix =
+        build_deposit_liquidity_ix(&depositor.pubkey(), &mint_a, &mint_b, 5, 5, 5, 5, expiration);
+    send_tx_expect_success(&mut svm, deposit_ix, &depositor, &[&depositor]);
+
+    let (pool_config, _) = amm::pool_config_address(&mint_a, &mint_b, POOL_FEE_TIER);
+    let (lp_mint, _) = amm::lp_mint_address(&pool_config);
+    let depositor_lp_ata =
+        spl_associated_token_account::get_associated_token_address(&depositor.pubkey(), &lp_mint);
+    let balance = |svm: &LiteSVM, address: &Pubkey| {
+        get_spl_account::<spl_token::state::Account>(svm, address).unwrap().amount
+    };
+
+    assert_eq!(balance(&svm, &depositor_lp_ata), 4);
+    assert_eq!(balance(&svm, &depositor_ata_a), 0);
+    assert_eq!(balance(&svm, &depositor_ata_b), 0);
+    assert_eq!(balance(&svm, &derive_vault(&mint_a, &mint_b, &mint_a)), 10_005);
+    assert_eq!(balance(&svm, &derive_vault(&mint_a, &mint_b, &mint_b)), 10_005);
+
+    println!("[Success] Paid 5 of each for 4 LP (rounded up in the pool's favor)");
+    println!("[TEST END] test_deposit_rounds_in_pool_favor");
+}
+
 #[test]
 fn test_swap_a_for_b() {
     // Test: Perform a token swap (A → B) after adding liquidity
@@ -471,4 +533,2295 @@ fn test_lock_unlock_pool() {
 
     println!("[Success] Pool successfully unlocked");
     println!("[TEST END] test_lock_unlock_pool");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_token_2022_transfer_fee_swap() {
+    // Test: Pool with Token-2022 mints that charge a 10% transfer fee
+    // The pool must price swaps on what the vault actually receives (k must not decrease)
+    println!("\n[TEST START] test_token_2022_transfer_fee_swap - Token-2022 transfer fee pool");
+
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let lp = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let swapper = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    println!("[Setup] Authority, LP provider, and swapper funded");
+
+    // 1000 bp = 10% transfer fee, no cap
+    let transfer_fee_bps = 1000u16;
+    let mint_a = create_transfer_fee_mint(&mut svm, &authority, DECIMALS, transfer_fee_bps, u64::MAX);
+    let mint_b = create_transfer_fee_mint(&mut svm, &authority, DECIMALS, transfer_fee_bps, u64::MAX);
+    println!("[Setup] Token-2022 mints with {} bp transfer fee created", transfer_fee_bps);
+
+    // Initialize pool under the Token-2022 program
+    let init_ix = amm_ix::initialize_pool(
+        &authority.pubkey(),
+        &mint_a,
+        &mint_b,
+        30,
+        0,
+        &TOKEN_2022_PROGRAM_ID,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[init_ix],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_ok(), "Token-2022 pool initialization failed: {:?}", result.err());
+    println!("[Success] Token-2022 pool initialized");
+
+    // LP deposits 100 of each; sends extra to cover the transfer fee
+    let lp_ata_a = create_token_2022_ata(&mut svm, &lp, &lp.pubkey(), &mint_a);
+    let lp_ata_b = create_token_2022_ata(&mut svm, &lp, &lp.pubkey(), &mint_b);
+    let lp_balance = 200_000_000_000;
+    mint_token_2022(&mut svm, &authority, &mint_a, &lp_ata_a, lp_balance);
+    mint_token_2022(&mut svm, &authority, &mint_b, &lp_ata_b, lp_balance);
+
+    let clock = svm.get_sysvar::<Clock>();
+    let expiration = clock.unix_timestamp + 60;
+    let deposit_amount = 100_000_000_000;
+
+    let deposit_ix = amm_ix::deposit_liquidity(
+        &lp.pubkey(),
+        &mint_a,
+        &mint_b,
+        POOL_FEE_TIER,
+        deposit_amount,
+        deposit_amount,
+        lp_balance,
+        lp_balance,
+        expiration,
+        &TOKEN_2022_PROGRAM_ID,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[deposit_ix],
+        Some(&lp.pubkey()),
+        &[&lp],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_ok(), "Token-2022 deposit failed: {:?}", result.err());
+
+    let vault_a = amm::vault_address(&mint_a, &mint_b, POOL_FEE_TIER, &mint_a, &TOKEN_2022_PROGRAM_ID);
+    let vault_b = amm::vault_address(&mint_a, &mint_b, POOL_FEE_TIER, &mint_b, &TOKEN_2022_PROGRAM_ID);
+
+    // Deposit is grossed up so the vaults are credited exactly the LP math amounts
+    let vault_a_before = get_token_2022_balance(&svm, &vault_a);
+    let vault_b_before = get_token_2022_balance(&svm, &vault_b);
+    assert_eq!(vault_a_before, deposit_amount, "Vault A should receive the full post-fee deposit");
+    assert_eq!(vault_b_before, deposit_amount, "Vault B should receive the full post-fee deposit");
+    println!("[Step 1] Vaults credited {} A / {} B after fees", vault_a_before, vault_b_before);
+
+    // Swap 10 A -> B
+    let swapper_ata_a = create_token_2022_ata(&mut svm, &swapper, &swapper.pubkey(), &mint_a);
+    let swap_amount = 10_000_000_000;
+    mint_token_2022(&mut svm, &authority, &mint_a, &swapper_ata_a, swap_amount);
+
+    let swap_ix = amm_ix::swap_tokens(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        POOL_FEE_TIER,
+        true,
+        swap_amount,
+        1,
+        expiration,
+        &TOKEN_2022_PROGRAM_ID,
+        None,
+        None,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[swap_ix],
+        Some(&swapper.pubkey()),
+        &[&swapper],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_ok(), "Token-2022 swap failed: {:?}", result.err());
+
+    let vault_a_after = get_token_2022_balance(&svm, &vault_a);
+    let vault_b_after = get_token_2022_balance(&svm, &vault_b);
+    let expected_input_fee = swap_amount * transfer_fee_bps as u64 / 10_000;
+
+    assert_eq!(
+        vault_a_after - vault_a_before,
+        swap_amount - expected_input_fee,
+        "Vault A should only be credited the post-fee input"
+    );
+
+    let k_before = vault_a_before as u128 * vault_b_before as u128;
+    let k_after = vault_a_after as u128 * vault_b_after as u128;
+    println!("[Info] k before: {}, k after: {}", k_before, k_after);
+    assert!(k_after >= k_before, "Constant product must not decrease when pricing on post-fee input");
+
+    println!("[Success] Swap priced on post-fee input, LPs protected");
+    println!("[TEST END] test_token_2022_transfer_fee_swap");
+}
+
+#[test]
+fn test_flash_loan_repaid_with_fee() {
+    // Test: Borrow from vault A, repay amount + fee, and close the loan in one transaction
+    println!("\n[TEST START] test_flash_loan_repaid_with_fee - Flash loan happy path");
+
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let borrower = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let liquidity = 100_000_000_000;
+    let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, liquidity);
+    let vault_a = derive_vault(&mint_a, &mint_b, &mint_a);
+    println!("[Setup] Pool seeded with {} of each token", liquidity);
+
+    // Borrower only holds enough to cover the fee
+    let loan_amount = 50_000_000_000;
+    let fee = amm::flash_loan_fee(loan_amount);
+    let borrower_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &borrower, &mint_a)
+        .owner(&borrower.pubkey())
+        .send()
+        .unwrap();
+    MintTo::new(&mut svm, &authority, &mint_a, &borrower_ata_a, fee)
+        .owner(&authority)
+        .send()
+        .unwrap();
+    println!("[Setup] Borrower holds {} token A (the fee)", fee);
+
+    println!("[Action] begin({}) -> repay({}) -> end", loan_amount, loan_amount + fee);
+    let begin_ix = build_flash_loan_begin_ix(
+        &borrower.pubkey(),
+        &mint_a,
+        &mint_b,
+        &mint_a,
+        &borrower_ata_a,
+        loan_amount,
+    );
+    let repay_ix = build_repay_ix(
+        &borrower_ata_a,
+        &mint_a,
+        &vault_a,
+        &borrower.pubkey(),
+        loan_amount + fee,
+    );
+    let end_ix = build_flash_loan_end_ix(&mint_a, &mint_b, &mint_a);
+    let tx = Transaction::new_signed_with_payer(
+        &[begin_ix, repay_ix, end_ix],
+        Some(&borrower.pubkey()),
+        &[&borrower],
+        svm.latest_blockhash(),
+    );
+
+    let result = svm.send_transaction(tx);
+    assert!(result.is_ok(), "Repaid flash loan failed: {:?}", result.err());
+
+    let vault: spl_token::state::Account = get_spl_account(&svm, &vault_a).unwrap();
+    assert_eq!(vault.amount, liquidity + fee, "Vault should keep the flash loan fee");
+
+    println!("[Success] Loan repaid, vault grew by {} (fee to LPs)", fee);
+    println!("[TEST END] test_flash_loan_repaid_with_fee");
+}
+
+#[test]
+fn test_flash_loan_not_repaid_fails() {
+    // Test: flash_loan_end rejects a loan that was never paid back
+    println!("\n[TEST START] test_flash_loan_not_repaid_fails - Repayment enforcement");
+
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let liquidity = 100_000_000_000;
+    let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, liquidity);
+    let vault_a = derive_vault(&mint_a, &mint_b, &mint_a);
+    let attacker_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &mint_a)
+        .owner(&attacker.pubkey())
+        .send()
+        .unwrap();
+    println!("[Setup] Pool seeded with {} of each token", liquidity);
+
+    println!("[Action] Attacker borrows the whole vault and calls end without repaying");
+    let begin_ix = build_flash_loan_begin_ix(
+        &attacker.pubkey(),
+        &mint_a,
+        &mint_b,
+        &mint_a,
+        &attacker_ata_a,
+        liquidity,
+    );
+    let end_ix = build_flash_loan_end_ix(&mint_a, &mint_b, &mint_a);
+    let tx = Transaction::new_signed_with_payer(
+        &[begin_ix, end_ix],
+        Some(&attacker.pubkey()),
+        &[&attacker],
+        svm.latest_blockhash(),
+    );
+
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Unrepaid flash loan should fail");
+
+    let vault: spl_token::state::Account = get_spl_account(&svm, &vault_a).unwrap();
+    assert_eq!(vault.amount, liquidity, "Vault should be untouched after revert");
+
+    println!("[Success] FlashLoanNotRepaid - whole transaction reverted");
+    println!("[TEST END] test_flash_loan_not_repaid_fails");
+}
+
+#[test]
+fn test_flash_loan_requires_end_instruction() {
+    // Test: flash_loan_begin refuses to lend unless flash_loan_end follows it
+    println!("\n[TEST START] test_flash_loan_requires_end_instruction - Instruction introspection");
+
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let liquidity = 100_000_000_000;
+    let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, liquidity);
+    let attacker_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &mint_a)
+        .owner(&attacker.pubkey())
+        .send()
+        .unwrap();
+
+    println!("[Action] Sending flash_loan_begin with no flash_loan_end");
+    let begin_ix = build_flash_loan_begin_ix(
+        &attacker.pubkey(),
+        &mint_a,
+        &mint_b,
+        &mint_a,
+        &attacker_ata_a,
+        liquidity,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[begin_ix],
+        Some(&attacker.pubkey()),
+        &[&attacker],
+        svm.latest_blockhash(),
+    );
+
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "flash_loan_begin without end should fail");
+
+    println!("[Success] MissingFlashLoanEnd - loan rejected before any tokens moved");
+    println!("[TEST END] test_flash_loan_requires_end_instruction");
+}
+
+#[test]
+fn test_permissioned_pool_creation() {
+    // Test: Once the global AmmConfig exists, only its pool creator can create pools
+    // and only with whitelisted fee tiers
+    println!("\n[TEST START] test_permissioned_pool_creation - Global AMM config enforcement");
+
+    let mut svm = setup_svm();
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let outsider = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    set_upgrade_authority(&mut svm, &AMM_PROGRAM_ID, &admin.pubkey());
+    println!("[Setup] Admin and outsider funded, admin holds the upgrade authority");
+
+    // Initialize global config: admin is the only pool creator, tiers 5/30/100 bp
+    println!("[Action] Initializing AMM config with fee tiers [5, 30, 100]");
+    let config_ix = build_initialize_amm_config_ix(&admin.pubkey(), &admin.pubkey(), &[5, 30, 100]);
+    let tx = Transaction::new_signed_with_payer(
+        &[config_ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_ok(), "AMM config init failed: {:?}", result.err());
+    println!("[Success] AMM config initialized");
+
+    let mint_a = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .unwrap();
+    let mint_b = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .unwrap();
+
+    // Outsider cannot create a pool
+    println!("[Action] Outsider tries to create a pool with a whitelisted fee");
+    let init_ix = build_initialize_pool_ix(&outsider.pubkey(), &mint_a, &mint_b, 30);
+    let tx = Transaction::new_signed_with_payer(
+        &[init_ix],
+        Some(&outsider.pubkey()),
+        &[&outsider],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Outsider should not be able to create pools");
+    println!("[Success] UnauthorizedPoolCreator - outsider rejected");
+
+    // Admin cannot use a fee outside the whitelist
+    println!("[Action] Admin tries to create a pool with 25bp (not whitelisted)");
+    let init_ix = build_initialize_pool_ix(&admin.pubkey(), &mint_a, &mint_b, 25);
+    let tx = Transaction::new_signed_with_payer(
+        &[init_ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Non-whitelisted fee should be rejected");
+    println!("[Success] FeeTierNotWhitelisted - 25bp rejected");
+
+    // Admin with a whitelisted fee succeeds
+    println!("[Action] Admin creates a pool with 30bp");
+    let init_ix = build_initialize_pool_ix(&admin.pubkey(), &mint_a, &mint_b, 30);
+    let tx = Transaction::new_signed_with_payer(
+        &[init_ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_ok(), "Whitelisted pool creation failed: {:?}", result.err());
+
+    println!("[Success] Pool created by the configured authority with a whitelisted fee");
+    println!("[TEST END] test_permissioned_pool_creation");
+}
+
+#[test]
+fn test_amm_config_admin() {
+    // Test: Only the upgrade authority can create the global config, and only
+    // its admin can update it; a rotated-out admin loses access
+    println!("\n[TEST START] test_amm_config_admin - Config initialization and updates");
+
+    let mut svm = setup_svm();
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let new_admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let outsider = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    set_upgrade_authority(&mut svm, &AMM_PROGRAM_ID, &admin.pubkey());
+    println!("[Setup] Admin holds the upgrade authority");
+
+    // Front-running the deploy script fails
+    println!("[Action] Outsider tries to initialize the config");
+    let ix = build_initialize_amm_config_ix(&outsider.pubkey(), &outsider.pubkey(), &[30]);
+    assert_tx_err_code!(
+        send_ix(&mut svm, ix, &outsider),
+        errors::amm_secure::AmmError::UnauthorizedConfigAdmin
+    );
+    println!("[Success] UnauthorizedConfigAdmin - outsider cannot initialize");
+
+    let ix = build_initialize_amm_config_ix(&admin.pubkey(), &admin.pubkey(), &[30]);
+    send_tx_expect_success(&mut svm, ix, &admin, &[&admin]);
+    println!("[Success] Upgrade authority initialized the config");
+
+    // Outsider cannot update
+    println!("[Action] Outsider tries to take over the config");
+    let ix = amm_ix::update_amm_config(&outsider.pubkey(), &outsider.pubkey(), &outsider.pubkey(), &[30]);
+    assert_tx_err_code!(
+        send_ix(&mut svm, ix, &outsider),
+        errors::amm_secure::AmmError::UnauthorizedConfigAdmin
+    );
+    println!("[Success] UnauthorizedConfigAdmin - outsider cannot update");
+
+    // Updates are validated like initialization
+    println!("[Action] Admin tries to whitelist a duplicate tier");
+    let ix = amm_ix::update_amm_config(&admin.pubkey(), &admin.pubkey(), &admin.pubkey(), &[30, 30]);
+    assert_tx_err_code!(
+        send_ix(&mut svm, ix, &admin),
+        errors::amm_secure::AmmError::DuplicateFeeTier
+    );
+    println!("[Success] DuplicateFeeTier - invalid tiers rejected");
+
+    // Admin rotates to a new admin and opens pool creation to them
+    println!("[Action] Admin hands the config to a new admin with tiers [5, 30]");
+    let ix = amm_ix::update_amm_config(&admin.pubkey(), &new_admin.pubkey(), &new_admin.pubkey(), &[5, 30]);
+    send_tx_expect_success(&mut svm, ix, &admin, &[&admin]);
+
+    let (amm_config, _) = amm::amm_config_address();
+    let config = amm::AmmConfig::try_from_bytes(&svm.get_account(&amm_config).unwrap().data)
+        .expect("AmmConfig should decode");
+    assert_eq!(config.admin, new_admin.pubkey());
+    assert_eq!(config.pool_creation_authority, new_admin.pubkey());
+    assert_eq!(config.fee_tiers, vec![5, 30]);
+    println!("[Success] Config rotated to the new admin");
+
+    println!("[Action] Old admin tries another update");
+    let ix = amm_ix::update_amm_config(&admin.pubkey(), &admin.pubkey(), &admin.pubkey(), &[30]);
+    assert_tx_err_code!(
+        send_ix(&mut svm, ix, &admin),
+        errors::amm_secure::AmmError::UnauthorizedConfigAdmin
+    );
+    println!("[Success] Rotated-out admin rejected");
+
+    println!("[TEST END] test_amm_config_admin");
+}
+
+#[test]
+fn test_swap_exact_out() {
+    // Test: Receive an exact amount of token B, paying at most max_input_amount of A
+    println!("\n[TEST START] test_swap_exact_out - Exact-output swap");
+
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let swapper = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let liquidity = 100_000_000_000;
+    let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, liquidity);
+    println!("[Setup] Pool seeded with {} of each token (30bp fee)", liquidity);
+
+    let swapper_balance_a = 20_000_000_000;
+    let swapper_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &swapper, &mint_a)
+        .owner(&swapper.pubkey())
+        .send()
+        .unwrap();
+    MintTo::new(&mut svm, &authority, &mint_a, &swapper_ata_a, swapper_balance_a)
+        .owner(&authority)
+        .send()
+        .unwrap();
+
+    // Expected input, rounded up twice: curve step then fee step
+    let output_amount: u64 = 10_000_000_000;
+    let net_input = (liquidity as u128 * output_amount as u128)
+        .div_ceil((liquidity - output_amount) as u128);
+    let expected_input = (net_input * 10_000).div_ceil(10_000 - 30) as u64;
+    println!("[Setup] Exact output {} B should cost {} A", output_amount, expected_input);
+
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+
+    // Max input one below the true cost must fail
+    println!("[Action] Swapping with max_input_amount = cost - 1 (should fail)");
+    let swap_ix = build_swap_tokens_exact_out_ix(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        expected_input - 1,
+        output_amount,
+        expiration,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[swap_ix],
+        Some(&swapper.pubkey()),
+        &[&swapper],
+        svm.latest_blockhash(),
+    );
+    assert!(svm.send_transaction(tx).is_err(), "Slippage guard should reject");
+    println!("[Success] SlippageExceeded - max input enforced");
+
+    println!("[Action] Swapping with max_input_amount = 12 A");
+    let swap_ix = build_swap_tokens_exact_out_ix(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        12_000_000_000,
+        output_amount,
+        expiration,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[swap_ix],
+        Some(&swapper.pubkey()),
+        &[&swapper],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_ok(), "Exact-out swap failed: {:?}", result.err());
+
+    let swapper_ata_b = spl_associated_token_account::get_associated_token_address(
+        &swapper.pubkey(),
+        &mint_b,
+    );
+    let account_a: spl_token::state::Account = get_spl_account(&svm, &swapper_ata_a).unwrap();
+    let account_b: spl_token::state::Account = get_spl_account(&svm, &swapper_ata_b).unwrap();
+
+    assert_eq!(account_b.amount, output_amount, "Swapper should receive the exact output");
+    assert_eq!(
+        swapper_balance_a - account_a.amount,
+        expected_input,
+        "Swapper should pay the rounded-up input"
+    );
+
+    println!("[Success] Received exactly {} B for {} A", account_b.amount, expected_input);
+    println!("[TEST END] test_swap_exact_out");
+}
+
+#[test]
+fn test_swap_exact_out_rounds_in_pool_favor() {
+    // Test: Tiny outputs whose true cost is a fraction of a token still cost at least 1
+    println!("\n[TEST START] test_swap_exact_out_rounds_in_pool_favor - Rounding direction");
+
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let swapper = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    // 1 A is worth 1,000,000 B
+    let (mint_a, mint_b) =
+        setup_pool_with_reserves(&mut svm, &authority, 1_000, 1_000_000_000);
+    println!("[Setup] Pool reserves: 1,000 A / 1,000,000,000 B");
+
+    let swapper_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &swapper, &mint_a)
+        .owner(&swapper.pubkey())
+        .send()
+        .unwrap();
+    MintTo::new(&mut svm, &authority, &mint_a, &swapper_ata_a, 10)
+        .owner(&authority)
+        .send()
+        .unwrap();
+
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+
+    // True cost of 500,000 B is ~0.5 A - rounding down would make it free
+    println!("[Action] Requesting 500,000 B with max_input_amount = 0");
+    let swap_ix = build_swap_tokens_exact_out_ix(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        0,
+        500_000,
+        expiration,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[swap_ix],
+        Some(&swapper.pubkey()),
+        &[&swapper],
+        svm.latest_blockhash(),
+    );
+    assert!(svm.send_transaction(tx).is_err(), "Free output should be rejected");
+    println!("[Success] Cannot receive output for 0 input");
+
+    println!("[Action] Requesting 500,000 B with max_input_amount = 10");
+    let swap_ix = build_swap_tokens_exact_out_ix(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        10,
+        500_000,
+        expiration,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[swap_ix],
+        Some(&swapper.pubkey()),
+        &[&swapper],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_ok(), "Exact-out swap failed: {:?}", result.err());
+
+    let account_a: spl_token::state::Account = get_spl_account(&svm, &swapper_ata_a).unwrap();
+    let paid = 10 - account_a.amount;
+    assert!(paid >= 1, "Swapper must pay at least 1 A");
+
+    println!("[Success] Paid {} A (rounded up in the pool's favor)", paid);
+    println!("[TEST END] test_swap_exact_out_rounds_in_pool_favor");
+}
+
+#[test]
+fn test_fee_change_timelock() {
+    println!("\n[TEST START] test_fee_change_timelock - Two-step fee change with delay");
+
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, 1_000_000_000);
+    println!("[Setup] Pool created with 30bp fee");
+
+    // Non-authority cannot queue a fee change
+    println!("[Action] Attacker requesting fee change to 1000bp");
+    let request_ix = build_request_fee_change_ix(&attacker.pubkey(), &mint_a, &mint_b, 1000);
+    let tx = Transaction::new_signed_with_payer(
+        &[request_ix],
+        Some(&attacker.pubkey()),
+        &[&attacker],
+        svm.latest_blockhash(),
+    );
+    assert!(svm.send_transaction(tx).is_err(), "Non-authority request should fail");
+    println!("[Success] Unauthorized request rejected");
+
+    // Request + immediate execute in one transaction must fail
+    println!("[Action] Authority requesting 100bp and executing immediately");
+    let request_ix = build_request_fee_change_ix(&authority.pubkey(), &mint_a, &mint_b, 100);
+    let change_ix = build_change_pool_fee_ix(&authority.pubkey(), &mint_a, &mint_b);
+    let tx = Transaction::new_signed_with_payer(
+        &[request_ix.clone(), change_ix.clone()],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+    assert!(svm.send_transaction(tx).is_err(), "Instant fee change should fail");
+    assert_eq!(get_pool_fee_basis_points(&svm, &mint_a, &mint_b), 30);
+    println!("[Success] Instant fee change rejected");
+
+    // Queue the change on its own
+    let tx = Transaction::new_signed_with_payer(
+        &[request_ix],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_ok(), "Fee change request failed: {:?}", result.err());
+    println!("[Success] Fee change to 100bp queued");
+
+    // Warp to just before the unlock time - still locked
+    let mut clock = svm.get_sysvar::<Clock>();
+    let requested_at = clock.unix_timestamp;
+    clock.unix_timestamp = requested_at + 172_800 - 1;
+    svm.set_sysvar::<Clock>(&clock);
+    svm.expire_blockhash();
+
+    println!("[Action] Executing 1 second before the 48 hour delay elapses");
+    let tx = Transaction::new_signed_with_payer(
+        &[change_ix.clone()],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+    assert!(svm.send_transaction(tx).is_err(), "Early execution should fail");
+    assert_eq!(get_pool_fee_basis_points(&svm, &mint_a, &mint_b), 30);
+    println!("[Success] Early execution rejected");
+
+    // Warp past the unlock time
+    clock.unix_timestamp = requested_at + 172_800;
+    svm.set_sysvar::<Clock>(&clock);
+    svm.expire_blockhash();
+
+    println!("[Action] Executing after the delay");
+    let tx = Transaction::new_signed_with_payer(
+        &[change_ix],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_ok(), "Fee change failed after delay: {:?}", result.err());
+    assert_eq!(get_pool_fee_basis_points(&svm, &mint_a, &mint_b), 100);
+
+    println!("[Success] Fee updated to 100bp after the timelock");
+    println!("[TEST END] test_fee_change_timelock");
+}
+
+#[test]
+fn test_fee_change_respects_fee_tiers() {
+    // Test: Once the global config exists, fee changes are held to its whitelisted
+    // tiers, both when queued and when applied after the delay
+    println!("\n[TEST START] test_fee_change_respects_fee_tiers - Fee changes stay on whitelisted tiers");
+
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, 1_000_000_000);
+    println!("[Setup] Pool created with 30bp fee");
+
+    set_upgrade_authority(&mut svm, &AMM_PROGRAM_ID, &authority.pubkey());
+    let ix = build_initialize_amm_config_ix(&authority.pubkey(), &authority.pubkey(), &[5, 30]);
+    send_tx_expect_success(&mut svm, ix, &authority, &[&authority]);
+    println!("[Setup] AmmConfig initialized with tiers [5, 30]");
+
+    // 100bp is under the global cap but not a tier
+    println!("[Action] Authority requesting 100bp");
+    let ix = build_request_fee_change_ix(&authority.pubkey(), &mint_a, &mint_b, 100);
+    assert_tx_err_code!(
+        send_ix(&mut svm, ix, &authority),
+        errors::amm_secure::AmmError::FeeTierNotWhitelisted
+    );
+    println!("[Success] FeeTierNotWhitelisted - off-tier fee cannot be queued");
+
+    println!("[Action] Authority requesting 5bp");
+    let ix = build_request_fee_change_ix(&authority.pubkey(), &mint_a, &mint_b, 5);
+    send_tx_expect_success(&mut svm, ix, &authority, &[&authority]);
+    println!("[Success] Whitelisted fee queued");
+
+    // Admin drops the 5bp tier while the change is pending
+    let ix = amm_ix::update_amm_config(&authority.pubkey(), &authority.pubkey(), &authority.pubkey(), &[30]);
+    send_tx_expect_success(&mut svm, ix, &authority, &[&authority]);
+    println!("[Setup] Admin removed the 5bp tier");
+
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += 172_800;
+    svm.set_sysvar::<Clock>(&clock);
+    svm.expire_blockhash();
+
+    println!("[Action] Applying the queued 5bp fee after the delay");
+    let ix = build_change_pool_fee_ix(&authority.pubkey(), &mint_a, &mint_b);
+    assert_tx_err_code!(
+        send_ix(&mut svm, ix, &authority),
+        errors::amm_secure::AmmError::FeeTierNotWhitelisted
+    );
+    assert_eq!(get_pool_fee_basis_points(&svm, &mint_a, &mint_b), 30);
+    println!("[Success] FeeTierNotWhitelisted - removed tier cannot be applied");
+
+    println!("[TEST END] test_fee_change_respects_fee_tiers");
+}
+
+#[test]
+fn test_emergency_withdraw_while_locked() {
+    println!("\n[TEST START] test_emergency_withdraw_while_locked - LP exit from a paused pool");
+
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let liquidity = 1_000_000_000;
+    let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, liquidity);
+    let (pool_config, _) = amm::pool_config_address(&mint_a, &mint_b, POOL_FEE_TIER);
+    let (lp_mint, _) = amm::lp_mint_address(&pool_config);
+    let authority_lp_ata = spl_associated_token_account::get_associated_token_address(
+        &authority.pubkey(),
+        &lp_mint,
+    );
+    let authority_ata_a = spl_associated_token_account::get_associated_token_address(
+        &authority.pubkey(),
+        &mint_a,
+    );
+    let lp_account: spl_token::state::Account = get_spl_account(&svm, &authority_lp_ata).unwrap();
+    let lp_to_burn = lp_account.amount / 2;
+    println!("[Setup] Pool seeded, authority holds {} LP", lp_account.amount);
+
+    // Emergency withdraw is only for locked pools
+    println!("[Action] Emergency withdraw on an unlocked pool");
+    let emergency_ix = build_emergency_withdraw_ix(&authority.pubkey(), &mint_a, &mint_b, lp_to_burn);
+    let tx = Transaction::new_signed_with_payer(
+        &[emergency_ix.clone()],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+    assert!(svm.send_transaction(tx).is_err(), "Emergency withdraw requires a locked pool");
+    println!("[Success] Rejected while unlocked");
+
+    let lock_ix = build_lock_pool_ix(&authority.pubkey(), &mint_a, &mint_b);
+    let tx = Transaction::new_signed_with_payer(
+        &[lock_ix],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+    svm.expire_blockhash();
+    println!("[Setup] Pool locked");
+
+    // Locked but not enabled - still frozen
+    println!("[Action] Emergency withdraw before the authority enables it");
+    let tx = Transaction::new_signed_with_payer(
+        &[emergency_ix.clone()],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+    assert!(svm.send_transaction(tx).is_err(), "Emergency withdraw should be disabled by default");
+    println!("[Success] Rejected while disabled");
+
+    // Only the pool authority can enable it
+    let enable_ix = build_set_emergency_withdraw_ix(&attacker.pubkey(), &mint_a, &mint_b, true);
+    let tx = Transaction::new_signed_with_payer(
+        &[enable_ix],
+        Some(&attacker.pubkey()),
+        &[&attacker],
+        svm.latest_blockhash(),
+    );
+    assert!(svm.send_transaction(tx).is_err(), "Non-authority should not toggle emergency withdraw");
+    println!("[Success] Non-authority cannot enable emergency withdraw");
+
+    let enable_ix = build_set_emergency_withdraw_ix(&authority.pubkey(), &mint_a, &mint_b, true);
+    let tx = Transaction::new_signed_with_payer(
+        &[enable_ix],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+    svm.expire_blockhash();
+    println!("[Setup] Emergency withdraw enabled by authority");
+
+    // Regular withdrawals stay frozen
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+    let withdraw_ix = build_withdraw_liquidity_ix(
+        &authority.pubkey(),
+        &mint_a,
+        &mint_b,
+        lp_to_burn,
+        0,
+        0,
+        expiration,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[withdraw_ix],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+    assert!(svm.send_transaction(tx).is_err(), "Regular withdraw should stay locked");
+
+    let balance_a_before: spl_token::state::Account = get_spl_account(&svm, &authority_ata_a).unwrap();
+
+    println!("[Action] Emergency withdrawing {} LP", lp_to_burn);
+    let tx = Transaction::new_signed_with_payer(
+        &[emergency_ix],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_ok(), "Emergency withdraw failed: {:?}", result.err());
+
+    let lp_after: spl_token::state::Account = get_spl_account(&svm, &authority_lp_ata).unwrap();
+    let balance_a_after: spl_token::state::Account = get_spl_account(&svm, &authority_ata_a).unwrap();
+    assert_eq!(lp_after.amount, lp_account.amount - lp_to_burn);
+
+    // Proportional share: lp_to_burn / lp_supply of the vault
+    // Authority is the only LP; MINIMUM_LIQUIDITY was never minted
+    let lp_supply = lp_account.amount;
+    let expected_a = (lp_to_burn as u128 * liquidity as u128 / lp_supply as u128) as u64;
+    assert_eq!(balance_a_after.amount - balance_a_before.amount, expected_a);
+
+    println!("[Success] Received {} A for {} LP while the pool is locked", expected_a, lp_to_burn);
+    println!("[TEST END] test_emergency_withdraw_while_locked");
+}
+
+#[test]
+fn test_swap_with_referrer() {
+    println!("\n[TEST START] test_swap_with_referrer - Referral share of the swap fee");
+
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let swapper = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let referrer = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    // Referral share above the cap is rejected at pool creation
+    let mint_x = CreateMint::new(&mut svm, &authority)
+        .authority(&authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .unwrap();
+    let mint_y = CreateMint::new(&mut svm, &authority)
+        .authority(&authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .unwrap();
+    println!("[Action] Creating pool with referral share of 6000bp (max 5000)");
+    let init_ix =
+        build_initialize_pool_ix_with_referral_fee(&authority.pubkey(), &mint_x, &mint_y, 30, 6000);
+    let tx = Transaction::new_signed_with_payer(
+        &[init_ix],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+    assert!(svm.send_transaction(tx).is_err(), "Referral share above cap should fail");
+    println!("[Success] Referral share cap enforced");
+
+    // 30bp fee, referrer gets 20% of it (6bp of the input)
+    let liquidity = 1_000_000_000;
+    let (mint_a, mint_b) =
+        setup_pool_with_referral_fee(&mut svm, &authority, liquidity, liquidity, 2000);
+    let vault_a = derive_vault(&mint_a, &mint_b, &mint_a);
+    println!("[Setup] Pool created with 30bp fee, 2000bp referral share");
+
+    let swap_amount = 10_000_000;
+    let swapper_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &swapper, &mint_a)
+        .owner(&swapper.pubkey())
+        .send()
+        .unwrap();
+    MintTo::new(&mut svm, &authority, &mint_a, &swapper_ata_a, swap_amount)
+        .owner(&authority)
+        .send()
+        .unwrap();
+    let referrer_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &referrer, &mint_a)
+        .owner(&referrer.pubkey())
+        .send()
+        .unwrap();
+    let referrer_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &referrer, &mint_b)
+        .owner(&referrer.pubkey())
+        .send()
+        .unwrap();
+
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+
+    // Pool vault cannot be the referrer
+    println!("[Action] Swapping with the token A vault as referrer");
+    let swap_ix = build_swap_tokens_ix_with_referrer(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        swap_amount,
+        1,
+        expiration,
+        &vault_a,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[swap_ix],
+        Some(&swapper.pubkey()),
+        &[&swapper],
+        svm.latest_blockhash(),
+    );
+    assert!(svm.send_transaction(tx).is_err(), "Pool vault as referrer should fail");
+    println!("[Success] Pool vault rejected as referrer");
+
+    // Referral is paid in the input token
+    println!("[Action] Swapping A -> B with a token B referrer account");
+    let swap_ix = build_swap_tokens_ix_with_referrer(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        swap_amount,
+        1,
+        expiration,
+        &referrer_ata_b,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[swap_ix],
+        Some(&swapper.pubkey()),
+        &[&swapper],
+        svm.latest_blockhash(),
+    );
+    assert!(svm.send_transaction(tx).is_err(), "Referrer with wrong mint should fail");
+    println!("[Success] Wrong-mint referrer rejected");
+
+    println!("[Action] Swapping {} A -> B with a valid referrer", swap_amount);
+    let swap_ix = build_swap_tokens_ix_with_referrer(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        swap_amount,
+        1,
+        expiration,
+        &referrer_ata_a,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[swap_ix],
+        Some(&swapper.pubkey()),
+        &[&swapper],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_ok(), "Referral swap failed: {:?}", result.err());
+
+    // 10,000,000 * 30 * 2000 / 10_000^2 = 6,000
+    let expected_referral = 6_000;
+    let referrer_a: spl_token::state::Account = get_spl_account(&svm, &referrer_ata_a).unwrap();
+    let vault_a_after: spl_token::state::Account = get_spl_account(&svm, &vault_a).unwrap();
+    assert_eq!(referrer_a.amount, expected_referral);
+    assert_eq!(vault_a_after.amount, liquidity + swap_amount - expected_referral);
+
+    println!("[Success] Referrer received {} A, LPs kept the rest of the fee", referrer_a.amount);
+    println!("[TEST END] test_swap_with_referrer");
+}
+
+#[test]
+fn test_swap_with_rebate() {
+    println!("\n[TEST START] test_swap_with_rebate - Reduced fee for LP holders");
+
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let swapper = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    // Authority is the first LP: 10,000,000,000 - 1,000 LP, above the 1 LP token threshold
+    let liquidity = 10_000_000_000;
+    let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, liquidity);
+    let (pool_config, _) = amm::pool_config_address(&mint_a, &mint_b, POOL_FEE_TIER);
+    let (lp_mint, _) = amm::lp_mint_address(&pool_config);
+    let authority_lp_ata =
+        spl_associated_token_account::get_associated_token_address(&authority.pubkey(), &lp_mint);
+    println!("[Setup] Pool created with 30bp fee, authority holds the LP tokens");
+
+    let swap_amount = 10_000_000;
+    let swapper_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &swapper, &mint_a)
+        .owner(&swapper.pubkey())
+        .send()
+        .unwrap();
+    MintTo::new(&mut svm, &authority, &mint_a, &swapper_ata_a, 3 * swap_amount)
+        .owner(&authority)
+        .send()
+        .unwrap();
+    CreateAssociatedTokenAccount::new(&mut svm, &swapper, &lp_mint)
+        .owner(&swapper.pubkey())
+        .send()
+        .unwrap();
+
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+
+    // Swapper without LP tokens pays the full fee
+    println!("[Action] Swapper with an empty LP account swaps with rebate");
+    let swap_ix = build_swap_with_rebate_ix(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        swap_amount,
+        1,
+        expiration,
+    );
+    let meta = send_tx(&mut svm, &[swap_ix], &swapper, &[&swapper]).expect("Swap should succeed");
+    assert!(meta.logs.iter().any(|log| log.contains("Swap fee: 30 basis points")));
+    println!("[Success] No LP tokens, full 30bp fee");
+
+    // Another LP's balance cannot be borrowed
+    println!("[Action] Swapper passes the authority's LP account");
+    let swap_ix = build_swap_with_rebate_ix_with_lp_account(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        swap_amount,
+        1,
+        expiration,
+        &authority_lp_ata,
+    );
+    let result = send_tx(&mut svm, &[swap_ix], &swapper, &[&swapper]);
+    assert!(result.is_err(), "Another user's LP account should be rejected");
+    println!("[Success] Foreign LP account rejected");
+
+    // A self-minted token is not an LP token
+    println!("[Action] Swapper passes an account of a mint they control");
+    let fake_mint = CreateMint::new(&mut svm, &swapper)
+        .authority(&swapper.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .unwrap();
+    let fake_lp_ata = CreateAssociatedTokenAccount::new(&mut svm, &swapper, &fake_mint)
+        .owner(&swapper.pubkey())
+        .send()
+        .unwrap();
+    MintTo::new(&mut svm, &swapper, &fake_mint, &fake_lp_ata, 1_000_000_000_000)
+        .owner(&swapper)
+        .send()
+        .unwrap();
+    let swap_ix = build_swap_with_rebate_ix_with_lp_account(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        swap_amount,
+        1,
+        expiration,
+        &fake_lp_ata,
+    );
+    let result = send_tx(&mut svm, &[swap_ix], &swapper, &[&swapper]);
+    assert!(result.is_err(), "Wrong-mint LP account should be rejected");
+    println!("[Success] Wrong-mint LP account rejected");
+
+    // The LP holder gets half the fee waived
+    println!("[Action] Authority (LP holder) swaps with rebate");
+    let authority_ata_a =
+        spl_associated_token_account::get_associated_token_address(&authority.pubkey(), &mint_a);
+    MintTo::new(&mut svm, &authority, &mint_a, &authority_ata_a, swap_amount)
+        .owner(&authority)
+        .send()
+        .unwrap();
+    let swap_ix = build_swap_with_rebate_ix(
+        &authority.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        swap_amount,
+        1,
+        expiration,
+    );
+    let meta = send_tx(&mut svm, &[swap_ix], &authority, &[&authority]).expect("Swap should succeed");
+    assert!(meta.logs.iter().any(|log| log.contains("Swap fee: 15 basis points")));
+    println!("[Success] LP holder swapped at 15bp");
+
+    println!("[TEST END] test_swap_with_rebate");
+}
+
+#[test]
+fn test_swap_oracle_guard() {
+    println!("\n[TEST START] test_swap_oracle_guard - Swaps checked against an oracle price");
+
+    let mut svm = setup_svm_with_oracle();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let swapper = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    // 1,000 A / 1,000 B: pool price 1 B per A
+    let liquidity = 1_000_000_000_000;
+    let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, liquidity);
+
+    // Feed at 1.000000 B per A, fresh for 60s, confidence up to 1%
+    let price_feed = derive_price_feed(&authority.pubkey(), 1);
+    let init_feed_ix = build_initialize_feed_ix(&authority.pubkey(), 1, -6, 60, 100);
+    send_tx_expect_success(&mut svm, init_feed_ix, &authority, &[&authority]);
+    let push_ix = build_push_price_ix(&authority.pubkey(), &price_feed, 1_000_000, 1_000);
+    send_tx_expect_success(&mut svm, push_ix, &authority, &[&authority]);
+    println!("[Setup] Pool at 1:1, oracle feed at 1.000000 B per A");
+
+    // Only the pool authority can configure the guard
+    println!("[Action] Non-authority sets the oracle guard");
+    let guard_ix =
+        build_set_oracle_guard_ix(&swapper.pubkey(), &mint_a, &mint_b, &price_feed, 200);
+    let failure = send_tx_expect_failure(&mut svm, guard_ix, &swapper, &[&swapper]);
+    assert!(failure.contains("UnauthorizedAccess"), "{}", failure);
+    println!("[Success] Non-authority rejected");
+
+    let guard_ix =
+        build_set_oracle_guard_ix(&authority.pubkey(), &mint_a, &mint_b, &price_feed, 200);
+    send_tx_expect_success(&mut svm, guard_ix, &authority, &[&authority]);
+    let pool = PoolConfig::try_from_bytes(
+        &svm.get_account(&amm::pool_config_address(&mint_a, &mint_b, POOL_FEE_TIER).0).unwrap().data,
+    )
+    .unwrap();
+    assert_eq!(pool.price_feed, price_feed);
+    assert_eq!(pool.max_oracle_deviation_bps, 200);
+    println!("[Setup] Oracle guard set to 200bp");
+
+    let swap_amount = 100_000_000_000;
+    let swapper_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &swapper, &mint_a)
+        .owner(&swapper.pubkey())
+        .send()
+        .unwrap();
+    MintTo::new(&mut svm, &authority, &mint_a, &swapper_ata_a, swap_amount)
+        .owner(&authority)
+        .send()
+        .unwrap();
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+
+    // Guarded pools need the feed
+    println!("[Action] Swapping without the price feed");
+    let swap_ix = build_swap_tokens_ix(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        1_000_000_000,
+        1,
+        expiration,
+    );
+    let failure = send_tx_expect_failure(&mut svm, swap_ix, &swapper, &[&swapper]);
+    assert!(failure.contains("PriceFeedRequired"), "{}", failure);
+    println!("[Success] Swap without feed rejected");
+
+    // 1 A moves the pool price ~0.2%
+    println!("[Action] Swapping 1 A -> B with the feed");
+    let swap_ix = build_swap_tokens_ix_with_price_feed(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        1_000_000_000,
+        1,
+        expiration,
+        &price_feed,
+    );
+    send_tx_expect_success(&mut svm, swap_ix, &swapper, &[&swapper]);
+    println!("[Success] Small swap within the guard");
+
+    // 50 A would move the pool price ~9%
+    println!("[Action] Swapping 50 A -> B with the feed");
+    let swap_ix = build_swap_tokens_ix_with_price_feed(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        50_000_000_000,
+        1,
+        expiration,
+        &price_feed,
+    );
+    let failure = send_tx_expect_failure(&mut svm, swap_ix, &swapper, &[&swapper]);
+    assert!(failure.contains("OraclePriceDeviation"), "{}", failure);
+    println!("[Success] Price-moving swap rejected");
+
+    // Feed goes stale after 60s without a push
+    advance_time(&mut svm, 120);
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+    println!("[Action] Swapping 1 A -> B against a 120s old price");
+    let swap_ix = build_swap_tokens_ix_with_price_feed(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        1_000_000_000,
+        1,
+        expiration,
+        &price_feed,
+    );
+    let failure = send_tx_expect_failure(&mut svm, swap_ix, &swapper, &[&swapper]);
+    assert!(failure.contains("StaleOraclePrice"), "{}", failure);
+    println!("[Success] Stale oracle price rejected");
+
+    let push_ix = build_push_price_ix(&authority.pubkey(), &price_feed, 1_000_000, 1_000);
+    send_tx_expect_success(&mut svm, push_ix, &authority, &[&authority]);
+    let swap_ix = build_swap_tokens_ix_with_price_feed(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        1_000_000_000,
+        1,
+        expiration,
+        &price_feed,
+    );
+    send_tx_expect_success(&mut svm, swap_ix, &swapper, &[&swapper]);
+    println!("[Success] Swaps resume once the feed is fresh again");
+}
+
+#[test]
+fn test_position_nft_lifecycle() {
+    println!("\n[TEST START] test_position_nft_lifecycle - Liquidity held as a Core NFT position");
+
+    let mut svm = setup_svm_with_mpl_core();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    // 1,000 A / 2,000 B: pool price 2 B per A
+    let (liquidity_a, liquidity_b) = (1_000_000_000_000, 2_000_000_000_000);
+    let (mint_a, mint_b) = setup_pool_with_reserves(&mut svm, &authority, liquidity_a, liquidity_b);
+    let (pool_config, _) = amm::pool_config_address(&mint_a, &mint_b, POOL_FEE_TIER);
+    let authority_ata_a = spl_associated_token_account::get_associated_token_address(
+        &authority.pubkey(),
+        &mint_a,
+    );
+    let authority_ata_b = spl_associated_token_account::get_associated_token_address(
+        &authority.pubkey(),
+        &mint_b,
+    );
+
+    let (deposit_a, deposit_b) = (100_000_000_000, 200_000_000_000);
+    MintTo::new(&mut svm, &authority, &mint_a, &authority_ata_a, deposit_a)
+        .owner(&authority)
+        .send()
+        .unwrap();
+    MintTo::new(&mut svm, &authority, &mint_b, &authority_ata_b, deposit_b)
+        .owner(&authority)
+        .send()
+        .unwrap();
+    println!("[Setup] Pool at 2 B per A, authority holds 100 A / 200 B");
+
+    // Deposit as a position: LP tokens go to the position LP vault, the NFT to the depositor
+    println!("[Action] Depositing 100 A / 200 B as an NFT position");
+    let asset = Keypair::new();
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+    let deposit_ix = build_deposit_position_ix(
+        &authority.pubkey(),
+        &asset.pubkey(),
+        &mint_a,
+        &mint_b,
+        deposit_a,
+        deposit_b,
+        deposit_a,
+        deposit_b,
+        expiration,
+    );
+    send_tx_expect_success(&mut svm, deposit_ix, &authority, &[&authority, &asset]);
+
+    let (position_pda, _) = amm::position_address(&asset.pubkey());
+    let position =
+        Position::try_from_bytes(&svm.get_account(&position_pda).unwrap().data).unwrap();
+    assert_eq!(position.pool_config, pool_config);
+    assert_eq!(position.asset, asset.pubkey());
+    assert!(position.amount_a <= deposit_a && position.amount_a > deposit_a - 10);
+    assert!(position.amount_b <= deposit_b && position.amount_b > deposit_b - 10);
+    assert_eq!(position.entry_price, position.amount_b * 1_000_000_000 / position.amount_a);
+
+    let lp_vault = amm::position_lp_vault_address(&mint_a, &mint_b, POOL_FEE_TIER, &TOKEN_PROGRAM_ID);
+    let lp_vault_account: spl_token::state::Account = get_spl_account(&svm, &lp_vault).unwrap();
+    assert_eq!(lp_vault_account.amount, position.lp_tokens);
+    assert_eq!(svm.get_account(&asset.pubkey()).unwrap().owner, MPL_CORE_PROGRAM_ID);
+    println!(
+        "[Success] Position minted: {} LP escrowed, entry price {}",
+        position.lp_tokens, position.entry_price
+    );
+
+    // Only the NFT owner can redeem the position
+    println!("[Action] Attacker withdraws the authority's position");
+    let withdraw_ix = build_withdraw_position_ix(
+        &attacker.pubkey(),
+        &asset.pubkey(),
+        &mint_a,
+        &mint_b,
+        0,
+        0,
+        expiration,
+    );
+    let failure = send_tx_expect_failure(&mut svm, withdraw_ix, &attacker, &[&attacker]);
+    assert!(failure.contains("PositionOwnerMismatch"), "{}", failure);
+    println!("[Success] Non-owner rejected");
+
+    // A position from another pool with the same LP amount cannot redeem this pool's escrow
+    println!("[Action] Attacker redeems a worthless pool's position against this pool");
+    let (fake_a, fake_b) = setup_pool_with_reserves(&mut svm, &attacker, liquidity_a, liquidity_b);
+    let attacker_ata_a = spl_associated_token_account::get_associated_token_address(
+        &attacker.pubkey(),
+        &fake_a,
+    );
+    let attacker_ata_b = spl_associated_token_account::get_associated_token_address(
+        &attacker.pubkey(),
+        &fake_b,
+    );
+    MintTo::new(&mut svm, &attacker, &fake_a, &attacker_ata_a, deposit_a)
+        .owner(&attacker)
+        .send()
+        .unwrap();
+    MintTo::new(&mut svm, &attacker, &fake_b, &attacker_ata_b, deposit_b)
+        .owner(&attacker)
+        .send()
+        .unwrap();
+    let fake_asset = Keypair::new();
+    let deposit_ix = build_deposit_position_ix(
+        &attacker.pubkey(),
+        &fake_asset.pubkey(),
+        &fake_a,
+        &fake_b,
+        deposit_a,
+        deposit_b,
+        deposit_a,
+        deposit_b,
+        expiration,
+    );
+    send_tx_expect_success(&mut svm, deposit_ix, &attacker, &[&attacker, &fake_asset]);
+
+    let withdraw_ix = build_withdraw_position_ix(
+        &attacker.pubkey(),
+        &fake_asset.pubkey(),
+        &mint_a,
+        &mint_b,
+        0,
+        0,
+        expiration,
+    );
+    let failure = send_tx_expect_failure(&mut svm, withdraw_ix, &attacker, &[&attacker]);
+    assert!(failure.contains("PositionPoolMismatch"), "{}", failure);
+    println!("[Success] Cross-pool position rejected");
+
+    // The owner redeems: LP burned, tokens returned, NFT burned, position closed
+    println!("[Action] Authority withdraws its position");
+    let balance_a_before = get_spl_account::<spl_token::state::Account>(&svm, &authority_ata_a)
+        .unwrap()
+        .amount;
+    let withdraw_ix = build_withdraw_position_ix(
+        &authority.pubkey(),
+        &asset.pubkey(),
+        &mint_a,
+        &mint_b,
+        position.amount_a - 10,
+        position.amount_b - 10,
+        expiration,
+    );
+    send_tx_expect_success(&mut svm, withdraw_ix, &authority, &[&authority]);
+
+    let balance_a_after = get_spl_account::<spl_token::state::Account>(&svm, &authority_ata_a)
+        .unwrap()
+        .amount;
+    assert!(balance_a_after - balance_a_before >= position.amount_a - 10);
+    let lp_vault_account: spl_token::state::Account = get_spl_account(&svm, &lp_vault).unwrap();
+    assert_eq!(lp_vault_account.amount, 0);
+    assert!(
+        svm.get_account(&position_pda).map_or(true, |a| a.lamports == 0),
+        "Position should be closed"
+    );
+    assert!(
+        svm.get_account(&asset.pubkey()).map_or(true, |a| a.data.len() <= 1),
+        "Position NFT should be burned"
+    );
+    println!("[Success] Position redeemed and its NFT burned");
+}
+
+#[test]
+fn test_position_lp_fee_claim() {
+    println!("\n[TEST START] test_position_lp_fee_claim - A position claims its share of swap fees");
+
+    let mut svm = setup_svm_with_mpl_core();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let (liquidity_a, liquidity_b) = (1_000_000_000_000, 2_000_000_000_000);
+    let (mint_a, mint_b) = setup_pool_with_reserves(&mut svm, &authority, liquidity_a, liquidity_b);
+    let (lp_mint, _) = amm::lp_mint_address(&amm::pool_config_address(&mint_a, &mint_b, POOL_FEE_TIER).0);
+    let vault_a = derive_vault(&mint_a, &mint_b, &mint_a);
+    let vault_b = derive_vault(&mint_a, &mint_b, &mint_b);
+    let authority_ata_a = spl_associated_token_account::get_associated_token_address(
+        &authority.pubkey(),
+        &mint_a,
+    );
+    let authority_ata_b = spl_associated_token_account::get_associated_token_address(
+        &authority.pubkey(),
+        &mint_b,
+    );
+    let balance = |svm: &litesvm::LiteSVM, account| {
+        get_spl_account::<spl_token::state::Account>(svm, account).unwrap().amount
+    };
+    // Pool liquidity backing `lp_tokens`: sqrt(reserve_a * reserve_b) * lp_tokens / lp_supply
+    let liquidity_of = |svm: &litesvm::LiteSVM, lp_tokens: u64| {
+        let supply = get_spl_account::<spl_token::state::Mint>(svm, &lp_mint).unwrap().supply;
+        let k = balance(svm, &vault_a) as f64 * balance(svm, &vault_b) as f64;
+        k.sqrt() * lp_tokens as f64 / supply as f64
+    };
+
+    let (deposit_a, deposit_b) = (100_000_000_000, 200_000_000_000);
+    let (trade_a, trade_b) = (500_000_000_000, 1_000_000_000_000);
+    MintTo::new(&mut svm, &authority, &mint_a, &authority_ata_a, deposit_a + trade_a)
+        .owner(&authority)
+        .send()
+        .unwrap();
+    MintTo::new(&mut svm, &authority, &mint_b, &authority_ata_b, deposit_b + trade_b)
+        .owner(&authority)
+        .send()
+        .unwrap();
+
+    let asset = Keypair::new();
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+    let deposit_ix = build_deposit_position_ix(
+        &authority.pubkey(),
+        &asset.pubkey(),
+        &mint_a,
+        &mint_b,
+        deposit_a,
+        deposit_b,
+        deposit_a,
+        deposit_b,
+        expiration,
+    );
+    send_tx_expect_success(&mut svm, deposit_ix, &authority, &[&authority, &asset]);
+
+    let (position_pda, _) = amm::position_address(&asset.pubkey());
+    let position =
+        Position::try_from_bytes(&svm.get_account(&position_pda).unwrap().data).unwrap();
+    let pool = get_pool_config(&svm, &mint_a, &mint_b);
+    assert!(position.fee_growth_checkpoint >= pool.fee_growth_global);
+    let deposited_liquidity = liquidity_of(&svm, position.lp_tokens);
+    println!("[Setup] Position holds {} LP", position.lp_tokens);
+
+    // Nothing earned yet: the checkpoint is rounded above the current growth
+    println!("[Action] Claiming before any swap");
+    let claim_ix = build_claim_lp_fees_ix(&authority.pubkey(), &asset.pubkey(), &mint_a, &mint_b);
+    let failure = send_tx_expect_failure(&mut svm, claim_ix, &authority, &[&authority]);
+    assert!(failure.contains("NoFeesToClaim"), "{}", failure);
+    println!("[Success] No fees to claim");
+
+    // Round trips pay the 30bp fee both ways and leave the price roughly where it was
+    println!("[Action] Trading back and forth through the pool");
+    for round in 0..5u64 {
+        let swap_ix = build_swap_tokens_ix(
+            &authority.pubkey(),
+            &mint_a,
+            &mint_b,
+            true,
+            trade_a / 5 - round,
+            1,
+            expiration,
+        );
+        send_tx_expect_success(&mut svm, swap_ix, &authority, &[&authority]);
+        let swap_ix = build_swap_tokens_ix(
+            &authority.pubkey(),
+            &mint_a,
+            &mint_b,
+            false,
+            trade_b / 5 - round,
+            1,
+            expiration,
+        );
+        send_tx_expect_success(&mut svm, swap_ix, &authority, &[&authority]);
+    }
+
+    // Only the NFT owner can claim
+    println!("[Action] Attacker claims the authority's fees");
+    let claim_ix = build_claim_lp_fees_ix(&attacker.pubkey(), &asset.pubkey(), &mint_a, &mint_b);
+    let failure = send_tx_expect_failure(&mut svm, claim_ix, &attacker, &[&attacker]);
+    assert!(failure.contains("PositionOwnerMismatch"), "{}", failure);
+    println!("[Success] Non-owner rejected");
+
+    println!("[Action] Authority claims the position's fees");
+    let earned_liquidity = liquidity_of(&svm, position.lp_tokens) - deposited_liquidity;
+    let (before_a, before_b) = (balance(&svm, &authority_ata_a), balance(&svm, &authority_ata_b));
+    let claim_ix = build_claim_lp_fees_ix(&authority.pubkey(), &asset.pubkey(), &mint_a, &mint_b);
+    send_tx_expect_success(&mut svm, claim_ix, &authority, &[&authority]);
+
+    let claimed = Position::try_from_bytes(&svm.get_account(&position_pda).unwrap().data).unwrap();
+    let fee_lp = position.lp_tokens - claimed.lp_tokens;
+    let (claimed_a, claimed_b) = (
+        balance(&svm, &authority_ata_a) - before_a,
+        balance(&svm, &authority_ata_b) - before_b,
+    );
+    assert!(fee_lp > 0 && claimed_a > 0 && claimed_b > 0);
+    assert!(claimed.fee_growth_checkpoint > position.fee_growth_checkpoint);
+    let fee_growth_global = get_pool_config(&svm, &mint_a, &mint_b).fee_growth_global;
+    assert!(claimed.fee_growth_checkpoint - fee_growth_global <= 1);
+
+    // The position keeps its deposited liquidity; the claim never exceeds what it earned
+    let kept_liquidity = liquidity_of(&svm, claimed.lp_tokens);
+    let claimed_liquidity = (claimed_a as f64 * claimed_b as f64).sqrt();
+    assert!(kept_liquidity >= deposited_liquidity * (1.0 - 1e-9));
+    assert!(claimed_liquidity <= earned_liquidity * (1.0 + 1e-9));
+    println!(
+        "[Success] Claimed {} LP worth of fees: {} A, {} B",
+        fee_lp, claimed_a, claimed_b
+    );
+
+    // Claiming again right away has nothing left to pay
+    let claim_ix = build_claim_lp_fees_ix(&authority.pubkey(), &asset.pubkey(), &mint_a, &mint_b);
+    let failure = send_tx_expect_failure(&mut svm, claim_ix, &authority, &[&authority]);
+    assert!(failure.contains("NoFeesToClaim"), "{}", failure);
+    println!("[Success] Second claim rejected, fees already paid");
+}
+
+#[test]
+fn test_swap_circuit_breaker() {
+    println!("\n[TEST START] test_swap_circuit_breaker - Swaps that move the price too far");
+
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let swapper = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    // 1,000 A / 1,000 B
+    let liquidity = 1_000_000_000_000;
+    let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, liquidity);
+    let vault_b = derive_vault(&mint_a, &mint_b, &mint_b);
+    println!("[Setup] Pool at 1:1 with 1,000 of each token");
+
+    // Only the pool authority can configure the breaker
+    println!("[Action] Non-authority sets the circuit breaker");
+    let breaker_ix = build_set_circuit_breaker_ix(&swapper.pubkey(), &mint_a, &mint_b, 500, false);
+    let failure = send_tx_expect_failure(&mut svm, breaker_ix, &swapper, &[&swapper]);
+    assert!(failure.contains("UnauthorizedAccess"), "{}", failure);
+    println!("[Success] Non-authority rejected");
+
+    // Auto-lock needs a limit to trip
+    println!("[Action] Enabling auto-lock with no price impact limit");
+    let breaker_ix = build_set_circuit_breaker_ix(&authority.pubkey(), &mint_a, &mint_b, 0, true);
+    let failure = send_tx_expect_failure(&mut svm, breaker_ix, &authority, &[&authority]);
+    assert!(failure.contains("InvalidCircuitBreakerConfig"), "{}", failure);
+    println!("[Success] Auto-lock without a limit rejected");
+
+    let breaker_ix = build_set_circuit_breaker_ix(&authority.pubkey(), &mint_a, &mint_b, 500, false);
+    send_tx_expect_success(&mut svm, breaker_ix, &authority, &[&authority]);
+    let pool = get_pool_config(&svm, &mint_a, &mint_b);
+    assert_eq!(pool.max_price_impact_bps, 500);
+    assert!(!pool.circuit_breaker_auto_lock);
+    println!("[Setup] Circuit breaker set to 500bp, no auto-lock");
+
+    let swapper_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &swapper, &mint_a)
+        .owner(&swapper.pubkey())
+        .send()
+        .unwrap();
+    MintTo::new(&mut svm, &authority, &mint_a, &swapper_ata_a, 100_000_000_000)
+        .owner(&authority)
+        .send()
+        .unwrap();
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+
+    // 1 A moves the price ~0.2%
+    println!("[Action] Swapping 1 A -> B");
+    let swap_ix = build_swap_tokens_ix(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        1_000_000_000,
+        1,
+        expiration,
+    );
+    send_tx_expect_success(&mut svm, swap_ix, &swapper, &[&swapper]);
+    println!("[Success] Small swap under the limit");
+
+    // 50 A would move the price ~9%
+    println!("[Action] Swapping 50 A -> B");
+    let swap_ix = build_swap_tokens_ix(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        50_000_000_000,
+        1,
+        expiration,
+    );
+    let failure = send_tx_expect_failure(&mut svm, swap_ix, &swapper, &[&swapper]);
+    assert!(failure.contains("PriceImpactTooHigh"), "{}", failure);
+    println!("[Success] Price-moving swap rejected");
+
+    // Exact-output swaps are held to the same limit: 40 B out moves the price ~8%
+    println!("[Action] Swapping A -> exactly 40 B");
+    let swap_ix = build_swap_tokens_exact_out_ix(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        100_000_000_000,
+        40_000_000_000,
+        expiration,
+    );
+    let failure = send_tx_expect_failure(&mut svm, swap_ix, &swapper, &[&swapper]);
+    assert!(failure.contains("PriceImpactTooHigh"), "{}", failure);
+    println!("[Success] Price-moving exact-output swap rejected");
+
+    let breaker_ix = build_set_circuit_breaker_ix(&authority.pubkey(), &mint_a, &mint_b, 500, true);
+    send_tx_expect_success(&mut svm, breaker_ix, &authority, &[&authority]);
+    println!("[Setup] Auto-lock enabled");
+
+    // A swap the swapper cannot pay for must not freeze the pool
+    println!("[Action] Quoting a 500 A swap while holding 99 A");
+    let swap_ix = build_swap_tokens_ix(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        500_000_000_000,
+        1,
+        expiration,
+    );
+    let failure = send_tx_expect_failure(&mut svm, swap_ix, &swapper, &[&swapper]);
+    assert!(failure.contains("PriceImpactTooHigh"), "{}", failure);
+    assert!(!get_pool_config(&svm, &mint_a, &mint_b).locked);
+    println!("[Success] Unfunded swap rejected, pool still open");
+
+    // A funded swap over the limit locks the pool and moves no tokens
+    let swapper_a_before: spl_token::state::Account = get_spl_account(&svm, &swapper_ata_a).unwrap();
+    let vault_b_before: spl_token::state::Account = get_spl_account(&svm, &vault_b).unwrap();
+    println!("[Action] Swapping 60 A -> B with auto-lock");
+    let swap_ix = build_swap_tokens_ix(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        60_000_000_000,
+        1,
+        expiration,
+    );
+    let meta = send_tx(&mut svm, &[swap_ix], &swapper, &[&swapper]).expect("Tripping swap should succeed");
+    assert!(meta.logs.iter().any(|log| log.contains("Circuit breaker tripped")));
+    assert!(get_pool_config(&svm, &mint_a, &mint_b).locked);
+
+    let swapper_a_after: spl_token::state::Account = get_spl_account(&svm, &swapper_ata_a).unwrap();
+    let vault_b_after: spl_token::state::Account = get_spl_account(&svm, &vault_b).unwrap();
+    assert_eq!(swapper_a_after.amount, swapper_a_before.amount);
+    assert_eq!(vault_b_after.amount, vault_b_before.amount);
+    println!("[Success] Pool locked, no tokens moved");
+
+    println!("[Action] Swapping 2 A -> B on the locked pool");
+    let swap_ix = build_swap_tokens_ix(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        2_000_000_000,
+        1,
+        expiration,
+    );
+    let failure = send_tx_expect_failure(&mut svm, swap_ix, &swapper, &[&swapper]);
+    assert!(failure.contains("PoolLocked"), "{}", failure);
+    println!("[Success] Locked pool rejects swaps");
+
+    // The authority reviews and reopens the pool
+    let unlock_ix = build_unlock_pool_ix(&authority.pubkey(), &mint_a, &mint_b);
+    send_tx_expect_success(&mut svm, unlock_ix, &authority, &[&authority]);
+    let swap_ix = build_swap_tokens_ix(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        3_000_000_000,
+        1,
+        expiration,
+    );
+    send_tx_expect_success(&mut svm, swap_ix, &swapper, &[&swapper]);
+    println!("[Success] Swaps resume once the authority unlocks the pool");
+
+    println!("[TEST END] test_swap_circuit_breaker");
+}
+
+#[test]
+fn test_swap_per_slot_limit() {
+    println!("\n[TEST START] test_swap_per_slot_limit - Swap input capped per slot");
+
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let swapper = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    // 1,000 A / 1,000 B
+    let liquidity = 1_000_000_000_000;
+    let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, liquidity);
+    println!("[Setup] Pool at 1:1 with 1,000 of each token");
+
+    // Only the pool authority can configure the limit, and only up to 100%
+    println!("[Action] Non-authority sets the swap limit");
+    let limit_ix = build_set_swap_limit_ix(&swapper.pubkey(), &mint_a, &mint_b, 1_000);
+    let failure = send_tx_expect_failure(&mut svm, limit_ix, &swapper, &[&swapper]);
+    assert!(failure.contains("UnauthorizedAccess"), "{}", failure);
+
+    let limit_ix = build_set_swap_limit_ix(&authority.pubkey(), &mint_a, &mint_b, 10_001);
+    let failure = send_tx_expect_failure(&mut svm, limit_ix, &authority, &[&authority]);
+    assert!(failure.contains("InvalidSwapLimitConfig"), "{}", failure);
+    println!("[Success] Non-authority and over-100% limits rejected");
+
+    // 10% of each vault per slot: 100 A in and 100 B in
+    let limit_ix = build_set_swap_limit_ix(&authority.pubkey(), &mint_a, &mint_b, 1_000);
+    send_tx_expect_success(&mut svm, limit_ix, &authority, &[&authority]);
+    assert_eq!(get_pool_config(&svm, &mint_a, &mint_b).max_swap_per_slot_bps, 1_000);
+    println!("[Setup] Swap limit set to 1000bp of reserves per slot");
+
+    for mint in [mint_a, mint_b] {
+        let swapper_ata = CreateAssociatedTokenAccount::new(&mut svm, &swapper, &mint)
+            .owner(&swapper.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &authority, &mint, &swapper_ata, 500_000_000_000)
+            .owner(&authority)
+            .send()
+            .unwrap();
+    }
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+    let swap_ix = |swap_token_a_for_b: bool, input_amount: u64| {
+        build_swap_tokens_ix(
+            &swapper.pubkey(),
+            &mint_a,
+            &mint_b,
+            swap_token_a_for_b,
+            input_amount,
+            1,
+            expiration,
+        )
+    };
+
+    // Front-run leg: 60 A in
+    println!("[Action] Swapping 60 A -> B");
+    send_tx_expect_success(&mut svm, swap_ix(true, 60_000_000_000), &swapper, &[&swapper]);
+    println!("[Success] First swap of the slot under the limit");
+
+    // 50 more A in the same slot would put 110 A into the vault
+    println!("[Action] Swapping another 50 A -> B in the same slot");
+    let failure =
+        send_tx_expect_failure(&mut svm, swap_ix(true, 50_000_000_000), &swapper, &[&swapper]);
+    assert!(failure.contains("SlotSwapLimitExceeded"), "{}", failure);
+
+    // Exact-output swaps count their input the same way: 40 B out needs ~49 A
+    let swap_exact_out_ix = build_swap_tokens_exact_out_ix(
+        &swapper.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        100_000_000_000,
+        40_000_000_000,
+        expiration,
+    );
+    let failure = send_tx_expect_failure(&mut svm, swap_exact_out_ix, &swapper, &[&swapper]);
+    assert!(failure.contains("SlotSwapLimitExceeded"), "{}", failure);
+    println!("[Success] Exact-in and exact-out swaps over the slot's A budget rejected");
+
+    // The B vault has its own budget, measured on its balance at the start of the slot
+    println!("[Action] Swapping 90 B -> A in the same slot");
+    send_tx_expect_success(&mut svm, swap_ix(false, 90_000_000_000), &swapper, &[&swapper]);
+    let failure =
+        send_tx_expect_failure(&mut svm, swap_ix(false, 20_000_000_000), &swapper, &[&swapper]);
+    assert!(failure.contains("SlotSwapLimitExceeded"), "{}", failure);
+    println!("[Success] B budget counted separately, and also capped");
+
+    let slot = svm.get_sysvar::<Clock>().slot;
+    let pool = get_pool_config(&svm, &mint_a, &mint_b);
+    let volume = pool.swap_volume_at(slot).expect("Current slot should be recorded");
+    assert_eq!(volume.reserve_a, liquidity);
+    assert_eq!(volume.reserve_b, liquidity);
+    assert_eq!(volume.volume_a, 60_000_000_000);
+    assert_eq!(volume.volume_b, 90_000_000_000);
+    println!("[Success] Slot volume recorded: {} A, {} B", volume.volume_a, volume.volume_b);
+
+    // The next slot starts a fresh budget
+    println!("[Action] Swapping 50 A -> B in the next slot");
+    svm.warp_to_slot(slot + 1);
+    svm.expire_blockhash();
+    send_tx_expect_success(&mut svm, swap_ix(true, 50_000_000_000), &swapper, &[&swapper]);
+    let pool = get_pool_config(&svm, &mint_a, &mint_b);
+    assert_eq!(pool.swap_volume_at(slot + 1).unwrap().volume_a, 50_000_000_000);
+    assert_eq!(pool.swap_volume_at(slot).unwrap().volume_b, 90_000_000_000);
+    println!("[Success] New slot, new budget; the previous slot stays in the ring");
+
+    // Disabling the limit lifts the cap
+    let limit_ix = build_set_swap_limit_ix(&authority.pubkey(), &mint_a, &mint_b, 0);
+    send_tx_expect_success(&mut svm, limit_ix, &authority, &[&authority]);
+    send_tx_expect_success(&mut svm, swap_ix(true, 150_000_000_000), &swapper, &[&swapper]);
+    println!("[Success] Limit disabled, large swap goes through");
+
+    println!("[TEST END] test_swap_per_slot_limit");
+}
+
+#[test]
+fn test_native_sol_deposit_and_swap() {
+    println!("\n[TEST START] test_native_sol_deposit_and_swap - Deposits and swaps paid in native SOL");
+
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 100 * LAMPORTS_PER_SOL);
+    let swapper = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    // wSOL / B pool at 1:1
+    let mint_b = CreateMint::new(&mut svm, &authority)
+        .authority(&authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .unwrap();
+    let init_ix = build_initialize_pool_ix(&authority.pubkey(), &NATIVE_MINT, &mint_b, 30);
+    send_tx_expect_success(&mut svm, init_ix, &authority, &[&authority]);
+
+    let authority_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &authority, &mint_b)
+        .owner(&authority.pubkey())
+        .send()
+        .unwrap();
+    MintTo::new(&mut svm, &authority, &mint_b, &authority_ata_b, 100_000_000_000)
+        .owner(&authority)
+        .send()
+        .unwrap();
+    let authority_wsol = spl_associated_token_account::get_associated_token_address(
+        &authority.pubkey(),
+        &NATIVE_MINT,
+    );
+    let swapper_wsol = spl_associated_token_account::get_associated_token_address(
+        &swapper.pubkey(),
+        &NATIVE_MINT,
+    );
+    let vault_a = derive_vault(&NATIVE_MINT, &mint_b, &NATIVE_MINT);
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+    println!("[Setup] wSOL / B pool created, authority holds 100 B and no wSOL account");
+
+    // Deposit 50 SOL + 50 B straight from the wallet
+    let deposit_amount = 50 * LAMPORTS_PER_SOL;
+    let lamports_before = svm.get_balance(&authority.pubkey()).unwrap();
+    println!("[Action] Depositing 50 SOL + 50 B with deposit_liquidity_sol");
+    let deposit_ix = build_deposit_liquidity_sol_ix(
+        &authority.pubkey(),
+        &NATIVE_MINT,
+        &mint_b,
+        deposit_amount,
+        50_000_000_000,
+        deposit_amount,
+        50_000_000_000,
+        expiration,
+    );
+    send_tx_expect_success(&mut svm, deposit_ix, &authority, &[&authority]);
+
+    let vault: spl_token::state::Account = get_spl_account(&svm, &vault_a).unwrap();
+    assert_eq!(vault.amount, deposit_amount);
+    assert!(svm.get_account(&authority_wsol).map_or(true, |account| account.lamports == 0));
+
+    // Only the deposit, the new LP ATA and the fee left the wallet; the wSOL ATA rent came back
+    let (pool_config, _) = amm::pool_config_address(&NATIVE_MINT, &mint_b, POOL_FEE_TIER);
+    let (lp_mint, _) = amm::lp_mint_address(&pool_config);
+    let lp_ata = spl_associated_token_account::get_associated_token_address(
+        &authority.pubkey(),
+        &lp_mint,
+    );
+    let lp_rent = svm.get_account(&lp_ata).unwrap().lamports;
+    let spent = lamports_before - svm.get_balance(&authority.pubkey()).unwrap();
+    assert!(spent - deposit_amount - lp_rent < 100_000, "spent {} lamports", spent);
+    println!("[Success] Vault holds 50 wSOL, temporary wSOL account closed and refunded");
+
+    // Swap 1 SOL -> B
+    let swap_amount = LAMPORTS_PER_SOL;
+    let lamports_before = svm.get_balance(&swapper.pubkey()).unwrap();
+    println!("[Action] Swapping 1 SOL -> B with swap_sol_for_token");
+    let swap_ix = build_swap_sol_for_token_ix(
+        &swapper.pubkey(),
+        &NATIVE_MINT,
+        &mint_b,
+        swap_amount,
+        1,
+        expiration,
+    );
+    send_tx_expect_success(&mut svm, swap_ix, &swapper, &[&swapper]);
+
+    let swapper_ata_b = spl_associated_token_account::get_associated_token_address(
+        &swapper.pubkey(),
+        &mint_b,
+    );
+    let swapper_b: spl_token::state::Account = get_spl_account(&svm, &swapper_ata_b).unwrap();
+    assert!(swapper_b.amount > 0);
+    assert!(svm.get_account(&swapper_wsol).map_or(true, |account| account.lamports == 0));
+    let ata_b_rent = svm.get_account(&swapper_ata_b).unwrap().lamports;
+    let spent = lamports_before - svm.get_balance(&swapper.pubkey()).unwrap();
+    assert!(spent - swap_amount - ata_b_rent < 100_000, "spent {} lamports", spent);
+    println!("[Success] Received {} B, temporary wSOL account closed and refunded", swapper_b.amount);
+
+    // A wSOL balance already in the ATA is not the instruction's to unwrap
+    println!("[Action] Swapping B -> wSOL, then SOL -> B with wSOL still in the ATA");
+    let swap_ix = build_swap_tokens_ix(
+        &swapper.pubkey(),
+        &NATIVE_MINT,
+        &mint_b,
+        false,
+        swapper_b.amount / 2,
+        1,
+        expiration,
+    );
+    send_tx_expect_success(&mut svm, swap_ix, &swapper, &[&swapper]);
+    let swap_ix = build_swap_sol_for_token_ix(
+        &swapper.pubkey(),
+        &NATIVE_MINT,
+        &mint_b,
+        swap_amount,
+        1,
+        expiration,
+    );
+    let failure = send_tx_expect_failure(&mut svm, swap_ix, &swapper, &[&swapper]);
+    assert!(failure.contains("WrappedSolAccountNotEmpty"), "{}", failure);
+    println!("[Success] Non-empty wSOL account rejected");
+
+    // Pools without a wSOL side have nothing to wrap into
+    println!("[Action] swap_sol_for_token on a pool with no wSOL side");
+    let (mint_c, mint_d) = setup_pool_with_liquidity(&mut svm, &authority, 1_000_000_000_000);
+    let swap_ix = build_swap_sol_for_token_ix(
+        &swapper.pubkey(),
+        &mint_c,
+        &mint_d,
+        swap_amount,
+        1,
+        expiration,
+    );
+    let failure = send_tx_expect_failure(&mut svm, swap_ix, &swapper, &[&swapper]);
+    assert!(failure.contains("NotNativeSolPool"), "{}", failure);
+    println!("[Success] Token-only pool rejected");
+
+    println!("[TEST END] test_native_sol_deposit_and_swap");
+}
+
+#[test]
+fn test_limit_order_lifecycle() {
+    println!("\n[TEST START] test_limit_order_lifecycle - Keeper-filled limit orders");
+
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let owner = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let keeper = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let trader = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    // 1,000 A / 1,000 B
+    let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, 1_000_000_000_000);
+    let owner_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &owner, &mint_a)
+        .owner(&owner.pubkey())
+        .send()
+        .unwrap();
+    MintTo::new(&mut svm, &authority, &mint_a, &owner_ata_a, 200_000_000_000)
+        .owner(&authority)
+        .send()
+        .unwrap();
+    let owner_ata_b = spl_associated_token_account::get_associated_token_address(
+        &owner.pubkey(),
+        &mint_b,
+    );
+    println!("[Setup] Pool at 1:1 with 1,000 of each token, owner holds 200 A");
+
+    // Sell 100 A for at least 95 B; the pool currently pays ~90.7 B
+    let keeper_tip = 10_000_000;
+    let expires_at = svm.get_sysvar::<Clock>().unix_timestamp + 3_600;
+    println!("[Action] Placing order 1: 100 A for at least 95 B, 0.01 SOL tip");
+    let place_ix = build_place_limit_order_ix(
+        &owner.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        1,
+        100_000_000_000,
+        95_000_000_000,
+        keeper_tip,
+        expires_at,
+    );
+    send_tx_expect_success(&mut svm, place_ix, &owner, &[&owner]);
+
+    let order_pda = derive_limit_order(&mint_a, &mint_b, &owner.pubkey(), 1);
+    let order_account = svm.get_account(&order_pda).unwrap();
+    let order = LimitOrder::try_from_bytes(&order_account.data).unwrap();
+    assert_eq!(order.input_mint, mint_a);
+    assert_eq!(order.input_amount, 100_000_000_000);
+    assert_eq!(order.min_output_amount, 95_000_000_000);
+    let escrow = amm::order_escrow_address(&order_pda, &mint_a, &TOKEN_PROGRAM_ID);
+    let escrow_account: spl_token::state::Account = get_spl_account(&svm, &escrow).unwrap();
+    assert_eq!(escrow_account.amount, 100_000_000_000);
+    assert!(svm.get_account(&owner_ata_b).is_some());
+    println!("[Success] 100 A escrowed, tip held on the order, owner's B account created");
+
+    // The pool price is below the limit
+    println!("[Action] Keeper fills before the price reaches the limit");
+    let fill_ix = build_fill_limit_order_ix(&keeper.pubkey(), &owner.pubkey(), &mint_a, &mint_b, true, 1);
+    let failure = send_tx_expect_failure(&mut svm, fill_ix, &keeper, &[&keeper]);
+    assert!(failure.contains("LimitPriceNotReached"), "{}", failure);
+    println!("[Success] Fill rejected, order stays open");
+
+    // A trader buys 200 B worth of A, raising the A price past the limit
+    let trader_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &trader, &mint_b)
+        .owner(&trader.pubkey())
+        .send()
+        .unwrap();
+    MintTo::new(&mut svm, &authority, &mint_b, &trader_ata_b, 200_000_000_000)
+        .owner(&authority)
+        .send()
+        .unwrap();
+    let swap_ix = build_swap_tokens_ix(
+        &trader.pubkey(),
+        &mint_a,
+        &mint_b,
+        false,
+        200_000_000_000,
+        1,
+        expires_at,
+    );
+    send_tx_expect_success(&mut svm, swap_ix, &trader, &[&trader]);
+    println!("[Setup] Trader swapped 200 B -> A, A now trades above 1.2 B");
+
+    let keeper_before = svm.get_balance(&keeper.pubkey()).unwrap();
+    println!("[Action] Keeper fills order 1");
+    let fill_ix = build_fill_limit_order_ix(&keeper.pubkey(), &owner.pubkey(), &mint_a, &mint_b, true, 1);
+    send_tx_expect_success(&mut svm, fill_ix, &keeper, &[&keeper]);
+
+    let owner_b: spl_token::state::Account = get_spl_account(&svm, &owner_ata_b).unwrap();
+    assert!(owner_b.amount >= 95_000_000_000, "owner received {} B", owner_b.amount);
+    assert!(svm.get_account(&order_pda).map_or(true, |a| a.lamports == 0));
+    assert!(svm.get_account(&escrow).map_or(true, |a| a.lamports == 0));
+    let keeper_after = svm.get_balance(&keeper.pubkey()).unwrap();
+    assert!(keeper_after > keeper_before, "keeper was not paid the tip");
+    println!("[Success] Owner received {} B, keeper paid, order and escrow closed", owner_b.amount);
+
+    // A second order that nobody fills in time
+    let expires_at = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+    let place_ix = build_place_limit_order_ix(
+        &owner.pubkey(),
+        &mint_a,
+        &mint_b,
+        true,
+        2,
+        100_000_000_000,
+        1_000_000_000_000,
+        keeper_tip,
+        expires_at,
+    );
+    send_tx_expect_success(&mut svm, place_ix, &owner, &[&owner]);
+    advance_time(&mut svm, 120);
+    println!("[Setup] Order 2 placed and left to expire");
+
+    println!("[Action] Keeper fills the expired order");
+    let fill_ix = build_fill_limit_order_ix(&keeper.pubkey(), &owner.pubkey(), &mint_a, &mint_b, true, 2);
+    let failure = send_tx_expect_failure(&mut svm, fill_ix, &keeper, &[&keeper]);
+    assert!(failure.contains("LimitOrderExpired"), "{}", failure);
+    println!("[Success] Expired order cannot be filled");
+
+    println!("[Action] Owner cancels order 2");
+    let owner_a_before: spl_token::state::Account = get_spl_account(&svm, &owner_ata_a).unwrap();
+    let cancel_ix = build_cancel_limit_order_ix(&owner.pubkey(), &mint_a, &mint_b, true, 2);
+    send_tx_expect_success(&mut svm, cancel_ix, &owner, &[&owner]);
+
+    let owner_a_after: spl_token::state::Account = get_spl_account(&svm, &owner_ata_a).unwrap();
+    assert_eq!(owner_a_after.amount, owner_a_before.amount + 100_000_000_000);
+    let order_pda = derive_limit_order(&mint_a, &mint_b, &owner.pubkey(), 2);
+    assert!(svm.get_account(&order_pda).map_or(true, |a| a.lamports == 0));
+    println!("[Success] 100 A refunded and the order closed");
+
+    println!("[TEST END] test_limit_order_lifecycle");
+}
+
+#[test]
+fn test_migrate_liquidity_between_fee_tiers() {
+    println!("\n[TEST START] test_migrate_liquidity_between_fee_tiers - LP moves to another fee tier");
+
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    // 30bp pool at 1,000 A / 1,000 B, the authority holds all of its LP
+    let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, 1_000_000_000_000);
+    let authority_ata_a =
+        spl_associated_token_account::get_associated_token_address(&authority.pubkey(), &mint_a);
+    let authority_ata_b =
+        spl_associated_token_account::get_associated_token_address(&authority.pubkey(), &mint_b);
+
+    // Same pair at the 1% tier: its own pool, 100 A / 200 B
+    let init_ix = build_initialize_pool_ix(&authority.pubkey(), &mint_a, &mint_b, 100);
+    send_tx_expect_success(&mut svm, init_ix, &authority, &[&authority]);
+    MintTo::new(&mut svm, &authority, &mint_a, &authority_ata_a, 100_000_000_000)
+        .owner(&authority)
+        .send()
+        .unwrap();
+    MintTo::new(&mut svm, &authority, &mint_b, &authority_ata_b, 200_000_000_000)
+        .owner(&authority)
+        .send()
+        .unwrap();
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+    let deposit_ix = amm_ix::deposit_liquidity(
+        &authority.pubkey(),
+        &mint_a,
+        &mint_b,
+        100,
+        100_000_000_000,
+        200_000_000_000,
+        100_000_000_000,
+        200_000_000_000,
+        expiration,
+        &TOKEN_PROGRAM_ID,
+    );
+    send_tx_expect_success(&mut svm, deposit_ix, &authority, &[&authority]);
+    println!("[Setup] 30bp A/B pool at 1:1, 1% A/B pool at 2 B per A");
+
+    let (source_pool, _) = amm::pool_config_address(&mint_a, &mint_b, POOL_FEE_TIER);
+    let (dest_pool, _) = amm::pool_config_address(&mint_a, &mint_b, 100);
+    assert_ne!(source_pool, dest_pool, "Each fee tier of a pair is its own pool");
+    let source_lp_ata = spl_associated_token_account::get_associated_token_address(
+        &authority.pubkey(),
+        &amm::lp_mint_address(&source_pool).0,
+    );
+    let dest_lp_ata = spl_associated_token_account::get_associated_token_address(
+        &authority.pubkey(),
+        &amm::lp_mint_address(&dest_pool).0,
+    );
+    let source_lp_before: spl_token::state::Account = get_spl_account(&svm, &source_lp_ata).unwrap();
+    let dest_lp_before: spl_token::state::Account = get_spl_account(&svm, &dest_lp_ata).unwrap();
+    let a_before: spl_token::state::Account = get_spl_account(&svm, &authority_ata_a).unwrap();
+
+    // 100 LP is ~100 A + 100 B; the 2:1 pool only takes 50 A with the 100 B (~70.7 LP)
+    println!("[Action] Migrating 100 LP with min_lp_out = 100 LP");
+    let migrate_ix = build_migrate_liquidity_ix(
+        &authority.pubkey(),
+        &mint_a,
+        &mint_b,
+        &mint_a,
+        &mint_b,
+        100,
+        100_000_000_000,
+        100_000_000_000,
+        expiration,
+    );
+    let failure = send_tx_expect_failure(&mut svm, migrate_ix, &authority, &[&authority]);
+    assert!(failure.contains("SlippageExceeded"), "{}", failure);
+    println!("[Success] Migration rejected below min_lp_out");
+
+    println!("[Action] Migrating 100 LP with min_lp_out = 70 LP");
+    let migrate_ix = build_migrate_liquidity_ix(
+        &authority.pubkey(),
+        &mint_a,
+        &mint_b,
+        &mint_a,
+        &mint_b,
+        100,
+        100_000_000_000,
+        70_000_000_000,
+        expiration,
+    );
+    send_tx_expect_success(&mut svm, migrate_ix, &authority, &[&authority]);
+
+    let source_lp_after: spl_token::state::Account = get_spl_account(&svm, &source_lp_ata).unwrap();
+    let dest_lp_after: spl_token::state::Account = get_spl_account(&svm, &dest_lp_ata).unwrap();
+    let a_after: spl_token::state::Account = get_spl_account(&svm, &authority_ata_a).unwrap();
+    let dest_vault_b: spl_token::state::Account = get_spl_account(
+        &svm,
+        &amm::vault_address(&mint_a, &mint_b, 100, &mint_b, &TOKEN_PROGRAM_ID),
+    )
+    .unwrap();
+    let dest_vault_a: spl_token::state::Account = get_spl_account(
+        &svm,
+        &amm::vault_address(&mint_a, &mint_b, 100, &mint_a, &TOKEN_PROGRAM_ID),
+    )
+    .unwrap();
+
+    assert_eq!(source_lp_before.amount - source_lp_after.amount, 100_000_000_000);
+    let lp_minted = dest_lp_after.amount - dest_lp_before.amount;
+    assert!(lp_minted >= 70_000_000_000, "minted {} LP", lp_minted);
+    assert!(dest_vault_b.amount >= 299_999_000_000, "B vault holds {}", dest_vault_b.amount);
+    assert!(dest_vault_a.amount >= 149_999_000_000, "A vault holds {}", dest_vault_a.amount);
+    let refund_a = a_after.amount - a_before.amount;
+    assert!(refund_a >= 49_999_000_000, "refunded {} A", refund_a);
+    println!(
+        "[Success] Burned 100 LP, minted {} LP in the 1% pool, {} A refunded",
+        lp_minted, refund_a
+    );
+
+    // A pool of a different pair, with a B account created for its authority
+    let mint_c = CreateMint::new(&mut svm, &authority)
+        .authority(&authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .unwrap();
+    let init_ix = build_initialize_pool_ix(&authority.pubkey(), &mint_a, &mint_c, 30);
+    send_tx_expect_success(&mut svm, init_ix, &authority, &[&authority]);
+    let (other_pool, _) = amm::pool_config_address(&mint_a, &mint_c, POOL_FEE_TIER);
+    let (other_authority, _) = amm::pool_authority_address(&other_pool);
+    CreateAssociatedTokenAccount::new(&mut svm, &authority, &mint_b)
+        .owner(&other_authority)
+        .send()
+        .unwrap();
+    println!("[Setup] A/C pool created, its authority given a B account");
+
+    println!("[Action] Migrating A/B LP into the A/C pool");
+    let migrate_ix = build_migrate_liquidity_ix(
+        &authority.pubkey(),
+        &mint_a,
+        &mint_b,
+        &mint_a,
+        &mint_c,
+        POOL_FEE_TIER,
+        100_000_000_000,
+        1,
+        expiration,
+    );
+    let failure = send_tx_expect_failure(&mut svm, migrate_ix, &authority, &[&authority]);
+    assert!(failure.contains("InvalidMigrationPool"), "{}", failure);
+    println!("[Success] Destination of another pair rejected");
+
+    println!("[TEST END] test_migrate_liquidity_between_fee_tiers");
+}
+
+// Swap against a pool its authority has locked (V007 in amm-vulnerable)
+struct SwapWhileLocked;
+
+struct LockedPool {
+    swapper: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+}
+
+impl DifferentialScenario for SwapWhileLocked {
+    type State = LockedPool;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V007",
+            title: "No Pool Lock Enforcement",
+            severity: Severity::High,
+            lesson: "Check the pool lock before any swap moves tokens",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, _program_id: &Pubkey) -> LockedPool {
+        let authority = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let swapper = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let (mint_a, mint_b) = setup_pool_with_liquidity(svm, &authority, 100_000_000_000);
+
+        let swapper_ata_a = CreateAssociatedTokenAccount::new(svm, &swapper, &mint_a)
+            .owner(&swapper.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(svm, &authority, &mint_a, &swapper_ata_a, 1_000_000_000)
+            .owner(&authority)
+            .send()
+            .unwrap();
+
+        let lock_ix = build_lock_pool_ix(&authority.pubkey(), &mint_a, &mint_b);
+        send_tx(svm, &[lock_ix], &authority, &[&authority]).expect("Lock should succeed");
+
+        LockedPool { swapper, mint_a, mint_b }
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        state: &mut LockedPool,
+    ) -> TransactionResult {
+        let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+        let swap_ix = build_swap_tokens_ix(
+            &state.swapper.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            true,
+            1_000_000_000,
+            1,
+            expiration,
+        );
+        send_tx(svm, &[swap_ix], &state.swapper, &[&state.swapper])
+    }
+}
+
+// Feature matrix: only the vuln-missing-lock-check build swaps on a locked pool
+// Requires ./build-vuln-matrix.sh amm-secure from the repository root
+#[test]
+fn test_feature_missing_lock_check() {
+    let variant = FeatureVariant {
+        program_id: AMM_PROGRAM_ID,
+        feature: "vuln-missing-lock-check",
+    };
+    let (mut baseline, mut variant_svm) = variant.load(
+        "target/deploy/amm_secure.so",
+        variant.so_path(".", "amm_secure"),
+    );
+    run_feature_differential(&mut baseline, &mut variant_svm, &variant, &SwapWhileLocked);
+}
@@ -0,0 +1,138 @@
+// Unit tests for the pool invariant helper (helpers/invariant.rs)
+// assert_k_not_decreased is called directly, without a LiteSVM pool, so reserves
+// and LP supplies can sit right at u64::MAX. There the cross-multiplied products
+// need all 256 bits, and a one-unit rounding step in either direction must be
+// told apart exactly.
+
+use amm_secure::{errors::AmmError, helpers::*};
+use anchor_lang::error::Error;
+
+const MAX: u64 = u64::MAX;
+
+// Anchor error code of a failed check
+fn error_code(result: anchor_lang::Result<()>) -> u32 {
+    match result.expect_err("check should fail") {
+        Error::AnchorError(error) => error.error_code_number,
+        Error::ProgramError(error) => panic!("expected an AmmError, got {:?}", error),
+    }
+}
+
+fn assert_violated(result: anchor_lang::Result<()>) {
+    assert_eq!(error_code(result), u32::from(AmmError::InvariantViolated));
+}
+
+#[test]
+fn test_swap_k_at_u64_max() {
+    // Unchanged reserves at the top of the range pass
+    let pre = PoolSnapshot::reserves(MAX, MAX);
+    assert!(assert_k_not_decreased(pre, pre, (0, 0)).is_ok());
+
+    // One unit less on either side is caught, although k only moves by ~2^-64
+    assert_violated(assert_k_not_decreased(pre, PoolSnapshot::reserves(MAX - 1, MAX), (0, 0)));
+    assert_violated(assert_k_not_decreased(pre, PoolSnapshot::reserves(MAX, MAX - 1), (0, 0)));
+}
+
+#[test]
+fn test_swap_exact_k_rebalance() {
+    // u64::MAX = 3 * 5 * 17 * 257 * 641 * 65537 * 6700417, so k can be kept exactly
+    let pre = PoolSnapshot::reserves(MAX, 15);
+    assert!(assert_k_not_decreased(pre, PoolSnapshot::reserves(MAX / 3, 45), (0, 0)).is_ok());
+    assert!(assert_k_not_decreased(pre, PoolSnapshot::reserves(MAX / 5, 75), (0, 0)).is_ok());
+
+    // Output rounded up by a single unit lowers k
+    assert_violated(assert_k_not_decreased(pre, PoolSnapshot::reserves(MAX / 5, 74), (0, 0)));
+}
+
+#[test]
+fn test_referral_fee_added_back() {
+    // A swap in A that paid a 7 unit referral out of the A vault
+    let pre = PoolSnapshot::reserves(MAX - 1_000, MAX - 1_000);
+    let post = PoolSnapshot::reserves(MAX - 1_007, MAX - 1_000);
+
+    assert_violated(assert_k_not_decreased(pre, post, (0, 0)));
+    assert!(assert_k_not_decreased(pre, post, (7, 0)).is_ok());
+
+    // Counting the fee on the wrong side does not help
+    assert_violated(assert_k_not_decreased(pre, post, (0, 7)));
+}
+
+#[test]
+fn test_fee_overflow_rejected() {
+    // A fee that could not have come out of the vault overflows instead of wrapping
+    let pre = PoolSnapshot::reserves(MAX - 1, MAX - 1);
+    let result = assert_k_not_decreased(pre, PoolSnapshot::reserves(MAX, MAX), (1, 0));
+    assert_eq!(error_code(result), u32::from(AmmError::Overflow));
+}
+
+#[test]
+fn test_withdrawal_rounding_near_u64_max() {
+    // Burning 1 LP of MAX pays out exactly 1 of each token
+    let pre = PoolSnapshot::new(MAX, MAX, MAX);
+    let (amount_a, amount_b) = calculate_withdrawal(1, MAX, MAX, MAX).unwrap();
+    assert_eq!((amount_a, amount_b), (1, 1));
+    let post = PoolSnapshot::new(MAX - amount_a, MAX - amount_b, MAX - 1);
+    assert!(assert_k_not_decreased(pre, post, (0, 0)).is_ok());
+
+    // Paying one extra unit is caught: both sides are ~2^256 here
+    let overpaid = PoolSnapshot::new(MAX - 2, MAX - 1, MAX - 1);
+    assert_violated(assert_k_not_decreased(pre, overpaid, (0, 0)));
+}
+
+#[test]
+fn test_withdrawal_rounds_in_pool_favor() {
+    // Half of an odd reserve: the floor keeps the extra unit in the pool
+    let pre = PoolSnapshot::new(MAX, 3, 2);
+    let (amount_a, amount_b) = calculate_withdrawal(1, MAX, 3, 2).unwrap();
+    assert_eq!((amount_a, amount_b), (MAX / 2, 1));
+    let post = PoolSnapshot::new(MAX - amount_a, 3 - amount_b, 1);
+    assert!(assert_k_not_decreased(pre, post, (0, 0)).is_ok());
+
+    // Rounding the payout up instead lowers k per LP token
+    let rounded_up = PoolSnapshot::new(MAX - (MAX / 2 + 1), 1, 1);
+    assert_violated(assert_k_not_decreased(pre, rounded_up, (0, 0)));
+}
+
+#[test]
+fn test_deposit_rounds_in_pool_favor() {
+    // 1 LP of 3 is worth 10/3 of each token: the depositor pays 4, not 3
+    let pre = PoolSnapshot::new(10, 10, 3);
+    let (amount_a, amount_b, lp_tokens) = calculate_subsequent_deposit(5, 5, 10, 10, 3).unwrap();
+    assert_eq!((amount_a, amount_b, lp_tokens), (4, 4, 1));
+    let post = PoolSnapshot::new(10 + amount_a, 10 + amount_b, 3 + lp_tokens);
+    assert!(assert_k_not_decreased(pre, post, (0, 0)).is_ok());
+
+    // Paying the rounded-down 3 would dilute the existing LPs
+    let rounded_down = PoolSnapshot::new(13, 13, 4);
+    assert_violated(assert_k_not_decreased(pre, rounded_down, (0, 0)));
+}
+
+#[test]
+fn test_deposit_near_u64_max() {
+    // A deposit that takes the reserves and supply to u64::MAX
+    let (amount_a, amount_b, lp_tokens) =
+        calculate_subsequent_deposit(MAX / 3, MAX / 3, MAX / 3 * 2, MAX / 3 * 2, MAX / 3 * 2)
+            .unwrap();
+    assert_eq!((amount_a, amount_b, lp_tokens), (MAX / 3, MAX / 3, MAX / 3));
+
+    let pre = PoolSnapshot::new(MAX / 3 * 2, MAX / 3 * 2, MAX / 3 * 2);
+    let post = PoolSnapshot::new(MAX, MAX, MAX);
+    assert!(assert_k_not_decreased(pre, post, (0, 0)).is_ok());
+
+    // Minting one LP token too many is caught
+    let pre = PoolSnapshot::new(MAX / 3 * 2, MAX / 3 * 2, MAX / 3 * 2 - 1);
+    assert_violated(assert_k_not_decreased(pre, post, (0, 0)));
+}
+
+#[test]
+fn test_first_deposit_and_full_withdrawal() {
+    // No LP supply before: nothing to compare against
+    let empty = PoolSnapshot::new(0, 0, 0);
+    assert!(assert_k_not_decreased(empty, PoolSnapshot::new(MAX, MAX, MAX), (0, 0)).is_ok());
+
+    // Burning the whole supply leaves no LP token whose value could drop
+    let pre = PoolSnapshot::new(MAX, MAX, MAX);
+    assert!(assert_k_not_decreased(pre, PoolSnapshot::new(0, 0, 0), (0, 0)).is_ok());
+
+    // Emptied reserves with LP tokens still outstanding are not
+    assert_violated(assert_k_not_decreased(pre, PoolSnapshot::new(0, MAX, 1), (0, 0)));
+}
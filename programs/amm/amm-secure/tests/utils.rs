@@ -1,123 +1,140 @@
 // Test utilities for AMM program
 
 use litesvm::LiteSVM;
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
 use solana_sdk::{
-    hash::hash,
+    clock::Clock,
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
     signature::{Keypair, Signer},
 };
-use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::{instruction::create_account, program::ID as SYSTEM_PROGRAM_ID};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account,
+};
+use spl_token_2022_interface::{
+    extension::{
+        transfer_fee::instruction::initialize_transfer_fee_config, ExtensionType,
+        StateWithExtensions,
+    },
+    instruction::{initialize_mint2, mint_to as token_2022_mint_to},
+    state::{Account as Token2022Account, Mint as Token2022Mint},
+};
 
-// Program ID matching declare_id!
-pub const AMM_PROGRAM_ID: Pubkey = Pubkey::new_from_array(amm_secure::ID.to_bytes());
+// LiteSVM helpers (setup, funding, send) come from the shared test kit
+pub use soteria_test_kit::*;
 
-// Build Anchor instruction discriminator
-// Formula: first 8 bytes of sha256("global:method_name")
-pub fn anchor_discriminator(method: &str) -> [u8; 8] {
-    let preimage = format!("global:{}", method);
-    let hash_result = hash(preimage.as_bytes());
-    let mut discriminator = [0u8; 8];
-    discriminator.copy_from_slice(&hash_result.to_bytes()[..8]);
-    discriminator
-}
+// PDAs, instruction builders and account decoders come from the client SDK
+pub use soteria_client::amm::{self, instructions as amm_ix, LimitOrder, PoolConfig, Position};
+
+// Program ID matching declare_id!
+pub const AMM_PROGRAM_ID: Pubkey = amm::PROGRAM_ID;
 
 // Standard program IDs
 pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
-pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_account::ID;
-use solana_system_interface::program::ID as system_program;
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = spl_token_2022_interface::ID;
 
-// PDA Seeds
-pub const AMM_CONFIG_SEED: &[u8] = b"amm_config";
-pub const AMM_AUTHORITY_SEED: &[u8] = b"amm_authority";
-pub const LP_MINT_SEED: &[u8] = b"lp_mint";
+// Wrapped SOL mint (SPL Token program)
+pub const NATIVE_MINT: Pubkey = spl_token::native_mint::ID;
 
 // Token decimals
 pub const DECIMALS: u8 = 9;
 
+// Fee tier (and creation fee) of the pools the setup helpers create
+// Pools are keyed by mints and fee tier; the build_* helpers target this tier
+pub const POOL_FEE_TIER: u16 = 30;
+
+// Oracle program (oracle-secure) that price feeds are created with
+pub const ORACLE_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("3hARN2soFtGhiAK4N4VhnKWhuFE29B7aL3uiT9eYeVHe");
+pub const ORACLE_PROGRAM_PATH: &str = "../../oracle/oracle-secure/target/deploy/oracle_secure.so";
+pub const ORACLE_START_TIMESTAMP: i64 = 1_700_000_000;
+
+// Metaplex Core program that LP position NFTs are minted with
+// Same dumped mainnet binary the NFT staking tests load
+pub const MPL_CORE_PROGRAM_ID: Pubkey = soteria_client::nft_staking::MPL_CORE_PROGRAM_ID;
+pub const MPL_CORE_PROGRAM_PATH: &str = "../../nfts/utils/mpl-core-sample-so/mpl_core.so";
+
+// Metadata URI given to position NFTs in tests
+pub const POSITION_URI: &str = "https://example.com/amm-position.json";
+
 // Setup LiteSVM with AMM program
 pub fn setup_svm() -> LiteSVM {
-    let mut svm = LiteSVM::new();
-    let program_bytes = include_bytes!("../target/deploy/amm_secure.so");
-    let _ = svm.add_program(AMM_PROGRAM_ID, program_bytes);
-    svm
+    ProgramHarness::builder()
+        .program_file(AMM_PROGRAM_ID, "target/deploy/amm_secure.so")
+        .build()
+        .into_svm()
 }
 
-// Create and fund account
-pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
-    let keypair = Keypair::new();
-    svm.airdrop(&keypair.pubkey(), lamports)
-        .expect("Airdrop should succeed");
-    keypair
+// Setup LiteSVM with the AMM and the oracle program used by oracle-guarded pools
+// The clock starts at ORACLE_START_TIMESTAMP because the oracle treats
+// publish_ts 0 as "never published"
+pub fn setup_svm_with_oracle() -> LiteSVM {
+    ProgramHarness::builder()
+        .program_file(AMM_PROGRAM_ID, "target/deploy/amm_secure.so")
+        .program_file(ORACLE_PROGRAM_ID, ORACLE_PROGRAM_PATH)
+        .unix_timestamp(ORACLE_START_TIMESTAMP)
+        .build()
+        .into_svm()
 }
 
-// Derive pool config PDA
-pub fn derive_pool_config_pda(token_a_mint: &Pubkey, token_b_mint: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[
-            AMM_CONFIG_SEED,
-            token_a_mint.as_ref(),
-            token_b_mint.as_ref(),
-        ],
-        &AMM_PROGRAM_ID,
-    )
+// Setup LiteSVM with the AMM and Metaplex Core (for LP position NFTs)
+pub fn setup_svm_with_mpl_core() -> LiteSVM {
+    ProgramHarness::builder()
+        .program_file(AMM_PROGRAM_ID, "target/deploy/amm_secure.so")
+        .program_file(MPL_CORE_PROGRAM_ID, MPL_CORE_PROGRAM_PATH)
+        .build()
+        .into_svm()
 }
 
-// Derive pool authority PDA
-pub fn derive_pool_authority_pda(pool_config: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[AMM_AUTHORITY_SEED, pool_config.as_ref()],
-        &AMM_PROGRAM_ID,
-    )
-}
+// The build_* helpers below are the SPL Token, no-referrer, POOL_FEE_TIER defaults
+// most tests want. Token-2022, referrer and other fee tier cases call amm_ix directly.
 
-// Derive LP mint PDA
-pub fn derive_lp_mint_pda(pool_config: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[LP_MINT_SEED, pool_config.as_ref()],
-        &AMM_PROGRAM_ID,
-    )
+// Build initialize_amm_config instruction
+pub fn build_initialize_amm_config_ix(
+    admin: &Pubkey,
+    pool_creation_authority: &Pubkey,
+    fee_tiers: &[u16],
+) -> Instruction {
+    amm_ix::initialize_amm_config(admin, pool_creation_authority, fee_tiers)
 }
 
-// Build initialize_pool instruction
+// Build initialize_pool instruction (SPL Token program, no referral share)
 pub fn build_initialize_pool_ix(
     authority: &Pubkey,
     token_a_mint: &Pubkey,
     token_b_mint: &Pubkey,
     fee_basis_points: u16,
 ) -> Instruction {
-    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint);
-    let (pool_authority, _) = derive_pool_authority_pda(&pool_config);
-    let (lp_token_mint, _) = derive_lp_mint_pda(&pool_config);
-    let token_a_vault = get_associated_token_address(&pool_authority, token_a_mint);
-    let token_b_vault = get_associated_token_address(&pool_authority, token_b_mint);
-
-    // Discriminator for initialize_pool
-    let discriminator = anchor_discriminator("initialize_pool");
-
-    let mut data = discriminator.to_vec();
-    data.extend_from_slice(&fee_basis_points.to_le_bytes());
+    build_initialize_pool_ix_with_referral_fee(
+        authority,
+        token_a_mint,
+        token_b_mint,
+        fee_basis_points,
+        0,
+    )
+}
 
-    Instruction {
-        program_id: AMM_PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*authority, true),
-            AccountMeta::new_readonly(*token_a_mint, false),
-            AccountMeta::new_readonly(*token_b_mint, false),
-            AccountMeta::new(pool_config, false),
-            AccountMeta::new_readonly(pool_authority, false),
-            AccountMeta::new(lp_token_mint, false),
-            AccountMeta::new(token_a_vault, false),
-            AccountMeta::new(token_b_vault, false),
-            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
-            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data,
-    }
+// Build initialize_pool instruction with a referral share of the swap fee (SPL Token program)
+pub fn build_initialize_pool_ix_with_referral_fee(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_basis_points: u16,
+    referral_fee_basis_points: u16,
+) -> Instruction {
+    amm_ix::initialize_pool(
+        authority,
+        token_a_mint,
+        token_b_mint,
+        fee_basis_points,
+        referral_fee_basis_points,
+        &TOKEN_PROGRAM_ID,
+    )
 }
 
-// Build deposit_liquidity instruction
+// Build deposit_liquidity instruction (SPL Token program)
 pub fn build_deposit_liquidity_ix(
     depositor: &Pubkey,
     token_a_mint: &Pubkey,
@@ -128,49 +145,48 @@ pub fn build_deposit_liquidity_ix(
     max_amount_b: u64,
     expiration: i64,
 ) -> Instruction {
-    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint);
-    let (pool_authority, _) = derive_pool_authority_pda(&pool_config);
-    let (lp_token_mint, _) = derive_lp_mint_pda(&pool_config);
-
-    let depositor_token_a = get_associated_token_address(depositor, token_a_mint);
-    let depositor_token_b = get_associated_token_address(depositor, token_b_mint);
-    let depositor_lp_token = get_associated_token_address(depositor, &lp_token_mint);
-    let token_a_vault = get_associated_token_address(&pool_authority, token_a_mint);
-    let token_b_vault = get_associated_token_address(&pool_authority, token_b_mint);
-
-    // Discriminator for deposit_liquidity
-    let discriminator = anchor_discriminator("deposit_liquidity");
-
-    let mut data = discriminator.to_vec();
-    data.extend_from_slice(&desired_amount_a.to_le_bytes());
-    data.extend_from_slice(&desired_amount_b.to_le_bytes());
-    data.extend_from_slice(&max_amount_a.to_le_bytes());
-    data.extend_from_slice(&max_amount_b.to_le_bytes());
-    data.extend_from_slice(&expiration.to_le_bytes());
+    amm_ix::deposit_liquidity(
+        depositor,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        desired_amount_a,
+        desired_amount_b,
+        max_amount_a,
+        max_amount_b,
+        expiration,
+        &TOKEN_PROGRAM_ID,
+    )
+}
 
-    Instruction {
-        program_id: AMM_PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*depositor, true),
-            AccountMeta::new_readonly(pool_config, false),
-            AccountMeta::new_readonly(pool_authority, false),
-            AccountMeta::new(lp_token_mint, false),
-            AccountMeta::new_readonly(*token_a_mint, false),
-            AccountMeta::new_readonly(*token_b_mint, false),
-            AccountMeta::new(depositor_token_a, false),
-            AccountMeta::new(depositor_token_b, false),
-            AccountMeta::new(depositor_lp_token, false),
-            AccountMeta::new(token_a_vault, false),
-            AccountMeta::new(token_b_vault, false),
-            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
-            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data,
-    }
+// Build deposit_liquidity_sol instruction (SPL Token program)
+// Amounts on the wrapped SOL side are lamports
+#[allow(clippy::too_many_arguments)]
+pub fn build_deposit_liquidity_sol_ix(
+    depositor: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    desired_amount_a: u64,
+    desired_amount_b: u64,
+    max_amount_a: u64,
+    max_amount_b: u64,
+    expiration: i64,
+) -> Instruction {
+    amm_ix::deposit_liquidity_sol(
+        depositor,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        desired_amount_a,
+        desired_amount_b,
+        max_amount_a,
+        max_amount_b,
+        expiration,
+        &TOKEN_PROGRAM_ID,
+    )
 }
 
-// Build withdraw_liquidity instruction
+// Build withdraw_liquidity instruction (SPL Token program)
 pub fn build_withdraw_liquidity_ix(
     withdrawer: &Pubkey,
     token_a_mint: &Pubkey,
@@ -180,48 +196,121 @@ pub fn build_withdraw_liquidity_ix(
     min_amount_b: u64,
     expiration: i64,
 ) -> Instruction {
-    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint);
-    let (pool_authority, _) = derive_pool_authority_pda(&pool_config);
-    let (lp_token_mint, _) = derive_lp_mint_pda(&pool_config);
+    amm_ix::withdraw_liquidity(
+        withdrawer,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        lp_tokens_to_burn,
+        min_amount_a,
+        min_amount_b,
+        expiration,
+        &TOKEN_PROGRAM_ID,
+    )
+}
 
-    let withdrawer_token_a = get_associated_token_address(withdrawer, token_a_mint);
-    let withdrawer_token_b = get_associated_token_address(withdrawer, token_b_mint);
-    let withdrawer_lp_token = get_associated_token_address(withdrawer, &lp_token_mint);
-    let token_a_vault = get_associated_token_address(&pool_authority, token_a_mint);
-    let token_b_vault = get_associated_token_address(&pool_authority, token_b_mint);
+// Build migrate_liquidity instruction (SPL Token program)
+// Moves LP from the (token_a_mint, token_b_mint) pool to the
+// (dest_token_a_mint, dest_token_b_mint, dest_fee_tier) pool
+#[allow(clippy::too_many_arguments)]
+pub fn build_migrate_liquidity_ix(
+    user: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    dest_token_a_mint: &Pubkey,
+    dest_token_b_mint: &Pubkey,
+    dest_fee_tier: u16,
+    lp_amount: u64,
+    min_lp_out: u64,
+    expiration: i64,
+) -> Instruction {
+    amm_ix::migrate_liquidity(
+        user,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        dest_token_a_mint,
+        dest_token_b_mint,
+        dest_fee_tier,
+        lp_amount,
+        min_lp_out,
+        expiration,
+        &TOKEN_PROGRAM_ID,
+    )
+}
 
-    // Discriminator for withdraw_liquidity
-    let discriminator = anchor_discriminator("withdraw_liquidity");
+// Build deposit_position instruction (SPL Token program)
+// `asset` must also sign the transaction
+#[allow(clippy::too_many_arguments)]
+pub fn build_deposit_position_ix(
+    depositor: &Pubkey,
+    asset: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    desired_amount_a: u64,
+    desired_amount_b: u64,
+    max_amount_a: u64,
+    max_amount_b: u64,
+    expiration: i64,
+) -> Instruction {
+    amm_ix::deposit_position(
+        depositor,
+        asset,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        desired_amount_a,
+        desired_amount_b,
+        max_amount_a,
+        max_amount_b,
+        expiration,
+        POSITION_URI,
+        &TOKEN_PROGRAM_ID,
+    )
+}
 
-    let mut data = discriminator.to_vec();
-    data.extend_from_slice(&lp_tokens_to_burn.to_le_bytes());
-    data.extend_from_slice(&min_amount_a.to_le_bytes());
-    data.extend_from_slice(&min_amount_b.to_le_bytes());
-    data.extend_from_slice(&expiration.to_le_bytes());
+// Build withdraw_position instruction (SPL Token program)
+// The pool is taken from the mints, so passing another pool's mints targets that pool
+pub fn build_withdraw_position_ix(
+    withdrawer: &Pubkey,
+    asset: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    min_amount_a: u64,
+    min_amount_b: u64,
+    expiration: i64,
+) -> Instruction {
+    amm_ix::withdraw_position(
+        withdrawer,
+        asset,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        min_amount_a,
+        min_amount_b,
+        expiration,
+        &TOKEN_PROGRAM_ID,
+    )
+}
 
-    Instruction {
-        program_id: AMM_PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*withdrawer, true),
-            AccountMeta::new_readonly(pool_config, false),
-            AccountMeta::new_readonly(pool_authority, false),
-            AccountMeta::new(lp_token_mint, false),
-            AccountMeta::new_readonly(*token_a_mint, false),
-            AccountMeta::new_readonly(*token_b_mint, false),
-            AccountMeta::new(withdrawer_token_a, false),
-            AccountMeta::new(withdrawer_token_b, false),
-            AccountMeta::new(withdrawer_lp_token, false),
-            AccountMeta::new(token_a_vault, false),
-            AccountMeta::new(token_b_vault, false),
-            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
-            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data,
-    }
+// Build claim_lp_fees instruction (SPL Token program)
+pub fn build_claim_lp_fees_ix(
+    owner: &Pubkey,
+    asset: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+) -> Instruction {
+    amm_ix::claim_lp_fees(
+        owner,
+        asset,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        &TOKEN_PROGRAM_ID,
+    )
 }
 
-// Build swap_tokens instruction
+// Build swap_tokens instruction (SPL Token program, no referrer)
 pub fn build_swap_tokens_ix(
     swapper: &Pubkey,
     token_a_mint: &Pubkey,
@@ -231,41 +320,145 @@ pub fn build_swap_tokens_ix(
     min_output_amount: u64,
     expiration: i64,
 ) -> Instruction {
-    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint);
-    let (pool_authority, _) = derive_pool_authority_pda(&pool_config);
+    amm_ix::swap_tokens(
+        swapper,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        swap_token_a_for_b,
+        input_amount,
+        min_output_amount,
+        expiration,
+        &TOKEN_PROGRAM_ID,
+        None,
+        None,
+    )
+}
 
-    let swapper_token_a = get_associated_token_address(swapper, token_a_mint);
-    let swapper_token_b = get_associated_token_address(swapper, token_b_mint);
-    let token_a_vault = get_associated_token_address(&pool_authority, token_a_mint);
-    let token_b_vault = get_associated_token_address(&pool_authority, token_b_mint);
+// Build swap_sol_for_token instruction (SPL Token program, no referrer)
+pub fn build_swap_sol_for_token_ix(
+    swapper: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    input_amount: u64,
+    min_output_amount: u64,
+    expiration: i64,
+) -> Instruction {
+    amm_ix::swap_sol_for_token(
+        swapper,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        input_amount,
+        min_output_amount,
+        expiration,
+        &TOKEN_PROGRAM_ID,
+        None,
+        None,
+    )
+}
 
-    // Discriminator for swap_tokens
-    let discriminator = anchor_discriminator("swap_tokens");
+// Build swap_tokens instruction paying a slice of the fee to referrer_token_account
+pub fn build_swap_tokens_ix_with_referrer(
+    swapper: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    swap_token_a_for_b: bool,
+    input_amount: u64,
+    min_output_amount: u64,
+    expiration: i64,
+    referrer_token_account: &Pubkey,
+) -> Instruction {
+    amm_ix::swap_tokens(
+        swapper,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        swap_token_a_for_b,
+        input_amount,
+        min_output_amount,
+        expiration,
+        &TOKEN_PROGRAM_ID,
+        Some(referrer_token_account),
+        None,
+    )
+}
+
+// Build swap_with_rebate instruction reading the swapper's own LP token ATA
+pub fn build_swap_with_rebate_ix(
+    swapper: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    swap_token_a_for_b: bool,
+    input_amount: u64,
+    min_output_amount: u64,
+    expiration: i64,
+) -> Instruction {
+    amm_ix::swap_with_rebate(
+        swapper,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        swap_token_a_for_b,
+        input_amount,
+        min_output_amount,
+        expiration,
+        &TOKEN_PROGRAM_ID,
+        None,
+        None,
+    )
+}
 
-    let mut data = discriminator.to_vec();
-    data.push(if swap_token_a_for_b { 1 } else { 0 });
-    data.extend_from_slice(&input_amount.to_le_bytes());
-    data.extend_from_slice(&min_output_amount.to_le_bytes());
-    data.extend_from_slice(&expiration.to_le_bytes());
+// Build swap_with_rebate instruction reading the LP balance from lp_token_account instead
+#[allow(clippy::too_many_arguments)]
+pub fn build_swap_with_rebate_ix_with_lp_account(
+    swapper: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    swap_token_a_for_b: bool,
+    input_amount: u64,
+    min_output_amount: u64,
+    expiration: i64,
+    lp_token_account: &Pubkey,
+) -> Instruction {
+    let mut ix = build_swap_with_rebate_ix(
+        swapper,
+        token_a_mint,
+        token_b_mint,
+        swap_token_a_for_b,
+        input_amount,
+        min_output_amount,
+        expiration,
+    );
+    // LP token account is the last account
+    let lp_index = ix.accounts.len() - 1;
+    ix.accounts[lp_index] = AccountMeta::new_readonly(*lp_token_account, false);
+    ix
+}
 
-    Instruction {
-        program_id: AMM_PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*swapper, true),
-            AccountMeta::new_readonly(pool_config, false),
-            AccountMeta::new_readonly(pool_authority, false),
-            AccountMeta::new_readonly(*token_a_mint, false),
-            AccountMeta::new_readonly(*token_b_mint, false),
-            AccountMeta::new(swapper_token_a, false),
-            AccountMeta::new(swapper_token_b, false),
-            AccountMeta::new(token_a_vault, false),
-            AccountMeta::new(token_b_vault, false),
-            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
-            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data,
-    }
+// Build swap_tokens_exact_out instruction (SPL Token program, no referrer)
+pub fn build_swap_tokens_exact_out_ix(
+    swapper: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    swap_token_a_for_b: bool,
+    max_input_amount: u64,
+    output_amount: u64,
+    expiration: i64,
+) -> Instruction {
+    amm_ix::swap_tokens_exact_out(
+        swapper,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        swap_token_a_for_b,
+        max_input_amount,
+        output_amount,
+        expiration,
+        &TOKEN_PROGRAM_ID,
+        None,
+        None,
+    )
 }
 
 // Build lock_pool instruction
@@ -274,19 +467,7 @@ pub fn build_lock_pool_ix(
     token_a_mint: &Pubkey,
     token_b_mint: &Pubkey,
 ) -> Instruction {
-    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint);
-
-    // Discriminator for lock_pool
-    let discriminator = anchor_discriminator("lock_pool");
-
-    Instruction {
-        program_id: AMM_PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new_readonly(*authority, true),
-            AccountMeta::new(pool_config, false),
-        ],
-        data: discriminator.to_vec(),
-    }
+    amm_ix::lock_pool(authority, token_a_mint, token_b_mint, POOL_FEE_TIER)
 }
 
 // Build unlock_pool instruction
@@ -295,17 +476,552 @@ pub fn build_unlock_pool_ix(
     token_a_mint: &Pubkey,
     token_b_mint: &Pubkey,
 ) -> Instruction {
-    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint);
+    amm_ix::unlock_pool(authority, token_a_mint, token_b_mint, POOL_FEE_TIER)
+}
 
-    // Discriminator for unlock_pool
-    let discriminator = anchor_discriminator("unlock_pool");
+// Build request_fee_change instruction
+pub fn build_request_fee_change_ix(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    new_fee_basis_points: u16,
+) -> Instruction {
+    amm_ix::request_fee_change(
+        authority,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        new_fee_basis_points,
+    )
+}
+
+// Build change_pool_fee instruction
+pub fn build_change_pool_fee_ix(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+) -> Instruction {
+    amm_ix::change_pool_fee(authority, token_a_mint, token_b_mint, POOL_FEE_TIER)
+}
+
+// Read the current swap fee from PoolConfig
+pub fn get_pool_fee_basis_points(
+    svm: &LiteSVM,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+) -> u16 {
+    let (pool_config, _) = amm::pool_config_address(token_a_mint, token_b_mint, POOL_FEE_TIER);
+    let account = svm.get_account(&pool_config).expect("Pool config should exist");
+    PoolConfig::try_from_bytes(&account.data)
+        .expect("Pool config should decode")
+        .fee_basis_points
+}
+
+// Build set_emergency_withdraw instruction
+pub fn build_set_emergency_withdraw_ix(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    enabled: bool,
+) -> Instruction {
+    amm_ix::set_emergency_withdraw(authority, token_a_mint, token_b_mint, POOL_FEE_TIER, enabled)
+}
+
+// Build emergency_withdraw instruction (SPL Token program)
+pub fn build_emergency_withdraw_ix(
+    withdrawer: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    lp_tokens_to_burn: u64,
+) -> Instruction {
+    amm_ix::emergency_withdraw(
+        withdrawer,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        lp_tokens_to_burn,
+        &TOKEN_PROGRAM_ID,
+    )
+}
+
+// Build set_oracle_guard instruction pinning a feed of the oracle-secure program
+pub fn build_set_oracle_guard_ix(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    price_feed: &Pubkey,
+    max_oracle_deviation_bps: u16,
+) -> Instruction {
+    amm_ix::set_oracle_guard(
+        authority,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        &ORACLE_PROGRAM_ID,
+        price_feed,
+        max_oracle_deviation_bps,
+    )
+}
+
+// Build swap_tokens instruction passing the pool's oracle price feed
+pub fn build_swap_tokens_ix_with_price_feed(
+    swapper: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    swap_token_a_for_b: bool,
+    input_amount: u64,
+    min_output_amount: u64,
+    expiration: i64,
+    price_feed: &Pubkey,
+) -> Instruction {
+    amm_ix::swap_tokens(
+        swapper,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        swap_token_a_for_b,
+        input_amount,
+        min_output_amount,
+        expiration,
+        &TOKEN_PROGRAM_ID,
+        None,
+        Some(price_feed),
+    )
+}
+
+// Build set_circuit_breaker instruction
+pub fn build_set_circuit_breaker_ix(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    max_price_impact_bps: u16,
+    auto_lock: bool,
+) -> Instruction {
+    amm_ix::set_circuit_breaker(
+        authority,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        max_price_impact_bps,
+        auto_lock,
+    )
+}
+
+// Build set_swap_limit instruction
+pub fn build_set_swap_limit_ix(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    max_swap_per_slot_bps: u16,
+) -> Instruction {
+    amm_ix::set_swap_limit(
+        authority,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        max_swap_per_slot_bps,
+    )
+}
+
+// Read the PoolConfig account
+pub fn get_pool_config(svm: &LiteSVM, token_a_mint: &Pubkey, token_b_mint: &Pubkey) -> PoolConfig {
+    let (pool_config, _) = amm::pool_config_address(token_a_mint, token_b_mint, POOL_FEE_TIER);
+    let account = svm.get_account(&pool_config).expect("Pool config should exist");
+    PoolConfig::try_from_bytes(&account.data).expect("Pool config should decode")
+}
+
+// LIMIT ORDER HELPERS
+
+// Build place_limit_order instruction (SPL Token program)
+#[allow(clippy::too_many_arguments)]
+pub fn build_place_limit_order_ix(
+    owner: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    swap_token_a_for_b: bool,
+    order_id: u64,
+    input_amount: u64,
+    min_output_amount: u64,
+    keeper_tip: u64,
+    expires_at: i64,
+) -> Instruction {
+    amm_ix::place_limit_order(
+        owner,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        swap_token_a_for_b,
+        order_id,
+        input_amount,
+        min_output_amount,
+        keeper_tip,
+        expires_at,
+        &TOKEN_PROGRAM_ID,
+    )
+}
+
+// Build cancel_limit_order instruction (SPL Token program)
+pub fn build_cancel_limit_order_ix(
+    owner: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    swap_token_a_for_b: bool,
+    order_id: u64,
+) -> Instruction {
+    amm_ix::cancel_limit_order(
+        owner,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        swap_token_a_for_b,
+        order_id,
+        &TOKEN_PROGRAM_ID,
+    )
+}
+
+// Build fill_limit_order instruction (SPL Token program, no price feed)
+pub fn build_fill_limit_order_ix(
+    keeper: &Pubkey,
+    owner: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    swap_token_a_for_b: bool,
+    order_id: u64,
+) -> Instruction {
+    amm_ix::fill_limit_order(
+        keeper,
+        owner,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        swap_token_a_for_b,
+        order_id,
+        &TOKEN_PROGRAM_ID,
+        None,
+    )
+}
+
+// Derive a limit order PDA for the (token_a_mint, token_b_mint) pool
+pub fn derive_limit_order(
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    owner: &Pubkey,
+    order_id: u64,
+) -> Pubkey {
+    let (pool_config, _) = amm::pool_config_address(token_a_mint, token_b_mint, POOL_FEE_TIER);
+    amm::limit_order_address(&pool_config, owner, order_id).0
+}
+
+// ORACLE HELPERS
+// Feeds are built by hand against the oracle-secure instruction layout
+
+// Derive an oracle feed PDA: [b"feed", authority, seed]
+pub fn derive_price_feed(authority: &Pubkey, seed: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"feed", authority.as_ref(), &seed.to_le_bytes()],
+        &ORACLE_PROGRAM_ID,
+    )
+    .0
+}
+
+// Build oracle initialize_feed instruction with the authority as its only publisher
+pub fn build_initialize_feed_ix(
+    authority: &Pubkey,
+    seed: u64,
+    exponent: i32,
+    max_staleness_seconds: i64,
+    max_confidence_bps: u16,
+) -> Instruction {
+    let mut data = anchor_discriminator("initialize_feed").to_vec();
+    data.extend_from_slice(&seed.to_le_bytes());
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(authority.as_ref());
+    data.extend_from_slice(&exponent.to_le_bytes());
+    data.extend_from_slice(&max_staleness_seconds.to_le_bytes());
+    data.extend_from_slice(&max_confidence_bps.to_le_bytes());
 
     Instruction {
-        program_id: AMM_PROGRAM_ID,
+        program_id: ORACLE_PROGRAM_ID,
         accounts: vec![
-            AccountMeta::new_readonly(*authority, true),
-            AccountMeta::new(pool_config, false),
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(derive_price_feed(authority, seed), false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
         ],
-        data: discriminator.to_vec(),
+        data,
     }
 }
+
+// Build oracle push_price instruction
+pub fn build_push_price_ix(
+    publisher: &Pubkey,
+    price_feed: &Pubkey,
+    price: u64,
+    confidence: u64,
+) -> Instruction {
+    let mut data = anchor_discriminator("push_price").to_vec();
+    data.extend_from_slice(&price.to_le_bytes());
+    data.extend_from_slice(&confidence.to_le_bytes());
+
+    Instruction {
+        program_id: ORACLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*publisher, true),
+            AccountMeta::new(*price_feed, false),
+        ],
+        data,
+    }
+}
+
+// Create two SPL mints, initialize a 30bp pool, and seed it with `liquidity` of each token
+// Returns (mint_a, mint_b); `authority` is the mint authority and the first LP
+pub fn setup_pool_with_liquidity(
+    svm: &mut LiteSVM,
+    authority: &Keypair,
+    liquidity: u64,
+) -> (Pubkey, Pubkey) {
+    setup_pool_with_reserves(svm, authority, liquidity, liquidity)
+}
+
+// Same as setup_pool_with_liquidity but with independent reserves (sets the starting price)
+pub fn setup_pool_with_reserves(
+    svm: &mut LiteSVM,
+    authority: &Keypair,
+    amount_a: u64,
+    amount_b: u64,
+) -> (Pubkey, Pubkey) {
+    setup_pool_with_referral_fee(svm, authority, amount_a, amount_b, 0)
+}
+
+// Same as setup_pool_with_reserves but with a referral share of the 30bp swap fee
+pub fn setup_pool_with_referral_fee(
+    svm: &mut LiteSVM,
+    authority: &Keypair,
+    amount_a: u64,
+    amount_b: u64,
+    referral_fee_basis_points: u16,
+) -> (Pubkey, Pubkey) {
+    let mint_a = CreateMint::new(svm, authority)
+        .authority(&authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .unwrap();
+    let mint_b = CreateMint::new(svm, authority)
+        .authority(&authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .unwrap();
+
+    let init_ix = build_initialize_pool_ix_with_referral_fee(
+        &authority.pubkey(),
+        &mint_a,
+        &mint_b,
+        POOL_FEE_TIER,
+        referral_fee_basis_points,
+    );
+    assert_tx_ok!(send_tx(svm, &[init_ix], authority, &[authority]));
+
+    let authority_ata_a = CreateAssociatedTokenAccount::new(svm, authority, &mint_a)
+        .owner(&authority.pubkey())
+        .send()
+        .unwrap();
+    let authority_ata_b = CreateAssociatedTokenAccount::new(svm, authority, &mint_b)
+        .owner(&authority.pubkey())
+        .send()
+        .unwrap();
+    MintTo::new(svm, authority, &mint_a, &authority_ata_a, amount_a)
+        .owner(authority)
+        .send()
+        .unwrap();
+    MintTo::new(svm, authority, &mint_b, &authority_ata_b, amount_b)
+        .owner(authority)
+        .send()
+        .unwrap();
+
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+    let deposit_ix = build_deposit_liquidity_ix(
+        &authority.pubkey(),
+        &mint_a,
+        &mint_b,
+        amount_a,
+        amount_b,
+        amount_a,
+        amount_b,
+        expiration,
+    );
+    assert_tx_ok!(send_tx(svm, &[deposit_ix], authority, &[authority]));
+
+    (mint_a, mint_b)
+}
+
+// Derive the pool vault (pool authority ATA) for one of the pool mints (SPL Token program)
+pub fn derive_vault(token_a_mint: &Pubkey, token_b_mint: &Pubkey, mint: &Pubkey) -> Pubkey {
+    amm::vault_address(token_a_mint, token_b_mint, POOL_FEE_TIER, mint, &TOKEN_PROGRAM_ID)
+}
+
+// Build flash_loan_begin instruction (SPL Token program)
+// Borrows `amount` of borrow_mint from the pool vault into borrower_token_account
+pub fn build_flash_loan_begin_ix(
+    borrower: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    borrow_mint: &Pubkey,
+    borrower_token_account: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    amm_ix::flash_loan_begin(
+        borrower,
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        borrow_mint,
+        borrower_token_account,
+        amount,
+        &TOKEN_PROGRAM_ID,
+    )
+}
+
+// Build flash_loan_end instruction (SPL Token program)
+pub fn build_flash_loan_end_ix(
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    borrow_mint: &Pubkey,
+) -> Instruction {
+    amm_ix::flash_loan_end(
+        token_a_mint,
+        token_b_mint,
+        POOL_FEE_TIER,
+        borrow_mint,
+        &TOKEN_PROGRAM_ID,
+    )
+}
+
+// Build an SPL Token transfer_checked instruction
+// Used by flash loan tests to repay the vault inside the same transaction
+pub fn build_repay_ix(
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    spl_token::instruction::transfer_checked(
+        &TOKEN_PROGRAM_ID,
+        source,
+        mint,
+        destination,
+        owner,
+        &[],
+        amount,
+        DECIMALS,
+    )
+    .unwrap()
+}
+
+// TOKEN-2022 HELPERS
+
+// Create a Token-2022 mint with the TransferFeeConfig extension
+// transfer_fee_basis_points is withheld on every transfer, capped at maximum_fee
+pub fn create_transfer_fee_mint(
+    svm: &mut LiteSVM,
+    payer: &Keypair,
+    decimals: u8,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> Pubkey {
+    let mint = Keypair::new();
+    let space = ExtensionType::try_calculate_account_len::<Token2022Mint>(&[
+        ExtensionType::TransferFeeConfig,
+    ])
+    .unwrap();
+    let lamports = svm.minimum_balance_for_rent_exemption(space);
+
+    let create_account_ix = create_account(
+        &payer.pubkey(),
+        &mint.pubkey(),
+        lamports,
+        space as u64,
+        &TOKEN_2022_PROGRAM_ID,
+    );
+
+    // Extension must be initialized before the mint itself
+    let init_fee_config_ix = initialize_transfer_fee_config(
+        &TOKEN_2022_PROGRAM_ID,
+        &mint.pubkey(),
+        Some(&payer.pubkey()),
+        Some(&payer.pubkey()),
+        transfer_fee_basis_points,
+        maximum_fee,
+    )
+    .unwrap();
+
+    let init_mint_ix = initialize_mint2(
+        &TOKEN_2022_PROGRAM_ID,
+        &mint.pubkey(),
+        &payer.pubkey(),
+        None,
+        decimals,
+    )
+    .unwrap();
+
+    assert_tx_ok!(
+        send_tx(
+            svm,
+            &[create_account_ix, init_fee_config_ix, init_mint_ix],
+            payer,
+            &[payer, &mint],
+        ),
+        "Failed to create transfer fee mint"
+    );
+
+    mint.pubkey()
+}
+
+// Create an associated token account owned by the Token-2022 program
+pub fn create_token_2022_ata(
+    svm: &mut LiteSVM,
+    payer: &Keypair,
+    owner: &Pubkey,
+    mint: &Pubkey,
+) -> Pubkey {
+    let ix =
+        create_associated_token_account(&payer.pubkey(), owner, mint, &TOKEN_2022_PROGRAM_ID);
+
+    assert_tx_ok!(send_tx(svm, &[ix], payer, &[payer]), "Failed to create Token-2022 ATA");
+
+    get_associated_token_address_with_program_id(owner, mint, &TOKEN_2022_PROGRAM_ID)
+}
+
+// Mint Token-2022 tokens (mint_authority must sign)
+pub fn mint_token_2022(
+    svm: &mut LiteSVM,
+    mint_authority: &Keypair,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    amount: u64,
+) {
+    let ix = token_2022_mint_to(
+        &TOKEN_2022_PROGRAM_ID,
+        mint,
+        destination,
+        &mint_authority.pubkey(),
+        &[],
+        amount,
+    )
+    .unwrap();
+
+    assert_tx_ok!(
+        send_tx(svm, &[ix], mint_authority, &[mint_authority]),
+        "Failed to mint Token-2022 tokens"
+    );
+}
+
+// Read the balance of a Token-2022 token account (extensions are skipped)
+pub fn get_token_2022_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    let account = svm
+        .get_account(token_account)
+        .expect("Token-2022 account should exist");
+    StateWithExtensions::<Token2022Account>::unpack(&account.data)
+        .expect("Failed to unpack Token-2022 account")
+        .base
+        .amount
+}
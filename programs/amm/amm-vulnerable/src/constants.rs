@@ -9,6 +9,9 @@
 pub const AMM_CONFIG_SEED: &[u8] = b"amm_config";
 pub const AMM_AUTHORITY_SEED: &[u8] = b"amm_authority";
 pub const LP_MINT_SEED: &[u8] = b"lp_mint";
+pub const AMM_GLOBAL_CONFIG_SEED: &[u8] = b"amm_global_config";
+pub const POSITION_SEED: &[u8] = b"position";
+pub const LIMIT_ORDER_SEED: &[u8] = b"limit_order";
 
 // VULNERABLE LIMITS
 
@@ -28,4 +31,46 @@ pub const MINIMUM_LIQUIDITY: u64 = 1;
 // Allows stale transactions to execute at unfavorable prices
 pub const MAX_EXPIRATION_SECONDS: i64 = 31_536_000;
 
+// Flash loan fee (same as secure version)
+// Charged on paper, but never enforced by flash_loan_end
+pub const FLASH_LOAN_FEE_BASIS_POINTS: u64 = 9;
+
+// Maximum number of whitelisted fee tiers (same as secure version)
+pub const MAX_FEE_TIERS: usize = 8;
+
+// Fee change delay (same as secure version)
+// Stored on the pool, but never enforced by change_pool_fee
+pub const FEE_CHANGE_DELAY_SECONDS: i64 = 172_800;
+
+// Maximum referral share of the swap fee (same as secure version)
+pub const MAX_REFERRAL_FEE_BASIS_POINTS: u16 = 5000;
+
+// Fee rebate for LP holders (same as secure version)
+// The LP balance it is granted on is never validated by swap_with_rebate
+pub const FEE_REBATE_MIN_LP_TOKENS: u64 = 1_000_000_000;
+pub const FEE_REBATE_BASIS_POINTS: u16 = 5000;
+
+// Maximum oracle deviation a pool can tolerate (same as secure version)
+pub const MAX_ORACLE_DEVIATION_BASIS_POINTS: u16 = 10_000;
+
+// Maximum price impact limit a pool can set (same as secure version)
+pub const MAX_PRICE_IMPACT_BASIS_POINTS: u16 = 10_000;
+
+// Maximum per-slot swap volume cap a pool can set (same as secure version)
+pub const MAX_SWAP_PER_SLOT_BASIS_POINTS: u16 = 10_000;
+
+// LP position NFTs (same as secure version)
+pub const POSITION_NAME: &str = "AMM LP Position";
+pub const MAX_POSITION_URI_LENGTH: usize = 200;
+pub const POSITION_PRICE_SCALE: u64 = 1_000_000_000;
+pub const POSITION_POOL_KEY: &str = "pool";
+pub const POSITION_AMOUNT_A_KEY: &str = "amount_a";
+pub const POSITION_AMOUNT_B_KEY: &str = "amount_b";
+pub const POSITION_LP_TOKENS_KEY: &str = "lp_tokens";
+pub const POSITION_ENTRY_PRICE_KEY: &str = "entry_price";
+
+// Anchor discriminator of the oracle's PriceFeed account
+// First 8 bytes of sha256("account:PriceFeed")
+pub const PRICE_FEED_DISCRIMINATOR: [u8; 8] = [189, 103, 252, 23, 152, 35, 243, 156];
+
 pub const ANCHOR_DISCRIMINATOR: usize = 8;
@@ -1,6 +1,8 @@
 
 use anchor_lang::prelude::*;
 
+pub use soteria_errors::amm_vulnerable::AmmError as AmmErrorCode;
+
 #[error_code]
 pub enum AmmError {
     #[msg("Fee basis points cannot exceed maximum allowed (1000 = 10%)")]
@@ -80,4 +82,79 @@ pub enum AmmError {
 
     #[msg("Invalid curve parameters provided")]
     InvalidCurveParams,
+
+    #[msg("A flash loan is already active for this pool")]
+    FlashLoanAlreadyActive,
+
+    #[msg("No flash loan is active for this pool")]
+    NoActiveFlashLoan,
+
+    #[msg("Flash loan begin must be followed by flash loan end in the same transaction")]
+    MissingFlashLoanEnd,
+
+    #[msg("Flash loan begin cannot be invoked via CPI")]
+    FlashLoanCpiNotAllowed,
+
+    #[msg("Only the pool creation authority can create pools")]
+    UnauthorizedPoolCreator,
+
+    #[msg("Fee is not one of the whitelisted fee tiers")]
+    FeeTierNotWhitelisted,
+
+    #[msg("Fee tiers must contain between 1 and MAX_FEE_TIERS entries")]
+    InvalidFeeTierCount,
+
+    #[msg("No fee change has been requested")]
+    NoPendingFeeChange,
+
+    #[msg("Pool is not locked")]
+    PoolNotLocked,
+
+    #[msg("Referral fee share exceeds maximum")]
+    ReferralFeeTooHigh,
+
+    #[msg("Invalid referrer token account")]
+    InvalidReferrer,
+
+    #[msg("Invalid oracle guard configuration")]
+    InvalidOracleConfig,
+
+    #[msg("Pool has an oracle guard but no price feed was passed")]
+    PriceFeedRequired,
+
+    #[msg("Price feed does not match the pool's oracle configuration")]
+    InvalidPriceFeed,
+
+    #[msg("Oracle price has not been published")]
+    OraclePriceUnavailable,
+
+    #[msg("Oracle confidence interval is too wide")]
+    OracleConfidenceTooWide,
+
+    #[msg("Pool price deviates too far from the oracle price")]
+    OraclePriceDeviation,
+
+    #[msg("Position URI too long")]
+    PositionUriTooLong,
+
+    #[msg("Invalid Metaplex Core program")]
+    InvalidMplCoreProgram,
+
+    #[msg("Signer does not own the position NFT")]
+    PositionOwnerMismatch,
+
+    #[msg("Invalid circuit breaker configuration")]
+    InvalidCircuitBreakerConfig,
+
+    #[msg("Neither pool token is wrapped SOL")]
+    NotNativeSolPool,
+
+    #[msg("Limit order has expired")]
+    LimitOrderExpired,
+
+    #[msg("Destination pool must differ from the source pool")]
+    InvalidMigrationPool,
+
+    #[msg("Invalid per-slot swap limit")]
+    InvalidSwapLimitConfig,
 }
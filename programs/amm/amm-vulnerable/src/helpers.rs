@@ -7,7 +7,12 @@
 // V004: Unchecked arithmetic (overflow/underflow risk)
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Burn, MintTo, Transfer, burn, mint_to, transfer};
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token_interface::{
+    Burn, CloseAccount, MintTo, SyncNative, TransferChecked, burn, close_account, mint_to,
+    sync_native, transfer_checked,
+};
 
 use crate::{constants::*, errors::*};
 
@@ -101,27 +106,176 @@ pub fn calculate_withdrawal(
     Ok((amount_a, amount_b))
 }
 
+// Calculate the entry price recorded on an LP position
+// Formula: entry_price = amount_b * POSITION_PRICE_SCALE / amount_a, rounded down
+pub fn calculate_entry_price(amount_a: u64, amount_b: u64) -> Result<u64> {
+    require!(amount_a > 0, AmmError::DivisionByZero);
+
+    // u128 intermediate cannot overflow for any u64 amount
+    let entry_price = amount_b as u128 * POSITION_PRICE_SCALE as u128 / amount_a as u128;
+
+    Ok(entry_price as u64)
+}
+
+// SWAP CALCULATION HELPERS
+
+// Calculate the input required to receive exactly `output_amount` (exact-output swap)
+// Inverse of the constant product formula with the swap fee taken from the input
+//
+// VULNERABILITY V018: Rounds DOWN (in the user's favor)
+// Secure version:
+//   net_input   = ceil(input_reserve * output_amount / (output_reserve - output_amount))
+//   gross_input = ceil(net_input * 10_000 / (10_000 - fee_basis_points))
+// Vulnerable version uses floor division for both steps
+// Attack: Request outputs whose true cost is below 1 input token - floor rounds the
+// cost to 0, so the swapper receives output tokens for free. Repeat to drain the vault.
+pub fn calculate_exact_out_input(
+    input_reserve: u64,
+    output_reserve: u64,
+    output_amount: u64,
+    fee_basis_points: u16,
+) -> Result<u64> {
+    require!(
+        output_amount < output_reserve,
+        AmmError::InsufficientPoolLiquidity
+    );
+
+    let net_input = (input_reserve as u128) * (output_amount as u128)
+        / ((output_reserve - output_amount) as u128);
+
+    let gross_input = net_input * 10_000 / (10_000 - fee_basis_points as u128);
+
+    Ok(gross_input as u64)
+}
+
+// FLASH LOAN HELPERS
+
+// Calculate the fee owed on a flash loan
+// Rounds up so even the smallest loan pays at least 1 token to LPs
+// Formula: fee = ceil(amount * FLASH_LOAN_FEE_BASIS_POINTS / 10_000)
+pub fn calculate_flash_loan_fee(amount: u64) -> Result<u64> {
+    // u128 intermediate cannot overflow for any u64 amount
+    let fee = (amount as u128 * FLASH_LOAN_FEE_BASIS_POINTS as u128 + 9_999) / 10_000;
+
+    Ok(fee as u64)
+}
+
+// REFERRAL HELPERS
+
+// Calculate the referrer's slice of the swap fee
+// Formula: referral = amount * fee_basis_points * referral_fee_basis_points / 10_000^2
+pub fn calculate_referral_fee(
+    amount: u64,
+    fee_basis_points: u16,
+    referral_fee_basis_points: u16,
+) -> Result<u64> {
+    // u128 intermediate cannot overflow for any u64 amount and u16 rates
+    let referral = amount as u128 * fee_basis_points as u128 * referral_fee_basis_points as u128
+        / 100_000_000;
+
+    Ok(referral as u64)
+}
+
+// FEE REBATE HELPERS
+
+// Swap fee charged to a swapper holding lp_balance LP tokens (same as secure version)
+pub fn calculate_rebated_fee(fee_basis_points: u16, lp_balance: u64) -> Result<u16> {
+    if lp_balance < FEE_REBATE_MIN_LP_TOKENS {
+        return Ok(fee_basis_points);
+    }
+
+    let rebate = fee_basis_points as u64 * FEE_REBATE_BASIS_POINTS as u64 / 10_000;
+
+    Ok((fee_basis_points as u64 - rebate) as u16)
+}
+
+// ORACLE HELPERS
+
+// Distance between the pool price and the oracle price, in basis points of the oracle price
+// Both prices are token B per token A in UI units:
+// pool   = (reserve_b / 10^decimals_b) / (reserve_a / 10^decimals_a)
+// oracle = price * 10^exponent
+// Formula: deviation = |pool - oracle| * 10_000 / oracle, rounded down
+pub fn calculate_oracle_deviation_bps(
+    reserve_a: u64,
+    reserve_b: u64,
+    decimals_a: u8,
+    decimals_b: u8,
+    price: u64,
+    exponent: i32,
+) -> Result<u128> {
+    require!(exponent <= 0, AmmError::InvalidPriceFeed);
+    require!(price > 0, AmmError::OraclePriceUnavailable);
+    require!(reserve_a > 0, AmmError::InsufficientPoolLiquidity);
+
+    // Cross-multiply to stay in integers, cancelling the shared power of ten:
+    // pool_value   = reserve_b * 10^(decimals_a - exponent)
+    // oracle_value = price * reserve_a * 10^decimals_b
+    let pool_exponent = decimals_a as u32 + exponent.unsigned_abs();
+    let oracle_exponent = decimals_b as u32;
+    let shared = pool_exponent.min(oracle_exponent);
+
+    let pool_value = 10u128
+        .checked_pow(pool_exponent - shared)
+        .and_then(|scale| scale.checked_mul(reserve_b as u128))
+        .ok_or(AmmError::Overflow)?;
+    let oracle_value = 10u128
+        .checked_pow(oracle_exponent - shared)
+        .and_then(|scale| scale.checked_mul(reserve_a as u128))
+        .and_then(|value| value.checked_mul(price as u128))
+        .ok_or(AmmError::Overflow)?;
+
+    let deviation = pool_value
+        .abs_diff(oracle_value)
+        .checked_mul(10_000)
+        .ok_or(AmmError::Overflow)?
+        .checked_div(oracle_value)
+        .ok_or(AmmError::DivisionByZero)?;
+
+    Ok(deviation)
+}
+
+// NATIVE SOL HELPERS
+
+// Which side of the pool is wrapped SOL
+// Returns true for token A, false for token B
+pub fn native_sol_is_token_a(token_a_mint: &Pubkey, token_b_mint: &Pubkey) -> Result<bool> {
+    if *token_a_mint == native_mint::ID {
+        Ok(true)
+    } else if *token_b_mint == native_mint::ID {
+        Ok(false)
+    } else {
+        err!(AmmError::NotNativeSolPool)
+    }
+}
+
 // CPI HELPERS
 
 // Generic token transfer helper
 // Used for transferring tokens to/from vaults
+// Uses transfer_checked so the same CPI works for SPL Token and Token-2022 mints
+// (Token-2022 rejects plain `transfer` for mints with transfer fee extensions)
 pub fn transfer_tokens<'info>(
     amount: u64,
+    decimals: u8,
     token_program: &AccountInfo<'info>,
     from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
     to: &AccountInfo<'info>,
     authority: &AccountInfo<'info>,
 ) -> Result<()> {
-    transfer(
+    transfer_checked(
         CpiContext::new(
             token_program.clone(),
-            Transfer {
+            TransferChecked {
                 from: from.clone(),
+                mint: mint.clone(),
                 to: to.clone(),
                 authority: authority.clone(),
             },
         ),
         amount,
+        decimals,
     )
 }
 
@@ -129,28 +283,55 @@ pub fn transfer_tokens<'info>(
 // Used in withdraw and swap instructions
 pub fn transfer_from_vault<'info>(
     amount: u64,
+    decimals: u8,
     token_program: &AccountInfo<'info>,
     from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
     to: &AccountInfo<'info>,
     authority: &AccountInfo<'info>,
     authority_seeds: &[&[u8]],
 ) -> Result<()> {
     let signer_seeds = &[authority_seeds];
 
-    transfer(
+    transfer_checked(
         CpiContext::new_with_signer(
             token_program.clone(),
-            Transfer {
+            TransferChecked {
                 from: from.clone(),
+                mint: mint.clone(),
                 to: to.clone(),
                 authority: authority.clone(),
             },
             signer_seeds,
         ),
         amount,
+        decimals,
     )
 }
 
+// Close a token account owned by a PDA (requires PDA signer)
+// Used when a limit order's escrow is emptied by a fill or a cancel
+// Rent goes to `destination`
+pub fn close_from_pda<'info>(
+    token_program: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let signer_seeds = &[authority_seeds];
+
+    close_account(CpiContext::new_with_signer(
+        token_program.clone(),
+        CloseAccount {
+            account: account.clone(),
+            destination: destination.clone(),
+            authority: authority.clone(),
+        },
+        signer_seeds,
+    ))
+}
+
 // Mint LP tokens (requires PDA authority)
 // Used when depositing liquidity
 pub fn mint_lp_tokens<'info>(
@@ -198,4 +379,76 @@ pub fn burn_lp_tokens<'info>(
         ),
         amount,
     )
-}
\ No newline at end of file
+}
+
+// Burn LP tokens held by the pool authority (requires PDA signer)
+// Used when withdrawing an LP position, whose tokens sit in the position LP vault
+pub fn burn_lp_tokens_from_vault<'info>(
+    amount: u64,
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let signer_seeds = &[authority_seeds];
+
+    burn(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            Burn {
+                mint: mint.clone(),
+                from: from.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+// Wrap lamports into a wSOL account (system transfer + sync_native)
+pub fn wrap_sol<'info>(
+    amount: u64,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    wrapped_sol_account: &AccountInfo<'info>,
+) -> Result<()> {
+    transfer(
+        CpiContext::new(
+            system_program.clone(),
+            Transfer {
+                from: owner.clone(),
+                to: wrapped_sol_account.clone(),
+            },
+        ),
+        amount,
+    )?;
+
+    sync_native(CpiContext::new(
+        token_program.clone(),
+        SyncNative {
+            account: wrapped_sol_account.clone(),
+        },
+    ))
+}
+
+// Close a temporary wSOL account, sending its lamports to destination
+// VULNERABILITY V026: destination is whatever the caller passed and the refund is never checked
+// Secure version closes to the owner and requires the owner's balance to rise by the full refund
+pub fn close_wrapped_sol<'info>(
+    token_program: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    wrapped_sol_account: &AccountInfo<'info>,
+) -> Result<()> {
+    close_account(CpiContext::new(
+        token_program.clone(),
+        CloseAccount {
+            account: wrapped_sol_account.clone(),
+            destination: destination.clone(),
+            authority: owner.clone(),
+        },
+    ))
+}
@@ -0,0 +1,117 @@
+// Cancel Limit Order Instruction - VULNERABLE VERSION
+//
+// Same as the secure version.
+//
+//
+// Returns an unfilled limit order's escrow to its owner and closes the order.
+//
+// HOW IT WORKS:
+// 1. The escrow balance is transferred back to the owner (order PDA signs)
+// 2. The escrow is closed to the owner
+// 3. The LimitOrder PDA is closed to the owner, refunding its rent and the keeper tip
+//
+// SECURITY:
+// - Only the order owner can cancel (has_one = owner)
+// - Works while the pool is locked or after expiry: cancelling never touches the
+//   pool, and the owner must always be able to get their tokens back
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, errors::*, helpers::*, state::*};
+
+#[derive(Accounts)]
+pub struct CancelLimitOrder<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub input_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Limit order PDA
+    // Seeds: ["limit_order", pool_config, owner, order_id]
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            LIMIT_ORDER_SEED,
+            limit_order.pool_config.as_ref(),
+            owner.key().as_ref(),
+            &limit_order.order_id.to_le_bytes(),
+        ],
+        bump = limit_order.bump,
+        has_one = owner @ AmmError::Unauthorized,
+        has_one = input_mint,
+    )]
+    pub limit_order: Box<Account<'info, LimitOrder>>,
+
+    #[account(
+        mut,
+        associated_token::mint = input_mint,
+        associated_token::authority = limit_order,
+        associated_token::token_program = token_program,
+    )]
+    pub order_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Recreated if the owner closed it after placing the order
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = input_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_input_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CancelLimitOrder<'info> {
+    pub fn cancel_limit_order(&mut self) -> Result<()> {
+        let pool_config_key = self.limit_order.pool_config;
+        let owner_key = self.owner.key();
+        let order_id_bytes = self.limit_order.order_id.to_le_bytes();
+        let order_seeds = &[
+            LIMIT_ORDER_SEED,
+            pool_config_key.as_ref(),
+            owner_key.as_ref(),
+            order_id_bytes.as_ref(),
+            &[self.limit_order.bump],
+        ];
+
+        // Return the full escrow balance, including anything sent to it after placement
+        let refund_amount = self.order_escrow.amount;
+        if refund_amount > 0 {
+            transfer_from_vault(
+                refund_amount,
+                self.input_mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.order_escrow.to_account_info(),
+                &self.input_mint.to_account_info(),
+                &self.owner_input_token.to_account_info(),
+                &self.limit_order.to_account_info(),
+                order_seeds,
+            )?;
+        }
+
+        close_from_pda(
+            &self.token_program.to_account_info(),
+            &self.order_escrow.to_account_info(),
+            &self.owner.to_account_info(),
+            &self.limit_order.to_account_info(),
+            order_seeds,
+        )?;
+
+        msg!(
+            "Limit order {} cancelled: {} refunded",
+            self.limit_order.order_id,
+            refund_amount
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+// Change Pool Fee Instruction - VULNERABLE VERSION
+//
+// Second step of the two-step fee change. Applies the fee queued by
+// request_fee_change.
+//
+// VULNERABILITIES:
+// - V019: Timelock never enforced - request + change in one transaction applies
+//   any fee instantly
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct ChangePoolFee<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+}
+
+impl<'info> ChangePoolFee<'info> {
+    pub fn change_pool_fee(&mut self) -> Result<()> {
+        // Validate authority
+        self.pool_config.assert_is_authority(&self.authority.key())?;
+
+        require!(
+            self.pool_config.fee_change_unlock_time != 0,
+            AmmError::NoPendingFeeChange
+        );
+
+        // VULNERABILITY V019: Timelock never enforced
+        // Secure version:
+        //   require!(current_time >= fee_change_unlock_time, AmmError::FeeChangeTimelockActive)
+        // Attack: Authority sees a large pending swap, front-runs it with
+        //         request_fee_change(50%) + change_pool_fee in one transaction
+        // Impact: Victim's swap pays the new fee with zero notice (fee rug)
+
+        let old_fee = self.pool_config.fee_basis_points;
+        self.pool_config.fee_basis_points = self.pool_config.pending_fee_basis_points;
+
+        // Clear pending change
+        self.pool_config.pending_fee_basis_points = 0;
+        self.pool_config.fee_change_unlock_time = 0;
+
+        msg!(
+            "Pool fee changed: {} -> {} bp",
+            old_fee,
+            self.pool_config.fee_basis_points
+        );
+
+        Ok(())
+    }
+}
@@ -8,11 +8,12 @@
 // V005: MINIMUM_LIQUIDITY = 1 - enables inflation attacks
 // V007: No pool lock enforcement - deposits work even when pool is locked
 // V010: No zero amount checks - wastes gas and can cause unexpected behavior
+// V015: Token-2022 transfer fees ignored - LP tokens minted for tokens the vault never received
 
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::{constants::*, errors::*, state::*, helpers::*};
@@ -29,6 +30,7 @@ pub struct DepositLiquidity<'info> {
             AMM_CONFIG_SEED,
             pool_config.token_a_mint.as_ref(),
             pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
         ],
         bump = pool_config.config_bump,
     )]
@@ -48,16 +50,17 @@ pub struct DepositLiquidity<'info> {
         seeds = [LP_MINT_SEED, pool_config.key().as_ref()],
         bump = pool_config.lp_mint_bump,
         mint::authority = pool_authority,
+        mint::token_program = token_program,
     )]
-    pub lp_token_mint: Box<Account<'info, Mint>>,
+    pub lp_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     // Token A mint (verified against pool_config)
     #[account(address = pool_config.token_a_mint)]
-    pub token_a_mint: Box<Account<'info, Mint>>,
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
 
     // Token B mint (verified against pool_config)
     #[account(address = pool_config.token_b_mint)]
-    pub token_b_mint: Box<Account<'info, Mint>>,
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
 
     // Depositor's token A account (source of token A)
     // Anchor validates mint and authority via constraints
@@ -65,16 +68,18 @@ pub struct DepositLiquidity<'info> {
         mut,
         token::mint = token_a_mint,
         token::authority = depositor,
+        token::token_program = token_program,
     )]
-    pub depositor_token_a: Account<'info, TokenAccount>,
+    pub depositor_token_a: InterfaceAccount<'info, TokenAccount>,
 
     // Depositor's token B account (source of token B)
     #[account(
         mut,
         token::mint = token_b_mint,
         token::authority = depositor,
+        token::token_program = token_program,
     )]
-    pub depositor_token_b: Account<'info, TokenAccount>,
+    pub depositor_token_b: InterfaceAccount<'info, TokenAccount>,
 
     // Depositor's LP token account (created if doesn't exist)
     #[account(
@@ -82,26 +87,29 @@ pub struct DepositLiquidity<'info> {
         payer = depositor,
         associated_token::mint = lp_token_mint,
         associated_token::authority = depositor,
+        associated_token::token_program = token_program,
     )]
-    pub depositor_lp_token: Box<Account<'info, TokenAccount>>,
+    pub depositor_lp_token: Box<InterfaceAccount<'info, TokenAccount>>,
 
     // Pool's token A vault (holds all token A in the pool)
     #[account(
         mut,
         token::mint = token_a_mint,
         token::authority = pool_authority,
+        token::token_program = token_program,
     )]
-    pub token_a_vault: Box<Account<'info, TokenAccount>>,
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     // Pool's token B vault (holds all token B in the pool)
     #[account(
         mut,
         token::mint = token_b_mint,
         token::authority = pool_authority,
+        token::token_program = token_program,
     )]
-    pub token_b_vault: Box<Account<'info, TokenAccount>>,
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
@@ -162,19 +170,29 @@ impl<'info> DepositLiquidity<'info> {
         // Secure version: require!(lp_tokens > 0, AmmError::InsufficientLiquidity);
         // Impact: User could deposit tokens and receive 0 LP tokens due to rounding
 
+        // VULNERABILITY V015: Token-2022 transfer fees ignored
+        // Secure version grosses up: amount_a + calculate_inverse_transfer_fee(&token_a_mint, amount_a)
+        // Vulnerable version sends amount_a, so a fee mint credits the vault with amount_a - fee
+        // while the depositor is still minted LP tokens for the full amount_a
+        // Impact: Every fee-mint deposit dilutes existing LPs by the withheld fee
+
         // Transfer tokens to vaults using helper
         transfer_tokens(
             amount_a,
+            self.token_a_mint.decimals,
             &self.token_program.to_account_info(),
             &self.depositor_token_a.to_account_info(),
+            &self.token_a_mint.to_account_info(),
             &self.token_a_vault.to_account_info(),
             &self.depositor.to_account_info(),
         )?;
 
         transfer_tokens(
             amount_b,
+            self.token_b_mint.decimals,
             &self.token_program.to_account_info(),
             &self.depositor_token_b.to_account_info(),
+            &self.token_b_mint.to_account_info(),
             &self.token_b_vault.to_account_info(),
             &self.depositor.to_account_info(),
         )?;
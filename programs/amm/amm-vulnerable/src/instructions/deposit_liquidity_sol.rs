@@ -0,0 +1,228 @@
+// Deposit Liquidity SOL Instruction - VULNERABLE VERSION
+//
+// deposit_liquidity for pools with a wrapped SOL side, paid in native SOL.
+// The native side's amounts are in lamports.
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// VULNERABILITIES:
+// V026: Temporary wSOL account closed to an unchecked refund_receiver
+//       - max_amount is wrapped instead of what the deposit needs
+//       - The unspent wSOL and the account rent go to whoever refund_receiver is
+//       - The refund is never checked against the depositor's balance
+// Also inherits V002, V003, V007 and V010 from deposit_liquidity
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, helpers::*, state::*};
+
+#[derive(Accounts)]
+pub struct DepositLiquiditySol<'info> {
+    // User adding liquidity (pays the SOL side and any ATA rent)
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    // Pool configuration PDA
+    #[account(
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+
+    // Pool authority PDA (mint authority of the LP token)
+    /// CHECK: PDA signer, validated by seeds
+    #[account(
+        seeds = [AMM_AUTHORITY_SEED, pool_config.key().as_ref()],
+        bump = pool_config.authority_bump,
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LP_MINT_SEED, pool_config.key().as_ref()],
+        bump = pool_config.lp_mint_bump,
+        mint::authority = pool_authority,
+        mint::token_program = token_program,
+    )]
+    pub lp_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = pool_config.token_a_mint)]
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = pool_config.token_b_mint)]
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Depositor's token A ATA (created for the wSOL side)
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = token_a_mint,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program,
+    )]
+    pub depositor_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Depositor's token B ATA
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = token_b_mint,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program,
+    )]
+    pub depositor_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = lp_token_mint,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program,
+    )]
+    pub depositor_lp_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_a_mint,
+        token::authority = pool_authority,
+        token::token_program = token_program,
+    )]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_b_mint,
+        token::authority = pool_authority,
+        token::token_program = token_program,
+    )]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    // VULNERABILITY V026: Refund destination is never tied to the depositor
+    // Secure version has no such account - the wSOL ATA always closes to the depositor
+    // Attack: A front end or router building the transaction sets this to itself
+    //         and keeps the leftover wSOL plus the ATA rent
+    /// CHECK: Intentionally unchecked (vulnerable)
+    #[account(mut)]
+    pub refund_receiver: UncheckedAccount<'info>,
+}
+
+impl<'info> DepositLiquiditySol<'info> {
+    pub fn deposit_liquidity_sol(
+        &mut self,
+        desired_amount_a: u64,
+        desired_amount_b: u64,
+        max_amount_a: u64,
+        max_amount_b: u64,
+        _expiration: i64,
+    ) -> Result<()> {
+        // Lock, expiration, zero amount and slippage checks are skipped as in deposit_liquidity
+
+        let sol_is_token_a =
+            native_sol_is_token_a(&self.token_a_mint.key(), &self.token_b_mint.key())?;
+
+        let vault_a_balance = self.token_a_vault.amount;
+        let vault_b_balance = self.token_b_vault.amount;
+        let lp_supply = self.lp_token_mint.supply;
+
+        let (amount_a, amount_b, lp_tokens) = if lp_supply == 0 {
+            calculate_first_deposit(desired_amount_a, desired_amount_b)?
+        } else {
+            calculate_subsequent_deposit(
+                desired_amount_a,
+                desired_amount_b,
+                vault_a_balance,
+                vault_b_balance,
+                lp_supply,
+            )?
+        };
+
+        // VULNERABILITY V026: Wraps the depositor's slippage cap, not the deposit amount
+        // Secure version: wraps gross_amount_a / gross_amount_b exactly
+        // Impact: max_amount - amount is left in the wSOL account for the close to hand out
+        let (wrapped_sol_account, wrap_amount) = if sol_is_token_a {
+            (&self.depositor_token_a, max_amount_a)
+        } else {
+            (&self.depositor_token_b, max_amount_b)
+        };
+
+        // VULNERABILITY V026: No check that the wSOL ATA starts empty
+        // Secure version: require!(wrapped_sol_account.amount == 0, AmmError::WrappedSolAccountNotEmpty);
+        // Impact: wSOL the depositor already held is unwrapped and refunded with the rest
+        wrap_sol(
+            wrap_amount,
+            &self.token_program.to_account_info(),
+            &self.system_program.to_account_info(),
+            &self.depositor.to_account_info(),
+            &wrapped_sol_account.to_account_info(),
+        )?;
+
+        transfer_tokens(
+            amount_a,
+            self.token_a_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.depositor_token_a.to_account_info(),
+            &self.token_a_mint.to_account_info(),
+            &self.token_a_vault.to_account_info(),
+            &self.depositor.to_account_info(),
+        )?;
+
+        transfer_tokens(
+            amount_b,
+            self.token_b_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.depositor_token_b.to_account_info(),
+            &self.token_b_mint.to_account_info(),
+            &self.token_b_vault.to_account_info(),
+            &self.depositor.to_account_info(),
+        )?;
+
+        let pool_config_key = self.pool_config.key();
+        let authority_seeds = &[
+            AMM_AUTHORITY_SEED,
+            pool_config_key.as_ref(),
+            &[self.pool_config.authority_bump],
+        ];
+
+        mint_lp_tokens(
+            lp_tokens,
+            &self.token_program.to_account_info(),
+            &self.lp_token_mint.to_account_info(),
+            &self.depositor_lp_token.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        // VULNERABILITY V026: Closed to refund_receiver with no refund check
+        // Secure version: close_wrapped_sol(token_program, depositor, wrapped_sol_account)
+        //                 then requires the depositor's balance to rise by the full refund
+        close_wrapped_sol(
+            &self.token_program.to_account_info(),
+            &self.depositor.to_account_info(),
+            &self.refund_receiver.to_account_info(),
+            &wrapped_sol_account.to_account_info(),
+        )?;
+
+        msg!(
+            "Deposited: {} A, {} B -> {} LP ({} lamports wrapped)",
+            amount_a,
+            amount_b,
+            lp_tokens,
+            wrap_amount
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+// Initialize AMM Config Instruction - VULNERABLE VERSION
+//
+// Creates the optional global config that is supposed to make pool creation
+// permissioned. The config itself is created correctly; the bug is that
+// initialize_pool never checks that the config it receives is this account (V017).
+//
+// VULNERABILITIES:
+// - V001: Fee tiers not capped by MAX_FEE_BASIS_POINTS
+// - V017: Config is trivially bypassed in initialize_pool (see initialize_pool.rs)
+
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct InitializeAmmConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR + AmmConfig::INIT_SPACE,
+        seeds = [AMM_GLOBAL_CONFIG_SEED],
+        bump
+    )]
+    pub amm_config: Box<Account<'info, AmmConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeAmmConfig<'info> {
+    pub fn initialize_amm_config(
+        &mut self,
+        pool_creation_authority: Pubkey,
+        fee_tiers: Vec<u16>,
+        bumps: &InitializeAmmConfigBumps,
+    ) -> Result<()> {
+        // Bounded so the account size is fixed
+        require!(
+            !fee_tiers.is_empty() && fee_tiers.len() <= MAX_FEE_TIERS,
+            AmmError::InvalidFeeTierCount
+        );
+
+        // VULNERABILITY V001: Fee tiers not validated
+        // Secure version: require!(fee <= MAX_FEE_BASIS_POINTS) for every tier
+
+        self.amm_config.set_inner(AmmConfig {
+            admin: self.admin.key(),
+            pool_creation_authority,
+            fee_tiers,
+            bump: bumps.amm_config,
+        });
+
+        msg!("AMM config initialized: pool creator {}", pool_creation_authority);
+        msg!("Fee tiers: {:?}", self.amm_config.fee_tiers);
+
+        Ok(())
+    }
+}
@@ -1,22 +1,39 @@
 // Initialize Pool Instruction
 //
 // Creates a new AMM liquidity pool for a token pair.
+//
+// VULNERABILITIES:
+// - V017: Global AmmConfig is optional and accepted without owner or PDA checks
 
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::{constants::*, errors::*, state::*};
 
 #[derive(Accounts)]
+#[instruction(fee_basis_points: u16)]
 pub struct InitializePool<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
-    pub token_a_mint: Box<Account<'info, Mint>>,
-    pub token_b_mint: Box<Account<'info, Mint>>,
+    // VULNERABILITY V017: Config account is optional and completely unchecked
+    // Secure version: always required, pinned with seeds = [AMM_GLOBAL_CONFIG_SEED]
+    // and deserialized with Account::try_from (owner + discriminator checks)
+    // Attack: Omit the config entirely, or pass a forged account naming yourself
+    //         as pool_creation_authority with any fee tier you like
+    /// CHECK: Intentionally unchecked (vulnerable)
+    pub amm_config: Option<UncheckedAccount<'info>>,
+
+    // Both mints must be owned by the token program passed in
+    // (SPL Token or Token-2022), so a single program handles every CPI
+    #[account(mint::token_program = token_program)]
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mint::token_program = token_program)]
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         init,
@@ -26,6 +43,7 @@ pub struct InitializePool<'info> {
             AMM_CONFIG_SEED,
             token_a_mint.key().as_ref(),
             token_b_mint.key().as_ref(),
+            fee_basis_points.to_le_bytes().as_ref(),
         ],
         bump
     )]
@@ -45,26 +63,29 @@ pub struct InitializePool<'info> {
         bump,
         mint::decimals = 9,
         mint::authority = pool_authority,
+        mint::token_program = token_program,
     )]
-    pub lp_token_mint: Box<Account<'info, Mint>>,
+    pub lp_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         init,
         payer = authority,
         associated_token::mint = token_a_mint,
         associated_token::authority = pool_authority,
+        associated_token::token_program = token_program,
     )]
-    pub token_a_vault: Box<Account<'info, TokenAccount>>,
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         init,
         payer = authority,
         associated_token::mint = token_b_mint,
         associated_token::authority = pool_authority,
+        associated_token::token_program = token_program,
     )]
-    pub token_b_vault: Box<Account<'info, TokenAccount>>,
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
@@ -73,6 +94,7 @@ impl<'info> InitializePool<'info> {
     pub fn initialize_pool(
         &mut self,
         fee_basis_points: u16,
+        referral_fee_basis_points: u16,
         bumps: &InitializePoolBumps,
     ) -> Result<()> {
         // VULNERABILITY 1: No fee validation
@@ -85,6 +107,20 @@ impl<'info> InitializePool<'info> {
         // Vulnerable version: Allows creating SOL/SOL or USDC/USDC pools
         // Attack: Confuse users with nonsense pools
 
+        // Referral share is capped (same as secure version)
+        require!(
+            referral_fee_basis_points <= MAX_REFERRAL_FEE_BASIS_POINTS,
+            AmmError::ReferralFeeTooHigh
+        );
+
+        // VULNERABILITY V017: Config only enforced if the caller chooses to pass it,
+        // and its data is trusted without checking who owns the account
+        if let Some(amm_config_info) = &self.amm_config {
+            let data = amm_config_info.try_borrow_data()?;
+            let amm_config = AmmConfig::try_deserialize(&mut &data[..])?;
+            amm_config.assert_can_create_pool(&self.authority.key(), fee_basis_points)?;
+        }
+
         // Initialize pool configuration
         self.pool_config.set_inner(PoolConfig {
             authority: self.authority.key(),
@@ -96,6 +132,22 @@ impl<'info> InitializePool<'info> {
             config_bump: bumps.pool_config,
             authority_bump: bumps.pool_authority,
             lp_mint_bump: bumps.lp_token_mint,
+            flash_loan_active: false,
+            flash_loan_mint: Pubkey::default(),
+            flash_loan_vault_balance_before: 0,
+            flash_loan_fee: 0,
+            pending_fee_basis_points: 0,
+            fee_change_unlock_time: 0,
+            fee_change_delay_seconds: FEE_CHANGE_DELAY_SECONDS,
+            emergency_withdraw_enabled: false,
+            referral_fee_basis_points,
+            oracle_program: Pubkey::default(),
+            price_feed: Pubkey::default(),
+            max_oracle_deviation_bps: 0,
+            max_price_impact_bps: 0,
+            circuit_breaker_auto_lock: false,
+            max_swap_per_slot_bps: 0,
+            fee_tier: fee_basis_points,
         });
 
         msg!(
@@ -103,7 +155,11 @@ impl<'info> InitializePool<'info> {
             self.token_a_mint.key(),
             self.token_b_mint.key()
         );
-        msg!("Fee: {} basis points", fee_basis_points);
+        msg!(
+            "Fee: {} basis points ({} to referrers)",
+            fee_basis_points,
+            referral_fee_basis_points
+        );
 
         Ok(())
     }
@@ -0,0 +1,348 @@
+// Migrate Liquidity Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// Moves an LP position from one pool to another pool of the same token pair
+// (a different fee tier): source LP is burned, the withdrawal is paid straight
+// into the destination vaults, and destination LP is minted.
+//
+// VULNERABILITIES:
+// V028: Destination pool never checked against the source pair - one side of
+//       the migration lands in a stray account the destination never reads
+// V015: Token-2022 transfer fees ignored, as in deposit_liquidity
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, errors::*, helpers::*, state::*};
+
+#[derive(Accounts)]
+pub struct MigrateLiquidity<'info> {
+    // LP moving between pools (pays for ATA creation if needed)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Pool the position leaves
+    #[account(
+        seeds = [
+            AMM_CONFIG_SEED,
+            source_pool_config.token_a_mint.as_ref(),
+            source_pool_config.token_b_mint.as_ref(),
+            source_pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = source_pool_config.config_bump,
+    )]
+    pub source_pool_config: Box<Account<'info, PoolConfig>>,
+
+    /// CHECK: PDA signer for the source vaults
+    #[account(
+        seeds = [AMM_AUTHORITY_SEED, source_pool_config.key().as_ref()],
+        bump = source_pool_config.authority_bump,
+    )]
+    pub source_pool_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LP_MINT_SEED, source_pool_config.key().as_ref()],
+        bump = source_pool_config.lp_mint_bump,
+    )]
+    pub source_lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Pool the position joins
+    // VULNERABILITY V028: never checked against the source pair (see handler)
+    #[account(
+        seeds = [
+            AMM_CONFIG_SEED,
+            dest_pool_config.token_a_mint.as_ref(),
+            dest_pool_config.token_b_mint.as_ref(),
+            dest_pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = dest_pool_config.config_bump,
+    )]
+    pub dest_pool_config: Box<Account<'info, PoolConfig>>,
+
+    /// CHECK: PDA signer for the destination LP mint
+    #[account(
+        seeds = [AMM_AUTHORITY_SEED, dest_pool_config.key().as_ref()],
+        bump = dest_pool_config.authority_bump,
+    )]
+    pub dest_pool_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LP_MINT_SEED, dest_pool_config.key().as_ref()],
+        bump = dest_pool_config.lp_mint_bump,
+        mint::authority = dest_pool_authority,
+        mint::token_program = token_program,
+    )]
+    pub dest_lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Token A and B of the source pool
+    #[account(address = source_pool_config.token_a_mint)]
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = source_pool_config.token_b_mint)]
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = source_lp_mint,
+        token::authority = user,
+        token::token_program = token_program,
+    )]
+    pub user_source_lp: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = dest_lp_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_dest_lp: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Receive whatever the destination ratio does not take
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_a_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = token_b_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_a_mint,
+        associated_token::authority = source_pool_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub source_vault_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_b_mint,
+        associated_token::authority = source_pool_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub source_vault_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Destination vaults, named by mint: these are the destination's B and A
+    // vaults when its mints are in reverse order
+    #[account(
+        mut,
+        associated_token::mint = token_a_mint,
+        associated_token::authority = dest_pool_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub dest_vault_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_b_mint,
+        associated_token::authority = dest_pool_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub dest_vault_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateLiquidity<'info> {
+    pub fn migrate_liquidity(
+        &mut self,
+        lp_amount: u64,
+        min_lp_out: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        // Both pools must be unlocked
+        self.source_pool_config.assert_not_locked()?;
+        self.dest_pool_config.assert_not_locked()?;
+
+        // Validate expiration using helper
+        validate_expiration(expiration)?;
+
+        // Destination must be a different pool
+        require_keys_neq!(
+            self.dest_pool_config.key(),
+            self.source_pool_config.key(),
+            AmmError::InvalidMigrationPool
+        );
+
+        // VULNERABILITY V028: Destination pool's mints never compared with the source pair
+        //
+        // The destination vaults are only required to be the destination authority's
+        // ATAs for token A and token B. If the destination pool trades A against some
+        // other token C, its "B vault" is an ATA anyone can create and fund, which the
+        // pool never prices, pays out, or counts as a reserve.
+        // Secure version:
+        //   require!(self.dest_pool_config.is_pool_mint(&self.token_a_mint.key())
+        //            && self.dest_pool_config.is_pool_mint(&self.token_b_mint.key()),
+        //            AmmError::InvalidMigrationPool);
+        //
+        // Example Attack (one-sided entry into an A/C pool):
+        //   1. Attacker creates the A/C pool authority's ATA for B and sends it 1 B
+        //   2. Attacker migrates an A/B position into the A/C pool
+        //   3. The stray 1 B "reserve" makes the B side look scarce, so LP is priced
+        //      on A alone and almost all of the B is refunded
+        //   4. Attacker withdraws from A/C: their A back plus C they never deposited
+        //
+        // Fix: Require both source mints to be mints of the destination pool
+
+        // Check non-zero LP amount
+        require!(lp_amount > 0, AmmError::ZeroWithdrawAmount);
+        require!(self.user_source_lp.amount >= lp_amount, AmmError::InsufficientBalance);
+
+        // Withdraw side, priced on the source reserves
+        let source_lp_supply = self.source_lp_mint.supply;
+        require!(source_lp_supply > 0, AmmError::InsufficientLiquidity);
+
+        let (amount_a, amount_b) = calculate_withdrawal(
+            lp_amount,
+            self.source_vault_a.amount,
+            self.source_vault_b.amount,
+            source_lp_supply,
+        )?;
+        require!(amount_a > 0, AmmError::InsufficientLiquidity);
+        require!(amount_b > 0, AmmError::InsufficientLiquidity);
+
+        // Deposit side, priced on the destination reserves
+        let dest_lp_supply = self.dest_lp_mint.supply;
+        let (deposit_a, deposit_b, lp_out) = if dest_lp_supply == 0 {
+            calculate_first_deposit(amount_a, amount_b)?
+        } else {
+            calculate_subsequent_deposit(
+                amount_a,
+                amount_b,
+                self.dest_vault_a.amount,
+                self.dest_vault_b.amount,
+                dest_lp_supply,
+            )?
+        };
+
+        // Slippage protection on the destination LP
+        require!(lp_out > 0, AmmError::InsufficientLiquidity);
+        require!(lp_out >= min_lp_out, AmmError::SlippageExceeded);
+
+        // VULNERABILITY V015: Token-2022 transfer fees ignored
+        // Secure version prices the deposit on amount - fee and grosses it back up,
+        // vulnerable version mints destination LP for tokens the vaults never received
+
+        // The rest of the withdrawal goes back to the user
+        let refund_a = amount_a
+            .checked_sub(deposit_a)
+            .ok_or(AmmError::Underflow)?;
+        let refund_b = amount_b
+            .checked_sub(deposit_b)
+            .ok_or(AmmError::Underflow)?;
+
+        // Burn source LP using helper
+        burn_lp_tokens(
+            lp_amount,
+            &self.token_program.to_account_info(),
+            &self.source_lp_mint.to_account_info(),
+            &self.user_source_lp.to_account_info(),
+            &self.user.to_account_info(),
+        )?;
+
+        let source_pool_key = self.source_pool_config.key();
+        let source_seeds = &[
+            AMM_AUTHORITY_SEED,
+            source_pool_key.as_ref(),
+            &[self.source_pool_config.authority_bump],
+        ];
+
+        // Source vaults -> destination vaults
+        transfer_from_vault(
+            deposit_a,
+            self.token_a_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.source_vault_a.to_account_info(),
+            &self.token_a_mint.to_account_info(),
+            &self.dest_vault_a.to_account_info(),
+            &self.source_pool_authority.to_account_info(),
+            source_seeds,
+        )?;
+
+        transfer_from_vault(
+            deposit_b,
+            self.token_b_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.source_vault_b.to_account_info(),
+            &self.token_b_mint.to_account_info(),
+            &self.dest_vault_b.to_account_info(),
+            &self.source_pool_authority.to_account_info(),
+            source_seeds,
+        )?;
+
+        // Source vaults -> user, for the side the destination ratio left over
+        if refund_a > 0 {
+            transfer_from_vault(
+                refund_a,
+                self.token_a_mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.source_vault_a.to_account_info(),
+                &self.token_a_mint.to_account_info(),
+                &self.user_token_a.to_account_info(),
+                &self.source_pool_authority.to_account_info(),
+                source_seeds,
+            )?;
+        }
+
+        if refund_b > 0 {
+            transfer_from_vault(
+                refund_b,
+                self.token_b_mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.source_vault_b.to_account_info(),
+                &self.token_b_mint.to_account_info(),
+                &self.user_token_b.to_account_info(),
+                &self.source_pool_authority.to_account_info(),
+                source_seeds,
+            )?;
+        }
+
+        // Mint destination LP using helper
+        let dest_pool_key = self.dest_pool_config.key();
+        let dest_seeds = &[
+            AMM_AUTHORITY_SEED,
+            dest_pool_key.as_ref(),
+            &[self.dest_pool_config.authority_bump],
+        ];
+
+        mint_lp_tokens(
+            lp_out,
+            &self.token_program.to_account_info(),
+            &self.dest_lp_mint.to_account_info(),
+            &self.user_dest_lp.to_account_info(),
+            &self.dest_pool_authority.to_account_info(),
+            dest_seeds,
+        )?;
+
+        msg!(
+            "Migrated: {} LP -> {} A, {} B -> {} LP ({} A, {} B refunded)",
+            lp_amount,
+            deposit_a,
+            deposit_b,
+            lp_out,
+            refund_a,
+            refund_b
+        );
+
+        Ok(())
+    }
+}
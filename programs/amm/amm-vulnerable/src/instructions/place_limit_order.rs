@@ -0,0 +1,191 @@
+// Place Limit Order Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// VULNERABILITIES:
+// V003: No expiration validation - expires_at is not bounded (see helpers.rs)
+// V027: The limit stored here is never enforced (see fill_limit_order.rs)
+//
+//
+// Escrows input tokens in a LimitOrder PDA to be swapped against the pool for at
+// least min_output_amount, once the pool price makes that possible. Any keeper
+// can fill the order with fill_limit_order and is paid keeper_tip lamports for it.
+//
+// HOW IT WORKS:
+// 1. A LimitOrder PDA and its escrow (the order's ATA for input_mint) are created
+// 2. input_amount moves from the owner to the escrow
+// 3. keeper_tip lamports move from the owner to the LimitOrder PDA
+// 4. The owner's output ATA is created if needed, so a fill never pays for it
+//
+// The order records the escrow balance, which is below input_amount for a
+// transfer fee mint.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, errors::*, helpers::*, state::*};
+
+#[derive(Accounts)]
+#[instruction(order_id: u64)]
+pub struct PlaceLimitOrder<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+
+    // Token sold - must be token A or token B of this pool
+    #[account(
+        constraint = pool_config.is_pool_mint(&input_mint.key()) @ AmmError::InvalidMint,
+        mint::token_program = token_program,
+    )]
+    pub input_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Token bought - the other pool mint
+    #[account(
+        constraint = pool_config.is_pool_mint(&output_mint.key()) @ AmmError::InvalidMint,
+        constraint = output_mint.key() != input_mint.key() @ AmmError::IdenticalTokenMints,
+        mint::token_program = token_program,
+    )]
+    pub output_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Limit order PDA
+    // Seeds: ["limit_order", pool_config, owner, order_id]
+    #[account(
+        init,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR + LimitOrder::INIT_SPACE,
+        seeds = [
+            LIMIT_ORDER_SEED,
+            pool_config.key().as_ref(),
+            owner.key().as_ref(),
+            &order_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub limit_order: Box<Account<'info, LimitOrder>>,
+
+    // Escrow holding the input tokens until the order is filled or cancelled
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = input_mint,
+        associated_token::authority = limit_order,
+        associated_token::token_program = token_program,
+    )]
+    pub order_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = input_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_input_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Receives the output on fill
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = output_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_output_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PlaceLimitOrder<'info> {
+    pub fn place_limit_order(
+        &mut self,
+        order_id: u64,
+        input_amount: u64,
+        min_output_amount: u64,
+        keeper_tip: u64,
+        expires_at: i64,
+        bumps: &PlaceLimitOrderBumps,
+    ) -> Result<()> {
+        // Check pool not locked
+        self.pool_config.assert_not_locked()?;
+
+        // VULNERABILITY V003: validate_expiration does nothing
+        validate_expiration(expires_at)?;
+
+        // Check non-zero amounts
+        // A zero limit would let a keeper fill the order at any price
+        require!(input_amount > 0, AmmError::ZeroSwapAmount);
+        require!(min_output_amount > 0, AmmError::SlippageExceeded);
+        require!(
+            self.owner_input_token.amount >= input_amount,
+            AmmError::InsufficientBalance
+        );
+
+        // Escrow the input tokens
+        transfer_tokens(
+            input_amount,
+            self.input_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.owner_input_token.to_account_info(),
+            &self.input_mint.to_account_info(),
+            &self.order_escrow.to_account_info(),
+            &self.owner.to_account_info(),
+        )?;
+
+        // Record what the escrow received after any transfer fee
+        self.order_escrow.reload()?;
+        let escrowed_amount = self.order_escrow.amount;
+        require!(escrowed_amount > 0, AmmError::ZeroSwapAmount);
+
+        // Hold the keeper tip on the order account, on top of its rent
+        if keeper_tip > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.owner.to_account_info(),
+                        to: self.limit_order.to_account_info(),
+                    },
+                ),
+                keeper_tip,
+            )?;
+        }
+
+        self.limit_order.set_inner(LimitOrder {
+            owner: self.owner.key(),
+            pool_config: self.pool_config.key(),
+            input_mint: self.input_mint.key(),
+            output_mint: self.output_mint.key(),
+            order_id,
+            input_amount: escrowed_amount,
+            min_output_amount,
+            keeper_tip,
+            expires_at,
+            bump: bumps.limit_order,
+        });
+
+        msg!(
+            "Limit order {} placed: {} in for at least {} out, {} lamport tip",
+            order_id,
+            escrowed_amount,
+            min_output_amount,
+            keeper_tip
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+// Request Fee Change Instruction - VULNERABLE VERSION
+//
+// First step of the two-step fee change. Queues a new swap fee and records an
+// unlock time - which change_pool_fee then ignores (V019).
+//
+// VULNERABILITIES:
+// - V001: New fee not validated against MAX_FEE_BASIS_POINTS
+// - V019: Timelock recorded but never enforced (see change_pool_fee.rs)
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, state::*};
+
+#[derive(Accounts)]
+pub struct RequestFeeChange<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+}
+
+impl<'info> RequestFeeChange<'info> {
+    pub fn request_fee_change(&mut self, new_fee_basis_points: u16) -> Result<()> {
+        // Validate authority
+        self.pool_config.assert_is_authority(&self.authority.key())?;
+
+        // VULNERABILITY V001: No fee validation
+        // Secure version: require!(new_fee_basis_points <= MAX_FEE_BASIS_POINTS)
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let unlock_time = current_time + self.pool_config.fee_change_delay_seconds;
+
+        self.pool_config.pending_fee_basis_points = new_fee_basis_points;
+        self.pool_config.fee_change_unlock_time = unlock_time;
+
+        msg!(
+            "Fee change requested: {} -> {} bp, executable after {}",
+            self.pool_config.fee_basis_points,
+            new_fee_basis_points,
+            unlock_time
+        );
+
+        Ok(())
+    }
+}
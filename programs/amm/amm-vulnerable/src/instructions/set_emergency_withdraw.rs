@@ -0,0 +1,38 @@
+// Set Emergency Withdraw Instruction
+//
+// Enables or disables emergency_withdraw for a pool. Only pool authority can toggle.
+//
+// The flag is stored correctly, but emergency_withdraw never reads it (V020).
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetEmergencyWithdraw<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+}
+
+impl<'info> SetEmergencyWithdraw<'info> {
+    pub fn set_emergency_withdraw(&mut self, enabled: bool) -> Result<()> {
+        // Validate authority
+        self.pool_config.assert_is_authority(&self.authority.key())?;
+
+        self.pool_config.emergency_withdraw_enabled = enabled;
+
+        msg!("Emergency withdraw enabled: {}", enabled);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+// Set Oracle Guard Instruction
+//
+// Pins the oracle feed a pool is checked against and the maximum deviation
+// between the post-swap pool price and the oracle price. Only pool authority can set.
+//
+// Setting max_oracle_deviation_bps to 0 disables the guard.
+//
+// The configuration is validated correctly, but the guard reads the feed
+// without checking staleness (V022, see state/price_feed.rs).
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetOracleGuard<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+}
+
+impl<'info> SetOracleGuard<'info> {
+    pub fn set_oracle_guard(
+        &mut self,
+        oracle_program: Pubkey,
+        price_feed: Pubkey,
+        max_oracle_deviation_bps: u16,
+    ) -> Result<()> {
+        // Validate authority
+        self.pool_config.assert_is_authority(&self.authority.key())?;
+
+        require!(
+            max_oracle_deviation_bps <= MAX_ORACLE_DEVIATION_BASIS_POINTS,
+            AmmError::InvalidOracleConfig
+        );
+
+        // An enabled guard needs a real feed to check against
+        if max_oracle_deviation_bps > 0 {
+            require!(
+                oracle_program != Pubkey::default() && price_feed != Pubkey::default(),
+                AmmError::InvalidOracleConfig
+            );
+        }
+
+        self.pool_config.oracle_program = oracle_program;
+        self.pool_config.price_feed = price_feed;
+        self.pool_config.max_oracle_deviation_bps = max_oracle_deviation_bps;
+
+        msg!(
+            "Oracle guard: feed {} max deviation {} basis points",
+            price_feed,
+            max_oracle_deviation_bps
+        );
+
+        Ok(())
+    }
+}
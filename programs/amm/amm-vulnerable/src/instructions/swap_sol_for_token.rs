@@ -0,0 +1,86 @@
+// Swap SOL For Token Instruction - VULNERABLE VERSION
+//
+// Exact-input swap out of the wrapped SOL side of a pool, paid in native SOL.
+// input_amount is in lamports. Everything else is the swap_tokens path.
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// VULNERABILITIES:
+// V026: Temporary wSOL account closed to an unchecked refund_receiver
+//       - The ATA rent goes to whoever refund_receiver is
+//       - Any wSOL already in the ATA is unwrapped and sent along with it
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{helpers::*, instructions::SwapTokens};
+
+#[derive(Accounts)]
+pub struct SwapSolForToken<'info> {
+    pub swap: SwapTokens<'info>,
+
+    // VULNERABILITY V026: Refund destination is never tied to the swapper
+    // Secure version has no such account - the wSOL ATA always closes to the swapper
+    /// CHECK: Intentionally unchecked (vulnerable)
+    #[account(mut)]
+    pub refund_receiver: UncheckedAccount<'info>,
+}
+
+impl<'info> SwapSolForToken<'info> {
+    pub fn swap_sol_for_token(
+        &mut self,
+        input_amount: u64,
+        min_output_amount: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        // SOL goes in, so the wSOL side is the input side
+        let swap_token_a_for_b = native_sol_is_token_a(
+            &self.swap.token_a_mint.key(),
+            &self.swap.token_b_mint.key(),
+        )?;
+
+        // VULNERABILITY V026: No check that the wSOL ATA starts empty
+        // Secure version: require!(wrapped_sol_account.amount == 0, AmmError::WrappedSolAccountNotEmpty);
+        let wrapped_sol_info = self.wrapped_sol_account(swap_token_a_for_b).to_account_info();
+
+        wrap_sol(
+            input_amount,
+            &self.swap.token_program.to_account_info(),
+            &self.swap.system_program.to_account_info(),
+            &self.swap.swapper.to_account_info(),
+            &wrapped_sol_info,
+        )?;
+
+        if swap_token_a_for_b {
+            self.swap.swapper_token_a.reload()?;
+        } else {
+            self.swap.swapper_token_b.reload()?;
+        }
+
+        let fee_basis_points = self.swap.pool_config.fee_basis_points;
+        self.swap.swap_exact_in(
+            swap_token_a_for_b,
+            input_amount,
+            min_output_amount,
+            expiration,
+            fee_basis_points,
+        )?;
+
+        // VULNERABILITY V026: Closed to refund_receiver with no refund check
+        close_wrapped_sol(
+            &self.swap.token_program.to_account_info(),
+            &self.swap.swapper.to_account_info(),
+            &self.refund_receiver.to_account_info(),
+            &wrapped_sol_info,
+        )
+    }
+
+    // The swapper's token account for the wSOL side
+    fn wrapped_sol_account(&self, swap_token_a_for_b: bool) -> &InterfaceAccount<'info, TokenAccount> {
+        if swap_token_a_for_b {
+            &self.swap.swapper_token_a
+        } else {
+            &self.swap.swapper_token_b
+        }
+    }
+}
@@ -8,11 +8,18 @@
 // V007: No pool lock enforcement - swaps work even when pool is locked
 // V010: No zero amount checks - wastes gas
 // V011: No liquidity checks before operations - may fail ungracefully
+// V015: Token-2022 transfer fees ignored - swaps priced on tokens the vault never received
+// V018: Exact-output input rounded down - small outputs cost nothing (see helpers.rs)
+// V021: Referrer can be a pool vault - referral "payouts" never leave the pool
+// V022: Oracle guard accepts stale prices (see state/price_feed.rs)
+// V024: Fee rebate LP balance read from any account (see swap_with_rebate.rs)
+// V025: Circuit breaker never enforced - one giant swap can drain the output vault
+// V029: Per-slot swap limit never enforced - sandwiches fit in a single block
 
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount, Transfer, transfer},
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked},
 };
 use constant_product_curve::{ConstantProduct, LiquidityPair};
 
@@ -28,6 +35,7 @@ pub struct SwapTokens<'info> {
             AMM_CONFIG_SEED,
             pool_config.token_a_mint.as_ref(),
             pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
         ],
         bump = pool_config.config_bump,
     )]
@@ -41,44 +49,56 @@ pub struct SwapTokens<'info> {
     pub pool_authority: UncheckedAccount<'info>,
 
     #[account(address = pool_config.token_a_mint)]
-    pub token_a_mint: Box<Account<'info, Mint>>,
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(address = pool_config.token_b_mint)]
-    pub token_b_mint: Box<Account<'info, Mint>>,
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         init_if_needed,
         payer = swapper,
         associated_token::mint = token_a_mint,
         associated_token::authority = swapper,
+        associated_token::token_program = token_program,
     )]
-    pub swapper_token_a: Box<Account<'info, TokenAccount>>,
+    pub swapper_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         init_if_needed,
         payer = swapper,
         associated_token::mint = token_b_mint,
         associated_token::authority = swapper,
+        associated_token::token_program = token_program,
     )]
-    pub swapper_token_b: Box<Account<'info, TokenAccount>>,
+    pub swapper_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
         token::mint = token_a_mint,
         token::authority = pool_authority,
+        token::token_program = token_program,
     )]
-    pub token_a_vault: Box<Account<'info, TokenAccount>>,
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
         token::mint = token_b_mint,
         token::authority = pool_authority,
+        token::token_program = token_program,
     )]
-    pub token_b_vault: Box<Account<'info, TokenAccount>>,
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+
+    // Optional referrer token account (input mint) paid a slice of the swap fee
+    #[account(mut)]
+    pub referrer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    // Optional oracle feed, required when the pool has an oracle guard
+    /// CHECK: Matched against pool_config.price_feed, owner and layout checked on read
+    pub price_feed: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> SwapTokens<'info> {
@@ -88,6 +108,26 @@ impl<'info> SwapTokens<'info> {
         input_amount: u64,
         min_output_amount: u64,
         expiration: i64,
+    ) -> Result<()> {
+        let fee_basis_points = self.pool_config.fee_basis_points;
+        self.swap_exact_in(
+            swap_token_a_for_b,
+            input_amount,
+            min_output_amount,
+            expiration,
+            fee_basis_points,
+        )
+    }
+
+    // Exact-input swap priced with fee_basis_points
+    // swap_tokens passes the pool fee, swap_with_rebate the rebated one
+    pub fn swap_exact_in(
+        &mut self,
+        swap_token_a_for_b: bool,
+        input_amount: u64,
+        min_output_amount: u64,
+        expiration: i64,
+        fee_basis_points: u16,
     ) -> Result<()> {
         // VULNERABILITY V007: No pool lock enforcement
         // Secure version: self.pool_config.assert_not_locked()?;
@@ -113,12 +153,18 @@ impl<'info> SwapTokens<'info> {
         // Secure version: require!(vault_b_balance > 0, AmmError::InsufficientPoolLiquidity);
         // Impact: Operations may fail ungracefully if pool is empty
 
+        // VULNERABILITY V015: Token-2022 transfer fees ignored
+        // Secure version prices the curve on input_amount - calculate_transfer_fee(input_mint, input_amount)
+        // Vulnerable version prices it on the gross input_amount
+        // Attack: With a 10% transfer fee mint, the vault receives 90% of the input but pays out
+        // as if it received 100%. Repeated swaps bleed the difference out of the LPs.
+
         // Initialize constant product curve
         let mut curve = ConstantProduct::init(
             vault_a_balance,
             vault_b_balance,
             vault_a_balance,
-            self.pool_config.fee_basis_points,
+            fee_basis_points,
             None,
         )
         .map_err(|_| AmmError::CurveCalculationFailed)?;
@@ -162,17 +208,105 @@ impl<'info> SwapTokens<'info> {
             AmmError::InsufficientPoolLiquidity
         );
 
+        // VULNERABILITY V025: Circuit breaker never enforced
+        // The pool authority can set max_price_impact_bps, but no swap reads it.
+        // Secure version computes the swap's price impact before any transfer:
+        //   calculate_price_impact_bps(input_reserve, output_reserve, deposit, withdraw)
+        // and rejects the swap (or locks the pool) when it exceeds the limit.
+        // Attack scenario:
+        // 1. Token A collapses on the market (or the attacker mints a pile of it)
+        // 2. Attacker dumps it into the pool in a single swap
+        // 3. The curve pays out nearly the whole token B vault at once,
+        //    long before anyone can lock the pool
+
+        // VULNERABILITY V029: Per-slot swap limit never enforced
+        // The pool authority can set max_swap_per_slot_bps, but no swap records
+        // or checks the volume paid into each vault during the slot.
+        // Secure version tracks input per slot in a ring buffer on the pool:
+        //   pool_config.record_swap_volume(slot, swap_token_a_for_b, net_input, reserve_a, reserve_b)
+        // and rejects the swap once the slot's input exceeds the limit.
+        // Attack scenario:
+        // 1. Attacker sees a victim's swap A -> B pending in the block
+        // 2. Front-runs with a swap A -> B far larger than the per-slot cap
+        // 3. Victim's swap fills at the worse price
+        // 4. Attacker sells the B back in the same slot and keeps the difference
+
         // Perform swap transfers
         if swap_token_a_for_b {
             self.deposit_token_a(swap_result.deposit)?;
+            self.pay_referrer(swap_token_a_for_b, swap_result.deposit, fee_basis_points)?;
             self.withdraw_token_b(swap_result.withdraw)?;
             msg!("Swapped {} A -> {} B", swap_result.deposit, swap_result.withdraw);
         } else {
             self.deposit_token_b(swap_result.deposit)?;
+            self.pay_referrer(swap_token_a_for_b, swap_result.deposit, fee_basis_points)?;
             self.withdraw_token_a(swap_result.withdraw)?;
             msg!("Swapped {} B -> {} A", swap_result.deposit, swap_result.withdraw);
         }
 
+        // Post-swap pool price must stay close to the oracle
+        self.check_oracle_guard()?;
+
+        Ok(())
+    }
+
+    pub fn swap_tokens_exact_out(
+        &mut self,
+        swap_token_a_for_b: bool,
+        max_input_amount: u64,
+        output_amount: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        // VULNERABILITY V007: No pool lock enforcement
+        // Secure version: self.pool_config.assert_not_locked()?;
+
+        // VULNERABILITY V003: No expiration validation (helper is a no-op)
+        validate_expiration(expiration)?;
+
+        // VULNERABILITY V010: No zero amount checks
+        // Secure version: require!(output_amount > 0, AmmError::ZeroSwapAmount);
+
+        let vault_a_balance = self.token_a_vault.amount;
+        let vault_b_balance = self.token_b_vault.amount;
+
+        let (input_reserve, output_reserve) = if swap_token_a_for_b {
+            (vault_a_balance, vault_b_balance)
+        } else {
+            (vault_b_balance, vault_a_balance)
+        };
+
+        // VULNERABILITY V018: Required input rounded down (in the user's favor)
+        // Secure version rounds both the curve and fee steps up
+        // Attack: Request tiny outputs whose true cost is < 1 input token; the
+        // rounded-down cost is 0 and the swapper receives tokens for free
+        let fee_basis_points = self.pool_config.fee_basis_points;
+        let input_amount = calculate_exact_out_input(
+            input_reserve,
+            output_reserve,
+            output_amount,
+            fee_basis_points,
+        )?;
+        // Missing: require!(input_amount > 0, AmmError::InvalidCurveParams);
+
+        // Slippage protection
+        require!(input_amount <= max_input_amount, AmmError::SlippageExceeded);
+
+        // Perform swap transfers
+        if swap_token_a_for_b {
+            self.deposit_token_a(input_amount)?;
+            self.pay_referrer(swap_token_a_for_b, input_amount, fee_basis_points)?;
+            self.withdraw_token_b(output_amount)?;
+            msg!("Swapped {} A -> exactly {} B", input_amount, output_amount);
+        } else {
+            self.deposit_token_b(input_amount)?;
+            self.pay_referrer(swap_token_a_for_b, input_amount, fee_basis_points)?;
+            self.withdraw_token_a(output_amount)?;
+            msg!("Swapped {} B -> exactly {} A", input_amount, output_amount);
+        }
+
+        // Post-swap pool price must stay close to the oracle
+        self.check_oracle_guard()?;
+
         Ok(())
     }
 
@@ -186,31 +320,130 @@ impl<'info> SwapTokens<'info> {
     //     Ok(())
     // }
 
+    // Pay the referrer's slice of the swap fee out of the input vault
+    // No-op when no referrer account is passed
+    fn pay_referrer(
+        &self,
+        swap_token_a_for_b: bool,
+        input_amount: u64,
+        fee_basis_points: u16,
+    ) -> Result<()> {
+        let referrer = match &self.referrer_token_account {
+            Some(referrer) => referrer,
+            None => return Ok(()),
+        };
+
+        let (input_mint, input_vault) = if swap_token_a_for_b {
+            (&self.token_a_mint, &self.token_a_vault)
+        } else {
+            (&self.token_b_mint, &self.token_b_vault)
+        };
+
+        // Referral is paid in the input token
+        require_keys_eq!(referrer.mint, input_mint.key(), AmmError::InvalidReferrer);
+
+        // VULNERABILITY V021: Referrer may be a pool vault
+        // Secure version: require_keys_neq!(referrer.owner, self.pool_authority.key(), AmmError::InvalidReferrer);
+        // Attack: Pass the input vault as referrer - the payout becomes a vault-to-vault
+        //         self-transfer that is logged as paid but never leaves the pool
+        // Impact: Referral accounting (logs, indexers, off-chain rewards) records payouts
+        //         that never happened, while the "paid" fee silently stays in reserves
+
+        let referral_amount = calculate_referral_fee(
+            input_amount,
+            fee_basis_points,
+            self.pool_config.referral_fee_basis_points,
+        )?;
+        if referral_amount == 0 {
+            return Ok(());
+        }
+
+        let pool_config_key = self.pool_config.key();
+        let authority_seeds = &[
+            AMM_AUTHORITY_SEED,
+            pool_config_key.as_ref(),
+            &[self.pool_config.authority_bump],
+        ];
+
+        transfer_from_vault(
+            referral_amount,
+            input_mint.decimals,
+            &self.token_program.to_account_info(),
+            &input_vault.to_account_info(),
+            &input_mint.to_account_info(),
+            &referrer.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        msg!("Referral fee paid: {} to {}", referral_amount, referrer.key());
+
+        Ok(())
+    }
+
+    // Reject the swap if it left the pool price too far from the oracle price
+    // No-op when the pool has no oracle guard
+    fn check_oracle_guard(&mut self) -> Result<()> {
+        if !self.pool_config.has_oracle_guard() {
+            return Ok(());
+        }
+
+        let feed = self.price_feed.as_ref().ok_or(AmmError::PriceFeedRequired)?;
+        require_keys_eq!(feed.key(), self.pool_config.price_feed, AmmError::InvalidPriceFeed);
+
+        let price_feed =
+            PriceFeed::load(&feed.to_account_info(), &self.pool_config.oracle_program)?;
+        price_feed.assert_usable(Clock::get()?.unix_timestamp)?;
+
+        // Compare against the reserves the swap left behind
+        self.token_a_vault.reload()?;
+        self.token_b_vault.reload()?;
+
+        let deviation_bps = calculate_oracle_deviation_bps(
+            self.token_a_vault.amount,
+            self.token_b_vault.amount,
+            self.token_a_mint.decimals,
+            self.token_b_mint.decimals,
+            price_feed.price,
+            price_feed.exponent,
+        )?;
+        require!(
+            deviation_bps <= self.pool_config.max_oracle_deviation_bps as u128,
+            AmmError::OraclePriceDeviation
+        );
+
+        Ok(())
+    }
+
     fn deposit_token_a(&self, amount: u64) -> Result<()> {
-        transfer(
+        transfer_checked(
             CpiContext::new(
                 self.token_program.to_account_info(),
-                Transfer {
+                TransferChecked {
                     from: self.swapper_token_a.to_account_info(),
+                    mint: self.token_a_mint.to_account_info(),
                     to: self.token_a_vault.to_account_info(),
                     authority: self.swapper.to_account_info(),
                 },
             ),
             amount,
+            self.token_a_mint.decimals,
         )
     }
 
     fn deposit_token_b(&self, amount: u64) -> Result<()> {
-        transfer(
+        transfer_checked(
             CpiContext::new(
                 self.token_program.to_account_info(),
-                Transfer {
+                TransferChecked {
                     from: self.swapper_token_b.to_account_info(),
+                    mint: self.token_b_mint.to_account_info(),
                     to: self.token_b_vault.to_account_info(),
                     authority: self.swapper.to_account_info(),
                 },
             ),
             amount,
+            self.token_b_mint.decimals,
         )
     }
 
@@ -223,17 +456,19 @@ impl<'info> SwapTokens<'info> {
         ];
         let signer_seeds = &[&authority_seeds[..]];
 
-        transfer(
+        transfer_checked(
             CpiContext::new_with_signer(
                 self.token_program.to_account_info(),
-                Transfer {
+                TransferChecked {
                     from: self.token_a_vault.to_account_info(),
+                    mint: self.token_a_mint.to_account_info(),
                     to: self.swapper_token_a.to_account_info(),
                     authority: self.pool_authority.to_account_info(),
                 },
                 signer_seeds,
             ),
             amount,
+            self.token_a_mint.decimals,
         )
     }
 
@@ -246,17 +481,19 @@ impl<'info> SwapTokens<'info> {
         ];
         let signer_seeds = &[&authority_seeds[..]];
 
-        transfer(
+        transfer_checked(
             CpiContext::new_with_signer(
                 self.token_program.to_account_info(),
-                Transfer {
+                TransferChecked {
                     from: self.token_b_vault.to_account_info(),
+                    mint: self.token_b_mint.to_account_info(),
                     to: self.swapper_token_b.to_account_info(),
                     authority: self.pool_authority.to_account_info(),
                 },
                 signer_seeds,
             ),
             amount,
+            self.token_b_mint.decimals,
         )
     }
 }
\ No newline at end of file
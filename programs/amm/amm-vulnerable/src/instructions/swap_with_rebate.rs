@@ -0,0 +1,61 @@
+// Swap With Rebate Instruction - VULNERABLE VERSION
+//
+// Exact-input swap with part of the fee waived for swappers holding at least
+// FEE_REBATE_MIN_LP_TOKENS of the pool's LP token.
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// VULNERABILITIES:
+// V024: LP balance read from any account - no owner, mint, or address checks,
+//       so any token account with a large balance earns the rebate
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{helpers::*, instructions::SwapTokens};
+
+#[derive(Accounts)]
+pub struct SwapWithRebate<'info> {
+    pub swap: SwapTokens<'info>,
+
+    // VULNERABILITY V024: LP mint never compared with pool_config.lp_token_mint
+    /// CHECK: Intentionally unchecked (vulnerable)
+    pub lp_token_mint: UncheckedAccount<'info>,
+
+    // VULNERABILITY V024: LP balance account is completely unchecked
+    // Secure version: associated_token::mint = lp_token_mint,
+    //                 associated_token::authority = swap.swapper,
+    //                 associated_token::token_program = swap.token_program
+    // Attack: Mint a worthless token to yourself and pass that account - or any
+    //         whale's LP account - to swap at the rebated fee
+    /// CHECK: Intentionally unchecked (vulnerable)
+    pub swapper_lp_token: UncheckedAccount<'info>,
+}
+
+impl<'info> SwapWithRebate<'info> {
+    pub fn swap_with_rebate(
+        &mut self,
+        swap_token_a_for_b: bool,
+        input_amount: u64,
+        min_output_amount: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        // Only the token account layout is checked; owner, mint and holder are trusted
+        let lp_balance = {
+            let data = self.swapper_lp_token.try_borrow_data()?;
+            TokenAccount::try_deserialize(&mut &data[..])?.amount
+        };
+
+        let fee_basis_points =
+            calculate_rebated_fee(self.swap.pool_config.fee_basis_points, lp_balance)?;
+        msg!("Swap fee: {} basis points", fee_basis_points);
+
+        self.swap.swap_exact_in(
+            swap_token_a_for_b,
+            input_amount,
+            min_output_amount,
+            expiration,
+            fee_basis_points,
+        )
+    }
+}
@@ -12,7 +12,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::{constants::*, errors::*, helpers::*, state::*};
@@ -27,6 +27,7 @@ pub struct WithdrawLiquidity<'info> {
             AMM_CONFIG_SEED,
             pool_config.token_a_mint.as_ref(),
             pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
         ],
         bump = pool_config.config_bump,
     )]
@@ -44,52 +45,57 @@ pub struct WithdrawLiquidity<'info> {
         seeds = [LP_MINT_SEED, pool_config.key().as_ref()],
         bump = pool_config.lp_mint_bump,
     )]
-    pub lp_token_mint: Box<Account<'info, Mint>>,
+    pub lp_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(address = pool_config.token_a_mint)]
-    pub token_a_mint: Box<Account<'info, Mint>>,
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(address = pool_config.token_b_mint)]
-    pub token_b_mint: Box<Account<'info, Mint>>,
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         init_if_needed,
         payer = withdrawer,
         associated_token::mint = token_a_mint,
         associated_token::authority = withdrawer,
+        associated_token::token_program = token_program,
     )]
-    pub withdrawer_token_a: Box<Account<'info, TokenAccount>>,
+    pub withdrawer_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         init_if_needed,
         payer = withdrawer,
         associated_token::mint = token_b_mint,
         associated_token::authority = withdrawer,
+        associated_token::token_program = token_program,
     )]
-    pub withdrawer_token_b: Box<Account<'info, TokenAccount>>,
+    pub withdrawer_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
         token::mint = lp_token_mint,           
         token::authority = withdrawer,         
+        token::token_program = token_program,
     )]
-    pub withdrawer_lp_token: Account<'info, TokenAccount>,
+    pub withdrawer_lp_token: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
         token::mint = token_a_mint,
         token::authority = pool_authority,
+        token::token_program = token_program,
     )]
-    pub token_a_vault: Box<Account<'info, TokenAccount>>,
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
         token::mint = token_b_mint,
         token::authority = pool_authority,
+        token::token_program = token_program,
     )]
-    pub token_b_vault: Box<Account<'info, TokenAccount>>,
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
@@ -177,8 +183,10 @@ impl<'info> WithdrawLiquidity<'info> {
 
         transfer_from_vault(
             amount_a,
+            self.token_a_mint.decimals,
             &self.token_program.to_account_info(),
             &self.token_a_vault.to_account_info(),
+            &self.token_a_mint.to_account_info(),
             &self.withdrawer_token_a.to_account_info(),
             &self.pool_authority.to_account_info(),
             authority_seeds,
@@ -186,8 +194,10 @@ impl<'info> WithdrawLiquidity<'info> {
 
         transfer_from_vault(
             amount_b,
+            self.token_b_mint.decimals,
             &self.token_program.to_account_info(),
             &self.token_b_vault.to_account_info(),
+            &self.token_b_mint.to_account_info(),
             &self.withdrawer_token_b.to_account_info(),
             &self.pool_authority.to_account_info(),
             authority_seeds,
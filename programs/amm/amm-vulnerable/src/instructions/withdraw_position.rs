@@ -0,0 +1,236 @@
+// Withdraw Position Instruction - VULNERABLE VERSION
+//
+// Closes an LP position opened by deposit_position: burns the position NFT and the
+// LP tokens it represents, and pays out the proportional share of both vaults.
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// VULNERABILITIES:
+// V023: Position never bound to the pool - a position minted in any pool redeems
+//       against whichever pool_config the caller passes
+// V004: Unchecked arithmetic in calculate_withdrawal
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use mpl_core::{ID as MPL_CORE_ID, accounts::BaseAssetV1, instructions::BurnV1CpiBuilder};
+
+use crate::{constants::*, errors::*, helpers::*, state::*};
+
+#[derive(Accounts)]
+pub struct WithdrawPosition<'info> {
+    // Position NFT owner (receives tokens and the position rent)
+    #[account(mut)]
+    pub withdrawer: Signer<'info>,
+
+    // Position NFT being redeemed
+    #[account(
+        mut,
+        constraint = asset.owner == withdrawer.key() @ AmmError::PositionOwnerMismatch,
+    )]
+    pub asset: Box<Account<'info, BaseAssetV1>>,
+
+    // Position record PDA
+    // Seeds: ["position", asset]
+    //
+    // VULNERABILITY V023: Position not bound to the pool
+    // Secure version: has_one = pool_config @ AmmError::PositionPoolMismatch
+    // Attack scenario:
+    // 1. Victim opens a position in pool Y; its LP tokens sit in Y's position LP vault
+    // 2. Attacker creates pool X from two worthless mints and deposits the same amounts,
+    //    so their position records the same lp_tokens
+    // 3. Attacker calls withdraw_position with their X position but Y's pool accounts
+    // 4. The program burns lp_tokens of Y's LP from Y's position LP vault and pays out
+    //    Y's real tokens to the attacker; the victim's position can no longer be redeemed
+    #[account(
+        mut,
+        close = withdrawer,
+        seeds = [POSITION_SEED, asset.key().as_ref()],
+        bump = position.bump,
+        has_one = asset,
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(
+        seeds = [
+            AMM_CONFIG_SEED,
+            pool_config.token_a_mint.as_ref(),
+            pool_config.token_b_mint.as_ref(),
+            pool_config.fee_tier.to_le_bytes().as_ref(),
+        ],
+        bump = pool_config.config_bump,
+    )]
+    pub pool_config: Box<Account<'info, PoolConfig>>,
+
+    /// CHECK: PDA signer
+    #[account(
+        seeds = [AMM_AUTHORITY_SEED, pool_config.key().as_ref()],
+        bump = pool_config.authority_bump,
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LP_MINT_SEED, pool_config.key().as_ref()],
+        bump = pool_config.lp_mint_bump,
+    )]
+    pub lp_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = pool_config.token_a_mint)]
+    pub token_a_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = pool_config.token_b_mint)]
+    pub token_b_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = withdrawer,
+        associated_token::mint = token_a_mint,
+        associated_token::authority = withdrawer,
+        associated_token::token_program = token_program,
+    )]
+    pub withdrawer_token_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = withdrawer,
+        associated_token::mint = token_b_mint,
+        associated_token::authority = withdrawer,
+        associated_token::token_program = token_program,
+    )]
+    pub withdrawer_token_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // LP tokens backing every position in this pool
+    #[account(
+        mut,
+        associated_token::mint = lp_token_mint,
+        associated_token::authority = pool_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub position_lp_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_a_mint,
+        token::authority = pool_authority,
+        token::token_program = token_program,
+    )]
+    pub token_a_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_b_mint,
+        token::authority = pool_authority,
+        token::token_program = token_program,
+    )]
+    pub token_b_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Metaplex Core program
+    #[account(address = MPL_CORE_ID @ AmmError::InvalidMplCoreProgram)]
+    /// CHECK: Validated by address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawPosition<'info> {
+    pub fn withdraw_position(
+        &mut self,
+        min_amount_a: u64,
+        min_amount_b: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        // Check pool not locked
+        self.pool_config.assert_not_locked()?;
+
+        // Validate expiration using helper
+        validate_expiration(expiration)?;
+
+        let lp_tokens = self.position.lp_tokens;
+        require!(self.position_lp_vault.amount >= lp_tokens, AmmError::InsufficientBalance);
+
+        let vault_a_balance = self.token_a_vault.amount;
+        let vault_b_balance = self.token_b_vault.amount;
+        let lp_supply = self.lp_token_mint.supply;
+
+        // Check pool has liquidity
+        require!(lp_supply > 0, AmmError::InsufficientLiquidity);
+        require!(vault_a_balance > 0, AmmError::InsufficientLiquidity);
+        require!(vault_b_balance > 0, AmmError::InsufficientLiquidity);
+
+        // Calculate withdrawal amounts using helper
+        // VULNERABILITY V004: Unchecked arithmetic in calculate_withdrawal
+        let (amount_a, amount_b) =
+            calculate_withdrawal(lp_tokens, vault_a_balance, vault_b_balance, lp_supply)?;
+
+        // Slippage protection (Token-2022 transfer fees ignored, see V015)
+        require!(amount_a >= min_amount_a, AmmError::InsufficientWithdrawAmount);
+        require!(amount_b >= min_amount_b, AmmError::InsufficientWithdrawAmount);
+
+        // Check non-zero withdrawals
+        require!(amount_a > 0, AmmError::InsufficientLiquidity);
+        require!(amount_b > 0, AmmError::InsufficientLiquidity);
+
+        let pool_config_key = self.pool_config.key();
+        let authority_seeds = &[
+            AMM_AUTHORITY_SEED,
+            pool_config_key.as_ref(),
+            &[self.pool_config.authority_bump],
+        ];
+
+        // Burn the position's LP tokens from the position LP vault
+        burn_lp_tokens_from_vault(
+            lp_tokens,
+            &self.token_program.to_account_info(),
+            &self.lp_token_mint.to_account_info(),
+            &self.position_lp_vault.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        // Transfer tokens from vaults using helper
+        transfer_from_vault(
+            amount_a,
+            self.token_a_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.token_a_vault.to_account_info(),
+            &self.token_a_mint.to_account_info(),
+            &self.withdrawer_token_a.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        transfer_from_vault(
+            amount_b,
+            self.token_b_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.token_b_vault.to_account_info(),
+            &self.token_b_mint.to_account_info(),
+            &self.withdrawer_token_b.to_account_info(),
+            &self.pool_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        // Burn the position NFT (owner signs; Position PDA is closed by Anchor)
+        BurnV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
+            .asset(&self.asset.to_account_info())
+            .payer(&self.withdrawer.to_account_info())
+            .authority(Some(&self.withdrawer.to_account_info()))
+            .system_program(Some(&self.system_program.to_account_info()))
+            .invoke()?;
+
+        msg!(
+            "Position closed: {} LP -> {} A, {} B (asset {})",
+            lp_tokens,
+            amount_a,
+            amount_b,
+            self.asset.key()
+        );
+
+        Ok(())
+    }
+}
@@ -10,6 +10,12 @@
 // - Missing authorization checks
 // - No pool lock enforcement
 // - Improper fee validation
+// - Oracle guard without staleness checks
+// - LP position NFTs not bound to their pool
+// - Fee rebates granted on unvalidated LP balances
+// - Circuit breaker configured but never enforced
+// - Temporary wSOL accounts closed to an unchecked refund receiver
+// - Limit orders filled without checking the stored limit
 //
 // VULNERABILITIES DOCUMENTED:
 // See individual instruction files for detailed vulnerability explanations.
@@ -31,9 +37,24 @@ declare_id!("AMM1VuLNerAbLe11111111111111111111111111111");
 pub mod amm_vulnerable {
     use super::*;
 
+    // VULNERABILITY: Config can be bypassed in initialize_pool
+    pub fn initialize_amm_config(
+        ctx: Context<InitializeAmmConfig>,
+        pool_creation_authority: Pubkey,
+        fee_tiers: Vec<u16>,
+    ) -> Result<()> {
+        ctx.accounts
+            .initialize_amm_config(pool_creation_authority, fee_tiers, &ctx.bumps)
+    }
+
     // VULNERABILITY: No fee validation
-    pub fn initialize_pool(ctx: Context<InitializePool>, fee_basis_points: u16) -> Result<()> {
-        ctx.accounts.initialize_pool(fee_basis_points, &ctx.bumps)
+    pub fn initialize_pool(
+        ctx: Context<InitializePool>,
+        fee_basis_points: u16,
+        referral_fee_basis_points: u16,
+    ) -> Result<()> {
+        ctx.accounts
+            .initialize_pool(fee_basis_points, referral_fee_basis_points, &ctx.bumps)
     }
 
     // VULNERABILITY: Missing slippage and expiration checks
@@ -54,6 +75,24 @@ pub mod amm_vulnerable {
         )
     }
 
+    // VULNERABILITY: Leftover wSOL and rent refunded to an unchecked account
+    pub fn deposit_liquidity_sol(
+        ctx: Context<DepositLiquiditySol>,
+        desired_amount_a: u64,
+        desired_amount_b: u64,
+        max_amount_a: u64,
+        max_amount_b: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        ctx.accounts.deposit_liquidity_sol(
+            desired_amount_a,
+            desired_amount_b,
+            max_amount_a,
+            max_amount_b,
+            expiration,
+        )
+    }
+
     // VULNERABILITY: Missing slippage and expiration checks
     pub fn withdraw_liquidity(
         ctx: Context<WithdrawLiquidity>,
@@ -70,6 +109,16 @@ pub mod amm_vulnerable {
         )
     }
 
+    // VULNERABILITY V028: Destination pool not checked against the source pair
+    pub fn migrate_liquidity(
+        ctx: Context<MigrateLiquidity>,
+        lp_amount: u64,
+        min_lp_out: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        ctx.accounts.migrate_liquidity(lp_amount, min_lp_out, expiration)
+    }
+
     // VULNERABILITY: Missing slippage and expiration checks
     pub fn swap_tokens(
         ctx: Context<SwapTokens>,
@@ -86,6 +135,49 @@ pub mod amm_vulnerable {
         )
     }
 
+    // VULNERABILITY: Required input rounded down (in the user's favor)
+    pub fn swap_tokens_exact_out(
+        ctx: Context<SwapTokens>,
+        swap_token_a_for_b: bool,
+        max_input_amount: u64,
+        output_amount: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        ctx.accounts.swap_tokens_exact_out(
+            swap_token_a_for_b,
+            max_input_amount,
+            output_amount,
+            expiration,
+        )
+    }
+
+    // VULNERABILITY: Rebate granted on an unchecked LP balance account
+    pub fn swap_with_rebate(
+        ctx: Context<SwapWithRebate>,
+        swap_token_a_for_b: bool,
+        input_amount: u64,
+        min_output_amount: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        ctx.accounts.swap_with_rebate(
+            swap_token_a_for_b,
+            input_amount,
+            min_output_amount,
+            expiration,
+        )
+    }
+
+    // VULNERABILITY: wSOL rent refunded to an unchecked account
+    pub fn swap_sol_for_token(
+        ctx: Context<SwapSolForToken>,
+        input_amount: u64,
+        min_output_amount: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        ctx.accounts
+            .swap_sol_for_token(input_amount, min_output_amount, expiration)
+    }
+
     // VULNERABILITY: No authorization check
     pub fn lock_pool(ctx: Context<LockPool>) -> Result<()> {
         ctx.accounts.lock_pool()
@@ -95,4 +187,121 @@ pub mod amm_vulnerable {
     pub fn unlock_pool(ctx: Context<UnlockPool>) -> Result<()> {
         ctx.accounts.unlock_pool()
     }
+
+    // VULNERABILITY: No pool lock enforcement
+    pub fn flash_loan_begin(ctx: Context<FlashLoanBegin>, amount: u64) -> Result<()> {
+        ctx.accounts.flash_loan_begin(amount)
+    }
+
+    // VULNERABILITY: Repayment never verified
+    pub fn flash_loan_end(ctx: Context<FlashLoanEnd>) -> Result<()> {
+        ctx.accounts.flash_loan_end()
+    }
+
+    // VULNERABILITY: No fee validation
+    pub fn request_fee_change(
+        ctx: Context<RequestFeeChange>,
+        new_fee_basis_points: u16,
+    ) -> Result<()> {
+        ctx.accounts.request_fee_change(new_fee_basis_points)
+    }
+
+    // VULNERABILITY: Timelock never enforced
+    pub fn change_pool_fee(ctx: Context<ChangePoolFee>) -> Result<()> {
+        ctx.accounts.change_pool_fee()
+    }
+
+    // Enable/disable emergency withdrawals while locked - only pool authority
+    pub fn set_emergency_withdraw(ctx: Context<SetEmergencyWithdraw>, enabled: bool) -> Result<()> {
+        ctx.accounts.set_emergency_withdraw(enabled)
+    }
+
+    // VULNERABILITY: emergency_withdraw_enabled flag never checked
+    pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>, lp_tokens_to_burn: u64) -> Result<()> {
+        ctx.accounts.emergency_withdraw(lp_tokens_to_burn)
+    }
+
+    // VULNERABILITY: Guarded swaps never check the feed's age
+    pub fn set_oracle_guard(
+        ctx: Context<SetOracleGuard>,
+        oracle_program: Pubkey,
+        price_feed: Pubkey,
+        max_oracle_deviation_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_oracle_guard(oracle_program, price_feed, max_oracle_deviation_bps)
+    }
+
+    // VULNERABILITY: Swaps never check the price impact limit
+    pub fn set_circuit_breaker(
+        ctx: Context<SetCircuitBreaker>,
+        max_price_impact_bps: u16,
+        auto_lock: bool,
+    ) -> Result<()> {
+        ctx.accounts.set_circuit_breaker(max_price_impact_bps, auto_lock)
+    }
+
+    // VULNERABILITY: Swaps never check the per-slot volume limit
+    pub fn set_swap_limit(ctx: Context<SetSwapLimit>, max_swap_per_slot_bps: u16) -> Result<()> {
+        ctx.accounts.set_swap_limit(max_swap_per_slot_bps)
+    }
+
+    // Add liquidity as an NFT position
+    pub fn deposit_position(
+        ctx: Context<DepositPosition>,
+        desired_amount_a: u64,
+        desired_amount_b: u64,
+        max_amount_a: u64,
+        max_amount_b: u64,
+        expiration: i64,
+        uri: String,
+    ) -> Result<()> {
+        ctx.accounts.deposit_position(
+            desired_amount_a,
+            desired_amount_b,
+            max_amount_a,
+            max_amount_b,
+            expiration,
+            uri,
+            &ctx.bumps,
+        )
+    }
+
+    // VULNERABILITY: Position is never checked against the pool it is redeemed from
+    pub fn withdraw_position(
+        ctx: Context<WithdrawPosition>,
+        min_amount_a: u64,
+        min_amount_b: u64,
+        expiration: i64,
+    ) -> Result<()> {
+        ctx.accounts.withdraw_position(min_amount_a, min_amount_b, expiration)
+    }
+
+    // Escrow input tokens to be swapped for at least min_output_amount
+    pub fn place_limit_order(
+        ctx: Context<PlaceLimitOrder>,
+        order_id: u64,
+        input_amount: u64,
+        min_output_amount: u64,
+        keeper_tip: u64,
+        expires_at: i64,
+    ) -> Result<()> {
+        ctx.accounts.place_limit_order(
+            order_id,
+            input_amount,
+            min_output_amount,
+            keeper_tip,
+            expires_at,
+            &ctx.bumps,
+        )
+    }
+
+    pub fn cancel_limit_order(ctx: Context<CancelLimitOrder>) -> Result<()> {
+        ctx.accounts.cancel_limit_order()
+    }
+
+    // VULNERABILITY: Fills at whatever price the keeper chooses, ignoring the order's limit
+    pub fn fill_limit_order(ctx: Context<FillLimitOrder>) -> Result<()> {
+        ctx.accounts.fill_limit_order()
+    }
 }
@@ -0,0 +1,43 @@
+// Global AMM Configuration State
+//
+// Optional singleton that turns pool creation into a permissioned operation.
+// Until it is initialized, anyone can create pools with any valid fee.
+// Once initialized, only `pool_creation_authority` can create pools, and only
+// with a fee from the whitelisted `fee_tiers`.
+//
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*};
+
+#[account]
+#[derive(InitSpace)]
+pub struct AmmConfig {
+    // Address that initialized the config
+    pub admin: Pubkey,
+
+    // Only this address can create new pools
+    pub pool_creation_authority: Pubkey,
+
+    // Whitelisted swap fees in basis points (e.g. [5, 30, 100])
+    #[max_len(MAX_FEE_TIERS)]
+    pub fee_tiers: Vec<u16>,
+
+    // PDA bump seed
+    pub bump: u8,
+}
+
+impl AmmConfig {
+    // Assert `creator` may create a pool charging `fee_basis_points`
+    // Called by initialize_pool when the global config exists
+    pub fn assert_can_create_pool(&self, creator: &Pubkey, fee_basis_points: u16) -> Result<()> {
+        require!(
+            self.pool_creation_authority == *creator,
+            AmmError::UnauthorizedPoolCreator
+        );
+        require!(
+            self.fee_tiers.contains(&fee_basis_points),
+            AmmError::FeeTierNotWhitelisted
+        );
+        Ok(())
+    }
+}
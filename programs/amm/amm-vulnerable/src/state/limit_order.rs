@@ -0,0 +1,42 @@
+// Limit Order State
+//
+// A resting order to swap a fixed amount of one pool token for at least
+// min_output_amount of the other. The input tokens sit in the order's escrow
+// (the LimitOrder PDA's ATA for input_mint) until a keeper fills the order once
+// the pool price crosses the limit, or the owner cancels it.
+//
+// The keeper tip is held as extra lamports on this account, on top of its rent,
+// and paid to whichever keeper fills the order.
+
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct LimitOrder {
+    // Trader who placed the order (receives the output, the rent, and any refund)
+    pub owner: Pubkey,
+
+    // Pool the order fills against
+    pub pool_config: Pubkey,
+
+    // Token sold (escrowed) and token bought - one of each pool mint
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+
+    // Owner-chosen id, lets one owner keep several orders on the same pool
+    pub order_id: u64,
+
+    // Input tokens credited to the escrow at placement (after transfer fees)
+    pub input_amount: u64,
+
+    // The limit: output the owner must receive for the whole input_amount
+    pub min_output_amount: u64,
+
+    // Lamports paid to the keeper that fills the order
+    pub keeper_tip: u64,
+
+    // Unix timestamp after which the order can no longer be filled
+    pub expires_at: i64,
+
+    pub bump: u8,
+}
@@ -3,5 +3,13 @@
 // Exports all state structures used by the AMM program
 
 pub mod pool_config;
+pub mod amm_config;
+pub mod price_feed;
+pub mod position;
+pub mod limit_order;
 
 pub use pool_config::*;
+pub use amm_config::*;
+pub use price_feed::*;
+pub use position::*;
+pub use limit_order::*;
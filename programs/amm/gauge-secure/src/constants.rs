@@ -0,0 +1,14 @@
+// Seed for gauge PDA
+// Derived with: [GAUGE_SEED, lp_mint_pubkey, reward_mint_pubkey]
+pub const GAUGE_SEED: &[u8] = b"gauge";
+
+// Seed for stake position PDA
+// Derived with: [STAKE_SEED, gauge_pubkey, owner_pubkey]
+pub const STAKE_SEED: &[u8] = b"stake";
+
+// REWARD ACCOUNTING
+
+// Scale of acc_reward_per_share, so small per-token rewards don't round to zero
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+
+pub const ANCHOR_DISCRIMINATOR: usize = 8;
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+pub use soteria_errors::gauge_secure::GaugeError as GaugeErrorCode;
+
+#[error_code]
+pub enum GaugeError {
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Emission rate must be greater than zero")]
+    ZeroEmissionRate,
+
+    #[msg("Reward amount must cover at least one second of emissions")]
+    InsufficientRewards,
+
+    #[msg("LP mint and reward mint must be different")]
+    IdenticalMints,
+
+    #[msg("Unstake amount exceeds the staked amount")]
+    InsufficientStake,
+
+    #[msg("Unauthorized: Cannot perform this action")]
+    Unauthorized,
+
+    #[msg("Arithmetic overflow")]
+    Overflow,
+
+    #[msg("Arithmetic underflow")]
+    Underflow,
+}
@@ -0,0 +1,157 @@
+// Stake LP Instruction
+//
+// Adds LP tokens to the owner's stake position, creating it on first stake.
+//
+// HOW IT WORKS:
+// 1. Brings the gauge's acc_reward_per_share up to date
+// 2. Settles the rewards the existing stake has earned
+// 3. Moves the LP tokens into the gauge's LP vault and adds them to the stake
+// 4. Resets reward_debt from the new stake, so it only earns from now on
+// 5. Pays out the settled rewards, signed by the gauge PDA
+//
+// SECURITY:
+// - Pending rewards are computed from the stake BEFORE it grows; a new stake
+//   never earns emissions from before it was made
+// - The position PDA is derived from the signer, so it can only stake into its own position
+// - Vaults must be the gauge's own (has_one)
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, helpers::*, state::*};
+
+#[derive(Accounts)]
+pub struct StakeLp<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GAUGE_SEED, lp_mint.key().as_ref(), reward_mint.key().as_ref()],
+        bump = gauge.bump,
+        has_one = lp_mint,
+        has_one = reward_mint,
+        has_one = lp_vault,
+        has_one = reward_vault,
+    )]
+    pub gauge: Box<Account<'info, Gauge>>,
+
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR + StakePosition::INIT_SPACE,
+        seeds = [STAKE_SEED, gauge.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub stake_position: Box<Account<'info, StakePosition>>,
+
+    #[account(mut)]
+    pub lp_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = lp_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_reward_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> StakeLp<'info> {
+    pub fn stake_lp(&mut self, amount: u64, bumps: &StakeLpBumps) -> Result<()> {
+        require!(amount > 0, GaugeError::ZeroAmount);
+
+        // First stake: the position was just created with zero amount and debt
+        if self.stake_position.owner == Pubkey::default() {
+            self.stake_position.set_inner(StakePosition {
+                gauge: self.gauge.key(),
+                owner: self.owner.key(),
+                amount: 0,
+                reward_debt: 0,
+                bump: bumps.stake_position,
+            });
+        }
+
+        self.gauge.update(Clock::get()?.unix_timestamp)?;
+        let acc_reward_per_share = self.gauge.acc_reward_per_share;
+
+        // Settle against the stake as it was, before adding to it
+        #[cfg(not(feature = "vuln-late-reward-settlement"))]
+        let pending = self.stake_position.pending(acc_reward_per_share)?;
+
+        transfer_tokens(
+            amount,
+            self.lp_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.owner_lp_account.to_account_info(),
+            &self.lp_mint.to_account_info(),
+            &self.lp_vault.to_account_info(),
+            &self.owner.to_account_info(),
+        )?;
+
+        self.stake_position.amount = self
+            .stake_position
+            .amount
+            .checked_add(amount)
+            .ok_or(GaugeError::Overflow)?;
+        self.gauge.total_staked = self
+            .gauge
+            .total_staked
+            .checked_add(amount)
+            .ok_or(GaugeError::Overflow)?;
+        // vuln-late-reward-settlement: settled against the grown stake, so the
+        // new tokens are paid for emissions from before they were staked
+        #[cfg(feature = "vuln-late-reward-settlement")]
+        let pending = self.stake_position.pending(acc_reward_per_share)?;
+        self.stake_position.reward_debt = self.stake_position.accrued(acc_reward_per_share)?;
+
+        if pending > 0 {
+            let lp_mint_key = self.lp_mint.key();
+            let reward_mint_key = self.reward_mint.key();
+            let gauge_seeds = &[
+                GAUGE_SEED,
+                lp_mint_key.as_ref(),
+                reward_mint_key.as_ref(),
+                &[self.gauge.bump],
+            ];
+
+            transfer_from_vault(
+                pending,
+                self.reward_mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.reward_vault.to_account_info(),
+                &self.reward_mint.to_account_info(),
+                &self.owner_reward_account.to_account_info(),
+                &self.gauge.to_account_info(),
+                gauge_seeds,
+            )?;
+        }
+
+        msg!(
+            "Staked {} LP (position {}), paid {} rewards",
+            amount,
+            self.stake_position.amount,
+            pending
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+// Unstake LP Instruction
+//
+// Withdraws LP tokens from the owner's stake position.
+//
+// HOW IT WORKS:
+// 1. Brings the gauge's acc_reward_per_share up to date
+// 2. Settles the rewards the stake has earned
+// 3. Removes the LP tokens from the stake and resets reward_debt from what is left
+// 4. Returns the LP tokens and pays out the settled rewards, signed by the gauge PDA
+//
+// SECURITY:
+// - Only the position owner can unstake (has_one = owner)
+// - Cannot unstake more than the position holds
+// - Pending rewards are computed from the stake BEFORE it shrinks
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, helpers::*, state::*};
+
+#[derive(Accounts)]
+pub struct UnstakeLp<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GAUGE_SEED, lp_mint.key().as_ref(), reward_mint.key().as_ref()],
+        bump = gauge.bump,
+        has_one = lp_mint,
+        has_one = reward_mint,
+        has_one = lp_vault,
+        has_one = reward_vault,
+    )]
+    pub gauge: Box<Account<'info, Gauge>>,
+
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, gauge.key().as_ref(), stake_position.owner.as_ref()],
+        bump = stake_position.bump,
+        has_one = gauge,
+        has_one = owner @ GaugeError::Unauthorized,
+    )]
+    pub stake_position: Box<Account<'info, StakePosition>>,
+
+    #[account(mut)]
+    pub lp_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = lp_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_reward_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> UnstakeLp<'info> {
+    pub fn unstake_lp(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, GaugeError::ZeroAmount);
+        require!(
+            amount <= self.stake_position.amount,
+            GaugeError::InsufficientStake
+        );
+
+        self.gauge.update(Clock::get()?.unix_timestamp)?;
+        let acc_reward_per_share = self.gauge.acc_reward_per_share;
+
+        // Settle against the stake as it was, before removing from it
+        let pending = self.stake_position.pending(acc_reward_per_share)?;
+
+        self.stake_position.amount -= amount;
+        self.gauge.total_staked = self
+            .gauge
+            .total_staked
+            .checked_sub(amount)
+            .ok_or(GaugeError::Underflow)?;
+        self.stake_position.reward_debt = self.stake_position.accrued(acc_reward_per_share)?;
+
+        let lp_mint_key = self.lp_mint.key();
+        let reward_mint_key = self.reward_mint.key();
+        let gauge_seeds = &[
+            GAUGE_SEED,
+            lp_mint_key.as_ref(),
+            reward_mint_key.as_ref(),
+            &[self.gauge.bump],
+        ];
+
+        transfer_from_vault(
+            amount,
+            self.lp_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.lp_vault.to_account_info(),
+            &self.lp_mint.to_account_info(),
+            &self.owner_lp_account.to_account_info(),
+            &self.gauge.to_account_info(),
+            gauge_seeds,
+        )?;
+
+        if pending > 0 {
+            transfer_from_vault(
+                pending,
+                self.reward_mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.reward_vault.to_account_info(),
+                &self.reward_mint.to_account_info(),
+                &self.owner_reward_account.to_account_info(),
+                &self.gauge.to_account_info(),
+                gauge_seeds,
+            )?;
+        }
+
+        msg!(
+            "Unstaked {} LP (position {}), paid {} rewards",
+            amount,
+            self.stake_position.amount,
+            pending
+        );
+
+        Ok(())
+    }
+}
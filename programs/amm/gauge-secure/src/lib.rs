@@ -0,0 +1,61 @@
+// Liquidity Mining Gauge Program - SECURE VERSION
+//
+// Companion program for the AMMs: LPs stake their LP tokens in a gauge and
+// earn a reward token emitted at a fixed rate. This program allows users to:
+// 1. Create a gauge for an LP mint, funded with the rewards it will emit
+// 2. Stake LP tokens, settling any rewards already earned
+// 3. Unstake LP tokens, settling any rewards already earned
+// 4. Claim earned rewards without touching the stake
+//
+// SECURITY FEATURES:
+// - Rewards earned so far are settled BEFORE a stake changes, and the reward
+//   debt is reset from the new stake AFTER, so a stake only earns from the
+//   moment it is made
+// - Emissions stop once the funded reward amount is used up, so the reward
+//   vault always covers every claim
+// - Only a stake position's owner can unstake or claim from it
+// - Vaults are recorded on the gauge and checked by every instruction (has_one)
+// - Checked arithmetic to prevent overflow/underflow
+//
+// REWARD MODEL:
+// The gauge tracks acc_reward_per_share: rewards emitted per staked LP token,
+// scaled by REWARD_PRECISION. A position has earned
+// amount * acc_reward_per_share / REWARD_PRECISION - reward_debt, where
+// reward_debt is the same product taken at the last settlement.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("D5RcoZJXmGbeETbNQF3U5HQ2smHGSQvLn6QG67uNsfw4");
+
+#[program]
+pub mod gauge_secure {
+    use super::*;
+
+    // Create a gauge emitting `emission_rate` rewards per second until `reward_amount` is used up
+    pub fn create_gauge(ctx: Context<CreateGauge>, emission_rate: u64, reward_amount: u64) -> Result<()> {
+        ctx.accounts.create_gauge(emission_rate, reward_amount, &ctx.bumps)
+    }
+
+    // Stake LP tokens, paying out rewards earned so far
+    pub fn stake_lp(ctx: Context<StakeLp>, amount: u64) -> Result<()> {
+        ctx.accounts.stake_lp(amount, &ctx.bumps)
+    }
+
+    // Unstake LP tokens, paying out rewards earned so far
+    pub fn unstake_lp(ctx: Context<UnstakeLp>, amount: u64) -> Result<()> {
+        ctx.accounts.unstake_lp(amount)
+    }
+
+    // Pay out rewards earned so far
+    pub fn claim_emissions(ctx: Context<ClaimEmissions>) -> Result<()> {
+        ctx.accounts.claim_emissions()
+    }
+}
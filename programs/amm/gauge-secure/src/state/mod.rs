@@ -0,0 +1,9 @@
+// State Module
+//
+// Exports all state structures used by the gauge program
+
+pub mod gauge;
+pub mod stake_position;
+
+pub use gauge::*;
+pub use stake_position::*;
@@ -0,0 +1,52 @@
+// Compute-unit regression tests for gauge-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+mod utils;
+
+use solana_sdk::signature::Signer;
+use soteria_test_kit::{ComputeBudget, DEFAULT_CU_BASELINES_FILE};
+use utils::*;
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let (mut svm, mint_authority, lp_mint, reward_mint) = setup_mints();
+
+    let ix = build_create_gauge_ix(&mint_authority.pubkey(), &lp_mint, &reward_mint, EMISSION_RATE, REWARD_AMOUNT);
+    budget.record("create_gauge", send_ix(&mut svm, ix, &mint_authority));
+
+    let (gauge, _) = derive_gauge_pda(&lp_mint, &reward_mint);
+    let mut scenario = GaugeScenario {
+        svm,
+        mint_authority,
+        lp_mint,
+        reward_mint,
+        gauge,
+    };
+    let alice = create_staker(&mut scenario);
+
+    // First stake: creates the position, nothing to pay out
+    let ix = build_stake_lp_ix(&alice.pubkey(), &lp_mint, &reward_mint, STAKE);
+    budget.record("stake_lp", send_ix(&mut scenario.svm, ix, &alice));
+
+    // Second stake: settles and pays rewards
+    advance_time(&mut scenario.svm, 100);
+    let ix = build_stake_lp_ix(&alice.pubkey(), &lp_mint, &reward_mint, STAKE);
+    budget.record("stake_lp_with_payout", send_ix(&mut scenario.svm, ix, &alice));
+
+    advance_time(&mut scenario.svm, 100);
+    let ix = build_claim_emissions_ix(&alice.pubkey(), &alice.pubkey(), &lp_mint, &reward_mint);
+    budget.record("claim_emissions", send_ix(&mut scenario.svm, ix, &alice));
+
+    advance_time(&mut scenario.svm, 100);
+    let ix = build_unstake_lp_ix(&alice.pubkey(), &alice.pubkey(), &lp_mint, &reward_mint, 2 * STAKE);
+    budget.record("unstake_lp", send_ix(&mut scenario.svm, ix, &alice));
+
+    budget.check();
+}
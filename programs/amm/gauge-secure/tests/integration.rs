@@ -0,0 +1,174 @@
+// Integration tests for liquidity mining gauge program using LiteSVM
+// These tests verify core functionality: gauge creation, staking, claiming
+// and unstaking, and the reward-per-share accounting behind them
+//
+// Every scenario uses a gauge emitting EMISSION_RATE (1 token per second)
+// funded with REWARD_AMOUNT (1000 seconds of emissions)
+
+mod utils;
+
+use utils::*;
+use solana_sdk::signature::Signer;
+use spl_associated_token_account::get_associated_token_address;
+
+#[test]
+fn test_create_gauge() {
+    // Test: Gauges take their rewards up front and emit them for a fixed time
+    println!("\n[TEST START] test_create_gauge - Gauge creation and funding");
+
+    let (mut svm, funder, lp_mint, reward_mint) = setup_mints();
+    println!("[Setup] LP and reward mints created, funder holds {} rewards", REWARD_AMOUNT);
+
+    // Invalid parameters are rejected
+    for (lp, emission_rate, reward_amount, reason) in [
+        (lp_mint, 0, REWARD_AMOUNT, "Zero emission rate"),
+        (lp_mint, EMISSION_RATE, EMISSION_RATE - 1, "Less than one second of rewards"),
+        (reward_mint, EMISSION_RATE, REWARD_AMOUNT, "Identical mints"),
+    ] {
+        let ix = build_create_gauge_ix(&funder.pubkey(), &lp, &reward_mint, emission_rate, reward_amount);
+        assert!(send_ix(&mut svm, ix, &funder).is_err(), "{} should fail", reason);
+    }
+    println!("[Success] Zero rate, underfunded and identical-mint gauges rejected");
+
+    println!("[Action] Create gauge: {} per second, {} funded", EMISSION_RATE, REWARD_AMOUNT);
+    let start = current_timestamp(&svm);
+    let ix = build_create_gauge_ix(&funder.pubkey(), &lp_mint, &reward_mint, EMISSION_RATE, REWARD_AMOUNT);
+    let result = send_ix(&mut svm, ix, &funder);
+    assert!(result.is_ok(), "Create gauge failed: {:?}", result.err());
+
+    let (gauge, _) = derive_gauge_pda(&lp_mint, &reward_mint);
+    let state = get_gauge(&svm, &gauge);
+    assert_eq!(state.emission_end_ts, start + 1000);
+    assert_eq!(state.last_update_ts, start);
+    assert_eq!(state.acc_reward_per_share, 0);
+    assert_eq!(state.total_staked, 0);
+    assert_eq!(token_balance(&svm, &get_associated_token_address(&gauge, &reward_mint)), REWARD_AMOUNT);
+    println!("[Success] Reward vault holds {}, emissions end after 1000 seconds", REWARD_AMOUNT);
+
+    println!("[TEST END] test_create_gauge");
+}
+
+#[test]
+fn test_stake_and_claim() {
+    // Test: A late staker only earns from the moment they stake
+    println!("\n[TEST START] test_stake_and_claim - Reward-per-share accounting");
+
+    let mut scenario = setup_gauge_scenario();
+    let alice = create_staker(&mut scenario);
+    let bob = create_staker(&mut scenario);
+    let (lp_mint, reward_mint) = (scenario.lp_mint, scenario.reward_mint);
+
+    println!("[Action] Alice stakes {} LP", STAKE);
+    stake(&mut scenario, &alice, STAKE);
+    assert_eq!(lp_balance(&scenario, &alice.pubkey()), STAKER_LP_BALANCE - STAKE);
+
+    // 100 seconds alone: Alice earns all 100 tokens
+    advance_time(&mut scenario.svm, 100);
+
+    println!("[Action] Bob stakes {} LP after 100 seconds", STAKE);
+    stake(&mut scenario, &bob, STAKE);
+    assert_eq!(reward_balance(&scenario, &bob.pubkey()), 0, "Bob earned nothing yet");
+    let state = get_gauge(&scenario.svm, &scenario.gauge);
+    assert_eq!(state.total_staked, 2 * STAKE);
+    assert_eq!(state.acc_reward_per_share, 1_000_000_000_000);
+    let (bob_position, _) = derive_stake_pda(&scenario.gauge, &bob.pubkey());
+    assert_eq!(get_stake_position(&scenario.svm, &bob_position), (STAKE, 100_000_000));
+    println!("[Success] Bob's reward debt covers the 100 seconds before he staked");
+
+    // 100 seconds shared: 50 tokens each
+    advance_time(&mut scenario.svm, 100);
+
+    // Bob cannot claim Alice's rewards
+    let ix = build_claim_emissions_ix(&bob.pubkey(), &alice.pubkey(), &lp_mint, &reward_mint);
+    assert!(send_ix(&mut scenario.svm, ix, &bob).is_err(), "Non-owner claim should fail");
+    println!("[Success] Claim by non-owner rejected");
+
+    println!("[Action] Alice and Bob claim");
+    claim(&mut scenario, &alice).expect("Alice claim should succeed");
+    claim(&mut scenario, &bob).expect("Bob claim should succeed");
+    assert_eq!(reward_balance(&scenario, &alice.pubkey()), 150_000_000);
+    assert_eq!(reward_balance(&scenario, &bob.pubkey()), 50_000_000);
+    println!("[Success] Alice received 150 tokens, Bob 50");
+
+    // Claiming again at the same time pays nothing
+    claim(&mut scenario, &alice).expect("Second claim should succeed");
+    assert_eq!(reward_balance(&scenario, &alice.pubkey()), 150_000_000);
+    println!("[Success] Repeat claim paid nothing");
+
+    println!("[TEST END] test_stake_and_claim");
+}
+
+#[test]
+fn test_unstake() {
+    // Test: Unstaking returns the LP tokens and settles the rewards
+    println!("\n[TEST START] test_unstake - LP withdrawal");
+
+    let mut scenario = setup_gauge_scenario();
+    let alice = create_staker(&mut scenario);
+    let bob = create_staker(&mut scenario);
+    let (lp_mint, reward_mint) = (scenario.lp_mint, scenario.reward_mint);
+
+    stake(&mut scenario, &alice, STAKE);
+    advance_time(&mut scenario.svm, 100);
+    println!("[Setup] Alice staked {} LP 100 seconds ago", STAKE);
+
+    // Bob cannot unstake Alice's LP tokens
+    let ix = build_unstake_lp_ix(&bob.pubkey(), &alice.pubkey(), &lp_mint, &reward_mint, STAKE);
+    assert!(send_ix(&mut scenario.svm, ix, &bob).is_err(), "Non-owner unstake should fail");
+    println!("[Success] Unstake by non-owner rejected");
+
+    let ix = build_unstake_lp_ix(&alice.pubkey(), &alice.pubkey(), &lp_mint, &reward_mint, STAKE + 1);
+    assert!(send_ix(&mut scenario.svm, ix, &alice).is_err(), "Over-unstake should fail");
+    println!("[Success] Unstaking more than the stake rejected");
+
+    println!("[Action] Alice unstakes everything");
+    let ix = build_unstake_lp_ix(&alice.pubkey(), &alice.pubkey(), &lp_mint, &reward_mint, STAKE);
+    let result = send_ix(&mut scenario.svm, ix, &alice);
+    assert!(result.is_ok(), "Unstake failed: {:?}", result.err());
+
+    assert_eq!(lp_balance(&scenario, &alice.pubkey()), STAKER_LP_BALANCE);
+    assert_eq!(reward_balance(&scenario, &alice.pubkey()), 100_000_000);
+    assert_eq!(get_gauge(&scenario.svm, &scenario.gauge).total_staked, 0);
+    println!("[Success] Alice got her LP back and 100 tokens of rewards");
+
+    // Nothing staked: later emissions stay in the vault and nobody earns them
+    advance_time(&mut scenario.svm, 100);
+    claim(&mut scenario, &alice).expect("Claim should succeed");
+    assert_eq!(reward_balance(&scenario, &alice.pubkey()), 100_000_000);
+    println!("[Success] No rewards accrue to an empty stake");
+
+    println!("[TEST END] test_unstake");
+}
+
+#[test]
+fn test_emissions_end_at_funded_amount() {
+    // Test: Claims never exceed what the gauge was funded with
+    println!("\n[TEST START] test_emissions_end_at_funded_amount - Emission cap");
+
+    let mut scenario = setup_gauge_scenario();
+    let alice = create_staker(&mut scenario);
+    let bob = create_staker(&mut scenario);
+
+    stake(&mut scenario, &alice, STAKE);
+    stake(&mut scenario, &bob, 3 * STAKE);
+    println!("[Setup] Alice staked {} LP, Bob {}", STAKE, 3 * STAKE);
+
+    println!("[Action] Wait past the end of emissions, then claim");
+    advance_time(&mut scenario.svm, 2_000);
+    claim(&mut scenario, &alice).expect("Alice claim should succeed");
+    claim(&mut scenario, &bob).expect("Bob claim should succeed");
+
+    assert_eq!(reward_balance(&scenario, &alice.pubkey()), 250_000_000);
+    assert_eq!(reward_balance(&scenario, &bob.pubkey()), 750_000_000);
+    let reward_vault = get_associated_token_address(&scenario.gauge, &scenario.reward_mint);
+    assert_eq!(token_balance(&scenario.svm, &reward_vault), 0);
+    println!("[Success] Rewards split 1:3 and the vault paid out exactly {}", REWARD_AMOUNT);
+
+    // Emissions have ended: nothing more accrues
+    advance_time(&mut scenario.svm, 1_000);
+    claim(&mut scenario, &alice).expect("Claim after the end should succeed");
+    assert_eq!(reward_balance(&scenario, &alice.pubkey()), 250_000_000);
+    println!("[Success] No rewards accrue after emissions end");
+
+    println!("[TEST END] test_emissions_end_at_funded_amount");
+}
@@ -0,0 +1,363 @@
+// Test utilities for liquidity mining gauge program
+
+use litesvm::{LiteSVM, types::TransactionResult};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo, get_spl_account};
+use litesvm_token::spl_token::state::Account as TokenAccount;
+use solana_sdk::{
+    clock::Clock,
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::program::ID as system_program;
+
+// Program ID matching declare_id!
+pub const GAUGE_PROGRAM_ID: Pubkey = Pubkey::new_from_array(gauge_secure::ID.to_bytes());
+
+// Build Anchor instruction discriminator
+// Formula: first 8 bytes of sha256("global:method_name")
+pub fn anchor_discriminator(method: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", method);
+    let hash_result = hash(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash_result.to_bytes()[..8]);
+    discriminator
+}
+
+// Standard program IDs
+pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_account::ID;
+
+// PDA Seeds
+pub const GAUGE_SEED: &[u8] = b"gauge";
+pub const STAKE_SEED: &[u8] = b"stake";
+
+// Both tokens have 6 decimals
+pub const DECIMALS: u8 = 6;
+
+// Gauge parameters used by the scenarios: 1 reward token per second for 1000 seconds
+pub const EMISSION_RATE: u64 = 1_000_000;
+pub const REWARD_AMOUNT: u64 = 1_000_000_000;
+
+// LP tokens each staker starts with
+pub const STAKER_LP_BALANCE: u64 = 1_000_000_000;
+
+// Stake used by the scenarios
+pub const STAKE: u64 = 100_000_000;
+
+// Gauge layout: discriminator (8) + 4 pubkeys (128) + emission_rate (8)
+//               + emission_end_ts (8) + acc_reward_per_share (16) + last_update_ts (8) + total_staked (8)
+const EMISSION_END_OFFSET: usize = 8 + 32 * 4 + 8;
+const ACC_REWARD_OFFSET: usize = EMISSION_END_OFFSET + 8;
+const LAST_UPDATE_OFFSET: usize = ACC_REWARD_OFFSET + 16;
+const TOTAL_STAKED_OFFSET: usize = LAST_UPDATE_OFFSET + 8;
+
+// Stake position layout: discriminator (8) + gauge (32) + owner (32) + amount (8) + reward_debt (16)
+const STAKE_AMOUNT_OFFSET: usize = 8 + 32 * 2;
+const REWARD_DEBT_OFFSET: usize = STAKE_AMOUNT_OFFSET + 8;
+
+// Setup LiteSVM with gauge program
+pub fn setup_svm() -> LiteSVM {
+    let mut svm = LiteSVM::new();
+    let program_bytes = include_bytes!("../target/deploy/gauge_secure.so");
+    let _ = svm.add_program(GAUGE_PROGRAM_ID, program_bytes);
+    svm
+}
+
+// Create and fund account
+pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
+    let keypair = Keypair::new();
+    svm.airdrop(&keypair.pubkey(), lamports)
+        .expect("Airdrop should succeed");
+    keypair
+}
+
+// Sign and send a single instruction
+pub fn send_ix(svm: &mut LiteSVM, ix: Instruction, signer: &Keypair) -> TransactionResult {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    svm.expire_blockhash();
+    result
+}
+
+pub fn current_timestamp(svm: &LiteSVM) -> i64 {
+    svm.get_sysvar::<Clock>().unix_timestamp
+}
+
+pub fn advance_time(svm: &mut LiteSVM, seconds: i64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += seconds;
+    svm.set_sysvar::<Clock>(&clock);
+}
+
+// Derive gauge PDA
+pub fn derive_gauge_pda(lp_mint: &Pubkey, reward_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[GAUGE_SEED, lp_mint.as_ref(), reward_mint.as_ref()],
+        &GAUGE_PROGRAM_ID,
+    )
+}
+
+// Derive stake position PDA
+pub fn derive_stake_pda(gauge: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STAKE_SEED, gauge.as_ref(), owner.as_ref()], &GAUGE_PROGRAM_ID)
+}
+
+// Build create_gauge instruction, funded from the funder's reward ATA
+pub fn build_create_gauge_ix(
+    funder: &Pubkey,
+    lp_mint: &Pubkey,
+    reward_mint: &Pubkey,
+    emission_rate: u64,
+    reward_amount: u64,
+) -> Instruction {
+    let (gauge, _) = derive_gauge_pda(lp_mint, reward_mint);
+
+    let mut data = anchor_discriminator("create_gauge").to_vec();
+    data.extend_from_slice(&emission_rate.to_le_bytes());
+    data.extend_from_slice(&reward_amount.to_le_bytes());
+
+    Instruction {
+        program_id: GAUGE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*funder, true),
+            AccountMeta::new_readonly(*lp_mint, false),
+            AccountMeta::new_readonly(*reward_mint, false),
+            AccountMeta::new(gauge, false),
+            AccountMeta::new(get_associated_token_address(&gauge, lp_mint), false),
+            AccountMeta::new(get_associated_token_address(&gauge, reward_mint), false),
+            AccountMeta::new(get_associated_token_address(funder, reward_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build stake_lp instruction
+pub fn build_stake_lp_ix(owner: &Pubkey, lp_mint: &Pubkey, reward_mint: &Pubkey, amount: u64) -> Instruction {
+    let (gauge, _) = derive_gauge_pda(lp_mint, reward_mint);
+    let (stake_position, _) = derive_stake_pda(&gauge, owner);
+
+    let mut data = anchor_discriminator("stake_lp").to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id: GAUGE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new(gauge, false),
+            AccountMeta::new_readonly(*lp_mint, false),
+            AccountMeta::new_readonly(*reward_mint, false),
+            AccountMeta::new(stake_position, false),
+            AccountMeta::new(get_associated_token_address(&gauge, lp_mint), false),
+            AccountMeta::new(get_associated_token_address(&gauge, reward_mint), false),
+            AccountMeta::new(get_associated_token_address(owner, lp_mint), false),
+            AccountMeta::new(get_associated_token_address(owner, reward_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build unstake_lp instruction for `position_owner`'s position
+// LP tokens and rewards go to the ATAs of `signer`
+pub fn build_unstake_lp_ix(
+    signer: &Pubkey,
+    position_owner: &Pubkey,
+    lp_mint: &Pubkey,
+    reward_mint: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let (gauge, _) = derive_gauge_pda(lp_mint, reward_mint);
+    let (stake_position, _) = derive_stake_pda(&gauge, position_owner);
+
+    let mut data = anchor_discriminator("unstake_lp").to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id: GAUGE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new(gauge, false),
+            AccountMeta::new_readonly(*lp_mint, false),
+            AccountMeta::new_readonly(*reward_mint, false),
+            AccountMeta::new(stake_position, false),
+            AccountMeta::new(get_associated_token_address(&gauge, lp_mint), false),
+            AccountMeta::new(get_associated_token_address(&gauge, reward_mint), false),
+            AccountMeta::new(get_associated_token_address(signer, lp_mint), false),
+            AccountMeta::new(get_associated_token_address(signer, reward_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Build claim_emissions instruction for `position_owner`'s position
+// Rewards go to the reward ATA of `signer`
+pub fn build_claim_emissions_ix(
+    signer: &Pubkey,
+    position_owner: &Pubkey,
+    lp_mint: &Pubkey,
+    reward_mint: &Pubkey,
+) -> Instruction {
+    let (gauge, _) = derive_gauge_pda(lp_mint, reward_mint);
+    let (stake_position, _) = derive_stake_pda(&gauge, position_owner);
+
+    Instruction {
+        program_id: GAUGE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new(gauge, false),
+            AccountMeta::new_readonly(*reward_mint, false),
+            AccountMeta::new(stake_position, false),
+            AccountMeta::new(get_associated_token_address(&gauge, reward_mint), false),
+            AccountMeta::new(get_associated_token_address(signer, reward_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: anchor_discriminator("claim_emissions").to_vec(),
+    }
+}
+
+// Gauge fields the tests check
+#[derive(Debug)]
+pub struct GaugeState {
+    pub emission_end_ts: i64,
+    pub acc_reward_per_share: u128,
+    pub last_update_ts: i64,
+    pub total_staked: u64,
+}
+
+pub fn get_gauge(svm: &LiteSVM, gauge: &Pubkey) -> GaugeState {
+    let account = svm.get_account(gauge).expect("Gauge should exist");
+    let data = &account.data;
+
+    GaugeState {
+        emission_end_ts: i64::from_le_bytes(data[EMISSION_END_OFFSET..EMISSION_END_OFFSET + 8].try_into().unwrap()),
+        acc_reward_per_share: u128::from_le_bytes(data[ACC_REWARD_OFFSET..ACC_REWARD_OFFSET + 16].try_into().unwrap()),
+        last_update_ts: i64::from_le_bytes(data[LAST_UPDATE_OFFSET..LAST_UPDATE_OFFSET + 8].try_into().unwrap()),
+        total_staked: u64::from_le_bytes(data[TOTAL_STAKED_OFFSET..TOTAL_STAKED_OFFSET + 8].try_into().unwrap()),
+    }
+}
+
+// (amount, reward_debt) of a stake position
+pub fn get_stake_position(svm: &LiteSVM, stake_position: &Pubkey) -> (u64, u128) {
+    let account = svm.get_account(stake_position).expect("Stake position should exist");
+    let data = &account.data;
+
+    (
+        u64::from_le_bytes(data[STAKE_AMOUNT_OFFSET..STAKE_AMOUNT_OFFSET + 8].try_into().unwrap()),
+        u128::from_le_bytes(data[REWARD_DEBT_OFFSET..REWARD_DEBT_OFFSET + 16].try_into().unwrap()),
+    )
+}
+
+pub fn token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    let account: TokenAccount = get_spl_account(svm, token_account).expect("Token account should exist");
+    account.amount
+}
+
+// Gauge scenario setup result
+pub struct GaugeScenario {
+    pub svm: LiteSVM,
+    pub mint_authority: Keypair,
+    pub lp_mint: Pubkey,
+    pub reward_mint: Pubkey,
+    pub gauge: Pubkey,
+}
+
+// Create the LP and reward mints (the mint authority doubles as funder)
+pub fn setup_mints() -> (LiteSVM, Keypair, Pubkey, Pubkey) {
+    let mut svm = setup_svm();
+    let mint_authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let lp_mint = CreateMint::new(&mut svm, &mint_authority)
+        .authority(&mint_authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create LP mint");
+    let reward_mint = CreateMint::new(&mut svm, &mint_authority)
+        .authority(&mint_authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create reward mint");
+
+    let funder_ata = CreateAssociatedTokenAccount::new(&mut svm, &mint_authority, &reward_mint)
+        .owner(&mint_authority.pubkey())
+        .send()
+        .expect("Failed to create funder reward ATA");
+    MintTo::new(&mut svm, &mint_authority, &reward_mint, &funder_ata, REWARD_AMOUNT)
+        .owner(&mint_authority)
+        .send()
+        .expect("Failed to mint rewards to funder");
+
+    (svm, mint_authority, lp_mint, reward_mint)
+}
+
+// Create the mints and a gauge emitting EMISSION_RATE, funded with REWARD_AMOUNT
+pub fn setup_gauge_scenario() -> GaugeScenario {
+    let (mut svm, mint_authority, lp_mint, reward_mint) = setup_mints();
+
+    let ix = build_create_gauge_ix(&mint_authority.pubkey(), &lp_mint, &reward_mint, EMISSION_RATE, REWARD_AMOUNT);
+    send_ix(&mut svm, ix, &mint_authority).expect("Gauge creation should succeed");
+
+    let (gauge, _) = derive_gauge_pda(&lp_mint, &reward_mint);
+
+    GaugeScenario {
+        svm,
+        mint_authority,
+        lp_mint,
+        reward_mint,
+        gauge,
+    }
+}
+
+// Create a staker holding STAKER_LP_BALANCE LP tokens and an empty reward ATA
+pub fn create_staker(scenario: &mut GaugeScenario) -> Keypair {
+    let staker = create_funded_account(&mut scenario.svm, 5 * LAMPORTS_PER_SOL);
+
+    let lp_ata = CreateAssociatedTokenAccount::new(&mut scenario.svm, &staker, &scenario.lp_mint)
+        .owner(&staker.pubkey())
+        .send()
+        .expect("Failed to create staker LP ATA");
+    MintTo::new(&mut scenario.svm, &scenario.mint_authority, &scenario.lp_mint, &lp_ata, STAKER_LP_BALANCE)
+        .owner(&scenario.mint_authority)
+        .send()
+        .expect("Failed to mint LP tokens to staker");
+    CreateAssociatedTokenAccount::new(&mut scenario.svm, &staker, &scenario.reward_mint)
+        .owner(&staker.pubkey())
+        .send()
+        .expect("Failed to create staker reward ATA");
+
+    staker
+}
+
+// Stake `amount` LP tokens into the scenario's gauge
+pub fn stake(scenario: &mut GaugeScenario, owner: &Keypair, amount: u64) {
+    let ix = build_stake_lp_ix(&owner.pubkey(), &scenario.lp_mint, &scenario.reward_mint, amount);
+    send_ix(&mut scenario.svm, ix, owner).expect("Staking should succeed");
+}
+
+// Claim the owner's own rewards
+pub fn claim(scenario: &mut GaugeScenario, owner: &Keypair) -> TransactionResult {
+    let ix = build_claim_emissions_ix(&owner.pubkey(), &owner.pubkey(), &scenario.lp_mint, &scenario.reward_mint);
+    send_ix(&mut scenario.svm, ix, owner)
+}
+
+pub fn lp_balance(scenario: &GaugeScenario, owner: &Pubkey) -> u64 {
+    token_balance(&scenario.svm, &get_associated_token_address(owner, &scenario.lp_mint))
+}
+
+pub fn reward_balance(scenario: &GaugeScenario, owner: &Pubkey) -> u64 {
+    token_balance(&scenario.svm, &get_associated_token_address(owner, &scenario.reward_mint))
+}
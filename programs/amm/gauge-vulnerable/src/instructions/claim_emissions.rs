@@ -0,0 +1,92 @@
+// Claim Emissions Instruction
+//
+// Pays out the rewards a stake position has earned, leaving the stake as is.
+//
+// HOW IT WORKS:
+// 1. Brings the gauge's acc_reward_per_share up to date
+// 2. Settles the rewards earned since the last settlement
+// 3. Resets reward_debt so the same rewards can't be claimed twice
+// 4. Pays out the settled rewards, signed by the gauge PDA
+//
+// SECURITY:
+// - Only the position owner can claim (has_one = owner)
+// - reward_debt is updated before the transfer
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, helpers::*, state::*};
+
+#[derive(Accounts)]
+pub struct ClaimEmissions<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GAUGE_SEED, gauge.lp_mint.as_ref(), reward_mint.key().as_ref()],
+        bump = gauge.bump,
+        has_one = reward_mint,
+        has_one = reward_vault,
+    )]
+    pub gauge: Box<Account<'info, Gauge>>,
+
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, gauge.key().as_ref(), stake_position.owner.as_ref()],
+        bump = stake_position.bump,
+        has_one = gauge,
+        has_one = owner @ GaugeError::Unauthorized,
+    )]
+    pub stake_position: Box<Account<'info, StakePosition>>,
+
+    #[account(mut)]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_reward_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClaimEmissions<'info> {
+    pub fn claim_emissions(&mut self) -> Result<()> {
+        self.gauge.update(Clock::get()?.unix_timestamp)?;
+        let acc_reward_per_share = self.gauge.acc_reward_per_share;
+
+        let pending = self.stake_position.pending(acc_reward_per_share)?;
+        self.stake_position.reward_debt = self.stake_position.accrued(acc_reward_per_share)?;
+
+        if pending > 0 {
+            let lp_mint_key = self.gauge.lp_mint;
+            let reward_mint_key = self.reward_mint.key();
+            let gauge_seeds = &[
+                GAUGE_SEED,
+                lp_mint_key.as_ref(),
+                reward_mint_key.as_ref(),
+                &[self.gauge.bump],
+            ];
+
+            transfer_from_vault(
+                pending,
+                self.reward_mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.reward_vault.to_account_info(),
+                &self.reward_mint.to_account_info(),
+                &self.owner_reward_account.to_account_info(),
+                &self.gauge.to_account_info(),
+                gauge_seeds,
+            )?;
+        }
+
+        msg!("Claimed {} rewards", pending);
+
+        Ok(())
+    }
+}
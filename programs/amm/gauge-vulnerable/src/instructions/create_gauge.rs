@@ -0,0 +1,127 @@
+// Create Gauge Instruction
+//
+// Opens a gauge for an LP mint and funds it with the rewards it will emit.
+//
+// HOW IT WORKS:
+// 1. Creates the gauge PDA for (lp_mint, reward_mint)
+// 2. Creates the LP and reward vault ATAs owned by the gauge
+// 3. Moves `reward_amount` from the funder into the reward vault
+// 4. Starts emissions now, ending once `reward_amount` has been emitted
+//
+// SECURITY:
+// - LP and reward mints must differ, so stakes and rewards never share a vault
+// - Emission rate and reward amount must be non-zero
+// - Emissions end when the funded amount runs out, so claims never exceed the vault
+// - Vaults are recorded on the gauge and checked by every later instruction (has_one)
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, errors::*, helpers::*, state::*};
+
+#[derive(Accounts)]
+pub struct CreateGauge<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mint::token_program = token_program)]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = funder,
+        space = ANCHOR_DISCRIMINATOR + Gauge::INIT_SPACE,
+        seeds = [GAUGE_SEED, lp_mint.key().as_ref(), reward_mint.key().as_ref()],
+        bump
+    )]
+    pub gauge: Box<Account<'info, Gauge>>,
+
+    #[account(
+        init,
+        payer = funder,
+        associated_token::mint = lp_mint,
+        associated_token::authority = gauge,
+        associated_token::token_program = token_program,
+    )]
+    pub lp_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = funder,
+        associated_token::mint = reward_mint,
+        associated_token::authority = gauge,
+        associated_token::token_program = token_program,
+    )]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = funder,
+        token::token_program = token_program,
+    )]
+    pub funder_reward_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateGauge<'info> {
+    pub fn create_gauge(
+        &mut self,
+        emission_rate: u64,
+        reward_amount: u64,
+        bumps: &CreateGaugeBumps,
+    ) -> Result<()> {
+        require_keys_neq!(
+            self.lp_mint.key(),
+            self.reward_mint.key(),
+            GaugeError::IdenticalMints
+        );
+        require!(emission_rate > 0, GaugeError::ZeroEmissionRate);
+        require!(reward_amount >= emission_rate, GaugeError::InsufficientRewards);
+
+        // Whole seconds only: any remainder below one second's emission stays in the vault
+        let now = Clock::get()?.unix_timestamp;
+        let duration = i64::try_from(reward_amount / emission_rate).map_err(|_| GaugeError::Overflow)?;
+        let emission_end_ts = now.checked_add(duration).ok_or(GaugeError::Overflow)?;
+
+        self.gauge.set_inner(Gauge {
+            lp_mint: self.lp_mint.key(),
+            reward_mint: self.reward_mint.key(),
+            lp_vault: self.lp_vault.key(),
+            reward_vault: self.reward_vault.key(),
+            emission_rate,
+            emission_end_ts,
+            acc_reward_per_share: 0,
+            last_update_ts: now,
+            total_staked: 0,
+            bump: bumps.gauge,
+        });
+
+        transfer_tokens(
+            reward_amount,
+            self.reward_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.funder_reward_account.to_account_info(),
+            &self.reward_mint.to_account_info(),
+            &self.reward_vault.to_account_info(),
+            &self.funder.to_account_info(),
+        )?;
+
+        msg!(
+            "Gauge created: {} rewards per second for {} seconds",
+            emission_rate,
+            duration
+        );
+
+        Ok(())
+    }
+}
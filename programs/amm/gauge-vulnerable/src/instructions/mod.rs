@@ -0,0 +1,13 @@
+// Instructions Module
+//
+// Exports all instruction handlers for the gauge program
+
+pub mod create_gauge;
+pub mod stake_lp;
+pub mod unstake_lp;
+pub mod claim_emissions;
+
+pub use create_gauge::*;
+pub use stake_lp::*;
+pub use unstake_lp::*;
+pub use claim_emissions::*;
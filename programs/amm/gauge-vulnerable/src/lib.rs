@@ -0,0 +1,55 @@
+// Liquidity Mining Gauge Program - VULNERABLE VERSION
+//
+// WARNING: This program contains intentional security vulnerabilities for educational purposes.
+// NEVER deploy this to production. Use gauge-secure instead.
+//
+// This vulnerable gauge demonstrates the classic reward-debt ordering flaw:
+// - stake_lp adds the new LP tokens to the stake before settling its rewards,
+//   so a fresh stake is paid for emissions from before it existed
+//
+// VULNERABILITIES DOCUMENTED:
+// See individual instruction files for detailed vulnerability explanations.
+// Each vulnerability is marked with VULNERABILITY comments.
+//
+// REWARD MODEL:
+// The gauge tracks acc_reward_per_share: rewards emitted per staked LP token,
+// scaled by REWARD_PRECISION. A position has earned
+// amount * acc_reward_per_share / REWARD_PRECISION - reward_debt, where
+// reward_debt is the same product taken at the last settlement.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("BnhLFRa5zrhXSQxzeZrKrfdQjg52YeanZJMJv2hJmJ8A");
+
+#[program]
+pub mod gauge_vulnerable {
+    use super::*;
+
+    // Create a gauge emitting `emission_rate` rewards per second until `reward_amount` is used up
+    pub fn create_gauge(ctx: Context<CreateGauge>, emission_rate: u64, reward_amount: u64) -> Result<()> {
+        ctx.accounts.create_gauge(emission_rate, reward_amount, &ctx.bumps)
+    }
+
+    // Stake LP tokens, paying out rewards earned so far
+    pub fn stake_lp(ctx: Context<StakeLp>, amount: u64) -> Result<()> {
+        ctx.accounts.stake_lp(amount, &ctx.bumps)
+    }
+
+    // Unstake LP tokens, paying out rewards earned so far
+    pub fn unstake_lp(ctx: Context<UnstakeLp>, amount: u64) -> Result<()> {
+        ctx.accounts.unstake_lp(amount)
+    }
+
+    // Pay out rewards earned so far
+    pub fn claim_emissions(ctx: Context<ClaimEmissions>) -> Result<()> {
+        ctx.accounts.claim_emissions()
+    }
+}
@@ -0,0 +1,72 @@
+// Gauge State
+//
+// One Gauge per (LP mint, reward mint). Holds the staked LP tokens and the
+// rewards still to be emitted, and the running acc_reward_per_share every
+// stake position is settled against.
+//
+// Emissions run at emission_rate per second from creation until
+// emission_end_ts, the time the funded reward amount is used up.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*};
+
+#[account]
+#[derive(InitSpace)]
+pub struct Gauge {
+    pub lp_mint: Pubkey,
+
+    pub reward_mint: Pubkey,
+
+    // Vaults: ATAs of the gauge PDA
+    pub lp_vault: Pubkey,
+
+    pub reward_vault: Pubkey,
+
+    // Reward tokens emitted per second, shared across all stakers
+    pub emission_rate: u64,
+
+    // Emissions stop here: creation + reward_amount / emission_rate
+    pub emission_end_ts: i64,
+
+    // Rewards emitted per staked LP token, scaled by REWARD_PRECISION
+    pub acc_reward_per_share: u128,
+
+    // Time acc_reward_per_share was last brought up to date
+    pub last_update_ts: i64,
+
+    // LP tokens held in the LP vault
+    pub total_staked: u64,
+
+    // PDA bump seed
+    pub bump: u8,
+}
+
+impl Gauge {
+    // Accrue the emissions since the last update into acc_reward_per_share
+    // Must run before any stake changes, or the new stake earns for time it wasn't staked
+    pub fn update(&mut self, now: i64) -> Result<()> {
+        let now = now.min(self.emission_end_ts);
+        if now <= self.last_update_ts {
+            return Ok(());
+        }
+
+        // With nothing staked the emissions stay in the vault
+        if self.total_staked > 0 {
+            let elapsed = (now - self.last_update_ts) as u128;
+            let emitted = elapsed
+                .checked_mul(self.emission_rate as u128)
+                .ok_or(GaugeError::Overflow)?;
+            let increment = emitted
+                .checked_mul(REWARD_PRECISION)
+                .ok_or(GaugeError::Overflow)?
+                / self.total_staked as u128;
+            self.acc_reward_per_share = self
+                .acc_reward_per_share
+                .checked_add(increment)
+                .ok_or(GaugeError::Overflow)?;
+        }
+
+        self.last_update_ts = now;
+        Ok(())
+    }
+}
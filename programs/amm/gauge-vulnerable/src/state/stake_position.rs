@@ -0,0 +1,46 @@
+// Stake Position State
+//
+// One owner's LP stake in one gauge. reward_debt is the share of
+// acc_reward_per_share the position has already been paid (or was never
+// entitled to), so only emissions after each settlement count as earned.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*};
+
+#[account]
+#[derive(InitSpace)]
+pub struct StakePosition {
+    // Gauge the position belongs to
+    pub gauge: Pubkey,
+
+    // Only this address can unstake or claim
+    pub owner: Pubkey,
+
+    // LP tokens staked
+    pub amount: u64,
+
+    // amount * acc_reward_per_share / REWARD_PRECISION at the last settlement
+    pub reward_debt: u128,
+
+    // PDA bump seed
+    pub bump: u8,
+}
+
+impl StakePosition {
+    // Rewards the current stake would have earned since the gauge started
+    pub fn accrued(&self, acc_reward_per_share: u128) -> Result<u128> {
+        let accrued = (self.amount as u128)
+            .checked_mul(acc_reward_per_share)
+            .ok_or(GaugeError::Overflow)?;
+        Ok(accrued / REWARD_PRECISION)
+    }
+
+    // Rewards earned since the last settlement
+    pub fn pending(&self, acc_reward_per_share: u128) -> Result<u64> {
+        let pending = self
+            .accrued(acc_reward_per_share)?
+            .checked_sub(self.reward_debt)
+            .ok_or(GaugeError::Underflow)?;
+        u64::try_from(pending).map_err(|_| error!(GaugeError::Overflow))
+    }
+}
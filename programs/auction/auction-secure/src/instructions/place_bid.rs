@@ -0,0 +1,139 @@
+// Place Bid Instruction
+//
+// Places a bid that beats the current highest bid and refunds the bidder it replaces.
+//
+// HOW IT WORKS:
+// 1. Checks the auction is open and has not reached end_ts
+// 2. Checks the bid is at least min_bid (first bid) or highest_bid + min_increment
+// 3. Transfers the new bid into bid_vault
+// 4. Refunds the previous highest bid from bid_vault to the previous bidder
+// 5. Records the new highest bid and bidder
+//
+// SECURITY:
+// - Bids only accepted while now < end_ts
+// - Seller cannot bid on their own auction (no shill bidding)
+// - Previous bidder's refund account must be owned by auction.highest_bidder,
+//   so a refund cannot be redirected to the new bidder
+// - bid_vault only ever holds the single highest bid
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct PlaceBid<'info> {
+    pub bidder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, auction.seller.as_ref(), auction.seed.to_le_bytes().as_ref()],
+        bump = auction.bump,
+        has_one = bid_mint,
+        has_one = bid_vault,
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+
+    pub bid_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = bid_mint,
+        token::authority = bidder,
+        token::token_program = token_program,
+    )]
+    pub bidder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub bid_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    // Previous highest bidder's bid-mint account, required once the auction has a bid
+    #[account(mut)]
+    pub previous_bidder_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+impl<'info> PlaceBid<'info> {
+    pub fn place_bid(&mut self, amount: u64) -> Result<()> {
+        self.auction.assert_open()?;
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(now < self.auction.end_ts, AuctionError::AuctionEnded);
+
+        require!(
+            self.bidder.key() != self.auction.seller,
+            AuctionError::SellerCannotBid
+        );
+
+        require!(
+            amount >= self.auction.minimum_next_bid()?,
+            AuctionError::BidTooLow
+        );
+
+        transfer_tokens(
+            amount,
+            self.bid_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.bidder_token_account.to_account_info(),
+            &self.bid_mint.to_account_info(),
+            &self.bid_vault.to_account_info(),
+            &self.bidder.to_account_info(),
+        )?;
+
+        // Return the outbid amount before recording the new leader
+        if self.auction.highest_bid > 0 {
+            self.refund_previous_bidder()?;
+        }
+
+        self.auction.highest_bid = amount;
+        self.auction.highest_bidder = self.bidder.key();
+
+        msg!("New highest bid: {} by {}", amount, self.bidder.key());
+
+        Ok(())
+    }
+
+    // Refund the current highest bid to the bidder who placed it
+    fn refund_previous_bidder(&self) -> Result<()> {
+        let refund_account = self
+            .previous_bidder_token_account
+            .as_ref()
+            .ok_or(AuctionError::MissingPreviousBidderAccount)?;
+
+        require_keys_eq!(
+            refund_account.owner,
+            self.auction.highest_bidder,
+            AuctionError::InvalidPreviousBidderAccount
+        );
+        require_keys_eq!(
+            refund_account.mint,
+            self.auction.bid_mint,
+            AuctionError::InvalidPreviousBidderAccount
+        );
+
+        let seller = self.auction.seller;
+        let seed_bytes = self.auction.seed.to_le_bytes();
+        let auction_seeds = &[
+            AUCTION_SEED,
+            seller.as_ref(),
+            seed_bytes.as_ref(),
+            &[self.auction.bump],
+        ];
+
+        transfer_from_vault(
+            self.auction.highest_bid,
+            self.bid_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.bid_vault.to_account_info(),
+            &self.bid_mint.to_account_info(),
+            &refund_account.to_account_info(),
+            &self.auction.to_account_info(),
+            auction_seeds,
+        )?;
+
+        msg!("Refunded {} to {}", self.auction.highest_bid, self.auction.highest_bidder);
+
+        Ok(())
+    }
+}
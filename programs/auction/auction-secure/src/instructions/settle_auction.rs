@@ -0,0 +1,120 @@
+// Settle Auction Instruction
+//
+// Pays out an auction once it has ended: the asset to the winner and the
+// winning bid to the seller. Anyone can crank settlement.
+//
+// HOW IT WORKS:
+// 1. Checks the auction is open, has ended, and has at least one bid
+// 2. Transfers the asset from asset_vault to the highest bidder
+// 3. Transfers the highest bid from bid_vault to the seller
+// 4. Marks the auction settled
+//
+// SECURITY:
+// - Only after end_ts, so the auction cannot be closed while others can still bid
+// - Settles at most once
+// - Asset destination must be owned by auction.highest_bidder
+// - Payment destination must be owned by auction.seller
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct SettleAuction<'info> {
+    pub settler: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, auction.seller.as_ref(), auction.seed.to_le_bytes().as_ref()],
+        bump = auction.bump,
+        has_one = asset_mint,
+        has_one = bid_mint,
+        has_one = asset_vault,
+        has_one = bid_vault,
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub bid_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub asset_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub bid_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = asset_mint,
+        token::authority = auction.highest_bidder,
+        token::token_program = token_program,
+    )]
+    pub winner_asset_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = bid_mint,
+        token::authority = auction.seller,
+        token::token_program = token_program,
+    )]
+    pub seller_bid_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> SettleAuction<'info> {
+    pub fn settle_auction(&mut self) -> Result<()> {
+        self.auction.assert_open()?;
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            cfg!(feature = "vuln-missing-end-check") || now >= self.auction.end_ts,
+            AuctionError::AuctionNotEnded
+        );
+
+        require!(self.auction.highest_bid > 0, AuctionError::NoBids);
+
+        let seller = self.auction.seller;
+        let seed_bytes = self.auction.seed.to_le_bytes();
+        let auction_seeds = &[
+            AUCTION_SEED,
+            seller.as_ref(),
+            seed_bytes.as_ref(),
+            &[self.auction.bump],
+        ];
+
+        transfer_from_vault(
+            self.auction.asset_amount,
+            self.asset_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.asset_vault.to_account_info(),
+            &self.asset_mint.to_account_info(),
+            &self.winner_asset_account.to_account_info(),
+            &self.auction.to_account_info(),
+            auction_seeds,
+        )?;
+
+        transfer_from_vault(
+            self.auction.highest_bid,
+            self.bid_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.bid_vault.to_account_info(),
+            &self.bid_mint.to_account_info(),
+            &self.seller_bid_account.to_account_info(),
+            &self.auction.to_account_info(),
+            auction_seeds,
+        )?;
+
+        self.auction.settled = true;
+
+        msg!(
+            "Auction settled: {} won for {}",
+            self.auction.highest_bidder,
+            self.auction.highest_bid
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+// English Auction Program - SECURE VERSION
+//
+// Implementation of an ascending-price (English) auction for an NFT or any
+// amount of a fungible token, paid for in a second token.
+// This program allows users to:
+// 1. Escrow an asset and open an auction with a minimum bid and increment
+// 2. Outbid the current leader, who is refunded automatically
+// 3. Settle the auction after it ends, paying the seller and the winner
+// 4. Cancel an auction that never received a bid
+//
+// SECURITY FEATURES:
+// - Outbid bidders refunded in the same instruction, to their own account
+// - Bids only before end_ts, settlement only after end_ts
+// - Seller cannot bid on their own auction
+// - Payouts constrained to the recorded winner and seller
+// - Checked arithmetic to prevent overflow/underflow
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("HEjngNmVwJ9bUYgTterQN91Z44g3bCDVpyfBs7fGraFn");
+
+#[program]
+pub mod auction_secure {
+    use super::*;
+
+    // Escrow an asset and open an auction for it
+    pub fn create_auction(
+        ctx: Context<CreateAuction>,
+        seed: u64,
+        asset_amount: u64,
+        min_bid: u64,
+        min_increment: u64,
+        duration_seconds: i64,
+    ) -> Result<()> {
+        ctx.accounts.create_auction(
+            seed,
+            asset_amount,
+            min_bid,
+            min_increment,
+            duration_seconds,
+            &ctx.bumps,
+        )
+    }
+
+    // Outbid the current leader, refunding them
+    pub fn place_bid(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
+        ctx.accounts.place_bid(amount)
+    }
+
+    // Pay out an ended auction (anyone can call)
+    pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
+        ctx.accounts.settle_auction()
+    }
+
+    // Withdraw an auction with no bids
+    pub fn cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
+        ctx.accounts.cancel_auction()
+    }
+}
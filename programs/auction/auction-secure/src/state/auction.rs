@@ -0,0 +1,84 @@
+// Auction State
+//
+// One English auction: the seller escrows an asset (an NFT or any amount of
+// a fungible token) and bidders compete in a second token until end_ts.
+// Only the current highest bid is held in the bid vault; every outbid
+// bidder is refunded in the same instruction that outbids them.
+
+
+use anchor_lang::prelude::*;
+use crate::errors::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Auction {
+    // Account selling the asset, receives the winning bid
+    pub seller: Pubkey,
+
+    // Mint of the asset being sold (decimals 0, amount 1 for an NFT)
+    pub asset_mint: Pubkey,
+
+    // Mint bids are paid in
+    pub bid_mint: Pubkey,
+
+    // Vault ATA holding the escrowed asset (owned by this PDA)
+    pub asset_vault: Pubkey,
+
+    // Vault ATA holding the current highest bid (owned by this PDA)
+    pub bid_vault: Pubkey,
+
+    // Seller-chosen seed so one seller can run several auctions
+    pub seed: u64,
+
+    // Amount of the asset being sold
+    pub asset_amount: u64,
+
+    // Lowest acceptable first bid
+    pub min_bid: u64,
+
+    // Each new bid must beat the highest bid by at least this much
+    pub min_increment: u64,
+
+    // Current highest bid (0 when there are no bids)
+    pub highest_bid: u64,
+
+    // Current highest bidder (default when there are no bids)
+    pub highest_bidder: Pubkey,
+
+    // Unix timestamp the auction opened
+    pub start_ts: i64,
+
+    // Unix timestamp after which no bids are accepted and the auction can settle
+    pub end_ts: i64,
+
+    // Set once the asset and winning bid have been paid out
+    pub settled: bool,
+
+    // Set once the seller has cancelled a bidless auction
+    pub cancelled: bool,
+
+    pub bump: u8,
+}
+
+impl Auction {
+    // Assert the auction is still open (not settled or cancelled)
+    pub fn assert_open(&self) -> Result<()> {
+        require!(!self.settled && !self.cancelled, AuctionError::AuctionClosed);
+        Ok(())
+    }
+
+    // Lowest bid the auction will accept next
+    // First bid: min_bid. Afterwards: highest_bid + min_increment
+    pub fn minimum_next_bid(&self) -> Result<u64> {
+        if self.highest_bid == 0 {
+            return Ok(self.min_bid);
+        }
+
+        let next = self
+            .highest_bid
+            .checked_add(self.min_increment)
+            .ok_or(AuctionError::Overflow)?;
+
+        Ok(next)
+    }
+}
@@ -0,0 +1,64 @@
+// Compute-unit regression tests for auction-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+mod utils;
+
+use solana_sdk::signature::Signer;
+use soteria_test_kit::{ComputeBudget, DEFAULT_CU_BASELINES_FILE};
+use utils::*;
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let mut scenario = setup_auction_scenario();
+    let seller = scenario.seller.pubkey();
+    let (asset_mint, bid_mint) = (scenario.asset_mint, scenario.bid_mint);
+
+    let ix = build_create_auction_ix(
+        &seller,
+        &asset_mint,
+        &bid_mint,
+        1,
+        ASSET_AMOUNT,
+        MIN_BID,
+        MIN_INCREMENT,
+        AUCTION_DURATION,
+    );
+    budget.record("create_auction", send_ix(&mut scenario.svm, ix, &scenario.seller));
+
+    // Cancelling returns the asset, which the second auction then sells
+    let (cancelled, _) = derive_auction_pda(&seller, 1);
+    let ix = build_cancel_auction_ix(&seller, &cancelled, &asset_mint);
+    budget.record("cancel_auction", send_ix(&mut scenario.svm, ix, &scenario.seller));
+
+    let auction = create_default_auction(&mut scenario, 2);
+    let alice = create_bidder(&mut scenario);
+    let bob = create_bidder(&mut scenario);
+
+    let ix = build_place_bid_ix(&alice.pubkey(), &auction, &bid_mint, MIN_BID, None);
+    budget.record("place_bid_first", send_ix(&mut scenario.svm, ix, &alice));
+
+    // Outbidding also refunds the previous bidder
+    let amount = MIN_BID + MIN_INCREMENT;
+    let ix = build_place_bid_ix(&bob.pubkey(), &auction, &bid_mint, amount, Some(&alice.pubkey()));
+    budget.record("place_bid_outbid", send_ix(&mut scenario.svm, ix, &bob));
+
+    advance_time(&mut scenario.svm, AUCTION_DURATION);
+    let ix = build_settle_auction_ix(
+        &alice.pubkey(),
+        &auction,
+        &asset_mint,
+        &bid_mint,
+        &bob.pubkey(),
+        &seller,
+    );
+    budget.record("settle_auction", send_ix(&mut scenario.svm, ix, &alice));
+
+    budget.check();
+}
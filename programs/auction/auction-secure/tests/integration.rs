@@ -0,0 +1,344 @@
+// Integration tests for auction program using LiteSVM
+// These tests verify core auction functionality: creation, outbidding with
+// automatic refunds, settlement timing, and cancellation
+
+mod utils;
+
+use utils::*;
+use litesvm::{types::TransactionResult, LiteSVM};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use soteria_test_kit::{
+    run_feature_differential, DifferentialScenario, ExploitInfo, FeatureVariant, Severity,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+#[test]
+fn test_create_auction() {
+    // Test: Create an auction and reject invalid parameters
+    println!("\n[TEST START] test_create_auction - Auction creation and validation");
+
+    let mut scenario = setup_auction_scenario();
+    println!("[Setup] Seller holds {} asset token (NFT)", ASSET_AMOUNT);
+
+    // Duration below the minimum is rejected
+    println!("[Action] Creating auction lasting 10 seconds");
+    let ix = build_create_auction_ix(
+        &scenario.seller.pubkey(),
+        &scenario.asset_mint,
+        &scenario.bid_mint,
+        1,
+        ASSET_AMOUNT,
+        MIN_BID,
+        MIN_INCREMENT,
+        10,
+    );
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.seller).is_err(), "Too-short auction should fail");
+    println!("[Success] Duration below minimum rejected");
+
+    // Selling a token for itself is rejected
+    println!("[Action] Creating auction with identical asset and bid mints");
+    let ix = build_create_auction_ix(
+        &scenario.seller.pubkey(),
+        &scenario.asset_mint,
+        &scenario.asset_mint,
+        1,
+        ASSET_AMOUNT,
+        MIN_BID,
+        MIN_INCREMENT,
+        AUCTION_DURATION,
+    );
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.seller).is_err(), "Identical mints should fail");
+    println!("[Success] Identical mints rejected");
+
+    println!("[Action] Creating default auction");
+    let auction = create_default_auction(&mut scenario, 1);
+
+    let asset_vault = get_associated_token_address(&auction, &scenario.asset_mint);
+    assert_eq!(token_balance(&scenario.svm, &asset_vault), ASSET_AMOUNT);
+    assert_eq!(token_balance(&scenario.svm, &scenario.seller_asset_ata), 0);
+    assert_eq!(get_highest_bid(&scenario.svm, &auction).0, 0);
+    println!("[Success] Asset escrowed in auction vault");
+
+    println!("[TEST END] test_create_auction");
+}
+
+#[test]
+fn test_bid_and_refund() {
+    // Test: Each new highest bid refunds the bidder it replaces
+    println!("\n[TEST START] test_bid_and_refund - Outbidding with automatic refund");
+
+    let mut scenario = setup_auction_scenario();
+    let auction = create_default_auction(&mut scenario, 1);
+    let alice = create_bidder(&mut scenario);
+    let bob = create_bidder(&mut scenario);
+    println!("[Setup] Auction open, Alice and Bob hold {} bid tokens each", BIDDER_BALANCE / ONE_TOKEN);
+
+    // First bid below the minimum is rejected
+    let ix = build_place_bid_ix(&alice.pubkey(), &auction, &scenario.bid_mint, MIN_BID - 1, None);
+    assert!(send_ix(&mut scenario.svm, ix, &alice).is_err(), "Bid below minimum should fail");
+    println!("[Success] Bid below minimum rejected");
+
+    println!("[Action] Alice bids {}", MIN_BID / ONE_TOKEN);
+    let ix = build_place_bid_ix(&alice.pubkey(), &auction, &scenario.bid_mint, MIN_BID, None);
+    let result = send_ix(&mut scenario.svm, ix, &alice);
+    assert!(result.is_ok(), "First bid failed: {:?}", result.err());
+    assert_eq!(bid_balance(&scenario, &alice.pubkey()), BIDDER_BALANCE - MIN_BID);
+
+    // Bob must beat Alice by the increment
+    let short_bid = MIN_BID + MIN_INCREMENT - 1;
+    let ix = build_place_bid_ix(&bob.pubkey(), &auction, &scenario.bid_mint, short_bid, Some(&alice.pubkey()));
+    assert!(send_ix(&mut scenario.svm, ix, &bob).is_err(), "Bid below increment should fail");
+    println!("[Success] Bid below the required increment rejected");
+
+    // Bob cannot send Alice's refund to himself
+    let bob_bid = MIN_BID + MIN_INCREMENT;
+    let ix = build_place_bid_ix(&bob.pubkey(), &auction, &scenario.bid_mint, bob_bid, Some(&bob.pubkey()));
+    assert!(send_ix(&mut scenario.svm, ix, &bob).is_err(), "Redirected refund should fail");
+    println!("[Success] Refund to the wrong account rejected");
+
+    // Outbidding without refunding Alice is not possible
+    let ix = build_place_bid_ix(&bob.pubkey(), &auction, &scenario.bid_mint, bob_bid, None);
+    assert!(send_ix(&mut scenario.svm, ix, &bob).is_err(), "Outbid without refund account should fail");
+    println!("[Success] Outbid without a refund account rejected");
+
+    println!("[Action] Bob bids {}", bob_bid / ONE_TOKEN);
+    let ix = build_place_bid_ix(&bob.pubkey(), &auction, &scenario.bid_mint, bob_bid, Some(&alice.pubkey()));
+    let result = send_ix(&mut scenario.svm, ix, &bob);
+    assert!(result.is_ok(), "Outbid failed: {:?}", result.err());
+
+    assert_eq!(bid_balance(&scenario, &alice.pubkey()), BIDDER_BALANCE);
+    assert_eq!(bid_balance(&scenario, &bob.pubkey()), BIDDER_BALANCE - bob_bid);
+    assert_eq!(bid_balance(&scenario, &auction), bob_bid);
+    assert_eq!(get_highest_bid(&scenario.svm, &auction), (bob_bid, bob.pubkey()));
+    println!("[Success] Alice refunded in full, vault holds only Bob's bid");
+
+    // The seller cannot bid up their own auction
+    let ix = build_place_bid_ix(&scenario.seller.pubkey(), &auction, &scenario.bid_mint, bob_bid * 2, Some(&bob.pubkey()));
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.seller).is_err(), "Seller bid should fail");
+    println!("[Success] Seller bid rejected");
+
+    println!("[TEST END] test_bid_and_refund");
+}
+
+#[test]
+fn test_settle_auction() {
+    // Test: Settlement waits for end_ts, then pays the seller and the winner once
+    println!("\n[TEST START] test_settle_auction - Settlement timing and payouts");
+
+    let mut scenario = setup_auction_scenario();
+    let auction = create_default_auction(&mut scenario, 1);
+    let alice = create_bidder(&mut scenario);
+    let bob = create_bidder(&mut scenario);
+
+    let ix = build_place_bid_ix(&alice.pubkey(), &auction, &scenario.bid_mint, MIN_BID, None);
+    send_ix(&mut scenario.svm, ix, &alice).expect("Alice's bid should succeed");
+    println!("[Setup] Alice leads with {}", MIN_BID / ONE_TOKEN);
+
+    println!("[Action] Alice tries to settle immediately");
+    let ix = build_settle_auction_ix(&alice.pubkey(), &auction, &scenario.asset_mint, &scenario.bid_mint, &alice.pubkey(), &scenario.seller.pubkey());
+    assert!(send_ix(&mut scenario.svm, ix, &alice).is_err(), "Early settlement should fail");
+    println!("[Success] Settlement before end rejected");
+
+    let bob_bid = MIN_BID * 2;
+    let ix = build_place_bid_ix(&bob.pubkey(), &auction, &scenario.bid_mint, bob_bid, Some(&alice.pubkey()));
+    send_ix(&mut scenario.svm, ix, &bob).expect("Bob's bid should succeed");
+    println!("[Setup] Bob outbids with {}", bob_bid / ONE_TOKEN);
+
+    advance_time(&mut scenario.svm, AUCTION_DURATION);
+    println!("[Setup] Auction has ended");
+
+    let ix = build_place_bid_ix(&alice.pubkey(), &auction, &scenario.bid_mint, bob_bid * 2, Some(&bob.pubkey()));
+    assert!(send_ix(&mut scenario.svm, ix, &alice).is_err(), "Bid after end should fail");
+    println!("[Success] Bid after end rejected");
+
+    // Payout accounts are pinned to the recorded winner
+    let ix = build_settle_auction_ix(&alice.pubkey(), &auction, &scenario.asset_mint, &scenario.bid_mint, &alice.pubkey(), &scenario.seller.pubkey());
+    assert!(send_ix(&mut scenario.svm, ix, &alice).is_err(), "Settling to the wrong winner should fail");
+    println!("[Success] Asset cannot be sent to a non-winner");
+
+    println!("[Action] Alice cranks settlement for Bob");
+    let ix = build_settle_auction_ix(&alice.pubkey(), &auction, &scenario.asset_mint, &scenario.bid_mint, &bob.pubkey(), &scenario.seller.pubkey());
+    let result = send_ix(&mut scenario.svm, ix, &alice);
+    assert!(result.is_ok(), "Settlement failed: {:?}", result.err());
+
+    assert_eq!(asset_balance(&scenario, &bob.pubkey()), ASSET_AMOUNT);
+    assert_eq!(token_balance(&scenario.svm, &scenario.seller_bid_ata), bob_bid);
+    assert_eq!(bid_balance(&scenario, &auction), 0);
+    println!("[Success] Bob received the asset, seller received {}", bob_bid / ONE_TOKEN);
+
+    let ix = build_settle_auction_ix(&alice.pubkey(), &auction, &scenario.asset_mint, &scenario.bid_mint, &bob.pubkey(), &scenario.seller.pubkey());
+    assert!(send_ix(&mut scenario.svm, ix, &alice).is_err(), "Second settlement should fail");
+    println!("[Success] Second settlement rejected");
+
+    println!("[TEST END] test_settle_auction");
+}
+
+#[test]
+fn test_cancel_auction() {
+    // Test: Only the seller can cancel, and only before any bid
+    println!("\n[TEST START] test_cancel_auction - Seller cancellation");
+
+    let mut scenario = setup_auction_scenario();
+    let auction = create_default_auction(&mut scenario, 1);
+    let alice = create_bidder(&mut scenario);
+
+    let ix = build_cancel_auction_ix(&alice.pubkey(), &auction, &scenario.asset_mint);
+    assert!(send_ix(&mut scenario.svm, ix, &alice).is_err(), "Non-seller cancel should fail");
+    println!("[Success] Non-seller cancel rejected");
+
+    let ix = build_place_bid_ix(&alice.pubkey(), &auction, &scenario.bid_mint, MIN_BID, None);
+    send_ix(&mut scenario.svm, ix, &alice).expect("Bid should succeed");
+
+    println!("[Action] Seller cancels after a bid");
+    let ix = build_cancel_auction_ix(&scenario.seller.pubkey(), &auction, &scenario.asset_mint);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.seller).is_err(), "Cancel with bids should fail");
+    println!("[Success] Cancel with an active bid rejected");
+
+    // A bidless auction ends up cancelled rather than settled
+    let mut scenario = setup_auction_scenario();
+    let auction = create_default_auction(&mut scenario, 2);
+    advance_time(&mut scenario.svm, AUCTION_DURATION);
+
+    let ix = build_settle_auction_ix(&scenario.seller.pubkey(), &auction, &scenario.asset_mint, &scenario.bid_mint, &scenario.seller.pubkey(), &scenario.seller.pubkey());
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.seller).is_err(), "Settling without bids should fail");
+    println!("[Success] Auction without bids cannot settle");
+
+    println!("[Action] Seller cancels the bidless auction");
+    let ix = build_cancel_auction_ix(&scenario.seller.pubkey(), &auction, &scenario.asset_mint);
+    let result = send_ix(&mut scenario.svm, ix, &scenario.seller);
+    assert!(result.is_ok(), "Cancel failed: {:?}", result.err());
+    assert_eq!(token_balance(&scenario.svm, &scenario.seller_asset_ata), ASSET_AMOUNT);
+    println!("[Success] Asset returned to seller");
+
+    println!("[TEST END] test_cancel_auction");
+}
+
+// The first bidder settles straight away and wins at the minimum price
+// (V002 in auction-vulnerable)
+struct EarlySettlement;
+
+struct EarlySettlementState {
+    bidder: Keypair,
+    seller: Pubkey,
+    auction: Pubkey,
+    asset_mint: Pubkey,
+    bid_mint: Pubkey,
+}
+
+impl DifferentialScenario for EarlySettlement {
+    type State = EarlySettlementState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Settlement Before End Time",
+            severity: Severity::High,
+            lesson: "Settle only once end_ts has passed and no more bids can arrive",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, _program_id: &Pubkey) -> EarlySettlementState {
+        let mint_authority = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let seller = create_funded_account(svm, 5 * LAMPORTS_PER_SOL);
+        let bidder = create_funded_account(svm, 5 * LAMPORTS_PER_SOL);
+
+        let asset_mint = CreateMint::new(svm, &mint_authority)
+            .authority(&mint_authority.pubkey())
+            .decimals(ASSET_DECIMALS)
+            .send()
+            .expect("Failed to create asset mint");
+        let bid_mint = CreateMint::new(svm, &mint_authority)
+            .authority(&mint_authority.pubkey())
+            .decimals(BID_DECIMALS)
+            .send()
+            .expect("Failed to create bid mint");
+
+        let seller_asset_ata = CreateAssociatedTokenAccount::new(svm, &seller, &asset_mint)
+            .owner(&seller.pubkey())
+            .send()
+            .expect("Failed to create seller asset ATA");
+        MintTo::new(svm, &mint_authority, &asset_mint, &seller_asset_ata, ASSET_AMOUNT)
+            .owner(&mint_authority)
+            .send()
+            .expect("Failed to mint asset");
+        CreateAssociatedTokenAccount::new(svm, &seller, &bid_mint)
+            .owner(&seller.pubkey())
+            .send()
+            .expect("Failed to create seller bid ATA");
+
+        CreateAssociatedTokenAccount::new(svm, &bidder, &asset_mint)
+            .owner(&bidder.pubkey())
+            .send()
+            .expect("Failed to create bidder asset ATA");
+        let bidder_bid_ata = CreateAssociatedTokenAccount::new(svm, &bidder, &bid_mint)
+            .owner(&bidder.pubkey())
+            .send()
+            .expect("Failed to create bidder bid ATA");
+        MintTo::new(svm, &mint_authority, &bid_mint, &bidder_bid_ata, BIDDER_BALANCE)
+            .owner(&mint_authority)
+            .send()
+            .expect("Failed to mint bid tokens");
+
+        let ix = build_create_auction_ix(
+            &seller.pubkey(),
+            &asset_mint,
+            &bid_mint,
+            1,
+            ASSET_AMOUNT,
+            MIN_BID,
+            MIN_INCREMENT,
+            AUCTION_DURATION,
+        );
+        send_ix(svm, ix, &seller).expect("Auction creation should succeed");
+        let auction = derive_auction_pda(&seller.pubkey(), 1).0;
+
+        let ix = build_place_bid_ix(&bidder.pubkey(), &auction, &bid_mint, MIN_BID, None);
+        send_ix(svm, ix, &bidder).expect("Bid should succeed");
+
+        EarlySettlementState {
+            bidder,
+            seller: seller.pubkey(),
+            auction,
+            asset_mint,
+            bid_mint,
+        }
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        state: &mut EarlySettlementState,
+    ) -> TransactionResult {
+        let bidder = state.bidder.pubkey();
+        let ix = build_settle_auction_ix(
+            &bidder,
+            &state.auction,
+            &state.asset_mint,
+            &state.bid_mint,
+            &bidder,
+            &state.seller,
+        );
+        send_ix(svm, ix, &state.bidder)
+    }
+}
+
+// Feature matrix: only the vuln-missing-end-check build settles before end_ts
+// Requires ./build-vuln-matrix.sh auction-secure from the repository root
+#[test]
+fn test_feature_missing_end_check() {
+    let variant = FeatureVariant {
+        program_id: AUCTION_PROGRAM_ID,
+        feature: "vuln-missing-end-check",
+    };
+    let (mut baseline, mut variant_svm) = variant.load(
+        "target/deploy/auction_secure.so",
+        variant.so_path(".", "auction_secure"),
+    );
+    run_feature_differential(&mut baseline, &mut variant_svm, &variant, &EarlySettlement);
+}
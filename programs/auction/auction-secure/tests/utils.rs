@@ -0,0 +1,323 @@
+// Test utilities for auction program
+
+use litesvm::{LiteSVM, types::TransactionResult};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo, get_spl_account};
+use litesvm_token::spl_token::state::Account as TokenAccount;
+use solana_sdk::{
+    clock::Clock,
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::program::ID as system_program;
+
+// Program ID matching declare_id!
+pub const AUCTION_PROGRAM_ID: Pubkey = Pubkey::new_from_array(auction_secure::ID.to_bytes());
+
+// Build Anchor instruction discriminator
+// Formula: first 8 bytes of sha256("global:method_name")
+pub fn anchor_discriminator(method: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", method);
+    let hash_result = hash(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash_result.to_bytes()[..8]);
+    discriminator
+}
+
+// Standard program IDs
+pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_account::ID;
+
+// PDA Seeds
+pub const AUCTION_SEED: &[u8] = b"auction";
+
+// The asset is an NFT-style token: 0 decimals, supply of 1
+pub const ASSET_DECIMALS: u8 = 0;
+pub const ASSET_AMOUNT: u64 = 1;
+
+// Bids are paid in a 9 decimal token
+pub const BID_DECIMALS: u8 = 9;
+pub const ONE_TOKEN: u64 = 1_000_000_000;
+
+// Default auction parameters
+pub const MIN_BID: u64 = 100 * ONE_TOKEN;
+pub const MIN_INCREMENT: u64 = 10 * ONE_TOKEN;
+pub const AUCTION_DURATION: i64 = 3_600;
+
+// Bid tokens each bidder starts with
+pub const BIDDER_BALANCE: u64 = 1_000 * ONE_TOKEN;
+
+// Setup LiteSVM with auction program
+pub fn setup_svm() -> LiteSVM {
+    let mut svm = LiteSVM::new();
+    let program_bytes = include_bytes!("../target/deploy/auction_secure.so");
+    let _ = svm.add_program(AUCTION_PROGRAM_ID, program_bytes);
+    svm
+}
+
+// Create and fund account
+pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
+    let keypair = Keypair::new();
+    svm.airdrop(&keypair.pubkey(), lamports)
+        .expect("Airdrop should succeed");
+    keypair
+}
+
+// Sign and send a single instruction
+pub fn send_ix(svm: &mut LiteSVM, ix: Instruction, signer: &Keypair) -> TransactionResult {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    svm.expire_blockhash();
+    result
+}
+
+pub fn advance_time(svm: &mut LiteSVM, seconds: i64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += seconds;
+    svm.set_sysvar::<Clock>(&clock);
+}
+
+// Derive auction PDA
+pub fn derive_auction_pda(seller: &Pubkey, seed: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[AUCTION_SEED, seller.as_ref(), &seed.to_le_bytes()],
+        &AUCTION_PROGRAM_ID,
+    )
+}
+
+// Build create_auction instruction
+pub fn build_create_auction_ix(
+    seller: &Pubkey,
+    asset_mint: &Pubkey,
+    bid_mint: &Pubkey,
+    seed: u64,
+    asset_amount: u64,
+    min_bid: u64,
+    min_increment: u64,
+    duration_seconds: i64,
+) -> Instruction {
+    let (auction, _) = derive_auction_pda(seller, seed);
+
+    let mut data = anchor_discriminator("create_auction").to_vec();
+    data.extend_from_slice(&seed.to_le_bytes());
+    data.extend_from_slice(&asset_amount.to_le_bytes());
+    data.extend_from_slice(&min_bid.to_le_bytes());
+    data.extend_from_slice(&min_increment.to_le_bytes());
+    data.extend_from_slice(&duration_seconds.to_le_bytes());
+
+    Instruction {
+        program_id: AUCTION_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*seller, true),
+            AccountMeta::new_readonly(*asset_mint, false),
+            AccountMeta::new_readonly(*bid_mint, false),
+            AccountMeta::new(get_associated_token_address(seller, asset_mint), false),
+            AccountMeta::new(auction, false),
+            AccountMeta::new(get_associated_token_address(&auction, asset_mint), false),
+            AccountMeta::new(get_associated_token_address(&auction, bid_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build place_bid instruction
+// `previous_bidder` is the current highest bidder to refund (None for the first bid)
+pub fn build_place_bid_ix(
+    bidder: &Pubkey,
+    auction: &Pubkey,
+    bid_mint: &Pubkey,
+    amount: u64,
+    previous_bidder: Option<&Pubkey>,
+) -> Instruction {
+    let mut data = anchor_discriminator("place_bid").to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    // previous_bidder_token_account: program ID means None for Anchor optional accounts
+    let previous_bidder_account = match previous_bidder {
+        Some(previous) => AccountMeta::new(get_associated_token_address(previous, bid_mint), false),
+        None => AccountMeta::new_readonly(AUCTION_PROGRAM_ID, false),
+    };
+
+    Instruction {
+        program_id: AUCTION_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*bidder, true),
+            AccountMeta::new(*auction, false),
+            AccountMeta::new_readonly(*bid_mint, false),
+            AccountMeta::new(get_associated_token_address(bidder, bid_mint), false),
+            AccountMeta::new(get_associated_token_address(auction, bid_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            previous_bidder_account,
+        ],
+        data,
+    }
+}
+
+// Build settle_auction instruction
+pub fn build_settle_auction_ix(
+    settler: &Pubkey,
+    auction: &Pubkey,
+    asset_mint: &Pubkey,
+    bid_mint: &Pubkey,
+    winner: &Pubkey,
+    seller: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: AUCTION_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*settler, true),
+            AccountMeta::new(*auction, false),
+            AccountMeta::new_readonly(*asset_mint, false),
+            AccountMeta::new_readonly(*bid_mint, false),
+            AccountMeta::new(get_associated_token_address(auction, asset_mint), false),
+            AccountMeta::new(get_associated_token_address(auction, bid_mint), false),
+            AccountMeta::new(get_associated_token_address(winner, asset_mint), false),
+            AccountMeta::new(get_associated_token_address(seller, bid_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: anchor_discriminator("settle_auction").to_vec(),
+    }
+}
+
+// Build cancel_auction instruction
+pub fn build_cancel_auction_ix(seller: &Pubkey, auction: &Pubkey, asset_mint: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: AUCTION_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*seller, true),
+            AccountMeta::new(*auction, false),
+            AccountMeta::new_readonly(*asset_mint, false),
+            AccountMeta::new(get_associated_token_address(auction, asset_mint), false),
+            AccountMeta::new(get_associated_token_address(seller, asset_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: anchor_discriminator("cancel_auction").to_vec(),
+    }
+}
+
+// Read (highest_bid, highest_bidder) from an Auction
+// Layout: discriminator (8) + 5 pubkeys (160) + seed (8) + asset_amount (8)
+//         + min_bid (8) + min_increment (8) + highest_bid (8) + highest_bidder (32)
+pub fn get_highest_bid(svm: &LiteSVM, auction: &Pubkey) -> (u64, Pubkey) {
+    let account = svm.get_account(auction).expect("Auction should exist");
+    let offset = 8 + 32 * 5 + 8 * 4;
+    let highest_bid = u64::from_le_bytes(account.data[offset..offset + 8].try_into().unwrap());
+    let highest_bidder = Pubkey::new_from_array(account.data[offset + 8..offset + 40].try_into().unwrap());
+    (highest_bid, highest_bidder)
+}
+
+pub fn token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    let account: TokenAccount = get_spl_account(svm, token_account).expect("Token account should exist");
+    account.amount
+}
+
+// Auction scenario setup result
+pub struct AuctionScenario {
+    pub svm: LiteSVM,
+    pub mint_authority: Keypair,
+    pub seller: Keypair,
+    pub asset_mint: Pubkey,
+    pub bid_mint: Pubkey,
+    pub seller_asset_ata: Pubkey,
+    pub seller_bid_ata: Pubkey,
+}
+
+// Create the asset and bid mints, give the seller ASSET_AMOUNT of the asset,
+// and create the seller's bid-mint account for the proceeds
+pub fn setup_auction_scenario() -> AuctionScenario {
+    let mut svm = setup_svm();
+    let mint_authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let seller = create_funded_account(&mut svm, 5 * LAMPORTS_PER_SOL);
+
+    let asset_mint = CreateMint::new(&mut svm, &mint_authority)
+        .authority(&mint_authority.pubkey())
+        .decimals(ASSET_DECIMALS)
+        .send()
+        .expect("Failed to create asset mint");
+    let bid_mint = CreateMint::new(&mut svm, &mint_authority)
+        .authority(&mint_authority.pubkey())
+        .decimals(BID_DECIMALS)
+        .send()
+        .expect("Failed to create bid mint");
+
+    let seller_asset_ata = CreateAssociatedTokenAccount::new(&mut svm, &seller, &asset_mint)
+        .owner(&seller.pubkey())
+        .send()
+        .expect("Failed to create seller asset ATA");
+    MintTo::new(&mut svm, &mint_authority, &asset_mint, &seller_asset_ata, ASSET_AMOUNT)
+        .owner(&mint_authority)
+        .send()
+        .expect("Failed to mint asset");
+
+    let seller_bid_ata = CreateAssociatedTokenAccount::new(&mut svm, &seller, &bid_mint)
+        .owner(&seller.pubkey())
+        .send()
+        .expect("Failed to create seller bid ATA");
+
+    AuctionScenario {
+        svm,
+        mint_authority,
+        seller,
+        asset_mint,
+        bid_mint,
+        seller_asset_ata,
+        seller_bid_ata,
+    }
+}
+
+// Create a bidder holding BIDDER_BALANCE bid tokens, with ATAs for both mints
+pub fn create_bidder(scenario: &mut AuctionScenario) -> Keypair {
+    let bidder = create_funded_account(&mut scenario.svm, 5 * LAMPORTS_PER_SOL);
+
+    CreateAssociatedTokenAccount::new(&mut scenario.svm, &bidder, &scenario.asset_mint)
+        .owner(&bidder.pubkey())
+        .send()
+        .expect("Failed to create bidder asset ATA");
+    let bid_ata = CreateAssociatedTokenAccount::new(&mut scenario.svm, &bidder, &scenario.bid_mint)
+        .owner(&bidder.pubkey())
+        .send()
+        .expect("Failed to create bidder bid ATA");
+    MintTo::new(&mut scenario.svm, &scenario.mint_authority, &scenario.bid_mint, &bid_ata, BIDDER_BALANCE)
+        .owner(&scenario.mint_authority)
+        .send()
+        .expect("Failed to mint bid tokens");
+
+    bidder
+}
+
+// Open the default auction (MIN_BID, MIN_INCREMENT, AUCTION_DURATION) and return its address
+pub fn create_default_auction(scenario: &mut AuctionScenario, seed: u64) -> Pubkey {
+    let ix = build_create_auction_ix(
+        &scenario.seller.pubkey(),
+        &scenario.asset_mint,
+        &scenario.bid_mint,
+        seed,
+        ASSET_AMOUNT,
+        MIN_BID,
+        MIN_INCREMENT,
+        AUCTION_DURATION,
+    );
+    send_ix(&mut scenario.svm, ix, &scenario.seller).expect("Auction creation should succeed");
+
+    derive_auction_pda(&scenario.seller.pubkey(), seed).0
+}
+
+pub fn bid_balance(scenario: &AuctionScenario, owner: &Pubkey) -> u64 {
+    token_balance(&scenario.svm, &get_associated_token_address(owner, &scenario.bid_mint))
+}
+
+pub fn asset_balance(scenario: &AuctionScenario, owner: &Pubkey) -> u64 {
+    token_balance(&scenario.svm, &get_associated_token_address(owner, &scenario.asset_mint))
+}
@@ -0,0 +1,64 @@
+// Auction Helper Functions
+//
+// Reusable CPI helpers for the auction program.
+// These functions reduce code duplication across instructions.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TransferChecked, transfer_checked};
+
+// CPI HELPERS
+
+// Generic token transfer helper
+// Used for moving user tokens into the auction vaults
+pub fn transfer_tokens<'info>(
+    amount: u64,
+    decimals: u8,
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+) -> Result<()> {
+    transfer_checked(
+        CpiContext::new(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+        ),
+        amount,
+        decimals,
+    )
+}
+
+// Transfer out of an auction vault, signed by the auction PDA
+pub fn transfer_from_vault<'info>(
+    amount: u64,
+    decimals: u8,
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let signer_seeds = &[authority_seeds];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )
+}
@@ -0,0 +1,93 @@
+// Place Bid Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// VULNERABILITY:
+// V001: Outbid bidders are never refunded - their bids stay locked in the vault
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct PlaceBid<'info> {
+    pub bidder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, auction.seller.as_ref(), auction.seed.to_le_bytes().as_ref()],
+        bump = auction.bump,
+        has_one = bid_mint,
+        has_one = bid_vault,
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+
+    pub bid_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = bid_mint,
+        token::authority = bidder,
+        token::token_program = token_program,
+    )]
+    pub bidder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub bid_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    // VULNERABILITY V001: No account to refund the previous highest bidder
+    // Secure version takes the previous bidder's bid-mint account here:
+    //   #[account(mut)]
+    //   pub previous_bidder_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+impl<'info> PlaceBid<'info> {
+    pub fn place_bid(&mut self, amount: u64) -> Result<()> {
+        self.auction.assert_open()?;
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(now < self.auction.end_ts, AuctionError::AuctionEnded);
+
+        require!(
+            self.bidder.key() != self.auction.seller,
+            AuctionError::SellerCannotBid
+        );
+
+        require!(
+            amount >= self.auction.minimum_next_bid()?,
+            AuctionError::BidTooLow
+        );
+
+        transfer_tokens(
+            amount,
+            self.bid_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.bidder_token_account.to_account_info(),
+            &self.bid_mint.to_account_info(),
+            &self.bid_vault.to_account_info(),
+            &self.bidder.to_account_info(),
+        )?;
+
+        // VULNERABILITY V001: Previous highest bid kept instead of refunded
+        // Secure version:
+        //   if self.auction.highest_bid > 0 {
+        //       self.refund_previous_bidder()?;
+        //   }
+        // Attack scenario (locked bids):
+        // 1. Alice bids 100, Bob outbids her with 110
+        // 2. Alice's 100 stays in bid_vault - highest_bid is simply overwritten
+        // 3. Settlement pays the seller highest_bid (110) and nothing else
+        // 4. Alice's 100 is stranded in a vault no instruction can withdraw from
+        //    Every losing bid in every auction is lost the same way
+
+        self.auction.highest_bid = amount;
+        self.auction.highest_bidder = self.bidder.key();
+
+        msg!("New highest bid: {} by {}", amount, self.bidder.key());
+
+        Ok(())
+    }
+}
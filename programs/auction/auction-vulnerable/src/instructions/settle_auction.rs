@@ -0,0 +1,115 @@
+// Settle Auction Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// VULNERABILITY:
+// V002: No end time check - the auction can be settled while bidding is still open
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct SettleAuction<'info> {
+    pub settler: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, auction.seller.as_ref(), auction.seed.to_le_bytes().as_ref()],
+        bump = auction.bump,
+        has_one = asset_mint,
+        has_one = bid_mint,
+        has_one = asset_vault,
+        has_one = bid_vault,
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub bid_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub asset_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub bid_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = asset_mint,
+        token::authority = auction.highest_bidder,
+        token::token_program = token_program,
+    )]
+    pub winner_asset_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = bid_mint,
+        token::authority = auction.seller,
+        token::token_program = token_program,
+    )]
+    pub seller_bid_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> SettleAuction<'info> {
+    pub fn settle_auction(&mut self) -> Result<()> {
+        self.auction.assert_open()?;
+
+        // VULNERABILITY V002: Settlement allowed before end_ts
+        // Secure version:
+        //   let now = Clock::get()?.unix_timestamp;
+        //   require!(now >= self.auction.end_ts, AuctionError::AuctionNotEnded);
+        // Attack scenario (instant win):
+        // 1. Seller opens a one hour auction for a valuable NFT, minimum bid 100
+        // 2. Attacker bids the minimum and settles in the next transaction
+        // 3. Settlement only needs a highest bid, so it succeeds immediately
+        // 4. Attacker gets the NFT for 100; everyone else's bids now fail
+        //    because the auction is settled
+
+        require!(self.auction.highest_bid > 0, AuctionError::NoBids);
+
+        let seller = self.auction.seller;
+        let seed_bytes = self.auction.seed.to_le_bytes();
+        let auction_seeds = &[
+            AUCTION_SEED,
+            seller.as_ref(),
+            seed_bytes.as_ref(),
+            &[self.auction.bump],
+        ];
+
+        transfer_from_vault(
+            self.auction.asset_amount,
+            self.asset_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.asset_vault.to_account_info(),
+            &self.asset_mint.to_account_info(),
+            &self.winner_asset_account.to_account_info(),
+            &self.auction.to_account_info(),
+            auction_seeds,
+        )?;
+
+        transfer_from_vault(
+            self.auction.highest_bid,
+            self.bid_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.bid_vault.to_account_info(),
+            &self.bid_mint.to_account_info(),
+            &self.seller_bid_account.to_account_info(),
+            &self.auction.to_account_info(),
+            auction_seeds,
+        )?;
+
+        self.auction.settled = true;
+
+        msg!(
+            "Auction settled: {} won for {}",
+            self.auction.highest_bidder,
+            self.auction.highest_bid
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+// English Auction Program - VULNERABLE VERSION
+//
+// WARNING: This program contains intentional security vulnerabilities for educational purposes.
+// NEVER deploy this to production. Use auction-secure instead.
+//
+// This vulnerable auction demonstrates two classic auction flaws:
+// - Keeping outbid bids instead of refunding them
+// - Settling before the auction has ended
+//
+// VULNERABILITIES DOCUMENTED:
+// See individual instruction files for detailed vulnerability explanations.
+// Each vulnerability is marked with VULNERABILITY comments.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("AUCwPWg3oCrLzQWd8dMY8jBzeqE4kKoMrNqNxfdd5cgy");
+
+#[program]
+pub mod auction_vulnerable {
+    use super::*;
+
+    // Escrow an asset and open an auction for it
+    pub fn create_auction(
+        ctx: Context<CreateAuction>,
+        seed: u64,
+        asset_amount: u64,
+        min_bid: u64,
+        min_increment: u64,
+        duration_seconds: i64,
+    ) -> Result<()> {
+        ctx.accounts.create_auction(
+            seed,
+            asset_amount,
+            min_bid,
+            min_increment,
+            duration_seconds,
+            &ctx.bumps,
+        )
+    }
+
+    // Outbid the current leader
+    // VULNERABILITY: Previous highest bid is never refunded
+    pub fn place_bid(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
+        ctx.accounts.place_bid(amount)
+    }
+
+    // Pay out an auction (anyone can call)
+    // VULNERABILITY: No check that the auction has ended
+    pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
+        ctx.accounts.settle_auction()
+    }
+
+    // Withdraw an auction with no bids
+    pub fn cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
+        ctx.accounts.cancel_auction()
+    }
+}
@@ -0,0 +1,7 @@
+// State Module
+//
+// Exports all state structures used by the auction program
+
+pub mod auction;
+
+pub use auction::*;
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+pub use soteria_errors::bridge_secure::BridgeError as BridgeErrorCode;
+
+#[error_code]
+pub enum BridgeError {
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Bridge must have between 1 and 10 guardians")]
+    InvalidGuardianCount,
+
+    #[msg("Guardian list contains a duplicate or default key")]
+    InvalidGuardian,
+
+    #[msg("Threshold must be between 1 and the number of guardians")]
+    InvalidThreshold,
+
+    #[msg("Signer is not a guardian of this bridge")]
+    NotGuardian,
+
+    #[msg("Guardian has already attested this message")]
+    DuplicateAttestation,
+
+    #[msg("Message does not have enough attestations")]
+    InsufficientAttestations,
+
+    #[msg("Message has already been executed")]
+    AlreadyExecuted,
+
+    #[msg("Bridge vault does not hold enough tokens")]
+    InsufficientLiquidity,
+
+    #[msg("Message has reached threshold and can no longer be rejected")]
+    ThresholdReached,
+}
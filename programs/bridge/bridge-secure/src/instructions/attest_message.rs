@@ -0,0 +1,71 @@
+// Attest Message Instruction
+//
+// A guardian confirms a posted message matches a transfer on the source chain.
+//
+// HOW IT WORKS:
+// 1. Checks the signer is a guardian of the bridge
+// 2. Checks the guardian has not already attested this message
+// 3. Records the attestation
+//
+// SECURITY:
+// - Guardian must sign and be in the bridge's guardian set
+// - Each guardian counts at most once per message
+// - No attestations after execution
+//
+// Why reject duplicates?
+// The threshold exists so that no single guardian can release funds. If one
+// guardian's attestation can be counted twice, a 2-of-3 bridge is really
+// 1-of-3.
+
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct AttestMessage<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(
+        seeds = [BRIDGE_SEED, bridge.admin.as_ref()],
+        bump = bridge.bump,
+    )]
+    pub bridge: Box<Account<'info, Bridge>>,
+
+    #[account(
+        mut,
+        seeds = [
+            MESSAGE_SEED,
+            bridge.key().as_ref(),
+            message.source_chain.to_le_bytes().as_ref(),
+            message.sequence.to_le_bytes().as_ref(),
+        ],
+        bump = message.bump,
+        has_one = bridge,
+    )]
+    pub message: Box<Account<'info, Message>>,
+}
+
+impl<'info> AttestMessage<'info> {
+    pub fn attest_message(&mut self) -> Result<()> {
+        let guardian = self.guardian.key();
+
+        require!(self.bridge.is_guardian(&guardian), BridgeError::NotGuardian);
+        require!(!self.message.executed, BridgeError::AlreadyExecuted);
+        #[cfg(not(feature = "vuln-missing-duplicate-check"))]
+        require!(
+            !self.message.attestations.contains(&guardian),
+            BridgeError::DuplicateAttestation
+        );
+
+        self.message.attestations.push(guardian);
+
+        msg!(
+            "Guardian {} attested ({} of {})",
+            guardian,
+            self.message.attestations.len(),
+            self.bridge.threshold
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+// Execute Message Instruction
+//
+// Releases a message's tokens once enough guardians have attested.
+//
+// HOW IT WORKS:
+// 1. Checks the message has threshold distinct attestations
+// 2. Checks it has not been executed, then marks it executed
+// 3. Transfers amount from the vault to the recipient, signed by the bridge PDA
+//
+// SECURITY:
+// - attestations.len() >= threshold (attestations are distinct by construction)
+// - Each message executes exactly once (executed flag)
+// - Tokens go only to a token account owned by message.recipient
+// - Anyone can execute; the recipient and amount are fixed by the message
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct ExecuteMessage<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [BRIDGE_SEED, bridge.admin.as_ref()],
+        bump = bridge.bump,
+        has_one = mint,
+        has_one = vault,
+    )]
+    pub bridge: Box<Account<'info, Bridge>>,
+
+    #[account(
+        mut,
+        seeds = [
+            MESSAGE_SEED,
+            bridge.key().as_ref(),
+            message.source_chain.to_le_bytes().as_ref(),
+            message.sequence.to_le_bytes().as_ref(),
+        ],
+        bump = message.bump,
+        has_one = bridge,
+    )]
+    pub message: Box<Account<'info, Message>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = message.recipient,
+        token::token_program = token_program,
+    )]
+    pub recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ExecuteMessage<'info> {
+    pub fn execute_message(&mut self) -> Result<()> {
+        require!(
+            self.message.attestations.len() >= self.bridge.threshold as usize,
+            BridgeError::InsufficientAttestations
+        );
+
+        require!(!self.message.executed, BridgeError::AlreadyExecuted);
+
+        let amount = self.message.amount;
+        require!(self.vault.amount >= amount, BridgeError::InsufficientLiquidity);
+
+        // Mark before transferring (checks-effects-interactions)
+        self.message.executed = true;
+
+        let admin = self.bridge.admin;
+        let bridge_seeds = &[BRIDGE_SEED, admin.as_ref(), &[self.bridge.bump]];
+
+        transfer_from_vault(
+            amount,
+            self.mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.vault.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.recipient_token_account.to_account_info(),
+            &self.bridge.to_account_info(),
+            bridge_seeds,
+        )?;
+
+        msg!(
+            "Message chain {} seq {} executed: {} to {}",
+            self.message.source_chain,
+            self.message.sequence,
+            amount,
+            self.message.recipient
+        );
+
+        Ok(())
+    }
+}
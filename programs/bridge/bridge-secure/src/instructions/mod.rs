@@ -0,0 +1,15 @@
+// Instructions Module
+//
+// Exports all instruction handlers for the bridge program
+
+pub mod initialize_bridge;
+pub mod post_message;
+pub mod attest_message;
+pub mod execute_message;
+pub mod reject_message;
+
+pub use initialize_bridge::*;
+pub use post_message::*;
+pub use attest_message::*;
+pub use execute_message::*;
+pub use reject_message::*;
@@ -0,0 +1,90 @@
+// Post Message Instruction
+//
+// A guardian relays a transfer observed on the source chain.
+//
+// HOW IT WORKS:
+// 1. Checks the relayer is a guardian of the bridge
+// 2. Creates the message PDA for (source_chain, sequence)
+// 3. Stores recipient and amount with no attestations
+//
+// SECURITY:
+// - Only guardians can post. The PDA holds the only slot for its sequence,
+//   so an open post would let anyone claim a future sequence with a bogus
+//   recipient and block the real transfer
+// - The PDA is keyed by (source_chain, sequence), so the same transfer cannot
+//   be posted twice
+// - A bogus message from a rogue guardian never reaches threshold, and any
+//   guardian can reject it to free the slot (see reject_message)
+// - amount > 0
+
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+#[instruction(source_chain: u16, sequence: u64)]
+pub struct PostMessage<'info> {
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    #[account(
+        seeds = [BRIDGE_SEED, bridge.admin.as_ref()],
+        bump = bridge.bump,
+    )]
+    pub bridge: Box<Account<'info, Bridge>>,
+
+    #[account(
+        init,
+        payer = relayer,
+        space = ANCHOR_DISCRIMINATOR + Message::INIT_SPACE,
+        seeds = [
+            MESSAGE_SEED,
+            bridge.key().as_ref(),
+            source_chain.to_le_bytes().as_ref(),
+            sequence.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub message: Box<Account<'info, Message>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PostMessage<'info> {
+    pub fn post_message(
+        &mut self,
+        source_chain: u16,
+        sequence: u64,
+        recipient: Pubkey,
+        amount: u64,
+        bumps: &PostMessageBumps,
+    ) -> Result<()> {
+        require!(
+            self.bridge.is_guardian(&self.relayer.key()),
+            BridgeError::NotGuardian
+        );
+
+        require!(amount > 0, BridgeError::ZeroAmount);
+
+        self.message.set_inner(Message {
+            bridge: self.bridge.key(),
+            source_chain,
+            sequence,
+            recipient,
+            amount,
+            attestations: Vec::new(),
+            executed: false,
+            bump: bumps.message,
+        });
+
+        msg!(
+            "Message posted: chain {} seq {} -> {} for {}",
+            source_chain,
+            sequence,
+            recipient,
+            amount
+        );
+
+        Ok(())
+    }
+}
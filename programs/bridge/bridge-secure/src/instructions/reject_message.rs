@@ -0,0 +1,73 @@
+// Reject Message Instruction
+//
+// A guardian closes a posted message that does not match the source chain.
+//
+// HOW IT WORKS:
+// 1. Checks the signer is a guardian of the bridge
+// 2. Checks the message is not executed and is below threshold
+// 3. Closes the message account, returning its rent to the guardian
+//
+// SECURITY:
+// - Guardian must sign and be in the bridge's guardian set
+// - Executed messages stay open: their account is the replay protection for
+//   the sequence
+// - A message with threshold attestations is agreed by the quorum, so one
+//   guardian cannot veto it
+//
+// Why reject at all?
+// The message PDA is the only slot for its (source_chain, sequence). A rogue
+// guardian can post a bogus recipient there before the real transfer is
+// relayed; without a way to close it, that sequence could never be paid. Once
+// rejected, any guardian posts the real transfer into the freed slot.
+
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct RejectMessage<'info> {
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    #[account(
+        seeds = [BRIDGE_SEED, bridge.admin.as_ref()],
+        bump = bridge.bump,
+    )]
+    pub bridge: Box<Account<'info, Bridge>>,
+
+    #[account(
+        mut,
+        close = guardian,
+        seeds = [
+            MESSAGE_SEED,
+            bridge.key().as_ref(),
+            message.source_chain.to_le_bytes().as_ref(),
+            message.sequence.to_le_bytes().as_ref(),
+        ],
+        bump = message.bump,
+        has_one = bridge,
+    )]
+    pub message: Box<Account<'info, Message>>,
+}
+
+impl<'info> RejectMessage<'info> {
+    pub fn reject_message(&mut self) -> Result<()> {
+        let guardian = self.guardian.key();
+
+        require!(self.bridge.is_guardian(&guardian), BridgeError::NotGuardian);
+        require!(!self.message.executed, BridgeError::AlreadyExecuted);
+        require!(
+            self.message.attestations.len() < self.bridge.threshold as usize,
+            BridgeError::ThresholdReached
+        );
+
+        msg!(
+            "Guardian {} rejected message chain {} seq {}",
+            guardian,
+            self.message.source_chain,
+            self.message.sequence
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+// Bridge Message Inbox Program - SECURE VERSION
+//
+// Destination side of a simplified guardian-attested token bridge.
+// This program allows users to:
+// 1. Create a bridge with an M-of-N guardian set and a token vault
+// 2. Post a message describing a transfer from another chain
+// 3. Let guardians attest to the message
+// 4. Execute the message once enough guardians agree, releasing tokens
+// 5. Reject an invalid message so its sequence can be posted again
+//
+// SECURITY FEATURES:
+// - Guardian attestations must be signed and are counted once per guardian
+// - Execution requires threshold distinct attestations
+// - Each message executes exactly once
+// - Messages keyed by (source chain, sequence) so a transfer is posted once
+// - Only guardians post, and any guardian can reject a message below
+//   threshold, so a sequence slot cannot be squatted for good
+// - Tokens released only to the message's recipient
+// - Guardian set and threshold validated at creation
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("GffMwipgANjeawsew8p5W3aRTv1EZ9zNgAfHV3wyYteo");
+
+#[program]
+pub mod bridge_secure {
+    use super::*;
+
+    // Create the bridge, its guardian set and vault
+    pub fn initialize_bridge(
+        ctx: Context<InitializeBridge>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        ctx.accounts.initialize_bridge(guardians, threshold, &ctx.bumps)
+    }
+
+    // Guardian-only: record a transfer observed on the source chain
+    pub fn post_message(
+        ctx: Context<PostMessage>,
+        source_chain: u16,
+        sequence: u64,
+        recipient: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.post_message(source_chain, sequence, recipient, amount, &ctx.bumps)
+    }
+
+    // Guardian-only: confirm a posted message
+    pub fn attest_message(ctx: Context<AttestMessage>) -> Result<()> {
+        ctx.accounts.attest_message()
+    }
+
+    // Release tokens for a message with enough attestations
+    pub fn execute_message(ctx: Context<ExecuteMessage>) -> Result<()> {
+        ctx.accounts.execute_message()
+    }
+
+    // Guardian-only: close a message that does not match the source chain
+    pub fn reject_message(ctx: Context<RejectMessage>) -> Result<()> {
+        ctx.accounts.reject_message()
+    }
+}
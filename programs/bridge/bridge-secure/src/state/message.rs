@@ -0,0 +1,43 @@
+// Message State
+//
+// One cross-chain transfer: "release `amount` to `recipient`", identified by
+// the chain it came from and its sequence number there.
+//
+// LIFECYCLE:
+// 1. A guardian posts the message
+// 2. Guardians attest to it, one attestation each
+// 3. Once threshold distinct guardians have attested, anyone can execute it
+//    and the tokens are released exactly once
+// 4. Until then, any guardian can reject it; the account is closed and the
+//    sequence can be posted again with the real transfer
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Message {
+    // Bridge this message releases from
+    pub bridge: Pubkey,
+
+    // Chain the transfer originated on
+    pub source_chain: u16,
+
+    // Sequence number on the source chain
+    pub sequence: u64,
+
+    // Account that receives the tokens
+    pub recipient: Pubkey,
+
+    // Tokens to release
+    pub amount: u64,
+
+    // Guardians that have attested (no duplicates)
+    #[max_len(MAX_GUARDIANS)]
+    pub attestations: Vec<Pubkey>,
+
+    // Set once the tokens have been released
+    pub executed: bool,
+
+    pub bump: u8,
+}
@@ -0,0 +1,79 @@
+// Compute-unit regression tests for bridge-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+mod utils;
+
+use litesvm_token::{CreateAssociatedTokenAccount, MintTo};
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::Signer};
+use soteria_test_kit::{ComputeBudget, DEFAULT_CU_BASELINES_FILE};
+use spl_associated_token_account::get_associated_token_address;
+use utils::*;
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let (mut svm, mint_authority, admin, guardians, mint) = setup_accounts();
+    let relayer = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+    let recipient = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+
+    let guardian_keys: Vec<Pubkey> = guardians.iter().map(|g| g.pubkey()).collect();
+    let ix = build_initialize_bridge_ix(&admin.pubkey(), &mint, &guardian_keys, THRESHOLD);
+    budget.record("initialize_bridge", send_ix(&mut svm, ix, &admin));
+    let (bridge, _) = derive_bridge_pda(&admin.pubkey());
+
+    let vault = get_associated_token_address(&bridge, &mint);
+    MintTo::new(&mut svm, &mint_authority, &mint, &vault, VAULT_BALANCE)
+        .owner(&mint_authority)
+        .send()
+        .expect("Failed to fund vault");
+    CreateAssociatedTokenAccount::new(&mut svm, &recipient, &mint)
+        .owner(&recipient.pubkey())
+        .send()
+        .expect("Failed to create recipient account");
+
+    let ix = build_post_message_ix(
+        &guardians[0].pubkey(),
+        &bridge,
+        SOURCE_CHAIN,
+        SEQUENCE,
+        &recipient.pubkey(),
+        MESSAGE_AMOUNT,
+    );
+    budget.record("post_message", send_ix(&mut svm, ix, &guardians[0]));
+    let (message, _) = derive_message_pda(&bridge, SOURCE_CHAIN, SEQUENCE);
+
+    let ix = build_attest_message_ix(&guardians[0].pubkey(), &bridge, &message);
+    budget.record("attest_message", send_ix(&mut svm, ix, &guardians[0]));
+    let ix = build_attest_message_ix(&guardians[1].pubkey(), &bridge, &message);
+    send_ix(&mut svm, ix, &guardians[1]).expect("Second attestation should succeed");
+
+    let ix = build_execute_message_ix(
+        &relayer.pubkey(),
+        &bridge,
+        &message,
+        &mint,
+        &recipient.pubkey(),
+    );
+    budget.record("execute_message", send_ix(&mut svm, ix, &relayer));
+
+    let ix = build_post_message_ix(
+        &guardians[0].pubkey(),
+        &bridge,
+        SOURCE_CHAIN,
+        SEQUENCE + 1,
+        &recipient.pubkey(),
+        MESSAGE_AMOUNT,
+    );
+    send_ix(&mut svm, ix, &guardians[0]).expect("Second post should succeed");
+    let (rejected, _) = derive_message_pda(&bridge, SOURCE_CHAIN, SEQUENCE + 1);
+    let ix = build_reject_message_ix(&guardians[1].pubkey(), &bridge, &rejected);
+    budget.record("reject_message", send_ix(&mut svm, ix, &guardians[1]));
+
+    budget.check();
+}
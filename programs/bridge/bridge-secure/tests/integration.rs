@@ -0,0 +1,253 @@
+// Integration tests for bridge message inbox program using LiteSVM
+// These tests verify core bridge functionality: guardian set validation,
+// guardian-only posting, M-of-N attestation, one-time token release, and
+// recovering a squatted sequence
+
+mod utils;
+
+use utils::*;
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::Signer};
+
+#[test]
+fn test_initialize_bridge() {
+    // Test: The guardian set and threshold are validated at creation
+    println!("\n[TEST START] test_initialize_bridge - Guardian set validation");
+
+    let (mut svm, _, admin, guardians, mint) = setup_accounts();
+    let keys: Vec<Pubkey> = guardians.iter().map(|g| g.pubkey()).collect();
+
+    let bad_configs: [(&str, Vec<Pubkey>, u8); 4] = [
+        ("no guardians", vec![], 1),
+        ("zero threshold", keys.clone(), 0),
+        ("threshold above guardian count", keys.clone(), 4),
+        ("duplicate guardian", vec![keys[0], keys[1], keys[0]], 2),
+    ];
+
+    for (name, guardian_keys, threshold) in bad_configs {
+        println!("[Action] Initializing bridge with {}", name);
+        let ix = build_initialize_bridge_ix(&admin.pubkey(), &mint, &guardian_keys, threshold);
+        assert!(send_ix(&mut svm, ix, &admin).is_err(), "Bridge with {} should fail", name);
+        println!("[Success] Bridge with {} rejected", name);
+    }
+
+    println!("[Action] Initializing a 2-of-3 bridge");
+    let ix = build_initialize_bridge_ix(&admin.pubkey(), &mint, &keys, THRESHOLD);
+    let result = send_ix(&mut svm, ix, &admin);
+    assert!(result.is_ok(), "Bridge initialization failed: {:?}", result.err());
+
+    let (bridge, _) = derive_bridge_pda(&admin.pubkey());
+    let vault = spl_associated_token_account::get_associated_token_address(&bridge, &mint);
+    let account = token_account(&svm, &vault);
+    assert_eq!(account.owner, bridge);
+    assert_eq!(account.amount, 0);
+    println!("[Success] Bridge created with an empty vault owned by the bridge PDA");
+
+    println!("[TEST END] test_initialize_bridge");
+}
+
+#[test]
+fn test_attest_message() {
+    // Test: Only guardians attest, and each guardian counts once
+    println!("\n[TEST START] test_attest_message - M-of-N attestation");
+
+    let mut scenario = setup_bridge_scenario();
+    println!("[Setup] 2-of-3 bridge with a posted 100-token message");
+
+    println!("[Action] Relayer tries to attest its own message");
+    let ix = build_attest_message_ix(&scenario.relayer.pubkey(), &scenario.bridge, &scenario.message);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.relayer).is_err(), "Non-guardian attest should fail");
+    println!("[Success] Attestation by non-guardian rejected");
+
+    println!("[Action] Guardian 0 attests");
+    let ix = scenario.attest_ix(0);
+    let result = send_ix(&mut scenario.svm, ix, &scenario.guardians[0]);
+    assert!(result.is_ok(), "Attest failed: {:?}", result.err());
+    assert_eq!(get_attestation_count(&scenario.svm, &scenario.message), 1);
+    println!("[Success] One attestation recorded");
+
+    println!("[Action] Guardian 0 attests a second time");
+    let ix = scenario.attest_ix(0);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.guardians[0]).is_err(), "Duplicate attest should fail");
+    assert_eq!(get_attestation_count(&scenario.svm, &scenario.message), 1);
+    println!("[Success] Duplicate attestation rejected");
+
+    println!("[Action] Executing with 1 of 2 required attestations");
+    let ix = scenario.execute_ix();
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.relayer).is_err(), "Execute below threshold should fail");
+    assert_eq!(scenario.recipient_balance(), 0);
+    println!("[Success] Execution below threshold rejected");
+
+    println!("[Action] Guardian 1 attests");
+    let ix = scenario.attest_ix(1);
+    let result = send_ix(&mut scenario.svm, ix, &scenario.guardians[1]);
+    assert!(result.is_ok(), "Attest failed: {:?}", result.err());
+    assert_eq!(get_attestation_count(&scenario.svm, &scenario.message), 2);
+    println!("[Success] Threshold reached with two distinct guardians");
+
+    println!("[TEST END] test_attest_message");
+}
+
+#[test]
+fn test_execute_message() {
+    // Test: An attested message releases its tokens exactly once
+    println!("\n[TEST START] test_execute_message - One-time token release");
+
+    let mut scenario = setup_bridge_scenario();
+    for guardian in 0..THRESHOLD as usize {
+        let ix = scenario.attest_ix(guardian);
+        send_ix(&mut scenario.svm, ix, &scenario.guardians[guardian]).expect("Attest should succeed");
+    }
+    println!("[Setup] Message attested by 2 of 3 guardians");
+
+    println!("[Action] Relayer executes the message");
+    let ix = scenario.execute_ix();
+    let result = send_ix(&mut scenario.svm, ix, &scenario.relayer);
+    assert!(result.is_ok(), "Execute failed: {:?}", result.err());
+    assert_eq!(scenario.recipient_balance(), MESSAGE_AMOUNT);
+    assert_eq!(scenario.vault_balance(), VAULT_BALANCE - MESSAGE_AMOUNT);
+    assert!(get_executed(&scenario.svm, &scenario.message));
+    println!("[Success] Recipient received 100 tokens");
+
+    println!("[Action] Relayer executes the same message again");
+    let ix = scenario.execute_ix();
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.relayer).is_err(), "Replay should fail");
+    println!("[Success] Replay rejected");
+
+    println!("[Action] Guardian 2 attests the executed message");
+    let ix = scenario.attest_ix(2);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.guardians[2]).is_err(), "Late attest should fail");
+    println!("[Success] Attestation after execution rejected");
+
+    println!("[Action] Guardian 0 re-posts the same source chain and sequence");
+    let ix = build_post_message_ix(
+        &scenario.guardians[0].pubkey(),
+        &scenario.bridge,
+        SOURCE_CHAIN,
+        SEQUENCE,
+        &scenario.recipient.pubkey(),
+        MESSAGE_AMOUNT,
+    );
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.guardians[0]).is_err(), "Re-post should fail");
+    assert_eq!(scenario.recipient_balance(), MESSAGE_AMOUNT);
+    assert_eq!(scenario.vault_balance(), VAULT_BALANCE - MESSAGE_AMOUNT);
+    println!("[Success] Recipient paid exactly once");
+
+    println!("[Action] Guardian 2 tries to reject the executed message");
+    let ix = build_reject_message_ix(&scenario.guardians[2].pubkey(), &scenario.bridge, &scenario.message);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.guardians[2]).is_err(), "Reject after execution should fail");
+    assert!(get_executed(&scenario.svm, &scenario.message));
+    println!("[Success] Executed message cannot be closed and re-posted");
+
+    println!("[TEST END] test_execute_message");
+}
+
+#[test]
+fn test_post_message() {
+    // Test: Only guardians can post, so nobody else can claim a sequence
+    println!("\n[TEST START] test_post_message - Guardian-only posting");
+
+    let mut scenario = setup_bridge_scenario();
+    let attacker = create_funded_account(&mut scenario.svm, LAMPORTS_PER_SOL);
+    let (next_message, _) = derive_message_pda(&scenario.bridge, SOURCE_CHAIN, SEQUENCE + 1);
+
+    println!("[Action] Non-guardian posts the next sequence to itself");
+    let ix = build_post_message_ix(
+        &attacker.pubkey(),
+        &scenario.bridge,
+        SOURCE_CHAIN,
+        SEQUENCE + 1,
+        &attacker.pubkey(),
+        VAULT_BALANCE,
+    );
+    assert!(send_ix(&mut scenario.svm, ix, &attacker).is_err(), "Non-guardian post should fail");
+    assert!(scenario.svm.get_account(&next_message).is_none());
+    println!("[Success] Post by non-guardian rejected, sequence slot still free");
+
+    println!("[Action] Guardian 1 posts the next sequence");
+    let ix = build_post_message_ix(
+        &scenario.guardians[1].pubkey(),
+        &scenario.bridge,
+        SOURCE_CHAIN,
+        SEQUENCE + 1,
+        &scenario.recipient.pubkey(),
+        MESSAGE_AMOUNT,
+    );
+    let result = send_ix(&mut scenario.svm, ix, &scenario.guardians[1]);
+    assert!(result.is_ok(), "Guardian post failed: {:?}", result.err());
+    assert_eq!(get_attestation_count(&scenario.svm, &next_message), 0);
+    println!("[Success] Guardian post recorded with no attestations");
+
+    println!("[TEST END] test_post_message");
+}
+
+#[test]
+fn test_reject_message() {
+    // Test: A sequence squatted by a rogue guardian is rejected and the real
+    // transfer is posted, attested and paid in the same slot
+    println!("\n[TEST START] test_reject_message - Recovering a squatted sequence");
+
+    let mut scenario = setup_bridge_scenario();
+    let rogue = &scenario.guardians[0];
+    let sequence = SEQUENCE + 1;
+    let (message, _) = derive_message_pda(&scenario.bridge, SOURCE_CHAIN, sequence);
+
+    println!("[Action] Rogue guardian 0 posts sequence {} paying itself, and attests it", sequence);
+    let ix = build_post_message_ix(
+        &rogue.pubkey(),
+        &scenario.bridge,
+        SOURCE_CHAIN,
+        sequence,
+        &rogue.pubkey(),
+        VAULT_BALANCE,
+    );
+    send_ix(&mut scenario.svm, ix, rogue).expect("Guardian post should succeed");
+    let ix = build_attest_message_ix(&rogue.pubkey(), &scenario.bridge, &message);
+    send_ix(&mut scenario.svm, ix, rogue).expect("Attest should succeed");
+    println!("[Setup] Bogus message holds the slot with 1 of 2 attestations");
+
+    println!("[Action] Non-guardian tries to reject it");
+    let ix = build_reject_message_ix(&scenario.relayer.pubkey(), &scenario.bridge, &message);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.relayer).is_err(), "Non-guardian reject should fail");
+    println!("[Success] Reject by non-guardian rejected");
+
+    println!("[Action] Guardian 1 rejects the bogus message");
+    let ix = build_reject_message_ix(&scenario.guardians[1].pubkey(), &scenario.bridge, &message);
+    let result = send_ix(&mut scenario.svm, ix, &scenario.guardians[1]);
+    assert!(result.is_ok(), "Reject failed: {:?}", result.err());
+    assert!(scenario.svm.get_account(&message).is_none());
+    println!("[Success] Bogus message closed, slot freed");
+
+    println!("[Action] Guardian 2 posts the real transfer into the same slot");
+    let ix = build_post_message_ix(
+        &scenario.guardians[2].pubkey(),
+        &scenario.bridge,
+        SOURCE_CHAIN,
+        sequence,
+        &scenario.recipient.pubkey(),
+        MESSAGE_AMOUNT,
+    );
+    send_ix(&mut scenario.svm, ix, &scenario.guardians[2]).expect("Re-post should succeed");
+    for guardian in 1..=2 {
+        let ix = build_attest_message_ix(&scenario.guardians[guardian].pubkey(), &scenario.bridge, &message);
+        send_ix(&mut scenario.svm, ix, &scenario.guardians[guardian]).expect("Attest should succeed");
+    }
+
+    println!("[Action] Rogue guardian tries to reject the attested transfer");
+    let ix = build_reject_message_ix(&rogue.pubkey(), &scenario.bridge, &message);
+    assert!(send_ix(&mut scenario.svm, ix, rogue).is_err(), "Reject at threshold should fail");
+    println!("[Success] One guardian cannot veto a message the quorum agreed on");
+
+    let ix = build_execute_message_ix(
+        &scenario.relayer.pubkey(),
+        &scenario.bridge,
+        &message,
+        &scenario.mint,
+        &scenario.recipient.pubkey(),
+    );
+    send_ix(&mut scenario.svm, ix, &scenario.relayer).expect("Execute should succeed");
+    assert_eq!(scenario.recipient_balance(), MESSAGE_AMOUNT);
+    assert_eq!(scenario.vault_balance(), VAULT_BALANCE - MESSAGE_AMOUNT);
+    println!("[Success] Real transfer paid from the recovered sequence");
+
+    println!("[TEST END] test_reject_message");
+}
@@ -0,0 +1,338 @@
+// Test utilities for bridge message inbox program
+
+use litesvm::{LiteSVM, types::TransactionResult};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo, get_spl_account};
+use litesvm_token::spl_token::state::Account as TokenAccount;
+use solana_sdk::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::program::ID as system_program;
+
+// Program ID matching declare_id!
+pub const BRIDGE_PROGRAM_ID: Pubkey = Pubkey::new_from_array(bridge_secure::ID.to_bytes());
+
+// Build Anchor instruction discriminator
+// Formula: first 8 bytes of sha256("global:method_name")
+pub fn anchor_discriminator(method: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", method);
+    let hash_result = hash(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash_result.to_bytes()[..8]);
+    discriminator
+}
+
+// Standard program IDs
+pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_account::ID;
+
+// PDA Seeds
+pub const BRIDGE_SEED: &[u8] = b"bridge";
+pub const MESSAGE_SEED: &[u8] = b"message";
+
+// Token configuration
+pub const DECIMALS: u8 = 6;
+pub const ONE_TOKEN: u64 = 1_000_000;
+
+// Bridge configuration used by the scenario: 2 of 3 guardians
+pub const GUARDIAN_COUNT: usize = 3;
+pub const THRESHOLD: u8 = 2;
+pub const VAULT_BALANCE: u64 = 1_000 * ONE_TOKEN;
+
+// Message used by the scenario
+pub const SOURCE_CHAIN: u16 = 2;
+pub const SEQUENCE: u64 = 1;
+pub const MESSAGE_AMOUNT: u64 = 100 * ONE_TOKEN;
+
+// Setup LiteSVM with bridge program
+pub fn setup_svm() -> LiteSVM {
+    let mut svm = LiteSVM::new();
+    let program_bytes = include_bytes!("../target/deploy/bridge_secure.so");
+    let _ = svm.add_program(BRIDGE_PROGRAM_ID, program_bytes);
+    svm
+}
+
+// Create and fund account
+pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
+    let keypair = Keypair::new();
+    svm.airdrop(&keypair.pubkey(), lamports)
+        .expect("Airdrop should succeed");
+    keypair
+}
+
+// Sign and send a single instruction
+pub fn send_ix(svm: &mut LiteSVM, ix: Instruction, signer: &Keypair) -> TransactionResult {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    svm.expire_blockhash();
+    result
+}
+
+// Derive bridge PDA
+pub fn derive_bridge_pda(admin: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BRIDGE_SEED, admin.as_ref()], &BRIDGE_PROGRAM_ID)
+}
+
+// Derive message PDA
+pub fn derive_message_pda(bridge: &Pubkey, source_chain: u16, sequence: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            MESSAGE_SEED,
+            bridge.as_ref(),
+            &source_chain.to_le_bytes(),
+            &sequence.to_le_bytes(),
+        ],
+        &BRIDGE_PROGRAM_ID,
+    )
+}
+
+// Build initialize_bridge instruction
+pub fn build_initialize_bridge_ix(
+    admin: &Pubkey,
+    mint: &Pubkey,
+    guardians: &[Pubkey],
+    threshold: u8,
+) -> Instruction {
+    let (bridge, _) = derive_bridge_pda(admin);
+
+    let mut data = anchor_discriminator("initialize_bridge").to_vec();
+    data.extend_from_slice(&(guardians.len() as u32).to_le_bytes());
+    for guardian in guardians {
+        data.extend_from_slice(guardian.as_ref());
+    }
+    data.push(threshold);
+
+    Instruction {
+        program_id: BRIDGE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(bridge, false),
+            AccountMeta::new(get_associated_token_address(&bridge, mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build post_message instruction
+pub fn build_post_message_ix(
+    relayer: &Pubkey,
+    bridge: &Pubkey,
+    source_chain: u16,
+    sequence: u64,
+    recipient: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let (message, _) = derive_message_pda(bridge, source_chain, sequence);
+
+    let mut data = anchor_discriminator("post_message").to_vec();
+    data.extend_from_slice(&source_chain.to_le_bytes());
+    data.extend_from_slice(&sequence.to_le_bytes());
+    data.extend_from_slice(recipient.as_ref());
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id: BRIDGE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*relayer, true),
+            AccountMeta::new_readonly(*bridge, false),
+            AccountMeta::new(message, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build attest_message instruction
+pub fn build_attest_message_ix(guardian: &Pubkey, bridge: &Pubkey, message: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: BRIDGE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*guardian, true),
+            AccountMeta::new_readonly(*bridge, false),
+            AccountMeta::new(*message, false),
+        ],
+        data: anchor_discriminator("attest_message").to_vec(),
+    }
+}
+
+// Build reject_message instruction
+pub fn build_reject_message_ix(guardian: &Pubkey, bridge: &Pubkey, message: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: BRIDGE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*guardian, true),
+            AccountMeta::new_readonly(*bridge, false),
+            AccountMeta::new(*message, false),
+        ],
+        data: anchor_discriminator("reject_message").to_vec(),
+    }
+}
+
+// Build execute_message instruction
+pub fn build_execute_message_ix(
+    executor: &Pubkey,
+    bridge: &Pubkey,
+    message: &Pubkey,
+    mint: &Pubkey,
+    recipient: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: BRIDGE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*executor, true),
+            AccountMeta::new_readonly(*bridge, false),
+            AccountMeta::new(*message, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(get_associated_token_address(bridge, mint), false),
+            AccountMeta::new(get_associated_token_address(recipient, mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: anchor_discriminator("execute_message").to_vec(),
+    }
+}
+
+// Message layout: discriminator (8) + bridge (32) + source_chain (2)
+//                 + sequence (8) + recipient (32) + amount (8)
+//                 + attestations (4 + 32 * n) + executed (1)
+const ATTESTATIONS_OFFSET: usize = 8 + 32 + 2 + 8 + 32 + 8;
+
+// Read the attestation count from a Message
+pub fn get_attestation_count(svm: &LiteSVM, message: &Pubkey) -> u32 {
+    let account = svm.get_account(message).expect("Message should exist");
+    u32::from_le_bytes(
+        account.data[ATTESTATIONS_OFFSET..ATTESTATIONS_OFFSET + 4]
+            .try_into()
+            .unwrap(),
+    )
+}
+
+// Read the executed flag from a Message
+pub fn get_executed(svm: &LiteSVM, message: &Pubkey) -> bool {
+    let account = svm.get_account(message).expect("Message should exist");
+    let count = get_attestation_count(svm, message) as usize;
+    account.data[ATTESTATIONS_OFFSET + 4 + 32 * count] != 0
+}
+
+pub fn token_account(svm: &LiteSVM, token_account: &Pubkey) -> TokenAccount {
+    get_spl_account(svm, token_account).expect("Token account should exist")
+}
+
+// Bridge scenario setup result
+pub struct BridgeScenario {
+    pub svm: LiteSVM,
+    pub guardians: Vec<Keypair>,
+    pub relayer: Keypair,
+    pub recipient: Keypair,
+    pub mint: Pubkey,
+    pub bridge: Pubkey,
+    pub vault: Pubkey,
+    pub message: Pubkey,
+    pub recipient_ata: Pubkey,
+}
+
+impl BridgeScenario {
+    // Attest instruction for one of the scenario guardians
+    pub fn attest_ix(&self, guardian: usize) -> Instruction {
+        build_attest_message_ix(&self.guardians[guardian].pubkey(), &self.bridge, &self.message)
+    }
+
+    // Execute instruction sent by the scenario relayer
+    pub fn execute_ix(&self) -> Instruction {
+        build_execute_message_ix(
+            &self.relayer.pubkey(),
+            &self.bridge,
+            &self.message,
+            &self.mint,
+            &self.recipient.pubkey(),
+        )
+    }
+
+    pub fn vault_balance(&self) -> u64 {
+        token_account(&self.svm, &self.vault).amount
+    }
+
+    pub fn recipient_balance(&self) -> u64 {
+        token_account(&self.svm, &self.recipient_ata).amount
+    }
+}
+
+// Create the mint, admin and guardian keypairs
+// Returns (svm, mint_authority, admin, guardians, mint)
+pub fn setup_accounts() -> (LiteSVM, Keypair, Keypair, Vec<Keypair>, Pubkey) {
+    let mut svm = setup_svm();
+    let mint_authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let guardians = (0..GUARDIAN_COUNT)
+        .map(|_| create_funded_account(&mut svm, LAMPORTS_PER_SOL))
+        .collect();
+
+    let mint = CreateMint::new(&mut svm, &mint_authority)
+        .authority(&mint_authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create mint");
+
+    (svm, mint_authority, admin, guardians, mint)
+}
+
+// Create a 2-of-3 bridge with a funded vault and post one 100-token message
+// (guardian 0 relays it; the scenario relayer only executes)
+pub fn setup_bridge_scenario() -> BridgeScenario {
+    let (mut svm, mint_authority, admin, guardians, mint) = setup_accounts();
+    let relayer = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+    let recipient = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+
+    let guardian_keys: Vec<Pubkey> = guardians.iter().map(|g| g.pubkey()).collect();
+    let ix = build_initialize_bridge_ix(&admin.pubkey(), &mint, &guardian_keys, THRESHOLD);
+    send_ix(&mut svm, ix, &admin).expect("Bridge initialization should succeed");
+    let (bridge, _) = derive_bridge_pda(&admin.pubkey());
+
+    // Tokens locked on the source chain are represented by a pre-funded vault
+    let vault = get_associated_token_address(&bridge, &mint);
+    MintTo::new(&mut svm, &mint_authority, &mint, &vault, VAULT_BALANCE)
+        .owner(&mint_authority)
+        .send()
+        .expect("Failed to fund vault");
+
+    let recipient_ata = CreateAssociatedTokenAccount::new(&mut svm, &recipient, &mint)
+        .owner(&recipient.pubkey())
+        .send()
+        .expect("Failed to create recipient account");
+
+    let ix = build_post_message_ix(
+        &guardians[0].pubkey(),
+        &bridge,
+        SOURCE_CHAIN,
+        SEQUENCE,
+        &recipient.pubkey(),
+        MESSAGE_AMOUNT,
+    );
+    send_ix(&mut svm, ix, &guardians[0]).expect("Post message should succeed");
+    let (message, _) = derive_message_pda(&bridge, SOURCE_CHAIN, SEQUENCE);
+
+    BridgeScenario {
+        svm,
+        guardians,
+        relayer,
+        recipient,
+        mint,
+        bridge,
+        vault,
+        message,
+        recipient_ata,
+    }
+}
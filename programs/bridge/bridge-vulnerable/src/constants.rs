@@ -0,0 +1,16 @@
+// Seed for bridge PDA (also the vault authority)
+// Derived with: [BRIDGE_SEED, admin_pubkey]
+pub const BRIDGE_SEED: &[u8] = b"bridge";
+
+// Seed for message PDA
+// Derived with: [MESSAGE_SEED, bridge_pubkey, source_chain.to_le_bytes(), sequence.to_le_bytes()]
+// One account per (source chain, sequence), so a message can only be posted once
+pub const MESSAGE_SEED: &[u8] = b"message";
+
+// GUARDIAN LIMITS
+
+// Maximum guardians per bridge
+// Guardians and attestations are stored on-chain, so this bounds account size
+pub const MAX_GUARDIANS: usize = 10;
+
+pub const ANCHOR_DISCRIMINATOR: usize = 8;
@@ -0,0 +1,39 @@
+// Bridge Helper Functions
+//
+// Reusable CPI helpers for the bridge program.
+// These functions reduce code duplication across instructions.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TransferChecked, transfer_checked};
+
+// CPI HELPERS
+
+// Transfer out of the bridge vault, signed by the bridge PDA
+// Used for releasing tokens to message recipients
+pub fn transfer_from_vault<'info>(
+    amount: u64,
+    decimals: u8,
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let signer_seeds = &[authority_seeds];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )
+}
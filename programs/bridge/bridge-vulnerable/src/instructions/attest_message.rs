@@ -0,0 +1,68 @@
+// Attest Message Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// A guardian confirms a posted message matches a transfer on the source chain.
+//
+// VULNERABILITY: V001: Duplicate attestations - the same guardian can attest
+// repeatedly and every copy counts toward the threshold
+
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct AttestMessage<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(
+        seeds = [BRIDGE_SEED, bridge.admin.as_ref()],
+        bump = bridge.bump,
+    )]
+    pub bridge: Box<Account<'info, Bridge>>,
+
+    #[account(
+        mut,
+        seeds = [
+            MESSAGE_SEED,
+            bridge.key().as_ref(),
+            message.source_chain.to_le_bytes().as_ref(),
+            message.sequence.to_le_bytes().as_ref(),
+        ],
+        bump = message.bump,
+        has_one = bridge,
+    )]
+    pub message: Box<Account<'info, Message>>,
+}
+
+impl<'info> AttestMessage<'info> {
+    pub fn attest_message(&mut self) -> Result<()> {
+        let guardian = self.guardian.key();
+
+        require!(self.bridge.is_guardian(&guardian), BridgeError::NotGuardian);
+        require!(!self.message.executed, BridgeError::AlreadyExecuted);
+
+        // VULNERABILITY V001: No check that this guardian has already attested
+        // Secure version:
+        //   require!(
+        //       !self.message.attestations.contains(&guardian),
+        //       BridgeError::DuplicateAttestation
+        //   );
+        // execute_message counts attestations.len(), so repeats count as new guardians.
+        // Attack scenario (single-guardian release):
+        // 1. Attacker compromises (or is) one guardian of a 2-of-3 bridge
+        // 2. Attacker posts a message paying themselves from the vault
+        // 3. Attacker attests it twice with the same key
+        // 4. attestations.len() == 2 meets the threshold and the message executes
+        self.message.attestations.push(guardian);
+
+        msg!(
+            "Guardian {} attested ({} of {})",
+            guardian,
+            self.message.attestations.len(),
+            self.bridge.threshold
+        );
+
+        Ok(())
+    }
+}
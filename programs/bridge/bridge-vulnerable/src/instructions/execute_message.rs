@@ -0,0 +1,103 @@
+// Execute Message Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// Releases a message's tokens once enough guardians have attested.
+//
+// VULNERABILITY: V002: Message replay - the executed flag is written but never
+// checked, so an attested message releases its tokens every time it is executed
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct ExecuteMessage<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [BRIDGE_SEED, bridge.admin.as_ref()],
+        bump = bridge.bump,
+        has_one = mint,
+        has_one = vault,
+    )]
+    pub bridge: Box<Account<'info, Bridge>>,
+
+    #[account(
+        mut,
+        seeds = [
+            MESSAGE_SEED,
+            bridge.key().as_ref(),
+            message.source_chain.to_le_bytes().as_ref(),
+            message.sequence.to_le_bytes().as_ref(),
+        ],
+        bump = message.bump,
+        has_one = bridge,
+    )]
+    pub message: Box<Account<'info, Message>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = message.recipient,
+        token::token_program = token_program,
+    )]
+    pub recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ExecuteMessage<'info> {
+    pub fn execute_message(&mut self) -> Result<()> {
+        require!(
+            self.message.attestations.len() >= self.bridge.threshold as usize,
+            BridgeError::InsufficientAttestations
+        );
+
+        // VULNERABILITY V002: No check that the message was already executed
+        // Secure version:
+        //   require!(!self.message.executed, BridgeError::AlreadyExecuted);
+        // The flag is still set below, but nothing on this path reads it.
+        // Attack scenario (replay):
+        // 1. A legitimate 100-token transfer is attested by 2 of 3 guardians
+        // 2. The recipient executes it and receives 100 tokens
+        // 3. The recipient executes it again, and again, until the vault is empty
+        // 4. Every other user's bridged tokens are now unbacked
+
+        let amount = self.message.amount;
+        require!(self.vault.amount >= amount, BridgeError::InsufficientLiquidity);
+
+        // Set on every execution but never checked (V002)
+        self.message.executed = true;
+
+        let admin = self.bridge.admin;
+        let bridge_seeds = &[BRIDGE_SEED, admin.as_ref(), &[self.bridge.bump]];
+
+        transfer_from_vault(
+            amount,
+            self.mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.vault.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.recipient_token_account.to_account_info(),
+            &self.bridge.to_account_info(),
+            bridge_seeds,
+        )?;
+
+        msg!(
+            "Message chain {} seq {} executed: {} to {}",
+            self.message.source_chain,
+            self.message.sequence,
+            amount,
+            self.message.recipient
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+// Initialize Bridge Instruction
+//
+// Creates the bridge with its guardian set and token vault.
+//
+// HOW IT WORKS:
+// 1. Validates the guardian set and threshold
+// 2. Creates the bridge PDA and its vault ATA
+//
+// SECURITY:
+// - 1 to MAX_GUARDIANS guardians, no duplicates, no default key
+// - 1 <= threshold <= number of guardians
+// - One bridge per admin (PDA seeds)
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct InitializeBridge<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR + Bridge::INIT_SPACE,
+        seeds = [BRIDGE_SEED, admin.key().as_ref()],
+        bump
+    )]
+    pub bridge: Box<Account<'info, Bridge>>,
+
+    #[account(
+        init,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = bridge,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeBridge<'info> {
+    pub fn initialize_bridge(
+        &mut self,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+        bumps: &InitializeBridgeBumps,
+    ) -> Result<()> {
+        require!(
+            !guardians.is_empty() && guardians.len() <= MAX_GUARDIANS,
+            BridgeError::InvalidGuardianCount
+        );
+
+        for (i, guardian) in guardians.iter().enumerate() {
+            require!(
+                *guardian != Pubkey::default() && !guardians[..i].contains(guardian),
+                BridgeError::InvalidGuardian
+            );
+        }
+
+        require!(
+            threshold > 0 && threshold as usize <= guardians.len(),
+            BridgeError::InvalidThreshold
+        );
+
+        let guardian_count = guardians.len();
+
+        self.bridge.set_inner(Bridge {
+            admin: self.admin.key(),
+            mint: self.mint.key(),
+            vault: self.vault.key(),
+            guardians,
+            threshold,
+            bump: bumps.bridge,
+        });
+
+        msg!(
+            "Bridge initialized: {} of {} guardians",
+            threshold,
+            guardian_count
+        );
+
+        Ok(())
+    }
+}
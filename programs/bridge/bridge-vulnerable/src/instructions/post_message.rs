@@ -0,0 +1,88 @@
+// Post Message Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// A relayer records a transfer observed on the source chain.
+//
+// VULNERABILITY: V003: Sequence squatting - anyone can post any future
+// sequence, and a posted message can never be closed or replaced
+
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+#[instruction(source_chain: u16, sequence: u64)]
+pub struct PostMessage<'info> {
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    #[account(
+        seeds = [BRIDGE_SEED, bridge.admin.as_ref()],
+        bump = bridge.bump,
+    )]
+    pub bridge: Box<Account<'info, Bridge>>,
+
+    #[account(
+        init,
+        payer = relayer,
+        space = ANCHOR_DISCRIMINATOR + Message::INIT_SPACE,
+        seeds = [
+            MESSAGE_SEED,
+            bridge.key().as_ref(),
+            source_chain.to_le_bytes().as_ref(),
+            sequence.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub message: Box<Account<'info, Message>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PostMessage<'info> {
+    pub fn post_message(
+        &mut self,
+        source_chain: u16,
+        sequence: u64,
+        recipient: Pubkey,
+        amount: u64,
+        bumps: &PostMessageBumps,
+    ) -> Result<()> {
+        // VULNERABILITY V003: No check that the relayer is a guardian
+        // Secure version:
+        //   require!(
+        //       self.bridge.is_guardian(&self.relayer.key()),
+        //       BridgeError::NotGuardian
+        //   );
+        // The PDA is the only slot for (source_chain, sequence), and there is no
+        // reject_message to close a bogus one.
+        // Attack scenario (sequence squatting):
+        // 1. Attacker watches the source chain for the next sequence numbers
+        // 2. Attacker posts each one first, with their own recipient
+        // 3. Guardians refuse to attest, but the slots stay taken
+        // 4. The real transfers can never be posted; their tokens are stranded
+        require!(amount > 0, BridgeError::ZeroAmount);
+
+        self.message.set_inner(Message {
+            bridge: self.bridge.key(),
+            source_chain,
+            sequence,
+            recipient,
+            amount,
+            attestations: Vec::new(),
+            executed: false,
+            bump: bumps.message,
+        });
+
+        msg!(
+            "Message posted: chain {} seq {} -> {} for {}",
+            source_chain,
+            sequence,
+            recipient,
+            amount
+        );
+
+        Ok(())
+    }
+}
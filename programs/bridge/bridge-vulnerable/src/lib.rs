@@ -0,0 +1,62 @@
+// Bridge Message Inbox Program - VULNERABLE VERSION
+//
+// WARNING: This program contains intentional security vulnerabilities for educational purposes.
+// NEVER deploy this to production. Use bridge-secure instead.
+//
+// This vulnerable bridge demonstrates three flaws behind real bridge exploits:
+// - One guardian's attestation can be counted many times toward the threshold
+// - An executed message can be executed again
+// - Anyone can claim a future sequence, and the slot can never be freed
+//
+// VULNERABILITIES DOCUMENTED:
+// See individual instruction files for detailed vulnerability explanations.
+// Each vulnerability is marked with VULNERABILITY comments.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("AFGdbJ8E4idYkXJy41B6DXQj7Fe9vY1juSWGoTTvdPAN");
+
+#[program]
+pub mod bridge_vulnerable {
+    use super::*;
+
+    // Create the bridge, its guardian set and vault
+    pub fn initialize_bridge(
+        ctx: Context<InitializeBridge>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        ctx.accounts.initialize_bridge(guardians, threshold, &ctx.bumps)
+    }
+
+    // Record a transfer observed on the source chain
+    pub fn post_message(
+        ctx: Context<PostMessage>,
+        source_chain: u16,
+        sequence: u64,
+        recipient: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.post_message(source_chain, sequence, recipient, amount, &ctx.bumps)
+    }
+
+    // Guardian-only: confirm a posted message
+    // VULNERABILITY: The same guardian can attest more than once
+    pub fn attest_message(ctx: Context<AttestMessage>) -> Result<()> {
+        ctx.accounts.attest_message()
+    }
+
+    // Release tokens for a message with enough attestations
+    // VULNERABILITY: Already-executed messages can be executed again
+    pub fn execute_message(ctx: Context<ExecuteMessage>) -> Result<()> {
+        ctx.accounts.execute_message()
+    }
+}
@@ -0,0 +1,35 @@
+// Bridge State
+//
+// Destination side of a token bridge: a vault of tokens released when the
+// guardian set agrees a transfer happened on the source chain.
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Bridge {
+    // Account that created the bridge and chose the guardians
+    pub admin: Pubkey,
+
+    // Mint released by executed messages
+    pub mint: Pubkey,
+
+    // Vault ATA holding releasable tokens (owned by this PDA)
+    pub vault: Pubkey,
+
+    // Keys that observe the source chain and attest to messages
+    #[max_len(MAX_GUARDIANS)]
+    pub guardians: Vec<Pubkey>,
+
+    // Distinct guardian attestations needed to execute a message (M of N)
+    pub threshold: u8,
+
+    pub bump: u8,
+}
+
+impl Bridge {
+    pub fn is_guardian(&self, key: &Pubkey) -> bool {
+        self.guardians.contains(key)
+    }
+}
@@ -0,0 +1,40 @@
+// Message State
+//
+// One cross-chain transfer: "release `amount` to `recipient`", identified by
+// the chain it came from and its sequence number there.
+//
+// LIFECYCLE:
+// 1. A relayer posts the message (anyone can)
+// 2. Guardians attest to it, one attestation each
+// 3. Once threshold attestations are recorded, anyone can execute it
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Message {
+    // Bridge this message releases from
+    pub bridge: Pubkey,
+
+    // Chain the transfer originated on
+    pub source_chain: u16,
+
+    // Sequence number on the source chain
+    pub sequence: u64,
+
+    // Account that receives the tokens
+    pub recipient: Pubkey,
+
+    // Tokens to release
+    pub amount: u64,
+
+    // Guardians that have attested (duplicates not rejected - V001)
+    #[max_len(MAX_GUARDIANS)]
+    pub attestations: Vec<Pubkey>,
+
+    // Set once the tokens have been released (never checked - V002)
+    pub executed: bool,
+
+    pub bump: u8,
+}
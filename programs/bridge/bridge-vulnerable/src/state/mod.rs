@@ -0,0 +1,9 @@
+// State Module
+//
+// Exports all state structures used by the bridge program
+
+pub mod bridge;
+pub mod message;
+
+pub use bridge::*;
+pub use message::*;
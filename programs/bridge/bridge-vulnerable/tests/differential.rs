@@ -0,0 +1,320 @@
+// Differential tests: bridge-secure vs bridge-vulnerable
+//
+// Each exploit script runs against both builds in one LiteSVM and must be
+// rejected by the secure build and accepted by the vulnerable one.
+// The test_feature_* tests replay them against bridge-secure rebuilt with the
+// matching vuln-* feature, which must be the only build to accept them.
+//
+// HOW TO RUN THESE TESTS:
+// The secure .so and its feature builds must be built first:
+//   ./build-vuln-matrix.sh bridge-secure
+//   cd programs/bridge/bridge-vulnerable
+//   cargo test-sbf --test differential
+
+use litesvm::{types::TransactionResult, LiteSVM};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use solana_system_interface::program::ID as system_program;
+use soteria_test_kit::*;
+use spl_associated_token_account::get_associated_token_address;
+
+// declare_id! of bridge-secure
+const SECURE_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("GffMwipgANjeawsew8p5W3aRTv1EZ9zNgAfHV3wyYteo");
+const VULNERABLE_PROGRAM_ID: Pubkey = Pubkey::new_from_array(bridge_vulnerable::ID.to_bytes());
+
+const PAIR: ProgramPair = ProgramPair {
+    secure: SECURE_PROGRAM_ID,
+    vulnerable: VULNERABLE_PROGRAM_ID,
+};
+
+// 2-of-3 bridge holding 1000 tokens, one 100-token message posted
+const GUARDIAN_COUNT: usize = 3;
+const THRESHOLD: u8 = 2;
+const VAULT_BALANCE: u64 = 1_000_000_000;
+const MESSAGE_AMOUNT: u64 = 100_000_000;
+const SOURCE_CHAIN: u16 = 2;
+const SEQUENCE: u64 = 1;
+
+fn setup_pair() -> LiteSVM {
+    PAIR.load(
+        "../bridge-secure/target/deploy/bridge_secure.so",
+        "target/deploy/bridge_vulnerable.so",
+    )
+}
+
+// Baseline bridge-secure and its build with one vuln-* feature, each in its own SVM
+fn setup_feature(feature: &'static str) -> (FeatureVariant, LiteSVM, LiteSVM) {
+    let variant = FeatureVariant { program_id: SECURE_PROGRAM_ID, feature };
+    let (baseline, variant_svm) = variant.load(
+        "../bridge-secure/target/deploy/bridge_secure.so",
+        variant.so_path("../bridge-secure", "bridge_secure"),
+    );
+    (variant, baseline, variant_svm)
+}
+
+fn post_message_ix(
+    program_id: &Pubkey,
+    poster: &Pubkey,
+    bridge: &Pubkey,
+    sequence: u64,
+    recipient: &Pubkey,
+) -> (Instruction, Pubkey) {
+    let (message, _) = Pubkey::find_program_address(
+        &[b"message", bridge.as_ref(), &SOURCE_CHAIN.to_le_bytes(), &sequence.to_le_bytes()],
+        program_id,
+    );
+
+    let mut data = anchor_discriminator("post_message").to_vec();
+    data.extend_from_slice(&SOURCE_CHAIN.to_le_bytes());
+    data.extend_from_slice(&sequence.to_le_bytes());
+    data.extend_from_slice(recipient.as_ref());
+    data.extend_from_slice(&MESSAGE_AMOUNT.to_le_bytes());
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*poster, true),
+            AccountMeta::new_readonly(*bridge, false),
+            AccountMeta::new(message, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    };
+    (ix, message)
+}
+
+// Bridge and posted message on one build
+struct Bridge {
+    program_id: Pubkey,
+    guardians: Vec<Keypair>,
+    relayer: Keypair,
+    recipient: Pubkey,
+    mint: Pubkey,
+    bridge: Pubkey,
+    message: Pubkey,
+}
+
+impl Bridge {
+    fn new(svm: &mut LiteSVM, program_id: &Pubkey) -> Self {
+        let admin = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let relayer = create_funded_account(svm, LAMPORTS_PER_SOL);
+        let recipient = create_funded_account(svm, LAMPORTS_PER_SOL);
+        let guardians: Vec<Keypair> = (0..GUARDIAN_COUNT)
+            .map(|_| create_funded_account(svm, LAMPORTS_PER_SOL))
+            .collect();
+
+        let mint = CreateMint::new(svm, &admin).decimals(6).send().unwrap();
+        let (bridge, _) =
+            Pubkey::find_program_address(&[b"bridge", admin.pubkey().as_ref()], program_id);
+
+        let mut data = anchor_discriminator("initialize_bridge").to_vec();
+        data.extend_from_slice(&(GUARDIAN_COUNT as u32).to_le_bytes());
+        for guardian in &guardians {
+            data.extend_from_slice(guardian.pubkey().as_ref());
+        }
+        data.push(THRESHOLD);
+
+        let ix = Instruction {
+            program_id: *program_id,
+            accounts: vec![
+                AccountMeta::new(admin.pubkey(), true),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new(bridge, false),
+                AccountMeta::new(get_associated_token_address(&bridge, &mint), false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+                AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+            data,
+        };
+        send_ix(svm, ix, &admin).expect("Bridge initialization should succeed on both builds");
+
+        let vault = get_associated_token_address(&bridge, &mint);
+        MintTo::new(svm, &admin, &mint, &vault, VAULT_BALANCE).send().unwrap();
+        CreateAssociatedTokenAccount::new(svm, &recipient, &mint).send().unwrap();
+
+        // Guardian 0 relays, since the secure build only accepts guardian posts
+        let (ix, message) = post_message_ix(
+            program_id,
+            &guardians[0].pubkey(),
+            &bridge,
+            SEQUENCE,
+            &recipient.pubkey(),
+        );
+        send_ix(svm, ix, &guardians[0]).expect("Post message should succeed on both builds");
+
+        Bridge {
+            program_id: *program_id,
+            guardians,
+            relayer,
+            recipient: recipient.pubkey(),
+            mint,
+            bridge,
+            message,
+        }
+    }
+
+    fn attest(&self, svm: &mut LiteSVM, guardian: usize) -> TransactionResult {
+        let ix = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(self.guardians[guardian].pubkey(), true),
+                AccountMeta::new_readonly(self.bridge, false),
+                AccountMeta::new(self.message, false),
+            ],
+            data: anchor_discriminator("attest_message").to_vec(),
+        };
+        send_ix(svm, ix, &self.guardians[guardian])
+    }
+
+    fn execute(&self, svm: &mut LiteSVM) -> TransactionResult {
+        let ix = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(self.relayer.pubkey(), true),
+                AccountMeta::new_readonly(self.bridge, false),
+                AccountMeta::new(self.message, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new(get_associated_token_address(&self.bridge, &self.mint), false),
+                AccountMeta::new(get_associated_token_address(&self.recipient, &self.mint), false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+            ],
+            data: anchor_discriminator("execute_message").to_vec(),
+        };
+        send_ix(svm, ix, &self.relayer)
+    }
+}
+
+// V001: One guardian attests twice and the message executes on its word alone
+struct DuplicateAttestation;
+
+impl DifferentialScenario for DuplicateAttestation {
+    type State = Bridge;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Single-Guardian Release - Duplicate Attestations",
+            severity: Severity::Critical,
+            lesson: "Count distinct guardians, and reject a guardian that has already attested",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, program_id: &Pubkey) -> Bridge {
+        Bridge::new(svm, program_id)
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        bridge: &mut Bridge,
+    ) -> TransactionResult {
+        for _ in 0..THRESHOLD {
+            bridge.attest(svm, 0)?;
+        }
+        bridge.execute(svm)
+    }
+}
+
+#[test]
+fn test_differential_duplicate_attestation() {
+    let mut svm = setup_pair();
+    run_differential(&mut svm, &PAIR, &DuplicateAttestation);
+}
+
+// V002: A legitimately executed message is executed again
+struct MessageReplay;
+
+impl DifferentialScenario for MessageReplay {
+    type State = Bridge;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Message Replay - Executed Flag Never Checked",
+            severity: Severity::Critical,
+            lesson: "Check the executed flag before releasing, not just after",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, program_id: &Pubkey) -> Bridge {
+        let bridge = Bridge::new(svm, program_id);
+        for guardian in 0..THRESHOLD as usize {
+            bridge.attest(svm, guardian).expect("Attest should succeed on both builds");
+        }
+        bridge.execute(svm).expect("First execution should succeed on both builds");
+        bridge
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        bridge: &mut Bridge,
+    ) -> TransactionResult {
+        bridge.execute(svm)
+    }
+}
+
+#[test]
+fn test_differential_message_replay() {
+    let mut svm = setup_pair();
+    run_differential(&mut svm, &PAIR, &MessageReplay);
+}
+
+// V003: An outsider claims the next sequence before the real transfer is posted
+struct SequenceSquatting;
+
+impl DifferentialScenario for SequenceSquatting {
+    type State = (Bridge, Keypair);
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V003",
+            title: "Sequence Squatting - Anyone Posts, Nothing Rejects",
+            severity: Severity::High,
+            lesson: "Restrict who can post into a one-per-sequence slot, and give guardians a way to reject what should not be there",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, program_id: &Pubkey) -> (Bridge, Keypair) {
+        let bridge = Bridge::new(svm, program_id);
+        let attacker = create_funded_account(svm, LAMPORTS_PER_SOL);
+        (bridge, attacker)
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        program_id: &Pubkey,
+        (bridge, attacker): &mut (Bridge, Keypair),
+    ) -> TransactionResult {
+        let (ix, _) = post_message_ix(
+            program_id,
+            &attacker.pubkey(),
+            &bridge.bridge,
+            SEQUENCE + 1,
+            &attacker.pubkey(),
+        );
+        send_ix(svm, ix, attacker)
+    }
+}
+
+#[test]
+fn test_differential_sequence_squatting() {
+    let mut svm = setup_pair();
+    run_differential(&mut svm, &PAIR, &SequenceSquatting);
+}
+
+#[test]
+fn test_feature_missing_duplicate_check() {
+    let (variant, mut baseline, mut variant_svm) = setup_feature("vuln-missing-duplicate-check");
+    run_feature_differential(&mut baseline, &mut variant_svm, &variant, &DuplicateAttestation);
+}
@@ -0,0 +1,14 @@
+// Seed for market PDA
+// Derived with: [MARKET_SEED, base_mint_pubkey, quote_mint_pubkey]
+pub const MARKET_SEED: &[u8] = b"market";
+
+// Seed for the order book slab PDA
+// Derived with: [ORDER_BOOK_SEED, market_pubkey]
+pub const ORDER_BOOK_SEED: &[u8] = b"order_book";
+
+// ORDER BOOK LIMITS
+
+// Number of order slots in the slab (bids and asks share the slab)
+pub const MAX_ORDERS: usize = 64;
+
+pub const ANCHOR_DISCRIMINATOR: usize = 8;
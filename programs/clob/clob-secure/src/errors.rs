@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+pub use soteria_errors::clob_secure::ClobError as ClobErrorCode;
+
+#[error_code]
+pub enum ClobError {
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Price must be greater than zero")]
+    ZeroPrice,
+
+    #[msg("Base and quote mints must be different")]
+    IdenticalMints,
+
+    #[msg("Order book is full")]
+    OrderBookFull,
+
+    #[msg("Order not found")]
+    OrderNotFound,
+
+    #[msg("Order is on the wrong side of the book")]
+    InvalidSide,
+
+    #[msg("Bid price is below the ask price")]
+    PricesDoNotCross,
+
+    #[msg("Bid and ask belong to the same owner")]
+    SelfTrade,
+
+    #[msg("Token account does not belong to the order owner")]
+    InvalidOwnerAccount,
+
+    #[msg("Unauthorized: Cannot perform this action")]
+    Unauthorized,
+
+    #[msg("Arithmetic overflow")]
+    Overflow,
+
+    #[msg("Arithmetic underflow")]
+    Underflow,
+}
@@ -0,0 +1,119 @@
+// Amend Order Instruction
+//
+// Moves a resting order to a new price, keeping its place in the slab
+// and its remaining quantity.
+//
+// HOW IT WORKS:
+// 1. Finds the order in the slab and checks the signer owns it
+// 2. Re-balances the locked funds:
+//    - Bid, price raised: owner locks (new - old) * quantity more quote
+//    - Bid, price lowered: (old - new) * quantity quote returned to the owner
+//    - Ask: nothing moves, asks lock base regardless of price
+// 3. Writes the new price
+//
+// SECURITY:
+// - Only the order owner can amend (order.owner == signer)
+// - new_price > 0
+// - Quote source/destination must belong to the owner
+// - Locked funds always equal price * quantity for bids afterwards
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct AmendOrder<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [MARKET_SEED, market.base_mint.as_ref(), quote_mint.key().as_ref()],
+        bump = market.bump,
+        has_one = quote_mint,
+        has_one = quote_vault,
+        has_one = order_book,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(mut)]
+    pub order_book: AccountLoader<'info, OrderBook>,
+
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> AmendOrder<'info> {
+    pub fn amend_order(&mut self, order_id: u64, new_price: u64) -> Result<()> {
+        require!(new_price > 0, ClobError::ZeroPrice);
+
+        // Update the order in the slab before moving funds
+        let (old_price, quantity, side) = {
+            let mut order_book = self.order_book.load_mut()?;
+            let index = order_book.index_of(order_id)?;
+            let order = &mut order_book.orders[index];
+
+            #[cfg(not(feature = "vuln-missing-owner-check"))]
+            require_keys_eq!(order.owner, self.owner.key(), ClobError::Unauthorized);
+
+            let old_price = order.price;
+            order.price = new_price;
+            (old_price, order.quantity, order.side())
+        };
+
+        if side == Side::Bid && new_price > old_price {
+            let extra = (new_price - old_price)
+                .checked_mul(quantity)
+                .ok_or(ClobError::Overflow)?;
+
+            transfer_tokens(
+                extra,
+                self.quote_mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.owner_quote_account.to_account_info(),
+                &self.quote_mint.to_account_info(),
+                &self.quote_vault.to_account_info(),
+                &self.owner.to_account_info(),
+            )?;
+        } else if side == Side::Bid && new_price < old_price {
+            let released = (old_price - new_price)
+                .checked_mul(quantity)
+                .ok_or(ClobError::Overflow)?;
+
+            let base_mint_key = self.market.base_mint;
+            let quote_mint_key = self.quote_mint.key();
+            let market_seeds = &[
+                MARKET_SEED,
+                base_mint_key.as_ref(),
+                quote_mint_key.as_ref(),
+                &[self.market.bump],
+            ];
+
+            transfer_from_vault(
+                released,
+                self.quote_mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.quote_vault.to_account_info(),
+                &self.quote_mint.to_account_info(),
+                &self.owner_quote_account.to_account_info(),
+                &self.market.to_account_info(),
+                market_seeds,
+            )?;
+        }
+
+        msg!("Order {} amended: price {} -> {}", order_id, old_price, new_price);
+
+        Ok(())
+    }
+}
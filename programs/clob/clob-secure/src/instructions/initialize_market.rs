@@ -0,0 +1,107 @@
+// Initialize Market Instruction
+//
+// Opens a trading pair: base tokens bought and sold for quote tokens.
+//
+// HOW IT WORKS:
+// 1. Creates the market PDA for (base_mint, quote_mint)
+// 2. Creates the zero-copy order book slab PDA with every slot free
+// 3. Creates the base and quote vault ATAs owned by the market
+//
+// SECURITY:
+// - Base and quote mints must differ
+// - One market per pair: the PDA seeds are the two mints
+// - Order book and vaults are recorded on the market and checked by
+//   every later instruction (has_one)
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct InitializeMarket<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mint::token_program = token_program)]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR + Market::INIT_SPACE,
+        seeds = [MARKET_SEED, base_mint.key().as_ref(), quote_mint.key().as_ref()],
+        bump
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR + std::mem::size_of::<OrderBook>(),
+        seeds = [ORDER_BOOK_SEED, market.key().as_ref()],
+        bump
+    )]
+    pub order_book: AccountLoader<'info, OrderBook>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = base_mint,
+        associated_token::authority = market,
+        associated_token::token_program = token_program,
+    )]
+    pub base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = market,
+        associated_token::token_program = token_program,
+    )]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeMarket<'info> {
+    pub fn initialize_market(&mut self, bumps: &InitializeMarketBumps) -> Result<()> {
+        require_keys_neq!(
+            self.base_mint.key(),
+            self.quote_mint.key(),
+            ClobError::IdenticalMints
+        );
+
+        self.market.set_inner(Market {
+            base_mint: self.base_mint.key(),
+            quote_mint: self.quote_mint.key(),
+            base_vault: self.base_vault.key(),
+            quote_vault: self.quote_vault.key(),
+            order_book: self.order_book.key(),
+            last_trade_price: 0,
+            bump: bumps.market,
+        });
+
+        // Zero-initialized slab: every slot starts free
+        let mut order_book = self.order_book.load_init()?;
+        order_book.market = self.market.key();
+        order_book.next_order_id = 0;
+
+        msg!(
+            "Market initialized: base {} / quote {}",
+            self.base_mint.key(),
+            self.quote_mint.key()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+// Match Orders Instruction
+//
+// Crank: fills a crossing bid and ask against each other. Anyone can call
+// it; the payouts only ever go to the two order owners.
+//
+// HOW IT WORKS:
+// 1. Loads both orders and checks they cross (bid price >= ask price)
+// 2. Fills min(bid quantity, ask quantity) at the resting price: the price
+//    of whichever order was placed first (lower order id)
+// 3. Pays out from the market vaults:
+//    - Base to the bidder
+//    - Fill * execution price quote to the asker
+//    - Price improvement (bid price - execution price) * fill back to the bidder
+// 4. Reduces both quantities, frees any slot that is completely filled,
+//    and records the execution price as the market's last trade price
+//
+// SECURITY:
+// - Bid must be a bid and ask must be an ask
+// - Self-trade prevention: bid and ask owners must differ, so nobody can
+//   print trades against themselves to move last_trade_price
+// - Payout token accounts must belong to the order owners
+// - Every payout is covered by the funds the two orders locked
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct MatchOrders<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, base_mint.key().as_ref(), quote_mint.key().as_ref()],
+        bump = market.bump,
+        has_one = base_mint,
+        has_one = quote_mint,
+        has_one = base_vault,
+        has_one = quote_vault,
+        has_one = order_book,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(mut)]
+    pub order_book: AccountLoader<'info, OrderBook>,
+
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Receives the filled base (owner checked against the bid in the handler)
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::token_program = token_program,
+    )]
+    pub bidder_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Receives any price improvement (owner checked against the bid in the handler)
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::token_program = token_program,
+    )]
+    pub bidder_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Receives the sale proceeds (owner checked against the ask in the handler)
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::token_program = token_program,
+    )]
+    pub asker_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> MatchOrders<'info> {
+    pub fn match_orders(&mut self, bid_id: u64, ask_id: u64) -> Result<()> {
+        // Validate and update both orders in the slab before paying out
+        let (fill, execution_price, bid_price) = {
+            let mut order_book = self.order_book.load_mut()?;
+            let bid_index = order_book.index_of(bid_id)?;
+            let ask_index = order_book.index_of(ask_id)?;
+            let bid = order_book.orders[bid_index];
+            let ask = order_book.orders[ask_index];
+
+            require!(bid.side() == Side::Bid, ClobError::InvalidSide);
+            require!(ask.side() == Side::Ask, ClobError::InvalidSide);
+            require_keys_neq!(bid.owner, ask.owner, ClobError::SelfTrade);
+            require!(bid.price >= ask.price, ClobError::PricesDoNotCross);
+
+            // Payouts go to the order owners, not wherever the cranker points them
+            require_keys_eq!(self.bidder_base_account.owner, bid.owner, ClobError::InvalidOwnerAccount);
+            require_keys_eq!(self.bidder_quote_account.owner, bid.owner, ClobError::InvalidOwnerAccount);
+            require_keys_eq!(self.asker_quote_account.owner, ask.owner, ClobError::InvalidOwnerAccount);
+
+            let fill = bid.quantity.min(ask.quantity);
+
+            // The order that was resting first sets the price
+            let execution_price = if bid.order_id < ask.order_id {
+                bid.price
+            } else {
+                ask.price
+            };
+
+            order_book.orders[bid_index].quantity = bid.quantity - fill;
+            order_book.orders[ask_index].quantity = ask.quantity - fill;
+
+            if order_book.orders[bid_index].quantity == 0 {
+                order_book.remove(bid_index);
+            }
+            if order_book.orders[ask_index].quantity == 0 {
+                order_book.remove(ask_index);
+            }
+
+            (fill, execution_price, bid.price)
+        };
+
+        let proceeds = execution_price
+            .checked_mul(fill)
+            .ok_or(ClobError::Overflow)?;
+        let price_improvement = (bid_price - execution_price)
+            .checked_mul(fill)
+            .ok_or(ClobError::Overflow)?;
+
+        let base_mint_key = self.base_mint.key();
+        let quote_mint_key = self.quote_mint.key();
+        let market_seeds = &[
+            MARKET_SEED,
+            base_mint_key.as_ref(),
+            quote_mint_key.as_ref(),
+            &[self.market.bump],
+        ];
+
+        // Base to the bidder
+        transfer_from_vault(
+            fill,
+            self.base_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.base_vault.to_account_info(),
+            &self.base_mint.to_account_info(),
+            &self.bidder_base_account.to_account_info(),
+            &self.market.to_account_info(),
+            market_seeds,
+        )?;
+
+        // Quote to the asker
+        transfer_from_vault(
+            proceeds,
+            self.quote_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.quote_vault.to_account_info(),
+            &self.quote_mint.to_account_info(),
+            &self.asker_quote_account.to_account_info(),
+            &self.market.to_account_info(),
+            market_seeds,
+        )?;
+
+        // Bid locked more than the execution price needs, return the difference
+        if price_improvement > 0 {
+            transfer_from_vault(
+                price_improvement,
+                self.quote_mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.quote_vault.to_account_info(),
+                &self.quote_mint.to_account_info(),
+                &self.bidder_quote_account.to_account_info(),
+                &self.market.to_account_info(),
+                market_seeds,
+            )?;
+        }
+
+        self.market.last_trade_price = execution_price;
+
+        msg!(
+            "Matched bid {} with ask {}: {} @ {}",
+            bid_id,
+            ask_id,
+            fill,
+            execution_price
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+// Order Book Program - SECURE VERSION
+//
+// Implementation of a minimal central limit order book (CLOB) with
+// crank-based matching. This program allows users to:
+// 1. Open a market for a base/quote token pair
+// 2. Place limit bids and asks, locking their funds in the market vaults
+// 3. Amend the price of or cancel their own resting orders
+// 4. Match any crossing bid and ask (permissionless crank)
+//
+// SECURITY FEATURES:
+// - Only an order's owner can amend or cancel it
+// - Self-trade prevention on every match
+// - Crank payouts pinned to the order owners' token accounts
+// - Fills execute at the resting order's price
+// - Checked arithmetic to prevent overflow/underflow
+//
+// ORDER BOOK LAYOUT:
+// Orders live in a zero-copy slab account (OrderBook) with MAX_ORDERS fixed
+// slots shared by both sides, read and written in place via AccountLoader.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+use state::Side;
+
+declare_id!("GoXsezFYSdFCZXbu7p9MJKhbhih2mSnBsGSnkhmqqpAe");
+
+#[program]
+pub mod clob_secure {
+    use super::*;
+
+    // Create the market, its order book slab, and its vaults
+    pub fn initialize_market(ctx: Context<InitializeMarket>) -> Result<()> {
+        ctx.accounts.initialize_market(&ctx.bumps)
+    }
+
+    // Rest a limit order on the book
+    pub fn place_order(ctx: Context<PlaceOrder>, side: Side, price: u64, quantity: u64) -> Result<()> {
+        ctx.accounts.place_order(side, price, quantity)
+    }
+
+    // Remove a resting order and return its locked funds
+    pub fn cancel_order(ctx: Context<CancelOrder>, order_id: u64) -> Result<()> {
+        ctx.accounts.cancel_order(order_id)
+    }
+
+    // Move a resting order to a new price
+    pub fn amend_order(ctx: Context<AmendOrder>, order_id: u64, new_price: u64) -> Result<()> {
+        ctx.accounts.amend_order(order_id, new_price)
+    }
+
+    // Fill a crossing bid and ask (anyone can call)
+    pub fn match_orders(ctx: Context<MatchOrders>, bid_id: u64, ask_id: u64) -> Result<()> {
+        ctx.accounts.match_orders(bid_id, ask_id)
+    }
+}
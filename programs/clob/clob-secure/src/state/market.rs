@@ -0,0 +1,34 @@
+// Market State
+//
+// One trading pair: orders buy and sell the base token, priced in the quote
+// token. Funds locked by resting orders are held in two vaults owned by
+// this PDA, and the orders themselves live in a separate zero-copy slab
+// (see order_book.rs).
+
+
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Market {
+    // Mint being bought and sold
+    pub base_mint: Pubkey,
+
+    // Mint prices are quoted in
+    pub quote_mint: Pubkey,
+
+    // Vault ATA holding base locked by resting asks (owned by this PDA)
+    pub base_vault: Pubkey,
+
+    // Vault ATA holding quote locked by resting bids (owned by this PDA)
+    pub quote_vault: Pubkey,
+
+    // Order book slab for this market
+    pub order_book: Pubkey,
+
+    // Price of the most recent fill (0 before the first trade)
+    // Read by other programs as a reference price for the pair
+    pub last_trade_price: u64,
+
+    pub bump: u8,
+}
@@ -0,0 +1,77 @@
+// Compute-unit regression tests for clob-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+mod utils;
+
+use litesvm_token::CreateMint;
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signature::Signer};
+use soteria_test_kit::{ComputeBudget, DEFAULT_CU_BASELINES_FILE};
+use utils::*;
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let mut svm = setup_svm();
+    let mint_authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let base_mint = CreateMint::new(&mut svm, &mint_authority)
+        .decimals(BASE_DECIMALS)
+        .send()
+        .expect("Failed to create base mint");
+    let quote_mint = CreateMint::new(&mut svm, &mint_authority)
+        .decimals(QUOTE_DECIMALS)
+        .send()
+        .expect("Failed to create quote mint");
+
+    let ix = build_initialize_market_ix(&mint_authority.pubkey(), &base_mint, &quote_mint);
+    budget.record("initialize_market", send_ix(&mut svm, ix, &mint_authority));
+
+    let (market, _) = derive_market_pda(&base_mint, &quote_mint);
+    let (order_book, _) = derive_order_book_pda(&market);
+    let mut scenario = ClobScenario {
+        svm,
+        mint_authority,
+        base_mint,
+        quote_mint,
+        market,
+        order_book,
+    };
+    let alice = create_trader(&mut scenario);
+    let bob = create_trader(&mut scenario);
+
+    let bid_id = get_next_order_id(&scenario.svm, &order_book);
+    let ix =
+        build_place_order_ix(&alice.pubkey(), &base_mint, &quote_mint, SIDE_BID, 2 * ONE_QUOTE, 10);
+    budget.record("place_order_bid", send_ix(&mut scenario.svm, ix, &alice));
+
+    let ask_id = get_next_order_id(&scenario.svm, &order_book);
+    let ix =
+        build_place_order_ix(&bob.pubkey(), &base_mint, &quote_mint, SIDE_ASK, 3 * ONE_QUOTE, 10);
+    budget.record("place_order_ask", send_ix(&mut scenario.svm, ix, &bob));
+
+    // Raising the bid locks the extra quote
+    let ix = build_amend_order_ix(&alice.pubkey(), &base_mint, &quote_mint, bid_id, 3 * ONE_QUOTE);
+    budget.record("amend_order", send_ix(&mut scenario.svm, ix, &alice));
+
+    let ix = build_match_orders_ix(
+        &bob.pubkey(),
+        &base_mint,
+        &quote_mint,
+        bid_id,
+        ask_id,
+        &alice.pubkey(),
+        &bob.pubkey(),
+    );
+    budget.record("match_orders", send_ix(&mut scenario.svm, ix, &bob));
+
+    let order_id = place_order(&mut scenario, &alice, SIDE_BID, ONE_QUOTE, 5);
+    let ix = build_cancel_order_ix(&alice.pubkey(), &base_mint, &quote_mint, order_id);
+    budget.record("cancel_order", send_ix(&mut scenario.svm, ix, &alice));
+
+    budget.check();
+}
@@ -0,0 +1,184 @@
+// Integration tests for order book program using LiteSVM
+// These tests verify core order book functionality: placing, cancelling and
+// amending orders, crank matching, and self-trade prevention
+
+mod utils;
+
+use utils::*;
+use solana_sdk::signature::Signer;
+
+#[test]
+fn test_place_and_cancel_order() {
+    // Test: Orders lock funds on placement and refund them on cancel
+    println!("\n[TEST START] test_place_and_cancel_order - Order placement and cancellation");
+
+    let mut scenario = setup_clob_scenario();
+    let alice = create_trader(&mut scenario);
+    let bob = create_trader(&mut scenario);
+    println!("[Setup] Market open, Alice and Bob funded");
+
+    // A second market for the same mint on both sides is rejected
+    let ix = build_initialize_market_ix(&alice.pubkey(), &scenario.base_mint, &scenario.base_mint);
+    assert!(send_ix(&mut scenario.svm, ix, &alice).is_err(), "Identical mints should fail");
+    println!("[Success] Market with identical mints rejected");
+
+    // Zero price is rejected
+    let ix = build_place_order_ix(&alice.pubkey(), &scenario.base_mint, &scenario.quote_mint, SIDE_BID, 0, 10);
+    assert!(send_ix(&mut scenario.svm, ix, &alice).is_err(), "Zero price should fail");
+    println!("[Success] Zero price rejected");
+
+    println!("[Action] Alice bids 10 @ 2 and asks 5 @ 3");
+    let bid_id = place_order(&mut scenario, &alice, SIDE_BID, 2 * ONE_QUOTE, 10);
+    let ask_id = place_order(&mut scenario, &alice, SIDE_ASK, 3 * ONE_QUOTE, 5);
+
+    assert_eq!(quote_balance(&scenario, &scenario.market), 20 * ONE_QUOTE);
+    assert_eq!(base_balance(&scenario, &scenario.market), 5);
+    assert_eq!(get_order(&scenario.svm, &scenario.order_book, bid_id), Some((alice.pubkey(), 2 * ONE_QUOTE, 10)));
+    assert_eq!(get_order(&scenario.svm, &scenario.order_book, ask_id), Some((alice.pubkey(), 3 * ONE_QUOTE, 5)));
+    println!("[Success] Bid locked 20 quote, ask locked 5 base");
+
+    // Bob cannot cancel Alice's order
+    let ix = build_cancel_order_ix(&bob.pubkey(), &scenario.base_mint, &scenario.quote_mint, bid_id);
+    assert!(send_ix(&mut scenario.svm, ix, &bob).is_err(), "Non-owner cancel should fail");
+    println!("[Success] Cancel by non-owner rejected");
+
+    println!("[Action] Alice cancels both orders");
+    let ix = build_cancel_order_ix(&alice.pubkey(), &scenario.base_mint, &scenario.quote_mint, bid_id);
+    let result = send_ix(&mut scenario.svm, ix, &alice);
+    assert!(result.is_ok(), "Cancel bid failed: {:?}", result.err());
+    let ix = build_cancel_order_ix(&alice.pubkey(), &scenario.base_mint, &scenario.quote_mint, ask_id);
+    let result = send_ix(&mut scenario.svm, ix, &alice);
+    assert!(result.is_ok(), "Cancel ask failed: {:?}", result.err());
+
+    assert_eq!(quote_balance(&scenario, &alice.pubkey()), TRADER_QUOTE_BALANCE);
+    assert_eq!(base_balance(&scenario, &alice.pubkey()), TRADER_BASE_BALANCE);
+    assert_eq!(get_order(&scenario.svm, &scenario.order_book, bid_id), None);
+    println!("[Success] Locked funds returned, slots freed");
+
+    // A cancelled order cannot be cancelled again
+    let ix = build_cancel_order_ix(&alice.pubkey(), &scenario.base_mint, &scenario.quote_mint, bid_id);
+    assert!(send_ix(&mut scenario.svm, ix, &alice).is_err(), "Second cancel should fail");
+    println!("[Success] Second cancel rejected");
+
+    println!("[TEST END] test_place_and_cancel_order");
+}
+
+#[test]
+fn test_match_orders() {
+    // Test: Crank fills crossing orders at the resting price
+    println!("\n[TEST START] test_match_orders - Crank matching");
+
+    let mut scenario = setup_clob_scenario();
+    let alice = create_trader(&mut scenario);
+    let bob = create_trader(&mut scenario);
+    let cranker = create_trader(&mut scenario);
+
+    let ask_id = place_order(&mut scenario, &alice, SIDE_ASK, 2 * ONE_QUOTE, 100);
+    let bid_id = place_order(&mut scenario, &bob, SIDE_BID, 3 * ONE_QUOTE, 60);
+    println!("[Setup] Alice asks 100 @ 2 (resting), Bob bids 60 @ 3");
+
+    // Sides swapped
+    let ix = build_match_orders_ix(&cranker.pubkey(), &scenario.base_mint, &scenario.quote_mint, ask_id, bid_id, &bob.pubkey(), &alice.pubkey());
+    assert!(send_ix(&mut scenario.svm, ix, &cranker).is_err(), "Swapped sides should fail");
+    println!("[Success] Bid/ask sides swapped rejected");
+
+    // Cranker tries to collect the proceeds
+    let ix = build_match_orders_ix(&cranker.pubkey(), &scenario.base_mint, &scenario.quote_mint, bid_id, ask_id, &bob.pubkey(), &cranker.pubkey());
+    assert!(send_ix(&mut scenario.svm, ix, &cranker).is_err(), "Redirected proceeds should fail");
+    println!("[Success] Payout to a non-owner account rejected");
+
+    println!("[Action] Cranker matches Bob's bid with Alice's ask");
+    let ix = build_match_orders_ix(&cranker.pubkey(), &scenario.base_mint, &scenario.quote_mint, bid_id, ask_id, &bob.pubkey(), &alice.pubkey());
+    let result = send_ix(&mut scenario.svm, ix, &cranker);
+    assert!(result.is_ok(), "Match failed: {:?}", result.err());
+
+    // 60 filled at Alice's resting price of 2
+    assert_eq!(base_balance(&scenario, &bob.pubkey()), TRADER_BASE_BALANCE + 60);
+    assert_eq!(quote_balance(&scenario, &bob.pubkey()), TRADER_QUOTE_BALANCE - 120 * ONE_QUOTE);
+    assert_eq!(quote_balance(&scenario, &alice.pubkey()), TRADER_QUOTE_BALANCE + 120 * ONE_QUOTE);
+    println!("[Success] Bob received 60 base for 120 quote (60 quote price improvement refunded)");
+
+    assert_eq!(get_order(&scenario.svm, &scenario.order_book, bid_id), None);
+    assert_eq!(get_order(&scenario.svm, &scenario.order_book, ask_id), Some((alice.pubkey(), 2 * ONE_QUOTE, 40)));
+    assert_eq!(base_balance(&scenario, &scenario.market), 40);
+    assert_eq!(quote_balance(&scenario, &scenario.market), 0);
+    assert_eq!(get_last_trade_price(&scenario.svm, &scenario.market), 2 * ONE_QUOTE);
+    println!("[Success] Bid filled, ask has 40 left, vaults hold exactly the remaining ask");
+
+    // Orders that do not cross are not matched
+    let low_bid = place_order(&mut scenario, &bob, SIDE_BID, ONE_QUOTE, 10);
+    let ix = build_match_orders_ix(&cranker.pubkey(), &scenario.base_mint, &scenario.quote_mint, low_bid, ask_id, &bob.pubkey(), &alice.pubkey());
+    assert!(send_ix(&mut scenario.svm, ix, &cranker).is_err(), "Non-crossing orders should fail");
+    println!("[Success] Non-crossing orders rejected");
+
+    println!("[TEST END] test_match_orders");
+}
+
+#[test]
+fn test_self_trade_rejected() {
+    // Test: An owner's bid cannot be matched against their own ask
+    println!("\n[TEST START] test_self_trade_rejected - Self-trade prevention");
+
+    let mut scenario = setup_clob_scenario();
+    let alice = create_trader(&mut scenario);
+
+    let ask_id = place_order(&mut scenario, &alice, SIDE_ASK, 500 * ONE_QUOTE, 1);
+    let bid_id = place_order(&mut scenario, &alice, SIDE_BID, 500 * ONE_QUOTE, 1);
+    println!("[Setup] Alice asks and bids 1 @ 500 against herself");
+
+    println!("[Action] Alice cranks her own orders together");
+    let ix = build_match_orders_ix(&alice.pubkey(), &scenario.base_mint, &scenario.quote_mint, bid_id, ask_id, &alice.pubkey(), &alice.pubkey());
+    assert!(send_ix(&mut scenario.svm, ix, &alice).is_err(), "Self-trade should fail");
+
+    assert_eq!(get_last_trade_price(&scenario.svm, &scenario.market), 0);
+    assert!(get_order(&scenario.svm, &scenario.order_book, bid_id).is_some());
+    assert!(get_order(&scenario.svm, &scenario.order_book, ask_id).is_some());
+    println!("[Success] Self-trade rejected, last trade price unchanged");
+
+    println!("[TEST END] test_self_trade_rejected");
+}
+
+#[test]
+fn test_amend_order() {
+    // Test: Owners can re-price their orders; locked funds follow the price
+    println!("\n[TEST START] test_amend_order - Order price amendment");
+
+    let mut scenario = setup_clob_scenario();
+    let alice = create_trader(&mut scenario);
+    let bob = create_trader(&mut scenario);
+
+    let bid_id = place_order(&mut scenario, &alice, SIDE_BID, 2 * ONE_QUOTE, 10);
+    let ask_id = place_order(&mut scenario, &alice, SIDE_ASK, 5 * ONE_QUOTE, 10);
+    println!("[Setup] Alice bids 10 @ 2 and asks 10 @ 5");
+
+    // Bob cannot re-price Alice's orders
+    let ix = build_amend_order_ix(&bob.pubkey(), &scenario.base_mint, &scenario.quote_mint, ask_id, 1);
+    assert!(send_ix(&mut scenario.svm, ix, &bob).is_err(), "Non-owner amend should fail");
+    assert_eq!(get_order(&scenario.svm, &scenario.order_book, ask_id), Some((alice.pubkey(), 5 * ONE_QUOTE, 10)));
+    println!("[Success] Amend by non-owner rejected");
+
+    println!("[Action] Alice raises her bid to 3");
+    let ix = build_amend_order_ix(&alice.pubkey(), &scenario.base_mint, &scenario.quote_mint, bid_id, 3 * ONE_QUOTE);
+    let result = send_ix(&mut scenario.svm, ix, &alice);
+    assert!(result.is_ok(), "Amend up failed: {:?}", result.err());
+    assert_eq!(quote_balance(&scenario, &scenario.market), 30 * ONE_QUOTE);
+    println!("[Success] 10 more quote locked");
+
+    println!("[Action] Alice lowers her bid to 1");
+    let ix = build_amend_order_ix(&alice.pubkey(), &scenario.base_mint, &scenario.quote_mint, bid_id, ONE_QUOTE);
+    let result = send_ix(&mut scenario.svm, ix, &alice);
+    assert!(result.is_ok(), "Amend down failed: {:?}", result.err());
+    assert_eq!(quote_balance(&scenario, &scenario.market), 10 * ONE_QUOTE);
+    assert_eq!(quote_balance(&scenario, &alice.pubkey()), TRADER_QUOTE_BALANCE - 10 * ONE_QUOTE);
+    println!("[Success] 20 quote released back to Alice");
+
+    println!("[Action] Alice lowers her ask to 4");
+    let ix = build_amend_order_ix(&alice.pubkey(), &scenario.base_mint, &scenario.quote_mint, ask_id, 4 * ONE_QUOTE);
+    let result = send_ix(&mut scenario.svm, ix, &alice);
+    assert!(result.is_ok(), "Amend ask failed: {:?}", result.err());
+    assert_eq!(get_order(&scenario.svm, &scenario.order_book, ask_id), Some((alice.pubkey(), 4 * ONE_QUOTE, 10)));
+    assert_eq!(base_balance(&scenario, &scenario.market), 10);
+    println!("[Success] Ask re-priced, locked base unchanged");
+
+    println!("[TEST END] test_amend_order");
+}
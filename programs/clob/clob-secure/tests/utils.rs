@@ -0,0 +1,357 @@
+// Test utilities for order book program
+
+use litesvm::{LiteSVM, types::TransactionResult};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo, get_spl_account};
+use litesvm_token::spl_token::state::Account as TokenAccount;
+use solana_sdk::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::program::ID as system_program;
+
+// Program ID matching declare_id!
+pub const CLOB_PROGRAM_ID: Pubkey = Pubkey::new_from_array(clob_secure::ID.to_bytes());
+
+// Build Anchor instruction discriminator
+// Formula: first 8 bytes of sha256("global:method_name")
+pub fn anchor_discriminator(method: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", method);
+    let hash_result = hash(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash_result.to_bytes()[..8]);
+    discriminator
+}
+
+// Standard program IDs
+pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_account::ID;
+
+// PDA Seeds
+pub const MARKET_SEED: &[u8] = b"market";
+pub const ORDER_BOOK_SEED: &[u8] = b"order_book";
+
+// Side enum as Borsh encodes it
+pub const SIDE_BID: u8 = 0;
+pub const SIDE_ASK: u8 = 1;
+
+// Base is traded in whole units (0 decimals), quote has 6 decimals
+// Prices are quote atoms per base atom, so 2 * ONE_QUOTE means 2 quote per base
+pub const BASE_DECIMALS: u8 = 0;
+pub const QUOTE_DECIMALS: u8 = 6;
+pub const ONE_QUOTE: u64 = 1_000_000;
+
+// Tokens each trader starts with
+pub const TRADER_BASE_BALANCE: u64 = 1_000;
+pub const TRADER_QUOTE_BALANCE: u64 = 10_000 * ONE_QUOTE;
+
+// OrderBook layout: discriminator (8) + market (32) + next_order_id (8) + orders
+// Order layout: order_id (8) + owner (32) + price (8) + quantity (8) + side (1)
+//               + is_active (1) + padding (6)
+const ORDERS_OFFSET: usize = 8 + 32 + 8;
+const ORDER_SIZE: usize = 64;
+const MAX_ORDERS: usize = 64;
+
+// Setup LiteSVM with order book program
+pub fn setup_svm() -> LiteSVM {
+    let mut svm = LiteSVM::new();
+    let program_bytes = include_bytes!("../target/deploy/clob_secure.so");
+    let _ = svm.add_program(CLOB_PROGRAM_ID, program_bytes);
+    svm
+}
+
+// Create and fund account
+pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
+    let keypair = Keypair::new();
+    svm.airdrop(&keypair.pubkey(), lamports)
+        .expect("Airdrop should succeed");
+    keypair
+}
+
+// Sign and send a single instruction
+pub fn send_ix(svm: &mut LiteSVM, ix: Instruction, signer: &Keypair) -> TransactionResult {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    svm.expire_blockhash();
+    result
+}
+
+// Derive market PDA
+pub fn derive_market_pda(base_mint: &Pubkey, quote_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MARKET_SEED, base_mint.as_ref(), quote_mint.as_ref()],
+        &CLOB_PROGRAM_ID,
+    )
+}
+
+// Derive order book slab PDA
+pub fn derive_order_book_pda(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ORDER_BOOK_SEED, market.as_ref()], &CLOB_PROGRAM_ID)
+}
+
+// Build initialize_market instruction
+pub fn build_initialize_market_ix(payer: &Pubkey, base_mint: &Pubkey, quote_mint: &Pubkey) -> Instruction {
+    let (market, _) = derive_market_pda(base_mint, quote_mint);
+    let (order_book, _) = derive_order_book_pda(&market);
+
+    Instruction {
+        program_id: CLOB_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*base_mint, false),
+            AccountMeta::new_readonly(*quote_mint, false),
+            AccountMeta::new(market, false),
+            AccountMeta::new(order_book, false),
+            AccountMeta::new(get_associated_token_address(&market, base_mint), false),
+            AccountMeta::new(get_associated_token_address(&market, quote_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data: anchor_discriminator("initialize_market").to_vec(),
+    }
+}
+
+// Accounts shared by place_order and cancel_order
+fn order_accounts(owner: &Pubkey, base_mint: &Pubkey, quote_mint: &Pubkey) -> Vec<AccountMeta> {
+    let (market, _) = derive_market_pda(base_mint, quote_mint);
+    let (order_book, _) = derive_order_book_pda(&market);
+
+    vec![
+        AccountMeta::new_readonly(*owner, true),
+        AccountMeta::new_readonly(market, false),
+        AccountMeta::new(order_book, false),
+        AccountMeta::new_readonly(*base_mint, false),
+        AccountMeta::new_readonly(*quote_mint, false),
+        AccountMeta::new(get_associated_token_address(&market, base_mint), false),
+        AccountMeta::new(get_associated_token_address(&market, quote_mint), false),
+        AccountMeta::new(get_associated_token_address(owner, base_mint), false),
+        AccountMeta::new(get_associated_token_address(owner, quote_mint), false),
+        AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+    ]
+}
+
+// Build place_order instruction
+pub fn build_place_order_ix(
+    owner: &Pubkey,
+    base_mint: &Pubkey,
+    quote_mint: &Pubkey,
+    side: u8,
+    price: u64,
+    quantity: u64,
+) -> Instruction {
+    let mut data = anchor_discriminator("place_order").to_vec();
+    data.push(side);
+    data.extend_from_slice(&price.to_le_bytes());
+    data.extend_from_slice(&quantity.to_le_bytes());
+
+    Instruction {
+        program_id: CLOB_PROGRAM_ID,
+        accounts: order_accounts(owner, base_mint, quote_mint),
+        data,
+    }
+}
+
+// Build cancel_order instruction
+pub fn build_cancel_order_ix(owner: &Pubkey, base_mint: &Pubkey, quote_mint: &Pubkey, order_id: u64) -> Instruction {
+    let mut data = anchor_discriminator("cancel_order").to_vec();
+    data.extend_from_slice(&order_id.to_le_bytes());
+
+    Instruction {
+        program_id: CLOB_PROGRAM_ID,
+        accounts: order_accounts(owner, base_mint, quote_mint),
+        data,
+    }
+}
+
+// Build amend_order instruction
+pub fn build_amend_order_ix(
+    owner: &Pubkey,
+    base_mint: &Pubkey,
+    quote_mint: &Pubkey,
+    order_id: u64,
+    new_price: u64,
+) -> Instruction {
+    let (market, _) = derive_market_pda(base_mint, quote_mint);
+    let (order_book, _) = derive_order_book_pda(&market);
+
+    let mut data = anchor_discriminator("amend_order").to_vec();
+    data.extend_from_slice(&order_id.to_le_bytes());
+    data.extend_from_slice(&new_price.to_le_bytes());
+
+    Instruction {
+        program_id: CLOB_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new_readonly(market, false),
+            AccountMeta::new(order_book, false),
+            AccountMeta::new_readonly(*quote_mint, false),
+            AccountMeta::new(get_associated_token_address(&market, quote_mint), false),
+            AccountMeta::new(get_associated_token_address(owner, quote_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Build match_orders instruction
+// Payouts go to the ATAs of `bidder` and `asker`
+pub fn build_match_orders_ix(
+    cranker: &Pubkey,
+    base_mint: &Pubkey,
+    quote_mint: &Pubkey,
+    bid_id: u64,
+    ask_id: u64,
+    bidder: &Pubkey,
+    asker: &Pubkey,
+) -> Instruction {
+    let (market, _) = derive_market_pda(base_mint, quote_mint);
+    let (order_book, _) = derive_order_book_pda(&market);
+
+    let mut data = anchor_discriminator("match_orders").to_vec();
+    data.extend_from_slice(&bid_id.to_le_bytes());
+    data.extend_from_slice(&ask_id.to_le_bytes());
+
+    Instruction {
+        program_id: CLOB_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*cranker, true),
+            AccountMeta::new(market, false),
+            AccountMeta::new(order_book, false),
+            AccountMeta::new_readonly(*base_mint, false),
+            AccountMeta::new_readonly(*quote_mint, false),
+            AccountMeta::new(get_associated_token_address(&market, base_mint), false),
+            AccountMeta::new(get_associated_token_address(&market, quote_mint), false),
+            AccountMeta::new(get_associated_token_address(bidder, base_mint), false),
+            AccountMeta::new(get_associated_token_address(bidder, quote_mint), false),
+            AccountMeta::new(get_associated_token_address(asker, quote_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Read next_order_id from the order book slab
+pub fn get_next_order_id(svm: &LiteSVM, order_book: &Pubkey) -> u64 {
+    let account = svm.get_account(order_book).expect("Order book should exist");
+    u64::from_le_bytes(account.data[40..48].try_into().unwrap())
+}
+
+// Find an active order in the slab and return (owner, price, quantity)
+pub fn get_order(svm: &LiteSVM, order_book: &Pubkey, order_id: u64) -> Option<(Pubkey, u64, u64)> {
+    let account = svm.get_account(order_book).expect("Order book should exist");
+
+    (0..MAX_ORDERS).find_map(|slot| {
+        let order = &account.data[ORDERS_OFFSET + slot * ORDER_SIZE..ORDERS_OFFSET + (slot + 1) * ORDER_SIZE];
+        let id = u64::from_le_bytes(order[0..8].try_into().unwrap());
+        let is_active = order[57] == 1;
+
+        (is_active && id == order_id).then(|| {
+            let owner = Pubkey::new_from_array(order[8..40].try_into().unwrap());
+            let price = u64::from_le_bytes(order[40..48].try_into().unwrap());
+            let quantity = u64::from_le_bytes(order[48..56].try_into().unwrap());
+            (owner, price, quantity)
+        })
+    })
+}
+
+// Read last_trade_price from a Market
+// Layout: discriminator (8) + 5 pubkeys (160) + last_trade_price (8)
+pub fn get_last_trade_price(svm: &LiteSVM, market: &Pubkey) -> u64 {
+    let account = svm.get_account(market).expect("Market should exist");
+    let offset = 8 + 32 * 5;
+    u64::from_le_bytes(account.data[offset..offset + 8].try_into().unwrap())
+}
+
+pub fn token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    let account: TokenAccount = get_spl_account(svm, token_account).expect("Token account should exist");
+    account.amount
+}
+
+// Order book scenario setup result
+pub struct ClobScenario {
+    pub svm: LiteSVM,
+    pub mint_authority: Keypair,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub market: Pubkey,
+    pub order_book: Pubkey,
+}
+
+// Create the base and quote mints and initialize their market
+pub fn setup_clob_scenario() -> ClobScenario {
+    let mut svm = setup_svm();
+    let mint_authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let base_mint = CreateMint::new(&mut svm, &mint_authority)
+        .authority(&mint_authority.pubkey())
+        .decimals(BASE_DECIMALS)
+        .send()
+        .expect("Failed to create base mint");
+    let quote_mint = CreateMint::new(&mut svm, &mint_authority)
+        .authority(&mint_authority.pubkey())
+        .decimals(QUOTE_DECIMALS)
+        .send()
+        .expect("Failed to create quote mint");
+
+    let ix = build_initialize_market_ix(&mint_authority.pubkey(), &base_mint, &quote_mint);
+    send_ix(&mut svm, ix, &mint_authority).expect("Market initialization should succeed");
+
+    let (market, _) = derive_market_pda(&base_mint, &quote_mint);
+    let (order_book, _) = derive_order_book_pda(&market);
+
+    ClobScenario {
+        svm,
+        mint_authority,
+        base_mint,
+        quote_mint,
+        market,
+        order_book,
+    }
+}
+
+// Create a trader holding TRADER_BASE_BALANCE base and TRADER_QUOTE_BALANCE quote
+pub fn create_trader(scenario: &mut ClobScenario) -> Keypair {
+    let trader = create_funded_account(&mut scenario.svm, 5 * LAMPORTS_PER_SOL);
+
+    for (mint, amount) in [
+        (scenario.base_mint, TRADER_BASE_BALANCE),
+        (scenario.quote_mint, TRADER_QUOTE_BALANCE),
+    ] {
+        let ata = CreateAssociatedTokenAccount::new(&mut scenario.svm, &trader, &mint)
+            .owner(&trader.pubkey())
+            .send()
+            .expect("Failed to create trader ATA");
+        MintTo::new(&mut scenario.svm, &scenario.mint_authority, &mint, &ata, amount)
+            .owner(&scenario.mint_authority)
+            .send()
+            .expect("Failed to mint to trader");
+    }
+
+    trader
+}
+
+// Place an order and return its id
+pub fn place_order(scenario: &mut ClobScenario, trader: &Keypair, side: u8, price: u64, quantity: u64) -> u64 {
+    let order_id = get_next_order_id(&scenario.svm, &scenario.order_book);
+    let ix = build_place_order_ix(&trader.pubkey(), &scenario.base_mint, &scenario.quote_mint, side, price, quantity);
+    send_ix(&mut scenario.svm, ix, trader).expect("Placing order should succeed");
+    order_id
+}
+
+pub fn base_balance(scenario: &ClobScenario, owner: &Pubkey) -> u64 {
+    token_balance(&scenario.svm, &get_associated_token_address(owner, &scenario.base_mint))
+}
+
+pub fn quote_balance(scenario: &ClobScenario, owner: &Pubkey) -> u64 {
+    token_balance(&scenario.svm, &get_associated_token_address(owner, &scenario.quote_mint))
+}
@@ -0,0 +1,121 @@
+// Cancel Order Instruction
+//
+// Removes a resting order and returns its locked funds.
+//
+// HOW IT WORKS:
+// 1. Finds the order in the slab and checks the signer owns it
+// 2. Returns the locked funds from the market vaults:
+//    - Bid: price * remaining quantity quote
+//    - Ask: remaining quantity base
+// 3. Frees the order's slot
+//
+// SECURITY:
+// - Only the order owner can cancel (order.owner == signer)
+// - Refund destinations must belong to the owner
+// - Refund is the order's own locked amount, never more
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct CancelOrder<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [MARKET_SEED, base_mint.key().as_ref(), quote_mint.key().as_ref()],
+        bump = market.bump,
+        has_one = base_mint,
+        has_one = quote_mint,
+        has_one = base_vault,
+        has_one = quote_vault,
+        has_one = order_book,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(mut)]
+    pub order_book: AccountLoader<'info, OrderBook>,
+
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CancelOrder<'info> {
+    pub fn cancel_order(&mut self, order_id: u64) -> Result<()> {
+        // Remove the order from the slab before paying out
+        let order = {
+            let mut order_book = self.order_book.load_mut()?;
+            let index = order_book.index_of(order_id)?;
+            let order = order_book.orders[index];
+
+            require_keys_eq!(order.owner, self.owner.key(), ClobError::Unauthorized);
+
+            order_book.remove(index);
+            order
+        };
+
+        let refund = order.locked_amount()?;
+
+        let base_mint_key = self.base_mint.key();
+        let quote_mint_key = self.quote_mint.key();
+        let market_seeds = &[
+            MARKET_SEED,
+            base_mint_key.as_ref(),
+            quote_mint_key.as_ref(),
+            &[self.market.bump],
+        ];
+
+        match order.side() {
+            Side::Bid => transfer_from_vault(
+                refund,
+                self.quote_mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.quote_vault.to_account_info(),
+                &self.quote_mint.to_account_info(),
+                &self.owner_quote_account.to_account_info(),
+                &self.market.to_account_info(),
+                market_seeds,
+            )?,
+            Side::Ask => transfer_from_vault(
+                refund,
+                self.base_mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.base_vault.to_account_info(),
+                &self.base_mint.to_account_info(),
+                &self.owner_base_account.to_account_info(),
+                &self.market.to_account_info(),
+                market_seeds,
+            )?,
+        }
+
+        msg!("Order {} cancelled, {} refunded", order_id, refund);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+// Instructions Module
+//
+// Exports all instruction handlers for the order book program
+
+pub mod initialize_market;
+pub mod place_order;
+pub mod cancel_order;
+pub mod amend_order;
+pub mod match_orders;
+
+pub use initialize_market::*;
+pub use place_order::*;
+pub use cancel_order::*;
+pub use amend_order::*;
+pub use match_orders::*;
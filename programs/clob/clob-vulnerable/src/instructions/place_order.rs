@@ -0,0 +1,122 @@
+// Place Order Instruction
+//
+// Rests a limit order on the book.
+//
+// HOW IT WORKS:
+// 1. Validates the price and quantity
+// 2. Locks the order's funds in the market vaults:
+//    - Bid: price * quantity quote into quote_vault
+//    - Ask: quantity base into base_vault
+// 3. Writes the order into the first free slot of the order book slab
+//
+// Orders never match on placement; a crank pairs crossing orders later
+// with match_orders.
+//
+// SECURITY:
+// - price > 0 and quantity > 0
+// - Locked amount computed with checked multiplication
+// - Order owner is the signer, so every order is backed by the owner's own funds
+// - Source token accounts must belong to the owner
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct PlaceOrder<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [MARKET_SEED, base_mint.key().as_ref(), quote_mint.key().as_ref()],
+        bump = market.bump,
+        has_one = base_mint,
+        has_one = quote_mint,
+        has_one = base_vault,
+        has_one = quote_vault,
+        has_one = order_book,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(mut)]
+    pub order_book: AccountLoader<'info, OrderBook>,
+
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> PlaceOrder<'info> {
+    pub fn place_order(&mut self, side: Side, price: u64, quantity: u64) -> Result<()> {
+        require!(price > 0, ClobError::ZeroPrice);
+        require!(quantity > 0, ClobError::ZeroAmount);
+
+        // Lock the order's funds
+        match side {
+            Side::Bid => {
+                let locked = price.checked_mul(quantity).ok_or(ClobError::Overflow)?;
+
+                transfer_tokens(
+                    locked,
+                    self.quote_mint.decimals,
+                    &self.token_program.to_account_info(),
+                    &self.owner_quote_account.to_account_info(),
+                    &self.quote_mint.to_account_info(),
+                    &self.quote_vault.to_account_info(),
+                    &self.owner.to_account_info(),
+                )?;
+            }
+            Side::Ask => {
+                transfer_tokens(
+                    quantity,
+                    self.base_mint.decimals,
+                    &self.token_program.to_account_info(),
+                    &self.owner_base_account.to_account_info(),
+                    &self.base_mint.to_account_info(),
+                    &self.base_vault.to_account_info(),
+                    &self.owner.to_account_info(),
+                )?;
+            }
+        }
+
+        let order_id = self
+            .order_book
+            .load_mut()?
+            .insert(self.owner.key(), side, price, quantity)?;
+
+        msg!(
+            "Order {} placed: {:?} {} @ {} by {}",
+            order_id,
+            side,
+            quantity,
+            price,
+            self.owner.key()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+// Order Book Program - VULNERABLE VERSION
+//
+// WARNING: This program contains intentional security vulnerabilities for educational purposes.
+// NEVER deploy this to production. Use clob-secure instead.
+//
+// This vulnerable order book demonstrates two classic CLOB flaws:
+// - Matching an owner's bid against their own ask (wash trading)
+// - Re-pricing orders without checking who owns them
+//
+// VULNERABILITIES DOCUMENTED:
+// See individual instruction files for detailed vulnerability explanations.
+// Each vulnerability is marked with VULNERABILITY comments.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+use state::Side;
+
+declare_id!("CcRCyCy7PXYneXPSxdbSvVaSLPHZhEM2menvH2ZysNS2");
+
+#[program]
+pub mod clob_vulnerable {
+    use super::*;
+
+    // Create the market, its order book slab, and its vaults
+    pub fn initialize_market(ctx: Context<InitializeMarket>) -> Result<()> {
+        ctx.accounts.initialize_market(&ctx.bumps)
+    }
+
+    // Rest a limit order on the book
+    pub fn place_order(ctx: Context<PlaceOrder>, side: Side, price: u64, quantity: u64) -> Result<()> {
+        ctx.accounts.place_order(side, price, quantity)
+    }
+
+    // Remove a resting order and return its locked funds
+    pub fn cancel_order(ctx: Context<CancelOrder>, order_id: u64) -> Result<()> {
+        ctx.accounts.cancel_order(order_id)
+    }
+
+    // Move a resting order to a new price
+    // VULNERABILITY: No owner check - anyone can re-price anyone's order
+    pub fn amend_order(ctx: Context<AmendOrder>, order_id: u64, new_price: u64) -> Result<()> {
+        ctx.accounts.amend_order(order_id, new_price)
+    }
+
+    // Fill a crossing bid and ask (anyone can call)
+    // VULNERABILITY: No self-trade prevention
+    pub fn match_orders(ctx: Context<MatchOrders>, bid_id: u64, ask_id: u64) -> Result<()> {
+        ctx.accounts.match_orders(bid_id, ask_id)
+    }
+}
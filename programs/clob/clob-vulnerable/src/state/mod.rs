@@ -0,0 +1,9 @@
+// State Module
+//
+// Exports all state structures used by the order book program
+
+pub mod market;
+pub mod order_book;
+
+pub use market::*;
+pub use order_book::*;
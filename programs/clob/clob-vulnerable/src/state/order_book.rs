@@ -0,0 +1,128 @@
+// Order Book State
+//
+// Fixed-size slab of order slots shared by bids and asks. The slab is a
+// zero-copy account: instructions read and write orders in place through
+// an AccountLoader instead of deserializing all MAX_ORDERS slots on every call.
+//
+// A slot is free when is_active == 0. Placing an order takes the first free
+// slot; cancelling or completely filling an order frees it again.
+//
+// Prices are quote atoms per base atom, so a bid locks price * quantity
+// quote and an ask locks quantity base.
+
+
+use anchor_lang::prelude::*;
+use bytemuck::Zeroable;
+use crate::{constants::*, errors::*};
+
+// Side of the book an order rests on
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Side {
+    Bid,
+    Ask,
+}
+
+#[account(zero_copy)]
+pub struct OrderBook {
+    // Market this slab belongs to
+    pub market: Pubkey,
+
+    // Id given to the next order placed (ids are never reused)
+    pub next_order_id: u64,
+
+    pub orders: [Order; MAX_ORDERS],
+}
+
+#[zero_copy]
+pub struct Order {
+    pub order_id: u64,
+
+    // Account that placed the order and receives its fills and refunds
+    pub owner: Pubkey,
+
+    // Quote atoms per base atom
+    pub price: u64,
+
+    // Base atoms still unfilled
+    pub quantity: u64,
+
+    // 0 = bid, 1 = ask
+    pub side: u8,
+
+    // 1 while the order is resting on the book
+    pub is_active: u8,
+
+    pub _padding: [u8; 6],
+}
+
+impl Order {
+    pub fn side(&self) -> Side {
+        if self.side == 0 {
+            Side::Bid
+        } else {
+            Side::Ask
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.is_active == 1
+    }
+
+    // Funds the order has locked in the market vaults
+    // Bid: price * quantity quote. Ask: quantity base
+    pub fn locked_amount(&self) -> Result<u64> {
+        match self.side() {
+            Side::Bid => Ok(self
+                .price
+                .checked_mul(self.quantity)
+                .ok_or(ClobError::Overflow)?),
+            Side::Ask => Ok(self.quantity),
+        }
+    }
+}
+
+impl OrderBook {
+    // Write a new order into the first free slot and return its id
+    pub fn insert(&mut self, owner: Pubkey, side: Side, price: u64, quantity: u64) -> Result<u64> {
+        let order_id = self.next_order_id;
+
+        let slot = self
+            .orders
+            .iter_mut()
+            .find(|order| !order.is_active())
+            .ok_or(ClobError::OrderBookFull)?;
+
+        *slot = Order {
+            order_id,
+            owner,
+            price,
+            quantity,
+            side: match side {
+                Side::Bid => 0,
+                Side::Ask => 1,
+            },
+            is_active: 1,
+            _padding: [0; 6],
+        };
+
+        self.next_order_id = order_id.checked_add(1).ok_or(ClobError::Overflow)?;
+
+        Ok(order_id)
+    }
+
+    // Slot index of an active order
+    pub fn index_of(&self, order_id: u64) -> Result<usize> {
+        let index = self
+            .orders
+            .iter()
+            .position(|order| order.is_active() && order.order_id == order_id)
+            .ok_or(ClobError::OrderNotFound)?;
+
+        Ok(index)
+    }
+
+    // Free a slot (cancelled or completely filled)
+    pub fn remove(&mut self, index: usize) {
+        self.orders[index] = Order::zeroed();
+    }
+}
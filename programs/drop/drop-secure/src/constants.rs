@@ -0,0 +1,32 @@
+// Seed for distributor PDA (also the vault authority)
+// Derived with: [DISTRIBUTOR_SEED, admin_pubkey, seed.to_le_bytes()]
+pub const DISTRIBUTOR_SEED: &[u8] = b"distributor";
+
+// DISTRIBUTOR LIMITS
+
+// Maximum leaves (claimants) per distributor
+// One bit per leaf is stored on the distributor, so this bounds its size
+pub const MAX_CLAIMANTS: u32 = 1024;
+
+// Size of the claimed bitmap in bytes (one bit per claimant)
+pub const CLAIMED_BITMAP_BYTES: usize = (MAX_CLAIMANTS / 8) as usize;
+
+// Maximum proof length: depth of a tree with MAX_CLAIMANTS leaves
+pub const MAX_PROOF_LEN: usize = 10;
+
+// Minimum claim window before the admin can claw back (1 hour)
+pub const MIN_CLAIM_WINDOW_SECONDS: i64 = 60 * 60;
+
+// Maximum claim window (365 days)
+// Keeps unclaimed tokens from being locked forever
+pub const MAX_CLAIM_WINDOW_SECONDS: i64 = 365 * 24 * 60 * 60;
+
+// MERKLE TREE
+
+// Domain separation prefixes
+// Leaves and inner nodes hash with different prefixes so an inner node can
+// never be passed off as a leaf (second preimage attack)
+pub const LEAF_PREFIX: &[u8] = &[0];
+pub const NODE_PREFIX: &[u8] = &[1];
+
+pub const ANCHOR_DISCRIMINATOR: usize = 8;
@@ -0,0 +1,117 @@
+// Claim Instruction
+//
+// A claimant proves their allocation against the merkle root and is paid.
+//
+// HOW IT WORKS:
+// 1. Rebuilds the leaf from (index, claimant, amount)
+// 2. Folds the proof into the leaf and compares with the stored root
+// 3. Marks the index as claimed in the bitmap
+// 4. Transfers amount from the vault to the claimant
+//
+// SECURITY:
+// - Claims only before clawback_ts
+// - index < num_nodes and not yet claimed (bitmap)
+// - Leaf binds the claimant's signing key, so a proof cannot be reused by
+//   anyone else
+// - Proof length bounded by MAX_PROOF_LEN
+// - total_claimed never exceeds max_total_claim
+//
+// Why a bitmap?
+// A valid proof stays valid forever. Without a record of paid indices the
+// same proof could be submitted again and again until the vault is empty.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    pub claimant: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [DISTRIBUTOR_SEED, distributor.admin.as_ref(), distributor.seed.to_le_bytes().as_ref()],
+        bump = distributor.bump,
+        has_one = mint,
+        has_one = vault,
+    )]
+    pub distributor: Box<Account<'info, Distributor>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = claimant,
+        token::token_program = token_program,
+    )]
+    pub claimant_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> Claim<'info> {
+    pub fn claim(&mut self, index: u32, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+        require!(amount > 0, DropError::ZeroAmount);
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(now < self.distributor.clawback_ts, DropError::ClaimWindowClosed);
+
+        require!(index < self.distributor.num_nodes, DropError::InvalidIndex);
+        #[cfg(not(feature = "vuln-missing-claimed-check"))]
+        require!(!self.distributor.is_claimed(index), DropError::AlreadyClaimed);
+
+        require!(proof.len() <= MAX_PROOF_LEN, DropError::ProofTooLong);
+        let leaf = hash_leaf(index, &self.claimant.key(), amount);
+        require!(
+            verify_proof(&proof, &self.distributor.merkle_root, leaf),
+            DropError::InvalidProof
+        );
+
+        let total_claimed = self
+            .distributor
+            .total_claimed
+            .checked_add(amount)
+            .ok_or(DropError::Overflow)?;
+        require!(
+            total_claimed <= self.distributor.max_total_claim,
+            DropError::ExceedsMaxClaim
+        );
+
+        // Mark before transferring (checks-effects-interactions)
+        self.distributor.set_claimed(index);
+        self.distributor.total_claimed = total_claimed;
+        self.distributor.num_claimed = self
+            .distributor
+            .num_claimed
+            .checked_add(1)
+            .ok_or(DropError::Overflow)?;
+
+        let seed_bytes = self.distributor.seed.to_le_bytes();
+        let distributor_seeds = &[
+            DISTRIBUTOR_SEED,
+            self.distributor.admin.as_ref(),
+            seed_bytes.as_ref(),
+            &[self.distributor.bump],
+        ];
+
+        transfer_from_vault(
+            amount,
+            self.mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.vault.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.claimant_token_account.to_account_info(),
+            &self.distributor.to_account_info(),
+            distributor_seeds,
+        )?;
+
+        msg!("Index {} claimed {} tokens", index, amount);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+// Create Distributor Instruction
+//
+// Opens an airdrop for every allocation in a merkle tree and funds it.
+//
+// HOW IT WORKS:
+// 1. Validates the root, total, leaf count and claim window
+// 2. Creates the distributor PDA and its vault ATA
+// 3. Transfers max_total_claim from the admin into the vault
+//
+// SECURITY:
+// - merkle_root must not be empty, max_total_claim > 0
+// - 1 <= num_nodes <= MAX_CLAIMANTS (bounded by the claimed bitmap)
+// - MIN_CLAIM_WINDOW_SECONDS <= claim window <= MAX_CLAIM_WINDOW_SECONDS
+// - Vault funded up front, so every valid claim can be paid
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct CreateDistributor<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = admin,
+        token::token_program = token_program,
+    )]
+    pub admin_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR + Distributor::INIT_SPACE,
+        seeds = [DISTRIBUTOR_SEED, admin.key().as_ref(), seed.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub distributor: Box<Account<'info, Distributor>>,
+
+    #[account(
+        init,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = distributor,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateDistributor<'info> {
+    pub fn create_distributor(
+        &mut self,
+        seed: u64,
+        merkle_root: [u8; 32],
+        max_total_claim: u64,
+        num_nodes: u32,
+        claim_window_seconds: i64,
+        bumps: &CreateDistributorBumps,
+    ) -> Result<()> {
+        require!(merkle_root != [0u8; 32], DropError::InvalidMerkleRoot);
+
+        require!(max_total_claim > 0, DropError::ZeroAmount);
+
+        require!(
+            (1..=MAX_CLAIMANTS).contains(&num_nodes),
+            DropError::InvalidNodeCount
+        );
+
+        require!(
+            (MIN_CLAIM_WINDOW_SECONDS..=MAX_CLAIM_WINDOW_SECONDS).contains(&claim_window_seconds),
+            DropError::InvalidClaimWindow
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let clawback_ts = now.checked_add(claim_window_seconds).ok_or(DropError::Overflow)?;
+
+        self.distributor.set_inner(Distributor {
+            admin: self.admin.key(),
+            mint: self.mint.key(),
+            vault: self.vault.key(),
+            seed,
+            merkle_root,
+            max_total_claim,
+            num_nodes,
+            total_claimed: 0,
+            num_claimed: 0,
+            clawback_ts,
+            bump: bumps.distributor,
+            claimed_bitmap: [0u8; CLAIMED_BITMAP_BYTES],
+        });
+
+        transfer_tokens(
+            max_total_claim,
+            self.mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.admin_token_account.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.vault.to_account_info(),
+            &self.admin.to_account_info(),
+        )?;
+
+        msg!(
+            "Distributor created: {} tokens for {} claimants until {}",
+            max_total_claim,
+            num_nodes,
+            clawback_ts
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+// Merkle Distributor Program - SECURE VERSION
+//
+// Implementation of a token airdrop backed by a merkle tree of allocations.
+// This program allows users to:
+// 1. Create a distributor with a merkle root and fund it with every allocation
+// 2. Claim an allocation by proving it against the root
+// 3. Claw back unclaimed tokens after the claim window closes
+//
+// SECURITY FEATURES:
+// - Claimed indices recorded in a bitmap; each leaf pays out once
+// - Leaf binds index, claimant and amount; the claimant must sign
+// - Domain-separated leaf and node hashes (no second preimage)
+// - Claims capped by max_total_claim
+// - Clawback only by the admin, only after the claim window
+// - Checked arithmetic to prevent overflow/underflow
+//
+// MERKLE TREE:
+// leaf = hash(0x00 || index || claimant || amount)
+// node = hash(0x01 || min(left, right) || max(left, right))
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("6p67vpSTT9qBSaCbdHcPffNpnH6FetXEen4Koa74XLag");
+
+#[program]
+pub mod drop_secure {
+    use super::*;
+
+    // Create and fund an airdrop for a merkle tree of allocations
+    pub fn create_distributor(
+        ctx: Context<CreateDistributor>,
+        seed: u64,
+        merkle_root: [u8; 32],
+        max_total_claim: u64,
+        num_nodes: u32,
+        claim_window_seconds: i64,
+    ) -> Result<()> {
+        ctx.accounts.create_distributor(
+            seed,
+            merkle_root,
+            max_total_claim,
+            num_nodes,
+            claim_window_seconds,
+            &ctx.bumps,
+        )
+    }
+
+    // Prove an allocation and receive it
+    pub fn claim(ctx: Context<Claim>, index: u32, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+        ctx.accounts.claim(index, amount, proof)
+    }
+
+    // Admin-only: recover unclaimed tokens after the claim window
+    pub fn clawback(ctx: Context<Clawback>) -> Result<()> {
+        ctx.accounts.clawback()
+    }
+}
@@ -0,0 +1,54 @@
+// Compute-unit regression tests for drop-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+mod utils;
+
+use solana_sdk::signature::Signer;
+use soteria_test_kit::{ComputeBudget, DEFAULT_CU_BASELINES_FILE};
+use spl_associated_token_account::get_associated_token_address;
+use utils::*;
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let (mut svm, admin, mint, claimants) = setup_tokens();
+    let tree = build_tree(&claimants);
+
+    let ix = build_create_distributor_ix(
+        &admin.pubkey(),
+        &mint,
+        DISTRIBUTOR_ID,
+        tree.root(),
+        MAX_TOTAL_CLAIM,
+        ALLOCATIONS.len() as u32,
+        CLAIM_WINDOW,
+    );
+    budget.record("create_distributor", send_ix(&mut svm, ix, &admin));
+    let (distributor, _) = derive_distributor_pda(&admin.pubkey(), DISTRIBUTOR_ID);
+
+    let mut scenario = DropScenario {
+        svm,
+        admin,
+        mint,
+        claimants,
+        tree,
+        distributor,
+        vault: get_associated_token_address(&distributor, &mint),
+    };
+
+    // Proof length grows with the tree, so the claim cost tracks the allocation count
+    let ix = scenario.claim_ix(0);
+    budget.record("claim", send_ix(&mut scenario.svm, ix, &scenario.claimants[0]));
+
+    advance_time(&mut scenario.svm, CLAIM_WINDOW);
+    let ix = build_clawback_ix(&scenario.admin.pubkey(), &distributor, &mint);
+    budget.record("clawback", send_ix(&mut scenario.svm, ix, &scenario.admin));
+
+    budget.check();
+}
@@ -0,0 +1,152 @@
+// Integration tests for merkle distributor program using LiteSVM
+// These tests verify core airdrop functionality: distributor creation,
+// proof-verified one-time claims, and admin clawback after the claim window
+
+mod utils;
+
+use utils::*;
+use solana_sdk::signature::Signer;
+
+#[test]
+fn test_create_distributor() {
+    // Test: Distributor is funded with every allocation; bad parameters rejected
+    println!("\n[TEST START] test_create_distributor - Distributor creation");
+
+    let (mut svm, admin, mint, claimants) = setup_tokens();
+    let tree = build_tree(&claimants);
+    let num_nodes = ALLOCATIONS.len() as u32;
+
+    let bad_params: [(&str, [u8; 32], u64, u32, i64); 5] = [
+        ("empty root", [0u8; 32], MAX_TOTAL_CLAIM, num_nodes, CLAIM_WINDOW),
+        ("zero total", tree.root(), 0, num_nodes, CLAIM_WINDOW),
+        ("zero claimants", tree.root(), MAX_TOTAL_CLAIM, 0, CLAIM_WINDOW),
+        ("too many claimants", tree.root(), MAX_TOTAL_CLAIM, MAX_CLAIMANTS + 1, CLAIM_WINDOW),
+        ("one-minute claim window", tree.root(), MAX_TOTAL_CLAIM, num_nodes, 60),
+    ];
+
+    for (name, root, total, nodes, window) in bad_params {
+        println!("[Action] Creating distributor with {}", name);
+        let ix = build_create_distributor_ix(&admin.pubkey(), &mint, DISTRIBUTOR_ID, root, total, nodes, window);
+        assert!(send_ix(&mut svm, ix, &admin).is_err(), "Distributor with {} should fail", name);
+        println!("[Success] Distributor with {} rejected", name);
+    }
+
+    println!("[Action] Creating distributor for 3 claimants, 600 tokens");
+    let ix = build_create_distributor_ix(
+        &admin.pubkey(),
+        &mint,
+        DISTRIBUTOR_ID,
+        tree.root(),
+        MAX_TOTAL_CLAIM,
+        num_nodes,
+        CLAIM_WINDOW,
+    );
+    let result = send_ix(&mut svm, ix, &admin);
+    assert!(result.is_ok(), "Distributor creation failed: {:?}", result.err());
+
+    let (distributor, _) = derive_distributor_pda(&admin.pubkey(), DISTRIBUTOR_ID);
+    let vault = spl_associated_token_account::get_associated_token_address(&distributor, &mint);
+    assert_eq!(token_balance(&svm, &vault), MAX_TOTAL_CLAIM);
+    assert_eq!(get_claimed(&svm, &distributor), (0, 0));
+    println!("[Success] Vault holds all 600 tokens, nothing claimed");
+
+    println!("[TEST END] test_create_distributor");
+}
+
+#[test]
+fn test_claim() {
+    // Test: Each claimant can claim their own allocation exactly once
+    println!("\n[TEST START] test_claim - Proof-verified, one-time claims");
+
+    let mut scenario = setup_drop_scenario();
+    let claimant = scenario.claimants[0].pubkey();
+    println!("[Setup] Distributor funded for 3 claimants");
+
+    println!("[Action] Claimant 0 claims with an inflated amount");
+    let ix = build_claim_ix(
+        &claimant,
+        &scenario.distributor,
+        &scenario.mint,
+        0,
+        ALLOCATIONS[0] * 2,
+        &scenario.tree.proof(0),
+    );
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.claimants[0]).is_err(), "Inflated claim should fail");
+    println!("[Success] Proof for a different amount rejected");
+
+    println!("[Action] Claimant 0 submits claimant 1's leaf and proof");
+    let ix = build_claim_ix(
+        &claimant,
+        &scenario.distributor,
+        &scenario.mint,
+        1,
+        ALLOCATIONS[1],
+        &scenario.tree.proof(1),
+    );
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.claimants[0]).is_err(), "Claiming another leaf should fail");
+    println!("[Success] Leaf is bound to the claimant's key");
+
+    println!("[Action] Claimant 0 claims their 100 tokens");
+    let ix = scenario.claim_ix(0);
+    let result = send_ix(&mut scenario.svm, ix, &scenario.claimants[0]);
+    assert!(result.is_ok(), "Claim failed: {:?}", result.err());
+    assert_eq!(scenario.balance_of(&claimant), ALLOCATIONS[0]);
+    assert_eq!(get_claimed(&scenario.svm, &scenario.distributor), (ALLOCATIONS[0], 1));
+    println!("[Success] Claimant 0 received 100 tokens");
+
+    println!("[Action] Claimant 0 claims again with the same proof");
+    let ix = scenario.claim_ix(0);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.claimants[0]).is_err(), "Second claim should fail");
+    assert_eq!(scenario.balance_of(&claimant), ALLOCATIONS[0]);
+    println!("[Success] Second claim rejected by the claimed bitmap");
+
+    println!("[Action] Claimant 2 (odd leaf, shorter proof) claims 300 tokens");
+    let ix = scenario.claim_ix(2);
+    let result = send_ix(&mut scenario.svm, ix, &scenario.claimants[2]);
+    assert!(result.is_ok(), "Claim failed: {:?}", result.err());
+    assert_eq!(scenario.balance_of(&scenario.claimants[2].pubkey()), ALLOCATIONS[2]);
+    assert_eq!(token_balance(&scenario.svm, &scenario.vault), ALLOCATIONS[1]);
+    println!("[Success] Vault holds exactly claimant 1's unclaimed 200 tokens");
+
+    println!("[TEST END] test_claim");
+}
+
+#[test]
+fn test_clawback() {
+    // Test: Only the admin, only after the window, and claims stop at the same time
+    println!("\n[TEST START] test_clawback - Recovering unclaimed tokens");
+
+    let mut scenario = setup_drop_scenario();
+    let admin = scenario.admin.pubkey();
+
+    let ix = scenario.claim_ix(0);
+    send_ix(&mut scenario.svm, ix, &scenario.claimants[0]).expect("Claim should succeed");
+    println!("[Setup] Claimant 0 claimed 100 of 600 tokens");
+
+    println!("[Action] Admin tries to claw back during the claim window");
+    let ix = build_clawback_ix(&admin, &scenario.distributor, &scenario.mint);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.admin).is_err(), "Early clawback should fail");
+    println!("[Success] Clawback before clawback_ts rejected");
+
+    advance_time(&mut scenario.svm, CLAIM_WINDOW);
+    println!("[Setup] Claim window closed");
+
+    let ix = scenario.claim_ix(1);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.claimants[1]).is_err(), "Late claim should fail");
+    println!("[Success] Claim after the window rejected");
+
+    println!("[Action] Claimant 0 tries to claw back");
+    let ix = build_clawback_ix(&scenario.claimants[0].pubkey(), &scenario.distributor, &scenario.mint);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.claimants[0]).is_err(), "Non-admin clawback should fail");
+    println!("[Success] Clawback by non-admin rejected");
+
+    println!("[Action] Admin claws back");
+    let ix = build_clawback_ix(&admin, &scenario.distributor, &scenario.mint);
+    let result = send_ix(&mut scenario.svm, ix, &scenario.admin);
+    assert!(result.is_ok(), "Clawback failed: {:?}", result.err());
+    assert_eq!(scenario.balance_of(&admin), MAX_TOTAL_CLAIM - ALLOCATIONS[0]);
+    assert_eq!(token_balance(&scenario.svm, &scenario.vault), 0);
+    println!("[Success] Admin recovered the 500 unclaimed tokens");
+
+    println!("[TEST END] test_clawback");
+}
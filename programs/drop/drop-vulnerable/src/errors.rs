@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+pub use soteria_errors::drop_vulnerable::DropError as DropErrorCode;
+
+#[error_code]
+pub enum DropError {
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Number of claimants must be between 1 and 1024")]
+    InvalidNodeCount,
+
+    #[msg("Merkle root must not be empty")]
+    InvalidMerkleRoot,
+
+    #[msg("Claim window is outside the allowed range (1 hour to 365 days)")]
+    InvalidClaimWindow,
+
+    #[msg("Claim window has closed")]
+    ClaimWindowClosed,
+
+    #[msg("Claim window is still open")]
+    ClaimWindowOpen,
+
+    #[msg("Claim index is out of range")]
+    InvalidIndex,
+
+    #[msg("Merkle proof is too long")]
+    ProofTooLong,
+
+    #[msg("Merkle proof does not match the root")]
+    InvalidProof,
+
+    #[msg("Claim would exceed the distributor's total")]
+    ExceedsMaxClaim,
+
+    #[msg("Only the distributor admin can perform this action")]
+    Unauthorized,
+
+    #[msg("Arithmetic overflow")]
+    Overflow,
+}
@@ -0,0 +1,116 @@
+// Claim Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// A claimant proves their allocation against the merkle root and is paid.
+//
+// VULNERABILITY: V001: Claims are never recorded - the claimed bitmap is
+// neither checked nor set, so one valid proof pays out again and again
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    pub claimant: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [DISTRIBUTOR_SEED, distributor.admin.as_ref(), distributor.seed.to_le_bytes().as_ref()],
+        bump = distributor.bump,
+        has_one = mint,
+        has_one = vault,
+    )]
+    pub distributor: Box<Account<'info, Distributor>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = claimant,
+        token::token_program = token_program,
+    )]
+    pub claimant_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> Claim<'info> {
+    pub fn claim(&mut self, index: u32, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+        require!(amount > 0, DropError::ZeroAmount);
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(now < self.distributor.clawback_ts, DropError::ClaimWindowClosed);
+
+        require!(index < self.distributor.num_nodes, DropError::InvalidIndex);
+
+        // VULNERABILITY V001: No check that this index was already paid
+        // Secure version:
+        //   require!(!self.distributor.is_claimed(index), DropError::AlreadyClaimed);
+        // A merkle proof stays valid forever; it proves the allocation
+        // exists, not that it is still owed.
+        // Attack scenario (double claim):
+        // 1. Three claimants are allocated 100, 200 and 300 tokens
+        // 2. Claimant 0 claims their 100 tokens with a valid proof
+        // 3. Claimant 0 submits the same proof five more times
+        // 4. The vault (600 tokens) is empty; claimants 1 and 2 get nothing
+
+        require!(proof.len() <= MAX_PROOF_LEN, DropError::ProofTooLong);
+        let leaf = hash_leaf(index, &self.claimant.key(), amount);
+        require!(
+            verify_proof(&proof, &self.distributor.merkle_root, leaf),
+            DropError::InvalidProof
+        );
+
+        let total_claimed = self
+            .distributor
+            .total_claimed
+            .checked_add(amount)
+            .ok_or(DropError::Overflow)?;
+        require!(
+            total_claimed <= self.distributor.max_total_claim,
+            DropError::ExceedsMaxClaim
+        );
+
+        // VULNERABILITY V001: Index is never marked as claimed
+        // Secure version:
+        //   self.distributor.set_claimed(index);
+        // total_claimed still caps the sum, but only at the vault size - it
+        // cannot tell whose allocation is being paid out
+        self.distributor.total_claimed = total_claimed;
+        self.distributor.num_claimed = self
+            .distributor
+            .num_claimed
+            .checked_add(1)
+            .ok_or(DropError::Overflow)?;
+
+        let seed_bytes = self.distributor.seed.to_le_bytes();
+        let distributor_seeds = &[
+            DISTRIBUTOR_SEED,
+            self.distributor.admin.as_ref(),
+            seed_bytes.as_ref(),
+            &[self.distributor.bump],
+        ];
+
+        transfer_from_vault(
+            amount,
+            self.mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.vault.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.claimant_token_account.to_account_info(),
+            &self.distributor.to_account_info(),
+            distributor_seeds,
+        )?;
+
+        msg!("Index {} claimed {} tokens", index, amount);
+
+        Ok(())
+    }
+}
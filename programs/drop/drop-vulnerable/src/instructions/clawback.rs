@@ -0,0 +1,79 @@
+// Clawback Instruction
+//
+// Returns unclaimed tokens to the admin once the claim window has closed.
+//
+// HOW IT WORKS:
+// 1. Checks the claim window has closed
+// 2. Transfers the whole vault balance to the admin
+//
+// SECURITY:
+// - Only the distributor admin (has_one = admin)
+// - Only at or after clawback_ts, so claimants get the full window
+// - Claims are rejected from clawback_ts on, so nothing is paid twice
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct Clawback<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [DISTRIBUTOR_SEED, distributor.admin.as_ref(), distributor.seed.to_le_bytes().as_ref()],
+        bump = distributor.bump,
+        has_one = admin @ DropError::Unauthorized,
+        has_one = mint,
+        has_one = vault,
+    )]
+    pub distributor: Box<Account<'info, Distributor>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = admin,
+        token::token_program = token_program,
+    )]
+    pub admin_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> Clawback<'info> {
+    pub fn clawback(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= self.distributor.clawback_ts, DropError::ClaimWindowOpen);
+
+        let remaining = self.vault.amount;
+        require!(remaining > 0, DropError::ZeroAmount);
+
+        let seed_bytes = self.distributor.seed.to_le_bytes();
+        let distributor_seeds = &[
+            DISTRIBUTOR_SEED,
+            self.distributor.admin.as_ref(),
+            seed_bytes.as_ref(),
+            &[self.distributor.bump],
+        ];
+
+        transfer_from_vault(
+            remaining,
+            self.mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.vault.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.admin_token_account.to_account_info(),
+            &self.distributor.to_account_info(),
+            distributor_seeds,
+        )?;
+
+        msg!("Clawed back {} unclaimed tokens", remaining);
+
+        Ok(())
+    }
+}
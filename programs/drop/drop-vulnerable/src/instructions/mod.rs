@@ -0,0 +1,11 @@
+// Instructions Module
+//
+// Exports all instruction handlers for the merkle distributor program
+
+pub mod create_distributor;
+pub mod claim;
+pub mod clawback;
+
+pub use create_distributor::*;
+pub use claim::*;
+pub use clawback::*;
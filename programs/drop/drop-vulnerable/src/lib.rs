@@ -0,0 +1,59 @@
+// Merkle Distributor Program - VULNERABLE VERSION
+//
+// WARNING: This program contains intentional security vulnerabilities for educational purposes.
+// NEVER deploy this to production. Use drop-secure instead.
+//
+// This vulnerable merkle distributor demonstrates the classic airdrop flaw:
+// - Claims are verified against the merkle root but never recorded, so the
+//   same proof can be replayed until the vault is empty
+//
+// VULNERABILITIES DOCUMENTED:
+// See individual instruction files for detailed vulnerability explanations.
+// Each vulnerability is marked with VULNERABILITY comments.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("3Ph6Gbmu2r5beDGCneiYdM5qojuSJv7kth7CQBgvx1RF");
+
+#[program]
+pub mod drop_vulnerable {
+    use super::*;
+
+    // Create and fund an airdrop for a merkle tree of allocations
+    pub fn create_distributor(
+        ctx: Context<CreateDistributor>,
+        seed: u64,
+        merkle_root: [u8; 32],
+        max_total_claim: u64,
+        num_nodes: u32,
+        claim_window_seconds: i64,
+    ) -> Result<()> {
+        ctx.accounts.create_distributor(
+            seed,
+            merkle_root,
+            max_total_claim,
+            num_nodes,
+            claim_window_seconds,
+            &ctx.bumps,
+        )
+    }
+
+    // Prove an allocation and receive it
+    // VULNERABILITY: Claimed indices are never recorded - proofs can be replayed
+    pub fn claim(ctx: Context<Claim>, index: u32, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+        ctx.accounts.claim(index, amount, proof)
+    }
+
+    // Admin-only: recover unclaimed tokens after the claim window
+    pub fn clawback(ctx: Context<Clawback>) -> Result<()> {
+        ctx.accounts.clawback()
+    }
+}
@@ -0,0 +1,50 @@
+// Distributor State
+//
+// One airdrop: a vault of tokens and the merkle root of every
+// (index, claimant, amount) allocation.
+//
+// Only the 32-byte root is stored on-chain. Each claimant submits their own
+// leaf and a proof; the program rebuilds the root from them. Which leaves have
+// been paid is meant to be tracked in a bitmap, one bit per index.
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Distributor {
+    // Account that created the distributor and can claw back leftovers
+    pub admin: Pubkey,
+
+    // Mint being distributed
+    pub mint: Pubkey,
+
+    // Vault ATA holding undistributed tokens (owned by this PDA)
+    pub vault: Pubkey,
+
+    // Admin-chosen seed so one admin can run several distributors
+    pub seed: u64,
+
+    // Root of the allocation tree
+    pub merkle_root: [u8; 32],
+
+    // Sum of every allocation in the tree (deposited at creation)
+    pub max_total_claim: u64,
+
+    // Number of leaves in the tree (valid indices are 0..num_nodes)
+    pub num_nodes: u32,
+
+    // Tokens paid out so far
+    pub total_claimed: u64,
+
+    // Leaves paid out so far
+    pub num_claimed: u32,
+
+    // Unix timestamp when claims close and clawback opens
+    pub clawback_ts: i64,
+
+    pub bump: u8,
+
+    // Intended to record claimed indices (never read or written, see V001)
+    pub claimed_bitmap: [u8; CLAIMED_BITMAP_BYTES],
+}
@@ -0,0 +1,7 @@
+// State Module
+//
+// Exports all state structures used by the merkle distributor program
+
+pub mod distributor;
+
+pub use distributor::*;
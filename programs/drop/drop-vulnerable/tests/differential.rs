@@ -0,0 +1,213 @@
+// Differential tests: drop-secure vs drop-vulnerable
+//
+// Each exploit script runs against both builds in one LiteSVM and must be
+// rejected by the secure build and accepted by the vulnerable one.
+// The test_feature_* tests replay them against drop-secure rebuilt with the
+// matching vuln-* feature, which must be the only build to accept them.
+//
+// HOW TO RUN THESE TESTS:
+// The secure .so and its feature builds must be built first:
+//   ./build-vuln-matrix.sh drop-secure
+//   cd programs/drop/drop-vulnerable
+//   cargo test-sbf --test differential
+
+use litesvm::{types::TransactionResult, LiteSVM};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    hash::hashv,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use solana_system_interface::program::ID as system_program;
+use soteria_test_kit::*;
+use spl_associated_token_account::get_associated_token_address;
+
+// declare_id! of drop-secure
+const SECURE_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("6p67vpSTT9qBSaCbdHcPffNpnH6FetXEen4Koa74XLag");
+const VULNERABLE_PROGRAM_ID: Pubkey = Pubkey::new_from_array(drop_vulnerable::ID.to_bytes());
+
+const PAIR: ProgramPair = ProgramPair {
+    secure: SECURE_PROGRAM_ID,
+    vulnerable: VULNERABLE_PROGRAM_ID,
+};
+
+// Two claimants, so the vault still holds tokens after the first claim
+const ALLOCATIONS: [u64; 2] = [100_000_000_000, 200_000_000_000];
+const MAX_TOTAL_CLAIM: u64 = 300_000_000_000;
+const CLAIM_WINDOW: i64 = 7 * 24 * 60 * 60;
+
+fn setup_pair() -> LiteSVM {
+    PAIR.load(
+        "../drop-secure/target/deploy/drop_secure.so",
+        "target/deploy/drop_vulnerable.so",
+    )
+}
+
+// Baseline drop-secure and its build with one vuln-* feature, each in its own SVM
+fn setup_feature(feature: &'static str) -> (FeatureVariant, LiteSVM, LiteSVM) {
+    let variant = FeatureVariant { program_id: SECURE_PROGRAM_ID, feature };
+    let (baseline, variant_svm) = variant.load(
+        "../drop-secure/target/deploy/drop_secure.so",
+        variant.so_path("../drop-secure", "drop_secure"),
+    );
+    (variant, baseline, variant_svm)
+}
+
+// Merkle hash prefixes (must match constants.rs)
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+// Same hashing as utils.rs and the on-chain helpers
+fn hash_leaf(index: u32, claimant: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, &index.to_le_bytes(), claimant.as_ref(), &amount.to_le_bytes()]).to_bytes()
+}
+
+fn hash_node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, first, second]).to_bytes()
+}
+
+// Funded distributor on one build
+struct Airdrop {
+    program_id: Pubkey,
+    claimants: Vec<Keypair>,
+    leaves: Vec<[u8; 32]>,
+    mint: Pubkey,
+    distributor: Pubkey,
+}
+
+impl Airdrop {
+    fn new(svm: &mut LiteSVM, program_id: &Pubkey) -> Self {
+        let admin = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let mint = CreateMint::new(svm, &admin).decimals(9).send().unwrap();
+        let admin_ata = CreateAssociatedTokenAccount::new(svm, &admin, &mint).send().unwrap();
+        MintTo::new(svm, &admin, &mint, &admin_ata, MAX_TOTAL_CLAIM).send().unwrap();
+
+        let claimants: Vec<Keypair> = ALLOCATIONS
+            .iter()
+            .map(|_| {
+                let claimant = create_funded_account(svm, LAMPORTS_PER_SOL);
+                CreateAssociatedTokenAccount::new(svm, &claimant, &mint).send().unwrap();
+                claimant
+            })
+            .collect();
+        let leaves: Vec<[u8; 32]> = claimants
+            .iter()
+            .zip(ALLOCATIONS)
+            .enumerate()
+            .map(|(i, (claimant, amount))| hash_leaf(i as u32, &claimant.pubkey(), amount))
+            .collect();
+        let root = hash_node(&leaves[0], &leaves[1]);
+
+        let seed = 1u64;
+        let (distributor, _) = Pubkey::find_program_address(
+            &[b"distributor", admin.pubkey().as_ref(), &seed.to_le_bytes()],
+            program_id,
+        );
+
+        let mut data = anchor_discriminator("create_distributor").to_vec();
+        data.extend_from_slice(&seed.to_le_bytes());
+        data.extend_from_slice(&root);
+        data.extend_from_slice(&MAX_TOTAL_CLAIM.to_le_bytes());
+        data.extend_from_slice(&(ALLOCATIONS.len() as u32).to_le_bytes());
+        data.extend_from_slice(&CLAIM_WINDOW.to_le_bytes());
+
+        let ix = Instruction {
+            program_id: *program_id,
+            accounts: vec![
+                AccountMeta::new(admin.pubkey(), true),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new(admin_ata, false),
+                AccountMeta::new(distributor, false),
+                AccountMeta::new(get_associated_token_address(&distributor, &mint), false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+                AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+            data,
+        };
+        send_ix(svm, ix, &admin).expect("Distributor creation should succeed on both builds");
+
+        Airdrop {
+            program_id: *program_id,
+            claimants,
+            leaves,
+            mint,
+            distributor,
+        }
+    }
+
+    // Claim for `index` with its own allocation; the proof is the other leaf
+    fn claim(&self, svm: &mut LiteSVM, index: usize) -> TransactionResult {
+        let claimant = &self.claimants[index];
+        let sibling = self.leaves[index ^ 1];
+        let vault = get_associated_token_address(&self.distributor, &self.mint);
+        let claimant_ata = get_associated_token_address(&claimant.pubkey(), &self.mint);
+
+        let mut data = anchor_discriminator("claim").to_vec();
+        data.extend_from_slice(&(index as u32).to_le_bytes());
+        data.extend_from_slice(&ALLOCATIONS[index].to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&sibling);
+
+        let ix = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(claimant.pubkey(), true),
+                AccountMeta::new(self.distributor, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new(claimant_ata, false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+            ],
+            data,
+        };
+        send_ix(svm, ix, claimant)
+    }
+}
+
+// V001: Claimant 0 replays their proof after a legitimate claim
+struct DoubleClaim;
+
+impl DifferentialScenario for DoubleClaim {
+    type State = Airdrop;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Double Claim - Missing Claimed Bitmap",
+            severity: Severity::Critical,
+            lesson: "Record every paid index and reject it on sight: a proof is not a receipt",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, program_id: &Pubkey) -> Airdrop {
+        let airdrop = Airdrop::new(svm, program_id);
+        airdrop.claim(svm, 0).expect("First claim should succeed on both builds");
+        airdrop
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        airdrop: &mut Airdrop,
+    ) -> TransactionResult {
+        airdrop.claim(svm, 0)
+    }
+}
+
+#[test]
+fn test_differential_double_claim() {
+    let mut svm = setup_pair();
+    run_differential(&mut svm, &PAIR, &DoubleClaim);
+}
+
+#[test]
+fn test_feature_missing_claimed_check() {
+    let (variant, mut baseline, mut variant_svm) = setup_feature("vuln-missing-claimed-check");
+    run_feature_differential(&mut baseline, &mut variant_svm, &variant, &DoubleClaim);
+}
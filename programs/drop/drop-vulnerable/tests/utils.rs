@@ -0,0 +1,351 @@
+// Test utilities for merkle distributor program
+
+use litesvm::{LiteSVM, types::TransactionResult};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo, get_spl_account};
+use litesvm_token::spl_token::state::Account as TokenAccount;
+use solana_sdk::{
+    clock::Clock,
+    hash::{hash, hashv},
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::program::ID as system_program;
+
+// Program ID matching declare_id!
+pub const DROP_PROGRAM_ID: Pubkey = Pubkey::new_from_array(drop_vulnerable::ID.to_bytes());
+
+// Build Anchor instruction discriminator
+// Formula: first 8 bytes of sha256("global:method_name")
+pub fn anchor_discriminator(method: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", method);
+    let hash_result = hash(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash_result.to_bytes()[..8]);
+    discriminator
+}
+
+// Standard program IDs
+pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_account::ID;
+
+// PDA Seeds
+pub const DISTRIBUTOR_SEED: &[u8] = b"distributor";
+
+// Merkle hash prefixes (must match constants.rs)
+pub const LEAF_PREFIX: &[u8] = &[0];
+pub const NODE_PREFIX: &[u8] = &[1];
+
+// Token configuration
+pub const DECIMALS: u8 = 9;
+pub const ONE_TOKEN: u64 = 1_000_000_000;
+
+// Distributor configuration used by the scenario
+pub const DISTRIBUTOR_ID: u64 = 1;
+pub const CLAIM_WINDOW: i64 = 7 * 24 * 60 * 60;
+
+// Allocation per claimant index
+pub const ALLOCATIONS: [u64; 3] = [100 * ONE_TOKEN, 200 * ONE_TOKEN, 300 * ONE_TOKEN];
+pub const MAX_TOTAL_CLAIM: u64 = 600 * ONE_TOKEN;
+
+// Setup LiteSVM with merkle distributor program
+pub fn setup_svm() -> LiteSVM {
+    let mut svm = LiteSVM::new();
+    let program_bytes = include_bytes!("../target/deploy/drop_vulnerable.so");
+    let _ = svm.add_program(DROP_PROGRAM_ID, program_bytes);
+    svm
+}
+
+// Create and fund account
+pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
+    let keypair = Keypair::new();
+    svm.airdrop(&keypair.pubkey(), lamports)
+        .expect("Airdrop should succeed");
+    keypair
+}
+
+// Sign and send a single instruction
+pub fn send_ix(svm: &mut LiteSVM, ix: Instruction, signer: &Keypair) -> TransactionResult {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    svm.expire_blockhash();
+    result
+}
+
+pub fn advance_time(svm: &mut LiteSVM, seconds: i64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += seconds;
+    svm.set_sysvar::<Clock>(&clock);
+}
+
+// MERKLE TREE
+// Mirrors the on-chain hashing in helpers.rs
+
+pub fn hash_leaf(index: u32, claimant: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, &index.to_le_bytes(), claimant.as_ref(), &amount.to_le_bytes()]).to_bytes()
+}
+
+pub fn hash_node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, first, second]).to_bytes()
+}
+
+// Merkle tree built bottom-up; a node without a sibling is carried up unchanged
+pub struct MerkleTree {
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    pub fn new(leaves: Vec<[u8; 32]>) -> Self {
+        let mut layers = vec![leaves];
+        while layers.last().unwrap().len() > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| if pair.len() == 2 { hash_node(&pair[0], &pair[1]) } else { pair[0] })
+                .collect();
+            layers.push(next);
+        }
+        MerkleTree { layers }
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.layers.last().unwrap()[0]
+    }
+
+    // Sibling at every level where one exists
+    pub fn proof(&self, index: usize) -> Vec<[u8; 32]> {
+        let mut proof = Vec::new();
+        let mut position = index;
+        for layer in &self.layers[..self.layers.len() - 1] {
+            let sibling = position ^ 1;
+            if sibling < layer.len() {
+                proof.push(layer[sibling]);
+            }
+            position /= 2;
+        }
+        proof
+    }
+}
+
+// Derive distributor PDA
+pub fn derive_distributor_pda(admin: &Pubkey, seed: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[DISTRIBUTOR_SEED, admin.as_ref(), &seed.to_le_bytes()],
+        &DROP_PROGRAM_ID,
+    )
+}
+
+// Build create_distributor instruction
+pub fn build_create_distributor_ix(
+    admin: &Pubkey,
+    mint: &Pubkey,
+    seed: u64,
+    merkle_root: [u8; 32],
+    max_total_claim: u64,
+    num_nodes: u32,
+    claim_window_seconds: i64,
+) -> Instruction {
+    let (distributor, _) = derive_distributor_pda(admin, seed);
+
+    let mut data = anchor_discriminator("create_distributor").to_vec();
+    data.extend_from_slice(&seed.to_le_bytes());
+    data.extend_from_slice(&merkle_root);
+    data.extend_from_slice(&max_total_claim.to_le_bytes());
+    data.extend_from_slice(&num_nodes.to_le_bytes());
+    data.extend_from_slice(&claim_window_seconds.to_le_bytes());
+
+    Instruction {
+        program_id: DROP_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(get_associated_token_address(admin, mint), false),
+            AccountMeta::new(distributor, false),
+            AccountMeta::new(get_associated_token_address(&distributor, mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build claim instruction
+pub fn build_claim_ix(
+    claimant: &Pubkey,
+    distributor: &Pubkey,
+    mint: &Pubkey,
+    index: u32,
+    amount: u64,
+    proof: &[[u8; 32]],
+) -> Instruction {
+    let mut data = anchor_discriminator("claim").to_vec();
+    data.extend_from_slice(&index.to_le_bytes());
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(&(proof.len() as u32).to_le_bytes());
+    for node in proof {
+        data.extend_from_slice(node);
+    }
+
+    Instruction {
+        program_id: DROP_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*claimant, true),
+            AccountMeta::new(*distributor, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(get_associated_token_address(distributor, mint), false),
+            AccountMeta::new(get_associated_token_address(claimant, mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Build clawback instruction
+pub fn build_clawback_ix(admin: &Pubkey, distributor: &Pubkey, mint: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: DROP_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new_readonly(*distributor, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(get_associated_token_address(distributor, mint), false),
+            AccountMeta::new(get_associated_token_address(admin, mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: anchor_discriminator("clawback").to_vec(),
+    }
+}
+
+// Read total_claimed and num_claimed from a Distributor
+// Layout: discriminator (8) + admin (32) + mint (32) + vault (32) + seed (8)
+//         + merkle_root (32) + max_total_claim (8) + num_nodes (4)
+//         + total_claimed (8) + num_claimed (4)
+pub fn get_claimed(svm: &LiteSVM, distributor: &Pubkey) -> (u64, u32) {
+    let account = svm.get_account(distributor).expect("Distributor should exist");
+    let offset = 8 + 32 + 32 + 32 + 8 + 32 + 8 + 4;
+    let total_claimed = u64::from_le_bytes(account.data[offset..offset + 8].try_into().unwrap());
+    let num_claimed = u32::from_le_bytes(account.data[offset + 8..offset + 12].try_into().unwrap());
+    (total_claimed, num_claimed)
+}
+
+pub fn token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    let account: TokenAccount = get_spl_account(svm, token_account).expect("Token account should exist");
+    account.amount
+}
+
+// Merkle distributor scenario setup result
+pub struct DropScenario {
+    pub svm: LiteSVM,
+    pub admin: Keypair,
+    pub mint: Pubkey,
+    pub claimants: Vec<Keypair>,
+    pub tree: MerkleTree,
+    pub distributor: Pubkey,
+    pub vault: Pubkey,
+}
+
+impl DropScenario {
+    // Claim instruction for claimant `index` with its own allocation and proof
+    pub fn claim_ix(&self, index: usize) -> Instruction {
+        build_claim_ix(
+            &self.claimants[index].pubkey(),
+            &self.distributor,
+            &self.mint,
+            index as u32,
+            ALLOCATIONS[index],
+            &self.tree.proof(index),
+        )
+    }
+
+    pub fn balance_of(&self, owner: &Pubkey) -> u64 {
+        token_balance(&self.svm, &get_associated_token_address(owner, &self.mint))
+    }
+}
+
+// Create a mint, one empty token account per claimant, and an admin account
+// holding MAX_TOTAL_CLAIM
+pub fn setup_tokens() -> (LiteSVM, Keypair, Pubkey, Vec<Keypair>) {
+    let mut svm = setup_svm();
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create mint");
+
+    let admin_ata = CreateAssociatedTokenAccount::new(&mut svm, &admin, &mint)
+        .owner(&admin.pubkey())
+        .send()
+        .expect("Failed to create admin token account");
+    MintTo::new(&mut svm, &admin, &mint, &admin_ata, MAX_TOTAL_CLAIM)
+        .owner(&admin)
+        .send()
+        .expect("Failed to mint to admin");
+
+    let claimants: Vec<Keypair> = ALLOCATIONS
+        .iter()
+        .map(|_| {
+            let claimant = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+            CreateAssociatedTokenAccount::new(&mut svm, &claimant, &mint)
+                .owner(&claimant.pubkey())
+                .send()
+                .expect("Failed to create claimant token account");
+            claimant
+        })
+        .collect();
+
+    (svm, admin, mint, claimants)
+}
+
+// Build the allocation tree for the claimants
+pub fn build_tree(claimants: &[Keypair]) -> MerkleTree {
+    let leaves = claimants
+        .iter()
+        .zip(ALLOCATIONS)
+        .enumerate()
+        .map(|(i, (claimant, amount))| hash_leaf(i as u32, &claimant.pubkey(), amount))
+        .collect();
+    MerkleTree::new(leaves)
+}
+
+// Create and fund a distributor for three claimants (100, 200 and 300 tokens)
+pub fn setup_drop_scenario() -> DropScenario {
+    let (mut svm, admin, mint, claimants) = setup_tokens();
+    let tree = build_tree(&claimants);
+
+    let ix = build_create_distributor_ix(
+        &admin.pubkey(),
+        &mint,
+        DISTRIBUTOR_ID,
+        tree.root(),
+        MAX_TOTAL_CLAIM,
+        ALLOCATIONS.len() as u32,
+        CLAIM_WINDOW,
+    );
+    send_ix(&mut svm, ix, &admin).expect("Distributor creation should succeed");
+
+    let (distributor, _) = derive_distributor_pda(&admin.pubkey(), DISTRIBUTOR_ID);
+    let vault = get_associated_token_address(&distributor, &mint);
+
+    DropScenario {
+        svm,
+        admin,
+        mint,
+        claimants,
+        tree,
+        distributor,
+        vault,
+    }
+}
@@ -0,0 +1,6 @@
+// Constants for PDA derivation
+
+// PDA seed prefixes (same as pino-escrow's MakeState::SEED_PREFIX)
+pub const OFFER_SEED: &[u8] = b"offer";
+
+pub const ANCHOR_DISCRIMINATOR: usize = 8;
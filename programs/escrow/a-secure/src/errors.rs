@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+pub use soteria_errors::a_secure::EscrowError as EscrowErrorCode;
+
+#[error_code]
+pub enum EscrowError {
+    #[msg("Offer expiry must be in the future")]
+    InvalidExpiry,
+
+    #[msg("Offer has expired")]
+    OfferExpired,
+
+    #[msg("Signer is not allowed to take this private offer")]
+    TakerNotAllowed,
+
+    #[msg("Token A mint does not match the offer")]
+    InvalidTokenMintA,
+
+    #[msg("Token B mint does not match the offer")]
+    InvalidTokenMintB,
+
+    #[msg("Signer is not the offer's proposer")]
+    InvalidProposer,
+
+    #[msg("Insufficient token balance")]
+    InsufficientFunds,
+}
@@ -0,0 +1,7 @@
+pub mod propose_offer;
+pub mod take_offer;
+pub mod refund_offer;
+
+pub use propose_offer::*;
+pub use take_offer::*;
+pub use refund_offer::*;
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Propose Offer Instruction
+//
+// The maker (Sarah) deposits Token A into a vault owned by a new offer PDA and
+// names the amount of Token B she wants in return.
+//
+// Every check from pino-escrow's OfferAccounts::try_from is expressed as an
+// account constraint below; the numbers in brackets refer to its SECURITY CHECKS.
+//
+// SECURITY FEATURES:
+// - Maker must sign [1]
+// - Token program must be SPL Token or Token-2022, mints owned by it [2]
+// - Maker's Token A account must be their ATA [3, 4]
+// - Offer PDA created with 'init', so an existing offer cannot be overwritten [5, 6]
+// - Vault is the offer's ATA and created with 'init' [7, 8]
+// - Expiry must be in the future
+// - TransferChecked validates the mint and decimals
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct ProposeOffer<'info> {
+    // Offer creator, pays for the offer and vault accounts
+    // [1] Signer<'info> replaces the manual is_signer() check
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    // Token A - what the maker is offering
+    // [2] InterfaceAccount checks the owner is a token program and the data is a mint;
+    //     mint::token_program ties it to the token program passed in
+    #[account(mint::token_program = token_program)]
+    pub token_mint_a: InterfaceAccount<'info, Mint>,
+
+    // Token B - what the maker wants in return
+    #[account(mint::token_program = token_program)]
+    pub token_mint_b: InterfaceAccount<'info, Mint>,
+
+    // Maker's Token A account (source of the deposit)
+    // [3, 4] associated_token constraints re-derive the ATA address
+    #[account(
+        mut,
+        associated_token::mint = token_mint_a,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program,
+    )]
+    pub maker_ata_a: InterfaceAccount<'info, TokenAccount>,
+
+    // Offer PDA
+    // Seeds: ["offer", maker, id]
+    // [5, 6] 'init' fails if the account already exists and requires it writable
+    #[account(
+        init,
+        payer = maker,
+        space = ANCHOR_DISCRIMINATOR + Offer::INIT_SPACE,
+        seeds = [OFFER_SEED, maker.key().as_ref(), &id.to_le_bytes()],
+        bump,
+    )]
+    pub offer: Account<'info, Offer>,
+
+    // Vault holding Token A until the offer is taken or refunded
+    // [7, 8] ATA of the offer PDA, created here
+    #[account(
+        init,
+        payer = maker,
+        associated_token::mint = token_mint_a,
+        associated_token::authority = offer,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    // [2] Interface<TokenInterface> only accepts SPL Token or Token-2022
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeOffer<'info> {
+    pub fn propose_offer(
+        &mut self,
+        id: u64,
+        token_b_wanted_amount: u64,
+        token_a_offered_amount: u64,
+        expires_at: i64,
+        allowed_taker: Pubkey,
+        bumps: &ProposeOfferBumps,
+    ) -> Result<()> {
+        // 1. Expiration Validation
+        // An expiry in the past would lock funds until the maker refunds
+        let now = Clock::get()?.unix_timestamp;
+        require!(expires_at > now, EscrowError::InvalidExpiry);
+
+        // 2. Initialize Offer State
+        // The canonical bump comes from Anchor's seeds check, not from the client
+        self.offer.set_inner(Offer {
+            id,
+            proposer: self.maker.key(),
+            token_mint_a: self.token_mint_a.key(),
+            token_mint_b: self.token_mint_b.key(),
+            token_b_wanted_amount,
+            token_a_offered_amount,
+            expires_at,
+            allowed_taker,
+            bump: bumps.offer,
+        });
+
+        // 3. Deposit Token A into the vault
+        transfer_checked(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.maker_ata_a.to_account_info(),
+                    mint: self.token_mint_a.to_account_info(),
+                    to: self.vault.to_account_info(),
+                    authority: self.maker.to_account_info(),
+                },
+            ),
+            token_a_offered_amount,
+            self.token_mint_a.decimals,
+        )
+    }
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Refund Offer Instruction
+//
+// The maker (Sarah) cancels an offer nobody has taken yet and recovers Token A.
+//
+// Flow:
+// 1. Vault sends Token A -> Maker's ATA A
+// 2. Vault is closed (rent returned to maker)
+// 3. Offer PDA is closed (rent returned to maker)
+//
+// Numbers in brackets refer to the SECURITY CHECKS in pino-escrow's
+// RefundOfferAccounts::try_from.
+//
+// SECURITY FEATURES:
+// - Maker must sign and be the offer's proposer [1, 4]
+// - Token program and mint ownership enforced by the account types [2]
+// - Offer owned by this program, correct discriminator and seeds [3]
+// - Refund destination and vault re-derived as ATAs [5, 6]
+
+#[derive(Accounts)]
+pub struct RefundOffer<'info> {
+    // [1] Signer<'info> replaces the manual is_signer() check
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    // [2] Owner and mint layout checked by InterfaceAccount
+    #[account(mint::token_program = token_program)]
+    pub token_mint_a: InterfaceAccount<'info, Mint>,
+
+    // Maker's Token A account (refund destination)
+    // [5] Owner, layout, writable and ATA address
+    #[account(
+        mut,
+        associated_token::mint = token_mint_a,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program,
+    )]
+    pub maker_ata_a: InterfaceAccount<'info, TokenAccount>,
+
+    // Offer PDA
+    // Seeds: ["offer", maker, id]
+    // [3] Account<Offer> checks owner and discriminator; seeds check the address
+    // [4] The signer must be the proposer and mint A must match
+    #[account(
+        mut,
+        close = maker,
+        seeds = [OFFER_SEED, maker.key().as_ref(), &offer.id.to_le_bytes()],
+        bump = offer.bump,
+        constraint = offer.proposer == maker.key() @ EscrowError::InvalidProposer,
+        has_one = token_mint_a @ EscrowError::InvalidTokenMintA,
+    )]
+    pub offer: Account<'info, Offer>,
+
+    // Vault holding Token A
+    // [6] Owner, layout, writable and ATA address of the offer PDA
+    #[account(
+        mut,
+        associated_token::mint = token_mint_a,
+        associated_token::authority = offer,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    // [2] Interface<TokenInterface> only accepts SPL Token or Token-2022
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RefundOffer<'info> {
+    pub fn refund_offer(&mut self) -> Result<()> {
+        // 1. Prepare PDA Signer
+        let maker_key = self.maker.key();
+        let id_bytes = self.offer.id.to_le_bytes();
+        let offer_seeds: &[&[u8]] = &[
+            OFFER_SEED,
+            maker_key.as_ref(),
+            &id_bytes,
+            &[self.offer.bump],
+        ];
+        let signer_seeds = &[offer_seeds];
+
+        // 2. Transfer Token A from Vault back to Maker
+        // Refunds the full vault balance, including anything sent to it after ProposeOffer
+        let vault_amount = self.vault.amount;
+        if vault_amount > 0 {
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TransferChecked {
+                        from: self.vault.to_account_info(),
+                        mint: self.token_mint_a.to_account_info(),
+                        to: self.maker_ata_a.to_account_info(),
+                        authority: self.offer.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                vault_amount,
+                self.token_mint_a.decimals,
+            )?;
+        }
+
+        // 3. Close Vault Account (rent to maker)
+        // The offer PDA itself is closed by Anchor's 'close = maker' after the handler
+        close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.vault.to_account_info(),
+                destination: self.maker.to_account_info(),
+                authority: self.offer.to_account_info(),
+            },
+            signer_seeds,
+        ))
+    }
+}
@@ -0,0 +1,229 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Take Offer Instruction
+//
+// The taker (Steve) pays Token B to the proposer and receives Token A from the vault.
+//
+// Flow:
+// 1. Taker sends Token B -> Proposer's ATA B (created if missing)
+// 2. Vault sends Token A -> Taker's ATA A
+// 3. Vault balance above the offer, if any -> Proposer's ATA A
+// 4. Vault is closed (rent returned to proposer)
+// 5. Offer PDA is closed (rent returned to taker as compensation)
+//
+// Numbers in brackets refer to the SECURITY CHECKS in pino-escrow's
+// TakeOfferAccounts::try_from.
+//
+// SECURITY FEATURES:
+// - Taker must sign [1]
+// - Token program and mint ownership enforced by the account types [2]
+// - Offer owned by this program, correct discriminator and seeds [3]
+// - Offer bound to the proposer and both mints with has_one [4]
+// - Private offers only fillable by allowed_taker [4]
+// - Every token account address re-derived as an ATA [5, 6, 7, 8]
+// - Balances checked before any transfer [7, 8]
+// - Taker gets exactly the offered amount; any vault surplus goes back to
+//   the proposer's own ATA A
+// - Expired offers cannot be taken
+// - Vault and offer closed in the same instruction, so an offer fills once
+
+#[derive(Accounts)]
+pub struct TakeOffer<'info> {
+    // Pays Token B, receives Token A and the offer rent
+    // [1] Signer<'info> replaces the manual is_signer() check
+    #[account(mut)]
+    pub taker: Signer<'info>,
+
+    // Original proposer (Sarah), receives the vault rent
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+
+    // Proposer's Token B account
+    // [5] Address derived from the proposer and mint B; created by the taker if missing
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = token_mint_b,
+        associated_token::authority = proposer,
+        associated_token::token_program = token_program,
+    )]
+    pub proposer_ata_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // [2] Owner and mint layout checked by InterfaceAccount
+    #[account(mint::token_program = token_program)]
+    pub token_mint_b: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mint::token_program = token_program)]
+    pub token_mint_a: Box<InterfaceAccount<'info, Mint>>,
+
+    // Taker's Token A account (receives the vault)
+    // [6] Owner, layout, writable and ATA address
+    #[account(
+        mut,
+        associated_token::mint = token_mint_a,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program,
+    )]
+    pub taker_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Taker's Token B account (source of the payment)
+    // [7] Owner, layout and ATA address; balance checked in the handler
+    #[account(
+        mut,
+        associated_token::mint = token_mint_b,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program,
+    )]
+    pub taker_ata_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Offer PDA
+    // Seeds: ["offer", proposer, id]
+    // [3] Account<Offer> checks owner and discriminator; seeds check the address
+    // [4] has_one replaces the stored-state comparisons
+    #[account(
+        mut,
+        close = taker,
+        seeds = [OFFER_SEED, proposer.key().as_ref(), &offer.id.to_le_bytes()],
+        bump = offer.bump,
+        has_one = proposer @ EscrowError::InvalidProposer,
+        has_one = token_mint_a @ EscrowError::InvalidTokenMintA,
+        has_one = token_mint_b @ EscrowError::InvalidTokenMintB,
+        constraint = offer.can_take(&taker.key()) @ EscrowError::TakerNotAllowed,
+    )]
+    pub offer: Account<'info, Offer>,
+
+    // Vault holding Token A
+    // [8] Owner, layout, writable and ATA address of the offer PDA
+    #[account(
+        mut,
+        associated_token::mint = token_mint_a,
+        associated_token::authority = offer,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // [2] Interface<TokenInterface> only accepts SPL Token or Token-2022
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    // Proposer's Token A account (receives any vault surplus)
+    // Last so the accounts above keep the same positions as pino-escrow's
+    #[account(
+        mut,
+        associated_token::mint = token_mint_a,
+        associated_token::authority = proposer,
+        associated_token::token_program = token_program,
+    )]
+    pub proposer_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+impl<'info> TakeOffer<'info> {
+    pub fn take_offer(&mut self) -> Result<()> {
+        // 1. Expiration Check
+        // Once the deadline passes the maker's price no longer applies
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            cfg!(feature = "vuln-missing-expiry-check") || !self.offer.is_expired(now),
+            EscrowError::OfferExpired
+        );
+
+        // 2. Balance Checks
+        // [7, 8] Fail with a clear error instead of inside the token program
+        let token_b_amount = self.offer.token_b_wanted_amount;
+        let token_a_amount = self.offer.token_a_offered_amount;
+        require!(
+            self.taker_ata_b.amount >= token_b_amount,
+            EscrowError::InsufficientFunds
+        );
+        require!(
+            self.vault.amount >= token_a_amount,
+            EscrowError::InsufficientFunds
+        );
+
+        // 3. Transfer Token B from Taker to Proposer
+        transfer_checked(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.taker_ata_b.to_account_info(),
+                    mint: self.token_mint_b.to_account_info(),
+                    to: self.proposer_ata_b.to_account_info(),
+                    authority: self.taker.to_account_info(),
+                },
+            ),
+            token_b_amount,
+            self.token_mint_b.decimals,
+        )?;
+
+        // 4. Prepare PDA Signer
+        let proposer_key = self.proposer.key();
+        let id_bytes = self.offer.id.to_le_bytes();
+        let offer_seeds: &[&[u8]] = &[
+            OFFER_SEED,
+            proposer_key.as_ref(),
+            &id_bytes,
+            &[self.offer.bump],
+        ];
+        let signer_seeds = &[offer_seeds];
+
+        // 5. Transfer Token A from Vault to Taker
+        // Vault transfers are PDA-signed, so only this program can release funds
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.vault.to_account_info(),
+                    mint: self.token_mint_a.to_account_info(),
+                    to: self.taker_ata_a.to_account_info(),
+                    authority: self.offer.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            token_a_amount,
+            self.token_mint_a.decimals,
+        )?;
+
+        // 6. Refund Vault Surplus to Proposer
+        // Anyone can send Token A to the vault; whatever sits above the offer
+        // is the maker's, and the vault must be empty before it can close
+        let surplus = self.vault.amount - token_a_amount;
+        if surplus > 0 {
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TransferChecked {
+                        from: self.vault.to_account_info(),
+                        mint: self.token_mint_a.to_account_info(),
+                        to: self.proposer_ata_a.to_account_info(),
+                        authority: self.offer.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                surplus,
+                self.token_mint_a.decimals,
+            )?;
+        }
+
+        // 7. Close Vault Account (rent to proposer)
+        // The offer PDA itself is closed by Anchor's 'close = taker' after the handler
+        close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.vault.to_account_info(),
+                destination: self.proposer.to_account_info(),
+                authority: self.offer.to_account_info(),
+            },
+            signer_seeds,
+        ))
+    }
+}
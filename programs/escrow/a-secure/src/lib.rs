@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+use instructions::*;
+
+declare_id!("ZkiBFvuS6kreoQyPoRqTSwuYbpNT7zoFMAPhy96B3zp");
+
+#[program]
+pub mod escrow_secure {
+    use super::*;
+
+    pub fn propose_offer(
+        ctx: Context<ProposeOffer>,
+        id: u64,
+        token_b_wanted_amount: u64,
+        token_a_offered_amount: u64,
+        expires_at: i64,
+        allowed_taker: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.propose_offer(
+            id,
+            token_b_wanted_amount,
+            token_a_offered_amount,
+            expires_at,
+            allowed_taker,
+            &ctx.bumps,
+        )
+    }
+
+    pub fn take_offer(ctx: Context<TakeOffer>) -> Result<()> {
+        ctx.accounts.take_offer()
+    }
+
+    pub fn refund_offer(ctx: Context<RefundOffer>) -> Result<()> {
+        ctx.accounts.refund_offer()
+    }
+}
@@ -0,0 +1,3 @@
+pub mod offer;
+
+pub use offer::*;
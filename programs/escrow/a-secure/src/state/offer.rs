@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+// Offer
+//
+// Anchor counterpart of pino-escrow's MakeState. The fields are the same, but
+// the account is Borsh-encoded behind an 8-byte discriminator instead of a
+// #[repr(C)] layout, so there is no is_initialized flag: Account<Offer> only
+// deserializes accounts this program created, and a closed offer has no data.
+#[account]
+#[derive(InitSpace)]
+pub struct Offer {
+    // Client-chosen id, part of the PDA seeds
+    pub id: u64,
+
+    pub proposer: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub token_b_wanted_amount: u64,
+    pub token_a_offered_amount: u64,
+
+    // Unix timestamp after which the offer can no longer be taken
+    pub expires_at: i64,
+
+    // Only taker allowed to fill the offer, Pubkey::default() for a public offer
+    pub allowed_taker: Pubkey,
+
+    pub bump: u8,
+}
+
+impl Offer {
+    // Expired once the clock reaches expires_at
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+
+    pub fn is_public(&self) -> bool {
+        self.allowed_taker == Pubkey::default()
+    }
+
+    // Public offers can be taken by anyone, private ones only by allowed_taker
+    pub fn can_take(&self, taker: &Pubkey) -> bool {
+        self.is_public() || self.allowed_taker == *taker
+    }
+}
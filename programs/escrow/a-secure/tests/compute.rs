@@ -0,0 +1,41 @@
+// Compute-unit regression tests for a-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// The same flow costs far fewer units in pino-escrow p-secure; compare with
+// its tests/compute.rs to see what Anchor's account validation adds.
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+mod utils;
+
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+use soteria_test_kit::{assert_tx_ok, send_ix, ComputeBudget, DEFAULT_CU_BASELINES_FILE};
+use utils::*;
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let mut svm = setup_svm();
+    let escrow = Escrow::Anchor;
+    let market = Market::new(&mut svm, &TOKEN_PROGRAM_ID);
+    let expires_at = current_timestamp(&svm) + OFFER_DURATION_SECONDS;
+
+    // Offer 0 is taken, offer 1 refunded
+    let ix = market.propose_ix(&escrow, 0, expires_at, &Pubkey::default());
+    budget.record("propose_offer", send_ix(&mut svm, ix, &market.maker));
+    let ix = market.propose_ix(&escrow, 1, expires_at, &Pubkey::default());
+    assert_tx_ok!(send_ix(&mut svm, ix, &market.maker));
+
+    // The program creates the maker's Token B account here
+    let ix = market.take_ix(&escrow, &market.taker.pubkey(), 0);
+    budget.record("take_offer", send_ix(&mut svm, ix, &market.taker));
+
+    let ix = market.refund_ix(&escrow, 1);
+    budget.record("refund_offer", send_ix(&mut svm, ix, &market.maker));
+
+    budget.check();
+}
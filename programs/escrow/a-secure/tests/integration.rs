@@ -0,0 +1,455 @@
+// Integration tests for the Anchor escrow port using LiteSVM
+//
+// Every scenario is a ParityScenario: it runs once against pino-escrow
+// p-secure and once against a-secure, and both must accept or reject it and
+// leave the same token balances behind.
+// 1. ProposeOffer + TakeOffer - Atomic swap completes
+// 2. ProposeOffer + RefundOffer - Maker recovers Token A
+// 3. Token-2022 - Full swap with Token-2022 mints
+// 4. Double take - Second take of the same offer is rejected
+// 5. Expired take - Offer cannot be taken after its deadline
+// 6. Private offer - Outsider cannot take an offer reserved for the taker
+// 7. Past expiry - ProposeOffer rejects an expiry that has already passed
+// 8. Vault surplus - Taker gets the offered amount, the maker the rest
+//
+// Anchor-only tests:
+// 9. Offer account layout - discriminator + Borsh fields instead of #[repr(C)]
+// 10. Feature matrix - only the vuln-missing-expiry-check build fills an
+//    expired offer (./build-vuln-matrix.sh escrow/a-secure first)
+//
+// HOW TO RUN THESE TESTS:
+// Both .so files must be built first:
+//   (cd ../../pino-escrow/p-secure && cargo build-sbf)
+//   cargo build-sbf && cargo test-sbf --test integration
+
+mod utils;
+
+use litesvm::{types::TransactionResult, LiteSVM};
+use litesvm_token::MintTo;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+use soteria_test_kit::*;
+use utils::*;
+
+const OFFER_ID: u64 = 1;
+const VAULT_SURPLUS: u64 = 1;
+
+// Fresh market on one implementation with offer OFFER_ID already proposed
+fn open_offer(svm: &mut LiteSVM, escrow: &Escrow, token_program: &Pubkey, private: bool) -> Market {
+    let market = Market::new(svm, token_program);
+    let allowed_taker = if private {
+        market.taker.pubkey()
+    } else {
+        Pubkey::default()
+    };
+    let expires_at = current_timestamp(svm) + OFFER_DURATION_SECONDS;
+
+    let ix = market.propose_ix(escrow, OFFER_ID, expires_at, &allowed_taker);
+    assert_tx_ok!(send_ix(svm, ix, &market.maker));
+    market
+}
+
+// Balances plus whether the offer account still exists
+fn observe_market(svm: &LiteSVM, escrow: &Escrow, market: &Market) -> Vec<u64> {
+    let mut observed = market.balances(svm, escrow, OFFER_ID);
+    let offer = escrow.offer_address(&market.maker.pubkey(), OFFER_ID);
+    observed.push(account_exists(svm, &offer) as u64);
+    observed
+}
+
+
+// ======================== SCENARIOS ========================
+
+
+// Taker pays Token B and receives the vault
+struct TakeOffer {
+    token_program: Pubkey,
+}
+
+impl ParityScenario for TakeOffer {
+    type Implementation = Escrow;
+    type State = Market;
+
+    fn name(&self) -> &'static str {
+        "ProposeOffer + TakeOffer"
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, escrow: &Escrow) -> Market {
+        open_offer(svm, escrow, &self.token_program, false)
+    }
+
+    fn run(&self, svm: &mut LiteSVM, escrow: &Escrow, market: &mut Market) -> TransactionResult {
+        let ix = market.take_ix(escrow, &market.taker.pubkey(), OFFER_ID);
+        send_ix(svm, ix, &market.taker)
+    }
+
+    fn observe(&self, svm: &LiteSVM, escrow: &Escrow, market: &Market) -> Vec<u64> {
+        observe_market(svm, escrow, market)
+    }
+}
+
+// Maker cancels and gets Token A back
+struct RefundOffer;
+
+impl ParityScenario for RefundOffer {
+    type Implementation = Escrow;
+    type State = Market;
+
+    fn name(&self) -> &'static str {
+        "ProposeOffer + RefundOffer"
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, escrow: &Escrow) -> Market {
+        open_offer(svm, escrow, &TOKEN_PROGRAM_ID, false)
+    }
+
+    fn run(&self, svm: &mut LiteSVM, escrow: &Escrow, market: &mut Market) -> TransactionResult {
+        let ix = market.refund_ix(escrow, OFFER_ID);
+        send_ix(svm, ix, &market.maker)
+    }
+
+    fn observe(&self, svm: &LiteSVM, escrow: &Escrow, market: &Market) -> Vec<u64> {
+        observe_market(svm, escrow, market)
+    }
+}
+
+// Taker fills the offer, then tries to fill it again
+struct DoubleTake;
+
+impl ParityScenario for DoubleTake {
+    type Implementation = Escrow;
+    type State = Market;
+
+    fn name(&self) -> &'static str {
+        "Double take"
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, escrow: &Escrow) -> Market {
+        let market = open_offer(svm, escrow, &TOKEN_PROGRAM_ID, false);
+        let ix = market.take_ix(escrow, &market.taker.pubkey(), OFFER_ID);
+        assert_tx_ok!(send_ix(svm, ix, &market.taker));
+        market
+    }
+
+    fn run(&self, svm: &mut LiteSVM, escrow: &Escrow, market: &mut Market) -> TransactionResult {
+        let ix = market.take_ix(escrow, &market.taker.pubkey(), OFFER_ID);
+        send_ix(svm, ix, &market.taker)
+    }
+
+    fn observe(&self, svm: &LiteSVM, escrow: &Escrow, market: &Market) -> Vec<u64> {
+        observe_market(svm, escrow, market)
+    }
+}
+
+// The deadline passes before the taker arrives
+struct ExpiredTake;
+
+impl ParityScenario for ExpiredTake {
+    type Implementation = Escrow;
+    type State = Market;
+
+    fn name(&self) -> &'static str {
+        "Expired take"
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, escrow: &Escrow) -> Market {
+        let market = open_offer(svm, escrow, &TOKEN_PROGRAM_ID, false);
+        advance_time(svm, OFFER_DURATION_SECONDS as u64);
+        market
+    }
+
+    fn run(&self, svm: &mut LiteSVM, escrow: &Escrow, market: &mut Market) -> TransactionResult {
+        let ix = market.take_ix(escrow, &market.taker.pubkey(), OFFER_ID);
+        send_ix(svm, ix, &market.taker)
+    }
+
+    fn observe(&self, svm: &LiteSVM, escrow: &Escrow, market: &Market) -> Vec<u64> {
+        observe_market(svm, escrow, market)
+    }
+}
+
+// Offer reserved for the taker; the outsider tries to fill it
+struct PrivateOfferOutsider;
+
+impl ParityScenario for PrivateOfferOutsider {
+    type Implementation = Escrow;
+    type State = Market;
+
+    fn name(&self) -> &'static str {
+        "Private offer taken by outsider"
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, escrow: &Escrow) -> Market {
+        open_offer(svm, escrow, &TOKEN_PROGRAM_ID, true)
+    }
+
+    fn run(&self, svm: &mut LiteSVM, escrow: &Escrow, market: &mut Market) -> TransactionResult {
+        let ix = market.take_ix(escrow, &market.outsider.pubkey(), OFFER_ID);
+        send_ix(svm, ix, &market.outsider)
+    }
+
+    fn observe(&self, svm: &LiteSVM, escrow: &Escrow, market: &Market) -> Vec<u64> {
+        observe_market(svm, escrow, market)
+    }
+}
+
+// Maker proposes an offer that expires right now
+struct PastExpiry;
+
+impl ParityScenario for PastExpiry {
+    type Implementation = Escrow;
+    type State = Market;
+
+    fn name(&self) -> &'static str {
+        "ProposeOffer with past expiry"
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, _escrow: &Escrow) -> Market {
+        Market::new(svm, &TOKEN_PROGRAM_ID)
+    }
+
+    fn run(&self, svm: &mut LiteSVM, escrow: &Escrow, market: &mut Market) -> TransactionResult {
+        let expires_at = current_timestamp(svm);
+        let ix = market.propose_ix(escrow, OFFER_ID, expires_at, &Pubkey::default());
+        send_ix(svm, ix, &market.maker)
+    }
+
+    fn observe(&self, svm: &LiteSVM, escrow: &Escrow, market: &Market) -> Vec<u64> {
+        observe_market(svm, escrow, market)
+    }
+}
+
+// Someone sends extra Token A to the vault before the taker arrives
+struct SurplusTake;
+
+impl ParityScenario for SurplusTake {
+    type Implementation = Escrow;
+    type State = Market;
+
+    fn name(&self) -> &'static str {
+        "TakeOffer with a vault surplus"
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, escrow: &Escrow) -> Market {
+        let market = open_offer(svm, escrow, &TOKEN_PROGRAM_ID, false);
+        let vault = escrow.vault_address(
+            &market.maker.pubkey(),
+            OFFER_ID,
+            &market.mint_a,
+            &market.token_program,
+        );
+        MintTo::new(svm, &market.mint_authority, &market.mint_a, &vault, VAULT_SURPLUS)
+            .token_program_id(&market.token_program)
+            .send()
+            .expect("Failed to mint the surplus to the vault");
+        market
+    }
+
+    fn run(&self, svm: &mut LiteSVM, escrow: &Escrow, market: &mut Market) -> TransactionResult {
+        let ix = market.take_ix(escrow, &market.taker.pubkey(), OFFER_ID);
+        send_ix(svm, ix, &market.taker)
+    }
+
+    fn observe(&self, svm: &LiteSVM, escrow: &Escrow, market: &Market) -> Vec<u64> {
+        observe_market(svm, escrow, market)
+    }
+}
+
+
+// ======================== TESTS ========================
+
+
+// Test 1: ProposeOffer + TakeOffer
+#[test]
+fn test_take_offer_parity() {
+    let mut svm = setup_svm();
+    let scenario = TakeOffer { token_program: TOKEN_PROGRAM_ID };
+
+    let result = run_parity(&mut svm, &IMPLEMENTATIONS, &scenario);
+
+    assert!(result.accepted());
+    // Maker got Token B, taker got Token A, vault and offer are closed
+    assert_eq!(
+        result.outcomes[0].observed,
+        vec![
+            INITIAL_MINT_AMOUNT - TOKEN_A_OFFER_AMOUNT,
+            TOKEN_B_WANTED_AMOUNT,
+            TOKEN_A_OFFER_AMOUNT,
+            INITIAL_MINT_AMOUNT - TOKEN_B_WANTED_AMOUNT,
+            0,
+            0,
+            0,
+        ]
+    );
+}
+
+// Test 2: ProposeOffer + RefundOffer
+#[test]
+fn test_refund_offer_parity() {
+    let mut svm = setup_svm();
+
+    let result = run_parity(&mut svm, &IMPLEMENTATIONS, &RefundOffer);
+
+    assert!(result.accepted());
+    assert_eq!(
+        result.outcomes[0].observed,
+        vec![INITIAL_MINT_AMOUNT, 0, 0, INITIAL_MINT_AMOUNT, 0, 0, 0]
+    );
+}
+
+// Test 3: Token-2022 mints
+#[test]
+fn test_token_2022_take_offer_parity() {
+    let mut svm = setup_svm();
+    let scenario = TakeOffer { token_program: TOKEN_2022_PROGRAM_ID };
+
+    let result = run_parity(&mut svm, &IMPLEMENTATIONS, &scenario);
+
+    assert!(result.accepted());
+    assert_eq!(result.outcomes[0].observed[2], TOKEN_A_OFFER_AMOUNT);
+}
+
+// Test 4: Double take
+#[test]
+fn test_double_take_parity() {
+    let mut svm = setup_svm();
+
+    let result = run_parity(&mut svm, &IMPLEMENTATIONS, &DoubleTake);
+
+    // Taker received Token A exactly once
+    assert!(!result.accepted());
+    assert_eq!(result.outcomes[0].observed[2], TOKEN_A_OFFER_AMOUNT);
+}
+
+// Test 5: Expired take
+#[test]
+fn test_expired_take_parity() {
+    let mut svm = setup_svm();
+
+    let result = run_parity(&mut svm, &IMPLEMENTATIONS, &ExpiredTake);
+
+    // Token A is still in the vault and the offer is still open
+    assert!(!result.accepted());
+    assert_eq!(result.outcomes[0].observed[5], TOKEN_A_OFFER_AMOUNT);
+    assert_eq!(result.outcomes[0].observed[6], 1);
+}
+
+// Test 6: Private offer taken by outsider
+#[test]
+fn test_private_offer_parity() {
+    let mut svm = setup_svm();
+
+    let result = run_parity(&mut svm, &IMPLEMENTATIONS, &PrivateOfferOutsider);
+
+    assert!(!result.accepted());
+    assert_eq!(result.outcomes[0].observed[4], 0);
+}
+
+// Test 7: Past expiry
+#[test]
+fn test_past_expiry_parity() {
+    let mut svm = setup_svm();
+
+    let result = run_parity(&mut svm, &IMPLEMENTATIONS, &PastExpiry);
+
+    assert!(!result.accepted());
+    assert_eq!(result.outcomes[0].observed[0], INITIAL_MINT_AMOUNT);
+}
+
+// Test 8: Vault surplus
+#[test]
+fn test_surplus_take_parity() {
+    let mut svm = setup_svm();
+
+    let result = run_parity(&mut svm, &IMPLEMENTATIONS, &SurplusTake);
+
+    // Taker got exactly the offer, the surplus went back to the maker
+    assert!(result.accepted());
+    assert_eq!(
+        result.outcomes[0].observed,
+        vec![
+            INITIAL_MINT_AMOUNT - TOKEN_A_OFFER_AMOUNT + VAULT_SURPLUS,
+            TOKEN_B_WANTED_AMOUNT,
+            TOKEN_A_OFFER_AMOUNT,
+            INITIAL_MINT_AMOUNT - TOKEN_B_WANTED_AMOUNT,
+            0,
+            0,
+            0,
+        ]
+    );
+}
+
+// Test 9: Offer account layout
+//
+// Anchor stores the same fields Borsh-encoded behind an 8-byte discriminator;
+// there is no is_initialized flag because a closed account has no data left
+#[test]
+fn test_anchor_offer_layout() {
+    let mut svm = setup_svm();
+    let escrow = Escrow::Anchor;
+    let market = open_offer(&mut svm, &escrow, &TOKEN_PROGRAM_ID, true);
+
+    let offer = escrow.offer_address(&market.maker.pubkey(), OFFER_ID);
+    let account = svm.get_account(&offer).expect("Offer should exist");
+    assert_eq!(account.owner, ANCHOR_PROGRAM_ID);
+
+    // [disc: 8][id: 8][proposer: 32][mint_a: 32][mint_b: 32]
+    // [wanted: 8][offered: 8][expires_at: 8][allowed_taker: 32][bump: 1]
+    let data = &account.data;
+    assert_eq!(data.len(), 8 + 8 + 32 * 3 + 8 * 3 + 32 + 1);
+    assert_eq!(u64::from_le_bytes(data[8..16].try_into().unwrap()), OFFER_ID);
+    assert_eq!(&data[16..48], market.maker.pubkey().as_ref());
+    assert_eq!(&data[48..80], market.mint_a.as_ref());
+    assert_eq!(&data[80..112], market.mint_b.as_ref());
+    assert_eq!(
+        u64::from_le_bytes(data[112..120].try_into().unwrap()),
+        TOKEN_B_WANTED_AMOUNT
+    );
+    assert_eq!(
+        u64::from_le_bytes(data[120..128].try_into().unwrap()),
+        TOKEN_A_OFFER_AMOUNT
+    );
+    assert_eq!(&data[136..168], market.taker.pubkey().as_ref());
+}
+
+
+// Test 10: Feature matrix - vuln-missing-expiry-check
+//
+// Same toggle as p-secure: the taker arrives after the deadline
+struct ExpiredTakeFeature;
+
+impl DifferentialScenario for ExpiredTakeFeature {
+    type State = Market;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Expired Offer Taken - Expiration Never Enforced",
+            severity: Severity::High,
+            lesson: "Reject TakeOffer once the clock is past the offer's expires_at",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, _program_id: &Pubkey) -> Market {
+        let market = open_offer(svm, &Escrow::Anchor, &TOKEN_PROGRAM_ID, false);
+        advance_time(svm, OFFER_DURATION_SECONDS as u64);
+        market
+    }
+
+    fn exploit(&self, svm: &mut LiteSVM, _program_id: &Pubkey, market: &mut Market) -> TransactionResult {
+        let ix = market.take_ix(&Escrow::Anchor, &market.taker.pubkey(), OFFER_ID);
+        send_ix(svm, ix, &market.taker)
+    }
+}
+
+#[test]
+fn test_feature_missing_expiry_check() {
+    let variant = FeatureVariant {
+        program_id: ANCHOR_PROGRAM_ID,
+        feature: "vuln-missing-expiry-check",
+    };
+    let (mut baseline, mut variant_svm) = variant.load(
+        "target/deploy/escrow_secure.so",
+        variant.so_path(".", "escrow_secure"),
+    );
+    run_feature_differential(&mut baseline, &mut variant_svm, &variant, &ExpiredTakeFeature);
+}
@@ -0,0 +1,397 @@
+// Test utilities for the Anchor escrow port
+//
+// Both escrows are loaded into one LiteSVM so every scenario can run against
+// pino-escrow p-secure and a-secure with the same accounts and amounts.
+// Escrow picks the instruction builders for one implementation:
+// - Pinocchio: soteria_client::escrow (1-byte discriminator, #[repr(C)] data)
+// - Anchor: built here (8-byte sighash, Borsh data, Anchor account order)
+
+use litesvm::LiteSVM;
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+use soteria_client::{
+    escrow::{self as pino_escrow, instructions as pino_ix},
+    token::{associated_token_address, ASSOCIATED_TOKEN_PROGRAM_ID},
+};
+use soteria_test_kit::*;
+
+// Program ID matching declare_id!
+pub const ANCHOR_PROGRAM_ID: Pubkey = Pubkey::new_from_array(escrow_secure::ID.to_bytes());
+pub const PINOCCHIO_PROGRAM_ID: Pubkey = pino_escrow::PROGRAM_ID;
+
+// Standard program IDs
+pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = spl_token_2022_interface::ID;
+
+// PDA Seeds
+pub const OFFER_SEED: &[u8] = b"offer";
+
+// Token configuration (same as the p-secure tests)
+pub const DECIMALS: u8 = 9;
+pub const INITIAL_MINT_AMOUNT: u64 = 1_000_000_000_000; // 1000 tokens
+pub const TOKEN_A_OFFER_AMOUNT: u64 = 100_000_000_000; // 100 tokens
+pub const TOKEN_B_WANTED_AMOUNT: u64 = 50_000_000_000; // 50 tokens
+pub const OFFER_DURATION_SECONDS: i64 = 60 * 60;
+
+// Load both escrows
+// p-secure must be built first: (cd ../../pino-escrow/p-secure && cargo build-sbf)
+pub fn setup_svm() -> LiteSVM {
+    ProgramHarness::builder()
+        .program_file(ANCHOR_PROGRAM_ID, "target/deploy/escrow_secure.so")
+        .program_file(
+            PINOCCHIO_PROGRAM_ID,
+            "../../pino-escrow/p-secure/target/deploy/secure.so",
+        )
+        .build()
+        .into_svm()
+}
+
+pub fn current_timestamp(svm: &LiteSVM) -> i64 {
+    svm.get_sysvar::<Clock>().unix_timestamp
+}
+
+// Read a token account balance from raw bytes (amount at offset 64)
+// Works for Token-2022 accounts with extensions; 0 if the account is closed
+pub fn token_amount(svm: &LiteSVM, address: &Pubkey) -> u64 {
+    svm.get_account(address)
+        .filter(|account| account.data.len() >= 72)
+        .map(|account| u64::from_le_bytes(account.data[64..72].try_into().unwrap()))
+        .unwrap_or_default()
+}
+
+pub fn account_exists(svm: &LiteSVM, address: &Pubkey) -> bool {
+    svm.get_account(address)
+        .map(|account| account.lamports > 0)
+        .unwrap_or(false)
+}
+
+// ======================== IMPLEMENTATIONS ========================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Escrow {
+    Pinocchio,
+    Anchor,
+}
+
+pub const IMPLEMENTATIONS: [(&str, Escrow); 2] =
+    [("pinocchio", Escrow::Pinocchio), ("anchor", Escrow::Anchor)];
+
+impl Escrow {
+    pub fn program_id(&self) -> Pubkey {
+        match self {
+            Escrow::Pinocchio => PINOCCHIO_PROGRAM_ID,
+            Escrow::Anchor => ANCHOR_PROGRAM_ID,
+        }
+    }
+
+    // Seeds: ["offer", maker, id] for both implementations
+    pub fn offer_address(&self, maker: &Pubkey, offer_id: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[OFFER_SEED, maker.as_ref(), &offer_id.to_le_bytes()],
+            &self.program_id(),
+        )
+        .0
+    }
+
+    pub fn vault_address(
+        &self,
+        maker: &Pubkey,
+        offer_id: u64,
+        token_mint_a: &Pubkey,
+        token_program: &Pubkey,
+    ) -> Pubkey {
+        associated_token_address(&self.offer_address(maker, offer_id), token_mint_a, token_program)
+    }
+
+    // Pass Pubkey::default() as allowed_taker for a public offer
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose_offer(
+        &self,
+        maker: &Pubkey,
+        token_mint_a: &Pubkey,
+        token_mint_b: &Pubkey,
+        offer_id: u64,
+        token_b_wanted_amount: u64,
+        token_a_offered_amount: u64,
+        expires_at: i64,
+        allowed_taker: &Pubkey,
+        token_program: &Pubkey,
+    ) -> Instruction {
+        match self {
+            Escrow::Pinocchio => pino_ix::propose_offer(
+                maker,
+                token_mint_a,
+                token_mint_b,
+                offer_id,
+                token_b_wanted_amount,
+                token_a_offered_amount,
+                expires_at,
+                allowed_taker,
+                token_program,
+            ),
+            Escrow::Anchor => {
+                let offer = self.offer_address(maker, offer_id);
+                let mut data = anchor_discriminator("propose_offer").to_vec();
+                data.extend_from_slice(&offer_id.to_le_bytes());
+                data.extend_from_slice(&token_b_wanted_amount.to_le_bytes());
+                data.extend_from_slice(&token_a_offered_amount.to_le_bytes());
+                data.extend_from_slice(&expires_at.to_le_bytes());
+                data.extend_from_slice(allowed_taker.as_ref());
+
+                Instruction {
+                    program_id: ANCHOR_PROGRAM_ID,
+                    accounts: vec![
+                        AccountMeta::new(*maker, true),
+                        AccountMeta::new_readonly(*token_mint_a, false),
+                        AccountMeta::new_readonly(*token_mint_b, false),
+                        AccountMeta::new(
+                            associated_token_address(maker, token_mint_a, token_program),
+                            false,
+                        ),
+                        AccountMeta::new(offer, false),
+                        AccountMeta::new(
+                            associated_token_address(&offer, token_mint_a, token_program),
+                            false,
+                        ),
+                        AccountMeta::new_readonly(*token_program, false),
+                        AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    ],
+                    data,
+                }
+            }
+        }
+    }
+
+    pub fn take_offer(
+        &self,
+        taker: &Pubkey,
+        proposer: &Pubkey,
+        token_mint_a: &Pubkey,
+        token_mint_b: &Pubkey,
+        offer_id: u64,
+        token_program: &Pubkey,
+    ) -> Instruction {
+        match self {
+            Escrow::Pinocchio => pino_ix::take_offer(
+                taker,
+                proposer,
+                token_mint_a,
+                token_mint_b,
+                offer_id,
+                token_program,
+            ),
+            Escrow::Anchor => {
+                let offer = self.offer_address(proposer, offer_id);
+
+                Instruction {
+                    program_id: ANCHOR_PROGRAM_ID,
+                    accounts: vec![
+                        AccountMeta::new(*taker, true),
+                        AccountMeta::new(*proposer, false),
+                        AccountMeta::new(
+                            associated_token_address(proposer, token_mint_b, token_program),
+                            false,
+                        ),
+                        AccountMeta::new_readonly(*token_mint_b, false),
+                        AccountMeta::new_readonly(*token_mint_a, false),
+                        AccountMeta::new(
+                            associated_token_address(taker, token_mint_a, token_program),
+                            false,
+                        ),
+                        AccountMeta::new(
+                            associated_token_address(taker, token_mint_b, token_program),
+                            false,
+                        ),
+                        AccountMeta::new(offer, false),
+                        AccountMeta::new(
+                            associated_token_address(&offer, token_mint_a, token_program),
+                            false,
+                        ),
+                        AccountMeta::new_readonly(*token_program, false),
+                        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                        AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                        AccountMeta::new(
+                            associated_token_address(proposer, token_mint_a, token_program),
+                            false,
+                        ),
+                    ],
+                    data: anchor_discriminator("take_offer").to_vec(),
+                }
+            }
+        }
+    }
+
+    pub fn refund_offer(
+        &self,
+        maker: &Pubkey,
+        token_mint_a: &Pubkey,
+        offer_id: u64,
+        token_program: &Pubkey,
+    ) -> Instruction {
+        match self {
+            Escrow::Pinocchio => pino_ix::refund_offer(maker, token_mint_a, offer_id, token_program),
+            Escrow::Anchor => {
+                let offer = self.offer_address(maker, offer_id);
+
+                Instruction {
+                    program_id: ANCHOR_PROGRAM_ID,
+                    accounts: vec![
+                        AccountMeta::new(*maker, true),
+                        AccountMeta::new_readonly(*token_mint_a, false),
+                        AccountMeta::new(
+                            associated_token_address(maker, token_mint_a, token_program),
+                            false,
+                        ),
+                        AccountMeta::new(offer, false),
+                        AccountMeta::new(
+                            associated_token_address(&offer, token_mint_a, token_program),
+                            false,
+                        ),
+                        AccountMeta::new_readonly(*token_program, false),
+                        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    ],
+                    data: anchor_discriminator("refund_offer").to_vec(),
+                }
+            }
+        }
+    }
+}
+
+// ======================== MARKET ========================
+
+// Fresh mints and funded participants for one run of a scenario
+// Maker holds Token A; taker and outsider hold Token B and have a Token A ATA
+// mint_authority can mint either token, e.g. extra Token A into a vault
+pub struct Market {
+    pub token_program: Pubkey,
+    pub mint_authority: Keypair,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub maker: Keypair,
+    pub taker: Keypair,
+    pub outsider: Keypair,
+}
+
+impl Market {
+    pub fn new(svm: &mut LiteSVM, token_program: &Pubkey) -> Self {
+        let payer = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let maker = create_funded_account(svm, 5 * LAMPORTS_PER_SOL);
+        let taker = create_funded_account(svm, 5 * LAMPORTS_PER_SOL);
+        let outsider = create_funded_account(svm, 5 * LAMPORTS_PER_SOL);
+
+        let mint_a = CreateMint::new(svm, &payer)
+            .decimals(DECIMALS)
+            .token_program_id(token_program)
+            .send()
+            .expect("Failed to create mint A");
+        let mint_b = CreateMint::new(svm, &payer)
+            .decimals(DECIMALS)
+            .token_program_id(token_program)
+            .send()
+            .expect("Failed to create mint B");
+
+        let maker_ata_a = CreateAssociatedTokenAccount::new(svm, &payer, &mint_a)
+            .owner(&maker.pubkey())
+            .token_program_id(token_program)
+            .send()
+            .expect("Failed to create maker ATA A");
+        MintTo::new(svm, &payer, &mint_a, &maker_ata_a, INITIAL_MINT_AMOUNT)
+            .token_program_id(token_program)
+            .send()
+            .expect("Failed to mint Token A");
+
+        for owner in [taker.pubkey(), outsider.pubkey()] {
+            CreateAssociatedTokenAccount::new(svm, &payer, &mint_a)
+                .owner(&owner)
+                .token_program_id(token_program)
+                .send()
+                .expect("Failed to create ATA A");
+            let ata_b = CreateAssociatedTokenAccount::new(svm, &payer, &mint_b)
+                .owner(&owner)
+                .token_program_id(token_program)
+                .send()
+                .expect("Failed to create ATA B");
+            MintTo::new(svm, &payer, &mint_b, &ata_b, INITIAL_MINT_AMOUNT)
+                .token_program_id(token_program)
+                .send()
+                .expect("Failed to mint Token B");
+        }
+
+        Self {
+            token_program: *token_program,
+            mint_authority: payer,
+            mint_a,
+            mint_b,
+            maker,
+            taker,
+            outsider,
+        }
+    }
+
+    pub fn ata(&self, owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+        associated_token_address(owner, mint, &self.token_program)
+    }
+
+    // Standard offer: TOKEN_A_OFFER_AMOUNT for TOKEN_B_WANTED_AMOUNT
+    pub fn propose_ix(
+        &self,
+        escrow: &Escrow,
+        offer_id: u64,
+        expires_at: i64,
+        allowed_taker: &Pubkey,
+    ) -> Instruction {
+        escrow.propose_offer(
+            &self.maker.pubkey(),
+            &self.mint_a,
+            &self.mint_b,
+            offer_id,
+            TOKEN_B_WANTED_AMOUNT,
+            TOKEN_A_OFFER_AMOUNT,
+            expires_at,
+            allowed_taker,
+            &self.token_program,
+        )
+    }
+
+    pub fn take_ix(&self, escrow: &Escrow, taker: &Pubkey, offer_id: u64) -> Instruction {
+        escrow.take_offer(
+            taker,
+            &self.maker.pubkey(),
+            &self.mint_a,
+            &self.mint_b,
+            offer_id,
+            &self.token_program,
+        )
+    }
+
+    pub fn refund_ix(&self, escrow: &Escrow, offer_id: u64) -> Instruction {
+        escrow.refund_offer(&self.maker.pubkey(), &self.mint_a, offer_id, &self.token_program)
+    }
+
+    // Token balances compared across implementations:
+    // [maker A, maker B, taker A, taker B, outsider A, vault A]
+    pub fn balances(&self, svm: &LiteSVM, escrow: &Escrow, offer_id: u64) -> Vec<u64> {
+        let maker = self.maker.pubkey();
+        let taker = self.taker.pubkey();
+        let outsider = self.outsider.pubkey();
+        vec![
+            token_amount(svm, &self.ata(&maker, &self.mint_a)),
+            token_amount(svm, &self.ata(&maker, &self.mint_b)),
+            token_amount(svm, &self.ata(&taker, &self.mint_a)),
+            token_amount(svm, &self.ata(&taker, &self.mint_b)),
+            token_amount(svm, &self.ata(&outsider, &self.mint_a)),
+            token_amount(
+                svm,
+                &escrow.vault_address(&maker, offer_id, &self.mint_a, &self.token_program),
+            ),
+        ]
+    }
+}
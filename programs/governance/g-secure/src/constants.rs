@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::Rank;
+
 // PDA Seeds
 //
 // These seeds are used to derive Program Derived Addresses (PDAs)
@@ -14,6 +16,20 @@ pub const USERPROFILE: &[u8] = b"user_profile";
 pub const USER_REGISTRY: &[u8] = b"user_registry";
 pub const VOTE_COOLDOWN: &[u8] = b"cooldown";
 pub const VOTE_RECORD: &[u8] = b"vote_record";
+pub const PROPOSAL: &[u8] = b"proposal";
+pub const BALLOT: &[u8] = b"ballot";
+pub const UNBONDING_TICKET: &[u8] = b"unbonding";
+pub const DELEGATION: &[u8] = b"delegation";
+pub const VOTE_HISTORY: &[u8] = b"vote_history";
+pub const SEASON_SNAPSHOT: &[u8] = b"season_snapshot";
+pub const SUPPORTED_MINT: &[u8] = b"supported_mint";
+pub const MINT_STAKE: &[u8] = b"mint_stake";
+pub const MULTISIG_ACTION: &[u8] = b"multisig_action";
+pub const MULTISIG_EXECUTOR: &[u8] = b"multisig_executor";
+pub const COUNCIL: &[u8] = b"council";
+pub const VOTE_ESCROW: &[u8] = b"vote_escrow";
+pub const TREASURY_HOLDING: &[u8] = b"treasury_holding";
+pub const PROPOSAL_DEPOSIT: &[u8] = b"proposal_deposit";
 
 // Account Space Constants
 pub const ANCHOR_DISCRIMINATOR: usize = 8;
@@ -27,6 +43,14 @@ pub const ANCHOR_DISCRIMINATOR: usize = 8;
 pub const MIN_USERNAME_LENGTH: usize = 3;
 pub const MAX_USERNAME_LENGTH: usize = 32;
 
+// Profile Metadata URI
+//
+// SECURITY: Bounds the avatar/metadata link stored on a profile
+// The profile is resized to fit the URI, so the cap bounds its size
+// Only schemes a front-end can fetch safely are accepted (no javascript:, data:)
+pub const MAX_METADATA_URI_LENGTH: usize = 200;
+pub const METADATA_URI_SCHEMES: [&str; 2] = ["https://", "ipfs://"];
+
 // Reputation System Limits
 //
 // SECURITY: Prevents reputation manipulation attacks
@@ -36,4 +60,112 @@ pub const REPUTATION_FLOOR: i64 = -1000;
 pub const REPUTATION_MEMBER_CAP: i64 = 50;
 pub const REPUTATION_BRONZE_CAP: i64 = 100;
 pub const REPUTATION_CONTRIBUTOR_CAP: i64 = 200;
-pub const REPUTATION_GUARDIAN_CAP: i64 = 400;
\ No newline at end of file
+pub const REPUTATION_GUARDIAN_CAP: i64 = 400;
+
+// Rank Gates
+//
+// SECURITY: Minimum rank for each gated action, checked with require_rank!
+// Delegation is gated like ballots since delegated power is only ever cast as ballots
+pub const MIN_DOWNVOTE_RANK: Rank = Rank::Bronze;
+pub const MIN_BALLOT_RANK: Rank = Rank::Member;
+
+// Proposal Constraints
+//
+// SECURITY: Bounds proposal metadata and the voting window
+// Thresholds are expressed in basis points (10_000 = 100%)
+// A fixed voting period gives every staker the same chance to vote
+pub const MAX_PROPOSAL_TITLE_LENGTH: usize = 64;
+pub const PROPOSAL_VOTING_PERIOD_SECONDS: i64 = 3 * 24 * 3600;
+pub const MAX_BASIS_POINTS: u64 = 10_000;
+
+// Reputation Decay
+//
+// SECURITY: Bounds the work done by a single decay call
+// Profiles idle for longer catch up over several calls
+pub const MAX_DECAY_EPOCHS_PER_CALL: i64 = 64;
+
+// Reward Emissions
+//
+// SECURITY: Reward rate is expressed per REWARD_RATE_SCALE staked base units
+// Products are computed in u128 so large stakes cannot overflow
+pub const REWARD_RATE_SCALE: u64 = 1_000_000_000;
+
+// Vote History
+//
+// SECURITY: History accounts start small and grow by realloc
+// Growth stops at VOTE_HISTORY_MAX_ENTRIES, after which the oldest entry
+// is overwritten so account size (and rent) stays bounded
+pub const VOTE_HISTORY_INITIAL_CAPACITY: usize = 4;
+pub const VOTE_HISTORY_GROWTH_STEP: usize = 4;
+pub const VOTE_HISTORY_MAX_ENTRIES: usize = 32;
+
+// Season Standings
+//
+// SECURITY: Snapshot keeps a fixed number of entries so its size is bounded
+pub const SEASON_TOP_N: usize = 10;
+
+// Supported Mints
+//
+// SECURITY: Weights are basis points of the base mint (10_000 = 1x)
+// The cap stops a single registered mint from dwarfing the base token
+pub const MAX_MINT_WEIGHT_BPS: u64 = 50_000;
+
+// Multisig Interface
+//
+// SECURITY: The executor PDA signs the CPI, so the target program is pinned.
+// Any other program handed that signature could forward it to the real multisig.
+pub const MULTISIG_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("HH8rYFiTjMX8FiiRgiFQx1jnXdT9D4TTiC5mSBhe9r7P");
+
+// Anchor discriminator of the multisig's create_proposal instruction
+// First 8 bytes of sha256("global:create_proposal")
+pub const MULTISIG_CREATE_PROPOSAL_DISCRIMINATOR: [u8; 8] = [132, 116, 68, 174, 216, 160, 198, 22];
+
+// Size of the multisig's Proposal account as created (Proposal::space(0, 0) there)
+// The executor is topped up with exactly this much rent before each CPI
+// Taken from the client mirror, which the multisig's own tests pin to its layout
+pub const MULTISIG_PROPOSAL_SPACE: usize = soteria_client::multisig::PROPOSAL_SPACE;
+
+// Vote Batches
+//
+// SECURITY: Each entry is authorised by its own Ed25519 instruction placed
+// directly before submit_vote_batch; the cap bounds the work per call
+pub const MAX_VOTE_BATCH_SIZE: usize = 8;
+
+// Signed vote: proposal (32) + support (1) + weight (8)
+pub const VOTE_MESSAGE_LEN: usize = 41;
+
+// Ed25519 precompile instruction data
+// [num_signatures: u8, padding: u8, then 7 u16 offsets per signature]
+pub const ED25519_OFFSETS_START: usize = 2;
+pub const ED25519_OFFSETS_SIZE: usize = 14;
+
+// Instruction index the precompile reads as "this instruction"
+pub const ED25519_CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+// Emergency Council
+//
+// SECURITY: Bounds the member list so the council account size is fixed
+// The veto window is capped so a council cannot hold proposals back forever
+pub const MAX_COUNCIL_MEMBERS: usize = 7;
+pub const MAX_VETO_WINDOW_SECONDS: i64 = 30 * 24 * 3600;
+
+// Vote Escrow
+//
+// SECURITY: Locked power decays linearly to zero at the unlock time:
+//   amount * (unlock_at - now) / MAX_LOCK_SECONDS
+// A maximum-length lock counts 1x; the minimum stops dust-length locks
+pub const MIN_LOCK_SECONDS: i64 = 7 * 24 * 3600;
+pub const MAX_LOCK_SECONDS: i64 = 4 * 365 * 24 * 3600;
+
+// Treasury Portfolio
+//
+// SECURITY: The cap bounds the accounts a value report must pass (4 per holding)
+// Values are reported in TREASURY_VALUE_DECIMALS fixed point of the feeds' quote
+// unit, so holdings with different decimals add up to one total
+pub const MAX_TREASURY_HOLDINGS: u16 = 8;
+pub const TREASURY_VALUE_DECIMALS: u32 = 6;
+
+// Anchor discriminator of the oracle's PriceFeed account
+// First 8 bytes of sha256("account:PriceFeed")
+pub const PRICE_FEED_DISCRIMINATOR: [u8; 8] = [189, 103, 252, 23, 152, 35, 243, 156];
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+pub use soteria_errors::g_secure::GovernanceError as GovernanceErrorCode;
+
 #[error_code]
 pub enum GovernanceError {
     // Stake-related errors
@@ -76,4 +78,199 @@ pub enum GovernanceError {
     
     #[msg("Account already initialized")]
     AccountAlreadyInitialized,
-}
\ No newline at end of file
+
+    // Proposal errors
+    #[msg("Quorum must be non-zero and threshold between 1 and 10,000 basis points")]
+    InvalidGovernanceParams,
+
+    #[msg("Proposal title must be between 1 and 64 characters")]
+    InvalidProposalTitle,
+
+    #[msg("Proposal is not active")]
+    ProposalNotActive,
+
+    #[msg("Voting period has ended")]
+    VotingPeriodEnded,
+
+    #[msg("Voting period is still active")]
+    VotingPeriodActive,
+
+    #[msg("Stake must be in place before the proposal was created")]
+    StakeAfterProposal,
+
+    // Unbonding errors
+    #[msg("Unbonding period must be greater than zero")]
+    InvalidUnbondingPeriod,
+
+    #[msg("Unbonding period has not elapsed")]
+    UnbondingPeriodActive,
+
+    #[msg("No unbonded tokens to claim")]
+    NothingToClaim,
+
+    // Delegation errors
+    #[msg("Cannot delegate votes to yourself")]
+    CannotDelegateToSelf,
+
+    #[msg("Delegation chains and cycles are not allowed")]
+    DelegationChainNotAllowed,
+
+    #[msg("Revoke your delegation before unstaking")]
+    StakeDelegated,
+
+    // Reputation decay errors
+    #[msg("Invalid reputation decay parameters")]
+    InvalidDecayParams,
+
+    #[msg("No full decay epoch has elapsed")]
+    DecayNotDue,
+
+    // Reward errors
+    #[msg("No staking rewards to claim")]
+    NoRewardsToClaim,
+
+    #[msg("Treasury reward reserve is insufficient")]
+    InsufficientRewardReserve,
+
+    // Profile management errors
+    #[msg("New username must differ from the current one")]
+    UsernameUnchanged,
+
+    #[msg("Unstake all tokens before closing the profile")]
+    ProfileHasStake,
+
+    #[msg("Revoke delegations before closing the profile")]
+    ProfileHasDelegations,
+
+    // Admin transfer errors
+    #[msg("Signer is not the pending admin")]
+    NotPendingAdmin,
+
+    // Slashing errors
+    #[msg("Slash must be between 1 and 10,000 basis points of a non-zero stake")]
+    InvalidSlashAmount,
+
+    #[msg("Delegation accounts are required to slash delegated stake")]
+    MissingDelegationAccounts,
+
+    // Season errors
+    #[msg("Season has not been closed yet")]
+    SeasonNotFinalized,
+
+    #[msg("Season has already been closed")]
+    SeasonAlreadyFinalized,
+
+    #[msg("User is not in the season standings")]
+    NotInSeasonStandings,
+
+    #[msg("Season reward already claimed")]
+    SeasonRewardClaimed,
+
+    // Supported mint errors
+    #[msg("Mint weight must be between 1 and 50,000 basis points")]
+    InvalidMintWeight,
+
+    #[msg("Supported-mint stakes need both the registry entry and the stake record")]
+    MissingMintStakeAccount,
+
+    // Multisig action errors
+    #[msg("Multisig actions must be attached before any ballot is cast")]
+    BallotsAlreadyCast,
+
+    #[msg("Proposal has not passed")]
+    ProposalNotPassed,
+
+    #[msg("Multisig action has already been executed")]
+    MultisigActionAlreadyExecuted,
+
+    #[msg("Multisig account does not match the attached action")]
+    InvalidMultisigAccount,
+
+    #[msg("Multisig program does not match MULTISIG_PROGRAM_ID")]
+    InvalidMultisigProgram,
+
+    // Vote batch errors
+    #[msg("Vote batch must hold between 1 and MAX_VOTE_BATCH_SIZE entries")]
+    InvalidVoteBatchSize,
+
+    #[msg("Each batch entry needs its voter profile and ballot account")]
+    MissingBatchAccounts,
+
+    #[msg("Entry is not verified by a matching Ed25519 instruction")]
+    InvalidSignatureInstruction,
+
+    #[msg("Signed key or message does not match the batch entry")]
+    SignatureMismatch,
+
+    #[msg("Vote weight must be non-zero and within the voter's power")]
+    InvalidVoteWeight,
+
+    #[msg("Ballot account does not match the voter and proposal")]
+    InvalidBallotAccount,
+
+    #[msg("Stake ramp period cannot be negative")]
+    InvalidStakeRamp,
+
+    // Vote account cleanup errors
+    #[msg("Vote account belongs to the current season")]
+    VoteSeasonNotOver,
+
+    // Emergency council errors
+    #[msg("Council needs 1 to MAX_COUNCIL_MEMBERS distinct members, a threshold within that count and a positive veto window")]
+    InvalidCouncilConfig,
+
+    #[msg("Signer is not a council member")]
+    NotCouncilMember,
+
+    #[msg("Not enough council members signed")]
+    InsufficientCouncilSignatures,
+
+    #[msg("Veto window has closed for this proposal")]
+    VetoWindowClosed,
+
+    #[msg("Proposal is still within the council veto window")]
+    VetoWindowActive,
+
+    // Rank gate errors
+    #[msg("Profile rank is too low for this action")]
+    RankTooLow,
+
+    // Vote escrow errors
+    #[msg("Lock duration must be between MIN_LOCK_SECONDS and MAX_LOCK_SECONDS")]
+    InvalidLockDuration,
+
+    #[msg("Lock has already expired")]
+    LockExpired,
+
+    #[msg("New unlock time must be later than the current one")]
+    LockNotExtended,
+
+    #[msg("Lock has not expired yet")]
+    LockNotExpired,
+
+    // Treasury portfolio errors
+    #[msg("Treasury already tracks MAX_TREASURY_HOLDINGS holdings")]
+    TooManyTreasuryHoldings,
+
+    #[msg("Price feed is not a PriceFeed account of the given oracle program")]
+    InvalidPriceFeed,
+
+    #[msg("Oracle price has never been published")]
+    OraclePriceUnavailable,
+
+    #[msg("Oracle price is older than the feed allows")]
+    StaleOraclePrice,
+
+    #[msg("Oracle confidence interval is wider than the feed allows")]
+    OracleConfidenceTooWide,
+
+    #[msg("Report needs every registered holding in index order, each with its token account, price feed and supported mint")]
+    IncompleteTreasuryReport,
+
+    // Profile metadata errors
+    #[msg("Metadata URI exceeds MAX_METADATA_URI_LENGTH")]
+    MetadataUriTooLong,
+
+    #[msg("Metadata URI must be an https:// or ipfs:// link without spaces or control characters")]
+    InvalidMetadataUri,
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ProposalStatus;
+
+// Stake Slashed Event
+//
+// Emitted by slash_stake so off-chain monitors can audit penalties
+// amount is the number of base units removed from the user's stake
+#[event]
+pub struct StakeSlashed {
+    pub user: Pubkey,
+    pub slashed_by: Pubkey,
+    pub slash_bps: u16,
+    pub amount: u64,
+    pub remaining_stake: u64,
+    pub burned: bool,
+    pub timestamp: i64,
+}
+
+// Multisig Action Executed Event
+//
+// Emitted by execute_multisig_action when a passed proposal is relayed
+// multisig_proposal is the proposal the executor PDA opened on the multisig
+#[event]
+pub struct MultisigActionExecuted {
+    pub proposal: Pubkey,
+    pub multisig: Pubkey,
+    pub multisig_proposal: Pubkey,
+    pub executed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+// Council Initialized Event
+//
+// Emitted by init_council with the emergency keys and their threshold
+#[event]
+pub struct CouncilInitialized {
+    pub config: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub veto_window: i64,
+    pub timestamp: i64,
+}
+
+// Council Pause Set Event
+//
+// Emitted by council_set_pause; signers are the council members counted
+#[event]
+pub struct CouncilPauseSet {
+    pub config: Pubkey,
+    pub paused: bool,
+    pub signers: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+// Proposal Vetoed Event
+//
+// Emitted by council_veto; previous_status is Active or Passed
+#[event]
+pub struct ProposalVetoed {
+    pub config: Pubkey,
+    pub proposal: Pubkey,
+    pub previous_status: ProposalStatus,
+    pub signers: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+// Proposal Deposit Settled Event
+//
+// Emitted by finalize_proposal; refunded is false when the deposit was burned
+// amount is the full balance settled, in base units
+#[event]
+pub struct ProposalDepositSettled {
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub amount: u64,
+    pub refunded: bool,
+    pub timestamp: i64,
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Accept Admin Instruction
+//
+// Second step of a two-step admin transfer
+// The proposed admin signs to take over the role
+//
+// SECURITY FEATURES:
+// - Only the pending admin can accept (signature required)
+// - Proves the new admin controls the key before the role moves
+// - Pending slot cleared so the acceptance cannot be replayed
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    // Pending admin
+    // Must match config.pending_admin
+    pub new_admin: Signer<'info>,
+
+    // Admin pubkey for PDA derivation
+    /// CHECK: Used for config PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Signer must be the recorded pending admin
+    #[account(
+        mut,
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump = config.config_bump,
+        constraint = config.pending_admin == new_admin.key() @ GovernanceError::NotPendingAdmin
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    pub fn accept_admin(&mut self) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Transfer Admin Role
+        // SECURITY: Pending slot cleared so the transfer completes exactly once
+        let config = &mut self.config;
+        let previous_admin = config.admin;
+        config.admin = self.new_admin.key();
+        config.pending_admin = Pubkey::default();
+
+        msg!("Admin transferred: {} -> {}", previous_admin, config.admin);
+
+        Ok(())
+    }
+}
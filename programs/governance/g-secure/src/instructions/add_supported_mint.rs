@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Add Supported Mint Instruction
+//
+// Admin-only registration of an additional stakeable token
+// Creates the registry entry with its voting weight and the treasury ATA
+// that will hold this mint's staked tokens
+//
+// SECURITY FEATURES:
+// - Admin-only access (signer checked against config.admin)
+// - Weight bounded to 1-50,000 basis points (up to 5x the base mint)
+// - Base mint cannot be re-registered with a different weight
+// - Registry PDA uses 'init', so a mint's weight cannot be overwritten
+// - Treasury ATA owned by the treasury authority PDA
+// - System pause check
+
+#[derive(Accounts)]
+pub struct AddSupportedMint<'info> {
+    // Admin authority
+    // Must be the current config.admin, pays for the new accounts
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Admin pubkey for PDA derivation
+    /// CHECK: Used for config and treasury PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Validates the signer against the current admin
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump = config.config_bump,
+        constraint = config.admin == authority.key() @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    // Treasury state PDA
+    // Seeds: ["treasury", admin]
+    // Provides the treasury authority bump
+    #[account(
+        seeds = [TREASURY, admin.key().as_ref()],
+        bump = treasury.state_bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    // Treasury authority PDA
+    // Seeds: ["treasury_auth", config, admin]
+    // SECURITY: Owns the new treasury ATA
+    #[account(
+        seeds = [TREASURYAUTH, config.key().as_ref(), admin.key().as_ref()],
+        bump = treasury.vault_bump,
+    )]
+    /// CHECK: PDA authority for treasury token accounts
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    // Mint being registered
+    // SECURITY: The base mint always counts 1x and uses the primary treasury
+    #[account(
+        constraint = token_mint_account.key() != config.token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub token_mint_account: Account<'info, Mint>,
+
+    // Supported mint registry PDA
+    // Seeds: ["supported_mint", config, mint]
+    // SECURITY: 'init' fails if the mint is already registered
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + SupportedMint::INIT_SPACE,
+        seeds = [SUPPORTED_MINT, config.key().as_ref(), token_mint_account.key().as_ref()],
+        bump
+    )]
+    pub supported_mint: Account<'info, SupportedMint>,
+
+    // Treasury token account for this mint (ATA)
+    // Authority is treasury_authority PDA for security
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = treasury_authority,
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddSupportedMint<'info> {
+    pub fn add_supported_mint(
+        &mut self,
+        weight_bps: u16,
+        bumps: AddSupportedMintBumps,
+    ) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. System Pause Check
+        // Prevents registry changes during maintenance
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        // 2. Weight Validation
+        // SECURITY: A zero weight would accept tokens that never vote,
+        // an unbounded weight would let one mint outvote every other staker
+        require!(
+            weight_bps > 0 && weight_bps as u64 <= MAX_MINT_WEIGHT_BPS,
+            GovernanceError::InvalidMintWeight
+        );
+
+        // 3. Record Supported Mint
+        self.supported_mint.set_inner(SupportedMint {
+            config: self.config.key(),
+            mint: self.token_mint_account.key(),
+            weight_bps,
+            treasury_token_account: self.treasury_token_account.key(),
+            total_staked: 0,
+            bump: bumps.supported_mint,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Attach Multisig Action Instruction
+//
+// Binds a multisig proposal to a governance proposal
+// If the governance proposal passes, execute_multisig_action opens it on the
+// multisig with the DAO's executor PDA as proposer
+//
+// SECURITY FEATURES:
+// - Only the governance proposal's proposer can attach an action
+// - Must happen before any ballot is cast, so every vote covers the action
+// - One action per proposal (action PDA uses 'init')
+// - Target multisig fixed here, not chosen by whoever executes
+
+#[derive(Accounts)]
+pub struct AttachMultisigAction<'info> {
+    // Proposer account
+    // Pays for the action account
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    // Admin pubkey for config derivation
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Proposal PDA
+    // Seeds: ["proposal", config, proposal_id]
+    // SECURITY: Only the proposer may decide what the proposal does
+    #[account(
+        seeds = [PROPOSAL, config.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+        constraint = proposal.proposer == proposer.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    // Multisig Action PDA
+    // Seeds: ["multisig_action", proposal]
+    // SECURITY: 'init' prevents swapping the action after it is attached
+    #[account(
+        init,
+        payer = proposer,
+        space = ANCHOR_DISCRIMINATOR + MultisigAction::INIT_SPACE,
+        seeds = [MULTISIG_ACTION, proposal.key().as_ref()],
+        bump
+    )]
+    pub multisig_action: Account<'info, MultisigAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AttachMultisigAction<'info> {
+    pub fn attach_multisig_action(
+        &mut self,
+        multisig: Pubkey,
+        action: MultisigProposalType,
+        bumps: AttachMultisigActionBumps,
+    ) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Proposal Status Check
+        require!(
+            self.proposal.status == ProposalStatus::Active,
+            GovernanceError::ProposalNotActive
+        );
+
+        // 2. No Ballots Yet
+        // SECURITY: An action attached mid-vote would ride on ballots cast
+        // for the title alone
+        require!(
+            self.proposal.votes_for == 0 && self.proposal.votes_against == 0,
+            GovernanceError::BallotsAlreadyCast
+        );
+
+        // 3. Multisig Validation
+        // The multisig program checks the account itself when the action runs
+        require!(multisig != Pubkey::default(), GovernanceError::InvalidInstructionData);
+
+        // 4. Record Action
+        self.multisig_action.set_inner(MultisigAction {
+            proposal: self.proposal.key(),
+            multisig,
+            action,
+            executed: false,
+            bump: bumps.multisig_action,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, require_rank, state::*};
+
+// Cast Ballot Instruction
+//
+// Records a stake-weighted vote for or against a governance proposal
+// Ballot weight equals the voter's effective power (own + delegated stake)
+// plus the decayed power of their vote escrow, if one is passed
+//
+// SECURITY FEATURES:
+// - One ballot per voter per proposal (ballot PDA uses 'init')
+// - Stake snapshot: stake and locks must predate the proposal
+// - Voting only while the proposal is Active and inside the voting window
+// - Minimum stake requirement
+// - Minimum rank requirement
+// - System pause check
+// - Checked arithmetic for tallies
+
+#[derive(Accounts)]
+pub struct CastBallot<'info> {
+    // Voter account
+    // Pays for the ballot record
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    // Admin pubkey for config derivation
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Validates system state and minimum stake
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Voter's profile
+    // Seeds: ["user_profile", voter]
+    // SECURITY: Validates ownership, stake amount and stake timestamp
+    #[account(
+        seeds = [USERPROFILE, voter.key().as_ref()],
+        bump,
+        constraint = voter_profile.owner == voter.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub voter_profile: Account<'info, UserProfile>,
+
+    // Proposal PDA
+    // Seeds: ["proposal", config, proposal_id]
+    // SECURITY: Seeds tie the proposal to this DAO's config
+    #[account(
+        mut,
+        seeds = [PROPOSAL, config.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    // Ballot record
+    // Seeds: ["ballot", proposal, voter]
+    // SECURITY: 'init' fails if the voter already cast a ballot
+    // Prevents the same stake being counted twice
+    #[account(
+        init,
+        payer = voter,
+        space = ANCHOR_DISCRIMINATOR + Ballot::INIT_SPACE,
+        seeds = [BALLOT, proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub ballot: Account<'info, Ballot>,
+
+    // Voter's vote escrow (only when voting with locked tokens)
+    // Seeds: ["vote_escrow", config, voter]
+    // SECURITY: Seeds bind the lock to this voter and this DAO
+    #[account(
+        seeds = [VOTE_ESCROW, config.key().as_ref(), voter.key().as_ref()],
+        bump = vote_escrow.bump,
+    )]
+    pub vote_escrow: Option<Account<'info, VoteEscrow>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CastBallot<'info> {
+    pub fn cast_ballot(&mut self, support: bool, bumps: CastBallotBumps) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. System Pause Check
+        // Prevents voting while the system is paused
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        // 2. Proposal Status Check
+        // Finalized proposals cannot receive more ballots
+        require!(
+            self.proposal.status == ProposalStatus::Active,
+            GovernanceError::ProposalNotActive
+        );
+
+        // 3. Voting Window Check
+        // SECURITY: Ballots after the deadline would change a settled outcome
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time < self.proposal.voting_ends_at,
+            GovernanceError::VotingPeriodEnded
+        );
+
+        // 4. Minimum Stake Requirement
+        // SECURITY: Prevents sybil voting with dust stakes
+        // Stake delegated away no longer counts for the delegator
+        // Power is matured as of proposal creation, so stake flashed in just
+        // before the proposal counts for almost nothing
+        let mut weight = self
+            .voter_profile
+            .effective_power(self.proposal.created_at, self.config.stake_ramp_seconds)
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        // Locked tokens add their power as of proposal creation
+        // SECURITY: A lock made or extended after the proposal does not count,
+        // and a lock that had expired by then counts for nothing
+        if let Some(vote_escrow) = self.vote_escrow.as_ref() {
+            require!(
+                vote_escrow.locked_at < self.proposal.created_at,
+                GovernanceError::StakeAfterProposal
+            );
+            let escrow_power = vote_escrow
+                .voting_power(self.proposal.created_at)
+                .ok_or(GovernanceError::MathOverflow)?;
+            weight = weight
+                .checked_add(escrow_power)
+                .ok_or(GovernanceError::MathOverflow)?;
+        }
+
+        require!(
+            weight >= self.config.minimum_stake,
+            GovernanceError::InsufficientStake
+        );
+
+        // 5. Stake Snapshot Check
+        // SECURITY: Stake added after the proposal was created does not count
+        // Without this, one voter could vote, unstake, and restake the same
+        // tokens from another profile to vote again
+        require!(
+            self.voter_profile.last_stake_timestamp < self.proposal.created_at,
+            GovernanceError::StakeAfterProposal
+        );
+
+        // 6. Rank Check
+        // SECURITY: Same rank gate as submit_vote_batch and delegate_votes
+        require_rank!(self.voter_profile, MIN_BALLOT_RANK);
+
+        // 7. Apply Stake-Weighted Ballot
+        // SECURITY: Checked addition prevents tally overflow
+        let proposal = &mut self.proposal;
+        if support {
+            proposal.votes_for = proposal
+                .votes_for
+                .checked_add(weight)
+                .ok_or(GovernanceError::MathOverflow)?;
+        } else {
+            proposal.votes_against = proposal
+                .votes_against
+                .checked_add(weight)
+                .ok_or(GovernanceError::MathOverflow)?;
+        }
+
+        // 8. Record Ballot
+        // Stores the counted weight for auditability
+        self.ballot.set_inner(Ballot {
+            voter: self.voter.key(),
+            proposal: self.proposal.key(),
+            support,
+            weight,
+            timestamp: current_time,
+            bump: bumps.ballot,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Change Username Instruction
+//
+// Moves a profile to a new username in a single transaction
+// The old registry entry is closed (rent refunded) and the new one claimed
+//
+// SECURITY FEATURES:
+// - Old registry derived from the profile's current username
+// - Old registry must be owned by the signer (cannot free someone else's name)
+// - New username must be unclaimed and pass length validation
+// - Free and claim happen atomically (no window for squatters)
+
+#[derive(Accounts)]
+#[instruction(new_username: String)]
+pub struct ChangeUsername<'info> {
+    // Profile owner
+    // Pays for the new registry and receives the old registry rent
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // User profile PDA
+    // Seeds: ["user_profile", user]
+    // SECURITY: Validates ownership and stores the username
+    #[account(
+        mut,
+        seeds = [USERPROFILE, user.key().as_ref()],
+        bump,
+        constraint = user_profile.owner == user.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    // Current username registry PDA
+    // Seeds: ["user_registry", user_profile.username]
+    // SECURITY: Derived from the profile and owner-checked
+    // Closed so the old username becomes available again
+    #[account(
+        mut,
+        close = user,
+        seeds = [USER_REGISTRY, user_profile.username.as_bytes()],
+        bump = old_registry.bump,
+        constraint = old_registry.owner == user.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub old_registry: Account<'info, UsernameRegistry>,
+
+    // New username registry PDA
+    // Seeds: ["user_registry", new_username]
+    // SECURITY: init_if_needed allows checking if username is claimed
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR + UsernameRegistry::INIT_SPACE,
+        seeds = [USER_REGISTRY, new_username.as_bytes()],
+        bump
+    )]
+    pub new_registry: Account<'info, UsernameRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ChangeUsername<'info> {
+    pub fn change_username(
+        &mut self,
+        new_username: String,
+        bumps: ChangeUsernameBumps,
+    ) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Username Length Validation
+        // Same rules as create_profile
+        require!(
+            new_username.len() >= MIN_USERNAME_LENGTH && new_username.len() <= MAX_USERNAME_LENGTH,
+            GovernanceError::InvalidUsername
+        );
+
+        // 2. Username Must Change
+        // SECURITY: Old and new registry would be the same account
+        // Closing it at the end would leave the profile without a registry
+        require!(
+            new_username != self.user_profile.username,
+            GovernanceError::UsernameUnchanged
+        );
+
+        // 3. Username Uniqueness Check
+        // Verify the new username hasn't been claimed already
+        let new_registry = &mut self.new_registry;
+        if new_registry.claimed {
+            return err!(GovernanceError::UsernameAlreadyExists);
+        }
+        new_registry.claimed = true;
+        new_registry.owner = self.user.key();
+        new_registry.bump = bumps.new_registry;
+
+        // 4. Release Old Username
+        // Account is closed by the 'close' constraint after this handler
+        self.old_registry.claimed = false;
+
+        // 5. Update Profile
+        self.user_profile.username = new_username;
+
+        Ok(())
+    }
+}
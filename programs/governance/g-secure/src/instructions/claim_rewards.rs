@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Claim Rewards Instruction
+//
+// Pays out staking rewards accrued in proportion to stake * time
+// Rewards come from the treasury balance above staked and unbonding tokens
+//
+// SECURITY FEATURES:
+// - Rewards settled with u128 checked math (no overflow on large stakes)
+// - Reward reserve check (staked and unbonding principal never paid out)
+// - Profile ownership validation
+// - Treasury PDA authority signs withdrawals (no private keys)
+// - Token mint validation
+// - System pause check
+
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    // User claiming rewards
+    // Must own the profile
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Admin pubkey for PDA derivation
+    /// CHECK: Used for config and treasury PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Source of the reward rate, token mint and pause state
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Treasury state PDA
+    // Seeds: ["treasury", admin]
+    // SECURITY: Staked and unbonding totals bound the reward reserve
+    #[account(
+        seeds = [TREASURY, admin.key().as_ref()],
+        bump = treasury.state_bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    // Treasury authority PDA
+    // Seeds: ["treasury_auth", config, admin]
+    // SECURITY: PDA signer for treasury withdrawals
+    #[account(
+        seeds = [TREASURYAUTH, config.key().as_ref(), admin.key().as_ref()],
+        bump = treasury.vault_bump,
+    )]
+    /// CHECK: PDA authority for signing treasury transfers
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    // User profile PDA
+    // Seeds: ["user_profile", user]
+    // SECURITY: Validates ownership and tracks accrued rewards
+    #[account(
+        mut,
+        seeds = [USERPROFILE, user.key().as_ref()],
+        bump,
+        constraint = user_profile.owner == user.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    // Token mint account
+    // SECURITY: Must match config.token_mint
+    #[account(
+        address = config.token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub token_mint_account: Account<'info, Mint>,
+
+    // User's token account (destination)
+    // SECURITY: Validated as user's ATA for correct mint
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    // Treasury token account (source)
+    // SECURITY: Validated against treasury state
+    #[account(
+        mut,
+        address = treasury.treasury_token_account @ GovernanceError::InvalidTreasuryAccount
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimRewards<'info> {
+    pub fn claim_rewards(&mut self) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. System Pause Check
+        // Prevents withdrawals during maintenance
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        // 2. Settle Accrued Rewards
+        // SECURITY: u128 checked math in accrue_rewards
+        // A stake held for a long time cannot overflow the reward product
+        let current_time = Clock::get()?.unix_timestamp;
+        self.user_profile
+            .accrue_rewards(self.config.reward_rate_per_sec, current_time)
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        let amount = self.user_profile.reward_debt;
+
+        // 3. Reward Amount Check
+        // Prevents empty claims
+        require!(amount > 0, GovernanceError::NoRewardsToClaim);
+
+        // 4. Reward Reserve Check
+        // SECURITY: Only tokens above staked and unbonding principal are rewards
+        // Paying from principal would leave stakers unable to withdraw
+        let locked = self
+            .treasury
+            .total_staked
+            .checked_add(self.treasury.total_unbonding)
+            .ok_or(GovernanceError::MathOverflow)?;
+        let reserve = self
+            .treasury_token_account
+            .amount
+            .checked_sub(locked)
+            .ok_or(GovernanceError::InsufficientRewardReserve)?;
+        require!(reserve >= amount, GovernanceError::InsufficientRewardReserve);
+
+        // 5. Transfer Rewards from Treasury to User
+        // SECURITY: Uses PDA authority to sign the transfer
+        let config = self.config.key();
+        let admin = self.admin.key();
+        let treasury_auth_seeds = &[
+            TREASURYAUTH,
+            config.as_ref(),
+            admin.as_ref(),
+            &[self.treasury.vault_bump],
+        ];
+        let signer_seeds = &[&treasury_auth_seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.treasury_token_account.to_account_info(),
+                to: self.user_token_account.to_account_info(),
+                authority: self.treasury_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, amount)?;
+
+        // 6. Clear Accrued Rewards
+        self.user_profile.reward_debt = 0;
+
+        Ok(())
+    }
+}
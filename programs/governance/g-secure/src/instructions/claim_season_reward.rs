@@ -0,0 +1,186 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Claim Season Reward Instruction
+//
+// Pays a user's share of a closed season's reward pool
+// Share is proportional to the user's reputation in the season standings
+//
+// SECURITY FEATURES:
+// - Season must be finalized (pool and standings fixed)
+// - One claim per standings entry (claimed flag)
+// - Proportional payout with i128 checked math
+// - Reward reserve check (staked and unbonding principal never paid out)
+// - Treasury PDA authority signs withdrawals (no private keys)
+// - Token mint validation
+// - System pause check
+
+#[derive(Accounts)]
+#[instruction(season_id: u64)]
+pub struct ClaimSeasonReward<'info> {
+    // User claiming the reward
+    // Must appear in the season standings
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Admin pubkey for PDA derivation
+    /// CHECK: Used for config and treasury PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Validates token mint and pause state
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Treasury state PDA
+    // Seeds: ["treasury", admin]
+    // SECURITY: Staked and unbonding totals bound the reward reserve
+    #[account(
+        seeds = [TREASURY, admin.key().as_ref()],
+        bump = treasury.state_bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    // Treasury authority PDA
+    // Seeds: ["treasury_auth", config, admin]
+    // SECURITY: PDA signer for treasury withdrawals
+    #[account(
+        seeds = [TREASURYAUTH, config.key().as_ref(), admin.key().as_ref()],
+        bump = treasury.vault_bump,
+    )]
+    /// CHECK: PDA authority for signing treasury transfers
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    // Season snapshot PDA
+    // Seeds: ["season_snapshot", config, season_id]
+    // SECURITY: Standings and claimed flags for the requested season
+    #[account(
+        mut,
+        seeds = [SEASON_SNAPSHOT, config.key().as_ref(), &season_id.to_le_bytes()],
+        bump = season_snapshot.bump
+    )]
+    pub season_snapshot: Account<'info, SeasonSnapshot>,
+
+    // Token mint account
+    // SECURITY: Must match config.token_mint
+    #[account(
+        address = config.token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub token_mint_account: Account<'info, Mint>,
+
+    // User's token account (destination)
+    // SECURITY: Validated as user's ATA for correct mint
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    // Treasury token account (source)
+    // SECURITY: Validated against treasury state
+    #[account(
+        mut,
+        address = treasury.treasury_token_account @ GovernanceError::InvalidTreasuryAccount
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimSeasonReward<'info> {
+    pub fn claim_season_reward(&mut self, season_id: u64) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. System Pause Check
+        // Prevents withdrawals during maintenance
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        // 2. Finalized Check
+        // SECURITY: Standings can still change while the season is open
+        require!(
+            self.season_snapshot.finalized,
+            GovernanceError::SeasonNotFinalized
+        );
+
+        // 3. Standings Lookup
+        let user = self.user.key();
+        let index = self
+            .season_snapshot
+            .entries
+            .iter()
+            .position(|e| e.user == user)
+            .ok_or(GovernanceError::NotInSeasonStandings)?;
+
+        // 4. Double Claim Check
+        // SECURITY: Each entry pays out once
+        require!(
+            !self.season_snapshot.entries[index].claimed,
+            GovernanceError::SeasonRewardClaimed
+        );
+
+        // 5. Calculate Share
+        // SECURITY: i128 checked math in reward_for
+        let amount = self
+            .season_snapshot
+            .reward_for(index)
+            .ok_or(GovernanceError::MathOverflow)?;
+        require!(amount > 0, GovernanceError::NoRewardsToClaim);
+
+        // 6. Reward Reserve Check
+        // SECURITY: Only tokens above staked and unbonding principal are rewards
+        let locked = self
+            .treasury
+            .total_staked
+            .checked_add(self.treasury.total_unbonding)
+            .ok_or(GovernanceError::MathOverflow)?;
+        let reserve = self
+            .treasury_token_account
+            .amount
+            .checked_sub(locked)
+            .ok_or(GovernanceError::InsufficientRewardReserve)?;
+        require!(reserve >= amount, GovernanceError::InsufficientRewardReserve);
+
+        // 7. Mark Claimed
+        // SECURITY: State updated before the transfer
+        self.season_snapshot.entries[index].claimed = true;
+
+        // 8. Transfer Reward from Treasury to User
+        // SECURITY: Uses PDA authority to sign the transfer
+        let config = self.config.key();
+        let admin = self.admin.key();
+        let treasury_auth_seeds = &[
+            TREASURYAUTH,
+            config.as_ref(),
+            admin.as_ref(),
+            &[self.treasury.vault_bump],
+        ];
+        let signer_seeds = &[&treasury_auth_seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.treasury_token_account.to_account_info(),
+                to: self.user_token_account.to_account_info(),
+                authority: self.treasury_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, amount)?;
+
+        msg!("Season {} reward claimed: {} -> {}", season_id, amount, user);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Claim Unbonded Instruction
+//
+// Withdraws tokens queued by unstake_tokens once the unbonding period has passed
+// Uses PDA authority to sign the transfer from treasury to user
+//
+// SECURITY FEATURES:
+// - Unlock time enforced from the ticket
+// - Ticket owner must be the signer
+// - Treasury PDA authority signs withdrawals (no private keys)
+// - Treasury balance check
+// - Token mint validation
+// - Ticket closed after claim (rent refunded, no replay)
+// - System pause check
+
+#[derive(Accounts)]
+pub struct ClaimUnbonded<'info> {
+    // User claiming tokens
+    // Receives the tokens and the ticket rent
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Admin pubkey for PDA derivation
+    /// CHECK: Used for config and treasury PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Validates token mint and pause state
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Treasury state PDA
+    // Seeds: ["treasury", admin]
+    // SECURITY: Provides treasury token account and authority bump
+    // Tracks tokens still queued for unbonding
+    #[account(
+        mut,
+        seeds = [TREASURY, admin.key().as_ref()],
+        bump = treasury.state_bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    // Treasury authority PDA
+    // Seeds: ["treasury_auth", config, admin]
+    // SECURITY: PDA signer for treasury withdrawals
+    #[account(
+        seeds = [TREASURYAUTH, config.key().as_ref(), admin.key().as_ref()],
+        bump = treasury.vault_bump,
+    )]
+    /// CHECK: PDA authority for signing treasury transfers
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    // Unbonding ticket PDA
+    // Seeds: ["unbonding", user]
+    // SECURITY: Owner check and close-on-claim prevent replaying a ticket
+    #[account(
+        mut,
+        close = user,
+        seeds = [UNBONDING_TICKET, user.key().as_ref()],
+        bump = unbonding_ticket.bump,
+        constraint = unbonding_ticket.owner == user.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub unbonding_ticket: Account<'info, UnbondingTicket>,
+
+    // Token mint account
+    // SECURITY: Must match config.token_mint
+    #[account(
+        address = config.token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub token_mint_account: Account<'info, Mint>,
+
+    // User's token account (destination)
+    // SECURITY: Validated as user's ATA for correct mint
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    // Treasury token account (source)
+    // SECURITY: Validated against treasury state
+    #[account(
+        mut,
+        address = treasury.treasury_token_account @ GovernanceError::InvalidTreasuryAccount
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimUnbonded<'info> {
+    pub fn claim_unbonded(&mut self) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. System Pause Check
+        // Prevents withdrawals during maintenance
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        let amount = self.unbonding_ticket.amount;
+
+        // 2. Ticket Amount Check
+        // Prevents empty claims
+        require!(amount > 0, GovernanceError::NothingToClaim);
+
+        // 3. Unlock Time Check
+        // SECURITY: Tokens stay locked for the full unbonding period
+        // Stops stake-vote-unstake-withdraw within a single block
+        let current_time = Clock::get()?.unix_timestamp;
+        #[cfg(not(feature = "vuln-missing-unbonding-check"))]
+        require!(
+            current_time >= self.unbonding_ticket.unlock_at,
+            GovernanceError::UnbondingPeriodActive
+        );
+
+        // 4. Treasury Balance Check
+        // SECURITY: Ensures treasury has sufficient tokens
+        require!(
+            self.treasury_token_account.amount >= amount,
+            GovernanceError::InsufficientTreasuryBalance
+        );
+
+        // 5. Transfer Tokens from Treasury to User
+        // SECURITY: Uses PDA authority to sign the transfer
+        let config = self.config.key();
+        let admin = self.admin.key();
+        let treasury_auth_seeds = &[
+            TREASURYAUTH,
+            config.as_ref(),
+            admin.as_ref(),
+            &[self.treasury.vault_bump],
+        ];
+        let signer_seeds = &[&treasury_auth_seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.treasury_token_account.to_account_info(),
+                to: self.user_token_account.to_account_info(),
+                authority: self.treasury_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, amount)?;
+
+        // 6. Clear Ticket
+        // Account is closed by the 'close' constraint after this handler
+        self.unbonding_ticket.amount = 0;
+        self.treasury.total_unbonding = self
+            .treasury
+            .total_unbonding
+            .checked_sub(amount)
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        Ok(())
+    }
+}
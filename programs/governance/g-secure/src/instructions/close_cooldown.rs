@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Close Cooldown Instruction
+//
+// Closes the voter's cooldown tracker after the season of their last vote is over
+// Rent is refunded to the voter; the next vote creates a fresh tracker
+//
+// SECURITY FEATURES:
+// - Only the voter can close their own tracker
+// - Last vote must be from an earlier season than config.current_season
+// - The longest role cooldown must have elapsed, so closing never cuts one short
+
+#[derive(Accounts)]
+pub struct CloseCooldown<'info> {
+    // Voter who owns the tracker
+    // Receives the tracker's rent
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    // Admin pubkey for config derivation
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Source of the current season
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Vote cooldown PDA
+    // Seeds: ["cooldown", voter]
+    // SECURITY: Derived from the signer, so only the voter's own tracker closes
+    #[account(
+        mut,
+        close = voter,
+        seeds = [VOTE_COOLDOWN, voter.key().as_ref()],
+        bump = vote_cooldown.bump,
+        constraint = vote_cooldown.voter == voter.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub vote_cooldown: Account<'info, VoteCooldown>,
+}
+
+impl<'info> CloseCooldown<'info> {
+    pub fn close_cooldown(&mut self) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Season Rollover Check
+        // SECURITY: Trackers from the open season stay in place
+        require!(
+            self.vote_cooldown.season < self.config.current_season,
+            GovernanceError::VoteSeasonNotOver
+        );
+
+        // 2. Cooldown Elapsed Check
+        // SECURITY: A season can roll over minutes after a vote
+        // Members have the longest cooldown, so once it has passed no role's is running
+        let longest_cooldown_seconds = MemberRanks::Member.cooldown_hours() as i64 * 3600;
+        let cooldown_ends = self
+            .vote_cooldown
+            .last_vote_timestamp
+            .checked_add(longest_cooldown_seconds)
+            .ok_or(GovernanceError::MathOverflow)?;
+        require!(
+            Clock::get()?.unix_timestamp >= cooldown_ends,
+            GovernanceError::VoteCooldownActive
+        );
+
+        // Account is closed by the 'close' constraint after this handler
+        Ok(())
+    }
+}
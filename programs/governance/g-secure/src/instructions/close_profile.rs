@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Close Profile Instruction
+//
+// Deletes a user profile and releases its username
+// Rent from both the profile and the registry entry is refunded to the user
+//
+// SECURITY FEATURES:
+// - Profile must hold no stake (staked tokens cannot be orphaned)
+// - No active delegation in either direction (no dangling voting power)
+// - Registry derived from the profile's username and owner-checked
+// - Only the profile owner can close
+
+#[derive(Accounts)]
+pub struct CloseProfile<'info> {
+    // Profile owner
+    // Receives the rent from both closed accounts
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // User profile PDA
+    // Seeds: ["user_profile", user]
+    // SECURITY: Validates ownership before closing
+    #[account(
+        mut,
+        close = user,
+        seeds = [USERPROFILE, user.key().as_ref()],
+        bump,
+        constraint = user_profile.owner == user.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    // Username registry PDA
+    // Seeds: ["user_registry", user_profile.username]
+    // SECURITY: Derived from the profile and owner-checked
+    // Closed so the username becomes available again
+    #[account(
+        mut,
+        close = user,
+        seeds = [USER_REGISTRY, user_profile.username.as_bytes()],
+        bump = user_registry.bump,
+        constraint = user_registry.owner == user.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub user_registry: Account<'info, UsernameRegistry>,
+}
+
+impl<'info> CloseProfile<'info> {
+    pub fn close_profile(&mut self) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Zero Stake Check
+        // SECURITY: Staked tokens are tracked on the profile
+        // Closing it would leave them in the treasury with no owner
+        require!(
+            self.user_profile.stake_amount == 0 && self.user_profile.weighted_stake == 0,
+            GovernanceError::ProfileHasStake
+        );
+
+        // 2. Delegation Check
+        // SECURITY: Delegated power must be revoked first
+        // Otherwise the delegate keeps power that no profile backs
+        require!(
+            self.user_profile.delegated_to == Pubkey::default()
+                && self.user_profile.delegated_stake == 0,
+            GovernanceError::ProfileHasDelegations
+        );
+
+        // 3. Release Username
+        // Accounts are closed by the 'close' constraints after this handler
+        self.user_registry.claimed = false;
+
+        Ok(())
+    }
+}
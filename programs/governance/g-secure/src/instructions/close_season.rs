@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Close Season Instruction
+//
+// Admin-only operation that finalizes the current season's snapshot
+// Records total stake and the reward pool, then opens the next season
+//
+// SECURITY FEATURES:
+// - Admin-only access (validated via config PDA)
+// - A season can only be closed once
+// - Total stake copied from the treasury (not caller supplied)
+// - Season counter advanced with checked math
+// - System pause check
+
+#[derive(Accounts)]
+pub struct CloseSeason<'info> {
+    // Admin authority
+    // Must be the current config.admin; pays for the snapshot if no reset created it
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Admin pubkey for PDA derivation
+    /// CHECK: Used for config and treasury PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Validates the signer against the current admin
+    // Holds the season counter advanced here
+    #[account(
+        mut,
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump = config.config_bump,
+        constraint = config.admin == authority.key() @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    // Treasury state PDA
+    // Seeds: ["treasury", admin]
+    // SECURITY: Source of the total stake recorded in the snapshot
+    #[account(
+        seeds = [TREASURY, admin.key().as_ref()],
+        bump = treasury.state_bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    // Season snapshot PDA
+    // Seeds: ["season_snapshot", config, current_season]
+    // SECURITY: Only the open season can be closed
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + SeasonSnapshot::INIT_SPACE,
+        seeds = [SEASON_SNAPSHOT, config.key().as_ref(), &config.current_season.to_le_bytes()],
+        bump
+    )]
+    pub season_snapshot: Account<'info, SeasonSnapshot>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseSeason<'info> {
+    pub fn close_season(&mut self, reward_pool: u64, bumps: CloseSeasonBumps) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. System Pause Check
+        // Prevents season changes during maintenance
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        let snapshot = &mut self.season_snapshot;
+
+        // 2. Finalized Check
+        // SECURITY: Reward pool cannot be rewritten after claims begin
+        require!(!snapshot.finalized, GovernanceError::SeasonAlreadyFinalized);
+
+        if snapshot.config == Pubkey::default() {
+            snapshot.config = self.config.key();
+            snapshot.season_id = self.config.current_season;
+            snapshot.bump = bumps.season_snapshot;
+        }
+
+        // 3. Finalize Snapshot
+        snapshot.total_staked = self.treasury.total_staked;
+        snapshot.reward_pool = reward_pool;
+        snapshot.finalized = true;
+
+        // 4. Open Next Season
+        // SECURITY: Later resets write to a fresh snapshot PDA
+        self.config.current_season = self
+            .config
+            .current_season
+            .checked_add(1)
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        msg!(
+            "Season {} closed: {} ranked, {} staked, {} reward pool",
+            snapshot.season_id,
+            snapshot.entries.len(),
+            snapshot.total_staked,
+            reward_pool
+        );
+
+        Ok(())
+    }
+}
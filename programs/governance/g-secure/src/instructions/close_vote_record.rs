@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Close Vote Record Instruction
+//
+// Closes one of the voter's reputation vote records after its season is over
+// Rent is refunded to the voter
+//
+// SECURITY FEATURES:
+// - Only the voter who cast the vote can close the record
+// - Record must be from an earlier season than config.current_season
+// - cast_vote only reverses current-season records, so closing an old one
+//   changes nothing about how the next vote on the same user counts
+
+#[derive(Accounts)]
+#[instruction(target_username: String)]
+pub struct CloseVoteRecord<'info> {
+    // Voter who cast the vote
+    // Receives the record's rent
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    // Admin pubkey for config derivation
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Source of the current season
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Vote record PDA
+    // Seeds: ["vote_record", voter, target_username]
+    // SECURITY: Derived from the signer, so only the voter's own record closes
+    #[account(
+        mut,
+        close = voter,
+        seeds = [VOTE_RECORD, voter.key().as_ref(), target_username.as_bytes()],
+        bump = vote_record.bump,
+        constraint = vote_record.voter == voter.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+}
+
+impl<'info> CloseVoteRecord<'info> {
+    pub fn close_vote_record(&mut self, target_username: String) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Season Rollover Check
+        // SECURITY: A current-season record is still reversed on a vote change
+        // Closing it would let the voter stack a second vote on the same user
+        require!(
+            self.vote_record.season < self.config.current_season,
+            GovernanceError::VoteSeasonNotOver
+        );
+
+        // Account is closed by the 'close' constraint after this handler
+        msg!(
+            "Closed vote record on {} from season {}",
+            target_username,
+            self.vote_record.season
+        );
+
+        Ok(())
+    }
+}
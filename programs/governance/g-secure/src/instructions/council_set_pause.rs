@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, events::*, state::*};
+
+// Council Set Pause Instruction
+//
+// Emergency pause (or resume) of the DAO by the council
+// Every instruction gated on config.is_paused stops while paused
+// The calling member signs, co-signing members are passed as remaining accounts
+//
+// SECURITY FEATURES:
+// - Caller must be a council member
+// - Needs council.threshold distinct member signatures
+// - Council PDA tied to this config
+// - Every change is logged with its signers
+
+#[derive(Accounts)]
+pub struct CouncilSetPause<'info> {
+    // Council member
+    // Counts as the first signature
+    pub member: Signer<'info>,
+
+    // Admin pubkey for config derivation
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    #[account(
+        mut,
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Council PDA
+    // Seeds: ["council", config]
+    // SECURITY: Seeds tie the council to this DAO's config
+    #[account(
+        seeds = [COUNCIL, config.key().as_ref()],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, Council>,
+}
+
+impl<'info> CouncilSetPause<'info> {
+    pub fn council_set_pause(&mut self, paused: bool, co_signers: &[AccountInfo]) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Membership Check
+        require!(
+            self.council.is_member(&self.member.key()),
+            GovernanceError::NotCouncilMember
+        );
+
+        // 2. Signature Count
+        // SECURITY: One compromised key cannot freeze the DAO on its own
+        let signers = self.council.count_signers(&self.member.key(), co_signers);
+        require!(
+            signers.len() >= self.council.threshold as usize,
+            GovernanceError::InsufficientCouncilSignatures
+        );
+
+        // 3. Apply Pause State
+        self.config.is_paused = paused;
+
+        emit!(CouncilPauseSet {
+            config: self.config.key(),
+            paused,
+            signers,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
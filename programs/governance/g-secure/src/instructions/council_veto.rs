@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, events::*, state::*};
+
+// Council Veto Instruction
+//
+// Cancels a malicious proposal before it can be executed
+// Allowed while the proposal is Active or Passed and until veto_window
+// seconds after its voting end; execute_multisig_action waits out the
+// same window, so a passing proposal cannot be relayed before a veto lands
+//
+// SECURITY FEATURES:
+// - Caller must be a council member
+// - Needs council.threshold distinct member signatures
+// - Only Active or Passed proposals, only within the veto window
+// - Council and proposal PDAs tied to this config
+// - Every veto is logged with its signers
+
+#[derive(Accounts)]
+pub struct CouncilVeto<'info> {
+    // Council member
+    // Counts as the first signature
+    pub member: Signer<'info>,
+
+    // Admin pubkey for config derivation
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Council PDA
+    // Seeds: ["council", config]
+    // SECURITY: Seeds tie the council to this DAO's config
+    #[account(
+        seeds = [COUNCIL, config.key().as_ref()],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, Council>,
+
+    // Proposal PDA
+    // Seeds: ["proposal", config, proposal_id]
+    // SECURITY: Seeds tie the proposal to this DAO's config
+    #[account(
+        mut,
+        seeds = [PROPOSAL, config.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+impl<'info> CouncilVeto<'info> {
+    pub fn council_veto(&mut self, co_signers: &[AccountInfo]) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Membership Check
+        require!(
+            self.council.is_member(&self.member.key()),
+            GovernanceError::NotCouncilMember
+        );
+
+        // 2. Signature Count
+        // SECURITY: One compromised key cannot veto legitimate proposals
+        let signers = self.council.count_signers(&self.member.key(), co_signers);
+        require!(
+            signers.len() >= self.council.threshold as usize,
+            GovernanceError::InsufficientCouncilSignatures
+        );
+
+        // 3. Proposal Status Check
+        // Rejected proposals never execute; Vetoed ones are already settled
+        require!(
+            matches!(
+                self.proposal.status,
+                ProposalStatus::Active | ProposalStatus::Passed
+            ),
+            GovernanceError::ProposalNotActive
+        );
+
+        // 4. Veto Window Check
+        let current_time = Clock::get()?.unix_timestamp;
+        let deadline = self
+            .council
+            .veto_deadline(self.proposal.voting_ends_at)
+            .ok_or(GovernanceError::MathOverflow)?;
+        require!(current_time < deadline, GovernanceError::VetoWindowClosed);
+
+        // 5. Veto Proposal
+        let previous_status = self.proposal.status;
+        self.proposal.status = ProposalStatus::Vetoed;
+
+        emit!(ProposalVetoed {
+            config: self.config.key(),
+            proposal: self.proposal.key(),
+            previous_status,
+            signers,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+}
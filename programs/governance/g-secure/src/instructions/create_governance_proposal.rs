@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+use soteria_common::math::mul_div_floor;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Create Governance Proposal Instruction
+//
+// Opens a new DAO proposal for stake-weighted voting
+// Each proposal gets its own PDA derived from the config's proposal counter
+// The proposer escrows config.proposal_deposit, scaled down by their rank, in
+// a ProposalDeposit PDA until finalize_proposal refunds or burns it
+//
+// SECURITY FEATURES:
+// - Only stakers meeting the minimum stake can open proposals
+// - Title length bounded to prevent storage abuse
+// - Voting window fixed at creation (PROPOSAL_VOTING_PERIOD_SECONDS)
+// - Creation timestamp recorded as the stake snapshot for ballots
+// - Deposit held in the ProposalDeposit PDA's ATA, base mint only
+// - System pause check
+// - Checked arithmetic for counter and deadline
+
+#[derive(Accounts)]
+pub struct CreateGovernanceProposal<'info> {
+    // Proposer account
+    // Pays for the proposal account
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    // Admin pubkey for config derivation
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Source of the proposal counter, pause flag and minimum stake
+    #[account(
+        mut,
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Proposer's profile
+    // Seeds: ["user_profile", proposer]
+    // SECURITY: Validates ownership and stake amount
+    #[account(
+        seeds = [USERPROFILE, proposer.key().as_ref()],
+        bump,
+        constraint = proposer_profile.owner == proposer.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub proposer_profile: Account<'info, UserProfile>,
+
+    // Proposal PDA
+    // Seeds: ["proposal", config, proposal_count]
+    // SECURITY: Counter-based seeds give every proposal a unique address
+    #[account(
+        init,
+        payer = proposer,
+        space = ANCHOR_DISCRIMINATOR + Proposal::INIT_SPACE,
+        seeds = [PROPOSAL, config.key().as_ref(), &config.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    // Token mint account
+    // SECURITY: Deposits are taken in the base mint only
+    #[account(
+        address = config.token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub token_mint_account: Box<Account<'info, Mint>>,
+
+    // Proposer's token account (source)
+    // SECURITY: Validated as proposer's ATA for correct mint
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = proposer
+    )]
+    pub proposer_token_account: Box<Account<'info, TokenAccount>>,
+
+    // Proposal deposit PDA
+    // Seeds: ["proposal_deposit", proposal]
+    // SECURITY: One deposit per proposal, released only by finalize_proposal
+    #[account(
+        init,
+        payer = proposer,
+        space = ANCHOR_DISCRIMINATOR + ProposalDeposit::INIT_SPACE,
+        seeds = [PROPOSAL_DEPOSIT, proposal.key().as_ref()],
+        bump
+    )]
+    pub proposal_deposit: Box<Account<'info, ProposalDeposit>>,
+
+    // Deposit token account (destination)
+    // SECURITY: ATA of the deposit PDA, so only the deposit can release it
+    #[account(
+        init,
+        payer = proposer,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = proposal_deposit
+    )]
+    pub deposit_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateGovernanceProposal<'info> {
+    pub fn create_proposal(
+        &mut self,
+        title: String,
+        bumps: CreateGovernanceProposalBumps,
+    ) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. System Pause Check
+        // Prevents new proposals while the system is paused
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        // 2. Title Validation
+        // Prevents empty titles and oversized proposal accounts
+        require!(
+            !title.is_empty() && title.len() <= MAX_PROPOSAL_TITLE_LENGTH,
+            GovernanceError::InvalidProposalTitle
+        );
+
+        // 3. Minimum Stake Requirement
+        // SECURITY: Prevents proposal spam from zero-stake accounts
+        // Uses effective power so delegates can propose on behalf of delegators
+        // Fresh stake only counts as it matures
+        let proposer_power = self
+            .proposer_profile
+            .effective_power(Clock::get()?.unix_timestamp, self.config.stake_ramp_seconds)
+            .ok_or(GovernanceError::MathOverflow)?;
+        require!(
+            proposer_power >= self.config.minimum_stake,
+            GovernanceError::InsufficientStake
+        );
+
+        // 4. Proposal Deposit
+        // Scaled down by the rank computed from current reputation
+        // SECURITY: Taken up front and held until finalization; a proposal that
+        // misses quorum forfeits it, so opening spam proposals costs tokens
+        let deposit_amount = mul_div_floor(
+            self.config.proposal_deposit,
+            self.proposer_profile.rank().proposal_deposit_bps(),
+            MAX_BASIS_POINTS,
+        )
+        .ok_or(GovernanceError::MathOverflow)?;
+
+        if deposit_amount > 0 {
+            let transfer_ctx = CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.proposer_token_account.to_account_info(),
+                    to: self.deposit_token_account.to_account_info(),
+                    authority: self.proposer.to_account_info(),
+                },
+            );
+            token::transfer(transfer_ctx, deposit_amount)?;
+        }
+
+        self.proposal_deposit.set_inner(ProposalDeposit {
+            proposal: self.proposal.key(),
+            depositor: self.proposer.key(),
+            amount: deposit_amount,
+            bump: bumps.proposal_deposit,
+        });
+
+        // 5. Calculate Voting Deadline
+        // SECURITY: Checked addition prevents overflow
+        let current_time = Clock::get()?.unix_timestamp;
+        let voting_ends_at = current_time
+            .checked_add(PROPOSAL_VOTING_PERIOD_SECONDS)
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        // 6. Initialize Proposal
+        // created_at doubles as the stake snapshot time for ballots
+        self.proposal.set_inner(Proposal {
+            config: self.config.key(),
+            proposer: self.proposer.key(),
+            proposal_id: self.config.proposal_count,
+            title,
+            votes_for: 0,
+            votes_against: 0,
+            created_at: current_time,
+            voting_ends_at,
+            status: ProposalStatus::Active,
+            bump: bumps.proposal,
+        });
+
+        // 7. Increment Proposal Counter
+        // SECURITY: Checked addition prevents overflow
+        self.config.proposal_count = self
+            .config
+            .proposal_count
+            .checked_add(1)
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        Ok(())
+    }
+}
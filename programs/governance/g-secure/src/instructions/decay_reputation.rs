@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Decay Reputation Instruction
+//
+// Permissionless crank that reduces a profile's positive reputation by
+// config.decay_rate_bps for every full epoch elapsed since last_decay_ts
+// Keeps ranks tied to recent participation instead of historic votes
+//
+// SECURITY FEATURES:
+// - Decay only for fully elapsed epochs (no repeat calls within an epoch)
+// - Decay clock advances by processed epochs only (partial epoch preserved)
+// - Bounded epochs per call
+// - Negative reputation is never decayed toward zero
+// - Role level recalculated after decay
+// - Checked arithmetic prevents overflow
+// - System pause check
+
+#[derive(Accounts)]
+pub struct DecayReputation<'info> {
+    // Caller account
+    // Anyone can crank decay; no special privileges
+    pub caller: Signer<'info>,
+
+    // Admin pubkey for config derivation
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Source of the decay rate and epoch length
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Target user's profile
+    // Seeds: ["user_profile", owner]
+    // SECURITY: Seeds derived from the stored owner ensure a real profile PDA
+    #[account(
+        mut,
+        seeds = [USERPROFILE, user_profile.owner.as_ref()],
+        bump,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+}
+
+impl<'info> DecayReputation<'info> {
+    pub fn decay_reputation(&mut self) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. System Pause Check
+        // Prevents reputation changes while the system is paused
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        // 2. Count Elapsed Epochs
+        // SECURITY: Only full epochs since the last decay count
+        // A second call inside the same epoch fails instead of decaying again
+        let current_time = Clock::get()?.unix_timestamp;
+        let profile = &mut self.user_profile;
+        let elapsed = current_time
+            .checked_sub(profile.last_decay_ts)
+            .ok_or(GovernanceError::MathOverflow)?;
+        let epochs = elapsed
+            .checked_div(self.config.decay_epoch_seconds)
+            .ok_or(GovernanceError::MathOverflow)?;
+        require!(epochs > 0, GovernanceError::DecayNotDue);
+
+        // 3. Bound Work Per Call
+        // Long-idle profiles catch up over several calls
+        let epochs = epochs.min(MAX_DECAY_EPOCHS_PER_CALL);
+
+        // 4. Apply Compounded Decay
+        // SECURITY: Only positive reputation decays
+        // Decay rounds up so small balances still reach zero, and can never
+        // exceed the current balance because the rate is capped at 100%
+        let rate = self.config.decay_rate_bps as i128;
+        let mut reputation = profile.reputation_points;
+        for _ in 0..epochs {
+            if reputation <= 0 || rate == 0 {
+                break;
+            }
+            let decay = (reputation as i128)
+                .checked_mul(rate)
+                .and_then(|v| v.checked_add(MAX_BASIS_POINTS as i128 - 1))
+                .and_then(|v| v.checked_div(MAX_BASIS_POINTS as i128))
+                .ok_or(GovernanceError::MathOverflow)?;
+            reputation = reputation
+                .checked_sub(decay as i64)
+                .ok_or(GovernanceError::MathOverflow)?;
+        }
+        profile.reputation_points = reputation;
+
+        // 5. Advance Decay Clock
+        // SECURITY: Moves forward by processed epochs, not to current_time
+        // The unfinished part of the current epoch still counts next time
+        let processed = epochs
+            .checked_mul(self.config.decay_epoch_seconds)
+            .ok_or(GovernanceError::MathOverflow)?;
+        profile.last_decay_ts = profile
+            .last_decay_ts
+            .checked_add(processed)
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        // 6. Update Role Level
+        // Decay can drop the profile into a lower rank
+        profile.role_level = MemberRanks::from_reputation(profile.reputation_points);
+
+        Ok(())
+    }
+}
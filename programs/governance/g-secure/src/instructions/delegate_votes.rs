@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, require_rank, state::*};
+
+// Delegate Votes Instruction
+//
+// Lends the delegator's staked voting power to another profile
+// The delegate's effective power grows by the delegated amount while the
+// delegator's own stake stops counting until the delegation is revoked
+//
+// SECURITY FEATURES:
+// - One active delegation per delegator (delegation PDA uses 'init')
+// - Self-delegation prevention
+// - No delegation chains or cycles (power is counted exactly once)
+// - Minimum stake requirement
+// - Minimum rank requirement (same gate as ballots)
+// - Delegate's stake timestamp reset (no lending power into open proposals)
+// - Checked arithmetic prevents overflow
+// - System pause check
+
+#[derive(Accounts)]
+pub struct DelegateVotes<'info> {
+    // Delegator account
+    // Pays for the delegation record
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    // Admin pubkey for config derivation
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Validates system state and minimum stake
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Delegator's profile
+    // Seeds: ["user_profile", delegator]
+    // SECURITY: Validates ownership and records the delegate
+    #[account(
+        mut,
+        seeds = [USERPROFILE, delegator.key().as_ref()],
+        bump,
+        constraint = delegator_profile.owner == delegator.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub delegator_profile: Account<'info, UserProfile>,
+
+    // Delegate's profile
+    // Seeds: ["user_profile", delegate_owner]
+    // SECURITY: Seeds derived from the stored owner ensure a real profile PDA
+    #[account(
+        mut,
+        seeds = [USERPROFILE, delegate_profile.owner.as_ref()],
+        bump,
+    )]
+    pub delegate_profile: Account<'info, UserProfile>,
+
+    // Delegation record
+    // Seeds: ["delegation", delegator]
+    // SECURITY: 'init' fails if the delegator already has an active delegation
+    #[account(
+        init,
+        payer = delegator,
+        space = ANCHOR_DISCRIMINATOR + Delegation::INIT_SPACE,
+        seeds = [DELEGATION, delegator.key().as_ref()],
+        bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DelegateVotes<'info> {
+    pub fn delegate_votes(&mut self, bumps: DelegateVotesBumps) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. System Pause Check
+        // Prevents delegation changes while the system is paused
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        // 2. Self-Delegation Prevention
+        // SECURITY: Delegating to yourself would add your stake twice
+        require!(
+            self.delegate_profile.owner != self.delegator.key(),
+            GovernanceError::CannotDelegateToSelf
+        );
+
+        // 3. Minimum Stake Requirement
+        // SECURITY: Prevents dust delegations
+        // Delegates the delegator's matured weighted power across all staked mints
+        // SECURITY: Delegating cannot skip the stake age ramp; power still
+        // maturing stays with the delegator
+        let amount = self
+            .delegator_profile
+            .matured_power(Clock::get()?.unix_timestamp, self.config.stake_ramp_seconds)
+            .ok_or(GovernanceError::MathOverflow)?;
+        require!(
+            amount >= self.config.minimum_stake,
+            GovernanceError::InsufficientStake
+        );
+
+        // SECURITY: Delegated power is only ever cast as ballots, so the
+        // delegator must hold the rank a ballot would require
+        require_rank!(self.delegator_profile, MIN_BALLOT_RANK);
+
+        // 4. Delegation Chain Prevention
+        // SECURITY: Delegate must vote with their own stake, and the
+        // delegator must not be holding anyone else's power
+        // Together these rule out chains and cycles (A -> B -> A), which
+        // would let the same stake be counted by more than one profile
+        require!(
+            self.delegate_profile.delegated_to == Pubkey::default(),
+            GovernanceError::DelegationChainNotAllowed
+        );
+        require!(
+            self.delegator_profile.delegated_stake == 0,
+            GovernanceError::DelegationChainNotAllowed
+        );
+
+        // 5. Credit Delegate
+        // SECURITY: Checked addition prevents overflow
+        // Stake timestamp reset so borrowed power cannot vote on proposals
+        // that were already open (same rule as fresh stake)
+        let current_time = Clock::get()?.unix_timestamp;
+        let delegate_profile = &mut self.delegate_profile;
+        delegate_profile.delegated_stake = delegate_profile
+            .delegated_stake
+            .checked_add(amount)
+            .ok_or(GovernanceError::MathOverflow)?;
+        delegate_profile.last_stake_timestamp = current_time;
+
+        // 6. Mark Delegator
+        // Own stake stops counting toward the delegator's effective power
+        self.delegator_profile.delegated_to = self.delegate_profile.owner;
+
+        // 7. Record Delegation
+        // Stored amount is what revoke_delegation removes from the delegate
+        self.delegation.set_inner(Delegation {
+            delegator: self.delegator.key(),
+            delegate: self.delegate_profile.owner,
+            amount,
+            created_at: current_time,
+            bump: bumps.delegation,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::{constants::*, errors::*, events::*, state::*};
+
+// Execute Multisig Action Instruction
+//
+// Relays a passed proposal's multisig action through CPI into the multisig
+// program's create_proposal. The DAO's executor PDA (["multisig_executor",
+// config]) is the proposer, so it must first be added to the multisig as an
+// Admin or Proposer member. The multisig proposal then goes through the
+// multisig's own approvals and timelock like any other.
+//
+// Permissionless: the caller only pays the rent for the multisig proposal.
+// That rent is moved into the executor right before the CPI and the multisig
+// refunds it to the executor when its proposal closes.
+//
+// SECURITY FEATURES:
+// - Only Passed proposals can be relayed
+// - Waits out the council veto window when a council exists
+// - Blocked while the DAO is paused
+// - Each action is relayed once (executed flag set before the CPI)
+// - Multisig account fixed by the action, program pinned to MULTISIG_PROGRAM_ID
+// - Executor PDA signs only for the multisig program
+
+#[derive(Accounts)]
+pub struct ExecuteMultisigAction<'info> {
+    // Caller account
+    // Pays the multisig proposal rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // Admin pubkey for config derivation
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Proposal PDA
+    // Seeds: ["proposal", config, proposal_id]
+    // SECURITY: Seeds tie the proposal to this DAO's config
+    #[account(
+        seeds = [PROPOSAL, config.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    // Multisig Action PDA
+    // Seeds: ["multisig_action", proposal]
+    // SECURITY: The action voters saw, not one supplied by the caller
+    #[account(
+        mut,
+        seeds = [MULTISIG_ACTION, proposal.key().as_ref()],
+        bump = multisig_action.bump,
+    )]
+    pub multisig_action: Account<'info, MultisigAction>,
+
+    // Executor PDA
+    // Seeds: ["multisig_executor", config]
+    // The DAO's member key on the multisig; signs create_proposal via seeds
+    #[account(
+        mut,
+        seeds = [MULTISIG_EXECUTOR, config.key().as_ref()],
+        bump,
+    )]
+    pub executor: SystemAccount<'info>,
+
+    // Target multisig
+    /// CHECK: Pinned to the action; validated by the multisig program
+    #[account(
+        mut,
+        address = multisig_action.multisig @ GovernanceError::InvalidMultisigAccount
+    )]
+    pub multisig: UncheckedAccount<'info>,
+
+    // Multisig proposal to create
+    /// CHECK: Seeds (["proposal", multisig, proposal_count]) checked by the multisig program
+    #[account(mut)]
+    pub multisig_proposal: UncheckedAccount<'info>,
+
+    // Multisig program
+    /// CHECK: Pinned to MULTISIG_PROGRAM_ID
+    #[account(address = MULTISIG_PROGRAM_ID @ GovernanceError::InvalidMultisigProgram)]
+    pub multisig_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    // Council PDA
+    // Seeds: ["council", config]
+    // SECURITY: Seeds fixed, so the veto window cannot be skipped by passing
+    // another account; empty when the DAO has no council
+    /// CHECK: Deserialized in the handler only when initialized
+    #[account(
+        seeds = [COUNCIL, config.key().as_ref()],
+        bump,
+    )]
+    pub council: UncheckedAccount<'info>,
+}
+
+impl<'info> ExecuteMultisigAction<'info> {
+    pub fn execute_multisig_action(&mut self, bumps: ExecuteMultisigActionBumps) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Proposal Outcome Check
+        // Active and Rejected proposals cannot trigger anything
+        require!(
+            self.proposal.status == ProposalStatus::Passed,
+            GovernanceError::ProposalNotPassed
+        );
+
+        // 2. System Pause Check
+        // SECURITY: A council pause also freezes queued actions
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        // 3. Veto Window Check
+        // SECURITY: The council gets its full window to veto before relay
+        if !self.council.data_is_empty() {
+            let data = self.council.try_borrow_data()?;
+            let council = Council::try_deserialize(&mut &data[..])?;
+            let deadline = council
+                .veto_deadline(self.proposal.voting_ends_at)
+                .ok_or(GovernanceError::MathOverflow)?;
+            require!(
+                Clock::get()?.unix_timestamp >= deadline,
+                GovernanceError::VetoWindowActive
+            );
+        }
+
+        // 4. Single Execution
+        // SECURITY: Flag flips before the CPI so a ballot is relayed once
+        require!(
+            !self.multisig_action.executed,
+            GovernanceError::MultisigActionAlreadyExecuted
+        );
+        self.multisig_action.executed = true;
+
+        // 5. Fund Executor
+        // The multisig charges its proposal rent to the proposer
+        let rent = Rent::get()?.minimum_balance(MULTISIG_PROPOSAL_SPACE);
+        transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.payer.to_account_info(),
+                    to: self.executor.to_account_info(),
+                },
+            ),
+            rent,
+        )?;
+
+        // 6. CPI into multisig create_proposal
+        // Account order follows the multisig's CreateProposal struct; the
+        // program ID stands in for the optional rent_payer (None)
+        let mut data = MULTISIG_CREATE_PROPOSAL_DISCRIMINATOR.to_vec();
+        self.multisig_action
+            .action
+            .serialize(&mut data)
+            .map_err(|_| error!(GovernanceError::InvalidInstructionData))?;
+
+        let ix = Instruction {
+            program_id: self.multisig_program.key(),
+            accounts: vec![
+                AccountMeta::new(self.executor.key(), true),
+                AccountMeta::new(self.multisig.key(), false),
+                AccountMeta::new(self.multisig_proposal.key(), false),
+                AccountMeta::new_readonly(self.system_program.key(), false),
+                AccountMeta::new_readonly(self.multisig_program.key(), false),
+            ],
+            data,
+        };
+
+        let config_key = self.config.key();
+        let executor_seeds = &[MULTISIG_EXECUTOR, config_key.as_ref(), &[bumps.executor]];
+        let signer_seeds = &[&executor_seeds[..]];
+
+        invoke_signed(
+            &ix,
+            &[
+                self.executor.to_account_info(),
+                self.multisig.to_account_info(),
+                self.multisig_proposal.to_account_info(),
+                self.system_program.to_account_info(),
+                self.multisig_program.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+
+        // 7. Emit Execution Event
+        emit!(MultisigActionExecuted {
+            proposal: self.proposal.key(),
+            multisig: self.multisig.key(),
+            multisig_proposal: self.multisig_proposal.key(),
+            executed_by: self.payer.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
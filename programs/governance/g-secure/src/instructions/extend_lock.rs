@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Extend Lock Instruction
+//
+// Moves a live lock's unlock time to now + duration, restoring its power
+// The locked amount is unchanged
+//
+// SECURITY FEATURES:
+// - Escrow owner must be the signer
+// - Expired locks cannot be extended (withdraw and lock again instead)
+// - New unlock time must be later than the current one
+// - Duration bounded by MIN_LOCK_SECONDS and MAX_LOCK_SECONDS
+// - Extension restarts the ballot snapshot
+// - System pause check
+
+#[derive(Accounts)]
+pub struct ExtendLock<'info> {
+    // Escrow owner
+    pub user: Signer<'info>,
+
+    // Admin pubkey for PDA derivation
+    /// CHECK: Used for config PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Validates pause state
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Vote escrow PDA
+    // Seeds: ["vote_escrow", config, user]
+    // SECURITY: Seeds and owner check tie the lock to the signer
+    #[account(
+        mut,
+        seeds = [VOTE_ESCROW, config.key().as_ref(), user.key().as_ref()],
+        bump = vote_escrow.bump,
+        constraint = vote_escrow.owner == user.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub vote_escrow: Account<'info, VoteEscrow>,
+}
+
+impl<'info> ExtendLock<'info> {
+    pub fn extend_lock(&mut self, duration: i64) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. System Pause Check
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        // 2. Lock Still Live
+        // SECURITY: An expired lock's tokens are already free to withdraw
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            !self.vote_escrow.is_expired(current_time),
+            GovernanceError::LockExpired
+        );
+
+        // 3. Duration Validation
+        require!(
+            (MIN_LOCK_SECONDS..=MAX_LOCK_SECONDS).contains(&duration),
+            GovernanceError::InvalidLockDuration
+        );
+
+        // 4. Unlock Time Only Moves Forward
+        // SECURITY: Shortening a lock would release tokens that already voted
+        // on the promise of a longer lock
+        let unlock_at = current_time
+            .checked_add(duration)
+            .ok_or(GovernanceError::MathOverflow)?;
+        require!(
+            unlock_at > self.vote_escrow.unlock_at,
+            GovernanceError::LockNotExtended
+        );
+
+        // 5. Update Lock
+        // SECURITY: Restarting locked_at stops an extension made after a
+        // proposal from raising the power counted on it
+        let vote_escrow = &mut self.vote_escrow;
+        vote_escrow.unlock_at = unlock_at;
+        vote_escrow.locked_at = current_time;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+use crate::{constants::*, errors::*, events::*, state::*};
+
+// Finalize Proposal Instruction
+//
+// Settles a proposal once its voting window has closed
+// Anyone can call this; the outcome depends only on the recorded tally
+// Settles the proposer's deposit: refunded if the proposal reached quorum
+// (whether it passed or not), burned otherwise
+//
+// SECURITY FEATURES:
+// - Cannot finalize before the voting period ends
+// - Cannot finalize twice (status must be Active)
+// - Quorum and approval threshold read from Config
+// - u128 intermediate math prevents overflow in the threshold check
+// - Deposit PDA signs the refund or burn, then deposit accounts are closed
+//   to the proposer (no replay)
+
+#[derive(Accounts)]
+pub struct FinalizeProposal<'info> {
+    // Caller account
+    // Permissionless - any signer can settle an expired proposal
+    pub caller: Signer<'info>,
+
+    // Admin pubkey for config derivation
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Source of quorum and approval threshold
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Proposal PDA
+    // Seeds: ["proposal", config, proposal_id]
+    // SECURITY: Seeds tie the proposal to this DAO's config
+    #[account(
+        mut,
+        seeds = [PROPOSAL, config.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    // Proposer
+    // Receives a refunded deposit and the rent of both deposit accounts
+    /// CHECK: Matched against proposal.proposer
+    #[account(
+        mut,
+        address = proposal.proposer @ GovernanceError::UnauthorizedUser
+    )]
+    pub proposer: UncheckedAccount<'info>,
+
+    // Token mint account
+    // SECURITY: Must match config.token_mint (supply changes on burn)
+    #[account(
+        mut,
+        address = config.token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub token_mint_account: Box<Account<'info, Mint>>,
+
+    // Proposer's token account (refund destination)
+    // SECURITY: Validated as proposer's ATA for correct mint
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = proposer
+    )]
+    pub proposer_token_account: Box<Account<'info, TokenAccount>>,
+
+    // Proposal deposit PDA
+    // Seeds: ["proposal_deposit", proposal]
+    // SECURITY: Closed here, so a deposit is settled exactly once
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [PROPOSAL_DEPOSIT, proposal.key().as_ref()],
+        bump = proposal_deposit.bump,
+    )]
+    pub proposal_deposit: Box<Account<'info, ProposalDeposit>>,
+
+    // Deposit token account (source)
+    // SECURITY: Validated as the deposit PDA's ATA
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = proposal_deposit
+    )]
+    pub deposit_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> FinalizeProposal<'info> {
+    pub fn finalize_proposal(&mut self) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Proposal Status Check
+        // Prevents re-finalizing a settled proposal
+        require!(
+            self.proposal.status == ProposalStatus::Active,
+            GovernanceError::ProposalNotActive
+        );
+
+        // 2. Voting Window Check
+        // SECURITY: Outcome cannot be locked in while ballots are still open
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time >= self.proposal.voting_ends_at,
+            GovernanceError::VotingPeriodActive
+        );
+
+        // 3. Quorum Check
+        // Total participating stake must reach the configured quorum
+        let proposal = &mut self.proposal;
+        let total_votes = proposal
+            .votes_for
+            .checked_add(proposal.votes_against)
+            .ok_or(GovernanceError::MathOverflow)?;
+        let quorum_reached = total_votes >= self.config.quorum;
+
+        // 4. Approval Threshold Check
+        // votes_for / total_votes >= approval_threshold_bps / 10_000
+        let approved = (proposal.votes_for as u128) * (MAX_BASIS_POINTS as u128)
+            >= (total_votes as u128) * (self.config.approval_threshold_bps as u128);
+
+        // 5. Record Outcome
+        proposal.status = if quorum_reached && approved {
+            ProposalStatus::Passed
+        } else {
+            ProposalStatus::Rejected
+        };
+
+        msg!(
+            "Proposal {} finalized: {:?} ({} for, {} against)",
+            proposal.proposal_id,
+            proposal.status,
+            proposal.votes_for,
+            proposal.votes_against
+        );
+
+        // 6. Settle Deposit
+        // SECURITY: Refund depends on quorum, not on the outcome, so a proposal
+        // the DAO engaged with and voted down is not penalised
+        // Settles the full balance, including anything sent to it after creation
+        let amount = self.settle_deposit(quorum_reached)?;
+
+        emit!(ProposalDepositSettled {
+            proposal: self.proposal.key(),
+            proposer: self.proposer.key(),
+            amount,
+            refunded: quorum_reached,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    // Refund or burn the escrowed deposit, then close its token account
+    // Returns the amount settled
+    // The deposit PDA signs; the 'close' constraint closes the PDA itself
+    fn settle_deposit(&self, refund: bool) -> Result<u64> {
+        let proposal = self.proposal.key();
+        let deposit_seeds = &[
+            PROPOSAL_DEPOSIT,
+            proposal.as_ref(),
+            &[self.proposal_deposit.bump],
+        ];
+        let signer_seeds = &[&deposit_seeds[..]];
+
+        let amount = self.deposit_token_account.amount;
+        if amount > 0 {
+            if refund {
+                let transfer_ctx = CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    Transfer {
+                        from: self.deposit_token_account.to_account_info(),
+                        to: self.proposer_token_account.to_account_info(),
+                        authority: self.proposal_deposit.to_account_info(),
+                    },
+                    signer_seeds,
+                );
+                token::transfer(transfer_ctx, amount)?;
+            } else {
+                let burn_ctx = CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    Burn {
+                        mint: self.token_mint_account.to_account_info(),
+                        from: self.deposit_token_account.to_account_info(),
+                        authority: self.proposal_deposit.to_account_info(),
+                    },
+                    signer_seeds,
+                );
+                token::burn(burn_ctx, amount)?;
+            }
+        }
+
+        // Rent goes back to the proposer who paid for it
+        let close_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.deposit_token_account.to_account_info(),
+                destination: self.proposer.to_account_info(),
+                authority: self.proposal_deposit.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::close_account(close_ctx)?;
+
+        Ok(amount)
+    }
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, state::*};
+use crate::{constants::*, errors::*, state::*};
 
 // Initialize DAO Instruction
 //
@@ -12,6 +12,10 @@ use crate::{constants::*, state::*};
 // - Config PDA prevents unauthorized modification
 // - Minimum stake requirement set at initialization
 // - Vote power multiplier configurable
+// - Proposal quorum and approval threshold validated
+// - Reputation decay rate and epoch length validated
+// - Stake age ramp validated
+// - Proposal deposit configurable (0 disables it)
 // - System starts unpaused by default
 
 #[derive(Accounts)]
@@ -45,17 +49,60 @@ impl<'info> InitializeDaoProgram<'info> {
         admin: Pubkey,
         token_mint: Pubkey,
         vote_power: u8,
+        quorum: u64,
+        approval_threshold_bps: u16,
+        unbonding_period: i64,
+        decay_rate_bps: u16,
+        decay_epoch_seconds: i64,
+        reward_rate_per_sec: u64,
+        stake_ramp_seconds: i64,
+        proposal_deposit: u64,
         bumps: InitializeDaoProgramBumps,
     ) -> Result<()> {
         // SECURITY: Admin passed as parameter instead of using signer
         // This allows flexibility in who initializes vs who controls the DAO
         // The admin derives the config PDA and has special privileges
 
+        // SECURITY: Proposal parameters must be meaningful
+        // A zero quorum lets a single ballot decide a proposal
+        // A threshold above 100% makes every proposal fail
+        require!(
+            quorum > 0
+                && approval_threshold_bps > 0
+                && approval_threshold_bps as u64 <= MAX_BASIS_POINTS,
+            GovernanceError::InvalidGovernanceParams
+        );
+
+        // SECURITY: A zero unbonding period makes unstaked tokens liquid
+        // in the same block, defeating the lockup
+        require!(unbonding_period > 0, GovernanceError::InvalidUnbondingPeriod);
+
+        // SECURITY: Decay is capped at 100% per epoch, and a zero-length
+        // epoch would make elapsed-epoch counting meaningless
+        require!(
+            decay_rate_bps as u64 <= MAX_BASIS_POINTS && decay_epoch_seconds > 0,
+            GovernanceError::InvalidDecayParams
+        );
+
+        // SECURITY: A negative ramp would read every stake as older than it is
+        require!(stake_ramp_seconds >= 0, GovernanceError::InvalidStakeRamp);
+
         self.config.set_inner(Config {
             admin: admin.key(),
             minimum_stake,
             token_mint,
             vote_power,
+            quorum,
+            approval_threshold_bps,
+            proposal_count: 0,
+            unbonding_period,
+            decay_rate_bps,
+            decay_epoch_seconds,
+            reward_rate_per_sec,
+            pending_admin: Pubkey::default(),
+            current_season: 0,
+            stake_ramp_seconds,
+            proposal_deposit,
             is_paused: false,
             config_bump: bumps.config,
         });
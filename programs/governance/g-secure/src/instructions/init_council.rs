@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, events::*, state::*};
+
+// Initialize Council Instruction
+//
+// Admin-only, one-time setup of the emergency council
+// The council can pause the DAO or veto a proposal within veto_window seconds
+// of its voting end, and only with `threshold` member signatures
+//
+// SECURITY FEATURES:
+// - Admin-only access (signer checked against config.admin)
+// - Council PDA uses 'init', so members cannot be swapped afterwards
+// - 1 <= threshold <= members.len() <= MAX_COUNCIL_MEMBERS, no duplicates
+// - Veto window bounded to (0, MAX_VETO_WINDOW_SECONDS]
+
+#[derive(Accounts)]
+pub struct InitCouncil<'info> {
+    // Admin authority
+    // Must be the current config.admin, pays for the council account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Admin pubkey for config derivation
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Validates the signer against the current admin
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump = config.config_bump,
+        constraint = config.admin == authority.key() @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    // Council PDA
+    // Seeds: ["council", config]
+    // SECURITY: 'init' fails if a council already exists
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + Council::INIT_SPACE,
+        seeds = [COUNCIL, config.key().as_ref()],
+        bump
+    )]
+    pub council: Account<'info, Council>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitCouncil<'info> {
+    pub fn init_council(
+        &mut self,
+        members: Vec<Pubkey>,
+        threshold: u8,
+        veto_window: i64,
+        bumps: InitCouncilBumps,
+    ) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Member List Validation
+        // SECURITY: A duplicated key would count twice towards the threshold
+        require!(
+            !members.is_empty() && members.len() <= MAX_COUNCIL_MEMBERS,
+            GovernanceError::InvalidCouncilConfig
+        );
+        for (index, member) in members.iter().enumerate() {
+            require!(
+                *member != Pubkey::default() && !members[..index].contains(member),
+                GovernanceError::InvalidCouncilConfig
+            );
+        }
+
+        // 2. Threshold Validation
+        require!(
+            threshold >= 1 && threshold as usize <= members.len(),
+            GovernanceError::InvalidCouncilConfig
+        );
+
+        // 3. Veto Window Validation
+        require!(
+            veto_window > 0 && veto_window <= MAX_VETO_WINDOW_SECONDS,
+            GovernanceError::InvalidCouncilConfig
+        );
+
+        // 4. Record Council
+        self.council.set_inner(Council {
+            config: self.config.key(),
+            members: members.clone(),
+            threshold,
+            veto_window,
+            bump: bumps.council,
+        });
+
+        emit!(CouncilInitialized {
+            config: self.config.key(),
+            members,
+            threshold,
+            veto_window,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
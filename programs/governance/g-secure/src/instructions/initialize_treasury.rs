@@ -101,7 +101,9 @@ impl<'info> InitializeTreasury<'info> {
             admin: self.admin.key(),
             total_staked: 0,
             stakers_count: 0,
+            total_unbonding: 0,
             treasury_token_account: self.treasury_token_account.key(),
+            holdings_count: 0,
             state_bump: bumps.treasury,
             vault_bump: bumps.treasury_authority,
         });
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Lock Tokens Instruction
+//
+// Locks base-mint tokens in a VoteEscrow PDA until now + duration (veCRV style)
+// Locked tokens vote through cast_ballot with power that decays linearly to
+// zero at the unlock time; they are released by withdraw_expired_lock
+//
+// SECURITY FEATURES:
+// - Duration bounded by MIN_LOCK_SECONDS and MAX_LOCK_SECONDS
+// - One lock per user per DAO (escrow PDA uses 'init')
+// - Token mint validation (base mint only)
+// - Tokens held in the escrow's own ATA, apart from the treasury
+// - Checked arithmetic for the unlock time
+// - System pause check
+
+#[derive(Accounts)]
+pub struct LockTokens<'info> {
+    // User locking tokens
+    // Pays for the escrow and its token account
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Admin pubkey for PDA derivation
+    /// CHECK: Used for config PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Validates token mint and pause state
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Vote escrow PDA
+    // Seeds: ["vote_escrow", config, user]
+    // SECURITY: 'init' fails if the user already has a lock in this DAO
+    #[account(
+        init,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR + VoteEscrow::INIT_SPACE,
+        seeds = [VOTE_ESCROW, config.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub vote_escrow: Account<'info, VoteEscrow>,
+
+    // Token mint account
+    // SECURITY: Must match config.token_mint
+    #[account(
+        address = config.token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub token_mint_account: Account<'info, Mint>,
+
+    // User's token account (source)
+    // SECURITY: Validated as user's ATA for correct mint
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    // Escrow token account (destination)
+    // SECURITY: ATA of the escrow PDA, so only the escrow can release it
+    #[account(
+        init,
+        payer = user,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = vote_escrow
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> LockTokens<'info> {
+    pub fn lock_tokens(&mut self, amount: u64, duration: i64, bumps: LockTokensBumps) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Amount Validation
+        // Prevents zero-value locks
+        require!(amount > 0, GovernanceError::InvalidStakeAmount);
+
+        // 2. Duration Validation
+        // SECURITY: Power is normalised to MAX_LOCK_SECONDS, so longer locks
+        // would count above 1x
+        require!(
+            (MIN_LOCK_SECONDS..=MAX_LOCK_SECONDS).contains(&duration),
+            GovernanceError::InvalidLockDuration
+        );
+
+        // 3. System Pause Check
+        // Prevents locking during maintenance
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        // 4. User Balance Check
+        // Ensures user has sufficient tokens
+        require!(
+            self.user_token_account.amount >= amount,
+            GovernanceError::InsufficientStake
+        );
+
+        // 5. Transfer Tokens to Escrow
+        // User signs the transfer from their account to the escrow
+        let transfer_ctx = CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.user_token_account.to_account_info(),
+                to: self.escrow_token_account.to_account_info(),
+                authority: self.user.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, amount)?;
+
+        // 6. Record Lock
+        // SECURITY: Unlock time taken from the clock's unix timestamp
+        let current_time = Clock::get()?.unix_timestamp;
+        let unlock_at = current_time
+            .checked_add(duration)
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        self.vote_escrow.set_inner(VoteEscrow {
+            owner: self.user.key(),
+            config: self.config.key(),
+            amount,
+            locked_at: current_time,
+            unlock_at,
+            bump: bumps.vote_escrow,
+        });
+
+        Ok(())
+    }
+}
@@ -5,6 +5,36 @@ pub mod stake_tokens;
 pub mod unstake_tokens;
 pub mod vote;
 pub mod reset_season;
+pub mod create_governance_proposal;
+pub mod cast_ballot;
+pub mod finalize_proposal;
+pub mod claim_unbonded;
+pub mod delegate_votes;
+pub mod revoke_delegation;
+pub mod decay_reputation;
+pub mod claim_rewards;
+pub mod change_username;
+pub mod close_profile;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod slash_stake;
+pub mod close_season;
+pub mod claim_season_reward;
+pub mod add_supported_mint;
+pub mod attach_multisig_action;
+pub mod execute_multisig_action;
+pub mod submit_vote_batch;
+pub mod close_vote_record;
+pub mod close_cooldown;
+pub mod init_council;
+pub mod council_set_pause;
+pub mod council_veto;
+pub mod lock_tokens;
+pub mod extend_lock;
+pub mod withdraw_expired_lock;
+pub mod register_treasury_holding;
+pub mod treasury_value_report;
+pub mod update_profile_metadata;
 
 pub use init::*;
 pub use initialize_treasury::*;
@@ -12,4 +42,34 @@ pub use create_profile::*;
 pub use stake_tokens::*;
 pub use unstake_tokens::*;
 pub use vote::*;
-pub use reset_season::*;
\ No newline at end of file
+pub use reset_season::*;
+pub use create_governance_proposal::*;
+pub use cast_ballot::*;
+pub use finalize_proposal::*;
+pub use claim_unbonded::*;
+pub use delegate_votes::*;
+pub use revoke_delegation::*;
+pub use decay_reputation::*;
+pub use claim_rewards::*;
+pub use change_username::*;
+pub use close_profile::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use slash_stake::*;
+pub use close_season::*;
+pub use claim_season_reward::*;
+pub use add_supported_mint::*;
+pub use attach_multisig_action::*;
+pub use execute_multisig_action::*;
+pub use submit_vote_batch::*;
+pub use close_vote_record::*;
+pub use close_cooldown::*;
+pub use init_council::*;
+pub use council_set_pause::*;
+pub use council_veto::*;
+pub use lock_tokens::*;
+pub use extend_lock::*;
+pub use withdraw_expired_lock::*;
+pub use register_treasury_holding::*;
+pub use treasury_value_report::*;
+pub use update_profile_metadata::*;
\ No newline at end of file
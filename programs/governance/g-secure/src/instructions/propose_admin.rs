@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Propose Admin Instruction
+//
+// First step of a two-step admin transfer
+// Records the proposed admin; the role only moves once they accept
+//
+// SECURITY FEATURES:
+// - Only the current admin can propose (signature required)
+// - Admin role unchanged until the new key signs accept_admin
+// - A mistyped key cannot brick the DAO (it simply never accepts)
+// - Proposing Pubkey::default() cancels a pending transfer
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    // Current admin
+    // Must match config.admin
+    pub authority: Signer<'info>,
+
+    // Admin pubkey for PDA derivation
+    /// CHECK: Used for config PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Seeds stay on the original admin key so existing PDAs remain valid
+    // Authority checked against the current admin field
+    #[account(
+        mut,
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump = config.config_bump,
+        constraint = config.admin == authority.key() @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> ProposeAdmin<'info> {
+    pub fn propose_admin(&mut self, new_admin: Pubkey) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Record Pending Admin
+        // SECURITY: No authority moves here; accept_admin completes the transfer
+        self.config.pending_admin = new_admin;
+
+        msg!(
+            "Admin transfer proposed: {} -> {}",
+            self.config.admin,
+            new_admin
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Register Treasury Holding Instruction
+//
+// Admin-only registration of a token the treasury holds, with the oracle feed
+// that prices it. Registered holdings make up the portfolio summed by
+// treasury_value_report. Any mint may be registered, including the base mint
+// and supported mints, whose staked tokens the report leaves out
+//
+// SECURITY FEATURES:
+// - Admin-only access (signer checked against config.admin)
+// - Holding count capped at MAX_TREASURY_HOLDINGS
+// - Holding PDA uses 'init', so a mint cannot be registered twice
+// - Feed must be a PriceFeed owned by the given oracle program, with a usable price
+// - Treasury ATA owned by the treasury authority PDA
+// - System pause check
+
+#[derive(Accounts)]
+pub struct RegisterTreasuryHolding<'info> {
+    // Admin authority
+    // Must be the current config.admin, pays for the new accounts
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Admin pubkey for PDA derivation
+    /// CHECK: Used for config and treasury PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Validates the signer against the current admin
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump = config.config_bump,
+        constraint = config.admin == authority.key() @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    // Treasury state PDA
+    // Seeds: ["treasury", admin]
+    // Records the holding count
+    #[account(
+        mut,
+        seeds = [TREASURY, admin.key().as_ref()],
+        bump = treasury.state_bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    // Treasury authority PDA
+    // Seeds: ["treasury_auth", config, admin]
+    // SECURITY: Owns the holding's token account
+    #[account(
+        seeds = [TREASURYAUTH, config.key().as_ref(), admin.key().as_ref()],
+        bump = treasury.vault_bump,
+    )]
+    /// CHECK: PDA authority for treasury token accounts
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    // Mint being registered
+    pub token_mint_account: Account<'info, Mint>,
+
+    // Treasury holding PDA
+    // Seeds: ["treasury_holding", treasury, mint]
+    // SECURITY: 'init' fails if the mint is already registered
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + TreasuryHolding::INIT_SPACE,
+        seeds = [TREASURY_HOLDING, treasury.key().as_ref(), token_mint_account.key().as_ref()],
+        bump
+    )]
+    pub treasury_holding: Account<'info, TreasuryHolding>,
+
+    // Treasury token account for this mint (ATA)
+    // Already exists for the base mint and supported mints
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = treasury_authority,
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    // Oracle feed pricing this mint
+    /// CHECK: Owner and layout checked by PriceFeed::load
+    pub price_feed: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterTreasuryHolding<'info> {
+    pub fn register_treasury_holding(
+        &mut self,
+        oracle_program: Pubkey,
+        bumps: RegisterTreasuryHoldingBumps,
+    ) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. System Pause Check
+        // Prevents registry changes during maintenance
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        // 2. Holding Cap
+        // SECURITY: Bounds the accounts every value report must pass
+        require!(
+            self.treasury.holdings_count < MAX_TREASURY_HOLDINGS,
+            GovernanceError::TooManyTreasuryHoldings
+        );
+
+        // 3. Price Feed Validation
+        // SECURITY: A feed that cannot be read now would make every report fail
+        let price_feed = PriceFeed::load(&self.price_feed.to_account_info(), &oracle_program)?;
+        price_feed.assert_usable(Clock::get()?.unix_timestamp)?;
+
+        // 4. Record Holding
+        let index = self.treasury.holdings_count;
+        self.treasury_holding.set_inner(TreasuryHolding {
+            treasury: self.treasury.key(),
+            mint: self.token_mint_account.key(),
+            token_account: self.treasury_token_account.key(),
+            oracle_program,
+            price_feed: self.price_feed.key(),
+            decimals: self.token_mint_account.decimals,
+            index,
+            bump: bumps.treasury_holding,
+        });
+
+        self.treasury.holdings_count = index
+            .checked_add(1)
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        msg!(
+            "Treasury holding {} registered: mint {}, feed {}",
+            index,
+            self.token_mint_account.key(),
+            self.price_feed.key()
+        );
+
+        Ok(())
+    }
+}
@@ -11,25 +11,30 @@ use crate::{constants::*, errors::*, state::*};
 // - Admin-only access (validated via config PDA)
 // - Does not affect user's stake amount
 // - Resets role to Member
+// - Final reputation recorded in the season snapshot before it is cleared
 // - System pause check
 // - Logs the reset action for auditability
 
 #[derive(Accounts)]
 #[instruction(user: Pubkey)]
 pub struct ResetUserReputation<'info> {
-    // Admin account
-    // Must be the configured admin
+    // Admin authority
+    // Must be the current config.admin (may differ from the seed key after rotation)
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    // Admin pubkey for PDA derivation
+    /// CHECK: Used for config PDA derivation
+    pub admin: UncheckedAccount<'info>,
 
     // Config PDA
     // Seeds: ["config", admin]
-    // SECURITY: Validates admin authority via has_one constraint
+    // SECURITY: Validates the signer against the current admin
     // Only the designated admin can perform this action
     #[account(
         seeds = [CONFIG, admin.key().as_ref()],
         bump,
-        has_one = admin @ GovernanceError::UnauthorizedAdmin
+        constraint = config.admin == authority.key() @ GovernanceError::UnauthorizedAdmin
     )]
     pub config: Account<'info, Config>,
 
@@ -42,19 +47,45 @@ pub struct ResetUserReputation<'info> {
         bump
     )]
     pub user_profile: Account<'info, UserProfile>,
+
+    // Season snapshot PDA
+    // Seeds: ["season_snapshot", config, current_season]
+    // SECURITY: Standings for the open season only
+    // Created by the first reset of the season, paid for by the admin
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + SeasonSnapshot::INIT_SPACE,
+        seeds = [SEASON_SNAPSHOT, config.key().as_ref(), &config.current_season.to_le_bytes()],
+        bump
+    )]
+    pub season_snapshot: Account<'info, SeasonSnapshot>,
+
+    pub system_program: Program<'info, System>,
 }
 
 impl<'info> ResetUserReputation<'info> {
-    pub fn reset_user_reputation(&mut self) -> Result<()> {
+    pub fn reset_user_reputation(&mut self, bumps: ResetUserReputationBumps) -> Result<()> {
         // SECURITY CHECKS
 
         // 1. System Pause Check
         // Prevents reputation resets during maintenance
         require!(!self.config.is_paused, GovernanceError::SystemPaused);
 
+        // 2. Record Season Standing
+        // SECURITY: Snapshot the reputation before it is cleared
+        // Standings are what claim_season_reward pays against
+        let snapshot = &mut self.season_snapshot;
+        if snapshot.config == Pubkey::default() {
+            snapshot.config = self.config.key();
+            snapshot.season_id = self.config.current_season;
+            snapshot.bump = bumps.season_snapshot;
+        }
+        snapshot.record(self.user_profile.owner, self.user_profile.reputation_points);
+
         let user_profile = &mut self.user_profile;
 
-        // 2. Reset Reputation Fields
+        // 3. Reset Reputation Fields
         // SECURITY: Only resets reputation-related data
         // Does NOT reset:
         // - Username (permanent identity)
@@ -68,7 +99,7 @@ impl<'info> ResetUserReputation<'info> {
         user_profile.downvotes_received = 0;
         user_profile.role_level = MemberRanks::Member;
 
-        // 3. Log Reset Action
+        // 4. Log Reset Action
         // Provides audit trail for admin actions
         msg!(
             "Reset reputation for user: {} ({})",
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Revoke Delegation Instruction
+//
+// Ends an active delegation and returns voting power to the delegator
+// Closes the delegation record and refunds its rent
+//
+// SECURITY FEATURES:
+// - Only the delegator can revoke
+// - Delegate profile validated against the delegation record
+// - Exact recorded amount removed (no drift between profiles)
+// - Delegator's stake timestamp reset (no voting twice on open proposals)
+// - Checked arithmetic prevents underflow
+
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    // Delegator account
+    // Receives the delegation rent
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    // Delegator's profile
+    // Seeds: ["user_profile", delegator]
+    // SECURITY: Validates ownership and clears the delegate
+    #[account(
+        mut,
+        seeds = [USERPROFILE, delegator.key().as_ref()],
+        bump,
+        constraint = delegator_profile.owner == delegator.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub delegator_profile: Account<'info, UserProfile>,
+
+    // Delegate's profile
+    // Seeds: ["user_profile", delegation.delegate]
+    // SECURITY: Must be the profile recorded in the delegation
+    #[account(
+        mut,
+        seeds = [USERPROFILE, delegation.delegate.as_ref()],
+        bump,
+    )]
+    pub delegate_profile: Account<'info, UserProfile>,
+
+    // Delegation record
+    // Seeds: ["delegation", delegator]
+    // SECURITY: Closed on revoke so the amount cannot be removed twice
+    #[account(
+        mut,
+        close = delegator,
+        seeds = [DELEGATION, delegator.key().as_ref()],
+        bump = delegation.bump,
+        constraint = delegation.delegator == delegator.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub delegation: Account<'info, Delegation>,
+}
+
+impl<'info> RevokeDelegation<'info> {
+    pub fn revoke_delegation(&mut self) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Remove Delegated Power
+        // SECURITY: Checked subtraction prevents underflow
+        let delegate_profile = &mut self.delegate_profile;
+        delegate_profile.delegated_stake = delegate_profile
+            .delegated_stake
+            .checked_sub(self.delegation.amount)
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        // 2. Restore Delegator
+        // SECURITY: Stake timestamp reset so the delegator cannot vote on
+        // proposals their delegate may already have voted on
+        let delegator_profile = &mut self.delegator_profile;
+        delegator_profile.delegated_to = Pubkey::default();
+        delegator_profile.last_stake_timestamp = Clock::get()?.unix_timestamp;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use soteria_common::math::mul_div_floor;
+
+use crate::{constants::*, errors::*, events::*, state::*};
+
+// Slash Stake Instruction
+//
+// Admin-only penalty for malicious voters
+// Removes a percentage of the user's active stake and its voting power
+// Slashed tokens are either burned or kept in the treasury reward reserve
+//
+// SECURITY FEATURES:
+// - Admin-only access (signer checked against config.admin)
+// - Slash bounded to 1-10,000 basis points
+// - Rewards settled on the pre-slash stake
+// - Delegated power trimmed so the delegate cannot keep slashed votes
+// - Treasury PDA authority signs burns (no private keys)
+// - Checked arithmetic prevents overflow/underflow
+// - StakeSlashed event for off-chain monitoring
+
+#[derive(Accounts)]
+pub struct SlashStake<'info> {
+    // Admin authority
+    // Must be the current config.admin
+    pub authority: Signer<'info>,
+
+    // Admin pubkey for PDA derivation
+    /// CHECK: Used for config and treasury PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Validates the signer against the current admin
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+        constraint = config.admin == authority.key() @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    // Treasury state PDA
+    // Seeds: ["treasury", admin]
+    // SECURITY: Tracks total staked and staker count
+    #[account(
+        mut,
+        seeds = [TREASURY, admin.key().as_ref()],
+        bump = treasury.state_bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    // Treasury authority PDA
+    // Seeds: ["treasury_auth", config, admin]
+    // SECURITY: PDA signer for burning slashed tokens
+    #[account(
+        seeds = [TREASURYAUTH, config.key().as_ref(), admin.key().as_ref()],
+        bump = treasury.vault_bump,
+    )]
+    /// CHECK: PDA authority for signing treasury burns
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    // Slashed user's profile
+    // Seeds: ["user_profile", user_profile.owner]
+    // SECURITY: PDA derivation ensures a real profile is slashed
+    #[account(
+        mut,
+        seeds = [USERPROFILE, user_profile.owner.as_ref()],
+        bump,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    // Delegation record (only when the user has delegated)
+    // Seeds: ["delegation", user_profile.owner]
+    // SECURITY: Amount trimmed so revoke removes the right power
+    #[account(
+        mut,
+        seeds = [DELEGATION, user_profile.owner.as_ref()],
+        bump = delegation.bump,
+    )]
+    pub delegation: Option<Account<'info, Delegation>>,
+
+    // Delegate's profile (only when the user has delegated)
+    // SECURITY: Checked against the delegation record in the handler
+    #[account(mut)]
+    pub delegate_profile: Option<Account<'info, UserProfile>>,
+
+    // Token mint account
+    // SECURITY: Must match config.token_mint (supply changes on burn)
+    #[account(
+        mut,
+        address = config.token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub token_mint_account: Account<'info, Mint>,
+
+    // Treasury token account
+    // SECURITY: Validated against treasury state
+    #[account(
+        mut,
+        address = treasury.treasury_token_account @ GovernanceError::InvalidTreasuryAccount
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> SlashStake<'info> {
+    pub fn slash_stake(&mut self, slash_bps: u16, burn: bool) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Slash Bounds Validation
+        // SECURITY: Cannot slash nothing or more than the whole stake
+        require!(
+            slash_bps > 0 && slash_bps as u64 <= MAX_BASIS_POINTS,
+            GovernanceError::InvalidSlashAmount
+        );
+
+        // 2. Calculate Slash Amount
+        // SECURITY: u128 intermediate prevents overflow on large stakes
+        let stake_amount = self.user_profile.stake_amount;
+        let amount = mul_div_floor(stake_amount, slash_bps as u64, MAX_BASIS_POINTS)
+            .ok_or(GovernanceError::MathOverflow)?;
+        require!(amount > 0, GovernanceError::InvalidSlashAmount);
+
+        let new_stake_amount = stake_amount
+            .checked_sub(amount)
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        // 3. Trim Delegated Power
+        // SECURITY: A delegate cannot keep votes backed by slashed tokens
+        // Delegation is capped at the power that remains after the slash
+        // (remaining base stake plus supported-mint weighted stake)
+        let remaining_power = new_stake_amount
+            .checked_add(self.user_profile.weighted_stake)
+            .ok_or(GovernanceError::MathOverflow)?;
+        if self.user_profile.delegated_to != Pubkey::default() {
+            let (Some(delegation), Some(delegate_profile)) =
+                (self.delegation.as_mut(), self.delegate_profile.as_mut())
+            else {
+                return err!(GovernanceError::MissingDelegationAccounts);
+            };
+            require!(
+                delegate_profile.owner == delegation.delegate,
+                GovernanceError::ProfileMismatch
+            );
+
+            let excess = delegation.amount.saturating_sub(remaining_power);
+            delegation.amount = delegation
+                .amount
+                .checked_sub(excess)
+                .ok_or(GovernanceError::MathOverflow)?;
+            delegate_profile.delegated_stake = delegate_profile
+                .delegated_stake
+                .checked_sub(excess)
+                .ok_or(GovernanceError::MathOverflow)?;
+        }
+
+        // 4. Update User Profile
+        // SECURITY: Settle rewards on the pre-slash stake before it changes
+        let current_time = Clock::get()?.unix_timestamp;
+        let user_profile = &mut self.user_profile;
+        user_profile
+            .accrue_rewards(self.config.reward_rate_per_sec, current_time)
+            .ok_or(GovernanceError::MathOverflow)?;
+        user_profile.stake_amount = new_stake_amount;
+
+        // 5. Update Treasury Totals
+        // SECURITY: Checked subtraction prevents underflow
+        // Redirected tokens stay in the treasury as reward reserve
+        let treasury = &mut self.treasury;
+        treasury.total_staked = treasury
+            .total_staked
+            .checked_sub(amount)
+            .ok_or(GovernanceError::MathOverflow)?;
+        if new_stake_amount == 0 {
+            treasury.stakers_count = treasury
+                .stakers_count
+                .checked_sub(1)
+                .ok_or(GovernanceError::MathOverflow)?;
+        }
+
+        // 6. Burn Slashed Tokens
+        // SECURITY: Uses PDA authority to sign the burn
+        if burn {
+            let config = self.config.key();
+            let admin = self.admin.key();
+            let treasury_auth_seeds = &[
+                TREASURYAUTH,
+                config.as_ref(),
+                admin.as_ref(),
+                &[self.treasury.vault_bump],
+            ];
+            let signer_seeds = &[&treasury_auth_seeds[..]];
+
+            let burn_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Burn {
+                    mint: self.token_mint_account.to_account_info(),
+                    from: self.treasury_token_account.to_account_info(),
+                    authority: self.treasury_authority.to_account_info(),
+                },
+                signer_seeds,
+            );
+            token::burn(burn_ctx, amount)?;
+        }
+
+        // 7. Emit Slash Event
+        emit!(StakeSlashed {
+            user: self.user_profile.owner,
+            slashed_by: self.authority.key(),
+            slash_bps,
+            amount,
+            remaining_stake: new_stake_amount,
+            burned: burn,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+}
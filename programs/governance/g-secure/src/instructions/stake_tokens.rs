@@ -10,10 +10,13 @@ use crate::{constants::*, errors::*, state::*};
 //
 // Allows users to stake tokens to gain voting rights
 // Staked tokens are held in the treasury until unstaked
+// The base mint (config.token_mint) counts 1x; any mint registered through
+// add_supported_mint is held in its own treasury ATA and counts at its weight
 //
 // SECURITY FEATURES:
-// - Minimum stake requirement prevents dust staking
-// - Token mint validation prevents wrong token
+// - Minimum stake requirement prevents dust staking (applied to voting power)
+// - Token mint validation: base mint or a registered SupportedMint
+// - Treasury account validated against the treasury or registry entry
 // - User profile ownership validation
 // - Checked arithmetic prevents overflow
 // - System pause check
@@ -61,10 +64,8 @@ pub struct Stake<'info> {
     pub user_profile: Account<'info, UserProfile>,
 
     // Token mint account
-    // SECURITY: Must match config.token_mint
-    #[account(
-        address = config.token_mint @ GovernanceError::InvalidTokenMint
-    )]
+    // SECURITY: Must match config.token_mint unless supported_mint is passed
+    // Checked in the handler
     pub token_mint_account: Account<'info, Mint>,
 
     // User's token account (source)
@@ -77,12 +78,36 @@ pub struct Stake<'info> {
     pub user_token_account: Account<'info, TokenAccount>,
 
     // Treasury token account (destination)
-    // SECURITY: Validated against treasury state
+    // SECURITY: Validated against the treasury state for the base mint,
+    // or against the registry entry for a supported mint (in the handler)
+    #[account(mut)]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    // Supported mint registry entry (only when staking a non-base mint)
+    // Seeds: ["supported_mint", config, mint]
+    // SECURITY: Seeds bind the weight to the mint actually being staked
     #[account(
         mut,
-        address = treasury.treasury_token_account @ GovernanceError::InvalidTreasuryAccount
+        seeds = [SUPPORTED_MINT, config.key().as_ref(), token_mint_account.key().as_ref()],
+        bump = supported_mint.bump,
     )]
-    pub treasury_token_account: Account<'info, TokenAccount>,
+    pub supported_mint: Option<Account<'info, SupportedMint>>,
+
+    // User's stake record for the supported mint (only with supported_mint)
+    // Seeds: ["mint_stake", config, mint, user]
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR + MintStake::INIT_SPACE,
+        seeds = [
+            MINT_STAKE,
+            config.key().as_ref(),
+            token_mint_account.key().as_ref(),
+            user.key().as_ref(),
+        ],
+        bump
+    )]
+    pub mint_stake: Option<Account<'info, MintStake>>,
 
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -90,32 +115,66 @@ pub struct Stake<'info> {
 }
 
 impl<'info> Stake<'info> {
-    pub fn stake_tokens(&mut self, amount: u64) -> Result<()> {
+    pub fn stake_tokens(&mut self, amount: u64, bumps: StakeBumps) -> Result<()> {
         // SECURITY CHECKS
 
         // 1. Amount Validation
         // Prevents zero-value stakes
         require!(amount > 0, GovernanceError::InvalidStakeAmount);
 
-        // 2. Minimum Stake Enforcement
+        // 2. Resolve Staked Mint
+        // SECURITY: The base mint goes to the primary treasury at 1x
+        // Any other mint must be registered and goes to its own treasury ATA
+        require!(
+            self.supported_mint.is_some() == self.mint_stake.is_some(),
+            GovernanceError::MissingMintStakeAccount
+        );
+        let power = match self.supported_mint.as_ref() {
+            None => {
+                require_keys_eq!(
+                    self.token_mint_account.key(),
+                    self.config.token_mint,
+                    GovernanceError::InvalidTokenMint
+                );
+                require_keys_eq!(
+                    self.treasury_token_account.key(),
+                    self.treasury.treasury_token_account,
+                    GovernanceError::InvalidTreasuryAccount
+                );
+                amount
+            }
+            Some(supported_mint) => {
+                require_keys_eq!(
+                    self.treasury_token_account.key(),
+                    supported_mint.treasury_token_account,
+                    GovernanceError::InvalidTreasuryAccount
+                );
+                supported_mint
+                    .voting_power(amount)
+                    .ok_or(GovernanceError::MathOverflow)?
+            }
+        };
+
+        // 3. Minimum Stake Enforcement
         // SECURITY: Prevents sybil attacks with dust stakes
+        // Compared against voting power so a low-weight mint cannot dust-stake
         require!(
-            amount >= self.config.minimum_stake,
+            power >= self.config.minimum_stake,
             GovernanceError::MinimumStakeRequired
         );
 
-        // 3. System Pause Check
+        // 4. System Pause Check
         // Prevents staking during maintenance
         require!(!self.config.is_paused, GovernanceError::SystemPaused);
 
-        // 4. User Balance Check
+        // 5. User Balance Check
         // Ensures user has sufficient tokens
         require!(
             self.user_token_account.amount >= amount,
             GovernanceError::InsufficientStake
         );
 
-        // 5. Transfer Tokens to Treasury
+        // 6. Transfer Tokens to Treasury
         // User signs the transfer from their account to treasury
         let transfer_ctx = CpiContext::new(
             self.token_program.to_account_info(),
@@ -127,22 +186,77 @@ impl<'info> Stake<'info> {
         );
         token::transfer(transfer_ctx, amount)?;
 
-        // 6. Update User Profile
+        // 7. Credit Supported-Mint Stake
+        // Weighted power is added to the profile; rewards accrue on base stake only
+        if let (Some(supported_mint), Some(mint_stake)) =
+            (self.supported_mint.as_mut(), self.mint_stake.as_mut())
+        {
+            if mint_stake.owner == Pubkey::default() {
+                mint_stake.owner = self.user.key();
+                mint_stake.mint = supported_mint.mint;
+                mint_stake.bump = bumps.mint_stake;
+            }
+            mint_stake.amount = mint_stake
+                .amount
+                .checked_add(amount)
+                .ok_or(GovernanceError::MathOverflow)?;
+            mint_stake.voting_power = mint_stake
+                .voting_power
+                .checked_add(power)
+                .ok_or(GovernanceError::MathOverflow)?;
+            supported_mint.total_staked = supported_mint
+                .total_staked
+                .checked_add(amount)
+                .ok_or(GovernanceError::MathOverflow)?;
+
+            let user_profile = &mut self.user_profile;
+            // SECURITY: New power starts maturing now, aged power keeps its age
+            user_profile
+                .add_stake_age(power, Clock::get()?.unix_timestamp)
+                .ok_or(GovernanceError::MathOverflow)?;
+            user_profile.weighted_stake = user_profile
+                .weighted_stake
+                .checked_add(power)
+                .ok_or(GovernanceError::MathOverflow)?;
+
+            // SECURITY: Weighted power obeys the same stake snapshot rule
+            user_profile.last_stake_timestamp = Clock::get()?.unix_timestamp;
+            user_profile.role_level = MemberRanks::from_reputation(user_profile.reputation_points);
+
+            return Ok(());
+        }
+
+        // 8. Update User Profile
         // Track if this is the user's first stake
         let user_profile = &mut self.user_profile;
         let was_new_staker = user_profile.stake_amount == 0;
 
+        // SECURITY: Settle rewards on the old stake before it changes
+        // New tokens must not earn rewards for time they were not staked
+        user_profile
+            .accrue_rewards(self.config.reward_rate_per_sec, Clock::get()?.unix_timestamp)
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        // SECURITY: New stake starts maturing now, aged stake keeps its age
+        user_profile
+            .add_stake_age(amount, Clock::get()?.unix_timestamp)
+            .ok_or(GovernanceError::MathOverflow)?;
+
         // SECURITY: Checked addition prevents overflow
         user_profile.stake_amount = user_profile
             .stake_amount
             .checked_add(amount)
             .ok_or(GovernanceError::MathOverflow)?;
 
-        // 7. Update Role Level
+        // SECURITY: Record when stake last increased
+        // Proposal ballots only count stake that predates the proposal
+        user_profile.last_stake_timestamp = Clock::get()?.unix_timestamp;
+
+        // 9. Update Role Level
         // Role automatically updates based on reputation
         user_profile.role_level = MemberRanks::from_reputation(user_profile.reputation_points);
 
-        // 8. Update Treasury Totals
+        // 10. Update Treasury Totals
         // SECURITY: Checked addition prevents overflow
         let treasury = &mut self.treasury;
         treasury.total_staked = treasury
@@ -150,7 +264,7 @@ impl<'info> Stake<'info> {
             .checked_add(amount)
             .ok_or(GovernanceError::MathOverflow)?;
 
-        // 9. Increment Stakers Count
+        // 11. Increment Stakers Count
         // Only increment for first-time stakers
         if was_new_staker {
             treasury.stakers_count = treasury
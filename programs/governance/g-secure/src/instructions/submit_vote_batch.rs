@@ -0,0 +1,312 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    instruction::Instruction,
+    sysvar::instructions::{
+        self as instructions_sysvar, load_current_index_checked, load_instruction_at_checked,
+    },
+};
+use anchor_lang::system_program::{create_account, CreateAccount};
+
+use crate::{constants::*, errors::*, require_rank, state::*};
+
+// Submit Vote Batch Instruction
+//
+// Lets a relayer settle many off-chain signed votes in one transaction
+// Each entry (voter, support, weight) is signed by the voter's wallet key
+// The transaction places one Ed25519 instruction per entry, in entry order,
+// directly before this one; the precompile checks the signatures and this
+// instruction checks, via the instructions sysvar, that each one signed the entry
+//
+// Remaining accounts: (voter_profile, ballot) for each entry, in entry order
+//
+// SECURITY FEATURES:
+// - Signature data must live in the Ed25519 instruction itself (all three
+//   instruction indices are u16::MAX), so the key and message read here are
+//   the ones the precompile verified
+// - Signed message binds the proposal, direction and weight
+// - Shares cast_ballot's ballot PDA: one ballot per voter per proposal
+// - Weight capped at the voter's effective power; stake must predate the proposal
+// - Minimum stake, minimum rank, system pause and voting window checks as in cast_ballot
+// - Checked arithmetic for tallies
+
+#[derive(Accounts)]
+pub struct SubmitVoteBatch<'info> {
+    // Relayer submitting the batch
+    // Pays for the ballot records; needs no voting power of its own
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    // Admin pubkey for config derivation
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Validates system state and minimum stake
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Proposal PDA
+    // Seeds: ["proposal", config, proposal_id]
+    // SECURITY: Seeds tie the proposal to this DAO's config
+    #[account(
+        mut,
+        seeds = [PROPOSAL, config.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    // Instructions sysvar
+    /// CHECK: Validated by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SubmitVoteBatch<'info> {
+    pub fn submit_vote_batch(
+        &mut self,
+        entries: Vec<VoteBatchEntry>,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. System Pause Check
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        // 2. Proposal Status Check
+        require!(
+            self.proposal.status == ProposalStatus::Active,
+            GovernanceError::ProposalNotActive
+        );
+
+        // 3. Voting Window Check
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time < self.proposal.voting_ends_at,
+            GovernanceError::VotingPeriodEnded
+        );
+
+        // 4. Batch Shape
+        // One (voter_profile, ballot) pair per entry
+        require!(
+            !entries.is_empty() && entries.len() <= MAX_VOTE_BATCH_SIZE,
+            GovernanceError::InvalidVoteBatchSize
+        );
+        require!(
+            remaining_accounts.len() == entries.len() * 2,
+            GovernanceError::MissingBatchAccounts
+        );
+
+        // 5. Locate the Ed25519 Instructions
+        // Entry i is verified by instruction (current - entries.len() + i)
+        let instructions = self.instructions.to_account_info();
+        let current_index = load_current_index_checked(&instructions)? as usize;
+        let first_index = current_index
+            .checked_sub(entries.len())
+            .ok_or(GovernanceError::InvalidSignatureInstruction)?;
+
+        let mut votes_for: u64 = 0;
+        let mut votes_against: u64 = 0;
+        for (i, (entry, accounts)) in entries
+            .iter()
+            .zip(remaining_accounts.chunks_exact(2))
+            .enumerate()
+        {
+            // 6. Signature Check
+            let signature_ix = load_instruction_at_checked(first_index + i, &instructions)?;
+            self.verify_vote_signature(&signature_ix, entry)?;
+
+            // 7. Voter Checks
+            self.validate_voter(entry, &accounts[0])?;
+
+            // 8. Record Ballot
+            // SECURITY: Fails if the voter already has a ballot on this proposal
+            self.record_ballot(entry, &accounts[1], current_time)?;
+
+            if entry.support {
+                votes_for = votes_for
+                    .checked_add(entry.weight)
+                    .ok_or(GovernanceError::MathOverflow)?;
+            } else {
+                votes_against = votes_against
+                    .checked_add(entry.weight)
+                    .ok_or(GovernanceError::MathOverflow)?;
+            }
+        }
+
+        // 9. Apply Tallies
+        // SECURITY: Checked addition prevents tally overflow
+        let proposal = &mut self.proposal;
+        proposal.votes_for = proposal
+            .votes_for
+            .checked_add(votes_for)
+            .ok_or(GovernanceError::MathOverflow)?;
+        proposal.votes_against = proposal
+            .votes_against
+            .checked_add(votes_against)
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        msg!(
+            "Vote batch: {} entries, {} for, {} against",
+            entries.len(),
+            votes_for,
+            votes_against
+        );
+
+        Ok(())
+    }
+
+    // Check that an Ed25519 instruction verified the voter's signature over this entry
+    fn verify_vote_signature(&self, ix: &Instruction, entry: &VoteBatchEntry) -> Result<()> {
+        require_keys_eq!(
+            ix.program_id,
+            ed25519_program::ID,
+            GovernanceError::InvalidSignatureInstruction
+        );
+
+        // Exactly one signature per instruction
+        let data = &ix.data;
+        require!(
+            data.len() >= ED25519_OFFSETS_START + ED25519_OFFSETS_SIZE && data[0] == 1,
+            GovernanceError::InvalidSignatureInstruction
+        );
+        let offset = |field: usize| {
+            let at = ED25519_OFFSETS_START + field * 2;
+            u16::from_le_bytes([data[at], data[at + 1]])
+        };
+        let signature_instruction_index = offset(1);
+        let public_key_offset = offset(2) as usize;
+        let public_key_instruction_index = offset(3);
+        let message_data_offset = offset(4) as usize;
+        let message_data_size = offset(5) as usize;
+        let message_instruction_index = offset(6);
+
+        // SECURITY: The precompile reads the signature, key and message from
+        // whichever instruction each index names. Any index other than
+        // u16::MAX means the bytes read below are not the ones it verified
+        require!(
+            signature_instruction_index == ED25519_CURRENT_INSTRUCTION
+                && public_key_instruction_index == ED25519_CURRENT_INSTRUCTION
+                && message_instruction_index == ED25519_CURRENT_INSTRUCTION,
+            GovernanceError::InvalidSignatureInstruction
+        );
+
+        let public_key = data
+            .get(public_key_offset..public_key_offset + 32)
+            .ok_or(GovernanceError::InvalidSignatureInstruction)?;
+        require!(
+            public_key == entry.voter.as_ref(),
+            GovernanceError::SignatureMismatch
+        );
+
+        let message = data
+            .get(message_data_offset..message_data_offset + message_data_size)
+            .ok_or(GovernanceError::InvalidSignatureInstruction)?;
+        require!(
+            message == entry.vote_message(&self.proposal.key()).as_slice(),
+            GovernanceError::SignatureMismatch
+        );
+
+        Ok(())
+    }
+
+    // Check the voter's profile and that the signed weight is backed by stake
+    fn validate_voter(&self, entry: &VoteBatchEntry, profile_info: &AccountInfo<'info>) -> Result<()> {
+        // SECURITY: Profile must be the voter's PDA and owned by this program
+        let (expected_profile, _) =
+            Pubkey::find_program_address(&[USERPROFILE, entry.voter.as_ref()], &crate::ID);
+        require_keys_eq!(
+            profile_info.key(),
+            expected_profile,
+            GovernanceError::ProfileMismatch
+        );
+        require_keys_eq!(
+            *profile_info.owner,
+            crate::ID,
+            GovernanceError::ProfileMismatch
+        );
+        let profile = UserProfile::try_deserialize(&mut &profile_info.try_borrow_data()?[..])?;
+
+        // SECURITY: Same eligibility as cast_ballot
+        let power = profile
+            .effective_power(self.proposal.created_at, self.config.stake_ramp_seconds)
+            .ok_or(GovernanceError::MathOverflow)?;
+        require!(
+            power >= self.config.minimum_stake,
+            GovernanceError::InsufficientStake
+        );
+        require!(
+            profile.last_stake_timestamp < self.proposal.created_at,
+            GovernanceError::StakeAfterProposal
+        );
+        require_rank!(profile, MIN_BALLOT_RANK);
+
+        // SECURITY: A signed weight above the voter's power is not backed by stake
+        require!(
+            entry.weight > 0 && entry.weight <= power,
+            GovernanceError::InvalidVoteWeight
+        );
+
+        Ok(())
+    }
+
+    // Create the voter's ballot PDA, paid by the relayer
+    fn record_ballot(
+        &self,
+        entry: &VoteBatchEntry,
+        ballot_info: &AccountInfo<'info>,
+        timestamp: i64,
+    ) -> Result<()> {
+        let proposal = self.proposal.key();
+        let (expected_ballot, bump) = Pubkey::find_program_address(
+            &[BALLOT, proposal.as_ref(), entry.voter.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            ballot_info.key(),
+            expected_ballot,
+            GovernanceError::InvalidBallotAccount
+        );
+
+        // SECURITY: create_account fails if the ballot exists, whether it came
+        // from cast_ballot, an earlier batch or an earlier entry in this one
+        let space = ANCHOR_DISCRIMINATOR + Ballot::INIT_SPACE;
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            BALLOT,
+            proposal.as_ref(),
+            entry.voter.as_ref(),
+            &[bump],
+        ]];
+        create_account(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                CreateAccount {
+                    from: self.relayer.to_account_info(),
+                    to: ballot_info.clone(),
+                },
+            )
+            .with_signer(signer_seeds),
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &crate::ID,
+        )?;
+
+        let ballot = Ballot {
+            voter: entry.voter,
+            proposal,
+            support: entry.support,
+            weight: entry.weight,
+            timestamp,
+            bump,
+        };
+        ballot.try_serialize(&mut &mut ballot_info.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+}
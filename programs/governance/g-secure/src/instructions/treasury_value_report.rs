@@ -0,0 +1,192 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Treasury Value Report Instruction
+//
+// Read-only view of the DAO's portfolio, priced on-chain from each holding's
+// registered oracle feed. Returns a TreasuryValueReport as return data
+//
+// Remaining accounts, four per holding, in holding index order:
+//   [treasury_holding, token_account, price_feed, supported_mint]
+// supported_mint is the ["supported_mint", config, mint] PDA whether or not the
+// mint was registered for staking; an empty account means nothing is staked
+//
+// Only tokens the DAO owns are counted:
+// - base mint: balance - (total_staked + total_unbonding)
+// - supported mint: balance - supported_mint.total_staked
+// - any other mint: the full balance
+//
+// SECURITY FEATURES:
+// - Every registered holding must be passed, so none can be left out of the total
+// - Token account and feed must be the ones recorded at registration
+// - Feed owner, staleness and confidence checked as in the oracle's get_price
+// - Stake liabilities subtracted, so stakers' principal is never reported as DAO funds
+// - No account is writable and no signer is required, so the report can be
+//   read from a simulated transaction
+
+#[derive(Accounts)]
+pub struct ReportTreasuryValue<'info> {
+    // Admin pubkey for PDA derivation
+    /// CHECK: Used for config and treasury PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // Provides the base mint
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Treasury state PDA
+    // Seeds: ["treasury", admin]
+    // Provides the holding count and the base mint liabilities
+    #[account(
+        seeds = [TREASURY, admin.key().as_ref()],
+        bump = treasury.state_bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+}
+
+impl<'info> ReportTreasuryValue<'info> {
+    pub fn treasury_value_report(
+        &self,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<TreasuryValueReport> {
+        // 1. Report Shape
+        // SECURITY: One group of four accounts for every registered holding
+        require!(
+            remaining_accounts.len() == self.treasury.holdings_count as usize * 4,
+            GovernanceError::IncompleteTreasuryReport
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let mut total_value: u128 = 0;
+        let mut holdings = Vec::with_capacity(self.treasury.holdings_count as usize);
+
+        for (index, accounts) in remaining_accounts.chunks_exact(4).enumerate() {
+            // 2. Holding Checks
+            let holding = self.load_holding(&accounts[0], index)?;
+
+            // 3. DAO-Owned Balance
+            let balance = self.load_balance(&holding, &accounts[1])?;
+            let liability = self.stake_liability(&holding, &accounts[3])?;
+            // A treasury short of its liabilities owns nothing of that mint
+            let amount = balance.saturating_sub(liability);
+
+            // 4. Price
+            // SECURITY: Only the feed pinned at registration, with a usable price
+            require_keys_eq!(
+                accounts[2].key(),
+                holding.price_feed,
+                GovernanceError::InvalidPriceFeed
+            );
+            let price_feed = PriceFeed::load(&accounts[2], &holding.oracle_program)?;
+            price_feed.assert_usable(now)?;
+
+            let value = price_feed.value_of(amount, holding.decimals)?;
+            total_value = total_value
+                .checked_add(value)
+                .ok_or(GovernanceError::MathOverflow)?;
+
+            holdings.push(HoldingValue {
+                mint: holding.mint,
+                amount,
+                price: price_feed.price,
+                exponent: price_feed.exponent,
+                value,
+            });
+        }
+
+        msg!(
+            "Treasury value: {} across {} holdings",
+            total_value,
+            holdings.len()
+        );
+
+        Ok(TreasuryValueReport {
+            total_value,
+            holdings,
+        })
+    }
+
+    // Deserialize the holding at `index` and check it belongs to this treasury
+    fn load_holding(&self, holding_info: &AccountInfo<'info>, index: usize) -> Result<TreasuryHolding> {
+        // SECURITY: Owned by this program, so the index and treasury fields are ours
+        require_keys_eq!(
+            *holding_info.owner,
+            crate::ID,
+            GovernanceError::IncompleteTreasuryReport
+        );
+        let holding =
+            TreasuryHolding::try_deserialize(&mut &holding_info.try_borrow_data()?[..])?;
+
+        require_keys_eq!(
+            holding.treasury,
+            self.treasury.key(),
+            GovernanceError::IncompleteTreasuryReport
+        );
+        require!(
+            holding.index as usize == index,
+            GovernanceError::IncompleteTreasuryReport
+        );
+
+        Ok(holding)
+    }
+
+    // Balance of the holding's treasury token account
+    fn load_balance(&self, holding: &TreasuryHolding, token_info: &AccountInfo<'info>) -> Result<u64> {
+        require_keys_eq!(
+            token_info.key(),
+            holding.token_account,
+            GovernanceError::InvalidTreasuryAccount
+        );
+        require_keys_eq!(
+            *token_info.owner,
+            token::ID,
+            GovernanceError::InvalidTreasuryAccount
+        );
+        let token_account = TokenAccount::try_deserialize(&mut &token_info.try_borrow_data()?[..])?;
+
+        Ok(token_account.amount)
+    }
+
+    // Tokens of this mint the treasury holds for stakers
+    fn stake_liability(
+        &self,
+        holding: &TreasuryHolding,
+        supported_mint_info: &AccountInfo<'info>,
+    ) -> Result<u64> {
+        // SECURITY: Must be the mint's registry PDA, so a registered mint
+        // cannot be passed as "not staked" with some other empty account
+        let (expected_supported_mint, _) = Pubkey::find_program_address(
+            &[SUPPORTED_MINT, self.config.key().as_ref(), holding.mint.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            supported_mint_info.key(),
+            expected_supported_mint,
+            GovernanceError::IncompleteTreasuryReport
+        );
+
+        if holding.mint == self.config.token_mint {
+            return self
+                .treasury
+                .total_staked
+                .checked_add(self.treasury.total_unbonding)
+                .ok_or_else(|| error!(GovernanceError::MathOverflow));
+        }
+
+        // Not registered for staking
+        if *supported_mint_info.owner != crate::ID {
+            return Ok(0);
+        }
+
+        let supported_mint =
+            SupportedMint::try_deserialize(&mut &supported_mint_info.try_borrow_data()?[..])?;
+        Ok(supported_mint.total_staked)
+    }
+}
@@ -1,21 +1,18 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{self, Mint, Token, TokenAccount, Transfer},
-};
 
 use crate::{constants::*, errors::*, state::*};
 
 // Unstake Tokens Instruction
 //
-// Allows users to withdraw their staked tokens from the treasury
-// Uses PDA authority to sign the transfer from treasury to user
+// Removes tokens from the user's active stake and queues them for withdrawal
+// Tokens stay in the treasury until claim_unbonded is called after the
+// configured unbonding period
 //
 // SECURITY FEATURES:
-// - Treasury PDA authority signs withdrawals (no private keys)
-// - Sufficient balance checks (user profile and treasury)
-// - Token mint validation
-// - Checked arithmetic prevents underflow
+// - Unbonding ticket delays withdrawal (no stake-vote-unstake in one block)
+// - Sufficient stake balance check
+// - Unlock time restarts when more tokens are queued
+// - Checked arithmetic prevents underflow/overflow
 // - System pause check
 // - Staker count tracking
 
@@ -32,7 +29,7 @@ pub struct Unstake<'info> {
 
     // Config PDA
     // Seeds: ["config", admin]
-    // SECURITY: Validates token mint
+    // SECURITY: Source of the unbonding period
     #[account(
         seeds = [CONFIG, admin.key().as_ref()],
         bump,
@@ -41,7 +38,7 @@ pub struct Unstake<'info> {
 
     // Treasury state PDA
     // Seeds: ["treasury", admin]
-    // SECURITY: Tracks total staked and validates treasury balance
+    // SECURITY: Tracks total staked and total unbonding
     #[account(
         mut,
         seeds = [TREASURY, admin.key().as_ref()],
@@ -49,17 +46,6 @@ pub struct Unstake<'info> {
     )]
     pub treasury: Account<'info, Treasury>,
 
-    // Treasury authority PDA
-    // Seeds: ["treasury_auth", config, admin]
-    // SECURITY: PDA signer for treasury withdrawals
-    // Only the program can sign, no private key exists
-    #[account(
-        seeds = [TREASURYAUTH, config.key().as_ref(), admin.key().as_ref()],
-        bump = treasury.vault_bump,
-    )]
-    /// CHECK: PDA authority for signing treasury transfers
-    pub treasury_authority: UncheckedAccount<'info>,
-
     // User profile PDA
     // Seeds: ["user_profile", user]
     // SECURITY: Validates ownership and tracks stake amount
@@ -71,37 +57,24 @@ pub struct Unstake<'info> {
     )]
     pub user_profile: Account<'info, UserProfile>,
 
-    // Token mint account
-    // SECURITY: Must match config.token_mint
-    #[account(
-        address = config.token_mint @ GovernanceError::InvalidTokenMint
-    )]
-    pub token_mint_account: Account<'info, Mint>,
-
-    // User's token account (destination)
-    // SECURITY: Validated as user's ATA for correct mint
-    #[account(
-        mut,
-        associated_token::mint = token_mint_account,
-        associated_token::authority = user
-    )]
-    pub user_token_account: Account<'info, TokenAccount>,
-
-    // Treasury token account (source)
-    // SECURITY: Validated against treasury state
+    // Unbonding ticket PDA
+    // Seeds: ["unbonding", user]
+    // SECURITY: Holds the queued amount and the time it unlocks
+    // One ticket per user; further unstakes add to it and restart the timer
     #[account(
-        mut,
-        address = treasury.treasury_token_account @ GovernanceError::InvalidTreasuryAccount
+        init_if_needed,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR + UnbondingTicket::INIT_SPACE,
+        seeds = [UNBONDING_TICKET, user.key().as_ref()],
+        bump
     )]
-    pub treasury_token_account: Account<'info, TokenAccount>,
+    pub unbonding_ticket: Account<'info, UnbondingTicket>,
 
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> Unstake<'info> {
-    pub fn unstake_tokens(&mut self, amount: u64) -> Result<()> {
+    pub fn unstake_tokens(&mut self, amount: u64, bumps: UnstakeBumps) -> Result<()> {
         // SECURITY CHECKS
 
         // 1. Amount Validation
@@ -114,6 +87,13 @@ impl<'info> Unstake<'info> {
 
         let user_profile = &mut self.user_profile;
 
+        // SECURITY: Delegated stake must stay locked
+        // Otherwise the delegate keeps voting power for withdrawn tokens
+        require!(
+            user_profile.delegated_to == Pubkey::default(),
+            GovernanceError::StakeDelegated
+        );
+
         // 3. User Stake Balance Check
         // SECURITY: Ensures user has enough staked tokens
         require!(
@@ -121,65 +101,61 @@ impl<'info> Unstake<'info> {
             GovernanceError::InsufficientStake
         );
 
-        // 4. Treasury Balance Check
-        // SECURITY: Ensures treasury has sufficient tokens
-        // Prevents withdrawal if treasury is drained
-        require!(
-            self.treasury_token_account.amount >= amount,
-            GovernanceError::InsufficientTreasuryBalance
-        );
-
-        let config = self.config.key();
-        let admin = self.admin.key();
-
-        // 5. Calculate New Stake Amount
+        // 4. Calculate New Stake Amount
         // SECURITY: Checked subtraction prevents underflow
         let new_stake_amount = user_profile
             .stake_amount
             .checked_sub(amount)
             .ok_or(GovernanceError::MathOverflow)?;
 
-        // 6. Transfer Tokens from Treasury to User
-        // SECURITY: Uses PDA authority to sign the transfer
-        // Treasury authority PDA has no private key, only program can sign
-        let treasury_auth_seeds = &[
-            TREASURYAUTH,
-            config.as_ref(),
-            admin.as_ref(),
-            &[self.treasury.vault_bump],
-        ];
-        let signer_seeds = &[&treasury_auth_seeds[..]];
-
-        let transfer_ctx = CpiContext::new_with_signer(
-            self.token_program.to_account_info(),
-            Transfer {
-                from: self.treasury_token_account.to_account_info(),
-                to: self.user_token_account.to_account_info(),
-                authority: self.treasury_authority.to_account_info(),
-            },
-            signer_seeds,
-        );
-        token::transfer(transfer_ctx, amount)?;
+        // 5. Update User Profile
+        // SECURITY: Settle rewards on the old stake before it changes
+        user_profile
+            .accrue_rewards(self.config.reward_rate_per_sec, Clock::get()?.unix_timestamp)
+            .ok_or(GovernanceError::MathOverflow)?;
 
-        // 7. Update User Profile
         // Track if user had stake before (for staker count)
         let was_staker = user_profile.stake_amount > 0;
         user_profile.stake_amount = new_stake_amount;
 
-        // 8. Update Role Level
+        // 6. Update Role Level
         // Role automatically updates based on reputation
         // Unstaking does not directly affect role
         user_profile.role_level = MemberRanks::from_reputation(user_profile.reputation_points);
 
-        // 9. Update Treasury Totals
+        // 7. Queue Tokens in Unbonding Ticket
+        // SECURITY: Unlock time restarts for the whole queued amount
+        // Prevents topping up an almost-expired ticket to skip the delay
+        let current_time = Clock::get()?.unix_timestamp;
+        let unlock_at = current_time
+            .checked_add(self.config.unbonding_period)
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        let ticket = &mut self.unbonding_ticket;
+        if ticket.owner == Pubkey::default() {
+            ticket.owner = self.user.key();
+            ticket.bump = bumps.unbonding_ticket;
+        }
+        ticket.amount = ticket
+            .amount
+            .checked_add(amount)
+            .ok_or(GovernanceError::MathOverflow)?;
+        ticket.unlock_at = unlock_at;
+
+        // 8. Update Treasury Totals
         // SECURITY: Checked subtraction prevents underflow
+        // Queued tokens no longer count as stake
         let treasury = &mut self.treasury;
         treasury.total_staked = treasury
             .total_staked
             .checked_sub(amount)
             .ok_or(GovernanceError::MathOverflow)?;
+        treasury.total_unbonding = treasury
+            .total_unbonding
+            .checked_add(amount)
+            .ok_or(GovernanceError::MathOverflow)?;
 
-        // 10. Decrement Stakers Count
+        // 9. Decrement Stakers Count
         // Only decrement if user unstaked everything
         if was_staker && new_stake_amount == 0 {
             treasury.stakers_count = treasury
@@ -190,4 +166,4 @@ impl<'info> Unstake<'info> {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Update Profile Metadata Instruction
+//
+// Sets or clears the metadata URI (avatar, bio, links) on a profile.
+// The profile account is resized to fit, with the owner topping up the rent.
+//
+// SECURITY FEATURES:
+// - Profile owner only
+// - URI never exceeds MAX_METADATA_URI_LENGTH, so the profile stays small
+//   enough for every instruction that loads it
+// - Only https:// and ipfs:// links, printable ASCII only
+
+#[derive(Accounts)]
+pub struct UpdateProfileMetadata<'info> {
+    // Profile owner
+    // Pays the rent for the extra bytes
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // User profile PDA
+    // Seeds: ["user_profile", user]
+    // SECURITY: Validates ownership
+    #[account(
+        mut,
+        seeds = [USERPROFILE, user.key().as_ref()],
+        bump,
+        constraint = user_profile.owner == user.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateProfileMetadata<'info> {
+    pub fn update_profile_metadata(&mut self, metadata_uri: Option<String>) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. URI Validation
+        // None clears the link and needs no checks
+        if let Some(uri) = &metadata_uri {
+            UserProfile::validate_metadata_uri(uri)?;
+        }
+
+        // 2. Rent Top-Up
+        // Clearing or shortening the URI leaves its surplus in the account;
+        // it goes back to the owner with the rest when the profile closes
+        let info = self.user_profile.to_account_info();
+        let new_space = UserProfile::space(metadata_uri.as_deref());
+        let rent_due = Rent::get()?
+            .minimum_balance(new_space)
+            .saturating_sub(info.lamports());
+
+        if rent_due > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.user.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+
+        // 3. Resize and store the URI
+        // Anchor serializes the profile into the resized account on exit
+        info.resize(new_space)?;
+        self.user_profile.metadata_uri = metadata_uri;
+
+        Ok(())
+    }
+}
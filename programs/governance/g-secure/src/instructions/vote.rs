@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
 
-use crate::{constants::*, errors::*, state::*};
+use crate::{constants::*, errors::*, require_rank, state::*};
 
 // Vote Instruction
 //
@@ -15,6 +16,7 @@ use crate::{constants::*, errors::*, state::*};
 // - Vote changing allowed (users can reverse their vote)
 // - Reputation floor prevents grief attacks
 // - Checked arithmetic prevents overflow/underflow
+// - Vote history ring buffer grown by realloc with a hard size cap
 
 #[derive(Accounts)]
 #[instruction(target_username: String)]
@@ -95,6 +97,19 @@ pub struct Vote<'info> {
     )]
     pub vote_record: Account<'info, VoteRecord>,
 
+    // Vote history
+    // Seeds: ["vote_history", voter]
+    // SECURITY: Audit trail of the voter's most recent votes
+    // Created at initial capacity, grown by realloc in cast_vote
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = VoteHistory::space(VOTE_HISTORY_INITIAL_CAPACITY),
+        seeds = [VOTE_HISTORY, voter.key().as_ref()],
+        bump
+    )]
+    pub vote_history: Account<'info, VoteHistory>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -115,10 +130,7 @@ impl<'info> Vote<'info> {
         // SECURITY: Downvote Restriction
         // Only Bronze rank and above can downvote
         // Prevents new users from immediate negative voting
-        require!(
-            self.voter_profile.role_level.can_downvote(),
-            GovernanceError::CannotDownvote
-        );
+        require_rank!(self.voter_profile, MIN_DOWNVOTE_RANK, GovernanceError::CannotDownvote);
 
         self.cast_vote(target_username, VoteType::Downvote, bumps)
     }
@@ -145,8 +157,15 @@ impl<'info> Vote<'info> {
         // 3. Minimum Stake Requirement
         // SECURITY: Prevents sybil attacks by requiring economic commitment
         // Users must stake tokens before gaining voting rights
+        // Effective power includes stake delegated to the voter and
+        // excludes stake the voter has delegated away
+        // Fresh stake only counts as it matures
+        let voting_power = self
+            .voter_profile
+            .effective_power(Clock::get()?.unix_timestamp, self.config.stake_ramp_seconds)
+            .ok_or(GovernanceError::MathOverflow)?;
         require!(
-            self.voter_profile.stake_amount >= self.config.minimum_stake,
+            voting_power >= self.config.minimum_stake,
             GovernanceError::InsufficientStake
         );
 
@@ -154,7 +173,7 @@ impl<'info> Vote<'info> {
         // SECURITY: Rate limiting to prevent spam voting
         // Different roles have different cooldown periods (0-24 hours)
         let current_time = Clock::get()?.unix_timestamp;
-        let cooldown_hours = self.voter_profile.role_level.cooldown_hours();
+        let cooldown_hours = self.voter_profile.rank().cooldown_hours();
 
         if cooldown_hours > 0 {
             let cooldown_seconds = cooldown_hours * 3600;
@@ -165,10 +184,13 @@ impl<'info> Vote<'info> {
         }
 
         // 5. Handle Vote Changes
-        // SECURITY: If user previously voted, reverse the old vote first
+        // SECURITY: If user previously voted this season, reverse the old vote first
         // This prevents double-counting reputation changes
+        // A record from an earlier season is closable, so it is treated like a closed one
+        let current_season = self.config.current_season;
         let vote_record = &self.vote_record;
-        let is_vote_change = vote_record.voter != Pubkey::default();
+        let is_vote_change =
+            vote_record.voter != Pubkey::default() && vote_record.season == current_season;
 
         if is_vote_change {
             // Reverse previous vote's reputation impact
@@ -255,6 +277,7 @@ impl<'info> Vote<'info> {
         // 11. Update Cooldown Tracker
         // Reset cooldown timer after successful vote
         self.vote_cooldown.last_vote_timestamp = current_time;
+        self.vote_cooldown.season = current_season;
         if self.vote_cooldown.voter == Pubkey::default() {
             self.vote_cooldown.voter = self.voter.key();
             self.vote_cooldown.bump = bumps.vote_cooldown;
@@ -269,9 +292,60 @@ impl<'info> Vote<'info> {
             vote_type,
             vote_weight,
             timestamp: current_time,
+            season: current_season,
             bump: bumps.vote_record,
         });
 
+        // 13. Record Vote History
+        // SECURITY: Grow by realloc while below the cap, then wrap and
+        // overwrite the oldest entry so the account never outgrows its space
+        if self.vote_history.owner == Pubkey::default() {
+            self.vote_history.owner = self.voter.key();
+            self.vote_history.bump = bumps.vote_history;
+            self.vote_history.capacity = VOTE_HISTORY_INITIAL_CAPACITY as u16;
+        }
+        if self.vote_history.is_full()
+            && (self.vote_history.capacity as usize) < VOTE_HISTORY_MAX_ENTRIES
+        {
+            self.grow_vote_history()?;
+        }
+        self.vote_history
+            .record(VoteHistoryEntry {
+                target: self.target_user_profile.owner,
+                vote_type,
+                timestamp: current_time,
+                weight: vote_weight,
+            })
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    fn grow_vote_history(&mut self) -> Result<()> {
+        let new_capacity = (self.vote_history.capacity as usize + VOTE_HISTORY_GROWTH_STEP)
+            .min(VOTE_HISTORY_MAX_ENTRIES);
+        let new_space = VoteHistory::space(new_capacity);
+        let history_info = self.vote_history.to_account_info();
+
+        // SECURITY: Voter tops up rent before the account is resized
+        // Keeps the history account rent-exempt at its new size
+        let rent_due = Rent::get()?
+            .minimum_balance(new_space)
+            .saturating_sub(history_info.lamports());
+        if rent_due > 0 {
+            let transfer_ctx = CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.voter.to_account_info(),
+                    to: history_info.clone(),
+                },
+            );
+            system_program::transfer(transfer_ctx, rent_due)?;
+        }
+
+        history_info.resize(new_space)?;
+        self.vote_history.grow(new_capacity as u16);
+
         Ok(())
     }
 }
\ No newline at end of file
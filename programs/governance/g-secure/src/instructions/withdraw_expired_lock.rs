@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Withdraw Expired Lock Instruction
+//
+// Returns a lock's tokens once its unlock time has passed
+// Uses the escrow PDA to sign the transfer, then closes the escrow and its
+// token account
+//
+// SECURITY FEATURES:
+// - Unlock time enforced from the escrow (unix time)
+// - Escrow owner must be the signer
+// - Escrow PDA signs the withdrawal (no private keys)
+// - Token mint validation
+// - Escrow and token account closed (rent refunded, no replay)
+// - System pause check
+
+#[derive(Accounts)]
+pub struct WithdrawExpiredLock<'info> {
+    // Escrow owner
+    // Receives the tokens and both accounts' rent
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Admin pubkey for PDA derivation
+    /// CHECK: Used for config PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    // Config PDA
+    // Seeds: ["config", admin]
+    // SECURITY: Validates token mint and pause state
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Vote escrow PDA
+    // Seeds: ["vote_escrow", config, user]
+    // SECURITY: Owner check and close-on-withdraw prevent replaying a lock
+    #[account(
+        mut,
+        close = user,
+        seeds = [VOTE_ESCROW, config.key().as_ref(), user.key().as_ref()],
+        bump = vote_escrow.bump,
+        constraint = vote_escrow.owner == user.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub vote_escrow: Account<'info, VoteEscrow>,
+
+    // Token mint account
+    // SECURITY: Must match config.token_mint
+    #[account(
+        address = config.token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub token_mint_account: Account<'info, Mint>,
+
+    // User's token account (destination)
+    // SECURITY: Validated as user's ATA for correct mint
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    // Escrow token account (source)
+    // SECURITY: Validated as the escrow PDA's ATA
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = vote_escrow
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawExpiredLock<'info> {
+    pub fn withdraw_expired_lock(&mut self) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. System Pause Check
+        // Prevents withdrawals during maintenance
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        // 2. Unlock Time Check
+        // SECURITY: Tokens stay locked until the unlock time they voted with
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            self.vote_escrow.is_expired(current_time),
+            GovernanceError::LockNotExpired
+        );
+
+        // 3. Transfer Tokens from Escrow to User
+        // SECURITY: Uses the escrow PDA to sign the transfer
+        // Returns the full balance, including anything sent to it after the lock
+        let config = self.config.key();
+        let user = self.user.key();
+        let escrow_seeds = &[
+            VOTE_ESCROW,
+            config.as_ref(),
+            user.as_ref(),
+            &[self.vote_escrow.bump],
+        ];
+        let signer_seeds = &[&escrow_seeds[..]];
+
+        let amount = self.escrow_token_account.amount;
+        if amount > 0 {
+            let transfer_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.escrow_token_account.to_account_info(),
+                    to: self.user_token_account.to_account_info(),
+                    authority: self.vote_escrow.to_account_info(),
+                },
+                signer_seeds,
+            );
+            token::transfer(transfer_ctx, amount)?;
+        }
+
+        // 4. Close Escrow Token Account
+        // Rent goes back to the user who paid for it
+        let close_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.escrow_token_account.to_account_info(),
+                destination: self.user.to_account_info(),
+                authority: self.vote_escrow.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::close_account(close_ctx)?;
+
+        // 5. Clear Lock
+        // Account is closed by the 'close' constraint after this handler
+        self.vote_escrow.amount = 0;
+
+        Ok(())
+    }
+}
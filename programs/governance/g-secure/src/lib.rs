@@ -1,5 +1,6 @@
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
@@ -8,6 +9,7 @@ use anchor_lang::prelude::*;
 pub use constants::*;
 pub use instructions::*;
 pub use errors::*;
+pub use events::*;
 pub use state::*;
 
 declare_id!("5TwUNSWhJ4AwYNnaP5APZyxBXW4usurdbfkCQCCRfAMP");
@@ -25,12 +27,28 @@ pub mod secure {
         minimum_stake: u64,
         token_mint: Pubkey,
         vote_power: u8,
+        quorum: u64,
+        approval_threshold_bps: u16,
+        unbonding_period: i64,
+        decay_rate_bps: u16,
+        decay_epoch_seconds: i64,
+        reward_rate_per_sec: u64,
+        stake_ramp_seconds: i64,
+        proposal_deposit: u64,
     ) -> Result<()> {
         ctx.accounts.initialize(
             minimum_stake,
             admin,
             token_mint,
             vote_power,
+            quorum,
+            approval_threshold_bps,
+            unbonding_period,
+            decay_rate_bps,
+            decay_epoch_seconds,
+            reward_rate_per_sec,
+            stake_ramp_seconds,
+            proposal_deposit,
             ctx.bumps
         )
     }
@@ -51,22 +69,40 @@ pub mod secure {
         ctx.accounts.create_profile(username, bumps)
     }
 
-    /// Stake tokens to gain voting rights
+    /// Register an additional stakeable mint with a voting weight (admin only)
+    pub fn add_supported_mint(
+        ctx: Context<AddSupportedMint>,
+        weight_bps: u16,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.add_supported_mint(weight_bps, bumps)
+    }
+
+    /// Stake the base mint or a supported mint to gain voting rights
     pub fn stake_tokens(
         ctx: Context<Stake>,
         amount: u64,
     ) -> Result<()> {
         // Validate amount
         require!(amount > 0, GovernanceError::InvalidStakeAmount);
-		ctx.accounts.stake_tokens(amount)
+        let bumps = ctx.bumps;
+		ctx.accounts.stake_tokens(amount, bumps)
     }
 
-    /// Unstake tokens and reduce voting power
+    /// Unstake tokens into an unbonding ticket and reduce voting power
     pub fn unstake_tokens(
         ctx: Context<Unstake>,
         amount: u64,
     ) -> Result<()> {
-        ctx.accounts.unstake_tokens(amount)
+        let bumps = ctx.bumps;
+        ctx.accounts.unstake_tokens(amount, bumps)
+    }
+
+    /// Withdraw unstaked tokens once the unbonding period has passed
+    pub fn claim_unbonded(
+        ctx: Context<ClaimUnbonded>,
+    ) -> Result<()> {
+        ctx.accounts.claim_unbonded()
     }
 
     /// Cast an upvote for another user
@@ -92,7 +128,235 @@ pub mod secure {
         ctx: Context<ResetUserReputation>,
         user: Pubkey
     ) -> Result<()> {
-        ctx.accounts.reset_user_reputation()
+        let bumps = ctx.bumps;
+        ctx.accounts.reset_user_reputation(bumps)
+    }
+
+    /// Create a stake-weighted governance proposal, escrowing the proposer's deposit
+    pub fn create_governance_proposal(
+        ctx: Context<CreateGovernanceProposal>,
+        title: String,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.create_proposal(title, bumps)
+    }
+
+    /// Cast a stake-weighted ballot on a proposal
+    pub fn cast_ballot(
+        ctx: Context<CastBallot>,
+        support: bool,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.cast_ballot(support, bumps)
+    }
+
+    /// Settle a batch of off-chain votes, each signed by its voter (Ed25519)
+    pub fn submit_vote_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, SubmitVoteBatch<'info>>,
+        entries: Vec<VoteBatchEntry>,
+    ) -> Result<()> {
+        ctx.accounts.submit_vote_batch(entries, ctx.remaining_accounts)
+    }
+
+    /// Finalize a proposal after its voting period ends and settle its deposit
+    pub fn finalize_proposal(
+        ctx: Context<FinalizeProposal>,
+    ) -> Result<()> {
+        ctx.accounts.finalize_proposal()
+    }
+
+    /// Attach a multisig proposal to a governance proposal before voting starts
+    pub fn attach_multisig_action(
+        ctx: Context<AttachMultisigAction>,
+        multisig: Pubkey,
+        action: MultisigProposalType,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.attach_multisig_action(multisig, action, bumps)
+    }
+
+    /// Open a passed proposal's multisig action on the multisig via CPI
+    pub fn execute_multisig_action(
+        ctx: Context<ExecuteMultisigAction>,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.execute_multisig_action(bumps)
+    }
+
+    /// Delegate staked voting power to another profile
+    pub fn delegate_votes(
+        ctx: Context<DelegateVotes>,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.delegate_votes(bumps)
+    }
+
+    /// Revoke a delegation and reclaim voting power
+    pub fn revoke_delegation(
+        ctx: Context<RevokeDelegation>,
+    ) -> Result<()> {
+        ctx.accounts.revoke_delegation()
+    }
+
+    /// Apply reputation decay for elapsed epochs (permissionless)
+    pub fn decay_reputation(
+        ctx: Context<DecayReputation>,
+    ) -> Result<()> {
+        ctx.accounts.decay_reputation()
+    }
+
+    /// Claim staking rewards accrued on staked tokens
+    pub fn claim_rewards(
+        ctx: Context<ClaimRewards>,
+    ) -> Result<()> {
+        ctx.accounts.claim_rewards()
+    }
+
+    /// Change username, releasing the old one
+    pub fn change_username(
+        ctx: Context<ChangeUsername>,
+        new_username: String,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.change_username(new_username, bumps)
+    }
+
+    /// Set or clear the profile's metadata URI (https:// or ipfs:// only)
+    pub fn update_profile_metadata(
+        ctx: Context<UpdateProfileMetadata>,
+        metadata_uri: Option<String>,
+    ) -> Result<()> {
+        ctx.accounts.update_profile_metadata(metadata_uri)
+    }
+
+    /// Close a profile and release its username (rent refunded)
+    pub fn close_profile(
+        ctx: Context<CloseProfile>,
+    ) -> Result<()> {
+        ctx.accounts.close_profile()
+    }
+
+    /// Propose a new admin (step one of a two-step transfer)
+    pub fn propose_admin(
+        ctx: Context<ProposeAdmin>,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.propose_admin(new_admin)
+    }
+
+    /// Accept a pending admin transfer (step two)
+    pub fn accept_admin(
+        ctx: Context<AcceptAdmin>,
+    ) -> Result<()> {
+        ctx.accounts.accept_admin()
+    }
+
+    /// Slash a user's stake (admin only)
+    pub fn slash_stake(
+        ctx: Context<SlashStake>,
+        slash_bps: u16,
+        burn: bool,
+    ) -> Result<()> {
+        ctx.accounts.slash_stake(slash_bps, burn)
+    }
+
+    /// Close the current season and set its reward pool (admin only)
+    pub fn close_season(
+        ctx: Context<CloseSeason>,
+        reward_pool: u64,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.close_season(reward_pool, bumps)
+    }
+
+    /// Claim a share of a closed season's reward pool
+    pub fn claim_season_reward(
+        ctx: Context<ClaimSeasonReward>,
+        season_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.claim_season_reward(season_id)
+    }
+
+    /// Close a vote record from an earlier season (rent refunded)
+    pub fn close_vote_record(
+        ctx: Context<CloseVoteRecord>,
+        target_username: String,
+    ) -> Result<()> {
+        ctx.accounts.close_vote_record(target_username)
+    }
+
+    /// Close the voter's cooldown tracker after its season (rent refunded)
+    pub fn close_cooldown(
+        ctx: Context<CloseCooldown>,
+    ) -> Result<()> {
+        ctx.accounts.close_cooldown()
+    }
+
+    /// Set up the emergency council (admin only, once)
+    pub fn init_council(
+        ctx: Context<InitCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+        veto_window: i64,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.init_council(members, threshold, veto_window, bumps)
+    }
+
+    /// Pause or resume the DAO; co-signing council members in remaining accounts
+    pub fn council_set_pause(
+        ctx: Context<CouncilSetPause>,
+        paused: bool,
+    ) -> Result<()> {
+        ctx.accounts.council_set_pause(paused, ctx.remaining_accounts)
+    }
+
+    /// Veto a proposal within the veto window; co-signing council members in remaining accounts
+    pub fn council_veto(
+        ctx: Context<CouncilVeto>,
+    ) -> Result<()> {
+        ctx.accounts.council_veto(ctx.remaining_accounts)
+    }
+
+    /// Lock base-mint tokens for vote-escrowed power that decays to the unlock time
+    pub fn lock_tokens(
+        ctx: Context<LockTokens>,
+        amount: u64,
+        duration: i64,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.lock_tokens(amount, duration, bumps)
+    }
+
+    /// Push a live lock's unlock time out to now + duration
+    pub fn extend_lock(
+        ctx: Context<ExtendLock>,
+        duration: i64,
+    ) -> Result<()> {
+        ctx.accounts.extend_lock(duration)
+    }
+
+    /// Withdraw locked tokens once the unlock time has passed
+    pub fn withdraw_expired_lock(
+        ctx: Context<WithdrawExpiredLock>,
+    ) -> Result<()> {
+        ctx.accounts.withdraw_expired_lock()
+    }
+
+    /// Register a token the treasury holds and the oracle feed pricing it (admin only)
+    pub fn register_treasury_holding(
+        ctx: Context<RegisterTreasuryHolding>,
+        oracle_program: Pubkey,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.register_treasury_holding(oracle_program, bumps)
+    }
+
+    /// Value every registered holding from its oracle feed (read-only view)
+    pub fn treasury_value_report<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReportTreasuryValue<'info>>,
+    ) -> Result<TreasuryValueReport> {
+        ctx.accounts.treasury_value_report(ctx.remaining_accounts)
     }
 
 }
\ No newline at end of file
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+// Emergency Council
+//
+// SECURITY: A fixed set of emergency keys set up once by the admin
+// Council actions (pause, veto) need `threshold` distinct member signatures
+// on the same transaction; veto_window extends past a proposal's voting end
+// and holds back its execution until the council has had time to react
+#[account]
+#[derive(InitSpace)]
+pub struct Council {
+    pub config: Pubkey,
+    #[max_len(MAX_COUNCIL_MEMBERS)]
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub veto_window: i64,
+    pub bump: u8,
+}
+
+impl Council {
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+
+    // Distinct members among the caller and the signing remaining accounts
+    // SECURITY: Non-signers, non-members and repeated keys are not counted
+    pub fn count_signers(&self, caller: &Pubkey, co_signers: &[AccountInfo]) -> Vec<Pubkey> {
+        let mut signers: Vec<Pubkey> = Vec::new();
+        if self.is_member(caller) {
+            signers.push(*caller);
+        }
+        for account in co_signers {
+            if account.is_signer && self.is_member(account.key) && !signers.contains(account.key) {
+                signers.push(*account.key);
+            }
+        }
+        signers
+    }
+
+    // Last timestamp at which a proposal ending at voting_ends_at can be vetoed
+    pub fn veto_deadline(&self, voting_ends_at: i64) -> Option<i64> {
+        voting_ends_at.checked_add(self.veto_window)
+    }
+}
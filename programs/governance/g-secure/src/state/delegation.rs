@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+// Delegation
+//
+// Records a delegator lending their voting power to another profile
+// SECURITY: One delegation per delegator (PDA seeded by delegator)
+// The stored amount is exactly what is removed from the delegate on revoke
+#[account]
+#[derive(InitSpace)]
+pub struct Delegation {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub amount: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
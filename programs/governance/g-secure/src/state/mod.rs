@@ -1,8 +1,34 @@
 pub mod user_profile;
+pub mod rank;
 pub mod treasury;
 pub mod vote;
+pub mod proposal;
+pub mod unbonding_ticket;
+pub mod delegation;
+pub mod vote_history;
+pub mod season_snapshot;
+pub mod supported_mint;
+pub mod multisig_action;
+pub mod council;
+pub mod vote_escrow;
+pub mod price_feed;
+pub mod treasury_holding;
+pub mod proposal_deposit;
 
 
 pub use user_profile::*;
+pub use rank::*;
 pub use treasury::*;
-pub use vote::*;
\ No newline at end of file
+pub use vote::*;
+pub use proposal::*;
+pub use unbonding_ticket::*;
+pub use delegation::*;
+pub use vote_history::*;
+pub use season_snapshot::*;
+pub use supported_mint::*;
+pub use multisig_action::*;
+pub use council::*;
+pub use vote_escrow::*;
+pub use price_feed::*;
+pub use treasury_holding::*;
+pub use proposal_deposit::*;
\ No newline at end of file
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+// Multisig Action
+//
+// SECURITY: Binds one governance proposal to one multisig proposal
+// Attached before any ballot is cast, so voters see exactly what they approve
+// Relayed at most once - executed flips before the CPI is made
+#[account]
+#[derive(InitSpace)]
+pub struct MultisigAction {
+    pub proposal: Pubkey,
+    pub multisig: Pubkey,
+    pub action: MultisigProposalType,
+    pub executed: bool,
+    pub bump: u8,
+}
+
+// Multisig Proposal Type
+//
+// Mirror of the multisig program's ProposalType, passed through as the
+// create_proposal argument. Declared here instead of importing the multisig
+// crate; variant order must match so the Borsh tags line up.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum MultisigProposalType {
+    AddMember { new_member: Pubkey, role: MultisigMemberRole },
+    RemoveMember { member_to_remove: Pubkey },
+    ChangeThreshold { new_threshold: u8 },
+    ChangeTimelock { new_timelock: u64 },
+    ApproveParentProposal { parent_multisig: Pubkey, parent_proposal_id: u64 },
+    SetVaultPaysRent { enabled: bool },
+}
+
+// Multisig Member Role
+//
+// Mirror of the multisig program's MemberRole (same variant order)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum MultisigMemberRole {
+    Admin,
+    Proposer,
+    Executor,
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*};
+
+// Oracle Price Feed
+//
+// Mirror of the PriceFeed account written by the oracle program
+// Declared here instead of importing the oracle crate so a holding can be priced
+// by any oracle program with the same account layout
+//
+// value      = price * 10^exponent (quote units per whole token)
+// confidence = +/- confidence * 10^exponent around the price
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PriceFeed {
+    pub authority: Pubkey,
+    pub seed: u64,
+    pub publishers: Vec<Pubkey>,
+    pub exponent: i32,
+    pub max_staleness_seconds: i64,
+    pub max_confidence_bps: u16,
+    pub price: u64,
+    pub confidence: u64,
+    pub publish_ts: i64,
+    pub last_publisher: Pubkey,
+    pub bump: u8,
+}
+
+impl PriceFeed {
+    // Deserialize a feed account owned by the given oracle program
+    // SECURITY: Owner and discriminator checked, so a look-alike account
+    // created by another program cannot supply its own price
+    pub fn load(feed: &AccountInfo, oracle_program: &Pubkey) -> Result<Self> {
+        require_keys_eq!(*feed.owner, *oracle_program, GovernanceError::InvalidPriceFeed);
+
+        let data = feed.try_borrow_data()?;
+        require!(
+            data.len() >= ANCHOR_DISCRIMINATOR,
+            GovernanceError::InvalidPriceFeed
+        );
+
+        let (discriminator, mut body) = data.split_at(ANCHOR_DISCRIMINATOR);
+        require!(
+            discriminator == PRICE_FEED_DISCRIMINATOR,
+            GovernanceError::InvalidPriceFeed
+        );
+
+        Self::deserialize(&mut body).map_err(|_| error!(GovernanceError::InvalidPriceFeed))
+    }
+
+    // Reject prices that were never published, are too old, or too uncertain
+    // SECURITY: Uses the feed's own limits, the same checks as the oracle's get_price
+    pub fn assert_usable(&self, now: i64) -> Result<()> {
+        require!(
+            self.publish_ts > 0 && self.price > 0,
+            GovernanceError::OraclePriceUnavailable
+        );
+
+        let age = now
+            .checked_sub(self.publish_ts)
+            .ok_or(GovernanceError::MathOverflow)?;
+        require!(
+            age <= self.max_staleness_seconds,
+            GovernanceError::StaleOraclePrice
+        );
+
+        // confidence * MAX_BASIS_POINTS <= price * max_confidence_bps
+        let scaled_confidence = (self.confidence as u128)
+            .checked_mul(MAX_BASIS_POINTS as u128)
+            .ok_or(GovernanceError::MathOverflow)?;
+        let max_confidence = (self.price as u128)
+            .checked_mul(self.max_confidence_bps as u128)
+            .ok_or(GovernanceError::MathOverflow)?;
+        require!(
+            scaled_confidence <= max_confidence,
+            GovernanceError::OracleConfidenceTooWide
+        );
+
+        Ok(())
+    }
+
+    // Value of `amount` base units of a mint with `decimals`, in
+    // TREASURY_VALUE_DECIMALS fixed point of the quote unit
+    //
+    //   amount * price * 10^(exponent + TREASURY_VALUE_DECIMALS - decimals)
+    // SECURITY: u128 throughout; rounds down so a report never overstates value
+    pub fn value_of(&self, amount: u64, decimals: u8) -> Result<u128> {
+        let raw = (amount as u128)
+            .checked_mul(self.price as u128)
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        let shift = (self.exponent as i64) + (TREASURY_VALUE_DECIMALS as i64) - (decimals as i64);
+        let scale = 10u128
+            .checked_pow(shift.unsigned_abs() as u32)
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        if shift >= 0 {
+            raw.checked_mul(scale)
+                .ok_or_else(|| error!(GovernanceError::MathOverflow))
+        } else {
+            Ok(raw / scale)
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+// Governance Proposal
+//
+// SECURITY: Tracks a single DAO proposal and its stake-weighted tally
+// Ballots are only accepted while the proposal is Active and before voting_ends_at
+// The outcome is decided once, against the quorum and threshold stored in Config
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub config: Pubkey,
+    pub proposer: Pubkey,
+    pub proposal_id: u64,
+    #[max_len(64)]
+    pub title: String,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub created_at: i64,
+    pub voting_ends_at: i64,
+    pub status: ProposalStatus,
+    pub bump: u8,
+}
+
+// Ballot Record
+//
+// SECURITY: One ballot per voter per proposal
+// Created with 'init' so a second ballot from the same voter fails
+// Stores the stake weight counted so the tally can be audited
+#[account]
+#[derive(InitSpace)]
+pub struct Ballot {
+    pub voter: Pubkey,
+    pub proposal: Pubkey,
+    pub support: bool,
+    pub weight: u64,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+// Vote Batch Entry
+//
+// One off-chain vote settled by submit_vote_batch
+// The voter signs vote_message() with their wallet key; the signature is
+// checked by an Ed25519 instruction in the same transaction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VoteBatchEntry {
+    pub voter: Pubkey,
+    pub support: bool,
+    pub weight: u64,
+}
+
+impl VoteBatchEntry {
+    // Signed bytes: proposal | support | weight (little-endian)
+    // SECURITY: Including the proposal stops a signature being replayed on another one
+    pub fn vote_message(&self, proposal: &Pubkey) -> [u8; VOTE_MESSAGE_LEN] {
+        let mut message = [0u8; VOTE_MESSAGE_LEN];
+        message[..32].copy_from_slice(proposal.as_ref());
+        message[32] = self.support as u8;
+        message[33..].copy_from_slice(&self.weight.to_le_bytes());
+        message
+    }
+}
+
+// Proposal Status Enum
+//
+// Active until finalized, then Passed or Rejected
+// Vetoed by the emergency council, from Active or Passed, within the veto window
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Copy, PartialEq, Eq)]
+pub enum ProposalStatus {
+    Active,
+    Passed,
+    Rejected,
+    Vetoed,
+}
+
+impl Space for ProposalStatus {
+    const INIT_SPACE: usize = 1;
+}
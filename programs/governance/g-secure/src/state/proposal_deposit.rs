@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+// Proposal Deposit
+//
+// SECURITY: Escrows the base-mint tokens a proposer put up to open a proposal
+// The tokens sit in this PDA's ATA, so only finalize_proposal can move them:
+// refunded to the proposer if the proposal reached quorum, burned otherwise
+// Seeds: ["proposal_deposit", proposal]
+#[account]
+#[derive(InitSpace)]
+pub struct ProposalDeposit {
+    pub proposal: Pubkey,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
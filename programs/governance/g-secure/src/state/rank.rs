@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+// Member Ranks
+//
+// Ranks are computed from reputation, never assigned, and ordered from
+// Member up to Leader so a gate is a single comparison
+// Every rank gate goes through require_rank! so the thresholds live in one place
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MemberRanks {
+    /// Entry level - Can upvote only, 24h cooldown
+    /// Reputation: 0-50 points
+    /// Perks: Basic profile, stake tokens, upvote others
+    Member,
+    /// First upgrade - Unlocks downvoting ability
+    /// Reputation: 50-100 points
+    /// Perks: Can downvote, bronze badge, all Member perks
+    Bronze,
+    /// Active community member - Enhanced voting power
+    /// Reputation: 100-200 points
+    /// Perks:  18h cooldown, can nominate others
+    Contributor,
+    /// Trusted moderator - Significant influence
+    /// Reputation: 200-400 points
+    /// Perks: 12h cooldown, can vote twice daily, moderator privileges
+    Guardian,
+    /// Elite status - Maximum privileges and recognition
+    /// Reputation: 400+ points
+    /// Perks: no cooldown, unlimited voting, governance proposals
+    Leader,
+}
+
+// Short name used by rank gates: require_rank!(profile, Rank::Bronze)
+pub type Rank = MemberRanks;
+
+impl MemberRanks {
+    pub fn can_downvote(&self) -> bool {
+        *self >= MIN_DOWNVOTE_RANK
+    }
+
+    pub fn cooldown_hours(&self) -> u64 {
+        match self {
+            MemberRanks::Member | MemberRanks::Bronze => 24,  // Full day cooldown
+            MemberRanks::Contributor => 18,                    // 25% reduction
+            MemberRanks::Guardian => 12,                       // 50% reduction
+            MemberRanks::Leader => 0,                          // No restrictions
+        }
+    }
+
+    // Calculate role from reputation points
+    //
+    // SECURITY: Automatic role progression based on reputation
+    // Prevents manual role manipulation by deriving from reputation
+    pub fn from_reputation(points: i64) -> Self {
+        match points {
+            p if p <= REPUTATION_MEMBER_CAP => MemberRanks::Member,
+            p if p <= REPUTATION_BRONZE_CAP => MemberRanks::Bronze,
+            p if p <= REPUTATION_CONTRIBUTOR_CAP => MemberRanks::Contributor,
+            p if p <= REPUTATION_GUARDIAN_CAP => MemberRanks::Guardian,
+            _ => MemberRanks::Leader,
+        }
+    }
+
+    // Share of config.proposal_deposit escrowed to open a proposal, in basis points
+    // Established members put up less; a new profile pays the full deposit
+    pub fn proposal_deposit_bps(&self) -> u64 {
+        match self {
+            MemberRanks::Member => 10_000,     // Full deposit
+            MemberRanks::Bronze => 8_000,      // 20% reduction
+            MemberRanks::Contributor => 6_000, // 40% reduction
+            MemberRanks::Guardian => 4_000,    // 60% reduction
+            MemberRanks::Leader => 2_000,      // 80% reduction
+        }
+    }
+
+    pub fn vote_weight(&self) -> u8 {
+        match self {
+            MemberRanks::Member => 1,        // +1 or -1 reputation
+            MemberRanks::Bronze => 1,        // +1 or -1 reputation
+            MemberRanks::Contributor => 2,   // +2 or -2 reputation
+            MemberRanks::Guardian => 2,      // +2 or -2 reputation
+            MemberRanks::Leader => 3,        // +3 or -3 reputation
+        }
+    }
+}
+
+impl Space for MemberRanks {
+	const INIT_SPACE : usize = 1;
+}
+
+// Require a minimum rank
+//
+// require_rank!(profile, Rank::Bronze) fails with RankTooLow
+// require_rank!(profile, Rank::Bronze, GovernanceError::CannotDownvote) uses the given error
+// SECURITY: Compares the rank computed from the profile's current reputation
+#[macro_export]
+macro_rules! require_rank {
+    ($profile:expr, $rank:expr $(,)?) => {
+        $crate::require_rank!($profile, $rank, $crate::errors::GovernanceError::RankTooLow)
+    };
+    ($profile:expr, $rank:expr, $error:expr $(,)?) => {
+        ::anchor_lang::require!($profile.rank() >= $rank, $error)
+    };
+}
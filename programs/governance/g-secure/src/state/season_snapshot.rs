@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+// Season Snapshot
+//
+// Final standings of a season, recorded as users are reset
+// Keeps the top SEASON_TOP_N positive reputations; close_season adds the
+// total stake and the reward pool that claim_season_reward pays out
+// SECURITY: One snapshot per (config, season) PDA with a bounded entry list
+// Each entry carries its own claimed flag so rewards are paid once
+#[account]
+#[derive(InitSpace)]
+pub struct SeasonSnapshot {
+    pub config: Pubkey,
+    pub season_id: u64,
+    pub total_staked: u64,
+    pub reward_pool: u64,
+    pub finalized: bool,
+    pub bump: u8,
+    #[max_len(SEASON_TOP_N)]
+    pub entries: Vec<SeasonEntry>,
+}
+
+// Season Entry
+//
+// A user's final reputation for the season
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct SeasonEntry {
+    pub user: Pubkey,
+    pub reputation: i64,
+    pub claimed: bool,
+}
+
+impl SeasonSnapshot {
+    // Record a user's final reputation
+    // Only positive reputation ranks; a user reset twice keeps their best score
+    // Once full, a new score replaces the lowest entry if it is higher
+    pub fn record(&mut self, user: Pubkey, reputation: i64) {
+        if reputation <= 0 {
+            return;
+        }
+
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.user == user) {
+            entry.reputation = entry.reputation.max(reputation);
+            return;
+        }
+
+        let entry = SeasonEntry {
+            user,
+            reputation,
+            claimed: false,
+        };
+        if self.entries.len() < SEASON_TOP_N {
+            self.entries.push(entry);
+        } else if let Some(lowest) = self.entries.iter_mut().min_by_key(|e| e.reputation) {
+            if reputation > lowest.reputation {
+                *lowest = entry;
+            }
+        }
+    }
+
+    // Reward owed to the entry at `index`
+    // reward_pool * reputation / total reputation in the standings
+    // SECURITY: i128 intermediates and checked math
+    pub fn reward_for(&self, index: usize) -> Option<u64> {
+        let total = self
+            .entries
+            .iter()
+            .try_fold(0i128, |sum, e| sum.checked_add(e.reputation as i128))?;
+        if total <= 0 {
+            return None;
+        }
+        let share = (self.reward_pool as i128)
+            .checked_mul(self.entries.get(index)?.reputation as i128)?
+            .checked_div(total)?;
+        u64::try_from(share).ok()
+    }
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use soteria_common::math::mul_div_floor;
+
+use crate::constants::*;
+
+// Supported Mint
+//
+// SECURITY: Registry entry for a token that may be staked besides config.token_mint
+// Seeds tie the entry to one DAO config and one mint, so only the admin's
+// add_supported_mint can create it and a weight cannot be claimed for another mint
+// Staked tokens are held in the treasury authority's ATA for this mint
+#[account]
+#[derive(InitSpace)]
+pub struct SupportedMint {
+    pub config: Pubkey,
+    pub mint: Pubkey,
+
+    // Voting weight in basis points of the base mint (10_000 = 1x)
+    pub weight_bps: u16,
+
+    // Treasury ATA holding this mint's staked tokens
+    pub treasury_token_account: Pubkey,
+
+    // Tokens of this mint currently staked
+    pub total_staked: u64,
+
+    pub bump: u8,
+}
+
+impl SupportedMint {
+    // Voting power for `amount` tokens of this mint
+    //
+    // SECURITY: u128 intermediate; rounds down so splitting a stake
+    // can never earn more power than staking it at once
+    pub fn voting_power(&self, amount: u64) -> Option<u64> {
+        mul_div_floor(amount, self.weight_bps as u64, MAX_BASIS_POINTS)
+    }
+}
+
+// Mint Stake
+//
+// Per-user record of tokens staked in one supported mint
+// voting_power is the weighted amount credited to the profile, kept so the
+// exact power can be removed later even if rounding differs per deposit
+#[account]
+#[derive(InitSpace)]
+pub struct MintStake {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub voting_power: u64,
+    pub bump: u8,
+}
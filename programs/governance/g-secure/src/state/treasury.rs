@@ -24,6 +24,54 @@ pub struct Config {
     // Multiplied with role weight to calculate final vote impact
     pub vote_power: u8,
 
+    // Proposal quorum
+    // SECURITY: Minimum total stake that must vote for a proposal to pass
+    // Prevents a handful of stakers from deciding proposals nobody saw
+    pub quorum: u64,
+
+    // Proposal approval threshold in basis points
+    // Share of participating stake that must vote in favor (5000 = 50%)
+    pub approval_threshold_bps: u16,
+
+    // Number of proposals created
+    // Used as the seed for the next proposal PDA
+    pub proposal_count: u64,
+
+    // Unbonding period in seconds
+    // SECURITY: Delay between unstake and withdrawal
+    // Unstaked tokens cannot be moved and restaked during an active vote
+    pub unbonding_period: i64,
+
+    // Reputation decay rate in basis points per epoch
+    // Share of positive reputation removed each elapsed epoch (1000 = 10%)
+    pub decay_rate_bps: u16,
+
+    // Length of a reputation decay epoch in seconds
+    pub decay_epoch_seconds: i64,
+
+    // Staking reward rate
+    // Reward base units emitted per second for every REWARD_RATE_SCALE staked
+    pub reward_rate_per_sec: u64,
+
+    // Pending admin for a two-step transfer
+    // SECURITY: Only this key can accept the admin role
+    // Pubkey::default() when no transfer is pending
+    pub pending_admin: Pubkey,
+
+    // Current season number
+    // Seeds the season snapshot that resets are recorded into
+    pub current_season: u64,
+
+    // Stake age ramp
+    // Seconds for fresh stake to reach full voting power; 0 counts it in full at once
+    pub stake_ramp_seconds: i64,
+
+    // Proposal deposit
+    // Base-mint tokens escrowed to open a proposal, scaled down by the proposer's rank
+    // SECURITY: Refunded only if the proposal reaches quorum, so spam proposals cost tokens
+    // 0 disables the deposit
+    pub proposal_deposit: u64,
+
     // System pause flag
     // SECURITY: Emergency stop for maintenance or security incidents
     pub is_paused: bool,
@@ -51,10 +99,18 @@ pub struct Treasury {
     // Tracks users with non-zero stake
     pub stakers_count: u64,
 
+    // Tokens queued in unbonding tickets
+    // Held in the treasury but no longer counted as stake
+    pub total_unbonding: u64,
+
     // Treasury token account address
     // Holds all staked tokens
     pub treasury_token_account: Pubkey,
 
+    // Number of registered TreasuryHolding entries
+    // Also the index of the next one; value reports must pass every holding
+    pub holdings_count: u16,
+
     // PDA bumps
     pub state_bump: u8,
     pub vault_bump: u8,
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+// Treasury Holding
+//
+// SECURITY: Registry entry for one token the treasury holds, with the oracle
+// feed that prices it
+// Seeds tie the entry to one treasury and one mint, so only the admin's
+// register_treasury_holding can create it and a mint cannot be listed twice
+// Tokens are held in the treasury authority's ATA for the mint, the same
+// account staking uses for the base mint and supported mints
+#[account]
+#[derive(InitSpace)]
+pub struct TreasuryHolding {
+    pub treasury: Pubkey,
+    pub mint: Pubkey,
+
+    // Treasury authority's ATA for this mint
+    pub token_account: Pubkey,
+
+    // Oracle program that owns the feed, and the feed itself
+    // SECURITY: Pinned at registration; reports reject any other feed
+    pub oracle_program: Pubkey,
+    pub price_feed: Pubkey,
+
+    // Mint decimals, copied so reports need not pass the mint
+    pub decimals: u8,
+
+    // Position in the treasury's holdings (0..holdings_count)
+    // SECURITY: Reports must pass holdings in index order, so none can be left out
+    pub index: u16,
+
+    pub bump: u8,
+}
+
+// Holding Value
+//
+// One line of a treasury value report
+// amount excludes tokens owed to stakers; value is in TREASURY_VALUE_DECIMALS
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct HoldingValue {
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub price: u64,
+    pub exponent: i32,
+    pub value: u128,
+}
+
+// Treasury Value Report
+//
+// Return value of treasury_value_report, one entry per holding in index order
+// total_value is the sum of the entries' values
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TreasuryValueReport {
+    pub total_value: u128,
+    pub holdings: Vec<HoldingValue>,
+}
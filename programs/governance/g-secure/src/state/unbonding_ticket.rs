@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+// Unbonding Ticket
+//
+// SECURITY: Holds unstaked tokens in a queue until unlock_at
+// Tokens remain in the treasury and no longer count as stake
+// Closed on claim so the same ticket cannot be redeemed twice
+#[account]
+#[derive(InitSpace)]
+pub struct UnbondingTicket {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub unlock_at: i64,
+    pub bump: u8,
+}
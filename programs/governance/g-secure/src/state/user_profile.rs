@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use soteria_common::math::mul_div_floor;
+
+use crate::{constants::*, errors::GovernanceError, state::{MemberRanks, Rank}};
 
 // User Profile
 //
@@ -19,74 +22,131 @@ pub struct UserProfile {
     pub total_votes_cast: u64,
     pub last_vote_timestamp: i64,
     pub created_at: i64,
+    pub last_stake_timestamp: i64,
+    pub delegated_to: Pubkey,
+    pub delegated_stake: u64,
+    pub last_decay_ts: i64,
+    pub reward_debt: u64,
+    pub last_reward_ts: i64,
+    pub weighted_stake: u64,
+    pub stake_start_ts: i64,
+
+    // Optional avatar/metadata link, set by update_profile_metadata
+    // Kept last: the profile is created without it and resized as it changes
+    #[max_len(MAX_METADATA_URI_LENGTH)]
+    pub metadata_uri: Option<String>,
 }
 
+impl UserProfile {
+    // Account size for a profile holding the given metadata URI
+    // INIT_SPACE reserves the longest URI; only the bytes in use are allocated
+    pub fn space(metadata_uri: Option<&str>) -> usize {
+        ANCHOR_DISCRIMINATOR + Self::INIT_SPACE - 4 - MAX_METADATA_URI_LENGTH
+            + metadata_uri.map_or(0, |uri| 4 + uri.len())
+    }
+
+    // Metadata URI validation
+    //
+    // SECURITY: Bounded length, https:// or ipfs:// scheme with something
+    // after it, and printable ASCII only, so the link cannot smuggle
+    // whitespace or control characters into the front-ends that render it
+    pub fn validate_metadata_uri(uri: &str) -> Result<()> {
+        require!(
+            uri.len() <= MAX_METADATA_URI_LENGTH,
+            GovernanceError::MetadataUriTooLong
+        );
+
+        let has_scheme = METADATA_URI_SCHEMES
+            .iter()
+            .any(|scheme| uri.len() > scheme.len() && uri.starts_with(scheme));
+        require!(
+            has_scheme && uri.bytes().all(|byte| byte.is_ascii_graphic()),
+            GovernanceError::InvalidMetadataUri
+        );
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Copy, PartialEq, Eq)]
-pub enum MemberRanks {
-    /// Entry level - Can upvote only, 24h cooldown
-    /// Reputation: 0-50 points
-    /// Perks: Basic profile, stake tokens, upvote others
-    Member,
-    /// First upgrade - Unlocks downvoting ability
-    /// Reputation: 50-100 points  
-    /// Perks: Can downvote, bronze badge, all Member perks
-    Bronze,
-    /// Active community member - Enhanced voting power
-    /// Reputation: 100-200 points
-    /// Perks:  18h cooldown, can nominate others
-    Contributor,
-    /// Trusted moderator - Significant influence
-    /// Reputation: 200-400 points
-    /// Perks: 12h cooldown, can vote twice daily, moderator privileges
-    Guardian,
-    /// Elite status - Maximum privileges and recognition
-    /// Reputation: 400+ points
-    /// Perks: no cooldown, unlimited voting, governance proposals
-    Leader,
- }
+        Ok(())
+    }
 
- impl MemberRanks {
-    pub fn can_downvote(&self) -> bool {
-        !matches!(self, MemberRanks::Member)
+    // Own voting power
+    //
+    // Weighted sum of every staked mint: base-mint stake counts 1x,
+    // supported mints add their weighted power via weighted_stake
+    pub fn own_power(&self) -> Option<u64> {
+        self.stake_amount.checked_add(self.weighted_stake)
     }
 
-    pub fn cooldown_hours(&self) -> u64 {
-        match self {
-            MemberRanks::Member | MemberRanks::Bronze => 24,  // Full day cooldown
-            MemberRanks::Contributor => 18,                    // 25% reduction
-            MemberRanks::Guardian => 12,                       // 50% reduction  
-            MemberRanks::Leader => 0,                          // No restrictions
+    // Matured voting power
+    //
+    // Own power ramps up linearly with stake age:
+    //   own_power * min(now - stake_start_ts, ramp_seconds) / ramp_seconds
+    // SECURITY: Freshly staked (or borrowed) tokens carry little weight,
+    // so a flash stake cannot swing a vote. A zero ramp counts full power
+    pub fn matured_power(&self, now: i64, ramp_seconds: i64) -> Option<u64> {
+        let own_power = self.own_power()?;
+        if ramp_seconds <= 0 {
+            return Some(own_power);
         }
+        let age = now.checked_sub(self.stake_start_ts)?.clamp(0, ramp_seconds);
+        mul_div_floor(own_power, age as u64, ramp_seconds as u64)
+    }
+
+    // Effective voting power
+    //
+    // Matured own power counts only while it has not been delegated away
+    // Power delegated to this profile by others is always added
+    pub fn effective_power(&self, now: i64, ramp_seconds: i64) -> Option<u64> {
+        let own_power = if self.delegated_to == Pubkey::default() {
+            self.matured_power(now, ramp_seconds)?
+        } else {
+            0
+        };
+        own_power.checked_add(self.delegated_stake)
     }
 
-    // Calculate role from reputation points
+    // Restart the stake clock for newly staked power
     //
-    // SECURITY: Automatic role progression based on reputation
-    // Prevents manual role manipulation by deriving from reputation
-    pub fn from_reputation(points: i64) -> Self {
-        match points {
-            i64::MIN..=50 => MemberRanks::Member,
-            51..=100 => MemberRanks::Bronze,
-            101..=200 => MemberRanks::Contributor,
-            201..=400 => MemberRanks::Guardian,
-            401..=i64::MAX => MemberRanks::Leader,
+    // Moves stake_start_ts to the power-weighted average of the current start
+    // and now, so new stake matures from now and existing stake keeps its age
+    // SECURITY: Topping up an aged stake cannot borrow that age for the new
+    // tokens; must be called before own power changes
+    pub fn add_stake_age(&mut self, added_power: u64, now: i64) -> Option<()> {
+        let held = self.own_power()? as i128;
+        let added = added_power as i128;
+        let total = held.checked_add(added)?;
+        if total == 0 {
+            return Some(());
         }
+        let start = held
+            .checked_mul(self.stake_start_ts as i128)?
+            .checked_add(added.checked_mul(now as i128)?)?
+            .checked_div(total)?;
+        self.stake_start_ts = i64::try_from(start).ok()?;
+        Some(())
     }
 
-    pub fn vote_weight(&self) -> u8 {
-    match self {
-        MemberRanks::Member => 1,        // +1 or -1 reputation
-        MemberRanks::Bronze => 1,        // +1 or -1 reputation  
-        MemberRanks::Contributor => 2,   // +2 or -2 reputation
-        MemberRanks::Guardian => 2,      // +2 or -2 reputation
-        MemberRanks::Leader => 3,        // +3 or -3 reputation
+    // Accrue staking rewards
+    //
+    // Adds stake * rate * elapsed / REWARD_RATE_SCALE to reward_debt
+    // SECURITY: u128 intermediates and checked math; must be called before
+    // stake_amount changes so rewards use the stake that was actually held
+    pub fn accrue_rewards(&mut self, reward_rate_per_sec: u64, now: i64) -> Option<()> {
+        let elapsed = now.checked_sub(self.last_reward_ts)?.max(0) as u128;
+        let reward = (self.stake_amount as u128)
+            .checked_mul(reward_rate_per_sec as u128)?
+            .checked_mul(elapsed)?
+            .checked_div(REWARD_RATE_SCALE as u128)?;
+        self.reward_debt = self.reward_debt.checked_add(u64::try_from(reward).ok()?)?;
+        self.last_reward_ts = now;
+        Some(())
     }
-}
- }
 
-impl Space for MemberRanks {
-	const INIT_SPACE : usize = 1;
+    // Current rank
+    //
+    // Derived from reputation_points rather than read from role_level
+    // SECURITY: Gates cannot be passed on a rank the profile no longer holds
+    pub fn rank(&self) -> Rank {
+        Rank::from_reputation(self.reputation_points)
+    }
 }
 
 
@@ -5,11 +5,14 @@ use anchor_lang::prelude::*;
 // SECURITY: Prevents spam voting attacks
 // Tracks last vote timestamp per user to enforce role-based cooldowns
 // Different roles have different cooldown periods (0-24 hours)
+// Closable by the voter once its season is over and no cooldown can be running
 #[account]
 #[derive(InitSpace)]
 pub struct VoteCooldown {
     pub voter: Pubkey,
     pub last_vote_timestamp: i64,
+    // Season of the last vote
+    pub season: u64,
     pub bump: u8,
 }
 
@@ -21,6 +24,10 @@ pub struct VoteCooldown {
 //
 // NOTE: vote_weight stored as i64 to preserve full voting power calculation
 // This prevents truncation when role_weight * vote_power exceeds u8::MAX
+//
+// Votes count once per season: only a record from the current season is
+// reversed on a vote change, so the voter can close it for its rent once
+// the season has rolled over
 #[account]
 #[derive(InitSpace)]
 pub struct VoteRecord {
@@ -31,6 +38,8 @@ pub struct VoteRecord {
     pub vote_type: VoteType,
     pub vote_weight: i64,
     pub timestamp: i64,
+    // Season the vote was cast in
+    pub season: u64,
     pub bump: u8,
 }
 
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use soteria_common::math::mul_div_floor;
+
+use crate::constants::*;
+
+// Vote Escrow
+//
+// SECURITY: Base-mint tokens locked until unlock_at, held in the escrow's own ATA
+// Voting power decays linearly to zero at unlock_at, so longer locks vote louder
+// locked_at moves on every lock or extension; ballots only count locks made
+// before the proposal, like staked tokens
+#[account]
+#[derive(InitSpace)]
+pub struct VoteEscrow {
+    pub owner: Pubkey,
+    pub config: Pubkey,
+    pub amount: u64,
+    pub locked_at: i64,
+    pub unlock_at: i64,
+    pub bump: u8,
+}
+
+impl VoteEscrow {
+    // Voting power at a given time
+    //
+    //   amount * min(unlock_at - now, MAX_LOCK_SECONDS) / MAX_LOCK_SECONDS
+    // SECURITY: Measured in unix time, never slots; zero once the lock expires
+    pub fn voting_power(&self, now: i64) -> Option<u64> {
+        let remaining = self.unlock_at.checked_sub(now)?.clamp(0, MAX_LOCK_SECONDS);
+        mul_div_floor(self.amount, remaining as u64, MAX_LOCK_SECONDS as u64)
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.unlock_at
+    }
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::VoteType;
+
+// Vote History
+//
+// Ring buffer of the most recent votes cast by a profile
+// Fixed-size header followed by the entry vector, so clients can page
+// through entries by offset (see VoteHistory::HEADER_SPACE)
+// SECURITY: Capacity grows by realloc up to VOTE_HISTORY_MAX_ENTRIES,
+// then the oldest entry is overwritten (account size stays bounded)
+#[account]
+pub struct VoteHistory {
+    pub owner: Pubkey,
+    pub bump: u8,
+
+    // Entries the account currently has space for
+    pub capacity: u16,
+
+    // Index the next entry is written to
+    pub head: u16,
+
+    // Votes ever recorded (older ones may have been overwritten)
+    pub total_recorded: u64,
+
+    pub entries: Vec<VoteHistoryEntry>,
+}
+
+// Vote History Entry
+//
+// One vote cast by the history owner
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct VoteHistoryEntry {
+    pub target: Pubkey,
+    pub vote_type: VoteType,
+    pub timestamp: i64,
+    pub weight: i64,
+}
+
+impl VoteHistory {
+    // owner + bump + capacity + head + total_recorded + entries length prefix
+    pub const HEADER_SPACE: usize = 32 + 1 + 2 + 2 + 8 + 4;
+
+    // Account size needed to hold `capacity` entries
+    pub fn space(capacity: usize) -> usize {
+        ANCHOR_DISCRIMINATOR + Self::HEADER_SPACE + capacity * VoteHistoryEntry::INIT_SPACE
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.entries.len() >= self.capacity as usize
+    }
+
+    // Record a larger capacity after the account has been reallocated
+    // Writing resumes at the end of the stored entries
+    pub fn grow(&mut self, new_capacity: u16) {
+        self.capacity = new_capacity;
+        self.head = self.entries.len() as u16;
+    }
+
+    // Append an entry, or overwrite the oldest one once at capacity
+    // SECURITY: Never pushes past capacity, so serialization always fits
+    pub fn record(&mut self, entry: VoteHistoryEntry) -> Option<()> {
+        let head = self.head as usize;
+        if head < self.entries.len() {
+            self.entries[head] = entry;
+        } else if !self.is_full() {
+            self.entries.push(entry);
+        } else {
+            return None;
+        }
+        self.head = ((head + 1) % self.capacity as usize) as u16;
+        self.total_recorded = self.total_recorded.checked_add(1)?;
+        Some(())
+    }
+}
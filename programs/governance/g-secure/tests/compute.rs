@@ -0,0 +1,127 @@
+// Compute-unit regression tests for g-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// downvote is not measured: it needs a Bronze-ranked voter, and reaching that
+// rank takes far more setup than the rest of this flow combined
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+mod utils;
+
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signature::Signer};
+use soteria_test_kit::{send_ix, ComputeBudget, DEFAULT_CU_BASELINES_FILE};
+use utils::*;
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let mut svm = setup_svm();
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let new_admin = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+    let alice = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let bob = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let carol = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let (admin_key, alice_key, bob_key) = (admin.pubkey(), alice.pubkey(), bob.pubkey());
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+
+    let ix = build_init_dao_ix(&admin_key, &admin_key, 10_000_000, &token_mint, 5);
+    budget.record("init_dao", send_ix(&mut svm, ix, &admin));
+
+    let ix = build_initialize_treasury_ix(&admin_key, &admin_key, &token_mint);
+    budget.record("initialize_treasury", send_ix(&mut svm, ix, &admin));
+    let treasury_token_account = governance::treasury_token_address(&admin_key, &token_mint);
+    MintTo::new(&mut svm, &admin, &token_mint, &treasury_token_account, 100_000_000_000)
+        .send()
+        .expect("Funding reward reserve should succeed");
+
+    let ix = build_create_profile_ix(&alice_key, "alice");
+    budget.record("create_profile", send_ix(&mut svm, ix, &alice));
+    let ix = build_create_profile_ix(&bob_key, "bob");
+    send_ix(&mut svm, ix, &bob).expect("Profile creation should succeed");
+
+    for user in [&alice_key, &bob_key] {
+        let user_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+            .owner(user)
+            .send()
+            .expect("Failed to create user ATA");
+        MintTo::new(&mut svm, &admin, &token_mint, &user_token_account, 100_000_000)
+            .send()
+            .expect("Minting should succeed");
+    }
+
+    let ix = build_stake_tokens_ix(&alice_key, &admin_key, &token_mint, 20_000_000);
+    budget.record("stake_tokens", send_ix(&mut svm, ix, &alice));
+    let ix = build_stake_tokens_ix(&bob_key, &admin_key, &token_mint, 20_000_000);
+    send_ix(&mut svm, ix, &bob).expect("Staking should succeed");
+
+    // Member cooldown is 24 hours
+    advance_time(&mut svm, 25 * 3600);
+    let ix = build_upvote_ix_with_target(&alice_key, &admin_key, &bob_key, "bob");
+    budget.record("upvote", send_ix(&mut svm, ix, &alice));
+
+    let ix = build_delegate_votes_ix(&bob_key, &admin_key, &alice_key);
+    budget.record("delegate_votes", send_ix(&mut svm, ix, &bob));
+    let ix = build_revoke_delegation_ix(&bob_key, &alice_key);
+    budget.record("revoke_delegation", send_ix(&mut svm, ix, &bob));
+
+    let ix = build_create_governance_proposal_ix(&alice_key, &admin_key, &token_mint, 0, "Fund grants");
+    budget.record("create_governance_proposal", send_ix(&mut svm, ix, &alice));
+    let ix = build_cast_ballot_ix(&alice_key, &admin_key, 0, true);
+    budget.record("cast_ballot", send_ix(&mut svm, ix, &alice));
+
+    advance_time(&mut svm, PROPOSAL_VOTING_PERIOD_SECONDS + 1);
+    let ix = build_finalize_proposal_ix(&admin_key, &admin_key, &token_mint, 0, &alice_key);
+    budget.record("finalize_proposal", send_ix(&mut svm, ix, &admin));
+
+    let ix = build_claim_rewards_ix(&alice_key, &admin_key, &token_mint);
+    budget.record("claim_rewards", send_ix(&mut svm, ix, &alice));
+
+    let ix = build_unstake_tokens_ix(&alice_key, &admin_key, 10_000_000);
+    budget.record("unstake_tokens", send_ix(&mut svm, ix, &alice));
+    advance_time(&mut svm, DEFAULT_UNBONDING_PERIOD as u64 + 1);
+    let ix = build_claim_unbonded_ix(&alice_key, &admin_key, &token_mint);
+    budget.record("claim_unbonded", send_ix(&mut svm, ix, &alice));
+
+    // More than one decay epoch has passed since the upvote
+    let ix = build_decay_reputation_ix(&carol.pubkey(), &admin_key, &bob_key);
+    budget.record("decay_reputation", send_ix(&mut svm, ix, &carol));
+
+    let ix = build_reset_user_reputation_ix(&admin_key, &admin_key, &bob_key, 0);
+    budget.record("reset_user_reputation", send_ix(&mut svm, ix, &admin));
+    let reward_pool = 3_000_000;
+    MintTo::new(&mut svm, &admin, &token_mint, &treasury_token_account, reward_pool)
+        .send()
+        .expect("Funding reward pool should succeed");
+    let ix = build_close_season_ix(&admin_key, &admin_key, 0, reward_pool);
+    budget.record("close_season", send_ix(&mut svm, ix, &admin));
+    let ix = build_claim_season_reward_ix(&bob_key, &admin_key, &token_mint, 0);
+    budget.record("claim_season_reward", send_ix(&mut svm, ix, &bob));
+
+    let ix =
+        build_slash_stake_ix(&admin_key, &admin_key, &token_mint, &bob_key, 2_500, false, None);
+    budget.record("slash_stake", send_ix(&mut svm, ix, &admin));
+
+    // A profile without stake can be renamed and closed
+    let ix = build_create_profile_ix(&carol.pubkey(), "carol");
+    send_ix(&mut svm, ix, &carol).expect("Profile creation should succeed");
+    let ix = build_change_username_ix(&carol.pubkey(), "carol", "caroline");
+    budget.record("change_username", send_ix(&mut svm, ix, &carol));
+    let ix = build_close_profile_ix(&carol.pubkey(), "caroline");
+    budget.record("close_profile", send_ix(&mut svm, ix, &carol));
+
+    let ix = build_propose_admin_ix(&admin_key, &admin_key, &new_admin.pubkey());
+    budget.record("propose_admin", send_ix(&mut svm, ix, &admin));
+    let ix = build_accept_admin_ix(&new_admin.pubkey(), &admin_key);
+    budget.record("accept_admin", send_ix(&mut svm, ix, &new_admin));
+
+    budget.check();
+}
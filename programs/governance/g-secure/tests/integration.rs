@@ -11,16 +11,44 @@
 // === Security Tests ===
 // 5. test_duplicate_username_rejected - Username uniqueness enforcement
 // 6. test_minimum_stake_enforcement - Cannot vote without minimum stake
+// 7. test_proposal_ballot_lifecycle - One stake-weighted ballot per voter, stake snapshot
+// 8. test_unbonding_period_enforced - Unstaked tokens locked until unbonding period ends
+// 9. test_delegation_no_cycles - Delegated power counted once, cycles rejected
+// 10. test_reputation_decay_per_epoch - Decay applied once per elapsed epoch
+// 11. test_claim_rewards_large_stake - Reward math cannot overflow, reserve protects principal
+// 12. test_change_username_and_close_profile - Only the owner's registry is freed, staked profiles stay open
+// 13. test_admin_two_step_transfer - Admin role moves only when the proposed key accepts
+// 14. test_vote_history_ring_buffer - History grows by realloc to a cap, then wraps
+// 15. test_slash_stake_partial_and_full - Admin-only slashing, delegated power trimmed, burn or redirect
+// 16. test_season_snapshot_rewards - Season standings paid once per entry, only after the season closes
+// 17. test_multi_mint_weighted_staking - Admin-registered mints stake into their own treasury at their weight
+// 18. test_multisig_action_via_cpi - Passed proposal opens its multisig proposal once, via the executor PDA
+// 19. test_vote_batch_settlement - Relayed signed votes count once, only with their own Ed25519 data
+// 20. test_stake_age_voting_power - Fresh stake votes with power ramped by its age
+// 21. test_close_vote_accounts_after_season - Vote records and cooldowns close only after their season
+// 22. test_emergency_council - Pause and veto need threshold council signatures, veto window holds back execution
+// 23. test_downvote_requires_bronze_rank - Rank gate rejects a Member downvote, leaves reputation untouched
+// 24. test_vote_escrow_lock - Locked power decays to the unlock time, extensions and late locks miss the snapshot
+// 25. test_treasury_value_report - Oracle-priced portfolio counts only DAO-owned tokens, every holding required
+// 26. test_proposal_deposit - Rank-scaled deposit refunded at quorum, burned without it
+// 27. test_profile_metadata_uri - Bounded https/ipfs URI, profile resized to fit
+//
+// === Feature Matrix Tests ===
+// 28. test_feature_missing_unbonding_check - Only the vuln-missing-unbonding-check build pays out early
 
 mod utils;
 
-use litesvm::LiteSVM;
-use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+use litesvm::{types::TransactionResult, LiteSVM};
+use litesvm_token::{get_spl_account, CreateAssociatedTokenAccount, CreateMint, MintTo};
 use solana_sdk::{
     native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
     signature::{Keypair, Signer},
     transaction::Transaction,
 };
+use soteria_test_kit::{
+    run_feature_differential, DifferentialScenario, ExploitInfo, FeatureVariant, Severity,
+};
 use utils::*;
 
 #[test]
@@ -528,4 +556,3442 @@ fn test_minimum_stake_enforcement() {
     println!("[Test] Vote succeeded with minimum stake - secure version enforces minimum");
 
     println!("[TEST END] test_minimum_stake_enforcement");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_proposal_ballot_lifecycle() {
+    println!("[TEST START] test_proposal_ballot_lifecycle");
+    let mut svm = setup_svm();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let voter = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let late_staker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    println!("[Setup] Accounts funded: admin, voter, late staker");
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+    println!("[Setup] Token mint created");
+
+    let ix = build_init_dao_ix(
+        &admin.pubkey(),
+        &admin.pubkey(),
+        10_000_000,
+        &token_mint,
+        5,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("DAO init should succeed");
+    println!("[Setup] DAO initialized (quorum: 10 tokens, threshold: 50%)");
+
+    let ix = build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx)
+        .expect("Treasury init should succeed");
+    println!("[Setup] Treasury initialized");
+
+    for (user, username) in [(&voter, "voter1"), (&late_staker, "latecomer")] {
+        let ix = build_create_profile_ix(&user.pubkey(), username);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.pubkey()),
+            &[user],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Profile creation should succeed");
+
+        let user_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+            .owner(&user.pubkey())
+            .send()
+            .expect("Failed to create user ATA");
+
+        MintTo::new(&mut svm, &admin, &token_mint, &user_token_account, 100_000_000)
+            .owner(&admin)
+            .send()
+            .expect("Minting should succeed");
+    }
+    println!("[Setup] Profiles created and tokens minted");
+
+    let ix = build_stake_tokens_ix(
+        &voter.pubkey(),
+        &admin.pubkey(),
+        &token_mint,
+        20_000_000,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Staking should succeed");
+    println!("[Setup] Voter staked 20 tokens");
+
+    advance_time(&mut svm, 60);
+
+    let ix = build_create_governance_proposal_ix(
+        &voter.pubkey(),
+        &admin.pubkey(),
+        &token_mint,
+        0,
+        "Fund community grants",
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Proposal creation should succeed");
+    println!("[Setup] Proposal 0 created");
+
+    println!("[Action] Voter casts a ballot in favor");
+    let ix = build_cast_ballot_ix(&voter.pubkey(), &admin.pubkey(), 0, true);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix.clone()],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("First ballot should succeed");
+
+    println!("[Action] Voter attempts a second ballot on the same proposal");
+    svm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Second ballot from the same voter should be rejected");
+    println!("[Verification] Double vote rejected - ballot record already exists");
+
+    println!("[Action] Late staker stakes after proposal creation and tries to vote");
+    let ix = build_stake_tokens_ix(
+        &late_staker.pubkey(),
+        &admin.pubkey(),
+        &token_mint,
+        50_000_000,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&late_staker.pubkey()),
+        &[&late_staker],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Staking should succeed");
+
+    let ix = build_cast_ballot_ix(&late_staker.pubkey(), &admin.pubkey(), 0, false);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&late_staker.pubkey()),
+        &[&late_staker],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Stake added after proposal creation should not vote");
+    println!("[Verification] Late stake rejected by snapshot check");
+
+    println!("[Action] Attempting to finalize before voting ends");
+    let ix = build_finalize_proposal_ix(&admin.pubkey(), &admin.pubkey(), &token_mint, 0, &voter.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix.clone()],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Finalize should fail while voting is open");
+    println!("[Verification] Early finalize rejected");
+
+    advance_time(&mut svm, PROPOSAL_VOTING_PERIOD_SECONDS + 1);
+    svm.expire_blockhash();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Finalize should succeed after voting ends");
+
+    let (config, _) = governance::config_address(&admin.pubkey());
+    let (proposal, _) = governance::proposal_address(&config, 0);
+    let (votes_for, votes_against, status) = get_proposal_tally(&svm, &proposal);
+    assert_eq!(votes_for, 20_000_000, "Only the single ballot should be counted");
+    assert_eq!(votes_against, 0, "Late stake should not be counted");
+    assert_eq!(status, 1, "Proposal should pass with quorum and majority");
+    println!("[Test] Proposal passed: {} for, {} against", votes_for, votes_against);
+
+    println!("[TEST END] test_proposal_ballot_lifecycle");
+}
+
+#[test]
+fn test_unbonding_period_enforced() {
+    println!("[TEST START] test_unbonding_period_enforced");
+    let mut svm = setup_svm();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let user = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    println!("[Setup] Admin and user funded");
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+    println!("[Setup] Token mint created");
+
+    let ix = build_init_dao_ix(
+        &admin.pubkey(),
+        &admin.pubkey(),
+        10_000_000,
+        &token_mint,
+        5,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("DAO init should succeed");
+    println!("[Setup] DAO initialized (unbonding period: 7 days)");
+
+    let ix = build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx)
+        .expect("Treasury init should succeed");
+    println!("[Setup] Treasury initialized");
+
+    let ix = build_create_profile_ix(&user.pubkey(), "staker");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&user.pubkey()),
+        &[&user],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx)
+        .expect("Profile creation should succeed");
+    println!("[Setup] User profile created");
+
+    let user_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+        .owner(&user.pubkey())
+        .send()
+        .expect("Failed to create user ATA");
+
+    MintTo::new(&mut svm, &admin, &token_mint, &user_token_account, 100_000_000)
+        .owner(&admin)
+        .send()
+        .expect("Minting should succeed");
+    println!("[Setup] Tokens minted to user");
+
+    let ix = build_stake_tokens_ix(
+        &user.pubkey(),
+        &admin.pubkey(),
+        &token_mint,
+        20_000_000,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&user.pubkey()),
+        &[&user],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Staking should succeed");
+    println!("[Setup] User staked 20 tokens");
+
+    println!("[Action] Unstaking 10 tokens into an unbonding ticket");
+    let ix = build_unstake_tokens_ix(&user.pubkey(), &admin.pubkey(), 10_000_000);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&user.pubkey()),
+        &[&user],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Unstake should succeed");
+
+    let balance = get_spl_account::<spl_token::state::Account>(&svm, &user_token_account)
+        .unwrap()
+        .amount;
+    assert_eq!(balance, 80_000_000, "Unstake should not return tokens immediately");
+    println!("[Verification] Tokens still held by treasury after unstake");
+
+    println!("[Action] Attempting to claim before the unbonding period ends");
+    let claim_ix = build_claim_unbonded_ix(&user.pubkey(), &admin.pubkey(), &token_mint);
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix.clone()],
+        Some(&user.pubkey()),
+        &[&user],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Claim should fail while unbonding");
+    println!("[Verification] Early claim rejected");
+
+    advance_time(&mut svm, DEFAULT_UNBONDING_PERIOD as u64 + 1);
+    svm.expire_blockhash();
+
+    println!("[Action] Claiming after the unbonding period");
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix],
+        Some(&user.pubkey()),
+        &[&user],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Claim should succeed after unbonding");
+
+    let balance = get_spl_account::<spl_token::state::Account>(&svm, &user_token_account)
+        .unwrap()
+        .amount;
+    assert_eq!(balance, 90_000_000, "Unbonded tokens should be returned");
+
+    let (ticket, _) = governance::unbonding_ticket_address(&user.pubkey());
+    assert!(
+        svm.get_account(&ticket).map_or(true, |a| a.lamports == 0),
+        "Unbonding ticket should be closed after claim"
+    );
+    println!("[Test] 10 tokens returned and ticket closed");
+
+    println!("[TEST END] test_unbonding_period_enforced");
+}
+
+#[test]
+fn test_delegation_no_cycles() {
+    println!("[TEST START] test_delegation_no_cycles");
+    let mut svm = setup_svm();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let alice = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let bob = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    println!("[Setup] Accounts funded: admin, alice, bob");
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+    println!("[Setup] Token mint created");
+
+    let ix = build_init_dao_ix(
+        &admin.pubkey(),
+        &admin.pubkey(),
+        10_000_000,
+        &token_mint,
+        5,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("DAO init should succeed");
+
+    let ix = build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx)
+        .expect("Treasury init should succeed");
+    println!("[Setup] DAO and treasury initialized");
+
+    for (user, username) in [(&alice, "alice"), (&bob, "bob")] {
+        let ix = build_create_profile_ix(&user.pubkey(), username);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.pubkey()),
+            &[user],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Profile creation should succeed");
+
+        let user_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+            .owner(&user.pubkey())
+            .send()
+            .expect("Failed to create user ATA");
+
+        MintTo::new(&mut svm, &admin, &token_mint, &user_token_account, 100_000_000)
+            .owner(&admin)
+            .send()
+            .expect("Minting should succeed");
+
+        let ix = build_stake_tokens_ix(&user.pubkey(), &admin.pubkey(), &token_mint, 20_000_000);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.pubkey()),
+            &[user],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Staking should succeed");
+    }
+    println!("[Setup] Alice and Bob each staked 20 tokens");
+
+    println!("[Action] Alice delegates her voting power to Bob");
+    let ix = build_delegate_votes_ix(&alice.pubkey(), &admin.pubkey(), &bob.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Delegation should succeed");
+
+    println!("[Action] Bob attempts to delegate back to Alice");
+    let ix = build_delegate_votes_ix(&bob.pubkey(), &admin.pubkey(), &alice.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&bob.pubkey()),
+        &[&bob],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Circular delegation should be rejected");
+    println!("[Verification] Delegation cycle rejected");
+
+    advance_time(&mut svm, 60);
+
+    let ix = build_create_governance_proposal_ix(
+        &bob.pubkey(),
+        &admin.pubkey(),
+        &token_mint,
+        0,
+        "Expand delegate program",
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&bob.pubkey()),
+        &[&bob],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Proposal creation should succeed");
+    println!("[Setup] Proposal 0 created by Bob");
+
+    println!("[Action] Alice tries to vote with stake she delegated away");
+    let ix = build_cast_ballot_ix(&alice.pubkey(), &admin.pubkey(), 0, true);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Delegator should have no voting power left");
+    println!("[Verification] Delegator ballot rejected");
+
+    println!("[Action] Bob votes with his own and Alice's delegated stake");
+    let ix = build_cast_ballot_ix(&bob.pubkey(), &admin.pubkey(), 0, true);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&bob.pubkey()),
+        &[&bob],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Delegate ballot should succeed");
+
+    println!("[Action] Alice revokes her delegation and tries to vote again");
+    let ix = build_revoke_delegation_ix(&alice.pubkey(), &bob.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Revoke should succeed");
+
+    svm.expire_blockhash();
+    let ix = build_cast_ballot_ix(&alice.pubkey(), &admin.pubkey(), 0, true);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Reclaimed stake should not vote on an open proposal");
+    println!("[Verification] Reclaimed stake rejected by snapshot check");
+
+    let (config, _) = governance::config_address(&admin.pubkey());
+    let (proposal, _) = governance::proposal_address(&config, 0);
+    let (votes_for, votes_against, _) = get_proposal_tally(&svm, &proposal);
+    assert_eq!(votes_for, 40_000_000, "Delegated stake should be counted exactly once");
+    assert_eq!(votes_against, 0);
+    println!("[Test] Tally: {} for - each staked token counted once", votes_for);
+
+    println!("[TEST END] test_delegation_no_cycles");
+}
+
+#[test]
+fn test_reputation_decay_per_epoch() {
+    println!("[TEST START] test_reputation_decay_per_epoch");
+    let mut svm = setup_svm();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let voter = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let target = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let cranker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    println!("[Setup] Accounts funded: admin, voter, target, cranker");
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+
+    let ix = build_init_dao_ix(
+        &admin.pubkey(),
+        &admin.pubkey(),
+        10_000_000,
+        &token_mint,
+        5,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("DAO init should succeed");
+
+    let ix = build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx)
+        .expect("Treasury init should succeed");
+    println!("[Setup] DAO initialized (decay: 10% per 7-day epoch)");
+
+    for (user, username) in [(&voter, "voter1"), (&target, "target1")] {
+        let ix = build_create_profile_ix(&user.pubkey(), username);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.pubkey()),
+            &[user],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Profile creation should succeed");
+    }
+
+    let voter_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+        .owner(&voter.pubkey())
+        .send()
+        .expect("Failed to create voter ATA");
+
+    MintTo::new(&mut svm, &admin, &token_mint, &voter_token_account, 100_000_000)
+        .owner(&admin)
+        .send()
+        .expect("Minting should succeed");
+
+    let ix = build_stake_tokens_ix(
+        &voter.pubkey(),
+        &admin.pubkey(),
+        &token_mint,
+        20_000_000,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Staking should succeed");
+
+    advance_time(&mut svm, 25 * 3600);
+
+    let ix = build_upvote_ix_with_target(
+        &voter.pubkey(),
+        &admin.pubkey(),
+        &target.pubkey(),
+        "target1",
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Upvote should succeed");
+
+    let (target_profile, _) = governance::user_profile_address(&target.pubkey());
+    assert_eq!(get_reputation(&svm, &target_profile), 5);
+    println!("[Setup] Target upvoted to 5 reputation");
+
+    println!("[Action] Cranking decay before a full epoch has elapsed");
+    let ix = build_decay_reputation_ix(&cranker.pubkey(), &admin.pubkey(), &target.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix.clone()],
+        Some(&cranker.pubkey()),
+        &[&cranker],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Decay should not apply inside the first epoch");
+    println!("[Verification] Early decay rejected");
+
+    println!("[Action] Advancing two epochs and cranking decay");
+    advance_time(&mut svm, 2 * DEFAULT_DECAY_EPOCH_SECONDS as u64 + 60);
+    svm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix.clone()],
+        Some(&cranker.pubkey()),
+        &[&cranker],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Decay should succeed after elapsed epochs");
+    assert_eq!(
+        get_reputation(&svm, &target_profile),
+        3,
+        "Two epochs of 10% decay (rounded up) should take 5 down to 3"
+    );
+    println!("[Verification] Reputation decayed 5 -> 3 for two epochs");
+
+    println!("[Action] Cranking decay again in the same epoch");
+    svm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&cranker.pubkey()),
+        &[&cranker],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Repeated decay within an epoch should be rejected");
+    assert_eq!(get_reputation(&svm, &target_profile), 3);
+    println!("[Test] Repeat decay rejected - reputation unchanged");
+
+    println!("[TEST END] test_reputation_decay_per_epoch");
+}
+
+#[test]
+fn test_claim_rewards_large_stake() {
+    println!("[TEST START] test_claim_rewards_large_stake");
+    let mut svm = setup_svm();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let whale = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    println!("[Setup] Accounts funded: admin, whale");
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+
+    let ix = build_init_dao_ix(
+        &admin.pubkey(),
+        &admin.pubkey(),
+        10_000_000,
+        &token_mint,
+        5,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("DAO init should succeed");
+
+    let ix = build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx)
+        .expect("Treasury init should succeed");
+    println!("[Setup] DAO and treasury initialized");
+
+    let ix = build_create_profile_ix(&whale.pubkey(), "whale");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&whale.pubkey()),
+        &[&whale],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx)
+        .expect("Profile creation should succeed");
+
+    let whale_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+        .owner(&whale.pubkey())
+        .send()
+        .expect("Failed to create whale ATA");
+
+    MintTo::new(&mut svm, &admin, &token_mint, &whale_token_account, 200_000_000_000)
+        .owner(&admin)
+        .send()
+        .expect("Minting should succeed");
+
+    let ix = build_stake_tokens_ix(
+        &whale.pubkey(),
+        &admin.pubkey(),
+        &token_mint,
+        100_000_000_000,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&whale.pubkey()),
+        &[&whale],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Staking should succeed");
+    println!("[Setup] Whale staked 100,000 tokens");
+
+    println!("[Action] Claiming with an empty reward reserve");
+    advance_time(&mut svm, 3600);
+    let claim_ix = build_claim_rewards_ix(&whale.pubkey(), &admin.pubkey(), &token_mint);
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix.clone()],
+        Some(&whale.pubkey()),
+        &[&whale],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Rewards must not be paid from staked principal");
+    println!("[Verification] Claim rejected - principal is not a reward reserve");
+
+    let treasury_token_account = governance::treasury_token_address(&admin.pubkey(), &token_mint);
+    MintTo::new(&mut svm, &admin, &token_mint, &treasury_token_account, 100_000_000_000)
+        .owner(&admin)
+        .send()
+        .expect("Funding reward reserve should succeed");
+    println!("[Setup] Reward reserve funded with 100,000 tokens");
+
+    println!("[Action] Claiming after one week staked");
+    advance_time(&mut svm, 7 * 24 * 3600 - 3600);
+    svm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix],
+        Some(&whale.pubkey()),
+        &[&whale],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Claim should succeed with a funded reserve");
+
+    // 100_000_000_000 staked * 1_000 rate * 604_800 s / 1_000_000_000 scale
+    // The intermediate product (6.048e19) exceeds u64::MAX
+    let expected_reward = 60_480_000_000;
+    let balance = get_spl_account::<spl_token::state::Account>(&svm, &whale_token_account)
+        .unwrap()
+        .amount;
+    assert_eq!(
+        balance,
+        100_000_000_000 + expected_reward,
+        "Reward should match stake * rate * time"
+    );
+    println!("[Test] Whale received {} reward base units for one week", expected_reward);
+
+    println!("[TEST END] test_claim_rewards_large_stake");
+}
+
+#[test]
+fn test_change_username_and_close_profile() {
+    println!("[TEST START] test_change_username_and_close_profile");
+    let mut svm = setup_svm();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let alice = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let bob = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let carol = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    println!("[Setup] Accounts funded: admin, alice, bob, carol");
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+
+    let ix = build_init_dao_ix(
+        &admin.pubkey(),
+        &admin.pubkey(),
+        10_000_000,
+        &token_mint,
+        5,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("DAO init should succeed");
+
+    let ix = build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx)
+        .expect("Treasury init should succeed");
+    println!("[Setup] DAO and treasury initialized");
+
+    for (user, username) in [(&alice, "alice"), (&bob, "bob")] {
+        let ix = build_create_profile_ix(&user.pubkey(), username);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.pubkey()),
+            &[user],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Profile creation should succeed");
+    }
+    println!("[Setup] Profiles created: alice, bob");
+
+    println!("[Action] Bob tries to rename himself to 'alice'");
+    let ix = build_change_username_ix(&bob.pubkey(), "bob", "alice");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&bob.pubkey()),
+        &[&bob],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Claimed username should be rejected");
+    println!("[Verification] Taken username rejected");
+
+    println!("[Action] Bob tries to free Alice's registry entry");
+    let ix = build_change_username_ix(&bob.pubkey(), "alice", "mallory");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&bob.pubkey()),
+        &[&bob],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Registry of another user should not be freed");
+    let (alice_registry, _) = governance::username_registry_address("alice");
+    assert!(svm.get_account(&alice_registry).is_some(), "Alice's registry should remain");
+    println!("[Verification] Foreign registry rejected");
+
+    println!("[Action] Bob renames himself to 'robert'");
+    let ix = build_change_username_ix(&bob.pubkey(), "bob", "robert");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&bob.pubkey()),
+        &[&bob],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Username change should succeed");
+
+    let (bob_profile, _) = governance::user_profile_address(&bob.pubkey());
+    let (old_registry, _) = governance::username_registry_address("bob");
+    assert_eq!(get_username(&svm, &bob_profile), "robert");
+    assert!(
+        svm.get_account(&old_registry).map_or(true, |a| a.lamports == 0),
+        "Old registry should be closed"
+    );
+    println!("[Verification] Profile renamed and old registry closed");
+
+    let alice_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+        .owner(&alice.pubkey())
+        .send()
+        .expect("Failed to create user ATA");
+
+    MintTo::new(&mut svm, &admin, &token_mint, &alice_token_account, 100_000_000)
+        .owner(&admin)
+        .send()
+        .expect("Minting should succeed");
+
+    let ix = build_stake_tokens_ix(&alice.pubkey(), &admin.pubkey(), &token_mint, 20_000_000);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Staking should succeed");
+    println!("[Setup] Alice staked 20 tokens");
+
+    println!("[Action] Alice tries to close her profile while staked");
+    let ix = build_close_profile_ix(&alice.pubkey(), "alice");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Profile with stake should not close");
+    println!("[Verification] Close rejected while stake is held");
+
+    println!("[Action] Bob closes his unstaked profile");
+    let bob_balance_before = svm.get_account(&bob.pubkey()).unwrap().lamports;
+    let ix = build_close_profile_ix(&bob.pubkey(), "robert");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&bob.pubkey()),
+        &[&bob],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Profile close should succeed");
+
+    let bob_balance_after = svm.get_account(&bob.pubkey()).unwrap().lamports;
+    assert!(bob_balance_after > bob_balance_before, "Rent should be refunded");
+    assert!(
+        svm.get_account(&bob_profile).map_or(true, |a| a.lamports == 0),
+        "Profile should be closed"
+    );
+    println!("[Verification] Profile closed and rent refunded");
+
+    println!("[Action] Carol claims the released username 'robert'");
+    let ix = build_create_profile_ix(&carol.pubkey(), "robert");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&carol.pubkey()),
+        &[&carol],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Released username should be claimable");
+    println!("[Test] Released username re-registered by a new user");
+
+    println!("[TEST END] test_change_username_and_close_profile");
+}
+
+#[test]
+fn test_admin_two_step_transfer() {
+    println!("[TEST START] test_admin_two_step_transfer");
+    let mut svm = setup_svm();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let new_admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let user = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    println!("[Setup] Accounts funded: admin, new_admin, attacker, user");
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+
+    let ix = build_init_dao_ix(
+        &admin.pubkey(),
+        &admin.pubkey(),
+        10_000_000,
+        &token_mint,
+        5,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("DAO init should succeed");
+
+    let ix = build_create_profile_ix(&user.pubkey(), "member");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&user.pubkey()),
+        &[&user],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Profile creation should succeed");
+    println!("[Setup] DAO initialized and member profile created");
+
+    let (config, _) = governance::config_address(&admin.pubkey());
+
+    println!("[Action] Admin proposes new_admin");
+    let ix = build_propose_admin_ix(&admin.pubkey(), &admin.pubkey(), &new_admin.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Proposal should succeed");
+    assert_eq!(get_config_admin(&svm, &config), admin.pubkey(), "Admin should not change on propose");
+    println!("[Verification] Admin unchanged until acceptance");
+
+    println!("[Action] Attacker tries to accept the pending transfer");
+    let ix = build_accept_admin_ix(&attacker.pubkey(), &admin.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&attacker.pubkey()),
+        &[&attacker],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Only the pending admin can accept");
+    println!("[Verification] Attacker acceptance rejected");
+
+    println!("[Action] new_admin accepts the transfer");
+    let ix = build_accept_admin_ix(&new_admin.pubkey(), &admin.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&new_admin.pubkey()),
+        &[&new_admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Acceptance should succeed");
+    assert_eq!(get_config_admin(&svm, &config), new_admin.pubkey());
+    println!("[Verification] Admin role transferred");
+
+    println!("[Action] Previous admin tries to reset a member");
+    let ix = build_reset_user_reputation_ix(&admin.pubkey(), &admin.pubkey(), &user.pubkey(), 0);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Previous admin should lose admin rights");
+    println!("[Verification] Previous admin rejected");
+
+    println!("[Action] New admin resets the member");
+    let ix = build_reset_user_reputation_ix(&new_admin.pubkey(), &admin.pubkey(), &user.pubkey(), 0);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&new_admin.pubkey()),
+        &[&new_admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("New admin should hold admin rights");
+    println!("[Test] Admin rights follow the accepted transfer");
+
+    println!("[TEST END] test_admin_two_step_transfer");
+}
+
+#[test]
+fn test_vote_history_ring_buffer() {
+    println!("[TEST START] test_vote_history_ring_buffer");
+    let mut svm = setup_svm();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let voter = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let target = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    println!("[Setup] Accounts funded: admin, voter, target");
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+
+    let ix = build_init_dao_ix(
+        &admin.pubkey(),
+        &admin.pubkey(),
+        10_000_000,
+        &token_mint,
+        5,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("DAO init should succeed");
+
+    let ix = build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx)
+        .expect("Treasury init should succeed");
+    println!("[Setup] DAO and treasury initialized");
+
+    for (user, username) in [(&voter, "voter1"), (&target, "target1")] {
+        let ix = build_create_profile_ix(&user.pubkey(), username);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.pubkey()),
+            &[user],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Profile creation should succeed");
+    }
+
+    let voter_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+        .owner(&voter.pubkey())
+        .send()
+        .expect("Failed to create voter ATA");
+
+    MintTo::new(&mut svm, &admin, &token_mint, &voter_token_account, 100_000_000)
+        .owner(&admin)
+        .send()
+        .expect("Minting should succeed");
+
+    let ix = build_stake_tokens_ix(&voter.pubkey(), &admin.pubkey(), &token_mint, 20_000_000);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Staking should succeed");
+    println!("[Setup] Profiles created and voter staked 20 tokens");
+
+    let (vote_history, _) = governance::vote_history_address(&voter.pubkey());
+    let cast_upvote = |svm: &mut LiteSVM| {
+        // Member cooldown is 24 hours
+        advance_time(svm, 25 * 3600);
+        let ix = build_upvote_ix_with_target(
+            &voter.pubkey(),
+            &admin.pubkey(),
+            &target.pubkey(),
+            "target1",
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&voter.pubkey()),
+            &[&voter],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Upvote should succeed");
+    };
+
+    println!("[Action] Voter casts 5 votes");
+    for _ in 0..5 {
+        cast_upvote(&mut svm);
+    }
+
+    let (capacity, head, total, entries) = get_vote_history(&svm, &vote_history);
+    assert_eq!(capacity, 8, "History should grow past its initial capacity");
+    assert_eq!((head, total, entries), (5, 5, 5));
+    let history_len = svm.get_account(&vote_history).unwrap().data.len();
+    println!("[Verification] History grown to capacity {} ({} bytes)", capacity, history_len);
+
+    println!("[Action] Voter casts 31 more votes (36 total)");
+    for _ in 0..31 {
+        cast_upvote(&mut svm);
+    }
+
+    let (capacity, head, total, entries) = get_vote_history(&svm, &vote_history);
+    assert_eq!(capacity, 32, "History should stop growing at the cap");
+    assert_eq!(entries, 32, "Entry count bounded by capacity");
+    assert_eq!(total, 36, "Every vote recorded");
+    assert_eq!(head, 4, "Oldest entries overwritten in ring order");
+    let capped_len = svm.get_account(&vote_history).unwrap().data.len();
+    println!("[Test] Ring buffer wrapped at {} entries ({} bytes)", capacity, capped_len);
+
+    println!("[TEST END] test_vote_history_ring_buffer");
+}
+
+#[test]
+fn test_slash_stake_partial_and_full() {
+    println!("[TEST START] test_slash_stake_partial_and_full");
+    let mut svm = setup_svm();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let alice = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let bob = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    println!("[Setup] Accounts funded: admin, alice, bob");
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+
+    let ix = build_init_dao_ix(
+        &admin.pubkey(),
+        &admin.pubkey(),
+        10_000_000,
+        &token_mint,
+        5,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("DAO init should succeed");
+
+    let ix = build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx)
+        .expect("Treasury init should succeed");
+    println!("[Setup] DAO and treasury initialized");
+
+    for (user, username) in [(&alice, "alice"), (&bob, "bob")] {
+        let ix = build_create_profile_ix(&user.pubkey(), username);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.pubkey()),
+            &[user],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Profile creation should succeed");
+
+        let user_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+            .owner(&user.pubkey())
+            .send()
+            .expect("Failed to create user ATA");
+
+        MintTo::new(&mut svm, &admin, &token_mint, &user_token_account, 100_000_000)
+            .owner(&admin)
+            .send()
+            .expect("Minting should succeed");
+
+        let ix = build_stake_tokens_ix(&user.pubkey(), &admin.pubkey(), &token_mint, 20_000_000);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.pubkey()),
+            &[user],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Staking should succeed");
+    }
+
+    let ix = build_delegate_votes_ix(&alice.pubkey(), &admin.pubkey(), &bob.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Delegation should succeed");
+    println!("[Setup] Alice and Bob each staked 20 tokens, Alice delegated to Bob");
+
+    let treasury_token_account = governance::treasury_token_address(&admin.pubkey(), &token_mint);
+    let (alice_profile, _) = governance::user_profile_address(&alice.pubkey());
+    let (bob_profile, _) = governance::user_profile_address(&bob.pubkey());
+
+    println!("[Action] Bob tries to slash Alice");
+    let ix = build_slash_stake_ix(
+        &bob.pubkey(),
+        &admin.pubkey(),
+        &token_mint,
+        &alice.pubkey(),
+        10_000,
+        true,
+        Some(&bob.pubkey()),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&bob.pubkey()),
+        &[&bob],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Only the admin can slash");
+    println!("[Verification] Non-admin slash rejected");
+
+    println!("[Action] Admin slashes Alice without the delegation accounts");
+    let ix = build_slash_stake_ix(
+        &admin.pubkey(),
+        &admin.pubkey(),
+        &token_mint,
+        &alice.pubkey(),
+        2_500,
+        false,
+        None,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Delegated stake must be slashed with its delegation");
+    println!("[Verification] Slash without delegation accounts rejected");
+
+    println!("[Action] Admin slashes 25% of Alice's stake into the treasury");
+    let ix = build_slash_stake_ix(
+        &admin.pubkey(),
+        &admin.pubkey(),
+        &token_mint,
+        &alice.pubkey(),
+        2_500,
+        false,
+        Some(&bob.pubkey()),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Partial slash should succeed");
+
+    assert_eq!(get_stake_amount(&svm, &alice_profile), 15_000_000);
+    let balance = get_spl_account::<spl_token::state::Account>(&svm, &treasury_token_account)
+        .unwrap()
+        .amount;
+    assert_eq!(balance, 40_000_000, "Redirected tokens stay in the treasury");
+    println!("[Verification] Alice stake 20 -> 15 tokens, treasury keeps slashed tokens");
+
+    println!("[Action] Admin burns all of Bob's stake");
+    let ix = build_slash_stake_ix(
+        &admin.pubkey(),
+        &admin.pubkey(),
+        &token_mint,
+        &bob.pubkey(),
+        10_000,
+        true,
+        None,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Full slash should succeed");
+
+    assert_eq!(get_stake_amount(&svm, &bob_profile), 0);
+    let balance = get_spl_account::<spl_token::state::Account>(&svm, &treasury_token_account)
+        .unwrap()
+        .amount;
+    assert_eq!(balance, 20_000_000, "Burned tokens leave the treasury");
+    println!("[Verification] Bob stake 20 -> 0 tokens, slashed tokens burned");
+
+    println!("[Action] Alice revokes her trimmed delegation");
+    let ix = build_revoke_delegation_ix(&alice.pubkey(), &bob.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Revoke should succeed after slash");
+    println!("[Test] Delegation accounting stayed consistent through the slash");
+
+    println!("[TEST END] test_slash_stake_partial_and_full");
+}
+
+#[test]
+fn test_season_snapshot_rewards() {
+    println!("[TEST START] test_season_snapshot_rewards");
+    let mut svm = setup_svm();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let voter = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let alice = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let bob = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    println!("[Setup] Accounts funded: admin, voter, alice, bob");
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+
+    let ix = build_init_dao_ix(
+        &admin.pubkey(),
+        &admin.pubkey(),
+        10_000_000,
+        &token_mint,
+        5,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("DAO init should succeed");
+
+    let ix = build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx)
+        .expect("Treasury init should succeed");
+    println!("[Setup] DAO and treasury initialized");
+
+    for (user, username) in [(&voter, "voter1"), (&alice, "alice"), (&bob, "bob")] {
+        let ix = build_create_profile_ix(&user.pubkey(), username);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.pubkey()),
+            &[user],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Profile creation should succeed");
+    }
+
+    let voter_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+        .owner(&voter.pubkey())
+        .send()
+        .expect("Failed to create voter ATA");
+    let alice_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+        .owner(&alice.pubkey())
+        .send()
+        .expect("Failed to create alice ATA");
+
+    MintTo::new(&mut svm, &admin, &token_mint, &voter_token_account, 100_000_000)
+        .owner(&admin)
+        .send()
+        .expect("Minting should succeed");
+
+    let ix = build_stake_tokens_ix(&voter.pubkey(), &admin.pubkey(), &token_mint, 20_000_000);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Staking should succeed");
+    println!("[Setup] Profiles created and voter staked 20 tokens");
+
+    // Alice receives two upvotes, bob one (Member cooldown is 24 hours)
+    for (target, username) in [(&alice, "alice"), (&alice, "alice"), (&bob, "bob")] {
+        advance_time(&mut svm, 25 * 3600);
+        let ix = build_upvote_ix_with_target(
+            &voter.pubkey(),
+            &admin.pubkey(),
+            &target.pubkey(),
+            username,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&voter.pubkey()),
+            &[&voter],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Upvote should succeed");
+    }
+
+    let (alice_profile, _) = governance::user_profile_address(&alice.pubkey());
+    let (bob_profile, _) = governance::user_profile_address(&bob.pubkey());
+    let alice_rep = get_reputation(&svm, &alice_profile);
+    let bob_rep = get_reputation(&svm, &bob_profile);
+    assert!(alice_rep > bob_rep && bob_rep > 0);
+    println!("[Setup] Season reputation: alice {}, bob {}", alice_rep, bob_rep);
+
+    println!("[Action] Admin resets alice and bob for season 0");
+    for user in [&alice, &bob] {
+        let ix = build_reset_user_reputation_ix(&admin.pubkey(), &admin.pubkey(), &user.pubkey(), 0);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&admin.pubkey()),
+            &[&admin],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Reset should succeed");
+    }
+    assert_eq!(get_reputation(&svm, &alice_profile), 0);
+    println!("[Verification] Reputation cleared");
+
+    println!("[Action] Alice claims before the season is closed");
+    let claim_ix = build_claim_season_reward_ix(&alice.pubkey(), &admin.pubkey(), &token_mint, 0);
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix.clone()],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Open season must not pay out");
+    println!("[Verification] Claim rejected - season still open");
+
+    let (config, _) = governance::config_address(&admin.pubkey());
+    let treasury_token_account = governance::treasury_token_address(&admin.pubkey(), &token_mint);
+    let reward_pool = 3_000_000;
+    MintTo::new(&mut svm, &admin, &token_mint, &treasury_token_account, reward_pool)
+        .owner(&admin)
+        .send()
+        .expect("Funding reward pool should succeed");
+
+    let ix = build_close_season_ix(&admin.pubkey(), &admin.pubkey(), 0, reward_pool);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Closing the season should succeed");
+
+    let (snapshot, _) = governance::season_snapshot_address(&config, 0);
+    let (season_id, total_staked, pool, finalized, entries) = get_season_snapshot(&svm, &snapshot);
+    assert_eq!((season_id, total_staked, pool, finalized, entries), (0, 20_000_000, reward_pool, true, 2));
+    println!("[Verification] Season 0 closed with 2 ranked users and {} staked", total_staked);
+
+    println!("[Action] Alice claims her share");
+    svm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix.clone()],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Claim should succeed after the season closes");
+
+    let expected = reward_pool * alice_rep as u64 / (alice_rep + bob_rep) as u64;
+    let balance = get_spl_account::<spl_token::state::Account>(&svm, &alice_token_account)
+        .unwrap()
+        .amount;
+    assert_eq!(balance, expected, "Share should be proportional to reputation");
+    println!("[Verification] Alice received {} of {}", balance, reward_pool);
+
+    println!("[Action] Alice claims again");
+    svm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Second claim must be rejected");
+    println!("[Verification] Double claim rejected");
+
+    println!("[Action] Voter (not ranked) claims");
+    let ix = build_claim_season_reward_ix(&voter.pubkey(), &admin.pubkey(), &token_mint, 0);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Users outside the standings must be rejected");
+    let balance = get_spl_account::<spl_token::state::Account>(&svm, &alice_token_account)
+        .unwrap()
+        .amount;
+    assert_eq!(balance, expected);
+    println!("[Test] Only ranked users claim, once each");
+
+    println!("[TEST END] test_season_snapshot_rewards");
+}
+
+#[test]
+fn test_multi_mint_weighted_staking() {
+    println!("[TEST START] test_multi_mint_weighted_staking");
+    let mut svm = setup_svm();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let alice = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    println!("[Setup] Accounts funded: admin, alice");
+
+    let mut mints = Vec::new();
+    for _ in 0..3 {
+        let mint = CreateMint::new(&mut svm, &admin)
+            .authority(&admin.pubkey())
+            .decimals(DECIMALS)
+            .send()
+            .expect("Mint creation should succeed");
+        mints.push(mint);
+    }
+    let (token_mint, lp_mint, unlisted_mint) = (mints[0], mints[1], mints[2]);
+
+    let ix = build_init_dao_ix(
+        &admin.pubkey(),
+        &admin.pubkey(),
+        10_000_000,
+        &token_mint,
+        5,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("DAO init should succeed");
+
+    let ix = build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx)
+        .expect("Treasury init should succeed");
+    println!("[Setup] DAO and treasury initialized");
+
+    println!("[Action] Alice tries to register the LP mint");
+    let ix = build_add_supported_mint_ix(&alice.pubkey(), &admin.pubkey(), &lp_mint, 50_000);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Only the admin can register mints");
+    println!("[Verification] Non-admin registration rejected");
+
+    println!("[Action] Admin tries to register the base mint and out-of-range weights");
+    for (mint, weight_bps) in [(token_mint, 20_000), (lp_mint, 0), (lp_mint, 50_001)] {
+        let ix = build_add_supported_mint_ix(&admin.pubkey(), &admin.pubkey(), &mint, weight_bps);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&admin.pubkey()),
+            &[&admin],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err(), "Base mint and out-of-range weights must be rejected");
+    }
+    println!("[Verification] Base mint and invalid weights rejected");
+
+    println!("[Action] Admin registers the LP mint at 2x");
+    let ix = build_add_supported_mint_ix(&admin.pubkey(), &admin.pubkey(), &lp_mint, 20_000);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Admin registration should succeed");
+
+    let ix = build_create_profile_ix(&alice.pubkey(), "alice");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx)
+        .expect("Profile creation should succeed");
+
+    for mint in [token_mint, lp_mint, unlisted_mint] {
+        let user_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &mint)
+            .owner(&alice.pubkey())
+            .send()
+            .expect("Failed to create user ATA");
+
+        MintTo::new(&mut svm, &admin, &mint, &user_token_account, 100_000_000)
+            .owner(&admin)
+            .send()
+            .expect("Minting should succeed");
+    }
+    println!("[Setup] Alice holds 100 tokens of each mint");
+
+    println!("[Action] Alice stakes 10 base tokens and 10 LP tokens");
+    let ix = build_stake_tokens_ix(&alice.pubkey(), &admin.pubkey(), &token_mint, 10_000_000);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Base stake should succeed");
+
+    let ix = build_stake_supported_mint_ix(&alice.pubkey(), &admin.pubkey(), &lp_mint, 10_000_000);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Supported-mint stake should succeed");
+
+    let (config, _) = governance::config_address(&admin.pubkey());
+    let (alice_profile, _) = governance::user_profile_address(&alice.pubkey());
+    let (supported_mint, _) = governance::supported_mint_address(&config, &lp_mint);
+    let (mint_stake, _) = governance::mint_stake_address(&config, &lp_mint, &alice.pubkey());
+
+    assert_eq!(get_stake_amount(&svm, &alice_profile), 10_000_000);
+    assert_eq!(get_weighted_stake(&svm, &alice_profile), 20_000_000);
+    assert_eq!(get_supported_mint(&svm, &supported_mint), (20_000, 10_000_000));
+    assert_eq!(get_mint_stake(&svm, &mint_stake), (10_000_000, 20_000_000));
+
+    let lp_treasury = governance::treasury_token_address(&admin.pubkey(), &lp_mint);
+    let balance = get_spl_account::<spl_token::state::Account>(&svm, &lp_treasury)
+        .unwrap()
+        .amount;
+    assert_eq!(balance, 10_000_000, "LP tokens are held in their own treasury ATA");
+    println!("[Verification] Base stake 10, LP stake 10 at 2x -> weighted stake 20");
+
+    println!("[Action] Alice tries to stake the LP mint as the base mint");
+    let ix = build_stake_tokens_ix(&alice.pubkey(), &admin.pubkey(), &lp_mint, 10_000_000);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Non-base mints need their registry entry");
+
+    println!("[Action] Alice tries to stake an unregistered mint");
+    let ix = build_stake_supported_mint_ix(
+        &alice.pubkey(),
+        &admin.pubkey(),
+        &unlisted_mint,
+        10_000_000,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Unregistered mints cannot be staked");
+    println!("[Verification] Wrong-path and unregistered stakes rejected");
+
+    advance_time(&mut svm, 60);
+
+    let ix = build_create_governance_proposal_ix(
+        &alice.pubkey(),
+        &admin.pubkey(),
+        &token_mint,
+        0,
+        "List LP tokens",
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Proposal creation should succeed");
+
+    println!("[Action] Alice casts a ballot with her weighted power");
+    let ix = build_cast_ballot_ix(&alice.pubkey(), &admin.pubkey(), 0, true);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Ballot should succeed");
+
+    let (proposal, _) = governance::proposal_address(&config, 0);
+    let (votes_for, _, _) = get_proposal_tally(&svm, &proposal);
+    assert_eq!(votes_for, 30_000_000, "Ballot weight is 10 base + 2x10 LP");
+    println!("[Test] Ballot counted {} (weighted sum of both mints)", votes_for);
+
+    println!("[TEST END] test_multi_mint_weighted_staking");
+}
+
+#[test]
+fn test_multisig_action_via_cpi() {
+    println!("[TEST START] test_multisig_action_via_cpi");
+    let mut svm = setup_svm_with_multisig();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let voter = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+
+    for ix in [
+        build_init_dao_ix(&admin.pubkey(), &admin.pubkey(), 10_000_000, &token_mint, 5),
+        build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint),
+    ] {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&admin.pubkey()),
+            &[&admin],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("DAO setup should succeed");
+    }
+
+    let ix = build_create_profile_ix(&voter.pubkey(), "voter1");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Profile creation should succeed");
+
+    let voter_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+        .owner(&voter.pubkey())
+        .send()
+        .expect("Failed to create voter ATA");
+    MintTo::new(&mut svm, &admin, &token_mint, &voter_token_account, 100_000_000)
+        .owner(&admin)
+        .send()
+        .expect("Minting should succeed");
+
+    let ix = build_stake_tokens_ix(&voter.pubkey(), &admin.pubkey(), &token_mint, 20_000_000);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Staking should succeed");
+    println!("[Setup] DAO initialized, voter staked 20 tokens");
+
+    // Multisig with the admin as sole member, threshold 1 and no timelock
+    let (config, _) = governance::config_address(&admin.pubkey());
+    let (executor, _) = governance::multisig_executor_address(&config);
+    let (multisig_pda, _) = multisig::multisig_address(&admin.pubkey(), 0);
+    let (vault, _) = multisig::vault_address(&multisig_pda);
+    let (add_member_proposal, _) = multisig::proposal_address(&multisig_pda, 0);
+
+    for ix in [
+        multisig_ix::create_multisig(&admin.pubkey(), &multisig_pda, &vault, 0, 1, 0),
+        multisig_ix::create_proposal(
+            &admin.pubkey(),
+            &multisig_pda,
+            &add_member_proposal,
+            multisig::ProposalType::AddMember {
+                new_member: executor,
+                role: multisig::MemberRole::Proposer,
+            },
+        ),
+        multisig_ix::execute_proposal(
+            &admin.pubkey(),
+            &multisig_pda,
+            &add_member_proposal,
+            &admin.pubkey(),
+        ),
+    ] {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&admin.pubkey()),
+            &[&admin],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Multisig setup should succeed");
+    }
+    println!("[Setup] Multisig created, DAO executor {} added as Proposer", executor);
+
+    advance_time(&mut svm, 60);
+
+    // Proposal 0 carries the action; attached in the same transaction
+    let action = multisig::ProposalType::ChangeThreshold { new_threshold: 2 };
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            build_create_governance_proposal_ix(
+                &voter.pubkey(),
+                &admin.pubkey(),
+                &token_mint,
+                0,
+                "Require both multisig members",
+            ),
+            build_attach_multisig_action_ix(
+                &voter.pubkey(),
+                &admin.pubkey(),
+                0,
+                &multisig_pda,
+                action,
+            ),
+        ],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Proposal with action should succeed");
+    println!("[Setup] Proposal 0 created with a ChangeThreshold action");
+
+    println!("[Action] Executing the action while the proposal is still active");
+    let execute_ix = build_execute_multisig_action_ix(
+        &voter.pubkey(),
+        &admin.pubkey(),
+        0,
+        &multisig_pda,
+        1,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[execute_ix.clone()],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Active proposals cannot trigger the multisig");
+    println!("[Verification] Unpassed proposal rejected");
+
+    let ix = build_cast_ballot_ix(&voter.pubkey(), &admin.pubkey(), 0, true);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Ballot should succeed");
+
+    println!("[Action] Attaching an action to a proposal that already has ballots");
+    let ix = build_create_governance_proposal_ix(
+        &voter.pubkey(),
+        &admin.pubkey(),
+        &token_mint,
+        1,
+        "Title-only proposal",
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix, build_cast_ballot_ix(&voter.pubkey(), &admin.pubkey(), 1, true)],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Proposal 1 and its ballot should succeed");
+
+    let ix = build_attach_multisig_action_ix(
+        &voter.pubkey(),
+        &admin.pubkey(),
+        1,
+        &multisig_pda,
+        action,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Actions cannot be attached once voting has started");
+    println!("[Verification] Late attachment rejected");
+
+    advance_time(&mut svm, PROPOSAL_VOTING_PERIOD_SECONDS + 1);
+
+    let ix = build_finalize_proposal_ix(&admin.pubkey(), &admin.pubkey(), &token_mint, 0, &voter.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Finalize should succeed");
+    let (proposal, _) = governance::proposal_address(&config, 0);
+    let (_, _, status) = get_proposal_tally(&svm, &proposal);
+    assert_eq!(status, 1, "Proposal should pass");
+    println!("[Setup] Proposal 0 passed");
+
+    println!("[Action] Executing with a substitute multisig program");
+    let mut spoofed_ix = execute_ix.clone();
+    spoofed_ix.accounts[8].pubkey = GOVERNANCE_PROGRAM_ID;
+    let tx = Transaction::new_signed_with_payer(
+        &[spoofed_ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Executor signature must only reach the multisig program");
+    println!("[Verification] Substitute program rejected");
+
+    println!("[Action] Executing the passed proposal's action");
+    let tx = Transaction::new_signed_with_payer(
+        &[execute_ix.clone()],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Passed proposal should reach the multisig");
+
+    let (multisig_proposal, _) = multisig::proposal_address(&multisig_pda, 1);
+    let account = svm.get_account(&multisig_proposal).expect("Multisig proposal should exist");
+    let relayed = multisig::Proposal::try_from_bytes(&account.data)
+        .expect("Multisig proposal should decode");
+    assert_eq!(relayed.proposer, executor, "Executor PDA is the proposer");
+    assert_eq!(relayed.proposal_type, action, "Relayed action is the one voted on");
+    assert_eq!(relayed.approval_count, 1, "Executor auto-approves like any proposer");
+    assert_eq!(
+        account.data.len(),
+        multisig::PROPOSAL_SPACE,
+        "Executor funded for the proposal size the multisig creates"
+    );
+    assert_eq!(
+        svm.get_balance(&executor).unwrap_or(0),
+        0,
+        "Rent passes straight through the executor"
+    );
+
+    let (multisig_action, _) = governance::multisig_action_address(&proposal);
+    assert!(get_multisig_action(&svm, &multisig_action).executed);
+    println!("[Test] Multisig proposal 1 opened by the DAO executor");
+
+    println!("[Action] Executing the same action again");
+    svm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[execute_ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "An action is relayed only once");
+    println!("[Verification] Second execution rejected");
+
+    println!("[Action] Multisig admin executes the relayed proposal");
+    let ix = multisig_ix::execute_proposal(
+        &admin.pubkey(),
+        &multisig_pda,
+        &multisig_proposal,
+        &executor,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Multisig execution should succeed");
+
+    let account = svm.get_account(&multisig_pda).expect("Multisig should exist");
+    let multisig_account = multisig::Multisig::try_from_bytes(&account.data)
+        .expect("Multisig should decode");
+    assert_eq!(multisig_account.threshold, 2, "DAO vote changed the multisig threshold");
+    assert!(
+        svm.get_balance(&executor).unwrap_or(0) > 0,
+        "Proposal rent refunded to the executor"
+    );
+    println!("[Test] Multisig threshold is now {}", multisig_account.threshold);
+
+    println!("[TEST END] test_multisig_action_via_cpi");
+}
+
+#[test]
+fn test_vote_batch_settlement() {
+    println!("[TEST START] test_vote_batch_settlement");
+    let mut svm = setup_svm();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let alice = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let bob = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let relayer = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+
+    for ix in [
+        build_init_dao_ix(&admin.pubkey(), &admin.pubkey(), 10_000_000, &token_mint, 5),
+        build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint),
+    ] {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&admin.pubkey()),
+            &[&admin],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("DAO setup should succeed");
+    }
+
+    for (user, username, stake) in [(&alice, "alice", 30_000_000), (&bob, "bobby", 20_000_000)] {
+        let ix = build_create_profile_ix(&user.pubkey(), username);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.pubkey()),
+            &[user],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Profile creation should succeed");
+
+        let user_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+            .owner(&user.pubkey())
+            .send()
+            .expect("Failed to create user ATA");
+        MintTo::new(&mut svm, &admin, &token_mint, &user_token_account, 100_000_000)
+            .owner(&admin)
+            .send()
+            .expect("Minting should succeed");
+
+        let ix = build_stake_tokens_ix(&user.pubkey(), &admin.pubkey(), &token_mint, stake);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.pubkey()),
+            &[user],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Staking should succeed");
+    }
+    println!("[Setup] Alice staked 30 tokens, Bob staked 20; the relayer holds no stake");
+
+    advance_time(&mut svm, 60);
+
+    let ix = build_create_governance_proposal_ix(&alice.pubkey(), &admin.pubkey(), &token_mint, 0, "Fund audits");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Proposal creation should succeed");
+
+    let (config, _) = governance::config_address(&admin.pubkey());
+    let (proposal, _) = governance::proposal_address(&config, 0);
+    let alice_vote = governance_ix::VoteBatchEntry {
+        voter: alice.pubkey(),
+        support: true,
+        weight: 30_000_000,
+    };
+    let bob_vote = governance_ix::VoteBatchEntry {
+        voter: bob.pubkey(),
+        support: false,
+        weight: 20_000_000,
+    };
+    println!("[Setup] Proposal 0 created; Alice signs a vote for, Bob a vote against");
+
+    let send_batch = |svm: &mut LiteSVM, instructions: &[solana_sdk::instruction::Instruction]| {
+        svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&relayer.pubkey()),
+            &[&relayer],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+    };
+
+    println!("[Action] Relaying a batch without its Ed25519 instruction");
+    let batch_ix = build_submit_vote_batch_ix(&relayer.pubkey(), &admin.pubkey(), 0, &[alice_vote]);
+    let result = send_batch(&mut svm, &[batch_ix.clone()]);
+    assert!(result.is_err(), "Entry without a signature instruction should be rejected");
+    println!("[Verification] Unsigned entry rejected");
+
+    println!("[Action] Relaying Alice's vote signed by the relayer's key");
+    let message = alice_vote.vote_message(&proposal);
+    let relayer_signed_ix = governance_ix::ed25519_verify(
+        &relayer.pubkey(),
+        &relayer.sign_message(&message),
+        &message,
+    );
+    let result = send_batch(&mut svm, &[relayer_signed_ix.clone(), batch_ix.clone()]);
+    assert!(result.is_err(), "Signature from another key should be rejected");
+    println!("[Verification] Wrong signer rejected");
+
+    println!("[Action] Relaying Alice's vote with the weight raised after signing");
+    let inflated_vote = governance_ix::VoteBatchEntry { weight: 40_000_000, ..alice_vote };
+    let result = send_batch(
+        &mut svm,
+        &[
+            build_signed_vote_ix(&alice, &proposal, &alice_vote),
+            build_submit_vote_batch_ix(&relayer.pubkey(), &admin.pubkey(), 0, &[inflated_vote]),
+        ],
+    );
+    assert!(result.is_err(), "Entry differing from the signed message should be rejected");
+    println!("[Verification] Altered entry rejected");
+
+    println!("[Action] Alice signs a weight above her stake");
+    let result = send_batch(
+        &mut svm,
+        &[
+            build_signed_vote_ix(&alice, &proposal, &inflated_vote),
+            build_submit_vote_batch_ix(&relayer.pubkey(), &admin.pubkey(), 0, &[inflated_vote]),
+        ],
+    );
+    assert!(result.is_err(), "Weight above effective power should be rejected");
+    println!("[Verification] Unbacked weight rejected");
+
+    // The precompile verifies the relayer's signature in instruction 0, while
+    // instruction 1 carries Alice's key and message at the same offsets
+    println!("[Action] Ed25519 instruction whose indices point at another instruction");
+    let mut forged_ix = governance_ix::ed25519_verify(
+        &alice.pubkey(),
+        &solana_sdk::signature::Signature::default(),
+        &message,
+    );
+    for index in [4, 8, 14] {
+        forged_ix.data[index..index + 2].copy_from_slice(&0u16.to_le_bytes());
+    }
+    let result = send_batch(&mut svm, &[relayer_signed_ix, forged_ix, batch_ix]);
+    assert!(result.is_err(), "Signature data from another instruction should be rejected");
+    println!("[Verification] Cross-instruction signature data rejected");
+
+    let (votes_for, votes_against, _) = get_proposal_tally(&svm, &proposal);
+    assert_eq!((votes_for, votes_against), (0, 0), "No rejected batch should count");
+
+    println!("[Action] Relaying both signed votes in one batch");
+    let batch = [
+        build_signed_vote_ix(&alice, &proposal, &alice_vote),
+        build_signed_vote_ix(&bob, &proposal, &bob_vote),
+        build_submit_vote_batch_ix(&relayer.pubkey(), &admin.pubkey(), 0, &[alice_vote, bob_vote]),
+    ];
+    send_batch(&mut svm, &batch).expect("Signed batch should settle");
+
+    let (votes_for, votes_against, _) = get_proposal_tally(&svm, &proposal);
+    assert_eq!(votes_for, 30_000_000);
+    assert_eq!(votes_against, 20_000_000);
+    let (alice_ballot, _) = governance::ballot_address(&proposal, &alice.pubkey());
+    assert!(svm.get_account(&alice_ballot).is_some(), "Ballot should be recorded");
+    println!("[Verification] Tally {} for, {} against; ballots recorded", votes_for, votes_against);
+
+    println!("[Action] Replaying the batch and casting Alice's ballot directly");
+    let result = send_batch(&mut svm, &batch);
+    assert!(result.is_err(), "Replayed batch should be rejected");
+    svm.expire_blockhash();
+    let ix = build_cast_ballot_ix(&alice.pubkey(), &admin.pubkey(), 0, true);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&alice.pubkey()),
+        &[&alice],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "A relayed vote should block a second ballot");
+
+    let (votes_for, votes_against, _) = get_proposal_tally(&svm, &proposal);
+    assert_eq!((votes_for, votes_against), (30_000_000, 20_000_000));
+    println!("[Verification] Each voter counted once across batches and cast_ballot");
+
+    println!("[TEST END] test_vote_batch_settlement");
+}
+
+#[test]
+fn test_stake_age_voting_power() {
+    println!("[TEST START] test_stake_age_voting_power");
+    let mut svm = setup_svm();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let holder = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let newcomer = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+
+    // Full voting power after 4 days of stake age
+    let ramp_seconds: u64 = 4 * 24 * 3600;
+    for ix in [
+        build_init_dao_with_ramp_ix(
+            &admin.pubkey(),
+            &admin.pubkey(),
+            10_000_000,
+            &token_mint,
+            5,
+            ramp_seconds as i64,
+        ),
+        build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint),
+    ] {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&admin.pubkey()),
+            &[&admin],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("DAO setup should succeed");
+    }
+    println!("[Setup] DAO initialized with a 4 day stake age ramp");
+
+    for (user, username) in [(&holder, "holder"), (&newcomer, "newcomer")] {
+        let ix = build_create_profile_ix(&user.pubkey(), username);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.pubkey()),
+            &[user],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Profile creation should succeed");
+
+        let user_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+            .owner(&user.pubkey())
+            .send()
+            .expect("Failed to create user ATA");
+        MintTo::new(&mut svm, &admin, &token_mint, &user_token_account, 100_000_000)
+            .owner(&admin)
+            .send()
+            .expect("Minting should succeed");
+    }
+
+    let stake = |svm: &mut LiteSVM, user: &Keypair, amount: u64| {
+        let ix = build_stake_tokens_ix(&user.pubkey(), &admin.pubkey(), &token_mint, amount);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.pubkey()),
+            &[user],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Staking should succeed");
+    };
+    let propose = |svm: &mut LiteSVM, proposal_id: u64, title: &str| {
+        let ix = build_create_governance_proposal_ix(&holder.pubkey(), &admin.pubkey(), &token_mint, proposal_id, title);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&holder.pubkey()),
+            &[&holder],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Proposal creation should succeed");
+    };
+    let ballot = |svm: &mut LiteSVM, voter: &Keypair, proposal_id: u64, support: bool| {
+        let ix = build_cast_ballot_ix(&voter.pubkey(), &admin.pubkey(), proposal_id, support);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&voter.pubkey()),
+            &[voter],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+    };
+
+    stake(&mut svm, &holder, 20_000_000);
+    advance_time(&mut svm, ramp_seconds);
+    println!("[Setup] Holder staked 20 tokens and held them for the full ramp");
+
+    stake(&mut svm, &newcomer, 100_000_000);
+    let (newcomer_profile, _) = governance::user_profile_address(&newcomer.pubkey());
+    let clock: solana_sdk::clock::Clock = svm.get_sysvar();
+    assert_eq!(get_stake_start_ts(&svm, &newcomer_profile), clock.unix_timestamp);
+    println!("[Setup] Newcomer staked 100 tokens; stake clock starts now");
+
+    advance_time(&mut svm, 3600);
+    propose(&mut svm, 0, "Fund community grants");
+    println!("[Setup] Proposal 0 created one hour after the newcomer staked");
+
+    println!("[Action] Newcomer votes with one hour of stake age");
+    let result = ballot(&mut svm, &newcomer, 0, false);
+    assert!(result.is_err(), "One hour of age is below the minimum stake in power");
+    ballot(&mut svm, &holder, 0, true).expect("Matured holder should vote");
+
+    let (config, _) = governance::config_address(&admin.pubkey());
+    let (proposal, _) = governance::proposal_address(&config, 0);
+    let (votes_for, votes_against, _) = get_proposal_tally(&svm, &proposal);
+    assert_eq!((votes_for, votes_against), (20_000_000, 0));
+    println!("[Verification] Flash stake cannot swing proposal 0: {} for, {} against", votes_for, votes_against);
+
+    advance_time(&mut svm, 24 * 3600 - 3600);
+    propose(&mut svm, 1, "Fund audits");
+    println!("[Setup] Proposal 1 created one day after the newcomer staked");
+
+    println!("[Action] Newcomer votes with a quarter of the ramp matured");
+    ballot(&mut svm, &newcomer, 1, false).expect("Partly matured stake should vote");
+
+    let (proposal, _) = governance::proposal_address(&config, 1);
+    let (_, votes_against, _) = get_proposal_tally(&svm, &proposal);
+    assert_eq!(votes_against, 25_000_000, "A quarter of the ramp counts a quarter of the stake");
+    println!("[Verification] Newcomer counted {} of 100 tokens staked", votes_against);
+
+    println!("[TEST END] test_stake_age_voting_power");
+}
+
+#[test]
+fn test_close_vote_accounts_after_season() {
+    println!("[TEST START] test_close_vote_accounts_after_season");
+    let mut svm = setup_svm();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let voter = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let alice = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+
+    let ix = build_init_dao_ix(&admin.pubkey(), &admin.pubkey(), 10_000_000, &token_mint, 5);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("DAO init should succeed");
+
+    let ix = build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Treasury init should succeed");
+
+    for (user, username) in [(&voter, "voter1"), (&alice, "alice")] {
+        let ix = build_create_profile_ix(&user.pubkey(), username);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.pubkey()),
+            &[user],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Profile creation should succeed");
+    }
+
+    let voter_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+        .owner(&voter.pubkey())
+        .send()
+        .expect("Failed to create voter ATA");
+    MintTo::new(&mut svm, &admin, &token_mint, &voter_token_account, 20_000_000)
+        .owner(&admin)
+        .send()
+        .expect("Minting should succeed");
+
+    let ix = build_stake_tokens_ix(&voter.pubkey(), &admin.pubkey(), &token_mint, 20_000_000);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Staking should succeed");
+    println!("[Setup] DAO initialized, voter staked 20 tokens");
+
+    let upvote_ix =
+        build_upvote_ix_with_target(&voter.pubkey(), &admin.pubkey(), &alice.pubkey(), "alice");
+    advance_time(&mut svm, 25 * 3600);
+    let tx = Transaction::new_signed_with_payer(
+        &[upvote_ix.clone()],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Upvote should succeed");
+
+    let (alice_profile, _) = governance::user_profile_address(&alice.pubkey());
+    let (vote_record, _) = governance::vote_record_address(&voter.pubkey(), "alice");
+    let (vote_cooldown, _) = governance::vote_cooldown_address(&voter.pubkey());
+    let first_rep = get_reputation(&svm, &alice_profile);
+    assert!(first_rep > 0);
+    assert_eq!(get_vote_record_season(&svm, &vote_record), 0);
+    println!("[Setup] Voter upvoted alice in season 0 (reputation {})", first_rep);
+
+    let close_record_ix = build_close_vote_record_ix(&voter.pubkey(), &admin.pubkey(), "alice");
+    let close_cooldown_ix = build_close_cooldown_ix(&voter.pubkey(), &admin.pubkey());
+
+    println!("[Action] Voter closes the record and cooldown while season 0 is open");
+    for ix in [&close_record_ix, &close_cooldown_ix] {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix.clone()],
+            Some(&voter.pubkey()),
+            &[&voter],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err(), "Open season accounts must stay");
+    }
+    println!("[Verification] Both closes rejected - season still open");
+
+    let ix = build_close_season_ix(&admin.pubkey(), &admin.pubkey(), 0, 0);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Closing the season should succeed");
+    println!("[Setup] Season 0 closed, season 1 open");
+
+    println!("[Action] Voter closes the cooldown minutes after their last vote");
+    svm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[close_cooldown_ix.clone()],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Closing must not cut a running cooldown short");
+    println!("[Verification] Rejected - cooldown still running");
+
+    println!("[Action] Voter closes both accounts after the cooldown");
+    advance_time(&mut svm, 25 * 3600);
+    let record_rent = svm.get_account(&vote_record).unwrap().lamports;
+    let cooldown_rent = svm.get_account(&vote_cooldown).unwrap().lamports;
+    let balance_before = svm.get_balance(&voter.pubkey()).unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[close_record_ix, close_cooldown_ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Closing season 0 accounts should succeed");
+
+    assert!(svm.get_account(&vote_record).map_or(true, |account| account.lamports == 0));
+    assert!(svm.get_account(&vote_cooldown).map_or(true, |account| account.lamports == 0));
+    let refunded = svm.get_balance(&voter.pubkey()).unwrap() + 5_000 - balance_before;
+    assert_eq!(refunded, record_rent + cooldown_rent);
+    println!("[Verification] {} lamports of rent refunded to the voter", refunded);
+
+    println!("[Action] Voter upvotes alice again in season 1");
+    svm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[upvote_ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("New season vote should succeed");
+    assert_eq!(get_reputation(&svm, &alice_profile), first_rep * 2);
+    assert_eq!(get_vote_record_season(&svm, &vote_record), 1);
+    println!("[Test] Season 1 vote counted on its own, record recreated for season 1");
+
+    println!("[TEST END] test_close_vote_accounts_after_season");
+}
+
+#[test]
+fn test_emergency_council() {
+    println!("[TEST START] test_emergency_council");
+    let mut svm = setup_svm();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let voter = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let members: Vec<Keypair> = (0..3)
+        .map(|_| create_funded_account(&mut svm, LAMPORTS_PER_SOL))
+        .collect();
+    let member_keys: Vec<Pubkey> = members.iter().map(|member| member.pubkey()).collect();
+    let outsider = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+    let veto_window: i64 = 24 * 3600;
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+
+    for ix in [
+        build_init_dao_ix(&admin.pubkey(), &admin.pubkey(), 10_000_000, &token_mint, 5),
+        build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint),
+    ] {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&admin.pubkey()),
+            &[&admin],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("DAO setup should succeed");
+    }
+
+    let ix = build_create_profile_ix(&voter.pubkey(), "voter1");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Profile creation should succeed");
+
+    let voter_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+        .owner(&voter.pubkey())
+        .send()
+        .expect("Failed to create voter ATA");
+    MintTo::new(&mut svm, &admin, &token_mint, &voter_token_account, 20_000_000)
+        .owner(&admin)
+        .send()
+        .expect("Minting should succeed");
+
+    let ix = build_stake_tokens_ix(&voter.pubkey(), &admin.pubkey(), &token_mint, 20_000_000);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Staking should succeed");
+    println!("[Setup] DAO initialized, voter staked 20 tokens");
+
+    println!("[Action] Non-admin sets up a council of their own keys");
+    let ix = build_init_council_ix(
+        &outsider.pubkey(),
+        &admin.pubkey(),
+        &[outsider.pubkey()],
+        1,
+        veto_window,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&outsider.pubkey()),
+        &[&outsider],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Only the admin can set up the council");
+    println!("[Verification] Non-admin council rejected");
+
+    println!("[Action] Admin sets up a council with a threshold above its size");
+    let ix = build_init_council_ix(&admin.pubkey(), &admin.pubkey(), &member_keys, 4, veto_window);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Threshold cannot exceed the member count");
+    println!("[Verification] Unreachable threshold rejected");
+
+    let ix = build_init_council_ix(&admin.pubkey(), &admin.pubkey(), &member_keys, 2, veto_window);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Council setup should succeed");
+
+    let (config, _) = governance::config_address(&admin.pubkey());
+    let (council_pda, _) = governance::council_address(&config);
+    let council = get_council(&svm, &council_pda);
+    assert_eq!(council.members, member_keys);
+    assert_eq!(council.threshold, 2);
+    println!("[Setup] Council of 3 keys, threshold 2, veto window {}s", veto_window);
+
+    println!("[Action] One council member pauses the DAO alone");
+    let ix = build_council_set_pause_ix(&member_keys[0], &admin.pubkey(), &[], true);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&members[0].pubkey()),
+        &[&members[0]],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "One key is below the council threshold");
+
+    println!("[Action] Member co-signs with an outsider");
+    let ix =
+        build_council_set_pause_ix(&member_keys[0], &admin.pubkey(), &[outsider.pubkey()], true);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&members[0].pubkey()),
+        &[&members[0], &outsider],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Non-member signatures do not count");
+    assert!(!get_config_paused(&svm, &config));
+    println!("[Verification] Pause below threshold rejected");
+
+    println!("[Action] Two council members pause the DAO");
+    let ix = build_council_set_pause_ix(&member_keys[0], &admin.pubkey(), &[member_keys[1]], true);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&members[0].pubkey()),
+        &[&members[0], &members[1]],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Threshold pause should succeed");
+    assert!(get_config_paused(&svm, &config));
+
+    let ix = build_create_governance_proposal_ix(&voter.pubkey(), &admin.pubkey(), &token_mint, 0, "Grant");
+    let tx = Transaction::new_signed_with_payer(
+        &[ix, build_cast_ballot_ix(&voter.pubkey(), &admin.pubkey(), 0, true)],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Ballots are frozen while paused");
+    println!("[Verification] DAO paused, ballots rejected");
+
+    let ix = build_council_set_pause_ix(&member_keys[2], &admin.pubkey(), &[member_keys[1]], false);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&members[2].pubkey()),
+        &[&members[2], &members[1]],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Threshold resume should succeed");
+    assert!(!get_config_paused(&svm, &config));
+    println!("[Setup] DAO resumed by two other members");
+
+    // Proposal 0 carries a multisig action, proposal 1 is title-only
+    let multisig = Pubkey::new_unique();
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            build_create_governance_proposal_ix(&voter.pubkey(), &admin.pubkey(), &token_mint, 0, "Malicious"),
+            build_attach_multisig_action_ix(
+                &voter.pubkey(),
+                &admin.pubkey(),
+                0,
+                &multisig,
+                multisig::ProposalType::ChangeThreshold { new_threshold: 1 },
+            ),
+            build_cast_ballot_ix(&voter.pubkey(), &admin.pubkey(), 0, true),
+            build_create_governance_proposal_ix(&voter.pubkey(), &admin.pubkey(), &token_mint, 1, "Routine"),
+            build_cast_ballot_ix(&voter.pubkey(), &admin.pubkey(), 1, true),
+        ],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Proposals and ballots should succeed");
+
+    advance_time(&mut svm, PROPOSAL_VOTING_PERIOD_SECONDS + 1);
+    for proposal_id in [0, 1] {
+        let ix = build_finalize_proposal_ix(&admin.pubkey(), &admin.pubkey(), &token_mint, proposal_id, &voter.pubkey());
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&admin.pubkey()),
+            &[&admin],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Finalize should succeed");
+    }
+    let (proposal_0, _) = governance::proposal_address(&config, 0);
+    let (proposal_1, _) = governance::proposal_address(&config, 1);
+    assert_eq!(get_proposal_tally(&svm, &proposal_0).2, 1, "Proposal 0 should pass");
+    println!("[Setup] Both proposals passed");
+
+    println!("[Action] Executing proposal 0 inside the veto window");
+    let ix = build_execute_multisig_action_ix(&voter.pubkey(), &admin.pubkey(), 0, &multisig, 0);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Execution waits for the veto window");
+    println!("[Verification] Early execution rejected");
+
+    println!("[Action] One council member vetoes proposal 0 alone");
+    let ix = build_council_veto_ix(&member_keys[0], &admin.pubkey(), 0, &[]);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&members[0].pubkey()),
+        &[&members[0]],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "One key cannot veto");
+    assert_eq!(get_proposal_tally(&svm, &proposal_0).2, 1);
+
+    println!("[Action] Two council members veto proposal 0");
+    let ix = build_council_veto_ix(&member_keys[0], &admin.pubkey(), 0, &[member_keys[2]]);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&members[0].pubkey()),
+        &[&members[0], &members[2]],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Threshold veto should succeed");
+    assert_eq!(get_proposal_tally(&svm, &proposal_0).2, 3, "Proposal 0 should be vetoed");
+    println!("[Verification] Proposal 0 vetoed");
+
+    println!("[Action] Council vetoes proposal 1 after the veto window");
+    advance_time(&mut svm, veto_window as u64);
+    let ix = build_council_veto_ix(&member_keys[1], &admin.pubkey(), 1, &[member_keys[2]]);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&members[1].pubkey()),
+        &[&members[1], &members[2]],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Veto window has closed");
+    assert_eq!(get_proposal_tally(&svm, &proposal_1).2, 1, "Proposal 1 stays passed");
+    println!("[Verification] Late veto rejected");
+
+    println!("[TEST END] test_emergency_council");
+}
+
+#[test]
+fn test_downvote_requires_bronze_rank() {
+    println!("[TEST START] test_downvote_requires_bronze_rank");
+    let mut svm = setup_svm();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let voter = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let target = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+
+    for ix in [
+        build_init_dao_ix(&admin.pubkey(), &admin.pubkey(), 10_000_000, &token_mint, 5),
+        build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint),
+    ] {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&admin.pubkey()),
+            &[&admin],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("DAO setup should succeed");
+    }
+
+    let target_username = "target1";
+    for (user, username) in [(&voter, "voter1"), (&target, target_username)] {
+        let ix = build_create_profile_ix(&user.pubkey(), username);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&user.pubkey()),
+            &[user],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Profile creation should succeed");
+    }
+
+    let voter_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+        .owner(&voter.pubkey())
+        .send()
+        .expect("Failed to create voter ATA");
+    MintTo::new(&mut svm, &admin, &token_mint, &voter_token_account, 100_000_000)
+        .owner(&admin)
+        .send()
+        .expect("Minting should succeed");
+
+    let ix = build_stake_tokens_ix(&voter.pubkey(), &admin.pubkey(), &token_mint, 20_000_000);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Staking should succeed");
+    println!("[Setup] Voter staked 20 tokens at Member rank");
+
+    advance_time(&mut svm, 25 * 3600);
+
+    println!("[Test] Member tries to downvote");
+    let ix = build_downvote_ix_with_target(
+        &voter.pubkey(),
+        &admin.pubkey(),
+        &target.pubkey(),
+        target_username,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Member downvote should fail the Bronze rank gate");
+
+    let (target_profile, _) = governance::user_profile_address(&target.pubkey());
+    assert_eq!(get_reputation(&svm, &target_profile), 0, "Target reputation unchanged");
+    println!("[Verification] Downvote rejected below Bronze");
+
+    println!("[Test] Same voter upvotes, which needs no rank");
+    let ix = build_upvote_ix_with_target(
+        &voter.pubkey(),
+        &admin.pubkey(),
+        &target.pubkey(),
+        target_username,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&voter.pubkey()),
+        &[&voter],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Upvote should succeed");
+    assert_eq!(get_reputation(&svm, &target_profile), 1, "Member upvote counts once");
+    println!("[Verification] Upvote accepted at Member rank");
+
+    println!("[TEST END] test_downvote_requires_bronze_rank");
+}
+
+#[test]
+fn test_vote_escrow_lock() {
+    println!("[TEST START] test_vote_escrow_lock");
+    let mut svm = setup_svm();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let holder = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let locker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+
+    let send = |svm: &mut LiteSVM, ix, signer: &Keypair| {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&signer.pubkey()),
+            &[signer],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+    };
+
+    send(&mut svm, build_init_dao_ix(&admin.pubkey(), &admin.pubkey(), 10_000_000, &token_mint, 5), &admin)
+        .expect("DAO init should succeed");
+    send(&mut svm, build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint), &admin)
+        .expect("Treasury init should succeed");
+
+    let mut token_accounts = Vec::new();
+    for (user, username) in [(&holder, "holder"), (&locker, "locker")] {
+        send(&mut svm, build_create_profile_ix(&user.pubkey(), username), user)
+            .expect("Profile creation should succeed");
+        let user_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+            .owner(&user.pubkey())
+            .send()
+            .expect("Failed to create user ATA");
+        MintTo::new(&mut svm, &admin, &token_mint, &user_token_account, 100_000_000)
+            .owner(&admin)
+            .send()
+            .expect("Minting should succeed");
+        token_accounts.push(user_token_account);
+    }
+    let locker_token_account = token_accounts[1];
+
+    send(&mut svm, build_stake_tokens_ix(&holder.pubkey(), &admin.pubkey(), &token_mint, 20_000_000), &holder)
+        .expect("Holder stake should succeed");
+    println!("[Setup] Holder staked 20 tokens to open proposals; locker holds 100 unstaked tokens");
+
+    let (config, _) = governance::config_address(&admin.pubkey());
+    let (vote_escrow, _) = governance::vote_escrow_address(&config, &locker.pubkey());
+    let max_lock = governance::MAX_LOCK_SECONDS;
+
+    println!("[Test] Lock longer than MAX_LOCK_SECONDS");
+    let ix = build_lock_tokens_ix(&locker.pubkey(), &admin.pubkey(), &token_mint, 100_000_000, max_lock + 1);
+    assert!(send(&mut svm, ix, &locker).is_err(), "Over-long lock should fail");
+
+    println!("[Action] Locker locks 100 tokens for half the maximum lock");
+    let ix = build_lock_tokens_ix(&locker.pubkey(), &admin.pubkey(), &token_mint, 100_000_000, max_lock / 2);
+    send(&mut svm, ix, &locker).expect("Lock should succeed");
+
+    let escrow = get_vote_escrow(&svm, &vote_escrow);
+    assert_eq!(escrow.amount, 100_000_000);
+    assert_eq!(escrow.unlock_at - escrow.locked_at, max_lock / 2);
+    let escrow_token_account = soteria_client::token::associated_token_address(
+        &vote_escrow,
+        &token_mint,
+        &soteria_client::token::TOKEN_PROGRAM_ID,
+    );
+    let balance = get_spl_account::<spl_token::state::Account>(&svm, &escrow_token_account)
+        .unwrap()
+        .amount;
+    assert_eq!(balance, 100_000_000, "Tokens held by the escrow's own ATA");
+    println!("[Verification] Escrow holds 100 tokens until {}", escrow.unlock_at);
+
+    println!("[Test] Withdraw and shortening extension before expiry");
+    let ix = build_withdraw_expired_lock_ix(&locker.pubkey(), &admin.pubkey(), &token_mint);
+    assert!(send(&mut svm, ix, &locker).is_err(), "Live lock cannot be withdrawn");
+    let ix = build_extend_lock_ix(&locker.pubkey(), &admin.pubkey(), governance::MIN_LOCK_SECONDS);
+    assert!(send(&mut svm, ix, &locker).is_err(), "Extension cannot pull the unlock time in");
+
+    advance_time(&mut svm, 3600);
+    send(&mut svm, build_create_governance_proposal_ix(&holder.pubkey(), &admin.pubkey(), &token_mint, 0, "Fund grants"), &holder)
+        .expect("Proposal 0 should be created");
+
+    println!("[Test] Locker votes without and then with the escrow");
+    let ix = build_cast_ballot_ix(&locker.pubkey(), &admin.pubkey(), 0, false);
+    assert!(send(&mut svm, ix, &locker).is_err(), "No stake and no escrow is below the minimum");
+    let ix = build_cast_ballot_with_escrow_ix(&locker.pubkey(), &admin.pubkey(), 0, false);
+    send(&mut svm, ix, &locker).expect("Escrow ballot should succeed");
+
+    let (proposal, _) = governance::proposal_address(&config, 0);
+    let expected = escrow.voting_power(get_proposal_created_at(&svm, &proposal));
+    let (_, votes_against, _) = get_proposal_tally(&svm, &proposal);
+    assert_eq!(votes_against, expected);
+    assert!(votes_against < 50_000_000 && votes_against > 49_990_000, "Half-length lock, one hour decayed");
+    println!("[Verification] Half-length lock counted {} of 100 tokens", votes_against);
+
+    println!("[Test] Extension made after a proposal does not count on it");
+    send(&mut svm, build_create_governance_proposal_ix(&holder.pubkey(), &admin.pubkey(), &token_mint, 1, "Fund audits"), &holder)
+        .expect("Proposal 1 should be created");
+    advance_time(&mut svm, 1);
+    let ix = build_extend_lock_ix(&locker.pubkey(), &admin.pubkey(), max_lock);
+    send(&mut svm, ix, &locker).expect("Extension to the maximum should succeed");
+    let ix = build_cast_ballot_with_escrow_ix(&locker.pubkey(), &admin.pubkey(), 1, false);
+    assert!(send(&mut svm, ix, &locker).is_err(), "Lock extended after proposal 1 misses its snapshot");
+
+    advance_time(&mut svm, 1);
+    send(&mut svm, build_create_governance_proposal_ix(&holder.pubkey(), &admin.pubkey(), &token_mint, 2, "Fund research"), &holder)
+        .expect("Proposal 2 should be created");
+    let ix = build_cast_ballot_with_escrow_ix(&locker.pubkey(), &admin.pubkey(), 2, false);
+    send(&mut svm, ix, &locker).expect("Extended lock votes on a later proposal");
+    let (proposal, _) = governance::proposal_address(&config, 2);
+    let (_, votes_against, _) = get_proposal_tally(&svm, &proposal);
+    assert!(votes_against > 99_990_000, "Maximum lock counts close to 1x");
+    println!("[Verification] Extended lock counted {} of 100 tokens", votes_against);
+
+    println!("[Test] Expired lock votes with nothing, then withdraws");
+    advance_time(&mut svm, max_lock as u64);
+    send(&mut svm, build_create_governance_proposal_ix(&holder.pubkey(), &admin.pubkey(), &token_mint, 3, "Fund audits again"), &holder)
+        .expect("Proposal 3 should be created");
+    let ix = build_cast_ballot_with_escrow_ix(&locker.pubkey(), &admin.pubkey(), 3, false);
+    assert!(send(&mut svm, ix, &locker).is_err(), "Expired lock has no power left");
+
+    let ix = build_withdraw_expired_lock_ix(&locker.pubkey(), &admin.pubkey(), &token_mint);
+    send(&mut svm, ix, &locker).expect("Expired lock should withdraw");
+    let balance = get_spl_account::<spl_token::state::Account>(&svm, &locker_token_account)
+        .unwrap()
+        .amount;
+    assert_eq!(balance, 100_000_000, "All locked tokens returned");
+    assert!(svm.get_account(&vote_escrow).map_or(true, |account| account.data.is_empty()));
+    assert!(svm.get_account(&escrow_token_account).map_or(true, |account| account.data.is_empty()));
+    println!("[Verification] Tokens returned, escrow and its token account closed");
+
+    println!("[TEST END] test_vote_escrow_lock");
+}
+
+#[test]
+fn test_treasury_value_report() {
+    println!("[TEST START] test_treasury_value_report");
+    let mut svm = setup_svm_with_oracle();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let alice = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let send = |svm: &mut LiteSVM, ix, signer: &Keypair| {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&signer.pubkey()),
+            &[signer],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+    };
+
+    // Base and LP mints use DECIMALS, the stablecoin 9 decimals
+    let mut mints = Vec::new();
+    for decimals in [DECIMALS, DECIMALS, 9] {
+        let mint = CreateMint::new(&mut svm, &admin)
+            .authority(&admin.pubkey())
+            .decimals(decimals)
+            .send()
+            .expect("Mint creation should succeed");
+        mints.push(mint);
+    }
+    let (token_mint, lp_mint, usd_mint) = (mints[0], mints[1], mints[2]);
+
+    send(&mut svm, build_init_dao_ix(&admin.pubkey(), &admin.pubkey(), 10_000_000, &token_mint, 5), &admin)
+        .expect("DAO init should succeed");
+    send(&mut svm, build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint), &admin)
+        .expect("Treasury init should succeed");
+    send(&mut svm, build_add_supported_mint_ix(&admin.pubkey(), &admin.pubkey(), &lp_mint, 20_000), &admin)
+        .expect("LP mint registration should succeed");
+
+    // Alice stakes 10 base and 10 LP tokens: stakers' principal, not DAO funds
+    send(&mut svm, build_create_profile_ix(&alice.pubkey(), "alice"), &alice)
+        .expect("Profile creation should succeed");
+    for mint in [token_mint, lp_mint] {
+        let user_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &mint)
+            .owner(&alice.pubkey())
+            .send()
+            .expect("Failed to create user ATA");
+        MintTo::new(&mut svm, &admin, &mint, &user_token_account, 10_000_000)
+            .owner(&admin)
+            .send()
+            .expect("Minting should succeed");
+    }
+    send(&mut svm, build_stake_tokens_ix(&alice.pubkey(), &admin.pubkey(), &token_mint, 10_000_000), &alice)
+        .expect("Base stake should succeed");
+    send(&mut svm, build_stake_supported_mint_ix(&alice.pubkey(), &admin.pubkey(), &lp_mint, 10_000_000), &alice)
+        .expect("LP stake should succeed");
+
+    // Feeds: base 2.00, LP 0.50, stablecoin 1.00000000 (a different exponent)
+    let base_feed = derive_price_feed(&admin.pubkey(), 1);
+    let lp_feed = derive_price_feed(&admin.pubkey(), 2);
+    let usd_feed = derive_price_feed(&admin.pubkey(), 3);
+    for (seed, feed, exponent, price) in [
+        (1, base_feed, -6, 2_000_000),
+        (2, lp_feed, -6, 500_000),
+        (3, usd_feed, -8, 100_000_000),
+    ] {
+        send(&mut svm, build_initialize_feed_ix(&admin.pubkey(), seed, exponent, 60, 100), &admin)
+            .expect("Feed init should succeed");
+        send(&mut svm, build_push_price_ix(&admin.pubkey(), &feed, price, 0), &admin)
+            .expect("Price push should succeed");
+    }
+    println!("[Setup] Alice staked 10 base + 10 LP; feeds at 2.00, 0.50 and 1.00");
+
+    println!("[Test] Non-admin registration and a feed from the wrong program");
+    let ix = build_register_treasury_holding_ix(&alice.pubkey(), &admin.pubkey(), &token_mint, &base_feed);
+    assert!(send(&mut svm, ix, &alice).is_err(), "Only the admin can register holdings");
+    let ix = governance_ix::register_treasury_holding(
+        &admin.pubkey(),
+        &admin.pubkey(),
+        &token_mint,
+        &GOVERNANCE_PROGRAM_ID,
+        &base_feed,
+    );
+    assert!(send(&mut svm, ix, &admin).is_err(), "Feed must be owned by the named oracle program");
+    println!("[Verification] Both rejected");
+
+    println!("[Action] Admin registers base, LP and stablecoin holdings");
+    for (mint, feed) in [(token_mint, base_feed), (lp_mint, lp_feed), (usd_mint, usd_feed)] {
+        let ix = build_register_treasury_holding_ix(&admin.pubkey(), &admin.pubkey(), &mint, &feed);
+        send(&mut svm, ix, &admin).expect("Holding registration should succeed");
+    }
+    let ix = build_register_treasury_holding_ix(&admin.pubkey(), &admin.pubkey(), &usd_mint, &usd_feed);
+    assert!(send(&mut svm, ix, &admin).is_err(), "A mint is registered once");
+
+    let (treasury, _) = governance::treasury_address(&admin.pubkey());
+    let (usd_holding, _) = governance::treasury_holding_address(&treasury, &usd_mint);
+    let holding = get_treasury_holding(&svm, &usd_holding);
+    assert_eq!(holding.index, 2);
+    assert_eq!(holding.decimals, 9);
+    assert_eq!(holding.price_feed, usd_feed);
+    assert_eq!(
+        holding.token_account,
+        governance::treasury_token_address(&admin.pubkey(), &usd_mint)
+    );
+    println!("[Verification] Holdings 0..3 registered, stablecoin ATA created");
+
+    // DAO funds on top of the staked principal
+    for (mint, amount) in [(token_mint, 50_000_000), (lp_mint, 5_000_000), (usd_mint, 1_000_000_000_000)] {
+        let treasury_token_account = governance::treasury_token_address(&admin.pubkey(), &mint);
+        MintTo::new(&mut svm, &admin, &mint, &treasury_token_account, amount)
+            .owner(&admin)
+            .send()
+            .expect("Minting should succeed");
+    }
+    println!("[Setup] Treasury funded with 50 base, 5 LP and 1,000 stablecoin");
+
+    let holdings = [(token_mint, base_feed), (lp_mint, lp_feed), (usd_mint, usd_feed)];
+
+    println!("[Action] Report the treasury value");
+    let ix = build_treasury_value_report_ix(&admin.pubkey(), &holdings);
+    let meta = send(&mut svm, ix, &alice).expect("Report should succeed");
+    let report = governance::TreasuryValueReport::try_from_bytes(&meta.return_data.data)
+        .expect("Report should decode");
+
+    // 50 * 2.00 = 100, 5 * 0.50 = 2.5, 1,000 * 1.00 = 1,000 (6-decimal values)
+    let amounts: Vec<u64> = report.holdings.iter().map(|h| h.amount).collect();
+    let values: Vec<u128> = report.holdings.iter().map(|h| h.value).collect();
+    assert_eq!(amounts, vec![50_000_000, 5_000_000, 1_000_000_000_000], "Staked principal excluded");
+    assert_eq!(values, vec![100_000_000, 2_500_000, 1_000_000_000]);
+    assert_eq!(report.total_value, 1_102_500_000);
+    println!("[Verification] Total value {} (1,102.50)", report.total_value);
+
+    println!("[Test] Reports leaving out or reordering holdings");
+    let ix = build_treasury_value_report_ix(&admin.pubkey(), &holdings[..2]);
+    assert!(send(&mut svm, ix, &alice).is_err(), "Every holding must be passed");
+    let reordered = [holdings[1], holdings[0], holdings[2]];
+    let ix = build_treasury_value_report_ix(&admin.pubkey(), &reordered);
+    assert!(send(&mut svm, ix, &alice).is_err(), "Holdings must be in index order");
+    let wrong_feed = [holdings[0], (lp_mint, base_feed), holdings[2]];
+    let ix = build_treasury_value_report_ix(&admin.pubkey(), &wrong_feed);
+    assert!(send(&mut svm, ix, &alice).is_err(), "Only the registered feed prices a holding");
+    println!("[Verification] Incomplete and mismatched reports rejected");
+
+    println!("[Test] Stale prices");
+    advance_time(&mut svm, 61);
+    // New blockhash so the identical report is not dropped as a duplicate
+    svm.expire_blockhash();
+    let ix = build_treasury_value_report_ix(&admin.pubkey(), &holdings);
+    assert!(send(&mut svm, ix, &alice).is_err(), "Stale feed cannot price the treasury");
+    println!("[Verification] Stale report rejected");
+
+    println!("[TEST END] test_treasury_value_report");
+}
+
+#[test]
+fn test_proposal_deposit() {
+    println!("[TEST START] test_proposal_deposit");
+    let mut svm = setup_svm();
+
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let proposer = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let token_mint = CreateMint::new(&mut svm, &admin)
+        .authority(&admin.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Mint creation should succeed");
+
+    let send = |svm: &mut LiteSVM, ix, signer: &Keypair| {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&signer.pubkey()),
+            &[signer],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+    };
+    let balance = |svm: &LiteSVM, token_account: &Pubkey| {
+        get_spl_account::<spl_token::state::Account>(svm, token_account)
+            .unwrap()
+            .amount
+    };
+    let supply = |svm: &LiteSVM| {
+        get_spl_account::<spl_token::state::Mint>(svm, &token_mint)
+            .unwrap()
+            .supply
+    };
+
+    // 10 token deposit, quorum DEFAULT_QUORUM (10 tokens)
+    let ix = build_init_dao_with_deposit_ix(
+        &admin.pubkey(),
+        &admin.pubkey(),
+        10_000_000,
+        &token_mint,
+        5,
+        10_000_000,
+    );
+    send(&mut svm, ix, &admin).expect("DAO init should succeed");
+    send(&mut svm, build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint), &admin)
+        .expect("Treasury init should succeed");
+
+    send(&mut svm, build_create_profile_ix(&proposer.pubkey(), "proposer"), &proposer)
+        .expect("Profile creation should succeed");
+    let proposer_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &token_mint)
+        .owner(&proposer.pubkey())
+        .send()
+        .expect("Failed to create proposer ATA");
+    MintTo::new(&mut svm, &admin, &token_mint, &proposer_token_account, 50_000_000)
+        .owner(&admin)
+        .send()
+        .expect("Minting should succeed");
+    send(&mut svm, build_stake_tokens_ix(&proposer.pubkey(), &admin.pubkey(), &token_mint, 20_000_000), &proposer)
+        .expect("Stake should succeed");
+    println!("[Setup] Proposer staked 20 tokens and holds 30 more");
+
+    // Stake must predate the proposal to count on its ballot
+    advance_time(&mut svm, 60);
+
+    let (config, _) = governance::config_address(&admin.pubkey());
+    let deposit_accounts = |proposal_id: u64| {
+        let (proposal, _) = governance::proposal_address(&config, proposal_id);
+        let (proposal_deposit, _) = governance::proposal_deposit_address(&proposal);
+        let deposit_token_account = soteria_client::token::associated_token_address(
+            &proposal_deposit,
+            &token_mint,
+            &soteria_client::token::TOKEN_PROGRAM_ID,
+        );
+        (proposal_deposit, deposit_token_account)
+    };
+
+    println!("[Action] Member proposer opens proposal 0 and votes on it");
+    let ix = build_create_governance_proposal_ix(&proposer.pubkey(), &admin.pubkey(), &token_mint, 0, "Fund grants");
+    send(&mut svm, ix, &proposer).expect("Proposal 0 should be created");
+    let (deposit_0, deposit_token_account_0) = deposit_accounts(0);
+    let deposit = get_proposal_deposit(&svm, &deposit_0);
+    assert_eq!(deposit.depositor, proposer.pubkey());
+    assert_eq!(deposit.amount, 10_000_000, "Member rank pays the full deposit");
+    assert_eq!(balance(&svm, &deposit_token_account_0), 10_000_000);
+    assert_eq!(balance(&svm, &proposer_token_account), 20_000_000);
+    send(&mut svm, build_cast_ballot_ix(&proposer.pubkey(), &admin.pubkey(), 0, false), &proposer)
+        .expect("Ballot should succeed");
+    println!("[Verification] 10 tokens escrowed for proposal 0");
+
+    println!("[Action] Proposer reaches Leader rank and opens proposal 1, which nobody votes on");
+    let (proposer_profile, _) = governance::user_profile_address(&proposer.pubkey());
+    set_reputation(&mut svm, &proposer_profile, 500);
+    let ix = build_create_governance_proposal_ix(&proposer.pubkey(), &admin.pubkey(), &token_mint, 1, "Fund audits");
+    send(&mut svm, ix, &proposer).expect("Proposal 1 should be created");
+    let (deposit_1, deposit_token_account_1) = deposit_accounts(1);
+    assert_eq!(get_proposal_deposit(&svm, &deposit_1).amount, 2_000_000, "Leader rank pays 20%");
+    assert_eq!(balance(&svm, &proposer_token_account), 18_000_000);
+    println!("[Verification] 2 tokens escrowed for proposal 1");
+
+    advance_time(&mut svm, PROPOSAL_VOTING_PERIOD_SECONDS + 1);
+
+    println!("[Test] Finalize paying the deposit to someone other than the proposer");
+    let ix = build_finalize_proposal_ix(&admin.pubkey(), &admin.pubkey(), &token_mint, 0, &admin.pubkey());
+    assert!(send(&mut svm, ix, &admin).is_err(), "Deposit only goes back to the proposer");
+
+    println!("[Action] Finalizing proposal 0 (rejected, but quorum reached)");
+    let supply_before = supply(&svm);
+    let ix = build_finalize_proposal_ix(&admin.pubkey(), &admin.pubkey(), &token_mint, 0, &proposer.pubkey());
+    send(&mut svm, ix, &admin).expect("Finalize should succeed");
+    let (proposal_0, _) = governance::proposal_address(&config, 0);
+    assert_eq!(get_proposal_tally(&svm, &proposal_0).2, 2, "Proposal 0 should be Rejected");
+    assert_eq!(balance(&svm, &proposer_token_account), 28_000_000, "Deposit refunded");
+    assert_eq!(supply(&svm), supply_before);
+    assert!(svm.get_account(&deposit_0).is_none(), "Deposit PDA closed");
+    assert!(svm.get_account(&deposit_token_account_0).is_none(), "Deposit ATA closed");
+    println!("[Verification] Voted-down proposal with quorum refunds its deposit");
+
+    println!("[Action] Finalizing proposal 1 (no quorum)");
+    let ix = build_finalize_proposal_ix(&admin.pubkey(), &admin.pubkey(), &token_mint, 1, &proposer.pubkey());
+    send(&mut svm, ix, &admin).expect("Finalize should succeed");
+    assert_eq!(balance(&svm, &proposer_token_account), 28_000_000, "Deposit not refunded");
+    assert_eq!(supply(&svm), supply_before - 2_000_000, "Deposit burned");
+    assert!(svm.get_account(&deposit_1).is_none(), "Deposit PDA closed");
+    assert!(svm.get_account(&deposit_token_account_1).is_none(), "Deposit ATA closed");
+    println!("[Verification] Proposal without quorum burns its deposit");
+
+    println!("[TEST END] test_proposal_deposit");
+}
+
+#[test]
+fn test_profile_metadata_uri() {
+    println!("[TEST START] test_profile_metadata_uri");
+    let mut svm = setup_svm();
+
+    let alice = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let bob = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let send = |svm: &mut LiteSVM, ix, signer: &Keypair| {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&signer.pubkey()),
+            &[signer],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+    };
+
+    for (user, username) in [(&alice, "alice"), (&bob, "bob")] {
+        send(&mut svm, build_create_profile_ix(&user.pubkey(), username), user)
+            .expect("Profile creation should succeed");
+    }
+    let (alice_profile, _) = governance::user_profile_address(&alice.pubkey());
+    let base_len = svm.get_account(&alice_profile).unwrap().data.len();
+    assert_eq!(get_metadata_uri(&svm, &alice_profile), None);
+    println!("[Setup] Profiles created without metadata ({} bytes)", base_len);
+
+    // Rejected: unsupported schemes, a bare scheme, whitespace, and one byte over the cap
+    let too_long = format!("https://{}", "a".repeat(200 - "https://".len() + 1));
+    for uri in [
+        "javascript:alert(1)",
+        "http://example.com/alice.json",
+        "data:text/html,<script>",
+        "https://",
+        "https://example.com/a b.json",
+        too_long.as_str(),
+    ] {
+        let ix = build_update_profile_metadata_ix(&alice.pubkey(), Some(uri));
+        assert!(send(&mut svm, ix, &alice).is_err(), "URI should be rejected: {}", uri);
+    }
+    assert_eq!(svm.get_account(&alice_profile).unwrap().data.len(), base_len);
+    println!("[Verification] Invalid and oversized URIs rejected");
+
+    // Only the owner's own profile can be updated
+    let ix = build_update_profile_metadata_ix(&bob.pubkey(), Some("https://example.com/bob.json"));
+    send(&mut svm, ix, &bob).expect("Bob can set his own URI");
+    assert_eq!(get_metadata_uri(&svm, &alice_profile), None, "Alice's profile untouched");
+
+    // Longest allowed URI: the profile grows by exactly its bytes and stays rent-exempt
+    let max_uri = format!("https://{}", "a".repeat(200 - "https://".len()));
+    let ix = build_update_profile_metadata_ix(&alice.pubkey(), Some(&max_uri));
+    send(&mut svm, ix, &alice).expect("URI at the cap should be accepted");
+    let account = svm.get_account(&alice_profile).unwrap();
+    assert_eq!(account.data.len(), base_len + 4 + max_uri.len());
+    assert!(account.lamports >= svm.minimum_balance_for_rent_exemption(account.data.len()));
+    assert_eq!(get_metadata_uri(&svm, &alice_profile), Some(max_uri));
+    println!("[Verification] Profile resized to {} bytes", account.data.len());
+
+    // Replacing and clearing shrink the profile back down
+    let ipfs_uri = "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+    let ix = build_update_profile_metadata_ix(&alice.pubkey(), Some(ipfs_uri));
+    send(&mut svm, ix, &alice).expect("ipfs:// URI should be accepted");
+    assert_eq!(svm.get_account(&alice_profile).unwrap().data.len(), base_len + 4 + ipfs_uri.len());
+    assert_eq!(get_metadata_uri(&svm, &alice_profile).as_deref(), Some(ipfs_uri));
+
+    let ix = build_update_profile_metadata_ix(&alice.pubkey(), None);
+    send(&mut svm, ix, &alice).expect("Clearing the URI should succeed");
+    assert_eq!(svm.get_account(&alice_profile).unwrap().data.len(), base_len);
+    assert_eq!(get_metadata_uri(&svm, &alice_profile), None);
+    println!("[Verification] URI replaced, then cleared");
+
+    println!("[TEST END] test_profile_metadata_uri");
+}
+
+// Claim an unbonding ticket in the block it was opened (V014 in g-vulnerable)
+struct ClaimBeforeUnbonding;
+
+struct UnbondingState {
+    admin: Keypair,
+    user: Keypair,
+    token_mint: Pubkey,
+}
+
+impl DifferentialScenario for ClaimBeforeUnbonding {
+    type State = UnbondingState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V014",
+            title: "Unbonding Period Not Enforced",
+            severity: Severity::High,
+            lesson: "Pay out an unbonding ticket only once its unlock time has passed",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, _program_id: &Pubkey) -> UnbondingState {
+        let admin = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let user = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+
+        let token_mint = CreateMint::new(svm, &admin)
+            .authority(&admin.pubkey())
+            .decimals(DECIMALS)
+            .send()
+            .expect("Mint creation should succeed");
+
+        let init_ix = build_init_dao_ix(&admin.pubkey(), &admin.pubkey(), 10_000_000, &token_mint, 5);
+        let treasury_ix = build_initialize_treasury_ix(&admin.pubkey(), &admin.pubkey(), &token_mint);
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix, treasury_ix],
+            Some(&admin.pubkey()),
+            &[&admin],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("DAO and treasury init should succeed");
+
+        let user_token_account = CreateAssociatedTokenAccount::new(svm, &admin, &token_mint)
+            .owner(&user.pubkey())
+            .send()
+            .expect("Failed to create user ATA");
+        MintTo::new(svm, &admin, &token_mint, &user_token_account, 100_000_000)
+            .owner(&admin)
+            .send()
+            .expect("Minting should succeed");
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                build_create_profile_ix(&user.pubkey(), "staker"),
+                build_stake_tokens_ix(&user.pubkey(), &admin.pubkey(), &token_mint, 20_000_000),
+                build_unstake_tokens_ix(&user.pubkey(), &admin.pubkey(), 10_000_000),
+            ],
+            Some(&user.pubkey()),
+            &[&user],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Profile, stake and unstake should succeed");
+
+        UnbondingState { admin, user, token_mint }
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        state: &mut UnbondingState,
+    ) -> TransactionResult {
+        let claim_ix =
+            build_claim_unbonded_ix(&state.user.pubkey(), &state.admin.pubkey(), &state.token_mint);
+        let tx = Transaction::new_signed_with_payer(
+            &[claim_ix],
+            Some(&state.user.pubkey()),
+            &[&state.user],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+    }
+}
+
+// Feature matrix: only the vuln-missing-unbonding-check build pays out early
+// Requires ./build-vuln-matrix.sh governance/g-secure from the repository root
+#[test]
+fn test_feature_missing_unbonding_check() {
+    let variant = FeatureVariant {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        feature: "vuln-missing-unbonding-check",
+    };
+    let (mut baseline, mut variant_svm) = variant.load(
+        "target/deploy/governance_secure.so",
+        variant.so_path(".", "governance_secure"),
+    );
+    run_feature_differential(&mut baseline, &mut variant_svm, &variant, &ClaimBeforeUnbonding);
+}
@@ -7,28 +7,48 @@ use solana_sdk::{
     pubkey::Pubkey,
     signature::{Keypair, Signer},
 };
-use spl_associated_token_account::get_associated_token_address;
+use soteria_client::governance::{
+    instructions::DaoParams, Config, Council, MintStake, MultisigAction, Proposal,
+    ProposalDeposit, SeasonSnapshot, SupportedMint, TreasuryHolding, UserProfile, VoteEscrow, VoteHistory,
+    VoteRecord,
+};
+
+// PDAs, instruction builders and account decoders come from the client SDK
+pub use soteria_client::governance::{self, instructions as governance_ix};
+pub use soteria_client::multisig::{self, instructions as multisig_ix};
+use soteria_test_kit::ProgramHarness;
 
 // Program ID matching declare_id!
-pub const GOVERNANCE_PROGRAM_ID: Pubkey = Pubkey::new_from_array(governance_secure::ID.to_bytes());
+pub const GOVERNANCE_PROGRAM_ID: Pubkey = governance::PROGRAM_ID;
 
-// Standard program IDs
-pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
-pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_account::ID;
-use solana_system_interface::program::ID as system_program;
+// Multisig program (m-secure) that executed multisig actions CPI into
+pub const MULTISIG_PROGRAM_ID: Pubkey = multisig::PROGRAM_ID;
+pub const MULTISIG_PROGRAM_PATH: &str = "../../multisig/m-secure/target/deploy/multisig_secure.so";
 
-// PDA Seeds
-pub const CONFIG: &[u8] = b"config";
-pub const TREASURY: &[u8] = b"treasury";
-pub const TREASURYAUTH: &[u8] = b"treasury_auth";
-pub const USERPROFILE: &[u8] = b"user_profile";
-pub const USER_REGISTRY: &[u8] = b"user_registry";
-pub const VOTE_COOLDOWN: &[u8] = b"cooldown";
-pub const VOTE_RECORD: &[u8] = b"vote_record";
+// Oracle program (oracle-secure) that treasury holdings are priced with
+pub const ORACLE_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("3hARN2soFtGhiAK4N4VhnKWhuFE29B7aL3uiT9eYeVHe");
+pub const ORACLE_PROGRAM_PATH: &str = "../../oracle/oracle-secure/target/deploy/oracle_secure.so";
+pub const ORACLE_START_TIMESTAMP: i64 = 1_700_000_000;
+
+use solana_system_interface::program::ID as system_program;
 
 // Token decimals
 pub const DECIMALS: u8 = 6;
 
+// Default DAO parameters used by build_init_dao_ix
+pub const DEFAULT_QUORUM: u64 = 10_000_000;
+pub const DEFAULT_APPROVAL_THRESHOLD_BPS: u16 = 5_000;
+pub const DEFAULT_UNBONDING_PERIOD: i64 = 7 * 24 * 3600;
+pub const DEFAULT_DECAY_RATE_BPS: u16 = 1_000;
+pub const DEFAULT_DECAY_EPOCH_SECONDS: i64 = 7 * 24 * 3600;
+pub const DEFAULT_REWARD_RATE_PER_SEC: u64 = 1_000;
+pub const DEFAULT_STAKE_RAMP_SECONDS: i64 = 0;
+pub const DEFAULT_PROPOSAL_DEPOSIT: u64 = 0;
+
+// Proposal voting window in advance_time units
+pub const PROPOSAL_VOTING_PERIOD_SECONDS: u64 = governance::PROPOSAL_VOTING_PERIOD_SECONDS as u64;
+
 // ======================== HELPERS ========================
 
 /// Build Anchor instruction discriminator (first 8 bytes of sha256("global:method_name"))
@@ -48,6 +68,27 @@ pub fn setup_svm() -> LiteSVM {
     svm
 }
 
+// Setup LiteSVM with governance and the multisig program
+pub fn setup_svm_with_multisig() -> LiteSVM {
+    ProgramHarness::builder()
+        .program_file(GOVERNANCE_PROGRAM_ID, "target/deploy/governance_secure.so")
+        .program_file(MULTISIG_PROGRAM_ID, MULTISIG_PROGRAM_PATH)
+        .build()
+        .into_svm()
+}
+
+// Setup LiteSVM with governance and the oracle program pricing treasury holdings
+// The clock starts at ORACLE_START_TIMESTAMP because the oracle treats
+// publish_ts 0 as "never published"
+pub fn setup_svm_with_oracle() -> LiteSVM {
+    ProgramHarness::builder()
+        .program_file(GOVERNANCE_PROGRAM_ID, "target/deploy/governance_secure.so")
+        .program_file(ORACLE_PROGRAM_ID, ORACLE_PROGRAM_PATH)
+        .unix_timestamp(ORACLE_START_TIMESTAMP)
+        .build()
+        .into_svm()
+}
+
 // Create and fund account
 pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
     let keypair = Keypair::new();
@@ -56,77 +97,90 @@ pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
     keypair
 }
 
-// Derive config PDA
-pub fn derive_config_pda(admin: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[CONFIG, admin.as_ref()], &GOVERNANCE_PROGRAM_ID)
-}
-
-// Derive treasury state PDA
-pub fn derive_treasury_pda(admin: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[TREASURY, admin.as_ref()], &GOVERNANCE_PROGRAM_ID)
-}
-
-// Derive treasury authority PDA
-pub fn derive_treasury_authority_pda(config: &Pubkey, admin: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[TREASURYAUTH, config.as_ref(), admin.as_ref()],
-        &GOVERNANCE_PROGRAM_ID,
+// Build init_dao instruction with default DAO parameters
+pub fn build_init_dao_ix(
+    signer: &Pubkey,
+    admin: &Pubkey,
+    minimum_stake: u64,
+    token_mint: &Pubkey,
+    vote_power: u8,
+) -> Instruction {
+    build_init_dao_with_ramp_ix(
+        signer,
+        admin,
+        minimum_stake,
+        token_mint,
+        vote_power,
+        DEFAULT_STAKE_RAMP_SECONDS,
     )
 }
 
-// Derive user profile PDA
-pub fn derive_user_profile_pda(user: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[USERPROFILE, user.as_ref()], &GOVERNANCE_PROGRAM_ID)
-}
-
-// Derive username registry PDA
-pub fn derive_username_registry_pda(username: &str) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[USER_REGISTRY, username.as_bytes()],
-        &GOVERNANCE_PROGRAM_ID,
+// Build init_dao instruction with a stake age ramp
+pub fn build_init_dao_with_ramp_ix(
+    signer: &Pubkey,
+    admin: &Pubkey,
+    minimum_stake: u64,
+    token_mint: &Pubkey,
+    vote_power: u8,
+    stake_ramp_seconds: i64,
+) -> Instruction {
+    build_init_dao_with_options_ix(
+        signer,
+        admin,
+        minimum_stake,
+        token_mint,
+        vote_power,
+        stake_ramp_seconds,
+        DEFAULT_PROPOSAL_DEPOSIT,
     )
 }
 
-// Derive vote cooldown PDA
-pub fn derive_vote_cooldown_pda(voter: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[VOTE_COOLDOWN, voter.as_ref()], &GOVERNANCE_PROGRAM_ID)
-}
-
-// Derive vote record PDA
-pub fn derive_vote_record_pda(voter: &Pubkey, target_username: &str) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[VOTE_RECORD, voter.as_ref(), target_username.as_bytes()],
-        &GOVERNANCE_PROGRAM_ID,
+// Build init_dao instruction with a proposal deposit
+pub fn build_init_dao_with_deposit_ix(
+    signer: &Pubkey,
+    admin: &Pubkey,
+    minimum_stake: u64,
+    token_mint: &Pubkey,
+    vote_power: u8,
+    proposal_deposit: u64,
+) -> Instruction {
+    build_init_dao_with_options_ix(
+        signer,
+        admin,
+        minimum_stake,
+        token_mint,
+        vote_power,
+        DEFAULT_STAKE_RAMP_SECONDS,
+        proposal_deposit,
     )
 }
 
-// Build init_dao instruction
-pub fn build_init_dao_ix(
+fn build_init_dao_with_options_ix(
     signer: &Pubkey,
     admin: &Pubkey,
     minimum_stake: u64,
     token_mint: &Pubkey,
     vote_power: u8,
+    stake_ramp_seconds: i64,
+    proposal_deposit: u64,
 ) -> Instruction {
-    let (config, _) = derive_config_pda(admin);
-
-    let discriminator = anchor_discriminator("init_dao");
-
-    let mut data = discriminator.to_vec();
-    data.extend_from_slice(admin.as_ref());
-    data.extend_from_slice(&minimum_stake.to_le_bytes());
-    data.extend_from_slice(token_mint.as_ref());
-    data.push(vote_power);
-
-    Instruction {
-        program_id: GOVERNANCE_PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*signer, true),
-            AccountMeta::new(config, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data,
-    }
+    governance_ix::init_dao(
+        signer,
+        &DaoParams {
+            admin: *admin,
+            minimum_stake,
+            token_mint: *token_mint,
+            vote_power,
+            quorum: DEFAULT_QUORUM,
+            approval_threshold_bps: DEFAULT_APPROVAL_THRESHOLD_BPS,
+            unbonding_period: DEFAULT_UNBONDING_PERIOD,
+            decay_rate_bps: DEFAULT_DECAY_RATE_BPS,
+            decay_epoch_seconds: DEFAULT_DECAY_EPOCH_SECONDS,
+            reward_rate_per_sec: DEFAULT_REWARD_RATE_PER_SEC,
+            stake_ramp_seconds,
+            proposal_deposit,
+        },
+    )
 }
 
 // Build initialize_treasury instruction
@@ -135,53 +189,12 @@ pub fn build_initialize_treasury_ix(
     admin: &Pubkey,
     token_mint: &Pubkey,
 ) -> Instruction {
-    let (config, _) = derive_config_pda(admin);
-    let (treasury, _) = derive_treasury_pda(admin);
-    let (treasury_authority, _) = derive_treasury_authority_pda(&config, admin);
-    let treasury_token_account = get_associated_token_address(&treasury_authority, token_mint);
-
-    let discriminator = anchor_discriminator("initialize_treasury");
-
-    Instruction {
-        program_id: GOVERNANCE_PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*signer, true),
-            AccountMeta::new_readonly(*admin, false),
-            AccountMeta::new_readonly(config, false),
-            AccountMeta::new(treasury, false),
-            AccountMeta::new_readonly(treasury_authority, false),
-            AccountMeta::new_readonly(*token_mint, false),
-            AccountMeta::new(treasury_token_account, false),
-            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
-            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data: discriminator.to_vec(),
-    }
+    governance_ix::initialize_treasury(signer, admin, token_mint)
 }
 
 // Build create_profile instruction
 pub fn build_create_profile_ix(user: &Pubkey, username: &str) -> Instruction {
-    let (user_registry, _) = derive_username_registry_pda(username);
-    let (user_profile, _) = derive_user_profile_pda(user);
-
-    let discriminator = anchor_discriminator("create_profile");
-
-    let mut data = discriminator.to_vec();
-    // Borsh serialization: len (4 bytes) + string bytes
-    data.extend_from_slice(&(username.len() as u32).to_le_bytes());
-    data.extend_from_slice(username.as_bytes());
-
-    Instruction {
-        program_id: GOVERNANCE_PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*user, true),
-            AccountMeta::new(user_registry, false),
-            AccountMeta::new(user_profile, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data,
-    }
+    governance_ix::create_profile(user, username)
 }
 
 // Build stake_tokens instruction
@@ -191,89 +204,69 @@ pub fn build_stake_tokens_ix(
     token_mint: &Pubkey,
     amount: u64,
 ) -> Instruction {
-    let (config, _) = derive_config_pda(admin);
-    let (treasury, _) = derive_treasury_pda(admin);
-    let (user_profile, _) = derive_user_profile_pda(user);
-    let (treasury_authority, _) = derive_treasury_authority_pda(&config, admin);
-
-    let user_token_account = get_associated_token_address(user, token_mint);
-    let treasury_token_account = get_associated_token_address(&treasury_authority, token_mint);
-
-    let discriminator = anchor_discriminator("stake_tokens");
+    governance_ix::stake_tokens(user, admin, token_mint, amount)
+}
 
-    let mut data = discriminator.to_vec();
-    data.extend_from_slice(&amount.to_le_bytes());
+// Build add_supported_mint instruction
+pub fn build_add_supported_mint_ix(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+    weight_bps: u16,
+) -> Instruction {
+    governance_ix::add_supported_mint(authority, admin, token_mint, weight_bps)
+}
 
-    Instruction {
-        program_id: GOVERNANCE_PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*user, true),
-            AccountMeta::new_readonly(*admin, false),
-            AccountMeta::new_readonly(config, false),
-            AccountMeta::new(treasury, false),
-            AccountMeta::new(user_profile, false),
-            AccountMeta::new_readonly(*token_mint, false),
-            AccountMeta::new(user_token_account, false),
-            AccountMeta::new(treasury_token_account, false),
-            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
-            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data,
-    }
+// Build stake_tokens instruction for a supported (non-base) mint
+pub fn build_stake_supported_mint_ix(
+    user: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    governance_ix::stake_supported_mint(user, admin, token_mint, amount)
 }
 
 // Build unstake_tokens instruction
 pub fn build_unstake_tokens_ix(
     user: &Pubkey,
     admin: &Pubkey,
-    token_mint: &Pubkey,
     amount: u64,
 ) -> Instruction {
-    let (config, _) = derive_config_pda(admin);
-    let (treasury, _) = derive_treasury_pda(admin);
-    let (treasury_authority, _) = derive_treasury_authority_pda(&config, admin);
-    let (user_profile, _) = derive_user_profile_pda(user);
-
-    let user_token_account = get_associated_token_address(user, token_mint);
-    let treasury_token_account = get_associated_token_address(&treasury_authority, token_mint);
-
-    let discriminator = anchor_discriminator("unstake_tokens");
+    governance_ix::unstake_tokens(user, admin, amount)
+}
 
-    let mut data = discriminator.to_vec();
-    data.extend_from_slice(&amount.to_le_bytes());
+// Build claim_unbonded instruction
+pub fn build_claim_unbonded_ix(
+    user: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+) -> Instruction {
+    governance_ix::claim_unbonded(user, admin, token_mint)
+}
 
-    Instruction {
-        program_id: GOVERNANCE_PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*user, true),
-            AccountMeta::new_readonly(*admin, false),
-            AccountMeta::new_readonly(config, false),
-            AccountMeta::new(treasury, false),
-            AccountMeta::new_readonly(treasury_authority, false),
-            AccountMeta::new(user_profile, false),
-            AccountMeta::new_readonly(*token_mint, false),
-            AccountMeta::new(user_token_account, false),
-            AccountMeta::new(treasury_token_account, false),
-            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
-            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data,
-    }
+// Build claim_rewards instruction
+pub fn build_claim_rewards_ix(
+    user: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+) -> Instruction {
+    governance_ix::claim_rewards(user, admin, token_mint)
 }
 
 // Build upvote instruction (without target profile – legacy / partial version)
+// Kept hand-rolled: the client only builds the complete account list
 pub fn build_upvote_ix(
     voter: &Pubkey,
     admin: &Pubkey,
     target_username: &str,
 ) -> Instruction {
-    let (config, _) = derive_config_pda(admin);
-    let (voter_profile, _) = derive_user_profile_pda(voter);
-    let (target_user_registry, _) = derive_username_registry_pda(target_username);
-    let (vote_cooldown, _) = derive_vote_cooldown_pda(voter);
-    let (vote_record, _) = derive_vote_record_pda(voter, target_username);
+    let (config, _) = governance::config_address(admin);
+    let (voter_profile, _) = governance::user_profile_address(voter);
+    let (target_user_registry, _) = governance::username_registry_address(target_username);
+    let (vote_cooldown, _) = governance::vote_cooldown_address(voter);
+    let (vote_record, _) = governance::vote_record_address(voter, target_username);
+    let (vote_history, _) = governance::vote_history_address(voter);
 
     let discriminator = anchor_discriminator("upvote");
 
@@ -292,6 +285,7 @@ pub fn build_upvote_ix(
             // Note: target_user_profile is missing here – this version might be incomplete
             AccountMeta::new(vote_cooldown, false),
             AccountMeta::new(vote_record, false),
+            AccountMeta::new(vote_history, false),
             AccountMeta::new_readonly(system_program, false),
         ],
         data,
@@ -305,34 +299,7 @@ pub fn build_upvote_ix_with_target(
     target_user: &Pubkey,
     target_username: &str,
 ) -> Instruction {
-    let (config, _) = derive_config_pda(admin);
-    let (voter_profile, _) = derive_user_profile_pda(voter);
-    let (target_user_registry, _) = derive_username_registry_pda(target_username);
-    let (target_user_profile, _) = derive_user_profile_pda(target_user);
-    let (vote_cooldown, _) = derive_vote_cooldown_pda(voter);
-    let (vote_record, _) = derive_vote_record_pda(voter, target_username);
-
-    let discriminator = anchor_discriminator("upvote");
-
-    let mut data = discriminator.to_vec();
-    data.extend_from_slice(&(target_username.len() as u32).to_le_bytes());
-    data.extend_from_slice(target_username.as_bytes());
-
-    Instruction {
-        program_id: GOVERNANCE_PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*voter, true),
-            AccountMeta::new_readonly(*admin, false),
-            AccountMeta::new_readonly(config, false),
-            AccountMeta::new(voter_profile, false),
-            AccountMeta::new_readonly(target_user_registry, false),
-            AccountMeta::new(target_user_profile, false),
-            AccountMeta::new(vote_cooldown, false),
-            AccountMeta::new(vote_record, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data,
-    }
+    governance_ix::upvote(voter, admin, target_user, target_username)
 }
 
 // Build downvote instruction
@@ -342,58 +309,420 @@ pub fn build_downvote_ix_with_target(
     target_user: &Pubkey,
     target_username: &str,
 ) -> Instruction {
-    let (config, _) = derive_config_pda(admin);
-    let (voter_profile, _) = derive_user_profile_pda(voter);
-    let (target_user_registry, _) = derive_username_registry_pda(target_username);
-    let (target_user_profile, _) = derive_user_profile_pda(target_user);
-    let (vote_cooldown, _) = derive_vote_cooldown_pda(voter);
-    let (vote_record, _) = derive_vote_record_pda(voter, target_username);
+    governance_ix::downvote(voter, admin, target_user, target_username)
+}
 
-    let discriminator = anchor_discriminator("downvote");
+// Build reset_user_reputation instruction
+// `season` must be the config's current season
+pub fn build_reset_user_reputation_ix(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    user: &Pubkey,
+    season: u64,
+) -> Instruction {
+    governance_ix::reset_user_reputation(authority, admin, user, season)
+}
 
-    let mut data = discriminator.to_vec();
-    data.extend_from_slice(&(target_username.len() as u32).to_le_bytes());
-    data.extend_from_slice(target_username.as_bytes());
+// Build propose_admin instruction
+pub fn build_propose_admin_ix(authority: &Pubkey, admin: &Pubkey, new_admin: &Pubkey) -> Instruction {
+    governance_ix::propose_admin(authority, admin, new_admin)
+}
 
-    Instruction {
-        program_id: GOVERNANCE_PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*voter, true),
-            AccountMeta::new_readonly(*admin, false),
-            AccountMeta::new_readonly(config, false),
-            AccountMeta::new(voter_profile, false),
-            AccountMeta::new_readonly(target_user_registry, false),
-            AccountMeta::new(target_user_profile, false),
-            AccountMeta::new(vote_cooldown, false),
-            AccountMeta::new(vote_record, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data,
-    }
+// Build accept_admin instruction
+pub fn build_accept_admin_ix(new_admin: &Pubkey, admin: &Pubkey) -> Instruction {
+    governance_ix::accept_admin(new_admin, admin)
 }
 
-// Build reset_user_reputation instruction
-pub fn build_reset_user_reputation_ix(
+// Build create_governance_proposal instruction
+pub fn build_create_governance_proposal_ix(
+    proposer: &Pubkey,
     admin: &Pubkey,
+    token_mint: &Pubkey,
+    proposal_id: u64,
+    title: &str,
+) -> Instruction {
+    governance_ix::create_governance_proposal(proposer, admin, token_mint, proposal_id, title)
+}
+
+// Build cast_ballot instruction
+pub fn build_cast_ballot_ix(
+    voter: &Pubkey,
+    admin: &Pubkey,
+    proposal_id: u64,
+    support: bool,
+) -> Instruction {
+    governance_ix::cast_ballot(voter, admin, proposal_id, support)
+}
+
+// Build cast_ballot instruction that also counts the voter's vote escrow
+pub fn build_cast_ballot_with_escrow_ix(
+    voter: &Pubkey,
+    admin: &Pubkey,
+    proposal_id: u64,
+    support: bool,
+) -> Instruction {
+    governance_ix::cast_ballot_with_escrow(voter, admin, proposal_id, support)
+}
+
+// Build lock_tokens instruction
+pub fn build_lock_tokens_ix(
     user: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+    amount: u64,
+    duration: i64,
 ) -> Instruction {
-    let (config, _) = derive_config_pda(admin);
-    let (user_profile, _) = derive_user_profile_pda(user);
+    governance_ix::lock_tokens(user, admin, token_mint, amount, duration)
+}
 
-    let discriminator = anchor_discriminator("reset_user_reputation");
+// Build extend_lock instruction
+pub fn build_extend_lock_ix(user: &Pubkey, admin: &Pubkey, duration: i64) -> Instruction {
+    governance_ix::extend_lock(user, admin, duration)
+}
 
-    let mut data = discriminator.to_vec();
-    data.extend_from_slice(user.as_ref());
+// Build withdraw_expired_lock instruction
+pub fn build_withdraw_expired_lock_ix(user: &Pubkey, admin: &Pubkey, token_mint: &Pubkey) -> Instruction {
+    governance_ix::withdraw_expired_lock(user, admin, token_mint)
+}
 
-    Instruction {
-        program_id: GOVERNANCE_PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*admin, true),
-            AccountMeta::new_readonly(config, false),
-            AccountMeta::new(user_profile, false),
-        ],
-        data,
-    }
+// Build submit_vote_batch instruction
+pub fn build_submit_vote_batch_ix(
+    relayer: &Pubkey,
+    admin: &Pubkey,
+    proposal_id: u64,
+    entries: &[governance_ix::VoteBatchEntry],
+) -> Instruction {
+    governance_ix::submit_vote_batch(relayer, admin, proposal_id, entries)
+}
+
+// Sign a batch entry with the voter's key and wrap it in an Ed25519 instruction
+pub fn build_signed_vote_ix(
+    voter: &Keypair,
+    proposal: &Pubkey,
+    entry: &governance_ix::VoteBatchEntry,
+) -> Instruction {
+    let message = entry.vote_message(proposal);
+    governance_ix::ed25519_verify(&voter.pubkey(), &voter.sign_message(&message), &message)
+}
+
+// Build finalize_proposal instruction
+// proposer receives the settled deposit
+pub fn build_finalize_proposal_ix(
+    caller: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+    proposal_id: u64,
+    proposer: &Pubkey,
+) -> Instruction {
+    governance_ix::finalize_proposal(caller, admin, token_mint, proposal_id, proposer)
+}
+
+// Build attach_multisig_action instruction
+pub fn build_attach_multisig_action_ix(
+    proposer: &Pubkey,
+    admin: &Pubkey,
+    proposal_id: u64,
+    multisig: &Pubkey,
+    action: multisig::ProposalType,
+) -> Instruction {
+    governance_ix::attach_multisig_action(proposer, admin, proposal_id, multisig, action)
+}
+
+// Build execute_multisig_action instruction
+// Targets the multisig's next proposal PDA (its current proposal_count)
+pub fn build_execute_multisig_action_ix(
+    payer: &Pubkey,
+    admin: &Pubkey,
+    proposal_id: u64,
+    multisig: &Pubkey,
+    multisig_proposal_id: u64,
+) -> Instruction {
+    let (multisig_proposal, _) = multisig::proposal_address(multisig, multisig_proposal_id);
+    governance_ix::execute_multisig_action(payer, admin, proposal_id, multisig, &multisig_proposal)
+}
+
+// Build delegate_votes instruction
+pub fn build_delegate_votes_ix(
+    delegator: &Pubkey,
+    admin: &Pubkey,
+    delegate: &Pubkey,
+) -> Instruction {
+    governance_ix::delegate_votes(delegator, admin, delegate)
+}
+
+// Build revoke_delegation instruction
+pub fn build_revoke_delegation_ix(delegator: &Pubkey, delegate: &Pubkey) -> Instruction {
+    governance_ix::revoke_delegation(delegator, delegate)
+}
+
+// Build decay_reputation instruction
+pub fn build_decay_reputation_ix(caller: &Pubkey, admin: &Pubkey, user: &Pubkey) -> Instruction {
+    governance_ix::decay_reputation(caller, admin, user)
+}
+
+// Build change_username instruction
+pub fn build_change_username_ix(user: &Pubkey, old_username: &str, new_username: &str) -> Instruction {
+    governance_ix::change_username(user, old_username, new_username)
+}
+
+// Build close_profile instruction
+pub fn build_close_profile_ix(user: &Pubkey, username: &str) -> Instruction {
+    governance_ix::close_profile(user, username)
+}
+
+// Build update_profile_metadata instruction
+pub fn build_update_profile_metadata_ix(user: &Pubkey, metadata_uri: Option<&str>) -> Instruction {
+    governance_ix::update_profile_metadata(user, metadata_uri)
+}
+
+// Read (capacity, head, total_recorded, entry count) from a vote history account
+pub fn get_vote_history(svm: &LiteSVM, history: &Pubkey) -> (u16, u16, u64, u32) {
+    let account = svm.get_account(history).expect("Vote history should exist");
+    let history = VoteHistory::try_from_bytes(&account.data).expect("Vote history should decode");
+    (
+        history.capacity,
+        history.head,
+        history.total_recorded,
+        history.entries.len() as u32,
+    )
+}
+
+// Build slash_stake instruction
+// Pass the delegate when the slashed user has an active delegation
+pub fn build_slash_stake_ix(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+    user: &Pubkey,
+    slash_bps: u16,
+    burn: bool,
+    delegate: Option<&Pubkey>,
+) -> Instruction {
+    governance_ix::slash_stake(authority, admin, token_mint, user, slash_bps, burn, delegate)
+}
+
+// Build close_season instruction
+// `season` must be the config's current season
+pub fn build_close_season_ix(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    season: u64,
+    reward_pool: u64,
+) -> Instruction {
+    governance_ix::close_season(authority, admin, season, reward_pool)
+}
+
+// Build claim_season_reward instruction
+pub fn build_claim_season_reward_ix(
+    user: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+    season_id: u64,
+) -> Instruction {
+    governance_ix::claim_season_reward(user, admin, token_mint, season_id)
+}
+
+// Build close_vote_record instruction
+pub fn build_close_vote_record_ix(voter: &Pubkey, admin: &Pubkey, target_username: &str) -> Instruction {
+    governance_ix::close_vote_record(voter, admin, target_username)
+}
+
+// Build close_cooldown instruction
+pub fn build_close_cooldown_ix(voter: &Pubkey, admin: &Pubkey) -> Instruction {
+    governance_ix::close_cooldown(voter, admin)
+}
+
+// Build init_council instruction
+pub fn build_init_council_ix(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    members: &[Pubkey],
+    threshold: u8,
+    veto_window: i64,
+) -> Instruction {
+    governance_ix::init_council(authority, admin, members, threshold, veto_window)
+}
+
+// Build council_set_pause instruction
+pub fn build_council_set_pause_ix(
+    member: &Pubkey,
+    admin: &Pubkey,
+    co_signers: &[Pubkey],
+    paused: bool,
+) -> Instruction {
+    governance_ix::council_set_pause(member, admin, co_signers, paused)
+}
+
+// Build council_veto instruction
+pub fn build_council_veto_ix(
+    member: &Pubkey,
+    admin: &Pubkey,
+    proposal_id: u64,
+    co_signers: &[Pubkey],
+) -> Instruction {
+    governance_ix::council_veto(member, admin, proposal_id, co_signers)
+}
+
+// Read the season a vote record was cast in
+pub fn get_vote_record_season(svm: &LiteSVM, vote_record: &Pubkey) -> u64 {
+    let account = svm.get_account(vote_record).expect("Vote record should exist");
+    VoteRecord::try_from_bytes(&account.data)
+        .expect("Vote record should decode")
+        .season
+}
+
+// Read (season_id, total_staked, reward_pool, finalized, entry count) from a season snapshot
+pub fn get_season_snapshot(svm: &LiteSVM, snapshot: &Pubkey) -> (u64, u64, u64, bool, u32) {
+    let account = svm.get_account(snapshot).expect("Season snapshot should exist");
+    let snapshot =
+        SeasonSnapshot::try_from_bytes(&account.data).expect("Season snapshot should decode");
+    (
+        snapshot.season_id,
+        snapshot.total_staked,
+        snapshot.reward_pool,
+        snapshot.finalized,
+        snapshot.entries.len() as u32,
+    )
+}
+
+// Decode a user profile account
+fn get_profile(svm: &LiteSVM, profile: &Pubkey) -> UserProfile {
+    let account = svm.get_account(profile).expect("Profile should exist");
+    UserProfile::try_from_bytes(&account.data).expect("Profile should decode")
+}
+
+// Read stake_amount from a user profile account
+pub fn get_stake_amount(svm: &LiteSVM, profile: &Pubkey) -> u64 {
+    get_profile(svm, profile).stake_amount
+}
+
+// Read weighted_stake (supported-mint voting power) from a user profile account
+pub fn get_weighted_stake(svm: &LiteSVM, profile: &Pubkey) -> u64 {
+    get_profile(svm, profile).weighted_stake
+}
+
+// Read stake_start_ts (when the profile's stake began maturing) from a user profile account
+pub fn get_stake_start_ts(svm: &LiteSVM, profile: &Pubkey) -> i64 {
+    get_profile(svm, profile).stake_start_ts
+}
+
+// Read (weight_bps, total_staked) from a supported mint registry entry
+pub fn get_supported_mint(svm: &LiteSVM, supported_mint: &Pubkey) -> (u16, u64) {
+    let account = svm.get_account(supported_mint).expect("Supported mint should exist");
+    let entry =
+        SupportedMint::try_from_bytes(&account.data).expect("Supported mint should decode");
+    (entry.weight_bps, entry.total_staked)
+}
+
+// Read (amount, voting_power) from a user's supported-mint stake record
+pub fn get_mint_stake(svm: &LiteSVM, mint_stake: &Pubkey) -> (u64, u64) {
+    let account = svm.get_account(mint_stake).expect("Mint stake should exist");
+    let record = MintStake::try_from_bytes(&account.data).expect("Mint stake should decode");
+    (record.amount, record.voting_power)
+}
+
+// Read the current admin from the config account
+pub fn get_config_admin(svm: &LiteSVM, config: &Pubkey) -> Pubkey {
+    let account = svm.get_account(config).expect("Config should exist");
+    Config::try_from_bytes(&account.data).expect("Config should decode").admin
+}
+
+// Read the pause flag from the config account
+pub fn get_config_paused(svm: &LiteSVM, config: &Pubkey) -> bool {
+    let account = svm.get_account(config).expect("Config should exist");
+    Config::try_from_bytes(&account.data).expect("Config should decode").is_paused
+}
+
+// Read the emergency council of a DAO
+pub fn get_council(svm: &LiteSVM, council: &Pubkey) -> Council {
+    let account = svm.get_account(council).expect("Council should exist");
+    Council::try_from_bytes(&account.data).expect("Council should decode")
+}
+
+// Read the username from a user profile account
+pub fn get_username(svm: &LiteSVM, profile: &Pubkey) -> String {
+    get_profile(svm, profile).username
+}
+
+// Read the metadata URI from a user profile account
+pub fn get_metadata_uri(svm: &LiteSVM, profile: &Pubkey) -> Option<String> {
+    get_profile(svm, profile).metadata_uri
+}
+
+// Read reputation_points from a user profile account
+pub fn get_reputation(svm: &LiteSVM, profile: &Pubkey) -> i64 {
+    get_profile(svm, profile).reputation_points
+}
+
+// Overwrite reputation_points on a user profile account
+// Reaching the higher ranks through upvotes takes far more setup than a test needs
+pub fn set_reputation(svm: &mut LiteSVM, profile: &Pubkey, points: i64) {
+    let mut account = svm.get_account(profile).expect("Profile should exist");
+    // discriminator + username (4 byte length prefix) + owner
+    let offset = 8 + 4 + get_profile(svm, profile).username.len() + 32;
+    account.data[offset..offset + 8].copy_from_slice(&points.to_le_bytes());
+    svm.set_account(*profile, account).expect("Profile should be writable");
+}
+
+// Read (votes_for, votes_against, status) from a proposal account
+// Status: 0 = Active, 1 = Passed, 2 = Rejected, 3 = Vetoed
+pub fn get_proposal_tally(svm: &LiteSVM, proposal: &Pubkey) -> (u64, u64, u8) {
+    let account = svm.get_account(proposal).expect("Proposal should exist");
+    let proposal = Proposal::try_from_bytes(&account.data).expect("Proposal should decode");
+    (proposal.votes_for, proposal.votes_against, proposal.status as u8)
+}
+
+// Read the deposit escrowed for a proposal
+pub fn get_proposal_deposit(svm: &LiteSVM, proposal_deposit: &Pubkey) -> ProposalDeposit {
+    let account = svm.get_account(proposal_deposit).expect("Proposal deposit should exist");
+    ProposalDeposit::try_from_bytes(&account.data).expect("Proposal deposit should decode")
+}
+
+// Read a proposal's creation time (the ballot snapshot)
+pub fn get_proposal_created_at(svm: &LiteSVM, proposal: &Pubkey) -> i64 {
+    let account = svm.get_account(proposal).expect("Proposal should exist");
+    Proposal::try_from_bytes(&account.data)
+        .expect("Proposal should decode")
+        .created_at
+}
+
+// Build register_treasury_holding instruction for a feed of the oracle-secure program
+pub fn build_register_treasury_holding_ix(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    token_mint: &Pubkey,
+    price_feed: &Pubkey,
+) -> Instruction {
+    governance_ix::register_treasury_holding(
+        authority,
+        admin,
+        token_mint,
+        &ORACLE_PROGRAM_ID,
+        price_feed,
+    )
+}
+
+// Build treasury_value_report instruction; holdings are (mint, price_feed) in index order
+pub fn build_treasury_value_report_ix(admin: &Pubkey, holdings: &[(Pubkey, Pubkey)]) -> Instruction {
+    governance_ix::treasury_value_report(admin, holdings)
+}
+
+// Read a treasury holding
+pub fn get_treasury_holding(svm: &LiteSVM, treasury_holding: &Pubkey) -> TreasuryHolding {
+    let account = svm.get_account(treasury_holding).expect("Treasury holding should exist");
+    TreasuryHolding::try_from_bytes(&account.data).expect("Treasury holding should decode")
+}
+
+// Read a user's vote escrow
+pub fn get_vote_escrow(svm: &LiteSVM, vote_escrow: &Pubkey) -> VoteEscrow {
+    let account = svm.get_account(vote_escrow).expect("Vote escrow should exist");
+    VoteEscrow::try_from_bytes(&account.data).expect("Vote escrow should decode")
+}
+
+// Read the multisig action attached to a proposal
+pub fn get_multisig_action(svm: &LiteSVM, multisig_action: &Pubkey) -> MultisigAction {
+    let account = svm.get_account(multisig_action).expect("Multisig action should exist");
+    MultisigAction::try_from_bytes(&account.data).expect("Multisig action should decode")
 }
 
 // Advance the SVM clock by the specified number of seconds
@@ -404,4 +733,64 @@ pub fn advance_time(svm: &mut LiteSVM, seconds: u64) {
 
     let current_slot = clock.slot;
     svm.warp_to_slot(current_slot + (seconds * 2) + 5);
-}
\ No newline at end of file
+}
+
+// ORACLE HELPERS
+// Feeds are built by hand against the oracle-secure instruction layout
+
+// Derive an oracle feed PDA: [b"feed", authority, seed]
+pub fn derive_price_feed(authority: &Pubkey, seed: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"feed", authority.as_ref(), &seed.to_le_bytes()],
+        &ORACLE_PROGRAM_ID,
+    )
+    .0
+}
+
+// Build oracle initialize_feed instruction with the authority as its only publisher
+pub fn build_initialize_feed_ix(
+    authority: &Pubkey,
+    seed: u64,
+    exponent: i32,
+    max_staleness_seconds: i64,
+    max_confidence_bps: u16,
+) -> Instruction {
+    let mut data = anchor_discriminator("initialize_feed").to_vec();
+    data.extend_from_slice(&seed.to_le_bytes());
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(authority.as_ref());
+    data.extend_from_slice(&exponent.to_le_bytes());
+    data.extend_from_slice(&max_staleness_seconds.to_le_bytes());
+    data.extend_from_slice(&max_confidence_bps.to_le_bytes());
+
+    Instruction {
+        program_id: ORACLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(derive_price_feed(authority, seed), false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build oracle push_price instruction
+pub fn build_push_price_ix(
+    publisher: &Pubkey,
+    price_feed: &Pubkey,
+    price: u64,
+    confidence: u64,
+) -> Instruction {
+    let mut data = anchor_discriminator("push_price").to_vec();
+    data.extend_from_slice(&price.to_le_bytes());
+    data.extend_from_slice(&confidence.to_le_bytes());
+
+    Instruction {
+        program_id: ORACLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*publisher, true),
+            AccountMeta::new(*price_feed, false),
+        ],
+        data,
+    }
+}
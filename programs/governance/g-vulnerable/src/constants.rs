@@ -9,6 +9,16 @@ pub const USERPROFILE: &[u8] = b"user_profile";
 pub const USER_REGISTRY: &[u8] = b"user_registry";
 pub const VOTE_COOLDOWN: &[u8] = b"cooldown";
 pub const VOTE_RECORD: &[u8] = b"vote_record";
+pub const PROPOSAL: &[u8] = b"proposal";
+pub const BALLOT: &[u8] = b"ballot";
+pub const UNBONDING_TICKET: &[u8] = b"unbonding";
+pub const DELEGATION: &[u8] = b"delegation";
+pub const VOTE_HISTORY: &[u8] = b"vote_history";
+pub const SEASON_SNAPSHOT: &[u8] = b"season_snapshot";
+pub const SUPPORTED_MINT: &[u8] = b"supported_mint";
+pub const MINT_STAKE: &[u8] = b"mint_stake";
+pub const COUNCIL: &[u8] = b"council";
+pub const VOTE_ESCROW: &[u8] = b"vote_escrow";
 
 // Account Space Constants
 pub const ANCHOR_DISCRIMINATOR: usize = 8;
@@ -23,8 +33,50 @@ pub const ANCHOR_DISCRIMINATOR: usize = 8;
 pub const MIN_USERNAME_LENGTH: usize = 1;
 pub const MAX_USERNAME_LENGTH: usize = 32;
 
+// Profile Metadata URI
+//
+// VULNERABILITY: Only used to size the profile account
+// update_profile_metadata never checks it, nor the URI scheme
+pub const MAX_METADATA_URI_LENGTH: usize = 200;
+
 // Reputation System
 //
 // VULNERABILITY: No reputation floor
 // Missing REPUTATION_FLOOR constant allows unlimited downvoting
 // Users can be griefed to i64::MIN reputation with no recovery path
+
+// Proposal Constraints
+pub const MAX_PROPOSAL_TITLE_LENGTH: usize = 64;
+pub const PROPOSAL_VOTING_PERIOD_SECONDS: i64 = 3 * 24 * 3600;
+pub const MAX_BASIS_POINTS: u64 = 10_000;
+
+// Reward Emissions
+pub const REWARD_RATE_SCALE: u64 = 1_000_000_000;
+
+// Vote History
+pub const VOTE_HISTORY_INITIAL_CAPACITY: usize = 4;
+
+// Season Standings
+pub const SEASON_TOP_N: usize = 10;
+
+// Supported Mints
+pub const MAX_MINT_WEIGHT_BPS: u64 = 50_000;
+
+// Vote Batches
+pub const MAX_VOTE_BATCH_SIZE: usize = 8;
+pub const VOTE_MESSAGE_LEN: usize = 41;
+pub const ED25519_OFFSETS_START: usize = 2;
+pub const ED25519_OFFSETS_SIZE: usize = 14;
+
+// Emergency Council
+pub const MAX_COUNCIL_MEMBERS: usize = 7;
+pub const MAX_VETO_WINDOW_SECONDS: i64 = 30 * 24 * 3600;
+
+// Vote Escrow
+pub const MIN_LOCK_SECONDS: i64 = 7 * 24 * 3600;
+pub const MAX_LOCK_SECONDS: i64 = 4 * 365 * 24 * 3600;
+
+// VULNERABILITY: Assumed slot length used to turn slots into seconds
+// Real slot times vary and skipped slots stretch them, so any fixed
+// conversion drifts away from the unix clock
+pub const SLOT_DURATION_MS: u64 = 400;
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ProposalStatus;
+
+// Stake Slashed Event
+//
+// Emitted by slash_stake so off-chain monitors can audit penalties
+// amount is the number of base units removed from the user's stake
+#[event]
+pub struct StakeSlashed {
+    pub user: Pubkey,
+    pub slashed_by: Pubkey,
+    pub slash_bps: u16,
+    pub amount: u64,
+    pub remaining_stake: u64,
+    pub burned: bool,
+    pub timestamp: i64,
+}
+
+// Council Initialized Event
+//
+// Emitted by init_council with the emergency keys and their threshold
+#[event]
+pub struct CouncilInitialized {
+    pub config: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub veto_window: i64,
+    pub timestamp: i64,
+}
+
+// Council Pause Set Event
+//
+// Emitted by council_set_pause; signers are the council members counted
+#[event]
+pub struct CouncilPauseSet {
+    pub config: Pubkey,
+    pub paused: bool,
+    pub signers: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+// Proposal Vetoed Event
+//
+// Emitted by council_veto; previous_status is Active or Passed
+#[event]
+pub struct ProposalVetoed {
+    pub config: Pubkey,
+    pub proposal: Pubkey,
+    pub previous_status: ProposalStatus,
+    pub signers: Vec<Pubkey>,
+    pub timestamp: i64,
+}
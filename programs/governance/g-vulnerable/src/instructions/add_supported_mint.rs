@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Add Supported Mint Instruction
+//
+// VULNERABILITY SUMMARY:
+// - No admin check (any signer can register a mint and choose its weight)
+
+#[derive(Accounts)]
+pub struct AddSupportedMint<'info> {
+    // VULNERABILITY: Any signer is accepted
+    // Missing: constraint = config.admin == authority.key()
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Used for config and treasury PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [TREASURY, admin.key().as_ref()],
+        bump = treasury.state_bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [TREASURYAUTH, config.key().as_ref(), admin.key().as_ref()],
+        bump = treasury.vault_bump,
+    )]
+    /// CHECK: PDA authority for treasury token accounts
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = token_mint_account.key() != config.token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub token_mint_account: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + SupportedMint::INIT_SPACE,
+        seeds = [SUPPORTED_MINT, config.key().as_ref(), token_mint_account.key().as_ref()],
+        bump
+    )]
+    pub supported_mint: Account<'info, SupportedMint>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = treasury_authority,
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddSupportedMint<'info> {
+    pub fn add_supported_mint(
+        &mut self,
+        weight_bps: u16,
+        bumps: AddSupportedMintBumps,
+    ) -> Result<()> {
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        // VULNERABILITY 1: No admin authorization
+        // Missing: require!(self.config.admin == self.authority.key(), GovernanceError::UnauthorizedAdmin);
+        // An attacker registers a mint they control at the maximum weight,
+        // mints themselves any amount and stakes it for unlimited voting power
+        require!(
+            weight_bps > 0 && weight_bps as u64 <= MAX_MINT_WEIGHT_BPS,
+            GovernanceError::InvalidMintWeight
+        );
+
+        self.supported_mint.set_inner(SupportedMint {
+            config: self.config.key(),
+            mint: self.token_mint_account.key(),
+            weight_bps,
+            treasury_token_account: self.treasury_token_account.key(),
+            total_staked: 0,
+            bump: bumps.supported_mint,
+        });
+
+        Ok(())
+    }
+}
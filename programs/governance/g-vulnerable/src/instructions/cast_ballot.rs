@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Cast Ballot Instruction
+//
+// VULNERABILITY SUMMARY:
+// - No double-vote protection (ballot uses init_if_needed, prior ballot ignored)
+// - No stake snapshot (stake added after proposal creation counts)
+// - Unchecked arithmetic on tallies
+// - Weight counts delegated power that may already be counted elsewhere
+// - Vote escrow power decayed by slot count, so expired locks still vote
+
+#[derive(Accounts)]
+pub struct CastBallot<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [USERPROFILE, voter.key().as_ref()],
+        bump,
+        constraint = voter_profile.owner == voter.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub voter_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL, config.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    // VULNERABILITY: init_if_needed on the ballot record
+    // An existing ballot is silently reused instead of rejecting the second vote
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = ANCHOR_DISCRIMINATOR + Ballot::INIT_SPACE,
+        seeds = [BALLOT, proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub ballot: Account<'info, Ballot>,
+
+    #[account(
+        seeds = [VOTE_ESCROW, config.key().as_ref(), voter.key().as_ref()],
+        bump = vote_escrow.bump,
+    )]
+    pub vote_escrow: Option<Account<'info, VoteEscrow>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CastBallot<'info> {
+    pub fn cast_ballot(&mut self, support: bool, bumps: CastBallotBumps) -> Result<()> {
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        require!(
+            self.proposal.status == ProposalStatus::Active,
+            GovernanceError::ProposalNotActive
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time < self.proposal.voting_ends_at,
+            GovernanceError::VotingPeriodEnded
+        );
+
+        // VULNERABILITY 4: Effective power trusts delegated_stake blindly
+        // Circular delegations inflate delegated_stake (see delegate_votes.rs)
+        let mut weight = self.voter_profile.effective_power();
+
+        // VULNERABILITY 5: Escrow power measured on the slot clock
+        // voting_power estimates elapsed time from slots, so a lock past its
+        // unlock_at still counts; no snapshot check on when the lock was made either
+        // Should be: vote_escrow.voting_power(self.proposal.created_at) in unix time
+        if let Some(vote_escrow) = self.vote_escrow.as_ref() {
+            weight = weight + vote_escrow.voting_power(Clock::get()?.slot);
+        }
+        require!(
+            weight >= self.config.minimum_stake,
+            GovernanceError::InsufficientStake
+        );
+
+        // VULNERABILITY 1: No double-vote check
+        // Missing: require!(self.ballot.voter == Pubkey::default(), ...)
+        // A voter who already cast a ballot can call again and add their stake twice
+
+        // VULNERABILITY 2: No stake snapshot check
+        // Missing: require!(self.voter_profile.last_stake_timestamp < self.proposal.created_at, ...)
+        // Tokens can be voted, unstaked, restaked from another profile and voted again
+
+        // VULNERABILITY 3: Unchecked arithmetic on tallies
+        let proposal = &mut self.proposal;
+        if support {
+            proposal.votes_for = proposal.votes_for + weight;
+        } else {
+            proposal.votes_against = proposal.votes_against + weight;
+        }
+
+        self.ballot.set_inner(Ballot {
+            voter: self.voter.key(),
+            proposal: self.proposal.key(),
+            support,
+            weight,
+            timestamp: current_time,
+            bump: bumps.ballot,
+        });
+
+        Ok(())
+    }
+}
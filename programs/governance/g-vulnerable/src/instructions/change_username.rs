@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Change Username Instruction
+//
+// VULNERABILITY SUMMARY:
+// - Old registry not derived from the profile's username
+// - No owner check on the registry being freed
+// - Any user can close another user's registry and claim the name
+
+#[derive(Accounts)]
+#[instruction(new_username: String)]
+pub struct ChangeUsername<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USERPROFILE, user.key().as_ref()],
+        bump,
+        constraint = user_profile.owner == user.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    // VULNERABILITY: Any registry account is accepted
+    // Missing: seeds = [USER_REGISTRY, user_profile.username.as_bytes()]
+    // Missing: constraint = old_registry.owner == user.key()
+    #[account(
+        mut,
+        close = user,
+    )]
+    pub old_registry: Account<'info, UsernameRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR + UsernameRegistry::INIT_SPACE,
+        seeds = [USER_REGISTRY, new_username.as_bytes()],
+        bump
+    )]
+    pub new_registry: Account<'info, UsernameRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ChangeUsername<'info> {
+    pub fn change_username(
+        &mut self,
+        new_username: String,
+        bumps: ChangeUsernameBumps,
+    ) -> Result<()> {
+        require!(
+            new_username.len() >= MIN_USERNAME_LENGTH && new_username.len() <= MAX_USERNAME_LENGTH,
+            GovernanceError::InvalidUsername
+        );
+
+        if self.new_registry.claimed {
+            return err!(GovernanceError::UsernameAlreadyExists);
+        }
+        self.new_registry.claimed = true;
+        self.new_registry.owner = self.user.key();
+        self.new_registry.bump = bumps.new_registry;
+
+        // VULNERABILITY 1: Registry ownership never verified
+        // Missing: require!(self.old_registry.owner == self.user.key(), ...)
+        // The victim's registry is closed, its rent goes to the caller,
+        // and the victim's username can then be claimed by anyone
+        self.old_registry.claimed = false;
+
+        self.user_profile.username = new_username;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Claim Rewards Instruction
+//
+// VULNERABILITY SUMMARY:
+// - Reward product computed in u64 (overflow for large stakes or long periods)
+// - No reward reserve check (rewards can be paid out of staked principal)
+
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Used for config and treasury PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [TREASURY, admin.key().as_ref()],
+        bump = treasury.state_bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [TREASURYAUTH, config.key().as_ref(), admin.key().as_ref()],
+        bump = treasury.vault_bump,
+    )]
+    /// CHECK: PDA authority for signing treasury transfers
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [USERPROFILE, user.key().as_ref()],
+        bump,
+        constraint = user_profile.owner == user.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        address = config.token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub token_mint_account: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = treasury.treasury_token_account @ GovernanceError::InvalidTreasuryAccount
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimRewards<'info> {
+    pub fn claim_rewards(&mut self) -> Result<()> {
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        // VULNERABILITY 1: Per-second reward multiplication in u64
+        // stake_amount * reward_rate_per_sec * elapsed overflows for large
+        // stakes; the claim aborts and the rewards can never be collected
+        let current_time = Clock::get()?.unix_timestamp;
+        self.user_profile.accrue_rewards(self.config.reward_rate_per_sec, current_time);
+
+        let amount = self.user_profile.reward_debt;
+        require!(amount > 0, GovernanceError::NoRewardsToClaim);
+
+        // VULNERABILITY 2: No reward reserve check
+        // Missing: require!(balance - total_staked - total_unbonding >= amount, ...)
+        // Rewards are paid from whatever the treasury holds, including principal
+        require!(
+            self.treasury_token_account.amount >= amount,
+            GovernanceError::InsufficientTreasuryBalance
+        );
+
+        let config = self.config.key();
+        let admin = self.admin.key();
+        let treasury_auth_seeds = &[
+            TREASURYAUTH,
+            config.as_ref(),
+            admin.as_ref(),
+            &[self.treasury.vault_bump],
+        ];
+        let signer_seeds = &[&treasury_auth_seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.treasury_token_account.to_account_info(),
+                to: self.user_token_account.to_account_info(),
+                authority: self.treasury_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, amount)?;
+
+        self.user_profile.reward_debt = 0;
+
+        Ok(())
+    }
+}
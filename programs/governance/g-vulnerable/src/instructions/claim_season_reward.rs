@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Claim Season Reward Instruction
+//
+// VULNERABILITY SUMMARY:
+// - Claimed flag never checked (same entry can claim repeatedly)
+// - No reward reserve check (season rewards can be paid out of staked principal)
+// - Unchecked share arithmetic
+
+#[derive(Accounts)]
+#[instruction(season_id: u64)]
+pub struct ClaimSeasonReward<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Used for config and treasury PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [TREASURY, admin.key().as_ref()],
+        bump = treasury.state_bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [TREASURYAUTH, config.key().as_ref(), admin.key().as_ref()],
+        bump = treasury.vault_bump,
+    )]
+    /// CHECK: PDA authority for signing treasury transfers
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_SNAPSHOT, config.key().as_ref(), &season_id.to_le_bytes()],
+        bump = season_snapshot.bump
+    )]
+    pub season_snapshot: Account<'info, SeasonSnapshot>,
+
+    #[account(
+        address = config.token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub token_mint_account: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = treasury.treasury_token_account @ GovernanceError::InvalidTreasuryAccount
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimSeasonReward<'info> {
+    pub fn claim_season_reward(&mut self, season_id: u64) -> Result<()> {
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        require!(
+            self.season_snapshot.finalized,
+            GovernanceError::SeasonNotFinalized
+        );
+
+        let user = self.user.key();
+        let index = self
+            .season_snapshot
+            .entries
+            .iter()
+            .position(|e| e.user == user)
+            .ok_or(GovernanceError::NotInSeasonStandings)?;
+
+        // VULNERABILITY 1: Claimed flag is never checked
+        // Missing: require!(!self.season_snapshot.entries[index].claimed, ...)
+        // Every call pays the full share again until the treasury is empty
+
+        // VULNERABILITY 2: Unchecked share arithmetic (see season_snapshot.rs)
+        let amount = self.season_snapshot.reward_for(index);
+        require!(amount > 0, GovernanceError::NoRewardsToClaim);
+
+        // VULNERABILITY 3: No reward reserve check
+        // Missing: require!(balance - total_staked - total_unbonding >= amount, ...)
+        // Repeated claims drain staked principal once the pool is gone
+        require!(
+            self.treasury_token_account.amount >= amount,
+            GovernanceError::InsufficientTreasuryBalance
+        );
+
+        let config = self.config.key();
+        let admin = self.admin.key();
+        let treasury_auth_seeds = &[
+            TREASURYAUTH,
+            config.as_ref(),
+            admin.as_ref(),
+            &[self.treasury.vault_bump],
+        ];
+        let signer_seeds = &[&treasury_auth_seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.treasury_token_account.to_account_info(),
+                to: self.user_token_account.to_account_info(),
+                authority: self.treasury_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, amount)?;
+
+        self.season_snapshot.entries[index].claimed = true;
+
+        msg!("Season {} reward claimed: {} -> {}", season_id, amount, user);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Claim Unbonded Instruction
+//
+// VULNERABILITY SUMMARY:
+// - No unlock time check (unbonding period never enforced)
+
+#[derive(Accounts)]
+pub struct ClaimUnbonded<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Used for config and treasury PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY, admin.key().as_ref()],
+        bump = treasury.state_bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [TREASURYAUTH, config.key().as_ref(), admin.key().as_ref()],
+        bump = treasury.vault_bump,
+    )]
+    /// CHECK: PDA authority for signing treasury transfers
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [UNBONDING_TICKET, user.key().as_ref()],
+        bump = unbonding_ticket.bump,
+        constraint = unbonding_ticket.owner == user.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub unbonding_ticket: Account<'info, UnbondingTicket>,
+
+    #[account(
+        address = config.token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub token_mint_account: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = treasury.treasury_token_account @ GovernanceError::InvalidTreasuryAccount
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimUnbonded<'info> {
+    pub fn claim_unbonded(&mut self) -> Result<()> {
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        let amount = self.unbonding_ticket.amount;
+        require!(amount > 0, GovernanceError::NothingToClaim);
+
+        // VULNERABILITY 1: No unlock time check
+        // Missing: require!(current_time >= self.unbonding_ticket.unlock_at, ...)
+        // Users can stake, vote, unstake and withdraw within a single block,
+        // then restake the same tokens from another wallet and vote again
+
+        require!(
+            self.treasury_token_account.amount >= amount,
+            GovernanceError::InsufficientTreasuryBalance
+        );
+
+        let config = self.config.key();
+        let admin = self.admin.key();
+        let treasury_auth_seeds = &[
+            TREASURYAUTH,
+            config.as_ref(),
+            admin.as_ref(),
+            &[self.treasury.vault_bump],
+        ];
+        let signer_seeds = &[&treasury_auth_seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.treasury_token_account.to_account_info(),
+                to: self.user_token_account.to_account_info(),
+                authority: self.treasury_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, amount)?;
+
+        self.unbonding_ticket.amount = 0;
+        self.treasury.total_unbonding = self.treasury.total_unbonding - amount;
+
+        Ok(())
+    }
+}
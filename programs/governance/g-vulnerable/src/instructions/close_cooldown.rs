@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Close Cooldown Instruction
+//
+// VULNERABILITY SUMMARY:
+// - No season check (tracker closes mid-season)
+// - No elapsed cooldown check (closing resets a running cooldown)
+
+#[derive(Accounts)]
+pub struct CloseCooldown<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = voter,
+        seeds = [VOTE_COOLDOWN, voter.key().as_ref()],
+        bump = vote_cooldown.bump,
+        constraint = vote_cooldown.voter == voter.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub vote_cooldown: Account<'info, VoteCooldown>,
+}
+
+impl<'info> CloseCooldown<'info> {
+    pub fn close_cooldown(&mut self) -> Result<()> {
+        // VULNERABILITY 1: No season rollover check
+        // Missing: require!(self.vote_cooldown.season < self.config.current_season, ...)
+
+        // VULNERABILITY 2: No elapsed cooldown check
+        // Missing: require!(now >= last_vote_timestamp + longest cooldown, GovernanceError::VoteCooldownActive);
+
+        Ok(())
+    }
+}
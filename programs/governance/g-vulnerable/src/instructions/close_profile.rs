@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Close Profile Instruction
+//
+// VULNERABILITY SUMMARY:
+// - No zero-stake check (staked tokens orphaned in the treasury)
+// - No delegation check (delegate keeps unbacked power)
+// - Registry not derived from the profile or owner-checked
+
+#[derive(Accounts)]
+pub struct CloseProfile<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [USERPROFILE, user.key().as_ref()],
+        bump,
+        constraint = user_profile.owner == user.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    // VULNERABILITY: Any registry account is accepted
+    // Missing: seeds = [USER_REGISTRY, user_profile.username.as_bytes()]
+    #[account(
+        mut,
+        close = user,
+    )]
+    pub user_registry: Account<'info, UsernameRegistry>,
+}
+
+impl<'info> CloseProfile<'info> {
+    pub fn close_profile(&mut self) -> Result<()> {
+        // VULNERABILITY 1: No zero-stake check
+        // Missing: require!(self.user_profile.stake_amount == 0, GovernanceError::ProfileHasStake);
+
+        // VULNERABILITY 2: No delegation check
+        // Missing: require!(self.user_profile.delegated_to == Pubkey::default() && ...)
+
+        self.user_registry.claimed = false;
+
+        Ok(())
+    }
+}
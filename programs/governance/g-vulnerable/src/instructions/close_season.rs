@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Close Season Instruction
+//
+// Admin-only operation that finalizes the current season's snapshot
+// Records total stake and the reward pool, then opens the next season
+// NOTE: The logic matches the secure version; the weakness is in
+// claim_season_reward.rs
+
+#[derive(Accounts)]
+pub struct CloseSeason<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Used for config and treasury PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump = config.config_bump,
+        constraint = config.admin == authority.key() @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [TREASURY, admin.key().as_ref()],
+        bump = treasury.state_bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + SeasonSnapshot::INIT_SPACE,
+        seeds = [SEASON_SNAPSHOT, config.key().as_ref(), &config.current_season.to_le_bytes()],
+        bump
+    )]
+    pub season_snapshot: Account<'info, SeasonSnapshot>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseSeason<'info> {
+    pub fn close_season(&mut self, reward_pool: u64, bumps: CloseSeasonBumps) -> Result<()> {
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        let snapshot = &mut self.season_snapshot;
+        require!(!snapshot.finalized, GovernanceError::SeasonAlreadyFinalized);
+
+        if snapshot.config == Pubkey::default() {
+            snapshot.config = self.config.key();
+            snapshot.season_id = self.config.current_season;
+            snapshot.bump = bumps.season_snapshot;
+        }
+
+        snapshot.total_staked = self.treasury.total_staked;
+        snapshot.reward_pool = reward_pool;
+        snapshot.finalized = true;
+
+        self.config.current_season = self.config.current_season + 1;
+
+        msg!(
+            "Season {} closed: {} ranked, {} staked, {} reward pool",
+            snapshot.season_id,
+            snapshot.entries.len(),
+            snapshot.total_staked,
+            reward_pool
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Close Vote Record Instruction
+//
+// VULNERABILITY SUMMARY:
+// - No season check (records close while their vote still counts)
+// - Records carry no season, so there is nothing to check against config.current_season
+
+#[derive(Accounts)]
+#[instruction(target_username: String)]
+pub struct CloseVoteRecord<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = voter,
+        seeds = [VOTE_RECORD, voter.key().as_ref(), target_username.as_bytes()],
+        bump = vote_record.bump,
+        constraint = vote_record.voter == voter.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+}
+
+impl<'info> CloseVoteRecord<'info> {
+    pub fn close_vote_record(&mut self, _target_username: String) -> Result<()> {
+        // VULNERABILITY: No season rollover check
+        // Missing: require!(self.vote_record.season < self.config.current_season,
+        //                   GovernanceError::VoteSeasonNotOver);
+        // The record is the only thing stopping a second vote on the same user
+        // (vote_record uses init), so closing it lets the voter vote again and
+        // the reputation from the first vote is never reversed
+
+        Ok(())
+    }
+}
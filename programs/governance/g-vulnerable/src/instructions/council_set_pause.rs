@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, events::*, state::*};
+
+// Council Set Pause Instruction
+//
+// VULNERABILITY SUMMARY:
+// - council.threshold is never checked
+// - Any single council key can freeze (or unfreeze) the whole DAO
+
+#[derive(Accounts)]
+pub struct CouncilSetPause<'info> {
+    pub member: Signer<'info>,
+
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [COUNCIL, config.key().as_ref()],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, Council>,
+}
+
+impl<'info> CouncilSetPause<'info> {
+    pub fn council_set_pause(&mut self, paused: bool) -> Result<()> {
+        require!(
+            self.council.is_member(&self.member.key()),
+            GovernanceError::NotCouncilMember
+        );
+
+        // VULNERABILITY: One member acts for the whole council
+        // Missing: count distinct signing members (caller + remaining accounts)
+        // require!(signers.len() >= self.council.threshold as usize, ...)
+
+        self.config.is_paused = paused;
+
+        emit!(CouncilPauseSet {
+            config: self.config.key(),
+            paused,
+            signers: vec![self.member.key()],
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, events::*, state::*};
+
+// Council Veto Instruction
+//
+// VULNERABILITY SUMMARY:
+// - council.threshold is never checked
+// - Any single council key can veto a proposal the DAO voted to pass
+
+#[derive(Accounts)]
+pub struct CouncilVeto<'info> {
+    pub member: Signer<'info>,
+
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [COUNCIL, config.key().as_ref()],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL, config.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+impl<'info> CouncilVeto<'info> {
+    pub fn council_veto(&mut self) -> Result<()> {
+        require!(
+            self.council.is_member(&self.member.key()),
+            GovernanceError::NotCouncilMember
+        );
+
+        // VULNERABILITY: One member acts for the whole council
+        // Missing: count distinct signing members (caller + remaining accounts)
+        // require!(signers.len() >= self.council.threshold as usize, ...)
+
+        require!(
+            matches!(
+                self.proposal.status,
+                ProposalStatus::Active | ProposalStatus::Passed
+            ),
+            GovernanceError::ProposalNotActive
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let deadline = self
+            .proposal
+            .voting_ends_at
+            .checked_add(self.council.veto_window)
+            .ok_or(GovernanceError::MathOverflow)?;
+        require!(current_time < deadline, GovernanceError::VetoWindowClosed);
+
+        let previous_status = self.proposal.status;
+        self.proposal.status = ProposalStatus::Vetoed;
+
+        emit!(ProposalVetoed {
+            config: self.config.key(),
+            proposal: self.proposal.key(),
+            previous_status,
+            signers: vec![self.member.key()],
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+}
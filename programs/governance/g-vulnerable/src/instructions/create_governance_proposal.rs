@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Create Governance Proposal Instruction
+//
+// Opens a new DAO proposal for stake-weighted voting
+// Each proposal gets its own PDA derived from the config's proposal counter
+
+#[derive(Accounts)]
+pub struct CreateGovernanceProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [USERPROFILE, proposer.key().as_ref()],
+        bump,
+        constraint = proposer_profile.owner == proposer.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub proposer_profile: Account<'info, UserProfile>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = ANCHOR_DISCRIMINATOR + Proposal::INIT_SPACE,
+        seeds = [PROPOSAL, config.key().as_ref(), &config.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateGovernanceProposal<'info> {
+    pub fn create_proposal(
+        &mut self,
+        title: String,
+        bumps: CreateGovernanceProposalBumps,
+    ) -> Result<()> {
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        require!(
+            !title.is_empty() && title.len() <= MAX_PROPOSAL_TITLE_LENGTH,
+            GovernanceError::InvalidProposalTitle
+        );
+
+        require!(
+            self.proposer_profile.effective_power() >= self.config.minimum_stake,
+            GovernanceError::InsufficientStake
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+
+        self.proposal.set_inner(Proposal {
+            config: self.config.key(),
+            proposer: self.proposer.key(),
+            proposal_id: self.config.proposal_count,
+            title,
+            votes_for: 0,
+            votes_against: 0,
+            created_at: current_time,
+            voting_ends_at: current_time + PROPOSAL_VOTING_PERIOD_SECONDS,
+            status: ProposalStatus::Active,
+            bump: bumps.proposal,
+        });
+
+        self.config.proposal_count = self.config.proposal_count + 1;
+
+        Ok(())
+    }
+}
@@ -31,7 +31,7 @@ pub struct CreateProfile<'info> {
     #[account(
         init,
         payer = user,
-        space = ANCHOR_DISCRIMINATOR + UserProfile::INIT_SPACE,
+        space = UserProfile::space(None),
         seeds = [USERPROFILE, user.key().as_ref()],
         bump
     )]
@@ -80,6 +80,15 @@ impl<'info> CreateProfile<'info> {
             total_votes_cast: 0,
             last_vote_timestamp: 0,
             created_at: Clock::get()?.unix_timestamp,
+            last_stake_timestamp: 0,
+            delegated_to: Pubkey::default(),
+            delegated_stake: 0,
+            last_decay_ts: Clock::get()?.unix_timestamp,
+            reward_debt: 0,
+            last_reward_ts: 0,
+            weighted_stake: 0,
+            stake_start_ts: 0,
+            metadata_uri: None,
         });
 
         Ok(())
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, state::*};
+
+// Decay Reputation Instruction
+//
+// VULNERABILITY SUMMARY:
+// - No elapsed-epoch check (every call applies a full epoch of decay)
+// - Decay clock reset to now instead of advancing by elapsed epochs
+// - No system pause check
+// - Unchecked arithmetic
+
+#[derive(Accounts)]
+pub struct DecayReputation<'info> {
+    pub caller: Signer<'info>,
+
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [USERPROFILE, user_profile.owner.as_ref()],
+        bump,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+}
+
+impl<'info> DecayReputation<'info> {
+    pub fn decay_reputation(&mut self) -> Result<()> {
+        // VULNERABILITY 1: No system pause check
+        // Missing: require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        // VULNERABILITY 2: No elapsed-epoch check
+        // Missing: let epochs = (now - last_decay_ts) / config.decay_epoch_seconds;
+        // Missing: require!(epochs > 0, GovernanceError::DecayNotDue);
+        // Anyone can call this repeatedly in the same block and grind a
+        // victim's reputation down to zero
+        let current_time = Clock::get()?.unix_timestamp;
+        let profile = &mut self.user_profile;
+
+        if profile.reputation_points > 0 {
+            // VULNERABILITY 3: Unchecked arithmetic
+            let decay = (profile.reputation_points as i128 * self.config.decay_rate_bps as i128
+                + MAX_BASIS_POINTS as i128
+                - 1)
+                / MAX_BASIS_POINTS as i128;
+            profile.reputation_points = profile.reputation_points - decay as i64;
+        }
+
+        // VULNERABILITY 4: Decay clock reset to now
+        // Should advance by whole epochs so partial epochs are not lost
+        profile.last_decay_ts = current_time;
+
+        profile.role_level = MemberRanks::from_reputation(profile.reputation_points);
+
+        Ok(())
+    }
+}
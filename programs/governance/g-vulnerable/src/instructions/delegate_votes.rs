@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Delegate Votes Instruction
+//
+// VULNERABILITY SUMMARY:
+// - No delegation chain or cycle check (A -> B -> A is accepted)
+// - Delegated amount includes power the delegator received from others
+// - Delegate's stake timestamp not reset (borrowed power votes on open proposals)
+// - Unchecked arithmetic
+
+#[derive(Accounts)]
+pub struct DelegateVotes<'info> {
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [USERPROFILE, delegator.key().as_ref()],
+        bump,
+        constraint = delegator_profile.owner == delegator.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub delegator_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        seeds = [USERPROFILE, delegate_profile.owner.as_ref()],
+        bump,
+    )]
+    pub delegate_profile: Account<'info, UserProfile>,
+
+    #[account(
+        init,
+        payer = delegator,
+        space = ANCHOR_DISCRIMINATOR + Delegation::INIT_SPACE,
+        seeds = [DELEGATION, delegator.key().as_ref()],
+        bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DelegateVotes<'info> {
+    pub fn delegate_votes(&mut self, bumps: DelegateVotesBumps) -> Result<()> {
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        require!(
+            self.delegate_profile.owner != self.delegator.key(),
+            GovernanceError::CannotDelegateToSelf
+        );
+
+        // VULNERABILITY 1: Delegated amount includes incoming delegations
+        // Should be: self.delegator_profile.own_power()
+        // Power received from others is passed on while still counted by the
+        // profiles it came from
+        let amount = self.delegator_profile.own_power() + self.delegator_profile.delegated_stake;
+        require!(
+            amount >= self.config.minimum_stake,
+            GovernanceError::InsufficientStake
+        );
+
+        // VULNERABILITY 2: No chain or cycle check
+        // Missing: require!(self.delegate_profile.delegated_to == Pubkey::default(), ...)
+        // Missing: require!(self.delegator_profile.delegated_stake == 0, ...)
+        // A delegates to B, then B delegates back to A: both profiles end up
+        // with delegated_stake and the same tokens vote more than once
+
+        // VULNERABILITY 3: Delegate's stake timestamp not reset
+        // Missing: self.delegate_profile.last_stake_timestamp = current_time;
+        // Delegated power can be used on proposals that were already open
+        let current_time = Clock::get()?.unix_timestamp;
+
+        // VULNERABILITY 4: Unchecked arithmetic
+        self.delegate_profile.delegated_stake = self.delegate_profile.delegated_stake + amount;
+        self.delegator_profile.delegated_to = self.delegate_profile.owner;
+
+        self.delegation.set_inner(Delegation {
+            delegator: self.delegator.key(),
+            delegate: self.delegate_profile.owner,
+            amount,
+            created_at: current_time,
+            bump: bumps.delegation,
+        });
+
+        Ok(())
+    }
+}
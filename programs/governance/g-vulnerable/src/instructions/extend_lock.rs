@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Extend Lock Instruction
+//
+// VULNERABILITY SUMMARY:
+// - Decay restarts from the current slot (same slot-based clock as lock_tokens)
+// - Unchecked arithmetic for the unlock time
+
+#[derive(Accounts)]
+pub struct ExtendLock<'info> {
+    pub user: Signer<'info>,
+
+    /// CHECK: Used for config PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [VOTE_ESCROW, config.key().as_ref(), user.key().as_ref()],
+        bump = vote_escrow.bump,
+        constraint = vote_escrow.owner == user.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub vote_escrow: Account<'info, VoteEscrow>,
+}
+
+impl<'info> ExtendLock<'info> {
+    pub fn extend_lock(&mut self, duration: i64) -> Result<()> {
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        let clock = Clock::get()?;
+        require!(
+            clock.unix_timestamp < self.vote_escrow.unlock_at,
+            GovernanceError::LockExpired
+        );
+        require!(
+            (MIN_LOCK_SECONDS..=MAX_LOCK_SECONDS).contains(&duration),
+            GovernanceError::InvalidLockDuration
+        );
+
+        // VULNERABILITY 2: Unchecked arithmetic
+        let unlock_at = clock.unix_timestamp + duration;
+        require!(
+            unlock_at > self.vote_escrow.unlock_at,
+            GovernanceError::LockNotExtended
+        );
+
+        // VULNERABILITY 1: Decay restarts from the current slot
+        let vote_escrow = &mut self.vote_escrow;
+        vote_escrow.lock_slot = clock.slot;
+        vote_escrow.lock_duration = duration;
+        vote_escrow.unlock_at = unlock_at;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Finalize Proposal Instruction
+//
+// Settles a proposal once its voting period ends
+// NOTE: The logic matches the secure version, but the tally it reads
+// can be inflated by repeated ballots (see cast_ballot.rs)
+
+#[derive(Accounts)]
+pub struct FinalizeProposal<'info> {
+    pub caller: Signer<'info>,
+
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL, config.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+impl<'info> FinalizeProposal<'info> {
+    pub fn finalize_proposal(&mut self) -> Result<()> {
+        require!(
+            self.proposal.status == ProposalStatus::Active,
+            GovernanceError::ProposalNotActive
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time >= self.proposal.voting_ends_at,
+            GovernanceError::VotingPeriodActive
+        );
+
+        let proposal = &mut self.proposal;
+        let total_votes = proposal.votes_for + proposal.votes_against;
+        let quorum_reached = total_votes >= self.config.quorum;
+        let approved = (proposal.votes_for as u128) * (MAX_BASIS_POINTS as u128)
+            >= (total_votes as u128) * (self.config.approval_threshold_bps as u128);
+
+        proposal.status = if quorum_reached && approved {
+            ProposalStatus::Passed
+        } else {
+            ProposalStatus::Rejected
+        };
+
+        msg!(
+            "Proposal {} finalized: {:?} ({} for, {} against)",
+            proposal.proposal_id,
+            proposal.status,
+            proposal.votes_for,
+            proposal.votes_against
+        );
+
+        Ok(())
+    }
+}
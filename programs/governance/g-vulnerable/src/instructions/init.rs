@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, state::*};
+use crate::{constants::*, errors::*, state::*};
 
 // Initialize DAO Instruction
 //
@@ -12,6 +12,8 @@ use crate::{constants::*, state::*};
 // - Config PDA prevents unauthorized modification
 // - Minimum stake requirement set at initialization
 // - Vote power multiplier configurable
+// - Proposal quorum and approval threshold validated
+// - Reputation decay rate and epoch length validated
 // - System starts unpaused by default
 
 #[derive(Accounts)]
@@ -45,17 +47,48 @@ impl<'info> InitializeDaoProgram<'info> {
         admin: Pubkey,
         token_mint: Pubkey,
         vote_power: u8,
+        quorum: u64,
+        approval_threshold_bps: u16,
+        unbonding_period: i64,
+        decay_rate_bps: u16,
+        decay_epoch_seconds: i64,
+        reward_rate_per_sec: u64,
+        stake_ramp_seconds: i64,
         bumps: InitializeDaoProgramBumps,
     ) -> Result<()> {
         // SECURITY: Admin passed as parameter instead of using signer
         // This allows flexibility in who initializes vs who controls the DAO
         // The admin derives the config PDA and has special privileges
 
+        require!(
+            quorum > 0
+                && approval_threshold_bps > 0
+                && approval_threshold_bps as u64 <= MAX_BASIS_POINTS,
+            GovernanceError::InvalidGovernanceParams
+        );
+
+        require!(unbonding_period > 0, GovernanceError::InvalidUnbondingPeriod);
+
+        require!(
+            decay_rate_bps as u64 <= MAX_BASIS_POINTS && decay_epoch_seconds > 0,
+            GovernanceError::InvalidDecayParams
+        );
+
         self.config.set_inner(Config {
             admin: admin.key(),
             minimum_stake,
             token_mint,
             vote_power,
+            quorum,
+            approval_threshold_bps,
+            proposal_count: 0,
+            unbonding_period,
+            decay_rate_bps,
+            decay_epoch_seconds,
+            reward_rate_per_sec,
+            pending_admin: Pubkey::default(),
+            current_season: 0,
+            stake_ramp_seconds,
             is_paused: false,
             config_bump: bumps.config,
         });
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, events::*, state::*};
+
+// Initialize Council Instruction
+//
+// Admin-only, one-time setup of the emergency council
+// The council itself is validated like the secure build; the flaw is in
+// council_set_pause and council_veto, which ignore the threshold
+
+#[derive(Accounts)]
+pub struct InitCouncil<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump = config.config_bump,
+        constraint = config.admin == authority.key() @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + Council::INIT_SPACE,
+        seeds = [COUNCIL, config.key().as_ref()],
+        bump
+    )]
+    pub council: Account<'info, Council>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitCouncil<'info> {
+    pub fn init_council(
+        &mut self,
+        members: Vec<Pubkey>,
+        threshold: u8,
+        veto_window: i64,
+        bumps: InitCouncilBumps,
+    ) -> Result<()> {
+        require!(
+            !members.is_empty() && members.len() <= MAX_COUNCIL_MEMBERS,
+            GovernanceError::InvalidCouncilConfig
+        );
+        for (index, member) in members.iter().enumerate() {
+            require!(
+                *member != Pubkey::default() && !members[..index].contains(member),
+                GovernanceError::InvalidCouncilConfig
+            );
+        }
+        require!(
+            threshold >= 1 && threshold as usize <= members.len(),
+            GovernanceError::InvalidCouncilConfig
+        );
+        require!(
+            veto_window > 0 && veto_window <= MAX_VETO_WINDOW_SECONDS,
+            GovernanceError::InvalidCouncilConfig
+        );
+
+        self.council.set_inner(Council {
+            config: self.config.key(),
+            members: members.clone(),
+            threshold,
+            veto_window,
+            bump: bumps.council,
+        });
+
+        emit!(CouncilInitialized {
+            config: self.config.key(),
+            members,
+            threshold,
+            veto_window,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
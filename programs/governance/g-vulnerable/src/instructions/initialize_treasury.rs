@@ -101,6 +101,7 @@ impl<'info> InitializeTreasury<'info> {
             admin: self.admin.key(),
             total_staked: 0,
             stakers_count: 0,
+            total_unbonding: 0,
             treasury_token_account: self.treasury_token_account.key(),
             state_bump: bumps.treasury,
             vault_bump: bumps.treasury_authority,
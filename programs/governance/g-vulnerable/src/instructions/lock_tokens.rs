@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Lock Tokens Instruction
+//
+// VULNERABILITY SUMMARY:
+// - Lock start recorded as a slot; power decays by slot count, not unix time
+// - Unchecked arithmetic for the unlock time
+
+#[derive(Accounts)]
+pub struct LockTokens<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Used for config PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR + VoteEscrow::INIT_SPACE,
+        seeds = [VOTE_ESCROW, config.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub vote_escrow: Account<'info, VoteEscrow>,
+
+    #[account(
+        address = config.token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub token_mint_account: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = user,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = vote_escrow
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> LockTokens<'info> {
+    pub fn lock_tokens(&mut self, amount: u64, duration: i64, bumps: LockTokensBumps) -> Result<()> {
+        require!(amount > 0, GovernanceError::InvalidStakeAmount);
+        require!(
+            (MIN_LOCK_SECONDS..=MAX_LOCK_SECONDS).contains(&duration),
+            GovernanceError::InvalidLockDuration
+        );
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        let transfer_ctx = CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.user_token_account.to_account_info(),
+                to: self.escrow_token_account.to_account_info(),
+                authority: self.user.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, amount)?;
+
+        let clock = Clock::get()?;
+
+        // VULNERABILITY 1: Lock start recorded as a slot
+        // Power decays from lock_slot (see VoteEscrow::voting_power), while
+        // unlock_at below is unix time; nothing keeps the two in step
+        // VULNERABILITY 2: Unchecked arithmetic
+        self.vote_escrow.set_inner(VoteEscrow {
+            owner: self.user.key(),
+            config: self.config.key(),
+            amount,
+            lock_slot: clock.slot,
+            lock_duration: duration,
+            unlock_at: clock.unix_timestamp + duration,
+            bump: bumps.vote_escrow,
+        });
+
+        Ok(())
+    }
+}
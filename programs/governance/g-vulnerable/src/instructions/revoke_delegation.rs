@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Revoke Delegation Instruction
+//
+// VULNERABILITY SUMMARY:
+// - Delegator's stake timestamp not reset (can vote again on open proposals)
+// - Unchecked arithmetic
+
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USERPROFILE, delegator.key().as_ref()],
+        bump,
+        constraint = delegator_profile.owner == delegator.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub delegator_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        seeds = [USERPROFILE, delegation.delegate.as_ref()],
+        bump,
+    )]
+    pub delegate_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        close = delegator,
+        seeds = [DELEGATION, delegator.key().as_ref()],
+        bump = delegation.bump,
+        constraint = delegation.delegator == delegator.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub delegation: Account<'info, Delegation>,
+}
+
+impl<'info> RevokeDelegation<'info> {
+    pub fn revoke_delegation(&mut self) -> Result<()> {
+        // VULNERABILITY 1: Unchecked arithmetic
+        self.delegate_profile.delegated_stake =
+            self.delegate_profile.delegated_stake - self.delegation.amount;
+
+        // VULNERABILITY 2: Stake timestamp not reset
+        // Missing: self.delegator_profile.last_stake_timestamp = Clock::get()?.unix_timestamp;
+        self.delegator_profile.delegated_to = Pubkey::default();
+
+        Ok(())
+    }
+}
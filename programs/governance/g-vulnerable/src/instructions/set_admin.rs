@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+// Set Admin Instruction
+//
+// VULNERABILITY SUMMARY:
+// - Single-step transfer (no acceptance by the new admin)
+// - Current admin is never required to sign
+// - Anyone who knows the admin pubkey can take over the DAO
+
+#[derive(Accounts)]
+pub struct SetAdmin<'info> {
+    // VULNERABILITY: Current admin is not a Signer
+    // Missing: pub authority: Signer<'info>
+    /// CHECK: Only compared against config.admin
+    pub authority: UncheckedAccount<'info>,
+
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump = config.config_bump,
+        constraint = config.admin == authority.key() @ GovernanceError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> SetAdmin<'info> {
+    pub fn set_admin(&mut self, new_admin: Pubkey) -> Result<()> {
+        // VULNERABILITY 1: Key comparison without a signature
+        // The constraint above only checks that the right pubkey was passed in
+
+        // VULNERABILITY 2: No two-step acceptance
+        // Missing: self.config.pending_admin = new_admin; (and a separate accept_admin)
+        // A wrong or hostile key takes over immediately
+        self.config.admin = new_admin;
+
+        Ok(())
+    }
+}
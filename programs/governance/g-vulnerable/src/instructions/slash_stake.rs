@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+
+use crate::{constants::*, errors::*, events::*, state::*};
+
+// Slash Stake Instruction
+//
+// VULNERABILITY SUMMARY:
+// - No admin check (any signer can slash any user)
+// - No bound on slash_bps
+// - Delegated power not trimmed (delegate keeps slashed votes)
+// - Unchecked arithmetic
+
+#[derive(Accounts)]
+pub struct SlashStake<'info> {
+    // VULNERABILITY: Any signer is accepted
+    // Missing: constraint = config.admin == authority.key()
+    pub authority: Signer<'info>,
+
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY, admin.key().as_ref()],
+        bump = treasury.state_bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [TREASURYAUTH, config.key().as_ref(), admin.key().as_ref()],
+        bump = treasury.vault_bump,
+    )]
+    /// CHECK: PDA authority for signing treasury burns
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [USERPROFILE, user_profile.owner.as_ref()],
+        bump,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        address = config.token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub token_mint_account: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        address = treasury.treasury_token_account @ GovernanceError::InvalidTreasuryAccount
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> SlashStake<'info> {
+    pub fn slash_stake(&mut self, slash_bps: u16, burn: bool) -> Result<()> {
+        // VULNERABILITY 1: No admin authorization
+        // Missing: require!(self.config.admin == self.authority.key(), GovernanceError::UnauthorizedAdmin);
+        // Anyone can wipe out any user's stake
+
+        // VULNERABILITY 2: No bound on slash_bps
+        // Missing: require!(slash_bps > 0 && slash_bps as u64 <= MAX_BASIS_POINTS, ...)
+        let amount = self.user_profile.stake_amount * slash_bps as u64 / MAX_BASIS_POINTS;
+
+        // VULNERABILITY 3: Delegated power not trimmed
+        // Missing: reduce delegation.amount and the delegate's delegated_stake
+        // The delegate keeps voting with tokens that no longer exist
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let user_profile = &mut self.user_profile;
+        user_profile.accrue_rewards(self.config.reward_rate_per_sec, current_time);
+        user_profile.stake_amount = user_profile.stake_amount - amount;
+
+        let treasury = &mut self.treasury;
+        treasury.total_staked = treasury.total_staked - amount;
+        if self.user_profile.stake_amount == 0 {
+            treasury.stakers_count = treasury.stakers_count - 1;
+        }
+
+        if burn {
+            let config = self.config.key();
+            let admin = self.admin.key();
+            let treasury_auth_seeds = &[
+                TREASURYAUTH,
+                config.as_ref(),
+                admin.as_ref(),
+                &[self.treasury.vault_bump],
+            ];
+            let signer_seeds = &[&treasury_auth_seeds[..]];
+
+            let burn_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Burn {
+                    mint: self.token_mint_account.to_account_info(),
+                    from: self.treasury_token_account.to_account_info(),
+                    authority: self.treasury_authority.to_account_info(),
+                },
+                signer_seeds,
+            );
+            token::burn(burn_ctx, amount)?;
+        }
+
+        emit!(StakeSlashed {
+            user: self.user_profile.owner,
+            slashed_by: self.authority.key(),
+            slash_bps,
+            amount,
+            remaining_stake: self.user_profile.stake_amount,
+            burned: burn,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+}
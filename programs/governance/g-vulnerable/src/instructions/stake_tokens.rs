@@ -57,11 +57,29 @@ pub struct Stake<'info> {
     )]
     pub user_token_account: Account<'info, TokenAccount>,
 
+    #[account(mut)]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
     #[account(
         mut,
-        address = treasury.treasury_token_account @ GovernanceError::InvalidTreasuryAccount
+        seeds = [SUPPORTED_MINT, config.key().as_ref(), token_mint_account.key().as_ref()],
+        bump = supported_mint.bump,
     )]
-    pub treasury_token_account: Account<'info, TokenAccount>,
+    pub supported_mint: Option<Account<'info, SupportedMint>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR + MintStake::INIT_SPACE,
+        seeds = [
+            MINT_STAKE,
+            config.key().as_ref(),
+            token_mint_account.key().as_ref(),
+            user.key().as_ref(),
+        ],
+        bump
+    )]
+    pub mint_stake: Option<Account<'info, MintStake>>,
 
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -69,7 +87,32 @@ pub struct Stake<'info> {
 }
 
 impl<'info> Stake<'info> {
-    pub fn stake_tokens(&mut self, amount: u64) -> Result<()> {
+    pub fn stake_tokens(&mut self, amount: u64, bumps: StakeBumps) -> Result<()> {
+        require!(
+            self.supported_mint.is_some() == self.mint_stake.is_some(),
+            GovernanceError::MissingMintStakeAccount
+        );
+        let power = match self.supported_mint.as_ref() {
+            None => {
+                require_keys_eq!(
+                    self.treasury_token_account.key(),
+                    self.treasury.treasury_token_account,
+                    GovernanceError::InvalidTreasuryAccount
+                );
+                amount
+            }
+            Some(supported_mint) => {
+                require_keys_eq!(
+                    self.treasury_token_account.key(),
+                    supported_mint.treasury_token_account,
+                    GovernanceError::InvalidTreasuryAccount
+                );
+                // Weight comes from a registry entry anyone can create
+                // (see add_supported_mint.rs)
+                supported_mint.voting_power(amount)
+            }
+        };
+
         // VULNERABILITY 1: No minimum stake check
         // The config.minimum_stake exists but is not enforced
         // Users can stake tiny amounts (1 lamport) to gain voting rights
@@ -93,13 +136,40 @@ impl<'info> Stake<'info> {
         );
         token::transfer(transfer_ctx, amount)?;
 
+        if let (Some(supported_mint), Some(mint_stake)) =
+            (self.supported_mint.as_mut(), self.mint_stake.as_mut())
+        {
+            if mint_stake.owner == Pubkey::default() {
+                mint_stake.owner = self.user.key();
+                mint_stake.mint = supported_mint.mint;
+                mint_stake.bump = bumps.mint_stake;
+            }
+            mint_stake.amount = mint_stake.amount + amount;
+            mint_stake.voting_power = mint_stake.voting_power + power;
+            supported_mint.total_staked = supported_mint.total_staked + amount;
+
+            let user_profile = &mut self.user_profile;
+            user_profile.weighted_stake = user_profile.weighted_stake + power;
+            user_profile.last_stake_timestamp = Clock::get()?.unix_timestamp;
+            user_profile.stake_start_ts = Clock::get()?.unix_timestamp;
+            user_profile.role_level = MemberRanks::from_reputation(user_profile.reputation_points);
+
+            return Ok(());
+        }
+
         let user_profile = &mut self.user_profile;
         let was_new_staker = user_profile.stake_amount == 0;
 
+        // VULNERABILITY 6: Reward accrual uses unchecked u64 multiplication
+        // See UserProfile::accrue_rewards - overflow here blocks further staking
+        user_profile.accrue_rewards(self.config.reward_rate_per_sec, Clock::get()?.unix_timestamp);
+
         // VULNERABILITY 4: Unchecked arithmetic
         // Using direct addition instead of checked_add
         // If stake_amount + amount > u64::MAX, program panics
         user_profile.stake_amount = user_profile.stake_amount + amount;
+        user_profile.last_stake_timestamp = Clock::get()?.unix_timestamp;
+        user_profile.stake_start_ts = Clock::get()?.unix_timestamp;
 
         user_profile.role_level = MemberRanks::from_reputation(user_profile.reputation_points);
 
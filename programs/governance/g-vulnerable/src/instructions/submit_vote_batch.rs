@@ -0,0 +1,260 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    instruction::Instruction,
+    sysvar::instructions::{
+        self as instructions_sysvar, load_current_index_checked, load_instruction_at_checked,
+    },
+};
+use anchor_lang::system_program::{create_account, CreateAccount};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Submit Vote Batch Instruction
+//
+// Lets a relayer settle many off-chain signed votes in one transaction
+// One Ed25519 instruction per entry, in entry order, directly before this one
+// Remaining accounts: (voter_profile, ballot) for each entry
+//
+// VULNERABILITY SUMMARY:
+// - Ed25519 instruction indices never checked: the key and message read from the
+//   instruction need not be the ones the precompile verified, so anyone's vote
+//   can be forged with a signature from the attacker's own key
+
+#[derive(Accounts)]
+pub struct SubmitVoteBatch<'info> {
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    /// CHECK: Used only for PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL, config.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// CHECK: Validated by address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SubmitVoteBatch<'info> {
+    pub fn submit_vote_batch(
+        &mut self,
+        entries: Vec<VoteBatchEntry>,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        require!(
+            self.proposal.status == ProposalStatus::Active,
+            GovernanceError::ProposalNotActive
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time < self.proposal.voting_ends_at,
+            GovernanceError::VotingPeriodEnded
+        );
+
+        require!(
+            !entries.is_empty() && entries.len() <= MAX_VOTE_BATCH_SIZE,
+            GovernanceError::InvalidVoteBatchSize
+        );
+        require!(
+            remaining_accounts.len() == entries.len() * 2,
+            GovernanceError::MissingBatchAccounts
+        );
+
+        let instructions = self.instructions.to_account_info();
+        let current_index = load_current_index_checked(&instructions)? as usize;
+        let first_index = current_index
+            .checked_sub(entries.len())
+            .ok_or(GovernanceError::InvalidSignatureInstruction)?;
+
+        let mut votes_for: u64 = 0;
+        let mut votes_against: u64 = 0;
+        for (i, (entry, accounts)) in entries
+            .iter()
+            .zip(remaining_accounts.chunks_exact(2))
+            .enumerate()
+        {
+            let signature_ix = load_instruction_at_checked(first_index + i, &instructions)?;
+            self.verify_vote_signature(&signature_ix, entry)?;
+
+            self.validate_voter(entry, &accounts[0])?;
+
+            self.record_ballot(entry, &accounts[1], current_time)?;
+
+            if entry.support {
+                votes_for = votes_for
+                    .checked_add(entry.weight)
+                    .ok_or(GovernanceError::MathOverflow)?;
+            } else {
+                votes_against = votes_against
+                    .checked_add(entry.weight)
+                    .ok_or(GovernanceError::MathOverflow)?;
+            }
+        }
+
+        let proposal = &mut self.proposal;
+        proposal.votes_for = proposal
+            .votes_for
+            .checked_add(votes_for)
+            .ok_or(GovernanceError::MathOverflow)?;
+        proposal.votes_against = proposal
+            .votes_against
+            .checked_add(votes_against)
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        msg!(
+            "Vote batch: {} entries, {} for, {} against",
+            entries.len(),
+            votes_for,
+            votes_against
+        );
+
+        Ok(())
+    }
+
+    fn verify_vote_signature(&self, ix: &Instruction, entry: &VoteBatchEntry) -> Result<()> {
+        require_keys_eq!(
+            ix.program_id,
+            ed25519_program::ID,
+            GovernanceError::InvalidSignatureInstruction
+        );
+
+        let data = &ix.data;
+        require!(
+            data.len() >= ED25519_OFFSETS_START + ED25519_OFFSETS_SIZE && data[0] == 1,
+            GovernanceError::InvalidSignatureInstruction
+        );
+        let offset = |field: usize| {
+            let at = ED25519_OFFSETS_START + field * 2;
+            u16::from_le_bytes([data[at], data[at + 1]])
+        };
+        let public_key_offset = offset(2) as usize;
+        let message_data_offset = offset(4) as usize;
+        let message_data_size = offset(5) as usize;
+
+        // VULNERABILITY: Signature, public key and message instruction indices ignored
+        // Missing: require!(signature_instruction_index == u16::MAX
+        //     && public_key_instruction_index == u16::MAX
+        //     && message_instruction_index == u16::MAX, ...)
+        // The precompile reads each field from the instruction its index names, but
+        // the key and message below are always read from this instruction
+        // Attack: point the indices at an instruction holding the attacker's own valid
+        // signature, and put the victim's key and a forged vote at the same offsets here
+        let public_key = data
+            .get(public_key_offset..public_key_offset + 32)
+            .ok_or(GovernanceError::InvalidSignatureInstruction)?;
+        require!(
+            public_key == entry.voter.as_ref(),
+            GovernanceError::SignatureMismatch
+        );
+
+        let message = data
+            .get(message_data_offset..message_data_offset + message_data_size)
+            .ok_or(GovernanceError::InvalidSignatureInstruction)?;
+        require!(
+            message == entry.vote_message(&self.proposal.key()).as_slice(),
+            GovernanceError::SignatureMismatch
+        );
+
+        Ok(())
+    }
+
+    fn validate_voter(&self, entry: &VoteBatchEntry, profile_info: &AccountInfo<'info>) -> Result<()> {
+        let (expected_profile, _) =
+            Pubkey::find_program_address(&[USERPROFILE, entry.voter.as_ref()], &crate::ID);
+        require_keys_eq!(
+            profile_info.key(),
+            expected_profile,
+            GovernanceError::ProfileMismatch
+        );
+        require_keys_eq!(
+            *profile_info.owner,
+            crate::ID,
+            GovernanceError::ProfileMismatch
+        );
+        let profile = UserProfile::try_deserialize(&mut &profile_info.try_borrow_data()?[..])?;
+
+        let power = profile.effective_power();
+        require!(
+            power >= self.config.minimum_stake,
+            GovernanceError::InsufficientStake
+        );
+        require!(
+            profile.last_stake_timestamp < self.proposal.created_at,
+            GovernanceError::StakeAfterProposal
+        );
+        require!(
+            entry.weight > 0 && entry.weight <= power,
+            GovernanceError::InvalidVoteWeight
+        );
+
+        Ok(())
+    }
+
+    fn record_ballot(
+        &self,
+        entry: &VoteBatchEntry,
+        ballot_info: &AccountInfo<'info>,
+        timestamp: i64,
+    ) -> Result<()> {
+        let proposal = self.proposal.key();
+        let (expected_ballot, bump) = Pubkey::find_program_address(
+            &[BALLOT, proposal.as_ref(), entry.voter.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            ballot_info.key(),
+            expected_ballot,
+            GovernanceError::InvalidBallotAccount
+        );
+
+        let space = ANCHOR_DISCRIMINATOR + Ballot::INIT_SPACE;
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            BALLOT,
+            proposal.as_ref(),
+            entry.voter.as_ref(),
+            &[bump],
+        ]];
+        create_account(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                CreateAccount {
+                    from: self.relayer.to_account_info(),
+                    to: ballot_info.clone(),
+                },
+            )
+            .with_signer(signer_seeds),
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &crate::ID,
+        )?;
+
+        let ballot = Ballot {
+            voter: entry.voter,
+            proposal,
+            support: entry.support,
+            weight: entry.weight,
+            timestamp,
+            bump,
+        };
+        ballot.try_serialize(&mut &mut ballot_info.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::{constants::*, state::*};
+
+// Update Profile Metadata Instruction
+//
+// VULNERABILITY SUMMARY:
+// - No length bound on the URI (profile grows to whatever the caller sends)
+// - No scheme check (javascript:, data: and raw text are all stored)
+
+#[derive(Accounts)]
+pub struct UpdateProfileMetadata<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USERPROFILE, user.key().as_ref()],
+        bump,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateProfileMetadata<'info> {
+    pub fn update_profile_metadata(&mut self, metadata_uri: Option<String>) -> Result<()> {
+        // VULNERABILITY [MEDIUM]: No bound or scheme check on the URI
+        //
+        // The profile is resized to fit any string, limited only by how much
+        // instruction data fits in the transaction (or a CPI). Every
+        // instruction that loads the profile - votes against it, delegation,
+        // slashing, rewards - deserializes the whole URI onto the 32 KiB heap
+        // and pays the compute for it, and the rent locked in the profile
+        // grows with it. The stored link is also handed to front-ends as-is.
+        //
+        // Example:
+        //   Mallory sets a 900 byte javascript: URI, 4.5x MAX_METADATA_URI_LENGTH.
+        //   Everyone who votes on Mallory's profile now loads it.
+        //
+        // Fix: require!(uri.len() <= MAX_METADATA_URI_LENGTH, ...) and accept
+        //      only https:// or ipfs:// links of printable ASCII
+        let info = self.user_profile.to_account_info();
+        let new_space = UserProfile::space(metadata_uri.as_deref());
+        let rent_due = Rent::get()?
+            .minimum_balance(new_space)
+            .saturating_sub(info.lamports());
+
+        if rent_due > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.user.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+
+        info.resize(new_space)?;
+        self.user_profile.metadata_uri = metadata_uri;
+
+        Ok(())
+    }
+}
@@ -13,6 +13,7 @@ use crate::{constants::*, errors::*, state::*};
 // - No reputation floor (unlimited downvoting)
 // - Unchecked arithmetic operations
 // - vote_weight truncated to u8 (loses precision)
+// - Vote history appended past its allocated space
 
 #[derive(Accounts)]
 #[instruction(target_username: String)]
@@ -75,6 +76,16 @@ pub struct Vote<'info> {
     )]
     pub vote_record: Account<'info, VoteRecord>,
 
+    // VULNERABILITY: Allocated once at initial capacity and never grown
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = VoteHistory::space(VOTE_HISTORY_INITIAL_CAPACITY),
+        seeds = [VOTE_HISTORY, voter.key().as_ref()],
+        bump
+    )]
+    pub vote_history: Account<'info, VoteHistory>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -183,6 +194,22 @@ impl<'info> Vote<'info> {
             bump: bumps.vote_record,
         });
 
+        if self.vote_history.owner == Pubkey::default() {
+            self.vote_history.owner = self.voter.key();
+            self.vote_history.bump = bumps.vote_history;
+            self.vote_history.capacity = VOTE_HISTORY_INITIAL_CAPACITY as u16;
+        }
+
+        // VULNERABILITY 10: Unbounded history append
+        // Missing: realloc growth and ring-buffer wrap at capacity
+        // Once full, the account fails to serialize and the voter can no longer vote
+        self.vote_history.record(VoteHistoryEntry {
+            target: self.target_user_profile.owner,
+            vote_type,
+            timestamp: current_time,
+            weight: vote_weight,
+        });
+
         Ok(())
     }
 }
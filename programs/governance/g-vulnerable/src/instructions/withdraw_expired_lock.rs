@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+// Withdraw Expired Lock Instruction
+//
+// Releases locked tokens once unlock_at (unix time) has passed
+// The unlock check itself is correct; the flaw is that voting power is
+// measured on a different clock (see VoteEscrow::voting_power), so the lock
+// can still vote right up to the withdrawal
+
+#[derive(Accounts)]
+pub struct WithdrawExpiredLock<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Used for config PDA derivation
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG, admin.key().as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [VOTE_ESCROW, config.key().as_ref(), user.key().as_ref()],
+        bump = vote_escrow.bump,
+        constraint = vote_escrow.owner == user.key() @ GovernanceError::UnauthorizedUser
+    )]
+    pub vote_escrow: Account<'info, VoteEscrow>,
+
+    #[account(
+        address = config.token_mint @ GovernanceError::InvalidTokenMint
+    )]
+    pub token_mint_account: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = vote_escrow
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawExpiredLock<'info> {
+    pub fn withdraw_expired_lock(&mut self) -> Result<()> {
+        require!(!self.config.is_paused, GovernanceError::SystemPaused);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time >= self.vote_escrow.unlock_at,
+            GovernanceError::LockNotExpired
+        );
+
+        let config = self.config.key();
+        let user = self.user.key();
+        let escrow_seeds = &[
+            VOTE_ESCROW,
+            config.as_ref(),
+            user.as_ref(),
+            &[self.vote_escrow.bump],
+        ];
+        let signer_seeds = &[&escrow_seeds[..]];
+
+        let amount = self.escrow_token_account.amount;
+        if amount > 0 {
+            let transfer_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.escrow_token_account.to_account_info(),
+                    to: self.user_token_account.to_account_info(),
+                    authority: self.vote_escrow.to_account_info(),
+                },
+                signer_seeds,
+            );
+            token::transfer(transfer_ctx, amount)?;
+        }
+
+        let close_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.escrow_token_account.to_account_info(),
+                destination: self.user.to_account_info(),
+                authority: self.vote_escrow.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::close_account(close_ctx)?;
+
+        self.vote_escrow.amount = 0;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
@@ -8,6 +9,7 @@ use anchor_lang::prelude::*;
 pub use constants::*;
 pub use instructions::*;
 pub use errors::*;
+pub use events::*;
 pub use state::*;
 
 declare_id!("Bx6GKFsyW1YbJdQu9f5mW5Z4T6rNpkQZPnYDVr7pump");
@@ -25,12 +27,26 @@ pub mod vulnerable {
         minimum_stake: u64,
         token_mint: Pubkey,
         vote_power: u8,
+        quorum: u64,
+        approval_threshold_bps: u16,
+        unbonding_period: i64,
+        decay_rate_bps: u16,
+        decay_epoch_seconds: i64,
+        reward_rate_per_sec: u64,
+        stake_ramp_seconds: i64,
     ) -> Result<()> {
         ctx.accounts.initialize(
             minimum_stake,
             admin,
             token_mint,
             vote_power,
+            quorum,
+            approval_threshold_bps,
+            unbonding_period,
+            decay_rate_bps,
+            decay_epoch_seconds,
+            reward_rate_per_sec,
+            stake_ramp_seconds,
             ctx.bumps
         )
     }
@@ -51,22 +67,40 @@ pub mod vulnerable {
         ctx.accounts.create_profile(username, bumps)
     }
 
-    /// Stake tokens to gain voting rights
+    /// Register an additional stakeable mint with a voting weight
+    pub fn add_supported_mint(
+        ctx: Context<AddSupportedMint>,
+        weight_bps: u16,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.add_supported_mint(weight_bps, bumps)
+    }
+
+    /// Stake the base mint or a supported mint to gain voting rights
     pub fn stake_tokens(
         ctx: Context<Stake>,
         amount: u64,
     ) -> Result<()> {
         // Validate amount
         require!(amount > 0, GovernanceError::InvalidStakeAmount);
-		ctx.accounts.stake_tokens(amount)
+        let bumps = ctx.bumps;
+		ctx.accounts.stake_tokens(amount, bumps)
     }
 
-    /// Unstake tokens and reduce voting power
+    /// Unstake tokens into an unbonding ticket and reduce voting power
     pub fn unstake_tokens(
         ctx: Context<Unstake>,
         amount: u64,
     ) -> Result<()> {
-        ctx.accounts.unstake_tokens(amount)
+        let bumps = ctx.bumps;
+        ctx.accounts.unstake_tokens(amount, bumps)
+    }
+
+    /// Withdraw unstaked tokens once the unbonding period has passed
+    pub fn claim_unbonded(
+        ctx: Context<ClaimUnbonded>,
+    ) -> Result<()> {
+        ctx.accounts.claim_unbonded()
     }
 
     /// Cast an upvote for another user
@@ -92,7 +126,194 @@ pub mod vulnerable {
         ctx: Context<ResetUserReputation>,
         user: Pubkey
     ) -> Result<()> {
-        ctx.accounts.reset_user_reputation()
+        let bumps = ctx.bumps;
+        ctx.accounts.reset_user_reputation(bumps)
+    }
+
+    /// Create a stake-weighted governance proposal
+    pub fn create_governance_proposal(
+        ctx: Context<CreateGovernanceProposal>,
+        title: String,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.create_proposal(title, bumps)
+    }
+
+    /// Cast a stake-weighted ballot on a proposal
+    pub fn cast_ballot(
+        ctx: Context<CastBallot>,
+        support: bool,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.cast_ballot(support, bumps)
+    }
+
+    /// Settle a batch of off-chain votes, each signed by its voter (Ed25519)
+    pub fn submit_vote_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, SubmitVoteBatch<'info>>,
+        entries: Vec<VoteBatchEntry>,
+    ) -> Result<()> {
+        ctx.accounts.submit_vote_batch(entries, ctx.remaining_accounts)
+    }
+
+    /// Finalize a proposal after its voting period ends
+    pub fn finalize_proposal(
+        ctx: Context<FinalizeProposal>,
+    ) -> Result<()> {
+        ctx.accounts.finalize_proposal()
+    }
+
+    /// Delegate staked voting power to another profile
+    pub fn delegate_votes(
+        ctx: Context<DelegateVotes>,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.delegate_votes(bumps)
+    }
+
+    /// Revoke a delegation and reclaim voting power
+    pub fn revoke_delegation(
+        ctx: Context<RevokeDelegation>,
+    ) -> Result<()> {
+        ctx.accounts.revoke_delegation()
+    }
+
+    /// Apply reputation decay for elapsed epochs (permissionless)
+    pub fn decay_reputation(
+        ctx: Context<DecayReputation>,
+    ) -> Result<()> {
+        ctx.accounts.decay_reputation()
+    }
+
+    /// Claim staking rewards accrued on staked tokens
+    pub fn claim_rewards(
+        ctx: Context<ClaimRewards>,
+    ) -> Result<()> {
+        ctx.accounts.claim_rewards()
+    }
+
+    /// Change username, releasing the old one
+    pub fn change_username(
+        ctx: Context<ChangeUsername>,
+        new_username: String,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.change_username(new_username, bumps)
+    }
+
+    /// Set or clear the profile's metadata URI
+    pub fn update_profile_metadata(
+        ctx: Context<UpdateProfileMetadata>,
+        metadata_uri: Option<String>,
+    ) -> Result<()> {
+        ctx.accounts.update_profile_metadata(metadata_uri)
+    }
+
+    /// Close a profile and release its username (rent refunded)
+    pub fn close_profile(
+        ctx: Context<CloseProfile>,
+    ) -> Result<()> {
+        ctx.accounts.close_profile()
+    }
+
+    /// Replace the DAO admin
+    pub fn set_admin(
+        ctx: Context<SetAdmin>,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.set_admin(new_admin)
+    }
+
+    /// Slash a user's stake
+    pub fn slash_stake(
+        ctx: Context<SlashStake>,
+        slash_bps: u16,
+        burn: bool,
+    ) -> Result<()> {
+        ctx.accounts.slash_stake(slash_bps, burn)
+    }
+
+    /// Close the current season and set its reward pool (admin only)
+    pub fn close_season(
+        ctx: Context<CloseSeason>,
+        reward_pool: u64,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.close_season(reward_pool, bumps)
+    }
+
+    /// Claim a share of a closed season's reward pool
+    pub fn claim_season_reward(
+        ctx: Context<ClaimSeasonReward>,
+        season_id: u64,
+    ) -> Result<()> {
+        ctx.accounts.claim_season_reward(season_id)
+    }
+
+    /// Close a vote record (rent refunded)
+    pub fn close_vote_record(
+        ctx: Context<CloseVoteRecord>,
+        target_username: String,
+    ) -> Result<()> {
+        ctx.accounts.close_vote_record(target_username)
+    }
+
+    /// Close the voter's cooldown tracker (rent refunded)
+    pub fn close_cooldown(
+        ctx: Context<CloseCooldown>,
+    ) -> Result<()> {
+        ctx.accounts.close_cooldown()
+    }
+
+    /// Set up the emergency council (admin only, once)
+    pub fn init_council(
+        ctx: Context<InitCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+        veto_window: i64,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.init_council(members, threshold, veto_window, bumps)
+    }
+
+    /// Pause or resume the DAO as a council member
+    pub fn council_set_pause(
+        ctx: Context<CouncilSetPause>,
+        paused: bool,
+    ) -> Result<()> {
+        ctx.accounts.council_set_pause(paused)
+    }
+
+    /// Veto a proposal within the veto window as a council member
+    pub fn council_veto(
+        ctx: Context<CouncilVeto>,
+    ) -> Result<()> {
+        ctx.accounts.council_veto()
+    }
+
+    /// Lock base-mint tokens for vote-escrowed power
+    pub fn lock_tokens(
+        ctx: Context<LockTokens>,
+        amount: u64,
+        duration: i64,
+    ) -> Result<()> {
+        let bumps = ctx.bumps;
+        ctx.accounts.lock_tokens(amount, duration, bumps)
+    }
+
+    /// Push a live lock's unlock time out to now + duration
+    pub fn extend_lock(
+        ctx: Context<ExtendLock>,
+        duration: i64,
+    ) -> Result<()> {
+        ctx.accounts.extend_lock(duration)
+    }
+
+    /// Withdraw locked tokens once the unlock time has passed
+    pub fn withdraw_expired_lock(
+        ctx: Context<WithdrawExpiredLock>,
+    ) -> Result<()> {
+        ctx.accounts.withdraw_expired_lock()
     }
 
 }
\ No newline at end of file
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+// Emergency Council
+//
+// Emergency keys that can pause the DAO or veto a proposal within
+// veto_window seconds of its voting end. threshold is stored but never
+// enforced by the council instructions in this build.
+#[account]
+#[derive(InitSpace)]
+pub struct Council {
+    pub config: Pubkey,
+    #[max_len(MAX_COUNCIL_MEMBERS)]
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub veto_window: i64,
+    pub bump: u8,
+}
+
+impl Council {
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+// Delegation
+//
+// VULNERABILITY: amount includes power the delegator received from others
+// Combined with missing cycle checks, the same stake is counted more than once
+#[account]
+#[derive(InitSpace)]
+pub struct Delegation {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub amount: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
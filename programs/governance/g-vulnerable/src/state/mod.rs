@@ -1,8 +1,24 @@
 pub mod user_profile;
 pub mod treasury;
 pub mod vote;
+pub mod proposal;
+pub mod unbonding_ticket;
+pub mod delegation;
+pub mod vote_history;
+pub mod season_snapshot;
+pub mod supported_mint;
+pub mod council;
+pub mod vote_escrow;
 
 
 pub use user_profile::*;
 pub use treasury::*;
-pub use vote::*;
\ No newline at end of file
+pub use vote::*;
+pub use proposal::*;
+pub use unbonding_ticket::*;
+pub use delegation::*;
+pub use vote_history::*;
+pub use season_snapshot::*;
+pub use supported_mint::*;
+pub use council::*;
+pub use vote_escrow::*;
\ No newline at end of file
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+// Governance Proposal
+//
+// Tracks a single DAO proposal and its stake-weighted tally
+// The outcome is decided once, against the quorum and threshold stored in Config
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub config: Pubkey,
+    pub proposer: Pubkey,
+    pub proposal_id: u64,
+    #[max_len(64)]
+    pub title: String,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub created_at: i64,
+    pub voting_ends_at: i64,
+    pub status: ProposalStatus,
+    pub bump: u8,
+}
+
+// Ballot Record
+//
+// VULNERABILITY: Created with 'init_if_needed' and never checked for a prior ballot
+// The same voter can submit the ballot instruction repeatedly
+// Each submission adds their full stake to the tally again
+#[account]
+#[derive(InitSpace)]
+pub struct Ballot {
+    pub voter: Pubkey,
+    pub proposal: Pubkey,
+    pub support: bool,
+    pub weight: u64,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+// Vote Batch Entry
+//
+// One off-chain vote settled by submit_vote_batch
+// The voter signs vote_message() with their wallet key
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VoteBatchEntry {
+    pub voter: Pubkey,
+    pub support: bool,
+    pub weight: u64,
+}
+
+impl VoteBatchEntry {
+    // Signed bytes: proposal | support | weight (little-endian)
+    pub fn vote_message(&self, proposal: &Pubkey) -> [u8; VOTE_MESSAGE_LEN] {
+        let mut message = [0u8; VOTE_MESSAGE_LEN];
+        message[..32].copy_from_slice(proposal.as_ref());
+        message[32] = self.support as u8;
+        message[33..].copy_from_slice(&self.weight.to_le_bytes());
+        message
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Copy, PartialEq, Eq)]
+pub enum ProposalStatus {
+    Active,
+    Passed,
+    Rejected,
+    Vetoed,
+}
+
+impl Space for ProposalStatus {
+    const INIT_SPACE: usize = 1;
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+// Season Snapshot
+//
+// Final standings of a season, recorded as users are reset
+// VULNERABILITY: claimed flags are written but never checked on claim
+#[account]
+#[derive(InitSpace)]
+pub struct SeasonSnapshot {
+    pub config: Pubkey,
+    pub season_id: u64,
+    pub total_staked: u64,
+    pub reward_pool: u64,
+    pub finalized: bool,
+    pub bump: u8,
+    #[max_len(SEASON_TOP_N)]
+    pub entries: Vec<SeasonEntry>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct SeasonEntry {
+    pub user: Pubkey,
+    pub reputation: i64,
+    pub claimed: bool,
+}
+
+impl SeasonSnapshot {
+    pub fn record(&mut self, user: Pubkey, reputation: i64) {
+        if reputation <= 0 {
+            return;
+        }
+
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.user == user) {
+            entry.reputation = entry.reputation.max(reputation);
+            return;
+        }
+
+        let entry = SeasonEntry {
+            user,
+            reputation,
+            claimed: false,
+        };
+        if self.entries.len() < SEASON_TOP_N {
+            self.entries.push(entry);
+        } else if let Some(lowest) = self.entries.iter_mut().min_by_key(|e| e.reputation) {
+            if reputation > lowest.reputation {
+                *lowest = entry;
+            }
+        }
+    }
+
+    pub fn reward_for(&self, index: usize) -> u64 {
+        let total: i64 = self.entries.iter().map(|e| e.reputation).sum();
+        (self.reward_pool as i128 * self.entries[index].reputation as i128 / total as i128) as u64
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+// Supported Mint
+//
+// VULNERABILITY: Entries can be created by any signer (see add_supported_mint.rs)
+// The weight is trusted by stake_tokens, so whoever registers a mint decides
+// how much voting power each of its tokens is worth
+#[account]
+#[derive(InitSpace)]
+pub struct SupportedMint {
+    pub config: Pubkey,
+    pub mint: Pubkey,
+    pub weight_bps: u16,
+    pub treasury_token_account: Pubkey,
+    pub total_staked: u64,
+    pub bump: u8,
+}
+
+impl SupportedMint {
+    // Voting power for `amount` tokens of this mint (unchecked)
+    pub fn voting_power(&self, amount: u64) -> u64 {
+        amount * self.weight_bps as u64 / MAX_BASIS_POINTS
+    }
+}
+
+// Mint Stake
+//
+// Per-user record of tokens staked in one supported mint
+#[account]
+#[derive(InitSpace)]
+pub struct MintStake {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub voting_power: u64,
+    pub bump: u8,
+}
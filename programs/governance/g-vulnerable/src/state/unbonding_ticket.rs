@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+// Unbonding Ticket
+//
+// VULNERABILITY: unlock_at is recorded but never checked on claim
+// Tokens can be withdrawn in the same block they were unstaked
+#[account]
+#[derive(InitSpace)]
+pub struct UnbondingTicket {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub unlock_at: i64,
+    pub bump: u8,
+}
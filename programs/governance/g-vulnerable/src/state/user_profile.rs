@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::*;
+
 // User Profile
 //
 // Stores user identity, reputation, and voting statistics
@@ -19,6 +21,65 @@ pub struct UserProfile {
     pub total_votes_cast: u64,
     pub last_vote_timestamp: i64,
     pub created_at: i64,
+    pub last_stake_timestamp: i64,
+    pub delegated_to: Pubkey,
+    pub delegated_stake: u64,
+    pub last_decay_ts: i64,
+    pub reward_debt: u64,
+    pub last_reward_ts: i64,
+    pub weighted_stake: u64,
+    pub stake_start_ts: i64,
+
+    // Optional avatar/metadata link, set by update_profile_metadata
+    #[max_len(MAX_METADATA_URI_LENGTH)]
+    pub metadata_uri: Option<String>,
+}
+
+impl UserProfile {
+    // Account size for a profile holding the given metadata URI
+    pub fn space(metadata_uri: Option<&str>) -> usize {
+        ANCHOR_DISCRIMINATOR + Self::INIT_SPACE - 4 - MAX_METADATA_URI_LENGTH
+            + metadata_uri.map_or(0, |uri| 4 + uri.len())
+    }
+
+    // Own voting power
+    //
+    // Weighted sum of every staked mint: base-mint stake counts 1x,
+    // supported mints add their weighted power via weighted_stake
+    pub fn own_power(&self) -> u64 {
+        self.stake_amount + self.weighted_stake
+    }
+
+    // Effective voting power
+    //
+    // Own power counts only while it has not been delegated away
+    // Power delegated to this profile by others is always added
+    //
+    // VULNERABILITY: Stake age is ignored
+    // stake_start_ts is recorded but config.stake_ramp_seconds never applied,
+    // so tokens staked a second ago vote with full weight. Borrowed tokens can
+    // be flash-staked right before a proposal and swing its outcome
+    // Should be: own power * min(age, ramp) / ramp
+    pub fn effective_power(&self) -> u64 {
+        let own_power = if self.delegated_to == Pubkey::default() {
+            self.own_power()
+        } else {
+            0
+        };
+        own_power + self.delegated_stake
+    }
+
+    // Accrue staking rewards
+    //
+    // VULNERABILITY: Per-second reward product computed in u64
+    // stake * rate * elapsed overflows for large stakes or long idle periods,
+    // aborting every instruction that settles rewards (stake, unstake, claim)
+    pub fn accrue_rewards(&mut self, reward_rate_per_sec: u64, now: i64) {
+        let elapsed = (now - self.last_reward_ts) as u64;
+        let reward = self.stake_amount * reward_rate_per_sec * elapsed / REWARD_RATE_SCALE;
+        self.reward_debt = self.reward_debt + reward;
+        self.last_reward_ts = now;
+    }
 }
 
 
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+// Vote Escrow
+//
+// Base-mint tokens locked until unlock_at, held in the escrow's own ATA
+// Voting power is meant to decay linearly to zero at unlock_at
+//
+// VULNERABILITY: Decay is tracked from the slot the lock was made in
+// lock_slot and lock_duration drive the power, while withdrawals follow
+// unlock_at in unix time, so the two clocks disagree
+#[account]
+#[derive(InitSpace)]
+pub struct VoteEscrow {
+    pub owner: Pubkey,
+    pub config: Pubkey,
+    pub amount: u64,
+    pub lock_slot: u64,
+    pub lock_duration: i64,
+    pub unlock_at: i64,
+    pub bump: u8,
+}
+
+impl VoteEscrow {
+    // Voting power at a given slot
+    //
+    // VULNERABILITY: Elapsed time estimated from slots
+    // (current_slot - lock_slot) * SLOT_DURATION_MS runs behind the clock
+    // whenever slots are slower than 400 ms, so power decays too slowly and a
+    // lock still votes after unlock_at, when its tokens can already be withdrawn
+    // Should be: amount * (unlock_at - unix_timestamp) / MAX_LOCK_SECONDS
+    pub fn voting_power(&self, current_slot: u64) -> u64 {
+        let elapsed = ((current_slot - self.lock_slot) * SLOT_DURATION_MS / 1000) as i64;
+        let remaining = (self.lock_duration - elapsed).clamp(0, MAX_LOCK_SECONDS);
+        (self.amount as u128 * remaining as u128 / MAX_LOCK_SECONDS as u128) as u64
+    }
+}
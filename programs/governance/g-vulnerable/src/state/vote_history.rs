@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::VoteType;
+
+// Vote History
+//
+// VULNERABILITY: Entries are appended without a bound
+// The account is allocated for VOTE_HISTORY_INITIAL_CAPACITY entries and
+// never grown or wrapped, so once it is full every vote fails to serialize
+#[account]
+pub struct VoteHistory {
+    pub owner: Pubkey,
+    pub bump: u8,
+    pub capacity: u16,
+    pub head: u16,
+    pub total_recorded: u64,
+    pub entries: Vec<VoteHistoryEntry>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct VoteHistoryEntry {
+    pub target: Pubkey,
+    pub vote_type: VoteType,
+    pub timestamp: i64,
+    pub weight: i64,
+}
+
+impl VoteHistory {
+    pub const HEADER_SPACE: usize = 32 + 1 + 2 + 2 + 8 + 4;
+
+    pub fn space(capacity: usize) -> usize {
+        ANCHOR_DISCRIMINATOR + Self::HEADER_SPACE + capacity * VoteHistoryEntry::INIT_SPACE
+    }
+
+    // VULNERABILITY: No capacity check and no ring wrap
+    // Missing: overwrite entries[head] once entries.len() == capacity
+    pub fn record(&mut self, entry: VoteHistoryEntry) {
+        self.entries.push(entry);
+        self.head = self.head + 1;
+        self.total_recorded = self.total_recorded + 1;
+    }
+}
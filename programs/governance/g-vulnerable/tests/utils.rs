@@ -1,9 +1,11 @@
-use litesvm::LiteSVM;
+use litesvm::{types::TransactionResult, LiteSVM};
+use litesvm_token::{get_spl_account, CreateAssociatedTokenAccount, CreateMint, MintTo};
 use solana_sdk::{
     hash::hash,
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
     signature::{Keypair, Signer},
+    transaction::Transaction,
 };
 use spl_associated_token_account::get_associated_token_address;
 
@@ -30,9 +32,34 @@ pub const USERPROFILE: &[u8] = b"user_profile";
 pub const USER_REGISTRY: &[u8] = b"user_registry";
 pub const VOTE_COOLDOWN: &[u8] = b"cooldown";
 pub const VOTE_RECORD: &[u8] = b"vote_record";
+pub const PROPOSAL: &[u8] = b"proposal";
+pub const BALLOT: &[u8] = b"ballot";
+pub const UNBONDING_TICKET: &[u8] = b"unbonding";
+pub const DELEGATION: &[u8] = b"delegation";
+pub const VOTE_HISTORY: &[u8] = b"vote_history";
+pub const SEASON_SNAPSHOT: &[u8] = b"season_snapshot";
+pub const SUPPORTED_MINT: &[u8] = b"supported_mint";
+pub const MINT_STAKE: &[u8] = b"mint_stake";
+pub const COUNCIL: &[u8] = b"council";
+pub const VOTE_ESCROW: &[u8] = b"vote_escrow";
+
+pub const ED25519_PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("Ed25519SigVerify111111111111111111111111111");
+pub const INSTRUCTIONS_SYSVAR_ID: Pubkey =
+    Pubkey::from_str_const("Sysvar1nstructions1111111111111111111111111");
 
 pub const DECIMALS: u8 = 6;
 
+pub const DEFAULT_QUORUM: u64 = 10_000_000;
+pub const DEFAULT_APPROVAL_THRESHOLD_BPS: u16 = 5_000;
+pub const DEFAULT_UNBONDING_PERIOD: i64 = 7 * 24 * 3600;
+pub const DEFAULT_DECAY_RATE_BPS: u16 = 1_000;
+pub const DEFAULT_DECAY_EPOCH_SECONDS: i64 = 7 * 24 * 3600;
+pub const DEFAULT_REWARD_RATE_PER_SEC: u64 = 1_000;
+pub const DEFAULT_STAKE_RAMP_SECONDS: i64 = 0;
+pub const PROPOSAL_VOTING_PERIOD_SECONDS: u64 = 3 * 24 * 3600;
+pub const MAX_LOCK_SECONDS: i64 = 4 * 365 * 24 * 3600;
+
 pub fn setup_svm() -> LiteSVM {
     let mut svm = LiteSVM::new();
    
@@ -82,6 +109,61 @@ pub fn derive_vote_record_pda(voter: &Pubkey, target_username: &str) -> (Pubkey,
     )
 }
 
+pub fn derive_proposal_pda(config: &Pubkey, proposal_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PROPOSAL, config.as_ref(), &proposal_id.to_le_bytes()],
+        &GOVERNANCE_PROGRAM_ID,
+    )
+}
+
+pub fn derive_ballot_pda(proposal: &Pubkey, voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BALLOT, proposal.as_ref(), voter.as_ref()], &GOVERNANCE_PROGRAM_ID)
+}
+
+pub fn derive_unbonding_ticket_pda(user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[UNBONDING_TICKET, user.as_ref()], &GOVERNANCE_PROGRAM_ID)
+}
+
+pub fn derive_delegation_pda(delegator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DELEGATION, delegator.as_ref()], &GOVERNANCE_PROGRAM_ID)
+}
+
+pub fn derive_vote_history_pda(voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VOTE_HISTORY, voter.as_ref()], &GOVERNANCE_PROGRAM_ID)
+}
+
+pub fn derive_season_snapshot_pda(config: &Pubkey, season_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEASON_SNAPSHOT, config.as_ref(), &season_id.to_le_bytes()],
+        &GOVERNANCE_PROGRAM_ID,
+    )
+}
+
+pub fn derive_supported_mint_pda(config: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SUPPORTED_MINT, config.as_ref(), mint.as_ref()],
+        &GOVERNANCE_PROGRAM_ID,
+    )
+}
+
+pub fn derive_mint_stake_pda(config: &Pubkey, mint: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MINT_STAKE, config.as_ref(), mint.as_ref(), user.as_ref()],
+        &GOVERNANCE_PROGRAM_ID,
+    )
+}
+
+pub fn derive_council_pda(config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COUNCIL, config.as_ref()], &GOVERNANCE_PROGRAM_ID)
+}
+
+pub fn derive_vote_escrow_pda(config: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VOTE_ESCROW, config.as_ref(), user.as_ref()],
+        &GOVERNANCE_PROGRAM_ID,
+    )
+}
+
 pub fn init_dao_instruction(
     signer: &Pubkey,
     admin: &Pubkey,
@@ -89,6 +171,38 @@ pub fn init_dao_instruction(
     minimum_stake: u64,
     token_mint: &Pubkey,
     vote_power: u8,
+) -> Instruction {
+    init_dao_instruction_with_params(
+        signer,
+        admin,
+        config,
+        minimum_stake,
+        token_mint,
+        vote_power,
+        DEFAULT_QUORUM,
+        DEFAULT_APPROVAL_THRESHOLD_BPS,
+        DEFAULT_UNBONDING_PERIOD,
+        DEFAULT_DECAY_RATE_BPS,
+        DEFAULT_DECAY_EPOCH_SECONDS,
+        DEFAULT_REWARD_RATE_PER_SEC,
+        DEFAULT_STAKE_RAMP_SECONDS,
+    )
+}
+
+pub fn init_dao_instruction_with_params(
+    signer: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    minimum_stake: u64,
+    token_mint: &Pubkey,
+    vote_power: u8,
+    quorum: u64,
+    approval_threshold_bps: u16,
+    unbonding_period: i64,
+    decay_rate_bps: u16,
+    decay_epoch_seconds: i64,
+    reward_rate_per_sec: u64,
+    stake_ramp_seconds: i64,
 ) -> Instruction {
     let discriminator = anchor_discriminator("init_dao");
     let mut data = discriminator.to_vec();
@@ -96,6 +210,13 @@ pub fn init_dao_instruction(
     data.extend_from_slice(&minimum_stake.to_le_bytes());
     data.extend_from_slice(&token_mint.to_bytes());
     data.extend_from_slice(&vote_power.to_le_bytes());
+    data.extend_from_slice(&quorum.to_le_bytes());
+    data.extend_from_slice(&approval_threshold_bps.to_le_bytes());
+    data.extend_from_slice(&unbonding_period.to_le_bytes());
+    data.extend_from_slice(&decay_rate_bps.to_le_bytes());
+    data.extend_from_slice(&decay_epoch_seconds.to_le_bytes());
+    data.extend_from_slice(&reward_rate_per_sec.to_le_bytes());
+    data.extend_from_slice(&stake_ramp_seconds.to_le_bytes());
 
     Instruction {
         program_id: GOVERNANCE_PROGRAM_ID,
@@ -162,6 +283,7 @@ pub fn create_profile_instruction(
     }
 }
 
+// Stake the base mint; the optional supported-mint accounts are passed as "None"
 pub fn stake_tokens_instruction(
     user: &Pubkey,
     admin: &Pubkey,
@@ -172,6 +294,34 @@ pub fn stake_tokens_instruction(
     user_token_account: &Pubkey,
     treasury_token_account: &Pubkey,
     amount: u64,
+) -> Instruction {
+    stake_supported_mint_instruction(
+        user,
+        admin,
+        config,
+        treasury,
+        user_profile,
+        token_mint,
+        user_token_account,
+        treasury_token_account,
+        &GOVERNANCE_PROGRAM_ID,
+        &GOVERNANCE_PROGRAM_ID,
+        amount,
+    )
+}
+
+pub fn stake_supported_mint_instruction(
+    user: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    treasury: &Pubkey,
+    user_profile: &Pubkey,
+    token_mint: &Pubkey,
+    user_token_account: &Pubkey,
+    treasury_token_account: &Pubkey,
+    supported_mint: &Pubkey,
+    mint_stake: &Pubkey,
+    amount: u64,
 ) -> Instruction {
     let discriminator = anchor_discriminator("stake_tokens");
     let mut data = discriminator.to_vec();
@@ -188,6 +338,42 @@ pub fn stake_tokens_instruction(
             AccountMeta::new_readonly(*token_mint, false),
             AccountMeta::new(*user_token_account, false),
             AccountMeta::new(*treasury_token_account, false),
+            AccountMeta::new(*supported_mint, false),
+            AccountMeta::new(*mint_stake, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+pub fn add_supported_mint_instruction(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    treasury: &Pubkey,
+    treasury_authority: &Pubkey,
+    token_mint: &Pubkey,
+    supported_mint: &Pubkey,
+    treasury_token_account: &Pubkey,
+    weight_bps: u16,
+) -> Instruction {
+    let discriminator = anchor_discriminator("add_supported_mint");
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&weight_bps.to_le_bytes());
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(*config, false),
+            AccountMeta::new_readonly(*treasury, false),
+            AccountMeta::new_readonly(*treasury_authority, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new(*supported_mint, false),
+            AccountMeta::new(*treasury_token_account, false),
             AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
             AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
             AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
@@ -196,6 +382,100 @@ pub fn stake_tokens_instruction(
     }
 }
 
+pub fn unstake_tokens_instruction(
+    user: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    treasury: &Pubkey,
+    user_profile: &Pubkey,
+    unbonding_ticket: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let discriminator = anchor_discriminator("unstake_tokens");
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(*config, false),
+            AccountMeta::new(*treasury, false),
+            AccountMeta::new(*user_profile, false),
+            AccountMeta::new(*unbonding_ticket, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+pub fn claim_unbonded_instruction(
+    user: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    treasury: &Pubkey,
+    treasury_authority: &Pubkey,
+    unbonding_ticket: &Pubkey,
+    token_mint: &Pubkey,
+    user_token_account: &Pubkey,
+    treasury_token_account: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("claim_unbonded");
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(*config, false),
+            AccountMeta::new(*treasury, false),
+            AccountMeta::new_readonly(*treasury_authority, false),
+            AccountMeta::new(*unbonding_ticket, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new(*user_token_account, false),
+            AccountMeta::new(*treasury_token_account, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
+pub fn claim_rewards_instruction(
+    user: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    treasury: &Pubkey,
+    treasury_authority: &Pubkey,
+    user_profile: &Pubkey,
+    token_mint: &Pubkey,
+    user_token_account: &Pubkey,
+    treasury_token_account: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("claim_rewards");
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(*config, false),
+            AccountMeta::new_readonly(*treasury, false),
+            AccountMeta::new_readonly(*treasury_authority, false),
+            AccountMeta::new(*user_profile, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new(*user_token_account, false),
+            AccountMeta::new(*treasury_token_account, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
 pub fn upvote_instruction(
     voter: &Pubkey,
     admin: &Pubkey,
@@ -205,6 +485,7 @@ pub fn upvote_instruction(
     target_user_profile: &Pubkey,
     vote_cooldown: &Pubkey,
     vote_record: &Pubkey,
+    vote_history: &Pubkey,
     target_username: &str,
 ) -> Instruction {
     let discriminator = anchor_discriminator("upvote");
@@ -224,6 +505,7 @@ pub fn upvote_instruction(
             AccountMeta::new(*target_user_profile, false),
             AccountMeta::new(*vote_cooldown, false),
             AccountMeta::new(*vote_record, false),
+            AccountMeta::new(*vote_history, false),
             AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
         ],
         data,
@@ -239,6 +521,7 @@ pub fn downvote_instruction(
     target_user_profile: &Pubkey,
     vote_cooldown: &Pubkey,
     vote_record: &Pubkey,
+    vote_history: &Pubkey,
     target_username: &str,
 ) -> Instruction {
     let discriminator = anchor_discriminator("downvote");
@@ -258,19 +541,852 @@ pub fn downvote_instruction(
             AccountMeta::new(*target_user_profile, false),
             AccountMeta::new(*vote_cooldown, false),
             AccountMeta::new(*vote_record, false),
+            AccountMeta::new(*vote_history, false),
             AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
         ],
         data,
     }
 }
 
+pub fn create_governance_proposal_instruction(
+    proposer: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    proposer_profile: &Pubkey,
+    proposal: &Pubkey,
+    title: &str,
+) -> Instruction {
+    let discriminator = anchor_discriminator("create_governance_proposal");
+    let mut data = discriminator.to_vec();
+    let title_len = title.len() as u32;
+    data.extend_from_slice(&title_len.to_le_bytes());
+    data.extend_from_slice(title.as_bytes());
 
-// Advance the SVM clock by the specified number of seconds
-pub fn advance_time(svm: &mut LiteSVM, seconds: u64) {
-    let mut clock: solana_sdk::clock::Clock = svm.get_sysvar();
-    clock.unix_timestamp += seconds as i64;
-    svm.set_sysvar(&clock);
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*proposer, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new(*config, false),
+            AccountMeta::new_readonly(*proposer_profile, false),
+            AccountMeta::new(*proposal, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
 
-    let current_slot = clock.slot;
-    svm.warp_to_slot(current_slot + (seconds * 2) + 5);
+// Ballot without a vote escrow; the program ID stands in for the absent optional account
+pub fn cast_ballot_instruction(
+    voter: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    voter_profile: &Pubkey,
+    proposal: &Pubkey,
+    ballot: &Pubkey,
+    support: bool,
+) -> Instruction {
+    cast_ballot_with_escrow_instruction(
+        voter,
+        admin,
+        config,
+        voter_profile,
+        proposal,
+        ballot,
+        &GOVERNANCE_PROGRAM_ID,
+        support,
+    )
+}
+
+pub fn cast_ballot_with_escrow_instruction(
+    voter: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    voter_profile: &Pubkey,
+    proposal: &Pubkey,
+    ballot: &Pubkey,
+    vote_escrow: &Pubkey,
+    support: bool,
+) -> Instruction {
+    let discriminator = anchor_discriminator("cast_ballot");
+    let mut data = discriminator.to_vec();
+    data.push(support as u8);
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*voter, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(*config, false),
+            AccountMeta::new_readonly(*voter_profile, false),
+            AccountMeta::new(*proposal, false),
+            AccountMeta::new(*ballot, false),
+            AccountMeta::new_readonly(*vote_escrow, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Bytes a voter signs for a batch entry: proposal | support | weight
+pub fn vote_message(proposal: &Pubkey, support: bool, weight: u64) -> Vec<u8> {
+    let mut message = proposal.to_bytes().to_vec();
+    message.push(support as u8);
+    message.extend_from_slice(&weight.to_le_bytes());
+    message
+}
+
+// Ed25519 precompile instruction with a single signature
+// Key at 16, signature at 48, message at 112; every index set to `instruction_index`
+// (u16::MAX means this instruction)
+pub fn ed25519_instruction(
+    public_key: &Pubkey,
+    signature: &[u8],
+    message: &[u8],
+    instruction_index: u16,
+) -> Instruction {
+    let mut data = vec![1u8, 0];
+    for value in [48, instruction_index, 16, instruction_index, 112, message.len() as u16, instruction_index] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(public_key.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ED25519_PROGRAM_ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+// Entries are (voter, support, weight); each brings its profile and ballot
+pub fn submit_vote_batch_instruction(
+    relayer: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    proposal: &Pubkey,
+    entries: &[(Pubkey, bool, u64)],
+) -> Instruction {
+    let discriminator = anchor_discriminator("submit_vote_batch");
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+
+    let mut accounts = vec![
+        AccountMeta::new(*relayer, true),
+        AccountMeta::new_readonly(*admin, false),
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*proposal, false),
+        AccountMeta::new_readonly(INSTRUCTIONS_SYSVAR_ID, false),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+    ];
+    for (voter, support, weight) in entries {
+        data.extend_from_slice(voter.as_ref());
+        data.push(*support as u8);
+        data.extend_from_slice(&weight.to_le_bytes());
+        accounts.push(AccountMeta::new_readonly(derive_user_profile_pda(voter).0, false));
+        accounts.push(AccountMeta::new(derive_ballot_pda(proposal, voter).0, false));
+    }
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts,
+        data,
+    }
+}
+
+pub fn finalize_proposal_instruction(
+    caller: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    proposal: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("finalize_proposal");
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*caller, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(*config, false),
+            AccountMeta::new(*proposal, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
+pub fn delegate_votes_instruction(
+    delegator: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    delegator_profile: &Pubkey,
+    delegate_profile: &Pubkey,
+    delegation: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("delegate_votes");
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*delegator, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(*config, false),
+            AccountMeta::new(*delegator_profile, false),
+            AccountMeta::new(*delegate_profile, false),
+            AccountMeta::new(*delegation, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
+pub fn revoke_delegation_instruction(
+    delegator: &Pubkey,
+    delegator_profile: &Pubkey,
+    delegate_profile: &Pubkey,
+    delegation: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("revoke_delegation");
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*delegator, true),
+            AccountMeta::new(*delegator_profile, false),
+            AccountMeta::new(*delegate_profile, false),
+            AccountMeta::new(*delegation, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
+pub fn decay_reputation_instruction(
+    caller: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    user_profile: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("decay_reputation");
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*caller, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(*config, false),
+            AccountMeta::new(*user_profile, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
+pub fn change_username_instruction(
+    user: &Pubkey,
+    user_profile: &Pubkey,
+    old_registry: &Pubkey,
+    new_registry: &Pubkey,
+    new_username: &str,
+) -> Instruction {
+    let discriminator = anchor_discriminator("change_username");
+    let mut data = discriminator.to_vec();
+    let username_len = new_username.len() as u32;
+    data.extend_from_slice(&username_len.to_le_bytes());
+    data.extend_from_slice(new_username.as_bytes());
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new(*user_profile, false),
+            AccountMeta::new(*old_registry, false),
+            AccountMeta::new(*new_registry, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+pub fn update_profile_metadata_instruction(
+    user: &Pubkey,
+    user_profile: &Pubkey,
+    metadata_uri: Option<&str>,
+) -> Instruction {
+    let discriminator = anchor_discriminator("update_profile_metadata");
+    let mut data = discriminator.to_vec();
+    match metadata_uri {
+        Some(uri) => {
+            data.push(1);
+            data.extend_from_slice(&(uri.len() as u32).to_le_bytes());
+            data.extend_from_slice(uri.as_bytes());
+        }
+        None => data.push(0),
+    }
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new(*user_profile, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+pub fn close_profile_instruction(
+    user: &Pubkey,
+    user_profile: &Pubkey,
+    user_registry: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("close_profile");
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new(*user_profile, false),
+            AccountMeta::new(*user_registry, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
+pub fn set_admin_instruction(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    new_admin: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("set_admin");
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(new_admin.as_ref());
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, false),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new(*config, false),
+        ],
+        data,
+    }
+}
+
+pub fn init_council_instruction(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    council: &Pubkey,
+    members: &[Pubkey],
+    threshold: u8,
+    veto_window: i64,
+) -> Instruction {
+    let discriminator = anchor_discriminator("init_council");
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&(members.len() as u32).to_le_bytes());
+    for member in members {
+        data.extend_from_slice(member.as_ref());
+    }
+    data.push(threshold);
+    data.extend_from_slice(&veto_window.to_le_bytes());
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(*config, false),
+            AccountMeta::new(*council, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+pub fn council_set_pause_instruction(
+    member: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    council: &Pubkey,
+    paused: bool,
+) -> Instruction {
+    let discriminator = anchor_discriminator("council_set_pause");
+    let mut data = discriminator.to_vec();
+    data.push(paused as u8);
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*member, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new(*config, false),
+            AccountMeta::new_readonly(*council, false),
+        ],
+        data,
+    }
+}
+
+pub fn council_veto_instruction(
+    member: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    council: &Pubkey,
+    proposal: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("council_veto");
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*member, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(*config, false),
+            AccountMeta::new_readonly(*council, false),
+            AccountMeta::new(*proposal, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
+pub fn reset_user_reputation_instruction(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    user_profile: &Pubkey,
+    season_snapshot: &Pubkey,
+    user: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("reset_user_reputation");
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(user.as_ref());
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(*config, false),
+            AccountMeta::new(*user_profile, false),
+            AccountMeta::new(*season_snapshot, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+pub fn slash_stake_instruction(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    treasury: &Pubkey,
+    treasury_authority: &Pubkey,
+    user_profile: &Pubkey,
+    token_mint: &Pubkey,
+    treasury_ata: &Pubkey,
+    slash_bps: u16,
+    burn: bool,
+) -> Instruction {
+    let discriminator = anchor_discriminator("slash_stake");
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&slash_bps.to_le_bytes());
+    data.push(burn as u8);
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(*config, false),
+            AccountMeta::new(*treasury, false),
+            AccountMeta::new_readonly(*treasury_authority, false),
+            AccountMeta::new(*user_profile, false),
+            AccountMeta::new(*token_mint, false),
+            AccountMeta::new(*treasury_ata, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+pub fn close_season_instruction(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    treasury: &Pubkey,
+    season_snapshot: &Pubkey,
+    reward_pool: u64,
+) -> Instruction {
+    let discriminator = anchor_discriminator("close_season");
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&reward_pool.to_le_bytes());
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new(*config, false),
+            AccountMeta::new_readonly(*treasury, false),
+            AccountMeta::new(*season_snapshot, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+pub fn claim_season_reward_instruction(
+    user: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    treasury: &Pubkey,
+    treasury_authority: &Pubkey,
+    season_snapshot: &Pubkey,
+    token_mint: &Pubkey,
+    user_token_account: &Pubkey,
+    treasury_token_account: &Pubkey,
+    season_id: u64,
+) -> Instruction {
+    let discriminator = anchor_discriminator("claim_season_reward");
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&season_id.to_le_bytes());
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(*config, false),
+            AccountMeta::new_readonly(*treasury, false),
+            AccountMeta::new_readonly(*treasury_authority, false),
+            AccountMeta::new(*season_snapshot, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new(*user_token_account, false),
+            AccountMeta::new(*treasury_token_account, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+pub fn close_vote_record_instruction(
+    voter: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    vote_record: &Pubkey,
+    target_username: &str,
+) -> Instruction {
+    let discriminator = anchor_discriminator("close_vote_record");
+    let mut data = discriminator.to_vec();
+    let username_len = target_username.len() as u32;
+    data.extend_from_slice(&username_len.to_le_bytes());
+    data.extend_from_slice(target_username.as_bytes());
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*voter, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(*config, false),
+            AccountMeta::new(*vote_record, false),
+        ],
+        data,
+    }
+}
+
+pub fn close_cooldown_instruction(
+    voter: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    vote_cooldown: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("close_cooldown");
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*voter, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(*config, false),
+            AccountMeta::new(*vote_cooldown, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
+pub fn lock_tokens_instruction(
+    user: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    vote_escrow: &Pubkey,
+    token_mint: &Pubkey,
+    user_token_account: &Pubkey,
+    escrow_token_account: &Pubkey,
+    amount: u64,
+    duration: i64,
+) -> Instruction {
+    let discriminator = anchor_discriminator("lock_tokens");
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(&duration.to_le_bytes());
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(*config, false),
+            AccountMeta::new(*vote_escrow, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new(*user_token_account, false),
+            AccountMeta::new(*escrow_token_account, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+pub fn withdraw_expired_lock_instruction(
+    user: &Pubkey,
+    admin: &Pubkey,
+    config: &Pubkey,
+    vote_escrow: &Pubkey,
+    token_mint: &Pubkey,
+    user_token_account: &Pubkey,
+    escrow_token_account: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("withdraw_expired_lock");
+
+    Instruction {
+        program_id: GOVERNANCE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(*admin, false),
+            AccountMeta::new_readonly(*config, false),
+            AccountMeta::new(*vote_escrow, false),
+            AccountMeta::new_readonly(*token_mint, false),
+            AccountMeta::new(*user_token_account, false),
+            AccountMeta::new(*escrow_token_account, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
+// Read stake_amount from a user profile account
+// Layout: discriminator (8) + username (4 + len) + owner (32) + reputation_points (8) + stake_amount (8)
+pub fn get_stake_amount(svm: &LiteSVM, profile: &Pubkey) -> u64 {
+    let account = svm.get_account(profile).expect("Profile should exist");
+    let data = &account.data;
+    let username_len = u32::from_le_bytes(data[8..12].try_into().unwrap()) as usize;
+    let offset = 12 + username_len + 32 + 8;
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+// Read weighted_stake from a user profile account
+// Layout: stake_amount as above, then role_level (1) + 6 u64/i64 counters and timestamps
+// + delegated_to (32) + delegated_stake, last_decay_ts, reward_debt, last_reward_ts (4 * 8)
+pub fn get_weighted_stake(svm: &LiteSVM, profile: &Pubkey) -> u64 {
+    let account = svm.get_account(profile).expect("Profile should exist");
+    let data = &account.data;
+    let username_len = u32::from_le_bytes(data[8..12].try_into().unwrap()) as usize;
+    let offset = 12 + username_len + 32 + 8 + 8 + 1 + 6 * 8 + 32 + 4 * 8;
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+// Read the current admin from the config account
+// Layout: discriminator (8) + admin (32)
+pub fn get_config_admin(svm: &LiteSVM, config: &Pubkey) -> Pubkey {
+    let account = svm.get_account(config).expect("Config should exist");
+    Pubkey::new_from_array(account.data[8..40].try_into().unwrap())
+}
+
+// Read the owner from a username registry account
+// Layout: discriminator (8) + claimed (1) + owner (32)
+pub fn get_registry_owner(svm: &LiteSVM, registry: &Pubkey) -> Pubkey {
+    let account = svm.get_account(registry).expect("Registry should exist");
+    Pubkey::new_from_array(account.data[9..41].try_into().unwrap())
+}
+
+// Read reputation_points from a user profile account
+// Layout: discriminator (8) + username (4 + len) + owner (32) + reputation_points (8)
+pub fn get_reputation(svm: &LiteSVM, profile: &Pubkey) -> i64 {
+    let account = svm.get_account(profile).expect("Profile should exist");
+    let data = &account.data;
+    let username_len = u32::from_le_bytes(data[8..12].try_into().unwrap()) as usize;
+    let offset = 12 + username_len + 32;
+    i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+// Read (votes_for, votes_against, status) from a proposal account
+// Status: 0 = Active, 1 = Passed, 2 = Rejected, 3 = Vetoed
+pub fn get_proposal_tally(svm: &LiteSVM, proposal: &Pubkey) -> (u64, u64, u8) {
+    let account = svm.get_account(proposal).expect("Proposal should exist");
+    let data = &account.data;
+    // discriminator (8) + config (32) + proposer (32) + proposal_id (8)
+    let title_len = u32::from_le_bytes(data[80..84].try_into().unwrap()) as usize;
+    let offset = 84 + title_len;
+    let votes_for = u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+    let votes_against = u64::from_le_bytes(data[offset + 8..offset + 16].try_into().unwrap());
+    let status = data[offset + 32];
+    (votes_for, votes_against, status)
+}
+
+
+// Advance the SVM clock by the specified number of seconds
+pub fn advance_time(svm: &mut LiteSVM, seconds: u64) {
+    let mut clock: solana_sdk::clock::Clock = svm.get_sysvar();
+    clock.unix_timestamp += seconds as i64;
+    svm.set_sysvar(&clock);
+
+    let current_slot = clock.slot;
+    svm.warp_to_slot(current_slot + (seconds * 2) + 5);
+}
+
+// DAO with a funded admin, a fresh governance mint and an initialized treasury
+pub struct DaoScenario {
+    pub svm: LiteSVM,
+    pub admin: Keypair,
+    pub mint: Pubkey,
+    pub config_pda: Pubkey,
+    pub treasury_pda: Pubkey,
+    pub treasury_authority: Pubkey,
+    pub treasury_token_account: Pubkey,
+}
+
+// Set up a DAO with default parameters and a vote power of 5
+pub fn setup_dao_scenario(minimum_stake: u64) -> DaoScenario {
+    setup_dao_scenario_with(|admin, config, mint| {
+        init_dao_instruction(admin, admin, config, minimum_stake, mint, 5)
+    })
+}
+
+// Set up a DAO from a custom initialize_dao instruction
+// The builder receives (admin, config, mint)
+pub fn setup_dao_scenario_with(
+    init_dao_ix: impl FnOnce(&Pubkey, &Pubkey, &Pubkey) -> Instruction,
+) -> DaoScenario {
+    let mut svm = setup_svm();
+    let admin = create_funded_account(&mut svm, 10_000_000_000);
+
+    let mint = CreateMint::new(&mut svm, &admin)
+        .decimals(DECIMALS)
+        .send()
+        .unwrap();
+
+    let (config_pda, _) = derive_config_pda(&admin.pubkey());
+    let (treasury_pda, _) = derive_treasury_pda(&admin.pubkey());
+    let (treasury_authority, _) = derive_treasury_authority_pda(&config_pda, &admin.pubkey());
+    let treasury_token_account = get_associated_token_address(&treasury_authority, &mint);
+
+    let mut scenario = DaoScenario {
+        svm,
+        admin,
+        mint,
+        config_pda,
+        treasury_pda,
+        treasury_authority,
+        treasury_token_account,
+    };
+
+    let admin = scenario.admin.insecure_clone();
+    let init_dao_ix = init_dao_ix(&admin.pubkey(), &config_pda, &mint);
+    scenario.send(init_dao_ix, &admin).unwrap();
+
+    let init_treasury_ix = initialize_treasury_instruction(
+        &admin.pubkey(),
+        &admin.pubkey(),
+        &config_pda,
+        &treasury_pda,
+        &treasury_authority,
+        &treasury_token_account,
+        &mint,
+    );
+    scenario.send(init_treasury_ix, &admin).unwrap();
+
+    scenario
+}
+
+impl DaoScenario {
+    // Sign and send a single instruction with the signer as fee payer
+    pub fn send(&mut self, ix: Instruction, signer: &Keypair) -> TransactionResult {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm.send_transaction(tx)
+    }
+
+    // Sign and send several instructions as one transaction
+    pub fn send_all(&mut self, ixs: &[Instruction], signer: &Keypair) -> TransactionResult {
+        let tx = Transaction::new_signed_with_payer(
+            ixs,
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        self.svm.send_transaction(tx)
+    }
+
+    // Claim a username and create the user's profile, returning (registry, profile)
+    pub fn create_profile(&mut self, user: &Keypair, username: &str) -> (Pubkey, Pubkey) {
+        let (registry, _) = derive_user_registry_pda(username);
+        let (profile, _) = derive_user_profile_pda(&user.pubkey());
+
+        let create_profile_ix =
+            create_profile_instruction(&user.pubkey(), &registry, &profile, username);
+        self.send(create_profile_ix, user).unwrap();
+
+        (registry, profile)
+    }
+
+    // Create the owner's governance token account and mint tokens into it
+    pub fn fund_tokens(&mut self, owner: &Pubkey, amount: u64) -> Pubkey {
+        let token_account = CreateAssociatedTokenAccount::new(&mut self.svm, &self.admin, &self.mint)
+            .owner(owner)
+            .send()
+            .unwrap();
+
+        MintTo::new(&mut self.svm, &self.admin, &self.mint, &token_account, amount)
+            .send()
+            .unwrap();
+
+        token_account
+    }
+
+    // Stake tokens from the user's token account into the treasury
+    pub fn stake(
+        &mut self,
+        user: &Keypair,
+        profile: &Pubkey,
+        token_account: &Pubkey,
+        amount: u64,
+    ) -> TransactionResult {
+        let stake_ix = stake_tokens_instruction(
+            &user.pubkey(),
+            &self.admin.pubkey(),
+            &self.config_pda,
+            &self.treasury_pda,
+            profile,
+            &self.mint,
+            token_account,
+            &self.treasury_token_account,
+            amount,
+        );
+        self.send(stake_ix, user)
+    }
+
+    // Read the token balance of an SPL token account
+    pub fn token_balance(&self, token_account: &Pubkey) -> u64 {
+        get_spl_account::<spl_token::state::Account>(&self.svm, token_account)
+            .unwrap()
+            .amount
+    }
 }
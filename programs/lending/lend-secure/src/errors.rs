@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+pub use soteria_errors::lend_secure::LendingError as LendingErrorCode;
+
+#[error_code]
+pub enum LendingError {
+    #[msg("Collateral factor cannot exceed maximum allowed (9000 = 90%)")]
+    CollateralFactorTooHigh,
+
+    #[msg("Liquidation threshold must be above the collateral factor and at most 9500 (95%)")]
+    InvalidLiquidationThreshold,
+
+    #[msg("Liquidation bonus cannot exceed maximum allowed (2000 = 20%)")]
+    LiquidationBonusTooHigh,
+
+    #[msg("Borrow rate cannot exceed maximum allowed (10000 = 100% APR)")]
+    BorrowRateTooHigh,
+
+    #[msg("Collateral and borrow mints must be different")]
+    IdenticalMints,
+
+    #[msg("Unauthorized: Cannot perform this action")]
+    Unauthorized,
+
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Price must be greater than zero")]
+    InvalidPrice,
+
+    #[msg("Market price has not been set")]
+    PriceNotSet,
+
+    #[msg("Market price is older than the maximum allowed age")]
+    StalePrice,
+
+    #[msg("Withdrawal exceeds deposited collateral")]
+    InsufficientCollateral,
+
+    #[msg("Not enough liquidity in the reserve for this borrow")]
+    InsufficientLiquidity,
+
+    #[msg("Borrow would exceed the position's borrowing power")]
+    BorrowLimitExceeded,
+
+    #[msg("Withdrawal would leave the position undercollateralized")]
+    WithdrawalUndercollateralized,
+
+    #[msg("Position has no outstanding debt")]
+    NoDebt,
+
+    #[msg("Position is healthy and cannot be liquidated")]
+    PositionHealthy,
+
+    #[msg("Repay amount exceeds the close factor for a single liquidation")]
+    LiquidationTooLarge,
+
+    #[msg("Obligation belongs to a different market")]
+    MarketMismatch,
+
+    #[msg("Arithmetic overflow")]
+    Overflow,
+
+    #[msg("Arithmetic underflow")]
+    Underflow,
+
+    #[msg("Division by zero")]
+    DivisionByZero,
+}
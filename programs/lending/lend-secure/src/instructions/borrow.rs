@@ -0,0 +1,134 @@
+// Borrow Instruction
+//
+// Borrows tokens from the reserve against the owner's deposited collateral.
+//
+// HOW IT WORKS:
+// 1. Accrues interest so existing debt is current
+// 2. Health check: existing debt + new borrow must fit within
+//    collateral_value * collateral_factor
+// 3. Transfers borrow tokens out of the liquidity vault
+// 4. Records the new debt on the obligation and the market
+//
+// SECURITY:
+// - Only the obligation owner can borrow (has_one + PDA seeds)
+// - Health factor checked on the whole position, not just the new amount
+// - Price must be fresh (MAX_PRICE_AGE_SECONDS)
+// - Reserve must hold enough liquidity
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct Borrow<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            MARKET_SEED,
+            market.collateral_mint.as_ref(),
+            market.borrow_mint.as_ref(),
+        ],
+        bump = market.market_bump,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    /// CHECK: PDA signer, validated by seeds
+    #[account(
+        seeds = [MARKET_AUTHORITY_SEED, market.key().as_ref()],
+        bump = market.authority_bump,
+    )]
+    pub market_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [OBLIGATION_SEED, market.key().as_ref(), owner.key().as_ref()],
+        bump = obligation.bump,
+        has_one = owner @ LendingError::Unauthorized,
+    )]
+    pub obligation: Box<Account<'info, Obligation>>,
+
+    #[account(address = market.borrow_mint)]
+    pub borrow_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = borrow_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_borrow_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, address = market.liquidity_vault)]
+    pub liquidity_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> Borrow<'info> {
+    pub fn borrow(&mut self, amount: u64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        // Bring the market and position up to date before any health math
+        self.market.accrue_interest(now)?;
+        self.obligation.settle_interest(self.market.borrow_index)?;
+
+        require!(amount > 0, LendingError::ZeroAmount);
+
+        // Stale prices are rejected - the collateral may have moved since
+        let price = self.market.fresh_price(now)?;
+
+        let new_debt = self
+            .obligation
+            .borrowed_amount
+            .checked_add(amount)
+            .ok_or(LendingError::Overflow)?;
+
+        // Health factor: total debt after this borrow must fit the collateral factor
+        require!(
+            is_within_limit(
+                self.obligation.collateral_amount,
+                new_debt,
+                price,
+                self.market.collateral_factor_bps,
+            )?,
+            LendingError::BorrowLimitExceeded
+        );
+
+        require!(
+            self.liquidity_vault.amount >= amount,
+            LendingError::InsufficientLiquidity
+        );
+
+        let market_key = self.market.key();
+        let authority_seeds = &[
+            MARKET_AUTHORITY_SEED,
+            market_key.as_ref(),
+            &[self.market.authority_bump],
+        ];
+
+        transfer_from_vault(
+            amount,
+            self.borrow_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.liquidity_vault.to_account_info(),
+            &self.borrow_mint.to_account_info(),
+            &self.owner_borrow_account.to_account_info(),
+            &self.market_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        self.obligation.borrowed_amount = new_debt;
+        self.market.total_borrows = self
+            .market
+            .total_borrows
+            .checked_add(amount)
+            .ok_or(LendingError::Overflow)?;
+
+        msg!("Borrowed {} (total debt {})", amount, new_debt);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+// Instructions Module
+//
+// Exports all instruction handlers for the lending program
+
+pub mod initialize_market;
+pub mod update_price;
+pub mod fund_reserve;
+pub mod deposit;
+pub mod withdraw;
+pub mod borrow;
+pub mod repay;
+pub mod liquidate;
+
+pub use initialize_market::*;
+pub use update_price::*;
+pub use fund_reserve::*;
+pub use deposit::*;
+pub use withdraw::*;
+pub use borrow::*;
+pub use repay::*;
+pub use liquidate::*;
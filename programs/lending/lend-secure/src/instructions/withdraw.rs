@@ -0,0 +1,127 @@
+// Withdraw Instruction
+//
+// Withdraws collateral from the owner's obligation.
+//
+// HOW IT WORKS:
+// 1. Accrues interest so the position's debt is current
+// 2. Removes the collateral from the obligation
+// 3. If the position still has debt, checks the remaining collateral
+//    covers it at the collateral factor
+// 4. Transfers collateral out of the vault, signed by the market authority
+//
+// SECURITY:
+// - Only the obligation owner can withdraw (has_one + PDA seeds)
+// - Health check runs against a fresh price (MAX_PRICE_AGE_SECONDS)
+// - Cannot withdraw more than deposited
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            MARKET_SEED,
+            market.collateral_mint.as_ref(),
+            market.borrow_mint.as_ref(),
+        ],
+        bump = market.market_bump,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    /// CHECK: PDA signer, validated by seeds
+    #[account(
+        seeds = [MARKET_AUTHORITY_SEED, market.key().as_ref()],
+        bump = market.authority_bump,
+    )]
+    pub market_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [OBLIGATION_SEED, market.key().as_ref(), owner.key().as_ref()],
+        bump = obligation.bump,
+        has_one = owner @ LendingError::Unauthorized,
+    )]
+    pub obligation: Box<Account<'info, Obligation>>,
+
+    #[account(address = market.collateral_mint)]
+    pub collateral_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_collateral_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, address = market.collateral_vault)]
+    pub collateral_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> Withdraw<'info> {
+    pub fn withdraw(&mut self, amount: u64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        // Bring the market and position up to date before any health math
+        self.market.accrue_interest(now)?;
+        self.obligation.settle_interest(self.market.borrow_index)?;
+
+        require!(amount > 0, LendingError::ZeroAmount);
+        require!(
+            amount <= self.obligation.collateral_amount,
+            LendingError::InsufficientCollateral
+        );
+
+        let remaining_collateral = self
+            .obligation
+            .collateral_amount
+            .checked_sub(amount)
+            .ok_or(LendingError::Underflow)?;
+
+        // Positions with debt must stay within the collateral factor
+        if self.obligation.borrowed_amount > 0 {
+            let price = self.market.fresh_price(now)?;
+            require!(
+                is_within_limit(
+                    remaining_collateral,
+                    self.obligation.borrowed_amount,
+                    price,
+                    self.market.collateral_factor_bps,
+                )?,
+                LendingError::WithdrawalUndercollateralized
+            );
+        }
+
+        let market_key = self.market.key();
+        let authority_seeds = &[
+            MARKET_AUTHORITY_SEED,
+            market_key.as_ref(),
+            &[self.market.authority_bump],
+        ];
+
+        transfer_from_vault(
+            amount,
+            self.collateral_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.collateral_vault.to_account_info(),
+            &self.collateral_mint.to_account_info(),
+            &self.owner_collateral_account.to_account_info(),
+            &self.market_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        self.obligation.collateral_amount = remaining_collateral;
+
+        msg!("Withdrew {} collateral", amount);
+
+        Ok(())
+    }
+}
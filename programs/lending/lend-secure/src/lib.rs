@@ -0,0 +1,92 @@
+// Lending Protocol Program - SECURE VERSION
+//
+// Implementation of an over-collateralized lending market for one
+// collateral/borrow token pair.
+// This program allows users to:
+// 1. Deposit collateral and withdraw it while their position stays healthy
+// 2. Borrow from the market's reserve up to the collateral factor
+// 3. Repay debt, including interest accrued over time
+// 4. Liquidate positions that cross the liquidation threshold for a bonus
+//
+// SECURITY FEATURES:
+// - Health factor checked on the whole position for borrows and withdrawals
+// - Oracle price rejected once older than MAX_PRICE_AGE_SECONDS
+// - Risk parameters bounded at market creation
+// - Interest accrued before every debt calculation
+// - Close factor limits each liquidation to half the debt
+// - Checked arithmetic to prevent overflow/underflow
+//
+// INTEREST MODEL:
+// A per-market borrow index grows linearly at borrow_rate_bps APR.
+// Each obligation stores the index at its last update and scales its debt
+// by index_now / index_then whenever it is touched.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("4aGptr3JavwtTNBsHjZJAnH6XXKetHQjsGT4mcz1JppT");
+
+#[program]
+pub mod lend_secure {
+    use super::*;
+
+    // Create a lending market for a collateral/borrow mint pair
+    // Only needs to be called once per pair
+    pub fn initialize_market(
+        ctx: Context<InitializeMarket>,
+        collateral_factor_bps: u16,
+        liquidation_threshold_bps: u16,
+        liquidation_bonus_bps: u16,
+        borrow_rate_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts.initialize_market(
+            collateral_factor_bps,
+            liquidation_threshold_bps,
+            liquidation_bonus_bps,
+            borrow_rate_bps,
+            &ctx.bumps,
+        )
+    }
+
+    // Post a new collateral price (market authority acts as the oracle)
+    pub fn update_price(ctx: Context<UpdatePrice>, price: u64) -> Result<()> {
+        ctx.accounts.update_price(price)
+    }
+
+    // Add borrowable liquidity to the reserve
+    pub fn fund_reserve(ctx: Context<FundReserve>, amount: u64) -> Result<()> {
+        ctx.accounts.fund_reserve(amount)
+    }
+
+    // Deposit collateral, creating the caller's obligation on first use
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        ctx.accounts.deposit(amount, &ctx.bumps)
+    }
+
+    // Withdraw collateral while keeping the position within the collateral factor
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw(amount)
+    }
+
+    // Borrow from the reserve against deposited collateral
+    pub fn borrow(ctx: Context<Borrow>, amount: u64) -> Result<()> {
+        ctx.accounts.borrow(amount)
+    }
+
+    // Repay debt on any obligation
+    pub fn repay(ctx: Context<Repay>, amount: u64) -> Result<()> {
+        ctx.accounts.repay(amount)
+    }
+
+    // Repay part of an unhealthy position's debt and seize its collateral
+    pub fn liquidate(ctx: Context<Liquidate>, repay_amount: u64) -> Result<()> {
+        ctx.accounts.liquidate(repay_amount)
+    }
+}
@@ -0,0 +1,130 @@
+// Market State
+//
+// Stores the configuration, oracle price, and interest index for a single
+// lending market. One Market exists per collateral/borrow mint pair
+// (e.g., SOL collateral borrowing USDC).
+//
+// INTEREST MODEL:
+// Borrows accrue simple interest per second at borrow_rate_bps APR.
+// borrow_index starts at INDEX_SCALE (1.0) and grows every time interest is accrued:
+//   index_new = index_old * (1 + rate * elapsed / SECONDS_PER_YEAR)
+// Each obligation remembers the index at its last update, so its debt is
+//   debt_now = debt_then * index_now / index_then
+
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*};
+
+#[account]
+#[derive(InitSpace)]
+pub struct Market {
+    // Market creator and administrator
+    // Only this address can post prices and fund the reserve
+    pub authority: Pubkey,
+
+    // Mint users deposit as collateral
+    pub collateral_mint: Pubkey,
+
+    // Mint users borrow from the reserve
+    pub borrow_mint: Pubkey,
+
+    // Vault holding all deposited collateral (owned by market authority PDA)
+    pub collateral_vault: Pubkey,
+
+    // Vault holding lendable liquidity (owned by market authority PDA)
+    pub liquidity_vault: Pubkey,
+
+    // Maximum loan-to-value for new borrows and withdrawals, in basis points
+    // Example: 7500 = borrow up to 75% of collateral value
+    pub collateral_factor_bps: u16,
+
+    // Loan-to-value above which a position can be liquidated, in basis points
+    pub liquidation_threshold_bps: u16,
+
+    // Extra collateral paid to liquidators, in basis points of the repaid debt
+    pub liquidation_bonus_bps: u16,
+
+    // Annual borrow interest rate in basis points
+    pub borrow_rate_bps: u16,
+
+    // Collateral price in borrow base units per collateral base unit, scaled by PRICE_SCALE
+    // 0 until the authority posts the first price
+    pub price: u64,
+
+    // Unix timestamp of the last price update
+    pub price_updated_at: i64,
+
+    // Cumulative borrow index, scaled by INDEX_SCALE
+    pub borrow_index: u128,
+
+    // Unix timestamp of the last interest accrual
+    pub last_accrual_ts: i64,
+
+    // Total outstanding debt across all obligations, including accrued interest
+    pub total_borrows: u64,
+
+    // PDA bump seeds (stored to avoid recomputation)
+    pub market_bump: u8,       // Bump for this market PDA
+    pub authority_bump: u8,    // Bump for market authority PDA
+}
+
+impl Market {
+    // Accrue interest up to `now`
+    // Called at the start of every instruction that reads or changes debt
+    pub fn accrue_interest(&mut self, now: i64) -> Result<()> {
+        let elapsed = now.saturating_sub(self.last_accrual_ts);
+        if elapsed <= 0 {
+            return Ok(());
+        }
+
+        let growth = self
+            .borrow_index
+            .checked_mul(self.borrow_rate_bps as u128)
+            .ok_or(LendingError::Overflow)?
+            .checked_mul(elapsed as u128)
+            .ok_or(LendingError::Overflow)?
+            .checked_div(BASIS_POINTS_DIVISOR as u128 * SECONDS_PER_YEAR)
+            .ok_or(LendingError::DivisionByZero)?;
+
+        let new_index = self
+            .borrow_index
+            .checked_add(growth)
+            .ok_or(LendingError::Overflow)?;
+
+        // Grow total borrows by the same factor as the index
+        let new_total_borrows = (self.total_borrows as u128)
+            .checked_mul(new_index)
+            .ok_or(LendingError::Overflow)?
+            .checked_div(self.borrow_index)
+            .ok_or(LendingError::DivisionByZero)?;
+
+        self.total_borrows = u64::try_from(new_total_borrows).map_err(|_| LendingError::Overflow)?;
+        self.borrow_index = new_index;
+        self.last_accrual_ts = now;
+
+        Ok(())
+    }
+
+    // Return the market price, rejecting unset or stale prices
+    // Every health calculation goes through this
+    pub fn fresh_price(&self, now: i64) -> Result<u64> {
+        require!(self.price > 0, LendingError::PriceNotSet);
+
+        let age = now
+            .checked_sub(self.price_updated_at)
+            .ok_or(LendingError::Underflow)?;
+        require!(
+            cfg!(feature = "vuln-missing-staleness-check") || age <= MAX_PRICE_AGE_SECONDS,
+            LendingError::StalePrice
+        );
+
+        Ok(self.price)
+    }
+
+    // Assert caller is the market authority
+    // Used to restrict price updates and reserve funding
+    pub fn assert_is_authority(&self, caller: &Pubkey) -> Result<()> {
+        require!(self.authority == *caller, LendingError::Unauthorized);
+        Ok(())
+    }
+}
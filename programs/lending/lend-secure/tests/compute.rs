@@ -0,0 +1,100 @@
+// Compute-unit regression tests for lend-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+mod utils;
+
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signature::Signer};
+use soteria_test_kit::{ComputeBudget, DEFAULT_CU_BASELINES_FILE};
+use utils::*;
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let collateral_mint = CreateMint::new(&mut svm, &authority)
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create collateral mint");
+    let borrow_mint = CreateMint::new(&mut svm, &authority)
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create borrow mint");
+
+    let ix = build_initialize_market_ix(
+        &authority.pubkey(),
+        &collateral_mint,
+        &borrow_mint,
+        COLLATERAL_FACTOR_BPS,
+        LIQUIDATION_THRESHOLD_BPS,
+        LIQUIDATION_BONUS_BPS,
+        BORROW_RATE_BPS,
+    );
+    budget.record("initialize_market", send_ix(&mut svm, ix, &authority));
+    let (market, _) = derive_market_pda(&collateral_mint, &borrow_mint);
+
+    let authority_ata = CreateAssociatedTokenAccount::new(&mut svm, &authority, &borrow_mint)
+        .send()
+        .expect("Failed to create authority borrow ATA");
+    MintTo::new(&mut svm, &authority, &borrow_mint, &authority_ata, RESERVE_LIQUIDITY)
+        .send()
+        .expect("Failed to mint reserve liquidity");
+    let ix = build_fund_reserve_ix(
+        &authority.pubkey(),
+        &collateral_mint,
+        &borrow_mint,
+        RESERVE_LIQUIDITY,
+    );
+    budget.record("fund_reserve", send_ix(&mut svm, ix, &authority));
+
+    let ix = build_update_price_ix(&authority.pubkey(), &market, INITIAL_PRICE);
+    budget.record("update_price", send_ix(&mut svm, ix, &authority));
+
+    let mut scenario = LendingScenario {
+        collateral_vault: derive_vault(&market, &collateral_mint),
+        liquidity_vault: derive_vault(&market, &borrow_mint),
+        svm,
+        authority,
+        collateral_mint,
+        borrow_mint,
+        market,
+    };
+    let borrower = create_user(&mut scenario, 1_000 * ONE_TOKEN, 0);
+    let liquidator = create_user(&mut scenario, 0, 1_000 * ONE_TOKEN);
+    let owner = borrower.pubkey();
+
+    let ix = build_deposit_ix(&owner, &collateral_mint, &borrow_mint, 1_000 * ONE_TOKEN);
+    budget.record("deposit", send_ix(&mut scenario.svm, ix, &borrower));
+
+    // 1400 debt against 2000 of collateral value
+    let ix = build_borrow_ix(&owner, &collateral_mint, &borrow_mint, 1_400 * ONE_TOKEN);
+    budget.record("borrow", send_ix(&mut scenario.svm, ix, &borrower));
+
+    let ix = build_repay_ix(&owner, &owner, &collateral_mint, &borrow_mint, 100 * ONE_TOKEN);
+    budget.record("repay", send_ix(&mut scenario.svm, ix, &borrower));
+
+    // 950 * 2.0 * 75% = 1425 still covers the 1300 debt
+    let ix = build_withdraw_ix(&owner, &collateral_mint, &borrow_mint, 50 * ONE_TOKEN);
+    budget.record("withdraw", send_ix(&mut scenario.svm, ix, &borrower));
+
+    // 950 * 1.5 * 80% = 1140 < 1300 debt
+    let ix = build_update_price_ix(&scenario.authority.pubkey(), &market, 15 * PRICE_SCALE / 10);
+    send_ix(&mut scenario.svm, ix, &scenario.authority).expect("Price update should succeed");
+    let ix = build_liquidate_ix(
+        &liquidator.pubkey(),
+        &owner,
+        &collateral_mint,
+        &borrow_mint,
+        500 * ONE_TOKEN,
+    );
+    budget.record("liquidate", send_ix(&mut scenario.svm, ix, &liquidator));
+
+    budget.check();
+}
@@ -0,0 +1,53 @@
+// Seed for market PDA
+// Derived with: [MARKET_SEED, collateral_mint, borrow_mint]
+pub const MARKET_SEED: &[u8] = b"market";
+
+// Seed for market authority PDA (signer for vault operations)
+// Derived with: [MARKET_AUTHORITY_SEED, market_pubkey]
+pub const MARKET_AUTHORITY_SEED: &[u8] = b"market_authority";
+
+// Seed for obligation PDA (one per borrower per market)
+// Derived with: [OBLIGATION_SEED, market_pubkey, owner_pubkey]
+pub const OBLIGATION_SEED: &[u8] = b"obligation";
+
+// RISK PARAMETERS
+
+// Denominator for all basis point values (10000 = 100%)
+pub const BASIS_POINTS_DIVISOR: u64 = 10_000;
+
+// Maximum collateral factor (9000 basis points = 90% loan-to-value)
+// Keeps a buffer between borrowing power and the liquidation threshold
+pub const MAX_COLLATERAL_FACTOR_BPS: u16 = 9000;
+
+// Maximum liquidation threshold (9500 basis points = 95%)
+// Positions must become liquidatable before they are fully underwater
+pub const MAX_LIQUIDATION_THRESHOLD_BPS: u16 = 9500;
+
+// Maximum liquidation bonus (2000 basis points = 20%)
+// Caps how much extra collateral a liquidator can seize
+pub const MAX_LIQUIDATION_BONUS_BPS: u16 = 2000;
+
+// Share of a position's debt that can be repaid in one liquidation (50%)
+pub const CLOSE_FACTOR_BPS: u64 = 5000;
+
+// Maximum annual borrow rate (10000 basis points = 100% APR)
+pub const MAX_BORROW_RATE_BPS: u16 = 10_000;
+
+// ORACLE
+
+// Prices are quoted as borrow-token base units per collateral base unit,
+// scaled by PRICE_SCALE. Example: 2_000_000 = 1 collateral unit is worth 2 borrow units
+pub const PRICE_SCALE: u128 = 1_000_000;
+
+// Maximum age of the market price before it is rejected (60 seconds)
+pub const MAX_PRICE_AGE_SECONDS: i64 = 60;
+
+// INTEREST
+
+// Starting value of the cumulative borrow index (1.0 scaled by 1e12)
+pub const INDEX_SCALE: u128 = 1_000_000_000_000;
+
+// Seconds in a 365 day year, used to turn the APR into a per-second rate
+pub const SECONDS_PER_YEAR: u128 = 31_536_000;
+
+pub const ANCHOR_DISCRIMINATOR: usize = 8;
@@ -0,0 +1,129 @@
+// Lending Helper Functions
+//
+// Reusable risk calculation and CPI helpers for the lending program.
+// These functions reduce code duplication across instructions.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TransferChecked, transfer_checked};
+
+use crate::{constants::*, errors::*};
+
+// RISK CALCULATION HELPERS
+
+// Value of a collateral amount in borrow base units
+// Formula: value = collateral * price / PRICE_SCALE
+pub fn calculate_collateral_value(collateral_amount: u64, price: u64) -> Result<u128> {
+    let value = (collateral_amount as u128)
+        .checked_mul(price as u128)
+        .ok_or(LendingError::Overflow)?
+        .checked_div(PRICE_SCALE)
+        .ok_or(LendingError::DivisionByZero)?;
+
+    Ok(value)
+}
+
+// Maximum debt a position can carry at the given loan-to-value
+// Formula: limit = collateral_value * bps / 10000
+//
+// Used with the collateral factor for borrows and withdrawals,
+// and with the liquidation threshold for liquidations
+pub fn calculate_debt_limit(collateral_amount: u64, price: u64, bps: u16) -> Result<u128> {
+    let limit = calculate_collateral_value(collateral_amount, price)?
+        .checked_mul(bps as u128)
+        .ok_or(LendingError::Overflow)?
+        .checked_div(BASIS_POINTS_DIVISOR as u128)
+        .ok_or(LendingError::DivisionByZero)?;
+
+    Ok(limit)
+}
+
+// Health factor check: debt must not exceed the debt limit
+// Returns true when the position is within its limit
+pub fn is_within_limit(
+    collateral_amount: u64,
+    debt: u64,
+    price: u64,
+    bps: u16,
+) -> Result<bool> {
+    let limit = calculate_debt_limit(collateral_amount, price, bps)?;
+    Ok(debt as u128 <= limit)
+}
+
+// Collateral a liquidator receives for repaying `repay_amount` of debt
+// Formula: seized = repay * (10000 + bonus) / 10000 * PRICE_SCALE / price
+// Capped at the position's collateral so a bad-debt position can still be closed
+pub fn calculate_liquidation_seize(
+    repay_amount: u64,
+    price: u64,
+    liquidation_bonus_bps: u16,
+    collateral_amount: u64,
+) -> Result<u64> {
+    require!(price > 0, LendingError::InvalidPrice);
+
+    let seized = (repay_amount as u128)
+        .checked_mul(BASIS_POINTS_DIVISOR as u128 + liquidation_bonus_bps as u128)
+        .ok_or(LendingError::Overflow)?
+        .checked_mul(PRICE_SCALE)
+        .ok_or(LendingError::Overflow)?
+        .checked_div(BASIS_POINTS_DIVISOR as u128 * price as u128)
+        .ok_or(LendingError::DivisionByZero)?;
+
+    Ok(std::cmp::min(seized, collateral_amount as u128) as u64)
+}
+
+// CPI HELPERS
+
+// Generic token transfer helper
+// Used for transferring user tokens into the vaults
+pub fn transfer_tokens<'info>(
+    amount: u64,
+    decimals: u8,
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+) -> Result<()> {
+    transfer_checked(
+        CpiContext::new(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+        ),
+        amount,
+        decimals,
+    )
+}
+
+// Transfer out of a market vault, signed by the market authority PDA
+pub fn transfer_from_vault<'info>(
+    amount: u64,
+    decimals: u8,
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let signer_seeds = &[authority_seeds];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )
+}
@@ -0,0 +1,99 @@
+// Deposit Instruction
+//
+// Deposits collateral into the market and credits it to the depositor's obligation.
+//
+// HOW IT WORKS:
+// 1. Creates the depositor's obligation PDA on first deposit
+// 2. Transfers collateral from the depositor into the collateral vault
+// 3. Increases the obligation's collateral_amount
+//
+// SECURITY:
+// - Obligation PDA is derived from the market and the signer, so nobody can
+//   credit collateral to (or create) someone else's position
+// - init_if_needed only fills in ownership fields on first use
+// - Collateral vault pinned to market.collateral_vault
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            MARKET_SEED,
+            market.collateral_mint.as_ref(),
+            market.borrow_mint.as_ref(),
+        ],
+        bump = market.market_bump,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR + Obligation::INIT_SPACE,
+        seeds = [OBLIGATION_SEED, market.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub obligation: Box<Account<'info, Obligation>>,
+
+    #[account(address = market.collateral_mint)]
+    pub collateral_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_collateral_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, address = market.collateral_vault)]
+    pub collateral_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Deposit<'info> {
+    pub fn deposit(&mut self, amount: u64, bumps: &DepositBumps) -> Result<()> {
+        require!(amount > 0, LendingError::ZeroAmount);
+
+        // First deposit - record who owns this position
+        if self.obligation.owner == Pubkey::default() {
+            self.obligation.set_inner(Obligation {
+                owner: self.owner.key(),
+                market: self.market.key(),
+                collateral_amount: 0,
+                borrowed_amount: 0,
+                borrow_index_snapshot: self.market.borrow_index,
+                bump: bumps.obligation,
+            });
+        }
+
+        transfer_tokens(
+            amount,
+            self.collateral_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.owner_collateral_account.to_account_info(),
+            &self.collateral_mint.to_account_info(),
+            &self.collateral_vault.to_account_info(),
+            &self.owner.to_account_info(),
+        )?;
+
+        self.obligation.collateral_amount = self
+            .obligation
+            .collateral_amount
+            .checked_add(amount)
+            .ok_or(LendingError::Overflow)?;
+
+        msg!("Deposited {} collateral", amount);
+
+        Ok(())
+    }
+}
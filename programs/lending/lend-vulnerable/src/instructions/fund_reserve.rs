@@ -0,0 +1,66 @@
+// Fund Reserve Instruction
+//
+// Moves borrow tokens from the market authority into the liquidity vault,
+// making them available to borrowers.
+//
+// SECURITY:
+// - Only the market authority can fund the reserve
+// - Source account must hold the market's borrow mint
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct FundReserve<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            MARKET_SEED,
+            market.collateral_mint.as_ref(),
+            market.borrow_mint.as_ref(),
+        ],
+        bump = market.market_bump,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(address = market.borrow_mint)]
+    pub borrow_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = borrow_mint,
+        token::authority = authority,
+        token::token_program = token_program,
+    )]
+    pub authority_borrow_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, address = market.liquidity_vault)]
+    pub liquidity_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> FundReserve<'info> {
+    pub fn fund_reserve(&mut self, amount: u64) -> Result<()> {
+        self.market.assert_is_authority(&self.authority.key())?;
+
+        require!(amount > 0, LendingError::ZeroAmount);
+
+        transfer_tokens(
+            amount,
+            self.borrow_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.authority_borrow_account.to_account_info(),
+            &self.borrow_mint.to_account_info(),
+            &self.liquidity_vault.to_account_info(),
+            &self.authority.to_account_info(),
+        )?;
+
+        msg!("Reserve funded: {}", amount);
+
+        Ok(())
+    }
+}
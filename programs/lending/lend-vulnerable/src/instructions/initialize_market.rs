@@ -0,0 +1,152 @@
+// Initialize Market Instruction
+//
+// Creates a new lending market for a collateral/borrow mint pair.
+//
+// HOW IT WORKS:
+// 1. Validates the risk parameters (collateral factor, liquidation threshold,
+//    liquidation bonus, borrow rate)
+// 2. Creates the market PDA and two vaults owned by the market authority PDA:
+//    - collateral_vault holds every borrower's deposited collateral
+//    - liquidity_vault holds the reserve that borrowers draw from
+// 3. Starts the borrow index at 1.0 with no price set
+//
+// SECURITY:
+// - collateral_factor <= MAX_COLLATERAL_FACTOR_BPS
+// - collateral_factor < liquidation_threshold <= MAX_LIQUIDATION_THRESHOLD_BPS
+//   (a fresh borrow can never be immediately liquidatable)
+// - liquidation_bonus <= MAX_LIQUIDATION_BONUS_BPS
+// - borrow_rate <= MAX_BORROW_RATE_BPS
+// - Collateral and borrow mints must differ
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct InitializeMarket<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub collateral_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mint::token_program = token_program)]
+    pub borrow_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + Market::INIT_SPACE,
+        seeds = [
+            MARKET_SEED,
+            collateral_mint.key().as_ref(),
+            borrow_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    /// CHECK: PDA signer for vault operations
+    #[account(
+        seeds = [MARKET_AUTHORITY_SEED, market.key().as_ref()],
+        bump
+    )]
+    pub market_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = market_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub collateral_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = borrow_mint,
+        associated_token::authority = market_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub liquidity_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeMarket<'info> {
+    pub fn initialize_market(
+        &mut self,
+        collateral_factor_bps: u16,
+        liquidation_threshold_bps: u16,
+        liquidation_bonus_bps: u16,
+        borrow_rate_bps: u16,
+        bumps: &InitializeMarketBumps,
+    ) -> Result<()> {
+        // Borrowing power is capped (max 90% LTV)
+        require!(
+            collateral_factor_bps <= MAX_COLLATERAL_FACTOR_BPS,
+            LendingError::CollateralFactorTooHigh
+        );
+
+        // Liquidation starts above the collateral factor and before the position is underwater
+        require!(
+            liquidation_threshold_bps > collateral_factor_bps
+                && liquidation_threshold_bps <= MAX_LIQUIDATION_THRESHOLD_BPS,
+            LendingError::InvalidLiquidationThreshold
+        );
+
+        require!(
+            liquidation_bonus_bps <= MAX_LIQUIDATION_BONUS_BPS,
+            LendingError::LiquidationBonusTooHigh
+        );
+
+        require!(
+            borrow_rate_bps <= MAX_BORROW_RATE_BPS,
+            LendingError::BorrowRateTooHigh
+        );
+
+        require!(
+            self.collateral_mint.key() != self.borrow_mint.key(),
+            LendingError::IdenticalMints
+        );
+
+        self.market.set_inner(Market {
+            authority: self.authority.key(),
+            collateral_mint: self.collateral_mint.key(),
+            borrow_mint: self.borrow_mint.key(),
+            collateral_vault: self.collateral_vault.key(),
+            liquidity_vault: self.liquidity_vault.key(),
+            collateral_factor_bps,
+            liquidation_threshold_bps,
+            liquidation_bonus_bps,
+            borrow_rate_bps,
+            price: 0,
+            price_updated_at: 0,
+            borrow_index: INDEX_SCALE,
+            last_accrual_ts: Clock::get()?.unix_timestamp,
+            total_borrows: 0,
+            market_bump: bumps.market,
+            authority_bump: bumps.market_authority,
+        });
+
+        msg!(
+            "Market initialized: {} collateral / {} borrow",
+            self.collateral_mint.key(),
+            self.borrow_mint.key()
+        );
+        msg!(
+            "Collateral factor: {} bps, liquidation threshold: {} bps",
+            collateral_factor_bps,
+            liquidation_threshold_bps
+        );
+
+        Ok(())
+    }
+}
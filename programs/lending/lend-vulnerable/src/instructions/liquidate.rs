@@ -0,0 +1,181 @@
+// Liquidate Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// VULNERABILITY:
+// V002: Liquidation decided on a price that may be hours or days old
+//
+// Repays part of an unhealthy position's debt in exchange for its collateral
+// plus a liquidation bonus.
+//
+// HOW IT WORKS:
+// 1. Accrues interest so the position's debt is current
+// 2. Confirms the position is past the liquidation threshold at the market price
+// 3. Caps the repayment at CLOSE_FACTOR_BPS (50%) of the debt
+// 4. Liquidator pays repay_amount into the liquidity vault
+// 5. Liquidator receives repay_amount * (1 + bonus) worth of collateral
+//
+// SECURITY:
+// - Healthy positions cannot be liquidated
+// - Close factor limits how much of a position one liquidation can take
+// - Seized collateral capped at the position's collateral
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct Liquidate<'info> {
+    pub liquidator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            MARKET_SEED,
+            market.collateral_mint.as_ref(),
+            market.borrow_mint.as_ref(),
+        ],
+        bump = market.market_bump,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    /// CHECK: PDA signer, validated by seeds
+    #[account(
+        seeds = [MARKET_AUTHORITY_SEED, market.key().as_ref()],
+        bump = market.authority_bump,
+    )]
+    pub market_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [OBLIGATION_SEED, market.key().as_ref(), obligation.owner.as_ref()],
+        bump = obligation.bump,
+    )]
+    pub obligation: Box<Account<'info, Obligation>>,
+
+    #[account(address = market.collateral_mint)]
+    pub collateral_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = market.borrow_mint)]
+    pub borrow_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Liquidator pays debt from here
+    #[account(
+        mut,
+        token::mint = borrow_mint,
+        token::authority = liquidator,
+        token::token_program = token_program,
+    )]
+    pub liquidator_borrow_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Liquidator receives seized collateral here
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = liquidator,
+        token::token_program = token_program,
+    )]
+    pub liquidator_collateral_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, address = market.collateral_vault)]
+    pub collateral_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, address = market.liquidity_vault)]
+    pub liquidity_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> Liquidate<'info> {
+    pub fn liquidate(&mut self, repay_amount: u64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        self.market.accrue_interest(now)?;
+        self.obligation.settle_interest(self.market.borrow_index)?;
+
+        require!(repay_amount > 0, LendingError::ZeroAmount);
+        require!(self.obligation.borrowed_amount > 0, LendingError::NoDebt);
+
+        // VULNERABILITY V002: Stale price accepted
+        // A stale low price lets liquidators seize healthy positions, and a stale
+        // high price hides underwater positions until they become bad debt
+        let price = self.market.fresh_price(now)?;
+
+        // Only positions past the liquidation threshold can be liquidated
+        require!(
+            !is_within_limit(
+                self.obligation.collateral_amount,
+                self.obligation.borrowed_amount,
+                price,
+                self.market.liquidation_threshold_bps,
+            )?,
+            LendingError::PositionHealthy
+        );
+
+        // Close factor: at most half the debt per liquidation (rounded up so dust can be cleared)
+        let max_repay = (self.obligation.borrowed_amount as u128)
+            .checked_mul(CLOSE_FACTOR_BPS as u128)
+            .ok_or(LendingError::Overflow)?
+            .checked_add(BASIS_POINTS_DIVISOR as u128 - 1)
+            .ok_or(LendingError::Overflow)?
+            / BASIS_POINTS_DIVISOR as u128;
+        require!(
+            repay_amount as u128 <= max_repay,
+            LendingError::LiquidationTooLarge
+        );
+
+        let seized = calculate_liquidation_seize(
+            repay_amount,
+            price,
+            self.market.liquidation_bonus_bps,
+            self.obligation.collateral_amount,
+        )?;
+
+        // Liquidator repays the debt
+        transfer_tokens(
+            repay_amount,
+            self.borrow_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.liquidator_borrow_account.to_account_info(),
+            &self.borrow_mint.to_account_info(),
+            &self.liquidity_vault.to_account_info(),
+            &self.liquidator.to_account_info(),
+        )?;
+
+        // Market pays out the collateral plus bonus
+        let market_key = self.market.key();
+        let authority_seeds = &[
+            MARKET_AUTHORITY_SEED,
+            market_key.as_ref(),
+            &[self.market.authority_bump],
+        ];
+
+        transfer_from_vault(
+            seized,
+            self.collateral_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.collateral_vault.to_account_info(),
+            &self.collateral_mint.to_account_info(),
+            &self.liquidator_collateral_account.to_account_info(),
+            &self.market_authority.to_account_info(),
+            authority_seeds,
+        )?;
+
+        self.obligation.borrowed_amount = self
+            .obligation
+            .borrowed_amount
+            .checked_sub(repay_amount)
+            .ok_or(LendingError::Underflow)?;
+        self.obligation.collateral_amount = self
+            .obligation
+            .collateral_amount
+            .checked_sub(seized)
+            .ok_or(LendingError::Underflow)?;
+        self.market.total_borrows = self.market.total_borrows.saturating_sub(repay_amount);
+
+        msg!("Liquidated: repaid {}, seized {} collateral", repay_amount, seized);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+// Repay Instruction
+//
+// Repays debt on an obligation. Anyone can repay on behalf of a borrower.
+//
+// HOW IT WORKS:
+// 1. Accrues interest so the debt includes everything owed up to now
+// 2. Caps the repayment at the outstanding debt (overpayment is never taken)
+// 3. Transfers borrow tokens from the payer into the liquidity vault
+// 4. Reduces the obligation's debt and the market's total borrows
+//
+// SECURITY:
+// - Obligation pinned to this market via PDA seeds
+// - Interest accrued before the repay amount is computed
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct Repay<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            MARKET_SEED,
+            market.collateral_mint.as_ref(),
+            market.borrow_mint.as_ref(),
+        ],
+        bump = market.market_bump,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        mut,
+        seeds = [OBLIGATION_SEED, market.key().as_ref(), obligation.owner.as_ref()],
+        bump = obligation.bump,
+    )]
+    pub obligation: Box<Account<'info, Obligation>>,
+
+    #[account(address = market.borrow_mint)]
+    pub borrow_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = borrow_mint,
+        token::authority = payer,
+        token::token_program = token_program,
+    )]
+    pub payer_borrow_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, address = market.liquidity_vault)]
+    pub liquidity_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> Repay<'info> {
+    pub fn repay(&mut self, amount: u64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        self.market.accrue_interest(now)?;
+        self.obligation.settle_interest(self.market.borrow_index)?;
+
+        require!(amount > 0, LendingError::ZeroAmount);
+        require!(self.obligation.borrowed_amount > 0, LendingError::NoDebt);
+
+        // Never take more than is owed
+        let repay_amount = std::cmp::min(amount, self.obligation.borrowed_amount);
+
+        transfer_tokens(
+            repay_amount,
+            self.borrow_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.payer_borrow_account.to_account_info(),
+            &self.borrow_mint.to_account_info(),
+            &self.liquidity_vault.to_account_info(),
+            &self.payer.to_account_info(),
+        )?;
+
+        self.obligation.borrowed_amount = self
+            .obligation
+            .borrowed_amount
+            .checked_sub(repay_amount)
+            .ok_or(LendingError::Underflow)?;
+
+        // Per-obligation debt rounds up, so the sum of positions can exceed
+        // total_borrows by a few base units - floor at zero instead of failing
+        self.market.total_borrows = self.market.total_borrows.saturating_sub(repay_amount);
+
+        msg!(
+            "Repaid {} (remaining debt {})",
+            repay_amount,
+            self.obligation.borrowed_amount
+        );
+
+        Ok(())
+    }
+}
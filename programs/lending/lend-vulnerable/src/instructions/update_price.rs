@@ -0,0 +1,45 @@
+// Update Price Instruction
+//
+// Posts a new collateral price for the market. The market authority acts as
+// the oracle: it pushes prices from an off-chain feed.
+//
+// SECURITY:
+// - Only the market authority can post prices
+// - Price must be non-zero
+// - The update timestamp comes from the Clock sysvar, not the caller,
+//   so every health check can tell how old the price is
+
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct UpdatePrice<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            MARKET_SEED,
+            market.collateral_mint.as_ref(),
+            market.borrow_mint.as_ref(),
+        ],
+        bump = market.market_bump,
+    )]
+    pub market: Box<Account<'info, Market>>,
+}
+
+impl<'info> UpdatePrice<'info> {
+    pub fn update_price(&mut self, price: u64) -> Result<()> {
+        self.market.assert_is_authority(&self.authority.key())?;
+
+        require!(price > 0, LendingError::InvalidPrice);
+
+        self.market.price = price;
+        self.market.price_updated_at = Clock::get()?.unix_timestamp;
+
+        msg!("Price updated: {}", price);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+// Lending Protocol Program - VULNERABLE VERSION
+//
+// WARNING: This program contains intentional security vulnerabilities for educational purposes.
+// NEVER deploy this to production. Use lend-secure instead.
+//
+// This vulnerable lending market demonstrates two classic DeFi lending flaws:
+// - Borrowing without a health factor check (reserve drain)
+// - Trusting an oracle price without checking how old it is
+//
+// VULNERABILITIES DOCUMENTED:
+// See individual instruction files for detailed vulnerability explanations.
+// Each vulnerability is marked with VULNERABILITY comments.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("9S4UWZcCJqTpFrvZfHbgFh3maMjEJYTTCTPippbddJ21");
+
+#[program]
+pub mod lend_vulnerable {
+    use super::*;
+
+    // Create a lending market for a collateral/borrow mint pair
+    // Only needs to be called once per pair
+    pub fn initialize_market(
+        ctx: Context<InitializeMarket>,
+        collateral_factor_bps: u16,
+        liquidation_threshold_bps: u16,
+        liquidation_bonus_bps: u16,
+        borrow_rate_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts.initialize_market(
+            collateral_factor_bps,
+            liquidation_threshold_bps,
+            liquidation_bonus_bps,
+            borrow_rate_bps,
+            &ctx.bumps,
+        )
+    }
+
+    // Post a new collateral price (market authority acts as the oracle)
+    pub fn update_price(ctx: Context<UpdatePrice>, price: u64) -> Result<()> {
+        ctx.accounts.update_price(price)
+    }
+
+    // Add borrowable liquidity to the reserve
+    pub fn fund_reserve(ctx: Context<FundReserve>, amount: u64) -> Result<()> {
+        ctx.accounts.fund_reserve(amount)
+    }
+
+    // Deposit collateral, creating the caller's obligation on first use
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        ctx.accounts.deposit(amount, &ctx.bumps)
+    }
+
+    // Withdraw collateral while keeping the position within the collateral factor
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw(amount)
+    }
+
+    // VULNERABILITY: No health factor check - borrow is not limited by collateral
+    pub fn borrow(ctx: Context<Borrow>, amount: u64) -> Result<()> {
+        ctx.accounts.borrow(amount)
+    }
+
+    // Repay debt on any obligation
+    pub fn repay(ctx: Context<Repay>, amount: u64) -> Result<()> {
+        ctx.accounts.repay(amount)
+    }
+
+    // Repay part of an unhealthy position's debt and seize its collateral
+    pub fn liquidate(ctx: Context<Liquidate>, repay_amount: u64) -> Result<()> {
+        ctx.accounts.liquidate(repay_amount)
+    }
+}
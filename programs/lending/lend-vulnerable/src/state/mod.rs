@@ -0,0 +1,9 @@
+// State Module
+//
+// Exports all state structures used by the lending program
+
+pub mod market;
+pub mod obligation;
+
+pub use market::*;
+pub use obligation::*;
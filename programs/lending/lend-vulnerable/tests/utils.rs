@@ -0,0 +1,435 @@
+// Test utilities for lending program
+
+use litesvm::{LiteSVM, types::TransactionResult};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo, get_spl_account};
+use litesvm_token::spl_token::state::Account as TokenAccount;
+use solana_sdk::{
+    clock::Clock,
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::program::ID as system_program;
+
+// Program ID matching declare_id!
+pub const LENDING_PROGRAM_ID: Pubkey = Pubkey::new_from_array(lend_vulnerable::ID.to_bytes());
+
+// Build Anchor instruction discriminator
+// Formula: first 8 bytes of sha256("global:method_name")
+pub fn anchor_discriminator(method: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", method);
+    let hash_result = hash(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash_result.to_bytes()[..8]);
+    discriminator
+}
+
+// Standard program IDs
+pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_account::ID;
+
+// PDA Seeds
+pub const MARKET_SEED: &[u8] = b"market";
+pub const MARKET_AUTHORITY_SEED: &[u8] = b"market_authority";
+pub const OBLIGATION_SEED: &[u8] = b"obligation";
+
+// Token decimals
+pub const DECIMALS: u8 = 9;
+pub const ONE_TOKEN: u64 = 1_000_000_000;
+
+// Default market parameters
+pub const COLLATERAL_FACTOR_BPS: u16 = 7500;      // 75% LTV
+pub const LIQUIDATION_THRESHOLD_BPS: u16 = 8000;  // liquidatable above 80% LTV
+pub const LIQUIDATION_BONUS_BPS: u16 = 500;       // 5% bonus to liquidators
+pub const BORROW_RATE_BPS: u16 = 1000;            // 10% APR
+
+// Prices are borrow base units per collateral base unit, scaled by 1e6
+pub const PRICE_SCALE: u64 = 1_000_000;
+pub const INITIAL_PRICE: u64 = 2 * PRICE_SCALE;   // 1 collateral = 2 borrow tokens
+
+// Liquidity the market authority seeds the reserve with
+pub const RESERVE_LIQUIDITY: u64 = 10_000 * ONE_TOKEN;
+
+// Setup LiteSVM with lending program
+pub fn setup_svm() -> LiteSVM {
+    let mut svm = LiteSVM::new();
+    let program_bytes = include_bytes!("../target/deploy/lend_vulnerable.so");
+    let _ = svm.add_program(LENDING_PROGRAM_ID, program_bytes);
+    svm
+}
+
+// Create and fund account
+pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
+    let keypair = Keypair::new();
+    svm.airdrop(&keypair.pubkey(), lamports)
+        .expect("Airdrop should succeed");
+    keypair
+}
+
+// Sign and send a single instruction
+pub fn send_ix(svm: &mut LiteSVM, ix: Instruction, signer: &Keypair) -> TransactionResult {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    svm.expire_blockhash();
+    result
+}
+
+pub fn advance_time(svm: &mut LiteSVM, seconds: i64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += seconds;
+    svm.set_sysvar::<Clock>(&clock);
+}
+
+// Derive market PDA
+pub fn derive_market_pda(collateral_mint: &Pubkey, borrow_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MARKET_SEED, collateral_mint.as_ref(), borrow_mint.as_ref()],
+        &LENDING_PROGRAM_ID,
+    )
+}
+
+// Derive market authority PDA
+pub fn derive_market_authority_pda(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MARKET_AUTHORITY_SEED, market.as_ref()],
+        &LENDING_PROGRAM_ID,
+    )
+}
+
+// Derive obligation PDA
+pub fn derive_obligation_pda(market: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[OBLIGATION_SEED, market.as_ref(), owner.as_ref()],
+        &LENDING_PROGRAM_ID,
+    )
+}
+
+// Derive a market vault (market authority ATA) for one of the market mints
+pub fn derive_vault(market: &Pubkey, mint: &Pubkey) -> Pubkey {
+    let (market_authority, _) = derive_market_authority_pda(market);
+    get_associated_token_address(&market_authority, mint)
+}
+
+// Build initialize_market instruction
+pub fn build_initialize_market_ix(
+    authority: &Pubkey,
+    collateral_mint: &Pubkey,
+    borrow_mint: &Pubkey,
+    collateral_factor_bps: u16,
+    liquidation_threshold_bps: u16,
+    liquidation_bonus_bps: u16,
+    borrow_rate_bps: u16,
+) -> Instruction {
+    let (market, _) = derive_market_pda(collateral_mint, borrow_mint);
+    let (market_authority, _) = derive_market_authority_pda(&market);
+
+    let mut data = anchor_discriminator("initialize_market").to_vec();
+    data.extend_from_slice(&collateral_factor_bps.to_le_bytes());
+    data.extend_from_slice(&liquidation_threshold_bps.to_le_bytes());
+    data.extend_from_slice(&liquidation_bonus_bps.to_le_bytes());
+    data.extend_from_slice(&borrow_rate_bps.to_le_bytes());
+
+    Instruction {
+        program_id: LENDING_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(*collateral_mint, false),
+            AccountMeta::new_readonly(*borrow_mint, false),
+            AccountMeta::new(market, false),
+            AccountMeta::new_readonly(market_authority, false),
+            AccountMeta::new(derive_vault(&market, collateral_mint), false),
+            AccountMeta::new(derive_vault(&market, borrow_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build update_price instruction
+pub fn build_update_price_ix(authority: &Pubkey, market: &Pubkey, price: u64) -> Instruction {
+    let mut data = anchor_discriminator("update_price").to_vec();
+    data.extend_from_slice(&price.to_le_bytes());
+
+    Instruction {
+        program_id: LENDING_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(*market, false),
+        ],
+        data,
+    }
+}
+
+// Build fund_reserve instruction
+pub fn build_fund_reserve_ix(
+    authority: &Pubkey,
+    collateral_mint: &Pubkey,
+    borrow_mint: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let (market, _) = derive_market_pda(collateral_mint, borrow_mint);
+
+    let mut data = anchor_discriminator("fund_reserve").to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id: LENDING_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(market, false),
+            AccountMeta::new_readonly(*borrow_mint, false),
+            AccountMeta::new(get_associated_token_address(authority, borrow_mint), false),
+            AccountMeta::new(derive_vault(&market, borrow_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Build deposit instruction
+pub fn build_deposit_ix(
+    owner: &Pubkey,
+    collateral_mint: &Pubkey,
+    borrow_mint: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let (market, _) = derive_market_pda(collateral_mint, borrow_mint);
+    let (obligation, _) = derive_obligation_pda(&market, owner);
+
+    let mut data = anchor_discriminator("deposit").to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id: LENDING_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new_readonly(market, false),
+            AccountMeta::new(obligation, false),
+            AccountMeta::new_readonly(*collateral_mint, false),
+            AccountMeta::new(get_associated_token_address(owner, collateral_mint), false),
+            AccountMeta::new(derive_vault(&market, collateral_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build withdraw instruction
+pub fn build_withdraw_ix(
+    owner: &Pubkey,
+    collateral_mint: &Pubkey,
+    borrow_mint: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let (market, _) = derive_market_pda(collateral_mint, borrow_mint);
+    let (market_authority, _) = derive_market_authority_pda(&market);
+    let (obligation, _) = derive_obligation_pda(&market, owner);
+
+    let mut data = anchor_discriminator("withdraw").to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id: LENDING_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new(market, false),
+            AccountMeta::new_readonly(market_authority, false),
+            AccountMeta::new(obligation, false),
+            AccountMeta::new_readonly(*collateral_mint, false),
+            AccountMeta::new(get_associated_token_address(owner, collateral_mint), false),
+            AccountMeta::new(derive_vault(&market, collateral_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Build borrow instruction
+pub fn build_borrow_ix(
+    owner: &Pubkey,
+    collateral_mint: &Pubkey,
+    borrow_mint: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let (market, _) = derive_market_pda(collateral_mint, borrow_mint);
+    let (market_authority, _) = derive_market_authority_pda(&market);
+    let (obligation, _) = derive_obligation_pda(&market, owner);
+
+    let mut data = anchor_discriminator("borrow").to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id: LENDING_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new(market, false),
+            AccountMeta::new_readonly(market_authority, false),
+            AccountMeta::new(obligation, false),
+            AccountMeta::new_readonly(*borrow_mint, false),
+            AccountMeta::new(get_associated_token_address(owner, borrow_mint), false),
+            AccountMeta::new(derive_vault(&market, borrow_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Build repay instruction (payer repays obligation_owner's debt)
+pub fn build_repay_ix(
+    payer: &Pubkey,
+    obligation_owner: &Pubkey,
+    collateral_mint: &Pubkey,
+    borrow_mint: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let (market, _) = derive_market_pda(collateral_mint, borrow_mint);
+    let (obligation, _) = derive_obligation_pda(&market, obligation_owner);
+
+    let mut data = anchor_discriminator("repay").to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id: LENDING_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*payer, true),
+            AccountMeta::new(market, false),
+            AccountMeta::new(obligation, false),
+            AccountMeta::new_readonly(*borrow_mint, false),
+            AccountMeta::new(get_associated_token_address(payer, borrow_mint), false),
+            AccountMeta::new(derive_vault(&market, borrow_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Read (collateral_amount, borrowed_amount) from an Obligation
+// Layout: discriminator (8) + owner (32) + market (32) + collateral (8) + borrowed (8)
+pub fn get_obligation(svm: &LiteSVM, market: &Pubkey, owner: &Pubkey) -> (u64, u64) {
+    let (obligation, _) = derive_obligation_pda(market, owner);
+    let account = svm.get_account(&obligation).expect("Obligation should exist");
+    let offset = 8 + 32 * 2;
+    let collateral = u64::from_le_bytes(account.data[offset..offset + 8].try_into().unwrap());
+    let borrowed = u64::from_le_bytes(account.data[offset + 8..offset + 16].try_into().unwrap());
+    (collateral, borrowed)
+}
+
+pub fn token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    let account: TokenAccount = get_spl_account(svm, token_account).expect("Token account should exist");
+    account.amount
+}
+
+// Lending market scenario setup result
+pub struct LendingScenario {
+    pub svm: LiteSVM,
+    pub authority: Keypair,
+    pub collateral_mint: Pubkey,
+    pub borrow_mint: Pubkey,
+    pub market: Pubkey,
+    pub collateral_vault: Pubkey,
+    pub liquidity_vault: Pubkey,
+}
+
+// Create both mints, initialize a market with the default parameters,
+// fund the reserve with RESERVE_LIQUIDITY, and post INITIAL_PRICE
+// `authority` is the mint authority for both mints and the market authority
+pub fn setup_lending_market() -> LendingScenario {
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let collateral_mint = CreateMint::new(&mut svm, &authority)
+        .authority(&authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create collateral mint");
+    let borrow_mint = CreateMint::new(&mut svm, &authority)
+        .authority(&authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create borrow mint");
+
+    let init_ix = build_initialize_market_ix(
+        &authority.pubkey(),
+        &collateral_mint,
+        &borrow_mint,
+        COLLATERAL_FACTOR_BPS,
+        LIQUIDATION_THRESHOLD_BPS,
+        LIQUIDATION_BONUS_BPS,
+        BORROW_RATE_BPS,
+    );
+    send_ix(&mut svm, init_ix, &authority).expect("Market initialization should succeed");
+
+    let (market, _) = derive_market_pda(&collateral_mint, &borrow_mint);
+
+    let authority_borrow_ata = CreateAssociatedTokenAccount::new(&mut svm, &authority, &borrow_mint)
+        .owner(&authority.pubkey())
+        .send()
+        .expect("Failed to create authority borrow ATA");
+    MintTo::new(&mut svm, &authority, &borrow_mint, &authority_borrow_ata, RESERVE_LIQUIDITY)
+        .owner(&authority)
+        .send()
+        .expect("Failed to mint reserve liquidity");
+
+    let fund_ix = build_fund_reserve_ix(
+        &authority.pubkey(),
+        &collateral_mint,
+        &borrow_mint,
+        RESERVE_LIQUIDITY,
+    );
+    send_ix(&mut svm, fund_ix, &authority).expect("Funding the reserve should succeed");
+
+    let price_ix = build_update_price_ix(&authority.pubkey(), &market, INITIAL_PRICE);
+    send_ix(&mut svm, price_ix, &authority).expect("Price update should succeed");
+
+    LendingScenario {
+        collateral_vault: derive_vault(&market, &collateral_mint),
+        liquidity_vault: derive_vault(&market, &borrow_mint),
+        svm,
+        authority,
+        collateral_mint,
+        borrow_mint,
+        market,
+    }
+}
+
+// Create a funded user holding `collateral_amount` collateral and `borrow_amount`
+// borrow tokens, with ATAs for both mints
+pub fn create_user(scenario: &mut LendingScenario, collateral_amount: u64, borrow_amount: u64) -> Keypair {
+    let user = create_funded_account(&mut scenario.svm, 5 * LAMPORTS_PER_SOL);
+
+    let collateral_ata = CreateAssociatedTokenAccount::new(&mut scenario.svm, &user, &scenario.collateral_mint)
+        .owner(&user.pubkey())
+        .send()
+        .expect("Failed to create collateral ATA");
+    let borrow_ata = CreateAssociatedTokenAccount::new(&mut scenario.svm, &user, &scenario.borrow_mint)
+        .owner(&user.pubkey())
+        .send()
+        .expect("Failed to create borrow ATA");
+
+    if collateral_amount > 0 {
+        MintTo::new(&mut scenario.svm, &scenario.authority, &scenario.collateral_mint, &collateral_ata, collateral_amount)
+            .owner(&scenario.authority)
+            .send()
+            .expect("Failed to mint collateral");
+    }
+    if borrow_amount > 0 {
+        MintTo::new(&mut scenario.svm, &scenario.authority, &scenario.borrow_mint, &borrow_ata, borrow_amount)
+            .owner(&scenario.authority)
+            .send()
+            .expect("Failed to mint borrow tokens");
+    }
+
+    user
+}
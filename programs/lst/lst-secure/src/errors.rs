@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+pub use soteria_errors::lst_secure::LstError as LstErrorCode;
+
+#[error_code]
+pub enum LstError {
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Deposit is below the minimum")]
+    DepositTooSmall,
+
+    #[msg("Exchange rate must be greater than zero")]
+    InvalidExchangeRate,
+
+    #[msg("Exchange rate was updated too recently")]
+    UpdateTooSoon,
+
+    #[msg("Exchange rate change exceeds the per-update limit")]
+    RateChangeTooLarge,
+
+    #[msg("Exchange rate is not backed by the SOL in the vault")]
+    RateNotBacked,
+
+    #[msg("SOL vault has insufficient balance")]
+    InsufficientVaultBalance,
+
+    #[msg("Unauthorized: Cannot perform this action")]
+    Unauthorized,
+
+    #[msg("Arithmetic overflow")]
+    Overflow,
+
+    #[msg("Division by zero")]
+    DivisionByZero,
+}
@@ -0,0 +1,95 @@
+// Deposit Instruction
+//
+// Stakes SOL and mints LST at the current exchange rate.
+//
+// HOW IT WORKS:
+// 1. Converts the deposit to LST: lamports * RATE_PRECISION / lamports_per_lst
+// 2. Transfers the SOL from the depositor into the vault
+// 3. Mints the LST to the depositor, signed by the pool PDA
+//
+// SECURITY:
+// - Deposit at least MIN_DEPOSIT_LAMPORTS
+// - LST rounded down, so the pool never mints more than the SOL received
+// - Vault, mint and exchange rate pinned to the pool (has_one + seeds)
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, pool.admin.as_ref()],
+        bump = pool.bump,
+        has_one = lst_mint,
+        has_one = sol_vault,
+        has_one = exchange_rate,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        seeds = [EXCHANGE_RATE_SEED, pool.key().as_ref()],
+        bump = exchange_rate.bump,
+    )]
+    pub exchange_rate: Box<Account<'info, ExchangeRate>>,
+
+    #[account(mut)]
+    pub lst_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, pool.key().as_ref()],
+        bump = pool.sol_vault_bump,
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = lst_mint,
+        token::authority = depositor,
+        token::token_program = token_program,
+    )]
+    pub depositor_lst_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Deposit<'info> {
+    pub fn deposit(&mut self, lamports: u64) -> Result<()> {
+        require!(lamports >= MIN_DEPOSIT_LAMPORTS, LstError::DepositTooSmall);
+
+        let lst_amount = self.exchange_rate.lst_for_lamports(lamports)?;
+        require!(lst_amount > 0, LstError::ZeroAmount);
+
+        transfer_sol(
+            lamports,
+            &self.system_program.to_account_info(),
+            &self.depositor.to_account_info(),
+            &self.sol_vault.to_account_info(),
+        )?;
+
+        let pool_seeds = &[
+            POOL_SEED,
+            self.pool.admin.as_ref(),
+            &[self.pool.bump],
+        ];
+
+        mint_lst(
+            lst_amount,
+            &self.token_program.to_account_info(),
+            &self.lst_mint.to_account_info(),
+            &self.depositor_lst_account.to_account_info(),
+            &self.pool.to_account_info(),
+            pool_seeds,
+        )?;
+
+        msg!("Deposited {} lamports for {} LST", lamports, lst_amount);
+
+        Ok(())
+    }
+}
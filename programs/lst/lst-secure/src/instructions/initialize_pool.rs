@@ -0,0 +1,89 @@
+// Initialize Pool Instruction
+//
+// Creates a liquid staking pool with its LST mint, SOL vault and exchange rate.
+//
+// HOW IT WORKS:
+// 1. Creates the pool PDA for the admin
+// 2. Creates the LST mint with the pool as mint authority
+// 3. Creates the exchange rate account at 1 SOL per LST
+// 4. Records the crank allowed to update the rate
+//
+// SECURITY:
+// - LST mint authority is the pool PDA, so only this program can mint
+// - SOL vault is a PDA derived from the pool; it needs no initialization,
+//   it is a system account that starts receiving SOL on the first deposit
+// - The crank is fixed here and checked on every rate update
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::{constants::*, state::*};
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR + Pool::INIT_SPACE,
+        seeds = [POOL_SEED, admin.key().as_ref()],
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [LST_MINT_SEED, pool.key().as_ref()],
+        bump,
+        mint::decimals = LST_DECIMALS,
+        mint::authority = pool,
+        mint::token_program = token_program,
+    )]
+    pub lst_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [SOL_VAULT_SEED, pool.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR + ExchangeRate::INIT_SPACE,
+        seeds = [EXCHANGE_RATE_SEED, pool.key().as_ref()],
+        bump
+    )]
+    pub exchange_rate: Box<Account<'info, ExchangeRate>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializePool<'info> {
+    pub fn initialize_pool(&mut self, crank: Pubkey, bumps: &InitializePoolBumps) -> Result<()> {
+        self.pool.set_inner(Pool {
+            admin: self.admin.key(),
+            lst_mint: self.lst_mint.key(),
+            sol_vault: self.sol_vault.key(),
+            exchange_rate: self.exchange_rate.key(),
+            bump: bumps.pool,
+            sol_vault_bump: bumps.sol_vault,
+        });
+
+        self.exchange_rate.set_inner(ExchangeRate {
+            pool: self.pool.key(),
+            crank,
+            lamports_per_lst: RATE_PRECISION,
+            last_update_ts: Clock::get()?.unix_timestamp,
+            bump: bumps.exchange_rate,
+        });
+
+        msg!("Pool initialized: LST mint {}, crank {}", self.lst_mint.key(), crank);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+// Instructions Module
+//
+// Exports all instruction handlers for the liquid staking program
+
+pub mod initialize_pool;
+pub mod deposit;
+pub mod withdraw;
+pub mod update_exchange_rate;
+
+pub use initialize_pool::*;
+pub use deposit::*;
+pub use withdraw::*;
+pub use update_exchange_rate::*;
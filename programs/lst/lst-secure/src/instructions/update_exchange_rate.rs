@@ -0,0 +1,98 @@
+// Update Exchange Rate Instruction
+//
+// Permissioned crank that passes staking rewards on to LST holders.
+//
+// HOW IT WORKS:
+// 1. Rewards land in the SOL vault off this program's books
+// 2. The crank computes vault_lamports / lst_supply off-chain
+// 3. The crank submits the new lamports_per_lst
+//
+// SECURITY:
+// - Only the crank recorded on the exchange rate account can update it
+// - At most one update per MIN_UPDATE_INTERVAL_SECONDS
+// - Each update moves the rate by at most MAX_RATE_CHANGE_BPS
+// - The new rate must be backed: lst_supply at the new rate <= vault lamports
+//
+// Why the bounds when the crank is trusted?
+// The rate decides how much SOL every LST is worth. A single bad update -
+// a bug, a fat finger, or a stolen crank key - would otherwise let LST be
+// minted cheaply or redeemed for more SOL than the vault holds.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use soteria_common::math::mul_div_floor;
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct UpdateExchangeRate<'info> {
+    pub crank: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, pool.admin.as_ref()],
+        bump = pool.bump,
+        has_one = lst_mint,
+        has_one = sol_vault,
+        has_one = exchange_rate,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[cfg_attr(not(feature = "vuln-missing-crank-check"), account(
+        mut,
+        seeds = [EXCHANGE_RATE_SEED, pool.key().as_ref()],
+        bump = exchange_rate.bump,
+        has_one = crank @ LstError::Unauthorized,
+    ))]
+    // vuln-missing-crank-check: any signer may move the rate within the bounds
+    #[cfg_attr(feature = "vuln-missing-crank-check", account(
+        mut,
+        seeds = [EXCHANGE_RATE_SEED, pool.key().as_ref()],
+        bump = exchange_rate.bump,
+    ))]
+    pub exchange_rate: Box<Account<'info, ExchangeRate>>,
+
+    pub lst_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [SOL_VAULT_SEED, pool.key().as_ref()],
+        bump = pool.sol_vault_bump,
+    )]
+    pub sol_vault: SystemAccount<'info>,
+}
+
+impl<'info> UpdateExchangeRate<'info> {
+    pub fn update_exchange_rate(&mut self, new_lamports_per_lst: u64) -> Result<()> {
+        require!(new_lamports_per_lst > 0, LstError::InvalidExchangeRate);
+
+        let now = Clock::get()?.unix_timestamp;
+        let next_update_ts = self
+            .exchange_rate
+            .last_update_ts
+            .checked_add(MIN_UPDATE_INTERVAL_SECONDS)
+            .ok_or(LstError::Overflow)?;
+        require!(now >= next_update_ts, LstError::UpdateTooSoon);
+
+        // |new - old| <= old * MAX_RATE_CHANGE_BPS / BPS_DENOMINATOR
+        let old_rate = self.exchange_rate.lamports_per_lst;
+        let max_change = mul_div_floor(old_rate, MAX_RATE_CHANGE_BPS, BPS_DENOMINATOR)
+            .ok_or(LstError::Overflow)?;
+        require!(
+            new_lamports_per_lst.abs_diff(old_rate) <= max_change,
+            LstError::RateChangeTooLarge
+        );
+
+        // Every LST in circulation must be redeemable at the new rate
+        let liabilities = ExchangeRate::lamports_at_rate(self.lst_mint.supply, new_lamports_per_lst)?;
+        require!(
+            liabilities <= self.sol_vault.lamports(),
+            LstError::RateNotBacked
+        );
+
+        self.exchange_rate.lamports_per_lst = new_lamports_per_lst;
+        self.exchange_rate.last_update_ts = now;
+
+        msg!("Exchange rate updated: {} -> {} lamports per LST", old_rate, new_lamports_per_lst);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+// Withdraw Instruction
+//
+// Burns LST and pays out SOL at the current exchange rate.
+//
+// HOW IT WORKS:
+// 1. Converts the LST to SOL: lst * lamports_per_lst / RATE_PRECISION
+// 2. Burns the LST from the holder
+// 3. Transfers the SOL from the vault, signed by the vault PDA
+//
+// SECURITY:
+// - Lamports rounded down, so the pool never pays more than the LST is worth
+// - Vault must hold enough SOL
+// - Holder must own the LST being burned
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, pool.admin.as_ref()],
+        bump = pool.bump,
+        has_one = lst_mint,
+        has_one = sol_vault,
+        has_one = exchange_rate,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        seeds = [EXCHANGE_RATE_SEED, pool.key().as_ref()],
+        bump = exchange_rate.bump,
+    )]
+    pub exchange_rate: Box<Account<'info, ExchangeRate>>,
+
+    #[account(mut)]
+    pub lst_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, pool.key().as_ref()],
+        bump = pool.sol_vault_bump,
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = lst_mint,
+        token::authority = holder,
+        token::token_program = token_program,
+    )]
+    pub holder_lst_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Withdraw<'info> {
+    pub fn withdraw(&mut self, lst_amount: u64) -> Result<()> {
+        require!(lst_amount > 0, LstError::ZeroAmount);
+
+        let lamports = self.exchange_rate.lamports_for_lst(lst_amount)?;
+        require!(lamports > 0, LstError::ZeroAmount);
+
+        require!(
+            self.sol_vault.lamports() >= lamports,
+            LstError::InsufficientVaultBalance
+        );
+
+        burn_lst(
+            lst_amount,
+            &self.token_program.to_account_info(),
+            &self.lst_mint.to_account_info(),
+            &self.holder_lst_account.to_account_info(),
+            &self.holder.to_account_info(),
+        )?;
+
+        let pool_key = self.pool.key();
+        let vault_seeds = &[
+            SOL_VAULT_SEED,
+            pool_key.as_ref(),
+            &[self.pool.sol_vault_bump],
+        ];
+
+        transfer_sol_from_vault(
+            lamports,
+            &self.system_program.to_account_info(),
+            &self.sol_vault.to_account_info(),
+            &self.holder.to_account_info(),
+            vault_seeds,
+        )?;
+
+        msg!("Withdrew {} lamports for {} LST", lamports, lst_amount);
+
+        Ok(())
+    }
+}
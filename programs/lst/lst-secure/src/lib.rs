@@ -0,0 +1,57 @@
+// Liquid Staking Program - SECURE VERSION
+//
+// Simplified SOL liquid staking: SOL goes into a vault, LST comes out.
+// This program allows users to:
+// 1. Create a pool with its LST mint, SOL vault and exchange rate account
+// 2. Deposit SOL and receive LST at the current exchange rate
+// 3. Burn LST and receive SOL at the current exchange rate
+// 4. Let a permissioned crank raise the rate as staking rewards accrue
+//
+// SECURITY FEATURES:
+// - LST mint authority is the pool PDA
+// - Exchange rate updated only by the recorded crank
+// - Rate updates rate-limited and bounded to MAX_RATE_CHANGE_BPS
+// - Rate must be backed by the SOL in the vault
+// - Conversions round in the pool's favour
+// - Checked arithmetic to prevent overflow/underflow
+//
+// EXCHANGE RATE:
+// lst      = lamports * RATE_PRECISION / lamports_per_lst
+// lamports = lst * lamports_per_lst / RATE_PRECISION
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("BfG6g6WUBnFh7fxngZHCxT5XQPvYZHWfyCLZ4npQqdWb");
+
+#[program]
+pub mod lst_secure {
+    use super::*;
+
+    // Create the pool, LST mint and exchange rate account
+    pub fn initialize_pool(ctx: Context<InitializePool>, crank: Pubkey) -> Result<()> {
+        ctx.accounts.initialize_pool(crank, &ctx.bumps)
+    }
+
+    // Stake SOL and mint LST
+    pub fn deposit(ctx: Context<Deposit>, lamports: u64) -> Result<()> {
+        ctx.accounts.deposit(lamports)
+    }
+
+    // Burn LST and receive SOL
+    pub fn withdraw(ctx: Context<Withdraw>, lst_amount: u64) -> Result<()> {
+        ctx.accounts.withdraw(lst_amount)
+    }
+
+    // Crank-only: pass staking rewards on to LST holders
+    pub fn update_exchange_rate(ctx: Context<UpdateExchangeRate>, new_lamports_per_lst: u64) -> Result<()> {
+        ctx.accounts.update_exchange_rate(new_lamports_per_lst)
+    }
+}
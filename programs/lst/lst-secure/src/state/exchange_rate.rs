@@ -0,0 +1,55 @@
+// Exchange Rate State
+//
+// How many lamports one LST is worth. Staking rewards land in the SOL vault
+// and a permissioned crank raises the rate to pass them on to LST holders.
+//
+// RATE MATH:
+// lst      = lamports * RATE_PRECISION / lamports_per_lst   (rounded down)
+// lamports = lst * lamports_per_lst / RATE_PRECISION        (rounded down)
+//
+// Both directions round in the pool's favour, so deposit/withdraw round trips
+// can never take out more SOL than they put in.
+
+use anchor_lang::prelude::*;
+use soteria_common::math::mul_div_floor;
+use crate::{constants::*, errors::*};
+
+#[account]
+#[derive(InitSpace)]
+pub struct ExchangeRate {
+    // Pool this rate belongs to
+    pub pool: Pubkey,
+
+    // Only account allowed to update the rate
+    pub crank: Pubkey,
+
+    // Lamports per whole LST, scaled by RATE_PRECISION
+    pub lamports_per_lst: u64,
+
+    // Unix timestamp of the last update (or pool creation)
+    pub last_update_ts: i64,
+
+    pub bump: u8,
+}
+
+impl ExchangeRate {
+    // LST minted for a SOL deposit
+    pub fn lst_for_lamports(&self, lamports: u64) -> Result<u64> {
+        require!(self.lamports_per_lst > 0, LstError::DivisionByZero);
+
+        mul_div_floor(lamports, RATE_PRECISION, self.lamports_per_lst)
+            .ok_or_else(|| error!(LstError::Overflow))
+    }
+
+    // SOL paid out for burning LST
+    pub fn lamports_for_lst(&self, lst: u64) -> Result<u64> {
+        Self::lamports_at_rate(lst, self.lamports_per_lst)
+    }
+
+    // SOL value of `lst` at an arbitrary rate
+    // Used to check a proposed rate against the vault before accepting it
+    pub fn lamports_at_rate(lst: u64, lamports_per_lst: u64) -> Result<u64> {
+        mul_div_floor(lst, lamports_per_lst, RATE_PRECISION)
+            .ok_or_else(|| error!(LstError::Overflow))
+    }
+}
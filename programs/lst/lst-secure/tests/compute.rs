@@ -0,0 +1,49 @@
+// Compute-unit regression tests for lst-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+mod utils;
+
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signature::Signer};
+use soteria_test_kit::{ComputeBudget, DEFAULT_CU_BASELINES_FILE};
+use utils::*;
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let mut svm = setup_svm();
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let crank = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+
+    let ix = build_initialize_pool_ix(&admin.pubkey(), &crank.pubkey());
+    budget.record("initialize_pool", send_ix(&mut svm, ix, &admin));
+    let (pool, _) = derive_pool_pda(&admin.pubkey());
+
+    let mut scenario = LstScenario {
+        svm,
+        admin,
+        crank,
+        pool,
+        sol_vault: derive_sol_vault(&pool),
+    };
+    let staker = create_staker(&mut scenario, 100 * LAMPORTS_PER_SOL);
+
+    let ix = build_deposit_ix(&staker.pubkey(), &pool, 100 * LAMPORTS_PER_SOL);
+    budget.record("deposit", send_ix(&mut scenario.svm, ix, &staker));
+
+    // +0.5% rewards, backed by the vault
+    advance_time(&mut scenario.svm, MIN_UPDATE_INTERVAL_SECONDS);
+    accrue_rewards(&mut scenario, LAMPORTS_PER_SOL / 2);
+    let ix = build_update_rate_ix(&scenario.crank.pubkey(), &pool, RATE_PRECISION * 1005 / 1000);
+    budget.record("update_exchange_rate", send_ix(&mut scenario.svm, ix, &scenario.crank));
+
+    let ix = build_withdraw_ix(&staker.pubkey(), &pool, 10 * ONE_LST);
+    budget.record("withdraw", send_ix(&mut scenario.svm, ix, &staker));
+
+    budget.check();
+}
@@ -0,0 +1,211 @@
+// Integration tests for liquid staking program using LiteSVM
+// These tests verify core staking functionality: pool creation, deposits,
+// withdrawals, and crank-only exchange rate updates
+
+mod utils;
+
+use utils::*;
+use litesvm::{types::TransactionResult, LiteSVM};
+use litesvm_token::CreateAssociatedTokenAccount;
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use soteria_test_kit::{
+    run_feature_differential, DifferentialScenario, ExploitInfo, FeatureVariant, Severity,
+};
+
+#[test]
+fn test_initialize_pool() {
+    // Test: Pool starts at 1 SOL per LST with no LST in circulation
+    println!("\n[TEST START] test_initialize_pool - Pool creation");
+
+    let mut scenario = setup_lst_scenario();
+    println!("[Setup] Pool created with crank {}", scenario.crank.pubkey());
+
+    assert_eq!(get_exchange_rate(&scenario.svm, &scenario.pool), RATE_PRECISION);
+    assert_eq!(lst_supply(&scenario.svm, &scenario.pool), 0);
+    assert_eq!(sol_balance(&scenario.svm, &scenario.sol_vault), 0);
+    println!("[Success] Exchange rate 1 SOL per LST, empty vault, zero supply");
+
+    println!("[Action] Admin tries to initialize the same pool again");
+    let ix = build_initialize_pool_ix(&scenario.admin.pubkey(), &scenario.admin.pubkey());
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.admin).is_err(), "Re-initialization should fail");
+    println!("[Success] Pool cannot be re-initialized to swap the crank");
+
+    println!("[TEST END] test_initialize_pool");
+}
+
+#[test]
+fn test_deposit_and_withdraw() {
+    // Test: SOL in, LST out at the current rate, and back again
+    println!("\n[TEST START] test_deposit_and_withdraw - Staking round trip");
+
+    let mut scenario = setup_lst_scenario();
+    let pool = scenario.pool;
+
+    let staker = create_staker(&mut scenario, 10 * LAMPORTS_PER_SOL);
+
+    println!("[Action] Depositing less than the minimum");
+    let ix = build_deposit_ix(&staker.pubkey(), &pool, 1_000);
+    assert!(send_ix(&mut scenario.svm, ix, &staker).is_err(), "Dust deposit should fail");
+    println!("[Success] Deposit below MIN_DEPOSIT_LAMPORTS rejected");
+
+    println!("[Action] Depositing 10 SOL");
+    let ix = build_deposit_ix(&staker.pubkey(), &pool, 10 * LAMPORTS_PER_SOL);
+    let result = send_ix(&mut scenario.svm, ix, &staker);
+    assert!(result.is_ok(), "Deposit failed: {:?}", result.err());
+    assert_eq!(lst_balance(&scenario.svm, &pool, &staker.pubkey()), 10 * ONE_LST);
+    assert_eq!(sol_balance(&scenario.svm, &scenario.sol_vault), 10 * LAMPORTS_PER_SOL);
+    println!("[Success] 10 LST minted, 10 SOL in the vault");
+
+    println!("[Action] Withdrawing 4 LST");
+    let ix = build_withdraw_ix(&staker.pubkey(), &pool, 4 * ONE_LST);
+    let result = send_ix(&mut scenario.svm, ix, &staker);
+    assert!(result.is_ok(), "Withdraw failed: {:?}", result.err());
+    assert_eq!(lst_balance(&scenario.svm, &pool, &staker.pubkey()), 6 * ONE_LST);
+    assert_eq!(lst_supply(&scenario.svm, &pool), 6 * ONE_LST);
+    assert_eq!(sol_balance(&scenario.svm, &scenario.sol_vault), 6 * LAMPORTS_PER_SOL);
+    println!("[Success] 4 LST burned for 4 SOL");
+
+    let ix = build_withdraw_ix(&staker.pubkey(), &pool, 7 * ONE_LST);
+    assert!(send_ix(&mut scenario.svm, ix, &staker).is_err(), "Burning more than held should fail");
+    println!("[Success] Withdrawing more LST than held rejected");
+
+    println!("[TEST END] test_deposit_and_withdraw");
+}
+
+#[test]
+fn test_update_exchange_rate() {
+    // Test: Only the crank can update, at most once per interval, within the
+    // per-update bound, and only to a rate the vault can back
+    println!("\n[TEST START] test_update_exchange_rate - Crank-only, bounded rate updates");
+
+    let mut scenario = setup_lst_scenario();
+    let pool = scenario.pool;
+    let crank = scenario.crank.pubkey();
+
+    let staker = stake(&mut scenario, 100 * LAMPORTS_PER_SOL);
+    println!("[Setup] 100 SOL staked for 100 LST");
+
+    // +0.5% rewards
+    let rewarded_rate = RATE_PRECISION + RATE_PRECISION / 200;
+
+    let outsider = create_staker(&mut scenario, 0);
+    let ix = build_update_rate_ix(&outsider.pubkey(), &pool, rewarded_rate);
+    assert!(send_ix(&mut scenario.svm, ix, &outsider).is_err(), "Outsider update should fail");
+    println!("[Success] Update by non-crank rejected");
+
+    let ix = build_update_rate_ix(&crank, &pool, rewarded_rate);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.crank).is_err(), "Update before interval should fail");
+    println!("[Success] Update before MIN_UPDATE_INTERVAL_SECONDS rejected");
+
+    advance_time(&mut scenario.svm, MIN_UPDATE_INTERVAL_SECONDS);
+    accrue_rewards(&mut scenario, LAMPORTS_PER_SOL / 2);
+    println!("[Setup] 0.5 SOL of staking rewards landed in the vault");
+
+    let ix = build_update_rate_ix(&crank, &pool, RATE_PRECISION * 102 / 100);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.crank).is_err(), "+2% should fail");
+    println!("[Success] +2% update rejected (above MAX_RATE_CHANGE_BPS)");
+
+    let ix = build_update_rate_ix(&crank, &pool, RATE_PRECISION * 101 / 100);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.crank).is_err(), "Unbacked rate should fail");
+    println!("[Success] +1% update rejected (100 LST would be worth 101 SOL, vault holds 100.5)");
+
+    println!("[Action] Crank raises the rate by the 0.5% actually earned");
+    let ix = build_update_rate_ix(&crank, &pool, rewarded_rate);
+    let result = send_ix(&mut scenario.svm, ix, &scenario.crank);
+    assert!(result.is_ok(), "Update failed: {:?}", result.err());
+    assert_eq!(get_exchange_rate(&scenario.svm, &pool), rewarded_rate);
+    println!("[Success] Exchange rate is now {} lamports per LST", rewarded_rate);
+
+    let ix = build_update_rate_ix(&crank, &pool, rewarded_rate + 1);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.crank).is_err(), "Back-to-back update should fail");
+    println!("[Success] Second update within the interval rejected");
+
+    // New deposits pay the higher rate
+    let late = stake(&mut scenario, 10_050_000_000);
+    assert_eq!(lst_balance(&scenario.svm, &pool, &late.pubkey()), 10 * ONE_LST);
+    println!("[Success] Late staker pays 10.05 SOL for 10 LST");
+
+    println!("[Action] Original staker withdraws all 100 LST");
+    let ix = build_withdraw_ix(&staker.pubkey(), &pool, 100 * ONE_LST);
+    let vault_before = sol_balance(&scenario.svm, &scenario.sol_vault);
+    let result = send_ix(&mut scenario.svm, ix, &staker);
+    assert!(result.is_ok(), "Withdraw failed: {:?}", result.err());
+    assert_eq!(vault_before - sol_balance(&scenario.svm, &scenario.sol_vault), 100_500_000_000);
+    println!("[Success] 100 LST redeemed for 100.5 SOL, rewards included");
+
+    println!("[TEST END] test_update_exchange_rate");
+}
+
+// An outsider submits a rate update. The change is within MAX_RATE_CHANGE_BPS and
+// backed, so only the crank check stands in the way (V001 in lst-vulnerable)
+struct OutsiderRateUpdate;
+
+struct OutsiderState {
+    outsider: Keypair,
+    pool: Pubkey,
+}
+
+impl DifferentialScenario for OutsiderRateUpdate {
+    type State = OutsiderState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Missing Crank Authorization",
+            severity: Severity::Critical,
+            lesson: "Only the recorded crank may move the exchange rate",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, _program_id: &Pubkey) -> OutsiderState {
+        let admin = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let crank = create_funded_account(svm, LAMPORTS_PER_SOL);
+        let staker = create_funded_account(svm, 101 * LAMPORTS_PER_SOL);
+        let outsider = create_funded_account(svm, LAMPORTS_PER_SOL);
+
+        let ix = build_initialize_pool_ix(&admin.pubkey(), &crank.pubkey());
+        send_ix(svm, ix, &admin).expect("Pool initialization should succeed");
+        let (pool, _) = derive_pool_pda(&admin.pubkey());
+
+        CreateAssociatedTokenAccount::new(svm, &staker, &derive_lst_mint(&pool))
+            .owner(&staker.pubkey())
+            .send()
+            .expect("Failed to create LST account");
+        let ix = build_deposit_ix(&staker.pubkey(), &pool, 100 * LAMPORTS_PER_SOL);
+        send_ix(svm, ix, &staker).expect("Deposit should succeed");
+
+        advance_time(svm, MIN_UPDATE_INTERVAL_SECONDS);
+
+        OutsiderState { outsider, pool }
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        state: &mut OutsiderState,
+    ) -> TransactionResult {
+        // -1%: the largest cut one update allows, and always backed
+        let ix = build_update_rate_ix(&state.outsider.pubkey(), &state.pool, RATE_PRECISION * 99 / 100);
+        send_ix(svm, ix, &state.outsider)
+    }
+}
+
+// Feature matrix: only the vuln-missing-crank-check build takes the outsider's rate
+// Requires ./build-vuln-matrix.sh lst-secure from the repository root
+#[test]
+fn test_feature_missing_crank_check() {
+    let variant = FeatureVariant {
+        program_id: LST_PROGRAM_ID,
+        feature: "vuln-missing-crank-check",
+    };
+    let (mut baseline, mut variant_svm) = variant.load(
+        "target/deploy/lst_secure.so",
+        variant.so_path(".", "lst_secure"),
+    );
+    run_feature_differential(&mut baseline, &mut variant_svm, &variant, &OutsiderRateUpdate);
+}
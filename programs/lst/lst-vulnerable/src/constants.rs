@@ -0,0 +1,32 @@
+// Seed for pool PDA (also the LST mint authority)
+// Derived with: [POOL_SEED, admin_pubkey]
+pub const POOL_SEED: &[u8] = b"pool";
+
+// Seed for the LST mint PDA
+// Derived with: [LST_MINT_SEED, pool_pubkey]
+pub const LST_MINT_SEED: &[u8] = b"lst_mint";
+
+// Seed for the SOL vault PDA (system account holding staked SOL)
+// Derived with: [SOL_VAULT_SEED, pool_pubkey]
+pub const SOL_VAULT_SEED: &[u8] = b"sol_vault";
+
+// Seed for the exchange rate PDA
+// Derived with: [EXCHANGE_RATE_SEED, pool_pubkey]
+pub const EXCHANGE_RATE_SEED: &[u8] = b"exchange_rate";
+
+// LST CONFIGURATION
+
+// LST uses the same decimals as SOL
+pub const LST_DECIMALS: u8 = 9;
+
+// Exchange rate is lamports per whole LST (10^LST_DECIMALS base units)
+// Starts at 1 SOL per LST and only moves as staking rewards accrue
+pub const RATE_PRECISION: u64 = 1_000_000_000;
+
+// DEPOSIT LIMITS
+
+// Minimum deposit (0.001 SOL)
+// Keeps the vault above the rent-exempt minimum on the first deposit
+pub const MIN_DEPOSIT_LAMPORTS: u64 = 1_000_000;
+
+pub const ANCHOR_DISCRIMINATOR: usize = 8;
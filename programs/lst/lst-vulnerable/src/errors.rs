@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+pub use soteria_errors::lst_vulnerable::LstError as LstErrorCode;
+
+#[error_code]
+pub enum LstError {
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Deposit is below the minimum")]
+    DepositTooSmall,
+
+    #[msg("Exchange rate must be greater than zero")]
+    InvalidExchangeRate,
+
+    #[msg("SOL vault has insufficient balance")]
+    InsufficientVaultBalance,
+
+    #[msg("Arithmetic overflow")]
+    Overflow,
+
+    #[msg("Division by zero")]
+    DivisionByZero,
+}
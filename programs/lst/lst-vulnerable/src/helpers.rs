@@ -0,0 +1,102 @@
+// Liquid Staking Helper Functions
+//
+// Reusable CPI helpers for the liquid staking program.
+// These functions reduce code duplication across instructions.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{Burn, MintTo, burn, mint_to};
+
+// CPI HELPERS
+
+// Transfer SOL from a signer
+// Used for depositing SOL into the vault
+pub fn transfer_sol<'info>(
+    amount: u64,
+    system_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+) -> Result<()> {
+    transfer(
+        CpiContext::new(
+            system_program.clone(),
+            Transfer {
+                from: from.clone(),
+                to: to.clone(),
+            },
+        ),
+        amount,
+    )
+}
+
+// Transfer SOL out of the vault (requires PDA signer)
+// Used when withdrawing
+pub fn transfer_sol_from_vault<'info>(
+    amount: u64,
+    system_program: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    vault_seeds: &[&[u8]],
+) -> Result<()> {
+    let signer_seeds = &[vault_seeds];
+
+    transfer(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Transfer {
+                from: vault.clone(),
+                to: to.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+// Mint LST (requires pool PDA authority)
+// Used when depositing SOL
+pub fn mint_lst<'info>(
+    amount: u64,
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let signer_seeds = &[authority_seeds];
+
+    mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            MintTo {
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+// Burn LST
+// Used when withdrawing SOL
+pub fn burn_lst<'info>(
+    amount: u64,
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+) -> Result<()> {
+    burn(
+        CpiContext::new(
+            token_program.clone(),
+            Burn {
+                mint: mint.clone(),
+                from: from.clone(),
+                authority: authority.clone(),
+            },
+        ),
+        amount,
+    )
+}
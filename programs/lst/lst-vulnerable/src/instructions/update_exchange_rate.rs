@@ -0,0 +1,83 @@
+// Update Exchange Rate Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// Crank that passes staking rewards on to LST holders.
+//
+// VULNERABILITIES:
+// V001: Missing crank check - anyone can set the exchange rate and mint
+//       LST that no SOL backs
+// V002: No bounds on the new rate - no update interval, no per-update limit,
+//       and no check that the vault can redeem every LST at the new rate
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct UpdateExchangeRate<'info> {
+    // VULNERABILITY V001: Any signer can update the rate
+    // Secure version names this account `crank` and checks it:
+    //   has_one = crank @ LstError::Unauthorized,
+    pub updater: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, pool.admin.as_ref()],
+        bump = pool.bump,
+        has_one = lst_mint,
+        has_one = sol_vault,
+        has_one = exchange_rate,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // VULNERABILITY V001: exchange_rate.crank is never compared to the signer
+    // Attack scenario (unbacked mint):
+    // 1. Honest stakers deposit 10 SOL for 10 LST
+    // 2. Attacker sets lamports_per_lst = 1 (1 lamport per LST)
+    // 3. Attacker deposits 0.001 SOL and receives 1,000,000 LST
+    // 4. Attacker sets the rate back to 1 SOL per LST
+    // 5. Attacker burns 10.001 LST and takes every lamport in the vault,
+    //    leaving honest stakers holding LST backed by nothing
+    #[account(
+        mut,
+        seeds = [EXCHANGE_RATE_SEED, pool.key().as_ref()],
+        bump = exchange_rate.bump,
+    )]
+    pub exchange_rate: Box<Account<'info, ExchangeRate>>,
+
+    // Secure version checks the new rate against the LST supply and the
+    // vault balance; here they are passed but never read
+    pub lst_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [SOL_VAULT_SEED, pool.key().as_ref()],
+        bump = pool.sol_vault_bump,
+    )]
+    pub sol_vault: SystemAccount<'info>,
+}
+
+impl<'info> UpdateExchangeRate<'info> {
+    pub fn update_exchange_rate(&mut self, new_lamports_per_lst: u64) -> Result<()> {
+        require!(new_lamports_per_lst > 0, LstError::InvalidExchangeRate);
+
+        // VULNERABILITY V002: New rate accepted as-is
+        // Secure version:
+        //   require!(now >= last_update_ts + MIN_UPDATE_INTERVAL_SECONDS)
+        //   require!(|new - old| <= old * MAX_RATE_CHANGE_BPS / BPS_DENOMINATOR)
+        //   require!(lst_supply * new / RATE_PRECISION <= sol_vault.lamports())
+        // Attack scenario (over-redemption):
+        // 1. Two stakers deposit 1 SOL and 10 SOL
+        // 2. The crank submits 100x the correct rate (bug, typo, or stolen key)
+        // 3. The 1 SOL staker burns 0.11 LST and receives all 11 SOL in the vault
+        // 4. The 10 SOL staker's LST can no longer be redeemed
+        let old_rate = self.exchange_rate.lamports_per_lst;
+
+        self.exchange_rate.lamports_per_lst = new_lamports_per_lst;
+        self.exchange_rate.last_update_ts = Clock::get()?.unix_timestamp;
+
+        msg!("Exchange rate updated: {} -> {} lamports per LST", old_rate, new_lamports_per_lst);
+
+        Ok(())
+    }
+}
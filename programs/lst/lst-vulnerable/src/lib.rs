@@ -0,0 +1,50 @@
+// Liquid Staking Program - VULNERABLE VERSION
+//
+// WARNING: This program contains intentional security vulnerabilities for educational purposes.
+// NEVER deploy this to production. Use lst-secure instead.
+//
+// This vulnerable liquid staking program demonstrates two classic exchange rate flaws:
+// - Anyone can update the rate that decides how much SOL each LST is worth
+// - Rate updates are not bounded or checked against the SOL that backs them
+//
+// VULNERABILITIES DOCUMENTED:
+// See individual instruction files for detailed vulnerability explanations.
+// Each vulnerability is marked with VULNERABILITY comments.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("ELL9FUDSRp8Bz2EU31mSSJGawN7DhBHjhp5RPcVQ2dRf");
+
+#[program]
+pub mod lst_vulnerable {
+    use super::*;
+
+    // Create the pool, LST mint and exchange rate account
+    pub fn initialize_pool(ctx: Context<InitializePool>, crank: Pubkey) -> Result<()> {
+        ctx.accounts.initialize_pool(crank, &ctx.bumps)
+    }
+
+    // Stake SOL and mint LST
+    pub fn deposit(ctx: Context<Deposit>, lamports: u64) -> Result<()> {
+        ctx.accounts.deposit(lamports)
+    }
+
+    // Burn LST and receive SOL
+    pub fn withdraw(ctx: Context<Withdraw>, lst_amount: u64) -> Result<()> {
+        ctx.accounts.withdraw(lst_amount)
+    }
+
+    // Pass staking rewards on to LST holders
+    // VULNERABILITY: No crank check and no bounds - anyone can set any rate
+    pub fn update_exchange_rate(ctx: Context<UpdateExchangeRate>, new_lamports_per_lst: u64) -> Result<()> {
+        ctx.accounts.update_exchange_rate(new_lamports_per_lst)
+    }
+}
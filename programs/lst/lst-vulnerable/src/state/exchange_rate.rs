@@ -0,0 +1,61 @@
+// Exchange Rate State - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// How many lamports one LST is worth. Staking rewards land in the SOL vault
+// and a crank raises the rate to pass them on to LST holders.
+//
+// VULNERABILITY:
+// V001: `crank` is recorded here but never checked (see update_exchange_rate.rs)
+//
+// RATE MATH:
+// lst      = lamports * RATE_PRECISION / lamports_per_lst   (rounded down)
+// lamports = lst * lamports_per_lst / RATE_PRECISION        (rounded down)
+//
+// Both directions round in the pool's favour, so deposit/withdraw round trips
+// can never take out more SOL than they put in.
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*};
+
+#[account]
+#[derive(InitSpace)]
+pub struct ExchangeRate {
+    // Pool this rate belongs to
+    pub pool: Pubkey,
+
+    // Account meant to update the rate
+    pub crank: Pubkey,
+
+    // Lamports per whole LST, scaled by RATE_PRECISION
+    pub lamports_per_lst: u64,
+
+    // Unix timestamp of the last update (or pool creation)
+    pub last_update_ts: i64,
+
+    pub bump: u8,
+}
+
+impl ExchangeRate {
+    // LST minted for a SOL deposit
+    pub fn lst_for_lamports(&self, lamports: u64) -> Result<u64> {
+        let lst = (lamports as u128)
+            .checked_mul(RATE_PRECISION as u128)
+            .ok_or(LstError::Overflow)?
+            .checked_div(self.lamports_per_lst as u128)
+            .ok_or(LstError::DivisionByZero)?;
+
+        u64::try_from(lst).map_err(|_| error!(LstError::Overflow))
+    }
+
+    // SOL paid out for burning LST
+    pub fn lamports_for_lst(&self, lst: u64) -> Result<u64> {
+        let lamports = (lst as u128)
+            .checked_mul(self.lamports_per_lst as u128)
+            .ok_or(LstError::Overflow)?
+            .checked_div(RATE_PRECISION as u128)
+            .ok_or(LstError::DivisionByZero)?;
+
+        u64::try_from(lamports).map_err(|_| error!(LstError::Overflow))
+    }
+}
@@ -0,0 +1,9 @@
+// State Module
+//
+// Exports all account structures for the liquid staking program
+
+pub mod pool;
+pub mod exchange_rate;
+
+pub use pool::*;
+pub use exchange_rate::*;
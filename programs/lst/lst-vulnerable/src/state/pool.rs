@@ -0,0 +1,25 @@
+// Pool State
+//
+// One liquid staking pool: SOL deposited into the vault is represented by
+// LST minted from a mint this PDA controls.
+
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Pool {
+    // Account that created the pool and chose the crank
+    pub admin: Pubkey,
+
+    // LST mint (PDA, mint authority is this pool)
+    pub lst_mint: Pubkey,
+
+    // System account PDA holding deposited SOL and accrued rewards
+    pub sol_vault: Pubkey,
+
+    // Exchange rate account for this pool
+    pub exchange_rate: Pubkey,
+
+    pub bump: u8,
+    pub sol_vault_bump: u8,
+}
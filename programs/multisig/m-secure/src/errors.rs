@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+pub use soteria_errors::m_secure::MultisigError as MultisigErrorCode;
+
 #[error_code]
 pub enum MultisigError {
     // Member validation errors
@@ -83,4 +85,64 @@ pub enum MultisigError {
 
     #[msg("Invalid parameter provided")]
     InvalidParameter,
+
+    // Nested multisig errors
+    #[msg("Parent multisig must be a different multisig")]
+    InvalidParentMultisig,
+
+    #[msg("Child proposal does not approve this parent proposal")]
+    ParentProposalMismatch,
+
+    // Rent payer errors
+    #[msg("Vault-funded proposals are disabled for this multisig")]
+    VaultRentDisabled,
+
+    #[msg("Rent payer must sign or be the multisig vault")]
+    InvalidRentPayer,
+
+    // Simulation guard errors
+    #[msg("Balance changes do not match the expected outcome")]
+    OutcomeMismatch,
+
+    // Large multisig errors
+    #[msg("Member slab is full - grow the multisig first")]
+    MemberSlabFull,
+
+    // Vault stake errors
+    #[msg("Stake account does not match the proposal")]
+    InvalidStakeAccount,
+
+    #[msg("Vote account does not match the proposal")]
+    InvalidVoteAccount,
+
+    // Proposal metadata errors
+    #[msg("Proposal title is too long")]
+    TitleTooLong,
+
+    #[msg("Proposal memo is too long")]
+    MemoTooLong,
+
+    // Token sweep errors
+    #[msg("Token mint does not match the proposal")]
+    InvalidMint,
+
+    // Proposal hash errors
+    #[msg("Proposal parameters do not match the approved proposal hash")]
+    ProposalHashMismatch,
+
+    // Reserved: the reentrancy guard was removed, but the code stays taken so
+    // the variants after it keep their numbers. Never returned.
+    #[msg("Reserved (formerly ReentrantExecution)")]
+    ReentrantExecution,
+
+    // Address lookup table errors
+    #[msg("Lookup table does not match the proposal")]
+    InvalidLookupTable,
+
+    #[msg("Lookup table addresses do not match the proposal")]
+    LookupTableAddressesMismatch,
+
+    // Threshold policy errors
+    #[msg("Threshold policy entry exceeds the owner count")]
+    InvalidThresholdPolicy,
 }
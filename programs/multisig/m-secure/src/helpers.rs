@@ -0,0 +1,81 @@
+// Multisig Helper Functions
+//
+// Rent payer handling shared by create_proposal and create_transfer_proposal.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::{constants::*, errors::*, state::Multisig};
+
+// Reimburse the proposer for a new proposal account's rent
+//
+// The proposer always funds the Anchor init; when a separate rent_payer is given,
+// it pays the proposer back in the same instruction. Returns the account that
+// gets the rent back when the proposal closes.
+//
+// rent_payer may be:
+// - None or the proposer: the proposer keeps paying (default)
+// - The vault: only when multisig.vault_pays_rent is set, signed with vault seeds
+// - Any other account: must sign the transaction (a sponsor)
+pub fn reimburse_proposal_rent<'info>(
+    rent_payer: Option<&UncheckedAccount<'info>>,
+    proposer: &Signer<'info>,
+    multisig_account: &Account<'info, Multisig>,
+    system_program: &Program<'info, System>,
+    rent: u64,
+) -> Result<Pubkey> {
+    let rent_payer = match rent_payer {
+        Some(rent_payer) if rent_payer.key() != proposer.key() => rent_payer,
+        _ => return Ok(proposer.key()),
+    };
+
+    if rent_payer.key() == multisig_account.vault {
+        // Vault-funded mode must be enabled by the multisig itself
+        require!(
+            multisig_account.vault_pays_rent,
+            MultisigError::VaultRentDisabled
+        );
+
+        // Vault must stay rent-exempt after paying
+        let vault_minimum = Rent::get()?
+            .minimum_balance(0)
+            .checked_add(rent)
+            .ok_or(MultisigError::Overflow)?;
+        require!(
+            rent_payer.lamports() >= vault_minimum,
+            MultisigError::InsufficientFunds
+        );
+
+        let multisig_key = multisig_account.key();
+        let vault_seeds = &[
+            VAULT,
+            multisig_key.as_ref(),
+            &[multisig_account.vault_bump],
+        ];
+        let signer_seeds = &[&vault_seeds[..]];
+
+        let cpi_context = CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Transfer {
+                from: rent_payer.to_account_info(),
+                to: proposer.to_account_info(),
+            },
+            signer_seeds,
+        );
+        transfer(cpi_context, rent)?;
+    } else {
+        // A sponsor must authorize spending its lamports
+        require!(rent_payer.is_signer, MultisigError::InvalidRentPayer);
+
+        let cpi_context = CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: rent_payer.to_account_info(),
+                to: proposer.to_account_info(),
+            },
+        );
+        transfer(cpi_context, rent)?;
+    }
+
+    Ok(rent_payer.key())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::*, constants::*};
+
+// Add Large Member Instruction
+//
+// Adds a member to a MultisigLarge and sets the new threshold in one step.
+// There is no proposal account: the change needs `threshold` member
+// signatures on this transaction - the admin plus co-signing members passed
+// as remaining accounts.
+//
+// SECURITY FEATURES:
+// - Admin (creator) must sign
+// - Only signing, current members count; each is counted once
+// - Blocked while paused
+// - New threshold validated against the new member count
+
+#[derive(Accounts)]
+pub struct AddLargeMember<'info> {
+    // Admin - must be the creator; counts as the first signature
+    pub admin: Signer<'info>,
+
+    // Large multisig PDA
+    // Seeds: ["multisig_large", creator, multisig_id]
+    #[account(
+        mut,
+        seeds = [
+            MULTISIG_LARGE,
+            multisig_large.load()?.creator.as_ref(),
+            &multisig_large.load()?.multisig_id.to_le_bytes(),
+        ],
+        bump = multisig_large.load()?.bump,
+    )]
+    pub multisig_large: AccountLoader<'info, MultisigLarge>,
+}
+
+impl<'info> AddLargeMember<'info> {
+    pub fn add_large_member(
+        &mut self,
+        new_member: Pubkey,
+        role: MemberRole,
+        new_threshold: u8,
+        co_signers: &[AccountInfo],
+    ) -> Result<()> {
+        let admin = self.admin.key();
+        let multisig_info = self.multisig_large.to_account_info();
+        let mut data = multisig_info.try_borrow_mut_data()?;
+        let (multisig, slab) = MultisigLarge::split_mut(&mut data);
+
+        // SECURITY CHECKS
+
+        // 1. Admin Check
+        require!(multisig.is_admin(&admin), MultisigError::OnlyAdmin);
+
+        // 2. Pause Check
+        require!(!multisig.is_paused(), MultisigError::MultisigPaused);
+
+        // 3. Signature Count
+        // SECURITY: Non-signers, non-members, the admin again and repeated
+        // accounts are all ignored
+        let mut signers: Vec<Pubkey> = vec![admin];
+        for account in co_signers {
+            if account.is_signer
+                && multisig.is_member(slab, account.key)
+                && !signers.contains(account.key)
+            {
+                signers.push(*account.key);
+            }
+        }
+        require!(
+            signers.len() >= multisig.threshold as usize,
+            MultisigError::InsufficientApprovals
+        );
+
+        // 4. New Member Validation
+        require!(new_member != Pubkey::default(), MultisigError::InvalidParameter);
+        require!(
+            !multisig.is_member(slab, &new_member),
+            MultisigError::AlreadyMember
+        );
+        require!(
+            multisig.member_count < multisig.member_capacity,
+            MultisigError::MemberSlabFull
+        );
+
+        // 5. Threshold Validation
+        let new_count = multisig.member_count + 1;
+        require!(new_threshold >= 1, MultisigError::InvalidThreshold);
+        require!(
+            new_threshold as u16 <= new_count,
+            MultisigError::ThresholdExceedsOwners
+        );
+
+        // 6. Write Member
+        slab[multisig.member_count as usize] = LargeMember::new(new_member, role);
+        multisig.member_count = new_count;
+        multisig.threshold = new_threshold;
+
+        Ok(())
+    }
+}
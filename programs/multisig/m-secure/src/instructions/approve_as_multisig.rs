@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::*, constants::*};
+
+// Approve As Multisig Instruction
+//
+// Lets a child multisig approve a proposal on a parent multisig it is a member of.
+// The child multisig is a PDA and can never sign, so no signer check is made for it.
+// Instead the child's authority comes from its own ApproveParentProposal proposal,
+// which must meet every condition execute_proposal enforces on the child
+// (threshold, timelock, expiry, executor role, proposal hash).
+//
+// Executing the child proposal and recording the parent approval happen together:
+// the child proposal is marked Executed and closed (rent returned to its rent payer),
+// and the child's bit is set in the parent proposal's approval bitmap.
+
+#[derive(Accounts)]
+pub struct ApproveAsMultisig<'info> {
+    // Executor - must be Admin or Executor on the child multisig
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    // Child multisig - the PDA member of the parent multisig
+    // Seeds prove it is a real multisig of this program, not a lookalike account
+    #[account(
+        mut,
+        seeds = [
+            MULTISIG,
+            child_multisig.creator.as_ref(),
+            &child_multisig.multisig_id.to_le_bytes(),
+        ],
+        bump = child_multisig.bump,
+    )]
+    pub child_multisig: Account<'info, Multisig>,
+
+    // Child proposal - the child's decision to approve the parent proposal
+    #[account(
+        mut,
+        seeds = [
+            PROPOSAL,
+            child_multisig.key().as_ref(),
+            &child_proposal.proposal_id.to_le_bytes(),
+        ],
+        bump = child_proposal.bump,
+        close = child_rent_payer,
+    )]
+    pub child_proposal: Account<'info, Proposal>,
+
+    // Child rent payer - receives rent from the closed child proposal
+    /// CHECK: Must match the rent payer recorded on child_proposal
+    #[account(mut, address = child_proposal.rent_payer @ MultisigError::InvalidRentPayer)]
+    pub child_rent_payer: UncheckedAccount<'info>,
+
+    // Parent multisig - lists the child multisig as a member
+    #[account(
+        seeds = [
+            MULTISIG,
+            multisig_account.creator.as_ref(),
+            &multisig_account.multisig_id.to_le_bytes(),
+        ],
+        bump = multisig_account.bump,
+    )]
+    pub multisig_account: Account<'info, Multisig>,
+
+    // Parent proposal being approved
+    #[account(
+        mut,
+        seeds = [
+            PROPOSAL,
+            multisig_account.key().as_ref(),
+            &proposal.proposal_id.to_le_bytes(),
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+impl<'info> ApproveAsMultisig<'info> {
+    pub fn approve_as_multisig(&mut self) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Pause Check
+        // Neither multisig may be paused
+        require!(
+            !self.multisig_account.paused && !self.child_multisig.paused,
+            MultisigError::MultisigPaused
+        );
+
+        // 2. Executor Permission Check
+        // Only Admin or Executor on the child can execute its proposals
+        require!(
+            self.child_multisig.can_execute(&self.executor.key()),
+            MultisigError::CannotExecute
+        );
+
+        // 3. Child Proposal Target Validation
+        // The child proposal must approve exactly this parent proposal
+        // Prevents replaying a child approval onto a different parent proposal
+        match self.child_proposal.proposal_type {
+            ProposalType::ApproveParentProposal { parent_multisig, parent_proposal_id } => {
+                require!(
+                    parent_multisig == self.multisig_account.key()
+                        && parent_proposal_id == self.proposal.proposal_id,
+                    MultisigError::ParentProposalMismatch
+                );
+            }
+            _ => return err!(MultisigError::InvalidProposalType),
+        }
+
+        // 4. Child Proposal Status Check
+        // Prevents re-using an executed or cancelled child proposal
+        require!(
+            self.child_proposal.is_active(),
+            MultisigError::ProposalNotActive
+        );
+
+        // 5. Child Threshold Check
+        // The child's own members must have reached its threshold
+        require!(
+            self.child_proposal.approval_count
+                >= self.child_multisig.threshold_for(&self.child_proposal.proposal_type),
+            MultisigError::InsufficientApprovals
+        );
+
+        // 6. Child Timelock and Expiry Checks
+        let clock = Clock::get()?;
+        require!(
+            self.child_proposal
+                .timelock_passed(clock.unix_timestamp, self.child_multisig.timelock_seconds),
+            MultisigError::TimelockNotPassed
+        );
+        require!(
+            !self.child_proposal.is_expired(clock.unix_timestamp),
+            MultisigError::ProposalExpired
+        );
+
+        // 7. Child Proposal Hash Check
+        // The child's parameters must still hash to what its members approved,
+        // so only the parent proposal they signed off on can be approved
+        require!(
+            self.child_proposal.has_valid_hash()?,
+            MultisigError::ProposalHashMismatch
+        );
+
+        // 8. Parent Proposal-Multisig Relationship Validation
+        require!(
+            self.proposal.multisig == self.multisig_account.key(),
+            MultisigError::NotAMember
+        );
+
+        // 9. Child Membership Validation
+        // The child multisig must be a member of the parent
+        let owner_index = self
+            .multisig_account
+            .member_index(&self.child_multisig.key())
+            .ok_or(MultisigError::NotAMember)?;
+
+        // 10. Parent Proposal Status Check
+        require!(
+            self.proposal.is_active(),
+            MultisigError::ProposalNotActive
+        );
+
+        // 11. Record Approval Using Bitmap
+        // Fails if the child multisig already approved this proposal
+        let success = self.proposal.approve(owner_index);
+        require!(success, MultisigError::AlreadyApproved);
+
+        require!(
+            self.proposal.approval_count <= self.multisig_account.owner_count,
+            MultisigError::Overflow
+        );
+
+        // 12. Mark Child Proposal as Executed
+        // Child proposal account is closed by Anchor (close = child_rent_payer)
+        self.child_multisig.last_executed_proposal = self.child_proposal.proposal_id;
+        self.child_proposal.status = ProposalStatus::Executed;
+        self.child_proposal.executed_at = clock.unix_timestamp;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::{state::*, errors::*, constants::*};
+
+// Attach Memo Instruction
+//
+// Lets the proposer describe an active proposal after creating it.
+// A given title replaces the current one; the memo text is appended to the memo.
+// The proposal account is resized to fit, with the proposer topping up the rent.
+//
+// SECURITY FEATURES:
+// - Proposer only, active proposals only
+// - Title and memo never exceed MAX_PROPOSAL_TITLE_LEN / MAX_PROPOSAL_MEMO_LEN,
+//   so the proposal stays small enough for every instruction to load
+
+#[derive(Accounts)]
+pub struct AttachMemo<'info> {
+    // Proposer - must have created the proposal
+    // Pays the rent for the extra bytes
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    // Multisig account - needed for proposal PDA validation
+    #[account(
+        seeds = [
+            MULTISIG,
+            multisig_account.creator.as_ref(),
+            &multisig_account.multisig_id.to_le_bytes(),
+        ],
+        bump = multisig_account.bump,
+    )]
+    pub multisig_account: Account<'info, Multisig>,
+
+    // Proposal receiving the metadata
+    #[account(
+        mut,
+        seeds = [
+            PROPOSAL,
+            multisig_account.key().as_ref(),
+            &proposal.proposal_id.to_le_bytes(),
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AttachMemo<'info> {
+    pub fn attach_memo(&mut self, title: Option<String>, memo: String) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Pause Check
+        require!(
+            !self.multisig_account.paused,
+            MultisigError::MultisigPaused
+        );
+
+        // 2. Proposal-Multisig Relationship Validation
+        require!(
+            self.proposal.multisig == self.multisig_account.key(),
+            MultisigError::NotAMember
+        );
+
+        // 3. Proposal Status Check
+        // Executed or cancelled proposals are final
+        require!(
+            self.proposal.is_active(),
+            MultisigError::ProposalNotActive
+        );
+
+        // 4. Authorization Check
+        // Only the proposer speaks for the proposal
+        require!(
+            self.proposer.key() == self.proposal.proposer,
+            MultisigError::NotProposer
+        );
+
+        // 5. Length Bounds
+        let title = title.unwrap_or_else(|| self.proposal.title.clone());
+        require!(
+            title.len() <= MAX_PROPOSAL_TITLE_LEN,
+            MultisigError::TitleTooLong
+        );
+
+        let memo_len = self
+            .proposal
+            .memo
+            .len()
+            .checked_add(memo.len())
+            .ok_or(MultisigError::Overflow)?;
+        require!(
+            memo_len <= MAX_PROPOSAL_MEMO_LEN,
+            MultisigError::MemoTooLong
+        );
+
+        // 6. Rent Top-Up
+        // A shorter title leaves its surplus in the account; like the rest of
+        // the proposal's lamports it goes to rent_payer when the proposal closes
+        let info = self.proposal.to_account_info();
+        let new_space = Proposal::space(title.len(), memo_len);
+        let rent_due = Rent::get()?
+            .minimum_balance(new_space)
+            .saturating_sub(info.lamports());
+
+        if rent_due > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.proposer.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+
+        // 7. Resize and store the metadata
+        // Anchor serializes the proposal into the resized account on exit
+        info.resize(new_space)?;
+        self.proposal.title = title;
+        self.proposal.memo.push_str(&memo);
+
+        Ok(())
+    }
+}
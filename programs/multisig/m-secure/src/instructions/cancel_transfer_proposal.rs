@@ -5,7 +5,7 @@ use crate::{state::*, errors::*, constants::*};
 //
 // Allows the proposer or creator to cancel an active transfer proposal.
 // Only active proposals can be cancelled.
-// Proposal account is closed and rent returned to the proposer.
+// Proposal account is closed and rent returned to its rent payer.
 //
 // Security: Only proposer or creator can cancel to prevent griefing attacks.
 
@@ -26,7 +26,7 @@ pub struct CancelTransferProposal<'info> {
     pub multisig_account: Account<'info, Multisig>,
 
     // Proposal being cancelled
-    // Rent returned to proposer (who created and paid for it)
+    // Rent returned to whoever paid for it
     #[account(
         mut,
         seeds = [
@@ -35,14 +35,15 @@ pub struct CancelTransferProposal<'info> {
             &transfer_proposal.proposal_id.to_le_bytes(),
         ],
         bump = transfer_proposal.bump,
-        close = proposer,
+        has_one = rent_payer @ MultisigError::InvalidRentPayer,
+        close = rent_payer,
     )]
      pub transfer_proposal: Account<'info, TransferProposal>,
 
-    // Proposer account - receives rent refund
+    // Rent payer account - receives rent refund (proposer, sponsor or vault)
     // Must be mutable to receive lamports
     #[account(mut)]
-    pub proposer: SystemAccount<'info>,
+    pub rent_payer: SystemAccount<'info>,
 }
 
 impl<'info> CancelTransferProposal<'info> {
@@ -80,8 +81,8 @@ impl<'info> CancelTransferProposal<'info> {
         // Prevents race conditions where proposal gets executed during cancellation
         self.transfer_proposal.status = ProposalStatus::Cancelled;
 
-        // Proposal account automatically closed by Anchor (close = proposer)
-        // Rent returned to original proposer who paid for creation
+        // Proposal account automatically closed by Anchor (close = rent_payer)
+        // Rent returned to whoever paid for creation
 
         Ok(())
     }
@@ -103,6 +103,8 @@ impl<'info> CreateMultisig<'info> {
             vault: self.vault.key(),
             bump: bumps.multisig_account,
             vault_bump: bumps.vault,
+            vault_pays_rent: false,
+            threshold_policy: ThresholdPolicy::default(),
         });
 
         // 5. Initialize Vault Account
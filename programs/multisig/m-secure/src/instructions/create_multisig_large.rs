@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, constants::*};
+
+// Create Large Multisig Instruction
+//
+// Initializes a zero-copy MultisigLarge with:
+// - Creator as member 0 (Admin) and threshold 1
+// - A member slab of LARGE_MEMBERS_INITIAL_CAPACITY zeroed slots
+//
+// Further members join with add_large_member; more slots come from
+// grow_multisig_large.
+
+#[derive(Accounts)]
+#[instruction(multisig_id: u64)]
+pub struct CreateMultisigLarge<'info> {
+    // Creator and first member
+    // Must sign and pay for account creation
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    // Large multisig PDA
+    // Seeds: ["multisig_large", creator, multisig_id]
+    // Sized for the header plus the initial member slab
+    #[account(
+        init,
+        payer = creator,
+        space = MultisigLarge::space(LARGE_MEMBERS_INITIAL_CAPACITY),
+        seeds = [
+            MULTISIG_LARGE,
+            creator.key().as_ref(),
+            &multisig_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub multisig_large: AccountLoader<'info, MultisigLarge>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateMultisigLarge<'info> {
+    pub fn create_multisig_large(
+        &mut self,
+        multisig_id: u64,
+        timelock_seconds: u64,
+        bumps: &CreateMultisigLargeBumps,
+    ) -> Result<()> {
+        // 1. Initialize Header
+        // load_init checks the account is fresh and writes the discriminator
+        {
+            let mut multisig = self.multisig_large.load_init()?;
+            multisig.creator = self.creator.key();
+            multisig.multisig_id = multisig_id;
+            multisig.timelock_seconds = timelock_seconds;
+            multisig.member_count = 1;
+            multisig.member_capacity = LARGE_MEMBERS_INITIAL_CAPACITY as u16;
+            multisig.threshold = 1;
+            multisig.bump = bumps.multisig_large;
+        }
+
+        // 2. Creator Takes Slot 0
+        // Written through the raw data once the header borrow is released;
+        // the slab is not part of the MultisigLarge struct
+        let multisig_info = self.multisig_large.to_account_info();
+        let mut data = multisig_info.try_borrow_mut_data()?;
+        let (_, slab) = MultisigLarge::split_mut(&mut data);
+        slab[0] = LargeMember::new(self.creator.key(), MemberRole::Admin);
+
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, errors::*, constants::*};
+use crate::{state::*, errors::*, constants::*, helpers::reimburse_proposal_rent};
 
 // Create Proposal Instruction
 //
@@ -8,6 +8,8 @@ use crate::{state::*, errors::*, constants::*};
 //
 // The proposer automatically approves their own proposal (approval_count starts at 1).
 // Proposal remains active until executed or cancelled.
+//
+// An optional rent_payer (the vault or a signing sponsor) reimburses the proposal rent.
 
 #[derive(Accounts)]
 pub struct CreateProposal<'info> {
@@ -35,7 +37,7 @@ pub struct CreateProposal<'info> {
     #[account(
         init,
         payer = proposer,
-        space = ANCHOR_DISCRIMINATOR + Proposal::INIT_SPACE,
+        space = Proposal::space(0, 0),
         seeds = [
             PROPOSAL,
             multisig_account.key().as_ref(),
@@ -46,6 +48,11 @@ pub struct CreateProposal<'info> {
     pub proposal: Account<'info, Proposal>,
 
     pub system_program: Program<'info, System>,
+
+    // Optional rent payer - reimburses the proposer for the proposal rent
+    /// CHECK: Validated in reimburse_proposal_rent (vault with flag set, or a signer)
+    #[account(mut)]
+    pub rent_payer: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> CreateProposal<'info> {
@@ -118,6 +125,13 @@ impl<'info> CreateProposal<'info> {
                     MultisigError::InvalidParameter
                 );
 
+                // New member may be another multisig PDA (approves via approve_as_multisig)
+                // but a multisig cannot be a member of itself
+                require!(
+                    new_member != self.multisig_account.key(),
+                    MultisigError::InvalidParentMultisig
+                );
+
                 // Validate max members not reached
                 // Fixed array has MAX_OWNERS limit
                 require!(
@@ -160,6 +174,12 @@ impl<'info> CreateProposal<'info> {
                     self.multisig_account.threshold <= new_owner_count,
                     MultisigError::ThresholdExceedsOwners
                 );
+
+                // Every threshold policy entry must stay reachable too
+                require!(
+                    self.multisig_account.threshold_policy.is_valid(new_owner_count),
+                    MultisigError::InvalidThresholdPolicy
+                );
             }
 
             ProposalType::ChangeThreshold { new_threshold } => {
@@ -187,6 +207,94 @@ impl<'info> CreateProposal<'info> {
                     MultisigError::InvalidParameter
                 );
             }
+
+            ProposalType::SetVaultPaysRent { enabled: _ } => {
+                // Only admin can change who pays proposal rent
+                require!(
+                    self.multisig_account.is_admin(&self.proposer.key()),
+                    MultisigError::OnlyAdmin
+                );
+            }
+
+            ProposalType::DelegateVaultStake { vote_account, amount } => {
+                // Only admin can commit vault SOL to a validator
+                require!(
+                    self.multisig_account.is_admin(&self.proposer.key()),
+                    MultisigError::OnlyAdmin
+                );
+
+                require!(
+                    vote_account != Pubkey::default() && amount > 0,
+                    MultisigError::InvalidParameter
+                );
+            }
+
+            ProposalType::DeactivateVaultStake { stake_account }
+            | ProposalType::WithdrawVaultStake { stake_account } => {
+                // Both only move stake back towards the vault
+                // The stake program rejects accounts the vault has no authority over
+                require!(
+                    stake_account != Pubkey::default(),
+                    MultisigError::InvalidParameter
+                );
+            }
+
+            ProposalType::SweepToken { mint, recipient } => {
+                // The mint and both associated token accounts are checked
+                // again against the proposal at execution
+                require!(
+                    mint != Pubkey::default() && recipient != Pubkey::default(),
+                    MultisigError::InvalidParameter
+                );
+            }
+
+            ProposalType::CreateLookupTable => {
+                // The table address is derived at execution from the vault
+                // and the executor's recent slot; nothing to check up front
+            }
+
+            ProposalType::ExtendLookupTable { lookup_table, addresses_hash: _, address_count } => {
+                // The lookup table program rejects tables the vault has no authority over
+                require!(
+                    lookup_table != Pubkey::default(),
+                    MultisigError::InvalidParameter
+                );
+
+                // At least one address, and few enough to fit one transaction
+                require!(
+                    address_count > 0 && address_count <= MAX_LOOKUP_TABLE_EXTEND,
+                    MultisigError::InvalidParameter
+                );
+            }
+
+            ProposalType::ApproveParentProposal { parent_multisig, parent_proposal_id: _ } => {
+                // A multisig cannot be its own parent
+                // Prevents a multisig from approving its own proposals through itself
+                require!(
+                    parent_multisig != self.multisig_account.key(),
+                    MultisigError::InvalidParentMultisig
+                );
+
+                // Validate parent is not default pubkey
+                require!(
+                    parent_multisig != Pubkey::default(),
+                    MultisigError::InvalidParameter
+                );
+            }
+
+            ProposalType::SetThresholdPolicy { policy } => {
+                // Only admin can change the threshold table
+                require!(
+                    self.multisig_account.is_admin(&self.proposer.key()),
+                    MultisigError::OnlyAdmin
+                );
+
+                // Entries above the owner count could never be met
+                require!(
+                    policy.is_valid(self.multisig_account.owner_count),
+                    MultisigError::InvalidThresholdPolicy
+                );
+            }
         }
 
         // 6. Increment Proposal Count
@@ -200,7 +308,17 @@ impl<'info> CreateProposal<'info> {
 
         let proposal_id = self.multisig_account.proposal_count - 1;
 
-        // 7. Initialize Proposal State
+        // 7. Settle Proposal Rent
+        // Recorded so the rent goes back to whoever paid it on close
+        let rent_payer = reimburse_proposal_rent(
+            self.rent_payer.as_ref(),
+            &self.proposer,
+            &self.multisig_account,
+            &self.system_program,
+            self.proposal.to_account_info().lamports(),
+        )?;
+
+        // 8. Initialize Proposal State
         // Proposer auto-approves their own proposal
         let mut approval_bitmap: u64 = 0;
         approval_bitmap |= 1u64 << proposer_index;
@@ -225,9 +343,16 @@ impl<'info> CreateProposal<'info> {
             created_at: clock.unix_timestamp,
             expires_at,
             executed_at: 0,
+            rent_payer,
             bump: bumps.proposal,
+            proposal_hash: [0; 32],
+            title: String::new(),
+            memo: String::new(),
         });
 
+        // Hash of exactly what the approvals are for, checked again at execution
+        self.proposal.proposal_hash = self.proposal.proposal_hash()?;
+
         Ok(())
     }
 }
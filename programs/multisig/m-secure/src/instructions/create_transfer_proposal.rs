@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, errors::*, constants::*};
+use crate::{state::*, errors::*, constants::*, helpers::reimburse_proposal_rent};
 
 // Create Transfer Proposal Instruction
 //
@@ -8,6 +8,10 @@ use crate::{state::*, errors::*, constants::*};
 //
 // Flow:
 // 1. Creates TransferProposal (for transfer-specific data)
+// 2. Optional rent_payer (the vault or a signing sponsor) reimburses the rent
+//
+// recipient_kind is Wallet unless the proposer explicitly opts in to paying
+// a program vault; see RecipientKind in state/transfer_proposal.rs
 
 #[derive(Accounts)]
 pub struct CreateTransferProposal<'info> {
@@ -43,6 +47,11 @@ pub struct CreateTransferProposal<'info> {
     pub transfer_proposal: Account<'info, TransferProposal>,
 
     pub system_program: Program<'info, System>,
+
+    // Optional rent payer - reimburses the proposer for the proposal rent
+    /// CHECK: Validated in reimburse_proposal_rent (vault with flag set, or a signer)
+    #[account(mut)]
+    pub rent_payer: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> CreateTransferProposal<'info> {
@@ -50,6 +59,7 @@ impl<'info> CreateTransferProposal<'info> {
         &mut self,
         amount: u64,
         recipient: Pubkey,
+        recipient_kind: RecipientKind,
         bumps: &CreateTransferProposalBumps,
     ) -> Result<()> {
         // SECURITY CHECKS
@@ -79,7 +89,18 @@ impl<'info> CreateTransferProposal<'info> {
             MultisigError::InvalidRecipient
         );
 
-        // 5. Amount Validation
+        // 5. Recipient Kind Validation
+        // A program vault names the program that owns it. The System Program
+        // is the Wallet path, and accounts owned by this program (proposals,
+        // the multisig itself) are never transfer targets
+        if let RecipientKind::ProgramVault { owner } = recipient_kind {
+            require!(
+                owner != System::id() && owner != crate::ID,
+                MultisigError::InvalidRecipient
+            );
+        }
+
+        // 6. Amount Validation
         require!(amount > 0, MultisigError::InvalidParameter);
 
         // Get proposer's index for auto-approval
@@ -87,7 +108,7 @@ impl<'info> CreateTransferProposal<'info> {
             .member_index(&self.proposer.key())
             .ok_or(MultisigError::NotAMember)?;
 
-        // 6. Increment Proposal Count
+        // 7. Increment Proposal Count
         self.multisig_account.proposal_count = self
             .multisig_account
             .proposal_count
@@ -96,7 +117,16 @@ impl<'info> CreateTransferProposal<'info> {
 
         let proposal_id = self.multisig_account.proposal_count - 1;
 
-        // 7. Initialize Base Proposal
+        // 8. Settle Proposal Rent
+        let rent_payer = reimburse_proposal_rent(
+            self.rent_payer.as_ref(),
+            &self.proposer,
+            &self.multisig_account,
+            &self.system_program,
+            self.transfer_proposal.to_account_info().lamports(),
+        )?;
+
+        // 9. Initialize Base Proposal
         let mut approval_bitmap: u64 = 0;
         approval_bitmap |= 1u64 << proposer_index;
 
@@ -121,6 +151,8 @@ impl<'info> CreateTransferProposal<'info> {
             executed_at: 0, 
             amount, 
             recipient, 
+            recipient_kind,
+            rent_payer,
             bump: bumps.transfer_proposal 
         });
 
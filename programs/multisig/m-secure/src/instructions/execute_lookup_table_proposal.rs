@@ -0,0 +1,240 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use crate::{state::*, errors::*, constants::*};
+
+// Execute Lookup Table Proposal Instruction
+//
+// Executes an approved address lookup table proposal through the address
+// lookup table program:
+// - CreateLookupTable: create the table at [vault, recent_slot] under the
+//   lookup table program, with the vault as authority and rent payer
+// - ExtendLookupTable: append the approved addresses to a vault-owned table
+//
+// The vault PDA is the table authority, so only proposals that pass this
+// multisig's approvals and timelock can change what a table resolves to.
+// Large vault transactions can then reference the table's accounts by index.
+//
+// Arguments:
+// - recent_slot: CreateLookupTable only; must still be in the SlotHashes
+//   sysvar, so it is chosen at execution rather than at proposal time
+// - addresses: ExtendLookupTable only; must hash to the proposal's addresses_hash
+//
+// Closes the proposal account after successful execution (rent returned to its rent payer).
+
+#[derive(Accounts)]
+pub struct ExecuteLookupTableProposal<'info> {
+    // Executor - must be Admin or Executor role
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    // Multisig account
+    #[account(
+        mut,
+        seeds = [
+            MULTISIG,
+            multisig_account.creator.as_ref(),
+            &multisig_account.multisig_id.to_le_bytes(),
+        ],
+        bump = multisig_account.bump,
+    )]
+    pub multisig_account: Account<'info, Multisig>,
+
+    // Proposal being executed
+    #[account(
+        mut,
+        seeds = [
+            PROPOSAL,
+            multisig_account.key().as_ref(),
+            &proposal.proposal_id.to_le_bytes(),
+        ],
+        bump = proposal.bump,
+        has_one = rent_payer @ MultisigError::InvalidRentPayer,
+        close = rent_payer,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    // Rent payer - whoever paid for the proposal (proposer, sponsor or vault)
+    /// CHECK: Validated by has_one constraint on proposal
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    // Vault PDA - table authority, pays the table's rent
+    #[account(
+        mut,
+        seeds = [
+            VAULT,
+            multisig_account.key().as_ref(),
+        ],
+        bump = multisig_account.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    // Lookup table the proposal acts on
+    /// CHECK: Derived or matched against the proposal in the handler; the
+    /// lookup table program checks its state and that the vault is its authority
+    #[account(mut)]
+    pub lookup_table: UncheckedAccount<'info>,
+
+    /// CHECK: Address lookup table program, pinned by address
+    #[account(address = ADDRESS_LOOKUP_TABLE_PROGRAM_ID)]
+    pub address_lookup_table_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExecuteLookupTableProposal<'info> {
+    pub fn execute_lookup_table_proposal(
+        &mut self,
+        recent_slot: u64,
+        addresses: Vec<Pubkey>,
+    ) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Pause Check
+        require!(
+            !self.multisig_account.paused,
+            MultisigError::MultisigPaused
+        );
+
+        // 2. Executor Permission Check
+        // Only Admin or Executor can execute proposals
+        require!(
+            self.multisig_account.can_execute(&self.executor.key()),
+            MultisigError::CannotExecute
+        );
+
+        // 3. Proposal Status Check
+        require!(
+            self.proposal.is_active(),
+            MultisigError::ProposalNotActive
+        );
+
+        // 4. Threshold Check
+        require!(
+            self.proposal.approval_count
+                >= self.multisig_account.threshold_for(&self.proposal.proposal_type),
+            MultisigError::InsufficientApprovals
+        );
+
+        // 5. Timelock Check
+        let clock = Clock::get()?;
+        require!(
+            self.proposal.timelock_passed(clock.unix_timestamp, self.multisig_account.timelock_seconds),
+            MultisigError::TimelockNotPassed
+        );
+
+        // 6. Expiry Check
+        require!(
+            !self.proposal.is_expired(clock.unix_timestamp),
+            MultisigError::ProposalExpired
+        );
+
+        // 7. Proposal Hash Check
+        // The parameters must still hash to what was approved
+        require!(
+            self.proposal.has_valid_hash()?,
+            MultisigError::ProposalHashMismatch
+        );
+
+        // Execute based on proposal type
+        match self.proposal.proposal_type {
+            ProposalType::CreateLookupTable => {
+                // 8. Lookup Table Derivation
+                // The lookup table program derives the same address and
+                // rejects a slot that is not in SlotHashes
+                let vault_key = self.vault.key();
+                let (expected_table, table_bump) = Pubkey::find_program_address(
+                    &[vault_key.as_ref(), &recent_slot.to_le_bytes()],
+                    &ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+                );
+                require!(
+                    self.lookup_table.key() == expected_table,
+                    MultisigError::InvalidLookupTable
+                );
+
+                let mut data = LOOKUP_TABLE_IX_CREATE.to_le_bytes().to_vec();
+                data.extend_from_slice(&recent_slot.to_le_bytes());
+                data.push(table_bump);
+
+                self.invoke_lookup_table(data)?;
+
+                msg!("Created lookup table {} for vault {}", expected_table, vault_key);
+            }
+
+            ProposalType::ExtendLookupTable { lookup_table, addresses_hash, address_count } => {
+                // 8. Lookup Table Check
+                require!(
+                    self.lookup_table.key() == lookup_table,
+                    MultisigError::InvalidLookupTable
+                );
+
+                // 9. Address List Check
+                // Only the exact list the approvers signed off on can be appended
+                require!(
+                    addresses.len() == address_count as usize
+                        && lookup_table_addresses_hash(&addresses) == addresses_hash,
+                    MultisigError::LookupTableAddressesMismatch
+                );
+
+                // Vec<Pubkey> is bincode encoded with a u64 length prefix
+                let mut data = LOOKUP_TABLE_IX_EXTEND.to_le_bytes().to_vec();
+                data.extend_from_slice(&(addresses.len() as u64).to_le_bytes());
+                for address in &addresses {
+                    data.extend_from_slice(address.as_ref());
+                }
+
+                self.invoke_lookup_table(data)?;
+            }
+
+            _ => return err!(MultisigError::InvalidProposalType),
+        }
+
+        // Update multisig state
+        self.multisig_account.last_executed_proposal = self.proposal.proposal_id;
+
+        // Mark Proposal as Executed
+        self.proposal.status = ProposalStatus::Executed;
+        self.proposal.executed_at = clock.unix_timestamp;
+
+        Ok(())
+    }
+
+    // CPI into the lookup table program with the vault PDA signing as both
+    // table authority and payer; create and extend take the same accounts
+    fn invoke_lookup_table(&self, data: Vec<u8>) -> Result<()> {
+        let multisig_key = self.multisig_account.key();
+        let vault_seeds = &[
+            VAULT,
+            multisig_key.as_ref(),
+            &[self.multisig_account.vault_bump],
+        ];
+        let signer_seeds = &[&vault_seeds[..]];
+
+        let vault_key = self.vault.key();
+        let ix = Instruction {
+            program_id: ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(self.lookup_table.key(), false),
+                AccountMeta::new_readonly(vault_key, true), // authority
+                AccountMeta::new(vault_key, true),          // payer
+                AccountMeta::new_readonly(self.system_program.key(), false),
+            ],
+            data,
+        };
+
+        invoke_signed(
+            &ix,
+            &[
+                self.lookup_table.to_account_info(),
+                self.vault.to_account_info(),
+                self.system_program.to_account_info(),
+                self.address_lookup_table_program.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+        Ok(())
+    }
+}
@@ -9,10 +9,13 @@ use crate::{state::*, errors::*, constants::*};
 // - RemoveMember: Remove existing member from multisig
 // - ChangeThreshold: Update approval threshold
 // - ChangeTimelock: Update timelock duration
+// - SetVaultPaysRent: Let the vault fund proposal rent
 //
 // TransferSol proposals use execute_transfer_proposal instead.
+// Vault stake proposals use execute_stake_proposal instead.
+// Token sweep proposals use execute_sweep_proposal instead.
 //
-// Closes the proposal account after successful execution (rent returned to proposer).
+// Closes the proposal account after successful execution (rent returned to its rent payer).
 
 #[derive(Accounts)]
 pub struct ExecuteProposal<'info> {
@@ -42,15 +45,15 @@ pub struct ExecuteProposal<'info> {
             &proposal.proposal_id.to_le_bytes(),
         ],
         bump = proposal.bump,
-        close = proposer,
+        has_one = rent_payer @ MultisigError::InvalidRentPayer,
+        close = rent_payer,
     )]
     pub proposal: Account<'info, Proposal>,
 
-    // Proposer - who created and paid for the proposal
-    
-    /// CHECK: Validated by has_one constraint on transfer_proposal
+    // Rent payer - whoever paid for the proposal (proposer, sponsor or vault)
+    /// CHECK: Validated by has_one constraint on proposal
     #[account(mut)]
-    pub proposer: UncheckedAccount<'info>,
+    pub rent_payer: UncheckedAccount<'info>,
 }
 
 impl<'info> ExecuteProposal<'info> {
@@ -88,10 +91,11 @@ impl<'info> ExecuteProposal<'info> {
         );
 
         // 5. Threshold Check
-        // Proposal must have required number of approvals
+        // Proposal must have the approvals the threshold policy sets for its type
         // Prevents premature execution
         require!(
-            self.proposal.approval_count >= self.multisig_account.threshold,
+            self.proposal.approval_count
+                >= self.multisig_account.threshold_for(&self.proposal.proposal_type),
             MultisigError::InsufficientApprovals
         );
 
@@ -120,7 +124,13 @@ impl<'info> ExecuteProposal<'info> {
             MultisigError::ProposalExpired
         );
 
-      
+        // 9. Proposal Hash Check
+        // The parameters must still hash to what was approved
+        // Prevents executing anything but the action the approvers signed off on
+        require!(
+            self.proposal.has_valid_hash()?,
+            MultisigError::ProposalHashMismatch
+        );
 
         // Execute based on proposal type
         match self.proposal.proposal_type {
@@ -212,6 +222,14 @@ impl<'info> ExecuteProposal<'info> {
                     self.multisig_account.is_valid_threshold(),
                     MultisigError::InvalidThreshold
                 );
+
+                // Threshold policy entries must stay within the new owner count
+                require!(
+                    self.multisig_account
+                        .threshold_policy
+                        .is_valid(self.multisig_account.owner_count),
+                    MultisigError::InvalidThresholdPolicy
+                );
             }
 
             ProposalType::ChangeThreshold { new_threshold } => {
@@ -244,13 +262,53 @@ impl<'info> ExecuteProposal<'info> {
                 // Update timelock
                 self.multisig_account.timelock_seconds = new_timelock;
             }
+
+            ProposalType::SetVaultPaysRent { enabled } => {
+                // Update who funds new proposals
+                self.multisig_account.vault_pays_rent = enabled;
+            }
+
+            ProposalType::SetThresholdPolicy { policy } => {
+                // 20. Threshold Policy Check
+                // Members may have been removed since the proposal was created
+                require!(
+                    policy.is_valid(self.multisig_account.owner_count),
+                    MultisigError::InvalidThresholdPolicy
+                );
+
+                self.multisig_account.threshold_policy = policy;
+            }
+
+            ProposalType::ApproveParentProposal { .. } => {
+                // Parent approvals only take effect through approve_as_multisig,
+                // which records the approval on the parent in the same step
+                return err!(MultisigError::InvalidProposalType);
+            }
+
+            ProposalType::DelegateVaultStake { .. }
+            | ProposalType::DeactivateVaultStake { .. }
+            | ProposalType::WithdrawVaultStake { .. } => {
+                // Stake proposals need the stake program accounts
+                return err!(MultisigError::InvalidProposalType);
+            }
+
+            ProposalType::SweepToken { .. } => {
+                // Token sweeps need the mint and token accounts
+                return err!(MultisigError::InvalidProposalType);
+            }
+
+            ProposalType::CreateLookupTable
+            | ProposalType::ExtendLookupTable { .. } => {
+                // Lookup table proposals need the lookup table program accounts
+                return err!(MultisigError::InvalidProposalType);
+            }
         }
 
-        // 20. Update last executed proposal
+        // 21. Update last executed proposal
         // Track execution history
         self.multisig_account.last_executed_proposal = self.proposal.proposal_id;
 
-        // 21. Mark Proposal as Executed
+        // 22. Mark Proposal as Executed
         // Prevents double-execution before account closure
         self.proposal.status = ProposalStatus::Executed;
 
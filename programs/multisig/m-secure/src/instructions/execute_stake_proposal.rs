@@ -0,0 +1,359 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+    sysvar::stake_history,
+};
+use anchor_lang::system_program::{create_account, CreateAccount};
+use crate::{state::*, errors::*, constants::*};
+
+// Execute Stake Proposal Instruction
+//
+// Executes an approved vault stake proposal through the native stake program:
+// - DelegateVaultStake: create ["vault_stake", multisig, proposal_id] from
+//   vault SOL, initialize it with the vault as staker and withdrawer, and
+//   delegate it to the proposal's vote account
+// - DeactivateVaultStake: deactivate a vault stake account
+// - WithdrawVaultStake: move a deactivated stake account's balance to the vault
+//
+// The vault PDA is the stake and withdraw authority, so only proposals that
+// pass this multisig's approvals and timelock can move the stake, and
+// withdrawals can only land in the vault.
+//
+// Closes the proposal account after successful execution (rent returned to its rent payer).
+
+#[derive(Accounts)]
+pub struct ExecuteStakeProposal<'info> {
+    // Executor - must be Admin or Executor role
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    // Multisig account
+    #[account(
+        mut,
+        seeds = [
+            MULTISIG,
+            multisig_account.creator.as_ref(),
+            &multisig_account.multisig_id.to_le_bytes(),
+        ],
+        bump = multisig_account.bump,
+    )]
+    pub multisig_account: Account<'info, Multisig>,
+
+    // Proposal being executed
+    #[account(
+        mut,
+        seeds = [
+            PROPOSAL,
+            multisig_account.key().as_ref(),
+            &proposal.proposal_id.to_le_bytes(),
+        ],
+        bump = proposal.bump,
+        has_one = rent_payer @ MultisigError::InvalidRentPayer,
+        close = rent_payer,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    // Rent payer - whoever paid for the proposal (proposer, sponsor or vault)
+    /// CHECK: Validated by has_one constraint on proposal
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    // Vault PDA - funds new stake, holds both stake authorities
+    #[account(
+        mut,
+        seeds = [
+            VAULT,
+            multisig_account.key().as_ref(),
+        ],
+        bump = multisig_account.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    // Stake account the proposal acts on
+    /// CHECK: Matched against the proposal in the handler; the stake program
+    /// checks its state and that the vault is its authority
+    #[account(mut)]
+    pub stake_account: UncheckedAccount<'info>,
+
+    // Validator vote account - DelegateVaultStake only
+    /// CHECK: Matched against the proposal; validated by the stake program
+    pub vote_account: Option<UncheckedAccount<'info>>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    pub rent: Sysvar<'info, Rent>,
+
+    /// CHECK: Stake history sysvar, pinned by address
+    #[account(address = stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+
+    /// CHECK: Stake config account, pinned by address
+    #[account(address = STAKE_CONFIG_ID)]
+    pub stake_config: UncheckedAccount<'info>,
+
+    /// CHECK: Native stake program, pinned by address
+    #[account(address = STAKE_PROGRAM_ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExecuteStakeProposal<'info> {
+    pub fn execute_stake_proposal(&mut self) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Pause Check
+        require!(
+            !self.multisig_account.paused,
+            MultisigError::MultisigPaused
+        );
+
+        // 2. Executor Permission Check
+        // Only Admin or Executor can execute proposals
+        require!(
+            self.multisig_account.can_execute(&self.executor.key()),
+            MultisigError::CannotExecute
+        );
+
+        // 3. Proposal Status Check
+        require!(
+            self.proposal.is_active(),
+            MultisigError::ProposalNotActive
+        );
+
+        // 4. Threshold Check
+        require!(
+            self.proposal.approval_count
+                >= self.multisig_account.threshold_for(&self.proposal.proposal_type),
+            MultisigError::InsufficientApprovals
+        );
+
+        // 5. Timelock Check
+        let clock = Clock::get()?;
+        require!(
+            self.proposal.timelock_passed(clock.unix_timestamp, self.multisig_account.timelock_seconds),
+            MultisigError::TimelockNotPassed
+        );
+
+        // 6. Expiry Check
+        require!(
+            !self.proposal.is_expired(clock.unix_timestamp),
+            MultisigError::ProposalExpired
+        );
+
+        // 7. Proposal Hash Check
+        // The parameters must still hash to what was approved
+        require!(
+            self.proposal.has_valid_hash()?,
+            MultisigError::ProposalHashMismatch
+        );
+
+        // Execute based on proposal type
+        match self.proposal.proposal_type {
+            ProposalType::DelegateVaultStake { vote_account, amount } => {
+                self.delegate_vault_stake(vote_account, amount)?;
+            }
+
+            ProposalType::DeactivateVaultStake { stake_account } => {
+                // 8. Stake Account Check
+                require!(
+                    self.stake_account.key() == stake_account,
+                    MultisigError::InvalidStakeAccount
+                );
+
+                self.invoke_stake(
+                    STAKE_IX_DEACTIVATE.to_le_bytes().to_vec(),
+                    vec![
+                        AccountMeta::new(stake_account, false),
+                        AccountMeta::new_readonly(self.clock.key(), false),
+                        AccountMeta::new_readonly(self.vault.key(), true),
+                    ],
+                    &[
+                        self.stake_account.to_account_info(),
+                        self.clock.to_account_info(),
+                        self.vault.to_account_info(),
+                    ],
+                )?;
+            }
+
+            ProposalType::WithdrawVaultStake { stake_account } => {
+                // 8. Stake Account Check
+                require!(
+                    self.stake_account.key() == stake_account,
+                    MultisigError::InvalidStakeAccount
+                );
+
+                // The full balance is only withdrawable once the stake has
+                // cooled down; the stake program enforces that
+                // The vault is both the recipient and the withdraw authority
+                let mut data = STAKE_IX_WITHDRAW.to_le_bytes().to_vec();
+                data.extend_from_slice(&self.stake_account.lamports().to_le_bytes());
+
+                self.invoke_stake(
+                    data,
+                    vec![
+                        AccountMeta::new(stake_account, false),
+                        AccountMeta::new(self.vault.key(), true),
+                        AccountMeta::new_readonly(self.clock.key(), false),
+                        AccountMeta::new_readonly(self.stake_history.key(), false),
+                        AccountMeta::new_readonly(self.vault.key(), true),
+                    ],
+                    &[
+                        self.stake_account.to_account_info(),
+                        self.vault.to_account_info(),
+                        self.clock.to_account_info(),
+                        self.stake_history.to_account_info(),
+                    ],
+                )?;
+            }
+
+            _ => return err!(MultisigError::InvalidProposalType),
+        }
+
+        // Update multisig state
+        self.multisig_account.last_executed_proposal = self.proposal.proposal_id;
+
+        // Mark Proposal as Executed
+        self.proposal.status = ProposalStatus::Executed;
+        self.proposal.executed_at = clock.unix_timestamp;
+
+        Ok(())
+    }
+
+    fn delegate_vault_stake(&self, vote_account: Pubkey, amount: u64) -> Result<()> {
+        let multisig_key = self.multisig_account.key();
+        let proposal_id = self.proposal.proposal_id.to_le_bytes();
+
+        // 8. Stake Account Derivation
+        // One fresh stake account per proposal; create_account fails if it exists
+        let (expected_stake, stake_bump) = Pubkey::find_program_address(
+            &[VAULT_STAKE, multisig_key.as_ref(), &proposal_id],
+            &crate::ID,
+        );
+        require!(
+            self.stake_account.key() == expected_stake,
+            MultisigError::InvalidStakeAccount
+        );
+
+        // 9. Vote Account Check
+        let vote = self
+            .vote_account
+            .as_ref()
+            .ok_or(MultisigError::InvalidVoteAccount)?;
+        require!(
+            vote.key() == vote_account,
+            MultisigError::InvalidVoteAccount
+        );
+
+        // 10. Vault Balance Check
+        // The stake account holds its own rent reserve on top of the stake
+        let lamports = self
+            .rent
+            .minimum_balance(STAKE_ACCOUNT_SPACE)
+            .checked_add(amount)
+            .ok_or(MultisigError::Overflow)?;
+        require!(
+            self.vault.lamports() >= lamports,
+            MultisigError::InsufficientFunds
+        );
+
+        // Create the stake account, funded by the vault
+        let vault_seeds = &[
+            VAULT,
+            multisig_key.as_ref(),
+            &[self.multisig_account.vault_bump],
+        ];
+        let stake_seeds = &[
+            VAULT_STAKE,
+            multisig_key.as_ref(),
+            &proposal_id,
+            &[stake_bump],
+        ];
+        let signer_seeds = &[&vault_seeds[..], &stake_seeds[..]];
+
+        create_account(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                CreateAccount {
+                    from: self.vault.to_account_info(),
+                    to: self.stake_account.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            lamports,
+            STAKE_ACCOUNT_SPACE as u64,
+            &STAKE_PROGRAM_ID,
+        )?;
+
+        // Initialize: vault is staker and withdrawer, no lockup
+        let vault_key = self.vault.key();
+        let mut data = STAKE_IX_INITIALIZE.to_le_bytes().to_vec();
+        data.extend_from_slice(vault_key.as_ref()); // staker
+        data.extend_from_slice(vault_key.as_ref()); // withdrawer
+        data.extend_from_slice(&0i64.to_le_bytes()); // lockup unix_timestamp
+        data.extend_from_slice(&0u64.to_le_bytes()); // lockup epoch
+        data.extend_from_slice(Pubkey::default().as_ref()); // lockup custodian
+
+        self.invoke_stake(
+            data,
+            vec![
+                AccountMeta::new(expected_stake, false),
+                AccountMeta::new_readonly(self.rent.key(), false),
+            ],
+            &[
+                self.stake_account.to_account_info(),
+                self.rent.to_account_info(),
+            ],
+        )?;
+
+        // Delegate to the validator
+        self.invoke_stake(
+            STAKE_IX_DELEGATE.to_le_bytes().to_vec(),
+            vec![
+                AccountMeta::new(expected_stake, false),
+                AccountMeta::new_readonly(vote_account, false),
+                AccountMeta::new_readonly(self.clock.key(), false),
+                AccountMeta::new_readonly(self.stake_history.key(), false),
+                AccountMeta::new_readonly(self.stake_config.key(), false),
+                AccountMeta::new_readonly(vault_key, true),
+            ],
+            &[
+                self.stake_account.to_account_info(),
+                vote.to_account_info(),
+                self.clock.to_account_info(),
+                self.stake_history.to_account_info(),
+                self.stake_config.to_account_info(),
+                self.vault.to_account_info(),
+            ],
+        )
+    }
+
+    // CPI into the stake program with the vault PDA as signing authority
+    fn invoke_stake(
+        &self,
+        data: Vec<u8>,
+        accounts: Vec<AccountMeta>,
+        account_infos: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let multisig_key = self.multisig_account.key();
+        let vault_seeds = &[
+            VAULT,
+            multisig_key.as_ref(),
+            &[self.multisig_account.vault_bump],
+        ];
+        let signer_seeds = &[&vault_seeds[..]];
+
+        let ix = Instruction {
+            program_id: STAKE_PROGRAM_ID,
+            accounts,
+            data,
+        };
+
+        let mut infos = account_infos.to_vec();
+        infos.push(self.stake_program.to_account_info());
+
+        invoke_signed(&ix, &infos, signer_seeds)?;
+        Ok(())
+    }
+}
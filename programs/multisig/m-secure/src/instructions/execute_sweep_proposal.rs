@@ -0,0 +1,216 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use soteria_common::ata;
+use crate::{state::*, errors::*, constants::*};
+
+// Execute Sweep Proposal Instruction
+//
+// Executes an approved SweepToken proposal: the vault's full balance of the
+// proposal's mint moves from the vault's associated token account to the
+// recipient's associated token account.
+//
+// The vault PDA is a system account and never creates token accounts itself,
+// so SPL tokens only reach it when someone sends them to its ATA. Without a
+// sweep they could not be moved again.
+//
+// Works for SPL Token and Token-2022 mints. The recipient's ATA must already exist.
+//
+// Closes the proposal account after successful execution (rent returned to its rent payer).
+
+#[derive(Accounts)]
+pub struct ExecuteSweepProposal<'info> {
+    // Executor - must be Admin or Executor role
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    // Multisig account
+    #[account(
+        mut,
+        seeds = [
+            MULTISIG,
+            multisig_account.creator.as_ref(),
+            &multisig_account.multisig_id.to_le_bytes(),
+        ],
+        bump = multisig_account.bump,
+    )]
+    pub multisig_account: Account<'info, Multisig>,
+
+    // Proposal being executed
+    #[account(
+        mut,
+        seeds = [
+            PROPOSAL,
+            multisig_account.key().as_ref(),
+            &proposal.proposal_id.to_le_bytes(),
+        ],
+        bump = proposal.bump,
+        has_one = rent_payer @ MultisigError::InvalidRentPayer,
+        close = rent_payer,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    // Rent payer - whoever paid for the proposal (proposer, sponsor or vault)
+    /// CHECK: Validated by has_one constraint on proposal
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    // Vault PDA - owner of the swept token account, signs the transfer
+    #[account(
+        seeds = [
+            VAULT,
+            multisig_account.key().as_ref(),
+        ],
+        bump = multisig_account.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    // Mint being swept - matched against the proposal in the handler
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    // Vault's associated token account for the mint
+    // Derived here, so only the vault's own ATA can be drained
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    // Recipient wallet - matched against the proposal in the handler
+    /// CHECK: Only compared with the proposal's recipient
+    pub recipient: UncheckedAccount<'info>,
+
+    // Recipient's associated token account for the mint
+    // Re-derived from the proposal's recipient in the handler, so the executor
+    // cannot redirect the tokens to an account of their own
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ExecuteSweepProposal<'info> {
+    pub fn execute_sweep_proposal(&mut self) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Pause Check
+        require!(
+            !self.multisig_account.paused,
+            MultisigError::MultisigPaused
+        );
+
+        // 2. Executor Permission Check
+        // Only Admin or Executor can execute proposals
+        require!(
+            self.multisig_account.can_execute(&self.executor.key()),
+            MultisigError::CannotExecute
+        );
+
+        // 3. Proposal Status Check
+        require!(
+            self.proposal.is_active(),
+            MultisigError::ProposalNotActive
+        );
+
+        // 4. Threshold Check
+        require!(
+            self.proposal.approval_count
+                >= self.multisig_account.threshold_for(&self.proposal.proposal_type),
+            MultisigError::InsufficientApprovals
+        );
+
+        // 5. Timelock Check
+        let clock = Clock::get()?;
+        require!(
+            self.proposal.timelock_passed(clock.unix_timestamp, self.multisig_account.timelock_seconds),
+            MultisigError::TimelockNotPassed
+        );
+
+        // 6. Expiry Check
+        require!(
+            !self.proposal.is_expired(clock.unix_timestamp),
+            MultisigError::ProposalExpired
+        );
+
+        // 7. Proposal Hash Check
+        // The parameters must still hash to what was approved
+        require!(
+            self.proposal.has_valid_hash()?,
+            MultisigError::ProposalHashMismatch
+        );
+
+        let ProposalType::SweepToken { mint, recipient } = self.proposal.proposal_type else {
+            return err!(MultisigError::InvalidProposalType);
+        };
+
+        // 8. Mint Check
+        // The approved mint, not whichever token the executor picks
+        require!(
+            self.mint.key() == mint,
+            MultisigError::InvalidMint
+        );
+
+        // 9. Recipient Check
+        // The destination must be the approved recipient's ATA for this mint
+        require!(
+            self.recipient.key() == recipient,
+            MultisigError::InvalidRecipient
+        );
+        require!(
+            ata::is_associated_token_address(
+                &self.recipient_token_account.key(),
+                &recipient,
+                &mint,
+                &self.token_program.key(),
+            ),
+            MultisigError::InvalidRecipient
+        );
+
+        // 10. Balance Check
+        let amount = self.vault_token_account.amount;
+        require!(amount > 0, MultisigError::InsufficientFunds);
+
+        // Transfer the full balance, signed by the vault PDA
+        let multisig_key = self.multisig_account.key();
+        let vault_seeds = &[
+            VAULT,
+            multisig_key.as_ref(),
+            &[self.multisig_account.vault_bump],
+        ];
+        let signer_seeds = &[&vault_seeds[..]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.vault_token_account.to_account_info(),
+                    mint: self.mint.to_account_info(),
+                    to: self.recipient_token_account.to_account_info(),
+                    authority: self.vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            self.mint.decimals,
+        )?;
+
+        msg!("Swept {} tokens of mint {} to {}", amount, mint, recipient);
+
+        // Update multisig state
+        self.multisig_account.last_executed_proposal = self.proposal.proposal_id;
+
+        // Mark Proposal as Executed
+        self.proposal.status = ProposalStatus::Executed;
+        self.proposal.executed_at = clock.unix_timestamp;
+
+        Ok(())
+    }
+}
@@ -14,8 +14,9 @@ use crate::{state::*, errors::*, constants::*};
 // 4. Timelock passed
 // 5. Not expired
 // 6. TransferProposal matches Proposal
-// 7. Recipient validation (writable, system-owned)
+// 7. Recipient validation (writable, system-owned or the pinned program vault owner)
 // 8. Vault has sufficient balance
+// 9. Optional expected outcome: vault and recipient deltas match after the transfer
 
 #[derive(Accounts)]
 pub struct ExecuteTransferProposal<'info> {
@@ -37,7 +38,7 @@ pub struct ExecuteTransferProposal<'info> {
 
 
     // Transfer Proposal PDA - linked to base proposal
-    // Security: Rent refunded to proposer (who paid to create it)
+    // Security: Rent refunded to whoever paid to create it
     #[account(
         mut,
         seeds = [
@@ -46,16 +47,16 @@ pub struct ExecuteTransferProposal<'info> {
             &transfer_proposal.proposal_id.to_le_bytes(),
         ],
         bump = transfer_proposal.bump,
-        has_one = proposer @ MultisigError::NotProposer,
-        close = proposer,
+        has_one = rent_payer @ MultisigError::InvalidRentPayer,
+        close = rent_payer,
     )]
     pub transfer_proposal: Account<'info, TransferProposal>,
 
-    // Proposer - who created and paid for the proposal
+    // Rent payer - who paid for the proposal (proposer, sponsor or vault)
     // Security: Receives rent refund when proposal is closed
     /// CHECK: Validated by has_one constraint on transfer_proposal
     #[account(mut)]
-    pub proposer: UncheckedAccount<'info>,
+    pub rent_payer: UncheckedAccount<'info>,
 
     // Vault PDA (holds the SOL)
     #[account(
@@ -70,7 +71,7 @@ pub struct ExecuteTransferProposal<'info> {
 
     // Recipient of funds - UncheckedAccount with manual validation
     /// CHECK: This account is validated manually in the instruction logic
-    /// We verify it is writable and system-owned before transfer
+    /// We verify it is writable and matches the proposal's RecipientKind before transfer
     #[account(mut)]
     pub recipient: UncheckedAccount<'info>,
 
@@ -78,7 +79,10 @@ pub struct ExecuteTransferProposal<'info> {
 }
 
 impl<'info> ExecuteTransferProposal<'info> {
-    pub fn execute_transfer_proposal(&mut self) -> Result<()> {
+    pub fn execute_transfer_proposal(
+        &mut self,
+        expected_outcome: Option<ExpectedOutcome>,
+    ) -> Result<()> {
         // SECURITY CHECKS
 
         // 1. Pause Check
@@ -103,8 +107,10 @@ impl<'info> ExecuteTransferProposal<'info> {
        
 
         // 5. Threshold Check
+        // The threshold policy can set a lower bar for small transfers
         require!(
-            self.transfer_proposal.approval_count >= self.multisig_account.threshold,
+            self.transfer_proposal.approval_count
+                >= self.multisig_account.transfer_threshold(self.transfer_proposal.amount),
             MultisigError::InsufficientApprovals
         );
 
@@ -123,9 +129,10 @@ impl<'info> ExecuteTransferProposal<'info> {
 
         // 8. Recipient Validation
         // Ensure recipient is writable (already checked by #[account(mut)])
-        // Ensure recipient is system-owned to prevent sending to PDAs without proper handling
+        // Wallet: recipient must be system-owned to prevent sending to PDAs without proper handling
+        // ProgramVault: recipient must still be owned by the program the signers approved
         require!(
-            self.recipient.owner == &anchor_lang::system_program::ID,
+            self.transfer_proposal.recipient_kind.accepts(&self.recipient),
             MultisigError::InvalidRecipient
         );
 
@@ -160,8 +167,24 @@ impl<'info> ExecuteTransferProposal<'info> {
             signer_seeds,
         );
 
+        let recipient_balance = self.recipient.lamports();
+
         transfer(cpi_context, self.transfer_proposal.amount)?;
 
+        // 11. Expected Outcome Check
+        // Balances are re-read after the CPI; the vault may only shrink and the
+        // recipient may only grow, by exactly the amounts the executor expects
+        if let Some(expected) = expected_outcome {
+            let vault_delta = vault_balance.checked_sub(self.vault.lamports());
+            let recipient_delta = self.recipient.lamports().checked_sub(recipient_balance);
+
+            require!(
+                vault_delta == Some(expected.vault_delta)
+                    && recipient_delta == Some(expected.recipient_delta),
+                MultisigError::OutcomeMismatch
+            );
+        }
+
         // Update proposal state
         self.transfer_proposal.status = ProposalStatus::Executed;
         self.transfer_proposal.executed_at = clock.unix_timestamp;
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::{state::*, errors::*, constants::*};
+
+// Grow Large Multisig Instruction
+//
+// Adds LARGE_MEMBERS_GROWTH_STEP zeroed slots to the member slab
+// The admin tops up rent for the new size, then the account is resized
+//
+// SECURITY FEATURES:
+// - Admin (creator) only
+// - Capacity never exceeds MAX_LARGE_MEMBERS
+// - New slots are zeroed by the runtime, so they never hold stale members
+
+#[derive(Accounts)]
+pub struct GrowMultisigLarge<'info> {
+    // Admin - must be the creator
+    // Pays the rent for the extra slots
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    // Large multisig PDA
+    // Seeds: ["multisig_large", creator, multisig_id]
+    #[account(
+        mut,
+        seeds = [
+            MULTISIG_LARGE,
+            multisig_large.load()?.creator.as_ref(),
+            &multisig_large.load()?.multisig_id.to_le_bytes(),
+        ],
+        bump = multisig_large.load()?.bump,
+    )]
+    pub multisig_large: AccountLoader<'info, MultisigLarge>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> GrowMultisigLarge<'info> {
+    pub fn grow_multisig_large(&mut self) -> Result<()> {
+        let (is_admin, capacity) = {
+            let multisig = self.multisig_large.load()?;
+            (
+                multisig.is_admin(&self.admin.key()),
+                multisig.member_capacity as usize,
+            )
+        };
+
+        // SECURITY CHECKS
+
+        // 1. Admin Check
+        require!(is_admin, MultisigError::OnlyAdmin);
+
+        // 2. Capacity Bound
+        let new_capacity = capacity
+            .checked_add(LARGE_MEMBERS_GROWTH_STEP)
+            .ok_or(MultisigError::Overflow)?;
+        require!(
+            new_capacity <= MAX_LARGE_MEMBERS,
+            MultisigError::MaxMembersReached
+        );
+
+        // 3. Rent Top-Up
+        let info = self.multisig_large.to_account_info();
+        let new_space = MultisigLarge::space(new_capacity);
+        let rent_due = Rent::get()?
+            .minimum_balance(new_space)
+            .saturating_sub(info.lamports());
+
+        if rent_due > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.admin.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+
+        // 4. Resize and record the new capacity
+        info.resize(new_space)?;
+        self.multisig_large.load_mut()?.member_capacity = new_capacity as u16;
+
+        Ok(())
+    }
+}
@@ -4,33 +4,63 @@
 // - create_transfer_proposal
 // - approve_proposal (governance only)
 // - approve_transfer_proposal (transfers only)
+// - approve_as_multisig (child multisig approves a parent proposal)
 // - execute_proposal (governance only)
 // - execute_transfer_proposal (transfers only)
+// - execute_stake_proposal (vault stake only)
+// - execute_sweep_proposal (token sweeps only)
+// - execute_lookup_table_proposal (address lookup tables only)
 // - cancel_proposal
+// - attach_memo (proposer only)
 // - toggle_pause (admin only)
 // - add_member (via proposal)
 // - remove_member (via proposal)
 // - change_threshold (via proposal)
 // - change_timelock (via proposal)
+// - create_multisig_large (zero-copy, large rosters)
+// - grow_multisig_large (admin only)
+// - add_large_member (admin + co-signers)
+// - toggle_pause_large (admin only)
+// - validate_proposal (read-only dry run of execute_transfer_proposal)
 
+pub mod add_large_member;
+pub mod attach_memo;
+pub mod approve_as_multisig;
 pub mod approve_proposal;
 pub mod approve_transfer_proposal;
 pub mod cancel_proposal;
 pub mod cancel_transfer_proposal;
 pub mod create_multisig;
+pub mod create_multisig_large;
 pub mod create_proposal;
 pub mod create_transfer_proposal;
+pub mod execute_lookup_table_proposal;
 pub mod execute_proposal;
+pub mod execute_stake_proposal;
+pub mod execute_sweep_proposal;
 pub mod execute_transfer_proposal;
+pub mod grow_multisig_large;
 pub mod toggle_pause;
+pub mod toggle_pause_large;
+pub mod validate_proposal;
 
+pub use add_large_member::*;
+pub use attach_memo::*;
+pub use approve_as_multisig::*;
 pub use approve_proposal::*;
 pub use approve_transfer_proposal::*;
 pub use cancel_proposal::*;
 pub use cancel_transfer_proposal::*;
 pub use create_multisig::*;
+pub use create_multisig_large::*;
 pub use create_proposal::*;
 pub use create_transfer_proposal::*;
+pub use execute_lookup_table_proposal::*;
 pub use execute_proposal::*;
+pub use execute_stake_proposal::*;
+pub use execute_sweep_proposal::*;
 pub use execute_transfer_proposal::*;
-pub use toggle_pause::*;  
+pub use grow_multisig_large::*;
+pub use toggle_pause::*;
+pub use toggle_pause_large::*;
+pub use validate_proposal::*;
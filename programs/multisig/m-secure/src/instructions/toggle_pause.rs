@@ -32,6 +32,7 @@ impl<'info> TogglePause<'info> {
 
         // 1. Admin Check
         // Only the creator (admin) can pause/unpause
+        #[cfg(not(feature = "vuln-missing-admin-check"))]
         require!(
             self.multisig_account.is_admin(&self.admin.key()),
             MultisigError::OnlyAdmin
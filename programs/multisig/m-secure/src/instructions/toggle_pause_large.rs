@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::*, constants::*};
+
+// Toggle Pause Large Instruction
+//
+// Same emergency brake as toggle_pause, for MultisigLarge
+// Only the header is loaded, so the cost does not depend on the slab size
+
+#[derive(Accounts)]
+pub struct TogglePauseLarge<'info> {
+    // Admin - must be the creator
+    pub admin: Signer<'info>,
+
+    // Large multisig to pause/unpause
+    #[account(
+        mut,
+        seeds = [
+            MULTISIG_LARGE,
+            multisig_large.load()?.creator.as_ref(),
+            &multisig_large.load()?.multisig_id.to_le_bytes(),
+        ],
+        bump = multisig_large.load()?.bump,
+    )]
+    pub multisig_large: AccountLoader<'info, MultisigLarge>,
+}
+
+impl<'info> TogglePauseLarge<'info> {
+    pub fn toggle_pause_large(&mut self) -> Result<()> {
+        let mut multisig = self.multisig_large.load_mut()?;
+
+        // SECURITY CHECKS
+
+        // 1. Admin Check
+        require!(
+            multisig.is_admin(&self.admin.key()),
+            MultisigError::OnlyAdmin
+        );
+
+        // Toggle pause state
+        multisig.paused ^= 1;
+
+        Ok(())
+    }
+}
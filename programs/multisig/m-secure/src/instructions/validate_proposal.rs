@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::*, constants::*};
+
+// Validate Proposal Instruction
+//
+// Read-only dry run of execute_transfer_proposal for front-ends
+// Runs every execution-time check against the current clock and balances and
+// returns the failing MultisigError codes instead of aborting on the first one
+//
+// Checks, in the order execute_transfer_proposal applies them:
+// 1. Pause check
+// 2. Executor permission
+// 3. Proposal is Active
+// 4. Threshold reached
+// 5. Timelock passed
+// 6. Not expired
+// 7. Recipient accepted by the proposal's RecipientKind
+// 8. Recipient matches the proposal
+// 9. Vault has sufficient balance
+//
+// No account is writable and no signer is required, so the result can be read
+// from a simulated transaction without paying for or landing anything
+
+#[derive(Accounts)]
+pub struct ValidateProposal<'info> {
+    // Key that would execute the proposal
+    /// CHECK: Only compared against the member roster, never signs
+    pub executor: UncheckedAccount<'info>,
+
+    // Multisig account
+    #[account(
+        seeds = [
+            MULTISIG,
+            multisig_account.creator.as_ref(),
+            &multisig_account.multisig_id.to_le_bytes(),
+        ],
+        bump = multisig_account.bump,
+    )]
+    pub multisig_account: Account<'info, Multisig>,
+
+    // Transfer Proposal PDA
+    #[account(
+        seeds = [
+            TRANSFER_PROPOSAL,
+            multisig_account.key().as_ref(),
+            &transfer_proposal.proposal_id.to_le_bytes(),
+        ],
+        bump = transfer_proposal.bump,
+    )]
+    pub transfer_proposal: Account<'info, TransferProposal>,
+
+    // Vault PDA (holds the SOL)
+    #[account(
+        seeds = [
+            VAULT,
+            multisig_account.key().as_ref(),
+        ],
+        bump = multisig_account.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    // Recipient the executor would pass
+    /// CHECK: Validated the same way as in execute_transfer_proposal, result reported
+    pub recipient: UncheckedAccount<'info>,
+}
+
+impl<'info> ValidateProposal<'info> {
+    pub fn validate_proposal(&self) -> Result<ProposalValidation> {
+        let multisig = &self.multisig_account;
+        let proposal = &self.transfer_proposal;
+        let now = Clock::get()?.unix_timestamp;
+
+        let checks = [
+            (!multisig.paused, MultisigError::MultisigPaused),
+            (
+                multisig.can_execute(&self.executor.key()),
+                MultisigError::CannotExecute,
+            ),
+            (
+                proposal.status == ProposalStatus::Active,
+                MultisigError::ProposalNotActive,
+            ),
+            (
+                proposal.approval_count >= multisig.transfer_threshold(proposal.amount),
+                MultisigError::InsufficientApprovals,
+            ),
+            (
+                proposal.timelock_passed(now, multisig.timelock_seconds),
+                MultisigError::TimelockNotPassed,
+            ),
+            (!proposal.is_expired(now), MultisigError::ProposalExpired),
+            (
+                proposal.recipient_kind.accepts(&self.recipient),
+                MultisigError::InvalidRecipient,
+            ),
+            (
+                proposal.recipient == self.recipient.key(),
+                MultisigError::InvalidRecipient,
+            ),
+            (
+                self.vault.lamports() >= proposal.amount,
+                MultisigError::InsufficientFunds,
+            ),
+        ];
+
+        // Same code twice (both recipient checks) is reported once
+        let mut errors: Vec<u32> = Vec::with_capacity(checks.len());
+        for (passed, error) in checks {
+            let code = u32::from(error);
+            if !passed && !errors.contains(&code) {
+                errors.push(code);
+            }
+        }
+
+        msg!(
+            "Proposal {} validation: {} failing check(s)",
+            proposal.proposal_id,
+            errors.len()
+        );
+
+        Ok(ProposalValidation { errors })
+    }
+}
@@ -3,6 +3,7 @@ pub mod instructions;
 pub mod errors;
 pub mod state;
 pub mod constants;
+pub mod helpers;
 
 pub use instructions::*;
 pub use errors::*;
@@ -42,12 +43,14 @@ pub mod secure {
     // Only Admin or Proposer roles can create proposals
     // Proposer automatically approves their own proposal
     // Creates both base Proposal and linked TransferProposal accounts
+    // recipient_kind: Wallet, or ProgramVault to pay a program-owned account
     pub fn create_transfer_proposal(
         ctx: Context<CreateTransferProposal>,
         amount: u64,
         recipient: Pubkey,
+        recipient_kind: RecipientKind,
     ) -> Result<()> {
-        ctx.accounts.create_transfer_proposal(amount, recipient, &ctx.bumps)
+        ctx.accounts.create_transfer_proposal(amount, recipient, recipient_kind, &ctx.bumps)
     }
 
     // Approve an existing governance proposal
@@ -62,6 +65,13 @@ pub mod secure {
         ctx.accounts.approve_transfer_proposal()
     }
 
+    // Approve a parent multisig proposal as a child multisig member
+    // Executes the child's ApproveParentProposal proposal in the same step
+    // The child multisig is a PDA, so its approval needs no signer
+    pub fn approve_as_multisig(ctx: Context<ApproveAsMultisig>) -> Result<()> {
+        ctx.accounts.approve_as_multisig()
+    }
+
     // Execute an approved governance proposal once threshold is reached
     // Handles AddMember, RemoveMember, ChangeThreshold, ChangeTimelock
     // For TransferSol: use execute_transfer_proposal instead
@@ -72,8 +82,44 @@ pub mod secure {
     // Execute an approved transfer proposal once threshold is reached
     // Uses UncheckedAccount for recipient with manual validation
     // Recipient must be writable and system-owned
-    pub fn execute_transfer_proposal(ctx: Context<ExecuteTransferProposal>) -> Result<()> {
-        ctx.accounts.execute_transfer_proposal()
+    // expected_outcome optionally asserts the vault and recipient balance deltas
+    pub fn execute_transfer_proposal(
+        ctx: Context<ExecuteTransferProposal>,
+        expected_outcome: Option<ExpectedOutcome>,
+    ) -> Result<()> {
+        ctx.accounts.execute_transfer_proposal(expected_outcome)
+    }
+
+    // Dry-run execute_transfer_proposal without mutating state
+    // Runs every execution-time check and returns the failing error codes
+    // No signer or writable accounts; meant to be simulated by front-ends
+    pub fn validate_proposal(ctx: Context<ValidateProposal>) -> Result<ProposalValidation> {
+        ctx.accounts.validate_proposal()
+    }
+
+    // Execute an approved vault stake proposal once threshold is reached
+    // Handles DelegateVaultStake, DeactivateVaultStake, WithdrawVaultStake
+    // The vault PDA signs the stake program CPIs as staker and withdrawer
+    pub fn execute_stake_proposal(ctx: Context<ExecuteStakeProposal>) -> Result<()> {
+        ctx.accounts.execute_stake_proposal()
+    }
+
+    // Execute an approved SweepToken proposal once threshold is reached
+    // Moves the vault ATA's full balance of the mint to the recipient's ATA
+    // Both token accounts are derived from the proposal's mint and recipient
+    pub fn execute_sweep_proposal(ctx: Context<ExecuteSweepProposal>) -> Result<()> {
+        ctx.accounts.execute_sweep_proposal()
+    }
+
+    // Execute an approved CreateLookupTable or ExtendLookupTable proposal
+    // The vault PDA signs the lookup table program CPIs as authority and payer
+    // recent_slot is used by CreateLookupTable, addresses by ExtendLookupTable
+    pub fn execute_lookup_table_proposal(
+        ctx: Context<ExecuteLookupTableProposal>,
+        recent_slot: u64,
+        addresses: Vec<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.execute_lookup_table_proposal(recent_slot, addresses)
     }
 
     // Cancel an active proposal
@@ -82,11 +128,57 @@ pub mod secure {
         ctx.accounts.cancel_proposal()
     }
 
+    // Set the title and append to the memo of an active proposal
+    // Only the proposer; proposer pays the rent for the extra bytes
+    // A None title keeps the current one
+    pub fn attach_memo(
+        ctx: Context<AttachMemo>,
+        title: Option<String>,
+        memo: String,
+    ) -> Result<()> {
+        ctx.accounts.attach_memo(title, memo)
+    }
+
     // Toggle pause state on the multisig
     // Only admin (creator) can pause/unpause
     // Emergency brake for security incidents
     pub fn toggle_pause(ctx: Context<TogglePause>) -> Result<()> {
         ctx.accounts.toggle_pause()
     }
+
+    // Initialize a zero-copy multisig for large rosters
+    // Creator becomes member 0 (Admin) with threshold 1
+    // Member slab starts at LARGE_MEMBERS_INITIAL_CAPACITY slots
+    pub fn create_multisig_large(
+        ctx: Context<CreateMultisigLarge>,
+        multisig_id: u64,
+        timelock_seconds: u64,
+    ) -> Result<()> {
+        ctx.accounts.create_multisig_large(multisig_id, timelock_seconds, &ctx.bumps)
+    }
+
+    // Grow the member slab by LARGE_MEMBERS_GROWTH_STEP slots
+    // Only admin (creator); admin pays the extra rent
+    pub fn grow_multisig_large(ctx: Context<GrowMultisigLarge>) -> Result<()> {
+        ctx.accounts.grow_multisig_large()
+    }
+
+    // Add a member to a large multisig and set the new threshold
+    // Needs threshold member signatures: admin plus co-signers in remaining accounts
+    pub fn add_large_member(
+        ctx: Context<AddLargeMember>,
+        new_member: Pubkey,
+        role: MemberRole,
+        new_threshold: u8,
+    ) -> Result<()> {
+        ctx.accounts
+            .add_large_member(new_member, role, new_threshold, ctx.remaining_accounts)
+    }
+
+    // Toggle pause state on a large multisig
+    // Only admin (creator) can pause/unpause
+    pub fn toggle_pause_large(ctx: Context<TogglePauseLarge>) -> Result<()> {
+        ctx.accounts.toggle_pause_large()
+    }
 }
 
@@ -7,3 +7,7 @@ pub use proposal::*;
 pub use member::*;
 pub mod transfer_proposal;
 pub use transfer_proposal::*;
+pub mod multisig_large;
+pub use multisig_large::*;
+pub mod threshold_policy;
+pub use threshold_policy::*;
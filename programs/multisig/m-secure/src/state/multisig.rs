@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::{
     state::{
-    Member, MemberRole
+    Member, MemberRole, ProposalType, ThresholdPolicy
     }, 
     constants::*
 };
@@ -19,6 +19,7 @@ pub struct Multisig {
 
     // Number of approvals required to execute a proposal
     // Must be: 1 <= threshold <= owners.len()
+    // Default for any action threshold_policy leaves unset
     pub threshold: u8,
 
     // Current number of active members
@@ -55,6 +56,15 @@ pub struct Multisig {
     // PDA bump seed for vault account
     // Used for vault PDA signing when executing proposals
     pub vault_bump: u8,
+
+    // When true, proposers may pass the vault as rent_payer
+    // The vault then reimburses proposal rent and is refunded on close
+    // Only changeable through a SetVaultPaysRent proposal
+    pub vault_pays_rent: bool,
+
+    // Per-action thresholds overriding `threshold` (see ThresholdPolicy)
+    // Only changeable through a SetThresholdPolicy proposal
+    pub threshold_policy: ThresholdPolicy,
 }
 
 impl Multisig {
@@ -117,4 +127,19 @@ impl Multisig {
     pub fn is_valid_threshold(&self) -> bool {
         self.threshold >= 1 && self.threshold <= self.owner_count
     }
+
+    // Approvals a governance proposal needs to execute
+    pub fn threshold_for(&self, proposal_type: &ProposalType) -> u8 {
+        self.policy_or_default(self.threshold_policy.entry_for(proposal_type))
+    }
+
+    // Approvals a TransferSol proposal of `amount` lamports needs to execute
+    pub fn transfer_threshold(&self, amount: u64) -> u8 {
+        self.policy_or_default(self.threshold_policy.transfer_entry(amount))
+    }
+
+    // A policy entry of 0 means the default threshold
+    fn policy_or_default(&self, entry: u8) -> u8 {
+        if entry == 0 { self.threshold } else { entry }
+    }
 }
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+use crate::{
+    state::MemberRole,
+    constants::*,
+};
+
+// Large multisig account
+//
+// Zero-copy variant of Multisig for rosters beyond MAX_OWNERS. The account is
+// a fixed header followed by a slab of member slots:
+//
+// [discriminator (8)][MultisigLarge header][LargeMember; member_capacity]
+//
+// Multisig is Borsh: every instruction decodes and re-encodes all MAX_OWNERS
+// members, so its cost grows with the array. Here the header is read in place
+// and members are cast straight out of the slab, so a call that only touches
+// the header costs the same at 16 slots as at 256.
+//
+// The slab starts at LARGE_MEMBERS_INITIAL_CAPACITY slots and grows by realloc
+// (grow_multisig_large) in LARGE_MEMBERS_GROWTH_STEP steps up to
+// MAX_LARGE_MEMBERS. Slots past member_count are zeroed and unused.
+#[account(zero_copy)]
+pub struct MultisigLarge {
+    // Creator of the multisig - admin, member 0, cannot be removed
+    pub creator: Pubkey,
+
+    // Unique identifier for this multisig (per creator)
+    pub multisig_id: u64,
+
+    // Timelock duration in seconds
+    pub timelock_seconds: u64,
+
+    // Total proposals ever created (used for proposal numbering)
+    pub proposal_count: u64,
+
+    // Slots in use / slots allocated in the member slab
+    pub member_count: u16,
+    pub member_capacity: u16,
+
+    // Number of member signatures required for roster changes
+    // Must be: 1 <= threshold <= member_count
+    pub threshold: u8,
+
+    // 1 while paused
+    pub paused: u8,
+
+    // PDA bump seed
+    pub bump: u8,
+
+    pub _padding: [u8; 1],
+}
+
+// One slot of the member slab
+#[zero_copy]
+pub struct LargeMember {
+    pub pubkey: Pubkey,
+
+    // MemberRole as u8 (Admin = 0, Proposer = 1, Executor = 2)
+    pub role: u8,
+}
+
+impl MultisigLarge {
+    // Bytes before the member slab
+    pub const HEADER_SPACE: usize = ANCHOR_DISCRIMINATOR + size_of::<MultisigLarge>();
+
+    // Account size for a slab of `capacity` slots
+    pub fn space(capacity: usize) -> usize {
+        Self::HEADER_SPACE + capacity * size_of::<LargeMember>()
+    }
+
+    // Split raw account data into the header and the member slab
+    // The AccountLoader has already checked the owner and discriminator
+    pub fn split(data: &[u8]) -> (&MultisigLarge, &[LargeMember]) {
+        let (header, slab) = data[ANCHOR_DISCRIMINATOR..].split_at(size_of::<MultisigLarge>());
+        let header: &MultisigLarge = bytemuck::from_bytes(header);
+        let slab_len = header.member_capacity as usize * size_of::<LargeMember>();
+        (header, bytemuck::cast_slice(&slab[..slab_len]))
+    }
+
+    pub fn split_mut(data: &mut [u8]) -> (&mut MultisigLarge, &mut [LargeMember]) {
+        let (header, slab) = data[ANCHOR_DISCRIMINATOR..].split_at_mut(size_of::<MultisigLarge>());
+        let header: &mut MultisigLarge = bytemuck::from_bytes_mut(header);
+        let slab_len = header.member_capacity as usize * size_of::<LargeMember>();
+        (header, bytemuck::cast_slice_mut(&mut slab[..slab_len]))
+    }
+
+    // Members in use (the slab is padded to member_capacity)
+    pub fn active_members<'a>(&self, slab: &'a [LargeMember]) -> &'a [LargeMember] {
+        &slab[..self.member_count as usize]
+    }
+
+    // Check if a pubkey is a member
+    pub fn is_member(&self, slab: &[LargeMember], key: &Pubkey) -> bool {
+        self.active_members(slab)
+            .iter()
+            .any(|member| &member.pubkey == key)
+    }
+
+    // Check if a member is admin (creator only, same rule as Multisig)
+    pub fn is_admin(&self, key: &Pubkey) -> bool {
+        key == &self.creator
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused == 1
+    }
+}
+
+impl LargeMember {
+    pub fn new(pubkey: Pubkey, role: MemberRole) -> Self {
+        LargeMember {
+            pubkey,
+            role: role as u8,
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::constants::MAX_OWNERS;
-use super::member::*;
+use anchor_lang::solana_program::hash::hashv;
+use soteria_common::time;
+use crate::{constants::*, errors::*};
+use super::{member::*, threshold_policy::ThresholdPolicy};
 
 // Proposal status enum
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
@@ -39,6 +41,69 @@ pub enum ProposalType {
     // Change the timelock duration
     // Only Admin can create this
     ChangeTimelock { new_timelock: u64 },
+
+    // Approve a proposal on a parent multisig this multisig is a member of
+    // Admin or Proposer can create this
+    // Executed through approve_as_multisig, never execute_proposal
+    ApproveParentProposal { parent_multisig: Pubkey, parent_proposal_id: u64 },
+
+    // Allow or forbid the vault as a proposal rent_payer
+    // Only Admin can create this
+    SetVaultPaysRent { enabled: bool },
+
+    // Move `amount` lamports from the vault into a new native stake account
+    // (["vault_stake", multisig, proposal_id]) delegated to `vote_account`
+    // Only Admin can create this
+    // Executed through execute_stake_proposal, never execute_proposal
+    DelegateVaultStake { vote_account: Pubkey, amount: u64 },
+
+    // Deactivate a vault stake account; it cools down over the next epoch
+    // Admin or Proposer can create this
+    // Executed through execute_stake_proposal, never execute_proposal
+    DeactivateVaultStake { stake_account: Pubkey },
+
+    // Withdraw a deactivated vault stake account's full balance to the vault
+    // Admin or Proposer can create this
+    // Executed through execute_stake_proposal, never execute_proposal
+    WithdrawVaultStake { stake_account: Pubkey },
+
+    // Move the vault's full balance of `mint` to `recipient`'s associated token
+    // account, recovering tokens sent to the vault's ATA
+    // Admin or Proposer can create this
+    // Executed through execute_sweep_proposal, never execute_proposal
+    SweepToken { mint: Pubkey, recipient: Pubkey },
+
+    // Create an address lookup table with the vault as its authority
+    // The table address derives from the vault and a recent slot, which the
+    // executor picks at execution since the slot must still be in SlotHashes
+    // Admin or Proposer can create this
+    // Executed through execute_lookup_table_proposal, never execute_proposal
+    CreateLookupTable,
+
+    // Append `address_count` addresses to a vault-owned lookup table
+    // The list itself is passed at execution and must hash to `addresses_hash`
+    // (see lookup_table_addresses_hash), keeping the variant fixed-size
+    // Admin or Proposer can create this
+    // Executed through execute_lookup_table_proposal, never execute_proposal
+    ExtendLookupTable {
+        lookup_table: Pubkey,
+        addresses_hash: [u8; 32],
+        address_count: u8,
+    },
+
+    // Replace the multisig's per-action threshold table
+    // Needs the approvals the current policy sets for settings changes
+    // Only Admin can create this
+    SetThresholdPolicy { policy: ThresholdPolicy },
+}
+
+// SHA-256 over the domain prefix and the addresses in order
+// Stored by ExtendLookupTable so approvers sign off on the exact list
+pub fn lookup_table_addresses_hash(addresses: &[Pubkey]) -> [u8; 32] {
+    let mut parts: Vec<&[u8]> = Vec::with_capacity(addresses.len() + 1);
+    parts.push(LOOKUP_TABLE_ADDRESSES_DOMAIN);
+    parts.extend(addresses.iter().map(|address| address.as_ref()));
+    hashv(&parts).to_bytes()
 }
 
 // Proposal account
@@ -78,12 +143,59 @@ pub struct Proposal {
     // Timestamp when proposal was executed (0 if not executed)
     pub executed_at: i64,
 
+    // Who paid the proposal's rent (proposer, vault or a sponsor)
+    // Receives the rent back when the proposal is executed or cancelled
+    pub rent_payer: Pubkey,
+
     // PDA bump seed
     pub bump: u8,
+
+    // Canonical hash of the action, set at creation (see proposal_hash)
+    // Off-chain signers compare it with their own digest before approving,
+    // and execution recomputes it so only the approved parameters can run
+    pub proposal_hash: [u8; 32],
+
+    // Optional metadata, empty until attach_memo sets it
+    // Kept last: the account is created without them and resized as they grow
+    #[max_len(MAX_PROPOSAL_TITLE_LEN)]
+    pub title: String,
+
+    #[max_len(MAX_PROPOSAL_MEMO_LEN)]
+    pub memo: String,
 }
 
 impl Proposal {
-    
+    // Account size for a title and memo of the given byte lengths
+    // INIT_SPACE reserves both maxima; only the bytes in use are allocated
+    pub fn space(title_len: usize, memo_len: usize) -> usize {
+        ANCHOR_DISCRIMINATOR + Self::INIT_SPACE - MAX_PROPOSAL_TITLE_LEN - MAX_PROPOSAL_MEMO_LEN
+            + title_len
+            + memo_len
+    }
+
+    // SHA-256 over the domain prefix, the Borsh-encoded proposal type (variant
+    // tag followed by its parameters), the multisig key and the proposal id,
+    // which is the nonce: the same action proposed twice hashes differently
+    pub fn proposal_hash(&self) -> Result<[u8; 32]> {
+        let mut action = Vec::new();
+        self.proposal_type
+            .serialize(&mut action)
+            .map_err(|_| error!(MultisigError::InvalidProposalType))?;
+
+        Ok(hashv(&[
+            PROPOSAL_HASH_DOMAIN,
+            &action,
+            self.multisig.as_ref(),
+            &self.proposal_id.to_le_bytes(),
+        ])
+        .to_bytes())
+    }
+
+    // Check the stored hash still matches the proposal's parameters
+    pub fn has_valid_hash(&self) -> Result<bool> {
+        Ok(self.proposal_hash()? == self.proposal_hash)
+    }
+
     // Check if a specific owner index has approved
     pub fn has_approved(&self, owner_index: usize) -> bool {
         if owner_index >= MAX_OWNERS {
@@ -119,7 +231,7 @@ impl Proposal {
 
     // Check if proposal has expired
     pub fn is_expired(&self, current_timestamp: i64) -> bool {
-        current_timestamp > self.expires_at
+        time::is_expired(current_timestamp, self.expires_at)
     }
 
     // Check if timelock has passed
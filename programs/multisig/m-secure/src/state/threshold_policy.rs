@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use super::proposal::ProposalType;
+
+// Threshold Policy
+//
+// Table of approval thresholds by kind of action, stored on the Multisig and
+// only changeable through a SetThresholdPolicy proposal. Lets routine actions
+// (e.g. small transfers) clear with fewer approvals than member changes.
+//
+// An entry of 0 falls back to the multisig's default threshold, so the
+// zeroed policy a multisig is created with changes nothing.
+// Every non-zero entry must stay within the owner count.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, InitSpace)]
+pub struct ThresholdPolicy {
+    // AddMember, RemoveMember
+    pub member_changes: u8,
+
+    // ChangeThreshold, ChangeTimelock, SetVaultPaysRent, SetThresholdPolicy
+    pub settings_changes: u8,
+
+    // Stake, SweepToken and lookup table proposals, and ApproveParentProposal,
+    // which acts for the multisig on its parent
+    pub vault_operations: u8,
+
+    // TransferSol proposals of at most small_transfer_limit lamports
+    pub small_transfers: u8,
+
+    // All other TransferSol proposals
+    pub transfers: u8,
+
+    // Largest transfer, in lamports, that small_transfers applies to
+    pub small_transfer_limit: u64,
+}
+
+impl ThresholdPolicy {
+    // Entry for a governance proposal (0 = default threshold)
+    pub fn entry_for(&self, proposal_type: &ProposalType) -> u8 {
+        match proposal_type {
+            ProposalType::AddMember { .. } | ProposalType::RemoveMember { .. } => {
+                self.member_changes
+            }
+
+            ProposalType::ChangeThreshold { .. }
+            | ProposalType::ChangeTimelock { .. }
+            | ProposalType::SetVaultPaysRent { .. }
+            | ProposalType::SetThresholdPolicy { .. } => self.settings_changes,
+
+            ProposalType::ApproveParentProposal { .. }
+            | ProposalType::DelegateVaultStake { .. }
+            | ProposalType::DeactivateVaultStake { .. }
+            | ProposalType::WithdrawVaultStake { .. }
+            | ProposalType::SweepToken { .. }
+            | ProposalType::CreateLookupTable
+            | ProposalType::ExtendLookupTable { .. } => self.vault_operations,
+        }
+    }
+
+    // Entry for a TransferSol proposal of `amount` lamports (0 = default threshold)
+    pub fn transfer_entry(&self, amount: u64) -> u8 {
+        if amount <= self.small_transfer_limit {
+            self.small_transfers
+        } else {
+            self.transfers
+        }
+    }
+
+    // Every entry is unset or reachable with owner_count members
+    pub fn is_valid(&self, owner_count: u8) -> bool {
+        [
+            self.member_changes,
+            self.settings_changes,
+            self.vault_operations,
+            self.small_transfers,
+            self.transfers,
+        ]
+        .iter()
+        .all(|&entry| entry <= owner_count)
+    }
+}
@@ -1,4 +1,8 @@
 use anchor_lang::prelude::*;
+use soteria_common::{
+    ownership::{is_owned_by, is_system_owned},
+    time,
+};
 
 use crate::{constants::MAX_OWNERS, state::*};
 
@@ -56,12 +60,78 @@ pub struct TransferProposal {
     // Always present, no Option needed
     pub recipient: Pubkey,
 
+    // How the recipient is validated at execution (wallet by default)
+    pub recipient_kind: RecipientKind,
+
+    // Who paid the proposal's rent (proposer, vault or a sponsor)
+    // Receives the rent back when the proposal is executed or cancelled
+    pub rent_payer: Pubkey,
+
     // PDA bump seed
     pub bump: u8,
 }
 
 
 
+// Recipient Kind
+//
+// Chosen when the transfer proposal is created, so every approval covers it.
+// Wallet is the default, strict path: the recipient must be owned by the
+// System Program, as a keypair wallet is.
+// ProgramVault opts in to paying a program-owned account, typically another
+// program's off-curve PDA vault. The owning program is pinned in the proposal
+// and must still own the recipient at execution, so the lamports cannot be
+// redirected to an account some other program controls
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum RecipientKind {
+    Wallet,
+    ProgramVault { owner: Pubkey },
+}
+
+impl RecipientKind {
+    // Whether `recipient` can receive this transfer right now
+    pub fn accepts(&self, recipient: &AccountInfo) -> bool {
+        match self {
+            RecipientKind::Wallet => is_system_owned(recipient),
+            RecipientKind::ProgramVault { owner } => {
+                is_owned_by(recipient, owner) && !recipient.executable
+            }
+        }
+    }
+}
+
+// Expected Outcome
+//
+// Optional guard passed to execute_transfer_proposal. The executor states how
+// many lamports the vault must lose and the recipient must gain; both balances
+// are re-read after the transfer CPI and any difference aborts the transaction.
+// Catches recipient accounts that do not behave like the one the signers
+// simulated (aliased with the vault, or otherwise substituted)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExpectedOutcome {
+    // Lamports leaving the vault
+    pub vault_delta: u64,
+
+    // Lamports arriving at the recipient
+    pub recipient_delta: u64,
+}
+
+// Proposal Validation
+//
+// Return value of validate_proposal. Lists the MultisigError codes (6000-based,
+// as they would appear in a failed transaction) that execute_transfer_proposal
+// would hit right now, in the order it checks them. Empty means executable
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProposalValidation {
+    pub errors: Vec<u32>,
+}
+
+impl ProposalValidation {
+    pub fn is_executable(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
 impl TransferProposal {
     
     // Check if a specific owner index has approved
@@ -99,7 +169,7 @@ impl TransferProposal {
 
     // Check if proposal has expired
     pub fn is_expired(&self, current_timestamp: i64) -> bool {
-        current_timestamp > self.expires_at
+        time::is_expired(current_timestamp, self.expires_at)
     }
 
     // Check if timelock has passed
@@ -0,0 +1,219 @@
+// Compute-unit regression tests for m-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+use litesvm::LiteSVM;
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::Signer};
+use soteria_client::multisig::{instructions as multisig_ix, *};
+use soteria_test_kit::*;
+
+const TIMELOCK_SECONDS: u64 = 1;
+
+fn setup_svm() -> LiteSVM {
+    ProgramHarness::builder()
+        .program_file(PROGRAM_ID, "target/deploy/multisig_secure.so")
+        .build()
+        .into_svm()
+}
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let mut svm = setup_svm();
+    let alice = create_funded_account(&mut svm, 20 * LAMPORTS_PER_SOL);
+    let bob = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let charlie = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let recipient = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+    let (admin, proposer) = (alice.pubkey(), bob.pubkey());
+
+    let (multisig, _) = multisig_address(&admin, 1);
+    let (vault, _) = vault_address(&multisig);
+    let ix = multisig_ix::create_multisig(&admin, &multisig, &vault, 1, 1, TIMELOCK_SECONDS);
+    budget.record("create_multisig", send_ix(&mut svm, ix, &alice));
+
+    // Proposals 0-2: add bob and charlie, then require 2 approvals
+    let (proposal, _) = proposal_address(&multisig, 0);
+    let add_bob = ProposalType::AddMember {
+        new_member: proposer,
+        role: MemberRole::Proposer,
+    };
+    let ix = multisig_ix::create_proposal(&admin, &multisig, &proposal, add_bob);
+    budget.record("create_proposal", send_ix(&mut svm, ix, &alice));
+    advance_time(&mut svm, TIMELOCK_SECONDS + 1);
+    let ix = multisig_ix::execute_proposal(&admin, &multisig, &proposal, &admin);
+    budget.record("execute_proposal", send_ix(&mut svm, ix, &alice));
+
+    let setup_proposals = [
+        ProposalType::AddMember {
+            new_member: charlie.pubkey(),
+            role: MemberRole::Executor,
+        },
+        ProposalType::ChangeThreshold { new_threshold: 2 },
+    ];
+    for (proposal_id, proposal_type) in (1..).zip(setup_proposals) {
+        let (proposal, _) = proposal_address(&multisig, proposal_id);
+        let ix = multisig_ix::create_proposal(&admin, &multisig, &proposal, proposal_type);
+        send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+        advance_time(&mut svm, TIMELOCK_SECONDS + 1);
+        let ix = multisig_ix::execute_proposal(&admin, &multisig, &proposal, &admin);
+        send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    }
+
+    let (proposal, _) = proposal_address(&multisig, 3);
+    let change_timelock = ProposalType::ChangeTimelock {
+        new_timelock: 2 * TIMELOCK_SECONDS,
+    };
+    let ix = multisig_ix::create_proposal(&proposer, &multisig, &proposal, change_timelock);
+    send_tx_expect_success(&mut svm, ix, &bob, &[&bob]);
+    let ix = multisig_ix::approve_proposal(&admin, &multisig, &proposal);
+    budget.record("approve_proposal", send_ix(&mut svm, ix, &alice));
+    let ix = multisig_ix::cancel_proposal(&admin, &multisig, &proposal, &proposer);
+    budget.record("cancel_proposal", send_ix(&mut svm, ix, &alice));
+
+    svm.airdrop(&vault, 5 * LAMPORTS_PER_SOL).expect("Vault funding should succeed");
+    let (transfer_proposal, _) = transfer_proposal_address(&multisig, 4);
+    let ix = multisig_ix::create_transfer_proposal(
+        &proposer,
+        &multisig,
+        &transfer_proposal,
+        LAMPORTS_PER_SOL,
+        &recipient.pubkey(),
+    );
+    budget.record("create_transfer_proposal", send_ix(&mut svm, ix, &bob));
+    let ix = multisig_ix::approve_transfer_proposal(&admin, &multisig, &transfer_proposal);
+    budget.record("approve_transfer_proposal", send_ix(&mut svm, ix, &alice));
+    advance_time(&mut svm, TIMELOCK_SECONDS + 1);
+    let ix = multisig_ix::execute_transfer_proposal(
+        &charlie.pubkey(),
+        &multisig,
+        &transfer_proposal,
+        &proposer,
+        &vault,
+        &recipient.pubkey(),
+    );
+    budget.record("execute_transfer_proposal", send_ix(&mut svm, ix, &charlie));
+
+    // Proposal 5 adds charlie's 1-of-1 multisig as a member; it then approves proposal 6
+    let (child, _) = multisig_address(&charlie.pubkey(), 1);
+    let (child_vault, _) = vault_address(&child);
+    let ix = multisig_ix::create_multisig(
+        &charlie.pubkey(),
+        &child,
+        &child_vault,
+        1,
+        1,
+        TIMELOCK_SECONDS,
+    );
+    send_tx_expect_success(&mut svm, ix, &charlie, &[&charlie]);
+
+    let (proposal, _) = proposal_address(&multisig, 5);
+    let add_child = ProposalType::AddMember {
+        new_member: child,
+        role: MemberRole::Executor,
+    };
+    let ix = multisig_ix::create_proposal(&admin, &multisig, &proposal, add_child);
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    let ix = multisig_ix::approve_proposal(&proposer, &multisig, &proposal);
+    send_tx_expect_success(&mut svm, ix, &bob, &[&bob]);
+    advance_time(&mut svm, 2 * TIMELOCK_SECONDS + 1);
+    let ix = multisig_ix::execute_proposal(&admin, &multisig, &proposal, &admin);
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+
+    let (parent_proposal, _) = proposal_address(&multisig, 6);
+    let ix = multisig_ix::create_proposal(
+        &admin,
+        &multisig,
+        &parent_proposal,
+        ProposalType::ChangeThreshold { new_threshold: 3 },
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    let (child_proposal, _) = proposal_address(&child, 0);
+    let approve_parent = ProposalType::ApproveParentProposal {
+        parent_multisig: multisig,
+        parent_proposal_id: 6,
+    };
+    let ix =
+        multisig_ix::create_proposal(&charlie.pubkey(), &child, &child_proposal, approve_parent);
+    send_tx_expect_success(&mut svm, ix, &charlie, &[&charlie]);
+    advance_time(&mut svm, TIMELOCK_SECONDS + 1);
+    let ix = multisig_ix::approve_as_multisig(
+        &charlie.pubkey(),
+        &child,
+        &child_proposal,
+        &charlie.pubkey(),
+        &multisig,
+        &parent_proposal,
+    );
+    budget.record("approve_as_multisig", send_ix(&mut svm, ix, &charlie));
+
+    let ix = multisig_ix::toggle_pause(&admin, &multisig);
+    budget.record("toggle_pause", send_ix(&mut svm, ix, &alice));
+
+    budget.check();
+}
+
+// Zero-copy MultisigLarge: toggle_pause_large only loads the header, so its
+// cost must not move when the member slab grows from 16 to 256 slots, and it
+// must not cost more than the Borsh toggle_pause on a 10-slot Multisig
+#[test]
+fn test_large_multisig_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let mut svm = setup_svm();
+    let alice = create_funded_account(&mut svm, 20 * LAMPORTS_PER_SOL);
+    let admin = alice.pubkey();
+
+    let (multisig, _) = multisig_address(&admin, 1);
+    let (vault, _) = vault_address(&multisig);
+    let ix = multisig_ix::create_multisig(&admin, &multisig, &vault, 1, 1, TIMELOCK_SECONDS);
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    let ix = multisig_ix::toggle_pause(&admin, &multisig);
+    let borsh_units = send_ix(&mut svm, ix, &alice)
+        .expect("toggle_pause should succeed")
+        .compute_units_consumed;
+
+    let (multisig_large, _) = multisig_large_address(&admin, 1);
+    let ix = multisig_ix::create_multisig_large(&admin, &multisig_large, 1, TIMELOCK_SECONDS);
+    budget.record("create_multisig_large", send_ix(&mut svm, ix, &alice));
+    let ix = multisig_ix::add_large_member(
+        &admin,
+        &multisig_large,
+        &[],
+        &Pubkey::new_unique(),
+        MemberRole::Proposer,
+        1,
+    );
+    budget.record("add_large_member", send_ix(&mut svm, ix, &alice));
+    let ix = multisig_ix::toggle_pause_large(&admin, &multisig_large);
+    let small_units = budget
+        .record("toggle_pause_large", send_ix(&mut svm, ix, &alice))
+        .compute_units_consumed;
+
+    // Grow 16 -> 256 slots
+    let ix = multisig_ix::grow_multisig_large(&admin, &multisig_large);
+    budget.record("grow_multisig_large", send_ix(&mut svm, ix, &alice));
+    for _ in 0..14 {
+        let ix = multisig_ix::grow_multisig_large(&admin, &multisig_large);
+        send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    }
+    let ix = multisig_ix::toggle_pause_large(&admin, &multisig_large);
+    let large_units = budget
+        .record("toggle_pause_large_256_slots", send_ix(&mut svm, ix, &alice))
+        .compute_units_consumed;
+
+    println!(
+        "toggle_pause: borsh {} CU, zero-copy {} CU at 16 slots, {} CU at 256 slots",
+        borsh_units, small_units, large_units
+    );
+    assert!(
+        large_units.abs_diff(small_units) <= small_units / 100,
+        "toggle_pause_large should not scale with slab size"
+    );
+    assert!(large_units <= borsh_units);
+
+    budget.check();
+}
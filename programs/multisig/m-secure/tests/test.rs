@@ -19,6 +19,50 @@
 // 12. test_non_member_cannot_approve - Non-members cannot approve proposals
 // 13. test_cannot_remove_creator - Creator is protected from removal
 // 14. test_cancel_proposal - Proposer or admin can cancel active proposals
+//
+// === Nested Multisig Tests ===
+// 15. test_nested_multisig_approval - Child multisig PDA approves a parent proposal
+// 16. test_nested_approval_bound_to_target - Child vote cannot be replayed or retargeted
+//
+// === Proposal Rent Tests ===
+// 17. test_vault_funded_proposal_rent - Vault pays proposal rent once enabled, refunded on close
+// 18. test_sponsor_rent_payer - A sponsor must sign to pay rent and gets it back on execution
+//
+// === Simulation Guard Tests ===
+// 19. test_transfer_expected_outcome - Mismatched balance deltas abort the transfer
+//
+// === Large Multisig Tests ===
+// 20. test_large_multisig_roster - Zero-copy roster fills, grows by realloc, needs co-signers
+//
+// === Vault Stake Tests ===
+// 21. test_vault_stake_delegation - Delegate, deactivate and withdraw vault SOL via proposals
+//
+// === Proposal Metadata Tests ===
+// 22. test_proposal_memo - Proposer attaches a bounded title and memo, account resized to fit
+//
+// === Token Sweep Tests ===
+// 23. test_vault_token_sweep - Tokens stuck in the vault's ATA are swept to the approved recipient
+//
+// === Proposal Hash Tests ===
+// 24. test_proposal_hash - Execution rejects parameters that no longer match the approved hash
+//
+// === Dry-Run Tests ===
+// 25. test_validate_proposal - Read-only validation reports every failing execution check
+//
+// === Recipient Kind Tests ===
+// 26. test_program_vault_recipient - Program-owned recipients need an explicit ProgramVault opt-in
+//
+// === Address Lookup Table Tests ===
+// 27. test_vault_lookup_table - Vault-owned lookup table created and extended with approved addresses
+//
+// === Threshold Policy Tests ===
+// 28. test_threshold_policy - Small transfers clear with fewer approvals than large ones
+//
+// === Layout Tests ===
+// 29. test_proposal_space - Client and governance relay size new proposals like the program
+//
+// === Feature Matrix Tests ===
+// 30. test_feature_missing_admin_check - Only the vuln-missing-admin-check build lets a non-admin pause
 
 // the test code is long, if you want to read and see how we did the test, go for it, else 
 // {
@@ -39,151 +83,42 @@
 // }
 
 use anchor_lang::prelude::pubkey;
-use litesvm::LiteSVM;
+use litesvm::{types::TransactionResult, LiteSVM};
+use litesvm_token::{
+    get_spl_account, spl_token::state::Account as TokenAccount, CreateAssociatedTokenAccount,
+    CreateMint, MintTo,
+};
 
 use solana_sdk::{
-    instruction::{AccountMeta, Instruction},
+    account::Account,
+    hash::Hash,
+    instruction::Instruction,
     native_token::LAMPORTS_PER_SOL,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
+    slot_hashes::SlotHashes,
     transaction::Transaction,
 };
 
+use soteria_client::multisig::{instructions as multisig_ix, *};
+use soteria_client::token::{associated_token_address, TOKEN_PROGRAM_ID};
+use soteria_test_kit::*;
+use multisig_secure::errors::MultisigErrorCode;
 
 
-use solana_system_interface::program::ID as system_program;
-
-
-// Program ID matching declare_id in lib.rs
-const PROGRAM_ID: Pubkey = solana_sdk::pubkey!("HH8rYFiTjMX8FiiRgiFQx1jnXdT9D4TTiC5mSBhe9r7P");
-
-// PDA seed constants (must match constants.rs)
-const MULTISIG_SEED: &[u8] = b"multisig";
-const PROPOSAL_SEED: &[u8] = b"proposal";
-const TRANSFER_PROPOSAL_SEED: &[u8] = b"transfer";
-const VAULT_SEED: &[u8] = b"vault";
-
-// Member roles (must match MemberRole enum in state/member.rs)
-#[repr(u8)]
-#[derive(Debug, Clone, Copy)]
-enum MemberRole {
-    Admin = 0,
-    Proposer = 1,
-    Executor = 2,
-}
-
-// Proposal types (must match ProposalType enum in state/proposal.rs)
-// Borsh/Anchor enums are 0-indexed!
-#[repr(u8)]
-#[derive(Debug, Clone, Copy)]
-enum ProposalTypeDiscriminator {
-    AddMember = 0,
-    RemoveMember = 1,
-    ChangeThreshold = 2,
-    ChangeTimelock = 3,
-}
-
 // ======================== HELPERS ========================
 
 /// Load the compiled program binary into LiteSVM
 fn setup_svm() -> LiteSVM {
-    let mut svm = LiteSVM::new();
-    let program_bytes = include_bytes!("../target/deploy/multisig_secure.so");
-    svm.add_program(PROGRAM_ID, program_bytes);
-    svm
-}
-
-/// Create a new keypair and fund it with SOL via airdrop
-fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
-    let keypair = Keypair::new();
-    svm.airdrop(&keypair.pubkey(), lamports)
-        .expect("Airdrop should succeed");
-    keypair
-}
-
-/// Derive the multisig PDA using seeds: ["multisig", creator_pubkey, multisig_id]
-fn derive_multisig_pda(creator: &Pubkey, multisig_id: u64) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[MULTISIG_SEED, creator.as_ref(), &multisig_id.to_le_bytes()],
-        &PROGRAM_ID,
-    )
-}
-
-/// Derive the vault PDA using seeds: ["vault", multisig_pubkey]
-fn derive_vault_pda(multisig: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[VAULT_SEED, multisig.as_ref()], &PROGRAM_ID)
-}
-
-/// Derive the proposal PDA using seeds: ["proposal", multisig_pubkey, proposal_id]
-fn derive_proposal_pda(multisig: &Pubkey, proposal_id: u64) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[PROPOSAL_SEED, multisig.as_ref(), &proposal_id.to_le_bytes()],
-        &PROGRAM_ID,
-    )
-}
-
-/// Derive the transfer proposal PDA using seeds: ["transfer", multisig_pubkey, proposal_id]
-fn derive_transfer_proposal_pda(multisig: &Pubkey, proposal_id: u64) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[
-            TRANSFER_PROPOSAL_SEED,
-            multisig.as_ref(),
-            &proposal_id.to_le_bytes(),
-        ],
-        &PROGRAM_ID,
-    )
-}
-
-/// Build Anchor instruction discriminator (8 bytes from sighash of "global:method_name")
-fn anchor_discriminator(method: &str) -> [u8; 8] {
-    let preimage = format!("global:{}", method);
-    let hash = solana_sdk::hash::hash(preimage.as_bytes());
-    let mut discriminator = [0u8; 8];
-    discriminator.copy_from_slice(&hash.to_bytes()[..8]);
-    discriminator
-}
-
-/// Advance the SVM clock by the specified number of seconds
-/// LiteSVM uses slot-based time, so we warp slots (approx 400ms each)
-
-fn advance_time(svm: &mut LiteSVM, seconds: u64) {
-    let mut clock: solana_sdk::clock::Clock = svm.get_sysvar();
-    clock.unix_timestamp += seconds as i64;
-    svm.set_sysvar(&clock);
-
-    let current_slot = clock.slot;
-    svm.warp_to_slot(current_slot + (seconds * 2) + 5);
+    ProgramHarness::builder()
+        .program_file(PROGRAM_ID, "target/deploy/multisig_secure.so")
+        .build()
+        .into_svm()
 }
 
 // ======================== INSTRUCTION BUILDERS ========================
 
-/// Build create_multisig instruction
-fn build_create_multisig_ix(
-    creator: &Pubkey,
-    multisig: &Pubkey,
-    vault: &Pubkey,
-    multisig_id: u64,
-    threshold: u8,
-    timelock_seconds: u64,
-) -> Instruction {
-    let discriminator = anchor_discriminator("create_multisig");
-
-    let mut data = discriminator.to_vec();
-    data.extend_from_slice(&multisig_id.to_le_bytes());
-    data.extend_from_slice(&[threshold]);
-    data.extend_from_slice(&timelock_seconds.to_le_bytes());
-
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*creator, true),
-            AccountMeta::new(*multisig, false),
-            AccountMeta::new(*vault, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data,
-    }
-}
+// The remaining builders come straight from soteria_client::multisig::instructions
 
 /// Build create_proposal instruction (AddMember variant)
 fn build_create_add_member_proposal_ix(
@@ -193,23 +128,15 @@ fn build_create_add_member_proposal_ix(
     new_member: &Pubkey,
     role: MemberRole,
 ) -> Instruction {
-    let discriminator = anchor_discriminator("create_proposal");
-
-    let mut data = discriminator.to_vec();
-    data.push(ProposalTypeDiscriminator::AddMember as u8);
-    data.extend_from_slice(new_member.as_ref());
-    data.push(role as u8);
-
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*proposer, true),
-            AccountMeta::new(*multisig, false),
-            AccountMeta::new(*proposal, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data,
-    }
+    multisig_ix::create_proposal(
+        proposer,
+        multisig,
+        proposal,
+        ProposalType::AddMember {
+            new_member: *new_member,
+            role,
+        },
+    )
 }
 
 /// Build create_proposal instruction (RemoveMember variant)
@@ -219,22 +146,14 @@ fn build_create_remove_member_proposal_ix(
     proposal: &Pubkey,
     member_to_remove: &Pubkey,
 ) -> Instruction {
-    let discriminator = anchor_discriminator("create_proposal");
-
-    let mut data = discriminator.to_vec();
-    data.push(ProposalTypeDiscriminator::RemoveMember as u8);
-    data.extend_from_slice(member_to_remove.as_ref());
-
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*proposer, true),
-            AccountMeta::new(*multisig, false),
-            AccountMeta::new(*proposal, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data,
-    }
+    multisig_ix::create_proposal(
+        proposer,
+        multisig,
+        proposal,
+        ProposalType::RemoveMember {
+            member_to_remove: *member_to_remove,
+        },
+    )
 }
 
 /// Build create_proposal instruction (ChangeThreshold variant)
@@ -244,22 +163,12 @@ fn build_create_change_threshold_proposal_ix(
     proposal: &Pubkey,
     new_threshold: u8,
 ) -> Instruction {
-    let discriminator = anchor_discriminator("create_proposal");
-
-    let mut data = discriminator.to_vec();
-    data.push(ProposalTypeDiscriminator::ChangeThreshold as u8);
-    data.push(new_threshold);
-
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*proposer, true),
-            AccountMeta::new(*multisig, false),
-            AccountMeta::new(*proposal, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data,
-    }
+    multisig_ix::create_proposal(
+        proposer,
+        multisig,
+        proposal,
+        ProposalType::ChangeThreshold { new_threshold },
+    )
 }
 
 /// Build create_proposal instruction (ChangeTimelock variant)
@@ -269,211 +178,12 @@ fn build_create_change_timelock_proposal_ix(
     proposal: &Pubkey,
     new_timelock: u64,
 ) -> Instruction {
-    let discriminator = anchor_discriminator("create_proposal");
-
-    let mut data = discriminator.to_vec();
-    data.push(ProposalTypeDiscriminator::ChangeTimelock as u8);
-    data.extend_from_slice(&new_timelock.to_le_bytes());
-
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*proposer, true),
-            AccountMeta::new(*multisig, false),
-            AccountMeta::new(*proposal, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data,
-    }
-}
-
-/// Build approve_proposal instruction
-fn build_approve_proposal_ix(
-    owner: &Pubkey,
-    multisig: &Pubkey,
-    proposal: &Pubkey,
-) -> Instruction {
-    let discriminator = anchor_discriminator("approve_proposal");
-
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*owner, true),
-            AccountMeta::new_readonly(*multisig, false),
-            AccountMeta::new(*proposal, false),
-        ],
-        data: discriminator.to_vec(),
-    }
-}
-
-/// Build execute_proposal instruction
-fn build_execute_proposal_ix(
-    executor: &Pubkey,
-    multisig: &Pubkey,
-    proposal: &Pubkey,
-    proposer: &Pubkey,
-) -> Instruction {
-    let discriminator = anchor_discriminator("execute_proposal");
-
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*executor, true),
-            AccountMeta::new(*multisig, false),
-            AccountMeta::new(*proposal, false),
-            AccountMeta::new(*proposer, false),
-        ],
-        data: discriminator.to_vec(),
-    }
-}
-
-/// Build cancel_proposal instruction
-fn build_cancel_proposal_ix(
-    canceller: &Pubkey,
-    multisig: &Pubkey,
-    proposal: &Pubkey,
-    proposer: &Pubkey,
-) -> Instruction {
-    let discriminator = anchor_discriminator("cancel_proposal");
-
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*canceller, true),
-            AccountMeta::new_readonly(*multisig, false),
-            AccountMeta::new(*proposal, false),
-             AccountMeta::new(*proposer, false),
-        ],
-        data: discriminator.to_vec(),
-    }
-}
-
-/// Build create_transfer_proposal instruction
-fn build_create_transfer_proposal_ix(
-    proposer: &Pubkey,
-    multisig: &Pubkey,
-    transfer_proposal: &Pubkey,
-    amount: u64,
-    recipient: &Pubkey,
-) -> Instruction {
-    let discriminator = anchor_discriminator("create_transfer_proposal");
-
-    let mut data = discriminator.to_vec();
-    data.extend_from_slice(&amount.to_le_bytes());
-    data.extend_from_slice(recipient.as_ref());
-
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*proposer, true),
-            AccountMeta::new(*multisig, false),
-            AccountMeta::new(*transfer_proposal, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data,
-    }
-}
-
-/// Build approve_transfer_proposal instruction
-fn build_approve_transfer_proposal_ix(
-    owner: &Pubkey,
-    multisig: &Pubkey,
-    transfer_proposal: &Pubkey,
-) -> Instruction {
-    let discriminator = anchor_discriminator("approve_transfer_proposal");
-
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*owner, true),
-            AccountMeta::new_readonly(*multisig, false),
-            AccountMeta::new(*transfer_proposal, false),
-        ],
-        data: discriminator.to_vec(),
-    }
-}
-
-/// Build execute_transfer_proposal instruction
-fn build_execute_transfer_proposal_ix(
-    executor: &Pubkey,
-    multisig: &Pubkey,
-    transfer_proposal: &Pubkey,
-    proposer: &Pubkey,
-    vault: &Pubkey,
-    recipient: &Pubkey,
-) -> Instruction {
-    let discriminator = anchor_discriminator("execute_transfer_proposal");
-
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*executor, true),
-            AccountMeta::new(*multisig, false),
-            AccountMeta::new(*transfer_proposal, false),
-            AccountMeta::new(*proposer, false),
-            AccountMeta::new(*vault, false),
-            AccountMeta::new(*recipient, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data: discriminator.to_vec(),
-    }
-}
-
-/// Build toggle_pause instruction
-fn build_toggle_pause_ix(admin: &Pubkey, multisig: &Pubkey) -> Instruction {
-    let discriminator = anchor_discriminator("toggle_pause");
-
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*admin, true),
-            AccountMeta::new(*multisig, false),
-        ],
-        data: discriminator.to_vec(),
-    }
-}
-
-// ======================== TRANSACTION HELPERS ========================
-
-/// Send a transaction and expect success
-fn send_tx_expect_success(
-    svm: &mut LiteSVM,
-    ix: Instruction,
-    payer: &Keypair,
-    signers: &[&Keypair],
-) {
-    let blockhash = svm.latest_blockhash();
-
-    let tx = Transaction::new_signed_with_payer(
-        &[ix],
-        Some(&payer.pubkey()),
-        signers,
-        blockhash,
-    );
-
-    svm.send_transaction(tx)
-        .expect("Transaction should succeed");
-}
-
-/// Send a transaction and expect failure
-fn send_tx_expect_failure(
-    svm: &mut LiteSVM,
-    ix: Instruction,
-    payer: &Keypair,
-    signers: &[&Keypair],
-) -> String {
-
-    let blockhash = svm.latest_blockhash();
-
-    let tx = Transaction::new_signed_with_payer(
-        &[ix],
-        Some(&payer.pubkey()),
-        signers,
-        blockhash,
-    );
-    let result = svm.send_transaction(tx);
-    assert!(result.is_err(), "Transaction should have failed");
-    format!("{:?}", result.err().unwrap())
+    multisig_ix::create_proposal(
+        proposer,
+        multisig,
+        proposal,
+        ProposalType::ChangeTimelock { new_timelock },
+    )
 }
 
 // ======================== SETUP HELPERS ========================
@@ -497,10 +207,10 @@ fn create_basic_multisig(
     multisig_id: u64,
     timelock_seconds: u64,
 ) -> (Pubkey, Pubkey) {
-    let (multisig, _) = derive_multisig_pda(&creator.pubkey(), multisig_id);
-    let (vault, _) = derive_vault_pda(&multisig);
+    let (multisig, _) = multisig_address(&creator.pubkey(), multisig_id);
+    let (vault, _) = vault_address(&multisig);
 
-    let create_ix = build_create_multisig_ix(
+    let create_ix = multisig_ix::create_multisig(
         &creator.pubkey(),
         &multisig,
         &vault,
@@ -525,7 +235,7 @@ fn add_member_to_multisig(
     proposal_id: u64,
     timelock_seconds: u64,
 ) {
-    let (proposal, _) = derive_proposal_pda(multisig, proposal_id);
+    let (proposal, _) = proposal_address(multisig, proposal_id);
 
     // Create AddMember proposal (auto-approved by proposer)
     let add_member_ix = build_create_add_member_proposal_ix(
@@ -541,7 +251,7 @@ fn add_member_to_multisig(
     advance_time(svm, timelock_seconds + 2);
 
     // Execute proposal
-    let execute_ix = build_execute_proposal_ix(&admin.pubkey(), multisig, &proposal, &admin.pubkey());
+    let execute_ix = multisig_ix::execute_proposal(&admin.pubkey(), multisig, &proposal, &admin.pubkey());
     send_tx_expect_success(svm, execute_ix, admin, &[admin]);
 }
 
@@ -560,15 +270,15 @@ fn test_create_multisig() {
     println!("[Setup] Alice (creator) funded: {}", alice.pubkey());
 
     let multisig_id = 1u64;
-    let (multisig, multisig_bump) = derive_multisig_pda(&alice.pubkey(), multisig_id);
-    let (vault, vault_bump) = derive_vault_pda(&multisig);
+    let (multisig, multisig_bump) = multisig_address(&alice.pubkey(), multisig_id);
+    let (vault, vault_bump) = vault_address(&multisig);
     println!("[Derive] Multisig PDA: {} (bump: {})", multisig, multisig_bump);
     println!("[Derive] Vault PDA: {} (bump: {})", vault, vault_bump);
 
     // Note: threshold must be 1 at creation since only 1 member exists
     let threshold = 1u8;
     let timelock_seconds = 60u64;
-    let create_ix = build_create_multisig_ix(
+    let create_ix = multisig_ix::create_multisig(
         &alice.pubkey(),
         &multisig,
         &vault,
@@ -611,6 +321,21 @@ fn test_create_multisig() {
         multisig_account.data.len()
     );
 
+    // Decode the account and check the stored configuration
+    let state = Multisig::try_from_bytes(&multisig_account.data).expect("Multisig should decode");
+    assert_eq!(state.creator, alice.pubkey());
+    assert_eq!(state.threshold, threshold);
+    assert_eq!(state.timelock_seconds, timelock_seconds);
+    assert_eq!(state.vault, vault);
+    assert_eq!(state.active_members().len(), 1);
+    assert_eq!(state.active_members()[0].role, MemberRole::Admin);
+    println!(
+        "[Verify] Decoded: threshold={}, timelock={}s, members={}",
+        state.threshold,
+        state.timelock_seconds,
+        state.active_members().len()
+    );
+
     // Verify vault account exists
     let vault_account = svm.get_account(&vault).expect("Vault PDA should exist");
     println!(
@@ -679,7 +404,7 @@ fn test_full_governance_flow() {
     // Create proposal to change threshold to 2 (proposal 2)
     println!("\n[Step 4] Alice proposes to change threshold to 2");
     let proposal_id_2 = 2u64;
-    let (proposal_2, _) = derive_proposal_pda(&multisig, proposal_id_2);
+    let (proposal_2, _) = proposal_address(&multisig, proposal_id_2);
 
     let change_threshold_ix = build_create_change_threshold_proposal_ix(
         &alice.pubkey(),
@@ -695,7 +420,7 @@ fn test_full_governance_flow() {
 
     // Execute threshold change
     println!("\n[Step 5] Executing threshold change");
-    let execute_ix = build_execute_proposal_ix(&alice.pubkey(), &multisig, &proposal_2, &alice.pubkey());
+    let execute_ix = multisig_ix::execute_proposal(&alice.pubkey(), &multisig, &proposal_2, &alice.pubkey());
     send_tx_expect_success(&mut svm, execute_ix, &alice, &[&alice]);
     println!("[Step 5] Threshold changed to 2");
 
@@ -708,10 +433,10 @@ fn test_full_governance_flow() {
         .expect("Vault funding should succeed");
 
     let transfer_proposal_id = 3u64;
-    let (transfer_proposal, _) = derive_transfer_proposal_pda(&multisig, transfer_proposal_id);
+    let (transfer_proposal, _) = transfer_proposal_address(&multisig, transfer_proposal_id);
 
     // Bob creates transfer proposal (Bob is Proposer, so auto-approves = 1 approval)
-    let create_transfer_ix = build_create_transfer_proposal_ix(
+    let create_transfer_ix = multisig_ix::create_transfer_proposal(
         &bob.pubkey(),
         &multisig,
         &transfer_proposal,
@@ -727,12 +452,12 @@ fn test_full_governance_flow() {
 
     // Alice approves (now 2/2)
     let approve_ix =
-        build_approve_transfer_proposal_ix(&alice.pubkey(), &multisig, &transfer_proposal);
+        multisig_ix::approve_transfer_proposal(&alice.pubkey(), &multisig, &transfer_proposal);
     send_tx_expect_success(&mut svm, approve_ix, &alice, &[&alice]);
     println!("[Step 6] Alice approved (2/2 approvals)");
 
     // Now execute should work
-    let execute_transfer_ix = build_execute_transfer_proposal_ix(
+    let execute_transfer_ix = multisig_ix::execute_transfer_proposal(
         &alice.pubkey(),
         &multisig,
         &transfer_proposal,
@@ -784,13 +509,13 @@ fn test_transfer_proposal_flow() {
     // Create transfer proposal
     let transfer_amount = LAMPORTS_PER_SOL;
     let proposal_id = 0u64;
-    let (transfer_proposal, _) = derive_transfer_proposal_pda(&multisig, proposal_id);
+    let (transfer_proposal, _) = transfer_proposal_address(&multisig, proposal_id);
 
     println!(
         "\n[Step 3] Creating transfer proposal (amount={} lamports)",
         transfer_amount
     );
-    let create_transfer_ix = build_create_transfer_proposal_ix(
+    let create_transfer_ix = multisig_ix::create_transfer_proposal(
         &alice.pubkey(),
         &multisig,
         &transfer_proposal,
@@ -812,7 +537,7 @@ fn test_transfer_proposal_flow() {
         recipient_balance_before
     );
 
-    let execute_transfer_ix = build_execute_transfer_proposal_ix(
+    let execute_transfer_ix = multisig_ix::execute_transfer_proposal(
         &alice.pubkey(),
         &multisig,
         &transfer_proposal,
@@ -895,7 +620,7 @@ fn test_remove_member() {
 
     // Create proposal to remove Bob (proposal 1)
     let proposal_id = 1u64;
-    let (proposal, _) = derive_proposal_pda(&multisig, proposal_id);
+    let (proposal, _) = proposal_address(&multisig, proposal_id);
 
     println!("\n[Step 3] Creating proposal to remove Bob");
     let remove_member_ix = build_create_remove_member_proposal_ix(
@@ -910,13 +635,13 @@ fn test_remove_member() {
     // Advance time and execute
     advance_time(&mut svm, timelock_seconds + 1);
 
-    let execute_ix = build_execute_proposal_ix(&alice.pubkey(), &multisig, &proposal, &alice.pubkey());
+    let execute_ix = multisig_ix::execute_proposal(&alice.pubkey(), &multisig, &proposal, &alice.pubkey());
     send_tx_expect_success(&mut svm, execute_ix, &alice, &[&alice]);
     println!("[Step 4] Bob removed from multisig");
 
     // Verify Bob cannot approve proposals anymore
     let proposal_id_2 = 2u64;
-    let (proposal_2, _) = derive_proposal_pda(&multisig, proposal_id_2);
+    let (proposal_2, _) = proposal_address(&multisig, proposal_id_2);
 
     // Alice creates a new proposal
     let charlie = Keypair::new();
@@ -930,11 +655,10 @@ fn test_remove_member() {
     send_tx_expect_success(&mut svm, add_charlie_ix, &alice, &[&alice]);
 
     // Bob tries to approve (should fail - not a member)
-    let approve_ix = build_approve_proposal_ix(&bob.pubkey(), &multisig, &proposal_2);
-    let error = send_tx_expect_failure(&mut svm, approve_ix, &bob, &[&bob]);
-    assert!(
-        error.contains("NotAMember") || error.contains("6300"),
-        "Bob should not be able to approve"
+    let approve_ix = multisig_ix::approve_proposal(&bob.pubkey(), &multisig, &proposal_2);
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[approve_ix], &bob, &[&bob]),
+        MultisigErrorCode::NotAMember
     );
     println!("[Verify] Bob cannot approve proposals (correctly rejected)");
 
@@ -964,7 +688,7 @@ fn test_change_timelock() {
     // Create proposal to change timelock to 10s (proposal 0)
     let new_timelock = 10u64;
     let proposal_id = 0u64;
-    let (proposal, _) = derive_proposal_pda(&multisig, proposal_id);
+    let (proposal, _) = proposal_address(&multisig, proposal_id);
 
     println!("\n[Step 2] Creating proposal to change timelock to {}s", new_timelock);
     let change_timelock_ix = build_create_change_timelock_proposal_ix(
@@ -980,7 +704,7 @@ fn test_change_timelock() {
     advance_time(&mut svm, initial_timelock + 1);
 
     // Execute timelock change
-    let execute_ix = build_execute_proposal_ix(&alice.pubkey(), &multisig, &proposal, &alice.pubkey());
+    let execute_ix = multisig_ix::execute_proposal(&alice.pubkey(), &multisig, &proposal, &alice.pubkey());
     send_tx_expect_success(&mut svm, execute_ix, &alice, &[&alice]);
     println!("[Step 3] Timelock changed to {}s", new_timelock);
 
@@ -989,9 +713,9 @@ fn test_change_timelock() {
     let recipient = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
 
     let transfer_proposal_id = 1u64;
-    let (transfer_proposal, _) = derive_transfer_proposal_pda(&multisig, transfer_proposal_id);
+    let (transfer_proposal, _) = transfer_proposal_address(&multisig, transfer_proposal_id);
 
-    let create_transfer_ix = build_create_transfer_proposal_ix(
+    let create_transfer_ix = multisig_ix::create_transfer_proposal(
         &alice.pubkey(),
         &multisig,
         &transfer_proposal,
@@ -1005,7 +729,7 @@ fn test_change_timelock() {
     advance_time(&mut svm, new_timelock + 1);
 
     // Should be able to execute with new shorter timelock
-    let execute_transfer_ix = build_execute_transfer_proposal_ix(
+    let execute_transfer_ix = multisig_ix::execute_transfer_proposal(
         &alice.pubkey(),
         &multisig,
         &transfer_proposal,
@@ -1044,14 +768,14 @@ fn test_toggle_pause() {
 
     // Pause
     println!("\n[Step 2] Pausing multisig");
-    let pause_ix = build_toggle_pause_ix(&alice.pubkey(), &multisig);
+    let pause_ix = multisig_ix::toggle_pause(&alice.pubkey(), &multisig);
     send_tx_expect_success(&mut svm, pause_ix, &alice, &[&alice]);
     println!("[Step 2] Multisig paused");
 
     // Try to create proposal (should fail)
     let bob = Keypair::new();
     let proposal_id = 0u64;
-    let (proposal, _) = derive_proposal_pda(&multisig, proposal_id);
+    let (proposal, _) = proposal_address(&multisig, proposal_id);
 
     println!("\n[Step 3] Trying to create proposal while paused");
     let add_member_ix = build_create_add_member_proposal_ix(
@@ -1063,16 +787,15 @@ fn test_toggle_pause() {
     );
     let add_member_ix = add_unique_meta(add_member_ix);
 
-    let error = send_tx_expect_failure(&mut svm, add_member_ix.clone(), &alice, &[&alice]);
-    assert!(
-        error.contains("MultisigPaused") || error.contains("6310"),
-        "Should fail with MultisigPaused"
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[add_member_ix.clone()], &alice, &[&alice]),
+        MultisigErrorCode::MultisigPaused
     );
     println!("[Step 3] Proposal creation blocked (as expected)");
 
     // Unpause
     println!("\n[Step 4] Unpausing multisig");
-    let unpause_ix = build_toggle_pause_ix(&alice.pubkey(), &multisig);
+    let unpause_ix = multisig_ix::toggle_pause(&alice.pubkey(), &multisig);
 
     let unpause_ix = add_unique_meta(unpause_ix);
 
@@ -1124,11 +847,10 @@ fn test_non_admin_cannot_pause() {
 
     // Bob tries to pause (should fail)
     println!("\n[Step 2] Bob tries to pause");
-    let pause_ix = build_toggle_pause_ix(&bob.pubkey(), &multisig);
-    let error = send_tx_expect_failure(&mut svm, pause_ix, &bob, &[&bob]);
-    assert!(
-        error.contains("OnlyAdmin") || error.contains("6304"),
-        "Should fail with OnlyAdmin"
+    let pause_ix = multisig_ix::toggle_pause(&bob.pubkey(), &multisig);
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[pause_ix], &bob, &[&bob]),
+        MultisigErrorCode::OnlyAdmin
     );
     println!("[Step 2] Pause blocked (only admin can pause)");
 
@@ -1162,9 +884,9 @@ fn test_timelock_enforcement() {
 
     // Create transfer proposal
     let proposal_id = 0u64;
-    let (transfer_proposal, _) = derive_transfer_proposal_pda(&multisig, proposal_id);
+    let (transfer_proposal, _) = transfer_proposal_address(&multisig, proposal_id);
 
-    let create_transfer_ix = build_create_transfer_proposal_ix(
+    let create_transfer_ix = multisig_ix::create_transfer_proposal(
         &alice.pubkey(),
         &multisig,
         &transfer_proposal,
@@ -1177,7 +899,7 @@ fn test_timelock_enforcement() {
     // Try to execute immediately (should fail - timelock not passed)
     println!("\n[Step 3] Trying to execute before timelock");
 
-    let failed_execute_ix = build_execute_transfer_proposal_ix(
+    let failed_execute_ix = multisig_ix::execute_transfer_proposal(
     &alice.pubkey(),
     &multisig,
     &transfer_proposal,
@@ -1188,11 +910,9 @@ fn test_timelock_enforcement() {
 
     // make first unique to avoid blockhash issues
     let failed_execute_ix = add_unique_meta(failed_execute_ix);
-    let error = send_tx_expect_failure(&mut svm, failed_execute_ix, &alice, &[&alice]);
-
-    assert!(
-        error.contains("TimelockNotPassed") || error.contains("6309"),
-        "Should fail with TimelockNotPassed"
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[failed_execute_ix], &alice, &[&alice]),
+        MultisigErrorCode::TimelockNotPassed
     );
     println!("[Step 3] Execution blocked (timelock not passed)");
 
@@ -1200,7 +920,7 @@ fn test_timelock_enforcement() {
     println!("\n[Step 4] Advancing time past timelock");
     advance_time(&mut svm, timelock_seconds + 1);
 
-   let execute_transfer_ix = build_execute_transfer_proposal_ix(
+   let execute_transfer_ix = multisig_ix::execute_transfer_proposal(
     &alice.pubkey(),
     &multisig,
     &transfer_proposal,
@@ -1253,7 +973,7 @@ fn test_threshold_enforcement() {
 
     // Change threshold to 2
     let proposal_id_1 = 1u64;
-    let (proposal_1, _) = derive_proposal_pda(&multisig, proposal_id_1);
+    let (proposal_1, _) = proposal_address(&multisig, proposal_id_1);
 
     let change_threshold_ix = build_create_change_threshold_proposal_ix(
         &alice.pubkey(),
@@ -1264,7 +984,7 @@ fn test_threshold_enforcement() {
     send_tx_expect_success(&mut svm, change_threshold_ix, &alice, &[&alice]);
     advance_time(&mut svm, timelock + 1);
 
-    let execute_ix = build_execute_proposal_ix(&alice.pubkey(), &multisig, &proposal_1, &alice.pubkey());
+    let execute_ix = multisig_ix::execute_proposal(&alice.pubkey(), &multisig, &proposal_1, &alice.pubkey());
     send_tx_expect_success(&mut svm, execute_ix, &alice, &[&alice]);
     println!("[Step 2] Threshold changed to 2");
 
@@ -1273,9 +993,9 @@ fn test_threshold_enforcement() {
     let recipient = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
 
     let transfer_proposal_id = 2u64;
-    let (transfer_proposal, _) = derive_transfer_proposal_pda(&multisig, transfer_proposal_id);
+    let (transfer_proposal, _) = transfer_proposal_address(&multisig, transfer_proposal_id);
 
-    let create_transfer_ix = build_create_transfer_proposal_ix(
+    let create_transfer_ix = multisig_ix::create_transfer_proposal(
         &alice.pubkey(),
         &multisig,
         &transfer_proposal,
@@ -1290,7 +1010,7 @@ fn test_threshold_enforcement() {
 
     // Try to execute with 1 approval (should fail)
     println!("\n[Step 4] Trying to execute with 1/2 approvals");
-    let execute_transfer_ix = build_execute_transfer_proposal_ix(
+    let execute_transfer_ix = multisig_ix::execute_transfer_proposal(
         &alice.pubkey(),
         &multisig,
         &transfer_proposal,
@@ -1299,7 +1019,7 @@ fn test_threshold_enforcement() {
         &recipient.pubkey(),
     );
 
-    let failed_execute_ix = build_execute_transfer_proposal_ix(
+    let failed_execute_ix = multisig_ix::execute_transfer_proposal(
     &alice.pubkey(),
     &multisig,
     &transfer_proposal,
@@ -1310,15 +1030,14 @@ fn test_threshold_enforcement() {
 
     let failed_execute_ix = add_unique_meta(failed_execute_ix);
 
-    let error = send_tx_expect_failure(&mut svm, failed_execute_ix, &alice, &[&alice]);
-    assert!(
-        error.contains("InsufficientApprovals") || error.contains("6306"),
-        "Should fail with InsufficientApprovals"
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[failed_execute_ix], &alice, &[&alice]),
+        MultisigErrorCode::InsufficientApprovals
     );
     println!("[Step 4] Execution blocked (need 2 approvals)");
 
     // Bob approves
-    let approve_ix = build_approve_transfer_proposal_ix(
+    let approve_ix = multisig_ix::approve_transfer_proposal(
         &bob.pubkey(),
         &multisig,
         &transfer_proposal,
@@ -1327,7 +1046,7 @@ fn test_threshold_enforcement() {
     println!("[Step 5] Bob approved (2/2 approvals)");
 
     // Execute should work now
-    let execute_transfer_ix = build_execute_transfer_proposal_ix(
+    let execute_transfer_ix = multisig_ix::execute_transfer_proposal(
         &alice.pubkey(),
         &multisig,
         &transfer_proposal,
@@ -1380,7 +1099,7 @@ fn test_double_approval_prevention() {
     // Create proposal (Alice auto-approves)
     let charlie = Keypair::new();
     let proposal_id = 1u64;
-    let (proposal, _) = derive_proposal_pda(&multisig, proposal_id);
+    let (proposal, _) = proposal_address(&multisig, proposal_id);
 
     let add_charlie_ix = build_create_add_member_proposal_ix(
         &alice.pubkey(),
@@ -1394,11 +1113,10 @@ fn test_double_approval_prevention() {
 
     // Alice tries to approve again (should fail)
     println!("\n[Step 3] Alice tries to approve again");
-    let approve_ix = build_approve_proposal_ix(&alice.pubkey(), &multisig, &proposal);
-    let error = send_tx_expect_failure(&mut svm, approve_ix, &alice, &[&alice]);
-    assert!(
-        error.contains("AlreadyApproved") || error.contains("6303"),
-        "Should fail with AlreadyApproved"
+    let approve_ix = multisig_ix::approve_proposal(&alice.pubkey(), &multisig, &proposal);
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[approve_ix], &alice, &[&alice]),
+        MultisigErrorCode::AlreadyApproved
     );
     println!("[Step 3] Double approval blocked");
 
@@ -1454,7 +1172,7 @@ fn test_role_based_access_control() {
     println!("\n[Step 2] Charlie (Executor) tries to create proposal");
     let dave = Keypair::new();
     let proposal_id = 2u64;
-    let (proposal, _) = derive_proposal_pda(&multisig, proposal_id);
+    let (proposal, _) = proposal_address(&multisig, proposal_id);
 
     let create_proposal_ix = build_create_add_member_proposal_ix(
         &charlie.pubkey(),
@@ -1463,10 +1181,9 @@ fn test_role_based_access_control() {
         &dave.pubkey(),
         MemberRole::Executor,
     );
-    let error = send_tx_expect_failure(&mut svm, create_proposal_ix, &charlie, &[&charlie]);
-    assert!(
-        error.contains("CannotPropose") || error.contains("6305"),
-        "Executor should not be able to propose"
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[create_proposal_ix], &charlie, &[&charlie]),
+        MultisigErrorCode::CannotPropose
     );
     println!("[Step 2] Executor cannot propose (as expected)");
 
@@ -1475,9 +1192,9 @@ fn test_role_based_access_control() {
     let recipient = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
 
     let transfer_proposal_id = 2u64;
-    let (transfer_proposal, _) = derive_transfer_proposal_pda(&multisig, transfer_proposal_id);
+    let (transfer_proposal, _) = transfer_proposal_address(&multisig, transfer_proposal_id);
 
-    let create_transfer_ix = build_create_transfer_proposal_ix(
+    let create_transfer_ix = multisig_ix::create_transfer_proposal(
         &alice.pubkey(),
         &multisig,
         &transfer_proposal,
@@ -1490,7 +1207,7 @@ fn test_role_based_access_control() {
 
     // Test: Proposer cannot execute
     println!("\n[Step 4] Bob (Proposer) tries to execute");
-    let execute_transfer_ix = build_execute_transfer_proposal_ix(
+    let execute_transfer_ix = multisig_ix::execute_transfer_proposal(
         &bob.pubkey(),
         &multisig,
         &transfer_proposal,
@@ -1498,16 +1215,15 @@ fn test_role_based_access_control() {
         &vault,
         &recipient.pubkey(),
     );
-    let error = send_tx_expect_failure(&mut svm, execute_transfer_ix, &bob, &[&bob]);
-    assert!(
-        error.contains("CannotExecute") || error.contains("6306"),
-        "Proposer should not be able to execute"
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[execute_transfer_ix], &bob, &[&bob]),
+        MultisigErrorCode::CannotExecute
     );
     println!("[Step 4] Proposer cannot execute (as expected)");
 
     // Test: Executor can execute
     println!("\n[Step 5] Charlie (Executor) executes");
-    let execute_transfer_ix = build_execute_transfer_proposal_ix(
+    let execute_transfer_ix = multisig_ix::execute_transfer_proposal(
         &charlie.pubkey(),
         &multisig,
         &transfer_proposal,
@@ -1540,7 +1256,7 @@ fn test_non_member_cannot_approve() {
 
     // Create a proposal
     let proposal_id = 0u64;
-    let (proposal, _) = derive_proposal_pda(&multisig, proposal_id);
+    let (proposal, _) = proposal_address(&multisig, proposal_id);
 
     let add_bob_ix = build_create_add_member_proposal_ix(
         &alice.pubkey(),
@@ -1554,11 +1270,10 @@ fn test_non_member_cannot_approve() {
 
     // Outsider tries to approve
     println!("\n[Step 2] Outsider tries to approve");
-    let approve_ix = build_approve_proposal_ix(&outsider.pubkey(), &multisig, &proposal);
-    let error = send_tx_expect_failure(&mut svm, approve_ix, &outsider, &[&outsider]);
-    assert!(
-        error.contains("NotAMember") || error.contains("6300"),
-        "Non-member should not be able to approve"
+    let approve_ix = multisig_ix::approve_proposal(&outsider.pubkey(), &multisig, &proposal);
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[approve_ix], &outsider, &[&outsider]),
+        MultisigErrorCode::NotAMember
     );
     println!("[Step 2] Non-member approval blocked");
 
@@ -1602,7 +1317,7 @@ fn test_cannot_remove_creator() {
     println!("[Step 1] Bob added");
 
     let proposal_id = 1u64;
-    let (proposal, _) = derive_proposal_pda(&multisig, proposal_id);
+    let (proposal, _) = proposal_address(&multisig, proposal_id);
 
     println!("\n[Step 2] Attempting to create proposal to remove creator");
 
@@ -1614,17 +1329,9 @@ fn test_cannot_remove_creator() {
     );
 
     // Proposal creation MUST FAIL
-    let error = send_tx_expect_failure(
-        &mut svm,
-        remove_alice_ix,
-        &alice,
-        &[&alice],
-    );
-
-    assert!(
-        error.contains("CannotRemoveCreator") || error.contains("6002"),
-        "Expected CannotRemoveCreator error, got: {}",
-        error
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[remove_alice_ix], &alice, &[&alice]),
+        MultisigErrorCode::CannotRemoveCreator
     );
 
     println!("[Step 2] Creator removal proposal correctly blocked");
@@ -1638,3 +1345,1641 @@ fn test_cannot_remove_creator() {
 
 
 
+
+// ======================== NESTED MULTISIG TESTS ========================
+
+/// Build create_proposal instruction (ApproveParentProposal variant)
+fn build_create_approve_parent_proposal_ix(
+    proposer: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+    parent_multisig: &Pubkey,
+    parent_proposal_id: u64,
+) -> Instruction {
+    multisig_ix::create_proposal(
+        proposer,
+        multisig,
+        proposal,
+        ProposalType::ApproveParentProposal {
+            parent_multisig: *parent_multisig,
+            parent_proposal_id,
+        },
+    )
+}
+
+/// Test 15: Child multisig approves a parent proposal
+///
+/// Scenario:
+///   - Parent (Alice) adds the child multisig PDA as a member, threshold becomes 2
+///   - Child (Carol + Dave, threshold 2) votes to approve parent proposal 2
+///   - approve_as_multisig is rejected until the child reaches its own threshold
+///   - The child's approval brings the parent proposal to 2/2 and it executes
+#[test]
+fn test_nested_multisig_approval() {
+    println!("\n=== TEST: Nested Multisig Approval ===\n");
+
+    let mut svm = setup_svm();
+
+    let alice = create_funded_account(&mut svm, 20 * LAMPORTS_PER_SOL);
+    let bob = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let carol = create_funded_account(&mut svm, 20 * LAMPORTS_PER_SOL);
+    let dave = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let timelock = 5u64;
+    let (parent, _) = create_basic_multisig(&mut svm, &alice, 1, timelock);
+    let (child, _) = create_basic_multisig(&mut svm, &carol, 1, timelock);
+    println!("[Setup] Parent multisig (Alice): {}", parent);
+    println!("[Setup] Child multisig (Carol): {}", child);
+
+    // Child: add Dave and require both Carol and Dave (proposals 0-1)
+    let dave_key = dave.pubkey();
+    add_member_to_multisig(&mut svm, &carol, &child, &dave_key, MemberRole::Executor, 0, timelock);
+    let (child_threshold, _) = proposal_address(&child, 1);
+    let ix =
+        build_create_change_threshold_proposal_ix(&carol.pubkey(), &child, &child_threshold, 2);
+    send_tx_expect_success(&mut svm, ix, &carol, &[&carol]);
+    advance_time(&mut svm, timelock + 1);
+    let ix =
+        multisig_ix::execute_proposal(&carol.pubkey(), &child, &child_threshold, &carol.pubkey());
+    send_tx_expect_success(&mut svm, ix, &carol, &[&carol]);
+    println!("[Step 1] Child multisig is 2-of-2");
+
+    // Parent: the child PDA joins as a member, then threshold becomes 2 (proposals 0-1)
+    add_member_to_multisig(&mut svm, &alice, &parent, &child, MemberRole::Executor, 0, timelock);
+    let (parent_threshold, _) = proposal_address(&parent, 1);
+    let ix =
+        build_create_change_threshold_proposal_ix(&alice.pubkey(), &parent, &parent_threshold, 2);
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    advance_time(&mut svm, timelock + 1);
+    let ix = multisig_ix::execute_proposal(
+        &alice.pubkey(),
+        &parent,
+        &parent_threshold,
+        &alice.pubkey(),
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    println!("[Step 2] Child multisig added to parent, parent is 2-of-2");
+
+    // Parent proposal 2: add Bob (Alice auto-approves, 1/2)
+    let (parent_proposal, _) = proposal_address(&parent, 2);
+    let ix = build_create_add_member_proposal_ix(
+        &alice.pubkey(),
+        &parent,
+        &parent_proposal,
+        &bob.pubkey(),
+        MemberRole::Proposer,
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    println!("[Step 3] Parent proposal 2 created (1/2 approvals)");
+
+    // Child proposal 2: approve parent proposal 2 (Carol auto-approves, 1/2)
+    let (child_proposal, _) = proposal_address(&child, 2);
+    let ix = build_create_approve_parent_proposal_ix(
+        &carol.pubkey(),
+        &child,
+        &child_proposal,
+        &parent,
+        2,
+    );
+    send_tx_expect_success(&mut svm, ix, &carol, &[&carol]);
+    advance_time(&mut svm, timelock + 1);
+
+    println!("\n[Step 4] Carol submits the child approval before Dave has voted");
+    let ix = multisig_ix::approve_as_multisig(
+        &carol.pubkey(),
+        &child,
+        &child_proposal,
+        &carol.pubkey(),
+        &parent,
+        &parent_proposal,
+    );
+    let error = send_tx_expect_failure(&mut svm, ix, &carol, &[&carol]);
+    assert!(
+        error.contains("InsufficientApprovals"),
+        "Expected InsufficientApprovals error, got: {}",
+        error
+    );
+    println!("[Step 4] Rejected: child threshold not met");
+
+    println!("\n[Step 5] Dave approves, child approval is submitted again");
+    let ix = multisig_ix::approve_proposal(&dave.pubkey(), &child, &child_proposal);
+    send_tx_expect_success(&mut svm, ix, &dave, &[&dave]);
+    let ix = multisig_ix::approve_as_multisig(
+        &carol.pubkey(),
+        &child,
+        &child_proposal,
+        &carol.pubkey(),
+        &parent,
+        &parent_proposal,
+    );
+    send_tx_expect_success(&mut svm, ix, &carol, &[&carol]);
+
+    let proposal_account =
+        svm.get_account(&parent_proposal).expect("Parent proposal should exist");
+    let state = Proposal::try_from_bytes(&proposal_account.data).expect("Proposal should decode");
+    assert_eq!(state.approval_count, 2);
+    assert!(
+        svm.get_account(&child_proposal).map_or(true, |account| account.lamports == 0),
+        "Child proposal should be closed once executed"
+    );
+    println!("[Step 5] Parent proposal 2 has 2/2 approvals, child proposal closed");
+
+    println!("\n[Step 6] Executing parent proposal 2");
+    advance_time(&mut svm, timelock + 1);
+    let ix =
+        multisig_ix::execute_proposal(&alice.pubkey(), &parent, &parent_proposal, &alice.pubkey());
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+
+    let multisig_account = svm.get_account(&parent).expect("Parent multisig should exist");
+    let state = Multisig::try_from_bytes(&multisig_account.data).expect("Multisig should decode");
+    assert!(state.active_members().iter().any(|member| member.pubkey == bob.pubkey()));
+    println!("[Step 6] Bob added to parent multisig");
+
+    println!("\n=== PASSED: test_nested_multisig_approval ===\n");
+}
+
+/// Test 16: Child approval is bound to one parent proposal
+///
+/// Scenario:
+///   - Child (Carol, threshold 1) votes to approve parent proposal 1
+///   - Submitting that vote against parent proposal 2 is rejected
+///   - The vote cannot be executed through execute_proposal either
+///   - Rewriting the stored vote to target proposal 2 breaks its proposal hash
+#[test]
+fn test_nested_approval_bound_to_target() {
+    println!("\n=== TEST: Nested Approval Bound To Target ===\n");
+
+    let mut svm = setup_svm();
+
+    let alice = create_funded_account(&mut svm, 20 * LAMPORTS_PER_SOL);
+    let carol = create_funded_account(&mut svm, 20 * LAMPORTS_PER_SOL);
+
+    let timelock = 5u64;
+    let (parent, _) = create_basic_multisig(&mut svm, &alice, 1, timelock);
+    let (child, _) = create_basic_multisig(&mut svm, &carol, 1, timelock);
+    add_member_to_multisig(&mut svm, &alice, &parent, &child, MemberRole::Executor, 0, timelock);
+
+    // Parent proposals 1 (benign) and 2 (the one the child never voted on)
+    let (benign, _) = proposal_address(&parent, 1);
+    let ix = build_create_change_timelock_proposal_ix(&alice.pubkey(), &parent, &benign, 60);
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    let (other, _) = proposal_address(&parent, 2);
+    let ix = build_create_change_threshold_proposal_ix(&alice.pubkey(), &parent, &other, 2);
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+
+    let (child_proposal, _) = proposal_address(&child, 0);
+    let ix = build_create_approve_parent_proposal_ix(
+        &carol.pubkey(),
+        &child,
+        &child_proposal,
+        &parent,
+        1,
+    );
+    send_tx_expect_success(&mut svm, ix, &carol, &[&carol]);
+    advance_time(&mut svm, timelock + 1);
+    println!("[Step 1] Child voted to approve parent proposal 1");
+
+    println!("\n[Step 2] Submitting the child vote against parent proposal 2");
+    let ix = multisig_ix::approve_as_multisig(
+        &carol.pubkey(),
+        &child,
+        &child_proposal,
+        &carol.pubkey(),
+        &parent,
+        &other,
+    );
+    let error = send_tx_expect_failure(&mut svm, ix, &carol, &[&carol]);
+    assert!(
+        error.contains("ParentProposalMismatch"),
+        "Expected ParentProposalMismatch error, got: {}",
+        error
+    );
+    println!("[Step 2] Rejected: child vote targets a different proposal");
+
+    println!("\n[Step 3] Executing the child vote through execute_proposal");
+    let ix =
+        multisig_ix::execute_proposal(&carol.pubkey(), &child, &child_proposal, &carol.pubkey());
+    let error = send_tx_expect_failure(&mut svm, ix, &carol, &[&carol]);
+    assert!(
+        error.contains("InvalidProposalType"),
+        "Expected InvalidProposalType error, got: {}",
+        error
+    );
+    println!("[Step 3] Rejected: parent approvals only go through approve_as_multisig");
+
+    println!("\n[Step 4] parent_proposal_id is rewritten from 1 to 2 after the vote");
+    // discriminator (8) + multisig (32) + proposal_id (8) + proposer (32)
+    // + variant tag (1) + parent_multisig (32)
+    let parent_proposal_id_offset = 8 + 32 + 8 + 32 + 1 + 32;
+    let mut tampered = svm.get_account(&child_proposal).unwrap();
+    tampered.data[parent_proposal_id_offset..parent_proposal_id_offset + 8]
+        .copy_from_slice(&2u64.to_le_bytes());
+    svm.set_account(child_proposal, tampered).unwrap();
+
+    let ix = multisig_ix::approve_as_multisig(
+        &carol.pubkey(),
+        &child,
+        &child_proposal,
+        &carol.pubkey(),
+        &parent,
+        &other,
+    );
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[ix], &carol, &[&carol]),
+        MultisigErrorCode::ProposalHashMismatch
+    );
+    println!("[Step 4] Rejected: the vote no longer matches the hash the child approved");
+
+    println!("\n=== PASSED: test_nested_approval_bound_to_target ===\n");
+}
+
+// ======================== PROPOSAL RENT TESTS ========================
+
+/// Test 17: Vault pays proposal rent once the multisig enables it
+///
+/// Scenario:
+///   - Vault as rent_payer is rejected while vault_pays_rent is off
+///   - A SetVaultPaysRent proposal turns it on
+///   - The vault reimburses Alice for a new proposal's rent
+///   - Cancelling refunds the vault, not Alice
+#[test]
+fn test_vault_funded_proposal_rent() {
+    println!("\n=== TEST: Vault Funded Proposal Rent ===\n");
+
+    let mut svm = setup_svm();
+
+    let alice = create_funded_account(&mut svm, 20 * LAMPORTS_PER_SOL);
+    let timelock = 5u64;
+    let (multisig, vault) = create_basic_multisig(&mut svm, &alice, 1, timelock);
+    svm.airdrop(&vault, 2 * LAMPORTS_PER_SOL).unwrap();
+    println!("[Step 1] Multisig created, vault funded with 2 SOL");
+
+    println!("\n[Step 2] Using the vault as rent_payer before it is enabled");
+    let (proposal, _) = proposal_address(&multisig, 0);
+    let ix = multisig_ix::create_proposal_with_rent_payer(
+        &alice.pubkey(),
+        &multisig,
+        &proposal,
+        ProposalType::ChangeTimelock { new_timelock: 10 },
+        &vault,
+        false,
+    );
+    let error = send_tx_expect_failure(&mut svm, ix, &alice, &[&alice]);
+    assert!(
+        error.contains("VaultRentDisabled"),
+        "Expected VaultRentDisabled error, got: {}",
+        error
+    );
+    println!("[Step 2] Rejected: vault_pays_rent is off");
+
+    let ix = multisig_ix::create_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &proposal,
+        ProposalType::SetVaultPaysRent { enabled: true },
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    advance_time(&mut svm, timelock + 1);
+    let ix = multisig_ix::execute_proposal(&alice.pubkey(), &multisig, &proposal, &alice.pubkey());
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+
+    let multisig_account = svm.get_account(&multisig).unwrap();
+    let state = Multisig::try_from_bytes(&multisig_account.data).expect("Multisig should decode");
+    assert!(state.vault_pays_rent);
+    println!("[Step 3] SetVaultPaysRent executed, vault_pays_rent = true");
+
+    println!("\n[Step 4] Creating proposal 1 with the vault as rent_payer");
+    let vault_before = svm.get_account(&vault).unwrap().lamports;
+    let alice_before = svm.get_account(&alice.pubkey()).unwrap().lamports;
+    let (proposal, _) = proposal_address(&multisig, 1);
+    let ix = multisig_ix::create_proposal_with_rent_payer(
+        &alice.pubkey(),
+        &multisig,
+        &proposal,
+        ProposalType::ChangeTimelock { new_timelock: 10 },
+        &vault,
+        false,
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+
+    let proposal_account = svm.get_account(&proposal).unwrap();
+    let rent = proposal_account.lamports;
+    let state = Proposal::try_from_bytes(&proposal_account.data).expect("Proposal should decode");
+    assert_eq!(state.rent_payer, vault);
+    assert_eq!(svm.get_account(&vault).unwrap().lamports, vault_before - rent);
+    let alice_spent = alice_before - svm.get_account(&alice.pubkey()).unwrap().lamports;
+    assert!(alice_spent < rent, "Alice should only pay the fee, spent {}", alice_spent);
+    println!("[Step 4] Vault paid {} lamports of rent, Alice paid only the fee", rent);
+
+    println!("\n[Step 5] Cancelling with Alice as the refund account");
+    let ix = multisig_ix::cancel_proposal(&alice.pubkey(), &multisig, &proposal, &alice.pubkey());
+    let error = send_tx_expect_failure(&mut svm, ix, &alice, &[&alice]);
+    assert!(
+        error.contains("InvalidRentPayer"),
+        "Expected InvalidRentPayer error, got: {}",
+        error
+    );
+    println!("[Step 5] Rejected: the refund belongs to the vault");
+
+    let ix = multisig_ix::cancel_proposal(&alice.pubkey(), &multisig, &proposal, &vault);
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    assert_eq!(svm.get_account(&vault).unwrap().lamports, vault_before);
+    println!("[Step 6] Cancelled, rent refunded to the vault");
+
+    println!("\n=== PASSED: test_vault_funded_proposal_rent ===\n");
+}
+
+/// Test 18: A sponsor pays a transfer proposal's rent
+///
+/// Scenario:
+///   - A sponsor that does not sign cannot be used as rent_payer
+///   - With the sponsor's signature, it reimburses Alice
+///   - Executing the transfer refunds the rent to the sponsor
+#[test]
+fn test_sponsor_rent_payer() {
+    println!("\n=== TEST: Sponsor Rent Payer ===\n");
+
+    let mut svm = setup_svm();
+
+    let alice = create_funded_account(&mut svm, 20 * LAMPORTS_PER_SOL);
+    let sponsor = create_funded_account(&mut svm, 5 * LAMPORTS_PER_SOL);
+    let recipient = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+    let timelock = 5u64;
+    let (multisig, vault) = create_basic_multisig(&mut svm, &alice, 1, timelock);
+    svm.airdrop(&vault, 2 * LAMPORTS_PER_SOL).unwrap();
+
+    println!("[Step 1] Sponsor listed as rent_payer without signing");
+    let (transfer_proposal, _) = transfer_proposal_address(&multisig, 0);
+    let ix = multisig_ix::create_transfer_proposal_with_rent_payer(
+        &alice.pubkey(),
+        &multisig,
+        &transfer_proposal,
+        LAMPORTS_PER_SOL,
+        &recipient.pubkey(),
+        &sponsor.pubkey(),
+        false,
+    );
+    let error = send_tx_expect_failure(&mut svm, ix, &alice, &[&alice]);
+    assert!(
+        error.contains("InvalidRentPayer"),
+        "Expected InvalidRentPayer error, got: {}",
+        error
+    );
+    println!("[Step 1] Rejected: sponsor did not sign");
+
+    println!("\n[Step 2] Sponsor signs and pays the rent");
+    let sponsor_before = svm.get_account(&sponsor.pubkey()).unwrap().lamports;
+    let ix = multisig_ix::create_transfer_proposal_with_rent_payer(
+        &alice.pubkey(),
+        &multisig,
+        &transfer_proposal,
+        LAMPORTS_PER_SOL,
+        &recipient.pubkey(),
+        &sponsor.pubkey(),
+        true,
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice, &sponsor]);
+
+    let proposal_account = svm.get_account(&transfer_proposal).unwrap();
+    let rent = proposal_account.lamports;
+    let state = TransferProposal::try_from_bytes(&proposal_account.data)
+        .expect("TransferProposal should decode");
+    assert_eq!(state.rent_payer, sponsor.pubkey());
+    assert_eq!(svm.get_account(&sponsor.pubkey()).unwrap().lamports, sponsor_before - rent);
+    println!("[Step 2] Sponsor paid {} lamports of rent", rent);
+
+    advance_time(&mut svm, timelock + 1);
+    let ix = multisig_ix::execute_transfer_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &transfer_proposal,
+        &sponsor.pubkey(),
+        &vault,
+        &recipient.pubkey(),
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    assert_eq!(svm.get_account(&sponsor.pubkey()).unwrap().lamports, sponsor_before);
+    println!("[Step 3] Transfer executed, rent refunded to the sponsor");
+
+    println!("\n=== PASSED: test_sponsor_rent_payer ===\n");
+}
+
+/// Test 19: Expected outcome guard on transfers
+///
+/// Scenario:
+///   - Executor passes deltas that do not match the transfer -> OutcomeMismatch,
+///     nothing moves
+///   - Executor passes the simulated deltas -> transfer succeeds
+#[test]
+fn test_transfer_expected_outcome() {
+    println!("\n=== TEST: Transfer Expected Outcome ===\n");
+
+    let mut svm = setup_svm();
+
+    let alice = create_funded_account(&mut svm, 20 * LAMPORTS_PER_SOL);
+    let recipient = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+    let timelock = 5u64;
+    let (multisig, vault) = create_basic_multisig(&mut svm, &alice, 1, timelock);
+    svm.airdrop(&vault, 5 * LAMPORTS_PER_SOL).unwrap();
+
+    let transfer_amount = 2 * LAMPORTS_PER_SOL;
+    let (transfer_proposal, _) = transfer_proposal_address(&multisig, 0);
+    let ix = multisig_ix::create_transfer_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &transfer_proposal,
+        transfer_amount,
+        &recipient.pubkey(),
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    advance_time(&mut svm, timelock + 1);
+
+    let vault_before = svm.get_account(&vault).unwrap().lamports;
+    let recipient_before = svm.get_account(&recipient.pubkey()).unwrap().lamports;
+
+    println!("[Step 1] Execute with deltas that do not match the transfer");
+    let ix = multisig_ix::execute_transfer_proposal_with_expected_outcome(
+        &alice.pubkey(),
+        &multisig,
+        &transfer_proposal,
+        &alice.pubkey(),
+        &vault,
+        &recipient.pubkey(),
+        &ExpectedOutcome {
+            vault_delta: transfer_amount,
+            recipient_delta: transfer_amount / 2,
+        },
+    );
+    let error = send_tx_expect_failure(&mut svm, ix, &alice, &[&alice]);
+    assert!(
+        error.contains("OutcomeMismatch"),
+        "Expected OutcomeMismatch error, got: {}",
+        error
+    );
+    assert_eq!(svm.get_account(&vault).unwrap().lamports, vault_before);
+    println!("[Step 1] Rejected: recipient delta differs, transfer rolled back");
+
+    println!("\n[Step 2] Execute with the simulated deltas");
+    let ix = multisig_ix::execute_transfer_proposal_with_expected_outcome(
+        &alice.pubkey(),
+        &multisig,
+        &transfer_proposal,
+        &alice.pubkey(),
+        &vault,
+        &recipient.pubkey(),
+        &ExpectedOutcome {
+            vault_delta: transfer_amount,
+            recipient_delta: transfer_amount,
+        },
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    assert_eq!(svm.get_account(&vault).unwrap().lamports, vault_before - transfer_amount);
+    assert_eq!(
+        svm.get_account(&recipient.pubkey()).unwrap().lamports,
+        recipient_before + transfer_amount
+    );
+    println!("[Step 2] Transfer executed, deltas verified on-chain");
+
+    println!("\n=== PASSED: test_transfer_expected_outcome ===\n");
+}
+
+// ======================== LARGE MULTISIG TESTS ========================
+
+/// Test 20: Zero-copy multisig roster
+///
+/// Scenario: Alice fills the initial 16-slot slab, grows it, then raises the
+/// threshold so further additions need a co-signing member
+/// Verifies: MemberSlabFull at capacity, admin-only grow, co-signer counting
+#[test]
+fn test_large_multisig_roster() {
+    println!("\n=== TEST: Large Multisig Roster ===\n");
+
+    let mut svm = setup_svm();
+    let alice = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let bob = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+    let (multisig_large, _) = multisig_large_address(&alice.pubkey(), 1);
+
+    let ix = multisig_ix::create_multisig_large(&alice.pubkey(), &multisig_large, 1, 60);
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+
+    let decode = |svm: &LiteSVM| {
+        MultisigLarge::try_from_bytes(&svm.get_account(&multisig_large).unwrap().data)
+            .expect("MultisigLarge should decode")
+    };
+    let multisig = decode(&svm);
+    assert_eq!(multisig.member_capacity, 16);
+    assert_eq!(multisig.threshold, 1);
+    assert_eq!(multisig.members[0].pubkey, alice.pubkey());
+    assert_eq!(multisig.members[0].role, MemberRole::Admin);
+    println!("[Step 1] Created with Alice in slot 0 of 16");
+
+    println!("\n[Step 2] Fill the slab (threshold 1: Alice alone signs)");
+    let mut new_members = vec![bob.pubkey()];
+    new_members.extend((0..14).map(|_| Pubkey::new_unique()));
+    for member in &new_members {
+        let ix = multisig_ix::add_large_member(
+            &alice.pubkey(),
+            &multisig_large,
+            &[],
+            member,
+            MemberRole::Proposer,
+            1,
+        );
+        send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    }
+    assert_eq!(decode(&svm).member_count, 16);
+
+    let ix = multisig_ix::add_large_member(
+        &alice.pubkey(),
+        &multisig_large,
+        &[],
+        &Pubkey::new_unique(),
+        MemberRole::Proposer,
+        1,
+    );
+    let error = send_tx_expect_failure(&mut svm, ix, &alice, &[&alice]);
+    assert!(error.contains("MemberSlabFull"), "Expected MemberSlabFull, got: {}", error);
+    println!("[Step 2] 17th member rejected: slab full");
+
+    println!("\n[Step 3] Grow the slab");
+    let ix = multisig_ix::grow_multisig_large(&bob.pubkey(), &multisig_large);
+    let error = send_tx_expect_failure(&mut svm, ix, &bob, &[&bob]);
+    assert!(error.contains("OnlyAdmin"), "Expected OnlyAdmin, got: {}", error);
+
+    let ix = multisig_ix::grow_multisig_large(&alice.pubkey(), &multisig_large);
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    let account = svm.get_account(&multisig_large).unwrap();
+    assert_eq!(account.data.len(), MultisigLarge::HEADER_SPACE + 32 * 33);
+    assert!(account.lamports >= svm.minimum_balance_for_rent_exemption(account.data.len()));
+    assert_eq!(decode(&svm).member_capacity, 32);
+    println!("[Step 3] Capacity 32, rent topped up by Alice");
+
+    println!("\n[Step 4] Add a member and raise the threshold to 2");
+    let ix = multisig_ix::add_large_member(
+        &alice.pubkey(),
+        &multisig_large,
+        &[],
+        &Pubkey::new_unique(),
+        MemberRole::Executor,
+        2,
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    assert_eq!(decode(&svm).threshold, 2);
+
+    println!("\n[Step 5] Alice alone or with a non-member co-signer is not enough");
+    let outsider = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+    let next_member = Pubkey::new_unique();
+    let ix = multisig_ix::add_large_member(
+        &alice.pubkey(),
+        &multisig_large,
+        &[outsider.pubkey()],
+        &next_member,
+        MemberRole::Executor,
+        2,
+    );
+    let error = send_tx_expect_failure(&mut svm, ix, &alice, &[&alice, &outsider]);
+    assert!(
+        error.contains("InsufficientApprovals"),
+        "Expected InsufficientApprovals, got: {}",
+        error
+    );
+
+    let ix = multisig_ix::add_large_member(
+        &alice.pubkey(),
+        &multisig_large,
+        &[bob.pubkey()],
+        &next_member,
+        MemberRole::Executor,
+        2,
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice, &bob]);
+    let multisig = decode(&svm);
+    assert_eq!(multisig.member_count, 18);
+    assert_eq!(multisig.members[17].pubkey, next_member);
+    println!("[Step 5] Bob co-signed, member 18 added");
+
+    println!("\n=== PASSED: test_large_multisig_roster ===\n");
+}
+
+// ======================== VAULT STAKE TESTS ========================
+
+const VOTE_PROGRAM_ID: Pubkey = pubkey!("Vote111111111111111111111111111111111111111");
+
+// VoteState account size
+const VOTE_ACCOUNT_SPACE: u64 = 3762;
+
+/// Create and initialize a vote account with `node` as identity, voter and withdrawer
+fn create_vote_account(svm: &mut LiteSVM, node: &Keypair) -> Pubkey {
+    let vote_account = Keypair::new();
+    let create_ix = solana_system_interface::instruction::create_account(
+        &node.pubkey(),
+        &vote_account.pubkey(),
+        svm.minimum_balance_for_rent_exemption(VOTE_ACCOUNT_SPACE as usize),
+        VOTE_ACCOUNT_SPACE,
+        &VOTE_PROGRAM_ID,
+    );
+
+    // VoteInstruction::InitializeAccount(VoteInit), bincode encoded
+    let mut data = 0u32.to_le_bytes().to_vec();
+    data.extend_from_slice(node.pubkey().as_ref()); // node_pubkey
+    data.extend_from_slice(node.pubkey().as_ref()); // authorized_voter
+    data.extend_from_slice(node.pubkey().as_ref()); // authorized_withdrawer
+    data.push(10); // commission
+    let init_ix = Instruction {
+        program_id: VOTE_PROGRAM_ID,
+        accounts: vec![
+            solana_sdk::instruction::AccountMeta::new(vote_account.pubkey(), false),
+            solana_sdk::instruction::AccountMeta::new_readonly(SYSVAR_RENT_ID, false),
+            solana_sdk::instruction::AccountMeta::new_readonly(SYSVAR_CLOCK_ID, false),
+            solana_sdk::instruction::AccountMeta::new_readonly(node.pubkey(), true),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix, init_ix],
+        Some(&node.pubkey()),
+        &[node, &vote_account],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).expect("Vote account creation should succeed");
+    vote_account.pubkey()
+}
+
+/// Test 21: Vault stake delegation lifecycle
+///
+/// Scenario: Alice's multisig stakes 2 SOL of idle vault SOL with a validator,
+/// then deactivates the stake and withdraws it back to the vault
+/// Verifies: stake account is a vault-controlled PDA, proposals must go through
+/// execute_stake_proposal, wrong accounts are rejected, funds return to the vault
+#[test]
+fn test_vault_stake_delegation() {
+    println!("\n=== TEST: Vault Stake Delegation ===\n");
+
+    let mut svm = setup_svm();
+    let alice = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let validator = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let vote_account = create_vote_account(&mut svm, &validator);
+
+    let timelock = 5u64;
+    let (multisig, vault) = create_basic_multisig(&mut svm, &alice, 1, timelock);
+    svm.airdrop(&vault, 10 * LAMPORTS_PER_SOL).unwrap();
+    let vault_before = svm.get_account(&vault).unwrap().lamports;
+
+    println!("[Step 1] Propose delegating 2 SOL");
+    let amount = 2 * LAMPORTS_PER_SOL;
+    let (proposal, _) = proposal_address(&multisig, 0);
+    let ix = multisig_ix::create_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &proposal,
+        ProposalType::DelegateVaultStake {
+            vote_account,
+            amount,
+        },
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    advance_time(&mut svm, timelock + 1);
+
+    let ix = multisig_ix::execute_proposal(&alice.pubkey(), &multisig, &proposal, &alice.pubkey());
+    let error = send_tx_expect_failure(&mut svm, ix, &alice, &[&alice]);
+    assert!(
+        error.contains("InvalidProposalType"),
+        "Expected InvalidProposalType, got: {}",
+        error
+    );
+    println!("[Step 1] execute_proposal refuses stake proposals");
+
+    println!("\n[Step 2] Execute with a stake account other than the proposal's PDA");
+    let (stake_account, _) = vault_stake_address(&multisig, 0);
+    let (wrong_stake, _) = vault_stake_address(&multisig, 1);
+    let ix = multisig_ix::execute_stake_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &proposal,
+        &alice.pubkey(),
+        &vault,
+        &wrong_stake,
+        Some(&vote_account),
+    );
+    let error = send_tx_expect_failure(&mut svm, ix, &alice, &[&alice]);
+    assert!(
+        error.contains("InvalidStakeAccount"),
+        "Expected InvalidStakeAccount, got: {}",
+        error
+    );
+
+    println!("\n[Step 3] Execute the delegation");
+    let ix = multisig_ix::execute_stake_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &proposal,
+        &alice.pubkey(),
+        &vault,
+        &stake_account,
+        Some(&vote_account),
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+
+    let stake = svm.get_account(&stake_account).expect("Stake account should exist");
+    let stake_rent = svm.minimum_balance_for_rent_exemption(stake.data.len());
+    assert_eq!(stake.owner, STAKE_PROGRAM_ID);
+    assert_eq!(stake.lamports, amount + stake_rent);
+    assert_eq!(
+        svm.get_account(&vault).unwrap().lamports,
+        vault_before - amount - stake_rent
+    );
+    println!("[Step 3] Stake account funded from the vault: {} lamports", stake.lamports);
+
+    println!("\n[Step 4] Deactivate and withdraw back to the vault");
+    let stake_proposals = [
+        ProposalType::DeactivateVaultStake { stake_account },
+        ProposalType::WithdrawVaultStake { stake_account },
+    ];
+    for (proposal_id, proposal_type) in (1..).zip(stake_proposals) {
+        let (proposal, _) = proposal_address(&multisig, proposal_id);
+        let ix = multisig_ix::create_proposal(&alice.pubkey(), &multisig, &proposal, proposal_type);
+        send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+        advance_time(&mut svm, timelock + 1);
+
+        // Stake activated and deactivated in the same epoch never becomes
+        // effective, so it is withdrawable without waiting for a cooldown
+        let ix = multisig_ix::execute_stake_proposal(
+            &alice.pubkey(),
+            &multisig,
+            &proposal,
+            &alice.pubkey(),
+            &vault,
+            &stake_account,
+            None,
+        );
+        send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    }
+
+    assert!(svm
+        .get_account(&stake_account)
+        .map_or(true, |account| account.lamports == 0));
+    assert_eq!(svm.get_account(&vault).unwrap().lamports, vault_before);
+    println!("[Step 4] Stake account emptied, vault balance restored");
+
+    println!("\n=== PASSED: test_vault_stake_delegation ===\n");
+}
+
+// ======================== PROPOSAL METADATA TESTS ========================
+
+/// Test 22: Proposal title and memo
+///
+/// Scenario: Alice attaches a title and memo to her proposal after creating it
+/// Verifies: only the proposer can attach, the account grows by exactly the
+/// bytes stored and stays rent-exempt, title and memo bounds are enforced
+#[test]
+fn test_proposal_memo() {
+    println!("\n=== TEST: Proposal Memo ===\n");
+
+    let mut svm = setup_svm();
+
+    let alice = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let bob = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let timelock = 5u64;
+    let (multisig, _) = create_basic_multisig(&mut svm, &alice, 1, timelock);
+    add_member_to_multisig(&mut svm, &alice, &multisig, &bob.pubkey(), MemberRole::Proposer, 0, timelock);
+
+    let (proposal, _) = proposal_address(&multisig, 1);
+    let ix = multisig_ix::create_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &proposal,
+        ProposalType::ChangeTimelock { new_timelock: 10 },
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+
+    let proposal_account = svm.get_account(&proposal).unwrap();
+    let base_len = proposal_account.data.len();
+    let state = Proposal::try_from_bytes(&proposal_account.data).expect("Proposal should decode");
+    assert!(state.title.is_empty() && state.memo.is_empty());
+    println!("[Step 1] Proposal created without metadata ({} bytes)", base_len);
+
+    println!("\n[Step 2] Bob tries to describe Alice's proposal");
+    let ix = multisig_ix::attach_memo(&bob.pubkey(), &multisig, &proposal, Some("Hijacked"), "");
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[ix], &bob, &[&bob]),
+        MultisigErrorCode::NotProposer
+    );
+    println!("[Step 2] Rejected: only the proposer can attach a memo");
+
+    println!("\n[Step 3] Alice attaches a title and memo");
+    let title = "Raise timelock";
+    let memo = "Longer review window for treasury moves";
+    let ix = multisig_ix::attach_memo(&alice.pubkey(), &multisig, &proposal, Some(title), memo);
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+
+    let proposal_account = svm.get_account(&proposal).unwrap();
+    assert_eq!(proposal_account.data.len(), base_len + title.len() + memo.len());
+    assert!(
+        proposal_account.lamports
+            >= svm.minimum_balance_for_rent_exemption(proposal_account.data.len())
+    );
+    let state = Proposal::try_from_bytes(&proposal_account.data).expect("Proposal should decode");
+    assert_eq!(state.title, title);
+    assert_eq!(state.memo, memo);
+    println!("[Step 3] Account grew to {} bytes, still rent-exempt", proposal_account.data.len());
+
+    println!("\n[Step 4] Appending to the memo without a new title");
+    let ix = multisig_ix::attach_memo(&alice.pubkey(), &multisig, &proposal, None, " (see forum)");
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+
+    let proposal_account = svm.get_account(&proposal).unwrap();
+    let state = Proposal::try_from_bytes(&proposal_account.data).expect("Proposal should decode");
+    assert_eq!(state.title, title);
+    assert_eq!(state.memo, format!("{} (see forum)", memo));
+    println!("[Step 4] Memo appended, title kept");
+
+    println!("\n[Step 5] Exceeding the bounds");
+    let long_title = "t".repeat(MAX_PROPOSAL_TITLE_LEN + 1);
+    let ix = multisig_ix::attach_memo(&alice.pubkey(), &multisig, &proposal, Some(&long_title), "");
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[ix], &alice, &[&alice]),
+        MultisigErrorCode::TitleTooLong
+    );
+
+    let remaining = MAX_PROPOSAL_MEMO_LEN - state.memo.len();
+    let ix = multisig_ix::attach_memo(&alice.pubkey(), &multisig, &proposal, None, &"m".repeat(remaining + 1));
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[ix], &alice, &[&alice]),
+        MultisigErrorCode::MemoTooLong
+    );
+
+    let ix = multisig_ix::attach_memo(&alice.pubkey(), &multisig, &proposal, None, &"m".repeat(remaining));
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    let proposal_account = svm.get_account(&proposal).unwrap();
+    assert_eq!(
+        proposal_account.data.len(),
+        base_len + title.len() + MAX_PROPOSAL_MEMO_LEN
+    );
+    println!("[Step 5] Over-long title and memo rejected, memo filled to the bound");
+
+    println!("\n[Step 6] Cancelling returns the grown rent to the rent payer");
+    let rent = proposal_account.lamports;
+    let alice_before = svm.get_account(&alice.pubkey()).unwrap().lamports;
+    let ix = multisig_ix::cancel_proposal(&alice.pubkey(), &multisig, &proposal, &alice.pubkey());
+    // Bob pays the fee so Alice's balance moves by the refund alone
+    send_tx_expect_success(&mut svm, ix, &bob, &[&bob, &alice]);
+    assert!(svm.get_account(&proposal).map_or(true, |account| account.lamports == 0));
+    assert_eq!(svm.get_account(&alice.pubkey()).unwrap().lamports, alice_before + rent);
+    println!("[Step 6] Proposal closed, {} lamports refunded", rent);
+
+    println!("\n=== PASSED: test_proposal_memo ===\n");
+}
+
+// ======================== TOKEN SWEEP TESTS ========================
+
+/// Test 23: Sweeping tokens out of the vault's ATA
+///
+/// Scenario: 500 tokens of a mint were sent to the vault's associated token account,
+/// and Alice's multisig approves sweeping them to a treasury wallet
+/// Verifies: the sweep only runs through execute_sweep_proposal, the executor cannot
+/// swap in their own wallet, their own token account or another mint, the full balance reaches the treasury ATA
+#[test]
+fn test_vault_token_sweep() {
+    println!("\n=== TEST: Vault Token Sweep ===\n");
+
+    const STUCK_AMOUNT: u64 = 500_000_000;
+
+    let mut svm = setup_svm();
+    let alice = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let treasury = Keypair::new();
+
+    let timelock = 5u64;
+    let (multisig, vault) = create_basic_multisig(&mut svm, &alice, 1, timelock);
+
+    // Two mints land in vault ATAs; only the first is approved for the sweep
+    let mint = CreateMint::new(&mut svm, &alice).decimals(6).send().unwrap();
+    let other_mint = CreateMint::new(&mut svm, &alice).decimals(6).send().unwrap();
+    for mint in [mint, other_mint] {
+        let vault_ata = CreateAssociatedTokenAccount::new(&mut svm, &alice, &mint)
+            .owner(&vault)
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &alice, &mint, &vault_ata, STUCK_AMOUNT).send().unwrap();
+        CreateAssociatedTokenAccount::new(&mut svm, &alice, &mint)
+            .owner(&treasury.pubkey())
+            .send()
+            .unwrap();
+    }
+    // Alice's own ATA, the one a dishonest executor would redirect the sweep to
+    CreateAssociatedTokenAccount::new(&mut svm, &alice, &mint).send().unwrap();
+    let vault_ata = associated_token_address(&vault, &mint, &TOKEN_PROGRAM_ID);
+    let treasury_ata = associated_token_address(&treasury.pubkey(), &mint, &TOKEN_PROGRAM_ID);
+    let token_amount = |svm: &LiteSVM, address: &Pubkey| {
+        get_spl_account::<TokenAccount>(svm, address).unwrap().amount
+    };
+
+    println!("[Step 1] Propose sweeping the stuck tokens to the treasury");
+    let (proposal, _) = proposal_address(&multisig, 0);
+    let ix = multisig_ix::create_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &proposal,
+        ProposalType::SweepToken {
+            mint,
+            recipient: treasury.pubkey(),
+        },
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    advance_time(&mut svm, timelock + 1);
+
+    let ix = multisig_ix::execute_proposal(&alice.pubkey(), &multisig, &proposal, &alice.pubkey());
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[ix], &alice, &[&alice]),
+        MultisigErrorCode::InvalidProposalType
+    );
+    println!("[Step 1] execute_proposal refuses sweep proposals");
+
+    println!("\n[Step 2] Executor names their own wallet as the recipient");
+    let sweep_ix = |mint: &Pubkey, recipient: &Pubkey| {
+        multisig_ix::execute_sweep_proposal(
+            &alice.pubkey(),
+            &multisig,
+            &proposal,
+            &alice.pubkey(),
+            &vault,
+            mint,
+            recipient,
+            &TOKEN_PROGRAM_ID,
+        )
+    };
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[sweep_ix(&mint, &alice.pubkey())], &alice, &[&alice]),
+        MultisigErrorCode::InvalidRecipient
+    );
+
+    // The treasury is named, but the destination is swapped for Alice's ATA
+    let mut redirect_ix = sweep_ix(&mint, &treasury.pubkey());
+    redirect_ix.accounts[8].pubkey = associated_token_address(&alice.pubkey(), &mint, &TOKEN_PROGRAM_ID);
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[redirect_ix], &alice, &[&alice]),
+        MultisigErrorCode::InvalidRecipient
+    );
+
+    println!("\n[Step 3] Executor sweeps a mint the proposal does not name");
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[sweep_ix(&other_mint, &treasury.pubkey())], &alice, &[&alice]),
+        MultisigErrorCode::InvalidMint
+    );
+    println!("[Step 3] All three substitutions rejected");
+
+    println!("\n[Step 4] Execute the sweep");
+    send_tx_expect_success(&mut svm, sweep_ix(&mint, &treasury.pubkey()), &alice, &[&alice]);
+
+    assert_eq!(token_amount(&svm, &treasury_ata), STUCK_AMOUNT);
+    assert_eq!(token_amount(&svm, &vault_ata), 0);
+    let other_vault_ata = associated_token_address(&vault, &other_mint, &TOKEN_PROGRAM_ID);
+    assert_eq!(token_amount(&svm, &other_vault_ata), STUCK_AMOUNT);
+    assert!(svm.get_account(&proposal).map_or(true, |account| account.lamports == 0));
+    println!("[Step 4] {} tokens swept to the treasury, other mint untouched", STUCK_AMOUNT);
+
+    println!("\n=== PASSED: test_vault_token_sweep ===\n");
+}
+
+// ======================== PROPOSAL HASH TESTS ========================
+
+/// Test 24: Canonical proposal hash
+///
+/// Scenario: Alice proposes raising the threshold to 2; the proposal's parameters
+/// are then altered in place, as a bug in any instruction writing the account could
+/// Verifies: the stored hash matches the client's proposal_hash for the same action,
+/// execution rejects parameters that no longer hash to it, and the approved ones execute
+#[test]
+fn test_proposal_hash() {
+    println!("\n=== TEST: Proposal Hash ===\n");
+
+    let mut svm = setup_svm();
+
+    let alice = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let bob = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let timelock = 5u64;
+    let (multisig, _) = create_basic_multisig(&mut svm, &alice, 1, timelock);
+    add_member_to_multisig(&mut svm, &alice, &multisig, &bob.pubkey(), MemberRole::Admin, 0, timelock);
+
+    let (proposal, _) = proposal_address(&multisig, 1);
+    let action = ProposalType::ChangeThreshold { new_threshold: 2 };
+    let ix = multisig_ix::create_proposal(&alice.pubkey(), &multisig, &proposal, action);
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+
+    let approved = svm.get_account(&proposal).unwrap();
+    let state = Proposal::try_from_bytes(&approved.data).expect("Proposal should decode");
+    assert_eq!(state.proposal_hash, action.proposal_hash(&multisig, 1));
+    assert!(state.has_valid_hash());
+    println!("[Step 1] Stored hash matches the hash Bob's wallet computes for the action");
+
+    println!("\n[Step 2] new_threshold is rewritten from 2 to 1 after approval");
+    // discriminator (8) + multisig (32) + proposal_id (8) + proposer (32) + variant tag (1)
+    let new_threshold_offset = 8 + 32 + 8 + 32 + 1;
+    let mut tampered = approved.clone();
+    tampered.data[new_threshold_offset] = 1;
+    svm.set_account(proposal, tampered).unwrap();
+
+    advance_time(&mut svm, timelock + 2);
+    let ix = multisig_ix::execute_proposal(&alice.pubkey(), &multisig, &proposal, &alice.pubkey());
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[ix], &alice, &[&alice]),
+        MultisigErrorCode::ProposalHashMismatch
+    );
+    println!("[Step 2] Rejected: parameters no longer match the approved hash");
+
+    println!("\n[Step 3] The approved parameters execute");
+    svm.set_account(proposal, approved).unwrap();
+    let ix = multisig_ix::execute_proposal(&alice.pubkey(), &multisig, &proposal, &alice.pubkey());
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+
+    let multisig_account = svm.get_account(&multisig).unwrap();
+    let state = Multisig::try_from_bytes(&multisig_account.data).expect("Multisig should decode");
+    assert_eq!(state.threshold, 2);
+    println!("[Step 3] Threshold is now 2");
+
+    println!("\n=== PASSED: test_proposal_hash ===\n");
+}
+
+// ======================== DRY-RUN TESTS ========================
+
+/// Test 25: Read-only proposal validation
+///
+/// Scenario: A transfer proposal asks for more than the vault holds and is
+/// still inside its timelock. validate_proposal is run before and after both
+/// are fixed
+/// Verifies: every failing check is reported in execution order, the proposal
+/// and multisig accounts are untouched, and an empty result means execution succeeds
+#[test]
+fn test_validate_proposal() {
+    println!("\n=== TEST: Validate Proposal ===\n");
+
+    let mut svm = setup_svm();
+
+    let alice = create_funded_account(&mut svm, 20 * LAMPORTS_PER_SOL);
+    let outsider = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+    let recipient = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+    let timelock = 60u64;
+    let (multisig, vault) = create_basic_multisig(&mut svm, &alice, 1, timelock);
+    svm.airdrop(&vault, LAMPORTS_PER_SOL / 2).unwrap();
+
+    let transfer_amount = 2 * LAMPORTS_PER_SOL;
+    let (transfer_proposal, _) = transfer_proposal_address(&multisig, 0);
+    let ix = multisig_ix::create_transfer_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &transfer_proposal,
+        transfer_amount,
+        &recipient.pubkey(),
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+
+    let proposal_before = svm.get_account(&transfer_proposal).unwrap();
+    let multisig_before = svm.get_account(&multisig).unwrap();
+
+    println!("[Step 1] Validate for a non-member, inside the timelock, underfunded vault");
+    let ix = multisig_ix::validate_proposal(
+        &outsider.pubkey(),
+        &multisig,
+        &transfer_proposal,
+        &vault,
+        &recipient.pubkey(),
+    );
+    let meta = send_tx(&mut svm, &[ix], &outsider, &[&outsider]).expect("validate_proposal should not fail");
+    let validation = ProposalValidation::try_from_bytes(&meta.return_data.data)
+        .expect("return data should decode");
+    assert_eq!(
+        validation.errors,
+        vec![
+            u32::from(MultisigErrorCode::CannotExecute),
+            u32::from(MultisigErrorCode::TimelockNotPassed),
+            u32::from(MultisigErrorCode::InsufficientFunds),
+        ]
+    );
+    assert_eq!(svm.get_account(&transfer_proposal).unwrap().data, proposal_before.data);
+    assert_eq!(svm.get_account(&multisig).unwrap().data, multisig_before.data);
+    println!("[Step 1] Reported: CannotExecute, TimelockNotPassed, InsufficientFunds; no state changed");
+
+    println!("\n[Step 2] Validate for the admin after funding the vault and waiting out the timelock");
+    svm.airdrop(&vault, 2 * LAMPORTS_PER_SOL).unwrap();
+    advance_time(&mut svm, timelock + 1);
+    let ix = multisig_ix::validate_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &transfer_proposal,
+        &vault,
+        &recipient.pubkey(),
+    );
+    let meta = send_tx(&mut svm, &[ix], &alice, &[&alice]).expect("validate_proposal should not fail");
+    let validation = ProposalValidation::try_from_bytes(&meta.return_data.data)
+        .expect("return data should decode");
+    assert!(validation.is_executable(), "unexpected errors: {:?}", validation.errors);
+    println!("[Step 2] No failing checks");
+
+    println!("\n[Step 3] Execute as validated");
+    let ix = multisig_ix::execute_transfer_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &transfer_proposal,
+        &alice.pubkey(),
+        &vault,
+        &recipient.pubkey(),
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    println!("[Step 3] Transfer executed");
+
+    println!("\n=== PASSED: test_validate_proposal ===\n");
+}
+
+// ======================== RECIPIENT KIND TESTS ========================
+
+/// Test 26: Program vault recipients
+///
+/// Scenario: The vault pays another program's PDA vault.
+///   - A Wallet proposal to the PDA fails at execution (strict default)
+///   - A ProgramVault proposal naming the System Program is rejected at creation
+///   - A ProgramVault proposal naming the PDA's owner fails while the PDA is
+///     owned by a different program, and executes once ownership matches
+/// Verifies: program-owned recipients are only paid with the explicit opt-in,
+/// and only while owned by the program the signers approved
+#[test]
+fn test_program_vault_recipient() {
+    println!("\n=== TEST: Program Vault Recipient ===\n");
+
+    let mut svm = setup_svm();
+
+    let alice = create_funded_account(&mut svm, 20 * LAMPORTS_PER_SOL);
+    let timelock = 5u64;
+    let (multisig, vault) = create_basic_multisig(&mut svm, &alice, 1, timelock);
+    svm.airdrop(&vault, 5 * LAMPORTS_PER_SOL).unwrap();
+
+    // Off-curve PDA vault owned by some other program
+    let vault_program = Pubkey::new_unique();
+    let (program_vault, _) = Pubkey::find_program_address(&[b"vault"], &vault_program);
+    let rent_exempt_lamports = svm.minimum_balance_for_rent_exemption(0);
+    let program_vault_account = |owner: Pubkey| Account {
+        lamports: rent_exempt_lamports,
+        data: vec![],
+        owner,
+        executable: false,
+        rent_epoch: 0,
+    };
+    svm.set_account(program_vault, program_vault_account(vault_program)).unwrap();
+
+    let transfer_amount = LAMPORTS_PER_SOL;
+
+    println!("[Step 1] Wallet proposal to a program-owned account");
+    let (wallet_proposal, _) = transfer_proposal_address(&multisig, 0);
+    let ix = multisig_ix::create_transfer_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &wallet_proposal,
+        transfer_amount,
+        &program_vault,
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    advance_time(&mut svm, timelock + 1);
+
+    let ix = multisig_ix::execute_transfer_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &wallet_proposal,
+        &alice.pubkey(),
+        &vault,
+        &program_vault,
+    );
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[ix], &alice, &[&alice]),
+        MultisigErrorCode::InvalidRecipient
+    );
+    println!("[Step 1] Rejected: recipient is not system-owned");
+
+    println!("\n[Step 2] ProgramVault proposal naming the System Program");
+    let (vault_proposal, _) = transfer_proposal_address(&multisig, 1);
+    let ix = multisig_ix::create_program_vault_transfer_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &vault_proposal,
+        transfer_amount,
+        &program_vault,
+        &solana_system_interface::program::ID,
+    );
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[ix], &alice, &[&alice]),
+        MultisigErrorCode::InvalidRecipient
+    );
+    println!("[Step 2] Rejected: system-owned recipients use the Wallet path");
+
+    println!("\n[Step 3] ProgramVault proposal naming the PDA's owner");
+    let ix = multisig_ix::create_program_vault_transfer_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &vault_proposal,
+        transfer_amount,
+        &program_vault,
+        &vault_program,
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+
+    let proposal_account = svm.get_account(&vault_proposal).unwrap();
+    let proposal = TransferProposal::try_from_bytes(&proposal_account.data)
+        .expect("TransferProposal should decode");
+    assert_eq!(proposal.recipient_kind, RecipientKind::ProgramVault { owner: vault_program });
+    advance_time(&mut svm, timelock + 1);
+
+    // Same address, now owned by a program the signers never approved
+    svm.set_account(program_vault, program_vault_account(Pubkey::new_unique())).unwrap();
+    let execute_ix = multisig_ix::execute_transfer_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &vault_proposal,
+        &alice.pubkey(),
+        &vault,
+        &program_vault,
+    );
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[execute_ix.clone()], &alice, &[&alice]),
+        MultisigErrorCode::InvalidRecipient
+    );
+    println!("[Step 3] Rejected: recipient owned by a different program");
+
+    svm.set_account(program_vault, program_vault_account(vault_program)).unwrap();
+    svm.expire_blockhash();
+    let recipient_before = svm.get_account(&program_vault).unwrap().lamports;
+    send_tx_expect_success(&mut svm, execute_ix, &alice, &[&alice]);
+    assert_eq!(
+        svm.get_account(&program_vault).unwrap().lamports,
+        recipient_before + transfer_amount
+    );
+    println!("[Step 3] Transfer executed to the program vault");
+
+    println!("\n=== PASSED: test_program_vault_recipient ===\n");
+}
+
+// ======================== ADDRESS LOOKUP TABLE TESTS ========================
+
+/// Test 27: Vault-owned address lookup table
+///
+/// Scenario: Alice's multisig creates a lookup table for its vault, then approves
+/// adding three accounts it transacts with so vault transactions can
+/// reference them by index
+/// Verifies: the table is derived from the vault and must be the one passed, the
+/// vault is its authority and pays its rent, and only the approved address list
+/// (same addresses, same order) can be appended
+#[test]
+fn test_vault_lookup_table() {
+    println!("\n=== TEST: Vault Lookup Table ===\n");
+
+    // LookupTableMeta is 56 bytes; the authority Option sits at offset 21
+    const LOOKUP_TABLE_META_SIZE: usize = 56;
+
+    let mut svm = setup_svm();
+    let alice = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let timelock = 5u64;
+    let (multisig, vault) = create_basic_multisig(&mut svm, &alice, 1, timelock);
+    svm.airdrop(&vault, LAMPORTS_PER_SOL).unwrap();
+
+    // The lookup table program only accepts a slot still in SlotHashes
+    let recent_slot = 100u64;
+    svm.warp_to_slot(recent_slot + 1);
+    svm.set_sysvar(&SlotHashes::new(&[(recent_slot, Hash::default())]));
+    let (lookup_table, _) = vault_lookup_table_address(&vault, recent_slot);
+
+    println!("[Step 1] Propose creating a lookup table");
+    let (proposal, _) = proposal_address(&multisig, 0);
+    let ix = multisig_ix::create_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &proposal,
+        ProposalType::CreateLookupTable,
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    advance_time(&mut svm, timelock + 1);
+
+    let ix = multisig_ix::execute_proposal(&alice.pubkey(), &multisig, &proposal, &alice.pubkey());
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[ix], &alice, &[&alice]),
+        MultisigErrorCode::InvalidProposalType
+    );
+    println!("[Step 1] execute_proposal refuses lookup table proposals");
+
+    println!("\n[Step 2] Execute with a table the vault and slot do not derive");
+    let create_ix = |table: &Pubkey| {
+        multisig_ix::execute_lookup_table_proposal(
+            &alice.pubkey(),
+            &multisig,
+            &proposal,
+            &alice.pubkey(),
+            &vault,
+            table,
+            recent_slot,
+            &[],
+        )
+    };
+    let (wrong_table, _) = vault_lookup_table_address(&alice.pubkey(), recent_slot);
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[create_ix(&wrong_table)], &alice, &[&alice]),
+        MultisigErrorCode::InvalidLookupTable
+    );
+
+    println!("\n[Step 3] Create the table");
+    let vault_before = svm.get_account(&vault).unwrap().lamports;
+    send_tx_expect_success(&mut svm, create_ix(&lookup_table), &alice, &[&alice]);
+
+    let table = svm.get_account(&lookup_table).expect("Lookup table should exist");
+    assert_eq!(table.owner, ADDRESS_LOOKUP_TABLE_PROGRAM_ID);
+    assert_eq!(table.data.len(), LOOKUP_TABLE_META_SIZE);
+    assert_eq!(table.data[21], 1); // Some(authority)
+    assert_eq!(table.data[22..54], vault.to_bytes());
+    assert_eq!(
+        svm.get_account(&vault).unwrap().lamports,
+        vault_before - table.lamports
+    );
+    println!("[Step 3] Table {} created, vault is authority and payer", lookup_table);
+
+    println!("\n[Step 4] Propose extending the table with three addresses");
+    let addresses = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+    let (proposal, _) = proposal_address(&multisig, 1);
+    let ix = multisig_ix::create_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &proposal,
+        ProposalType::extend_lookup_table(lookup_table, &addresses),
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    advance_time(&mut svm, timelock + 1);
+
+    println!("\n[Step 5] Executor reorders, truncates or substitutes the addresses");
+    let extend_ix = |table: &Pubkey, addresses: &[Pubkey]| {
+        multisig_ix::execute_lookup_table_proposal(
+            &alice.pubkey(),
+            &multisig,
+            &proposal,
+            &alice.pubkey(),
+            &vault,
+            table,
+            0,
+            addresses,
+        )
+    };
+    let substituted = [addresses[0], addresses[1], alice.pubkey()];
+    for tampered in [
+        &[addresses[2], addresses[1], addresses[0]][..],
+        &addresses[..2],
+        &substituted[..],
+    ] {
+        assert_tx_err_code!(
+            send_tx(&mut svm, &[extend_ix(&lookup_table, tampered)], &alice, &[&alice]),
+            MultisigErrorCode::LookupTableAddressesMismatch
+        );
+    }
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[extend_ix(&wrong_table, &addresses)], &alice, &[&alice]),
+        MultisigErrorCode::InvalidLookupTable
+    );
+    println!("[Step 5] Every altered list rejected");
+
+    println!("\n[Step 6] Extend with the approved addresses");
+    send_tx_expect_success(&mut svm, extend_ix(&lookup_table, &addresses), &alice, &[&alice]);
+
+    let table = svm.get_account(&lookup_table).unwrap();
+    let stored: Vec<Pubkey> = table.data[LOOKUP_TABLE_META_SIZE..]
+        .chunks_exact(32)
+        .map(|chunk| Pubkey::try_from(chunk).unwrap())
+        .collect();
+    assert_eq!(stored, addresses);
+    assert!(svm.get_account(&proposal).map_or(true, |account| account.lamports == 0));
+    println!("[Step 6] Table now resolves {} addresses", stored.len());
+
+    println!("\n=== PASSED: test_vault_lookup_table ===\n");
+}
+
+/// Test 28: Per-action threshold policy
+///
+/// Scenario:
+///   - 2-of-3 multisig (Alice, Bob, Carol)
+///   - A policy entry above the owner count is rejected
+///   - Policy lets transfers up to 1 SOL clear with one approval
+///   - A 2 SOL transfer still needs the default threshold of 2
+///   - Removing a member that would strand a policy entry is rejected
+#[test]
+fn test_threshold_policy() {
+    println!("\n=== TEST: Threshold Policy ===\n");
+
+    let mut svm = setup_svm();
+
+    let alice = create_funded_account(&mut svm, 20 * LAMPORTS_PER_SOL);
+    let bob = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let carol = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let timelock = 5u64;
+    let (multisig, vault) = create_basic_multisig(&mut svm, &alice, 1, timelock);
+    svm.airdrop(&vault, 5 * LAMPORTS_PER_SOL).unwrap();
+
+    add_member_to_multisig(&mut svm, &alice, &multisig, &bob.pubkey(), MemberRole::Proposer, 0, timelock);
+    add_member_to_multisig(&mut svm, &alice, &multisig, &carol.pubkey(), MemberRole::Proposer, 1, timelock);
+
+    let (proposal, _) = proposal_address(&multisig, 2);
+    let ix = build_create_change_threshold_proposal_ix(&alice.pubkey(), &multisig, &proposal, 2);
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    advance_time(&mut svm, timelock + 1);
+    let ix = multisig_ix::execute_proposal(&alice.pubkey(), &multisig, &proposal, &alice.pubkey());
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    println!("[Step 1] 2-of-3 multisig ready");
+
+    println!("\n[Step 2] Propose a policy entry above the owner count");
+    let (proposal, _) = proposal_address(&multisig, 3);
+    let unreachable = ThresholdPolicy {
+        member_changes: 4,
+        ..ThresholdPolicy::default()
+    };
+    let ix = multisig_ix::create_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &proposal,
+        ProposalType::SetThresholdPolicy { policy: unreachable },
+    );
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[ix], &alice, &[&alice]),
+        MultisigErrorCode::InvalidThresholdPolicy
+    );
+    println!("[Step 2] Rejected: 3 owners cannot reach 4 approvals");
+
+    println!("\n[Step 3] Set a policy: member changes need 3, small transfers need 1");
+    let policy = ThresholdPolicy {
+        member_changes: 3,
+        small_transfers: 1,
+        small_transfer_limit: LAMPORTS_PER_SOL,
+        ..ThresholdPolicy::default()
+    };
+    let ix = multisig_ix::create_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &proposal,
+        ProposalType::SetThresholdPolicy { policy },
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+    let ix = multisig_ix::approve_proposal(&bob.pubkey(), &multisig, &proposal);
+    send_tx_expect_success(&mut svm, ix, &bob, &[&bob]);
+    advance_time(&mut svm, timelock + 1);
+    let ix = multisig_ix::execute_proposal(&alice.pubkey(), &multisig, &proposal, &alice.pubkey());
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+
+    let multisig_account = svm.get_account(&multisig).unwrap();
+    let state = Multisig::try_from_bytes(&multisig_account.data).expect("Multisig should decode");
+    assert_eq!(state.threshold_policy, policy);
+    println!("[Step 3] Policy stored on the multisig");
+
+    let recipient = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+    let transfer = |svm: &mut LiteSVM, proposal_id: u64, amount: u64| {
+        let (transfer_proposal, _) = transfer_proposal_address(&multisig, proposal_id);
+        let ix = multisig_ix::create_transfer_proposal(
+            &alice.pubkey(),
+            &multisig,
+            &transfer_proposal,
+            amount,
+            &recipient.pubkey(),
+        );
+        send_tx_expect_success(svm, ix, &alice, &[&alice]);
+        advance_time(svm, timelock + 1);
+        let ix = multisig_ix::execute_transfer_proposal(
+            &alice.pubkey(),
+            &multisig,
+            &transfer_proposal,
+            &alice.pubkey(),
+            &vault,
+            &recipient.pubkey(),
+        );
+        send_tx(svm, &[ix], &alice, &[&alice])
+    };
+
+    println!("\n[Step 4] 1 SOL transfer with Alice's approval only");
+    let recipient_before = svm.get_account(&recipient.pubkey()).unwrap().lamports;
+    assert!(transfer(&mut svm, 4, LAMPORTS_PER_SOL).is_ok());
+    assert_eq!(
+        svm.get_account(&recipient.pubkey()).unwrap().lamports,
+        recipient_before + LAMPORTS_PER_SOL
+    );
+    println!("[Step 4] Executed with 1/3 approvals");
+
+    println!("\n[Step 5] 2 SOL transfer with Alice's approval only");
+    assert_tx_err_code!(
+        transfer(&mut svm, 5, 2 * LAMPORTS_PER_SOL),
+        MultisigErrorCode::InsufficientApprovals
+    );
+    println!("[Step 5] Blocked: above the limit the default threshold of 2 applies");
+
+    println!("\n[Step 6] Propose removing Carol while member changes need 3");
+    let (proposal, _) = proposal_address(&multisig, 6);
+    let ix = build_create_remove_member_proposal_ix(&alice.pubkey(), &multisig, &proposal, &carol.pubkey());
+    assert_tx_err_code!(
+        send_tx(&mut svm, &[ix], &alice, &[&alice]),
+        MultisigErrorCode::InvalidThresholdPolicy
+    );
+    println!("[Step 6] Rejected: 2 remaining owners could never approve a member change");
+
+    println!("\n=== PASSED: test_threshold_policy ===\n");
+}
+
+/// Test 29: Proposal account size
+///
+/// The governance relay funds its executor with rent for exactly
+/// PROPOSAL_SPACE bytes before creating a proposal here, so a new
+/// ProposalType variant that grows the account must bump it too.
+///
+/// Scenario:
+///   - Program's Proposal::space(0, 0) equals the client's PROPOSAL_SPACE
+///   - A proposal with the largest variant (ExtendLookupTable) is created
+///     at exactly that size
+#[test]
+fn test_proposal_space() {
+    println!("\n=== TEST: Proposal Space ===\n");
+
+    assert_eq!(multisig_secure::state::Proposal::space(0, 0), PROPOSAL_SPACE);
+    println!("[Step 1] Program and client agree on {} bytes", PROPOSAL_SPACE);
+
+    let mut svm = setup_svm();
+    let alice = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let (multisig, _) = create_basic_multisig(&mut svm, &alice, 1, 5);
+
+    let (proposal, _) = proposal_address(&multisig, 0);
+    let ix = multisig_ix::create_proposal(
+        &alice.pubkey(),
+        &multisig,
+        &proposal,
+        ProposalType::ExtendLookupTable {
+            lookup_table: Pubkey::new_unique(),
+            addresses_hash: [7; 32],
+            address_count: MAX_LOOKUP_TABLE_EXTEND,
+        },
+    );
+    send_tx_expect_success(&mut svm, ix, &alice, &[&alice]);
+
+    let account = svm.get_account(&proposal).expect("Proposal should exist");
+    assert_eq!(account.data.len(), PROPOSAL_SPACE);
+    println!("[Step 2] ExtendLookupTable proposal created at {} bytes", account.data.len());
+
+    println!("\n=== PASSED: test_proposal_space ===\n");
+}
+
+// ======================== FEATURE MATRIX TESTS ========================
+
+// Non-admin member pauses the multisig (V012 in m-vulnerable)
+struct UnauthorizedPause;
+
+struct PauseState {
+    bob: Keypair,
+    multisig: Pubkey,
+}
+
+impl DifferentialScenario for UnauthorizedPause {
+    type State = PauseState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V012",
+            title: "Unrestricted Pause Permission",
+            severity: Severity::High,
+            lesson: "Only the multisig admin may toggle the pause flag",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, _program_id: &Pubkey) -> PauseState {
+        let alice = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let bob = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let (multisig, _) = create_basic_multisig(svm, &alice, 1, 5);
+        add_member_to_multisig(svm, &alice, &multisig, &bob.pubkey(), MemberRole::Proposer, 0, 5);
+
+        PauseState { bob, multisig }
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        state: &mut PauseState,
+    ) -> TransactionResult {
+        let pause_ix = multisig_ix::toggle_pause(&state.bob.pubkey(), &state.multisig);
+        send_tx(svm, &[pause_ix], &state.bob, &[&state.bob])
+    }
+}
+
+/// Test 30: Feature matrix - vuln-missing-admin-check
+///
+/// Requires ./build-vuln-matrix.sh multisig/m-secure from the repository root.
+///
+/// Scenario:
+///   - The baseline build rejects a Proposer's toggle_pause
+///   - The build with vuln-missing-admin-check accepts it
+#[test]
+fn test_feature_missing_admin_check() {
+    let variant = FeatureVariant {
+        program_id: PROGRAM_ID,
+        feature: "vuln-missing-admin-check",
+    };
+    let (mut baseline, mut variant_svm) = variant.load(
+        "target/deploy/multisig_secure.so",
+        variant.so_path(".", "multisig_secure"),
+    );
+    run_feature_differential(&mut baseline, &mut variant_svm, &variant, &UnauthorizedPause);
+}
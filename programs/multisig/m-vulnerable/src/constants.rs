@@ -22,3 +22,15 @@ pub const MAX_OWNERS: usize = 10;
 //
 // Fix: Implement strict expiry checks and consider shorter periods.
 pub const DEFAULT_EXPIRY_PERIOD: u64 = 7 * 24 * 60 * 60;
+
+// VULNERABILITY [MEDIUM]: Proposal metadata bounds never enforced
+//
+// These only size Proposal::INIT_SPACE. attach_memo accepts any length and
+// resizes the proposal to fit, so repeated calls grow it without limit.
+//
+// Fix: Reject titles and memos past these bounds before resizing.
+pub const MAX_PROPOSAL_TITLE_LEN: usize = 64;
+pub const MAX_PROPOSAL_MEMO_LEN: usize = 128;
+
+// Domain prefix of the proposal hash (see Proposal::proposal_hash)
+pub const PROPOSAL_HASH_DOMAIN: &[u8] = b"soteria:multisig:proposal:v1";
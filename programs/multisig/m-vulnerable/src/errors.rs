@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+pub use soteria_errors::m_vulnerable::MultisigError as MultisigErrorCode;
+
 
 #[error_code]
 pub enum MultisigError {
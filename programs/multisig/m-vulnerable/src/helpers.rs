@@ -0,0 +1,71 @@
+// Multisig Helper Functions - VULNERABLE VERSION
+//
+// Rent payer handling shared by create_proposal and create_transfer_proposal.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::{constants::*, errors::*, state::Multisig};
+
+// Reimburse the proposer for a new proposal account's rent
+//
+// Returns the account recorded as the proposal's rent payer.
+pub fn reimburse_proposal_rent<'info>(
+    rent_payer: Option<&UncheckedAccount<'info>>,
+    proposer: &Signer<'info>,
+    multisig_account: &Account<'info, Multisig>,
+    system_program: &Program<'info, System>,
+    rent: u64,
+) -> Result<Pubkey> {
+    let rent_payer = match rent_payer {
+        Some(rent_payer) if rent_payer.key() != proposer.key() => rent_payer,
+        _ => return Ok(proposer.key()),
+    };
+
+    if rent_payer.key() == multisig_account.vault {
+        // VULNERABILITY [HIGH]: Vault pays rent without the multisig's consent
+        //
+        // The secure version validates: multisig_account.vault_pays_rent
+        // Without it, any proposer can make the vault fund their proposals,
+        // whether or not the members ever enabled vault-funded rent.
+        //
+        // Example Attack:
+        //   1. Attacker creates a proposal with rent_payer = vault
+        //   2. The vault reimburses the attacker's rent
+        //   3. Attacker cancels the proposal, closing it to themselves (V010)
+        //   4. Attacker pockets the rent and repeats until the vault is empty
+        //
+        // Fix: require!(multisig_account.vault_pays_rent, MultisigError::VaultRentDisabled);
+        let multisig_key = multisig_account.key();
+        let vault_seeds = &[
+            VAULT,
+            multisig_key.as_ref(),
+            &[multisig_account.vault_bump],
+        ];
+        let signer_seeds = &[&vault_seeds[..]];
+
+        let cpi_context = CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Transfer {
+                from: rent_payer.to_account_info(),
+                to: proposer.to_account_info(),
+            },
+            signer_seeds,
+        );
+        transfer(cpi_context, rent)?;
+    } else {
+        // A sponsor must authorize spending its lamports
+        require!(rent_payer.is_signer, MultisigError::Unauthorized);
+
+        let cpi_context = CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: rent_payer.to_account_info(),
+                to: proposer.to_account_info(),
+            },
+        );
+        transfer(cpi_context, rent)?;
+    }
+
+    Ok(rent_payer.key())
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::*, constants::*};
+
+// Approve As Multisig Instruction - VULNERABLE VERSION
+//
+// Lets a child multisig (a PDA member) approve a parent proposal by executing
+// the child's ApproveParentProposal proposal, with a critical missing check.
+
+#[derive(Accounts)]
+pub struct ApproveAsMultisig<'info> {
+    // VULNERABILITY [CRITICAL]: No executor role validation
+    //
+    // Same gap as execute_proposal (V006): anyone can submit the child's vote.
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            MULTISIG,
+            child_multisig.creator.as_ref(),
+            &child_multisig.multisig_id.to_le_bytes(),
+        ],
+        bump = child_multisig.bump,
+    )]
+    pub child_multisig: Account<'info, Multisig>,
+
+    // VULNERABILITY [HIGH]: close = executor (V010)
+    //
+    // Rent from the consumed child proposal goes to whoever submits it.
+    #[account(
+        mut,
+        seeds = [
+            PROPOSAL,
+            child_multisig.key().as_ref(),
+            &child_proposal.proposal_id.to_le_bytes(),
+        ],
+        bump = child_proposal.bump,
+        close = executor, // VULNERABLE: Should be child proposer
+    )]
+    pub child_proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [
+            MULTISIG,
+            multisig_account.creator.as_ref(),
+            &multisig_account.multisig_id.to_le_bytes(),
+        ],
+        bump = multisig_account.bump,
+    )]
+    pub multisig_account: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [
+            PROPOSAL,
+            multisig_account.key().as_ref(),
+            &proposal.proposal_id.to_le_bytes(),
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+impl<'info> ApproveAsMultisig<'info> {
+    pub fn approve_as_multisig(&mut self) -> Result<()> {
+        // VULNERABILITY [CRITICAL]: Child vote not bound to this parent proposal
+        //
+        // The secure version validates:
+        //   parent_multisig == multisig_account.key()
+        //   parent_proposal_id == proposal.proposal_id
+        //
+        // Only the variant is checked here, so a child vote for one parent
+        // proposal can be spent on any other proposal the child is a member of.
+        //
+        // Example Attack:
+        //   1. Child multisig votes to approve harmless parent proposal #1
+        //   2. Attacker submits that vote with malicious parent proposal #2
+        //   3. Proposal #2 gets the child's approval bit and reaches threshold
+        //   4. The child's members never agreed to proposal #2
+        //
+        // Fix: require!(parent_multisig == self.multisig_account.key()
+        //          && parent_proposal_id == self.proposal.proposal_id,
+        //          MultisigError::ParentProposalMismatch);
+        require!(
+            matches!(
+                self.child_proposal.proposal_type,
+                ProposalType::ApproveParentProposal { .. }
+            ),
+            MultisigError::InvalidOperation
+        );
+
+        require!(
+            self.child_proposal.is_active(),
+            MultisigError::ProposalNotActive
+        );
+
+        // Pause, timelock and expiry checks are missing here too (V007, V003, V014)
+        require!(
+            self.child_proposal.approval_count >= self.child_multisig.threshold,
+            MultisigError::InsufficientApprovals
+        );
+
+        let owner_index = self
+            .multisig_account
+            .member_index(&self.child_multisig.key())
+            .ok_or(MultisigError::NotAMember)?;
+
+        require!(
+            self.proposal.approve(owner_index),
+            MultisigError::InvalidOperation
+        );
+
+        self.child_multisig.last_executed_proposal = self.child_proposal.proposal_id;
+        self.child_proposal.status = ProposalStatus::Executed;
+        self.child_proposal.executed_at = Clock::get()?.unix_timestamp;
+
+        Ok(())
+    }
+}
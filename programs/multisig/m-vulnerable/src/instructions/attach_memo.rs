@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::{state::*, errors::*, constants::*};
+
+// Attach Memo Instruction - VULNERABLE VERSION
+//
+// Lets the proposer set a title and append to the memo of an active proposal,
+// resizing the proposal account to fit.
+
+#[derive(Accounts)]
+pub struct AttachMemo<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            MULTISIG,
+            multisig_account.creator.as_ref(),
+            &multisig_account.multisig_id.to_le_bytes(),
+        ],
+        bump = multisig_account.bump,
+    )]
+    pub multisig_account: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [
+            PROPOSAL,
+            multisig_account.key().as_ref(),
+            &proposal.proposal_id.to_le_bytes(),
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AttachMemo<'info> {
+    pub fn attach_memo(&mut self, title: Option<String>, memo: String) -> Result<()> {
+        require!(
+            self.proposal.is_active(),
+            MultisigError::ProposalNotActive
+        );
+        require!(
+            self.proposer.key() == self.proposal.proposer,
+            MultisigError::Unauthorized
+        );
+
+        // VULNERABILITY [MEDIUM]: No bound on title or memo length
+        //
+        // The secure version requires:
+        // - title.len() <= MAX_PROPOSAL_TITLE_LEN
+        // - memo.len() + new memo length <= MAX_PROPOSAL_MEMO_LEN
+        //
+        // Without them, each call grows the proposal by whatever the proposer
+        // sends. Every instruction touching the proposal deserializes the whole
+        // memo onto the 32 KiB heap, so the account can be grown until the
+        // program runs out of memory loading it.
+        //
+        // Example Attack:
+        //   1. Proposer appends ~1 KB of memo per transaction
+        //   2. The proposal grows far past Proposal::space(64, 128)
+        //   3. Eventually attach_memo itself fails with out-of-memory
+        let title = title.unwrap_or_else(|| self.proposal.title.clone());
+        let memo_len = self.proposal.memo.len() + memo.len();
+
+        let info = self.proposal.to_account_info();
+        let new_space = Proposal::space(title.len(), memo_len);
+        let rent_due = Rent::get()?
+            .minimum_balance(new_space)
+            .saturating_sub(info.lamports());
+
+        if rent_due > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.proposer.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+
+        info.resize(new_space)?;
+        self.proposal.title = title;
+        self.proposal.memo.push_str(&memo); // VULNERABLE: Unbounded growth
+
+        Ok(())
+    }
+}
@@ -121,6 +121,7 @@ impl<'info> CreateMultisig<'info> {
             vault: self.vault.key(),
             bump: bumps.multisig_account,
             vault_bump: bumps.vault,
+            vault_pays_rent: false,
         });
 
         // Initialize vault account
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, constants::*};
+use crate::{state::*, constants::*, helpers::reimburse_proposal_rent};
 
 // Create Proposal Instruction - VULNERABLE VERSION
 //
@@ -45,7 +45,7 @@ pub struct CreateProposal<'info> {
     #[account(
         init,
         payer = proposer,
-        space = ANCHOR_DISCRIMINATOR + Proposal::INIT_SPACE,
+        space = Proposal::space(0, 0),
         seeds = [
             PROPOSAL,
             multisig_account.key().as_ref(),
@@ -56,6 +56,12 @@ pub struct CreateProposal<'info> {
     pub proposal: Account<'info, Proposal>,
 
     pub system_program: Program<'info, System>,
+
+    // Optional rent payer - reimburses the proposer for the proposal rent
+    // VULNERABILITY [HIGH]: The vault is accepted without vault_pays_rent (see helpers.rs)
+    /// CHECK: Validated in reimburse_proposal_rent
+    #[account(mut)]
+    pub rent_payer: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> CreateProposal<'info> {
@@ -154,6 +160,14 @@ impl<'info> CreateProposal<'info> {
 
         let proposal_id = self.multisig_account.proposal_count - 1;
 
+        let rent_payer = reimburse_proposal_rent(
+            self.rent_payer.as_ref(),
+            &self.proposer,
+            &self.multisig_account,
+            &self.system_program,
+            self.proposal.to_account_info().lamports(),
+        )?;
+
         // Initialize proposal with auto-approval from proposer
         let mut approval_bitmap: u64 = 0;
         approval_bitmap |= 1u64 << proposer_index;
@@ -182,8 +196,13 @@ impl<'info> CreateProposal<'info> {
             created_at: clock.unix_timestamp,
             expires_at,
             executed_at: 0,
+            rent_payer,
             bump: bumps.proposal,
+            proposal_hash: [0; 32],
+            title: String::new(),
+            memo: String::new(),
         });
+        self.proposal.proposal_hash = self.proposal.proposal_hash()?;
 
         Ok(())
     }
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{state::*,  constants::*};
+use crate::{state::*,  constants::*, helpers::reimburse_proposal_rent};
 
 // Create Transfer Proposal Instruction - VULNERABLE VERSION
 //
@@ -38,6 +38,12 @@ pub struct CreateTransferProposal<'info> {
     pub transfer_proposal: Account<'info, TransferProposal>,
 
     pub system_program: Program<'info, System>,
+
+    // Optional rent payer - reimburses the proposer for the proposal rent
+    // VULNERABILITY [HIGH]: The vault is accepted without vault_pays_rent (see helpers.rs)
+    /// CHECK: Validated in reimburse_proposal_rent
+    #[account(mut)]
+    pub rent_payer: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> CreateTransferProposal<'info> {
@@ -100,6 +106,14 @@ impl<'info> CreateTransferProposal<'info> {
 
         let proposal_id = self.multisig_account.proposal_count - 1;
 
+        let rent_payer = reimburse_proposal_rent(
+            self.rent_payer.as_ref(),
+            &self.proposer,
+            &self.multisig_account,
+            &self.system_program,
+            self.transfer_proposal.to_account_info().lamports(),
+        )?;
+
         let mut approval_bitmap: u64 = 0;
         approval_bitmap |= 1u64 << proposer_index;
 
@@ -122,6 +136,7 @@ impl<'info> CreateTransferProposal<'info> {
             executed_at: 0,
             amount, // VULNERABLE: Unvalidated amount
             recipient, // VULNERABLE: Unvalidated recipient
+            rent_payer,
             bump: bumps.transfer_proposal,
         });
 
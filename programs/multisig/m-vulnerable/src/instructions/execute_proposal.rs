@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{state::*,  constants::*};
+use crate::{state::*, errors::*, constants::*};
 
 // Execute Proposal Instruction - VULNERABLE VERSION
 //
@@ -134,6 +134,15 @@ impl<'info> ExecuteProposal<'info> {
         // Fix: require!(!self.proposal.is_expired(clock.unix_timestamp), MultisigError::ProposalExpired);
 
 
+        // VULNERABILITY [CRITICAL]: Missing proposal hash check
+        //
+        // The secure version validates that the parameters still hash to the
+        // proposal_hash set at creation. Here update_proposal rewrites both the
+        // action and its hash, so approvals given for one action execute another.
+        //
+        // Fix: require!(self.proposal.proposal_hash()? == approved hash, MultisigError::ProposalHashMismatch);
+
+
         // Execute the proposal type
         // Note: Even the execution logic has vulnerabilities!
         match self.proposal.proposal_type {
@@ -216,6 +225,20 @@ impl<'info> ExecuteProposal<'info> {
 
                 self.multisig_account.timelock_seconds = new_timelock; // VULNERABLE: Unvalidated value
             }
+
+            ProposalType::SetVaultPaysRent { enabled } => {
+                self.multisig_account.vault_pays_rent = enabled;
+            }
+
+            ProposalType::ApproveParentProposal { .. } => {
+                // Parent approvals only take effect through approve_as_multisig
+                return err!(MultisigError::InvalidOperation);
+            }
+
+            ProposalType::SweepToken { .. } => {
+                // Token sweeps only take effect through execute_sweep_proposal
+                return err!(MultisigError::InvalidOperation);
+            }
         }
 
         // Mark proposal as executed
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use crate::{state::*, errors::*, constants::*};
+
+// Execute Sweep Proposal Instruction - VULNERABLE VERSION
+//
+// Executes an approved SweepToken proposal, moving the vault's token balance
+// to a recipient. The token accounts are taken as passed.
+
+#[derive(Accounts)]
+pub struct ExecuteSweepProposal<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            MULTISIG,
+            multisig_account.creator.as_ref(),
+            &multisig_account.multisig_id.to_le_bytes(),
+        ],
+        bump = multisig_account.bump,
+    )]
+    pub multisig_account: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [
+            PROPOSAL,
+            multisig_account.key().as_ref(),
+            &proposal.proposal_id.to_le_bytes(),
+        ],
+        bump = proposal.bump,
+        close = executor, // VULNERABLE: Should be rent_payer
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [
+            VAULT,
+            multisig_account.key().as_ref(),
+        ],
+        bump = multisig_account.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    // VULNERABILITY [CRITICAL]: Mint and token accounts never tied to the proposal
+    //
+    // The secure version uses:
+    // associated_token::mint = mint, associated_token::authority = vault
+    // associated_token::mint = mint, associated_token::authority = recipient
+    // require!(mint.key() == proposal mint && recipient.key() == proposal recipient)
+    //
+    // Here the executor picks all three. The token program only checks that
+    // the source is owned by the vault, so any vault token account of any
+    // mint can be drained into any account of the executor's choosing.
+    //
+    // Example Attack:
+    //   1. Owners approve sweeping 10 stray DUST tokens to the treasury
+    //   2. The vault also holds 1,000,000 USDC in its USDC ATA
+    //   3. Executor passes the USDC mint, the vault's USDC ATA and their own USDC ATA
+    //   4. The whole USDC balance moves to the executor
+    //
+    // Fix: Derive both ATAs from the proposal's mint and recipient
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ExecuteSweepProposal<'info> {
+    pub fn execute_sweep_proposal(&mut self) -> Result<()> {
+        // Status and threshold are checked; the flaw here is in the accounts
+        require!(
+            self.proposal.status == ProposalStatus::Active,
+            MultisigError::ProposalNotActive
+        );
+        require!(
+            self.proposal.approval_count >= self.multisig_account.threshold,
+            MultisigError::InsufficientApprovals
+        );
+
+        // VULNERABILITY [CRITICAL]: Proposal's mint and recipient never compared
+        //
+        // Fix: require!(self.mint.key() == mint, MultisigError::InvalidMint);
+        // Fix: require!(self.recipient.key() == recipient, MultisigError::InvalidRecipient);
+        let ProposalType::SweepToken { .. } = self.proposal.proposal_type else {
+            return err!(MultisigError::InvalidOperation);
+        };
+
+        let amount = self.vault_token_account.amount;
+
+        let multisig_key = self.multisig_account.key();
+        let vault_seeds = &[
+            VAULT,
+            multisig_key.as_ref(),
+            &[self.multisig_account.vault_bump],
+        ];
+        let signer_seeds = &[&vault_seeds[..]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.vault_token_account.to_account_info(),
+                    mint: self.mint.to_account_info(),
+                    to: self.recipient_token_account.to_account_info(),
+                    authority: self.vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            self.mint.decimals,
+        )?;
+
+        self.proposal.status = ProposalStatus::Executed;
+        self.proposal.executed_at = Clock::get()?.unix_timestamp;
+        self.multisig_account.last_executed_proposal = self.proposal.proposal_id;
+
+        Ok(())
+    }
+}
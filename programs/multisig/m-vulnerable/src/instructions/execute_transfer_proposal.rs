@@ -69,7 +69,10 @@ pub struct ExecuteTransferProposal<'info> {
 }
 
 impl<'info> ExecuteTransferProposal<'info> {
-    pub fn execute_transfer_proposal(&mut self) -> Result<()> {
+    pub fn execute_transfer_proposal(
+        &mut self,
+        _expected_outcome: Option<ExpectedOutcome>,
+    ) -> Result<()> {
         // VULNERABILITY [CRITICAL]: Missing pause check
         //
         // Fix: require!(!self.multisig_account.paused, MultisigError::MultisigPaused);
@@ -158,6 +161,15 @@ impl<'info> ExecuteTransferProposal<'info> {
         // this allows complete fund drainage.
         transfer(cpi_context, self.transfer_proposal.amount)?;
 
+        // VULNERABILITY [HIGH]: Expected outcome never verified
+        //
+        // The executor can pass the balance deltas the signers simulated, but
+        // they are dropped unread. A substituted recipient (or the vault itself)
+        // produces different deltas and the transfer still succeeds.
+        //
+        // Fix: re-read vault and recipient lamports after the CPI and
+        // require!(deltas == expected, MultisigError::OutcomeMismatch);
+
         // Update proposal state
         self.transfer_proposal.status = ProposalStatus::Executed;
         self.transfer_proposal.executed_at = Clock::get()?.unix_timestamp;
@@ -10,9 +10,13 @@ pub mod create_proposal;
 pub mod create_transfer_proposal;
 pub mod approve_proposal;
 pub mod approve_transfer_proposal;
+pub mod approve_as_multisig;
 pub mod execute_proposal;
 pub mod execute_transfer_proposal;
+pub mod execute_sweep_proposal;
 pub mod cancel_proposal;
+pub mod attach_memo;
+pub mod update_proposal;
 pub mod toggle_pause;
 
 pub use create_multisig::*;
@@ -20,7 +24,11 @@ pub use create_proposal::*;
 pub use create_transfer_proposal::*;
 pub use approve_proposal::*;
 pub use approve_transfer_proposal::*;
+pub use approve_as_multisig::*;
 pub use execute_proposal::*;
 pub use execute_transfer_proposal::*;
+pub use execute_sweep_proposal::*;
 pub use cancel_proposal::*;
+pub use attach_memo::*;
+pub use update_proposal::*;
 pub use toggle_pause::*;
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::*, constants::*};
+
+// Update Proposal Instruction - VULNERABLE VERSION
+//
+// Lets the proposer correct the action of an active proposal.
+// The secure version has no such instruction: a proposal's parameters are
+// fixed at creation and pinned by its hash, so a change means a new proposal.
+
+#[derive(Accounts)]
+pub struct UpdateProposal<'info> {
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            MULTISIG,
+            multisig_account.creator.as_ref(),
+            &multisig_account.multisig_id.to_le_bytes(),
+        ],
+        bump = multisig_account.bump,
+    )]
+    pub multisig_account: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [
+            PROPOSAL,
+            multisig_account.key().as_ref(),
+            &proposal.proposal_id.to_le_bytes(),
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+impl<'info> UpdateProposal<'info> {
+    pub fn update_proposal(&mut self, proposal_type: ProposalType) -> Result<()> {
+        require!(
+            self.proposal.is_active(),
+            MultisigError::ProposalNotActive
+        );
+        require!(
+            self.proposer.key() == self.proposal.proposer,
+            MultisigError::Unauthorized
+        );
+
+        // VULNERABILITY [CRITICAL]: Approvals survive a change of parameters
+        //
+        // Every approval was given for the old action, yet approval_bitmap and
+        // approval_count are kept, and the hash is recomputed so it matches the
+        // new action. Owners who checked the hash before approving are bound to
+        // an action they never saw, and execute_proposal never compares the
+        // hash with anything.
+        //
+        // Example Attack:
+        //   1. Proposer creates ChangeTimelock { new_timelock: 3600 }
+        //   2. The other owners check the hash and approve, threshold is met
+        //   3. Proposer updates it to AddMember { accomplice, Admin }
+        //   4. Proposer executes: the accomplice joins as Admin on old approvals
+        //
+        // Fix: Don't allow edits, or clear approval_bitmap and reset
+        // approval_count to the proposer's approval on every edit.
+        self.proposal.proposal_type = proposal_type;
+        self.proposal.proposal_hash = self.proposal.proposal_hash()?;
+
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@ pub mod instructions;
 pub mod errors;
 pub mod state;
 pub mod constants;
+pub mod helpers;
 
 pub use instructions::*;
 pub use errors::*;
@@ -85,6 +86,17 @@ pub mod vulnerable {
         ctx.accounts.approve_transfer_proposal()
     }
 
+    // VULNERABILITY [CRITICAL]: Child approval not bound to the parent proposal
+    //
+    // The secure version requires the child's ApproveParentProposal to name
+    // this parent multisig and this parent proposal id. This vulnerable
+    // version accepts any executable child vote for any parent proposal.
+    //
+    // Fix: Compare parent_multisig and parent_proposal_id with the accounts.
+    pub fn approve_as_multisig(ctx: Context<ApproveAsMultisig>) -> Result<()> {
+        ctx.accounts.approve_as_multisig()
+    }
+
     // VULNERABILITY [CRITICAL]: Missing threshold and timelock checks
     //
     // The secure version validates:
@@ -107,8 +119,23 @@ pub mod vulnerable {
     // - Vault has sufficient balance
     //
     // Fix: Implement full recipient and balance validation.
-    pub fn execute_transfer_proposal(ctx: Context<ExecuteTransferProposal>) -> Result<()> {
-        ctx.accounts.execute_transfer_proposal()
+    //
+    // VULNERABILITY [HIGH]: expected_outcome is ignored
+    // Mismatched vault/recipient deltas are accepted silently.
+    pub fn execute_transfer_proposal(
+        ctx: Context<ExecuteTransferProposal>,
+        expected_outcome: Option<ExpectedOutcome>,
+    ) -> Result<()> {
+        ctx.accounts.execute_transfer_proposal(expected_outcome)
+    }
+
+    // VULNERABILITY [CRITICAL]: Sweep token accounts not derived
+    //
+    // The secure version derives the vault's and the recipient's ATAs from the
+    // proposal's mint and recipient. Here the executor passes any vault token
+    // account and any destination, and takes the tokens.
+    pub fn execute_sweep_proposal(ctx: Context<ExecuteSweepProposal>) -> Result<()> {
+        ctx.accounts.execute_sweep_proposal()
     }
 
     // VULNERABILITY [HIGH]: Anyone can cancel any proposal
@@ -122,6 +149,35 @@ pub mod vulnerable {
         ctx.accounts.cancel_proposal()
     }
 
+    // VULNERABILITY [MEDIUM]: Unbounded proposal metadata
+    //
+    // The secure version caps the title at 64 bytes and the memo at 128.
+    // This vulnerable version appends any memo and resizes the proposal to
+    // fit, so the proposer can grow it until the program cannot load it.
+    //
+    // Fix: Check both lengths against the bounds before resizing.
+    pub fn attach_memo(
+        ctx: Context<AttachMemo>,
+        title: Option<String>,
+        memo: String,
+    ) -> Result<()> {
+        ctx.accounts.attach_memo(title, memo)
+    }
+
+    // VULNERABILITY [CRITICAL]: Approved parameters can be swapped
+    //
+    // The secure version has no update: parameters are fixed at creation and
+    // execution checks they still match the proposal hash. Here the proposer
+    // replaces the action while every approval stays counted.
+    //
+    // Fix: Reset approvals on update, or drop the instruction.
+    pub fn update_proposal(
+        ctx: Context<UpdateProposal>,
+        proposal_type: ProposalType,
+    ) -> Result<()> {
+        ctx.accounts.update_proposal(proposal_type)
+    }
+
     // VULNERABILITY [CRITICAL]: No admin check on pause
     //
     // The secure version only allows the creator (admin) to pause.
@@ -55,6 +55,10 @@ pub struct Multisig {
     pub vault: Pubkey,
     pub bump: u8,
     pub vault_bump: u8,
+
+    // When true, proposers may pass the vault as rent_payer
+    // VULNERABILITY [HIGH]: Stored but never checked (see helpers.rs)
+    pub vault_pays_rent: bool,
 }
 
 impl Multisig {
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::constants::MAX_OWNERS;
+use anchor_lang::solana_program::hash::hashv;
+use crate::{constants::*, errors::*};
 use super::member::*;
 
 // Proposal status enum
@@ -65,6 +66,17 @@ pub enum ProposalType {
     // - timelock = u64::MAX (proposals can never execute, DoS)
     // - timelock = 0 (no delay, immediate execution of malicious proposals)
     ChangeTimelock { new_timelock: u64 },
+
+    // Approve a proposal on a parent multisig this multisig is a member of
+    // Consumed by approve_as_multisig (see V019 there)
+    ApproveParentProposal { parent_multisig: Pubkey, parent_proposal_id: u64 },
+
+    // Allow or forbid the vault as a proposal rent_payer
+    SetVaultPaysRent { enabled: bool },
+
+    // Sweep the vault's balance of an SPL token to a recipient
+    // Executed through execute_sweep_proposal (see V023 there)
+    SweepToken { mint: Pubkey, recipient: Pubkey },
 }
 
 // Proposal account
@@ -101,10 +113,53 @@ pub struct Proposal {
     pub created_at: i64,
     pub expires_at: i64,
     pub executed_at: i64,
+
+    // Who paid the proposal's rent (proposer, vault or a sponsor)
+    pub rent_payer: Pubkey,
+
     pub bump: u8,
+
+    // VULNERABILITY [CRITICAL]: Hash follows the parameters instead of pinning them
+    //
+    // The secure version sets the hash once at creation and execute_proposal
+    // requires the parameters to still hash to it. Here update_proposal
+    // recomputes it after every edit and nothing checks it at execution, so it
+    // always "matches" whatever the proposal says now, not what was approved.
+    pub proposal_hash: [u8; 32],
+
+    // Optional metadata set by attach_memo (see V022 there)
+    #[max_len(MAX_PROPOSAL_TITLE_LEN)]
+    pub title: String,
+
+    #[max_len(MAX_PROPOSAL_MEMO_LEN)]
+    pub memo: String,
 }
 
 impl Proposal {
+    // Account size for a title and memo of the given byte lengths
+    pub fn space(title_len: usize, memo_len: usize) -> usize {
+        ANCHOR_DISCRIMINATOR + Self::INIT_SPACE - MAX_PROPOSAL_TITLE_LEN - MAX_PROPOSAL_MEMO_LEN
+            + title_len
+            + memo_len
+    }
+
+    // SHA-256 over the domain, the Borsh-encoded proposal type, the multisig
+    // and the proposal id (same digest as the secure version)
+    pub fn proposal_hash(&self) -> Result<[u8; 32]> {
+        let mut action = Vec::new();
+        self.proposal_type
+            .serialize(&mut action)
+            .map_err(|_| error!(MultisigError::InvalidOperation))?;
+
+        Ok(hashv(&[
+            PROPOSAL_HASH_DOMAIN,
+            &action,
+            self.multisig.as_ref(),
+            &self.proposal_id.to_le_bytes(),
+        ])
+        .to_bytes())
+    }
+
     // VULNERABILITY [CRITICAL]: has_approved might not be used
     //
     // The method exists but vulnerable instructions may not call it,
@@ -44,9 +44,22 @@ pub struct TransferProposal {
     // - Sending to PDAs that can't handle lamports
     pub recipient: Pubkey,
 
+    // Who paid the proposal's rent (proposer, vault or a sponsor)
+    pub rent_payer: Pubkey,
+
     pub bump: u8,
 }
 
+// Expected Outcome
+//
+// VULNERABILITY: Accepted by execute_transfer_proposal but never compared
+// against the actual balance changes (see execute_transfer_proposal.rs)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExpectedOutcome {
+    pub vault_delta: u64,
+    pub recipient_delta: u64,
+}
+
 impl TransferProposal {
     // Same vulnerabilities as Proposal for approval methods
 
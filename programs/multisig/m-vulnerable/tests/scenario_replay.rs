@@ -0,0 +1,23 @@
+// Scenario Replays: exploits written in the test kit's JSON scenario format
+//
+// Same attacks as exploit_threshold_bypass.rs and exploit_unauthorized_pause.rs,
+// described as data in tests/scenarios/. The files never name a program ID, so
+// they replay against whichever build is passed here.
+
+mod utils;
+
+use utils::*;
+
+const PROGRAM_PATH: &str = "target/deploy/multisig_vulnerable.so";
+
+#[test]
+fn test_scenario_threshold_bypass() {
+    let scenario = Scenario::load("tests/scenarios/threshold_bypass.json");
+    run_exploit(&scenario.replay(PROGRAM_ID, PROGRAM_PATH));
+}
+
+#[test]
+fn test_scenario_unauthorized_pause() {
+    let scenario = Scenario::load("tests/scenarios/unauthorized_pause.json");
+    run_exploit(&scenario.replay(PROGRAM_ID, PROGRAM_PATH));
+}
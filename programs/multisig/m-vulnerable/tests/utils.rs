@@ -6,10 +6,12 @@ use solana_sdk::{
     native_token::LAMPORTS_PER_SOL,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
-    transaction::Transaction,
 };
 use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
 
+// LiteSVM helpers (setup, funding, clock, send) come from the shared test kit
+pub use soteria_test_kit::*;
+
 // Program ID matching declare_id!
 pub const PROGRAM_ID: Pubkey = solana_sdk::pubkey!("2Skteich3Jdz4W41oek3wrwdFSFRJcgvaAT7H1bxGvck");
 
@@ -36,33 +38,19 @@ pub enum ProposalTypeDiscriminator {
     RemoveMember = 1,
     ChangeThreshold = 2,
     ChangeTimelock = 3,
+    ApproveParentProposal = 4,
+    SetVaultPaysRent = 5,
+    SweepToken = 6,
 }
 
 // ======================== HELPERS ========================
 
-/// Build Anchor instruction discriminator (8 bytes from sighash of "global:method_name")
-pub fn anchor_discriminator(method: &str) -> [u8; 8] {
-    let preimage = format!("global:{}", method);
-    let hash = solana_sdk::hash::hash(preimage.as_bytes());
-    let mut discriminator = [0u8; 8];
-    discriminator.copy_from_slice(&hash.to_bytes()[..8]);
-    discriminator
-}
-
 /// Load the compiled program binary into LiteSVM
 pub fn setup_svm() -> LiteSVM {
-    let mut svm = LiteSVM::new();
-    let program_bytes = include_bytes!("../target/deploy/multisig_vulnerable.so");
-    svm.add_program(PROGRAM_ID, program_bytes);
-    svm
-}
-
-/// Create a new keypair and fund it with SOL via airdrop
-pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
-    let keypair = Keypair::new();
-    svm.airdrop(&keypair.pubkey(), lamports)
-        .expect("Airdrop should succeed");
-    keypair
+    ProgramHarness::builder()
+        .program_file(PROGRAM_ID, "target/deploy/multisig_vulnerable.so")
+        .build()
+        .into_svm()
 }
 
 /// Derive the multisig PDA using seeds: ["multisig", creator_pubkey, multisig_id]
@@ -118,11 +106,45 @@ pub fn get_multisig_proposal_count(svm: &LiteSVM, multisig: &Pubkey) -> u64 {
     u64::from_le_bytes(proposal_count_bytes)
 }
 
-/// Advance the SVM clock by the specified number of seconds
-pub fn advance_time(svm: &mut LiteSVM, seconds: u64) {
-    let mut clock: solana_sdk::clock::Clock = svm.get_sysvar();
-    clock.unix_timestamp += seconds as i64;
-    svm.set_sysvar(&clock);
+/// Get the approval bitmap from a proposal account
+pub fn get_proposal_approval_bitmap(svm: &LiteSVM, proposal: &Pubkey) -> u64 {
+    let account = svm.get_account(proposal).expect("Proposal account should exist");
+
+    // Proposal account layout:
+    // 8 bytes: discriminator
+    // 32 bytes: multisig
+    // 8 bytes: proposal_id
+    // 32 bytes: proposer
+    // 1 byte + variant fields: proposal_type (Borsh, so size depends on the variant)
+    // 1 byte: status
+    // 8 bytes: approval_bitmap <- we want this
+
+    let type_offset = 8 + 32 + 8 + 32;
+    let variant_len = match account.data[type_offset] {
+        0 => 32 + 1, // AddMember
+        1 => 32,     // RemoveMember
+        2 => 1,      // ChangeThreshold
+        3 => 8,      // ChangeTimelock
+        4 => 32 + 8, // ApproveParentProposal
+        _ => 1,      // SetVaultPaysRent
+    };
+    let offset = type_offset + 1 + variant_len + 1;
+    let mut bitmap_bytes = [0u8; 8];
+    bitmap_bytes.copy_from_slice(&account.data[offset..offset + 8]);
+    u64::from_le_bytes(bitmap_bytes)
+}
+
+/// Get the paused flag from a multisig account
+pub fn get_multisig_paused(svm: &LiteSVM, multisig: &Pubkey) -> bool {
+    let account = svm.get_account(multisig).expect("Multisig account should exist");
+
+    // Same layout as above, then:
+    // 8 bytes: proposal_count
+    // 8 bytes: last_executed_proposal
+    // 1 byte: paused <- we want this
+
+    let offset = 8 + 8 + 32 + 1 + 1 + (33 * 10) + 8 + 8;
+    account.data[offset] != 0
 }
 
 // ======================== INSTRUCTION BUILDERS ========================
@@ -155,6 +177,117 @@ pub fn build_create_multisig_ix(
     }
 }
 
+/// Build create_proposal instruction
+/// `fields` is the Borsh encoding of the variant's fields, written after its tag
+pub fn build_create_proposal_ix(
+    proposer: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+    proposal_type: ProposalTypeDiscriminator,
+    fields: &[u8],
+) -> Instruction {
+    let discriminator = anchor_discriminator("create_proposal");
+
+    let mut data = discriminator.to_vec();
+    data.push(proposal_type as u8);
+    data.extend_from_slice(fields);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*proposer, true),
+            AccountMeta::new(*multisig, false),
+            AccountMeta::new(*proposal, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false), // rent_payer: None
+        ],
+        data,
+    }
+}
+
+/// Set the optional rent_payer of a create_proposal/create_transfer_proposal instruction
+/// The vault is passed as a non-signer; the program signs for it with the vault seeds
+pub fn with_rent_payer(mut ix: Instruction, rent_payer: &Pubkey, is_signer: bool) -> Instruction {
+    let last = ix.accounts.len() - 1;
+    ix.accounts[last] = AccountMeta::new(*rent_payer, is_signer);
+    ix
+}
+
+/// Build execute_proposal instruction
+/// Note: m-vulnerable does NOT have proposer account (rent goes to executor instead)
+pub fn build_execute_proposal_ix(
+    executor: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("execute_proposal");
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*executor, true),
+            AccountMeta::new(*multisig, false),
+            AccountMeta::new(*proposal, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
+/// Build execute_sweep_proposal instruction
+/// Note: m-vulnerable takes the token accounts as passed, with no recipient wallet
+#[allow(clippy::too_many_arguments)]
+pub fn build_execute_sweep_proposal_ix(
+    executor: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+    vault: &Pubkey,
+    mint: &Pubkey,
+    vault_token_account: &Pubkey,
+    recipient_token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("execute_sweep_proposal");
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*executor, true),
+            AccountMeta::new(*multisig, false),
+            AccountMeta::new(*proposal, false),
+            AccountMeta::new_readonly(*vault, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(*vault_token_account, false),
+            AccountMeta::new(*recipient_token_account, false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
+/// Build approve_as_multisig instruction
+/// Note: m-vulnerable does NOT have child proposer account (rent goes to executor instead)
+pub fn build_approve_as_multisig_ix(
+    executor: &Pubkey,
+    child_multisig: &Pubkey,
+    child_proposal: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("approve_as_multisig");
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*executor, true),
+            AccountMeta::new(*child_multisig, false),
+            AccountMeta::new(*child_proposal, false),
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(*proposal, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
 /// Build create_transfer_proposal instruction
 pub fn build_create_transfer_proposal_ix(
     proposer: &Pubkey,
@@ -176,6 +309,104 @@ pub fn build_create_transfer_proposal_ix(
             AccountMeta::new(*multisig, false),
             AccountMeta::new(*transfer_proposal, false),
             AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false), // rent_payer: None
+        ],
+        data,
+    }
+}
+
+/// Build cancel_proposal instruction
+/// Note: m-vulnerable closes the proposal to the canceller (no rent payer account)
+pub fn build_cancel_proposal_ix(
+    canceller: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("cancel_proposal");
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*canceller, true),
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(*proposal, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
+/// Build attach_memo instruction
+/// A None title keeps the current one; memo is appended to the proposal's memo
+pub fn build_attach_memo_ix(
+    proposer: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+    title: Option<&str>,
+    memo: &str,
+) -> Instruction {
+    let discriminator = anchor_discriminator("attach_memo");
+
+    // Option<String> then String: Borsh u32 length prefixes
+    let mut data = discriminator.to_vec();
+    match title {
+        Some(title) => {
+            data.push(1);
+            data.extend_from_slice(&(title.len() as u32).to_le_bytes());
+            data.extend_from_slice(title.as_bytes());
+        }
+        None => data.push(0),
+    }
+    data.extend_from_slice(&(memo.len() as u32).to_le_bytes());
+    data.extend_from_slice(memo.as_bytes());
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*proposer, true),
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(*proposal, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+/// Build approve_proposal instruction
+pub fn build_approve_proposal_ix(owner: &Pubkey, multisig: &Pubkey, proposal: &Pubkey) -> Instruction {
+    let discriminator = anchor_discriminator("approve_proposal");
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(*proposal, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
+/// Build update_proposal instruction (vulnerable only)
+/// `fields` is the Borsh encoding of the new variant's fields, written after its tag
+pub fn build_update_proposal_ix(
+    proposer: &Pubkey,
+    multisig: &Pubkey,
+    proposal: &Pubkey,
+    proposal_type: ProposalTypeDiscriminator,
+    fields: &[u8],
+) -> Instruction {
+    let discriminator = anchor_discriminator("update_proposal");
+
+    let mut data = discriminator.to_vec();
+    data.push(proposal_type as u8);
+    data.extend_from_slice(fields);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*proposer, true),
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(*proposal, false),
         ],
         data,
     }
@@ -209,8 +440,6 @@ pub fn build_execute_transfer_proposal_ix(
     vault: &Pubkey,
     recipient: &Pubkey,
 ) -> Instruction {
-    let discriminator = anchor_discriminator("execute_transfer_proposal");
-
     Instruction {
         program_id: PROGRAM_ID,
         accounts: vec![
@@ -221,10 +450,31 @@ pub fn build_execute_transfer_proposal_ix(
             AccountMeta::new(*recipient, false),
             AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
         ],
-        data: discriminator.to_vec(),
+        data: build_execute_transfer_proposal_data(),
     }
 }
 
+/// Build execute_transfer_proposal instruction with an expected outcome
+/// (the vulnerable program ignores it)
+pub fn build_execute_transfer_proposal_with_outcome_ix(
+    executor: &Pubkey,
+    multisig: &Pubkey,
+    transfer_proposal: &Pubkey,
+    vault: &Pubkey,
+    recipient: &Pubkey,
+    vault_delta: u64,
+    recipient_delta: u64,
+) -> Instruction {
+    let mut ix = build_execute_transfer_proposal_ix(executor, multisig, transfer_proposal, vault, recipient);
+
+    // Replace the None tag with Some(ExpectedOutcome)
+    ix.data.pop();
+    ix.data.push(1);
+    ix.data.extend_from_slice(&vault_delta.to_le_bytes());
+    ix.data.extend_from_slice(&recipient_delta.to_le_bytes());
+    ix
+}
+
 /// Build toggle_pause instruction
 pub fn build_toggle_pause_ix(admin: &Pubkey, multisig: &Pubkey) -> Instruction {
     let discriminator = anchor_discriminator("toggle_pause");
@@ -239,48 +489,6 @@ pub fn build_toggle_pause_ix(admin: &Pubkey, multisig: &Pubkey) -> Instruction {
     }
 }
 
-// ======================== TRANSACTION HELPERS ========================
-
-/// Send a transaction and expect success
-pub fn send_tx_expect_success(
-    svm: &mut LiteSVM,
-    ix: Instruction,
-    payer: &Keypair,
-    signers: &[&Keypair],
-) {
-    let blockhash = svm.latest_blockhash();
-
-    let tx = Transaction::new_signed_with_payer(
-        &[ix],
-        Some(&payer.pubkey()),
-        signers,
-        blockhash,
-    );
-
-    svm.send_transaction(tx)
-        .expect("Transaction should succeed");
-}
-
-/// Send a transaction and expect failure
-pub fn send_tx_expect_failure(
-    svm: &mut LiteSVM,
-    ix: Instruction,
-    payer: &Keypair,
-    signers: &[&Keypair],
-) -> String {
-    let blockhash = svm.latest_blockhash();
-
-    let tx = Transaction::new_signed_with_payer(
-        &[ix],
-        Some(&payer.pubkey()),
-        signers,
-        blockhash,
-    );
-    let result = svm.send_transaction(tx);
-    assert!(result.is_err(), "Transaction should have failed");
-    format!("{:?}", result.err().unwrap())
-}
-
 // ======================== SETUP HELPERS ========================
 
 /// Multisig scenario setup result
@@ -402,10 +610,12 @@ pub fn build_approve_transfer_proposal_data() -> Vec<u8> {
     discriminator.to_vec()
 }
 
-/// Build execute_transfer_proposal instruction data (discriminator only)
+/// Build execute_transfer_proposal instruction data (discriminator + None expected outcome)
 pub fn build_execute_transfer_proposal_data() -> Vec<u8> {
     let discriminator = anchor_discriminator("execute_transfer_proposal");
-    discriminator.to_vec()
+    let mut data = discriminator.to_vec();
+    data.push(0);
+    data
 }
 
 /// Build toggle_pause instruction data (discriminator only)
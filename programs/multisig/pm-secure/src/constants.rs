@@ -0,0 +1,14 @@
+// Seeds for the vault PDA: ["vault", multisig]
+// The multisig and proposal seeds live on their state structs (SEED_PREFIX)
+pub const VAULT_SEED: &[u8] = b"vault";
+
+// Maximum number of members allowed in the multisig
+// Each member owns one bit of a proposal's approval bitmap
+pub const MAX_OWNERS: usize = 10;
+
+// Upper bound for the execution delay (2 days in seconds)
+pub const MAX_TIMELOCK: u64 = 2 * 24 * 60 * 60;
+
+// Default expiry grace period (7 days in seconds)
+// Proposals expire after: created_at + timelock + grace_period
+pub const DEFAULT_EXPIRY_PERIOD: u64 = 7 * 24 * 60 * 60;
@@ -0,0 +1,56 @@
+use pinocchio::error::ProgramError;
+
+pub use soteria_errors::pm_secure::MultisigError as MultisigErrorCode;
+
+// Custom errors for the Pinocchio multisig
+//
+// Anchor's #[error_code] generates codes starting at 6000 and logs the name.
+// Here each variant maps to ProgramError::Custom(index), so clients match on the
+// number (NotAMember = 0, AlreadyMember = 1, ...). Keep new variants at the end
+// so existing codes never shift.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MultisigError {
+    // Member validation errors
+    NotAMember,
+    AlreadyMember,
+    CannotRemoveCreator,
+    MaxMembersReached,
+    MinimumOneMember,
+
+    // Role/permission errors
+    OnlyAdmin,
+    CannotPropose,
+    CannotExecute,
+    CannotAddSelf,
+    InvalidRole,
+
+    // Threshold errors
+    InvalidThreshold,
+    ThresholdExceedsOwners,
+
+    // Proposal errors
+    ProposalNotActive,
+    AlreadyApproved,
+    InsufficientApprovals,
+    ProposalExpired,
+    TimelockNotPassed,
+    InvalidProposalType,
+    InvalidProposal,
+
+    // Execution errors
+    InsufficientFunds,
+    InvalidRecipient,
+
+    // Arithmetic errors
+    Overflow,
+
+    // State errors
+    InvalidParameter,
+}
+
+impl From<MultisigError> for ProgramError {
+    fn from(error: MultisigError) -> Self {
+        ProgramError::Custom(error as u32)
+    }
+}
@@ -0,0 +1,123 @@
+use pinocchio::{
+    AccountView, ProgramResult, error::ProgramError,
+    sysvars::{Sysvar, clock::Clock}
+};
+
+use crate::{
+    errors::MultisigError,
+    state::{MultisigState, ProposalState},
+};
+
+
+// Account context for the Approve Proposal instruction
+//
+// Any member, whatever their role, sets their bit in the approval bitmap.
+// Works for governance and transfer proposals alike, since both share the
+// same account layout.
+pub struct ApproveProposalAccounts<'a> {
+    pub member: &'a AccountView,
+    pub multisig: &'a AccountView,
+    pub proposal: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for ApproveProposalAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [member, multisig, proposal, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // SECURITY CHECKS
+
+        // 1: Signer Check
+        // An approval is only worth something if the member signed it
+        if !member.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+
+        // 2: Multisig Account Checks
+        // Owner, size, initialized and PDA derivation
+        MultisigState::check_account(multisig)?;
+
+
+        // 3: Proposal Account Checks
+        // Owner, size, writable, PDA derivation and proposal.multisig == multisig
+        ProposalState::check_account(proposal, multisig.address())?;
+
+        Ok(Self {
+            member,
+            multisig,
+            proposal,
+        })
+    }
+}
+
+
+pub struct ApproveProposalInstruction<'a> {
+    pub accounts: ApproveProposalAccounts<'a>,
+}
+
+impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for ApproveProposalInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, _data): (&'a [AccountView], &'a [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = ApproveProposalAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+
+// INSTRUCTION HANDLER
+
+impl<'a> ApproveProposalInstruction<'a> {
+    pub fn handler(&self) -> ProgramResult {
+        let multisig_data = self.accounts.multisig.try_borrow()?;
+        let multisig_state = MultisigState::load(&multisig_data)?;
+
+        let mut proposal_data = self.accounts.proposal.try_borrow_mut()?;
+        let proposal_state = ProposalState::load_mut(&mut proposal_data)?;
+
+        // 1: Membership Check
+        // The member's index is their bit in the approval bitmap
+        let member_index = multisig_state
+            .member_index(self.accounts.member.address())
+            .ok_or(MultisigError::NotAMember)?;
+
+
+        // 2: Status Check
+        // Executed proposals are closed, but the status is checked anyway
+        if !proposal_state.is_active() {
+            return Err(MultisigError::ProposalNotActive.into());
+        }
+
+
+        // 3: Expiry Check
+        // Approving a stale proposal has no effect other than noise
+        let now = Clock::get()?.unix_timestamp;
+        if proposal_state.is_expired(now) {
+            return Err(MultisigError::ProposalExpired.into());
+        }
+
+
+        // 4: Bitmap Update
+        // approve() refuses a bit that is already set, so one member can
+        // never count twice toward the threshold
+        if !proposal_state.approve(member_index) {
+            return Err(MultisigError::AlreadyApproved.into());
+        }
+
+
+        // 5: Approval Count Sanity Check
+        // Defense against bitmap bugs: never more approvals than members
+        if proposal_state.approval_count > multisig_state.owner_count {
+            return Err(MultisigError::Overflow.into());
+        }
+
+        Ok(())
+    }
+}
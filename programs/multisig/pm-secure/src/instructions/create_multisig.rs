@@ -0,0 +1,240 @@
+use pinocchio::{
+    AccountView, Address, ProgramResult, cpi::Seed, cpi::Signer, error::ProgramError,
+    sysvars::{Sysvar, rent::Rent}
+};
+
+use crate::{
+    constants::{MAX_TIMELOCK, VAULT_SEED},
+    errors::MultisigError,
+    state::MultisigState,
+};
+
+
+// Account context for the Create Multisig instruction
+//
+// The creator opens a multisig with themselves as the only member (Admin,
+// threshold 1) and a SOL vault PDA. Further members are added by proposal.
+//
+// In Anchor, this would be generated by #[derive(Accounts)]
+// In Pinocchio, we write all validation logic manually.
+pub struct CreateMultisigAccounts<'a> {
+    // Pays for both accounts and becomes the first member
+    pub creator: &'a AccountView,
+
+    // The multisig PDA (will be created)
+    pub multisig: &'a AccountView,
+
+    // The vault PDA (will be created as a system account)
+    pub vault: &'a AccountView,
+    pub system_program: &'a AccountView,
+}
+
+// Converting FROM: &'a[AccountView] (what runtime gives us)
+// Converting TO: CreateMultisigAccounts<'a> (our validated, typed struct)
+impl<'a> TryFrom<&'a [AccountView]> for CreateMultisigAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [creator, multisig, vault, system_program, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // SECURITY CHECKS
+
+        // 1: Signer Check
+        // The creator pays for the accounts and is recorded as admin
+        if !creator.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+
+        // 2: System Program Check
+        // Both accounts are created through it, a fake program could
+        // "create" them without transferring any rent
+        if system_program.address().ne(&pinocchio_system::ID) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+
+        // 3: Multisig Account Checks
+        // Must be empty (no reinitialization) and writable
+        if !multisig.is_data_empty() || multisig.lamports() != 0 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        if !multisig.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+
+        // 4: Vault Account Checks
+        // A vault that already holds lamports would make CreateAccount fail,
+        // so reject it up front with a clear error
+        if !vault.is_data_empty() || vault.lamports() != 0 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        if !vault.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            creator,
+            multisig,
+            vault,
+            system_program,
+        })
+    }
+}
+
+
+// Instruction data for creating a multisig
+//
+// Packed little-endian bytes, no padding:
+// [multisig_id: 8][timelock_seconds: 8]
+pub struct CreateMultisigData {
+    // Identifier chosen by the creator (used in PDA derivation)
+    pub multisig_id: [u8; 8],
+
+    // Delay between proposal creation and earliest execution
+    pub timelock_seconds: u64,
+}
+
+impl CreateMultisigData {
+    pub const LEN: usize = 16;
+}
+
+impl<'a> TryFrom<&'a [u8]> for CreateMultisigData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let mut multisig_id = [0u8; 8];
+        multisig_id.copy_from_slice(&data[0..8]);
+
+        let mut timelock_bytes = [0u8; 8];
+        timelock_bytes.copy_from_slice(&data[8..16]);
+
+        Ok(Self {
+            multisig_id,
+            timelock_seconds: u64::from_le_bytes(timelock_bytes),
+        })
+    }
+}
+
+
+// Complete instruction context - combines validated accounts and parsed data
+pub struct CreateMultisigInstruction<'a> {
+    pub accounts: CreateMultisigAccounts<'a>,
+    pub data: CreateMultisigData,
+}
+
+impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for CreateMultisigInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, data): (&'a [AccountView], &'a [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = CreateMultisigAccounts::try_from(accounts)?;
+        let data = CreateMultisigData::try_from(data)?;
+
+        Ok(Self { accounts, data })
+    }
+}
+
+
+// INSTRUCTION HANDLER
+
+impl<'a> CreateMultisigInstruction<'a> {
+    pub fn handler(&self) -> ProgramResult {
+
+        // 1: Validate Timelock
+        // Bounded so a typo cannot freeze every proposal for years
+        if self.data.timelock_seconds > MAX_TIMELOCK {
+            return Err(MultisigError::InvalidParameter.into());
+        }
+
+
+        // 2: Verify Multisig PDA Address
+        // Seeds include the creator, so nobody can squat another user's multisig
+        let (expected_multisig, bump) = Address::find_program_address(
+            &[
+                MultisigState::SEED_PREFIX,
+                self.accounts.creator.address().as_array(),
+                &self.data.multisig_id,
+            ],
+            &crate::ID,
+        );
+
+        if expected_multisig.ne(self.accounts.multisig.address()) {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+
+        // 3: Verify Vault PDA Address
+        // Only this multisig can sign for its vault
+        let (expected_vault, vault_bump) = Address::find_program_address(
+            &[VAULT_SEED, self.accounts.multisig.address().as_array()],
+            &crate::ID,
+        );
+
+        if expected_vault.ne(self.accounts.vault.address()) {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+
+        // 4: Create the multisig PDA account
+        let rent = Rent::get()?;
+        let space = MultisigState::LEN;
+
+        pinocchio_system::instructions::CreateAccount {
+            from: self.accounts.creator,
+            to: self.accounts.multisig,
+            space: space as u64,
+            lamports: rent.try_minimum_balance(space)?,
+            owner: &crate::ID,
+        }
+        .invoke_signed(&[Signer::from(&[
+            Seed::from(MultisigState::SEED_PREFIX),
+            Seed::from(self.accounts.creator.address().as_array()),
+            Seed::from(&self.data.multisig_id),
+            Seed::from(&[bump]),
+        ])])?;
+
+
+        // 5: Create the vault PDA as a system account
+        // Zero space and system-owned, so the system program can move its
+        // lamports later when the vault PDA signs a transfer
+        pinocchio_system::instructions::CreateAccount {
+            from: self.accounts.creator,
+            to: self.accounts.vault,
+            space: 0,
+            lamports: rent.try_minimum_balance(0)?,
+            owner: &pinocchio_system::ID,
+        }
+        .invoke_signed(&[Signer::from(&[
+            Seed::from(VAULT_SEED),
+            Seed::from(self.accounts.multisig.address().as_array()),
+            Seed::from(&[vault_bump]),
+        ])])?;
+
+
+        // 6: Initialize the multisig state
+        let mut multisig_data = self.accounts.multisig.try_borrow_mut()?;
+        let multisig_state = MultisigState::load_mut(&mut multisig_data)?;
+
+        multisig_state.set_inner(
+            self.data.multisig_id,
+            *self.accounts.creator.address(),
+            *self.accounts.vault.address(),
+            self.data.timelock_seconds,
+            bump,
+            vault_bump,
+        );
+
+        Ok(())
+    }
+}
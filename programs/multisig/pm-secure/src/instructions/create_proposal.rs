@@ -0,0 +1,347 @@
+use pinocchio::{
+    AccountView, Address, ProgramResult, cpi::Seed, cpi::Signer, error::ProgramError,
+    sysvars::{Sysvar, clock::Clock, rent::Rent}
+};
+
+use crate::{
+    constants::{DEFAULT_EXPIRY_PERIOD, MAX_OWNERS, MAX_TIMELOCK},
+    errors::MultisigError,
+    state::{MemberRole, MultisigState, ProposalKind, ProposalState},
+};
+
+
+// Account context for the Create Proposal instruction
+// Shared with CreateTransferProposal, which needs the same accounts
+//
+// A member with the Admin or Proposer role opens a proposal. The proposal id
+// is the multisig's proposal_count, so ids are sequential and cannot collide.
+pub struct CreateProposalAccounts<'a> {
+    // Pays the proposal rent and gets it back on execution
+    pub proposer: &'a AccountView,
+    pub multisig: &'a AccountView,
+
+    // The proposal PDA (will be created)
+    pub proposal: &'a AccountView,
+    pub system_program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for CreateProposalAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [proposer, multisig, proposal, system_program, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // SECURITY CHECKS
+
+        // 1: Signer Check
+        if !proposer.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+
+        // 2: System Program Check
+        if system_program.address().ne(&pinocchio_system::ID) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+
+        // 3: Multisig Account Checks
+        // Owner, size, initialized and PDA derivation
+        // Writable because proposal_count is incremented
+        MultisigState::check_account(multisig)?;
+
+        if !multisig.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+
+        // 4: Role Check
+        // Only Admins and Proposers may open proposals
+        {
+            let multisig_data = multisig.try_borrow()?;
+            let multisig_state = MultisigState::load(&multisig_data)?;
+
+            if !multisig_state.is_member(proposer.address()) {
+                return Err(MultisigError::NotAMember.into());
+            }
+
+            if !multisig_state.can_propose(proposer.address()) {
+                return Err(MultisigError::CannotPropose.into());
+            }
+        }
+
+
+        // 5: Proposal Account Checks
+        // Must be empty (no reinitialization) and writable
+        // The address is verified against the next id in open_proposal
+        if !proposal.is_data_empty() || proposal.lamports() != 0 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        if !proposal.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            proposer,
+            multisig,
+            proposal,
+            system_program,
+        })
+    }
+}
+
+
+// Instruction data for a governance proposal
+//
+// Packed little-endian bytes, no padding:
+// [kind: 1][role: 1][target: 32][value: 8]
+pub struct CreateProposalData {
+    pub kind: ProposalKind,
+    pub role: u8,
+    pub target: Address,
+    pub value: u64,
+}
+
+impl CreateProposalData {
+    pub const LEN: usize = 42;
+}
+
+impl<'a> TryFrom<&'a [u8]> for CreateProposalData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let mut target = [0u8; 32];
+        target.copy_from_slice(&data[2..34]);
+
+        let mut value = [0u8; 8];
+        value.copy_from_slice(&data[34..42]);
+
+        Ok(Self {
+            kind: ProposalKind::try_from(data[0])?,
+            role: data[1],
+            target: Address::new_from_array(target),
+            value: u64::from_le_bytes(value),
+        })
+    }
+}
+
+
+pub struct CreateProposalInstruction<'a> {
+    pub accounts: CreateProposalAccounts<'a>,
+    pub data: CreateProposalData,
+}
+
+impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for CreateProposalInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, data): (&'a [AccountView], &'a [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = CreateProposalAccounts::try_from(accounts)?;
+        let data = CreateProposalData::try_from(data)?;
+
+        Ok(Self { accounts, data })
+    }
+}
+
+
+// INSTRUCTION HANDLER
+
+impl<'a> CreateProposalInstruction<'a> {
+    pub fn handler(&self) -> ProgramResult {
+
+        // 1: Validate the proposed change against the current multisig
+        // Catches proposals that could never execute before anyone votes;
+        // execute_proposal re-checks since membership may change meanwhile
+        {
+            let multisig_data = self.accounts.multisig.try_borrow()?;
+            let multisig_state = MultisigState::load(&multisig_data)?;
+            let proposer = self.accounts.proposer.address();
+            let target = &self.data.target;
+
+            match self.data.kind {
+                ProposalKind::AddMember => {
+                    if !multisig_state.is_admin(proposer) {
+                        return Err(MultisigError::OnlyAdmin.into());
+                    }
+                    if target.eq(proposer) {
+                        return Err(MultisigError::CannotAddSelf.into());
+                    }
+                    if target.as_array() == &[0u8; 32] {
+                        return Err(MultisigError::InvalidParameter.into());
+                    }
+                    if multisig_state.is_member(target) {
+                        return Err(MultisigError::AlreadyMember.into());
+                    }
+                    if multisig_state.owner_count as usize >= MAX_OWNERS {
+                        return Err(MultisigError::MaxMembersReached.into());
+                    }
+                    MemberRole::try_from(self.data.role)?;
+                }
+
+                ProposalKind::RemoveMember => {
+                    if !multisig_state.is_admin(proposer) {
+                        return Err(MultisigError::OnlyAdmin.into());
+                    }
+                    if !multisig_state.is_member(target) {
+                        return Err(MultisigError::NotAMember.into());
+                    }
+                    if target.eq(&multisig_state.creator) {
+                        return Err(MultisigError::CannotRemoveCreator.into());
+                    }
+                    if multisig_state.owner_count <= 1 {
+                        return Err(MultisigError::MinimumOneMember.into());
+                    }
+                    // Removing a member must not leave the threshold unreachable
+                    if multisig_state.threshold > multisig_state.owner_count - 1 {
+                        return Err(MultisigError::InvalidThreshold.into());
+                    }
+                }
+
+                ProposalKind::ChangeThreshold => {
+                    if self.data.value < 1 {
+                        return Err(MultisigError::InvalidThreshold.into());
+                    }
+                    if self.data.value > multisig_state.owner_count as u64 {
+                        return Err(MultisigError::ThresholdExceedsOwners.into());
+                    }
+                }
+
+                ProposalKind::ChangeTimelock => {
+                    if !multisig_state.is_admin(proposer) {
+                        return Err(MultisigError::OnlyAdmin.into());
+                    }
+                    if self.data.value > MAX_TIMELOCK {
+                        return Err(MultisigError::InvalidParameter.into());
+                    }
+                }
+
+                // Transfers go through CreateTransferProposal, which validates
+                // the recipient and amount
+                ProposalKind::TransferSol => {
+                    return Err(MultisigError::InvalidProposalType.into());
+                }
+            }
+        }
+
+
+        // 2: Create, initialize and auto-approve the proposal
+        open_proposal(
+            &self.accounts,
+            self.data.kind,
+            self.data.role,
+            self.data.target,
+            self.data.value,
+        )
+    }
+}
+
+
+// Create the proposal PDA for the next id and record the proposer's approval
+// Used by both CreateProposal and CreateTransferProposal
+pub(crate) fn open_proposal(
+    accounts: &CreateProposalAccounts,
+    kind: ProposalKind,
+    role: u8,
+    target: Address,
+    value: u64,
+) -> ProgramResult {
+
+    // 1: Read the next id, expiry inputs and the proposer's bitmap bit
+    let (proposal_id, timelock_seconds, proposer_index) = {
+        let multisig_data = accounts.multisig.try_borrow()?;
+        let multisig_state = MultisigState::load(&multisig_data)?;
+
+        let proposer_index = multisig_state
+            .member_index(accounts.proposer.address())
+            .ok_or(MultisigError::NotAMember)?;
+
+        (multisig_state.proposal_count, multisig_state.timelock_seconds, proposer_index)
+    };
+    let proposal_id_bytes = proposal_id.to_le_bytes();
+
+
+    // 2: Verify Proposal PDA Address
+    // The id comes from the multisig, never from instruction data, so a
+    // proposal cannot overwrite or pre-empt another one
+    let (expected_proposal, bump) = Address::find_program_address(
+        &[
+            ProposalState::SEED_PREFIX,
+            accounts.multisig.address().as_array(),
+            &proposal_id_bytes,
+        ],
+        &crate::ID,
+    );
+
+    if expected_proposal.ne(accounts.proposal.address()) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+
+    // 3: Compute the expiry
+    // created_at + timelock + grace period, with checked math
+    let created_at = Clock::get()?.unix_timestamp;
+    let expires_at = timelock_seconds
+        .checked_add(DEFAULT_EXPIRY_PERIOD)
+        .and_then(|window| created_at.checked_add(window as i64))
+        .ok_or(MultisigError::Overflow)?;
+
+
+    // 4: Create the proposal PDA account
+    let space = ProposalState::LEN;
+    let lamports = Rent::get()?.try_minimum_balance(space)?;
+
+    pinocchio_system::instructions::CreateAccount {
+        from: accounts.proposer,
+        to: accounts.proposal,
+        space: space as u64,
+        lamports,
+        owner: &crate::ID,
+    }
+    .invoke_signed(&[Signer::from(&[
+        Seed::from(ProposalState::SEED_PREFIX),
+        Seed::from(accounts.multisig.address().as_array()),
+        Seed::from(&proposal_id_bytes),
+        Seed::from(&[bump]),
+    ])])?;
+
+
+    // 5: Initialize the proposal and auto-approve for the proposer
+    {
+        let mut proposal_data = accounts.proposal.try_borrow_mut()?;
+        let proposal_state = ProposalState::load_mut(&mut proposal_data)?;
+
+        proposal_state.set_inner(
+            proposal_id,
+            *accounts.multisig.address(),
+            *accounts.proposer.address(),
+            kind,
+            role,
+            target,
+            value,
+            created_at,
+            expires_at,
+            bump,
+        );
+        proposal_state.approve(proposer_index);
+    }
+
+
+    // 6: Advance the proposal counter
+    let mut multisig_data = accounts.multisig.try_borrow_mut()?;
+    let multisig_state = MultisigState::load_mut(&mut multisig_data)?;
+
+    multisig_state.proposal_count = multisig_state
+        .proposal_count
+        .checked_add(1)
+        .ok_or(MultisigError::Overflow)?;
+
+    Ok(())
+}
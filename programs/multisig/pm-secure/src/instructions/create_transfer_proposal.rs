@@ -0,0 +1,107 @@
+use pinocchio::{AccountView, Address, ProgramResult, error::ProgramError};
+
+use crate::{
+    errors::MultisigError,
+    state::{MultisigState, ProposalKind},
+};
+
+use super::create_proposal::{open_proposal, CreateProposalAccounts};
+
+
+// Instruction data for a SOL transfer proposal
+//
+// Accounts are the same as CreateProposal: [proposer, multisig, proposal, system_program]
+// The proposal stores the recipient and amount; the vault is only touched on execution
+//
+// Packed little-endian bytes, no padding:
+// [amount: 8][recipient: 32]
+pub struct CreateTransferProposalData {
+    // Lamports to send from the vault
+    pub amount: u64,
+    pub recipient: Address,
+}
+
+impl CreateTransferProposalData {
+    pub const LEN: usize = 40;
+}
+
+impl<'a> TryFrom<&'a [u8]> for CreateTransferProposalData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let mut amount = [0u8; 8];
+        amount.copy_from_slice(&data[0..8]);
+
+        let mut recipient = [0u8; 32];
+        recipient.copy_from_slice(&data[8..40]);
+
+        Ok(Self {
+            amount: u64::from_le_bytes(amount),
+            recipient: Address::new_from_array(recipient),
+        })
+    }
+}
+
+
+pub struct CreateTransferProposalInstruction<'a> {
+    pub accounts: CreateProposalAccounts<'a>,
+    pub data: CreateTransferProposalData,
+}
+
+impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for CreateTransferProposalInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, data): (&'a [AccountView], &'a [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = CreateProposalAccounts::try_from(accounts)?;
+        let data = CreateTransferProposalData::try_from(data)?;
+
+        Ok(Self { accounts, data })
+    }
+}
+
+
+// INSTRUCTION HANDLER
+
+impl<'a> CreateTransferProposalInstruction<'a> {
+    pub fn handler(&self) -> ProgramResult {
+
+        // 1: Amount Check
+        // A zero transfer would only burn an approval round
+        if self.data.amount == 0 {
+            return Err(MultisigError::InvalidParameter.into());
+        }
+
+
+        // 2: Recipient Checks
+        // The default address is a common client bug, and paying the vault
+        // back to itself is a no-op that still consumes a proposal
+        if self.data.recipient.as_array() == &[0u8; 32] {
+            return Err(MultisigError::InvalidRecipient.into());
+        }
+
+        let vault = {
+            let multisig_data = self.accounts.multisig.try_borrow()?;
+            MultisigState::load(&multisig_data)?.vault
+        };
+
+        if self.data.recipient.eq(&vault) {
+            return Err(MultisigError::InvalidRecipient.into());
+        }
+
+
+        // 3: Create, initialize and auto-approve the proposal
+        open_proposal(
+            &self.accounts,
+            ProposalKind::TransferSol,
+            0,
+            self.data.recipient,
+            self.data.amount,
+        )
+    }
+}
@@ -0,0 +1,192 @@
+use pinocchio::{
+    AccountView, ProgramResult, error::ProgramError,
+    sysvars::{Sysvar, clock::Clock}
+};
+
+use crate::{
+    constants::MAX_TIMELOCK,
+    errors::MultisigError,
+    state::{MemberRole, MultisigState, ProposalKind, ProposalState},
+};
+
+
+// Account context for the Execute Proposal instruction
+//
+// An Admin or Executor applies a governance proposal (members, threshold,
+// timelock) once it has enough approvals and its timelock has passed.
+// The proposal is closed and its rent returned to the proposer who paid it.
+pub struct ExecuteProposalAccounts<'a> {
+    pub executor: &'a AccountView,
+    pub multisig: &'a AccountView,
+    pub proposal: &'a AccountView,
+
+    // Receives the proposal rent
+    pub proposer: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for ExecuteProposalAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [executor, multisig, proposal, proposer, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // SECURITY CHECKS
+
+        // 1: Signer Check
+        if !executor.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+
+        // 2: Multisig Account Checks
+        // Owner, size, initialized and PDA derivation
+        // Writable because the governance change is written to it
+        MultisigState::check_account(multisig)?;
+
+        if !multisig.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+
+        // 3: Proposal Account Checks
+        // Owner, size, writable, PDA derivation and proposal.multisig == multisig
+        ProposalState::check_account(proposal, multisig.address())?;
+
+
+        // 4: Executor Role, Proposal Kind and Rent Destination
+        {
+            let multisig_data = multisig.try_borrow()?;
+            let multisig_state = MultisigState::load(&multisig_data)?;
+
+            if !multisig_state.can_execute(executor.address()) {
+                return Err(MultisigError::CannotExecute.into());
+            }
+
+            let proposal_data = proposal.try_borrow()?;
+            let proposal_state = ProposalState::load(&proposal_data)?;
+
+            // Transfers move vault funds and must go through ExecuteTransferProposal
+            if proposal_state.kind == ProposalKind::TransferSol as u8 {
+                return Err(MultisigError::InvalidProposalType.into());
+            }
+
+            // Rent goes back to whoever paid for the proposal, not the caller
+            if proposal_state.proposer.ne(proposer.address()) {
+                return Err(MultisigError::InvalidProposal.into());
+            }
+        }
+
+        if !proposer.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            executor,
+            multisig,
+            proposal,
+            proposer,
+        })
+    }
+}
+
+
+pub struct ExecuteProposalInstruction<'a> {
+    pub accounts: ExecuteProposalAccounts<'a>,
+}
+
+impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for ExecuteProposalInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, _data): (&'a [AccountView], &'a [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = ExecuteProposalAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+
+// INSTRUCTION HANDLER
+
+impl<'a> ExecuteProposalInstruction<'a> {
+    pub fn handler(&self) -> ProgramResult {
+        {
+            let mut multisig_data = self.accounts.multisig.try_borrow_mut()?;
+            let multisig_state = MultisigState::load_mut(&mut multisig_data)?;
+
+            let proposal_data = self.accounts.proposal.try_borrow()?;
+            let proposal_state = ProposalState::load(&proposal_data)?;
+
+            // 1: Execution Gate
+            // Active, threshold reached, timelock passed, not expired
+            let now = Clock::get()?.unix_timestamp;
+            proposal_state.check_executable(multisig_state, now)?;
+
+
+            // 2: Apply the Change
+            // Every precondition checked at creation is checked again, since
+            // other proposals may have changed the multisig in the meantime
+            let target = &proposal_state.target;
+
+            match ProposalKind::try_from(proposal_state.kind)? {
+                ProposalKind::AddMember => {
+                    if multisig_state.is_member(target) {
+                        return Err(MultisigError::AlreadyMember.into());
+                    }
+                    if target.as_array() == &[0u8; 32] {
+                        return Err(MultisigError::InvalidParameter.into());
+                    }
+                    let role = MemberRole::try_from(proposal_state.role)?;
+                    multisig_state.add_member(*target, role)?;
+                }
+
+                ProposalKind::RemoveMember => {
+                    if target.eq(&multisig_state.creator) {
+                        return Err(MultisigError::CannotRemoveCreator.into());
+                    }
+                    if multisig_state.owner_count <= 1 {
+                        return Err(MultisigError::MinimumOneMember.into());
+                    }
+                    let index = multisig_state
+                        .member_index(target)
+                        .ok_or(MultisigError::NotAMember)?;
+                    multisig_state.remove_member(index)?;
+
+                    // The threshold must still be reachable with one member less
+                    if !multisig_state.is_valid_threshold() {
+                        return Err(MultisigError::InvalidThreshold.into());
+                    }
+                }
+
+                ProposalKind::ChangeThreshold => {
+                    if proposal_state.value < 1 {
+                        return Err(MultisigError::InvalidThreshold.into());
+                    }
+                    if proposal_state.value > multisig_state.owner_count as u64 {
+                        return Err(MultisigError::ThresholdExceedsOwners.into());
+                    }
+                    multisig_state.threshold = proposal_state.value as u8;
+                }
+
+                ProposalKind::ChangeTimelock => {
+                    if proposal_state.value > MAX_TIMELOCK {
+                        return Err(MultisigError::InvalidParameter.into());
+                    }
+                    multisig_state.timelock_seconds = proposal_state.value;
+                }
+
+                ProposalKind::TransferSol => {
+                    return Err(MultisigError::InvalidProposalType.into());
+                }
+            }
+        }
+
+
+        // 3: Close the Proposal
+        // Marked executed first, then rent returned to the proposer
+        ProposalState::close(self.accounts.proposal, self.accounts.proposer)
+    }
+}
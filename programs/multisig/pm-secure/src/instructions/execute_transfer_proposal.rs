@@ -0,0 +1,198 @@
+use pinocchio::{
+    AccountView, ProgramResult, cpi::Seed, cpi::Signer, error::ProgramError,
+    sysvars::{Sysvar, clock::Clock}
+};
+
+use crate::{
+    constants::VAULT_SEED,
+    errors::MultisigError,
+    state::{MultisigState, ProposalKind, ProposalState},
+};
+
+
+// Account context for the Execute Transfer Proposal instruction
+//
+// An Admin or Executor sends SOL from the vault to the recipient stored in
+// the proposal, once it has enough approvals and its timelock has passed.
+//
+// Flow:
+// 1. Vault PDA signs a system transfer -> Recipient
+// 2. Proposal PDA is closed (rent returned to the proposer)
+pub struct ExecuteTransferProposalAccounts<'a> {
+    pub executor: &'a AccountView,
+    pub multisig: &'a AccountView,
+    pub proposal: &'a AccountView,
+
+    // Receives the proposal rent
+    pub proposer: &'a AccountView,
+
+    // System-owned PDA holding the multisig's SOL
+    pub vault: &'a AccountView,
+    pub recipient: &'a AccountView,
+    pub system_program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for ExecuteTransferProposalAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [executor, multisig, proposal, proposer, vault, recipient, system_program, ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // SECURITY CHECKS
+
+        // 1: Signer Check
+        if !executor.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+
+        // 2: System Program Check
+        // The vault transfer is a CPI into it, a fake program would receive
+        // the vault's signature
+        if system_program.address().ne(&pinocchio_system::ID) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+
+        // 3: Multisig Account Checks
+        // Owner, size, initialized and PDA derivation
+        MultisigState::check_account(multisig)?;
+
+
+        // 4: Proposal Account Checks
+        // Owner, size, writable, PDA derivation and proposal.multisig == multisig
+        ProposalState::check_account(proposal, multisig.address())?;
+
+
+        // 5: Executor Role, Proposal Kind, Vault, Recipient and Rent Destination
+        {
+            let multisig_data = multisig.try_borrow()?;
+            let multisig_state = MultisigState::load(&multisig_data)?;
+
+            if !multisig_state.can_execute(executor.address()) {
+                return Err(MultisigError::CannotExecute.into());
+            }
+
+            let proposal_data = proposal.try_borrow()?;
+            let proposal_state = ProposalState::load(&proposal_data)?;
+
+            // Governance changes must go through ExecuteProposal
+            if proposal_state.kind != ProposalKind::TransferSol as u8 {
+                return Err(MultisigError::InvalidProposalType.into());
+            }
+
+            // Vault must be this multisig's vault, not another multisig's
+            if multisig_state.vault.ne(vault.address()) {
+                return Err(ProgramError::InvalidSeeds);
+            }
+
+            // Recipient must be the one the members approved
+            if proposal_state.target.ne(recipient.address()) {
+                return Err(MultisigError::InvalidRecipient.into());
+            }
+
+            // Rent goes back to whoever paid for the proposal, not the caller
+            if proposal_state.proposer.ne(proposer.address()) {
+                return Err(MultisigError::InvalidProposal.into());
+            }
+        }
+
+        if !vault.owned_by(&pinocchio_system::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if !vault.is_writable() || !recipient.is_writable() || !proposer.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+
+        // 6: Recipient Ownership
+        // Only plain system accounts receive SOL; a program-owned recipient
+        // could be a PDA whose owner program does not expect the lamports
+        if !recipient.owned_by(&pinocchio_system::ID) {
+            return Err(MultisigError::InvalidRecipient.into());
+        }
+
+        Ok(Self {
+            executor,
+            multisig,
+            proposal,
+            proposer,
+            vault,
+            recipient,
+            system_program,
+        })
+    }
+}
+
+
+pub struct ExecuteTransferProposalInstruction<'a> {
+    pub accounts: ExecuteTransferProposalAccounts<'a>,
+}
+
+impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for ExecuteTransferProposalInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, _data): (&'a [AccountView], &'a [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = ExecuteTransferProposalAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+
+// INSTRUCTION HANDLER
+
+impl<'a> ExecuteTransferProposalInstruction<'a> {
+    pub fn handler(&self) -> ProgramResult {
+
+        // 1: Execution Gate
+        // Active, threshold reached, timelock passed, not expired
+        let (amount, vault_bump) = {
+            let multisig_data = self.accounts.multisig.try_borrow()?;
+            let multisig_state = MultisigState::load(&multisig_data)?;
+
+            let proposal_data = self.accounts.proposal.try_borrow()?;
+            let proposal_state = ProposalState::load(&proposal_data)?;
+
+            let now = Clock::get()?.unix_timestamp;
+            proposal_state.check_executable(multisig_state, now)?;
+
+            (proposal_state.value, multisig_state.vault_bump)
+        };
+
+
+        // 2: Balance Check
+        // Fail with a clear error instead of a system program failure
+        if self.accounts.vault.lamports() < amount {
+            return Err(MultisigError::InsufficientFunds.into());
+        }
+
+
+        // 3: Transfer SOL from the Vault
+        // The vault is system-owned, so only a system transfer signed with
+        // its PDA seeds can move the lamports
+        let bump_binding = [vault_bump];
+        pinocchio_system::instructions::Transfer {
+            from: self.accounts.vault,
+            to: self.accounts.recipient,
+            lamports: amount,
+        }
+        .invoke_signed(&[Signer::from(&[
+            Seed::from(VAULT_SEED),
+            Seed::from(self.accounts.multisig.address().as_array()),
+            Seed::from(&bump_binding),
+        ])])?;
+
+
+        // 4: Close the Proposal
+        // Marked executed first, then rent returned to the proposer
+        ProposalState::close(self.accounts.proposal, self.accounts.proposer)
+    }
+}
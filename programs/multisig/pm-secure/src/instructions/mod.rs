@@ -0,0 +1,41 @@
+pub mod create_multisig;
+pub mod create_proposal;
+pub mod create_transfer_proposal;
+pub mod approve_proposal;
+pub mod execute_proposal;
+pub mod execute_transfer_proposal;
+
+pub use create_multisig::*;
+pub use create_proposal::*;
+pub use create_transfer_proposal::*;
+pub use approve_proposal::*;
+pub use execute_proposal::*;
+pub use execute_transfer_proposal::*;
+
+use pinocchio::error::ProgramError;
+
+#[repr(u8)]
+pub enum Instruction {
+    CreateMultisig = 0,
+    CreateProposal = 1,
+    CreateTransferProposal = 2,
+    ApproveProposal = 3,
+    ExecuteProposal = 4,
+    ExecuteTransferProposal = 5,
+}
+
+impl TryFrom<&u8> for Instruction {
+    type Error = ProgramError;
+
+    fn try_from(value: &u8) -> Result<Self, Self::Error> {
+        match *value {
+            0 => Ok(Instruction::CreateMultisig),
+            1 => Ok(Instruction::CreateProposal),
+            2 => Ok(Instruction::CreateTransferProposal),
+            3 => Ok(Instruction::ApproveProposal),
+            4 => Ok(Instruction::ExecuteProposal),
+            5 => Ok(Instruction::ExecuteTransferProposal),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
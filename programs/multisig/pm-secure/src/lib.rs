@@ -0,0 +1,76 @@
+#![no_std]
+
+use pinocchio::{
+    AccountView,
+    Address,
+    ProgramResult,
+    error::ProgramError,
+    address,
+    no_allocator,
+    program_entrypoint
+};
+
+pub mod constants;
+pub mod errors;
+pub mod state;
+pub mod instructions;
+
+use instructions::{
+    CreateMultisigInstruction,
+    CreateProposalInstruction,
+    CreateTransferProposalInstruction,
+    ApproveProposalInstruction,
+    ExecuteProposalInstruction,
+    ExecuteTransferProposalInstruction,
+    Instruction
+};
+
+address::declare_id!("8CGhZB6i2y3mCe2K27Z2UCTg3nBfohgi6VYE5cjKupx9");
+
+program_entrypoint!(process_instruction);
+no_allocator!();
+
+pub fn process_instruction(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+
+    // Verify correct program ID
+    if program_id != &crate::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Split discriminator from instruction data
+    let (discriminator, data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    // Route to appropriate instruction handler
+    match Instruction::try_from(discriminator)? {
+        Instruction::CreateMultisig => {
+            let ix = CreateMultisigInstruction::try_from((accounts, data))?;
+            ix.handler()
+        }
+        Instruction::CreateProposal => {
+            let ix = CreateProposalInstruction::try_from((accounts, data))?;
+            ix.handler()
+        }
+        Instruction::CreateTransferProposal => {
+            let ix = CreateTransferProposalInstruction::try_from((accounts, data))?;
+            ix.handler()
+        }
+        Instruction::ApproveProposal => {
+            let ix = ApproveProposalInstruction::try_from((accounts, data))?;
+            ix.handler()
+        }
+        Instruction::ExecuteProposal => {
+            let ix = ExecuteProposalInstruction::try_from((accounts, data))?;
+            ix.handler()
+        }
+        Instruction::ExecuteTransferProposal => {
+            let ix = ExecuteTransferProposalInstruction::try_from((accounts, data))?;
+            ix.handler()
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod multisig;
+pub mod proposal;
+
+pub use multisig::*;
+pub use proposal::*;
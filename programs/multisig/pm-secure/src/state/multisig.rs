@@ -0,0 +1,255 @@
+use pinocchio::{AccountView, Address, error::ProgramError};
+use core::mem::transmute;
+
+use crate::{constants::MAX_OWNERS, errors::MultisigError};
+
+
+// Member roles, stored as one byte per member slot
+//
+// Admin    - can propose and execute; only the creator is treated as admin
+//            for membership and timelock changes
+// Proposer - can create proposals
+// Executor - can execute proposals that reached threshold
+// Every member can approve, whatever their role
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum MemberRole {
+    Admin = 0,
+    Proposer = 1,
+    Executor = 2,
+}
+
+impl TryFrom<u8> for MemberRole {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(MemberRole::Admin),
+            1 => Ok(MemberRole::Proposer),
+            2 => Ok(MemberRole::Executor),
+            _ => Err(MultisigError::InvalidRole.into()),
+        }
+    }
+}
+
+
+// This represents the multisig PDA: its members, their roles and the rules a
+// proposal must satisfy before it can be executed.
+//
+// Anchor's version stores a [Member; 10] array of (pubkey, enum) pairs. A Rust
+// enum with data has no stable layout, so here the pubkeys and the role bytes
+// are kept in two parallel arrays that share the same index.
+//
+// The u64 fields come first so #[repr(C)] inserts no padding between fields;
+// the explicit tail padding keeps LEN a multiple of 8.
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct MultisigState {
+    // Caller-chosen identifier (used in PDA derivation)
+    pub multisig_id: [u8; 8],
+    // Number of proposals created so far, doubles as the next proposal id
+    pub proposal_count: u64,
+    // Delay between proposal creation and earliest execution
+    pub timelock_seconds: u64,
+    pub creator: Address,
+    // System-owned PDA holding the SOL moved by transfer proposals
+    pub vault: Address,
+    // Member at index i owns bit i of every proposal's approval bitmap
+    pub members: [Address; MAX_OWNERS],
+    pub roles: [u8; MAX_OWNERS],
+    pub threshold: u8,
+    pub owner_count: u8,
+    pub bump: u8,
+    pub vault_bump: u8,
+    // 0 = uninitialized, 1 = active
+    pub is_initialized: u8,
+    pub _padding: [u8; 1],
+}
+
+
+impl MultisigState {
+    // Seed prefix for PDA derivation: ["multisig", creator, multisig_id]
+    pub const SEED_PREFIX: &'static [u8] = b"multisig";
+    pub const LEN: usize = core::mem::size_of::<MultisigState>();
+
+    // Load mutable reference from account data
+    //
+    // Safety:
+    // 1. We verify the length matches exactly
+    // 2. #[repr(C)] ensures predictable, sequential memory layout
+    // 3. All fields are plain old data (POD) types
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() != MultisigState::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &mut *transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    // Load immutable reference from account data
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &*(bytes.as_ptr() as *const Self) })
+    }
+
+    // Initialize a single-member multisig
+    // The creator is the only member, an Admin, and the threshold is 1;
+    // everybody else joins through AddMember proposals
+    #[inline(always)]
+    pub fn set_inner(
+        &mut self,
+        multisig_id: [u8; 8],
+        creator: Address,
+        vault: Address,
+        timelock_seconds: u64,
+        bump: u8,
+        vault_bump: u8,
+    ) {
+        self.multisig_id = multisig_id;
+        self.proposal_count = 0;
+        self.timelock_seconds = timelock_seconds;
+        self.creator = creator;
+        self.vault = vault;
+        self.members = [Address::new_from_array([0u8; 32]); MAX_OWNERS];
+        self.members[0] = creator;
+        self.roles = [0u8; MAX_OWNERS];
+        self.roles[0] = MemberRole::Admin as u8;
+        self.threshold = 1;
+        self.owner_count = 1;
+        self.bump = bump;
+        self.vault_bump = vault_bump;
+        self.is_initialized = 1;
+    }
+
+    // Validate an account claiming to be a multisig
+    //
+    // Owner, size and initialized checks reject fakes and other account types
+    // (a ProposalState has a different LEN). The PDA check ties the account
+    // to the creator and id stored inside it.
+    pub fn check_account(account: &AccountView) -> Result<(), ProgramError> {
+        if !account.owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let data = account.try_borrow()?;
+        let state = Self::load(&data)?;
+
+        if !state.is_active() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        let (expected_address, _) = Address::find_program_address(
+            &[
+                Self::SEED_PREFIX,
+                state.creator.as_array(),
+                &state.multisig_id,
+            ],
+            &crate::ID,
+        );
+
+        if expected_address.ne(account.address()) {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        Ok(())
+    }
+
+    // Helper: Check if multisig is initialized
+    #[inline(always)]
+    pub fn is_active(&self) -> bool {
+        self.is_initialized == 1
+    }
+
+    // Helper: Position of a member, which is also their bitmap bit
+    // Only the first owner_count slots are live
+    #[inline(always)]
+    pub fn member_index(&self, address: &Address) -> Option<usize> {
+        self.members[..self.owner_count as usize]
+            .iter()
+            .position(|member| member.eq(address))
+    }
+
+    #[inline(always)]
+    pub fn is_member(&self, address: &Address) -> bool {
+        self.member_index(address).is_some()
+    }
+
+    // Helper: Admins and Proposers may create proposals
+    #[inline(always)]
+    pub fn can_propose(&self, address: &Address) -> bool {
+        match self.member_index(address) {
+            Some(index) => {
+                self.roles[index] == MemberRole::Admin as u8
+                    || self.roles[index] == MemberRole::Proposer as u8
+            }
+            None => false,
+        }
+    }
+
+    // Helper: Admins and Executors may execute proposals
+    #[inline(always)]
+    pub fn can_execute(&self, address: &Address) -> bool {
+        match self.member_index(address) {
+            Some(index) => {
+                self.roles[index] == MemberRole::Admin as u8
+                    || self.roles[index] == MemberRole::Executor as u8
+            }
+            None => false,
+        }
+    }
+
+    // Helper: Only the creator may propose membership and timelock changes
+    #[inline(always)]
+    pub fn is_admin(&self, address: &Address) -> bool {
+        self.creator.eq(address)
+    }
+
+    // Helper: Threshold must be between 1 and the member count
+    #[inline(always)]
+    pub fn is_valid_threshold(&self) -> bool {
+        self.threshold >= 1 && self.threshold <= self.owner_count
+    }
+
+    // Append a member to the next free slot
+    pub fn add_member(&mut self, member: Address, role: MemberRole) -> Result<(), ProgramError> {
+        let index = self.owner_count as usize;
+        if index >= MAX_OWNERS {
+            return Err(MultisigError::MaxMembersReached.into());
+        }
+
+        self.members[index] = member;
+        self.roles[index] = role as u8;
+        self.owner_count += 1;
+        Ok(())
+    }
+
+    // Remove the member at `index`, shifting later members left so the live
+    // slots stay contiguous
+    pub fn remove_member(&mut self, index: usize) -> Result<(), ProgramError> {
+        let owner_count = self.owner_count as usize;
+        if index >= owner_count {
+            return Err(MultisigError::NotAMember.into());
+        }
+
+        for i in index..owner_count - 1 {
+            self.members[i] = self.members[i + 1];
+            self.roles[i] = self.roles[i + 1];
+        }
+
+        // Clear the last slot
+        self.members[owner_count - 1] = Address::new_from_array([0u8; 32]);
+        self.roles[owner_count - 1] = 0;
+        self.owner_count -= 1;
+        Ok(())
+    }
+}
@@ -0,0 +1,271 @@
+use pinocchio::{AccountView, Address, error::ProgramError};
+use core::mem::transmute;
+
+use crate::{constants::MAX_OWNERS, errors::MultisigError, state::MultisigState};
+
+
+// What a proposal does once executed
+//
+// Anchor's ProposalType is an enum with data; here the kind is one byte and
+// its arguments live in the generic target/value/role fields of ProposalState:
+//
+// AddMember        target = new member, role = their role
+// RemoveMember     target = member to remove
+// ChangeThreshold  value  = new threshold
+// ChangeTimelock   value  = new timelock in seconds
+// TransferSol      target = recipient, value = lamports sent from the vault
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum ProposalKind {
+    AddMember = 0,
+    RemoveMember = 1,
+    ChangeThreshold = 2,
+    ChangeTimelock = 3,
+    TransferSol = 4,
+}
+
+impl TryFrom<u8> for ProposalKind {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ProposalKind::AddMember),
+            1 => Ok(ProposalKind::RemoveMember),
+            2 => Ok(ProposalKind::ChangeThreshold),
+            3 => Ok(ProposalKind::ChangeTimelock),
+            4 => Ok(ProposalKind::TransferSol),
+            _ => Err(MultisigError::InvalidProposalType.into()),
+        }
+    }
+}
+
+
+// This represents a proposal PDA, governance or transfer.
+//
+// Anchor's version uses two account types (Proposal and TransferProposal).
+// One fixed layout is enough here: the kind byte decides which execute
+// instruction accepts the proposal, so a transfer can never be executed as a
+// governance change or the other way round.
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ProposalState {
+    pub proposal_id: u64,
+    // Bit i is set once the member at multisig.members[i] approved
+    pub approval_bitmap: u64,
+    pub created_at: i64,
+    // created_at + timelock + DEFAULT_EXPIRY_PERIOD
+    pub expires_at: i64,
+    // New threshold, new timelock or lamports, depending on kind
+    pub value: u64,
+    pub multisig: Address,
+    // Paid the rent, receives it back when the proposal is executed
+    pub proposer: Address,
+    // Member to add or remove, or transfer recipient, depending on kind
+    pub target: Address,
+    pub kind: u8,
+    // Role for AddMember, unused otherwise
+    pub role: u8,
+    // 0 = uninitialized, 1 = active, 2 = executed
+    pub status: u8,
+    pub approval_count: u8,
+    pub bump: u8,
+    pub _padding: [u8; 3],
+}
+
+
+impl ProposalState {
+    // Seed prefix for PDA derivation: ["proposal", multisig, proposal_id]
+    pub const SEED_PREFIX: &'static [u8] = b"proposal";
+    pub const LEN: usize = core::mem::size_of::<ProposalState>();
+
+    pub const STATUS_ACTIVE: u8 = 1;
+    pub const STATUS_EXECUTED: u8 = 2;
+
+    // Load mutable reference from account data
+    //
+    // Safety: same reasoning as MultisigState::load_mut
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() != ProposalState::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &mut *transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    // Load immutable reference from account data
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &*(bytes.as_ptr() as *const Self) })
+    }
+
+    // Initialize all fields at once
+    #[inline(always)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_inner(
+        &mut self,
+        proposal_id: u64,
+        multisig: Address,
+        proposer: Address,
+        kind: ProposalKind,
+        role: u8,
+        target: Address,
+        value: u64,
+        created_at: i64,
+        expires_at: i64,
+        bump: u8,
+    ) {
+        self.proposal_id = proposal_id;
+        self.approval_bitmap = 0;
+        self.created_at = created_at;
+        self.expires_at = expires_at;
+        self.value = value;
+        self.multisig = multisig;
+        self.proposer = proposer;
+        self.target = target;
+        self.kind = kind as u8;
+        self.role = role;
+        self.status = Self::STATUS_ACTIVE;
+        self.approval_count = 0;
+        self.bump = bump;
+    }
+
+    // Validate an account claiming to be a proposal of `multisig`
+    //
+    // Owner and size checks reject fakes and multisig accounts. The stored
+    // multisig must match, otherwise approvals gathered in one multisig could
+    // be used to execute against another. The PDA check ties the account to
+    // the id stored inside it.
+    pub fn check_account(account: &AccountView, multisig: &Address) -> Result<(), ProgramError> {
+        if !account.owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account.data_len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !account.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let data = account.try_borrow()?;
+        let state = Self::load(&data)?;
+
+        if state.multisig.ne(multisig) {
+            return Err(MultisigError::InvalidProposal.into());
+        }
+
+        let (expected_address, _) = Address::find_program_address(
+            &[
+                Self::SEED_PREFIX,
+                multisig.as_array(),
+                &state.proposal_id.to_le_bytes(),
+            ],
+            &crate::ID,
+        );
+
+        if expected_address.ne(account.address()) {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        Ok(())
+    }
+
+    // Helper: Check if proposal can still be approved or executed
+    #[inline(always)]
+    pub fn is_active(&self) -> bool {
+        self.status == Self::STATUS_ACTIVE
+    }
+
+    // Helper: Check if the member at `owner_index` already approved
+    #[inline(always)]
+    pub fn has_approved(&self, owner_index: usize) -> bool {
+        if owner_index >= MAX_OWNERS {
+            return false;
+        }
+        (self.approval_bitmap & (1u64 << owner_index)) != 0
+    }
+
+    // Record an approval from the member at `owner_index`
+    // Returns false if the index is out of range or the bit is already set
+    #[inline(always)]
+    pub fn approve(&mut self, owner_index: usize) -> bool {
+        if owner_index >= MAX_OWNERS || self.has_approved(owner_index) {
+            return false;
+        }
+
+        self.approval_bitmap |= 1u64 << owner_index;
+        self.approval_count += 1;
+        true
+    }
+
+    // Helper: Check if the timelock has passed
+    #[inline(always)]
+    pub fn timelock_passed(&self, now: i64, timelock_seconds: u64) -> bool {
+        now >= self.created_at.saturating_add(timelock_seconds as i64)
+    }
+
+    // Helper: Check if the proposal deadline has passed
+    #[inline(always)]
+    pub fn is_expired(&self, now: i64) -> bool {
+        now > self.expires_at
+    }
+
+    // Shared execution gate for both execute instructions
+    //
+    // 1. Still active (no double execution)
+    // 2. Enough approvals for the current threshold
+    // 3. Approval count never above the member count (bitmap sanity)
+    // 4. Timelock passed
+    // 5. Not expired
+    pub fn check_executable(&self, multisig: &MultisigState, now: i64) -> Result<(), ProgramError> {
+        if !self.is_active() {
+            return Err(MultisigError::ProposalNotActive.into());
+        }
+
+        if self.approval_count < multisig.threshold {
+            return Err(MultisigError::InsufficientApprovals.into());
+        }
+
+        if self.approval_count > multisig.owner_count {
+            return Err(MultisigError::Overflow.into());
+        }
+
+        #[cfg(not(feature = "vuln-missing-timelock-check"))]
+        if !self.timelock_passed(now, multisig.timelock_seconds) {
+            return Err(MultisigError::TimelockNotPassed.into());
+        }
+
+        if self.is_expired(now) {
+            return Err(MultisigError::ProposalExpired.into());
+        }
+
+        Ok(())
+    }
+
+    // Close a proposal account and send its rent to `destination`
+    //
+    // Same closure procedure as the escrow's offer: mark the data, move the
+    // lamports, shrink to zero bytes and hand the account back to the runtime
+    pub fn close(account: &AccountView, destination: &AccountView) -> Result<(), ProgramError> {
+        {
+            let mut data = account.try_borrow_mut()?;
+            let state = Self::load_mut(&mut data)?;
+            state.status = Self::STATUS_EXECUTED;
+        }
+
+        let lamports = account.lamports();
+        destination.set_lamports(destination.lamports().saturating_add(lamports));
+        account.set_lamports(0);
+        account.resize(0)?;
+        account.close()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,751 @@
+// Integration tests for the Pinocchio multisig program using LiteSVM
+//
+// Test Coverage:
+//
+// === Happy Path Tests ===
+// 1. test_create_multisig - Creator is the only Admin, threshold 1, vault funded for rent
+// 2. test_governance_flow - Add members, raise threshold to 2, second approval required
+// 3. test_transfer_proposal_flow - Fund vault, create and execute a SOL transfer, rent refunded
+// 4. test_remove_member - Removing a member shifts the member list left
+//
+// === Security Tests ===
+// 5. test_timelock_enforcement - Cannot execute before the timelock passes
+// 6. test_bitmap_and_threshold - Double approval, non-member approval, missing approvals
+// 7. test_role_based_access_control - Proposer cannot execute, Executor cannot propose
+// 8. test_proposal_kind_mismatch - Transfers and governance changes use separate execute paths
+// 9. test_transfer_recipient_checks - Recipient must match the proposal, vault balance enforced
+// 10. test_proposal_expiry - Expired proposals cannot be approved or executed
+//
+// === Feature Matrix ===
+// 11. test_feature_missing_timelock_check - Only the vuln-missing-timelock-check
+//     build executes before the timelock
+//
+// HOW TO RUN:
+//   cargo build-sbf
+//   cargo test --test integration
+// Test 11 also needs ./build-vuln-matrix.sh pm-secure from the repository root
+
+use litesvm::{types::TransactionResult, LiteSVM};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::TransactionError,
+};
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+use soteria_test_kit::*;
+
+use pm_secure::{
+    constants::{DEFAULT_EXPIRY_PERIOD, VAULT_SEED},
+    errors::MultisigError,
+    state::{MemberRole, MultisigState, ProposalKind, ProposalState},
+};
+
+// Program ID matching declare_id!("8CGhZB6i2y3mCe2K27Z2UCTg3nBfohgi6VYE5cjKupx9")
+const PROGRAM_ID: Pubkey = Pubkey::new_from_array(pm_secure::ID.to_bytes());
+
+// Instruction discriminators (see instructions/mod.rs)
+const CREATE_MULTISIG: u8 = 0;
+const CREATE_PROPOSAL: u8 = 1;
+const CREATE_TRANSFER_PROPOSAL: u8 = 2;
+const APPROVE_PROPOSAL: u8 = 3;
+const EXECUTE_PROPOSAL: u8 = 4;
+const EXECUTE_TRANSFER_PROPOSAL: u8 = 5;
+
+// MultisigState byte offsets (#[repr(C)], no interior padding)
+const TIMELOCK_OFFSET: usize = 16;
+const MEMBERS_OFFSET: usize = 88;
+const ROLES_OFFSET: usize = 408;
+const THRESHOLD_OFFSET: usize = 418;
+const OWNER_COUNT_OFFSET: usize = 419;
+
+// ProposalState byte offsets
+const APPROVAL_BITMAP_OFFSET: usize = 8;
+const APPROVAL_COUNT_OFFSET: usize = 139;
+
+const MULTISIG_ID: u64 = 1;
+const TIMELOCK_SECONDS: u64 = 60 * 60;
+const TRANSFER_AMOUNT: u64 = 2 * LAMPORTS_PER_SOL;
+
+
+// ======================== HELPERS ========================
+
+// Load the compiled program binary into LiteSVM
+fn setup_svm() -> LiteSVM {
+    ProgramHarness::builder()
+        .program_file(PROGRAM_ID, "target/deploy/pm_secure.so")
+        .build()
+        .into_svm()
+}
+
+fn multisig_address(creator: &Pubkey, multisig_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[MultisigState::SEED_PREFIX, creator.as_ref(), &multisig_id.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+    .0
+}
+
+fn vault_address(multisig: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[VAULT_SEED, multisig.as_ref()], &PROGRAM_ID).0
+}
+
+fn proposal_address(multisig: &Pubkey, proposal_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[ProposalState::SEED_PREFIX, multisig.as_ref(), &proposal_id.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+    .0
+}
+
+fn account_data(svm: &LiteSVM, address: &Pubkey) -> Vec<u8> {
+    svm.get_account(address).map(|account| account.data).unwrap_or_default()
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+fn member_at(data: &[u8], index: usize) -> Pubkey {
+    let start = MEMBERS_OFFSET + index * 32;
+    Pubkey::new_from_array(data[start..start + 32].try_into().unwrap())
+}
+
+// Assert the transaction failed with the given MultisigError
+fn assert_multisig_error(result: TransactionResult, error: MultisigError) {
+    let failed = assert_tx_err!(result);
+    assert_eq!(
+        failed.err,
+        TransactionError::InstructionError(0, InstructionError::Custom(error as u32)),
+        "Expected {:?}",
+        error
+    );
+}
+
+
+// ======================== INSTRUCTION BUILDERS ========================
+
+fn create_multisig_ix(creator: &Pubkey, multisig_id: u64, timelock_seconds: u64) -> Instruction {
+    let multisig = multisig_address(creator, multisig_id);
+
+    let mut data = vec![CREATE_MULTISIG];
+    data.extend_from_slice(&multisig_id.to_le_bytes());
+    data.extend_from_slice(&timelock_seconds.to_le_bytes());
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*creator, true),
+            AccountMeta::new(multisig, false),
+            AccountMeta::new(vault_address(&multisig), false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+fn proposal_accounts(proposer: &Pubkey, multisig: &Pubkey, proposal_id: u64) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(*proposer, true),
+        AccountMeta::new(*multisig, false),
+        AccountMeta::new(proposal_address(multisig, proposal_id), false),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+    ]
+}
+
+fn create_proposal_ix(
+    proposer: &Pubkey,
+    multisig: &Pubkey,
+    proposal_id: u64,
+    kind: ProposalKind,
+    role: MemberRole,
+    target: &Pubkey,
+    value: u64,
+) -> Instruction {
+    let mut data = vec![CREATE_PROPOSAL, kind as u8, role as u8];
+    data.extend_from_slice(target.as_ref());
+    data.extend_from_slice(&value.to_le_bytes());
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: proposal_accounts(proposer, multisig, proposal_id),
+        data,
+    }
+}
+
+fn add_member_ix(
+    admin: &Pubkey,
+    multisig: &Pubkey,
+    proposal_id: u64,
+    new_member: &Pubkey,
+    role: MemberRole,
+) -> Instruction {
+    create_proposal_ix(admin, multisig, proposal_id, ProposalKind::AddMember, role, new_member, 0)
+}
+
+fn change_threshold_ix(
+    proposer: &Pubkey,
+    multisig: &Pubkey,
+    proposal_id: u64,
+    new_threshold: u64,
+) -> Instruction {
+    create_proposal_ix(
+        proposer,
+        multisig,
+        proposal_id,
+        ProposalKind::ChangeThreshold,
+        MemberRole::Admin,
+        &Pubkey::default(),
+        new_threshold,
+    )
+}
+
+fn create_transfer_proposal_ix(
+    proposer: &Pubkey,
+    multisig: &Pubkey,
+    proposal_id: u64,
+    amount: u64,
+    recipient: &Pubkey,
+) -> Instruction {
+    let mut data = vec![CREATE_TRANSFER_PROPOSAL];
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(recipient.as_ref());
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: proposal_accounts(proposer, multisig, proposal_id),
+        data,
+    }
+}
+
+fn approve_ix(member: &Pubkey, multisig: &Pubkey, proposal_id: u64) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*member, true),
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(proposal_address(multisig, proposal_id), false),
+        ],
+        data: vec![APPROVE_PROPOSAL],
+    }
+}
+
+fn execute_proposal_ix(
+    executor: &Pubkey,
+    multisig: &Pubkey,
+    proposal_id: u64,
+    proposer: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*executor, true),
+            AccountMeta::new(*multisig, false),
+            AccountMeta::new(proposal_address(multisig, proposal_id), false),
+            AccountMeta::new(*proposer, false),
+        ],
+        data: vec![EXECUTE_PROPOSAL],
+    }
+}
+
+fn execute_transfer_proposal_ix(
+    executor: &Pubkey,
+    multisig: &Pubkey,
+    proposal_id: u64,
+    proposer: &Pubkey,
+    recipient: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*executor, true),
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(proposal_address(multisig, proposal_id), false),
+            AccountMeta::new(*proposer, false),
+            AccountMeta::new(vault_address(multisig), false),
+            AccountMeta::new(*recipient, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: vec![EXECUTE_TRANSFER_PROPOSAL],
+    }
+}
+
+
+// ======================== FIXTURE ========================
+
+// Multisig with the creator (Admin), a Proposer and an Executor, threshold 1
+struct Fixture {
+    svm: LiteSVM,
+    admin: Keypair,
+    proposer: Keypair,
+    executor: Keypair,
+    multisig: Pubkey,
+    next_proposal_id: u64,
+}
+
+impl Fixture {
+    fn new(timelock_seconds: u64) -> Self {
+        let mut svm = setup_svm();
+        let admin = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let proposer = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let executor = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let multisig = multisig_address(&admin.pubkey(), MULTISIG_ID);
+
+        assert_tx_ok!(send_ix(
+            &mut svm,
+            create_multisig_ix(&admin.pubkey(), MULTISIG_ID, timelock_seconds),
+            &admin
+        ));
+
+        let mut fixture = Self {
+            svm,
+            admin,
+            proposer,
+            executor,
+            multisig,
+            next_proposal_id: 0,
+        };
+
+        let (proposer_key, executor_key) = (fixture.proposer.pubkey(), fixture.executor.pubkey());
+        fixture.pass_add_member(&proposer_key, MemberRole::Proposer);
+        fixture.pass_add_member(&executor_key, MemberRole::Executor);
+        fixture
+    }
+
+    fn take_proposal_id(&mut self) -> u64 {
+        let id = self.next_proposal_id;
+        self.next_proposal_id += 1;
+        id
+    }
+
+    // Propose, wait out the timelock and execute an AddMember as the admin
+    // Only valid while the threshold is 1
+    fn pass_add_member(&mut self, member: &Pubkey, role: MemberRole) {
+        let id = self.take_proposal_id();
+        let admin_key = self.admin.pubkey();
+        let ix = add_member_ix(&admin_key, &self.multisig, id, member, role);
+        assert_tx_ok!(send_ix(&mut self.svm, ix, &self.admin));
+
+        let timelock = read_u64(&account_data(&self.svm, &self.multisig), TIMELOCK_OFFSET);
+        advance_time(&mut self.svm, timelock);
+
+        let ix = execute_proposal_ix(&admin_key, &self.multisig, id, &admin_key);
+        assert_tx_ok!(send_ix(&mut self.svm, ix, &self.admin));
+    }
+
+    fn multisig_data(&self) -> Vec<u8> {
+        account_data(&self.svm, &self.multisig)
+    }
+}
+
+
+// ======================== TESTS ========================
+
+#[test]
+fn test_create_multisig() {
+    let mut svm = setup_svm();
+    let creator = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let multisig = multisig_address(&creator.pubkey(), MULTISIG_ID);
+
+    assert_tx_ok!(send_ix(
+        &mut svm,
+        create_multisig_ix(&creator.pubkey(), MULTISIG_ID, TIMELOCK_SECONDS),
+        &creator
+    ));
+
+    let account = svm.get_account(&multisig).unwrap();
+    assert_eq!(account.owner, PROGRAM_ID);
+    assert_eq!(account.data.len(), MultisigState::LEN);
+
+    let data = account.data;
+    assert_eq!(member_at(&data, 0), creator.pubkey());
+    assert_eq!(data[ROLES_OFFSET], MemberRole::Admin as u8);
+    assert_eq!(data[THRESHOLD_OFFSET], 1);
+    assert_eq!(data[OWNER_COUNT_OFFSET], 1);
+    assert_eq!(read_u64(&data, TIMELOCK_OFFSET), TIMELOCK_SECONDS);
+
+    // Vault is a rent-exempt system account
+    let vault = svm.get_account(&vault_address(&multisig)).unwrap();
+    assert_eq!(vault.owner, SYSTEM_PROGRAM_ID);
+    assert!(vault.lamports > 0);
+
+    // Same id cannot be created twice
+    let result = send_ix(
+        &mut svm,
+        create_multisig_ix(&creator.pubkey(), MULTISIG_ID, TIMELOCK_SECONDS),
+        &creator,
+    );
+    assert_tx_err!(result);
+
+    // Timelock above 2 days is rejected
+    let result = send_ix(
+        &mut svm,
+        create_multisig_ix(&creator.pubkey(), MULTISIG_ID + 1, 3 * 24 * 60 * 60),
+        &creator,
+    );
+    assert_multisig_error(result, MultisigError::InvalidParameter);
+}
+
+#[test]
+fn test_governance_flow() {
+    let mut f = Fixture::new(TIMELOCK_SECONDS);
+    let admin_key = f.admin.pubkey();
+    let proposer_key = f.proposer.pubkey();
+    let executor_key = f.executor.pubkey();
+
+    let data = f.multisig_data();
+    assert_eq!(data[OWNER_COUNT_OFFSET], 3);
+    assert_eq!(member_at(&data, 1), proposer_key);
+    assert_eq!(member_at(&data, 2), executor_key);
+    assert_eq!(data[ROLES_OFFSET + 1], MemberRole::Proposer as u8);
+    assert_eq!(data[ROLES_OFFSET + 2], MemberRole::Executor as u8);
+
+    // Proposer raises the threshold to 2; the proposer's own bit is set on creation
+    let id = f.take_proposal_id();
+    let ix = change_threshold_ix(&proposer_key, &f.multisig, id, 2);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.proposer));
+
+    let proposal = account_data(&f.svm, &proposal_address(&f.multisig, id));
+    assert_eq!(read_u64(&proposal, APPROVAL_BITMAP_OFFSET), 0b010);
+    assert_eq!(proposal[APPROVAL_COUNT_OFFSET], 1);
+
+    advance_time(&mut f.svm, TIMELOCK_SECONDS);
+    let ix = execute_proposal_ix(&executor_key, &f.multisig, id, &proposer_key);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.executor));
+    assert_eq!(f.multisig_data()[THRESHOLD_OFFSET], 2);
+
+    // Executed proposal is closed
+    let closed = proposal_address(&f.multisig, id);
+    assert_eq!(f.svm.get_balance(&closed).unwrap_or(0), 0);
+
+    // With threshold 2, a new member needs a second approval
+    let new_member = Keypair::new();
+    let id = f.take_proposal_id();
+    let ix = add_member_ix(&admin_key, &f.multisig, id, &new_member.pubkey(), MemberRole::Proposer);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.admin));
+    advance_time(&mut f.svm, TIMELOCK_SECONDS);
+
+    let ix = execute_proposal_ix(&admin_key, &f.multisig, id, &admin_key);
+    assert_multisig_error(send_ix(&mut f.svm, ix, &f.admin), MultisigError::InsufficientApprovals);
+
+    assert_tx_ok!(send_ix(&mut f.svm, approve_ix(&executor_key, &f.multisig, id), &f.executor));
+    let proposal = account_data(&f.svm, &proposal_address(&f.multisig, id));
+    assert_eq!(read_u64(&proposal, APPROVAL_BITMAP_OFFSET), 0b101);
+
+    let ix = execute_proposal_ix(&admin_key, &f.multisig, id, &admin_key);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.admin));
+
+    let data = f.multisig_data();
+    assert_eq!(data[OWNER_COUNT_OFFSET], 4);
+    assert_eq!(member_at(&data, 3), new_member.pubkey());
+}
+
+#[test]
+fn test_transfer_proposal_flow() {
+    let mut f = Fixture::new(TIMELOCK_SECONDS);
+    let proposer_key = f.proposer.pubkey();
+    let executor_key = f.executor.pubkey();
+    let recipient = Keypair::new().pubkey();
+    let vault = vault_address(&f.multisig);
+
+    f.svm.airdrop(&vault, 5 * LAMPORTS_PER_SOL).unwrap();
+    f.svm.airdrop(&recipient, LAMPORTS_PER_SOL).unwrap();
+    let vault_before = f.svm.get_balance(&vault).unwrap();
+    let proposer_before = f.svm.get_balance(&proposer_key).unwrap();
+
+    let id = f.take_proposal_id();
+    let ix = create_transfer_proposal_ix(&proposer_key, &f.multisig, id, TRANSFER_AMOUNT, &recipient);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.proposer));
+
+    advance_time(&mut f.svm, TIMELOCK_SECONDS);
+    let ix = execute_transfer_proposal_ix(&executor_key, &f.multisig, id, &proposer_key, &recipient);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.executor));
+
+    assert_eq!(f.svm.get_balance(&vault).unwrap(), vault_before - TRANSFER_AMOUNT);
+    assert_eq!(f.svm.get_balance(&recipient).unwrap(), LAMPORTS_PER_SOL + TRANSFER_AMOUNT);
+
+    // Proposal rent came back to the proposer (who only paid the fee)
+    assert!(f.svm.get_balance(&proposer_key).unwrap() >= proposer_before - 10_000);
+
+    // Cannot be executed a second time
+    let ix = execute_transfer_proposal_ix(&executor_key, &f.multisig, id, &proposer_key, &recipient);
+    assert_tx_err!(send_ix(&mut f.svm, ix, &f.executor));
+}
+
+#[test]
+fn test_remove_member() {
+    let mut f = Fixture::new(0);
+    let admin_key = f.admin.pubkey();
+    let proposer_key = f.proposer.pubkey();
+    let executor_key = f.executor.pubkey();
+
+    let id = f.take_proposal_id();
+    let ix = create_proposal_ix(
+        &admin_key,
+        &f.multisig,
+        id,
+        ProposalKind::RemoveMember,
+        MemberRole::Admin,
+        &proposer_key,
+        0,
+    );
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.admin));
+    let ix = execute_proposal_ix(&admin_key, &f.multisig, id, &admin_key);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.admin));
+
+    // Executor shifted into the proposer's slot, role included
+    let data = f.multisig_data();
+    assert_eq!(data[OWNER_COUNT_OFFSET], 2);
+    assert_eq!(member_at(&data, 1), executor_key);
+    assert_eq!(data[ROLES_OFFSET + 1], MemberRole::Executor as u8);
+    assert_eq!(member_at(&data, 2), Pubkey::default());
+
+    // Creator cannot be removed
+    let id = f.next_proposal_id;
+    let ix = create_proposal_ix(
+        &admin_key,
+        &f.multisig,
+        id,
+        ProposalKind::RemoveMember,
+        MemberRole::Admin,
+        &admin_key,
+        0,
+    );
+    assert_multisig_error(send_ix(&mut f.svm, ix, &f.admin), MultisigError::CannotRemoveCreator);
+}
+
+#[test]
+fn test_timelock_enforcement() {
+    let mut f = Fixture::new(TIMELOCK_SECONDS);
+    let proposer_key = f.proposer.pubkey();
+    let executor_key = f.executor.pubkey();
+
+    let id = f.take_proposal_id();
+    let ix = change_threshold_ix(&proposer_key, &f.multisig, id, 2);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.proposer));
+
+    // One second short of the timelock
+    advance_time(&mut f.svm, TIMELOCK_SECONDS - 1);
+    let ix = execute_proposal_ix(&executor_key, &f.multisig, id, &proposer_key);
+    assert_multisig_error(send_ix(&mut f.svm, ix, &f.executor), MultisigError::TimelockNotPassed);
+
+    advance_time(&mut f.svm, 1);
+    let ix = execute_proposal_ix(&executor_key, &f.multisig, id, &proposer_key);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.executor));
+}
+
+#[test]
+fn test_bitmap_and_threshold() {
+    let mut f = Fixture::new(0);
+    let admin_key = f.admin.pubkey();
+    let proposer_key = f.proposer.pubkey();
+
+    let id = f.take_proposal_id();
+    let ix = change_threshold_ix(&proposer_key, &f.multisig, id, 3);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.proposer));
+    let ix = execute_proposal_ix(&admin_key, &f.multisig, id, &proposer_key);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.admin));
+
+    let id = f.take_proposal_id();
+    let ix = change_threshold_ix(&proposer_key, &f.multisig, id, 1);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.proposer));
+
+    // Proposer was auto-approved and cannot approve again
+    let ix = approve_ix(&proposer_key, &f.multisig, id);
+    assert_multisig_error(send_ix(&mut f.svm, ix, &f.proposer), MultisigError::AlreadyApproved);
+
+    // Outsiders have no bit to set
+    let outsider = create_funded_account(&mut f.svm, LAMPORTS_PER_SOL);
+    let ix = approve_ix(&outsider.pubkey(), &f.multisig, id);
+    assert_multisig_error(send_ix(&mut f.svm, ix, &outsider), MultisigError::NotAMember);
+
+    // 2 of 3 is not enough
+    assert_tx_ok!(send_ix(&mut f.svm, approve_ix(&admin_key, &f.multisig, id), &f.admin));
+    let ix = execute_proposal_ix(&admin_key, &f.multisig, id, &proposer_key);
+    assert_multisig_error(send_ix(&mut f.svm, ix, &f.admin), MultisigError::InsufficientApprovals);
+
+    let executor_key = f.executor.pubkey();
+    assert_tx_ok!(send_ix(&mut f.svm, approve_ix(&executor_key, &f.multisig, id), &f.executor));
+    let proposal = account_data(&f.svm, &proposal_address(&f.multisig, id));
+    assert_eq!(read_u64(&proposal, APPROVAL_BITMAP_OFFSET), 0b111);
+    assert_eq!(proposal[APPROVAL_COUNT_OFFSET], 3);
+
+    let ix = execute_proposal_ix(&admin_key, &f.multisig, id, &proposer_key);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.admin));
+    assert_eq!(f.multisig_data()[THRESHOLD_OFFSET], 1);
+}
+
+#[test]
+fn test_role_based_access_control() {
+    let mut f = Fixture::new(0);
+    let proposer_key = f.proposer.pubkey();
+    let executor_key = f.executor.pubkey();
+
+    // Executor cannot propose
+    let id = f.next_proposal_id;
+    let ix = change_threshold_ix(&executor_key, &f.multisig, id, 2);
+    assert_multisig_error(send_ix(&mut f.svm, ix, &f.executor), MultisigError::CannotPropose);
+
+    // Proposer cannot add members (admin only)
+    let ix = add_member_ix(&proposer_key, &f.multisig, id, &Pubkey::new_unique(), MemberRole::Admin);
+    assert_multisig_error(send_ix(&mut f.svm, ix, &f.proposer), MultisigError::OnlyAdmin);
+
+    // Proposer cannot execute
+    let id = f.take_proposal_id();
+    let ix = change_threshold_ix(&proposer_key, &f.multisig, id, 2);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.proposer));
+    let ix = execute_proposal_ix(&proposer_key, &f.multisig, id, &proposer_key);
+    assert_multisig_error(send_ix(&mut f.svm, ix, &f.proposer), MultisigError::CannotExecute);
+
+    // Rent must go back to the proposer, not the executor
+    let ix = execute_proposal_ix(&executor_key, &f.multisig, id, &executor_key);
+    assert_multisig_error(send_ix(&mut f.svm, ix, &f.executor), MultisigError::InvalidProposal);
+}
+
+#[test]
+fn test_proposal_kind_mismatch() {
+    let mut f = Fixture::new(0);
+    let proposer_key = f.proposer.pubkey();
+    let executor_key = f.executor.pubkey();
+    let recipient = Pubkey::new_unique();
+    f.svm.airdrop(&vault_address(&f.multisig), 5 * LAMPORTS_PER_SOL).unwrap();
+
+    // A transfer cannot be created through the governance instruction
+    let id = f.next_proposal_id;
+    let ix = create_proposal_ix(
+        &proposer_key,
+        &f.multisig,
+        id,
+        ProposalKind::TransferSol,
+        MemberRole::Admin,
+        &recipient,
+        TRANSFER_AMOUNT,
+    );
+    assert_multisig_error(send_ix(&mut f.svm, ix, &f.proposer), MultisigError::InvalidProposalType);
+
+    // A transfer proposal cannot be executed as a governance change
+    let transfer_id = f.take_proposal_id();
+    let ix = create_transfer_proposal_ix(&proposer_key, &f.multisig, transfer_id, TRANSFER_AMOUNT, &recipient);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.proposer));
+    let ix = execute_proposal_ix(&executor_key, &f.multisig, transfer_id, &proposer_key);
+    assert_multisig_error(send_ix(&mut f.svm, ix, &f.executor), MultisigError::InvalidProposalType);
+
+    // A governance proposal cannot be executed as a transfer
+    let governance_id = f.take_proposal_id();
+    let ix = change_threshold_ix(&proposer_key, &f.multisig, governance_id, 2);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.proposer));
+    let ix = execute_transfer_proposal_ix(&executor_key, &f.multisig, governance_id, &proposer_key, &recipient);
+    assert_multisig_error(send_ix(&mut f.svm, ix, &f.executor), MultisigError::InvalidProposalType);
+}
+
+#[test]
+fn test_transfer_recipient_checks() {
+    let mut f = Fixture::new(0);
+    let proposer_key = f.proposer.pubkey();
+    let executor_key = f.executor.pubkey();
+    let recipient = Pubkey::new_unique();
+    let attacker = create_funded_account(&mut f.svm, LAMPORTS_PER_SOL);
+    let vault = vault_address(&f.multisig);
+
+    // Vault only holds its rent reserve, so the transfer is unfunded
+    let id = f.take_proposal_id();
+    let ix = create_transfer_proposal_ix(&proposer_key, &f.multisig, id, TRANSFER_AMOUNT, &recipient);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.proposer));
+
+    // Executor tries to redirect the approved transfer
+    let ix = execute_transfer_proposal_ix(&executor_key, &f.multisig, id, &proposer_key, &attacker.pubkey());
+    assert_multisig_error(send_ix(&mut f.svm, ix, &f.executor), MultisigError::InvalidRecipient);
+
+    let ix = execute_transfer_proposal_ix(&executor_key, &f.multisig, id, &proposer_key, &recipient);
+    assert_multisig_error(send_ix(&mut f.svm, ix, &f.executor), MultisigError::InsufficientFunds);
+
+    f.svm.airdrop(&vault, 5 * LAMPORTS_PER_SOL).unwrap();
+    let ix = execute_transfer_proposal_ix(&executor_key, &f.multisig, id, &proposer_key, &recipient);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.executor));
+    assert_eq!(f.svm.get_balance(&recipient).unwrap(), TRANSFER_AMOUNT);
+
+    // Paying the vault back to itself is rejected at creation
+    let id = f.next_proposal_id;
+    let ix = create_transfer_proposal_ix(&proposer_key, &f.multisig, id, TRANSFER_AMOUNT, &vault);
+    assert_multisig_error(send_ix(&mut f.svm, ix, &f.proposer), MultisigError::InvalidRecipient);
+}
+
+#[test]
+fn test_proposal_expiry() {
+    let mut f = Fixture::new(TIMELOCK_SECONDS);
+    let admin_key = f.admin.pubkey();
+    let proposer_key = f.proposer.pubkey();
+
+    let id = f.take_proposal_id();
+    let ix = change_threshold_ix(&proposer_key, &f.multisig, id, 2);
+    assert_tx_ok!(send_ix(&mut f.svm, ix, &f.proposer));
+
+    advance_time(&mut f.svm, TIMELOCK_SECONDS + DEFAULT_EXPIRY_PERIOD + 1);
+
+    let ix = approve_ix(&admin_key, &f.multisig, id);
+    assert_multisig_error(send_ix(&mut f.svm, ix, &f.admin), MultisigError::ProposalExpired);
+
+    let ix = execute_proposal_ix(&admin_key, &f.multisig, id, &proposer_key);
+    assert_multisig_error(send_ix(&mut f.svm, ix, &f.admin), MultisigError::ProposalExpired);
+}
+
+// Execute a governance proposal the moment it is created, before the timelock
+// (V003 in m-vulnerable)
+struct SkipTimelock;
+
+struct SkipTimelockState {
+    admin: Keypair,
+    multisig: Pubkey,
+}
+
+impl DifferentialScenario for SkipTimelock {
+    type State = SkipTimelockState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V003",
+            title: "Missing Timelock Enforcement",
+            severity: Severity::Critical,
+            lesson: "Execute only once created_at + timelock_seconds has passed",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, _program_id: &Pubkey) -> SkipTimelockState {
+        let admin = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let multisig = multisig_address(&admin.pubkey(), MULTISIG_ID);
+
+        let ix = create_multisig_ix(&admin.pubkey(), MULTISIG_ID, TIMELOCK_SECONDS);
+        assert_tx_ok!(send_ix(svm, ix, &admin));
+
+        // Auto-approved by the admin, so threshold 1 is already met
+        let ix = change_threshold_ix(&admin.pubkey(), &multisig, 0, 1);
+        assert_tx_ok!(send_ix(svm, ix, &admin));
+
+        SkipTimelockState { admin, multisig }
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        state: &mut SkipTimelockState,
+    ) -> TransactionResult {
+        let admin_key = state.admin.pubkey();
+        let ix = execute_proposal_ix(&admin_key, &state.multisig, 0, &admin_key);
+        send_ix(svm, ix, &state.admin)
+    }
+}
+
+#[test]
+fn test_feature_missing_timelock_check() {
+    let variant = FeatureVariant {
+        program_id: PROGRAM_ID,
+        feature: "vuln-missing-timelock-check",
+    };
+    let (mut baseline, mut variant_svm) = variant.load(
+        "target/deploy/pm_secure.so",
+        variant.so_path(".", "pm_secure"),
+    );
+    run_feature_differential(&mut baseline, &mut variant_svm, &variant, &SkipTimelock);
+}
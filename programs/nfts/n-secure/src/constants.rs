@@ -1,15 +1,57 @@
 // Constants for PDA derivation and program constraints
 
+use anchor_lang::prelude::*;
+
 // PDA seed prefixes
 pub const COLLECTION_STATE: &[u8] = b"collection_state";
+pub const REWARD_CONFIG: &[u8] = b"reward_config";
+pub const STAKE_RECORD: &[u8] = b"stake_record";
+pub const COLLECTION_TREASURY: &[u8] = b"collection_treasury";
+pub const RARITY_CONFIG: &[u8] = b"rarity_config";
+pub const BADGE_RECORD: &[u8] = b"badge_record";
 
 // Attribute keys for staking data
 pub const STAKED_KEY: &str = "staked";
 pub const STAKED_TIME_KEY: &str = "staked_time";
+pub const RARITY_KEY: &str = "rarity";
 
 // Staking constraints
 pub const MIN_STAKE_DURATION: i64 = 30 * 24 * 60 * 60; // 30 days in seconds
 
+// Unstake cooldown between request_unstake and finalize_unstake
+// The asset stays frozen and earns nothing while it runs
+pub const UNSTAKE_COOLDOWN: i64 = 7 * 24 * 60 * 60; // 7 days in seconds
+
+// Rarity multipliers (basis points, 10_000 = 1x)
+pub const BASE_MULTIPLIER_BPS: u16 = 10_000;
+pub const MAX_MULTIPLIER_BPS: u16 = 50_000;
+pub const MAX_RARITY_TIERS: usize = 8;
+pub const MAX_RARITY_VALUE_LENGTH: usize = 16;
+
+// Milestone badges (days staked; new collections start at 30/90/365)
+pub const BADGE_MILESTONE_COUNT: usize = 3;
+pub const DEFAULT_BADGE_MILESTONE_DAYS: [u16; BADGE_MILESTONE_COUNT] = [30, 90, 365];
+pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+// Governance reputation boost
+// Voter profiles are the governance program's UserProfile PDAs: ["user_profile", owner]
+pub const GOVERNANCE_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("5TwUNSWhJ4AwYNnaP5APZyxBXW4usurdbfkCQCCRfAMP");
+pub const GOVERNANCE_USER_PROFILE: &[u8] = b"user_profile";
+
+// Anchor discriminator of the governance UserProfile account
+// First 8 bytes of sha256("account:UserProfile")
+pub const USER_PROFILE_DISCRIMINATOR: [u8; 8] = [32, 37, 119, 205, 179, 180, 13, 194];
+
+// (minimum reputation, multiplier bps), highest tier first
+// Thresholds match the governance ranks: Bronze, Contributor, Guardian, Leader
+pub const REPUTATION_TIERS: [(i64, u16); 4] = [
+    (401, 20_000),
+    (201, 15_000),
+    (101, 12_500),
+    (51, 11_000),
+];
+
 // NFT metadata constraints
 pub const MAX_NAME_LENGTH: usize = 32;
 pub const MAX_URI_LENGTH: usize = 200;
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+pub use soteria_errors::n_secure::NftError as NftErrorCode;
+
 #[error_code]
 pub enum NftError {
     #[msg("Collection has already been initialized")]
@@ -67,4 +69,76 @@ pub enum NftError {
 
     #[msg("Invalid payer account")]
     InvalidPayer,
+
+    #[msg("Reward rate must be greater than zero")]
+    InvalidRewardRate,
+
+    #[msg("Reward mint does not match reward config")]
+    InvalidRewardMint,
+
+    #[msg("No rewards available to claim")]
+    NoRewardsToClaim,
+
+    #[msg("Staking is paused for this collection")]
+    CollectionPaused,
+
+    #[msg("Collection staking cap reached")]
+    StakingCapReached,
+
+    #[msg("Staking cap cannot be below the number of NFTs already staked")]
+    InvalidStakingCap,
+
+    #[msg("Too many rarity tiers")]
+    TooManyRarityTiers,
+
+    #[msg("Rarity value is empty or too long")]
+    InvalidRarityValue,
+
+    #[msg("Rarity multiplier is out of range")]
+    InvalidRarityMultiplier,
+
+    #[msg("Duplicate rarity value")]
+    DuplicateRarityTier,
+
+    #[msg("Milestone index out of range")]
+    InvalidMilestone,
+
+    #[msg("Milestone thresholds must be non-zero and strictly increasing")]
+    InvalidMilestoneDays,
+
+    #[msg("Asset has not been staked long enough for this milestone")]
+    MilestoneNotReached,
+
+    #[msg("Voter profile is not the owner's governance UserProfile")]
+    InvalidVoterProfile,
+
+    #[msg("Voter profile is not owned by the governance program")]
+    VoterProfileNotGovernanceOwned,
+
+    #[msg("Staking attribute key appears more than once")]
+    DuplicateStakingAttribute,
+
+    #[msg("Attribute key imitates a staking attribute")]
+    InvalidStakingAttribute,
+
+    #[msg("Unstake has already been requested for this asset")]
+    UnstakeAlreadyRequested,
+
+    #[msg("Unstake has not been requested for this asset")]
+    UnstakeNotRequested,
+
+    #[msg("Unstake cooldown has not ended")]
+    UnstakeCooldownActive,
+
+    #[msg("Lockup is complete; use request_unstake")]
+    LockupAlreadyComplete,
+
+    #[msg("Funding amount must cover at least one second of emissions")]
+    InvalidFundingAmount,
+
+    #[msg("Reward vault cannot cover this claim")]
+    InsufficientRewardFunds,
+
+    #[msg("Handoff new owner must differ from the current owner")]
+    InvalidNewOwner,
 }
@@ -0,0 +1,126 @@
+// Staking Attribute Helpers
+//
+// Canonical reader/writer for the staking data kept on an asset's
+// Attributes plugin: "staked" (stake timestamp, "0" when unstaked) and
+// "staked_time" (accumulated seconds from previous stakes).
+//
+// Keys must match byte-for-byte and appear at most once. Keys that only
+// differ by case or surrounding whitespace are rejected, so an attribute
+// that looks like staking data to an indexer can never be silently
+// ignored here. Values must be canonical non-negative integers.
+
+use anchor_lang::prelude::*;
+use mpl_core::types::{Attribute, Attributes};
+
+use crate::{constants::*, errors::NftError};
+
+// Staking data parsed from an Attributes plugin
+// Unrelated attributes (rarity, traits) are carried through untouched
+pub struct StakingAttributes {
+    // Stake timestamp, 0 when the asset is not staked
+    pub staked: i64,
+    // Accumulated seconds from previous stakes
+    pub staked_time: i64,
+    initialized: bool,
+    other: Vec<Attribute>,
+}
+
+impl StakingAttributes {
+    // Staking data for an asset without an Attributes plugin
+    pub fn empty() -> Self {
+        Self {
+            staked: 0,
+            staked_time: 0,
+            initialized: false,
+            other: Vec::new(),
+        }
+    }
+
+    // Parse staking data, validating keys and values
+    // Both keys must be present together, or neither (never staked)
+    pub fn read(attributes: Attributes) -> Result<Self> {
+        let mut staked: Option<i64> = None;
+        let mut staked_time: Option<i64> = None;
+        let mut other: Vec<Attribute> = Vec::new();
+
+        for attribute in attributes.attribute_list {
+            let slot = if attribute.key == STAKED_KEY {
+                &mut staked
+            } else if attribute.key == STAKED_TIME_KEY {
+                &mut staked_time
+            } else {
+                // Reject lookalike keys ("Staked", " staked") outright
+                require!(
+                    !is_lookalike_key(&attribute.key),
+                    NftError::InvalidStakingAttribute
+                );
+                other.push(attribute);
+                continue;
+            };
+
+            require!(slot.is_none(), NftError::DuplicateStakingAttribute);
+            *slot = Some(parse_seconds(&attribute.value)?);
+        }
+
+        match (staked, staked_time) {
+            (Some(staked), Some(staked_time)) => Ok(Self {
+                staked,
+                staked_time,
+                initialized: true,
+                other,
+            }),
+            (None, None) => Ok(Self {
+                staked: 0,
+                staked_time: 0,
+                initialized: false,
+                other,
+            }),
+            _ => Err(NftError::StakingNotInitialized.into()),
+        }
+    }
+
+    // True once stake has written both staking keys
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    pub fn is_staked(&self) -> bool {
+        self.staked != 0
+    }
+
+    // Attributes plugin data with canonical staking keys appended
+    pub fn into_attributes(self) -> Attributes {
+        let mut attribute_list = self.other;
+        attribute_list.push(Attribute {
+            key: STAKED_KEY.to_string(),
+            value: self.staked.to_string(),
+        });
+        attribute_list.push(Attribute {
+            key: STAKED_TIME_KEY.to_string(),
+            value: self.staked_time.to_string(),
+        });
+
+        Attributes { attribute_list }
+    }
+}
+
+// A key an indexer could read as a staking key without being one
+fn is_lookalike_key(key: &str) -> bool {
+    let normalized = key.trim();
+    normalized.eq_ignore_ascii_case(STAKED_KEY) || normalized.eq_ignore_ascii_case(STAKED_TIME_KEY)
+}
+
+// Parse a non-negative integer written exactly as i64::to_string would
+// "+5", "05" and " 5" are rejected so each value has one encoding
+fn parse_seconds(value: &str) -> Result<i64> {
+    let seconds = value
+        .parse::<i64>()
+        .map_err(|_| NftError::InvalidTimestamp)?;
+
+    require!(
+        seconds >= 0 && seconds.to_string() == value,
+        NftError::InvalidTimestamp
+    );
+
+    Ok(seconds)
+}
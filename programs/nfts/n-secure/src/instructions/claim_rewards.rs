@@ -0,0 +1,254 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, TransferChecked},
+};
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    fetch_plugin,
+    types::{Attributes, PluginType, UpdateAuthority},
+};
+
+use crate::{
+    constants::*,
+    errors::NftError,
+    state::{GovernanceProfile, RarityConfig, RewardConfig, StakeRecord},
+};
+
+// Claim Rewards Instruction
+//
+// Pays reward tokens from the collection's reward vault for the time an NFT
+// has spent staked.
+// Only the asset owner can claim, and rewards go to the owner's token account.
+//
+// Staked time is read from the StakeRecord PDA, not from the asset's
+// attributes. last_claim advances on every claim so each second is paid once.
+// Rewards can only be claimed while the asset is staked, and stop accruing
+// when request_unstake starts the cooldown or funded emissions end
+// (emission_end_ts), whichever comes first.
+//
+// If the collection has a RarityConfig, the reward is scaled by the
+// multiplier for the asset's "rarity" attribute, read from the asset itself.
+//
+// If the owner passes their governance UserProfile, the reward is also scaled
+// by their reputation tier. The profile must be the governance program's
+// UserProfile PDA for the owner and be owned by that program, so a profile
+// created anywhere else cannot claim a tier.
+
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    // Asset owner
+    // Must match asset.owner, pays for the reward token account if needed
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // Asset earning rewards
+    // Validates ownership
+    #[account(
+        has_one = owner @ NftError::AssetOwnerMismatch,
+    )]
+    pub asset: Account<'info, BaseAssetV1>,
+
+    // Metaplex Core collection
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    // Stake record PDA
+    // Seeds: ["stake_record", asset]
+    // Source of truth for staked time and claim tracking
+    #[account(
+        mut,
+        seeds = [
+            STAKE_RECORD,
+            asset.key().as_ref(),
+        ],
+        bump = stake_record.bump,
+        has_one = owner @ NftError::UnauthorizedOwner,
+        has_one = asset @ NftError::NotStaked,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+
+    // Reward config PDA
+    // Seeds: ["reward_config", collection]
+    // Signs the vault transfer as vault authority
+    #[account(
+        mut,
+        seeds = [
+            REWARD_CONFIG,
+            collection.key().as_ref(),
+        ],
+        bump = reward_config.bump,
+        has_one = reward_mint @ NftError::InvalidRewardMint,
+    )]
+    pub reward_config: Account<'info, RewardConfig>,
+
+    // Rarity config PDA
+    // Seeds: ["rarity_config", collection]
+    // Canonical PDA enforced by seeds so it cannot be swapped or omitted;
+    // deserialized as RarityConfig in the handler when initialized
+    #[account(
+        seeds = [
+            RARITY_CONFIG,
+            collection.key().as_ref(),
+        ],
+        bump,
+    )]
+    /// CHECK: Canonical PDA enforced by seeds; owner and discriminator checked in handler
+    pub rarity_config: UncheckedAccount<'info>,
+
+    // Reward mint
+    // Validated by reward_config has_one constraint
+    pub reward_mint: Account<'info, Mint>,
+
+    // Reward vault
+    // Reward config's ATA for the reward mint, filled by fund_rewards
+    #[account(
+        mut,
+        associated_token::mint = reward_mint,
+        associated_token::authority = reward_config,
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    // Owner's reward token account
+    // Created on first claim
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = reward_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_reward_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    // Owner's governance UserProfile (optional)
+    // Seeds: ["user_profile", owner] under GOVERNANCE_PROGRAM_ID
+    // Omitted (program ID passed) to claim without a reputation boost
+    /// CHECK: PDA derivation, program owner and discriminator checked in handler
+    pub voter_profile: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> ClaimRewards<'info> {
+    pub fn claim_rewards(&mut self) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Asset Owner Validation
+        require!(
+            self.asset.owner == self.owner.key(),
+            NftError::AssetOwnerMismatch
+        );
+
+        // 2. Asset Collection Validation
+        require!(
+            self.asset.update_authority == UpdateAuthority::Collection(self.collection.key()),
+            NftError::AssetNotInCollection
+        );
+
+        // 3. Get Accrual Cutoff
+        // Nothing accrues past the end of funded emissions
+        let current_time = Clock::get()?.unix_timestamp;
+        let accrual_time = self.reward_config.emission_cutoff(current_time);
+
+        // 4. Calculate Unclaimed Time
+        // Per-asset claim tracking: only time since last_claim earns rewards
+        // Uses checked arithmetic to prevent underflow
+        let unclaimed_time = self.stake_record.unclaimed_seconds(accrual_time)?;
+        require!(unclaimed_time > 0, NftError::NoRewardsToClaim);
+
+        let base_amount = self.reward_config.reward_for(unclaimed_time)?;
+
+        // 5. Apply Rarity Multiplier
+        // Rarity comes from the asset's authority-managed Attributes plugin,
+        // never from instruction data
+        let multiplier_bps = self.rarity_multiplier()?;
+        let amount = RarityConfig::apply_multiplier(base_amount, multiplier_bps)?;
+
+        // 6. Apply Reputation Multiplier
+        // Only a UserProfile the governance program created for this owner counts
+        let reputation_bps = self.reputation_multiplier()?;
+        let amount = RarityConfig::apply_multiplier(amount, reputation_bps)?;
+        require!(amount > 0, NftError::NoRewardsToClaim);
+
+        // 7. Vault Balance Check
+        // Multipliers and concurrent stakes can outpace the schedule; the
+        // vault balance is the hard cap on what is paid
+        require!(
+            self.reward_vault.amount >= amount,
+            NftError::InsufficientRewardFunds
+        );
+
+        // 8. Record Claim
+        // Updated before paying so the same time cannot be claimed twice
+        // Paid up to the unstake request or emission end, whichever is first
+        self.stake_record.last_claim = self.stake_record.accrual_end(accrual_time);
+
+        // 9. Pay Rewards from the Vault via PDA Authority
+        let collection_key = self.collection.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            REWARD_CONFIG,
+            collection_key.as_ref(),
+            &[self.reward_config.bump],
+        ]];
+
+        token::transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.reward_vault.to_account_info(),
+                    mint: self.reward_mint.to_account_info(),
+                    to: self.owner_reward_account.to_account_info(),
+                    authority: self.reward_config.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            self.reward_mint.decimals,
+        )?;
+
+        // 10. Update Paid Counter
+        self.reward_config.record_paid(amount)?;
+
+        Ok(())
+    }
+    // Look up the asset's rarity multiplier
+    // 1x if the collection has no RarityConfig or the asset has no rarity
+    fn rarity_multiplier(&self) -> Result<u16> {
+        if self.rarity_config.data_is_empty() {
+            return Ok(BASE_MULTIPLIER_BPS);
+        }
+
+        // Account::try_from verifies the owner is this program and the
+        // RarityConfig discriminator matches before trusting any field
+        let rarity_config_info = self.rarity_config.to_account_info();
+        let rarity_config = Account::<RarityConfig>::try_from(&rarity_config_info)?;
+
+        let rarity = fetch_plugin::<BaseAssetV1, Attributes>(
+            &self.asset.to_account_info(),
+            PluginType::Attributes,
+        )
+        .ok()
+        .and_then(|(_, attributes, _)| {
+            attributes
+                .attribute_list
+                .into_iter()
+                .find(|attribute| attribute.key == RARITY_KEY)
+                .map(|attribute| attribute.value)
+        });
+
+        Ok(rarity_config.multiplier_for(rarity.as_deref()))
+    }
+
+    // Look up the owner's governance reputation multiplier
+    // 1x if no profile was passed
+    fn reputation_multiplier(&self) -> Result<u16> {
+        let Some(voter_profile) = &self.voter_profile else {
+            return Ok(BASE_MULTIPLIER_BPS);
+        };
+
+        // SECURITY: load() checks the PDA derivation against the owner and
+        // GOVERNANCE_PROGRAM_ID, and that the governance program owns it
+        let profile = GovernanceProfile::load(&voter_profile.to_account_info(), &self.owner.key())?;
+        Ok(profile.multiplier_bps())
+    }
+}
@@ -4,7 +4,11 @@ use mpl_core::{
     instructions::CreateCollectionV2CpiBuilder,
 };
 
-use crate::{constants::*, errors::NftError, state::CollectionState};
+use crate::{
+    constants::*,
+    errors::NftError,
+    state::{CollectionState, PenaltyCurve},
+};
 
 // Create Collection Instruction
 //
@@ -92,6 +96,12 @@ impl<'info> CreateCollection<'info> {
             collection: self.collection.key(),
             total_minted: 0,
             total_staked: 0,
+            max_staked: 0,
+            paused: false,
+            early_unstake_penalty: 0,
+            penalty_curve: PenaltyCurve::Linear,
+            badge_milestone_days: DEFAULT_BADGE_MILESTONE_DAYS,
+            handoff_keeps_stake_time: false,
             bump: bumps.collection_state,
         });
 
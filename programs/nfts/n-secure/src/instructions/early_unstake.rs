@@ -1,4 +1,7 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
 use mpl_core::{
     ID as MPL_CORE_ID,
     accounts::{BaseAssetV1, BaseCollectionV1},
@@ -9,20 +12,27 @@ use mpl_core::{
     },
 };
 
-use crate::{constants::*, errors::NftError, state::CollectionState};
+use crate::{
+    constants::*,
+    errors::NftError,
+    state::{CollectionState, CollectionTreasury, StakeRecord},
+};
 
-// Unstake NFT Instruction
+// Early Unstake NFT Instruction
 //
-// Unstakes an NFT by thawing it and updating total staked time.
-// Only the asset owner can unstake their NFT.
+// Unstakes an NFT before MIN_STAKE_DURATION has passed, for a SOL penalty.
+// Only the asset owner can early unstake their NFT.
 //
-// Removes FreezeDelegate plugin to allow transfers.
-// Updates Attributes plugin to accumulate staked time and reset timestamp.
+// The penalty scales with the remaining lockup using the collection's
+// penalty curve and is paid from the owner to the collection treasury PDA.
+// Otherwise behaves like finalize_unstake: thaws the asset, updates attributes and
+// closes the StakeRecord, so claim_rewards must be called first.
 
 #[derive(Accounts)]
-pub struct Unstake<'info> {
+pub struct EarlyUnstake<'info> {
     // Asset owner
-    // Must match asset.owner
+    // Must match asset.owner, pays the penalty, receives the stake record rent
+    #[account(mut)]
     pub owner: Signer<'info>,
 
     // Collection update authority
@@ -62,6 +72,37 @@ pub struct Unstake<'info> {
     )]
     pub collection_state: Account<'info, CollectionState>,
 
+    // Stake record PDA
+    // Seeds: ["stake_record", asset]
+    // Must exist (asset is staked) and belong to the signer
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            STAKE_RECORD,
+            asset.key().as_ref(),
+        ],
+        bump = stake_record.bump,
+        has_one = owner @ NftError::UnauthorizedOwner,
+        has_one = asset @ NftError::NotStaked,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+
+    // Collection treasury PDA
+    // Seeds: ["collection_treasury", collection]
+    // Receives early unstake penalties, created on first use
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR + CollectionTreasury::INIT_SPACE,
+        seeds = [
+            COLLECTION_TREASURY,
+            collection.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub collection_treasury: Account<'info, CollectionTreasury>,
+
     // Metaplex Core program
     #[account(address = MPL_CORE_ID @ NftError::InvalidMplCoreProgram)]
     /// CHECK: Validated by address constraint
@@ -70,8 +111,8 @@ pub struct Unstake<'info> {
     pub system_program: Program<'info, System>,
 }
 
-impl<'info> Unstake<'info> {
-    pub fn unstake(&mut self) -> Result<()> {
+impl<'info> EarlyUnstake<'info> {
+    pub fn early_unstake(&mut self, bumps: &EarlyUnstakeBumps) -> Result<()> {
         // SECURITY CHECKS
 
         // 1. Asset Owner Validation
@@ -92,11 +133,44 @@ impl<'info> Unstake<'info> {
             NftError::CollectionAuthorityMismatch
         );
 
-        // 4. Get Current Timestamp - should be past our staking time 
-        
+        // 4. Get Current Timestamp
         let current_time = Clock::get()?.unix_timestamp;
 
-        // 5. Update Attributes Plugin
+        // Time staked comes from the stake record, not the attribute value
+        let time_staked = self.stake_record.staked_seconds(current_time)?;
+
+        // Past the lockup the exit goes through the request_unstake cooldown
+        require!(
+            time_staked < MIN_STAKE_DURATION,
+            NftError::LockupAlreadyComplete
+        );
+
+        // 5. Calculate Penalty
+        // Scales with the remaining lockup; checked math, zero once lockup is met
+        let penalty = self.collection_state.early_unstake_penalty(time_staked)?;
+
+        // 6. Collect Penalty into Collection Treasury
+        let treasury = &mut self.collection_treasury;
+        if treasury.collection == Pubkey::default() {
+            treasury.collection = self.collection.key();
+            treasury.bump = bumps.collection_treasury;
+        }
+
+        if penalty > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.owner.to_account_info(),
+                        to: treasury.to_account_info(),
+                    },
+                ),
+                penalty,
+            )?;
+            treasury.record_penalty(penalty)?;
+        }
+
+        // 7. Update Attributes Plugin
         match fetch_plugin::<BaseAssetV1, Attributes>(
             &self.asset.to_account_info(),
             mpl_core::types::PluginType::Attributes,
@@ -111,17 +185,6 @@ impl<'info> Unstake<'info> {
                         // Ensure asset is currently staked
                         require!(attribute.value != "0", NftError::NotStaked);
 
-                        // Parse staked timestamp
-                        let staked_timestamp = attribute
-                            .value
-                            .parse::<i64>()
-                            .map_err(|_| NftError::InvalidTimestamp)?;
-
-                        // Calculate time staked using checked arithmetic
-                        let time_staked = current_time
-                            .checked_sub(staked_timestamp)
-                            .ok_or(NftError::Underflow)?;
-
                         // Add to accumulated staked_time
                         staked_time = staked_time
                             .checked_add(time_staked)
@@ -174,7 +237,7 @@ impl<'info> Unstake<'info> {
             }
         }
 
-        // 6. Thaw Asset by Updating FreezeDelegate
+        // 8. Thaw Asset by Updating FreezeDelegate
         UpdatePluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
             .asset(&self.asset.to_account_info())
             .collection(Some(&self.collection.to_account_info()))
@@ -184,7 +247,7 @@ impl<'info> Unstake<'info> {
             .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: false }))
             .invoke()?;
 
-        // 7. Remove FreezeDelegate Plugin
+        // 9. Remove FreezeDelegate Plugin
         RemovePluginV1CpiBuilder::new(&self.mpl_core_program)
             .asset(&self.asset.to_account_info())
             .collection(Some(&self.collection.to_account_info()))
@@ -194,7 +257,8 @@ impl<'info> Unstake<'info> {
             .plugin_type(PluginType::FreezeDelegate)
             .invoke()?;
 
-        // 8. Decrement Staked Counter
+        // 10. Decrement Staked Counter
+        // Stake record is closed by the 'close' constraint after this handler
         self.collection_state.decrement_staked()?;
 
         Ok(())
@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    ID as MPL_CORE_ID,
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    fetch_plugin,
+    instructions::{RemovePluginV1CpiBuilder, UpdatePluginV1CpiBuilder},
+    types::{
+        Attributes, FreezeDelegate, Plugin, PluginType, UpdateAuthority,
+    },
+};
+
+use crate::{
+    constants::*,
+    errors::NftError,
+    helpers::StakingAttributes,
+    state::{CollectionState, StakeRecord},
+};
+
+// Finalize Unstake NFT Instruction
+//
+// Second step of a normal unstake: thaws the NFT and updates total staked time.
+// Only the asset owner can unstake their NFT.
+//
+// Removes FreezeDelegate plugin to allow transfers.
+// Updates Attributes plugin to accumulate staked time and reset timestamp.
+// Staked time comes from the StakeRecord PDA, which is closed to the owner.
+// Rewards accrue on the record, so claim_rewards must be called before unstaking.
+// Requires request_unstake's cooldown to have ended; see early_unstake for
+// exiting before MIN_STAKE_DURATION.
+
+#[derive(Accounts)]
+pub struct FinalizeUnstake<'info> {
+    // Asset owner
+    // Must match asset.owner, receives the stake record rent
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // Collection update authority
+    // Must match collection.update_authority
+    pub update_authority: Signer<'info>,
+
+    // Payer for plugin operations
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // Asset being unstaked
+    // Validates ownership
+    #[account(
+        mut,
+        has_one = owner @ NftError::AssetOwnerMismatch,
+    )]
+    pub asset: Account<'info, BaseAssetV1>,
+
+    // Metaplex Core collection
+    // Validates authority controls the collection
+    #[account(
+        mut,
+        has_one = update_authority @ NftError::CollectionAuthorityMismatch,
+    )]
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    // Collection state PDA
+    // Seeds: ["collection_state", collection]
+    // Tracks total minted and staked
+    #[account(
+        mut,
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+
+    // Stake record PDA
+    // Seeds: ["stake_record", asset]
+    // Must exist (asset is staked) and belong to the signer
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            STAKE_RECORD,
+            asset.key().as_ref(),
+        ],
+        bump = stake_record.bump,
+        has_one = owner @ NftError::UnauthorizedOwner,
+        has_one = asset @ NftError::NotStaked,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+
+    // Metaplex Core program
+    #[account(address = MPL_CORE_ID @ NftError::InvalidMplCoreProgram)]
+    /// CHECK: Validated by address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FinalizeUnstake<'info> {
+    pub fn finalize_unstake(&mut self) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Asset Owner Validation
+        require!(
+            self.asset.owner == self.owner.key(),
+            NftError::AssetOwnerMismatch
+        );
+
+        // 2. Asset Collection Validation
+        require!(
+            self.asset.update_authority == UpdateAuthority::Collection(self.collection.key()),
+            NftError::AssetNotInCollection
+        );
+
+        // 3. Collection Authority Validation
+        require!(
+            self.update_authority.key() == self.collection_state.authority,
+            NftError::CollectionAuthorityMismatch
+        );
+
+        // 4. Cooldown Check
+        // request_unstake checked the lockup and started the cooldown
+        require!(
+            self.stake_record.unstake_requested(),
+            NftError::UnstakeNotRequested
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+        #[cfg(not(feature = "vuln-missing-cooldown-check"))]
+        require!(
+            current_time >= self.stake_record.cooldown_ends_at,
+            NftError::UnstakeCooldownActive
+        );
+
+        // Time staked comes from the stake record, not the attribute value
+        // The cooldown counts: the asset stayed frozen through it
+        let time_staked = self.stake_record.staked_seconds(current_time)?;
+
+        // 5. Update Attributes Plugin
+        // Staking keys are read through StakingAttributes, which rejects
+        // duplicate or lookalike keys and non-numeric values
+        match fetch_plugin::<BaseAssetV1, Attributes>(
+            &self.asset.to_account_info(),
+            mpl_core::types::PluginType::Attributes,
+        ) {
+            Ok((_, fetched_attributes, _)) => {
+                let mut staking = StakingAttributes::read(fetched_attributes)?;
+
+                // Ensure staking was initialized and asset is currently staked
+                require!(staking.is_initialized(), NftError::StakingNotInitialized);
+                require!(staking.is_staked(), NftError::NotStaked);
+
+                // Add to accumulated staked_time and reset staked key to 0
+                staking.staked_time = staking
+                    .staked_time
+                    .checked_add(time_staked)
+                    .ok_or(NftError::Overflow)?;
+                staking.staked = 0;
+
+                // Update the Attributes plugin
+                UpdatePluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
+                    .asset(&self.asset.to_account_info())
+                    .collection(Some(&self.collection.to_account_info()))
+                    .payer(&self.payer.to_account_info())
+                    .authority(Some(&self.update_authority.to_account_info()))
+                    .system_program(&self.system_program.to_account_info())
+                    .plugin(Plugin::Attributes(staking.into_attributes()))
+                    .invoke()?;
+            }
+            Err(_) => {
+                // Attributes plugin must exist for staking
+                return Err(NftError::AttributesNotInitialized.into());
+            }
+        }
+
+        // 6. Thaw Asset by Updating FreezeDelegate
+        UpdatePluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
+            .asset(&self.asset.to_account_info())
+            .collection(Some(&self.collection.to_account_info()))
+            .payer(&self.payer.to_account_info())
+            .authority(Some(&self.update_authority.to_account_info()))
+            .system_program(&self.system_program.to_account_info())
+            .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: false }))
+            .invoke()?;
+
+        // 7. Remove FreezeDelegate Plugin
+        RemovePluginV1CpiBuilder::new(&self.mpl_core_program)
+            .asset(&self.asset.to_account_info())
+            .collection(Some(&self.collection.to_account_info()))
+            .payer(&self.payer)
+            .authority(Some(&self.owner))
+            .system_program(&self.system_program)
+            .plugin_type(PluginType::FreezeDelegate)
+            .invoke()?;
+
+        // 8. Decrement Staked Counter
+        // Stake record is closed by the 'close' constraint after this handler
+        self.collection_state.decrement_staked()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, TransferChecked};
+use mpl_core::accounts::BaseCollectionV1;
+
+use crate::{
+    constants::*,
+    errors::NftError,
+    state::{CollectionState, RewardConfig},
+};
+
+// Fund Rewards Instruction
+//
+// Deposits reward tokens into the collection's reward vault.
+// Only the collection authority can fund rewards.
+//
+// Each deposit extends emission_end_ts by amount / reward_rate_per_sec
+// seconds. The schedule runs continuously from init_reward_config, so a
+// deposit made after the end has passed first pays for the lapsed time.
+// Claims stop accruing at emission_end_ts and are paid out of the vault,
+// so emissions can never exceed what has been deposited.
+
+#[derive(Accounts)]
+pub struct FundRewards<'info> {
+    // Collection authority
+    // Must match collection_state.authority
+    pub authority: Signer<'info>,
+
+    // Metaplex Core collection
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    // Collection state PDA
+    // Seeds: ["collection_state", collection]
+    // Validates the signer is the collection authority
+    #[account(
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+        has_one = authority @ NftError::UnauthorizedAuthority,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+
+    // Reward config PDA
+    // Seeds: ["reward_config", collection]
+    // Tracks funding and the emission end
+    #[account(
+        mut,
+        seeds = [
+            REWARD_CONFIG,
+            collection.key().as_ref(),
+        ],
+        bump = reward_config.bump,
+        has_one = reward_mint @ NftError::InvalidRewardMint,
+    )]
+    pub reward_config: Account<'info, RewardConfig>,
+
+    // Reward mint
+    // Validated by reward_config has_one constraint
+    pub reward_mint: Account<'info, Mint>,
+
+    // Authority's reward token account
+    // Source of the deposit
+    #[account(
+        mut,
+        associated_token::mint = reward_mint,
+        associated_token::authority = authority,
+    )]
+    pub authority_reward_account: Account<'info, TokenAccount>,
+
+    // Reward vault
+    // Reward config's ATA for the reward mint
+    #[account(
+        mut,
+        associated_token::mint = reward_mint,
+        associated_token::authority = reward_config,
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> FundRewards<'info> {
+    pub fn fund_rewards(&mut self, amount: u64) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Extend Emissions
+        // Rejects deposits too small to pay for a single second
+        self.reward_config.record_funding(amount)?;
+
+        // 2. Transfer Deposit into the Vault
+        token::transfer_checked(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.authority_reward_account.to_account_info(),
+                    mint: self.reward_mint.to_account_info(),
+                    to: self.reward_vault.to_account_info(),
+                    authority: self.authority.to_account_info(),
+                },
+            ),
+            amount,
+            self.reward_mint.decimals,
+        )?;
+
+        msg!(
+            "Funded {} reward base units, emissions end at {}",
+            amount,
+            self.reward_config.emission_end_ts
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use mpl_core::accounts::BaseCollectionV1;
+
+use crate::{
+    constants::*,
+    errors::NftError,
+    state::{CollectionState, RewardConfig},
+};
+
+// Init Reward Config Instruction
+//
+// Sets up reward emissions for a collection's staked NFTs.
+// Only the collection authority can configure rewards.
+//
+// Rewards are paid in an existing SPL mint from a reward vault, the reward
+// config PDA's associated token account, created here and filled through
+// fund_rewards. Emissions start here and end immediately until funded, so
+// nothing accrues before the first deposit.
+
+#[derive(Accounts)]
+pub struct InitRewardConfig<'info> {
+    // Collection authority
+    // Must match collection_state.authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Metaplex Core collection
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    // Collection state PDA
+    // Seeds: ["collection_state", collection]
+    // Validates the signer is the collection authority
+    #[account(
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+        has_one = authority @ NftError::UnauthorizedAuthority,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+
+    // Reward config PDA
+    // Seeds: ["reward_config", collection]
+    // Stores the emission rate, owns the reward vault
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + RewardConfig::INIT_SPACE,
+        seeds = [
+            REWARD_CONFIG,
+            collection.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub reward_config: Account<'info, RewardConfig>,
+
+    // Mint rewards are paid in
+    pub reward_mint: Account<'info, Mint>,
+
+    // Reward vault
+    // Reward config's ATA for the reward mint; claims are paid from here
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = reward_mint,
+        associated_token::authority = reward_config,
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitRewardConfig<'info> {
+    pub fn init_reward_config(
+        &mut self,
+        reward_rate_per_sec: u64,
+        bumps: &InitRewardConfigBumps,
+    ) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Reward Rate Validation
+        // A zero rate would let claims succeed without paying anything
+        require!(reward_rate_per_sec > 0, NftError::InvalidRewardRate);
+
+        // 2. Initialize Reward Config
+        self.reward_config.set_inner(RewardConfig {
+            collection: self.collection.key(),
+            reward_mint: self.reward_mint.key(),
+            reward_rate_per_sec,
+            total_rewards_paid: 0,
+            total_funded: 0,
+            emission_end_ts: Clock::get()?.unix_timestamp,
+            bump: bumps.reward_config,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseCollectionV1;
+
+use crate::{constants::*, errors::NftError, state::CollectionState};
+
+// Migrate Collection State Instruction
+//
+// Moves a collection's staking state to a new Metaplex Core collection,
+// for when a project rotates its update authority or re-launches the collection.
+//
+// The state is re-created at the new collection's PDA with every counter and
+// setting carried over, and the old state is closed to the old authority.
+// The new collection's update authority becomes the collection authority.
+//
+// Staked assets keep their stake records; the authority moves them into the
+// new collection with Metaplex Core before they can be unstaked there.
+// Reward, rarity and treasury accounts are keyed by collection and are set up
+// again for the new one.
+
+#[derive(Accounts)]
+pub struct MigrateCollectionState<'info> {
+    // Current collection authority
+    // Must match collection_state.authority and the old collection's update authority
+    // Receives the old state's rent
+    #[account(mut)]
+    pub old_authority: Signer<'info>,
+
+    // Incoming collection authority
+    // Must be the new collection's update authority, pays for the new state
+    #[account(mut)]
+    pub new_authority: Signer<'info>,
+
+    // Metaplex Core collection the state currently tracks
+    #[account(
+        constraint = old_collection.update_authority == old_authority.key()
+            @ NftError::CollectionAuthorityMismatch,
+    )]
+    pub old_collection: Account<'info, BaseCollectionV1>,
+
+    // Metaplex Core collection taking over
+    #[account(
+        constraint = new_collection.update_authority == new_authority.key()
+            @ NftError::CollectionAuthorityMismatch,
+    )]
+    pub new_collection: Account<'info, BaseCollectionV1>,
+
+    // Current collection state PDA
+    // Seeds: ["collection_state", old_collection]
+    // Closed once its state is copied
+    #[account(
+        mut,
+        close = old_authority,
+        seeds = [
+            COLLECTION_STATE,
+            old_collection.key().as_ref(),
+        ],
+        bump = old_collection_state.bump,
+        constraint = old_collection_state.authority == old_authority.key()
+            @ NftError::UnauthorizedAuthority,
+    )]
+    pub old_collection_state: Account<'info, CollectionState>,
+
+    // Collection state PDA for the new collection
+    // Seeds: ["collection_state", new_collection]
+    // init fails if the new collection already has staking state,
+    // which includes migrating a collection onto itself
+    #[account(
+        init,
+        payer = new_authority,
+        space = ANCHOR_DISCRIMINATOR + CollectionState::INIT_SPACE,
+        seeds = [
+            COLLECTION_STATE,
+            new_collection.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub new_collection_state: Account<'info, CollectionState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateCollectionState<'info> {
+    pub fn migrate_collection_state(
+        &mut self,
+        bumps: &MigrateCollectionStateBumps,
+    ) -> Result<()> {
+        // SECURITY: Both update authorities have signed and matched their
+        // collections in the account constraints
+
+        // Copy state to the new collection
+        // Counters and settings carry over, so staked NFTs stay counted
+        // against the cap and keep their badge and penalty schedule
+        self.new_collection_state.set_inner(CollectionState {
+            authority: self.new_authority.key(),
+            collection: self.new_collection.key(),
+            bump: bumps.new_collection_state,
+            ..CollectionState::clone(&self.old_collection_state)
+        });
+
+        msg!(
+            "Collection state migrated from {} to {} ({} staked)",
+            self.old_collection.key(),
+            self.new_collection.key(),
+            self.new_collection_state.total_staked
+        );
+
+        Ok(())
+    }
+}
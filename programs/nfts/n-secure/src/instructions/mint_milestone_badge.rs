@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    ID as MPL_CORE_ID,
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    instructions::CreateV2CpiBuilder,
+    types::{
+        ImmutableMetadata, PermanentFreezeDelegate, Plugin, PluginAuthority,
+        PluginAuthorityPair, UpdateAuthority,
+    },
+};
+
+use crate::{
+    constants::*,
+    errors::NftError,
+    state::{BadgeRecord, CollectionState, StakeRecord},
+};
+
+// Mint Milestone Badge Instruction
+//
+// Mints a soulbound reputation badge once an asset has been staked past one of
+// the collection's milestone thresholds (30/90/365 days by default).
+// Only the owner recorded in the asset's StakeRecord can mint.
+//
+// The badge is a standalone Metaplex Core asset created frozen with the
+// PermanentFreezeDelegate plugin under PluginAuthority::None, so nobody
+// (owner, collection authority or this program) can ever thaw or transfer it.
+// A BadgeRecord PDA per asset and milestone prevents minting the same badge twice.
+
+#[derive(Accounts)]
+#[instruction(milestone: u8)]
+pub struct MintMilestoneBadge<'info> {
+    // Asset owner
+    // Must match asset.owner and stake_record.owner; receives the badge
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // Badge asset account (new soulbound NFT)
+    // Must be a new keypair, must sign
+    #[account(mut)]
+    pub badge: Signer<'info>,
+
+    // Staked asset
+    // Validates ownership
+    #[account(
+        has_one = owner @ NftError::AssetOwnerMismatch,
+    )]
+    pub asset: Account<'info, BaseAssetV1>,
+
+    // Metaplex Core collection the asset belongs to
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    // Collection state PDA
+    // Seeds: ["collection_state", collection]
+    // Source of the milestone thresholds; becomes the badge's update authority
+    #[account(
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+
+    // Stake record PDA
+    // Seeds: ["stake_record", asset]
+    // Only exists while the asset is staked; staked_at is the source of truth
+    #[account(
+        seeds = [
+            STAKE_RECORD,
+            asset.key().as_ref(),
+        ],
+        bump = stake_record.bump,
+        has_one = owner @ NftError::UnauthorizedOwner,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+
+    // Badge record PDA
+    // Seeds: ["badge_record", asset, milestone]
+    // init fails if this milestone's badge was already minted for the asset
+    #[account(
+        init,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR + BadgeRecord::INIT_SPACE,
+        seeds = [
+            BADGE_RECORD,
+            asset.key().as_ref(),
+            &[milestone],
+        ],
+        bump,
+    )]
+    pub badge_record: Account<'info, BadgeRecord>,
+
+    // Metaplex Core program
+    #[account(address = MPL_CORE_ID @ NftError::InvalidMplCoreProgram)]
+    /// CHECK: Validated by address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MintMilestoneBadge<'info> {
+    pub fn mint_milestone_badge(
+        &mut self,
+        milestone: u8,
+        name: String,
+        uri: String,
+        bumps: &MintMilestoneBadgeBumps,
+    ) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Name Validation
+        require!(!name.is_empty(), NftError::EmptyName);
+        require!(name.len() <= MAX_NAME_LENGTH, NftError::NameTooLong);
+
+        // 2. URI Validation
+        require!(!uri.is_empty(), NftError::EmptyUri);
+        require!(uri.len() <= MAX_URI_LENGTH, NftError::UriTooLong);
+
+        // 3. Asset Collection Validation
+        // Thresholds must come from the collection the asset was staked in
+        require!(
+            self.asset.update_authority == UpdateAuthority::Collection(self.collection.key()),
+            NftError::AssetNotInCollection
+        );
+
+        // 4. Milestone Validation
+        // Out-of-range milestones are rejected by badge_threshold
+        let threshold = self.collection_state.badge_threshold(milestone)?;
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            self.stake_record.staked_seconds(current_time)? >= threshold,
+            NftError::MilestoneNotReached
+        );
+
+        // 5. Mint Soulbound Badge via CPI to Metaplex Core
+        // - PermanentFreezeDelegate: frozen at creation; PluginAuthority::None
+        //   means no authority exists that could ever thaw it
+        // - ImmutableMetadata: the milestone name/URI cannot be rewritten
+        let plugins = vec![
+            PluginAuthorityPair {
+                plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate {
+                    frozen: true,
+                }),
+                authority: Some(PluginAuthority::None),
+            },
+            PluginAuthorityPair {
+                plugin: Plugin::ImmutableMetadata(ImmutableMetadata {}),
+                authority: None,
+            },
+        ];
+
+        CreateV2CpiBuilder::new(&self.mpl_core_program.to_account_info())
+            .asset(&self.badge.to_account_info())
+            .payer(&self.owner.to_account_info())
+            .owner(Some(&self.owner.to_account_info()))
+            .update_authority(Some(&self.collection_state.to_account_info()))
+            .system_program(&self.system_program.to_account_info())
+            .name(name)
+            .uri(uri)
+            .plugins(plugins)
+            .invoke()?;
+
+        // 6. Record Badge
+        self.badge_record.set_inner(BadgeRecord {
+            asset: self.asset.key(),
+            owner: self.owner.key(),
+            badge: self.badge.key(),
+            milestone,
+            awarded_at: current_time,
+            bump: bumps.badge_record,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,246 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    ID as MPL_CORE_ID,
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    fetch_plugin,
+    instructions::{
+        AddPluginV1CpiBuilder, RemovePluginV1CpiBuilder, TransferV1CpiBuilder,
+        UpdatePluginV1CpiBuilder,
+    },
+    types::{
+        Attributes, FreezeDelegate, Plugin, PluginAuthority, PluginType, UpdateAuthority,
+    },
+};
+
+use crate::{
+    constants::*,
+    errors::NftError,
+    helpers::StakingAttributes,
+    state::{CollectionState, StakeRecord},
+};
+
+// Owner Handoff Instruction
+//
+// Moves a staked NFT to a buyer without leaving the staking program:
+// thaws the asset, transfers it, and freezes it again for the new owner,
+// all in one instruction, so it is never transferable outside a sale.
+// Both the seller and the buyer sign; the buyer takes over the stake record.
+//
+// With the collection's handoff_keeps_stake_time set, the stake keeps its
+// start time (lockup and badge progress carry over). Otherwise the seller's
+// time is added to staked_time and the stake restarts at the handoff.
+// Rewards accrue to the record's owner, so the seller calls claim_rewards
+// first; anything unclaimed is forfeited.
+//
+// Accounts Metaplex Core needs for the transfer beyond the fixed ones
+// (e.g. external plugin accounts) go in remaining accounts and are forwarded
+// to the transfer CPI as-is. The program signs nothing in that CPI, so they
+// carry no authority the transaction's signers did not already grant.
+
+#[derive(Accounts)]
+pub struct OwnerHandoff<'info> {
+    // Current asset owner (seller)
+    // Must match asset.owner and stake_record.owner
+    pub owner: Signer<'info>,
+
+    // Buyer
+    // Signs to accept the staked asset; re-adds the FreezeDelegate as owner
+    pub new_owner: Signer<'info>,
+
+    // Collection update authority
+    // Must match collection.update_authority
+    pub update_authority: Signer<'info>,
+
+    // Payer for plugin operations
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // Asset being handed off
+    // Validates ownership
+    #[account(
+        mut,
+        has_one = owner @ NftError::AssetOwnerMismatch,
+    )]
+    pub asset: Account<'info, BaseAssetV1>,
+
+    // Metaplex Core collection
+    // Validates authority controls the collection
+    #[account(
+        mut,
+        has_one = update_authority @ NftError::CollectionAuthorityMismatch,
+    )]
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    // Collection state PDA
+    // Seeds: ["collection_state", collection]
+    // Holds the handoff policy; stake counters are unchanged
+    #[account(
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+
+    // Stake record PDA
+    // Seeds: ["stake_record", asset]
+    // Must exist (asset is staked) and belong to the seller
+    #[account(
+        mut,
+        seeds = [
+            STAKE_RECORD,
+            asset.key().as_ref(),
+        ],
+        bump = stake_record.bump,
+        has_one = owner @ NftError::UnauthorizedOwner,
+        has_one = asset @ NftError::NotStaked,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+
+    // Metaplex Core program
+    #[account(address = MPL_CORE_ID @ NftError::InvalidMplCoreProgram)]
+    /// CHECK: Validated by address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> OwnerHandoff<'info> {
+    pub fn owner_handoff(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Asset Owner Validation
+        require!(
+            self.asset.owner == self.owner.key(),
+            NftError::AssetOwnerMismatch
+        );
+
+        // 2. Asset Collection Validation
+        require!(
+            self.asset.update_authority == UpdateAuthority::Collection(self.collection.key()),
+            NftError::AssetNotInCollection
+        );
+
+        // 3. Collection Authority Validation
+        require!(
+            self.update_authority.key() == self.collection_state.authority,
+            NftError::CollectionAuthorityMismatch
+        );
+
+        // 4. New Owner Validation
+        require!(
+            self.new_owner.key() != self.owner.key(),
+            NftError::InvalidNewOwner
+        );
+
+        // 5. Unstake Status Check
+        // An asset in its unstake cooldown is on its way out, not for sale
+        require!(
+            !self.stake_record.unstake_requested(),
+            NftError::UnstakeAlreadyRequested
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let keep_stake_time = self.collection_state.handoff_keeps_stake_time;
+
+        // 6. Update Attributes Plugin
+        // Restarting the stake banks the seller's time like finalize_unstake
+        // would; keeping it leaves the attributes untouched
+        match fetch_plugin::<BaseAssetV1, Attributes>(
+            &self.asset.to_account_info(),
+            PluginType::Attributes,
+        ) {
+            Ok((_, fetched_attributes, _)) => {
+                let mut staking = StakingAttributes::read(fetched_attributes)?;
+
+                // Ensure staking was initialized and asset is currently staked
+                require!(staking.is_initialized(), NftError::StakingNotInitialized);
+                require!(staking.is_staked(), NftError::NotStaked);
+
+                if !keep_stake_time {
+                    let time_staked = self.stake_record.staked_seconds(current_time)?;
+                    staking.staked_time = staking
+                        .staked_time
+                        .checked_add(time_staked)
+                        .ok_or(NftError::Overflow)?;
+                    staking.staked = current_time;
+
+                    UpdatePluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
+                        .asset(&self.asset.to_account_info())
+                        .collection(Some(&self.collection.to_account_info()))
+                        .payer(&self.payer.to_account_info())
+                        .authority(Some(&self.update_authority.to_account_info()))
+                        .system_program(&self.system_program.to_account_info())
+                        .plugin(Plugin::Attributes(staking.into_attributes()))
+                        .invoke()?;
+                }
+            }
+            Err(_) => {
+                // Attributes plugin must exist for staking
+                return Err(NftError::AttributesNotInitialized.into());
+            }
+        }
+
+        // 7. Thaw Asset by Updating FreezeDelegate
+        UpdatePluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
+            .asset(&self.asset.to_account_info())
+            .collection(Some(&self.collection.to_account_info()))
+            .payer(&self.payer.to_account_info())
+            .authority(Some(&self.update_authority.to_account_info()))
+            .system_program(&self.system_program.to_account_info())
+            .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: false }))
+            .invoke()?;
+
+        // 8. Remove FreezeDelegate Plugin
+        // Owner-managed, so the seller removes it before the transfer
+        RemovePluginV1CpiBuilder::new(&self.mpl_core_program)
+            .asset(&self.asset.to_account_info())
+            .collection(Some(&self.collection.to_account_info()))
+            .payer(&self.payer)
+            .authority(Some(&self.owner))
+            .system_program(&self.system_program)
+            .plugin_type(PluginType::FreezeDelegate)
+            .invoke()?;
+
+        // 9. Transfer Asset to the New Owner
+        let extra_accounts: Vec<(&AccountInfo<'info>, bool, bool)> = remaining_accounts
+            .iter()
+            .map(|account| (account, account.is_writable, account.is_signer))
+            .collect();
+
+        TransferV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
+            .asset(&self.asset.to_account_info())
+            .collection(Some(&self.collection.to_account_info()))
+            .payer(&self.payer.to_account_info())
+            .authority(Some(&self.owner.to_account_info()))
+            .new_owner(&self.new_owner.to_account_info())
+            .system_program(Some(&self.system_program.to_account_info()))
+            .add_remaining_accounts(&extra_accounts)
+            .invoke()?;
+
+        // 10. Freeze Asset for the New Owner
+        // Same PluginAuthority::UpdateAuthority as stake, so the buyer cannot
+        // thaw it without going through the program either
+        AddPluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
+            .asset(&self.asset.to_account_info())
+            .collection(Some(&self.collection.to_account_info()))
+            .payer(&self.payer.to_account_info())
+            .authority(Some(&self.new_owner.to_account_info()))
+            .system_program(&self.system_program.to_account_info())
+            .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: true }))
+            .init_authority(PluginAuthority::UpdateAuthority)
+            .invoke()?;
+
+        // 11. Hand Over the Stake Record
+        // Rewards start accruing to the buyer from now
+        let stake_record = &mut self.stake_record;
+        stake_record.owner = self.new_owner.key();
+        stake_record.last_claim = current_time;
+        if !keep_stake_time {
+            stake_record.staked_at = current_time;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    types::UpdateAuthority,
+};
+
+use crate::{
+    constants::*,
+    errors::NftError,
+    state::StakeRecord,
+};
+
+// Request Unstake Instruction
+//
+// First step of a normal unstake. Starts an UNSTAKE_COOLDOWN on the StakeRecord;
+// finalize_unstake thaws the asset once it has run.
+// Only the asset owner can request, and only after MIN_STAKE_DURATION.
+//
+// Rewards stop accruing at the request, and the asset stays frozen through the
+// cooldown. Staking just long enough to catch a reward rate or badge window and
+// leaving straight after therefore costs a full cooldown of idle stake.
+
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    // Asset owner
+    // Must match asset.owner
+    pub owner: Signer<'info>,
+
+    // Asset being unstaked
+    // Validates ownership
+    #[account(
+        has_one = owner @ NftError::AssetOwnerMismatch,
+    )]
+    pub asset: Account<'info, BaseAssetV1>,
+
+    // Metaplex Core collection
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    // Stake record PDA
+    // Seeds: ["stake_record", asset]
+    // Must exist (asset is staked) and belong to the signer
+    #[account(
+        mut,
+        seeds = [
+            STAKE_RECORD,
+            asset.key().as_ref(),
+        ],
+        bump = stake_record.bump,
+        has_one = owner @ NftError::UnauthorizedOwner,
+        has_one = asset @ NftError::NotStaked,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+}
+
+impl<'info> RequestUnstake<'info> {
+    pub fn request_unstake(&mut self) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Asset Owner Validation
+        require!(
+            self.asset.owner == self.owner.key(),
+            NftError::AssetOwnerMismatch
+        );
+
+        // 2. Asset Collection Validation
+        require!(
+            self.asset.update_authority == UpdateAuthority::Collection(self.collection.key()),
+            NftError::AssetNotInCollection
+        );
+
+        // 3. One Request per Stake
+        // A second request would restart the cooldown and move the accrual end
+        require!(
+            !self.stake_record.unstake_requested(),
+            NftError::UnstakeAlreadyRequested
+        );
+
+        // 4. Lockup Check
+        // Lockup must be complete; early_unstake exits sooner for a penalty
+        let current_time = Clock::get()?.unix_timestamp;
+        let time_staked = self.stake_record.staked_seconds(current_time)?;
+        require!(
+            time_staked >= MIN_STAKE_DURATION,
+            NftError::MinimumStakeDurationNotMet
+        );
+
+        // 5. Start Cooldown
+        self.stake_record.unstake_requested_at = current_time;
+        self.stake_record.cooldown_ends_at = current_time
+            .checked_add(UNSTAKE_COOLDOWN)
+            .ok_or(NftError::Overflow)?;
+
+        msg!(
+            "Unstake requested for {}, cooldown ends at {}",
+            self.asset.key(),
+            self.stake_record.cooldown_ends_at
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    ID as MPL_CORE_ID,
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    fetch_plugin,
+    instructions::{AddPluginV1CpiBuilder, UpdatePluginV1CpiBuilder},
+    types::{Attribute, Attributes, Plugin, PluginAuthority, PluginType, UpdateAuthority},
+};
+
+use crate::{constants::*, errors::NftError, state::CollectionState};
+
+// Set Asset Rarity Instruction
+//
+// Writes the "rarity" attribute on an asset's Attributes plugin.
+// Only the collection authority can assign rarity.
+//
+// The Attributes plugin is authority-managed, so owners cannot change
+// their own rarity; claim_rewards reads this value from the asset.
+
+#[derive(Accounts)]
+pub struct SetAssetRarity<'info> {
+    // Collection authority
+    // Must match collection_state.authority and collection.update_authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Asset receiving the rarity attribute
+    #[account(mut)]
+    pub asset: Account<'info, BaseAssetV1>,
+
+    // Metaplex Core collection
+    // Validates authority controls the collection
+    #[account(
+        mut,
+        constraint = collection.update_authority == authority.key() @ NftError::CollectionAuthorityMismatch,
+    )]
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    // Collection state PDA
+    // Seeds: ["collection_state", collection]
+    // Validates the signer is the collection authority
+    #[account(
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+        has_one = authority @ NftError::UnauthorizedAuthority,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+
+    // Metaplex Core program
+    #[account(address = MPL_CORE_ID @ NftError::InvalidMplCoreProgram)]
+    /// CHECK: Validated by address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetAssetRarity<'info> {
+    pub fn set_asset_rarity(&mut self, rarity: String) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Rarity Validation
+        require!(
+            !rarity.is_empty() && rarity.len() <= MAX_RARITY_VALUE_LENGTH,
+            NftError::InvalidRarityValue
+        );
+
+        // 2. Asset Collection Validation
+        require!(
+            self.asset.update_authority == UpdateAuthority::Collection(self.collection.key()),
+            NftError::AssetNotInCollection
+        );
+
+        // 3. Add or Update Attributes Plugin
+        // Other attributes (staking data) are preserved
+        match fetch_plugin::<BaseAssetV1, Attributes>(
+            &self.asset.to_account_info(),
+            PluginType::Attributes,
+        ) {
+            Ok((_, fetched_attribute_list, _)) => {
+                let mut attribute_list: Vec<Attribute> = fetched_attribute_list
+                    .attribute_list
+                    .into_iter()
+                    .filter(|attribute| attribute.key != RARITY_KEY)
+                    .collect();
+
+                attribute_list.push(Attribute {
+                    key: RARITY_KEY.to_string(),
+                    value: rarity,
+                });
+
+                UpdatePluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
+                    .asset(&self.asset.to_account_info())
+                    .collection(Some(&self.collection.to_account_info()))
+                    .payer(&self.authority.to_account_info())
+                    .authority(Some(&self.authority.to_account_info()))
+                    .system_program(&self.system_program.to_account_info())
+                    .plugin(Plugin::Attributes(Attributes { attribute_list }))
+                    .invoke()?;
+            }
+            Err(_) => {
+                AddPluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
+                    .asset(&self.asset.to_account_info())
+                    .collection(Some(&self.collection.to_account_info()))
+                    .payer(&self.authority.to_account_info())
+                    .authority(Some(&self.authority.to_account_info()))
+                    .system_program(&self.system_program.to_account_info())
+                    .plugin(Plugin::Attributes(Attributes {
+                        attribute_list: vec![Attribute {
+                            key: RARITY_KEY.to_string(),
+                            value: rarity,
+                        }],
+                    }))
+                    .init_authority(PluginAuthority::UpdateAuthority)
+                    .invoke()?;
+            }
+        }
+
+        Ok(())
+    }
+}
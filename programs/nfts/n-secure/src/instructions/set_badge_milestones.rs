@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseCollectionV1;
+
+use crate::{constants::*, errors::NftError, state::CollectionState};
+
+// Set Badge Milestones Instruction
+//
+// Configures how long an asset must stay staked to earn each milestone badge.
+// Only the collection authority can change the thresholds.
+//
+// Thresholds are in days and must be non-zero and strictly increasing, so
+// milestone 0 is always the easiest badge to earn.
+
+#[derive(Accounts)]
+pub struct SetBadgeMilestones<'info> {
+    // Collection authority
+    // Must match collection_state.authority
+    pub authority: Signer<'info>,
+
+    // Metaplex Core collection
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    // Collection state PDA
+    // Seeds: ["collection_state", collection]
+    // Validates the signer is the collection authority
+    #[account(
+        mut,
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+        has_one = authority @ NftError::UnauthorizedAuthority,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+}
+
+impl<'info> SetBadgeMilestones<'info> {
+    pub fn set_badge_milestones(
+        &mut self,
+        milestone_days: [u16; BADGE_MILESTONE_COUNT],
+    ) -> Result<()> {
+        // SECURITY CHECKS
+        // Authority validated by has_one constraint
+
+        // 1. Threshold Validation
+        // A zero threshold would hand out badges on the stake itself
+        require!(milestone_days[0] > 0, NftError::InvalidMilestoneDays);
+        require!(
+            milestone_days.windows(2).all(|pair| pair[0] < pair[1]),
+            NftError::InvalidMilestoneDays
+        );
+
+        // 2. Update Milestones
+        self.collection_state.badge_milestone_days = milestone_days;
+
+        Ok(())
+    }
+}
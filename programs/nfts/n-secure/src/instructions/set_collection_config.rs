@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseCollectionV1;
+
+use crate::{constants::*, errors::NftError, state::CollectionState};
+
+// Set Collection Config Instruction
+//
+// Updates collection-level staking limits.
+// Only the collection authority can change the config.
+//
+// max_staked caps how many NFTs can be staked at once (0 = no cap).
+// paused blocks new stakes; staked NFTs can still be unstaked.
+
+#[derive(Accounts)]
+pub struct SetCollectionConfig<'info> {
+    // Collection authority
+    // Must match collection_state.authority
+    pub authority: Signer<'info>,
+
+    // Metaplex Core collection
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    // Collection state PDA
+    // Seeds: ["collection_state", collection]
+    // Validates the signer is the collection authority
+    #[account(
+        mut,
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+        has_one = authority @ NftError::UnauthorizedAuthority,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+}
+
+impl<'info> SetCollectionConfig<'info> {
+    pub fn set_collection_config(&mut self, max_staked: u64, paused: bool) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Staking Cap Validation
+        // A cap below the current count would leave the collection over its limit
+        require!(
+            max_staked == 0 || max_staked >= self.collection_state.total_staked,
+            NftError::InvalidStakingCap
+        );
+
+        // 2. Update Collection Config
+        self.collection_state.max_staked = max_staked;
+        self.collection_state.paused = paused;
+
+        Ok(())
+    }
+}
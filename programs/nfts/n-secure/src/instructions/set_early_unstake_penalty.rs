@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseCollectionV1;
+
+use crate::{
+    constants::*,
+    errors::NftError,
+    state::{CollectionState, PenaltyCurve},
+};
+
+// Set Early Unstake Penalty Instruction
+//
+// Configures the penalty charged by early_unstake.
+// Only the collection authority can change the penalty.
+//
+// early_unstake_penalty is the lamport charge with the full lockup remaining;
+// penalty_curve controls how it scales down to zero at MIN_STAKE_DURATION.
+
+#[derive(Accounts)]
+pub struct SetEarlyUnstakePenalty<'info> {
+    // Collection authority
+    // Must match collection_state.authority
+    pub authority: Signer<'info>,
+
+    // Metaplex Core collection
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    // Collection state PDA
+    // Seeds: ["collection_state", collection]
+    // Validates the signer is the collection authority
+    #[account(
+        mut,
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+        has_one = authority @ NftError::UnauthorizedAuthority,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+}
+
+impl<'info> SetEarlyUnstakePenalty<'info> {
+    pub fn set_early_unstake_penalty(
+        &mut self,
+        early_unstake_penalty: u64,
+        penalty_curve: PenaltyCurve,
+    ) -> Result<()> {
+        // SECURITY CHECKS
+        // Authority validated by has_one constraint
+
+        // 1. Update Penalty Config
+        self.collection_state.early_unstake_penalty = early_unstake_penalty;
+        self.collection_state.penalty_curve = penalty_curve;
+
+        Ok(())
+    }
+}
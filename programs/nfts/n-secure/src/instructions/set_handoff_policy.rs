@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseCollectionV1;
+
+use crate::{constants::*, errors::NftError, state::CollectionState};
+
+// Set Handoff Policy Instruction
+//
+// Configures what owner_handoff does with a stake's start time.
+// Only the collection authority can change the policy.
+//
+// keep_stake_time = true lets the buyer inherit the seller's lockup and badge
+// progress; false (the default) restarts the stake at the handoff.
+
+#[derive(Accounts)]
+pub struct SetHandoffPolicy<'info> {
+    // Collection authority
+    // Must match collection_state.authority
+    pub authority: Signer<'info>,
+
+    // Metaplex Core collection
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    // Collection state PDA
+    // Seeds: ["collection_state", collection]
+    // Validates the signer is the collection authority
+    #[account(
+        mut,
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+        has_one = authority @ NftError::UnauthorizedAuthority,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+}
+
+impl<'info> SetHandoffPolicy<'info> {
+    pub fn set_handoff_policy(&mut self, keep_stake_time: bool) -> Result<()> {
+        // SECURITY CHECKS
+        // Authority validated by has_one constraint
+
+        // 1. Update Handoff Policy
+        self.collection_state.handoff_keeps_stake_time = keep_stake_time;
+
+        Ok(())
+    }
+}
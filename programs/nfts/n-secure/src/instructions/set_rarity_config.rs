@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseCollectionV1;
+
+use crate::{
+    constants::*,
+    errors::NftError,
+    state::{CollectionState, RarityConfig, RarityTier},
+};
+
+// Set Rarity Config Instruction
+//
+// Creates or replaces the rarity multiplier table for a collection.
+// Only the collection authority can set multipliers.
+//
+// claim_rewards looks up the asset's "rarity" attribute in this table.
+// Assets with no rarity, or a value not in the table, earn 1x.
+
+#[derive(Accounts)]
+pub struct SetRarityConfig<'info> {
+    // Collection authority
+    // Must match collection_state.authority, pays for the config on first use
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Metaplex Core collection
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    // Collection state PDA
+    // Seeds: ["collection_state", collection]
+    // Validates the signer is the collection authority
+    #[account(
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+        has_one = authority @ NftError::UnauthorizedAuthority,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+
+    // Rarity config PDA
+    // Seeds: ["rarity_config", collection]
+    // Created on first call, tiers replaced on later calls
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + RarityConfig::INIT_SPACE,
+        seeds = [
+            RARITY_CONFIG,
+            collection.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub rarity_config: Account<'info, RarityConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetRarityConfig<'info> {
+    pub fn set_rarity_config(
+        &mut self,
+        tiers: Vec<RarityTier>,
+        bumps: &SetRarityConfigBumps,
+    ) -> Result<()> {
+        // SECURITY CHECKS
+
+        // 1. Tier Validation
+        // Bounded count, non-empty unique values, multipliers within (0, 5x]
+        RarityConfig::validate_tiers(&tiers)?;
+
+        // 2. Store Tiers
+        self.rarity_config.set_inner(RarityConfig {
+            collection: self.collection.key(),
+            tiers,
+            bump: bumps.rarity_config,
+        });
+
+        Ok(())
+    }
+}
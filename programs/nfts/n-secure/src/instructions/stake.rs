@@ -5,11 +5,16 @@ use mpl_core::{
     fetch_plugin,
     instructions::{AddPluginV1CpiBuilder, UpdatePluginV1CpiBuilder},
     types::{
-        Attribute, Attributes, FreezeDelegate, Plugin, PluginAuthority, UpdateAuthority,
+        Attributes, FreezeDelegate, Plugin, PluginAuthority, UpdateAuthority,
     },
 };
 
-use crate::{constants::*, errors::NftError, state::CollectionState};
+use crate::{
+    constants::*,
+    errors::NftError,
+    helpers::StakingAttributes,
+    state::{CollectionState, StakeRecord},
+};
 
 // Stake NFT Instruction
 //
@@ -18,6 +23,7 @@ use crate::{constants::*, errors::NftError, state::CollectionState};
 //
 // Adds FreezeDelegate plugin to prevent transfers during staking.
 // Adds or updates Attributes plugin to track staking timestamp and accumulated time.
+// Creates a StakeRecord PDA that finalize_unstake and claim_rewards treat as the source of truth.
 
 #[derive(Accounts)]
 pub struct Stake<'info> {
@@ -62,6 +68,21 @@ pub struct Stake<'info> {
     )]
     pub collection_state: Account<'info, CollectionState>,
 
+    // Stake record PDA
+    // Seeds: ["stake_record", asset]
+    // One record per asset; init fails if the asset is already staked
+    #[account(
+        init,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR + StakeRecord::INIT_SPACE,
+        seeds = [
+            STAKE_RECORD,
+            asset.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+
     // Metaplex Core program
     #[account(address = MPL_CORE_ID @ NftError::InvalidMplCoreProgram)]
     /// CHECK: Validated by address constraint
@@ -71,7 +92,7 @@ pub struct Stake<'info> {
 }
 
 impl<'info> Stake<'info> {
-    pub fn stake(&mut self) -> Result<()> {
+    pub fn stake(&mut self, bumps: &StakeBumps) -> Result<()> {
         // SECURITY CHECKS
 
         // 1. Asset Owner Validation
@@ -92,50 +113,34 @@ impl<'info> Stake<'info> {
             NftError::CollectionAuthorityMismatch
         );
 
-        // 4. Get Current Timestamp
+        // 4. Collection Config Validation
+        // Authority can pause new stakes or cap how many NFTs are staked
+        require!(!self.collection_state.paused, NftError::CollectionPaused);
+        require!(
+            self.collection_state.has_capacity(),
+            NftError::StakingCapReached
+        );
+
+        // 5. Get Current Timestamp
         let current_time = Clock::get()?.unix_timestamp;
 
-        // 5. Add or Update Attributes Plugin 
+        // 6. Add or Update Attributes Plugin
         // The Attribute Plugin is a Authority Managed plugin that can store key value pairs of data within the asset.The Attribute Plugin will work in areas such as: Storing on chain attributes/traits of the Asset which can be read by on chain programs.Storing health and other statistical data that can be modified by a game/program.
-        
+        //
+        // Staking keys are read through StakingAttributes, which rejects
+        // duplicate or lookalike keys and non-numeric values
         match fetch_plugin::<BaseAssetV1, Attributes>(
             &self.asset.to_account_info(),
             mpl_core::types::PluginType::Attributes,
         ) {
-            Ok((_, fetched_attribute_list, _)) => {
+            Ok((_, fetched_attributes, _)) => {
                 // Asset has Attributes plugin - validate and update
-                let mut attribute_list: Vec<Attribute> = Vec::new();
-                let mut is_initialized: bool = false;
-
-                for attribute in fetched_attribute_list.attribute_list {
-                    // we use the stake key for timelocking while storing the timestamp so that we can perform staking checks eg: locking for 30 days etc 
-
-                    if attribute.key == STAKED_KEY {
-                        // Ensure asset is not already staked
-                        require!(attribute.value == "0", NftError::AlreadyStaked);
-
-                        // Update staked key with current timestamp
-                        attribute_list.push(Attribute {
-                            key: STAKED_KEY.to_string(),
-                            value: current_time.to_string(),
-                        });
-                        is_initialized = true;
-                    } else {
-                        attribute_list.push(attribute);
-                    }
-                }
-
-                // If staking attributes don't exist, add them
-                if !is_initialized {
-                    attribute_list.push(Attribute {
-                        key: STAKED_KEY.to_string(),
-                        value: current_time.to_string(),
-                    });
-                    attribute_list.push(Attribute {
-                        key: STAKED_TIME_KEY.to_string(),
-                        value: 0.to_string(),
-                    });
-                }
+                // we use the stake key for timelocking while storing the timestamp so that we can perform staking checks eg: locking for 30 days etc
+                let mut staking = StakingAttributes::read(fetched_attributes)?;
+
+                // Ensure asset is not already staked
+                require!(!staking.is_staked(), NftError::AlreadyStaked);
+                staking.staked = current_time;
 
                 // Update the Attributes plugin
                 UpdatePluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
@@ -144,40 +149,32 @@ impl<'info> Stake<'info> {
                     .payer(&self.payer.to_account_info())
                     .authority(Some(&self.update_authority.to_account_info()))
                     .system_program(&self.system_program.to_account_info())
-                    .plugin(Plugin::Attributes(Attributes { attribute_list }))
+                    .plugin(Plugin::Attributes(staking.into_attributes()))
                     .invoke()?;
             }
             Err(_) => {
                 // Asset doesn't have Attributes plugin - add it
+                let mut staking = StakingAttributes::empty();
+                staking.staked = current_time;
+
                 AddPluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
                     .asset(&self.asset.to_account_info())
                     .collection(Some(&self.collection.to_account_info()))
                     .payer(&self.payer.to_account_info())
                     .authority(Some(&self.update_authority.to_account_info()))
                     .system_program(&self.system_program.to_account_info())
-                    .plugin(Plugin::Attributes(Attributes {
-                        attribute_list: vec![
-                            Attribute {
-                                key: STAKED_KEY.to_string(),
-                                value: current_time.to_string(),
-                            },
-                            Attribute {
-                                key: STAKED_TIME_KEY.to_string(),
-                                value: 0.to_string(),
-                            },
-                        ],
-                    }))
+                    .plugin(Plugin::Attributes(staking.into_attributes()))
                     .init_authority(PluginAuthority::UpdateAuthority)
                     .invoke()?;
             }
         }
 
-        // 6. Add FreezeDelegate Plugin
+        // 7. Add FreezeDelegate Plugin
         // CRITICAL SECURITY: Use PluginAuthority::UpdateAuthority, NOT Owner
         //
         // PluginAuthority::UpdateAuthority means only the collection authority can
         // remove the freeze, preventing the owner from unstaking without going
-        // through our program's unstake instruction.
+        // through our program's finalize_unstake instruction.
         //
         // If we used PluginAuthority::Owner, the owner could remove the FreezeDelegate
         // directly via MPL Core, bypassing our staking logic and time tracking.
@@ -192,7 +189,19 @@ impl<'info> Stake<'info> {
             .init_authority(PluginAuthority::UpdateAuthority)
             .invoke()?;
 
-        // 7. Increment Staked Counter
+        // 8. Create Stake Record
+        // Rewards accrue from staked_at; last_claim starts at the same time
+        self.stake_record.set_inner(StakeRecord {
+            asset: self.asset.key(),
+            owner: self.owner.key(),
+            staked_at: current_time,
+            last_claim: current_time,
+            unstake_requested_at: 0,
+            cooldown_ends_at: 0,
+            bump: bumps.stake_record,
+        });
+
+        // 9. Increment Staked Counter
         self.collection_state.increment_staked()?;
 
         Ok(())
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{BADGE_MILESTONE_COUNT, MIN_STAKE_DURATION, SECONDS_PER_DAY};
+
 /// Tracks the state of our NFT collection for the staking program
 /// This PDA stores metadata about the collection used for validation
 #[account]
@@ -18,6 +20,25 @@ pub struct CollectionState {
     /// Total number of NFTs currently staked
     pub total_staked: u64,
 
+    /// Maximum number of NFTs that can be staked at once (0 = no cap)
+    pub max_staked: u64,
+
+    /// When true, new stakes are rejected (unstaking still works)
+    pub paused: bool,
+
+    /// Lamports charged for an early unstake with the full lockup remaining
+    pub early_unstake_penalty: u64,
+
+    /// How the early unstake penalty scales with the remaining lockup
+    pub penalty_curve: PenaltyCurve,
+
+    /// Days an asset must stay staked to earn each milestone badge
+    pub badge_milestone_days: [u16; BADGE_MILESTONE_COUNT],
+
+    /// When true, owner_handoff keeps the stake's start time, so the buyer
+    /// inherits the seller's lockup and badge progress; otherwise it restarts
+    pub handoff_keeps_stake_time: bool,
+
     /// Bump seed for PDA derivation
     pub bump: u8,
 }
@@ -40,6 +61,51 @@ impl CollectionState {
         Ok(())
     }
 
+    /// Check whether the collection can accept another stake
+    pub fn has_capacity(&self) -> bool {
+        self.max_staked == 0 || self.total_staked < self.max_staked
+    }
+
+    /// Early unstake penalty in lamports for the given staked time
+    /// Zero once the minimum staking duration has been met
+    pub fn early_unstake_penalty(&self, time_staked: i64) -> Result<u64> {
+        let remaining = MIN_STAKE_DURATION
+            .checked_sub(time_staked)
+            .ok_or(crate::errors::NftError::Underflow)?;
+        if remaining <= 0 {
+            return Ok(0);
+        }
+
+        let remaining = remaining as u128;
+        let lockup = MIN_STAKE_DURATION as u128;
+        let max_penalty = self.early_unstake_penalty as u128;
+
+        let penalty = match self.penalty_curve {
+            PenaltyCurve::Linear => max_penalty
+                .checked_mul(remaining)
+                .and_then(|v| v.checked_div(lockup)),
+            PenaltyCurve::Quadratic => max_penalty
+                .checked_mul(remaining)
+                .and_then(|v| v.checked_mul(remaining))
+                .and_then(|v| v.checked_div(lockup.checked_mul(lockup)?)),
+        }
+        .ok_or(crate::errors::NftError::Overflow)?;
+
+        u64::try_from(penalty).map_err(|_| crate::errors::NftError::Overflow.into())
+    }
+
+    /// Seconds an asset must stay staked to earn the given milestone badge
+    pub fn badge_threshold(&self, milestone: u8) -> Result<i64> {
+        let days = self
+            .badge_milestone_days
+            .get(milestone as usize)
+            .ok_or(crate::errors::NftError::InvalidMilestone)?;
+        let seconds = (*days as i64)
+            .checked_mul(SECONDS_PER_DAY)
+            .ok_or(crate::errors::NftError::Overflow)?;
+        Ok(seconds)
+    }
+
     /// Decrement the total staked counter
     pub fn decrement_staked(&mut self) -> Result<()> {
         self.total_staked = self.total_staked
@@ -48,3 +114,16 @@ impl CollectionState {
         Ok(())
     }
 }
+
+/// Shape of the early unstake penalty over the remaining lockup
+/// Linear charges in proportion to time left, Quadratic drops off faster
+/// as the lockup nears its end
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Copy, PartialEq, Eq)]
+pub enum PenaltyCurve {
+    Linear,
+    Quadratic,
+}
+
+impl Space for PenaltyCurve {
+    const INIT_SPACE: usize = 1;
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::NftError;
+
+/// Holds lamports collected from early unstake penalties
+/// One treasury per collection, created on the first early unstake
+#[account]
+#[derive(InitSpace)]
+pub struct CollectionTreasury {
+    /// The Metaplex Core collection this treasury belongs to
+    pub collection: Pubkey,
+
+    /// Total penalty lamports collected
+    pub total_penalties: u64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl CollectionTreasury {
+    /// Add to the total penalties counter
+    pub fn record_penalty(&mut self, amount: u64) -> Result<()> {
+        self.total_penalties = self.total_penalties
+            .checked_add(amount)
+            .ok_or(NftError::Overflow)?;
+        Ok(())
+    }
+}
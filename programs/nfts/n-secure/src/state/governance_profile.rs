@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::NftError};
+
+/// Leading fields of the governance program's UserProfile account
+/// Only the prefix needed for the reputation boost is decoded
+#[derive(AnchorDeserialize)]
+pub struct GovernanceProfile {
+    /// Display name chosen at registration
+    pub username: String,
+
+    /// Wallet the profile belongs to
+    pub owner: Pubkey,
+
+    /// Governance reputation; the rank tiers are derived from it
+    pub reputation_points: i64,
+}
+
+impl GovernanceProfile {
+    /// Load the owner's profile, rejecting any account the governance
+    /// program did not create for that owner
+    pub fn load(info: &AccountInfo, owner: &Pubkey) -> Result<Self> {
+        let (expected, _) = Pubkey::find_program_address(
+            &[GOVERNANCE_USER_PROFILE, owner.as_ref()],
+            &GOVERNANCE_PROGRAM_ID,
+        );
+        require_keys_eq!(info.key(), expected, NftError::InvalidVoterProfile);
+        require_keys_eq!(
+            *info.owner,
+            GOVERNANCE_PROGRAM_ID,
+            NftError::VoterProfileNotGovernanceOwned
+        );
+
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() >= ANCHOR_DISCRIMINATOR && data[..ANCHOR_DISCRIMINATOR] == USER_PROFILE_DISCRIMINATOR,
+            NftError::InvalidVoterProfile
+        );
+        let profile = Self::deserialize(&mut &data[ANCHOR_DISCRIMINATOR..])
+            .map_err(|_| NftError::InvalidVoterProfile)?;
+        require_keys_eq!(profile.owner, *owner, NftError::InvalidVoterProfile);
+
+        Ok(profile)
+    }
+
+    /// Reward multiplier for this profile's reputation tier, 1x below Bronze
+    pub fn multiplier_bps(&self) -> u16 {
+        REPUTATION_TIERS
+            .iter()
+            .find(|(min_points, _)| self.reputation_points >= *min_points)
+            .map_or(BASE_MULTIPLIER_BPS, |(_, multiplier_bps)| *multiplier_bps)
+    }
+}
@@ -1,3 +1,15 @@
+pub mod badge_record;
 pub mod collection_state;
+pub mod collection_treasury;
+pub mod governance_profile;
+pub mod rarity_config;
+pub mod reward_config;
+pub mod stake_record;
 
+pub use badge_record::*;
 pub use collection_state::*;
+pub use collection_treasury::*;
+pub use governance_profile::*;
+pub use rarity_config::*;
+pub use reward_config::*;
+pub use stake_record::*;
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::NftError};
+
+/// Reward multipliers keyed by an asset's rarity attribute
+/// Optional per collection; until it exists every asset earns 1x
+#[account]
+#[derive(InitSpace)]
+pub struct RarityConfig {
+    /// The Metaplex Core collection these multipliers apply to
+    pub collection: Pubkey,
+
+    /// Rarity value to multiplier mapping
+    #[max_len(MAX_RARITY_TIERS)]
+    pub tiers: Vec<RarityTier>,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// A single rarity value and its reward multiplier
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct RarityTier {
+    /// Value of the asset's "rarity" attribute (e.g. "legendary")
+    #[max_len(MAX_RARITY_VALUE_LENGTH)]
+    pub value: String,
+
+    /// Reward multiplier in basis points (10_000 = 1x)
+    pub multiplier_bps: u16,
+}
+
+impl RarityConfig {
+    /// Validate a tier list before storing it
+    pub fn validate_tiers(tiers: &[RarityTier]) -> Result<()> {
+        require!(tiers.len() <= MAX_RARITY_TIERS, NftError::TooManyRarityTiers);
+
+        for (i, tier) in tiers.iter().enumerate() {
+            require!(
+                !tier.value.is_empty() && tier.value.len() <= MAX_RARITY_VALUE_LENGTH,
+                NftError::InvalidRarityValue
+            );
+            require!(
+                tier.multiplier_bps > 0 && tier.multiplier_bps <= MAX_MULTIPLIER_BPS,
+                NftError::InvalidRarityMultiplier
+            );
+            require!(
+                !tiers[..i].iter().any(|t| t.value == tier.value),
+                NftError::DuplicateRarityTier
+            );
+        }
+        Ok(())
+    }
+
+    /// Multiplier for a rarity value, 1x if unset or not configured
+    pub fn multiplier_for(&self, rarity: Option<&str>) -> u16 {
+        rarity
+            .and_then(|r| self.tiers.iter().find(|t| t.value == r))
+            .map_or(BASE_MULTIPLIER_BPS, |t| t.multiplier_bps)
+    }
+
+    /// Apply a basis-point multiplier to a reward amount
+    pub fn apply_multiplier(amount: u64, multiplier_bps: u16) -> Result<u64> {
+        let scaled = (amount as u128)
+            .checked_mul(multiplier_bps as u128)
+            .ok_or(NftError::Overflow)?
+            / BASE_MULTIPLIER_BPS as u128;
+        u64::try_from(scaled).map_err(|_| NftError::Overflow.into())
+    }
+}
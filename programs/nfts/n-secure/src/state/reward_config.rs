@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::NftError;
+
+/// Reward emission settings for a collection's staked NFTs
+/// This PDA owns the reward vault that claims are paid from
+#[account]
+#[derive(InitSpace)]
+pub struct RewardConfig {
+    /// The Metaplex Core collection these rewards are paid for
+    pub collection: Pubkey,
+
+    /// SPL mint rewards are paid in
+    pub reward_mint: Pubkey,
+
+    /// Reward base units emitted per staked second, per NFT
+    pub reward_rate_per_sec: u64,
+
+    /// Total reward base units paid out so far
+    pub total_rewards_paid: u64,
+
+    /// Total reward base units deposited through fund_rewards
+    pub total_funded: u64,
+
+    /// Timestamp rewards stop accruing at
+    /// Starts at init_reward_config; fund_rewards extends it at reward_rate_per_sec
+    pub emission_end_ts: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl RewardConfig {
+    /// Reward owed for a number of staked seconds
+    pub fn reward_for(&self, staked_seconds: i64) -> Result<u64> {
+        let seconds = u64::try_from(staked_seconds).map_err(|_| NftError::Underflow)?;
+        let reward = seconds
+            .checked_mul(self.reward_rate_per_sec)
+            .ok_or(NftError::Overflow)?;
+        Ok(reward)
+    }
+
+    /// Last moment rewards accrue up to: now, or the end of funded emissions
+    pub fn emission_cutoff(&self, current_time: i64) -> i64 {
+        current_time.min(self.emission_end_ts)
+    }
+
+    /// Extend emissions by the seconds a deposit pays for at the configured rate
+    /// Always extends from the current end, even once it has passed, so the
+    /// schedule stays continuous and a late deposit first covers the lapse
+    pub fn record_funding(&mut self, amount: u64) -> Result<()> {
+        let funded_seconds = amount / self.reward_rate_per_sec;
+        require!(funded_seconds > 0, NftError::InvalidFundingAmount);
+
+        let funded_seconds = i64::try_from(funded_seconds).map_err(|_| NftError::Overflow)?;
+        self.emission_end_ts = self
+            .emission_end_ts
+            .checked_add(funded_seconds)
+            .ok_or(NftError::Overflow)?;
+        self.total_funded = self.total_funded
+            .checked_add(amount)
+            .ok_or(NftError::Overflow)?;
+        Ok(())
+    }
+
+    /// Add to the total paid counter
+    pub fn record_paid(&mut self, amount: u64) -> Result<()> {
+        self.total_rewards_paid = self.total_rewards_paid
+            .checked_add(amount)
+            .ok_or(NftError::Overflow)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::NftError;
+
+/// Per-asset staking record
+/// Created in stake and closed in finalize_unstake; the source of truth for
+/// stake ownership and reward accrual (attributes are informational only)
+#[account]
+#[derive(InitSpace)]
+pub struct StakeRecord {
+    /// The staked Metaplex Core asset
+    pub asset: Pubkey,
+
+    /// Current owner of the staked asset
+    /// Set in stake, moved to the buyer by owner_handoff
+    pub owner: Pubkey,
+
+    /// Timestamp the asset was staked
+    pub staked_at: i64,
+
+    /// Timestamp rewards were last paid up to
+    pub last_claim: i64,
+
+    /// Timestamp request_unstake was called (0 = not requested)
+    /// Rewards stop accruing here
+    pub unstake_requested_at: i64,
+
+    /// Timestamp finalize_unstake is allowed from (0 = not requested)
+    pub cooldown_ends_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl StakeRecord {
+    /// Whether request_unstake has started the cooldown
+    pub fn unstake_requested(&self) -> bool {
+        self.unstake_requested_at != 0
+    }
+
+    /// Last moment rewards accrue up to: now, or the unstake request
+    pub fn accrual_end(&self, current_time: i64) -> i64 {
+        if self.unstake_requested() {
+            current_time.min(self.unstake_requested_at)
+        } else {
+            current_time
+        }
+    }
+
+    /// Seconds staked since rewards were last claimed
+    /// Stops at the unstake request, so the cooldown earns nothing
+    pub fn unclaimed_seconds(&self, current_time: i64) -> Result<i64> {
+        let seconds = self
+            .accrual_end(current_time)
+            .checked_sub(self.last_claim)
+            .ok_or(NftError::Underflow)?;
+        Ok(seconds)
+    }
+
+    /// Seconds staked since the record was created
+    pub fn staked_seconds(&self, current_time: i64) -> Result<i64> {
+        let seconds = current_time
+            .checked_sub(self.staked_at)
+            .ok_or(NftError::Underflow)?;
+        Ok(seconds)
+    }
+}
@@ -0,0 +1,153 @@
+// Compute-unit regression tests for n-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+mod utils;
+
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+};
+use soteria_test_kit::{send_tx, ComputeBudget, DEFAULT_CU_BASELINES_FILE};
+use utils::*;
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let owner = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let collection = Keypair::new();
+    let kept = Keypair::new();
+    let released = Keypair::new();
+
+    let ix = nft_ix::create_collection(
+        &authority.pubkey(),
+        &collection.pubkey(),
+        &authority.pubkey(),
+        "Compute Collection",
+        "https://example.com/collection.json",
+    );
+    let result = send_tx(&mut svm, &[ix], &authority, &[&authority, &collection]);
+    budget.record("create_collection", result);
+
+    let ix = nft_ix::mint_nft(
+        &authority.pubkey(),
+        &kept.pubkey(),
+        &collection.pubkey(),
+        &authority.pubkey(),
+        &owner.pubkey(),
+        &authority.pubkey(),
+        "Compute NFT #1",
+        "https://example.com/nft1.json",
+    );
+    budget.record("mint_nft", send_tx(&mut svm, &[ix], &authority, &[&authority, &kept]));
+    let ix = nft_ix::mint_nft(
+        &authority.pubkey(),
+        &released.pubkey(),
+        &collection.pubkey(),
+        &authority.pubkey(),
+        &owner.pubkey(),
+        &authority.pubkey(),
+        "Compute NFT #2",
+        "https://example.com/nft2.json",
+    );
+    send_tx_expect_success(&mut svm, ix, &authority, &[&authority, &released]);
+
+    let reward_mint = create_reward_mint(&mut svm, &authority, FUNDED_EMISSION_SECONDS * 10);
+    let ix = nft_ix::init_reward_config(&authority.pubkey(), &collection.pubkey(), &reward_mint, 10);
+    budget.record("init_reward_config", send_tx(&mut svm, &[ix], &authority, &[&authority]));
+    let ix = nft_ix::fund_rewards(
+        &authority.pubkey(),
+        &collection.pubkey(),
+        &reward_mint,
+        FUNDED_EMISSION_SECONDS * 10,
+    );
+    budget.record("fund_rewards", send_tx(&mut svm, &[ix], &authority, &[&authority]));
+
+    let ix = nft_ix::set_collection_config(&authority.pubkey(), &collection.pubkey(), 0, false);
+    budget.record("set_collection_config", send_tx(&mut svm, &[ix], &authority, &[&authority]));
+
+    let ix = nft_ix::set_rarity_config(
+        &authority.pubkey(),
+        &collection.pubkey(),
+        &[("common", 10_000), ("legendary", 30_000)],
+    );
+    budget.record("set_rarity_config", send_tx(&mut svm, &[ix], &authority, &[&authority]));
+
+    let ix = nft_ix::set_asset_rarity(
+        &authority.pubkey(),
+        &kept.pubkey(),
+        &collection.pubkey(),
+        "legendary",
+    );
+    budget.record("set_asset_rarity", send_tx(&mut svm, &[ix], &authority, &[&authority]));
+
+    let ix = nft_ix::set_early_unstake_penalty(
+        &authority.pubkey(),
+        &collection.pubkey(),
+        LAMPORTS_PER_SOL,
+        PenaltyCurve::Linear,
+    );
+    let result = send_tx(&mut svm, &[ix], &authority, &[&authority]);
+    budget.record("set_early_unstake_penalty", result);
+
+    let ix = nft_ix::stake(
+        &owner.pubkey(),
+        &authority.pubkey(),
+        &owner.pubkey(),
+        &kept.pubkey(),
+        &collection.pubkey(),
+    );
+    budget.record("stake", send_tx(&mut svm, &[ix], &owner, &[&owner, &authority]));
+    let ix = nft_ix::stake(
+        &owner.pubkey(),
+        &authority.pubkey(),
+        &owner.pubkey(),
+        &released.pubkey(),
+        &collection.pubkey(),
+    );
+    send_tx_expect_success(&mut svm, ix, &owner, &[&owner, &authority]);
+
+    // The first claim also creates the owner's reward token account
+    advance_time(&mut svm, 24 * 60 * 60);
+    let ix = nft_ix::claim_rewards(
+        &owner.pubkey(),
+        &kept.pubkey(),
+        &collection.pubkey(),
+        &reward_mint,
+        None,
+    );
+    budget.record("claim_rewards", send_tx(&mut svm, &[ix], &owner, &[&owner]));
+
+    let ix = nft_ix::early_unstake(
+        &owner.pubkey(),
+        &authority.pubkey(),
+        &authority.pubkey(),
+        &released.pubkey(),
+        &collection.pubkey(),
+    );
+    let result = send_tx(&mut svm, &[ix], &authority, &[&authority, &owner]);
+    budget.record("early_unstake", result);
+
+    advance_time(&mut svm, MIN_STAKE_DURATION as u64);
+    let ix = nft_ix::request_unstake(&owner.pubkey(), &kept.pubkey(), &collection.pubkey());
+    budget.record("request_unstake", send_tx(&mut svm, &[ix], &owner, &[&owner]));
+
+    advance_time(&mut svm, UNSTAKE_COOLDOWN as u64);
+    let ix = nft_ix::finalize_unstake(
+        &owner.pubkey(),
+        &authority.pubkey(),
+        &owner.pubkey(),
+        &kept.pubkey(),
+        &collection.pubkey(),
+    );
+    budget.record("finalize_unstake", send_tx(&mut svm, &[ix], &owner, &[&owner, &authority]));
+
+    budget.check();
+}
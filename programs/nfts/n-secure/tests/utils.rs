@@ -2,6 +2,7 @@
 // TODO: we might have a stack overflow issue. working on it 
 
 use litesvm::LiteSVM;
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
 use solana_sdk::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
@@ -11,16 +12,25 @@ use solana_sdk::{
 use solana_system_interface::program::ID as system_program;
 use borsh::BorshSerialize;
 
+// PDAs, instruction builders and account decoders come from the client SDK
+pub use soteria_client::nft_staking::{
+    self, instructions as nft_ix, CollectionState, CollectionTreasury, PenaltyCurve, RewardConfig,
+    StakeRecord, MIN_STAKE_DURATION, UNSTAKE_COOLDOWN,
+};
+
+// Voter profiles for the reputation boost are governance UserProfile PDAs
+pub use soteria_client::governance;
+
 // Program ID matching declare_id!
-pub const PROGRAM_ID: Pubkey = solana_sdk::pubkey!("xbwEtBJ9eoyGCAkvr4P2JmMH8wSnrb6amh2po57oGGJ");
+pub const PROGRAM_ID: Pubkey = nft_staking::PROGRAM_ID;
 
-pub const MPL_CORE_ID: Pubkey = solana_sdk::pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+// Reward token decimals for test mints
+pub const REWARD_DECIMALS: u8 = 6;
 
-// Seed constants (must match constants.rs)
-pub const COLLECTION_STATE: &[u8] = b"collection_state";
-pub const STAKED_KEY: &str = "staked";
-pub const STAKED_TIME_KEY: &str = "staked_time";
-pub const MIN_STAKE_DURATION: i64 = 30 * 24 * 60 * 60; // 30 days in seconds
+// Emissions funded by init_funded_rewards (one year at the configured rate)
+pub const FUNDED_EMISSION_SECONDS: u64 = 365 * 24 * 60 * 60;
+
+pub const MPL_CORE_ID: Pubkey = nft_staking::MPL_CORE_PROGRAM_ID;
 
 // ======================== HELPERS ========================
 
@@ -58,12 +68,106 @@ pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
     keypair
 }
 
-/// Derive the collection_state PDA using seeds: ["collection_state", collection]
-pub fn derive_collection_state_pda(collection: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[COLLECTION_STATE, collection.as_ref()],
-        &PROGRAM_ID,
+/// Read total_penalties from a CollectionTreasury account
+pub fn get_treasury_penalties(svm: &LiteSVM, treasury: &Pubkey) -> u64 {
+    let account = svm.get_account(treasury).expect("Treasury should exist");
+    CollectionTreasury::try_from_bytes(&account.data)
+        .expect("Treasury should decode")
+        .total_penalties
+}
+
+/// Read the CollectionState tracking a collection
+pub fn get_collection_state(svm: &LiteSVM, collection: &Pubkey) -> CollectionState {
+    let (collection_state, _) = nft_staking::collection_state_address(collection);
+    let account = svm.get_account(&collection_state).expect("Collection state should exist");
+    CollectionState::try_from_bytes(&account.data).expect("Collection state should decode")
+}
+
+/// Read and deserialize a collection's RewardConfig account
+pub fn get_reward_config(svm: &LiteSVM, collection: &Pubkey) -> RewardConfig {
+    let (reward_config, _) = nft_staking::reward_config_address(collection);
+    let account = svm.get_account(&reward_config).expect("Reward config should exist");
+    RewardConfig::try_from_bytes(&account.data).expect("Reward config should decode")
+}
+
+/// Create a reward mint controlled by authority and mint supply to its token account
+pub fn create_reward_mint(svm: &mut LiteSVM, authority: &Keypair, supply: u64) -> Pubkey {
+    let reward_mint = CreateMint::new(svm, authority)
+        .authority(&authority.pubkey())
+        .decimals(REWARD_DECIMALS)
+        .send()
+        .expect("Failed to create reward mint");
+
+    let authority_account = create_token_account(svm, authority, &reward_mint);
+    MintTo::new(svm, authority, &reward_mint, &authority_account, supply)
+        .owner(authority)
+        .send()
+        .expect("Failed to mint reward supply");
+
+    reward_mint
+}
+
+/// Create owner's associated token account for mint
+pub fn create_token_account(svm: &mut LiteSVM, owner: &Keypair, mint: &Pubkey) -> Pubkey {
+    CreateAssociatedTokenAccount::new(svm, owner, mint)
+        .owner(&owner.pubkey())
+        .send()
+        .expect("Failed to create token account")
+}
+
+/// Configure rewards with a new reward mint and fund FUNDED_EMISSION_SECONDS of
+/// emissions; returns the reward mint
+pub fn init_funded_rewards(
+    svm: &mut LiteSVM,
+    authority: &Keypair,
+    collection: &Pubkey,
+    reward_rate: u64,
+) -> Pubkey {
+    let funding = FUNDED_EMISSION_SECONDS * reward_rate;
+    let reward_mint = create_reward_mint(svm, authority, funding);
+
+    let ix = nft_ix::init_reward_config(&authority.pubkey(), collection, &reward_mint, reward_rate);
+    send_tx_expect_success(svm, ix, authority, &[authority]);
+    let ix = nft_ix::fund_rewards(&authority.pubkey(), collection, &reward_mint, funding);
+    send_tx_expect_success(svm, ix, authority, &[authority]);
+
+    reward_mint
+}
+
+/// Read the amount from an SPL token account (bytes 64..72)
+pub fn get_token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    let account = svm.get_account(token_account).expect("Token account should exist");
+    u64::from_le_bytes(account.data[64..72].try_into().unwrap())
+}
+
+/// Write a governance UserProfile account with the given reputation
+/// program_owner is normally the governance program; anything else models a forged profile
+pub fn set_voter_profile(
+    svm: &mut LiteSVM,
+    address: &Pubkey,
+    program_owner: &Pubkey,
+    owner: &Pubkey,
+    reputation_points: i64,
+) {
+    let mut data = soteria_client::account_discriminator("UserProfile").to_vec();
+    "voter".to_string().serialize(&mut data).unwrap();
+    data.extend_from_slice(owner.as_ref());
+    data.extend_from_slice(&reputation_points.to_le_bytes());
+    // Remaining UserProfile fields are not read by claim_rewards
+    data.resize(256, 0);
+
+    let lamports = svm.minimum_balance_for_rent_exemption(data.len());
+    svm.set_account(
+        *address,
+        solana_sdk::account::Account {
+            lamports,
+            data,
+            owner: *program_owner,
+            executable: false,
+            rent_epoch: 0,
+        },
     )
+    .expect("Profile account should be set");
 }
 
 /// Advance the SVM clock by the specified number of seconds
@@ -73,6 +177,25 @@ pub fn advance_time(svm: &mut LiteSVM, seconds: u64) {
     svm.set_sysvar(&clock);
 }
 
+/// Read and deserialize a StakeRecord account
+pub fn get_stake_record(svm: &LiteSVM, asset: &Pubkey) -> StakeRecord {
+    let (stake_record, _) = nft_staking::stake_record_address(asset);
+    let account = svm.get_account(&stake_record).expect("Stake record should exist");
+    StakeRecord::try_from_bytes(&account.data).expect("Stake record should decode")
+}
+
+/// Send request_unstake and advance the clock to the end of the cooldown
+pub fn request_unstake_and_wait(
+    svm: &mut LiteSVM,
+    owner: &Keypair,
+    asset: &Pubkey,
+    collection: &Pubkey,
+) {
+    let ix = nft_ix::request_unstake(&owner.pubkey(), asset, collection);
+    send_tx_expect_success(svm, ix, owner, &[owner]);
+    advance_time(svm, UNSTAKE_COOLDOWN as u64);
+}
+
 // ======================== MPL-CORE HELPERS ========================
 
 /// Create a Metaplex Core collection (manual instruction)
@@ -178,141 +301,171 @@ pub fn mint_mpl_asset(
     svm.send_transaction(tx).expect("Failed to mint MPL Core asset");
 }
 
-// ======================== INSTRUCTION BUILDERS ========================
-
-/// Build create_collection instruction
-pub fn build_create_collection_ix(
-    authority: &Pubkey,
+/// Overwrite an asset's existing Attributes plugin directly through mpl-core
+/// authority must be the collection's update authority
+pub fn set_asset_attributes(
+    svm: &mut LiteSVM,
+    authority: &Keypair,
+    asset: &Pubkey,
     collection: &Pubkey,
-    collection_state: &Pubkey,
-    payer: &Pubkey,
-    mpl_core_program: &Pubkey,
-    name: String,
-    uri: String,
-) -> Instruction {
-    let discriminator = anchor_discriminator("create_collection");
+    attributes: &[(&str, &str)],
+) {
+    // UpdatePluginV1 (mpl-core instructions use a single-byte discriminator)
+    let mut data = vec![6u8];
+    data.extend_from_slice(&attributes_plugin_data(attributes));
 
-    let mut data = discriminator.to_vec();
+    let accounts = vec![
+        AccountMeta::new(*asset, false),                           // asset
+        AccountMeta::new(*collection, false),                      // collection
+        AccountMeta::new(authority.pubkey(), true),                // payer
+        AccountMeta::new_readonly(authority.pubkey(), true),       // authority
+        AccountMeta::new_readonly(system_program, false),          // system_program
+        AccountMeta::new_readonly(MPL_CORE_ID, false),             // log_wrapper: None
+    ];
 
-    // Serialize name
-    data.extend_from_slice(&(name.len() as u32).to_le_bytes());
-    data.extend_from_slice(name.as_bytes());
-
-    // Serialize uri
-    data.extend_from_slice(&(uri.len() as u32).to_le_bytes());
-    data.extend_from_slice(uri.as_bytes());
-
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*authority, true),
-            AccountMeta::new(*collection, true),
-            AccountMeta::new(*collection_state, false),
-            AccountMeta::new(*payer, true),
-            AccountMeta::new_readonly(*mpl_core_program, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
+    let ix = Instruction {
+        program_id: MPL_CORE_ID,
+        accounts,
         data,
+    };
+
+    send_tx_expect_success(svm, ix, authority, &[authority]);
+}
+
+// ======================== MPL-CORE PLUGIN FIXTURES ========================
+
+/// Plugin state an asset is pre-loaded with before a stake or unstake test
+/// Fixtures go straight through mpl-core, so no StakeRecord is ever created
+pub enum AssetPluginFixture<'a> {
+    /// No plugins beyond what mint_nft adds (Attributes and FreezeDelegate missing)
+    Bare,
+    /// FreezeDelegate { frozen: true } added by the owner, no Attributes
+    Frozen,
+    /// Attributes and FreezeDelegate exactly as stake leaves them
+    Staked { staked_at: i64 },
+    /// Attributes plugin holding the given list, no FreezeDelegate
+    /// Use for corrupted staking keys: duplicates, lookalikes, non-numeric values
+    Attributes(&'a [(&'a str, &'a str)]),
+}
+
+/// Serialize an Attributes plugin (Plugin::Attributes = 6, then Vec<Attribute { key, value }>)
+fn attributes_plugin_data(attributes: &[(&str, &str)]) -> Vec<u8> {
+    let mut data = vec![6u8];
+    data.extend_from_slice(&(attributes.len() as u32).to_le_bytes());
+    for (key, value) in attributes {
+        key.serialize(&mut data).expect("Failed to serialize key");
+        value.serialize(&mut data).expect("Failed to serialize value");
     }
+    data
 }
 
-/// Build mint_nft instruction
-pub fn build_mint_nft_ix(
-    authority: &Pubkey,
+/// Serialized Option<Authority> for AddPluginV1: None keeps the plugin's default manager
+const DEFAULT_PLUGIN_AUTHORITY: &[u8] = &[0u8];
+/// Some(Authority::UpdateAuthority)
+const UPDATE_AUTHORITY_PLUGIN_AUTHORITY: &[u8] = &[1u8, 2u8];
+
+/// Add a plugin to an asset directly through mpl-core (AddPluginV1)
+/// plugin is the borsh-serialized Plugin, init_authority a serialized Option<Authority>
+fn add_asset_plugin(
+    svm: &mut LiteSVM,
+    signer: &Keypair,
     asset: &Pubkey,
     collection: &Pubkey,
-    collection_state: &Pubkey,
-    update_authority: &Pubkey,
-    owner: &Pubkey,
-    payer: &Pubkey,
-    mpl_core_program: &Pubkey,
-    name: String,
-    uri: String,
-) -> Instruction {
-    let discriminator = anchor_discriminator("mint_nft");
+    plugin: &[u8],
+    init_authority: &[u8],
+) {
+    // AddPluginV1 (single-byte discriminator), then AddPluginV1Args { plugin, init_authority }
+    let mut data = vec![2u8];
+    data.extend_from_slice(plugin);
+    data.extend_from_slice(init_authority);
 
-    let mut data = discriminator.to_vec();
+    let accounts = vec![
+        AccountMeta::new(*asset, false),                           // asset
+        AccountMeta::new(*collection, false),                      // collection
+        AccountMeta::new(signer.pubkey(), true),                   // payer
+        AccountMeta::new_readonly(signer.pubkey(), true),          // authority
+        AccountMeta::new_readonly(system_program, false),          // system_program
+        AccountMeta::new_readonly(MPL_CORE_ID, false),             // log_wrapper: None
+    ];
 
-    // Serialize name
-    data.extend_from_slice(&(name.len() as u32).to_le_bytes());
-    data.extend_from_slice(name.as_bytes());
-
-    // Serialize uri
-    data.extend_from_slice(&(uri.len() as u32).to_le_bytes());
-    data.extend_from_slice(uri.as_bytes());
-
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new(*authority, true),           // authority (signer)
-            AccountMeta::new(*asset, true),               // asset (signer)
-            AccountMeta::new(*collection, false),         // collection
-            AccountMeta::new(*collection_state, false),   // collection_state
-            AccountMeta::new_readonly(*update_authority, false), // update_authority
-            AccountMeta::new_readonly(*owner, false),     // owner
-            AccountMeta::new(*payer, true),               // payer (signer)
-            AccountMeta::new_readonly(*mpl_core_program, false), // mpl_core_program
-            AccountMeta::new_readonly(system_program, false),    // system_program
-        ],
+    let ix = Instruction {
+        program_id: MPL_CORE_ID,
+        accounts,
         data,
-    }
+    };
+
+    send_tx_expect_success(svm, ix, signer, &[signer]);
 }
 
-/// Build stake instruction (no args, just discriminator)
-pub fn build_stake_ix(
-    owner: &Pubkey,
-    update_authority: &Pubkey,
-    payer: &Pubkey,
+/// Add an Attributes plugin to an asset that has none
+/// authority must be the collection's update authority (Attributes is authority-managed)
+pub fn add_asset_attributes(
+    svm: &mut LiteSVM,
+    authority: &Keypair,
     asset: &Pubkey,
     collection: &Pubkey,
-    collection_state: &Pubkey,
-    mpl_core_program: &Pubkey,
-
-) -> Instruction {
-    let discriminator = anchor_discriminator("stake");
-
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new_readonly(*owner, true),
-            AccountMeta::new_readonly(*update_authority, true),
-            AccountMeta::new(*payer, true),
-            AccountMeta::new(*asset, false),
-            AccountMeta::new(*collection, false),
-            AccountMeta::new(*collection_state, false),
-            AccountMeta::new_readonly(*mpl_core_program, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data: discriminator.to_vec(),
-    }
+    attributes: &[(&str, &str)],
+) {
+    let plugin = attributes_plugin_data(attributes);
+    add_asset_plugin(svm, authority, asset, collection, &plugin, DEFAULT_PLUGIN_AUTHORITY);
 }
 
-/// Build unstake instruction (no args, just discriminator)
-pub fn build_unstake_ix(
-    owner: &Pubkey,
-    update_authority: &Pubkey,
-    payer: &Pubkey,
+/// Freeze an asset the way stake does: FreezeDelegate added by the owner,
+/// with the collection's update authority as the plugin authority
+pub fn freeze_asset(
+    svm: &mut LiteSVM,
+    owner: &Keypair,
     asset: &Pubkey,
     collection: &Pubkey,
-    collection_state: &Pubkey,
-    mpl_core_program: &Pubkey,
-) -> Instruction {
-    let discriminator = anchor_discriminator("unstake");
-
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![
-            AccountMeta::new_readonly(*owner, true),
-            AccountMeta::new_readonly(*update_authority, true),
-            AccountMeta::new(*payer, true),
-            AccountMeta::new(*asset, false),
-            AccountMeta::new(*collection, false),
-            AccountMeta::new(*collection_state, false),
-            AccountMeta::new_readonly(*mpl_core_program, false),
-            AccountMeta::new_readonly(system_program, false),
-        ],
-        data: discriminator.to_vec(),
+) {
+    // Plugin::FreezeDelegate = 1, FreezeDelegate { frozen: true }
+    add_asset_plugin(svm, owner, asset, collection, &[1u8, 1u8], UPDATE_AUTHORITY_PLUGIN_AUTHORITY);
+}
+
+/// Mint an asset into a collection created with nft_ix::create_collection,
+/// then pre-load it with the fixture's plugins
+pub fn mint_asset_with_plugins(
+    svm: &mut LiteSVM,
+    authority: &Keypair,
+    owner: &Keypair,
+    collection: &Pubkey,
+    fixture: AssetPluginFixture,
+) -> Keypair {
+    let asset = Keypair::new();
+    let mint_nft_ix = nft_ix::mint_nft(
+        &authority.pubkey(),
+        &asset.pubkey(),
+        collection,
+        &authority.pubkey(),
+        &owner.pubkey(),
+        &authority.pubkey(),
+        "Fixture NFT",
+        "https://example.com/fixture.json",
+    );
+    send_tx_expect_success(svm, mint_nft_ix, authority, &[authority, &asset]);
+
+    match fixture {
+        AssetPluginFixture::Bare => {}
+        AssetPluginFixture::Frozen => {
+            freeze_asset(svm, owner, &asset.pubkey(), collection);
+        }
+        AssetPluginFixture::Staked { staked_at } => {
+            let staked_at = staked_at.to_string();
+            add_asset_attributes(
+                svm,
+                authority,
+                &asset.pubkey(),
+                collection,
+                &[("staked", &staked_at), ("staked_time", "0")],
+            );
+            freeze_asset(svm, owner, &asset.pubkey(), collection);
+        }
+        AssetPluginFixture::Attributes(attributes) => {
+            add_asset_attributes(svm, authority, &asset.pubkey(), collection, attributes);
+        }
     }
+
+    asset
 }
 
 // ======================== TRANSACTION HELPERS ========================
@@ -0,0 +1,83 @@
+// Staking Attribute Helpers - VULNERABLE VERSION
+//
+// Reads and writes the "staked" / "staked_time" attributes on an asset's
+// Attributes plugin with substring key matching and lenient parsing.
+
+use mpl_core::types::{Attribute, Attributes};
+
+use crate::constants::*;
+
+// Staking data located in an Attributes plugin
+// Matched attributes are written back in place under their original keys
+pub struct StakingAttributes {
+    pub staked: i64,
+    pub staked_time: i64,
+    attribute_list: Vec<Attribute>,
+    staked_index: Option<usize>,
+    staked_time_index: Option<usize>,
+}
+
+impl StakingAttributes {
+    pub fn empty() -> Self {
+        Self {
+            staked: 0,
+            staked_time: 0,
+            attribute_list: Vec::new(),
+            staked_index: None,
+            staked_time_index: None,
+        }
+    }
+
+    // VULNERABILITY [HIGH]: Substring attribute key matching
+    //
+    // The secure version matches keys byte-for-byte, rejects duplicates
+    // and lookalike keys, and requires canonical non-negative integers:
+    // if attribute.key == STAKED_KEY { ... }
+    //
+    // Here any key containing "staked" is read as the stake timestamp, and
+    // the last match wins, so a trait like "staked_season" written after
+    // staking shadows the real "staked" value. Unparseable values become 0.
+    //
+    // Example Attack:
+    //   1. Owner stakes; the asset gets staked = <now>, staked_time = 0
+    //   2. A trait "staked_season" = "1" is added to the asset
+    //   3. unstake reads the stake timestamp as 1 (January 1970)
+    //   4. staked_time is credited with ~55 years; claim_rewards pays it out
+    //
+    // Fix: Match STAKED_KEY / STAKED_TIME_KEY exactly, once each
+    pub fn read(attributes: Attributes) -> Self {
+        let mut staking = Self::empty();
+
+        for (index, attribute) in attributes.attribute_list.iter().enumerate() {
+            if attribute.key.contains(STAKED_TIME_KEY) {
+                staking.staked_time = attribute.value.parse::<i64>().unwrap_or(0);
+                staking.staked_time_index = Some(index);
+            } else if attribute.key.contains(STAKED_KEY) {
+                staking.staked = attribute.value.parse::<i64>().unwrap_or(0);
+                staking.staked_index = Some(index);
+            }
+        }
+
+        staking.attribute_list = attributes.attribute_list;
+        staking
+    }
+
+    pub fn into_attributes(self) -> Attributes {
+        let mut attribute_list = self.attribute_list;
+
+        for (index, key, value) in [
+            (self.staked_index, STAKED_KEY, self.staked),
+            (self.staked_time_index, STAKED_TIME_KEY, self.staked_time),
+        ] {
+            match index {
+                Some(index) => attribute_list[index].value = value.to_string(),
+                None => attribute_list.push(Attribute {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                }),
+            }
+        }
+
+        Attributes { attribute_list }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod attributes;
+
+pub use attributes::*;
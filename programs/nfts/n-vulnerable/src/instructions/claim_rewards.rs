@@ -0,0 +1,239 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, MintTo, Token, TokenAccount},
+};
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    fetch_plugin,
+    types::{Attributes, PluginType},
+};
+
+use crate::{
+    constants::*,
+    errors::NftError,
+    state::{CollectionState, GovernanceProfile, RarityConfig, RewardConfig},
+};
+
+// Claim Rewards Instruction - VULNERABLE VERSION
+//
+// Mints reward tokens with critical security vulnerabilities.
+
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub asset: Account<'info, BaseAssetV1>,
+
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    #[account(
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+
+    #[account(
+        mut,
+        seeds = [
+            REWARD_CONFIG,
+            collection.key().as_ref(),
+        ],
+        bump = reward_config.bump,
+        has_one = reward_mint @ NftError::InvalidRewardMint,
+    )]
+    pub reward_config: Account<'info, RewardConfig>,
+
+    #[account(
+        seeds = [
+            RARITY_CONFIG,
+            collection.key().as_ref(),
+        ],
+        bump,
+    )]
+    /// CHECK: Canonical PDA enforced by seeds; deserialized in handler when initialized
+    pub rarity_config: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub reward_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = reward_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_reward_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Deserialized as a governance UserProfile in handler
+    pub voter_profile: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> ClaimRewards<'info> {
+    pub fn claim_rewards(&mut self, rarity: String) -> Result<()> {
+        // VULNERABILITY [CRITICAL]: Missing owner validation
+        //
+        // The secure version validates:
+        // require!(self.asset.owner == self.owner.key(), NftError::AssetOwnerMismatch);
+        //
+        // Without this check, ANYONE can claim rewards for ANYONE ELSE'S NFT!
+        // Rewards are minted to the caller's token account, not the owner's.
+        //
+        // Fix: Validate asset.owner == owner.key()
+
+        // VULNERABILITY [CRITICAL]: Missing collection validation
+        //
+        // The secure version validates:
+        // require!(self.asset.update_authority == UpdateAuthority::Collection(self.collection.key()), ...);
+        //
+        // Without this check, an asset from any collection with staking
+        // attributes can mint this collection's reward token.
+        //
+        // Fix: Validate asset belongs to the correct collection
+
+        let current_time = Clock::get()?.unix_timestamp;
+
+        let (_, fetched_attribute_list, _) = fetch_plugin::<BaseAssetV1, Attributes>(
+            &self.asset.to_account_info(),
+            PluginType::Attributes,
+        )
+        .map_err(|_| NftError::AttributesNotInitialized)?;
+
+        let mut staked_timestamp: i64 = 0;
+        let mut staked_time: i64 = 0;
+
+        for attribute in fetched_attribute_list.attribute_list.iter() {
+            if attribute.key == STAKED_KEY {
+                staked_timestamp = attribute.value.parse::<i64>().unwrap_or(0);
+            } else if attribute.key == STAKED_TIME_KEY {
+                staked_time = attribute.value.parse::<i64>().unwrap_or(0);
+            }
+        }
+
+        // VULNERABILITY [MEDIUM]: Unchecked time arithmetic
+        //
+        // The secure version uses checked_sub/checked_add and rejects
+        // negative durations before converting to u64.
+        //
+        // Fix: Use checked arithmetic and RewardConfig::reward_for()
+        let mut total_staked_time = staked_time;
+        if staked_timestamp != 0 {
+            total_staked_time = total_staked_time + (current_time - staked_timestamp); // VULNERABLE: Unchecked
+        }
+
+        // VULNERABILITY [CRITICAL]: No per-asset claim tracking
+        //
+        // The secure version reads a StakeRecord PDA and only pays for time
+        // since its last_claim timestamp:
+        // let unclaimed_time = self.stake_record.unclaimed_seconds(current_time)?;
+        //
+        // Without it, every call pays for the asset's entire staking history.
+        //
+        // Example Attack:
+        //   1. Stake NFT for one day
+        //   2. Call claim_rewards in a loop
+        //   3. Each call mints a full day of rewards
+        //
+        // Fix: Track last_claim on the StakeRecord and pay only the difference
+        let base_amount = total_staked_time as u64 * self.reward_config.reward_rate_per_sec; // VULNERABLE: Unchecked mul
+
+        // VULNERABILITY [CRITICAL]: Rarity read from instruction data
+        //
+        // The secure version reads the "rarity" attribute from the asset's
+        // authority-managed Attributes plugin, which only the collection
+        // authority can write.
+        //
+        // Here the caller passes rarity as an argument, so every asset can
+        // claim at the highest configured multiplier.
+        //
+        // Example Attack:
+        //   1. Collection sets "common" = 1x and "legendary" = 5x
+        //   2. Attacker stakes a common NFT
+        //   3. Attacker calls claim_rewards with rarity = "legendary"
+        //   4. Attacker mints 5x the rewards their NFT earns
+        //
+        // Fix: Read RARITY_KEY from the asset via fetch_plugin::<BaseAssetV1, Attributes>
+        let multiplier_bps = if self.rarity_config.data_is_empty() {
+            BASE_MULTIPLIER_BPS
+        } else {
+            let rarity_config_info = self.rarity_config.to_account_info();
+            let rarity_config = Account::<RarityConfig>::try_from(&rarity_config_info)?;
+            rarity_config.multiplier_for(Some(rarity.as_str())) // VULNERABLE: User-supplied rarity
+        };
+
+        let amount = base_amount * multiplier_bps as u64 / BASE_MULTIPLIER_BPS as u64; // VULNERABLE: Unchecked mul
+
+        // VULNERABILITY [CRITICAL]: Voter profile not tied to the governance program
+        //
+        // The secure version validates:
+        // require_keys_eq!(voter_profile.key(), <["user_profile", owner] PDA of GOVERNANCE_PROGRAM_ID>, ...);
+        // require_keys_eq!(*voter_profile.owner, GOVERNANCE_PROGRAM_ID, ...);
+        //
+        // Here any account with the UserProfile discriminator is trusted.
+        //
+        // Example Attack:
+        //   1. Attacker deploys a program that writes a UserProfile-shaped
+        //      account with reputation_points = 1000
+        //   2. Attacker passes it as voter_profile
+        //   3. Attacker mints at the Leader tier (2x) without any reputation
+        //
+        // Fix: Derive the PDA from the owner and check the governance program owns it
+        let amount = match &self.voter_profile {
+            Some(voter_profile) => {
+                let profile = GovernanceProfile::load(&voter_profile.to_account_info())?;
+                amount * profile.multiplier_bps() as u64 / BASE_MULTIPLIER_BPS as u64 // VULNERABLE: Unchecked mul
+            }
+            None => amount,
+        };
+        require!(amount > 0, NftError::NoRewardsToClaim);
+
+        // VULNERABILITY [HIGH]: Rewards minted without funding
+        //
+        // The secure version pays claims out of a reward vault filled by
+        // fund_rewards, and stops accrual at the funded emission end:
+        // let accrual_time = self.reward_config.emission_cutoff(current_time);
+        // require!(self.reward_vault.amount >= amount, NftError::InsufficientRewardFunds);
+        //
+        // Here the reward config is the mint authority, so every claim mints
+        // new supply. Emissions have no budget and no end date, and every
+        // other bug in this instruction mints unlimited tokens instead of
+        // draining a bounded vault.
+        //
+        // Fix: Transfer claims from a funded vault and cap accrual at emission_end_ts
+
+        let collection_key = self.collection.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            REWARD_CONFIG,
+            collection_key.as_ref(),
+            &[self.reward_config.bump],
+        ]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                MintTo {
+                    mint: self.reward_mint.to_account_info(),
+                    to: self.owner_reward_account.to_account_info(),
+                    authority: self.reward_config.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        // VULNERABILITY [MEDIUM]: Unchecked increment
+        //
+        // Fix: self.reward_config.record_minted(amount)?;
+        self.reward_config.total_rewards_minted += amount; // VULNERABLE: Can overflow
+
+        Ok(())
+    }
+}
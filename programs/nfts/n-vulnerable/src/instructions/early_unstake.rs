@@ -0,0 +1,216 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use mpl_core::{
+    ID as MPL_CORE_ID,
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    fetch_plugin,
+    instructions::{RemovePluginV1CpiBuilder, UpdatePluginV1CpiBuilder},
+    types::{
+        Attribute, Attributes, FreezeDelegate, Plugin, PluginType, UpdateAuthority,
+    },
+};
+
+use crate::{
+    constants::*,
+    errors::NftError,
+    state::{CollectionState, CollectionTreasury, PenaltyCurve},
+};
+
+// Early Unstake NFT Instruction - VULNERABLE VERSION
+//
+// Unstakes an NFT before MIN_STAKE_DURATION for a SOL penalty,
+// computing the penalty with unchecked arithmetic.
+
+#[derive(Accounts)]
+pub struct EarlyUnstake<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub update_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub asset: Account<'info, BaseAssetV1>,
+
+    #[account(mut)]
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    #[account(
+        mut,
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR + CollectionTreasury::INIT_SPACE,
+        seeds = [
+            COLLECTION_TREASURY,
+            collection.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub collection_treasury: Account<'info, CollectionTreasury>,
+
+    #[account(address = MPL_CORE_ID @ NftError::InvalidMplCoreProgram)]
+    /// CHECK: Validated by address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> EarlyUnstake<'info> {
+    pub fn early_unstake(&mut self, bumps: &EarlyUnstakeBumps) -> Result<()> {
+        require!(
+            self.asset.owner == self.owner.key(),
+            NftError::AssetOwnerMismatch
+        );
+
+        require!(
+            self.asset.update_authority == UpdateAuthority::Collection(self.collection.key()),
+            NftError::AssetNotInCollection
+        );
+
+        require!(
+            self.update_authority.key() == self.collection_state.authority,
+            NftError::CollectionAuthorityMismatch
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let mut time_staked: i64 = 0;
+
+        match fetch_plugin::<BaseAssetV1, Attributes>(
+            &self.asset.to_account_info(),
+            mpl_core::types::PluginType::Attributes,
+        ) {
+            Ok((_, fetched_attribute_list, _)) => {
+                let mut attribute_list: Vec<Attribute> = Vec::new();
+                let mut staked_time: i64 = 0;
+
+                for attribute in fetched_attribute_list.attribute_list.iter() {
+                    if attribute.key == STAKED_KEY {
+                        require!(attribute.value != "0", NftError::NotStaked);
+
+                        let staked_timestamp = attribute
+                            .value
+                            .parse::<i64>()
+                            .unwrap_or(0);
+
+                        time_staked = current_time - staked_timestamp; // VULNERABLE: Unchecked sub
+                        staked_time = staked_time + time_staked; // VULNERABLE: Unchecked add
+
+                        attribute_list.push(Attribute {
+                            key: STAKED_KEY.to_string(),
+                            value: 0.to_string(),
+                        });
+                    } else if attribute.key == STAKED_TIME_KEY {
+                        let existing_time = attribute
+                            .value
+                            .parse::<i64>()
+                            .unwrap_or(0);
+
+                        staked_time = staked_time + existing_time; // VULNERABLE: Unchecked add
+                    } else {
+                        attribute_list.push(attribute.clone());
+                    }
+                }
+
+                attribute_list.push(Attribute {
+                    key: STAKED_TIME_KEY.to_string(),
+                    value: staked_time.to_string(),
+                });
+
+                UpdatePluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
+                    .asset(&self.asset.to_account_info())
+                    .collection(Some(&self.collection.to_account_info()))
+                    .payer(&self.payer.to_account_info())
+                    .authority(Some(&self.update_authority.to_account_info()))
+                    .system_program(&self.system_program.to_account_info())
+                    .plugin(Plugin::Attributes(Attributes { attribute_list }))
+                    .invoke()?;
+            }
+            Err(_) => {
+                return Err(NftError::AttributesNotInitialized.into());
+            }
+        }
+
+        // VULNERABILITY [HIGH]: Unchecked subtraction in penalty math
+        //
+        // The secure version uses CollectionState::early_unstake_penalty():
+        // let remaining = MIN_STAKE_DURATION.checked_sub(time_staked)?;
+        // if remaining <= 0 { return Ok(0); }
+        //
+        // Here the remaining lockup is subtracted without a check and cast
+        // straight to u64. Once the lockup has passed, remaining is negative
+        // and the cast wraps it to a value near u64::MAX.
+        //
+        // Example Attack:
+        //   1. Owner stakes past MIN_STAKE_DURATION and calls early_unstake
+        //   2. remaining wraps to ~u64::MAX instead of zero
+        //   3. With overflow checks the curve math aborts, so the exit fails
+        //   4. Without them the multiply wraps and the owner is charged an
+        //      arbitrary penalty, up to their whole balance
+        //
+        // Fix: Use checked_sub, return zero once the lockup is met,
+        //      and do the curve math in u128 with checked operations
+        let remaining = (MIN_STAKE_DURATION - time_staked) as u64; // VULNERABLE: Unchecked sub, wrapping cast
+        let lockup = MIN_STAKE_DURATION as u64;
+        let max_penalty = self.collection_state.early_unstake_penalty;
+
+        let penalty = match self.collection_state.penalty_curve {
+            PenaltyCurve::Linear => max_penalty * remaining / lockup, // VULNERABLE: Unchecked mul
+            PenaltyCurve::Quadratic => {
+                max_penalty * remaining * remaining / (lockup * lockup) // VULNERABLE: Unchecked mul
+            }
+        };
+
+        let treasury = &mut self.collection_treasury;
+        treasury.collection = self.collection.key();
+        treasury.bump = bumps.collection_treasury;
+
+        if penalty > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.owner.to_account_info(),
+                        to: treasury.to_account_info(),
+                    },
+                ),
+                penalty,
+            )?;
+        }
+        treasury.total_penalties += penalty; // VULNERABLE: Can overflow
+
+        UpdatePluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
+            .asset(&self.asset.to_account_info())
+            .collection(Some(&self.collection.to_account_info()))
+            .payer(&self.payer.to_account_info())
+            .authority(Some(&self.update_authority.to_account_info()))
+            .system_program(&self.system_program.to_account_info())
+            .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: false }))
+            .invoke()?;
+
+        RemovePluginV1CpiBuilder::new(&self.mpl_core_program)
+            .asset(&self.asset.to_account_info())
+            .collection(Some(&self.collection.to_account_info()))
+            .payer(&self.payer)
+            .authority(Some(&self.owner))
+            .system_program(&self.system_program)
+            .plugin_type(PluginType::FreezeDelegate)
+            .invoke()?;
+
+        self.collection_state.total_staked -= 1; // VULNERABLE: Can underflow
+
+        Ok(())
+    }
+}
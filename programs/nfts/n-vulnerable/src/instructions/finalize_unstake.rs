@@ -5,18 +5,23 @@ use mpl_core::{
     fetch_plugin,
     instructions::{RemovePluginV1CpiBuilder, UpdatePluginV1CpiBuilder},
     types::{
-        Attribute, Attributes, FreezeDelegate, Plugin, PluginType, UpdateAuthority,
+        Attributes, FreezeDelegate, Plugin, PluginType, UpdateAuthority,
     },
 };
 
-use crate::{constants::*, errors::NftError, state::CollectionState};
+use crate::{
+    constants::*,
+    errors::NftError,
+    helpers::StakingAttributes,
+    state::CollectionState,
+};
 
-// Unstake NFT Instruction - VULNERABLE VERSION
+// Finalize Unstake NFT Instruction - VULNERABLE VERSION
 //
 // Unstakes NFTs with critical security vulnerabilities.
 
 #[derive(Accounts)]
-pub struct Unstake<'info> {
+pub struct FinalizeUnstake<'info> {
     pub owner: Signer<'info>,
 
     pub update_authority: Signer<'info>,
@@ -47,8 +52,8 @@ pub struct Unstake<'info> {
     pub system_program: Program<'info, System>,
 }
 
-impl<'info> Unstake<'info> {
-    pub fn unstake(&mut self) -> Result<()> {
+impl<'info> FinalizeUnstake<'info> {
+    pub fn finalize_unstake(&mut self) -> Result<()> {
         // VULNERABILITY [CRITICAL]: Missing owner validation
         //
         // The secure version validates:
@@ -83,6 +88,23 @@ impl<'info> Unstake<'info> {
         //
         // Fix: require!(self.update_authority.key() == self.collection_state.authority, ...);
 
+        // VULNERABILITY [HIGH]: Unstake cooldown not enforced
+        //
+        // The secure version takes the stake record and checks:
+        // require!(self.stake_record.unstake_requested(), NftError::UnstakeNotRequested);
+        // require!(current_time >= self.stake_record.cooldown_ends_at, NftError::UnstakeCooldownActive);
+        //
+        // This version never reads the stake record, so request_unstake's
+        // cooldown is only bookkeeping and finalize runs immediately.
+        //
+        // Example Attack:
+        //   1. Stake just before a reward rate increase or badge window
+        //   2. Collect the boosted rewards
+        //   3. request_unstake and finalize_unstake in the same transaction
+        //   4. Capital is never idle, so stakers who stay are diluted
+        //
+        // Fix: Pass the stake record and require the cooldown to have ended
+
         let current_time = Clock::get()?.unix_timestamp;
 
         // VULNERABILITY [HIGH]: Missing staking state validation
@@ -97,60 +119,37 @@ impl<'info> Unstake<'info> {
         //
         // Fix: Check if staked attribute value is != "0" before unstaking
 
+        // Staking keys are located by substring (see helpers/attributes.rs)
         match fetch_plugin::<BaseAssetV1, Attributes>(
             &self.asset.to_account_info(),
             mpl_core::types::PluginType::Attributes,
         ) {
-            Ok((_, fetched_attribute_list, _)) => {
-                let mut attribute_list: Vec<Attribute> = Vec::new();
-                let mut is_initialized: bool = false;
-                let mut staked_time: i64 = 0;
-
-                for attribute in fetched_attribute_list.attribute_list.iter() {
-                    if attribute.key == STAKED_KEY {
-                        // VULNERABLE: No check if already unstaked!
-
-                        // Parse staked timestamp - VULNERABLE: No error handling
-                        let staked_timestamp = attribute
-                            .value
-                            .parse::<i64>()
-                            .unwrap_or(0);
-
-                        // VULNERABILITY [MEDIUM]: Unchecked arithmetic
-                        //
-                        // The secure version uses:
-                        // current_time.checked_sub(staked_timestamp).ok_or(NftError::Underflow)?;
-                        //
-                        // Without checked arithmetic, can cause integer underflow/overflow
-                        //
-                        // Fix: Use checked_sub and checked_add
-                        let time_staked = current_time - staked_timestamp; // VULNERABLE: Unchecked sub
-
-                        staked_time = staked_time + time_staked; // VULNERABLE: Unchecked add
-
-                        attribute_list.push(Attribute {
-                            key: STAKED_KEY.to_string(),
-                            value: 0.to_string(),
-                        });
-                        is_initialized = true;
-                    } else if attribute.key == STAKED_TIME_KEY {
-                        let existing_time = attribute
-                            .value
-                            .parse::<i64>()
-                            .unwrap_or(0);
-
-                        staked_time = staked_time + existing_time; // VULNERABLE: Unchecked add
-                    } else {
-                        attribute_list.push(attribute.clone());
-                    }
-                }
-
+            Ok((_, fetched_attributes, _)) => {
+                // VULNERABLE: No check if already unstaked!
                 // VULNERABLE: No check if staking was initialized!
-
-                attribute_list.push(Attribute {
-                    key: STAKED_TIME_KEY.to_string(),
-                    value: staked_time.to_string(),
-                });
+                let mut staking = StakingAttributes::read(fetched_attributes);
+
+                // VULNERABILITY [MEDIUM]: Unchecked arithmetic
+                //
+                // The secure version uses:
+                // current_time.checked_sub(staked_timestamp).ok_or(NftError::Underflow)?;
+                //
+                // Without checked arithmetic, can cause integer underflow/overflow
+                //
+                // Fix: Use checked_sub and checked_add
+                let time_staked = current_time - staking.staked; // VULNERABLE: Unchecked sub
+
+                // VULNERABILITY [MEDIUM]: Missing minimum duration check
+                //
+                // The secure version enforces the lockup:
+                // require!(time_staked >= MIN_STAKE_DURATION, NftError::MinimumStakeDurationNotMet);
+                //
+                // Without it, early_unstake's penalty can be skipped entirely.
+                //
+                // Fix: Reject unstakes before MIN_STAKE_DURATION
+
+                staking.staked_time = staking.staked_time + time_staked; // VULNERABLE: Unchecked add
+                staking.staked = 0;
 
                 UpdatePluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
                     .asset(&self.asset.to_account_info())
@@ -158,7 +157,7 @@ impl<'info> Unstake<'info> {
                     .payer(&self.payer.to_account_info())
                     .authority(Some(&self.update_authority.to_account_info()))
                     .system_program(&self.system_program.to_account_info())
-                    .plugin(Plugin::Attributes(Attributes { attribute_list }))
+                    .plugin(Plugin::Attributes(staking.into_attributes()))
                     .invoke()?;
             }
             Err(_) => {
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use mpl_core::accounts::BaseCollectionV1;
+
+use crate::{
+    constants::*,
+    errors::NftError,
+    state::{CollectionState, RewardConfig},
+};
+
+// Init Reward Config Instruction - VULNERABLE VERSION
+//
+// Sets up reward emissions for a collection's staked NFTs.
+// Unlike the secure version, rewards come from a PDA mint the program can
+// mint from at will rather than a vault filled by fund_rewards; the reward
+// vulnerabilities are in claim_rewards.rs.
+
+#[derive(Accounts)]
+pub struct InitRewardConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    #[account(
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+        has_one = authority @ NftError::UnauthorizedAuthority,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + RewardConfig::INIT_SPACE,
+        seeds = [
+            REWARD_CONFIG,
+            collection.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub reward_config: Account<'info, RewardConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            REWARD_MINT,
+            collection.key().as_ref(),
+        ],
+        bump,
+        mint::decimals = REWARD_DECIMALS,
+        mint::authority = reward_config,
+    )]
+    pub reward_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitRewardConfig<'info> {
+    pub fn init_reward_config(
+        &mut self,
+        reward_rate_per_sec: u64,
+        bumps: &InitRewardConfigBumps,
+    ) -> Result<()> {
+        require!(reward_rate_per_sec > 0, NftError::InvalidRewardRate);
+
+        self.reward_config.set_inner(RewardConfig {
+            collection: self.collection.key(),
+            reward_mint: self.reward_mint.key(),
+            reward_rate_per_sec,
+            total_rewards_minted: 0,
+            bump: bumps.reward_config,
+            mint_bump: bumps.reward_mint,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseCollectionV1;
+
+use crate::{constants::*, state::CollectionState};
+
+// Migrate Collection State Instruction - VULNERABLE VERSION
+//
+// Moves a collection's staking state to a new Metaplex Core collection,
+// closing the old state. Only the new collection's authority is checked.
+
+#[derive(Accounts)]
+pub struct MigrateCollectionState<'info> {
+    // VULNERABILITY [CRITICAL]: Old authority never signs or matches
+    //
+    // The secure version uses:
+    // old_authority: Signer<'info>
+    // constraint = old_collection.update_authority == old_authority.key()
+    // constraint = old_collection_state.authority == old_authority.key()
+    //
+    // Without them, anyone with a collection of their own can take over the
+    // staking state of any collection and collect its rent.
+    //
+    // Example Attack:
+    //   1. Attacker creates an empty Metaplex Core collection
+    //   2. Attacker migrates a live collection's state to it, naming
+    //      themselves as old_authority
+    //   3. Attacker becomes the collection authority, the victim's state is
+    //      closed, and minting and staking in the victim collection stop
+    //
+    // Fix: Require the old authority to sign and match both the old
+    // collection and its collection state
+    /// CHECK: Not validated (VULNERABILITY)
+    #[account(mut)]
+    pub old_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub new_authority: Signer<'info>,
+
+    pub old_collection: Account<'info, BaseCollectionV1>,
+
+    #[account(
+        constraint = new_collection.update_authority == new_authority.key(),
+    )]
+    pub new_collection: Account<'info, BaseCollectionV1>,
+
+    #[account(
+        mut,
+        close = old_authority,
+        seeds = [
+            COLLECTION_STATE,
+            old_collection.key().as_ref(),
+        ],
+        bump = old_collection_state.bump,
+    )]
+    pub old_collection_state: Account<'info, CollectionState>,
+
+    #[account(
+        init,
+        payer = new_authority,
+        space = ANCHOR_DISCRIMINATOR + CollectionState::INIT_SPACE,
+        seeds = [
+            COLLECTION_STATE,
+            new_collection.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub new_collection_state: Account<'info, CollectionState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateCollectionState<'info> {
+    pub fn migrate_collection_state(
+        &mut self,
+        bumps: &MigrateCollectionStateBumps,
+    ) -> Result<()> {
+        self.new_collection_state.set_inner(CollectionState {
+            authority: self.new_authority.key(),
+            collection: self.new_collection.key(),
+            bump: bumps.new_collection_state,
+            ..CollectionState::clone(&self.old_collection_state)
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    ID as MPL_CORE_ID,
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    instructions::CreateV2CpiBuilder,
+    types::{
+        ImmutableMetadata, PermanentFreezeDelegate, Plugin, PluginAuthority,
+        PluginAuthorityPair,
+    },
+};
+
+use crate::{
+    constants::*,
+    errors::NftError,
+    state::{BadgeRecord, CollectionState, StakeRecord},
+};
+
+// Mint Milestone Badge Instruction - VULNERABLE VERSION
+//
+// Mints a soulbound badge once an asset has been staked past a milestone,
+// but never remembers which badges were already handed out.
+
+#[derive(Accounts)]
+#[instruction(milestone: u8)]
+pub struct MintMilestoneBadge<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub badge: Signer<'info>,
+
+    #[account(
+        has_one = owner @ NftError::AssetOwnerMismatch,
+    )]
+    pub asset: Account<'info, BaseAssetV1>,
+
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    #[account(
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+
+    #[account(
+        seeds = [
+            STAKE_RECORD,
+            asset.key().as_ref(),
+        ],
+        bump = stake_record.bump,
+        has_one = owner @ NftError::UnauthorizedOwner,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+
+    // VULNERABILITY [HIGH]: init_if_needed on the badge record
+    //
+    // The secure version uses 'init', so the second mint for the same asset
+    // and milestone fails because the record already exists.
+    //
+    // Example Attack:
+    //   1. Owner stakes one NFT for 30 days
+    //   2. Owner calls mint_milestone_badge(0) five times
+    //   3. Five 30-day badges are minted; the record just points at the last one
+    //   4. Any system counting badges as reputation is inflated 5x
+    //
+    // Fix: Replace init_if_needed with init
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR + BadgeRecord::INIT_SPACE,
+        seeds = [
+            BADGE_RECORD,
+            asset.key().as_ref(),
+            &[milestone],
+        ],
+        bump,
+    )]
+    pub badge_record: Account<'info, BadgeRecord>,
+
+    #[account(address = MPL_CORE_ID @ NftError::InvalidMplCoreProgram)]
+    /// CHECK: Validated by address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MintMilestoneBadge<'info> {
+    pub fn mint_milestone_badge(
+        &mut self,
+        milestone: u8,
+        name: String,
+        uri: String,
+        bumps: &MintMilestoneBadgeBumps,
+    ) -> Result<()> {
+        // VULNERABILITY [LOW]: No collection check
+        //
+        // Fix: require!(self.asset.update_authority == UpdateAuthority::Collection(
+        //      self.collection.key()), NftError::AssetNotInCollection);
+
+        let days = *self
+            .collection_state
+            .badge_milestone_days
+            .get(milestone as usize)
+            .ok_or(NftError::InvalidMilestone)?;
+
+        // VULNERABILITY [MEDIUM]: Unchecked time arithmetic
+        //
+        // Fix: self.collection_state.badge_threshold(milestone)? and
+        //      self.stake_record.staked_seconds(current_time)?
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time - self.stake_record.staked_at >= days as i64 * SECONDS_PER_DAY,
+            NftError::MilestoneNotReached
+        );
+
+        // VULNERABILITY [HIGH]: No "already awarded" check (see badge_record)
+        let plugins = vec![
+            PluginAuthorityPair {
+                plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate {
+                    frozen: true,
+                }),
+                authority: Some(PluginAuthority::None),
+            },
+            PluginAuthorityPair {
+                plugin: Plugin::ImmutableMetadata(ImmutableMetadata {}),
+                authority: None,
+            },
+        ];
+
+        CreateV2CpiBuilder::new(&self.mpl_core_program.to_account_info())
+            .asset(&self.badge.to_account_info())
+            .payer(&self.owner.to_account_info())
+            .owner(Some(&self.owner.to_account_info()))
+            .update_authority(Some(&self.collection_state.to_account_info()))
+            .system_program(&self.system_program.to_account_info())
+            .name(name)
+            .uri(uri)
+            .plugins(plugins)
+            .invoke()?;
+
+        self.badge_record.set_inner(BadgeRecord {
+            asset: self.asset.key(),
+            owner: self.owner.key(),
+            badge: self.badge.key(),
+            milestone,
+            awarded_at: current_time,
+            bump: bumps.badge_record,
+        });
+
+        Ok(())
+    }
+}
@@ -1,9 +1,31 @@
 pub mod create_collection;
 pub mod mint_nft;
 pub mod stake;
-pub mod unstake;
+pub mod request_unstake;
+pub mod finalize_unstake;
+pub mod init_reward_config;
+pub mod claim_rewards;
+pub mod set_collection_config;
+pub mod set_early_unstake_penalty;
+pub mod early_unstake;
+pub mod set_rarity_config;
+pub mod set_asset_rarity;
+pub mod set_badge_milestones;
+pub mod mint_milestone_badge;
+pub mod migrate_collection_state;
 
 pub use create_collection::*;
 pub use mint_nft::*;
 pub use stake::*;
-pub use unstake::*;
+pub use request_unstake::*;
+pub use finalize_unstake::*;
+pub use init_reward_config::*;
+pub use claim_rewards::*;
+pub use set_collection_config::*;
+pub use set_early_unstake_penalty::*;
+pub use early_unstake::*;
+pub use set_rarity_config::*;
+pub use set_asset_rarity::*;
+pub use set_badge_milestones::*;
+pub use mint_milestone_badge::*;
+pub use migrate_collection_state::*;
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::{BaseAssetV1, BaseCollectionV1};
+
+use crate::{
+    constants::*,
+    state::StakeRecord,
+};
+
+// Request Unstake Instruction - VULNERABLE VERSION
+//
+// Records an unstake cooldown on the StakeRecord that finalize_unstake never
+// checks.
+
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    pub owner: Signer<'info>,
+
+    pub asset: Account<'info, BaseAssetV1>,
+
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    #[account(
+        mut,
+        seeds = [
+            STAKE_RECORD,
+            asset.key().as_ref(),
+        ],
+        bump = stake_record.bump,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+}
+
+impl<'info> RequestUnstake<'info> {
+    pub fn request_unstake(&mut self) -> Result<()> {
+        // VULNERABILITY [MEDIUM]: Missing owner validation
+        //
+        // Fix: require!(self.stake_record.owner == self.owner.key(), NftError::UnauthorizedOwner);
+
+        // VULNERABILITY [LOW]: Repeat requests restart the cooldown
+        //
+        // Fix: require!(self.stake_record.unstake_requested_at == 0, NftError::UnstakeAlreadyRequested);
+
+        let current_time = Clock::get()?.unix_timestamp;
+
+        self.stake_record.unstake_requested_at = current_time;
+        self.stake_record.cooldown_ends_at = current_time + UNSTAKE_COOLDOWN; // VULNERABLE: Unchecked add
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseCollectionV1;
+
+use crate::{constants::*, errors::NftError, state::CollectionState};
+
+// Set Badge Milestones Instruction - VULNERABLE VERSION
+//
+// Configures how long an asset must stay staked to earn each milestone badge.
+
+#[derive(Accounts)]
+pub struct SetBadgeMilestones<'info> {
+    pub authority: Signer<'info>,
+
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    #[account(
+        mut,
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+        has_one = authority @ NftError::UnauthorizedAuthority,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+}
+
+impl<'info> SetBadgeMilestones<'info> {
+    pub fn set_badge_milestones(
+        &mut self,
+        milestone_days: [u16; BADGE_MILESTONE_COUNT],
+    ) -> Result<()> {
+        // VULNERABILITY [MEDIUM]: No threshold validation
+        //
+        // A zero threshold hands out the badge on the stake itself, and
+        // unordered thresholds make a "365-day" badge easier than the 30-day one
+        //
+        // Fix: require!(milestone_days[0] > 0, NftError::InvalidMilestoneDays);
+        //      require!(milestone_days.windows(2).all(|p| p[0] < p[1]), ...);
+        self.collection_state.badge_milestone_days = milestone_days;
+
+        Ok(())
+    }
+}
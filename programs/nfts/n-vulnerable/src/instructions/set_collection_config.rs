@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseCollectionV1;
+
+use crate::{constants::*, state::CollectionState};
+
+// Set Collection Config Instruction - VULNERABLE VERSION
+//
+// Updates collection-level staking limits without checking who is asking.
+
+#[derive(Accounts)]
+pub struct SetCollectionConfig<'info> {
+    pub authority: Signer<'info>,
+
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    // VULNERABILITY [CRITICAL]: Missing authority constraint
+    //
+    // The secure version uses:
+    // has_one = authority @ NftError::UnauthorizedAuthority
+    //
+    // Without it, any signer can rewrite the config of any collection.
+    //
+    // Example Attack:
+    //   1. Attacker finds a live collection
+    //   2. Attacker calls set_collection_config with paused = true
+    //   3. Every new stake fails with CollectionPaused
+    //   4. Or sets max_staked to the current count to lock out new stakers
+    //
+    // Fix: Add has_one = authority to the collection_state constraint
+    #[account(
+        mut,
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+}
+
+impl<'info> SetCollectionConfig<'info> {
+    pub fn set_collection_config(&mut self, max_staked: u64, paused: bool) -> Result<()> {
+        // VULNERABILITY [MEDIUM]: No staking cap validation
+        //
+        // Fix: require!(max_staked == 0 || max_staked >= self.collection_state.total_staked, ...);
+        self.collection_state.max_staked = max_staked;
+        self.collection_state.paused = paused;
+
+        Ok(())
+    }
+}
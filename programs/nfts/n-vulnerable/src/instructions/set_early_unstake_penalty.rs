@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseCollectionV1;
+
+use crate::{
+    constants::*,
+    errors::NftError,
+    state::{CollectionState, PenaltyCurve},
+};
+
+// Set Early Unstake Penalty Instruction - VULNERABLE VERSION
+//
+// Configures the penalty charged by early_unstake.
+// NOTE: Matches the secure version; the flaw is in how
+// early_unstake.rs applies the penalty.
+
+#[derive(Accounts)]
+pub struct SetEarlyUnstakePenalty<'info> {
+    pub authority: Signer<'info>,
+
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    #[account(
+        mut,
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+        has_one = authority @ NftError::UnauthorizedAuthority,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+}
+
+impl<'info> SetEarlyUnstakePenalty<'info> {
+    pub fn set_early_unstake_penalty(
+        &mut self,
+        early_unstake_penalty: u64,
+        penalty_curve: PenaltyCurve,
+    ) -> Result<()> {
+        self.collection_state.early_unstake_penalty = early_unstake_penalty;
+        self.collection_state.penalty_curve = penalty_curve;
+
+        Ok(())
+    }
+}
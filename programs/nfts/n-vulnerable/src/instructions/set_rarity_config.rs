@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseCollectionV1;
+
+use crate::{
+    constants::*,
+    errors::NftError,
+    state::{CollectionState, RarityConfig, RarityTier},
+};
+
+// Set Rarity Config Instruction - VULNERABLE VERSION
+//
+// Creates or replaces the rarity multiplier table for a collection.
+
+#[derive(Accounts)]
+pub struct SetRarityConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    #[account(
+        seeds = [
+            COLLECTION_STATE,
+            collection.key().as_ref(),
+        ],
+        bump = collection_state.bump,
+        has_one = authority @ NftError::UnauthorizedAuthority,
+    )]
+    pub collection_state: Account<'info, CollectionState>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + RarityConfig::INIT_SPACE,
+        seeds = [
+            RARITY_CONFIG,
+            collection.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub rarity_config: Account<'info, RarityConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetRarityConfig<'info> {
+    pub fn set_rarity_config(
+        &mut self,
+        tiers: Vec<RarityTier>,
+        bumps: &SetRarityConfigBumps,
+    ) -> Result<()> {
+        // VULNERABILITY [MEDIUM]: No tier validation
+        //
+        // Fix: RarityConfig::validate_tiers(&tiers)?; (bounded count,
+        //      unique non-empty values, multipliers within (0, 5x])
+        self.rarity_config.set_inner(RarityConfig {
+            collection: self.collection.key(),
+            tiers,
+            bump: bumps.rarity_config,
+        });
+
+        Ok(())
+    }
+}
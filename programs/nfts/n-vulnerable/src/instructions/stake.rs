@@ -5,11 +5,16 @@ use mpl_core::{
     fetch_plugin,
     instructions::{AddPluginV1CpiBuilder, UpdatePluginV1CpiBuilder},
     types::{
-        Attribute, Attributes, FreezeDelegate, Plugin, PluginAuthority, UpdateAuthority,
+        Attributes, FreezeDelegate, Plugin, PluginAuthority, UpdateAuthority,
     },
 };
 
-use crate::{constants::*, errors::NftError, state::CollectionState};
+use crate::{
+    constants::*,
+    errors::NftError,
+    helpers::StakingAttributes,
+    state::{CollectionState, StakeRecord},
+};
 
 // Stake NFT Instruction - VULNERABLE VERSION
 //
@@ -40,6 +45,20 @@ pub struct Stake<'info> {
     )]
     pub collection_state: Account<'info, CollectionState>,
 
+    // VULNERABLE: init_if_needed reuses an existing record
+    // The secure version uses init, so a staked asset cannot be staked again
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR + StakeRecord::INIT_SPACE,
+        seeds = [
+            STAKE_RECORD,
+            asset.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+
     #[account(address = MPL_CORE_ID @ NftError::InvalidMplCoreProgram)]
     /// CHECK: Validated by address constraint
     pub mpl_core_program: UncheckedAccount<'info>,
@@ -48,7 +67,7 @@ pub struct Stake<'info> {
 }
 
 impl<'info> Stake<'info> {
-    pub fn stake(&mut self) -> Result<()> {
+    pub fn stake(&mut self, bumps: &StakeBumps) -> Result<()> {
         // VULNERABILITY [CRITICAL]: Missing owner validation
         //
         // The secure version validates:
@@ -83,6 +102,15 @@ impl<'info> Stake<'info> {
         //
         // Fix: require!(self.update_authority.key() == self.collection_state.authority, ...);
 
+        // Collection config is enforced here, but set_collection_config
+        // lets anyone change it (see set_collection_config.rs)
+        require!(!self.collection_state.paused, NftError::CollectionPaused);
+        require!(
+            self.collection_state.max_staked == 0
+                || self.collection_state.total_staked < self.collection_state.max_staked,
+            NftError::StakingCapReached
+        );
+
         let current_time = Clock::get()?.unix_timestamp;
 
         // VULNERABILITY [HIGH]: Missing double-staking check
@@ -95,37 +123,16 @@ impl<'info> Stake<'info> {
         //
         // Fix: Check if staked attribute value is "0" before staking
 
+        // Staking keys are located by substring (see helpers/attributes.rs)
         match fetch_plugin::<BaseAssetV1, Attributes>(
             &self.asset.to_account_info(),
             mpl_core::types::PluginType::Attributes,
         ) {
-            Ok((_, fetched_attribute_list, _)) => {
-                let mut attribute_list: Vec<Attribute> = Vec::new();
-                let mut is_initialized: bool = false;
-
-                for attribute in fetched_attribute_list.attribute_list {
-                    if attribute.key == STAKED_KEY {
-                        // VULNERABLE: No check if already staked!
-                        attribute_list.push(Attribute {
-                            key: STAKED_KEY.to_string(),
-                            value: current_time.to_string(),
-                        });
-                        is_initialized = true;
-                    } else {
-                        attribute_list.push(attribute);
-                    }
-                }
-
-                if !is_initialized {
-                    attribute_list.push(Attribute {
-                        key: STAKED_KEY.to_string(),
-                        value: current_time.to_string(),
-                    });
-                    attribute_list.push(Attribute {
-                        key: STAKED_TIME_KEY.to_string(),
-                        value: 0.to_string(),
-                    });
-                }
+            Ok((_, fetched_attributes, _)) => {
+                let mut staking = StakingAttributes::read(fetched_attributes);
+
+                // VULNERABLE: No check if already staked!
+                staking.staked = current_time;
 
                 UpdatePluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
                     .asset(&self.asset.to_account_info())
@@ -133,28 +140,20 @@ impl<'info> Stake<'info> {
                     .payer(&self.payer.to_account_info())
                     .authority(Some(&self.update_authority.to_account_info()))
                     .system_program(&self.system_program.to_account_info())
-                    .plugin(Plugin::Attributes(Attributes { attribute_list }))
+                    .plugin(Plugin::Attributes(staking.into_attributes()))
                     .invoke()?;
             }
             Err(_) => {
+                let mut staking = StakingAttributes::empty();
+                staking.staked = current_time;
+
                 AddPluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
                     .asset(&self.asset.to_account_info())
                     .collection(Some(&self.collection.to_account_info()))
                     .payer(&self.payer.to_account_info())
                     .authority(Some(&self.update_authority.to_account_info()))
                     .system_program(&self.system_program.to_account_info())
-                    .plugin(Plugin::Attributes(Attributes {
-                        attribute_list: vec![
-                            Attribute {
-                                key: STAKED_KEY.to_string(),
-                                value: current_time.to_string(),
-                            },
-                            Attribute {
-                                key: STAKED_TIME_KEY.to_string(),
-                                value: 0.to_string(),
-                            },
-                        ],
-                    }))
+                    .plugin(Plugin::Attributes(staking.into_attributes()))
                     .init_authority(PluginAuthority::UpdateAuthority)
                     .invoke()?;
             }
@@ -189,6 +188,17 @@ impl<'info> Stake<'info> {
             .init_authority(PluginAuthority::Owner) // VULNERABLE: Should be UpdateAuthority
             .invoke()?;
 
+        // Record is overwritten on every stake; only mint_milestone_badge reads it back
+        self.stake_record.set_inner(StakeRecord {
+            asset: self.asset.key(),
+            owner: self.owner.key(),
+            staked_at: current_time,
+            last_claim: current_time,
+            unstake_requested_at: 0,
+            cooldown_ends_at: 0,
+            bump: bumps.stake_record,
+        });
+
         // VULNERABILITY [MEDIUM]: Unchecked increment
         //
         // Fix: self.collection_state.increment_staked()?;
@@ -4,8 +4,11 @@ pub mod constants;
 pub mod errors;
 pub mod instructions;
 pub mod state;
+pub mod helpers;
 
+use constants::BADGE_MILESTONE_COUNT;
 use instructions::*;
+use state::{PenaltyCurve, RarityTier};
 
 declare_id!("vuLNv6w3qNfiHWT8miRGv8FLqSy8FYXPKXNxJjHvgHN");
 
@@ -30,10 +33,77 @@ pub mod nft_staking_vulnerable {
     }
 
     pub fn stake(ctx: Context<Stake>) -> Result<()> {
-        ctx.accounts.stake()
+        ctx.accounts.stake(&ctx.bumps)
     }
 
-    pub fn unstake(ctx: Context<Unstake>) -> Result<()> {
-        ctx.accounts.unstake()
+    pub fn request_unstake(ctx: Context<RequestUnstake>) -> Result<()> {
+        ctx.accounts.request_unstake()
+    }
+
+    pub fn finalize_unstake(ctx: Context<FinalizeUnstake>) -> Result<()> {
+        ctx.accounts.finalize_unstake()
+    }
+
+    pub fn init_reward_config(
+        ctx: Context<InitRewardConfig>,
+        reward_rate_per_sec: u64,
+    ) -> Result<()> {
+        ctx.accounts.init_reward_config(reward_rate_per_sec, &ctx.bumps)
+    }
+
+    pub fn claim_rewards(ctx: Context<ClaimRewards>, rarity: String) -> Result<()> {
+        ctx.accounts.claim_rewards(rarity)
+    }
+
+    pub fn set_collection_config(
+        ctx: Context<SetCollectionConfig>,
+        max_staked: u64,
+        paused: bool,
+    ) -> Result<()> {
+        ctx.accounts.set_collection_config(max_staked, paused)
+    }
+
+    pub fn set_early_unstake_penalty(
+        ctx: Context<SetEarlyUnstakePenalty>,
+        early_unstake_penalty: u64,
+        penalty_curve: PenaltyCurve,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_early_unstake_penalty(early_unstake_penalty, penalty_curve)
+    }
+
+    pub fn early_unstake(ctx: Context<EarlyUnstake>) -> Result<()> {
+        ctx.accounts.early_unstake(&ctx.bumps)
+    }
+
+    pub fn set_rarity_config(
+        ctx: Context<SetRarityConfig>,
+        tiers: Vec<RarityTier>,
+    ) -> Result<()> {
+        ctx.accounts.set_rarity_config(tiers, &ctx.bumps)
+    }
+
+    pub fn set_asset_rarity(ctx: Context<SetAssetRarity>, rarity: String) -> Result<()> {
+        ctx.accounts.set_asset_rarity(rarity)
+    }
+
+    pub fn set_badge_milestones(
+        ctx: Context<SetBadgeMilestones>,
+        milestone_days: [u16; BADGE_MILESTONE_COUNT],
+    ) -> Result<()> {
+        ctx.accounts.set_badge_milestones(milestone_days)
+    }
+
+    pub fn mint_milestone_badge(
+        ctx: Context<MintMilestoneBadge>,
+        milestone: u8,
+        name: String,
+        uri: String,
+    ) -> Result<()> {
+        ctx.accounts.mint_milestone_badge(milestone, name, uri, &ctx.bumps)
+    }
+
+    pub fn migrate_collection_state(ctx: Context<MigrateCollectionState>) -> Result<()> {
+        ctx.accounts.migrate_collection_state(&ctx.bumps)
     }
 }
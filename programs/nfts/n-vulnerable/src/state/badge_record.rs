@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// Milestone badge record - VULNERABLE VERSION
+/// Created with init_if_needed and overwritten on every mint, so it records
+/// only the latest badge instead of preventing a second one
+#[account]
+#[derive(InitSpace)]
+pub struct BadgeRecord {
+    /// The staked Metaplex Core asset that earned the badge
+    pub asset: Pubkey,
+
+    /// Owner the badge was minted to
+    pub owner: Pubkey,
+
+    /// The badge Metaplex Core asset
+    pub badge: Pubkey,
+
+    /// Index into CollectionState.badge_milestone_days
+    pub milestone: u8,
+
+    /// Timestamp the badge was minted
+    pub awarded_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::BADGE_MILESTONE_COUNT;
+
 /// Tracks the state of our NFT collection for the staking program - VULNERABLE VERSION
 /// This PDA stores metadata about the collection used for validation
 #[account]
@@ -18,6 +20,21 @@ pub struct CollectionState {
     /// Total number of NFTs currently staked
     pub total_staked: u64,
 
+    /// Maximum number of NFTs that can be staked at once (0 = no cap)
+    pub max_staked: u64,
+
+    /// When true, new stakes are rejected (unstaking still works)
+    pub paused: bool,
+
+    /// Lamports charged for an early unstake with the full lockup remaining
+    pub early_unstake_penalty: u64,
+
+    /// How the early unstake penalty scales with the remaining lockup
+    pub penalty_curve: PenaltyCurve,
+
+    /// Days an asset must stay staked to earn each milestone badge
+    pub badge_milestone_days: [u16; BADGE_MILESTONE_COUNT],
+
     /// Bump seed for PDA derivation
     pub bump: u8,
 }
@@ -42,5 +59,21 @@ impl CollectionState {
     // Example Vulnerability in unstake.rs:
     //   self.collection_state.total_staked -= 1; // Can underflow at 0
     //
+    // The secure early_unstake_penalty() helper is also missing;
+    // early_unstake.rs computes the penalty inline with unchecked math.
+    //
     // Fix: Use the checked arithmetic helper methods from the secure version
 }
+
+/// Shape of the early unstake penalty over the remaining lockup
+/// Linear charges in proportion to time left, Quadratic drops off faster
+/// as the lockup nears its end
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Copy, PartialEq, Eq)]
+pub enum PenaltyCurve {
+    Linear,
+    Quadratic,
+}
+
+impl Space for PenaltyCurve {
+    const INIT_SPACE: usize = 1;
+}
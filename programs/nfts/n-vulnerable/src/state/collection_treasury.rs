@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+/// Holds lamports collected from early unstake penalties - VULNERABLE VERSION
+/// One treasury per collection, created on the first early unstake
+#[account]
+#[derive(InitSpace)]
+pub struct CollectionTreasury {
+    /// The Metaplex Core collection this treasury belongs to
+    pub collection: Pubkey,
+
+    /// Total penalty lamports collected
+    pub total_penalties: u64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl CollectionTreasury {
+    // VULNERABILITY NOTE: record_penalty() removed in vulnerable version
+    //
+    // early_unstake.rs adds to total_penalties with unchecked +=
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::NftError};
+
+/// Leading fields of the governance program's UserProfile account
+/// Only the prefix needed for the reputation boost is decoded
+#[derive(AnchorDeserialize)]
+pub struct GovernanceProfile {
+    /// Display name chosen at registration
+    pub username: String,
+
+    /// Wallet the profile belongs to
+    pub owner: Pubkey,
+
+    /// Governance reputation; the rank tiers are derived from it
+    pub reputation_points: i64,
+}
+
+impl GovernanceProfile {
+    /// Decode a UserProfile from raw account data
+    /// Checks the discriminator only, not who created the account
+    pub fn load(info: &AccountInfo) -> Result<Self> {
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() >= ANCHOR_DISCRIMINATOR && data[..ANCHOR_DISCRIMINATOR] == USER_PROFILE_DISCRIMINATOR,
+            NftError::InvalidVoterProfile
+        );
+        Self::deserialize(&mut &data[ANCHOR_DISCRIMINATOR..])
+            .map_err(|_| NftError::InvalidVoterProfile.into())
+    }
+
+    /// Reward multiplier for this profile's reputation tier, 1x below Bronze
+    pub fn multiplier_bps(&self) -> u16 {
+        REPUTATION_TIERS
+            .iter()
+            .find(|(min_points, _)| self.reputation_points >= *min_points)
+            .map_or(BASE_MULTIPLIER_BPS, |(_, multiplier_bps)| *multiplier_bps)
+    }
+}
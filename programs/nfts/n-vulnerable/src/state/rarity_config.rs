@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+/// Reward multipliers keyed by an asset's rarity attribute - VULNERABLE VERSION
+/// Optional per collection; until it exists every asset earns 1x
+#[account]
+#[derive(InitSpace)]
+pub struct RarityConfig {
+    /// The Metaplex Core collection these multipliers apply to
+    pub collection: Pubkey,
+
+    /// Rarity value to multiplier mapping
+    #[max_len(MAX_RARITY_TIERS)]
+    pub tiers: Vec<RarityTier>,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// A single rarity value and its reward multiplier
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct RarityTier {
+    /// Value of the asset's "rarity" attribute (e.g. "legendary")
+    #[max_len(MAX_RARITY_VALUE_LENGTH)]
+    pub value: String,
+
+    /// Reward multiplier in basis points (10_000 = 1x)
+    pub multiplier_bps: u16,
+}
+
+impl RarityConfig {
+    /// Multiplier for a rarity value, 1x if unset or not configured
+    pub fn multiplier_for(&self, rarity: Option<&str>) -> u16 {
+        rarity
+            .and_then(|r| self.tiers.iter().find(|t| t.value == r))
+            .map_or(BASE_MULTIPLIER_BPS, |t| t.multiplier_bps)
+    }
+
+    // VULNERABILITY NOTE: validate_tiers() and apply_multiplier() removed
+    //
+    // set_rarity_config.rs stores tiers without range checks and
+    // claim_rewards.rs multiplies with unchecked arithmetic.
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+/// Reward emission settings for a collection's staked NFTs - VULNERABLE VERSION
+/// This PDA is also the mint authority of the reward mint
+#[account]
+#[derive(InitSpace)]
+pub struct RewardConfig {
+    /// The Metaplex Core collection these rewards are paid for
+    pub collection: Pubkey,
+
+    /// SPL mint rewards are minted from
+    pub reward_mint: Pubkey,
+
+    /// Reward base units emitted per staked second, per NFT
+    pub reward_rate_per_sec: u64,
+
+    /// Total reward base units minted so far
+    pub total_rewards_minted: u64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Bump seed for the reward mint PDA
+    pub mint_bump: u8,
+}
+
+impl RewardConfig {
+    // VULNERABILITY NOTE: Checked helpers removed in vulnerable version
+    //
+    // The secure version provides:
+    // - reward_for() - rejects negative durations, uses checked_mul
+    // - record_minted() - uses checked_add on the minted counter
+    //
+    // claim_rewards.rs multiplies and adds directly instead.
+}
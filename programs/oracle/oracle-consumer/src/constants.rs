@@ -0,0 +1,11 @@
+// Seed for market PDA (also the vault authority)
+// Derived with: [MARKET_SEED, feed_pubkey]
+pub const MARKET_SEED: &[u8] = b"market";
+
+// ORACLE INTERFACE
+
+// Anchor discriminator of the oracle's get_price instruction
+// First 8 bytes of sha256("global:get_price")
+pub const GET_PRICE_DISCRIMINATOR: [u8; 8] = [238, 38, 193, 106, 228, 32, 210, 33];
+
+pub const ANCHOR_DISCRIMINATOR: usize = 8;
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+pub use soteria_errors::oracle_consumer::ConsumerError as ConsumerErrorCode;
+
+#[error_code]
+pub enum ConsumerError {
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Feed is not owned by the oracle program")]
+    InvalidFeed,
+
+    #[msg("Base and quote mints must have the same decimals")]
+    DecimalsMismatch,
+
+    #[msg("Oracle returned no price")]
+    MissingReturnData,
+
+    #[msg("Price was returned by a different program")]
+    InvalidReturnData,
+
+    #[msg("Oracle exponent must not be positive")]
+    InvalidExponent,
+
+    #[msg("Output is below the minimum requested")]
+    SlippageExceeded,
+
+    #[msg("Market does not hold enough quote tokens")]
+    InsufficientLiquidity,
+
+    #[msg("Arithmetic overflow")]
+    Overflow,
+}
@@ -0,0 +1,117 @@
+// Oracle Consumer Helper Functions
+//
+// Oracle CPI and token CPI helpers for the oracle consumer program.
+// These functions reduce code duplication across instructions.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::{get_return_data, invoke},
+};
+use anchor_spl::token_interface::{TransferChecked, transfer_checked};
+
+use crate::{constants::*, errors::*, state::*};
+
+// ORACLE HELPERS
+
+// Read a validated price from the oracle via CPI
+// The oracle's get_price checks staleness and confidence and returns the
+// price through return data
+//
+// Why check the returning program id?
+// Return data is a single slot shared by the whole transaction. If the CPI
+// returned nothing, get_return_data would hand back whatever an earlier
+// instruction left there.
+pub fn fetch_oracle_price<'info>(
+    oracle_program: &AccountInfo<'info>,
+    feed: &AccountInfo<'info>,
+) -> Result<OraclePrice> {
+    let ix = Instruction {
+        program_id: oracle_program.key(),
+        accounts: vec![AccountMeta::new_readonly(feed.key(), false)],
+        data: GET_PRICE_DISCRIMINATOR.to_vec(),
+    };
+
+    invoke(&ix, &[feed.clone(), oracle_program.clone()])?;
+
+    let (program_id, data) = get_return_data().ok_or(ConsumerError::MissingReturnData)?;
+    require_keys_eq!(program_id, oracle_program.key(), ConsumerError::InvalidReturnData);
+
+    OraclePrice::try_from_slice(&data).map_err(|_| error!(ConsumerError::InvalidReturnData))
+}
+
+// Convert a base amount to quote at the oracle price
+// Formula: quote = base * price / 10^(-exponent), rounded down
+pub fn quote_for_base(base_amount: u64, oracle_price: &OraclePrice) -> Result<u64> {
+    require!(oracle_price.exponent <= 0, ConsumerError::InvalidExponent);
+
+    let scale = 10u128
+        .checked_pow(oracle_price.exponent.unsigned_abs())
+        .ok_or(ConsumerError::Overflow)?;
+
+    let quote = (base_amount as u128)
+        .checked_mul(oracle_price.price as u128)
+        .ok_or(ConsumerError::Overflow)?
+        .checked_div(scale)
+        .ok_or(ConsumerError::Overflow)?;
+
+    u64::try_from(quote).map_err(|_| error!(ConsumerError::Overflow))
+}
+
+// CPI HELPERS
+
+// Generic token transfer helper
+// Used for moving base tokens from the seller into the market
+pub fn transfer_tokens<'info>(
+    amount: u64,
+    decimals: u8,
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+) -> Result<()> {
+    transfer_checked(
+        CpiContext::new(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+        ),
+        amount,
+        decimals,
+    )
+}
+
+// Transfer tokens from vault (requires PDA signer)
+// Used for paying sellers out of the quote vault
+pub fn transfer_from_vault<'info>(
+    amount: u64,
+    decimals: u8,
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let signer_seeds = &[authority_seeds];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )
+}
@@ -0,0 +1,101 @@
+// Initialize Market Instruction
+//
+// Creates a market that buys base tokens at the price of one oracle feed.
+//
+// HOW IT WORKS:
+// 1. Pins the oracle program and feed the market trusts
+// 2. Creates the market PDA and its base/quote vault ATAs
+//
+// SECURITY:
+// - oracle_program must be an executable account
+// - feed must be owned by oracle_program
+// - Base and quote mints share decimals, so the oracle price applies to
+//   base units directly
+// - One market per feed (PDA seeds)
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct InitializeMarket<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Any executable program; stored and pinned for every sale
+    #[account(executable)]
+    pub oracle_program: UncheckedAccount<'info>,
+
+    /// CHECK: Deserialized by the oracle during get_price; only its owner is checked here
+    #[account(owner = oracle_program.key() @ ConsumerError::InvalidFeed)]
+    pub feed: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR + Market::INIT_SPACE,
+        seeds = [MARKET_SEED, feed.key().as_ref()],
+        bump
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(mint::token_program = token_program)]
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mint::token_program = token_program)]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = admin,
+        associated_token::mint = base_mint,
+        associated_token::authority = market,
+        associated_token::token_program = token_program,
+    )]
+    pub base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = admin,
+        associated_token::mint = quote_mint,
+        associated_token::authority = market,
+        associated_token::token_program = token_program,
+    )]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeMarket<'info> {
+    pub fn initialize_market(&mut self, bumps: &InitializeMarketBumps) -> Result<()> {
+        require!(
+            self.base_mint.decimals == self.quote_mint.decimals,
+            ConsumerError::DecimalsMismatch
+        );
+
+        self.market.set_inner(Market {
+            admin: self.admin.key(),
+            oracle_program: self.oracle_program.key(),
+            feed: self.feed.key(),
+            base_mint: self.base_mint.key(),
+            quote_mint: self.quote_mint.key(),
+            base_vault: self.base_vault.key(),
+            quote_vault: self.quote_vault.key(),
+            bump: bumps.market,
+        });
+
+        msg!(
+            "Market initialized: oracle {}, feed {}",
+            self.oracle_program.key(),
+            self.feed.key()
+        );
+
+        Ok(())
+    }
+}
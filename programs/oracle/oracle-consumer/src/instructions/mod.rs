@@ -0,0 +1,9 @@
+// Instructions Module
+//
+// Exports all instruction handlers for the oracle consumer program
+
+pub mod initialize_market;
+pub mod sell;
+
+pub use initialize_market::*;
+pub use sell::*;
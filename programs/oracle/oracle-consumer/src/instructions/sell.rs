@@ -0,0 +1,131 @@
+// Sell Instruction
+//
+// Sells base tokens to the market at the oracle price.
+//
+// HOW IT WORKS:
+// 1. CPIs into the oracle's get_price for a validated price
+// 2. quote_out = base_amount * price / 10^(-exponent)
+// 3. Transfers base from the seller into the market
+// 4. Pays quote_out from the quote vault, signed by the market PDA
+//
+// SECURITY:
+// - Oracle program and feed must be the ones pinned at initialization
+// - Price freshness and confidence are enforced by the oracle itself
+// - Return data must come from the pinned oracle program
+// - Seller sets min_quote_out as slippage protection
+//
+// The market trusts the oracle completely: if the oracle accepts a fake or
+// stale price, the market pays it.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct Sell<'info> {
+    pub seller: Signer<'info>,
+
+    #[account(
+        seeds = [MARKET_SEED, feed.key().as_ref()],
+        bump = market.bump,
+        has_one = oracle_program,
+        has_one = feed,
+        has_one = base_mint,
+        has_one = quote_mint,
+        has_one = base_vault,
+        has_one = quote_vault,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    /// CHECK: Pinned by has_one on market
+    pub oracle_program: UncheckedAccount<'info>,
+
+    /// CHECK: Pinned by has_one on market, deserialized by the oracle
+    pub feed: UncheckedAccount<'info>,
+
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::authority = seller,
+        token::token_program = token_program,
+    )]
+    pub seller_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::authority = seller,
+        token::token_program = token_program,
+    )]
+    pub seller_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> Sell<'info> {
+    pub fn sell(&mut self, base_amount: u64, min_quote_out: u64) -> Result<()> {
+        require!(base_amount > 0, ConsumerError::ZeroAmount);
+
+        let oracle_price = fetch_oracle_price(
+            &self.oracle_program.to_account_info(),
+            &self.feed.to_account_info(),
+        )?;
+
+        let quote_out = quote_for_base(base_amount, &oracle_price)?;
+        require!(quote_out > 0, ConsumerError::ZeroAmount);
+        require!(quote_out >= min_quote_out, ConsumerError::SlippageExceeded);
+
+        require!(
+            self.quote_vault.amount >= quote_out,
+            ConsumerError::InsufficientLiquidity
+        );
+
+        transfer_tokens(
+            base_amount,
+            self.base_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.seller_base_account.to_account_info(),
+            &self.base_mint.to_account_info(),
+            &self.base_vault.to_account_info(),
+            &self.seller.to_account_info(),
+        )?;
+
+        let feed_key = self.feed.key();
+        let market_seeds = &[
+            MARKET_SEED,
+            feed_key.as_ref(),
+            &[self.market.bump],
+        ];
+
+        transfer_from_vault(
+            quote_out,
+            self.quote_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.quote_vault.to_account_info(),
+            &self.quote_mint.to_account_info(),
+            &self.seller_quote_account.to_account_info(),
+            &self.market.to_account_info(),
+            market_seeds,
+        )?;
+
+        msg!(
+            "Sold {} base for {} quote at price {}e{}",
+            base_amount,
+            quote_out,
+            oracle_price.price,
+            oracle_price.exponent
+        );
+
+        Ok(())
+    }
+}
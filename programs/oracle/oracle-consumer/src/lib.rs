@@ -0,0 +1,44 @@
+// Oracle Consumer Program - EXAMPLE
+//
+// Example program that reads prices from the oracle via CPI.
+// This program allows users to:
+// 1. Create a market pinned to one oracle program and price feed
+// 2. Sell base tokens to the market at the oracle price
+//
+// It contains no intentional vulnerabilities. Pointed at oracle-secure it is
+// safe; pointed at oracle-vulnerable it pays out whatever price the oracle
+// lets through, which is how the oracle exploit tests drain it.
+//
+// SECURITY FEATURES:
+// - Oracle program and feed pinned at market creation
+// - Price read through the oracle's validating get_price, not raw account data
+// - Return data checked to come from the pinned oracle
+// - Slippage protection on every sale
+// - Checked arithmetic to prevent overflow/underflow
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("6Uv9ziDBNH1exi1nERZLBHGp4t2Vh1wMoaXbuRcHwydw");
+
+#[program]
+pub mod oracle_consumer {
+    use super::*;
+
+    // Create a market priced by an oracle feed
+    pub fn initialize_market(ctx: Context<InitializeMarket>) -> Result<()> {
+        ctx.accounts.initialize_market(&ctx.bumps)
+    }
+
+    // Sell base tokens at the oracle price
+    pub fn sell(ctx: Context<Sell>, base_amount: u64, min_quote_out: u64) -> Result<()> {
+        ctx.accounts.sell(base_amount, min_quote_out)
+    }
+}
@@ -0,0 +1,33 @@
+// Market State
+//
+// Buys base tokens from anyone at the oracle price, paying out of a quote
+// vault the admin keeps funded. Stands in for any program that moves value
+// based on an oracle: a lending market valuing collateral, a perp DEX
+// marking positions, or an AMM rebalancing against a reference price.
+
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Market {
+    // Account that created the market
+    pub admin: Pubkey,
+
+    // Oracle program trusted for prices
+    pub oracle_program: Pubkey,
+
+    // Price feed (owned by oracle_program) quoting base in quote
+    pub feed: Pubkey,
+
+    // Token the market buys
+    pub base_mint: Pubkey,
+
+    // Token the market pays with
+    pub quote_mint: Pubkey,
+
+    // Vault ATAs (owned by this PDA)
+    pub base_vault: Pubkey,
+    pub quote_vault: Pubkey,
+
+    pub bump: u8,
+}
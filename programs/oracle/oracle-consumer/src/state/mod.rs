@@ -0,0 +1,9 @@
+// State Module
+//
+// Exports all account structures for the oracle consumer program
+
+pub mod market;
+pub mod oracle_price;
+
+pub use market::*;
+pub use oracle_price::*;
@@ -0,0 +1,17 @@
+// Oracle Price
+//
+// Mirror of the OraclePrice struct the oracle returns from get_price.
+// Declared here instead of importing the oracle crate so the consumer can be
+// pointed at any oracle program implementing the same interface.
+//
+// value = price * 10^exponent
+
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct OraclePrice {
+    pub price: u64,
+    pub confidence: u64,
+    pub exponent: i32,
+    pub publish_ts: i64,
+}
@@ -0,0 +1,133 @@
+// Integration tests for the example oracle consumer using LiteSVM
+// These tests verify the consumer's own checks: market configuration, the
+// slippage and liquidity limits on sell, and that a price the oracle refuses
+// never reaches a sale
+//
+// HOW TO RUN THESE TESTS:
+//   (cd ../oracle-secure && cargo build-sbf)
+//   cargo build-sbf && cargo test-sbf -- --nocapture
+
+mod utils;
+
+use litesvm_token::CreateMint;
+use oracle_consumer::errors::ConsumerErrorCode;
+use solana_sdk::signature::Signer;
+use soteria_test_kit::{errors::oracle_secure::OracleError as OracleErrorCode, *};
+use utils::*;
+
+#[test]
+fn test_initialize_market_validation() {
+    // Test: A market needs a feed owned by the oracle and mints with equal decimals
+    println!("\n[TEST START] test_initialize_market_validation - Market configuration");
+
+    let mut scenario = setup_consumer_scenario();
+    let admin = &scenario.authority;
+
+    println!("[Action] Creating a market whose feed the oracle does not own");
+    let ix = build_initialize_market_ix(
+        &admin.pubkey(),
+        &ORACLE_PROGRAM_ID,
+        &scenario.base_mint,
+        &scenario.base_mint,
+        &scenario.quote_mint,
+    );
+    assert_tx_err_code!(send_ix(&mut scenario.svm, ix, admin), ConsumerErrorCode::InvalidFeed);
+    println!("[Success] Feed owned by the token program rejected");
+
+    println!("[Action] Creating a market with a 9-decimal quote mint");
+    let (second_feed, _) = derive_feed_pda(&admin.pubkey(), FEED_ID + 1);
+    let ix = build_initialize_feed_ix(&admin.pubkey(), FEED_ID + 1, &scenario.publisher.pubkey());
+    send_ix(&mut scenario.svm, ix, admin).expect("Second feed should be created");
+
+    let wide_quote_mint = CreateMint::new(&mut scenario.svm, &scenario.mint_authority)
+        .decimals(DECIMALS + 3)
+        .send()
+        .expect("Failed to create quote mint");
+    let ix = build_initialize_market_ix(
+        &admin.pubkey(),
+        &ORACLE_PROGRAM_ID,
+        &second_feed,
+        &scenario.base_mint,
+        &wide_quote_mint,
+    );
+    assert_tx_err_code!(send_ix(&mut scenario.svm, ix, admin), ConsumerErrorCode::DecimalsMismatch);
+    println!("[Success] Mismatched decimals rejected");
+
+    println!("[TEST END] test_initialize_market_validation");
+}
+
+#[test]
+fn test_sell_limits() {
+    // Test: Sales pay the oracle price and stop at the seller's minimum and the
+    // market's liquidity
+    println!("\n[TEST START] test_sell_limits - Slippage and liquidity");
+
+    let mut scenario = setup_consumer_scenario();
+    scenario.push_price(PRICE, CONFIDENCE).expect("Push should succeed");
+    let seller = scenario.create_seller(200 * ONE_TOKEN);
+    println!("[Setup] Feed price 100 quote per base, market holds 10,000 quote");
+
+    assert_tx_err_code!(scenario.sell(&seller, 0, 0), ConsumerErrorCode::ZeroAmount);
+    println!("[Success] Zero sale rejected");
+
+    println!("[Action] Selling 10 base with a minimum of 1,001 quote");
+    assert_tx_err_code!(
+        scenario.sell(&seller, 10 * ONE_TOKEN, 1_001 * ONE_TOKEN),
+        ConsumerErrorCode::SlippageExceeded
+    );
+    println!("[Success] Sale below min_quote_out rejected");
+
+    println!("[Action] Selling 101 base, worth more than the quote vault holds");
+    assert_tx_err_code!(
+        scenario.sell(&seller, 101 * ONE_TOKEN, 0),
+        ConsumerErrorCode::InsufficientLiquidity
+    );
+    println!("[Success] Sale beyond market liquidity rejected");
+
+    println!("[Action] Selling 10 base with a minimum of 1,000 quote");
+    scenario
+        .sell(&seller, 10 * ONE_TOKEN, 1_000 * ONE_TOKEN)
+        .expect("Sale at the oracle price should succeed");
+    assert_eq!(scenario.base_balance(&seller.pubkey()), 190 * ONE_TOKEN);
+    assert_eq!(scenario.quote_balance(&seller.pubkey()), 1_000 * ONE_TOKEN);
+    assert_eq!(
+        token_balance(&scenario.svm, &scenario.quote_vault),
+        MARKET_LIQUIDITY - 1_000 * ONE_TOKEN
+    );
+    println!("[Success] Seller paid 1,000 quote for 10 base");
+
+    println!("[TEST END] test_sell_limits");
+}
+
+#[test]
+fn test_stale_price_rejected() {
+    // Test: Once the feed's price is older than max_staleness_seconds the
+    // oracle's get_price fails, so the consumer's sell fails with it and
+    // nothing moves
+    println!("\n[TEST START] test_stale_price_rejected - Stale oracle price");
+
+    let mut scenario = setup_consumer_scenario();
+    let seller = scenario.create_seller(10 * ONE_TOKEN);
+    scenario.push_price(PRICE, CONFIDENCE).expect("Push should succeed");
+
+    println!("[Action] Waiting past max_staleness_seconds, then selling");
+    advance_time(&mut scenario.svm, MAX_STALENESS_SECONDS as u64 + 1);
+    assert_tx_err_code!(
+        scenario.sell(&seller, 10 * ONE_TOKEN, 0),
+        OracleErrorCode::StalePrice
+    );
+    assert_eq!(scenario.base_balance(&seller.pubkey()), 10 * ONE_TOKEN);
+    assert_eq!(scenario.quote_balance(&seller.pubkey()), 0);
+    assert_eq!(token_balance(&scenario.svm, &scenario.quote_vault), MARKET_LIQUIDITY);
+    println!("[Success] StalePrice from the oracle aborts the sale");
+
+    println!("[Action] Publisher pushes a fresh price");
+    scenario.push_price(PRICE, CONFIDENCE).expect("Push should succeed");
+    scenario
+        .sell(&seller, 10 * ONE_TOKEN, 0)
+        .expect("Sale at a fresh price should succeed");
+    assert_eq!(scenario.quote_balance(&seller.pubkey()), 1_000 * ONE_TOKEN);
+    println!("[Success] Sale succeeds once the price is fresh");
+
+    println!("[TEST END] test_stale_price_rejected");
+}
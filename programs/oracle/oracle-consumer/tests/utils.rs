@@ -0,0 +1,259 @@
+// Test utilities for the example oracle consumer
+//
+// The consumer is tested against oracle-secure, the oracle it is meant to be
+// pointed at. Both .so files are loaded into one LiteSVM.
+
+use litesvm::{LiteSVM, types::TransactionResult};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo, get_spl_account};
+use litesvm_token::spl_token::state::Account as TokenAccount;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use solana_system_interface::program::ID as system_program;
+use soteria_test_kit::*;
+use spl_associated_token_account::get_associated_token_address;
+
+// declare_id! of oracle-secure
+pub const ORACLE_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("3hARN2soFtGhiAK4N4VhnKWhuFE29B7aL3uiT9eYeVHe");
+pub const CONSUMER_PROGRAM_ID: Pubkey = Pubkey::new_from_array(oracle_consumer::ID.to_bytes());
+
+// Standard program IDs
+pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_account::ID;
+
+// PDA Seeds
+pub const FEED_SEED: &[u8] = b"feed";
+pub const MARKET_SEED: &[u8] = b"market";
+
+// Feed configuration: one publisher, stale after 60 seconds, 1% confidence
+pub const FEED_ID: u64 = 1;
+pub const EXPONENT: i32 = -6;
+pub const MAX_STALENESS_SECONDS: i64 = 60;
+pub const MAX_CONFIDENCE_BPS: u16 = 100;
+
+// Both mints use 6 decimals
+pub const DECIMALS: u8 = 6;
+pub const ONE_TOKEN: u64 = 1_000_000;
+
+// 100 quote per base at exponent -6, with a 0.1% confidence interval
+pub const PRICE: u64 = 100_000_000;
+pub const CONFIDENCE: u64 = 100_000;
+
+// Quote tokens the admin deposits for the market to pay sellers
+pub const MARKET_LIQUIDITY: u64 = 10_000 * ONE_TOKEN;
+
+// LiteSVM starts the clock at 0, which get_price treats as "never published"
+pub const START_TIMESTAMP: i64 = 1_700_000_000;
+
+// Oracle built first: (cd ../oracle-secure && cargo build-sbf)
+pub fn setup_svm() -> LiteSVM {
+    ProgramHarness::builder()
+        .program_file(ORACLE_PROGRAM_ID, "../oracle-secure/target/deploy/oracle_secure.so")
+        .program_file(CONSUMER_PROGRAM_ID, "target/deploy/oracle_consumer.so")
+        .unix_timestamp(START_TIMESTAMP)
+        .build()
+        .into_svm()
+}
+
+// Derive feed PDA
+pub fn derive_feed_pda(authority: &Pubkey, seed: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[FEED_SEED, authority.as_ref(), &seed.to_le_bytes()],
+        &ORACLE_PROGRAM_ID,
+    )
+}
+
+// Derive consumer market PDA
+pub fn derive_market_pda(feed: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MARKET_SEED, feed.as_ref()], &CONSUMER_PROGRAM_ID)
+}
+
+// Build oracle initialize_feed instruction with a single publisher
+pub fn build_initialize_feed_ix(authority: &Pubkey, seed: u64, publisher: &Pubkey) -> Instruction {
+    let (feed, _) = derive_feed_pda(authority, seed);
+
+    let mut data = anchor_discriminator("initialize_feed").to_vec();
+    data.extend_from_slice(&seed.to_le_bytes());
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(publisher.as_ref());
+    data.extend_from_slice(&EXPONENT.to_le_bytes());
+    data.extend_from_slice(&MAX_STALENESS_SECONDS.to_le_bytes());
+    data.extend_from_slice(&MAX_CONFIDENCE_BPS.to_le_bytes());
+
+    Instruction {
+        program_id: ORACLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(feed, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build consumer initialize_market instruction
+pub fn build_initialize_market_ix(
+    admin: &Pubkey,
+    oracle_program: &Pubkey,
+    feed: &Pubkey,
+    base_mint: &Pubkey,
+    quote_mint: &Pubkey,
+) -> Instruction {
+    let (market, _) = derive_market_pda(feed);
+
+    Instruction {
+        program_id: CONSUMER_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new_readonly(*oracle_program, false),
+            AccountMeta::new_readonly(*feed, false),
+            AccountMeta::new(market, false),
+            AccountMeta::new_readonly(*base_mint, false),
+            AccountMeta::new_readonly(*quote_mint, false),
+            AccountMeta::new(get_associated_token_address(&market, base_mint), false),
+            AccountMeta::new(get_associated_token_address(&market, quote_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data: anchor_discriminator("initialize_market").to_vec(),
+    }
+}
+
+pub fn token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    let account: TokenAccount = get_spl_account(svm, token_account).expect("Token account should exist");
+    account.amount
+}
+
+// Consumer scenario setup result
+pub struct ConsumerScenario {
+    pub svm: LiteSVM,
+    pub authority: Keypair,
+    pub publisher: Keypair,
+    pub feed: Pubkey,
+    pub mint_authority: Keypair,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub market: Pubkey,
+    pub quote_vault: Pubkey,
+}
+
+// Feed with one publisher and a market priced by it
+// The quote vault holds MARKET_LIQUIDITY; no price is pushed yet
+pub fn setup_consumer_scenario() -> ConsumerScenario {
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let publisher = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+
+    let ix = build_initialize_feed_ix(&authority.pubkey(), FEED_ID, &publisher.pubkey());
+    send_ix(&mut svm, ix, &authority).expect("Feed initialization should succeed");
+    let (feed, _) = derive_feed_pda(&authority.pubkey(), FEED_ID);
+
+    let mint_authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let base_mint = CreateMint::new(&mut svm, &mint_authority)
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create base mint");
+    let quote_mint = CreateMint::new(&mut svm, &mint_authority)
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create quote mint");
+
+    let ix = build_initialize_market_ix(
+        &authority.pubkey(),
+        &ORACLE_PROGRAM_ID,
+        &feed,
+        &base_mint,
+        &quote_mint,
+    );
+    send_ix(&mut svm, ix, &authority).expect("Market initialization should succeed");
+    let (market, _) = derive_market_pda(&feed);
+
+    let quote_vault = get_associated_token_address(&market, &quote_mint);
+    MintTo::new(&mut svm, &mint_authority, &quote_mint, &quote_vault, MARKET_LIQUIDITY)
+        .send()
+        .expect("Failed to fund quote vault");
+
+    ConsumerScenario {
+        svm,
+        authority,
+        publisher,
+        feed,
+        mint_authority,
+        base_mint,
+        quote_mint,
+        market,
+        quote_vault,
+    }
+}
+
+impl ConsumerScenario {
+    pub fn push_price(&mut self, price: u64, confidence: u64) -> TransactionResult {
+        let mut data = anchor_discriminator("push_price").to_vec();
+        data.extend_from_slice(&price.to_le_bytes());
+        data.extend_from_slice(&confidence.to_le_bytes());
+
+        let ix = Instruction {
+            program_id: ORACLE_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(self.publisher.pubkey(), true),
+                AccountMeta::new(self.feed, false),
+            ],
+            data,
+        };
+        send_ix(&mut self.svm, ix, &self.publisher)
+    }
+
+    // Wallet holding `base_amount` base tokens and an empty quote account
+    pub fn create_seller(&mut self, base_amount: u64) -> Keypair {
+        let seller = create_funded_account(&mut self.svm, LAMPORTS_PER_SOL);
+        let base_ata = CreateAssociatedTokenAccount::new(&mut self.svm, &seller, &self.base_mint)
+            .send()
+            .expect("Failed to create base account");
+        CreateAssociatedTokenAccount::new(&mut self.svm, &seller, &self.quote_mint)
+            .send()
+            .expect("Failed to create quote account");
+        MintTo::new(&mut self.svm, &self.mint_authority, &self.base_mint, &base_ata, base_amount)
+            .send()
+            .expect("Failed to mint base tokens");
+        seller
+    }
+
+    pub fn sell(&mut self, seller: &Keypair, base_amount: u64, min_quote_out: u64) -> TransactionResult {
+        let mut data = anchor_discriminator("sell").to_vec();
+        data.extend_from_slice(&base_amount.to_le_bytes());
+        data.extend_from_slice(&min_quote_out.to_le_bytes());
+
+        let owner = seller.pubkey();
+        let ix = Instruction {
+            program_id: CONSUMER_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(owner, true),
+                AccountMeta::new_readonly(self.market, false),
+                AccountMeta::new_readonly(ORACLE_PROGRAM_ID, false),
+                AccountMeta::new_readonly(self.feed, false),
+                AccountMeta::new_readonly(self.base_mint, false),
+                AccountMeta::new_readonly(self.quote_mint, false),
+                AccountMeta::new(get_associated_token_address(&owner, &self.base_mint), false),
+                AccountMeta::new(get_associated_token_address(&owner, &self.quote_mint), false),
+                AccountMeta::new(get_associated_token_address(&self.market, &self.base_mint), false),
+                AccountMeta::new(self.quote_vault, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        };
+        send_ix(&mut self.svm, ix, seller)
+    }
+
+    pub fn base_balance(&self, owner: &Pubkey) -> u64 {
+        token_balance(&self.svm, &get_associated_token_address(owner, &self.base_mint))
+    }
+
+    pub fn quote_balance(&self, owner: &Pubkey) -> u64 {
+        token_balance(&self.svm, &get_associated_token_address(owner, &self.quote_mint))
+    }
+}
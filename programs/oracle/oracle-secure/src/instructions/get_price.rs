@@ -0,0 +1,67 @@
+// Get Price Instruction
+//
+// Read-only entry point for consumer programs. Validates the feed's latest
+// price and returns it through return data.
+//
+// HOW IT WORKS:
+// 1. Consumer CPIs into get_price with the feed account
+// 2. The oracle checks the price exists, is fresh, and is confident enough
+// 3. The price comes back via set_return_data; the consumer reads it with
+//    get_return_data and checks the returning program id
+//
+// SECURITY:
+// - Feed must be a PriceFeed owned by this program (Anchor Account)
+// - now - publish_ts <= max_staleness_seconds
+// - confidence / price <= max_confidence_bps
+//
+// Why validate here instead of in every consumer?
+// A consumer that forgets the staleness check will happily act on a price
+// from hours ago. Doing it in the oracle means no consumer can skip it.
+
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct GetPrice<'info> {
+    #[account(
+        seeds = [FEED_SEED, feed.authority.as_ref(), feed.seed.to_le_bytes().as_ref()],
+        bump = feed.bump,
+    )]
+    pub feed: Box<Account<'info, PriceFeed>>,
+}
+
+impl<'info> GetPrice<'info> {
+    pub fn get_price(&self) -> Result<OraclePrice> {
+        require!(self.feed.publish_ts > 0, OracleError::PriceUnavailable);
+
+        let now = Clock::get()?.unix_timestamp;
+        let age = now
+            .checked_sub(self.feed.publish_ts)
+            .ok_or(OracleError::Overflow)?;
+        require!(
+            cfg!(feature = "vuln-missing-staleness-check")
+                || age <= self.feed.max_staleness_seconds,
+            OracleError::StalePrice
+        );
+
+        // confidence * BPS_DENOMINATOR <= price * max_confidence_bps
+        let scaled_confidence = (self.feed.confidence as u128)
+            .checked_mul(BPS_DENOMINATOR as u128)
+            .ok_or(OracleError::Overflow)?;
+        let max_confidence = (self.feed.price as u128)
+            .checked_mul(self.feed.max_confidence_bps as u128)
+            .ok_or(OracleError::Overflow)?;
+        require!(
+            scaled_confidence <= max_confidence,
+            OracleError::ConfidenceTooWide
+        );
+
+        Ok(OraclePrice {
+            price: self.feed.price,
+            confidence: self.feed.confidence,
+            exponent: self.feed.exponent,
+            publish_ts: self.feed.publish_ts,
+        })
+    }
+}
@@ -0,0 +1,51 @@
+// Push Price Instruction
+//
+// A publisher writes the latest price and confidence to a feed.
+//
+// HOW IT WORKS:
+// 1. Checks the signer is one of the feed's publishers
+// 2. Stores price, confidence, the current time, and the publisher
+//
+// SECURITY:
+// - Publisher must sign and be on the feed's publisher list
+// - Price must be non-zero
+// - publish_ts comes from the Clock, not from the publisher, so a publisher
+//   cannot make an old price look fresh
+
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct PushPrice<'info> {
+    pub publisher: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [FEED_SEED, feed.authority.as_ref(), feed.seed.to_le_bytes().as_ref()],
+        bump = feed.bump,
+    )]
+    pub feed: Box<Account<'info, PriceFeed>>,
+}
+
+impl<'info> PushPrice<'info> {
+    pub fn push_price(&mut self, price: u64, confidence: u64) -> Result<()> {
+        require!(
+            self.feed.is_publisher(&self.publisher.key()),
+            OracleError::UnauthorizedPublisher
+        );
+
+        require!(price > 0, OracleError::InvalidPrice);
+
+        let now = Clock::get()?.unix_timestamp;
+
+        self.feed.price = price;
+        self.feed.confidence = confidence;
+        self.feed.publish_ts = now;
+        self.feed.last_publisher = self.publisher.key();
+
+        msg!("Price pushed: {} +/- {} at {}", price, confidence, now);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+// Price Oracle Program - SECURE VERSION
+//
+// Push-based price oracle read by other programs via CPI.
+// This program allows users to:
+// 1. Create a price feed with a fixed set of publishers
+// 2. Let publishers push the latest price and confidence interval
+// 3. Let consumer programs read a validated price through get_price
+//
+// SECURITY FEATURES:
+// - Only signing publishers on the feed's list can push prices
+// - Publish time taken from the Clock
+// - get_price enforces the feed's staleness and confidence limits
+// - Feed configuration validated at creation
+// - Checked arithmetic to prevent overflow/underflow
+//
+// CONSUMERS:
+// See programs/oracle/oracle-consumer for an example program that CPIs into
+// get_price and prices token sales with the result.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+use instructions::*;
+use state::OraclePrice;
+
+declare_id!("3hARN2soFtGhiAK4N4VhnKWhuFE29B7aL3uiT9eYeVHe");
+
+#[program]
+pub mod oracle_secure {
+    use super::*;
+
+    // Create a feed with its publishers and limits
+    pub fn initialize_feed(
+        ctx: Context<InitializeFeed>,
+        seed: u64,
+        publishers: Vec<Pubkey>,
+        exponent: i32,
+        max_staleness_seconds: i64,
+        max_confidence_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts.initialize_feed(
+            seed,
+            publishers,
+            exponent,
+            max_staleness_seconds,
+            max_confidence_bps,
+            &ctx.bumps,
+        )
+    }
+
+    // Publisher-only: write the latest price
+    pub fn push_price(ctx: Context<PushPrice>, price: u64, confidence: u64) -> Result<()> {
+        ctx.accounts.push_price(price, confidence)
+    }
+
+    // Return the latest price if it is fresh and confident enough
+    pub fn get_price(ctx: Context<GetPrice>) -> Result<OraclePrice> {
+        ctx.accounts.get_price()
+    }
+}
@@ -0,0 +1,7 @@
+// State Module
+//
+// Exports all account structures for the oracle program
+
+pub mod price_feed;
+
+pub use price_feed::*;
@@ -0,0 +1,66 @@
+// Price Feed State
+//
+// One price (e.g. SOL/USDC) written by a fixed set of publishers and read by
+// other programs through the get_price instruction.
+//
+// PRICE FORMAT:
+// value      = price * 10^exponent
+// confidence = +/- confidence * 10^exponent around the price
+//
+// The feed carries its own freshness and confidence limits so every consumer
+// gets the same checks without having to re-implement them.
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct PriceFeed {
+    // Account that created the feed and chose its publishers
+    pub authority: Pubkey,
+
+    // Authority-chosen seed so one authority can run several feeds
+    pub seed: u64,
+
+    // Accounts allowed to push prices
+    #[max_len(MAX_PUBLISHERS)]
+    pub publishers: Vec<Pubkey>,
+
+    // Decimal exponent shared by price and confidence
+    pub exponent: i32,
+
+    // get_price rejects prices older than this
+    pub max_staleness_seconds: i64,
+
+    // get_price rejects prices with confidence / price above this
+    pub max_confidence_bps: u16,
+
+    // Latest price (0 until the first push)
+    pub price: u64,
+
+    // Latest confidence interval
+    pub confidence: u64,
+
+    // Unix timestamp of the latest push
+    pub publish_ts: i64,
+
+    // Publisher of the latest price
+    pub last_publisher: Pubkey,
+
+    pub bump: u8,
+}
+
+impl PriceFeed {
+    pub fn is_publisher(&self, key: &Pubkey) -> bool {
+        self.publishers.contains(key)
+    }
+}
+
+// Price returned to consumers by get_price (via return data)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct OraclePrice {
+    pub price: u64,
+    pub confidence: u64,
+    pub exponent: i32,
+    pub publish_ts: i64,
+}
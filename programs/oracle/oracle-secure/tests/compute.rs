@@ -0,0 +1,56 @@
+// Compute-unit regression tests for oracle-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// The consumer's sell is measured too: it pays for the get_price CPI, which is
+// where the staleness and confidence checks run for real integrations.
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+mod utils;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    signature::Signer,
+};
+use soteria_test_kit::{ComputeBudget, DEFAULT_CU_BASELINES_FILE};
+use utils::*;
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let mut scenario = setup_oracle_scenario();
+
+    // The scenario feed is created in setup; measure a second one with the same shape
+    let authority = create_funded_account(&mut scenario.svm, 10 * LAMPORTS_PER_SOL);
+    let publisher_keys: Vec<_> = scenario.publishers.iter().map(|p| p.pubkey()).collect();
+    let ix = build_initialize_feed_ix(
+        &authority.pubkey(),
+        FEED_ID,
+        &publisher_keys,
+        EXPONENT,
+        MAX_STALENESS_SECONDS,
+        MAX_CONFIDENCE_BPS,
+    );
+    budget.record("initialize_feed", send_ix(&mut scenario.svm, ix, &authority));
+
+    let publisher = &scenario.publishers[0];
+    let ix = build_push_price_ix(&publisher.pubkey(), &scenario.feed, PRICE, CONFIDENCE);
+    budget.record("push_price", send_ix(&mut scenario.svm, ix, publisher));
+
+    let ix = Instruction {
+        program_id: ORACLE_PROGRAM_ID,
+        accounts: vec![AccountMeta::new_readonly(scenario.feed, false)],
+        data: anchor_discriminator("get_price").to_vec(),
+    };
+    budget.record("get_price", send_ix(&mut scenario.svm, ix, &authority));
+
+    let seller = create_seller(&mut scenario, 10 * ONE_TOKEN);
+    budget.record("consumer_sell", sell(&mut scenario, &seller, 10 * ONE_TOKEN, 0));
+
+    budget.check();
+}
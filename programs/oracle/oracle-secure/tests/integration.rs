@@ -0,0 +1,220 @@
+// Integration tests for price oracle program using LiteSVM
+// These tests verify core oracle functionality: feed creation, publisher-only
+// price pushes, and the staleness/confidence checks consumers rely on when
+// reading prices via CPI
+
+mod utils;
+
+use utils::*;
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::Signer};
+
+#[test]
+fn test_initialize_feed() {
+    // Test: Feed is created with no price, and bad configurations are rejected
+    println!("\n[TEST START] test_initialize_feed - Feed creation");
+
+    let mut scenario = setup_oracle_scenario();
+    println!("[Setup] Feed created with {} publishers", scenario.publishers.len());
+
+    let feed_price = get_feed_price(&scenario.svm, &scenario.feed);
+    assert_eq!(feed_price.price, 0);
+    assert_eq!(feed_price.publish_ts, 0);
+    println!("[Success] Feed starts with no price");
+
+    let seller = create_seller(&mut scenario, 10 * ONE_TOKEN);
+    assert!(sell(&mut scenario, &seller, 10 * ONE_TOKEN, 0).is_err(), "Sale before first price should fail");
+    println!("[Success] get_price refuses a feed that was never published");
+
+    let authority = create_funded_account(&mut scenario.svm, LAMPORTS_PER_SOL);
+    let publisher = Pubkey::new_unique();
+
+    let bad_configs: [(&str, Vec<Pubkey>, i32, i64, u16); 5] = [
+        ("no publishers", vec![], EXPONENT, MAX_STALENESS_SECONDS, MAX_CONFIDENCE_BPS),
+        ("duplicate publisher", vec![publisher, publisher], EXPONENT, MAX_STALENESS_SECONDS, MAX_CONFIDENCE_BPS),
+        ("zero staleness", vec![publisher], EXPONENT, 0, MAX_CONFIDENCE_BPS),
+        ("zero confidence limit", vec![publisher], EXPONENT, MAX_STALENESS_SECONDS, 0),
+        ("positive exponent", vec![publisher], 1, MAX_STALENESS_SECONDS, MAX_CONFIDENCE_BPS),
+    ];
+
+    for (name, publishers, exponent, staleness, confidence_bps) in bad_configs {
+        println!("[Action] Creating feed with {}", name);
+        let ix = build_initialize_feed_ix(&authority.pubkey(), FEED_ID, &publishers, exponent, staleness, confidence_bps);
+        assert!(send_ix(&mut scenario.svm, ix, &authority).is_err(), "Feed with {} should fail", name);
+        println!("[Success] Feed with {} rejected", name);
+    }
+
+    println!("[TEST END] test_initialize_feed");
+}
+
+#[test]
+fn test_push_price() {
+    // Test: Only signing publishers can push, and the push is timestamped
+    println!("\n[TEST START] test_push_price - Publisher-only price updates");
+
+    let mut scenario = setup_oracle_scenario();
+    let feed = scenario.feed;
+
+    println!("[Action] Outsider tries to push a price");
+    let outsider = create_funded_account(&mut scenario.svm, LAMPORTS_PER_SOL);
+    let ix = build_push_price_ix(&outsider.pubkey(), &feed, PRICE, CONFIDENCE);
+    assert!(send_ix(&mut scenario.svm, ix, &outsider).is_err(), "Outsider push should fail");
+    println!("[Success] Push by non-publisher rejected");
+
+    assert!(push_price(&mut scenario, 0, 0).is_err(), "Zero price should fail");
+    println!("[Success] Zero price rejected");
+
+    println!("[Action] Publisher 1 pushes {} +/- {}", PRICE, CONFIDENCE);
+    let result = push_price(&mut scenario, PRICE, CONFIDENCE);
+    assert!(result.is_ok(), "Push failed: {:?}", result.err());
+
+    let feed_price = get_feed_price(&scenario.svm, &feed);
+    assert_eq!(feed_price.price, PRICE);
+    assert_eq!(feed_price.confidence, CONFIDENCE);
+    assert_eq!(feed_price.publish_ts, current_timestamp(&scenario.svm));
+    assert_eq!(feed_price.last_publisher, scenario.publishers[0].pubkey());
+    println!("[Success] Price, confidence, and publish time recorded");
+
+    println!("[Action] Publisher 2 pushes a newer price");
+    advance_time(&mut scenario.svm, 10);
+    let second = &scenario.publishers[1];
+    let ix = build_push_price_ix(&second.pubkey(), &feed, PRICE + ONE_TOKEN, CONFIDENCE);
+    let result = send_ix(&mut scenario.svm, ix, second);
+    assert!(result.is_ok(), "Second publisher push failed: {:?}", result.err());
+
+    let feed_price = get_feed_price(&scenario.svm, &feed);
+    assert_eq!(feed_price.price, PRICE + ONE_TOKEN);
+    assert_eq!(feed_price.last_publisher, scenario.publishers[1].pubkey());
+    println!("[Success] Any listed publisher can update the feed");
+
+    println!("[TEST END] test_push_price");
+}
+
+#[test]
+fn test_consumer_sells_at_oracle_price() {
+    // Test: Consumer reads the price via CPI and pays base * price
+    println!("\n[TEST START] test_consumer_sells_at_oracle_price - CPI price read");
+
+    let mut scenario = setup_oracle_scenario();
+    push_price(&mut scenario, PRICE, CONFIDENCE).expect("Push should succeed");
+    println!("[Setup] Feed price 100 quote per base");
+
+    let seller = create_seller(&mut scenario, 10 * ONE_TOKEN);
+
+    println!("[Action] Selling 10 base with min_quote_out above the oracle price");
+    let result = sell(&mut scenario, &seller, 10 * ONE_TOKEN, 1_001 * ONE_TOKEN);
+    assert!(result.is_err(), "Sale below min_quote_out should fail");
+    println!("[Success] Slippage protection enforced");
+
+    println!("[Action] Selling 10 base for at least 1,000 quote");
+    let result = sell(&mut scenario, &seller, 10 * ONE_TOKEN, 1_000 * ONE_TOKEN);
+    assert!(result.is_ok(), "Sale failed: {:?}", result.err());
+
+    assert_eq!(quote_balance(&scenario, &seller.pubkey()), 1_000 * ONE_TOKEN);
+    assert_eq!(token_balance(&scenario.svm, &scenario.quote_vault), MARKET_LIQUIDITY - 1_000 * ONE_TOKEN);
+    println!("[Success] Seller received 1,000 quote");
+
+    println!("[TEST END] test_consumer_sells_at_oracle_price");
+}
+
+#[test]
+fn test_stale_and_uncertain_prices_rejected() {
+    // Test: get_price refuses old prices and prices with a wide confidence
+    // interval, so the consumer cannot trade on them
+    println!("\n[TEST START] test_stale_and_uncertain_prices_rejected - get_price validation");
+
+    let mut scenario = setup_oracle_scenario();
+    let seller = create_seller(&mut scenario, 10 * ONE_TOKEN);
+
+    push_price(&mut scenario, PRICE, CONFIDENCE).expect("Push should succeed");
+    println!("[Setup] Feed price 100 quote per base");
+
+    println!("[Action] Waiting past max_staleness_seconds, then selling");
+    advance_time(&mut scenario.svm, MAX_STALENESS_SECONDS + 1);
+    assert!(sell(&mut scenario, &seller, ONE_TOKEN, 0).is_err(), "Sale at stale price should fail");
+    println!("[Success] Stale price rejected");
+
+    println!("[Action] Publisher pushes a price with a 2% confidence interval");
+    push_price(&mut scenario, PRICE, PRICE / 50).expect("Push should succeed");
+    assert!(sell(&mut scenario, &seller, ONE_TOKEN, 0).is_err(), "Sale at uncertain price should fail");
+    println!("[Success] Confidence wider than max_confidence_bps rejected");
+
+    println!("[Action] Publisher pushes a fresh, tight price");
+    push_price(&mut scenario, PRICE, CONFIDENCE).expect("Push should succeed");
+    let result = sell(&mut scenario, &seller, ONE_TOKEN, 0);
+    assert!(result.is_ok(), "Sale failed: {:?}", result.err());
+    assert_eq!(quote_balance(&scenario, &seller.pubkey()), 100 * ONE_TOKEN);
+    println!("[Success] Sale succeeds once the price is fresh and confident");
+
+    println!("[TEST END] test_stale_and_uncertain_prices_rejected");
+}
+
+#[test]
+fn test_consumer_pins_oracle_and_feed() {
+    // Test: The consumer only accepts the feed and oracle it was created with
+    println!("\n[TEST START] test_consumer_pins_oracle_and_feed - Consumer account validation");
+
+    let mut scenario = setup_oracle_scenario();
+    push_price(&mut scenario, PRICE, CONFIDENCE).expect("Push should succeed");
+
+    println!("[Setup] Attacker creates their own feed and pushes 1000x the price");
+    let attacker = create_funded_account(&mut scenario.svm, 10 * LAMPORTS_PER_SOL);
+    let ix = build_initialize_feed_ix(
+        &attacker.pubkey(),
+        FEED_ID,
+        &[attacker.pubkey()],
+        EXPONENT,
+        MAX_STALENESS_SECONDS,
+        MAX_CONFIDENCE_BPS,
+    );
+    send_ix(&mut scenario.svm, ix, &attacker).expect("Attacker feed should be created");
+    let (attacker_feed, _) = derive_feed_pda(&attacker.pubkey(), FEED_ID);
+    let ix = build_push_price_ix(&attacker.pubkey(), &attacker_feed, PRICE * 1_000, CONFIDENCE * 1_000);
+    send_ix(&mut scenario.svm, ix, &attacker).expect("Attacker push should succeed");
+
+    let seller = create_seller(&mut scenario, ONE_TOKEN);
+
+    println!("[Action] Selling to the market with the attacker's feed");
+    let ix = build_sell_ix(
+        &seller.pubkey(),
+        &scenario.market,
+        &ORACLE_PROGRAM_ID,
+        &attacker_feed,
+        &scenario.base_mint,
+        &scenario.quote_mint,
+        ONE_TOKEN,
+        0,
+    );
+    assert!(send_ix(&mut scenario.svm, ix, &seller).is_err(), "Foreign feed should fail");
+    println!("[Success] Feed not pinned by the market rejected");
+
+    println!("[Action] Selling with a different program in place of the oracle");
+    let ix = build_sell_ix(
+        &seller.pubkey(),
+        &scenario.market,
+        &TOKEN_PROGRAM_ID,
+        &scenario.feed,
+        &scenario.base_mint,
+        &scenario.quote_mint,
+        ONE_TOKEN,
+        0,
+    );
+    assert!(send_ix(&mut scenario.svm, ix, &seller).is_err(), "Foreign oracle program should fail");
+    println!("[Success] Oracle program not pinned by the market rejected");
+
+    println!("[Action] Creating a market whose feed is not owned by the named oracle");
+    let ix = build_initialize_market_ix(
+        &attacker.pubkey(),
+        &CONSUMER_PROGRAM_ID,
+        &attacker_feed,
+        &scenario.base_mint,
+        &scenario.quote_mint,
+    );
+    assert!(send_ix(&mut scenario.svm, ix, &attacker).is_err(), "Mismatched oracle should fail");
+    println!("[Success] Feed owner must match the oracle program");
+
+    assert_eq!(quote_balance(&scenario, &seller.pubkey()), 0);
+    assert_eq!(token_balance(&scenario.svm, &scenario.quote_vault), MARKET_LIQUIDITY);
+    println!("[Success] Market paid nothing");
+
+    println!("[TEST END] test_consumer_pins_oracle_and_feed");
+}
@@ -0,0 +1,383 @@
+// Test utilities for price oracle program and its example consumer
+
+use litesvm::{LiteSVM, types::TransactionResult};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo, get_spl_account};
+use litesvm_token::spl_token::state::Account as TokenAccount;
+use solana_sdk::{
+    clock::Clock,
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::program::ID as system_program;
+
+// Program IDs matching declare_id!
+pub const ORACLE_PROGRAM_ID: Pubkey = Pubkey::new_from_array(oracle_secure::ID.to_bytes());
+pub const CONSUMER_PROGRAM_ID: Pubkey = Pubkey::new_from_array(oracle_consumer::ID.to_bytes());
+
+// Build Anchor instruction discriminator
+// Formula: first 8 bytes of sha256("global:method_name")
+pub fn anchor_discriminator(method: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", method);
+    let hash_result = hash(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash_result.to_bytes()[..8]);
+    discriminator
+}
+
+// Standard program IDs
+pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_account::ID;
+
+// PDA Seeds
+pub const FEED_SEED: &[u8] = b"feed";
+pub const MARKET_SEED: &[u8] = b"market";
+
+// Feed configuration used by the scenario
+pub const FEED_ID: u64 = 1;
+pub const EXPONENT: i32 = -6;
+pub const MAX_STALENESS_SECONDS: i64 = 60;
+pub const MAX_CONFIDENCE_BPS: u16 = 100;
+
+// Both mints use 6 decimals
+pub const DECIMALS: u8 = 6;
+pub const ONE_TOKEN: u64 = 1_000_000;
+
+// 100 quote per base at exponent -6, with a 0.1% confidence interval
+pub const PRICE: u64 = 100_000_000;
+pub const CONFIDENCE: u64 = 100_000;
+
+// Quote tokens the admin deposits for the market to pay sellers
+pub const MARKET_LIQUIDITY: u64 = 10_000 * ONE_TOKEN;
+
+// LiteSVM starts the clock at 0, which get_price treats as "never published"
+pub const START_TIMESTAMP: i64 = 1_700_000_000;
+
+// Setup LiteSVM with the oracle and consumer programs
+pub fn setup_svm() -> LiteSVM {
+    let mut svm = LiteSVM::new();
+    let oracle_bytes = include_bytes!("../target/deploy/oracle_secure.so");
+    let _ = svm.add_program(ORACLE_PROGRAM_ID, oracle_bytes);
+    let consumer_bytes = include_bytes!("../../oracle-consumer/target/deploy/oracle_consumer.so");
+    let _ = svm.add_program(CONSUMER_PROGRAM_ID, consumer_bytes);
+
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = START_TIMESTAMP;
+    svm.set_sysvar::<Clock>(&clock);
+    svm
+}
+
+// Create and fund account
+pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
+    let keypair = Keypair::new();
+    svm.airdrop(&keypair.pubkey(), lamports)
+        .expect("Airdrop should succeed");
+    keypair
+}
+
+// Sign and send a single instruction
+pub fn send_ix(svm: &mut LiteSVM, ix: Instruction, signer: &Keypair) -> TransactionResult {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    svm.expire_blockhash();
+    result
+}
+
+pub fn advance_time(svm: &mut LiteSVM, seconds: i64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += seconds;
+    svm.set_sysvar::<Clock>(&clock);
+}
+
+pub fn current_timestamp(svm: &LiteSVM) -> i64 {
+    svm.get_sysvar::<Clock>().unix_timestamp
+}
+
+// Derive feed PDA
+pub fn derive_feed_pda(authority: &Pubkey, seed: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[FEED_SEED, authority.as_ref(), &seed.to_le_bytes()],
+        &ORACLE_PROGRAM_ID,
+    )
+}
+
+// Derive consumer market PDA
+pub fn derive_market_pda(feed: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MARKET_SEED, feed.as_ref()], &CONSUMER_PROGRAM_ID)
+}
+
+// Build initialize_feed instruction
+pub fn build_initialize_feed_ix(
+    authority: &Pubkey,
+    seed: u64,
+    publishers: &[Pubkey],
+    exponent: i32,
+    max_staleness_seconds: i64,
+    max_confidence_bps: u16,
+) -> Instruction {
+    let (feed, _) = derive_feed_pda(authority, seed);
+
+    let mut data = anchor_discriminator("initialize_feed").to_vec();
+    data.extend_from_slice(&seed.to_le_bytes());
+    data.extend_from_slice(&(publishers.len() as u32).to_le_bytes());
+    for publisher in publishers {
+        data.extend_from_slice(publisher.as_ref());
+    }
+    data.extend_from_slice(&exponent.to_le_bytes());
+    data.extend_from_slice(&max_staleness_seconds.to_le_bytes());
+    data.extend_from_slice(&max_confidence_bps.to_le_bytes());
+
+    Instruction {
+        program_id: ORACLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(feed, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build push_price instruction
+pub fn build_push_price_ix(publisher: &Pubkey, feed: &Pubkey, price: u64, confidence: u64) -> Instruction {
+    let mut data = anchor_discriminator("push_price").to_vec();
+    data.extend_from_slice(&price.to_le_bytes());
+    data.extend_from_slice(&confidence.to_le_bytes());
+
+    Instruction {
+        program_id: ORACLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*publisher, true),
+            AccountMeta::new(*feed, false),
+        ],
+        data,
+    }
+}
+
+// Build consumer initialize_market instruction
+pub fn build_initialize_market_ix(
+    admin: &Pubkey,
+    oracle_program: &Pubkey,
+    feed: &Pubkey,
+    base_mint: &Pubkey,
+    quote_mint: &Pubkey,
+) -> Instruction {
+    let (market, _) = derive_market_pda(feed);
+
+    Instruction {
+        program_id: CONSUMER_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new_readonly(*oracle_program, false),
+            AccountMeta::new_readonly(*feed, false),
+            AccountMeta::new(market, false),
+            AccountMeta::new_readonly(*base_mint, false),
+            AccountMeta::new_readonly(*quote_mint, false),
+            AccountMeta::new(get_associated_token_address(&market, base_mint), false),
+            AccountMeta::new(get_associated_token_address(&market, quote_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data: anchor_discriminator("initialize_market").to_vec(),
+    }
+}
+
+// Build consumer sell instruction
+// The market is passed separately from the feed so tests can pair a market
+// with a feed or oracle program it was not created for
+#[allow(clippy::too_many_arguments)]
+pub fn build_sell_ix(
+    seller: &Pubkey,
+    market: &Pubkey,
+    oracle_program: &Pubkey,
+    feed: &Pubkey,
+    base_mint: &Pubkey,
+    quote_mint: &Pubkey,
+    base_amount: u64,
+    min_quote_out: u64,
+) -> Instruction {
+    let mut data = anchor_discriminator("sell").to_vec();
+    data.extend_from_slice(&base_amount.to_le_bytes());
+    data.extend_from_slice(&min_quote_out.to_le_bytes());
+
+    Instruction {
+        program_id: CONSUMER_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*seller, true),
+            AccountMeta::new_readonly(*market, false),
+            AccountMeta::new_readonly(*oracle_program, false),
+            AccountMeta::new_readonly(*feed, false),
+            AccountMeta::new_readonly(*base_mint, false),
+            AccountMeta::new_readonly(*quote_mint, false),
+            AccountMeta::new(get_associated_token_address(seller, base_mint), false),
+            AccountMeta::new(get_associated_token_address(seller, quote_mint), false),
+            AccountMeta::new(get_associated_token_address(market, base_mint), false),
+            AccountMeta::new(get_associated_token_address(market, quote_mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Latest price fields read from a PriceFeed
+pub struct FeedPrice {
+    pub price: u64,
+    pub confidence: u64,
+    pub publish_ts: i64,
+    pub last_publisher: Pubkey,
+}
+
+// Read the latest price from a PriceFeed
+// Layout: discriminator (8) + authority (32) + seed (8) + publishers (4 + 32 * n)
+//         + exponent (4) + max_staleness_seconds (8) + max_confidence_bps (2)
+//         + price (8) + confidence (8) + publish_ts (8) + last_publisher (32)
+pub fn get_feed_price(svm: &LiteSVM, feed: &Pubkey) -> FeedPrice {
+    let account = svm.get_account(feed).expect("Feed should exist");
+    let data = &account.data;
+
+    let publisher_count = u32::from_le_bytes(data[48..52].try_into().unwrap()) as usize;
+    let offset = 52 + 32 * publisher_count + 4 + 8 + 2;
+
+    FeedPrice {
+        price: u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap()),
+        confidence: u64::from_le_bytes(data[offset + 8..offset + 16].try_into().unwrap()),
+        publish_ts: i64::from_le_bytes(data[offset + 16..offset + 24].try_into().unwrap()),
+        last_publisher: Pubkey::new_from_array(data[offset + 24..offset + 56].try_into().unwrap()),
+    }
+}
+
+pub fn token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    let account: TokenAccount = get_spl_account(svm, token_account).expect("Token account should exist");
+    account.amount
+}
+
+// Oracle scenario setup result
+pub struct OracleScenario {
+    pub svm: LiteSVM,
+    pub publishers: Vec<Keypair>,
+    pub feed: Pubkey,
+    pub mint_authority: Keypair,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub market: Pubkey,
+    pub quote_vault: Pubkey,
+}
+
+// Create a feed with two publishers and a consumer market priced by it
+// The quote vault is funded with MARKET_LIQUIDITY; no price is pushed yet
+pub fn setup_oracle_scenario() -> OracleScenario {
+    let mut svm = setup_svm();
+    let authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let publishers: Vec<Keypair> = (0..2)
+        .map(|_| create_funded_account(&mut svm, LAMPORTS_PER_SOL))
+        .collect();
+    let publisher_keys: Vec<Pubkey> = publishers.iter().map(|p| p.pubkey()).collect();
+
+    let ix = build_initialize_feed_ix(
+        &authority.pubkey(),
+        FEED_ID,
+        &publisher_keys,
+        EXPONENT,
+        MAX_STALENESS_SECONDS,
+        MAX_CONFIDENCE_BPS,
+    );
+    send_ix(&mut svm, ix, &authority).expect("Feed initialization should succeed");
+    let (feed, _) = derive_feed_pda(&authority.pubkey(), FEED_ID);
+
+    let mint_authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let base_mint = CreateMint::new(&mut svm, &mint_authority)
+        .authority(&mint_authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create base mint");
+    let quote_mint = CreateMint::new(&mut svm, &mint_authority)
+        .authority(&mint_authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create quote mint");
+
+    let ix = build_initialize_market_ix(
+        &authority.pubkey(),
+        &ORACLE_PROGRAM_ID,
+        &feed,
+        &base_mint,
+        &quote_mint,
+    );
+    send_ix(&mut svm, ix, &authority).expect("Market initialization should succeed");
+    let (market, _) = derive_market_pda(&feed);
+
+    let quote_vault = get_associated_token_address(&market, &quote_mint);
+    MintTo::new(&mut svm, &mint_authority, &quote_mint, &quote_vault, MARKET_LIQUIDITY)
+        .owner(&mint_authority)
+        .send()
+        .expect("Failed to fund quote vault");
+
+    OracleScenario {
+        svm,
+        publishers,
+        feed,
+        mint_authority,
+        base_mint,
+        quote_mint,
+        market,
+        quote_vault,
+    }
+}
+
+// Push a price from the first publisher
+pub fn push_price(scenario: &mut OracleScenario, price: u64, confidence: u64) -> TransactionResult {
+    let ix = build_push_price_ix(&scenario.publishers[0].pubkey(), &scenario.feed, price, confidence);
+    send_ix(&mut scenario.svm, ix, &scenario.publishers[0])
+}
+
+// Create a wallet holding `base_amount` base tokens and an empty quote account
+pub fn create_seller(scenario: &mut OracleScenario, base_amount: u64) -> Keypair {
+    let seller = create_funded_account(&mut scenario.svm, LAMPORTS_PER_SOL);
+
+    let base_ata = CreateAssociatedTokenAccount::new(&mut scenario.svm, &seller, &scenario.base_mint)
+        .owner(&seller.pubkey())
+        .send()
+        .expect("Failed to create base account");
+    CreateAssociatedTokenAccount::new(&mut scenario.svm, &seller, &scenario.quote_mint)
+        .owner(&seller.pubkey())
+        .send()
+        .expect("Failed to create quote account");
+
+    if base_amount > 0 {
+        MintTo::new(&mut scenario.svm, &scenario.mint_authority, &scenario.base_mint, &base_ata, base_amount)
+            .owner(&scenario.mint_authority)
+            .send()
+            .expect("Failed to mint base tokens");
+    }
+
+    seller
+}
+
+// Sell base tokens to the scenario market through the scenario feed
+pub fn sell(scenario: &mut OracleScenario, seller: &Keypair, base_amount: u64, min_quote_out: u64) -> TransactionResult {
+    let ix = build_sell_ix(
+        &seller.pubkey(),
+        &scenario.market,
+        &ORACLE_PROGRAM_ID,
+        &scenario.feed,
+        &scenario.base_mint,
+        &scenario.quote_mint,
+        base_amount,
+        min_quote_out,
+    );
+    send_ix(&mut scenario.svm, ix, seller)
+}
+
+pub fn quote_balance(scenario: &OracleScenario, owner: &Pubkey) -> u64 {
+    token_balance(&scenario.svm, &get_associated_token_address(owner, &scenario.quote_mint))
+}
@@ -0,0 +1,20 @@
+// Seed for price feed PDA
+// Derived with: [FEED_SEED, authority_pubkey, seed.to_le_bytes()]
+pub const FEED_SEED: &[u8] = b"feed";
+
+// FEED LIMITS
+
+// Maximum publishers per feed
+pub const MAX_PUBLISHERS: usize = 5;
+
+// Maximum staleness a feed can be configured with (1 hour)
+// Consumers should never act on prices older than this
+pub const MAX_STALENESS_SECONDS: i64 = 60 * 60;
+
+// Exponent range: price is price * 10^exponent
+pub const MIN_EXPONENT: i32 = -12;
+pub const MAX_EXPONENT: i32 = 0;
+
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+pub const ANCHOR_DISCRIMINATOR: usize = 8;
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+pub use soteria_errors::oracle_vulnerable::OracleError as OracleErrorCode;
+
+#[error_code]
+pub enum OracleError {
+    #[msg("Feed must have between 1 and 5 publishers")]
+    InvalidPublisherCount,
+
+    #[msg("Publisher list contains a duplicate or default key")]
+    InvalidPublisher,
+
+    #[msg("Staleness limit must be between 1 second and 1 hour")]
+    InvalidStaleness,
+
+    #[msg("Confidence limit must be between 1 and 10000 basis points")]
+    InvalidConfidenceLimit,
+
+    #[msg("Exponent must be between -12 and 0")]
+    InvalidExponent,
+
+    #[msg("Signer is not a publisher for this feed")]
+    UnauthorizedPublisher,
+
+    #[msg("Price must be greater than zero")]
+    InvalidPrice,
+
+    #[msg("Feed has no price yet")]
+    PriceUnavailable,
+
+    #[msg("Price confidence interval is too wide")]
+    ConfidenceTooWide,
+
+    #[msg("Arithmetic overflow")]
+    Overflow,
+}
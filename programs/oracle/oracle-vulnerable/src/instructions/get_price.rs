@@ -0,0 +1,59 @@
+// Get Price Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// Read-only entry point for consumer programs. Returns the feed's latest
+// price through return data.
+//
+// VULNERABILITY: V002: Missing staleness check - a price published hours or
+// days ago is returned as if it were current
+
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct GetPrice<'info> {
+    #[account(
+        seeds = [FEED_SEED, feed.authority.as_ref(), feed.seed.to_le_bytes().as_ref()],
+        bump = feed.bump,
+    )]
+    pub feed: Box<Account<'info, PriceFeed>>,
+}
+
+impl<'info> GetPrice<'info> {
+    pub fn get_price(&self) -> Result<OraclePrice> {
+        require!(self.feed.publish_ts > 0, OracleError::PriceUnavailable);
+
+        // VULNERABILITY V002: publish_ts is returned but never checked
+        // Secure version:
+        //   let age = now - self.feed.publish_ts;
+        //   require!(age <= self.feed.max_staleness_seconds, OracleError::StalePrice);
+        // Attack scenario (stale price arbitrage):
+        // 1. Publisher pushes 100 quote per base, then stops (outage, halted
+        //    chain, congested network)
+        // 2. The real market price falls to 10
+        // 3. Attacker buys base elsewhere at 10 and sells it to a consumer
+        //    market at the stale 100
+        // 4. The market pays 10x value on every sale until the feed is updated
+
+        // confidence * BPS_DENOMINATOR <= price * max_confidence_bps
+        let scaled_confidence = (self.feed.confidence as u128)
+            .checked_mul(BPS_DENOMINATOR as u128)
+            .ok_or(OracleError::Overflow)?;
+        let max_confidence = (self.feed.price as u128)
+            .checked_mul(self.feed.max_confidence_bps as u128)
+            .ok_or(OracleError::Overflow)?;
+        require!(
+            scaled_confidence <= max_confidence,
+            OracleError::ConfidenceTooWide
+        );
+
+        Ok(OraclePrice {
+            price: self.feed.price,
+            confidence: self.feed.confidence,
+            exponent: self.feed.exponent,
+            publish_ts: self.feed.publish_ts,
+        })
+    }
+}
@@ -0,0 +1,99 @@
+// Initialize Feed Instruction
+//
+// Creates a price feed with its publishers and freshness/confidence limits.
+//
+// HOW IT WORKS:
+// 1. Validates the publisher set and limits
+// 2. Creates the feed PDA with no price yet
+//
+// SECURITY:
+// - 1 to MAX_PUBLISHERS publishers, no duplicates, no default key
+// - 1 <= max_staleness_seconds <= MAX_STALENESS_SECONDS
+// - 1 <= max_confidence_bps <= BPS_DENOMINATOR
+// - MIN_EXPONENT <= exponent <= MAX_EXPONENT
+
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct InitializeFeed<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + PriceFeed::INIT_SPACE,
+        seeds = [FEED_SEED, authority.key().as_ref(), seed.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub feed: Box<Account<'info, PriceFeed>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeFeed<'info> {
+    pub fn initialize_feed(
+        &mut self,
+        seed: u64,
+        publishers: Vec<Pubkey>,
+        exponent: i32,
+        max_staleness_seconds: i64,
+        max_confidence_bps: u16,
+        bumps: &InitializeFeedBumps,
+    ) -> Result<()> {
+        require!(
+            !publishers.is_empty() && publishers.len() <= MAX_PUBLISHERS,
+            OracleError::InvalidPublisherCount
+        );
+
+        for (i, publisher) in publishers.iter().enumerate() {
+            require!(
+                *publisher != Pubkey::default() && !publishers[..i].contains(publisher),
+                OracleError::InvalidPublisher
+            );
+        }
+
+        require!(
+            (1..=MAX_STALENESS_SECONDS).contains(&max_staleness_seconds),
+            OracleError::InvalidStaleness
+        );
+
+        require!(
+            max_confidence_bps > 0 && max_confidence_bps as u64 <= BPS_DENOMINATOR,
+            OracleError::InvalidConfidenceLimit
+        );
+
+        require!(
+            (MIN_EXPONENT..=MAX_EXPONENT).contains(&exponent),
+            OracleError::InvalidExponent
+        );
+
+        let publisher_count = publishers.len();
+
+        self.feed.set_inner(PriceFeed {
+            authority: self.authority.key(),
+            seed,
+            publishers,
+            exponent,
+            max_staleness_seconds,
+            max_confidence_bps,
+            price: 0,
+            confidence: 0,
+            publish_ts: 0,
+            last_publisher: Pubkey::default(),
+            bump: bumps.feed,
+        });
+
+        msg!(
+            "Feed initialized: {} publishers, staleness {}s, confidence {} bps",
+            publisher_count,
+            max_staleness_seconds,
+            max_confidence_bps
+        );
+
+        Ok(())
+    }
+}
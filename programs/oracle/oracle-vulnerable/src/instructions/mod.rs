@@ -0,0 +1,11 @@
+// Instructions Module
+//
+// Exports all instruction handlers for the oracle program
+
+pub mod initialize_feed;
+pub mod push_price;
+pub mod get_price;
+
+pub use initialize_feed::*;
+pub use push_price::*;
+pub use get_price::*;
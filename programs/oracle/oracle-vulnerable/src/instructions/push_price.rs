@@ -0,0 +1,60 @@
+// Push Price Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// A publisher writes the latest price and confidence to a feed.
+//
+// VULNERABILITY: V001: Missing publisher signature check - the publisher is
+// only looked up in the feed's list, so anyone can push a price in a
+// publisher's name
+
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct PushPrice<'info> {
+    // VULNERABILITY V001: Publisher is not required to sign
+    // Secure version:
+    //   pub publisher: Signer<'info>,
+    /// CHECK: Only compared against the feed's publisher list
+    pub publisher: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [FEED_SEED, feed.authority.as_ref(), feed.seed.to_le_bytes().as_ref()],
+        bump = feed.bump,
+    )]
+    pub feed: Box<Account<'info, PriceFeed>>,
+}
+
+impl<'info> PushPrice<'info> {
+    pub fn push_price(&mut self, price: u64, confidence: u64) -> Result<()> {
+        // VULNERABILITY V001: Membership is checked, authority is not
+        // Publisher keys are public (they are stored in the feed), so this
+        // check stops nobody.
+        // Attack scenario (forged price):
+        // 1. Attacker reads a publisher key from the feed account
+        // 2. Attacker pushes 1000x the real price, naming that publisher and
+        //    paying the fee from their own wallet
+        // 3. Attacker sells 10 base tokens to a consumer market priced by the feed
+        // 4. The market pays 1000x value and its quote vault is drained
+        require!(
+            self.feed.is_publisher(&self.publisher.key()),
+            OracleError::UnauthorizedPublisher
+        );
+
+        require!(price > 0, OracleError::InvalidPrice);
+
+        let now = Clock::get()?.unix_timestamp;
+
+        self.feed.price = price;
+        self.feed.confidence = confidence;
+        self.feed.publish_ts = now;
+        self.feed.last_publisher = self.publisher.key();
+
+        msg!("Price pushed: {} +/- {} at {}", price, confidence, now);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+// Price Oracle Program - VULNERABLE VERSION
+//
+// WARNING: This program contains intentional security vulnerabilities for educational purposes.
+// NEVER deploy this to production. Use oracle-secure instead.
+//
+// This vulnerable oracle demonstrates two classic price feed flaws:
+// - Anyone can push a price in a publisher's name
+// - Consumers are handed prices no matter how old they are
+//
+// Both flaws pass straight through to programs that trust the oracle. The
+// exploit tests drain the example consumer in programs/oracle/oracle-consumer.
+//
+// VULNERABILITIES DOCUMENTED:
+// See individual instruction files for detailed vulnerability explanations.
+// Each vulnerability is marked with VULNERABILITY comments.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+use instructions::*;
+use state::OraclePrice;
+
+declare_id!("AFZZKb52f7sMcwmhu3AYtE5X3foSJ8iCVNxqh1TGx97D");
+
+#[program]
+pub mod oracle_vulnerable {
+    use super::*;
+
+    // Create a feed with its publishers and limits
+    pub fn initialize_feed(
+        ctx: Context<InitializeFeed>,
+        seed: u64,
+        publishers: Vec<Pubkey>,
+        exponent: i32,
+        max_staleness_seconds: i64,
+        max_confidence_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts.initialize_feed(
+            seed,
+            publishers,
+            exponent,
+            max_staleness_seconds,
+            max_confidence_bps,
+            &ctx.bumps,
+        )
+    }
+
+    // Write the latest price
+    // VULNERABILITY: Publisher is not required to sign
+    pub fn push_price(ctx: Context<PushPrice>, price: u64, confidence: u64) -> Result<()> {
+        ctx.accounts.push_price(price, confidence)
+    }
+
+    // Return the latest price if it is confident enough
+    // VULNERABILITY: Price age is never checked
+    pub fn get_price(ctx: Context<GetPrice>) -> Result<OraclePrice> {
+        ctx.accounts.get_price()
+    }
+}
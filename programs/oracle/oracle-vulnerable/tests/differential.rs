@@ -0,0 +1,324 @@
+// Differential tests: oracle-secure vs oracle-vulnerable
+//
+// Each exploit script runs against both builds in one LiteSVM and must be
+// rejected by the secure build and accepted by the vulnerable one. The
+// consumer is loaded once; each market records which oracle build it reads.
+// The test_feature_* tests replay them against oracle-secure rebuilt with the
+// matching vuln-* feature, which must be the only build to accept them.
+//
+// HOW TO RUN THESE TESTS:
+// All three .so files and the secure feature builds must be built first:
+//   ./build-vuln-matrix.sh oracle-secure
+//   (cd programs/oracle/oracle-consumer && cargo build-sbf)
+//   cd programs/oracle/oracle-vulnerable
+//   cargo test-sbf --test differential
+
+use litesvm::{types::TransactionResult, LiteSVM};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use solana_system_interface::program::ID as system_program;
+use soteria_test_kit::*;
+use spl_associated_token_account::get_associated_token_address;
+
+// declare_id! of oracle-secure
+const SECURE_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("3hARN2soFtGhiAK4N4VhnKWhuFE29B7aL3uiT9eYeVHe");
+const VULNERABLE_PROGRAM_ID: Pubkey = Pubkey::new_from_array(oracle_vulnerable::ID.to_bytes());
+const CONSUMER_PROGRAM_ID: Pubkey = Pubkey::new_from_array(oracle_consumer::ID.to_bytes());
+
+const PAIR: ProgramPair = ProgramPair {
+    secure: SECURE_PROGRAM_ID,
+    vulnerable: VULNERABLE_PROGRAM_ID,
+};
+
+// 100 quote per base at exponent -6, stale after 60 seconds
+const EXPONENT: i32 = -6;
+const MAX_STALENESS_SECONDS: i64 = 60;
+const MAX_CONFIDENCE_BPS: u16 = 100;
+const PRICE: u64 = 100_000_000;
+const CONFIDENCE: u64 = 100_000;
+const ONE_TOKEN: u64 = 1_000_000;
+const MARKET_LIQUIDITY: u64 = 10_000 * ONE_TOKEN;
+
+// get_price treats a zero timestamp as "never published"
+const START_TIMESTAMP: i64 = 1_700_000_000;
+
+fn setup_pair() -> LiteSVM {
+    ProgramHarness::builder()
+        .program_file(SECURE_PROGRAM_ID, "../oracle-secure/target/deploy/oracle_secure.so")
+        .program_file(VULNERABLE_PROGRAM_ID, "target/deploy/oracle_vulnerable.so")
+        .program_file(
+            CONSUMER_PROGRAM_ID,
+            "../oracle-consumer/target/deploy/oracle_consumer.so",
+        )
+        .unix_timestamp(START_TIMESTAMP)
+        .build()
+        .into_svm()
+}
+
+// Baseline oracle-secure and its build with one vuln-* feature, each in its own
+// SVM next to the consumer
+fn setup_feature(feature: &'static str) -> (FeatureVariant, LiteSVM, LiteSVM) {
+    let variant = FeatureVariant { program_id: SECURE_PROGRAM_ID, feature };
+    let load = |path: std::path::PathBuf| {
+        ProgramHarness::builder()
+            .program_file(SECURE_PROGRAM_ID, path)
+            .program_file(
+                CONSUMER_PROGRAM_ID,
+                "../oracle-consumer/target/deploy/oracle_consumer.so",
+            )
+            .unix_timestamp(START_TIMESTAMP)
+            .build()
+            .into_svm()
+    };
+    let baseline = load("../oracle-secure/target/deploy/oracle_secure.so".into());
+    let variant_svm = load(variant.so_path("../oracle-secure", "oracle_secure"));
+    (variant, baseline, variant_svm)
+}
+
+// Feed with one publisher on one build, and a consumer market priced by it
+struct Oracle {
+    program_id: Pubkey,
+    publisher: Keypair,
+    feed: Pubkey,
+    mint_authority: Keypair,
+    base_mint: Pubkey,
+    quote_mint: Pubkey,
+    market: Pubkey,
+}
+
+impl Oracle {
+    fn new(svm: &mut LiteSVM, program_id: &Pubkey) -> Self {
+        let authority = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let publisher = create_funded_account(svm, LAMPORTS_PER_SOL);
+
+        let seed = 1u64;
+        let (feed, _) = Pubkey::find_program_address(
+            &[b"feed", authority.pubkey().as_ref(), &seed.to_le_bytes()],
+            program_id,
+        );
+
+        let mut data = anchor_discriminator("initialize_feed").to_vec();
+        data.extend_from_slice(&seed.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(publisher.pubkey().as_ref());
+        data.extend_from_slice(&EXPONENT.to_le_bytes());
+        data.extend_from_slice(&MAX_STALENESS_SECONDS.to_le_bytes());
+        data.extend_from_slice(&MAX_CONFIDENCE_BPS.to_le_bytes());
+
+        let ix = Instruction {
+            program_id: *program_id,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new(feed, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+            data,
+        };
+        send_ix(svm, ix, &authority)
+            .expect("Feed initialization should succeed on both builds");
+
+        let mint_authority = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let base_mint = CreateMint::new(svm, &mint_authority).decimals(6).send().unwrap();
+        let quote_mint = CreateMint::new(svm, &mint_authority).decimals(6).send().unwrap();
+
+        let (market, _) =
+            Pubkey::find_program_address(&[b"market", feed.as_ref()], &CONSUMER_PROGRAM_ID);
+        let quote_vault = get_associated_token_address(&market, &quote_mint);
+
+        let ix = Instruction {
+            program_id: CONSUMER_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(*program_id, false),
+                AccountMeta::new_readonly(feed, false),
+                AccountMeta::new(market, false),
+                AccountMeta::new_readonly(base_mint, false),
+                AccountMeta::new_readonly(quote_mint, false),
+                AccountMeta::new(get_associated_token_address(&market, &base_mint), false),
+                AccountMeta::new(quote_vault, false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+                AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+            data: anchor_discriminator("initialize_market").to_vec(),
+        };
+        send_ix(svm, ix, &authority)
+            .expect("Market initialization should succeed on both builds");
+
+        MintTo::new(svm, &mint_authority, &quote_mint, &quote_vault, MARKET_LIQUIDITY)
+            .send()
+            .unwrap();
+
+        Oracle {
+            program_id: *program_id,
+            publisher,
+            feed,
+            mint_authority,
+            base_mint,
+            quote_mint,
+            market,
+        }
+    }
+
+    // push_price naming the feed's publisher; `publisher_signs` is false for a forged push
+    fn push_price(
+        &self,
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        publisher_signs: bool,
+        price: u64,
+    ) -> TransactionResult {
+        let mut data = anchor_discriminator("push_price").to_vec();
+        data.extend_from_slice(&price.to_le_bytes());
+        data.extend_from_slice(&CONFIDENCE.to_le_bytes());
+
+        let ix = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(self.publisher.pubkey(), publisher_signs),
+                AccountMeta::new(self.feed, false),
+            ],
+            data,
+        };
+        send_ix(svm, ix, payer)
+    }
+
+    // Wallet holding `base_amount` base tokens and an empty quote account
+    fn create_seller(&self, svm: &mut LiteSVM, base_amount: u64) -> Keypair {
+        let seller = create_funded_account(svm, LAMPORTS_PER_SOL);
+        let base_ata = CreateAssociatedTokenAccount::new(svm, &seller, &self.base_mint)
+            .send()
+            .unwrap();
+        CreateAssociatedTokenAccount::new(svm, &seller, &self.quote_mint).send().unwrap();
+        MintTo::new(svm, &self.mint_authority, &self.base_mint, &base_ata, base_amount)
+            .send()
+            .unwrap();
+        seller
+    }
+
+    fn sell(&self, svm: &mut LiteSVM, seller: &Keypair, base_amount: u64) -> TransactionResult {
+        let mut data = anchor_discriminator("sell").to_vec();
+        data.extend_from_slice(&base_amount.to_le_bytes());
+        data.extend_from_slice(&0u64.to_le_bytes());
+
+        let owner = seller.pubkey();
+        let market_vault = |mint| get_associated_token_address(&self.market, mint);
+        let ix = Instruction {
+            program_id: CONSUMER_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(owner, true),
+                AccountMeta::new_readonly(self.market, false),
+                AccountMeta::new_readonly(self.program_id, false),
+                AccountMeta::new_readonly(self.feed, false),
+                AccountMeta::new_readonly(self.base_mint, false),
+                AccountMeta::new_readonly(self.quote_mint, false),
+                AccountMeta::new(get_associated_token_address(&owner, &self.base_mint), false),
+                AccountMeta::new(get_associated_token_address(&owner, &self.quote_mint), false),
+                AccountMeta::new(market_vault(&self.base_mint), false),
+                AccountMeta::new(market_vault(&self.quote_mint), false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+            ],
+            data,
+        };
+        send_ix(svm, ix, seller)
+    }
+}
+
+// Feed and market plus the attacker's wallet
+struct AttackState {
+    oracle: Oracle,
+    attacker: Keypair,
+}
+
+// V001: An outsider pushes 1000x the price in the publisher's name
+struct UnsignedPricePush;
+
+impl DifferentialScenario for UnsignedPricePush {
+    type State = AttackState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Forged Price Push - Missing Publisher Signature",
+            severity: Severity::Critical,
+            lesson: "A publisher list is only as strong as the signature behind it: use Signer",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, program_id: &Pubkey) -> AttackState {
+        let oracle = Oracle::new(svm, program_id);
+        oracle
+            .push_price(svm, &oracle.publisher, true, PRICE)
+            .expect("Signed push should succeed on both builds");
+        let attacker = create_funded_account(svm, LAMPORTS_PER_SOL);
+        AttackState { oracle, attacker }
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        state: &mut AttackState,
+    ) -> TransactionResult {
+        state.oracle.push_price(svm, &state.attacker, false, PRICE * 1_000)
+    }
+}
+
+#[test]
+fn test_differential_unsigned_price_push() {
+    let mut svm = setup_pair();
+    run_differential(&mut svm, &PAIR, &UnsignedPricePush);
+}
+
+// V002: A seller trades against a day-old price
+struct StalePrice;
+
+impl DifferentialScenario for StalePrice {
+    type State = AttackState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Stale Price Arbitrage - Missing Staleness Check",
+            severity: Severity::High,
+            lesson: "Reject prices older than the feed's max_staleness_seconds",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, program_id: &Pubkey) -> AttackState {
+        let oracle = Oracle::new(svm, program_id);
+        oracle
+            .push_price(svm, &oracle.publisher, true, PRICE)
+            .expect("Signed push should succeed on both builds");
+        let attacker = oracle.create_seller(svm, 100 * ONE_TOKEN);
+        AttackState { oracle, attacker }
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        state: &mut AttackState,
+    ) -> TransactionResult {
+        advance_time(svm, 24 * 60 * 60);
+        state.oracle.sell(svm, &state.attacker, 100 * ONE_TOKEN)
+    }
+}
+
+#[test]
+fn test_differential_stale_price() {
+    let mut svm = setup_pair();
+    run_differential(&mut svm, &PAIR, &StalePrice);
+}
+
+#[test]
+fn test_feature_missing_staleness_check() {
+    let (variant, mut baseline, mut variant_svm) = setup_feature("vuln-missing-staleness-check");
+    run_feature_differential(&mut baseline, &mut variant_svm, &variant, &StalePrice);
+}
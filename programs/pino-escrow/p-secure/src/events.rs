@@ -0,0 +1,128 @@
+use pinocchio::Address;
+use pinocchio_log::logger::log_message;
+
+// Structured Event Logs
+//
+// Handlers log one line per event so indexers and tests can follow offers
+// without parsing free-form messages. An event is a one-byte tag followed by
+// its fields, packed little-endian in the order they are passed, and logged
+// as hex after a fixed prefix:
+//
+//   Program log: event:<tag><fields>
+//
+// Hex keeps the line printable, and the whole line is built on the stack,
+// so no allocator is needed.
+//
+// Usage:
+//   log_event!(EventTag::OfferTaken, offer, proposer, taker, amount_a, amount_b, fee);
+
+// Prefix marking an event line among the program's other logs
+pub const EVENT_PREFIX: &str = "event:";
+
+// Largest tag plus fields an event can carry
+// OfferProposed is the largest at 153 bytes
+pub const MAX_EVENT_LEN: usize = 160;
+
+const MAX_EVENT_LOG_LEN: usize = EVENT_PREFIX.len() + 2 * MAX_EVENT_LEN;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+// Event tags, the first byte of every event
+//
+// OfferProposed: offer, maker, token_mint_a, token_mint_b,
+//                token_a_offered_amount (u64), token_b_wanted_amount (u64), expires_at (i64)
+// OfferTaken:    offer, proposer, taker,
+//                token_a_amount (u64), token_b_amount (u64), fee_amount (u64)
+// DisputeOpened: offer, disputant, dispute_deadline (i64)
+// DisputeResolved: offer, arbiter, recipient, token_a_amount (u64)
+// DustSwept:     offer, maker, amount (u64)
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum EventTag {
+    OfferProposed = 0,
+    OfferTaken = 1,
+    DisputeOpened = 2,
+    DisputeResolved = 3,
+    DustSwept = 4,
+}
+
+// Fixed-size buffer an event is encoded into before it is logged
+pub struct EventWriter {
+    data: [u8; MAX_EVENT_LEN],
+    len: usize,
+}
+
+impl EventWriter {
+    pub fn new(tag: EventTag) -> Self {
+        let mut data = [0u8; MAX_EVENT_LEN];
+        data[0] = tag as u8;
+        Self { data, len: 1 }
+    }
+
+    // Append raw field bytes
+    // Bytes past MAX_EVENT_LEN are dropped, every event here fits
+    pub fn push(&mut self, bytes: &[u8]) {
+        let count = bytes.len().min(MAX_EVENT_LEN - self.len);
+        self.data[self.len..self.len + count].copy_from_slice(&bytes[..count]);
+        self.len += count;
+    }
+
+    // Encoded tag and fields
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+
+    // Log the event as EVENT_PREFIX followed by the hex-encoded bytes
+    pub fn log(&self) {
+        let mut line = [0u8; MAX_EVENT_LOG_LEN];
+        line[..EVENT_PREFIX.len()].copy_from_slice(EVENT_PREFIX.as_bytes());
+
+        let mut end = EVENT_PREFIX.len();
+        for byte in self.as_bytes() {
+            line[end] = HEX_DIGITS[(byte >> 4) as usize];
+            line[end + 1] = HEX_DIGITS[(byte & 0x0f) as usize];
+            end += 2;
+        }
+
+        log_message(&line[..end]);
+    }
+}
+
+// A value that can be packed into an event
+pub trait EventField {
+    fn write(&self, event: &mut EventWriter);
+}
+
+impl<T: EventField + ?Sized> EventField for &T {
+    fn write(&self, event: &mut EventWriter) {
+        (**self).write(event);
+    }
+}
+
+impl EventField for Address {
+    fn write(&self, event: &mut EventWriter) {
+        event.push(self.as_array());
+    }
+}
+
+impl EventField for u64 {
+    fn write(&self, event: &mut EventWriter) {
+        event.push(&self.to_le_bytes());
+    }
+}
+
+impl EventField for i64 {
+    fn write(&self, event: &mut EventWriter) {
+        event.push(&self.to_le_bytes());
+    }
+}
+
+// Log a structured event: a tag followed by its fields
+#[macro_export]
+macro_rules! log_event {
+    ($tag:expr $(, $field:expr)* $(,)?) => {{
+        let mut event = $crate::events::EventWriter::new($tag);
+        $( $crate::events::EventField::write(&$field, &mut event); )*
+        event.log();
+    }};
+}
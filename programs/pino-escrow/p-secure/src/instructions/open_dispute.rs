@@ -0,0 +1,144 @@
+use pinocchio::{
+    AccountView,
+    ProgramResult,
+    error::ProgramError,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+use crate::{events::EventTag, log_event, state::MakeState};
+
+// Account context for the Open Dispute instruction
+//
+// Either side of a private offer with an arbiter freezes it for the arbiter.
+//
+// Flow:
+// 1. dispute_deadline = now + dispute_window is written to the offer
+// 2. Until the deadline, TakeOffer, RefundOffer and ReapExpiredOffer are blocked
+//    and only the arbiter's ResolveDispute can release the vault
+// 3. If the arbiter does not act in time the dispute lapses and the offer
+//    behaves as before; a lapsed dispute cannot be reopened
+//
+// In Anchor, this would be generated by #[derive(Accounts)]
+// In Pinocchio, we write all validation logic manually.
+pub struct OpenDisputeAccounts<'a> {
+    pub disputant: &'a AccountView,       // Maker or the offer's allowed taker
+    pub offer: &'a AccountView,
+}
+
+// Converting FROM: &'a[AccountView] (what runtime gives us)
+// Converting TO: OpenDisputeAccounts<'a> (our validated, typed struct)
+impl<'a> TryFrom<&'a [AccountView]> for OpenDisputeAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        // Destructure account array
+        let [disputant, offer, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // SECURITY CHECKS
+
+        // 1: Signer Check
+        // Only a party to the deal can freeze it
+        if !disputant.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+
+        // 2: Offer Account Checks
+        // Owner check ensures offer is owned by this program (not a fake)
+        if !offer.owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        // Size check ensures correct data layout
+        if offer.data_len() != MakeState::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Writable check for recording the dispute deadline
+        if !offer.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self { disputant, offer })
+    }
+}
+
+// Open Dispute Instruction
+pub struct OpenDisputeInstruction<'a> {
+    pub accounts: OpenDisputeAccounts<'a>,
+}
+
+impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for OpenDisputeInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, _data): (&'a [AccountView], &'a [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = OpenDisputeAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+
+// INSTRUCTION HANDLER
+
+impl<'a> OpenDisputeInstruction<'a> {
+    pub fn handler(&self) -> ProgramResult {
+
+        // 1: Load Offer State
+        let mut offer_data = self.accounts.offer.try_borrow_mut()?;
+        let offer_state = MakeState::load_mut(&mut offer_data)?;
+
+        // Active check prevents disputing an offer that was already settled
+        if !offer_state.is_active() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Arbiter check: only offers proposed with an arbiter can be disputed
+        if !offer_state.has_arbiter() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+
+        // 2: Disputant Check
+        // Maker or allowed taker; an arbiter is only set on private offers
+        let disputant = self.accounts.disputant.address();
+        if offer_state.proposer.ne(disputant) && offer_state.allowed_taker.ne(disputant) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+
+        // 3: Dispute Window
+        // Disputes are opened while the offer is live, and only once,
+        // so a party cannot keep the vault frozen by reopening
+        let current_time = Clock::get()?.unix_timestamp;
+        if offer_state.is_expired(current_time) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if offer_state.dispute_deadline != 0 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let dispute_deadline = current_time
+            .checked_add(offer_state.dispute_window)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        offer_state.dispute_deadline = dispute_deadline;
+
+        drop(offer_data);
+
+
+        // 4: Log DisputeOpened
+        log_event!(
+            EventTag::DisputeOpened,
+            self.accounts.offer.address(),
+            disputant,
+            dispute_deadline,
+        );
+
+        Ok(())
+    }
+}
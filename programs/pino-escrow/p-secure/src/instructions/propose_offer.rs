@@ -2,11 +2,10 @@ use core::mem::{transmute, size_of};
 
 use pinocchio::{
     AccountView, Address, ProgramResult, cpi::Seed, cpi::Signer, error::ProgramError,
-    sysvars::{Sysvar, rent::Rent}
+    sysvars::{Sysvar, clock::Clock, rent::Rent}
 };
-use pinocchio_token::{instructions::TransferChecked, state::Mint};
 
-use crate::state::MakeState;
+use crate::{events::EventTag, log_event, parser, state::MakeState, token_interface};
 
 
 // Account context for the Offer instruction
@@ -80,26 +79,47 @@ impl<'a> TryFrom<&'a [AccountView]> for OfferAccounts<'a> {
         }
 
 
-        // 2: Token Mint Ownership
-        // Verifies both token mints are owned by the SPL Token program
-        // Prevents attackers from passing fake mint accounts
+        // 2: Token Program & Mint Ownership
+        // Token program must be SPL Token or Token-2022, never an arbitrary program
+        // All owner checks below are made against it, so a fake program would pass them
+        if !token_interface::is_token_program(token_program.address()) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        // Validates mints are owned by that token program and have a mint layout
         if !token_mint_a.owned_by(token_program.address()) {
             return Err(ProgramError::InvalidAccountOwner);
         }
 
+        if !token_interface::is_mint(token_mint_a)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
         if !token_mint_b.owned_by(token_program.address()) {
             return Err(ProgramError::InvalidAccountOwner);
         }
 
+        if !token_interface::is_mint(token_mint_b)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Transfer-fee mints would leave the vault short of the offered amount
+        // and the proposer short of the wanted amount (see has_transfer_fee)
+        if token_interface::has_transfer_fee(token_mint_a)?
+            || token_interface::has_transfer_fee(token_mint_b)?
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
 
         // 3: Maker's Token Account Validation
-        // Owner check ensures the account belongs to SPL Token Program
+        // Owner check ensures the account belongs to the token program
         if !maker_ata_a.owned_by(token_program.address()) {
             return Err(ProgramError::InvalidAccountOwner);
         }
 
-        // Size check ensures correct data layout for a TokenAccount
-        if maker_ata_a.data_len() != pinocchio_token::state::TokenAccount::LEN {
+        // Size check ensures correct data layout for a token account (base or extended)
+        if !token_interface::is_token_account(maker_ata_a)? {
             return Err(ProgramError::InvalidAccountData);
         }
 
@@ -183,6 +203,8 @@ impl<'a> TryFrom<&'a [AccountView]> for OfferAccounts<'a> {
 //
 // This is similar to Anchor's instruction parameters, but we parse it manually.
 // The client sends this data as raw bytes, and we deserialize it here.
+// On the wire it is the v2 payload behind a version byte (see parser.rs);
+// older layouts are decoded into this same struct.
 
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -196,6 +218,12 @@ pub struct ProposalOfferData {
     // Amount of Token A the maker is offering
     pub token_a_offered_amount: u64,
 
+    // Unix timestamp after which the offer can no longer be taken
+    pub expires_at: i64,
+
+    // Only taker allowed to fill the offer, all zeroes for a public offer
+    pub allowed_taker: Address,
+
     pub bump: u8,
 }
 
@@ -225,6 +253,45 @@ impl<'a> TryFrom<&'a [u8]> for ProposalOfferData {
 }
 
 
+// Optional arbiter appended after ProposalOfferData
+//
+// Offers proposed without it have no arbiter and cannot be disputed.
+// An arbiter can only be named on a private offer, so both sides of a
+// dispute (maker and allowed taker) are known up front.
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ArbiterConfig {
+    // Third party who may award the vault to maker or taker
+    pub arbiter: Address,
+
+    // Seconds the arbiter has to resolve a dispute once it is opened
+    pub dispute_window: i64,
+}
+
+impl ArbiterConfig {
+    pub const LEN: usize = core::mem::size_of::<ArbiterConfig>();
+
+    // No arbiter: all-zero address and window
+    pub const NONE: ArbiterConfig = ArbiterConfig {
+        arbiter: Address::new_from_array([0u8; 32]),
+        dispute_window: 0,
+    };
+}
+
+impl<'a> TryFrom<&'a [u8]> for ArbiterConfig {
+    type Error = ProgramError;
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        Ok(unsafe {
+            transmute(
+                TryInto::<[u8; size_of::<ArbiterConfig>()]>::try_into(data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?,
+            )
+        })
+    }
+}
+
+
 // Complete instruction context - combines validated accounts and parsed data
 //
 // This is the final structure passed to the instruction handler.
@@ -232,6 +299,7 @@ impl<'a> TryFrom<&'a [u8]> for ProposalOfferData {
 pub struct ProposeOfferInstruction<'a> {
     pub accounts: OfferAccounts<'a>,
     pub data: ProposalOfferData,
+    pub arbiter: ArbiterConfig,
 }
 
 // Parse both accounts and instruction data together
@@ -242,9 +310,11 @@ impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for ProposeOfferInstruction<'a>
         (accounts, data): (&'a [AccountView], &'a [u8]),
     ) -> Result<Self, Self::Error> {
         let accounts = OfferAccounts::try_from(accounts)?;
-        let data = ProposalOfferData::try_from(data)?;
 
-        Ok(Self { accounts, data })
+        // Version header picks the layout; unknown versions are rejected
+        let (data, arbiter) = parser::parse_propose_offer(data)?;
+
+        Ok(Self { accounts, data, arbiter })
     }
 }
 
@@ -274,13 +344,46 @@ impl<'a> ProposeOfferInstruction<'a> {
         }
 
 
-        // 2: Calculate rent for offer account
+        // 2: Validate Expiration
+        // Offer must still be takeable when created
+        // An expiry in the past would lock funds until someone reaps the offer
+        let current_time = Clock::get()?.unix_timestamp;
+        if self.data.expires_at <= current_time {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+
+        // 3: Validate Arbiter
+        // Disputes need a named taker, and the arbiter must be neither party
+        // The window bounds how long a dispute can hold the vault
+        if self.arbiter.arbiter.as_array() != &[0u8; 32] {
+            if self.data.allowed_taker.as_array() == &[0u8; 32] {
+                return Err(ProgramError::InvalidInstructionData);
+            }
+
+            if self.arbiter.arbiter.eq(self.accounts.maker.address())
+                || self.arbiter.arbiter.eq(&self.data.allowed_taker)
+            {
+                return Err(ProgramError::InvalidInstructionData);
+            }
+
+            if self.arbiter.dispute_window <= 0
+                || self.arbiter.dispute_window > MakeState::MAX_DISPUTE_WINDOW
+            {
+                return Err(ProgramError::InvalidInstructionData);
+            }
+        } else if self.arbiter.dispute_window != 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+
+        // 4: Calculate rent for offer account
         let rent = Rent::get()?;
         let space = MakeState::LEN;
         let lamports = rent.try_minimum_balance(space)?;
 
 
-        // 3: Create the offer PDA account
+        // 5: Create the offer PDA account
         pinocchio_system::instructions::CreateAccount {
             from: self.accounts.maker,
             to: self.accounts.offer,
@@ -296,7 +399,7 @@ impl<'a> ProposeOfferInstruction<'a> {
         ])])?;
 
 
-        // 4: Initialize the offer state
+        // 6: Initialize the offer state
         // Drop borrow before CPI to avoid runtime borrow conflicts
         {
             let mut offer_data = self.accounts.offer.try_borrow_mut()?;
@@ -309,12 +412,16 @@ impl<'a> ProposeOfferInstruction<'a> {
                 *self.accounts.token_mint_b.address(),
                 self.data.token_b_wanted_amount,
                 self.data.token_a_offered_amount,
+                self.data.expires_at,
+                self.data.allowed_taker,
+                self.arbiter.arbiter,
+                self.arbiter.dispute_window,
                 bump,
             );
         }
 
 
-        // 5: Create the vault ATA
+        // 7: Create the vault ATA
         pinocchio_associated_token_account::instructions::Create {
             account: self.accounts.vault,
             funding_account: self.accounts.maker,
@@ -326,18 +433,32 @@ impl<'a> ProposeOfferInstruction<'a> {
         .invoke()?;
 
 
-        // 6: Transfer tokens from maker to vault
+        // 8: Transfer tokens from maker to vault
         // Uses TransferChecked instead of Transfer to validate mint and decimals
         // Prevents token mismatch attacks
-        TransferChecked {
-            from: self.accounts.maker_ata_a,
-            to: self.accounts.vault,
-            authority: self.accounts.maker,
-            mint: self.accounts.token_mint_a,
-            amount: self.data.token_a_offered_amount,
-            decimals: Mint::from_account_view(self.accounts.token_mint_a)?.decimals(),
-        }
-        .invoke()?;
+        token_interface::transfer_checked(
+            self.accounts.maker_ata_a,
+            self.accounts.token_mint_a,
+            self.accounts.vault,
+            self.accounts.maker,
+            self.data.token_a_offered_amount,
+            token_interface::mint_decimals(self.accounts.token_mint_a)?,
+            self.accounts.token_program,
+            &[],
+        )?;
+
+
+        // 9: Log OfferProposed
+        log_event!(
+            EventTag::OfferProposed,
+            self.accounts.offer.address(),
+            self.accounts.maker.address(),
+            self.accounts.token_mint_a.address(),
+            self.accounts.token_mint_b.address(),
+            self.data.token_a_offered_amount,
+            self.data.token_b_wanted_amount,
+            self.data.expires_at,
+        );
 
         Ok(())
     }
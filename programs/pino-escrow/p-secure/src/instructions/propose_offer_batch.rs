@@ -0,0 +1,459 @@
+use core::mem::{transmute, size_of};
+
+use pinocchio::{
+    AccountView, Address, ProgramResult, cpi::Seed, cpi::Signer, error::ProgramError,
+    sysvars::{Sysvar, clock::Clock, rent::Rent}
+};
+
+use crate::{
+    events::EventTag,
+    instructions::ArbiterConfig,
+    log_event,
+    state::MakeState,
+    token_interface,
+};
+
+
+// Maximum number of offers a single ProposeOfferBatch call can create
+// Bounded so the parsed data fits in a fixed array (no allocator in this program)
+pub const MAX_BATCH_OFFERS: usize = 4;
+
+// Accounts before the per-offer pairs:
+// maker, token_mint_a, token_mint_b, maker_ata_a, token_program, system_program, ata_program
+const FIXED_ACCOUNTS: usize = 7;
+
+
+// Account context for the Propose Offer Batch instruction
+//
+// Same accounts as ProposeOffer, but the offer and vault are repeated once per offer.
+// All offers in a batch share the same mints, deadline and allowed taker.
+//
+// Layout:
+// [maker, token_mint_a, token_mint_b, maker_ata_a, token_program, system_program, ata_program,
+//  offer_0, vault_0, offer_1, vault_1, ...]
+//
+// In Anchor, a variable number of accounts goes through ctx.remaining_accounts
+// In Pinocchio, the account slice is already just a slice, so we split it ourselves.
+pub struct ProposeOfferBatchAccounts<'a> {
+    pub maker: &'a AccountView,
+    pub token_mint_a: &'a AccountView,
+    pub token_mint_b: &'a AccountView,
+    pub maker_ata_a: &'a AccountView,
+    pub token_program: &'a AccountView,
+    pub system_program: &'a AccountView,
+
+    // Offer/vault pairs: offer i at index 2i, its vault at 2i + 1
+    pub offer_accounts: &'a [AccountView],
+}
+
+impl<'a> ProposeOfferBatchAccounts<'a> {
+    // Number of offer/vault pairs passed
+    #[inline(always)]
+    pub fn offer_count(&self) -> usize {
+        self.offer_accounts.len() / 2
+    }
+
+    // Offer and vault for the i-th offer in the batch
+    #[inline(always)]
+    pub fn offer_pair(&self, index: usize) -> (&'a AccountView, &'a AccountView) {
+        (&self.offer_accounts[2 * index], &self.offer_accounts[2 * index + 1])
+    }
+}
+
+// Converting FROM: &'a[AccountView] (what runtime gives us)
+// Converting TO: ProposeOfferBatchAccounts<'a> (our validated, typed struct)
+impl<'a> TryFrom<&'a [AccountView]> for ProposeOfferBatchAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        if accounts.len() < FIXED_ACCOUNTS {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        // Split the fixed accounts from the offer/vault pairs
+        let (fixed, offer_accounts) = accounts.split_at(FIXED_ACCOUNTS);
+        let [maker, token_mint_a, token_mint_b, maker_ata_a, token_program, system_program, _] =
+            fixed
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // SECURITY CHECKS
+
+        // 1: Offer Account Count
+        // Pairs must be complete and within the batch limit
+        if offer_accounts.is_empty() || offer_accounts.len() % 2 != 0 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        if offer_accounts.len() / 2 > MAX_BATCH_OFFERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+
+        // 2: Signer Check
+        // Ensures only the actual maker can create escrow offers
+        if !maker.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+
+        // 3: Token Program & Mint Ownership
+        // Checked once for the whole batch since every offer shares the mints
+        if !token_interface::is_token_program(token_program.address()) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        if !token_mint_a.owned_by(token_program.address()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if !token_interface::is_mint(token_mint_a)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !token_mint_b.owned_by(token_program.address()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if !token_interface::is_mint(token_mint_b)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Transfer-fee mints would leave the vault short of the offered amount
+        // and the proposer short of the wanted amount (see has_transfer_fee)
+        if token_interface::has_transfer_fee(token_mint_a)?
+            || token_interface::has_transfer_fee(token_mint_b)?
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+
+        // 4: Maker's Token Account - Ownership, Layout & Address
+        // One source account funds every offer in the batch
+        if !maker_ata_a.owned_by(token_program.address()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if !token_interface::is_token_account(maker_ata_a)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let (expected_maker_ata, _) = Address::find_program_address(
+            &[
+                maker.address().as_array(),
+                token_program.address().as_array(),
+                token_mint_a.address().as_array(),
+            ],
+            &pinocchio_associated_token_account::ID,
+        );
+
+        if expected_maker_ata.ne(maker_ata_a.address()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+
+        // 5: Per-Offer Checks
+        // Same offer and vault checks as ProposeOffer, repeated for every pair
+        // Offer PDA addresses are verified in the handler once the ids are known
+        for pair in offer_accounts.chunks_exact(2) {
+            let (offer, vault) = (&pair[0], &pair[1]);
+
+            if !offer.is_data_empty() {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+
+            if !offer.is_writable() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let (expected_vault, _) = Address::find_program_address(
+                &[
+                    offer.address().as_array(),
+                    token_program.address().as_array(),
+                    token_mint_a.address().as_array(),
+                ],
+                &pinocchio_associated_token_account::ID,
+            );
+
+            if expected_vault.ne(vault.address()) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            if !vault.is_data_empty() {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+
+            if !vault.is_writable() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+
+        // All validations passed
+        Ok(Self {
+            maker,
+            token_mint_a,
+            token_mint_b,
+            maker_ata_a,
+            token_program,
+            system_program,
+            offer_accounts,
+        })
+    }
+}
+
+
+// Instruction data for proposing a batch of escrow offers
+//
+// Packed as a fixed header followed by `count` entries:
+//   [header: 56][entry: 16] * count
+//
+// Offer ids are sequential: first_id, first_id + 1, ... (little-endian u64)
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ProposeOfferBatchHeader {
+    // Id of the first offer, the rest follow sequentially
+    pub first_id: [u8; 8],
+
+    // Deadline shared by every offer in the batch
+    pub expires_at: i64,
+
+    // Only taker allowed to fill the offers, all zeroes for public offers
+    pub allowed_taker: Address,
+
+    // Number of entries that follow the header (1..=MAX_BATCH_OFFERS)
+    pub count: u8,
+}
+
+impl ProposeOfferBatchHeader {
+    pub const LEN: usize = size_of::<ProposeOfferBatchHeader>();
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct BatchOfferEntry {
+    // Amount of Token B the maker wants for this offer
+    pub token_b_wanted_amount: u64,
+
+    // Amount of Token A the maker is offering in this offer
+    pub token_a_offered_amount: u64,
+}
+
+impl BatchOfferEntry {
+    pub const LEN: usize = size_of::<BatchOfferEntry>();
+}
+
+pub struct ProposeOfferBatchData {
+    pub header: ProposeOfferBatchHeader,
+    pub entries: [BatchOfferEntry; MAX_BATCH_OFFERS],
+}
+
+impl ProposeOfferBatchData {
+    // Number of offers in the batch
+    #[inline(always)]
+    pub fn count(&self) -> usize {
+        self.header.count as usize
+    }
+
+    // Offer id for the i-th offer: first_id + index
+    #[inline(always)]
+    pub fn offer_id(&self, index: usize) -> Result<[u8; 8], ProgramError> {
+        u64::from_le_bytes(self.header.first_id)
+            .checked_add(index as u64)
+            .map(u64::to_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)
+    }
+}
+
+// Parse instruction data from raw bytes
+//
+// The length must match the declared count exactly, so a short, long or
+// mis-counted buffer is rejected before any entry is read.
+// Header and entries use the same transmute approach as ProposalOfferData.
+
+impl<'a> TryFrom<&'a [u8]> for ProposeOfferBatchData {
+    type Error = ProgramError;
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() < ProposeOfferBatchHeader::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let (header_bytes, entry_bytes) = data.split_at(ProposeOfferBatchHeader::LEN);
+        let header: ProposeOfferBatchHeader = unsafe {
+            transmute(
+                TryInto::<[u8; ProposeOfferBatchHeader::LEN]>::try_into(header_bytes)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?,
+            )
+        };
+
+        let count = header.count as usize;
+        if count == 0 || count > MAX_BATCH_OFFERS {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if entry_bytes.len() != count * BatchOfferEntry::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let mut entries = [BatchOfferEntry { token_b_wanted_amount: 0, token_a_offered_amount: 0 }; MAX_BATCH_OFFERS];
+        for (entry, bytes) in entries.iter_mut().zip(entry_bytes.chunks_exact(BatchOfferEntry::LEN)) {
+            *entry = unsafe {
+                transmute(
+                    TryInto::<[u8; BatchOfferEntry::LEN]>::try_into(bytes)
+                        .map_err(|_| ProgramError::InvalidInstructionData)?,
+                )
+            };
+        }
+
+        Ok(Self { header, entries })
+    }
+}
+
+
+// Complete instruction context - combines validated accounts and parsed data
+pub struct ProposeOfferBatchInstruction<'a> {
+    pub accounts: ProposeOfferBatchAccounts<'a>,
+    pub data: ProposeOfferBatchData,
+}
+
+// Parse both accounts and instruction data together
+impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for ProposeOfferBatchInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, data): (&'a [AccountView], &'a [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = ProposeOfferBatchAccounts::try_from(accounts)?;
+        let data = ProposeOfferBatchData::try_from(data)?;
+
+        // Declared count must match the offer/vault pairs actually passed
+        if data.count() != accounts.offer_count() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self { accounts, data })
+    }
+}
+
+
+// INSTRUCTION HANDLER
+
+
+impl<'a> ProposeOfferBatchInstruction<'a> {
+
+    // Execute the Propose Offer Batch instruction
+    pub fn handler(&self) -> ProgramResult {
+
+        // 1: Validate Expiration
+        // Shared by every offer, so checked once
+        let current_time = Clock::get()?.unix_timestamp;
+        if self.data.header.expires_at <= current_time {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+
+        // 2: Single Rent Calculation
+        // Every offer account has the same size, so one calculation covers the batch
+        let rent = Rent::get()?;
+        let space = MakeState::LEN;
+        let lamports = rent.try_minimum_balance(space)?;
+
+        let decimals = token_interface::mint_decimals(self.accounts.token_mint_a)?;
+
+
+        // 3: Create Each Offer
+        for index in 0..self.data.count() {
+            let (offer, vault) = self.accounts.offer_pair(index);
+            let entry = self.data.entries[index];
+            let offer_id = self.data.offer_id(index)?;
+
+            // 3a: Verify Offer PDA Address for this sequential id
+            let (expected_offer_address, bump) = Address::find_program_address(
+                &[
+                    MakeState::SEED_PREFIX,
+                    self.accounts.maker.address().as_array(),
+                    &offer_id,
+                ],
+                &crate::ID,
+            );
+
+            if expected_offer_address.ne(offer.address()) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            // 3b: Create the offer PDA account
+            pinocchio_system::instructions::CreateAccount {
+                from: self.accounts.maker,
+                to: offer,
+                space: space as u64,
+                lamports,
+                owner: &crate::ID,
+            }
+            .invoke_signed(&[Signer::from(&[
+                Seed::from(MakeState::SEED_PREFIX),
+                Seed::from(self.accounts.maker.address().as_array()),
+                Seed::from(&offer_id),
+                Seed::from(&[bump]),
+            ])])?;
+
+            // 3c: Initialize the offer state
+            {
+                let mut offer_data = offer.try_borrow_mut()?;
+                let offer_state = MakeState::load_mut(&mut offer_data)?;
+
+                offer_state.set_inner(
+                    offer_id,
+                    *self.accounts.maker.address(),
+                    *self.accounts.token_mint_a.address(),
+                    *self.accounts.token_mint_b.address(),
+                    entry.token_b_wanted_amount,
+                    entry.token_a_offered_amount,
+                    self.data.header.expires_at,
+                    self.data.header.allowed_taker,
+                    // Batched offers never have an arbiter
+                    ArbiterConfig::NONE.arbiter,
+                    ArbiterConfig::NONE.dispute_window,
+                    bump,
+                );
+            }
+
+            // 3d: Create the vault ATA
+            pinocchio_associated_token_account::instructions::Create {
+                account: vault,
+                funding_account: self.accounts.maker,
+                mint: self.accounts.token_mint_a,
+                token_program: self.accounts.token_program,
+                system_program: self.accounts.system_program,
+                wallet: offer,
+            }
+            .invoke()?;
+
+            // 3e: Transfer tokens from maker to vault
+            token_interface::transfer_checked(
+                self.accounts.maker_ata_a,
+                self.accounts.token_mint_a,
+                vault,
+                self.accounts.maker,
+                entry.token_a_offered_amount,
+                decimals,
+                self.accounts.token_program,
+                &[],
+            )?;
+
+            // 3f: Log OfferProposed, same event as a single ProposeOffer
+            log_event!(
+                EventTag::OfferProposed,
+                offer.address(),
+                self.accounts.maker.address(),
+                self.accounts.token_mint_a.address(),
+                self.accounts.token_mint_b.address(),
+                entry.token_a_offered_amount,
+                entry.token_b_wanted_amount,
+                self.data.header.expires_at,
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,313 @@
+use pinocchio::{
+    AccountView,
+    Address,
+    ProgramResult,
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+use crate::{state::MakeState, token_interface};
+
+// Account context for the Reap Expired Offer instruction
+//
+// Anyone can clean up an offer whose deadline has passed.
+// Token A and all rent go back to the maker, never to the caller.
+//
+// Flow:
+// 1. Maker's ATA A is created if it was closed (paid by caller)
+// 2. Vault sends Token A -> Maker's ATA A
+// 3. Vault is closed (rent returned to maker)
+// 4. Offer PDA is closed (rent returned to maker)
+//
+// In Anchor, this would be generated by #[derive(Accounts)]
+// In Pinocchio, we write all validation logic manually.
+pub struct ReapExpiredOfferAccounts<'a> {
+    pub caller: &'a AccountView,          // Anyone (pays for maker ATA A if needed)
+    pub maker: &'a AccountView,           // Original proposer (Sarah)
+    pub token_mint_a: &'a AccountView,
+    pub maker_ata_a: &'a AccountView,     // Sarah's Token A account (refund destination)
+    pub offer: &'a AccountView,
+    pub vault: &'a AccountView,           // Vault holding Token A
+    pub token_program: &'a AccountView,
+    pub system_program: &'a AccountView,
+}
+
+// Converting FROM: &'a[AccountView] (what runtime gives us)
+// Converting TO: ReapExpiredOfferAccounts<'a> (our validated, typed struct)
+impl<'a> TryFrom<&'a [AccountView]> for ReapExpiredOfferAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        // Destructure account array
+        let [caller, maker, token_mint_a, maker_ata_a, offer, vault, token_program, system_program, ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // SECURITY CHECKS
+
+        // 1: Caller & Maker Checks
+        // Caller only signs to pay fees and maker ATA rent, no authority is granted
+        // Maker must be writable to receive the refunded rent
+        if !caller.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if !maker.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+
+        // 2: Token Program & Mint Ownership
+        // Token program must be SPL Token or Token-2022, never an arbitrary program
+        // All owner checks below are made against it, so a fake program would pass them
+        if !token_interface::is_token_program(token_program.address()) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        // Validates mints are owned by that token program and have a mint layout
+        if !token_mint_a.owned_by(token_program.address()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if !token_interface::is_mint(token_mint_a)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+
+        // 3: Offer Account Checks
+        // Owner check ensures offer is owned by this program (not a fake)
+        if !offer.owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        // Size check ensures correct data layout
+        if offer.data_len() != MakeState::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Writable check for closing the offer later
+        if !offer.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // 4: Load and Validate Offer State
+        // Maker is taken from stored state, so funds can only return to them
+        {
+            let offer_data = offer.try_borrow()?;
+            let offer_state = MakeState::load(&offer_data)?;
+
+            // Active check prevents reaping an offer that was already settled
+            if !offer_state.is_active() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            // Expiration check keeps live offers out of reach
+            // Without it anyone could cancel any open offer
+            let current_time = Clock::get()?.unix_timestamp;
+            if !offer_state.is_expired(current_time) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            // Dispute check leaves an open dispute to the arbiter until its window lapses
+            if offer_state.is_disputed(current_time) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            // Proposer check ensures refund goes to the original maker
+            if offer_state.proposer.ne(maker.address()) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            // Mint check prevents passing a different token as Token A
+            if offer_state.token_mint_a.ne(token_mint_a.address()) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+
+
+        // 5: Maker ATA A - Address Derivation
+        // Derives maker's Token A ATA so refunded tokens cannot be redirected
+        // Maker might have closed it since proposing, so it may be empty
+        if !maker_ata_a.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let (expected_maker_ata_a, _) = Address::find_program_address(
+            &[
+                maker.address().as_array(),
+                token_program.address().as_array(),
+                token_mint_a.address().as_array(),
+            ],
+            &pinocchio_associated_token_account::ID,
+        );
+
+        if expected_maker_ata_a.ne(maker_ata_a.address()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !maker_ata_a.is_data_empty() && !maker_ata_a.owned_by(token_program.address()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+
+        // 6: Vault - Ownership & Address
+        // Vault must be the ATA derived from this offer PDA
+        if !vault.owned_by(token_program.address()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if !token_interface::is_token_account(vault)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !vault.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let (expected_vault, _) = Address::find_program_address(
+            &[
+                offer.address().as_array(),
+                token_program.address().as_array(),
+                token_mint_a.address().as_array(),
+            ],
+            &pinocchio_associated_token_account::ID,
+        );
+
+        if expected_vault.ne(vault.address()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // All validations passed
+        Ok(Self {
+            caller,
+            maker,
+            token_mint_a,
+            maker_ata_a,
+            offer,
+            vault,
+            token_program,
+            system_program,
+        })
+    }
+}
+
+// Reap Expired Offer Instruction
+pub struct ReapExpiredOfferInstruction<'a> {
+    pub accounts: ReapExpiredOfferAccounts<'a>,
+}
+
+impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for ReapExpiredOfferInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, _data): (&'a [AccountView], &'a [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = ReapExpiredOfferAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+
+// INSTRUCTION HANDLER
+
+impl<'a> ReapExpiredOfferInstruction<'a> {
+    pub fn handler(&self) -> ProgramResult {
+
+        // 1: Load Offer State
+        let offer_data = self.accounts.offer.try_borrow()?;
+        let offer_state = MakeState::load(&offer_data)?;
+
+        // Double-checks active status in handler to prevent race conditions
+        if !offer_state.is_active() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let bump = offer_state.bump;
+        let offer_id = offer_state.id;
+
+        // Explicitly drops the borrow before making CPIs to avoid runtime borrow conflicts
+        drop(offer_data);
+
+
+        // 2: Create Maker's ATA A if Needed
+        // Caller covers the rent, maker still receives everything else
+        if self.accounts.maker_ata_a.is_data_empty() {
+            pinocchio_associated_token_account::instructions::Create {
+                account: self.accounts.maker_ata_a,
+                funding_account: self.accounts.caller,
+                mint: self.accounts.token_mint_a,
+                token_program: self.accounts.token_program,
+                system_program: self.accounts.system_program,
+                wallet: self.accounts.maker,
+            }
+            .invoke()?;
+        }
+
+
+        // 3: Prepare PDA Signer
+        let bump_binding = [bump];
+        let seeds = [
+            Seed::from(MakeState::SEED_PREFIX),
+            Seed::from(self.accounts.maker.address().as_array()),
+            Seed::from(&offer_id),
+            Seed::from(&bump_binding),
+        ];
+        let signer = Signer::from(&seeds);
+
+
+        // 4: Transfer Token A from Vault back to Maker
+        // Returns the full vault balance, including anything sent to it after ProposeOffer
+        let vault_amount = token_interface::token_account_amount(self.accounts.vault)?;
+
+        if vault_amount > 0 {
+            token_interface::transfer_checked(
+                self.accounts.vault,
+                self.accounts.token_mint_a,
+                self.accounts.maker_ata_a,
+                self.accounts.offer,
+                vault_amount,
+                token_interface::mint_decimals(self.accounts.token_mint_a)?,
+                self.accounts.token_program,
+                &[signer.clone()],
+            )?;
+        }
+
+
+        // 5: Close Vault Account
+        token_interface::close_account(
+            self.accounts.vault,
+            self.accounts.maker,
+            self.accounts.offer,
+            self.accounts.token_program,
+            &[signer],
+        )?;
+
+        // 6: Close Offer Account
+        // Rent goes back to the maker who paid for the account, not the caller
+        // Caller gets nothing, so reaping carries no incentive to grief
+        {
+            let mut offer_data = self.accounts.offer.try_borrow_mut()?;
+            offer_data[0] = 0xff;
+        }
+
+        let lamports = self.accounts.offer.lamports();
+        self.accounts.maker.set_lamports(
+            self.accounts.maker.lamports().saturating_add(lamports)
+        );
+
+        // Zero out offer lamports
+        self.accounts.offer.set_lamports(0);
+
+        // Resize account to 0 bytes
+        self.accounts.offer.resize(0)?;
+
+        // Close the account
+        self.accounts.offer.close()?;
+
+        Ok(())
+    }
+}
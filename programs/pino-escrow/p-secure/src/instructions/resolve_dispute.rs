@@ -0,0 +1,338 @@
+use pinocchio::{
+    AccountView,
+    Address,
+    ProgramResult,
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+use crate::{events::EventTag, log_event, state::MakeState, token_interface};
+
+// Account context for the Resolve Dispute instruction
+//
+// The offer's arbiter awards the vault to the maker or the allowed taker.
+//
+// Flow:
+// 1. Vault sends Token A -> Recipient's ATA A (maker or allowed taker)
+// 2. Vault is closed (rent returned to maker)
+// 3. Offer PDA is closed (rent returned to maker)
+//
+// The recipient's Token A account must already exist.
+//
+// In Anchor, this would be generated by #[derive(Accounts)]
+// In Pinocchio, we write all validation logic manually.
+pub struct ResolveDisputeAccounts<'a> {
+    pub arbiter: &'a AccountView,
+    pub maker: &'a AccountView,           // Original proposer, receives rent
+    pub recipient: &'a AccountView,       // Maker or allowed taker, per the award
+    pub token_mint_a: &'a AccountView,
+    pub recipient_ata_a: &'a AccountView, // Recipient's Token A account
+    pub offer: &'a AccountView,
+    pub vault: &'a AccountView,           // Vault holding Token A
+    pub token_program: &'a AccountView,
+    pub system_program: &'a AccountView,
+}
+
+// Converting FROM: &'a[AccountView] (what runtime gives us)
+// Converting TO: ResolveDisputeAccounts<'a> (our validated, typed struct)
+impl<'a> TryFrom<&'a [AccountView]> for ResolveDisputeAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        // Destructure account array
+        let [arbiter, maker, recipient, token_mint_a, recipient_ata_a, offer, vault, token_program, system_program, ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // SECURITY CHECKS
+
+        // 1: Signer Check
+        // Only the arbiter recorded in the offer can settle a dispute
+        if !arbiter.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+
+        // 2: Token Program & Mint Ownership
+        // Token program must be SPL Token or Token-2022, never an arbitrary program
+        if !token_interface::is_token_program(token_program.address()) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        if !token_mint_a.owned_by(token_program.address()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if !token_interface::is_mint(token_mint_a)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+
+        // 3: Offer Account Checks
+        // Owner check ensures offer is owned by this program (not a fake)
+        if !offer.owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        // Size check ensures correct data layout
+        if offer.data_len() != MakeState::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Writable check for closing the offer later
+        if !offer.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // 4: Load and Validate Offer State
+        {
+            let offer_data = offer.try_borrow()?;
+            let offer_state = MakeState::load(&offer_data)?;
+
+            // Active check prevents resolving an offer that was already settled
+            if !offer_state.is_active() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            // Arbiter check ties the award to the third party the maker named
+            if !offer_state.has_arbiter() || offer_state.arbiter.ne(arbiter.address()) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            // Proposer check ensures rent returns to the original maker
+            if offer_state.proposer.ne(maker.address()) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            // Mint check prevents passing a different token as Token A
+            if offer_state.token_mint_a.ne(token_mint_a.address()) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+
+
+        // 5: Recipient ATA A - Ownership & Address
+        // Derived from the recipient, which the handler matches to the award
+        if !recipient_ata_a.owned_by(token_program.address()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if !token_interface::is_token_account(recipient_ata_a)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !recipient_ata_a.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let (expected_recipient_ata_a, _) = Address::find_program_address(
+            &[
+                recipient.address().as_array(),
+                token_program.address().as_array(),
+                token_mint_a.address().as_array(),
+            ],
+            &pinocchio_associated_token_account::ID,
+        );
+
+        if expected_recipient_ata_a.ne(recipient_ata_a.address()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+
+        // 6: Vault - Ownership & Address
+        // Vault must be the ATA derived from this offer PDA
+        if !vault.owned_by(token_program.address()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if !token_interface::is_token_account(vault)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !vault.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let (expected_vault, _) = Address::find_program_address(
+            &[
+                offer.address().as_array(),
+                token_program.address().as_array(),
+                token_mint_a.address().as_array(),
+            ],
+            &pinocchio_associated_token_account::ID,
+        );
+
+        if expected_vault.ne(vault.address()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // All validations passed
+        Ok(Self {
+            arbiter,
+            maker,
+            recipient,
+            token_mint_a,
+            recipient_ata_a,
+            offer,
+            vault,
+            token_program,
+            system_program,
+        })
+    }
+}
+
+
+// Which side of the deal the arbiter awards the vault to
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DisputeAward {
+    Maker = 0,
+    Taker = 1,
+}
+
+impl TryFrom<&[u8]> for DisputeAward {
+    type Error = ProgramError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        match data {
+            [0] => Ok(DisputeAward::Maker),
+            [1] => Ok(DisputeAward::Taker),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+// Resolve Dispute Instruction
+pub struct ResolveDisputeInstruction<'a> {
+    pub accounts: ResolveDisputeAccounts<'a>,
+    pub award: DisputeAward,
+}
+
+impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for ResolveDisputeInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, data): (&'a [AccountView], &'a [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = ResolveDisputeAccounts::try_from(accounts)?;
+        let award = DisputeAward::try_from(data)?;
+
+        Ok(Self { accounts, award })
+    }
+}
+
+
+// INSTRUCTION HANDLER
+
+impl<'a> ResolveDisputeInstruction<'a> {
+    pub fn handler(&self) -> ProgramResult {
+
+        // 1: Load Offer State
+        let offer_data = self.accounts.offer.try_borrow()?;
+        let offer_state = MakeState::load(&offer_data)?;
+
+        // Double-checks active status in handler to prevent race conditions
+        if !offer_state.is_active() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Dispute check: the arbiter acts only on an open dispute, inside its window
+        // Without a dispute the arbiter could override an offer neither side contested
+        let current_time = Clock::get()?.unix_timestamp;
+        if !offer_state.is_disputed(current_time) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Recipient check: the award picks the maker or the allowed taker, nobody else
+        let expected_recipient = match self.award {
+            DisputeAward::Maker => offer_state.proposer,
+            DisputeAward::Taker => offer_state.allowed_taker,
+        };
+
+        if expected_recipient.ne(self.accounts.recipient.address()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let bump = offer_state.bump;
+        let offer_id = offer_state.id;
+
+        // Explicitly drops the borrow before making CPIs to avoid runtime borrow conflicts
+        drop(offer_data);
+
+
+        // 2: Prepare PDA Signer
+        let bump_binding = [bump];
+        let seeds = [
+            Seed::from(MakeState::SEED_PREFIX),
+            Seed::from(self.accounts.maker.address().as_array()),
+            Seed::from(&offer_id),
+            Seed::from(&bump_binding),
+        ];
+        let signer = Signer::from(&seeds);
+
+
+        // 3: Transfer Token A from Vault to the Recipient
+        // Awards the full vault balance, including anything sent to it after ProposeOffer
+        let vault_amount = token_interface::token_account_amount(self.accounts.vault)?;
+
+        if vault_amount > 0 {
+            token_interface::transfer_checked(
+                self.accounts.vault,
+                self.accounts.token_mint_a,
+                self.accounts.recipient_ata_a,
+                self.accounts.offer,
+                vault_amount,
+                token_interface::mint_decimals(self.accounts.token_mint_a)?,
+                self.accounts.token_program,
+                &[signer.clone()],
+            )?;
+        }
+
+
+        // 4: Close Vault Account
+        token_interface::close_account(
+            self.accounts.vault,
+            self.accounts.maker,
+            self.accounts.offer,
+            self.accounts.token_program,
+            &[signer],
+        )?;
+
+
+        // 5: Close Offer Account
+        // Rent goes back to the maker who paid for the account
+        {
+            let mut offer_data = self.accounts.offer.try_borrow_mut()?;
+            offer_data[0] = 0xff;
+        }
+
+        let lamports = self.accounts.offer.lamports();
+        self.accounts.maker.set_lamports(
+            self.accounts.maker.lamports().saturating_add(lamports)
+        );
+
+        // Zero out offer lamports
+        self.accounts.offer.set_lamports(0);
+
+        // Resize account to 0 bytes
+        self.accounts.offer.resize(0)?;
+
+        // Close the account
+        self.accounts.offer.close()?;
+
+
+        // 6: Log DisputeResolved
+        log_event!(
+            EventTag::DisputeResolved,
+            self.accounts.offer.address(),
+            self.accounts.arbiter.address(),
+            self.accounts.recipient.address(),
+            vault_amount,
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,250 @@
+use pinocchio::{
+    AccountView,
+    Address,
+    ProgramResult,
+    cpi::{Seed, Signer},
+    error::ProgramError,
+};
+
+use crate::{events::EventTag, log_event, state::MakeState, token_interface};
+
+// Account context for the Sweep Dust instruction
+//
+// Anyone can send Token A to an offer's vault, leaving it holding more than
+// token_a_offered_amount. TakeOffer already returns that surplus to the maker
+// when the offer fills; SweepDust lets the maker (Sarah) recover it earlier
+// without closing the offer.
+//
+// Flow:
+// 1. Vault sends (vault balance - token_a_offered_amount) -> Maker's ATA A
+// 2. Offer and vault stay open with exactly the offered amount
+//
+// In Anchor, this would be generated by #[derive(Accounts)]
+// In Pinocchio, we write all validation logic manually.
+pub struct SweepDustAccounts<'a> {
+    pub maker: &'a AccountView,           // Original proposer (Sarah)
+    pub token_mint_a: &'a AccountView,
+    pub maker_ata_a: &'a AccountView,     // Sarah's Token A account (residue destination)
+    pub offer: &'a AccountView,
+    pub vault: &'a AccountView,           // Vault holding Token A
+    pub token_program: &'a AccountView,
+}
+
+// Converting FROM: &'a[AccountView] (what runtime gives us)
+// Converting TO: SweepDustAccounts<'a> (our validated, typed struct)
+impl<'a> TryFrom<&'a [AccountView]> for SweepDustAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        // Destructure account array
+        let [maker, token_mint_a, maker_ata_a, offer, vault, token_program, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // SECURITY CHECKS
+
+        // 1: Signer Check
+        // Only the maker can sweep their own vault
+        if !maker.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+
+        // 2: Token Program & Mint Ownership
+        // Token program must be SPL Token or Token-2022, never an arbitrary program
+        if !token_interface::is_token_program(token_program.address()) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        if !token_mint_a.owned_by(token_program.address()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if !token_interface::is_mint(token_mint_a)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+
+        // 3: Offer Account Checks
+        // Owner check ensures offer is owned by this program (not a fake)
+        if !offer.owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        // Size check ensures correct data layout
+        if offer.data_len() != MakeState::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // 4: Load and Validate Offer State
+        {
+            let offer_data = offer.try_borrow()?;
+            let offer_state = MakeState::load(&offer_data)?;
+
+            // Active check: a settled offer has no vault left to sweep
+            if !offer_state.is_active() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            // Proposer check ties the sweep to the original maker
+            if offer_state.proposer.ne(maker.address()) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            // Mint check prevents passing a different token as Token A
+            if offer_state.token_mint_a.ne(token_mint_a.address()) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+
+
+        // 5: Maker ATA A - Ownership & Address
+        // Ensures the residue goes to the maker and nobody else
+        if !maker_ata_a.owned_by(token_program.address()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if !token_interface::is_token_account(maker_ata_a)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !maker_ata_a.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let (expected_maker_ata_a, _) = Address::find_program_address(
+            &[
+                maker.address().as_array(),
+                token_program.address().as_array(),
+                token_mint_a.address().as_array(),
+            ],
+            &pinocchio_associated_token_account::ID,
+        );
+
+        if expected_maker_ata_a.ne(maker_ata_a.address()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+
+        // 6: Vault - Ownership & Address
+        // Vault must be the ATA derived from this offer PDA
+        if !vault.owned_by(token_program.address()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if !token_interface::is_token_account(vault)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !vault.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let (expected_vault, _) = Address::find_program_address(
+            &[
+                offer.address().as_array(),
+                token_program.address().as_array(),
+                token_mint_a.address().as_array(),
+            ],
+            &pinocchio_associated_token_account::ID,
+        );
+
+        if expected_vault.ne(vault.address()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // All validations passed
+        Ok(Self {
+            maker,
+            token_mint_a,
+            maker_ata_a,
+            offer,
+            vault,
+            token_program,
+        })
+    }
+}
+
+// Sweep Dust Instruction
+pub struct SweepDustInstruction<'a> {
+    pub accounts: SweepDustAccounts<'a>,
+}
+
+impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for SweepDustInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, _data): (&'a [AccountView], &'a [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = SweepDustAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+
+// INSTRUCTION HANDLER
+
+impl<'a> SweepDustInstruction<'a> {
+    pub fn handler(&self) -> ProgramResult {
+
+        // 1: Load Offer State
+        let offer_data = self.accounts.offer.try_borrow()?;
+        let offer_state = MakeState::load(&offer_data)?;
+
+        let token_a_amount = offer_state.token_a_offered_amount;
+        let bump = offer_state.bump;
+        let offer_id = offer_state.id;
+
+        // Explicitly drops the borrow before making CPIs to avoid runtime borrow conflicts
+        drop(offer_data);
+
+
+        // 2: Compute the Residue
+        // Only the balance above token_a_offered_amount is swept, so the offer
+        // still holds everything a taker is owed
+        let vault_amount = token_interface::token_account_amount(self.accounts.vault)?;
+        let residue = vault_amount
+            .checked_sub(token_a_amount)
+            .ok_or(ProgramError::InsufficientFunds)?;
+
+        if residue == 0 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+
+        // 3: Prepare PDA Signer
+        let bump_binding = [bump];
+        let seeds = [
+            Seed::from(MakeState::SEED_PREFIX),
+            Seed::from(self.accounts.maker.address().as_array()),
+            Seed::from(&offer_id),
+            Seed::from(&bump_binding),
+        ];
+        let signer = Signer::from(&seeds);
+
+
+        // 4: Transfer the Residue from Vault back to Maker
+        token_interface::transfer_checked(
+            self.accounts.vault,
+            self.accounts.token_mint_a,
+            self.accounts.maker_ata_a,
+            self.accounts.offer,
+            residue,
+            token_interface::mint_decimals(self.accounts.token_mint_a)?,
+            self.accounts.token_program,
+            &[signer],
+        )?;
+
+
+        // 5: Log DustSwept
+        log_event!(
+            EventTag::DustSwept,
+            self.accounts.offer.address(),
+            self.accounts.maker.address(),
+            residue,
+        );
+
+        Ok(())
+    }
+}
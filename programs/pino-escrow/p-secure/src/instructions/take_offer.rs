@@ -1,16 +1,15 @@
 use pinocchio::{
     AccountView,
-    Address,
     ProgramResult,
     cpi::{Seed, Signer},
     error::ProgramError,
-};
-use pinocchio_token::{
-    instructions::{TransferChecked, CloseAccount},
-    state::{Mint, TokenAccount},
+    sysvars::{Sysvar, clock::Clock},
 };
 
-use crate::state::MakeState;
+use crate::{
+    events::EventTag, instructions::ProtocolFee, log_event, state::MakeState, token_interface,
+    validation,
+};
 
 // Account context for the Take Offer instruction
 //
@@ -20,8 +19,9 @@ use crate::state::MakeState;
 // Flow:
 // 1. Taker sends Token B -> Proposer's ATA B
 // 2. Vault sends Token A -> Taker's ATA A
-// 3. Vault is closed (rent returned to proposer)
-// 4. Offer PDA is closed (rent returned to taker as compensation)
+// 3. Vault balance above the offer, if any -> Proposer's ATA A
+// 4. Vault is closed (rent returned to proposer)
+// 5. Offer PDA is closed (rent returned to taker as compensation)
 //
 // In Anchor, this would be generated by #[derive(Accounts)]
 // In Pinocchio, we write all validation logic manually.
@@ -37,6 +37,14 @@ pub struct TakeOfferAccounts<'a> {
     pub vault: &'a AccountView,           // Vault holding Token A
     pub token_program: &'a AccountView,
     pub system_program: &'a AccountView,
+    pub proposer_ata_a: &'a AccountView,  // Sarah's Token A account (vault surplus)
+}
+
+impl<'a> TakeOfferAccounts<'a> {
+    // Accounts above plus the ATA program (needed for the Create CPI), which
+    // sits between system_program and proposer_ata_a
+    // TakeOfferWithFee's fee accounts follow them
+    pub const COUNT: usize = 13;
 }
 
 // Converting FROM: &'a[AccountView] (what runtime gives us)
@@ -46,52 +54,63 @@ impl<'a> TryFrom<&'a [AccountView]> for TakeOfferAccounts<'a> {
 
     fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
         // Destructure account array
-        let [taker, proposer, proposer_ata_b, token_mint_b, token_mint_a, taker_ata_a, taker_ata_b, offer, vault, token_program, system_program, ..] =
+        let [taker, proposer, proposer_ata_b, token_mint_b, token_mint_a, taker_ata_a, taker_ata_b, offer, vault, token_program, system_program, _associated_token_program, proposer_ata_a, ..] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
         // SECURITY CHECKS
+        // Ordered cheapest first (see validation.rs): a transaction that fails
+        // a flag or owner check is rejected before any address is derived
 
-        // 1: Signer Check
+        // 1: Flags
         // Only the actual taker can accept an offer
-        if !taker.is_signer() {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
+        validation::check_signer(taker)?;
 
+        // The offer is closed, the vault drained and closed, and Token A
+        // lands in the taker's ATA A and any surplus in the proposer's ATA A
+        validation::check_writable(offer)?;
+        validation::check_writable(vault)?;
+        validation::check_writable(taker_ata_a)?;
+        validation::check_writable(proposer_ata_a)?;
 
 
-        // 2: Token Mint Ownership
-        // Validates both mints are legitimate SPL Token mints
-        if !token_mint_a.owned_by(token_program.address()) {
-            return Err(ProgramError::InvalidAccountOwner);
-        }
+        // 2: Token Program & Account Owners
+        // Token program must be SPL Token or Token-2022, never an arbitrary program
+        // All owner checks below are made against it, so a fake program would pass them
+        validation::check_token_program(token_program)?;
 
-        if !token_mint_b.owned_by(token_program.address()) {
-            return Err(ProgramError::InvalidAccountOwner);
-        }
+        // Offer must be owned by this program (not a fake)
+        validation::check_owner(offer, &crate::ID)?;
 
+        // Mints and token accounts must be owned by that token program
+        validation::check_owner(token_mint_a, token_program.address())?;
+        validation::check_owner(token_mint_b, token_program.address())?;
+        validation::check_owner(taker_ata_a, token_program.address())?;
+        validation::check_owner(taker_ata_b, token_program.address())?;
+        validation::check_owner(vault, token_program.address())?;
+        validation::check_owner(proposer_ata_a, token_program.address())?;
 
-        // 3: Offer Account Checks
-        // Owner check ensures offer is owned by this program (not a fake)
-        if !offer.owned_by(&crate::ID) {
-            return Err(ProgramError::InvalidAccountOwner);
-        }
 
-        // Size check ensures correct data layout
+        // 3: Account Layouts
+        // Size check ensures correct offer data layout
         if offer.data_len() != MakeState::LEN {
             return Err(ProgramError::InvalidAccountData);
         }
 
-        // Writable check for closing the offer later
-        if !offer.is_writable() {
-            return Err(ProgramError::InvalidAccountData);
-        }
+        validation::check_mint(token_mint_a)?;
+        validation::check_mint(token_mint_b)?;
+        validation::check_token_account(taker_ata_a)?;
+        validation::check_token_account(taker_ata_b)?;
+        validation::check_token_account(vault)?;
+        validation::check_token_account(proposer_ata_a)?;
+
 
-        // 4: Load and Validate Offer State
+        // 4: Offer State & Balances
         // Validates stored state matches provided accounts
-        let (offer_state_proposer, offer_state_mint_b) = {
+        // The offer is borrowed once and the amounts copied out for the balance checks
+        let (token_b_wanted_amount, token_a_offered_amount) = {
             let offer_data = offer.try_borrow()?;
             let offer_state = MakeState::load(&offer_data)?;
 
@@ -114,129 +133,62 @@ impl<'a> TryFrom<&'a [AccountView]> for TakeOfferAccounts<'a> {
                 return Err(ProgramError::InvalidAccountData);
             }
 
-            // Return values for next validation
-            (offer_state.proposer, offer_state.token_mint_b)
-        };
-
-
-        // 5: Proposer ATA B - Address Derivation
-        // Derives proposer's Token B ATA from stored state
-        // Ensures tokens go to the correct recipient
-        // Proposer might not have Token B account yet!
-        let (expected_proposer_ata_b, _) = Address::find_program_address(
-            &[
-                offer_state_proposer.as_array(),
-                token_program.address().as_array(),
-                offer_state_mint_b.as_array(),
-            ],
-            &pinocchio_associated_token_account::ID,
-        );
-
-        if expected_proposer_ata_b.ne(proposer_ata_b.address()) {
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        // 6: Taker ATA A - Ownership & Address
-        // Four-part validation: owner, size, writable, and address derivation
-        if !taker_ata_a.owned_by(token_program.address()) {
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-
-        if taker_ata_a.data_len() != TokenAccount::LEN {
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        if !taker_ata_a.is_writable() {
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        let (expected_taker_ata_a, _) = Address::find_program_address(
-            &[
-                taker.address().as_array(),
-                token_program.address().as_array(),
-                token_mint_a.address().as_array(),
-            ],
-            &pinocchio_associated_token_account::ID,
-        );
-
-        if expected_taker_ata_a.ne(taker_ata_a.address()) {
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        // 7: Taker ATA B - Ownership, Address & Balance
-        // Owner, size, and address derivation for taker's Token B source account
-        if !taker_ata_b.owned_by(token_program.address()) {
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-
-        if taker_ata_b.data_len() != TokenAccount::LEN {
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        let (expected_taker_ata_b, _) = Address::find_program_address(
-            &[
-                taker.address().as_array(),
-                token_program.address().as_array(),
-                token_mint_b.address().as_array(),
-            ],
-            &pinocchio_associated_token_account::ID,
-        );
-
-        if expected_taker_ata_b.ne(taker_ata_b.address()) {
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        // Verify taker has sufficient Token B balance
-        {
-            let offer_data = offer.try_borrow()?;
-            let offer_state = MakeState::load(&offer_data)?;
-
-            let taker_token_account = TokenAccount::from_account_view(taker_ata_b)?;
-
-            if taker_token_account.amount() < offer_state.token_b_wanted_amount {
-                return Err(ProgramError::InsufficientFunds);
+            // Allow-list check keeps private offers reserved for the chosen taker
+            if !offer_state.can_take(taker.address()) {
+                return Err(ProgramError::InvalidAccountData);
             }
-        }
 
+            (offer_state.token_b_wanted_amount, offer_state.token_a_offered_amount)
+        };
 
-        // 8: Vault - Ownership, Address & Balance
-        // Full validation of vault: owner, size, writable, and address derivation from offer PDA
-        if !vault.owned_by(token_program.address()) {
-            return Err(ProgramError::InvalidAccountOwner);
-        }
+        // Taker must hold enough Token B, the vault enough Token A
+        validation::check_min_balance(taker_ata_b, token_b_wanted_amount)?;
+        validation::check_min_balance(vault, token_a_offered_amount)?;
 
-        if vault.data_len() != TokenAccount::LEN {
-            return Err(ProgramError::InvalidAccountData);
-        }
 
-        if !vault.is_writable() {
-            return Err(ProgramError::InvalidAccountData);
-        }
+        // 5: Address Derivation
+        // Every ATA is derived and compared, most expensive so done last
+        // The proposer and mints were matched against the offer state above
 
-        let (expected_vault, _) = Address::find_program_address(
-            &[
-                offer.address().as_array(),
-                token_program.address().as_array(),
-                token_mint_a.address().as_array(),
-            ],
-            &pinocchio_associated_token_account::ID,
-        );
+        // Proposer's Token B ATA: ensures tokens go to the correct recipient
+        // Proposer might not have Token B account yet, so no owner or layout check
+        validation::check_associated_token_address(
+            proposer_ata_b,
+            proposer.address(),
+            token_program,
+            token_mint_b.address(),
+        )?;
 
-        if expected_vault.ne(vault.address()) {
-            return Err(ProgramError::InvalidAccountData);
-        }
+        // Proposer's Token A ATA: the vault surplus goes back to the maker only
+        validation::check_associated_token_address(
+            proposer_ata_a,
+            proposer.address(),
+            token_program,
+            token_mint_a.address(),
+        )?;
 
-        // Verify vault has sufficient Token A balance
-        {
-            let offer_data = offer.try_borrow()?;
-            let offer_state = MakeState::load(&offer_data)?;
+        // Taker's Token A destination and Token B source
+        validation::check_associated_token_address(
+            taker_ata_a,
+            taker.address(),
+            token_program,
+            token_mint_a.address(),
+        )?;
 
-            let vault_token_account = TokenAccount::from_account_view(vault)?;
+        validation::check_associated_token_address(
+            taker_ata_b,
+            taker.address(),
+            token_program,
+            token_mint_b.address(),
+        )?;
 
-            if vault_token_account.amount() < offer_state.token_a_offered_amount {
-                return Err(ProgramError::InsufficientFunds);
-            }
-        }
+        // Vault: the offer PDA's Token A ATA
+        validation::check_associated_token_address(
+            vault,
+            offer.address(),
+            token_program,
+            token_mint_a.address(),
+        )?;
 
         // All validations passed
         Ok(Self {
@@ -251,13 +203,16 @@ impl<'a> TryFrom<&'a [AccountView]> for TakeOfferAccounts<'a> {
             vault,
             token_program,
             system_program,
+            proposer_ata_a,
         })
     }
 }
 
 // Take Offer Instruction
+// fee is set when routed through TakeOfferWithFee
 pub struct TakeOfferInstruction<'a> {
     pub accounts: TakeOfferAccounts<'a>,
+    pub fee: Option<ProtocolFee<'a>>,
 }
 
 impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for TakeOfferInstruction<'a> {
@@ -268,7 +223,7 @@ impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for TakeOfferInstruction<'a> {
     ) -> Result<Self, Self::Error> {
         let accounts = TakeOfferAccounts::try_from(accounts)?;
 
-        Ok(Self { accounts })
+        Ok(Self { accounts, fee: None })
     }
 }
 
@@ -287,6 +242,20 @@ impl<'a> TakeOfferInstruction<'a> {
             return Err(ProgramError::InvalidAccountData);
         }
 
+        // Expiration check stops takers from filling a stale offer
+        // Once the deadline passes the maker's price no longer applies
+        let current_time = Clock::get()?.unix_timestamp;
+        #[cfg(not(feature = "vuln-missing-expiry-check"))]
+        if offer_state.is_expired(current_time) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Dispute check freezes the offer while the arbiter decides
+        // Otherwise either side could settle around an open dispute
+        if offer_state.is_disputed(current_time) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
         let token_b_amount = offer_state.token_b_wanted_amount;
         let token_a_amount = offer_state.token_a_offered_amount;
         let bump = offer_state.bump;
@@ -311,15 +280,23 @@ impl<'a> TakeOfferInstruction<'a> {
         }
 
         // 3: Transfer Token B from Taker to Proposer
-        TransferChecked {
-            from: self.accounts.taker_ata_b,
-            to: self.accounts.proposer_ata_b,
-            authority: self.accounts.taker,
-            mint: self.accounts.token_mint_b,
-            amount: token_b_amount,
-            decimals: Mint::from_account_view(self.accounts.token_mint_b)?.decimals(),
-        }
-        .invoke()?;
+        // With a protocol fee, the fee is paid to the fee vault first and the proposer gets the rest
+        let token_b_decimals = token_interface::mint_decimals(self.accounts.token_mint_b)?;
+        let proposer_amount = match &self.fee {
+            Some(fee) => fee.collect(&self.accounts, token_b_amount, token_b_decimals)?,
+            None => token_b_amount,
+        };
+
+        token_interface::transfer_checked(
+            self.accounts.taker_ata_b,
+            self.accounts.token_mint_b,
+            self.accounts.proposer_ata_b,
+            self.accounts.taker,
+            proposer_amount,
+            token_b_decimals,
+            self.accounts.token_program,
+            &[],
+        )?;
 
 
         // 4: Prepare PDA Signer
@@ -335,29 +312,51 @@ impl<'a> TakeOfferInstruction<'a> {
 
         // 5: Transfer Token A from Vault to Taker
         // Vault transfers are PDA-signed, ensuring only the escrow program can release funds
-        let vault_amount = TokenAccount::from_account_view(self.accounts.vault)?.amount();
-        let transfer_amount = vault_amount.min(token_a_amount);
-
-        TransferChecked {
-            from: self.accounts.vault,
-            to: self.accounts.taker_ata_a,
-            authority: self.accounts.offer,
-            mint: self.accounts.token_mint_a,
-            amount: transfer_amount,
-            decimals: Mint::from_account_view(self.accounts.token_mint_a)?.decimals(),
+        // The taker gets exactly the offered amount, never less (checked in TryFrom)
+        let vault_amount = token_interface::token_account_amount(self.accounts.vault)?;
+        let surplus = vault_amount
+            .checked_sub(token_a_amount)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let token_a_decimals = token_interface::mint_decimals(self.accounts.token_mint_a)?;
+
+        token_interface::transfer_checked(
+            self.accounts.vault,
+            self.accounts.token_mint_a,
+            self.accounts.taker_ata_a,
+            self.accounts.offer,
+            token_a_amount,
+            token_a_decimals,
+            self.accounts.token_program,
+            &[signer.clone()],
+        )?;
+
+        // 6: Refund Vault Surplus to Proposer
+        // Anyone can send Token A to the vault; whatever sits above the offer
+        // is the maker's, and the vault must be empty before it can close
+        if surplus > 0 {
+            token_interface::transfer_checked(
+                self.accounts.vault,
+                self.accounts.token_mint_a,
+                self.accounts.proposer_ata_a,
+                self.accounts.offer,
+                surplus,
+                token_a_decimals,
+                self.accounts.token_program,
+                &[signer.clone()],
+            )?;
         }
-        .invoke_signed(&[signer.clone()])?;
 
 
-        // 6: Close Vault Account
-        CloseAccount {
-            account: self.accounts.vault,
-            destination: self.accounts.proposer,
-            authority: self.accounts.offer,
-        }
-        .invoke_signed(&[signer])?;
+        // 7: Close Vault Account
+        token_interface::close_account(
+            self.accounts.vault,
+            self.accounts.proposer,
+            self.accounts.offer,
+            self.accounts.token_program,
+            &[signer],
+        )?;
 
-        // 7: Close Offer Account
+        // 8: Close Offer Account
         // Complete closure procedure:
         // - Marks data as invalid (0xff discriminator)
         // - Transfers rent to taker (compensation for gas)
@@ -382,6 +381,19 @@ impl<'a> TakeOfferInstruction<'a> {
         // Close the account
         self.accounts.offer.close()?;
 
+
+        // 9: Log OfferTaken
+        // token_b_amount is the taker's full payment, fee_amount the part sent to the fee vault
+        log_event!(
+            EventTag::OfferTaken,
+            self.accounts.offer.address(),
+            self.accounts.proposer.address(),
+            self.accounts.taker.address(),
+            token_a_amount,
+            token_b_amount,
+            token_b_amount - proposer_amount,
+        );
+
         Ok(())
     }
 }
@@ -0,0 +1,108 @@
+use core::mem::{transmute, size_of};
+
+use pinocchio::{Address, error::ProgramError};
+
+use crate::instructions::{ArbiterConfig, ProposalOfferData};
+
+
+// Versioned ProposeOffer Instruction Data
+//
+// ProposeOffer data starts with a one-byte version header, so the layout can
+// grow without an older client's bytes being read as the newer struct:
+//
+//   [version: u8][payload]
+//
+// v1: [id: 8][token_b_wanted: u64][token_a_offered: u64][bump: u8][padding: 7]
+//     Public offer that never expires
+// v2: [id: 8][token_b_wanted: u64][token_a_offered: u64]
+//     [expires_at: i64][allowed_taker: 32][bump: u8][padding: 7]
+//     optionally followed by an ArbiterConfig
+//
+// Every version is decoded into the same ProposalOfferData, so the handler
+// never sees which layout the client used.
+// An unknown version, a truncated payload or trailing bytes are all rejected
+// with InvalidInstructionData before any field is read.
+
+pub const PROPOSE_OFFER_V1: u8 = 1;
+pub const PROPOSE_OFFER_V2: u8 = 2;
+
+// Expiry given to v1 offers, which predate expiring offers
+pub const NO_EXPIRY: i64 = i64::MAX;
+
+
+// v1 payload: the original offer, before expiry and private takers
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ProposalOfferDataV1 {
+    pub id: [u8; 8],
+    pub token_b_wanted_amount: u64,
+    pub token_a_offered_amount: u64,
+    pub bump: u8,
+}
+
+impl ProposalOfferDataV1 {
+    pub const LEN: usize = core::mem::size_of::<ProposalOfferDataV1>();
+}
+
+// Same transmute approach as ProposalOfferData
+// The copy into a fixed-size array means the payload needs no alignment
+impl<'a> TryFrom<&'a [u8]> for ProposalOfferDataV1 {
+    type Error = ProgramError;
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        Ok(unsafe {
+            transmute(
+                TryInto::<[u8; size_of::<ProposalOfferDataV1>()]>::try_into(data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?,
+            )
+        })
+    }
+}
+
+impl From<ProposalOfferDataV1> for ProposalOfferData {
+    fn from(v1: ProposalOfferDataV1) -> Self {
+        Self {
+            id: v1.id,
+            token_b_wanted_amount: v1.token_b_wanted_amount,
+            token_a_offered_amount: v1.token_a_offered_amount,
+            expires_at: NO_EXPIRY,
+            allowed_taker: Address::new_from_array([0u8; 32]),
+            bump: v1.bump,
+        }
+    }
+}
+
+
+// Parse versioned ProposeOffer data into the offer and its optional arbiter
+//
+// v1 has no arbiter: it cannot name the allowed taker an arbiter requires,
+// so any bytes after a v1 payload are rejected.
+pub fn parse_propose_offer(data: &[u8]) -> Result<(ProposalOfferData, ArbiterConfig), ProgramError> {
+    let (version, payload) = data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *version {
+        PROPOSE_OFFER_V1 => {
+            let offer = ProposalOfferDataV1::try_from(payload)?;
+            Ok((offer.into(), ArbiterConfig::NONE))
+        }
+        PROPOSE_OFFER_V2 => {
+            // Payload is either the plain offer or the offer followed by an ArbiterConfig
+            if payload.len() < ProposalOfferData::LEN {
+                return Err(ProgramError::InvalidInstructionData);
+            }
+            let (offer_data, arbiter_data) = payload.split_at(ProposalOfferData::LEN);
+
+            let offer = ProposalOfferData::try_from(offer_data)?;
+            let arbiter = if arbiter_data.is_empty() {
+                ArbiterConfig::NONE
+            } else {
+                ArbiterConfig::try_from(arbiter_data)?
+            };
+
+            Ok((offer, arbiter))
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
@@ -26,6 +26,19 @@ pub struct MakeState {
     pub token_b_wanted_amount: u64,
     // Amount of token A Sarah is offering
     pub token_a_offered_amount: u64,
+    // Unix timestamp after which the offer can no longer be taken
+    pub expires_at: i64,
+    // Only taker allowed to fill the offer (private OTC deal)
+    // All zeroes = public offer, anyone can take it
+    pub allowed_taker: Address,
+    // Optional third party who settles disputes between maker and taker
+    // All zeroes = no arbiter, disputes cannot be opened
+    pub arbiter: Address,
+    // Seconds the arbiter has to resolve a dispute once it is opened
+    pub dispute_window: i64,
+    // Unix timestamp until which an open dispute can be resolved
+    // 0 = no dispute has been opened
+    pub dispute_deadline: i64,
     pub bump: u8,
     // Whether this escrow is active - 1 byte
     // 0 = inactive/closed, 1 = active
@@ -38,6 +51,8 @@ impl MakeState {
     // Seed prefix for PDA derivation
     pub const SEED_PREFIX: &'static [u8] = b"offer";
     pub const LEN: usize = core::mem::size_of::<MakeState>();
+    // Longest dispute window a maker can give the arbiter (30 days)
+    pub const MAX_DISPUTE_WINDOW: i64 = 30 * 24 * 60 * 60;
 
     // Load mutable reference from account data
     //
@@ -77,6 +92,10 @@ impl MakeState {
         token_mint_b: Address,
         token_b_wanted_amount: u64,
         token_a_offered_amount: u64,
+        expires_at: i64,
+        allowed_taker: Address,
+        arbiter: Address,
+        dispute_window: i64,
         bump: u8,
     ) {
         self.id = id;
@@ -85,6 +104,11 @@ impl MakeState {
         self.token_mint_b = token_mint_b;
         self.token_b_wanted_amount = token_b_wanted_amount;
         self.token_a_offered_amount = token_a_offered_amount;
+        self.expires_at = expires_at;
+        self.allowed_taker = allowed_taker;
+        self.arbiter = arbiter;
+        self.dispute_window = dispute_window;
+        self.dispute_deadline = 0;
         self.bump = bump;
         self.is_initialized = 1; // Mark as active
     }
@@ -95,6 +119,38 @@ impl MakeState {
         self.is_initialized == 1
     }
 
+    // Helper: Check if the offer deadline has passed
+    #[inline(always)]
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+
+    // Helper: Check if anyone can take the offer
+    #[inline(always)]
+    pub fn is_public(&self) -> bool {
+        self.allowed_taker.as_array() == &[0u8; 32]
+    }
+
+    // Helper: Check if the taker may fill the offer
+    // Public offers accept anyone, private offers only the allowed taker
+    #[inline(always)]
+    pub fn can_take(&self, taker: &Address) -> bool {
+        self.is_public() || self.allowed_taker.eq(taker)
+    }
+
+    // Helper: Check if the maker named an arbiter
+    #[inline(always)]
+    pub fn has_arbiter(&self) -> bool {
+        self.arbiter.as_array() != &[0u8; 32]
+    }
+
+    // Helper: Check if a dispute is open and the arbiter can still resolve it
+    // Once the window lapses the offer behaves as if no dispute was opened
+    #[inline(always)]
+    pub fn is_disputed(&self, now: i64) -> bool {
+        self.dispute_deadline != 0 && now < self.dispute_deadline
+    }
+
     // Helper: Close/deactivate the escrow
     #[inline(always)]
     pub fn close(&mut self) {
@@ -0,0 +1,123 @@
+use pinocchio::{AccountView, Address, error::ProgramError};
+
+use crate::token_interface;
+
+// Account Validation
+//
+// Building blocks for the manual account checks, grouped by what they cost.
+// Account structs run them cheapest first and return on the first failure,
+// so a bad transaction is rejected before it pays for the expensive checks:
+//
+// 1. Flags (signer, writable): read from the account header, no data access
+// 2. Address compares (token program, owner): one 32-byte comparison each
+// 3. Layout (mint, token account): data length, at most one byte of data
+// 4. State and balances: borrow the account data and read fields
+// 5. Address derivation (find_program_address): a SHA-256 per bump tried,
+//    by far the most expensive, so it always runs last
+//
+// Reordering never drops a check. A valid transaction passes the same checks
+// as before; only the error returned by one with several faults can change.
+
+
+// Tier 1: Flags
+
+// Account signed the transaction
+#[inline]
+pub fn check_signer(account: &AccountView) -> Result<(), ProgramError> {
+    if !account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(())
+}
+
+// Account was passed writable (needed to move its tokens or close it)
+#[inline]
+pub fn check_writable(account: &AccountView) -> Result<(), ProgramError> {
+    if !account.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(())
+}
+
+
+// Tier 2: Address Compares
+
+// Token program is SPL Token or Token-2022, never an arbitrary program
+// Must run before any owner check made against it
+#[inline]
+pub fn check_token_program(token_program: &AccountView) -> Result<(), ProgramError> {
+    if !token_interface::is_token_program(token_program.address()) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    Ok(())
+}
+
+// Account is owned by the expected program
+#[inline]
+pub fn check_owner(account: &AccountView, owner: &Address) -> Result<(), ProgramError> {
+    if !account.owned_by(owner) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    Ok(())
+}
+
+
+// Tier 3: Layout
+
+// Account has a mint layout for either token program
+#[inline]
+pub fn check_mint(account: &AccountView) -> Result<(), ProgramError> {
+    if !token_interface::is_mint(account)? {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(())
+}
+
+// Account has a token account layout for either token program
+#[inline]
+pub fn check_token_account(account: &AccountView) -> Result<(), ProgramError> {
+    if !token_interface::is_token_account(account)? {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(())
+}
+
+
+// Tier 4: Balances
+
+// Token account holds at least the given amount
+// Run check_token_account first: this one reports a bad layout as
+// InvalidAccountData, indistinguishable from the other tier 3 failures
+#[inline]
+pub fn check_min_balance(account: &AccountView, amount: u64) -> Result<(), ProgramError> {
+    if token_interface::token_account_amount(account)? < amount {
+        return Err(ProgramError::InsufficientFunds);
+    }
+    Ok(())
+}
+
+
+// Tier 5: Address Derivation
+
+// Account is the associated token account of wallet for mint
+#[inline]
+pub fn check_associated_token_address(
+    account: &AccountView,
+    wallet: &Address,
+    token_program: &AccountView,
+    mint: &Address,
+) -> Result<(), ProgramError> {
+    let (expected, _) = Address::find_program_address(
+        &[
+            wallet.as_array(),
+            token_program.address().as_array(),
+            mint.as_array(),
+        ],
+        &pinocchio_associated_token_account::ID,
+    );
+
+    if expected.ne(account.address()) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(())
+}
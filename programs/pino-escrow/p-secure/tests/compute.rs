@@ -0,0 +1,125 @@
+// Compute-unit regression tests for p-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+use litesvm::LiteSVM;
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::Instruction,
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::Signer,
+};
+use soteria_client::escrow::{instructions as escrow_ix, offer_address, vault_address, PROGRAM_ID};
+use soteria_test_kit::*;
+
+const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+const DECIMALS: u8 = 9;
+const INITIAL_MINT_AMOUNT: u64 = 1_000_000_000_000;
+const TOKEN_A_OFFER_AMOUNT: u64 = 100_000_000_000;
+const TOKEN_B_WANTED_AMOUNT: u64 = 50_000_000_000;
+const OFFER_DURATION_SECONDS: i64 = 60 * 60;
+
+fn setup_svm() -> LiteSVM {
+    ProgramHarness::builder()
+        .program_file(PROGRAM_ID, "target/deploy/secure.so")
+        .build()
+        .into_svm()
+}
+
+// Public SPL Token offer of TOKEN_A_OFFER_AMOUNT for TOKEN_B_WANTED_AMOUNT
+fn build_propose_offer_ix(
+    maker: &Pubkey,
+    mint_a: &Pubkey,
+    mint_b: &Pubkey,
+    offer_id: u64,
+    expires_at: i64,
+) -> Instruction {
+    escrow_ix::propose_offer(
+        maker,
+        mint_a,
+        mint_b,
+        offer_id,
+        TOKEN_B_WANTED_AMOUNT,
+        TOKEN_A_OFFER_AMOUNT,
+        expires_at,
+        &Pubkey::default(),
+        &TOKEN_PROGRAM_ID,
+    )
+}
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let mut svm = setup_svm();
+    let maker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let taker = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let (maker_key, taker_key) = (maker.pubkey(), taker.pubkey());
+
+    let mint_a = CreateMint::new(&mut svm, &maker).decimals(DECIMALS).send().unwrap();
+    let mint_b = CreateMint::new(&mut svm, &maker).decimals(DECIMALS).send().unwrap();
+    let maker_ata_a = CreateAssociatedTokenAccount::new(&mut svm, &maker, &mint_a).send().unwrap();
+    MintTo::new(&mut svm, &maker, &mint_a, &maker_ata_a, INITIAL_MINT_AMOUNT).send().unwrap();
+    CreateAssociatedTokenAccount::new(&mut svm, &maker, &mint_a)
+        .owner(&taker_key)
+        .send()
+        .unwrap();
+    let taker_ata_b = CreateAssociatedTokenAccount::new(&mut svm, &maker, &mint_b)
+        .owner(&taker_key)
+        .send()
+        .unwrap();
+    MintTo::new(&mut svm, &maker, &mint_b, &taker_ata_b, INITIAL_MINT_AMOUNT).send().unwrap();
+
+    // Offers 0-2 are public and taken, refunded and reaped in turn
+    let expires_at = svm.get_sysvar::<Clock>().unix_timestamp + OFFER_DURATION_SECONDS;
+    let ix = build_propose_offer_ix(&maker_key, &mint_a, &mint_b, 0, expires_at);
+    budget.record("propose_offer", send_ix(&mut svm, ix, &maker));
+    for offer_id in 1..3 {
+        let ix = build_propose_offer_ix(&maker_key, &mint_a, &mint_b, offer_id, expires_at);
+        send_tx_expect_success(&mut svm, ix, &maker, &[&maker]);
+    }
+
+    // The program creates the maker's Token B account here
+    let ix = escrow_ix::take_offer(&taker_key, &maker_key, &mint_a, &mint_b, 0, &TOKEN_PROGRAM_ID);
+    budget.record("take_offer", send_ix(&mut svm, ix, &taker));
+
+    let ix = escrow_ix::refund_offer(&maker_key, &mint_a, 1, &TOKEN_PROGRAM_ID);
+    budget.record("refund_offer", send_ix(&mut svm, ix, &maker));
+
+    advance_time(&mut svm, OFFER_DURATION_SECONDS as u64 + 1);
+    let ix = escrow_ix::reap_expired_offer(&taker_key, &maker_key, &mint_a, 2, &TOKEN_PROGRAM_ID);
+    budget.record("reap_expired_offer", send_ix(&mut svm, ix, &taker));
+
+    let expires_at = svm.get_sysvar::<Clock>().unix_timestamp + OFFER_DURATION_SECONDS;
+    let entries = [
+        (TOKEN_B_WANTED_AMOUNT, TOKEN_A_OFFER_AMOUNT),
+        (2 * TOKEN_B_WANTED_AMOUNT, TOKEN_A_OFFER_AMOUNT),
+        (TOKEN_B_WANTED_AMOUNT, 2 * TOKEN_A_OFFER_AMOUNT),
+    ];
+    let ix = escrow_ix::propose_offer_batch(
+        &maker_key,
+        &mint_a,
+        &mint_b,
+        10,
+        expires_at,
+        &Pubkey::default(),
+        &entries,
+        &TOKEN_PROGRAM_ID,
+    );
+    budget.record("propose_offer_batch", send_ix(&mut svm, ix, &maker));
+
+    // Dust in a batch offer's vault is swept back to the maker
+    let (offer, _) = offer_address(&maker_key, 10);
+    let vault = vault_address(&offer, &mint_a, &TOKEN_PROGRAM_ID);
+    MintTo::new(&mut svm, &maker, &mint_a, &vault, 1).send().unwrap();
+    let ix = escrow_ix::sweep_dust(&maker_key, &mint_a, 10, &TOKEN_PROGRAM_ID);
+    budget.record("sweep_dust", send_ix(&mut svm, ix, &maker));
+
+    budget.check();
+}
@@ -1,8 +1,24 @@
 pub mod propose_offer;
+pub mod propose_offer_batch;
+pub mod set_fee_config;
 pub mod take_offer;
+pub mod refund_offer;
+pub mod reap_expired_offer;
+pub mod take_offer_with_fee;
+pub mod open_dispute;
+pub mod resolve_dispute;
+pub mod sweep_dust;
 
 pub use propose_offer::*;
+pub use propose_offer_batch::*;
+pub use set_fee_config::*;
 pub use take_offer::*;
+pub use refund_offer::*;
+pub use reap_expired_offer::*;
+pub use take_offer_with_fee::*;
+pub use open_dispute::*;
+pub use resolve_dispute::*;
+pub use sweep_dust::*;
 
 use pinocchio::error::ProgramError;
 
@@ -10,6 +26,14 @@ use pinocchio::error::ProgramError;
 pub enum Instruction {
     ProposeOffer = 0,
     TakeOffer = 1,
+    RefundOffer = 2,
+    ReapExpiredOffer = 3,
+    ProposeOfferBatch = 4,
+    SetFeeConfig = 5,
+    TakeOfferWithFee = 6,
+    OpenDispute = 7,
+    ResolveDispute = 8,
+    SweepDust = 9,
 }
 
 impl TryFrom<&u8> for Instruction {
@@ -19,6 +43,14 @@ impl TryFrom<&u8> for Instruction {
         match *value {
             0 => Ok(Instruction::ProposeOffer),
             1 => Ok(Instruction::TakeOffer),
+            2 => Ok(Instruction::RefundOffer),
+            3 => Ok(Instruction::ReapExpiredOffer),
+            4 => Ok(Instruction::ProposeOfferBatch),
+            5 => Ok(Instruction::SetFeeConfig),
+            6 => Ok(Instruction::TakeOfferWithFee),
+            7 => Ok(Instruction::OpenDispute),
+            8 => Ok(Instruction::ResolveDispute),
+            9 => Ok(Instruction::SweepDust),
             _ => Err(ProgramError::InvalidInstructionData),
         }
     }
@@ -1,12 +1,11 @@
 use core::mem::{transmute, size_of};
 
 use pinocchio::{
-    AccountView, ProgramResult, cpi::Seed, cpi::Signer, error::ProgramError,
+    AccountView, Address, ProgramResult, cpi::Seed, cpi::Signer, error::ProgramError,
     sysvars::{Sysvar, rent::Rent}
 };
-use pinocchio_token::{instructions::TransferChecked, state::Mint};
 
-use crate::state::MakeState;
+use crate::{state::MakeState, token_interface};
 
 
 // Account context for the Offer instruction
@@ -65,6 +64,21 @@ impl<'a> TryFrom<&'a [AccountView]> for OfferAccounts<'a> {
         // Fix: if !maker.is_signer() { return Err(ProgramError::MissingRequiredSignature); }
 
 
+        // VULNERABILITY [CRITICAL]: Missing token program validation
+        //
+        // token_program is never checked against SPL Token or Token-2022.
+        // Every token CPI is sent to whatever program the caller passes, and
+        // any owner check made against token_program.address() is meaningless.
+        //
+        // Example:
+        //   Attacker deploys a fake token program whose TransferChecked is a no-op.
+        //   Attacker creates fake mints and token accounts owned by that program.
+        //   The escrow "moves" tokens by invoking the fake program, so balances
+        //   the escrow relies on never change while the instruction succeeds.
+        //
+        // Fix: if !token_interface::is_token_program(token_program.address()) { return Err(ProgramError::IncorrectProgramId); }
+
+
         // VULNERABILITY [CRITICAL]: Missing token mint ownership validation
         //
         // Neither token_mint_a nor token_mint_b are verified to be owned by the SPL Token program.
@@ -93,7 +107,7 @@ impl<'a> TryFrom<&'a [AccountView]> for OfferAccounts<'a> {
         //   Program reads this fake balance and proceeds with the escrow.
         //
         // Fix: if !maker_ata_a.owned_by(token_program.address()) { return Err(...); }
-        // Fix: if maker_ata_a.data_len() != TokenAccount::LEN { return Err(...); }
+        // Fix: if !token_interface::is_token_account(maker_ata_a)? { return Err(...); }
 
 
         // VULNERABILITY [HIGH]: Missing maker ATA address derivation check
@@ -186,6 +200,12 @@ pub struct ProposalOfferData {
     // Amount of Token A the maker is offering
     pub token_a_offered_amount: u64,
 
+    // Unix timestamp after which the offer can no longer be taken
+    pub expires_at: i64,
+
+    // Only taker allowed to fill the offer, all zeroes for a public offer
+    pub allowed_taker: Address,
+
     pub bump: u8,
 }
 
@@ -206,9 +226,40 @@ impl<'a> TryFrom<&'a [u8]> for ProposalOfferData {
 }
 
 
+// Optional arbiter appended after ProposalOfferData
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ArbiterConfig {
+    pub arbiter: Address,
+    pub dispute_window: i64,
+}
+
+impl ArbiterConfig {
+    pub const LEN: usize = core::mem::size_of::<ArbiterConfig>();
+
+    pub const NONE: ArbiterConfig = ArbiterConfig {
+        arbiter: Address::new_from_array([0u8; 32]),
+        dispute_window: 0,
+    };
+}
+
+impl<'a> TryFrom<&'a [u8]> for ArbiterConfig {
+    type Error = ProgramError;
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        Ok(unsafe {
+            transmute(
+                TryInto::<[u8; size_of::<ArbiterConfig>()]>::try_into(data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?,
+            )
+        })
+    }
+}
+
+
 pub struct ProposeOfferInstruction<'a> {
     pub accounts: OfferAccounts<'a>,
     pub data: ProposalOfferData,
+    pub arbiter: ArbiterConfig,
 }
 
 impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for ProposeOfferInstruction<'a> {
@@ -218,9 +269,20 @@ impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for ProposeOfferInstruction<'a>
         (accounts, data): (&'a [AccountView], &'a [u8]),
     ) -> Result<Self, Self::Error> {
         let accounts = OfferAccounts::try_from(accounts)?;
-        let data = ProposalOfferData::try_from(data)?;
 
-        Ok(Self { accounts, data })
+        if data.len() < ProposalOfferData::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let (offer_data, arbiter_data) = data.split_at(ProposalOfferData::LEN);
+
+        let data = ProposalOfferData::try_from(offer_data)?;
+        let arbiter = if arbiter_data.is_empty() {
+            ArbiterConfig::NONE
+        } else {
+            ArbiterConfig::try_from(arbiter_data)?
+        };
+
+        Ok(Self { accounts, data, arbiter })
     }
 }
 
@@ -251,6 +313,21 @@ impl<'a> ProposeOfferInstruction<'a> {
         //
         // Fix: Derive PDA with find_program_address and compare address + use returned bump.
 
+        // VULNERABILITY [LOW]: Missing expiration validation
+        //
+        // expires_at is stored as given. An offer can be created already expired,
+        // or with a deadline far enough out that it never matters.
+        //
+        // Fix: if self.data.expires_at <= Clock::get()?.unix_timestamp { return Err(...); }
+
+        // VULNERABILITY [LOW]: Missing arbiter validation
+        //
+        // The arbiter and dispute window are stored as given: an arbiter on a
+        // public offer, the maker as their own arbiter, or a zero/huge window.
+        //
+        // Fix: Require a private offer, an arbiter distinct from both parties,
+        //      and 0 < dispute_window <= MakeState::MAX_DISPUTE_WINDOW
+
         // Use bump from instruction data directly (not verified as canonical)
         let bump = self.data.bump;
 
@@ -288,6 +365,10 @@ impl<'a> ProposeOfferInstruction<'a> {
                 *self.accounts.token_mint_b.address(),
                 self.data.token_b_wanted_amount,
                 self.data.token_a_offered_amount,
+                self.data.expires_at,
+                self.data.allowed_taker,
+                self.arbiter.arbiter,
+                self.arbiter.dispute_window,
                 bump,
             );
         }
@@ -306,15 +387,16 @@ impl<'a> ProposeOfferInstruction<'a> {
 
 
         // Transfer tokens from maker to vault
-        TransferChecked {
-            from: self.accounts.maker_ata_a,
-            to: self.accounts.vault,
-            authority: self.accounts.maker,
-            mint: self.accounts.token_mint_a,
-            amount: self.data.token_a_offered_amount,
-            decimals: Mint::from_account_view(self.accounts.token_mint_a)?.decimals(),
-        }
-        .invoke()?;
+        token_interface::transfer_checked(
+            self.accounts.maker_ata_a,
+            self.accounts.token_mint_a,
+            self.accounts.vault,
+            self.accounts.maker,
+            self.data.token_a_offered_amount,
+            token_interface::mint_decimals(self.accounts.token_mint_a)?,
+            self.accounts.token_program,
+            &[],
+        )?;
 
         Ok(())
     }
@@ -0,0 +1,313 @@
+use core::mem::{transmute, size_of};
+
+use pinocchio::{
+    AccountView, Address, ProgramResult, cpi::Seed, cpi::Signer, error::ProgramError,
+    sysvars::{Sysvar, rent::Rent}
+};
+
+use crate::{instructions::ArbiterConfig, state::MakeState, token_interface};
+
+
+// Maximum number of offers a single ProposeOfferBatch call can create
+pub const MAX_BATCH_OFFERS: usize = 4;
+
+// Accounts before the per-offer pairs:
+// maker, token_mint_a, token_mint_b, maker_ata_a, token_program, system_program, ata_program
+const FIXED_ACCOUNTS: usize = 7;
+
+
+// Account context for the Propose Offer Batch instruction
+//
+// Layout:
+// [maker, token_mint_a, token_mint_b, maker_ata_a, token_program, system_program, ata_program,
+//  offer_0, vault_0, offer_1, vault_1, ...]
+pub struct ProposeOfferBatchAccounts<'a> {
+    pub maker: &'a AccountView,
+    pub token_mint_a: &'a AccountView,
+    pub token_mint_b: &'a AccountView,
+    pub maker_ata_a: &'a AccountView,
+    pub token_program: &'a AccountView,
+    pub system_program: &'a AccountView,
+
+    // Offer/vault pairs: offer i at index 2i, its vault at 2i + 1
+    pub offer_accounts: &'a [AccountView],
+}
+
+impl<'a> ProposeOfferBatchAccounts<'a> {
+    // Offer and vault for the i-th offer in the batch
+    #[inline(always)]
+    pub fn offer_pair(&self, index: usize) -> Result<(&'a AccountView, &'a AccountView), ProgramError> {
+        match (self.offer_accounts.get(2 * index), self.offer_accounts.get(2 * index + 1)) {
+            (Some(offer), Some(vault)) => Ok((offer, vault)),
+            _ => Err(ProgramError::NotEnoughAccountKeys),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for ProposeOfferBatchAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        if accounts.len() < FIXED_ACCOUNTS {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        let (fixed, offer_accounts) = accounts.split_at(FIXED_ACCOUNTS);
+        let [maker, token_mint_a, token_mint_b, maker_ata_a, token_program, system_program, _] =
+            fixed
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // VULNERABILITY [LOW]: Offer/vault pair count not matched against instruction data
+        //
+        // The number of pairs passed is never compared to the declared count.
+        // Extra pairs are silently ignored instead of rejected.
+        //
+        // Example:
+        //   Client passes 4 offer/vault pairs but encodes count = 3.
+        //   Only 3 offers are created, the transaction still succeeds,
+        //   and the client believes all 4 exist.
+        //
+        // Fix: if data.count() != offer_accounts.len() / 2 { return Err(ProgramError::InvalidInstructionData); }
+
+
+        // VULNERABILITY [CRITICAL]: Same account checks omitted as ProposeOffer
+        //
+        // Signer, token program, mint ownership, maker ATA, and every per-offer
+        // offer/vault check are missing, exactly as in propose_offer.rs.
+        // Each omission is multiplied by the number of offers in the batch.
+        //
+        // Fix: Apply the ProposeOffer checks once for the shared accounts and
+        //      once per offer/vault pair.
+
+
+        // No validations - all accounts accepted as-is
+        Ok(Self {
+            maker,
+            token_mint_a,
+            token_mint_b,
+            maker_ata_a,
+            token_program,
+            system_program,
+            offer_accounts,
+        })
+    }
+}
+
+
+// Instruction data for proposing a batch of escrow offers
+//
+// Packed as a fixed header followed by `count` entries:
+//   [header: 56][entry: 16] * count
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ProposeOfferBatchHeader {
+    // Id of the first offer, the rest follow sequentially
+    pub first_id: [u8; 8],
+
+    // Deadline shared by every offer in the batch
+    pub expires_at: i64,
+
+    // Only taker allowed to fill the offers, all zeroes for public offers
+    pub allowed_taker: Address,
+
+    // Number of entries that follow the header (1..=MAX_BATCH_OFFERS)
+    pub count: u8,
+}
+
+impl ProposeOfferBatchHeader {
+    pub const LEN: usize = size_of::<ProposeOfferBatchHeader>();
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct BatchOfferEntry {
+    // Amount of Token B the maker wants for this offer
+    pub token_b_wanted_amount: u64,
+
+    // Amount of Token A the maker is offering in this offer
+    pub token_a_offered_amount: u64,
+}
+
+impl BatchOfferEntry {
+    pub const LEN: usize = size_of::<BatchOfferEntry>();
+}
+
+pub struct ProposeOfferBatchData {
+    pub header: ProposeOfferBatchHeader,
+    pub entries: [BatchOfferEntry; MAX_BATCH_OFFERS],
+}
+
+impl ProposeOfferBatchData {
+    // Number of offers in the batch
+    #[inline(always)]
+    pub fn count(&self) -> usize {
+        self.header.count as usize
+    }
+
+    // Offer id for the i-th offer: first_id + index
+    #[inline(always)]
+    pub fn offer_id(&self, index: usize) -> Result<[u8; 8], ProgramError> {
+        u64::from_le_bytes(self.header.first_id)
+            .checked_add(index as u64)
+            .map(u64::to_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for ProposeOfferBatchData {
+    type Error = ProgramError;
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() < ProposeOfferBatchHeader::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let (header_bytes, entry_bytes) = data.split_at(ProposeOfferBatchHeader::LEN);
+        let header: ProposeOfferBatchHeader = unsafe {
+            transmute(
+                TryInto::<[u8; ProposeOfferBatchHeader::LEN]>::try_into(header_bytes)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?,
+            )
+        };
+
+        let count = header.count as usize;
+        if count == 0 || count > MAX_BATCH_OFFERS {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if entry_bytes.len() != count * BatchOfferEntry::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let mut entries = [BatchOfferEntry { token_b_wanted_amount: 0, token_a_offered_amount: 0 }; MAX_BATCH_OFFERS];
+        for (entry, bytes) in entries.iter_mut().zip(entry_bytes.chunks_exact(BatchOfferEntry::LEN)) {
+            *entry = unsafe {
+                transmute(
+                    TryInto::<[u8; BatchOfferEntry::LEN]>::try_into(bytes)
+                        .map_err(|_| ProgramError::InvalidInstructionData)?,
+                )
+            };
+        }
+
+        Ok(Self { header, entries })
+    }
+}
+
+
+pub struct ProposeOfferBatchInstruction<'a> {
+    pub accounts: ProposeOfferBatchAccounts<'a>,
+    pub data: ProposeOfferBatchData,
+}
+
+impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for ProposeOfferBatchInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, data): (&'a [AccountView], &'a [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = ProposeOfferBatchAccounts::try_from(accounts)?;
+        let data = ProposeOfferBatchData::try_from(data)?;
+
+        Ok(Self { accounts, data })
+    }
+}
+
+
+// INSTRUCTION HANDLER
+
+
+impl<'a> ProposeOfferBatchInstruction<'a> {
+
+    pub fn handler(&self) -> ProgramResult {
+
+        // VULNERABILITY [LOW]: Missing expiration validation (see propose_offer.rs)
+        //
+        // Fix: if self.data.header.expires_at <= Clock::get()?.unix_timestamp { return Err(...); }
+
+
+        // 1: Single Rent Calculation
+        let rent = Rent::get()?;
+        let space = MakeState::LEN;
+        let lamports = rent.try_minimum_balance(space)?;
+
+        let decimals = token_interface::mint_decimals(self.accounts.token_mint_a)?;
+
+
+        // 2: Create Each Offer
+        for index in 0..self.data.count() {
+            let (offer, vault) = self.accounts.offer_pair(index)?;
+            let entry = self.data.entries[index];
+            let offer_id = self.data.offer_id(index)?;
+
+            // Bump is found here only because the batch data carries none;
+            // the derived address is never compared to the offer account
+            let (_, bump) = Address::find_program_address(
+                &[
+                    MakeState::SEED_PREFIX,
+                    self.accounts.maker.address().as_array(),
+                    &offer_id,
+                ],
+                &crate::ID,
+            );
+
+            pinocchio_system::instructions::CreateAccount {
+                from: self.accounts.maker,
+                to: offer,
+                space: space as u64,
+                lamports,
+                owner: &crate::ID,
+            }
+            .invoke_signed(&[Signer::from(&[
+                Seed::from(MakeState::SEED_PREFIX),
+                Seed::from(self.accounts.maker.address().as_array()),
+                Seed::from(&offer_id),
+                Seed::from(&[bump]),
+            ])])?;
+
+            {
+                let mut offer_data = offer.try_borrow_mut()?;
+                let offer_state = MakeState::load_mut(&mut offer_data)?;
+
+                offer_state.set_inner(
+                    offer_id,
+                    *self.accounts.maker.address(),
+                    *self.accounts.token_mint_a.address(),
+                    *self.accounts.token_mint_b.address(),
+                    entry.token_b_wanted_amount,
+                    entry.token_a_offered_amount,
+                    self.data.header.expires_at,
+                    self.data.header.allowed_taker,
+                    // Batched offers never have an arbiter
+                    ArbiterConfig::NONE.arbiter,
+                    ArbiterConfig::NONE.dispute_window,
+                    bump,
+                );
+            }
+
+            pinocchio_associated_token_account::instructions::Create {
+                account: vault,
+                funding_account: self.accounts.maker,
+                mint: self.accounts.token_mint_a,
+                token_program: self.accounts.token_program,
+                system_program: self.accounts.system_program,
+                wallet: offer,
+            }
+            .invoke()?;
+
+            token_interface::transfer_checked(
+                self.accounts.maker_ata_a,
+                self.accounts.token_mint_a,
+                vault,
+                self.accounts.maker,
+                entry.token_a_offered_amount,
+                decimals,
+                self.accounts.token_program,
+                &[],
+            )?;
+        }
+
+        Ok(())
+    }
+}
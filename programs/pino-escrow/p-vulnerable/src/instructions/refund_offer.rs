@@ -0,0 +1,274 @@
+use pinocchio::{
+    AccountView,
+    Address,
+    ProgramResult,
+    cpi::{Seed, Signer},
+    error::ProgramError,
+};
+
+use crate::{state::MakeState, token_interface};
+
+// Account context for the Refund Offer instruction
+//
+// The maker (Sarah) cancels an offer nobody has taken yet and recovers Token A.
+//
+// Flow:
+// 1. Vault sends Token A -> Maker's ATA A
+// 2. Vault is closed (rent returned to maker)
+// 3. Offer PDA is closed (rent returned to maker)
+//
+pub struct RefundOfferAccounts<'a> {
+    pub maker: &'a AccountView,           // Original proposer (Sarah)
+    pub token_mint_a: &'a AccountView,
+    pub maker_ata_a: &'a AccountView,     // Sarah's Token A account (refund destination)
+    pub offer: &'a AccountView,
+    pub vault: &'a AccountView,           // Vault holding Token A
+    pub token_program: &'a AccountView,
+    pub system_program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for RefundOfferAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        // Destructure account array
+        let [maker, token_mint_a, maker_ata_a, offer, vault, token_program, system_program, ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // VULNERABILITY [CRITICAL]: Missing signer check on maker
+        //
+        // The maker account is not verified as a transaction signer.
+        // The remaining checks tie the refund to the maker recorded in the offer,
+        // so anyone can pass that pubkey and cancel an offer they do not own.
+        //
+        // Example:
+        //   Sarah lists 100 Token A for 50 Token B at a good price.
+        //   Attacker sees Steve's TakeOffer in flight and front-runs it with
+        //   RefundOffer using Sarah's pubkey as maker (not signing).
+        //   The offer and vault are closed, Steve's swap fails, and Sarah's
+        //   listing is gone without her authorization.
+        //
+        // Fix: if !maker.is_signer() { return Err(ProgramError::MissingRequiredSignature); }
+
+
+        // 1: Token Mint Ownership
+        // Validates the mint is owned by the passed token program
+        // VULNERABLE: token_program itself is never checked (see take_offer.rs)
+        if !token_mint_a.owned_by(token_program.address()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+
+        // 2: Offer Account Checks
+        // Owner check ensures offer is owned by this program (not a fake)
+        if !offer.owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        // Size check ensures correct data layout
+        if offer.data_len() != MakeState::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Writable check for closing the offer later
+        if !offer.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // 3: Load and Validate Offer State
+        // The signer must be the maker recorded in the offer
+        {
+            let offer_data = offer.try_borrow()?;
+            let offer_state = MakeState::load(&offer_data)?;
+
+            // Active check prevents refunding an offer that was already settled
+            if !offer_state.is_active() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            // Proposer check ties the refund to the original maker
+            if offer_state.proposer.ne(maker.address()) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            // Mint check prevents passing a different token as Token A
+            if offer_state.token_mint_a.ne(token_mint_a.address()) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+
+
+        // 4: Maker ATA A - Ownership & Address
+        // Four-part validation: owner, size, writable, and address derivation
+        // Ensures refunded tokens return to the maker and nobody else
+        if !maker_ata_a.owned_by(token_program.address()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if !token_interface::is_token_account(maker_ata_a)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !maker_ata_a.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let (expected_maker_ata_a, _) = Address::find_program_address(
+            &[
+                maker.address().as_array(),
+                token_program.address().as_array(),
+                token_mint_a.address().as_array(),
+            ],
+            &pinocchio_associated_token_account::ID,
+        );
+
+        if expected_maker_ata_a.ne(maker_ata_a.address()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+
+        // 5: Vault - Ownership & Address
+        // Vault must be the ATA derived from this offer PDA
+        if !vault.owned_by(token_program.address()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if !token_interface::is_token_account(vault)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !vault.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let (expected_vault, _) = Address::find_program_address(
+            &[
+                offer.address().as_array(),
+                token_program.address().as_array(),
+                token_mint_a.address().as_array(),
+            ],
+            &pinocchio_associated_token_account::ID,
+        );
+
+        if expected_vault.ne(vault.address()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // All validations passed
+        Ok(Self {
+            maker,
+            token_mint_a,
+            maker_ata_a,
+            offer,
+            vault,
+            token_program,
+            system_program,
+        })
+    }
+}
+
+// Refund Offer Instruction
+pub struct RefundOfferInstruction<'a> {
+    pub accounts: RefundOfferAccounts<'a>,
+}
+
+impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for RefundOfferInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, _data): (&'a [AccountView], &'a [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = RefundOfferAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+
+// INSTRUCTION HANDLER
+
+impl<'a> RefundOfferInstruction<'a> {
+    pub fn handler(&self) -> ProgramResult {
+
+        // 1: Load Offer State
+        let offer_data = self.accounts.offer.try_borrow()?;
+        let offer_state = MakeState::load(&offer_data)?;
+
+        // Double-checks active status in handler to prevent race conditions
+        if !offer_state.is_active() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let bump = offer_state.bump;
+        let offer_id = offer_state.id;
+
+        // Explicitly drops the borrow before making CPIs to avoid runtime borrow conflicts
+        drop(offer_data);
+
+
+        // 2: Prepare PDA Signer
+        let bump_binding = [bump];
+        let seeds = [
+            Seed::from(MakeState::SEED_PREFIX),
+            Seed::from(self.accounts.maker.address().as_array()),
+            Seed::from(&offer_id),
+            Seed::from(&bump_binding),
+        ];
+        let signer = Signer::from(&seeds);
+
+
+        // 3: Transfer Token A from Vault back to Maker
+        // Refunds the full vault balance, including anything sent to it after ProposeOffer
+        let vault_amount = token_interface::token_account_amount(self.accounts.vault)?;
+
+        if vault_amount > 0 {
+            token_interface::transfer_checked(
+                self.accounts.vault,
+                self.accounts.token_mint_a,
+                self.accounts.maker_ata_a,
+                self.accounts.offer,
+                vault_amount,
+                token_interface::mint_decimals(self.accounts.token_mint_a)?,
+                self.accounts.token_program,
+                &[signer.clone()],
+            )?;
+        }
+
+
+        // 4: Close Vault Account
+        token_interface::close_account(
+            self.accounts.vault,
+            self.accounts.maker,
+            self.accounts.offer,
+            self.accounts.token_program,
+            &[signer],
+        )?;
+
+        // 5: Close Offer Account
+        // Same closure procedure as TakeOffer, but rent goes back to the maker
+        // who paid for the account
+        {
+            let mut offer_data = self.accounts.offer.try_borrow_mut()?;
+            offer_data[0] = 0xff;
+        }
+
+        let lamports = self.accounts.offer.lamports();
+        self.accounts.maker.set_lamports(
+            self.accounts.maker.lamports().saturating_add(lamports)
+        );
+
+        // Zero out offer lamports
+        self.accounts.offer.set_lamports(0);
+
+        // Resize account to 0 bytes
+        self.accounts.offer.resize(0)?;
+
+        // Close the account
+        self.accounts.offer.close()?;
+
+        Ok(())
+    }
+}
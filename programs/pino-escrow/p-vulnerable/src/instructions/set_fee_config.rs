@@ -0,0 +1,137 @@
+use pinocchio::{
+    AccountView, Address, ProgramResult, cpi::Seed, cpi::Signer, error::ProgramError,
+    sysvars::{Sysvar, rent::Rent}
+};
+
+use crate::state::FeeConfig;
+
+// Account context for the Set Fee Config instruction
+//
+// Creates the program's FeeConfig PDA on first call, updates the fee afterwards.
+// The first caller becomes the fee authority, so the config is created at deployment.
+// Later calls must be signed by that authority.
+pub struct SetFeeConfigAccounts<'a> {
+    pub authority: &'a AccountView,       // Fee authority, pays for the config on creation
+    pub fee_config: &'a AccountView,
+    pub system_program: &'a AccountView,
+}
+
+// Converting FROM: &'a[AccountView] (what runtime gives us)
+// Converting TO: SetFeeConfigAccounts<'a> (our validated, typed struct)
+impl<'a> TryFrom<&'a [AccountView]> for SetFeeConfigAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [authority, fee_config, system_program, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // SECURITY CHECKS
+
+        // 1: Signer Check
+        // Only the authority can create or change the fee
+        if !authority.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // 2: Fee Config Writable Check
+        if !fee_config.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // 3: Existing Config Checks
+        // Once created, the config must be ours and the signer its authority
+        if !fee_config.is_data_empty() {
+            if !fee_config.owned_by(&crate::ID) {
+                return Err(ProgramError::InvalidAccountOwner);
+            }
+
+            let fee_config_data = fee_config.try_borrow()?;
+            let fee_config_state = FeeConfig::load(&fee_config_data)?;
+
+            if !fee_config_state.is_active() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            if fee_config_state.authority.ne(authority.address()) {
+                return Err(ProgramError::IncorrectAuthority);
+            }
+        }
+
+        Ok(Self {
+            authority,
+            fee_config,
+            system_program,
+        })
+    }
+}
+
+
+// Set Fee Config Instruction
+//
+// Data: [fee_bps: u16 LE]
+pub struct SetFeeConfigInstruction<'a> {
+    pub accounts: SetFeeConfigAccounts<'a>,
+    pub fee_bps: u16,
+}
+
+impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for SetFeeConfigInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, data): (&'a [AccountView], &'a [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = SetFeeConfigAccounts::try_from(accounts)?;
+        let fee_bps = u16::from_le_bytes(
+            data.try_into().map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+
+        Ok(Self { accounts, fee_bps })
+    }
+}
+
+
+// INSTRUCTION HANDLER
+
+impl<'a> SetFeeConfigInstruction<'a> {
+    pub fn handler(&self) -> ProgramResult {
+
+        // 1: Validate Fee
+        // Caps the fee so a taker always forwards most of the payment to the maker
+        if self.fee_bps > FeeConfig::MAX_FEE_BPS {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        // 2: Verify Fee Config PDA Address
+        let (expected_fee_config, bump) =
+            Address::find_program_address(&[FeeConfig::SEED_PREFIX], &crate::ID);
+
+        if expected_fee_config.ne(self.accounts.fee_config.address()) {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        // 3: Create the Config on First Call
+        if self.accounts.fee_config.is_data_empty() {
+            let lamports = Rent::get()?.try_minimum_balance(FeeConfig::LEN)?;
+
+            pinocchio_system::instructions::CreateAccount {
+                from: self.accounts.authority,
+                to: self.accounts.fee_config,
+                space: FeeConfig::LEN as u64,
+                lamports,
+                owner: &crate::ID,
+            }
+            .invoke_signed(&[Signer::from(&[
+                Seed::from(FeeConfig::SEED_PREFIX),
+                Seed::from(&[bump]),
+            ])])?;
+        }
+
+        // 4: Store Authority and Fee
+        let mut fee_config_data = self.accounts.fee_config.try_borrow_mut()?;
+        let fee_config_state = FeeConfig::load_mut(&mut fee_config_data)?;
+        fee_config_state.set_inner(*self.accounts.authority.address(), self.fee_bps, bump);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,208 @@
+use pinocchio::{
+    AccountView,
+    Address,
+    ProgramResult,
+    cpi::{Seed, Signer},
+    error::ProgramError,
+};
+
+use crate::{state::MakeState, token_interface};
+
+// Account context for the Sweep Dust instruction
+//
+// The maker (Sarah) sweeps Token A sent to the vault on top of the offered
+// amount back to her ATA. The offer and vault stay open.
+//
+// Flow:
+// 1. Vault sends the residue -> Maker's ATA A
+//
+pub struct SweepDustAccounts<'a> {
+    pub maker: &'a AccountView,           // Original proposer (Sarah)
+    pub token_mint_a: &'a AccountView,
+    pub maker_ata_a: &'a AccountView,     // Sarah's Token A account (residue destination)
+    pub offer: &'a AccountView,
+    pub vault: &'a AccountView,           // Vault holding Token A
+    pub token_program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for SweepDustAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        // Destructure account array
+        let [maker, token_mint_a, maker_ata_a, offer, vault, token_program, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1: Signer Check
+        // Only the maker can sweep their own vault
+        if !maker.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+
+        // 2: Token Mint Ownership
+        // VULNERABLE: token_program itself is never checked (see take_offer.rs)
+        if !token_mint_a.owned_by(token_program.address()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+
+        // 3: Offer Account Checks
+        if !offer.owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if offer.data_len() != MakeState::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // 4: Load and Validate Offer State
+        {
+            let offer_data = offer.try_borrow()?;
+            let offer_state = MakeState::load(&offer_data)?;
+
+            // Active check: a settled offer has no vault left to sweep
+            if !offer_state.is_active() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            // Proposer check ties the sweep to the original maker
+            if offer_state.proposer.ne(maker.address()) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            // Mint check prevents passing a different token as Token A
+            if offer_state.token_mint_a.ne(token_mint_a.address()) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+
+
+        // 5: Maker ATA A - Ownership & Address
+        if !maker_ata_a.owned_by(token_program.address()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if !token_interface::is_token_account(maker_ata_a)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let (expected_maker_ata_a, _) = Address::find_program_address(
+            &[
+                maker.address().as_array(),
+                token_program.address().as_array(),
+                token_mint_a.address().as_array(),
+            ],
+            &pinocchio_associated_token_account::ID,
+        );
+
+        if expected_maker_ata_a.ne(maker_ata_a.address()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+
+        // 6: Vault - Ownership & Address
+        if !vault.owned_by(token_program.address()) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let (expected_vault, _) = Address::find_program_address(
+            &[
+                offer.address().as_array(),
+                token_program.address().as_array(),
+                token_mint_a.address().as_array(),
+            ],
+            &pinocchio_associated_token_account::ID,
+        );
+
+        if expected_vault.ne(vault.address()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            maker,
+            token_mint_a,
+            maker_ata_a,
+            offer,
+            vault,
+            token_program,
+        })
+    }
+}
+
+// Sweep Dust Instruction
+pub struct SweepDustInstruction<'a> {
+    pub accounts: SweepDustAccounts<'a>,
+}
+
+impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for SweepDustInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, _data): (&'a [AccountView], &'a [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = SweepDustAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+
+// INSTRUCTION HANDLER
+
+impl<'a> SweepDustInstruction<'a> {
+    pub fn handler(&self) -> ProgramResult {
+
+        // 1: Load Offer State
+        let offer_data = self.accounts.offer.try_borrow()?;
+        let offer_state = MakeState::load(&offer_data)?;
+
+        let bump = offer_state.bump;
+        let offer_id = offer_state.id;
+
+        drop(offer_data);
+
+
+        // VULNERABILITY [HIGH]: Sweep not bounded to the residue
+        //
+        // The whole vault balance is swept, not just what sits above
+        // token_a_offered_amount. The offer stays listed at its original
+        // terms with nothing behind it, and TakeOffer pays out min(vault, offered).
+        //
+        // Example:
+        //   Sarah offers 100 Token A for 50 Token B.
+        //   She sweeps all 100 Token A back and leaves the offer open.
+        //   Steve takes it, pays 50 Token B and receives 0 Token A.
+        //
+        // Fix: sweep vault_amount.checked_sub(token_a_offered_amount)? and reject zero,
+        //      and require vault_amount == token_a_offered_amount in TakeOffer.
+        let vault_amount = token_interface::token_account_amount(self.accounts.vault)?;
+
+
+        // 2: Prepare PDA Signer
+        let bump_binding = [bump];
+        let seeds = [
+            Seed::from(MakeState::SEED_PREFIX),
+            Seed::from(self.accounts.maker.address().as_array()),
+            Seed::from(&offer_id),
+            Seed::from(&bump_binding),
+        ];
+        let signer = Signer::from(&seeds);
+
+
+        // 3: Transfer the Sweep from Vault back to Maker
+        token_interface::transfer_checked(
+            self.accounts.vault,
+            self.accounts.token_mint_a,
+            self.accounts.maker_ata_a,
+            self.accounts.offer,
+            vault_amount,
+            token_interface::mint_decimals(self.accounts.token_mint_a)?,
+            self.accounts.token_program,
+            &[signer],
+        )?;
+
+        Ok(())
+    }
+}
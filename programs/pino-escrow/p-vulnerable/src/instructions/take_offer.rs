@@ -4,12 +4,8 @@ use pinocchio::{
     cpi::{Seed, Signer},
     error::ProgramError,
 };
-use pinocchio_token::{
-    instructions::{TransferChecked, CloseAccount},
-    state::{Mint, TokenAccount},
-};
 
-use crate::state::MakeState;
+use crate::{instructions::ProtocolFee, state::MakeState, token_interface};
 
 // Account context for the Take Offer instruction
 //
@@ -34,6 +30,12 @@ pub struct TakeOfferAccounts<'a> {
     pub system_program: &'a AccountView,
 }
 
+impl<'a> TakeOfferAccounts<'a> {
+    // Accounts above plus the ATA program (needed for the Create CPI)
+    // TakeOfferWithFee's fee accounts follow them
+    pub const COUNT: usize = 12;
+}
+
 impl<'a> TryFrom<&'a [AccountView]> for TakeOfferAccounts<'a> {
     type Error = ProgramError;
 
@@ -59,6 +61,21 @@ impl<'a> TryFrom<&'a [AccountView]> for TakeOfferAccounts<'a> {
         // Fix: if !taker.is_signer() { return Err(ProgramError::MissingRequiredSignature); }
 
 
+        // VULNERABILITY [CRITICAL]: Missing token program validation
+        //
+        // token_program is never checked against SPL Token or Token-2022.
+        // Every token CPI is sent to whatever program the caller passes, and
+        // any owner check made against token_program.address() is meaningless.
+        //
+        // Example:
+        //   Attacker deploys a fake token program whose TransferChecked is a no-op.
+        //   Attacker creates fake mints and token accounts owned by that program.
+        //   The escrow "moves" tokens by invoking the fake program, so balances
+        //   the escrow relies on never change while the instruction succeeds.
+        //
+        // Fix: if !token_interface::is_token_program(token_program.address()) { return Err(ProgramError::IncorrectProgramId); }
+
+
         // VULNERABILITY [CRITICAL]: Missing token mint ownership validation
         //
         // Neither token_mint_a nor token_mint_b are verified to be owned by SPL Token program.
@@ -212,8 +229,10 @@ impl<'a> TryFrom<&'a [AccountView]> for TakeOfferAccounts<'a> {
 }
 
 // Take Offer Instruction
+// fee is set when routed through TakeOfferWithFee
 pub struct TakeOfferInstruction<'a> {
     pub accounts: TakeOfferAccounts<'a>,
+    pub fee: Option<ProtocolFee<'a>>,
 }
 
 impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for TakeOfferInstruction<'a> {
@@ -224,7 +243,7 @@ impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for TakeOfferInstruction<'a> {
     ) -> Result<Self, Self::Error> {
         let accounts = TakeOfferAccounts::try_from(accounts)?;
 
-        Ok(Self { accounts })
+        Ok(Self { accounts, fee: None })
     }
 }
 
@@ -257,6 +276,32 @@ impl<'a> TakeOfferInstruction<'a> {
         let bump = offer_state.bump;
         let offer_id = offer_state.id;
 
+        // VULNERABILITY [HIGH]: Expiration deadline never enforced
+        //
+        // The deadline is read from the offer but never compared to the clock.
+        // Expired offers stay fillable forever at the maker's original price.
+        //
+        // Example:
+        //   Sarah offers 100 Token A for 50 Token B, expiring in one hour.
+        //   A week later Token A has doubled in value.
+        //   Steve takes the expired offer at the stale price.
+        //
+        // Fix: if offer_state.is_expired(Clock::get()?.unix_timestamp) { return Err(...); }
+        let _expires_at = offer_state.expires_at; // VULNERABLE: Read but never compared
+
+        // VULNERABILITY [HIGH]: Taker allow-list never enforced
+        //
+        // ProposeOffer stores allowed_taker for private OTC deals, but it is
+        // never compared to the taker. Any account can fill a private offer.
+        //
+        // Example:
+        //   Sarah negotiates 100 Token A for 50 Token B with Steve only.
+        //   Eve watches the chain, sees the offer and takes it first.
+        //   Steve's deal is gone and Eve got a price never offered to her.
+        //
+        // Fix: if !offer_state.can_take(self.accounts.taker.address()) { return Err(ProgramError::InvalidAccountData); }
+        let _allowed_taker = offer_state.allowed_taker; // VULNERABLE: Read but never compared
+
         // VULNERABILITY [MEDIUM]: Missing explicit borrow drop before CPI
         //
         // The offer_data borrow is not explicitly dropped before CPIs.
@@ -286,15 +331,23 @@ impl<'a> TakeOfferInstruction<'a> {
         }
 
         // 3: Transfer Token B from Taker to Proposer
-        TransferChecked {
-            from: self.accounts.taker_ata_b,
-            to: self.accounts.proposer_ata_b,
-            authority: self.accounts.taker,
-            mint: self.accounts.token_mint_b,
-            amount: token_b_amount,
-            decimals: Mint::from_account_view(self.accounts.token_mint_b)?.decimals(),
-        }
-        .invoke()?;
+        // With a protocol fee, the fee is paid to the fee vault first and the proposer gets the rest
+        let token_b_decimals = token_interface::mint_decimals(self.accounts.token_mint_b)?;
+        let proposer_amount = match &self.fee {
+            Some(fee) => fee.collect(&self.accounts, token_b_amount, token_b_decimals)?,
+            None => token_b_amount,
+        };
+
+        token_interface::transfer_checked(
+            self.accounts.taker_ata_b,
+            self.accounts.token_mint_b,
+            self.accounts.proposer_ata_b,
+            self.accounts.taker,
+            proposer_amount,
+            token_b_decimals,
+            self.accounts.token_program,
+            &[],
+        )?;
 
 
         // 4: Prepare PDA Signer
@@ -309,27 +362,41 @@ impl<'a> TakeOfferInstruction<'a> {
 
 
         // 5: Transfer Token A from Vault to Taker
-        let vault_amount = TokenAccount::from_account_view(self.accounts.vault)?.amount();
-        let transfer_amount = vault_amount.min(token_a_amount);
-
-        TransferChecked {
-            from: self.accounts.vault,
-            to: self.accounts.taker_ata_a,
-            authority: self.accounts.offer,
-            mint: self.accounts.token_mint_a,
-            amount: transfer_amount,
-            decimals: Mint::from_account_view(self.accounts.token_mint_a)?.decimals(),
-        }
-        .invoke_signed(&[signer.clone()])?;
+        // VULNERABILITY [HIGH]: Short vault silently under-delivers
+        //
+        // The taker is sent min(vault, token_a_offered_amount) after already
+        // paying the full token_b_wanted_amount. A vault holding less than the
+        // offer settles anyway, and the shortfall is simply never delivered.
+        //
+        // Example:
+        //   Sarah offers 100 Token A for 50 Token B, then drains the vault with SweepDust.
+        //   Steve takes the offer, pays 50 Token B and receives 0 Token A.
+        //
+        // Fix: reject vault_amount < token_a_amount, transfer exactly token_a_amount
+        //      and return vault_amount - token_a_amount to the proposer's ATA A.
+        let vault_amount = token_interface::token_account_amount(self.accounts.vault)?;
+        let transfer_amount = vault_amount.min(token_a_amount); // VULNERABLE: Clamped instead of rejected
+
+        token_interface::transfer_checked(
+            self.accounts.vault,
+            self.accounts.token_mint_a,
+            self.accounts.taker_ata_a,
+            self.accounts.offer,
+            transfer_amount,
+            token_interface::mint_decimals(self.accounts.token_mint_a)?,
+            self.accounts.token_program,
+            &[signer.clone()],
+        )?;
 
 
         // 6: Close Vault Account
-        CloseAccount {
-            account: self.accounts.vault,
-            destination: self.accounts.proposer,
-            authority: self.accounts.offer,
-        }
-        .invoke_signed(&[signer])?;
+        token_interface::close_account(
+            self.accounts.vault,
+            self.accounts.proposer,
+            self.accounts.offer,
+            self.accounts.token_program,
+            &[signer],
+        )?;
 
         // 7: Close Offer Account
         {
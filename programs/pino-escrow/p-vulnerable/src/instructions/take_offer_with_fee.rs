@@ -0,0 +1,196 @@
+use pinocchio::{
+    AccountView,
+    Address,
+    ProgramResult,
+    error::ProgramError,
+};
+
+use crate::{
+    instructions::{TakeOfferAccounts, TakeOfferInstruction},
+    state::FeeConfig,
+    token_interface,
+};
+
+// Take Offer With Fee
+//
+// Marketplace wrapper around TakeOffer: the same swap, but a protocol fee is
+// taken out of the taker's Token B payment before the rest reaches the proposer.
+//
+// Flow:
+// 1. Taker sends fee_bps of the Token B payment -> Fee vault
+// 2. Taker sends the rest of the Token B payment -> Proposer's ATA B
+// 3. Vault sends Token A -> Taker's ATA A, then vault and offer are closed as in TakeOffer
+//
+// Accounts: the TakeOffer accounts and ATA program, then fee_config, fee_recipient, fee_vault
+// The fee vault is the fee authority's Token B ATA, created by the taker if needed
+pub struct ProtocolFee<'a> {
+    pub fee_config: &'a AccountView,
+    pub fee_recipient: &'a AccountView,   // FeeConfig authority
+    pub fee_vault: &'a AccountView,       // Fee authority's Token B account
+    pub fee_bps: u16,
+}
+
+// Converting FROM: the accounts after the TakeOffer accounts
+// Converting TO: ProtocolFee<'a> (our validated, typed struct)
+impl<'a> TryFrom<(&'a [AccountView], &TakeOfferAccounts<'a>)> for ProtocolFee<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, take): (&'a [AccountView], &TakeOfferAccounts<'a>),
+    ) -> Result<Self, Self::Error> {
+        let [fee_config, fee_recipient, fee_vault, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // SECURITY CHECKS
+
+        // 1: Fee Config Account Checks
+        // Must be this program's single FeeConfig PDA, so the taker cannot
+        // substitute a config with their own rate or recipient
+        if !fee_config.owned_by(&crate::ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let (fee_authority, fee_bps) = {
+            let fee_config_data = fee_config.try_borrow()?;
+            let fee_config_state = FeeConfig::load(&fee_config_data)?;
+
+            if !fee_config_state.is_active() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let (expected_fee_config, _) =
+                Address::find_program_address(&[FeeConfig::SEED_PREFIX], &crate::ID);
+
+            if expected_fee_config.ne(fee_config.address()) {
+                return Err(ProgramError::InvalidSeeds);
+            }
+
+            (fee_config_state.authority, fee_config_state.fee_bps)
+        };
+
+        // 2: Fee Recipient Check
+        // The fee is only ever paid to the configured authority
+        if fee_authority.ne(fee_recipient.address()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // 3: Fee Vault - Address, Ownership & Writable
+        // Derived from the fee authority and Token B mint, like proposer_ata_b
+        // Fee authority might not have a Token B account yet!
+        let (expected_fee_vault, _) = Address::find_program_address(
+            &[
+                fee_authority.as_array(),
+                take.token_program.address().as_array(),
+                take.token_mint_b.address().as_array(),
+            ],
+            &pinocchio_associated_token_account::ID,
+        );
+
+        if expected_fee_vault.ne(fee_vault.address()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !fee_vault.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !fee_vault.is_data_empty() {
+            if !fee_vault.owned_by(take.token_program.address()) {
+                return Err(ProgramError::InvalidAccountOwner);
+            }
+
+            if !token_interface::is_token_account(fee_vault)? {
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+
+        Ok(Self {
+            fee_config,
+            fee_recipient,
+            fee_vault,
+            fee_bps,
+        })
+    }
+}
+
+impl<'a> ProtocolFee<'a> {
+    // Pay the fee on a Token B payment to the fee vault
+    // Returns what is left for the proposer
+    pub fn collect(
+        &self,
+        take: &TakeOfferAccounts<'a>,
+        token_b_amount: u64,
+        token_b_decimals: u8,
+    ) -> Result<u64, ProgramError> {
+        let fee_amount = FeeConfig::fee_for(token_b_amount, self.fee_bps); // VULNERABLE: See FeeConfig::fee_for
+        let proposer_amount = token_b_amount - fee_amount;
+
+        if fee_amount == 0 {
+            return Ok(proposer_amount);
+        }
+
+        // Create the fee vault if this is the first fee paid in Token B
+        if self.fee_vault.is_data_empty() {
+            pinocchio_associated_token_account::instructions::Create {
+                account: self.fee_vault,
+                funding_account: take.taker,
+                mint: take.token_mint_b,
+                token_program: take.token_program,
+                system_program: take.system_program,
+                wallet: self.fee_recipient,
+            }
+            .invoke()?;
+        }
+
+        token_interface::transfer_checked(
+            take.taker_ata_b,
+            take.token_mint_b,
+            self.fee_vault,
+            take.taker,
+            fee_amount,
+            token_b_decimals,
+            take.token_program,
+            &[],
+        )?;
+
+        Ok(proposer_amount)
+    }
+}
+
+
+// Take Offer With Fee Instruction
+// Validates the TakeOffer accounts and the fee accounts, then runs TakeOffer with the fee
+pub struct TakeOfferWithFeeInstruction<'a> {
+    pub take: TakeOfferInstruction<'a>,
+}
+
+impl<'a> TryFrom<(&'a [AccountView], &'a [u8])> for TakeOfferWithFeeInstruction<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, _data): (&'a [AccountView], &'a [u8]),
+    ) -> Result<Self, Self::Error> {
+        let take_accounts = TakeOfferAccounts::try_from(accounts)?;
+        let fee_accounts = accounts
+            .get(TakeOfferAccounts::COUNT..)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let fee = ProtocolFee::try_from((fee_accounts, &take_accounts))?;
+
+        Ok(Self {
+            take: TakeOfferInstruction {
+                accounts: take_accounts,
+                fee: Some(fee),
+            },
+        })
+    }
+}
+
+
+// INSTRUCTION HANDLER
+
+impl<'a> TakeOfferWithFeeInstruction<'a> {
+    pub fn handler(&self) -> ProgramResult {
+        self.take.handler()
+    }
+}
@@ -12,10 +12,19 @@ use pinocchio::{
 
 pub mod state;
 pub mod instructions;
+pub mod token_interface;
 
 use instructions::{
     ProposeOfferInstruction,
     TakeOfferInstruction,
+    RefundOfferInstruction,
+    ReapExpiredOfferInstruction,
+    ProposeOfferBatchInstruction,
+    SetFeeConfigInstruction,
+    TakeOfferWithFeeInstruction,
+    OpenDisputeInstruction,
+    ResolveDisputeInstruction,
+    SweepDustInstruction,
     Instruction
 };
 
@@ -59,5 +68,37 @@ pub fn process_instruction(
             let ix = TakeOfferInstruction::try_from((accounts, data))?;
             ix.handler()
         }
+        Instruction::RefundOffer => {
+            let ix = RefundOfferInstruction::try_from((accounts, data))?;
+            ix.handler()
+        }
+        Instruction::ReapExpiredOffer => {
+            let ix = ReapExpiredOfferInstruction::try_from((accounts, data))?;
+            ix.handler()
+        }
+        Instruction::ProposeOfferBatch => {
+            let ix = ProposeOfferBatchInstruction::try_from((accounts, data))?;
+            ix.handler()
+        }
+        Instruction::SetFeeConfig => {
+            let ix = SetFeeConfigInstruction::try_from((accounts, data))?;
+            ix.handler()
+        }
+        Instruction::TakeOfferWithFee => {
+            let ix = TakeOfferWithFeeInstruction::try_from((accounts, data))?;
+            ix.handler()
+        }
+        Instruction::OpenDispute => {
+            let ix = OpenDisputeInstruction::try_from((accounts, data))?;
+            ix.handler()
+        }
+        Instruction::ResolveDispute => {
+            let ix = ResolveDisputeInstruction::try_from((accounts, data))?;
+            ix.handler()
+        }
+        Instruction::SweepDust => {
+            let ix = SweepDustInstruction::try_from((accounts, data))?;
+            ix.handler()
+        }
     }
 }
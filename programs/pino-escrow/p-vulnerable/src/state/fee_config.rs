@@ -0,0 +1,85 @@
+use pinocchio::{error::ProgramError, Address};
+use core::mem::transmute;
+
+
+// Protocol fee settings for TakeOfferWithFee.
+// A single PDA per program: every fee-taking take pays the same rate to the same authority.
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct FeeConfig {
+    // Can change the fee; its Token B ATA is the fee vault
+    pub authority: Address,
+    // Fee in basis points of the taker's Token B payment (10_000 = 100%)
+    pub fee_bps: u16,
+    pub bump: u8,
+    // 0 = uninitialized, 1 = active
+    pub is_initialized: u8,
+}
+
+
+
+impl FeeConfig {
+    // Seed prefix for PDA derivation (no other seeds)
+    pub const SEED_PREFIX: &'static [u8] = b"fee_config";
+    pub const LEN: usize = core::mem::size_of::<FeeConfig>();
+
+    // Basis point denominator and the highest fee the authority may set (10%)
+    pub const BPS_DENOMINATOR: u64 = 10_000;
+    pub const MAX_FEE_BPS: u16 = 1_000;
+
+    // Load mutable reference from account data
+    // Same layout guarantees as MakeState::load_mut
+    #[inline(always)]
+    pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if bytes.len() != FeeConfig::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &mut *transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+    }
+
+    // Load immutable reference from account data
+    #[inline(always)]
+    pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        if bytes.len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(unsafe { &*(bytes.as_ptr() as *const Self) })
+    }
+
+    // Initialize all fields at once
+    #[inline(always)]
+    pub fn set_inner(&mut self, authority: Address, fee_bps: u16, bump: u8) {
+        self.authority = authority;
+        self.fee_bps = fee_bps;
+        self.bump = bump;
+        self.is_initialized = 1;
+    }
+
+    // Helper: Check if the config is initialized
+    #[inline(always)]
+    pub fn is_active(&self) -> bool {
+        self.is_initialized == 1
+    }
+
+    // Helper: Fee owed on a Token B payment, rounded down
+    //
+    // VULNERABILITY [HIGH]: Unchecked multiplication
+    //
+    // amount * fee_bps is computed in u64. For payments above u64::MAX / fee_bps
+    // the product wraps, and the fee collapses to almost nothing.
+    // (A plain `*` wraps the same way in any build without overflow-checks.)
+    //
+    // Example:
+    //   fee_bps = 100 (1%), offer wants 184_467_440_737_095_517 Token B
+    //   amount * 100 wraps to 84, so the fee is 84 / 10_000 = 0
+    //   Maker and taker settle a huge trade without paying the protocol
+    //
+    // Fix: Multiply in u128 with checked_mul, as in the secure version
+    #[inline(always)]
+    pub fn fee_for(amount: u64, fee_bps: u16) -> u64 {
+        amount.wrapping_mul(fee_bps as u64) / Self::BPS_DENOMINATOR // VULNERABLE: Wraps on overflow
+    }
+}
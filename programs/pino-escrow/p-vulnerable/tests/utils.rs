@@ -3,6 +3,7 @@
 use litesvm::LiteSVM;
 use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
 use solana_sdk::{
+    clock::Clock,
     instruction::{AccountMeta, Instruction},
     native_token::LAMPORTS_PER_SOL,
     pubkey::Pubkey,
@@ -21,6 +22,8 @@ pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_account::ID
 
 // Seed prefix must match MakeState::SEED_PREFIX
 pub const OFFER_SEED_PREFIX: &[u8] = b"offer";
+// Seed prefix must match FeeConfig::SEED_PREFIX
+pub const FEE_CONFIG_SEED_PREFIX: &[u8] = b"fee_config";
 
 // Token configuration
 pub const DECIMALS: u8 = 9;
@@ -30,9 +33,19 @@ pub const INITIAL_MINT_AMOUNT: u64 = 1_000_000_000_000; // 1000 tokens
 pub const TOKEN_A_OFFER_AMOUNT: u64 = 100_000_000_000;  // 100 tokens
 pub const TOKEN_B_WANTED_AMOUNT: u64 = 50_000_000_000;  // 50 tokens
 
+// Offers stay open for one hour
+pub const OFFER_DURATION_SECONDS: i64 = 60 * 60;
+
 // Instruction discriminators
 pub const PROPOSE_OFFER_DISCRIMINATOR: u8 = 0;
 pub const TAKE_OFFER_DISCRIMINATOR: u8 = 1;
+pub const REFUND_OFFER_DISCRIMINATOR: u8 = 2;
+pub const REAP_EXPIRED_OFFER_DISCRIMINATOR: u8 = 3;
+pub const SET_FEE_CONFIG_DISCRIMINATOR: u8 = 5;
+pub const TAKE_OFFER_WITH_FEE_DISCRIMINATOR: u8 = 6;
+pub const OPEN_DISPUTE_DISCRIMINATOR: u8 = 7;
+pub const RESOLVE_DISPUTE_DISCRIMINATOR: u8 = 8;
+pub const SWEEP_DUST_DISCRIMINATOR: u8 = 9;
 
 
 pub fn setup_svm() -> LiteSVM {
@@ -56,28 +69,87 @@ pub fn derive_offer_pda(maker: &Pubkey, id: &[u8; 8]) -> (Pubkey, u8) {
     )
 }
 
+pub fn derive_fee_config_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_CONFIG_SEED_PREFIX], &PROGRAM_ID)
+}
+
+pub fn current_timestamp(svm: &LiteSVM) -> i64 {
+    svm.get_sysvar::<Clock>().unix_timestamp
+}
+
+pub fn advance_time(svm: &mut LiteSVM, seconds: i64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += seconds;
+    svm.set_sysvar::<Clock>(&clock);
+}
+
 // Build ProposeOffer instruction data
-// Layout: discriminator(1) + id(8) + token_b_wanted(8) + token_a_offered(8) + bump(1) + padding(7)
+// Layout: discriminator(1) + id(8) + token_b_wanted(8) + token_a_offered(8) + expires_at(8) + allowed_taker(32) + bump(1) + padding(7)
 pub fn build_propose_offer_data(
     id: [u8; 8],
     token_b_wanted_amount: u64,
     token_a_offered_amount: u64,
+    expires_at: i64,
+    allowed_taker: &Pubkey,
     bump: u8,
 ) -> Vec<u8> {
-    let mut data = Vec::with_capacity(33);
+    let mut data = Vec::with_capacity(73);
     data.push(PROPOSE_OFFER_DISCRIMINATOR);
     data.extend_from_slice(&id);
     data.extend_from_slice(&token_b_wanted_amount.to_le_bytes());
     data.extend_from_slice(&token_a_offered_amount.to_le_bytes());
+    data.extend_from_slice(&expires_at.to_le_bytes());
+    data.extend_from_slice(allowed_taker.as_ref());
     data.push(bump);
     data.extend_from_slice(&[0u8; 7]); // padding for repr(C) alignment
     data
 }
 
+// ArbiterConfig appended to ProposeOffer data
+// Layout: arbiter(32) + dispute_window(8)
+pub fn build_arbiter_config_data(arbiter: &Pubkey, dispute_window: i64) -> Vec<u8> {
+    let mut data = Vec::with_capacity(40);
+    data.extend_from_slice(arbiter.as_ref());
+    data.extend_from_slice(&dispute_window.to_le_bytes());
+    data
+}
+
 pub fn build_take_offer_data() -> Vec<u8> {
     vec![TAKE_OFFER_DISCRIMINATOR]
 }
 
+pub fn build_refund_offer_data() -> Vec<u8> {
+    vec![REFUND_OFFER_DISCRIMINATOR]
+}
+
+pub fn build_reap_expired_offer_data() -> Vec<u8> {
+    vec![REAP_EXPIRED_OFFER_DISCRIMINATOR]
+}
+
+// Layout: discriminator(1) + fee_bps(2)
+pub fn build_set_fee_config_data(fee_bps: u16) -> Vec<u8> {
+    let mut data = vec![SET_FEE_CONFIG_DISCRIMINATOR];
+    data.extend_from_slice(&fee_bps.to_le_bytes());
+    data
+}
+
+pub fn build_take_offer_with_fee_data() -> Vec<u8> {
+    vec![TAKE_OFFER_WITH_FEE_DISCRIMINATOR]
+}
+
+pub fn build_open_dispute_data() -> Vec<u8> {
+    vec![OPEN_DISPUTE_DISCRIMINATOR]
+}
+
+// Layout: discriminator(1) + award(1), 0 = maker, 1 = taker
+pub fn build_resolve_dispute_data(award: u8) -> Vec<u8> {
+    vec![RESOLVE_DISPUTE_DISCRIMINATOR, award]
+}
+
+pub fn build_sweep_dust_data() -> Vec<u8> {
+    vec![SWEEP_DUST_DISCRIMINATOR]
+}
+
 // Escrow scenario setup result
 pub struct EscrowScenario {
     pub svm: LiteSVM,
@@ -178,11 +250,40 @@ pub fn create_offer(
     vault_ata: &Pubkey,
     offer_id: [u8; 8],
     bump: u8,
+) {
+    create_private_offer(
+        svm,
+        proposer,
+        mint_a,
+        mint_b,
+        proposer_ata_a,
+        offer_pda,
+        vault_ata,
+        offer_id,
+        &Pubkey::default(),
+        bump,
+    );
+}
+
+// Helper to create an offer only allowed_taker may fill (private OTC deal)
+pub fn create_private_offer(
+    svm: &mut LiteSVM,
+    proposer: &Keypair,
+    mint_a: &Pubkey,
+    mint_b: &Pubkey,
+    proposer_ata_a: &Pubkey,
+    offer_pda: &Pubkey,
+    vault_ata: &Pubkey,
+    offer_id: [u8; 8],
+    allowed_taker: &Pubkey,
+    bump: u8,
 ) {
     let ix_data = build_propose_offer_data(
         offer_id,
         TOKEN_B_WANTED_AMOUNT,
         TOKEN_A_OFFER_AMOUNT,
+        current_timestamp(svm) + OFFER_DURATION_SECONDS,
+        allowed_taker,
         bump,
     );
 
@@ -211,3 +312,28 @@ pub fn create_offer(
 
     svm.send_transaction(tx).expect("ProposeOffer should succeed");
 }
+
+// Helper to create the program's FeeConfig with fee_authority as authority
+pub fn set_fee_config(svm: &mut LiteSVM, fee_authority: &Keypair, fee_bps: u16) -> Pubkey {
+    let (fee_config_pda, _) = derive_fee_config_pda();
+
+    let set_fee_ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(fee_authority.pubkey(), true),
+            AccountMeta::new(fee_config_pda, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: build_set_fee_config_data(fee_bps),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_fee_ix],
+        Some(&fee_authority.pubkey()),
+        &[fee_authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(tx).expect("SetFeeConfig should succeed");
+    fee_config_pda
+}
@@ -0,0 +1,29 @@
+// Seed for raffle PDA (also the vault authority)
+// Derived with: [RAFFLE_SEED, creator_pubkey, seed.to_le_bytes()]
+pub const RAFFLE_SEED: &[u8] = b"raffle";
+
+// RAFFLE LIMITS
+
+// Maximum tickets per raffle
+// Every entrant is stored on the raffle account, so this bounds its size
+pub const MAX_TICKETS: u32 = 100;
+
+// Minimum raffle length (1 minute)
+pub const MIN_RAFFLE_DURATION_SECONDS: i64 = 60;
+
+// Maximum raffle length (30 days)
+// Keeps creators from locking ticket money indefinitely
+pub const MAX_RAFFLE_DURATION_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+// DRAW LIMITS
+
+// Time after end_ts the creator has to draw (1 day)
+// Past reveal_deadline an undrawn raffle refunds every ticket
+pub const REVEAL_WINDOW_SECONDS: i64 = 24 * 60 * 60;
+
+// Slots between seal_raffle and the slot whose hash seeds the draw
+// The hash does not exist yet when the raffle is sealed, so nobody can
+// compute the winner before that slot is produced
+pub const RANDOMNESS_DELAY_SLOTS: u64 = 10;
+
+pub const ANCHOR_DISCRIMINATOR: usize = 8;
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+pub use soteria_errors::raffle_secure::RaffleError as RaffleErrorCode;
+
+#[error_code]
+pub enum RaffleError {
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Maximum tickets must be between 1 and 100")]
+    InvalidMaxTickets,
+
+    #[msg("Raffle duration is outside the allowed range (1 minute to 30 days)")]
+    InvalidDuration,
+
+    #[msg("Raffle has ended, no more tickets can be bought")]
+    RaffleEnded,
+
+    #[msg("Raffle has not ended yet")]
+    RaffleNotEnded,
+
+    #[msg("All tickets have been sold")]
+    SoldOut,
+
+    #[msg("Raffle creator cannot buy tickets")]
+    CreatorCannotEnter,
+
+    #[msg("Winner has already been drawn")]
+    AlreadyDrawn,
+
+    #[msg("No tickets were sold")]
+    NoTickets,
+
+    #[msg("Revealed secret does not match the commitment")]
+    InvalidReveal,
+
+    #[msg("Token account does not belong to the winner")]
+    InvalidWinnerAccount,
+
+    #[msg("Unauthorized: Cannot perform this action")]
+    Unauthorized,
+
+    #[msg("Arithmetic overflow")]
+    Overflow,
+
+    #[msg("Reveal deadline has passed, tickets can only be refunded")]
+    RevealDeadlinePassed,
+
+    #[msg("Reveal deadline has not passed yet")]
+    RevealWindowOpen,
+
+    #[msg("Raffle has not been sealed")]
+    NotSealed,
+
+    #[msg("Raffle has already been sealed")]
+    AlreadySealed,
+
+    #[msg("Slot hash for the randomness slot is not available")]
+    RandomnessUnavailable,
+
+    #[msg("Ticket index out of range")]
+    InvalidTicket,
+
+    #[msg("Ticket has already been refunded")]
+    AlreadyRefunded,
+
+    #[msg("Token account does not belong to the ticket holder")]
+    InvalidRefundAccount,
+}
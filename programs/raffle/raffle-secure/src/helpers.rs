@@ -0,0 +1,92 @@
+// Raffle Helper Functions
+//
+// Reusable CPI and sysvar helpers for the raffle program.
+// These functions reduce code duplication across instructions.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TransferChecked, transfer_checked};
+
+// CPI HELPERS
+
+// Generic token transfer helper
+// Used for paying ticket prices into the raffle vault
+pub fn transfer_tokens<'info>(
+    amount: u64,
+    decimals: u8,
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+) -> Result<()> {
+    transfer_checked(
+        CpiContext::new(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+        ),
+        amount,
+        decimals,
+    )
+}
+
+// Transfer out of the raffle vault, signed by the raffle PDA
+pub fn transfer_from_vault<'info>(
+    amount: u64,
+    decimals: u8,
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let signer_seeds = &[authority_seeds];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )
+}
+
+// SYSVAR HELPERS
+
+// Hash of `slot` from the SlotHashes sysvar account, if it is still listed
+// Layout: entry count (u64) then (slot u64, hash [u8; 32]) pairs, newest first.
+// The sysvar keeps the last 512 slots, so a slot that is too recent (not yet
+// produced) or too old both return None
+pub fn slot_hash(slot_hashes: &AccountInfo, slot: u64) -> Option<[u8; 32]> {
+    const ENTRY_LEN: usize = 8 + 32;
+
+    let data = slot_hashes.try_borrow_data().ok()?;
+    let count = u64::from_le_bytes(data.get(..8)?.try_into().ok()?) as usize;
+
+    for index in 0..count {
+        let start = 8 + index * ENTRY_LEN;
+        let entry = data.get(start..start + ENTRY_LEN)?;
+        let entry_slot = u64::from_le_bytes(entry[..8].try_into().ok()?);
+
+        if entry_slot == slot {
+            return entry[8..].try_into().ok();
+        }
+        if entry_slot < slot {
+            return None;
+        }
+    }
+
+    None
+}
@@ -0,0 +1,116 @@
+// Draw Winner Instruction
+//
+// Reveals the creator's secret, picks the winning ticket and pays it the pot.
+//
+// HOW IT WORKS:
+// 1. Checks the raffle is sealed, not drawn, and within reveal_deadline
+// 2. Reads the hash of randomness_slot from the SlotHashes sysvar
+// 3. Checks hash(secret) matches the commitment made at creation
+// 4. Winning ticket = hash(secret || entropy || slot_hash) % tickets_sold
+// 5. Pays ticket_price * tickets_sold from the vault to the winner
+//
+// SECURITY:
+// - Only the creator can reveal (signer + has_one + PDA seeds)
+// - Only after seal_raffle, so the slot hash was unknown while tickets sold
+// - Secret must match the commitment, so the creator cannot try several
+//   secrets and reveal the one they like
+// - Only until reveal_deadline; after it the tickets are refunded instead
+// - The slot hash must still be in SlotHashes (last 512 slots). A creator who
+//   waits it out cannot draw, and the raffle falls through to refunds
+// - Prize destination must belong to the winning entrant
+// - Draws at most once
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct DrawWinner<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RAFFLE_SEED, creator.key().as_ref(), raffle.seed.to_le_bytes().as_ref()],
+        bump = raffle.bump,
+        has_one = creator @ RaffleError::Unauthorized,
+        has_one = ticket_mint,
+        has_one = ticket_vault,
+    )]
+    pub raffle: Box<Account<'info, Raffle>>,
+
+    pub ticket_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub ticket_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Owner is checked against the winning entrant in the handler
+    #[account(
+        mut,
+        token::mint = ticket_mint,
+        token::token_program = token_program,
+    )]
+    pub winner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: SlotHashes sysvar, validated by address
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> DrawWinner<'info> {
+    pub fn draw_winner(&mut self, secret: [u8; 32]) -> Result<()> {
+        require!(!self.raffle.drawn, RaffleError::AlreadyDrawn);
+
+        // Sealing requires end_ts and at least one ticket
+        require!(self.raffle.is_sealed(), RaffleError::NotSealed);
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now <= self.raffle.reveal_deadline,
+            RaffleError::RevealDeadlinePassed
+        );
+
+        let slot_hash = slot_hash(&self.slot_hashes, self.raffle.randomness_slot)
+            .ok_or(RaffleError::RandomnessUnavailable)?;
+
+        let winning_index = self.raffle.winning_index(&secret, &slot_hash)?;
+        let winner = self.raffle.entrants[winning_index];
+
+        require_keys_eq!(
+            self.winner_token_account.owner,
+            winner,
+            RaffleError::InvalidWinnerAccount
+        );
+
+        let pot = self.raffle.pot()?;
+
+        let seed_bytes = self.raffle.seed.to_le_bytes();
+        let raffle_seeds = &[
+            RAFFLE_SEED,
+            self.raffle.creator.as_ref(),
+            seed_bytes.as_ref(),
+            &[self.raffle.bump],
+        ];
+
+        transfer_from_vault(
+            pot,
+            self.ticket_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.ticket_vault.to_account_info(),
+            &self.ticket_mint.to_account_info(),
+            &self.winner_token_account.to_account_info(),
+            &self.raffle.to_account_info(),
+            raffle_seeds,
+        )?;
+
+        self.raffle.drawn = true;
+        self.raffle.winner = winner;
+
+        msg!("Ticket {} won: {} paid {}", winning_index, winner, pot);
+
+        Ok(())
+    }
+}
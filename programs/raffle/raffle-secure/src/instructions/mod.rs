@@ -0,0 +1,15 @@
+// Instructions Module
+//
+// Exports all instruction handlers for the raffle program
+
+pub mod create_raffle;
+pub mod buy_ticket;
+pub mod seal_raffle;
+pub mod draw_winner;
+pub mod refund_ticket;
+
+pub use create_raffle::*;
+pub use buy_ticket::*;
+pub use seal_raffle::*;
+pub use draw_winner::*;
+pub use refund_ticket::*;
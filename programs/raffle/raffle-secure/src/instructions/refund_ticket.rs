@@ -0,0 +1,103 @@
+// Refund Ticket Instruction
+//
+// Returns one ticket's price to its holder once the reveal deadline has
+// passed without a draw.
+//
+// HOW IT WORKS:
+// 1. Checks the raffle was not drawn and reveal_deadline has passed
+// 2. Checks the ticket exists and has not been refunded
+// 3. Pays ticket_price from the vault to the ticket holder's token account
+// 4. Clears entrants[ticket_index] so the ticket cannot be refunded again
+//
+// SECURITY:
+// - Permissionless: a creator who never reveals cannot keep buyers' tokens
+//   locked, and buyers do not depend on the creator to get them back
+// - Only after reveal_deadline, when draw_winner can no longer run, so a
+//   refunded ticket can never also win
+// - Refund destination must belong to the ticket holder
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct RefundTicket<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RAFFLE_SEED, raffle.creator.as_ref(), raffle.seed.to_le_bytes().as_ref()],
+        bump = raffle.bump,
+        has_one = ticket_mint,
+        has_one = ticket_vault,
+    )]
+    pub raffle: Box<Account<'info, Raffle>>,
+
+    pub ticket_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub ticket_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Owner is checked against the ticket holder in the handler
+    #[account(
+        mut,
+        token::mint = ticket_mint,
+        token::token_program = token_program,
+    )]
+    pub entrant_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> RefundTicket<'info> {
+    pub fn refund_ticket(&mut self, ticket_index: u32) -> Result<()> {
+        require!(!self.raffle.drawn, RaffleError::AlreadyDrawn);
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(now > self.raffle.reveal_deadline, RaffleError::RevealWindowOpen);
+
+        let index = ticket_index as usize;
+        require!(index < self.raffle.entrants.len(), RaffleError::InvalidTicket);
+
+        let entrant = self.raffle.entrants[index];
+        require_keys_neq!(entrant, Pubkey::default(), RaffleError::AlreadyRefunded);
+
+        #[cfg(not(feature = "vuln-missing-refund-owner-check"))]
+        require_keys_eq!(
+            self.entrant_token_account.owner,
+            entrant,
+            RaffleError::InvalidRefundAccount
+        );
+
+        let seed_bytes = self.raffle.seed.to_le_bytes();
+        let raffle_seeds = &[
+            RAFFLE_SEED,
+            self.raffle.creator.as_ref(),
+            seed_bytes.as_ref(),
+            &[self.raffle.bump],
+        ];
+
+        transfer_from_vault(
+            self.raffle.ticket_price,
+            self.ticket_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.ticket_vault.to_account_info(),
+            &self.ticket_mint.to_account_info(),
+            &self.entrant_token_account.to_account_info(),
+            &self.raffle.to_account_info(),
+            raffle_seeds,
+        )?;
+
+        self.raffle.entrants[index] = Pubkey::default();
+
+        msg!(
+            "Ticket {} refunded: {} paid {}",
+            ticket_index,
+            entrant,
+            self.raffle.ticket_price
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+// Seal Raffle Instruction
+//
+// Fixes the future slot whose hash seeds the draw.
+//
+// HOW IT WORKS:
+// 1. Checks the raffle has ended, has tickets, and has not been sealed
+// 2. Sets randomness_slot = current slot + RANDOMNESS_DELAY_SLOTS
+//
+// SECURITY:
+// - Only after end_ts, so the entrant list is final before the slot is chosen
+// - The hash of randomness_slot does not exist yet, so the creator (who knows
+//   the secret and the entropy) still cannot compute the winner
+// - Seals at most once: the creator cannot re-roll a slot they dislike
+// - Only the creator, who must then reveal while the slot hash is listed in
+//   SlotHashes; nobody else can start that clock
+
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+pub struct SealRaffle<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RAFFLE_SEED, creator.key().as_ref(), raffle.seed.to_le_bytes().as_ref()],
+        bump = raffle.bump,
+        has_one = creator @ RaffleError::Unauthorized,
+    )]
+    pub raffle: Box<Account<'info, Raffle>>,
+}
+
+impl<'info> SealRaffle<'info> {
+    pub fn seal_raffle(&mut self) -> Result<()> {
+        require!(!self.raffle.drawn, RaffleError::AlreadyDrawn);
+        require!(!self.raffle.is_sealed(), RaffleError::AlreadySealed);
+
+        let clock = Clock::get()?;
+        require!(clock.unix_timestamp >= self.raffle.end_ts, RaffleError::RaffleNotEnded);
+        require!(
+            clock.unix_timestamp <= self.raffle.reveal_deadline,
+            RaffleError::RevealDeadlinePassed
+        );
+
+        require!(self.raffle.tickets_sold() > 0, RaffleError::NoTickets);
+
+        self.raffle.randomness_slot = clock
+            .slot
+            .checked_add(RANDOMNESS_DELAY_SLOTS)
+            .ok_or(RaffleError::Overflow)?;
+
+        msg!("Raffle sealed: randomness from slot {}", self.raffle.randomness_slot);
+
+        Ok(())
+    }
+}
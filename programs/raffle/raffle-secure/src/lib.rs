@@ -0,0 +1,82 @@
+// Raffle Program - SECURE VERSION
+//
+// Implementation of a token raffle where the whole pot goes to one ticket.
+// This program allows users to:
+// 1. Open a raffle with a ticket price, ticket limit and duration
+// 2. Buy tickets until the raffle ends
+// 3. Seal the raffle after the end, fixing a future randomness slot
+// 4. Draw the winner and pay them the pot
+// 5. Refund every ticket if the creator does not draw by the deadline
+//
+// SECURITY FEATURES:
+// - Commit-reveal randomness: creator commits hash(secret) before any sale
+// - Every ticket purchase mixed into the entropy
+// - Hash of a slot chosen after sales close mixed in, so the creator cannot
+//   grind the last ticket through a second wallet
+// - Creator cannot buy tickets
+// - Draw only after sealing, only by the creator, only once
+// - Permissionless refunds after reveal_deadline, so withholding the secret
+//   never locks the pot
+// - Prize pinned to the winning entrant's token account
+// - Checked arithmetic to prevent overflow/underflow
+//
+// RANDOMNESS:
+// winning ticket = hash(secret || entropy || slot_hash) % tickets_sold
+// entropy        = hash(entropy || buyer || ticket_index) for every ticket
+// slot_hash      = SlotHashes entry for randomness_slot
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("2wD2bps99kmz96Cyq2UEhCiBy6Wg7PyAF8HYWwoUY6kB");
+
+#[program]
+pub mod raffle_secure {
+    use super::*;
+
+    // Open a raffle and commit to the secret that will pick the winner
+    pub fn create_raffle(
+        ctx: Context<CreateRaffle>,
+        seed: u64,
+        ticket_price: u64,
+        max_tickets: u32,
+        duration_seconds: i64,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.create_raffle(
+            seed,
+            ticket_price,
+            max_tickets,
+            duration_seconds,
+            commitment,
+            &ctx.bumps,
+        )
+    }
+
+    // Buy one ticket
+    pub fn buy_ticket(ctx: Context<BuyTicket>) -> Result<()> {
+        ctx.accounts.buy_ticket()
+    }
+
+    // Fix the future slot whose hash seeds the draw
+    pub fn seal_raffle(ctx: Context<SealRaffle>) -> Result<()> {
+        ctx.accounts.seal_raffle()
+    }
+
+    // Reveal the secret and pay the winning ticket
+    pub fn draw_winner(ctx: Context<DrawWinner>, secret: [u8; 32]) -> Result<()> {
+        ctx.accounts.draw_winner(secret)
+    }
+
+    // Refund one ticket of a raffle not drawn by its reveal deadline
+    pub fn refund_ticket(ctx: Context<RefundTicket>, ticket_index: u32) -> Result<()> {
+        ctx.accounts.refund_ticket(ticket_index)
+    }
+}
@@ -0,0 +1,7 @@
+// State Module
+//
+// Exports all state structures used by the raffle program
+
+pub mod raffle;
+
+pub use raffle::*;
@@ -0,0 +1,118 @@
+// Raffle State
+//
+// One raffle: entrants buy tickets at a fixed price until end_ts, and the
+// whole pot goes to a single winning ticket.
+//
+// RANDOMNESS (commit-reveal + future slot hash):
+// - At creation the creator commits to hash(secret) without revealing secret
+// - Every ticket purchase folds the buyer into `entropy`
+// - After end_ts the creator seals the raffle, fixing randomness_slot a few
+//   slots in the future
+// - The creator reveals secret; the winner is
+//   hash(secret || entropy || slot_hash(randomness_slot)) % tickets_sold
+//
+// Buyers cannot predict the result because they never see the secret. The
+// creator knows the secret and the entropy, but the slot hash does not exist
+// until after sales close, so a ticket bought through a second wallet cannot
+// be ground into a win.
+//
+// WITHHOLDING:
+// A creator who dislikes the outcome can refuse to reveal. Past
+// reveal_deadline anyone can refund every ticket, so withholding only cancels
+// the raffle - it never locks the pot. Production raffles should use a VRF.
+
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::{constants::*, errors::*};
+
+#[account]
+#[derive(InitSpace)]
+pub struct Raffle {
+    // Account that created the raffle and reveals the secret
+    pub creator: Pubkey,
+
+    // Mint tickets are paid in (and the prize is paid out in)
+    pub ticket_mint: Pubkey,
+
+    // Vault ATA holding ticket payments (owned by this PDA)
+    pub ticket_vault: Pubkey,
+
+    // Creator-chosen seed so one creator can run several raffles
+    pub seed: u64,
+
+    // Price of one ticket
+    pub ticket_price: u64,
+
+    // Maximum tickets that can be sold (<= MAX_TICKETS)
+    pub max_tickets: u32,
+
+    // Unix timestamp after which no tickets are sold and the winner can be drawn
+    pub end_ts: i64,
+
+    // hash(secret), fixed at creation
+    pub commitment: [u8; 32],
+
+    // Running hash of every ticket purchase
+    pub entropy: [u8; 32],
+
+    // Set once the winner has been drawn and paid
+    pub drawn: bool,
+
+    // Winning entrant (default until drawn)
+    pub winner: Pubkey,
+
+    // end_ts + REVEAL_WINDOW_SECONDS; after it the raffle can only be refunded
+    pub reveal_deadline: i64,
+
+    // Slot whose hash seeds the draw (0 until sealed)
+    pub randomness_slot: u64,
+
+    pub bump: u8,
+
+    // Ticket i belongs to entrants[i] (default once refunded)
+    #[max_len(MAX_TICKETS)]
+    pub entrants: Vec<Pubkey>,
+}
+
+impl Raffle {
+    pub fn tickets_sold(&self) -> u64 {
+        self.entrants.len() as u64
+    }
+
+    // Total prize: ticket_price * tickets_sold
+    pub fn pot(&self) -> Result<u64> {
+        let pot = self
+            .ticket_price
+            .checked_mul(self.tickets_sold())
+            .ok_or(RaffleError::Overflow)?;
+
+        Ok(pot)
+    }
+
+    // Fold a ticket purchase into the entropy
+    // entropy = hash(entropy || buyer || ticket_index)
+    pub fn mix_entropy(&mut self, buyer: &Pubkey) {
+        let ticket_index = self.tickets_sold().to_le_bytes();
+        self.entropy = hashv(&[&self.entropy, buyer.as_ref(), &ticket_index]).to_bytes();
+    }
+
+    pub fn is_sealed(&self) -> bool {
+        self.randomness_slot != 0
+    }
+
+    // Winning ticket index for the revealed secret
+    // index = first 8 bytes of hash(secret || entropy || slot_hash) % tickets_sold
+    pub fn winning_index(&self, secret: &[u8; 32], slot_hash: &[u8; 32]) -> Result<usize> {
+        require!(
+            hashv(&[secret]).to_bytes() == self.commitment,
+            RaffleError::InvalidReveal
+        );
+
+        let randomness = hashv(&[secret, &self.entropy, slot_hash]).to_bytes();
+        let mut value = [0u8; 8];
+        value.copy_from_slice(&randomness[..8]);
+
+        Ok((u64::from_le_bytes(value) % self.tickets_sold()) as usize)
+    }
+}
@@ -0,0 +1,52 @@
+// Compute-unit regression tests for raffle-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+mod utils;
+
+use solana_sdk::{hash::Hash, signature::Signer};
+use soteria_test_kit::{ComputeBudget, DEFAULT_CU_BASELINES_FILE};
+use spl_associated_token_account::get_associated_token_address;
+use utils::*;
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let mut scenario = setup_raffle_scenario();
+    let creator = scenario.creator.pubkey();
+    let mint = scenario.mint;
+
+    let ix = build_create_raffle_ix(
+        &creator,
+        &mint,
+        1,
+        TICKET_PRICE,
+        MAX_TICKETS,
+        RAFFLE_DURATION,
+        commitment(&SECRET),
+    );
+    budget.record("create_raffle", send_ix(&mut scenario.svm, ix, &scenario.creator));
+    let (raffle, _) = derive_raffle_pda(&creator, 1);
+
+    // A single entrant, so the draw has a known winner
+    let entrant = create_entrant(&mut scenario);
+    let ix = build_buy_ticket_ix(&entrant.pubkey(), &raffle, &mint);
+    budget.record("buy_ticket", send_ix(&mut scenario.svm, ix, &entrant));
+
+    advance_time(&mut scenario.svm, RAFFLE_DURATION);
+    let ix = build_seal_raffle_ix(&creator, &raffle);
+    budget.record("seal_raffle", send_ix(&mut scenario.svm, ix, &scenario.creator));
+    let randomness_slot = get_randomness_slot(&scenario.svm, &raffle);
+    produce_slot(&mut scenario.svm, randomness_slot + 1, Hash::new_unique());
+
+    let winner_ata = get_associated_token_address(&entrant.pubkey(), &mint);
+    let ix = build_draw_winner_ix(&creator, &raffle, &mint, &winner_ata, SECRET);
+    budget.record("draw_winner", send_ix(&mut scenario.svm, ix, &scenario.creator));
+
+    budget.check();
+}
@@ -0,0 +1,429 @@
+// Integration tests for raffle program using LiteSVM
+// These tests verify core raffle functionality: creation, ticket sales,
+// the commit-reveal draw, and refunds when the creator withholds the secret
+
+mod utils;
+
+use utils::*;
+use litesvm::{types::TransactionResult, LiteSVM};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    hash::Hash,
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use soteria_test_kit::{
+    run_feature_differential, DifferentialScenario, ExploitInfo, FeatureVariant, Severity,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+#[test]
+fn test_create_raffle() {
+    // Test: Create a raffle and reject invalid parameters
+    println!("\n[TEST START] test_create_raffle - Raffle creation and validation");
+
+    let mut scenario = setup_raffle_scenario();
+    let creator = scenario.creator.pubkey();
+
+    let invalid = [
+        ("zero ticket price", 0, MAX_TICKETS, RAFFLE_DURATION),
+        ("zero max tickets", TICKET_PRICE, 0, RAFFLE_DURATION),
+        ("too many tickets", TICKET_PRICE, 101, RAFFLE_DURATION),
+        ("duration too short", TICKET_PRICE, MAX_TICKETS, 10),
+        ("duration too long", TICKET_PRICE, MAX_TICKETS, 31 * 24 * 60 * 60),
+    ];
+    for (label, price, max_tickets, duration) in invalid {
+        println!("[Action] Creating raffle with {}", label);
+        let ix = build_create_raffle_ix(
+            &creator,
+            &scenario.mint,
+            1,
+            price,
+            max_tickets,
+            duration,
+            commitment(&SECRET),
+        );
+        assert!(send_ix(&mut scenario.svm, ix, &scenario.creator).is_err(), "{} should fail", label);
+        println!("[Success] Raffle with {} rejected", label);
+    }
+
+    println!("[Action] Creating default raffle ({} tickets at {} token)", MAX_TICKETS, TICKET_PRICE / ONE_TOKEN);
+    let raffle = create_default_raffle(&mut scenario, 1);
+
+    assert_eq!(token_balance(&scenario.svm, &derive_vault(&raffle, &scenario.mint)), 0);
+    assert_eq!(get_raffle_result(&scenario.svm, &raffle), (false, Pubkey::default()));
+    println!("[Success] Raffle opened with an empty vault and no winner");
+
+    println!("[TEST END] test_create_raffle");
+}
+
+#[test]
+fn test_buy_ticket() {
+    // Test: Tickets are sold only to non-creators, up to the limit, before the end
+    println!("\n[TEST START] test_buy_ticket - Ticket sales");
+
+    let mut scenario = setup_raffle_scenario();
+    let mint = scenario.mint;
+
+    // Small raffle so it sells out quickly
+    let ix = build_create_raffle_ix(
+        &scenario.creator.pubkey(),
+        &mint,
+        1,
+        TICKET_PRICE,
+        2,
+        RAFFLE_DURATION,
+        commitment(&SECRET),
+    );
+    send_ix(&mut scenario.svm, ix, &scenario.creator).expect("Raffle creation should succeed");
+    let (raffle, _) = derive_raffle_pda(&scenario.creator.pubkey(), 1);
+    let vault = derive_vault(&raffle, &mint);
+    println!("[Setup] Raffle with 2 tickets");
+
+    // The creator knows the secret, so they cannot enter
+    println!("[Action] Creator tries to buy a ticket");
+    let ix = build_buy_ticket_ix(&scenario.creator.pubkey(), &raffle, &mint);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.creator).is_err(), "Creator ticket should fail");
+    println!("[Success] Creator cannot enter their own raffle");
+
+    println!("[Action] Two entrants buy tickets");
+    let first = buy_ticket(&mut scenario, &raffle);
+    buy_ticket(&mut scenario, &raffle);
+    assert_eq!(token_balance(&scenario.svm, &vault), 2 * TICKET_PRICE);
+    assert_eq!(
+        token_balance(&scenario.svm, &get_associated_token_address(&first.pubkey(), &mint)),
+        ENTRANT_BALANCE - TICKET_PRICE
+    );
+    println!("[Success] Ticket prices escrowed in the raffle vault");
+
+    let late = create_entrant(&mut scenario);
+    let ix = build_buy_ticket_ix(&late.pubkey(), &raffle, &mint);
+    assert!(send_ix(&mut scenario.svm, ix, &late).is_err(), "Sold out raffle should reject");
+    println!("[Success] Sold out raffle rejects further tickets");
+
+    // A fresh raffle stops selling at end_ts
+    let open = create_default_raffle(&mut scenario, 2);
+    advance_time(&mut scenario.svm, RAFFLE_DURATION);
+    let ix = build_buy_ticket_ix(&late.pubkey(), &open, &mint);
+    assert!(send_ix(&mut scenario.svm, ix, &late).is_err(), "Ticket after end should fail");
+    println!("[Success] Tickets cannot be bought after the raffle ends");
+
+    println!("[TEST END] test_buy_ticket");
+}
+
+#[test]
+fn test_draw_winner() {
+    // Test: Only the creator can draw, after sealing, with the committed secret,
+    // paying the pot to the winning entrant exactly once
+    println!("\n[TEST START] test_draw_winner - Commit-reveal draw");
+
+    let mut scenario = setup_raffle_scenario();
+    let mint = scenario.mint;
+    let creator = scenario.creator.pubkey();
+    let raffle = create_default_raffle(&mut scenario, 1);
+
+    let entrants: Vec<_> = (0..4).map(|_| buy_ticket(&mut scenario, &raffle)).collect();
+    let pot = 4 * TICKET_PRICE;
+    println!("[Setup] {} tickets sold, pot = {} tokens", entrants.len(), pot / ONE_TOKEN);
+
+    let first_ata = get_associated_token_address(&entrants[0].pubkey(), &mint);
+
+    let ix = build_draw_winner_ix(&creator, &raffle, &mint, &first_ata, SECRET);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.creator).is_err(), "Draw before end should fail");
+    println!("[Success] Draw before the end rejected");
+
+    let ix = build_seal_raffle_ix(&creator, &raffle);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.creator).is_err(), "Seal before end should fail");
+    println!("[Success] Seal before the end rejected");
+
+    advance_time(&mut scenario.svm, RAFFLE_DURATION);
+
+    let ix = build_draw_winner_ix(&creator, &raffle, &mint, &first_ata, SECRET);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.creator).is_err(), "Draw before seal should fail");
+    println!("[Success] Draw before sealing rejected");
+
+    let ix = build_seal_raffle_ix(&entrants[0].pubkey(), &raffle);
+    assert!(send_ix(&mut scenario.svm, ix, &entrants[0]).is_err(), "Non-creator seal should fail");
+    println!("[Success] Seal by non-creator rejected");
+
+    seal_raffle(&mut scenario, &raffle);
+    println!("[Action] Creator sealed the raffle, randomness slot produced");
+
+    let ix = build_seal_raffle_ix(&creator, &raffle);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.creator).is_err(), "Second seal should fail");
+    println!("[Success] Raffle cannot be re-sealed to re-roll the slot");
+
+    let ix = build_draw_winner_ix(&creator, &raffle, &mint, &first_ata, [8u8; 32]);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.creator).is_err(), "Wrong secret should fail");
+    println!("[Success] Reveal that does not match the commitment rejected");
+
+    let ix = build_draw_winner_ix(&entrants[0].pubkey(), &raffle, &mint, &first_ata, SECRET);
+    assert!(send_ix(&mut scenario.svm, ix, &entrants[0]).is_err(), "Non-creator draw should fail");
+    println!("[Success] Draw by non-creator rejected");
+
+    // Only the winning entrant's account is accepted as the prize destination
+    println!("[Action] Creator reveals the secret");
+    let mut winner = None;
+    for entrant in &entrants {
+        let ata = get_associated_token_address(&entrant.pubkey(), &mint);
+        let ix = build_draw_winner_ix(&creator, &raffle, &mint, &ata, SECRET);
+        if send_ix(&mut scenario.svm, ix, &scenario.creator).is_ok() {
+            winner = Some((entrant.pubkey(), ata));
+            break;
+        }
+    }
+    let (winner, winner_ata) = winner.expect("Exactly one entrant should win");
+
+    assert_eq!(token_balance(&scenario.svm, &winner_ata), ENTRANT_BALANCE - TICKET_PRICE + pot);
+    assert_eq!(token_balance(&scenario.svm, &derive_vault(&raffle, &mint)), 0);
+    assert_eq!(get_raffle_result(&scenario.svm, &raffle), (true, winner));
+    println!("[Success] Winner {} paid the {} token pot", winner, pot / ONE_TOKEN);
+
+    let ix = build_draw_winner_ix(&creator, &raffle, &mint, &winner_ata, SECRET);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.creator).is_err(), "Second draw should fail");
+    println!("[Success] Raffle cannot be drawn twice");
+
+    println!("[TEST END] test_draw_winner");
+}
+
+#[test]
+fn test_randomness_slot() {
+    // Test: Sealing fixes a slot that has not been produced yet, and the draw
+    // only runs while that slot's hash is listed in SlotHashes
+    println!("\n[TEST START] test_randomness_slot - Future slot hash");
+
+    let mut scenario = setup_raffle_scenario();
+    let mint = scenario.mint;
+    let creator = scenario.creator.pubkey();
+    let raffle = create_default_raffle(&mut scenario, 1);
+    let entrant = buy_ticket(&mut scenario, &raffle);
+    let entrant_ata = get_associated_token_address(&entrant.pubkey(), &mint);
+
+    advance_time(&mut scenario.svm, RAFFLE_DURATION);
+    produce_slot(&mut scenario.svm, 100, Hash::new_unique());
+
+    let ix = build_seal_raffle_ix(&creator, &raffle);
+    send_ix(&mut scenario.svm, ix, &scenario.creator).expect("Sealing should succeed");
+    let randomness_slot = get_randomness_slot(&scenario.svm, &raffle);
+    assert!(randomness_slot > 100, "Randomness slot must be in the future");
+    println!("[Success] Sealed at slot 100, randomness from slot {}", randomness_slot);
+
+    // The creator knows the secret and the entropy, but not this hash yet
+    let ix = build_draw_winner_ix(&creator, &raffle, &mint, &entrant_ata, SECRET);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.creator).is_err(), "Draw before the slot should fail");
+    println!("[Success] Draw before the randomness slot is produced rejected");
+
+    // Waiting until the hash leaves SlotHashes does not help either
+    produce_slot(&mut scenario.svm, randomness_slot + 600, Hash::new_unique());
+    let ix = build_draw_winner_ix(&creator, &raffle, &mint, &entrant_ata, SECRET);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.creator).is_err(), "Draw after the hash expired should fail");
+    println!("[Success] Draw after the slot hash left SlotHashes rejected");
+
+    println!("[TEST END] test_randomness_slot");
+}
+
+#[test]
+fn test_refund_after_withheld_reveal() {
+    // Test: A creator who never reveals cannot lock the pot; after the reveal
+    // deadline anyone refunds each ticket to its holder, exactly once
+    println!("\n[TEST START] test_refund_after_withheld_reveal - Withholding creator");
+
+    let mut scenario = setup_raffle_scenario();
+    let mint = scenario.mint;
+    let creator = scenario.creator.pubkey();
+    let raffle = create_default_raffle(&mut scenario, 1);
+    let vault = derive_vault(&raffle, &mint);
+
+    let entrants: Vec<_> = (0..3).map(|_| buy_ticket(&mut scenario, &raffle)).collect();
+    let atas: Vec<_> = entrants
+        .iter()
+        .map(|entrant| get_associated_token_address(&entrant.pubkey(), &mint))
+        .collect();
+    println!("[Setup] {} tickets sold", entrants.len());
+
+    advance_time(&mut scenario.svm, RAFFLE_DURATION);
+    seal_raffle(&mut scenario, &raffle);
+    println!("[Action] Creator sealed the raffle, then withholds the secret");
+
+    // A bystander with no ticket triggers the refunds
+    let keeper = create_entrant(&mut scenario);
+
+    let ix = build_refund_ticket_ix(&keeper.pubkey(), &raffle, &mint, &atas[0], 0);
+    assert!(send_ix(&mut scenario.svm, ix, &keeper).is_err(), "Refund inside the reveal window should fail");
+    println!("[Success] Refund before the reveal deadline rejected");
+
+    advance_time(&mut scenario.svm, REVEAL_WINDOW + 1);
+
+    let ix = build_draw_winner_ix(&creator, &raffle, &mint, &atas[0], SECRET);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.creator).is_err(), "Draw after the deadline should fail");
+    println!("[Success] Draw after the reveal deadline rejected");
+
+    let ix = build_refund_ticket_ix(&keeper.pubkey(), &raffle, &mint, &atas[1], 0);
+    assert!(send_ix(&mut scenario.svm, ix, &keeper).is_err(), "Refund to another holder should fail");
+    println!("[Success] Refund to an account that does not hold the ticket rejected");
+
+    println!("[Action] Keeper refunds every ticket");
+    for (index, ata) in atas.iter().enumerate() {
+        let ix = build_refund_ticket_ix(&keeper.pubkey(), &raffle, &mint, ata, index as u32);
+        send_ix(&mut scenario.svm, ix, &keeper).expect("Refund should succeed");
+        assert_eq!(token_balance(&scenario.svm, ata), ENTRANT_BALANCE);
+    }
+    assert_eq!(token_balance(&scenario.svm, &vault), 0);
+    println!("[Success] Every entrant got their ticket price back, vault empty");
+
+    let ix = build_refund_ticket_ix(&keeper.pubkey(), &raffle, &mint, &atas[0], 0);
+    assert!(send_ix(&mut scenario.svm, ix, &keeper).is_err(), "Second refund should fail");
+    let ix = build_refund_ticket_ix(&keeper.pubkey(), &raffle, &mint, &atas[0], 3);
+    assert!(send_ix(&mut scenario.svm, ix, &keeper).is_err(), "Unsold ticket refund should fail");
+    println!("[Success] Tickets cannot be refunded twice or past the last sold");
+
+    println!("[TEST END] test_refund_after_withheld_reveal");
+}
+
+#[test]
+fn test_refund_unsealed_raffle() {
+    // Test: A creator who never even seals is refunded the same way, and a
+    // drawn raffle cannot be refunded
+    println!("\n[TEST START] test_refund_unsealed_raffle - Creator never seals");
+
+    let mut scenario = setup_raffle_scenario();
+    let mint = scenario.mint;
+    let creator = scenario.creator.pubkey();
+
+    let abandoned = create_default_raffle(&mut scenario, 1);
+    let entrant = buy_ticket(&mut scenario, &abandoned);
+    let entrant_ata = get_associated_token_address(&entrant.pubkey(), &mint);
+
+    let drawn = create_default_raffle(&mut scenario, 2);
+    let winner = buy_ticket(&mut scenario, &drawn);
+    let winner_ata = get_associated_token_address(&winner.pubkey(), &mint);
+
+    advance_time(&mut scenario.svm, RAFFLE_DURATION);
+    seal_raffle(&mut scenario, &drawn);
+    let ix = build_draw_winner_ix(&creator, &drawn, &mint, &winner_ata, SECRET);
+    send_ix(&mut scenario.svm, ix, &scenario.creator).expect("Draw should succeed");
+    println!("[Setup] Raffle 1 never sealed, raffle 2 drawn");
+
+    advance_time(&mut scenario.svm, REVEAL_WINDOW + 1);
+
+    let ix = build_seal_raffle_ix(&creator, &abandoned);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.creator).is_err(), "Seal after the deadline should fail");
+    println!("[Success] Seal after the reveal deadline rejected");
+
+    let ix = build_refund_ticket_ix(&entrant.pubkey(), &abandoned, &mint, &entrant_ata, 0);
+    send_ix(&mut scenario.svm, ix, &entrant).expect("Refund should succeed");
+    assert_eq!(token_balance(&scenario.svm, &entrant_ata), ENTRANT_BALANCE);
+    println!("[Success] Unsealed raffle refunded");
+
+    let ix = build_refund_ticket_ix(&winner.pubkey(), &drawn, &mint, &winner_ata, 0);
+    assert!(send_ix(&mut scenario.svm, ix, &winner).is_err(), "Refund of a drawn raffle should fail");
+    println!("[Success] Drawn raffle cannot be refunded");
+
+    println!("[TEST END] test_refund_unsealed_raffle");
+}
+
+// Refund someone else's ticket into the caller's own token account once the
+// reveal deadline has passed
+struct RefundTheft;
+
+struct RefundTheftState {
+    attacker: Keypair,
+    raffle: Pubkey,
+    mint: Pubkey,
+    attacker_ata: Pubkey,
+}
+
+impl DifferentialScenario for RefundTheft {
+    type State = RefundTheftState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "F001",
+            title: "Refund Paid to the Wrong Account",
+            severity: Severity::High,
+            lesson: "Pay a refund only into a token account owned by the ticket holder",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, _program_id: &Pubkey) -> RefundTheftState {
+        let mint_authority = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let creator = create_funded_account(svm, 5 * LAMPORTS_PER_SOL);
+        let victim = create_funded_account(svm, 5 * LAMPORTS_PER_SOL);
+        let attacker = create_funded_account(svm, 5 * LAMPORTS_PER_SOL);
+
+        let mint = CreateMint::new(svm, &mint_authority)
+            .authority(&mint_authority.pubkey())
+            .decimals(DECIMALS)
+            .send()
+            .expect("Failed to create mint");
+        let victim_ata = CreateAssociatedTokenAccount::new(svm, &victim, &mint)
+            .owner(&victim.pubkey())
+            .send()
+            .expect("Failed to create victim ATA");
+        MintTo::new(svm, &mint_authority, &mint, &victim_ata, ENTRANT_BALANCE)
+            .owner(&mint_authority)
+            .send()
+            .expect("Failed to mint to victim");
+        let attacker_ata = CreateAssociatedTokenAccount::new(svm, &attacker, &mint)
+            .owner(&attacker.pubkey())
+            .send()
+            .expect("Failed to create attacker ATA");
+
+        let ix = build_create_raffle_ix(
+            &creator.pubkey(),
+            &mint,
+            1,
+            TICKET_PRICE,
+            MAX_TICKETS,
+            RAFFLE_DURATION,
+            commitment(&SECRET),
+        );
+        send_ix(svm, ix, &creator).expect("Raffle creation should succeed");
+        let raffle = derive_raffle_pda(&creator.pubkey(), 1).0;
+
+        let ix = build_buy_ticket_ix(&victim.pubkey(), &raffle, &mint);
+        send_ix(svm, ix, &victim).expect("Ticket purchase should succeed");
+
+        // The creator never seals, so the ticket becomes refundable
+        advance_time(svm, RAFFLE_DURATION + REVEAL_WINDOW + 1);
+
+        RefundTheftState {
+            attacker,
+            raffle,
+            mint,
+            attacker_ata,
+        }
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        state: &mut RefundTheftState,
+    ) -> TransactionResult {
+        let ix = build_refund_ticket_ix(
+            &state.attacker.pubkey(),
+            &state.raffle,
+            &state.mint,
+            &state.attacker_ata,
+            0,
+        );
+        send_ix(svm, ix, &state.attacker)
+    }
+}
+
+// Feature matrix: only the vuln-missing-refund-owner-check build pays the refund to the attacker
+// Requires ./build-vuln-matrix.sh raffle-secure from the repository root
+#[test]
+fn test_feature_missing_refund_owner_check() {
+    let variant = FeatureVariant {
+        program_id: RAFFLE_PROGRAM_ID,
+        feature: "vuln-missing-refund-owner-check",
+    };
+    let (mut baseline, mut variant_svm) = variant.load(
+        "target/deploy/raffle_secure.so",
+        variant.so_path(".", "raffle_secure"),
+    );
+    run_feature_differential(&mut baseline, &mut variant_svm, &variant, &RefundTheft);
+}
@@ -0,0 +1,343 @@
+// Test utilities for raffle program
+
+use litesvm::{LiteSVM, types::TransactionResult};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo, get_spl_account};
+use litesvm_token::spl_token::state::Account as TokenAccount;
+use solana_sdk::{
+    clock::Clock,
+    hash::{hash, Hash},
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    slot_hashes::SlotHashes,
+    sysvar::slot_hashes,
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::program::ID as system_program;
+
+// Program ID matching declare_id!
+pub const RAFFLE_PROGRAM_ID: Pubkey = Pubkey::new_from_array(raffle_secure::ID.to_bytes());
+
+// Build Anchor instruction discriminator
+// Formula: first 8 bytes of sha256("global:method_name")
+pub fn anchor_discriminator(method: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", method);
+    let hash_result = hash(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash_result.to_bytes()[..8]);
+    discriminator
+}
+
+// Standard program IDs
+pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_account::ID;
+
+// PDA Seeds
+pub const RAFFLE_SEED: &[u8] = b"raffle";
+
+// Token configuration
+pub const DECIMALS: u8 = 9;
+pub const ONE_TOKEN: u64 = 1_000_000_000;
+
+// Default raffle: 10 tickets at 1 token each, open for one hour
+pub const TICKET_PRICE: u64 = ONE_TOKEN;
+pub const MAX_TICKETS: u32 = 10;
+pub const RAFFLE_DURATION: i64 = 60 * 60;
+
+// Matches REVEAL_WINDOW_SECONDS
+pub const REVEAL_WINDOW: i64 = 24 * 60 * 60;
+
+// Secret the creator commits to at creation and reveals at the draw
+pub const SECRET: [u8; 32] = [7u8; 32];
+
+// Tokens every entrant starts with
+pub const ENTRANT_BALANCE: u64 = 100 * ONE_TOKEN;
+
+// Setup LiteSVM with raffle program
+pub fn setup_svm() -> LiteSVM {
+    let mut svm = LiteSVM::new();
+    let program_bytes = include_bytes!("../target/deploy/raffle_secure.so");
+    let _ = svm.add_program(RAFFLE_PROGRAM_ID, program_bytes);
+    svm
+}
+
+// Create and fund account
+pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
+    let keypair = Keypair::new();
+    svm.airdrop(&keypair.pubkey(), lamports)
+        .expect("Airdrop should succeed");
+    keypair
+}
+
+// Sign and send a single instruction
+pub fn send_ix(svm: &mut LiteSVM, ix: Instruction, signer: &Keypair) -> TransactionResult {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    svm.expire_blockhash();
+    result
+}
+
+pub fn advance_time(svm: &mut LiteSVM, seconds: i64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += seconds;
+    svm.set_sysvar::<Clock>(&clock);
+}
+
+// Move the clock to `slot` and list `slot_hash` for `slot - 1` in SlotHashes,
+// as if that slot had just been produced
+pub fn produce_slot(svm: &mut LiteSVM, slot: u64, slot_hash: Hash) {
+    svm.warp_to_slot(slot);
+    svm.set_sysvar::<SlotHashes>(&SlotHashes::new(&[(slot - 1, slot_hash)]));
+}
+
+// Commitment stored at creation: hash(secret)
+pub fn commitment(secret: &[u8; 32]) -> [u8; 32] {
+    hash(secret).to_bytes()
+}
+
+// Derive raffle PDA
+pub fn derive_raffle_pda(creator: &Pubkey, seed: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[RAFFLE_SEED, creator.as_ref(), &seed.to_le_bytes()],
+        &RAFFLE_PROGRAM_ID,
+    )
+}
+
+// Ticket vault: the raffle's ATA
+pub fn derive_vault(raffle: &Pubkey, mint: &Pubkey) -> Pubkey {
+    get_associated_token_address(raffle, mint)
+}
+
+// Build create_raffle instruction
+pub fn build_create_raffle_ix(
+    creator: &Pubkey,
+    mint: &Pubkey,
+    seed: u64,
+    ticket_price: u64,
+    max_tickets: u32,
+    duration_seconds: i64,
+    commitment: [u8; 32],
+) -> Instruction {
+    let (raffle, _) = derive_raffle_pda(creator, seed);
+
+    let mut data = anchor_discriminator("create_raffle").to_vec();
+    data.extend_from_slice(&seed.to_le_bytes());
+    data.extend_from_slice(&ticket_price.to_le_bytes());
+    data.extend_from_slice(&max_tickets.to_le_bytes());
+    data.extend_from_slice(&duration_seconds.to_le_bytes());
+    data.extend_from_slice(&commitment);
+
+    Instruction {
+        program_id: RAFFLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*creator, true),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(raffle, false),
+            AccountMeta::new(derive_vault(&raffle, mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build buy_ticket instruction
+pub fn build_buy_ticket_ix(buyer: &Pubkey, raffle: &Pubkey, mint: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: RAFFLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*buyer, true),
+            AccountMeta::new(*raffle, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(get_associated_token_address(buyer, mint), false),
+            AccountMeta::new(derive_vault(raffle, mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: anchor_discriminator("buy_ticket").to_vec(),
+    }
+}
+
+// Build seal_raffle instruction
+pub fn build_seal_raffle_ix(creator: &Pubkey, raffle: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: RAFFLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*creator, true),
+            AccountMeta::new(*raffle, false),
+        ],
+        data: anchor_discriminator("seal_raffle").to_vec(),
+    }
+}
+
+// Build draw_winner instruction paying winner_token_account
+pub fn build_draw_winner_ix(
+    creator: &Pubkey,
+    raffle: &Pubkey,
+    mint: &Pubkey,
+    winner_token_account: &Pubkey,
+    secret: [u8; 32],
+) -> Instruction {
+    let mut data = anchor_discriminator("draw_winner").to_vec();
+    data.extend_from_slice(&secret);
+
+    Instruction {
+        program_id: RAFFLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*creator, true),
+            AccountMeta::new(*raffle, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(derive_vault(raffle, mint), false),
+            AccountMeta::new(*winner_token_account, false),
+            AccountMeta::new_readonly(slot_hashes::ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Build refund_ticket instruction paying entrant_token_account
+pub fn build_refund_ticket_ix(
+    caller: &Pubkey,
+    raffle: &Pubkey,
+    mint: &Pubkey,
+    entrant_token_account: &Pubkey,
+    ticket_index: u32,
+) -> Instruction {
+    let mut data = anchor_discriminator("refund_ticket").to_vec();
+    data.extend_from_slice(&ticket_index.to_le_bytes());
+
+    Instruction {
+        program_id: RAFFLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*caller, true),
+            AccountMeta::new(*raffle, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(derive_vault(raffle, mint), false),
+            AccountMeta::new(*entrant_token_account, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Read (drawn, winner) from a Raffle
+// Layout: discriminator (8) + creator, ticket_mint, ticket_vault (96) + seed (8)
+//         + ticket_price (8) + max_tickets (4) + end_ts (8) + commitment (32)
+//         + entropy (32) + drawn (1) + winner (32)
+const DRAWN_OFFSET: usize = 8 + 32 * 3 + 8 + 8 + 4 + 8 + 32 + 32;
+
+pub fn get_raffle_result(svm: &LiteSVM, raffle: &Pubkey) -> (bool, Pubkey) {
+    let account = svm.get_account(raffle).expect("Raffle should exist");
+    let offset = DRAWN_OFFSET;
+    let drawn = account.data[offset] != 0;
+    let winner = Pubkey::new_from_array(account.data[offset + 1..offset + 33].try_into().unwrap());
+    (drawn, winner)
+}
+
+// Read randomness_slot from a Raffle
+// Follows winner (32) and reveal_deadline (8)
+pub fn get_randomness_slot(svm: &LiteSVM, raffle: &Pubkey) -> u64 {
+    let account = svm.get_account(raffle).expect("Raffle should exist");
+    let offset = DRAWN_OFFSET + 1 + 32 + 8;
+    u64::from_le_bytes(account.data[offset..offset + 8].try_into().unwrap())
+}
+
+pub fn token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    let account: TokenAccount = get_spl_account(svm, token_account).expect("Token account should exist");
+    account.amount
+}
+
+// Raffle scenario setup result
+pub struct RaffleScenario {
+    pub svm: LiteSVM,
+    pub mint_authority: Keypair,
+    pub creator: Keypair,
+    pub mint: Pubkey,
+}
+
+// Create the mint and a creator wallet holding ENTRANT_BALANCE tokens, so
+// tests show the creator is refused a ticket for who they are, not their balance
+pub fn setup_raffle_scenario() -> RaffleScenario {
+    let mut svm = setup_svm();
+    let mint_authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let creator = create_funded_account(&mut svm, 5 * LAMPORTS_PER_SOL);
+
+    let mint = CreateMint::new(&mut svm, &mint_authority)
+        .authority(&mint_authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create mint");
+
+    let creator_ata = CreateAssociatedTokenAccount::new(&mut svm, &creator, &mint)
+        .owner(&creator.pubkey())
+        .send()
+        .expect("Failed to create creator ATA");
+    MintTo::new(&mut svm, &mint_authority, &mint, &creator_ata, ENTRANT_BALANCE)
+        .owner(&mint_authority)
+        .send()
+        .expect("Failed to mint to creator");
+
+    RaffleScenario {
+        svm,
+        mint_authority,
+        creator,
+        mint,
+    }
+}
+
+// Create a wallet holding ENTRANT_BALANCE tokens of the scenario mint
+pub fn create_entrant(scenario: &mut RaffleScenario) -> Keypair {
+    let entrant = create_funded_account(&mut scenario.svm, 5 * LAMPORTS_PER_SOL);
+
+    let ata = CreateAssociatedTokenAccount::new(&mut scenario.svm, &entrant, &scenario.mint)
+        .owner(&entrant.pubkey())
+        .send()
+        .expect("Failed to create entrant ATA");
+    MintTo::new(&mut scenario.svm, &scenario.mint_authority, &scenario.mint, &ata, ENTRANT_BALANCE)
+        .owner(&scenario.mint_authority)
+        .send()
+        .expect("Failed to mint to entrant");
+
+    entrant
+}
+
+// Open the default raffle committed to SECRET and return its address
+pub fn create_default_raffle(scenario: &mut RaffleScenario, seed: u64) -> Pubkey {
+    let ix = build_create_raffle_ix(
+        &scenario.creator.pubkey(),
+        &scenario.mint,
+        seed,
+        TICKET_PRICE,
+        MAX_TICKETS,
+        RAFFLE_DURATION,
+        commitment(&SECRET),
+    );
+    send_ix(&mut scenario.svm, ix, &scenario.creator).expect("Raffle creation should succeed");
+
+    derive_raffle_pda(&scenario.creator.pubkey(), seed).0
+}
+
+// Have a fresh entrant buy one ticket
+pub fn buy_ticket(scenario: &mut RaffleScenario, raffle: &Pubkey) -> Keypair {
+    let entrant = create_entrant(scenario);
+    let ix = build_buy_ticket_ix(&entrant.pubkey(), raffle, &scenario.mint);
+    send_ix(&mut scenario.svm, ix, &entrant).expect("Ticket purchase should succeed");
+    entrant
+}
+
+// Seal the raffle and produce its randomness slot, so draw_winner can run
+pub fn seal_raffle(scenario: &mut RaffleScenario, raffle: &Pubkey) {
+    let ix = build_seal_raffle_ix(&scenario.creator.pubkey(), raffle);
+    send_ix(&mut scenario.svm, ix, &scenario.creator).expect("Sealing should succeed");
+
+    let randomness_slot = get_randomness_slot(&scenario.svm, raffle);
+    produce_slot(&mut scenario.svm, randomness_slot + 1, Hash::new_unique());
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+pub use soteria_errors::raffle_vulnerable::RaffleError as RaffleErrorCode;
+
+#[error_code]
+pub enum RaffleError {
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Maximum tickets must be between 1 and 100")]
+    InvalidMaxTickets,
+
+    #[msg("Raffle duration is outside the allowed range (1 minute to 30 days)")]
+    InvalidDuration,
+
+    #[msg("Raffle has ended, no more tickets can be bought")]
+    RaffleEnded,
+
+    #[msg("Raffle has not ended yet")]
+    RaffleNotEnded,
+
+    #[msg("All tickets have been sold")]
+    SoldOut,
+
+    #[msg("Raffle creator cannot buy tickets")]
+    CreatorCannotEnter,
+
+    #[msg("Winner has already been drawn")]
+    AlreadyDrawn,
+
+    #[msg("No tickets were sold")]
+    NoTickets,
+
+    #[msg("Token account does not belong to the winner")]
+    InvalidWinnerAccount,
+
+    #[msg("Arithmetic overflow")]
+    Overflow,
+}
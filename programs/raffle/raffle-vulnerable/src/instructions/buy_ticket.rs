@@ -0,0 +1,86 @@
+// Buy Ticket Instruction
+//
+// Buys one ticket in an open raffle.
+//
+// HOW IT WORKS:
+// 1. Checks the raffle is still selling and has tickets left
+// 2. Transfers ticket_price from the buyer into the ticket vault
+// 3. Records the buyer as the owner of the next ticket
+//
+// SECURITY:
+// - Only before end_ts and up to max_tickets
+// - The creator cannot enter their own raffle
+// - Payment source must belong to the buyer
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct BuyTicket<'info> {
+    pub buyer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RAFFLE_SEED, raffle.creator.as_ref(), raffle.seed.to_le_bytes().as_ref()],
+        bump = raffle.bump,
+        has_one = ticket_mint,
+        has_one = ticket_vault,
+    )]
+    pub raffle: Box<Account<'info, Raffle>>,
+
+    pub ticket_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = ticket_mint,
+        token::authority = buyer,
+        token::token_program = token_program,
+    )]
+    pub buyer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub ticket_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> BuyTicket<'info> {
+    pub fn buy_ticket(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(now < self.raffle.end_ts, RaffleError::RaffleEnded);
+
+        require!(
+            self.raffle.tickets_sold() < self.raffle.max_tickets as u64,
+            RaffleError::SoldOut
+        );
+
+        require_keys_neq!(
+            self.buyer.key(),
+            self.raffle.creator,
+            RaffleError::CreatorCannotEnter
+        );
+
+        transfer_tokens(
+            self.raffle.ticket_price,
+            self.ticket_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.buyer_token_account.to_account_info(),
+            &self.ticket_mint.to_account_info(),
+            &self.ticket_vault.to_account_info(),
+            &self.buyer.to_account_info(),
+        )?;
+
+        let buyer = self.buyer.key();
+        self.raffle.entrants.push(buyer);
+
+        msg!(
+            "Ticket {} bought by {}",
+            self.raffle.tickets_sold() - 1,
+            buyer
+        );
+
+        Ok(())
+    }
+}
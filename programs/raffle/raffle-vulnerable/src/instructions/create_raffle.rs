@@ -0,0 +1,100 @@
+// Create Raffle Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// Opens a raffle with a fixed ticket price.
+//
+// VULNERABILITY:
+// V001: No commitment to a secret - the draw has no unpredictable input
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct CreateRaffle<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub ticket_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = ANCHOR_DISCRIMINATOR + Raffle::INIT_SPACE,
+        seeds = [RAFFLE_SEED, creator.key().as_ref(), seed.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub raffle: Box<Account<'info, Raffle>>,
+
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = ticket_mint,
+        associated_token::authority = raffle,
+        associated_token::token_program = token_program,
+    )]
+    pub ticket_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateRaffle<'info> {
+    // VULNERABILITY V001: No commitment parameter
+    // Secure version takes commitment = hash(secret) here, before any ticket
+    // is sold, and checks the revealed secret against it in draw_winner
+    pub fn create_raffle(
+        &mut self,
+        seed: u64,
+        ticket_price: u64,
+        max_tickets: u32,
+        duration_seconds: i64,
+        bumps: &CreateRaffleBumps,
+    ) -> Result<()> {
+        require!(ticket_price > 0, RaffleError::ZeroAmount);
+
+        require!(
+            (1..=MAX_TICKETS).contains(&max_tickets),
+            RaffleError::InvalidMaxTickets
+        );
+
+        require!(
+            (MIN_RAFFLE_DURATION_SECONDS..=MAX_RAFFLE_DURATION_SECONDS).contains(&duration_seconds),
+            RaffleError::InvalidDuration
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let end_ts = now.checked_add(duration_seconds).ok_or(RaffleError::Overflow)?;
+
+        self.raffle.set_inner(Raffle {
+            creator: self.creator.key(),
+            ticket_mint: self.ticket_mint.key(),
+            ticket_vault: self.ticket_vault.key(),
+            seed,
+            ticket_price,
+            max_tickets,
+            end_ts,
+            drawn: false,
+            winner: Pubkey::default(),
+            bump: bumps.raffle,
+            entrants: Vec::new(),
+        });
+
+        msg!(
+            "Raffle created: {} tickets at {} until {}",
+            max_tickets,
+            ticket_price,
+            end_ts
+        );
+
+        Ok(())
+    }
+}
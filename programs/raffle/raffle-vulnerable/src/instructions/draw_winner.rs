@@ -0,0 +1,107 @@
+// Draw Winner Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// Picks the winning ticket and pays it the pot.
+//
+// VULNERABILITIES:
+// V001: Winning ticket = Clock::unix_timestamp % tickets_sold, which anyone
+//       can compute ahead of time
+// V002: Anyone can call draw_winner, so an entrant chooses the exact second
+//       the draw happens - the second in which they win
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct DrawWinner<'info> {
+    // VULNERABILITY V002: Any signer can trigger the draw
+    // Secure version names this account `creator` and checks it:
+    //   seeds = [RAFFLE_SEED, creator.key().as_ref(), ...],
+    //   has_one = creator @ RaffleError::Unauthorized,
+    pub drawer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RAFFLE_SEED, raffle.creator.as_ref(), raffle.seed.to_le_bytes().as_ref()],
+        bump = raffle.bump,
+        has_one = ticket_mint,
+        has_one = ticket_vault,
+    )]
+    pub raffle: Box<Account<'info, Raffle>>,
+
+    pub ticket_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub ticket_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Owner is checked against the winning entrant in the handler
+    #[account(
+        mut,
+        token::mint = ticket_mint,
+        token::token_program = token_program,
+    )]
+    pub winner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> DrawWinner<'info> {
+    pub fn draw_winner(&mut self) -> Result<()> {
+        require!(!self.raffle.drawn, RaffleError::AlreadyDrawn);
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= self.raffle.end_ts, RaffleError::RaffleNotEnded);
+
+        require!(self.raffle.tickets_sold() > 0, RaffleError::NoTickets);
+
+        // VULNERABILITY V001 + V002: Winner chosen by the caller's timing
+        // Secure version: creator reveals a secret matching the commitment and
+        // the index is hash(secret || entropy) % tickets_sold
+        // Attack scenario (predicted winner):
+        // 1. Three honest users buy tickets 0-2, attacker buys ticket 3
+        // 2. Raffle ends; attacker waits for a second t with t % 4 == 3
+        // 3. Attacker calls draw_winner in that second, passing their own
+        //    token account as the winner account
+        // 4. winning_index(t) == 3, the owner check passes, attacker takes
+        //    the whole pot with a single ticket
+        let winning_index = self.raffle.winning_index(now);
+        let winner = self.raffle.entrants[winning_index];
+
+        require_keys_eq!(
+            self.winner_token_account.owner,
+            winner,
+            RaffleError::InvalidWinnerAccount
+        );
+
+        let pot = self.raffle.pot()?;
+
+        let seed_bytes = self.raffle.seed.to_le_bytes();
+        let raffle_seeds = &[
+            RAFFLE_SEED,
+            self.raffle.creator.as_ref(),
+            seed_bytes.as_ref(),
+            &[self.raffle.bump],
+        ];
+
+        transfer_from_vault(
+            pot,
+            self.ticket_mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.ticket_vault.to_account_info(),
+            &self.ticket_mint.to_account_info(),
+            &self.winner_token_account.to_account_info(),
+            &self.raffle.to_account_info(),
+            raffle_seeds,
+        )?;
+
+        self.raffle.drawn = true;
+        self.raffle.winner = winner;
+
+        msg!("Ticket {} won: {} paid {}", winning_index, winner, pot);
+
+        Ok(())
+    }
+}
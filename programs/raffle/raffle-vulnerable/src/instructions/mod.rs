@@ -0,0 +1,11 @@
+// Instructions Module
+//
+// Exports all instruction handlers for the raffle program
+
+pub mod create_raffle;
+pub mod buy_ticket;
+pub mod draw_winner;
+
+pub use create_raffle::*;
+pub use buy_ticket::*;
+pub use draw_winner::*;
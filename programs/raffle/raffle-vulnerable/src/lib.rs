@@ -0,0 +1,58 @@
+// Raffle Program - VULNERABLE VERSION
+//
+// WARNING: This program contains intentional security vulnerabilities for educational purposes.
+// NEVER deploy this to production. Use raffle-secure instead.
+//
+// This vulnerable raffle demonstrates two classic on-chain randomness flaws:
+// - Winner derived from the clock, which every caller can read ahead of time
+// - Anyone can trigger the draw, choosing the moment that favours them
+//
+// VULNERABILITIES DOCUMENTED:
+// See individual instruction files for detailed vulnerability explanations.
+// Each vulnerability is marked with VULNERABILITY comments.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("AzZN5KNhY348ESpc3oW9xHUbtUH3vprHj1Epu8zCZMtD");
+
+#[program]
+pub mod raffle_vulnerable {
+    use super::*;
+
+    // Open a raffle
+    // VULNERABILITY: No commitment to a secret, the draw has no unpredictable input
+    pub fn create_raffle(
+        ctx: Context<CreateRaffle>,
+        seed: u64,
+        ticket_price: u64,
+        max_tickets: u32,
+        duration_seconds: i64,
+    ) -> Result<()> {
+        ctx.accounts.create_raffle(
+            seed,
+            ticket_price,
+            max_tickets,
+            duration_seconds,
+            &ctx.bumps,
+        )
+    }
+
+    // Buy one ticket
+    pub fn buy_ticket(ctx: Context<BuyTicket>) -> Result<()> {
+        ctx.accounts.buy_ticket()
+    }
+
+    // Pick the winning ticket and pay it the pot
+    // VULNERABILITY: Winner = unix_timestamp % tickets_sold, callable by anyone
+    pub fn draw_winner(ctx: Context<DrawWinner>) -> Result<()> {
+        ctx.accounts.draw_winner()
+    }
+}
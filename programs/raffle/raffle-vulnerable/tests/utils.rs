@@ -0,0 +1,253 @@
+// Test utilities for raffle program
+
+use litesvm::{LiteSVM, types::TransactionResult};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo, get_spl_account};
+use litesvm_token::spl_token::state::Account as TokenAccount;
+use solana_sdk::{
+    clock::Clock,
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::program::ID as system_program;
+
+// Program ID matching declare_id!
+pub const RAFFLE_PROGRAM_ID: Pubkey = Pubkey::new_from_array(raffle_vulnerable::ID.to_bytes());
+
+// Build Anchor instruction discriminator
+// Formula: first 8 bytes of sha256("global:method_name")
+pub fn anchor_discriminator(method: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", method);
+    let hash_result = hash(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash_result.to_bytes()[..8]);
+    discriminator
+}
+
+// Standard program IDs
+pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_account::ID;
+
+// PDA Seeds
+pub const RAFFLE_SEED: &[u8] = b"raffle";
+
+// Token configuration
+pub const DECIMALS: u8 = 9;
+pub const ONE_TOKEN: u64 = 1_000_000_000;
+
+// Default raffle: 10 tickets at 1 token each, open for one hour
+pub const TICKET_PRICE: u64 = ONE_TOKEN;
+pub const MAX_TICKETS: u32 = 10;
+pub const RAFFLE_DURATION: i64 = 60 * 60;
+
+// Tokens every entrant starts with
+pub const ENTRANT_BALANCE: u64 = 100 * ONE_TOKEN;
+
+// Setup LiteSVM with raffle program
+pub fn setup_svm() -> LiteSVM {
+    let mut svm = LiteSVM::new();
+    let program_bytes = include_bytes!("../target/deploy/raffle_vulnerable.so");
+    let _ = svm.add_program(RAFFLE_PROGRAM_ID, program_bytes);
+    svm
+}
+
+// Create and fund account
+pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
+    let keypair = Keypair::new();
+    svm.airdrop(&keypair.pubkey(), lamports)
+        .expect("Airdrop should succeed");
+    keypair
+}
+
+// Sign and send a single instruction
+pub fn send_ix(svm: &mut LiteSVM, ix: Instruction, signer: &Keypair) -> TransactionResult {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    svm.expire_blockhash();
+    result
+}
+
+pub fn current_timestamp(svm: &LiteSVM) -> i64 {
+    svm.get_sysvar::<Clock>().unix_timestamp
+}
+
+pub fn advance_time(svm: &mut LiteSVM, seconds: i64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += seconds;
+    svm.set_sysvar::<Clock>(&clock);
+}
+
+// Derive raffle PDA
+pub fn derive_raffle_pda(creator: &Pubkey, seed: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[RAFFLE_SEED, creator.as_ref(), &seed.to_le_bytes()],
+        &RAFFLE_PROGRAM_ID,
+    )
+}
+
+// Ticket vault: the raffle's ATA
+pub fn derive_vault(raffle: &Pubkey, mint: &Pubkey) -> Pubkey {
+    get_associated_token_address(raffle, mint)
+}
+
+// Build create_raffle instruction
+pub fn build_create_raffle_ix(
+    creator: &Pubkey,
+    mint: &Pubkey,
+    seed: u64,
+    ticket_price: u64,
+    max_tickets: u32,
+    duration_seconds: i64,
+) -> Instruction {
+    let (raffle, _) = derive_raffle_pda(creator, seed);
+
+    let mut data = anchor_discriminator("create_raffle").to_vec();
+    data.extend_from_slice(&seed.to_le_bytes());
+    data.extend_from_slice(&ticket_price.to_le_bytes());
+    data.extend_from_slice(&max_tickets.to_le_bytes());
+    data.extend_from_slice(&duration_seconds.to_le_bytes());
+
+    Instruction {
+        program_id: RAFFLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*creator, true),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(raffle, false),
+            AccountMeta::new(derive_vault(&raffle, mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build buy_ticket instruction
+pub fn build_buy_ticket_ix(buyer: &Pubkey, raffle: &Pubkey, mint: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: RAFFLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*buyer, true),
+            AccountMeta::new(*raffle, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(get_associated_token_address(buyer, mint), false),
+            AccountMeta::new(derive_vault(raffle, mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: anchor_discriminator("buy_ticket").to_vec(),
+    }
+}
+
+// Build draw_winner instruction paying winner_token_account
+pub fn build_draw_winner_ix(
+    drawer: &Pubkey,
+    raffle: &Pubkey,
+    mint: &Pubkey,
+    winner_token_account: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: RAFFLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*drawer, true),
+            AccountMeta::new(*raffle, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(derive_vault(raffle, mint), false),
+            AccountMeta::new(*winner_token_account, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: anchor_discriminator("draw_winner").to_vec(),
+    }
+}
+
+// Read (drawn, winner) from a Raffle
+// Layout: discriminator (8) + creator, ticket_mint, ticket_vault (96) + seed (8)
+//         + ticket_price (8) + max_tickets (4) + end_ts (8) + drawn (1) + winner (32)
+pub fn get_raffle_result(svm: &LiteSVM, raffle: &Pubkey) -> (bool, Pubkey) {
+    let account = svm.get_account(raffle).expect("Raffle should exist");
+    let offset = 8 + 32 * 3 + 8 + 8 + 4 + 8;
+    let drawn = account.data[offset] != 0;
+    let winner = Pubkey::new_from_array(account.data[offset + 1..offset + 33].try_into().unwrap());
+    (drawn, winner)
+}
+
+pub fn token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    let account: TokenAccount = get_spl_account(svm, token_account).expect("Token account should exist");
+    account.amount
+}
+
+// Raffle scenario setup result
+pub struct RaffleScenario {
+    pub svm: LiteSVM,
+    pub mint_authority: Keypair,
+    pub creator: Keypair,
+    pub mint: Pubkey,
+}
+
+// Create the mint and a creator wallet
+pub fn setup_raffle_scenario() -> RaffleScenario {
+    let mut svm = setup_svm();
+    let mint_authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let creator = create_funded_account(&mut svm, 5 * LAMPORTS_PER_SOL);
+
+    let mint = CreateMint::new(&mut svm, &mint_authority)
+        .authority(&mint_authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create mint");
+
+
+    RaffleScenario {
+        svm,
+        mint_authority,
+        creator,
+        mint,
+    }
+}
+
+// Create a wallet holding ENTRANT_BALANCE tokens of the scenario mint
+pub fn create_entrant(scenario: &mut RaffleScenario) -> Keypair {
+    let entrant = create_funded_account(&mut scenario.svm, 5 * LAMPORTS_PER_SOL);
+
+    let ata = CreateAssociatedTokenAccount::new(&mut scenario.svm, &entrant, &scenario.mint)
+        .owner(&entrant.pubkey())
+        .send()
+        .expect("Failed to create entrant ATA");
+    MintTo::new(&mut scenario.svm, &scenario.mint_authority, &scenario.mint, &ata, ENTRANT_BALANCE)
+        .owner(&scenario.mint_authority)
+        .send()
+        .expect("Failed to mint to entrant");
+
+    entrant
+}
+
+// Open the default raffle and return its address
+pub fn create_default_raffle(scenario: &mut RaffleScenario, seed: u64) -> Pubkey {
+    let ix = build_create_raffle_ix(
+        &scenario.creator.pubkey(),
+        &scenario.mint,
+        seed,
+        TICKET_PRICE,
+        MAX_TICKETS,
+        RAFFLE_DURATION,
+    );
+    send_ix(&mut scenario.svm, ix, &scenario.creator).expect("Raffle creation should succeed");
+
+    derive_raffle_pda(&scenario.creator.pubkey(), seed).0
+}
+
+// Have a fresh entrant buy one ticket
+pub fn buy_ticket(scenario: &mut RaffleScenario, raffle: &Pubkey) -> Keypair {
+    let entrant = create_entrant(scenario);
+    let ix = build_buy_ticket_ix(&entrant.pubkey(), raffle, &scenario.mint);
+    send_ix(&mut scenario.svm, ix, &entrant).expect("Ticket purchase should succeed");
+    entrant
+}
@@ -0,0 +1,14 @@
+// Seed for stream PDA
+// Derived with: [STREAM_SEED, sender_pubkey, recipient_pubkey, seed.to_le_bytes()]
+pub const STREAM_SEED: &[u8] = b"stream";
+
+// Seed for the treasury PDA (authority over the pooled per-mint vaults)
+// Derived with: [TREASURY_SEED]
+pub const TREASURY_SEED: &[u8] = b"treasury";
+
+// STREAM LIMITS
+
+// Maximum time between start and stop of a stream (10 years)
+pub const MAX_STREAM_DURATION_SECONDS: i64 = 10 * 365 * 24 * 60 * 60;
+
+pub const ANCHOR_DISCRIMINATOR: usize = 8;
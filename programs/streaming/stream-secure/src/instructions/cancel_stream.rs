@@ -0,0 +1,145 @@
+// Cancel Stream Instruction
+//
+// Stops a stream early, settling both sides.
+//
+// HOW IT WORKS:
+// 1. Computes the streamed amount at the current time
+// 2. Pays the recipient anything streamed but not yet withdrawn
+// 3. Refunds the sender everything that has not streamed yet
+// 4. Freezes the stream at the streamed amount
+//
+// SECURITY:
+// - Only the sender can cancel (signer + has_one + PDA seeds)
+// - Refund destination must belong to the sender
+// - Recipient's share goes to an account owned by the recorded recipient,
+//   so cancelling can never take back what has already streamed
+// - Cancels at most once
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct CancelStream<'info> {
+    pub sender: Signer<'info>,
+
+    #[cfg_attr(not(feature = "vuln-missing-sender-check"), account(
+        mut,
+        seeds = [
+            STREAM_SEED,
+            sender.key().as_ref(),
+            stream.recipient.as_ref(),
+            stream.seed.to_le_bytes().as_ref(),
+        ],
+        bump = stream.bump,
+        has_one = sender @ StreamError::Unauthorized,
+        has_one = mint,
+    ))]
+    // vuln-missing-sender-check: seeds from the stream itself, any signer may cancel
+    #[cfg_attr(feature = "vuln-missing-sender-check", account(
+        mut,
+        seeds = [
+            STREAM_SEED,
+            stream.sender.as_ref(),
+            stream.recipient.as_ref(),
+            stream.seed.to_le_bytes().as_ref(),
+        ],
+        bump = stream.bump,
+        has_one = mint,
+    ))]
+    pub stream: Box<Account<'info, Stream>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: PDA signer for the pooled vaults, validated by seeds
+    #[account(seeds = [TREASURY_SEED], bump = stream.treasury_bump)]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = sender,
+        token::token_program = token_program,
+    )]
+    pub sender_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = stream.recipient,
+        token::token_program = token_program,
+    )]
+    pub recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CancelStream<'info> {
+    pub fn cancel_stream(&mut self) -> Result<()> {
+        require!(!self.stream.cancelled, StreamError::StreamCancelled);
+
+        let now = Clock::get()?.unix_timestamp;
+
+        let streamed = self.stream.streamed_amount(now)?;
+        let owed_to_recipient = streamed
+            .checked_sub(self.stream.withdrawn_amount)
+            .ok_or(StreamError::Underflow)?;
+        let refund = self
+            .stream
+            .deposited_amount
+            .checked_sub(streamed)
+            .ok_or(StreamError::Underflow)?;
+
+        let treasury_seeds = &[TREASURY_SEED, &[self.stream.treasury_bump]];
+
+        // Settle what has already streamed
+        if owed_to_recipient > 0 {
+            transfer_from_vault(
+                owed_to_recipient,
+                self.mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.vault.to_account_info(),
+                &self.mint.to_account_info(),
+                &self.recipient_token_account.to_account_info(),
+                &self.treasury.to_account_info(),
+                treasury_seeds,
+            )?;
+        }
+
+        // Return the rest to the sender
+        if refund > 0 {
+            transfer_from_vault(
+                refund,
+                self.mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.vault.to_account_info(),
+                &self.mint.to_account_info(),
+                &self.sender_token_account.to_account_info(),
+                &self.treasury.to_account_info(),
+                treasury_seeds,
+            )?;
+        }
+
+        // Freeze the stream: everything streamed has been paid out
+        self.stream.deposited_amount = streamed;
+        self.stream.withdrawn_amount = streamed;
+        self.stream.cancelled = true;
+
+        msg!(
+            "Stream cancelled: {} paid to recipient, {} refunded to sender",
+            owed_to_recipient,
+            refund
+        );
+
+        Ok(())
+    }
+}
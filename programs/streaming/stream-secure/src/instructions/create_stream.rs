@@ -0,0 +1,142 @@
+// Create Stream Instruction
+//
+// Opens a stream that pays the recipient rate_per_second tokens every second
+// between start_ts and stop_ts.
+//
+// HOW IT WORKS:
+// 1. Validates the rate and time range
+// 2. Computes the deposit: rate_per_second * (stop_ts - start_ts)
+// 3. Creates the stream PDA (and the pooled vault for this mint on first use)
+// 4. Transfers the deposit from the sender into the pooled vault
+//
+// SECURITY:
+// - rate_per_second > 0
+// - now <= start_ts < stop_ts and stop_ts - start_ts <= MAX_STREAM_DURATION_SECONDS
+// - Deposit computed with checked multiplication: a rate that overflows is
+//   rejected instead of wrapping to a tiny deposit
+// - Vault pinned to the treasury ATA for this mint
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct CreateStream<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    /// CHECK: Only recorded as the stream's recipient, never read or written
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = sender,
+        token::token_program = token_program,
+    )]
+    pub sender_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = sender,
+        space = ANCHOR_DISCRIMINATOR + Stream::INIT_SPACE,
+        seeds = [
+            STREAM_SEED,
+            sender.key().as_ref(),
+            recipient.key().as_ref(),
+            seed.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub stream: Box<Account<'info, Stream>>,
+
+    /// CHECK: PDA signer for the pooled vaults
+    #[account(seeds = [TREASURY_SEED], bump)]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = sender,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateStream<'info> {
+    pub fn create_stream(
+        &mut self,
+        seed: u64,
+        rate_per_second: u64,
+        start_ts: i64,
+        stop_ts: i64,
+        bumps: &CreateStreamBumps,
+    ) -> Result<()> {
+        require!(rate_per_second > 0, StreamError::ZeroAmount);
+        require!(start_ts < stop_ts, StreamError::InvalidTimeRange);
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(start_ts >= now, StreamError::StartInPast);
+
+        let duration = stop_ts.checked_sub(start_ts).ok_or(StreamError::Underflow)?;
+        require!(
+            duration <= MAX_STREAM_DURATION_SECONDS,
+            StreamError::DurationTooLong
+        );
+
+        // Deposit covers the whole stream; overflow means the rate is impossible to fund
+        #[cfg(not(feature = "vuln-unchecked-math"))]
+        let deposit = rate_per_second
+            .checked_mul(duration as u64)
+            .ok_or(StreamError::Overflow)?;
+        #[cfg(feature = "vuln-unchecked-math")]
+        let deposit = rate_per_second.wrapping_mul(duration as u64);
+
+        self.stream.set_inner(Stream {
+            sender: self.sender.key(),
+            recipient: self.recipient.key(),
+            mint: self.mint.key(),
+            seed,
+            deposited_amount: deposit,
+            withdrawn_amount: 0,
+            rate_per_second,
+            start_ts,
+            stop_ts,
+            cancelled: false,
+            bump: bumps.stream,
+            treasury_bump: bumps.treasury,
+        });
+
+        transfer_tokens(
+            deposit,
+            self.mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.sender_token_account.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.vault.to_account_info(),
+            &self.sender.to_account_info(),
+        )?;
+
+        msg!(
+            "Stream created: {} per second to {} ({} deposited)",
+            rate_per_second,
+            self.recipient.key(),
+            deposit
+        );
+
+        Ok(())
+    }
+}
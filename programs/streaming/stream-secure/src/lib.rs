@@ -0,0 +1,57 @@
+// Payment Streaming Program - SECURE VERSION
+//
+// Implementation of Sablier-style token streaming: a sender deposits up front
+// and the recipient's balance grows every second until the stream stops.
+// This program allows users to:
+// 1. Open a stream paying a fixed rate per second between two timestamps
+// 2. Let the recipient withdraw whatever has streamed so far
+// 3. Cancel a stream, paying out the streamed part and refunding the rest
+//
+// SECURITY FEATURES:
+// - Checked rate * time multiplication for deposits and streamed amounts
+// - Only the recipient can withdraw, only the sender can cancel
+// - Every payout pinned to the recorded sender or recipient
+// - Per-stream accounting keeps pooled vault funds separated
+// - Checked arithmetic to prevent overflow/underflow
+//
+// STREAM MODEL:
+// deposit  = rate_per_second * (stop_ts - start_ts)
+// streamed = rate_per_second * (min(now, stop_ts) - start_ts)
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("HF4KPQbgzbkcYw9ciGyY67vE8nSEJR9M7AphoYhZt7wb");
+
+#[program]
+pub mod stream_secure {
+    use super::*;
+
+    // Deposit funds and open a stream to a recipient
+    pub fn create_stream(
+        ctx: Context<CreateStream>,
+        seed: u64,
+        rate_per_second: u64,
+        start_ts: i64,
+        stop_ts: i64,
+    ) -> Result<()> {
+        ctx.accounts.create_stream(seed, rate_per_second, start_ts, stop_ts, &ctx.bumps)
+    }
+
+    // Withdraw tokens that have already streamed
+    pub fn withdraw_from_stream(ctx: Context<WithdrawFromStream>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw_from_stream(amount)
+    }
+
+    // Stop a stream early and settle both sides
+    pub fn cancel_stream(ctx: Context<CancelStream>) -> Result<()> {
+        ctx.accounts.cancel_stream()
+    }
+}
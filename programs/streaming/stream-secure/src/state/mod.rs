@@ -0,0 +1,7 @@
+// State Module
+//
+// Exports all state structures used by the streaming program
+
+pub mod stream;
+
+pub use stream::*;
@@ -0,0 +1,46 @@
+// Compute-unit regression tests for stream-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+mod utils;
+
+use solana_sdk::signature::Signer;
+use soteria_test_kit::{ComputeBudget, DEFAULT_CU_BASELINES_FILE};
+use utils::*;
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let mut scenario = setup_stream_scenario();
+    let sender = scenario.sender.pubkey();
+    let recipient = scenario.recipient.pubkey();
+
+    let start_ts = current_timestamp(&scenario.svm) + START_DELAY;
+    let ix = build_create_stream_ix(
+        &sender,
+        &recipient,
+        &scenario.mint,
+        1,
+        RATE_PER_SECOND,
+        start_ts,
+        start_ts + STREAM_DURATION,
+    );
+    budget.record("create_stream", send_ix(&mut scenario.svm, ix, &scenario.sender));
+    let (stream, _) = derive_stream_pda(&sender, &recipient, 1);
+
+    // Halfway through the stream
+    advance_time(&mut scenario.svm, START_DELAY + STREAM_DURATION / 2);
+    let ix = build_withdraw_ix(&recipient, &stream, &scenario.mint, 10 * ONE_TOKEN);
+    budget.record("withdraw_from_stream", send_ix(&mut scenario.svm, ix, &scenario.recipient));
+
+    // Pays the recipient's streamed balance and refunds the rest to the sender
+    let ix = build_cancel_stream_ix(&sender, &stream, &scenario.mint, &recipient);
+    budget.record("cancel_stream", send_ix(&mut scenario.svm, ix, &scenario.sender));
+
+    budget.check();
+}
@@ -0,0 +1,184 @@
+// Integration tests for payment streaming program using LiteSVM
+// These tests verify core streaming functionality: creation, per-second
+// withdrawals, overflow rejection, and cancellation
+
+mod utils;
+
+use utils::*;
+use solana_sdk::signature::Signer;
+
+#[test]
+fn test_create_stream() {
+    // Test: Create a stream and reject invalid time ranges
+    println!("\n[TEST START] test_create_stream - Stream creation and validation");
+
+    let mut scenario = setup_stream_scenario();
+    let now = current_timestamp(&scenario.svm);
+    println!("[Setup] Sender holds {} tokens", SENDER_BALANCE / ONE_TOKEN);
+
+    // Stop before start is rejected
+    println!("[Action] Creating stream that stops before it starts");
+    let ix = build_create_stream_ix(
+        &scenario.sender.pubkey(),
+        &scenario.recipient.pubkey(),
+        &scenario.mint,
+        1,
+        RATE_PER_SECOND,
+        now + 100,
+        now + 50,
+    );
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.sender).is_err(), "Inverted range should fail");
+    println!("[Success] Inverted time range rejected");
+
+    // Start in the past is rejected
+    println!("[Action] Creating stream that started in the past");
+    advance_time(&mut scenario.svm, 1_000);
+    let ix = build_create_stream_ix(
+        &scenario.sender.pubkey(),
+        &scenario.recipient.pubkey(),
+        &scenario.mint,
+        1,
+        RATE_PER_SECOND,
+        now,
+        now + 2_000,
+    );
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.sender).is_err(), "Past start should fail");
+    println!("[Success] Past start rejected");
+
+    println!("[Action] Creating default stream ({} tokens over {}s)", STREAM_DEPOSIT / ONE_TOKEN, STREAM_DURATION);
+    let (stream, _) = create_default_stream(&mut scenario, 1);
+
+    assert_eq!(token_balance(&scenario.svm, &derive_vault(&scenario.mint)), STREAM_DEPOSIT);
+    assert_eq!(token_balance(&scenario.svm, &scenario.sender_ata), SENDER_BALANCE - STREAM_DEPOSIT);
+    assert_eq!(get_stream_amounts(&scenario.svm, &stream), (STREAM_DEPOSIT, 0));
+    println!("[Success] Deposit of rate * duration escrowed in the pooled vault");
+
+    println!("[TEST END] test_create_stream");
+}
+
+#[test]
+fn test_rate_overflow_rejected() {
+    // Test: A rate whose deposit overflows u64 is rejected, not wrapped
+    println!("\n[TEST START] test_rate_overflow_rejected - Checked rate * duration");
+
+    let mut scenario = setup_stream_scenario();
+    let start_ts = current_timestamp(&scenario.svm) + START_DELAY;
+
+    // 2^63 * 2 = 2^64 wraps to 0 with unchecked multiplication
+    let rate = 1u64 << 63;
+    println!("[Action] Creating stream with rate {} for 2 seconds", rate);
+    let ix = build_create_stream_ix(
+        &scenario.sender.pubkey(),
+        &scenario.recipient.pubkey(),
+        &scenario.mint,
+        1,
+        rate,
+        start_ts,
+        start_ts + 2,
+    );
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.sender).is_err(), "Overflowing deposit should fail");
+    assert_eq!(token_balance(&scenario.svm, &scenario.sender_ata), SENDER_BALANCE);
+    println!("[Success] Overflowing rate rejected, no stream opened");
+
+    println!("[TEST END] test_rate_overflow_rejected");
+}
+
+#[test]
+fn test_withdraw_streamed_amount() {
+    // Test: Recipient withdraws only what has streamed so far
+    println!("\n[TEST START] test_withdraw_streamed_amount - Per-second withdrawals");
+
+    let mut scenario = setup_stream_scenario();
+    let (stream, start_ts) = create_default_stream(&mut scenario, 1);
+    let recipient = scenario.recipient.pubkey();
+
+    // Nothing is withdrawable before the start
+    let ix = build_withdraw_ix(&recipient, &stream, &scenario.mint, 1);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.recipient).is_err(), "Withdraw before start should fail");
+    println!("[Success] Withdraw before start rejected");
+
+    // Move to 25% of the stream
+    let elapsed = STREAM_DURATION / 4;
+    advance_time(&mut scenario.svm, start_ts + elapsed - current_timestamp(&scenario.svm));
+    let streamed = RATE_PER_SECOND * elapsed as u64;
+    println!("[Setup] {}s into the stream, {} tokens streamed", elapsed, streamed / ONE_TOKEN);
+
+    let ix = build_withdraw_ix(&recipient, &stream, &scenario.mint, streamed + 1);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.recipient).is_err(), "Over-withdraw should fail");
+    println!("[Success] Withdrawing more than streamed rejected");
+
+    // Only the recipient can withdraw
+    let outsider = create_token_holder(&mut scenario, 0);
+    let mut ix = build_withdraw_ix(&outsider.pubkey(), &stream, &scenario.mint, streamed);
+    ix.accounts[5].pubkey = scenario.recipient_ata;
+    assert!(send_ix(&mut scenario.svm, ix, &outsider).is_err(), "Outsider withdraw should fail");
+    println!("[Success] Withdraw by non-recipient rejected");
+
+    println!("[Action] Recipient withdraws {} tokens", streamed / ONE_TOKEN);
+    let ix = build_withdraw_ix(&recipient, &stream, &scenario.mint, streamed);
+    let result = send_ix(&mut scenario.svm, ix, &scenario.recipient);
+    assert!(result.is_ok(), "Withdraw failed: {:?}", result.err());
+    assert_eq!(token_balance(&scenario.svm, &scenario.recipient_ata), streamed);
+    assert_eq!(get_stream_amounts(&scenario.svm, &stream), (STREAM_DEPOSIT, streamed));
+    println!("[Success] Streamed amount withdrawn");
+
+    // Long after the stop, only the remaining deposit is available
+    advance_time(&mut scenario.svm, STREAM_DURATION * 10);
+    let remaining = STREAM_DEPOSIT - streamed;
+    let ix = build_withdraw_ix(&recipient, &stream, &scenario.mint, remaining + 1);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.recipient).is_err(), "Withdraw past deposit should fail");
+
+    let ix = build_withdraw_ix(&recipient, &stream, &scenario.mint, remaining);
+    let result = send_ix(&mut scenario.svm, ix, &scenario.recipient);
+    assert!(result.is_ok(), "Final withdraw failed: {:?}", result.err());
+    assert_eq!(token_balance(&scenario.svm, &scenario.recipient_ata), STREAM_DEPOSIT);
+    assert_eq!(token_balance(&scenario.svm, &derive_vault(&scenario.mint)), 0);
+    println!("[Success] Streaming stops at stop_ts, full deposit withdrawn");
+
+    println!("[TEST END] test_withdraw_streamed_amount");
+}
+
+#[test]
+fn test_cancel_stream() {
+    // Test: Cancelling pays the streamed part and refunds the rest
+    println!("\n[TEST START] test_cancel_stream - Cancellation settlement");
+
+    let mut scenario = setup_stream_scenario();
+    let (stream, start_ts) = create_default_stream(&mut scenario, 1);
+    let sender = scenario.sender.pubkey();
+    let recipient = scenario.recipient.pubkey();
+
+    // Move to 40% of the stream
+    let elapsed = STREAM_DURATION * 2 / 5;
+    advance_time(&mut scenario.svm, start_ts + elapsed - current_timestamp(&scenario.svm));
+    let streamed = RATE_PER_SECOND * elapsed as u64;
+    println!("[Setup] {}s into the stream, {} tokens streamed", elapsed, streamed / ONE_TOKEN);
+
+    // Only the sender can cancel
+    let outsider = create_token_holder(&mut scenario, 0);
+    let mut ix = build_cancel_stream_ix(&outsider.pubkey(), &stream, &scenario.mint, &recipient);
+    ix.accounts[5].pubkey = scenario.sender_ata;
+    assert!(send_ix(&mut scenario.svm, ix, &outsider).is_err(), "Outsider cancel should fail");
+    println!("[Success] Cancel by non-sender rejected");
+
+    println!("[Action] Sender cancels the stream");
+    let ix = build_cancel_stream_ix(&sender, &stream, &scenario.mint, &recipient);
+    let result = send_ix(&mut scenario.svm, ix, &scenario.sender);
+    assert!(result.is_ok(), "Cancel failed: {:?}", result.err());
+
+    assert_eq!(token_balance(&scenario.svm, &scenario.recipient_ata), streamed);
+    assert_eq!(token_balance(&scenario.svm, &scenario.sender_ata), SENDER_BALANCE - streamed);
+    assert_eq!(token_balance(&scenario.svm, &derive_vault(&scenario.mint)), 0);
+    assert_eq!(get_stream_amounts(&scenario.svm, &stream), (streamed, streamed));
+    println!("[Success] Recipient paid {} tokens, sender refunded {}", streamed / ONE_TOKEN, (STREAM_DEPOSIT - streamed) / ONE_TOKEN);
+
+    // A cancelled stream stays frozen
+    let ix = build_cancel_stream_ix(&sender, &stream, &scenario.mint, &recipient);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.sender).is_err(), "Second cancel should fail");
+    advance_time(&mut scenario.svm, STREAM_DURATION);
+    let ix = build_withdraw_ix(&recipient, &stream, &scenario.mint, 1);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.recipient).is_err(), "Withdraw after cancel should fail");
+    println!("[Success] Cancelled stream cannot be cancelled again or keep streaming");
+
+    println!("[TEST END] test_cancel_stream");
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+pub use soteria_errors::stream_vulnerable::StreamError as StreamErrorCode;
+
+#[error_code]
+pub enum StreamError {
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Stream must start before it stops")]
+    InvalidTimeRange,
+
+    #[msg("Stream cannot start in the past")]
+    StartInPast,
+
+    #[msg("Stream duration cannot exceed maximum allowed (10 years)")]
+    DurationTooLong,
+
+    #[msg("Withdrawal exceeds the amount streamed so far")]
+    AmountExceedsAvailable,
+
+    #[msg("Stream has been cancelled")]
+    StreamCancelled,
+
+    #[msg("Unauthorized: Cannot perform this action")]
+    Unauthorized,
+
+    #[msg("Arithmetic overflow")]
+    Overflow,
+
+    #[msg("Arithmetic underflow")]
+    Underflow,
+}
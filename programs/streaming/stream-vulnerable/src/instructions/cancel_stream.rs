@@ -0,0 +1,134 @@
+// Cancel Stream Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// VULNERABILITY:
+// V002: Missing sender verification - anyone can cancel any stream and
+//       send the unstreamed refund to their own token account
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct CancelStream<'info> {
+    // VULNERABILITY V002: Any signer can cancel
+    // Secure version names this account `sender` and checks it:
+    //   seeds = [STREAM_SEED, sender.key().as_ref(), ...],
+    //   has_one = sender @ StreamError::Unauthorized,
+    pub canceller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            STREAM_SEED,
+            stream.sender.as_ref(),
+            stream.recipient.as_ref(),
+            stream.seed.to_le_bytes().as_ref(),
+        ],
+        bump = stream.bump,
+        has_one = mint,
+    )]
+    pub stream: Box<Account<'info, Stream>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: PDA signer for the pooled vaults, validated by seeds
+    #[account(seeds = [TREASURY_SEED], bump = stream.treasury_bump)]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // VULNERABILITY V002: Refund destination not tied to the stream's sender
+    // Secure version: token::authority = sender
+    // Attack scenario (refund theft):
+    // 1. Victim streams 100 tokens to a recipient over 100 seconds
+    // 2. 40 seconds in, attacker calls cancel_stream, passing their own token
+    //    account as sender_token_account
+    // 3. Recipient is paid the 40 streamed tokens, so nothing looks wrong to them
+    // 4. The 60 unstreamed tokens are "refunded" to the attacker
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub sender_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = stream.recipient,
+        token::token_program = token_program,
+    )]
+    pub recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CancelStream<'info> {
+    pub fn cancel_stream(&mut self) -> Result<()> {
+        require!(!self.stream.cancelled, StreamError::StreamCancelled);
+
+        let now = Clock::get()?.unix_timestamp;
+
+        let streamed = self.stream.streamed_amount(now)?;
+        let owed_to_recipient = streamed
+            .checked_sub(self.stream.withdrawn_amount)
+            .ok_or(StreamError::Underflow)?;
+        let refund = self
+            .stream
+            .deposited_amount
+            .checked_sub(streamed)
+            .ok_or(StreamError::Underflow)?;
+
+        let treasury_seeds = &[TREASURY_SEED, &[self.stream.treasury_bump]];
+
+        // Settle what has already streamed
+        if owed_to_recipient > 0 {
+            transfer_from_vault(
+                owed_to_recipient,
+                self.mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.vault.to_account_info(),
+                &self.mint.to_account_info(),
+                &self.recipient_token_account.to_account_info(),
+                &self.treasury.to_account_info(),
+                treasury_seeds,
+            )?;
+        }
+
+        // Return the rest to the sender
+        if refund > 0 {
+            transfer_from_vault(
+                refund,
+                self.mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.vault.to_account_info(),
+                &self.mint.to_account_info(),
+                &self.sender_token_account.to_account_info(),
+                &self.treasury.to_account_info(),
+                treasury_seeds,
+            )?;
+        }
+
+        // Freeze the stream: everything streamed has been paid out
+        self.stream.deposited_amount = streamed;
+        self.stream.withdrawn_amount = streamed;
+        self.stream.cancelled = true;
+
+        msg!(
+            "Stream cancelled: {} paid to recipient, {} refunded to sender",
+            owed_to_recipient,
+            refund
+        );
+
+        Ok(())
+    }
+}
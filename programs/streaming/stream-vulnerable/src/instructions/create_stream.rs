@@ -0,0 +1,139 @@
+// Create Stream Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// VULNERABILITY:
+// V001: Unchecked multiplication - rate_per_second * duration wraps, so a huge
+//       rate can be opened with a tiny deposit
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct CreateStream<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    /// CHECK: Only recorded as the stream's recipient, never read or written
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = sender,
+        token::token_program = token_program,
+    )]
+    pub sender_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = sender,
+        space = ANCHOR_DISCRIMINATOR + Stream::INIT_SPACE,
+        seeds = [
+            STREAM_SEED,
+            sender.key().as_ref(),
+            recipient.key().as_ref(),
+            seed.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub stream: Box<Account<'info, Stream>>,
+
+    /// CHECK: PDA signer for the pooled vaults
+    #[account(seeds = [TREASURY_SEED], bump)]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = sender,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateStream<'info> {
+    pub fn create_stream(
+        &mut self,
+        seed: u64,
+        rate_per_second: u64,
+        start_ts: i64,
+        stop_ts: i64,
+        bumps: &CreateStreamBumps,
+    ) -> Result<()> {
+        require!(rate_per_second > 0, StreamError::ZeroAmount);
+        require!(start_ts < stop_ts, StreamError::InvalidTimeRange);
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(start_ts >= now, StreamError::StartInPast);
+
+        let duration = stop_ts.checked_sub(start_ts).ok_or(StreamError::Underflow)?;
+        require!(
+            duration <= MAX_STREAM_DURATION_SECONDS,
+            StreamError::DurationTooLong
+        );
+
+        // VULNERABILITY V001: Deposit computed with unchecked multiplication
+        // (wrapping_mul stands in for a plain `*` built without overflow-checks)
+        // Secure version:
+        //   let deposit = rate_per_second
+        //       .checked_mul(duration as u64)
+        //       .ok_or(StreamError::Overflow)?;
+        // Attack scenario (pooled vault drain):
+        // 1. Victims have deposited 100 tokens into the pooled vault for this mint
+        // 2. Attacker opens a 2 second stream to their own second wallet with
+        //    rate_per_second = 2^63 + 1 token
+        // 3. deposit = 2 * rate wraps past 2^64 to just 2 tokens
+        // 4. One second after start, streamed_amount = rate (~9.2e18)
+        // 5. Attacker withdraws the entire vault, including every victim's deposit
+        let deposit = rate_per_second.wrapping_mul(duration as u64);
+
+        self.stream.set_inner(Stream {
+            sender: self.sender.key(),
+            recipient: self.recipient.key(),
+            mint: self.mint.key(),
+            seed,
+            deposited_amount: deposit,
+            withdrawn_amount: 0,
+            rate_per_second,
+            start_ts,
+            stop_ts,
+            cancelled: false,
+            bump: bumps.stream,
+            treasury_bump: bumps.treasury,
+        });
+
+        transfer_tokens(
+            deposit,
+            self.mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.sender_token_account.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.vault.to_account_info(),
+            &self.sender.to_account_info(),
+        )?;
+
+        msg!(
+            "Stream created: {} per second to {} ({} deposited)",
+            rate_per_second,
+            self.recipient.key(),
+            deposit
+        );
+
+        Ok(())
+    }
+}
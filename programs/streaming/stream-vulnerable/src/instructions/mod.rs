@@ -0,0 +1,11 @@
+// Instructions Module
+//
+// Exports all instruction handlers for the streaming program
+
+pub mod create_stream;
+pub mod withdraw_from_stream;
+pub mod cancel_stream;
+
+pub use create_stream::*;
+pub use withdraw_from_stream::*;
+pub use cancel_stream::*;
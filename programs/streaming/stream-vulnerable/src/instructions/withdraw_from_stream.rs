@@ -0,0 +1,101 @@
+// Withdraw From Stream Instruction
+//
+// Withdraws tokens that have already streamed to the recipient.
+//
+// HOW IT WORKS:
+// 1. Computes the streamed amount at the current time
+// 2. Checks the requested amount is no more than streamed - withdrawn
+// 3. Transfers it from the pooled vault, signed by the treasury PDA
+//
+// SECURITY:
+// - Only the recorded recipient can withdraw (signer + has_one + PDA seeds)
+// - Destination token account must belong to the recipient
+// - NOTE: Bounded only by Stream::streamed_amount, which is unchecked in
+//   this version (V001) - a wrapped stream can draw on other streams'
+//   funds in the pooled vault
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct WithdrawFromStream<'info> {
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            STREAM_SEED,
+            stream.sender.as_ref(),
+            recipient.key().as_ref(),
+            stream.seed.to_le_bytes().as_ref(),
+        ],
+        bump = stream.bump,
+        has_one = recipient @ StreamError::Unauthorized,
+        has_one = mint,
+    )]
+    pub stream: Box<Account<'info, Stream>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: PDA signer for the pooled vaults, validated by seeds
+    #[account(seeds = [TREASURY_SEED], bump = stream.treasury_bump)]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = recipient,
+        token::token_program = token_program,
+    )]
+    pub recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WithdrawFromStream<'info> {
+    pub fn withdraw_from_stream(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, StreamError::ZeroAmount);
+
+        let now = Clock::get()?.unix_timestamp;
+        let withdrawable = self.stream.withdrawable_amount(now)?;
+        require!(amount <= withdrawable, StreamError::AmountExceedsAvailable);
+
+        let treasury_seeds = &[TREASURY_SEED, &[self.stream.treasury_bump]];
+
+        transfer_from_vault(
+            amount,
+            self.mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.vault.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.recipient_token_account.to_account_info(),
+            &self.treasury.to_account_info(),
+            treasury_seeds,
+        )?;
+
+        self.stream.withdrawn_amount = self
+            .stream
+            .withdrawn_amount
+            .checked_add(amount)
+            .ok_or(StreamError::Overflow)?;
+
+        msg!(
+            "Withdrew {} ({} of {} withdrawn)",
+            amount,
+            self.stream.withdrawn_amount,
+            self.stream.deposited_amount
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+// Payment Streaming Program - VULNERABLE VERSION
+//
+// WARNING: This program contains intentional security vulnerabilities for educational purposes.
+// NEVER deploy this to production. Use stream-secure instead.
+//
+// This vulnerable streaming program demonstrates two classic streaming flaws:
+// - Unchecked rate * time multiplication that wraps around
+// - Cancelling a stream without verifying the sender
+//
+// VULNERABILITIES DOCUMENTED:
+// See individual instruction files for detailed vulnerability explanations.
+// Each vulnerability is marked with VULNERABILITY comments.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("2gxBkTEfKGeR8c26mt94zCcygGUBgHe2zETjoVwC1C6b");
+
+#[program]
+pub mod stream_vulnerable {
+    use super::*;
+
+    // Deposit funds and open a stream to a recipient
+    // VULNERABILITY: Deposit uses unchecked rate * duration multiplication
+    pub fn create_stream(
+        ctx: Context<CreateStream>,
+        seed: u64,
+        rate_per_second: u64,
+        start_ts: i64,
+        stop_ts: i64,
+    ) -> Result<()> {
+        ctx.accounts.create_stream(seed, rate_per_second, start_ts, stop_ts, &ctx.bumps)
+    }
+
+    // Withdraw tokens that have already streamed
+    // VULNERABILITY: Streamed amount uses unchecked rate * elapsed multiplication
+    pub fn withdraw_from_stream(ctx: Context<WithdrawFromStream>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw_from_stream(amount)
+    }
+
+    // Stop a stream early and settle both sides
+    // VULNERABILITY: No sender verification - anyone can cancel and take the refund
+    pub fn cancel_stream(ctx: Context<CancelStream>) -> Result<()> {
+        ctx.accounts.cancel_stream()
+    }
+}
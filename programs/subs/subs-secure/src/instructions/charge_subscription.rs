@@ -0,0 +1,115 @@
+// Charge Subscription Instruction
+//
+// Permissionless crank that pulls one period's payment into the merchant vault.
+//
+// HOW IT WORKS:
+// 1. Checks the subscription is due (now >= next_charge_ts)
+// 2. Transfers plan.amount from the subscriber, signed by the subscription
+//    PDA as delegate
+// 3. Moves next_charge_ts forward by one period
+//
+// SECURITY:
+// - Amount is always plan.amount; the crank cannot choose it
+// - At most one charge per period
+// - Payment can only go to the plan's merchant_vault
+// - Remaining approval checked before transfer
+//
+// Why is the crank permissionless?
+// Every input that matters (amount, timing, destination) is fixed by the
+// plan and the subscription, so anyone running the crank can only do what
+// the subscriber already agreed to.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct ChargeSubscription<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [PLAN_SEED, plan.merchant.as_ref(), plan.plan_id.to_le_bytes().as_ref()],
+        bump = plan.bump,
+        has_one = mint,
+        has_one = merchant_vault,
+    )]
+    pub plan: Box<Account<'info, Plan>>,
+
+    #[account(
+        mut,
+        seeds = [SUBSCRIPTION_SEED, plan.key().as_ref(), subscription.subscriber.as_ref()],
+        bump = subscription.bump,
+        has_one = plan,
+        has_one = subscriber_token_account,
+    )]
+    pub subscription: Box<Account<'info, Subscription>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub subscriber_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub merchant_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ChargeSubscription<'info> {
+    pub fn charge_subscription(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            cfg!(feature = "vuln-missing-period-check") || now >= self.subscription.next_charge_ts,
+            SubsError::ChargeTooEarly
+        );
+
+        let amount = self.plan.amount;
+        require!(
+            self.subscriber_token_account.delegate == Some(self.subscription.key()).into()
+                && self.subscriber_token_account.delegated_amount >= amount,
+            SubsError::InsufficientAllowance
+        );
+
+        // Advance before transferring (checks-effects-interactions)
+        self.subscription.next_charge_ts = self
+            .subscription
+            .next_charge_ts
+            .checked_add(self.plan.period_seconds)
+            .ok_or(SubsError::Overflow)?;
+        self.subscription.charges = self
+            .subscription
+            .charges
+            .checked_add(1)
+            .ok_or(SubsError::Overflow)?;
+
+        let plan_key = self.plan.key();
+        let subscriber = self.subscription.subscriber;
+        let subscription_seeds = &[
+            SUBSCRIPTION_SEED,
+            plan_key.as_ref(),
+            subscriber.as_ref(),
+            &[self.subscription.bump],
+        ];
+
+        transfer_as_delegate(
+            amount,
+            self.mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.subscriber_token_account.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.merchant_vault.to_account_info(),
+            &self.subscription.to_account_info(),
+            subscription_seeds,
+        )?;
+
+        msg!(
+            "Charged {} (charge #{}), next due at {}",
+            amount,
+            self.subscription.charges,
+            self.subscription.next_charge_ts
+        );
+
+        Ok(())
+    }
+}
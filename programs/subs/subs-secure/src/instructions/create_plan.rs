@@ -0,0 +1,78 @@
+// Create Plan Instruction
+//
+// A merchant offers a fixed amount every period, paid into their vault.
+//
+// HOW IT WORKS:
+// 1. Validates the amount and period
+// 2. Creates the plan PDA pointing at the merchant's token account
+//
+// SECURITY:
+// - amount > 0
+// - MIN_PERIOD_SECONDS <= period_seconds <= MAX_PERIOD_SECONDS
+// - merchant_vault must be a token account of the plan mint owned by the merchant
+// - Amount and period are fixed for the life of the plan
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*};
+
+#[derive(Accounts)]
+#[instruction(plan_id: u64)]
+pub struct CreatePlan<'info> {
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        token::mint = mint,
+        token::authority = merchant,
+        token::token_program = token_program,
+    )]
+    pub merchant_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = merchant,
+        space = ANCHOR_DISCRIMINATOR + Plan::INIT_SPACE,
+        seeds = [PLAN_SEED, merchant.key().as_ref(), plan_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub plan: Box<Account<'info, Plan>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreatePlan<'info> {
+    pub fn create_plan(
+        &mut self,
+        plan_id: u64,
+        amount: u64,
+        period_seconds: i64,
+        bumps: &CreatePlanBumps,
+    ) -> Result<()> {
+        require!(amount > 0, SubsError::ZeroAmount);
+
+        require!(
+            (MIN_PERIOD_SECONDS..=MAX_PERIOD_SECONDS).contains(&period_seconds),
+            SubsError::InvalidPeriod
+        );
+
+        self.plan.set_inner(Plan {
+            merchant: self.merchant.key(),
+            mint: self.mint.key(),
+            merchant_vault: self.merchant_vault.key(),
+            plan_id,
+            amount,
+            period_seconds,
+            bump: bumps.plan,
+        });
+
+        msg!("Plan created: {} every {}s", amount, period_seconds);
+
+        Ok(())
+    }
+}
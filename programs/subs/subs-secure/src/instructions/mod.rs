@@ -0,0 +1,13 @@
+// Instructions Module
+//
+// Exports all instruction handlers for the subscription program
+
+pub mod create_plan;
+pub mod subscribe;
+pub mod charge_subscription;
+pub mod cancel_subscription;
+
+pub use create_plan::*;
+pub use subscribe::*;
+pub use charge_subscription::*;
+pub use cancel_subscription::*;
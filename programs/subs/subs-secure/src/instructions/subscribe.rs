@@ -0,0 +1,101 @@
+// Subscribe Instruction
+//
+// A subscriber joins a plan and approves the subscription PDA to pull payments.
+//
+// HOW IT WORKS:
+// 1. Creates the subscription PDA for (plan, subscriber)
+// 2. Approves the PDA as delegate for plan.amount * approved_periods
+// 3. The first charge is due immediately
+//
+// SECURITY:
+// - 1 <= approved_periods <= MAX_APPROVED_PERIODS
+// - Token account must be the subscriber's, in the plan mint
+// - The approval is the subscriber's hard limit: the program cannot pull
+//   more than was approved, whatever the crank does
+//
+// SPL token accounts hold a single delegate. Subscribing from an account
+// that already pays another plan replaces that plan's approval, so use a
+// dedicated token account per subscription.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct Subscribe<'info> {
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+
+    #[account(
+        seeds = [PLAN_SEED, plan.merchant.as_ref(), plan.plan_id.to_le_bytes().as_ref()],
+        bump = plan.bump,
+        has_one = mint,
+    )]
+    pub plan: Box<Account<'info, Plan>>,
+
+    #[account(
+        init,
+        payer = subscriber,
+        space = ANCHOR_DISCRIMINATOR + Subscription::INIT_SPACE,
+        seeds = [SUBSCRIPTION_SEED, plan.key().as_ref(), subscriber.key().as_ref()],
+        bump
+    )]
+    pub subscription: Box<Account<'info, Subscription>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = subscriber,
+        token::token_program = token_program,
+    )]
+    pub subscriber_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Subscribe<'info> {
+    pub fn subscribe(&mut self, approved_periods: u32, bumps: &SubscribeBumps) -> Result<()> {
+        require!(
+            (1..=MAX_APPROVED_PERIODS).contains(&approved_periods),
+            SubsError::InvalidApprovedPeriods
+        );
+
+        let allowance = self
+            .plan
+            .amount
+            .checked_mul(approved_periods as u64)
+            .ok_or(SubsError::Overflow)?;
+
+        let now = Clock::get()?.unix_timestamp;
+
+        self.subscription.set_inner(Subscription {
+            plan: self.plan.key(),
+            subscriber: self.subscriber.key(),
+            subscriber_token_account: self.subscriber_token_account.key(),
+            approved_periods,
+            next_charge_ts: now,
+            charges: 0,
+            bump: bumps.subscription,
+        });
+
+        approve_delegate(
+            allowance,
+            &self.token_program.to_account_info(),
+            &self.subscriber_token_account.to_account_info(),
+            &self.subscription.to_account_info(),
+            &self.subscriber.to_account_info(),
+        )?;
+
+        msg!(
+            "Subscribed: {} periods approved ({} tokens)",
+            approved_periods,
+            allowance
+        );
+
+        Ok(())
+    }
+}
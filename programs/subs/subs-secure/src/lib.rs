@@ -0,0 +1,58 @@
+// Subscription Billing Program - SECURE VERSION
+//
+// Implementation of recurring token payments through delegate approvals.
+// This program allows users to:
+// 1. Create a plan charging a fixed amount every period
+// 2. Subscribe by approving the subscription PDA for a number of periods
+// 3. Let anyone crank due charges into the merchant's vault
+// 4. Cancel, revoking the approval
+//
+// SECURITY FEATURES:
+// - Charge amount fixed by the plan, not chosen by the crank
+// - At most one charge per period (next_charge_ts)
+// - Subscriber exposure capped by the delegate approval
+// - Payments only to the plan's merchant vault
+// - Cancel revokes only this subscription's approval
+// - Checked arithmetic to prevent overflow/underflow
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("qBEfUy9354yX94b3CZSFCVewQzgUSEjxdAznbSzJQ7D");
+
+#[program]
+pub mod subs_secure {
+    use super::*;
+
+    // Offer a fixed amount every period
+    pub fn create_plan(
+        ctx: Context<CreatePlan>,
+        plan_id: u64,
+        amount: u64,
+        period_seconds: i64,
+    ) -> Result<()> {
+        ctx.accounts.create_plan(plan_id, amount, period_seconds, &ctx.bumps)
+    }
+
+    // Join a plan and approve the subscription as delegate
+    pub fn subscribe(ctx: Context<Subscribe>, approved_periods: u32) -> Result<()> {
+        ctx.accounts.subscribe(approved_periods, &ctx.bumps)
+    }
+
+    // Permissionless crank: take one period's payment when due
+    pub fn charge_subscription(ctx: Context<ChargeSubscription>) -> Result<()> {
+        ctx.accounts.charge_subscription()
+    }
+
+    // Subscriber-only: revoke the approval and close the subscription
+    pub fn cancel_subscription(ctx: Context<CancelSubscription>) -> Result<()> {
+        ctx.accounts.cancel_subscription()
+    }
+}
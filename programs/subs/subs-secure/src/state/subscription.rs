@@ -0,0 +1,34 @@
+// Subscription State
+//
+// One subscriber on one plan.
+//
+// The subscriber approves this PDA as delegate on their token account for
+// plan.amount * approved_periods. The charge crank transfers out of that
+// account with the PDA's signature, so the subscriber never has to sign a
+// renewal - and can never lose more than they approved.
+
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Subscription {
+    // Plan being paid for
+    pub plan: Pubkey,
+
+    // Account that subscribed
+    pub subscriber: Pubkey,
+
+    // Subscriber token account this PDA is delegate on
+    pub subscriber_token_account: Pubkey,
+
+    // Periods covered by the delegate approval
+    pub approved_periods: u32,
+
+    // Unix timestamp from which the next charge may be taken
+    pub next_charge_ts: i64,
+
+    // Charges taken so far
+    pub charges: u32,
+
+    pub bump: u8,
+}
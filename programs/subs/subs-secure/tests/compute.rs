@@ -0,0 +1,38 @@
+// Compute-unit regression tests for subs-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+mod utils;
+
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signature::Signer};
+use soteria_test_kit::{ComputeBudget, DEFAULT_CU_BASELINES_FILE};
+use utils::*;
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let (mut svm, _, merchant, subscriber, mint) = setup_accounts();
+    let cranker = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+
+    let ix = build_create_plan_ix(&merchant.pubkey(), &mint, PLAN_ID, PLAN_AMOUNT, PERIOD_SECONDS);
+    budget.record("create_plan", send_ix(&mut svm, ix, &merchant));
+    let (plan, _) = derive_plan_pda(&merchant.pubkey(), PLAN_ID);
+
+    let ix = build_subscribe_ix(&subscriber.pubkey(), &plan, &mint, APPROVED_PERIODS);
+    budget.record("subscribe", send_ix(&mut svm, ix, &subscriber));
+
+    // The first period is due as soon as the subscription opens
+    let ix =
+        build_charge_ix(&cranker.pubkey(), &plan, &subscriber.pubkey(), &merchant.pubkey(), &mint);
+    budget.record("charge_subscription", send_ix(&mut svm, ix, &cranker));
+
+    let ix = build_cancel_ix(&subscriber.pubkey(), &plan, &mint);
+    budget.record("cancel_subscription", send_ix(&mut svm, ix, &subscriber));
+
+    budget.check();
+}
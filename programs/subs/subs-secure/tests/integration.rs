@@ -0,0 +1,272 @@
+// Integration tests for subscription billing program using LiteSVM
+// These tests verify core billing functionality: plan creation, delegate
+// approval on subscribe, once-per-period charges, and cancellation
+
+mod utils;
+
+use utils::*;
+use litesvm::{types::TransactionResult, LiteSVM};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL,
+    program_option::COption,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use soteria_test_kit::{
+    run_feature_differential, DifferentialScenario, ExploitInfo, FeatureVariant, Severity,
+};
+
+#[test]
+fn test_create_plan_and_subscribe() {
+    // Test: Plans validate their terms; subscribing approves the subscription PDA
+    println!("\n[TEST START] test_create_plan_and_subscribe - Plan creation and delegate approval");
+
+    let (mut svm, _, merchant, subscriber, mint) = setup_accounts();
+
+    let bad_plans: [(&str, u64, i64); 3] = [
+        ("zero amount", 0, PERIOD_SECONDS),
+        ("one-hour period", PLAN_AMOUNT, 60 * 60),
+        ("two-year period", PLAN_AMOUNT, 2 * 365 * 24 * 60 * 60),
+    ];
+
+    for (name, amount, period) in bad_plans {
+        println!("[Action] Creating plan with {}", name);
+        let ix = build_create_plan_ix(&merchant.pubkey(), &mint, PLAN_ID, amount, period);
+        assert!(send_ix(&mut svm, ix, &merchant).is_err(), "Plan with {} should fail", name);
+        println!("[Success] Plan with {} rejected", name);
+    }
+
+    println!("[Action] Creating a 10-token monthly plan");
+    let ix = build_create_plan_ix(&merchant.pubkey(), &mint, PLAN_ID, PLAN_AMOUNT, PERIOD_SECONDS);
+    let result = send_ix(&mut svm, ix, &merchant);
+    assert!(result.is_ok(), "Plan creation failed: {:?}", result.err());
+    let (plan, _) = derive_plan_pda(&merchant.pubkey(), PLAN_ID);
+    println!("[Success] Plan created");
+
+    println!("[Action] Subscribing with 13 periods approved");
+    let ix = build_subscribe_ix(&subscriber.pubkey(), &plan, &mint, 13);
+    assert!(send_ix(&mut svm, ix, &subscriber).is_err(), "13 periods should fail");
+    println!("[Success] Approval above MAX_APPROVED_PERIODS rejected");
+
+    println!("[Action] Subscribing with {} periods approved", APPROVED_PERIODS);
+    let ix = build_subscribe_ix(&subscriber.pubkey(), &plan, &mint, APPROVED_PERIODS);
+    let result = send_ix(&mut svm, ix, &subscriber);
+    assert!(result.is_ok(), "Subscribe failed: {:?}", result.err());
+
+    let (subscription, _) = derive_subscription_pda(&plan, &subscriber.pubkey());
+    let ata = spl_associated_token_account::get_associated_token_address(&subscriber.pubkey(), &mint);
+    let account = token_account(&svm, &ata);
+    assert_eq!(account.delegate, COption::Some(subscription));
+    assert_eq!(account.delegated_amount, PLAN_AMOUNT * APPROVED_PERIODS as u64);
+    assert_eq!(account.amount, SUBSCRIBER_BALANCE);
+    println!("[Success] Subscription PDA approved for 30 tokens; nothing charged yet");
+
+    println!("[TEST END] test_create_plan_and_subscribe");
+}
+
+#[test]
+fn test_charge_subscription() {
+    // Test: The crank takes exactly plan.amount, once per period, up to the approval
+    println!("\n[TEST START] test_charge_subscription - Once-per-period charges");
+
+    let mut scenario = setup_subs_scenario();
+    println!("[Setup] Subscribed to 10 tokens / 30 days with 3 periods approved");
+
+    println!("[Action] Crank takes the first charge");
+    let ix = scenario.charge_ix();
+    let result = send_ix(&mut scenario.svm, ix, &scenario.cranker);
+    assert!(result.is_ok(), "Charge failed: {:?}", result.err());
+    assert_eq!(scenario.merchant_balance(), PLAN_AMOUNT);
+    assert_eq!(scenario.subscriber_balance(), SUBSCRIBER_BALANCE - PLAN_AMOUNT);
+    println!("[Success] Merchant received 10 tokens");
+
+    println!("[Action] Crank tries to charge again in the same period");
+    let ix = scenario.charge_ix();
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.cranker).is_err(), "Early charge should fail");
+    advance_time(&mut scenario.svm, PERIOD_SECONDS - 1);
+    let ix = scenario.charge_ix();
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.cranker).is_err(), "Early charge should fail");
+    println!("[Success] Charges before next_charge_ts rejected");
+
+    println!("[Action] Crank charges for periods 2 and 3");
+    for _ in 0..2 {
+        advance_time(&mut scenario.svm, PERIOD_SECONDS);
+        let ix = scenario.charge_ix();
+        let result = send_ix(&mut scenario.svm, ix, &scenario.cranker);
+        assert!(result.is_ok(), "Charge failed: {:?}", result.err());
+    }
+    assert_eq!(scenario.merchant_balance(), 3 * PLAN_AMOUNT);
+    assert_eq!(get_charges(&scenario.svm, &scenario.subscription), 3);
+    println!("[Success] Three charges taken, one per period");
+
+    println!("[Action] Crank tries a fourth charge beyond the approval");
+    advance_time(&mut scenario.svm, PERIOD_SECONDS);
+    let ix = scenario.charge_ix();
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.cranker).is_err(), "Charge beyond approval should fail");
+    assert_eq!(scenario.subscriber_balance(), SUBSCRIBER_BALANCE - 3 * PLAN_AMOUNT);
+    println!("[Success] Subscriber never pays more than they approved");
+
+    println!("[TEST END] test_charge_subscription");
+}
+
+#[test]
+fn test_charge_amount_from_plan() {
+    // Test: A crank that appends its own amount, as subs-vulnerable's
+    // charge_subscription accepts, still only takes plan.amount
+    println!("\n[TEST START] test_charge_amount_from_plan - Caller cannot pick the amount");
+
+    let mut scenario = setup_subs_scenario();
+    let allowance = PLAN_AMOUNT * APPROVED_PERIODS as u64;
+
+    println!("[Action] Crank charges with the full {} token approval as the amount", allowance / ONE_TOKEN);
+    let mut ix = scenario.charge_ix();
+    ix.data.extend_from_slice(&allowance.to_le_bytes());
+    let result = send_ix(&mut scenario.svm, ix, &scenario.cranker);
+    assert!(result.is_ok(), "Charge failed: {:?}", result.err());
+
+    assert_eq!(scenario.merchant_balance(), PLAN_AMOUNT);
+    assert_eq!(scenario.subscriber_balance(), SUBSCRIBER_BALANCE - PLAN_AMOUNT);
+    assert_eq!(get_charges(&scenario.svm, &scenario.subscription), 1);
+    println!("[Success] Only plan.amount charged, the rest of the approval is untouched");
+
+    println!("[TEST END] test_charge_amount_from_plan");
+}
+
+#[test]
+fn test_cancel_subscription() {
+    // Test: Only the subscriber can cancel; cancelling revokes and closes
+    println!("\n[TEST START] test_cancel_subscription - Revoking the approval");
+
+    let mut scenario = setup_subs_scenario();
+    let ix = scenario.charge_ix();
+    send_ix(&mut scenario.svm, ix, &scenario.cranker).expect("Charge should succeed");
+    println!("[Setup] One charge taken");
+
+    println!("[Action] Merchant tries to cancel the subscription");
+    let ix = build_cancel_ix(&scenario.merchant.pubkey(), &scenario.plan, &scenario.mint);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.merchant).is_err(), "Merchant cancel should fail");
+    println!("[Success] Cancel by non-subscriber rejected");
+
+    println!("[Action] Subscriber cancels");
+    let ix = build_cancel_ix(&scenario.subscriber.pubkey(), &scenario.plan, &scenario.mint);
+    let result = send_ix(&mut scenario.svm, ix, &scenario.subscriber);
+    assert!(result.is_ok(), "Cancel failed: {:?}", result.err());
+
+    let account = token_account(&scenario.svm, &scenario.subscriber_ata);
+    assert_eq!(account.delegate, COption::None);
+    assert_eq!(account.delegated_amount, 0);
+    assert!(
+        scenario.svm.get_account(&scenario.subscription).map_or(true, |a| a.lamports == 0),
+        "Subscription should be closed after cancel"
+    );
+    println!("[Success] Approval revoked and subscription closed");
+
+    println!("[Action] Crank tries to charge the cancelled subscription");
+    advance_time(&mut scenario.svm, PERIOD_SECONDS);
+    let ix = scenario.charge_ix();
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.cranker).is_err(), "Charge after cancel should fail");
+    assert_eq!(scenario.subscriber_balance(), SUBSCRIBER_BALANCE - PLAN_AMOUNT);
+    println!("[Success] No further charges");
+
+    println!("[TEST END] test_cancel_subscription");
+}
+
+// Charge a second time in the period the first charge already paid for
+// (V002 in subs-vulnerable)
+struct EarlyCharge;
+
+struct EarlyChargeState {
+    cranker: Keypair,
+    merchant: Pubkey,
+    subscriber: Pubkey,
+    mint: Pubkey,
+    plan: Pubkey,
+}
+
+impl DifferentialScenario for EarlyCharge {
+    type State = EarlyChargeState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Missing Period Check",
+            severity: Severity::High,
+            lesson: "Charge at most once per period, from next_charge_ts onwards",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, _program_id: &Pubkey) -> EarlyChargeState {
+        let mint_authority = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let merchant = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let subscriber = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+        let cranker = create_funded_account(svm, LAMPORTS_PER_SOL);
+
+        let mint = CreateMint::new(svm, &mint_authority)
+            .authority(&mint_authority.pubkey())
+            .decimals(DECIMALS)
+            .send()
+            .expect("Failed to create mint");
+        CreateAssociatedTokenAccount::new(svm, &merchant, &mint)
+            .owner(&merchant.pubkey())
+            .send()
+            .expect("Failed to create merchant vault");
+        let subscriber_ata = CreateAssociatedTokenAccount::new(svm, &subscriber, &mint)
+            .owner(&subscriber.pubkey())
+            .send()
+            .expect("Failed to create subscriber account");
+        MintTo::new(svm, &mint_authority, &mint, &subscriber_ata, SUBSCRIBER_BALANCE)
+            .owner(&mint_authority)
+            .send()
+            .expect("Failed to mint to subscriber");
+
+        let ix = build_create_plan_ix(&merchant.pubkey(), &mint, PLAN_ID, PLAN_AMOUNT, PERIOD_SECONDS);
+        send_ix(svm, ix, &merchant).expect("Plan creation should succeed");
+        let (plan, _) = derive_plan_pda(&merchant.pubkey(), PLAN_ID);
+
+        let ix = build_subscribe_ix(&subscriber.pubkey(), &plan, &mint, APPROVED_PERIODS);
+        send_ix(svm, ix, &subscriber).expect("Subscribe should succeed");
+
+        let ix = build_charge_ix(&cranker.pubkey(), &plan, &subscriber.pubkey(), &merchant.pubkey(), &mint);
+        send_ix(svm, ix, &cranker).expect("First charge should succeed");
+
+        EarlyChargeState {
+            cranker,
+            merchant: merchant.pubkey(),
+            subscriber: subscriber.pubkey(),
+            mint,
+            plan,
+        }
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        _program_id: &Pubkey,
+        state: &mut EarlyChargeState,
+    ) -> TransactionResult {
+        let ix = build_charge_ix(
+            &state.cranker.pubkey(),
+            &state.plan,
+            &state.subscriber,
+            &state.merchant,
+            &state.mint,
+        );
+        send_ix(svm, ix, &state.cranker)
+    }
+}
+
+// Feature matrix: only the vuln-missing-period-check build takes the second charge
+// Requires ./build-vuln-matrix.sh subs-secure from the repository root
+#[test]
+fn test_feature_missing_period_check() {
+    let variant = FeatureVariant {
+        program_id: SUBS_PROGRAM_ID,
+        feature: "vuln-missing-period-check",
+    };
+    let (mut baseline, mut variant_svm) = variant.load(
+        "target/deploy/subs_secure.so",
+        variant.so_path(".", "subs_secure"),
+    );
+    run_feature_differential(&mut baseline, &mut variant_svm, &variant, &EarlyCharge);
+}
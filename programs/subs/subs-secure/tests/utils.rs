@@ -0,0 +1,300 @@
+// Test utilities for subscription billing program
+
+use litesvm::{LiteSVM, types::TransactionResult};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo, get_spl_account};
+use litesvm_token::spl_token::state::Account as TokenAccount;
+use solana_sdk::{
+    clock::Clock,
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::program::ID as system_program;
+
+// Program ID matching declare_id!
+pub const SUBS_PROGRAM_ID: Pubkey = Pubkey::new_from_array(subs_secure::ID.to_bytes());
+
+// Build Anchor instruction discriminator
+// Formula: first 8 bytes of sha256("global:method_name")
+pub fn anchor_discriminator(method: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", method);
+    let hash_result = hash(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash_result.to_bytes()[..8]);
+    discriminator
+}
+
+// Standard program IDs
+pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+
+// PDA Seeds
+pub const PLAN_SEED: &[u8] = b"plan";
+pub const SUBSCRIPTION_SEED: &[u8] = b"subscription";
+
+// Token configuration
+pub const DECIMALS: u8 = 6;
+pub const ONE_TOKEN: u64 = 1_000_000;
+
+// Plan configuration used by the scenario: 10 tokens every 30 days
+pub const PLAN_ID: u64 = 1;
+pub const PLAN_AMOUNT: u64 = 10 * ONE_TOKEN;
+pub const PERIOD_SECONDS: i64 = 30 * 24 * 60 * 60;
+pub const APPROVED_PERIODS: u32 = 3;
+
+// Subscriber starting balance
+pub const SUBSCRIBER_BALANCE: u64 = 1_000 * ONE_TOKEN;
+
+// Setup LiteSVM with subscription program
+pub fn setup_svm() -> LiteSVM {
+    let mut svm = LiteSVM::new();
+    let program_bytes = include_bytes!("../target/deploy/subs_secure.so");
+    let _ = svm.add_program(SUBS_PROGRAM_ID, program_bytes);
+    svm
+}
+
+// Create and fund account
+pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
+    let keypair = Keypair::new();
+    svm.airdrop(&keypair.pubkey(), lamports)
+        .expect("Airdrop should succeed");
+    keypair
+}
+
+// Sign and send a single instruction
+pub fn send_ix(svm: &mut LiteSVM, ix: Instruction, signer: &Keypair) -> TransactionResult {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    svm.expire_blockhash();
+    result
+}
+
+pub fn advance_time(svm: &mut LiteSVM, seconds: i64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += seconds;
+    svm.set_sysvar::<Clock>(&clock);
+}
+
+// Derive plan PDA
+pub fn derive_plan_pda(merchant: &Pubkey, plan_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PLAN_SEED, merchant.as_ref(), &plan_id.to_le_bytes()],
+        &SUBS_PROGRAM_ID,
+    )
+}
+
+// Derive subscription PDA
+pub fn derive_subscription_pda(plan: &Pubkey, subscriber: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SUBSCRIPTION_SEED, plan.as_ref(), subscriber.as_ref()],
+        &SUBS_PROGRAM_ID,
+    )
+}
+
+// Build create_plan instruction
+pub fn build_create_plan_ix(
+    merchant: &Pubkey,
+    mint: &Pubkey,
+    plan_id: u64,
+    amount: u64,
+    period_seconds: i64,
+) -> Instruction {
+    let (plan, _) = derive_plan_pda(merchant, plan_id);
+
+    let mut data = anchor_discriminator("create_plan").to_vec();
+    data.extend_from_slice(&plan_id.to_le_bytes());
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(&period_seconds.to_le_bytes());
+
+    Instruction {
+        program_id: SUBS_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*merchant, true),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(get_associated_token_address(merchant, mint), false),
+            AccountMeta::new(plan, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build subscribe instruction
+pub fn build_subscribe_ix(subscriber: &Pubkey, plan: &Pubkey, mint: &Pubkey, approved_periods: u32) -> Instruction {
+    let (subscription, _) = derive_subscription_pda(plan, subscriber);
+
+    let mut data = anchor_discriminator("subscribe").to_vec();
+    data.extend_from_slice(&approved_periods.to_le_bytes());
+
+    Instruction {
+        program_id: SUBS_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*subscriber, true),
+            AccountMeta::new_readonly(*plan, false),
+            AccountMeta::new(subscription, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(get_associated_token_address(subscriber, mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build charge_subscription instruction
+pub fn build_charge_ix(
+    cranker: &Pubkey,
+    plan: &Pubkey,
+    subscriber: &Pubkey,
+    merchant: &Pubkey,
+    mint: &Pubkey,
+) -> Instruction {
+    let (subscription, _) = derive_subscription_pda(plan, subscriber);
+
+    Instruction {
+        program_id: SUBS_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*cranker, true),
+            AccountMeta::new_readonly(*plan, false),
+            AccountMeta::new(subscription, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(get_associated_token_address(subscriber, mint), false),
+            AccountMeta::new(get_associated_token_address(merchant, mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: anchor_discriminator("charge_subscription").to_vec(),
+    }
+}
+
+// Build cancel_subscription instruction
+pub fn build_cancel_ix(subscriber: &Pubkey, plan: &Pubkey, mint: &Pubkey) -> Instruction {
+    let (subscription, _) = derive_subscription_pda(plan, subscriber);
+
+    Instruction {
+        program_id: SUBS_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*subscriber, true),
+            AccountMeta::new(subscription, false),
+            AccountMeta::new(get_associated_token_address(subscriber, mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: anchor_discriminator("cancel_subscription").to_vec(),
+    }
+}
+
+// Read charges from a Subscription
+// Layout: discriminator (8) + plan (32) + subscriber (32)
+//         + subscriber_token_account (32) + approved_periods (4)
+//         + next_charge_ts (8) + charges (4)
+pub fn get_charges(svm: &LiteSVM, subscription: &Pubkey) -> u32 {
+    let account = svm.get_account(subscription).expect("Subscription should exist");
+    let offset = 8 + 32 + 32 + 32 + 4 + 8;
+    u32::from_le_bytes(account.data[offset..offset + 4].try_into().unwrap())
+}
+
+pub fn token_account(svm: &LiteSVM, token_account: &Pubkey) -> TokenAccount {
+    get_spl_account(svm, token_account).expect("Token account should exist")
+}
+
+// Subscription scenario setup result
+pub struct SubsScenario {
+    pub svm: LiteSVM,
+    pub merchant: Keypair,
+    pub subscriber: Keypair,
+    pub cranker: Keypair,
+    pub mint: Pubkey,
+    pub plan: Pubkey,
+    pub subscription: Pubkey,
+    pub merchant_vault: Pubkey,
+    pub subscriber_ata: Pubkey,
+}
+
+impl SubsScenario {
+    // Charge instruction sent by the scenario cranker
+    pub fn charge_ix(&self) -> Instruction {
+        build_charge_ix(
+            &self.cranker.pubkey(),
+            &self.plan,
+            &self.subscriber.pubkey(),
+            &self.merchant.pubkey(),
+            &self.mint,
+        )
+    }
+
+    pub fn merchant_balance(&self) -> u64 {
+        token_account(&self.svm, &self.merchant_vault).amount
+    }
+
+    pub fn subscriber_balance(&self) -> u64 {
+        token_account(&self.svm, &self.subscriber_ata).amount
+    }
+}
+
+// Create the mint, a merchant with an empty vault, and a funded subscriber
+// Returns (svm, mint_authority, merchant, subscriber, mint)
+pub fn setup_accounts() -> (LiteSVM, Keypair, Keypair, Keypair, Pubkey) {
+    let mut svm = setup_svm();
+    let mint_authority = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let merchant = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let subscriber = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+    let mint = CreateMint::new(&mut svm, &mint_authority)
+        .authority(&mint_authority.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create mint");
+
+    CreateAssociatedTokenAccount::new(&mut svm, &merchant, &mint)
+        .owner(&merchant.pubkey())
+        .send()
+        .expect("Failed to create merchant vault");
+
+    let subscriber_ata = CreateAssociatedTokenAccount::new(&mut svm, &subscriber, &mint)
+        .owner(&subscriber.pubkey())
+        .send()
+        .expect("Failed to create subscriber account");
+    MintTo::new(&mut svm, &mint_authority, &mint, &subscriber_ata, SUBSCRIBER_BALANCE)
+        .owner(&mint_authority)
+        .send()
+        .expect("Failed to mint to subscriber");
+
+    (svm, mint_authority, merchant, subscriber, mint)
+}
+
+// Create a 10-token monthly plan and subscribe with 3 periods approved
+pub fn setup_subs_scenario() -> SubsScenario {
+    let (mut svm, _, merchant, subscriber, mint) = setup_accounts();
+    let cranker = create_funded_account(&mut svm, LAMPORTS_PER_SOL);
+
+    let ix = build_create_plan_ix(&merchant.pubkey(), &mint, PLAN_ID, PLAN_AMOUNT, PERIOD_SECONDS);
+    send_ix(&mut svm, ix, &merchant).expect("Plan creation should succeed");
+    let (plan, _) = derive_plan_pda(&merchant.pubkey(), PLAN_ID);
+
+    let ix = build_subscribe_ix(&subscriber.pubkey(), &plan, &mint, APPROVED_PERIODS);
+    send_ix(&mut svm, ix, &subscriber).expect("Subscribe should succeed");
+    let (subscription, _) = derive_subscription_pda(&plan, &subscriber.pubkey());
+
+    let merchant_vault = get_associated_token_address(&merchant.pubkey(), &mint);
+    let subscriber_ata = get_associated_token_address(&subscriber.pubkey(), &mint);
+
+    SubsScenario {
+        svm,
+        merchant,
+        subscriber,
+        cranker,
+        mint,
+        plan,
+        subscription,
+        merchant_vault,
+        subscriber_ata,
+    }
+}
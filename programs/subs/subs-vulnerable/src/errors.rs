@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+pub use soteria_errors::subs_vulnerable::SubsError as SubsErrorCode;
+
+#[error_code]
+pub enum SubsError {
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Billing period is outside the allowed range (1 day to 365 days)")]
+    InvalidPeriod,
+
+    #[msg("Approved periods must be between 1 and 12")]
+    InvalidApprovedPeriods,
+
+    #[msg("Subscriber's remaining approval does not cover this charge")]
+    InsufficientAllowance,
+
+    #[msg("Only the subscriber can perform this action")]
+    Unauthorized,
+
+    #[msg("Arithmetic overflow")]
+    Overflow,
+}
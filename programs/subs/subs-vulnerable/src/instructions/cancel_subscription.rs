@@ -0,0 +1,58 @@
+// Cancel Subscription Instruction
+//
+// A subscriber stops paying: the delegate approval is revoked and the
+// subscription is closed.
+//
+// HOW IT WORKS:
+// 1. Revokes the delegate if it is still this subscription
+// 2. Closes the subscription PDA, refunding rent to the subscriber
+//
+// SECURITY:
+// - Only the subscriber (has_one = subscriber)
+// - Only revokes this subscription's approval, never another delegate's
+// - Once closed, charge_subscription has no account to charge against
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct CancelSubscription<'info> {
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+
+    #[account(
+        mut,
+        close = subscriber,
+        seeds = [SUBSCRIPTION_SEED, subscription.plan.as_ref(), subscriber.key().as_ref()],
+        bump = subscription.bump,
+        has_one = subscriber @ SubsError::Unauthorized,
+        has_one = subscriber_token_account,
+    )]
+    pub subscription: Box<Account<'info, Subscription>>,
+
+    #[account(mut)]
+    pub subscriber_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CancelSubscription<'info> {
+    pub fn cancel_subscription(&mut self) -> Result<()> {
+        if self.subscriber_token_account.delegate == Some(self.subscription.key()).into() {
+            revoke_delegate(
+                &self.token_program.to_account_info(),
+                &self.subscriber_token_account.to_account_info(),
+                &self.subscriber.to_account_info(),
+            )?;
+        }
+
+        msg!(
+            "Subscription cancelled after {} charges",
+            self.subscription.charges
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+// Charge Subscription Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// Permissionless crank that pulls a payment into the merchant vault.
+//
+// VULNERABILITIES:
+// V001: Crank-chosen amount - the caller decides how much to pull, up to the
+//       subscriber's whole approval
+// V002: Missing period check - charges can be taken back-to-back, as often
+//       as the crank likes
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct ChargeSubscription<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [PLAN_SEED, plan.merchant.as_ref(), plan.plan_id.to_le_bytes().as_ref()],
+        bump = plan.bump,
+        has_one = mint,
+        has_one = merchant_vault,
+    )]
+    pub plan: Box<Account<'info, Plan>>,
+
+    #[account(
+        mut,
+        seeds = [SUBSCRIPTION_SEED, plan.key().as_ref(), subscription.subscriber.as_ref()],
+        bump = subscription.bump,
+        has_one = plan,
+        has_one = subscriber_token_account,
+    )]
+    pub subscription: Box<Account<'info, Subscription>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub subscriber_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub merchant_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ChargeSubscription<'info> {
+    pub fn charge_subscription(&mut self, amount: u64) -> Result<()> {
+        // VULNERABILITY V002: No check that the subscription is due
+        // Secure version:
+        //   require!(now >= self.subscription.next_charge_ts, SubsError::ChargeTooEarly);
+        // next_charge_ts is still advanced below, but nothing ever reads it.
+        // Attack scenario (back-to-back charges):
+        // 1. Subscriber approves 12 months of a 10-token monthly plan
+        // 2. The crank calls charge_subscription 12 times in one slot
+        // 3. A year of payments leaves the subscriber on day one
+
+        // VULNERABILITY V001: Amount comes from the caller, not the plan
+        // Secure version:
+        //   let amount = self.plan.amount;
+        // The crank is permissionless, so "the caller" is anyone.
+        // Attack scenario (allowance sweep):
+        // 1. Subscriber approves 12 periods (120 tokens) for a 10-token plan
+        // 2. Anyone calls charge_subscription(120 tokens)
+        // 3. The whole approval moves to the merchant in one charge
+        require!(amount > 0, SubsError::ZeroAmount);
+        require!(
+            self.subscriber_token_account.delegate == Some(self.subscription.key()).into()
+                && self.subscriber_token_account.delegated_amount >= amount,
+            SubsError::InsufficientAllowance
+        );
+
+        // Advanced on every charge but never checked (V002)
+        self.subscription.next_charge_ts = self
+            .subscription
+            .next_charge_ts
+            .checked_add(self.plan.period_seconds)
+            .ok_or(SubsError::Overflow)?;
+        self.subscription.charges = self
+            .subscription
+            .charges
+            .checked_add(1)
+            .ok_or(SubsError::Overflow)?;
+
+        let plan_key = self.plan.key();
+        let subscriber = self.subscription.subscriber;
+        let subscription_seeds = &[
+            SUBSCRIPTION_SEED,
+            plan_key.as_ref(),
+            subscriber.as_ref(),
+            &[self.subscription.bump],
+        ];
+
+        transfer_as_delegate(
+            amount,
+            self.mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.subscriber_token_account.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.merchant_vault.to_account_info(),
+            &self.subscription.to_account_info(),
+            subscription_seeds,
+        )?;
+
+        msg!(
+            "Charged {} (charge #{}), next due at {}",
+            amount,
+            self.subscription.charges,
+            self.subscription.next_charge_ts
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+// Subscription Billing Program - VULNERABLE VERSION
+//
+// WARNING: This program contains intentional security vulnerabilities for educational purposes.
+// NEVER deploy this to production. Use subs-secure instead.
+//
+// This vulnerable subscription program demonstrates two classic pull-payment flaws:
+// - The charge amount is chosen by whoever runs the crank
+// - Charges are not limited to one per billing period
+//
+// VULNERABILITIES DOCUMENTED:
+// See individual instruction files for detailed vulnerability explanations.
+// Each vulnerability is marked with VULNERABILITY comments.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("2TVPnHzBwrtTpfeqGKa5CUEhYBWeaKaYwWSStB4kASB6");
+
+#[program]
+pub mod subs_vulnerable {
+    use super::*;
+
+    // Offer a fixed amount every period
+    pub fn create_plan(
+        ctx: Context<CreatePlan>,
+        plan_id: u64,
+        amount: u64,
+        period_seconds: i64,
+    ) -> Result<()> {
+        ctx.accounts.create_plan(plan_id, amount, period_seconds, &ctx.bumps)
+    }
+
+    // Join a plan and approve the subscription as delegate
+    pub fn subscribe(ctx: Context<Subscribe>, approved_periods: u32) -> Result<()> {
+        ctx.accounts.subscribe(approved_periods, &ctx.bumps)
+    }
+
+    // Permissionless crank: take a payment
+    // VULNERABILITY: Caller picks the amount and can charge at any time
+    pub fn charge_subscription(ctx: Context<ChargeSubscription>, amount: u64) -> Result<()> {
+        ctx.accounts.charge_subscription(amount)
+    }
+
+    // Subscriber-only: revoke the approval and close the subscription
+    pub fn cancel_subscription(ctx: Context<CancelSubscription>) -> Result<()> {
+        ctx.accounts.cancel_subscription()
+    }
+}
@@ -0,0 +1,9 @@
+// State Module
+//
+// Exports all state structures used by the subscription program
+
+pub mod plan;
+pub mod subscription;
+
+pub use plan::*;
+pub use subscription::*;
@@ -0,0 +1,29 @@
+// Plan State
+//
+// A merchant's offer: a fixed amount of one mint every period.
+
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Plan {
+    // Account that created the plan and is paid by it
+    pub merchant: Pubkey,
+
+    // Mint subscriptions are paid in
+    pub mint: Pubkey,
+
+    // Merchant token account that receives every charge
+    pub merchant_vault: Pubkey,
+
+    // Merchant-chosen id so one merchant can offer several plans
+    pub plan_id: u64,
+
+    // Amount charged per period
+    pub amount: u64,
+
+    // Seconds between charges
+    pub period_seconds: i64,
+
+    pub bump: u8,
+}
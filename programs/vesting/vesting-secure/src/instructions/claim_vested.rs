@@ -0,0 +1,100 @@
+// Claim Vested Instruction
+//
+// Pays the beneficiary every token that has vested but not yet been claimed.
+//
+// HOW IT WORKS:
+// 1. Computes the vested amount at the current time
+// 2. Subtracts what has already been claimed
+// 3. Transfers the difference from the vault, signed by the schedule PDA
+//
+// SECURITY:
+// - Only the recorded beneficiary can claim (signer + has_one + PDA seeds)
+// - Destination token account must belong to the beneficiary
+// - Nothing vests before the cliff
+// - claimed_amount can never exceed the vested amount
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    pub beneficiary: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            VESTING_SEED,
+            schedule.creator.as_ref(),
+            beneficiary.key().as_ref(),
+            schedule.seed.to_le_bytes().as_ref(),
+        ],
+        bump = schedule.bump,
+        has_one = beneficiary @ VestingError::Unauthorized,
+        has_one = mint,
+        has_one = vault,
+    )]
+    pub schedule: Box<Account<'info, VestingSchedule>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = beneficiary,
+        token::token_program = token_program,
+    )]
+    pub beneficiary_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClaimVested<'info> {
+    pub fn claim_vested(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        let claimable = self.schedule.claimable_amount(now)?;
+        require!(claimable > 0, VestingError::NothingToClaim);
+
+        let creator = self.schedule.creator;
+        let beneficiary = self.schedule.beneficiary;
+        let seed_bytes = self.schedule.seed.to_le_bytes();
+        let schedule_seeds = &[
+            VESTING_SEED,
+            creator.as_ref(),
+            beneficiary.as_ref(),
+            seed_bytes.as_ref(),
+            &[self.schedule.bump],
+        ];
+
+        transfer_from_vault(
+            claimable,
+            self.mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.vault.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.beneficiary_token_account.to_account_info(),
+            &self.schedule.to_account_info(),
+            schedule_seeds,
+        )?;
+
+        self.schedule.claimed_amount = self
+            .schedule
+            .claimed_amount
+            .checked_add(claimable)
+            .ok_or(VestingError::Overflow)?;
+
+        msg!(
+            "Claimed {} tokens ({} of {} claimed)",
+            claimable,
+            self.schedule.claimed_amount,
+            self.schedule.total_amount
+        );
+
+        Ok(())
+    }
+}
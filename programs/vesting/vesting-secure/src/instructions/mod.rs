@@ -0,0 +1,11 @@
+// Instructions Module
+//
+// Exports all instruction handlers for the vesting program
+
+pub mod create_vesting_schedule;
+pub mod claim_vested;
+pub mod revoke_vesting;
+
+pub use create_vesting_schedule::*;
+pub use claim_vested::*;
+pub use revoke_vesting::*;
@@ -0,0 +1,66 @@
+// Token Vesting Program - SECURE VERSION
+//
+// Implementation of token vesting with linear and cliff schedules.
+// This program allows users to:
+// 1. Lock tokens for a beneficiary under a vesting schedule
+// 2. Let the beneficiary claim tokens as they vest
+// 3. Revoke the unvested remainder of a revocable schedule
+//
+// SECURITY FEATURES:
+// - Nothing is claimable before the cliff
+// - Only the recorded beneficiary can claim, into their own token account
+// - Revoking always pays out what has already vested
+// - Schedule timestamps and duration validated at creation
+// - Checked arithmetic to prevent overflow/underflow
+//
+// SCHEDULE MODEL:
+// vested = total * (now - start) / (end - start) once now >= cliff.
+// cliff == start gives a pure linear schedule; cliff == end gives a pure cliff.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("62FSBn8beyQ2DaPXq7iszrfeaqMxX3KMZzUR6iGP26vk");
+
+#[program]
+pub mod vesting_secure {
+    use super::*;
+
+    // Lock tokens for a beneficiary under a new vesting schedule
+    pub fn create_vesting_schedule(
+        ctx: Context<CreateVestingSchedule>,
+        seed: u64,
+        total_amount: u64,
+        start_ts: i64,
+        cliff_ts: i64,
+        end_ts: i64,
+        revocable: bool,
+    ) -> Result<()> {
+        ctx.accounts.create_vesting_schedule(
+            seed,
+            total_amount,
+            start_ts,
+            cliff_ts,
+            end_ts,
+            revocable,
+            &ctx.bumps,
+        )
+    }
+
+    // Claim every vested token not yet claimed
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        ctx.accounts.claim_vested()
+    }
+
+    // Cancel the unvested remainder of a revocable schedule
+    pub fn revoke_vesting(ctx: Context<RevokeVesting>) -> Result<()> {
+        ctx.accounts.revoke_vesting()
+    }
+}
@@ -0,0 +1,49 @@
+// Compute-unit regression tests for vesting-secure
+// Each instruction runs once on its happy path; the units it consumes are
+// compared with tests/compute-baselines.txt and the test fails on growth beyond
+// the tolerance (see ComputeBudget in soteria-test-kit)
+//
+// HOW TO RUN:
+//   cargo test-sbf --test compute
+//   SOTERIA_UPDATE_CU_BASELINES=1 cargo test-sbf --test compute   # accept new costs
+
+mod utils;
+
+use solana_sdk::signature::Signer;
+use soteria_test_kit::{ComputeBudget, DEFAULT_CU_BASELINES_FILE};
+use utils::*;
+
+#[test]
+fn test_compute_units() {
+    let mut budget = ComputeBudget::load(DEFAULT_CU_BASELINES_FILE);
+    let mut scenario = setup_vesting_scenario();
+    let creator = scenario.creator.pubkey();
+    let beneficiary = scenario.beneficiary.pubkey();
+
+    let now = current_timestamp(&scenario.svm);
+    let ix = build_create_schedule_ix(
+        &creator,
+        &beneficiary,
+        &scenario.mint,
+        1,
+        GRANT_AMOUNT,
+        now,
+        now + CLIFF_DURATION,
+        now + VESTING_DURATION,
+        true,
+    );
+    budget.record("create_vesting_schedule", send_ix(&mut scenario.svm, ix, &scenario.creator));
+    let (schedule, _) = derive_schedule_pda(&creator, &beneficiary, 1);
+
+    // 40% vested
+    advance_time(&mut scenario.svm, 400);
+    let ix = build_claim_ix(&beneficiary, &schedule, &scenario.mint, &scenario.beneficiary_ata);
+    budget.record("claim_vested", send_ix(&mut scenario.svm, ix, &scenario.beneficiary));
+
+    // 60% vested, the unclaimed 20% goes to the beneficiary on revoke
+    advance_time(&mut scenario.svm, 200);
+    let ix = build_revoke_ix(&creator, &schedule, &scenario.mint, &scenario.beneficiary_ata);
+    budget.record("revoke_vesting", send_ix(&mut scenario.svm, ix, &scenario.creator));
+
+    budget.check();
+}
@@ -0,0 +1,260 @@
+// Integration tests for vesting program using LiteSVM
+// These tests verify core vesting functionality: schedule creation, cliff
+// enforcement, linear and cliff-only unlocks, beneficiary checks, and revocation
+
+mod utils;
+
+use utils::*;
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signature::Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+#[test]
+fn test_create_vesting_schedule() {
+    // Test: Create a schedule and reject invalid timestamps and amounts
+    println!("\n[TEST START] test_create_vesting_schedule - Schedule creation and validation");
+
+    let mut scenario = setup_vesting_scenario();
+    println!("[Setup] Creator funded with {} tokens", GRANT_AMOUNT * 10 / ONE_TOKEN);
+
+    println!("[Action] Creating default schedule (cliff {}s, end {}s)", CLIFF_DURATION, VESTING_DURATION);
+    let schedule = create_default_schedule(&mut scenario, 1, true);
+
+    let vault = get_associated_token_address(&schedule, &scenario.mint);
+    assert_eq!(token_balance(&scenario.svm, &vault), GRANT_AMOUNT);
+    assert_eq!(get_schedule_amounts(&scenario.svm, &schedule), (GRANT_AMOUNT, 0));
+    println!("[Success] Vault holds {} tokens", GRANT_AMOUNT / ONE_TOKEN);
+
+    let now = current_timestamp(&scenario.svm);
+
+    // Cliff before start is rejected
+    println!("[Action] Creating schedule with cliff before start");
+    let ix = build_create_schedule_ix(
+        &scenario.creator.pubkey(),
+        &scenario.beneficiary.pubkey(),
+        &scenario.mint,
+        2,
+        GRANT_AMOUNT,
+        now + 100,
+        now,
+        now + VESTING_DURATION,
+        true,
+    );
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.creator).is_err(), "Cliff before start should fail");
+    println!("[Success] Cliff before start rejected");
+
+    // Cliff after end is rejected
+    println!("[Action] Creating schedule with cliff after end");
+    let ix = build_create_schedule_ix(
+        &scenario.creator.pubkey(),
+        &scenario.beneficiary.pubkey(),
+        &scenario.mint,
+        3,
+        GRANT_AMOUNT,
+        now,
+        now + VESTING_DURATION + 1,
+        now + VESTING_DURATION,
+        true,
+    );
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.creator).is_err(), "Cliff after end should fail");
+    println!("[Success] Cliff after end rejected");
+
+    // Zero amount is rejected
+    println!("[Action] Creating schedule with zero tokens");
+    let ix = build_create_schedule_ix(
+        &scenario.creator.pubkey(),
+        &scenario.beneficiary.pubkey(),
+        &scenario.mint,
+        4,
+        0,
+        now,
+        now + CLIFF_DURATION,
+        now + VESTING_DURATION,
+        true,
+    );
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.creator).is_err(), "Zero amount should fail");
+    println!("[Success] Zero amount rejected");
+
+    println!("[TEST END] test_create_vesting_schedule");
+}
+
+#[test]
+fn test_claim_before_cliff_rejected() {
+    // Test: Nothing can be claimed before the cliff, even though time has passed since start
+    println!("\n[TEST START] test_claim_before_cliff_rejected - Cliff enforcement");
+
+    let mut scenario = setup_vesting_scenario();
+    let schedule = create_default_schedule(&mut scenario, 1, false);
+    println!("[Setup] Schedule created, cliff at +{}s", CLIFF_DURATION);
+
+    advance_time(&mut scenario.svm, CLIFF_DURATION - 1);
+    println!("[Action] Beneficiary claims 1 second before the cliff");
+    let ix = build_claim_ix(&scenario.beneficiary.pubkey(), &schedule, &scenario.mint, &scenario.beneficiary_ata);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.beneficiary).is_err(), "Claim before cliff should fail");
+    assert_eq!(token_balance(&scenario.svm, &scenario.beneficiary_ata), 0);
+    println!("[Success] Claim rejected, beneficiary received nothing");
+
+    advance_time(&mut scenario.svm, 1);
+    println!("[Action] Beneficiary claims exactly at the cliff");
+    let ix = build_claim_ix(&scenario.beneficiary.pubkey(), &schedule, &scenario.mint, &scenario.beneficiary_ata);
+    let result = send_ix(&mut scenario.svm, ix, &scenario.beneficiary);
+    assert!(result.is_ok(), "Claim at cliff failed: {:?}", result.err());
+
+    // Linear vesting measured from start: 250 / 1000 of the grant
+    let expected = GRANT_AMOUNT / 4;
+    assert_eq!(token_balance(&scenario.svm, &scenario.beneficiary_ata), expected);
+    println!("[Success] Cliff catch-up paid {} tokens", expected / ONE_TOKEN);
+
+    println!("[TEST END] test_claim_before_cliff_rejected");
+}
+
+#[test]
+fn test_linear_vesting_claims() {
+    // Test: Tokens unlock linearly between cliff and end and can be claimed in pieces
+    println!("\n[TEST START] test_linear_vesting_claims - Linear unlock");
+
+    let mut scenario = setup_vesting_scenario();
+    let schedule = create_default_schedule(&mut scenario, 1, false);
+
+    advance_time(&mut scenario.svm, VESTING_DURATION / 2);
+    println!("[Action] Claiming halfway through the schedule");
+    let ix = build_claim_ix(&scenario.beneficiary.pubkey(), &schedule, &scenario.mint, &scenario.beneficiary_ata);
+    let result = send_ix(&mut scenario.svm, ix, &scenario.beneficiary);
+    assert!(result.is_ok(), "Halfway claim failed: {:?}", result.err());
+    assert_eq!(token_balance(&scenario.svm, &scenario.beneficiary_ata), GRANT_AMOUNT / 2);
+    println!("[Success] Received {} tokens", GRANT_AMOUNT / 2 / ONE_TOKEN);
+
+    // Claiming again at the same time has nothing new to pay
+    let ix = build_claim_ix(&scenario.beneficiary.pubkey(), &schedule, &scenario.mint, &scenario.beneficiary_ata);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.beneficiary).is_err(), "Repeat claim should fail");
+    println!("[Success] Repeat claim with nothing new rejected");
+
+    advance_time(&mut scenario.svm, VESTING_DURATION);
+    println!("[Action] Claiming after the schedule has ended");
+    let ix = build_claim_ix(&scenario.beneficiary.pubkey(), &schedule, &scenario.mint, &scenario.beneficiary_ata);
+    let result = send_ix(&mut scenario.svm, ix, &scenario.beneficiary);
+    assert!(result.is_ok(), "Final claim failed: {:?}", result.err());
+
+    assert_eq!(token_balance(&scenario.svm, &scenario.beneficiary_ata), GRANT_AMOUNT);
+    assert_eq!(get_schedule_amounts(&scenario.svm, &schedule), (GRANT_AMOUNT, GRANT_AMOUNT));
+    println!("[Success] Full grant of {} tokens claimed", GRANT_AMOUNT / ONE_TOKEN);
+
+    println!("[TEST END] test_linear_vesting_claims");
+}
+
+#[test]
+fn test_cliff_only_schedule() {
+    // Test: A schedule with cliff == end unlocks everything at once
+    println!("\n[TEST START] test_cliff_only_schedule - All-at-once unlock");
+
+    let mut scenario = setup_vesting_scenario();
+    let now = current_timestamp(&scenario.svm);
+    let ix = build_create_schedule_ix(
+        &scenario.creator.pubkey(),
+        &scenario.beneficiary.pubkey(),
+        &scenario.mint,
+        1,
+        GRANT_AMOUNT,
+        now,
+        now + VESTING_DURATION,
+        now + VESTING_DURATION,
+        false,
+    );
+    send_ix(&mut scenario.svm, ix, &scenario.creator).expect("Schedule creation should succeed");
+    let (schedule, _) = derive_schedule_pda(&scenario.creator.pubkey(), &scenario.beneficiary.pubkey(), 1);
+    println!("[Setup] Cliff-only schedule, unlocks at +{}s", VESTING_DURATION);
+
+    advance_time(&mut scenario.svm, VESTING_DURATION - 1);
+    let ix = build_claim_ix(&scenario.beneficiary.pubkey(), &schedule, &scenario.mint, &scenario.beneficiary_ata);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.beneficiary).is_err(), "Claim before unlock should fail");
+    println!("[Success] Nothing claimable 1 second before unlock");
+
+    advance_time(&mut scenario.svm, 1);
+    let ix = build_claim_ix(&scenario.beneficiary.pubkey(), &schedule, &scenario.mint, &scenario.beneficiary_ata);
+    let result = send_ix(&mut scenario.svm, ix, &scenario.beneficiary);
+    assert!(result.is_ok(), "Claim at unlock failed: {:?}", result.err());
+    assert_eq!(token_balance(&scenario.svm, &scenario.beneficiary_ata), GRANT_AMOUNT);
+    println!("[Success] Entire grant claimed at unlock");
+
+    println!("[TEST END] test_cliff_only_schedule");
+}
+
+#[test]
+fn test_beneficiary_substitution_prevented() {
+    // Test: Only the recorded beneficiary can claim, and only into their own account
+    println!("\n[TEST START] test_beneficiary_substitution_prevented - Beneficiary checks");
+
+    let mut scenario = setup_vesting_scenario();
+    let schedule = create_default_schedule(&mut scenario, 1, true);
+    advance_time(&mut scenario.svm, VESTING_DURATION);
+    println!("[Setup] Schedule fully vested");
+
+    let attacker = create_funded_account(&mut scenario.svm, LAMPORTS_PER_SOL);
+    let attacker_ata = create_token_account(&mut scenario.svm, &attacker, &scenario.mint, &attacker.pubkey());
+
+    println!("[Action] Attacker claims the schedule into their own account");
+    let ix = build_claim_ix(&attacker.pubkey(), &schedule, &scenario.mint, &attacker_ata);
+    assert!(send_ix(&mut scenario.svm, ix, &attacker).is_err(), "Attacker claim should fail");
+    println!("[Success] Non-beneficiary claim rejected");
+
+    println!("[Action] Creator revokes, redirecting vested tokens to their own account");
+    let ix = build_revoke_ix(&scenario.creator.pubkey(), &schedule, &scenario.mint, &scenario.creator_ata);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.creator).is_err(), "Redirected revoke should fail");
+    println!("[Success] Revoke into a non-beneficiary account rejected");
+
+    assert_eq!(token_balance(&scenario.svm, &attacker_ata), 0);
+    let ix = build_claim_ix(&scenario.beneficiary.pubkey(), &schedule, &scenario.mint, &scenario.beneficiary_ata);
+    send_ix(&mut scenario.svm, ix, &scenario.beneficiary).expect("Beneficiary claim should succeed");
+    assert_eq!(token_balance(&scenario.svm, &scenario.beneficiary_ata), GRANT_AMOUNT);
+    println!("[Success] Beneficiary still receives the full grant");
+
+    println!("[TEST END] test_beneficiary_substitution_prevented");
+}
+
+#[test]
+fn test_revoke_vesting() {
+    // Test: Revoking pays out the vested part and returns the unvested part
+    println!("\n[TEST START] test_revoke_vesting - Revocation split");
+
+    let mut scenario = setup_vesting_scenario();
+    let schedule = create_default_schedule(&mut scenario, 1, true);
+    let creator_balance_before = token_balance(&scenario.svm, &scenario.creator_ata);
+
+    advance_time(&mut scenario.svm, 400);
+    println!("[Setup] 40% of the schedule has vested");
+
+    // Only the creator may revoke
+    let ix = build_revoke_ix(&scenario.beneficiary.pubkey(), &schedule, &scenario.mint, &scenario.beneficiary_ata);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.beneficiary).is_err(), "Non-creator revoke should fail");
+    println!("[Success] Non-creator revoke rejected");
+
+    println!("[Action] Creator revokes the schedule");
+    let ix = build_revoke_ix(&scenario.creator.pubkey(), &schedule, &scenario.mint, &scenario.beneficiary_ata);
+    let result = send_ix(&mut scenario.svm, ix, &scenario.creator);
+    assert!(result.is_ok(), "Revoke failed: {:?}", result.err());
+
+    let vested = GRANT_AMOUNT * 4 / 10;
+    assert_eq!(token_balance(&scenario.svm, &scenario.beneficiary_ata), vested);
+    assert_eq!(
+        token_balance(&scenario.svm, &scenario.creator_ata),
+        creator_balance_before + GRANT_AMOUNT - vested
+    );
+    assert_eq!(get_schedule_amounts(&scenario.svm, &schedule), (vested, vested));
+    println!("[Success] Beneficiary kept {} vested, creator recovered {}", vested / ONE_TOKEN, (GRANT_AMOUNT - vested) / ONE_TOKEN);
+
+    let ix = build_revoke_ix(&scenario.creator.pubkey(), &schedule, &scenario.mint, &scenario.beneficiary_ata);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.creator).is_err(), "Second revoke should fail");
+    println!("[Success] Second revoke rejected");
+
+    advance_time(&mut scenario.svm, VESTING_DURATION);
+    let ix = build_claim_ix(&scenario.beneficiary.pubkey(), &schedule, &scenario.mint, &scenario.beneficiary_ata);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.beneficiary).is_err(), "Claim after revoke should fail");
+    println!("[Success] Revoked schedule stops vesting");
+
+    // Non-revocable schedules cannot be revoked
+    let fixed_schedule = create_default_schedule(&mut scenario, 2, false);
+    let ix = build_revoke_ix(&scenario.creator.pubkey(), &fixed_schedule, &scenario.mint, &scenario.beneficiary_ata);
+    assert!(send_ix(&mut scenario.svm, ix, &scenario.creator).is_err(), "Non-revocable revoke should fail");
+    println!("[Success] Non-revocable schedule cannot be revoked");
+
+    println!("[TEST END] test_revoke_vesting");
+}
@@ -0,0 +1,11 @@
+// Seed for vesting schedule PDA (also the vault authority)
+// Derived with: [VESTING_SEED, creator_pubkey, beneficiary_pubkey, seed.to_le_bytes()]
+pub const VESTING_SEED: &[u8] = b"vesting";
+
+// SCHEDULE LIMITS
+
+// Maximum time between start and end of a schedule (10 years)
+// Keeps schedules from locking tokens effectively forever
+pub const MAX_VESTING_DURATION_SECONDS: i64 = 10 * 365 * 24 * 60 * 60;
+
+pub const ANCHOR_DISCRIMINATOR: usize = 8;
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+pub use soteria_errors::vesting_vulnerable::VestingError as VestingErrorCode;
+
+#[error_code]
+pub enum VestingError {
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Schedule must satisfy start <= cliff <= end and start < end")]
+    InvalidSchedule,
+
+    #[msg("Vesting duration cannot exceed maximum allowed (10 years)")]
+    DurationTooLong,
+
+    #[msg("No vested tokens available to claim")]
+    NothingToClaim,
+
+    #[msg("Vesting schedule is not revocable")]
+    NotRevocable,
+
+    #[msg("Vesting schedule has already been revoked")]
+    AlreadyRevoked,
+
+    #[msg("Unauthorized: Cannot perform this action")]
+    Unauthorized,
+
+    #[msg("Arithmetic overflow")]
+    Overflow,
+
+    #[msg("Arithmetic underflow")]
+    Underflow,
+
+    #[msg("Division by zero")]
+    DivisionByZero,
+}
@@ -0,0 +1,112 @@
+// Vesting Helper Functions - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// VULNERABILITY:
+// V001: Missing cliff check - tokens vest linearly from start_ts, ignoring cliff_ts
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TransferChecked, transfer_checked};
+
+use crate::errors::*;
+
+// VESTING MATH
+
+// Tokens unlocked at `now` for a schedule
+// Formula: vested = total * (now - start) / (end - start), rounded down
+//
+// Returns 0 before start and the full amount from end_ts onwards
+pub fn calculate_vested_amount(
+    total_amount: u64,
+    start_ts: i64,
+    _cliff_ts: i64,
+    end_ts: i64,
+    now: i64,
+) -> Result<u64> {
+    // VULNERABILITY V001: Missing cliff check
+    // Secure version:
+    //   if now < cliff_ts {
+    //       return Ok(0);
+    //   }
+    // Attack scenario (early claim):
+    // 1. Creator grants 1000 tokens over 1000 seconds with a 500 second cliff
+    //    (e.g. an employee grant that should unlock nothing for the first half)
+    // 2. 250 seconds in, the beneficiary calls claim_vested
+    // 3. The linear formula below runs without the cliff gate and pays 250 tokens
+    // 4. The beneficiary leaves before the cliff, keeping tokens they never earned
+    if now < start_ts {
+        return Ok(0);
+    }
+
+    if now >= end_ts {
+        return Ok(total_amount);
+    }
+
+    let elapsed = now.checked_sub(start_ts).ok_or(VestingError::Underflow)?;
+    let duration = end_ts.checked_sub(start_ts).ok_or(VestingError::Underflow)?;
+
+    let vested = (total_amount as u128)
+        .checked_mul(elapsed as u128)
+        .ok_or(VestingError::Overflow)?
+        .checked_div(duration as u128)
+        .ok_or(VestingError::DivisionByZero)?;
+
+    Ok(vested as u64)
+}
+
+// CPI HELPERS
+
+// Generic token transfer helper
+// Used for funding the vault from the creator
+pub fn transfer_tokens<'info>(
+    amount: u64,
+    decimals: u8,
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+) -> Result<()> {
+    transfer_checked(
+        CpiContext::new(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+        ),
+        amount,
+        decimals,
+    )
+}
+
+// Transfer out of the schedule vault, signed by the schedule PDA
+pub fn transfer_from_vault<'info>(
+    amount: u64,
+    decimals: u8,
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let signer_seeds = &[authority_seeds];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )
+}
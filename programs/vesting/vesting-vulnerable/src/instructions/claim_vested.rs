@@ -0,0 +1,104 @@
+// Claim Vested Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// VULNERABILITY:
+// V001: Vested amount ignores the cliff (see helpers.rs)
+// V002: Beneficiary substitution - any signer can claim into any token account
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    // VULNERABILITY V002: Any signer is accepted as the claimer
+    // Secure version names this account `beneficiary` and adds
+    // `has_one = beneficiary @ VestingError::Unauthorized` to the schedule
+    pub claimer: Signer<'info>,
+
+    // Seeds are rebuilt from the schedule's own fields, so they always match
+    // and say nothing about who is calling
+    #[account(
+        mut,
+        seeds = [
+            VESTING_SEED,
+            schedule.creator.as_ref(),
+            schedule.beneficiary.as_ref(),
+            schedule.seed.to_le_bytes().as_ref(),
+        ],
+        bump = schedule.bump,
+        has_one = mint,
+        has_one = vault,
+    )]
+    pub schedule: Box<Account<'info, VestingSchedule>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // VULNERABILITY V002: Destination only checked for the right mint
+    // Secure version: token::authority = beneficiary
+    // Attack scenario (beneficiary substitution):
+    // 1. Attacker watches for a vesting schedule with unclaimed vested tokens
+    // 2. Attacker signs claim_vested themselves, passing the victim's schedule
+    //    and their own token account as beneficiary_token_account
+    // 3. The vault pays the vested tokens to the attacker
+    // 4. claimed_amount increases, so the real beneficiary can never claim them
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub beneficiary_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClaimVested<'info> {
+    pub fn claim_vested(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        let claimable = self.schedule.claimable_amount(now)?;
+        require!(claimable > 0, VestingError::NothingToClaim);
+
+        let creator = self.schedule.creator;
+        let beneficiary = self.schedule.beneficiary;
+        let seed_bytes = self.schedule.seed.to_le_bytes();
+        let schedule_seeds = &[
+            VESTING_SEED,
+            creator.as_ref(),
+            beneficiary.as_ref(),
+            seed_bytes.as_ref(),
+            &[self.schedule.bump],
+        ];
+
+        transfer_from_vault(
+            claimable,
+            self.mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.vault.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.beneficiary_token_account.to_account_info(),
+            &self.schedule.to_account_info(),
+            schedule_seeds,
+        )?;
+
+        self.schedule.claimed_amount = self
+            .schedule
+            .claimed_amount
+            .checked_add(claimable)
+            .ok_or(VestingError::Overflow)?;
+
+        msg!(
+            "Claimed {} tokens ({} of {} claimed)",
+            claimable,
+            self.schedule.claimed_amount,
+            self.schedule.total_amount
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+// Create Vesting Schedule Instruction
+//
+// Locks tokens from the creator in a vault that unlocks to the beneficiary over time.
+//
+// HOW IT WORKS:
+// 1. Validates the schedule timestamps and amount
+// 2. Creates the schedule PDA and a vault ATA owned by it
+// 3. Transfers total_amount from the creator into the vault
+//
+// SECURITY:
+// - total_amount > 0
+// - start_ts <= cliff_ts <= end_ts and start_ts < end_ts
+// - end_ts - start_ts <= MAX_VESTING_DURATION_SECONDS
+// - Schedule PDA seeds include creator and beneficiary, so the beneficiary
+//   recorded here is the only one that can ever derive this schedule
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct CreateVestingSchedule<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: Only recorded as the schedule's beneficiary, never read or written
+    pub beneficiary: UncheckedAccount<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = creator,
+        token::token_program = token_program,
+    )]
+    pub creator_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = ANCHOR_DISCRIMINATOR + VestingSchedule::INIT_SPACE,
+        seeds = [
+            VESTING_SEED,
+            creator.key().as_ref(),
+            beneficiary.key().as_ref(),
+            seed.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub schedule: Box<Account<'info, VestingSchedule>>,
+
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = schedule,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateVestingSchedule<'info> {
+    pub fn create_vesting_schedule(
+        &mut self,
+        seed: u64,
+        total_amount: u64,
+        start_ts: i64,
+        cliff_ts: i64,
+        end_ts: i64,
+        revocable: bool,
+        bumps: &CreateVestingScheduleBumps,
+    ) -> Result<()> {
+        require!(total_amount > 0, VestingError::ZeroAmount);
+
+        // Cliff must fall inside the vesting window
+        require!(
+            start_ts < end_ts && start_ts <= cliff_ts && cliff_ts <= end_ts,
+            VestingError::InvalidSchedule
+        );
+
+        let duration = end_ts.checked_sub(start_ts).ok_or(VestingError::Underflow)?;
+        require!(
+            duration <= MAX_VESTING_DURATION_SECONDS,
+            VestingError::DurationTooLong
+        );
+
+        self.schedule.set_inner(VestingSchedule {
+            creator: self.creator.key(),
+            beneficiary: self.beneficiary.key(),
+            mint: self.mint.key(),
+            vault: self.vault.key(),
+            seed,
+            total_amount,
+            claimed_amount: 0,
+            start_ts,
+            cliff_ts,
+            end_ts,
+            revocable,
+            revoked: false,
+            bump: bumps.schedule,
+        });
+
+        transfer_tokens(
+            total_amount,
+            self.mint.decimals,
+            &self.token_program.to_account_info(),
+            &self.creator_token_account.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.vault.to_account_info(),
+            &self.creator.to_account_info(),
+        )?;
+
+        msg!(
+            "Vesting schedule created: {} tokens for {}",
+            total_amount,
+            self.beneficiary.key()
+        );
+        msg!("Start: {}, cliff: {}, end: {}", start_ts, cliff_ts, end_ts);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+// Revoke Vesting Instruction - VULNERABLE VERSION
+//
+// WARNING: This version contains intentional vulnerabilities for educational purposes.
+//
+// VULNERABILITY:
+// V001: Vested amount ignores the cliff (see helpers.rs)
+// V002: Beneficiary substitution - vested tokens can be paid to any token account
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{constants::*, errors::*, state::*, helpers::*};
+
+#[derive(Accounts)]
+pub struct RevokeVesting<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            VESTING_SEED,
+            creator.key().as_ref(),
+            schedule.beneficiary.as_ref(),
+            schedule.seed.to_le_bytes().as_ref(),
+        ],
+        bump = schedule.bump,
+        has_one = creator @ VestingError::Unauthorized,
+        has_one = mint,
+        has_one = vault,
+    )]
+    pub schedule: Box<Account<'info, VestingSchedule>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = creator,
+        token::token_program = token_program,
+    )]
+    pub creator_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // VULNERABILITY V002: Beneficiary's account not tied to schedule.beneficiary
+    // Secure version: token::authority = schedule.beneficiary
+    // Attack scenario (creator claws back vested tokens):
+    // 1. Beneficiary has vested tokens they have not claimed yet
+    // 2. Creator revokes, passing their own token account as beneficiary_token_account
+    // 3. The vested portion and the unvested remainder both go to the creator
+    // 4. The schedule is marked fully claimed, so the beneficiary gets nothing
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub beneficiary_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> RevokeVesting<'info> {
+    pub fn revoke_vesting(&mut self) -> Result<()> {
+        require!(self.schedule.revocable, VestingError::NotRevocable);
+        require!(!self.schedule.revoked, VestingError::AlreadyRevoked);
+
+        let now = Clock::get()?.unix_timestamp;
+
+        let vested = self.schedule.vested_amount(now)?;
+        let owed_to_beneficiary = vested
+            .checked_sub(self.schedule.claimed_amount)
+            .ok_or(VestingError::Underflow)?;
+        let unvested = self
+            .schedule
+            .total_amount
+            .checked_sub(vested)
+            .ok_or(VestingError::Underflow)?;
+
+        let creator = self.schedule.creator;
+        let beneficiary = self.schedule.beneficiary;
+        let seed_bytes = self.schedule.seed.to_le_bytes();
+        let schedule_seeds = &[
+            VESTING_SEED,
+            creator.as_ref(),
+            beneficiary.as_ref(),
+            seed_bytes.as_ref(),
+            &[self.schedule.bump],
+        ];
+
+        // Settle what the beneficiary has already earned
+        if owed_to_beneficiary > 0 {
+            transfer_from_vault(
+                owed_to_beneficiary,
+                self.mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.vault.to_account_info(),
+                &self.mint.to_account_info(),
+                &self.beneficiary_token_account.to_account_info(),
+                &self.schedule.to_account_info(),
+                schedule_seeds,
+            )?;
+        }
+
+        // Return the rest to the creator
+        if unvested > 0 {
+            transfer_from_vault(
+                unvested,
+                self.mint.decimals,
+                &self.token_program.to_account_info(),
+                &self.vault.to_account_info(),
+                &self.mint.to_account_info(),
+                &self.creator_token_account.to_account_info(),
+                &self.schedule.to_account_info(),
+                schedule_seeds,
+            )?;
+        }
+
+        // Freeze the schedule: everything vested has been paid out
+        self.schedule.total_amount = vested;
+        self.schedule.claimed_amount = vested;
+        self.schedule.revoked = true;
+
+        msg!(
+            "Schedule revoked: {} paid to beneficiary, {} returned to creator",
+            owed_to_beneficiary,
+            unvested
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+// Token Vesting Program - VULNERABLE VERSION
+//
+// WARNING: This program contains intentional security vulnerabilities for educational purposes.
+// NEVER deploy this to production. Use vesting-secure instead.
+//
+// This vulnerable vesting program demonstrates two classic vesting flaws:
+// - Computing the vested amount without honoring the cliff
+// - Letting the caller choose who receives the vested tokens
+//
+// VULNERABILITIES DOCUMENTED:
+// See individual instruction files for detailed vulnerability explanations.
+// Each vulnerability is marked with VULNERABILITY comments.
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod state;
+pub mod helpers;
+
+use instructions::*;
+
+declare_id!("EMbRHmosc9krmn6oG318kzQwDWodPFUzdgwRLJiX9G3P");
+
+#[program]
+pub mod vesting_vulnerable {
+    use super::*;
+
+    // Lock tokens for a beneficiary under a new vesting schedule
+    pub fn create_vesting_schedule(
+        ctx: Context<CreateVestingSchedule>,
+        seed: u64,
+        total_amount: u64,
+        start_ts: i64,
+        cliff_ts: i64,
+        end_ts: i64,
+        revocable: bool,
+    ) -> Result<()> {
+        ctx.accounts.create_vesting_schedule(
+            seed,
+            total_amount,
+            start_ts,
+            cliff_ts,
+            end_ts,
+            revocable,
+            &ctx.bumps,
+        )
+    }
+
+    // Claim every vested token not yet claimed
+    // VULNERABILITY: No cliff check and no beneficiary check on the claimer or destination
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        ctx.accounts.claim_vested()
+    }
+
+    // Cancel the unvested remainder of a revocable schedule
+    pub fn revoke_vesting(ctx: Context<RevokeVesting>) -> Result<()> {
+        ctx.accounts.revoke_vesting()
+    }
+}
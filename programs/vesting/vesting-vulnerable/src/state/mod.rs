@@ -0,0 +1,7 @@
+// State Module
+//
+// Exports all state structures used by the vesting program
+
+pub mod vesting_schedule;
+
+pub use vesting_schedule::*;
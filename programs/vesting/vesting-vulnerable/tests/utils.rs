@@ -0,0 +1,264 @@
+// Test utilities for vesting program
+
+use litesvm::{LiteSVM, types::TransactionResult};
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo, get_spl_account};
+use litesvm_token::spl_token::state::Account as TokenAccount;
+use solana_sdk::{
+    clock::Clock,
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use solana_system_interface::program::ID as system_program;
+
+// Program ID matching declare_id!
+pub const VESTING_PROGRAM_ID: Pubkey = Pubkey::new_from_array(vesting_vulnerable::ID.to_bytes());
+
+// Build Anchor instruction discriminator
+// Formula: first 8 bytes of sha256("global:method_name")
+pub fn anchor_discriminator(method: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", method);
+    let hash_result = hash(preimage.as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash_result.to_bytes()[..8]);
+    discriminator
+}
+
+// Standard program IDs
+pub const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = spl_associated_token_account::ID;
+
+// PDA Seeds
+pub const VESTING_SEED: &[u8] = b"vesting";
+
+// Token decimals
+pub const DECIMALS: u8 = 9;
+pub const ONE_TOKEN: u64 = 1_000_000_000;
+
+// Default schedule: 1000 tokens over 1000 seconds with a 250 second cliff
+pub const GRANT_AMOUNT: u64 = 1_000 * ONE_TOKEN;
+pub const VESTING_DURATION: i64 = 1_000;
+pub const CLIFF_DURATION: i64 = 250;
+
+// Setup LiteSVM with vesting program
+pub fn setup_svm() -> LiteSVM {
+    let mut svm = LiteSVM::new();
+    let program_bytes = include_bytes!("../target/deploy/vesting_vulnerable.so");
+    let _ = svm.add_program(VESTING_PROGRAM_ID, program_bytes);
+    svm
+}
+
+// Create and fund account
+pub fn create_funded_account(svm: &mut LiteSVM, lamports: u64) -> Keypair {
+    let keypair = Keypair::new();
+    svm.airdrop(&keypair.pubkey(), lamports)
+        .expect("Airdrop should succeed");
+    keypair
+}
+
+// Sign and send a single instruction
+pub fn send_ix(svm: &mut LiteSVM, ix: Instruction, signer: &Keypair) -> TransactionResult {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    svm.expire_blockhash();
+    result
+}
+
+pub fn current_timestamp(svm: &LiteSVM) -> i64 {
+    svm.get_sysvar::<Clock>().unix_timestamp
+}
+
+pub fn advance_time(svm: &mut LiteSVM, seconds: i64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += seconds;
+    svm.set_sysvar::<Clock>(&clock);
+}
+
+// Derive vesting schedule PDA
+pub fn derive_schedule_pda(creator: &Pubkey, beneficiary: &Pubkey, seed: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VESTING_SEED, creator.as_ref(), beneficiary.as_ref(), &seed.to_le_bytes()],
+        &VESTING_PROGRAM_ID,
+    )
+}
+
+// Build create_vesting_schedule instruction
+pub fn build_create_schedule_ix(
+    creator: &Pubkey,
+    beneficiary: &Pubkey,
+    mint: &Pubkey,
+    seed: u64,
+    total_amount: u64,
+    start_ts: i64,
+    cliff_ts: i64,
+    end_ts: i64,
+    revocable: bool,
+) -> Instruction {
+    let (schedule, _) = derive_schedule_pda(creator, beneficiary, seed);
+
+    let mut data = anchor_discriminator("create_vesting_schedule").to_vec();
+    data.extend_from_slice(&seed.to_le_bytes());
+    data.extend_from_slice(&total_amount.to_le_bytes());
+    data.extend_from_slice(&start_ts.to_le_bytes());
+    data.extend_from_slice(&cliff_ts.to_le_bytes());
+    data.extend_from_slice(&end_ts.to_le_bytes());
+    data.push(revocable as u8);
+
+    Instruction {
+        program_id: VESTING_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*creator, true),
+            AccountMeta::new_readonly(*beneficiary, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(get_associated_token_address(creator, mint), false),
+            AccountMeta::new(schedule, false),
+            AccountMeta::new(get_associated_token_address(&schedule, mint), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    }
+}
+
+// Build claim_vested instruction
+// `claimer` signs; `destination` receives the tokens
+pub fn build_claim_ix(
+    claimer: &Pubkey,
+    schedule: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: VESTING_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*claimer, true),
+            AccountMeta::new(*schedule, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(get_associated_token_address(schedule, mint), false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: anchor_discriminator("claim_vested").to_vec(),
+    }
+}
+
+// Build revoke_vesting instruction
+// `beneficiary_destination` receives the vested but unclaimed tokens
+pub fn build_revoke_ix(
+    creator: &Pubkey,
+    schedule: &Pubkey,
+    mint: &Pubkey,
+    beneficiary_destination: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: VESTING_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(*creator, true),
+            AccountMeta::new(*schedule, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(get_associated_token_address(schedule, mint), false),
+            AccountMeta::new(get_associated_token_address(creator, mint), false),
+            AccountMeta::new(*beneficiary_destination, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: anchor_discriminator("revoke_vesting").to_vec(),
+    }
+}
+
+// Read (total_amount, claimed_amount) from a VestingSchedule
+// Layout: discriminator (8) + 4 pubkeys (128) + seed (8) + total (8) + claimed (8)
+pub fn get_schedule_amounts(svm: &LiteSVM, schedule: &Pubkey) -> (u64, u64) {
+    let account = svm.get_account(schedule).expect("Schedule should exist");
+    let offset = 8 + 32 * 4 + 8;
+    let total = u64::from_le_bytes(account.data[offset..offset + 8].try_into().unwrap());
+    let claimed = u64::from_le_bytes(account.data[offset + 8..offset + 16].try_into().unwrap());
+    (total, claimed)
+}
+
+pub fn token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    let account: TokenAccount = get_spl_account(svm, token_account).expect("Token account should exist");
+    account.amount
+}
+
+// Vesting scenario setup result
+pub struct VestingScenario {
+    pub svm: LiteSVM,
+    pub creator: Keypair,
+    pub beneficiary: Keypair,
+    pub mint: Pubkey,
+    pub creator_ata: Pubkey,
+    pub beneficiary_ata: Pubkey,
+}
+
+// Create the mint, fund the creator with GRANT_AMOUNT * 10 tokens,
+// and create an empty token account for the beneficiary
+// `creator` is also the mint authority
+pub fn setup_vesting_scenario() -> VestingScenario {
+    let mut svm = setup_svm();
+    let creator = create_funded_account(&mut svm, 10 * LAMPORTS_PER_SOL);
+    let beneficiary = create_funded_account(&mut svm, 5 * LAMPORTS_PER_SOL);
+
+    let mint = CreateMint::new(&mut svm, &creator)
+        .authority(&creator.pubkey())
+        .decimals(DECIMALS)
+        .send()
+        .expect("Failed to create mint");
+
+    let creator_ata = CreateAssociatedTokenAccount::new(&mut svm, &creator, &mint)
+        .owner(&creator.pubkey())
+        .send()
+        .expect("Failed to create creator ATA");
+    MintTo::new(&mut svm, &creator, &mint, &creator_ata, GRANT_AMOUNT * 10)
+        .owner(&creator)
+        .send()
+        .expect("Failed to mint to creator");
+
+    let beneficiary_ata = create_token_account(&mut svm, &creator, &mint, &beneficiary.pubkey());
+
+    VestingScenario {
+        svm,
+        creator,
+        beneficiary,
+        mint,
+        creator_ata,
+        beneficiary_ata,
+    }
+}
+
+// Create an ATA for `owner`, paid for by `payer`
+pub fn create_token_account(svm: &mut LiteSVM, payer: &Keypair, mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+    CreateAssociatedTokenAccount::new(svm, payer, mint)
+        .owner(owner)
+        .send()
+        .expect("Failed to create token account")
+}
+
+// Create the default schedule (GRANT_AMOUNT, cliff after CLIFF_DURATION,
+// fully vested after VESTING_DURATION) starting now, and return its address
+pub fn create_default_schedule(scenario: &mut VestingScenario, seed: u64, revocable: bool) -> Pubkey {
+    let now = current_timestamp(&scenario.svm);
+    let ix = build_create_schedule_ix(
+        &scenario.creator.pubkey(),
+        &scenario.beneficiary.pubkey(),
+        &scenario.mint,
+        seed,
+        GRANT_AMOUNT,
+        now,
+        now + CLIFF_DURATION,
+        now + VESTING_DURATION,
+        revocable,
+    );
+    send_ix(&mut scenario.svm, ix, &scenario.creator).expect("Schedule creation should succeed");
+
+    derive_schedule_pda(&scenario.creator.pubkey(), &scenario.beneficiary.pubkey(), seed).0
+}